@@ -13,6 +13,8 @@
 
 mod adler32;
 mod crc32;
+mod crc64;
 
 pub use adler32::Adler32Context;
 pub use crc32::{Crc32Context, ReversedCrc32Context};
+pub use crc64::ReversedCrc64Context;
@@ -24,7 +24,7 @@ use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_layout_map::LayoutMap;
 
 use super::huffman::HuffmanTree;
-use super::traits::Bitstream;
+use super::traits::{Bitstream, StreamDecompressor};
 
 /// Bzip2 data header signature.
 pub(super) const BZIP2_DATA_HEADER_SIGNATURE: [u8; 2] = [0x42, 0x5a]; // BZ
@@ -32,6 +32,15 @@ pub(super) const BZIP2_DATA_HEADER_SIGNATURE: [u8; 2] = [0x42, 0x5a]; // BZ
 /// Bzip2 block size;
 pub(super) const BZIP2_BLOCK_SIZE: usize = 100000;
 
+/// Bzip2 maximum block size, which corresponds to compression level 9.
+const BZIP2_MAXIMUM_BLOCK_SIZE: usize = 9 * BZIP2_BLOCK_SIZE;
+
+/// Bzip2 block signature.
+const BZIP2_BLOCK_SIGNATURE: u64 = 0x314159265359;
+
+/// Bzip2 end of stream signature.
+const BZIP2_END_OF_STREAM_SIGNATURE: u64 = 0x177245385090;
+
 /// Bitstream for bzip2 compressed data.
 pub(super) struct Bzip2Bitstream<'a> {
     /// Byte steam.
@@ -48,6 +57,9 @@ pub(super) struct Bzip2Bitstream<'a> {
 
     /// Number of bits in the bits buffer.
     pub number_of_bits: usize,
+
+    /// Value to indicate the bits buffer was filled beyond the end of the byte stream.
+    pub overflow: bool,
 }
 
 impl<'a> Bzip2Bitstream<'a> {
@@ -60,6 +72,7 @@ impl<'a> Bzip2Bitstream<'a> {
             data_size: data_size,
             bits: 0,
             number_of_bits: 0,
+            overflow: false,
         }
     }
 
@@ -73,6 +86,8 @@ impl<'a> Bzip2Bitstream<'a> {
             if self.data_offset < self.data_size {
                 self.bits |= self.data[self.data_offset] as u32;
                 self.data_offset += 1;
+            } else {
+                self.overflow = true;
             }
             self.number_of_bits += 8;
         }
@@ -213,11 +228,11 @@ impl Bzip2BlockHeader {
     ) -> Result<(), ErrorTrace> {
         self.signature = ((bitstream.get_value(24) as u64) << 24) | bitstream.get_value(24) as u64;
 
-        if self.signature == 0x177245385090 {
+        if self.signature == BZIP2_END_OF_STREAM_SIGNATURE {
             self.checksum = bitstream.get_value(32);
             self.randomized_flag = 0;
             self.origin_pointer = 0;
-        } else if self.signature == 0x314159265359 {
+        } else if self.signature == BZIP2_BLOCK_SIGNATURE {
             self.checksum = bitstream.get_value(32);
             self.randomized_flag = bitstream.get_value(1);
             self.origin_pointer = bitstream.get_value(24);
@@ -234,7 +249,7 @@ impl Bzip2BlockHeader {
             string_parts.push(format!("    signature: 0x{:012x},\n", self.signature));
             string_parts.push(format!("    checksum: 0x{:08x},\n", self.checksum));
 
-            if self.signature == 0x314159265359 {
+            if self.signature == BZIP2_BLOCK_SIGNATURE {
                 string_parts.push(format!("    randomized_flag: {},\n", self.randomized_flag));
                 string_parts.push(format!(
                     "    origin_pointer: 0x{:06x},\n",
@@ -249,6 +264,57 @@ impl Bzip2BlockHeader {
     }
 }
 
+/// Block decoder state used by bzip2 compressed data.
+struct Bzip2BlockState {
+    /// Symbol stack used by the inverse move-to-front transform.
+    symbol_stack: [u8; 256],
+
+    /// Number of symbols.
+    number_of_symbols: usize,
+
+    /// Selectors.
+    selectors: Vec<u8>,
+
+    /// Number of selectors.
+    number_of_selectors: usize,
+
+    /// Huffman trees.
+    huffman_trees: Vec<HuffmanTree>,
+
+    /// Size of the block data decoded so far.
+    block_data_size: usize,
+
+    /// Number of run-length symbols of the current run.
+    number_of_run_length_symbols: u64,
+
+    /// Run-length value of the current run.
+    run_length_value: u64,
+
+    /// Index of the next symbol.
+    symbol_index: usize,
+
+    /// Index of the Huffman tree used to decode the next symbol.
+    tree_index: usize,
+}
+
+impl Bzip2BlockState {
+    /// Creates a new block state.
+    pub fn new() -> Self {
+        Self {
+            symbol_stack: [0; 256],
+            number_of_symbols: 0,
+            selectors: vec![0; 32769], // ( 1 << 15 ) + 1 = 32769
+            number_of_selectors: 0,
+            huffman_trees: Vec::new(),
+            block_data_size: 0,
+            number_of_run_length_symbols: 0,
+            run_length_value: 0,
+            symbol_index: 0,
+            tree_index: 0,
+        }
+    }
+}
+
 /// Context for decompressing bzip2 compressed data.
 pub struct Bzip2Context {
     /// Mediator.
@@ -307,9 +373,10 @@ impl Bzip2Context {
         bitstream: &mut Bzip2Bitstream,
         uncompressed_data: &mut [u8],
     ) -> Result<(), ErrorTrace> {
-        let mut block_data: [u8; BZIP2_BLOCK_SIZE] = [0; BZIP2_BLOCK_SIZE];
-        let mut selectors: [u8; 32769] = [0; 32769]; // ( 1 << 15 ) + 1 = 32769
-        let mut symbol_stack: [u8; 256] = [0; 256];
+        let mut block_data: Vec<u8> = vec![0; BZIP2_MAXIMUM_BLOCK_SIZE];
+        let mut block_state: Bzip2BlockState = Bzip2BlockState::new();
+        let mut block_uncompressed_data: Vec<u8> = Vec::new();
+        let mut calculated_checksum: u32 = 0;
         let mut uncompressed_data_offset: usize = 0;
         let uncompressed_data_size: usize = uncompressed_data.len();
 
@@ -317,67 +384,90 @@ impl Bzip2Context {
         while bitstream.data_offset < bitstream.data_size {
             block_header.read_from_bitstream(bitstream)?;
 
-            if block_header.signature == 0x177245385090 {
+            if block_header.signature == BZIP2_END_OF_STREAM_SIGNATURE {
                 break;
             }
-            if (block_header.origin_pointer as usize) >= BZIP2_BLOCK_SIZE {
-                return Err(keramics_core::error_trace_new!(format!(
-                    "Invalid origin pointer: 0x{:06x} value out of bounds",
-                    block_header.origin_pointer
-                )));
-            }
-            let number_of_symbols: usize = self.read_symbol_stack(bitstream, &mut symbol_stack)?;
-            let number_of_trees: u32 = bitstream.get_value(3);
-            let number_of_selectors: u32 = bitstream.get_value(15);
+            block_uncompressed_data.clear();
 
-            if self.mediator.debug_output {
-                self.mediator.debug_print(format!("Bzip2Bitstream {{\n",));
-                self.mediator
-                    .debug_print(format!("    number_of_symbols: {}\n", number_of_symbols));
-                self.mediator
-                    .debug_print(format!("    number_of_trees: {}\n", number_of_trees));
-                self.mediator.debug_print(format!(
-                    "    number_of_selectors: {}\n",
-                    number_of_selectors
-                ));
-                self.mediator.debug_print(format!("}}\n\n",));
-            }
-            self.read_selectors(
+            let block_checksum: u32 = self.decompress_block(
                 bitstream,
-                &mut selectors,
-                number_of_selectors as usize,
-                number_of_trees as usize,
+                &block_header,
+                &mut block_state,
+                &mut block_data,
+                &mut block_uncompressed_data,
             )?;
-            let mut huffman_trees: Vec<HuffmanTree> = Vec::new();
+            let block_uncompressed_data_size: usize = block_uncompressed_data.len();
 
-            for _ in 0..number_of_trees {
-                let mut huffman_tree: HuffmanTree = HuffmanTree::new(number_of_symbols, 20);
+            if block_uncompressed_data_size > uncompressed_data_size - uncompressed_data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid uncompressed data value too small"
+                ));
+            }
+            let data_end_offset: usize = uncompressed_data_offset + block_uncompressed_data_size;
+            uncompressed_data[uncompressed_data_offset..data_end_offset]
+                .copy_from_slice(&block_uncompressed_data);
 
-                self.read_huffman_tree(bitstream, &mut huffman_tree, number_of_symbols)?;
+            uncompressed_data_offset = data_end_offset;
 
-                huffman_trees.push(huffman_tree);
-            }
-            let block_data_size: usize = self.read_block_data(
-                bitstream,
-                &huffman_trees,
-                number_of_trees as usize,
-                &selectors,
-                number_of_selectors as usize,
-                &mut symbol_stack,
-                number_of_symbols,
-                &mut block_data,
-            )?;
-            self.reverse_burrows_wheeler_transform(
-                &block_data,
-                block_data_size,
-                block_header.origin_pointer,
-                uncompressed_data,
-                &mut uncompressed_data_offset,
-                uncompressed_data_size,
-            )?;
+            calculated_checksum = calculated_checksum.rotate_left(1) ^ block_checksum;
         }
+        if block_header.signature == BZIP2_END_OF_STREAM_SIGNATURE
+            && block_header.checksum != calculated_checksum
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} bzip2 stream checksums",
+                block_header.checksum, calculated_checksum
+            )));
+        }
+        self.uncompressed_data_size = uncompressed_data_offset;
+
+        Ok(())
+    }
+
+    /// Decompress a block from a bitstream.
+    ///
+    /// The uncompressed data of the block is appended to the uncompressed data and the
+    /// checksum of the block is returned.
+    fn decompress_block(
+        &self,
+        bitstream: &mut Bzip2Bitstream,
+        block_header: &Bzip2BlockHeader,
+        block_state: &mut Bzip2BlockState,
+        block_data: &mut [u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<u32, ErrorTrace> {
+        if (block_header.origin_pointer as usize) >= block_data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid origin pointer: 0x{:06x} value out of bounds",
+                block_header.origin_pointer
+            )));
+        }
+        self.read_block_tables(bitstream, block_state)?;
+        self.read_block_data(bitstream, block_state, block_data)?;
+
+        self.finish_block(block_header, block_state, block_data, uncompressed_data)
+    }
+
+    /// Finishes a block of which all block data has been decoded.
+    ///
+    /// The uncompressed data of the block is appended to the uncompressed data and the
+    /// checksum of the block is returned.
+    fn finish_block(
+        &self,
+        block_header: &Bzip2BlockHeader,
+        block_state: &Bzip2BlockState,
+        block_data: &[u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<u32, ErrorTrace> {
+        let uncompressed_data_offset: usize = uncompressed_data.len();
+
+        self.reverse_burrows_wheeler_transform(
+            &block_data[0..block_state.block_data_size],
+            block_header.origin_pointer,
+            uncompressed_data,
+        )?;
         let mut crc32_context: Crc32Context = Crc32Context::new(0x04c11db7, 0);
-        crc32_context.update(&uncompressed_data[0..uncompressed_data_offset]);
+        crc32_context.update(&uncompressed_data[uncompressed_data_offset..]);
         let calculated_checksum: u32 = crc32_context.finalize();
 
         if block_header.checksum != calculated_checksum {
@@ -386,134 +476,203 @@ impl Bzip2Context {
                 block_header.checksum, calculated_checksum
             )));
         }
-        self.uncompressed_data_size = uncompressed_data_offset;
+        Ok(calculated_checksum)
+    }
+
+    /// Reads the symbol stack, selectors and Huffman trees of a block from a bitstream.
+    fn read_block_tables(
+        &self,
+        bitstream: &mut Bzip2Bitstream,
+        block_state: &mut Bzip2BlockState,
+    ) -> Result<(), ErrorTrace> {
+        let number_of_symbols: usize =
+            self.read_symbol_stack(bitstream, &mut block_state.symbol_stack)?;
+        let number_of_trees: u32 = bitstream.get_value(3);
+        let number_of_selectors: u32 = bitstream.get_value(15);
+
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!("Bzip2Bitstream {{\n",));
+            self.mediator
+                .debug_print(format!("    number_of_symbols: {}\n", number_of_symbols));
+            self.mediator
+                .debug_print(format!("    number_of_trees: {}\n", number_of_trees));
+            self.mediator.debug_print(format!(
+                "    number_of_selectors: {}\n",
+                number_of_selectors
+            ));
+            self.mediator.debug_print(format!("}}\n\n",));
+        }
+        self.read_selectors(
+            bitstream,
+            &mut block_state.selectors,
+            number_of_selectors as usize,
+            number_of_trees as usize,
+        )?;
+        block_state.huffman_trees.clear();
+
+        for _ in 0..number_of_trees {
+            let mut huffman_tree: HuffmanTree = HuffmanTree::new(number_of_symbols, 20);
+
+            self.read_huffman_tree(bitstream, &mut huffman_tree, number_of_symbols)?;
+
+            block_state.huffman_trees.push(huffman_tree);
+        }
+        block_state.number_of_symbols = number_of_symbols;
+        block_state.number_of_selectors = number_of_selectors as usize;
+        block_state.block_data_size = 0;
+        block_state.number_of_run_length_symbols = 0;
+        block_state.run_length_value = 0;
+        block_state.symbol_index = 0;
+        block_state.tree_index = block_state.selectors[0] as usize;
 
         Ok(())
     }
 
     /// Reads block data from a bitstream.
+    ///
+    /// Returns the size of the block data.
     fn read_block_data(
         &self,
         bitstream: &mut Bzip2Bitstream,
-        huffman_trees: &[HuffmanTree],
-        number_of_trees: usize,
-        selectors: &[u8],
-        number_of_selectors: usize,
-        symbol_stack: &mut [u8],
-        number_of_symbols: usize,
+        block_state: &mut Bzip2BlockState,
         block_data: &mut [u8],
     ) -> Result<usize, ErrorTrace> {
-        let end_of_block_symbol: u16 = (number_of_symbols - 1) as u16;
-        let mut block_data_offset: usize = 0;
-        let mut number_of_run_length_symbols: u64 = 0;
-        let mut run_length_value: u64 = 0;
-        let mut symbol_index: usize = 0;
-        let mut tree_index: usize = selectors[0] as usize;
-
         if self.mediator.debug_output {
             self.mediator.debug_print(format!("Bzip2BlockData {{\n",));
         }
-        loop {
-            if tree_index >= number_of_trees {
-                return Err(keramics_core::error_trace_new!(format!(
-                    "Invalid tree index: {} value out of bounds",
-                    tree_index
-                )));
+        while !self.read_block_data_symbol(bitstream, block_state, block_data)? {
+            if bitstream.overflow {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
             }
-            let huffman_tree: &HuffmanTree = &huffman_trees[tree_index];
-            let symbol: u16 = huffman_tree.decode_symbol(bitstream)?;
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!("}}\n\n",));
+        }
+        Ok(block_state.block_data_size)
+    }
 
-            if number_of_run_length_symbols != 0 && symbol > 1 {
-                let mut run_length: u64 =
-                    ((1 << number_of_run_length_symbols) | run_length_value) - 1;
+    /// Reads a symbol of the block data from a bitstream.
+    ///
+    /// The block state and data are not changed if the bitstream overflows. Returns true if
+    /// the symbol is the end-of-block symbol.
+    fn read_block_data_symbol(
+        &self,
+        bitstream: &mut Bzip2Bitstream,
+        block_state: &mut Bzip2BlockState,
+        block_data: &mut [u8],
+    ) -> Result<bool, ErrorTrace> {
+        let end_of_block_symbol: u16 = (block_state.number_of_symbols - 1) as u16;
+        let tree_index: usize = block_state.tree_index;
 
-                if self.mediator.debug_output {
-                    self.mediator
-                        .debug_print(format!("    0-byte run-length: {}\n", run_length,));
-                }
-                if (run_length as usize) > BZIP2_BLOCK_SIZE - block_data_offset {
-                    return Err(keramics_core::error_trace_new!(format!(
-                        "Invalid run length: {} value out of bounds",
-                        run_length
-                    )));
-                }
-                number_of_run_length_symbols = 0;
-                run_length_value = 0;
+        if tree_index >= block_state.huffman_trees.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid tree index: {} value out of bounds",
+                tree_index
+            )));
+        }
+        let huffman_tree: &HuffmanTree = &block_state.huffman_trees[tree_index];
+        let symbol: u16 = huffman_tree.decode_symbol(bitstream)?;
 
-                while run_length > 0 {
-                    // Inverse move-to-front transform.
-                    // Note that 0 is already at the front of the stack hence the stack does not need to be reordered.
-                    block_data[block_data_offset] = symbol_stack[0];
+        if bitstream.overflow {
+            return Ok(false);
+        }
+        if block_state.number_of_run_length_symbols != 0 && symbol > 1 {
+            let mut run_length: u64 = ((1 << block_state.number_of_run_length_symbols)
+                | block_state.run_length_value)
+                - 1;
 
-                    block_data_offset += 1;
-                    run_length -= 1;
-                }
+            if self.mediator.debug_output {
+                self.mediator
+                    .debug_print(format!("    0-byte run-length: {}\n", run_length,));
             }
-            if symbol == end_of_block_symbol {
-                if self.mediator.debug_output {
-                    self.mediator
-                        .debug_print(format!("    symbol: {}\n", symbol));
-                }
-                break;
+            if (run_length as usize) > block_data.len() - block_state.block_data_size {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid run length: {} value out of bounds",
+                    run_length
+                )));
             }
-            if symbol == 0 || symbol == 1 {
-                run_length_value |= (symbol as u64) << (number_of_run_length_symbols as u64);
-                number_of_run_length_symbols += 1;
+            block_state.number_of_run_length_symbols = 0;
+            block_state.run_length_value = 0;
 
-                if self.mediator.debug_output {
-                    self.mediator
-                        .debug_print(format!("    symbol: {} (run-length)\n", symbol));
-                }
-            } else if symbol < end_of_block_symbol {
+            while run_length > 0 {
                 // Inverse move-to-front transform.
-                let stack_value_index: usize = (symbol as usize) - 1;
+                // Note that 0 is already at the front of the stack hence the stack does not need to be reordered.
+                block_data[block_state.block_data_size] = block_state.symbol_stack[0];
 
-                let stack_value: u8 = symbol_stack[stack_value_index];
+                block_state.block_data_size += 1;
+                run_length -= 1;
+            }
+        }
+        if symbol == end_of_block_symbol {
+            if self.mediator.debug_output {
+                self.mediator
+                    .debug_print(format!("    symbol: {}\n", symbol));
+            }
+            return Ok(true);
+        }
+        if symbol == 0 || symbol == 1 {
+            // A run-length of 20 or more symbols exceeds the maximum block size.
+            if block_state.number_of_run_length_symbols >= 20 {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid number of run-length symbols: {} value out of bounds",
+                    block_state.number_of_run_length_symbols + 1
+                )));
+            }
+            block_state.run_length_value |=
+                (symbol as u64) << block_state.number_of_run_length_symbols;
+            block_state.number_of_run_length_symbols += 1;
 
-                for stack_index in (0..stack_value_index).rev() {
-                    symbol_stack[stack_index + 1] = symbol_stack[stack_index];
-                }
-                symbol_stack[0] = stack_value;
+            if self.mediator.debug_output {
+                self.mediator
+                    .debug_print(format!("    symbol: {} (run-length)\n", symbol));
+            }
+        } else if symbol < end_of_block_symbol {
+            // Inverse move-to-front transform.
+            let stack_value_index: usize = (symbol as usize) - 1;
 
-                if self.mediator.debug_output {
-                    self.mediator
-                        .debug_print(format!("    symbol: {} (MTF: {})\n", symbol, stack_value));
-                }
-                if block_data_offset >= BZIP2_BLOCK_SIZE {
-                    return Err(keramics_core::error_trace_new!(format!(
-                        "Invalid block data offset: {} value out of bounds",
-                        block_data_offset
-                    )));
-                }
-                block_data[block_data_offset] = stack_value;
+            let stack_value: u8 = block_state.symbol_stack[stack_value_index];
 
-                block_data_offset += 1;
-            } else {
+            for stack_index in (0..stack_value_index).rev() {
+                block_state.symbol_stack[stack_index + 1] = block_state.symbol_stack[stack_index];
+            }
+            block_state.symbol_stack[0] = stack_value;
+
+            if self.mediator.debug_output {
+                self.mediator
+                    .debug_print(format!("    symbol: {} (MTF: {})\n", symbol, stack_value));
+            }
+            if block_state.block_data_size >= block_data.len() {
                 return Err(keramics_core::error_trace_new!(format!(
-                    "Invalid symbol: {} value out of bounds",
-                    symbol
+                    "Invalid block data offset: {} value out of bounds",
+                    block_state.block_data_size
                 )));
             }
-            symbol_index += 1;
+            block_data[block_state.block_data_size] = stack_value;
+
+            block_state.block_data_size += 1;
+        } else {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid symbol: {} value out of bounds",
+                symbol
+            )));
+        }
+        block_state.symbol_index += 1;
 
-            if symbol_index % 50 == 0 {
-                let selector_index: usize = symbol_index / 50;
+        if block_state.symbol_index % 50 == 0 {
+            let selector_index: usize = block_state.symbol_index / 50;
 
-                if selector_index > number_of_selectors {
-                    return Err(keramics_core::error_trace_new!(format!(
-                        "Invalid selector index: {} value out of bounds",
-                        selector_index
-                    )));
-                }
-                tree_index = selectors[selector_index] as usize;
+            if selector_index > block_state.number_of_selectors {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid selector index: {} value out of bounds",
+                    selector_index
+                )));
             }
+            block_state.tree_index = block_state.selectors[selector_index] as usize;
         }
-        if self.mediator.debug_output {
-            self.mediator.debug_print(format!("}}\n\n",));
-        }
-        Ok(block_data_offset)
+        Ok(false)
     }
-
     /// Reads a Huffman tree from a bitstream.
     fn read_huffman_tree(
         &self,
@@ -534,6 +693,10 @@ impl Bzip2Context {
                 let value_32bit: u32 = bitstream.get_value(1);
                 if value_32bit == 0 {
                     code_size += 1;
+                } else if code_size == 0 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid code size: value out of bounds"
+                    ));
                 } else {
                     code_size -= 1;
                 }
@@ -558,7 +721,14 @@ impl Bzip2Context {
 
         for symbol_index in 0..number_of_symbols {
             code_size = code_size_array[symbol_index] as u32;
-            check_value -= 1 << (largest_code_size - code_size);
+            check_value = match check_value.checked_sub(1 << (largest_code_size - code_size)) {
+                Some(value) => value,
+                None => {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid check value: value out of bounds"
+                    ));
+                }
+            };
         }
         if check_value != 0 {
             return Err(keramics_core::error_trace_new!(format!(
@@ -682,23 +852,31 @@ impl Bzip2Context {
         Ok(symbol_index + 2)
     }
 
-    /// Performs a Burrows-Wheeler transform
+    /// Performs a reverse Burrows-Wheeler transform
+    ///
+    /// The uncompressed data is appended to the uncompressed data.
     fn reverse_burrows_wheeler_transform(
         &self,
         block_data: &[u8],
-        block_data_size: usize,
         origin_pointer: u32,
-        uncompressed_data: &mut [u8],
-        uncompressed_data_offset: &mut usize,
-        uncompressed_data_size: usize,
+        uncompressed_data: &mut Vec<u8>,
     ) -> Result<(), ErrorTrace> {
-        let mut data_offset: usize = *uncompressed_data_offset;
+        let block_data_size: usize = block_data.len();
         let mut distribution_value: usize = 0;
         let mut distributions: [usize; 256] = [0; 256];
         let mut last_byte_value: u8 = 0;
         let mut number_of_last_byte_values: u8 = 0;
-        let mut permutations: [usize; BZIP2_BLOCK_SIZE] = [0; BZIP2_BLOCK_SIZE];
+        let mut permutations: Vec<usize> = vec![0; block_data_size];
 
+        if block_data_size == 0 {
+            return Ok(());
+        }
+        if (origin_pointer as usize) >= block_data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid origin pointer: 0x{:06x} value out of bounds",
+                origin_pointer
+            )));
+        }
         for block_data_offset in 0..block_data_size {
             let byte_value: u8 = block_data[block_data_offset];
             distributions[byte_value as usize] += 1;
@@ -720,15 +898,9 @@ impl Bzip2Context {
             let mut byte_value: u8 = block_data[permutation_value];
 
             if number_of_last_byte_values == 4 {
-                if byte_value as usize > uncompressed_data_size - data_offset {
-                    return Err(keramics_core::error_trace_new!(
-                        "Invalid uncompressed data value too small"
-                    ));
-                }
                 while byte_value > 0 {
-                    uncompressed_data[data_offset] = last_byte_value;
+                    uncompressed_data.push(last_byte_value);
 
-                    data_offset += 1;
                     byte_value -= 1;
                 }
                 last_byte_value = 0;
@@ -740,21 +912,254 @@ impl Bzip2Context {
                 last_byte_value = byte_value;
                 number_of_last_byte_values += 1;
 
-                if data_offset >= uncompressed_data_size {
-                    return Err(keramics_core::error_trace_new!(
-                        "Invalid uncompressed data value too small"
-                    ));
+                uncompressed_data.push(byte_value);
+            }
+            permutation_value = permutations[permutation_value];
+        }
+        Ok(())
+    }
+}
+
+/// Decoder state of a bzip2 compressed stream.
+#[derive(Clone, Copy, PartialEq)]
+enum Bzip2StreamState {
+    /// A stream header is expected.
+    StreamHeader,
+
+    /// A block header or end of stream marker is expected.
+    BlockHeader,
+
+    /// The symbol stack, selectors and Huffman trees of a block are expected.
+    BlockTables,
+
+    /// Block data symbols are expected.
+    BlockData,
+
+    /// The end of the compressed stream was reached.
+    EndOfStream,
+}
+
+/// Context for decompressing bzip2 compressed data in chunks.
+pub struct Bzip2StreamContext {
+    /// Context used to decompress the blocks.
+    bzip2_context: Bzip2Context,
+
+    /// Decoder state.
+    state: Bzip2StreamState,
+
+    /// Buffered compressed data.
+    compressed_data: Vec<u8>,
+
+    /// Number of bits of the first byte of the buffered compressed data that have been read.
+    bit_offset: usize,
+
+    /// Header of the current block.
+    block_header: Bzip2BlockHeader,
+
+    /// Decoder state of the current block.
+    block_state: Bzip2BlockState,
+
+    /// Block data.
+    block_data: Vec<u8>,
+
+    /// Calculated stream checksum.
+    calculated_checksum: u32,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: u64,
+}
+
+impl Bzip2StreamContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            bzip2_context: Bzip2Context::new(),
+            state: Bzip2StreamState::StreamHeader,
+            compressed_data: Vec::new(),
+            bit_offset: 0,
+            block_header: Bzip2BlockHeader::new(),
+            block_state: Bzip2BlockState::new(),
+            block_data: Vec::new(),
+            calculated_checksum: 0,
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Reads the stream header.
+    ///
+    /// Returns the block size.
+    fn read_stream_header(data: &[u8]) -> Result<usize, ErrorTrace> {
+        let mut stream_header: Bzip2StreamHeader = Bzip2StreamHeader::new();
+        stream_header.read_data(data)?;
+
+        Ok(((data[3] - 0x30) as usize) * BZIP2_BLOCK_SIZE)
+    }
+}
+
+impl StreamDecompressor for Bzip2StreamContext {
+    /// Decompresses a chunk of compressed data.
+    fn decompress_chunk(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        self.compressed_data.extend_from_slice(compressed_data);
+
+        let uncompressed_data_offset: usize = uncompressed_data.len();
+        let mut bit_offset: usize = self.bit_offset;
+
+        let mut bitstream: Bzip2Bitstream = Bzip2Bitstream::new(&self.compressed_data, 0);
+
+        if bit_offset > 0 {
+            bitstream.skip_bits(bit_offset);
+        }
+        // The decoder state is kept between chunks. A block header, the tables of a block or
+        // a block data symbol is only consumed when all of its compressed data is available,
+        // otherwise the bitstream is rewound to the bit offset that precedes it.
+        loop {
+            match self.state {
+                Bzip2StreamState::StreamHeader => {
+                    // The stream header starts at a byte boundary.
+                    let data_offset: usize = bit_offset / 8;
+
+                    if self.compressed_data.len() - data_offset < 4 {
+                        break;
+                    }
+                    let block_size: usize =
+                        match Self::read_stream_header(&self.compressed_data[data_offset..]) {
+                            Ok(block_size) => block_size,
+                            Err(mut error) => {
+                                keramics_core::error_trace_add_frame!(
+                                    error,
+                                    "Unable to read stream header"
+                                );
+                                return Err(error);
+                            }
+                        };
+                    self.block_data.resize(block_size, 0);
+                    self.calculated_checksum = 0;
+
+                    bitstream = Bzip2Bitstream::new(&self.compressed_data, data_offset + 4);
+
+                    self.state = Bzip2StreamState::BlockHeader;
                 }
-                uncompressed_data[data_offset] = byte_value;
+                Bzip2StreamState::BlockHeader => {
+                    let result: Result<(), ErrorTrace> =
+                        self.block_header.read_from_bitstream(&mut bitstream);
 
-                data_offset += 1;
+                    if bitstream.overflow {
+                        break;
+                    }
+                    if let Err(mut error) = result {
+                        keramics_core::error_trace_add_frame!(error, "Unable to read block header");
+                        return Err(error);
+                    }
+                    if self.block_header.signature == BZIP2_END_OF_STREAM_SIGNATURE {
+                        if self.block_header.checksum != self.calculated_checksum {
+                            return Err(keramics_core::error_trace_new!(format!(
+                                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} bzip2 stream checksums",
+                                self.block_header.checksum, self.calculated_checksum
+                            )));
+                        }
+                        self.state = Bzip2StreamState::EndOfStream;
+                    } else {
+                        if (self.block_header.origin_pointer as usize) >= self.block_data.len() {
+                            return Err(keramics_core::error_trace_new!(format!(
+                                "Invalid origin pointer: 0x{:06x} value out of bounds",
+                                self.block_header.origin_pointer
+                            )));
+                        }
+                        self.state = Bzip2StreamState::BlockTables;
+                    }
+                }
+                Bzip2StreamState::BlockTables => {
+                    let result: Result<(), ErrorTrace> = self
+                        .bzip2_context
+                        .read_block_tables(&mut bitstream, &mut self.block_state);
+
+                    if bitstream.overflow {
+                        break;
+                    }
+                    if let Err(mut error) = result {
+                        keramics_core::error_trace_add_frame!(error, "Unable to read block tables");
+                        return Err(error);
+                    }
+                    self.state = Bzip2StreamState::BlockData;
+                }
+                Bzip2StreamState::BlockData => {
+                    let result: Result<bool, ErrorTrace> =
+                        self.bzip2_context.read_block_data_symbol(
+                            &mut bitstream,
+                            &mut self.block_state,
+                            &mut self.block_data,
+                        );
+                    if bitstream.overflow {
+                        break;
+                    }
+                    match result {
+                        Ok(false) => {}
+                        Ok(true) => {
+                            let block_checksum: u32 = match self.bzip2_context.finish_block(
+                                &self.block_header,
+                                &self.block_state,
+                                &self.block_data,
+                                uncompressed_data,
+                            ) {
+                                Ok(block_checksum) => block_checksum,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to decompress block"
+                                    );
+                                    return Err(error);
+                                }
+                            };
+                            self.calculated_checksum =
+                                self.calculated_checksum.rotate_left(1) ^ block_checksum;
+
+                            self.state = Bzip2StreamState::BlockHeader;
+                        }
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to read block data"
+                            );
+                            return Err(error);
+                        }
+                    }
+                }
+                Bzip2StreamState::EndOfStream => {
+                    // The compressed stream ends at the next byte boundary.
+                    bit_offset = bit_offset.div_ceil(8) * 8;
+
+                    let data_offset: usize = bit_offset / 8;
+
+                    // Data that follows the end of the stream is ignored unless it contains
+                    // another concatenated bzip2 stream.
+                    if self.compressed_data.len() - data_offset < 3
+                        || self.compressed_data[data_offset..data_offset + 3] != *b"BZh"
+                    {
+                        break;
+                    }
+                    self.state = Bzip2StreamState::StreamHeader;
+
+                    continue;
+                }
             }
-            permutation_value = permutations[permutation_value];
+            bit_offset = (bitstream.data_offset * 8) - bitstream.number_of_bits;
         }
-        *uncompressed_data_offset = data_offset;
+        self.uncompressed_data_size += (uncompressed_data.len() - uncompressed_data_offset) as u64;
+
+        self.compressed_data.drain(0..bit_offset / 8);
+        self.bit_offset = bit_offset % 8;
 
         Ok(())
     }
+
+    /// Determines if the end of the compressed stream was reached.
+    fn is_end_of_stream(&self) -> bool {
+        self.state == Bzip2StreamState::EndOfStream
+    }
 }
 
 #[cfg(test)]
@@ -932,39 +1337,12 @@ mod tests {
         let mut bitstream: Bzip2Bitstream = Bzip2Bitstream::new(&test_data, 4);
         block_header.read_from_bitstream(&mut bitstream)?;
 
-        let mut symbol_stack: [u8; 256] = [0; 256];
-        let number_of_symbols: usize =
-            test_context.read_symbol_stack(&mut bitstream, &mut symbol_stack)?;
-        let number_of_trees: u32 = bitstream.get_value(3);
-        let number_of_selectors: u32 = bitstream.get_value(15);
-
-        let mut selectors: [u8; 32769] = [0; 32769];
-        test_context.read_selectors(
-            &mut bitstream,
-            &mut selectors,
-            number_of_selectors as usize,
-            number_of_trees as usize,
-        )?;
-        let mut huffman_trees: Vec<HuffmanTree> = Vec::new();
-
-        for _ in 0..number_of_trees {
-            let mut huffman_tree: HuffmanTree = HuffmanTree::new(number_of_symbols, 20);
-
-            test_context.read_huffman_tree(&mut bitstream, &mut huffman_tree, number_of_symbols)?;
+        let mut block_state: Bzip2BlockState = Bzip2BlockState::new();
+        test_context.read_block_tables(&mut bitstream, &mut block_state)?;
 
-            huffman_trees.push(huffman_tree);
-        }
         let mut block_data: [u8; BZIP2_BLOCK_SIZE] = [0; BZIP2_BLOCK_SIZE];
-        let block_data_size: usize = test_context.read_block_data(
-            &mut bitstream,
-            &huffman_trees,
-            number_of_trees as usize,
-            &selectors,
-            number_of_selectors as usize,
-            &mut symbol_stack,
-            number_of_symbols,
-            &mut block_data,
-        )?;
+        let block_data_size: usize =
+            test_context.read_block_data(&mut bitstream, &mut block_state, &mut block_data)?;
         let expected_block_data: [u8; 108] = [
             0x3f, 0x66, 0x73, 0x72, 0x72, 0x64, 0x6b, 0x6b, 0x65, 0x61, 0x64, 0x64, 0x72, 0x72,
             0x66, 0x66, 0x73, 0x2c, 0x65, 0x73, 0x3f, 0x3f, 0x3f, 0x64, 0x01, 0x20, 0x20, 0x20,
@@ -990,22 +1368,14 @@ mod tests {
             0x72, 0x74, 0x73, 0x73, 0x73, 0x65, 0x65, 0x6c, 0x6c, 0x68, 0x6f, 0x6c, 0x6c, 0x20,
             0x20, 0x20, 0x65, 0x61, 0x61, 0x20, 0x62,
         ];
-        let mut uncompressed_data: [u8; 35] = [0; 35];
-        let mut uncompressed_data_offset: usize = 0;
-        test_context.reverse_burrows_wheeler_transform(
-            &block_data,
-            35,
-            30,
-            &mut uncompressed_data,
-            &mut uncompressed_data_offset,
-            35,
-        )?;
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+        test_context.reverse_burrows_wheeler_transform(&block_data, 30, &mut uncompressed_data)?;
         let expected_uncompressed_data: [u8; 35] = [
             0x73, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6c, 0x6c, 0x73, 0x20, 0x73, 0x65, 0x61, 0x73,
             0x68, 0x65, 0x6c, 0x6c, 0x73, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
             0x65, 0x61, 0x73, 0x68, 0x6f, 0x72, 0x65,
         ];
-        assert_eq!(uncompressed_data_offset, 35);
+        assert_eq!(uncompressed_data.len(), 35);
         assert_eq!(&uncompressed_data, &expected_uncompressed_data);
 
         Ok(())
@@ -1130,4 +1500,83 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_block_data_with_truncated_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let test_context: Bzip2Context = Bzip2Context::new();
+
+        let mut block_header: Bzip2BlockHeader = Bzip2BlockHeader::new();
+        let mut bitstream: Bzip2Bitstream = Bzip2Bitstream::new(&test_data[0..96], 4);
+        block_header.read_from_bitstream(&mut bitstream)?;
+
+        let mut block_state: Bzip2BlockState = Bzip2BlockState::new();
+        test_context.read_block_tables(&mut bitstream, &mut block_state)?;
+
+        let mut block_data: [u8; BZIP2_BLOCK_SIZE] = [0; BZIP2_BLOCK_SIZE];
+        let result: Result<usize, ErrorTrace> =
+            test_context.read_block_data(&mut bitstream, &mut block_state, &mut block_data);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_decompress_chunk() -> Result<(), ErrorTrace> {
+        let mut test_context: Bzip2StreamContext = Bzip2StreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in test_data.chunks(7) {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 108);
+
+        let expected_uncompressed_data: [u8; 108] = [
+            0x49, 0x66, 0x20, 0x50, 0x65, 0x74, 0x65, 0x72, 0x20, 0x50, 0x69, 0x70, 0x65, 0x72,
+            0x20, 0x70, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x61, 0x20, 0x70, 0x65, 0x63, 0x6b,
+            0x20, 0x6f, 0x66, 0x20, 0x70, 0x69, 0x63, 0x6b, 0x6c, 0x65, 0x64, 0x20, 0x70, 0x65,
+            0x70, 0x70, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x77, 0x68, 0x65, 0x72, 0x65, 0x27, 0x73,
+            0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x65, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x70,
+            0x69, 0x63, 0x6b, 0x6c, 0x65, 0x64, 0x20, 0x70, 0x65, 0x70, 0x70, 0x65, 0x72, 0x73,
+            0x20, 0x50, 0x65, 0x74, 0x65, 0x72, 0x20, 0x50, 0x69, 0x70, 0x65, 0x72, 0x20, 0x70,
+            0x69, 0x63, 0x6b, 0x65, 0x64, 0x3f, 0x3f, 0x3f, 0x3f, 0x3f,
+        ];
+        assert_eq!(&uncompressed_data, &expected_uncompressed_data);
+
+        // Concatenated streams are decompressed as a single stream.
+        test_context.decompress_chunk(&test_data, &mut uncompressed_data)?;
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 216);
+        assert_eq!(&uncompressed_data[108..], &expected_uncompressed_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_decompress_chunk_with_single_bytes() -> Result<(), ErrorTrace> {
+        let mut test_context: Bzip2StreamContext = Bzip2StreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in test_data.chunks(1) {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+
+            // Compressed data is only buffered until it can be decoded.
+            assert!(test_context.compressed_data.len() < 64);
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 108);
+        assert_eq!(&uncompressed_data[0..17], b"If Peter Piper pi");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,122 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+
+use super::deflate::DeflateCompressionContext;
+use super::enums::CompressionMethod;
+use super::lznt1::Lznt1CompressionContext;
+use super::traits::Compressor;
+use super::zlib::ZlibCompressionContext;
+
+impl Compressor for DeflateCompressionContext {
+    /// Compress data.
+    fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        DeflateCompressionContext::compress(self, uncompressed_data, compressed_data)
+    }
+}
+
+impl Compressor for Lznt1CompressionContext {
+    /// Compress data.
+    fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        Lznt1CompressionContext::compress(self, uncompressed_data, compressed_data)
+    }
+}
+
+impl Compressor for ZlibCompressionContext {
+    /// Compress data.
+    fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        ZlibCompressionContext::compress(self, uncompressed_data, compressed_data)
+    }
+}
+
+/// Creates a new compressor.
+///
+/// Unlike [`new_decompressor`](crate::new_decompressor) this returns an error, since only a
+/// subset of the compression methods can be used to compress data.
+pub fn new_compressor(
+    compression_method: &CompressionMethod,
+) -> Result<Box<dyn Compressor>, ErrorTrace> {
+    match compression_method {
+        CompressionMethod::Deflate => Ok(Box::new(DeflateCompressionContext::new())),
+        CompressionMethod::Lznt1 => Ok(Box::new(Lznt1CompressionContext::new())),
+        CompressionMethod::Zlib => Ok(Box::new(ZlibCompressionContext::new())),
+        _ => Err(keramics_core::error_trace_new!(format!(
+            "Unsupported compression method: {:?}",
+            compression_method
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::decompressor::new_decompressor;
+    use crate::traits::Decompressor;
+
+    #[test]
+    fn test_new_compressor() {
+        let compression_methods: Vec<CompressionMethod> = vec![
+            CompressionMethod::Deflate,
+            CompressionMethod::Lznt1,
+            CompressionMethod::Zlib,
+        ];
+        for compression_method in compression_methods {
+            let result: Result<Box<dyn Compressor>, ErrorTrace> =
+                new_compressor(&compression_method);
+            assert!(result.is_ok());
+        }
+        let result: Result<Box<dyn Compressor>, ErrorTrace> =
+            new_compressor(&CompressionMethod::Adc);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_compress_and_decompress() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = b"Keramics test data ".repeat(512);
+
+        let compression_methods: Vec<CompressionMethod> = vec![
+            CompressionMethod::Deflate,
+            CompressionMethod::Lznt1,
+            CompressionMethod::Zlib,
+        ];
+        for compression_method in compression_methods {
+            let mut compressor: Box<dyn Compressor> = new_compressor(&compression_method)?;
+
+            let mut compressed_data: Vec<u8> = Vec::new();
+            compressor.compress(&test_data, &mut compressed_data)?;
+            assert!(compressed_data.len() < test_data.len());
+
+            let mut decompressor: Box<dyn Decompressor> = new_decompressor(&compression_method);
+
+            let mut uncompressed_data: Vec<u8> = vec![0; test_data.len()];
+            decompressor.decompress(&compressed_data, &mut uncompressed_data)?;
+            assert_eq!(decompressor.get_uncompressed_data_size(), test_data.len());
+            assert_eq!(uncompressed_data, test_data);
+        }
+        Ok(())
+    }
+}
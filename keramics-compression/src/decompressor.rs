@@ -0,0 +1,358 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+
+use super::adc::AdcContext;
+use super::bzip2::{Bzip2Context, Bzip2StreamContext};
+use super::deflate::{DeflateContext, DeflateStreamContext};
+use super::enums::CompressionMethod;
+use super::lzfse::LzfseContext;
+use super::lzma::LzmaContext;
+use super::lzma2::Lzma2Context;
+use super::lznt1::Lznt1Context;
+use super::lzvn::LzvnContext;
+use super::lzx::LzxContext;
+use super::lzxpress::{LzxpressContext, LzxpressHuffmanContext};
+use super::traits::{Decompressor, StreamDecompressor};
+use super::xz::XzContext;
+use super::zlib::{ZlibContext, ZlibStreamContext};
+use super::zstd::ZstdContext;
+
+impl Decompressor for AdcContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        AdcContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for Bzip2Context {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        Bzip2Context::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for DeflateContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        DeflateContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzfseContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzfseContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzmaContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzmaContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for Lzma2Context {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        Lzma2Context::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for Lznt1Context {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        Lznt1Context::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzvnContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzvnContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzxContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzxContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzxpressContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzxpressContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for LzxpressHuffmanContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        LzxpressHuffmanContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for XzContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        XzContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for ZlibContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        ZlibContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+impl Decompressor for ZstdContext {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        ZstdContext::decompress(self, compressed_data, uncompressed_data)
+    }
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize {
+        self.uncompressed_data_size
+    }
+}
+
+/// Creates a new decompressor.
+///
+/// Every compression method can be decompressed, hence unlike
+/// [`new_compressor`](crate::new_compressor) this cannot fail.
+pub fn new_decompressor(compression_method: &CompressionMethod) -> Box<dyn Decompressor> {
+    match compression_method {
+        CompressionMethod::Adc => Box::new(AdcContext::new()),
+        CompressionMethod::Bzip2 => Box::new(Bzip2Context::new()),
+        CompressionMethod::Deflate => Box::new(DeflateContext::new()),
+        CompressionMethod::Lzfse => Box::new(LzfseContext::new()),
+        CompressionMethod::Lzma => Box::new(LzmaContext::new()),
+        CompressionMethod::Lzma2 => Box::new(Lzma2Context::new()),
+        CompressionMethod::Lznt1 => Box::new(Lznt1Context::new()),
+        CompressionMethod::Lzvn => Box::new(LzvnContext::new()),
+        CompressionMethod::Lzx => Box::new(LzxContext::new()),
+        CompressionMethod::Lzxpress => Box::new(LzxpressContext::new()),
+        CompressionMethod::LzxpressHuffman => Box::new(LzxpressHuffmanContext::new()),
+        CompressionMethod::Xz => Box::new(XzContext::new()),
+        CompressionMethod::Zlib => Box::new(ZlibContext::new()),
+        CompressionMethod::Zstd => Box::new(ZstdContext::new()),
+    }
+}
+
+/// Creates a new stream decompressor.
+pub fn new_stream_decompressor(
+    compression_method: &CompressionMethod,
+) -> Result<Box<dyn StreamDecompressor>, ErrorTrace> {
+    match compression_method {
+        CompressionMethod::Bzip2 => Ok(Box::new(Bzip2StreamContext::new())),
+        CompressionMethod::Deflate => Ok(Box::new(DeflateStreamContext::new())),
+        CompressionMethod::Zlib => Ok(Box::new(ZlibStreamContext::new())),
+        _ => Err(keramics_core::error_trace_new!(format!(
+            "Unsupported stream compression method: {:?}",
+            compression_method
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_decompressor() {
+        let compression_methods: Vec<CompressionMethod> = vec![
+            CompressionMethod::Adc,
+            CompressionMethod::Bzip2,
+            CompressionMethod::Deflate,
+            CompressionMethod::Lzfse,
+            CompressionMethod::Lzma,
+            CompressionMethod::Lzma2,
+            CompressionMethod::Lznt1,
+            CompressionMethod::Lzvn,
+            CompressionMethod::Lzx,
+            CompressionMethod::Lzxpress,
+            CompressionMethod::LzxpressHuffman,
+            CompressionMethod::Xz,
+            CompressionMethod::Zlib,
+            CompressionMethod::Zstd,
+        ];
+        for compression_method in compression_methods {
+            let decompressor: Box<dyn Decompressor> = new_decompressor(&compression_method);
+            assert_eq!(decompressor.get_uncompressed_data_size(), 0);
+        }
+    }
+
+    #[test]
+    fn test_decompress() -> Result<(), ErrorTrace> {
+        let test_data: [u8; 10] = [0x83, 0xfe, 0xed, 0xfa, 0xce, 0x00, 0x00, 0x40, 0x00, 0x06];
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&CompressionMethod::Adc);
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 11];
+        decompressor.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(decompressor.get_uncompressed_data_size(), 11);
+
+        let expected_data: [u8; 11] = [
+            0xfe, 0xed, 0xfa, 0xce, 0xce, 0xce, 0xce, 0xfe, 0xed, 0xfa, 0xce,
+        ];
+        assert_eq!(uncompressed_data, expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_stream_decompressor() {
+        let compression_methods: Vec<CompressionMethod> = vec![
+            CompressionMethod::Bzip2,
+            CompressionMethod::Deflate,
+            CompressionMethod::Zlib,
+        ];
+        for compression_method in compression_methods {
+            let result: Result<Box<dyn StreamDecompressor>, ErrorTrace> =
+                new_stream_decompressor(&compression_method);
+            assert!(result.is_ok());
+
+            let stream_decompressor: Box<dyn StreamDecompressor> = result.unwrap();
+            assert!(!stream_decompressor.is_end_of_stream());
+        }
+        let result: Result<Box<dyn StreamDecompressor>, ErrorTrace> =
+            new_stream_decompressor(&CompressionMethod::Adc);
+        assert!(result.is_err());
+    }
+}
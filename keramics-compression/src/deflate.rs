@@ -11,15 +11,18 @@
  * under the License.
  */
 
-//! DEFLATE decompression.
+//! DEFLATE compression and decompression.
 //!
-//! Provides decompression support for DEFLATE compressed data (RFC 1951).
+//! Provides compression and decompression support for DEFLATE compressed data (RFC 1951).
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
 use keramics_core::ErrorTrace;
 use keramics_core::mediator::Mediator;
 
 use super::huffman::HuffmanTree;
-use super::traits::Bitstream;
+use super::traits::{Bitstream, StreamDecompressor};
 
 /// Uncompressed DEFLATE block type.
 const DEFLATE_BLOCK_TYPE_UNCOMPRESED: u32 = 0;
@@ -52,6 +55,27 @@ const DEFLATE_DISTANCE_CODES_BASE: [u16; 30] = [
     2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
 ];
 
+/// Maximum size of the DEFLATE sliding window.
+const DEFLATE_WINDOW_SIZE: usize = 32768;
+
+/// Minimum size of a DEFLATE match.
+const DEFLATE_MINIMUM_MATCH_SIZE: usize = 3;
+
+/// Maximum size of a DEFLATE match.
+const DEFLATE_MAXIMUM_MATCH_SIZE: usize = 258;
+
+/// Maximum uncompressed data size of a block written by the compressor.
+const DEFLATE_MAXIMUM_BLOCK_SIZE: usize = 65535;
+
+/// Maximum number of tokens of a block written by the compressor.
+const DEFLATE_MAXIMUM_NUMBER_OF_TOKENS: usize = 16384;
+
+/// Number of bits of the hash used by the compressor to find matches.
+const DEFLATE_HASH_BITS: usize = 15;
+
+/// Maximum number of match candidates tested by the compressor per position.
+const DEFLATE_MAXIMUM_CHAIN_LENGTH: usize = 128;
+
 /// Number of extra bits of distance codes for decoding Huffman encoded blocks.
 const DEFLATE_DISTANCE_CODES_NUMBER_OF_EXTRA_BITS: [u16; 30] = [
     0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
@@ -74,6 +98,9 @@ pub(super) struct DeflateBitstream<'a> {
 
     /// Number of bits in the bits buffer.
     pub number_of_bits: usize,
+
+    /// Value to indicate the bits buffer was filled beyond the end of the byte stream.
+    pub overflow: bool,
 }
 
 impl<'a> DeflateBitstream<'a> {
@@ -86,6 +113,7 @@ impl<'a> DeflateBitstream<'a> {
             data_size: data_size,
             bits: 0,
             number_of_bits: 0,
+            overflow: false,
         }
     }
 
@@ -131,6 +159,8 @@ impl<'a> DeflateBitstream<'a> {
             if self.data_offset < self.data_size {
                 self.bits |= (self.data[self.data_offset] as u32) << self.number_of_bits;
                 self.data_offset += 1;
+            } else {
+                self.overflow = true;
             }
             self.number_of_bits += 8;
         }
@@ -633,6 +663,1102 @@ impl DeflateContext {
     }
 }
 
+/// Decoder state of a DEFLATE compressed stream.
+#[derive(Clone, Copy, PartialEq)]
+enum DeflateStreamState {
+    /// A block header is expected.
+    BlockHeader,
+
+    /// Uncompressed block with the number of bytes that remain to be copied.
+    UncompressedBlock(usize),
+
+    /// Huffman encoded block that uses the fixed Huffman trees.
+    FixedHuffmanBlock,
+
+    /// Huffman encoded block that uses the dynamic Huffman trees.
+    DynamicHuffmanBlock,
+
+    /// The end of the compressed stream was reached.
+    EndOfStream,
+}
+
+/// Block boundary in DEFLATE compressed data.
+///
+/// A block boundary contains the state needed to resume decompression at the start of a block.
+pub struct DeflateBlockBoundary {
+    /// Offset of the compressed data relative to the start of the compressed data provided to
+    /// the context.
+    pub compressed_data_offset: u64,
+
+    /// Number of bits of the first byte of the compressed data that precede the block.
+    pub bit_offset: usize,
+
+    /// Offset of the uncompressed data relative to the start of the uncompressed data produced
+    /// by the context.
+    pub uncompressed_data_offset: u64,
+
+    /// Sliding window with the uncompressed data that precedes the block.
+    pub window: Vec<u8>,
+}
+
+/// Context for decompressing DEFLATE compressed data in chunks.
+pub struct DeflateStreamContext {
+    /// Context used to build the Huffman trees.
+    deflate_context: DeflateContext,
+
+    /// Dynamic Huffman tree for decoding literals of the current block.
+    dynamic_literals_huffman_tree: HuffmanTree,
+
+    /// Dynamic Huffman tree for decoding distances of the current block.
+    dynamic_distances_huffman_tree: HuffmanTree,
+
+    /// Decoder state.
+    state: DeflateStreamState,
+
+    /// Value to indicate the current block is the last block.
+    last_block: bool,
+
+    /// Buffered compressed data.
+    compressed_data: Vec<u8>,
+
+    /// Offset of the buffered compressed data relative to the start of the compressed data
+    /// provided to the context.
+    compressed_data_base_offset: u64,
+
+    /// Offset of the next unread byte in the buffered compressed data.
+    compressed_data_offset: usize,
+
+    /// Number of bits of the next unread byte that have been read.
+    bit_offset: usize,
+
+    /// Sliding window with the most recently uncompressed data.
+    window: Vec<u8>,
+
+    /// Minimum distance of uncompressed data between recorded block boundaries, where 0
+    /// disables recording block boundaries.
+    block_boundary_interval: u64,
+
+    /// Uncompressed data offset of the most recently recorded block boundary.
+    block_boundary_uncompressed_data_offset: u64,
+
+    /// Recorded block boundaries.
+    block_boundaries: Vec<DeflateBlockBoundary>,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: u64,
+}
+
+impl DeflateStreamContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            deflate_context: DeflateContext::new(),
+            dynamic_literals_huffman_tree: HuffmanTree::new(288, 15),
+            dynamic_distances_huffman_tree: HuffmanTree::new(30, 15),
+            state: DeflateStreamState::BlockHeader,
+            last_block: false,
+            compressed_data: Vec::new(),
+            compressed_data_base_offset: 0,
+            compressed_data_offset: 0,
+            bit_offset: 0,
+            window: Vec::new(),
+            block_boundary_interval: 0,
+            block_boundary_uncompressed_data_offset: 0,
+            block_boundaries: Vec::new(),
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Creates a new context that resumes decompression at a block boundary.
+    ///
+    /// The bit offset is the number of bits of the first byte of the compressed data that
+    /// precede the block and the window contains the most recently uncompressed data.
+    pub fn new_at_block_boundary(bit_offset: usize, window: &[u8]) -> Self {
+        let window_offset: usize = window.len().saturating_sub(DEFLATE_WINDOW_SIZE);
+
+        let mut stream_context: Self = Self::new();
+        stream_context.bit_offset = bit_offset % 8;
+        stream_context.window = window[window_offset..].to_vec();
+
+        stream_context
+    }
+
+    /// Sets the minimum distance of uncompressed data between recorded block boundaries.
+    ///
+    /// An interval of 0 disables recording block boundaries.
+    pub fn set_block_boundary_interval(&mut self, block_boundary_interval: u64) {
+        self.block_boundary_interval = block_boundary_interval;
+    }
+
+    /// Takes the block boundaries that were recorded since the previous call.
+    pub fn take_block_boundaries(&mut self) -> Vec<DeflateBlockBoundary> {
+        std::mem::take(&mut self.block_boundaries)
+    }
+
+    /// Retrieves the buffered compressed data that follows the end of the compressed stream.
+    pub fn get_trailing_data(&self) -> &[u8] {
+        if self.state != DeflateStreamState::EndOfStream {
+            return &[];
+        }
+        &self.compressed_data[self.compressed_data_offset..]
+    }
+
+    /// Reads a block header and, for a dynamic Huffman encoded block, its Huffman trees.
+    ///
+    /// Returns the decoder state of the block and a value to indicate the block is the last
+    /// block.
+    fn read_block_header(
+        deflate_context: &mut DeflateContext,
+        bitstream: &mut DeflateBitstream,
+        dynamic_literals_huffman_tree: &mut HuffmanTree,
+        dynamic_distances_huffman_tree: &mut HuffmanTree,
+    ) -> Result<(DeflateStreamState, bool), ErrorTrace> {
+        let mut block_header: DeflateBlockHeader = DeflateBlockHeader::new();
+
+        match block_header.read_from_bitstream(bitstream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read block header");
+                return Err(error);
+            }
+        }
+        let state: DeflateStreamState = match block_header.block_type {
+            DEFLATE_BLOCK_TYPE_UNCOMPRESED => {
+                // Ignore the bits in the buffer upto the next byte.
+                let skip_bits: usize = bitstream.number_of_bits & 0x07;
+
+                if skip_bits > 0 {
+                    bitstream.skip_bits(skip_bits);
+                }
+                let value_32bit: u32 = bitstream.get_value(32);
+                let block_size: usize = (value_32bit & 0x0000ffff) as usize;
+                let block_size_copy: usize = ((value_32bit >> 16) ^ 0xffff) as usize;
+
+                if block_size != block_size_copy {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Mismatch in uncompressed block size: {} and copy: {}",
+                        block_size, block_size_copy
+                    )));
+                }
+                DeflateStreamState::UncompressedBlock(block_size)
+            }
+            DEFLATE_BLOCK_TYPE_HUFFMAN_FIXED => {
+                if !deflate_context.build_fixed_huffman_trees {
+                    match deflate_context.build_fixed_huffman_trees() {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to build fixed Huffman trees"
+                            );
+                            return Err(error);
+                        }
+                    }
+                }
+                DeflateStreamState::FixedHuffmanBlock
+            }
+            DEFLATE_BLOCK_TYPE_HUFFMAN_DYNAMIC => {
+                match deflate_context.build_dynamic_huffman_trees(
+                    bitstream,
+                    dynamic_literals_huffman_tree,
+                    dynamic_distances_huffman_tree,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to build dynamic Huffman trees"
+                        );
+                        return Err(error);
+                    }
+                }
+                DeflateStreamState::DynamicHuffmanBlock
+            }
+            _ => {
+                return Err(keramics_core::error_trace_new!("Unsupported block type"));
+            }
+        };
+        Ok((state, block_header.last_block_flag != 0))
+    }
+
+    /// Decodes a Huffman encoded symbol.
+    ///
+    /// The uncompressed data of the symbol is appended to the sliding window and the
+    /// uncompressed data, unless the compressed data of the symbol is not fully available.
+    /// Returns true if the symbol is the end-of-block symbol.
+    fn decode_huffman_encoded_symbol(
+        bitstream: &mut DeflateBitstream,
+        literals_huffman_tree: &HuffmanTree,
+        distances_huffman_tree: &HuffmanTree,
+        window: &mut Vec<u8>,
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<bool, ErrorTrace> {
+        let mut symbol: u16 = match literals_huffman_tree.decode_symbol(bitstream) {
+            Ok(symbol) => symbol,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to decode literal symbol");
+                return Err(error);
+            }
+        };
+        if bitstream.overflow {
+            return Ok(false);
+        }
+        if symbol == 256 {
+            return Ok(true);
+        }
+        if symbol < 256 {
+            window.push(symbol as u8);
+            uncompressed_data.push(symbol as u8);
+
+            return Ok(false);
+        }
+        if symbol >= 286 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid symbol: {}",
+                symbol
+            )));
+        }
+        symbol -= 257;
+
+        let number_of_extra_bits: u16 = DEFLATE_LITERAL_CODES_NUMBER_OF_EXTRA_BITS[symbol as usize];
+        let extra_bits: u32 = bitstream.get_value(number_of_extra_bits as usize);
+
+        let compression_size: usize =
+            ((DEFLATE_LITERAL_CODES_BASE[symbol as usize] as u32) + extra_bits) as usize;
+
+        symbol = match distances_huffman_tree.decode_symbol(bitstream) {
+            Ok(symbol) => symbol,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to decode distance symbol");
+                return Err(error);
+            }
+        };
+        if symbol >= 30 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid distance symbol: {}",
+                symbol
+            )));
+        }
+        let number_of_extra_bits: u16 =
+            DEFLATE_DISTANCE_CODES_NUMBER_OF_EXTRA_BITS[symbol as usize];
+        let extra_bits: u32 = bitstream.get_value(number_of_extra_bits as usize);
+
+        if bitstream.overflow {
+            return Ok(false);
+        }
+        let compression_offset: usize =
+            ((DEFLATE_DISTANCE_CODES_BASE[symbol as usize] as u32) + extra_bits) as usize;
+
+        let window_size: usize = window.len();
+
+        if compression_offset > window_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid compression offset: {} value out of bounds",
+                compression_offset
+            )));
+        }
+        let mut compression_data_offset: usize = window_size - compression_offset;
+
+        for _ in 0..compression_size {
+            let byte_value: u8 = window[compression_data_offset];
+            window.push(byte_value);
+
+            compression_data_offset += 1;
+        }
+        uncompressed_data.extend_from_slice(&window[window_size..]);
+
+        Ok(false)
+    }
+}
+
+impl StreamDecompressor for DeflateStreamContext {
+    /// Decompresses a chunk of compressed data.
+    fn decompress_chunk(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        self.compressed_data.extend_from_slice(compressed_data);
+
+        if self.state == DeflateStreamState::EndOfStream {
+            return Ok(());
+        }
+        let uncompressed_data_offset: usize = uncompressed_data.len();
+        let mut bit_offset: usize = (self.compressed_data_offset * 8) + self.bit_offset;
+
+        let mut bitstream: DeflateBitstream =
+            DeflateBitstream::new(&self.compressed_data, self.compressed_data_offset);
+
+        if self.bit_offset > 0 {
+            bitstream.skip_bits(self.bit_offset);
+        }
+        // The decoder state is kept between chunks. A block header, a Huffman encoded symbol
+        // or part of an uncompressed block is only consumed when all of its compressed data
+        // is available, otherwise the bitstream is rewound to the bit offset that precedes it.
+        loop {
+            match self.state {
+                DeflateStreamState::BlockHeader => {
+                    let uncompressed_data_size: u64 = self.uncompressed_data_size
+                        + (uncompressed_data.len() - uncompressed_data_offset) as u64;
+
+                    if self.block_boundary_interval > 0
+                        && uncompressed_data_size - self.block_boundary_uncompressed_data_offset
+                            >= self.block_boundary_interval
+                    {
+                        let window_offset: usize =
+                            self.window.len().saturating_sub(DEFLATE_WINDOW_SIZE);
+
+                        self.block_boundaries.push(DeflateBlockBoundary {
+                            compressed_data_offset: self.compressed_data_base_offset
+                                + (bit_offset / 8) as u64,
+                            bit_offset: bit_offset % 8,
+                            uncompressed_data_offset: uncompressed_data_size,
+                            window: self.window[window_offset..].to_vec(),
+                        });
+                        self.block_boundary_uncompressed_data_offset = uncompressed_data_size;
+                    }
+                    let result: Result<(DeflateStreamState, bool), ErrorTrace> =
+                        Self::read_block_header(
+                            &mut self.deflate_context,
+                            &mut bitstream,
+                            &mut self.dynamic_literals_huffman_tree,
+                            &mut self.dynamic_distances_huffman_tree,
+                        );
+                    if bitstream.overflow {
+                        break;
+                    }
+                    match result {
+                        Ok((state, last_block)) => {
+                            self.state = state;
+                            self.last_block = last_block;
+                        }
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to decompress block"
+                            );
+                            return Err(error);
+                        }
+                    }
+                }
+                DeflateStreamState::UncompressedBlock(0) => {
+                    self.state = if self.last_block {
+                        DeflateStreamState::EndOfStream
+                    } else {
+                        DeflateStreamState::BlockHeader
+                    };
+                }
+                DeflateStreamState::UncompressedBlock(block_size) => {
+                    // The data of an uncompressed block starts at a byte boundary.
+                    let data_offset: usize = bit_offset / 8;
+                    let copy_size: usize =
+                        std::cmp::min(block_size, self.compressed_data.len() - data_offset);
+
+                    if copy_size == 0 {
+                        break;
+                    }
+                    let data_end_offset: usize = data_offset + copy_size;
+
+                    self.window
+                        .extend_from_slice(&self.compressed_data[data_offset..data_end_offset]);
+                    uncompressed_data
+                        .extend_from_slice(&self.compressed_data[data_offset..data_end_offset]);
+
+                    bitstream = DeflateBitstream::new(&self.compressed_data, data_end_offset);
+
+                    self.state = DeflateStreamState::UncompressedBlock(block_size - copy_size);
+                }
+                DeflateStreamState::FixedHuffmanBlock | DeflateStreamState::DynamicHuffmanBlock => {
+                    let (literals_huffman_tree, distances_huffman_tree) =
+                        if self.state == DeflateStreamState::FixedHuffmanBlock {
+                            (
+                                &self.deflate_context.fixed_literals_huffman_tree,
+                                &self.deflate_context.fixed_distances_huffman_tree,
+                            )
+                        } else {
+                            (
+                                &self.dynamic_literals_huffman_tree,
+                                &self.dynamic_distances_huffman_tree,
+                            )
+                        };
+                    let result: Result<bool, ErrorTrace> = Self::decode_huffman_encoded_symbol(
+                        &mut bitstream,
+                        literals_huffman_tree,
+                        distances_huffman_tree,
+                        &mut self.window,
+                        uncompressed_data,
+                    );
+                    if bitstream.overflow {
+                        break;
+                    }
+                    match result {
+                        Ok(false) => {}
+                        Ok(true) => {
+                            self.state = if self.last_block {
+                                DeflateStreamState::EndOfStream
+                            } else {
+                                DeflateStreamState::BlockHeader
+                            };
+                        }
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to decompress Huffman encoded block"
+                            );
+                            return Err(error);
+                        }
+                    }
+                }
+                DeflateStreamState::EndOfStream => {
+                    // The compressed stream ends at the next byte boundary.
+                    bit_offset = bit_offset.div_ceil(8) * 8;
+
+                    break;
+                }
+            }
+            bit_offset = (bitstream.data_offset * 8) - bitstream.number_of_bits;
+
+            if self.window.len() >= 2 * DEFLATE_WINDOW_SIZE {
+                self.window
+                    .drain(0..self.window.len() - DEFLATE_WINDOW_SIZE);
+            }
+        }
+        self.uncompressed_data_size += (uncompressed_data.len() - uncompressed_data_offset) as u64;
+
+        self.compressed_data_offset = bit_offset / 8;
+        self.bit_offset = bit_offset % 8;
+
+        if self.state != DeflateStreamState::EndOfStream {
+            self.compressed_data.drain(0..self.compressed_data_offset);
+
+            self.compressed_data_base_offset += self.compressed_data_offset as u64;
+            self.compressed_data_offset = 0;
+        }
+        Ok(())
+    }
+
+    /// Determines if the end of the compressed stream was reached.
+    fn is_end_of_stream(&self) -> bool {
+        self.state == DeflateStreamState::EndOfStream
+    }
+}
+
+/// Bitstream writer for DEFLATE compressed data.
+pub(super) struct DeflateBitstreamWriter<'a> {
+    /// Byte stream.
+    data: &'a mut Vec<u8>,
+
+    /// Bits buffer.
+    bits: u64,
+
+    /// Number of bits in the bits buffer.
+    number_of_bits: usize,
+}
+
+impl<'a> DeflateBitstreamWriter<'a> {
+    /// Creates a new bitstream writer that appends to the byte stream.
+    pub fn new(data: &'a mut Vec<u8>) -> Self {
+        Self {
+            data: data,
+            bits: 0,
+            number_of_bits: 0,
+        }
+    }
+
+    /// Writes the bits buffer up to the next byte boundary.
+    pub fn flush(&mut self) {
+        if self.number_of_bits > 0 {
+            self.data.push((self.bits & 0xff) as u8);
+        }
+        self.bits = 0;
+        self.number_of_bits = 0;
+    }
+
+    /// Writes bytes to the bitstream.
+    /// Note that this flushes the internal bits buffer.
+    pub fn write_bytes(&mut self, data: &[u8]) {
+        self.flush();
+        self.data.extend_from_slice(data);
+    }
+
+    /// Writes a value in little-endian bit order.
+    #[inline(always)]
+    pub fn write_value(&mut self, value: u32, number_of_bits: usize) {
+        self.bits |= (value as u64) << self.number_of_bits;
+        self.number_of_bits += number_of_bits;
+
+        while self.number_of_bits >= 8 {
+            self.data.push((self.bits & 0xff) as u8);
+            self.bits >>= 8;
+            self.number_of_bits -= 8;
+        }
+    }
+}
+
+/// LZ77 token used for compressing DEFLATE data.
+#[derive(Clone, Copy)]
+enum DeflateToken {
+    /// Literal byte value.
+    Literal(u8),
+
+    /// Match of a size and distance.
+    Match(u16, u16),
+}
+
+/// Huffman encoder for DEFLATE compressed data.
+struct DeflateHuffmanEncoder {
+    /// Code sizes.
+    code_sizes: Vec<u8>,
+
+    /// Bit-reversed (canonical) Huffman codes.
+    codes: Vec<u16>,
+}
+
+impl DeflateHuffmanEncoder {
+    /// Creates a new Huffman encoder from code sizes.
+    pub fn new(code_sizes: &[u8]) -> Self {
+        let mut code_size_counts: [u16; 16] = [0; 16];
+
+        for code_size in code_sizes.iter() {
+            code_size_counts[*code_size as usize] += 1;
+        }
+        code_size_counts[0] = 0;
+
+        let mut next_codes: [u16; 16] = [0; 16];
+        let mut huffman_code: u16 = 0;
+
+        for bit_index in 1..16 {
+            huffman_code = (huffman_code + code_size_counts[bit_index - 1]) << 1;
+            next_codes[bit_index] = huffman_code;
+        }
+        let mut codes: Vec<u16> = vec![0; code_sizes.len()];
+
+        for (symbol, code_size) in code_sizes.iter().enumerate() {
+            if *code_size == 0 {
+                continue;
+            }
+            let huffman_code: u16 = next_codes[*code_size as usize];
+            next_codes[*code_size as usize] += 1;
+
+            codes[symbol] = huffman_code.reverse_bits() >> (16 - *code_size);
+        }
+        Self {
+            code_sizes: code_sizes.to_vec(),
+            codes: codes,
+        }
+    }
+
+    /// Determines the number of bits needed to encode the symbols.
+    pub fn get_encoded_size(&self, frequencies: &[u32]) -> usize {
+        frequencies
+            .iter()
+            .zip(self.code_sizes.iter())
+            .map(|(frequency, code_size)| *frequency as usize * *code_size as usize)
+            .sum()
+    }
+
+    /// Writes a symbol to the bitstream.
+    #[inline(always)]
+    pub fn write_symbol(&self, bitstream: &mut DeflateBitstreamWriter, symbol: usize) {
+        bitstream.write_value(self.codes[symbol] as u32, self.code_sizes[symbol] as usize);
+    }
+}
+
+/// Determines the Huffman code sizes of symbols from their frequencies.
+///
+/// The frequencies are scaled down until none of the code sizes exceed the maximum code size.
+fn get_huffman_code_sizes(frequencies: &[u32], maximum_code_size: u8) -> Vec<u8> {
+    let mut scaled_frequencies: Vec<u32> = frequencies.to_vec();
+
+    loop {
+        let mut code_sizes: Vec<u8> = vec![0; frequencies.len()];
+
+        let symbols: Vec<usize> = (0..scaled_frequencies.len())
+            .filter(|symbol| scaled_frequencies[*symbol] > 0)
+            .collect();
+
+        if symbols.len() == 1 {
+            code_sizes[symbols[0]] = 1;
+        }
+        if symbols.len() <= 1 {
+            return code_sizes;
+        }
+        // Leaf nodes are stored first, branch nodes are appended as they are created.
+        let mut parent_nodes: Vec<usize> = vec![0; symbols.len()];
+        let mut heap: BinaryHeap<Reverse<(u32, usize)>> = symbols
+            .iter()
+            .enumerate()
+            .map(|(node_index, symbol)| Reverse((scaled_frequencies[*symbol], node_index)))
+            .collect();
+
+        while let Some(Reverse((first_weight, first_node_index))) = heap.pop() {
+            // The last remaining node is the root node.
+            let Reverse((second_weight, second_node_index)) = match heap.pop() {
+                Some(heap_entry) => heap_entry,
+                None => break,
+            };
+
+            let node_index: usize = parent_nodes.len();
+            parent_nodes.push(0);
+
+            parent_nodes[first_node_index] = node_index;
+            parent_nodes[second_node_index] = node_index;
+
+            heap.push(Reverse((first_weight + second_weight, node_index)));
+        }
+        let root_node_index: usize = parent_nodes.len() - 1;
+        let mut node_depths: Vec<u8> = vec![0; parent_nodes.len()];
+
+        for node_index in (0..root_node_index).rev() {
+            node_depths[node_index] = node_depths[parent_nodes[node_index]] + 1;
+        }
+        let largest_code_size: u8 = node_depths[0..symbols.len()]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0);
+
+        if largest_code_size <= maximum_code_size {
+            for (node_index, symbol) in symbols.iter().enumerate() {
+                code_sizes[*symbol] = node_depths[node_index];
+            }
+            return code_sizes;
+        }
+        for frequency in scaled_frequencies.iter_mut() {
+            if *frequency > 1 {
+                *frequency >>= 1;
+            }
+        }
+    }
+}
+
+/// Context for compressing data using DEFLATE.
+pub struct DeflateCompressionContext {
+    /// Maximum number of match candidates to test per position.
+    maximum_chain_length: usize,
+}
+
+impl DeflateCompressionContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            maximum_chain_length: DEFLATE_MAXIMUM_CHAIN_LENGTH,
+        }
+    }
+
+    /// Compress data.
+    ///
+    /// The compressed data is appended to `compressed_data`.
+    pub fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        let mut bitstream: DeflateBitstreamWriter = DeflateBitstreamWriter::new(compressed_data);
+
+        self.compress_to_bitstream(uncompressed_data, &mut bitstream);
+
+        bitstream.flush();
+
+        Ok(())
+    }
+
+    /// Compress data to a bitstream.
+    pub(super) fn compress_to_bitstream(
+        &mut self,
+        uncompressed_data: &[u8],
+        bitstream: &mut DeflateBitstreamWriter,
+    ) {
+        let uncompressed_data_size: usize = uncompressed_data.len();
+
+        let mut hash_heads: Vec<usize> = vec![usize::MAX; 1 << DEFLATE_HASH_BITS];
+        let mut hash_chains: Vec<usize> = vec![usize::MAX; DEFLATE_WINDOW_SIZE];
+
+        let mut tokens: Vec<DeflateToken> = Vec::new();
+        let mut block_offset: usize = 0;
+        let mut data_offset: usize = 0;
+
+        while data_offset < uncompressed_data_size {
+            let (match_size, match_distance) =
+                self.find_match(uncompressed_data, data_offset, &hash_heads, &hash_chains);
+            let token_size: usize = if match_size >= DEFLATE_MINIMUM_MATCH_SIZE {
+                tokens.push(DeflateToken::Match(
+                    match_size as u16,
+                    match_distance as u16,
+                ));
+                match_size
+            } else {
+                tokens.push(DeflateToken::Literal(uncompressed_data[data_offset]));
+                1
+            };
+            for _ in 0..token_size {
+                if data_offset + DEFLATE_MINIMUM_MATCH_SIZE <= uncompressed_data_size {
+                    let hash: usize = Self::get_hash(uncompressed_data, data_offset);
+
+                    hash_chains[data_offset % DEFLATE_WINDOW_SIZE] = hash_heads[hash];
+                    hash_heads[hash] = data_offset;
+                }
+                data_offset += 1;
+            }
+            if data_offset - block_offset + DEFLATE_MAXIMUM_MATCH_SIZE > DEFLATE_MAXIMUM_BLOCK_SIZE
+                || tokens.len() >= DEFLATE_MAXIMUM_NUMBER_OF_TOKENS
+            {
+                let is_last_block: bool = data_offset >= uncompressed_data_size;
+
+                Self::write_block(
+                    bitstream,
+                    &tokens,
+                    &uncompressed_data[block_offset..data_offset],
+                    is_last_block,
+                );
+                tokens.clear();
+                block_offset = data_offset;
+
+                if is_last_block {
+                    return;
+                }
+            }
+        }
+        Self::write_block(
+            bitstream,
+            &tokens,
+            &uncompressed_data[block_offset..data_offset],
+            true,
+        );
+    }
+
+    /// Finds the longest match in the sliding window.
+    ///
+    /// Returns the match size and distance, where a match size of 0 indicates no match.
+    fn find_match(
+        &self,
+        uncompressed_data: &[u8],
+        data_offset: usize,
+        hash_heads: &[usize],
+        hash_chains: &[usize],
+    ) -> (usize, usize) {
+        let uncompressed_data_size: usize = uncompressed_data.len();
+
+        if data_offset + DEFLATE_MINIMUM_MATCH_SIZE > uncompressed_data_size {
+            return (0, 0);
+        }
+        let maximum_match_size: usize = usize::min(
+            DEFLATE_MAXIMUM_MATCH_SIZE,
+            uncompressed_data_size - data_offset,
+        );
+
+        let mut match_candidate_offset: usize =
+            hash_heads[Self::get_hash(uncompressed_data, data_offset)];
+        let mut match_size: usize = 0;
+        let mut match_distance: usize = 0;
+
+        for _ in 0..self.maximum_chain_length {
+            if match_candidate_offset >= data_offset
+                || data_offset - match_candidate_offset > DEFLATE_WINDOW_SIZE
+            {
+                break;
+            }
+            let mut candidate_match_size: usize = 0;
+
+            while candidate_match_size < maximum_match_size
+                && uncompressed_data[match_candidate_offset + candidate_match_size]
+                    == uncompressed_data[data_offset + candidate_match_size]
+            {
+                candidate_match_size += 1;
+            }
+            if candidate_match_size > match_size {
+                match_size = candidate_match_size;
+                match_distance = data_offset - match_candidate_offset;
+
+                if match_size == maximum_match_size {
+                    break;
+                }
+            }
+            match_candidate_offset = hash_chains[match_candidate_offset % DEFLATE_WINDOW_SIZE];
+        }
+        (match_size, match_distance)
+    }
+
+    /// Calculates the hash of the 3 bytes at the data offset.
+    #[inline(always)]
+    fn get_hash(data: &[u8], data_offset: usize) -> usize {
+        let hash: usize = ((data[data_offset] as usize) << 10)
+            ^ ((data[data_offset + 1] as usize) << 5)
+            ^ (data[data_offset + 2] as usize);
+
+        hash & ((1 << DEFLATE_HASH_BITS) - 1)
+    }
+
+    /// Retrieves the literal code index of a match size.
+    #[inline(always)]
+    fn get_literal_code_index(match_size: usize) -> usize {
+        DEFLATE_LITERAL_CODES_BASE.partition_point(|base| *base as usize <= match_size) - 1
+    }
+
+    /// Retrieves the distance code index of a match distance.
+    #[inline(always)]
+    fn get_distance_code_index(match_distance: usize) -> usize {
+        DEFLATE_DISTANCE_CODES_BASE.partition_point(|base| *base as usize <= match_distance) - 1
+    }
+
+    /// Run-length encodes the code sizes of dynamic Huffman trees.
+    ///
+    /// Returns code size symbols and their extra bits values.
+    fn get_code_size_symbols(code_sizes: &[u8]) -> Vec<(u8, u8)> {
+        let mut code_size_symbols: Vec<(u8, u8)> = Vec::new();
+        let number_of_code_sizes: usize = code_sizes.len();
+        let mut code_size_index: usize = 0;
+
+        while code_size_index < number_of_code_sizes {
+            let code_size: u8 = code_sizes[code_size_index];
+            let mut run_size: usize = 1;
+
+            while code_size_index + run_size < number_of_code_sizes
+                && code_sizes[code_size_index + run_size] == code_size
+            {
+                run_size += 1;
+            }
+            code_size_index += run_size;
+
+            if code_size == 0 {
+                while run_size >= 11 {
+                    let repeat_size: usize = usize::min(run_size, 138);
+                    code_size_symbols.push((18, (repeat_size - 11) as u8));
+                    run_size -= repeat_size;
+                }
+                if run_size >= 3 {
+                    code_size_symbols.push((17, (run_size - 3) as u8));
+                    run_size = 0;
+                }
+            } else {
+                code_size_symbols.push((code_size, 0));
+                run_size -= 1;
+
+                while run_size >= 3 {
+                    let repeat_size: usize = usize::min(run_size, 6);
+                    code_size_symbols.push((16, (repeat_size - 3) as u8));
+                    run_size -= repeat_size;
+                }
+            }
+            for _ in 0..run_size {
+                code_size_symbols.push((code_size, 0));
+            }
+        }
+        code_size_symbols
+    }
+
+    /// Writes a block.
+    ///
+    /// The block is written uncompressed, with fixed or with dynamic Huffman trees, whichever
+    /// results in the smallest block.
+    fn write_block(
+        bitstream: &mut DeflateBitstreamWriter,
+        tokens: &[DeflateToken],
+        block_data: &[u8],
+        is_last_block: bool,
+    ) {
+        let mut literal_frequencies: Vec<u32> = vec![0; 286];
+        let mut distance_frequencies: Vec<u32> = vec![0; 30];
+        let mut number_of_extra_bits: usize = 0;
+
+        for token in tokens.iter() {
+            match token {
+                DeflateToken::Literal(byte_value) => {
+                    literal_frequencies[*byte_value as usize] += 1;
+                }
+                DeflateToken::Match(match_size, match_distance) => {
+                    let literal_code_index: usize =
+                        Self::get_literal_code_index(*match_size as usize);
+                    let distance_code_index: usize =
+                        Self::get_distance_code_index(*match_distance as usize);
+
+                    literal_frequencies[257 + literal_code_index] += 1;
+                    distance_frequencies[distance_code_index] += 1;
+
+                    number_of_extra_bits += DEFLATE_LITERAL_CODES_NUMBER_OF_EXTRA_BITS
+                        [literal_code_index] as usize
+                        + DEFLATE_DISTANCE_CODES_NUMBER_OF_EXTRA_BITS[distance_code_index] as usize;
+                }
+            }
+        }
+        literal_frequencies[256] = 1;
+
+        // Determine the size of the block with fixed Huffman trees.
+        let mut fixed_code_sizes: Vec<u8> = vec![0; 318];
+
+        for symbol in 0..318 {
+            fixed_code_sizes[symbol] = if symbol < 144 {
+                8
+            } else if symbol < 256 {
+                9
+            } else if symbol < 280 {
+                7
+            } else if symbol < 288 {
+                8
+            } else {
+                5
+            };
+        }
+        let fixed_literals_encoder: DeflateHuffmanEncoder =
+            DeflateHuffmanEncoder::new(&fixed_code_sizes[0..288]);
+        let fixed_distances_encoder: DeflateHuffmanEncoder =
+            DeflateHuffmanEncoder::new(&fixed_code_sizes[288..318]);
+
+        let fixed_block_size: usize = 3
+            + fixed_literals_encoder.get_encoded_size(&literal_frequencies)
+            + fixed_distances_encoder.get_encoded_size(&distance_frequencies)
+            + number_of_extra_bits;
+
+        // Determine the size of the block with dynamic Huffman trees.
+        let literal_code_sizes: Vec<u8> = get_huffman_code_sizes(&literal_frequencies, 15);
+        let mut distance_code_sizes: Vec<u8> = get_huffman_code_sizes(&distance_frequencies, 15);
+
+        // At least one distance code is required by decoders even if no distances are used.
+        if distance_code_sizes.iter().all(|code_size| *code_size == 0) {
+            distance_code_sizes[0] = 1;
+        }
+        let number_of_literal_codes: usize = usize::max(
+            257,
+            literal_code_sizes
+                .iter()
+                .rposition(|code_size| *code_size != 0)
+                .unwrap_or(0)
+                + 1,
+        );
+        let number_of_distance_codes: usize = distance_code_sizes
+            .iter()
+            .rposition(|code_size| *code_size != 0)
+            .unwrap_or(0)
+            + 1;
+
+        let mut code_sizes: Vec<u8> = literal_code_sizes[0..number_of_literal_codes].to_vec();
+        code_sizes.extend_from_slice(&distance_code_sizes[0..number_of_distance_codes]);
+
+        let code_size_symbols: Vec<(u8, u8)> = Self::get_code_size_symbols(&code_sizes);
+
+        let mut code_size_frequencies: Vec<u32> = vec![0; 19];
+        let mut code_size_number_of_extra_bits: usize = 0;
+
+        for (symbol, _) in code_size_symbols.iter() {
+            code_size_frequencies[*symbol as usize] += 1;
+
+            code_size_number_of_extra_bits += match symbol {
+                16 => 2,
+                17 => 3,
+                18 => 7,
+                _ => 0,
+            };
+        }
+        let code_size_code_sizes: Vec<u8> = get_huffman_code_sizes(&code_size_frequencies, 7);
+
+        let number_of_code_sizes: usize = usize::max(
+            4,
+            DEFLATE_CODE_SIZES_SEQUENCE
+                .iter()
+                .rposition(|code_size_index| code_size_code_sizes[*code_size_index as usize] != 0)
+                .unwrap_or(0)
+                + 1,
+        );
+        let code_sizes_encoder: DeflateHuffmanEncoder =
+            DeflateHuffmanEncoder::new(&code_size_code_sizes);
+        let literals_encoder: DeflateHuffmanEncoder =
+            DeflateHuffmanEncoder::new(&literal_code_sizes);
+        let distances_encoder: DeflateHuffmanEncoder =
+            DeflateHuffmanEncoder::new(&distance_code_sizes);
+
+        let dynamic_block_size: usize = 3
+            + 14
+            + (3 * number_of_code_sizes)
+            + code_sizes_encoder.get_encoded_size(&code_size_frequencies)
+            + code_size_number_of_extra_bits
+            + literals_encoder.get_encoded_size(&literal_frequencies)
+            + distances_encoder.get_encoded_size(&distance_frequencies)
+            + number_of_extra_bits;
+
+        // The uncompressed block size includes the worst case for the byte alignment.
+        let uncompressed_block_size: usize = 3 + 7 + 32 + (8 * block_data.len());
+
+        let last_block_flag: u32 = if is_last_block { 1 } else { 0 };
+
+        if uncompressed_block_size < fixed_block_size
+            && uncompressed_block_size < dynamic_block_size
+        {
+            bitstream.write_value(last_block_flag, 1);
+            bitstream.write_value(DEFLATE_BLOCK_TYPE_UNCOMPRESED, 2);
+            bitstream.flush();
+
+            let block_size: u32 = block_data.len() as u32;
+            bitstream.write_value(block_size | ((block_size ^ 0xffff) << 16), 32);
+            bitstream.write_bytes(block_data);
+        } else if fixed_block_size <= dynamic_block_size {
+            bitstream.write_value(last_block_flag, 1);
+            bitstream.write_value(DEFLATE_BLOCK_TYPE_HUFFMAN_FIXED, 2);
+
+            Self::write_tokens(
+                bitstream,
+                tokens,
+                &fixed_literals_encoder,
+                &fixed_distances_encoder,
+            );
+        } else {
+            bitstream.write_value(last_block_flag, 1);
+            bitstream.write_value(DEFLATE_BLOCK_TYPE_HUFFMAN_DYNAMIC, 2);
+            bitstream.write_value((number_of_literal_codes - 257) as u32, 5);
+            bitstream.write_value((number_of_distance_codes - 1) as u32, 5);
+            bitstream.write_value((number_of_code_sizes - 4) as u32, 4);
+
+            for code_size_index in DEFLATE_CODE_SIZES_SEQUENCE[0..number_of_code_sizes].iter() {
+                bitstream.write_value(code_size_code_sizes[*code_size_index as usize] as u32, 3);
+            }
+            for (symbol, extra_bits_value) in code_size_symbols.iter() {
+                code_sizes_encoder.write_symbol(bitstream, *symbol as usize);
+
+                match symbol {
+                    16 => bitstream.write_value(*extra_bits_value as u32, 2),
+                    17 => bitstream.write_value(*extra_bits_value as u32, 3),
+                    18 => bitstream.write_value(*extra_bits_value as u32, 7),
+                    _ => {}
+                }
+            }
+            Self::write_tokens(bitstream, tokens, &literals_encoder, &distances_encoder);
+        }
+    }
+
+    /// Writes Huffman encoded tokens followed by the end-of-block code.
+    fn write_tokens(
+        bitstream: &mut DeflateBitstreamWriter,
+        tokens: &[DeflateToken],
+        literals_encoder: &DeflateHuffmanEncoder,
+        distances_encoder: &DeflateHuffmanEncoder,
+    ) {
+        for token in tokens.iter() {
+            match token {
+                DeflateToken::Literal(byte_value) => {
+                    literals_encoder.write_symbol(bitstream, *byte_value as usize);
+                }
+                DeflateToken::Match(match_size, match_distance) => {
+                    let literal_code_index: usize =
+                        Self::get_literal_code_index(*match_size as usize);
+                    let distance_code_index: usize =
+                        Self::get_distance_code_index(*match_distance as usize);
+
+                    literals_encoder.write_symbol(bitstream, 257 + literal_code_index);
+                    bitstream.write_value(
+                        (*match_size - DEFLATE_LITERAL_CODES_BASE[literal_code_index]) as u32,
+                        DEFLATE_LITERAL_CODES_NUMBER_OF_EXTRA_BITS[literal_code_index] as usize,
+                    );
+                    distances_encoder.write_symbol(bitstream, distance_code_index);
+                    bitstream.write_value(
+                        (*match_distance - DEFLATE_DISTANCE_CODES_BASE[distance_code_index]) as u32,
+                        DEFLATE_DISTANCE_CODES_NUMBER_OF_EXTRA_BITS[distance_code_index] as usize,
+                    );
+                }
+            }
+        }
+        literals_encoder.write_symbol(bitstream, 256);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1034,4 +2160,233 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_stream_decompress_chunk() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateStreamContext = DeflateStreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in test_data.chunks(100) {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 11358);
+        assert_eq!(test_context.get_trailing_data().len(), 0);
+
+        let expected_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        assert_eq!(&uncompressed_data, &expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_decompress_chunk_with_single_bytes() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateStreamContext = DeflateStreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for (data_offset, chunk) in test_data.chunks(1).enumerate() {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+
+            // Compressed data is only buffered until it can be decoded.
+            assert!(test_context.compressed_data.len() < 512);
+
+            if data_offset == test_data.len() / 2 {
+                assert!(uncompressed_data.len() > 0);
+            }
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 11358);
+        assert_eq!(test_context.get_trailing_data().len(), 0);
+
+        let expected_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        assert_eq!(&uncompressed_data, &expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_decompress_chunk_with_uncompressed_block() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateStreamContext = DeflateStreamContext::new();
+
+        let test_data: [u8; 14] = [
+            0x01, 0x08, 0x00, 0xf7, 0xff, 0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73, 0xff,
+        ];
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        test_context.decompress_chunk(&test_data[0..7], &mut uncompressed_data)?;
+        assert!(!test_context.is_end_of_stream());
+        assert_eq!(uncompressed_data, b"Ke");
+
+        test_context.decompress_chunk(&test_data[7..], &mut uncompressed_data)?;
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(uncompressed_data, b"Keramics");
+        assert_eq!(test_context.get_trailing_data(), [0xff]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_new_at_block_boundary() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateStreamContext = DeflateStreamContext::new();
+
+        let test_data: [u8; 30] = [
+            0xf2, 0x4e, 0x2d, 0x4a, 0xcc, 0xcd, 0x4c, 0x2e, 0x56, 0x28, 0x49, 0x2d, 0x2e, 0x51,
+            0x48, 0x49, 0x2c, 0x49, 0x54, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xf3, 0xc6, 0x14,
+            0x02, 0x00,
+        ];
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        test_context.set_block_boundary_interval(1);
+
+        test_context.decompress_chunk(&test_data[0..25], &mut uncompressed_data)?;
+        assert!(!test_context.is_end_of_stream());
+        assert_eq!(uncompressed_data, b"Keramics test data ");
+
+        let block_boundaries: Vec<DeflateBlockBoundary> = test_context.take_block_boundaries();
+        assert_eq!(block_boundaries.len(), 1);
+        assert_eq!(block_boundaries[0].compressed_data_offset, 20);
+        assert_eq!(block_boundaries[0].bit_offset, 2);
+        assert_eq!(block_boundaries[0].uncompressed_data_offset, 19);
+        assert_eq!(block_boundaries[0].window, b"Keramics test data ");
+
+        // The third block refers to uncompressed data of the first block.
+        let mut test_context: DeflateStreamContext = DeflateStreamContext::new_at_block_boundary(
+            block_boundaries[0].bit_offset,
+            &block_boundaries[0].window,
+        );
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        test_context.decompress_chunk(&test_data[20..], &mut uncompressed_data)?;
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(uncompressed_data, b"Keramics test data ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_huffman_code_sizes() {
+        let code_sizes: Vec<u8> = get_huffman_code_sizes(&[5, 9, 12, 13, 16, 45], 15);
+        assert_eq!(code_sizes, [4, 4, 3, 3, 3, 1]);
+
+        let code_sizes: Vec<u8> = get_huffman_code_sizes(&[0, 7, 0], 15);
+        assert_eq!(code_sizes, [0, 1, 0]);
+
+        let code_sizes: Vec<u8> = get_huffman_code_sizes(&[1, 1, 2, 3, 5, 8, 13, 21], 4);
+        assert!(
+            code_sizes
+                .iter()
+                .all(|code_size| *code_size > 0 && *code_size <= 4)
+        );
+    }
+
+    #[test]
+    fn test_compress() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateCompressionContext = DeflateCompressionContext::new();
+
+        let test_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert!(compressed_data.len() < 4096);
+
+        let mut decompression_context: DeflateContext = DeflateContext::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 11358];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 11358);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_empty_data() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateCompressionContext = DeflateCompressionContext::new();
+
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&[], &mut compressed_data)?;
+        assert_eq!(compressed_data, [0x03, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_uncompressed_block() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateCompressionContext = DeflateCompressionContext::new();
+
+        // Pseudo-random data that cannot be compressed.
+        let mut test_data: Vec<u8> = vec![0; 100000];
+        let mut seed: u32 = 0x12345678;
+
+        for byte_value in test_data.iter_mut() {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            *byte_value = (seed >> 16) as u8;
+        }
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert_eq!(compressed_data[0] & 0x07, 0x00);
+
+        let mut decompression_context: DeflateContext = DeflateContext::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 100000];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 100000);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_repetitive_data() -> Result<(), ErrorTrace> {
+        let mut test_context: DeflateCompressionContext = DeflateCompressionContext::new();
+
+        let test_data: Vec<u8> = b"Keramics test data ".repeat(10000);
+
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert!(compressed_data.len() < 2048);
+
+        let mut decompression_context: DeflateStreamContext = DeflateStreamContext::new();
+
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in compressed_data.chunks(64) {
+            decompression_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+        }
+        assert!(decompression_context.is_end_of_stream());
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
 }
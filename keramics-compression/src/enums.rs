@@ -0,0 +1,31 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// Compression method.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompressionMethod {
+    Adc,
+    Bzip2,
+    Deflate,
+    Lzfse,
+    Lzma,
+    Lzma2,
+    Lznt1,
+    Lzvn,
+    Lzx,
+    Lzxpress,
+    LzxpressHuffman,
+    Xz,
+    Zlib,
+    Zstd,
+}
@@ -13,22 +13,39 @@
 
 mod adc;
 mod bzip2;
+mod compressor;
+mod decompressor;
 mod deflate;
+mod enums;
 mod huffman;
 mod lzfse;
+mod lzma;
+mod lzma2;
 mod lznt1;
 mod lzvn;
 mod lzx;
 mod lzxpress;
 mod traits;
+mod xz;
 mod zlib;
+mod zstd;
 
 pub use adc::AdcContext;
-pub use bzip2::Bzip2Context;
-pub use deflate::DeflateContext;
+pub use bzip2::{Bzip2Context, Bzip2StreamContext};
+pub use compressor::new_compressor;
+pub use decompressor::{new_decompressor, new_stream_decompressor};
+pub use deflate::{
+    DeflateBlockBoundary, DeflateCompressionContext, DeflateContext, DeflateStreamContext,
+};
+pub use enums::CompressionMethod;
 pub use lzfse::LzfseContext;
-pub use lznt1::Lznt1Context;
+pub use lzma::LzmaContext;
+pub use lzma2::Lzma2Context;
+pub use lznt1::{Lznt1CompressionContext, Lznt1Context};
 pub use lzvn::LzvnContext;
 pub use lzx::LzxContext;
 pub use lzxpress::{LzxpressContext, LzxpressHuffmanContext};
-pub use zlib::ZlibContext;
+pub use traits::{Compressor, Decompressor, StreamDecompressor};
+pub use xz::XzContext;
+pub use zlib::{ZlibCompressionContext, ZlibContext, ZlibStreamContext};
+pub use zstd::ZstdContext;
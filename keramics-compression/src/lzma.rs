@@ -0,0 +1,848 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! LZMA decompression.
+//!
+//! Provides decompression support for LZMA compressed data.
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+/// Number of bits of the probability of the range decoder.
+const LZMA_NUMBER_OF_PROBABILITY_BITS: u32 = 11;
+
+/// Initial probability of the range decoder.
+const LZMA_INITIAL_PROBABILITY: u16 = 1 << (LZMA_NUMBER_OF_PROBABILITY_BITS - 1);
+
+/// Number of bits to shift when adapting a probability.
+const LZMA_NUMBER_OF_MOVE_BITS: u32 = 5;
+
+/// Number of states.
+const LZMA_NUMBER_OF_STATES: usize = 12;
+
+/// Maximum number of position states.
+const LZMA_MAXIMUM_NUMBER_OF_POSITION_STATES: usize = 16;
+
+/// Number of length to position states.
+const LZMA_NUMBER_OF_LENGTH_TO_POSITION_STATES: usize = 4;
+
+/// Index of the first position slot that uses the alignment bits.
+const LZMA_END_POSITION_MODEL_INDEX: usize = 14;
+
+/// Number of full distances.
+const LZMA_NUMBER_OF_FULL_DISTANCES: usize = 1 << (LZMA_END_POSITION_MODEL_INDEX >> 1);
+
+/// Number of alignment bits.
+const LZMA_NUMBER_OF_ALIGN_BITS: usize = 4;
+
+/// Minimum match length.
+const LZMA_MINIMUM_MATCH_LENGTH: usize = 2;
+
+/// Size of the LZMA data header.
+const LZMA_DATA_HEADER_SIZE: usize = 13;
+
+/// Range decoder for LZMA compressed data.
+pub(super) struct LzmaRangeDecoder<'a> {
+    /// Byte steam.
+    data: &'a [u8],
+
+    /// Current offset in the byte stream.
+    pub data_offset: usize,
+
+    /// Size of the byte stream in bytes.
+    data_size: usize,
+
+    /// Range.
+    range: u32,
+
+    /// Code.
+    code: u32,
+
+    /// Value to indicate the range decoder read beyond the end of the byte stream.
+    pub overflow: bool,
+}
+
+impl<'a> LzmaRangeDecoder<'a> {
+    /// Creates a new range decoder.
+    pub fn new(data: &'a [u8], data_offset: usize) -> Result<Self, ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_offset > data_size || 5 > data_size - data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        if data[data_offset] != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported range decoder initial byte value"
+            ));
+        }
+        let mut range_decoder: Self = Self {
+            data: data,
+            data_offset: data_offset + 1,
+            data_size: data_size,
+            range: 0xffffffff,
+            code: 0,
+            overflow: false,
+        };
+        for _ in 0..4 {
+            range_decoder.code = (range_decoder.code << 8) | range_decoder.read_byte() as u32;
+        }
+        if range_decoder.code == range_decoder.range {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid range decoder code value out of bounds"
+            ));
+        }
+        Ok(range_decoder)
+    }
+
+    /// Determines if the range decoder finished without remaining code.
+    pub fn is_finished(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Reads a byte from the byte stream.
+    #[inline(always)]
+    fn read_byte(&mut self) -> u8 {
+        // If the byte stream overflows return 0 byte values.
+        if self.data_offset < self.data_size {
+            let byte_value: u8 = self.data[self.data_offset];
+            self.data_offset += 1;
+
+            byte_value
+        } else {
+            self.overflow = true;
+
+            0
+        }
+    }
+
+    /// Normalizes the range.
+    #[inline(always)]
+    fn normalize(&mut self) {
+        if self.range < 0x01000000 {
+            self.range <<= 8;
+            self.code = (self.code << 8) | self.read_byte() as u32;
+        }
+    }
+
+    /// Decodes a bit using a probability.
+    #[inline(always)]
+    pub fn decode_bit(&mut self, probability: &mut u16) -> u32 {
+        let bound: u32 = (self.range >> LZMA_NUMBER_OF_PROBABILITY_BITS) * (*probability as u32);
+
+        let bit: u32 = if self.code < bound {
+            self.range = bound;
+            *probability +=
+                ((1 << LZMA_NUMBER_OF_PROBABILITY_BITS) - *probability) >> LZMA_NUMBER_OF_MOVE_BITS;
+            0
+        } else {
+            self.range -= bound;
+            self.code -= bound;
+            *probability -= *probability >> LZMA_NUMBER_OF_MOVE_BITS;
+            1
+        };
+        self.normalize();
+
+        bit
+    }
+
+    /// Decodes bits without a probability.
+    pub fn decode_direct_bits(&mut self, number_of_bits: usize) -> u32 {
+        let mut value_32bit: u32 = 0;
+
+        for _ in 0..number_of_bits {
+            self.range >>= 1;
+
+            let bit: u32 = if self.code >= self.range {
+                self.code -= self.range;
+                1
+            } else {
+                0
+            };
+            value_32bit = (value_32bit << 1) | bit;
+
+            self.normalize();
+        }
+        value_32bit
+    }
+
+    /// Decodes a value using a bit tree.
+    pub fn decode_bit_tree(&mut self, probabilities: &mut [u16], number_of_bits: usize) -> u32 {
+        let mut tree_index: usize = 1;
+
+        for _ in 0..number_of_bits {
+            let bit: u32 = self.decode_bit(&mut probabilities[tree_index]);
+            tree_index = (tree_index << 1) | bit as usize;
+        }
+        (tree_index - (1 << number_of_bits)) as u32
+    }
+
+    /// Decodes a value using a reverse bit tree.
+    pub fn decode_reverse_bit_tree(
+        &mut self,
+        probabilities: &mut [u16],
+        number_of_bits: usize,
+    ) -> u32 {
+        let mut tree_index: usize = 1;
+        let mut value_32bit: u32 = 0;
+
+        for bit_index in 0..number_of_bits {
+            let bit: u32 = self.decode_bit(&mut probabilities[tree_index]);
+            tree_index = (tree_index << 1) | bit as usize;
+            value_32bit |= bit << bit_index;
+        }
+        value_32bit
+    }
+}
+
+/// Length decoder for LZMA compressed data.
+struct LzmaLengthDecoder {
+    /// Probability of the first choice.
+    choice: u16,
+
+    /// Probability of the second choice.
+    choice2: u16,
+
+    /// Probabilities of the low lengths per position state.
+    low: [u16; LZMA_MAXIMUM_NUMBER_OF_POSITION_STATES << 3],
+
+    /// Probabilities of the middle lengths per position state.
+    middle: [u16; LZMA_MAXIMUM_NUMBER_OF_POSITION_STATES << 3],
+
+    /// Probabilities of the high lengths.
+    high: [u16; 256],
+}
+
+impl LzmaLengthDecoder {
+    /// Creates a new length decoder.
+    pub fn new() -> Self {
+        Self {
+            choice: LZMA_INITIAL_PROBABILITY,
+            choice2: LZMA_INITIAL_PROBABILITY,
+            low: [LZMA_INITIAL_PROBABILITY; LZMA_MAXIMUM_NUMBER_OF_POSITION_STATES << 3],
+            middle: [LZMA_INITIAL_PROBABILITY; LZMA_MAXIMUM_NUMBER_OF_POSITION_STATES << 3],
+            high: [LZMA_INITIAL_PROBABILITY; 256],
+        }
+    }
+
+    /// Decodes a length.
+    pub fn decode(&mut self, range_decoder: &mut LzmaRangeDecoder, position_state: usize) -> usize {
+        let tree_offset: usize = position_state << 3;
+
+        if range_decoder.decode_bit(&mut self.choice) == 0 {
+            return range_decoder.decode_bit_tree(&mut self.low[tree_offset..tree_offset + 8], 3)
+                as usize;
+        }
+        if range_decoder.decode_bit(&mut self.choice2) == 0 {
+            return 8 + range_decoder
+                .decode_bit_tree(&mut self.middle[tree_offset..tree_offset + 8], 3)
+                as usize;
+        }
+        16 + range_decoder.decode_bit_tree(&mut self.high, 8) as usize
+    }
+}
+
+/// Decoder for LZMA compressed data.
+///
+/// The uncompressed data is used as dictionary, which allows the decoder state to be
+/// preserved between LZMA2 chunks.
+pub(super) struct LzmaDecoder {
+    /// Number of literal context bits.
+    number_of_literal_context_bits: usize,
+
+    /// Number of literal position bits.
+    number_of_literal_position_bits: usize,
+
+    /// Number of position bits.
+    number_of_position_bits: usize,
+
+    /// Dictionary size.
+    pub dictionary_size: u32,
+
+    /// Probabilities of the literals.
+    literals: Vec<u16>,
+
+    /// Probabilities of the match flags per state and position state.
+    is_match: [u16; LZMA_NUMBER_OF_STATES << 4],
+
+    /// Probabilities of the repeated match flags per state.
+    is_rep: [u16; LZMA_NUMBER_OF_STATES],
+
+    /// Probabilities of the first repeated distance flags per state.
+    is_rep_g0: [u16; LZMA_NUMBER_OF_STATES],
+
+    /// Probabilities of the second repeated distance flags per state.
+    is_rep_g1: [u16; LZMA_NUMBER_OF_STATES],
+
+    /// Probabilities of the third repeated distance flags per state.
+    is_rep_g2: [u16; LZMA_NUMBER_OF_STATES],
+
+    /// Probabilities of the long first repeated match flags per state and position state.
+    is_rep0_long: [u16; LZMA_NUMBER_OF_STATES << 4],
+
+    /// Probabilities of the position slots per length to position state.
+    position_slots: [u16; LZMA_NUMBER_OF_LENGTH_TO_POSITION_STATES << 6],
+
+    /// Probabilities of the distances of position slots without alignment bits.
+    positions: [u16; 1 + LZMA_NUMBER_OF_FULL_DISTANCES - LZMA_END_POSITION_MODEL_INDEX],
+
+    /// Probabilities of the alignment bits.
+    align: [u16; 1 << LZMA_NUMBER_OF_ALIGN_BITS],
+
+    /// Length decoder of matches.
+    length_decoder: LzmaLengthDecoder,
+
+    /// Length decoder of repeated matches.
+    rep_length_decoder: LzmaLengthDecoder,
+
+    /// State.
+    state: usize,
+
+    /// Most recent match distances.
+    reps: [usize; 4],
+}
+
+impl LzmaDecoder {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self {
+            number_of_literal_context_bits: 0,
+            number_of_literal_position_bits: 0,
+            number_of_position_bits: 0,
+            dictionary_size: 0,
+            literals: Vec::new(),
+            is_match: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES << 4],
+            is_rep: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES],
+            is_rep_g0: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES],
+            is_rep_g1: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES],
+            is_rep_g2: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES],
+            is_rep0_long: [LZMA_INITIAL_PROBABILITY; LZMA_NUMBER_OF_STATES << 4],
+            position_slots: [LZMA_INITIAL_PROBABILITY;
+                LZMA_NUMBER_OF_LENGTH_TO_POSITION_STATES << 6],
+            positions: [LZMA_INITIAL_PROBABILITY;
+                1 + LZMA_NUMBER_OF_FULL_DISTANCES - LZMA_END_POSITION_MODEL_INDEX],
+            align: [LZMA_INITIAL_PROBABILITY; 1 << LZMA_NUMBER_OF_ALIGN_BITS],
+            length_decoder: LzmaLengthDecoder::new(),
+            rep_length_decoder: LzmaLengthDecoder::new(),
+            state: 0,
+            reps: [0; 4],
+        }
+    }
+
+    /// Sets the properties from the properties byte.
+    pub fn set_properties(&mut self, properties: u8) -> Result<(), ErrorTrace> {
+        if properties >= 9 * 5 * 5 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported properties: 0x{:02x}",
+                properties
+            )));
+        }
+        let mut value: usize = properties as usize;
+
+        self.number_of_literal_context_bits = value % 9;
+        value /= 9;
+        self.number_of_literal_position_bits = value % 5;
+        self.number_of_position_bits = value / 5;
+
+        Ok(())
+    }
+
+    /// Resets the state and probabilities.
+    pub fn reset_state(&mut self) {
+        let number_of_literals: usize =
+            0x300 << (self.number_of_literal_context_bits + self.number_of_literal_position_bits);
+
+        self.literals.clear();
+        self.literals
+            .resize(number_of_literals, LZMA_INITIAL_PROBABILITY);
+
+        self.is_match.fill(LZMA_INITIAL_PROBABILITY);
+        self.is_rep.fill(LZMA_INITIAL_PROBABILITY);
+        self.is_rep_g0.fill(LZMA_INITIAL_PROBABILITY);
+        self.is_rep_g1.fill(LZMA_INITIAL_PROBABILITY);
+        self.is_rep_g2.fill(LZMA_INITIAL_PROBABILITY);
+        self.is_rep0_long.fill(LZMA_INITIAL_PROBABILITY);
+        self.position_slots.fill(LZMA_INITIAL_PROBABILITY);
+        self.positions.fill(LZMA_INITIAL_PROBABILITY);
+        self.align.fill(LZMA_INITIAL_PROBABILITY);
+
+        self.length_decoder = LzmaLengthDecoder::new();
+        self.rep_length_decoder = LzmaLengthDecoder::new();
+
+        self.state = 0;
+        self.reps = [0; 4];
+    }
+
+    /// Decodes a literal.
+    fn decode_literal(
+        &mut self,
+        range_decoder: &mut LzmaRangeDecoder,
+        uncompressed_data: &[u8],
+        uncompressed_data_offset: usize,
+        dictionary_offset: usize,
+    ) -> u8 {
+        let previous_byte: usize = if uncompressed_data_offset > dictionary_offset {
+            uncompressed_data[uncompressed_data_offset - 1] as usize
+        } else {
+            0
+        };
+        let literal_position_mask: usize = (1 << self.number_of_literal_position_bits) - 1;
+        let literal_state: usize = ((uncompressed_data_offset & literal_position_mask)
+            << self.number_of_literal_context_bits)
+            | (previous_byte >> (8 - self.number_of_literal_context_bits));
+
+        let literals_offset: usize = 0x300 * literal_state;
+        let probabilities: &mut [u16] =
+            &mut self.literals[literals_offset..literals_offset + 0x300];
+
+        let mut symbol: usize = 1;
+
+        if self.state >= 7 {
+            let mut match_byte: usize =
+                uncompressed_data[uncompressed_data_offset - self.reps[0] - 1] as usize;
+
+            while symbol < 0x100 {
+                let match_bit: usize = (match_byte >> 7) & 1;
+                match_byte <<= 1;
+
+                let bit: usize = range_decoder
+                    .decode_bit(&mut probabilities[((1 + match_bit) << 8) + symbol])
+                    as usize;
+                symbol = (symbol << 1) | bit;
+
+                if match_bit != bit {
+                    break;
+                }
+            }
+        }
+        while symbol < 0x100 {
+            symbol = (symbol << 1) | range_decoder.decode_bit(&mut probabilities[symbol]) as usize;
+        }
+        (symbol - 0x100) as u8
+    }
+
+    /// Decodes a match distance.
+    fn decode_distance(&mut self, range_decoder: &mut LzmaRangeDecoder, length: usize) -> u32 {
+        let length_state: usize = if length < LZMA_NUMBER_OF_LENGTH_TO_POSITION_STATES - 1 {
+            length
+        } else {
+            LZMA_NUMBER_OF_LENGTH_TO_POSITION_STATES - 1
+        };
+        let tree_offset: usize = length_state << 6;
+        let position_slot: u32 = range_decoder
+            .decode_bit_tree(&mut self.position_slots[tree_offset..tree_offset + 64], 6);
+
+        if position_slot < 4 {
+            return position_slot;
+        }
+        let number_of_direct_bits: usize = ((position_slot >> 1) - 1) as usize;
+        let mut distance: u32 = (2 | (position_slot & 1)) << number_of_direct_bits;
+
+        if (position_slot as usize) < LZMA_END_POSITION_MODEL_INDEX {
+            let positions_offset: usize = (distance - position_slot) as usize;
+
+            distance += range_decoder.decode_reverse_bit_tree(
+                &mut self.positions[positions_offset..],
+                number_of_direct_bits,
+            );
+        } else {
+            distance += range_decoder
+                .decode_direct_bits(number_of_direct_bits - LZMA_NUMBER_OF_ALIGN_BITS)
+                << LZMA_NUMBER_OF_ALIGN_BITS;
+            distance +=
+                range_decoder.decode_reverse_bit_tree(&mut self.align, LZMA_NUMBER_OF_ALIGN_BITS);
+        }
+        distance
+    }
+
+    /// Decodes an end marker.
+    ///
+    /// Used when the end of the uncompressed data was reached, since an end marker can follow
+    /// the last uncompressed byte. Returns true if an end marker was found.
+    pub fn decode_end_marker(
+        &mut self,
+        range_decoder: &mut LzmaRangeDecoder,
+        uncompressed_data_offset: usize,
+    ) -> Result<bool, ErrorTrace> {
+        if range_decoder.overflow {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let position_mask: usize = (1 << self.number_of_position_bits) - 1;
+        let position_state: usize = uncompressed_data_offset & position_mask;
+        let state_index: usize = (self.state << 4) | position_state;
+
+        // An end marker is encoded as a (non-repeated) match with a distance of 0xffffffff.
+        if range_decoder.decode_bit(&mut self.is_match[state_index]) == 0
+            || range_decoder.decode_bit(&mut self.is_rep[self.state]) != 0
+        {
+            return Ok(false);
+        }
+        let match_length: usize = self.length_decoder.decode(range_decoder, position_state);
+
+        self.state = if self.state < 7 { 7 } else { 10 };
+
+        let distance: u32 = self.decode_distance(range_decoder, match_length);
+
+        if range_decoder.overflow {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        Ok(distance == 0xffffffff)
+    }
+
+    /// Decodes compressed data.
+    ///
+    /// Decoding stops when the end of the uncompressed data or an end marker is reached. The
+    /// uncompressed data before the dictionary offset is not referenced. Returns true if an
+    /// end marker was found.
+    pub fn decode(
+        &mut self,
+        range_decoder: &mut LzmaRangeDecoder,
+        uncompressed_data: &mut [u8],
+        uncompressed_data_offset: &mut usize,
+        uncompressed_data_end_offset: usize,
+        dictionary_offset: usize,
+    ) -> Result<bool, ErrorTrace> {
+        let position_mask: usize = (1 << self.number_of_position_bits) - 1;
+        let mut data_offset: usize = *uncompressed_data_offset;
+
+        while data_offset < uncompressed_data_end_offset {
+            if range_decoder.overflow {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let position_state: usize = data_offset & position_mask;
+            let state_index: usize = (self.state << 4) | position_state;
+
+            if range_decoder.decode_bit(&mut self.is_match[state_index]) == 0 {
+                uncompressed_data[data_offset] = self.decode_literal(
+                    range_decoder,
+                    uncompressed_data,
+                    data_offset,
+                    dictionary_offset,
+                );
+                data_offset += 1;
+
+                self.state = if self.state < 4 {
+                    0
+                } else if self.state < 10 {
+                    self.state - 3
+                } else {
+                    self.state - 6
+                };
+                continue;
+            }
+            let mut match_length: usize;
+
+            if range_decoder.decode_bit(&mut self.is_rep[self.state]) != 0 {
+                if data_offset == dictionary_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid repeated match without preceding data"
+                    ));
+                }
+                if range_decoder.decode_bit(&mut self.is_rep_g0[self.state]) == 0 {
+                    if range_decoder.decode_bit(&mut self.is_rep0_long[state_index]) == 0 {
+                        // Short repeated match of a single byte.
+                        self.state = if self.state < 7 { 9 } else { 11 };
+
+                        uncompressed_data[data_offset] =
+                            uncompressed_data[data_offset - self.reps[0] - 1];
+                        data_offset += 1;
+
+                        continue;
+                    }
+                } else {
+                    let distance: usize;
+
+                    if range_decoder.decode_bit(&mut self.is_rep_g1[self.state]) == 0 {
+                        distance = self.reps[1];
+                    } else {
+                        if range_decoder.decode_bit(&mut self.is_rep_g2[self.state]) == 0 {
+                            distance = self.reps[2];
+                        } else {
+                            distance = self.reps[3];
+                            self.reps[3] = self.reps[2];
+                        }
+                        self.reps[2] = self.reps[1];
+                    }
+                    self.reps[1] = self.reps[0];
+                    self.reps[0] = distance;
+                }
+                match_length = self
+                    .rep_length_decoder
+                    .decode(range_decoder, position_state);
+
+                self.state = if self.state < 7 { 8 } else { 11 };
+            } else {
+                self.reps[3] = self.reps[2];
+                self.reps[2] = self.reps[1];
+                self.reps[1] = self.reps[0];
+
+                match_length = self.length_decoder.decode(range_decoder, position_state);
+
+                self.state = if self.state < 7 { 7 } else { 10 };
+
+                let distance: u32 = self.decode_distance(range_decoder, match_length);
+
+                if distance == 0xffffffff {
+                    *uncompressed_data_offset = data_offset;
+
+                    return Ok(true);
+                }
+                self.reps[0] = distance as usize;
+            }
+            match_length += LZMA_MINIMUM_MATCH_LENGTH;
+
+            if self.reps[0] >= data_offset - dictionary_offset
+                || self.reps[0] >= self.dictionary_size as usize
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid match distance: {} value out of bounds",
+                    self.reps[0] + 1
+                )));
+            }
+            if match_length > uncompressed_data_end_offset - data_offset {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid match length: {} value out of bounds",
+                    match_length
+                )));
+            }
+            let mut match_offset: usize = data_offset - self.reps[0] - 1;
+
+            for _ in 0..match_length {
+                uncompressed_data[data_offset] = uncompressed_data[match_offset];
+
+                data_offset += 1;
+                match_offset += 1;
+            }
+        }
+        if range_decoder.overflow {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        *uncompressed_data_offset = data_offset;
+
+        Ok(false)
+    }
+}
+
+/// Context for decompressing LZMA compressed data.
+///
+/// The compressed data is expected to start with the 13-byte LZMA data header used by the
+/// .lzma (LZMA alone) format.
+pub struct LzmaContext {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: usize,
+}
+
+impl LzmaContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Decompress data.
+    pub fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        if compressed_data.len() < LZMA_DATA_HEADER_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let properties: u8 = compressed_data[0];
+        let dictionary_size: u32 = bytes_to_u32_le!(compressed_data, 1);
+        let stored_uncompressed_data_size: u64 = bytes_to_u64_le!(compressed_data, 5);
+
+        if self.mediator.debug_output {
+            let mut string_parts: Vec<String> = Vec::new();
+            string_parts.push(format!("LzmaDataHeader {{\n"));
+            string_parts.push(format!("    properties: 0x{:02x},\n", properties));
+            string_parts.push(format!("    dictionary_size: {},\n", dictionary_size));
+            string_parts.push(format!(
+                "    uncompressed_data_size: {},\n",
+                stored_uncompressed_data_size as i64
+            ));
+            string_parts.push(format!("}}\n\n"));
+
+            self.mediator.debug_print(string_parts.join(""));
+        }
+        let mut uncompressed_data_end_offset: usize = uncompressed_data.len();
+
+        if stored_uncompressed_data_size != 0xffffffffffffffff {
+            if stored_uncompressed_data_size > uncompressed_data_end_offset as u64 {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid uncompressed data value too small"
+                ));
+            }
+            uncompressed_data_end_offset = stored_uncompressed_data_size as usize;
+        }
+        let mut lzma_decoder: LzmaDecoder = LzmaDecoder::new();
+        lzma_decoder.set_properties(properties)?;
+        lzma_decoder.dictionary_size = dictionary_size.max(4096);
+        lzma_decoder.reset_state();
+
+        let mut range_decoder: LzmaRangeDecoder =
+            LzmaRangeDecoder::new(compressed_data, LZMA_DATA_HEADER_SIZE)?;
+
+        let mut uncompressed_data_offset: usize = 0;
+        let mut end_marker_found: bool = lzma_decoder.decode(
+            &mut range_decoder,
+            uncompressed_data,
+            &mut uncompressed_data_offset,
+            uncompressed_data_end_offset,
+            0,
+        )?;
+        // When the uncompressed data size is not stored the end marker can directly follow
+        // the last uncompressed byte that fills the uncompressed data.
+        if !end_marker_found
+            && stored_uncompressed_data_size == 0xffffffffffffffff
+            && uncompressed_data_offset == uncompressed_data_end_offset
+        {
+            end_marker_found = match lzma_decoder
+                .decode_end_marker(&mut range_decoder, uncompressed_data_offset)
+            {
+                Ok(end_marker_found) => end_marker_found,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decode end marker");
+                    return Err(error);
+                }
+            };
+        }
+        if !end_marker_found && stored_uncompressed_data_size == 0xffffffffffffffff {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid uncompressed data value too small"
+            ));
+        }
+        if end_marker_found && !range_decoder.is_finished() {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid range decoder code value after end marker"
+            ));
+        }
+        self.uncompressed_data_size = uncompressed_data_offset;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x5d, 0x00, 0x00, 0x80, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00,
+            0x25, 0x99, 0x4a, 0x46, 0x23, 0x5e, 0x8d, 0x74, 0xcf, 0xd7, 0x5c, 0xc1, 0xac, 0x12,
+            0x84, 0x33, 0xb2, 0x21, 0x81, 0x34, 0xb4, 0x8d, 0xa8, 0xff, 0x0b, 0x93, 0xff, 0xff,
+            0x23, 0xc4, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_range_decoder_new() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+
+        let range_decoder: LzmaRangeDecoder = LzmaRangeDecoder::new(&test_data, 13)?;
+        assert_eq!(range_decoder.data_offset, 18);
+        assert!(!range_decoder.is_finished());
+
+        let result: Result<LzmaRangeDecoder, ErrorTrace> = LzmaRangeDecoder::new(&test_data, 43);
+        assert!(result.is_err());
+
+        test_data[13] = 0xff;
+        let result: Result<LzmaRangeDecoder, ErrorTrace> = LzmaRangeDecoder::new(&test_data, 13);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_properties() -> Result<(), ErrorTrace> {
+        let mut lzma_decoder: LzmaDecoder = LzmaDecoder::new();
+        lzma_decoder.set_properties(0x5d)?;
+
+        assert_eq!(lzma_decoder.number_of_literal_context_bits, 3);
+        assert_eq!(lzma_decoder.number_of_literal_position_bits, 0);
+        assert_eq!(lzma_decoder.number_of_position_bits, 2);
+
+        let result: Result<(), ErrorTrace> = lzma_decoder.set_properties(0xe1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress() -> Result<(), ErrorTrace> {
+        let mut test_context: LzmaContext = LzmaContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 128];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 76);
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4);
+        assert_eq!(
+            &uncompressed_data[0..test_context.uncompressed_data_size],
+            &expected_data
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_exact_uncompressed_data_size() -> Result<(), ErrorTrace> {
+        let mut test_context: LzmaContext = LzmaContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 76];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 76);
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4);
+        assert_eq!(uncompressed_data, expected_data);
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 75];
+
+        let result: Result<(), ErrorTrace> =
+            test_context.decompress(&test_data, &mut uncompressed_data);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_uncompressed_data_too_small() {
+        let mut test_context: LzmaContext = LzmaContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 64];
+
+        let result: Result<(), ErrorTrace> =
+            test_context.decompress(&test_data, &mut uncompressed_data);
+        assert!(result.is_err());
+    }
+}
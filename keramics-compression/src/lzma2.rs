@@ -0,0 +1,311 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! LZMA2 decompression.
+//!
+//! Provides decompression support for LZMA2 compressed data.
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_types::bytes_to_u16_be;
+
+use super::lzma::{LzmaDecoder, LzmaRangeDecoder};
+
+/// Context for decompressing LZMA2 compressed data.
+pub struct Lzma2Context {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// LZMA decoder.
+    lzma_decoder: LzmaDecoder,
+
+    /// Dictionary size.
+    dictionary_size: u32,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: usize,
+}
+
+impl Lzma2Context {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            lzma_decoder: LzmaDecoder::new(),
+            dictionary_size: 0xffffffff,
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Sets the dictionary size from the LZMA2 properties byte.
+    pub fn set_properties(&mut self, properties: u8) -> Result<(), ErrorTrace> {
+        if properties > 40 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported properties: 0x{:02x}",
+                properties
+            )));
+        }
+        self.dictionary_size = if properties == 40 {
+            0xffffffff
+        } else {
+            (2 | (properties as u32 & 1)) << ((properties / 2) + 11)
+        };
+        Ok(())
+    }
+
+    /// Decompress data.
+    pub fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        let mut compressed_data_offset: usize = 0;
+        let mut uncompressed_data_offset: usize = 0;
+
+        self.decompress_chunks(
+            compressed_data,
+            &mut compressed_data_offset,
+            uncompressed_data,
+            &mut uncompressed_data_offset,
+        )?;
+        self.uncompressed_data_size = uncompressed_data_offset;
+
+        Ok(())
+    }
+
+    /// Decompress chunks until the end of stream chunk.
+    pub(super) fn decompress_chunks(
+        &mut self,
+        compressed_data: &[u8],
+        compressed_data_offset: &mut usize,
+        uncompressed_data: &mut [u8],
+        uncompressed_data_offset: &mut usize,
+    ) -> Result<(), ErrorTrace> {
+        let compressed_data_size: usize = compressed_data.len();
+        let uncompressed_data_size: usize = uncompressed_data.len();
+
+        let mut data_offset: usize = *compressed_data_offset;
+        let mut dictionary_offset: usize = *uncompressed_data_offset;
+        let mut dictionary_reset_required: bool = true;
+        let mut properties_required: bool = true;
+
+        self.lzma_decoder.dictionary_size = self.dictionary_size;
+
+        loop {
+            if data_offset >= compressed_data_size {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let control_byte: u8 = compressed_data[data_offset];
+            data_offset += 1;
+
+            if control_byte == 0x00 {
+                break;
+            }
+            let header_size: usize = if control_byte >= 0x80 {
+                if control_byte >= 0xc0 { 5 } else { 4 }
+            } else {
+                2
+            };
+            if header_size > compressed_data_size - data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "Lzma2ChunkHeader data of size: {} at offset: {} (0x{:08x})\n",
+                    header_size + 1,
+                    data_offset - 1,
+                    data_offset - 1
+                ));
+                self.mediator.debug_print_data(
+                    &compressed_data[data_offset - 1..data_offset + header_size],
+                    true,
+                );
+            }
+            if control_byte < 0x80 {
+                // Uncompressed chunk with (0x01) or without (0x02) dictionary reset.
+                match control_byte {
+                    0x01 => {
+                        dictionary_offset = *uncompressed_data_offset;
+                        dictionary_reset_required = false;
+                        properties_required = true;
+                    }
+                    0x02 => {}
+                    _ => {
+                        return Err(keramics_core::error_trace_new!(format!(
+                            "Unsupported control byte: 0x{:02x}",
+                            control_byte
+                        )));
+                    }
+                }
+                if dictionary_reset_required {
+                    return Err(keramics_core::error_trace_new!("Missing dictionary reset"));
+                }
+                let chunk_size: usize = bytes_to_u16_be!(compressed_data, data_offset) as usize + 1;
+                data_offset += 2;
+
+                if chunk_size > compressed_data_size - data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                if chunk_size > uncompressed_data_size - *uncompressed_data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid uncompressed data value too small"
+                    ));
+                }
+                let uncompressed_data_end_offset: usize = *uncompressed_data_offset + chunk_size;
+
+                uncompressed_data[*uncompressed_data_offset..uncompressed_data_end_offset]
+                    .copy_from_slice(&compressed_data[data_offset..data_offset + chunk_size]);
+
+                data_offset += chunk_size;
+                *uncompressed_data_offset = uncompressed_data_end_offset;
+
+                continue;
+            }
+            let chunk_uncompressed_data_size: usize = ((((control_byte & 0x1f) as usize) << 16)
+                | bytes_to_u16_be!(compressed_data, data_offset) as usize)
+                + 1;
+            let chunk_compressed_data_size: usize =
+                bytes_to_u16_be!(compressed_data, data_offset + 2) as usize + 1;
+            data_offset += 4;
+
+            // Reset modes: 0 = nothing, 1 = state reset, 2 = state reset and new properties,
+            // 3 = state reset, new properties and dictionary reset.
+            let reset_mode: u8 = (control_byte >> 5) & 0x03;
+
+            if reset_mode == 3 {
+                dictionary_offset = *uncompressed_data_offset;
+                dictionary_reset_required = false;
+            } else if dictionary_reset_required {
+                return Err(keramics_core::error_trace_new!("Missing dictionary reset"));
+            }
+            if reset_mode >= 2 {
+                self.lzma_decoder
+                    .set_properties(compressed_data[data_offset])?;
+                data_offset += 1;
+
+                properties_required = false;
+            } else if properties_required {
+                return Err(keramics_core::error_trace_new!("Missing properties"));
+            }
+            if reset_mode >= 1 {
+                self.lzma_decoder.reset_state();
+            }
+            if chunk_compressed_data_size > compressed_data_size - data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            if chunk_uncompressed_data_size > uncompressed_data_size - *uncompressed_data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid uncompressed data value too small"
+                ));
+            }
+            let chunk_end_offset: usize = data_offset + chunk_compressed_data_size;
+
+            let mut range_decoder: LzmaRangeDecoder =
+                LzmaRangeDecoder::new(&compressed_data[..chunk_end_offset], data_offset)?;
+
+            let uncompressed_data_end_offset: usize =
+                *uncompressed_data_offset + chunk_uncompressed_data_size;
+
+            let end_marker_found: bool = self.lzma_decoder.decode(
+                &mut range_decoder,
+                uncompressed_data,
+                uncompressed_data_offset,
+                uncompressed_data_end_offset,
+                dictionary_offset,
+            )?;
+            if end_marker_found {
+                return Err(keramics_core::error_trace_new!(
+                    "Unsupported end marker in LZMA2 chunk"
+                ));
+            }
+            if range_decoder.data_offset != chunk_end_offset || !range_decoder.is_finished() {
+                return Err(keramics_core::error_trace_new!(
+                    "Mismatch between LZMA2 chunk compressed data size and decoded data"
+                ));
+            }
+            data_offset = chunk_end_offset;
+        }
+        *compressed_data_offset = data_offset;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0xe0, 0x00, 0x4b, 0x00, 0x1a, 0x5d, 0x00, 0x25, 0x99, 0x4a, 0x46, 0x23, 0x5e, 0x8d,
+            0x74, 0xcf, 0xd7, 0x5c, 0xc1, 0xac, 0x12, 0x84, 0x33, 0xb2, 0x21, 0x81, 0x34, 0xb4,
+            0x8d, 0xa7, 0x2a, 0xa0, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_set_properties() -> Result<(), ErrorTrace> {
+        let mut test_context: Lzma2Context = Lzma2Context::new();
+
+        test_context.set_properties(0x16)?;
+        assert_eq!(test_context.dictionary_size, 8388608);
+
+        test_context.set_properties(0x28)?;
+        assert_eq!(test_context.dictionary_size, 0xffffffff);
+
+        let result: Result<(), ErrorTrace> = test_context.set_properties(0x29);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress() -> Result<(), ErrorTrace> {
+        let mut test_context: Lzma2Context = Lzma2Context::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 128];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 76);
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4);
+        assert_eq!(
+            &uncompressed_data[0..test_context.uncompressed_data_size],
+            &expected_data
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_uncompressed_chunk() -> Result<(), ErrorTrace> {
+        let mut test_context: Lzma2Context = Lzma2Context::new();
+
+        let test_data: [u8; 12] = [
+            0x01, 0x00, 0x07, 0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73, 0x00,
+        ];
+        let mut uncompressed_data: Vec<u8> = vec![0; 16];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 8);
+        assert_eq!(&uncompressed_data[0..8], b"Keramics");
+
+        Ok(())
+    }
+}
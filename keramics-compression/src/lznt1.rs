@@ -11,15 +11,24 @@
  * under the License.
  */
 
-//! LZNT1 decompression.
+//! LZNT1 compression and decompression.
 //!
-//! Provides decompression support for LZNT1 compressed data.
+//! Provides compression and decompression support for LZNT1 compressed data.
 
 use keramics_core::ErrorTrace;
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_layout_map::LayoutMap;
 use keramics_types::bytes_to_u16_le;
 
+/// Uncompressed data size of a LZNT1 block.
+const LZNT1_BLOCK_SIZE: usize = 4096;
+
+/// Number of bits of the hash used by the compressor to find matches.
+const LZNT1_HASH_BITS: usize = 12;
+
+/// Maximum number of match candidates tested by the compressor per position.
+const LZNT1_MAXIMUM_CHAIN_LENGTH: usize = 256;
+
 #[derive(LayoutMap)]
 #[layout_map(
     structure(
@@ -325,6 +334,143 @@ impl Lznt1Context {
     }
 }
 
+/// Context for compressing data using LZNT1.
+pub struct Lznt1CompressionContext {}
+
+impl Lznt1CompressionContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Compress data.
+    ///
+    /// The compressed data is appended to `compressed_data`.
+    pub fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        for block_data in uncompressed_data.chunks(LZNT1_BLOCK_SIZE) {
+            let block_compressed_data: Vec<u8> = Self::compress_block(block_data);
+
+            // Blocks that do not compress are stored uncompressed.
+            if block_compressed_data.len() < block_data.len() {
+                let block_header: u16 = 0xb000 | (block_compressed_data.len() - 1) as u16;
+
+                compressed_data.extend_from_slice(&block_header.to_le_bytes());
+                compressed_data.extend_from_slice(&block_compressed_data);
+            } else {
+                let block_header: u16 = 0x3000 | (block_data.len() - 1) as u16;
+
+                compressed_data.extend_from_slice(&block_header.to_le_bytes());
+                compressed_data.extend_from_slice(block_data);
+            }
+        }
+        Ok(())
+    }
+
+    /// Compress a block.
+    fn compress_block(block_data: &[u8]) -> Vec<u8> {
+        let block_size: usize = block_data.len();
+
+        let mut hash_heads: Vec<usize> = vec![usize::MAX; 1 << LZNT1_HASH_BITS];
+        let mut hash_chains: Vec<usize> = vec![usize::MAX; block_size];
+
+        let mut compressed_data: Vec<u8> = Vec::new();
+        let mut uncompressed_data_offset: usize = 0;
+
+        while uncompressed_data_offset < block_size {
+            let compression_flags_offset: usize = compressed_data.len();
+            compressed_data.push(0);
+
+            for bit_index in 0..8 {
+                if uncompressed_data_offset >= block_size {
+                    break;
+                }
+                // The compression tuple match size mask and distance shift are dependent on the uncompressed data offset.
+                let mut compression_tuple_threshold: usize = 16;
+                let mut compression_tuple_distance_shift: u16 = 12;
+                let mut compression_tuple_match_size_mask: u16 = 0x0fff;
+
+                while uncompressed_data_offset > compression_tuple_threshold {
+                    compression_tuple_distance_shift -= 1;
+                    compression_tuple_match_size_mask >>= 1;
+                    compression_tuple_threshold <<= 1;
+                }
+                let maximum_match_size: usize = usize::min(
+                    compression_tuple_match_size_mask as usize + 3,
+                    block_size - uncompressed_data_offset,
+                );
+                let mut match_size: usize = 0;
+                let mut match_distance: usize = 0;
+
+                if maximum_match_size >= 3 {
+                    let mut match_candidate_offset: usize =
+                        hash_heads[Self::get_hash(block_data, uncompressed_data_offset)];
+
+                    for _ in 0..LZNT1_MAXIMUM_CHAIN_LENGTH {
+                        if match_candidate_offset >= uncompressed_data_offset {
+                            break;
+                        }
+                        let mut candidate_match_size: usize = 0;
+
+                        while candidate_match_size < maximum_match_size
+                            && block_data[match_candidate_offset + candidate_match_size]
+                                == block_data[uncompressed_data_offset + candidate_match_size]
+                        {
+                            candidate_match_size += 1;
+                        }
+                        if candidate_match_size > match_size {
+                            match_size = candidate_match_size;
+                            match_distance = uncompressed_data_offset - match_candidate_offset;
+
+                            if match_size == maximum_match_size {
+                                break;
+                            }
+                        }
+                        match_candidate_offset = hash_chains[match_candidate_offset];
+                    }
+                }
+                let token_size: usize = if match_size >= 3 {
+                    let compression_tuple: u16 = (((match_distance - 1) as u16)
+                        << compression_tuple_distance_shift)
+                        | (match_size - 3) as u16;
+
+                    compressed_data.extend_from_slice(&compression_tuple.to_le_bytes());
+                    compressed_data[compression_flags_offset] |= 1 << bit_index;
+
+                    match_size
+                } else {
+                    compressed_data.push(block_data[uncompressed_data_offset]);
+
+                    1
+                };
+                for _ in 0..token_size {
+                    if uncompressed_data_offset + 3 <= block_size {
+                        let hash: usize = Self::get_hash(block_data, uncompressed_data_offset);
+
+                        hash_chains[uncompressed_data_offset] = hash_heads[hash];
+                        hash_heads[hash] = uncompressed_data_offset;
+                    }
+                    uncompressed_data_offset += 1;
+                }
+            }
+        }
+        compressed_data
+    }
+
+    /// Calculates the hash of the 3 bytes at the data offset.
+    #[inline(always)]
+    fn get_hash(data: &[u8], data_offset: usize) -> usize {
+        let hash: usize = ((data[data_offset] as usize) << 8)
+            ^ ((data[data_offset + 1] as usize) << 4)
+            ^ (data[data_offset + 2] as usize);
+
+        hash & ((1 << LZNT1_HASH_BITS) - 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -944,4 +1090,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_compress() -> Result<(), ErrorTrace> {
+        let mut test_context: Lznt1CompressionContext = Lznt1CompressionContext::new();
+
+        let test_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert!(compressed_data.len() < 8192);
+
+        let mut decompression_context: Lznt1Context = Lznt1Context::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 11358];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 11358);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_uncompressed_block() -> Result<(), ErrorTrace> {
+        let mut test_context: Lznt1CompressionContext = Lznt1CompressionContext::new();
+
+        // Pseudo-random data that cannot be compressed.
+        let mut test_data: Vec<u8> = vec![0; 5000];
+        let mut seed: u32 = 0x12345678;
+
+        for byte_value in test_data.iter_mut() {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            *byte_value = (seed >> 16) as u8;
+        }
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert_eq!(compressed_data.len(), 5004);
+        assert_eq!(&compressed_data[0..2], [0xff, 0x3f]);
+
+        let mut decompression_context: Lznt1Context = Lznt1Context::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 5000];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 5000);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_repetitive_data() -> Result<(), ErrorTrace> {
+        let mut test_context: Lznt1CompressionContext = Lznt1CompressionContext::new();
+
+        let test_data: Vec<u8> = vec![0; 65536];
+
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert!(compressed_data.len() < 256);
+
+        let mut decompression_context: Lznt1Context = Lznt1Context::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0xff; 65536];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 65536);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
 }
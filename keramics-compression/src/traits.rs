@@ -11,6 +11,8 @@
  * under the License.
  */
 
+use keramics_core::ErrorTrace;
+
 /// Bitstream trait.
 pub trait Bitstream {
     /// Retrieves a bit value.
@@ -19,3 +21,44 @@ pub trait Bitstream {
     /// Skips a number of bits.
     fn skip_bits(&mut self, number_of_bits: usize);
 }
+
+/// Compressor trait.
+pub trait Compressor {
+    /// Compress data.
+    ///
+    /// The compressed data is appended to `compressed_data`.
+    fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace>;
+}
+
+/// Decompressor trait.
+pub trait Decompressor {
+    /// Decompress data.
+    fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace>;
+
+    /// Retrieves the size of the most recently decompressed data.
+    fn get_uncompressed_data_size(&self) -> usize;
+}
+
+/// Stream decompressor trait.
+pub trait StreamDecompressor {
+    /// Decompresses a chunk of compressed data.
+    ///
+    /// The uncompressed data that can be decoded from the compressed data provided so far is
+    /// appended to the uncompressed data.
+    fn decompress_chunk(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace>;
+
+    /// Determines if the end of the compressed stream was reached.
+    fn is_end_of_stream(&self) -> bool;
+}
@@ -0,0 +1,832 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! XZ decompression.
+//!
+//! Provides decompression support for XZ compressed data.
+
+use keramics_checksums::{ReversedCrc32Context, ReversedCrc64Context};
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_hashes::{DigestHashContext, Sha256Context};
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+use super::lzma2::Lzma2Context;
+
+/// XZ stream header signature.
+const XZ_STREAM_HEADER_SIGNATURE: [u8; 6] = [0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00];
+
+/// XZ stream footer signature.
+const XZ_STREAM_FOOTER_SIGNATURE: [u8; 2] = [0x59, 0x5a]; // YZ
+
+/// XZ LZMA2 filter identifier.
+const XZ_FILTER_LZMA2: u64 = 0x21;
+
+/// XZ no check type.
+const XZ_CHECK_TYPE_NONE: u8 = 0x00;
+
+/// XZ CRC-32 check type.
+const XZ_CHECK_TYPE_CRC32: u8 = 0x01;
+
+/// XZ CRC-64 check type.
+const XZ_CHECK_TYPE_CRC64: u8 = 0x04;
+
+/// XZ SHA-256 check type.
+const XZ_CHECK_TYPE_SHA256: u8 = 0x0a;
+
+/// Sizes of the checks per check type.
+const XZ_CHECK_SIZES: [usize; 16] = [0, 4, 4, 4, 8, 8, 8, 16, 16, 16, 32, 32, 32, 64, 64, 64];
+
+/// Reads a variable-size integer.
+fn read_variable_size_integer(data: &[u8], data_offset: &mut usize) -> Result<u64, ErrorTrace> {
+    let data_size: usize = data.len();
+    let mut value_64bit: u64 = 0;
+
+    for byte_index in 0..9 {
+        if *data_offset >= data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid variable-size integer data value too small"
+            ));
+        }
+        let byte_value: u8 = data[*data_offset];
+        *data_offset += 1;
+
+        if byte_index > 0 && byte_value == 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported variable-size integer with trailing zero byte"
+            ));
+        }
+        value_64bit |= ((byte_value & 0x7f) as u64) << (7 * byte_index);
+
+        if byte_value & 0x80 == 0 {
+            return Ok(value_64bit);
+        }
+    }
+    Err(keramics_core::error_trace_new!(
+        "Invalid variable-size integer value out of bounds"
+    ))
+}
+
+/// Calculates the XZ CRC-32 of data.
+fn calculate_crc32(data: &[u8]) -> u32 {
+    let mut crc32_context: ReversedCrc32Context = ReversedCrc32Context::new(0xedb88320, 0);
+    crc32_context.update(data);
+    crc32_context.finalize()
+}
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "signature", data_type = "[u8; 6]"),
+        field(name = "stream_flags", data_type = "u16", format = "hex"),
+        field(name = "checksum", data_type = "u32", format = "hex"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Stream header used by XZ compressed data.
+struct XzStreamHeader {
+    /// Check type.
+    pub check_type: u8,
+}
+
+impl XzStreamHeader {
+    /// Creates a new stream header.
+    pub fn new() -> Self {
+        Self { check_type: 0 }
+    }
+
+    /// Reads the stream header.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 12 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ stream header data size"
+            ));
+        }
+        if data[0..6] != XZ_STREAM_HEADER_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ stream header signature"
+            ));
+        }
+        let stored_checksum: u32 = bytes_to_u32_le!(data, 8);
+        let calculated_checksum: u32 = calculate_crc32(&data[6..8]);
+
+        if stored_checksum != calculated_checksum {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                stored_checksum, calculated_checksum
+            )));
+        }
+        if data[6] != 0 || data[7] & 0xf0 != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported stream flags: 0x{:02x}{:02x}",
+                data[7], data[6]
+            )));
+        }
+        self.check_type = data[7];
+
+        Ok(())
+    }
+}
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "checksum", data_type = "u32", format = "hex"),
+        field(name = "backward_size", data_type = "u32"),
+        field(name = "stream_flags", data_type = "u16", format = "hex"),
+        field(name = "signature", data_type = "[u8; 2]", format = "char"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Stream footer used by XZ compressed data.
+struct XzStreamFooter {
+    /// Index size.
+    pub index_size: usize,
+
+    /// Check type.
+    pub check_type: u8,
+}
+
+impl XzStreamFooter {
+    /// Creates a new stream footer.
+    pub fn new() -> Self {
+        Self {
+            index_size: 0,
+            check_type: 0,
+        }
+    }
+
+    /// Reads the stream footer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 12 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ stream footer data size"
+            ));
+        }
+        if data[10..12] != XZ_STREAM_FOOTER_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ stream footer signature"
+            ));
+        }
+        let stored_checksum: u32 = bytes_to_u32_le!(data, 0);
+        let calculated_checksum: u32 = calculate_crc32(&data[4..10]);
+
+        if stored_checksum != calculated_checksum {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                stored_checksum, calculated_checksum
+            )));
+        }
+        let backward_size: u32 = bytes_to_u32_le!(data, 4);
+        self.index_size = ((backward_size as usize) + 1) * 4;
+        self.check_type = data[9];
+
+        Ok(())
+    }
+}
+
+/// Block header used by XZ compressed data.
+struct XzBlockHeader {
+    /// Header size.
+    pub header_size: usize,
+
+    /// Compressed size.
+    pub compressed_size: Option<u64>,
+
+    /// Uncompressed size.
+    pub uncompressed_size: Option<u64>,
+
+    /// LZMA2 properties.
+    pub lzma2_properties: u8,
+}
+
+impl XzBlockHeader {
+    /// Creates a new block header.
+    pub fn new() -> Self {
+        Self {
+            header_size: 0,
+            compressed_size: None,
+            uncompressed_size: None,
+            lzma2_properties: 0,
+        }
+    }
+
+    /// Reads the block header.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 1 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ block header data size"
+            ));
+        }
+        self.header_size = ((data[0] as usize) + 1) * 4;
+
+        if self.header_size > data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported XZ block header data size"
+            ));
+        }
+        let checksum_offset: usize = self.header_size - 4;
+        let stored_checksum: u32 = bytes_to_u32_le!(data, checksum_offset);
+        let calculated_checksum: u32 = calculate_crc32(&data[0..checksum_offset]);
+
+        if stored_checksum != calculated_checksum {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                stored_checksum, calculated_checksum
+            )));
+        }
+        let block_flags: u8 = data[1];
+
+        if block_flags & 0x3c != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported block flags: 0x{:02x}",
+                block_flags
+            )));
+        }
+        let header_data: &[u8] = &data[0..checksum_offset];
+        let mut data_offset: usize = 2;
+
+        self.compressed_size = if block_flags & 0x40 != 0 {
+            Some(read_variable_size_integer(header_data, &mut data_offset)?)
+        } else {
+            None
+        };
+        self.uncompressed_size = if block_flags & 0x80 != 0 {
+            Some(read_variable_size_integer(header_data, &mut data_offset)?)
+        } else {
+            None
+        };
+        let number_of_filters: u8 = (block_flags & 0x03) + 1;
+
+        if number_of_filters != 1 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported number of filters: {}",
+                number_of_filters
+            )));
+        }
+        let filter_identifier: u64 = read_variable_size_integer(header_data, &mut data_offset)?;
+        let properties_size: u64 = read_variable_size_integer(header_data, &mut data_offset)?;
+
+        if filter_identifier != XZ_FILTER_LZMA2 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported filter: 0x{:02x}",
+                filter_identifier
+            )));
+        }
+        if properties_size != 1 || data_offset >= checksum_offset {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported LZMA2 filter properties size: {}",
+                properties_size
+            )));
+        }
+        self.lzma2_properties = header_data[data_offset];
+        data_offset += 1;
+
+        if header_data[data_offset..]
+            .iter()
+            .any(|byte_value| *byte_value != 0)
+        {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported block header padding"
+            ));
+        }
+        let mediator = Mediator::current();
+        if mediator.debug_output {
+            let mut string_parts: Vec<String> = Vec::new();
+            string_parts.push(format!("XzBlockHeader {{\n"));
+            string_parts.push(format!("    header_size: {},\n", self.header_size));
+            string_parts.push(format!("    block_flags: 0x{:02x},\n", block_flags));
+            string_parts.push(format!(
+                "    compressed_size: {:?},\n",
+                self.compressed_size
+            ));
+            string_parts.push(format!(
+                "    uncompressed_size: {:?},\n",
+                self.uncompressed_size
+            ));
+            string_parts.push(format!(
+                "    filter_identifier: 0x{:02x},\n",
+                filter_identifier
+            ));
+            string_parts.push(format!(
+                "    lzma2_properties: 0x{:02x},\n",
+                self.lzma2_properties
+            ));
+            string_parts.push(format!("    checksum: 0x{:08x},\n", stored_checksum));
+            string_parts.push(format!("}}\n\n"));
+
+            mediator.debug_print(string_parts.join(""));
+        }
+        Ok(())
+    }
+}
+
+/// Context for decompressing XZ compressed data.
+pub struct XzContext {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: usize,
+}
+
+impl XzContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Decompress data.
+    pub fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        let compressed_data_size: usize = compressed_data.len();
+        let mut compressed_data_offset: usize = 0;
+        let mut uncompressed_data_offset: usize = 0;
+
+        while compressed_data_offset < compressed_data_size {
+            match self.decompress_stream(
+                compressed_data,
+                &mut compressed_data_offset,
+                uncompressed_data,
+                &mut uncompressed_data_offset,
+            ) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decompress stream");
+                    return Err(error);
+                }
+            }
+            // Stream padding consists of a multiple of 4 zero bytes.
+            while compressed_data_size - compressed_data_offset >= 4
+                && compressed_data[compressed_data_offset..compressed_data_offset + 4] == [0; 4]
+            {
+                compressed_data_offset += 4;
+            }
+            if compressed_data_offset < compressed_data_size
+                && !compressed_data[compressed_data_offset..]
+                    .starts_with(&XZ_STREAM_HEADER_SIGNATURE)
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported data after stream at offset: {} (0x{:08x})",
+                    compressed_data_offset, compressed_data_offset
+                )));
+            }
+        }
+        self.uncompressed_data_size = uncompressed_data_offset;
+
+        Ok(())
+    }
+
+    /// Decompress a stream.
+    fn decompress_stream(
+        &mut self,
+        compressed_data: &[u8],
+        compressed_data_offset: &mut usize,
+        uncompressed_data: &mut [u8],
+        uncompressed_data_offset: &mut usize,
+    ) -> Result<(), ErrorTrace> {
+        let compressed_data_size: usize = compressed_data.len();
+        let mut data_offset: usize = *compressed_data_offset;
+
+        let mut stream_header: XzStreamHeader = XzStreamHeader::new();
+
+        if self.mediator.debug_output && compressed_data_size - data_offset >= 12 {
+            self.mediator.debug_print(format!(
+                "XzStreamHeader data of size: 12 at offset: {} (0x{:08x})\n",
+                data_offset, data_offset
+            ));
+            self.mediator
+                .debug_print_data(&compressed_data[data_offset..data_offset + 12], true);
+            self.mediator.debug_print(XzStreamHeader::debug_read_data(
+                &compressed_data[data_offset..data_offset + 12],
+            ));
+        }
+        stream_header.read_data(&compressed_data[data_offset..])?;
+        data_offset += 12;
+
+        let check_size: usize = XZ_CHECK_SIZES[stream_header.check_type as usize];
+
+        // Unpadded and uncompressed sizes of the blocks, used to verify the index.
+        let mut block_sizes: Vec<(u64, u64)> = Vec::new();
+
+        loop {
+            if data_offset >= compressed_data_size {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            // An index indicator of 0 marks the start of the index.
+            if compressed_data[data_offset] == 0x00 {
+                break;
+            }
+            let block_offset: usize = data_offset;
+
+            let mut block_header: XzBlockHeader = XzBlockHeader::new();
+
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "XzBlockHeader at offset: {} (0x{:08x})\n",
+                    block_offset, block_offset
+                ));
+            }
+            match block_header.read_data(&compressed_data[block_offset..]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read block header");
+                    return Err(error);
+                }
+            }
+            data_offset += block_header.header_size;
+
+            let block_data_offset: usize = data_offset;
+            let block_uncompressed_data_offset: usize = *uncompressed_data_offset;
+
+            let mut lzma2_context: Lzma2Context = Lzma2Context::new();
+            lzma2_context.set_properties(block_header.lzma2_properties)?;
+
+            match lzma2_context.decompress_chunks(
+                compressed_data,
+                &mut data_offset,
+                uncompressed_data,
+                uncompressed_data_offset,
+            ) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decompress LZMA2 data");
+                    return Err(error);
+                }
+            }
+            let compressed_size: u64 = (data_offset - block_data_offset) as u64;
+            let uncompressed_size: u64 =
+                (*uncompressed_data_offset - block_uncompressed_data_offset) as u64;
+
+            if block_header
+                .compressed_size
+                .is_some_and(|size| size != compressed_size)
+            {
+                return Err(keramics_core::error_trace_new!(
+                    "Mismatch between block header and decompressed compressed size"
+                ));
+            }
+            if block_header
+                .uncompressed_size
+                .is_some_and(|size| size != uncompressed_size)
+            {
+                return Err(keramics_core::error_trace_new!(
+                    "Mismatch between block header and decompressed uncompressed size"
+                ));
+            }
+            // Block padding aligns the check to a multiple of 4 bytes.
+            while (data_offset - block_offset) % 4 != 0 {
+                if data_offset >= compressed_data_size || compressed_data[data_offset] != 0 {
+                    return Err(keramics_core::error_trace_new!("Unsupported block padding"));
+                }
+                data_offset += 1;
+            }
+            if check_size > compressed_data_size - data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            self.verify_check(
+                stream_header.check_type,
+                &compressed_data[data_offset..data_offset + check_size],
+                &uncompressed_data[block_uncompressed_data_offset..*uncompressed_data_offset],
+            )?;
+            data_offset += check_size;
+
+            let unpadded_size: u64 =
+                (block_header.header_size as u64) + compressed_size + (check_size as u64);
+            block_sizes.push((unpadded_size, uncompressed_size));
+        }
+        let index_offset: usize = data_offset;
+
+        match self.read_index(compressed_data, &mut data_offset, &block_sizes) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read index");
+                return Err(error);
+            }
+        }
+        let mut stream_footer: XzStreamFooter = XzStreamFooter::new();
+
+        if 12 > compressed_data_size - data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "XzStreamFooter data of size: 12 at offset: {} (0x{:08x})\n",
+                data_offset, data_offset
+            ));
+            self.mediator
+                .debug_print_data(&compressed_data[data_offset..data_offset + 12], true);
+            self.mediator.debug_print(XzStreamFooter::debug_read_data(
+                &compressed_data[data_offset..data_offset + 12],
+            ));
+        }
+        stream_footer.read_data(&compressed_data[data_offset..data_offset + 12])?;
+
+        if stream_footer.index_size != data_offset - index_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Mismatch between stream footer and index size"
+            ));
+        }
+        if stream_footer.check_type != stream_header.check_type {
+            return Err(keramics_core::error_trace_new!(
+                "Mismatch between stream header and footer flags"
+            ));
+        }
+        *compressed_data_offset = data_offset + 12;
+
+        Ok(())
+    }
+
+    /// Reads and verifies the index.
+    fn read_index(
+        &self,
+        compressed_data: &[u8],
+        compressed_data_offset: &mut usize,
+        block_sizes: &[(u64, u64)],
+    ) -> Result<(), ErrorTrace> {
+        let compressed_data_size: usize = compressed_data.len();
+        let index_offset: usize = *compressed_data_offset;
+
+        // Skip the index indicator.
+        let mut data_offset: usize = index_offset + 1;
+
+        let number_of_records: u64 = read_variable_size_integer(compressed_data, &mut data_offset)?;
+
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "XzIndex at offset: {} (0x{:08x}) {{\n",
+                index_offset, index_offset
+            ));
+            self.mediator
+                .debug_print(format!("    number_of_records: {},\n", number_of_records));
+        }
+        if number_of_records != block_sizes.len() as u64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between number of index records: {} and blocks: {}",
+                number_of_records,
+                block_sizes.len()
+            )));
+        }
+        for (unpadded_size, uncompressed_size) in block_sizes.iter() {
+            let record_unpadded_size: u64 =
+                read_variable_size_integer(compressed_data, &mut data_offset)?;
+            let record_uncompressed_size: u64 =
+                read_variable_size_integer(compressed_data, &mut data_offset)?;
+
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "    record: unpadded size: {}, uncompressed size: {},\n",
+                    record_unpadded_size, record_uncompressed_size
+                ));
+            }
+            if record_unpadded_size != *unpadded_size
+                || record_uncompressed_size != *uncompressed_size
+            {
+                return Err(keramics_core::error_trace_new!(
+                    "Mismatch between index record and block sizes"
+                ));
+            }
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!("}}\n\n"));
+        }
+        while (data_offset - index_offset) % 4 != 0 {
+            if data_offset >= compressed_data_size || compressed_data[data_offset] != 0 {
+                return Err(keramics_core::error_trace_new!("Unsupported index padding"));
+            }
+            data_offset += 1;
+        }
+        if 4 > compressed_data_size - data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let stored_checksum: u32 = bytes_to_u32_le!(compressed_data, data_offset);
+        let calculated_checksum: u32 = calculate_crc32(&compressed_data[index_offset..data_offset]);
+
+        if stored_checksum != calculated_checksum {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} index checksums",
+                stored_checksum, calculated_checksum
+            )));
+        }
+        *compressed_data_offset = data_offset + 4;
+
+        Ok(())
+    }
+
+    /// Verifies the check of the uncompressed data of a block.
+    fn verify_check(
+        &self,
+        check_type: u8,
+        check_data: &[u8],
+        uncompressed_data: &[u8],
+    ) -> Result<(), ErrorTrace> {
+        match check_type {
+            XZ_CHECK_TYPE_NONE => {}
+            XZ_CHECK_TYPE_CRC32 => {
+                let stored_checksum: u32 = bytes_to_u32_le!(check_data, 0);
+                let calculated_checksum: u32 = calculate_crc32(uncompressed_data);
+
+                if stored_checksum != calculated_checksum {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} block CRC-32 checksums",
+                        stored_checksum, calculated_checksum
+                    )));
+                }
+            }
+            XZ_CHECK_TYPE_CRC64 => {
+                let mut crc64_context: ReversedCrc64Context =
+                    ReversedCrc64Context::new(0xc96c5795d7870f42, 0);
+                crc64_context.update(uncompressed_data);
+
+                let stored_checksum: u64 = bytes_to_u64_le!(check_data, 0);
+                let calculated_checksum: u64 = crc64_context.finalize();
+
+                if stored_checksum != calculated_checksum {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Mismatch between stored: 0x{:016x} and calculated: 0x{:016x} block CRC-64 checksums",
+                        stored_checksum, calculated_checksum
+                    )));
+                }
+            }
+            XZ_CHECK_TYPE_SHA256 => {
+                let mut sha256_context: Sha256Context = Sha256Context::new();
+                sha256_context.update(uncompressed_data);
+
+                let calculated_hash: Vec<u8> = sha256_context.finalize();
+
+                if check_data != calculated_hash.as_slice() {
+                    return Err(keramics_core::error_trace_new!(
+                        "Mismatch between stored and calculated block SHA-256 hashes"
+                    ));
+                }
+            }
+            _ => {
+                // Other check types are reserved and cannot be verified.
+                if self.mediator.debug_output {
+                    self.mediator.debug_print(format!(
+                        "Unsupported check type: 0x{:02x} not verified\n\n",
+                        check_type
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00, 0x00, 0x04, 0xe6, 0xd6, 0xb4, 0x46, 0x02, 0x00,
+            0x21, 0x01, 0x16, 0x00, 0x00, 0x00, 0x74, 0x2f, 0xe5, 0xa3, 0xe0, 0x00, 0x4b, 0x00,
+            0x1a, 0x5d, 0x00, 0x25, 0x99, 0x4a, 0x46, 0x23, 0x5e, 0x8d, 0x74, 0xcf, 0xd7, 0x5c,
+            0xc1, 0xac, 0x12, 0x84, 0x33, 0xb2, 0x21, 0x81, 0x34, 0xb4, 0x8d, 0xa7, 0x2a, 0xa0,
+            0x00, 0x00, 0x00, 0x00, 0xd4, 0x5a, 0x37, 0x7e, 0x33, 0xb9, 0xb1, 0xa6, 0x00, 0x01,
+            0x36, 0x4c, 0xe5, 0x29, 0xf0, 0xd6, 0x1f, 0xb6, 0xf3, 0x7d, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x04, 0x59, 0x5a,
+        ];
+    }
+
+    #[test]
+    fn test_read_variable_size_integer() -> Result<(), ErrorTrace> {
+        let test_data: [u8; 4] = [0x7f, 0x80, 0x01, 0x80];
+
+        let mut data_offset: usize = 0;
+        let value_64bit: u64 = read_variable_size_integer(&test_data, &mut data_offset)?;
+        assert_eq!(value_64bit, 127);
+        assert_eq!(data_offset, 1);
+
+        let value_64bit: u64 = read_variable_size_integer(&test_data, &mut data_offset)?;
+        assert_eq!(value_64bit, 128);
+        assert_eq!(data_offset, 3);
+
+        let result: Result<u64, ErrorTrace> =
+            read_variable_size_integer(&test_data, &mut data_offset);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_stream_header() -> Result<(), ErrorTrace> {
+        let mut stream_header: XzStreamHeader = XzStreamHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        stream_header.read_data(&test_data)?;
+
+        assert_eq!(stream_header.check_type, XZ_CHECK_TYPE_CRC64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress() -> Result<(), ErrorTrace> {
+        let mut test_context: XzContext = XzContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 128];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 76);
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4);
+        assert_eq!(
+            &uncompressed_data[0..test_context.uncompressed_data_size],
+            &expected_data
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_sha256_check_and_multiple_streams() -> Result<(), ErrorTrace> {
+        let mut test_context: XzContext = XzContext::new();
+
+        let stream_data: [u8; 88] = [
+            0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00, 0x00, 0x0a, 0xe1, 0xfb, 0x0c, 0xa1, 0x02, 0x00,
+            0x21, 0x01, 0x16, 0x00, 0x00, 0x00, 0x74, 0x2f, 0xe5, 0xa3, 0x01, 0x00, 0x07, 0x4b,
+            0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73, 0x00, 0x77, 0x6f, 0x59, 0x2d, 0x2a, 0xe3,
+            0xed, 0xe9, 0xe9, 0x54, 0xdf, 0xfa, 0x04, 0x1f, 0x4a, 0x25, 0xa6, 0xf9, 0xa4, 0xea,
+            0x86, 0xe0, 0x40, 0xc8, 0x33, 0xf9, 0xf3, 0xd2, 0x8d, 0x6c, 0x2b, 0x9f, 0x00, 0x01,
+            0x38, 0x08, 0xe2, 0x81, 0xc2, 0x39, 0x18, 0x9b, 0x4b, 0x9a, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x0a, 0x59, 0x5a,
+        ];
+        let mut test_data: Vec<u8> = stream_data.to_vec();
+        test_data.extend_from_slice(&[0; 4]);
+        test_data.extend_from_slice(&stream_data);
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 32];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 16);
+        assert_eq!(&uncompressed_data[0..16], b"KeramicsKeramics");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_corrupted_check() {
+        let mut test_context: XzContext = XzContext::new();
+
+        let mut test_data: Vec<u8> = get_test_data();
+        let check_offset: usize = test_data.len() - 28;
+        test_data[check_offset] ^= 0xff;
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 128];
+        let result: Result<(), ErrorTrace> =
+            test_context.decompress(&test_data, &mut uncompressed_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decompress_file() -> Result<(), ErrorTrace> {
+        let mut test_context: XzContext = XzContext::new();
+
+        let test_data: Vec<u8> = match fs::read("../test_data/lzma/compressed") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test data file",
+                    error
+                ));
+            }
+        };
+        let mut uncompressed_data: Vec<u8> = vec![0; 512];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 512);
+        assert_eq!(&uncompressed_data[510..512], [0x55, 0xaa]);
+
+        Ok(())
+    }
+}
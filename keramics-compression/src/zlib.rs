@@ -11,16 +11,20 @@
  * under the License.
  */
 
-//! ZLIB decompression.
+//! ZLIB compression and decompression.
 //!
-//! Provides decompression support for ZLIB compressed data (RFC 1950).
+//! Provides compression and decompression support for ZLIB compressed data (RFC 1950).
 
 use keramics_checksums::Adler32Context;
 use keramics_core::ErrorTrace;
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_types::bytes_to_u32_be;
 
-use super::deflate::{DeflateBitstream, DeflateContext};
+use super::deflate::{
+    DeflateBitstream, DeflateBitstreamWriter, DeflateCompressionContext, DeflateContext,
+    DeflateStreamContext,
+};
+use super::traits::StreamDecompressor;
 
 /// Data header used by ZLIB compressed data.
 struct ZlibDataHeader {
@@ -180,6 +184,159 @@ impl ZlibContext {
     }
 }
 
+/// Context for decompressing ZLIB compressed data in chunks.
+pub struct ZlibStreamContext {
+    /// Buffered data header and checksum data.
+    data: Vec<u8>,
+
+    /// Value to indicate the data header was read.
+    data_header_read: bool,
+
+    /// DEFLATE stream context.
+    deflate_context: DeflateStreamContext,
+
+    /// Adler-32 context of the uncompressed data.
+    adler32_context: Adler32Context,
+
+    /// Value to indicate the end of the compressed stream was reached.
+    end_of_stream: bool,
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: u64,
+}
+
+impl ZlibStreamContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            data_header_read: false,
+            deflate_context: DeflateStreamContext::new(),
+            adler32_context: Adler32Context::new(1),
+            end_of_stream: false,
+            uncompressed_data_size: 0,
+        }
+    }
+}
+
+impl StreamDecompressor for ZlibStreamContext {
+    /// Decompresses a chunk of compressed data.
+    fn decompress_chunk(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        if self.end_of_stream {
+            return Ok(());
+        }
+        let mut deflate_data: &[u8] = compressed_data;
+
+        if !self.data_header_read {
+            self.data.extend_from_slice(compressed_data);
+
+            if self.data.len() < 2 {
+                return Ok(());
+            }
+            let header_size: usize = if self.data[1] & 0x20 == 0 { 2 } else { 6 };
+
+            if self.data.len() < header_size {
+                return Ok(());
+            }
+            let mut data_header: ZlibDataHeader = ZlibDataHeader::new();
+            data_header.read_data(&self.data)?;
+
+            self.data_header_read = true;
+
+            deflate_data =
+                &compressed_data[compressed_data.len() - (self.data.len() - header_size)..];
+            self.data.clear();
+        }
+        if !self.deflate_context.is_end_of_stream() {
+            let uncompressed_data_offset: usize = uncompressed_data.len();
+
+            match self
+                .deflate_context
+                .decompress_chunk(deflate_data, uncompressed_data)
+            {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to decompress DEFLATE data"
+                    );
+                    return Err(error);
+                }
+            }
+            self.adler32_context
+                .update(&uncompressed_data[uncompressed_data_offset..]);
+
+            if !self.deflate_context.is_end_of_stream() {
+                return Ok(());
+            }
+            self.uncompressed_data_size = self.deflate_context.uncompressed_data_size;
+
+            self.data
+                .extend_from_slice(self.deflate_context.get_trailing_data());
+        } else {
+            self.data.extend_from_slice(deflate_data);
+        }
+        if self.data.len() >= 4 {
+            let stored_checksum: u32 = bytes_to_u32_be!(self.data, 0);
+            let calculated_checksum: u32 = self.adler32_context.finalize();
+
+            if stored_checksum != calculated_checksum {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                    stored_checksum, calculated_checksum
+                )));
+            }
+            self.end_of_stream = true;
+        }
+        Ok(())
+    }
+
+    /// Determines if the end of the compressed stream was reached.
+    fn is_end_of_stream(&self) -> bool {
+        self.end_of_stream
+    }
+}
+
+/// Context for compressing data using ZLIB.
+pub struct ZlibCompressionContext {}
+
+impl ZlibCompressionContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Compress data.
+    ///
+    /// The compressed data is appended to `compressed_data`.
+    pub fn compress(
+        &mut self,
+        uncompressed_data: &[u8],
+        compressed_data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        // Compression method DEFLATE with a 32 KiB window and default compression level.
+        compressed_data.extend_from_slice(&[0x78, 0x9c]);
+
+        let mut bitstream: DeflateBitstreamWriter = DeflateBitstreamWriter::new(compressed_data);
+        let mut deflate_context: DeflateCompressionContext = DeflateCompressionContext::new();
+        deflate_context.compress_to_bitstream(uncompressed_data, &mut bitstream);
+
+        bitstream.flush();
+
+        let mut adler32_context: Adler32Context = Adler32Context::new(1);
+        adler32_context.update(uncompressed_data);
+        let checksum: u32 = adler32_context.finalize();
+
+        compressed_data.extend_from_slice(&checksum.to_be_bytes());
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -505,4 +662,103 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_stream_decompress_chunk() -> Result<(), ErrorTrace> {
+        let mut test_context: ZlibStreamContext = ZlibStreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in test_data.chunks(16) {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 11358);
+
+        let expected_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        assert_eq!(&uncompressed_data, &expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_decompress_chunk_with_single_bytes() -> Result<(), ErrorTrace> {
+        let mut test_context: ZlibStreamContext = ZlibStreamContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+
+        for chunk in test_data.chunks(1) {
+            assert!(!test_context.is_end_of_stream());
+
+            test_context.decompress_chunk(chunk, &mut uncompressed_data)?;
+        }
+        assert!(test_context.is_end_of_stream());
+        assert_eq!(test_context.uncompressed_data_size, 11358);
+
+        let expected_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        assert_eq!(&uncompressed_data, &expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress() -> Result<(), ErrorTrace> {
+        let mut test_context: ZlibCompressionContext = ZlibCompressionContext::new();
+
+        let test_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&test_data, &mut compressed_data)?;
+        assert_eq!(&compressed_data[0..2], [0x78, 0x9c]);
+
+        let mut decompression_context: ZlibContext = ZlibContext::new();
+
+        let mut uncompressed_data: Vec<u8> = vec![0; 11358];
+        decompression_context.decompress(&compressed_data, &mut uncompressed_data)?;
+        assert_eq!(decompression_context.uncompressed_data_size, 11358);
+        assert_eq!(&uncompressed_data, &test_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_with_empty_data() -> Result<(), ErrorTrace> {
+        let mut test_context: ZlibCompressionContext = ZlibCompressionContext::new();
+
+        let mut compressed_data: Vec<u8> = Vec::new();
+        test_context.compress(&[], &mut compressed_data)?;
+        assert_eq!(
+            compressed_data,
+            [0x78, 0x9c, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1822 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Zstandard (zstd) decompression.
+//!
+//! Provides decompression support for Zstandard compressed data (RFC 8878).
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le};
+
+use super::traits::Bitstream;
+
+/// Zstandard frame signature.
+const ZSTD_FRAME_SIGNATURE: u32 = 0xfd2fb528;
+
+/// Zstandard skippable frame signature, where the lower 4 bits can have any value.
+const ZSTD_SKIPPABLE_FRAME_SIGNATURE: u32 = 0x184d2a50;
+
+/// Raw Zstandard block type.
+const ZSTD_BLOCK_TYPE_RAW: u32 = 0;
+
+/// Run-length encoded (RLE) Zstandard block type.
+const ZSTD_BLOCK_TYPE_RLE: u32 = 1;
+
+/// Compressed Zstandard block type.
+const ZSTD_BLOCK_TYPE_COMPRESSED: u32 = 2;
+
+/// Raw literals section type.
+const ZSTD_LITERALS_TYPE_RAW: u8 = 0;
+
+/// Run-length encoded (RLE) literals section type.
+const ZSTD_LITERALS_TYPE_RLE: u8 = 1;
+
+/// Huffman compressed literals section type.
+const ZSTD_LITERALS_TYPE_COMPRESSED: u8 = 2;
+
+/// Predefined symbol compression mode.
+const ZSTD_COMPRESSION_MODE_PREDEFINED: u8 = 0;
+
+/// Run-length encoded (RLE) symbol compression mode.
+const ZSTD_COMPRESSION_MODE_RLE: u8 = 1;
+
+/// FSE compressed symbol compression mode.
+const ZSTD_COMPRESSION_MODE_FSE: u8 = 2;
+
+/// Maximum Huffman code size.
+const ZSTD_MAXIMUM_HUFFMAN_CODE_SIZE: u8 = 11;
+
+/// Base values of literal length codes.
+const ZSTD_LITERAL_LENGTH_CODES_BASE: [u32; 36] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 20, 22, 24, 28, 32, 40, 48, 64,
+    128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536,
+];
+
+/// Number of extra bits of literal length codes.
+const ZSTD_LITERAL_LENGTH_CODES_NUMBER_OF_EXTRA_BITS: [u8; 36] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 3, 3, 4, 6, 7, 8, 9, 10, 11,
+    12, 13, 14, 15, 16,
+];
+
+/// Predefined distribution of literal length codes.
+const ZSTD_LITERAL_LENGTH_CODES_DEFAULT_DISTRIBUTION: [i16; 36] = [
+    4, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 1, 1, 1, 1, 1,
+    -1, -1, -1, -1,
+];
+
+/// Base values of match length codes.
+const ZSTD_MATCH_LENGTH_CODES_BASE: [u32; 53] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+    28, 29, 30, 31, 32, 33, 34, 35, 37, 39, 41, 43, 47, 51, 59, 67, 83, 99, 131, 259, 515, 1027,
+    2051, 4099, 8195, 16387, 32771, 65539,
+];
+
+/// Number of extra bits of match length codes.
+const ZSTD_MATCH_LENGTH_CODES_NUMBER_OF_EXTRA_BITS: [u8; 53] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 1, 1, 1, 2, 2, 3, 3, 4, 4, 5, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+];
+
+/// Predefined distribution of match length codes.
+const ZSTD_MATCH_LENGTH_CODES_DEFAULT_DISTRIBUTION: [i16; 53] = [
+    1, 4, 3, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1, -1, -1,
+];
+
+/// Predefined distribution of offset codes.
+const ZSTD_OFFSET_CODES_DEFAULT_DISTRIBUTION: [i16; 29] = [
+    1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1,
+];
+
+/// Bitstream for Zstandard compressed data that is read forwards.
+struct ZstdBitstream<'a> {
+    /// Byte steam.
+    data: &'a [u8],
+
+    /// Current offset in the byte stream.
+    pub data_offset: usize,
+
+    /// Size of the byte stream in bytes.
+    pub data_size: usize,
+
+    /// Bits buffer.
+    bits: u64,
+
+    /// Number of bits in the bits buffer.
+    number_of_bits: usize,
+}
+
+impl<'a> ZstdBitstream<'a> {
+    /// Creates a new bitstream.
+    pub fn new(data: &'a [u8], data_offset: usize) -> Self {
+        let data_size: usize = data.len();
+        Self {
+            data: data,
+            data_offset: data_offset,
+            data_size: data_size,
+            bits: 0,
+            number_of_bits: 0,
+        }
+    }
+
+    /// Reads input data forwards into the bits buffer in little-endian byte order.
+    #[inline(always)]
+    fn read_data(&mut self, number_of_bits: usize) {
+        while self.number_of_bits < number_of_bits {
+            // If the bit stream overflows fill the bit buffer with 0 byte values.
+            if self.data_offset < self.data_size {
+                self.bits |= (self.data[self.data_offset] as u64) << self.number_of_bits;
+            }
+            self.data_offset += 1;
+            self.number_of_bits += 8;
+        }
+    }
+}
+
+impl<'a> Bitstream for ZstdBitstream<'a> {
+    /// Retrieves a bit value.
+    fn get_value(&mut self, number_of_bits: usize) -> u32 {
+        // Note that this does not check if number_of_bits <= 32
+        if number_of_bits == 0 {
+            return 0;
+        }
+        self.read_data(number_of_bits);
+
+        let bit_value: u32 = (self.bits & ((1 << number_of_bits) - 1)) as u32;
+
+        self.bits >>= number_of_bits;
+        self.number_of_bits -= number_of_bits;
+
+        bit_value
+    }
+
+    /// Skips a number of bits.
+    fn skip_bits(&mut self, number_of_bits: usize) {
+        self.get_value(number_of_bits);
+    }
+}
+
+/// Bitstream for Zstandard compressed data that is read backwards.
+///
+/// The bitstream starts at the most significant bit of the last byte, after the padding bit.
+struct ZstdReverseBitstream<'a> {
+    /// Byte steam.
+    data: &'a [u8],
+
+    /// Current bit offset in the byte stream.
+    bit_offset: isize,
+}
+
+impl<'a> ZstdReverseBitstream<'a> {
+    /// Creates a new bitstream.
+    pub fn new(data: &'a [u8]) -> Result<Self, ErrorTrace> {
+        let last_byte: u8 = match data.last() {
+            Some(byte_value) => *byte_value,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid bitstream data value too small"
+                ));
+            }
+        };
+        if last_byte == 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid bitstream padding value out of bounds"
+            ));
+        }
+        let bit_offset: isize =
+            (((data.len() - 1) * 8) as isize) + (7 - last_byte.leading_zeros() as isize);
+
+        Ok(Self {
+            data: data,
+            bit_offset: bit_offset,
+        })
+    }
+
+    /// Retrieves a bit value at a specific bit offset.
+    ///
+    /// Bits before the start of the byte stream are read as 0.
+    #[inline(always)]
+    fn get_value_at_offset(&self, bit_offset: isize, number_of_bits: usize) -> u32 {
+        if bit_offset < 0 {
+            let number_of_available_bits: isize = number_of_bits as isize + bit_offset;
+
+            if number_of_available_bits <= 0 {
+                return 0;
+            }
+            return self.get_value_at_offset(0, number_of_available_bits as usize)
+                << (-bit_offset as usize);
+        }
+        let data_offset: usize = (bit_offset / 8) as usize;
+        let mut value_64bit: u64 = 0;
+
+        for byte_index in 0..8 {
+            if data_offset + byte_index >= self.data.len() {
+                break;
+            }
+            value_64bit |= (self.data[data_offset + byte_index] as u64) << (byte_index * 8);
+        }
+        ((value_64bit >> (bit_offset % 8)) & ((1 << number_of_bits) - 1)) as u32
+    }
+
+    /// Retrieves a bit value without consuming the bits.
+    #[inline(always)]
+    pub fn peek_value(&self, number_of_bits: usize) -> u32 {
+        self.get_value_at_offset(self.bit_offset - number_of_bits as isize, number_of_bits)
+    }
+
+    /// Determines if all the bits of the byte stream were consumed.
+    pub fn is_finished(&self) -> bool {
+        self.bit_offset == 0
+    }
+
+    /// Determines if more bits were consumed than available in the byte stream.
+    pub fn is_overflow(&self) -> bool {
+        self.bit_offset < 0
+    }
+}
+
+impl<'a> Bitstream for ZstdReverseBitstream<'a> {
+    /// Retrieves a bit value.
+    fn get_value(&mut self, number_of_bits: usize) -> u32 {
+        // Note that this does not check if number_of_bits <= 32
+        if number_of_bits == 0 {
+            return 0;
+        }
+        self.bit_offset -= number_of_bits as isize;
+
+        self.get_value_at_offset(self.bit_offset, number_of_bits)
+    }
+
+    /// Skips a number of bits.
+    fn skip_bits(&mut self, number_of_bits: usize) {
+        self.bit_offset -= number_of_bits as isize;
+    }
+}
+
+/// Finite State Entropy (FSE) decoding table entry.
+#[derive(Clone)]
+struct ZstdFseTableEntry {
+    /// Symbol.
+    symbol: u8,
+
+    /// Number of bits to read for the next state.
+    number_of_bits: u8,
+
+    /// Base value of the next state.
+    baseline: u16,
+}
+
+/// Finite State Entropy (FSE) decoding table.
+#[derive(Clone)]
+struct ZstdFseTable {
+    /// Accuracy log.
+    accuracy_log: u8,
+
+    /// Entries.
+    entries: Vec<ZstdFseTableEntry>,
+}
+
+impl ZstdFseTable {
+    /// Creates a new table.
+    pub fn new() -> Self {
+        Self {
+            accuracy_log: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Builds the table from a normalized distribution.
+    pub fn build(&mut self, distribution: &[i16], accuracy_log: u8) -> Result<(), ErrorTrace> {
+        let table_size: usize = 1 << accuracy_log;
+        let mut high_threshold: usize = table_size - 1;
+
+        let mut symbols: Vec<u8> = vec![0; table_size];
+        let mut next_states: Vec<u16> = vec![0; distribution.len()];
+
+        // Symbols with a "less than 1" probability are placed at the end of the table.
+        for (symbol, probability) in distribution.iter().enumerate() {
+            if *probability == -1 {
+                if high_threshold == 0 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid distribution value out of bounds"
+                    ));
+                }
+                symbols[high_threshold] = symbol as u8;
+                high_threshold -= 1;
+
+                next_states[symbol] = 1;
+            } else if *probability > 0 {
+                next_states[symbol] = *probability as u16;
+            }
+        }
+        let step: usize = (table_size >> 1) + (table_size >> 3) + 3;
+        let mut table_index: usize = 0;
+
+        for (symbol, probability) in distribution.iter().enumerate() {
+            for _ in 0..i16::max(*probability, 0) {
+                symbols[table_index] = symbol as u8;
+
+                loop {
+                    table_index = (table_index + step) & (table_size - 1);
+
+                    if table_index <= high_threshold {
+                        break;
+                    }
+                }
+            }
+        }
+        if table_index != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid distribution value out of bounds"
+            ));
+        }
+        self.accuracy_log = accuracy_log;
+        self.entries.clear();
+
+        for symbol in symbols.into_iter() {
+            let next_state: u16 = next_states[symbol as usize];
+            next_states[symbol as usize] += 1;
+
+            let number_of_bits: u8 = accuracy_log - (15 - next_state.leading_zeros() as u8);
+            let baseline: u16 = (((next_state as usize) << number_of_bits) - table_size) as u16;
+
+            self.entries.push(ZstdFseTableEntry {
+                symbol: symbol,
+                number_of_bits: number_of_bits,
+                baseline: baseline,
+            });
+        }
+        Ok(())
+    }
+
+    /// Builds a table with a single run-length encoded (RLE) symbol.
+    pub fn build_rle(&mut self, symbol: u8) {
+        self.accuracy_log = 0;
+        self.entries.clear();
+        self.entries.push(ZstdFseTableEntry {
+            symbol: symbol,
+            number_of_bits: 0,
+            baseline: 0,
+        });
+    }
+
+    /// Reads the table description from a bitstream and builds the table.
+    pub fn read_from_bitstream(
+        &mut self,
+        bitstream: &mut ZstdBitstream,
+        maximum_accuracy_log: u8,
+        maximum_symbol: usize,
+    ) -> Result<(), ErrorTrace> {
+        let accuracy_log: u8 = bitstream.get_value(4) as u8 + 5;
+
+        if accuracy_log > maximum_accuracy_log {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid accuracy log: {} value out of bounds",
+                accuracy_log
+            )));
+        }
+        let mut distribution: Vec<i16> = Vec::new();
+
+        // The remaining probability points plus 1.
+        let mut remaining: u32 = (1 << accuracy_log) + 1;
+        let mut threshold: u32 = 1 << accuracy_log;
+        let mut number_of_bits: usize = accuracy_log as usize + 1;
+
+        while remaining > 1 {
+            if distribution.len() > maximum_symbol {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid distribution number of symbols value out of bounds"
+                ));
+            }
+            let maximum_small_value: u32 = (2 * threshold) - 1 - remaining;
+
+            let mut value: u32 = bitstream.get_value(number_of_bits - 1);
+
+            if value >= maximum_small_value {
+                value |= bitstream.get_value(1) << (number_of_bits - 1);
+
+                if value >= threshold {
+                    value -= maximum_small_value;
+                }
+            }
+            let probability: i16 = value as i16 - 1;
+
+            if probability < 0 {
+                remaining -= 1;
+            } else {
+                if probability as u32 >= remaining {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid probability value out of bounds"
+                    ));
+                }
+                remaining -= probability as u32;
+            }
+            distribution.push(probability);
+
+            if probability == 0 {
+                loop {
+                    let number_of_repeats: u32 = bitstream.get_value(2);
+
+                    for _ in 0..number_of_repeats {
+                        distribution.push(0);
+                    }
+                    if number_of_repeats != 3 {
+                        break;
+                    }
+                }
+            }
+            while remaining < threshold {
+                number_of_bits -= 1;
+                threshold >>= 1;
+            }
+        }
+        if distribution.len() > maximum_symbol + 1 {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid distribution number of symbols value out of bounds"
+            ));
+        }
+        if bitstream.data_offset > bitstream.data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        self.build(&distribution, accuracy_log)
+    }
+
+    /// Retrieves the symbol of a state.
+    #[inline(always)]
+    pub fn get_symbol(&self, state: usize) -> u8 {
+        self.entries[state].symbol
+    }
+
+    /// Reads the initial state from a bitstream.
+    #[inline(always)]
+    pub fn read_initial_state(&self, bitstream: &mut dyn Bitstream) -> usize {
+        bitstream.get_value(self.accuracy_log as usize) as usize
+    }
+
+    /// Reads the next state from a bitstream.
+    #[inline(always)]
+    pub fn read_next_state(&self, bitstream: &mut dyn Bitstream, state: usize) -> usize {
+        let table_entry: &ZstdFseTableEntry = &self.entries[state];
+
+        table_entry.baseline as usize
+            + bitstream.get_value(table_entry.number_of_bits as usize) as usize
+    }
+}
+
+/// Huffman decoding table.
+struct ZstdHuffmanTable {
+    /// Largest code size.
+    largest_code_size: u8,
+
+    /// Entries, containing the symbol and code size.
+    entries: Vec<(u8, u8)>,
+}
+
+impl ZstdHuffmanTable {
+    /// Creates a new table.
+    pub fn new() -> Self {
+        Self {
+            largest_code_size: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Builds the table from weights.
+    ///
+    /// The weight of the last symbol is not stored and derived from the other weights.
+    pub fn build(&mut self, weights: &[u8]) -> Result<(), ErrorTrace> {
+        let mut sum_of_weights: u32 = 0;
+
+        for weight in weights.iter() {
+            if *weight > ZSTD_MAXIMUM_HUFFMAN_CODE_SIZE {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid weight: {} value out of bounds",
+                    weight
+                )));
+            }
+            if *weight > 0 {
+                sum_of_weights += 1 << (*weight - 1);
+            }
+        }
+        if sum_of_weights == 0 {
+            return Err(keramics_core::error_trace_new!("Missing weights"));
+        }
+        let largest_code_size: u8 = (32 - sum_of_weights.leading_zeros()) as u8;
+
+        if largest_code_size > ZSTD_MAXIMUM_HUFFMAN_CODE_SIZE {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid largest code size: {} value out of bounds",
+                largest_code_size
+            )));
+        }
+        let remaining_weight: u32 = (1 << largest_code_size) - sum_of_weights;
+
+        if !remaining_weight.is_power_of_two() {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid weights value out of bounds"
+            ));
+        }
+        let mut code_sizes: Vec<u8> = Vec::with_capacity(weights.len() + 1);
+
+        for weight in weights
+            .iter()
+            .chain([remaining_weight.trailing_zeros() as u8 + 1].iter())
+        {
+            code_sizes.push(if *weight > 0 {
+                largest_code_size + 1 - *weight
+            } else {
+                0
+            });
+        }
+        // The longest codes are stored first.
+        let mut code_size_offsets: Vec<usize> = vec![0; largest_code_size as usize + 1];
+
+        for code_size in (1..largest_code_size).rev() {
+            let number_of_codes: usize = code_sizes
+                .iter()
+                .filter(|symbol_code_size| **symbol_code_size == code_size + 1)
+                .count();
+
+            code_size_offsets[code_size as usize] = code_size_offsets[code_size as usize + 1]
+                + (number_of_codes << (largest_code_size - code_size - 1));
+        }
+        self.largest_code_size = largest_code_size;
+        self.entries = vec![(0, 0); 1 << largest_code_size];
+
+        for (symbol, code_size) in code_sizes.iter().enumerate() {
+            if *code_size == 0 {
+                continue;
+            }
+            let entries_start_offset: usize = code_size_offsets[*code_size as usize];
+            let entries_end_offset: usize =
+                entries_start_offset + (1 << (largest_code_size - *code_size));
+
+            self.entries[entries_start_offset..entries_end_offset].fill((symbol as u8, *code_size));
+
+            code_size_offsets[*code_size as usize] = entries_end_offset;
+        }
+        Ok(())
+    }
+
+    /// Reads the table description and builds the table.
+    ///
+    /// Returns the size of the table description.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        if data.is_empty() {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let header: u8 = data[0];
+        let mut weights: Vec<u8> = Vec::new();
+
+        let data_size: usize = if header >= 128 {
+            let number_of_weights: usize = header as usize - 127;
+            let data_size: usize = number_of_weights.div_ceil(2);
+
+            if data_size > data.len() - 1 {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            for weight_index in 0..number_of_weights {
+                let byte_value: u8 = data[1 + (weight_index / 2)];
+
+                weights.push(if weight_index % 2 == 0 {
+                    byte_value >> 4
+                } else {
+                    byte_value & 0x0f
+                });
+            }
+            data_size
+        } else {
+            let data_size: usize = header as usize;
+
+            if data_size > data.len() - 1 {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let weights_data: &[u8] = &data[1..1 + data_size];
+
+            let mut bitstream: ZstdBitstream = ZstdBitstream::new(weights_data, 0);
+            let mut fse_table: ZstdFseTable = ZstdFseTable::new();
+            fse_table.read_from_bitstream(&mut bitstream, 6, 255)?;
+
+            let mut reverse_bitstream: ZstdReverseBitstream =
+                ZstdReverseBitstream::new(&weights_data[bitstream.data_offset..])?;
+
+            // The weights are encoded with 2 interleaved states.
+            let mut first_state: usize = fse_table.read_initial_state(&mut reverse_bitstream);
+            let mut second_state: usize = fse_table.read_initial_state(&mut reverse_bitstream);
+
+            loop {
+                if weights.len() >= 255 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid number of weights value out of bounds"
+                    ));
+                }
+                weights.push(fse_table.get_symbol(first_state));
+                first_state = fse_table.read_next_state(&mut reverse_bitstream, first_state);
+
+                if reverse_bitstream.is_overflow() {
+                    weights.push(fse_table.get_symbol(second_state));
+                    break;
+                }
+                weights.push(fse_table.get_symbol(second_state));
+                second_state = fse_table.read_next_state(&mut reverse_bitstream, second_state);
+
+                if reverse_bitstream.is_overflow() {
+                    weights.push(fse_table.get_symbol(first_state));
+                    break;
+                }
+            }
+            data_size
+        };
+        self.build(&weights)?;
+
+        Ok(1 + data_size)
+    }
+
+    /// Decodes a Huffman encoded stream.
+    pub fn decode_stream(&self, data: &[u8], output_data: &mut [u8]) -> Result<(), ErrorTrace> {
+        let mut bitstream: ZstdReverseBitstream = ZstdReverseBitstream::new(data)?;
+
+        for output_data_offset in 0..output_data.len() {
+            let (symbol, code_size) =
+                self.entries[bitstream.peek_value(self.largest_code_size as usize) as usize];
+
+            bitstream.skip_bits(code_size as usize);
+
+            output_data[output_data_offset] = symbol;
+        }
+        if !bitstream.is_finished() {
+            return Err(keramics_core::error_trace_new!(
+                "Mismatch between Huffman stream size and decoded data"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Context for decompressing Zstandard compressed data.
+pub struct ZstdContext {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Huffman table of the literals.
+    huffman_table: Option<ZstdHuffmanTable>,
+
+    /// FSE table of the literal length codes.
+    literal_lengths_table: Option<ZstdFseTable>,
+
+    /// FSE table of the match length codes.
+    match_lengths_table: Option<ZstdFseTable>,
+
+    /// FSE table of the offset codes.
+    offsets_table: Option<ZstdFseTable>,
+
+    /// Repeated offsets.
+    repeated_offsets: [usize; 3],
+
+    /// Uncompressed data size.
+    pub uncompressed_data_size: usize,
+}
+
+impl ZstdContext {
+    /// Creates a new context.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            huffman_table: None,
+            literal_lengths_table: None,
+            match_lengths_table: None,
+            offsets_table: None,
+            repeated_offsets: [1, 4, 8],
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Decompress data.
+    pub fn decompress(
+        &mut self,
+        compressed_data: &[u8],
+        uncompressed_data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        let mut compressed_data_offset: usize = 0;
+        let compressed_data_size: usize = compressed_data.len();
+
+        let mut uncompressed_data_offset: usize = 0;
+
+        while compressed_data_offset < compressed_data_size {
+            // Data that follows the last frame, such as padding, is ignored once the
+            // uncompressed data buffer is filled.
+            if uncompressed_data_offset >= uncompressed_data.len() {
+                break;
+            }
+            if 4 > compressed_data_size - compressed_data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let signature: u32 = bytes_to_u32_le!(compressed_data, compressed_data_offset);
+
+            if signature & 0xfffffff0 == ZSTD_SKIPPABLE_FRAME_SIGNATURE {
+                if 8 > compressed_data_size - compressed_data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                let frame_size: usize =
+                    bytes_to_u32_le!(compressed_data, compressed_data_offset + 4) as usize;
+
+                if frame_size > compressed_data_size - compressed_data_offset - 8 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                compressed_data_offset += 8 + frame_size;
+
+                continue;
+            }
+            if signature != ZSTD_FRAME_SIGNATURE {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported frame signature: 0x{:08x}",
+                    signature
+                )));
+            }
+            match self.decompress_frame(
+                compressed_data,
+                &mut compressed_data_offset,
+                uncompressed_data,
+                &mut uncompressed_data_offset,
+            ) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decompress frame");
+                    return Err(error);
+                }
+            }
+        }
+        self.uncompressed_data_size = uncompressed_data_offset;
+
+        Ok(())
+    }
+
+    /// Decompress a frame.
+    fn decompress_frame(
+        &mut self,
+        compressed_data: &[u8],
+        compressed_data_offset: &mut usize,
+        uncompressed_data: &mut [u8],
+        uncompressed_data_offset: &mut usize,
+    ) -> Result<(), ErrorTrace> {
+        let compressed_data_size: usize = compressed_data.len();
+        let mut data_offset: usize = *compressed_data_offset + 4;
+
+        if data_offset >= compressed_data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let frame_header_descriptor: u8 = compressed_data[data_offset];
+
+        let content_size_flag: u8 = frame_header_descriptor >> 6;
+        let single_segment_flag: u8 = (frame_header_descriptor >> 5) & 0x01;
+        let content_checksum_flag: u8 = (frame_header_descriptor >> 2) & 0x01;
+        let dictionary_identifier_flag: u8 = frame_header_descriptor & 0x03;
+
+        if frame_header_descriptor & 0x08 != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported frame header descriptor reserved flag"
+            ));
+        }
+        let window_descriptor_size: usize = if single_segment_flag == 0 { 1 } else { 0 };
+        let dictionary_identifier_size: usize = match dictionary_identifier_flag {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            _ => 4,
+        };
+        let content_size_size: usize = match content_size_flag {
+            0 => single_segment_flag as usize,
+            1 => 2,
+            2 => 4,
+            _ => 8,
+        };
+        let frame_header_size: usize =
+            1 + window_descriptor_size + dictionary_identifier_size + content_size_size;
+
+        if frame_header_size > compressed_data_size - data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "ZstdFrameHeader data of size: {} at offset: {} (0x{:08x})\n",
+                frame_header_size + 4,
+                *compressed_data_offset,
+                *compressed_data_offset
+            ));
+            self.mediator.debug_print_data(
+                &compressed_data[*compressed_data_offset..data_offset + frame_header_size],
+                true,
+            );
+        }
+        data_offset += 1 + window_descriptor_size;
+
+        let mut dictionary_identifier: u32 = 0;
+
+        for byte_index in 0..dictionary_identifier_size {
+            dictionary_identifier |=
+                (compressed_data[data_offset + byte_index] as u32) << (byte_index * 8);
+        }
+        if dictionary_identifier != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported dictionary identifier: 0x{:08x}",
+                dictionary_identifier
+            )));
+        }
+        data_offset += dictionary_identifier_size;
+
+        let mut content_size: u64 = 0;
+
+        for byte_index in 0..content_size_size {
+            content_size |= (compressed_data[data_offset + byte_index] as u64) << (byte_index * 8);
+        }
+        if content_size_size == 2 {
+            content_size += 256;
+        }
+        data_offset += content_size_size;
+
+        // The state of the decoder is reset for every frame.
+        self.huffman_table = None;
+        self.literal_lengths_table = None;
+        self.match_lengths_table = None;
+        self.offsets_table = None;
+        self.repeated_offsets = [1, 4, 8];
+
+        let frame_start_offset: usize = *uncompressed_data_offset;
+        let uncompressed_data_size: usize = uncompressed_data.len();
+
+        loop {
+            if 3 > compressed_data_size - data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let block_header: u32 = bytes_to_u16_le!(compressed_data, data_offset) as u32
+                | ((compressed_data[data_offset + 2] as u32) << 16);
+
+            let last_block_flag: u32 = block_header & 0x00000001;
+            let block_type: u32 = (block_header >> 1) & 0x00000003;
+            let block_size: usize = (block_header >> 3) as usize;
+
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "ZstdBlockHeader data of size: 3 at offset: {} (0x{:08x})\n",
+                    data_offset, data_offset
+                ));
+                self.mediator
+                    .debug_print_data(&compressed_data[data_offset..data_offset + 3], true);
+                self.mediator.debug_print(format!(
+                    "ZstdBlockHeader {{\n    last_block_flag: {},\n    block_type: {},\n    block_size: {},\n}}\n\n",
+                    last_block_flag, block_type, block_size
+                ));
+            }
+            data_offset += 3;
+
+            match block_type {
+                ZSTD_BLOCK_TYPE_RAW => {
+                    if block_size > compressed_data_size - data_offset {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid compressed data value too small"
+                        ));
+                    }
+                    if block_size > uncompressed_data_size - *uncompressed_data_offset {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid uncompressed data value too small"
+                        ));
+                    }
+                    uncompressed_data
+                        [*uncompressed_data_offset..*uncompressed_data_offset + block_size]
+                        .copy_from_slice(&compressed_data[data_offset..data_offset + block_size]);
+
+                    data_offset += block_size;
+                    *uncompressed_data_offset += block_size;
+                }
+                ZSTD_BLOCK_TYPE_RLE => {
+                    if data_offset >= compressed_data_size {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid compressed data value too small"
+                        ));
+                    }
+                    if block_size > uncompressed_data_size - *uncompressed_data_offset {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid uncompressed data value too small"
+                        ));
+                    }
+                    uncompressed_data
+                        [*uncompressed_data_offset..*uncompressed_data_offset + block_size]
+                        .fill(compressed_data[data_offset]);
+
+                    data_offset += 1;
+                    *uncompressed_data_offset += block_size;
+                }
+                ZSTD_BLOCK_TYPE_COMPRESSED => {
+                    if block_size > compressed_data_size - data_offset {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid compressed data value too small"
+                        ));
+                    }
+                    match self.decompress_block(
+                        &compressed_data[data_offset..data_offset + block_size],
+                        uncompressed_data,
+                        uncompressed_data_offset,
+                        frame_start_offset,
+                    ) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to decompress block"
+                            );
+                            return Err(error);
+                        }
+                    }
+                    data_offset += block_size;
+                }
+                _ => {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Unsupported block type: {}",
+                        block_type
+                    )));
+                }
+            }
+            if last_block_flag != 0 {
+                break;
+            }
+        }
+        if content_size_size > 0
+            && content_size != (*uncompressed_data_offset - frame_start_offset) as u64
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between frame content size: {} and uncompressed data size: {}",
+                content_size,
+                *uncompressed_data_offset - frame_start_offset
+            )));
+        }
+        // Note that the content checksum (XXH64) is currently not verified.
+        if content_checksum_flag != 0 {
+            if 4 > compressed_data_size - data_offset {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            data_offset += 4;
+        }
+        *compressed_data_offset = data_offset;
+
+        Ok(())
+    }
+
+    /// Decompress a compressed block.
+    fn decompress_block(
+        &mut self,
+        block_data: &[u8],
+        uncompressed_data: &mut [u8],
+        uncompressed_data_offset: &mut usize,
+        frame_start_offset: usize,
+    ) -> Result<(), ErrorTrace> {
+        let block_data_size: usize = block_data.len();
+        let mut block_data_offset: usize = 0;
+
+        let literals: Vec<u8> = self.read_literals(block_data, &mut block_data_offset)?;
+
+        if block_data_offset >= block_data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let mut number_of_sequences: usize = block_data[block_data_offset] as usize;
+        block_data_offset += 1;
+
+        if number_of_sequences >= 128 {
+            if block_data_offset >= block_data_size {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            if number_of_sequences < 255 {
+                number_of_sequences =
+                    ((number_of_sequences - 128) << 8) + block_data[block_data_offset] as usize;
+                block_data_offset += 1;
+            } else {
+                if 2 > block_data_size - block_data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                number_of_sequences =
+                    bytes_to_u16_le!(block_data, block_data_offset) as usize + 0x7f00;
+                block_data_offset += 2;
+            }
+        }
+        let uncompressed_data_size: usize = uncompressed_data.len();
+        let mut literals_offset: usize = 0;
+
+        if number_of_sequences > 0 {
+            if block_data_offset >= block_data_size {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            let compression_modes: u8 = block_data[block_data_offset];
+            block_data_offset += 1;
+
+            if compression_modes & 0x03 != 0 {
+                return Err(keramics_core::error_trace_new!(
+                    "Unsupported compression modes reserved bits"
+                ));
+            }
+            Self::read_sequence_table(
+                &mut self.literal_lengths_table,
+                compression_modes >> 6,
+                block_data,
+                &mut block_data_offset,
+                &ZSTD_LITERAL_LENGTH_CODES_DEFAULT_DISTRIBUTION,
+                6,
+                9,
+                35,
+            )?;
+            Self::read_sequence_table(
+                &mut self.offsets_table,
+                (compression_modes >> 4) & 0x03,
+                block_data,
+                &mut block_data_offset,
+                &ZSTD_OFFSET_CODES_DEFAULT_DISTRIBUTION,
+                5,
+                8,
+                31,
+            )?;
+            Self::read_sequence_table(
+                &mut self.match_lengths_table,
+                (compression_modes >> 2) & 0x03,
+                block_data,
+                &mut block_data_offset,
+                &ZSTD_MATCH_LENGTH_CODES_DEFAULT_DISTRIBUTION,
+                6,
+                9,
+                52,
+            )?;
+            let literal_lengths_table: &ZstdFseTable = match self.literal_lengths_table.as_ref() {
+                Some(fse_table) => fse_table,
+                None => {
+                    return Err(keramics_core::error_trace_new!(
+                        "Missing literal lengths table"
+                    ));
+                }
+            };
+            let offsets_table: &ZstdFseTable = match self.offsets_table.as_ref() {
+                Some(fse_table) => fse_table,
+                None => {
+                    return Err(keramics_core::error_trace_new!("Missing offsets table"));
+                }
+            };
+            let match_lengths_table: &ZstdFseTable = match self.match_lengths_table.as_ref() {
+                Some(fse_table) => fse_table,
+                None => {
+                    return Err(keramics_core::error_trace_new!(
+                        "Missing match lengths table"
+                    ));
+                }
+            };
+
+            let mut bitstream: ZstdReverseBitstream =
+                ZstdReverseBitstream::new(&block_data[block_data_offset..])?;
+
+            let mut literal_length_state: usize =
+                literal_lengths_table.read_initial_state(&mut bitstream);
+            let mut offset_state: usize = offsets_table.read_initial_state(&mut bitstream);
+            let mut match_length_state: usize =
+                match_lengths_table.read_initial_state(&mut bitstream);
+
+            for sequence_index in 0..number_of_sequences {
+                let literal_length_code: usize =
+                    literal_lengths_table.get_symbol(literal_length_state) as usize;
+                let offset_code: usize = offsets_table.get_symbol(offset_state) as usize;
+                let match_length_code: usize =
+                    match_lengths_table.get_symbol(match_length_state) as usize;
+
+                if literal_length_code > 35 || match_length_code > 52 || offset_code > 31 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid sequence code value out of bounds"
+                    ));
+                }
+                let offset_value: usize =
+                    (1 << offset_code) + bitstream.get_value(offset_code) as usize;
+                let match_length: usize = ZSTD_MATCH_LENGTH_CODES_BASE[match_length_code] as usize
+                    + bitstream.get_value(
+                        ZSTD_MATCH_LENGTH_CODES_NUMBER_OF_EXTRA_BITS[match_length_code] as usize,
+                    ) as usize;
+                let literal_length: usize = ZSTD_LITERAL_LENGTH_CODES_BASE[literal_length_code]
+                    as usize
+                    + bitstream.get_value(
+                        ZSTD_LITERAL_LENGTH_CODES_NUMBER_OF_EXTRA_BITS[literal_length_code]
+                            as usize,
+                    ) as usize;
+
+                if sequence_index + 1 < number_of_sequences {
+                    literal_length_state =
+                        literal_lengths_table.read_next_state(&mut bitstream, literal_length_state);
+                    match_length_state =
+                        match_lengths_table.read_next_state(&mut bitstream, match_length_state);
+                    offset_state = offsets_table.read_next_state(&mut bitstream, offset_state);
+                }
+                let offset: usize = if offset_value > 3 {
+                    let offset: usize = offset_value - 3;
+
+                    self.repeated_offsets =
+                        [offset, self.repeated_offsets[0], self.repeated_offsets[1]];
+                    offset
+                } else {
+                    // A literal length of 0 shifts the repeated offset index by 1.
+                    let repeated_offset_index: usize = if literal_length == 0 {
+                        offset_value
+                    } else {
+                        offset_value - 1
+                    };
+                    if repeated_offset_index == 0 {
+                        self.repeated_offsets[0]
+                    } else {
+                        let offset: usize = if repeated_offset_index == 3 {
+                            self.repeated_offsets[0].wrapping_sub(1)
+                        } else {
+                            self.repeated_offsets[repeated_offset_index]
+                        };
+                        if repeated_offset_index > 1 {
+                            self.repeated_offsets[2] = self.repeated_offsets[1];
+                        }
+                        self.repeated_offsets[1] = self.repeated_offsets[0];
+                        self.repeated_offsets[0] = offset;
+
+                        offset
+                    }
+                };
+                if literal_length > literals.len() - literals_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid literal length value out of bounds"
+                    ));
+                }
+                if literal_length + match_length
+                    > uncompressed_data_size - *uncompressed_data_offset
+                {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid uncompressed data value too small"
+                    ));
+                }
+                uncompressed_data
+                    [*uncompressed_data_offset..*uncompressed_data_offset + literal_length]
+                    .copy_from_slice(&literals[literals_offset..literals_offset + literal_length]);
+
+                literals_offset += literal_length;
+                *uncompressed_data_offset += literal_length;
+
+                if offset == 0 || offset > *uncompressed_data_offset - frame_start_offset {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid offset: {} value out of bounds",
+                        offset
+                    )));
+                }
+                let mut match_offset: usize = *uncompressed_data_offset - offset;
+
+                for _ in 0..match_length {
+                    uncompressed_data[*uncompressed_data_offset] = uncompressed_data[match_offset];
+
+                    match_offset += 1;
+                    *uncompressed_data_offset += 1;
+                }
+            }
+            if !bitstream.is_finished() {
+                return Err(keramics_core::error_trace_new!(
+                    "Mismatch between sequences bitstream size and decoded data"
+                ));
+            }
+        }
+        let literals_remainder_size: usize = literals.len() - literals_offset;
+
+        if literals_remainder_size > uncompressed_data_size - *uncompressed_data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid uncompressed data value too small"
+            ));
+        }
+        uncompressed_data
+            [*uncompressed_data_offset..*uncompressed_data_offset + literals_remainder_size]
+            .copy_from_slice(&literals[literals_offset..]);
+
+        *uncompressed_data_offset += literals_remainder_size;
+
+        Ok(())
+    }
+
+    /// Reads the literals section.
+    fn read_literals(
+        &mut self,
+        block_data: &[u8],
+        block_data_offset: &mut usize,
+    ) -> Result<Vec<u8>, ErrorTrace> {
+        let block_data_size: usize = block_data.len();
+        let mut data_offset: usize = *block_data_offset;
+
+        if data_offset >= block_data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let literals_type: u8 = block_data[data_offset] & 0x03;
+        let size_format: u8 = (block_data[data_offset] >> 2) & 0x03;
+
+        let (header_size, number_of_size_bits, number_of_streams): (usize, usize, usize) =
+            match literals_type {
+                ZSTD_LITERALS_TYPE_RAW | ZSTD_LITERALS_TYPE_RLE => match size_format {
+                    1 => (2, 12, 1),
+                    3 => (3, 20, 1),
+                    _ => (1, 5, 1),
+                },
+                _ => match size_format {
+                    0 => (3, 10, 1),
+                    1 => (3, 10, 4),
+                    2 => (4, 14, 4),
+                    _ => (5, 18, 4),
+                },
+            };
+        if header_size > block_data_size - data_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let mut header: u64 = 0;
+
+        for byte_index in 0..header_size {
+            header |= (block_data[data_offset + byte_index] as u64) << (byte_index * 8);
+        }
+        let header_bit_shift: usize = if header_size == 1 { 3 } else { 4 };
+        let size_bit_mask: u64 = (1 << number_of_size_bits) - 1;
+
+        let regenerated_size: usize = ((header >> header_bit_shift) & size_bit_mask) as usize;
+
+        data_offset += header_size;
+
+        let mut literals: Vec<u8> = vec![0; regenerated_size];
+
+        match literals_type {
+            ZSTD_LITERALS_TYPE_RAW => {
+                if regenerated_size > block_data_size - data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                literals.copy_from_slice(&block_data[data_offset..data_offset + regenerated_size]);
+
+                data_offset += regenerated_size;
+            }
+            ZSTD_LITERALS_TYPE_RLE => {
+                if data_offset >= block_data_size {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                literals.fill(block_data[data_offset]);
+
+                data_offset += 1;
+            }
+            _ => {
+                let compressed_size: usize =
+                    ((header >> (header_bit_shift + number_of_size_bits)) & size_bit_mask) as usize;
+
+                if compressed_size > block_data_size - data_offset {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                let mut compressed_data: &[u8] =
+                    &block_data[data_offset..data_offset + compressed_size];
+
+                if literals_type == ZSTD_LITERALS_TYPE_COMPRESSED {
+                    let mut huffman_table: ZstdHuffmanTable = ZstdHuffmanTable::new();
+
+                    let table_size: usize = match huffman_table.read_data(compressed_data) {
+                        Ok(table_size) => table_size,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to read Huffman table"
+                            );
+                            return Err(error);
+                        }
+                    };
+                    compressed_data = &compressed_data[table_size..];
+
+                    self.huffman_table = Some(huffman_table);
+                }
+                let huffman_table: &ZstdHuffmanTable = match self.huffman_table.as_ref() {
+                    Some(huffman_table) => huffman_table,
+                    None => {
+                        return Err(keramics_core::error_trace_new!("Missing Huffman table"));
+                    }
+                };
+                if number_of_streams == 1 {
+                    huffman_table.decode_stream(compressed_data, &mut literals)?;
+                } else {
+                    if 6 > compressed_data.len() {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid compressed data value too small"
+                        ));
+                    }
+                    let mut stream_sizes: [usize; 4] = [
+                        bytes_to_u16_le!(compressed_data, 0) as usize,
+                        bytes_to_u16_le!(compressed_data, 2) as usize,
+                        bytes_to_u16_le!(compressed_data, 4) as usize,
+                        0,
+                    ];
+                    let streams_size: usize = stream_sizes[0] + stream_sizes[1] + stream_sizes[2];
+
+                    if streams_size > compressed_data.len() - 6 {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid compressed data value too small"
+                        ));
+                    }
+                    stream_sizes[3] = compressed_data.len() - 6 - streams_size;
+
+                    let segment_size: usize = regenerated_size.div_ceil(4);
+
+                    if 3 * segment_size > regenerated_size {
+                        return Err(keramics_core::error_trace_new!(
+                            "Invalid regenerated size value out of bounds"
+                        ));
+                    }
+                    let mut stream_offset: usize = 6;
+                    let mut literals_offset: usize = 0;
+
+                    for (stream_index, stream_size) in stream_sizes.iter().enumerate() {
+                        let literals_end_offset: usize = if stream_index == 3 {
+                            regenerated_size
+                        } else {
+                            literals_offset + segment_size
+                        };
+                        huffman_table.decode_stream(
+                            &compressed_data[stream_offset..stream_offset + stream_size],
+                            &mut literals[literals_offset..literals_end_offset],
+                        )?;
+                        stream_offset += stream_size;
+                        literals_offset = literals_end_offset;
+                    }
+                }
+                data_offset += compressed_size;
+            }
+        }
+        *block_data_offset = data_offset;
+
+        Ok(literals)
+    }
+
+    /// Reads a sequence FSE table.
+    fn read_sequence_table(
+        fse_table: &mut Option<ZstdFseTable>,
+        compression_mode: u8,
+        block_data: &[u8],
+        block_data_offset: &mut usize,
+        default_distribution: &[i16],
+        default_accuracy_log: u8,
+        maximum_accuracy_log: u8,
+        maximum_symbol: usize,
+    ) -> Result<(), ErrorTrace> {
+        match compression_mode {
+            ZSTD_COMPRESSION_MODE_PREDEFINED => {
+                let mut predefined_fse_table: ZstdFseTable = ZstdFseTable::new();
+                predefined_fse_table.build(default_distribution, default_accuracy_log)?;
+
+                *fse_table = Some(predefined_fse_table);
+            }
+            ZSTD_COMPRESSION_MODE_RLE => {
+                if *block_data_offset >= block_data.len() {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid compressed data value too small"
+                    ));
+                }
+                let symbol: u8 = block_data[*block_data_offset];
+                *block_data_offset += 1;
+
+                if symbol as usize > maximum_symbol {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid symbol: {} value out of bounds",
+                        symbol
+                    )));
+                }
+                let mut rle_fse_table: ZstdFseTable = ZstdFseTable::new();
+                rle_fse_table.build_rle(symbol);
+
+                *fse_table = Some(rle_fse_table);
+            }
+            ZSTD_COMPRESSION_MODE_FSE => {
+                let mut bitstream: ZstdBitstream =
+                    ZstdBitstream::new(block_data, *block_data_offset);
+
+                let mut compressed_fse_table: ZstdFseTable = ZstdFseTable::new();
+                compressed_fse_table.read_from_bitstream(
+                    &mut bitstream,
+                    maximum_accuracy_log,
+                    maximum_symbol,
+                )?;
+                *block_data_offset = bitstream.data_offset;
+
+                *fse_table = Some(compressed_fse_table);
+            }
+            _ => {
+                if fse_table.is_none() {
+                    return Err(keramics_core::error_trace_new!(
+                        "Missing FSE table to repeat"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x28, 0xb5, 0x2f, 0xfd, 0x64, 0x5e, 0x2b, 0x1d, 0x77, 0x00, 0x2a, 0x7a, 0x2c, 0x14,
+            0x2e, 0xd0, 0x8c, 0x86, 0xb6, 0x0d, 0x36, 0x0c, 0x6a, 0xc9, 0xbe, 0xf4, 0x73, 0x29,
+            0xff, 0x3f, 0x9b, 0x34, 0xc8, 0xf2, 0x1c, 0x0c, 0x29, 0x70, 0x5a, 0x4e, 0x2a, 0x38,
+            0xcc, 0x73, 0x21, 0x5f, 0x04, 0x37, 0xd7, 0x2b, 0x64, 0x06, 0xc1, 0x05, 0x2f, 0xf5,
+            0x30, 0x3a, 0xf6, 0x30, 0x71, 0x3d, 0x01, 0x25, 0x01, 0x2c, 0x01, 0xcc, 0x19, 0x7a,
+            0x14, 0x57, 0xfc, 0x56, 0xd2, 0xfa, 0x34, 0x4d, 0x5b, 0x15, 0x7f, 0xe2, 0x2c, 0xdf,
+            0x52, 0x4a, 0xcb, 0x85, 0x82, 0x79, 0x19, 0x50, 0xf1, 0xd6, 0x02, 0x4b, 0x55, 0xca,
+            0xbc, 0x7d, 0xe9, 0x73, 0xd3, 0x74, 0xb5, 0x74, 0xed, 0xde, 0x79, 0x17, 0x01, 0x34,
+            0x30, 0x80, 0x41, 0xf1, 0x21, 0x8c, 0x1c, 0xa3, 0x0c, 0xa4, 0x4e, 0x2f, 0x54, 0x19,
+            0xde, 0x5a, 0xc9, 0x3b, 0x15, 0x4c, 0x8e, 0x7a, 0xfd, 0x26, 0x5b, 0x3e, 0xc8, 0xef,
+            0x97, 0x0a, 0xd7, 0x61, 0x6a, 0xea, 0xd4, 0x59, 0x17, 0x26, 0x7e, 0x65, 0x17, 0xca,
+            0x80, 0xad, 0x50, 0xf4, 0x7b, 0x21, 0x7e, 0x10, 0xaf, 0x04, 0x64, 0x38, 0x4e, 0x2b,
+            0xd8, 0xf6, 0x79, 0x56, 0x21, 0x7f, 0x71, 0x26, 0x76, 0x4e, 0x4c, 0x6c, 0xd9, 0xf5,
+            0xf5, 0x8e, 0x5b, 0xc6, 0x52, 0x25, 0xbe, 0x3d, 0x07, 0xb2, 0x6c, 0xee, 0xb0, 0x69,
+            0xea, 0x5a, 0x72, 0x83, 0xad, 0xe8, 0xd2, 0x71, 0x15, 0x28, 0xaf, 0xe3, 0x5e, 0x62,
+            0xe4, 0xd4, 0xb5, 0x23, 0x2f, 0xf2, 0x5b, 0x65, 0xd1, 0x39, 0x8d, 0x72, 0x04, 0xad,
+            0xe2, 0x75, 0xed, 0xcf, 0x53, 0xbb, 0xb0, 0xd5, 0x71, 0xc8, 0x2c, 0x2a, 0x80, 0x4e,
+            0xf3, 0x96, 0x1d, 0x87, 0x03, 0xa8, 0x4b, 0x30, 0x5a, 0x70, 0x7d, 0x61, 0x27, 0xbe,
+            0x45, 0xd9, 0xb5, 0xae, 0xa4, 0xde, 0x5a, 0x86, 0xce, 0x98, 0xde, 0x0f, 0x79, 0x19,
+            0x5c, 0x6d, 0x15, 0xae, 0x96, 0xd8, 0x0f, 0xdf, 0xe1, 0x1d, 0x87, 0x2d, 0xff, 0x20,
+            0x63, 0xe9, 0x34, 0x2a, 0x14, 0x0b, 0x18, 0xdd, 0x56, 0x9d, 0x36, 0x65, 0xee, 0x80,
+            0x8c, 0x01, 0x24, 0x20, 0x3c, 0x22, 0x13, 0xc9, 0xc3, 0x21, 0x45, 0x90, 0x70, 0x80,
+            0x88, 0x44, 0x38, 0x22, 0x98, 0x1c, 0x12, 0x09, 0x8c, 0x40, 0x48, 0x40, 0x09, 0x04,
+            0xe2, 0x31, 0x79, 0x38, 0x40, 0x20, 0x22, 0xa0, 0x03, 0xa4, 0x81, 0x12, 0x0a, 0x11,
+            0xc7, 0x64, 0x01, 0x83, 0x66, 0x0c, 0x1a, 0x5e, 0x46, 0x32, 0x2a, 0x95, 0x0a, 0x03,
+            0x06, 0x0f, 0x20, 0xe7, 0x9c, 0x02, 0x1a, 0x34, 0x70, 0x80, 0x11, 0xe7, 0x6b, 0xd9,
+            0x00, 0xa6, 0x06, 0x0c, 0x07, 0xd8, 0x56, 0x9b, 0xff, 0xc0, 0xbb, 0x5d, 0xe9, 0x03,
+            0xde, 0x51, 0x09, 0x35, 0x70, 0x41, 0x8e, 0x6a, 0xa0, 0x68, 0x37, 0xb4, 0x6d, 0x5a,
+            0x59, 0x8c, 0xe5, 0x73, 0x87, 0x12, 0xdf, 0x5a, 0x88, 0x16, 0x44, 0x47, 0xe5, 0xd7,
+            0x3d, 0x09, 0x46, 0x48, 0x66, 0x00, 0x19, 0x0e, 0x16, 0xe2, 0x55, 0xcc, 0xb5, 0x3f,
+            0x0a, 0xde, 0x14, 0xbd, 0x3a, 0x63, 0xfb, 0x17, 0xec, 0x74, 0xf0, 0x51, 0x30, 0x30,
+            0xd1, 0x2d, 0x44, 0xe1, 0xb2, 0x35, 0xda, 0xe7, 0x0b, 0x9c, 0x0f, 0x46, 0xac, 0xdf,
+            0x3e, 0xa5, 0x50, 0x4f, 0x3c, 0x3d, 0xfd, 0x54, 0xeb, 0x4a, 0xe7, 0xc4, 0xbf, 0x6e,
+            0xed, 0xe7, 0xae, 0xa3, 0x4e, 0xed, 0x5a, 0x19, 0xd3, 0x7c, 0x30, 0x75, 0xbb, 0x63,
+            0x7b, 0x8d, 0x2b, 0x2c, 0x97, 0xa4, 0xb4, 0x2d, 0x3b, 0x75, 0x76, 0x85, 0x02, 0x99,
+            0x45, 0x86, 0xa5, 0xf3, 0xa5, 0xa9, 0xd3, 0x67, 0x79, 0x11, 0x63, 0xd9, 0x78, 0x5d,
+            0xea, 0x5a, 0x6d, 0x23, 0x30, 0x7b, 0x95, 0x0c, 0xdf, 0x35, 0x5d, 0xfc, 0xc9, 0xd6,
+            0x74, 0xb9, 0xd0, 0x94, 0x61, 0xc4, 0xce, 0x7f, 0x38, 0xa1, 0x6b, 0x3e, 0x67, 0x96,
+            0x4d, 0x1b, 0x65, 0x9b, 0xae, 0xbd, 0xcf, 0xcb, 0xbb, 0x24, 0xbb, 0x4b, 0xb7, 0x4d,
+            0x29, 0x67, 0x5e, 0xa7, 0xeb, 0x8e, 0xd2, 0xf5, 0xd5, 0x3a, 0x17, 0x45, 0xe3, 0x29,
+            0xea, 0x92, 0xa2, 0x7c, 0x87, 0x45, 0x65, 0xd9, 0x17, 0x9c, 0xd0, 0xd5, 0x0e, 0x41,
+            0x0d, 0xa5, 0x2c, 0x43, 0x67, 0xd0, 0x93, 0x13, 0xc7, 0x96, 0x0c, 0x8e, 0x4a, 0xe7,
+            0xd6, 0xaf, 0x34, 0x53, 0x35, 0x5b, 0x92, 0x69, 0x52, 0x12, 0xbf, 0xf2, 0x69, 0x5a,
+            0xb9, 0xc0, 0x5b, 0x01, 0x8a, 0x1c, 0xa6, 0xae, 0x3f, 0xeb, 0xf8, 0x94, 0xe4, 0xb7,
+            0x37, 0xab, 0x50, 0xc2, 0x74, 0xc9, 0xb2, 0x5b, 0x32, 0x4b, 0xa5, 0x2d, 0x1d, 0xf5,
+            0x07, 0x9f, 0x65, 0x86, 0xbd, 0x3e, 0x47, 0x4e, 0x7d, 0x82, 0x05, 0xa2, 0xcf, 0xf8,
+            0x35, 0xca, 0xa4, 0xf4, 0xb5, 0x0a, 0x1b, 0xcf, 0x59, 0x71, 0xcc, 0x36, 0xae, 0xd3,
+            0x47, 0x19, 0xba, 0x6d, 0xe8, 0xde, 0xf2, 0x98, 0x6a, 0x9a, 0x4a, 0x19, 0x5d, 0xfb,
+            0x5c, 0x60, 0xd6, 0x99, 0x1f, 0x92, 0x87, 0xa4, 0x01, 0x01, 0x15, 0x92, 0x07, 0x4a,
+            0x1a, 0x8a, 0xc5, 0x45, 0x3b, 0x65, 0xe8, 0x14, 0x71, 0x56, 0xe0, 0xb7, 0x3a, 0x20,
+            0x03, 0x81, 0x45, 0x59, 0xf5, 0x17, 0x36, 0x8d, 0x38, 0x9f, 0x75, 0xfd, 0xda, 0x76,
+            0x9e, 0x59, 0x1f, 0x05, 0x67, 0xbc, 0x52, 0x0a, 0x77, 0xf7, 0x77, 0xf5, 0x04, 0x19,
+            0x07, 0x70, 0x36, 0x67, 0xf5, 0x89, 0x47, 0xe7, 0xe6, 0x8e, 0x17, 0xbf, 0xde, 0x7c,
+            0xe8, 0x75, 0xd3, 0x66, 0x17, 0xb7, 0x10, 0xc6, 0x12, 0x31, 0xa7, 0x4a, 0x19, 0x34,
+            0xf1, 0xee, 0xb3, 0x26, 0x81, 0x8c, 0x36, 0x40, 0x99, 0x3e, 0xfe, 0xd2, 0x92, 0xe5,
+            0xf3, 0x56, 0x7b, 0xcb, 0x2f, 0xed, 0xc4, 0x6b, 0x65, 0xc4, 0xf2, 0xce, 0x04, 0x19,
+            0x9c, 0x7f, 0x78, 0x5b, 0xd4, 0xb0, 0x98, 0x4f, 0x61, 0x32, 0xf1, 0x36, 0x8c, 0x62,
+            0x3e, 0x60, 0xd0, 0x3b, 0xcf, 0xd0, 0x65, 0xfa, 0x57, 0x9e, 0x9d, 0x62, 0x27, 0xba,
+            0x89, 0x3b, 0x54, 0x38, 0x22, 0x3c, 0x26, 0x10, 0x10, 0xc5, 0x03, 0x17, 0x8b, 0x77,
+            0xb9, 0xfc, 0x4a, 0x29, 0xa5, 0xa6, 0xe9, 0xce, 0xaa, 0xdb, 0x9b, 0x3e, 0x11, 0xf3,
+            0xfa, 0xb2, 0x40, 0xba, 0x76, 0x85, 0xb4, 0x41, 0xd7, 0x6f, 0xb1, 0xe0, 0x41, 0x65,
+            0x19, 0x5b, 0xe6, 0x7f, 0xd2, 0xa6, 0xe7, 0x29, 0x46, 0xf9, 0x30, 0x1e, 0x41, 0x46,
+            0x01, 0xab, 0xd5, 0xe5, 0xea, 0x8c, 0x54, 0x37, 0x67, 0x4e, 0x5f, 0x57, 0x92, 0x61,
+            0xbe, 0x55, 0x0d, 0xdd, 0xcb, 0x0c, 0xd9, 0x64, 0xa3, 0xee, 0xee, 0x39, 0x75, 0xcd,
+            0x3c, 0x90, 0xd1, 0x59, 0x59, 0xd7, 0x74, 0x79, 0x5b, 0x96, 0xb8, 0x68, 0x06, 0xd6,
+            0x37, 0x4d, 0x99, 0x51, 0xce, 0xee, 0xab, 0xb7, 0x7e, 0x34, 0x99, 0x7a, 0x6f, 0xc4,
+            0x9a, 0x32, 0x08, 0x4a, 0xbe, 0xe3, 0x4d, 0x8c, 0x9d, 0xcf, 0x30, 0x7b, 0x4a, 0x3a,
+            0x98, 0x0e, 0xe1, 0xa2, 0x5d, 0x3b, 0xe8, 0x0c, 0xed, 0x2e, 0x23, 0x08, 0xe8, 0x14,
+            0x25, 0x83, 0x22, 0xa6, 0x94, 0x2d, 0x0f, 0x3a, 0x43, 0x98, 0xae, 0x96, 0x72, 0x66,
+            0xc5, 0xf4, 0x33, 0xd3, 0x10, 0x39, 0x96, 0xd1, 0x36, 0x9c, 0x01, 0x8d, 0x4f, 0xd9,
+            0x06, 0xdf, 0xe4, 0x6b, 0x5a, 0x32, 0xfc, 0x25, 0xad, 0x0d, 0x0e, 0x09, 0xe4, 0xc1,
+            0xe5, 0xb2, 0x4d, 0x17, 0x58, 0xdc, 0x71, 0x0c, 0x98, 0x24, 0x60, 0x36, 0x02, 0x94,
+            0x20, 0x8a, 0xa2, 0x28, 0x02, 0x8a, 0x3a, 0x8a, 0x53, 0x3b, 0xdf, 0x89, 0x0a, 0x65,
+            0x48, 0xd1, 0x50, 0xf7, 0x19, 0xb7, 0x59, 0xe2, 0x27, 0xd2, 0xb5, 0xa8, 0xbf, 0xe0,
+            0x62, 0x6f, 0x9d, 0xc6, 0x05, 0x00, 0x4e, 0x80, 0xb6, 0x0e, 0x0c, 0xc8, 0x88, 0x54,
+            0x32, 0x85, 0x04, 0x11, 0x28, 0xb0, 0xdd, 0x97, 0x5e, 0xa6, 0xda, 0xb1, 0x17, 0xbb,
+            0xba, 0x64, 0x10, 0x9f, 0xe9, 0xa6, 0xc9, 0x03, 0x08, 0x1c, 0x11, 0x11, 0x0d, 0x07,
+            0x88, 0xa3, 0xc2, 0x6f, 0xa3, 0xb4, 0xee, 0xf8, 0xcc, 0xef, 0xe1, 0x5c, 0x6c, 0x9d,
+            0xd5, 0xce, 0x38, 0x95, 0x95, 0x9d, 0xa0, 0xad, 0xc8, 0xcb, 0xa0, 0x51, 0xb6, 0x38,
+            0x6d, 0xcf, 0x2b, 0xd0, 0x55, 0xcb, 0xc4, 0x74, 0x06, 0x13, 0x7b, 0xf7, 0x4c, 0x9d,
+            0x0a, 0x0d, 0xd5, 0x40, 0x86, 0x02, 0xab, 0xdd, 0x0b, 0xad, 0xb7, 0x52, 0x56, 0x32,
+            0x8a, 0x69, 0xd1, 0x6f, 0xd9, 0x1e, 0xc3, 0x92, 0x1f, 0xd3, 0x64, 0x5e, 0x7a, 0x93,
+            0x10, 0xb4, 0x8e, 0x69, 0x65, 0xed, 0x36, 0xcb, 0x1e, 0x27, 0xf2, 0x59, 0xaa, 0x6b,
+            0x5f, 0x7a, 0xa8, 0x9b, 0x7c, 0x52, 0x19, 0x3b, 0xc5, 0xba, 0x97, 0x9b, 0x45, 0x19,
+            0xc3, 0x4b, 0x8b, 0xab, 0xf6, 0x46, 0x59, 0xf3, 0xe6, 0x78, 0x5d, 0xc4, 0xcb, 0xb2,
+            0x39, 0x73, 0xed, 0x94, 0xe3, 0x83, 0xe9, 0x92, 0xfa, 0xf0, 0x80, 0x8c, 0x04, 0xae,
+            0x6a, 0xbb, 0xf5, 0xcf, 0xeb, 0xee, 0xd7, 0x57, 0x86, 0x96, 0xb5, 0xdb, 0x42, 0xcd,
+            0xce, 0x1c, 0x12, 0x88, 0x88, 0x88, 0x44, 0x04, 0x46, 0x1a, 0x0f, 0x12, 0x91, 0x46,
+            0x44, 0x43, 0xe2, 0x80, 0x4c, 0x1e, 0x12, 0x2c, 0x4c, 0x1e, 0x0f, 0x0f, 0x08, 0x8d,
+            0x09, 0x11, 0x22, 0x0e, 0x0a, 0x98, 0x26, 0x10, 0x07, 0x05, 0x07, 0x03, 0x90, 0x47,
+            0x44, 0x02, 0xe4, 0xd1, 0x00, 0x49, 0x8e, 0x87, 0xc9, 0x63, 0x72, 0x5d, 0xda, 0x7d,
+            0xd6, 0x63, 0x72, 0x80, 0x3c, 0x1c, 0x40, 0x0b, 0x90, 0x06, 0x4a, 0x80, 0x88, 0x40,
+            0xa0, 0xc4, 0xf1, 0x98, 0x40, 0x1a, 0x91, 0x48, 0xa3, 0x02, 0x4e, 0x24, 0x20, 0x26,
+            0x4c, 0x1e, 0x29, 0x84, 0x5f, 0xa8, 0xa3, 0xa9, 0x81, 0xa0, 0xb4, 0x10, 0x11, 0x8d,
+            0x8c, 0x24, 0x49, 0xa1, 0x03, 0x43, 0x40, 0x10, 0x40, 0x60, 0xb0, 0xc0, 0x11, 0x32,
+            0x89, 0x4a, 0x6e, 0x1f, 0x43, 0xf0, 0xd2, 0xca, 0x6c, 0x28, 0x05, 0xa9, 0x30, 0x02,
+            0x89, 0x52, 0x46, 0x46, 0x48, 0x02, 0x91, 0x20, 0x25, 0x49, 0x92, 0xc2, 0x32, 0x07,
+            0xa3, 0x70, 0x28, 0xeb, 0xd9, 0x11, 0x63, 0x22, 0xb4, 0x43, 0x74, 0x1f, 0x79, 0xe3,
+            0xa8, 0x0d, 0x3a, 0xba, 0x81, 0x37, 0x84, 0x3a, 0x39, 0xdf, 0x1e, 0xeb, 0x82, 0x4b,
+            0xa7, 0x70, 0xa8, 0x9f, 0xdc, 0x44, 0xdd, 0x4c, 0xc8, 0x2d, 0x16, 0x85, 0xda, 0xef,
+            0x8c, 0x34, 0xff, 0x50, 0xae, 0xd2, 0xb9, 0xb1, 0x76, 0xa7, 0xc6, 0xc8, 0xef, 0xb4,
+            0xa3, 0xd9, 0xa9, 0x6a, 0xb6, 0x2e, 0x33, 0x1a, 0x2b, 0x16, 0x56, 0xcc, 0xa0, 0xe6,
+            0x7f, 0xe4, 0xfe, 0x03, 0x45, 0x8a, 0x47, 0x02, 0x15, 0x5d, 0xac, 0xbc, 0x51, 0xe8,
+            0x8e, 0xc2, 0xaa, 0x8a, 0xc2, 0x24, 0xf9, 0x37, 0xc0, 0xd0, 0x77, 0xa9, 0x0b, 0xa0,
+            0x50, 0xfd, 0x10, 0xa2, 0xdf, 0x41, 0x21, 0x8a, 0x0e, 0xa4, 0xf1, 0x9d, 0xd6, 0xbf,
+            0xb0, 0xbb, 0x41, 0xb1, 0x26, 0xb5, 0xb4, 0x42, 0x07, 0xb7, 0x68, 0xc4, 0x58, 0x79,
+            0xdd, 0x83, 0xc4, 0x93, 0x3f, 0x54, 0x13, 0x74, 0x16, 0xba, 0x9f, 0xa1, 0xc3, 0xa6,
+            0x92, 0xd7, 0x4a, 0xe2, 0x69, 0x72, 0x98, 0x9a, 0xac, 0xf2, 0x9f, 0x71, 0x7d, 0x80,
+            0x85, 0x20, 0x87, 0xd4, 0x64, 0x8b, 0xd4, 0xb2, 0x56, 0xd0, 0x1d, 0xf2, 0xaf, 0x4d,
+            0x0e, 0x6f, 0xa5, 0x28, 0x8d, 0x11, 0xcf, 0x15, 0x30, 0x09, 0x03, 0xfb, 0xe2, 0x89,
+            0xa1, 0x8f, 0xcc, 0x52, 0x3f, 0xbb, 0x9d, 0xee, 0x2b, 0x84, 0x2f, 0x70, 0xa1, 0xab,
+            0x7a, 0x65, 0xec, 0xa1, 0x83, 0xf4, 0xd3, 0x96, 0x1c, 0x37, 0xfe, 0x9b, 0xae, 0xcc,
+            0x2f, 0x24, 0x2b, 0x7a, 0xa1, 0xc4, 0x15, 0x2f, 0x30, 0xa0, 0x92, 0xc4, 0x54, 0xee,
+            0x37, 0xf8, 0x59, 0xc3, 0x91, 0xf5, 0x4c, 0x61, 0x36, 0xc8, 0xb4, 0x56, 0x6d, 0x14,
+            0x14, 0x17, 0xa5, 0xd0, 0xd5, 0x14, 0x8d, 0xce, 0x69, 0x8d, 0xfd, 0xe9, 0x88, 0xfe,
+            0x18, 0xe6, 0x7b, 0x72, 0x41, 0xde, 0xaa, 0x8a, 0x27, 0xa6, 0x1b, 0x97, 0x0c, 0x4d,
+            0x81, 0x41, 0xca, 0x57, 0x38, 0x02, 0xb6, 0x6e, 0x27, 0x86, 0x78, 0x28, 0xd2, 0xd1,
+            0xd2, 0x91, 0x08, 0xf4, 0xee, 0xb8, 0x7a, 0x52, 0x67, 0x70, 0xd8, 0xac, 0x91, 0x59,
+            0x59, 0x8e, 0x15, 0xa9, 0xa8, 0xcb, 0xcc, 0xe0, 0x4d, 0x8d, 0xd9, 0x9d, 0xf4, 0x58,
+            0xd2, 0x07, 0x1b, 0x61, 0x8d, 0x08, 0xc7, 0xf6, 0xb9, 0x12, 0xbc, 0x06, 0x09, 0x16,
+            0x79, 0xb1, 0x0f, 0x21, 0xff, 0xe9, 0x0f, 0x11, 0x05, 0x45, 0x8d, 0xd6, 0xba, 0x32,
+            0x50, 0x81, 0xaf, 0x45, 0x48, 0xd5, 0x59, 0x92, 0x12, 0x6b, 0x47, 0x36, 0x2b, 0x2b,
+            0xc5, 0x31, 0x25, 0x5a, 0x7d, 0x77, 0x83, 0x46, 0xe2, 0xef, 0xa6, 0x47, 0xe4, 0x53,
+            0x63, 0xe2, 0xc7, 0xbc, 0x5c, 0x59, 0x00, 0x06, 0x12, 0xc3, 0xcd, 0xd3, 0xd0, 0x95,
+            0x8c, 0x09, 0x17, 0x15, 0x8f, 0xb5, 0x01, 0x54, 0xd7, 0xdc, 0x52, 0x62, 0xbf, 0x6a,
+            0x01, 0x2d, 0x68, 0x81, 0x6b, 0xac, 0xe8, 0xb0, 0x63, 0x36, 0x79, 0xdc, 0x33, 0x81,
+            0xcc, 0xaa, 0x42, 0xc9, 0x12, 0xb9, 0x59, 0x29, 0x1c, 0x53, 0xb4, 0xee, 0xca, 0x61,
+            0x6b, 0x12, 0xdb, 0xe9, 0x6d, 0x2f, 0x14, 0x3d, 0x99, 0x28, 0xbc, 0x96, 0xdb, 0xaf,
+            0xed, 0xb8, 0x7d, 0xd9, 0x05, 0x61, 0x04, 0xcd, 0x3a, 0xab, 0xf7, 0xfb, 0xea, 0x27,
+            0xdc, 0x71, 0xe1, 0x53, 0x41, 0x45, 0x14, 0x34, 0x70, 0x1f, 0x02, 0xe6, 0x6c, 0x88,
+            0x43, 0x1d, 0xee, 0xa1, 0x5b, 0x19, 0x47, 0xed, 0x45, 0x11, 0xbc, 0xf5, 0x84, 0x0f,
+            0x98, 0x9e, 0x80, 0x16, 0xb0, 0x1b, 0x44, 0xd0, 0xe0, 0xd8, 0xa0, 0x48, 0xd3, 0xf3,
+            0x62, 0xf3, 0x02, 0x0a, 0x43, 0x1c, 0x91, 0x1f, 0xda, 0x3d, 0xad, 0x50, 0x3f, 0xcb,
+            0xc8, 0xe5, 0x8b, 0x57, 0x18, 0xf0, 0x75, 0x9f, 0x5b, 0xed, 0xa0, 0x8b, 0x76, 0x8b,
+            0x5c, 0x40, 0x3f, 0x63, 0x44, 0xe5, 0xcd, 0x6e, 0xd8, 0x1c, 0xff, 0xd3, 0x2d, 0xf9,
+            0xe3, 0x6e, 0x7e, 0x6b, 0x82, 0x50, 0x2e, 0x2c, 0x4d, 0xa8, 0x98, 0xf0, 0xba, 0xd4,
+            0x5a, 0x05, 0xa3, 0x16, 0x0c, 0x81, 0x2e, 0x9e, 0xaf, 0x27, 0x8a, 0x4a, 0x90, 0x0e,
+            0x24, 0xfc, 0x06, 0x8b, 0xf5, 0xf8, 0x24, 0xde, 0x2b, 0x0a, 0x34, 0xc0, 0x1f, 0xb6,
+            0xbc, 0x98, 0xc1, 0x41, 0xc8, 0x4e, 0x50, 0xad, 0x60, 0xa4, 0xe2, 0x5b, 0x62, 0x5e,
+            0xc7, 0xce, 0x4b, 0x09, 0x0e, 0xea, 0x48, 0x1d, 0xc7, 0xf5, 0x89, 0x62, 0x41, 0x87,
+            0x3d, 0xcf, 0xe8, 0xe5, 0x76, 0xb9, 0x33, 0xf5, 0x78, 0x47, 0xfb, 0x36, 0x30, 0x56,
+            0xb3, 0xae, 0xf3, 0x5f, 0xda, 0xe9, 0xba, 0x19, 0x0e, 0x04, 0x16, 0x11, 0xfb, 0x66,
+            0x07, 0xd0, 0x5b, 0x69, 0x78, 0xab, 0x11, 0x89, 0x05, 0xd0, 0x06, 0xcd, 0xd6, 0x47,
+            0x82, 0xc8, 0x5a, 0x23, 0xb0, 0x5b, 0x8d, 0x55, 0x8e, 0xa1, 0x3b, 0x33, 0xd9, 0x5c,
+            0x94, 0x7b, 0xcf, 0x9f, 0x32, 0xbe, 0xd5, 0xe5, 0x1d, 0x24, 0x89, 0x99, 0xb1, 0x94,
+            0x07, 0xa2, 0x53, 0x7b, 0xaf, 0x51, 0x46, 0x28, 0xc3, 0xd7, 0xce, 0xec, 0x44, 0x1e,
+            0x06, 0x7e, 0x6d, 0x3a, 0x86, 0x2f, 0x87, 0xb8, 0xf5, 0x08, 0x6d, 0xa7, 0x8f, 0xde,
+            0x8c, 0x10, 0x90, 0x65, 0x23, 0x2f, 0xfc, 0xa7, 0x62, 0xd0, 0x3c, 0x1e, 0x2e, 0x7c,
+            0x61, 0x2e, 0x05, 0xba, 0x24, 0xb0, 0x0e, 0x04, 0xc1, 0x6e, 0xa5, 0x38, 0x45, 0x13,
+            0x9b, 0x4a, 0xe9, 0x6e, 0xf1, 0xab, 0xe6, 0x19, 0xde, 0x97, 0x54, 0x7e, 0xb1, 0x48,
+            0xf3, 0x3f, 0x3a, 0x45, 0xea, 0xa8, 0x7e, 0xe9, 0x0a, 0x78, 0x52, 0xd4, 0xd7, 0xd4,
+            0x4a, 0x1c, 0x38, 0x7e, 0x21, 0xe1, 0x7a, 0xff, 0x1b, 0x43, 0x5b, 0x4c, 0x18, 0x1b,
+            0x98, 0x75, 0x72, 0x55, 0x81, 0x30, 0xab, 0xab, 0xef, 0x95, 0xd8, 0x4a, 0x51, 0x84,
+            0xeb, 0x56, 0x84, 0xc6, 0x6f, 0x54, 0x4e, 0x03, 0xf8, 0xb4, 0xdd, 0xd0, 0xbb, 0x06,
+            0x6e, 0x11, 0x5a, 0xc2, 0xd8, 0xa8, 0xcd, 0x8c, 0xd6, 0x62, 0x6b, 0x95, 0x1e, 0x58,
+            0xec, 0xf8, 0x5b, 0x91, 0xc1, 0x3a, 0x20, 0xeb, 0xb1, 0x74, 0xa1, 0xaf, 0x6a, 0x9c,
+            0x87, 0x75, 0x63, 0x1d, 0x37, 0x81, 0xdc, 0x63, 0xb8, 0x5e, 0x82, 0x27, 0xb4, 0x14,
+            0xae, 0xb0, 0x6a, 0x28, 0xad, 0x15, 0x03, 0x38, 0x08, 0x23, 0x4c, 0xb5, 0x1f, 0xb4,
+            0xb2, 0x15, 0x1a, 0xec, 0x76, 0xf9, 0x65, 0x90, 0x4b, 0x93, 0x45, 0x23, 0xe3, 0x08,
+            0x01, 0x28, 0x6a, 0xaa, 0x3f, 0xbe, 0xba, 0x93, 0x52, 0x3c, 0xc7, 0x1c, 0x9b, 0x97,
+            0x56, 0xd6, 0x54, 0xc9, 0x9e, 0xf0, 0xbb, 0xc3, 0xa2, 0x1a, 0x92, 0x3a, 0x85, 0x21,
+            0xf9, 0xaa, 0x35, 0x26, 0x81, 0xc2, 0x48, 0x3c, 0xca, 0x8a, 0x77, 0xea, 0x26, 0x0b,
+            0xb3, 0xdf, 0x31, 0x8d, 0x82, 0xd3, 0x97, 0xb8, 0xf6, 0x81, 0x5c, 0xd0, 0xf3, 0x93,
+            0xc6, 0x4d, 0xa3, 0xa8, 0x0e, 0xd4, 0xa2, 0x8e, 0x3d, 0xab, 0x2b, 0xd0, 0xa8, 0xdd,
+            0xa3, 0x87, 0x39, 0xf2, 0x9a, 0xdc, 0x3e, 0x71, 0xf2, 0x95, 0x53, 0x61, 0x00, 0x40,
+            0xcb, 0xa3, 0x01, 0x1b, 0x40, 0x88, 0x65, 0x04, 0xd7, 0x5e, 0x65, 0x0b, 0x17, 0x30,
+            0x41, 0x4f, 0x73, 0xd5, 0x80, 0x33, 0xe1, 0x69, 0x46, 0x29, 0x4a, 0x03, 0x41, 0xeb,
+            0x4a, 0x33, 0x90, 0xb2, 0xc4, 0x7d, 0x32, 0x32, 0x6f, 0xeb, 0x14, 0x4c, 0x55, 0xac,
+            0xae, 0x12, 0x3a, 0x86, 0xdb, 0x27, 0x4d, 0xf0, 0x6f, 0x3e, 0x74, 0xb6, 0x3a, 0x52,
+            0x20, 0x20, 0x4b, 0x19, 0x2b, 0xee, 0x44, 0xe0, 0x1e, 0xf9, 0x5b, 0x5a, 0x98, 0x56,
+            0x1b, 0x65, 0x15, 0xa8, 0x8a, 0x37, 0x7c, 0x01, 0x53, 0xae, 0x7a, 0xcb, 0x09, 0xb1,
+            0x41, 0x97, 0x49, 0xa4, 0xe4, 0xb8, 0x10, 0xfc, 0x02, 0x20, 0xc0, 0xf7, 0xa7, 0xa5,
+            0x2b, 0xcf, 0xa2, 0xc5, 0x4d, 0xa6, 0xc5, 0x2e, 0xda, 0x39, 0x70, 0xc6, 0xa0, 0x87,
+            0x0b, 0x70, 0x41, 0xe0, 0xdb, 0x41, 0xe5, 0x40, 0x86, 0x44, 0x46, 0xc1, 0x35, 0x34,
+            0x1d, 0xdf, 0xe0, 0xcf, 0x05, 0x45, 0x8d, 0xee, 0x74, 0x55, 0x1d, 0x5c, 0x4f, 0xb5,
+            0x91, 0x5f, 0x85, 0x05, 0x56, 0xbe, 0x86, 0x8b, 0x92, 0x8c, 0xaf, 0x9e, 0x64, 0xa9,
+            0x44, 0x98, 0x67, 0x73, 0xfb, 0x7a, 0x1d, 0x39, 0x7d, 0x30, 0xf6, 0x5a, 0x5e, 0x5f,
+            0xf7, 0x25, 0x7e, 0x29, 0xd4, 0x00, 0xb6, 0xce, 0xe8, 0x93, 0x77, 0xfa, 0x06, 0x02,
+            0xeb, 0x7c, 0x8f, 0x6a, 0x86, 0x61, 0x86, 0xc8, 0xdf, 0x71, 0xe1, 0x20, 0x9a, 0x57,
+            0xe6, 0x53, 0x0c, 0xfa, 0xdd, 0x05, 0x0d, 0x58, 0x81, 0x08, 0x65, 0x5b, 0x72, 0x33,
+            0x85, 0xf9, 0x7c, 0x36, 0x5d, 0x32, 0xbe, 0xa7, 0xd3, 0x4a, 0xeb, 0x96, 0xb1, 0x35,
+            0xa2, 0xda, 0x2c, 0xc0, 0x1a, 0x0b, 0x11, 0x8a, 0xd7, 0xf6, 0xd9, 0xf0, 0xe9, 0x29,
+            0x89, 0xbf, 0x81, 0x42, 0x4d, 0xed, 0xa5, 0x64, 0xf1, 0x46, 0xe9, 0x94, 0xaf, 0x2a,
+            0x51, 0x84, 0x2f, 0x63, 0x33, 0x5a, 0xfe, 0x0a, 0x39, 0x93, 0xaa, 0x0e, 0x47, 0x5a,
+            0x15, 0x45, 0x65, 0xb3, 0x52, 0xfd, 0xdc, 0x9a, 0x8d, 0xd9, 0xcc, 0xd0, 0xc1, 0x30,
+            0x36, 0xde, 0x0b, 0x76, 0x0a, 0xba, 0xbf, 0x83, 0xaf, 0x55, 0xc6, 0xa3, 0xcc, 0x99,
+            0x29, 0x95, 0x84, 0xc2, 0xe2, 0x74, 0x67, 0xfd, 0xd9, 0x65, 0xc2, 0xb7, 0x1a, 0xb0,
+            0x13, 0x16, 0x5c, 0x6b, 0x00, 0x7f, 0xbe, 0xc3, 0xb9, 0xa8, 0xbb, 0xc1, 0x5f, 0xc7,
+            0x09, 0xc2, 0xe1, 0x98, 0xc2, 0xf5, 0x6f, 0x35, 0x83, 0xce, 0x1b, 0xae, 0x4b, 0xa4,
+            0x38, 0xea, 0x2c, 0xcd, 0xa1, 0x33, 0x18, 0xa0, 0x1d, 0x44, 0x50, 0x7c, 0xf7, 0x46,
+            0xa6, 0x55, 0xa7, 0x9c, 0x48, 0x98, 0xea, 0x7c, 0x39, 0x96, 0xbb, 0x08, 0x79, 0x9d,
+            0xb1, 0xed, 0xa4, 0x93, 0x46, 0x6a, 0x84, 0x33, 0x43, 0x1e, 0x5e, 0x16, 0x4f, 0x01,
+            0x48, 0x7b, 0x78, 0x49, 0xc1, 0x65, 0xa5, 0x0c, 0xa6, 0x8c, 0xaf, 0x3f, 0x76, 0x26,
+            0xd5, 0xf3, 0xbe, 0x07, 0x96, 0x2a, 0x76, 0xea, 0x3f, 0xf9, 0x9e, 0xbc, 0xa0, 0xd6,
+            0x16, 0x73, 0xa0, 0x0b, 0x6c, 0x10, 0x64, 0x83, 0x64, 0xa1, 0xd8, 0x21, 0x6e, 0xf8,
+            0x65, 0xa7, 0xc1, 0x78, 0xe3, 0xdc, 0x82, 0x76, 0x87, 0x21, 0x6f, 0x35, 0xd1, 0xc6,
+            0x88, 0x14, 0x63, 0xeb, 0xa8, 0xd0, 0x41, 0x7c, 0x3b, 0x74, 0x20, 0xf1, 0x6c, 0xbd,
+            0x27, 0x02, 0x3d, 0x43, 0x41, 0x1b, 0xe0, 0x6f, 0x55, 0xff, 0x22, 0x5a, 0xfe, 0xbd,
+            0xd2, 0x29, 0x2f, 0x61, 0x0a, 0x7b, 0x0e, 0xed, 0x49, 0x33, 0x99, 0xfb, 0xb0, 0xce,
+            0xa3, 0xb5, 0x72, 0x2a, 0x4d, 0xde, 0x34, 0xb5, 0x95, 0x6a, 0x4b, 0x1c, 0x3f, 0x7b,
+            0xe9, 0xb1, 0xb0, 0xdf, 0x4d, 0xac, 0x62, 0xad, 0x4a, 0x22, 0xdd, 0x76, 0x73, 0xdb,
+            0xbf, 0x68, 0x1d, 0x72, 0xc9, 0xad, 0xad, 0xf8, 0x16, 0x36, 0x1c, 0x20, 0x7d, 0xd2,
+            0x86, 0xe9, 0x05, 0x04, 0x87, 0x4c, 0xbf, 0x21, 0x2a, 0x59, 0x11, 0xdb, 0xfb, 0x3e,
+            0xfb, 0xb1, 0xd1, 0xd6, 0x12, 0x10, 0xd2, 0x20, 0x51, 0xc1, 0x1b, 0x05, 0x8e, 0x41,
+            0x90, 0x3d, 0x1d, 0xfe, 0x9d, 0x44, 0xb8, 0x96, 0x1c, 0x34, 0xb8, 0xc8, 0x70, 0xbb,
+            0x85, 0xa7, 0x24, 0x0c, 0x3b, 0x39, 0x15, 0x32, 0xba, 0x2e, 0x50, 0x06, 0x66, 0x6b,
+            0x3e, 0xef, 0xd1, 0x9a, 0x7b, 0xf4, 0xa7, 0x98, 0x96, 0x43, 0x51, 0x94, 0xab, 0xd3,
+            0x6c, 0x5f, 0x53, 0x05, 0xb4, 0x6a, 0xbc, 0x47, 0xd0, 0x71, 0xbe, 0x40, 0xda, 0xb0,
+            0x91, 0x31, 0xa3, 0x3f, 0xd4, 0xf8, 0x01, 0xe8, 0x0c, 0x6f, 0x65, 0x3e, 0x41, 0xc3,
+            0xa1, 0xe7, 0x8a, 0x5b, 0x4e, 0xbf, 0xa9, 0x50, 0x9f, 0x92, 0xd1, 0x77, 0x67, 0x6f,
+            0x17, 0x28, 0x1c, 0x57, 0xbc, 0xcc, 0xff, 0x3d, 0x45, 0x85, 0xab, 0x79, 0x53, 0xa1,
+            0xc3, 0x3b, 0xa1, 0x86, 0x81, 0x88, 0xbe, 0x99, 0x09, 0x6e, 0x0d, 0x8f, 0x12, 0xcf,
+            0xcd, 0xd3, 0x3c, 0xf3, 0xb0, 0x32, 0x7f, 0x3a, 0x85, 0x06, 0xa9, 0xdd, 0x1a, 0x6a,
+            0x04, 0x59, 0x06, 0x59, 0x80, 0x16, 0x67, 0x5e, 0x85, 0x83, 0x5c, 0x34, 0x8e, 0x20,
+            0xb7, 0x74, 0x61, 0x8a, 0x51, 0xd2, 0xe3, 0xcb, 0x4e, 0x1d, 0x0c, 0xeb, 0x5b, 0x16,
+            0x45, 0x45, 0x12, 0x63, 0x21, 0x6f, 0x46, 0x36, 0x0e, 0xd6, 0x19, 0xdd, 0x60, 0x28,
+            0xb7, 0x03, 0x8e, 0x54, 0xfe, 0x42, 0xfb, 0xd2, 0x97, 0xa8, 0x2d, 0x30, 0xdb, 0x3d,
+            0x76, 0xa4, 0xbe, 0x8b, 0xdd, 0x32, 0xe9, 0xc7, 0x4e, 0x71, 0x7b, 0x73, 0x1b, 0xbd,
+            0x55, 0x49, 0xb1, 0xea, 0x49, 0x07, 0x37, 0xda, 0xd9, 0xb1, 0xbf, 0x96, 0xb8, 0x26,
+            0x34, 0x31, 0xe0, 0xa1, 0xa3, 0x97, 0x92, 0x30, 0xc7, 0x28, 0x4f, 0x29, 0x50, 0xda,
+            0xe8, 0x4f, 0xfc, 0x0d, 0x94, 0x3f, 0xc3, 0x11, 0xf4, 0x9b, 0xf2, 0xb4, 0x0d, 0x70,
+            0x2e, 0x39, 0xf0, 0x46, 0x58, 0x4b, 0x92, 0x79, 0x99, 0xc1, 0x6a, 0x2b, 0xab, 0xd0,
+            0x54, 0x6a, 0xb0, 0x0d, 0xbb, 0x82, 0x97, 0xc0, 0x68, 0xee, 0xd4, 0x63, 0x24, 0x56,
+            0xc1, 0x25, 0xbd, 0xa4, 0x24, 0xbb, 0xd3, 0x74, 0x25, 0x62, 0xd6, 0xf5, 0xcc, 0xe4,
+            0x57, 0x3e, 0x19, 0x3f, 0x8e, 0x34, 0x4d, 0xc4, 0xa7, 0x3d, 0xb8, 0x85, 0xeb, 0x7a,
+            0xd0, 0xb1, 0xa9, 0xef, 0xc6, 0xe7, 0xf5, 0xaf, 0x85, 0xb0, 0x34, 0x68, 0xa4, 0xb9,
+            0xd4, 0x53, 0x24, 0xc9, 0x4c, 0xb0, 0xcb, 0x81, 0x21, 0x41, 0x0f, 0xfe, 0xfc, 0xce,
+            0x93, 0x49, 0x5e, 0xef, 0x9f, 0x23, 0x90, 0x11, 0x4f, 0x19, 0x49, 0xa0, 0x46, 0xf6,
+            0x99, 0xb4, 0xfa, 0x08, 0xe9, 0x2b, 0xf9, 0x2a, 0x0e, 0xf7, 0x04, 0xf3, 0x4a, 0xa0,
+            0xc2, 0xb3, 0xbc, 0xa5, 0x54, 0x2c, 0xd8, 0xa2, 0x8e, 0x94, 0xff, 0xe4, 0xe2, 0xdc,
+            0x50, 0x4c, 0x18, 0xb7, 0xb2, 0x43, 0x94, 0xbc, 0xad, 0x8f, 0x01, 0xd5, 0x65, 0x53,
+            0x90, 0xf7, 0x94, 0x9c, 0x68, 0xb3, 0xa3, 0xd6, 0x41, 0x8d, 0x61, 0x2e, 0xcb, 0x87,
+            0x05, 0x0e, 0x5e, 0x76, 0x42, 0x9f, 0x81, 0x72, 0x02, 0xe2, 0xed, 0x1b, 0xb6, 0x25,
+            0x68, 0x98, 0x38, 0x0d, 0x57, 0x9a, 0x0d, 0xce, 0x7f, 0xf6, 0xb9, 0x8f, 0xaf, 0x34,
+            0x19, 0xbf, 0xd1, 0x44, 0x60, 0x94, 0x79, 0x4d, 0x02, 0x68, 0x7d, 0xb2, 0x22, 0x9d,
+            0x31, 0x52, 0xe4, 0xc5, 0x4c, 0xa7, 0xa1, 0x05, 0xb7, 0x9c, 0xa5, 0xde, 0x8b, 0xa0,
+            0x5e, 0x24, 0x4c, 0xb0, 0x07, 0x21, 0x03, 0x62, 0x69, 0xa8, 0x74, 0x0c, 0x4c, 0xb0,
+            0xf8, 0x02, 0x65, 0x02, 0xaa, 0x84, 0xfc, 0x66, 0x8b, 0x98, 0x14, 0x1e, 0xd3, 0x7a,
+            0x29, 0x84, 0x86, 0x2d, 0xf8, 0xcb, 0xa3, 0x7b, 0x27, 0x62, 0xcd, 0xa5, 0xed, 0xb7,
+            0x1f, 0x0c, 0xb1, 0xde, 0x83, 0x9c, 0x6f, 0x47, 0xbe, 0x0d, 0xe1, 0x8d, 0x81, 0x84,
+            0xf5, 0xe3, 0x4f, 0x7c, 0x28, 0x9e, 0x0f, 0xbe, 0xc2, 0x15, 0xab, 0x96, 0x87, 0x53,
+            0x02, 0x1b, 0x77, 0xb7, 0x90, 0x54, 0x46, 0x23, 0xe5, 0x78, 0x8b, 0xc7, 0xdf, 0x5c,
+            0x59, 0xe1, 0x99, 0x13, 0x02, 0xab, 0x23, 0x99, 0xf9, 0x45, 0x1a, 0x27, 0xa3, 0xc2,
+            0xbb, 0x57, 0xa6, 0x2a, 0x36, 0x96, 0x91, 0x70, 0xf1, 0xc0, 0x67, 0xba, 0x3e, 0xda,
+            0x88, 0xba, 0x85, 0xba, 0x7a, 0x10, 0x75, 0x26, 0xc8, 0xc9, 0x5a, 0xe4, 0xcc, 0xa7,
+            0xfc, 0x84, 0x0c, 0x41, 0x3d, 0x3f, 0x43, 0x3e, 0x64, 0xad, 0x6e, 0xf6, 0x8a, 0xfb,
+            0x20, 0x92, 0x9e, 0xd7, 0x8e, 0x13, 0x05, 0x86, 0xf0, 0xe8, 0x88, 0xd4, 0xd0, 0x01,
+            0x7c, 0x39, 0x68, 0xcf, 0x92, 0x52, 0x00, 0x01, 0xa2, 0x30, 0x5c, 0x09, 0xa7, 0x4d,
+            0x2d, 0x04, 0xa3, 0xe0, 0x04, 0x83, 0x17, 0x33, 0x77, 0xc9, 0x58, 0x8b, 0x25, 0xbf,
+            0x4b, 0x8b, 0xc7, 0xe2, 0x14, 0x0a, 0xd1, 0x70, 0xfc, 0x53, 0x64, 0x77, 0xc2, 0x83,
+            0x90, 0x77, 0x94, 0x6f, 0x02, 0x26, 0x18, 0x60, 0xac, 0x15, 0xfa, 0x54, 0x04, 0x37,
+            0xa5, 0x24, 0x19, 0x64, 0xdb, 0x28, 0x8b, 0xd4, 0xb5, 0xde, 0x08, 0x16, 0xb8, 0x18,
+            0xb0, 0xf8, 0x7a, 0xbe, 0x7f, 0xb3, 0x00, 0x57, 0xbf, 0xad, 0x1d, 0x0d, 0xf4, 0x47,
+            0x62, 0x2a, 0xea, 0x7d, 0x40, 0xc1, 0xa9, 0xaa, 0x3a, 0x11, 0xaa, 0x37, 0x47, 0x95,
+            0xc9, 0xa8, 0xff, 0x96, 0x08, 0x5c, 0x65, 0x17, 0x57, 0xe9, 0x54, 0x12, 0xa9, 0x61,
+            0xcb, 0x49, 0x53, 0x6b, 0xa4, 0xe0, 0x6e, 0x2d, 0xe3, 0x03, 0x0e, 0xe9, 0x43, 0x5a,
+            0x93, 0x7b, 0x06, 0x6e, 0x03, 0x40, 0x12, 0xae, 0x8a, 0x66, 0x92, 0xb5, 0xbf, 0x8c,
+            0x39, 0x2d, 0x8a, 0x81, 0x49, 0x38, 0xd0, 0xc8, 0xa5, 0x97, 0xf5, 0x29, 0x13, 0xb1,
+            0x6e, 0xc4, 0x0f, 0x9e, 0xd7, 0x26, 0x06, 0x8b, 0x45, 0xd2, 0x0d, 0xa4, 0x09, 0x19,
+            0x17, 0xaf, 0xf9, 0xc9, 0x00, 0x7e, 0xef, 0x90, 0x04, 0xde, 0x08, 0x0b, 0x8c, 0xf7,
+            0x2f, 0xd8, 0x39, 0xbf, 0xb2, 0x52, 0x25, 0xf6, 0xac, 0xb2, 0x0a, 0x16, 0x61, 0xd5,
+            0xe2, 0xa4, 0xca, 0x7b, 0xd5, 0x5c, 0x46, 0x4b, 0xbe, 0xf4, 0xc8, 0x7a, 0xe8, 0xc5,
+            0xb6, 0x29, 0xa6, 0xe8, 0x3d, 0xf1, 0xf2, 0x8e, 0xdf, 0xbe, 0xbc, 0x97, 0x03, 0x80,
+            0x2a, 0x60, 0x0e, 0x8e, 0x7c, 0x2f, 0x1d, 0xf0, 0xad, 0xe0, 0x9a, 0xaf, 0x13, 0xa1,
+            0x6f, 0x91, 0x3a, 0x2f, 0x89, 0x11, 0x16, 0xdf, 0x7b, 0x70, 0x72, 0x98, 0xf4, 0x4e,
+            0x7f, 0xfa, 0xf7, 0x95, 0x95, 0x92, 0x85, 0x4f, 0x07, 0x67, 0x09, 0xce, 0x30, 0x59,
+            0x13, 0xd8, 0xd8, 0x1f, 0x05, 0x14, 0x97, 0xd6, 0x01, 0x92, 0x8a, 0x1f, 0x9f, 0x8b,
+            0x49, 0x5d, 0x2a, 0x89, 0x42, 0x5c, 0xc2, 0xa9, 0x24, 0x09, 0x6e, 0x23, 0xcf, 0x8f,
+            0x18, 0x8a, 0x17, 0x1b, 0x41, 0x99, 0xd4, 0x7d, 0xa9, 0x55, 0x79, 0x51, 0x72, 0x34,
+            0x87, 0x6b, 0xce, 0x2a, 0x37, 0x36, 0xa3, 0x0e, 0x71, 0xdb, 0xa6, 0x70, 0xae, 0xa7,
+            0x97, 0xc6, 0x49, 0x5a, 0x45, 0x43, 0xd1, 0x67, 0x42, 0xab, 0x00, 0x84, 0x48, 0x4a,
+            0xb4, 0xcc, 0xcb, 0x8f, 0x29, 0xbf, 0x69, 0xad, 0x6a, 0x0e, 0x19, 0x37, 0x7b, 0xaa,
+            0x65, 0xe9, 0x3f, 0xfc, 0x73, 0x2f, 0x66, 0xf6, 0x8f, 0x8a, 0x8c, 0x22, 0x02, 0xed,
+            0xd5, 0xa4, 0xe7,
+        ];
+    }
+
+    #[test]
+    fn test_reverse_bitstream_get_value() -> Result<(), ErrorTrace> {
+        let test_data: [u8; 2] = [0xb5, 0x1a];
+        let mut test_bitstream: ZstdReverseBitstream = ZstdReverseBitstream::new(&test_data)?;
+
+        assert_eq!(test_bitstream.get_value(4), 0x0a);
+        assert_eq!(test_bitstream.peek_value(4), 0x0b);
+        assert_eq!(test_bitstream.get_value(8), 0xb5);
+        assert!(test_bitstream.is_finished());
+
+        assert_eq!(test_bitstream.get_value(2), 0);
+        assert!(test_bitstream.is_overflow());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_huffman_table_build() -> Result<(), ErrorTrace> {
+        let mut test_table: ZstdHuffmanTable = ZstdHuffmanTable::new();
+
+        // Weights of symbols 0 - 4, the weight of symbol 5 is derived.
+        test_table.build(&[4, 3, 2, 0, 1])?;
+        assert_eq!(test_table.largest_code_size, 4);
+        assert_eq!(test_table.entries[0], (4, 4));
+        assert_eq!(test_table.entries[1], (5, 4));
+        assert_eq!(test_table.entries[2], (2, 3));
+        assert_eq!(test_table.entries[4], (1, 2));
+        assert_eq!(test_table.entries[8], (0, 1));
+        assert_eq!(test_table.entries[15], (0, 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress() -> Result<(), ErrorTrace> {
+        let mut test_context: ZstdContext = ZstdContext::new();
+
+        let test_data: [u8; 40] = [
+            0x28, 0xb5, 0x2f, 0xfd, 0x24, 0x4c, 0xdd, 0x00, 0x00, 0xa0, 0x4b, 0x65, 0x72, 0x61,
+            0x6d, 0x69, 0x63, 0x73, 0x20, 0x74, 0x65, 0x73, 0x74, 0x20, 0x64, 0x61, 0x74, 0x61,
+            0x20, 0x4b, 0x01, 0x00, 0x6a, 0xe7, 0x64, 0x02, 0x4f, 0x4e, 0x9b, 0x93,
+        ];
+        let mut uncompressed_data: Vec<u8> = vec![0; 128];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 76);
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4);
+        assert_eq!(
+            &uncompressed_data[0..test_context.uncompressed_data_size],
+            &expected_data
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decompress_with_compressed_literals() -> Result<(), ErrorTrace> {
+        let mut test_context: ZstdContext = ZstdContext::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let mut uncompressed_data: Vec<u8> = vec![0; 11358];
+        test_context.decompress(&test_data, &mut uncompressed_data)?;
+        assert_eq!(test_context.uncompressed_data_size, 11358);
+
+        let expected_data: Vec<u8> = match fs::read("../LICENSE") {
+            Ok(data) => data,
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable read test reference file",
+                    error
+                ));
+            }
+        };
+        assert_eq!(&uncompressed_data, &expected_data);
+
+        Ok(())
+    }
+}
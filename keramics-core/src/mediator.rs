@@ -17,10 +17,16 @@ use super::formatters::format_as_hexdump;
 
 pub type MediatorReference = Arc<Mediator>;
 
+/// Callback to retrieve a credential, such as a password, for a specific description.
+pub type MediatorCredentialCallback = Box<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
 /// Mediator.
 pub struct Mediator {
     /// Debug output.
     pub debug_output: bool,
+
+    /// Credential callback.
+    pub credential_callback: Option<MediatorCredentialCallback>,
 }
 
 impl Mediator {
@@ -28,6 +34,7 @@ impl Mediator {
     pub fn new(debug_output: bool) -> Self {
         Self {
             debug_output: debug_output,
+            credential_callback: None,
         }
     }
 
@@ -41,6 +48,14 @@ impl Mediator {
         CURRENT_MEDIATOR.with(|mediator| *mediator.write().unwrap() = Arc::new(self))
     }
 
+    /// Retrieves a credential, such as a password, for a specific description.
+    pub fn get_credential(&self, description: &str) -> Option<String> {
+        match &self.credential_callback {
+            Some(credential_callback) => credential_callback(description),
+            None => None,
+        }
+    }
+
     /// Prints a string for debugging.
     // TODO: Change text to &str
     pub fn debug_print(&self, text: String) {
@@ -71,4 +86,21 @@ mod tests {
 
         mediator.debug_print(String::from("test"));
     }
+
+    #[test]
+    fn test_get_credential() {
+        let mediator: Mediator = Mediator::new(false);
+        assert_eq!(mediator.get_credential("test"), None);
+
+        let mediator: Mediator = Mediator {
+            debug_output: false,
+            credential_callback: Some(Box::new(|description: &str| {
+                Some(format!("{} password", description))
+            })),
+        };
+        assert_eq!(
+            mediator.get_credential("test"),
+            Some(String::from("test password"))
+        );
+    }
 }
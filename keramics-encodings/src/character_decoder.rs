@@ -35,14 +35,34 @@ use super::koi8_u::DecoderKoi8U;
 use super::mac_arabic::DecoderMacArabic;
 use super::mac_celtic::DecoderMacCeltic;
 use super::mac_central_eur_roman::DecoderMacCentralEurRoman;
+use super::mac_chinese_simplified::DecoderMacChineseSimplified;
+use super::mac_chinese_traditional::DecoderMacChineseTraditional;
 use super::mac_croatian::DecoderMacCroatian;
 use super::mac_cyrillic::DecoderMacCyrillic;
 use super::mac_dingbats::DecoderMacDingbats;
 use super::mac_farsi::DecoderMacFarsi;
 use super::mac_gaelic::DecoderMacGaelic;
+use super::mac_greek::DecoderMacGreek;
+use super::mac_hebrew::DecoderMacHebrew;
+use super::mac_icelandic::DecoderMacIcelandic;
+use super::mac_inuit::DecoderMacInuit;
+use super::mac_japanese::DecoderMacJapanese;
+use super::mac_korean::DecoderMacKorean;
+use super::mac_roman::DecoderMacRoman;
+use super::mac_russian::DecoderMacRussian;
+use super::mac_symbol::DecoderMacSymbol;
+use super::mac_thai::DecoderMacThai;
+use super::mac_turkish::DecoderMacTurkish;
+use super::mac_ukrainian::DecoderMacUkrainian;
+use super::ucs2::DecoderUcs2;
 use super::utf8::DecoderUtf8;
+use super::utf16::{DecoderUtf16BigEndian, DecoderUtf16LittleEndian};
+use super::utf32::{DecoderUtf32BigEndian, DecoderUtf32LittleEndian};
 use super::windows874::DecoderWindows874;
 use super::windows932::DecoderWindows932;
+use super::windows936::DecoderWindows936;
+use super::windows949::DecoderWindows949;
+use super::windows950::DecoderWindows950;
 use super::windows1250::DecoderWindows1250;
 use super::windows1251::DecoderWindows1251;
 use super::windows1252::DecoderWindows1252;
@@ -56,6 +76,9 @@ use super::windows1258::DecoderWindows1258;
 pub type CharacterDecoder<'a> = Box<dyn Iterator<Item = Result<u32, ErrorTrace>> + 'a>;
 
 /// Creates a new character decoder.
+///
+/// Every character encoding can be decoded, hence unlike
+/// [`try_new_character_encoder`](crate::try_new_character_encoder) this cannot fail.
 pub fn new_character_decoder<'a>(
     encoding: &CharacterEncoding,
     bytes: &'a [u8],
@@ -82,14 +105,40 @@ pub fn new_character_decoder<'a>(
         CharacterEncoding::MacArabic => Box::new(DecoderMacArabic::new(bytes)),
         CharacterEncoding::MacCeltic => Box::new(DecoderMacCeltic::new(bytes)),
         CharacterEncoding::MacCentralEurRoman => Box::new(DecoderMacCentralEurRoman::new(bytes)),
+        CharacterEncoding::MacChineseSimplified => {
+            Box::new(DecoderMacChineseSimplified::new(bytes))
+        }
+        CharacterEncoding::MacChineseTraditional => {
+            Box::new(DecoderMacChineseTraditional::new(bytes))
+        }
         CharacterEncoding::MacCroatian => Box::new(DecoderMacCroatian::new(bytes)),
         CharacterEncoding::MacCyrillic => Box::new(DecoderMacCyrillic::new(bytes)),
         CharacterEncoding::MacDingbats => Box::new(DecoderMacDingbats::new(bytes)),
         CharacterEncoding::MacFarsi => Box::new(DecoderMacFarsi::new(bytes)),
         CharacterEncoding::MacGaelic => Box::new(DecoderMacGaelic::new(bytes)),
+        CharacterEncoding::MacGreek => Box::new(DecoderMacGreek::new(bytes)),
+        CharacterEncoding::MacHebrew => Box::new(DecoderMacHebrew::new(bytes)),
+        CharacterEncoding::MacIcelandic => Box::new(DecoderMacIcelandic::new(bytes)),
+        CharacterEncoding::MacInuit => Box::new(DecoderMacInuit::new(bytes)),
+        CharacterEncoding::MacJapanese => Box::new(DecoderMacJapanese::new(bytes)),
+        CharacterEncoding::MacKorean => Box::new(DecoderMacKorean::new(bytes)),
+        CharacterEncoding::MacRoman => Box::new(DecoderMacRoman::new(bytes)),
+        CharacterEncoding::MacRussian => Box::new(DecoderMacRussian::new(bytes)),
+        CharacterEncoding::MacSymbol => Box::new(DecoderMacSymbol::new(bytes)),
+        CharacterEncoding::MacThai => Box::new(DecoderMacThai::new(bytes)),
+        CharacterEncoding::MacTurkish => Box::new(DecoderMacTurkish::new(bytes)),
+        CharacterEncoding::MacUkrainian => Box::new(DecoderMacUkrainian::new(bytes)),
+        CharacterEncoding::Ucs2 => Box::new(DecoderUcs2::new(bytes)),
+        CharacterEncoding::Utf16BigEndian => Box::new(DecoderUtf16BigEndian::new(bytes)),
+        CharacterEncoding::Utf16LittleEndian => Box::new(DecoderUtf16LittleEndian::new(bytes)),
+        CharacterEncoding::Utf32BigEndian => Box::new(DecoderUtf32BigEndian::new(bytes)),
+        CharacterEncoding::Utf32LittleEndian => Box::new(DecoderUtf32LittleEndian::new(bytes)),
         CharacterEncoding::Utf8 => Box::new(DecoderUtf8::new(bytes)),
         CharacterEncoding::Windows874 => Box::new(DecoderWindows874::new(bytes)),
         CharacterEncoding::Windows932 => Box::new(DecoderWindows932::new(bytes)),
+        CharacterEncoding::Windows936 => Box::new(DecoderWindows936::new(bytes)),
+        CharacterEncoding::Windows949 => Box::new(DecoderWindows949::new(bytes)),
+        CharacterEncoding::Windows950 => Box::new(DecoderWindows950::new(bytes)),
         CharacterEncoding::Windows1250 => Box::new(DecoderWindows1250::new(bytes)),
         CharacterEncoding::Windows1251 => Box::new(DecoderWindows1251::new(bytes)),
         CharacterEncoding::Windows1252 => Box::new(DecoderWindows1252::new(bytes)),
@@ -99,7 +148,6 @@ pub fn new_character_decoder<'a>(
         CharacterEncoding::Windows1256 => Box::new(DecoderWindows1256::new(bytes)),
         CharacterEncoding::Windows1257 => Box::new(DecoderWindows1257::new(bytes)),
         CharacterEncoding::Windows1258 => Box::new(DecoderWindows1258::new(bytes)),
-        _ => todo!(),
     }
 }
 
@@ -131,13 +179,35 @@ mod tests {
             CharacterEncoding::MacArabic,
             CharacterEncoding::MacCeltic,
             CharacterEncoding::MacCentralEurRoman,
+            CharacterEncoding::MacChineseSimplified,
+            CharacterEncoding::MacChineseTraditional,
             CharacterEncoding::MacCroatian,
             CharacterEncoding::MacCyrillic,
             CharacterEncoding::MacDingbats,
             CharacterEncoding::MacFarsi,
             CharacterEncoding::MacGaelic,
+            CharacterEncoding::MacGreek,
+            CharacterEncoding::MacHebrew,
+            CharacterEncoding::MacIcelandic,
+            CharacterEncoding::MacInuit,
+            CharacterEncoding::MacJapanese,
+            CharacterEncoding::MacKorean,
+            CharacterEncoding::MacRoman,
+            CharacterEncoding::MacRussian,
+            CharacterEncoding::MacSymbol,
+            CharacterEncoding::MacThai,
+            CharacterEncoding::MacTurkish,
+            CharacterEncoding::MacUkrainian,
+            CharacterEncoding::Ucs2,
+            CharacterEncoding::Utf16BigEndian,
+            CharacterEncoding::Utf16LittleEndian,
+            CharacterEncoding::Utf32BigEndian,
+            CharacterEncoding::Utf32LittleEndian,
             CharacterEncoding::Windows874,
             CharacterEncoding::Windows932,
+            CharacterEncoding::Windows936,
+            CharacterEncoding::Windows949,
+            CharacterEncoding::Windows950,
             CharacterEncoding::Windows1250,
             CharacterEncoding::Windows1251,
             CharacterEncoding::Windows1252,
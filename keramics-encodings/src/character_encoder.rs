@@ -35,13 +35,33 @@ use super::koi8_u::EncoderKoi8U;
 use super::mac_arabic::EncoderMacArabic;
 use super::mac_celtic::EncoderMacCeltic;
 use super::mac_central_eur_roman::EncoderMacCentralEurRoman;
+use super::mac_chinese_simplified::EncoderMacChineseSimplified;
+use super::mac_chinese_traditional::EncoderMacChineseTraditional;
 use super::mac_croatian::EncoderMacCroatian;
 use super::mac_cyrillic::EncoderMacCyrillic;
 use super::mac_dingbats::EncoderMacDingbats;
 use super::mac_farsi::EncoderMacFarsi;
 use super::mac_gaelic::EncoderMacGaelic;
+use super::mac_greek::EncoderMacGreek;
+use super::mac_hebrew::EncoderMacHebrew;
+use super::mac_icelandic::EncoderMacIcelandic;
+use super::mac_inuit::EncoderMacInuit;
+use super::mac_japanese::EncoderMacJapanese;
+use super::mac_korean::EncoderMacKorean;
+use super::mac_roman::EncoderMacRoman;
+use super::mac_russian::EncoderMacRussian;
+use super::mac_symbol::EncoderMacSymbol;
+use super::mac_thai::EncoderMacThai;
+use super::mac_turkish::EncoderMacTurkish;
+use super::mac_ukrainian::EncoderMacUkrainian;
+use super::ucs2::EncoderUcs2;
+use super::utf16::{EncoderUtf16BigEndian, EncoderUtf16LittleEndian};
+use super::utf32::{EncoderUtf32BigEndian, EncoderUtf32LittleEndian};
 use super::windows874::EncoderWindows874;
 use super::windows932::EncoderWindows932;
+use super::windows936::EncoderWindows936;
+use super::windows949::EncoderWindows949;
+use super::windows950::EncoderWindows950;
 use super::windows1250::EncoderWindows1250;
 use super::windows1251::EncoderWindows1251;
 use super::windows1252::EncoderWindows1252;
@@ -55,11 +75,26 @@ use super::windows1258::EncoderWindows1258;
 pub type CharacterEncoder<'a> = Box<dyn Iterator<Item = Result<Vec<u8>, ErrorTrace>> + 'a>;
 
 /// Creates a new character encoder.
+///
+/// Panics if the character encoding is not supported, use [`try_new_character_encoder`] to
+/// retrieve an error instead.
 pub fn new_character_encoder<'a>(
     encoding: &CharacterEncoding,
     code_points: &'a [u32],
 ) -> CharacterEncoder<'a> {
-    match encoding {
+    match try_new_character_encoder(encoding, code_points) {
+        Ok(character_encoder) => character_encoder,
+        Err(_) => panic!("Unsupported character encoding: {:?}", encoding),
+    }
+}
+
+/// Creates a new character encoder or returns an error if the character encoding is not
+/// supported.
+pub fn try_new_character_encoder<'a>(
+    encoding: &CharacterEncoding,
+    code_points: &'a [u32],
+) -> Result<CharacterEncoder<'a>, ErrorTrace> {
+    let character_encoder: CharacterEncoder = match encoding {
         CharacterEncoding::Ascii => Box::new(EncoderAscii::new(code_points)),
         CharacterEncoding::Iso8859_1 => Box::new(EncoderIso8859_1::new(code_points)),
         CharacterEncoding::Iso8859_2 => Box::new(EncoderIso8859_2::new(code_points)),
@@ -83,13 +118,43 @@ pub fn new_character_encoder<'a>(
         CharacterEncoding::MacCentralEurRoman => {
             Box::new(EncoderMacCentralEurRoman::new(code_points))
         }
+        CharacterEncoding::MacChineseSimplified => {
+            Box::new(EncoderMacChineseSimplified::new(code_points))
+        }
+        CharacterEncoding::MacChineseTraditional => {
+            Box::new(EncoderMacChineseTraditional::new(code_points))
+        }
         CharacterEncoding::MacCroatian => Box::new(EncoderMacCroatian::new(code_points)),
         CharacterEncoding::MacCyrillic => Box::new(EncoderMacCyrillic::new(code_points)),
         CharacterEncoding::MacDingbats => Box::new(EncoderMacDingbats::new(code_points)),
         CharacterEncoding::MacFarsi => Box::new(EncoderMacFarsi::new(code_points)),
         CharacterEncoding::MacGaelic => Box::new(EncoderMacGaelic::new(code_points)),
+        CharacterEncoding::MacGreek => Box::new(EncoderMacGreek::new(code_points)),
+        CharacterEncoding::MacHebrew => Box::new(EncoderMacHebrew::new(code_points)),
+        CharacterEncoding::MacIcelandic => Box::new(EncoderMacIcelandic::new(code_points)),
+        CharacterEncoding::MacInuit => Box::new(EncoderMacInuit::new(code_points)),
+        CharacterEncoding::MacJapanese => Box::new(EncoderMacJapanese::new(code_points)),
+        CharacterEncoding::MacKorean => Box::new(EncoderMacKorean::new(code_points)),
+        CharacterEncoding::MacRoman => Box::new(EncoderMacRoman::new(code_points)),
+        CharacterEncoding::MacRussian => Box::new(EncoderMacRussian::new(code_points)),
+        CharacterEncoding::MacSymbol => Box::new(EncoderMacSymbol::new(code_points)),
+        CharacterEncoding::MacThai => Box::new(EncoderMacThai::new(code_points)),
+        CharacterEncoding::MacTurkish => Box::new(EncoderMacTurkish::new(code_points)),
+        CharacterEncoding::MacUkrainian => Box::new(EncoderMacUkrainian::new(code_points)),
+        CharacterEncoding::Ucs2 => Box::new(EncoderUcs2::new(code_points)),
+        CharacterEncoding::Utf16BigEndian => Box::new(EncoderUtf16BigEndian::new(code_points)),
+        CharacterEncoding::Utf16LittleEndian => {
+            Box::new(EncoderUtf16LittleEndian::new(code_points))
+        }
+        CharacterEncoding::Utf32BigEndian => Box::new(EncoderUtf32BigEndian::new(code_points)),
+        CharacterEncoding::Utf32LittleEndian => {
+            Box::new(EncoderUtf32LittleEndian::new(code_points))
+        }
         CharacterEncoding::Windows874 => Box::new(EncoderWindows874::new(code_points)),
         CharacterEncoding::Windows932 => Box::new(EncoderWindows932::new(code_points)),
+        CharacterEncoding::Windows936 => Box::new(EncoderWindows936::new(code_points)),
+        CharacterEncoding::Windows949 => Box::new(EncoderWindows949::new(code_points)),
+        CharacterEncoding::Windows950 => Box::new(EncoderWindows950::new(code_points)),
         CharacterEncoding::Windows1250 => Box::new(EncoderWindows1250::new(code_points)),
         CharacterEncoding::Windows1251 => Box::new(EncoderWindows1251::new(code_points)),
         CharacterEncoding::Windows1252 => Box::new(EncoderWindows1252::new(code_points)),
@@ -99,8 +164,14 @@ pub fn new_character_encoder<'a>(
         CharacterEncoding::Windows1256 => Box::new(EncoderWindows1256::new(code_points)),
         CharacterEncoding::Windows1257 => Box::new(EncoderWindows1257::new(code_points)),
         CharacterEncoding::Windows1258 => Box::new(EncoderWindows1258::new(code_points)),
-        _ => todo!(),
-    }
+        _ => {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported character encoding: {:?}",
+                encoding
+            )));
+        }
+    };
+    Ok(character_encoder)
 }
 
 #[cfg(test)]
@@ -131,13 +202,35 @@ mod tests {
             CharacterEncoding::MacArabic,
             CharacterEncoding::MacCeltic,
             CharacterEncoding::MacCentralEurRoman,
+            CharacterEncoding::MacChineseSimplified,
+            CharacterEncoding::MacChineseTraditional,
             CharacterEncoding::MacCroatian,
             CharacterEncoding::MacCyrillic,
             CharacterEncoding::MacDingbats,
             CharacterEncoding::MacFarsi,
             CharacterEncoding::MacGaelic,
+            CharacterEncoding::MacGreek,
+            CharacterEncoding::MacHebrew,
+            CharacterEncoding::MacIcelandic,
+            CharacterEncoding::MacInuit,
+            CharacterEncoding::MacJapanese,
+            CharacterEncoding::MacKorean,
+            CharacterEncoding::MacRoman,
+            CharacterEncoding::MacRussian,
+            CharacterEncoding::MacSymbol,
+            CharacterEncoding::MacThai,
+            CharacterEncoding::MacTurkish,
+            CharacterEncoding::MacUkrainian,
+            CharacterEncoding::Ucs2,
+            CharacterEncoding::Utf16BigEndian,
+            CharacterEncoding::Utf16LittleEndian,
+            CharacterEncoding::Utf32BigEndian,
+            CharacterEncoding::Utf32LittleEndian,
             CharacterEncoding::Windows874,
             CharacterEncoding::Windows932,
+            CharacterEncoding::Windows936,
+            CharacterEncoding::Windows949,
+            CharacterEncoding::Windows950,
             CharacterEncoding::Windows1250,
             CharacterEncoding::Windows1251,
             CharacterEncoding::Windows1252,
@@ -154,4 +247,17 @@ mod tests {
             let _ = new_character_encoder(&encoding, &code_points);
         }
     }
+
+    #[test]
+    fn test_try_new_character_encoder() {
+        let code_points: [u32; 8] = [0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73];
+
+        let result: Result<CharacterEncoder, ErrorTrace> =
+            try_new_character_encoder(&CharacterEncoding::Ascii, &code_points);
+        assert!(result.is_ok());
+
+        let result: Result<CharacterEncoder, ErrorTrace> =
+            try_new_character_encoder(&CharacterEncoding::Utf8, &code_points);
+        assert!(result.is_err());
+    }
 }
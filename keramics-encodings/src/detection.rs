@@ -0,0 +1,223 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Character encoding detection.
+//!
+//! Provides heuristics to determine the most plausible character encoding of
+//! a byte sequence out of a set of candidate character encodings.
+
+use super::character_decoder::{CharacterDecoder, new_character_decoder};
+use super::enums::CharacterEncoding;
+
+/// Retrieves the character encoding and size of a byte order mark (BOM).
+fn get_byte_order_mark(bytes: &[u8]) -> Option<(CharacterEncoding, usize)> {
+    if bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        Some((CharacterEncoding::Utf8, 3))
+    } else if bytes.starts_with(&[0xff, 0xfe, 0x00, 0x00]) {
+        Some((CharacterEncoding::Utf32LittleEndian, 4))
+    } else if bytes.starts_with(&[0x00, 0x00, 0xfe, 0xff]) {
+        Some((CharacterEncoding::Utf32BigEndian, 4))
+    } else if bytes.starts_with(&[0xff, 0xfe]) {
+        Some((CharacterEncoding::Utf16LittleEndian, 2))
+    } else if bytes.starts_with(&[0xfe, 0xff]) {
+        Some((CharacterEncoding::Utf16BigEndian, 2))
+    } else {
+        None
+    }
+}
+
+/// Retrieves the score of a single decoded code point.
+fn get_code_point_score(code_point: u32) -> isize {
+    match code_point {
+        0x09 | 0x0a | 0x0d | 0x20 => 2,
+        0x30..=0x39 | 0x41..=0x5a | 0x61..=0x7a => 4,
+        0x21..=0x2f | 0x3a..=0x40 | 0x5b..=0x60 | 0x7b..=0x7e => 1,
+        // Control characters are unlikely to be part of text.
+        0x00..=0x1f | 0x7f..=0x9f => -16,
+        // Private use and specials are unlikely to be part of text.
+        0xe000..=0xf8ff | 0xfff0..=0xffff => -8,
+        _ => match char::from_u32(code_point) {
+            Some(character) => {
+                if character.is_alphabetic() {
+                    2
+                } else if character.is_numeric() || character.is_whitespace() {
+                    1
+                } else {
+                    -2
+                }
+            }
+            None => -16,
+        },
+    }
+}
+
+/// Scores how plausible it is that a byte sequence is encoded in a specific character encoding.
+///
+/// Returns None if the byte sequence cannot be decoded with the character encoding. A higher
+/// score indicates a more plausible character encoding.
+pub fn score_character_encoding(encoding: &CharacterEncoding, bytes: &[u8]) -> Option<isize> {
+    let mut score: isize = 0;
+
+    let data: &[u8] = match get_byte_order_mark(bytes) {
+        Some((bom_encoding, bom_size)) => {
+            if bom_encoding == *encoding {
+                score += 1024;
+            }
+            &bytes[bom_size..]
+        }
+        None => bytes,
+    };
+    let mut character_decoder: CharacterDecoder = new_character_decoder(encoding, data);
+
+    // Text in a single language tends to consist of characters from the same 128 code point
+    // block, except for Latin based text where accented characters are typically surrounded
+    // by ASCII characters.
+    let mut last_block: Option<u32> = None;
+
+    while let Some(result) = character_decoder.next() {
+        let code_point: u32 = match result {
+            Ok(code_point) => code_point,
+            Err(_) => return None,
+        };
+        score += get_code_point_score(code_point);
+
+        if code_point < 0x80 {
+            last_block = None;
+        } else {
+            let block: u32 = code_point >> 7;
+
+            if last_block == Some(block) {
+                if code_point < 0x0250 {
+                    score -= 4;
+                } else {
+                    score += 1;
+                }
+            }
+            last_block = Some(block);
+
+            // Valid multi-byte UTF-8 sequences are unlikely to occur by chance.
+            if *encoding == CharacterEncoding::Utf8 {
+                score += 4;
+            }
+        }
+    }
+    Some(score)
+}
+
+/// Determines the most plausible character encoding of a byte sequence.
+///
+/// The candidates are scored based on the byte order mark (BOM), whether the byte sequence
+/// can be decoded and the frequency of the decoded characters. If multiple candidates have
+/// the same score the first one is returned.
+pub fn detect_character_encoding(
+    bytes: &[u8],
+    candidates: &[CharacterEncoding],
+) -> Option<CharacterEncoding> {
+    let mut detected_encoding: Option<&CharacterEncoding> = None;
+    let mut detected_score: isize = 0;
+
+    for encoding in candidates.iter() {
+        let score: isize = match score_character_encoding(encoding, bytes) {
+            Some(score) => score,
+            None => continue,
+        };
+        if detected_encoding.is_none() || score > detected_score {
+            detected_encoding = Some(encoding);
+            detected_score = score;
+        }
+    }
+    detected_encoding.cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_character_encoding() {
+        let byte_string: [u8; 8] = [b'K', b'e', b'r', b'a', b'm', b'i', b'c', b's'];
+
+        let result: Option<isize> =
+            score_character_encoding(&CharacterEncoding::Ascii, &byte_string);
+        assert_eq!(result, Some(32));
+
+        let byte_string: [u8; 2] = [0xc3, 0x28];
+
+        let result: Option<isize> =
+            score_character_encoding(&CharacterEncoding::Utf8, &byte_string);
+        assert_eq!(result, None);
+
+        let result: Option<isize> =
+            score_character_encoding(&CharacterEncoding::MacInuit, &byte_string);
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn test_detect_character_encoding() {
+        let candidates: [CharacterEncoding; 5] = [
+            CharacterEncoding::Windows1252,
+            CharacterEncoding::Utf8,
+            CharacterEncoding::Utf16LittleEndian,
+            CharacterEncoding::Koi8R,
+            CharacterEncoding::Windows1251,
+        ];
+        // "Keramics" in UTF-16 little-endian with byte order mark.
+        let byte_string: [u8; 18] = [
+            0xff, 0xfe, 0x4b, 0x00, 0x65, 0x00, 0x72, 0x00, 0x61, 0x00, 0x6d, 0x00, 0x69, 0x00,
+            0x63, 0x00, 0x73, 0x00,
+        ];
+        let result: Option<CharacterEncoding> =
+            detect_character_encoding(&byte_string, &candidates);
+        assert_eq!(result, Some(CharacterEncoding::Utf16LittleEndian));
+
+        // "Keramics" in UTF-16 little-endian without byte order mark.
+        let result: Option<CharacterEncoding> =
+            detect_character_encoding(&byte_string[2..], &candidates);
+        assert_eq!(result, Some(CharacterEncoding::Utf16LittleEndian));
+
+        // "Café" in UTF-8.
+        let byte_string: [u8; 5] = [0x43, 0x61, 0x66, 0xc3, 0xa9];
+
+        let result: Option<CharacterEncoding> =
+            detect_character_encoding(&byte_string, &candidates);
+        assert_eq!(result, Some(CharacterEncoding::Utf8));
+
+        // "Café" in Windows 1252.
+        let byte_string: [u8; 4] = [0x43, 0x61, 0x66, 0xe9];
+
+        let result: Option<CharacterEncoding> =
+            detect_character_encoding(&byte_string, &candidates);
+        assert_eq!(result, Some(CharacterEncoding::Windows1252));
+
+        // "Привет" in Windows 1251.
+        let byte_string: [u8; 6] = [0xcf, 0xf0, 0xe8, 0xe2, 0xe5, 0xf2];
+
+        let result: Option<CharacterEncoding> = detect_character_encoding(
+            &byte_string,
+            &[
+                CharacterEncoding::Windows1252,
+                CharacterEncoding::Windows1251,
+            ],
+        );
+        assert_eq!(result, Some(CharacterEncoding::Windows1251));
+
+        // "ᐃᓄᒃᑎᑐᑦ" in MacInuit.
+        let byte_string: [u8; 6] = [0x80, 0xb1, 0x9a, 0x8d, 0x8f, 0x93];
+
+        let result: Option<CharacterEncoding> = detect_character_encoding(
+            &byte_string,
+            &[CharacterEncoding::Windows1252, CharacterEncoding::MacInuit],
+        );
+        assert_eq!(result, Some(CharacterEncoding::MacInuit));
+    }
+}
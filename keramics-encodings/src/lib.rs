@@ -15,6 +15,7 @@ mod ascii;
 mod base64;
 mod character_decoder;
 mod character_encoder;
+mod detection;
 mod enums;
 mod iso8859_1;
 mod iso8859_10;
@@ -36,11 +37,28 @@ mod koi8_u;
 mod mac_arabic;
 mod mac_celtic;
 mod mac_central_eur_roman;
+mod mac_chinese_simplified;
+mod mac_chinese_traditional;
 mod mac_croatian;
 mod mac_cyrillic;
 mod mac_dingbats;
 mod mac_farsi;
 mod mac_gaelic;
+mod mac_greek;
+mod mac_hebrew;
+mod mac_icelandic;
+mod mac_inuit;
+mod mac_japanese;
+mod mac_korean;
+mod mac_roman;
+mod mac_russian;
+mod mac_symbol;
+mod mac_thai;
+mod mac_turkish;
+mod mac_ukrainian;
+mod ucs2;
+mod utf16;
+mod utf32;
 mod utf8;
 mod windows1250;
 mod windows1251;
@@ -53,11 +71,15 @@ mod windows1257;
 mod windows1258;
 mod windows874;
 mod windows932;
+mod windows936;
+mod windows949;
+mod windows950;
 
 pub use ascii::{DecoderAscii, EncoderAscii};
 pub use base64::{Base64Context, Base64Stream};
 pub use character_decoder::{CharacterDecoder, new_character_decoder};
-pub use character_encoder::{CharacterEncoder, new_character_encoder};
+pub use character_encoder::{CharacterEncoder, new_character_encoder, try_new_character_encoder};
+pub use detection::{detect_character_encoding, score_character_encoding};
 pub use enums::CharacterEncoding;
 pub use iso8859_1::{DecoderIso8859_1, EncoderIso8859_1};
 pub use iso8859_2::{DecoderIso8859_2, EncoderIso8859_2};
@@ -79,14 +101,40 @@ pub use koi8_u::{DecoderKoi8U, EncoderKoi8U};
 pub use mac_arabic::{DecoderMacArabic, EncoderMacArabic};
 pub use mac_celtic::{DecoderMacCeltic, EncoderMacCeltic};
 pub use mac_central_eur_roman::{DecoderMacCentralEurRoman, EncoderMacCentralEurRoman};
+pub use mac_chinese_simplified::{DecoderMacChineseSimplified, EncoderMacChineseSimplified};
+pub use mac_chinese_traditional::{DecoderMacChineseTraditional, EncoderMacChineseTraditional};
 pub use mac_croatian::{DecoderMacCroatian, EncoderMacCroatian};
 pub use mac_cyrillic::{DecoderMacCyrillic, EncoderMacCyrillic};
 pub use mac_dingbats::{DecoderMacDingbats, EncoderMacDingbats};
 pub use mac_farsi::{DecoderMacFarsi, EncoderMacFarsi};
 pub use mac_gaelic::{DecoderMacGaelic, EncoderMacGaelic};
+pub use mac_greek::{DecoderMacGreek, EncoderMacGreek};
+pub use mac_hebrew::{DecoderMacHebrew, EncoderMacHebrew};
+pub use mac_icelandic::{DecoderMacIcelandic, EncoderMacIcelandic};
+pub use mac_inuit::{DecoderMacInuit, EncoderMacInuit};
+pub use mac_japanese::{DecoderMacJapanese, EncoderMacJapanese};
+pub use mac_korean::{DecoderMacKorean, EncoderMacKorean};
+pub use mac_roman::{DecoderMacRoman, EncoderMacRoman};
+pub use mac_russian::{DecoderMacRussian, EncoderMacRussian};
+pub use mac_symbol::{DecoderMacSymbol, EncoderMacSymbol};
+pub use mac_thai::{DecoderMacThai, EncoderMacThai};
+pub use mac_turkish::{DecoderMacTurkish, EncoderMacTurkish};
+pub use mac_ukrainian::{DecoderMacUkrainian, EncoderMacUkrainian};
+pub use ucs2::{DecoderUcs2, EncoderUcs2};
 pub use utf8::DecoderUtf8;
+pub use utf16::{
+    DecoderUtf16BigEndian, DecoderUtf16LittleEndian, EncoderUtf16BigEndian,
+    EncoderUtf16LittleEndian,
+};
+pub use utf32::{
+    DecoderUtf32BigEndian, DecoderUtf32LittleEndian, EncoderUtf32BigEndian,
+    EncoderUtf32LittleEndian,
+};
 pub use windows874::{DecoderWindows874, EncoderWindows874};
 pub use windows932::{DecoderWindows932, EncoderWindows932};
+pub use windows936::{DecoderWindows936, EncoderWindows936};
+pub use windows949::{DecoderWindows949, EncoderWindows949};
+pub use windows950::{DecoderWindows950, EncoderWindows950};
 pub use windows1250::{DecoderWindows1250, EncoderWindows1250};
 pub use windows1251::{DecoderWindows1251, EncoderWindows1251};
 pub use windows1252::{DecoderWindows1252, EncoderWindows1252};
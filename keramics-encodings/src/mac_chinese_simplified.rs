@@ -0,0 +1,27824 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! MacChineseSimplified encoding.
+//!
+//! Provides support for encoding and decoding MacChineseSimplified.
+
+use keramics_core::ErrorTrace;
+
+/// MacChineseSimplified decoder.
+pub struct DecoderMacChineseSimplified<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderMacChineseSimplified<'a> {
+    const BASE_0XA1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x3000),
+        Some(0x3001),
+        Some(0x3002),
+        Some(0x00b7),
+        Some(0x02c9),
+        Some(0x02c7),
+        Some(0x00a8),
+        Some(0x3003),
+        Some(0x3005),
+        Some(0x2014),
+        Some(0x301c),
+        Some(0x2016),
+        Some(0x22ef),
+        Some(0x2018),
+        Some(0x2019),
+        Some(0x201c),
+        Some(0x201d),
+        Some(0x3014),
+        Some(0x3015),
+        Some(0x3008),
+        Some(0x3009),
+        Some(0x300a),
+        Some(0x300b),
+        Some(0x300c),
+        Some(0x300d),
+        Some(0x300e),
+        Some(0x300f),
+        Some(0x3016),
+        Some(0x3017),
+        Some(0x3010),
+        Some(0x3011),
+        Some(0x00b1),
+        Some(0x00d7),
+        Some(0x00f7),
+        Some(0x2236),
+        Some(0x2227),
+        Some(0x2228),
+        Some(0x2211),
+        Some(0x220f),
+        Some(0x222a),
+        Some(0x2229),
+        Some(0x2208),
+        Some(0x2237),
+        Some(0x221a),
+        Some(0x22a5),
+        Some(0x2225),
+        Some(0x2220),
+        Some(0x2312),
+        Some(0x2299),
+        Some(0x222b),
+        Some(0x222e),
+        Some(0x2261),
+        Some(0x224c),
+        Some(0x2248),
+        Some(0x223d),
+        Some(0x221d),
+        Some(0x2260),
+        Some(0x226e),
+        Some(0x226f),
+        Some(0x2264),
+        Some(0x2265),
+        Some(0x221e),
+        Some(0x2235),
+        Some(0x2234),
+        Some(0x2642),
+        Some(0x2640),
+        Some(0x00b0),
+        Some(0x2032),
+        Some(0x2033),
+        Some(0x2103),
+        Some(0xff04),
+        Some(0x00a4),
+        Some(0x00a2),
+        Some(0x00a3),
+        Some(0x2030),
+        Some(0x00a7),
+        Some(0x2116),
+        Some(0x2606),
+        Some(0x2605),
+        Some(0x25cb),
+        Some(0x25cf),
+        Some(0x25ce),
+        Some(0x25c7),
+        Some(0x25c6),
+        Some(0x25a1),
+        Some(0x25a0),
+        Some(0x25b3),
+        Some(0x25b2),
+        Some(0x203b),
+        Some(0x2192),
+        Some(0x2190),
+        Some(0x2191),
+        Some(0x2193),
+        Some(0x3013),
+    ];
+
+    const BASE_0XA2B0: [Option<u16>; 77] = [
+        None,
+        Some(0x2488),
+        Some(0x2489),
+        Some(0x248a),
+        Some(0x248b),
+        Some(0x248c),
+        Some(0x248d),
+        Some(0x248e),
+        Some(0x248f),
+        Some(0x2490),
+        Some(0x2491),
+        Some(0x2492),
+        Some(0x2493),
+        Some(0x2494),
+        Some(0x2495),
+        Some(0x2496),
+        Some(0x2497),
+        Some(0x2498),
+        Some(0x2499),
+        Some(0x249a),
+        Some(0x249b),
+        Some(0x2474),
+        Some(0x2475),
+        Some(0x2476),
+        Some(0x2477),
+        Some(0x2478),
+        Some(0x2479),
+        Some(0x247a),
+        Some(0x247b),
+        Some(0x247c),
+        Some(0x247d),
+        Some(0x247e),
+        Some(0x247f),
+        Some(0x2480),
+        Some(0x2481),
+        Some(0x2482),
+        Some(0x2483),
+        Some(0x2484),
+        Some(0x2485),
+        Some(0x2486),
+        Some(0x2487),
+        Some(0x2460),
+        Some(0x2461),
+        Some(0x2462),
+        Some(0x2463),
+        Some(0x2464),
+        Some(0x2465),
+        Some(0x2466),
+        Some(0x2467),
+        Some(0x2468),
+        Some(0x2469),
+        None,
+        None,
+        Some(0x3220),
+        Some(0x3221),
+        Some(0x3222),
+        Some(0x3223),
+        Some(0x3224),
+        Some(0x3225),
+        Some(0x3226),
+        Some(0x3227),
+        Some(0x3228),
+        Some(0x3229),
+        None,
+        None,
+        Some(0x2160),
+        Some(0x2161),
+        Some(0x2162),
+        Some(0x2163),
+        Some(0x2164),
+        Some(0x2165),
+        Some(0x2166),
+        Some(0x2167),
+        Some(0x2168),
+        Some(0x2169),
+        Some(0x216a),
+        Some(0x216b),
+    ];
+
+    const BASE_0XA3A0: [Option<u16>; 95] = [
+        None,
+        Some(0xff01),
+        Some(0xff02),
+        Some(0xff03),
+        Some(0x00a5),
+        Some(0xff05),
+        Some(0xff06),
+        Some(0xff07),
+        Some(0xff08),
+        Some(0xff09),
+        Some(0xff0a),
+        Some(0xff0b),
+        Some(0xff0c),
+        Some(0xff0d),
+        Some(0xff0e),
+        Some(0xff0f),
+        Some(0xff10),
+        Some(0xff11),
+        Some(0xff12),
+        Some(0xff13),
+        Some(0xff14),
+        Some(0xff15),
+        Some(0xff16),
+        Some(0xff17),
+        Some(0xff18),
+        Some(0xff19),
+        Some(0xff1a),
+        Some(0xff1b),
+        Some(0xff1c),
+        Some(0xff1d),
+        Some(0xff1e),
+        Some(0xff1f),
+        Some(0xff20),
+        Some(0xff21),
+        Some(0xff22),
+        Some(0xff23),
+        Some(0xff24),
+        Some(0xff25),
+        Some(0xff26),
+        Some(0xff27),
+        Some(0xff28),
+        Some(0xff29),
+        Some(0xff2a),
+        Some(0xff2b),
+        Some(0xff2c),
+        Some(0xff2d),
+        Some(0xff2e),
+        Some(0xff2f),
+        Some(0xff30),
+        Some(0xff31),
+        Some(0xff32),
+        Some(0xff33),
+        Some(0xff34),
+        Some(0xff35),
+        Some(0xff36),
+        Some(0xff37),
+        Some(0xff38),
+        Some(0xff39),
+        Some(0xff3a),
+        Some(0xff3b),
+        Some(0xff3c),
+        Some(0xff3d),
+        Some(0xff3e),
+        Some(0xff3f),
+        Some(0xff40),
+        Some(0xff41),
+        Some(0xff42),
+        Some(0xff43),
+        Some(0xff44),
+        Some(0xff45),
+        Some(0xff46),
+        Some(0xff47),
+        Some(0xff48),
+        Some(0xff49),
+        Some(0xff4a),
+        Some(0xff4b),
+        Some(0xff4c),
+        Some(0xff4d),
+        Some(0xff4e),
+        Some(0xff4f),
+        Some(0xff50),
+        Some(0xff51),
+        Some(0xff52),
+        Some(0xff53),
+        Some(0xff54),
+        Some(0xff55),
+        Some(0xff56),
+        Some(0xff57),
+        Some(0xff58),
+        Some(0xff59),
+        Some(0xff5a),
+        Some(0xff5b),
+        Some(0xff5c),
+        Some(0xff5d),
+        Some(0x203e),
+    ];
+
+    const BASE_0XA4A0: [Option<u16>; 84] = [
+        None,
+        Some(0x3041),
+        Some(0x3042),
+        Some(0x3043),
+        Some(0x3044),
+        Some(0x3045),
+        Some(0x3046),
+        Some(0x3047),
+        Some(0x3048),
+        Some(0x3049),
+        Some(0x304a),
+        Some(0x304b),
+        Some(0x304c),
+        Some(0x304d),
+        Some(0x304e),
+        Some(0x304f),
+        Some(0x3050),
+        Some(0x3051),
+        Some(0x3052),
+        Some(0x3053),
+        Some(0x3054),
+        Some(0x3055),
+        Some(0x3056),
+        Some(0x3057),
+        Some(0x3058),
+        Some(0x3059),
+        Some(0x305a),
+        Some(0x305b),
+        Some(0x305c),
+        Some(0x305d),
+        Some(0x305e),
+        Some(0x305f),
+        Some(0x3060),
+        Some(0x3061),
+        Some(0x3062),
+        Some(0x3063),
+        Some(0x3064),
+        Some(0x3065),
+        Some(0x3066),
+        Some(0x3067),
+        Some(0x3068),
+        Some(0x3069),
+        Some(0x306a),
+        Some(0x306b),
+        Some(0x306c),
+        Some(0x306d),
+        Some(0x306e),
+        Some(0x306f),
+        Some(0x3070),
+        Some(0x3071),
+        Some(0x3072),
+        Some(0x3073),
+        Some(0x3074),
+        Some(0x3075),
+        Some(0x3076),
+        Some(0x3077),
+        Some(0x3078),
+        Some(0x3079),
+        Some(0x307a),
+        Some(0x307b),
+        Some(0x307c),
+        Some(0x307d),
+        Some(0x307e),
+        Some(0x307f),
+        Some(0x3080),
+        Some(0x3081),
+        Some(0x3082),
+        Some(0x3083),
+        Some(0x3084),
+        Some(0x3085),
+        Some(0x3086),
+        Some(0x3087),
+        Some(0x3088),
+        Some(0x3089),
+        Some(0x308a),
+        Some(0x308b),
+        Some(0x308c),
+        Some(0x308d),
+        Some(0x308e),
+        Some(0x308f),
+        Some(0x3090),
+        Some(0x3091),
+        Some(0x3092),
+        Some(0x3093),
+    ];
+
+    const BASE_0XA5A0: [Option<u16>; 87] = [
+        None,
+        Some(0x30a1),
+        Some(0x30a2),
+        Some(0x30a3),
+        Some(0x30a4),
+        Some(0x30a5),
+        Some(0x30a6),
+        Some(0x30a7),
+        Some(0x30a8),
+        Some(0x30a9),
+        Some(0x30aa),
+        Some(0x30ab),
+        Some(0x30ac),
+        Some(0x30ad),
+        Some(0x30ae),
+        Some(0x30af),
+        Some(0x30b0),
+        Some(0x30b1),
+        Some(0x30b2),
+        Some(0x30b3),
+        Some(0x30b4),
+        Some(0x30b5),
+        Some(0x30b6),
+        Some(0x30b7),
+        Some(0x30b8),
+        Some(0x30b9),
+        Some(0x30ba),
+        Some(0x30bb),
+        Some(0x30bc),
+        Some(0x30bd),
+        Some(0x30be),
+        Some(0x30bf),
+        Some(0x30c0),
+        Some(0x30c1),
+        Some(0x30c2),
+        Some(0x30c3),
+        Some(0x30c4),
+        Some(0x30c5),
+        Some(0x30c6),
+        Some(0x30c7),
+        Some(0x30c8),
+        Some(0x30c9),
+        Some(0x30ca),
+        Some(0x30cb),
+        Some(0x30cc),
+        Some(0x30cd),
+        Some(0x30ce),
+        Some(0x30cf),
+        Some(0x30d0),
+        Some(0x30d1),
+        Some(0x30d2),
+        Some(0x30d3),
+        Some(0x30d4),
+        Some(0x30d5),
+        Some(0x30d6),
+        Some(0x30d7),
+        Some(0x30d8),
+        Some(0x30d9),
+        Some(0x30da),
+        Some(0x30db),
+        Some(0x30dc),
+        Some(0x30dd),
+        Some(0x30de),
+        Some(0x30df),
+        Some(0x30e0),
+        Some(0x30e1),
+        Some(0x30e2),
+        Some(0x30e3),
+        Some(0x30e4),
+        Some(0x30e5),
+        Some(0x30e6),
+        Some(0x30e7),
+        Some(0x30e8),
+        Some(0x30e9),
+        Some(0x30ea),
+        Some(0x30eb),
+        Some(0x30ec),
+        Some(0x30ed),
+        Some(0x30ee),
+        Some(0x30ef),
+        Some(0x30f0),
+        Some(0x30f1),
+        Some(0x30f2),
+        Some(0x30f3),
+        Some(0x30f4),
+        Some(0x30f5),
+        Some(0x30f6),
+    ];
+
+    const BASE_0XA6A0: [Option<u16>; 86] = [
+        None,
+        Some(0x0391),
+        Some(0x0392),
+        Some(0x0393),
+        Some(0x0394),
+        Some(0x0395),
+        Some(0x0396),
+        Some(0x0397),
+        Some(0x0398),
+        Some(0x0399),
+        Some(0x039a),
+        Some(0x039b),
+        Some(0x039c),
+        Some(0x039d),
+        Some(0x039e),
+        Some(0x039f),
+        Some(0x03a0),
+        Some(0x03a1),
+        Some(0x03a3),
+        Some(0x03a4),
+        Some(0x03a5),
+        Some(0x03a6),
+        Some(0x03a7),
+        Some(0x03a8),
+        Some(0x03a9),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x03b1),
+        Some(0x03b2),
+        Some(0x03b3),
+        Some(0x03b4),
+        Some(0x03b5),
+        Some(0x03b6),
+        Some(0x03b7),
+        Some(0x03b8),
+        Some(0x03b9),
+        Some(0x03ba),
+        Some(0x03bb),
+        Some(0x03bc),
+        Some(0x03bd),
+        Some(0x03be),
+        Some(0x03bf),
+        Some(0x03c0),
+        Some(0x03c1),
+        Some(0x03c3),
+        Some(0x03c4),
+        Some(0x03c5),
+        Some(0x03c6),
+        Some(0x03c7),
+        Some(0x03c8),
+        Some(0x03c9),
+        Some(0xff0c),
+        Some(0x3002),
+        Some(0x3001),
+        Some(0xff1a),
+        Some(0xff1b),
+        Some(0xff01),
+        Some(0xff1f),
+        Some(0xfe35),
+        Some(0xfe36),
+        Some(0xfe39),
+        Some(0xfe3a),
+        Some(0xfe3f),
+        Some(0xfe40),
+        Some(0xfe3d),
+        Some(0xfe3e),
+        Some(0xfe41),
+        Some(0xfe42),
+        Some(0xfe43),
+        Some(0xfe44),
+        Some(0x3016),
+        Some(0x3017),
+        Some(0xfe3b),
+        Some(0xfe3c),
+        Some(0xfe37),
+        Some(0xfe38),
+        Some(0xfe31),
+        Some(0x22ef),
+        Some(0xfe33),
+        Some(0xfe34),
+    ];
+
+    const BASE_0XA7A0: [Option<u16>; 82] = [
+        None,
+        Some(0x0410),
+        Some(0x0411),
+        Some(0x0412),
+        Some(0x0413),
+        Some(0x0414),
+        Some(0x0415),
+        Some(0x0401),
+        Some(0x0416),
+        Some(0x0417),
+        Some(0x0418),
+        Some(0x0419),
+        Some(0x041a),
+        Some(0x041b),
+        Some(0x041c),
+        Some(0x041d),
+        Some(0x041e),
+        Some(0x041f),
+        Some(0x0420),
+        Some(0x0421),
+        Some(0x0422),
+        Some(0x0423),
+        Some(0x0424),
+        Some(0x0425),
+        Some(0x0426),
+        Some(0x0427),
+        Some(0x0428),
+        Some(0x0429),
+        Some(0x042a),
+        Some(0x042b),
+        Some(0x042c),
+        Some(0x042d),
+        Some(0x042e),
+        Some(0x042f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x0430),
+        Some(0x0431),
+        Some(0x0432),
+        Some(0x0433),
+        Some(0x0434),
+        Some(0x0435),
+        Some(0x0451),
+        Some(0x0436),
+        Some(0x0437),
+        Some(0x0438),
+        Some(0x0439),
+        Some(0x043a),
+        Some(0x043b),
+        Some(0x043c),
+        Some(0x043d),
+        Some(0x043e),
+        Some(0x043f),
+        Some(0x0440),
+        Some(0x0441),
+        Some(0x0442),
+        Some(0x0443),
+        Some(0x0444),
+        Some(0x0445),
+        Some(0x0446),
+        Some(0x0447),
+        Some(0x0448),
+        Some(0x0449),
+        Some(0x044a),
+        Some(0x044b),
+        Some(0x044c),
+        Some(0x044d),
+        Some(0x044e),
+        Some(0x044f),
+    ];
+
+    const BASE_0XA8A0: [Option<u16>; 74] = [
+        None,
+        Some(0x0101),
+        Some(0x00e1),
+        Some(0x01ce),
+        Some(0x00e0),
+        Some(0x0113),
+        Some(0x00e9),
+        Some(0x011b),
+        Some(0x00e8),
+        Some(0x012b),
+        Some(0x00ed),
+        Some(0x01d0),
+        Some(0x00ec),
+        Some(0x014d),
+        Some(0x00f3),
+        Some(0x01d2),
+        Some(0x00f2),
+        Some(0x016b),
+        Some(0x00fa),
+        Some(0x01d4),
+        Some(0x00f9),
+        Some(0x01d6),
+        Some(0x01d8),
+        Some(0x01da),
+        Some(0x01dc),
+        Some(0x00fc),
+        Some(0x00ea),
+        Some(0x0251),
+        Some(0x1e3f),
+        Some(0x0144),
+        Some(0x0148),
+        Some(0x01f9),
+        Some(0x0261),
+        None,
+        None,
+        None,
+        None,
+        Some(0x3105),
+        Some(0x3106),
+        Some(0x3107),
+        Some(0x3108),
+        Some(0x3109),
+        Some(0x310a),
+        Some(0x310b),
+        Some(0x310c),
+        Some(0x310d),
+        Some(0x310e),
+        Some(0x310f),
+        Some(0x3110),
+        Some(0x3111),
+        Some(0x3112),
+        Some(0x3113),
+        Some(0x3114),
+        Some(0x3115),
+        Some(0x3116),
+        Some(0x3117),
+        Some(0x3118),
+        Some(0x3119),
+        Some(0x311a),
+        Some(0x311b),
+        Some(0x311c),
+        Some(0x311d),
+        Some(0x311e),
+        Some(0x311f),
+        Some(0x3120),
+        Some(0x3121),
+        Some(0x3122),
+        Some(0x3123),
+        Some(0x3124),
+        Some(0x3125),
+        Some(0x3126),
+        Some(0x3127),
+        Some(0x3128),
+        Some(0x3129),
+    ];
+
+    const BASE_0XA9A0: [Option<u16>; 80] = [
+        None,
+        None,
+        None,
+        None,
+        Some(0x2500),
+        Some(0x2501),
+        Some(0x2502),
+        Some(0x2503),
+        Some(0x2504),
+        Some(0x2505),
+        Some(0x2506),
+        Some(0x2507),
+        Some(0x2508),
+        Some(0x2509),
+        Some(0x250a),
+        Some(0x250b),
+        Some(0x250c),
+        Some(0x250d),
+        Some(0x250e),
+        Some(0x250f),
+        Some(0x2510),
+        Some(0x2511),
+        Some(0x2512),
+        Some(0x2513),
+        Some(0x2514),
+        Some(0x2515),
+        Some(0x2516),
+        Some(0x2517),
+        Some(0x2518),
+        Some(0x2519),
+        Some(0x251a),
+        Some(0x251b),
+        Some(0x251c),
+        Some(0x251d),
+        Some(0x251e),
+        Some(0x251f),
+        Some(0x2520),
+        Some(0x2521),
+        Some(0x2522),
+        Some(0x2523),
+        Some(0x2524),
+        Some(0x2525),
+        Some(0x2526),
+        Some(0x2527),
+        Some(0x2528),
+        Some(0x2529),
+        Some(0x252a),
+        Some(0x252b),
+        Some(0x252c),
+        Some(0x252d),
+        Some(0x252e),
+        Some(0x252f),
+        Some(0x2530),
+        Some(0x2531),
+        Some(0x2532),
+        Some(0x2533),
+        Some(0x2534),
+        Some(0x2535),
+        Some(0x2536),
+        Some(0x2537),
+        Some(0x2538),
+        Some(0x2539),
+        Some(0x253a),
+        Some(0x253b),
+        Some(0x253c),
+        Some(0x253d),
+        Some(0x253e),
+        Some(0x253f),
+        Some(0x2540),
+        Some(0x2541),
+        Some(0x2542),
+        Some(0x2543),
+        Some(0x2544),
+        Some(0x2545),
+        Some(0x2546),
+        Some(0x2547),
+        Some(0x2548),
+        Some(0x2549),
+        Some(0x254a),
+        Some(0x254b),
+    ];
+
+    const BASE_0XB0A0: [Option<u16>; 95] = [
+        None,
+        Some(0x554a),
+        Some(0x963f),
+        Some(0x57c3),
+        Some(0x6328),
+        Some(0x54ce),
+        Some(0x5509),
+        Some(0x54c0),
+        Some(0x7691),
+        Some(0x764c),
+        Some(0x853c),
+        Some(0x77ee),
+        Some(0x827e),
+        Some(0x788d),
+        Some(0x7231),
+        Some(0x9698),
+        Some(0x978d),
+        Some(0x6c28),
+        Some(0x5b89),
+        Some(0x4ffa),
+        Some(0x6309),
+        Some(0x6697),
+        Some(0x5cb8),
+        Some(0x80fa),
+        Some(0x6848),
+        Some(0x80ae),
+        Some(0x6602),
+        Some(0x76ce),
+        Some(0x51f9),
+        Some(0x6556),
+        Some(0x71ac),
+        Some(0x7ff1),
+        Some(0x8884),
+        Some(0x50b2),
+        Some(0x5965),
+        Some(0x61ca),
+        Some(0x6fb3),
+        Some(0x82ad),
+        Some(0x634c),
+        Some(0x6252),
+        Some(0x53ed),
+        Some(0x5427),
+        Some(0x7b06),
+        Some(0x516b),
+        Some(0x75a4),
+        Some(0x5df4),
+        Some(0x62d4),
+        Some(0x8dcb),
+        Some(0x9776),
+        Some(0x628a),
+        Some(0x8019),
+        Some(0x575d),
+        Some(0x9738),
+        Some(0x7f62),
+        Some(0x7238),
+        Some(0x767d),
+        Some(0x67cf),
+        Some(0x767e),
+        Some(0x6446),
+        Some(0x4f70),
+        Some(0x8d25),
+        Some(0x62dc),
+        Some(0x7a17),
+        Some(0x6591),
+        Some(0x73ed),
+        Some(0x642c),
+        Some(0x6273),
+        Some(0x822c),
+        Some(0x9881),
+        Some(0x677f),
+        Some(0x7248),
+        Some(0x626e),
+        Some(0x62cc),
+        Some(0x4f34),
+        Some(0x74e3),
+        Some(0x534a),
+        Some(0x529e),
+        Some(0x7eca),
+        Some(0x90a6),
+        Some(0x5e2e),
+        Some(0x6886),
+        Some(0x699c),
+        Some(0x8180),
+        Some(0x7ed1),
+        Some(0x68d2),
+        Some(0x78c5),
+        Some(0x868c),
+        Some(0x9551),
+        Some(0x508d),
+        Some(0x8c24),
+        Some(0x82de),
+        Some(0x80de),
+        Some(0x5305),
+        Some(0x8912),
+        Some(0x5265),
+    ];
+
+    const BASE_0XB1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x8584),
+        Some(0x96f9),
+        Some(0x4fdd),
+        Some(0x5821),
+        Some(0x9971),
+        Some(0x5b9d),
+        Some(0x62b1),
+        Some(0x62a5),
+        Some(0x66b4),
+        Some(0x8c79),
+        Some(0x9c8d),
+        Some(0x7206),
+        Some(0x676f),
+        Some(0x7891),
+        Some(0x60b2),
+        Some(0x5351),
+        Some(0x5317),
+        Some(0x8f88),
+        Some(0x80cc),
+        Some(0x8d1d),
+        Some(0x94a1),
+        Some(0x500d),
+        Some(0x72c8),
+        Some(0x5907),
+        Some(0x60eb),
+        Some(0x7119),
+        Some(0x88ab),
+        Some(0x5954),
+        Some(0x82ef),
+        Some(0x672c),
+        Some(0x7b28),
+        Some(0x5d29),
+        Some(0x7ef7),
+        Some(0x752d),
+        Some(0x6cf5),
+        Some(0x8e66),
+        Some(0x8ff8),
+        Some(0x903c),
+        Some(0x9f3b),
+        Some(0x6bd4),
+        Some(0x9119),
+        Some(0x7b14),
+        Some(0x5f7c),
+        Some(0x78a7),
+        Some(0x84d6),
+        Some(0x853d),
+        Some(0x6bd5),
+        Some(0x6bd9),
+        Some(0x6bd6),
+        Some(0x5e01),
+        Some(0x5e87),
+        Some(0x75f9),
+        Some(0x95ed),
+        Some(0x655d),
+        Some(0x5f0a),
+        Some(0x5fc5),
+        Some(0x8f9f),
+        Some(0x58c1),
+        Some(0x81c2),
+        Some(0x907f),
+        Some(0x965b),
+        Some(0x97ad),
+        Some(0x8fb9),
+        Some(0x7f16),
+        Some(0x8d2c),
+        Some(0x6241),
+        Some(0x4fbf),
+        Some(0x53d8),
+        Some(0x535e),
+        Some(0x8fa8),
+        Some(0x8fa9),
+        Some(0x8fab),
+        Some(0x904d),
+        Some(0x6807),
+        Some(0x5f6a),
+        Some(0x8198),
+        Some(0x8868),
+        Some(0x9cd6),
+        Some(0x618b),
+        Some(0x522b),
+        Some(0x762a),
+        Some(0x5f6c),
+        Some(0x658c),
+        Some(0x6fd2),
+        Some(0x6ee8),
+        Some(0x5bbe),
+        Some(0x6448),
+        Some(0x5175),
+        Some(0x51b0),
+        Some(0x67c4),
+        Some(0x4e19),
+        Some(0x79c9),
+        Some(0x997c),
+        Some(0x70b3),
+    ];
+
+    const BASE_0XB2A0: [Option<u16>; 95] = [
+        None,
+        Some(0x75c5),
+        Some(0x5e76),
+        Some(0x73bb),
+        Some(0x83e0),
+        Some(0x64ad),
+        Some(0x62e8),
+        Some(0x94b5),
+        Some(0x6ce2),
+        Some(0x535a),
+        Some(0x52c3),
+        Some(0x640f),
+        Some(0x94c2),
+        Some(0x7b94),
+        Some(0x4f2f),
+        Some(0x5e1b),
+        Some(0x8236),
+        Some(0x8116),
+        Some(0x818a),
+        Some(0x6e24),
+        Some(0x6cca),
+        Some(0x9a73),
+        Some(0x6355),
+        Some(0x535c),
+        Some(0x54fa),
+        Some(0x8865),
+        Some(0x57e0),
+        Some(0x4e0d),
+        Some(0x5e03),
+        Some(0x6b65),
+        Some(0x7c3f),
+        Some(0x90e8),
+        Some(0x6016),
+        Some(0x64e6),
+        Some(0x731c),
+        Some(0x88c1),
+        Some(0x6750),
+        Some(0x624d),
+        Some(0x8d22),
+        Some(0x776c),
+        Some(0x8e29),
+        Some(0x91c7),
+        Some(0x5f69),
+        Some(0x83dc),
+        Some(0x8521),
+        Some(0x9910),
+        Some(0x53c2),
+        Some(0x8695),
+        Some(0x6b8b),
+        Some(0x60ed),
+        Some(0x60e8),
+        Some(0x707f),
+        Some(0x82cd),
+        Some(0x8231),
+        Some(0x4ed3),
+        Some(0x6ca7),
+        Some(0x85cf),
+        Some(0x64cd),
+        Some(0x7cd9),
+        Some(0x69fd),
+        Some(0x66f9),
+        Some(0x8349),
+        Some(0x5395),
+        Some(0x7b56),
+        Some(0x4fa7),
+        Some(0x518c),
+        Some(0x6d4b),
+        Some(0x5c42),
+        Some(0x8e6d),
+        Some(0x63d2),
+        Some(0x53c9),
+        Some(0x832c),
+        Some(0x8336),
+        Some(0x67e5),
+        Some(0x78b4),
+        Some(0x643d),
+        Some(0x5bdf),
+        Some(0x5c94),
+        Some(0x5dee),
+        Some(0x8be7),
+        Some(0x62c6),
+        Some(0x67f4),
+        Some(0x8c7a),
+        Some(0x6400),
+        Some(0x63ba),
+        Some(0x8749),
+        Some(0x998b),
+        Some(0x8c17),
+        Some(0x7f20),
+        Some(0x94f2),
+        Some(0x4ea7),
+        Some(0x9610),
+        Some(0x98a4),
+        Some(0x660c),
+        Some(0x7316),
+    ];
+
+    const BASE_0XB3A0: [Option<u16>; 95] = [
+        None,
+        Some(0x573a),
+        Some(0x5c1d),
+        Some(0x5e38),
+        Some(0x957f),
+        Some(0x507f),
+        Some(0x80a0),
+        Some(0x5382),
+        Some(0x655e),
+        Some(0x7545),
+        Some(0x5531),
+        Some(0x5021),
+        Some(0x8d85),
+        Some(0x6284),
+        Some(0x949e),
+        Some(0x671d),
+        Some(0x5632),
+        Some(0x6f6e),
+        Some(0x5de2),
+        Some(0x5435),
+        Some(0x7092),
+        Some(0x8f66),
+        Some(0x626f),
+        Some(0x64a4),
+        Some(0x63a3),
+        Some(0x5f7b),
+        Some(0x6f88),
+        Some(0x90f4),
+        Some(0x81e3),
+        Some(0x8fb0),
+        Some(0x5c18),
+        Some(0x6668),
+        Some(0x5ff1),
+        Some(0x6c89),
+        Some(0x9648),
+        Some(0x8d81),
+        Some(0x886c),
+        Some(0x6491),
+        Some(0x79f0),
+        Some(0x57ce),
+        Some(0x6a59),
+        Some(0x6210),
+        Some(0x5448),
+        Some(0x4e58),
+        Some(0x7a0b),
+        Some(0x60e9),
+        Some(0x6f84),
+        Some(0x8bda),
+        Some(0x627f),
+        Some(0x901e),
+        Some(0x9a8b),
+        Some(0x79e4),
+        Some(0x5403),
+        Some(0x75f4),
+        Some(0x6301),
+        Some(0x5319),
+        Some(0x6c60),
+        Some(0x8fdf),
+        Some(0x5f1b),
+        Some(0x9a70),
+        Some(0x803b),
+        Some(0x9f7f),
+        Some(0x4f88),
+        Some(0x5c3a),
+        Some(0x8d64),
+        Some(0x7fc5),
+        Some(0x65a5),
+        Some(0x70bd),
+        Some(0x5145),
+        Some(0x51b2),
+        Some(0x866b),
+        Some(0x5d07),
+        Some(0x5ba0),
+        Some(0x62bd),
+        Some(0x916c),
+        Some(0x7574),
+        Some(0x8e0c),
+        Some(0x7a20),
+        Some(0x6101),
+        Some(0x7b79),
+        Some(0x4ec7),
+        Some(0x7ef8),
+        Some(0x7785),
+        Some(0x4e11),
+        Some(0x81ed),
+        Some(0x521d),
+        Some(0x51fa),
+        Some(0x6a71),
+        Some(0x53a8),
+        Some(0x8e87),
+        Some(0x9504),
+        Some(0x96cf),
+        Some(0x6ec1),
+        Some(0x9664),
+        Some(0x695a),
+    ];
+
+    const BASE_0XB4A0: [Option<u16>; 95] = [
+        None,
+        Some(0x7840),
+        Some(0x50a8),
+        Some(0x77d7),
+        Some(0x6410),
+        Some(0x89e6),
+        Some(0x5904),
+        Some(0x63e3),
+        Some(0x5ddd),
+        Some(0x7a7f),
+        Some(0x693d),
+        Some(0x4f20),
+        Some(0x8239),
+        Some(0x5598),
+        Some(0x4e32),
+        Some(0x75ae),
+        Some(0x7a97),
+        Some(0x5e62),
+        Some(0x5e8a),
+        Some(0x95ef),
+        Some(0x521b),
+        Some(0x5439),
+        Some(0x708a),
+        Some(0x6376),
+        Some(0x9524),
+        Some(0x5782),
+        Some(0x6625),
+        Some(0x693f),
+        Some(0x9187),
+        Some(0x5507),
+        Some(0x6df3),
+        Some(0x7eaf),
+        Some(0x8822),
+        Some(0x6233),
+        Some(0x7ef0),
+        Some(0x75b5),
+        Some(0x8328),
+        Some(0x78c1),
+        Some(0x96cc),
+        Some(0x8f9e),
+        Some(0x6148),
+        Some(0x74f7),
+        Some(0x8bcd),
+        Some(0x6b64),
+        Some(0x523a),
+        Some(0x8d50),
+        Some(0x6b21),
+        Some(0x806a),
+        Some(0x8471),
+        Some(0x56f1),
+        Some(0x5306),
+        Some(0x4ece),
+        Some(0x4e1b),
+        Some(0x51d1),
+        Some(0x7c97),
+        Some(0x918b),
+        Some(0x7c07),
+        Some(0x4fc3),
+        Some(0x8e7f),
+        Some(0x7be1),
+        Some(0x7a9c),
+        Some(0x6467),
+        Some(0x5d14),
+        Some(0x50ac),
+        Some(0x8106),
+        Some(0x7601),
+        Some(0x7cb9),
+        Some(0x6dec),
+        Some(0x7fe0),
+        Some(0x6751),
+        Some(0x5b58),
+        Some(0x5bf8),
+        Some(0x78cb),
+        Some(0x64ae),
+        Some(0x6413),
+        Some(0x63aa),
+        Some(0x632b),
+        Some(0x9519),
+        Some(0x642d),
+        Some(0x8fbe),
+        Some(0x7b54),
+        Some(0x7629),
+        Some(0x6253),
+        Some(0x5927),
+        Some(0x5446),
+        Some(0x6b79),
+        Some(0x50a3),
+        Some(0x6234),
+        Some(0x5e26),
+        Some(0x6b86),
+        Some(0x4ee3),
+        Some(0x8d37),
+        Some(0x888b),
+        Some(0x5f85),
+        Some(0x902e),
+    ];
+
+    const BASE_0XB5A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6020),
+        Some(0x803d),
+        Some(0x62c5),
+        Some(0x4e39),
+        Some(0x5355),
+        Some(0x90f8),
+        Some(0x63b8),
+        Some(0x80c6),
+        Some(0x65e6),
+        Some(0x6c2e),
+        Some(0x4f46),
+        Some(0x60ee),
+        Some(0x6de1),
+        Some(0x8bde),
+        Some(0x5f39),
+        Some(0x86cb),
+        Some(0x5f53),
+        Some(0x6321),
+        Some(0x515a),
+        Some(0x8361),
+        Some(0x6863),
+        Some(0x5200),
+        Some(0x6363),
+        Some(0x8e48),
+        Some(0x5012),
+        Some(0x5c9b),
+        Some(0x7977),
+        Some(0x5bfc),
+        Some(0x5230),
+        Some(0x7a3b),
+        Some(0x60bc),
+        Some(0x9053),
+        Some(0x76d7),
+        Some(0x5fb7),
+        Some(0x5f97),
+        Some(0x7684),
+        Some(0x8e6c),
+        Some(0x706f),
+        Some(0x767b),
+        Some(0x7b49),
+        Some(0x77aa),
+        Some(0x51f3),
+        Some(0x9093),
+        Some(0x5824),
+        Some(0x4f4e),
+        Some(0x6ef4),
+        Some(0x8fea),
+        Some(0x654c),
+        Some(0x7b1b),
+        Some(0x72c4),
+        Some(0x6da4),
+        Some(0x7fdf),
+        Some(0x5ae1),
+        Some(0x62b5),
+        Some(0x5e95),
+        Some(0x5730),
+        Some(0x8482),
+        Some(0x7b2c),
+        Some(0x5e1d),
+        Some(0x5f1f),
+        Some(0x9012),
+        Some(0x7f14),
+        Some(0x98a0),
+        Some(0x6382),
+        Some(0x6ec7),
+        Some(0x7898),
+        Some(0x70b9),
+        Some(0x5178),
+        Some(0x975b),
+        Some(0x57ab),
+        Some(0x7535),
+        Some(0x4f43),
+        Some(0x7538),
+        Some(0x5e97),
+        Some(0x60e6),
+        Some(0x5960),
+        Some(0x6dc0),
+        Some(0x6bbf),
+        Some(0x7889),
+        Some(0x53fc),
+        Some(0x96d5),
+        Some(0x51cb),
+        Some(0x5201),
+        Some(0x6389),
+        Some(0x540a),
+        Some(0x9493),
+        Some(0x8c03),
+        Some(0x8dcc),
+        Some(0x7239),
+        Some(0x789f),
+        Some(0x8776),
+        Some(0x8fed),
+        Some(0x8c0d),
+        Some(0x53e0),
+    ];
+
+    const BASE_0XB6A0: [Option<u16>; 95] = [
+        None,
+        Some(0x4e01),
+        Some(0x76ef),
+        Some(0x53ee),
+        Some(0x9489),
+        Some(0x9876),
+        Some(0x9f0e),
+        Some(0x952d),
+        Some(0x5b9a),
+        Some(0x8ba2),
+        Some(0x4e22),
+        Some(0x4e1c),
+        Some(0x51ac),
+        Some(0x8463),
+        Some(0x61c2),
+        Some(0x52a8),
+        Some(0x680b),
+        Some(0x4f97),
+        Some(0x606b),
+        Some(0x51bb),
+        Some(0x6d1e),
+        Some(0x515c),
+        Some(0x6296),
+        Some(0x6597),
+        Some(0x9661),
+        Some(0x8c46),
+        Some(0x9017),
+        Some(0x75d8),
+        Some(0x90fd),
+        Some(0x7763),
+        Some(0x6bd2),
+        Some(0x728a),
+        Some(0x72ec),
+        Some(0x8bfb),
+        Some(0x5835),
+        Some(0x7779),
+        Some(0x8d4c),
+        Some(0x675c),
+        Some(0x9540),
+        Some(0x809a),
+        Some(0x5ea6),
+        Some(0x6e21),
+        Some(0x5992),
+        Some(0x7aef),
+        Some(0x77ed),
+        Some(0x953b),
+        Some(0x6bb5),
+        Some(0x65ad),
+        Some(0x7f0e),
+        Some(0x5806),
+        Some(0x5151),
+        Some(0x961f),
+        Some(0x5bf9),
+        Some(0x58a9),
+        Some(0x5428),
+        Some(0x8e72),
+        Some(0x6566),
+        Some(0x987f),
+        Some(0x56e4),
+        Some(0x949d),
+        Some(0x76fe),
+        Some(0x9041),
+        Some(0x6387),
+        Some(0x54c6),
+        Some(0x591a),
+        Some(0x593a),
+        Some(0x579b),
+        Some(0x8eb2),
+        Some(0x6735),
+        Some(0x8dfa),
+        Some(0x8235),
+        Some(0x5241),
+        Some(0x60f0),
+        Some(0x5815),
+        Some(0x86fe),
+        Some(0x5ce8),
+        Some(0x9e45),
+        Some(0x4fc4),
+        Some(0x989d),
+        Some(0x8bb9),
+        Some(0x5a25),
+        Some(0x6076),
+        Some(0x5384),
+        Some(0x627c),
+        Some(0x904f),
+        Some(0x9102),
+        Some(0x997f),
+        Some(0x6069),
+        Some(0x800c),
+        Some(0x513f),
+        Some(0x8033),
+        Some(0x5c14),
+        Some(0x9975),
+        Some(0x6d31),
+        Some(0x4e8c),
+    ];
+
+    const BASE_0XB7A0: [Option<u16>; 95] = [
+        None,
+        Some(0x8d30),
+        Some(0x53d1),
+        Some(0x7f5a),
+        Some(0x7b4f),
+        Some(0x4f10),
+        Some(0x4e4f),
+        Some(0x9600),
+        Some(0x6cd5),
+        Some(0x73d0),
+        Some(0x85e9),
+        Some(0x5e06),
+        Some(0x756a),
+        Some(0x7ffb),
+        Some(0x6a0a),
+        Some(0x77fe),
+        Some(0x9492),
+        Some(0x7e41),
+        Some(0x51e1),
+        Some(0x70e6),
+        Some(0x53cd),
+        Some(0x8fd4),
+        Some(0x8303),
+        Some(0x8d29),
+        Some(0x72af),
+        Some(0x996d),
+        Some(0x6cdb),
+        Some(0x574a),
+        Some(0x82b3),
+        Some(0x65b9),
+        Some(0x80aa),
+        Some(0x623f),
+        Some(0x9632),
+        Some(0x59a8),
+        Some(0x4eff),
+        Some(0x8bbf),
+        Some(0x7eba),
+        Some(0x653e),
+        Some(0x83f2),
+        Some(0x975e),
+        Some(0x5561),
+        Some(0x98de),
+        Some(0x80a5),
+        Some(0x532a),
+        Some(0x8bfd),
+        Some(0x5420),
+        Some(0x80ba),
+        Some(0x5e9f),
+        Some(0x6cb8),
+        Some(0x8d39),
+        Some(0x82ac),
+        Some(0x915a),
+        Some(0x5429),
+        Some(0x6c1b),
+        Some(0x5206),
+        Some(0x7eb7),
+        Some(0x575f),
+        Some(0x711a),
+        Some(0x6c7e),
+        Some(0x7c89),
+        Some(0x594b),
+        Some(0x4efd),
+        Some(0x5fff),
+        Some(0x6124),
+        Some(0x7caa),
+        Some(0x4e30),
+        Some(0x5c01),
+        Some(0x67ab),
+        Some(0x8702),
+        Some(0x5cf0),
+        Some(0x950b),
+        Some(0x98ce),
+        Some(0x75af),
+        Some(0x70fd),
+        Some(0x9022),
+        Some(0x51af),
+        Some(0x7f1d),
+        Some(0x8bbd),
+        Some(0x5949),
+        Some(0x51e4),
+        Some(0x4f5b),
+        Some(0x5426),
+        Some(0x592b),
+        Some(0x6577),
+        Some(0x80a4),
+        Some(0x5b75),
+        Some(0x6276),
+        Some(0x62c2),
+        Some(0x8f90),
+        Some(0x5e45),
+        Some(0x6c1f),
+        Some(0x7b26),
+        Some(0x4f0f),
+        Some(0x4fd8),
+        Some(0x670d),
+    ];
+
+    const BASE_0XB8A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6d6e),
+        Some(0x6daa),
+        Some(0x798f),
+        Some(0x88b1),
+        Some(0x5f17),
+        Some(0x752b),
+        Some(0x629a),
+        Some(0x8f85),
+        Some(0x4fef),
+        Some(0x91dc),
+        Some(0x65a7),
+        Some(0x812f),
+        Some(0x8151),
+        Some(0x5e9c),
+        Some(0x8150),
+        Some(0x8d74),
+        Some(0x526f),
+        Some(0x8986),
+        Some(0x8d4b),
+        Some(0x590d),
+        Some(0x5085),
+        Some(0x4ed8),
+        Some(0x961c),
+        Some(0x7236),
+        Some(0x8179),
+        Some(0x8d1f),
+        Some(0x5bcc),
+        Some(0x8ba3),
+        Some(0x9644),
+        Some(0x5987),
+        Some(0x7f1a),
+        Some(0x5490),
+        Some(0x5676),
+        Some(0x560e),
+        Some(0x8be5),
+        Some(0x6539),
+        Some(0x6982),
+        Some(0x9499),
+        Some(0x76d6),
+        Some(0x6e89),
+        Some(0x5e72),
+        Some(0x7518),
+        Some(0x6746),
+        Some(0x67d1),
+        Some(0x7aff),
+        Some(0x809d),
+        Some(0x8d76),
+        Some(0x611f),
+        Some(0x79c6),
+        Some(0x6562),
+        Some(0x8d63),
+        Some(0x5188),
+        Some(0x521a),
+        Some(0x94a2),
+        Some(0x7f38),
+        Some(0x809b),
+        Some(0x7eb2),
+        Some(0x5c97),
+        Some(0x6e2f),
+        Some(0x6760),
+        Some(0x7bd9),
+        Some(0x768b),
+        Some(0x9ad8),
+        Some(0x818f),
+        Some(0x7f94),
+        Some(0x7cd5),
+        Some(0x641e),
+        Some(0x9550),
+        Some(0x7a3f),
+        Some(0x544a),
+        Some(0x54e5),
+        Some(0x6b4c),
+        Some(0x6401),
+        Some(0x6208),
+        Some(0x9e3d),
+        Some(0x80f3),
+        Some(0x7599),
+        Some(0x5272),
+        Some(0x9769),
+        Some(0x845b),
+        Some(0x683c),
+        Some(0x86e4),
+        Some(0x9601),
+        Some(0x9694),
+        Some(0x94ec),
+        Some(0x4e2a),
+        Some(0x5404),
+        Some(0x7ed9),
+        Some(0x6839),
+        Some(0x8ddf),
+        Some(0x8015),
+        Some(0x66f4),
+        Some(0x5e9a),
+        Some(0x7fb9),
+    ];
+
+    const BASE_0XB9A0: [Option<u16>; 95] = [
+        None,
+        Some(0x57c2),
+        Some(0x803f),
+        Some(0x6897),
+        Some(0x5de5),
+        Some(0x653b),
+        Some(0x529f),
+        Some(0x606d),
+        Some(0x9f9a),
+        Some(0x4f9b),
+        Some(0x8eac),
+        Some(0x516c),
+        Some(0x5bab),
+        Some(0x5f13),
+        Some(0x5de9),
+        Some(0x6c5e),
+        Some(0x62f1),
+        Some(0x8d21),
+        Some(0x5171),
+        Some(0x94a9),
+        Some(0x52fe),
+        Some(0x6c9f),
+        Some(0x82df),
+        Some(0x72d7),
+        Some(0x57a2),
+        Some(0x6784),
+        Some(0x8d2d),
+        Some(0x591f),
+        Some(0x8f9c),
+        Some(0x83c7),
+        Some(0x5495),
+        Some(0x7b8d),
+        Some(0x4f30),
+        Some(0x6cbd),
+        Some(0x5b64),
+        Some(0x59d1),
+        Some(0x9f13),
+        Some(0x53e4),
+        Some(0x86ca),
+        Some(0x9aa8),
+        Some(0x8c37),
+        Some(0x80a1),
+        Some(0x6545),
+        Some(0x987e),
+        Some(0x56fa),
+        Some(0x96c7),
+        Some(0x522e),
+        Some(0x74dc),
+        Some(0x5250),
+        Some(0x5be1),
+        Some(0x6302),
+        Some(0x8902),
+        Some(0x4e56),
+        Some(0x62d0),
+        Some(0x602a),
+        Some(0x68fa),
+        Some(0x5173),
+        Some(0x5b98),
+        Some(0x51a0),
+        Some(0x89c2),
+        Some(0x7ba1),
+        Some(0x9986),
+        Some(0x7f50),
+        Some(0x60ef),
+        Some(0x704c),
+        Some(0x8d2f),
+        Some(0x5149),
+        Some(0x5e7f),
+        Some(0x901b),
+        Some(0x7470),
+        Some(0x89c4),
+        Some(0x572d),
+        Some(0x7845),
+        Some(0x5f52),
+        Some(0x9f9f),
+        Some(0x95fa),
+        Some(0x8f68),
+        Some(0x9b3c),
+        Some(0x8be1),
+        Some(0x7678),
+        Some(0x6842),
+        Some(0x67dc),
+        Some(0x8dea),
+        Some(0x8d35),
+        Some(0x523d),
+        Some(0x8f8a),
+        Some(0x6eda),
+        Some(0x68cd),
+        Some(0x9505),
+        Some(0x90ed),
+        Some(0x56fd),
+        Some(0x679c),
+        Some(0x88f9),
+        Some(0x8fc7),
+        Some(0x54c8),
+    ];
+
+    const BASE_0XBAA0: [Option<u16>; 95] = [
+        None,
+        Some(0x9ab8),
+        Some(0x5b69),
+        Some(0x6d77),
+        Some(0x6c26),
+        Some(0x4ea5),
+        Some(0x5bb3),
+        Some(0x9a87),
+        Some(0x9163),
+        Some(0x61a8),
+        Some(0x90af),
+        Some(0x97e9),
+        Some(0x542b),
+        Some(0x6db5),
+        Some(0x5bd2),
+        Some(0x51fd),
+        Some(0x558a),
+        Some(0x7f55),
+        Some(0x7ff0),
+        Some(0x64bc),
+        Some(0x634d),
+        Some(0x65f1),
+        Some(0x61be),
+        Some(0x608d),
+        Some(0x710a),
+        Some(0x6c57),
+        Some(0x6c49),
+        Some(0x592f),
+        Some(0x676d),
+        Some(0x822a),
+        Some(0x58d5),
+        Some(0x568e),
+        Some(0x8c6a),
+        Some(0x6beb),
+        Some(0x90dd),
+        Some(0x597d),
+        Some(0x8017),
+        Some(0x53f7),
+        Some(0x6d69),
+        Some(0x5475),
+        Some(0x559d),
+        Some(0x8377),
+        Some(0x83cf),
+        Some(0x6838),
+        Some(0x79be),
+        Some(0x548c),
+        Some(0x4f55),
+        Some(0x5408),
+        Some(0x76d2),
+        Some(0x8c89),
+        Some(0x9602),
+        Some(0x6cb3),
+        Some(0x6db8),
+        Some(0x8d6b),
+        Some(0x8910),
+        Some(0x9e64),
+        Some(0x8d3a),
+        Some(0x563f),
+        Some(0x9ed1),
+        Some(0x75d5),
+        Some(0x5f88),
+        Some(0x72e0),
+        Some(0x6068),
+        Some(0x54fc),
+        Some(0x4ea8),
+        Some(0x6a2a),
+        Some(0x8861),
+        Some(0x6052),
+        Some(0x8f70),
+        Some(0x54c4),
+        Some(0x70d8),
+        Some(0x8679),
+        Some(0x9e3f),
+        Some(0x6d2a),
+        Some(0x5b8f),
+        Some(0x5f18),
+        Some(0x7ea2),
+        Some(0x5589),
+        Some(0x4faf),
+        Some(0x7334),
+        Some(0x543c),
+        Some(0x539a),
+        Some(0x5019),
+        Some(0x540e),
+        Some(0x547c),
+        Some(0x4e4e),
+        Some(0x5ffd),
+        Some(0x745a),
+        Some(0x58f6),
+        Some(0x846b),
+        Some(0x80e1),
+        Some(0x8774),
+        Some(0x72d0),
+        Some(0x7cca),
+        Some(0x6e56),
+    ];
+
+    const BASE_0XBBA0: [Option<u16>; 95] = [
+        None,
+        Some(0x5f27),
+        Some(0x864e),
+        Some(0x552c),
+        Some(0x62a4),
+        Some(0x4e92),
+        Some(0x6caa),
+        Some(0x6237),
+        Some(0x82b1),
+        Some(0x54d7),
+        Some(0x534e),
+        Some(0x733e),
+        Some(0x6ed1),
+        Some(0x753b),
+        Some(0x5212),
+        Some(0x5316),
+        Some(0x8bdd),
+        Some(0x69d0),
+        Some(0x5f8a),
+        Some(0x6000),
+        Some(0x6dee),
+        Some(0x574f),
+        Some(0x6b22),
+        Some(0x73af),
+        Some(0x6853),
+        Some(0x8fd8),
+        Some(0x7f13),
+        Some(0x6362),
+        Some(0x60a3),
+        Some(0x5524),
+        Some(0x75ea),
+        Some(0x8c62),
+        Some(0x7115),
+        Some(0x6da3),
+        Some(0x5ba6),
+        Some(0x5e7b),
+        Some(0x8352),
+        Some(0x614c),
+        Some(0x9ec4),
+        Some(0x78fa),
+        Some(0x8757),
+        Some(0x7c27),
+        Some(0x7687),
+        Some(0x51f0),
+        Some(0x60f6),
+        Some(0x714c),
+        Some(0x6643),
+        Some(0x5e4c),
+        Some(0x604d),
+        Some(0x8c0e),
+        Some(0x7070),
+        Some(0x6325),
+        Some(0x8f89),
+        Some(0x5fbd),
+        Some(0x6062),
+        Some(0x86d4),
+        Some(0x56de),
+        Some(0x6bc1),
+        Some(0x6094),
+        Some(0x6167),
+        Some(0x5349),
+        Some(0x60e0),
+        Some(0x6666),
+        Some(0x8d3f),
+        Some(0x79fd),
+        Some(0x4f1a),
+        Some(0x70e9),
+        Some(0x6c47),
+        Some(0x8bb3),
+        Some(0x8bf2),
+        Some(0x7ed8),
+        Some(0x8364),
+        Some(0x660f),
+        Some(0x5a5a),
+        Some(0x9b42),
+        Some(0x6d51),
+        Some(0x6df7),
+        Some(0x8c41),
+        Some(0x6d3b),
+        Some(0x4f19),
+        Some(0x706b),
+        Some(0x83b7),
+        Some(0x6216),
+        Some(0x60d1),
+        Some(0x970d),
+        Some(0x8d27),
+        Some(0x7978),
+        Some(0x51fb),
+        Some(0x573e),
+        Some(0x57fa),
+        Some(0x673a),
+        Some(0x7578),
+        Some(0x7a3d),
+        Some(0x79ef),
+        Some(0x7b95),
+    ];
+
+    const BASE_0XBCA0: [Option<u16>; 95] = [
+        None,
+        Some(0x808c),
+        Some(0x9965),
+        Some(0x8ff9),
+        Some(0x6fc0),
+        Some(0x8ba5),
+        Some(0x9e21),
+        Some(0x59ec),
+        Some(0x7ee9),
+        Some(0x7f09),
+        Some(0x5409),
+        Some(0x6781),
+        Some(0x68d8),
+        Some(0x8f91),
+        Some(0x7c4d),
+        Some(0x96c6),
+        Some(0x53ca),
+        Some(0x6025),
+        Some(0x75be),
+        Some(0x6c72),
+        Some(0x5373),
+        Some(0x5ac9),
+        Some(0x7ea7),
+        Some(0x6324),
+        Some(0x51e0),
+        Some(0x810a),
+        Some(0x5df1),
+        Some(0x84df),
+        Some(0x6280),
+        Some(0x5180),
+        Some(0x5b63),
+        Some(0x4f0e),
+        Some(0x796d),
+        Some(0x5242),
+        Some(0x60b8),
+        Some(0x6d4e),
+        Some(0x5bc4),
+        Some(0x5bc2),
+        Some(0x8ba1),
+        Some(0x8bb0),
+        Some(0x65e2),
+        Some(0x5fcc),
+        Some(0x9645),
+        Some(0x5993),
+        Some(0x7ee7),
+        Some(0x7eaa),
+        Some(0x5609),
+        Some(0x67b7),
+        Some(0x5939),
+        Some(0x4f73),
+        Some(0x5bb6),
+        Some(0x52a0),
+        Some(0x835a),
+        Some(0x988a),
+        Some(0x8d3e),
+        Some(0x7532),
+        Some(0x94be),
+        Some(0x5047),
+        Some(0x7a3c),
+        Some(0x4ef7),
+        Some(0x67b6),
+        Some(0x9a7e),
+        Some(0x5ac1),
+        Some(0x6b7c),
+        Some(0x76d1),
+        Some(0x575a),
+        Some(0x5c16),
+        Some(0x7b3a),
+        Some(0x95f4),
+        Some(0x714e),
+        Some(0x517c),
+        Some(0x80a9),
+        Some(0x8270),
+        Some(0x5978),
+        Some(0x7f04),
+        Some(0x8327),
+        Some(0x68c0),
+        Some(0x67ec),
+        Some(0x78b1),
+        Some(0x7877),
+        Some(0x62e3),
+        Some(0x6361),
+        Some(0x7b80),
+        Some(0x4fed),
+        Some(0x526a),
+        Some(0x51cf),
+        Some(0x8350),
+        Some(0x69db),
+        Some(0x9274),
+        Some(0x8df5),
+        Some(0x8d31),
+        Some(0x89c1),
+        Some(0x952e),
+        Some(0x7bad),
+        Some(0x4ef6),
+    ];
+
+    const BASE_0XBDA0: [Option<u16>; 95] = [
+        None,
+        Some(0x5065),
+        Some(0x8230),
+        Some(0x5251),
+        Some(0x996f),
+        Some(0x6e10),
+        Some(0x6e85),
+        Some(0x6da7),
+        Some(0x5efa),
+        Some(0x50f5),
+        Some(0x59dc),
+        Some(0x5c06),
+        Some(0x6d46),
+        Some(0x6c5f),
+        Some(0x7586),
+        Some(0x848b),
+        Some(0x6868),
+        Some(0x5956),
+        Some(0x8bb2),
+        Some(0x5320),
+        Some(0x9171),
+        Some(0x964d),
+        Some(0x8549),
+        Some(0x6912),
+        Some(0x7901),
+        Some(0x7126),
+        Some(0x80f6),
+        Some(0x4ea4),
+        Some(0x90ca),
+        Some(0x6d47),
+        Some(0x9a84),
+        Some(0x5a07),
+        Some(0x56bc),
+        Some(0x6405),
+        Some(0x94f0),
+        Some(0x77eb),
+        Some(0x4fa5),
+        Some(0x811a),
+        Some(0x72e1),
+        Some(0x89d2),
+        Some(0x997a),
+        Some(0x7f34),
+        Some(0x7ede),
+        Some(0x527f),
+        Some(0x6559),
+        Some(0x9175),
+        Some(0x8f7f),
+        Some(0x8f83),
+        Some(0x53eb),
+        Some(0x7a96),
+        Some(0x63ed),
+        Some(0x63a5),
+        Some(0x7686),
+        Some(0x79f8),
+        Some(0x8857),
+        Some(0x9636),
+        Some(0x622a),
+        Some(0x52ab),
+        Some(0x8282),
+        Some(0x6854),
+        Some(0x6770),
+        Some(0x6377),
+        Some(0x776b),
+        Some(0x7aed),
+        Some(0x6d01),
+        Some(0x7ed3),
+        Some(0x89e3),
+        Some(0x59d0),
+        Some(0x6212),
+        Some(0x85c9),
+        Some(0x82a5),
+        Some(0x754c),
+        Some(0x501f),
+        Some(0x4ecb),
+        Some(0x75a5),
+        Some(0x8beb),
+        Some(0x5c4a),
+        Some(0x5dfe),
+        Some(0x7b4b),
+        Some(0x65a4),
+        Some(0x91d1),
+        Some(0x4eca),
+        Some(0x6d25),
+        Some(0x895f),
+        Some(0x7d27),
+        Some(0x9526),
+        Some(0x4ec5),
+        Some(0x8c28),
+        Some(0x8fdb),
+        Some(0x9773),
+        Some(0x664b),
+        Some(0x7981),
+        Some(0x8fd1),
+        Some(0x70ec),
+        Some(0x6d78),
+    ];
+
+    const BASE_0XBEA0: [Option<u16>; 95] = [
+        None,
+        Some(0x5c3d),
+        Some(0x52b2),
+        Some(0x8346),
+        Some(0x5162),
+        Some(0x830e),
+        Some(0x775b),
+        Some(0x6676),
+        Some(0x9cb8),
+        Some(0x4eac),
+        Some(0x60ca),
+        Some(0x7cbe),
+        Some(0x7cb3),
+        Some(0x7ecf),
+        Some(0x4e95),
+        Some(0x8b66),
+        Some(0x666f),
+        Some(0x9888),
+        Some(0x9759),
+        Some(0x5883),
+        Some(0x656c),
+        Some(0x955c),
+        Some(0x5f84),
+        Some(0x75c9),
+        Some(0x9756),
+        Some(0x7adf),
+        Some(0x7ade),
+        Some(0x51c0),
+        Some(0x70af),
+        Some(0x7a98),
+        Some(0x63ea),
+        Some(0x7a76),
+        Some(0x7ea0),
+        Some(0x7396),
+        Some(0x97ed),
+        Some(0x4e45),
+        Some(0x7078),
+        Some(0x4e5d),
+        Some(0x9152),
+        Some(0x53a9),
+        Some(0x6551),
+        Some(0x65e7),
+        Some(0x81fc),
+        Some(0x8205),
+        Some(0x548e),
+        Some(0x5c31),
+        Some(0x759a),
+        Some(0x97a0),
+        Some(0x62d8),
+        Some(0x72d9),
+        Some(0x75bd),
+        Some(0x5c45),
+        Some(0x9a79),
+        Some(0x83ca),
+        Some(0x5c40),
+        Some(0x5480),
+        Some(0x77e9),
+        Some(0x4e3e),
+        Some(0x6cae),
+        Some(0x805a),
+        Some(0x62d2),
+        Some(0x636e),
+        Some(0x5de8),
+        Some(0x5177),
+        Some(0x8ddd),
+        Some(0x8e1e),
+        Some(0x952f),
+        Some(0x4ff1),
+        Some(0x53e5),
+        Some(0x60e7),
+        Some(0x70ac),
+        Some(0x5267),
+        Some(0x6350),
+        Some(0x9e43),
+        Some(0x5a1f),
+        Some(0x5026),
+        Some(0x7737),
+        Some(0x5377),
+        Some(0x7ee2),
+        Some(0x6485),
+        Some(0x652b),
+        Some(0x6289),
+        Some(0x6398),
+        Some(0x5014),
+        Some(0x7235),
+        Some(0x89c9),
+        Some(0x51b3),
+        Some(0x8bc0),
+        Some(0x7edd),
+        Some(0x5747),
+        Some(0x83cc),
+        Some(0x94a7),
+        Some(0x519b),
+        Some(0x541b),
+        Some(0x5cfb),
+    ];
+
+    const BASE_0XBFA0: [Option<u16>; 95] = [
+        None,
+        Some(0x4fca),
+        Some(0x7ae3),
+        Some(0x6d5a),
+        Some(0x90e1),
+        Some(0x9a8f),
+        Some(0x5580),
+        Some(0x5496),
+        Some(0x5361),
+        Some(0x54af),
+        Some(0x5f00),
+        Some(0x63e9),
+        Some(0x6977),
+        Some(0x51ef),
+        Some(0x6168),
+        Some(0x520a),
+        Some(0x582a),
+        Some(0x52d8),
+        Some(0x574e),
+        Some(0x780d),
+        Some(0x770b),
+        Some(0x5eb7),
+        Some(0x6177),
+        Some(0x7ce0),
+        Some(0x625b),
+        Some(0x6297),
+        Some(0x4ea2),
+        Some(0x7095),
+        Some(0x8003),
+        Some(0x62f7),
+        Some(0x70e4),
+        Some(0x9760),
+        Some(0x5777),
+        Some(0x82db),
+        Some(0x67ef),
+        Some(0x68f5),
+        Some(0x78d5),
+        Some(0x9897),
+        Some(0x79d1),
+        Some(0x58f3),
+        Some(0x54b3),
+        Some(0x53ef),
+        Some(0x6e34),
+        Some(0x514b),
+        Some(0x523b),
+        Some(0x5ba2),
+        Some(0x8bfe),
+        Some(0x80af),
+        Some(0x5543),
+        Some(0x57a6),
+        Some(0x6073),
+        Some(0x5751),
+        Some(0x542d),
+        Some(0x7a7a),
+        Some(0x6050),
+        Some(0x5b54),
+        Some(0x63a7),
+        Some(0x62a0),
+        Some(0x53e3),
+        Some(0x6263),
+        Some(0x5bc7),
+        Some(0x67af),
+        Some(0x54ed),
+        Some(0x7a9f),
+        Some(0x82e6),
+        Some(0x9177),
+        Some(0x5e93),
+        Some(0x88e4),
+        Some(0x5938),
+        Some(0x57ae),
+        Some(0x630e),
+        Some(0x8de8),
+        Some(0x80ef),
+        Some(0x5757),
+        Some(0x7b77),
+        Some(0x4fa9),
+        Some(0x5feb),
+        Some(0x5bbd),
+        Some(0x6b3e),
+        Some(0x5321),
+        Some(0x7b50),
+        Some(0x72c2),
+        Some(0x6846),
+        Some(0x77ff),
+        Some(0x7736),
+        Some(0x65f7),
+        Some(0x51b5),
+        Some(0x4e8f),
+        Some(0x76d4),
+        Some(0x5cbf),
+        Some(0x7aa5),
+        Some(0x8475),
+        Some(0x594e),
+        Some(0x9b41),
+        Some(0x5080),
+    ];
+
+    const BASE_0XC0A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9988),
+        Some(0x6127),
+        Some(0x6e83),
+        Some(0x5764),
+        Some(0x6606),
+        Some(0x6346),
+        Some(0x56f0),
+        Some(0x62ec),
+        Some(0x6269),
+        Some(0x5ed3),
+        Some(0x9614),
+        Some(0x5783),
+        Some(0x62c9),
+        Some(0x5587),
+        Some(0x8721),
+        Some(0x814a),
+        Some(0x8fa3),
+        Some(0x5566),
+        Some(0x83b1),
+        Some(0x6765),
+        Some(0x8d56),
+        Some(0x84dd),
+        Some(0x5a6a),
+        Some(0x680f),
+        Some(0x62e6),
+        Some(0x7bee),
+        Some(0x9611),
+        Some(0x5170),
+        Some(0x6f9c),
+        Some(0x8c30),
+        Some(0x63fd),
+        Some(0x89c8),
+        Some(0x61d2),
+        Some(0x7f06),
+        Some(0x70c2),
+        Some(0x6ee5),
+        Some(0x7405),
+        Some(0x6994),
+        Some(0x72fc),
+        Some(0x5eca),
+        Some(0x90ce),
+        Some(0x6717),
+        Some(0x6d6a),
+        Some(0x635e),
+        Some(0x52b3),
+        Some(0x7262),
+        Some(0x8001),
+        Some(0x4f6c),
+        Some(0x59e5),
+        Some(0x916a),
+        Some(0x70d9),
+        Some(0x6d9d),
+        Some(0x52d2),
+        Some(0x4e50),
+        Some(0x96f7),
+        Some(0x956d),
+        Some(0x857e),
+        Some(0x78ca),
+        Some(0x7d2f),
+        Some(0x5121),
+        Some(0x5792),
+        Some(0x64c2),
+        Some(0x808b),
+        Some(0x7c7b),
+        Some(0x6cea),
+        Some(0x68f1),
+        Some(0x695e),
+        Some(0x51b7),
+        Some(0x5398),
+        Some(0x68a8),
+        Some(0x7281),
+        Some(0x9ece),
+        Some(0x7bf1),
+        Some(0x72f8),
+        Some(0x79bb),
+        Some(0x6f13),
+        Some(0x7406),
+        Some(0x674e),
+        Some(0x91cc),
+        Some(0x9ca4),
+        Some(0x793c),
+        Some(0x8389),
+        Some(0x8354),
+        Some(0x540f),
+        Some(0x6817),
+        Some(0x4e3d),
+        Some(0x5389),
+        Some(0x52b1),
+        Some(0x783e),
+        Some(0x5386),
+        Some(0x5229),
+        Some(0x5088),
+        Some(0x4f8b),
+        Some(0x4fd0),
+    ];
+
+    const BASE_0XC1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x75e2),
+        Some(0x7acb),
+        Some(0x7c92),
+        Some(0x6ca5),
+        Some(0x96b6),
+        Some(0x529b),
+        Some(0x7483),
+        Some(0x54e9),
+        Some(0x4fe9),
+        Some(0x8054),
+        Some(0x83b2),
+        Some(0x8fde),
+        Some(0x9570),
+        Some(0x5ec9),
+        Some(0x601c),
+        Some(0x6d9f),
+        Some(0x5e18),
+        Some(0x655b),
+        Some(0x8138),
+        Some(0x94fe),
+        Some(0x604b),
+        Some(0x70bc),
+        Some(0x7ec3),
+        Some(0x7cae),
+        Some(0x51c9),
+        Some(0x6881),
+        Some(0x7cb1),
+        Some(0x826f),
+        Some(0x4e24),
+        Some(0x8f86),
+        Some(0x91cf),
+        Some(0x667e),
+        Some(0x4eae),
+        Some(0x8c05),
+        Some(0x64a9),
+        Some(0x804a),
+        Some(0x50da),
+        Some(0x7597),
+        Some(0x71ce),
+        Some(0x5be5),
+        Some(0x8fbd),
+        Some(0x6f66),
+        Some(0x4e86),
+        Some(0x6482),
+        Some(0x9563),
+        Some(0x5ed6),
+        Some(0x6599),
+        Some(0x5217),
+        Some(0x88c2),
+        Some(0x70c8),
+        Some(0x52a3),
+        Some(0x730e),
+        Some(0x7433),
+        Some(0x6797),
+        Some(0x78f7),
+        Some(0x9716),
+        Some(0x4e34),
+        Some(0x90bb),
+        Some(0x9cde),
+        Some(0x6dcb),
+        Some(0x51db),
+        Some(0x8d41),
+        Some(0x541d),
+        Some(0x62ce),
+        Some(0x73b2),
+        Some(0x83f1),
+        Some(0x96f6),
+        Some(0x9f84),
+        Some(0x94c3),
+        Some(0x4f36),
+        Some(0x7f9a),
+        Some(0x51cc),
+        Some(0x7075),
+        Some(0x9675),
+        Some(0x5cad),
+        Some(0x9886),
+        Some(0x53e6),
+        Some(0x4ee4),
+        Some(0x6e9c),
+        Some(0x7409),
+        Some(0x69b4),
+        Some(0x786b),
+        Some(0x998f),
+        Some(0x7559),
+        Some(0x5218),
+        Some(0x7624),
+        Some(0x6d41),
+        Some(0x67f3),
+        Some(0x516d),
+        Some(0x9f99),
+        Some(0x804b),
+        Some(0x5499),
+        Some(0x7b3c),
+        Some(0x7abf),
+    ];
+
+    const BASE_0XC2A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9686),
+        Some(0x5784),
+        Some(0x62e2),
+        Some(0x9647),
+        Some(0x697c),
+        Some(0x5a04),
+        Some(0x6402),
+        Some(0x7bd3),
+        Some(0x6f0f),
+        Some(0x964b),
+        Some(0x82a6),
+        Some(0x5362),
+        Some(0x9885),
+        Some(0x5e90),
+        Some(0x7089),
+        Some(0x63b3),
+        Some(0x5364),
+        Some(0x864f),
+        Some(0x9c81),
+        Some(0x9e93),
+        Some(0x788c),
+        Some(0x9732),
+        Some(0x8def),
+        Some(0x8d42),
+        Some(0x9e7f),
+        Some(0x6f5e),
+        Some(0x7984),
+        Some(0x5f55),
+        Some(0x9646),
+        Some(0x622e),
+        Some(0x9a74),
+        Some(0x5415),
+        Some(0x94dd),
+        Some(0x4fa3),
+        Some(0x65c5),
+        Some(0x5c65),
+        Some(0x5c61),
+        Some(0x7f15),
+        Some(0x8651),
+        Some(0x6c2f),
+        Some(0x5f8b),
+        Some(0x7387),
+        Some(0x6ee4),
+        Some(0x7eff),
+        Some(0x5ce6),
+        Some(0x631b),
+        Some(0x5b6a),
+        Some(0x6ee6),
+        Some(0x5375),
+        Some(0x4e71),
+        Some(0x63a0),
+        Some(0x7565),
+        Some(0x62a1),
+        Some(0x8f6e),
+        Some(0x4f26),
+        Some(0x4ed1),
+        Some(0x6ca6),
+        Some(0x7eb6),
+        Some(0x8bba),
+        Some(0x841d),
+        Some(0x87ba),
+        Some(0x7f57),
+        Some(0x903b),
+        Some(0x9523),
+        Some(0x7ba9),
+        Some(0x9aa1),
+        Some(0x88f8),
+        Some(0x843d),
+        Some(0x6d1b),
+        Some(0x9a86),
+        Some(0x7edc),
+        Some(0x5988),
+        Some(0x9ebb),
+        Some(0x739b),
+        Some(0x7801),
+        Some(0x8682),
+        Some(0x9a6c),
+        Some(0x9a82),
+        Some(0x561b),
+        Some(0x5417),
+        Some(0x57cb),
+        Some(0x4e70),
+        Some(0x9ea6),
+        Some(0x5356),
+        Some(0x8fc8),
+        Some(0x8109),
+        Some(0x7792),
+        Some(0x9992),
+        Some(0x86ee),
+        Some(0x6ee1),
+        Some(0x8513),
+        Some(0x66fc),
+        Some(0x6162),
+        Some(0x6f2b),
+    ];
+
+    const BASE_0XC3A0: [Option<u16>; 95] = [
+        None,
+        Some(0x8c29),
+        Some(0x8292),
+        Some(0x832b),
+        Some(0x76f2),
+        Some(0x6c13),
+        Some(0x5fd9),
+        Some(0x83bd),
+        Some(0x732b),
+        Some(0x8305),
+        Some(0x951a),
+        Some(0x6bdb),
+        Some(0x77db),
+        Some(0x94c6),
+        Some(0x536f),
+        Some(0x8302),
+        Some(0x5192),
+        Some(0x5e3d),
+        Some(0x8c8c),
+        Some(0x8d38),
+        Some(0x4e48),
+        Some(0x73ab),
+        Some(0x679a),
+        Some(0x6885),
+        Some(0x9176),
+        Some(0x9709),
+        Some(0x7164),
+        Some(0x6ca1),
+        Some(0x7709),
+        Some(0x5a92),
+        Some(0x9541),
+        Some(0x6bcf),
+        Some(0x7f8e),
+        Some(0x6627),
+        Some(0x5bd0),
+        Some(0x59b9),
+        Some(0x5a9a),
+        Some(0x95e8),
+        Some(0x95f7),
+        Some(0x4eec),
+        Some(0x840c),
+        Some(0x8499),
+        Some(0x6aac),
+        Some(0x76df),
+        Some(0x9530),
+        Some(0x731b),
+        Some(0x68a6),
+        Some(0x5b5f),
+        Some(0x772f),
+        Some(0x919a),
+        Some(0x9761),
+        Some(0x7cdc),
+        Some(0x8ff7),
+        Some(0x8c1c),
+        Some(0x5f25),
+        Some(0x7c73),
+        Some(0x79d8),
+        Some(0x89c5),
+        Some(0x6ccc),
+        Some(0x871c),
+        Some(0x5bc6),
+        Some(0x5e42),
+        Some(0x68c9),
+        Some(0x7720),
+        Some(0x7ef5),
+        Some(0x5195),
+        Some(0x514d),
+        Some(0x52c9),
+        Some(0x5a29),
+        Some(0x7f05),
+        Some(0x9762),
+        Some(0x82d7),
+        Some(0x63cf),
+        Some(0x7784),
+        Some(0x85d0),
+        Some(0x79d2),
+        Some(0x6e3a),
+        Some(0x5e99),
+        Some(0x5999),
+        Some(0x8511),
+        Some(0x706d),
+        Some(0x6c11),
+        Some(0x62bf),
+        Some(0x76bf),
+        Some(0x654f),
+        Some(0x60af),
+        Some(0x95fd),
+        Some(0x660e),
+        Some(0x879f),
+        Some(0x9e23),
+        Some(0x94ed),
+        Some(0x540d),
+        Some(0x547d),
+        Some(0x8c2c),
+        Some(0x6478),
+    ];
+
+    const BASE_0XC4A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6479),
+        Some(0x8611),
+        Some(0x6a21),
+        Some(0x819c),
+        Some(0x78e8),
+        Some(0x6469),
+        Some(0x9b54),
+        Some(0x62b9),
+        Some(0x672b),
+        Some(0x83ab),
+        Some(0x58a8),
+        Some(0x9ed8),
+        Some(0x6cab),
+        Some(0x6f20),
+        Some(0x5bde),
+        Some(0x964c),
+        Some(0x8c0b),
+        Some(0x725f),
+        Some(0x67d0),
+        Some(0x62c7),
+        Some(0x7261),
+        Some(0x4ea9),
+        Some(0x59c6),
+        Some(0x6bcd),
+        Some(0x5893),
+        Some(0x66ae),
+        Some(0x5e55),
+        Some(0x52df),
+        Some(0x6155),
+        Some(0x6728),
+        Some(0x76ee),
+        Some(0x7766),
+        Some(0x7267),
+        Some(0x7a46),
+        Some(0x62ff),
+        Some(0x54ea),
+        Some(0x5450),
+        Some(0x94a0),
+        Some(0x90a3),
+        Some(0x5a1c),
+        Some(0x7eb3),
+        Some(0x6c16),
+        Some(0x4e43),
+        Some(0x5976),
+        Some(0x8010),
+        Some(0x5948),
+        Some(0x5357),
+        Some(0x7537),
+        Some(0x96be),
+        Some(0x56ca),
+        Some(0x6320),
+        Some(0x8111),
+        Some(0x607c),
+        Some(0x95f9),
+        Some(0x6dd6),
+        Some(0x5462),
+        Some(0x9981),
+        Some(0x5185),
+        Some(0x5ae9),
+        Some(0x80fd),
+        Some(0x59ae),
+        Some(0x9713),
+        Some(0x502a),
+        Some(0x6ce5),
+        Some(0x5c3c),
+        Some(0x62df),
+        Some(0x4f60),
+        Some(0x533f),
+        Some(0x817b),
+        Some(0x9006),
+        Some(0x6eba),
+        Some(0x852b),
+        Some(0x62c8),
+        Some(0x5e74),
+        Some(0x78be),
+        Some(0x64b5),
+        Some(0x637b),
+        Some(0x5ff5),
+        Some(0x5a18),
+        Some(0x917f),
+        Some(0x9e1f),
+        Some(0x5c3f),
+        Some(0x634f),
+        Some(0x8042),
+        Some(0x5b7d),
+        Some(0x556e),
+        Some(0x954a),
+        Some(0x954d),
+        Some(0x6d85),
+        Some(0x60a8),
+        Some(0x67e0),
+        Some(0x72de),
+        Some(0x51dd),
+        Some(0x5b81),
+    ];
+
+    const BASE_0XC5A0: [Option<u16>; 95] = [
+        None,
+        Some(0x62e7),
+        Some(0x6cde),
+        Some(0x725b),
+        Some(0x626d),
+        Some(0x94ae),
+        Some(0x7ebd),
+        Some(0x8113),
+        Some(0x6d53),
+        Some(0x519c),
+        Some(0x5f04),
+        Some(0x5974),
+        Some(0x52aa),
+        Some(0x6012),
+        Some(0x5973),
+        Some(0x6696),
+        Some(0x8650),
+        Some(0x759f),
+        Some(0x632a),
+        Some(0x61e6),
+        Some(0x7cef),
+        Some(0x8bfa),
+        Some(0x54e6),
+        Some(0x6b27),
+        Some(0x9e25),
+        Some(0x6bb4),
+        Some(0x85d5),
+        Some(0x5455),
+        Some(0x5076),
+        Some(0x6ca4),
+        Some(0x556a),
+        Some(0x8db4),
+        Some(0x722c),
+        Some(0x5e15),
+        Some(0x6015),
+        Some(0x7436),
+        Some(0x62cd),
+        Some(0x6392),
+        Some(0x724c),
+        Some(0x5f98),
+        Some(0x6e43),
+        Some(0x6d3e),
+        Some(0x6500),
+        Some(0x6f58),
+        Some(0x76d8),
+        Some(0x78d0),
+        Some(0x76fc),
+        Some(0x7554),
+        Some(0x5224),
+        Some(0x53db),
+        Some(0x4e53),
+        Some(0x5e9e),
+        Some(0x65c1),
+        Some(0x802a),
+        Some(0x80d6),
+        Some(0x629b),
+        Some(0x5486),
+        Some(0x5228),
+        Some(0x70ae),
+        Some(0x888d),
+        Some(0x8dd1),
+        Some(0x6ce1),
+        Some(0x5478),
+        Some(0x80da),
+        Some(0x57f9),
+        Some(0x88f4),
+        Some(0x8d54),
+        Some(0x966a),
+        Some(0x914d),
+        Some(0x4f69),
+        Some(0x6c9b),
+        Some(0x55b7),
+        Some(0x76c6),
+        Some(0x7830),
+        Some(0x62a8),
+        Some(0x70f9),
+        Some(0x6f8e),
+        Some(0x5f6d),
+        Some(0x84ec),
+        Some(0x68da),
+        Some(0x787c),
+        Some(0x7bf7),
+        Some(0x81a8),
+        Some(0x670b),
+        Some(0x9e4f),
+        Some(0x6367),
+        Some(0x78b0),
+        Some(0x576f),
+        Some(0x7812),
+        Some(0x9739),
+        Some(0x6279),
+        Some(0x62ab),
+        Some(0x5288),
+        Some(0x7435),
+        Some(0x6bd7),
+    ];
+
+    const BASE_0XC6A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5564),
+        Some(0x813e),
+        Some(0x75b2),
+        Some(0x76ae),
+        Some(0x5339),
+        Some(0x75de),
+        Some(0x50fb),
+        Some(0x5c41),
+        Some(0x8b6c),
+        Some(0x7bc7),
+        Some(0x504f),
+        Some(0x7247),
+        Some(0x9a97),
+        Some(0x98d8),
+        Some(0x6f02),
+        Some(0x74e2),
+        Some(0x7968),
+        Some(0x6487),
+        Some(0x77a5),
+        Some(0x62fc),
+        Some(0x9891),
+        Some(0x8d2b),
+        Some(0x54c1),
+        Some(0x8058),
+        Some(0x4e52),
+        Some(0x576a),
+        Some(0x82f9),
+        Some(0x840d),
+        Some(0x5e73),
+        Some(0x51ed),
+        Some(0x74f6),
+        Some(0x8bc4),
+        Some(0x5c4f),
+        Some(0x5761),
+        Some(0x6cfc),
+        Some(0x9887),
+        Some(0x5a46),
+        Some(0x7834),
+        Some(0x9b44),
+        Some(0x8feb),
+        Some(0x7c95),
+        Some(0x5256),
+        Some(0x6251),
+        Some(0x94fa),
+        Some(0x4ec6),
+        Some(0x8386),
+        Some(0x8461),
+        Some(0x83e9),
+        Some(0x84b2),
+        Some(0x57d4),
+        Some(0x6734),
+        Some(0x5703),
+        Some(0x666e),
+        Some(0x6d66),
+        Some(0x8c31),
+        Some(0x66dd),
+        Some(0x7011),
+        Some(0x671f),
+        Some(0x6b3a),
+        Some(0x6816),
+        Some(0x621a),
+        Some(0x59bb),
+        Some(0x4e03),
+        Some(0x51c4),
+        Some(0x6f06),
+        Some(0x67d2),
+        Some(0x6c8f),
+        Some(0x5176),
+        Some(0x68cb),
+        Some(0x5947),
+        Some(0x6b67),
+        Some(0x7566),
+        Some(0x5d0e),
+        Some(0x8110),
+        Some(0x9f50),
+        Some(0x65d7),
+        Some(0x7948),
+        Some(0x7941),
+        Some(0x9a91),
+        Some(0x8d77),
+        Some(0x5c82),
+        Some(0x4e5e),
+        Some(0x4f01),
+        Some(0x542f),
+        Some(0x5951),
+        Some(0x780c),
+        Some(0x5668),
+        Some(0x6c14),
+        Some(0x8fc4),
+        Some(0x5f03),
+        Some(0x6c7d),
+        Some(0x6ce3),
+        Some(0x8bab),
+        Some(0x6390),
+    ];
+
+    const BASE_0XC7A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6070),
+        Some(0x6d3d),
+        Some(0x7275),
+        Some(0x6266),
+        Some(0x948e),
+        Some(0x94c5),
+        Some(0x5343),
+        Some(0x8fc1),
+        Some(0x7b7e),
+        Some(0x4edf),
+        Some(0x8c26),
+        Some(0x4e7e),
+        Some(0x9ed4),
+        Some(0x94b1),
+        Some(0x94b3),
+        Some(0x524d),
+        Some(0x6f5c),
+        Some(0x9063),
+        Some(0x6d45),
+        Some(0x8c34),
+        Some(0x5811),
+        Some(0x5d4c),
+        Some(0x6b20),
+        Some(0x6b49),
+        Some(0x67aa),
+        Some(0x545b),
+        Some(0x8154),
+        Some(0x7f8c),
+        Some(0x5899),
+        Some(0x8537),
+        Some(0x5f3a),
+        Some(0x62a2),
+        Some(0x6a47),
+        Some(0x9539),
+        Some(0x6572),
+        Some(0x6084),
+        Some(0x6865),
+        Some(0x77a7),
+        Some(0x4e54),
+        Some(0x4fa8),
+        Some(0x5de7),
+        Some(0x9798),
+        Some(0x64ac),
+        Some(0x7fd8),
+        Some(0x5ced),
+        Some(0x4fcf),
+        Some(0x7a8d),
+        Some(0x5207),
+        Some(0x8304),
+        Some(0x4e14),
+        Some(0x602f),
+        Some(0x7a83),
+        Some(0x94a6),
+        Some(0x4fb5),
+        Some(0x4eb2),
+        Some(0x79e6),
+        Some(0x7434),
+        Some(0x52e4),
+        Some(0x82b9),
+        Some(0x64d2),
+        Some(0x79bd),
+        Some(0x5bdd),
+        Some(0x6c81),
+        Some(0x9752),
+        Some(0x8f7b),
+        Some(0x6c22),
+        Some(0x503e),
+        Some(0x537f),
+        Some(0x6e05),
+        Some(0x64ce),
+        Some(0x6674),
+        Some(0x6c30),
+        Some(0x60c5),
+        Some(0x9877),
+        Some(0x8bf7),
+        Some(0x5e86),
+        Some(0x743c),
+        Some(0x7a77),
+        Some(0x79cb),
+        Some(0x4e18),
+        Some(0x90b1),
+        Some(0x7403),
+        Some(0x6c42),
+        Some(0x56da),
+        Some(0x914b),
+        Some(0x6cc5),
+        Some(0x8d8b),
+        Some(0x533a),
+        Some(0x86c6),
+        Some(0x66f2),
+        Some(0x8eaf),
+        Some(0x5c48),
+        Some(0x9a71),
+        Some(0x6e20),
+    ];
+
+    const BASE_0XC8A0: [Option<u16>; 95] = [
+        None,
+        Some(0x53d6),
+        Some(0x5a36),
+        Some(0x9f8b),
+        Some(0x8da3),
+        Some(0x53bb),
+        Some(0x5708),
+        Some(0x98a7),
+        Some(0x6743),
+        Some(0x919b),
+        Some(0x6cc9),
+        Some(0x5168),
+        Some(0x75ca),
+        Some(0x62f3),
+        Some(0x72ac),
+        Some(0x5238),
+        Some(0x529d),
+        Some(0x7f3a),
+        Some(0x7094),
+        Some(0x7638),
+        Some(0x5374),
+        Some(0x9e4a),
+        Some(0x69b7),
+        Some(0x786e),
+        Some(0x96c0),
+        Some(0x88d9),
+        Some(0x7fa4),
+        Some(0x7136),
+        Some(0x71c3),
+        Some(0x5189),
+        Some(0x67d3),
+        Some(0x74e4),
+        Some(0x58e4),
+        Some(0x6518),
+        Some(0x56b7),
+        Some(0x8ba9),
+        Some(0x9976),
+        Some(0x6270),
+        Some(0x7ed5),
+        Some(0x60f9),
+        Some(0x70ed),
+        Some(0x58ec),
+        Some(0x4ec1),
+        Some(0x4eba),
+        Some(0x5fcd),
+        Some(0x97e7),
+        Some(0x4efb),
+        Some(0x8ba4),
+        Some(0x5203),
+        Some(0x598a),
+        Some(0x7eab),
+        Some(0x6254),
+        Some(0x4ecd),
+        Some(0x65e5),
+        Some(0x620e),
+        Some(0x8338),
+        Some(0x84c9),
+        Some(0x8363),
+        Some(0x878d),
+        Some(0x7194),
+        Some(0x6eb6),
+        Some(0x5bb9),
+        Some(0x7ed2),
+        Some(0x5197),
+        Some(0x63c9),
+        Some(0x67d4),
+        Some(0x8089),
+        Some(0x8339),
+        Some(0x8815),
+        Some(0x5112),
+        Some(0x5b7a),
+        Some(0x5982),
+        Some(0x8fb1),
+        Some(0x4e73),
+        Some(0x6c5d),
+        Some(0x5165),
+        Some(0x8925),
+        Some(0x8f6f),
+        Some(0x962e),
+        Some(0x854a),
+        Some(0x745e),
+        Some(0x9510),
+        Some(0x95f0),
+        Some(0x6da6),
+        Some(0x82e5),
+        Some(0x5f31),
+        Some(0x6492),
+        Some(0x6d12),
+        Some(0x8428),
+        Some(0x816e),
+        Some(0x9cc3),
+        Some(0x585e),
+        Some(0x8d5b),
+        Some(0x4e09),
+        Some(0x53c1),
+    ];
+
+    const BASE_0XC9A0: [Option<u16>; 95] = [
+        None,
+        Some(0x4f1e),
+        Some(0x6563),
+        Some(0x6851),
+        Some(0x55d3),
+        Some(0x4e27),
+        Some(0x6414),
+        Some(0x9a9a),
+        Some(0x626b),
+        Some(0x5ac2),
+        Some(0x745f),
+        Some(0x8272),
+        Some(0x6da9),
+        Some(0x68ee),
+        Some(0x50e7),
+        Some(0x838e),
+        Some(0x7802),
+        Some(0x6740),
+        Some(0x5239),
+        Some(0x6c99),
+        Some(0x7eb1),
+        Some(0x50bb),
+        Some(0x5565),
+        Some(0x715e),
+        Some(0x7b5b),
+        Some(0x6652),
+        Some(0x73ca),
+        Some(0x82eb),
+        Some(0x6749),
+        Some(0x5c71),
+        Some(0x5220),
+        Some(0x717d),
+        Some(0x886b),
+        Some(0x95ea),
+        Some(0x9655),
+        Some(0x64c5),
+        Some(0x8d61),
+        Some(0x81b3),
+        Some(0x5584),
+        Some(0x6c55),
+        Some(0x6247),
+        Some(0x7f2e),
+        Some(0x5892),
+        Some(0x4f24),
+        Some(0x5546),
+        Some(0x8d4f),
+        Some(0x664c),
+        Some(0x4e0a),
+        Some(0x5c1a),
+        Some(0x88f3),
+        Some(0x68a2),
+        Some(0x634e),
+        Some(0x7a0d),
+        Some(0x70e7),
+        Some(0x828d),
+        Some(0x52fa),
+        Some(0x97f6),
+        Some(0x5c11),
+        Some(0x54e8),
+        Some(0x90b5),
+        Some(0x7ecd),
+        Some(0x5962),
+        Some(0x8d4a),
+        Some(0x86c7),
+        Some(0x820c),
+        Some(0x820d),
+        Some(0x8d66),
+        Some(0x6444),
+        Some(0x5c04),
+        Some(0x6151),
+        Some(0x6d89),
+        Some(0x793e),
+        Some(0x8bbe),
+        Some(0x7837),
+        Some(0x7533),
+        Some(0x547b),
+        Some(0x4f38),
+        Some(0x8eab),
+        Some(0x6df1),
+        Some(0x5a20),
+        Some(0x7ec5),
+        Some(0x795e),
+        Some(0x6c88),
+        Some(0x5ba1),
+        Some(0x5a76),
+        Some(0x751a),
+        Some(0x80be),
+        Some(0x614e),
+        Some(0x6e17),
+        Some(0x58f0),
+        Some(0x751f),
+        Some(0x7525),
+        Some(0x7272),
+        Some(0x5347),
+        Some(0x7ef3),
+    ];
+
+    const BASE_0XCAA0: [Option<u16>; 95] = [
+        None,
+        Some(0x7701),
+        Some(0x76db),
+        Some(0x5269),
+        Some(0x80dc),
+        Some(0x5723),
+        Some(0x5e08),
+        Some(0x5931),
+        Some(0x72ee),
+        Some(0x65bd),
+        Some(0x6e7f),
+        Some(0x8bd7),
+        Some(0x5c38),
+        Some(0x8671),
+        Some(0x5341),
+        Some(0x77f3),
+        Some(0x62fe),
+        Some(0x65f6),
+        Some(0x4ec0),
+        Some(0x98df),
+        Some(0x8680),
+        Some(0x5b9e),
+        Some(0x8bc6),
+        Some(0x53f2),
+        Some(0x77e2),
+        Some(0x4f7f),
+        Some(0x5c4e),
+        Some(0x9a76),
+        Some(0x59cb),
+        Some(0x5f0f),
+        Some(0x793a),
+        Some(0x58eb),
+        Some(0x4e16),
+        Some(0x67ff),
+        Some(0x4e8b),
+        Some(0x62ed),
+        Some(0x8a93),
+        Some(0x901d),
+        Some(0x52bf),
+        Some(0x662f),
+        Some(0x55dc),
+        Some(0x566c),
+        Some(0x9002),
+        Some(0x4ed5),
+        Some(0x4f8d),
+        Some(0x91ca),
+        Some(0x9970),
+        Some(0x6c0f),
+        Some(0x5e02),
+        Some(0x6043),
+        Some(0x5ba4),
+        Some(0x89c6),
+        Some(0x8bd5),
+        Some(0x6536),
+        Some(0x624b),
+        Some(0x9996),
+        Some(0x5b88),
+        Some(0x5bff),
+        Some(0x6388),
+        Some(0x552e),
+        Some(0x53d7),
+        Some(0x7626),
+        Some(0x517d),
+        Some(0x852c),
+        Some(0x67a2),
+        Some(0x68b3),
+        Some(0x6b8a),
+        Some(0x6292),
+        Some(0x8f93),
+        Some(0x53d4),
+        Some(0x8212),
+        Some(0x6dd1),
+        Some(0x758f),
+        Some(0x4e66),
+        Some(0x8d4e),
+        Some(0x5b70),
+        Some(0x719f),
+        Some(0x85af),
+        Some(0x6691),
+        Some(0x66d9),
+        Some(0x7f72),
+        Some(0x8700),
+        Some(0x9ecd),
+        Some(0x9f20),
+        Some(0x5c5e),
+        Some(0x672f),
+        Some(0x8ff0),
+        Some(0x6811),
+        Some(0x675f),
+        Some(0x620d),
+        Some(0x7ad6),
+        Some(0x5885),
+        Some(0x5eb6),
+        Some(0x6570),
+        Some(0x6f31),
+    ];
+
+    const BASE_0XCBA0: [Option<u16>; 95] = [
+        None,
+        Some(0x6055),
+        Some(0x5237),
+        Some(0x800d),
+        Some(0x6454),
+        Some(0x8870),
+        Some(0x7529),
+        Some(0x5e05),
+        Some(0x6813),
+        Some(0x62f4),
+        Some(0x971c),
+        Some(0x53cc),
+        Some(0x723d),
+        Some(0x8c01),
+        Some(0x6c34),
+        Some(0x7761),
+        Some(0x7a0e),
+        Some(0x542e),
+        Some(0x77ac),
+        Some(0x987a),
+        Some(0x821c),
+        Some(0x8bf4),
+        Some(0x7855),
+        Some(0x6714),
+        Some(0x70c1),
+        Some(0x65af),
+        Some(0x6495),
+        Some(0x5636),
+        Some(0x601d),
+        Some(0x79c1),
+        Some(0x53f8),
+        Some(0x4e1d),
+        Some(0x6b7b),
+        Some(0x8086),
+        Some(0x5bfa),
+        Some(0x55e3),
+        Some(0x56db),
+        Some(0x4f3a),
+        Some(0x4f3c),
+        Some(0x9972),
+        Some(0x5df3),
+        Some(0x677e),
+        Some(0x8038),
+        Some(0x6002),
+        Some(0x9882),
+        Some(0x9001),
+        Some(0x5b8b),
+        Some(0x8bbc),
+        Some(0x8bf5),
+        Some(0x641c),
+        Some(0x8258),
+        Some(0x64de),
+        Some(0x55fd),
+        Some(0x82cf),
+        Some(0x9165),
+        Some(0x4fd7),
+        Some(0x7d20),
+        Some(0x901f),
+        Some(0x7c9f),
+        Some(0x50f3),
+        Some(0x5851),
+        Some(0x6eaf),
+        Some(0x5bbf),
+        Some(0x8bc9),
+        Some(0x8083),
+        Some(0x9178),
+        Some(0x849c),
+        Some(0x7b97),
+        Some(0x867d),
+        Some(0x968b),
+        Some(0x968f),
+        Some(0x7ee5),
+        Some(0x9ad3),
+        Some(0x788e),
+        Some(0x5c81),
+        Some(0x7a57),
+        Some(0x9042),
+        Some(0x96a7),
+        Some(0x795f),
+        Some(0x5b59),
+        Some(0x635f),
+        Some(0x7b0b),
+        Some(0x84d1),
+        Some(0x68ad),
+        Some(0x5506),
+        Some(0x7f29),
+        Some(0x7410),
+        Some(0x7d22),
+        Some(0x9501),
+        Some(0x6240),
+        Some(0x584c),
+        Some(0x4ed6),
+        Some(0x5b83),
+        Some(0x5979),
+        Some(0x5854),
+    ];
+
+    const BASE_0XCCA0: [Option<u16>; 95] = [
+        None,
+        Some(0x736d),
+        Some(0x631e),
+        Some(0x8e4b),
+        Some(0x8e0f),
+        Some(0x80ce),
+        Some(0x82d4),
+        Some(0x62ac),
+        Some(0x53f0),
+        Some(0x6cf0),
+        Some(0x915e),
+        Some(0x592a),
+        Some(0x6001),
+        Some(0x6c70),
+        Some(0x574d),
+        Some(0x644a),
+        Some(0x8d2a),
+        Some(0x762b),
+        Some(0x6ee9),
+        Some(0x575b),
+        Some(0x6a80),
+        Some(0x75f0),
+        Some(0x6f6d),
+        Some(0x8c2d),
+        Some(0x8c08),
+        Some(0x5766),
+        Some(0x6bef),
+        Some(0x8892),
+        Some(0x78b3),
+        Some(0x63a2),
+        Some(0x53f9),
+        Some(0x70ad),
+        Some(0x6c64),
+        Some(0x5858),
+        Some(0x642a),
+        Some(0x5802),
+        Some(0x68e0),
+        Some(0x819b),
+        Some(0x5510),
+        Some(0x7cd6),
+        Some(0x5018),
+        Some(0x8eba),
+        Some(0x6dcc),
+        Some(0x8d9f),
+        Some(0x70eb),
+        Some(0x638f),
+        Some(0x6d9b),
+        Some(0x6ed4),
+        Some(0x7ee6),
+        Some(0x8404),
+        Some(0x6843),
+        Some(0x9003),
+        Some(0x6dd8),
+        Some(0x9676),
+        Some(0x8ba8),
+        Some(0x5957),
+        Some(0x7279),
+        Some(0x85e4),
+        Some(0x817e),
+        Some(0x75bc),
+        Some(0x8a8a),
+        Some(0x68af),
+        Some(0x5254),
+        Some(0x8e22),
+        Some(0x9511),
+        Some(0x63d0),
+        Some(0x9898),
+        Some(0x8e44),
+        Some(0x557c),
+        Some(0x4f53),
+        Some(0x66ff),
+        Some(0x568f),
+        Some(0x60d5),
+        Some(0x6d95),
+        Some(0x5243),
+        Some(0x5c49),
+        Some(0x5929),
+        Some(0x6dfb),
+        Some(0x586b),
+        Some(0x7530),
+        Some(0x751c),
+        Some(0x606c),
+        Some(0x8214),
+        Some(0x8146),
+        Some(0x6311),
+        Some(0x6761),
+        Some(0x8fe2),
+        Some(0x773a),
+        Some(0x8df3),
+        Some(0x8d34),
+        Some(0x94c1),
+        Some(0x5e16),
+        Some(0x5385),
+        Some(0x542c),
+        Some(0x70c3),
+    ];
+
+    const BASE_0XCDA0: [Option<u16>; 95] = [
+        None,
+        Some(0x6c40),
+        Some(0x5ef7),
+        Some(0x505c),
+        Some(0x4ead),
+        Some(0x5ead),
+        Some(0x633a),
+        Some(0x8247),
+        Some(0x901a),
+        Some(0x6850),
+        Some(0x916e),
+        Some(0x77b3),
+        Some(0x540c),
+        Some(0x94dc),
+        Some(0x5f64),
+        Some(0x7ae5),
+        Some(0x6876),
+        Some(0x6345),
+        Some(0x7b52),
+        Some(0x7edf),
+        Some(0x75db),
+        Some(0x5077),
+        Some(0x6295),
+        Some(0x5934),
+        Some(0x900f),
+        Some(0x51f8),
+        Some(0x79c3),
+        Some(0x7a81),
+        Some(0x56fe),
+        Some(0x5f92),
+        Some(0x9014),
+        Some(0x6d82),
+        Some(0x5c60),
+        Some(0x571f),
+        Some(0x5410),
+        Some(0x5154),
+        Some(0x6e4d),
+        Some(0x56e2),
+        Some(0x63a8),
+        Some(0x9893),
+        Some(0x817f),
+        Some(0x8715),
+        Some(0x892a),
+        Some(0x9000),
+        Some(0x541e),
+        Some(0x5c6f),
+        Some(0x81c0),
+        Some(0x62d6),
+        Some(0x6258),
+        Some(0x8131),
+        Some(0x9e35),
+        Some(0x9640),
+        Some(0x9a6e),
+        Some(0x9a7c),
+        Some(0x692d),
+        Some(0x59a5),
+        Some(0x62d3),
+        Some(0x553e),
+        Some(0x6316),
+        Some(0x54c7),
+        Some(0x86d9),
+        Some(0x6d3c),
+        Some(0x5a03),
+        Some(0x74e6),
+        Some(0x889c),
+        Some(0x6b6a),
+        Some(0x5916),
+        Some(0x8c4c),
+        Some(0x5f2f),
+        Some(0x6e7e),
+        Some(0x73a9),
+        Some(0x987d),
+        Some(0x4e38),
+        Some(0x70f7),
+        Some(0x5b8c),
+        Some(0x7897),
+        Some(0x633d),
+        Some(0x665a),
+        Some(0x7696),
+        Some(0x60cb),
+        Some(0x5b9b),
+        Some(0x5a49),
+        Some(0x4e07),
+        Some(0x8155),
+        Some(0x6c6a),
+        Some(0x738b),
+        Some(0x4ea1),
+        Some(0x6789),
+        Some(0x7f51),
+        Some(0x5f80),
+        Some(0x65fa),
+        Some(0x671b),
+        Some(0x5fd8),
+        Some(0x5984),
+        Some(0x5a01),
+    ];
+
+    const BASE_0XCEA0: [Option<u16>; 95] = [
+        None,
+        Some(0x5dcd),
+        Some(0x5fae),
+        Some(0x5371),
+        Some(0x97e6),
+        Some(0x8fdd),
+        Some(0x6845),
+        Some(0x56f4),
+        Some(0x552f),
+        Some(0x60df),
+        Some(0x4e3a),
+        Some(0x6f4d),
+        Some(0x7ef4),
+        Some(0x82c7),
+        Some(0x840e),
+        Some(0x59d4),
+        Some(0x4f1f),
+        Some(0x4f2a),
+        Some(0x5c3e),
+        Some(0x7eac),
+        Some(0x672a),
+        Some(0x851a),
+        Some(0x5473),
+        Some(0x754f),
+        Some(0x80c3),
+        Some(0x5582),
+        Some(0x9b4f),
+        Some(0x4f4d),
+        Some(0x6e2d),
+        Some(0x8c13),
+        Some(0x5c09),
+        Some(0x6170),
+        Some(0x536b),
+        Some(0x761f),
+        Some(0x6e29),
+        Some(0x868a),
+        Some(0x6587),
+        Some(0x95fb),
+        Some(0x7eb9),
+        Some(0x543b),
+        Some(0x7a33),
+        Some(0x7d0a),
+        Some(0x95ee),
+        Some(0x55e1),
+        Some(0x7fc1),
+        Some(0x74ee),
+        Some(0x631d),
+        Some(0x8717),
+        Some(0x6da1),
+        Some(0x7a9d),
+        Some(0x6211),
+        Some(0x65a1),
+        Some(0x5367),
+        Some(0x63e1),
+        Some(0x6c83),
+        Some(0x5deb),
+        Some(0x545c),
+        Some(0x94a8),
+        Some(0x4e4c),
+        Some(0x6c61),
+        Some(0x8bec),
+        Some(0x5c4b),
+        Some(0x65e0),
+        Some(0x829c),
+        Some(0x68a7),
+        Some(0x543e),
+        Some(0x5434),
+        Some(0x6bcb),
+        Some(0x6b66),
+        Some(0x4e94),
+        Some(0x6342),
+        Some(0x5348),
+        Some(0x821e),
+        Some(0x4f0d),
+        Some(0x4fae),
+        Some(0x575e),
+        Some(0x620a),
+        Some(0x96fe),
+        Some(0x6664),
+        Some(0x7269),
+        Some(0x52ff),
+        Some(0x52a1),
+        Some(0x609f),
+        Some(0x8bef),
+        Some(0x6614),
+        Some(0x7199),
+        Some(0x6790),
+        Some(0x897f),
+        Some(0x7852),
+        Some(0x77fd),
+        Some(0x6670),
+        Some(0x563b),
+        Some(0x5438),
+        Some(0x9521),
+        Some(0x727a),
+    ];
+
+    const BASE_0XCFA0: [Option<u16>; 95] = [
+        None,
+        Some(0x7a00),
+        Some(0x606f),
+        Some(0x5e0c),
+        Some(0x6089),
+        Some(0x819d),
+        Some(0x5915),
+        Some(0x60dc),
+        Some(0x7184),
+        Some(0x70ef),
+        Some(0x6eaa),
+        Some(0x6c50),
+        Some(0x7280),
+        Some(0x6a84),
+        Some(0x88ad),
+        Some(0x5e2d),
+        Some(0x4e60),
+        Some(0x5ab3),
+        Some(0x559c),
+        Some(0x94e3),
+        Some(0x6d17),
+        Some(0x7cfb),
+        Some(0x9699),
+        Some(0x620f),
+        Some(0x7ec6),
+        Some(0x778e),
+        Some(0x867e),
+        Some(0x5323),
+        Some(0x971e),
+        Some(0x8f96),
+        Some(0x6687),
+        Some(0x5ce1),
+        Some(0x4fa0),
+        Some(0x72ed),
+        Some(0x4e0b),
+        Some(0x53a6),
+        Some(0x590f),
+        Some(0x5413),
+        Some(0x6380),
+        Some(0x9528),
+        Some(0x5148),
+        Some(0x4ed9),
+        Some(0x9c9c),
+        Some(0x7ea4),
+        Some(0x54b8),
+        Some(0x8d24),
+        Some(0x8854),
+        Some(0x8237),
+        Some(0x95f2),
+        Some(0x6d8e),
+        Some(0x5f26),
+        Some(0x5acc),
+        Some(0x663e),
+        Some(0x9669),
+        Some(0x73b0),
+        Some(0x732e),
+        Some(0x53bf),
+        Some(0x817a),
+        Some(0x9985),
+        Some(0x7fa1),
+        Some(0x5baa),
+        Some(0x9677),
+        Some(0x9650),
+        Some(0x7ebf),
+        Some(0x76f8),
+        Some(0x53a2),
+        Some(0x9576),
+        Some(0x9999),
+        Some(0x7bb1),
+        Some(0x8944),
+        Some(0x6e58),
+        Some(0x4e61),
+        Some(0x7fd4),
+        Some(0x7965),
+        Some(0x8be6),
+        Some(0x60f3),
+        Some(0x54cd),
+        Some(0x4eab),
+        Some(0x9879),
+        Some(0x5df7),
+        Some(0x6a61),
+        Some(0x50cf),
+        Some(0x5411),
+        Some(0x8c61),
+        Some(0x8427),
+        Some(0x785d),
+        Some(0x9704),
+        Some(0x524a),
+        Some(0x54ee),
+        Some(0x56a3),
+        Some(0x9500),
+        Some(0x6d88),
+        Some(0x5bb5),
+        Some(0x6dc6),
+        Some(0x6653),
+    ];
+
+    const BASE_0XD0A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5c0f),
+        Some(0x5b5d),
+        Some(0x6821),
+        Some(0x8096),
+        Some(0x5578),
+        Some(0x7b11),
+        Some(0x6548),
+        Some(0x6954),
+        Some(0x4e9b),
+        Some(0x6b47),
+        Some(0x874e),
+        Some(0x978b),
+        Some(0x534f),
+        Some(0x631f),
+        Some(0x643a),
+        Some(0x90aa),
+        Some(0x659c),
+        Some(0x80c1),
+        Some(0x8c10),
+        Some(0x5199),
+        Some(0x68b0),
+        Some(0x5378),
+        Some(0x87f9),
+        Some(0x61c8),
+        Some(0x6cc4),
+        Some(0x6cfb),
+        Some(0x8c22),
+        Some(0x5c51),
+        Some(0x85aa),
+        Some(0x82af),
+        Some(0x950c),
+        Some(0x6b23),
+        Some(0x8f9b),
+        Some(0x65b0),
+        Some(0x5ffb),
+        Some(0x5fc3),
+        Some(0x4fe1),
+        Some(0x8845),
+        Some(0x661f),
+        Some(0x8165),
+        Some(0x7329),
+        Some(0x60fa),
+        Some(0x5174),
+        Some(0x5211),
+        Some(0x578b),
+        Some(0x5f62),
+        Some(0x90a2),
+        Some(0x884c),
+        Some(0x9192),
+        Some(0x5e78),
+        Some(0x674f),
+        Some(0x6027),
+        Some(0x59d3),
+        Some(0x5144),
+        Some(0x51f6),
+        Some(0x80f8),
+        Some(0x5308),
+        Some(0x6c79),
+        Some(0x96c4),
+        Some(0x718a),
+        Some(0x4f11),
+        Some(0x4fee),
+        Some(0x7f9e),
+        Some(0x673d),
+        Some(0x55c5),
+        Some(0x9508),
+        Some(0x79c0),
+        Some(0x8896),
+        Some(0x7ee3),
+        Some(0x589f),
+        Some(0x620c),
+        Some(0x9700),
+        Some(0x865a),
+        Some(0x5618),
+        Some(0x987b),
+        Some(0x5f90),
+        Some(0x8bb8),
+        Some(0x84c4),
+        Some(0x9157),
+        Some(0x53d9),
+        Some(0x65ed),
+        Some(0x5e8f),
+        Some(0x755c),
+        Some(0x6064),
+        Some(0x7d6e),
+        Some(0x5a7f),
+        Some(0x7eea),
+        Some(0x7eed),
+        Some(0x8f69),
+        Some(0x55a7),
+        Some(0x5ba3),
+        Some(0x60ac),
+        Some(0x65cb),
+        Some(0x7384),
+    ];
+
+    const BASE_0XD1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9009),
+        Some(0x7663),
+        Some(0x7729),
+        Some(0x7eda),
+        Some(0x9774),
+        Some(0x859b),
+        Some(0x5b66),
+        Some(0x7a74),
+        Some(0x96ea),
+        Some(0x8840),
+        Some(0x52cb),
+        Some(0x718f),
+        Some(0x5faa),
+        Some(0x65ec),
+        Some(0x8be2),
+        Some(0x5bfb),
+        Some(0x9a6f),
+        Some(0x5de1),
+        Some(0x6b89),
+        Some(0x6c5b),
+        Some(0x8bad),
+        Some(0x8baf),
+        Some(0x900a),
+        Some(0x8fc5),
+        Some(0x538b),
+        Some(0x62bc),
+        Some(0x9e26),
+        Some(0x9e2d),
+        Some(0x5440),
+        Some(0x4e2b),
+        Some(0x82bd),
+        Some(0x7259),
+        Some(0x869c),
+        Some(0x5d16),
+        Some(0x8859),
+        Some(0x6daf),
+        Some(0x96c5),
+        Some(0x54d1),
+        Some(0x4e9a),
+        Some(0x8bb6),
+        Some(0x7109),
+        Some(0x54bd),
+        Some(0x9609),
+        Some(0x70df),
+        Some(0x6df9),
+        Some(0x76d0),
+        Some(0x4e25),
+        Some(0x7814),
+        Some(0x8712),
+        Some(0x5ca9),
+        Some(0x5ef6),
+        Some(0x8a00),
+        Some(0x989c),
+        Some(0x960e),
+        Some(0x708e),
+        Some(0x6cbf),
+        Some(0x5944),
+        Some(0x63a9),
+        Some(0x773c),
+        Some(0x884d),
+        Some(0x6f14),
+        Some(0x8273),
+        Some(0x5830),
+        Some(0x71d5),
+        Some(0x538c),
+        Some(0x781a),
+        Some(0x96c1),
+        Some(0x5501),
+        Some(0x5f66),
+        Some(0x7130),
+        Some(0x5bb4),
+        Some(0x8c1a),
+        Some(0x9a8c),
+        Some(0x6b83),
+        Some(0x592e),
+        Some(0x9e2f),
+        Some(0x79e7),
+        Some(0x6768),
+        Some(0x626c),
+        Some(0x4f6f),
+        Some(0x75a1),
+        Some(0x7f8a),
+        Some(0x6d0b),
+        Some(0x9633),
+        Some(0x6c27),
+        Some(0x4ef0),
+        Some(0x75d2),
+        Some(0x517b),
+        Some(0x6837),
+        Some(0x6f3e),
+        Some(0x9080),
+        Some(0x8170),
+        Some(0x5996),
+        Some(0x7476),
+    ];
+
+    const BASE_0XD2A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6447),
+        Some(0x5c27),
+        Some(0x9065),
+        Some(0x7a91),
+        Some(0x8c23),
+        Some(0x59da),
+        Some(0x54ac),
+        Some(0x8200),
+        Some(0x836f),
+        Some(0x8981),
+        Some(0x8000),
+        Some(0x6930),
+        Some(0x564e),
+        Some(0x8036),
+        Some(0x7237),
+        Some(0x91ce),
+        Some(0x51b6),
+        Some(0x4e5f),
+        Some(0x9875),
+        Some(0x6396),
+        Some(0x4e1a),
+        Some(0x53f6),
+        Some(0x66f3),
+        Some(0x814b),
+        Some(0x591c),
+        Some(0x6db2),
+        Some(0x4e00),
+        Some(0x58f9),
+        Some(0x533b),
+        Some(0x63d6),
+        Some(0x94f1),
+        Some(0x4f9d),
+        Some(0x4f0a),
+        Some(0x8863),
+        Some(0x9890),
+        Some(0x5937),
+        Some(0x9057),
+        Some(0x79fb),
+        Some(0x4eea),
+        Some(0x80f0),
+        Some(0x7591),
+        Some(0x6c82),
+        Some(0x5b9c),
+        Some(0x59e8),
+        Some(0x5f5d),
+        Some(0x6905),
+        Some(0x8681),
+        Some(0x501a),
+        Some(0x5df2),
+        Some(0x4e59),
+        Some(0x77e3),
+        Some(0x4ee5),
+        Some(0x827a),
+        Some(0x6291),
+        Some(0x6613),
+        Some(0x9091),
+        Some(0x5c79),
+        Some(0x4ebf),
+        Some(0x5f79),
+        Some(0x81c6),
+        Some(0x9038),
+        Some(0x8084),
+        Some(0x75ab),
+        Some(0x4ea6),
+        Some(0x88d4),
+        Some(0x610f),
+        Some(0x6bc5),
+        Some(0x5fc6),
+        Some(0x4e49),
+        Some(0x76ca),
+        Some(0x6ea2),
+        Some(0x8be3),
+        Some(0x8bae),
+        Some(0x8c0a),
+        Some(0x8bd1),
+        Some(0x5f02),
+        Some(0x7ffc),
+        Some(0x7fcc),
+        Some(0x7ece),
+        Some(0x8335),
+        Some(0x836b),
+        Some(0x56e0),
+        Some(0x6bb7),
+        Some(0x97f3),
+        Some(0x9634),
+        Some(0x59fb),
+        Some(0x541f),
+        Some(0x94f6),
+        Some(0x6deb),
+        Some(0x5bc5),
+        Some(0x996e),
+        Some(0x5c39),
+        Some(0x5f15),
+        Some(0x9690),
+    ];
+
+    const BASE_0XD3A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5370),
+        Some(0x82f1),
+        Some(0x6a31),
+        Some(0x5a74),
+        Some(0x9e70),
+        Some(0x5e94),
+        Some(0x7f28),
+        Some(0x83b9),
+        Some(0x8424),
+        Some(0x8425),
+        Some(0x8367),
+        Some(0x8747),
+        Some(0x8fce),
+        Some(0x8d62),
+        Some(0x76c8),
+        Some(0x5f71),
+        Some(0x9896),
+        Some(0x786c),
+        Some(0x6620),
+        Some(0x54df),
+        Some(0x62e5),
+        Some(0x4f63),
+        Some(0x81c3),
+        Some(0x75c8),
+        Some(0x5eb8),
+        Some(0x96cd),
+        Some(0x8e0a),
+        Some(0x86f9),
+        Some(0x548f),
+        Some(0x6cf3),
+        Some(0x6d8c),
+        Some(0x6c38),
+        Some(0x607f),
+        Some(0x52c7),
+        Some(0x7528),
+        Some(0x5e7d),
+        Some(0x4f18),
+        Some(0x60a0),
+        Some(0x5fe7),
+        Some(0x5c24),
+        Some(0x7531),
+        Some(0x90ae),
+        Some(0x94c0),
+        Some(0x72b9),
+        Some(0x6cb9),
+        Some(0x6e38),
+        Some(0x9149),
+        Some(0x6709),
+        Some(0x53cb),
+        Some(0x53f3),
+        Some(0x4f51),
+        Some(0x91c9),
+        Some(0x8bf1),
+        Some(0x53c8),
+        Some(0x5e7c),
+        Some(0x8fc2),
+        Some(0x6de4),
+        Some(0x4e8e),
+        Some(0x76c2),
+        Some(0x6986),
+        Some(0x865e),
+        Some(0x611a),
+        Some(0x8206),
+        Some(0x4f59),
+        Some(0x4fde),
+        Some(0x903e),
+        Some(0x9c7c),
+        Some(0x6109),
+        Some(0x6e1d),
+        Some(0x6e14),
+        Some(0x9685),
+        Some(0x4e88),
+        Some(0x5a31),
+        Some(0x96e8),
+        Some(0x4e0e),
+        Some(0x5c7f),
+        Some(0x79b9),
+        Some(0x5b87),
+        Some(0x8bed),
+        Some(0x7fbd),
+        Some(0x7389),
+        Some(0x57df),
+        Some(0x828b),
+        Some(0x90c1),
+        Some(0x5401),
+        Some(0x9047),
+        Some(0x55bb),
+        Some(0x5cea),
+        Some(0x5fa1),
+        Some(0x6108),
+        Some(0x6b32),
+        Some(0x72f1),
+        Some(0x80b2),
+        Some(0x8a89),
+    ];
+
+    const BASE_0XD4A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6d74),
+        Some(0x5bd3),
+        Some(0x88d5),
+        Some(0x9884),
+        Some(0x8c6b),
+        Some(0x9a6d),
+        Some(0x9e33),
+        Some(0x6e0a),
+        Some(0x51a4),
+        Some(0x5143),
+        Some(0x57a3),
+        Some(0x8881),
+        Some(0x539f),
+        Some(0x63f4),
+        Some(0x8f95),
+        Some(0x56ed),
+        Some(0x5458),
+        Some(0x5706),
+        Some(0x733f),
+        Some(0x6e90),
+        Some(0x7f18),
+        Some(0x8fdc),
+        Some(0x82d1),
+        Some(0x613f),
+        Some(0x6028),
+        Some(0x9662),
+        Some(0x66f0),
+        Some(0x7ea6),
+        Some(0x8d8a),
+        Some(0x8dc3),
+        Some(0x94a5),
+        Some(0x5cb3),
+        Some(0x7ca4),
+        Some(0x6708),
+        Some(0x60a6),
+        Some(0x9605),
+        Some(0x8018),
+        Some(0x4e91),
+        Some(0x90e7),
+        Some(0x5300),
+        Some(0x9668),
+        Some(0x5141),
+        Some(0x8fd0),
+        Some(0x8574),
+        Some(0x915d),
+        Some(0x6655),
+        Some(0x97f5),
+        Some(0x5b55),
+        Some(0x531d),
+        Some(0x7838),
+        Some(0x6742),
+        Some(0x683d),
+        Some(0x54c9),
+        Some(0x707e),
+        Some(0x5bb0),
+        Some(0x8f7d),
+        Some(0x518d),
+        Some(0x5728),
+        Some(0x54b1),
+        Some(0x6512),
+        Some(0x6682),
+        Some(0x8d5e),
+        Some(0x8d43),
+        Some(0x810f),
+        Some(0x846c),
+        Some(0x906d),
+        Some(0x7cdf),
+        Some(0x51ff),
+        Some(0x85fb),
+        Some(0x67a3),
+        Some(0x65e9),
+        Some(0x6fa1),
+        Some(0x86a4),
+        Some(0x8e81),
+        Some(0x566a),
+        Some(0x9020),
+        Some(0x7682),
+        Some(0x7076),
+        Some(0x71e5),
+        Some(0x8d23),
+        Some(0x62e9),
+        Some(0x5219),
+        Some(0x6cfd),
+        Some(0x8d3c),
+        Some(0x600e),
+        Some(0x589e),
+        Some(0x618e),
+        Some(0x66fe),
+        Some(0x8d60),
+        Some(0x624e),
+        Some(0x55b3),
+        Some(0x6e23),
+        Some(0x672d),
+        Some(0x8f67),
+    ];
+
+    const BASE_0XD5A0: [Option<u16>; 95] = [
+        None,
+        Some(0x94e1),
+        Some(0x95f8),
+        Some(0x7728),
+        Some(0x6805),
+        Some(0x69a8),
+        Some(0x548b),
+        Some(0x4e4d),
+        Some(0x70b8),
+        Some(0x8bc8),
+        Some(0x6458),
+        Some(0x658b),
+        Some(0x5b85),
+        Some(0x7a84),
+        Some(0x503a),
+        Some(0x5be8),
+        Some(0x77bb),
+        Some(0x6be1),
+        Some(0x8a79),
+        Some(0x7c98),
+        Some(0x6cbe),
+        Some(0x76cf),
+        Some(0x65a9),
+        Some(0x8f97),
+        Some(0x5d2d),
+        Some(0x5c55),
+        Some(0x8638),
+        Some(0x6808),
+        Some(0x5360),
+        Some(0x6218),
+        Some(0x7ad9),
+        Some(0x6e5b),
+        Some(0x7efd),
+        Some(0x6a1f),
+        Some(0x7ae0),
+        Some(0x5f70),
+        Some(0x6f33),
+        Some(0x5f20),
+        Some(0x638c),
+        Some(0x6da8),
+        Some(0x6756),
+        Some(0x4e08),
+        Some(0x5e10),
+        Some(0x8d26),
+        Some(0x4ed7),
+        Some(0x80c0),
+        Some(0x7634),
+        Some(0x969c),
+        Some(0x62db),
+        Some(0x662d),
+        Some(0x627e),
+        Some(0x6cbc),
+        Some(0x8d75),
+        Some(0x7167),
+        Some(0x7f69),
+        Some(0x5146),
+        Some(0x8087),
+        Some(0x53ec),
+        Some(0x906e),
+        Some(0x6298),
+        Some(0x54f2),
+        Some(0x86f0),
+        Some(0x8f99),
+        Some(0x8005),
+        Some(0x9517),
+        Some(0x8517),
+        Some(0x8fd9),
+        Some(0x6d59),
+        Some(0x73cd),
+        Some(0x659f),
+        Some(0x771f),
+        Some(0x7504),
+        Some(0x7827),
+        Some(0x81fb),
+        Some(0x8d1e),
+        Some(0x9488),
+        Some(0x4fa6),
+        Some(0x6795),
+        Some(0x75b9),
+        Some(0x8bca),
+        Some(0x9707),
+        Some(0x632f),
+        Some(0x9547),
+        Some(0x9635),
+        Some(0x84b8),
+        Some(0x6323),
+        Some(0x7741),
+        Some(0x5f81),
+        Some(0x72f0),
+        Some(0x4e89),
+        Some(0x6014),
+        Some(0x6574),
+        Some(0x62ef),
+        Some(0x6b63),
+        Some(0x653f),
+    ];
+
+    const BASE_0XD6A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5e27),
+        Some(0x75c7),
+        Some(0x90d1),
+        Some(0x8bc1),
+        Some(0x829d),
+        Some(0x679d),
+        Some(0x652f),
+        Some(0x5431),
+        Some(0x8718),
+        Some(0x77e5),
+        Some(0x80a2),
+        Some(0x8102),
+        Some(0x6c41),
+        Some(0x4e4b),
+        Some(0x7ec7),
+        Some(0x804c),
+        Some(0x76f4),
+        Some(0x690d),
+        Some(0x6b96),
+        Some(0x6267),
+        Some(0x503c),
+        Some(0x4f84),
+        Some(0x5740),
+        Some(0x6307),
+        Some(0x6b62),
+        Some(0x8dbe),
+        Some(0x53ea),
+        Some(0x65e8),
+        Some(0x7eb8),
+        Some(0x5fd7),
+        Some(0x631a),
+        Some(0x63b7),
+        Some(0x81f3),
+        Some(0x81f4),
+        Some(0x7f6e),
+        Some(0x5e1c),
+        Some(0x5cd9),
+        Some(0x5236),
+        Some(0x667a),
+        Some(0x79e9),
+        Some(0x7a1a),
+        Some(0x8d28),
+        Some(0x7099),
+        Some(0x75d4),
+        Some(0x6ede),
+        Some(0x6cbb),
+        Some(0x7a92),
+        Some(0x4e2d),
+        Some(0x76c5),
+        Some(0x5fe0),
+        Some(0x949f),
+        Some(0x8877),
+        Some(0x7ec8),
+        Some(0x79cd),
+        Some(0x80bf),
+        Some(0x91cd),
+        Some(0x4ef2),
+        Some(0x4f17),
+        Some(0x821f),
+        Some(0x5468),
+        Some(0x5dde),
+        Some(0x6d32),
+        Some(0x8bcc),
+        Some(0x7ca5),
+        Some(0x8f74),
+        Some(0x8098),
+        Some(0x5e1a),
+        Some(0x5492),
+        Some(0x76b1),
+        Some(0x5b99),
+        Some(0x663c),
+        Some(0x9aa4),
+        Some(0x73e0),
+        Some(0x682a),
+        Some(0x86db),
+        Some(0x6731),
+        Some(0x732a),
+        Some(0x8bf8),
+        Some(0x8bdb),
+        Some(0x9010),
+        Some(0x7af9),
+        Some(0x70db),
+        Some(0x716e),
+        Some(0x62c4),
+        Some(0x77a9),
+        Some(0x5631),
+        Some(0x4e3b),
+        Some(0x8457),
+        Some(0x67f1),
+        Some(0x52a9),
+        Some(0x86c0),
+        Some(0x8d2e),
+        Some(0x94f8),
+        Some(0x7b51),
+    ];
+
+    const BASE_0XD7A0: [Option<u16>; 90] = [
+        None,
+        Some(0x4f4f),
+        Some(0x6ce8),
+        Some(0x795d),
+        Some(0x9a7b),
+        Some(0x6293),
+        Some(0x722a),
+        Some(0x62fd),
+        Some(0x4e13),
+        Some(0x7816),
+        Some(0x8f6c),
+        Some(0x64b0),
+        Some(0x8d5a),
+        Some(0x7bc6),
+        Some(0x6869),
+        Some(0x5e84),
+        Some(0x88c5),
+        Some(0x5986),
+        Some(0x649e),
+        Some(0x58ee),
+        Some(0x72b6),
+        Some(0x690e),
+        Some(0x9525),
+        Some(0x8ffd),
+        Some(0x8d58),
+        Some(0x5760),
+        Some(0x7f00),
+        Some(0x8c06),
+        Some(0x51c6),
+        Some(0x6349),
+        Some(0x62d9),
+        Some(0x5353),
+        Some(0x684c),
+        Some(0x7422),
+        Some(0x8301),
+        Some(0x914c),
+        Some(0x5544),
+        Some(0x7740),
+        Some(0x707c),
+        Some(0x6d4a),
+        Some(0x5179),
+        Some(0x54a8),
+        Some(0x8d44),
+        Some(0x59ff),
+        Some(0x6ecb),
+        Some(0x6dc4),
+        Some(0x5b5c),
+        Some(0x7d2b),
+        Some(0x4ed4),
+        Some(0x7c7d),
+        Some(0x6ed3),
+        Some(0x5b50),
+        Some(0x81ea),
+        Some(0x6e0d),
+        Some(0x5b57),
+        Some(0x9b03),
+        Some(0x68d5),
+        Some(0x8e2a),
+        Some(0x5b97),
+        Some(0x7efc),
+        Some(0x603b),
+        Some(0x7eb5),
+        Some(0x90b9),
+        Some(0x8d70),
+        Some(0x594f),
+        Some(0x63cd),
+        Some(0x79df),
+        Some(0x8db3),
+        Some(0x5352),
+        Some(0x65cf),
+        Some(0x7956),
+        Some(0x8bc5),
+        Some(0x963b),
+        Some(0x7ec4),
+        Some(0x94bb),
+        Some(0x7e82),
+        Some(0x5634),
+        Some(0x9189),
+        Some(0x6700),
+        Some(0x7f6a),
+        Some(0x5c0a),
+        Some(0x9075),
+        Some(0x6628),
+        Some(0x5de6),
+        Some(0x4f50),
+        Some(0x67de),
+        Some(0x505a),
+        Some(0x4f5c),
+        Some(0x5750),
+        Some(0x5ea7),
+    ];
+
+    const BASE_0XD8A0: [Option<u16>; 95] = [
+        None,
+        Some(0x4e8d),
+        Some(0x4e0c),
+        Some(0x5140),
+        Some(0x4e10),
+        Some(0x5eff),
+        Some(0x5345),
+        Some(0x4e15),
+        Some(0x4e98),
+        Some(0x4e1e),
+        Some(0x9b32),
+        Some(0x5b6c),
+        Some(0x5669),
+        Some(0x4e28),
+        Some(0x79ba),
+        Some(0x4e3f),
+        Some(0x5315),
+        Some(0x4e47),
+        Some(0x592d),
+        Some(0x723b),
+        Some(0x536e),
+        Some(0x6c10),
+        Some(0x56df),
+        Some(0x80e4),
+        Some(0x9997),
+        Some(0x6bd3),
+        Some(0x777e),
+        Some(0x9f17),
+        Some(0x4e36),
+        Some(0x4e9f),
+        Some(0x9f10),
+        Some(0x4e5c),
+        Some(0x4e69),
+        Some(0x4e93),
+        Some(0x8288),
+        Some(0x5b5b),
+        Some(0x556c),
+        Some(0x560f),
+        Some(0x4ec4),
+        Some(0x538d),
+        Some(0x539d),
+        Some(0x53a3),
+        Some(0x53a5),
+        Some(0x53ae),
+        Some(0x9765),
+        Some(0x8d5d),
+        Some(0x531a),
+        Some(0x53f5),
+        Some(0x5326),
+        Some(0x532e),
+        Some(0x533e),
+        Some(0x8d5c),
+        Some(0x5366),
+        Some(0x5363),
+        Some(0x5202),
+        Some(0x5208),
+        Some(0x520e),
+        Some(0x522d),
+        Some(0x5233),
+        Some(0x523f),
+        Some(0x5240),
+        Some(0x524c),
+        Some(0x525e),
+        Some(0x5261),
+        Some(0x525c),
+        Some(0x84af),
+        Some(0x527d),
+        Some(0x5282),
+        Some(0x5281),
+        Some(0x5290),
+        Some(0x5293),
+        Some(0x5182),
+        Some(0x7f54),
+        Some(0x4ebb),
+        Some(0x4ec3),
+        Some(0x4ec9),
+        Some(0x4ec2),
+        Some(0x4ee8),
+        Some(0x4ee1),
+        Some(0x4eeb),
+        Some(0x4ede),
+        Some(0x4f1b),
+        Some(0x4ef3),
+        Some(0x4f22),
+        Some(0x4f64),
+        Some(0x4ef5),
+        Some(0x4f25),
+        Some(0x4f27),
+        Some(0x4f09),
+        Some(0x4f2b),
+        Some(0x4f5e),
+        Some(0x4f67),
+        Some(0x6538),
+        Some(0x4f5a),
+        Some(0x4f5d),
+    ];
+
+    const BASE_0XD9A0: [Option<u16>; 95] = [
+        None,
+        Some(0x4f5f),
+        Some(0x4f57),
+        Some(0x4f32),
+        Some(0x4f3d),
+        Some(0x4f76),
+        Some(0x4f74),
+        Some(0x4f91),
+        Some(0x4f89),
+        Some(0x4f83),
+        Some(0x4f8f),
+        Some(0x4f7e),
+        Some(0x4f7b),
+        Some(0x4faa),
+        Some(0x4f7c),
+        Some(0x4fac),
+        Some(0x4f94),
+        Some(0x4fe6),
+        Some(0x4fe8),
+        Some(0x4fea),
+        Some(0x4fc5),
+        Some(0x4fda),
+        Some(0x4fe3),
+        Some(0x4fdc),
+        Some(0x4fd1),
+        Some(0x4fdf),
+        Some(0x4ff8),
+        Some(0x5029),
+        Some(0x504c),
+        Some(0x4ff3),
+        Some(0x502c),
+        Some(0x500f),
+        Some(0x502e),
+        Some(0x502d),
+        Some(0x4ffe),
+        Some(0x501c),
+        Some(0x500c),
+        Some(0x5025),
+        Some(0x5028),
+        Some(0x507e),
+        Some(0x5043),
+        Some(0x5055),
+        Some(0x5048),
+        Some(0x504e),
+        Some(0x506c),
+        Some(0x507b),
+        Some(0x50a5),
+        Some(0x50a7),
+        Some(0x50a9),
+        Some(0x50ba),
+        Some(0x50d6),
+        Some(0x5106),
+        Some(0x50ed),
+        Some(0x50ec),
+        Some(0x50e6),
+        Some(0x50ee),
+        Some(0x5107),
+        Some(0x510b),
+        Some(0x4edd),
+        Some(0x6c3d),
+        Some(0x4f58),
+        Some(0x4f65),
+        Some(0x4fce),
+        Some(0x9fa0),
+        Some(0x6c46),
+        Some(0x7c74),
+        Some(0x516e),
+        Some(0x5dfd),
+        Some(0x9ec9),
+        Some(0x9998),
+        Some(0x5181),
+        Some(0x5914),
+        Some(0x52f9),
+        Some(0x530d),
+        Some(0x8a07),
+        Some(0x5310),
+        Some(0x51eb),
+        Some(0x5919),
+        Some(0x5155),
+        Some(0x4ea0),
+        Some(0x5156),
+        Some(0x4eb3),
+        Some(0x886e),
+        Some(0x88a4),
+        Some(0x4eb5),
+        Some(0x8114),
+        Some(0x88d2),
+        Some(0x7980),
+        Some(0x5b34),
+        Some(0x8803),
+        Some(0x7fb8),
+        Some(0x51ab),
+        Some(0x51b1),
+        Some(0x51bd),
+        Some(0x51bc),
+    ];
+
+    const BASE_0XDAA0: [Option<u16>; 95] = [
+        None,
+        Some(0x51c7),
+        Some(0x5196),
+        Some(0x51a2),
+        Some(0x51a5),
+        Some(0x8ba0),
+        Some(0x8ba6),
+        Some(0x8ba7),
+        Some(0x8baa),
+        Some(0x8bb4),
+        Some(0x8bb5),
+        Some(0x8bb7),
+        Some(0x8bc2),
+        Some(0x8bc3),
+        Some(0x8bcb),
+        Some(0x8bcf),
+        Some(0x8bce),
+        Some(0x8bd2),
+        Some(0x8bd3),
+        Some(0x8bd4),
+        Some(0x8bd6),
+        Some(0x8bd8),
+        Some(0x8bd9),
+        Some(0x8bdc),
+        Some(0x8bdf),
+        Some(0x8be0),
+        Some(0x8be4),
+        Some(0x8be8),
+        Some(0x8be9),
+        Some(0x8bee),
+        Some(0x8bf0),
+        Some(0x8bf3),
+        Some(0x8bf6),
+        Some(0x8bf9),
+        Some(0x8bfc),
+        Some(0x8bff),
+        Some(0x8c00),
+        Some(0x8c02),
+        Some(0x8c04),
+        Some(0x8c07),
+        Some(0x8c0c),
+        Some(0x8c0f),
+        Some(0x8c11),
+        Some(0x8c12),
+        Some(0x8c14),
+        Some(0x8c15),
+        Some(0x8c16),
+        Some(0x8c19),
+        Some(0x8c1b),
+        Some(0x8c18),
+        Some(0x8c1d),
+        Some(0x8c1f),
+        Some(0x8c20),
+        Some(0x8c21),
+        Some(0x8c25),
+        Some(0x8c27),
+        Some(0x8c2a),
+        Some(0x8c2b),
+        Some(0x8c2e),
+        Some(0x8c2f),
+        Some(0x8c32),
+        Some(0x8c33),
+        Some(0x8c35),
+        Some(0x8c36),
+        Some(0x5369),
+        Some(0x537a),
+        Some(0x961d),
+        Some(0x9622),
+        Some(0x9621),
+        Some(0x9631),
+        Some(0x962a),
+        Some(0x963d),
+        Some(0x963c),
+        Some(0x9642),
+        Some(0x9649),
+        Some(0x9654),
+        Some(0x965f),
+        Some(0x9667),
+        Some(0x966c),
+        Some(0x9672),
+        Some(0x9674),
+        Some(0x9688),
+        Some(0x968d),
+        Some(0x9697),
+        Some(0x96b0),
+        Some(0x9097),
+        Some(0x909b),
+        Some(0x909d),
+        Some(0x9099),
+        Some(0x90ac),
+        Some(0x90a1),
+        Some(0x90b4),
+        Some(0x90b3),
+        Some(0x90b6),
+        Some(0x90ba),
+    ];
+
+    const BASE_0XDBA0: [Option<u16>; 95] = [
+        None,
+        Some(0x90b8),
+        Some(0x90b0),
+        Some(0x90cf),
+        Some(0x90c5),
+        Some(0x90be),
+        Some(0x90d0),
+        Some(0x90c4),
+        Some(0x90c7),
+        Some(0x90d3),
+        Some(0x90e6),
+        Some(0x90e2),
+        Some(0x90dc),
+        Some(0x90d7),
+        Some(0x90db),
+        Some(0x90eb),
+        Some(0x90ef),
+        Some(0x90fe),
+        Some(0x9104),
+        Some(0x9122),
+        Some(0x911e),
+        Some(0x9123),
+        Some(0x9131),
+        Some(0x912f),
+        Some(0x9139),
+        Some(0x9143),
+        Some(0x9146),
+        Some(0x520d),
+        Some(0x5942),
+        Some(0x52a2),
+        Some(0x52ac),
+        Some(0x52ad),
+        Some(0x52be),
+        Some(0x54ff),
+        Some(0x52d0),
+        Some(0x52d6),
+        Some(0x52f0),
+        Some(0x53df),
+        Some(0x71ee),
+        Some(0x77cd),
+        Some(0x5ef4),
+        Some(0x51f5),
+        Some(0x51fc),
+        Some(0x9b2f),
+        Some(0x53b6),
+        Some(0x5f01),
+        Some(0x755a),
+        Some(0x5def),
+        Some(0x574c),
+        Some(0x57a9),
+        Some(0x57a1),
+        Some(0x587e),
+        Some(0x58bc),
+        Some(0x58c5),
+        Some(0x58d1),
+        Some(0x5729),
+        Some(0x572c),
+        Some(0x572a),
+        Some(0x5733),
+        Some(0x5739),
+        Some(0x572e),
+        Some(0x572f),
+        Some(0x575c),
+        Some(0x573b),
+        Some(0x5742),
+        Some(0x5769),
+        Some(0x5785),
+        Some(0x576b),
+        Some(0x5786),
+        Some(0x577c),
+        Some(0x577b),
+        Some(0x5768),
+        Some(0x576d),
+        Some(0x5776),
+        Some(0x5773),
+        Some(0x57ad),
+        Some(0x57a4),
+        Some(0x578c),
+        Some(0x57b2),
+        Some(0x57cf),
+        Some(0x57a7),
+        Some(0x57b4),
+        Some(0x5793),
+        Some(0x57a0),
+        Some(0x57d5),
+        Some(0x57d8),
+        Some(0x57da),
+        Some(0x57d9),
+        Some(0x57d2),
+        Some(0x57b8),
+        Some(0x57f4),
+        Some(0x57ef),
+        Some(0x57f8),
+        Some(0x57e4),
+        Some(0x57dd),
+    ];
+
+    const BASE_0XDCA0: [Option<u16>; 95] = [
+        None,
+        Some(0x580b),
+        Some(0x580d),
+        Some(0x57fd),
+        Some(0x57ed),
+        Some(0x5800),
+        Some(0x581e),
+        Some(0x5819),
+        Some(0x5844),
+        Some(0x5820),
+        Some(0x5865),
+        Some(0x586c),
+        Some(0x5881),
+        Some(0x5889),
+        Some(0x589a),
+        Some(0x5880),
+        Some(0x99a8),
+        Some(0x9f19),
+        Some(0x61ff),
+        Some(0x8279),
+        Some(0x827d),
+        Some(0x827f),
+        Some(0x828f),
+        Some(0x828a),
+        Some(0x82a8),
+        Some(0x8284),
+        Some(0x828e),
+        Some(0x8291),
+        Some(0x8297),
+        Some(0x8299),
+        Some(0x82ab),
+        Some(0x82b8),
+        Some(0x82be),
+        Some(0x82b0),
+        Some(0x82c8),
+        Some(0x82ca),
+        Some(0x82e3),
+        Some(0x8298),
+        Some(0x82b7),
+        Some(0x82ae),
+        Some(0x82cb),
+        Some(0x82cc),
+        Some(0x82c1),
+        Some(0x82a9),
+        Some(0x82b4),
+        Some(0x82a1),
+        Some(0x82aa),
+        Some(0x829f),
+        Some(0x82c4),
+        Some(0x82ce),
+        Some(0x82a4),
+        Some(0x82e1),
+        Some(0x8309),
+        Some(0x82f7),
+        Some(0x82e4),
+        Some(0x830f),
+        Some(0x8307),
+        Some(0x82dc),
+        Some(0x82f4),
+        Some(0x82d2),
+        Some(0x82d8),
+        Some(0x830c),
+        Some(0x82fb),
+        Some(0x82d3),
+        Some(0x8311),
+        Some(0x831a),
+        Some(0x8306),
+        Some(0x8314),
+        Some(0x8315),
+        Some(0x82e0),
+        Some(0x82d5),
+        Some(0x831c),
+        Some(0x8351),
+        Some(0x835b),
+        Some(0x835c),
+        Some(0x8308),
+        Some(0x8392),
+        Some(0x833c),
+        Some(0x8334),
+        Some(0x8331),
+        Some(0x839b),
+        Some(0x835e),
+        Some(0x832f),
+        Some(0x834f),
+        Some(0x8347),
+        Some(0x8343),
+        Some(0x835f),
+        Some(0x8340),
+        Some(0x8317),
+        Some(0x8360),
+        Some(0x832d),
+        Some(0x833a),
+        Some(0x8333),
+        Some(0x8366),
+        Some(0x8365),
+    ];
+
+    const BASE_0XDDA0: [Option<u16>; 95] = [
+        None,
+        Some(0x8368),
+        Some(0x831b),
+        Some(0x8369),
+        Some(0x836c),
+        Some(0x836a),
+        Some(0x836d),
+        Some(0x836e),
+        Some(0x83b0),
+        Some(0x8378),
+        Some(0x83b3),
+        Some(0x83b4),
+        Some(0x83a0),
+        Some(0x83aa),
+        Some(0x8393),
+        Some(0x839c),
+        Some(0x8385),
+        Some(0x837c),
+        Some(0x83b6),
+        Some(0x83a9),
+        Some(0x837d),
+        Some(0x83b8),
+        Some(0x837b),
+        Some(0x8398),
+        Some(0x839e),
+        Some(0x83a8),
+        Some(0x83ba),
+        Some(0x83bc),
+        Some(0x83c1),
+        Some(0x8401),
+        Some(0x83e5),
+        Some(0x83d8),
+        Some(0x5807),
+        Some(0x8418),
+        Some(0x840b),
+        Some(0x83dd),
+        Some(0x83fd),
+        Some(0x83d6),
+        Some(0x841c),
+        Some(0x8438),
+        Some(0x8411),
+        Some(0x8406),
+        Some(0x83d4),
+        Some(0x83df),
+        Some(0x840f),
+        Some(0x8403),
+        Some(0x83f8),
+        Some(0x83f9),
+        Some(0x83ea),
+        Some(0x83c5),
+        Some(0x83c0),
+        Some(0x8426),
+        Some(0x83f0),
+        Some(0x83e1),
+        Some(0x845c),
+        Some(0x8451),
+        Some(0x845a),
+        Some(0x8459),
+        Some(0x8473),
+        Some(0x8487),
+        Some(0x8488),
+        Some(0x847a),
+        Some(0x8489),
+        Some(0x8478),
+        Some(0x843c),
+        Some(0x8446),
+        Some(0x8469),
+        Some(0x8476),
+        Some(0x848c),
+        Some(0x848e),
+        Some(0x8431),
+        Some(0x846d),
+        Some(0x84c1),
+        Some(0x84cd),
+        Some(0x84d0),
+        Some(0x84e6),
+        Some(0x84bd),
+        Some(0x84d3),
+        Some(0x84ca),
+        Some(0x84bf),
+        Some(0x84ba),
+        Some(0x84e0),
+        Some(0x84a1),
+        Some(0x84b9),
+        Some(0x84b4),
+        Some(0x8497),
+        Some(0x84e5),
+        Some(0x84e3),
+        Some(0x850c),
+        Some(0x750d),
+        Some(0x8538),
+        Some(0x84f0),
+        Some(0x8539),
+        Some(0x851f),
+        Some(0x853a),
+    ];
+
+    const BASE_0XDEA0: [Option<u16>; 95] = [
+        None,
+        Some(0x8556),
+        Some(0x853b),
+        Some(0x84ff),
+        Some(0x84fc),
+        Some(0x8559),
+        Some(0x8548),
+        Some(0x8568),
+        Some(0x8564),
+        Some(0x855e),
+        Some(0x857a),
+        Some(0x77a2),
+        Some(0x8543),
+        Some(0x8572),
+        Some(0x857b),
+        Some(0x85a4),
+        Some(0x85a8),
+        Some(0x8587),
+        Some(0x858f),
+        Some(0x8579),
+        Some(0x85ae),
+        Some(0x859c),
+        Some(0x8585),
+        Some(0x85b9),
+        Some(0x85b7),
+        Some(0x85b0),
+        Some(0x85d3),
+        Some(0x85c1),
+        Some(0x85dc),
+        Some(0x85ff),
+        Some(0x8627),
+        Some(0x8605),
+        Some(0x8629),
+        Some(0x8616),
+        Some(0x863c),
+        Some(0x5efe),
+        Some(0x5f08),
+        Some(0x593c),
+        Some(0x5941),
+        Some(0x8037),
+        Some(0x5955),
+        Some(0x595a),
+        Some(0x5958),
+        Some(0x530f),
+        Some(0x5c22),
+        Some(0x5c25),
+        Some(0x5c2c),
+        Some(0x5c34),
+        Some(0x624c),
+        Some(0x626a),
+        Some(0x629f),
+        Some(0x62bb),
+        Some(0x62ca),
+        Some(0x62da),
+        Some(0x62d7),
+        Some(0x62ee),
+        Some(0x6322),
+        Some(0x62f6),
+        Some(0x6339),
+        Some(0x634b),
+        Some(0x6343),
+        Some(0x63ad),
+        Some(0x63f6),
+        Some(0x6371),
+        Some(0x637a),
+        Some(0x638e),
+        Some(0x63b4),
+        Some(0x636d),
+        Some(0x63ac),
+        Some(0x638a),
+        Some(0x6369),
+        Some(0x63ae),
+        Some(0x63bc),
+        Some(0x63f2),
+        Some(0x63f8),
+        Some(0x63e0),
+        Some(0x63ff),
+        Some(0x63c4),
+        Some(0x63de),
+        Some(0x63ce),
+        Some(0x6452),
+        Some(0x63c6),
+        Some(0x63be),
+        Some(0x6445),
+        Some(0x6441),
+        Some(0x640b),
+        Some(0x641b),
+        Some(0x6420),
+        Some(0x640c),
+        Some(0x6426),
+        Some(0x6421),
+        Some(0x645e),
+        Some(0x6484),
+        Some(0x646d),
+        Some(0x6496),
+    ];
+
+    const BASE_0XDFA0: [Option<u16>; 95] = [
+        None,
+        Some(0x647a),
+        Some(0x64b7),
+        Some(0x64b8),
+        Some(0x6499),
+        Some(0x64ba),
+        Some(0x64c0),
+        Some(0x64d0),
+        Some(0x64d7),
+        Some(0x64e4),
+        Some(0x64e2),
+        Some(0x6509),
+        Some(0x6525),
+        Some(0x652e),
+        Some(0x5f0b),
+        Some(0x5fd2),
+        Some(0x7519),
+        Some(0x5f11),
+        Some(0x535f),
+        Some(0x53f1),
+        Some(0x53fd),
+        Some(0x53e9),
+        Some(0x53e8),
+        Some(0x53fb),
+        Some(0x5412),
+        Some(0x5416),
+        Some(0x5406),
+        Some(0x544b),
+        Some(0x5452),
+        Some(0x5453),
+        Some(0x5454),
+        Some(0x5456),
+        Some(0x5443),
+        Some(0x5421),
+        Some(0x5457),
+        Some(0x5459),
+        Some(0x5423),
+        Some(0x5432),
+        Some(0x5482),
+        Some(0x5494),
+        Some(0x5477),
+        Some(0x5471),
+        Some(0x5464),
+        Some(0x549a),
+        Some(0x549b),
+        Some(0x5484),
+        Some(0x5476),
+        Some(0x5466),
+        Some(0x549d),
+        Some(0x54d0),
+        Some(0x54ad),
+        Some(0x54c2),
+        Some(0x54b4),
+        Some(0x54d2),
+        Some(0x54a7),
+        Some(0x54a6),
+        Some(0x54d3),
+        Some(0x54d4),
+        Some(0x5472),
+        Some(0x54a3),
+        Some(0x54d5),
+        Some(0x54bb),
+        Some(0x54bf),
+        Some(0x54cc),
+        Some(0x54d9),
+        Some(0x54da),
+        Some(0x54dc),
+        Some(0x54a9),
+        Some(0x54aa),
+        Some(0x54a4),
+        Some(0x54dd),
+        Some(0x54cf),
+        Some(0x54de),
+        Some(0x551b),
+        Some(0x54e7),
+        Some(0x5520),
+        Some(0x54fd),
+        Some(0x5514),
+        Some(0x54f3),
+        Some(0x5522),
+        Some(0x5523),
+        Some(0x550f),
+        Some(0x5511),
+        Some(0x5527),
+        Some(0x552a),
+        Some(0x5567),
+        Some(0x558f),
+        Some(0x55b5),
+        Some(0x5549),
+        Some(0x556d),
+        Some(0x5541),
+        Some(0x5555),
+        Some(0x553f),
+        Some(0x5550),
+        Some(0x553c),
+    ];
+
+    const BASE_0XE0A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5537),
+        Some(0x5556),
+        Some(0x5575),
+        Some(0x5576),
+        Some(0x5577),
+        Some(0x5533),
+        Some(0x5530),
+        Some(0x555c),
+        Some(0x558b),
+        Some(0x55d2),
+        Some(0x5583),
+        Some(0x55b1),
+        Some(0x55b9),
+        Some(0x5588),
+        Some(0x5581),
+        Some(0x559f),
+        Some(0x557e),
+        Some(0x55d6),
+        Some(0x5591),
+        Some(0x557b),
+        Some(0x55df),
+        Some(0x55bd),
+        Some(0x55be),
+        Some(0x5594),
+        Some(0x5599),
+        Some(0x55ea),
+        Some(0x55f7),
+        Some(0x55c9),
+        Some(0x561f),
+        Some(0x55d1),
+        Some(0x55eb),
+        Some(0x55ec),
+        Some(0x55d4),
+        Some(0x55e6),
+        Some(0x55dd),
+        Some(0x55c4),
+        Some(0x55ef),
+        Some(0x55e5),
+        Some(0x55f2),
+        Some(0x55f3),
+        Some(0x55cc),
+        Some(0x55cd),
+        Some(0x55e8),
+        Some(0x55f5),
+        Some(0x55e4),
+        Some(0x8f94),
+        Some(0x561e),
+        Some(0x5608),
+        Some(0x560c),
+        Some(0x5601),
+        Some(0x5624),
+        Some(0x5623),
+        Some(0x55fe),
+        Some(0x5600),
+        Some(0x5627),
+        Some(0x562d),
+        Some(0x5658),
+        Some(0x5639),
+        Some(0x5657),
+        Some(0x562c),
+        Some(0x564d),
+        Some(0x5662),
+        Some(0x5659),
+        Some(0x565c),
+        Some(0x564c),
+        Some(0x5654),
+        Some(0x5686),
+        Some(0x5664),
+        Some(0x5671),
+        Some(0x566b),
+        Some(0x567b),
+        Some(0x567c),
+        Some(0x5685),
+        Some(0x5693),
+        Some(0x56af),
+        Some(0x56d4),
+        Some(0x56d7),
+        Some(0x56dd),
+        Some(0x56e1),
+        Some(0x56f5),
+        Some(0x56eb),
+        Some(0x56f9),
+        Some(0x56ff),
+        Some(0x5704),
+        Some(0x570a),
+        Some(0x5709),
+        Some(0x571c),
+        Some(0x5e0f),
+        Some(0x5e19),
+        Some(0x5e14),
+        Some(0x5e11),
+        Some(0x5e31),
+        Some(0x5e3b),
+        Some(0x5e3c),
+    ];
+
+    const BASE_0XE1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5e37),
+        Some(0x5e44),
+        Some(0x5e54),
+        Some(0x5e5b),
+        Some(0x5e5e),
+        Some(0x5e61),
+        Some(0x5c8c),
+        Some(0x5c7a),
+        Some(0x5c8d),
+        Some(0x5c90),
+        Some(0x5c96),
+        Some(0x5c88),
+        Some(0x5c98),
+        Some(0x5c99),
+        Some(0x5c91),
+        Some(0x5c9a),
+        Some(0x5c9c),
+        Some(0x5cb5),
+        Some(0x5ca2),
+        Some(0x5cbd),
+        Some(0x5cac),
+        Some(0x5cab),
+        Some(0x5cb1),
+        Some(0x5ca3),
+        Some(0x5cc1),
+        Some(0x5cb7),
+        Some(0x5cc4),
+        Some(0x5cd2),
+        Some(0x5ce4),
+        Some(0x5ccb),
+        Some(0x5ce5),
+        Some(0x5d02),
+        Some(0x5d03),
+        Some(0x5d27),
+        Some(0x5d26),
+        Some(0x5d2e),
+        Some(0x5d24),
+        Some(0x5d1e),
+        Some(0x5d06),
+        Some(0x5d1b),
+        Some(0x5d58),
+        Some(0x5d3e),
+        Some(0x5d34),
+        Some(0x5d3d),
+        Some(0x5d6c),
+        Some(0x5d5b),
+        Some(0x5d6f),
+        Some(0x5d5d),
+        Some(0x5d6b),
+        Some(0x5d4b),
+        Some(0x5d4a),
+        Some(0x5d69),
+        Some(0x5d74),
+        Some(0x5d82),
+        Some(0x5d99),
+        Some(0x5d9d),
+        Some(0x8c73),
+        Some(0x5db7),
+        Some(0x5dc5),
+        Some(0x5f73),
+        Some(0x5f77),
+        Some(0x5f82),
+        Some(0x5f87),
+        Some(0x5f89),
+        Some(0x5f8c),
+        Some(0x5f95),
+        Some(0x5f99),
+        Some(0x5f9c),
+        Some(0x5fa8),
+        Some(0x5fad),
+        Some(0x5fb5),
+        Some(0x5fbc),
+        Some(0x8862),
+        Some(0x5f61),
+        Some(0x72ad),
+        Some(0x72b0),
+        Some(0x72b4),
+        Some(0x72b7),
+        Some(0x72b8),
+        Some(0x72c3),
+        Some(0x72c1),
+        Some(0x72ce),
+        Some(0x72cd),
+        Some(0x72d2),
+        Some(0x72e8),
+        Some(0x72ef),
+        Some(0x72e9),
+        Some(0x72f2),
+        Some(0x72f4),
+        Some(0x72f7),
+        Some(0x7301),
+        Some(0x72f3),
+        Some(0x7303),
+        Some(0x72fa),
+    ];
+
+    const BASE_0XE2A0: [Option<u16>; 95] = [
+        None,
+        Some(0x72fb),
+        Some(0x7317),
+        Some(0x7313),
+        Some(0x7321),
+        Some(0x730a),
+        Some(0x731e),
+        Some(0x731d),
+        Some(0x7315),
+        Some(0x7322),
+        Some(0x7339),
+        Some(0x7325),
+        Some(0x732c),
+        Some(0x7338),
+        Some(0x7331),
+        Some(0x7350),
+        Some(0x734d),
+        Some(0x7357),
+        Some(0x7360),
+        Some(0x736c),
+        Some(0x736f),
+        Some(0x737e),
+        Some(0x821b),
+        Some(0x5925),
+        Some(0x98e7),
+        Some(0x5924),
+        Some(0x5902),
+        Some(0x9963),
+        Some(0x9967),
+        Some(0x9968),
+        Some(0x9969),
+        Some(0x996a),
+        Some(0x996b),
+        Some(0x996c),
+        Some(0x9974),
+        Some(0x9977),
+        Some(0x997d),
+        Some(0x9980),
+        Some(0x9984),
+        Some(0x9987),
+        Some(0x998a),
+        Some(0x998d),
+        Some(0x9990),
+        Some(0x9991),
+        Some(0x9993),
+        Some(0x9994),
+        Some(0x9995),
+        Some(0x5e80),
+        Some(0x5e91),
+        Some(0x5e8b),
+        Some(0x5e96),
+        Some(0x5ea5),
+        Some(0x5ea0),
+        Some(0x5eb9),
+        Some(0x5eb5),
+        Some(0x5ebe),
+        Some(0x5eb3),
+        Some(0x8d53),
+        Some(0x5ed2),
+        Some(0x5ed1),
+        Some(0x5edb),
+        Some(0x5ee8),
+        Some(0x5eea),
+        Some(0x81ba),
+        Some(0x5fc4),
+        Some(0x5fc9),
+        Some(0x5fd6),
+        Some(0x5fcf),
+        Some(0x6003),
+        Some(0x5fee),
+        Some(0x6004),
+        Some(0x5fe1),
+        Some(0x5fe4),
+        Some(0x5ffe),
+        Some(0x6005),
+        Some(0x6006),
+        Some(0x5fea),
+        Some(0x5fed),
+        Some(0x5ff8),
+        Some(0x6019),
+        Some(0x6035),
+        Some(0x6026),
+        Some(0x601b),
+        Some(0x600f),
+        Some(0x600d),
+        Some(0x6029),
+        Some(0x602b),
+        Some(0x600a),
+        Some(0x603f),
+        Some(0x6021),
+        Some(0x6078),
+        Some(0x6079),
+        Some(0x607b),
+        Some(0x607a),
+        Some(0x6042),
+    ];
+
+    const BASE_0XE3A0: [Option<u16>; 95] = [
+        None,
+        Some(0x606a),
+        Some(0x607d),
+        Some(0x6096),
+        Some(0x609a),
+        Some(0x60ad),
+        Some(0x609d),
+        Some(0x6083),
+        Some(0x6092),
+        Some(0x608c),
+        Some(0x609b),
+        Some(0x60ec),
+        Some(0x60bb),
+        Some(0x60b1),
+        Some(0x60dd),
+        Some(0x60d8),
+        Some(0x60c6),
+        Some(0x60da),
+        Some(0x60b4),
+        Some(0x6120),
+        Some(0x6126),
+        Some(0x6115),
+        Some(0x6123),
+        Some(0x60f4),
+        Some(0x6100),
+        Some(0x610e),
+        Some(0x612b),
+        Some(0x614a),
+        Some(0x6175),
+        Some(0x61ac),
+        Some(0x6194),
+        Some(0x61a7),
+        Some(0x61b7),
+        Some(0x61d4),
+        Some(0x61f5),
+        Some(0x5fdd),
+        Some(0x96b3),
+        Some(0x95e9),
+        Some(0x95eb),
+        Some(0x95f1),
+        Some(0x95f3),
+        Some(0x95f5),
+        Some(0x95f6),
+        Some(0x95fc),
+        Some(0x95fe),
+        Some(0x9603),
+        Some(0x9604),
+        Some(0x9606),
+        Some(0x9608),
+        Some(0x960a),
+        Some(0x960b),
+        Some(0x960c),
+        Some(0x960d),
+        Some(0x960f),
+        Some(0x9612),
+        Some(0x9615),
+        Some(0x9616),
+        Some(0x9617),
+        Some(0x9619),
+        Some(0x961a),
+        Some(0x4e2c),
+        Some(0x723f),
+        Some(0x6215),
+        Some(0x6c35),
+        Some(0x6c54),
+        Some(0x6c5c),
+        Some(0x6c4a),
+        Some(0x6ca3),
+        Some(0x6c85),
+        Some(0x6c90),
+        Some(0x6c94),
+        Some(0x6c8c),
+        Some(0x6c68),
+        Some(0x6c69),
+        Some(0x6c74),
+        Some(0x6c76),
+        Some(0x6c86),
+        Some(0x6ca9),
+        Some(0x6cd0),
+        Some(0x6cd4),
+        Some(0x6cad),
+        Some(0x6cf7),
+        Some(0x6cf8),
+        Some(0x6cf1),
+        Some(0x6cd7),
+        Some(0x6cb2),
+        Some(0x6ce0),
+        Some(0x6cd6),
+        Some(0x6cfa),
+        Some(0x6ceb),
+        Some(0x6cee),
+        Some(0x6cb1),
+        Some(0x6cd3),
+        Some(0x6cef),
+        Some(0x6cfe),
+    ];
+
+    const BASE_0XE4A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6d39),
+        Some(0x6d27),
+        Some(0x6d0c),
+        Some(0x6d43),
+        Some(0x6d48),
+        Some(0x6d07),
+        Some(0x6d04),
+        Some(0x6d19),
+        Some(0x6d0e),
+        Some(0x6d2b),
+        Some(0x6d4d),
+        Some(0x6d2e),
+        Some(0x6d35),
+        Some(0x6d1a),
+        Some(0x6d4f),
+        Some(0x6d52),
+        Some(0x6d54),
+        Some(0x6d33),
+        Some(0x6d91),
+        Some(0x6d6f),
+        Some(0x6d9e),
+        Some(0x6da0),
+        Some(0x6d5e),
+        Some(0x6d93),
+        Some(0x6d94),
+        Some(0x6d5c),
+        Some(0x6d60),
+        Some(0x6d7c),
+        Some(0x6d63),
+        Some(0x6e1a),
+        Some(0x6dc7),
+        Some(0x6dc5),
+        Some(0x6dde),
+        Some(0x6e0e),
+        Some(0x6dbf),
+        Some(0x6de0),
+        Some(0x6e11),
+        Some(0x6de6),
+        Some(0x6ddd),
+        Some(0x6dd9),
+        Some(0x6e16),
+        Some(0x6dab),
+        Some(0x6e0c),
+        Some(0x6dae),
+        Some(0x6e2b),
+        Some(0x6e6e),
+        Some(0x6e4e),
+        Some(0x6e6b),
+        Some(0x6eb2),
+        Some(0x6e5f),
+        Some(0x6e86),
+        Some(0x6e53),
+        Some(0x6e54),
+        Some(0x6e32),
+        Some(0x6e25),
+        Some(0x6e44),
+        Some(0x6edf),
+        Some(0x6eb1),
+        Some(0x6e98),
+        Some(0x6ee0),
+        Some(0x6f2d),
+        Some(0x6ee2),
+        Some(0x6ea5),
+        Some(0x6ea7),
+        Some(0x6ebd),
+        Some(0x6ebb),
+        Some(0x6eb7),
+        Some(0x6ed7),
+        Some(0x6eb4),
+        Some(0x6ecf),
+        Some(0x6e8f),
+        Some(0x6ec2),
+        Some(0x6e9f),
+        Some(0x6f62),
+        Some(0x6f46),
+        Some(0x6f47),
+        Some(0x6f24),
+        Some(0x6f15),
+        Some(0x6ef9),
+        Some(0x6f2f),
+        Some(0x6f36),
+        Some(0x6f4b),
+        Some(0x6f74),
+        Some(0x6f2a),
+        Some(0x6f09),
+        Some(0x6f29),
+        Some(0x6f89),
+        Some(0x6f8d),
+        Some(0x6f8c),
+        Some(0x6f78),
+        Some(0x6f72),
+        Some(0x6f7c),
+        Some(0x6f7a),
+        Some(0x6fd1),
+    ];
+
+    const BASE_0XE5A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6fc9),
+        Some(0x6fa7),
+        Some(0x6fb9),
+        Some(0x6fb6),
+        Some(0x6fc2),
+        Some(0x6fe1),
+        Some(0x6fee),
+        Some(0x6fde),
+        Some(0x6fe0),
+        Some(0x6fef),
+        Some(0x701a),
+        Some(0x7023),
+        Some(0x701b),
+        Some(0x7039),
+        Some(0x7035),
+        Some(0x704f),
+        Some(0x705e),
+        Some(0x5b80),
+        Some(0x5b84),
+        Some(0x5b95),
+        Some(0x5b93),
+        Some(0x5ba5),
+        Some(0x5bb8),
+        Some(0x752f),
+        Some(0x9a9e),
+        Some(0x6434),
+        Some(0x5be4),
+        Some(0x5bee),
+        Some(0x8930),
+        Some(0x5bf0),
+        Some(0x8e47),
+        Some(0x8b07),
+        Some(0x8fb6),
+        Some(0x8fd3),
+        Some(0x8fd5),
+        Some(0x8fe5),
+        Some(0x8fee),
+        Some(0x8fe4),
+        Some(0x8fe9),
+        Some(0x8fe6),
+        Some(0x8ff3),
+        Some(0x8fe8),
+        Some(0x9005),
+        Some(0x9004),
+        Some(0x900b),
+        Some(0x9026),
+        Some(0x9011),
+        Some(0x900d),
+        Some(0x9016),
+        Some(0x9021),
+        Some(0x9035),
+        Some(0x9036),
+        Some(0x902d),
+        Some(0x902f),
+        Some(0x9044),
+        Some(0x9051),
+        Some(0x9052),
+        Some(0x9050),
+        Some(0x9068),
+        Some(0x9058),
+        Some(0x9062),
+        Some(0x905b),
+        Some(0x66b9),
+        Some(0x9074),
+        Some(0x907d),
+        Some(0x9082),
+        Some(0x9088),
+        Some(0x9083),
+        Some(0x908b),
+        Some(0x5f50),
+        Some(0x5f57),
+        Some(0x5f56),
+        Some(0x5f58),
+        Some(0x5c3b),
+        Some(0x54ab),
+        Some(0x5c50),
+        Some(0x5c59),
+        Some(0x5b71),
+        Some(0x5c63),
+        Some(0x5c66),
+        Some(0x7fbc),
+        Some(0x5f2a),
+        Some(0x5f29),
+        Some(0x5f2d),
+        Some(0x8274),
+        Some(0x5f3c),
+        Some(0x9b3b),
+        Some(0x5c6e),
+        Some(0x5981),
+        Some(0x5983),
+        Some(0x598d),
+        Some(0x59a9),
+        Some(0x59aa),
+        Some(0x59a3),
+    ];
+
+    const BASE_0XE6A0: [Option<u16>; 95] = [
+        None,
+        Some(0x5997),
+        Some(0x59ca),
+        Some(0x59ab),
+        Some(0x599e),
+        Some(0x59a4),
+        Some(0x59d2),
+        Some(0x59b2),
+        Some(0x59af),
+        Some(0x59d7),
+        Some(0x59be),
+        Some(0x5a05),
+        Some(0x5a06),
+        Some(0x59dd),
+        Some(0x5a08),
+        Some(0x59e3),
+        Some(0x59d8),
+        Some(0x59f9),
+        Some(0x5a0c),
+        Some(0x5a09),
+        Some(0x5a32),
+        Some(0x5a34),
+        Some(0x5a11),
+        Some(0x5a23),
+        Some(0x5a13),
+        Some(0x5a40),
+        Some(0x5a67),
+        Some(0x5a4a),
+        Some(0x5a55),
+        Some(0x5a3c),
+        Some(0x5a62),
+        Some(0x5a75),
+        Some(0x80ec),
+        Some(0x5aaa),
+        Some(0x5a9b),
+        Some(0x5a77),
+        Some(0x5a7a),
+        Some(0x5abe),
+        Some(0x5aeb),
+        Some(0x5ab2),
+        Some(0x5ad2),
+        Some(0x5ad4),
+        Some(0x5ab8),
+        Some(0x5ae0),
+        Some(0x5ae3),
+        Some(0x5af1),
+        Some(0x5ad6),
+        Some(0x5ae6),
+        Some(0x5ad8),
+        Some(0x5adc),
+        Some(0x5b09),
+        Some(0x5b17),
+        Some(0x5b16),
+        Some(0x5b32),
+        Some(0x5b37),
+        Some(0x5b40),
+        Some(0x5c15),
+        Some(0x5c1c),
+        Some(0x5b5a),
+        Some(0x5b65),
+        Some(0x5b73),
+        Some(0x5b51),
+        Some(0x5b53),
+        Some(0x5b62),
+        Some(0x9a75),
+        Some(0x9a77),
+        Some(0x9a78),
+        Some(0x9a7a),
+        Some(0x9a7f),
+        Some(0x9a7d),
+        Some(0x9a80),
+        Some(0x9a81),
+        Some(0x9a85),
+        Some(0x9a88),
+        Some(0x9a8a),
+        Some(0x9a90),
+        Some(0x9a92),
+        Some(0x9a93),
+        Some(0x9a96),
+        Some(0x9a98),
+        Some(0x9a9b),
+        Some(0x9a9c),
+        Some(0x9a9d),
+        Some(0x9a9f),
+        Some(0x9aa0),
+        Some(0x9aa2),
+        Some(0x9aa3),
+        Some(0x9aa5),
+        Some(0x9aa7),
+        Some(0x7e9f),
+        Some(0x7ea1),
+        Some(0x7ea3),
+        Some(0x7ea5),
+        Some(0x7ea8),
+        Some(0x7ea9),
+    ];
+
+    const BASE_0XE7A0: [Option<u16>; 95] = [
+        None,
+        Some(0x7ead),
+        Some(0x7eb0),
+        Some(0x7ebe),
+        Some(0x7ec0),
+        Some(0x7ec1),
+        Some(0x7ec2),
+        Some(0x7ec9),
+        Some(0x7ecb),
+        Some(0x7ecc),
+        Some(0x7ed0),
+        Some(0x7ed4),
+        Some(0x7ed7),
+        Some(0x7edb),
+        Some(0x7ee0),
+        Some(0x7ee1),
+        Some(0x7ee8),
+        Some(0x7eeb),
+        Some(0x7eee),
+        Some(0x7eef),
+        Some(0x7ef1),
+        Some(0x7ef2),
+        Some(0x7f0d),
+        Some(0x7ef6),
+        Some(0x7efa),
+        Some(0x7efb),
+        Some(0x7efe),
+        Some(0x7f01),
+        Some(0x7f02),
+        Some(0x7f03),
+        Some(0x7f07),
+        Some(0x7f08),
+        Some(0x7f0b),
+        Some(0x7f0c),
+        Some(0x7f0f),
+        Some(0x7f11),
+        Some(0x7f12),
+        Some(0x7f17),
+        Some(0x7f19),
+        Some(0x7f1c),
+        Some(0x7f1b),
+        Some(0x7f1f),
+        Some(0x7f21),
+        Some(0x7f22),
+        Some(0x7f23),
+        Some(0x7f24),
+        Some(0x7f25),
+        Some(0x7f26),
+        Some(0x7f27),
+        Some(0x7f2a),
+        Some(0x7f2b),
+        Some(0x7f2c),
+        Some(0x7f2d),
+        Some(0x7f2f),
+        Some(0x7f30),
+        Some(0x7f31),
+        Some(0x7f32),
+        Some(0x7f33),
+        Some(0x7f35),
+        Some(0x5e7a),
+        Some(0x757f),
+        Some(0x5ddb),
+        Some(0x753e),
+        Some(0x9095),
+        Some(0x738e),
+        Some(0x7391),
+        Some(0x73ae),
+        Some(0x73a2),
+        Some(0x739f),
+        Some(0x73cf),
+        Some(0x73c2),
+        Some(0x73d1),
+        Some(0x73b7),
+        Some(0x73b3),
+        Some(0x73c0),
+        Some(0x73c9),
+        Some(0x73c8),
+        Some(0x73e5),
+        Some(0x73d9),
+        Some(0x987c),
+        Some(0x740a),
+        Some(0x73e9),
+        Some(0x73e7),
+        Some(0x73de),
+        Some(0x73ba),
+        Some(0x73f2),
+        Some(0x740f),
+        Some(0x742a),
+        Some(0x745b),
+        Some(0x7426),
+        Some(0x7425),
+        Some(0x7428),
+        Some(0x7430),
+        Some(0x742e),
+        Some(0x742c),
+    ];
+
+    const BASE_0XE8A0: [Option<u16>; 95] = [
+        None,
+        Some(0x741b),
+        Some(0x741a),
+        Some(0x7441),
+        Some(0x745c),
+        Some(0x7457),
+        Some(0x7455),
+        Some(0x7459),
+        Some(0x7477),
+        Some(0x746d),
+        Some(0x747e),
+        Some(0x749c),
+        Some(0x748e),
+        Some(0x7480),
+        Some(0x7481),
+        Some(0x7487),
+        Some(0x748b),
+        Some(0x749e),
+        Some(0x74a8),
+        Some(0x74a9),
+        Some(0x7490),
+        Some(0x74a7),
+        Some(0x74d2),
+        Some(0x74ba),
+        Some(0x97ea),
+        Some(0x97eb),
+        Some(0x97ec),
+        Some(0x674c),
+        Some(0x6753),
+        Some(0x675e),
+        Some(0x6748),
+        Some(0x6769),
+        Some(0x67a5),
+        Some(0x6787),
+        Some(0x676a),
+        Some(0x6773),
+        Some(0x6798),
+        Some(0x67a7),
+        Some(0x6775),
+        Some(0x67a8),
+        Some(0x679e),
+        Some(0x67ad),
+        Some(0x678b),
+        Some(0x6777),
+        Some(0x677c),
+        Some(0x67f0),
+        Some(0x6809),
+        Some(0x67d8),
+        Some(0x680a),
+        Some(0x67e9),
+        Some(0x67b0),
+        Some(0x680c),
+        Some(0x67d9),
+        Some(0x67b5),
+        Some(0x67da),
+        Some(0x67b3),
+        Some(0x67dd),
+        Some(0x6800),
+        Some(0x67c3),
+        Some(0x67b8),
+        Some(0x67e2),
+        Some(0x680e),
+        Some(0x67c1),
+        Some(0x67fd),
+        Some(0x6832),
+        Some(0x6833),
+        Some(0x6860),
+        Some(0x6861),
+        Some(0x684e),
+        Some(0x6862),
+        Some(0x6844),
+        Some(0x6864),
+        Some(0x6883),
+        Some(0x681d),
+        Some(0x6855),
+        Some(0x6866),
+        Some(0x6841),
+        Some(0x6867),
+        Some(0x6840),
+        Some(0x683e),
+        Some(0x684a),
+        Some(0x6849),
+        Some(0x6829),
+        Some(0x68b5),
+        Some(0x688f),
+        Some(0x6874),
+        Some(0x6877),
+        Some(0x6893),
+        Some(0x686b),
+        Some(0x68c2),
+        Some(0x696e),
+        Some(0x68fc),
+        Some(0x691f),
+        Some(0x6920),
+        Some(0x68f9),
+    ];
+
+    const BASE_0XE9A0: [Option<u16>; 95] = [
+        None,
+        Some(0x6924),
+        Some(0x68f0),
+        Some(0x690b),
+        Some(0x6901),
+        Some(0x6957),
+        Some(0x68e3),
+        Some(0x6910),
+        Some(0x6971),
+        Some(0x6939),
+        Some(0x6960),
+        Some(0x6942),
+        Some(0x695d),
+        Some(0x6984),
+        Some(0x696b),
+        Some(0x6980),
+        Some(0x6998),
+        Some(0x6978),
+        Some(0x6934),
+        Some(0x69cc),
+        Some(0x6987),
+        Some(0x6988),
+        Some(0x69ce),
+        Some(0x6989),
+        Some(0x6966),
+        Some(0x6963),
+        Some(0x6979),
+        Some(0x699b),
+        Some(0x69a7),
+        Some(0x69bb),
+        Some(0x69ab),
+        Some(0x69ad),
+        Some(0x69d4),
+        Some(0x69b1),
+        Some(0x69c1),
+        Some(0x69ca),
+        Some(0x69df),
+        Some(0x6995),
+        Some(0x69e0),
+        Some(0x698d),
+        Some(0x69ff),
+        Some(0x6a2f),
+        Some(0x69ed),
+        Some(0x6a17),
+        Some(0x6a18),
+        Some(0x6a65),
+        Some(0x69f2),
+        Some(0x6a44),
+        Some(0x6a3e),
+        Some(0x6aa0),
+        Some(0x6a50),
+        Some(0x6a5b),
+        Some(0x6a35),
+        Some(0x6a8e),
+        Some(0x6a79),
+        Some(0x6a3d),
+        Some(0x6a28),
+        Some(0x6a58),
+        Some(0x6a7c),
+        Some(0x6a91),
+        Some(0x6a90),
+        Some(0x6aa9),
+        Some(0x6a97),
+        Some(0x6aab),
+        Some(0x7337),
+        Some(0x7352),
+        Some(0x6b81),
+        Some(0x6b82),
+        Some(0x6b87),
+        Some(0x6b84),
+        Some(0x6b92),
+        Some(0x6b93),
+        Some(0x6b8d),
+        Some(0x6b9a),
+        Some(0x6b9b),
+        Some(0x6ba1),
+        Some(0x6baa),
+        Some(0x8f6b),
+        Some(0x8f6d),
+        Some(0x8f71),
+        Some(0x8f72),
+        Some(0x8f73),
+        Some(0x8f75),
+        Some(0x8f76),
+        Some(0x8f78),
+        Some(0x8f77),
+        Some(0x8f79),
+        Some(0x8f7a),
+        Some(0x8f7c),
+        Some(0x8f7e),
+        Some(0x8f81),
+        Some(0x8f82),
+        Some(0x8f84),
+        Some(0x8f87),
+        Some(0x8f8b),
+    ];
+
+    const BASE_0XEAA0: [Option<u16>; 95] = [
+        None,
+        Some(0x8f8d),
+        Some(0x8f8e),
+        Some(0x8f8f),
+        Some(0x8f98),
+        Some(0x8f9a),
+        Some(0x8ece),
+        Some(0x620b),
+        Some(0x6217),
+        Some(0x621b),
+        Some(0x621f),
+        Some(0x6222),
+        Some(0x6221),
+        Some(0x6225),
+        Some(0x6224),
+        Some(0x622c),
+        Some(0x81e7),
+        Some(0x74ef),
+        Some(0x74f4),
+        Some(0x74ff),
+        Some(0x750f),
+        Some(0x7511),
+        Some(0x7513),
+        Some(0x6534),
+        Some(0x65ee),
+        Some(0x65ef),
+        Some(0x65f0),
+        Some(0x660a),
+        Some(0x6619),
+        Some(0x6772),
+        Some(0x6603),
+        Some(0x6615),
+        Some(0x6600),
+        Some(0x7085),
+        Some(0x66f7),
+        Some(0x661d),
+        Some(0x6634),
+        Some(0x6631),
+        Some(0x6636),
+        Some(0x6635),
+        Some(0x8006),
+        Some(0x665f),
+        Some(0x6654),
+        Some(0x6641),
+        Some(0x664f),
+        Some(0x6656),
+        Some(0x6661),
+        Some(0x6657),
+        Some(0x6677),
+        Some(0x6684),
+        Some(0x668c),
+        Some(0x66a7),
+        Some(0x669d),
+        Some(0x66be),
+        Some(0x66db),
+        Some(0x66dc),
+        Some(0x66e6),
+        Some(0x66e9),
+        Some(0x8d32),
+        Some(0x8d33),
+        Some(0x8d36),
+        Some(0x8d3b),
+        Some(0x8d3d),
+        Some(0x8d40),
+        Some(0x8d45),
+        Some(0x8d46),
+        Some(0x8d48),
+        Some(0x8d49),
+        Some(0x8d47),
+        Some(0x8d4d),
+        Some(0x8d55),
+        Some(0x8d59),
+        Some(0x89c7),
+        Some(0x89ca),
+        Some(0x89cb),
+        Some(0x89cc),
+        Some(0x89ce),
+        Some(0x89cf),
+        Some(0x89d0),
+        Some(0x89d1),
+        Some(0x726e),
+        Some(0x729f),
+        Some(0x725d),
+        Some(0x7266),
+        Some(0x726f),
+        Some(0x727e),
+        Some(0x727f),
+        Some(0x7284),
+        Some(0x728b),
+        Some(0x728d),
+        Some(0x728f),
+        Some(0x7292),
+        Some(0x6308),
+        Some(0x6332),
+        Some(0x63b0),
+    ];
+
+    const BASE_0XEBA0: [Option<u16>; 95] = [
+        None,
+        Some(0x643f),
+        Some(0x64d8),
+        Some(0x8004),
+        Some(0x6bea),
+        Some(0x6bf3),
+        Some(0x6bfd),
+        Some(0x6bf5),
+        Some(0x6bf9),
+        Some(0x6c05),
+        Some(0x6c07),
+        Some(0x6c06),
+        Some(0x6c0d),
+        Some(0x6c15),
+        Some(0x6c18),
+        Some(0x6c19),
+        Some(0x6c1a),
+        Some(0x6c21),
+        Some(0x6c29),
+        Some(0x6c24),
+        Some(0x6c2a),
+        Some(0x6c32),
+        Some(0x6535),
+        Some(0x6555),
+        Some(0x656b),
+        Some(0x724d),
+        Some(0x7252),
+        Some(0x7256),
+        Some(0x7230),
+        Some(0x8662),
+        Some(0x5216),
+        Some(0x809f),
+        Some(0x809c),
+        Some(0x8093),
+        Some(0x80bc),
+        Some(0x670a),
+        Some(0x80bd),
+        Some(0x80b1),
+        Some(0x80ab),
+        Some(0x80ad),
+        Some(0x80b4),
+        Some(0x80b7),
+        Some(0x80e7),
+        Some(0x80e8),
+        Some(0x80e9),
+        Some(0x80ea),
+        Some(0x80db),
+        Some(0x80c2),
+        Some(0x80c4),
+        Some(0x80d9),
+        Some(0x80cd),
+        Some(0x80d7),
+        Some(0x6710),
+        Some(0x80dd),
+        Some(0x80eb),
+        Some(0x80f1),
+        Some(0x80f4),
+        Some(0x80ed),
+        Some(0x810d),
+        Some(0x810e),
+        Some(0x80f2),
+        Some(0x80fc),
+        Some(0x6715),
+        Some(0x8112),
+        Some(0x8c5a),
+        Some(0x8136),
+        Some(0x811e),
+        Some(0x812c),
+        Some(0x8118),
+        Some(0x8132),
+        Some(0x8148),
+        Some(0x814c),
+        Some(0x8153),
+        Some(0x8174),
+        Some(0x8159),
+        Some(0x815a),
+        Some(0x8171),
+        Some(0x8160),
+        Some(0x8169),
+        Some(0x817c),
+        Some(0x817d),
+        Some(0x816d),
+        Some(0x8167),
+        Some(0x584d),
+        Some(0x5ab5),
+        Some(0x8188),
+        Some(0x8182),
+        Some(0x8191),
+        Some(0x6ed5),
+        Some(0x81a3),
+        Some(0x81aa),
+        Some(0x81cc),
+        Some(0x6726),
+        Some(0x81ca),
+        Some(0x81bb),
+    ];
+
+    const BASE_0XECA0: [Option<u16>; 95] = [
+        None,
+        Some(0x81c1),
+        Some(0x81a6),
+        Some(0x6b24),
+        Some(0x6b37),
+        Some(0x6b39),
+        Some(0x6b43),
+        Some(0x6b46),
+        Some(0x6b59),
+        Some(0x98d1),
+        Some(0x98d2),
+        Some(0x98d3),
+        Some(0x98d5),
+        Some(0x98d9),
+        Some(0x98da),
+        Some(0x6bb3),
+        Some(0x5f40),
+        Some(0x6bc2),
+        Some(0x89f3),
+        Some(0x6590),
+        Some(0x9f51),
+        Some(0x6593),
+        Some(0x65bc),
+        Some(0x65c6),
+        Some(0x65c4),
+        Some(0x65c3),
+        Some(0x65cc),
+        Some(0x65ce),
+        Some(0x65d2),
+        Some(0x65d6),
+        Some(0x7080),
+        Some(0x709c),
+        Some(0x7096),
+        Some(0x709d),
+        Some(0x70bb),
+        Some(0x70c0),
+        Some(0x70b7),
+        Some(0x70ab),
+        Some(0x70b1),
+        Some(0x70e8),
+        Some(0x70ca),
+        Some(0x7110),
+        Some(0x7113),
+        Some(0x7116),
+        Some(0x712f),
+        Some(0x7131),
+        Some(0x7173),
+        Some(0x715c),
+        Some(0x7168),
+        Some(0x7145),
+        Some(0x7172),
+        Some(0x714a),
+        Some(0x7178),
+        Some(0x717a),
+        Some(0x7198),
+        Some(0x71b3),
+        Some(0x71b5),
+        Some(0x71a8),
+        Some(0x71a0),
+        Some(0x71e0),
+        Some(0x71d4),
+        Some(0x71e7),
+        Some(0x71f9),
+        Some(0x721d),
+        Some(0x7228),
+        Some(0x706c),
+        Some(0x7118),
+        Some(0x7166),
+        Some(0x71b9),
+        Some(0x623e),
+        Some(0x623d),
+        Some(0x6243),
+        Some(0x6248),
+        Some(0x6249),
+        Some(0x793b),
+        Some(0x7940),
+        Some(0x7946),
+        Some(0x7949),
+        Some(0x795b),
+        Some(0x795c),
+        Some(0x7953),
+        Some(0x795a),
+        Some(0x7962),
+        Some(0x7957),
+        Some(0x7960),
+        Some(0x796f),
+        Some(0x7967),
+        Some(0x797a),
+        Some(0x7985),
+        Some(0x798a),
+        Some(0x799a),
+        Some(0x79a7),
+        Some(0x79b3),
+        Some(0x5fd1),
+        Some(0x5fd0),
+    ];
+
+    const BASE_0XEDA0: [Option<u16>; 95] = [
+        None,
+        Some(0x603c),
+        Some(0x605d),
+        Some(0x605a),
+        Some(0x6067),
+        Some(0x6041),
+        Some(0x6059),
+        Some(0x6063),
+        Some(0x60ab),
+        Some(0x6106),
+        Some(0x610d),
+        Some(0x615d),
+        Some(0x61a9),
+        Some(0x619d),
+        Some(0x61cb),
+        Some(0x61d1),
+        Some(0x6206),
+        Some(0x8080),
+        Some(0x807f),
+        Some(0x6c93),
+        Some(0x6cf6),
+        Some(0x6dfc),
+        Some(0x77f6),
+        Some(0x77f8),
+        Some(0x7800),
+        Some(0x7809),
+        Some(0x7817),
+        Some(0x7818),
+        Some(0x7811),
+        Some(0x65ab),
+        Some(0x782d),
+        Some(0x781c),
+        Some(0x781d),
+        Some(0x7839),
+        Some(0x783a),
+        Some(0x783b),
+        Some(0x781f),
+        Some(0x783c),
+        Some(0x7825),
+        Some(0x782c),
+        Some(0x7823),
+        Some(0x7829),
+        Some(0x784e),
+        Some(0x786d),
+        Some(0x7856),
+        Some(0x7857),
+        Some(0x7826),
+        Some(0x7850),
+        Some(0x7847),
+        Some(0x784c),
+        Some(0x786a),
+        Some(0x789b),
+        Some(0x7893),
+        Some(0x789a),
+        Some(0x7887),
+        Some(0x789c),
+        Some(0x78a1),
+        Some(0x78a3),
+        Some(0x78b2),
+        Some(0x78b9),
+        Some(0x78a5),
+        Some(0x78d4),
+        Some(0x78d9),
+        Some(0x78c9),
+        Some(0x78ec),
+        Some(0x78f2),
+        Some(0x7905),
+        Some(0x78f4),
+        Some(0x7913),
+        Some(0x7924),
+        Some(0x791e),
+        Some(0x7934),
+        Some(0x9f9b),
+        Some(0x9ef9),
+        Some(0x9efb),
+        Some(0x9efc),
+        Some(0x76f1),
+        Some(0x7704),
+        Some(0x770d),
+        Some(0x76f9),
+        Some(0x7707),
+        Some(0x7708),
+        Some(0x771a),
+        Some(0x7722),
+        Some(0x7719),
+        Some(0x772d),
+        Some(0x7726),
+        Some(0x7735),
+        Some(0x7738),
+        Some(0x7750),
+        Some(0x7751),
+        Some(0x7747),
+        Some(0x7743),
+        Some(0x775a),
+        Some(0x7768),
+    ];
+
+    const BASE_0XEEA0: [Option<u16>; 95] = [
+        None,
+        Some(0x7762),
+        Some(0x7765),
+        Some(0x777f),
+        Some(0x778d),
+        Some(0x777d),
+        Some(0x7780),
+        Some(0x778c),
+        Some(0x7791),
+        Some(0x779f),
+        Some(0x77a0),
+        Some(0x77b0),
+        Some(0x77b5),
+        Some(0x77bd),
+        Some(0x753a),
+        Some(0x7540),
+        Some(0x754e),
+        Some(0x754b),
+        Some(0x7548),
+        Some(0x755b),
+        Some(0x7572),
+        Some(0x7579),
+        Some(0x7583),
+        Some(0x7f58),
+        Some(0x7f61),
+        Some(0x7f5f),
+        Some(0x8a48),
+        Some(0x7f68),
+        Some(0x7f74),
+        Some(0x7f71),
+        Some(0x7f79),
+        Some(0x7f81),
+        Some(0x7f7e),
+        Some(0x76cd),
+        Some(0x76e5),
+        Some(0x8832),
+        Some(0x9485),
+        Some(0x9486),
+        Some(0x9487),
+        Some(0x948b),
+        Some(0x948a),
+        Some(0x948c),
+        Some(0x948d),
+        Some(0x948f),
+        Some(0x9490),
+        Some(0x9494),
+        Some(0x9497),
+        Some(0x9495),
+        Some(0x949a),
+        Some(0x949b),
+        Some(0x949c),
+        Some(0x94a3),
+        Some(0x94a4),
+        Some(0x94ab),
+        Some(0x94aa),
+        Some(0x94ad),
+        Some(0x94ac),
+        Some(0x94af),
+        Some(0x94b0),
+        Some(0x94b2),
+        Some(0x94b4),
+        Some(0x94b6),
+        Some(0x94b7),
+        Some(0x94b8),
+        Some(0x94b9),
+        Some(0x94ba),
+        Some(0x94bc),
+        Some(0x94bd),
+        Some(0x94bf),
+        Some(0x94c4),
+        Some(0x94c8),
+        Some(0x94c9),
+        Some(0x94ca),
+        Some(0x94cb),
+        Some(0x94cc),
+        Some(0x94cd),
+        Some(0x94ce),
+        Some(0x94d0),
+        Some(0x94d1),
+        Some(0x94d2),
+        Some(0x94d5),
+        Some(0x94d6),
+        Some(0x94d7),
+        Some(0x94d9),
+        Some(0x94d8),
+        Some(0x94db),
+        Some(0x94de),
+        Some(0x94df),
+        Some(0x94e0),
+        Some(0x94e2),
+        Some(0x94e4),
+        Some(0x94e5),
+        Some(0x94e7),
+        Some(0x94e8),
+        Some(0x94ea),
+    ];
+
+    const BASE_0XEFA0: [Option<u16>; 95] = [
+        None,
+        Some(0x94e9),
+        Some(0x94eb),
+        Some(0x94ee),
+        Some(0x94ef),
+        Some(0x94f3),
+        Some(0x94f4),
+        Some(0x94f5),
+        Some(0x94f7),
+        Some(0x94f9),
+        Some(0x94fc),
+        Some(0x94fd),
+        Some(0x94ff),
+        Some(0x9503),
+        Some(0x9502),
+        Some(0x9506),
+        Some(0x9507),
+        Some(0x9509),
+        Some(0x950a),
+        Some(0x950d),
+        Some(0x950e),
+        Some(0x950f),
+        Some(0x9512),
+        Some(0x9513),
+        Some(0x9514),
+        Some(0x9515),
+        Some(0x9516),
+        Some(0x9518),
+        Some(0x951b),
+        Some(0x951d),
+        Some(0x951e),
+        Some(0x951f),
+        Some(0x9522),
+        Some(0x952a),
+        Some(0x952b),
+        Some(0x9529),
+        Some(0x952c),
+        Some(0x9531),
+        Some(0x9532),
+        Some(0x9534),
+        Some(0x9536),
+        Some(0x9537),
+        Some(0x9538),
+        Some(0x953c),
+        Some(0x953e),
+        Some(0x953f),
+        Some(0x9542),
+        Some(0x9535),
+        Some(0x9544),
+        Some(0x9545),
+        Some(0x9546),
+        Some(0x9549),
+        Some(0x954c),
+        Some(0x954e),
+        Some(0x954f),
+        Some(0x9552),
+        Some(0x9553),
+        Some(0x9554),
+        Some(0x9556),
+        Some(0x9557),
+        Some(0x9558),
+        Some(0x9559),
+        Some(0x955b),
+        Some(0x955e),
+        Some(0x955f),
+        Some(0x955d),
+        Some(0x9561),
+        Some(0x9562),
+        Some(0x9564),
+        Some(0x9565),
+        Some(0x9566),
+        Some(0x9567),
+        Some(0x9568),
+        Some(0x9569),
+        Some(0x956a),
+        Some(0x956b),
+        Some(0x956c),
+        Some(0x956f),
+        Some(0x9571),
+        Some(0x9572),
+        Some(0x9573),
+        Some(0x953a),
+        Some(0x77e7),
+        Some(0x77ec),
+        Some(0x96c9),
+        Some(0x79d5),
+        Some(0x79ed),
+        Some(0x79e3),
+        Some(0x79eb),
+        Some(0x7a06),
+        Some(0x5d47),
+        Some(0x7a03),
+        Some(0x7a02),
+        Some(0x7a1e),
+        Some(0x7a14),
+    ];
+
+    const BASE_0XF0A0: [Option<u16>; 95] = [
+        None,
+        Some(0x7a39),
+        Some(0x7a37),
+        Some(0x7a51),
+        Some(0x9ecf),
+        Some(0x99a5),
+        Some(0x7a70),
+        Some(0x7688),
+        Some(0x768e),
+        Some(0x7693),
+        Some(0x7699),
+        Some(0x76a4),
+        Some(0x74de),
+        Some(0x74e0),
+        Some(0x752c),
+        Some(0x9e20),
+        Some(0x9e22),
+        Some(0x9e28),
+        Some(0x9e29),
+        Some(0x9e2a),
+        Some(0x9e2b),
+        Some(0x9e2c),
+        Some(0x9e32),
+        Some(0x9e31),
+        Some(0x9e36),
+        Some(0x9e38),
+        Some(0x9e37),
+        Some(0x9e39),
+        Some(0x9e3a),
+        Some(0x9e3e),
+        Some(0x9e41),
+        Some(0x9e42),
+        Some(0x9e44),
+        Some(0x9e46),
+        Some(0x9e47),
+        Some(0x9e48),
+        Some(0x9e49),
+        Some(0x9e4b),
+        Some(0x9e4c),
+        Some(0x9e4e),
+        Some(0x9e51),
+        Some(0x9e55),
+        Some(0x9e57),
+        Some(0x9e5a),
+        Some(0x9e5b),
+        Some(0x9e5c),
+        Some(0x9e5e),
+        Some(0x9e63),
+        Some(0x9e66),
+        Some(0x9e67),
+        Some(0x9e68),
+        Some(0x9e69),
+        Some(0x9e6a),
+        Some(0x9e6b),
+        Some(0x9e6c),
+        Some(0x9e71),
+        Some(0x9e6d),
+        Some(0x9e73),
+        Some(0x7592),
+        Some(0x7594),
+        Some(0x7596),
+        Some(0x75a0),
+        Some(0x759d),
+        Some(0x75ac),
+        Some(0x75a3),
+        Some(0x75b3),
+        Some(0x75b4),
+        Some(0x75b8),
+        Some(0x75c4),
+        Some(0x75b1),
+        Some(0x75b0),
+        Some(0x75c3),
+        Some(0x75c2),
+        Some(0x75d6),
+        Some(0x75cd),
+        Some(0x75e3),
+        Some(0x75e8),
+        Some(0x75e6),
+        Some(0x75e4),
+        Some(0x75eb),
+        Some(0x75e7),
+        Some(0x7603),
+        Some(0x75f1),
+        Some(0x75fc),
+        Some(0x75ff),
+        Some(0x7610),
+        Some(0x7600),
+        Some(0x7605),
+        Some(0x760c),
+        Some(0x7617),
+        Some(0x760a),
+        Some(0x7625),
+        Some(0x7618),
+        Some(0x7615),
+        Some(0x7619),
+    ];
+
+    const BASE_0XF1A0: [Option<u16>; 95] = [
+        None,
+        Some(0x761b),
+        Some(0x763c),
+        Some(0x7622),
+        Some(0x7620),
+        Some(0x7640),
+        Some(0x762d),
+        Some(0x7630),
+        Some(0x763f),
+        Some(0x7635),
+        Some(0x7643),
+        Some(0x763e),
+        Some(0x7633),
+        Some(0x764d),
+        Some(0x765e),
+        Some(0x7654),
+        Some(0x765c),
+        Some(0x7656),
+        Some(0x766b),
+        Some(0x766f),
+        Some(0x7fca),
+        Some(0x7ae6),
+        Some(0x7a78),
+        Some(0x7a79),
+        Some(0x7a80),
+        Some(0x7a86),
+        Some(0x7a88),
+        Some(0x7a95),
+        Some(0x7aa6),
+        Some(0x7aa0),
+        Some(0x7aac),
+        Some(0x7aa8),
+        Some(0x7aad),
+        Some(0x7ab3),
+        Some(0x8864),
+        Some(0x8869),
+        Some(0x8872),
+        Some(0x887d),
+        Some(0x887f),
+        Some(0x8882),
+        Some(0x88a2),
+        Some(0x88c6),
+        Some(0x88b7),
+        Some(0x88bc),
+        Some(0x88c9),
+        Some(0x88e2),
+        Some(0x88ce),
+        Some(0x88e3),
+        Some(0x88e5),
+        Some(0x88f1),
+        Some(0x891a),
+        Some(0x88fc),
+        Some(0x88e8),
+        Some(0x88fe),
+        Some(0x88f0),
+        Some(0x8921),
+        Some(0x8919),
+        Some(0x8913),
+        Some(0x891b),
+        Some(0x890a),
+        Some(0x8934),
+        Some(0x892b),
+        Some(0x8936),
+        Some(0x8941),
+        Some(0x8966),
+        Some(0x897b),
+        Some(0x758b),
+        Some(0x80e5),
+        Some(0x76b2),
+        Some(0x76b4),
+        Some(0x77dc),
+        Some(0x8012),
+        Some(0x8014),
+        Some(0x8016),
+        Some(0x801c),
+        Some(0x8020),
+        Some(0x8022),
+        Some(0x8025),
+        Some(0x8026),
+        Some(0x8027),
+        Some(0x8029),
+        Some(0x8028),
+        Some(0x8031),
+        Some(0x800b),
+        Some(0x8035),
+        Some(0x8043),
+        Some(0x8046),
+        Some(0x804d),
+        Some(0x8052),
+        Some(0x8069),
+        Some(0x8071),
+        Some(0x8983),
+        Some(0x9878),
+        Some(0x9880),
+        Some(0x9883),
+    ];
+
+    const BASE_0XF2A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9889),
+        Some(0x988c),
+        Some(0x988d),
+        Some(0x988f),
+        Some(0x9894),
+        Some(0x989a),
+        Some(0x989b),
+        Some(0x989e),
+        Some(0x989f),
+        Some(0x98a1),
+        Some(0x98a2),
+        Some(0x98a5),
+        Some(0x98a6),
+        Some(0x864d),
+        Some(0x8654),
+        Some(0x866c),
+        Some(0x866e),
+        Some(0x867f),
+        Some(0x867a),
+        Some(0x867c),
+        Some(0x867b),
+        Some(0x86a8),
+        Some(0x868d),
+        Some(0x868b),
+        Some(0x86ac),
+        Some(0x869d),
+        Some(0x86a7),
+        Some(0x86a3),
+        Some(0x86aa),
+        Some(0x8693),
+        Some(0x86a9),
+        Some(0x86b6),
+        Some(0x86c4),
+        Some(0x86b5),
+        Some(0x86ce),
+        Some(0x86b0),
+        Some(0x86ba),
+        Some(0x86b1),
+        Some(0x86af),
+        Some(0x86c9),
+        Some(0x86cf),
+        Some(0x86b4),
+        Some(0x86e9),
+        Some(0x86f1),
+        Some(0x86f2),
+        Some(0x86ed),
+        Some(0x86f3),
+        Some(0x86d0),
+        Some(0x8713),
+        Some(0x86de),
+        Some(0x86f4),
+        Some(0x86df),
+        Some(0x86d8),
+        Some(0x86d1),
+        Some(0x8703),
+        Some(0x8707),
+        Some(0x86f8),
+        Some(0x8708),
+        Some(0x870a),
+        Some(0x870d),
+        Some(0x8709),
+        Some(0x8723),
+        Some(0x873b),
+        Some(0x871e),
+        Some(0x8725),
+        Some(0x872e),
+        Some(0x871a),
+        Some(0x873e),
+        Some(0x8748),
+        Some(0x8734),
+        Some(0x8731),
+        Some(0x8729),
+        Some(0x8737),
+        Some(0x873f),
+        Some(0x8782),
+        Some(0x8722),
+        Some(0x877d),
+        Some(0x877e),
+        Some(0x877b),
+        Some(0x8760),
+        Some(0x8770),
+        Some(0x874c),
+        Some(0x876e),
+        Some(0x878b),
+        Some(0x8753),
+        Some(0x8763),
+        Some(0x877c),
+        Some(0x8764),
+        Some(0x8759),
+        Some(0x8765),
+        Some(0x8793),
+        Some(0x87af),
+        Some(0x87a8),
+        Some(0x87d2),
+    ];
+
+    const BASE_0XF3A0: [Option<u16>; 95] = [
+        None,
+        Some(0x87c6),
+        Some(0x8788),
+        Some(0x8785),
+        Some(0x87ad),
+        Some(0x8797),
+        Some(0x8783),
+        Some(0x87ab),
+        Some(0x87e5),
+        Some(0x87ac),
+        Some(0x87b5),
+        Some(0x87b3),
+        Some(0x87cb),
+        Some(0x87d3),
+        Some(0x87bd),
+        Some(0x87d1),
+        Some(0x87c0),
+        Some(0x87ca),
+        Some(0x87db),
+        Some(0x87ea),
+        Some(0x87e0),
+        Some(0x87ee),
+        Some(0x8816),
+        Some(0x8813),
+        Some(0x87fe),
+        Some(0x880a),
+        Some(0x881b),
+        Some(0x8821),
+        Some(0x8839),
+        Some(0x883c),
+        Some(0x7f36),
+        Some(0x7f42),
+        Some(0x7f44),
+        Some(0x7f45),
+        Some(0x8210),
+        Some(0x7afa),
+        Some(0x7afd),
+        Some(0x7b08),
+        Some(0x7b03),
+        Some(0x7b04),
+        Some(0x7b15),
+        Some(0x7b0a),
+        Some(0x7b2b),
+        Some(0x7b0f),
+        Some(0x7b47),
+        Some(0x7b38),
+        Some(0x7b2a),
+        Some(0x7b19),
+        Some(0x7b2e),
+        Some(0x7b31),
+        Some(0x7b20),
+        Some(0x7b25),
+        Some(0x7b24),
+        Some(0x7b33),
+        Some(0x7b3e),
+        Some(0x7b1e),
+        Some(0x7b58),
+        Some(0x7b5a),
+        Some(0x7b45),
+        Some(0x7b75),
+        Some(0x7b4c),
+        Some(0x7b5d),
+        Some(0x7b60),
+        Some(0x7b6e),
+        Some(0x7b7b),
+        Some(0x7b62),
+        Some(0x7b72),
+        Some(0x7b71),
+        Some(0x7b90),
+        Some(0x7ba6),
+        Some(0x7ba7),
+        Some(0x7bb8),
+        Some(0x7bac),
+        Some(0x7b9d),
+        Some(0x7ba8),
+        Some(0x7b85),
+        Some(0x7baa),
+        Some(0x7b9c),
+        Some(0x7ba2),
+        Some(0x7bab),
+        Some(0x7bb4),
+        Some(0x7bd1),
+        Some(0x7bc1),
+        Some(0x7bcc),
+        Some(0x7bdd),
+        Some(0x7bda),
+        Some(0x7be5),
+        Some(0x7be6),
+        Some(0x7bea),
+        Some(0x7c0c),
+        Some(0x7bfe),
+        Some(0x7bfc),
+        Some(0x7c0f),
+        Some(0x7c16),
+        Some(0x7c0b),
+    ];
+
+    const BASE_0XF4A0: [Option<u16>; 95] = [
+        None,
+        Some(0x7c1f),
+        Some(0x7c2a),
+        Some(0x7c26),
+        Some(0x7c38),
+        Some(0x7c41),
+        Some(0x7c40),
+        Some(0x81fe),
+        Some(0x8201),
+        Some(0x8202),
+        Some(0x8204),
+        Some(0x81ec),
+        Some(0x8844),
+        Some(0x8221),
+        Some(0x8222),
+        Some(0x8223),
+        Some(0x822d),
+        Some(0x822f),
+        Some(0x8228),
+        Some(0x822b),
+        Some(0x8238),
+        Some(0x823b),
+        Some(0x8233),
+        Some(0x8234),
+        Some(0x823e),
+        Some(0x8244),
+        Some(0x8249),
+        Some(0x824b),
+        Some(0x824f),
+        Some(0x825a),
+        Some(0x825f),
+        Some(0x8268),
+        Some(0x887e),
+        Some(0x8885),
+        Some(0x8888),
+        Some(0x88d8),
+        Some(0x88df),
+        Some(0x895e),
+        Some(0x7f9d),
+        Some(0x7f9f),
+        Some(0x7fa7),
+        Some(0x7faf),
+        Some(0x7fb0),
+        Some(0x7fb2),
+        Some(0x7c7c),
+        Some(0x6549),
+        Some(0x7c91),
+        Some(0x7c9d),
+        Some(0x7c9c),
+        Some(0x7c9e),
+        Some(0x7ca2),
+        Some(0x7cb2),
+        Some(0x7cbc),
+        Some(0x7cbd),
+        Some(0x7cc1),
+        Some(0x7cc7),
+        Some(0x7ccc),
+        Some(0x7ccd),
+        Some(0x7cc8),
+        Some(0x7cc5),
+        Some(0x7cd7),
+        Some(0x7ce8),
+        Some(0x826e),
+        Some(0x66a8),
+        Some(0x7fbf),
+        Some(0x7fce),
+        Some(0x7fd5),
+        Some(0x7fe5),
+        Some(0x7fe1),
+        Some(0x7fe6),
+        Some(0x7fe9),
+        Some(0x7fee),
+        Some(0x7ff3),
+        Some(0x7cf8),
+        Some(0x7d77),
+        Some(0x7da6),
+        Some(0x7dae),
+        Some(0x7e47),
+        Some(0x7e9b),
+        Some(0x9eb8),
+        Some(0x9eb4),
+        Some(0x8d73),
+        Some(0x8d84),
+        Some(0x8d94),
+        Some(0x8d91),
+        Some(0x8db1),
+        Some(0x8d67),
+        Some(0x8d6d),
+        Some(0x8c47),
+        Some(0x8c49),
+        Some(0x914a),
+        Some(0x9150),
+        Some(0x914e),
+        Some(0x914f),
+        Some(0x9164),
+    ];
+
+    const BASE_0XF5A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9162),
+        Some(0x9161),
+        Some(0x9170),
+        Some(0x9169),
+        Some(0x916f),
+        Some(0x917d),
+        Some(0x917e),
+        Some(0x9172),
+        Some(0x9174),
+        Some(0x9179),
+        Some(0x918c),
+        Some(0x9185),
+        Some(0x9190),
+        Some(0x918d),
+        Some(0x9191),
+        Some(0x91a2),
+        Some(0x91a3),
+        Some(0x91aa),
+        Some(0x91ad),
+        Some(0x91ae),
+        Some(0x91af),
+        Some(0x91b5),
+        Some(0x91b4),
+        Some(0x91ba),
+        Some(0x8c55),
+        Some(0x9e7e),
+        Some(0x8db8),
+        Some(0x8deb),
+        Some(0x8e05),
+        Some(0x8e59),
+        Some(0x8e69),
+        Some(0x8db5),
+        Some(0x8dbf),
+        Some(0x8dbc),
+        Some(0x8dba),
+        Some(0x8dc4),
+        Some(0x8dd6),
+        Some(0x8dd7),
+        Some(0x8dda),
+        Some(0x8dde),
+        Some(0x8dce),
+        Some(0x8dcf),
+        Some(0x8ddb),
+        Some(0x8dc6),
+        Some(0x8dec),
+        Some(0x8df7),
+        Some(0x8df8),
+        Some(0x8de3),
+        Some(0x8df9),
+        Some(0x8dfb),
+        Some(0x8de4),
+        Some(0x8e09),
+        Some(0x8dfd),
+        Some(0x8e14),
+        Some(0x8e1d),
+        Some(0x8e1f),
+        Some(0x8e2c),
+        Some(0x8e2e),
+        Some(0x8e23),
+        Some(0x8e2f),
+        Some(0x8e3a),
+        Some(0x8e40),
+        Some(0x8e39),
+        Some(0x8e35),
+        Some(0x8e3d),
+        Some(0x8e31),
+        Some(0x8e49),
+        Some(0x8e41),
+        Some(0x8e42),
+        Some(0x8e51),
+        Some(0x8e52),
+        Some(0x8e4a),
+        Some(0x8e70),
+        Some(0x8e76),
+        Some(0x8e7c),
+        Some(0x8e6f),
+        Some(0x8e74),
+        Some(0x8e85),
+        Some(0x8e8f),
+        Some(0x8e94),
+        Some(0x8e90),
+        Some(0x8e9c),
+        Some(0x8e9e),
+        Some(0x8c78),
+        Some(0x8c82),
+        Some(0x8c8a),
+        Some(0x8c85),
+        Some(0x8c98),
+        Some(0x8c94),
+        Some(0x659b),
+        Some(0x89d6),
+        Some(0x89de),
+        Some(0x89da),
+        Some(0x89dc),
+    ];
+
+    const BASE_0XF6A0: [Option<u16>; 95] = [
+        None,
+        Some(0x89e5),
+        Some(0x89eb),
+        Some(0x89ef),
+        Some(0x8a3e),
+        Some(0x8b26),
+        Some(0x9753),
+        Some(0x96e9),
+        Some(0x96f3),
+        Some(0x96ef),
+        Some(0x9706),
+        Some(0x9701),
+        Some(0x9708),
+        Some(0x970f),
+        Some(0x970e),
+        Some(0x972a),
+        Some(0x972d),
+        Some(0x9730),
+        Some(0x973e),
+        Some(0x9f80),
+        Some(0x9f83),
+        Some(0x9f85),
+        Some(0x9f86),
+        Some(0x9f87),
+        Some(0x9f88),
+        Some(0x9f89),
+        Some(0x9f8a),
+        Some(0x9f8c),
+        Some(0x9efe),
+        Some(0x9f0b),
+        Some(0x9f0d),
+        Some(0x96b9),
+        Some(0x96bc),
+        Some(0x96bd),
+        Some(0x96ce),
+        Some(0x96d2),
+        Some(0x77bf),
+        Some(0x96e0),
+        Some(0x928e),
+        Some(0x92ae),
+        Some(0x92c8),
+        Some(0x933e),
+        Some(0x936a),
+        Some(0x93ca),
+        Some(0x938f),
+        Some(0x943e),
+        Some(0x946b),
+        Some(0x9c7f),
+        Some(0x9c82),
+        Some(0x9c85),
+        Some(0x9c86),
+        Some(0x9c87),
+        Some(0x9c88),
+        Some(0x7a23),
+        Some(0x9c8b),
+        Some(0x9c8e),
+        Some(0x9c90),
+        Some(0x9c91),
+        Some(0x9c92),
+        Some(0x9c94),
+        Some(0x9c95),
+        Some(0x9c9a),
+        Some(0x9c9b),
+        Some(0x9c9e),
+        Some(0x9c9f),
+        Some(0x9ca0),
+        Some(0x9ca1),
+        Some(0x9ca2),
+        Some(0x9ca3),
+        Some(0x9ca5),
+        Some(0x9ca6),
+        Some(0x9ca7),
+        Some(0x9ca8),
+        Some(0x9ca9),
+        Some(0x9cab),
+        Some(0x9cad),
+        Some(0x9cae),
+        Some(0x9cb0),
+        Some(0x9cb1),
+        Some(0x9cb2),
+        Some(0x9cb3),
+        Some(0x9cb4),
+        Some(0x9cb5),
+        Some(0x9cb6),
+        Some(0x9cb7),
+        Some(0x9cba),
+        Some(0x9cbb),
+        Some(0x9cbc),
+        Some(0x9cbd),
+        Some(0x9cc4),
+        Some(0x9cc5),
+        Some(0x9cc6),
+        Some(0x9cc7),
+        Some(0x9cca),
+        Some(0x9ccb),
+    ];
+
+    const BASE_0XF7A0: [Option<u16>; 95] = [
+        None,
+        Some(0x9ccc),
+        Some(0x9ccd),
+        Some(0x9cce),
+        Some(0x9ccf),
+        Some(0x9cd0),
+        Some(0x9cd3),
+        Some(0x9cd4),
+        Some(0x9cd5),
+        Some(0x9cd7),
+        Some(0x9cd8),
+        Some(0x9cd9),
+        Some(0x9cdc),
+        Some(0x9cdd),
+        Some(0x9cdf),
+        Some(0x9ce2),
+        Some(0x977c),
+        Some(0x9785),
+        Some(0x9791),
+        Some(0x9792),
+        Some(0x9794),
+        Some(0x97af),
+        Some(0x97ab),
+        Some(0x97a3),
+        Some(0x97b2),
+        Some(0x97b4),
+        Some(0x9ab1),
+        Some(0x9ab0),
+        Some(0x9ab7),
+        Some(0x9e58),
+        Some(0x9ab6),
+        Some(0x9aba),
+        Some(0x9abc),
+        Some(0x9ac1),
+        Some(0x9ac0),
+        Some(0x9ac5),
+        Some(0x9ac2),
+        Some(0x9acb),
+        Some(0x9acc),
+        Some(0x9ad1),
+        Some(0x9b45),
+        Some(0x9b43),
+        Some(0x9b47),
+        Some(0x9b49),
+        Some(0x9b48),
+        Some(0x9b4d),
+        Some(0x9b51),
+        Some(0x98e8),
+        Some(0x990d),
+        Some(0x992e),
+        Some(0x9955),
+        Some(0x9954),
+        Some(0x9adf),
+        Some(0x9ae1),
+        Some(0x9ae6),
+        Some(0x9aef),
+        Some(0x9aeb),
+        Some(0x9afb),
+        Some(0x9aed),
+        Some(0x9af9),
+        Some(0x9b08),
+        Some(0x9b0f),
+        Some(0x9b13),
+        Some(0x9b1f),
+        Some(0x9b23),
+        Some(0x9ebd),
+        Some(0x9ebe),
+        Some(0x7e3b),
+        Some(0x9e82),
+        Some(0x9e87),
+        Some(0x9e88),
+        Some(0x9e8b),
+        Some(0x9e92),
+        Some(0x93d6),
+        Some(0x9e9d),
+        Some(0x9e9f),
+        Some(0x9edb),
+        Some(0x9edc),
+        Some(0x9edd),
+        Some(0x9ee0),
+        Some(0x9edf),
+        Some(0x9ee2),
+        Some(0x9ee9),
+        Some(0x9ee7),
+        Some(0x9ee5),
+        Some(0x9eea),
+        Some(0x9eef),
+        Some(0x9f22),
+        Some(0x9f2c),
+        Some(0x9f2f),
+        Some(0x9f39),
+        Some(0x9f37),
+        Some(0x9f3d),
+        Some(0x9f3e),
+        Some(0x9f44),
+    ];
+
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderMacChineseSimplified<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.bytes.get(self.byte_index) {
+            Some(byte_value) => {
+                self.byte_index += 1;
+
+                let additional_byte_value: u8 = match *byte_value {
+                    0xa1..=0xf7 => match self.bytes.get(self.byte_index) {
+                        Some(value) => {
+                            self.byte_index += 1;
+
+                            *value
+                        }
+                        None => {
+                            return Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacChineseSimplified: 0x{:02x} as Unicode - missing additional byte",
+                                *byte_value
+                            ))));
+                        }
+                    },
+                    _ => {
+                        let code_point: Option<u16> = match *byte_value {
+                            0x00..=0x7f => Some(*byte_value as u16),
+                            0x80 => Some(0x00fc),
+                            0x81 => Some(0xf880),
+                            0x82 => Some(0xf881),
+                            0xa0 => Some(0x00a0),
+                            0xfd => Some(0x00a9),
+                            0xfe => Some(0x2122),
+                            0xff => Some(0x2026),
+                            _ => None,
+                        };
+                        return match code_point {
+                            Some(code_point) => Some(Ok(code_point as u32)),
+                            None => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacChineseSimplified: 0x{:02x} as Unicode",
+                                *byte_value
+                            )))),
+                        };
+                    }
+                };
+                let code_point: Option<u16> = match *byte_value {
+                    0xa1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XA1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa2 => match additional_byte_value {
+                        0xb0..0xfd => Self::BASE_0XA2B0[(additional_byte_value - 0xb0) as usize],
+                        _ => None,
+                    },
+                    0xa3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XA3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa4 => match additional_byte_value {
+                        0xa0..0xf4 => Self::BASE_0XA4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa5 => match additional_byte_value {
+                        0xa0..0xf7 => Self::BASE_0XA5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa6 => match additional_byte_value {
+                        0xa0..0xf6 => Self::BASE_0XA6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa7 => match additional_byte_value {
+                        0xa0..0xf2 => Self::BASE_0XA7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa8 => match additional_byte_value {
+                        0xa0..0xea => Self::BASE_0XA8A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xa9 => match additional_byte_value {
+                        0xa0..0xf0 => Self::BASE_0XA9A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb0 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB0A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb2 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB2A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb4 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb5 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb6 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb7 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb8 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB8A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xb9 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XB9A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xba => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBAA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xbb => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBBA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xbc => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBCA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xbd => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBDA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xbe => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBEA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xbf => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XBFA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc0 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC0A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc2 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC2A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc4 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc5 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc6 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc7 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc8 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC8A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xc9 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XC9A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xca => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCAA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xcb => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCBA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xcc => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCCA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xcd => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCDA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xce => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCEA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xcf => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XCFA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd0 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD0A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd2 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD2A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd4 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd5 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd6 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd7 => match additional_byte_value {
+                        0xa0..0xfa => Self::BASE_0XD7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd8 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD8A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xd9 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XD9A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xda => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDAA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xdb => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDBA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xdc => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDCA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xdd => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDDA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xde => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDEA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xdf => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XDFA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe0 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE0A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe2 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE2A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe4 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe5 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe6 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe7 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe8 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE8A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xe9 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XE9A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xea => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XEAA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xeb => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XEBA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xec => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XECA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xed => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XEDA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xee => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XEEA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xef => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XEFA0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf0 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF0A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf1 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF1A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf2 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF2A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf3 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF3A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf4 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF4A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf5 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF5A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf6 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF6A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    0xf7 => match additional_byte_value {
+                        0xa0..=0xfe => Self::BASE_0XF7A0[(additional_byte_value - 0xa0) as usize],
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match code_point {
+                    Some(code_point) => Some(Ok(code_point as u32)),
+                    None => Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to decode MacChineseSimplified: 0x{:02x}, 0x{:02x} as Unicode",
+                        *byte_value, additional_byte_value
+                    )))),
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+/// MacChineseSimplified encoder.
+pub struct EncoderMacChineseSimplified<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderMacChineseSimplified<'a> {
+    const BASE_0X00A0: [Option<&'static [u8]>; 24] = [
+        Some(&[0xa0]),
+        None,
+        Some(&[0xa1, 0xe9]),
+        Some(&[0xa1, 0xea]),
+        Some(&[0xa1, 0xe8]),
+        Some(&[0xa3, 0xa4]),
+        None,
+        Some(&[0xa1, 0xec]),
+        Some(&[0xa1, 0xa7]),
+        Some(&[0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xe3]),
+        Some(&[0xa1, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xa4]),
+    ];
+
+    const BASE_0X00D0: [Option<&'static [u8]>; 56] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa4]),
+        Some(&[0xa8, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa8]),
+        Some(&[0xa8, 0xa6]),
+        Some(&[0xa8, 0xba]),
+        None,
+        Some(&[0xa8, 0xac]),
+        Some(&[0xa8, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xb0]),
+        Some(&[0xa8, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xc2]),
+        None,
+        Some(&[0xa8, 0xb4]),
+        Some(&[0xa8, 0xb2]),
+        None,
+        Some(&[0xa8, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0110: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0140: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xad]),
+        None,
+        None,
+    ];
+
+    const BASE_0X01C8: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa3]),
+        None,
+        Some(&[0xa8, 0xab]),
+        None,
+        Some(&[0xa8, 0xaf]),
+        None,
+        Some(&[0xa8, 0xb3]),
+        None,
+        Some(&[0xa8, 0xb5]),
+        None,
+        Some(&[0xa8, 0xb6]),
+        None,
+        Some(&[0xa8, 0xb7]),
+        None,
+        Some(&[0xa8, 0xb8]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0390: [Option<&'static [u8]>; 64] = [
+        None,
+        Some(&[0xa6, 0xa1]),
+        Some(&[0xa6, 0xa2]),
+        Some(&[0xa6, 0xa3]),
+        Some(&[0xa6, 0xa4]),
+        Some(&[0xa6, 0xa5]),
+        Some(&[0xa6, 0xa6]),
+        Some(&[0xa6, 0xa7]),
+        Some(&[0xa6, 0xa8]),
+        Some(&[0xa6, 0xa9]),
+        Some(&[0xa6, 0xaa]),
+        Some(&[0xa6, 0xab]),
+        Some(&[0xa6, 0xac]),
+        Some(&[0xa6, 0xad]),
+        Some(&[0xa6, 0xae]),
+        Some(&[0xa6, 0xaf]),
+        Some(&[0xa6, 0xb0]),
+        Some(&[0xa6, 0xb1]),
+        None,
+        Some(&[0xa6, 0xb2]),
+        Some(&[0xa6, 0xb3]),
+        Some(&[0xa6, 0xb4]),
+        Some(&[0xa6, 0xb5]),
+        Some(&[0xa6, 0xb6]),
+        Some(&[0xa6, 0xb7]),
+        Some(&[0xa6, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xc1]),
+        Some(&[0xa6, 0xc2]),
+        Some(&[0xa6, 0xc3]),
+        Some(&[0xa6, 0xc4]),
+        Some(&[0xa6, 0xc5]),
+        Some(&[0xa6, 0xc6]),
+        Some(&[0xa6, 0xc7]),
+        Some(&[0xa6, 0xc8]),
+        Some(&[0xa6, 0xc9]),
+        Some(&[0xa6, 0xca]),
+        Some(&[0xa6, 0xcb]),
+        Some(&[0xa6, 0xcc]),
+        Some(&[0xa6, 0xcd]),
+        Some(&[0xa6, 0xce]),
+        Some(&[0xa6, 0xcf]),
+        Some(&[0xa6, 0xd0]),
+        Some(&[0xa6, 0xd1]),
+        None,
+        Some(&[0xa6, 0xd2]),
+        Some(&[0xa6, 0xd3]),
+        Some(&[0xa6, 0xd4]),
+        Some(&[0xa6, 0xd5]),
+        Some(&[0xa6, 0xd6]),
+        Some(&[0xa6, 0xd7]),
+        Some(&[0xa6, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0400: [Option<&'static [u8]>; 88] = [
+        None,
+        Some(&[0xa7, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xa1]),
+        Some(&[0xa7, 0xa2]),
+        Some(&[0xa7, 0xa3]),
+        Some(&[0xa7, 0xa4]),
+        Some(&[0xa7, 0xa5]),
+        Some(&[0xa7, 0xa6]),
+        Some(&[0xa7, 0xa8]),
+        Some(&[0xa7, 0xa9]),
+        Some(&[0xa7, 0xaa]),
+        Some(&[0xa7, 0xab]),
+        Some(&[0xa7, 0xac]),
+        Some(&[0xa7, 0xad]),
+        Some(&[0xa7, 0xae]),
+        Some(&[0xa7, 0xaf]),
+        Some(&[0xa7, 0xb0]),
+        Some(&[0xa7, 0xb1]),
+        Some(&[0xa7, 0xb2]),
+        Some(&[0xa7, 0xb3]),
+        Some(&[0xa7, 0xb4]),
+        Some(&[0xa7, 0xb5]),
+        Some(&[0xa7, 0xb6]),
+        Some(&[0xa7, 0xb7]),
+        Some(&[0xa7, 0xb8]),
+        Some(&[0xa7, 0xb9]),
+        Some(&[0xa7, 0xba]),
+        Some(&[0xa7, 0xbb]),
+        Some(&[0xa7, 0xbc]),
+        Some(&[0xa7, 0xbd]),
+        Some(&[0xa7, 0xbe]),
+        Some(&[0xa7, 0xbf]),
+        Some(&[0xa7, 0xc0]),
+        Some(&[0xa7, 0xc1]),
+        Some(&[0xa7, 0xd1]),
+        Some(&[0xa7, 0xd2]),
+        Some(&[0xa7, 0xd3]),
+        Some(&[0xa7, 0xd4]),
+        Some(&[0xa7, 0xd5]),
+        Some(&[0xa7, 0xd6]),
+        Some(&[0xa7, 0xd8]),
+        Some(&[0xa7, 0xd9]),
+        Some(&[0xa7, 0xda]),
+        Some(&[0xa7, 0xdb]),
+        Some(&[0xa7, 0xdc]),
+        Some(&[0xa7, 0xdd]),
+        Some(&[0xa7, 0xde]),
+        Some(&[0xa7, 0xdf]),
+        Some(&[0xa7, 0xe0]),
+        Some(&[0xa7, 0xe1]),
+        Some(&[0xa7, 0xe2]),
+        Some(&[0xa7, 0xe3]),
+        Some(&[0xa7, 0xe4]),
+        Some(&[0xa7, 0xe5]),
+        Some(&[0xa7, 0xe6]),
+        Some(&[0xa7, 0xe7]),
+        Some(&[0xa7, 0xe8]),
+        Some(&[0xa7, 0xe9]),
+        Some(&[0xa7, 0xea]),
+        Some(&[0xa7, 0xeb]),
+        Some(&[0xa7, 0xec]),
+        Some(&[0xa7, 0xed]),
+        Some(&[0xa7, 0xee]),
+        Some(&[0xa7, 0xef]),
+        Some(&[0xa7, 0xf0]),
+        Some(&[0xa7, 0xf1]),
+        None,
+        Some(&[0xa7, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2010: [Option<&'static [u8]>; 48] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xaa]),
+        None,
+        Some(&[0xa1, 0xac]),
+        None,
+        Some(&[0xa1, 0xae]),
+        Some(&[0xa1, 0xaf]),
+        None,
+        None,
+        Some(&[0xa1, 0xb0]),
+        Some(&[0xa1, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xff]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xeb]),
+        None,
+        Some(&[0xa1, 0xe4]),
+        Some(&[0xa1, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf9]),
+        None,
+        None,
+        Some(&[0xa3, 0xfe]),
+        None,
+    ];
+
+    const BASE_0X2160: [Option<&'static [u8]>; 16] = [
+        Some(&[0xa2, 0xf1]),
+        Some(&[0xa2, 0xf2]),
+        Some(&[0xa2, 0xf3]),
+        Some(&[0xa2, 0xf4]),
+        Some(&[0xa2, 0xf5]),
+        Some(&[0xa2, 0xf6]),
+        Some(&[0xa2, 0xf7]),
+        Some(&[0xa2, 0xf8]),
+        Some(&[0xa2, 0xf9]),
+        Some(&[0xa2, 0xfa]),
+        Some(&[0xa2, 0xfb]),
+        Some(&[0xa2, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2190: [Option<&'static [u8]>; 8] = [
+        Some(&[0xa1, 0xfb]),
+        Some(&[0xa1, 0xfc]),
+        Some(&[0xa1, 0xfa]),
+        Some(&[0xa1, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2208: [Option<&'static [u8]>; 72] = [
+        Some(&[0xa1, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xc7]),
+        None,
+        Some(&[0xa1, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xcc]),
+        None,
+        None,
+        Some(&[0xa1, 0xd8]),
+        Some(&[0xa1, 0xde]),
+        None,
+        Some(&[0xa1, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xce]),
+        None,
+        Some(&[0xa1, 0xc4]),
+        Some(&[0xa1, 0xc5]),
+        Some(&[0xa1, 0xc9]),
+        Some(&[0xa1, 0xc8]),
+        Some(&[0xa1, 0xd2]),
+        None,
+        None,
+        Some(&[0xa1, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xe0]),
+        Some(&[0xa1, 0xdf]),
+        Some(&[0xa1, 0xc3]),
+        Some(&[0xa1, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd5]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2260: [Option<&'static [u8]>; 16] = [
+        Some(&[0xa1, 0xd9]),
+        Some(&[0xa1, 0xd4]),
+        None,
+        None,
+        Some(&[0xa1, 0xdc]),
+        Some(&[0xa1, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xda]),
+        Some(&[0xa1, 0xdb]),
+    ];
+
+    const BASE_0X2460: [Option<&'static [u8]>; 64] = [
+        Some(&[0xa2, 0xd9]),
+        Some(&[0xa2, 0xda]),
+        Some(&[0xa2, 0xdb]),
+        Some(&[0xa2, 0xdc]),
+        Some(&[0xa2, 0xdd]),
+        Some(&[0xa2, 0xde]),
+        Some(&[0xa2, 0xdf]),
+        Some(&[0xa2, 0xe0]),
+        Some(&[0xa2, 0xe1]),
+        Some(&[0xa2, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0xc5]),
+        Some(&[0xa2, 0xc6]),
+        Some(&[0xa2, 0xc7]),
+        Some(&[0xa2, 0xc8]),
+        Some(&[0xa2, 0xc9]),
+        Some(&[0xa2, 0xca]),
+        Some(&[0xa2, 0xcb]),
+        Some(&[0xa2, 0xcc]),
+        Some(&[0xa2, 0xcd]),
+        Some(&[0xa2, 0xce]),
+        Some(&[0xa2, 0xcf]),
+        Some(&[0xa2, 0xd0]),
+        Some(&[0xa2, 0xd1]),
+        Some(&[0xa2, 0xd2]),
+        Some(&[0xa2, 0xd3]),
+        Some(&[0xa2, 0xd4]),
+        Some(&[0xa2, 0xd5]),
+        Some(&[0xa2, 0xd6]),
+        Some(&[0xa2, 0xd7]),
+        Some(&[0xa2, 0xd8]),
+        Some(&[0xa2, 0xb1]),
+        Some(&[0xa2, 0xb2]),
+        Some(&[0xa2, 0xb3]),
+        Some(&[0xa2, 0xb4]),
+        Some(&[0xa2, 0xb5]),
+        Some(&[0xa2, 0xb6]),
+        Some(&[0xa2, 0xb7]),
+        Some(&[0xa2, 0xb8]),
+        Some(&[0xa2, 0xb9]),
+        Some(&[0xa2, 0xba]),
+        Some(&[0xa2, 0xbb]),
+        Some(&[0xa2, 0xbc]),
+        Some(&[0xa2, 0xbd]),
+        Some(&[0xa2, 0xbe]),
+        Some(&[0xa2, 0xbf]),
+        Some(&[0xa2, 0xc0]),
+        Some(&[0xa2, 0xc1]),
+        Some(&[0xa2, 0xc2]),
+        Some(&[0xa2, 0xc3]),
+        Some(&[0xa2, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2500: [Option<&'static [u8]>; 80] = [
+        Some(&[0xa9, 0xa4]),
+        Some(&[0xa9, 0xa5]),
+        Some(&[0xa9, 0xa6]),
+        Some(&[0xa9, 0xa7]),
+        Some(&[0xa9, 0xa8]),
+        Some(&[0xa9, 0xa9]),
+        Some(&[0xa9, 0xaa]),
+        Some(&[0xa9, 0xab]),
+        Some(&[0xa9, 0xac]),
+        Some(&[0xa9, 0xad]),
+        Some(&[0xa9, 0xae]),
+        Some(&[0xa9, 0xaf]),
+        Some(&[0xa9, 0xb0]),
+        Some(&[0xa9, 0xb1]),
+        Some(&[0xa9, 0xb2]),
+        Some(&[0xa9, 0xb3]),
+        Some(&[0xa9, 0xb4]),
+        Some(&[0xa9, 0xb5]),
+        Some(&[0xa9, 0xb6]),
+        Some(&[0xa9, 0xb7]),
+        Some(&[0xa9, 0xb8]),
+        Some(&[0xa9, 0xb9]),
+        Some(&[0xa9, 0xba]),
+        Some(&[0xa9, 0xbb]),
+        Some(&[0xa9, 0xbc]),
+        Some(&[0xa9, 0xbd]),
+        Some(&[0xa9, 0xbe]),
+        Some(&[0xa9, 0xbf]),
+        Some(&[0xa9, 0xc0]),
+        Some(&[0xa9, 0xc1]),
+        Some(&[0xa9, 0xc2]),
+        Some(&[0xa9, 0xc3]),
+        Some(&[0xa9, 0xc4]),
+        Some(&[0xa9, 0xc5]),
+        Some(&[0xa9, 0xc6]),
+        Some(&[0xa9, 0xc7]),
+        Some(&[0xa9, 0xc8]),
+        Some(&[0xa9, 0xc9]),
+        Some(&[0xa9, 0xca]),
+        Some(&[0xa9, 0xcb]),
+        Some(&[0xa9, 0xcc]),
+        Some(&[0xa9, 0xcd]),
+        Some(&[0xa9, 0xce]),
+        Some(&[0xa9, 0xcf]),
+        Some(&[0xa9, 0xd0]),
+        Some(&[0xa9, 0xd1]),
+        Some(&[0xa9, 0xd2]),
+        Some(&[0xa9, 0xd3]),
+        Some(&[0xa9, 0xd4]),
+        Some(&[0xa9, 0xd5]),
+        Some(&[0xa9, 0xd6]),
+        Some(&[0xa9, 0xd7]),
+        Some(&[0xa9, 0xd8]),
+        Some(&[0xa9, 0xd9]),
+        Some(&[0xa9, 0xda]),
+        Some(&[0xa9, 0xdb]),
+        Some(&[0xa9, 0xdc]),
+        Some(&[0xa9, 0xdd]),
+        Some(&[0xa9, 0xde]),
+        Some(&[0xa9, 0xdf]),
+        Some(&[0xa9, 0xe0]),
+        Some(&[0xa9, 0xe1]),
+        Some(&[0xa9, 0xe2]),
+        Some(&[0xa9, 0xe3]),
+        Some(&[0xa9, 0xe4]),
+        Some(&[0xa9, 0xe5]),
+        Some(&[0xa9, 0xe6]),
+        Some(&[0xa9, 0xe7]),
+        Some(&[0xa9, 0xe8]),
+        Some(&[0xa9, 0xe9]),
+        Some(&[0xa9, 0xea]),
+        Some(&[0xa9, 0xeb]),
+        Some(&[0xa9, 0xec]),
+        Some(&[0xa9, 0xed]),
+        Some(&[0xa9, 0xee]),
+        Some(&[0xa9, 0xef]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X25C0: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf4]),
+        Some(&[0xa1, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf0]),
+        None,
+        None,
+        Some(&[0xa1, 0xf2]),
+        Some(&[0xa1, 0xf1]),
+    ];
+
+    const BASE_0X3000: [Option<&'static [u8]>; 32] = [
+        Some(&[0xa1, 0xa1]),
+        Some(&[0xa1, 0xa2]),
+        Some(&[0xa1, 0xa3]),
+        Some(&[0xa1, 0xa8]),
+        None,
+        Some(&[0xa1, 0xa9]),
+        None,
+        None,
+        Some(&[0xa1, 0xb4]),
+        Some(&[0xa1, 0xb5]),
+        Some(&[0xa1, 0xb6]),
+        Some(&[0xa1, 0xb7]),
+        Some(&[0xa1, 0xb8]),
+        Some(&[0xa1, 0xb9]),
+        Some(&[0xa1, 0xba]),
+        Some(&[0xa1, 0xbb]),
+        Some(&[0xa1, 0xbe]),
+        Some(&[0xa1, 0xbf]),
+        None,
+        Some(&[0xa1, 0xfe]),
+        Some(&[0xa1, 0xb2]),
+        Some(&[0xa1, 0xb3]),
+        Some(&[0xa1, 0xbc]),
+        Some(&[0xa1, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xab]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3040: [Option<&'static [u8]>; 240] = [
+        None,
+        Some(&[0xa4, 0xa1]),
+        Some(&[0xa4, 0xa2]),
+        Some(&[0xa4, 0xa3]),
+        Some(&[0xa4, 0xa4]),
+        Some(&[0xa4, 0xa5]),
+        Some(&[0xa4, 0xa6]),
+        Some(&[0xa4, 0xa7]),
+        Some(&[0xa4, 0xa8]),
+        Some(&[0xa4, 0xa9]),
+        Some(&[0xa4, 0xaa]),
+        Some(&[0xa4, 0xab]),
+        Some(&[0xa4, 0xac]),
+        Some(&[0xa4, 0xad]),
+        Some(&[0xa4, 0xae]),
+        Some(&[0xa4, 0xaf]),
+        Some(&[0xa4, 0xb0]),
+        Some(&[0xa4, 0xb1]),
+        Some(&[0xa4, 0xb2]),
+        Some(&[0xa4, 0xb3]),
+        Some(&[0xa4, 0xb4]),
+        Some(&[0xa4, 0xb5]),
+        Some(&[0xa4, 0xb6]),
+        Some(&[0xa4, 0xb7]),
+        Some(&[0xa4, 0xb8]),
+        Some(&[0xa4, 0xb9]),
+        Some(&[0xa4, 0xba]),
+        Some(&[0xa4, 0xbb]),
+        Some(&[0xa4, 0xbc]),
+        Some(&[0xa4, 0xbd]),
+        Some(&[0xa4, 0xbe]),
+        Some(&[0xa4, 0xbf]),
+        Some(&[0xa4, 0xc0]),
+        Some(&[0xa4, 0xc1]),
+        Some(&[0xa4, 0xc2]),
+        Some(&[0xa4, 0xc3]),
+        Some(&[0xa4, 0xc4]),
+        Some(&[0xa4, 0xc5]),
+        Some(&[0xa4, 0xc6]),
+        Some(&[0xa4, 0xc7]),
+        Some(&[0xa4, 0xc8]),
+        Some(&[0xa4, 0xc9]),
+        Some(&[0xa4, 0xca]),
+        Some(&[0xa4, 0xcb]),
+        Some(&[0xa4, 0xcc]),
+        Some(&[0xa4, 0xcd]),
+        Some(&[0xa4, 0xce]),
+        Some(&[0xa4, 0xcf]),
+        Some(&[0xa4, 0xd0]),
+        Some(&[0xa4, 0xd1]),
+        Some(&[0xa4, 0xd2]),
+        Some(&[0xa4, 0xd3]),
+        Some(&[0xa4, 0xd4]),
+        Some(&[0xa4, 0xd5]),
+        Some(&[0xa4, 0xd6]),
+        Some(&[0xa4, 0xd7]),
+        Some(&[0xa4, 0xd8]),
+        Some(&[0xa4, 0xd9]),
+        Some(&[0xa4, 0xda]),
+        Some(&[0xa4, 0xdb]),
+        Some(&[0xa4, 0xdc]),
+        Some(&[0xa4, 0xdd]),
+        Some(&[0xa4, 0xde]),
+        Some(&[0xa4, 0xdf]),
+        Some(&[0xa4, 0xe0]),
+        Some(&[0xa4, 0xe1]),
+        Some(&[0xa4, 0xe2]),
+        Some(&[0xa4, 0xe3]),
+        Some(&[0xa4, 0xe4]),
+        Some(&[0xa4, 0xe5]),
+        Some(&[0xa4, 0xe6]),
+        Some(&[0xa4, 0xe7]),
+        Some(&[0xa4, 0xe8]),
+        Some(&[0xa4, 0xe9]),
+        Some(&[0xa4, 0xea]),
+        Some(&[0xa4, 0xeb]),
+        Some(&[0xa4, 0xec]),
+        Some(&[0xa4, 0xed]),
+        Some(&[0xa4, 0xee]),
+        Some(&[0xa4, 0xef]),
+        Some(&[0xa4, 0xf0]),
+        Some(&[0xa4, 0xf1]),
+        Some(&[0xa4, 0xf2]),
+        Some(&[0xa4, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xa1]),
+        Some(&[0xa5, 0xa2]),
+        Some(&[0xa5, 0xa3]),
+        Some(&[0xa5, 0xa4]),
+        Some(&[0xa5, 0xa5]),
+        Some(&[0xa5, 0xa6]),
+        Some(&[0xa5, 0xa7]),
+        Some(&[0xa5, 0xa8]),
+        Some(&[0xa5, 0xa9]),
+        Some(&[0xa5, 0xaa]),
+        Some(&[0xa5, 0xab]),
+        Some(&[0xa5, 0xac]),
+        Some(&[0xa5, 0xad]),
+        Some(&[0xa5, 0xae]),
+        Some(&[0xa5, 0xaf]),
+        Some(&[0xa5, 0xb0]),
+        Some(&[0xa5, 0xb1]),
+        Some(&[0xa5, 0xb2]),
+        Some(&[0xa5, 0xb3]),
+        Some(&[0xa5, 0xb4]),
+        Some(&[0xa5, 0xb5]),
+        Some(&[0xa5, 0xb6]),
+        Some(&[0xa5, 0xb7]),
+        Some(&[0xa5, 0xb8]),
+        Some(&[0xa5, 0xb9]),
+        Some(&[0xa5, 0xba]),
+        Some(&[0xa5, 0xbb]),
+        Some(&[0xa5, 0xbc]),
+        Some(&[0xa5, 0xbd]),
+        Some(&[0xa5, 0xbe]),
+        Some(&[0xa5, 0xbf]),
+        Some(&[0xa5, 0xc0]),
+        Some(&[0xa5, 0xc1]),
+        Some(&[0xa5, 0xc2]),
+        Some(&[0xa5, 0xc3]),
+        Some(&[0xa5, 0xc4]),
+        Some(&[0xa5, 0xc5]),
+        Some(&[0xa5, 0xc6]),
+        Some(&[0xa5, 0xc7]),
+        Some(&[0xa5, 0xc8]),
+        Some(&[0xa5, 0xc9]),
+        Some(&[0xa5, 0xca]),
+        Some(&[0xa5, 0xcb]),
+        Some(&[0xa5, 0xcc]),
+        Some(&[0xa5, 0xcd]),
+        Some(&[0xa5, 0xce]),
+        Some(&[0xa5, 0xcf]),
+        Some(&[0xa5, 0xd0]),
+        Some(&[0xa5, 0xd1]),
+        Some(&[0xa5, 0xd2]),
+        Some(&[0xa5, 0xd3]),
+        Some(&[0xa5, 0xd4]),
+        Some(&[0xa5, 0xd5]),
+        Some(&[0xa5, 0xd6]),
+        Some(&[0xa5, 0xd7]),
+        Some(&[0xa5, 0xd8]),
+        Some(&[0xa5, 0xd9]),
+        Some(&[0xa5, 0xda]),
+        Some(&[0xa5, 0xdb]),
+        Some(&[0xa5, 0xdc]),
+        Some(&[0xa5, 0xdd]),
+        Some(&[0xa5, 0xde]),
+        Some(&[0xa5, 0xdf]),
+        Some(&[0xa5, 0xe0]),
+        Some(&[0xa5, 0xe1]),
+        Some(&[0xa5, 0xe2]),
+        Some(&[0xa5, 0xe3]),
+        Some(&[0xa5, 0xe4]),
+        Some(&[0xa5, 0xe5]),
+        Some(&[0xa5, 0xe6]),
+        Some(&[0xa5, 0xe7]),
+        Some(&[0xa5, 0xe8]),
+        Some(&[0xa5, 0xe9]),
+        Some(&[0xa5, 0xea]),
+        Some(&[0xa5, 0xeb]),
+        Some(&[0xa5, 0xec]),
+        Some(&[0xa5, 0xed]),
+        Some(&[0xa5, 0xee]),
+        Some(&[0xa5, 0xef]),
+        Some(&[0xa5, 0xf0]),
+        Some(&[0xa5, 0xf1]),
+        Some(&[0xa5, 0xf2]),
+        Some(&[0xa5, 0xf3]),
+        Some(&[0xa5, 0xf4]),
+        Some(&[0xa5, 0xf5]),
+        Some(&[0xa5, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xc5]),
+        Some(&[0xa8, 0xc6]),
+        Some(&[0xa8, 0xc7]),
+        Some(&[0xa8, 0xc8]),
+        Some(&[0xa8, 0xc9]),
+        Some(&[0xa8, 0xca]),
+        Some(&[0xa8, 0xcb]),
+        Some(&[0xa8, 0xcc]),
+        Some(&[0xa8, 0xcd]),
+        Some(&[0xa8, 0xce]),
+        Some(&[0xa8, 0xcf]),
+        Some(&[0xa8, 0xd0]),
+        Some(&[0xa8, 0xd1]),
+        Some(&[0xa8, 0xd2]),
+        Some(&[0xa8, 0xd3]),
+        Some(&[0xa8, 0xd4]),
+        Some(&[0xa8, 0xd5]),
+        Some(&[0xa8, 0xd6]),
+        Some(&[0xa8, 0xd7]),
+        Some(&[0xa8, 0xd8]),
+        Some(&[0xa8, 0xd9]),
+        Some(&[0xa8, 0xda]),
+        Some(&[0xa8, 0xdb]),
+        Some(&[0xa8, 0xdc]),
+        Some(&[0xa8, 0xdd]),
+        Some(&[0xa8, 0xde]),
+        Some(&[0xa8, 0xdf]),
+        Some(&[0xa8, 0xe0]),
+        Some(&[0xa8, 0xe1]),
+        Some(&[0xa8, 0xe2]),
+        Some(&[0xa8, 0xe3]),
+        Some(&[0xa8, 0xe4]),
+        Some(&[0xa8, 0xe5]),
+        Some(&[0xa8, 0xe6]),
+        Some(&[0xa8, 0xe7]),
+        Some(&[0xa8, 0xe8]),
+        Some(&[0xa8, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3220: [Option<&'static [u8]>; 16] = [
+        Some(&[0xa2, 0xe5]),
+        Some(&[0xa2, 0xe6]),
+        Some(&[0xa2, 0xe7]),
+        Some(&[0xa2, 0xe8]),
+        Some(&[0xa2, 0xe9]),
+        Some(&[0xa2, 0xea]),
+        Some(&[0xa2, 0xeb]),
+        Some(&[0xa2, 0xec]),
+        Some(&[0xa2, 0xed]),
+        Some(&[0xa2, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X4E00: [Option<&'static [u8]>; 656] = [
+        Some(&[0xd2, 0xbb]),
+        Some(&[0xb6, 0xa1]),
+        None,
+        Some(&[0xc6, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xf2]),
+        Some(&[0xd5, 0xc9]),
+        Some(&[0xc8, 0xfd]),
+        Some(&[0xc9, 0xcf]),
+        Some(&[0xcf, 0xc2]),
+        Some(&[0xd8, 0xa2]),
+        Some(&[0xb2, 0xbb]),
+        Some(&[0xd3, 0xeb]),
+        None,
+        Some(&[0xd8, 0xa4]),
+        Some(&[0xb3, 0xf3]),
+        None,
+        Some(&[0xd7, 0xa8]),
+        Some(&[0xc7, 0xd2]),
+        Some(&[0xd8, 0xa7]),
+        Some(&[0xca, 0xc0]),
+        None,
+        Some(&[0xc7, 0xf0]),
+        Some(&[0xb1, 0xfb]),
+        Some(&[0xd2, 0xb5]),
+        Some(&[0xb4, 0xd4]),
+        Some(&[0xb6, 0xab]),
+        Some(&[0xcb, 0xbf]),
+        Some(&[0xd8, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xaa]),
+        None,
+        Some(&[0xc1, 0xbd]),
+        Some(&[0xd1, 0xcf]),
+        None,
+        Some(&[0xc9, 0xa5]),
+        Some(&[0xd8, 0xad]),
+        None,
+        Some(&[0xb8, 0xf6]),
+        Some(&[0xd1, 0xbe]),
+        Some(&[0xe3, 0xdc]),
+        Some(&[0xd6, 0xd0]),
+        None,
+        None,
+        Some(&[0xb7, 0xe1]),
+        None,
+        Some(&[0xb4, 0xae]),
+        None,
+        Some(&[0xc1, 0xd9]),
+        None,
+        Some(&[0xd8, 0xbc]),
+        None,
+        Some(&[0xcd, 0xe8]),
+        Some(&[0xb5, 0xa4]),
+        Some(&[0xce, 0xaa]),
+        Some(&[0xd6, 0xf7]),
+        None,
+        Some(&[0xc0, 0xf6]),
+        Some(&[0xbe, 0xd9]),
+        Some(&[0xd8, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xcb]),
+        None,
+        Some(&[0xbe, 0xc3]),
+        None,
+        Some(&[0xd8, 0xb1]),
+        Some(&[0xc3, 0xb4]),
+        Some(&[0xd2, 0xe5]),
+        None,
+        Some(&[0xd6, 0xae]),
+        Some(&[0xce, 0xda]),
+        Some(&[0xd5, 0xa7]),
+        Some(&[0xba, 0xf5]),
+        Some(&[0xb7, 0xa6]),
+        Some(&[0xc0, 0xd6]),
+        None,
+        Some(&[0xc6, 0xb9]),
+        Some(&[0xc5, 0xd2]),
+        Some(&[0xc7, 0xc7]),
+        None,
+        Some(&[0xb9, 0xd4]),
+        None,
+        Some(&[0xb3, 0xcb]),
+        Some(&[0xd2, 0xd2]),
+        None,
+        None,
+        Some(&[0xd8, 0xbf]),
+        Some(&[0xbe, 0xc5]),
+        Some(&[0xc6, 0xf2]),
+        Some(&[0xd2, 0xb2]),
+        Some(&[0xcf, 0xb0]),
+        Some(&[0xcf, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xe9]),
+        None,
+        None,
+        Some(&[0xd8, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xf2]),
+        Some(&[0xc2, 0xd2]),
+        None,
+        Some(&[0xc8, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xcb]),
+        None,
+        Some(&[0xd3, 0xe8]),
+        Some(&[0xd5, 0xf9]),
+        None,
+        Some(&[0xca, 0xc2]),
+        Some(&[0xb6, 0xfe]),
+        Some(&[0xd8, 0xa1]),
+        Some(&[0xd3, 0xda]),
+        Some(&[0xbf, 0xf7]),
+        None,
+        Some(&[0xd4, 0xc6]),
+        Some(&[0xbb, 0xa5]),
+        Some(&[0xd8, 0xc1]),
+        Some(&[0xce, 0xe5]),
+        Some(&[0xbe, 0xae]),
+        None,
+        None,
+        Some(&[0xd8, 0xa8]),
+        None,
+        Some(&[0xd1, 0xc7]),
+        Some(&[0xd0, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xbd]),
+        Some(&[0xd9, 0xef]),
+        Some(&[0xcd, 0xf6]),
+        Some(&[0xbf, 0xba]),
+        None,
+        Some(&[0xbd, 0xbb]),
+        Some(&[0xba, 0xa5]),
+        Some(&[0xd2, 0xe0]),
+        Some(&[0xb2, 0xfa]),
+        Some(&[0xba, 0xe0]),
+        Some(&[0xc4, 0xb6]),
+        None,
+        Some(&[0xcf, 0xed]),
+        Some(&[0xbe, 0xa9]),
+        Some(&[0xcd, 0xa4]),
+        Some(&[0xc1, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xd7]),
+        Some(&[0xd9, 0xf1]),
+        None,
+        Some(&[0xd9, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xcb]),
+        Some(&[0xd8, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xda]),
+        Some(&[0xca, 0xb2]),
+        Some(&[0xc8, 0xca]),
+        Some(&[0xd8, 0xec]),
+        Some(&[0xd8, 0xea]),
+        Some(&[0xd8, 0xc6]),
+        Some(&[0xbd, 0xf6]),
+        Some(&[0xc6, 0xcd]),
+        Some(&[0xb3, 0xf0]),
+        None,
+        Some(&[0xd8, 0xeb]),
+        Some(&[0xbd, 0xf1]),
+        Some(&[0xbd, 0xe9]),
+        None,
+        Some(&[0xc8, 0xd4]),
+        Some(&[0xb4, 0xd3]),
+        None,
+        None,
+        Some(&[0xc2, 0xd8]),
+        None,
+        Some(&[0xb2, 0xd6]),
+        Some(&[0xd7, 0xd0]),
+        Some(&[0xca, 0xcb]),
+        Some(&[0xcb, 0xfb]),
+        Some(&[0xd5, 0xcc]),
+        Some(&[0xb8, 0xb6]),
+        Some(&[0xcf, 0xc9]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xda]),
+        Some(&[0xd8, 0xf0]),
+        Some(&[0xc7, 0xaa]),
+        None,
+        Some(&[0xd8, 0xee]),
+        None,
+        Some(&[0xb4, 0xfa]),
+        Some(&[0xc1, 0xee]),
+        Some(&[0xd2, 0xd4]),
+        None,
+        None,
+        Some(&[0xd8, 0xed]),
+        None,
+        Some(&[0xd2, 0xc7]),
+        Some(&[0xd8, 0xef]),
+        Some(&[0xc3, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf6]),
+        None,
+        Some(&[0xd6, 0xd9]),
+        Some(&[0xd8, 0xf2]),
+        None,
+        Some(&[0xd8, 0xf5]),
+        Some(&[0xbc, 0xfe]),
+        Some(&[0xbc, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xce]),
+        None,
+        Some(&[0xb7, 0xdd]),
+        None,
+        Some(&[0xb7, 0xc2]),
+        None,
+        Some(&[0xc6, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xf8]),
+        Some(&[0xd2, 0xc1]),
+        None,
+        None,
+        Some(&[0xce, 0xe9]),
+        Some(&[0xbc, 0xbf]),
+        Some(&[0xb7, 0xfc]),
+        Some(&[0xb7, 0xa5]),
+        Some(&[0xd0, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xda]),
+        Some(&[0xd3, 0xc5]),
+        Some(&[0xbb, 0xef]),
+        Some(&[0xbb, 0xe1]),
+        Some(&[0xd8, 0xf1]),
+        None,
+        None,
+        Some(&[0xc9, 0xa1]),
+        Some(&[0xce, 0xb0]),
+        Some(&[0xb4, 0xab]),
+        None,
+        Some(&[0xd8, 0xf3]),
+        None,
+        Some(&[0xc9, 0xcb]),
+        Some(&[0xd8, 0xf6]),
+        Some(&[0xc2, 0xd7]),
+        Some(&[0xd8, 0xf7]),
+        None,
+        None,
+        Some(&[0xce, 0xb1]),
+        Some(&[0xd8, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xae]),
+        Some(&[0xb9, 0xc0]),
+        None,
+        Some(&[0xd9, 0xa3]),
+        None,
+        Some(&[0xb0, 0xe9]),
+        None,
+        Some(&[0xc1, 0xe6]),
+        None,
+        Some(&[0xc9, 0xec]),
+        None,
+        Some(&[0xcb, 0xc5]),
+        None,
+        Some(&[0xcb, 0xc6]),
+        Some(&[0xd9, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xe8]),
+        None,
+        None,
+        Some(&[0xb5, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xbb]),
+        Some(&[0xb5, 0xcd]),
+        Some(&[0xd7, 0xa1]),
+        Some(&[0xd7, 0xf4]),
+        Some(&[0xd3, 0xd3]),
+        None,
+        Some(&[0xcc, 0xe5]),
+        None,
+        Some(&[0xba, 0xce]),
+        None,
+        Some(&[0xd9, 0xa2]),
+        Some(&[0xd9, 0xdc]),
+        Some(&[0xd3, 0xe0]),
+        Some(&[0xd8, 0xfd]),
+        Some(&[0xb7, 0xf0]),
+        Some(&[0xd7, 0xf7]),
+        Some(&[0xd8, 0xfe]),
+        Some(&[0xd8, 0xfa]),
+        Some(&[0xd9, 0xa1]),
+        Some(&[0xc4, 0xe3]),
+        None,
+        None,
+        Some(&[0xd3, 0xb6]),
+        Some(&[0xd8, 0xf4]),
+        Some(&[0xd9, 0xdd]),
+        None,
+        Some(&[0xd8, 0xfb]),
+        None,
+        Some(&[0xc5, 0xe5]),
+        None,
+        None,
+        Some(&[0xc0, 0xd0]),
+        None,
+        None,
+        Some(&[0xd1, 0xf0]),
+        Some(&[0xb0, 0xdb]),
+        None,
+        None,
+        Some(&[0xbc, 0xd1]),
+        Some(&[0xd9, 0xa6]),
+        None,
+        Some(&[0xd9, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xac]),
+        Some(&[0xd9, 0xae]),
+        None,
+        Some(&[0xd9, 0xab]),
+        Some(&[0xca, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xa9]),
+        Some(&[0xd6, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xde]),
+        Some(&[0xd9, 0xa8]),
+        None,
+        Some(&[0xc0, 0xfd]),
+        None,
+        Some(&[0xca, 0xcc]),
+        None,
+        Some(&[0xd9, 0xaa]),
+        None,
+        Some(&[0xd9, 0xa7]),
+        None,
+        None,
+        Some(&[0xd9, 0xb0]),
+        None,
+        None,
+        Some(&[0xb6, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xa9]),
+        None,
+        Some(&[0xd2, 0xc0]),
+        None,
+        None,
+        Some(&[0xcf, 0xc0]),
+        None,
+        None,
+        Some(&[0xc2, 0xc2]),
+        None,
+        Some(&[0xbd, 0xc4]),
+        Some(&[0xd5, 0xec]),
+        Some(&[0xb2, 0xe0]),
+        Some(&[0xc7, 0xc8]),
+        Some(&[0xbf, 0xeb]),
+        Some(&[0xd9, 0xad]),
+        None,
+        Some(&[0xd9, 0xaf]),
+        None,
+        Some(&[0xce, 0xea]),
+        Some(&[0xba, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xd9]),
+        Some(&[0xb6, 0xed]),
+        Some(&[0xd9, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xde]),
+        Some(&[0xc7, 0xce]),
+        Some(&[0xc0, 0xfe]),
+        Some(&[0xd9, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xd7]),
+        Some(&[0xb7, 0xfd]),
+        None,
+        Some(&[0xd9, 0xb5]),
+        None,
+        Some(&[0xd9, 0xb7]),
+        Some(&[0xb1, 0xa3]),
+        Some(&[0xd3, 0xe1]),
+        Some(&[0xd9, 0xb9]),
+        None,
+        Some(&[0xd0, 0xc5]),
+        None,
+        Some(&[0xd9, 0xb6]),
+        None,
+        None,
+        Some(&[0xd9, 0xb1]),
+        None,
+        Some(&[0xd9, 0xb2]),
+        Some(&[0xc1, 0xa9]),
+        Some(&[0xd9, 0xb3]),
+        None,
+        None,
+        Some(&[0xbc, 0xf3]),
+        Some(&[0xd0, 0xde]),
+        Some(&[0xb8, 0xa9]),
+        None,
+        Some(&[0xbe, 0xe3]),
+        None,
+        Some(&[0xd9, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xba]),
+        None,
+        Some(&[0xb0, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xc4]),
+        Some(&[0xb1, 0xb6]),
+        None,
+        Some(&[0xd9, 0xbf]),
+        None,
+        None,
+        Some(&[0xb5, 0xb9]),
+        None,
+        Some(&[0xbe, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xc8]),
+        Some(&[0xba, 0xf2]),
+        Some(&[0xd2, 0xd0]),
+        None,
+        Some(&[0xd9, 0xc3]),
+        None,
+        None,
+        Some(&[0xbd, 0xe8]),
+        None,
+        Some(&[0xb3, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xc5]),
+        Some(&[0xbe, 0xeb]),
+        None,
+        Some(&[0xd9, 0xc6]),
+        Some(&[0xd9, 0xbb]),
+        Some(&[0xc4, 0xdf]),
+        None,
+        Some(&[0xd9, 0xbe]),
+        Some(&[0xd9, 0xc1]),
+        Some(&[0xd9, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xae]),
+        None,
+        Some(&[0xd6, 0xb5]),
+        None,
+        Some(&[0xc7, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xd9]),
+        Some(&[0xd9, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xbc]),
+        None,
+        Some(&[0xd9, 0xcb]),
+        Some(&[0xc6, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xf6]),
+        None,
+        Some(&[0xcd, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xbc]),
+        Some(&[0xcd, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xcd]),
+        None,
+        None,
+        Some(&[0xd9, 0xc7]),
+        Some(&[0xb3, 0xa5]),
+        Some(&[0xbf, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xb5]),
+        None,
+        None,
+        Some(&[0xc0, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xf8]),
+        None,
+        None,
+    ];
+
+    const BASE_0X50A0: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xf6]),
+        None,
+        Some(&[0xd9, 0xce]),
+        None,
+        Some(&[0xd9, 0xcf]),
+        Some(&[0xb4, 0xa2]),
+        Some(&[0xd9, 0xd0]),
+        None,
+        None,
+        Some(&[0xb4, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd1]),
+        Some(&[0xc9, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X50C8: [Option<&'static [u8]>; 96] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd2]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd6]),
+        Some(&[0xc9, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd5]),
+        Some(&[0xd9, 0xd4]),
+        Some(&[0xd9, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xdb]),
+        None,
+        Some(&[0xbd, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd3]),
+        Some(&[0xd9, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5138: [Option<&'static [u8]>; 1496] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xf9]),
+        Some(&[0xd8, 0xa3]),
+        Some(&[0xd4, 0xca]),
+        None,
+        Some(&[0xd4, 0xaa]),
+        Some(&[0xd0, 0xd6]),
+        Some(&[0xb3, 0xe4]),
+        Some(&[0xd5, 0xd7]),
+        None,
+        Some(&[0xcf, 0xc8]),
+        Some(&[0xb9, 0xe2]),
+        None,
+        Some(&[0xbf, 0xcb]),
+        None,
+        Some(&[0xc3, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xd2]),
+        None,
+        None,
+        Some(&[0xcd, 0xc3]),
+        Some(&[0xd9, 0xee]),
+        Some(&[0xd9, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xb3]),
+        None,
+        Some(&[0xb6, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xa4]),
+        None,
+        None,
+        Some(&[0xc8, 0xeb]),
+        None,
+        None,
+        Some(&[0xc8, 0xab]),
+        None,
+        None,
+        Some(&[0xb0, 0xcb]),
+        Some(&[0xb9, 0xab]),
+        Some(&[0xc1, 0xf9]),
+        Some(&[0xd9, 0xe2]),
+        None,
+        Some(&[0xc0, 0xbc]),
+        Some(&[0xb9, 0xb2]),
+        None,
+        Some(&[0xb9, 0xd8]),
+        Some(&[0xd0, 0xcb]),
+        Some(&[0xb1, 0xf8]),
+        Some(&[0xc6, 0xe4]),
+        Some(&[0xbe, 0xdf]),
+        Some(&[0xb5, 0xe4]),
+        Some(&[0xd7, 0xc8]),
+        None,
+        Some(&[0xd1, 0xf8]),
+        Some(&[0xbc, 0xe6]),
+        Some(&[0xca, 0xde]),
+        None,
+        None,
+        Some(&[0xbc, 0xbd]),
+        Some(&[0xd9, 0xe6]),
+        Some(&[0xd8, 0xe7]),
+        None,
+        None,
+        Some(&[0xc4, 0xda]),
+        None,
+        None,
+        Some(&[0xb8, 0xd4]),
+        Some(&[0xc8, 0xbd]),
+        None,
+        None,
+        Some(&[0xb2, 0xe1]),
+        Some(&[0xd4, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xb0]),
+        None,
+        None,
+        Some(&[0xc3, 0xe1]),
+        Some(&[0xda, 0xa2]),
+        Some(&[0xc8, 0xdf]),
+        None,
+        Some(&[0xd0, 0xb4]),
+        None,
+        Some(&[0xbe, 0xfc]),
+        Some(&[0xc5, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xda]),
+        None,
+        Some(&[0xda, 0xa3]),
+        None,
+        Some(&[0xd4, 0xa9]),
+        Some(&[0xda, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xfb]),
+        Some(&[0xb6, 0xac]),
+        None,
+        None,
+        Some(&[0xb7, 0xeb]),
+        Some(&[0xb1, 0xf9]),
+        Some(&[0xd9, 0xfc]),
+        Some(&[0xb3, 0xe5]),
+        Some(&[0xbe, 0xf6]),
+        None,
+        Some(&[0xbf, 0xf6]),
+        Some(&[0xd2, 0xb1]),
+        Some(&[0xc0, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xb3]),
+        Some(&[0xd9, 0xfe]),
+        Some(&[0xd9, 0xfd]),
+        None,
+        None,
+        Some(&[0xbe, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xe0]),
+        None,
+        Some(&[0xd7, 0xbc]),
+        Some(&[0xda, 0xa1]),
+        None,
+        Some(&[0xc1, 0xb9]),
+        None,
+        Some(&[0xb5, 0xf2]),
+        Some(&[0xc1, 0xe8]),
+        None,
+        None,
+        Some(&[0xbc, 0xf5]),
+        None,
+        Some(&[0xb4, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xdd]),
+        None,
+        Some(&[0xc4, 0xfd]),
+        None,
+        None,
+        Some(&[0xbc, 0xb8]),
+        Some(&[0xb7, 0xb2]),
+        None,
+        None,
+        Some(&[0xb7, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xec]),
+        None,
+        Some(&[0xc6, 0xbe]),
+        None,
+        Some(&[0xbf, 0xad]),
+        Some(&[0xbb, 0xcb]),
+        None,
+        None,
+        Some(&[0xb5, 0xca]),
+        None,
+        Some(&[0xdb, 0xc9]),
+        Some(&[0xd0, 0xd7]),
+        None,
+        Some(&[0xcd, 0xb9]),
+        Some(&[0xb0, 0xbc]),
+        Some(&[0xb3, 0xf6]),
+        Some(&[0xbb, 0xf7]),
+        Some(&[0xdb, 0xca]),
+        Some(&[0xba, 0xaf]),
+        None,
+        Some(&[0xd4, 0xe4]),
+        Some(&[0xb5, 0xb6]),
+        Some(&[0xb5, 0xf3]),
+        Some(&[0xd8, 0xd6]),
+        Some(&[0xc8, 0xd0]),
+        None,
+        None,
+        Some(&[0xb7, 0xd6]),
+        Some(&[0xc7, 0xd0]),
+        Some(&[0xd8, 0xd7]),
+        None,
+        Some(&[0xbf, 0xaf]),
+        None,
+        None,
+        Some(&[0xdb, 0xbb]),
+        Some(&[0xd8, 0xd8]),
+        None,
+        None,
+        Some(&[0xd0, 0xcc]),
+        Some(&[0xbb, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xbe]),
+        Some(&[0xc1, 0xd0]),
+        Some(&[0xc1, 0xf5]),
+        Some(&[0xd4, 0xf2]),
+        Some(&[0xb8, 0xd5]),
+        Some(&[0xb4, 0xb4]),
+        None,
+        Some(&[0xb3, 0xf5]),
+        None,
+        None,
+        Some(&[0xc9, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xd9]),
+        Some(&[0xc0, 0xfb]),
+        None,
+        Some(&[0xb1, 0xf0]),
+        None,
+        Some(&[0xd8, 0xd9]),
+        Some(&[0xb9, 0xce]),
+        None,
+        Some(&[0xb5, 0xbd]),
+        None,
+        None,
+        Some(&[0xd8, 0xda]),
+        None,
+        None,
+        Some(&[0xd6, 0xc6]),
+        Some(&[0xcb, 0xa2]),
+        Some(&[0xc8, 0xaf]),
+        Some(&[0xc9, 0xb2]),
+        Some(&[0xb4, 0xcc]),
+        Some(&[0xbf, 0xcc]),
+        None,
+        Some(&[0xb9, 0xf4]),
+        None,
+        Some(&[0xd8, 0xdb]),
+        Some(&[0xd8, 0xdc]),
+        Some(&[0xb6, 0xe7]),
+        Some(&[0xbc, 0xc1]),
+        Some(&[0xcc, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf7]),
+        None,
+        Some(&[0xd8, 0xdd]),
+        Some(&[0xc7, 0xb0]),
+        None,
+        None,
+        Some(&[0xb9, 0xd0]),
+        Some(&[0xbd, 0xa3]),
+        None,
+        None,
+        Some(&[0xcc, 0xde]),
+        None,
+        Some(&[0xc6, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xe0]),
+        None,
+        Some(&[0xd8, 0xde]),
+        None,
+        None,
+        Some(&[0xd8, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xfe]),
+        None,
+        Some(&[0xbe, 0xe7]),
+        None,
+        Some(&[0xca, 0xa3]),
+        Some(&[0xbc, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xb1]),
+        None,
+        None,
+        Some(&[0xb8, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xe2]),
+        None,
+        Some(&[0xbd, 0xcb]),
+        None,
+        Some(&[0xd8, 0xe4]),
+        Some(&[0xd8, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xe5]),
+        None,
+        None,
+        Some(&[0xd8, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xa6]),
+        None,
+        Some(&[0xc8, 0xb0]),
+        Some(&[0xb0, 0xec]),
+        Some(&[0xb9, 0xa6]),
+        Some(&[0xbc, 0xd3]),
+        Some(&[0xce, 0xf1]),
+        Some(&[0xdb, 0xbd]),
+        Some(&[0xc1, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xaf]),
+        Some(&[0xd6, 0xfa]),
+        Some(&[0xc5, 0xac]),
+        Some(&[0xbd, 0xd9]),
+        Some(&[0xdb, 0xbe]),
+        Some(&[0xdb, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xf8]),
+        Some(&[0xbe, 0xa2]),
+        Some(&[0xc0, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc0]),
+        Some(&[0xca, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xc2]),
+        None,
+        Some(&[0xc3, 0xe3]),
+        None,
+        Some(&[0xd1, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc2]),
+        None,
+        Some(&[0xc0, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc3]),
+        None,
+        Some(&[0xbf, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe8]),
+        Some(&[0xc9, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xb4]),
+        Some(&[0xce, 0xf0]),
+        Some(&[0xd4, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xfc]),
+        Some(&[0xb4, 0xd2]),
+        None,
+        Some(&[0xd0, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe9]),
+        None,
+        Some(&[0xde, 0xcb]),
+        Some(&[0xd9, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xb0]),
+        Some(&[0xbb, 0xaf]),
+        Some(&[0xb1, 0xb1]),
+        None,
+        Some(&[0xb3, 0xd7]),
+        Some(&[0xd8, 0xce]),
+        None,
+        None,
+        Some(&[0xd4, 0xd1]),
+        None,
+        None,
+        Some(&[0xbd, 0xb3]),
+        Some(&[0xbf, 0xef]),
+        None,
+        Some(&[0xcf, 0xbb]),
+        None,
+        None,
+        Some(&[0xd8, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa5]),
+        Some(&[0xc7, 0xf8]),
+        Some(&[0xd2, 0xbd]),
+        None,
+        None,
+        Some(&[0xd8, 0xd2]),
+        Some(&[0xc4, 0xe4]),
+        None,
+        Some(&[0xca, 0xae]),
+        None,
+        Some(&[0xc7, 0xa7]),
+        None,
+        Some(&[0xd8, 0xa6]),
+        None,
+        Some(&[0xc9, 0xfd]),
+        Some(&[0xce, 0xe7]),
+        Some(&[0xbb, 0xdc]),
+        Some(&[0xb0, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xaa]),
+        Some(&[0xd0, 0xad]),
+        None,
+        Some(&[0xb1, 0xb0]),
+        Some(&[0xd7, 0xe4]),
+        Some(&[0xd7, 0xbf]),
+        None,
+        Some(&[0xb5, 0xa5]),
+        Some(&[0xc2, 0xf4]),
+        Some(&[0xc4, 0xcf]),
+        None,
+        None,
+        Some(&[0xb2, 0xa9]),
+        None,
+        Some(&[0xb2, 0xb7]),
+        None,
+        Some(&[0xb1, 0xe5]),
+        Some(&[0xdf, 0xb2]),
+        Some(&[0xd5, 0xbc]),
+        Some(&[0xbf, 0xa8]),
+        Some(&[0xc2, 0xac]),
+        Some(&[0xd8, 0xd5]),
+        Some(&[0xc2, 0xb1]),
+        None,
+        Some(&[0xd8, 0xd4]),
+        Some(&[0xce, 0xd4]),
+        None,
+        Some(&[0xda, 0xe0]),
+        None,
+        Some(&[0xce, 0xc0]),
+        None,
+        None,
+        Some(&[0xd8, 0xb4]),
+        Some(&[0xc3, 0xae]),
+        Some(&[0xd3, 0xa1]),
+        Some(&[0xce, 0xa3]),
+        None,
+        Some(&[0xbc, 0xb4]),
+        Some(&[0xc8, 0xb4]),
+        Some(&[0xc2, 0xd1]),
+        None,
+        Some(&[0xbe, 0xed]),
+        Some(&[0xd0, 0xb6]),
+        None,
+        Some(&[0xda, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xe4]),
+        None,
+        None,
+        Some(&[0xb3, 0xa7]),
+        None,
+        Some(&[0xb6, 0xf2]),
+        Some(&[0xcc, 0xfc]),
+        Some(&[0xc0, 0xfa]),
+        None,
+        None,
+        Some(&[0xc0, 0xf7]),
+        None,
+        Some(&[0xd1, 0xb9]),
+        Some(&[0xd1, 0xe1]),
+        Some(&[0xd8, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xde]),
+        None,
+        None,
+        Some(&[0xc0, 0xe5]),
+        None,
+        Some(&[0xba, 0xf1]),
+        None,
+        None,
+        Some(&[0xd8, 0xc8]),
+        None,
+        Some(&[0xd4, 0xad]),
+        None,
+        None,
+        Some(&[0xcf, 0xe1]),
+        Some(&[0xd8, 0xc9]),
+        None,
+        Some(&[0xd8, 0xca]),
+        Some(&[0xcf, 0xc3]),
+        None,
+        Some(&[0xb3, 0xf8]),
+        Some(&[0xbe, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xd8]),
+        None,
+        Some(&[0xc8, 0xfe]),
+        Some(&[0xb2, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xd6]),
+        Some(&[0xb2, 0xe6]),
+        Some(&[0xbc, 0xb0]),
+        Some(&[0xd3, 0xd1]),
+        Some(&[0xcb, 0xab]),
+        Some(&[0xb7, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xa2]),
+        None,
+        None,
+        Some(&[0xca, 0xe5]),
+        None,
+        Some(&[0xc8, 0xa1]),
+        Some(&[0xca, 0xdc]),
+        Some(&[0xb1, 0xe4]),
+        Some(&[0xd0, 0xf0]),
+        None,
+        Some(&[0xc5, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc5]),
+        Some(&[0xb5, 0xfe]),
+        None,
+        None,
+        Some(&[0xbf, 0xda]),
+        Some(&[0xb9, 0xc5]),
+        Some(&[0xbe, 0xe4]),
+        Some(&[0xc1, 0xed]),
+        None,
+        Some(&[0xdf, 0xb6]),
+        Some(&[0xdf, 0xb5]),
+        Some(&[0xd6, 0xbb]),
+        Some(&[0xbd, 0xd0]),
+        Some(&[0xd5, 0xd9]),
+        Some(&[0xb0, 0xc8]),
+        Some(&[0xb6, 0xa3]),
+        Some(&[0xbf, 0xc9]),
+        Some(&[0xcc, 0xa8]),
+        Some(&[0xdf, 0xb3]),
+        Some(&[0xca, 0xb7]),
+        Some(&[0xd3, 0xd2]),
+        None,
+        Some(&[0xd8, 0xcf]),
+        Some(&[0xd2, 0xb6]),
+        Some(&[0xba, 0xc5]),
+        Some(&[0xcb, 0xbe]),
+        Some(&[0xcc, 0xbe]),
+        None,
+        Some(&[0xdf, 0xb7]),
+        Some(&[0xb5, 0xf0]),
+        Some(&[0xdf, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xf5]),
+        None,
+        Some(&[0xb3, 0xd4]),
+        Some(&[0xb8, 0xf7]),
+        None,
+        Some(&[0xdf, 0xba]),
+        None,
+        Some(&[0xba, 0xcf]),
+        Some(&[0xbc, 0xaa]),
+        Some(&[0xb5, 0xf5]),
+        None,
+        Some(&[0xcd, 0xac]),
+        Some(&[0xc3, 0xfb]),
+        Some(&[0xba, 0xf3]),
+        Some(&[0xc0, 0xf4]),
+        Some(&[0xcd, 0xc2]),
+        Some(&[0xcf, 0xf2]),
+        Some(&[0xdf, 0xb8]),
+        Some(&[0xcf, 0xc5]),
+        None,
+        Some(&[0xc2, 0xc0]),
+        Some(&[0xdf, 0xb9]),
+        Some(&[0xc2, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xfd]),
+        None,
+        Some(&[0xc1, 0xdf]),
+        Some(&[0xcd, 0xcc]),
+        Some(&[0xd2, 0xf7]),
+        Some(&[0xb7, 0xcd]),
+        Some(&[0xdf, 0xc1]),
+        None,
+        Some(&[0xdf, 0xc4]),
+        None,
+        None,
+        Some(&[0xb7, 0xf1]),
+        Some(&[0xb0, 0xc9]),
+        Some(&[0xb6, 0xd6]),
+        Some(&[0xb7, 0xd4]),
+        None,
+        Some(&[0xba, 0xac]),
+        Some(&[0xcc, 0xfd]),
+        Some(&[0xbf, 0xd4]),
+        Some(&[0xcb, 0xb1]),
+        Some(&[0xc6, 0xf4]),
+        None,
+        Some(&[0xd6, 0xa8]),
+        Some(&[0xdf, 0xc5]),
+        None,
+        Some(&[0xce, 0xe2]),
+        Some(&[0xb3, 0xb3]),
+        None,
+        None,
+        Some(&[0xce, 0xfc]),
+        Some(&[0xb4, 0xb5]),
+        None,
+        Some(&[0xce, 0xc7]),
+        Some(&[0xba, 0xf0]),
+        None,
+        Some(&[0xce, 0xe1]),
+        None,
+        Some(&[0xd1, 0xbd]),
+        None,
+        None,
+        Some(&[0xdf, 0xc0]),
+        None,
+        None,
+        Some(&[0xb4, 0xf4]),
+        None,
+        Some(&[0xb3, 0xca]),
+        None,
+        Some(&[0xb8, 0xe6]),
+        Some(&[0xdf, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xc5]),
+        None,
+        Some(&[0xdf, 0xbc]),
+        Some(&[0xdf, 0xbd]),
+        Some(&[0xdf, 0xbe]),
+        Some(&[0xc5, 0xbb]),
+        Some(&[0xdf, 0xbf]),
+        Some(&[0xdf, 0xc2]),
+        Some(&[0xd4, 0xb1]),
+        Some(&[0xdf, 0xc3]),
+        None,
+        Some(&[0xc7, 0xba]),
+        Some(&[0xce, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xd8]),
+        None,
+        Some(&[0xdf, 0xca]),
+        None,
+        Some(&[0xdf, 0xcf]),
+        None,
+        Some(&[0xd6, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xc9]),
+        Some(&[0xdf, 0xda]),
+        Some(&[0xce, 0xb6]),
+        None,
+        Some(&[0xba, 0xc7]),
+        Some(&[0xdf, 0xce]),
+        Some(&[0xdf, 0xc8]),
+        Some(&[0xc5, 0xde]),
+        None,
+        None,
+        Some(&[0xc9, 0xeb]),
+        Some(&[0xba, 0xf4]),
+        Some(&[0xc3, 0xfc]),
+        None,
+        None,
+        Some(&[0xbe, 0xd7]),
+        None,
+        Some(&[0xdf, 0xc6]),
+        None,
+        Some(&[0xdf, 0xcd]),
+        None,
+        Some(&[0xc5, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xa6]),
+        Some(&[0xba, 0xcd]),
+        None,
+        Some(&[0xbe, 0xcc]),
+        Some(&[0xd3, 0xbd]),
+        Some(&[0xb8, 0xc0]),
+        None,
+        Some(&[0xd6, 0xe4]),
+        None,
+        Some(&[0xdf, 0xc7]),
+        Some(&[0xb9, 0xbe]),
+        Some(&[0xbf, 0xa7]),
+        None,
+        None,
+        Some(&[0xc1, 0xfc]),
+        Some(&[0xdf, 0xcb]),
+        Some(&[0xdf, 0xcc]),
+        None,
+        Some(&[0xdf, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xdb]),
+        Some(&[0xdf, 0xe5]),
+        None,
+        Some(&[0xdf, 0xd7]),
+        Some(&[0xdf, 0xd6]),
+        Some(&[0xd7, 0xc9]),
+        Some(&[0xdf, 0xe3]),
+        Some(&[0xdf, 0xe4]),
+        Some(&[0xe5, 0xeb]),
+        Some(&[0xd2, 0xa7]),
+        Some(&[0xdf, 0xd2]),
+        None,
+        Some(&[0xbf, 0xa9]),
+        None,
+        Some(&[0xd4, 0xdb]),
+        None,
+        Some(&[0xbf, 0xc8]),
+        Some(&[0xdf, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xcc]),
+        None,
+        None,
+        Some(&[0xdf, 0xdd]),
+        None,
+        Some(&[0xd1, 0xca]),
+        None,
+        Some(&[0xdf, 0xde]),
+        Some(&[0xb0, 0xa7]),
+        Some(&[0xc6, 0xb7]),
+        Some(&[0xdf, 0xd3]),
+        None,
+        Some(&[0xba, 0xe5]),
+        None,
+        Some(&[0xb6, 0xdf]),
+        Some(&[0xcd, 0xdb]),
+        Some(&[0xb9, 0xfe]),
+        Some(&[0xd4, 0xd5]),
+        None,
+        None,
+        Some(&[0xdf, 0xdf]),
+        Some(&[0xcf, 0xec]),
+        Some(&[0xb0, 0xa5]),
+        Some(&[0xdf, 0xe7]),
+        Some(&[0xdf, 0xd1]),
+        Some(&[0xd1, 0xc6]),
+        Some(&[0xdf, 0xd5]),
+        Some(&[0xdf, 0xd8]),
+        Some(&[0xdf, 0xd9]),
+        Some(&[0xdf, 0xdc]),
+        None,
+        Some(&[0xbb, 0xa9]),
+        None,
+        Some(&[0xdf, 0xe0]),
+        Some(&[0xdf, 0xe1]),
+        None,
+        Some(&[0xdf, 0xe2]),
+        Some(&[0xdf, 0xe6]),
+        Some(&[0xdf, 0xe8]),
+        Some(&[0xd3, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xe7]),
+        Some(&[0xc5, 0xb6]),
+        Some(&[0xdf, 0xea]),
+        Some(&[0xc9, 0xda]),
+        Some(&[0xc1, 0xa8]),
+        Some(&[0xc4, 0xc4]),
+        None,
+        None,
+        Some(&[0xbf, 0xde]),
+        Some(&[0xcf, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xdc]),
+        Some(&[0xdf, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xb8]),
+        None,
+        Some(&[0xba, 0xdf]),
+        Some(&[0xdf, 0xec]),
+        None,
+        Some(&[0xdb, 0xc1]),
+        None,
+        Some(&[0xd1, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xf4]),
+        Some(&[0xb4, 0xbd]),
+        None,
+        Some(&[0xb0, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xf1]),
+        Some(&[0xcc, 0xc6]),
+        Some(&[0xdf, 0xf2]),
+        None,
+        None,
+        Some(&[0xdf, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xeb]),
+        None,
+        Some(&[0xdf, 0xef]),
+        Some(&[0xdf, 0xf0]),
+        Some(&[0xbb, 0xbd]),
+        None,
+        None,
+        Some(&[0xdf, 0xf3]),
+        None,
+        None,
+        Some(&[0xdf, 0xf4]),
+        None,
+        Some(&[0xbb, 0xa3]),
+        None,
+        Some(&[0xca, 0xdb]),
+        Some(&[0xce, 0xa8]),
+        Some(&[0xe0, 0xa7]),
+        Some(&[0xb3, 0xaa]),
+        None,
+        Some(&[0xe0, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xfe]),
+        None,
+        Some(&[0xcd, 0xd9]),
+        Some(&[0xdf, 0xfc]),
+        None,
+        Some(&[0xdf, 0xfa]),
+        None,
+        Some(&[0xbf, 0xd0]),
+        Some(&[0xd7, 0xc4]),
+        None,
+        Some(&[0xc9, 0xcc]),
+        None,
+        None,
+        Some(&[0xdf, 0xf8]),
+        Some(&[0xb0, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xfb]),
+        Some(&[0xe0, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xc8]),
+        None,
+        None,
+        Some(&[0xc6, 0xa1]),
+        Some(&[0xc9, 0xb6]),
+        Some(&[0xc0, 0xb2]),
+        Some(&[0xdf, 0xf5]),
+        None,
+        None,
+        Some(&[0xc5, 0xbe]),
+        None,
+        Some(&[0xd8, 0xc4]),
+        Some(&[0xdf, 0xf9]),
+        Some(&[0xc4, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa3]),
+        Some(&[0xe0, 0xa4]),
+        Some(&[0xe0, 0xa5]),
+        Some(&[0xd0, 0xa5]),
+        None,
+        None,
+        Some(&[0xe0, 0xb4]),
+        Some(&[0xcc, 0xe4]),
+        None,
+        Some(&[0xe0, 0xb1]),
+        None,
+        Some(&[0xbf, 0xa6]),
+        Some(&[0xe0, 0xaf]),
+        Some(&[0xce, 0xb9]),
+        Some(&[0xe0, 0xab]),
+        Some(&[0xc9, 0xc6]),
+        None,
+        None,
+        Some(&[0xc0, 0xae]),
+        Some(&[0xe0, 0xae]),
+        Some(&[0xba, 0xed]),
+        Some(&[0xba, 0xb0]),
+        Some(&[0xe0, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xf6]),
+        None,
+        Some(&[0xe0, 0xb3]),
+        None,
+        None,
+        Some(&[0xe0, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xad]),
+        Some(&[0xe0, 0xb9]),
+        None,
+        None,
+        Some(&[0xcf, 0xb2]),
+        Some(&[0xba, 0xc8]),
+        None,
+        Some(&[0xe0, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xac]),
+        None,
+        Some(&[0xd4, 0xfb]),
+        None,
+        Some(&[0xdf, 0xf7]),
+        None,
+        Some(&[0xc5, 0xe7]),
+        None,
+        Some(&[0xe0, 0xad]),
+        None,
+        Some(&[0xd3, 0xf7]),
+        None,
+        Some(&[0xe0, 0xb6]),
+        Some(&[0xe0, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xc4]),
+        Some(&[0xd0, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xbc]),
+        None,
+        None,
+        Some(&[0xe0, 0xc9]),
+        Some(&[0xe0, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xbe]),
+        Some(&[0xe0, 0xaa]),
+        Some(&[0xc9, 0xa4]),
+        Some(&[0xe0, 0xc1]),
+        None,
+        Some(&[0xe0, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xc8]),
+        Some(&[0xe0, 0xc3]),
+        None,
+        Some(&[0xe0, 0xb5]),
+        None,
+        Some(&[0xce, 0xcb]),
+        None,
+        Some(&[0xcb, 0xc3]),
+        Some(&[0xe0, 0xcd]),
+        Some(&[0xe0, 0xc6]),
+        Some(&[0xe0, 0xc2]),
+        None,
+        Some(&[0xe0, 0xcb]),
+        None,
+        Some(&[0xe0, 0xba]),
+        Some(&[0xe0, 0xbf]),
+        Some(&[0xe0, 0xc0]),
+        None,
+        None,
+        Some(&[0xe0, 0xc5]),
+        None,
+        None,
+        Some(&[0xe0, 0xc7]),
+        Some(&[0xe0, 0xc8]),
+        None,
+        Some(&[0xe0, 0xcc]),
+        None,
+        Some(&[0xe0, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xd4]),
+        Some(&[0xe0, 0xd5]),
+        None,
+        Some(&[0xe0, 0xd6]),
+        Some(&[0xe0, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd0]),
+        Some(&[0xbc, 0xce]),
+        None,
+        None,
+        Some(&[0xe0, 0xd1]),
+        None,
+        Some(&[0xb8, 0xc2]),
+        Some(&[0xd8, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xea]),
+        None,
+        None,
+        Some(&[0xc2, 0xef]),
+        None,
+        None,
+        Some(&[0xe0, 0xcf]),
+        Some(&[0xe0, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd4]),
+        Some(&[0xe0, 0xd3]),
+        None,
+        None,
+        Some(&[0xe0, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xdc]),
+        Some(&[0xe0, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xf6]),
+        Some(&[0xb3, 0xb0]),
+        None,
+        Some(&[0xd7, 0xec]),
+        None,
+        Some(&[0xcb, 0xbb]),
+        None,
+        None,
+        Some(&[0xe0, 0xda]),
+        None,
+        Some(&[0xce, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe1]),
+        Some(&[0xe0, 0xdd]),
+        Some(&[0xd2, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe2]),
+        None,
+        None,
+        Some(&[0xe0, 0xdb]),
+        Some(&[0xe0, 0xd9]),
+        Some(&[0xe0, 0xdf]),
+        None,
+        None,
+        Some(&[0xe0, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xde]),
+        None,
+        Some(&[0xe0, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xf7]),
+        Some(&[0xd8, 0xac]),
+        Some(&[0xd4, 0xeb]),
+        Some(&[0xe0, 0xe6]),
+        Some(&[0xca, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe7]),
+        Some(&[0xe0, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe9]),
+        Some(&[0xe0, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xbf]),
+        Some(&[0xcc, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xec]),
+        None,
+        None,
+        Some(&[0xe0, 0xed]),
+        None,
+        None,
+        Some(&[0xc7, 0xf4]),
+        Some(&[0xcb, 0xc4]),
+        None,
+        Some(&[0xe0, 0xee]),
+        Some(&[0xbb, 0xd8]),
+        Some(&[0xd8, 0xb6]),
+        Some(&[0xd2, 0xf2]),
+        Some(&[0xe0, 0xef]),
+        Some(&[0xcd, 0xc5]),
+        None,
+        Some(&[0xb6, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf1]),
+        None,
+        Some(&[0xd4, 0xb0]),
+        None,
+        None,
+        Some(&[0xc0, 0xa7]),
+        Some(&[0xb4, 0xd1]),
+        None,
+        None,
+        Some(&[0xce, 0xa7]),
+        Some(&[0xe0, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf2]),
+        Some(&[0xb9, 0xcc]),
+        None,
+        None,
+        Some(&[0xb9, 0xfa]),
+        Some(&[0xcd, 0xbc]),
+        Some(&[0xe0, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xd4]),
+        Some(&[0xe0, 0xf4]),
+        None,
+        Some(&[0xd4, 0xb2]),
+        None,
+        Some(&[0xc8, 0xa6]),
+        Some(&[0xe0, 0xf6]),
+        Some(&[0xe0, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5718: [Option<&'static [u8]>; 344] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf7]),
+        None,
+        None,
+        Some(&[0xcd, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xda]),
+        Some(&[0xdb, 0xd7]),
+        Some(&[0xdb, 0xd9]),
+        None,
+        Some(&[0xdb, 0xd8]),
+        Some(&[0xb9, 0xe7]),
+        Some(&[0xdb, 0xdc]),
+        Some(&[0xdb, 0xdd]),
+        Some(&[0xb5, 0xd8]),
+        None,
+        None,
+        Some(&[0xdb, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xdb]),
+        Some(&[0xb3, 0xa1]),
+        Some(&[0xdb, 0xdf]),
+        None,
+        None,
+        Some(&[0xbb, 0xf8]),
+        None,
+        Some(&[0xd6, 0xb7]),
+        None,
+        Some(&[0xdb, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xf9]),
+        None,
+        None,
+        Some(&[0xb7, 0xbb]),
+        None,
+        Some(&[0xdb, 0xd0]),
+        Some(&[0xcc, 0xae]),
+        Some(&[0xbf, 0xb2]),
+        Some(&[0xbb, 0xb5]),
+        Some(&[0xd7, 0xf8]),
+        Some(&[0xbf, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xe9]),
+        None,
+        None,
+        Some(&[0xbc, 0xe1]),
+        Some(&[0xcc, 0xb3]),
+        Some(&[0xdb, 0xde]),
+        Some(&[0xb0, 0xd3]),
+        Some(&[0xce, 0xeb]),
+        Some(&[0xb7, 0xd8]),
+        Some(&[0xd7, 0xb9]),
+        Some(&[0xc6, 0xc2]),
+        None,
+        None,
+        Some(&[0xc0, 0xa4]),
+        None,
+        Some(&[0xcc, 0xb9]),
+        None,
+        Some(&[0xdb, 0xe7]),
+        Some(&[0xdb, 0xe1]),
+        Some(&[0xc6, 0xba]),
+        Some(&[0xdb, 0xe3]),
+        None,
+        Some(&[0xdb, 0xe8]),
+        None,
+        Some(&[0xc5, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xea]),
+        None,
+        None,
+        Some(&[0xdb, 0xe9]),
+        Some(&[0xbf, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xe6]),
+        Some(&[0xdb, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xb9]),
+        Some(&[0xc0, 0xac]),
+        Some(&[0xc2, 0xa2]),
+        Some(&[0xdb, 0xe2]),
+        Some(&[0xdb, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xcd]),
+        Some(&[0xdb, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xdd]),
+        Some(&[0xdb, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xf3]),
+        Some(&[0xdb, 0xd2]),
+        Some(&[0xb9, 0xb8]),
+        Some(&[0xd4, 0xab]),
+        Some(&[0xdb, 0xec]),
+        None,
+        Some(&[0xbf, 0xd1]),
+        Some(&[0xdb, 0xf0]),
+        None,
+        Some(&[0xdb, 0xd1]),
+        None,
+        Some(&[0xb5, 0xe6]),
+        None,
+        Some(&[0xdb, 0xeb]),
+        Some(&[0xbf, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xee]),
+        None,
+        Some(&[0xdb, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xa1]),
+        Some(&[0xb0, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xf1]),
+        None,
+        None,
+        Some(&[0xb3, 0xc7]),
+        Some(&[0xdb, 0xef]),
+        None,
+        None,
+        Some(&[0xdb, 0xf8]),
+        None,
+        Some(&[0xc6, 0xd2]),
+        Some(&[0xdb, 0xf4]),
+        None,
+        None,
+        Some(&[0xdb, 0xf5]),
+        Some(&[0xdb, 0xf7]),
+        Some(&[0xdb, 0xf6]),
+        None,
+        None,
+        Some(&[0xdb, 0xfe]),
+        None,
+        Some(&[0xd3, 0xf2]),
+        Some(&[0xb2, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xa4]),
+        None,
+        Some(&[0xdb, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xfa]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xfc]),
+        Some(&[0xc5, 0xe0]),
+        Some(&[0xbb, 0xf9]),
+        None,
+        None,
+        Some(&[0xdc, 0xa3]),
+        None,
+        None,
+        Some(&[0xdc, 0xa5]),
+        None,
+        Some(&[0xcc, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xd1]),
+        Some(&[0xdd, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xa1]),
+        None,
+        Some(&[0xdc, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xa6]),
+        None,
+        Some(&[0xdc, 0xa9]),
+        Some(&[0xb1, 0xa4]),
+        None,
+        None,
+        Some(&[0xb5, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xfa]),
+        Some(&[0xeb, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xdc]),
+        None,
+        None,
+        Some(&[0xcb, 0xfe]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xee]),
+        Some(&[0xdc, 0xab]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5878: [Option<&'static [u8]>; 56] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xd3]),
+        None,
+        Some(&[0xdc, 0xaf]),
+        Some(&[0xdc, 0xac]),
+        None,
+        Some(&[0xbe, 0xb3]),
+        None,
+        Some(&[0xca, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xca]),
+        Some(&[0xc4, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xbd]),
+        Some(&[0xdc, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xf6]),
+        Some(&[0xd0, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xab]),
+        Some(&[0xb6, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X58B8: [Option<&'static [u8]>; 456] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xbf]),
+        Some(&[0xc8, 0xc9]),
+        None,
+        Some(&[0xd7, 0xb3]),
+        None,
+        Some(&[0xc9, 0xf9]),
+        None,
+        None,
+        Some(&[0xbf, 0xc7]),
+        None,
+        None,
+        Some(&[0xba, 0xf8]),
+        None,
+        None,
+        Some(&[0xd2, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xba]),
+        None,
+        Some(&[0xb4, 0xa6]),
+        None,
+        None,
+        Some(&[0xb1, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xb4]),
+        None,
+        Some(&[0xcf, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe7]),
+        Some(&[0xcf, 0xa6]),
+        Some(&[0xcd, 0xe2]),
+        None,
+        None,
+        Some(&[0xd9, 0xed]),
+        Some(&[0xb6, 0xe0]),
+        None,
+        Some(&[0xd2, 0xb9]),
+        None,
+        None,
+        Some(&[0xb9, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb9]),
+        Some(&[0xe2, 0xb7]),
+        None,
+        Some(&[0xb4, 0xf3]),
+        None,
+        Some(&[0xcc, 0xec]),
+        Some(&[0xcc, 0xab]),
+        Some(&[0xb7, 0xf2]),
+        None,
+        Some(&[0xd8, 0xb2]),
+        Some(&[0xd1, 0xeb]),
+        Some(&[0xba, 0xbb]),
+        None,
+        Some(&[0xca, 0xa7]),
+        None,
+        None,
+        Some(&[0xcd, 0xb7]),
+        None,
+        None,
+        Some(&[0xd2, 0xc4]),
+        Some(&[0xbf, 0xe4]),
+        Some(&[0xbc, 0xd0]),
+        Some(&[0xb6, 0xe1]),
+        None,
+        Some(&[0xde, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xc6]),
+        Some(&[0xdb, 0xbc]),
+        None,
+        Some(&[0xd1, 0xd9]),
+        None,
+        None,
+        Some(&[0xc6, 0xe6]),
+        Some(&[0xc4, 0xce]),
+        Some(&[0xb7, 0xee]),
+        None,
+        Some(&[0xb7, 0xdc]),
+        None,
+        None,
+        Some(&[0xbf, 0xfc]),
+        Some(&[0xd7, 0xe0]),
+        None,
+        Some(&[0xc6, 0xf5]),
+        None,
+        None,
+        Some(&[0xb1, 0xbc]),
+        Some(&[0xde, 0xc8]),
+        Some(&[0xbd, 0xb1]),
+        Some(&[0xcc, 0xd7]),
+        Some(&[0xde, 0xca]),
+        None,
+        Some(&[0xde, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xec]),
+        None,
+        Some(&[0xc9, 0xdd]),
+        None,
+        None,
+        Some(&[0xb0, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xae]),
+        Some(&[0xc5, 0xab]),
+        None,
+        Some(&[0xc4, 0xcc]),
+        None,
+        Some(&[0xbc, 0xe9]),
+        Some(&[0xcb, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf9]),
+        Some(&[0xc8, 0xe7]),
+        Some(&[0xe5, 0xfa]),
+        Some(&[0xcd, 0xfd]),
+        None,
+        Some(&[0xd7, 0xb1]),
+        Some(&[0xb8, 0xbe]),
+        Some(&[0xc2, 0xe8]),
+        None,
+        Some(&[0xc8, 0xd1]),
+        None,
+        None,
+        Some(&[0xe5, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xca]),
+        Some(&[0xbc, 0xcb]),
+        None,
+        None,
+        Some(&[0xd1, 0xfd]),
+        Some(&[0xe6, 0xa1]),
+        None,
+        Some(&[0xc3, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xfe]),
+        Some(&[0xe6, 0xa5]),
+        Some(&[0xcd, 0xd7]),
+        None,
+        None,
+        Some(&[0xb7, 0xc1]),
+        Some(&[0xe5, 0xfc]),
+        Some(&[0xe5, 0xfd]),
+        Some(&[0xe6, 0xa3]),
+        None,
+        None,
+        Some(&[0xc4, 0xdd]),
+        Some(&[0xe6, 0xa8]),
+        None,
+        None,
+        Some(&[0xe6, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xc3]),
+        None,
+        Some(&[0xc6, 0xde]),
+        None,
+        None,
+        Some(&[0xe6, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xa2]),
+        Some(&[0xca, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xe3]),
+        Some(&[0xb9, 0xc3]),
+        Some(&[0xe6, 0xa6]),
+        Some(&[0xd0, 0xd5]),
+        Some(&[0xce, 0xaf]),
+        None,
+        None,
+        Some(&[0xe6, 0xa9]),
+        Some(&[0xe6, 0xb0]),
+        None,
+        Some(&[0xd2, 0xa6]),
+        None,
+        Some(&[0xbd, 0xaa]),
+        Some(&[0xe6, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xaf]),
+        None,
+        Some(&[0xc0, 0xd1]),
+        None,
+        None,
+        Some(&[0xd2, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb1]),
+        None,
+        Some(&[0xd2, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xcb]),
+        None,
+        Some(&[0xcd, 0xfe]),
+        None,
+        Some(&[0xcd, 0xde]),
+        Some(&[0xc2, 0xa6]),
+        Some(&[0xe6, 0xab]),
+        Some(&[0xe6, 0xac]),
+        Some(&[0xbd, 0xbf]),
+        Some(&[0xe6, 0xae]),
+        Some(&[0xe6, 0xb3]),
+        None,
+        None,
+        Some(&[0xe6, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb6]),
+        None,
+        Some(&[0xe6, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xef]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xc8]),
+        None,
+        None,
+        Some(&[0xbe, 0xea]),
+        Some(&[0xc9, 0xef]),
+        None,
+        None,
+        Some(&[0xe6, 0xb7]),
+        None,
+        Some(&[0xb6, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xe9]),
+        Some(&[0xe6, 0xb4]),
+        None,
+        Some(&[0xe6, 0xb5]),
+        None,
+        Some(&[0xc8, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xc5]),
+        None,
+        None,
+        Some(&[0xcd, 0xf1]),
+        Some(&[0xe6, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xba]),
+        None,
+        None,
+        Some(&[0xc0, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xa4]),
+        Some(&[0xe6, 0xbf]),
+        Some(&[0xc9, 0xf4]),
+        Some(&[0xe6, 0xc3]),
+        None,
+        None,
+        Some(&[0xe6, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xf6]),
+    ];
+
+    const BASE_0X5A90: [Option<&'static [u8]>; 104] = [
+        None,
+        None,
+        Some(&[0xc3, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xc4]),
+        Some(&[0xe6, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xc7]),
+        Some(&[0xcf, 0xb1]),
+        None,
+        Some(&[0xeb, 0xf4]),
+        None,
+        None,
+        Some(&[0xe6, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xc5]),
+        None,
+        None,
+        Some(&[0xbc, 0xde]),
+        Some(&[0xc9, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xb5]),
+        None,
+        None,
+        Some(&[0xcf, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xc8]),
+        None,
+        Some(&[0xe6, 0xc9]),
+        None,
+        Some(&[0xe6, 0xce]),
+        None,
+        Some(&[0xe6, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xcb]),
+        Some(&[0xb5, 0xd5]),
+        None,
+        Some(&[0xe6, 0xcc]),
+        None,
+        None,
+        Some(&[0xe6, 0xcf]),
+        None,
+        None,
+        Some(&[0xc4, 0xdb]),
+        None,
+        Some(&[0xe6, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5B08: [Option<&'static [u8]>; 16] = [
+        None,
+        Some(&[0xe6, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd4]),
+        Some(&[0xe6, 0xd3]),
+    ];
+
+    const BASE_0X5B30: [Option<&'static [u8]>; 600] = [
+        None,
+        None,
+        Some(&[0xe6, 0xd5]),
+        None,
+        Some(&[0xd9, 0xf8]),
+        None,
+        None,
+        Some(&[0xe6, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xd3]),
+        Some(&[0xe6, 0xdd]),
+        None,
+        Some(&[0xe6, 0xde]),
+        Some(&[0xbf, 0xd7]),
+        Some(&[0xd4, 0xd0]),
+        None,
+        Some(&[0xd7, 0xd6]),
+        Some(&[0xb4, 0xe6]),
+        Some(&[0xcb, 0xef]),
+        Some(&[0xe6, 0xda]),
+        Some(&[0xd8, 0xc3]),
+        Some(&[0xd7, 0xce]),
+        Some(&[0xd0, 0xa2]),
+        None,
+        Some(&[0xc3, 0xcf]),
+        None,
+        None,
+        Some(&[0xe6, 0xdf]),
+        Some(&[0xbc, 0xbe]),
+        Some(&[0xb9, 0xc2]),
+        Some(&[0xe6, 0xdb]),
+        Some(&[0xd1, 0xa7]),
+        None,
+        None,
+        Some(&[0xba, 0xa2]),
+        Some(&[0xc2, 0xcf]),
+        None,
+        Some(&[0xd8, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xeb]),
+        Some(&[0xe5, 0xee]),
+        None,
+        Some(&[0xe6, 0xdc]),
+        None,
+        Some(&[0xb7, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xe6]),
+        None,
+        None,
+        Some(&[0xc4, 0xf5]),
+        None,
+        None,
+        Some(&[0xe5, 0xb2]),
+        Some(&[0xc4, 0xfe]),
+        None,
+        Some(&[0xcb, 0xfc]),
+        Some(&[0xe5, 0xb3]),
+        Some(&[0xd5, 0xac]),
+        None,
+        Some(&[0xd3, 0xee]),
+        Some(&[0xca, 0xd8]),
+        Some(&[0xb0, 0xb2]),
+        None,
+        Some(&[0xcb, 0xce]),
+        Some(&[0xcd, 0xea]),
+        None,
+        None,
+        Some(&[0xba, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb5]),
+        None,
+        Some(&[0xe5, 0xb4]),
+        None,
+        Some(&[0xd7, 0xda]),
+        Some(&[0xb9, 0xd9]),
+        Some(&[0xd6, 0xe6]),
+        Some(&[0xb6, 0xa8]),
+        Some(&[0xcd, 0xf0]),
+        Some(&[0xd2, 0xcb]),
+        Some(&[0xb1, 0xa6]),
+        Some(&[0xca, 0xb5]),
+        None,
+        Some(&[0xb3, 0xe8]),
+        Some(&[0xc9, 0xf3]),
+        Some(&[0xbf, 0xcd]),
+        Some(&[0xd0, 0xfb]),
+        Some(&[0xca, 0xd2]),
+        Some(&[0xe5, 0xb6]),
+        Some(&[0xbb, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xdc]),
+        Some(&[0xb9, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xd7]),
+        None,
+        None,
+        Some(&[0xba, 0xa6]),
+        Some(&[0xd1, 0xe7]),
+        Some(&[0xcf, 0xfc]),
+        Some(&[0xbc, 0xd2]),
+        None,
+        Some(&[0xe5, 0xb7]),
+        Some(&[0xc8, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xed]),
+        Some(&[0xb1, 0xf6]),
+        Some(&[0xcb, 0xde]),
+        None,
+        None,
+        Some(&[0xbc, 0xc5]),
+        None,
+        Some(&[0xbc, 0xc4]),
+        Some(&[0xd2, 0xfa]),
+        Some(&[0xc3, 0xdc]),
+        Some(&[0xbf, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xc2]),
+        None,
+        Some(&[0xba, 0xae]),
+        Some(&[0xd4, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xde]),
+        Some(&[0xc4, 0xaf]),
+        Some(&[0xb2, 0xec]),
+        None,
+        Some(&[0xb9, 0xd1]),
+        None,
+        None,
+        Some(&[0xe5, 0xbb]),
+        Some(&[0xc1, 0xc8]),
+        None,
+        None,
+        Some(&[0xd5, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xbc]),
+        None,
+        Some(&[0xe5, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xe7]),
+        Some(&[0xb6, 0xd4]),
+        Some(&[0xcb, 0xc2]),
+        Some(&[0xd1, 0xb0]),
+        Some(&[0xb5, 0xbc]),
+        None,
+        None,
+        Some(&[0xca, 0xd9]),
+        None,
+        Some(&[0xb7, 0xe2]),
+        None,
+        None,
+        Some(&[0xc9, 0xe4]),
+        None,
+        Some(&[0xbd, 0xab]),
+        None,
+        None,
+        Some(&[0xce, 0xbe]),
+        Some(&[0xd7, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xa1]),
+        None,
+        Some(&[0xc9, 0xd9]),
+        None,
+        None,
+        Some(&[0xb6, 0xfb]),
+        Some(&[0xe6, 0xd8]),
+        Some(&[0xbc, 0xe2]),
+        None,
+        Some(&[0xb3, 0xbe]),
+        None,
+        Some(&[0xc9, 0xd0]),
+        None,
+        Some(&[0xe6, 0xd9]),
+        Some(&[0xb3, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xcc]),
+        None,
+        Some(&[0xd3, 0xc8]),
+        Some(&[0xde, 0xcd]),
+        None,
+        Some(&[0xd2, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xcd]),
+        None,
+        None,
+        Some(&[0xde, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xac]),
+        Some(&[0xd2, 0xfc]),
+        Some(&[0xb3, 0xdf]),
+        Some(&[0xe5, 0xea]),
+        Some(&[0xc4, 0xe1]),
+        Some(&[0xbe, 0xa1]),
+        Some(&[0xce, 0xb2]),
+        Some(&[0xc4, 0xf2]),
+        Some(&[0xbe, 0xd6]),
+        Some(&[0xc6, 0xa8]),
+        Some(&[0xb2, 0xe3]),
+        None,
+        None,
+        Some(&[0xbe, 0xd3]),
+        None,
+        None,
+        Some(&[0xc7, 0xfc]),
+        Some(&[0xcc, 0xeb]),
+        Some(&[0xbd, 0xec]),
+        Some(&[0xce, 0xdd]),
+        None,
+        None,
+        Some(&[0xca, 0xba]),
+        Some(&[0xc6, 0xc1]),
+        Some(&[0xe5, 0xec]),
+        Some(&[0xd0, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xf4]),
+        None,
+        Some(&[0xcd, 0xc0]),
+        Some(&[0xc2, 0xc5]),
+        None,
+        Some(&[0xe5, 0xef]),
+        None,
+        Some(&[0xc2, 0xc4]),
+        Some(&[0xe5, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf8]),
+        Some(&[0xcd, 0xcd]),
+        None,
+        Some(&[0xc9, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xd9]),
+        Some(&[0xe1, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xec]),
+        None,
+        Some(&[0xcb, 0xea]),
+        Some(&[0xc6, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa7]),
+        Some(&[0xe1, 0xa9]),
+        None,
+        None,
+        Some(&[0xe1, 0xaa]),
+        Some(&[0xe1, 0xaf]),
+        None,
+        None,
+        Some(&[0xb2, 0xed]),
+        None,
+        Some(&[0xe1, 0xab]),
+        Some(&[0xb8, 0xda]),
+        Some(&[0xe1, 0xad]),
+        Some(&[0xe1, 0xae]),
+        Some(&[0xe1, 0xb0]),
+        Some(&[0xb5, 0xba]),
+        Some(&[0xe1, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb3]),
+        Some(&[0xe1, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xd2]),
+        None,
+        Some(&[0xe1, 0xb6]),
+        Some(&[0xe1, 0xb5]),
+        Some(&[0xc1, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb7]),
+        None,
+        Some(&[0xd4, 0xc0]),
+        None,
+        Some(&[0xe1, 0xb2]),
+        None,
+        Some(&[0xe1, 0xba]),
+        Some(&[0xb0, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb4]),
+        None,
+        Some(&[0xbf, 0xf9]),
+        None,
+        Some(&[0xe1, 0xb9]),
+        None,
+        None,
+        Some(&[0xe1, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xbf]),
+        None,
+        None,
+        Some(&[0xe1, 0xbd]),
+        Some(&[0xe1, 0xbf]),
+        Some(&[0xc2, 0xcd]),
+        None,
+        Some(&[0xb6, 0xeb]),
+        None,
+        Some(&[0xd3, 0xf8]),
+        None,
+        None,
+        Some(&[0xc7, 0xcd]),
+        None,
+        None,
+        Some(&[0xb7, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc0]),
+        Some(&[0xe1, 0xc1]),
+        None,
+        None,
+        Some(&[0xe1, 0xc7]),
+        Some(&[0xb3, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xde]),
+        None,
+        Some(&[0xd1, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc8]),
+        None,
+        None,
+        Some(&[0xe1, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc5]),
+        None,
+        Some(&[0xe1, 0xc3]),
+        Some(&[0xe1, 0xc2]),
+        None,
+        Some(&[0xb1, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xb8]),
+        Some(&[0xe1, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xcc]),
+        Some(&[0xe1, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xfa]),
+        None,
+        None,
+        Some(&[0xe1, 0xd3]),
+        Some(&[0xe1, 0xd2]),
+        Some(&[0xc7, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc9]),
+        None,
+        None,
+        Some(&[0xe1, 0xce]),
+        None,
+        Some(&[0xe1, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd4]),
+        None,
+        Some(&[0xe1, 0xd1]),
+        Some(&[0xe1, 0xcd]),
+        None,
+        None,
+        Some(&[0xe1, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5DB0: [Option<&'static [u8]>; 896] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xdd]),
+        None,
+        Some(&[0xb4, 0xa8]),
+        Some(&[0xd6, 0xdd]),
+        None,
+        None,
+        Some(&[0xd1, 0xb2]),
+        Some(&[0xb3, 0xb2]),
+        None,
+        None,
+        Some(&[0xb9, 0xa4]),
+        Some(&[0xd7, 0xf3]),
+        Some(&[0xc7, 0xc9]),
+        Some(&[0xbe, 0xde]),
+        Some(&[0xb9, 0xae]),
+        None,
+        Some(&[0xce, 0xd7]),
+        None,
+        None,
+        Some(&[0xb2, 0xee]),
+        Some(&[0xdb, 0xcf]),
+        None,
+        Some(&[0xbc, 0xba]),
+        Some(&[0xd2, 0xd1]),
+        Some(&[0xcb, 0xc8]),
+        Some(&[0xb0, 0xcd]),
+        None,
+        None,
+        Some(&[0xcf, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe3]),
+        Some(&[0xbd, 0xed]),
+        None,
+        None,
+        Some(&[0xb1, 0xd2]),
+        Some(&[0xca, 0xd0]),
+        Some(&[0xb2, 0xbc]),
+        None,
+        Some(&[0xcb, 0xa7]),
+        Some(&[0xb7, 0xab]),
+        None,
+        Some(&[0xca, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xa3]),
+        None,
+        None,
+        Some(&[0xe0, 0xf8]),
+        Some(&[0xd5, 0xca]),
+        Some(&[0xe0, 0xfb]),
+        None,
+        None,
+        Some(&[0xe0, 0xfa]),
+        Some(&[0xc5, 0xc1]),
+        Some(&[0xcc, 0xfb]),
+        None,
+        Some(&[0xc1, 0xb1]),
+        Some(&[0xe0, 0xf9]),
+        Some(&[0xd6, 0xe3]),
+        Some(&[0xb2, 0xaf]),
+        Some(&[0xd6, 0xc4]),
+        Some(&[0xb5, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xf8]),
+        Some(&[0xd6, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xaf]),
+        Some(&[0xb0, 0xef]),
+        None,
+        None,
+        Some(&[0xe0, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa1]),
+        Some(&[0xb3, 0xa3]),
+        None,
+        None,
+        Some(&[0xe0, 0xfd]),
+        Some(&[0xe0, 0xfe]),
+        Some(&[0xc3, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xdd]),
+        None,
+        Some(&[0xe1, 0xa2]),
+        Some(&[0xb7, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa3]),
+        Some(&[0xc4, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa4]),
+        None,
+        None,
+        Some(&[0xe1, 0xa5]),
+        None,
+        None,
+        Some(&[0xe1, 0xa6]),
+        Some(&[0xb4, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xc9]),
+        Some(&[0xc6, 0xbd]),
+        Some(&[0xc4, 0xea]),
+        None,
+        Some(&[0xb2, 0xa2]),
+        None,
+        Some(&[0xd0, 0xd2]),
+        None,
+        Some(&[0xe7, 0xdb]),
+        Some(&[0xbb, 0xc3]),
+        Some(&[0xd3, 0xd7]),
+        Some(&[0xd3, 0xc4]),
+        None,
+        Some(&[0xb9, 0xe3]),
+        Some(&[0xe2, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xaf]),
+        None,
+        Some(&[0xc7, 0xec]),
+        Some(&[0xb1, 0xd3]),
+        None,
+        None,
+        Some(&[0xb4, 0xb2]),
+        Some(&[0xe2, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xf2]),
+        Some(&[0xc2, 0xae]),
+        Some(&[0xe2, 0xd0]),
+        None,
+        Some(&[0xbf, 0xe2]),
+        Some(&[0xd3, 0xa6]),
+        Some(&[0xb5, 0xd7]),
+        Some(&[0xe2, 0xd2]),
+        Some(&[0xb5, 0xea]),
+        None,
+        Some(&[0xc3, 0xed]),
+        Some(&[0xb8, 0xfd]),
+        None,
+        Some(&[0xb8, 0xae]),
+        None,
+        Some(&[0xc5, 0xd3]),
+        Some(&[0xb7, 0xcf]),
+        Some(&[0xe2, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd3]),
+        Some(&[0xb6, 0xc8]),
+        Some(&[0xd7, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd8]),
+        None,
+        Some(&[0xe2, 0xd6]),
+        Some(&[0xca, 0xfc]),
+        Some(&[0xbf, 0xb5]),
+        Some(&[0xd3, 0xb9]),
+        Some(&[0xe2, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xae]),
+        Some(&[0xc0, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdb]),
+        Some(&[0xe2, 0xda]),
+        Some(&[0xc0, 0xaa]),
+        None,
+        None,
+        Some(&[0xc1, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdd]),
+        None,
+        Some(&[0xe2, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc8]),
+        None,
+        Some(&[0xd1, 0xd3]),
+        Some(&[0xcd, 0xa2]),
+        None,
+        None,
+        Some(&[0xbd, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xc3]),
+        Some(&[0xd8, 0xa5]),
+        Some(&[0xbf, 0xaa]),
+        Some(&[0xdb, 0xcd]),
+        Some(&[0xd2, 0xec]),
+        Some(&[0xc6, 0xfa]),
+        Some(&[0xc5, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xc4]),
+        None,
+        Some(&[0xb1, 0xd7]),
+        Some(&[0xdf, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xbd]),
+        None,
+        Some(&[0xdf, 0xb1]),
+        None,
+        Some(&[0xb9, 0xad]),
+        None,
+        Some(&[0xd2, 0xfd]),
+        None,
+        Some(&[0xb8, 0xa5]),
+        Some(&[0xba, 0xeb]),
+        None,
+        None,
+        Some(&[0xb3, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xdc]),
+        Some(&[0xd5, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xd6]),
+        Some(&[0xcf, 0xd2]),
+        Some(&[0xbb, 0xa1]),
+        None,
+        Some(&[0xe5, 0xf3]),
+        Some(&[0xe5, 0xf2]),
+        None,
+        None,
+        Some(&[0xe5, 0xf4]),
+        None,
+        Some(&[0xcd, 0xe4]),
+        None,
+        Some(&[0xc8, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xaf]),
+        Some(&[0xc7, 0xbf]),
+        None,
+        Some(&[0xe5, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe6]),
+        None,
+        Some(&[0xb9, 0xe9]),
+        Some(&[0xb5, 0xb1]),
+        None,
+        Some(&[0xc2, 0xbc]),
+        Some(&[0xe5, 0xe8]),
+        Some(&[0xe5, 0xe7]),
+        Some(&[0xe5, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xea]),
+        Some(&[0xd0, 0xce]),
+        None,
+        Some(&[0xcd, 0xae]),
+        None,
+        Some(&[0xd1, 0xe5]),
+        None,
+        None,
+        Some(&[0xb2, 0xca]),
+        Some(&[0xb1, 0xeb]),
+        None,
+        Some(&[0xb1, 0xf2]),
+        Some(&[0xc5, 0xed]),
+        None,
+        None,
+        Some(&[0xd5, 0xc3]),
+        Some(&[0xd3, 0xb0]),
+        None,
+        Some(&[0xe1, 0xdc]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xdd]),
+        None,
+        Some(&[0xd2, 0xdb]),
+        None,
+        Some(&[0xb3, 0xb9]),
+        Some(&[0xb1, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xf9]),
+        Some(&[0xd5, 0xf7]),
+        Some(&[0xe1, 0xde]),
+        None,
+        Some(&[0xbe, 0xb6]),
+        Some(&[0xb4, 0xfd]),
+        None,
+        Some(&[0xe1, 0xdf]),
+        Some(&[0xba, 0xdc]),
+        Some(&[0xe1, 0xe0]),
+        Some(&[0xbb, 0xb2]),
+        Some(&[0xc2, 0xc9]),
+        Some(&[0xe1, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xec]),
+        None,
+        Some(&[0xcd, 0xbd]),
+        None,
+        None,
+        Some(&[0xe1, 0xe2]),
+        None,
+        Some(&[0xb5, 0xc3]),
+        Some(&[0xc5, 0xc7]),
+        Some(&[0xe1, 0xe3]),
+        None,
+        None,
+        Some(&[0xe1, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe5]),
+        None,
+        Some(&[0xd1, 0xad]),
+        None,
+        None,
+        Some(&[0xe1, 0xe6]),
+        Some(&[0xce, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe7]),
+        None,
+        Some(&[0xb5, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe8]),
+        Some(&[0xbb, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xc4]),
+        Some(&[0xe2, 0xe0]),
+        Some(&[0xb1, 0xd8]),
+        Some(&[0xd2, 0xe4]),
+        None,
+        None,
+        Some(&[0xe2, 0xe1]),
+        None,
+        None,
+        Some(&[0xbc, 0xc9]),
+        Some(&[0xc8, 0xcc]),
+        None,
+        Some(&[0xe2, 0xe3]),
+        Some(&[0xec, 0xfe]),
+        Some(&[0xec, 0xfd]),
+        Some(&[0xdf, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xe2]),
+        Some(&[0xd6, 0xbe]),
+        Some(&[0xcd, 0xfc]),
+        Some(&[0xc3, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc3]),
+        None,
+        None,
+        Some(&[0xd6, 0xd2]),
+        Some(&[0xe2, 0xe7]),
+        None,
+        None,
+        Some(&[0xe2, 0xe8]),
+        None,
+        None,
+        Some(&[0xd3, 0xc7]),
+        None,
+        None,
+        Some(&[0xe2, 0xec]),
+        Some(&[0xbf, 0xec]),
+        None,
+        Some(&[0xe2, 0xed]),
+        Some(&[0xe2, 0xe5]),
+        None,
+        None,
+        Some(&[0xb3, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xee]),
+        None,
+        None,
+        Some(&[0xe2, 0xee]),
+        None,
+        None,
+        Some(&[0xd0, 0xc3]),
+        None,
+        Some(&[0xba, 0xf6]),
+        Some(&[0xe2, 0xe9]),
+        Some(&[0xb7, 0xde]),
+        Some(&[0xbb, 0xb3]),
+        Some(&[0xcc, 0xac]),
+        Some(&[0xcb, 0xcb]),
+        Some(&[0xe2, 0xe4]),
+        Some(&[0xe2, 0xe6]),
+        Some(&[0xe2, 0xea]),
+        Some(&[0xe2, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf7]),
+        None,
+        None,
+        Some(&[0xe2, 0xf4]),
+        Some(&[0xd4, 0xf5]),
+        Some(&[0xe2, 0xf3]),
+        None,
+        None,
+        Some(&[0xc5, 0xad]),
+        None,
+        Some(&[0xd5, 0xfa]),
+        Some(&[0xc5, 0xc2]),
+        Some(&[0xb2, 0xc0]),
+        None,
+        None,
+        Some(&[0xe2, 0xef]),
+        None,
+        Some(&[0xe2, 0xf2]),
+        Some(&[0xc1, 0xaf]),
+        Some(&[0xcb, 0xbc]),
+        None,
+        None,
+        Some(&[0xb5, 0xa1]),
+        Some(&[0xe2, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xb1]),
+        Some(&[0xe2, 0xf1]),
+        Some(&[0xd0, 0xd4]),
+        Some(&[0xd4, 0xb9]),
+        Some(&[0xe2, 0xf5]),
+        Some(&[0xb9, 0xd6]),
+        Some(&[0xe2, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xdc]),
+        Some(&[0xed, 0xa1]),
+        None,
+        None,
+        Some(&[0xe2, 0xf8]),
+        None,
+        Some(&[0xed, 0xa5]),
+        Some(&[0xe2, 0xfe]),
+        Some(&[0xca, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xb5]),
+        None,
+        Some(&[0xbb, 0xd0]),
+        None,
+        None,
+        Some(&[0xbf, 0xd6]),
+        None,
+        Some(&[0xba, 0xe3]),
+        None,
+        None,
+        Some(&[0xcb, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xa6]),
+        Some(&[0xed, 0xa3]),
+        None,
+        None,
+        Some(&[0xed, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xd6]),
+        Some(&[0xed, 0xa7]),
+        Some(&[0xd0, 0xf4]),
+        None,
+        None,
+        Some(&[0xed, 0xa4]),
+        Some(&[0xba, 0xde]),
+        Some(&[0xb6, 0xf7]),
+        Some(&[0xe3, 0xa1]),
+        Some(&[0xb6, 0xb2]),
+        Some(&[0xcc, 0xf1]),
+        Some(&[0xb9, 0xa7]),
+        None,
+        Some(&[0xcf, 0xa2]),
+        Some(&[0xc7, 0xa1]),
+        None,
+        None,
+        Some(&[0xbf, 0xd2]),
+        None,
+        None,
+        Some(&[0xb6, 0xf1]),
+        None,
+        Some(&[0xe2, 0xfa]),
+        Some(&[0xe2, 0xfb]),
+        Some(&[0xe2, 0xfd]),
+        Some(&[0xe2, 0xfc]),
+        Some(&[0xc4, 0xd5]),
+        Some(&[0xe3, 0xa2]),
+        None,
+        Some(&[0xd3, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xa7]),
+        Some(&[0xc7, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xa4]),
+        None,
+        None,
+        Some(&[0xe3, 0xa9]),
+        Some(&[0xba, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xa8]),
+        None,
+        Some(&[0xbb, 0xda]),
+        None,
+        Some(&[0xe3, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xa4]),
+        Some(&[0xe3, 0xaa]),
+        None,
+        Some(&[0xe3, 0xa6]),
+        None,
+        Some(&[0xce, 0xf2]),
+        Some(&[0xd3, 0xc6]),
+        None,
+        None,
+        Some(&[0xbb, 0xbc]),
+        None,
+        None,
+        Some(&[0xd4, 0xc3]),
+        None,
+        Some(&[0xc4, 0xfa]),
+        None,
+        None,
+        Some(&[0xed, 0xa8]),
+        Some(&[0xd0, 0xfc]),
+        Some(&[0xe3, 0xa5]),
+        None,
+        Some(&[0xc3, 0xf5]),
+        None,
+        Some(&[0xe3, 0xad]),
+        Some(&[0xb1, 0xaf]),
+        None,
+        Some(&[0xe3, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xc2]),
+        None,
+        None,
+        Some(&[0xe3, 0xac]),
+        Some(&[0xb5, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xe9]),
+        Some(&[0xe3, 0xb0]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xaa]),
+        Some(&[0xcd, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xe8]),
+        None,
+        None,
+        Some(&[0xe3, 0xaf]),
+        None,
+        Some(&[0xe3, 0xb1]),
+        None,
+        Some(&[0xcf, 0xa7]),
+        Some(&[0xe3, 0xae]),
+        None,
+        Some(&[0xce, 0xa9]),
+        Some(&[0xbb, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xeb]),
+        Some(&[0xbe, 0xe5]),
+        Some(&[0xb2, 0xd2]),
+        Some(&[0xb3, 0xcd]),
+        None,
+        Some(&[0xb1, 0xb9]),
+        Some(&[0xe3, 0xab]),
+        Some(&[0xb2, 0xd1]),
+        Some(&[0xb5, 0xac]),
+        Some(&[0xb9, 0xdf]),
+        Some(&[0xb6, 0xe8]),
+        None,
+        None,
+        Some(&[0xcf, 0xeb]),
+        Some(&[0xe3, 0xb7]),
+        None,
+        Some(&[0xbb, 0xcc]),
+        None,
+        None,
+        Some(&[0xc8, 0xc7]),
+        Some(&[0xd0, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xb8]),
+        Some(&[0xb3, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xa9]),
+        None,
+        Some(&[0xd3, 0xfa]),
+        Some(&[0xd3, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xaa]),
+        Some(&[0xe3, 0xb9]),
+        Some(&[0xd2, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xd0]),
+        Some(&[0xe3, 0xb3]),
+        None,
+        None,
+        Some(&[0xe3, 0xb6]),
+        Some(&[0xb7, 0xdf]),
+        None,
+        Some(&[0xe3, 0xb4]),
+        Some(&[0xc0, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xba]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6138: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xc8]),
+        None,
+        Some(&[0xe3, 0xbb]),
+        None,
+        Some(&[0xbb, 0xc5]),
+        None,
+        Some(&[0xc9, 0xf7]),
+        None,
+        None,
+        Some(&[0xc9, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xdb]),
+        Some(&[0xbf, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xbc]),
+        None,
+        Some(&[0xbf, 0xb6]),
+    ];
+
+    const BASE_0X6188: [Option<&'static [u8]>; 80] = [
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xef]),
+        None,
+        None,
+        Some(&[0xd4, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xbf]),
+        Some(&[0xba, 0xa9]),
+        Some(&[0xed, 0xac]),
+        None,
+        None,
+        Some(&[0xe3, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb8]),
+        None,
+        Some(&[0xb0, 0xc3]),
+        Some(&[0xed, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xaf]),
+        Some(&[0xc0, 0xc1]),
+        None,
+        Some(&[0xe3, 0xc1]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X61E0: [Option<&'static [u8]>; 776] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xb0]),
+        None,
+        Some(&[0xb8, 0xea]),
+        None,
+        Some(&[0xce, 0xec]),
+        Some(&[0xea, 0xa7]),
+        Some(&[0xd0, 0xe7]),
+        Some(&[0xca, 0xf9]),
+        Some(&[0xc8, 0xd6]),
+        Some(&[0xcf, 0xb7]),
+        Some(&[0xb3, 0xc9]),
+        Some(&[0xce, 0xd2]),
+        Some(&[0xbd, 0xe4]),
+        None,
+        None,
+        Some(&[0xe3, 0xde]),
+        Some(&[0xbb, 0xf2]),
+        Some(&[0xea, 0xa8]),
+        Some(&[0xd5, 0xbd]),
+        None,
+        Some(&[0xc6, 0xdd]),
+        Some(&[0xea, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xaa]),
+        None,
+        Some(&[0xea, 0xac]),
+        Some(&[0xea, 0xab]),
+        None,
+        Some(&[0xea, 0xae]),
+        Some(&[0xea, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xd8]),
+        None,
+        Some(&[0xea, 0xaf]),
+        None,
+        Some(&[0xc2, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xc1]),
+        Some(&[0xb4, 0xf7]),
+        None,
+        None,
+        Some(&[0xbb, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xe6]),
+        Some(&[0xec, 0xe5]),
+        Some(&[0xb7, 0xbf]),
+        Some(&[0xcb, 0xf9]),
+        Some(&[0xb1, 0xe2]),
+        None,
+        Some(&[0xec, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xc8]),
+        Some(&[0xec, 0xe8]),
+        Some(&[0xec, 0xe9]),
+        None,
+        Some(&[0xca, 0xd6]),
+        Some(&[0xde, 0xd0]),
+        Some(&[0xb2, 0xc5]),
+        Some(&[0xd4, 0xfa]),
+        None,
+        None,
+        Some(&[0xc6, 0xcb]),
+        Some(&[0xb0, 0xc7]),
+        Some(&[0xb4, 0xf2]),
+        Some(&[0xc8, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xd0]),
+        None,
+        None,
+        Some(&[0xbf, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xdb]),
+        None,
+        None,
+        Some(&[0xc7, 0xa4]),
+        Some(&[0xd6, 0xb4]),
+        None,
+        Some(&[0xc0, 0xa9]),
+        Some(&[0xde, 0xd1]),
+        Some(&[0xc9, 0xa8]),
+        Some(&[0xd1, 0xef]),
+        Some(&[0xc5, 0xa4]),
+        Some(&[0xb0, 0xe7]),
+        Some(&[0xb3, 0xb6]),
+        Some(&[0xc8, 0xc5]),
+        None,
+        None,
+        Some(&[0xb0, 0xe2]),
+        None,
+        None,
+        Some(&[0xb7, 0xf6]),
+        None,
+        None,
+        Some(&[0xc5, 0xfa]),
+        None,
+        None,
+        Some(&[0xb6, 0xf3]),
+        None,
+        Some(&[0xd5, 0xd2]),
+        Some(&[0xb3, 0xd0]),
+        Some(&[0xbc, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xf1]),
+        Some(&[0xb0, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xd6]),
+        Some(&[0xca, 0xe3]),
+        Some(&[0xd7, 0xa5]),
+        None,
+        Some(&[0xcd, 0xb6]),
+        Some(&[0xb6, 0xb6]),
+        Some(&[0xbf, 0xb9]),
+        Some(&[0xd5, 0xdb]),
+        None,
+        Some(&[0xb8, 0xa7]),
+        Some(&[0xc5, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xd2]),
+        Some(&[0xbf, 0xd9]),
+        Some(&[0xc2, 0xd5]),
+        Some(&[0xc7, 0xc0]),
+        None,
+        Some(&[0xbb, 0xa4]),
+        Some(&[0xb1, 0xa8]),
+        None,
+        None,
+        Some(&[0xc5, 0xea]),
+        None,
+        None,
+        Some(&[0xc5, 0xfb]),
+        Some(&[0xcc, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xa8]),
+        None,
+        Some(&[0xde, 0xd3]),
+        Some(&[0xd1, 0xba]),
+        Some(&[0xb3, 0xe9]),
+        None,
+        Some(&[0xc3, 0xf2]),
+        None,
+        None,
+        Some(&[0xb7, 0xf7]),
+        None,
+        Some(&[0xd6, 0xf4]),
+        Some(&[0xb5, 0xa3]),
+        Some(&[0xb2, 0xf0]),
+        Some(&[0xc4, 0xb4]),
+        Some(&[0xc4, 0xe9]),
+        Some(&[0xc0, 0xad]),
+        Some(&[0xde, 0xd4]),
+        None,
+        Some(&[0xb0, 0xe8]),
+        Some(&[0xc5, 0xc4]),
+        Some(&[0xc1, 0xe0]),
+        None,
+        Some(&[0xb9, 0xd5]),
+        None,
+        Some(&[0xbe, 0xdc]),
+        Some(&[0xcd, 0xd8]),
+        Some(&[0xb0, 0xce]),
+        None,
+        Some(&[0xcd, 0xcf]),
+        Some(&[0xde, 0xd6]),
+        Some(&[0xbe, 0xd0]),
+        Some(&[0xd7, 0xbe]),
+        Some(&[0xde, 0xd5]),
+        Some(&[0xd5, 0xd0]),
+        Some(&[0xb0, 0xdd]),
+        None,
+        None,
+        Some(&[0xc4, 0xe2]),
+        None,
+        None,
+        Some(&[0xc2, 0xa3]),
+        Some(&[0xbc, 0xf0]),
+        None,
+        Some(&[0xd3, 0xb5]),
+        Some(&[0xc0, 0xb9]),
+        Some(&[0xc5, 0xa1]),
+        Some(&[0xb2, 0xa6]),
+        Some(&[0xd4, 0xf1]),
+        None,
+        None,
+        Some(&[0xc0, 0xa8]),
+        Some(&[0xca, 0xc3]),
+        Some(&[0xde, 0xd7]),
+        Some(&[0xd5, 0xfc]),
+        None,
+        Some(&[0xb9, 0xb0]),
+        None,
+        Some(&[0xc8, 0xad]),
+        Some(&[0xcb, 0xa9]),
+        None,
+        Some(&[0xde, 0xd9]),
+        Some(&[0xbf, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xb4]),
+        Some(&[0xd7, 0xa7]),
+        Some(&[0xca, 0xb0]),
+        Some(&[0xc4, 0xc3]),
+        None,
+        Some(&[0xb3, 0xd6]),
+        Some(&[0xb9, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xb8]),
+        Some(&[0xea, 0xfc]),
+        Some(&[0xb0, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xe6]),
+        None,
+        None,
+        Some(&[0xcc, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xbf]),
+        Some(&[0xc2, 0xce]),
+        None,
+        Some(&[0xce, 0xce]),
+        Some(&[0xcc, 0xa2]),
+        Some(&[0xd0, 0xae]),
+        Some(&[0xc4, 0xd3]),
+        Some(&[0xb5, 0xb2]),
+        Some(&[0xde, 0xd8]),
+        Some(&[0xd5, 0xf5]),
+        Some(&[0xbc, 0xb7]),
+        Some(&[0xbb, 0xd3]),
+        None,
+        None,
+        Some(&[0xb0, 0xa4]),
+        None,
+        Some(&[0xc5, 0xb2]),
+        Some(&[0xb4, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xf1]),
+        None,
+        None,
+        Some(&[0xea, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xda]),
+        Some(&[0xcd, 0xa6]),
+        None,
+        None,
+        Some(&[0xcd, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xe6]),
+        Some(&[0xde, 0xdc]),
+        None,
+        Some(&[0xcd, 0xb1]),
+        Some(&[0xc0, 0xa6]),
+        None,
+        None,
+        Some(&[0xd7, 0xbd]),
+        None,
+        Some(&[0xde, 0xdb]),
+        Some(&[0xb0, 0xc6]),
+        Some(&[0xba, 0xb4]),
+        Some(&[0xc9, 0xd3]),
+        Some(&[0xc4, 0xf3]),
+        Some(&[0xbe, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xcc]),
+        Some(&[0xcb, 0xf0]),
+        None,
+        Some(&[0xbc, 0xf1]),
+        Some(&[0xbb, 0xbb]),
+        Some(&[0xb5, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xf5]),
+        None,
+        Some(&[0xde, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xe3]),
+        Some(&[0xbe, 0xdd]),
+        None,
+        None,
+        Some(&[0xde, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xb7]),
+        Some(&[0xbd, 0xdd]),
+        None,
+        None,
+        Some(&[0xde, 0xe0]),
+        Some(&[0xc4, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xc6]),
+        None,
+        Some(&[0xb5, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xde]),
+        Some(&[0xca, 0xda]),
+        Some(&[0xb5, 0xf4]),
+        Some(&[0xde, 0xe5]),
+        None,
+        Some(&[0xd5, 0xc6]),
+        None,
+        Some(&[0xde, 0xe1]),
+        Some(&[0xcc, 0xcd]),
+        Some(&[0xc6, 0xfe]),
+        None,
+        Some(&[0xc5, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xb4]),
+        None,
+        Some(&[0xbe, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xd3]),
+        None,
+        Some(&[0xcc, 0xbd]),
+        Some(&[0xb3, 0xb8]),
+        None,
+        Some(&[0xbd, 0xd3]),
+        None,
+        Some(&[0xbf, 0xd8]),
+        Some(&[0xcd, 0xc6]),
+        Some(&[0xd1, 0xda]),
+        Some(&[0xb4, 0xeb]),
+        None,
+        Some(&[0xde, 0xe4]),
+        Some(&[0xde, 0xdd]),
+        Some(&[0xde, 0xe7]),
+        None,
+        Some(&[0xea, 0xfe]),
+        None,
+        None,
+        Some(&[0xc2, 0xb0]),
+        Some(&[0xde, 0xe2]),
+        None,
+        None,
+        Some(&[0xd6, 0xc0]),
+        Some(&[0xb5, 0xa7]),
+        None,
+        Some(&[0xb2, 0xf4]),
+        None,
+        Some(&[0xde, 0xe8]),
+        None,
+        Some(&[0xde, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xed]),
+        None,
+        Some(&[0xde, 0xf1]),
+        None,
+        None,
+        Some(&[0xc8, 0xe0]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xe1]),
+        Some(&[0xde, 0xef]),
+        Some(&[0xc3, 0xe8]),
+        Some(&[0xcc, 0xe1]),
+        None,
+        Some(&[0xb2, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xee]),
+        None,
+        Some(&[0xde, 0xeb]),
+        Some(&[0xce, 0xd5]),
+        None,
+        Some(&[0xb4, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xab]),
+        Some(&[0xbe, 0xbe]),
+        None,
+        None,
+        Some(&[0xbd, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xe9]),
+        None,
+        Some(&[0xd4, 0xae]),
+        None,
+        Some(&[0xde, 0xde]),
+        None,
+        Some(&[0xde, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xbf]),
+        None,
+        Some(&[0xde, 0xec]),
+        Some(&[0xb2, 0xf3]),
+        Some(&[0xb8, 0xe9]),
+        Some(&[0xc2, 0xa7]),
+        None,
+        None,
+        Some(&[0xbd, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xf5]),
+        Some(&[0xde, 0xf8]),
+        None,
+        None,
+        Some(&[0xb2, 0xab]),
+        Some(&[0xb4, 0xa4]),
+        None,
+        None,
+        Some(&[0xb4, 0xea]),
+        Some(&[0xc9, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xf6]),
+        Some(&[0xcb, 0xd1]),
+        None,
+        Some(&[0xb8, 0xe3]),
+        None,
+        Some(&[0xde, 0xf7]),
+        Some(&[0xde, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xc2]),
+        None,
+        Some(&[0xb0, 0xe1]),
+        Some(&[0xb4, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xaf]),
+        None,
+        None,
+        Some(&[0xb2, 0xeb]),
+        None,
+        Some(&[0xeb, 0xa1]),
+        None,
+        Some(&[0xde, 0xf4]),
+        None,
+        None,
+        Some(&[0xc9, 0xe3]),
+        Some(&[0xde, 0xf3]),
+        Some(&[0xb0, 0xda]),
+        Some(&[0xd2, 0xa1]),
+        Some(&[0xb1, 0xf7]),
+        None,
+        Some(&[0xcc, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xf0]),
+        None,
+        Some(&[0xcb, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xdd]),
+        None,
+        Some(&[0xc4, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xfe]),
+        Some(&[0xc4, 0xa1]),
+        Some(&[0xdf, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xcc]),
+        None,
+        Some(&[0xde, 0xfc]),
+        Some(&[0xbe, 0xef]),
+        None,
+        Some(&[0xc6, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xc5]),
+        Some(&[0xc8, 0xf6]),
+        None,
+        None,
+        Some(&[0xcb, 0xba]),
+        Some(&[0xde, 0xfe]),
+        None,
+        None,
+        Some(&[0xdf, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc3]),
+        None,
+        None,
+        Some(&[0xc7, 0xcb]),
+        Some(&[0xb2, 0xa5]),
+        Some(&[0xb4, 0xe9]),
+        None,
+        Some(&[0xd7, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xec]),
+        None,
+        Some(&[0xdf, 0xa2]),
+        Some(&[0xdf, 0xa3]),
+        None,
+        Some(&[0xdf, 0xa5]),
+        None,
+        Some(&[0xba, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xa6]),
+        None,
+        Some(&[0xc0, 0xde]),
+        None,
+        None,
+        Some(&[0xc9, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xd9]),
+        Some(&[0xc7, 0xe6]),
+        None,
+        Some(&[0xdf, 0xa7]),
+        None,
+        Some(&[0xc7, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xa8]),
+        Some(&[0xeb, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xaa]),
+        None,
+        Some(&[0xdf, 0xa9]),
+        None,
+        Some(&[0xb2, 0xc1]),
+        None,
+    ];
+
+    const BASE_0X6500: [Option<&'static [u8]>; 448] = [
+        Some(&[0xc5, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xf0]),
+        None,
+        None,
+        Some(&[0xdf, 0xad]),
+        Some(&[0xd6, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xb7]),
+        Some(&[0xeb, 0xb6]),
+        Some(&[0xca, 0xd5]),
+        None,
+        Some(&[0xd8, 0xfc]),
+        Some(&[0xb8, 0xc4]),
+        None,
+        Some(&[0xb9, 0xa5]),
+        None,
+        None,
+        Some(&[0xb7, 0xc5]),
+        Some(&[0xd5, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xca]),
+        None,
+        None,
+        Some(&[0xd0, 0xa7]),
+        Some(&[0xf4, 0xcd]),
+        None,
+        None,
+        Some(&[0xb5, 0xd0]),
+        None,
+        None,
+        Some(&[0xc3, 0xf4]),
+        None,
+        Some(&[0xbe, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xb7]),
+        Some(&[0xb0, 0xbd]),
+        None,
+        None,
+        Some(&[0xbd, 0xcc]),
+        None,
+        Some(&[0xc1, 0xb2]),
+        None,
+        Some(&[0xb1, 0xd6]),
+        Some(&[0xb3, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xd2]),
+        Some(&[0xc9, 0xa2]),
+        None,
+        None,
+        Some(&[0xb6, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xb8]),
+        Some(&[0xbe, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xfd]),
+        None,
+        Some(&[0xc7, 0xc3]),
+        None,
+        Some(&[0xd5, 0xfb]),
+        None,
+        None,
+        Some(&[0xb7, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xab]),
+        Some(&[0xb1, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xb3]),
+        Some(&[0xb0, 0xdf]),
+        None,
+        Some(&[0xec, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xb7]),
+        None,
+        Some(&[0xc1, 0xcf]),
+        None,
+        Some(&[0xf5, 0xfa]),
+        Some(&[0xd0, 0xb1]),
+        None,
+        None,
+        Some(&[0xd5, 0xe5]),
+        None,
+        Some(&[0xce, 0xd3]),
+        None,
+        None,
+        Some(&[0xbd, 0xef]),
+        Some(&[0xb3, 0xe2]),
+        None,
+        Some(&[0xb8, 0xab]),
+        None,
+        Some(&[0xd5, 0xb6]),
+        None,
+        Some(&[0xed, 0xbd]),
+        None,
+        Some(&[0xb6, 0xcf]),
+        None,
+        Some(&[0xcb, 0xb9]),
+        Some(&[0xd0, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xbd]),
+        None,
+        None,
+        Some(&[0xec, 0xb6]),
+        Some(&[0xca, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xd4]),
+        None,
+        Some(&[0xec, 0xb9]),
+        Some(&[0xec, 0xb8]),
+        Some(&[0xc2, 0xc3]),
+        Some(&[0xec, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xfd]),
+        Some(&[0xec, 0xba]),
+        None,
+        Some(&[0xec, 0xbb]),
+        Some(&[0xd7, 0xe5]),
+        None,
+        None,
+        Some(&[0xec, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xbd]),
+        Some(&[0xc6, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xde]),
+        None,
+        Some(&[0xbc, 0xc8]),
+        None,
+        None,
+        Some(&[0xc8, 0xd5]),
+        Some(&[0xb5, 0xa9]),
+        Some(&[0xbe, 0xc9]),
+        Some(&[0xd6, 0xbc]),
+        Some(&[0xd4, 0xe7]),
+        None,
+        None,
+        Some(&[0xd1, 0xae]),
+        Some(&[0xd0, 0xf1]),
+        Some(&[0xea, 0xb8]),
+        Some(&[0xea, 0xb9]),
+        Some(&[0xea, 0xba]),
+        Some(&[0xba, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xb1]),
+        Some(&[0xbf, 0xf5]),
+        None,
+        None,
+        Some(&[0xcd, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xc0]),
+        None,
+        Some(&[0xb0, 0xba]),
+        Some(&[0xea, 0xbe]),
+        None,
+        None,
+        Some(&[0xc0, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xbb]),
+        None,
+        Some(&[0xb2, 0xfd]),
+        None,
+        Some(&[0xc3, 0xf7]),
+        Some(&[0xbb, 0xe8]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xd7]),
+        Some(&[0xce, 0xf4]),
+        Some(&[0xea, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xc3]),
+        None,
+        Some(&[0xd0, 0xc7]),
+        Some(&[0xd3, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xba]),
+        None,
+        Some(&[0xc3, 0xc1]),
+        Some(&[0xd7, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xd1]),
+        None,
+        Some(&[0xca, 0xc7]),
+        None,
+        Some(&[0xea, 0xc5]),
+        None,
+        None,
+        Some(&[0xea, 0xc4]),
+        Some(&[0xea, 0xc7]),
+        Some(&[0xea, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xe7]),
+        None,
+        Some(&[0xcf, 0xd4]),
+        None,
+        None,
+        Some(&[0xea, 0xcb]),
+        None,
+        Some(&[0xbb, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xfa]),
+        Some(&[0xc9, 0xce]),
+        None,
+        None,
+        Some(&[0xea, 0xcc]),
+        None,
+        None,
+        Some(&[0xc9, 0xb9]),
+        Some(&[0xcf, 0xfe]),
+        Some(&[0xea, 0xca]),
+        Some(&[0xd4, 0xce]),
+        Some(&[0xea, 0xcd]),
+        Some(&[0xea, 0xcf]),
+        None,
+        None,
+        Some(&[0xcd, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xc9]),
+        None,
+        Some(&[0xea, 0xce]),
+        None,
+        None,
+        Some(&[0xce, 0xee]),
+        None,
+        Some(&[0xbb, 0xde]),
+        None,
+        Some(&[0xb3, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xd5]),
+        Some(&[0xbe, 0xb0]),
+        Some(&[0xce, 0xfa]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xe7]),
+        None,
+        Some(&[0xbe, 0xa7]),
+        Some(&[0xea, 0xd0]),
+        None,
+        None,
+        Some(&[0xd6, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xdd]),
+        None,
+        Some(&[0xea, 0xd1]),
+        None,
+        None,
+        Some(&[0xcf, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xaf]),
+        Some(&[0xb0, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xd3]),
+        Some(&[0xf4, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xd5]),
+        None,
+    ];
+
+    const BASE_0X66D8: [Option<&'static [u8]>; 624] = [
+        None,
+        Some(&[0xca, 0xef]),
+        None,
+        Some(&[0xea, 0xd6]),
+        Some(&[0xea, 0xd7]),
+        Some(&[0xc6, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xd8]),
+        None,
+        None,
+        Some(&[0xea, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xbb]),
+        None,
+        Some(&[0xc7, 0xfa]),
+        Some(&[0xd2, 0xb7]),
+        Some(&[0xb8, 0xfc]),
+        None,
+        None,
+        Some(&[0xea, 0xc2]),
+        None,
+        Some(&[0xb2, 0xdc]),
+        None,
+        None,
+        Some(&[0xc2, 0xfc]),
+        None,
+        Some(&[0xd4, 0xf8]),
+        Some(&[0xcc, 0xe6]),
+        Some(&[0xd7, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xc2]),
+        Some(&[0xd3, 0xd0]),
+        Some(&[0xeb, 0xc3]),
+        Some(&[0xc5, 0xf3]),
+        None,
+        Some(&[0xb7, 0xfe]),
+        None,
+        None,
+        Some(&[0xeb, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xb7]),
+        Some(&[0xeb, 0xde]),
+        None,
+        Some(&[0xc0, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xfb]),
+        None,
+        Some(&[0xb3, 0xaf]),
+        None,
+        Some(&[0xc6, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xfc]),
+        None,
+        Some(&[0xc4, 0xbe]),
+        None,
+        Some(&[0xce, 0xb4]),
+        Some(&[0xc4, 0xa9]),
+        Some(&[0xb1, 0xbe]),
+        Some(&[0xd4, 0xfd]),
+        None,
+        Some(&[0xca, 0xf5]),
+        None,
+        Some(&[0xd6, 0xec]),
+        None,
+        None,
+        Some(&[0xc6, 0xd3]),
+        Some(&[0xb6, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xfa]),
+        None,
+        None,
+        Some(&[0xd0, 0xe0]),
+        None,
+        None,
+        Some(&[0xc9, 0xb1]),
+        None,
+        Some(&[0xd4, 0xd3]),
+        Some(&[0xc8, 0xa8]),
+        None,
+        None,
+        Some(&[0xb8, 0xcb]),
+        None,
+        Some(&[0xe8, 0xbe]),
+        Some(&[0xc9, 0xbc]),
+        None,
+        None,
+        Some(&[0xe8, 0xbb]),
+        None,
+        Some(&[0xc0, 0xee]),
+        Some(&[0xd0, 0xd3]),
+        Some(&[0xb2, 0xc4]),
+        Some(&[0xb4, 0xe5]),
+        None,
+        Some(&[0xe8, 0xbc]),
+        None,
+        None,
+        Some(&[0xd5, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xc5]),
+        None,
+        Some(&[0xe8, 0xbd]),
+        Some(&[0xca, 0xf8]),
+        Some(&[0xb8, 0xdc]),
+        Some(&[0xcc, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xb4]),
+        None,
+        None,
+        Some(&[0xd1, 0xee]),
+        Some(&[0xe8, 0xbf]),
+        Some(&[0xe8, 0xc2]),
+        None,
+        None,
+        Some(&[0xba, 0xbc]),
+        None,
+        Some(&[0xb1, 0xad]),
+        Some(&[0xbd, 0xdc]),
+        None,
+        Some(&[0xea, 0xbd]),
+        Some(&[0xe8, 0xc3]),
+        None,
+        Some(&[0xe8, 0xc6]),
+        None,
+        Some(&[0xe8, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xcc]),
+        None,
+        Some(&[0xcb, 0xc9]),
+        Some(&[0xb0, 0xe5]),
+        None,
+        Some(&[0xbc, 0xab]),
+        None,
+        None,
+        Some(&[0xb9, 0xb9]),
+        None,
+        None,
+        Some(&[0xe8, 0xc1]),
+        None,
+        Some(&[0xcd, 0xf7]),
+        None,
+        Some(&[0xe8, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xed]),
+        None,
+        Some(&[0xc1, 0xd6]),
+        Some(&[0xe8, 0xc4]),
+        None,
+        Some(&[0xc3, 0xb6]),
+        None,
+        Some(&[0xb9, 0xfb]),
+        Some(&[0xd6, 0xa6]),
+        Some(&[0xe8, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xe0]),
+        Some(&[0xd4, 0xe6]),
+        None,
+        Some(&[0xe8, 0xc0]),
+        None,
+        Some(&[0xe8, 0xc5]),
+        Some(&[0xe8, 0xc7]),
+        None,
+        Some(&[0xc7, 0xb9]),
+        Some(&[0xb7, 0xe3]),
+        None,
+        Some(&[0xe8, 0xc9]),
+        None,
+        Some(&[0xbf, 0xdd]),
+        Some(&[0xe8, 0xd2]),
+        None,
+        None,
+        Some(&[0xe8, 0xd7]),
+        None,
+        Some(&[0xe8, 0xd5]),
+        Some(&[0xbc, 0xdc]),
+        Some(&[0xbc, 0xcf]),
+        Some(&[0xe8, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xde]),
+        None,
+        Some(&[0xe8, 0xda]),
+        Some(&[0xb1, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xd8]),
+        Some(&[0xc4, 0xb3]),
+        Some(&[0xb8, 0xcc]),
+        Some(&[0xc6, 0xe2]),
+        Some(&[0xc8, 0xbe]),
+        Some(&[0xc8, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xcf]),
+        Some(&[0xe8, 0xd4]),
+        Some(&[0xe8, 0xd6]),
+        None,
+        Some(&[0xb9, 0xf1]),
+        Some(&[0xe8, 0xd8]),
+        Some(&[0xd7, 0xf5]),
+        None,
+        Some(&[0xc4, 0xfb]),
+        None,
+        Some(&[0xe8, 0xdc]),
+        None,
+        None,
+        Some(&[0xb2, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd1]),
+        None,
+        None,
+        Some(&[0xbc, 0xed]),
+        None,
+        None,
+        Some(&[0xbf, 0xc2]),
+        Some(&[0xe8, 0xcd]),
+        Some(&[0xd6, 0xf9]),
+        None,
+        Some(&[0xc1, 0xf8]),
+        Some(&[0xb2, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xdf]),
+        None,
+        Some(&[0xca, 0xc1]),
+        Some(&[0xe8, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xa4]),
+        None,
+        Some(&[0xb1, 0xea]),
+        Some(&[0xd5, 0xbb]),
+        Some(&[0xe8, 0xce]),
+        Some(&[0xe8, 0xd0]),
+        Some(&[0xb6, 0xb0]),
+        Some(&[0xe8, 0xd3]),
+        None,
+        Some(&[0xe8, 0xdd]),
+        Some(&[0xc0, 0xb8]),
+        None,
+        Some(&[0xca, 0xf7]),
+        None,
+        Some(&[0xcb, 0xa8]),
+        None,
+        None,
+        Some(&[0xc6, 0xdc]),
+        Some(&[0xc0, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf2]),
+        Some(&[0xd6, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe0]),
+        Some(&[0xe8, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf9]),
+        Some(&[0xba, 0xcb]),
+        Some(&[0xb8, 0xf9]),
+        None,
+        None,
+        Some(&[0xb8, 0xf1]),
+        Some(&[0xd4, 0xd4]),
+        Some(&[0xe8, 0xef]),
+        None,
+        Some(&[0xe8, 0xee]),
+        Some(&[0xe8, 0xec]),
+        Some(&[0xb9, 0xf0]),
+        Some(&[0xcc, 0xd2]),
+        Some(&[0xe8, 0xe6]),
+        Some(&[0xce, 0xa6]),
+        Some(&[0xbf, 0xf2]),
+        None,
+        Some(&[0xb0, 0xb8]),
+        Some(&[0xe8, 0xf1]),
+        Some(&[0xe8, 0xf0]),
+        None,
+        Some(&[0xd7, 0xc0]),
+        None,
+        Some(&[0xe8, 0xe4]),
+        None,
+        Some(&[0xcd, 0xa9]),
+        Some(&[0xc9, 0xa3]),
+        None,
+        Some(&[0xbb, 0xb8]),
+        Some(&[0xbd, 0xdb]),
+        Some(&[0xe8, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe2]),
+        Some(&[0xe8, 0xe3]),
+        Some(&[0xe8, 0xe5]),
+        Some(&[0xb5, 0xb5]),
+        Some(&[0xe8, 0xe7]),
+        Some(&[0xc7, 0xc5]),
+        Some(&[0xe8, 0xeb]),
+        Some(&[0xe8, 0xed]),
+        Some(&[0xbd, 0xb0]),
+        Some(&[0xd7, 0xae]),
+        None,
+        Some(&[0xe8, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf5]),
+        None,
+        Some(&[0xcd, 0xb0]),
+        Some(&[0xe8, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xba]),
+        None,
+        Some(&[0xe8, 0xe8]),
+        None,
+        Some(&[0xc3, 0xb7]),
+        Some(&[0xb0, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xd2]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xce]),
+        Some(&[0xce, 0xe0]),
+        Some(&[0xc0, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xf3]),
+        None,
+        Some(&[0xcc, 0xdd]),
+        Some(&[0xd0, 0xb5]),
+        None,
+        None,
+        Some(&[0xca, 0xe1]),
+        None,
+        Some(&[0xe8, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xec]),
+        None,
+        Some(&[0xe8, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xde]),
+        None,
+        Some(&[0xc6, 0xe5]),
+        None,
+        Some(&[0xb9, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xf4]),
+        None,
+        None,
+        Some(&[0xd7, 0xd8]),
+        None,
+        None,
+        Some(&[0xbc, 0xac]),
+        None,
+        Some(&[0xc5, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xc4]),
+        None,
+        None,
+        Some(&[0xe9, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xad]),
+        None,
+        Some(&[0xe9, 0xa2]),
+        Some(&[0xc0, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xfe]),
+        Some(&[0xb9, 0xd7]),
+        None,
+        Some(&[0xe8, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa3]),
+        None,
+        Some(&[0xd6, 0xb2]),
+        Some(&[0xd7, 0xb5]),
+        None,
+        Some(&[0xe9, 0xa7]),
+        None,
+        Some(&[0xbd, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xfc]),
+        Some(&[0xe8, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xd6]),
+        None,
+        None,
+        Some(&[0xd2, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xaa]),
+        None,
+        Some(&[0xb4, 0xbb]),
+        None,
+        None,
+        Some(&[0xe9, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6950: [Option<&'static [u8]>; 352] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xa8]),
+        None,
+        None,
+        Some(&[0xe9, 0xa5]),
+        None,
+        None,
+        Some(&[0xb3, 0xfe]),
+        None,
+        None,
+        Some(&[0xe9, 0xac]),
+        Some(&[0xc0, 0xe3]),
+        None,
+        Some(&[0xe9, 0xaa]),
+        None,
+        None,
+        Some(&[0xe9, 0xb9]),
+        None,
+        None,
+        Some(&[0xe9, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xae]),
+        None,
+        None,
+        Some(&[0xe8, 0xfa]),
+        None,
+        None,
+        Some(&[0xe9, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xac]),
+        Some(&[0xe9, 0xb1]),
+        Some(&[0xe9, 0xba]),
+        None,
+        None,
+        Some(&[0xc2, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xaf]),
+        None,
+        Some(&[0xb8, 0xc5]),
+        None,
+        Some(&[0xe9, 0xad]),
+        None,
+        Some(&[0xd3, 0xdc]),
+        Some(&[0xe9, 0xb4]),
+        Some(&[0xe9, 0xb5]),
+        Some(&[0xe9, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xc6]),
+        Some(&[0xe9, 0xc5]),
+        None,
+        None,
+        Some(&[0xe9, 0xb0]),
+        None,
+        None,
+        Some(&[0xe9, 0xbb]),
+        Some(&[0xb0, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbc]),
+        Some(&[0xd5, 0xa5]),
+        None,
+        None,
+        Some(&[0xe9, 0xbe]),
+        None,
+        Some(&[0xe9, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc1]),
+        None,
+        None,
+        Some(&[0xc1, 0xf1]),
+        None,
+        None,
+        Some(&[0xc8, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc3]),
+        None,
+        Some(&[0xe9, 0xb3]),
+        None,
+        Some(&[0xe9, 0xb6]),
+        None,
+        Some(&[0xbb, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc4]),
+        Some(&[0xe9, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xdb]),
+        None,
+        Some(&[0xe9, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xcb]),
+        Some(&[0xe9, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xc1]),
+        None,
+        Some(&[0xc4, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd8]),
+        None,
+        Some(&[0xba, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc9]),
+        None,
+        Some(&[0xd3, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd7]),
+        Some(&[0xe9, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xcf]),
+        None,
+        None,
+        Some(&[0xc7, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd9]),
+        Some(&[0xb3, 0xc8]),
+        None,
+        Some(&[0xe9, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd6]),
+        None,
+        None,
+        Some(&[0xe9, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd5]),
+        None,
+        Some(&[0xe9, 0xdc]),
+        Some(&[0xe9, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xdd]),
+        None,
+        Some(&[0xe9, 0xdf]),
+        Some(&[0xc3, 0xca]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6B20: [Option<&'static [u8]>; 1232] = [
+        Some(&[0xc7, 0xb7]),
+        Some(&[0xb4, 0xce]),
+        Some(&[0xbb, 0xb6]),
+        Some(&[0xd0, 0xc0]),
+        Some(&[0xec, 0xa3]),
+        None,
+        None,
+        Some(&[0xc5, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xa4]),
+        None,
+        Some(&[0xec, 0xa5]),
+        Some(&[0xc6, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xa6]),
+        None,
+        None,
+        Some(&[0xec, 0xa7]),
+        Some(&[0xd0, 0xaa]),
+        None,
+        Some(&[0xc7, 0xb8]),
+        None,
+        None,
+        Some(&[0xb8, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xb9]),
+        Some(&[0xd5, 0xfd]),
+        Some(&[0xb4, 0xcb]),
+        Some(&[0xb2, 0xbd]),
+        Some(&[0xce, 0xe4]),
+        Some(&[0xc6, 0xe7]),
+        None,
+        None,
+        Some(&[0xcd, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xf5]),
+        None,
+        Some(&[0xcb, 0xc0]),
+        Some(&[0xbc, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe2]),
+        Some(&[0xe9, 0xe3]),
+        Some(&[0xd1, 0xea]),
+        Some(&[0xe9, 0xe5]),
+        None,
+        Some(&[0xb4, 0xf9]),
+        Some(&[0xe9, 0xe4]),
+        None,
+        Some(&[0xd1, 0xb3]),
+        Some(&[0xca, 0xe2]),
+        Some(&[0xb2, 0xd0]),
+        None,
+        Some(&[0xe9, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe6]),
+        Some(&[0xe9, 0xe7]),
+        None,
+        None,
+        Some(&[0xd6, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe9]),
+        Some(&[0xe9, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xaf]),
+        Some(&[0xc5, 0xb9]),
+        Some(&[0xb6, 0xce]),
+        None,
+        Some(&[0xd2, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xee]),
+        None,
+        Some(&[0xbb, 0xd9]),
+        Some(&[0xec, 0xb1]),
+        None,
+        None,
+        Some(&[0xd2, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xe3]),
+        None,
+        Some(&[0xc4, 0xb8]),
+        None,
+        Some(&[0xc3, 0xbf]),
+        None,
+        None,
+        Some(&[0xb6, 0xbe]),
+        Some(&[0xd8, 0xb9]),
+        Some(&[0xb1, 0xc8]),
+        Some(&[0xb1, 0xcf]),
+        Some(&[0xb1, 0xd1]),
+        Some(&[0xc5, 0xfe]),
+        None,
+        Some(&[0xb1, 0xd0]),
+        None,
+        Some(&[0xc3, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xa4]),
+        Some(&[0xba, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xa5]),
+        None,
+        Some(&[0xeb, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xa9]),
+        Some(&[0xeb, 0xab]),
+        Some(&[0xeb, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xac]),
+        None,
+        Some(&[0xca, 0xcf]),
+        Some(&[0xd8, 0xb5]),
+        Some(&[0xc3, 0xf1]),
+        None,
+        Some(&[0xc3, 0xa5]),
+        Some(&[0xc6, 0xf8]),
+        Some(&[0xeb, 0xad]),
+        Some(&[0xc4, 0xca]),
+        None,
+        Some(&[0xeb, 0xae]),
+        Some(&[0xeb, 0xaf]),
+        Some(&[0xeb, 0xb0]),
+        Some(&[0xb7, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xfa]),
+        None,
+        Some(&[0xeb, 0xb1]),
+        Some(&[0xc7, 0xe2]),
+        None,
+        Some(&[0xeb, 0xb3]),
+        None,
+        Some(&[0xba, 0xa4]),
+        Some(&[0xd1, 0xf5]),
+        Some(&[0xb0, 0xb1]),
+        Some(&[0xeb, 0xb2]),
+        Some(&[0xeb, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xaa]),
+        Some(&[0xc2, 0xc8]),
+        Some(&[0xc7, 0xe8]),
+        None,
+        Some(&[0xeb, 0xb5]),
+        None,
+        Some(&[0xcb, 0xae]),
+        Some(&[0xe3, 0xdf]),
+        None,
+        None,
+        Some(&[0xd3, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xdb]),
+        None,
+        None,
+        Some(&[0xcd, 0xa1]),
+        Some(&[0xd6, 0xad]),
+        Some(&[0xc7, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe0]),
+        Some(&[0xbb, 0xe3]),
+        None,
+        Some(&[0xba, 0xba]),
+        Some(&[0xe3, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xe0]),
+        Some(&[0xc9, 0xc7]),
+        None,
+        Some(&[0xba, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xb4]),
+        Some(&[0xe3, 0xe1]),
+        Some(&[0xc8, 0xea]),
+        Some(&[0xb9, 0xaf]),
+        Some(&[0xbd, 0xad]),
+        Some(&[0xb3, 0xd8]),
+        Some(&[0xce, 0xdb]),
+        None,
+        None,
+        Some(&[0xcc, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xe8]),
+        Some(&[0xe3, 0xe9]),
+        Some(&[0xcd, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xad]),
+        None,
+        Some(&[0xbc, 0xb3]),
+        None,
+        Some(&[0xe3, 0xea]),
+        None,
+        Some(&[0xe3, 0xeb]),
+        None,
+        None,
+        Some(&[0xd0, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xfb]),
+        Some(&[0xb7, 0xda]),
+        None,
+        None,
+        Some(&[0xc7, 0xdf]),
+        Some(&[0xd2, 0xca]),
+        Some(&[0xce, 0xd6]),
+        None,
+        Some(&[0xe3, 0xe4]),
+        Some(&[0xe3, 0xec]),
+        None,
+        Some(&[0xc9, 0xf2]),
+        Some(&[0xb3, 0xc1]),
+        None,
+        None,
+        Some(&[0xe3, 0xe7]),
+        None,
+        None,
+        Some(&[0xc6, 0xe3]),
+        Some(&[0xe3, 0xe5]),
+        None,
+        None,
+        Some(&[0xed, 0xb3]),
+        Some(&[0xe3, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xb3]),
+        None,
+        Some(&[0xc5, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xb5]),
+        None,
+        Some(&[0xc3, 0xbb]),
+        None,
+        Some(&[0xe3, 0xe3]),
+        Some(&[0xc5, 0xbd]),
+        Some(&[0xc1, 0xa4]),
+        Some(&[0xc2, 0xd9]),
+        Some(&[0xb2, 0xd7]),
+        None,
+        Some(&[0xe3, 0xed]),
+        Some(&[0xbb, 0xa6]),
+        Some(&[0xc4, 0xad]),
+        None,
+        Some(&[0xe3, 0xf0]),
+        Some(&[0xbe, 0xda]),
+        None,
+        None,
+        Some(&[0xe3, 0xfb]),
+        Some(&[0xe3, 0xf5]),
+        Some(&[0xba, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xd0]),
+        Some(&[0xd3, 0xcd]),
+        None,
+        Some(&[0xd6, 0xce]),
+        Some(&[0xd5, 0xd3]),
+        Some(&[0xb9, 0xc1]),
+        Some(&[0xd5, 0xb4]),
+        Some(&[0xd1, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb9]),
+        Some(&[0xc7, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xaa]),
+        Some(&[0xb2, 0xb4]),
+        None,
+        Some(&[0xc3, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xee]),
+        None,
+        None,
+        Some(&[0xe3, 0xfc]),
+        Some(&[0xe3, 0xef]),
+        Some(&[0xb7, 0xa8]),
+        Some(&[0xe3, 0xf7]),
+        Some(&[0xe3, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xba]),
+        None,
+        None,
+        Some(&[0xc5, 0xa2]),
+        None,
+        Some(&[0xe3, 0xf6]),
+        Some(&[0xc5, 0xdd]),
+        Some(&[0xb2, 0xa8]),
+        Some(&[0xc6, 0xfc]),
+        None,
+        Some(&[0xc4, 0xe0]),
+        None,
+        None,
+        Some(&[0xd7, 0xa2]),
+        None,
+        Some(&[0xc0, 0xe1]),
+        Some(&[0xe3, 0xf9]),
+        None,
+        None,
+        Some(&[0xe3, 0xfa]),
+        Some(&[0xe3, 0xfd]),
+        Some(&[0xcc, 0xa9]),
+        Some(&[0xe3, 0xf3]),
+        None,
+        Some(&[0xd3, 0xbe]),
+        None,
+        Some(&[0xb1, 0xc3]),
+        Some(&[0xed, 0xb4]),
+        Some(&[0xe3, 0xf1]),
+        Some(&[0xe3, 0xf2]),
+        None,
+        Some(&[0xe3, 0xf8]),
+        Some(&[0xd0, 0xba]),
+        Some(&[0xc6, 0xc3]),
+        Some(&[0xd4, 0xf3]),
+        Some(&[0xe3, 0xfe]),
+        None,
+        None,
+        Some(&[0xbd, 0xe0]),
+        None,
+        None,
+        Some(&[0xe4, 0xa7]),
+        None,
+        None,
+        Some(&[0xe4, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf3]),
+        Some(&[0xe4, 0xa3]),
+        None,
+        Some(&[0xe4, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xb4]),
+        None,
+        Some(&[0xe4, 0xa8]),
+        Some(&[0xe4, 0xae]),
+        Some(&[0xc2, 0xe5]),
+        None,
+        None,
+        Some(&[0xb6, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xf2]),
+        None,
+        Some(&[0xe4, 0xa2]),
+        None,
+        None,
+        Some(&[0xba, 0xe9]),
+        Some(&[0xe4, 0xaa]),
+        None,
+        None,
+        Some(&[0xe4, 0xac]),
+        None,
+        None,
+        Some(&[0xb6, 0xfd]),
+        Some(&[0xd6, 0xde]),
+        Some(&[0xe4, 0xb2]),
+        None,
+        Some(&[0xe4, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa1]),
+        None,
+        Some(&[0xbb, 0xee]),
+        Some(&[0xcd, 0xdd]),
+        Some(&[0xc7, 0xa2]),
+        Some(&[0xc5, 0xc9]),
+        None,
+        None,
+        Some(&[0xc1, 0xf7]),
+        None,
+        Some(&[0xe4, 0xa4]),
+        None,
+        Some(&[0xc7, 0xb3]),
+        Some(&[0xbd, 0xac]),
+        Some(&[0xbd, 0xbd]),
+        Some(&[0xe4, 0xa5]),
+        None,
+        Some(&[0xd7, 0xc7]),
+        Some(&[0xb2, 0xe2]),
+        None,
+        Some(&[0xe4, 0xab]),
+        Some(&[0xbc, 0xc3]),
+        Some(&[0xe4, 0xaf]),
+        None,
+        Some(&[0xbb, 0xeb]),
+        Some(&[0xe4, 0xb0]),
+        Some(&[0xc5, 0xa8]),
+        Some(&[0xe4, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xe3]),
+        Some(&[0xbf, 0xa3]),
+        None,
+        Some(&[0xe4, 0xba]),
+        None,
+        Some(&[0xe4, 0xb7]),
+        None,
+        Some(&[0xe4, 0xbb]),
+        None,
+        None,
+        Some(&[0xe4, 0xbd]),
+        None,
+        None,
+        Some(&[0xc6, 0xd6]),
+        None,
+        None,
+        Some(&[0xba, 0xc6]),
+        Some(&[0xc0, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xa1]),
+        Some(&[0xe4, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xa1]),
+        None,
+        None,
+        Some(&[0xba, 0xa3]),
+        Some(&[0xbd, 0xfe]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xbf]),
+        None,
+        None,
+        Some(&[0xc4, 0xf9]),
+        None,
+        None,
+        Some(&[0xcf, 0xfb]),
+        Some(&[0xc9, 0xe6]),
+        None,
+        None,
+        Some(&[0xd3, 0xbf]),
+        None,
+        Some(&[0xcf, 0xd1]),
+        None,
+        None,
+        Some(&[0xe4, 0xb3]),
+        None,
+        Some(&[0xe4, 0xb8]),
+        Some(&[0xe4, 0xb9]),
+        Some(&[0xcc, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xce]),
+        None,
+        Some(&[0xc0, 0xd4]),
+        Some(&[0xe4, 0xb5]),
+        Some(&[0xc1, 0xb0]),
+        Some(&[0xe4, 0xb6]),
+        Some(&[0xce, 0xd0]),
+        None,
+        Some(&[0xbb, 0xc1]),
+        Some(&[0xb5, 0xd3]),
+        None,
+        Some(&[0xc8, 0xf3]),
+        Some(&[0xbd, 0xa7]),
+        Some(&[0xd5, 0xc7]),
+        Some(&[0xc9, 0xac]),
+        Some(&[0xb8, 0xa2]),
+        Some(&[0xe4, 0xca]),
+        None,
+        None,
+        Some(&[0xe4, 0xcc]),
+        Some(&[0xd1, 0xc4]),
+        None,
+        None,
+        Some(&[0xd2, 0xba]),
+        None,
+        None,
+        Some(&[0xba, 0xad]),
+        None,
+        None,
+        Some(&[0xba, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xc3]),
+        Some(&[0xb5, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xcd]),
+        Some(&[0xe4, 0xc0]),
+        Some(&[0xcf, 0xfd]),
+        Some(&[0xe4, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xdc]),
+        Some(&[0xcc, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xd7]),
+        None,
+        Some(&[0xcc, 0xd4]),
+        Some(&[0xe4, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xc7]),
+        Some(&[0xe4, 0xc1]),
+        None,
+        Some(&[0xe4, 0xc4]),
+        Some(&[0xb5, 0xad]),
+        None,
+        None,
+        Some(&[0xd3, 0xd9]),
+        None,
+        Some(&[0xe4, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xf9]),
+        Some(&[0xb4, 0xe3]),
+        None,
+        Some(&[0xbb, 0xb4]),
+        None,
+        None,
+        Some(&[0xc9, 0xee]),
+        None,
+        Some(&[0xb4, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xec]),
+        None,
+        Some(&[0xd1, 0xcd]),
+        None,
+        Some(&[0xcc, 0xed]),
+        Some(&[0xed, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xa8]),
+        None,
+        Some(&[0xe4, 0xcb]),
+        Some(&[0xd7, 0xd5]),
+        Some(&[0xe4, 0xc2]),
+        None,
+        Some(&[0xbd, 0xa5]),
+        Some(&[0xe4, 0xc5]),
+        None,
+        None,
+        Some(&[0xd3, 0xe6]),
+        None,
+        Some(&[0xe4, 0xc9]),
+        Some(&[0xc9, 0xf8]),
+        None,
+        None,
+        Some(&[0xe4, 0xbe]),
+        None,
+        None,
+        Some(&[0xd3, 0xe5]),
+        None,
+        None,
+        Some(&[0xc7, 0xfe]),
+        Some(&[0xb6, 0xc9]),
+        None,
+        Some(&[0xd4, 0xfc]),
+        Some(&[0xb2, 0xb3]),
+        Some(&[0xe4, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc2]),
+        None,
+        Some(&[0xe4, 0xcd]),
+        None,
+        Some(&[0xce, 0xbc]),
+        None,
+        Some(&[0xb8, 0xdb]),
+        None,
+        None,
+        Some(&[0xe4, 0xd6]),
+        None,
+        Some(&[0xbf, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xce]),
+        None,
+        Some(&[0xc3, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xc8]),
+        Some(&[0xe4, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xc4]),
+        Some(&[0xe4, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd4]),
+        Some(&[0xe4, 0xd5]),
+        None,
+        Some(&[0xba, 0xfe]),
+        None,
+        Some(&[0xcf, 0xe6]),
+        None,
+        None,
+        Some(&[0xd5, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd0]),
+        None,
+        None,
+        Some(&[0xe4, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xe5]),
+        Some(&[0xca, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xa3]),
+        None,
+        Some(&[0xbd, 0xa6]),
+        Some(&[0xe4, 0xd3]),
+        None,
+        None,
+        Some(&[0xb8, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xe7]),
+        Some(&[0xd4, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xef]),
+        None,
+        None,
+        Some(&[0xe4, 0xe9]),
+        None,
+        None,
+        Some(&[0xd2, 0xe7]),
+        None,
+        None,
+        Some(&[0xe4, 0xdf]),
+        None,
+        Some(&[0xe4, 0xe0]),
+        None,
+        None,
+        Some(&[0xcf, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xdd]),
+        None,
+        Some(&[0xe4, 0xda]),
+        Some(&[0xe4, 0xd1]),
+        None,
+        Some(&[0xe4, 0xe5]),
+        None,
+        Some(&[0xc8, 0xdc]),
+        Some(&[0xe4, 0xe3]),
+        None,
+        None,
+        Some(&[0xc4, 0xe7]),
+        Some(&[0xe4, 0xe2]),
+        None,
+        Some(&[0xe4, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xfc]),
+        Some(&[0xe4, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xe6]),
+        None,
+        Some(&[0xbb, 0xac]),
+        None,
+        Some(&[0xd7, 0xd2]),
+        Some(&[0xcc, 0xcf]),
+        Some(&[0xeb, 0xf8]),
+        None,
+        Some(&[0xe4, 0xe4]),
+        None,
+        None,
+        Some(&[0xb9, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xcd]),
+        Some(&[0xe4, 0xd9]),
+        Some(&[0xe4, 0xdc]),
+        Some(&[0xc2, 0xfa]),
+        Some(&[0xe4, 0xde]),
+        None,
+        Some(&[0xc2, 0xcb]),
+        Some(&[0xc0, 0xc4]),
+        Some(&[0xc2, 0xd0]),
+        None,
+        Some(&[0xb1, 0xf5]),
+        Some(&[0xcc, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xe1]),
+        None,
+        None,
+        Some(&[0xe4, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xec]),
+        Some(&[0xd1, 0xdd]),
+        Some(&[0xe4, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf6]),
+        Some(&[0xe4, 0xf4]),
+        Some(&[0xc2, 0xfe]),
+        None,
+        Some(&[0xe4, 0xdd]),
+        None,
+        Some(&[0xe4, 0xf0]),
+        None,
+        Some(&[0xca, 0xfe]),
+        None,
+        Some(&[0xd5, 0xc4]),
+        None,
+        None,
+        Some(&[0xe4, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xeb]),
+        Some(&[0xe4, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf2]),
+        None,
+        Some(&[0xce, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xb1]),
+        None,
+        Some(&[0xc2, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xb6]),
+        Some(&[0xb3, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xfb]),
+        None,
+        Some(&[0xe4, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xfa]),
+        None,
+        Some(&[0xe4, 0xfd]),
+        None,
+        Some(&[0xe4, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xba]),
+        Some(&[0xe4, 0xf7]),
+        None,
+        None,
+        Some(&[0xe4, 0xf9]),
+        Some(&[0xe4, 0xf8]),
+        Some(&[0xc5, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xc4]),
+        None,
+        None,
+        Some(&[0xe5, 0xa4]),
+        None,
+        None,
+        Some(&[0xe5, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xa4]),
+        None,
+        Some(&[0xe5, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xfe]),
+        Some(&[0xb1, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa8]),
+        None,
+        Some(&[0xe5, 0xa9]),
+        Some(&[0xe5, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa7]),
+        Some(&[0xe5, 0xaa]),
+    ];
+
+    const BASE_0X7010: [Option<&'static [u8]>; 24] = [
+        None,
+        Some(&[0xc6, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xab]),
+        Some(&[0xe5, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xac]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7048: [Option<&'static [u8]>; 448] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xe0]),
+        None,
+        None,
+        Some(&[0xe5, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xf0]),
+        Some(&[0xec, 0xe1]),
+        Some(&[0xc3, 0xf0]),
+        None,
+        Some(&[0xb5, 0xc6]),
+        Some(&[0xbb, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xe9]),
+        Some(&[0xd4, 0xee]),
+        None,
+        Some(&[0xbe, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xc6]),
+        None,
+        Some(&[0xd4, 0xd6]),
+        Some(&[0xb2, 0xd3]),
+        Some(&[0xec, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xaf]),
+        Some(&[0xb4, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xb4]),
+        None,
+        Some(&[0xc8, 0xb2]),
+        Some(&[0xbf, 0xbb]),
+        Some(&[0xec, 0xc0]),
+        None,
+        None,
+        Some(&[0xd6, 0xcb]),
+        None,
+        None,
+        Some(&[0xec, 0xbf]),
+        Some(&[0xec, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xc5]),
+        Some(&[0xbe, 0xe6]),
+        Some(&[0xcc, 0xbf]),
+        Some(&[0xc5, 0xda]),
+        Some(&[0xbe, 0xbc]),
+        None,
+        Some(&[0xec, 0xc6]),
+        None,
+        Some(&[0xb1, 0xfe]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xc4]),
+        Some(&[0xd5, 0xa8]),
+        Some(&[0xb5, 0xe3]),
+        None,
+        Some(&[0xec, 0xc2]),
+        Some(&[0xc1, 0xb6]),
+        Some(&[0xb3, 0xe3]),
+        None,
+        None,
+        Some(&[0xec, 0xc3]),
+        Some(&[0xcb, 0xb8]),
+        Some(&[0xc0, 0xc3]),
+        Some(&[0xcc, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xd2]),
+        None,
+        Some(&[0xec, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xe6]),
+        Some(&[0xc0, 0xd3]),
+        None,
+        Some(&[0xd6, 0xf2]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xbe]),
+        None,
+        Some(&[0xb7, 0xb3]),
+        Some(&[0xc9, 0xd5]),
+        Some(&[0xec, 0xc7]),
+        Some(&[0xbb, 0xe2]),
+        None,
+        Some(&[0xcc, 0xcc]),
+        Some(&[0xbd, 0xfd]),
+        Some(&[0xc8, 0xc8]),
+        None,
+        Some(&[0xcf, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xe9]),
+        None,
+        Some(&[0xc5, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xc9]),
+        Some(&[0xba, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xc9]),
+        None,
+        None,
+        Some(&[0xec, 0xca]),
+        None,
+        Some(&[0xbb, 0xc0]),
+        Some(&[0xec, 0xcb]),
+        None,
+        Some(&[0xec, 0xe2]),
+        Some(&[0xb1, 0xba]),
+        Some(&[0xb7, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xcc]),
+        Some(&[0xd1, 0xe6]),
+        Some(&[0xec, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd3]),
+        None,
+        Some(&[0xbb, 0xcd]),
+        None,
+        Some(&[0xbc, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xcf]),
+        None,
+        Some(&[0xc9, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xba]),
+        None,
+        Some(&[0xec, 0xe3]),
+        Some(&[0xd5, 0xd5]),
+        Some(&[0xec, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd2]),
+        Some(&[0xec, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd4]),
+        None,
+        Some(&[0xec, 0xd5]),
+        None,
+        None,
+        Some(&[0xc9, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd6]),
+        Some(&[0xce, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xec]),
+        Some(&[0xec, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xd7]),
+        None,
+        Some(&[0xec, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xdc]),
+        Some(&[0xd1, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xef]),
+        None,
+        Some(&[0xec, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xac]),
+        None,
+    ];
+
+    const BASE_0X7218: [Option<&'static [u8]>; 480] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xe0]),
+        None,
+        Some(&[0xd7, 0xa6]),
+        None,
+        Some(&[0xc5, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xbc]),
+        Some(&[0xb0, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xf4]),
+        Some(&[0xb8, 0xb8]),
+        Some(&[0xd2, 0xaf]),
+        Some(&[0xb0, 0xd6]),
+        Some(&[0xb5, 0xf9]),
+        None,
+        Some(&[0xd8, 0xb3]),
+        None,
+        Some(&[0xcb, 0xac]),
+        None,
+        Some(&[0xe3, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xac]),
+        Some(&[0xb0, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xc6]),
+        Some(&[0xeb, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xbb]),
+        None,
+        None,
+        Some(&[0xd1, 0xc0]),
+        None,
+        Some(&[0xc5, 0xa3]),
+        None,
+        Some(&[0xea, 0xf2]),
+        None,
+        Some(&[0xc4, 0xb2]),
+        None,
+        Some(&[0xc4, 0xb5]),
+        Some(&[0xc0, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xf3]),
+        Some(&[0xc4, 0xc1]),
+        None,
+        Some(&[0xce, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xf0]),
+        Some(&[0xea, 0xf4]),
+        None,
+        None,
+        Some(&[0xc9, 0xfc]),
+        None,
+        None,
+        Some(&[0xc7, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xd8]),
+        Some(&[0xce, 0xfe]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xf5]),
+        Some(&[0xea, 0xf6]),
+        Some(&[0xcf, 0xac]),
+        Some(&[0xc0, 0xe7]),
+        None,
+        None,
+        Some(&[0xea, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xbf]),
+        Some(&[0xea, 0xf8]),
+        None,
+        Some(&[0xea, 0xf9]),
+        None,
+        Some(&[0xea, 0xfa]),
+        None,
+        None,
+        Some(&[0xea, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xae]),
+        Some(&[0xe1, 0xeb]),
+        None,
+        Some(&[0xb7, 0xb8]),
+        Some(&[0xe1, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xed]),
+        None,
+        Some(&[0xd7, 0xb4]),
+        Some(&[0xe1, 0xee]),
+        Some(&[0xe1, 0xef]),
+        Some(&[0xd3, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf1]),
+        Some(&[0xbf, 0xf1]),
+        Some(&[0xe1, 0xf0]),
+        Some(&[0xb5, 0xd2]),
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf3]),
+        Some(&[0xe1, 0xf2]),
+        None,
+        Some(&[0xba, 0xfc]),
+        None,
+        Some(&[0xe1, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xb7]),
+        None,
+        Some(&[0xbe, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xfc]),
+        None,
+        Some(&[0xba, 0xdd]),
+        Some(&[0xbd, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf5]),
+        Some(&[0xe1, 0xf7]),
+        None,
+        None,
+        Some(&[0xb6, 0xc0]),
+        Some(&[0xcf, 0xc1]),
+        Some(&[0xca, 0xa8]),
+        Some(&[0xe1, 0xf6]),
+        Some(&[0xd5, 0xf8]),
+        Some(&[0xd3, 0xfc]),
+        Some(&[0xe1, 0xf8]),
+        Some(&[0xe1, 0xfc]),
+        Some(&[0xe1, 0xf9]),
+        None,
+        None,
+        Some(&[0xe1, 0xfa]),
+        Some(&[0xc0, 0xea]),
+        None,
+        Some(&[0xe1, 0xfe]),
+        Some(&[0xe2, 0xa1]),
+        Some(&[0xc0, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xfb]),
+        None,
+        Some(&[0xe1, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xa3]),
+        None,
+        Some(&[0xe2, 0xa8]),
+        Some(&[0xb2, 0xfe]),
+        Some(&[0xe2, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xcd]),
+        Some(&[0xb2, 0xc2]),
+        Some(&[0xe2, 0xa7]),
+        Some(&[0xe2, 0xa6]),
+        None,
+        None,
+        Some(&[0xe2, 0xa4]),
+        Some(&[0xe2, 0xa9]),
+        None,
+        None,
+        Some(&[0xe2, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xc9]),
+        Some(&[0xd6, 0xed]),
+        Some(&[0xc3, 0xa8]),
+        Some(&[0xe2, 0xac]),
+        None,
+        Some(&[0xcf, 0xd7]),
+        None,
+        None,
+        Some(&[0xe2, 0xae]),
+        None,
+        None,
+        Some(&[0xba, 0xef]),
+        None,
+        None,
+        Some(&[0xe9, 0xe0]),
+        Some(&[0xe2, 0xad]),
+        Some(&[0xe2, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xab]),
+        Some(&[0xd4, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb0]),
+        None,
+        None,
+        Some(&[0xe2, 0xaf]),
+        None,
+        Some(&[0xe9, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb3]),
+        Some(&[0xcc, 0xa1]),
+        None,
+        Some(&[0xe2, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xfe]),
+        None,
+        None,
+        Some(&[0xc2, 0xca]),
+        None,
+        Some(&[0xd3, 0xf1]),
+        None,
+        Some(&[0xcd, 0xf5]),
+        None,
+        None,
+        Some(&[0xe7, 0xe0]),
+        None,
+        None,
+        Some(&[0xe7, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xe4]),
+        None,
+        None,
+        Some(&[0xe7, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xe6]),
+        None,
+        Some(&[0xc3, 0xb5]),
+        None,
+        None,
+        Some(&[0xe7, 0xe2]),
+        Some(&[0xbb, 0xb7]),
+        Some(&[0xcf, 0xd6]),
+        None,
+        Some(&[0xc1, 0xe1]),
+        Some(&[0xe7, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xe8]),
+        None,
+        None,
+        Some(&[0xe7, 0xf4]),
+        Some(&[0xb2, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xea]),
+        None,
+        Some(&[0xe7, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xec]),
+        Some(&[0xe7, 0xeb]),
+        Some(&[0xc9, 0xba]),
+        None,
+        None,
+        Some(&[0xd5, 0xe4]),
+        None,
+        Some(&[0xe7, 0xe5]),
+        Some(&[0xb7, 0xa9]),
+        Some(&[0xe7, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf3]),
+        None,
+        Some(&[0xd6, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xed]),
+        None,
+        Some(&[0xe7, 0xf2]),
+        None,
+        Some(&[0xe7, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7400: [Option<&'static [u8]>; 72] = [
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xf2]),
+        None,
+        Some(&[0xc0, 0xc5]),
+        Some(&[0xc0, 0xed]),
+        None,
+        None,
+        Some(&[0xc1, 0xf0]),
+        Some(&[0xe7, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf6]),
+        Some(&[0xcb, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xa2]),
+        Some(&[0xe8, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xc1]),
+        None,
+        None,
+        Some(&[0xe7, 0xfa]),
+        Some(&[0xe7, 0xf9]),
+        None,
+        Some(&[0xe7, 0xfb]),
+        None,
+        Some(&[0xe7, 0xf7]),
+        None,
+        Some(&[0xe7, 0xfe]),
+        None,
+        Some(&[0xe7, 0xfd]),
+        None,
+        Some(&[0xe7, 0xfc]),
+        None,
+        None,
+        Some(&[0xc1, 0xd5]),
+        Some(&[0xc7, 0xd9]),
+        Some(&[0xc5, 0xfd]),
+        Some(&[0xc5, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7450: [Option<&'static [u8]>; 96] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xa6]),
+        None,
+        Some(&[0xe8, 0xa5]),
+        None,
+        Some(&[0xe8, 0xa7]),
+        Some(&[0xba, 0xf7]),
+        Some(&[0xe7, 0xf8]),
+        Some(&[0xe8, 0xa4]),
+        None,
+        Some(&[0xc8, 0xf0]),
+        Some(&[0xc9, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xa9]),
+        None,
+        None,
+        Some(&[0xb9, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xfe]),
+        Some(&[0xe8, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xaa]),
+        None,
+        Some(&[0xe8, 0xad]),
+        Some(&[0xe8, 0xae]),
+        None,
+        Some(&[0xc1, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xb0]),
+        None,
+        None,
+        Some(&[0xe8, 0xac]),
+        None,
+        Some(&[0xe8, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xab]),
+        None,
+        Some(&[0xe8, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xb5]),
+        Some(&[0xe8, 0xb2]),
+        Some(&[0xe8, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X74D0: [Option<&'static [u8]>; 1416] = [
+        None,
+        None,
+        Some(&[0xe8, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xcf]),
+        None,
+        Some(&[0xf0, 0xac]),
+        None,
+        Some(&[0xf0, 0xad]),
+        None,
+        Some(&[0xc6, 0xb0]),
+        Some(&[0xb0, 0xea]),
+        Some(&[0xc8, 0xbf]),
+        None,
+        Some(&[0xcd, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xcd]),
+        Some(&[0xea, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xb2]),
+        None,
+        Some(&[0xc6, 0xbf]),
+        Some(&[0xb4, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xf9]),
+        None,
+        Some(&[0xea, 0xb4]),
+        None,
+        Some(&[0xea, 0xb5]),
+        None,
+        Some(&[0xea, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xca]),
+        Some(&[0xdf, 0xb0]),
+        Some(&[0xc9, 0xf5]),
+        None,
+        Some(&[0xcc, 0xf0]),
+        None,
+        None,
+        Some(&[0xc9, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xfb]),
+        None,
+        None,
+        Some(&[0xd3, 0xc3]),
+        Some(&[0xcb, 0xa6]),
+        None,
+        Some(&[0xb8, 0xa6]),
+        Some(&[0xf0, 0xae]),
+        Some(&[0xb1, 0xc2]),
+        None,
+        Some(&[0xe5, 0xb8]),
+        Some(&[0xcc, 0xef]),
+        Some(&[0xd3, 0xc9]),
+        Some(&[0xbc, 0xd7]),
+        Some(&[0xc9, 0xea]),
+        None,
+        Some(&[0xb5, 0xe7]),
+        None,
+        Some(&[0xc4, 0xd0]),
+        Some(&[0xb5, 0xe9]),
+        None,
+        Some(&[0xee, 0xae]),
+        Some(&[0xbb, 0xad]),
+        None,
+        None,
+        Some(&[0xe7, 0xde]),
+        None,
+        Some(&[0xee, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xa9]),
+        None,
+        None,
+        Some(&[0xee, 0xb2]),
+        None,
+        None,
+        Some(&[0xee, 0xb1]),
+        Some(&[0xbd, 0xe7]),
+        None,
+        Some(&[0xee, 0xb0]),
+        Some(&[0xce, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xf4]),
+        Some(&[0xdb, 0xce]),
+        Some(&[0xee, 0xb3]),
+        Some(&[0xd0, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xd4]),
+        Some(&[0xc6, 0xe8]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xb4]),
+        None,
+        Some(&[0xb3, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xfb]),
+        Some(&[0xee, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xdc]),
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xb6]),
+        None,
+        None,
+        Some(&[0xbd, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xe8]),
+        None,
+        Some(&[0xd2, 0xc9]),
+        Some(&[0xf0, 0xda]),
+        None,
+        Some(&[0xf0, 0xdb]),
+        None,
+        Some(&[0xf0, 0xdc]),
+        Some(&[0xc1, 0xc6]),
+        None,
+        Some(&[0xb8, 0xed]),
+        Some(&[0xbe, 0xce]),
+        None,
+        None,
+        Some(&[0xf0, 0xde]),
+        None,
+        Some(&[0xc5, 0xb1]),
+        Some(&[0xf0, 0xdd]),
+        Some(&[0xd1, 0xf1]),
+        None,
+        Some(&[0xf0, 0xe0]),
+        Some(&[0xb0, 0xcc]),
+        Some(&[0xbd, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xdf]),
+        Some(&[0xf0, 0xdf]),
+        None,
+        Some(&[0xb4, 0xaf]),
+        Some(&[0xb7, 0xe8]),
+        Some(&[0xf0, 0xe6]),
+        Some(&[0xf0, 0xe5]),
+        Some(&[0xc6, 0xa3]),
+        Some(&[0xf0, 0xe1]),
+        Some(&[0xf0, 0xe2]),
+        Some(&[0xb4, 0xc3]),
+        None,
+        None,
+        Some(&[0xf0, 0xe3]),
+        Some(&[0xd5, 0xee]),
+        None,
+        None,
+        Some(&[0xcc, 0xdb]),
+        Some(&[0xbe, 0xd2]),
+        Some(&[0xbc, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xe8]),
+        Some(&[0xf0, 0xe7]),
+        Some(&[0xf0, 0xe4]),
+        Some(&[0xb2, 0xa1]),
+        None,
+        Some(&[0xd6, 0xa2]),
+        Some(&[0xd3, 0xb8]),
+        Some(&[0xbe, 0xb7]),
+        Some(&[0xc8, 0xac]),
+        None,
+        None,
+        Some(&[0xf0, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf7]),
+        None,
+        Some(&[0xd6, 0xcc]),
+        Some(&[0xba, 0xdb]),
+        Some(&[0xf0, 0xe9]),
+        None,
+        Some(&[0xb6, 0xbb]),
+        None,
+        None,
+        Some(&[0xcd, 0xb4]),
+        None,
+        None,
+        Some(&[0xc6, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xa1]),
+        Some(&[0xf0, 0xeb]),
+        Some(&[0xf0, 0xee]),
+        None,
+        Some(&[0xf0, 0xed]),
+        Some(&[0xf0, 0xf0]),
+        Some(&[0xf0, 0xec]),
+        None,
+        Some(&[0xbb, 0xbe]),
+        Some(&[0xf0, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xb5]),
+        Some(&[0xf0, 0xf2]),
+        None,
+        None,
+        Some(&[0xb3, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xd4]),
+        None,
+        None,
+        Some(&[0xf0, 0xf3]),
+        None,
+        None,
+        Some(&[0xf0, 0xf4]),
+        Some(&[0xf0, 0xf6]),
+        Some(&[0xb4, 0xe1]),
+        None,
+        Some(&[0xf0, 0xf1]),
+        None,
+        Some(&[0xf0, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xfa]),
+        None,
+        Some(&[0xf0, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xfd]),
+        None,
+        Some(&[0xf0, 0xf9]),
+        Some(&[0xf0, 0xfc]),
+        Some(&[0xf0, 0xfe]),
+        None,
+        Some(&[0xf1, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc1]),
+        Some(&[0xf1, 0xa4]),
+        None,
+        Some(&[0xf1, 0xa3]),
+        None,
+        Some(&[0xc1, 0xf6]),
+        Some(&[0xf0, 0xfb]),
+        Some(&[0xca, 0xdd]),
+        None,
+        None,
+        Some(&[0xb4, 0xf1]),
+        Some(&[0xb1, 0xf1]),
+        Some(&[0xcc, 0xb1]),
+        None,
+        Some(&[0xf1, 0xa6]),
+        None,
+        None,
+        Some(&[0xf1, 0xa7]),
+        None,
+        None,
+        Some(&[0xf1, 0xac]),
+        Some(&[0xd5, 0xce]),
+        Some(&[0xf1, 0xa9]),
+        None,
+        None,
+        Some(&[0xc8, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xa2]),
+        None,
+        Some(&[0xf1, 0xab]),
+        Some(&[0xf1, 0xa8]),
+        Some(&[0xf1, 0xa5]),
+        None,
+        None,
+        Some(&[0xf1, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xa9]),
+        Some(&[0xf1, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xaf]),
+        None,
+        Some(&[0xf1, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xb0]),
+        None,
+        Some(&[0xf1, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xef]),
+        None,
+        None,
+        Some(&[0xb5, 0xc7]),
+        None,
+        Some(&[0xb0, 0xd7]),
+        Some(&[0xb0, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xed]),
+        None,
+        Some(&[0xb5, 0xc4]),
+        None,
+        Some(&[0xbd, 0xd4]),
+        Some(&[0xbb, 0xca]),
+        Some(&[0xf0, 0xa7]),
+        None,
+        None,
+        Some(&[0xb8, 0xde]),
+        None,
+        None,
+        Some(&[0xf0, 0xa8]),
+        None,
+        None,
+        Some(&[0xb0, 0xa8]),
+        None,
+        Some(&[0xf0, 0xa9]),
+        None,
+        None,
+        Some(&[0xcd, 0xee]),
+        None,
+        None,
+        Some(&[0xf0, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa4]),
+        None,
+        None,
+        Some(&[0xd6, 0xe5]),
+        Some(&[0xf1, 0xe4]),
+        None,
+        Some(&[0xf1, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xf3]),
+        None,
+        None,
+        Some(&[0xd3, 0xdb]),
+        None,
+        None,
+        Some(&[0xd6, 0xd1]),
+        Some(&[0xc5, 0xe8]),
+        None,
+        Some(&[0xd3, 0xaf]),
+        None,
+        Some(&[0xd2, 0xe6]),
+        None,
+        None,
+        Some(&[0xee, 0xc1]),
+        Some(&[0xb0, 0xbb]),
+        Some(&[0xd5, 0xb5]),
+        Some(&[0xd1, 0xce]),
+        Some(&[0xbc, 0xe0]),
+        Some(&[0xba, 0xd0]),
+        None,
+        Some(&[0xbf, 0xf8]),
+        None,
+        Some(&[0xb8, 0xc7]),
+        Some(&[0xb5, 0xc1]),
+        Some(&[0xc5, 0xcc]),
+        None,
+        None,
+        Some(&[0xca, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xbf]),
+        Some(&[0xb6, 0xa2]),
+        None,
+        Some(&[0xed, 0xec]),
+        Some(&[0xc3, 0xa4]),
+        None,
+        Some(&[0xd6, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xe0]),
+        Some(&[0xed, 0xef]),
+        None,
+        None,
+        Some(&[0xc5, 0xce]),
+        None,
+        Some(&[0xb6, 0xdc]),
+        None,
+        None,
+        Some(&[0xca, 0xa1]),
+        None,
+        None,
+        Some(&[0xed, 0xed]),
+        None,
+        None,
+        Some(&[0xed, 0xf0]),
+        Some(&[0xed, 0xf1]),
+        Some(&[0xc3, 0xbc]),
+        None,
+        Some(&[0xbf, 0xb4]),
+        None,
+        Some(&[0xed, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xf4]),
+        Some(&[0xed, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xe6]),
+        Some(&[0xc3, 0xdf]),
+        None,
+        Some(&[0xed, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xf6]),
+        None,
+        Some(&[0xd5, 0xa3]),
+        Some(&[0xd1, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xf5]),
+        None,
+        Some(&[0xc3, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xf7]),
+        Some(&[0xbf, 0xf4]),
+        Some(&[0xbe, 0xec]),
+        Some(&[0xed, 0xf8]),
+        None,
+        Some(&[0xcc, 0xf7]),
+        None,
+        Some(&[0xd1, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xc5]),
+        Some(&[0xd5, 0xf6]),
+        None,
+        Some(&[0xed, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xf9]),
+        Some(&[0xed, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xfd]),
+        Some(&[0xbe, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xaf]),
+        Some(&[0xee, 0xa1]),
+        Some(&[0xb6, 0xbd]),
+        None,
+        Some(&[0xee, 0xa2]),
+        Some(&[0xc4, 0xc0]),
+        None,
+        Some(&[0xed, 0xfe]),
+        None,
+        None,
+        Some(&[0xbd, 0xde]),
+        Some(&[0xb2, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xa5]),
+        Some(&[0xd8, 0xba]),
+        Some(&[0xee, 0xa3]),
+        Some(&[0xee, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xe9]),
+        Some(&[0xb3, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xa7]),
+        Some(&[0xee, 0xa4]),
+        Some(&[0xcf, 0xb9]),
+        None,
+        None,
+        Some(&[0xee, 0xa8]),
+        Some(&[0xc2, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xa9]),
+        Some(&[0xee, 0xaa]),
+        None,
+        Some(&[0xde, 0xab]),
+        None,
+        None,
+        Some(&[0xc6, 0xb3]),
+        None,
+        Some(&[0xc7, 0xc6]),
+        None,
+        Some(&[0xd6, 0xf5]),
+        Some(&[0xb5, 0xc9]),
+        None,
+        Some(&[0xcb, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xab]),
+        None,
+        None,
+        Some(&[0xcd, 0xab]),
+        None,
+        Some(&[0xee, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xb0]),
+        None,
+        Some(&[0xee, 0xad]),
+        None,
+        Some(&[0xf6, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xac]),
+        Some(&[0xf1, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xb8]),
+        Some(&[0xd2, 0xd3]),
+        None,
+        Some(&[0xd6, 0xaa]),
+        None,
+        Some(&[0xef, 0xf2]),
+        None,
+        Some(&[0xbe, 0xd8]),
+        None,
+        Some(&[0xbd, 0xc3]),
+        Some(&[0xef, 0xf3]),
+        Some(&[0xb6, 0xcc]),
+        Some(&[0xb0, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xaf]),
+        None,
+        None,
+        Some(&[0xed, 0xb6]),
+        None,
+        Some(&[0xed, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xf9]),
+        Some(&[0xb7, 0xaf]),
+        Some(&[0xbf, 0xf3]),
+        Some(&[0xed, 0xb8]),
+        Some(&[0xc2, 0xeb]),
+        Some(&[0xc9, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xb9]),
+        None,
+        None,
+        Some(&[0xc6, 0xf6]),
+        Some(&[0xbf, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xbc]),
+        Some(&[0xc5, 0xf8]),
+        None,
+        Some(&[0xd1, 0xd0]),
+        None,
+        Some(&[0xd7, 0xa9]),
+        Some(&[0xed, 0xba]),
+        Some(&[0xed, 0xbb]),
+        None,
+        Some(&[0xd1, 0xe2]),
+        None,
+        Some(&[0xed, 0xbf]),
+        Some(&[0xed, 0xc0]),
+        None,
+        Some(&[0xed, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xc8]),
+        None,
+        Some(&[0xed, 0xc6]),
+        Some(&[0xed, 0xce]),
+        Some(&[0xd5, 0xe8]),
+        None,
+        Some(&[0xed, 0xc9]),
+        None,
+        None,
+        Some(&[0xed, 0xc7]),
+        Some(&[0xed, 0xbe]),
+        None,
+        None,
+        Some(&[0xc5, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xc6]),
+        None,
+        None,
+        Some(&[0xc9, 0xe9]),
+        Some(&[0xd4, 0xd2]),
+        Some(&[0xed, 0xc1]),
+        Some(&[0xed, 0xc2]),
+        Some(&[0xed, 0xc3]),
+        Some(&[0xed, 0xc5]),
+        None,
+        Some(&[0xc0, 0xf9]),
+        None,
+        Some(&[0xb4, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xe8]),
+        None,
+        Some(&[0xed, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xd1]),
+        None,
+        Some(&[0xed, 0xca]),
+        None,
+        Some(&[0xed, 0xcf]),
+        None,
+        Some(&[0xce, 0xf8]),
+        None,
+        None,
+        Some(&[0xcb, 0xb6]),
+        Some(&[0xed, 0xcc]),
+        Some(&[0xed, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xd2]),
+        Some(&[0xc1, 0xf2]),
+        Some(&[0xd3, 0xb2]),
+        Some(&[0xed, 0xcb]),
+        Some(&[0xc8, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xd6]),
+        None,
+        Some(&[0xb5, 0xef]),
+        None,
+        None,
+        Some(&[0xc2, 0xb5]),
+        Some(&[0xb0, 0xad]),
+        Some(&[0xcb, 0xe9]),
+        None,
+        None,
+        Some(&[0xb1, 0xae]),
+        None,
+        Some(&[0xed, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xeb]),
+        Some(&[0xb5, 0xe2]),
+        None,
+        Some(&[0xed, 0xd5]),
+        Some(&[0xed, 0xd3]),
+        Some(&[0xed, 0xd7]),
+        None,
+        None,
+        Some(&[0xb5, 0xfa]),
+        None,
+        Some(&[0xed, 0xd8]),
+        None,
+        Some(&[0xed, 0xd9]),
+        None,
+        Some(&[0xed, 0xdc]),
+        None,
+        Some(&[0xb1, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xf6]),
+        Some(&[0xbc, 0xee]),
+        Some(&[0xed, 0xda]),
+        Some(&[0xcc, 0xbc]),
+        Some(&[0xb2, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xeb]),
+        None,
+        None,
+        Some(&[0xb4, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xdf]),
+        Some(&[0xc0, 0xda]),
+        Some(&[0xb4, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xdd]),
+        Some(&[0xbf, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe1]),
+        None,
+        Some(&[0xed, 0xe3]),
+        None,
+        None,
+        Some(&[0xc1, 0xd7]),
+        None,
+        None,
+        Some(&[0xbb, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xbe]),
+        Some(&[0xec, 0xea]),
+        Some(&[0xc0, 0xf1]),
+        None,
+        Some(&[0xc9, 0xe7]),
+        None,
+        Some(&[0xec, 0xeb]),
+        Some(&[0xc6, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xec]),
+        None,
+        Some(&[0xc6, 0xed]),
+        Some(&[0xec, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xf0]),
+        None,
+        None,
+        Some(&[0xd7, 0xe6]),
+        Some(&[0xec, 0xf3]),
+        None,
+        None,
+        Some(&[0xec, 0xf1]),
+        Some(&[0xec, 0xee]),
+        Some(&[0xec, 0xef]),
+        Some(&[0xd7, 0xa3]),
+        Some(&[0xc9, 0xf1]),
+        Some(&[0xcb, 0xee]),
+        Some(&[0xec, 0xf4]),
+        None,
+        Some(&[0xec, 0xf2]),
+        None,
+        None,
+        Some(&[0xcf, 0xe9]),
+        None,
+        Some(&[0xec, 0xf6]),
+        Some(&[0xc6, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xc0]),
+        None,
+        Some(&[0xec, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xbb]),
+        Some(&[0xbb, 0xf6]),
+        None,
+        Some(&[0xec, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xf7]),
+        Some(&[0xbd, 0xfb]),
+        None,
+        None,
+        Some(&[0xc2, 0xbb]),
+        Some(&[0xec, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xed]),
+        Some(&[0xd8, 0xae]),
+        Some(&[0xc0, 0xeb]),
+        None,
+        Some(&[0xc7, 0xdd]),
+        Some(&[0xba, 0xcc]),
+        None,
+        Some(&[0xd0, 0xe3]),
+        Some(&[0xcb, 0xbd]),
+        None,
+        Some(&[0xcd, 0xba]),
+        None,
+        None,
+        Some(&[0xb8, 0xd1]),
+        None,
+        None,
+        Some(&[0xb1, 0xfc]),
+        None,
+        Some(&[0xc7, 0xef]),
+        None,
+        Some(&[0xd6, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xc6]),
+        Some(&[0xc3, 0xeb]),
+        None,
+        None,
+        Some(&[0xef, 0xf5]),
+        None,
+        None,
+        Some(&[0xc3, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xf7]),
+        Some(&[0xb3, 0xd3]),
+        None,
+        Some(&[0xc7, 0xd8]),
+        Some(&[0xd1, 0xed]),
+        None,
+        Some(&[0xd6, 0xc8]),
+        None,
+        Some(&[0xef, 0xf8]),
+        None,
+        Some(&[0xef, 0xf6]),
+        None,
+        Some(&[0xbb, 0xfd]),
+        Some(&[0xb3, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xd5]),
+        None,
+        None,
+        Some(&[0xd2, 0xc6]),
+        None,
+        Some(&[0xbb, 0xe0]),
+        None,
+        None,
+        Some(&[0xcf, 0xa1]),
+        None,
+        Some(&[0xef, 0xfc]),
+        Some(&[0xef, 0xfb]),
+        None,
+        None,
+        Some(&[0xef, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xcc]),
+        None,
+        Some(&[0xc9, 0xd4]),
+        Some(&[0xcb, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xfe]),
+        None,
+        None,
+        Some(&[0xb0, 0xde]),
+        None,
+        None,
+        Some(&[0xd6, 0xc9]),
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xfd]),
+        None,
+        Some(&[0xb3, 0xed]),
+        None,
+        None,
+        Some(&[0xf6, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xa2]),
+        None,
+        Some(&[0xf0, 0xa1]),
+        None,
+        Some(&[0xb5, 0xbe]),
+        Some(&[0xbc, 0xda]),
+        Some(&[0xbb, 0xfc]),
+        None,
+        Some(&[0xb8, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xeb]),
+    ];
+
+    const BASE_0X7A70: [Option<&'static [u8]>; 480] = [
+        Some(&[0xf0, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xa8]),
+        None,
+        Some(&[0xbe, 0xbf]),
+        Some(&[0xc7, 0xee]),
+        Some(&[0xf1, 0xb6]),
+        Some(&[0xf1, 0xb7]),
+        Some(&[0xbf, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xa9]),
+        Some(&[0xf1, 0xb8]),
+        Some(&[0xcd, 0xbb]),
+        None,
+        Some(&[0xc7, 0xd4]),
+        Some(&[0xd5, 0xad]),
+        None,
+        Some(&[0xf1, 0xb9]),
+        None,
+        Some(&[0xf1, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xa4]),
+        Some(&[0xd6, 0xcf]),
+        None,
+        None,
+        Some(&[0xf1, 0xbb]),
+        Some(&[0xbd, 0xd1]),
+        Some(&[0xb4, 0xb0]),
+        Some(&[0xbe, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xdc]),
+        Some(&[0xce, 0xd1]),
+        None,
+        Some(&[0xbf, 0xdf]),
+        Some(&[0xf1, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xfa]),
+        Some(&[0xf1, 0xbc]),
+        None,
+        Some(&[0xf1, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xbe]),
+        Some(&[0xf1, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xfa]),
+        None,
+        None,
+        Some(&[0xd5, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xba]),
+        Some(&[0xbe, 0xb9]),
+        Some(&[0xd5, 0xc2]),
+        None,
+        None,
+        Some(&[0xbf, 0xa2]),
+        None,
+        Some(&[0xcd, 0xaf]),
+        Some(&[0xf1, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xdf]),
+        None,
+        Some(&[0xb6, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xf1]),
+        Some(&[0xf3, 0xc3]),
+        None,
+        None,
+        Some(&[0xf3, 0xc4]),
+        None,
+        Some(&[0xb8, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xc6]),
+        Some(&[0xf3, 0xc7]),
+        None,
+        Some(&[0xb0, 0xca]),
+        None,
+        Some(&[0xf3, 0xc5]),
+        None,
+        Some(&[0xf3, 0xc9]),
+        Some(&[0xcb, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xcb]),
+        None,
+        Some(&[0xd0, 0xa6]),
+        None,
+        None,
+        Some(&[0xb1, 0xca]),
+        Some(&[0xf3, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xcf]),
+        None,
+        Some(&[0xb5, 0xd1]),
+        None,
+        None,
+        Some(&[0xf3, 0xd7]),
+        None,
+        Some(&[0xf3, 0xd2]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xd4]),
+        Some(&[0xf3, 0xd3]),
+        Some(&[0xb7, 0xfb]),
+        None,
+        Some(&[0xb1, 0xbf]),
+        None,
+        Some(&[0xf3, 0xce]),
+        Some(&[0xf3, 0xca]),
+        Some(&[0xb5, 0xda]),
+        None,
+        Some(&[0xf3, 0xd0]),
+        None,
+        None,
+        Some(&[0xf3, 0xd1]),
+        None,
+        Some(&[0xf3, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xcd]),
+        None,
+        Some(&[0xbc, 0xe3]),
+        None,
+        Some(&[0xc1, 0xfd]),
+        None,
+        Some(&[0xf3, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xda]),
+        None,
+        Some(&[0xf3, 0xcc]),
+        None,
+        Some(&[0xb5, 0xc8]),
+        None,
+        Some(&[0xbd, 0xee]),
+        Some(&[0xf3, 0xdc]),
+        None,
+        None,
+        Some(&[0xb7, 0xa4]),
+        Some(&[0xbf, 0xf0]),
+        Some(&[0xd6, 0xfe]),
+        Some(&[0xcd, 0xb2]),
+        None,
+        Some(&[0xb4, 0xf0]),
+        None,
+        Some(&[0xb2, 0xdf]),
+        None,
+        Some(&[0xf3, 0xd8]),
+        None,
+        Some(&[0xf3, 0xd9]),
+        Some(&[0xc9, 0xb8]),
+        None,
+        Some(&[0xf3, 0xdd]),
+        None,
+        None,
+        Some(&[0xf3, 0xde]),
+        None,
+        Some(&[0xf3, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xdf]),
+        None,
+        None,
+        Some(&[0xf3, 0xe3]),
+        Some(&[0xf3, 0xe2]),
+        None,
+        None,
+        Some(&[0xf3, 0xdb]),
+        None,
+        Some(&[0xbf, 0xea]),
+        None,
+        Some(&[0xb3, 0xef]),
+        None,
+        Some(&[0xf3, 0xe0]),
+        None,
+        None,
+        Some(&[0xc7, 0xa9]),
+        None,
+        Some(&[0xbc, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xbf]),
+        None,
+        None,
+        Some(&[0xf3, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xad]),
+        Some(&[0xbb, 0xfe]),
+        None,
+        Some(&[0xcb, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xed]),
+        Some(&[0xf3, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xdc]),
+        Some(&[0xf3, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xe5]),
+        Some(&[0xf3, 0xe6]),
+        Some(&[0xf3, 0xea]),
+        Some(&[0xc2, 0xe1]),
+        Some(&[0xf3, 0xec]),
+        Some(&[0xf3, 0xef]),
+        Some(&[0xf3, 0xe8]),
+        Some(&[0xbc, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xe4]),
+        None,
+        None,
+        Some(&[0xf3, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xad]),
+        Some(&[0xc6, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xf1]),
+        None,
+        Some(&[0xc2, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xdd]),
+        Some(&[0xf3, 0xf5]),
+        None,
+        None,
+        Some(&[0xf3, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xf6]),
+        Some(&[0xf3, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xba]),
+        None,
+        None,
+        Some(&[0xc0, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xfb]),
+        None,
+        Some(&[0xf3, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xfe]),
+        Some(&[0xf3, 0xf9]),
+        None,
+        None,
+        Some(&[0xf3, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xa3]),
+        Some(&[0xbb, 0xc9]),
+        None,
+        None,
+        Some(&[0xf4, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xbe]),
+        Some(&[0xf4, 0xa6]),
+        Some(&[0xf4, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xae]),
+        None,
+        None,
+    ];
+
+    const BASE_0X7C70: [Option<&'static [u8]>; 160] = [
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xd7]),
+        Some(&[0xd9, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xe0]),
+        Some(&[0xf4, 0xcc]),
+        Some(&[0xd7, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xce]),
+        Some(&[0xc1, 0xa3]),
+        None,
+        None,
+        Some(&[0xc6, 0xc9]),
+        None,
+        Some(&[0xb4, 0xd6]),
+        Some(&[0xd5, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xd0]),
+        Some(&[0xf4, 0xcf]),
+        Some(&[0xf4, 0xd1]),
+        Some(&[0xcb, 0xda]),
+        None,
+        None,
+        Some(&[0xf4, 0xd2]),
+        None,
+        Some(&[0xd4, 0xc1]),
+        Some(&[0xd6, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xe0]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xb8]),
+        None,
+        None,
+        Some(&[0xc1, 0xbb]),
+        Some(&[0xf4, 0xd3]),
+        Some(&[0xbe, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xe2]),
+        None,
+        None,
+        Some(&[0xf4, 0xd4]),
+        Some(&[0xf4, 0xd5]),
+        Some(&[0xbe, 0xab]),
+        None,
+        None,
+        Some(&[0xf4, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xdb]),
+        None,
+        Some(&[0xf4, 0xd7]),
+        Some(&[0xf4, 0xda]),
+        None,
+        Some(&[0xba, 0xfd]),
+        None,
+        Some(&[0xf4, 0xd8]),
+        Some(&[0xf4, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xe2]),
+        Some(&[0xcc, 0xc7]),
+        Some(&[0xf4, 0xdc]),
+        None,
+        Some(&[0xb2, 0xda]),
+        None,
+        None,
+        Some(&[0xc3, 0xd3]),
+        None,
+        None,
+        Some(&[0xd4, 0xe3]),
+        Some(&[0xbf, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xe9]),
+        None,
+        None,
+        Some(&[0xcf, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7D20: [Option<&'static [u8]>; 16] = [
+        Some(&[0xcb, 0xd8]),
+        None,
+        Some(&[0xcb, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xdb]),
+    ];
+
+    const BASE_0X7E38: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xed]),
+    ];
+
+    const BASE_0X7E98: [Option<&'static [u8]>; 824] = [
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xf9]),
+        Some(&[0xbe, 0xc0]),
+        Some(&[0xe6, 0xfa]),
+        Some(&[0xba, 0xec]),
+        Some(&[0xe6, 0xfb]),
+        Some(&[0xcf, 0xcb]),
+        Some(&[0xe6, 0xfc]),
+        Some(&[0xd4, 0xbc]),
+        Some(&[0xbc, 0xb6]),
+        Some(&[0xe6, 0xfd]),
+        Some(&[0xe6, 0xfe]),
+        Some(&[0xbc, 0xcd]),
+        Some(&[0xc8, 0xd2]),
+        Some(&[0xce, 0xb3]),
+        Some(&[0xe7, 0xa1]),
+        None,
+        Some(&[0xb4, 0xbf]),
+        Some(&[0xe7, 0xa2]),
+        Some(&[0xc9, 0xb4]),
+        Some(&[0xb8, 0xd9]),
+        Some(&[0xc4, 0xc9]),
+        None,
+        Some(&[0xd7, 0xdd]),
+        Some(&[0xc2, 0xda]),
+        Some(&[0xb7, 0xd7]),
+        Some(&[0xd6, 0xbd]),
+        Some(&[0xce, 0xc6]),
+        Some(&[0xb7, 0xc4]),
+        None,
+        None,
+        Some(&[0xc5, 0xa6]),
+        Some(&[0xe7, 0xa3]),
+        Some(&[0xcf, 0xdf]),
+        Some(&[0xe7, 0xa4]),
+        Some(&[0xe7, 0xa5]),
+        Some(&[0xe7, 0xa6]),
+        Some(&[0xc1, 0xb7]),
+        Some(&[0xd7, 0xe9]),
+        Some(&[0xc9, 0xf0]),
+        Some(&[0xcf, 0xb8]),
+        Some(&[0xd6, 0xaf]),
+        Some(&[0xd6, 0xd5]),
+        Some(&[0xe7, 0xa7]),
+        Some(&[0xb0, 0xed]),
+        Some(&[0xe7, 0xa8]),
+        Some(&[0xe7, 0xa9]),
+        Some(&[0xc9, 0xdc]),
+        Some(&[0xd2, 0xef]),
+        Some(&[0xbe, 0xad]),
+        Some(&[0xe7, 0xaa]),
+        Some(&[0xb0, 0xf3]),
+        Some(&[0xc8, 0xde]),
+        Some(&[0xbd, 0xe1]),
+        Some(&[0xe7, 0xab]),
+        Some(&[0xc8, 0xc6]),
+        None,
+        Some(&[0xe7, 0xac]),
+        Some(&[0xbb, 0xe6]),
+        Some(&[0xb8, 0xf8]),
+        Some(&[0xd1, 0xa4]),
+        Some(&[0xe7, 0xad]),
+        Some(&[0xc2, 0xe7]),
+        Some(&[0xbe, 0xf8]),
+        Some(&[0xbd, 0xca]),
+        Some(&[0xcd, 0xb3]),
+        Some(&[0xe7, 0xae]),
+        Some(&[0xe7, 0xaf]),
+        Some(&[0xbe, 0xee]),
+        Some(&[0xd0, 0xe5]),
+        None,
+        Some(&[0xcb, 0xe7]),
+        Some(&[0xcc, 0xd0]),
+        Some(&[0xbc, 0xcc]),
+        Some(&[0xe7, 0xb0]),
+        Some(&[0xbc, 0xa8]),
+        Some(&[0xd0, 0xf7]),
+        Some(&[0xe7, 0xb1]),
+        None,
+        Some(&[0xd0, 0xf8]),
+        Some(&[0xe7, 0xb2]),
+        Some(&[0xe7, 0xb3]),
+        Some(&[0xb4, 0xc2]),
+        Some(&[0xe7, 0xb4]),
+        Some(&[0xe7, 0xb5]),
+        Some(&[0xc9, 0xfe]),
+        Some(&[0xce, 0xac]),
+        Some(&[0xc3, 0xe0]),
+        Some(&[0xe7, 0xb7]),
+        Some(&[0xb1, 0xc1]),
+        Some(&[0xb3, 0xf1]),
+        None,
+        Some(&[0xe7, 0xb8]),
+        Some(&[0xe7, 0xb9]),
+        Some(&[0xd7, 0xdb]),
+        Some(&[0xd5, 0xc0]),
+        Some(&[0xe7, 0xba]),
+        Some(&[0xc2, 0xcc]),
+        Some(&[0xd7, 0xba]),
+        Some(&[0xe7, 0xbb]),
+        Some(&[0xe7, 0xbc]),
+        Some(&[0xe7, 0xbd]),
+        Some(&[0xbc, 0xea]),
+        Some(&[0xc3, 0xe5]),
+        Some(&[0xc0, 0xc2]),
+        Some(&[0xe7, 0xbe]),
+        Some(&[0xe7, 0xbf]),
+        Some(&[0xbc, 0xa9]),
+        None,
+        Some(&[0xe7, 0xc0]),
+        Some(&[0xe7, 0xc1]),
+        Some(&[0xe7, 0xb6]),
+        Some(&[0xb6, 0xd0]),
+        Some(&[0xe7, 0xc2]),
+        None,
+        Some(&[0xe7, 0xc3]),
+        Some(&[0xe7, 0xc4]),
+        Some(&[0xbb, 0xba]),
+        Some(&[0xb5, 0xde]),
+        Some(&[0xc2, 0xc6]),
+        Some(&[0xb1, 0xe0]),
+        Some(&[0xe7, 0xc5]),
+        Some(&[0xd4, 0xb5]),
+        Some(&[0xe7, 0xc6]),
+        Some(&[0xb8, 0xbf]),
+        Some(&[0xe7, 0xc8]),
+        Some(&[0xe7, 0xc7]),
+        Some(&[0xb7, 0xec]),
+        None,
+        Some(&[0xe7, 0xc9]),
+        Some(&[0xb2, 0xf8]),
+        Some(&[0xe7, 0xca]),
+        Some(&[0xe7, 0xcb]),
+        Some(&[0xe7, 0xcc]),
+        Some(&[0xe7, 0xcd]),
+        Some(&[0xe7, 0xce]),
+        Some(&[0xe7, 0xcf]),
+        Some(&[0xe7, 0xd0]),
+        Some(&[0xd3, 0xa7]),
+        Some(&[0xcb, 0xf5]),
+        Some(&[0xe7, 0xd1]),
+        Some(&[0xe7, 0xd2]),
+        Some(&[0xe7, 0xd3]),
+        Some(&[0xe7, 0xd4]),
+        Some(&[0xc9, 0xc9]),
+        Some(&[0xe7, 0xd5]),
+        Some(&[0xe7, 0xd6]),
+        Some(&[0xe7, 0xd7]),
+        Some(&[0xe7, 0xd8]),
+        Some(&[0xe7, 0xd9]),
+        Some(&[0xbd, 0xc9]),
+        Some(&[0xe7, 0xda]),
+        Some(&[0xf3, 0xbe]),
+        None,
+        Some(&[0xb8, 0xd7]),
+        None,
+        Some(&[0xc8, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xbf]),
+        None,
+        Some(&[0xf3, 0xc0]),
+        Some(&[0xf3, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xde]),
+        Some(&[0xcd, 0xf8]),
+        None,
+        None,
+        Some(&[0xd8, 0xe8]),
+        Some(&[0xba, 0xb1]),
+        None,
+        Some(&[0xc2, 0xde]),
+        Some(&[0xee, 0xb7]),
+        None,
+        Some(&[0xb7, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xb9]),
+        None,
+        Some(&[0xee, 0xb8]),
+        Some(&[0xb0, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xbb]),
+        Some(&[0xd5, 0xd6]),
+        Some(&[0xd7, 0xef]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xc3]),
+        None,
+        None,
+        Some(&[0xee, 0xbd]),
+        Some(&[0xca, 0xf0]),
+        None,
+        Some(&[0xee, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xc0]),
+        None,
+        None,
+        Some(&[0xee, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf2]),
+        None,
+        Some(&[0xc7, 0xbc]),
+        None,
+        Some(&[0xc3, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xe7]),
+        None,
+        None,
+        Some(&[0xf4, 0xc6]),
+        Some(&[0xd0, 0xdf]),
+        Some(&[0xf4, 0xc7]),
+        None,
+        Some(&[0xcf, 0xdb]),
+        None,
+        None,
+        Some(&[0xc8, 0xba]),
+        None,
+        None,
+        Some(&[0xf4, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xc9]),
+        Some(&[0xf4, 0xca]),
+        None,
+        Some(&[0xf4, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xfa]),
+        Some(&[0xb8, 0xfe]),
+        None,
+        None,
+        Some(&[0xe5, 0xf1]),
+        Some(&[0xd3, 0xf0]),
+        None,
+        Some(&[0xf4, 0xe0]),
+        None,
+        Some(&[0xce, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xb4]),
+        None,
+        Some(&[0xd2, 0xee]),
+        None,
+        Some(&[0xf4, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xe8]),
+        Some(&[0xf4, 0xe2]),
+        None,
+        None,
+        Some(&[0xc7, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xd4]),
+        Some(&[0xb4, 0xe4]),
+        Some(&[0xf4, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xe3]),
+        Some(&[0xf4, 0xe5]),
+        None,
+        None,
+        Some(&[0xf4, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xe7]),
+        None,
+        Some(&[0xba, 0xb2]),
+        Some(&[0xb0, 0xbf]),
+        None,
+        Some(&[0xf4, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xad]),
+        Some(&[0xd2, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xab]),
+        Some(&[0xc0, 0xcf]),
+        None,
+        Some(&[0xbf, 0xbc]),
+        Some(&[0xeb, 0xa3]),
+        Some(&[0xd5, 0xdf]),
+        Some(&[0xea, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xf3]),
+        Some(&[0xb6, 0xf8]),
+        Some(&[0xcb, 0xa3]),
+        None,
+        None,
+        Some(&[0xc4, 0xcd]),
+        None,
+        Some(&[0xf1, 0xe7]),
+        None,
+        Some(&[0xf1, 0xe8]),
+        Some(&[0xb8, 0xfb]),
+        Some(&[0xf1, 0xe9]),
+        Some(&[0xba, 0xc4]),
+        Some(&[0xd4, 0xc5]),
+        Some(&[0xb0, 0xd2]),
+        None,
+        None,
+        Some(&[0xf1, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xeb]),
+        None,
+        Some(&[0xf1, 0xec]),
+        None,
+        None,
+        Some(&[0xf1, 0xed]),
+        Some(&[0xf1, 0xee]),
+        Some(&[0xf1, 0xef]),
+        Some(&[0xf1, 0xf1]),
+        Some(&[0xf1, 0xf0]),
+        Some(&[0xc5, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xf2]),
+        None,
+        Some(&[0xb6, 0xfa]),
+        None,
+        Some(&[0xf1, 0xf4]),
+        Some(&[0xd2, 0xae]),
+        Some(&[0xde, 0xc7]),
+        Some(&[0xcb, 0xca]),
+        None,
+        None,
+        Some(&[0xb3, 0xdc]),
+        None,
+        Some(&[0xb5, 0xa2]),
+        None,
+        Some(&[0xb9, 0xa2]),
+        None,
+        None,
+        Some(&[0xc4, 0xf4]),
+        Some(&[0xf1, 0xf5]),
+        None,
+        None,
+        Some(&[0xf1, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc4]),
+        Some(&[0xc1, 0xfb]),
+        Some(&[0xd6, 0xb0]),
+        Some(&[0xf1, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xf8]),
+        None,
+        Some(&[0xc1, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xb8]),
+        None,
+        Some(&[0xbe, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xf9]),
+        Some(&[0xb4, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xb2]),
+        Some(&[0xed, 0xb1]),
+        None,
+        None,
+        Some(&[0xcb, 0xe0]),
+        Some(&[0xd2, 0xde]),
+        None,
+        Some(&[0xcb, 0xc1]),
+        Some(&[0xd5, 0xd8]),
+        None,
+        Some(&[0xc8, 0xe2]),
+        None,
+        Some(&[0xc0, 0xdf]),
+        Some(&[0xbc, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xc1]),
+        None,
+        None,
+        Some(&[0xd0, 0xa4]),
+        None,
+        Some(&[0xd6, 0xe2]),
+        None,
+        Some(&[0xb6, 0xc7]),
+        Some(&[0xb8, 0xd8]),
+        Some(&[0xeb, 0xc0]),
+        Some(&[0xb8, 0xce]),
+        None,
+        Some(&[0xeb, 0xbf]),
+        Some(&[0xb3, 0xa6]),
+        Some(&[0xb9, 0xc9]),
+        Some(&[0xd6, 0xab]),
+        None,
+        Some(&[0xb7, 0xf4]),
+        Some(&[0xb7, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xe7]),
+        Some(&[0xb7, 0xbe]),
+        Some(&[0xeb, 0xc6]),
+        None,
+        Some(&[0xeb, 0xc7]),
+        Some(&[0xb0, 0xb9]),
+        Some(&[0xbf, 0xcf]),
+        None,
+        Some(&[0xeb, 0xc5]),
+        Some(&[0xd3, 0xfd]),
+        None,
+        Some(&[0xeb, 0xc8]),
+        None,
+        None,
+        Some(&[0xeb, 0xc9]),
+        None,
+        None,
+        Some(&[0xb7, 0xce]),
+        None,
+        Some(&[0xeb, 0xc2]),
+        Some(&[0xeb, 0xc4]),
+        Some(&[0xc9, 0xf6]),
+        Some(&[0xd6, 0xd7]),
+        Some(&[0xd5, 0xcd]),
+        Some(&[0xd0, 0xb2]),
+        Some(&[0xeb, 0xcf]),
+        Some(&[0xce, 0xb8]),
+        Some(&[0xeb, 0xd0]),
+        None,
+        Some(&[0xb5, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xb3]),
+        Some(&[0xeb, 0xd2]),
+        Some(&[0xcc, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xd6]),
+        Some(&[0xeb, 0xd3]),
+        None,
+        Some(&[0xeb, 0xd1]),
+        Some(&[0xc5, 0xdf]),
+        Some(&[0xeb, 0xce]),
+        Some(&[0xca, 0xa4]),
+        Some(&[0xeb, 0xd5]),
+        Some(&[0xb0, 0xfb]),
+        None,
+        None,
+        Some(&[0xba, 0xfa]),
+        None,
+        None,
+        Some(&[0xd8, 0xb7]),
+        Some(&[0xf1, 0xe3]),
+        None,
+        Some(&[0xeb, 0xca]),
+        Some(&[0xeb, 0xcb]),
+        Some(&[0xeb, 0xcc]),
+        Some(&[0xeb, 0xcd]),
+        Some(&[0xeb, 0xd6]),
+        Some(&[0xe6, 0xc0]),
+        Some(&[0xeb, 0xd9]),
+        None,
+        Some(&[0xbf, 0xe8]),
+        Some(&[0xd2, 0xc8]),
+        Some(&[0xeb, 0xd7]),
+        Some(&[0xeb, 0xdc]),
+        Some(&[0xb8, 0xec]),
+        Some(&[0xeb, 0xd8]),
+        None,
+        Some(&[0xbd, 0xba]),
+        None,
+        Some(&[0xd0, 0xd8]),
+        None,
+        Some(&[0xb0, 0xb7]),
+        None,
+        Some(&[0xeb, 0xdd]),
+        Some(&[0xc4, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xe0]),
+        None,
+        None,
+        Some(&[0xc2, 0xf6]),
+        Some(&[0xbc, 0xb9]),
+        None,
+        None,
+        Some(&[0xeb, 0xda]),
+        Some(&[0xeb, 0xdb]),
+        Some(&[0xd4, 0xe0]),
+        Some(&[0xc6, 0xea]),
+        Some(&[0xc4, 0xd4]),
+        Some(&[0xeb, 0xdf]),
+        Some(&[0xc5, 0xa7]),
+        Some(&[0xd9, 0xf5]),
+        None,
+        Some(&[0xb2, 0xb1]),
+        None,
+        Some(&[0xeb, 0xe4]),
+        None,
+        Some(&[0xbd, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xe3]),
+        None,
+        None,
+        Some(&[0xb8, 0xac]),
+        None,
+        Some(&[0xcd, 0xd1]),
+        Some(&[0xeb, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xe1]),
+        None,
+        Some(&[0xc1, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xf3]),
+        None,
+        Some(&[0xeb, 0xe6]),
+        None,
+        Some(&[0xc0, 0xb0]),
+        Some(&[0xd2, 0xb8]),
+        Some(&[0xeb, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xaf]),
+        Some(&[0xb8, 0xad]),
+        None,
+        Some(&[0xeb, 0xe8]),
+        Some(&[0xc7, 0xbb]),
+        Some(&[0xcd, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xea]),
+        Some(&[0xeb, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xc8]),
+        None,
+        Some(&[0xeb, 0xf2]),
+        None,
+        Some(&[0xeb, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xf1]),
+        Some(&[0xc8, 0xf9]),
+        None,
+        Some(&[0xd1, 0xfc]),
+        Some(&[0xeb, 0xec]),
+        None,
+        None,
+        Some(&[0xeb, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xb9]),
+        Some(&[0xcf, 0xd9]),
+        Some(&[0xc4, 0xe5]),
+        Some(&[0xeb, 0xef]),
+        Some(&[0xeb, 0xf0]),
+        Some(&[0xcc, 0xda]),
+        Some(&[0xcd, 0xc8]),
+        Some(&[0xb0, 0xf2]),
+        None,
+        Some(&[0xeb, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xf5]),
+        None,
+        Some(&[0xb2, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xe0]),
+        None,
+        Some(&[0xeb, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xec]),
+        None,
+        None,
+        Some(&[0xcc, 0xc5]),
+        Some(&[0xc4, 0xa4]),
+        Some(&[0xcf, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xf9]),
+        None,
+        None,
+        Some(&[0xec, 0xa2]),
+        None,
+        Some(&[0xc5, 0xf2]),
+        None,
+        Some(&[0xeb, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdf]),
+        Some(&[0xeb, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xce]),
+        Some(&[0xec, 0xa1]),
+        Some(&[0xb1, 0xdb]),
+        Some(&[0xd3, 0xb7]),
+        None,
+        None,
+        Some(&[0xd2, 0xdc]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xfd]),
+        None,
+        Some(&[0xeb, 0xfb]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X81E0: [Option<&'static [u8]>; 1040] = [
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xb0]),
+        None,
+        None,
+        Some(&[0xd7, 0xd4]),
+        None,
+        Some(&[0xf4, 0xab]),
+        Some(&[0xb3, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xc1]),
+        Some(&[0xd6, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xe9]),
+        Some(&[0xbe, 0xca]),
+        None,
+        Some(&[0xf4, 0xa7]),
+        None,
+        Some(&[0xd2, 0xa8]),
+        Some(&[0xf4, 0xa8]),
+        Some(&[0xf4, 0xa9]),
+        None,
+        Some(&[0xf4, 0xaa]),
+        Some(&[0xbe, 0xcb]),
+        Some(&[0xd3, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xe0]),
+        Some(&[0xc9, 0xe1]),
+        None,
+        None,
+        Some(&[0xf3, 0xc2]),
+        None,
+        Some(&[0xca, 0xe6]),
+        None,
+        Some(&[0xcc, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb6]),
+        Some(&[0xcb, 0xb4]),
+        None,
+        Some(&[0xce, 0xe8]),
+        Some(&[0xd6, 0xdb]),
+        None,
+        Some(&[0xf4, 0xad]),
+        Some(&[0xf4, 0xae]),
+        Some(&[0xf4, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xb2]),
+        None,
+        Some(&[0xba, 0xbd]),
+        Some(&[0xf4, 0xb3]),
+        Some(&[0xb0, 0xe3]),
+        Some(&[0xf4, 0xb0]),
+        None,
+        Some(&[0xf4, 0xb1]),
+        Some(&[0xbd, 0xa2]),
+        Some(&[0xb2, 0xd5]),
+        None,
+        Some(&[0xf4, 0xb6]),
+        Some(&[0xf4, 0xb7]),
+        Some(&[0xb6, 0xe6]),
+        Some(&[0xb2, 0xb0]),
+        Some(&[0xcf, 0xcf]),
+        Some(&[0xf4, 0xb4]),
+        Some(&[0xb4, 0xac]),
+        None,
+        Some(&[0xf4, 0xb5]),
+        None,
+        None,
+        Some(&[0xf4, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xb9]),
+        None,
+        None,
+        Some(&[0xcd, 0xa7]),
+        None,
+        Some(&[0xf4, 0xba]),
+        None,
+        Some(&[0xf4, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xd2]),
+        None,
+        Some(&[0xf4, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xde]),
+        Some(&[0xc1, 0xbc]),
+        Some(&[0xbc, 0xe8]),
+        None,
+        Some(&[0xc9, 0xab]),
+        Some(&[0xd1, 0xde]),
+        Some(&[0xe5, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xb3]),
+        Some(&[0xd2, 0xd5]),
+        None,
+        None,
+        Some(&[0xdc, 0xb4]),
+        Some(&[0xb0, 0xac]),
+        Some(&[0xdc, 0xb5]),
+        None,
+        None,
+        Some(&[0xbd, 0xda]),
+        None,
+        Some(&[0xdc, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xc2]),
+        None,
+        Some(&[0xdc, 0xb7]),
+        Some(&[0xd3, 0xf3]),
+        None,
+        Some(&[0xc9, 0xd6]),
+        Some(&[0xdc, 0xba]),
+        Some(&[0xdc, 0xb6]),
+        None,
+        Some(&[0xdc, 0xbb]),
+        Some(&[0xc3, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xbc]),
+        Some(&[0xdc, 0xc5]),
+        Some(&[0xdc, 0xbd]),
+        None,
+        None,
+        Some(&[0xce, 0xdf]),
+        Some(&[0xd6, 0xa5]),
+        None,
+        Some(&[0xdc, 0xcf]),
+        None,
+        Some(&[0xdc, 0xcd]),
+        None,
+        None,
+        Some(&[0xdc, 0xd2]),
+        Some(&[0xbd, 0xe6]),
+        Some(&[0xc2, 0xab]),
+        None,
+        Some(&[0xdc, 0xb8]),
+        Some(&[0xdc, 0xcb]),
+        Some(&[0xdc, 0xce]),
+        Some(&[0xdc, 0xbe]),
+        Some(&[0xb7, 0xd2]),
+        Some(&[0xb0, 0xc5]),
+        Some(&[0xdc, 0xc7]),
+        Some(&[0xd0, 0xbe]),
+        Some(&[0xdc, 0xc1]),
+        Some(&[0xbb, 0xa8]),
+        None,
+        Some(&[0xb7, 0xbc]),
+        Some(&[0xdc, 0xcc]),
+        None,
+        None,
+        Some(&[0xdc, 0xc6]),
+        Some(&[0xdc, 0xbf]),
+        Some(&[0xc7, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xbf]),
+        Some(&[0xdc, 0xc0]),
+        None,
+        None,
+        Some(&[0xdc, 0xca]),
+        None,
+        None,
+        Some(&[0xdc, 0xd0]),
+        None,
+        None,
+        Some(&[0xce, 0xad]),
+        Some(&[0xdc, 0xc2]),
+        None,
+        Some(&[0xdc, 0xc3]),
+        Some(&[0xdc, 0xc8]),
+        Some(&[0xdc, 0xc9]),
+        Some(&[0xb2, 0xd4]),
+        Some(&[0xdc, 0xd1]),
+        Some(&[0xcb, 0xd5]),
+        None,
+        Some(&[0xd4, 0xb7]),
+        Some(&[0xdc, 0xdb]),
+        Some(&[0xdc, 0xdf]),
+        Some(&[0xcc, 0xa6]),
+        Some(&[0xdc, 0xe6]),
+        None,
+        Some(&[0xc3, 0xe7]),
+        Some(&[0xdc, 0xdc]),
+        None,
+        None,
+        Some(&[0xbf, 0xc1]),
+        Some(&[0xdc, 0xd9]),
+        None,
+        Some(&[0xb0, 0xfa]),
+        Some(&[0xb9, 0xb6]),
+        Some(&[0xdc, 0xe5]),
+        Some(&[0xdc, 0xd3]),
+        None,
+        Some(&[0xdc, 0xc4]),
+        Some(&[0xdc, 0xd6]),
+        Some(&[0xc8, 0xf4]),
+        Some(&[0xbf, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xbd]),
+        None,
+        Some(&[0xd3, 0xa2]),
+        None,
+        None,
+        Some(&[0xdc, 0xda]),
+        None,
+        None,
+        Some(&[0xdc, 0xd5]),
+        None,
+        Some(&[0xc6, 0xbb]),
+        None,
+        Some(&[0xdc, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xc2]),
+        Some(&[0xc3, 0xaf]),
+        Some(&[0xb7, 0xb6]),
+        Some(&[0xc7, 0xd1]),
+        Some(&[0xc3, 0xa9]),
+        Some(&[0xdc, 0xe2]),
+        Some(&[0xdc, 0xd8]),
+        Some(&[0xdc, 0xeb]),
+        Some(&[0xdc, 0xd4]),
+        None,
+        None,
+        Some(&[0xdc, 0xdd]),
+        None,
+        Some(&[0xbe, 0xa5]),
+        Some(&[0xdc, 0xd7]),
+        None,
+        Some(&[0xdc, 0xe0]),
+        None,
+        None,
+        Some(&[0xdc, 0xe3]),
+        Some(&[0xdc, 0xe4]),
+        None,
+        Some(&[0xdc, 0xf8]),
+        None,
+        None,
+        Some(&[0xdc, 0xe1]),
+        Some(&[0xdd, 0xa2]),
+        Some(&[0xdc, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xeb]),
+        Some(&[0xb4, 0xc4]),
+        None,
+        None,
+        Some(&[0xc3, 0xa3]),
+        Some(&[0xb2, 0xe7]),
+        Some(&[0xdc, 0xfa]),
+        None,
+        Some(&[0xdc, 0xf2]),
+        None,
+        Some(&[0xdc, 0xef]),
+        None,
+        Some(&[0xdc, 0xfc]),
+        Some(&[0xdc, 0xee]),
+        Some(&[0xd2, 0xf0]),
+        Some(&[0xb2, 0xe8]),
+        None,
+        Some(&[0xc8, 0xd7]),
+        Some(&[0xc8, 0xe3]),
+        Some(&[0xdc, 0xfb]),
+        None,
+        Some(&[0xdc, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xf7]),
+        None,
+        None,
+        Some(&[0xdc, 0xf5]),
+        None,
+        None,
+        Some(&[0xbe, 0xa3]),
+        Some(&[0xdc, 0xf4]),
+        None,
+        Some(&[0xb2, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xf3]),
+        Some(&[0xbc, 0xf6]),
+        Some(&[0xdc, 0xe8]),
+        Some(&[0xbb, 0xc4]),
+        None,
+        Some(&[0xc0, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xd4]),
+        Some(&[0xdc, 0xe9]),
+        Some(&[0xdc, 0xea]),
+        None,
+        Some(&[0xdc, 0xf1]),
+        Some(&[0xdc, 0xf6]),
+        Some(&[0xdc, 0xf9]),
+        Some(&[0xb5, 0xb4]),
+        None,
+        Some(&[0xc8, 0xd9]),
+        Some(&[0xbb, 0xe7]),
+        Some(&[0xdc, 0xfe]),
+        Some(&[0xdc, 0xfd]),
+        Some(&[0xd3, 0xab]),
+        Some(&[0xdd, 0xa1]),
+        Some(&[0xdd, 0xa3]),
+        Some(&[0xdd, 0xa5]),
+        Some(&[0xd2, 0xf1]),
+        Some(&[0xdd, 0xa4]),
+        Some(&[0xdd, 0xa6]),
+        Some(&[0xdd, 0xa7]),
+        Some(&[0xd2, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xc9]),
+        Some(&[0xdd, 0xa9]),
+        None,
+        None,
+        Some(&[0xdd, 0xb6]),
+        Some(&[0xdd, 0xb1]),
+        Some(&[0xdd, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xb0]),
+        Some(&[0xc6, 0xce]),
+        None,
+        None,
+        Some(&[0xc0, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xec]),
+        Some(&[0xdd, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xb7]),
+        None,
+        None,
+        Some(&[0xdc, 0xf0]),
+        Some(&[0xdd, 0xaf]),
+        None,
+        Some(&[0xdd, 0xb8]),
+        None,
+        Some(&[0xdd, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xb9]),
+        Some(&[0xdd, 0xb3]),
+        Some(&[0xdd, 0xad]),
+        Some(&[0xc4, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xa8]),
+        Some(&[0xc0, 0xb3]),
+        Some(&[0xc1, 0xab]),
+        Some(&[0xdd, 0xaa]),
+        Some(&[0xdd, 0xab]),
+        None,
+        Some(&[0xdd, 0xb2]),
+        Some(&[0xbb, 0xf1]),
+        Some(&[0xdd, 0xb5]),
+        Some(&[0xd3, 0xa8]),
+        Some(&[0xdd, 0xba]),
+        None,
+        Some(&[0xdd, 0xbb]),
+        Some(&[0xc3, 0xa7]),
+        None,
+        None,
+        Some(&[0xdd, 0xd2]),
+        Some(&[0xdd, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xd1]),
+        None,
+        Some(&[0xb9, 0xbd]),
+        None,
+        None,
+        Some(&[0xbe, 0xd5]),
+        None,
+        Some(&[0xbe, 0xfa]),
+        None,
+        None,
+        Some(&[0xba, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xca]),
+        None,
+        Some(&[0xdd, 0xc5]),
+        None,
+        Some(&[0xdd, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xcb]),
+        Some(&[0xdd, 0xc3]),
+        None,
+        Some(&[0xdd, 0xcb]),
+        Some(&[0xb2, 0xa4]),
+        Some(&[0xdd, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xd0]),
+        Some(&[0xdd, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xd4]),
+        Some(&[0xc1, 0xe2]),
+        Some(&[0xb7, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xce]),
+        Some(&[0xdd, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xbd]),
+        None,
+        Some(&[0xdd, 0xcd]),
+        Some(&[0xcc, 0xd1]),
+        None,
+        Some(&[0xdd, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xc2]),
+        Some(&[0xc3, 0xc8]),
+        Some(&[0xc6, 0xbc]),
+        Some(&[0xce, 0xae]),
+        Some(&[0xdd, 0xcc]),
+        None,
+        Some(&[0xdd, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xc6]),
+        Some(&[0xc2, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xa9]),
+        Some(&[0xd3, 0xaa]),
+        Some(&[0xdd, 0xd3]),
+        Some(&[0xcf, 0xf4]),
+        Some(&[0xc8, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xe0]),
+        Some(&[0xc2, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xf8]),
+        None,
+        Some(&[0xdd, 0xd9]),
+        Some(&[0xdd, 0xd8]),
+        Some(&[0xb8, 0xf0]),
+        Some(&[0xdd, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xcf]),
+        None,
+        Some(&[0xb6, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xe2]),
+        None,
+        Some(&[0xba, 0xf9]),
+        Some(&[0xd4, 0xe1]),
+        Some(&[0xdd, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xd0]),
+        None,
+        Some(&[0xdd, 0xda]),
+        None,
+        Some(&[0xbf, 0xfb]),
+        Some(&[0xdd, 0xe3]),
+        None,
+        Some(&[0xdd, 0xdf]),
+        None,
+        Some(&[0xdd, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xdb]),
+        Some(&[0xdd, 0xdc]),
+        Some(&[0xdd, 0xde]),
+        None,
+        Some(&[0xbd, 0xaf]),
+        Some(&[0xdd, 0xe4]),
+        None,
+        Some(&[0xdd, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xf5]),
+        None,
+        Some(&[0xc3, 0xc9]),
+        None,
+        None,
+        Some(&[0xcb, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xe1]),
+        None,
+        None,
+        Some(&[0xc6, 0xd1]),
+        None,
+        Some(&[0xdd, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xf4]),
+        Some(&[0xdd, 0xf3]),
+        Some(&[0xdd, 0xf0]),
+        None,
+        None,
+        Some(&[0xdd, 0xec]),
+        None,
+        Some(&[0xdd, 0xef]),
+        None,
+        Some(&[0xdd, 0xe8]),
+        None,
+        None,
+        Some(&[0xd0, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xd8]),
+        Some(&[0xdd, 0xee]),
+        None,
+        None,
+        Some(&[0xdd, 0xe9]),
+        None,
+        None,
+        Some(&[0xdd, 0xea]),
+        Some(&[0xcb, 0xf2]),
+        None,
+        Some(&[0xdd, 0xed]),
+        None,
+        None,
+        Some(&[0xb1, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xb6]),
+        None,
+        Some(&[0xbc, 0xbb]),
+        Some(&[0xdd, 0xf1]),
+        None,
+        None,
+        Some(&[0xdd, 0xf7]),
+        None,
+        Some(&[0xdd, 0xf6]),
+        Some(&[0xdd, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa4]),
+        None,
+        None,
+        Some(&[0xde, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xef]),
+        None,
+        Some(&[0xc2, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xe1]),
+        None,
+        None,
+        Some(&[0xce, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xfd]),
+        None,
+        Some(&[0xb2, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xe8]),
+        Some(&[0xca, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xbe]),
+        Some(&[0xdd, 0xfa]),
+        Some(&[0xdd, 0xfc]),
+        Some(&[0xdd, 0xfe]),
+        Some(&[0xde, 0xa2]),
+        Some(&[0xb0, 0xaa]),
+        Some(&[0xb1, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa6]),
+        Some(&[0xbd, 0xb6]),
+        Some(&[0xc8, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa1]),
+        None,
+        None,
+        Some(&[0xde, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xad]),
+        None,
+        Some(&[0xd4, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb3]),
+        Some(&[0xde, 0xaa]),
+        Some(&[0xde, 0xae]),
+        None,
+        None,
+        Some(&[0xc0, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xa1]),
+        Some(&[0xde, 0xb6]),
+        None,
+        Some(&[0xde, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xa6]),
+        Some(&[0xde, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb0]),
+        None,
+        Some(&[0xd0, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb4]),
+        Some(&[0xca, 0xed]),
+        Some(&[0xde, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb8]),
+        None,
+        Some(&[0xde, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xd8]),
+        Some(&[0xc3, 0xea]),
+        None,
+        None,
+        Some(&[0xde, 0xba]),
+        None,
+        Some(&[0xc5, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X85F8: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xc1]),
+        None,
+    ];
+
+    const BASE_0X8620: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xbe]),
+        None,
+        Some(&[0xde, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xc2]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8648: [Option<&'static [u8]>; 768] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xae]),
+        Some(&[0xbb, 0xa2]),
+        Some(&[0xc2, 0xb2]),
+        Some(&[0xc5, 0xb0]),
+        Some(&[0xc2, 0xc7]),
+        None,
+        None,
+        Some(&[0xf2, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xe6]),
+        Some(&[0xf2, 0xb0]),
+        None,
+        Some(&[0xf2, 0xb1]),
+        None,
+        None,
+        Some(&[0xca, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xe7]),
+        Some(&[0xf2, 0xb3]),
+        Some(&[0xf2, 0xb5]),
+        Some(&[0xf2, 0xb4]),
+        Some(&[0xcb, 0xe4]),
+        Some(&[0xcf, 0xba]),
+        Some(&[0xf2, 0xb2]),
+        Some(&[0xca, 0xb4]),
+        Some(&[0xd2, 0xcf]),
+        Some(&[0xc2, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xc3]),
+        Some(&[0xf2, 0xb8]),
+        Some(&[0xb0, 0xf6]),
+        Some(&[0xf2, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xbe]),
+        None,
+        Some(&[0xb2, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xc1]),
+        Some(&[0xf2, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xbc]),
+        Some(&[0xd4, 0xe9]),
+        None,
+        None,
+        Some(&[0xf2, 0xbb]),
+        Some(&[0xf2, 0xb6]),
+        Some(&[0xf2, 0xbf]),
+        Some(&[0xf2, 0xbd]),
+        None,
+        Some(&[0xf2, 0xb9]),
+        None,
+        None,
+        Some(&[0xf2, 0xc7]),
+        Some(&[0xf2, 0xc4]),
+        Some(&[0xf2, 0xc6]),
+        None,
+        None,
+        Some(&[0xf2, 0xca]),
+        Some(&[0xf2, 0xc2]),
+        Some(&[0xf2, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xc1]),
+        None,
+        Some(&[0xc7, 0xf9]),
+        Some(&[0xc9, 0xdf]),
+        None,
+        Some(&[0xf2, 0xc8]),
+        Some(&[0xb9, 0xc6]),
+        Some(&[0xb5, 0xb0]),
+        None,
+        None,
+        Some(&[0xf2, 0xc3]),
+        Some(&[0xf2, 0xc9]),
+        Some(&[0xf2, 0xd0]),
+        Some(&[0xf2, 0xd6]),
+        None,
+        None,
+        Some(&[0xbb, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xd5]),
+        Some(&[0xcd, 0xdc]),
+        None,
+        Some(&[0xd6, 0xeb]),
+        None,
+        None,
+        Some(&[0xf2, 0xd2]),
+        Some(&[0xf2, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xce]),
+        Some(&[0xc2, 0xf9]),
+        None,
+        Some(&[0xd5, 0xdd]),
+        Some(&[0xf2, 0xcc]),
+        Some(&[0xf2, 0xcd]),
+        Some(&[0xf2, 0xcf]),
+        Some(&[0xf2, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xd9]),
+        Some(&[0xd3, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xea]),
+        None,
+        Some(&[0xca, 0xf1]),
+        None,
+        Some(&[0xb7, 0xe4]),
+        Some(&[0xf2, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xd8]),
+        Some(&[0xf2, 0xda]),
+        Some(&[0xf2, 0xdd]),
+        Some(&[0xf2, 0xdb]),
+        None,
+        None,
+        Some(&[0xf2, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xd1]),
+        Some(&[0xf2, 0xd1]),
+        None,
+        Some(&[0xcd, 0xc9]),
+        None,
+        Some(&[0xce, 0xcf]),
+        Some(&[0xd6, 0xa9]),
+        None,
+        Some(&[0xf2, 0xe3]),
+        None,
+        Some(&[0xc3, 0xdb]),
+        None,
+        Some(&[0xf2, 0xe0]),
+        None,
+        None,
+        Some(&[0xc0, 0xaf]),
+        Some(&[0xf2, 0xec]),
+        Some(&[0xf2, 0xde]),
+        None,
+        Some(&[0xf2, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xe2]),
+        None,
+        None,
+        Some(&[0xf2, 0xe7]),
+        None,
+        None,
+        Some(&[0xf2, 0xe6]),
+        None,
+        None,
+        Some(&[0xf2, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xdf]),
+        None,
+        None,
+        Some(&[0xf2, 0xe4]),
+        Some(&[0xf2, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xac]),
+        Some(&[0xf2, 0xe5]),
+        Some(&[0xb2, 0xf5]),
+        None,
+        None,
+        Some(&[0xf2, 0xf2]),
+        None,
+        Some(&[0xd0, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xc8]),
+        None,
+        Some(&[0xf2, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xf0]),
+        None,
+        None,
+        Some(&[0xf2, 0xf6]),
+        Some(&[0xf2, 0xf8]),
+        Some(&[0xf2, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xf3]),
+        None,
+        Some(&[0xf2, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xfb]),
+        None,
+        Some(&[0xb5, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xef]),
+        Some(&[0xf2, 0xf7]),
+        Some(&[0xf2, 0xed]),
+        Some(&[0xf2, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xeb]),
+        Some(&[0xf3, 0xa6]),
+        None,
+        Some(&[0xf3, 0xa3]),
+        None,
+        None,
+        Some(&[0xf3, 0xa2]),
+        None,
+        None,
+        Some(&[0xf2, 0xf4]),
+        None,
+        Some(&[0xc8, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xfd]),
+        None,
+        None,
+        Some(&[0xf3, 0xa7]),
+        Some(&[0xf3, 0xa9]),
+        Some(&[0xf3, 0xa4]),
+        None,
+        Some(&[0xf2, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xab]),
+        None,
+        Some(&[0xf3, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xdd]),
+        None,
+        None,
+        Some(&[0xf3, 0xae]),
+        None,
+        None,
+        Some(&[0xf3, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb1]),
+        Some(&[0xf3, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xaf]),
+        Some(&[0xf2, 0xfe]),
+        Some(&[0xf3, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xb7]),
+        None,
+        Some(&[0xc8, 0xe4]),
+        Some(&[0xf3, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xbb]),
+        Some(&[0xb4, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xbc]),
+        None,
+        None,
+        Some(&[0xf3, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xac]),
+        Some(&[0xd0, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xd0]),
+        Some(&[0xd1, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xce]),
+        None,
+        None,
+        Some(&[0xbd, 0xd6]),
+        None,
+        Some(&[0xd1, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xe2]),
+        Some(&[0xe1, 0xe9]),
+        Some(&[0xd2, 0xc2]),
+        Some(&[0xf1, 0xc2]),
+        Some(&[0xb2, 0xb9]),
+        None,
+        None,
+        Some(&[0xb1, 0xed]),
+        Some(&[0xf1, 0xc3]),
+        None,
+        Some(&[0xc9, 0xc0]),
+        Some(&[0xb3, 0xc4]),
+        None,
+        Some(&[0xd9, 0xf2]),
+        None,
+        Some(&[0xcb, 0xa5]),
+        None,
+        Some(&[0xf1, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xc5]),
+        Some(&[0xf4, 0xc0]),
+        Some(&[0xf1, 0xc6]),
+        None,
+        Some(&[0xd4, 0xac]),
+        Some(&[0xf1, 0xc7]),
+        None,
+        Some(&[0xb0, 0xc0]),
+        Some(&[0xf4, 0xc1]),
+        None,
+        None,
+        Some(&[0xf4, 0xc2]),
+        None,
+        None,
+        Some(&[0xb4, 0xfc]),
+        None,
+        Some(&[0xc5, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xc8]),
+        None,
+        Some(&[0xd9, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xbb]),
+        None,
+        Some(&[0xcf, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xc3]),
+        Some(&[0xc1, 0xd1]),
+        None,
+        None,
+        Some(&[0xd7, 0xb0]),
+        Some(&[0xf1, 0xc9]),
+        None,
+        None,
+        Some(&[0xf1, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xf6]),
+        None,
+        Some(&[0xd2, 0xe1]),
+        Some(&[0xd4, 0xa3]),
+        None,
+        None,
+        Some(&[0xf4, 0xc3]),
+        Some(&[0xc8, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xc4]),
+        None,
+        None,
+        Some(&[0xf1, 0xcd]),
+        Some(&[0xf1, 0xcf]),
+        Some(&[0xbf, 0xe3]),
+        Some(&[0xf1, 0xd0]),
+        None,
+        None,
+        Some(&[0xf1, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xd6]),
+        Some(&[0xf1, 0xd1]),
+        None,
+        Some(&[0xc9, 0xd1]),
+        Some(&[0xc5, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xe3]),
+        Some(&[0xb9, 0xfc]),
+        None,
+        None,
+        Some(&[0xf1, 0xd3]),
+        None,
+        Some(&[0xf1, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xd6]),
+        None,
+        Some(&[0xb0, 0xfd]),
+        Some(&[0xf1, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xd8]),
+        Some(&[0xf1, 0xd2]),
+        Some(&[0xf1, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xca]),
+        Some(&[0xf1, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xdc]),
+        None,
+        Some(&[0xf1, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xdf]),
+        None,
+        None,
+        Some(&[0xcf, 0xe5]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8958: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xc5]),
+        Some(&[0xbd, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xe0]),
+        None,
+    ];
+
+    const BASE_0X8978: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xf7]),
+        None,
+        Some(&[0xd2, 0xaa]),
+        None,
+        Some(&[0xf1, 0xfb]),
+        None,
+        None,
+        Some(&[0xb8, 0xb2]),
+        None,
+    ];
+
+    const BASE_0X89C0: [Option<&'static [u8]>; 72] = [
+        None,
+        Some(&[0xbc, 0xfb]),
+        Some(&[0xb9, 0xdb]),
+        None,
+        Some(&[0xb9, 0xe6]),
+        Some(&[0xc3, 0xd9]),
+        Some(&[0xca, 0xd3]),
+        Some(&[0xea, 0xe8]),
+        Some(&[0xc0, 0xc0]),
+        Some(&[0xbe, 0xf5]),
+        Some(&[0xea, 0xe9]),
+        Some(&[0xea, 0xea]),
+        Some(&[0xea, 0xeb]),
+        None,
+        Some(&[0xea, 0xec]),
+        Some(&[0xea, 0xed]),
+        Some(&[0xea, 0xee]),
+        Some(&[0xea, 0xef]),
+        Some(&[0xbd, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xfd]),
+        None,
+        Some(&[0xf5, 0xfe]),
+        None,
+        Some(&[0xf5, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xe2]),
+        None,
+        Some(&[0xf6, 0xa1]),
+        Some(&[0xb4, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xea]),
+    ];
+
+    const BASE_0X8A78: [Option<&'static [u8]>; 32] = [
+        None,
+        Some(&[0xd5, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xfe]),
+        Some(&[0xcc, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8BA0: [Option<&'static [u8]>; 256] = [
+        Some(&[0xda, 0xa5]),
+        Some(&[0xbc, 0xc6]),
+        Some(&[0xb6, 0xa9]),
+        Some(&[0xb8, 0xbc]),
+        Some(&[0xc8, 0xcf]),
+        Some(&[0xbc, 0xa5]),
+        Some(&[0xda, 0xa6]),
+        Some(&[0xda, 0xa7]),
+        Some(&[0xcc, 0xd6]),
+        Some(&[0xc8, 0xc3]),
+        Some(&[0xda, 0xa8]),
+        Some(&[0xc6, 0xfd]),
+        None,
+        Some(&[0xd1, 0xb5]),
+        Some(&[0xd2, 0xe9]),
+        Some(&[0xd1, 0xb6]),
+        Some(&[0xbc, 0xc7]),
+        None,
+        Some(&[0xbd, 0xb2]),
+        Some(&[0xbb, 0xe4]),
+        Some(&[0xda, 0xa9]),
+        Some(&[0xda, 0xaa]),
+        Some(&[0xd1, 0xc8]),
+        Some(&[0xda, 0xab]),
+        Some(&[0xd0, 0xed]),
+        Some(&[0xb6, 0xef]),
+        Some(&[0xc2, 0xdb]),
+        None,
+        Some(&[0xcb, 0xcf]),
+        Some(&[0xb7, 0xed]),
+        Some(&[0xc9, 0xe8]),
+        Some(&[0xb7, 0xc3]),
+        Some(&[0xbe, 0xf7]),
+        Some(&[0xd6, 0xa4]),
+        Some(&[0xda, 0xac]),
+        Some(&[0xda, 0xad]),
+        Some(&[0xc6, 0xc0]),
+        Some(&[0xd7, 0xe7]),
+        Some(&[0xca, 0xb6]),
+        None,
+        Some(&[0xd5, 0xa9]),
+        Some(&[0xcb, 0xdf]),
+        Some(&[0xd5, 0xef]),
+        Some(&[0xda, 0xae]),
+        Some(&[0xd6, 0xdf]),
+        Some(&[0xb4, 0xca]),
+        Some(&[0xda, 0xb0]),
+        Some(&[0xda, 0xaf]),
+        None,
+        Some(&[0xd2, 0xeb]),
+        Some(&[0xda, 0xb1]),
+        Some(&[0xda, 0xb2]),
+        Some(&[0xda, 0xb3]),
+        Some(&[0xca, 0xd4]),
+        Some(&[0xda, 0xb4]),
+        Some(&[0xca, 0xab]),
+        Some(&[0xda, 0xb5]),
+        Some(&[0xda, 0xb6]),
+        Some(&[0xb3, 0xcf]),
+        Some(&[0xd6, 0xef]),
+        Some(&[0xda, 0xb7]),
+        Some(&[0xbb, 0xb0]),
+        Some(&[0xb5, 0xae]),
+        Some(&[0xda, 0xb8]),
+        Some(&[0xda, 0xb9]),
+        Some(&[0xb9, 0xee]),
+        Some(&[0xd1, 0xaf]),
+        Some(&[0xd2, 0xe8]),
+        Some(&[0xda, 0xba]),
+        Some(&[0xb8, 0xc3]),
+        Some(&[0xcf, 0xea]),
+        Some(&[0xb2, 0xef]),
+        Some(&[0xda, 0xbb]),
+        Some(&[0xda, 0xbc]),
+        None,
+        Some(&[0xbd, 0xeb]),
+        Some(&[0xce, 0xdc]),
+        Some(&[0xd3, 0xef]),
+        Some(&[0xda, 0xbd]),
+        Some(&[0xce, 0xf3]),
+        Some(&[0xda, 0xbe]),
+        Some(&[0xd3, 0xd5]),
+        Some(&[0xbb, 0xe5]),
+        Some(&[0xda, 0xbf]),
+        Some(&[0xcb, 0xb5]),
+        Some(&[0xcb, 0xd0]),
+        Some(&[0xda, 0xc0]),
+        Some(&[0xc7, 0xeb]),
+        Some(&[0xd6, 0xee]),
+        Some(&[0xda, 0xc1]),
+        Some(&[0xc5, 0xb5]),
+        Some(&[0xb6, 0xc1]),
+        Some(&[0xda, 0xc2]),
+        Some(&[0xb7, 0xcc]),
+        Some(&[0xbf, 0xce]),
+        Some(&[0xda, 0xc3]),
+        Some(&[0xda, 0xc4]),
+        Some(&[0xcb, 0xad]),
+        Some(&[0xda, 0xc5]),
+        Some(&[0xb5, 0xf7]),
+        Some(&[0xda, 0xc6]),
+        Some(&[0xc1, 0xc2]),
+        Some(&[0xd7, 0xbb]),
+        Some(&[0xda, 0xc7]),
+        Some(&[0xcc, 0xb8]),
+        None,
+        Some(&[0xd2, 0xea]),
+        Some(&[0xc4, 0xb1]),
+        Some(&[0xda, 0xc8]),
+        Some(&[0xb5, 0xfd]),
+        Some(&[0xbb, 0xd1]),
+        Some(&[0xda, 0xc9]),
+        Some(&[0xd0, 0xb3]),
+        Some(&[0xda, 0xca]),
+        Some(&[0xda, 0xcb]),
+        Some(&[0xce, 0xbd]),
+        Some(&[0xda, 0xcc]),
+        Some(&[0xda, 0xcd]),
+        Some(&[0xda, 0xce]),
+        Some(&[0xb2, 0xf7]),
+        Some(&[0xda, 0xd1]),
+        Some(&[0xda, 0xcf]),
+        Some(&[0xd1, 0xe8]),
+        Some(&[0xda, 0xd0]),
+        Some(&[0xc3, 0xd5]),
+        Some(&[0xda, 0xd2]),
+        None,
+        Some(&[0xda, 0xd3]),
+        Some(&[0xda, 0xd4]),
+        Some(&[0xda, 0xd5]),
+        Some(&[0xd0, 0xbb]),
+        Some(&[0xd2, 0xa5]),
+        Some(&[0xb0, 0xf9]),
+        Some(&[0xda, 0xd6]),
+        Some(&[0xc7, 0xab]),
+        Some(&[0xda, 0xd7]),
+        Some(&[0xbd, 0xf7]),
+        Some(&[0xc3, 0xa1]),
+        Some(&[0xda, 0xd8]),
+        Some(&[0xda, 0xd9]),
+        Some(&[0xc3, 0xfd]),
+        Some(&[0xcc, 0xb7]),
+        Some(&[0xda, 0xda]),
+        Some(&[0xda, 0xdb]),
+        Some(&[0xc0, 0xbe]),
+        Some(&[0xc6, 0xd7]),
+        Some(&[0xda, 0xdc]),
+        Some(&[0xda, 0xdd]),
+        Some(&[0xc7, 0xb4]),
+        Some(&[0xda, 0xde]),
+        Some(&[0xda, 0xdf]),
+        Some(&[0xb9, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xb9]),
+        Some(&[0xf4, 0xf8]),
+        None,
+        Some(&[0xf4, 0xf9]),
+        None,
+        None,
+        Some(&[0xcd, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xf3]),
+        Some(&[0xbb, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xc0]),
+        Some(&[0xd4, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf4]),
+        Some(&[0xb1, 0xaa]),
+        Some(&[0xb2, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf5]),
+        None,
+        None,
+        Some(&[0xf5, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xd1]),
+        Some(&[0xf5, 0xf6]),
+        None,
+        Some(&[0xc3, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8D18: [Option<&'static [u8]>; 424] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xb4]),
+        Some(&[0xd5, 0xea]),
+        Some(&[0xb8, 0xba]),
+        None,
+        Some(&[0xb9, 0xb1]),
+        Some(&[0xb2, 0xc6]),
+        Some(&[0xd4, 0xf0]),
+        Some(&[0xcf, 0xcd]),
+        Some(&[0xb0, 0xdc]),
+        Some(&[0xd5, 0xcb]),
+        Some(&[0xbb, 0xf5]),
+        Some(&[0xd6, 0xca]),
+        Some(&[0xb7, 0xb7]),
+        Some(&[0xcc, 0xb0]),
+        Some(&[0xc6, 0xb6]),
+        Some(&[0xb1, 0xe1]),
+        Some(&[0xb9, 0xba]),
+        Some(&[0xd6, 0xfc]),
+        Some(&[0xb9, 0xe1]),
+        Some(&[0xb7, 0xa1]),
+        Some(&[0xbc, 0xfa]),
+        Some(&[0xea, 0xda]),
+        Some(&[0xea, 0xdb]),
+        Some(&[0xcc, 0xf9]),
+        Some(&[0xb9, 0xf3]),
+        Some(&[0xea, 0xdc]),
+        Some(&[0xb4, 0xfb]),
+        Some(&[0xc3, 0xb3]),
+        Some(&[0xb7, 0xd1]),
+        Some(&[0xba, 0xd8]),
+        Some(&[0xea, 0xdd]),
+        Some(&[0xd4, 0xf4]),
+        Some(&[0xea, 0xde]),
+        Some(&[0xbc, 0xd6]),
+        Some(&[0xbb, 0xdf]),
+        Some(&[0xea, 0xdf]),
+        Some(&[0xc1, 0xde]),
+        Some(&[0xc2, 0xb8]),
+        Some(&[0xd4, 0xdf]),
+        Some(&[0xd7, 0xca]),
+        Some(&[0xea, 0xe0]),
+        Some(&[0xea, 0xe1]),
+        Some(&[0xea, 0xe4]),
+        Some(&[0xea, 0xe2]),
+        Some(&[0xea, 0xe3]),
+        Some(&[0xc9, 0xde]),
+        Some(&[0xb8, 0xb3]),
+        Some(&[0xb6, 0xc4]),
+        Some(&[0xea, 0xe5]),
+        Some(&[0xca, 0xea]),
+        Some(&[0xc9, 0xcd]),
+        Some(&[0xb4, 0xcd]),
+        None,
+        None,
+        Some(&[0xe2, 0xd9]),
+        Some(&[0xc5, 0xe2]),
+        Some(&[0xea, 0xe6]),
+        Some(&[0xc0, 0xb5]),
+        None,
+        Some(&[0xd7, 0xb8]),
+        Some(&[0xea, 0xe7]),
+        Some(&[0xd7, 0xac]),
+        Some(&[0xc8, 0xfc]),
+        Some(&[0xd8, 0xd3]),
+        Some(&[0xd8, 0xcd]),
+        Some(&[0xd4, 0xde]),
+        None,
+        Some(&[0xd4, 0xf9]),
+        Some(&[0xc9, 0xc4]),
+        Some(&[0xd3, 0xae]),
+        Some(&[0xb8, 0xd3]),
+        Some(&[0xb3, 0xe0]),
+        None,
+        Some(&[0xc9, 0xe2]),
+        Some(&[0xf4, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xd5]),
+        None,
+        Some(&[0xf4, 0xf7]),
+        None,
+        None,
+        Some(&[0xd7, 0xdf]),
+        None,
+        None,
+        Some(&[0xf4, 0xf1]),
+        Some(&[0xb8, 0xb0]),
+        Some(&[0xd5, 0xd4]),
+        Some(&[0xb8, 0xcf]),
+        Some(&[0xc6, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xc3]),
+        None,
+        None,
+        Some(&[0xf4, 0xf2]),
+        Some(&[0xb3, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xbd]),
+        Some(&[0xc7, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xf4]),
+        None,
+        None,
+        Some(&[0xf4, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xf5]),
+        None,
+        Some(&[0xd7, 0xe3]),
+        Some(&[0xc5, 0xbf]),
+        Some(&[0xf5, 0xc0]),
+        None,
+        None,
+        Some(&[0xf5, 0xbb]),
+        None,
+        Some(&[0xf5, 0xc3]),
+        None,
+        Some(&[0xf5, 0xc2]),
+        None,
+        Some(&[0xd6, 0xba]),
+        Some(&[0xf5, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xbe]),
+        Some(&[0xf5, 0xc4]),
+        None,
+        Some(&[0xf5, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xcf]),
+        Some(&[0xb5, 0xf8]),
+        None,
+        Some(&[0xf5, 0xc9]),
+        Some(&[0xf5, 0xca]),
+        None,
+        Some(&[0xc5, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xc5]),
+        Some(&[0xf5, 0xc6]),
+        None,
+        None,
+        Some(&[0xf5, 0xc7]),
+        Some(&[0xf5, 0xcb]),
+        None,
+        Some(&[0xbe, 0xe0]),
+        Some(&[0xf5, 0xc8]),
+        Some(&[0xb8, 0xfa]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd0]),
+        Some(&[0xf5, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xe7]),
+        None,
+        Some(&[0xb9, 0xf2]),
+        Some(&[0xf5, 0xbc]),
+        Some(&[0xf5, 0xcd]),
+        None,
+        None,
+        Some(&[0xc2, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xf8]),
+        None,
+        Some(&[0xbc, 0xf9]),
+        None,
+        Some(&[0xf5, 0xce]),
+        Some(&[0xf5, 0xcf]),
+        Some(&[0xf5, 0xd1]),
+        Some(&[0xb6, 0xe5]),
+        Some(&[0xf5, 0xd2]),
+        None,
+        Some(&[0xf5, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd4]),
+        Some(&[0xd3, 0xbb]),
+        None,
+        Some(&[0xb3, 0xec]),
+        None,
+        None,
+        Some(&[0xcc, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd7]),
+        Some(&[0xbe, 0xe1]),
+        Some(&[0xf5, 0xd8]),
+        None,
+        None,
+        Some(&[0xcc, 0xdf]),
+        Some(&[0xf5, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xc8]),
+        Some(&[0xd7, 0xd9]),
+        None,
+        Some(&[0xf5, 0xd9]),
+        None,
+        Some(&[0xf5, 0xda]),
+        Some(&[0xf5, 0xdc]),
+        None,
+        Some(&[0xf5, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xe0]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xdf]),
+        Some(&[0xf5, 0xdd]),
+        None,
+        None,
+        Some(&[0xf5, 0xe1]),
+        None,
+        None,
+        Some(&[0xf5, 0xde]),
+        Some(&[0xf5, 0xe4]),
+        Some(&[0xf5, 0xe5]),
+        None,
+        Some(&[0xcc, 0xe3]),
+        None,
+        None,
+        Some(&[0xe5, 0xbf]),
+        Some(&[0xb5, 0xb8]),
+        Some(&[0xf5, 0xe3]),
+        Some(&[0xf5, 0xe8]),
+        Some(&[0xcc, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xe6]),
+        Some(&[0xf5, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xc4]),
+        None,
+        None,
+        Some(&[0xf5, 0xbf]),
+        None,
+        None,
+        Some(&[0xb5, 0xc5]),
+        Some(&[0xb2, 0xe4]),
+        None,
+        Some(&[0xf5, 0xec]),
+        Some(&[0xf5, 0xe9]),
+        None,
+        Some(&[0xb6, 0xd7]),
+        None,
+        Some(&[0xf5, 0xed]),
+        None,
+        Some(&[0xf5, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xeb]),
+        None,
+        None,
+        Some(&[0xb4, 0xda]),
+        None,
+        Some(&[0xd4, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xee]),
+        None,
+        Some(&[0xb3, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xef]),
+        Some(&[0xf5, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xf2]),
+        None,
+        Some(&[0xf5, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xed]),
+        Some(&[0xb9, 0xaa]),
+        None,
+        None,
+        Some(&[0xc7, 0xfb]),
+        None,
+        None,
+        Some(&[0xb6, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8F60: [Option<&'static [u8]>; 424] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xb5]),
+        Some(&[0xd4, 0xfe]),
+        Some(&[0xb9, 0xec]),
+        Some(&[0xd0, 0xf9]),
+        None,
+        Some(&[0xe9, 0xed]),
+        Some(&[0xd7, 0xaa]),
+        Some(&[0xe9, 0xee]),
+        Some(&[0xc2, 0xd6]),
+        Some(&[0xc8, 0xed]),
+        Some(&[0xba, 0xe4]),
+        Some(&[0xe9, 0xef]),
+        Some(&[0xe9, 0xf0]),
+        Some(&[0xe9, 0xf1]),
+        Some(&[0xd6, 0xe1]),
+        Some(&[0xe9, 0xf2]),
+        Some(&[0xe9, 0xf3]),
+        Some(&[0xe9, 0xf5]),
+        Some(&[0xe9, 0xf4]),
+        Some(&[0xe9, 0xf6]),
+        Some(&[0xe9, 0xf7]),
+        Some(&[0xc7, 0xe1]),
+        Some(&[0xe9, 0xf8]),
+        Some(&[0xd4, 0xd8]),
+        Some(&[0xe9, 0xf9]),
+        Some(&[0xbd, 0xce]),
+        None,
+        Some(&[0xe9, 0xfa]),
+        Some(&[0xe9, 0xfb]),
+        Some(&[0xbd, 0xcf]),
+        Some(&[0xe9, 0xfc]),
+        Some(&[0xb8, 0xa8]),
+        Some(&[0xc1, 0xbe]),
+        Some(&[0xe9, 0xfd]),
+        Some(&[0xb1, 0xb2]),
+        Some(&[0xbb, 0xd4]),
+        Some(&[0xb9, 0xf5]),
+        Some(&[0xe9, 0xfe]),
+        None,
+        Some(&[0xea, 0xa1]),
+        Some(&[0xea, 0xa2]),
+        Some(&[0xea, 0xa3]),
+        Some(&[0xb7, 0xf8]),
+        Some(&[0xbc, 0xad]),
+        None,
+        Some(&[0xca, 0xe4]),
+        Some(&[0xe0, 0xce]),
+        Some(&[0xd4, 0xaf]),
+        Some(&[0xcf, 0xbd]),
+        Some(&[0xd5, 0xb7]),
+        Some(&[0xea, 0xa4]),
+        Some(&[0xd5, 0xde]),
+        Some(&[0xea, 0xa5]),
+        Some(&[0xd0, 0xc1]),
+        Some(&[0xb9, 0xbc]),
+        None,
+        Some(&[0xb4, 0xc7]),
+        Some(&[0xb1, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xe6]),
+        Some(&[0xb1, 0xe7]),
+        None,
+        Some(&[0xb1, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xbd]),
+        Some(&[0xc8, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc1]),
+        None,
+        None,
+        Some(&[0xb1, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xc9]),
+        Some(&[0xb4, 0xef]),
+        None,
+        None,
+        Some(&[0xc7, 0xa8]),
+        Some(&[0xd3, 0xd8]),
+        None,
+        Some(&[0xc6, 0xf9]),
+        Some(&[0xd1, 0xb8]),
+        None,
+        Some(&[0xb9, 0xfd]),
+        Some(&[0xc2, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xad]),
+        None,
+        Some(&[0xd4, 0xcb]),
+        Some(&[0xbd, 0xfc]),
+        None,
+        Some(&[0xe5, 0xc2]),
+        Some(&[0xb7, 0xb5]),
+        Some(&[0xe5, 0xc3]),
+        None,
+        None,
+        Some(&[0xbb, 0xb9]),
+        Some(&[0xd5, 0xe2]),
+        None,
+        Some(&[0xbd, 0xf8]),
+        Some(&[0xd4, 0xb6]),
+        Some(&[0xce, 0xa5]),
+        Some(&[0xc1, 0xac]),
+        Some(&[0xb3, 0xd9]),
+        None,
+        None,
+        Some(&[0xcc, 0xf6]),
+        None,
+        Some(&[0xe5, 0xc6]),
+        Some(&[0xe5, 0xc4]),
+        Some(&[0xe5, 0xc8]),
+        None,
+        Some(&[0xe5, 0xca]),
+        Some(&[0xe5, 0xc7]),
+        Some(&[0xb5, 0xcf]),
+        Some(&[0xc6, 0xc8]),
+        None,
+        Some(&[0xb5, 0xfc]),
+        Some(&[0xe5, 0xc5]),
+        None,
+        Some(&[0xca, 0xf6]),
+        None,
+        None,
+        Some(&[0xe5, 0xc9]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xd4]),
+        Some(&[0xb1, 0xc5]),
+        Some(&[0xbc, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xb7]),
+        None,
+        None,
+        Some(&[0xcd, 0xcb]),
+        Some(&[0xcb, 0xcd]),
+        Some(&[0xca, 0xca]),
+        Some(&[0xcc, 0xd3]),
+        Some(&[0xe5, 0xcc]),
+        Some(&[0xe5, 0xcb]),
+        Some(&[0xc4, 0xe6]),
+        None,
+        None,
+        Some(&[0xd1, 0xa1]),
+        Some(&[0xd1, 0xb7]),
+        Some(&[0xe5, 0xcd]),
+        None,
+        Some(&[0xe5, 0xd0]),
+        None,
+        Some(&[0xcd, 0xb8]),
+        Some(&[0xd6, 0xf0]),
+        Some(&[0xe5, 0xcf]),
+        Some(&[0xb5, 0xdd]),
+        None,
+        Some(&[0xcd, 0xbe]),
+        None,
+        Some(&[0xe5, 0xd1]),
+        Some(&[0xb6, 0xba]),
+        None,
+        None,
+        Some(&[0xcd, 0xa8]),
+        Some(&[0xb9, 0xe4]),
+        None,
+        Some(&[0xca, 0xc5]),
+        Some(&[0xb3, 0xd1]),
+        Some(&[0xcb, 0xd9]),
+        Some(&[0xd4, 0xec]),
+        Some(&[0xe5, 0xd2]),
+        Some(&[0xb7, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd5]),
+        Some(&[0xb4, 0xfe]),
+        Some(&[0xe5, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd3]),
+        Some(&[0xe5, 0xd4]),
+        None,
+        Some(&[0xd2, 0xdd]),
+        None,
+        None,
+        Some(&[0xc2, 0xdf]),
+        Some(&[0xb1, 0xc6]),
+        None,
+        Some(&[0xd3, 0xe2]),
+        None,
+        None,
+        Some(&[0xb6, 0xdd]),
+        Some(&[0xcb, 0xec]),
+        None,
+        Some(&[0xe5, 0xd7]),
+        None,
+        None,
+        Some(&[0xd3, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xe9]),
+        None,
+        Some(&[0xb6, 0xf4]),
+        Some(&[0xe5, 0xda]),
+        Some(&[0xe5, 0xd8]),
+        Some(&[0xe5, 0xd9]),
+        Some(&[0xb5, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xc5]),
+        Some(&[0xe5, 0xdc]),
+        None,
+        None,
+        Some(&[0xe5, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xdd]),
+        Some(&[0xc7, 0xb2]),
+        None,
+        Some(&[0xd2, 0xa3]),
+        None,
+        None,
+        Some(&[0xe5, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xe2]),
+        Some(&[0xd5, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe0]),
+        Some(&[0xd7, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe1]),
+        None,
+        Some(&[0xb1, 0xdc]),
+        Some(&[0xd1, 0xfb]),
+        None,
+        Some(&[0xe5, 0xe2]),
+        Some(&[0xe5, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe3]),
+        None,
+        None,
+        Some(&[0xe5, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xd8]),
+        None,
+        Some(&[0xb5, 0xcb]),
+        None,
+        Some(&[0xe7, 0xdf]),
+        None,
+        Some(&[0xda, 0xf5]),
+        None,
+        Some(&[0xda, 0xf8]),
+        None,
+        Some(&[0xda, 0xf6]),
+        None,
+        Some(&[0xda, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xfa]),
+        Some(&[0xd0, 0xcf]),
+        Some(&[0xc4, 0xc7]),
+        None,
+        None,
+        Some(&[0xb0, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb0]),
+        None,
+        Some(&[0xda, 0xf9]),
+        None,
+        Some(&[0xd3, 0xca]),
+        Some(&[0xba, 0xaa]),
+        Some(&[0xdb, 0xa2]),
+        Some(&[0xc7, 0xf1]),
+        None,
+        Some(&[0xda, 0xfc]),
+        Some(&[0xda, 0xfb]),
+        Some(&[0xc9, 0xdb]),
+        Some(&[0xda, 0xfd]),
+        None,
+        Some(&[0xdb, 0xa1]),
+        Some(&[0xd7, 0xde]),
+        Some(&[0xda, 0xfe]),
+        Some(&[0xc1, 0xda]),
+        None,
+        None,
+        Some(&[0xdb, 0xa5]),
+        None,
+        None,
+        Some(&[0xd3, 0xf4]),
+        None,
+        None,
+        Some(&[0xdb, 0xa7]),
+        Some(&[0xdb, 0xa4]),
+        None,
+        Some(&[0xdb, 0xa8]),
+        None,
+        None,
+        Some(&[0xbd, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xc9]),
+        Some(&[0xdb, 0xa3]),
+        Some(&[0xdb, 0xa6]),
+        Some(&[0xd6, 0xa3]),
+        None,
+        Some(&[0xdb, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xae]),
+        Some(&[0xdb, 0xac]),
+        Some(&[0xba, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xa4]),
+        Some(&[0xdb, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xaa]),
+        Some(&[0xd4, 0xc7]),
+        Some(&[0xb2, 0xbf]),
+        None,
+        None,
+        Some(&[0xdb, 0xaf]),
+        None,
+        Some(&[0xb9, 0xf9]),
+        None,
+        Some(&[0xdb, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xbc]),
+        Some(&[0xdb, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xf5]),
+        None,
+        Some(&[0xdb, 0xb2]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9118: [Option<&'static [u8]>; 200] = [
+        None,
+        Some(&[0xb1, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xb3]),
+        Some(&[0xdb, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xb7]),
+        None,
+        Some(&[0xdb, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xb9]),
+        None,
+        None,
+        Some(&[0xdb, 0xba]),
+        None,
+        None,
+        Some(&[0xd3, 0xcf]),
+        Some(&[0xf4, 0xfa]),
+        Some(&[0xc7, 0xf5]),
+        Some(&[0xd7, 0xc3]),
+        Some(&[0xc5, 0xe4]),
+        Some(&[0xf4, 0xfc]),
+        Some(&[0xf4, 0xfd]),
+        Some(&[0xf4, 0xfb]),
+        None,
+        Some(&[0xbe, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xef]),
+        None,
+        None,
+        Some(&[0xb7, 0xd3]),
+        None,
+        None,
+        Some(&[0xd4, 0xcd]),
+        Some(&[0xcc, 0xaa]),
+        None,
+        None,
+        Some(&[0xf5, 0xa2]),
+        Some(&[0xf5, 0xa1]),
+        Some(&[0xba, 0xa8]),
+        Some(&[0xf4, 0xfe]),
+        Some(&[0xcb, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xa4]),
+        Some(&[0xc0, 0xd2]),
+        None,
+        Some(&[0xb3, 0xea]),
+        None,
+        Some(&[0xcd, 0xaa]),
+        Some(&[0xf5, 0xa5]),
+        Some(&[0xf5, 0xa3]),
+        Some(&[0xbd, 0xb4]),
+        Some(&[0xf5, 0xa8]),
+        None,
+        Some(&[0xf5, 0xa9]),
+        Some(&[0xbd, 0xcd]),
+        Some(&[0xc3, 0xb8]),
+        Some(&[0xbf, 0xe1]),
+        Some(&[0xcb, 0xe1]),
+        Some(&[0xf5, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xa6]),
+        Some(&[0xf5, 0xa7]),
+        Some(&[0xc4, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xac]),
+        None,
+        Some(&[0xb4, 0xbc]),
+        None,
+        Some(&[0xd7, 0xed]),
+        None,
+        Some(&[0xb4, 0xd7]),
+        Some(&[0xf5, 0xab]),
+        Some(&[0xf5, 0xae]),
+        None,
+        None,
+        Some(&[0xf5, 0xad]),
+        Some(&[0xf5, 0xaf]),
+        Some(&[0xd0, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xd1]),
+        Some(&[0xc8, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb0]),
+        Some(&[0xf5, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb2]),
+        None,
+        None,
+        Some(&[0xf5, 0xb3]),
+        Some(&[0xf5, 0xb4]),
+        Some(&[0xf5, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb7]),
+        Some(&[0xf5, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xc9]),
+        None,
+        Some(&[0xd3, 0xd4]),
+        Some(&[0xca, 0xcd]),
+        None,
+        Some(&[0xc0, 0xef]),
+        Some(&[0xd6, 0xd8]),
+        Some(&[0xd2, 0xb0]),
+        Some(&[0xc1, 0xbf]),
+        None,
+        Some(&[0xbd, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xaa]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9480: [Option<&'static [u8]>; 256] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xc4]),
+        Some(&[0xee, 0xc5]),
+        Some(&[0xee, 0xc6]),
+        Some(&[0xd5, 0xeb]),
+        Some(&[0xb6, 0xa4]),
+        Some(&[0xee, 0xc8]),
+        Some(&[0xee, 0xc7]),
+        Some(&[0xee, 0xc9]),
+        Some(&[0xee, 0xca]),
+        Some(&[0xc7, 0xa5]),
+        Some(&[0xee, 0xcb]),
+        Some(&[0xee, 0xcc]),
+        None,
+        Some(&[0xb7, 0xb0]),
+        Some(&[0xb5, 0xf6]),
+        Some(&[0xee, 0xcd]),
+        Some(&[0xee, 0xcf]),
+        None,
+        Some(&[0xee, 0xce]),
+        None,
+        Some(&[0xb8, 0xc6]),
+        Some(&[0xee, 0xd0]),
+        Some(&[0xee, 0xd1]),
+        Some(&[0xee, 0xd2]),
+        Some(&[0xb6, 0xdb]),
+        Some(&[0xb3, 0xae]),
+        Some(&[0xd6, 0xd3]),
+        Some(&[0xc4, 0xc6]),
+        Some(&[0xb1, 0xb5]),
+        Some(&[0xb8, 0xd6]),
+        Some(&[0xee, 0xd3]),
+        Some(&[0xee, 0xd4]),
+        Some(&[0xd4, 0xbf]),
+        Some(&[0xc7, 0xd5]),
+        Some(&[0xbe, 0xfb]),
+        Some(&[0xce, 0xd9]),
+        Some(&[0xb9, 0xb3]),
+        Some(&[0xee, 0xd6]),
+        Some(&[0xee, 0xd5]),
+        Some(&[0xee, 0xd8]),
+        Some(&[0xee, 0xd7]),
+        Some(&[0xc5, 0xa5]),
+        Some(&[0xee, 0xd9]),
+        Some(&[0xee, 0xda]),
+        Some(&[0xc7, 0xae]),
+        Some(&[0xee, 0xdb]),
+        Some(&[0xc7, 0xaf]),
+        Some(&[0xee, 0xdc]),
+        Some(&[0xb2, 0xa7]),
+        Some(&[0xee, 0xdd]),
+        Some(&[0xee, 0xde]),
+        Some(&[0xee, 0xdf]),
+        Some(&[0xee, 0xe0]),
+        Some(&[0xee, 0xe1]),
+        Some(&[0xd7, 0xea]),
+        Some(&[0xee, 0xe2]),
+        Some(&[0xee, 0xe3]),
+        Some(&[0xbc, 0xd8]),
+        Some(&[0xee, 0xe4]),
+        Some(&[0xd3, 0xcb]),
+        Some(&[0xcc, 0xfa]),
+        Some(&[0xb2, 0xac]),
+        Some(&[0xc1, 0xe5]),
+        Some(&[0xee, 0xe5]),
+        Some(&[0xc7, 0xa6]),
+        Some(&[0xc3, 0xad]),
+        None,
+        Some(&[0xee, 0xe6]),
+        Some(&[0xee, 0xe7]),
+        Some(&[0xee, 0xe8]),
+        Some(&[0xee, 0xe9]),
+        Some(&[0xee, 0xea]),
+        Some(&[0xee, 0xeb]),
+        Some(&[0xee, 0xec]),
+        None,
+        Some(&[0xee, 0xed]),
+        Some(&[0xee, 0xee]),
+        Some(&[0xee, 0xef]),
+        None,
+        None,
+        Some(&[0xee, 0xf0]),
+        Some(&[0xee, 0xf1]),
+        Some(&[0xee, 0xf2]),
+        Some(&[0xee, 0xf4]),
+        Some(&[0xee, 0xf3]),
+        None,
+        Some(&[0xee, 0xf5]),
+        Some(&[0xcd, 0xad]),
+        Some(&[0xc2, 0xc1]),
+        Some(&[0xee, 0xf6]),
+        Some(&[0xee, 0xf7]),
+        Some(&[0xee, 0xf8]),
+        Some(&[0xd5, 0xa1]),
+        Some(&[0xee, 0xf9]),
+        Some(&[0xcf, 0xb3]),
+        Some(&[0xee, 0xfa]),
+        Some(&[0xee, 0xfb]),
+        None,
+        Some(&[0xee, 0xfc]),
+        Some(&[0xee, 0xfd]),
+        Some(&[0xef, 0xa1]),
+        Some(&[0xee, 0xfe]),
+        Some(&[0xef, 0xa2]),
+        Some(&[0xb8, 0xf5]),
+        Some(&[0xc3, 0xfa]),
+        Some(&[0xef, 0xa3]),
+        Some(&[0xef, 0xa4]),
+        Some(&[0xbd, 0xc2]),
+        Some(&[0xd2, 0xbf]),
+        Some(&[0xb2, 0xf9]),
+        Some(&[0xef, 0xa5]),
+        Some(&[0xef, 0xa6]),
+        Some(&[0xef, 0xa7]),
+        Some(&[0xd2, 0xf8]),
+        Some(&[0xef, 0xa8]),
+        Some(&[0xd6, 0xfd]),
+        Some(&[0xef, 0xa9]),
+        Some(&[0xc6, 0xcc]),
+        None,
+        Some(&[0xef, 0xaa]),
+        Some(&[0xef, 0xab]),
+        Some(&[0xc1, 0xb4]),
+        Some(&[0xef, 0xac]),
+        Some(&[0xcf, 0xfa]),
+        Some(&[0xcb, 0xf8]),
+        Some(&[0xef, 0xae]),
+        Some(&[0xef, 0xad]),
+        Some(&[0xb3, 0xfa]),
+        Some(&[0xb9, 0xf8]),
+        Some(&[0xef, 0xaf]),
+        Some(&[0xef, 0xb0]),
+        Some(&[0xd0, 0xe2]),
+        Some(&[0xef, 0xb1]),
+        Some(&[0xef, 0xb2]),
+        Some(&[0xb7, 0xe6]),
+        Some(&[0xd0, 0xbf]),
+        Some(&[0xef, 0xb3]),
+        Some(&[0xef, 0xb4]),
+        Some(&[0xef, 0xb5]),
+        Some(&[0xc8, 0xf1]),
+        Some(&[0xcc, 0xe0]),
+        Some(&[0xef, 0xb6]),
+        Some(&[0xef, 0xb7]),
+        Some(&[0xef, 0xb8]),
+        Some(&[0xef, 0xb9]),
+        Some(&[0xef, 0xba]),
+        Some(&[0xd5, 0xe0]),
+        Some(&[0xef, 0xbb]),
+        Some(&[0xb4, 0xed]),
+        Some(&[0xc3, 0xaa]),
+        Some(&[0xef, 0xbc]),
+        None,
+        Some(&[0xef, 0xbd]),
+        Some(&[0xef, 0xbe]),
+        Some(&[0xef, 0xbf]),
+        None,
+        Some(&[0xce, 0xfd]),
+        Some(&[0xef, 0xc0]),
+        Some(&[0xc2, 0xe0]),
+        Some(&[0xb4, 0xb8]),
+        Some(&[0xd7, 0xb6]),
+        Some(&[0xbd, 0xf5]),
+        None,
+        Some(&[0xcf, 0xc7]),
+        Some(&[0xef, 0xc3]),
+        Some(&[0xef, 0xc1]),
+        Some(&[0xef, 0xc2]),
+        Some(&[0xef, 0xc4]),
+        Some(&[0xb6, 0xa7]),
+        Some(&[0xbc, 0xfc]),
+        Some(&[0xbe, 0xe2]),
+        Some(&[0xc3, 0xcc]),
+        Some(&[0xef, 0xc5]),
+        Some(&[0xef, 0xc6]),
+        None,
+        Some(&[0xef, 0xc7]),
+        Some(&[0xef, 0xcf]),
+        Some(&[0xef, 0xc8]),
+        Some(&[0xef, 0xc9]),
+        Some(&[0xef, 0xca]),
+        Some(&[0xc7, 0xc2]),
+        Some(&[0xef, 0xf1]),
+        Some(&[0xb6, 0xcd]),
+        Some(&[0xef, 0xcb]),
+        None,
+        Some(&[0xef, 0xcc]),
+        Some(&[0xef, 0xcd]),
+        Some(&[0xb6, 0xc6]),
+        Some(&[0xc3, 0xbe]),
+        Some(&[0xef, 0xce]),
+        None,
+        Some(&[0xef, 0xd0]),
+        Some(&[0xef, 0xd1]),
+        Some(&[0xef, 0xd2]),
+        Some(&[0xd5, 0xf2]),
+        None,
+        Some(&[0xef, 0xd3]),
+        Some(&[0xc4, 0xf7]),
+        None,
+        Some(&[0xef, 0xd4]),
+        Some(&[0xc4, 0xf8]),
+        Some(&[0xef, 0xd5]),
+        Some(&[0xef, 0xd6]),
+        Some(&[0xb8, 0xe4]),
+        Some(&[0xb0, 0xf7]),
+        Some(&[0xef, 0xd7]),
+        Some(&[0xef, 0xd8]),
+        Some(&[0xef, 0xd9]),
+        None,
+        Some(&[0xef, 0xda]),
+        Some(&[0xef, 0xdb]),
+        Some(&[0xef, 0xdc]),
+        Some(&[0xef, 0xdd]),
+        None,
+        Some(&[0xef, 0xde]),
+        Some(&[0xbe, 0xb5]),
+        Some(&[0xef, 0xe1]),
+        Some(&[0xef, 0xdf]),
+        Some(&[0xef, 0xe0]),
+        None,
+        Some(&[0xef, 0xe2]),
+        Some(&[0xef, 0xe3]),
+        Some(&[0xc1, 0xcd]),
+        Some(&[0xef, 0xe4]),
+        Some(&[0xef, 0xe5]),
+        Some(&[0xef, 0xe6]),
+        Some(&[0xef, 0xe7]),
+        Some(&[0xef, 0xe8]),
+        Some(&[0xef, 0xe9]),
+        Some(&[0xef, 0xea]),
+        Some(&[0xef, 0xeb]),
+        Some(&[0xef, 0xec]),
+        Some(&[0xc0, 0xd8]),
+        None,
+        Some(&[0xef, 0xed]),
+        Some(&[0xc1, 0xad]),
+        Some(&[0xef, 0xee]),
+        Some(&[0xef, 0xef]),
+        Some(&[0xef, 0xf0]),
+        None,
+        None,
+        Some(&[0xcf, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xa4]),
+    ];
+
+    const BASE_0X95E8: [Option<&'static [u8]>; 344] = [
+        Some(&[0xc3, 0xc5]),
+        Some(&[0xe3, 0xc5]),
+        Some(&[0xc9, 0xc1]),
+        Some(&[0xe3, 0xc6]),
+        None,
+        Some(&[0xb1, 0xd5]),
+        Some(&[0xce, 0xca]),
+        Some(&[0xb4, 0xb3]),
+        Some(&[0xc8, 0xf2]),
+        Some(&[0xe3, 0xc7]),
+        Some(&[0xcf, 0xd0]),
+        Some(&[0xe3, 0xc8]),
+        Some(&[0xbc, 0xe4]),
+        Some(&[0xe3, 0xc9]),
+        Some(&[0xe3, 0xca]),
+        Some(&[0xc3, 0xc6]),
+        Some(&[0xd5, 0xa2]),
+        Some(&[0xc4, 0xd6]),
+        Some(&[0xb9, 0xeb]),
+        Some(&[0xce, 0xc5]),
+        Some(&[0xe3, 0xcb]),
+        Some(&[0xc3, 0xf6]),
+        Some(&[0xe3, 0xcc]),
+        None,
+        Some(&[0xb7, 0xa7]),
+        Some(&[0xb8, 0xf3]),
+        Some(&[0xba, 0xd2]),
+        Some(&[0xe3, 0xcd]),
+        Some(&[0xe3, 0xce]),
+        Some(&[0xd4, 0xc4]),
+        Some(&[0xe3, 0xcf]),
+        None,
+        Some(&[0xe3, 0xd0]),
+        Some(&[0xd1, 0xcb]),
+        Some(&[0xe3, 0xd1]),
+        Some(&[0xe3, 0xd2]),
+        Some(&[0xe3, 0xd3]),
+        Some(&[0xe3, 0xd4]),
+        Some(&[0xd1, 0xd6]),
+        Some(&[0xe3, 0xd5]),
+        Some(&[0xb2, 0xfb]),
+        Some(&[0xc0, 0xbb]),
+        Some(&[0xe3, 0xd6]),
+        None,
+        Some(&[0xc0, 0xab]),
+        Some(&[0xe3, 0xd7]),
+        Some(&[0xe3, 0xd8]),
+        Some(&[0xe3, 0xd9]),
+        None,
+        Some(&[0xe3, 0xda]),
+        Some(&[0xe3, 0xdb]),
+        None,
+        Some(&[0xb8, 0xb7]),
+        Some(&[0xda, 0xe2]),
+        None,
+        Some(&[0xb6, 0xd3]),
+        None,
+        Some(&[0xda, 0xe4]),
+        Some(&[0xda, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xee]),
+        None,
+        None,
+        Some(&[0xda, 0xe5]),
+        Some(&[0xb7, 0xc0]),
+        Some(&[0xd1, 0xf4]),
+        Some(&[0xd2, 0xf5]),
+        Some(&[0xd5, 0xf3]),
+        Some(&[0xbd, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xe8]),
+        Some(&[0xda, 0xe8]),
+        Some(&[0xda, 0xe7]),
+        None,
+        Some(&[0xb0, 0xa2]),
+        Some(&[0xcd, 0xd3]),
+        None,
+        Some(&[0xda, 0xe9]),
+        None,
+        Some(&[0xb8, 0xbd]),
+        Some(&[0xbc, 0xca]),
+        Some(&[0xc2, 0xbd]),
+        Some(&[0xc2, 0xa4]),
+        Some(&[0xb3, 0xc2]),
+        Some(&[0xda, 0xea]),
+        None,
+        Some(&[0xc2, 0xaa]),
+        Some(&[0xc4, 0xb0]),
+        Some(&[0xbd, 0xb5]),
+        None,
+        None,
+        Some(&[0xcf, 0xde]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xeb]),
+        Some(&[0xc9, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xec]),
+        None,
+        Some(&[0xb6, 0xb8]),
+        Some(&[0xd4, 0xba]),
+        None,
+        Some(&[0xb3, 0xfd]),
+        None,
+        None,
+        Some(&[0xda, 0xed]),
+        Some(&[0xd4, 0xc9]),
+        Some(&[0xcf, 0xd5]),
+        Some(&[0xc5, 0xe3]),
+        None,
+        Some(&[0xda, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xef]),
+        None,
+        Some(&[0xda, 0xf0]),
+        Some(&[0xc1, 0xea]),
+        Some(&[0xcc, 0xd5]),
+        Some(&[0xcf, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xe7]),
+        Some(&[0xc2, 0xa1]),
+        None,
+        Some(&[0xda, 0xf1]),
+        None,
+        None,
+        Some(&[0xcb, 0xe5]),
+        None,
+        Some(&[0xda, 0xf2]),
+        None,
+        Some(&[0xcb, 0xe6]),
+        Some(&[0xd2, 0xfe]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xf4]),
+        None,
+        None,
+        Some(&[0xda, 0xf3]),
+        Some(&[0xb0, 0xaf]),
+        Some(&[0xcf, 0xb6]),
+        None,
+        None,
+        Some(&[0xd5, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xf4]),
+        None,
+        None,
+        Some(&[0xe3, 0xc4]),
+        None,
+        None,
+        Some(&[0xc1, 0xa5]),
+        None,
+        None,
+        Some(&[0xf6, 0xbf]),
+        None,
+        None,
+        Some(&[0xf6, 0xc0]),
+        Some(&[0xf6, 0xc1]),
+        Some(&[0xc4, 0xd1]),
+        None,
+        Some(&[0xc8, 0xb8]),
+        Some(&[0xd1, 0xe3]),
+        None,
+        None,
+        Some(&[0xd0, 0xdb]),
+        Some(&[0xd1, 0xc5]),
+        Some(&[0xbc, 0xaf]),
+        Some(&[0xb9, 0xcd]),
+        None,
+        Some(&[0xef, 0xf4]),
+        None,
+        None,
+        Some(&[0xb4, 0xc6]),
+        Some(&[0xd3, 0xba]),
+        Some(&[0xf6, 0xc2]),
+        Some(&[0xb3, 0xfb]),
+        None,
+        None,
+        Some(&[0xf6, 0xc3]),
+        None,
+        None,
+        Some(&[0xb5, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xea]),
+        Some(&[0xf6, 0xa7]),
+        Some(&[0xd1, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xa8]),
+        None,
+        None,
+        Some(&[0xc1, 0xe3]),
+        Some(&[0xc0, 0xd7]),
+        None,
+        Some(&[0xb1, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xed]),
+        None,
+        Some(&[0xd0, 0xe8]),
+        Some(&[0xf6, 0xab]),
+        None,
+        None,
+        Some(&[0xcf, 0xf6]),
+        None,
+        Some(&[0xf6, 0xaa]),
+        Some(&[0xd5, 0xf0]),
+        Some(&[0xf6, 0xac]),
+        Some(&[0xc3, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xf4]),
+        Some(&[0xf6, 0xae]),
+        Some(&[0xf6, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xde]),
+        None,
+        None,
+        Some(&[0xc1, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xaa]),
+        None,
+        Some(&[0xcf, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xaf]),
+        None,
+        None,
+        Some(&[0xf6, 0xb0]),
+        None,
+        None,
+        Some(&[0xf6, 0xb1]),
+        None,
+        Some(&[0xc2, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xd4]),
+        Some(&[0xc5, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xb2]),
+        None,
+    ];
+
+    const BASE_0X9750: [Option<&'static [u8]>; 104] = [
+        None,
+        None,
+        Some(&[0xc7, 0xe0]),
+        Some(&[0xf6, 0xa6]),
+        None,
+        None,
+        Some(&[0xbe, 0xb8]),
+        None,
+        None,
+        Some(&[0xbe, 0xb2]),
+        None,
+        Some(&[0xb5, 0xe5]),
+        None,
+        None,
+        Some(&[0xb7, 0xc7]),
+        None,
+        Some(&[0xbf, 0xbf]),
+        Some(&[0xc3, 0xd2]),
+        Some(&[0xc3, 0xe6]),
+        None,
+        None,
+        Some(&[0xd8, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xf9]),
+        Some(&[0xd1, 0xa5]),
+        None,
+        Some(&[0xb0, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xac]),
+        None,
+        Some(&[0xb0, 0xb0]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xb2]),
+        Some(&[0xf7, 0xb3]),
+        None,
+        Some(&[0xf7, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xcf]),
+        None,
+        None,
+        Some(&[0xf7, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xb6]),
+        None,
+        Some(&[0xb1, 0xde]),
+        None,
+        Some(&[0xf7, 0xb5]),
+        None,
+        None,
+        Some(&[0xf7, 0xb8]),
+        None,
+        Some(&[0xf7, 0xb9]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X97E0: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xa4]),
+        Some(&[0xc8, 0xcd]),
+        None,
+        Some(&[0xba, 0xab]),
+        Some(&[0xe8, 0xb8]),
+        Some(&[0xe8, 0xb9]),
+        Some(&[0xe8, 0xba]),
+        Some(&[0xbe, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xf4]),
+        None,
+        Some(&[0xd4, 0xcf]),
+        Some(&[0xc9, 0xd8]),
+        None,
+    ];
+
+    const BASE_0X9870: [Option<&'static [u8]>; 56] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xb3]),
+        Some(&[0xb6, 0xa5]),
+        Some(&[0xc7, 0xea]),
+        Some(&[0xf1, 0xfc]),
+        Some(&[0xcf, 0xee]),
+        Some(&[0xcb, 0xb3]),
+        Some(&[0xd0, 0xeb]),
+        Some(&[0xe7, 0xef]),
+        Some(&[0xcd, 0xe7]),
+        Some(&[0xb9, 0xcb]),
+        Some(&[0xb6, 0xd9]),
+        Some(&[0xf1, 0xfd]),
+        Some(&[0xb0, 0xe4]),
+        Some(&[0xcb, 0xcc]),
+        Some(&[0xf1, 0xfe]),
+        Some(&[0xd4, 0xa4]),
+        Some(&[0xc2, 0xad]),
+        Some(&[0xc1, 0xec]),
+        Some(&[0xc6, 0xc4]),
+        Some(&[0xbe, 0xb1]),
+        Some(&[0xf2, 0xa1]),
+        Some(&[0xbc, 0xd5]),
+        None,
+        Some(&[0xf2, 0xa2]),
+        Some(&[0xf2, 0xa3]),
+        None,
+        Some(&[0xf2, 0xa4]),
+        Some(&[0xd2, 0xc3]),
+        Some(&[0xc6, 0xb5]),
+        None,
+        Some(&[0xcd, 0xc7]),
+        Some(&[0xf2, 0xa5]),
+        None,
+        Some(&[0xd3, 0xb1]),
+        Some(&[0xbf, 0xc5]),
+        Some(&[0xcc, 0xe2]),
+        None,
+        Some(&[0xf2, 0xa6]),
+        Some(&[0xf2, 0xa7]),
+        Some(&[0xd1, 0xd5]),
+        Some(&[0xb6, 0xee]),
+        Some(&[0xf2, 0xa8]),
+        Some(&[0xf2, 0xa9]),
+        Some(&[0xb5, 0xdf]),
+        Some(&[0xf2, 0xaa]),
+        Some(&[0xf2, 0xab]),
+        None,
+        Some(&[0xb2, 0xfc]),
+        Some(&[0xf2, 0xac]),
+        Some(&[0xf2, 0xad]),
+        Some(&[0xc8, 0xa7]),
+    ];
+
+    const BASE_0X98C8: [Option<&'static [u8]>; 40] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xe7]),
+        None,
+        None,
+        Some(&[0xec, 0xa9]),
+        Some(&[0xec, 0xaa]),
+        Some(&[0xec, 0xab]),
+        None,
+        Some(&[0xec, 0xac]),
+        None,
+        None,
+        Some(&[0xc6, 0xae]),
+        Some(&[0xec, 0xad]),
+        Some(&[0xec, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xc9]),
+        Some(&[0xca, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb8]),
+        Some(&[0xf7, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9950: [Option<&'static [u8]>; 96] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xd3]),
+        Some(&[0xf7, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xbb]),
+        None,
+        Some(&[0xbc, 0xa2]),
+        None,
+        Some(&[0xe2, 0xbc]),
+        Some(&[0xe2, 0xbd]),
+        Some(&[0xe2, 0xbe]),
+        Some(&[0xe2, 0xbf]),
+        Some(&[0xe2, 0xc0]),
+        Some(&[0xe2, 0xc1]),
+        Some(&[0xb7, 0xb9]),
+        Some(&[0xd2, 0xfb]),
+        Some(&[0xbd, 0xa4]),
+        Some(&[0xca, 0xce]),
+        Some(&[0xb1, 0xa5]),
+        Some(&[0xcb, 0xc7]),
+        None,
+        Some(&[0xe2, 0xc2]),
+        Some(&[0xb6, 0xfc]),
+        Some(&[0xc8, 0xc4]),
+        Some(&[0xe2, 0xc3]),
+        None,
+        None,
+        Some(&[0xbd, 0xc8]),
+        None,
+        Some(&[0xb1, 0xfd]),
+        Some(&[0xe2, 0xc4]),
+        None,
+        Some(&[0xb6, 0xf6]),
+        Some(&[0xe2, 0xc5]),
+        Some(&[0xc4, 0xd9]),
+        None,
+        None,
+        Some(&[0xe2, 0xc6]),
+        Some(&[0xcf, 0xda]),
+        Some(&[0xb9, 0xdd]),
+        Some(&[0xe2, 0xc7]),
+        Some(&[0xc0, 0xa1]),
+        None,
+        Some(&[0xe2, 0xc8]),
+        Some(&[0xb2, 0xf6]),
+        None,
+        Some(&[0xe2, 0xc9]),
+        None,
+        Some(&[0xc1, 0xf3]),
+        Some(&[0xe2, 0xca]),
+        Some(&[0xe2, 0xcb]),
+        Some(&[0xc2, 0xf8]),
+        Some(&[0xe2, 0xcc]),
+        Some(&[0xe2, 0xcd]),
+        Some(&[0xe2, 0xce]),
+        Some(&[0xca, 0xd7]),
+        Some(&[0xd8, 0xb8]),
+        Some(&[0xd9, 0xe5]),
+        Some(&[0xcf, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xa5]),
+        None,
+        None,
+        Some(&[0xdc, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9A68: [Option<&'static [u8]>; 240] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xed]),
+        Some(&[0xd4, 0xa6]),
+        Some(&[0xcd, 0xd4]),
+        Some(&[0xd1, 0xb1]),
+        Some(&[0xb3, 0xdb]),
+        Some(&[0xc7, 0xfd]),
+        None,
+        Some(&[0xb2, 0xb5]),
+        Some(&[0xc2, 0xbf]),
+        Some(&[0xe6, 0xe0]),
+        Some(&[0xca, 0xbb]),
+        Some(&[0xe6, 0xe1]),
+        Some(&[0xe6, 0xe2]),
+        Some(&[0xbe, 0xd4]),
+        Some(&[0xe6, 0xe3]),
+        Some(&[0xd7, 0xa4]),
+        Some(&[0xcd, 0xd5]),
+        Some(&[0xe6, 0xe5]),
+        Some(&[0xbc, 0xdd]),
+        Some(&[0xe6, 0xe4]),
+        Some(&[0xe6, 0xe6]),
+        Some(&[0xe6, 0xe7]),
+        Some(&[0xc2, 0xee]),
+        None,
+        Some(&[0xbd, 0xbe]),
+        Some(&[0xe6, 0xe8]),
+        Some(&[0xc2, 0xe6]),
+        Some(&[0xba, 0xa7]),
+        Some(&[0xe6, 0xe9]),
+        None,
+        Some(&[0xe6, 0xea]),
+        Some(&[0xb3, 0xd2]),
+        Some(&[0xd1, 0xe9]),
+        None,
+        None,
+        Some(&[0xbf, 0xa5]),
+        Some(&[0xe6, 0xeb]),
+        Some(&[0xc6, 0xef]),
+        Some(&[0xe6, 0xec]),
+        Some(&[0xe6, 0xed]),
+        None,
+        None,
+        Some(&[0xe6, 0xee]),
+        Some(&[0xc6, 0xad]),
+        Some(&[0xe6, 0xef]),
+        None,
+        Some(&[0xc9, 0xa7]),
+        Some(&[0xe6, 0xf0]),
+        Some(&[0xe6, 0xf1]),
+        Some(&[0xe6, 0xf2]),
+        Some(&[0xe5, 0xb9]),
+        Some(&[0xe6, 0xf3]),
+        Some(&[0xe6, 0xf4]),
+        Some(&[0xc2, 0xe2]),
+        Some(&[0xe6, 0xf5]),
+        Some(&[0xe6, 0xf6]),
+        Some(&[0xd6, 0xe8]),
+        Some(&[0xe6, 0xf7]),
+        None,
+        Some(&[0xe6, 0xf8]),
+        Some(&[0xb9, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xbb]),
+        Some(&[0xf7, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xbe]),
+        Some(&[0xf7, 0xbc]),
+        Some(&[0xba, 0xa1]),
+        None,
+        Some(&[0xf7, 0xbf]),
+        None,
+        Some(&[0xf7, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xc2]),
+        Some(&[0xf7, 0xc1]),
+        Some(&[0xf7, 0xc4]),
+        None,
+        None,
+        Some(&[0xf7, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xc5]),
+        Some(&[0xf7, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xc7]),
+        None,
+        Some(&[0xcb, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xd4]),
+        None,
+        Some(&[0xf7, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xd8]),
+        None,
+        Some(&[0xf7, 0xda]),
+        None,
+        Some(&[0xf7, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xdb]),
+        None,
+        Some(&[0xf7, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xcb]),
+        None,
+        None,
+        Some(&[0xd8, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf7]),
+        Some(&[0xb9, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xfd]),
+        Some(&[0xbb, 0xea]),
+        Some(&[0xf7, 0xc9]),
+        Some(&[0xc6, 0xc7]),
+        Some(&[0xf7, 0xc8]),
+        None,
+        Some(&[0xf7, 0xca]),
+        Some(&[0xf7, 0xcc]),
+        Some(&[0xf7, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xcd]),
+        None,
+        Some(&[0xce, 0xba]),
+        None,
+        Some(&[0xf7, 0xce]),
+        None,
+        None,
+        Some(&[0xc4, 0xa7]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9C78: [Option<&'static [u8]>; 112] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xe3]),
+        None,
+        None,
+        Some(&[0xf6, 0xcf]),
+        None,
+        Some(&[0xc2, 0xb3]),
+        Some(&[0xf6, 0xd0]),
+        None,
+        None,
+        Some(&[0xf6, 0xd1]),
+        Some(&[0xf6, 0xd2]),
+        Some(&[0xf6, 0xd3]),
+        Some(&[0xf6, 0xd4]),
+        None,
+        None,
+        Some(&[0xf6, 0xd6]),
+        None,
+        Some(&[0xb1, 0xab]),
+        Some(&[0xf6, 0xd7]),
+        None,
+        Some(&[0xf6, 0xd8]),
+        Some(&[0xf6, 0xd9]),
+        Some(&[0xf6, 0xda]),
+        None,
+        Some(&[0xf6, 0xdb]),
+        Some(&[0xf6, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xdd]),
+        Some(&[0xf6, 0xde]),
+        Some(&[0xcf, 0xca]),
+        None,
+        Some(&[0xf6, 0xdf]),
+        Some(&[0xf6, 0xe0]),
+        Some(&[0xf6, 0xe1]),
+        Some(&[0xf6, 0xe2]),
+        Some(&[0xf6, 0xe3]),
+        Some(&[0xf6, 0xe4]),
+        Some(&[0xc0, 0xf0]),
+        Some(&[0xf6, 0xe5]),
+        Some(&[0xf6, 0xe6]),
+        Some(&[0xf6, 0xe7]),
+        Some(&[0xf6, 0xe8]),
+        Some(&[0xf6, 0xe9]),
+        None,
+        Some(&[0xf6, 0xea]),
+        None,
+        Some(&[0xf6, 0xeb]),
+        Some(&[0xf6, 0xec]),
+        None,
+        Some(&[0xf6, 0xed]),
+        Some(&[0xf6, 0xee]),
+        Some(&[0xf6, 0xef]),
+        Some(&[0xf6, 0xf0]),
+        Some(&[0xf6, 0xf1]),
+        Some(&[0xf6, 0xf2]),
+        Some(&[0xf6, 0xf3]),
+        Some(&[0xf6, 0xf4]),
+        Some(&[0xbe, 0xa8]),
+        None,
+        Some(&[0xf6, 0xf5]),
+        Some(&[0xf6, 0xf6]),
+        Some(&[0xf6, 0xf7]),
+        Some(&[0xf6, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8, 0xfa]),
+        Some(&[0xf6, 0xf9]),
+        Some(&[0xf6, 0xfa]),
+        Some(&[0xf6, 0xfb]),
+        Some(&[0xf6, 0xfc]),
+        None,
+        None,
+        Some(&[0xf6, 0xfd]),
+        Some(&[0xf6, 0xfe]),
+        Some(&[0xf7, 0xa1]),
+        Some(&[0xf7, 0xa2]),
+        Some(&[0xf7, 0xa3]),
+        Some(&[0xf7, 0xa4]),
+        Some(&[0xf7, 0xa5]),
+        None,
+        None,
+        Some(&[0xf7, 0xa6]),
+        Some(&[0xf7, 0xa7]),
+        Some(&[0xf7, 0xa8]),
+        Some(&[0xb1, 0xee]),
+        Some(&[0xf7, 0xa9]),
+        Some(&[0xf7, 0xaa]),
+        Some(&[0xf7, 0xab]),
+        None,
+        None,
+        Some(&[0xf7, 0xac]),
+        Some(&[0xf7, 0xad]),
+        Some(&[0xc1, 0xdb]),
+        Some(&[0xf7, 0xae]),
+        None,
+        None,
+        Some(&[0xf7, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9E18: [Option<&'static [u8]>; 320] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xf1]),
+        Some(&[0xf0, 0xaf]),
+        Some(&[0xbc, 0xa6]),
+        Some(&[0xf0, 0xb0]),
+        Some(&[0xc3, 0xf9]),
+        None,
+        Some(&[0xc5, 0xb8]),
+        Some(&[0xd1, 0xbb]),
+        None,
+        Some(&[0xf0, 0xb1]),
+        Some(&[0xf0, 0xb2]),
+        Some(&[0xf0, 0xb3]),
+        Some(&[0xf0, 0xb4]),
+        Some(&[0xf0, 0xb5]),
+        Some(&[0xd1, 0xbc]),
+        None,
+        Some(&[0xd1, 0xec]),
+        None,
+        Some(&[0xf0, 0xb7]),
+        Some(&[0xf0, 0xb6]),
+        Some(&[0xd4, 0xa7]),
+        None,
+        Some(&[0xcd, 0xd2]),
+        Some(&[0xf0, 0xb8]),
+        Some(&[0xf0, 0xba]),
+        Some(&[0xf0, 0xb9]),
+        Some(&[0xf0, 0xbb]),
+        Some(&[0xf0, 0xbc]),
+        None,
+        None,
+        Some(&[0xb8, 0xeb]),
+        Some(&[0xf0, 0xbd]),
+        Some(&[0xba, 0xe8]),
+        None,
+        Some(&[0xf0, 0xbe]),
+        Some(&[0xf0, 0xbf]),
+        Some(&[0xbe, 0xe9]),
+        Some(&[0xf0, 0xc0]),
+        Some(&[0xb6, 0xec]),
+        Some(&[0xf0, 0xc1]),
+        Some(&[0xf0, 0xc2]),
+        Some(&[0xf0, 0xc3]),
+        Some(&[0xf0, 0xc4]),
+        Some(&[0xc8, 0xb5]),
+        Some(&[0xf0, 0xc5]),
+        Some(&[0xf0, 0xc6]),
+        None,
+        Some(&[0xf0, 0xc7]),
+        Some(&[0xc5, 0xf4]),
+        None,
+        Some(&[0xf0, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xc9]),
+        None,
+        Some(&[0xf0, 0xca]),
+        Some(&[0xf7, 0xbd]),
+        None,
+        Some(&[0xf0, 0xcb]),
+        Some(&[0xf0, 0xcc]),
+        Some(&[0xf0, 0xcd]),
+        None,
+        Some(&[0xf0, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0xcf]),
+        Some(&[0xba, 0xd7]),
+        None,
+        Some(&[0xf0, 0xd0]),
+        Some(&[0xf0, 0xd1]),
+        Some(&[0xf0, 0xd2]),
+        Some(&[0xf0, 0xd3]),
+        Some(&[0xf0, 0xd4]),
+        Some(&[0xf0, 0xd5]),
+        Some(&[0xf0, 0xd6]),
+        Some(&[0xf0, 0xd8]),
+        None,
+        None,
+        Some(&[0xd3, 0xa5]),
+        Some(&[0xf0, 0xd7]),
+        None,
+        Some(&[0xf0, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xba]),
+        Some(&[0xc2, 0xb9]),
+        None,
+        None,
+        Some(&[0xf7, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xe5]),
+        Some(&[0xf7, 0xe6]),
+        None,
+        None,
+        Some(&[0xf7, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xe8]),
+        Some(&[0xc2, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xea]),
+        None,
+        Some(&[0xf7, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xef]),
+        None,
+        None,
+        Some(&[0xc2, 0xe9]),
+        None,
+        Some(&[0xf7, 0xe1]),
+        Some(&[0xf7, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xf2]),
+        Some(&[0xc0, 0xe8]),
+        Some(&[0xf0, 0xa4]),
+        None,
+        Some(&[0xba, 0xda]),
+        None,
+        None,
+        Some(&[0xc7, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xac]),
+        None,
+        None,
+        Some(&[0xf7, 0xec]),
+        Some(&[0xf7, 0xed]),
+        Some(&[0xf7, 0xee]),
+        None,
+        Some(&[0xf7, 0xf0]),
+        Some(&[0xf7, 0xef]),
+        None,
+        Some(&[0xf7, 0xf1]),
+        None,
+        None,
+        Some(&[0xf7, 0xf4]),
+        None,
+        Some(&[0xf7, 0xf3]),
+        None,
+        Some(&[0xf7, 0xf2]),
+        Some(&[0xf7, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xe9]),
+        None,
+        Some(&[0xed, 0xea]),
+        Some(&[0xed, 0xeb]),
+        None,
+        Some(&[0xf6, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xbd]),
+        None,
+        Some(&[0xf6, 0xbe]),
+        Some(&[0xb6, 0xa6]),
+        None,
+        Some(&[0xd8, 0xbe]),
+        None,
+        None,
+        Some(&[0xb9, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xbb]),
+        None,
+        Some(&[0xdc, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xf3]),
+        None,
+        Some(&[0xf7, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xf8]),
+        None,
+        None,
+        Some(&[0xf7, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xfb]),
+        None,
+        Some(&[0xf7, 0xfa]),
+        None,
+        Some(&[0xb1, 0xc7]),
+        None,
+        Some(&[0xf7, 0xfc]),
+        Some(&[0xf7, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xeb]),
+        Some(&[0xec, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9F78: [Option<&'static [u8]>; 48] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xdd]),
+        Some(&[0xf6, 0xb3]),
+        None,
+        None,
+        Some(&[0xf6, 0xb4]),
+        Some(&[0xc1, 0xe4]),
+        Some(&[0xf6, 0xb5]),
+        Some(&[0xf6, 0xb6]),
+        Some(&[0xf6, 0xb7]),
+        Some(&[0xf6, 0xb8]),
+        Some(&[0xf6, 0xb9]),
+        Some(&[0xf6, 0xba]),
+        Some(&[0xc8, 0xa3]),
+        Some(&[0xf6, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xfa]),
+        Some(&[0xb9, 0xa8]),
+        Some(&[0xed, 0xe8]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xea]),
+        Some(&[0xd9, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFE30: [Option<&'static [u8]>; 24] = [
+        None,
+        Some(&[0xa6, 0xf2]),
+        None,
+        Some(&[0xa6, 0xf4]),
+        Some(&[0xa6, 0xf5]),
+        Some(&[0xa6, 0xe0]),
+        Some(&[0xa6, 0xe1]),
+        Some(&[0xa6, 0xf0]),
+        Some(&[0xa6, 0xf1]),
+        Some(&[0xa6, 0xe2]),
+        Some(&[0xa6, 0xe3]),
+        Some(&[0xa6, 0xee]),
+        Some(&[0xa6, 0xef]),
+        Some(&[0xa6, 0xe6]),
+        Some(&[0xa6, 0xe7]),
+        Some(&[0xa6, 0xe4]),
+        Some(&[0xa6, 0xe5]),
+        Some(&[0xa6, 0xe8]),
+        Some(&[0xa6, 0xe9]),
+        Some(&[0xa6, 0xea]),
+        Some(&[0xa6, 0xeb]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFF00: [Option<&'static [u8]>; 96] = [
+        None,
+        Some(&[0xa3, 0xa1]),
+        Some(&[0xa3, 0xa2]),
+        Some(&[0xa3, 0xa3]),
+        Some(&[0xa1, 0xe7]),
+        Some(&[0xa3, 0xa5]),
+        Some(&[0xa3, 0xa6]),
+        Some(&[0xa3, 0xa7]),
+        Some(&[0xa3, 0xa8]),
+        Some(&[0xa3, 0xa9]),
+        Some(&[0xa3, 0xaa]),
+        Some(&[0xa3, 0xab]),
+        Some(&[0xa3, 0xac]),
+        Some(&[0xa3, 0xad]),
+        Some(&[0xa3, 0xae]),
+        Some(&[0xa3, 0xaf]),
+        Some(&[0xa3, 0xb0]),
+        Some(&[0xa3, 0xb1]),
+        Some(&[0xa3, 0xb2]),
+        Some(&[0xa3, 0xb3]),
+        Some(&[0xa3, 0xb4]),
+        Some(&[0xa3, 0xb5]),
+        Some(&[0xa3, 0xb6]),
+        Some(&[0xa3, 0xb7]),
+        Some(&[0xa3, 0xb8]),
+        Some(&[0xa3, 0xb9]),
+        Some(&[0xa3, 0xba]),
+        Some(&[0xa3, 0xbb]),
+        Some(&[0xa3, 0xbc]),
+        Some(&[0xa3, 0xbd]),
+        Some(&[0xa3, 0xbe]),
+        Some(&[0xa3, 0xbf]),
+        Some(&[0xa3, 0xc0]),
+        Some(&[0xa3, 0xc1]),
+        Some(&[0xa3, 0xc2]),
+        Some(&[0xa3, 0xc3]),
+        Some(&[0xa3, 0xc4]),
+        Some(&[0xa3, 0xc5]),
+        Some(&[0xa3, 0xc6]),
+        Some(&[0xa3, 0xc7]),
+        Some(&[0xa3, 0xc8]),
+        Some(&[0xa3, 0xc9]),
+        Some(&[0xa3, 0xca]),
+        Some(&[0xa3, 0xcb]),
+        Some(&[0xa3, 0xcc]),
+        Some(&[0xa3, 0xcd]),
+        Some(&[0xa3, 0xce]),
+        Some(&[0xa3, 0xcf]),
+        Some(&[0xa3, 0xd0]),
+        Some(&[0xa3, 0xd1]),
+        Some(&[0xa3, 0xd2]),
+        Some(&[0xa3, 0xd3]),
+        Some(&[0xa3, 0xd4]),
+        Some(&[0xa3, 0xd5]),
+        Some(&[0xa3, 0xd6]),
+        Some(&[0xa3, 0xd7]),
+        Some(&[0xa3, 0xd8]),
+        Some(&[0xa3, 0xd9]),
+        Some(&[0xa3, 0xda]),
+        Some(&[0xa3, 0xdb]),
+        Some(&[0xa3, 0xdc]),
+        Some(&[0xa3, 0xdd]),
+        Some(&[0xa3, 0xde]),
+        Some(&[0xa3, 0xdf]),
+        Some(&[0xa3, 0xe0]),
+        Some(&[0xa3, 0xe1]),
+        Some(&[0xa3, 0xe2]),
+        Some(&[0xa3, 0xe3]),
+        Some(&[0xa3, 0xe4]),
+        Some(&[0xa3, 0xe5]),
+        Some(&[0xa3, 0xe6]),
+        Some(&[0xa3, 0xe7]),
+        Some(&[0xa3, 0xe8]),
+        Some(&[0xa3, 0xe9]),
+        Some(&[0xa3, 0xea]),
+        Some(&[0xa3, 0xeb]),
+        Some(&[0xa3, 0xec]),
+        Some(&[0xa3, 0xed]),
+        Some(&[0xa3, 0xee]),
+        Some(&[0xa3, 0xef]),
+        Some(&[0xa3, 0xf0]),
+        Some(&[0xa3, 0xf1]),
+        Some(&[0xa3, 0xf2]),
+        Some(&[0xa3, 0xf3]),
+        Some(&[0xa3, 0xf4]),
+        Some(&[0xa3, 0xf5]),
+        Some(&[0xa3, 0xf6]),
+        Some(&[0xa3, 0xf7]),
+        Some(&[0xa3, 0xf8]),
+        Some(&[0xa3, 0xf9]),
+        Some(&[0xa3, 0xfa]),
+        Some(&[0xa3, 0xfb]),
+        Some(&[0xa3, 0xfc]),
+        Some(&[0xa3, 0xfd]),
+        None,
+        None,
+    ];
+
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderMacChineseSimplified<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                match *code_point {
+                    0x0000..=0x007f => Some(Ok(vec![*code_point as u8])),
+                    0x00a0..0x00b8 => {
+                        match Self::BASE_0X00A0[(*code_point as u32 - 0x00a0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x00d0..0x0108 => {
+                        match Self::BASE_0X00D0[(*code_point as u32 - 0x00d0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0110..0x0130 => {
+                        match Self::BASE_0X0110[(*code_point as u32 - 0x0110) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0140..0x0150 => {
+                        match Self::BASE_0X0140[(*code_point as u32 - 0x0140) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x01c8..0x01e0 => {
+                        match Self::BASE_0X01C8[(*code_point as u32 - 0x01c8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0390..0x03d0 => {
+                        match Self::BASE_0X0390[(*code_point as u32 - 0x0390) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0400..0x0458 => {
+                        match Self::BASE_0X0400[(*code_point as u32 - 0x0400) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2010..0x2040 => {
+                        match Self::BASE_0X2010[(*code_point as u32 - 0x2010) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2160..0x2170 => {
+                        match Self::BASE_0X2160[(*code_point as u32 - 0x2160) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2190..0x2198 => {
+                        match Self::BASE_0X2190[(*code_point as u32 - 0x2190) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2208..0x2250 => {
+                        match Self::BASE_0X2208[(*code_point as u32 - 0x2208) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2260..0x2270 => {
+                        match Self::BASE_0X2260[(*code_point as u32 - 0x2260) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2460..0x24a0 => {
+                        match Self::BASE_0X2460[(*code_point as u32 - 0x2460) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2500..0x2550 => {
+                        match Self::BASE_0X2500[(*code_point as u32 - 0x2500) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x25c0..0x25d0 => {
+                        match Self::BASE_0X25C0[(*code_point as u32 - 0x25c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3000..0x3020 => {
+                        match Self::BASE_0X3000[(*code_point as u32 - 0x3000) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3040..0x3130 => {
+                        match Self::BASE_0X3040[(*code_point as u32 - 0x3040) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3220..0x3230 => {
+                        match Self::BASE_0X3220[(*code_point as u32 - 0x3220) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x4e00..0x5090 => {
+                        match Self::BASE_0X4E00[(*code_point as u32 - 0x4e00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x50a0..0x50c0 => {
+                        match Self::BASE_0X50A0[(*code_point as u32 - 0x50a0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x50c8..0x5128 => {
+                        match Self::BASE_0X50C8[(*code_point as u32 - 0x50c8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5138..0x5710 => {
+                        match Self::BASE_0X5138[(*code_point as u32 - 0x5138) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5718..0x5870 => {
+                        match Self::BASE_0X5718[(*code_point as u32 - 0x5718) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5878..0x58b0 => {
+                        match Self::BASE_0X5878[(*code_point as u32 - 0x5878) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x58b8..0x5a80 => {
+                        match Self::BASE_0X58B8[(*code_point as u32 - 0x58b8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5a90..0x5af8 => {
+                        match Self::BASE_0X5A90[(*code_point as u32 - 0x5a90) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5b08..0x5b18 => {
+                        match Self::BASE_0X5B08[(*code_point as u32 - 0x5b08) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5b30..0x5d88 => {
+                        match Self::BASE_0X5B30[(*code_point as u32 - 0x5b30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5db0..0x6130 => {
+                        match Self::BASE_0X5DB0[(*code_point as u32 - 0x5db0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6138..0x6178 => {
+                        match Self::BASE_0X6138[(*code_point as u32 - 0x6138) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6188..0x61d8 => {
+                        match Self::BASE_0X6188[(*code_point as u32 - 0x6188) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x61e0..0x64e8 => {
+                        match Self::BASE_0X61E0[(*code_point as u32 - 0x61e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6500..0x66c0 => {
+                        match Self::BASE_0X6500[(*code_point as u32 - 0x6500) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x66d8..0x6948 => {
+                        match Self::BASE_0X66D8[(*code_point as u32 - 0x66d8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6950..0x6ab0 => {
+                        match Self::BASE_0X6950[(*code_point as u32 - 0x6950) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6b20..0x6ff0 => {
+                        match Self::BASE_0X6B20[(*code_point as u32 - 0x6b20) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7010..0x7028 => {
+                        match Self::BASE_0X7010[(*code_point as u32 - 0x7010) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7048..0x7208 => {
+                        match Self::BASE_0X7048[(*code_point as u32 - 0x7048) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7218..0x73f8 => {
+                        match Self::BASE_0X7218[(*code_point as u32 - 0x7218) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7400..0x7448 => {
+                        match Self::BASE_0X7400[(*code_point as u32 - 0x7400) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7450..0x74b0 => {
+                        match Self::BASE_0X7450[(*code_point as u32 - 0x7450) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x74d0..0x7a58 => {
+                        match Self::BASE_0X74D0[(*code_point as u32 - 0x74d0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7a70..0x7c50 => {
+                        match Self::BASE_0X7A70[(*code_point as u32 - 0x7a70) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7c70..0x7d10 => {
+                        match Self::BASE_0X7C70[(*code_point as u32 - 0x7c70) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7d20..0x7d30 => {
+                        match Self::BASE_0X7D20[(*code_point as u32 - 0x7d20) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7e38..0x7e48 => {
+                        match Self::BASE_0X7E38[(*code_point as u32 - 0x7e38) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7e98..0x81d0 => {
+                        match Self::BASE_0X7E98[(*code_point as u32 - 0x7e98) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x81e0..0x85f0 => {
+                        match Self::BASE_0X81E0[(*code_point as u32 - 0x81e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x85f8..0x8618 => {
+                        match Self::BASE_0X85F8[(*code_point as u32 - 0x85f8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8620..0x8640 => {
+                        match Self::BASE_0X8620[(*code_point as u32 - 0x8620) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8648..0x8948 => {
+                        match Self::BASE_0X8648[(*code_point as u32 - 0x8648) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8958..0x8968 => {
+                        match Self::BASE_0X8958[(*code_point as u32 - 0x8958) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8978..0x8988 => {
+                        match Self::BASE_0X8978[(*code_point as u32 - 0x8978) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x89c0..0x8a08 => {
+                        match Self::BASE_0X89C0[(*code_point as u32 - 0x89c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8a78..0x8a98 => {
+                        match Self::BASE_0X8A78[(*code_point as u32 - 0x8a78) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8ba0..0x8ca0 => {
+                        match Self::BASE_0X8BA0[(*code_point as u32 - 0x8ba0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8d18..0x8ec0 => {
+                        match Self::BASE_0X8D18[(*code_point as u32 - 0x8d18) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8f60..0x9108 => {
+                        match Self::BASE_0X8F60[(*code_point as u32 - 0x8f60) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9118..0x91e0 => {
+                        match Self::BASE_0X9118[(*code_point as u32 - 0x9118) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9480..0x9580 => {
+                        match Self::BASE_0X9480[(*code_point as u32 - 0x9480) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x95e8..0x9740 => {
+                        match Self::BASE_0X95E8[(*code_point as u32 - 0x95e8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9750..0x97b8 => {
+                        match Self::BASE_0X9750[(*code_point as u32 - 0x9750) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x97e0..0x97f8 => {
+                        match Self::BASE_0X97E0[(*code_point as u32 - 0x97e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9870..0x98a8 => {
+                        match Self::BASE_0X9870[(*code_point as u32 - 0x9870) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x98c8..0x98f0 => {
+                        match Self::BASE_0X98C8[(*code_point as u32 - 0x98c8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9950..0x99b0 => {
+                        match Self::BASE_0X9950[(*code_point as u32 - 0x9950) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9a68..0x9b58 => {
+                        match Self::BASE_0X9A68[(*code_point as u32 - 0x9a68) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9c78..0x9ce8 => {
+                        match Self::BASE_0X9C78[(*code_point as u32 - 0x9c78) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9e18..0x9f58 => {
+                        match Self::BASE_0X9E18[(*code_point as u32 - 0x9e18) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9f78..0x9fa8 => {
+                        match Self::BASE_0X9F78[(*code_point as u32 - 0x9f78) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xfe30..0xfe48 => {
+                        match Self::BASE_0XFE30[(*code_point as u32 - 0xfe30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xff00..0xff60 => {
+                        match Self::BASE_0XFF00[(*code_point as u32 - 0xff00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x016b => Some(Ok(vec![0xa8, 0xb1])),
+                    0x01f9 => Some(Ok(vec![0xa8, 0xbf])),
+                    0x0251 => Some(Ok(vec![0xa8, 0xbb])),
+                    0x0261 => Some(Ok(vec![0xa8, 0xc0])),
+                    0x02c7 => Some(Ok(vec![0xa1, 0xa6])),
+                    0x02c9 => Some(Ok(vec![0xa1, 0xa5])),
+                    0x1e3f => Some(Ok(vec![0xa8, 0xbc])),
+                    0x2103 => Some(Ok(vec![0xa1, 0xe6])),
+                    0x2116 => Some(Ok(vec![0xa1, 0xed])),
+                    0x2122 => Some(Ok(vec![0xfe])),
+                    0x2299 => Some(Ok(vec![0xa1, 0xd1])),
+                    0x22a5 => Some(Ok(vec![0xa1, 0xcd])),
+                    0x22ef => Some(Ok(vec![0xa1, 0xad])),
+                    0x2312 => Some(Ok(vec![0xa1, 0xd0])),
+                    0x25a0 => Some(Ok(vec![0xa1, 0xf6])),
+                    0x25a1 => Some(Ok(vec![0xa1, 0xf5])),
+                    0x25b2 => Some(Ok(vec![0xa1, 0xf8])),
+                    0x25b3 => Some(Ok(vec![0xa1, 0xf7])),
+                    0x2605 => Some(Ok(vec![0xa1, 0xef])),
+                    0x2606 => Some(Ok(vec![0xa1, 0xee])),
+                    0x2640 => Some(Ok(vec![0xa1, 0xe2])),
+                    0x2642 => Some(Ok(vec![0xa1, 0xe1])),
+                    0x5d99 => Some(Ok(vec![0xe1, 0xd7])),
+                    0x5d9d => Some(Ok(vec![0xe1, 0xd8])),
+                    0x7035 => Some(Ok(vec![0xe5, 0xaf])),
+                    0x7039 => Some(Ok(vec![0xe5, 0xae])),
+                    0x74ba => Some(Ok(vec![0xe8, 0xb7])),
+                    0x7d6e => Some(Ok(vec![0xd0, 0xf5])),
+                    0x7d77 => Some(Ok(vec![0xf4, 0xea])),
+                    0x7da6 => Some(Ok(vec![0xf4, 0xeb])),
+                    0x7dae => Some(Ok(vec![0xf4, 0xec])),
+                    0x7e82 => Some(Ok(vec![0xd7, 0xeb])),
+                    0x8a3e => Some(Ok(vec![0xf6, 0xa4])),
+                    0x8a48 => Some(Ok(vec![0xee, 0xba])),
+                    0x8b07 => Some(Ok(vec![0xe5, 0xc0])),
+                    0x8b26 => Some(Ok(vec![0xf6, 0xa5])),
+                    0x8b66 => Some(Ok(vec![0xbe, 0xaf])),
+                    0x8b6c => Some(Ok(vec![0xc6, 0xa9])),
+                    0x8ece => Some(Ok(vec![0xea, 0xa6])),
+                    0x9274 => Some(Ok(vec![0xbc, 0xf8])),
+                    0x928e => Some(Ok(vec![0xf6, 0xc6])),
+                    0x92ae => Some(Ok(vec![0xf6, 0xc7])),
+                    0x92c8 => Some(Ok(vec![0xf6, 0xc8])),
+                    0x933e => Some(Ok(vec![0xf6, 0xc9])),
+                    0x936a => Some(Ok(vec![0xf6, 0xca])),
+                    0x938f => Some(Ok(vec![0xf6, 0xcc])),
+                    0x93ca => Some(Ok(vec![0xf6, 0xcb])),
+                    0x93d6 => Some(Ok(vec![0xf7, 0xe9])),
+                    0x943e => Some(Ok(vec![0xf6, 0xcd])),
+                    0x946b => Some(Ok(vec![0xf6, 0xce])),
+                    0x990d => Some(Ok(vec![0xf7, 0xd0])),
+                    0x9910 => Some(Ok(vec![0xb2, 0xcd])),
+                    0x992e => Some(Ok(vec![0xf7, 0xd1])),
+                    0xf880 => Some(Ok(vec![0x81])),
+                    0xf881 => Some(Ok(vec![0x82])),
+                    _ => {
+                        return Some(Err(keramics_core::error_trace_new!(format!(
+                            "Unable to encode code point: U+{:04x} as MacChineseSimplified",
+                            *code_point as u32
+                        ))));
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 8] = [b'K', b'e', b'r', b'a', b'm', b'i', b'c', b's'];
+
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_unsupported_bytes() {
+        let byte_string: [u8; 2] = [0xa1, 0x3f];
+
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xa2, 0x3f];
+
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xa3, 0x3f];
+
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 1] = [0xa1];
+
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encode() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 8] = [0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73];
+
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![b'K'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'e'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'r'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'a'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'm'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'i'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'c'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b's'])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0x00a1];
+
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x00d0];
+
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x0110];
+
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,658 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! MacHebrew encoding.
+//!
+//! Provides support for encoding and decoding MacHebrew.
+
+use keramics_core::ErrorTrace;
+
+/// MacHebrew decoder.
+pub struct DecoderMacHebrew<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+
+    /// Remaining code points of a byte sequence that maps to multiple code points.
+    remaining_code_points: &'static [u16],
+}
+
+impl<'a> DecoderMacHebrew<'a> {
+    const BASE_0X80: [Option<u16>; 128] = [
+        Some(0x00c4),
+        None,
+        Some(0x00c7),
+        Some(0x00c9),
+        Some(0x00d1),
+        Some(0x00d6),
+        Some(0x00dc),
+        Some(0x00e1),
+        Some(0x00e0),
+        Some(0x00e2),
+        Some(0x00e4),
+        Some(0x00e3),
+        Some(0x00e5),
+        Some(0x00e7),
+        Some(0x00e9),
+        Some(0x00e8),
+        Some(0x00ea),
+        Some(0x00eb),
+        Some(0x00ed),
+        Some(0x00ec),
+        Some(0x00ee),
+        Some(0x00ef),
+        Some(0x00f1),
+        Some(0x00f3),
+        Some(0x00f2),
+        Some(0x00f4),
+        Some(0x00f6),
+        Some(0x00f5),
+        Some(0x00fa),
+        Some(0x00f9),
+        Some(0x00fb),
+        Some(0x00fc),
+        Some(0x0020),
+        Some(0x0021),
+        Some(0x0022),
+        Some(0x0023),
+        Some(0x0024),
+        Some(0x0025),
+        Some(0x20aa),
+        Some(0x0027),
+        Some(0x0029),
+        Some(0x0028),
+        Some(0x002a),
+        Some(0x002b),
+        Some(0x002c),
+        Some(0x002d),
+        Some(0x002e),
+        Some(0x002f),
+        Some(0x0030),
+        Some(0x0031),
+        Some(0x0032),
+        Some(0x0033),
+        Some(0x0034),
+        Some(0x0035),
+        Some(0x0036),
+        Some(0x0037),
+        Some(0x0038),
+        Some(0x0039),
+        Some(0x003a),
+        Some(0x003b),
+        Some(0x003c),
+        Some(0x003d),
+        Some(0x003e),
+        Some(0x003f),
+        None,
+        Some(0x201e),
+        Some(0xf89b),
+        Some(0xf89c),
+        Some(0xf89d),
+        Some(0xf89e),
+        Some(0x05bc),
+        Some(0xfb4b),
+        Some(0xfb35),
+        Some(0x2026),
+        Some(0x00a0),
+        Some(0x05b8),
+        Some(0x05b7),
+        Some(0x05b5),
+        Some(0x05b6),
+        Some(0x05b4),
+        Some(0x2013),
+        Some(0x2014),
+        Some(0x201c),
+        Some(0x201d),
+        Some(0x2018),
+        Some(0x2019),
+        Some(0xfb2a),
+        Some(0xfb2b),
+        Some(0x05bf),
+        Some(0x05b0),
+        Some(0x05b2),
+        Some(0x05b1),
+        Some(0x05bb),
+        Some(0x05b9),
+        Some(0x05b8),
+        Some(0x05b3),
+        Some(0x05d0),
+        Some(0x05d1),
+        Some(0x05d2),
+        Some(0x05d3),
+        Some(0x05d4),
+        Some(0x05d5),
+        Some(0x05d6),
+        Some(0x05d7),
+        Some(0x05d8),
+        Some(0x05d9),
+        Some(0x05da),
+        Some(0x05db),
+        Some(0x05dc),
+        Some(0x05dd),
+        Some(0x05de),
+        Some(0x05df),
+        Some(0x05e0),
+        Some(0x05e1),
+        Some(0x05e2),
+        Some(0x05e3),
+        Some(0x05e4),
+        Some(0x05e5),
+        Some(0x05e6),
+        Some(0x05e7),
+        Some(0x05e8),
+        Some(0x05e9),
+        Some(0x05ea),
+        Some(0x007d),
+        Some(0x005d),
+        Some(0x007b),
+        Some(0x005b),
+        Some(0x007c),
+    ];
+
+    /// Retrieves the code points of a byte sequence that maps to multiple code points.
+    fn get_code_point_sequence(value: u8) -> Option<&'static [u16]> {
+        match value {
+            0x81 => Some(&[0x05f2, 0x05b7]),
+            0xc0 => Some(&[0x05dc, 0x05b9]),
+            _ => None,
+        }
+    }
+
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+            remaining_code_points: &[],
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderMacHebrew<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.remaining_code_points.is_empty() {
+            let code_point: u16 = self.remaining_code_points[0];
+            self.remaining_code_points = &self.remaining_code_points[1..];
+
+            return Some(Ok(code_point as u32));
+        }
+        match self.bytes.get(self.byte_index) {
+            Some(byte_value) => {
+                self.byte_index += 1;
+
+                if *byte_value < 0x80 {
+                    Some(Ok(*byte_value as u32))
+                } else {
+                    match Self::BASE_0X80[(*byte_value - 0x80) as usize] {
+                        Some(code_point) => Some(Ok(code_point as u32)),
+                        None => match Self::get_code_point_sequence(*byte_value) {
+                            Some(code_points) => {
+                                self.remaining_code_points = &code_points[1..];
+
+                                Some(Ok(code_points[0] as u32))
+                            }
+                            None => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacHebrew: 0x{:02x} as Unicode",
+                                *byte_value
+                            )))),
+                        },
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+/// MacHebrew encoder.
+pub struct EncoderMacHebrew<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderMacHebrew<'a> {
+    const BASE_0X0020: [Option<&'static [u8]>; 32] = [
+        Some(&[0xa0]),
+        Some(&[0xa1]),
+        Some(&[0xa2]),
+        Some(&[0xa3]),
+        Some(&[0xa4]),
+        Some(&[0xa5]),
+        Some(&[0x26]),
+        Some(&[0xa7]),
+        Some(&[0xa9]),
+        Some(&[0xa8]),
+        Some(&[0xaa]),
+        Some(&[0xab]),
+        Some(&[0xac]),
+        Some(&[0xad]),
+        Some(&[0xae]),
+        Some(&[0xaf]),
+        Some(&[0xb0]),
+        Some(&[0xb1]),
+        Some(&[0xb2]),
+        Some(&[0xb3]),
+        Some(&[0xb4]),
+        Some(&[0xb5]),
+        Some(&[0xb6]),
+        Some(&[0xb7]),
+        Some(&[0xb8]),
+        Some(&[0xb9]),
+        Some(&[0xba]),
+        Some(&[0xbb]),
+        Some(&[0xbc]),
+        Some(&[0xbd]),
+        Some(&[0xbe]),
+        Some(&[0xbf]),
+    ];
+
+    const BASE_0X0078: [Option<&'static [u8]>; 8] = [
+        Some(&[0x78]),
+        Some(&[0x79]),
+        Some(&[0x7a]),
+        Some(&[0xfd]),
+        Some(&[0xff]),
+        Some(&[0xfb]),
+        Some(&[0x7e]),
+        Some(&[0x7f]),
+    ];
+
+    const BASE_0X00C0: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x80]),
+        None,
+        None,
+        Some(&[0x82]),
+        None,
+        Some(&[0x83]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x84]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x85]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86]),
+        None,
+        None,
+        None,
+        Some(&[0x88]),
+        Some(&[0x87]),
+        Some(&[0x89]),
+        Some(&[0x8b]),
+        Some(&[0x8a]),
+        Some(&[0x8c]),
+        None,
+        Some(&[0x8d]),
+        Some(&[0x8f]),
+        Some(&[0x8e]),
+        Some(&[0x90]),
+        Some(&[0x91]),
+        Some(&[0x93]),
+        Some(&[0x92]),
+        Some(&[0x94]),
+        Some(&[0x95]),
+        None,
+        Some(&[0x96]),
+        Some(&[0x98]),
+        Some(&[0x97]),
+        Some(&[0x99]),
+        Some(&[0x9b]),
+        Some(&[0x9a]),
+        None,
+        None,
+        Some(&[0x9d]),
+        Some(&[0x9c]),
+        Some(&[0x9e]),
+        Some(&[0x9f]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X05B0: [Option<&'static [u8]>; 16] = [
+        Some(&[0xd9]),
+        Some(&[0xdb]),
+        Some(&[0xda]),
+        Some(&[0xdf]),
+        Some(&[0xcf]),
+        Some(&[0xcd]),
+        Some(&[0xce]),
+        Some(&[0xcc]),
+        Some(&[0xcb]),
+        Some(&[0xdd]),
+        None,
+        Some(&[0xdc]),
+        Some(&[0xc6]),
+        None,
+        None,
+        Some(&[0xd8]),
+    ];
+
+    const BASE_0X05D0: [Option<&'static [u8]>; 32] = [
+        Some(&[0xe0]),
+        Some(&[0xe1]),
+        Some(&[0xe2]),
+        Some(&[0xe3]),
+        Some(&[0xe4]),
+        Some(&[0xe5]),
+        Some(&[0xe6]),
+        Some(&[0xe7]),
+        Some(&[0xe8]),
+        Some(&[0xe9]),
+        Some(&[0xea]),
+        Some(&[0xeb]),
+        Some(&[0xec]),
+        Some(&[0xed]),
+        Some(&[0xee]),
+        Some(&[0xef]),
+        Some(&[0xf0]),
+        Some(&[0xf1]),
+        Some(&[0xf2]),
+        Some(&[0xf3]),
+        Some(&[0xf4]),
+        Some(&[0xf5]),
+        Some(&[0xf6]),
+        Some(&[0xf7]),
+        Some(&[0xf8]),
+        Some(&[0xf9]),
+        Some(&[0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2010: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        Some(&[0xd0]),
+        Some(&[0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xd4]),
+        Some(&[0xd5]),
+        None,
+        None,
+        Some(&[0xd2]),
+        Some(&[0xd3]),
+        Some(&[0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9]),
+        None,
+    ];
+
+    const BASE_0XF898: [Option<&'static [u8]>; 8] = [
+        None,
+        None,
+        None,
+        Some(&[0xc2]),
+        Some(&[0xc3]),
+        Some(&[0xc4]),
+        Some(&[0xc5]),
+        None,
+    ];
+
+    const BASE_0XFB28: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        Some(&[0xd6]),
+        Some(&[0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc8]),
+        None,
+        None,
+    ];
+
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderMacHebrew<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                match *code_point {
+                    0x0000..=0x001f
+                    | 0x0026
+                    | 0x0040..=0x005a
+                    | 0x005c
+                    | 0x005e..=0x007a
+                    | 0x007e..=0x007f => Some(Ok(vec![*code_point as u8])),
+                    0x0020..0x0040 => {
+                        match Self::BASE_0X0020[(*code_point as u32 - 0x0020) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0078..0x0080 => {
+                        match Self::BASE_0X0078[(*code_point as u32 - 0x0078) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x00c0..0x0100 => {
+                        match Self::BASE_0X00C0[(*code_point as u32 - 0x00c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x05b0..0x05c0 => {
+                        match Self::BASE_0X05B0[(*code_point as u32 - 0x05b0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x05d0..0x05f0 => {
+                        match Self::BASE_0X05D0[(*code_point as u32 - 0x05d0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2010..0x2028 => {
+                        match Self::BASE_0X2010[(*code_point as u32 - 0x2010) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xf898..0xf8a0 => {
+                        match Self::BASE_0XF898[(*code_point as u32 - 0xf898) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xfb28..0xfb38 => {
+                        match Self::BASE_0XFB28[(*code_point as u32 - 0xfb28) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacHebrew",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x005b => Some(Ok(vec![0xfe])),
+                    0x005d => Some(Ok(vec![0xfc])),
+                    0x00a0 => Some(Ok(vec![0xca])),
+                    0x20aa => Some(Ok(vec![0xa6])),
+                    0xfb4b => Some(Ok(vec![0xc7])),
+                    _ => {
+                        return Some(Err(keramics_core::error_trace_new!(format!(
+                            "Unable to encode code point: U+{:04x} as MacHebrew",
+                            *code_point as u32
+                        ))));
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 8] = [b'K', b'e', b'r', b'a', b'm', b'i', b'c', b's'];
+
+        let mut decoder: DecoderMacHebrew = DecoderMacHebrew::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_code_point_sequence() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 1] = [0x81];
+
+        let mut decoder: DecoderMacHebrew = DecoderMacHebrew::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x05f2)));
+        assert_eq!(decoder.next(), Some(Ok(0x05b7)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 8] = [0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73];
+
+        let mut encoder: EncoderMacHebrew = EncoderMacHebrew::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![b'K'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'e'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'r'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'a'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'm'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'i'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'c'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b's'])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0x00c0];
+
+        let mut encoder: EncoderMacHebrew = EncoderMacHebrew::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderMacHebrew = EncoderMacHebrew::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,30425 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! MacJapanese encoding.
+//!
+//! Provides support for encoding and decoding MacJapanese.
+
+use keramics_core::ErrorTrace;
+
+/// MacJapanese decoder.
+pub struct DecoderMacJapanese<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+
+    /// Remaining code points of a byte sequence that maps to multiple code points.
+    remaining_code_points: &'static [u16],
+}
+
+impl<'a> DecoderMacJapanese<'a> {
+    const BASE_0X8140: [Option<u16>; 192] = [
+        Some(0x3000),
+        Some(0x3001),
+        Some(0x3002),
+        Some(0xff0c),
+        Some(0xff0e),
+        Some(0x30fb),
+        Some(0xff1a),
+        Some(0xff1b),
+        Some(0xff1f),
+        Some(0xff01),
+        Some(0x309b),
+        Some(0x309c),
+        Some(0x00b4),
+        Some(0xff40),
+        Some(0x00a8),
+        Some(0xff3e),
+        Some(0xffe3),
+        Some(0xff3f),
+        Some(0x30fd),
+        Some(0x30fe),
+        Some(0x309d),
+        Some(0x309e),
+        Some(0x3003),
+        Some(0x4edd),
+        Some(0x3005),
+        Some(0x3006),
+        Some(0x3007),
+        Some(0x30fc),
+        Some(0x2014),
+        Some(0x2010),
+        Some(0xff0f),
+        Some(0xff3c),
+        Some(0x301c),
+        Some(0x2016),
+        Some(0xff5c),
+        Some(0x2026),
+        Some(0x2025),
+        Some(0x2018),
+        Some(0x2019),
+        Some(0x201c),
+        Some(0x201d),
+        Some(0xff08),
+        Some(0xff09),
+        Some(0x3014),
+        Some(0x3015),
+        Some(0xff3b),
+        Some(0xff3d),
+        Some(0xff5b),
+        Some(0xff5d),
+        Some(0x3008),
+        Some(0x3009),
+        Some(0x300a),
+        Some(0x300b),
+        Some(0x300c),
+        Some(0x300d),
+        Some(0x300e),
+        Some(0x300f),
+        Some(0x3010),
+        Some(0x3011),
+        Some(0xff0b),
+        Some(0x2212),
+        Some(0x00b1),
+        Some(0x00d7),
+        None,
+        Some(0x00f7),
+        Some(0xff1d),
+        Some(0x2260),
+        Some(0xff1c),
+        Some(0xff1e),
+        Some(0x2266),
+        Some(0x2267),
+        Some(0x221e),
+        Some(0x2234),
+        Some(0x2642),
+        Some(0x2640),
+        Some(0x00b0),
+        Some(0x2032),
+        Some(0x2033),
+        Some(0x2103),
+        Some(0xffe5),
+        Some(0xff04),
+        Some(0x00a2),
+        Some(0x00a3),
+        Some(0xff05),
+        Some(0xff03),
+        Some(0xff06),
+        Some(0xff0a),
+        Some(0xff20),
+        Some(0x00a7),
+        Some(0x2606),
+        Some(0x2605),
+        Some(0x25cb),
+        Some(0x25cf),
+        Some(0x25ce),
+        Some(0x25c7),
+        Some(0x25c6),
+        Some(0x25a1),
+        Some(0x25a0),
+        Some(0x25b3),
+        Some(0x25b2),
+        Some(0x25bd),
+        Some(0x25bc),
+        Some(0x203b),
+        Some(0x3012),
+        Some(0x2192),
+        Some(0x2190),
+        Some(0x2191),
+        Some(0x2193),
+        Some(0x3013),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2208),
+        Some(0x220b),
+        Some(0x2286),
+        Some(0x2287),
+        Some(0x2282),
+        Some(0x2283),
+        Some(0x222a),
+        Some(0x2229),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2227),
+        Some(0x2228),
+        Some(0x00ac),
+        Some(0x21d2),
+        Some(0x21d4),
+        Some(0x2200),
+        Some(0x2203),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2220),
+        Some(0x22a5),
+        Some(0x2312),
+        Some(0x2202),
+        Some(0x2207),
+        Some(0x2261),
+        Some(0x2252),
+        Some(0x226a),
+        Some(0x226b),
+        Some(0x221a),
+        Some(0x223d),
+        Some(0x221d),
+        Some(0x2235),
+        Some(0x222b),
+        Some(0x222c),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x212b),
+        Some(0x2030),
+        Some(0x266f),
+        Some(0x266d),
+        Some(0x266a),
+        Some(0x2020),
+        Some(0x2021),
+        Some(0x00b6),
+        None,
+        None,
+        None,
+        None,
+        Some(0x25ef),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8248: [Option<u16>; 170] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xff10),
+        Some(0xff11),
+        Some(0xff12),
+        Some(0xff13),
+        Some(0xff14),
+        Some(0xff15),
+        Some(0xff16),
+        Some(0xff17),
+        Some(0xff18),
+        Some(0xff19),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xff21),
+        Some(0xff22),
+        Some(0xff23),
+        Some(0xff24),
+        Some(0xff25),
+        Some(0xff26),
+        Some(0xff27),
+        Some(0xff28),
+        Some(0xff29),
+        Some(0xff2a),
+        Some(0xff2b),
+        Some(0xff2c),
+        Some(0xff2d),
+        Some(0xff2e),
+        Some(0xff2f),
+        Some(0xff30),
+        Some(0xff31),
+        Some(0xff32),
+        Some(0xff33),
+        Some(0xff34),
+        Some(0xff35),
+        Some(0xff36),
+        Some(0xff37),
+        Some(0xff38),
+        Some(0xff39),
+        Some(0xff3a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xff41),
+        Some(0xff42),
+        Some(0xff43),
+        Some(0xff44),
+        Some(0xff45),
+        Some(0xff46),
+        Some(0xff47),
+        Some(0xff48),
+        Some(0xff49),
+        Some(0xff4a),
+        Some(0xff4b),
+        Some(0xff4c),
+        Some(0xff4d),
+        Some(0xff4e),
+        Some(0xff4f),
+        Some(0xff50),
+        Some(0xff51),
+        Some(0xff52),
+        Some(0xff53),
+        Some(0xff54),
+        Some(0xff55),
+        Some(0xff56),
+        Some(0xff57),
+        Some(0xff58),
+        Some(0xff59),
+        Some(0xff5a),
+        None,
+        None,
+        None,
+        None,
+        Some(0x3041),
+        Some(0x3042),
+        Some(0x3043),
+        Some(0x3044),
+        Some(0x3045),
+        Some(0x3046),
+        Some(0x3047),
+        Some(0x3048),
+        Some(0x3049),
+        Some(0x304a),
+        Some(0x304b),
+        Some(0x304c),
+        Some(0x304d),
+        Some(0x304e),
+        Some(0x304f),
+        Some(0x3050),
+        Some(0x3051),
+        Some(0x3052),
+        Some(0x3053),
+        Some(0x3054),
+        Some(0x3055),
+        Some(0x3056),
+        Some(0x3057),
+        Some(0x3058),
+        Some(0x3059),
+        Some(0x305a),
+        Some(0x305b),
+        Some(0x305c),
+        Some(0x305d),
+        Some(0x305e),
+        Some(0x305f),
+        Some(0x3060),
+        Some(0x3061),
+        Some(0x3062),
+        Some(0x3063),
+        Some(0x3064),
+        Some(0x3065),
+        Some(0x3066),
+        Some(0x3067),
+        Some(0x3068),
+        Some(0x3069),
+        Some(0x306a),
+        Some(0x306b),
+        Some(0x306c),
+        Some(0x306d),
+        Some(0x306e),
+        Some(0x306f),
+        Some(0x3070),
+        Some(0x3071),
+        Some(0x3072),
+        Some(0x3073),
+        Some(0x3074),
+        Some(0x3075),
+        Some(0x3076),
+        Some(0x3077),
+        Some(0x3078),
+        Some(0x3079),
+        Some(0x307a),
+        Some(0x307b),
+        Some(0x307c),
+        Some(0x307d),
+        Some(0x307e),
+        Some(0x307f),
+        Some(0x3080),
+        Some(0x3081),
+        Some(0x3082),
+        Some(0x3083),
+        Some(0x3084),
+        Some(0x3085),
+        Some(0x3086),
+        Some(0x3087),
+        Some(0x3088),
+        Some(0x3089),
+        Some(0x308a),
+        Some(0x308b),
+        Some(0x308c),
+        Some(0x308d),
+        Some(0x308e),
+        Some(0x308f),
+        Some(0x3090),
+        Some(0x3091),
+        Some(0x3092),
+        Some(0x3093),
+    ];
+
+    const BASE_0X8340: [Option<u16>; 151] = [
+        Some(0x30a1),
+        Some(0x30a2),
+        Some(0x30a3),
+        Some(0x30a4),
+        Some(0x30a5),
+        Some(0x30a6),
+        Some(0x30a7),
+        Some(0x30a8),
+        Some(0x30a9),
+        Some(0x30aa),
+        Some(0x30ab),
+        Some(0x30ac),
+        Some(0x30ad),
+        Some(0x30ae),
+        Some(0x30af),
+        Some(0x30b0),
+        Some(0x30b1),
+        Some(0x30b2),
+        Some(0x30b3),
+        Some(0x30b4),
+        Some(0x30b5),
+        Some(0x30b6),
+        Some(0x30b7),
+        Some(0x30b8),
+        Some(0x30b9),
+        Some(0x30ba),
+        Some(0x30bb),
+        Some(0x30bc),
+        Some(0x30bd),
+        Some(0x30be),
+        Some(0x30bf),
+        Some(0x30c0),
+        Some(0x30c1),
+        Some(0x30c2),
+        Some(0x30c3),
+        Some(0x30c4),
+        Some(0x30c5),
+        Some(0x30c6),
+        Some(0x30c7),
+        Some(0x30c8),
+        Some(0x30c9),
+        Some(0x30ca),
+        Some(0x30cb),
+        Some(0x30cc),
+        Some(0x30cd),
+        Some(0x30ce),
+        Some(0x30cf),
+        Some(0x30d0),
+        Some(0x30d1),
+        Some(0x30d2),
+        Some(0x30d3),
+        Some(0x30d4),
+        Some(0x30d5),
+        Some(0x30d6),
+        Some(0x30d7),
+        Some(0x30d8),
+        Some(0x30d9),
+        Some(0x30da),
+        Some(0x30db),
+        Some(0x30dc),
+        Some(0x30dd),
+        Some(0x30de),
+        Some(0x30df),
+        None,
+        Some(0x30e0),
+        Some(0x30e1),
+        Some(0x30e2),
+        Some(0x30e3),
+        Some(0x30e4),
+        Some(0x30e5),
+        Some(0x30e6),
+        Some(0x30e7),
+        Some(0x30e8),
+        Some(0x30e9),
+        Some(0x30ea),
+        Some(0x30eb),
+        Some(0x30ec),
+        Some(0x30ed),
+        Some(0x30ee),
+        Some(0x30ef),
+        Some(0x30f0),
+        Some(0x30f1),
+        Some(0x30f2),
+        Some(0x30f3),
+        Some(0x30f4),
+        Some(0x30f5),
+        Some(0x30f6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x0391),
+        Some(0x0392),
+        Some(0x0393),
+        Some(0x0394),
+        Some(0x0395),
+        Some(0x0396),
+        Some(0x0397),
+        Some(0x0398),
+        Some(0x0399),
+        Some(0x039a),
+        Some(0x039b),
+        Some(0x039c),
+        Some(0x039d),
+        Some(0x039e),
+        Some(0x039f),
+        Some(0x03a0),
+        Some(0x03a1),
+        Some(0x03a3),
+        Some(0x03a4),
+        Some(0x03a5),
+        Some(0x03a6),
+        Some(0x03a7),
+        Some(0x03a8),
+        Some(0x03a9),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x03b1),
+        Some(0x03b2),
+        Some(0x03b3),
+        Some(0x03b4),
+        Some(0x03b5),
+        Some(0x03b6),
+        Some(0x03b7),
+        Some(0x03b8),
+        Some(0x03b9),
+        Some(0x03ba),
+        Some(0x03bb),
+        Some(0x03bc),
+        Some(0x03bd),
+        Some(0x03be),
+        Some(0x03bf),
+        Some(0x03c0),
+        Some(0x03c1),
+        Some(0x03c3),
+        Some(0x03c4),
+        Some(0x03c5),
+        Some(0x03c6),
+        Some(0x03c7),
+        Some(0x03c8),
+        Some(0x03c9),
+    ];
+
+    const BASE_0X8440: [Option<u16>; 127] = [
+        Some(0x0410),
+        Some(0x0411),
+        Some(0x0412),
+        Some(0x0413),
+        Some(0x0414),
+        Some(0x0415),
+        Some(0x0401),
+        Some(0x0416),
+        Some(0x0417),
+        Some(0x0418),
+        Some(0x0419),
+        Some(0x041a),
+        Some(0x041b),
+        Some(0x041c),
+        Some(0x041d),
+        Some(0x041e),
+        Some(0x041f),
+        Some(0x0420),
+        Some(0x0421),
+        Some(0x0422),
+        Some(0x0423),
+        Some(0x0424),
+        Some(0x0425),
+        Some(0x0426),
+        Some(0x0427),
+        Some(0x0428),
+        Some(0x0429),
+        Some(0x042a),
+        Some(0x042b),
+        Some(0x042c),
+        Some(0x042d),
+        Some(0x042e),
+        Some(0x042f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x0430),
+        Some(0x0431),
+        Some(0x0432),
+        Some(0x0433),
+        Some(0x0434),
+        Some(0x0435),
+        Some(0x0451),
+        Some(0x0436),
+        Some(0x0437),
+        Some(0x0438),
+        Some(0x0439),
+        Some(0x043a),
+        Some(0x043b),
+        Some(0x043c),
+        Some(0x043d),
+        None,
+        Some(0x043e),
+        Some(0x043f),
+        Some(0x0440),
+        Some(0x0441),
+        Some(0x0442),
+        Some(0x0443),
+        Some(0x0444),
+        Some(0x0445),
+        Some(0x0446),
+        Some(0x0447),
+        Some(0x0448),
+        Some(0x0449),
+        Some(0x044a),
+        Some(0x044b),
+        Some(0x044c),
+        Some(0x044d),
+        Some(0x044e),
+        Some(0x044f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2500),
+        Some(0x2502),
+        Some(0x250c),
+        Some(0x2510),
+        Some(0x2518),
+        Some(0x2514),
+        Some(0x251c),
+        Some(0x252c),
+        Some(0x2524),
+        Some(0x2534),
+        Some(0x253c),
+        Some(0x2501),
+        Some(0x2503),
+        Some(0x250f),
+        Some(0x2513),
+        Some(0x251b),
+        Some(0x2517),
+        Some(0x2523),
+        Some(0x2533),
+        Some(0x252b),
+        Some(0x253b),
+        Some(0x254b),
+        Some(0x2520),
+        Some(0x252f),
+        Some(0x2528),
+        Some(0x2537),
+        Some(0x253f),
+        Some(0x251d),
+        Some(0x2530),
+        Some(0x2525),
+        Some(0x2538),
+        Some(0x2542),
+    ];
+
+    const BASE_0X8540: [Option<u16>; 181] = [
+        Some(0x2460),
+        Some(0x2461),
+        Some(0x2462),
+        Some(0x2463),
+        Some(0x2464),
+        Some(0x2465),
+        Some(0x2466),
+        Some(0x2467),
+        Some(0x2468),
+        Some(0x2469),
+        Some(0x246a),
+        Some(0x246b),
+        Some(0x246c),
+        Some(0x246d),
+        Some(0x246e),
+        Some(0x246f),
+        Some(0x2470),
+        Some(0x2471),
+        Some(0x2472),
+        Some(0x2473),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2474),
+        Some(0x2475),
+        Some(0x2476),
+        Some(0x2477),
+        Some(0x2478),
+        Some(0x2479),
+        Some(0x247a),
+        Some(0x247b),
+        Some(0x247c),
+        Some(0x247d),
+        Some(0x247e),
+        Some(0x247f),
+        Some(0x2480),
+        Some(0x2481),
+        Some(0x2482),
+        Some(0x2483),
+        Some(0x2484),
+        Some(0x2485),
+        Some(0x2486),
+        Some(0x2487),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2776),
+        Some(0x2777),
+        Some(0x2778),
+        None,
+        Some(0x2779),
+        Some(0x277a),
+        Some(0x277b),
+        Some(0x277c),
+        Some(0x277d),
+        Some(0x277e),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2488),
+        Some(0x2489),
+        Some(0x248a),
+        Some(0x248b),
+        Some(0x248c),
+        Some(0x248d),
+        Some(0x248e),
+        Some(0x248f),
+        Some(0x2490),
+        None,
+        None,
+        None,
+        None,
+        Some(0x2160),
+        Some(0x2161),
+        Some(0x2162),
+        Some(0x2163),
+        Some(0x2164),
+        Some(0x2165),
+        Some(0x2166),
+        Some(0x2167),
+        Some(0x2168),
+        Some(0x2169),
+        Some(0x216a),
+        Some(0x216b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2170),
+        Some(0x2171),
+        Some(0x2172),
+        Some(0x2173),
+        Some(0x2174),
+        Some(0x2175),
+        Some(0x2176),
+        Some(0x2177),
+        Some(0x2178),
+        Some(0x2179),
+        Some(0x217a),
+        Some(0x217b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x249c),
+        Some(0x249d),
+        Some(0x249e),
+        Some(0x249f),
+        Some(0x24a0),
+        Some(0x24a1),
+        Some(0x24a2),
+        Some(0x24a3),
+        Some(0x24a4),
+        Some(0x24a5),
+        Some(0x24a6),
+        Some(0x24a7),
+        Some(0x24a8),
+        Some(0x24a9),
+        Some(0x24aa),
+        Some(0x24ab),
+        Some(0x24ac),
+        Some(0x24ad),
+        Some(0x24ae),
+        Some(0x24af),
+        Some(0x24b0),
+        Some(0x24b1),
+        Some(0x24b2),
+        Some(0x24b3),
+        Some(0x24b4),
+        Some(0x24b5),
+    ];
+
+    const BASE_0X8640: [Option<u16>; 151] = [
+        Some(0x339c),
+        Some(0x339f),
+        Some(0x339d),
+        Some(0x33a0),
+        Some(0x33a4),
+        Some(0xff4d),
+        Some(0x33a1),
+        Some(0x33a5),
+        Some(0x339e),
+        Some(0x33a2),
+        Some(0x338e),
+        Some(0xff47),
+        Some(0x338f),
+        Some(0x33c4),
+        Some(0x3396),
+        Some(0x3397),
+        Some(0x2113),
+        Some(0x3398),
+        Some(0x33b3),
+        Some(0x33b2),
+        Some(0x33b1),
+        Some(0x33b0),
+        Some(0x2109),
+        Some(0x33d4),
+        Some(0x33cb),
+        Some(0x3390),
+        Some(0x3385),
+        Some(0x3386),
+        Some(0x3387),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x2116),
+        Some(0x33cd),
+        Some(0x2121),
+        None,
+        Some(0x2664),
+        Some(0x2667),
+        Some(0x2661),
+        Some(0x2662),
+        Some(0x2660),
+        Some(0x2663),
+        Some(0x2665),
+        Some(0x2666),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3020),
+        Some(0x260e),
+        Some(0x3004),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x261e),
+        Some(0x261c),
+        Some(0x261d),
+        Some(0x261f),
+        Some(0x21c6),
+        Some(0x21c4),
+        Some(0x21c5),
+        None,
+        Some(0x21e8),
+        Some(0x21e6),
+        Some(0x21e7),
+        Some(0x21e9),
+        Some(0x21e8),
+        Some(0x21e6),
+        Some(0x21e7),
+        Some(0x21e9),
+    ];
+
+    const BASE_0X8740: [Option<u16>; 192] = [
+        Some(0x3230),
+        Some(0x322a),
+        Some(0x322b),
+        Some(0x322c),
+        Some(0x322d),
+        Some(0x322e),
+        Some(0x322f),
+        Some(0x3240),
+        Some(0x3237),
+        Some(0x3242),
+        Some(0x3243),
+        Some(0x3239),
+        Some(0x323a),
+        Some(0x3231),
+        Some(0x323e),
+        Some(0x3234),
+        Some(0x3232),
+        Some(0x323b),
+        Some(0x3236),
+        Some(0x3233),
+        Some(0x3235),
+        Some(0x323c),
+        Some(0x323d),
+        Some(0x323f),
+        Some(0x3238),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x32a4),
+        Some(0x32a5),
+        Some(0x32a6),
+        Some(0x32a7),
+        Some(0x32a8),
+        Some(0x32a9),
+        Some(0x3296),
+        Some(0x329d),
+        Some(0x3298),
+        Some(0x329e),
+        None,
+        Some(0x3299),
+        Some(0x3349),
+        Some(0x3322),
+        Some(0x334d),
+        Some(0x3314),
+        Some(0x3316),
+        Some(0x3305),
+        Some(0x3333),
+        Some(0x334e),
+        Some(0x3303),
+        Some(0x3336),
+        Some(0x3318),
+        Some(0x3315),
+        Some(0x3327),
+        Some(0x3351),
+        Some(0x334a),
+        Some(0x3339),
+        Some(0x3357),
+        Some(0x330d),
+        Some(0x3342),
+        Some(0x3323),
+        Some(0x3326),
+        Some(0x333b),
+        Some(0x332b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3300),
+        Some(0x331e),
+        Some(0x332a),
+        Some(0x3331),
+        Some(0x3347),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x337e),
+        Some(0x337d),
+        Some(0x337c),
+        Some(0x337b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x337f),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8840: [Option<u16>; 192] = [
+        Some(0x222e),
+        Some(0x221f),
+        Some(0x22bf),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x301d),
+        Some(0x301f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3094),
+        None,
+        Some(0x30f7),
+        Some(0x30f8),
+        Some(0x30f9),
+        Some(0x30fa),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x4e9c),
+        Some(0x5516),
+        Some(0x5a03),
+        Some(0x963f),
+        Some(0x54c0),
+        Some(0x611b),
+        Some(0x6328),
+        Some(0x59f6),
+        Some(0x9022),
+        Some(0x8475),
+        Some(0x831c),
+        Some(0x7a50),
+        Some(0x60aa),
+        Some(0x63e1),
+        Some(0x6e25),
+        Some(0x65ed),
+        Some(0x8466),
+        Some(0x82a6),
+        Some(0x9bf5),
+        Some(0x6893),
+        Some(0x5727),
+        Some(0x65a1),
+        Some(0x6271),
+        Some(0x5b9b),
+        Some(0x59d0),
+        Some(0x867b),
+        Some(0x98f4),
+        Some(0x7d62),
+        Some(0x7dbe),
+        Some(0x9b8e),
+        Some(0x6216),
+        Some(0x7c9f),
+        Some(0x88b7),
+        Some(0x5b89),
+        Some(0x5eb5),
+        Some(0x6309),
+        Some(0x6697),
+        Some(0x6848),
+        Some(0x95c7),
+        Some(0x978d),
+        Some(0x674f),
+        Some(0x4ee5),
+        Some(0x4f0a),
+        Some(0x4f4d),
+        Some(0x4f9d),
+        Some(0x5049),
+        Some(0x56f2),
+        Some(0x5937),
+        Some(0x59d4),
+        Some(0x5a01),
+        Some(0x5c09),
+        Some(0x60df),
+        Some(0x610f),
+        Some(0x6170),
+        Some(0x6613),
+        Some(0x6905),
+        Some(0x70ba),
+        Some(0x754f),
+        Some(0x7570),
+        Some(0x79fb),
+        Some(0x7dad),
+        Some(0x7def),
+        Some(0x80c3),
+        Some(0x840e),
+        Some(0x8863),
+        Some(0x8b02),
+        Some(0x9055),
+        Some(0x907a),
+        Some(0x533b),
+        Some(0x4e95),
+        Some(0x4ea5),
+        Some(0x57df),
+        Some(0x80b2),
+        Some(0x90c1),
+        Some(0x78ef),
+        Some(0x4e00),
+        Some(0x58f1),
+        Some(0x6ea2),
+        Some(0x9038),
+        Some(0x7a32),
+        Some(0x8328),
+        Some(0x828b),
+        Some(0x9c2f),
+        Some(0x5141),
+        Some(0x5370),
+        Some(0x54bd),
+        Some(0x54e1),
+        Some(0x56e0),
+        Some(0x59fb),
+        Some(0x5f15),
+        Some(0x98f2),
+        Some(0x6deb),
+        Some(0x80e4),
+        Some(0x852d),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8940: [Option<u16>; 192] = [
+        Some(0x9662),
+        Some(0x9670),
+        Some(0x96a0),
+        Some(0x97fb),
+        Some(0x540b),
+        Some(0x53f3),
+        Some(0x5b87),
+        Some(0x70cf),
+        Some(0x7fbd),
+        Some(0x8fc2),
+        Some(0x96e8),
+        Some(0x536f),
+        Some(0x9d5c),
+        Some(0x7aba),
+        Some(0x4e11),
+        Some(0x7893),
+        Some(0x81fc),
+        Some(0x6e26),
+        Some(0x5618),
+        Some(0x5504),
+        Some(0x6b1d),
+        Some(0x851a),
+        Some(0x9c3b),
+        Some(0x59e5),
+        Some(0x53a9),
+        Some(0x6d66),
+        Some(0x74dc),
+        Some(0x958f),
+        Some(0x5642),
+        Some(0x4e91),
+        Some(0x904b),
+        Some(0x96f2),
+        Some(0x834f),
+        Some(0x990c),
+        Some(0x53e1),
+        Some(0x55b6),
+        Some(0x5b30),
+        Some(0x5f71),
+        Some(0x6620),
+        Some(0x66f3),
+        Some(0x6804),
+        Some(0x6c38),
+        Some(0x6cf3),
+        Some(0x6d29),
+        Some(0x745b),
+        Some(0x76c8),
+        Some(0x7a4e),
+        Some(0x9834),
+        Some(0x82f1),
+        Some(0x885b),
+        Some(0x8a60),
+        Some(0x92ed),
+        Some(0x6db2),
+        Some(0x75ab),
+        Some(0x76ca),
+        Some(0x99c5),
+        Some(0x60a6),
+        Some(0x8b01),
+        Some(0x8d8a),
+        Some(0x95b2),
+        Some(0x698e),
+        Some(0x53ad),
+        Some(0x5186),
+        None,
+        Some(0x5712),
+        Some(0x5830),
+        Some(0x5944),
+        Some(0x5bb4),
+        Some(0x5ef6),
+        Some(0x6028),
+        Some(0x63a9),
+        Some(0x63f4),
+        Some(0x6cbf),
+        Some(0x6f14),
+        Some(0x708e),
+        Some(0x7114),
+        Some(0x7159),
+        Some(0x71d5),
+        Some(0x733f),
+        Some(0x7e01),
+        Some(0x8276),
+        Some(0x82d1),
+        Some(0x8597),
+        Some(0x9060),
+        Some(0x925b),
+        Some(0x9d1b),
+        Some(0x5869),
+        Some(0x65bc),
+        Some(0x6c5a),
+        Some(0x7525),
+        Some(0x51f9),
+        Some(0x592e),
+        Some(0x5965),
+        Some(0x5f80),
+        Some(0x5fdc),
+        Some(0x62bc),
+        Some(0x65fa),
+        Some(0x6a2a),
+        Some(0x6b27),
+        Some(0x6bb4),
+        Some(0x738b),
+        Some(0x7fc1),
+        Some(0x8956),
+        Some(0x9d2c),
+        Some(0x9d0e),
+        Some(0x9ec4),
+        Some(0x5ca1),
+        Some(0x6c96),
+        Some(0x837b),
+        Some(0x5104),
+        Some(0x5c4b),
+        Some(0x61b6),
+        Some(0x81c6),
+        Some(0x6876),
+        Some(0x7261),
+        Some(0x4e59),
+        Some(0x4ffa),
+        Some(0x5378),
+        Some(0x6069),
+        Some(0x6e29),
+        Some(0x7a4f),
+        Some(0x97f3),
+        Some(0x4e0b),
+        Some(0x5316),
+        Some(0x4eee),
+        Some(0x4f55),
+        Some(0x4f3d),
+        Some(0x4fa1),
+        Some(0x4f73),
+        Some(0x52a0),
+        Some(0x53ef),
+        Some(0x5609),
+        Some(0x590f),
+        Some(0x5ac1),
+        Some(0x5bb6),
+        Some(0x5be1),
+        Some(0x79d1),
+        Some(0x6687),
+        Some(0x679c),
+        Some(0x67b6),
+        Some(0x6b4c),
+        Some(0x6cb3),
+        Some(0x706b),
+        Some(0x73c2),
+        Some(0x798d),
+        Some(0x79be),
+        Some(0x7a3c),
+        Some(0x7b87),
+        Some(0x82b1),
+        Some(0x82db),
+        Some(0x8304),
+        Some(0x8377),
+        Some(0x83ef),
+        Some(0x83d3),
+        Some(0x8766),
+        Some(0x8ab2),
+        Some(0x5629),
+        Some(0x8ca8),
+        Some(0x8fe6),
+        Some(0x904e),
+        Some(0x971e),
+        Some(0x868a),
+        Some(0x4fc4),
+        Some(0x5ce8),
+        Some(0x6211),
+        Some(0x7259),
+        Some(0x753b),
+        Some(0x81e5),
+        Some(0x82bd),
+        Some(0x86fe),
+        Some(0x8cc0),
+        Some(0x96c5),
+        Some(0x9913),
+        Some(0x99d5),
+        Some(0x4ecb),
+        Some(0x4f1a),
+        Some(0x89e3),
+        Some(0x56de),
+        Some(0x584a),
+        Some(0x58ca),
+        Some(0x5efb),
+        Some(0x5feb),
+        Some(0x602a),
+        Some(0x6094),
+        Some(0x6062),
+        Some(0x61d0),
+        Some(0x6212),
+        Some(0x62d0),
+        Some(0x6539),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8A40: [Option<u16>; 192] = [
+        Some(0x9b41),
+        Some(0x6666),
+        Some(0x68b0),
+        Some(0x6d77),
+        Some(0x7070),
+        Some(0x754c),
+        Some(0x7686),
+        Some(0x7d75),
+        Some(0x82a5),
+        Some(0x87f9),
+        Some(0x958b),
+        Some(0x968e),
+        Some(0x8c9d),
+        Some(0x51f1),
+        Some(0x52be),
+        Some(0x5916),
+        Some(0x54b3),
+        Some(0x5bb3),
+        Some(0x5d16),
+        Some(0x6168),
+        Some(0x6982),
+        Some(0x6daf),
+        Some(0x788d),
+        Some(0x84cb),
+        Some(0x8857),
+        Some(0x8a72),
+        Some(0x93a7),
+        Some(0x9ab8),
+        Some(0x6d6c),
+        Some(0x99a8),
+        Some(0x86d9),
+        Some(0x57a3),
+        Some(0x67ff),
+        Some(0x86ce),
+        Some(0x920e),
+        Some(0x5283),
+        Some(0x5687),
+        Some(0x5404),
+        Some(0x5ed3),
+        Some(0x62e1),
+        Some(0x64b9),
+        Some(0x683c),
+        Some(0x6838),
+        Some(0x6bbb),
+        Some(0x7372),
+        Some(0x78ba),
+        Some(0x7a6b),
+        Some(0x899a),
+        Some(0x89d2),
+        Some(0x8d6b),
+        Some(0x8f03),
+        Some(0x90ed),
+        Some(0x95a3),
+        Some(0x9694),
+        Some(0x9769),
+        Some(0x5b66),
+        Some(0x5cb3),
+        Some(0x697d),
+        Some(0x984d),
+        Some(0x984e),
+        Some(0x639b),
+        Some(0x7b20),
+        Some(0x6a2b),
+        None,
+        Some(0x6a7f),
+        Some(0x68b6),
+        Some(0x9c0d),
+        Some(0x6f5f),
+        Some(0x5272),
+        Some(0x559d),
+        Some(0x6070),
+        Some(0x62ec),
+        Some(0x6d3b),
+        Some(0x6e07),
+        Some(0x6ed1),
+        Some(0x845b),
+        Some(0x8910),
+        Some(0x8f44),
+        Some(0x4e14),
+        Some(0x9c39),
+        Some(0x53f6),
+        Some(0x691b),
+        Some(0x6a3a),
+        Some(0x9784),
+        Some(0x682a),
+        Some(0x515c),
+        Some(0x7ac3),
+        Some(0x84b2),
+        Some(0x91dc),
+        Some(0x938c),
+        Some(0x565b),
+        Some(0x9d28),
+        Some(0x6822),
+        Some(0x8305),
+        Some(0x8431),
+        Some(0x7ca5),
+        Some(0x5208),
+        Some(0x82c5),
+        Some(0x74e6),
+        Some(0x4e7e),
+        Some(0x4f83),
+        Some(0x51a0),
+        Some(0x5bd2),
+        Some(0x520a),
+        Some(0x52d8),
+        Some(0x52e7),
+        Some(0x5dfb),
+        Some(0x559a),
+        Some(0x582a),
+        Some(0x59e6),
+        Some(0x5b8c),
+        Some(0x5b98),
+        Some(0x5bdb),
+        Some(0x5e72),
+        Some(0x5e79),
+        Some(0x60a3),
+        Some(0x611f),
+        Some(0x6163),
+        Some(0x61be),
+        Some(0x63db),
+        Some(0x6562),
+        Some(0x67d1),
+        Some(0x6853),
+        Some(0x68fa),
+        Some(0x6b3e),
+        Some(0x6b53),
+        Some(0x6c57),
+        Some(0x6f22),
+        Some(0x6f97),
+        Some(0x6f45),
+        Some(0x74b0),
+        Some(0x7518),
+        Some(0x76e3),
+        Some(0x770b),
+        Some(0x7aff),
+        Some(0x7ba1),
+        Some(0x7c21),
+        Some(0x7de9),
+        Some(0x7f36),
+        Some(0x7ff0),
+        Some(0x809d),
+        Some(0x8266),
+        Some(0x839e),
+        Some(0x89b3),
+        Some(0x8acc),
+        Some(0x8cab),
+        Some(0x9084),
+        Some(0x9451),
+        Some(0x9593),
+        Some(0x9591),
+        Some(0x95a2),
+        Some(0x9665),
+        Some(0x97d3),
+        Some(0x9928),
+        Some(0x8218),
+        Some(0x4e38),
+        Some(0x542b),
+        Some(0x5cb8),
+        Some(0x5dcc),
+        Some(0x73a9),
+        Some(0x764c),
+        Some(0x773c),
+        Some(0x5ca9),
+        Some(0x7feb),
+        Some(0x8d0b),
+        Some(0x96c1),
+        Some(0x9811),
+        Some(0x9854),
+        Some(0x9858),
+        Some(0x4f01),
+        Some(0x4f0e),
+        Some(0x5371),
+        Some(0x559c),
+        Some(0x5668),
+        Some(0x57fa),
+        Some(0x5947),
+        Some(0x5b09),
+        Some(0x5bc4),
+        Some(0x5c90),
+        Some(0x5e0c),
+        Some(0x5e7e),
+        Some(0x5fcc),
+        Some(0x63ee),
+        Some(0x673a),
+        Some(0x65d7),
+        Some(0x65e2),
+        Some(0x671f),
+        Some(0x68cb),
+        Some(0x68c4),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8B40: [Option<u16>; 192] = [
+        Some(0x6a5f),
+        Some(0x5e30),
+        Some(0x6bc5),
+        Some(0x6c17),
+        Some(0x6c7d),
+        Some(0x757f),
+        Some(0x7948),
+        Some(0x5b63),
+        Some(0x7a00),
+        Some(0x7d00),
+        Some(0x5fbd),
+        Some(0x898f),
+        Some(0x8a18),
+        Some(0x8cb4),
+        Some(0x8d77),
+        Some(0x8ecc),
+        Some(0x8f1d),
+        Some(0x98e2),
+        Some(0x9a0e),
+        Some(0x9b3c),
+        Some(0x4e80),
+        Some(0x507d),
+        Some(0x5100),
+        Some(0x5993),
+        Some(0x5b9c),
+        Some(0x622f),
+        Some(0x6280),
+        Some(0x64ec),
+        Some(0x6b3a),
+        Some(0x72a0),
+        Some(0x7591),
+        Some(0x7947),
+        Some(0x7fa9),
+        Some(0x87fb),
+        Some(0x8abc),
+        Some(0x8b70),
+        Some(0x63ac),
+        Some(0x83ca),
+        Some(0x97a0),
+        Some(0x5409),
+        Some(0x5403),
+        Some(0x55ab),
+        Some(0x6854),
+        Some(0x6a58),
+        Some(0x8a70),
+        Some(0x7827),
+        Some(0x6775),
+        Some(0x9ecd),
+        Some(0x5374),
+        Some(0x5ba2),
+        Some(0x811a),
+        Some(0x8650),
+        Some(0x9006),
+        Some(0x4e18),
+        Some(0x4e45),
+        Some(0x4ec7),
+        Some(0x4f11),
+        Some(0x53ca),
+        Some(0x5438),
+        Some(0x5bae),
+        Some(0x5f13),
+        Some(0x6025),
+        Some(0x6551),
+        None,
+        Some(0x673d),
+        Some(0x6c42),
+        Some(0x6c72),
+        Some(0x6ce3),
+        Some(0x7078),
+        Some(0x7403),
+        Some(0x7a76),
+        Some(0x7aae),
+        Some(0x7b08),
+        Some(0x7d1a),
+        Some(0x7cfe),
+        Some(0x7d66),
+        Some(0x65e7),
+        Some(0x725b),
+        Some(0x53bb),
+        Some(0x5c45),
+        Some(0x5de8),
+        Some(0x62d2),
+        Some(0x62e0),
+        Some(0x6319),
+        Some(0x6e20),
+        Some(0x865a),
+        Some(0x8a31),
+        Some(0x8ddd),
+        Some(0x92f8),
+        Some(0x6f01),
+        Some(0x79a6),
+        Some(0x9b5a),
+        Some(0x4ea8),
+        Some(0x4eab),
+        Some(0x4eac),
+        Some(0x4f9b),
+        Some(0x4fa0),
+        Some(0x50d1),
+        Some(0x5147),
+        Some(0x7af6),
+        Some(0x5171),
+        Some(0x51f6),
+        Some(0x5354),
+        Some(0x5321),
+        Some(0x537f),
+        Some(0x53eb),
+        Some(0x55ac),
+        Some(0x5883),
+        Some(0x5ce1),
+        Some(0x5f37),
+        Some(0x5f4a),
+        Some(0x602f),
+        Some(0x6050),
+        Some(0x606d),
+        Some(0x631f),
+        Some(0x6559),
+        Some(0x6a4b),
+        Some(0x6cc1),
+        Some(0x72c2),
+        Some(0x72ed),
+        Some(0x77ef),
+        Some(0x80f8),
+        Some(0x8105),
+        Some(0x8208),
+        Some(0x854e),
+        Some(0x90f7),
+        Some(0x93e1),
+        Some(0x97ff),
+        Some(0x9957),
+        Some(0x9a5a),
+        Some(0x4ef0),
+        Some(0x51dd),
+        Some(0x5c2d),
+        Some(0x6681),
+        Some(0x696d),
+        Some(0x5c40),
+        Some(0x66f2),
+        Some(0x6975),
+        Some(0x7389),
+        Some(0x6850),
+        Some(0x7c81),
+        Some(0x50c5),
+        Some(0x52e4),
+        Some(0x5747),
+        Some(0x5dfe),
+        Some(0x9326),
+        Some(0x65a4),
+        Some(0x6b23),
+        Some(0x6b3d),
+        Some(0x7434),
+        Some(0x7981),
+        Some(0x79bd),
+        Some(0x7b4b),
+        Some(0x7dca),
+        Some(0x82b9),
+        Some(0x83cc),
+        Some(0x887f),
+        Some(0x895f),
+        Some(0x8b39),
+        Some(0x8fd1),
+        Some(0x91d1),
+        Some(0x541f),
+        Some(0x9280),
+        Some(0x4e5d),
+        Some(0x5036),
+        Some(0x53e5),
+        Some(0x533a),
+        Some(0x72d7),
+        Some(0x7396),
+        Some(0x77e9),
+        Some(0x82e6),
+        Some(0x8eaf),
+        Some(0x99c6),
+        Some(0x99c8),
+        Some(0x99d2),
+        Some(0x5177),
+        Some(0x611a),
+        Some(0x865e),
+        Some(0x55b0),
+        Some(0x7a7a),
+        Some(0x5076),
+        Some(0x5bd3),
+        Some(0x9047),
+        Some(0x9685),
+        Some(0x4e32),
+        Some(0x6adb),
+        Some(0x91e7),
+        Some(0x5c51),
+        Some(0x5c48),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8C40: [Option<u16>; 192] = [
+        Some(0x6398),
+        Some(0x7a9f),
+        Some(0x6c93),
+        Some(0x9774),
+        Some(0x8f61),
+        Some(0x7aaa),
+        Some(0x718a),
+        Some(0x9688),
+        Some(0x7c82),
+        Some(0x6817),
+        Some(0x7e70),
+        Some(0x6851),
+        Some(0x936c),
+        Some(0x52f2),
+        Some(0x541b),
+        Some(0x85ab),
+        Some(0x8a13),
+        Some(0x7fa4),
+        Some(0x8ecd),
+        Some(0x90e1),
+        Some(0x5366),
+        Some(0x8888),
+        Some(0x7941),
+        Some(0x4fc2),
+        Some(0x50be),
+        Some(0x5211),
+        Some(0x5144),
+        Some(0x5553),
+        Some(0x572d),
+        Some(0x73ea),
+        Some(0x578b),
+        Some(0x5951),
+        Some(0x5f62),
+        Some(0x5f84),
+        Some(0x6075),
+        Some(0x6176),
+        Some(0x6167),
+        Some(0x61a9),
+        Some(0x63b2),
+        Some(0x643a),
+        Some(0x656c),
+        Some(0x666f),
+        Some(0x6842),
+        Some(0x6e13),
+        Some(0x7566),
+        Some(0x7a3d),
+        Some(0x7cfb),
+        Some(0x7d4c),
+        Some(0x7d99),
+        Some(0x7e4b),
+        Some(0x7f6b),
+        Some(0x830e),
+        Some(0x834a),
+        Some(0x86cd),
+        Some(0x8a08),
+        Some(0x8a63),
+        Some(0x8b66),
+        Some(0x8efd),
+        Some(0x981a),
+        Some(0x9d8f),
+        Some(0x82b8),
+        Some(0x8fce),
+        Some(0x9be8),
+        None,
+        Some(0x5287),
+        Some(0x621f),
+        Some(0x6483),
+        Some(0x6fc0),
+        Some(0x9699),
+        Some(0x6841),
+        Some(0x5091),
+        Some(0x6b20),
+        Some(0x6c7a),
+        Some(0x6f54),
+        Some(0x7a74),
+        Some(0x7d50),
+        Some(0x8840),
+        Some(0x8a23),
+        Some(0x6708),
+        Some(0x4ef6),
+        Some(0x5039),
+        Some(0x5026),
+        Some(0x5065),
+        Some(0x517c),
+        Some(0x5238),
+        Some(0x5263),
+        Some(0x55a7),
+        Some(0x570f),
+        Some(0x5805),
+        Some(0x5acc),
+        Some(0x5efa),
+        Some(0x61b2),
+        Some(0x61f8),
+        Some(0x62f3),
+        Some(0x6372),
+        Some(0x691c),
+        Some(0x6a29),
+        Some(0x727d),
+        Some(0x72ac),
+        Some(0x732e),
+        Some(0x7814),
+        Some(0x786f),
+        Some(0x7d79),
+        Some(0x770c),
+        Some(0x80a9),
+        Some(0x898b),
+        Some(0x8b19),
+        Some(0x8ce2),
+        Some(0x8ed2),
+        Some(0x9063),
+        Some(0x9375),
+        Some(0x967a),
+        Some(0x9855),
+        Some(0x9a13),
+        Some(0x9e78),
+        Some(0x5143),
+        Some(0x539f),
+        Some(0x53b3),
+        Some(0x5e7b),
+        Some(0x5f26),
+        Some(0x6e1b),
+        Some(0x6e90),
+        Some(0x7384),
+        Some(0x73fe),
+        Some(0x7d43),
+        Some(0x8237),
+        Some(0x8a00),
+        Some(0x8afa),
+        Some(0x9650),
+        Some(0x4e4e),
+        Some(0x500b),
+        Some(0x53e4),
+        Some(0x547c),
+        Some(0x56fa),
+        Some(0x59d1),
+        Some(0x5b64),
+        Some(0x5df1),
+        Some(0x5eab),
+        Some(0x5f27),
+        Some(0x6238),
+        Some(0x6545),
+        Some(0x67af),
+        Some(0x6e56),
+        Some(0x72d0),
+        Some(0x7cca),
+        Some(0x88b4),
+        Some(0x80a1),
+        Some(0x80e1),
+        Some(0x83f0),
+        Some(0x864e),
+        Some(0x8a87),
+        Some(0x8de8),
+        Some(0x9237),
+        Some(0x96c7),
+        Some(0x9867),
+        Some(0x9f13),
+        Some(0x4e94),
+        Some(0x4e92),
+        Some(0x4f0d),
+        Some(0x5348),
+        Some(0x5449),
+        Some(0x543e),
+        Some(0x5a2f),
+        Some(0x5f8c),
+        Some(0x5fa1),
+        Some(0x609f),
+        Some(0x68a7),
+        Some(0x6a8e),
+        Some(0x745a),
+        Some(0x7881),
+        Some(0x8a9e),
+        Some(0x8aa4),
+        Some(0x8b77),
+        Some(0x9190),
+        Some(0x4e5e),
+        Some(0x9bc9),
+        Some(0x4ea4),
+        Some(0x4f7c),
+        Some(0x4faf),
+        Some(0x5019),
+        Some(0x5016),
+        Some(0x5149),
+        Some(0x516c),
+        Some(0x529f),
+        Some(0x52b9),
+        Some(0x52fe),
+        Some(0x539a),
+        Some(0x53e3),
+        Some(0x5411),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8D40: [Option<u16>; 192] = [
+        Some(0x540e),
+        Some(0x5589),
+        Some(0x5751),
+        Some(0x57a2),
+        Some(0x597d),
+        Some(0x5b54),
+        Some(0x5b5d),
+        Some(0x5b8f),
+        Some(0x5de5),
+        Some(0x5de7),
+        Some(0x5df7),
+        Some(0x5e78),
+        Some(0x5e83),
+        Some(0x5e9a),
+        Some(0x5eb7),
+        Some(0x5f18),
+        Some(0x6052),
+        Some(0x614c),
+        Some(0x6297),
+        Some(0x62d8),
+        Some(0x63a7),
+        Some(0x653b),
+        Some(0x6602),
+        Some(0x6643),
+        Some(0x66f4),
+        Some(0x676d),
+        Some(0x6821),
+        Some(0x6897),
+        Some(0x69cb),
+        Some(0x6c5f),
+        Some(0x6d2a),
+        Some(0x6d69),
+        Some(0x6e2f),
+        Some(0x6e9d),
+        Some(0x7532),
+        Some(0x7687),
+        Some(0x786c),
+        Some(0x7a3f),
+        Some(0x7ce0),
+        Some(0x7d05),
+        Some(0x7d18),
+        Some(0x7d5e),
+        Some(0x7db1),
+        Some(0x8015),
+        Some(0x8003),
+        Some(0x80af),
+        Some(0x80b1),
+        Some(0x8154),
+        Some(0x818f),
+        Some(0x822a),
+        Some(0x8352),
+        Some(0x884c),
+        Some(0x8861),
+        Some(0x8b1b),
+        Some(0x8ca2),
+        Some(0x8cfc),
+        Some(0x90ca),
+        Some(0x9175),
+        Some(0x9271),
+        Some(0x783f),
+        Some(0x92fc),
+        Some(0x95a4),
+        Some(0x964d),
+        None,
+        Some(0x9805),
+        Some(0x9999),
+        Some(0x9ad8),
+        Some(0x9d3b),
+        Some(0x525b),
+        Some(0x52ab),
+        Some(0x53f7),
+        Some(0x5408),
+        Some(0x58d5),
+        Some(0x62f7),
+        Some(0x6fe0),
+        Some(0x8c6a),
+        Some(0x8f5f),
+        Some(0x9eb9),
+        Some(0x514b),
+        Some(0x523b),
+        Some(0x544a),
+        Some(0x56fd),
+        Some(0x7a40),
+        Some(0x9177),
+        Some(0x9d60),
+        Some(0x9ed2),
+        Some(0x7344),
+        Some(0x6f09),
+        Some(0x8170),
+        Some(0x7511),
+        Some(0x5ffd),
+        Some(0x60da),
+        Some(0x9aa8),
+        Some(0x72db),
+        Some(0x8fbc),
+        Some(0x6b64),
+        Some(0x9803),
+        Some(0x4eca),
+        Some(0x56f0),
+        Some(0x5764),
+        Some(0x58be),
+        Some(0x5a5a),
+        Some(0x6068),
+        Some(0x61c7),
+        Some(0x660f),
+        Some(0x6606),
+        Some(0x6839),
+        Some(0x68b1),
+        Some(0x6df7),
+        Some(0x75d5),
+        Some(0x7d3a),
+        Some(0x826e),
+        Some(0x9b42),
+        Some(0x4e9b),
+        Some(0x4f50),
+        Some(0x53c9),
+        Some(0x5506),
+        Some(0x5d6f),
+        Some(0x5de6),
+        Some(0x5dee),
+        Some(0x67fb),
+        Some(0x6c99),
+        Some(0x7473),
+        Some(0x7802),
+        Some(0x8a50),
+        Some(0x9396),
+        Some(0x88df),
+        Some(0x5750),
+        Some(0x5ea7),
+        Some(0x632b),
+        Some(0x50b5),
+        Some(0x50ac),
+        Some(0x518d),
+        Some(0x6700),
+        Some(0x54c9),
+        Some(0x585e),
+        Some(0x59bb),
+        Some(0x5bb0),
+        Some(0x5f69),
+        Some(0x624d),
+        Some(0x63a1),
+        Some(0x683d),
+        Some(0x6b73),
+        Some(0x6e08),
+        Some(0x707d),
+        Some(0x91c7),
+        Some(0x7280),
+        Some(0x7815),
+        Some(0x7826),
+        Some(0x796d),
+        Some(0x658e),
+        Some(0x7d30),
+        Some(0x83dc),
+        Some(0x88c1),
+        Some(0x8f09),
+        Some(0x969b),
+        Some(0x5264),
+        Some(0x5728),
+        Some(0x6750),
+        Some(0x7f6a),
+        Some(0x8ca1),
+        Some(0x51b4),
+        Some(0x5742),
+        Some(0x962a),
+        Some(0x583a),
+        Some(0x698a),
+        Some(0x80b4),
+        Some(0x54b2),
+        Some(0x5d0e),
+        Some(0x57fc),
+        Some(0x7895),
+        Some(0x9dfa),
+        Some(0x4f5c),
+        Some(0x524a),
+        Some(0x548b),
+        Some(0x643e),
+        Some(0x6628),
+        Some(0x6714),
+        Some(0x67f5),
+        Some(0x7a84),
+        Some(0x7b56),
+        Some(0x7d22),
+        Some(0x932f),
+        Some(0x685c),
+        Some(0x9bad),
+        Some(0x7b39),
+        Some(0x5319),
+        Some(0x518a),
+        Some(0x5237),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8E40: [Option<u16>; 192] = [
+        Some(0x5bdf),
+        Some(0x62f6),
+        Some(0x64ae),
+        Some(0x64e6),
+        Some(0x672d),
+        Some(0x6bba),
+        Some(0x85a9),
+        Some(0x96d1),
+        Some(0x7690),
+        Some(0x9bd6),
+        Some(0x634c),
+        Some(0x9306),
+        Some(0x9bab),
+        Some(0x76bf),
+        Some(0x6652),
+        Some(0x4e09),
+        Some(0x5098),
+        Some(0x53c2),
+        Some(0x5c71),
+        Some(0x60e8),
+        Some(0x6492),
+        Some(0x6563),
+        Some(0x685f),
+        Some(0x71e6),
+        Some(0x73ca),
+        Some(0x7523),
+        Some(0x7b97),
+        Some(0x7e82),
+        Some(0x8695),
+        Some(0x8b83),
+        Some(0x8cdb),
+        Some(0x9178),
+        Some(0x9910),
+        Some(0x65ac),
+        Some(0x66ab),
+        Some(0x6b8b),
+        Some(0x4ed5),
+        Some(0x4ed4),
+        Some(0x4f3a),
+        Some(0x4f7f),
+        Some(0x523a),
+        Some(0x53f8),
+        Some(0x53f2),
+        Some(0x55e3),
+        Some(0x56db),
+        Some(0x58eb),
+        Some(0x59cb),
+        Some(0x59c9),
+        Some(0x59ff),
+        Some(0x5b50),
+        Some(0x5c4d),
+        Some(0x5e02),
+        Some(0x5e2b),
+        Some(0x5fd7),
+        Some(0x601d),
+        Some(0x6307),
+        Some(0x652f),
+        Some(0x5b5c),
+        Some(0x65af),
+        Some(0x65bd),
+        Some(0x65e8),
+        Some(0x679d),
+        Some(0x6b62),
+        None,
+        Some(0x6b7b),
+        Some(0x6c0f),
+        Some(0x7345),
+        Some(0x7949),
+        Some(0x79c1),
+        Some(0x7cf8),
+        Some(0x7d19),
+        Some(0x7d2b),
+        Some(0x80a2),
+        Some(0x8102),
+        Some(0x81f3),
+        Some(0x8996),
+        Some(0x8a5e),
+        Some(0x8a69),
+        Some(0x8a66),
+        Some(0x8a8c),
+        Some(0x8aee),
+        Some(0x8cc7),
+        Some(0x8cdc),
+        Some(0x96cc),
+        Some(0x98fc),
+        Some(0x6b6f),
+        Some(0x4e8b),
+        Some(0x4f3c),
+        Some(0x4f8d),
+        Some(0x5150),
+        Some(0x5b57),
+        Some(0x5bfa),
+        Some(0x6148),
+        Some(0x6301),
+        Some(0x6642),
+        Some(0x6b21),
+        Some(0x6ecb),
+        Some(0x6cbb),
+        Some(0x723e),
+        Some(0x74bd),
+        Some(0x75d4),
+        Some(0x78c1),
+        Some(0x793a),
+        Some(0x800c),
+        Some(0x8033),
+        Some(0x81ea),
+        Some(0x8494),
+        Some(0x8f9e),
+        Some(0x6c50),
+        Some(0x9e7f),
+        Some(0x5f0f),
+        Some(0x8b58),
+        Some(0x9d2b),
+        Some(0x7afa),
+        Some(0x8ef8),
+        Some(0x5b8d),
+        Some(0x96eb),
+        Some(0x4e03),
+        Some(0x53f1),
+        Some(0x57f7),
+        Some(0x5931),
+        Some(0x5ac9),
+        Some(0x5ba4),
+        Some(0x6089),
+        Some(0x6e7f),
+        Some(0x6f06),
+        Some(0x75be),
+        Some(0x8cea),
+        Some(0x5b9f),
+        Some(0x8500),
+        Some(0x7be0),
+        Some(0x5072),
+        Some(0x67f4),
+        Some(0x829d),
+        Some(0x5c61),
+        Some(0x854a),
+        Some(0x7e1e),
+        Some(0x820e),
+        Some(0x5199),
+        Some(0x5c04),
+        Some(0x6368),
+        Some(0x8d66),
+        Some(0x659c),
+        Some(0x716e),
+        Some(0x793e),
+        Some(0x7d17),
+        Some(0x8005),
+        Some(0x8b1d),
+        Some(0x8eca),
+        Some(0x906e),
+        Some(0x86c7),
+        Some(0x90aa),
+        Some(0x501f),
+        Some(0x52fa),
+        Some(0x5c3a),
+        Some(0x6753),
+        Some(0x707c),
+        Some(0x7235),
+        Some(0x914c),
+        Some(0x91c8),
+        Some(0x932b),
+        Some(0x82e5),
+        Some(0x5bc2),
+        Some(0x5f31),
+        Some(0x60f9),
+        Some(0x4e3b),
+        Some(0x53d6),
+        Some(0x5b88),
+        Some(0x624b),
+        Some(0x6731),
+        Some(0x6b8a),
+        Some(0x72e9),
+        Some(0x73e0),
+        Some(0x7a2e),
+        Some(0x816b),
+        Some(0x8da3),
+        Some(0x9152),
+        Some(0x9996),
+        Some(0x5112),
+        Some(0x53d7),
+        Some(0x546a),
+        Some(0x5bff),
+        Some(0x6388),
+        Some(0x6a39),
+        Some(0x7dac),
+        Some(0x9700),
+        Some(0x56da),
+        Some(0x53ce),
+        Some(0x5468),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8F40: [Option<u16>; 192] = [
+        Some(0x5b97),
+        Some(0x5c31),
+        Some(0x5dde),
+        Some(0x4fee),
+        Some(0x6101),
+        Some(0x62fe),
+        Some(0x6d32),
+        Some(0x79c0),
+        Some(0x79cb),
+        Some(0x7d42),
+        Some(0x7e4d),
+        Some(0x7fd2),
+        Some(0x81ed),
+        Some(0x821f),
+        Some(0x8490),
+        Some(0x8846),
+        Some(0x8972),
+        Some(0x8b90),
+        Some(0x8e74),
+        Some(0x8f2f),
+        Some(0x9031),
+        Some(0x914b),
+        Some(0x916c),
+        Some(0x96c6),
+        Some(0x919c),
+        Some(0x4ec0),
+        Some(0x4f4f),
+        Some(0x5145),
+        Some(0x5341),
+        Some(0x5f93),
+        Some(0x620e),
+        Some(0x67d4),
+        Some(0x6c41),
+        Some(0x6e0b),
+        Some(0x7363),
+        Some(0x7e26),
+        Some(0x91cd),
+        Some(0x9283),
+        Some(0x53d4),
+        Some(0x5919),
+        Some(0x5bbf),
+        Some(0x6dd1),
+        Some(0x795d),
+        Some(0x7e2e),
+        Some(0x7c9b),
+        Some(0x587e),
+        Some(0x719f),
+        Some(0x51fa),
+        Some(0x8853),
+        Some(0x8ff0),
+        Some(0x4fca),
+        Some(0x5cfb),
+        Some(0x6625),
+        Some(0x77ac),
+        Some(0x7ae3),
+        Some(0x821c),
+        Some(0x99ff),
+        Some(0x51c6),
+        Some(0x5faa),
+        Some(0x65ec),
+        Some(0x696f),
+        Some(0x6b89),
+        Some(0x6df3),
+        None,
+        Some(0x6e96),
+        Some(0x6f64),
+        Some(0x76fe),
+        Some(0x7d14),
+        Some(0x5de1),
+        Some(0x9075),
+        Some(0x9187),
+        Some(0x9806),
+        Some(0x51e6),
+        Some(0x521d),
+        Some(0x6240),
+        Some(0x6691),
+        Some(0x66d9),
+        Some(0x6e1a),
+        Some(0x5eb6),
+        Some(0x7dd2),
+        Some(0x7f72),
+        Some(0x66f8),
+        Some(0x85af),
+        Some(0x85f7),
+        Some(0x8af8),
+        Some(0x52a9),
+        Some(0x53d9),
+        Some(0x5973),
+        Some(0x5e8f),
+        Some(0x5f90),
+        Some(0x6055),
+        Some(0x92e4),
+        Some(0x9664),
+        Some(0x50b7),
+        Some(0x511f),
+        Some(0x52dd),
+        Some(0x5320),
+        Some(0x5347),
+        Some(0x53ec),
+        Some(0x54e8),
+        Some(0x5546),
+        Some(0x5531),
+        Some(0x5617),
+        Some(0x5968),
+        Some(0x59be),
+        Some(0x5a3c),
+        Some(0x5bb5),
+        Some(0x5c06),
+        Some(0x5c0f),
+        Some(0x5c11),
+        Some(0x5c1a),
+        Some(0x5e84),
+        Some(0x5e8a),
+        Some(0x5ee0),
+        Some(0x5f70),
+        Some(0x627f),
+        Some(0x6284),
+        Some(0x62db),
+        Some(0x638c),
+        Some(0x6377),
+        Some(0x6607),
+        Some(0x660c),
+        Some(0x662d),
+        Some(0x6676),
+        Some(0x677e),
+        Some(0x68a2),
+        Some(0x6a1f),
+        Some(0x6a35),
+        Some(0x6cbc),
+        Some(0x6d88),
+        Some(0x6e09),
+        Some(0x6e58),
+        Some(0x713c),
+        Some(0x7126),
+        Some(0x7167),
+        Some(0x75c7),
+        Some(0x7701),
+        Some(0x785d),
+        Some(0x7901),
+        Some(0x7965),
+        Some(0x79f0),
+        Some(0x7ae0),
+        Some(0x7b11),
+        Some(0x7ca7),
+        Some(0x7d39),
+        Some(0x8096),
+        Some(0x83d6),
+        Some(0x848b),
+        Some(0x8549),
+        Some(0x885d),
+        Some(0x88f3),
+        Some(0x8a1f),
+        Some(0x8a3c),
+        Some(0x8a54),
+        Some(0x8a73),
+        Some(0x8c61),
+        Some(0x8cde),
+        Some(0x91a4),
+        Some(0x9266),
+        Some(0x937e),
+        Some(0x9418),
+        Some(0x969c),
+        Some(0x9798),
+        Some(0x4e0a),
+        Some(0x4e08),
+        Some(0x4e1e),
+        Some(0x4e57),
+        Some(0x5197),
+        Some(0x5270),
+        Some(0x57ce),
+        Some(0x5834),
+        Some(0x58cc),
+        Some(0x5b22),
+        Some(0x5e38),
+        Some(0x60c5),
+        Some(0x64fe),
+        Some(0x6761),
+        Some(0x6756),
+        Some(0x6d44),
+        Some(0x72b6),
+        Some(0x7573),
+        Some(0x7a63),
+        Some(0x84b8),
+        Some(0x8b72),
+        Some(0x91b8),
+        Some(0x9320),
+        Some(0x5631),
+        Some(0x57f4),
+        Some(0x98fe),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9040: [Option<u16>; 192] = [
+        Some(0x62ed),
+        Some(0x690d),
+        Some(0x6b96),
+        Some(0x71ed),
+        Some(0x7e54),
+        Some(0x8077),
+        Some(0x8272),
+        Some(0x89e6),
+        Some(0x98df),
+        Some(0x8755),
+        Some(0x8fb1),
+        Some(0x5c3b),
+        Some(0x4f38),
+        Some(0x4fe1),
+        Some(0x4fb5),
+        Some(0x5507),
+        Some(0x5a20),
+        Some(0x5bdd),
+        Some(0x5be9),
+        Some(0x5fc3),
+        Some(0x614e),
+        Some(0x632f),
+        Some(0x65b0),
+        Some(0x664b),
+        Some(0x68ee),
+        Some(0x699b),
+        Some(0x6d78),
+        Some(0x6df1),
+        Some(0x7533),
+        Some(0x75b9),
+        Some(0x771f),
+        Some(0x795e),
+        Some(0x79e6),
+        Some(0x7d33),
+        Some(0x81e3),
+        Some(0x82af),
+        Some(0x85aa),
+        Some(0x89aa),
+        Some(0x8a3a),
+        Some(0x8eab),
+        Some(0x8f9b),
+        Some(0x9032),
+        Some(0x91dd),
+        Some(0x9707),
+        Some(0x4eba),
+        Some(0x4ec1),
+        Some(0x5203),
+        Some(0x5875),
+        Some(0x58ec),
+        Some(0x5c0b),
+        Some(0x751a),
+        Some(0x5c3d),
+        Some(0x814e),
+        Some(0x8a0a),
+        Some(0x8fc5),
+        Some(0x9663),
+        Some(0x976d),
+        Some(0x7b25),
+        Some(0x8acf),
+        Some(0x9808),
+        Some(0x9162),
+        Some(0x56f3),
+        Some(0x53a8),
+        None,
+        Some(0x9017),
+        Some(0x5439),
+        Some(0x5782),
+        Some(0x5e25),
+        Some(0x63a8),
+        Some(0x6c34),
+        Some(0x708a),
+        Some(0x7761),
+        Some(0x7c8b),
+        Some(0x7fe0),
+        Some(0x8870),
+        Some(0x9042),
+        Some(0x9154),
+        Some(0x9310),
+        Some(0x9318),
+        Some(0x968f),
+        Some(0x745e),
+        Some(0x9ac4),
+        Some(0x5d07),
+        Some(0x5d69),
+        Some(0x6570),
+        Some(0x67a2),
+        Some(0x8da8),
+        Some(0x96db),
+        Some(0x636e),
+        Some(0x6749),
+        Some(0x6919),
+        Some(0x83c5),
+        Some(0x9817),
+        Some(0x96c0),
+        Some(0x88fe),
+        Some(0x6f84),
+        Some(0x647a),
+        Some(0x5bf8),
+        Some(0x4e16),
+        Some(0x702c),
+        Some(0x755d),
+        Some(0x662f),
+        Some(0x51c4),
+        Some(0x5236),
+        Some(0x52e2),
+        Some(0x59d3),
+        Some(0x5f81),
+        Some(0x6027),
+        Some(0x6210),
+        Some(0x653f),
+        Some(0x6574),
+        Some(0x661f),
+        Some(0x6674),
+        Some(0x68f2),
+        Some(0x6816),
+        Some(0x6b63),
+        Some(0x6e05),
+        Some(0x7272),
+        Some(0x751f),
+        Some(0x76db),
+        Some(0x7cbe),
+        Some(0x8056),
+        Some(0x58f0),
+        Some(0x88fd),
+        Some(0x897f),
+        Some(0x8aa0),
+        Some(0x8a93),
+        Some(0x8acb),
+        Some(0x901d),
+        Some(0x9192),
+        Some(0x9752),
+        Some(0x9759),
+        Some(0x6589),
+        Some(0x7a0e),
+        Some(0x8106),
+        Some(0x96bb),
+        Some(0x5e2d),
+        Some(0x60dc),
+        Some(0x621a),
+        Some(0x65a5),
+        Some(0x6614),
+        Some(0x6790),
+        Some(0x77f3),
+        Some(0x7a4d),
+        Some(0x7c4d),
+        Some(0x7e3e),
+        Some(0x810a),
+        Some(0x8cac),
+        Some(0x8d64),
+        Some(0x8de1),
+        Some(0x8e5f),
+        Some(0x78a9),
+        Some(0x5207),
+        Some(0x62d9),
+        Some(0x63a5),
+        Some(0x6442),
+        Some(0x6298),
+        Some(0x8a2d),
+        Some(0x7a83),
+        Some(0x7bc0),
+        Some(0x8aac),
+        Some(0x96ea),
+        Some(0x7d76),
+        Some(0x820c),
+        Some(0x8749),
+        Some(0x4ed9),
+        Some(0x5148),
+        Some(0x5343),
+        Some(0x5360),
+        Some(0x5ba3),
+        Some(0x5c02),
+        Some(0x5c16),
+        Some(0x5ddd),
+        Some(0x6226),
+        Some(0x6247),
+        Some(0x64b0),
+        Some(0x6813),
+        Some(0x6834),
+        Some(0x6cc9),
+        Some(0x6d45),
+        Some(0x6d17),
+        Some(0x67d3),
+        Some(0x6f5c),
+        Some(0x714e),
+        Some(0x717d),
+        Some(0x65cb),
+        Some(0x7a7f),
+        Some(0x7bad),
+        Some(0x7dda),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9140: [Option<u16>; 192] = [
+        Some(0x7e4a),
+        Some(0x7fa8),
+        Some(0x817a),
+        Some(0x821b),
+        Some(0x8239),
+        Some(0x85a6),
+        Some(0x8a6e),
+        Some(0x8cce),
+        Some(0x8df5),
+        Some(0x9078),
+        Some(0x9077),
+        Some(0x92ad),
+        Some(0x9291),
+        Some(0x9583),
+        Some(0x9bae),
+        Some(0x524d),
+        Some(0x5584),
+        Some(0x6f38),
+        Some(0x7136),
+        Some(0x5168),
+        Some(0x7985),
+        Some(0x7e55),
+        Some(0x81b3),
+        Some(0x7cce),
+        Some(0x564c),
+        Some(0x5851),
+        Some(0x5ca8),
+        Some(0x63aa),
+        Some(0x66fe),
+        Some(0x66fd),
+        Some(0x695a),
+        Some(0x72d9),
+        Some(0x758f),
+        Some(0x758e),
+        Some(0x790e),
+        Some(0x7956),
+        Some(0x79df),
+        Some(0x7c97),
+        Some(0x7d20),
+        Some(0x7d44),
+        Some(0x8607),
+        Some(0x8a34),
+        Some(0x963b),
+        Some(0x9061),
+        Some(0x9f20),
+        Some(0x50e7),
+        Some(0x5275),
+        Some(0x53cc),
+        Some(0x53e2),
+        Some(0x5009),
+        Some(0x55aa),
+        Some(0x58ee),
+        Some(0x594f),
+        Some(0x723d),
+        Some(0x5b8b),
+        Some(0x5c64),
+        Some(0x531d),
+        Some(0x60e3),
+        Some(0x60f3),
+        Some(0x635c),
+        Some(0x6383),
+        Some(0x633f),
+        Some(0x63bb),
+        None,
+        Some(0x64cd),
+        Some(0x65e9),
+        Some(0x66f9),
+        Some(0x5de3),
+        Some(0x69cd),
+        Some(0x69fd),
+        Some(0x6f15),
+        Some(0x71e5),
+        Some(0x4e89),
+        Some(0x75e9),
+        Some(0x76f8),
+        Some(0x7a93),
+        Some(0x7cdf),
+        Some(0x7dcf),
+        Some(0x7d9c),
+        Some(0x8061),
+        Some(0x8349),
+        Some(0x8358),
+        Some(0x846c),
+        Some(0x84bc),
+        Some(0x85fb),
+        Some(0x88c5),
+        Some(0x8d70),
+        Some(0x9001),
+        Some(0x906d),
+        Some(0x9397),
+        Some(0x971c),
+        Some(0x9a12),
+        Some(0x50cf),
+        Some(0x5897),
+        Some(0x618e),
+        Some(0x81d3),
+        Some(0x8535),
+        Some(0x8d08),
+        Some(0x9020),
+        Some(0x4fc3),
+        Some(0x5074),
+        Some(0x5247),
+        Some(0x5373),
+        Some(0x606f),
+        Some(0x6349),
+        Some(0x675f),
+        Some(0x6e2c),
+        Some(0x8db3),
+        Some(0x901f),
+        Some(0x4fd7),
+        Some(0x5c5e),
+        Some(0x8cca),
+        Some(0x65cf),
+        Some(0x7d9a),
+        Some(0x5352),
+        Some(0x8896),
+        Some(0x5176),
+        Some(0x63c3),
+        Some(0x5b58),
+        Some(0x5b6b),
+        Some(0x5c0a),
+        Some(0x640d),
+        Some(0x6751),
+        Some(0x905c),
+        Some(0x4ed6),
+        Some(0x591a),
+        Some(0x592a),
+        Some(0x6c70),
+        Some(0x8a51),
+        Some(0x553e),
+        Some(0x5815),
+        Some(0x59a5),
+        Some(0x60f0),
+        Some(0x6253),
+        Some(0x67c1),
+        Some(0x8235),
+        Some(0x6955),
+        Some(0x9640),
+        Some(0x99c4),
+        Some(0x9a28),
+        Some(0x4f53),
+        Some(0x5806),
+        Some(0x5bfe),
+        Some(0x8010),
+        Some(0x5cb1),
+        Some(0x5e2f),
+        Some(0x5f85),
+        Some(0x6020),
+        Some(0x614b),
+        Some(0x6234),
+        Some(0x66ff),
+        Some(0x6cf0),
+        Some(0x6ede),
+        Some(0x80ce),
+        Some(0x817f),
+        Some(0x82d4),
+        Some(0x888b),
+        Some(0x8cb8),
+        Some(0x9000),
+        Some(0x902e),
+        Some(0x968a),
+        Some(0x9edb),
+        Some(0x9bdb),
+        Some(0x4ee3),
+        Some(0x53f0),
+        Some(0x5927),
+        Some(0x7b2c),
+        Some(0x918d),
+        Some(0x984c),
+        Some(0x9df9),
+        Some(0x6edd),
+        Some(0x7027),
+        Some(0x5353),
+        Some(0x5544),
+        Some(0x5b85),
+        Some(0x6258),
+        Some(0x629e),
+        Some(0x62d3),
+        Some(0x6ca2),
+        Some(0x6fef),
+        Some(0x7422),
+        Some(0x8a17),
+        Some(0x9438),
+        Some(0x6fc1),
+        Some(0x8afe),
+        Some(0x8338),
+        Some(0x51e7),
+        Some(0x86f8),
+        Some(0x53ea),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9240: [Option<u16>; 192] = [
+        Some(0x53e9),
+        Some(0x4f46),
+        Some(0x9054),
+        Some(0x8fb0),
+        Some(0x596a),
+        Some(0x8131),
+        Some(0x5dfd),
+        Some(0x7aea),
+        Some(0x8fbf),
+        Some(0x68da),
+        Some(0x8c37),
+        Some(0x72f8),
+        Some(0x9c48),
+        Some(0x6a3d),
+        Some(0x8ab0),
+        Some(0x4e39),
+        Some(0x5358),
+        Some(0x5606),
+        Some(0x5766),
+        Some(0x62c5),
+        Some(0x63a2),
+        Some(0x65e6),
+        Some(0x6b4e),
+        Some(0x6de1),
+        Some(0x6e5b),
+        Some(0x70ad),
+        Some(0x77ed),
+        Some(0x7aef),
+        Some(0x7baa),
+        Some(0x7dbb),
+        Some(0x803d),
+        Some(0x80c6),
+        Some(0x86cb),
+        Some(0x8a95),
+        Some(0x935b),
+        Some(0x56e3),
+        Some(0x58c7),
+        Some(0x5f3e),
+        Some(0x65ad),
+        Some(0x6696),
+        Some(0x6a80),
+        Some(0x6bb5),
+        Some(0x7537),
+        Some(0x8ac7),
+        Some(0x5024),
+        Some(0x77e5),
+        Some(0x5730),
+        Some(0x5f1b),
+        Some(0x6065),
+        Some(0x667a),
+        Some(0x6c60),
+        Some(0x75f4),
+        Some(0x7a1a),
+        Some(0x7f6e),
+        Some(0x81f4),
+        Some(0x8718),
+        Some(0x9045),
+        Some(0x99b3),
+        Some(0x7bc9),
+        Some(0x755c),
+        Some(0x7af9),
+        Some(0x7b51),
+        Some(0x84c4),
+        None,
+        Some(0x9010),
+        Some(0x79e9),
+        Some(0x7a92),
+        Some(0x8336),
+        Some(0x5ae1),
+        Some(0x7740),
+        Some(0x4e2d),
+        Some(0x4ef2),
+        Some(0x5b99),
+        Some(0x5fe0),
+        Some(0x62bd),
+        Some(0x663c),
+        Some(0x67f1),
+        Some(0x6ce8),
+        Some(0x866b),
+        Some(0x8877),
+        Some(0x8a3b),
+        Some(0x914e),
+        Some(0x92f3),
+        Some(0x99d0),
+        Some(0x6a17),
+        Some(0x7026),
+        Some(0x732a),
+        Some(0x82e7),
+        Some(0x8457),
+        Some(0x8caf),
+        Some(0x4e01),
+        Some(0x5146),
+        Some(0x51cb),
+        Some(0x558b),
+        Some(0x5bf5),
+        Some(0x5e16),
+        Some(0x5e33),
+        Some(0x5e81),
+        Some(0x5f14),
+        Some(0x5f35),
+        Some(0x5f6b),
+        Some(0x5fb4),
+        Some(0x61f2),
+        Some(0x6311),
+        Some(0x66a2),
+        Some(0x671d),
+        Some(0x6f6e),
+        Some(0x7252),
+        Some(0x753a),
+        Some(0x773a),
+        Some(0x8074),
+        Some(0x8139),
+        Some(0x8178),
+        Some(0x8776),
+        Some(0x8abf),
+        Some(0x8adc),
+        Some(0x8d85),
+        Some(0x8df3),
+        Some(0x929a),
+        Some(0x9577),
+        Some(0x9802),
+        Some(0x9ce5),
+        Some(0x52c5),
+        Some(0x6357),
+        Some(0x76f4),
+        Some(0x6715),
+        Some(0x6c88),
+        Some(0x73cd),
+        Some(0x8cc3),
+        Some(0x93ae),
+        Some(0x9673),
+        Some(0x6d25),
+        Some(0x589c),
+        Some(0x690e),
+        Some(0x69cc),
+        Some(0x8ffd),
+        Some(0x939a),
+        Some(0x75db),
+        Some(0x901a),
+        Some(0x585a),
+        Some(0x6802),
+        Some(0x63b4),
+        Some(0x69fb),
+        Some(0x4f43),
+        Some(0x6f2c),
+        Some(0x67d8),
+        Some(0x8fbb),
+        Some(0x8526),
+        Some(0x7db4),
+        Some(0x9354),
+        Some(0x693f),
+        Some(0x6f70),
+        Some(0x576a),
+        Some(0x58f7),
+        Some(0x5b2c),
+        Some(0x7d2c),
+        Some(0x722a),
+        Some(0x540a),
+        Some(0x91e3),
+        Some(0x9db4),
+        Some(0x4ead),
+        Some(0x4f4e),
+        Some(0x505c),
+        Some(0x5075),
+        Some(0x5243),
+        Some(0x8c9e),
+        Some(0x5448),
+        Some(0x5824),
+        Some(0x5b9a),
+        Some(0x5e1d),
+        Some(0x5e95),
+        Some(0x5ead),
+        Some(0x5ef7),
+        Some(0x5f1f),
+        Some(0x608c),
+        Some(0x62b5),
+        Some(0x633a),
+        Some(0x63d0),
+        Some(0x68af),
+        Some(0x6c40),
+        Some(0x7887),
+        Some(0x798e),
+        Some(0x7a0b),
+        Some(0x7de0),
+        Some(0x8247),
+        Some(0x8a02),
+        Some(0x8ae6),
+        Some(0x8e44),
+        Some(0x9013),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9340: [Option<u16>; 192] = [
+        Some(0x90b8),
+        Some(0x912d),
+        Some(0x91d8),
+        Some(0x9f0e),
+        Some(0x6ce5),
+        Some(0x6458),
+        Some(0x64e2),
+        Some(0x6575),
+        Some(0x6ef4),
+        Some(0x7684),
+        Some(0x7b1b),
+        Some(0x9069),
+        Some(0x93d1),
+        Some(0x6eba),
+        Some(0x54f2),
+        Some(0x5fb9),
+        Some(0x64a4),
+        Some(0x8f4d),
+        Some(0x8fed),
+        Some(0x9244),
+        Some(0x5178),
+        Some(0x586b),
+        Some(0x5929),
+        Some(0x5c55),
+        Some(0x5e97),
+        Some(0x6dfb),
+        Some(0x7e8f),
+        Some(0x751c),
+        Some(0x8cbc),
+        Some(0x8ee2),
+        Some(0x985b),
+        Some(0x70b9),
+        Some(0x4f1d),
+        Some(0x6bbf),
+        Some(0x6fb1),
+        Some(0x7530),
+        Some(0x96fb),
+        Some(0x514e),
+        Some(0x5410),
+        Some(0x5835),
+        Some(0x5857),
+        Some(0x59ac),
+        Some(0x5c60),
+        Some(0x5f92),
+        Some(0x6597),
+        Some(0x675c),
+        Some(0x6e21),
+        Some(0x767b),
+        Some(0x83df),
+        Some(0x8ced),
+        Some(0x9014),
+        Some(0x90fd),
+        Some(0x934d),
+        Some(0x7825),
+        Some(0x783a),
+        Some(0x52aa),
+        Some(0x5ea6),
+        Some(0x571f),
+        Some(0x5974),
+        Some(0x6012),
+        Some(0x5012),
+        Some(0x515a),
+        Some(0x51ac),
+        None,
+        Some(0x51cd),
+        Some(0x5200),
+        Some(0x5510),
+        Some(0x5854),
+        Some(0x5858),
+        Some(0x5957),
+        Some(0x5b95),
+        Some(0x5cf6),
+        Some(0x5d8b),
+        Some(0x60bc),
+        Some(0x6295),
+        Some(0x642d),
+        Some(0x6771),
+        Some(0x6843),
+        Some(0x68bc),
+        Some(0x68df),
+        Some(0x76d7),
+        Some(0x6dd8),
+        Some(0x6e6f),
+        Some(0x6d9b),
+        Some(0x706f),
+        Some(0x71c8),
+        Some(0x5f53),
+        Some(0x75d8),
+        Some(0x7977),
+        Some(0x7b49),
+        Some(0x7b54),
+        Some(0x7b52),
+        Some(0x7cd6),
+        Some(0x7d71),
+        Some(0x5230),
+        Some(0x8463),
+        Some(0x8569),
+        Some(0x85e4),
+        Some(0x8a0e),
+        Some(0x8b04),
+        Some(0x8c46),
+        Some(0x8e0f),
+        Some(0x9003),
+        Some(0x900f),
+        Some(0x9419),
+        Some(0x9676),
+        Some(0x982d),
+        Some(0x9a30),
+        Some(0x95d8),
+        Some(0x50cd),
+        Some(0x52d5),
+        Some(0x540c),
+        Some(0x5802),
+        Some(0x5c0e),
+        Some(0x61a7),
+        Some(0x649e),
+        Some(0x6d1e),
+        Some(0x77b3),
+        Some(0x7ae5),
+        Some(0x80f4),
+        Some(0x8404),
+        Some(0x9053),
+        Some(0x9285),
+        Some(0x5ce0),
+        Some(0x9d07),
+        Some(0x533f),
+        Some(0x5f97),
+        Some(0x5fb3),
+        Some(0x6d9c),
+        Some(0x7279),
+        Some(0x7763),
+        Some(0x79bf),
+        Some(0x7be4),
+        Some(0x6bd2),
+        Some(0x72ec),
+        Some(0x8aad),
+        Some(0x6803),
+        Some(0x6a61),
+        Some(0x51f8),
+        Some(0x7a81),
+        Some(0x6934),
+        Some(0x5c4a),
+        Some(0x9cf6),
+        Some(0x82eb),
+        Some(0x5bc5),
+        Some(0x9149),
+        Some(0x701e),
+        Some(0x5678),
+        Some(0x5c6f),
+        Some(0x60c7),
+        Some(0x6566),
+        Some(0x6c8c),
+        Some(0x8c5a),
+        Some(0x9041),
+        Some(0x9813),
+        Some(0x5451),
+        Some(0x66c7),
+        Some(0x920d),
+        Some(0x5948),
+        Some(0x90a3),
+        Some(0x5185),
+        Some(0x4e4d),
+        Some(0x51ea),
+        Some(0x8599),
+        Some(0x8b0e),
+        Some(0x7058),
+        Some(0x637a),
+        Some(0x934b),
+        Some(0x6962),
+        Some(0x99b4),
+        Some(0x7e04),
+        Some(0x7577),
+        Some(0x5357),
+        Some(0x6960),
+        Some(0x8edf),
+        Some(0x96e3),
+        Some(0x6c5d),
+        Some(0x4e8c),
+        Some(0x5c3c),
+        Some(0x5f10),
+        Some(0x8fe9),
+        Some(0x5302),
+        Some(0x8cd1),
+        Some(0x8089),
+        Some(0x8679),
+        Some(0x5eff),
+        Some(0x65e5),
+        Some(0x4e73),
+        Some(0x5165),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9440: [Option<u16>; 192] = [
+        Some(0x5982),
+        Some(0x5c3f),
+        Some(0x97ee),
+        Some(0x4efb),
+        Some(0x598a),
+        Some(0x5fcd),
+        Some(0x8a8d),
+        Some(0x6fe1),
+        Some(0x79b0),
+        Some(0x7962),
+        Some(0x5be7),
+        Some(0x8471),
+        Some(0x732b),
+        Some(0x71b1),
+        Some(0x5e74),
+        Some(0x5ff5),
+        Some(0x637b),
+        Some(0x649a),
+        Some(0x71c3),
+        Some(0x7c98),
+        Some(0x4e43),
+        Some(0x5efc),
+        Some(0x4e4b),
+        Some(0x57dc),
+        Some(0x56a2),
+        Some(0x60a9),
+        Some(0x6fc3),
+        Some(0x7d0d),
+        Some(0x80fd),
+        Some(0x8133),
+        Some(0x81bf),
+        Some(0x8fb2),
+        Some(0x8997),
+        Some(0x86a4),
+        Some(0x5df4),
+        Some(0x628a),
+        Some(0x64ad),
+        Some(0x8987),
+        Some(0x6777),
+        Some(0x6ce2),
+        Some(0x6d3e),
+        Some(0x7436),
+        Some(0x7834),
+        Some(0x5a46),
+        Some(0x7f75),
+        Some(0x82ad),
+        Some(0x99ac),
+        Some(0x4ff3),
+        Some(0x5ec3),
+        Some(0x62dd),
+        Some(0x6392),
+        Some(0x6557),
+        Some(0x676f),
+        Some(0x76c3),
+        Some(0x724c),
+        Some(0x80cc),
+        Some(0x80ba),
+        Some(0x8f29),
+        Some(0x914d),
+        Some(0x500d),
+        Some(0x57f9),
+        Some(0x5a92),
+        Some(0x6885),
+        None,
+        Some(0x6973),
+        Some(0x7164),
+        Some(0x72fd),
+        Some(0x8cb7),
+        Some(0x58f2),
+        Some(0x8ce0),
+        Some(0x966a),
+        Some(0x9019),
+        Some(0x877f),
+        Some(0x79e4),
+        Some(0x77e7),
+        Some(0x8429),
+        Some(0x4f2f),
+        Some(0x5265),
+        Some(0x535a),
+        Some(0x62cd),
+        Some(0x67cf),
+        Some(0x6cca),
+        Some(0x767d),
+        Some(0x7b94),
+        Some(0x7c95),
+        Some(0x8236),
+        Some(0x8584),
+        Some(0x8feb),
+        Some(0x66dd),
+        Some(0x6f20),
+        Some(0x7206),
+        Some(0x7e1b),
+        Some(0x83ab),
+        Some(0x99c1),
+        Some(0x9ea6),
+        Some(0x51fd),
+        Some(0x7bb1),
+        Some(0x7872),
+        Some(0x7bb8),
+        Some(0x8087),
+        Some(0x7b48),
+        Some(0x6ae8),
+        Some(0x5e61),
+        Some(0x808c),
+        Some(0x7551),
+        Some(0x7560),
+        Some(0x516b),
+        Some(0x9262),
+        Some(0x6e8c),
+        Some(0x767a),
+        Some(0x9197),
+        Some(0x9aea),
+        Some(0x4f10),
+        Some(0x7f70),
+        Some(0x629c),
+        Some(0x7b4f),
+        Some(0x95a5),
+        Some(0x9ce9),
+        Some(0x567a),
+        Some(0x5859),
+        Some(0x86e4),
+        Some(0x96bc),
+        Some(0x4f34),
+        Some(0x5224),
+        Some(0x534a),
+        Some(0x53cd),
+        Some(0x53db),
+        Some(0x5e06),
+        Some(0x642c),
+        Some(0x6591),
+        Some(0x677f),
+        Some(0x6c3e),
+        Some(0x6c4e),
+        Some(0x7248),
+        Some(0x72af),
+        Some(0x73ed),
+        Some(0x7554),
+        Some(0x7e41),
+        Some(0x822c),
+        Some(0x85e9),
+        Some(0x8ca9),
+        Some(0x7bc4),
+        Some(0x91c6),
+        Some(0x7169),
+        Some(0x9812),
+        Some(0x98ef),
+        Some(0x633d),
+        Some(0x6669),
+        Some(0x756a),
+        Some(0x76e4),
+        Some(0x78d0),
+        Some(0x8543),
+        Some(0x86ee),
+        Some(0x532a),
+        Some(0x5351),
+        Some(0x5426),
+        Some(0x5983),
+        Some(0x5e87),
+        Some(0x5f7c),
+        Some(0x60b2),
+        Some(0x6249),
+        Some(0x6279),
+        Some(0x62ab),
+        Some(0x6590),
+        Some(0x6bd4),
+        Some(0x6ccc),
+        Some(0x75b2),
+        Some(0x76ae),
+        Some(0x7891),
+        Some(0x79d8),
+        Some(0x7dcb),
+        Some(0x7f77),
+        Some(0x80a5),
+        Some(0x88ab),
+        Some(0x8ab9),
+        Some(0x8cbb),
+        Some(0x907f),
+        Some(0x975e),
+        Some(0x98db),
+        Some(0x6a0b),
+        Some(0x7c38),
+        Some(0x5099),
+        Some(0x5c3e),
+        Some(0x5fae),
+        Some(0x6787),
+        Some(0x6bd8),
+        Some(0x7435),
+        Some(0x7709),
+        Some(0x7f8e),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9540: [Option<u16>; 192] = [
+        Some(0x9f3b),
+        Some(0x67ca),
+        Some(0x7a17),
+        Some(0x5339),
+        Some(0x758b),
+        Some(0x9aed),
+        Some(0x5f66),
+        Some(0x819d),
+        Some(0x83f1),
+        Some(0x8098),
+        Some(0x5f3c),
+        Some(0x5fc5),
+        Some(0x7562),
+        Some(0x7b46),
+        Some(0x903c),
+        Some(0x6867),
+        Some(0x59eb),
+        Some(0x5a9b),
+        Some(0x7d10),
+        Some(0x767e),
+        Some(0x8b2c),
+        Some(0x4ff5),
+        Some(0x5f6a),
+        Some(0x6a19),
+        Some(0x6c37),
+        Some(0x6f02),
+        Some(0x74e2),
+        Some(0x7968),
+        Some(0x8868),
+        Some(0x8a55),
+        Some(0x8c79),
+        Some(0x5edf),
+        Some(0x63cf),
+        Some(0x75c5),
+        Some(0x79d2),
+        Some(0x82d7),
+        Some(0x9328),
+        Some(0x92f2),
+        Some(0x849c),
+        Some(0x86ed),
+        Some(0x9c2d),
+        Some(0x54c1),
+        Some(0x5f6c),
+        Some(0x658c),
+        Some(0x6d5c),
+        Some(0x7015),
+        Some(0x8ca7),
+        Some(0x8cd3),
+        Some(0x983b),
+        Some(0x654f),
+        Some(0x74f6),
+        Some(0x4e0d),
+        Some(0x4ed8),
+        Some(0x57e0),
+        Some(0x592b),
+        Some(0x5a66),
+        Some(0x5bcc),
+        Some(0x51a8),
+        Some(0x5e03),
+        Some(0x5e9c),
+        Some(0x6016),
+        Some(0x6276),
+        Some(0x6577),
+        None,
+        Some(0x65a7),
+        Some(0x666e),
+        Some(0x6d6e),
+        Some(0x7236),
+        Some(0x7b26),
+        Some(0x8150),
+        Some(0x819a),
+        Some(0x8299),
+        Some(0x8b5c),
+        Some(0x8ca0),
+        Some(0x8ce6),
+        Some(0x8d74),
+        Some(0x961c),
+        Some(0x9644),
+        Some(0x4fae),
+        Some(0x64ab),
+        Some(0x6b66),
+        Some(0x821e),
+        Some(0x8461),
+        Some(0x856a),
+        Some(0x90e8),
+        Some(0x5c01),
+        Some(0x6953),
+        Some(0x98a8),
+        Some(0x847a),
+        Some(0x8557),
+        Some(0x4f0f),
+        Some(0x526f),
+        Some(0x5fa9),
+        Some(0x5e45),
+        Some(0x670d),
+        Some(0x798f),
+        Some(0x8179),
+        Some(0x8907),
+        Some(0x8986),
+        Some(0x6df5),
+        Some(0x5f17),
+        Some(0x6255),
+        Some(0x6cb8),
+        Some(0x4ecf),
+        Some(0x7269),
+        Some(0x9b92),
+        Some(0x5206),
+        Some(0x543b),
+        Some(0x5674),
+        Some(0x58b3),
+        Some(0x61a4),
+        Some(0x626e),
+        Some(0x711a),
+        Some(0x596e),
+        Some(0x7c89),
+        Some(0x7cde),
+        Some(0x7d1b),
+        Some(0x96f0),
+        Some(0x6587),
+        Some(0x805e),
+        Some(0x4e19),
+        Some(0x4f75),
+        Some(0x5175),
+        Some(0x5840),
+        Some(0x5e63),
+        Some(0x5e73),
+        Some(0x5f0a),
+        Some(0x67c4),
+        Some(0x4e26),
+        Some(0x853d),
+        Some(0x9589),
+        Some(0x965b),
+        Some(0x7c73),
+        Some(0x9801),
+        Some(0x50fb),
+        Some(0x58c1),
+        Some(0x7656),
+        Some(0x78a7),
+        Some(0x5225),
+        Some(0x77a5),
+        Some(0x8511),
+        Some(0x7b86),
+        Some(0x504f),
+        Some(0x5909),
+        Some(0x7247),
+        Some(0x7bc7),
+        Some(0x7de8),
+        Some(0x8fba),
+        Some(0x8fd4),
+        Some(0x904d),
+        Some(0x4fbf),
+        Some(0x52c9),
+        Some(0x5a29),
+        Some(0x5f01),
+        Some(0x97ad),
+        Some(0x4fdd),
+        Some(0x8217),
+        Some(0x92ea),
+        Some(0x5703),
+        Some(0x6355),
+        Some(0x6b69),
+        Some(0x752b),
+        Some(0x88dc),
+        Some(0x8f14),
+        Some(0x7a42),
+        Some(0x52df),
+        Some(0x5893),
+        Some(0x6155),
+        Some(0x620a),
+        Some(0x66ae),
+        Some(0x6bcd),
+        Some(0x7c3f),
+        Some(0x83e9),
+        Some(0x5023),
+        Some(0x4ff8),
+        Some(0x5305),
+        Some(0x5446),
+        Some(0x5831),
+        Some(0x5949),
+        Some(0x5b9d),
+        Some(0x5cf0),
+        Some(0x5cef),
+        Some(0x5d29),
+        Some(0x5e96),
+        Some(0x62b1),
+        Some(0x6367),
+        Some(0x653e),
+        Some(0x65b9),
+        Some(0x670b),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9640: [Option<u16>; 192] = [
+        Some(0x6cd5),
+        Some(0x6ce1),
+        Some(0x70f9),
+        Some(0x7832),
+        Some(0x7e2b),
+        Some(0x80de),
+        Some(0x82b3),
+        Some(0x840c),
+        Some(0x84ec),
+        Some(0x8702),
+        Some(0x8912),
+        Some(0x8a2a),
+        Some(0x8c4a),
+        Some(0x90a6),
+        Some(0x92d2),
+        Some(0x98fd),
+        Some(0x9cf3),
+        Some(0x9d6c),
+        Some(0x4e4f),
+        Some(0x4ea1),
+        Some(0x508d),
+        Some(0x5256),
+        Some(0x574a),
+        Some(0x59a8),
+        Some(0x5e3d),
+        Some(0x5fd8),
+        Some(0x5fd9),
+        Some(0x623f),
+        Some(0x66b4),
+        Some(0x671b),
+        Some(0x67d0),
+        Some(0x68d2),
+        Some(0x5192),
+        Some(0x7d21),
+        Some(0x80aa),
+        Some(0x81a8),
+        Some(0x8b00),
+        Some(0x8c8c),
+        Some(0x8cbf),
+        Some(0x927e),
+        Some(0x9632),
+        Some(0x5420),
+        Some(0x982c),
+        Some(0x5317),
+        Some(0x50d5),
+        Some(0x535c),
+        Some(0x58a8),
+        Some(0x64b2),
+        Some(0x6734),
+        Some(0x7267),
+        Some(0x7766),
+        Some(0x7a46),
+        Some(0x91e6),
+        Some(0x52c3),
+        Some(0x6ca1),
+        Some(0x6b86),
+        Some(0x5800),
+        Some(0x5e4c),
+        Some(0x5954),
+        Some(0x672c),
+        Some(0x7ffb),
+        Some(0x51e1),
+        Some(0x76c6),
+        None,
+        Some(0x6469),
+        Some(0x78e8),
+        Some(0x9b54),
+        Some(0x9ebb),
+        Some(0x57cb),
+        Some(0x59b9),
+        Some(0x6627),
+        Some(0x679a),
+        Some(0x6bce),
+        Some(0x54e9),
+        Some(0x69d9),
+        Some(0x5e55),
+        Some(0x819c),
+        Some(0x6795),
+        Some(0x9baa),
+        Some(0x67fe),
+        Some(0x9c52),
+        Some(0x685d),
+        Some(0x4ea6),
+        Some(0x4fe3),
+        Some(0x53c8),
+        Some(0x62b9),
+        Some(0x672b),
+        Some(0x6cab),
+        Some(0x8fc4),
+        Some(0x4fad),
+        Some(0x7e6d),
+        Some(0x9ebf),
+        Some(0x4e07),
+        Some(0x6162),
+        Some(0x6e80),
+        Some(0x6f2b),
+        Some(0x8513),
+        Some(0x5473),
+        Some(0x672a),
+        Some(0x9b45),
+        Some(0x5df3),
+        Some(0x7b95),
+        Some(0x5cac),
+        Some(0x5bc6),
+        Some(0x871c),
+        Some(0x6e4a),
+        Some(0x84d1),
+        Some(0x7a14),
+        Some(0x8108),
+        Some(0x5999),
+        Some(0x7c8d),
+        Some(0x6c11),
+        Some(0x7720),
+        Some(0x52d9),
+        Some(0x5922),
+        Some(0x7121),
+        Some(0x725f),
+        Some(0x77db),
+        Some(0x9727),
+        Some(0x9d61),
+        Some(0x690b),
+        Some(0x5a7f),
+        Some(0x5a18),
+        Some(0x51a5),
+        Some(0x540d),
+        Some(0x547d),
+        Some(0x660e),
+        Some(0x76df),
+        Some(0x8ff7),
+        Some(0x9298),
+        Some(0x9cf4),
+        Some(0x59ea),
+        Some(0x725d),
+        Some(0x6ec5),
+        Some(0x514d),
+        Some(0x68c9),
+        Some(0x7dbf),
+        Some(0x7dec),
+        Some(0x9762),
+        Some(0x9eba),
+        Some(0x6478),
+        Some(0x6a21),
+        Some(0x8302),
+        Some(0x5984),
+        Some(0x5b5f),
+        Some(0x6bdb),
+        Some(0x731b),
+        Some(0x76f2),
+        Some(0x7db2),
+        Some(0x8017),
+        Some(0x8499),
+        Some(0x5132),
+        Some(0x6728),
+        Some(0x9ed9),
+        Some(0x76ee),
+        Some(0x6762),
+        Some(0x52ff),
+        Some(0x9905),
+        Some(0x5c24),
+        Some(0x623b),
+        Some(0x7c7e),
+        Some(0x8cb0),
+        Some(0x554f),
+        Some(0x60b6),
+        Some(0x7d0b),
+        Some(0x9580),
+        Some(0x5301),
+        Some(0x4e5f),
+        Some(0x51b6),
+        Some(0x591c),
+        Some(0x723a),
+        Some(0x8036),
+        Some(0x91ce),
+        Some(0x5f25),
+        Some(0x77e2),
+        Some(0x5384),
+        Some(0x5f79),
+        Some(0x7d04),
+        Some(0x85ac),
+        Some(0x8a33),
+        Some(0x8e8d),
+        Some(0x9756),
+        Some(0x67f3),
+        Some(0x85ae),
+        Some(0x9453),
+        Some(0x6109),
+        Some(0x6108),
+        Some(0x6cb9),
+        Some(0x7652),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9740: [Option<u16>; 192] = [
+        Some(0x8aed),
+        Some(0x8f38),
+        Some(0x552f),
+        Some(0x4f51),
+        Some(0x512a),
+        Some(0x52c7),
+        Some(0x53cb),
+        Some(0x5ba5),
+        Some(0x5e7d),
+        Some(0x60a0),
+        Some(0x6182),
+        Some(0x63d6),
+        Some(0x6709),
+        Some(0x67da),
+        Some(0x6e67),
+        Some(0x6d8c),
+        Some(0x7336),
+        Some(0x7337),
+        Some(0x7531),
+        Some(0x7950),
+        Some(0x88d5),
+        Some(0x8a98),
+        Some(0x904a),
+        Some(0x9091),
+        Some(0x90f5),
+        Some(0x96c4),
+        Some(0x878d),
+        Some(0x5915),
+        Some(0x4e88),
+        Some(0x4f59),
+        Some(0x4e0e),
+        Some(0x8a89),
+        Some(0x8f3f),
+        Some(0x9810),
+        Some(0x50ad),
+        Some(0x5e7c),
+        Some(0x5996),
+        Some(0x5bb9),
+        Some(0x5eb8),
+        Some(0x63da),
+        Some(0x63fa),
+        Some(0x64c1),
+        Some(0x66dc),
+        Some(0x694a),
+        Some(0x69d8),
+        Some(0x6d0b),
+        Some(0x6eb6),
+        Some(0x7194),
+        Some(0x7528),
+        Some(0x7aaf),
+        Some(0x7f8a),
+        Some(0x8000),
+        Some(0x8449),
+        Some(0x84c9),
+        Some(0x8981),
+        Some(0x8b21),
+        Some(0x8e0a),
+        Some(0x9065),
+        Some(0x967d),
+        Some(0x990a),
+        Some(0x617e),
+        Some(0x6291),
+        Some(0x6b32),
+        None,
+        Some(0x6c83),
+        Some(0x6d74),
+        Some(0x7fcc),
+        Some(0x7ffc),
+        Some(0x6dc0),
+        Some(0x7f85),
+        Some(0x87ba),
+        Some(0x88f8),
+        Some(0x6765),
+        Some(0x83b1),
+        Some(0x983c),
+        Some(0x96f7),
+        Some(0x6d1b),
+        Some(0x7d61),
+        Some(0x843d),
+        Some(0x916a),
+        Some(0x4e71),
+        Some(0x5375),
+        Some(0x5d50),
+        Some(0x6b04),
+        Some(0x6feb),
+        Some(0x85cd),
+        Some(0x862d),
+        Some(0x89a7),
+        Some(0x5229),
+        Some(0x540f),
+        Some(0x5c65),
+        Some(0x674e),
+        Some(0x68a8),
+        Some(0x7406),
+        Some(0x7483),
+        Some(0x75e2),
+        Some(0x88cf),
+        Some(0x88e1),
+        Some(0x91cc),
+        Some(0x96e2),
+        Some(0x9678),
+        Some(0x5f8b),
+        Some(0x7387),
+        Some(0x7acb),
+        Some(0x844e),
+        Some(0x63a0),
+        Some(0x7565),
+        Some(0x5289),
+        Some(0x6d41),
+        Some(0x6e9c),
+        Some(0x7409),
+        Some(0x7559),
+        Some(0x786b),
+        Some(0x7c92),
+        Some(0x9686),
+        Some(0x7adc),
+        Some(0x9f8d),
+        Some(0x4fb6),
+        Some(0x616e),
+        Some(0x65c5),
+        Some(0x865c),
+        Some(0x4e86),
+        Some(0x4eae),
+        Some(0x50da),
+        Some(0x4e21),
+        Some(0x51cc),
+        Some(0x5bee),
+        Some(0x6599),
+        Some(0x6881),
+        Some(0x6dbc),
+        Some(0x731f),
+        Some(0x7642),
+        Some(0x77ad),
+        Some(0x7a1c),
+        Some(0x7ce7),
+        Some(0x826f),
+        Some(0x8ad2),
+        Some(0x907c),
+        Some(0x91cf),
+        Some(0x9675),
+        Some(0x9818),
+        Some(0x529b),
+        Some(0x7dd1),
+        Some(0x502b),
+        Some(0x5398),
+        Some(0x6797),
+        Some(0x6dcb),
+        Some(0x71d0),
+        Some(0x7433),
+        Some(0x81e8),
+        Some(0x8f2a),
+        Some(0x96a3),
+        Some(0x9c57),
+        Some(0x9e9f),
+        Some(0x7460),
+        Some(0x5841),
+        Some(0x6d99),
+        Some(0x7d2f),
+        Some(0x985e),
+        Some(0x4ee4),
+        Some(0x4f36),
+        Some(0x4f8b),
+        Some(0x51b7),
+        Some(0x52b1),
+        Some(0x5dba),
+        Some(0x601c),
+        Some(0x73b2),
+        Some(0x793c),
+        Some(0x82d3),
+        Some(0x9234),
+        Some(0x96b7),
+        Some(0x96f6),
+        Some(0x970a),
+        Some(0x9e97),
+        Some(0x9f62),
+        Some(0x66a6),
+        Some(0x6b74),
+        Some(0x5217),
+        Some(0x52a3),
+        Some(0x70c8),
+        Some(0x88c2),
+        Some(0x5ec9),
+        Some(0x604b),
+        Some(0x6190),
+        Some(0x6f23),
+        Some(0x7149),
+        Some(0x7c3e),
+        Some(0x7df4),
+        Some(0x806f),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9840: [Option<u16>; 192] = [
+        Some(0x84ee),
+        Some(0x9023),
+        Some(0x932c),
+        Some(0x5442),
+        Some(0x9b6f),
+        Some(0x6ad3),
+        Some(0x7089),
+        Some(0x8cc2),
+        Some(0x8def),
+        Some(0x9732),
+        Some(0x52b4),
+        Some(0x5a41),
+        Some(0x5eca),
+        Some(0x5f04),
+        Some(0x6717),
+        Some(0x697c),
+        Some(0x6994),
+        Some(0x6d6a),
+        Some(0x6f0f),
+        Some(0x7262),
+        Some(0x72fc),
+        Some(0x7bed),
+        Some(0x8001),
+        Some(0x807e),
+        Some(0x874b),
+        Some(0x90ce),
+        Some(0x516d),
+        Some(0x9e93),
+        Some(0x7984),
+        Some(0x808b),
+        Some(0x9332),
+        Some(0x8ad6),
+        Some(0x502d),
+        Some(0x548c),
+        Some(0x8a71),
+        Some(0x6b6a),
+        Some(0x8cc4),
+        Some(0x8107),
+        Some(0x60d1),
+        Some(0x67a0),
+        Some(0x9df2),
+        Some(0x4e99),
+        Some(0x4e98),
+        Some(0x9c10),
+        Some(0x8a6b),
+        Some(0x85c1),
+        Some(0x8568),
+        Some(0x6900),
+        Some(0x6e7e),
+        Some(0x7897),
+        Some(0x8155),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5f0c),
+        Some(0x4e10),
+        Some(0x4e15),
+        Some(0x4e2a),
+        Some(0x4e31),
+        Some(0x4e36),
+        Some(0x4e3c),
+        Some(0x4e3f),
+        Some(0x4e42),
+        Some(0x4e56),
+        Some(0x4e58),
+        Some(0x4e82),
+        Some(0x4e85),
+        Some(0x8c6b),
+        Some(0x4e8a),
+        Some(0x8212),
+        Some(0x5f0d),
+        Some(0x4e8e),
+        Some(0x4e9e),
+        Some(0x4e9f),
+        Some(0x4ea0),
+        Some(0x4ea2),
+        Some(0x4eb0),
+        Some(0x4eb3),
+        Some(0x4eb6),
+        Some(0x4ece),
+        Some(0x4ecd),
+        Some(0x4ec4),
+        Some(0x4ec6),
+        Some(0x4ec2),
+        Some(0x4ed7),
+        Some(0x4ede),
+        Some(0x4eed),
+        Some(0x4edf),
+        Some(0x4ef7),
+        Some(0x4f09),
+        Some(0x4f5a),
+        Some(0x4f30),
+        Some(0x4f5b),
+        Some(0x4f5d),
+        Some(0x4f57),
+        Some(0x4f47),
+        Some(0x4f76),
+        Some(0x4f88),
+        Some(0x4f8f),
+        Some(0x4f98),
+        Some(0x4f7b),
+        Some(0x4f69),
+        Some(0x4f70),
+        Some(0x4f91),
+        Some(0x4f6f),
+        Some(0x4f86),
+        Some(0x4f96),
+        Some(0x5118),
+        Some(0x4fd4),
+        Some(0x4fdf),
+        Some(0x4fce),
+        Some(0x4fd8),
+        Some(0x4fdb),
+        Some(0x4fd1),
+        Some(0x4fda),
+        Some(0x4fd0),
+        Some(0x4fe4),
+        Some(0x4fe5),
+        Some(0x501a),
+        Some(0x5028),
+        Some(0x5014),
+        Some(0x502a),
+        Some(0x5025),
+        Some(0x5005),
+        Some(0x4f1c),
+        Some(0x4ff6),
+        Some(0x5021),
+        Some(0x5029),
+        Some(0x502c),
+        Some(0x4ffe),
+        Some(0x4fef),
+        Some(0x5011),
+        Some(0x5006),
+        Some(0x5043),
+        Some(0x5047),
+        Some(0x6703),
+        Some(0x5055),
+        Some(0x5050),
+        Some(0x5048),
+        Some(0x505a),
+        Some(0x5056),
+        Some(0x506c),
+        Some(0x5078),
+        Some(0x5080),
+        Some(0x509a),
+        Some(0x5085),
+        Some(0x50b4),
+        Some(0x50b2),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9940: [Option<u16>; 192] = [
+        Some(0x50c9),
+        Some(0x50ca),
+        Some(0x50b3),
+        Some(0x50c2),
+        Some(0x50d6),
+        Some(0x50de),
+        Some(0x50e5),
+        Some(0x50ed),
+        Some(0x50e3),
+        Some(0x50ee),
+        Some(0x50f9),
+        Some(0x50f5),
+        Some(0x5109),
+        Some(0x5101),
+        Some(0x5102),
+        Some(0x5116),
+        Some(0x5115),
+        Some(0x5114),
+        Some(0x511a),
+        Some(0x5121),
+        Some(0x513a),
+        Some(0x5137),
+        Some(0x513c),
+        Some(0x513b),
+        Some(0x513f),
+        Some(0x5140),
+        Some(0x5152),
+        Some(0x514c),
+        Some(0x5154),
+        Some(0x5162),
+        Some(0x7af8),
+        Some(0x5169),
+        Some(0x516a),
+        Some(0x516e),
+        Some(0x5180),
+        Some(0x5182),
+        Some(0x56d8),
+        Some(0x518c),
+        Some(0x5189),
+        Some(0x518f),
+        Some(0x5191),
+        Some(0x5193),
+        Some(0x5195),
+        Some(0x5196),
+        Some(0x51a4),
+        Some(0x51a6),
+        Some(0x51a2),
+        Some(0x51a9),
+        Some(0x51aa),
+        Some(0x51ab),
+        Some(0x51b3),
+        Some(0x51b1),
+        Some(0x51b2),
+        Some(0x51b0),
+        Some(0x51b5),
+        Some(0x51bd),
+        Some(0x51c5),
+        Some(0x51c9),
+        Some(0x51db),
+        Some(0x51e0),
+        Some(0x8655),
+        Some(0x51e9),
+        Some(0x51ed),
+        None,
+        Some(0x51f0),
+        Some(0x51f5),
+        Some(0x51fe),
+        Some(0x5204),
+        Some(0x520b),
+        Some(0x5214),
+        Some(0x520e),
+        Some(0x5227),
+        Some(0x522a),
+        Some(0x522e),
+        Some(0x5233),
+        Some(0x5239),
+        Some(0x524f),
+        Some(0x5244),
+        Some(0x524b),
+        Some(0x524c),
+        Some(0x525e),
+        Some(0x5254),
+        Some(0x526a),
+        Some(0x5274),
+        Some(0x5269),
+        Some(0x5273),
+        Some(0x527f),
+        Some(0x527d),
+        Some(0x528d),
+        Some(0x5294),
+        Some(0x5292),
+        Some(0x5271),
+        Some(0x5288),
+        Some(0x5291),
+        Some(0x8fa8),
+        Some(0x8fa7),
+        Some(0x52ac),
+        Some(0x52ad),
+        Some(0x52bc),
+        Some(0x52b5),
+        Some(0x52c1),
+        Some(0x52cd),
+        Some(0x52d7),
+        Some(0x52de),
+        Some(0x52e3),
+        Some(0x52e6),
+        Some(0x98ed),
+        Some(0x52e0),
+        Some(0x52f3),
+        Some(0x52f5),
+        Some(0x52f8),
+        Some(0x52f9),
+        Some(0x5306),
+        Some(0x5308),
+        Some(0x7538),
+        Some(0x530d),
+        Some(0x5310),
+        Some(0x530f),
+        Some(0x5315),
+        Some(0x531a),
+        Some(0x5323),
+        Some(0x532f),
+        Some(0x5331),
+        Some(0x5333),
+        Some(0x5338),
+        Some(0x5340),
+        Some(0x5346),
+        Some(0x5345),
+        Some(0x4e17),
+        Some(0x5349),
+        Some(0x534d),
+        Some(0x51d6),
+        Some(0x535e),
+        Some(0x5369),
+        Some(0x536e),
+        Some(0x5918),
+        Some(0x537b),
+        Some(0x5377),
+        Some(0x5382),
+        Some(0x5396),
+        Some(0x53a0),
+        Some(0x53a6),
+        Some(0x53a5),
+        Some(0x53ae),
+        Some(0x53b0),
+        Some(0x53b6),
+        Some(0x53c3),
+        Some(0x7c12),
+        Some(0x96d9),
+        Some(0x53df),
+        Some(0x66fc),
+        Some(0x71ee),
+        Some(0x53ee),
+        Some(0x53e8),
+        Some(0x53ed),
+        Some(0x53fa),
+        Some(0x5401),
+        Some(0x543d),
+        Some(0x5440),
+        Some(0x542c),
+        Some(0x542d),
+        Some(0x543c),
+        Some(0x542e),
+        Some(0x5436),
+        Some(0x5429),
+        Some(0x541d),
+        Some(0x544e),
+        Some(0x548f),
+        Some(0x5475),
+        Some(0x548e),
+        Some(0x545f),
+        Some(0x5471),
+        Some(0x5477),
+        Some(0x5470),
+        Some(0x5492),
+        Some(0x547b),
+        Some(0x5480),
+        Some(0x5476),
+        Some(0x5484),
+        Some(0x5490),
+        Some(0x5486),
+        Some(0x54c7),
+        Some(0x54a2),
+        Some(0x54b8),
+        Some(0x54a5),
+        Some(0x54ac),
+        Some(0x54c4),
+        Some(0x54c8),
+        Some(0x54a8),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9A40: [Option<u16>; 192] = [
+        Some(0x54ab),
+        Some(0x54c2),
+        Some(0x54a4),
+        Some(0x54be),
+        Some(0x54bc),
+        Some(0x54d8),
+        Some(0x54e5),
+        Some(0x54e6),
+        Some(0x550f),
+        Some(0x5514),
+        Some(0x54fd),
+        Some(0x54ee),
+        Some(0x54ed),
+        Some(0x54fa),
+        Some(0x54e2),
+        Some(0x5539),
+        Some(0x5540),
+        Some(0x5563),
+        Some(0x554c),
+        Some(0x552e),
+        Some(0x555c),
+        Some(0x5545),
+        Some(0x5556),
+        Some(0x5557),
+        Some(0x5538),
+        Some(0x5533),
+        Some(0x555d),
+        Some(0x5599),
+        Some(0x5580),
+        Some(0x54af),
+        Some(0x558a),
+        Some(0x559f),
+        Some(0x557b),
+        Some(0x557e),
+        Some(0x5598),
+        Some(0x559e),
+        Some(0x55ae),
+        Some(0x557c),
+        Some(0x5583),
+        Some(0x55a9),
+        Some(0x5587),
+        Some(0x55a8),
+        Some(0x55da),
+        Some(0x55c5),
+        Some(0x55df),
+        Some(0x55c4),
+        Some(0x55dc),
+        Some(0x55e4),
+        Some(0x55d4),
+        Some(0x5614),
+        Some(0x55f7),
+        Some(0x5616),
+        Some(0x55fe),
+        Some(0x55fd),
+        Some(0x561b),
+        Some(0x55f9),
+        Some(0x564e),
+        Some(0x5650),
+        Some(0x71df),
+        Some(0x5634),
+        Some(0x5636),
+        Some(0x5632),
+        Some(0x5638),
+        None,
+        Some(0x566b),
+        Some(0x5664),
+        Some(0x562f),
+        Some(0x566c),
+        Some(0x566a),
+        Some(0x5686),
+        Some(0x5680),
+        Some(0x568a),
+        Some(0x56a0),
+        Some(0x5694),
+        Some(0x568f),
+        Some(0x56a5),
+        Some(0x56ae),
+        Some(0x56b6),
+        Some(0x56b4),
+        Some(0x56c2),
+        Some(0x56bc),
+        Some(0x56c1),
+        Some(0x56c3),
+        Some(0x56c0),
+        Some(0x56c8),
+        Some(0x56ce),
+        Some(0x56d1),
+        Some(0x56d3),
+        Some(0x56d7),
+        Some(0x56ee),
+        Some(0x56f9),
+        Some(0x5700),
+        Some(0x56ff),
+        Some(0x5704),
+        Some(0x5709),
+        Some(0x5708),
+        Some(0x570b),
+        Some(0x570d),
+        Some(0x5713),
+        Some(0x5718),
+        Some(0x5716),
+        Some(0x55c7),
+        Some(0x571c),
+        Some(0x5726),
+        Some(0x5737),
+        Some(0x5738),
+        Some(0x574e),
+        Some(0x573b),
+        Some(0x5740),
+        Some(0x574f),
+        Some(0x5769),
+        Some(0x57c0),
+        Some(0x5788),
+        Some(0x5761),
+        Some(0x577f),
+        Some(0x5789),
+        Some(0x5793),
+        Some(0x57a0),
+        Some(0x57b3),
+        Some(0x57a4),
+        Some(0x57aa),
+        Some(0x57b0),
+        Some(0x57c3),
+        Some(0x57c6),
+        Some(0x57d4),
+        Some(0x57d2),
+        Some(0x57d3),
+        Some(0x580a),
+        Some(0x57d6),
+        Some(0x57e3),
+        Some(0x580b),
+        Some(0x5819),
+        Some(0x581d),
+        Some(0x5872),
+        Some(0x5821),
+        Some(0x5862),
+        Some(0x584b),
+        Some(0x5870),
+        Some(0x6bc0),
+        Some(0x5852),
+        Some(0x583d),
+        Some(0x5879),
+        Some(0x5885),
+        Some(0x58b9),
+        Some(0x589f),
+        Some(0x58ab),
+        Some(0x58ba),
+        Some(0x58de),
+        Some(0x58bb),
+        Some(0x58b8),
+        Some(0x58ae),
+        Some(0x58c5),
+        Some(0x58d3),
+        Some(0x58d1),
+        Some(0x58d7),
+        Some(0x58d9),
+        Some(0x58d8),
+        Some(0x58e5),
+        Some(0x58dc),
+        Some(0x58e4),
+        Some(0x58df),
+        Some(0x58ef),
+        Some(0x58fa),
+        Some(0x58f9),
+        Some(0x58fb),
+        Some(0x58fc),
+        Some(0x58fd),
+        Some(0x5902),
+        Some(0x590a),
+        Some(0x5910),
+        Some(0x591b),
+        Some(0x68a6),
+        Some(0x5925),
+        Some(0x592c),
+        Some(0x592d),
+        Some(0x5932),
+        Some(0x5938),
+        Some(0x593e),
+        Some(0x7ad2),
+        Some(0x5955),
+        Some(0x5950),
+        Some(0x594e),
+        Some(0x595a),
+        Some(0x5958),
+        Some(0x5962),
+        Some(0x5960),
+        Some(0x5967),
+        Some(0x596c),
+        Some(0x5969),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9B40: [Option<u16>; 192] = [
+        Some(0x5978),
+        Some(0x5981),
+        Some(0x599d),
+        Some(0x4f5e),
+        Some(0x4fab),
+        Some(0x59a3),
+        Some(0x59b2),
+        Some(0x59c6),
+        Some(0x59e8),
+        Some(0x59dc),
+        Some(0x598d),
+        Some(0x59d9),
+        Some(0x59da),
+        Some(0x5a25),
+        Some(0x5a1f),
+        Some(0x5a11),
+        Some(0x5a1c),
+        Some(0x5a09),
+        Some(0x5a1a),
+        Some(0x5a40),
+        Some(0x5a6c),
+        Some(0x5a49),
+        Some(0x5a35),
+        Some(0x5a36),
+        Some(0x5a62),
+        Some(0x5a6a),
+        Some(0x5a9a),
+        Some(0x5abc),
+        Some(0x5abe),
+        Some(0x5acb),
+        Some(0x5ac2),
+        Some(0x5abd),
+        Some(0x5ae3),
+        Some(0x5ad7),
+        Some(0x5ae6),
+        Some(0x5ae9),
+        Some(0x5ad6),
+        Some(0x5afa),
+        Some(0x5afb),
+        Some(0x5b0c),
+        Some(0x5b0b),
+        Some(0x5b16),
+        Some(0x5b32),
+        Some(0x5ad0),
+        Some(0x5b2a),
+        Some(0x5b36),
+        Some(0x5b3e),
+        Some(0x5b43),
+        Some(0x5b45),
+        Some(0x5b40),
+        Some(0x5b51),
+        Some(0x5b55),
+        Some(0x5b5a),
+        Some(0x5b5b),
+        Some(0x5b65),
+        Some(0x5b69),
+        Some(0x5b70),
+        Some(0x5b73),
+        Some(0x5b75),
+        Some(0x5b78),
+        Some(0x6588),
+        Some(0x5b7a),
+        Some(0x5b80),
+        None,
+        Some(0x5b83),
+        Some(0x5ba6),
+        Some(0x5bb8),
+        Some(0x5bc3),
+        Some(0x5bc7),
+        Some(0x5bc9),
+        Some(0x5bd4),
+        Some(0x5bd0),
+        Some(0x5be4),
+        Some(0x5be6),
+        Some(0x5be2),
+        Some(0x5bde),
+        Some(0x5be5),
+        Some(0x5beb),
+        Some(0x5bf0),
+        Some(0x5bf6),
+        Some(0x5bf3),
+        Some(0x5c05),
+        Some(0x5c07),
+        Some(0x5c08),
+        Some(0x5c0d),
+        Some(0x5c13),
+        Some(0x5c20),
+        Some(0x5c22),
+        Some(0x5c28),
+        Some(0x5c38),
+        Some(0x5c39),
+        Some(0x5c41),
+        Some(0x5c46),
+        Some(0x5c4e),
+        Some(0x5c53),
+        Some(0x5c50),
+        Some(0x5c4f),
+        Some(0x5b71),
+        Some(0x5c6c),
+        Some(0x5c6e),
+        Some(0x4e62),
+        Some(0x5c76),
+        Some(0x5c79),
+        Some(0x5c8c),
+        Some(0x5c91),
+        Some(0x5c94),
+        Some(0x599b),
+        Some(0x5cab),
+        Some(0x5cbb),
+        Some(0x5cb6),
+        Some(0x5cbc),
+        Some(0x5cb7),
+        Some(0x5cc5),
+        Some(0x5cbe),
+        Some(0x5cc7),
+        Some(0x5cd9),
+        Some(0x5ce9),
+        Some(0x5cfd),
+        Some(0x5cfa),
+        Some(0x5ced),
+        Some(0x5d8c),
+        Some(0x5cea),
+        Some(0x5d0b),
+        Some(0x5d15),
+        Some(0x5d17),
+        Some(0x5d5c),
+        Some(0x5d1f),
+        Some(0x5d1b),
+        Some(0x5d11),
+        Some(0x5d14),
+        Some(0x5d22),
+        Some(0x5d1a),
+        Some(0x5d19),
+        Some(0x5d18),
+        Some(0x5d4c),
+        Some(0x5d52),
+        Some(0x5d4e),
+        Some(0x5d4b),
+        Some(0x5d6c),
+        Some(0x5d73),
+        Some(0x5d76),
+        Some(0x5d87),
+        Some(0x5d84),
+        Some(0x5d82),
+        Some(0x5da2),
+        Some(0x5d9d),
+        Some(0x5dac),
+        Some(0x5dae),
+        Some(0x5dbd),
+        Some(0x5d90),
+        Some(0x5db7),
+        Some(0x5dbc),
+        Some(0x5dc9),
+        Some(0x5dcd),
+        Some(0x5dd3),
+        Some(0x5dd2),
+        Some(0x5dd6),
+        Some(0x5ddb),
+        Some(0x5deb),
+        Some(0x5df2),
+        Some(0x5df5),
+        Some(0x5e0b),
+        Some(0x5e1a),
+        Some(0x5e19),
+        Some(0x5e11),
+        Some(0x5e1b),
+        Some(0x5e36),
+        Some(0x5e37),
+        Some(0x5e44),
+        Some(0x5e43),
+        Some(0x5e40),
+        Some(0x5e4e),
+        Some(0x5e57),
+        Some(0x5e54),
+        Some(0x5e5f),
+        Some(0x5e62),
+        Some(0x5e64),
+        Some(0x5e47),
+        Some(0x5e75),
+        Some(0x5e76),
+        Some(0x5e7a),
+        Some(0x9ebc),
+        Some(0x5e7f),
+        Some(0x5ea0),
+        Some(0x5ec1),
+        Some(0x5ec2),
+        Some(0x5ec8),
+        Some(0x5ed0),
+        Some(0x5ecf),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9C40: [Option<u16>; 192] = [
+        Some(0x5ed6),
+        Some(0x5ee3),
+        Some(0x5edd),
+        Some(0x5eda),
+        Some(0x5edb),
+        Some(0x5ee2),
+        Some(0x5ee1),
+        Some(0x5ee8),
+        Some(0x5ee9),
+        Some(0x5eec),
+        Some(0x5ef1),
+        Some(0x5ef3),
+        Some(0x5ef0),
+        Some(0x5ef4),
+        Some(0x5ef8),
+        Some(0x5efe),
+        Some(0x5f03),
+        Some(0x5f09),
+        Some(0x5f5d),
+        Some(0x5f5c),
+        Some(0x5f0b),
+        Some(0x5f11),
+        Some(0x5f16),
+        Some(0x5f29),
+        Some(0x5f2d),
+        Some(0x5f38),
+        Some(0x5f41),
+        Some(0x5f48),
+        Some(0x5f4c),
+        Some(0x5f4e),
+        Some(0x5f2f),
+        Some(0x5f51),
+        Some(0x5f56),
+        Some(0x5f57),
+        Some(0x5f59),
+        Some(0x5f61),
+        Some(0x5f6d),
+        Some(0x5f73),
+        Some(0x5f77),
+        Some(0x5f83),
+        Some(0x5f82),
+        Some(0x5f7f),
+        Some(0x5f8a),
+        Some(0x5f88),
+        Some(0x5f91),
+        Some(0x5f87),
+        Some(0x5f9e),
+        Some(0x5f99),
+        Some(0x5f98),
+        Some(0x5fa0),
+        Some(0x5fa8),
+        Some(0x5fad),
+        Some(0x5fbc),
+        Some(0x5fd6),
+        Some(0x5ffb),
+        Some(0x5fe4),
+        Some(0x5ff8),
+        Some(0x5ff1),
+        Some(0x5fdd),
+        Some(0x60b3),
+        Some(0x5fff),
+        Some(0x6021),
+        Some(0x6060),
+        None,
+        Some(0x6019),
+        Some(0x6010),
+        Some(0x6029),
+        Some(0x600e),
+        Some(0x6031),
+        Some(0x601b),
+        Some(0x6015),
+        Some(0x602b),
+        Some(0x6026),
+        Some(0x600f),
+        Some(0x603a),
+        Some(0x605a),
+        Some(0x6041),
+        Some(0x606a),
+        Some(0x6077),
+        Some(0x605f),
+        Some(0x604a),
+        Some(0x6046),
+        Some(0x604d),
+        Some(0x6063),
+        Some(0x6043),
+        Some(0x6064),
+        Some(0x6042),
+        Some(0x606c),
+        Some(0x606b),
+        Some(0x6059),
+        Some(0x6081),
+        Some(0x608d),
+        Some(0x60e7),
+        Some(0x6083),
+        Some(0x609a),
+        Some(0x6084),
+        Some(0x609b),
+        Some(0x6096),
+        Some(0x6097),
+        Some(0x6092),
+        Some(0x60a7),
+        Some(0x608b),
+        Some(0x60e1),
+        Some(0x60b8),
+        Some(0x60e0),
+        Some(0x60d3),
+        Some(0x60b4),
+        Some(0x5ff0),
+        Some(0x60bd),
+        Some(0x60c6),
+        Some(0x60b5),
+        Some(0x60d8),
+        Some(0x614d),
+        Some(0x6115),
+        Some(0x6106),
+        Some(0x60f6),
+        Some(0x60f7),
+        Some(0x6100),
+        Some(0x60f4),
+        Some(0x60fa),
+        Some(0x6103),
+        Some(0x6121),
+        Some(0x60fb),
+        Some(0x60f1),
+        Some(0x610d),
+        Some(0x610e),
+        Some(0x6147),
+        Some(0x613e),
+        Some(0x6128),
+        Some(0x6127),
+        Some(0x614a),
+        Some(0x613f),
+        Some(0x613c),
+        Some(0x612c),
+        Some(0x6134),
+        Some(0x613d),
+        Some(0x6142),
+        Some(0x6144),
+        Some(0x6173),
+        Some(0x6177),
+        Some(0x6158),
+        Some(0x6159),
+        Some(0x615a),
+        Some(0x616b),
+        Some(0x6174),
+        Some(0x616f),
+        Some(0x6165),
+        Some(0x6171),
+        Some(0x615f),
+        Some(0x615d),
+        Some(0x6153),
+        Some(0x6175),
+        Some(0x6199),
+        Some(0x6196),
+        Some(0x6187),
+        Some(0x61ac),
+        Some(0x6194),
+        Some(0x619a),
+        Some(0x618a),
+        Some(0x6191),
+        Some(0x61ab),
+        Some(0x61ae),
+        Some(0x61cc),
+        Some(0x61ca),
+        Some(0x61c9),
+        Some(0x61f7),
+        Some(0x61c8),
+        Some(0x61c3),
+        Some(0x61c6),
+        Some(0x61ba),
+        Some(0x61cb),
+        Some(0x7f79),
+        Some(0x61cd),
+        Some(0x61e6),
+        Some(0x61e3),
+        Some(0x61f6),
+        Some(0x61fa),
+        Some(0x61f4),
+        Some(0x61ff),
+        Some(0x61fd),
+        Some(0x61fc),
+        Some(0x61fe),
+        Some(0x6200),
+        Some(0x6208),
+        Some(0x6209),
+        Some(0x620d),
+        Some(0x620c),
+        Some(0x6214),
+        Some(0x621b),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9D40: [Option<u16>; 192] = [
+        Some(0x621e),
+        Some(0x6221),
+        Some(0x622a),
+        Some(0x622e),
+        Some(0x6230),
+        Some(0x6232),
+        Some(0x6233),
+        Some(0x6241),
+        Some(0x624e),
+        Some(0x625e),
+        Some(0x6263),
+        Some(0x625b),
+        Some(0x6260),
+        Some(0x6268),
+        Some(0x627c),
+        Some(0x6282),
+        Some(0x6289),
+        Some(0x627e),
+        Some(0x6292),
+        Some(0x6293),
+        Some(0x6296),
+        Some(0x62d4),
+        Some(0x6283),
+        Some(0x6294),
+        Some(0x62d7),
+        Some(0x62d1),
+        Some(0x62bb),
+        Some(0x62cf),
+        Some(0x62ff),
+        Some(0x62c6),
+        Some(0x64d4),
+        Some(0x62c8),
+        Some(0x62dc),
+        Some(0x62cc),
+        Some(0x62ca),
+        Some(0x62c2),
+        Some(0x62c7),
+        Some(0x629b),
+        Some(0x62c9),
+        Some(0x630c),
+        Some(0x62ee),
+        Some(0x62f1),
+        Some(0x6327),
+        Some(0x6302),
+        Some(0x6308),
+        Some(0x62ef),
+        Some(0x62f5),
+        Some(0x6350),
+        Some(0x633e),
+        Some(0x634d),
+        Some(0x641c),
+        Some(0x634f),
+        Some(0x6396),
+        Some(0x638e),
+        Some(0x6380),
+        Some(0x63ab),
+        Some(0x6376),
+        Some(0x63a3),
+        Some(0x638f),
+        Some(0x6389),
+        Some(0x639f),
+        Some(0x63b5),
+        Some(0x636b),
+        None,
+        Some(0x6369),
+        Some(0x63be),
+        Some(0x63e9),
+        Some(0x63c0),
+        Some(0x63c6),
+        Some(0x63e3),
+        Some(0x63c9),
+        Some(0x63d2),
+        Some(0x63f6),
+        Some(0x63c4),
+        Some(0x6416),
+        Some(0x6434),
+        Some(0x6406),
+        Some(0x6413),
+        Some(0x6426),
+        Some(0x6436),
+        Some(0x651d),
+        Some(0x6417),
+        Some(0x6428),
+        Some(0x640f),
+        Some(0x6467),
+        Some(0x646f),
+        Some(0x6476),
+        Some(0x644e),
+        Some(0x652a),
+        Some(0x6495),
+        Some(0x6493),
+        Some(0x64a5),
+        Some(0x64a9),
+        Some(0x6488),
+        Some(0x64bc),
+        Some(0x64da),
+        Some(0x64d2),
+        Some(0x64c5),
+        Some(0x64c7),
+        Some(0x64bb),
+        Some(0x64d8),
+        Some(0x64c2),
+        Some(0x64f1),
+        Some(0x64e7),
+        Some(0x8209),
+        Some(0x64e0),
+        Some(0x64e1),
+        Some(0x62ac),
+        Some(0x64e3),
+        Some(0x64ef),
+        Some(0x652c),
+        Some(0x64f6),
+        Some(0x64f4),
+        Some(0x64f2),
+        Some(0x64fa),
+        Some(0x6500),
+        Some(0x64fd),
+        Some(0x6518),
+        Some(0x651c),
+        Some(0x6505),
+        Some(0x6524),
+        Some(0x6523),
+        Some(0x652b),
+        Some(0x6534),
+        Some(0x6535),
+        Some(0x6537),
+        Some(0x6536),
+        Some(0x6538),
+        Some(0x754b),
+        Some(0x6548),
+        Some(0x6556),
+        Some(0x6555),
+        Some(0x654d),
+        Some(0x6558),
+        Some(0x655e),
+        Some(0x655d),
+        Some(0x6572),
+        Some(0x6578),
+        Some(0x6582),
+        Some(0x6583),
+        Some(0x8b8a),
+        Some(0x659b),
+        Some(0x659f),
+        Some(0x65ab),
+        Some(0x65b7),
+        Some(0x65c3),
+        Some(0x65c6),
+        Some(0x65c1),
+        Some(0x65c4),
+        Some(0x65cc),
+        Some(0x65d2),
+        Some(0x65db),
+        Some(0x65d9),
+        Some(0x65e0),
+        Some(0x65e1),
+        Some(0x65f1),
+        Some(0x6772),
+        Some(0x660a),
+        Some(0x6603),
+        Some(0x65fb),
+        Some(0x6773),
+        Some(0x6635),
+        Some(0x6636),
+        Some(0x6634),
+        Some(0x661c),
+        Some(0x664f),
+        Some(0x6644),
+        Some(0x6649),
+        Some(0x6641),
+        Some(0x665e),
+        Some(0x665d),
+        Some(0x6664),
+        Some(0x6667),
+        Some(0x6668),
+        Some(0x665f),
+        Some(0x6662),
+        Some(0x6670),
+        Some(0x6683),
+        Some(0x6688),
+        Some(0x668e),
+        Some(0x6689),
+        Some(0x6684),
+        Some(0x6698),
+        Some(0x669d),
+        Some(0x66c1),
+        Some(0x66b9),
+        Some(0x66c9),
+        Some(0x66be),
+        Some(0x66bc),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9E40: [Option<u16>; 192] = [
+        Some(0x66c4),
+        Some(0x66b8),
+        Some(0x66d6),
+        Some(0x66da),
+        Some(0x66e0),
+        Some(0x663f),
+        Some(0x66e6),
+        Some(0x66e9),
+        Some(0x66f0),
+        Some(0x66f5),
+        Some(0x66f7),
+        Some(0x670f),
+        Some(0x6716),
+        Some(0x671e),
+        Some(0x6726),
+        Some(0x6727),
+        Some(0x9738),
+        Some(0x672e),
+        Some(0x673f),
+        Some(0x6736),
+        Some(0x6741),
+        Some(0x6738),
+        Some(0x6737),
+        Some(0x6746),
+        Some(0x675e),
+        Some(0x6760),
+        Some(0x6759),
+        Some(0x6763),
+        Some(0x6764),
+        Some(0x6789),
+        Some(0x6770),
+        Some(0x67a9),
+        Some(0x677c),
+        Some(0x676a),
+        Some(0x678c),
+        Some(0x678b),
+        Some(0x67a6),
+        Some(0x67a1),
+        Some(0x6785),
+        Some(0x67b7),
+        Some(0x67ef),
+        Some(0x67b4),
+        Some(0x67ec),
+        Some(0x67b3),
+        Some(0x67e9),
+        Some(0x67b8),
+        Some(0x67e4),
+        Some(0x67de),
+        Some(0x67dd),
+        Some(0x67e2),
+        Some(0x67ee),
+        Some(0x67b9),
+        Some(0x67ce),
+        Some(0x67c6),
+        Some(0x67e7),
+        Some(0x6a9c),
+        Some(0x681e),
+        Some(0x6846),
+        Some(0x6829),
+        Some(0x6840),
+        Some(0x684d),
+        Some(0x6832),
+        Some(0x684e),
+        None,
+        Some(0x68b3),
+        Some(0x682b),
+        Some(0x6859),
+        Some(0x6863),
+        Some(0x6877),
+        Some(0x687f),
+        Some(0x689f),
+        Some(0x688f),
+        Some(0x68ad),
+        Some(0x6894),
+        Some(0x689d),
+        Some(0x689b),
+        Some(0x6883),
+        Some(0x6aae),
+        Some(0x68b9),
+        Some(0x6874),
+        Some(0x68b5),
+        Some(0x68a0),
+        Some(0x68ba),
+        Some(0x690f),
+        Some(0x688d),
+        Some(0x687e),
+        Some(0x6901),
+        Some(0x68ca),
+        Some(0x6908),
+        Some(0x68d8),
+        Some(0x6922),
+        Some(0x6926),
+        Some(0x68e1),
+        Some(0x690c),
+        Some(0x68cd),
+        Some(0x68d4),
+        Some(0x68e7),
+        Some(0x68d5),
+        Some(0x6936),
+        Some(0x6912),
+        Some(0x6904),
+        Some(0x68d7),
+        Some(0x68e3),
+        Some(0x6925),
+        Some(0x68f9),
+        Some(0x68e0),
+        Some(0x68ef),
+        Some(0x6928),
+        Some(0x692a),
+        Some(0x691a),
+        Some(0x6923),
+        Some(0x6921),
+        Some(0x68c6),
+        Some(0x6979),
+        Some(0x6977),
+        Some(0x695c),
+        Some(0x6978),
+        Some(0x696b),
+        Some(0x6954),
+        Some(0x697e),
+        Some(0x696e),
+        Some(0x6939),
+        Some(0x6974),
+        Some(0x693d),
+        Some(0x6959),
+        Some(0x6930),
+        Some(0x6961),
+        Some(0x695e),
+        Some(0x695d),
+        Some(0x6981),
+        Some(0x696a),
+        Some(0x69b2),
+        Some(0x69ae),
+        Some(0x69d0),
+        Some(0x69bf),
+        Some(0x69c1),
+        Some(0x69d3),
+        Some(0x69be),
+        Some(0x69ce),
+        Some(0x5be8),
+        Some(0x69ca),
+        Some(0x69dd),
+        Some(0x69bb),
+        Some(0x69c3),
+        Some(0x69a7),
+        Some(0x6a2e),
+        Some(0x6991),
+        Some(0x69a0),
+        Some(0x699c),
+        Some(0x6995),
+        Some(0x69b4),
+        Some(0x69de),
+        Some(0x69e8),
+        Some(0x6a02),
+        Some(0x6a1b),
+        Some(0x69ff),
+        Some(0x6b0a),
+        Some(0x69f9),
+        Some(0x69f2),
+        Some(0x69e7),
+        Some(0x6a05),
+        Some(0x69b1),
+        Some(0x6a1e),
+        Some(0x69ed),
+        Some(0x6a14),
+        Some(0x69eb),
+        Some(0x6a0a),
+        Some(0x6a12),
+        Some(0x6ac1),
+        Some(0x6a23),
+        Some(0x6a13),
+        Some(0x6a44),
+        Some(0x6a0c),
+        Some(0x6a72),
+        Some(0x6a36),
+        Some(0x6a78),
+        Some(0x6a47),
+        Some(0x6a62),
+        Some(0x6a59),
+        Some(0x6a66),
+        Some(0x6a48),
+        Some(0x6a38),
+        Some(0x6a22),
+        Some(0x6a90),
+        Some(0x6a8d),
+        Some(0x6aa0),
+        Some(0x6a84),
+        Some(0x6aa2),
+        Some(0x6aa3),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9F40: [Option<u16>; 192] = [
+        Some(0x6a97),
+        Some(0x8617),
+        Some(0x6abb),
+        Some(0x6ac3),
+        Some(0x6ac2),
+        Some(0x6ab8),
+        Some(0x6ab3),
+        Some(0x6aac),
+        Some(0x6ade),
+        Some(0x6ad1),
+        Some(0x6adf),
+        Some(0x6aaa),
+        Some(0x6ada),
+        Some(0x6aea),
+        Some(0x6afb),
+        Some(0x6b05),
+        Some(0x8616),
+        Some(0x6afa),
+        Some(0x6b12),
+        Some(0x6b16),
+        Some(0x9b31),
+        Some(0x6b1f),
+        Some(0x6b38),
+        Some(0x6b37),
+        Some(0x76dc),
+        Some(0x6b39),
+        Some(0x98ee),
+        Some(0x6b47),
+        Some(0x6b43),
+        Some(0x6b49),
+        Some(0x6b50),
+        Some(0x6b59),
+        Some(0x6b54),
+        Some(0x6b5b),
+        Some(0x6b5f),
+        Some(0x6b61),
+        Some(0x6b78),
+        Some(0x6b79),
+        Some(0x6b7f),
+        Some(0x6b80),
+        Some(0x6b84),
+        Some(0x6b83),
+        Some(0x6b8d),
+        Some(0x6b98),
+        Some(0x6b95),
+        Some(0x6b9e),
+        Some(0x6ba4),
+        Some(0x6baa),
+        Some(0x6bab),
+        Some(0x6baf),
+        Some(0x6bb2),
+        Some(0x6bb1),
+        Some(0x6bb3),
+        Some(0x6bb7),
+        Some(0x6bbc),
+        Some(0x6bc6),
+        Some(0x6bcb),
+        Some(0x6bd3),
+        Some(0x6bdf),
+        Some(0x6bec),
+        Some(0x6beb),
+        Some(0x6bf3),
+        Some(0x6bef),
+        None,
+        Some(0x9ebe),
+        Some(0x6c08),
+        Some(0x6c13),
+        Some(0x6c14),
+        Some(0x6c1b),
+        Some(0x6c24),
+        Some(0x6c23),
+        Some(0x6c5e),
+        Some(0x6c55),
+        Some(0x6c62),
+        Some(0x6c6a),
+        Some(0x6c82),
+        Some(0x6c8d),
+        Some(0x6c9a),
+        Some(0x6c81),
+        Some(0x6c9b),
+        Some(0x6c7e),
+        Some(0x6c68),
+        Some(0x6c73),
+        Some(0x6c92),
+        Some(0x6c90),
+        Some(0x6cc4),
+        Some(0x6cf1),
+        Some(0x6cd3),
+        Some(0x6cbd),
+        Some(0x6cd7),
+        Some(0x6cc5),
+        Some(0x6cdd),
+        Some(0x6cae),
+        Some(0x6cb1),
+        Some(0x6cbe),
+        Some(0x6cba),
+        Some(0x6cdb),
+        Some(0x6cef),
+        Some(0x6cd9),
+        Some(0x6cea),
+        Some(0x6d1f),
+        Some(0x884d),
+        Some(0x6d36),
+        Some(0x6d2b),
+        Some(0x6d3d),
+        Some(0x6d38),
+        Some(0x6d19),
+        Some(0x6d35),
+        Some(0x6d33),
+        Some(0x6d12),
+        Some(0x6d0c),
+        Some(0x6d63),
+        Some(0x6d93),
+        Some(0x6d64),
+        Some(0x6d5a),
+        Some(0x6d79),
+        Some(0x6d59),
+        Some(0x6d8e),
+        Some(0x6d95),
+        Some(0x6fe4),
+        Some(0x6d85),
+        Some(0x6df9),
+        Some(0x6e15),
+        Some(0x6e0a),
+        Some(0x6db5),
+        Some(0x6dc7),
+        Some(0x6de6),
+        Some(0x6db8),
+        Some(0x6dc6),
+        Some(0x6dec),
+        Some(0x6dde),
+        Some(0x6dcc),
+        Some(0x6de8),
+        Some(0x6dd2),
+        Some(0x6dc5),
+        Some(0x6dfa),
+        Some(0x6dd9),
+        Some(0x6de4),
+        Some(0x6dd5),
+        Some(0x6dea),
+        Some(0x6dee),
+        Some(0x6e2d),
+        Some(0x6e6e),
+        Some(0x6e2e),
+        Some(0x6e19),
+        Some(0x6e72),
+        Some(0x6e5f),
+        Some(0x6e3e),
+        Some(0x6e23),
+        Some(0x6e6b),
+        Some(0x6e2b),
+        Some(0x6e76),
+        Some(0x6e4d),
+        Some(0x6e1f),
+        Some(0x6e43),
+        Some(0x6e3a),
+        Some(0x6e4e),
+        Some(0x6e24),
+        Some(0x6eff),
+        Some(0x6e1d),
+        Some(0x6e38),
+        Some(0x6e82),
+        Some(0x6eaa),
+        Some(0x6e98),
+        Some(0x6ec9),
+        Some(0x6eb7),
+        Some(0x6ed3),
+        Some(0x6ebd),
+        Some(0x6eaf),
+        Some(0x6ec4),
+        Some(0x6eb2),
+        Some(0x6ed4),
+        Some(0x6ed5),
+        Some(0x6e8f),
+        Some(0x6ea5),
+        Some(0x6ec2),
+        Some(0x6e9f),
+        Some(0x6f41),
+        Some(0x6f11),
+        Some(0x704c),
+        Some(0x6eec),
+        Some(0x6ef8),
+        Some(0x6efe),
+        Some(0x6f3f),
+        Some(0x6ef2),
+        Some(0x6f31),
+        Some(0x6eef),
+        Some(0x6f32),
+        Some(0x6ecc),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE040: [Option<u16>; 192] = [
+        Some(0x6f3e),
+        Some(0x6f13),
+        Some(0x6ef7),
+        Some(0x6f86),
+        Some(0x6f7a),
+        Some(0x6f78),
+        Some(0x6f81),
+        Some(0x6f80),
+        Some(0x6f6f),
+        Some(0x6f5b),
+        Some(0x6ff3),
+        Some(0x6f6d),
+        Some(0x6f82),
+        Some(0x6f7c),
+        Some(0x6f58),
+        Some(0x6f8e),
+        Some(0x6f91),
+        Some(0x6fc2),
+        Some(0x6f66),
+        Some(0x6fb3),
+        Some(0x6fa3),
+        Some(0x6fa1),
+        Some(0x6fa4),
+        Some(0x6fb9),
+        Some(0x6fc6),
+        Some(0x6faa),
+        Some(0x6fdf),
+        Some(0x6fd5),
+        Some(0x6fec),
+        Some(0x6fd4),
+        Some(0x6fd8),
+        Some(0x6ff1),
+        Some(0x6fee),
+        Some(0x6fdb),
+        Some(0x7009),
+        Some(0x700b),
+        Some(0x6ffa),
+        Some(0x7011),
+        Some(0x7001),
+        Some(0x700f),
+        Some(0x6ffe),
+        Some(0x701b),
+        Some(0x701a),
+        Some(0x6f74),
+        Some(0x701d),
+        Some(0x7018),
+        Some(0x701f),
+        Some(0x7030),
+        Some(0x703e),
+        Some(0x7032),
+        Some(0x7051),
+        Some(0x7063),
+        Some(0x7099),
+        Some(0x7092),
+        Some(0x70af),
+        Some(0x70f1),
+        Some(0x70ac),
+        Some(0x70b8),
+        Some(0x70b3),
+        Some(0x70ae),
+        Some(0x70df),
+        Some(0x70cb),
+        Some(0x70dd),
+        None,
+        Some(0x70d9),
+        Some(0x7109),
+        Some(0x70fd),
+        Some(0x711c),
+        Some(0x7119),
+        Some(0x7165),
+        Some(0x7155),
+        Some(0x7188),
+        Some(0x7166),
+        Some(0x7162),
+        Some(0x714c),
+        Some(0x7156),
+        Some(0x716c),
+        Some(0x718f),
+        Some(0x71fb),
+        Some(0x7184),
+        Some(0x7195),
+        Some(0x71a8),
+        Some(0x71ac),
+        Some(0x71d7),
+        Some(0x71b9),
+        Some(0x71be),
+        Some(0x71d2),
+        Some(0x71c9),
+        Some(0x71d4),
+        Some(0x71ce),
+        Some(0x71e0),
+        Some(0x71ec),
+        Some(0x71e7),
+        Some(0x71f5),
+        Some(0x71fc),
+        Some(0x71f9),
+        Some(0x71ff),
+        Some(0x720d),
+        Some(0x7210),
+        Some(0x721b),
+        Some(0x7228),
+        Some(0x722d),
+        Some(0x722c),
+        Some(0x7230),
+        Some(0x7232),
+        Some(0x723b),
+        Some(0x723c),
+        Some(0x723f),
+        Some(0x7240),
+        Some(0x7246),
+        Some(0x724b),
+        Some(0x7258),
+        Some(0x7274),
+        Some(0x727e),
+        Some(0x7282),
+        Some(0x7281),
+        Some(0x7287),
+        Some(0x7292),
+        Some(0x7296),
+        Some(0x72a2),
+        Some(0x72a7),
+        Some(0x72b9),
+        Some(0x72b2),
+        Some(0x72c3),
+        Some(0x72c6),
+        Some(0x72c4),
+        Some(0x72ce),
+        Some(0x72d2),
+        Some(0x72e2),
+        Some(0x72e0),
+        Some(0x72e1),
+        Some(0x72f9),
+        Some(0x72f7),
+        Some(0x500f),
+        Some(0x7317),
+        Some(0x730a),
+        Some(0x731c),
+        Some(0x7316),
+        Some(0x731d),
+        Some(0x7334),
+        Some(0x732f),
+        Some(0x7329),
+        Some(0x7325),
+        Some(0x733e),
+        Some(0x734e),
+        Some(0x734f),
+        Some(0x9ed8),
+        Some(0x7357),
+        Some(0x736a),
+        Some(0x7368),
+        Some(0x7370),
+        Some(0x7378),
+        Some(0x7375),
+        Some(0x737b),
+        Some(0x737a),
+        Some(0x73c8),
+        Some(0x73b3),
+        Some(0x73ce),
+        Some(0x73bb),
+        Some(0x73c0),
+        Some(0x73e5),
+        Some(0x73ee),
+        Some(0x73de),
+        Some(0x74a2),
+        Some(0x7405),
+        Some(0x746f),
+        Some(0x7425),
+        Some(0x73f8),
+        Some(0x7432),
+        Some(0x743a),
+        Some(0x7455),
+        Some(0x743f),
+        Some(0x745f),
+        Some(0x7459),
+        Some(0x7441),
+        Some(0x745c),
+        Some(0x7469),
+        Some(0x7470),
+        Some(0x7463),
+        Some(0x746a),
+        Some(0x7476),
+        Some(0x747e),
+        Some(0x748b),
+        Some(0x749e),
+        Some(0x74a7),
+        Some(0x74ca),
+        Some(0x74cf),
+        Some(0x74d4),
+        Some(0x73f1),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE140: [Option<u16>; 192] = [
+        Some(0x74e0),
+        Some(0x74e3),
+        Some(0x74e7),
+        Some(0x74e9),
+        Some(0x74ee),
+        Some(0x74f2),
+        Some(0x74f0),
+        Some(0x74f1),
+        Some(0x74f8),
+        Some(0x74f7),
+        Some(0x7504),
+        Some(0x7503),
+        Some(0x7505),
+        Some(0x750c),
+        Some(0x750e),
+        Some(0x750d),
+        Some(0x7515),
+        Some(0x7513),
+        Some(0x751e),
+        Some(0x7526),
+        Some(0x752c),
+        Some(0x753c),
+        Some(0x7544),
+        Some(0x754d),
+        Some(0x754a),
+        Some(0x7549),
+        Some(0x755b),
+        Some(0x7546),
+        Some(0x755a),
+        Some(0x7569),
+        Some(0x7564),
+        Some(0x7567),
+        Some(0x756b),
+        Some(0x756d),
+        Some(0x7578),
+        Some(0x7576),
+        Some(0x7586),
+        Some(0x7587),
+        Some(0x7574),
+        Some(0x758a),
+        Some(0x7589),
+        Some(0x7582),
+        Some(0x7594),
+        Some(0x759a),
+        Some(0x759d),
+        Some(0x75a5),
+        Some(0x75a3),
+        Some(0x75c2),
+        Some(0x75b3),
+        Some(0x75c3),
+        Some(0x75b5),
+        Some(0x75bd),
+        Some(0x75b8),
+        Some(0x75bc),
+        Some(0x75b1),
+        Some(0x75cd),
+        Some(0x75ca),
+        Some(0x75d2),
+        Some(0x75d9),
+        Some(0x75e3),
+        Some(0x75de),
+        Some(0x75fe),
+        Some(0x75ff),
+        None,
+        Some(0x75fc),
+        Some(0x7601),
+        Some(0x75f0),
+        Some(0x75fa),
+        Some(0x75f2),
+        Some(0x75f3),
+        Some(0x760b),
+        Some(0x760d),
+        Some(0x7609),
+        Some(0x761f),
+        Some(0x7627),
+        Some(0x7620),
+        Some(0x7621),
+        Some(0x7622),
+        Some(0x7624),
+        Some(0x7634),
+        Some(0x7630),
+        Some(0x763b),
+        Some(0x7647),
+        Some(0x7648),
+        Some(0x7646),
+        Some(0x765c),
+        Some(0x7658),
+        Some(0x7661),
+        Some(0x7662),
+        Some(0x7668),
+        Some(0x7669),
+        Some(0x766a),
+        Some(0x7667),
+        Some(0x766c),
+        Some(0x7670),
+        Some(0x7672),
+        Some(0x7676),
+        Some(0x7678),
+        Some(0x767c),
+        Some(0x7680),
+        Some(0x7683),
+        Some(0x7688),
+        Some(0x768b),
+        Some(0x768e),
+        Some(0x7696),
+        Some(0x7693),
+        Some(0x7699),
+        Some(0x769a),
+        Some(0x76b0),
+        Some(0x76b4),
+        Some(0x76b8),
+        Some(0x76b9),
+        Some(0x76ba),
+        Some(0x76c2),
+        Some(0x76cd),
+        Some(0x76d6),
+        Some(0x76d2),
+        Some(0x76de),
+        Some(0x76e1),
+        Some(0x76e5),
+        Some(0x76e7),
+        Some(0x76ea),
+        Some(0x862f),
+        Some(0x76fb),
+        Some(0x7708),
+        Some(0x7707),
+        Some(0x7704),
+        Some(0x7729),
+        Some(0x7724),
+        Some(0x771e),
+        Some(0x7725),
+        Some(0x7726),
+        Some(0x771b),
+        Some(0x7737),
+        Some(0x7738),
+        Some(0x7747),
+        Some(0x775a),
+        Some(0x7768),
+        Some(0x776b),
+        Some(0x775b),
+        Some(0x7765),
+        Some(0x777f),
+        Some(0x777e),
+        Some(0x7779),
+        Some(0x778e),
+        Some(0x778b),
+        Some(0x7791),
+        Some(0x77a0),
+        Some(0x779e),
+        Some(0x77b0),
+        Some(0x77b6),
+        Some(0x77b9),
+        Some(0x77bf),
+        Some(0x77bc),
+        Some(0x77bd),
+        Some(0x77bb),
+        Some(0x77c7),
+        Some(0x77cd),
+        Some(0x77d7),
+        Some(0x77da),
+        Some(0x77dc),
+        Some(0x77e3),
+        Some(0x77ee),
+        Some(0x77fc),
+        Some(0x780c),
+        Some(0x7812),
+        Some(0x7926),
+        Some(0x7820),
+        Some(0x792a),
+        Some(0x7845),
+        Some(0x788e),
+        Some(0x7874),
+        Some(0x7886),
+        Some(0x787c),
+        Some(0x789a),
+        Some(0x788c),
+        Some(0x78a3),
+        Some(0x78b5),
+        Some(0x78aa),
+        Some(0x78af),
+        Some(0x78d1),
+        Some(0x78c6),
+        Some(0x78cb),
+        Some(0x78d4),
+        Some(0x78be),
+        Some(0x78bc),
+        Some(0x78c5),
+        Some(0x78ca),
+        Some(0x78ec),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE240: [Option<u16>; 192] = [
+        Some(0x78e7),
+        Some(0x78da),
+        Some(0x78fd),
+        Some(0x78f4),
+        Some(0x7907),
+        Some(0x7912),
+        Some(0x7911),
+        Some(0x7919),
+        Some(0x792c),
+        Some(0x792b),
+        Some(0x7940),
+        Some(0x7960),
+        Some(0x7957),
+        Some(0x795f),
+        Some(0x795a),
+        Some(0x7955),
+        Some(0x7953),
+        Some(0x797a),
+        Some(0x797f),
+        Some(0x798a),
+        Some(0x799d),
+        Some(0x79a7),
+        Some(0x9f4b),
+        Some(0x79aa),
+        Some(0x79ae),
+        Some(0x79b3),
+        Some(0x79b9),
+        Some(0x79ba),
+        Some(0x79c9),
+        Some(0x79d5),
+        Some(0x79e7),
+        Some(0x79ec),
+        Some(0x79e1),
+        Some(0x79e3),
+        Some(0x7a08),
+        Some(0x7a0d),
+        Some(0x7a18),
+        Some(0x7a19),
+        Some(0x7a20),
+        Some(0x7a1f),
+        Some(0x7980),
+        Some(0x7a31),
+        Some(0x7a3b),
+        Some(0x7a3e),
+        Some(0x7a37),
+        Some(0x7a43),
+        Some(0x7a57),
+        Some(0x7a49),
+        Some(0x7a61),
+        Some(0x7a62),
+        Some(0x7a69),
+        Some(0x9f9d),
+        Some(0x7a70),
+        Some(0x7a79),
+        Some(0x7a7d),
+        Some(0x7a88),
+        Some(0x7a97),
+        Some(0x7a95),
+        Some(0x7a98),
+        Some(0x7a96),
+        Some(0x7aa9),
+        Some(0x7ac8),
+        Some(0x7ab0),
+        None,
+        Some(0x7ab6),
+        Some(0x7ac5),
+        Some(0x7ac4),
+        Some(0x7abf),
+        Some(0x9083),
+        Some(0x7ac7),
+        Some(0x7aca),
+        Some(0x7acd),
+        Some(0x7acf),
+        Some(0x7ad5),
+        Some(0x7ad3),
+        Some(0x7ad9),
+        Some(0x7ada),
+        Some(0x7add),
+        Some(0x7ae1),
+        Some(0x7ae2),
+        Some(0x7ae6),
+        Some(0x7aed),
+        Some(0x7af0),
+        Some(0x7b02),
+        Some(0x7b0f),
+        Some(0x7b0a),
+        Some(0x7b06),
+        Some(0x7b33),
+        Some(0x7b18),
+        Some(0x7b19),
+        Some(0x7b1e),
+        Some(0x7b35),
+        Some(0x7b28),
+        Some(0x7b36),
+        Some(0x7b50),
+        Some(0x7b7a),
+        Some(0x7b04),
+        Some(0x7b4d),
+        Some(0x7b0b),
+        Some(0x7b4c),
+        Some(0x7b45),
+        Some(0x7b75),
+        Some(0x7b65),
+        Some(0x7b74),
+        Some(0x7b67),
+        Some(0x7b70),
+        Some(0x7b71),
+        Some(0x7b6c),
+        Some(0x7b6e),
+        Some(0x7b9d),
+        Some(0x7b98),
+        Some(0x7b9f),
+        Some(0x7b8d),
+        Some(0x7b9c),
+        Some(0x7b9a),
+        Some(0x7b8b),
+        Some(0x7b92),
+        Some(0x7b8f),
+        Some(0x7b5d),
+        Some(0x7b99),
+        Some(0x7bcb),
+        Some(0x7bc1),
+        Some(0x7bcc),
+        Some(0x7bcf),
+        Some(0x7bb4),
+        Some(0x7bc6),
+        Some(0x7bdd),
+        Some(0x7be9),
+        Some(0x7c11),
+        Some(0x7c14),
+        Some(0x7be6),
+        Some(0x7be5),
+        Some(0x7c60),
+        Some(0x7c00),
+        Some(0x7c07),
+        Some(0x7c13),
+        Some(0x7bf3),
+        Some(0x7bf7),
+        Some(0x7c17),
+        Some(0x7c0d),
+        Some(0x7bf6),
+        Some(0x7c23),
+        Some(0x7c27),
+        Some(0x7c2a),
+        Some(0x7c1f),
+        Some(0x7c37),
+        Some(0x7c2b),
+        Some(0x7c3d),
+        Some(0x7c4c),
+        Some(0x7c43),
+        Some(0x7c54),
+        Some(0x7c4f),
+        Some(0x7c40),
+        Some(0x7c50),
+        Some(0x7c58),
+        Some(0x7c5f),
+        Some(0x7c64),
+        Some(0x7c56),
+        Some(0x7c65),
+        Some(0x7c6c),
+        Some(0x7c75),
+        Some(0x7c83),
+        Some(0x7c90),
+        Some(0x7ca4),
+        Some(0x7cad),
+        Some(0x7ca2),
+        Some(0x7cab),
+        Some(0x7ca1),
+        Some(0x7ca8),
+        Some(0x7cb3),
+        Some(0x7cb2),
+        Some(0x7cb1),
+        Some(0x7cae),
+        Some(0x7cb9),
+        Some(0x7cbd),
+        Some(0x7cc0),
+        Some(0x7cc5),
+        Some(0x7cc2),
+        Some(0x7cd8),
+        Some(0x7cd2),
+        Some(0x7cdc),
+        Some(0x7ce2),
+        Some(0x9b3b),
+        Some(0x7cef),
+        Some(0x7cf2),
+        Some(0x7cf4),
+        Some(0x7cf6),
+        Some(0x7cfa),
+        Some(0x7d06),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE340: [Option<u16>; 192] = [
+        Some(0x7d02),
+        Some(0x7d1c),
+        Some(0x7d15),
+        Some(0x7d0a),
+        Some(0x7d45),
+        Some(0x7d4b),
+        Some(0x7d2e),
+        Some(0x7d32),
+        Some(0x7d3f),
+        Some(0x7d35),
+        Some(0x7d46),
+        Some(0x7d73),
+        Some(0x7d56),
+        Some(0x7d4e),
+        Some(0x7d72),
+        Some(0x7d68),
+        Some(0x7d6e),
+        Some(0x7d4f),
+        Some(0x7d63),
+        Some(0x7d93),
+        Some(0x7d89),
+        Some(0x7d5b),
+        Some(0x7d8f),
+        Some(0x7d7d),
+        Some(0x7d9b),
+        Some(0x7dba),
+        Some(0x7dae),
+        Some(0x7da3),
+        Some(0x7db5),
+        Some(0x7dc7),
+        Some(0x7dbd),
+        Some(0x7dab),
+        Some(0x7e3d),
+        Some(0x7da2),
+        Some(0x7daf),
+        Some(0x7ddc),
+        Some(0x7db8),
+        Some(0x7d9f),
+        Some(0x7db0),
+        Some(0x7dd8),
+        Some(0x7ddd),
+        Some(0x7de4),
+        Some(0x7dde),
+        Some(0x7dfb),
+        Some(0x7df2),
+        Some(0x7de1),
+        Some(0x7e05),
+        Some(0x7e0a),
+        Some(0x7e23),
+        Some(0x7e21),
+        Some(0x7e12),
+        Some(0x7e31),
+        Some(0x7e1f),
+        Some(0x7e09),
+        Some(0x7e0b),
+        Some(0x7e22),
+        Some(0x7e46),
+        Some(0x7e66),
+        Some(0x7e3b),
+        Some(0x7e35),
+        Some(0x7e39),
+        Some(0x7e43),
+        Some(0x7e37),
+        None,
+        Some(0x7e32),
+        Some(0x7e3a),
+        Some(0x7e67),
+        Some(0x7e5d),
+        Some(0x7e56),
+        Some(0x7e5e),
+        Some(0x7e59),
+        Some(0x7e5a),
+        Some(0x7e79),
+        Some(0x7e6a),
+        Some(0x7e69),
+        Some(0x7e7c),
+        Some(0x7e7b),
+        Some(0x7e83),
+        Some(0x7dd5),
+        Some(0x7e7d),
+        Some(0x8fae),
+        Some(0x7e7f),
+        Some(0x7e88),
+        Some(0x7e89),
+        Some(0x7e8c),
+        Some(0x7e92),
+        Some(0x7e90),
+        Some(0x7e93),
+        Some(0x7e94),
+        Some(0x7e96),
+        Some(0x7e8e),
+        Some(0x7e9b),
+        Some(0x7e9c),
+        Some(0x7f38),
+        Some(0x7f3a),
+        Some(0x7f45),
+        Some(0x7f4c),
+        Some(0x7f4d),
+        Some(0x7f4e),
+        Some(0x7f50),
+        Some(0x7f51),
+        Some(0x7f55),
+        Some(0x7f54),
+        Some(0x7f58),
+        Some(0x7f5f),
+        Some(0x7f60),
+        Some(0x7f68),
+        Some(0x7f69),
+        Some(0x7f67),
+        Some(0x7f78),
+        Some(0x7f82),
+        Some(0x7f86),
+        Some(0x7f83),
+        Some(0x7f88),
+        Some(0x7f87),
+        Some(0x7f8c),
+        Some(0x7f94),
+        Some(0x7f9e),
+        Some(0x7f9d),
+        Some(0x7f9a),
+        Some(0x7fa3),
+        Some(0x7faf),
+        Some(0x7fb2),
+        Some(0x7fb9),
+        Some(0x7fae),
+        Some(0x7fb6),
+        Some(0x7fb8),
+        Some(0x8b71),
+        Some(0x7fc5),
+        Some(0x7fc6),
+        Some(0x7fca),
+        Some(0x7fd5),
+        Some(0x7fd4),
+        Some(0x7fe1),
+        Some(0x7fe6),
+        Some(0x7fe9),
+        Some(0x7ff3),
+        Some(0x7ff9),
+        Some(0x98dc),
+        Some(0x8006),
+        Some(0x8004),
+        Some(0x800b),
+        Some(0x8012),
+        Some(0x8018),
+        Some(0x8019),
+        Some(0x801c),
+        Some(0x8021),
+        Some(0x8028),
+        Some(0x803f),
+        Some(0x803b),
+        Some(0x804a),
+        Some(0x8046),
+        Some(0x8052),
+        Some(0x8058),
+        Some(0x805a),
+        Some(0x805f),
+        Some(0x8062),
+        Some(0x8068),
+        Some(0x8073),
+        Some(0x8072),
+        Some(0x8070),
+        Some(0x8076),
+        Some(0x8079),
+        Some(0x807d),
+        Some(0x807f),
+        Some(0x8084),
+        Some(0x8086),
+        Some(0x8085),
+        Some(0x809b),
+        Some(0x8093),
+        Some(0x809a),
+        Some(0x80ad),
+        Some(0x5190),
+        Some(0x80ac),
+        Some(0x80db),
+        Some(0x80e5),
+        Some(0x80d9),
+        Some(0x80dd),
+        Some(0x80c4),
+        Some(0x80da),
+        Some(0x80d6),
+        Some(0x8109),
+        Some(0x80ef),
+        Some(0x80f1),
+        Some(0x811b),
+        Some(0x8129),
+        Some(0x8123),
+        Some(0x812f),
+        Some(0x814b),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE440: [Option<u16>; 192] = [
+        Some(0x968b),
+        Some(0x8146),
+        Some(0x813e),
+        Some(0x8153),
+        Some(0x8151),
+        Some(0x80fc),
+        Some(0x8171),
+        Some(0x816e),
+        Some(0x8165),
+        Some(0x8166),
+        Some(0x8174),
+        Some(0x8183),
+        Some(0x8188),
+        Some(0x818a),
+        Some(0x8180),
+        Some(0x8182),
+        Some(0x81a0),
+        Some(0x8195),
+        Some(0x81a4),
+        Some(0x81a3),
+        Some(0x815f),
+        Some(0x8193),
+        Some(0x81a9),
+        Some(0x81b0),
+        Some(0x81b5),
+        Some(0x81be),
+        Some(0x81b8),
+        Some(0x81bd),
+        Some(0x81c0),
+        Some(0x81c2),
+        Some(0x81ba),
+        Some(0x81c9),
+        Some(0x81cd),
+        Some(0x81d1),
+        Some(0x81d9),
+        Some(0x81d8),
+        Some(0x81c8),
+        Some(0x81da),
+        Some(0x81df),
+        Some(0x81e0),
+        Some(0x81e7),
+        Some(0x81fa),
+        Some(0x81fb),
+        Some(0x81fe),
+        Some(0x8201),
+        Some(0x8202),
+        Some(0x8205),
+        Some(0x8207),
+        Some(0x820a),
+        Some(0x820d),
+        Some(0x8210),
+        Some(0x8216),
+        Some(0x8229),
+        Some(0x822b),
+        Some(0x8238),
+        Some(0x8233),
+        Some(0x8240),
+        Some(0x8259),
+        Some(0x8258),
+        Some(0x825d),
+        Some(0x825a),
+        Some(0x825f),
+        Some(0x8264),
+        None,
+        Some(0x8262),
+        Some(0x8268),
+        Some(0x826a),
+        Some(0x826b),
+        Some(0x822e),
+        Some(0x8271),
+        Some(0x8277),
+        Some(0x8278),
+        Some(0x827e),
+        Some(0x828d),
+        Some(0x8292),
+        Some(0x82ab),
+        Some(0x829f),
+        Some(0x82bb),
+        Some(0x82ac),
+        Some(0x82e1),
+        Some(0x82e3),
+        Some(0x82df),
+        Some(0x82d2),
+        Some(0x82f4),
+        Some(0x82f3),
+        Some(0x82fa),
+        Some(0x8393),
+        Some(0x8303),
+        Some(0x82fb),
+        Some(0x82f9),
+        Some(0x82de),
+        Some(0x8306),
+        Some(0x82dc),
+        Some(0x8309),
+        Some(0x82d9),
+        Some(0x8335),
+        Some(0x8334),
+        Some(0x8316),
+        Some(0x8332),
+        Some(0x8331),
+        Some(0x8340),
+        Some(0x8339),
+        Some(0x8350),
+        Some(0x8345),
+        Some(0x832f),
+        Some(0x832b),
+        Some(0x8317),
+        Some(0x8318),
+        Some(0x8385),
+        Some(0x839a),
+        Some(0x83aa),
+        Some(0x839f),
+        Some(0x83a2),
+        Some(0x8396),
+        Some(0x8323),
+        Some(0x838e),
+        Some(0x8387),
+        Some(0x838a),
+        Some(0x837c),
+        Some(0x83b5),
+        Some(0x8373),
+        Some(0x8375),
+        Some(0x83a0),
+        Some(0x8389),
+        Some(0x83a8),
+        Some(0x83f4),
+        Some(0x8413),
+        Some(0x83eb),
+        Some(0x83ce),
+        Some(0x83fd),
+        Some(0x8403),
+        Some(0x83d8),
+        Some(0x840b),
+        Some(0x83c1),
+        Some(0x83f7),
+        Some(0x8407),
+        Some(0x83e0),
+        Some(0x83f2),
+        Some(0x840d),
+        Some(0x8422),
+        Some(0x8420),
+        Some(0x83bd),
+        Some(0x8438),
+        Some(0x8506),
+        Some(0x83fb),
+        Some(0x846d),
+        Some(0x842a),
+        Some(0x843c),
+        Some(0x855a),
+        Some(0x8484),
+        Some(0x8477),
+        Some(0x846b),
+        Some(0x84ad),
+        Some(0x846e),
+        Some(0x8482),
+        Some(0x8469),
+        Some(0x8446),
+        Some(0x842c),
+        Some(0x846f),
+        Some(0x8479),
+        Some(0x8435),
+        Some(0x84ca),
+        Some(0x8462),
+        Some(0x84b9),
+        Some(0x84bf),
+        Some(0x849f),
+        Some(0x84d9),
+        Some(0x84cd),
+        Some(0x84bb),
+        Some(0x84da),
+        Some(0x84d0),
+        Some(0x84c1),
+        Some(0x84c6),
+        Some(0x84d6),
+        Some(0x84a1),
+        Some(0x8521),
+        Some(0x84ff),
+        Some(0x84f4),
+        Some(0x8517),
+        Some(0x8518),
+        Some(0x852c),
+        Some(0x851f),
+        Some(0x8515),
+        Some(0x8514),
+        Some(0x84fc),
+        Some(0x8540),
+        Some(0x8563),
+        Some(0x8558),
+        Some(0x8548),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE540: [Option<u16>; 192] = [
+        Some(0x8541),
+        Some(0x8602),
+        Some(0x854b),
+        Some(0x8555),
+        Some(0x8580),
+        Some(0x85a4),
+        Some(0x8588),
+        Some(0x8591),
+        Some(0x858a),
+        Some(0x85a8),
+        Some(0x856d),
+        Some(0x8594),
+        Some(0x859b),
+        Some(0x85ea),
+        Some(0x8587),
+        Some(0x859c),
+        Some(0x8577),
+        Some(0x857e),
+        Some(0x8590),
+        Some(0x85c9),
+        Some(0x85ba),
+        Some(0x85cf),
+        Some(0x85b9),
+        Some(0x85d0),
+        Some(0x85d5),
+        Some(0x85dd),
+        Some(0x85e5),
+        Some(0x85dc),
+        Some(0x85f9),
+        Some(0x860a),
+        Some(0x8613),
+        Some(0x860b),
+        Some(0x85fe),
+        Some(0x85fa),
+        Some(0x8606),
+        Some(0x8622),
+        Some(0x861a),
+        Some(0x8630),
+        Some(0x863f),
+        Some(0x864d),
+        Some(0x4e55),
+        Some(0x8654),
+        Some(0x865f),
+        Some(0x8667),
+        Some(0x8671),
+        Some(0x8693),
+        Some(0x86a3),
+        Some(0x86a9),
+        Some(0x86aa),
+        Some(0x868b),
+        Some(0x868c),
+        Some(0x86b6),
+        Some(0x86af),
+        Some(0x86c4),
+        Some(0x86c6),
+        Some(0x86b0),
+        Some(0x86c9),
+        Some(0x8823),
+        Some(0x86ab),
+        Some(0x86d4),
+        Some(0x86de),
+        Some(0x86e9),
+        Some(0x86ec),
+        None,
+        Some(0x86df),
+        Some(0x86db),
+        Some(0x86ef),
+        Some(0x8712),
+        Some(0x8706),
+        Some(0x8708),
+        Some(0x8700),
+        Some(0x8703),
+        Some(0x86fb),
+        Some(0x8711),
+        Some(0x8709),
+        Some(0x870d),
+        Some(0x86f9),
+        Some(0x870a),
+        Some(0x8734),
+        Some(0x873f),
+        Some(0x8737),
+        Some(0x873b),
+        Some(0x8725),
+        Some(0x8729),
+        Some(0x871a),
+        Some(0x8760),
+        Some(0x875f),
+        Some(0x8778),
+        Some(0x874c),
+        Some(0x874e),
+        Some(0x8774),
+        Some(0x8757),
+        Some(0x8768),
+        Some(0x876e),
+        Some(0x8759),
+        Some(0x8753),
+        Some(0x8763),
+        Some(0x876a),
+        Some(0x8805),
+        Some(0x87a2),
+        Some(0x879f),
+        Some(0x8782),
+        Some(0x87af),
+        Some(0x87cb),
+        Some(0x87bd),
+        Some(0x87c0),
+        Some(0x87d0),
+        Some(0x96d6),
+        Some(0x87ab),
+        Some(0x87c4),
+        Some(0x87b3),
+        Some(0x87c7),
+        Some(0x87c6),
+        Some(0x87bb),
+        Some(0x87ef),
+        Some(0x87f2),
+        Some(0x87e0),
+        Some(0x880f),
+        Some(0x880d),
+        Some(0x87fe),
+        Some(0x87f6),
+        Some(0x87f7),
+        Some(0x880e),
+        Some(0x87d2),
+        Some(0x8811),
+        Some(0x8816),
+        Some(0x8815),
+        Some(0x8822),
+        Some(0x8821),
+        Some(0x8831),
+        Some(0x8836),
+        Some(0x8839),
+        Some(0x8827),
+        Some(0x883b),
+        Some(0x8844),
+        Some(0x8842),
+        Some(0x8852),
+        Some(0x8859),
+        Some(0x885e),
+        Some(0x8862),
+        Some(0x886b),
+        Some(0x8881),
+        Some(0x887e),
+        Some(0x889e),
+        Some(0x8875),
+        Some(0x887d),
+        Some(0x88b5),
+        Some(0x8872),
+        Some(0x8882),
+        Some(0x8897),
+        Some(0x8892),
+        Some(0x88ae),
+        Some(0x8899),
+        Some(0x88a2),
+        Some(0x888d),
+        Some(0x88a4),
+        Some(0x88b0),
+        Some(0x88bf),
+        Some(0x88b1),
+        Some(0x88c3),
+        Some(0x88c4),
+        Some(0x88d4),
+        Some(0x88d8),
+        Some(0x88d9),
+        Some(0x88dd),
+        Some(0x88f9),
+        Some(0x8902),
+        Some(0x88fc),
+        Some(0x88f4),
+        Some(0x88e8),
+        Some(0x88f2),
+        Some(0x8904),
+        Some(0x890c),
+        Some(0x890a),
+        Some(0x8913),
+        Some(0x8943),
+        Some(0x891e),
+        Some(0x8925),
+        Some(0x892a),
+        Some(0x892b),
+        Some(0x8941),
+        Some(0x8944),
+        Some(0x893b),
+        Some(0x8936),
+        Some(0x8938),
+        Some(0x894c),
+        Some(0x891d),
+        Some(0x8960),
+        Some(0x895e),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE640: [Option<u16>; 192] = [
+        Some(0x8966),
+        Some(0x8964),
+        Some(0x896d),
+        Some(0x896a),
+        Some(0x896f),
+        Some(0x8974),
+        Some(0x8977),
+        Some(0x897e),
+        Some(0x8983),
+        Some(0x8988),
+        Some(0x898a),
+        Some(0x8993),
+        Some(0x8998),
+        Some(0x89a1),
+        Some(0x89a9),
+        Some(0x89a6),
+        Some(0x89ac),
+        Some(0x89af),
+        Some(0x89b2),
+        Some(0x89ba),
+        Some(0x89bd),
+        Some(0x89bf),
+        Some(0x89c0),
+        Some(0x89da),
+        Some(0x89dc),
+        Some(0x89dd),
+        Some(0x89e7),
+        Some(0x89f4),
+        Some(0x89f8),
+        Some(0x8a03),
+        Some(0x8a16),
+        Some(0x8a10),
+        Some(0x8a0c),
+        Some(0x8a1b),
+        Some(0x8a1d),
+        Some(0x8a25),
+        Some(0x8a36),
+        Some(0x8a41),
+        Some(0x8a5b),
+        Some(0x8a52),
+        Some(0x8a46),
+        Some(0x8a48),
+        Some(0x8a7c),
+        Some(0x8a6d),
+        Some(0x8a6c),
+        Some(0x8a62),
+        Some(0x8a85),
+        Some(0x8a82),
+        Some(0x8a84),
+        Some(0x8aa8),
+        Some(0x8aa1),
+        Some(0x8a91),
+        Some(0x8aa5),
+        Some(0x8aa6),
+        Some(0x8a9a),
+        Some(0x8aa3),
+        Some(0x8ac4),
+        Some(0x8acd),
+        Some(0x8ac2),
+        Some(0x8ada),
+        Some(0x8aeb),
+        Some(0x8af3),
+        Some(0x8ae7),
+        None,
+        Some(0x8ae4),
+        Some(0x8af1),
+        Some(0x8b14),
+        Some(0x8ae0),
+        Some(0x8ae2),
+        Some(0x8af7),
+        Some(0x8ade),
+        Some(0x8adb),
+        Some(0x8b0c),
+        Some(0x8b07),
+        Some(0x8b1a),
+        Some(0x8ae1),
+        Some(0x8b16),
+        Some(0x8b10),
+        Some(0x8b17),
+        Some(0x8b20),
+        Some(0x8b33),
+        Some(0x97ab),
+        Some(0x8b26),
+        Some(0x8b2b),
+        Some(0x8b3e),
+        Some(0x8b28),
+        Some(0x8b41),
+        Some(0x8b4c),
+        Some(0x8b4f),
+        Some(0x8b4e),
+        Some(0x8b49),
+        Some(0x8b56),
+        Some(0x8b5b),
+        Some(0x8b5a),
+        Some(0x8b6b),
+        Some(0x8b5f),
+        Some(0x8b6c),
+        Some(0x8b6f),
+        Some(0x8b74),
+        Some(0x8b7d),
+        Some(0x8b80),
+        Some(0x8b8c),
+        Some(0x8b8e),
+        Some(0x8b92),
+        Some(0x8b93),
+        Some(0x8b96),
+        Some(0x8b99),
+        Some(0x8b9a),
+        Some(0x8c3a),
+        Some(0x8c41),
+        Some(0x8c3f),
+        Some(0x8c48),
+        Some(0x8c4c),
+        Some(0x8c4e),
+        Some(0x8c50),
+        Some(0x8c55),
+        Some(0x8c62),
+        Some(0x8c6c),
+        Some(0x8c78),
+        Some(0x8c7a),
+        Some(0x8c82),
+        Some(0x8c89),
+        Some(0x8c85),
+        Some(0x8c8a),
+        Some(0x8c8d),
+        Some(0x8c8e),
+        Some(0x8c94),
+        Some(0x8c7c),
+        Some(0x8c98),
+        Some(0x621d),
+        Some(0x8cad),
+        Some(0x8caa),
+        Some(0x8cbd),
+        Some(0x8cb2),
+        Some(0x8cb3),
+        Some(0x8cae),
+        Some(0x8cb6),
+        Some(0x8cc8),
+        Some(0x8cc1),
+        Some(0x8ce4),
+        Some(0x8ce3),
+        Some(0x8cda),
+        Some(0x8cfd),
+        Some(0x8cfa),
+        Some(0x8cfb),
+        Some(0x8d04),
+        Some(0x8d05),
+        Some(0x8d0a),
+        Some(0x8d07),
+        Some(0x8d0f),
+        Some(0x8d0d),
+        Some(0x8d10),
+        Some(0x9f4e),
+        Some(0x8d13),
+        Some(0x8ccd),
+        Some(0x8d14),
+        Some(0x8d16),
+        Some(0x8d67),
+        Some(0x8d6d),
+        Some(0x8d71),
+        Some(0x8d73),
+        Some(0x8d81),
+        Some(0x8d99),
+        Some(0x8dc2),
+        Some(0x8dbe),
+        Some(0x8dba),
+        Some(0x8dcf),
+        Some(0x8dda),
+        Some(0x8dd6),
+        Some(0x8dcc),
+        Some(0x8ddb),
+        Some(0x8dcb),
+        Some(0x8dea),
+        Some(0x8deb),
+        Some(0x8ddf),
+        Some(0x8de3),
+        Some(0x8dfc),
+        Some(0x8e08),
+        Some(0x8e09),
+        Some(0x8dff),
+        Some(0x8e1d),
+        Some(0x8e1e),
+        Some(0x8e10),
+        Some(0x8e1f),
+        Some(0x8e42),
+        Some(0x8e35),
+        Some(0x8e30),
+        Some(0x8e34),
+        Some(0x8e4a),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE740: [Option<u16>; 192] = [
+        Some(0x8e47),
+        Some(0x8e49),
+        Some(0x8e4c),
+        Some(0x8e50),
+        Some(0x8e48),
+        Some(0x8e59),
+        Some(0x8e64),
+        Some(0x8e60),
+        Some(0x8e2a),
+        Some(0x8e63),
+        Some(0x8e55),
+        Some(0x8e76),
+        Some(0x8e72),
+        Some(0x8e7c),
+        Some(0x8e81),
+        Some(0x8e87),
+        Some(0x8e85),
+        Some(0x8e84),
+        Some(0x8e8b),
+        Some(0x8e8a),
+        Some(0x8e93),
+        Some(0x8e91),
+        Some(0x8e94),
+        Some(0x8e99),
+        Some(0x8eaa),
+        Some(0x8ea1),
+        Some(0x8eac),
+        Some(0x8eb0),
+        Some(0x8ec6),
+        Some(0x8eb1),
+        Some(0x8ebe),
+        Some(0x8ec5),
+        Some(0x8ec8),
+        Some(0x8ecb),
+        Some(0x8edb),
+        Some(0x8ee3),
+        Some(0x8efc),
+        Some(0x8efb),
+        Some(0x8eeb),
+        Some(0x8efe),
+        Some(0x8f0a),
+        Some(0x8f05),
+        Some(0x8f15),
+        Some(0x8f12),
+        Some(0x8f19),
+        Some(0x8f13),
+        Some(0x8f1c),
+        Some(0x8f1f),
+        Some(0x8f1b),
+        Some(0x8f0c),
+        Some(0x8f26),
+        Some(0x8f33),
+        Some(0x8f3b),
+        Some(0x8f39),
+        Some(0x8f45),
+        Some(0x8f42),
+        Some(0x8f3e),
+        Some(0x8f4c),
+        Some(0x8f49),
+        Some(0x8f46),
+        Some(0x8f4e),
+        Some(0x8f57),
+        Some(0x8f5c),
+        None,
+        Some(0x8f62),
+        Some(0x8f63),
+        Some(0x8f64),
+        Some(0x8f9c),
+        Some(0x8f9f),
+        Some(0x8fa3),
+        Some(0x8fad),
+        Some(0x8faf),
+        Some(0x8fb7),
+        Some(0x8fda),
+        Some(0x8fe5),
+        Some(0x8fe2),
+        Some(0x8fea),
+        Some(0x8fef),
+        Some(0x9087),
+        Some(0x8ff4),
+        Some(0x9005),
+        Some(0x8ff9),
+        Some(0x8ffa),
+        Some(0x9011),
+        Some(0x9015),
+        Some(0x9021),
+        Some(0x900d),
+        Some(0x901e),
+        Some(0x9016),
+        Some(0x900b),
+        Some(0x9027),
+        Some(0x9036),
+        Some(0x9035),
+        Some(0x9039),
+        Some(0x8ff8),
+        Some(0x904f),
+        Some(0x9050),
+        Some(0x9051),
+        Some(0x9052),
+        Some(0x900e),
+        Some(0x9049),
+        Some(0x903e),
+        Some(0x9056),
+        Some(0x9058),
+        Some(0x905e),
+        Some(0x9068),
+        Some(0x906f),
+        Some(0x9076),
+        Some(0x96a8),
+        Some(0x9072),
+        Some(0x9082),
+        Some(0x907d),
+        Some(0x9081),
+        Some(0x9080),
+        Some(0x908a),
+        Some(0x9089),
+        Some(0x908f),
+        Some(0x90a8),
+        Some(0x90af),
+        Some(0x90b1),
+        Some(0x90b5),
+        Some(0x90e2),
+        Some(0x90e4),
+        Some(0x6248),
+        Some(0x90db),
+        Some(0x9102),
+        Some(0x9112),
+        Some(0x9119),
+        Some(0x9132),
+        Some(0x9130),
+        Some(0x914a),
+        Some(0x9156),
+        Some(0x9158),
+        Some(0x9163),
+        Some(0x9165),
+        Some(0x9169),
+        Some(0x9173),
+        Some(0x9172),
+        Some(0x918b),
+        Some(0x9189),
+        Some(0x9182),
+        Some(0x91a2),
+        Some(0x91ab),
+        Some(0x91af),
+        Some(0x91aa),
+        Some(0x91b5),
+        Some(0x91b4),
+        Some(0x91ba),
+        Some(0x91c0),
+        Some(0x91c1),
+        Some(0x91c9),
+        Some(0x91cb),
+        Some(0x91d0),
+        Some(0x91d6),
+        Some(0x91df),
+        Some(0x91e1),
+        Some(0x91db),
+        Some(0x91fc),
+        Some(0x91f5),
+        Some(0x91f6),
+        Some(0x921e),
+        Some(0x91ff),
+        Some(0x9214),
+        Some(0x922c),
+        Some(0x9215),
+        Some(0x9211),
+        Some(0x925e),
+        Some(0x9257),
+        Some(0x9245),
+        Some(0x9249),
+        Some(0x9264),
+        Some(0x9248),
+        Some(0x9295),
+        Some(0x923f),
+        Some(0x924b),
+        Some(0x9250),
+        Some(0x929c),
+        Some(0x9296),
+        Some(0x9293),
+        Some(0x929b),
+        Some(0x925a),
+        Some(0x92cf),
+        Some(0x92b9),
+        Some(0x92b7),
+        Some(0x92e9),
+        Some(0x930f),
+        Some(0x92fa),
+        Some(0x9344),
+        Some(0x932e),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE840: [Option<u16>; 192] = [
+        Some(0x9319),
+        Some(0x9322),
+        Some(0x931a),
+        Some(0x9323),
+        Some(0x933a),
+        Some(0x9335),
+        Some(0x933b),
+        Some(0x935c),
+        Some(0x9360),
+        Some(0x937c),
+        Some(0x936e),
+        Some(0x9356),
+        Some(0x93b0),
+        Some(0x93ac),
+        Some(0x93ad),
+        Some(0x9394),
+        Some(0x93b9),
+        Some(0x93d6),
+        Some(0x93d7),
+        Some(0x93e8),
+        Some(0x93e5),
+        Some(0x93d8),
+        Some(0x93c3),
+        Some(0x93dd),
+        Some(0x93d0),
+        Some(0x93c8),
+        Some(0x93e4),
+        Some(0x941a),
+        Some(0x9414),
+        Some(0x9413),
+        Some(0x9403),
+        Some(0x9407),
+        Some(0x9410),
+        Some(0x9436),
+        Some(0x942b),
+        Some(0x9435),
+        Some(0x9421),
+        Some(0x943a),
+        Some(0x9441),
+        Some(0x9452),
+        Some(0x9444),
+        Some(0x945b),
+        Some(0x9460),
+        Some(0x9462),
+        Some(0x945e),
+        Some(0x946a),
+        Some(0x9229),
+        Some(0x9470),
+        Some(0x9475),
+        Some(0x9477),
+        Some(0x947d),
+        Some(0x945a),
+        Some(0x947c),
+        Some(0x947e),
+        Some(0x9481),
+        Some(0x947f),
+        Some(0x9582),
+        Some(0x9587),
+        Some(0x958a),
+        Some(0x9594),
+        Some(0x9596),
+        Some(0x9598),
+        Some(0x9599),
+        None,
+        Some(0x95a0),
+        Some(0x95a8),
+        Some(0x95a7),
+        Some(0x95ad),
+        Some(0x95bc),
+        Some(0x95bb),
+        Some(0x95b9),
+        Some(0x95be),
+        Some(0x95ca),
+        Some(0x6ff6),
+        Some(0x95c3),
+        Some(0x95cd),
+        Some(0x95cc),
+        Some(0x95d5),
+        Some(0x95d4),
+        Some(0x95d6),
+        Some(0x95dc),
+        Some(0x95e1),
+        Some(0x95e5),
+        Some(0x95e2),
+        Some(0x9621),
+        Some(0x9628),
+        Some(0x962e),
+        Some(0x962f),
+        Some(0x9642),
+        Some(0x964c),
+        Some(0x964f),
+        Some(0x964b),
+        Some(0x9677),
+        Some(0x965c),
+        Some(0x965e),
+        Some(0x965d),
+        Some(0x965f),
+        Some(0x9666),
+        Some(0x9672),
+        Some(0x966c),
+        Some(0x968d),
+        Some(0x9698),
+        Some(0x9695),
+        Some(0x9697),
+        Some(0x96aa),
+        Some(0x96a7),
+        Some(0x96b1),
+        Some(0x96b2),
+        Some(0x96b0),
+        Some(0x96b4),
+        Some(0x96b6),
+        Some(0x96b8),
+        Some(0x96b9),
+        Some(0x96ce),
+        Some(0x96cb),
+        Some(0x96c9),
+        Some(0x96cd),
+        Some(0x894d),
+        Some(0x96dc),
+        Some(0x970d),
+        Some(0x96d5),
+        Some(0x96f9),
+        Some(0x9704),
+        Some(0x9706),
+        Some(0x9708),
+        Some(0x9713),
+        Some(0x970e),
+        Some(0x9711),
+        Some(0x970f),
+        Some(0x9716),
+        Some(0x9719),
+        Some(0x9724),
+        Some(0x972a),
+        Some(0x9730),
+        Some(0x9739),
+        Some(0x973d),
+        Some(0x973e),
+        Some(0x9744),
+        Some(0x9746),
+        Some(0x9748),
+        Some(0x9742),
+        Some(0x9749),
+        Some(0x975c),
+        Some(0x9760),
+        Some(0x9764),
+        Some(0x9766),
+        Some(0x9768),
+        Some(0x52d2),
+        Some(0x976b),
+        Some(0x9771),
+        Some(0x9779),
+        Some(0x9785),
+        Some(0x977c),
+        Some(0x9781),
+        Some(0x977a),
+        Some(0x9786),
+        Some(0x978b),
+        Some(0x978f),
+        Some(0x9790),
+        Some(0x979c),
+        Some(0x97a8),
+        Some(0x97a6),
+        Some(0x97a3),
+        Some(0x97b3),
+        Some(0x97b4),
+        Some(0x97c3),
+        Some(0x97c6),
+        Some(0x97c8),
+        Some(0x97cb),
+        Some(0x97dc),
+        Some(0x97ed),
+        Some(0x9f4f),
+        Some(0x97f2),
+        Some(0x7adf),
+        Some(0x97f6),
+        Some(0x97f5),
+        Some(0x980f),
+        Some(0x980c),
+        Some(0x9838),
+        Some(0x9824),
+        Some(0x9821),
+        Some(0x9837),
+        Some(0x983d),
+        Some(0x9846),
+        Some(0x984f),
+        Some(0x984b),
+        Some(0x986b),
+        Some(0x986f),
+        Some(0x9870),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XE940: [Option<u16>; 192] = [
+        Some(0x9871),
+        Some(0x9874),
+        Some(0x9873),
+        Some(0x98aa),
+        Some(0x98af),
+        Some(0x98b1),
+        Some(0x98b6),
+        Some(0x98c4),
+        Some(0x98c3),
+        Some(0x98c6),
+        Some(0x98e9),
+        Some(0x98eb),
+        Some(0x9903),
+        Some(0x9909),
+        Some(0x9912),
+        Some(0x9914),
+        Some(0x9918),
+        Some(0x9921),
+        Some(0x991d),
+        Some(0x991e),
+        Some(0x9924),
+        Some(0x9920),
+        Some(0x992c),
+        Some(0x992e),
+        Some(0x993d),
+        Some(0x993e),
+        Some(0x9942),
+        Some(0x9949),
+        Some(0x9945),
+        Some(0x9950),
+        Some(0x994b),
+        Some(0x9951),
+        Some(0x9952),
+        Some(0x994c),
+        Some(0x9955),
+        Some(0x9997),
+        Some(0x9998),
+        Some(0x99a5),
+        Some(0x99ad),
+        Some(0x99ae),
+        Some(0x99bc),
+        Some(0x99df),
+        Some(0x99db),
+        Some(0x99dd),
+        Some(0x99d8),
+        Some(0x99d1),
+        Some(0x99ed),
+        Some(0x99ee),
+        Some(0x99f1),
+        Some(0x99f2),
+        Some(0x99fb),
+        Some(0x99f8),
+        Some(0x9a01),
+        Some(0x9a0f),
+        Some(0x9a05),
+        Some(0x99e2),
+        Some(0x9a19),
+        Some(0x9a2b),
+        Some(0x9a37),
+        Some(0x9a45),
+        Some(0x9a42),
+        Some(0x9a40),
+        Some(0x9a43),
+        None,
+        Some(0x9a3e),
+        Some(0x9a55),
+        Some(0x9a4d),
+        Some(0x9a5b),
+        Some(0x9a57),
+        Some(0x9a5f),
+        Some(0x9a62),
+        Some(0x9a65),
+        Some(0x9a64),
+        Some(0x9a69),
+        Some(0x9a6b),
+        Some(0x9a6a),
+        Some(0x9aad),
+        Some(0x9ab0),
+        Some(0x9abc),
+        Some(0x9ac0),
+        Some(0x9acf),
+        Some(0x9ad1),
+        Some(0x9ad3),
+        Some(0x9ad4),
+        Some(0x9ade),
+        Some(0x9adf),
+        Some(0x9ae2),
+        Some(0x9ae3),
+        Some(0x9ae6),
+        Some(0x9aef),
+        Some(0x9aeb),
+        Some(0x9aee),
+        Some(0x9af4),
+        Some(0x9af1),
+        Some(0x9af7),
+        Some(0x9afb),
+        Some(0x9b06),
+        Some(0x9b18),
+        Some(0x9b1a),
+        Some(0x9b1f),
+        Some(0x9b22),
+        Some(0x9b23),
+        Some(0x9b25),
+        Some(0x9b27),
+        Some(0x9b28),
+        Some(0x9b29),
+        Some(0x9b2a),
+        Some(0x9b2e),
+        Some(0x9b2f),
+        Some(0x9b32),
+        Some(0x9b44),
+        Some(0x9b43),
+        Some(0x9b4f),
+        Some(0x9b4d),
+        Some(0x9b4e),
+        Some(0x9b51),
+        Some(0x9b58),
+        Some(0x9b74),
+        Some(0x9b93),
+        Some(0x9b83),
+        Some(0x9b91),
+        Some(0x9b96),
+        Some(0x9b97),
+        Some(0x9b9f),
+        Some(0x9ba0),
+        Some(0x9ba8),
+        Some(0x9bb4),
+        Some(0x9bc0),
+        Some(0x9bca),
+        Some(0x9bb9),
+        Some(0x9bc6),
+        Some(0x9bcf),
+        Some(0x9bd1),
+        Some(0x9bd2),
+        Some(0x9be3),
+        Some(0x9be2),
+        Some(0x9be4),
+        Some(0x9bd4),
+        Some(0x9be1),
+        Some(0x9c3a),
+        Some(0x9bf2),
+        Some(0x9bf1),
+        Some(0x9bf0),
+        Some(0x9c15),
+        Some(0x9c14),
+        Some(0x9c09),
+        Some(0x9c13),
+        Some(0x9c0c),
+        Some(0x9c06),
+        Some(0x9c08),
+        Some(0x9c12),
+        Some(0x9c0a),
+        Some(0x9c04),
+        Some(0x9c2e),
+        Some(0x9c1b),
+        Some(0x9c25),
+        Some(0x9c24),
+        Some(0x9c21),
+        Some(0x9c30),
+        Some(0x9c47),
+        Some(0x9c32),
+        Some(0x9c46),
+        Some(0x9c3e),
+        Some(0x9c5a),
+        Some(0x9c60),
+        Some(0x9c67),
+        Some(0x9c76),
+        Some(0x9c78),
+        Some(0x9ce7),
+        Some(0x9cec),
+        Some(0x9cf0),
+        Some(0x9d09),
+        Some(0x9d08),
+        Some(0x9ceb),
+        Some(0x9d03),
+        Some(0x9d06),
+        Some(0x9d2a),
+        Some(0x9d26),
+        Some(0x9daf),
+        Some(0x9d23),
+        Some(0x9d1f),
+        Some(0x9d44),
+        Some(0x9d15),
+        Some(0x9d12),
+        Some(0x9d41),
+        Some(0x9d3f),
+        Some(0x9d3e),
+        Some(0x9d46),
+        Some(0x9d48),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XEA40: [Option<u16>; 101] = [
+        Some(0x9d5d),
+        Some(0x9d5e),
+        Some(0x9d64),
+        Some(0x9d51),
+        Some(0x9d50),
+        Some(0x9d59),
+        Some(0x9d72),
+        Some(0x9d89),
+        Some(0x9d87),
+        Some(0x9dab),
+        Some(0x9d6f),
+        Some(0x9d7a),
+        Some(0x9d9a),
+        Some(0x9da4),
+        Some(0x9da9),
+        Some(0x9db2),
+        Some(0x9dc4),
+        Some(0x9dc1),
+        Some(0x9dbb),
+        Some(0x9db8),
+        Some(0x9dba),
+        Some(0x9dc6),
+        Some(0x9dcf),
+        Some(0x9dc2),
+        Some(0x9dd9),
+        Some(0x9dd3),
+        Some(0x9df8),
+        Some(0x9de6),
+        Some(0x9ded),
+        Some(0x9def),
+        Some(0x9dfd),
+        Some(0x9e1a),
+        Some(0x9e1b),
+        Some(0x9e1e),
+        Some(0x9e75),
+        Some(0x9e79),
+        Some(0x9e7d),
+        Some(0x9e81),
+        Some(0x9e88),
+        Some(0x9e8b),
+        Some(0x9e8c),
+        Some(0x9e92),
+        Some(0x9e95),
+        Some(0x9e91),
+        Some(0x9e9d),
+        Some(0x9ea5),
+        Some(0x9ea9),
+        Some(0x9eb8),
+        Some(0x9eaa),
+        Some(0x9ead),
+        Some(0x9761),
+        Some(0x9ecc),
+        Some(0x9ece),
+        Some(0x9ecf),
+        Some(0x9ed0),
+        Some(0x9ed4),
+        Some(0x9edc),
+        Some(0x9ede),
+        Some(0x9edd),
+        Some(0x9ee0),
+        Some(0x9ee5),
+        Some(0x9ee8),
+        Some(0x9eef),
+        None,
+        Some(0x9ef4),
+        Some(0x9ef6),
+        Some(0x9ef7),
+        Some(0x9ef9),
+        Some(0x9efb),
+        Some(0x9efc),
+        Some(0x9efd),
+        Some(0x9f07),
+        Some(0x9f08),
+        Some(0x76b7),
+        Some(0x9f15),
+        Some(0x9f21),
+        Some(0x9f2c),
+        Some(0x9f3e),
+        Some(0x9f4a),
+        Some(0x9f52),
+        Some(0x9f54),
+        Some(0x9f63),
+        Some(0x9f5f),
+        Some(0x9f60),
+        Some(0x9f61),
+        Some(0x9f66),
+        Some(0x9f67),
+        Some(0x9f6c),
+        Some(0x9f6a),
+        Some(0x9f77),
+        Some(0x9f72),
+        Some(0x9f76),
+        Some(0x9f95),
+        Some(0x9f9c),
+        Some(0x9fa0),
+        Some(0x582f),
+        Some(0x69c7),
+        Some(0x9059),
+        Some(0x7464),
+        Some(0x51dc),
+        Some(0x7199),
+    ];
+
+    const BASE_0XEB40: [Option<u16>; 66] = [
+        None,
+        Some(0x3001),
+        Some(0x3002),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xffe3),
+        Some(0xfe33),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30fc),
+        Some(0xfe31),
+        Some(0x2010),
+        None,
+        None,
+        Some(0x301c),
+        Some(0x2016),
+        Some(0xff5c),
+        Some(0x2026),
+        Some(0xfe30),
+        None,
+        None,
+        None,
+        None,
+        Some(0xfe35),
+        Some(0xfe36),
+        Some(0xfe39),
+        Some(0xfe3a),
+        Some(0xff3b),
+        Some(0xff3d),
+        Some(0xfe37),
+        Some(0xfe38),
+        Some(0xfe3f),
+        Some(0xfe40),
+        Some(0xfe3d),
+        Some(0xfe3e),
+        Some(0xfe41),
+        Some(0xfe42),
+        Some(0xfe43),
+        Some(0xfe44),
+        Some(0xfe3b),
+        Some(0xfe3c),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xff1d),
+    ];
+
+    const BASE_0XEC98: [Option<u16>; 85] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3041),
+        None,
+        Some(0x3043),
+        None,
+        Some(0x3045),
+        None,
+        Some(0x3047),
+        None,
+        Some(0x3049),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3063),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3083),
+        None,
+        Some(0x3085),
+        None,
+        Some(0x3087),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x308e),
+    ];
+
+    const BASE_0XED40: [Option<u16>; 87] = [
+        Some(0x30a1),
+        None,
+        Some(0x30a3),
+        None,
+        Some(0x30a5),
+        None,
+        Some(0x30a7),
+        None,
+        Some(0x30a9),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30c3),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30e3),
+        None,
+        Some(0x30e5),
+        None,
+        Some(0x30e7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30ee),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30f5),
+        Some(0x30f6),
+    ];
+
+    const LOOKUP_TABLES_0X87: [&'static [Option<u16>]; 25] = [
+        &Self::BASE_0X8740,
+        &Self::BASE_0X8840,
+        &Self::BASE_0X8940,
+        &Self::BASE_0X8A40,
+        &Self::BASE_0X8B40,
+        &Self::BASE_0X8C40,
+        &Self::BASE_0X8D40,
+        &Self::BASE_0X8E40,
+        &Self::BASE_0X8F40,
+        &Self::BASE_0X9040,
+        &Self::BASE_0X9140,
+        &Self::BASE_0X9240,
+        &Self::BASE_0X9340,
+        &Self::BASE_0X9440,
+        &Self::BASE_0X9540,
+        &Self::BASE_0X9640,
+        &Self::BASE_0X9740,
+        &Self::BASE_0X9840,
+        &Self::BASE_0X9940,
+        &Self::BASE_0X9A40,
+        &Self::BASE_0X9B40,
+        &Self::BASE_0X9C40,
+        &Self::BASE_0X9D40,
+        &Self::BASE_0X9E40,
+        &Self::BASE_0X9F40,
+    ];
+
+    const LOOKUP_TABLES_0XE0: [&'static [Option<u16>]; 10] = [
+        &Self::BASE_0XE040,
+        &Self::BASE_0XE140,
+        &Self::BASE_0XE240,
+        &Self::BASE_0XE340,
+        &Self::BASE_0XE440,
+        &Self::BASE_0XE540,
+        &Self::BASE_0XE640,
+        &Self::BASE_0XE740,
+        &Self::BASE_0XE840,
+        &Self::BASE_0XE940,
+    ];
+
+    /// Retrieves the code points of a byte sequence that maps to multiple code points.
+    fn get_code_point_sequence(value: u16) -> Option<&'static [u16]> {
+        match value {
+            0x8591 => Some(&[0x0030, 0x002e]),
+            0x85ab => Some(&[0x0058, 0x0049, 0x0049, 0x0049]),
+            0x85ac => Some(&[0x0058, 0x0049, 0x0056]),
+            0x85ad => Some(&[0x0058, 0x0056]),
+            0x85bf => Some(&[0x0078, 0x0069, 0x0069, 0x0069]),
+            0x85c0 => Some(&[0x0078, 0x0069, 0x0076]),
+            0x85c1 => Some(&[0x0078, 0x0076]),
+            0x865d => Some(&[0x0054, 0x0042]),
+            0x869e => Some(&[0x0046, 0x0041, 0x0058]),
+            0x86ce => Some(&[0x2193, 0x2191]),
+            0x8791 => Some(&[0x5927, 0x20dd]),
+            0x8792 => Some(&[0x5c0f, 0x20dd]),
+            0x879d => Some(&[0x63a7, 0x20dd]),
+            0x87fb => Some(&[0x6709, 0x9650, 0x4f1a, 0x793e]),
+            0x87fc => Some(&[0x8ca1, 0x56e3, 0x6cd5, 0x4eba]),
+            _ => None,
+        }
+    }
+
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+            remaining_code_points: &[],
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderMacJapanese<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.remaining_code_points.is_empty() {
+            let code_point: u16 = self.remaining_code_points[0];
+            self.remaining_code_points = &self.remaining_code_points[1..];
+
+            return Some(Ok(code_point as u32));
+        }
+        match self.bytes.get(self.byte_index) {
+            Some(byte_value) => {
+                self.byte_index += 1;
+
+                let additional_byte_value: u8 = match *byte_value {
+                    0x81..=0x9f | 0xe0..=0xed => match self.bytes.get(self.byte_index) {
+                        Some(value) => {
+                            self.byte_index += 1;
+
+                            *value
+                        }
+                        None => {
+                            return Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacJapanese: 0x{:02x} as Unicode - missing additional byte",
+                                *byte_value
+                            ))));
+                        }
+                    },
+                    _ => {
+                        let code_point: Option<u16> = match *byte_value {
+                            0x00..=0x5b | 0x5d..=0x7f => Some(*byte_value as u16),
+                            0x5c => Some(0x00a5),
+                            0x80 => Some(0x005c),
+                            0xa0 => Some(0x00a0),
+                            0xa1..=0xdf => Some(0xff61 + ((*byte_value - 0xa1) as u16)),
+                            0xfd => Some(0x00a9),
+                            0xfe => Some(0x2122),
+                            0xff => Some(0x2026),
+                            _ => None,
+                        };
+                        return match code_point {
+                            Some(code_point) => Some(Ok(code_point as u32)),
+                            None => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacJapanese: 0x{:02x} as Unicode",
+                                *byte_value
+                            )))),
+                        };
+                    }
+                };
+                let code_point: Option<u16> = match *byte_value {
+                    0x81 => match additional_byte_value {
+                        0x40..=0xff => Self::BASE_0X8140[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0x82 => match additional_byte_value {
+                        0x48..0xf2 => Self::BASE_0X8248[(additional_byte_value - 0x48) as usize],
+                        _ => None,
+                    },
+                    0x83 => match additional_byte_value {
+                        0x40..0xd7 => Self::BASE_0X8340[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0x84 => match additional_byte_value {
+                        0x40..0xbf => Self::BASE_0X8440[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0x85 => match additional_byte_value {
+                        0x40..0xf5 => Self::BASE_0X8540[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0x86 => match additional_byte_value {
+                        0x40..0xd7 => Self::BASE_0X8640[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0x87..=0x9f => {
+                        let lookup_table: &[Option<u16>] =
+                            Self::LOOKUP_TABLES_0X87[(*byte_value - 0x87) as usize];
+
+                        match additional_byte_value {
+                            0x40..=0xff => lookup_table[(additional_byte_value - 0x40) as usize],
+                            _ => None,
+                        }
+                    }
+                    0xe0..=0xe9 => {
+                        let lookup_table: &[Option<u16>] =
+                            Self::LOOKUP_TABLES_0XE0[(*byte_value - 0xe0) as usize];
+
+                        match additional_byte_value {
+                            0x40..=0xff => lookup_table[(additional_byte_value - 0x40) as usize],
+                            _ => None,
+                        }
+                    }
+                    0xea => match additional_byte_value {
+                        0x40..0xa5 => Self::BASE_0XEA40[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0xeb => match additional_byte_value {
+                        0x40..0x82 => Self::BASE_0XEB40[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    0xec => match additional_byte_value {
+                        0x98..0xed => Self::BASE_0XEC98[(additional_byte_value - 0x98) as usize],
+                        _ => None,
+                    },
+                    0xed => match additional_byte_value {
+                        0x40..0x97 => Self::BASE_0XED40[(additional_byte_value - 0x40) as usize],
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match code_point {
+                    Some(code_point) => Some(Ok(code_point as u32)),
+                    None => {
+                        let value: u16 =
+                            ((*byte_value as u16) << 8) | (additional_byte_value as u16);
+
+                        match Self::get_code_point_sequence(value) {
+                            Some(code_points) => {
+                                self.remaining_code_points = &code_points[1..];
+
+                                Some(Ok(code_points[0] as u32))
+                            }
+                            None => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode MacJapanese: 0x{:02x}, 0x{:02x} as Unicode",
+                                *byte_value, additional_byte_value
+                            )))),
+                        }
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+/// MacJapanese encoder.
+pub struct EncoderMacJapanese<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderMacJapanese<'a> {
+    const BASE_0X00A0: [Option<&'static [u8]>; 24] = [
+        Some(&[0xa0]),
+        None,
+        Some(&[0x81, 0x91]),
+        Some(&[0x81, 0x92]),
+        None,
+        Some(&[0x5c]),
+        None,
+        Some(&[0x81, 0x98]),
+        Some(&[0x81, 0x4e]),
+        Some(&[0xfd]),
+        None,
+        None,
+        Some(&[0x81, 0xca]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x8b]),
+        Some(&[0x81, 0x7d]),
+        None,
+        None,
+        Some(&[0x81, 0x4c]),
+        None,
+        Some(&[0x81, 0xf7]),
+        None,
+    ];
+
+    const BASE_0X0390: [Option<&'static [u8]>; 64] = [
+        None,
+        Some(&[0x83, 0x9f]),
+        Some(&[0x83, 0xa0]),
+        Some(&[0x83, 0xa1]),
+        Some(&[0x83, 0xa2]),
+        Some(&[0x83, 0xa3]),
+        Some(&[0x83, 0xa4]),
+        Some(&[0x83, 0xa5]),
+        Some(&[0x83, 0xa6]),
+        Some(&[0x83, 0xa7]),
+        Some(&[0x83, 0xa8]),
+        Some(&[0x83, 0xa9]),
+        Some(&[0x83, 0xaa]),
+        Some(&[0x83, 0xab]),
+        Some(&[0x83, 0xac]),
+        Some(&[0x83, 0xad]),
+        Some(&[0x83, 0xae]),
+        Some(&[0x83, 0xaf]),
+        None,
+        Some(&[0x83, 0xb0]),
+        Some(&[0x83, 0xb1]),
+        Some(&[0x83, 0xb2]),
+        Some(&[0x83, 0xb3]),
+        Some(&[0x83, 0xb4]),
+        Some(&[0x83, 0xb5]),
+        Some(&[0x83, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x83, 0xbf]),
+        Some(&[0x83, 0xc0]),
+        Some(&[0x83, 0xc1]),
+        Some(&[0x83, 0xc2]),
+        Some(&[0x83, 0xc3]),
+        Some(&[0x83, 0xc4]),
+        Some(&[0x83, 0xc5]),
+        Some(&[0x83, 0xc6]),
+        Some(&[0x83, 0xc7]),
+        Some(&[0x83, 0xc8]),
+        Some(&[0x83, 0xc9]),
+        Some(&[0x83, 0xca]),
+        Some(&[0x83, 0xcb]),
+        Some(&[0x83, 0xcc]),
+        Some(&[0x83, 0xcd]),
+        Some(&[0x83, 0xce]),
+        Some(&[0x83, 0xcf]),
+        None,
+        Some(&[0x83, 0xd0]),
+        Some(&[0x83, 0xd1]),
+        Some(&[0x83, 0xd2]),
+        Some(&[0x83, 0xd3]),
+        Some(&[0x83, 0xd4]),
+        Some(&[0x83, 0xd5]),
+        Some(&[0x83, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0400: [Option<&'static [u8]>; 88] = [
+        None,
+        Some(&[0x84, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x84, 0x40]),
+        Some(&[0x84, 0x41]),
+        Some(&[0x84, 0x42]),
+        Some(&[0x84, 0x43]),
+        Some(&[0x84, 0x44]),
+        Some(&[0x84, 0x45]),
+        Some(&[0x84, 0x47]),
+        Some(&[0x84, 0x48]),
+        Some(&[0x84, 0x49]),
+        Some(&[0x84, 0x4a]),
+        Some(&[0x84, 0x4b]),
+        Some(&[0x84, 0x4c]),
+        Some(&[0x84, 0x4d]),
+        Some(&[0x84, 0x4e]),
+        Some(&[0x84, 0x4f]),
+        Some(&[0x84, 0x50]),
+        Some(&[0x84, 0x51]),
+        Some(&[0x84, 0x52]),
+        Some(&[0x84, 0x53]),
+        Some(&[0x84, 0x54]),
+        Some(&[0x84, 0x55]),
+        Some(&[0x84, 0x56]),
+        Some(&[0x84, 0x57]),
+        Some(&[0x84, 0x58]),
+        Some(&[0x84, 0x59]),
+        Some(&[0x84, 0x5a]),
+        Some(&[0x84, 0x5b]),
+        Some(&[0x84, 0x5c]),
+        Some(&[0x84, 0x5d]),
+        Some(&[0x84, 0x5e]),
+        Some(&[0x84, 0x5f]),
+        Some(&[0x84, 0x60]),
+        Some(&[0x84, 0x70]),
+        Some(&[0x84, 0x71]),
+        Some(&[0x84, 0x72]),
+        Some(&[0x84, 0x73]),
+        Some(&[0x84, 0x74]),
+        Some(&[0x84, 0x75]),
+        Some(&[0x84, 0x77]),
+        Some(&[0x84, 0x78]),
+        Some(&[0x84, 0x79]),
+        Some(&[0x84, 0x7a]),
+        Some(&[0x84, 0x7b]),
+        Some(&[0x84, 0x7c]),
+        Some(&[0x84, 0x7d]),
+        Some(&[0x84, 0x7e]),
+        Some(&[0x84, 0x80]),
+        Some(&[0x84, 0x81]),
+        Some(&[0x84, 0x82]),
+        Some(&[0x84, 0x83]),
+        Some(&[0x84, 0x84]),
+        Some(&[0x84, 0x85]),
+        Some(&[0x84, 0x86]),
+        Some(&[0x84, 0x87]),
+        Some(&[0x84, 0x88]),
+        Some(&[0x84, 0x89]),
+        Some(&[0x84, 0x8a]),
+        Some(&[0x84, 0x8b]),
+        Some(&[0x84, 0x8c]),
+        Some(&[0x84, 0x8d]),
+        Some(&[0x84, 0x8e]),
+        Some(&[0x84, 0x8f]),
+        Some(&[0x84, 0x90]),
+        Some(&[0x84, 0x91]),
+        None,
+        Some(&[0x84, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2010: [Option<&'static [u8]>; 48] = [
+        Some(&[0x81, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x5c]),
+        None,
+        Some(&[0x81, 0x61]),
+        None,
+        Some(&[0x81, 0x65]),
+        Some(&[0x81, 0x66]),
+        None,
+        None,
+        Some(&[0x81, 0x67]),
+        Some(&[0x81, 0x68]),
+        None,
+        None,
+        Some(&[0x81, 0xf5]),
+        Some(&[0x81, 0xf6]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x64]),
+        Some(&[0x81, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xf1]),
+        None,
+        Some(&[0x81, 0x8c]),
+        Some(&[0x81, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2100: [Option<&'static [u8]>; 48] = [
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x50]),
+        None,
+        None,
+        Some(&[0x86, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x9d]),
+        Some(&[0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2160: [Option<&'static [u8]>; 32] = [
+        Some(&[0x85, 0x9f]),
+        Some(&[0x85, 0xa0]),
+        Some(&[0x85, 0xa1]),
+        Some(&[0x85, 0xa2]),
+        Some(&[0x85, 0xa3]),
+        Some(&[0x85, 0xa4]),
+        Some(&[0x85, 0xa5]),
+        Some(&[0x85, 0xa6]),
+        Some(&[0x85, 0xa7]),
+        Some(&[0x85, 0xa8]),
+        Some(&[0x85, 0xa9]),
+        Some(&[0x85, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x85, 0xb3]),
+        Some(&[0x85, 0xb4]),
+        Some(&[0x85, 0xb5]),
+        Some(&[0x85, 0xb6]),
+        Some(&[0x85, 0xb7]),
+        Some(&[0x85, 0xb8]),
+        Some(&[0x85, 0xb9]),
+        Some(&[0x85, 0xba]),
+        Some(&[0x85, 0xbb]),
+        Some(&[0x85, 0xbc]),
+        Some(&[0x85, 0xbd]),
+        Some(&[0x85, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2190: [Option<&'static [u8]>; 8] = [
+        Some(&[0x81, 0xa9]),
+        Some(&[0x81, 0xaa]),
+        Some(&[0x81, 0xa8]),
+        Some(&[0x81, 0xab]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X21C0: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0xcc]),
+        Some(&[0x86, 0xcd]),
+        Some(&[0x86, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xcb]),
+        None,
+        Some(&[0x81, 0xcc]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X21E0: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0xd0]),
+        Some(&[0x86, 0xd1]),
+        Some(&[0x86, 0xcf]),
+        Some(&[0x86, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2200: [Option<&'static [u8]>; 64] = [
+        Some(&[0x81, 0xcd]),
+        None,
+        Some(&[0x81, 0xdd]),
+        Some(&[0x81, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xde]),
+        Some(&[0x81, 0xb8]),
+        None,
+        None,
+        Some(&[0x81, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xe3]),
+        None,
+        None,
+        Some(&[0x81, 0xe5]),
+        Some(&[0x81, 0x87]),
+        Some(&[0x88, 0x41]),
+        Some(&[0x81, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xc8]),
+        Some(&[0x81, 0xc9]),
+        Some(&[0x81, 0xbf]),
+        Some(&[0x81, 0xbe]),
+        Some(&[0x81, 0xe7]),
+        Some(&[0x81, 0xe8]),
+        None,
+        Some(&[0x88, 0x40]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x88]),
+        Some(&[0x81, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xe4]),
+        None,
+        None,
+    ];
+
+    const BASE_0X2250: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        Some(&[0x81, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x82]),
+        Some(&[0x81, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x85]),
+        Some(&[0x81, 0x86]),
+        None,
+        None,
+        Some(&[0x81, 0xe1]),
+        Some(&[0x81, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2280: [Option<&'static [u8]>; 8] = [
+        None,
+        None,
+        Some(&[0x81, 0xbc]),
+        Some(&[0x81, 0xbd]),
+        None,
+        None,
+        Some(&[0x81, 0xba]),
+        Some(&[0x81, 0xbb]),
+    ];
+
+    const BASE_0X2460: [Option<&'static [u8]>; 88] = [
+        Some(&[0x85, 0x40]),
+        Some(&[0x85, 0x41]),
+        Some(&[0x85, 0x42]),
+        Some(&[0x85, 0x43]),
+        Some(&[0x85, 0x44]),
+        Some(&[0x85, 0x45]),
+        Some(&[0x85, 0x46]),
+        Some(&[0x85, 0x47]),
+        Some(&[0x85, 0x48]),
+        Some(&[0x85, 0x49]),
+        Some(&[0x85, 0x4a]),
+        Some(&[0x85, 0x4b]),
+        Some(&[0x85, 0x4c]),
+        Some(&[0x85, 0x4d]),
+        Some(&[0x85, 0x4e]),
+        Some(&[0x85, 0x4f]),
+        Some(&[0x85, 0x50]),
+        Some(&[0x85, 0x51]),
+        Some(&[0x85, 0x52]),
+        Some(&[0x85, 0x53]),
+        Some(&[0x85, 0x5e]),
+        Some(&[0x85, 0x5f]),
+        Some(&[0x85, 0x60]),
+        Some(&[0x85, 0x61]),
+        Some(&[0x85, 0x62]),
+        Some(&[0x85, 0x63]),
+        Some(&[0x85, 0x64]),
+        Some(&[0x85, 0x65]),
+        Some(&[0x85, 0x66]),
+        Some(&[0x85, 0x67]),
+        Some(&[0x85, 0x68]),
+        Some(&[0x85, 0x69]),
+        Some(&[0x85, 0x6a]),
+        Some(&[0x85, 0x6b]),
+        Some(&[0x85, 0x6c]),
+        Some(&[0x85, 0x6d]),
+        Some(&[0x85, 0x6e]),
+        Some(&[0x85, 0x6f]),
+        Some(&[0x85, 0x70]),
+        Some(&[0x85, 0x71]),
+        Some(&[0x85, 0x92]),
+        Some(&[0x85, 0x93]),
+        Some(&[0x85, 0x94]),
+        Some(&[0x85, 0x95]),
+        Some(&[0x85, 0x96]),
+        Some(&[0x85, 0x97]),
+        Some(&[0x85, 0x98]),
+        Some(&[0x85, 0x99]),
+        Some(&[0x85, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x85, 0xdb]),
+        Some(&[0x85, 0xdc]),
+        Some(&[0x85, 0xdd]),
+        Some(&[0x85, 0xde]),
+        Some(&[0x85, 0xdf]),
+        Some(&[0x85, 0xe0]),
+        Some(&[0x85, 0xe1]),
+        Some(&[0x85, 0xe2]),
+        Some(&[0x85, 0xe3]),
+        Some(&[0x85, 0xe4]),
+        Some(&[0x85, 0xe5]),
+        Some(&[0x85, 0xe6]),
+        Some(&[0x85, 0xe7]),
+        Some(&[0x85, 0xe8]),
+        Some(&[0x85, 0xe9]),
+        Some(&[0x85, 0xea]),
+        Some(&[0x85, 0xeb]),
+        Some(&[0x85, 0xec]),
+        Some(&[0x85, 0xed]),
+        Some(&[0x85, 0xee]),
+        Some(&[0x85, 0xef]),
+        Some(&[0x85, 0xf0]),
+        Some(&[0x85, 0xf1]),
+        Some(&[0x85, 0xf2]),
+        Some(&[0x85, 0xf3]),
+        Some(&[0x85, 0xf4]),
+        None,
+        None,
+    ];
+
+    const BASE_0X2500: [Option<&'static [u8]>; 80] = [
+        Some(&[0x84, 0x9f]),
+        Some(&[0x84, 0xaa]),
+        Some(&[0x84, 0xa0]),
+        Some(&[0x84, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x84, 0xa1]),
+        None,
+        None,
+        Some(&[0x84, 0xac]),
+        Some(&[0x84, 0xa2]),
+        None,
+        None,
+        Some(&[0x84, 0xad]),
+        Some(&[0x84, 0xa4]),
+        None,
+        None,
+        Some(&[0x84, 0xaf]),
+        Some(&[0x84, 0xa3]),
+        None,
+        None,
+        Some(&[0x84, 0xae]),
+        Some(&[0x84, 0xa5]),
+        Some(&[0x84, 0xba]),
+        None,
+        None,
+        Some(&[0x84, 0xb5]),
+        None,
+        None,
+        Some(&[0x84, 0xb0]),
+        Some(&[0x84, 0xa7]),
+        Some(&[0x84, 0xbc]),
+        None,
+        None,
+        Some(&[0x84, 0xb7]),
+        None,
+        None,
+        Some(&[0x84, 0xb2]),
+        Some(&[0x84, 0xa6]),
+        None,
+        None,
+        Some(&[0x84, 0xb6]),
+        Some(&[0x84, 0xbb]),
+        None,
+        None,
+        Some(&[0x84, 0xb1]),
+        Some(&[0x84, 0xa8]),
+        None,
+        None,
+        Some(&[0x84, 0xb8]),
+        Some(&[0x84, 0xbd]),
+        None,
+        None,
+        Some(&[0x84, 0xb3]),
+        Some(&[0x84, 0xa9]),
+        None,
+        None,
+        Some(&[0x84, 0xb9]),
+        None,
+        None,
+        Some(&[0x84, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x84, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X25B0: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        Some(&[0x81, 0xa3]),
+        Some(&[0x81, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0xa5]),
+        Some(&[0x81, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x9f]),
+        Some(&[0x81, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x9b]),
+        None,
+        None,
+        Some(&[0x81, 0x9d]),
+        Some(&[0x81, 0x9c]),
+    ];
+
+    const BASE_0X2600: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x9a]),
+        Some(&[0x81, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0xc8]),
+        Some(&[0x86, 0xc9]),
+        Some(&[0x86, 0xc7]),
+        Some(&[0x86, 0xca]),
+    ];
+
+    const BASE_0X2660: [Option<&'static [u8]>; 16] = [
+        Some(&[0x86, 0xa3]),
+        Some(&[0x86, 0xa1]),
+        Some(&[0x86, 0xa2]),
+        Some(&[0x86, 0xa4]),
+        Some(&[0x86, 0x9f]),
+        Some(&[0x86, 0xa5]),
+        Some(&[0x86, 0xa6]),
+        Some(&[0x86, 0xa0]),
+        None,
+        None,
+        Some(&[0x81, 0xf4]),
+        None,
+        None,
+        Some(&[0x81, 0xf3]),
+        None,
+        Some(&[0x81, 0xf2]),
+    ];
+
+    const BASE_0X2770: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x85, 0x7c]),
+        Some(&[0x85, 0x7d]),
+        Some(&[0x85, 0x7e]),
+        Some(&[0x85, 0x80]),
+        Some(&[0x85, 0x81]),
+        Some(&[0x85, 0x82]),
+        Some(&[0x85, 0x83]),
+        Some(&[0x85, 0x84]),
+        Some(&[0x85, 0x85]),
+        None,
+    ];
+
+    const BASE_0X3000: [Option<&'static [u8]>; 40] = [
+        Some(&[0x81, 0x40]),
+        Some(&[0x81, 0x41]),
+        Some(&[0x81, 0x42]),
+        Some(&[0x81, 0x56]),
+        Some(&[0x86, 0xb5]),
+        Some(&[0x81, 0x58]),
+        Some(&[0x81, 0x59]),
+        Some(&[0x81, 0x5a]),
+        Some(&[0x81, 0x71]),
+        Some(&[0x81, 0x72]),
+        Some(&[0x81, 0x73]),
+        Some(&[0x81, 0x74]),
+        Some(&[0x81, 0x75]),
+        Some(&[0x81, 0x76]),
+        Some(&[0x81, 0x77]),
+        Some(&[0x81, 0x78]),
+        Some(&[0x81, 0x79]),
+        Some(&[0x81, 0x7a]),
+        Some(&[0x81, 0xa7]),
+        Some(&[0x81, 0xac]),
+        Some(&[0x81, 0x6b]),
+        Some(&[0x81, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x60]),
+        Some(&[0x88, 0x54]),
+        None,
+        Some(&[0x88, 0x55]),
+        Some(&[0x86, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3040: [Option<&'static [u8]>; 192] = [
+        None,
+        Some(&[0x82, 0x9f]),
+        Some(&[0x82, 0xa0]),
+        Some(&[0x82, 0xa1]),
+        Some(&[0x82, 0xa2]),
+        Some(&[0x82, 0xa3]),
+        Some(&[0x82, 0xa4]),
+        Some(&[0x82, 0xa5]),
+        Some(&[0x82, 0xa6]),
+        Some(&[0x82, 0xa7]),
+        Some(&[0x82, 0xa8]),
+        Some(&[0x82, 0xa9]),
+        Some(&[0x82, 0xaa]),
+        Some(&[0x82, 0xab]),
+        Some(&[0x82, 0xac]),
+        Some(&[0x82, 0xad]),
+        Some(&[0x82, 0xae]),
+        Some(&[0x82, 0xaf]),
+        Some(&[0x82, 0xb0]),
+        Some(&[0x82, 0xb1]),
+        Some(&[0x82, 0xb2]),
+        Some(&[0x82, 0xb3]),
+        Some(&[0x82, 0xb4]),
+        Some(&[0x82, 0xb5]),
+        Some(&[0x82, 0xb6]),
+        Some(&[0x82, 0xb7]),
+        Some(&[0x82, 0xb8]),
+        Some(&[0x82, 0xb9]),
+        Some(&[0x82, 0xba]),
+        Some(&[0x82, 0xbb]),
+        Some(&[0x82, 0xbc]),
+        Some(&[0x82, 0xbd]),
+        Some(&[0x82, 0xbe]),
+        Some(&[0x82, 0xbf]),
+        Some(&[0x82, 0xc0]),
+        Some(&[0x82, 0xc1]),
+        Some(&[0x82, 0xc2]),
+        Some(&[0x82, 0xc3]),
+        Some(&[0x82, 0xc4]),
+        Some(&[0x82, 0xc5]),
+        Some(&[0x82, 0xc6]),
+        Some(&[0x82, 0xc7]),
+        Some(&[0x82, 0xc8]),
+        Some(&[0x82, 0xc9]),
+        Some(&[0x82, 0xca]),
+        Some(&[0x82, 0xcb]),
+        Some(&[0x82, 0xcc]),
+        Some(&[0x82, 0xcd]),
+        Some(&[0x82, 0xce]),
+        Some(&[0x82, 0xcf]),
+        Some(&[0x82, 0xd0]),
+        Some(&[0x82, 0xd1]),
+        Some(&[0x82, 0xd2]),
+        Some(&[0x82, 0xd3]),
+        Some(&[0x82, 0xd4]),
+        Some(&[0x82, 0xd5]),
+        Some(&[0x82, 0xd6]),
+        Some(&[0x82, 0xd7]),
+        Some(&[0x82, 0xd8]),
+        Some(&[0x82, 0xd9]),
+        Some(&[0x82, 0xda]),
+        Some(&[0x82, 0xdb]),
+        Some(&[0x82, 0xdc]),
+        Some(&[0x82, 0xdd]),
+        Some(&[0x82, 0xde]),
+        Some(&[0x82, 0xdf]),
+        Some(&[0x82, 0xe0]),
+        Some(&[0x82, 0xe1]),
+        Some(&[0x82, 0xe2]),
+        Some(&[0x82, 0xe3]),
+        Some(&[0x82, 0xe4]),
+        Some(&[0x82, 0xe5]),
+        Some(&[0x82, 0xe6]),
+        Some(&[0x82, 0xe7]),
+        Some(&[0x82, 0xe8]),
+        Some(&[0x82, 0xe9]),
+        Some(&[0x82, 0xea]),
+        Some(&[0x82, 0xeb]),
+        Some(&[0x82, 0xec]),
+        Some(&[0x82, 0xed]),
+        Some(&[0x82, 0xee]),
+        Some(&[0x82, 0xef]),
+        Some(&[0x82, 0xf0]),
+        Some(&[0x82, 0xf1]),
+        Some(&[0x88, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x4a]),
+        Some(&[0x81, 0x4b]),
+        Some(&[0x81, 0x54]),
+        Some(&[0x81, 0x55]),
+        None,
+        None,
+        Some(&[0x83, 0x40]),
+        Some(&[0x83, 0x41]),
+        Some(&[0x83, 0x42]),
+        Some(&[0x83, 0x43]),
+        Some(&[0x83, 0x44]),
+        Some(&[0x83, 0x45]),
+        Some(&[0x83, 0x46]),
+        Some(&[0x83, 0x47]),
+        Some(&[0x83, 0x48]),
+        Some(&[0x83, 0x49]),
+        Some(&[0x83, 0x4a]),
+        Some(&[0x83, 0x4b]),
+        Some(&[0x83, 0x4c]),
+        Some(&[0x83, 0x4d]),
+        Some(&[0x83, 0x4e]),
+        Some(&[0x83, 0x4f]),
+        Some(&[0x83, 0x50]),
+        Some(&[0x83, 0x51]),
+        Some(&[0x83, 0x52]),
+        Some(&[0x83, 0x53]),
+        Some(&[0x83, 0x54]),
+        Some(&[0x83, 0x55]),
+        Some(&[0x83, 0x56]),
+        Some(&[0x83, 0x57]),
+        Some(&[0x83, 0x58]),
+        Some(&[0x83, 0x59]),
+        Some(&[0x83, 0x5a]),
+        Some(&[0x83, 0x5b]),
+        Some(&[0x83, 0x5c]),
+        Some(&[0x83, 0x5d]),
+        Some(&[0x83, 0x5e]),
+        Some(&[0x83, 0x5f]),
+        Some(&[0x83, 0x60]),
+        Some(&[0x83, 0x61]),
+        Some(&[0x83, 0x62]),
+        Some(&[0x83, 0x63]),
+        Some(&[0x83, 0x64]),
+        Some(&[0x83, 0x65]),
+        Some(&[0x83, 0x66]),
+        Some(&[0x83, 0x67]),
+        Some(&[0x83, 0x68]),
+        Some(&[0x83, 0x69]),
+        Some(&[0x83, 0x6a]),
+        Some(&[0x83, 0x6b]),
+        Some(&[0x83, 0x6c]),
+        Some(&[0x83, 0x6d]),
+        Some(&[0x83, 0x6e]),
+        Some(&[0x83, 0x6f]),
+        Some(&[0x83, 0x70]),
+        Some(&[0x83, 0x71]),
+        Some(&[0x83, 0x72]),
+        Some(&[0x83, 0x73]),
+        Some(&[0x83, 0x74]),
+        Some(&[0x83, 0x75]),
+        Some(&[0x83, 0x76]),
+        Some(&[0x83, 0x77]),
+        Some(&[0x83, 0x78]),
+        Some(&[0x83, 0x79]),
+        Some(&[0x83, 0x7a]),
+        Some(&[0x83, 0x7b]),
+        Some(&[0x83, 0x7c]),
+        Some(&[0x83, 0x7d]),
+        Some(&[0x83, 0x7e]),
+        Some(&[0x83, 0x80]),
+        Some(&[0x83, 0x81]),
+        Some(&[0x83, 0x82]),
+        Some(&[0x83, 0x83]),
+        Some(&[0x83, 0x84]),
+        Some(&[0x83, 0x85]),
+        Some(&[0x83, 0x86]),
+        Some(&[0x83, 0x87]),
+        Some(&[0x83, 0x88]),
+        Some(&[0x83, 0x89]),
+        Some(&[0x83, 0x8a]),
+        Some(&[0x83, 0x8b]),
+        Some(&[0x83, 0x8c]),
+        Some(&[0x83, 0x8d]),
+        Some(&[0x83, 0x8e]),
+        Some(&[0x83, 0x8f]),
+        Some(&[0x83, 0x90]),
+        Some(&[0x83, 0x91]),
+        Some(&[0x83, 0x92]),
+        Some(&[0x83, 0x93]),
+        Some(&[0x83, 0x94]),
+        Some(&[0x83, 0x95]),
+        Some(&[0x83, 0x96]),
+        Some(&[0x88, 0x6a]),
+        Some(&[0x88, 0x6b]),
+        Some(&[0x88, 0x6c]),
+        Some(&[0x88, 0x6d]),
+        Some(&[0x81, 0x45]),
+        Some(&[0x81, 0x5b]),
+        Some(&[0x81, 0x52]),
+        Some(&[0x81, 0x53]),
+        None,
+    ];
+
+    const BASE_0X3228: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        Some(&[0x87, 0x41]),
+        Some(&[0x87, 0x42]),
+        Some(&[0x87, 0x43]),
+        Some(&[0x87, 0x44]),
+        Some(&[0x87, 0x45]),
+        Some(&[0x87, 0x46]),
+        Some(&[0x87, 0x40]),
+        Some(&[0x87, 0x4d]),
+        Some(&[0x87, 0x50]),
+        Some(&[0x87, 0x53]),
+        Some(&[0x87, 0x4f]),
+        Some(&[0x87, 0x54]),
+        Some(&[0x87, 0x52]),
+        Some(&[0x87, 0x48]),
+        Some(&[0x87, 0x58]),
+        Some(&[0x87, 0x4b]),
+        Some(&[0x87, 0x4c]),
+        Some(&[0x87, 0x51]),
+        Some(&[0x87, 0x55]),
+        Some(&[0x87, 0x56]),
+        Some(&[0x87, 0x4e]),
+        Some(&[0x87, 0x57]),
+        Some(&[0x87, 0x47]),
+        None,
+        Some(&[0x87, 0x49]),
+        Some(&[0x87, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3290: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0x99]),
+        None,
+        Some(&[0x87, 0x9b]),
+        Some(&[0x87, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0x87, 0x9a]),
+        Some(&[0x87, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0x93]),
+        Some(&[0x87, 0x94]),
+        Some(&[0x87, 0x95]),
+        Some(&[0x87, 0x96]),
+        Some(&[0x87, 0x97]),
+        Some(&[0x87, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3300: [Option<&'static [u8]>; 88] = [
+        Some(&[0x87, 0xbd]),
+        None,
+        None,
+        Some(&[0x87, 0xa7]),
+        None,
+        Some(&[0x87, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xa2]),
+        Some(&[0x87, 0xaa]),
+        Some(&[0x87, 0xa3]),
+        None,
+        Some(&[0x87, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xa0]),
+        Some(&[0x87, 0xb2]),
+        None,
+        None,
+        Some(&[0x87, 0xb3]),
+        Some(&[0x87, 0xab]),
+        None,
+        None,
+        Some(&[0x87, 0xbf]),
+        Some(&[0x87, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xc0]),
+        None,
+        Some(&[0x87, 0xa5]),
+        None,
+        None,
+        Some(&[0x87, 0xa8]),
+        None,
+        None,
+        Some(&[0x87, 0xae]),
+        None,
+        Some(&[0x87, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xc1]),
+        None,
+        Some(&[0x87, 0x9f]),
+        Some(&[0x87, 0xad]),
+        None,
+        None,
+        Some(&[0x87, 0xa1]),
+        Some(&[0x87, 0xa6]),
+        None,
+        None,
+        Some(&[0x87, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xaf]),
+    ];
+
+    const BASE_0X3378: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        Some(&[0x87, 0xe8]),
+        Some(&[0x87, 0xe7]),
+        Some(&[0x87, 0xe6]),
+        Some(&[0x87, 0xe5]),
+        Some(&[0x87, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x5a]),
+        Some(&[0x86, 0x5b]),
+        Some(&[0x86, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x4a]),
+        Some(&[0x86, 0x4c]),
+        Some(&[0x86, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x4e]),
+        Some(&[0x86, 0x4f]),
+        Some(&[0x86, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x40]),
+        Some(&[0x86, 0x42]),
+        Some(&[0x86, 0x48]),
+        Some(&[0x86, 0x41]),
+        Some(&[0x86, 0x43]),
+        Some(&[0x86, 0x46]),
+        Some(&[0x86, 0x49]),
+        None,
+        Some(&[0x86, 0x44]),
+        Some(&[0x86, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x55]),
+        Some(&[0x86, 0x54]),
+        Some(&[0x86, 0x53]),
+        Some(&[0x86, 0x52]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X33C0: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x58]),
+        None,
+        Some(&[0x86, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x86, 0x57]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X4E00: [Option<&'static [u8]>; 288] = [
+        Some(&[0x88, 0xea]),
+        Some(&[0x92, 0x9a]),
+        None,
+        Some(&[0x8e, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x9c]),
+        Some(&[0x8f, 0xe4]),
+        Some(&[0x8e, 0x4f]),
+        Some(&[0x8f, 0xe3]),
+        Some(&[0x89, 0xba]),
+        None,
+        Some(&[0x95, 0x73]),
+        Some(&[0x97, 0x5e]),
+        None,
+        Some(&[0x98, 0xa0]),
+        Some(&[0x89, 0x4e]),
+        None,
+        None,
+        Some(&[0x8a, 0x8e]),
+        Some(&[0x98, 0xa1]),
+        Some(&[0x90, 0xa2]),
+        Some(&[0x99, 0xc0]),
+        Some(&[0x8b, 0x75]),
+        Some(&[0x95, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xe5]),
+        None,
+        None,
+        Some(&[0x97, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xa2]),
+        None,
+        None,
+        Some(&[0x92, 0x86]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xa3]),
+        Some(&[0x8b, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xa4]),
+        None,
+        Some(&[0x8a, 0xdb]),
+        Some(&[0x92, 0x4f]),
+        None,
+        Some(&[0x8e, 0xe5]),
+        Some(&[0x98, 0xa5]),
+        None,
+        None,
+        Some(&[0x98, 0xa6]),
+        None,
+        None,
+        Some(&[0x98, 0xa7]),
+        Some(&[0x94, 0x54]),
+        None,
+        Some(&[0x8b, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x56]),
+        None,
+        Some(&[0x93, 0xe1]),
+        Some(&[0x8c, 0xc1]),
+        Some(&[0x96, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x68]),
+        Some(&[0x98, 0xa8]),
+        Some(&[0x8f, 0xe6]),
+        Some(&[0x98, 0xa9]),
+        Some(&[0x89, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xe3]),
+        Some(&[0x8c, 0xee]),
+        Some(&[0x96, 0xe7]),
+        None,
+        None,
+        Some(&[0x9b, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x90]),
+        None,
+        Some(&[0x93, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xa3]),
+        None,
+        Some(&[0x8b, 0x54]),
+        None,
+        Some(&[0x98, 0xaa]),
+        None,
+        None,
+        Some(&[0x98, 0xab]),
+        Some(&[0x97, 0xb9]),
+        None,
+        Some(&[0x97, 0x5c]),
+        Some(&[0x91, 0x88]),
+        Some(&[0x98, 0xad]),
+        Some(&[0x8e, 0x96]),
+        Some(&[0x93, 0xf1]),
+        None,
+        Some(&[0x98, 0xb0]),
+        None,
+        None,
+        Some(&[0x89, 0x5d]),
+        Some(&[0x8c, 0xdd]),
+        None,
+        Some(&[0x8c, 0xdc]),
+        Some(&[0x88, 0xe4]),
+        None,
+        None,
+        Some(&[0x98, 0x6a]),
+        Some(&[0x98, 0x69]),
+        None,
+        Some(&[0x8d, 0xb1]),
+        Some(&[0x88, 0x9f]),
+        None,
+        Some(&[0x98, 0xb1]),
+        Some(&[0x98, 0xb2]),
+        Some(&[0x98, 0xb3]),
+        Some(&[0x96, 0x53]),
+        Some(&[0x98, 0xb4]),
+        None,
+        Some(&[0x8c, 0xf0]),
+        Some(&[0x88, 0xe5]),
+        Some(&[0x96, 0x92]),
+        None,
+        Some(&[0x8b, 0x9c]),
+        None,
+        None,
+        Some(&[0x8b, 0x9d]),
+        Some(&[0x8b, 0x9e]),
+        Some(&[0x92, 0xe0]),
+        Some(&[0x97, 0xba]),
+        None,
+        Some(&[0x98, 0xb5]),
+        None,
+        None,
+        Some(&[0x98, 0xb6]),
+        None,
+        None,
+        Some(&[0x98, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x59]),
+        Some(&[0x90, 0x6d]),
+        Some(&[0x98, 0xbc]),
+        None,
+        Some(&[0x98, 0xba]),
+        None,
+        Some(&[0x98, 0xbb]),
+        Some(&[0x8b, 0x77]),
+        None,
+        None,
+        Some(&[0x8d, 0xa1]),
+        Some(&[0x89, 0xee]),
+        None,
+        Some(&[0x98, 0xb9]),
+        Some(&[0x98, 0xb8]),
+        Some(&[0x95, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x65]),
+        Some(&[0x8e, 0x64]),
+        Some(&[0x91, 0xbc]),
+        Some(&[0x98, 0xbd]),
+        Some(&[0x95, 0x74]),
+        Some(&[0x90, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0x81, 0x57]),
+        Some(&[0x98, 0xbe]),
+        Some(&[0x98, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xe3]),
+        Some(&[0x97, 0xdf]),
+        Some(&[0x88, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xbf]),
+        Some(&[0x89, 0xbc]),
+        None,
+        Some(&[0x8b, 0xc2]),
+        None,
+        Some(&[0x92, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x8f]),
+        Some(&[0x98, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xc2]),
+        Some(&[0x88, 0xc9]),
+        None,
+        None,
+        Some(&[0x8c, 0xde]),
+        Some(&[0x8a, 0xea]),
+        Some(&[0x95, 0x9a]),
+        Some(&[0x94, 0xb0]),
+        Some(&[0x8b, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xef]),
+        None,
+        Some(&[0x98, 0xe5]),
+        Some(&[0x93, 0x60]),
+        None,
+        None,
+    ];
+
+    const BASE_0X4F28: [Option<&'static [u8]>; 376] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x8c]),
+        Some(&[0x98, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xba]),
+        None,
+        Some(&[0x97, 0xe0]),
+        None,
+        Some(&[0x90, 0x4c]),
+        None,
+        Some(&[0x8e, 0x66]),
+        None,
+        Some(&[0x8e, 0x97]),
+        Some(&[0x89, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xcf]),
+        None,
+        None,
+        Some(&[0x92, 0x41]),
+        Some(&[0x98, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xca]),
+        Some(&[0x92, 0xe1]),
+        Some(&[0x8f, 0x5a]),
+        Some(&[0x8d, 0xb2]),
+        Some(&[0x97, 0x43]),
+        None,
+        Some(&[0x91, 0xcc]),
+        None,
+        Some(&[0x89, 0xbd]),
+        None,
+        Some(&[0x98, 0xc7]),
+        None,
+        Some(&[0x97, 0x5d]),
+        Some(&[0x98, 0xc3]),
+        Some(&[0x98, 0xc5]),
+        Some(&[0x8d, 0xec]),
+        Some(&[0x98, 0xc6]),
+        Some(&[0x9b, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xd1]),
+        Some(&[0x98, 0xcf]),
+        None,
+        None,
+        Some(&[0x89, 0xc0]),
+        None,
+        Some(&[0x95, 0xb9]),
+        Some(&[0x98, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xcd]),
+        Some(&[0x8c, 0xf1]),
+        None,
+        None,
+        Some(&[0x8e, 0x67]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xa4]),
+        None,
+        None,
+        Some(&[0x98, 0xd2]),
+        None,
+        Some(&[0x98, 0xca]),
+        None,
+        None,
+        Some(&[0x97, 0xe1]),
+        None,
+        Some(&[0x8e, 0x98]),
+        None,
+        Some(&[0x98, 0xcb]),
+        None,
+        Some(&[0x98, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xd3]),
+        None,
+        Some(&[0x98, 0xcc]),
+        None,
+        None,
+        Some(&[0x8b, 0x9f]),
+        None,
+        Some(&[0x88, 0xcb]),
+        None,
+        None,
+        Some(&[0x8b, 0xa0]),
+        Some(&[0x89, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x44]),
+        None,
+        Some(&[0x96, 0x99]),
+        Some(&[0x95, 0x8e]),
+        Some(&[0x8c, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x4e]),
+        Some(&[0x97, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xd6]),
+        None,
+        None,
+        Some(&[0x8c, 0x57]),
+        Some(&[0x91, 0xa3]),
+        Some(&[0x89, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x72]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xd7]),
+        None,
+        Some(&[0x98, 0xdc]),
+        Some(&[0x98, 0xda]),
+        None,
+        None,
+        Some(&[0x98, 0xd5]),
+        None,
+        None,
+        Some(&[0x91, 0xad]),
+        Some(&[0x98, 0xd8]),
+        None,
+        Some(&[0x98, 0xdb]),
+        Some(&[0x98, 0xd9]),
+        None,
+        Some(&[0x95, 0xdb]),
+        None,
+        Some(&[0x98, 0xd6]),
+        None,
+        Some(&[0x90, 0x4d]),
+        None,
+        Some(&[0x96, 0x93]),
+        Some(&[0x98, 0xdd]),
+        Some(&[0x98, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x43]),
+        Some(&[0x98, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x6f]),
+        None,
+        Some(&[0x95, 0x55]),
+        Some(&[0x98, 0xe6]),
+        None,
+        Some(&[0x95, 0xee]),
+        None,
+        Some(&[0x89, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xe4]),
+        Some(&[0x98, 0xed]),
+        None,
+        None,
+        Some(&[0x91, 0x71]),
+        None,
+        Some(&[0x8c, 0xc2]),
+        None,
+        Some(&[0x94, 0x7b]),
+        None,
+        Some(&[0xe0, 0xc5]),
+        None,
+        Some(&[0x98, 0xec]),
+        Some(&[0x93, 0x7c]),
+        None,
+        Some(&[0x98, 0xe1]),
+        None,
+        Some(&[0x8c, 0xf4]),
+        None,
+        None,
+        Some(&[0x8c, 0xf3]),
+        Some(&[0x98, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xd8]),
+        None,
+        Some(&[0x98, 0xe7]),
+        None,
+        Some(&[0x95, 0xed]),
+        Some(&[0x92, 0x6c]),
+        Some(&[0x98, 0xe3]),
+        Some(&[0x8c, 0x91]),
+        None,
+        Some(&[0x98, 0xe0]),
+        Some(&[0x98, 0xe8]),
+        Some(&[0x98, 0xe2]),
+        Some(&[0x97, 0xcf]),
+        Some(&[0x98, 0xe9]),
+        Some(&[0x98, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xe4]),
+        None,
+        None,
+        Some(&[0x8c, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xef]),
+        Some(&[0x98, 0xf3]),
+        Some(&[0x88, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xce]),
+        Some(&[0x98, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xf1]),
+        Some(&[0x98, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xf4]),
+        None,
+        Some(&[0x92, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xc3]),
+        None,
+        Some(&[0x91, 0xa4]),
+        Some(&[0x92, 0xe3]),
+        Some(&[0x8b, 0xf4]),
+        None,
+        Some(&[0x98, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x55]),
+        None,
+        None,
+        Some(&[0x98, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x50]),
+        Some(&[0x94, 0xf5]),
+        Some(&[0x98, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X50A8: [Option<&'static [u8]>; 736] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xc3]),
+        Some(&[0x97, 0x62]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0xfc]),
+        Some(&[0x99, 0x42]),
+        Some(&[0x98, 0xfb]),
+        Some(&[0x8d, 0xc2]),
+        None,
+        Some(&[0x8f, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x43]),
+        None,
+        None,
+        Some(&[0x8b, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x40]),
+        Some(&[0x99, 0x41]),
+        None,
+        None,
+        Some(&[0x93, 0xad]),
+        None,
+        Some(&[0x91, 0x9c]),
+        None,
+        Some(&[0x8b, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x6c]),
+        Some(&[0x99, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x48]),
+        None,
+        Some(&[0x99, 0x46]),
+        None,
+        Some(&[0x91, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x47]),
+        Some(&[0x99, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x4a]),
+        None,
+        Some(&[0x95, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x56]),
+        Some(&[0x99, 0x4d]),
+        Some(&[0x99, 0x4e]),
+        None,
+        Some(&[0x89, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xf2]),
+        None,
+        Some(&[0x99, 0x51]),
+        Some(&[0x99, 0x50]),
+        Some(&[0x99, 0x4f]),
+        None,
+        Some(&[0x98, 0xd4]),
+        None,
+        Some(&[0x99, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x9e]),
+        None,
+        Some(&[0x99, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x55]),
+        None,
+        None,
+        Some(&[0x99, 0x54]),
+        Some(&[0x99, 0x57]),
+        Some(&[0x99, 0x56]),
+        None,
+        None,
+        Some(&[0x99, 0x58]),
+        Some(&[0x99, 0x59]),
+        Some(&[0x88, 0xf2]),
+        None,
+        Some(&[0x8c, 0xb3]),
+        Some(&[0x8c, 0x5a]),
+        Some(&[0x8f, 0x5b]),
+        Some(&[0x92, 0x9b]),
+        Some(&[0x8b, 0xa2]),
+        Some(&[0x90, 0xe6]),
+        Some(&[0x8c, 0xf5]),
+        None,
+        Some(&[0x8d, 0x8e]),
+        Some(&[0x99, 0x5b]),
+        Some(&[0x96, 0xc6]),
+        Some(&[0x93, 0x65]),
+        None,
+        Some(&[0x8e, 0x99]),
+        None,
+        Some(&[0x99, 0x5a]),
+        None,
+        Some(&[0x99, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x7d]),
+        None,
+        Some(&[0x8a, 0x95]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x5d]),
+        None,
+        None,
+        Some(&[0x93, 0xfc]),
+        None,
+        None,
+        Some(&[0x91, 0x53]),
+        Some(&[0x99, 0x5f]),
+        Some(&[0x99, 0x60]),
+        Some(&[0x94, 0xaa]),
+        Some(&[0x8c, 0xf6]),
+        Some(&[0x98, 0x5a]),
+        Some(&[0x99, 0x61]),
+        None,
+        None,
+        Some(&[0x8b, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xba]),
+        Some(&[0x91, 0xb4]),
+        Some(&[0x8b, 0xef]),
+        Some(&[0x93, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x93]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x62]),
+        None,
+        Some(&[0x99, 0x63]),
+        None,
+        None,
+        Some(&[0x93, 0xe0]),
+        Some(&[0x89, 0x7e]),
+        None,
+        None,
+        Some(&[0x99, 0x66]),
+        Some(&[0x8d, 0xfb]),
+        None,
+        Some(&[0x99, 0x65]),
+        Some(&[0x8d, 0xc4]),
+        None,
+        Some(&[0x99, 0x67]),
+        Some(&[0xe3, 0xec]),
+        Some(&[0x99, 0x68]),
+        Some(&[0x96, 0x60]),
+        Some(&[0x99, 0x69]),
+        None,
+        Some(&[0x99, 0x6a]),
+        Some(&[0x99, 0x6b]),
+        Some(&[0x8f, 0xe7]),
+        None,
+        Some(&[0x8e, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xa5]),
+        None,
+        Some(&[0x99, 0x6e]),
+        None,
+        Some(&[0x99, 0x6c]),
+        Some(&[0x96, 0xbb]),
+        Some(&[0x99, 0x6d]),
+        None,
+        Some(&[0x95, 0x79]),
+        Some(&[0x99, 0x6f]),
+        Some(&[0x99, 0x70]),
+        Some(&[0x99, 0x71]),
+        Some(&[0x93, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x75]),
+        Some(&[0x99, 0x73]),
+        Some(&[0x99, 0x74]),
+        Some(&[0x99, 0x72]),
+        Some(&[0x8d, 0xe1]),
+        Some(&[0x99, 0x76]),
+        Some(&[0x96, 0xe8]),
+        Some(&[0x97, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xa6]),
+        Some(&[0x99, 0x78]),
+        Some(&[0x8f, 0x79]),
+        None,
+        None,
+        Some(&[0x99, 0x79]),
+        None,
+        Some(&[0x92, 0x9c]),
+        Some(&[0x97, 0xbd]),
+        Some(&[0x93, 0x80]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x7a]),
+        Some(&[0xea, 0xa3]),
+        Some(&[0x8b, 0xc3]),
+        None,
+        None,
+        Some(&[0x99, 0x7b]),
+        Some(&[0x96, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x88]),
+        Some(&[0x91, 0xfa]),
+        None,
+        Some(&[0x99, 0x7d]),
+        Some(&[0x93, 0xe2]),
+        None,
+        None,
+        Some(&[0x99, 0x7e]),
+        None,
+        None,
+        Some(&[0x99, 0x80]),
+        Some(&[0x8a, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x81]),
+        Some(&[0x8b, 0xa5]),
+        None,
+        Some(&[0x93, 0xca]),
+        Some(&[0x89, 0x9a]),
+        Some(&[0x8f, 0x6f]),
+        None,
+        None,
+        Some(&[0x94, 0x9f]),
+        Some(&[0x99, 0x82]),
+        None,
+        Some(&[0x93, 0x81]),
+        None,
+        None,
+        Some(&[0x90, 0x6e]),
+        Some(&[0x99, 0x83]),
+        None,
+        Some(&[0x95, 0xaa]),
+        Some(&[0x90, 0xd8]),
+        Some(&[0x8a, 0xa0]),
+        None,
+        Some(&[0x8a, 0xa7]),
+        Some(&[0x99, 0x84]),
+        None,
+        None,
+        Some(&[0x99, 0x86]),
+        None,
+        None,
+        Some(&[0x8c, 0x59]),
+        None,
+        None,
+        Some(&[0x99, 0x85]),
+        None,
+        None,
+        Some(&[0x97, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xbb]),
+        Some(&[0x95, 0xca]),
+        None,
+        Some(&[0x99, 0x87]),
+        None,
+        Some(&[0x97, 0x98]),
+        Some(&[0x99, 0x88]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x89]),
+        None,
+        Some(&[0x93, 0x9e]),
+        None,
+        None,
+        Some(&[0x99, 0x8a]),
+        None,
+        None,
+        Some(&[0x90, 0xa7]),
+        Some(&[0x8d, 0xfc]),
+        Some(&[0x8c, 0x94]),
+        Some(&[0x99, 0x8b]),
+        Some(&[0x8e, 0x68]),
+        Some(&[0x8d, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xe4]),
+        Some(&[0x99, 0x8d]),
+        None,
+        None,
+        Some(&[0x91, 0xa5]),
+        None,
+        None,
+        Some(&[0x8d, 0xed]),
+        Some(&[0x99, 0x8e]),
+        Some(&[0x99, 0x8f]),
+        Some(&[0x91, 0x4f]),
+        None,
+        Some(&[0x99, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x91]),
+        None,
+        Some(&[0x96, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x84]),
+        None,
+        None,
+        Some(&[0x99, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x95]),
+        Some(&[0x8d, 0xdc]),
+        Some(&[0x94, 0x8d]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x94]),
+        Some(&[0x99, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x9b]),
+        Some(&[0x8f, 0xe8]),
+        Some(&[0x99, 0x9b]),
+        Some(&[0x8a, 0x84]),
+        Some(&[0x99, 0x95]),
+        Some(&[0x99, 0x93]),
+        Some(&[0x91, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x97]),
+        None,
+        Some(&[0x99, 0x96]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x63]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x80]),
+        Some(&[0x99, 0x9c]),
+        Some(&[0x97, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x98]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x9d]),
+        Some(&[0x99, 0x9a]),
+        None,
+        Some(&[0x99, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xf7]),
+        Some(&[0x89, 0xc1]),
+        None,
+        None,
+        Some(&[0x97, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x95]),
+        Some(&[0x93, 0x77]),
+        Some(&[0x8d, 0x85]),
+        Some(&[0x99, 0xa0]),
+        Some(&[0x99, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xe3]),
+        None,
+        None,
+        Some(&[0x98, 0x4a]),
+        Some(&[0x99, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xf8]),
+        None,
+        None,
+        Some(&[0x99, 0xa2]),
+        None,
+        Some(&[0x8a, 0x4e]),
+        None,
+        None,
+        Some(&[0x99, 0xa4]),
+        None,
+        Some(&[0x96, 0x75]),
+        None,
+        Some(&[0x92, 0xba]),
+        None,
+        Some(&[0x97, 0x45]),
+        None,
+        Some(&[0x95, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd3]),
+        None,
+        None,
+        Some(&[0x93, 0xae]),
+        None,
+        Some(&[0x99, 0xa6]),
+        Some(&[0x8a, 0xa8]),
+        Some(&[0x96, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x9f]),
+        Some(&[0x99, 0xa7]),
+        Some(&[0x95, 0xe5]),
+        Some(&[0x99, 0xab]),
+        None,
+        Some(&[0x90, 0xa8]),
+        Some(&[0x99, 0xa8]),
+        Some(&[0x8b, 0xce]),
+        None,
+        Some(&[0x99, 0xa9]),
+        Some(&[0x8a, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x4d]),
+        Some(&[0x99, 0xac]),
+        None,
+        Some(&[0x99, 0xad]),
+        None,
+        None,
+        Some(&[0x99, 0xae]),
+        Some(&[0x99, 0xaf]),
+        Some(&[0x8e, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xf9]),
+        Some(&[0x96, 0xdc]),
+        None,
+        Some(&[0x96, 0xe6]),
+        Some(&[0x93, 0xf5]),
+        None,
+        None,
+        Some(&[0x95, 0xef]),
+        Some(&[0x99, 0xb0]),
+        None,
+        Some(&[0x99, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xb3]),
+        None,
+        Some(&[0x99, 0xb5]),
+        Some(&[0x99, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xb6]),
+        Some(&[0x89, 0xbb]),
+        Some(&[0x96, 0x6b]),
+        None,
+        Some(&[0x8d, 0xfa]),
+        Some(&[0x99, 0xb7]),
+        None,
+        None,
+        Some(&[0x91, 0x78]),
+        None,
+        None,
+        Some(&[0x8f, 0xa0]),
+        Some(&[0x8b, 0xa7]),
+        None,
+        Some(&[0x99, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xb9]),
+        None,
+        Some(&[0x99, 0xba]),
+        None,
+        Some(&[0x99, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xbc]),
+        Some(&[0x95, 0x43]),
+        Some(&[0x8b, 0xe6]),
+        Some(&[0x88, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xbd]),
+        Some(&[0x99, 0xbd]),
+        Some(&[0x8f, 0x5c]),
+        None,
+        Some(&[0x90, 0xe7]),
+        None,
+        Some(&[0x99, 0xbf]),
+        Some(&[0x99, 0xbe]),
+        Some(&[0x8f, 0xa1]),
+        Some(&[0x8c, 0xdf]),
+        Some(&[0x99, 0xc1]),
+        Some(&[0x94, 0xbc]),
+        None,
+        None,
+        Some(&[0x99, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xda]),
+        Some(&[0x91, 0xb2]),
+        Some(&[0x91, 0xec]),
+        Some(&[0x8b, 0xa6]),
+        None,
+        None,
+        Some(&[0x93, 0xec]),
+        Some(&[0x92, 0x50]),
+        None,
+        Some(&[0x94, 0x8e]),
+        None,
+        Some(&[0x96, 0x6d]),
+        None,
+        Some(&[0x99, 0xc4]),
+        None,
+        Some(&[0x90, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x54]),
+        None,
+        None,
+        Some(&[0x99, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xc6]),
+        Some(&[0x89, 0x4b]),
+        Some(&[0x88, 0xf3]),
+        Some(&[0x8a, 0xeb]),
+        None,
+        Some(&[0x91, 0xa6]),
+        Some(&[0x8b, 0x70]),
+        Some(&[0x97, 0x91]),
+        None,
+        Some(&[0x99, 0xc9]),
+        Some(&[0x89, 0xb5]),
+        None,
+        None,
+        Some(&[0x99, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xa8]),
+        None,
+        None,
+        Some(&[0x99, 0xca]),
+        None,
+        Some(&[0x96, 0xef]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5390: [Option<&'static [u8]>; 392] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xcb]),
+        None,
+        Some(&[0x97, 0xd0]),
+        None,
+        Some(&[0x8c, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xb4]),
+        Some(&[0x99, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xce]),
+        Some(&[0x99, 0xcd]),
+        None,
+        Some(&[0x90, 0x7e]),
+        Some(&[0x89, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x7d]),
+        Some(&[0x99, 0xcf]),
+        None,
+        Some(&[0x99, 0xd0]),
+        None,
+        None,
+        Some(&[0x8c, 0xb5]),
+        None,
+        None,
+        Some(&[0x99, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x51]),
+        Some(&[0x99, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x94]),
+        Some(&[0x8d, 0xb3]),
+        Some(&[0x8b, 0x79]),
+        Some(&[0x97, 0x46]),
+        Some(&[0x91, 0x6f]),
+        Some(&[0x94, 0xbd]),
+        Some(&[0x8e, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x66]),
+        None,
+        Some(&[0x8e, 0xe6]),
+        Some(&[0x8e, 0xf3]),
+        None,
+        Some(&[0x8f, 0x96]),
+        None,
+        Some(&[0x94, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xd5]),
+        None,
+        Some(&[0x89, 0x62]),
+        Some(&[0x91, 0x70]),
+        Some(&[0x8c, 0xfb]),
+        Some(&[0x8c, 0xc3]),
+        Some(&[0x8b, 0xe5]),
+        None,
+        None,
+        Some(&[0x99, 0xd9]),
+        Some(&[0x92, 0x40]),
+        Some(&[0x91, 0xfc]),
+        Some(&[0x8b, 0xa9]),
+        Some(&[0x8f, 0xa2]),
+        Some(&[0x99, 0xda]),
+        Some(&[0x99, 0xd8]),
+        Some(&[0x89, 0xc2]),
+        Some(&[0x91, 0xe4]),
+        Some(&[0x8e, 0xb6]),
+        Some(&[0x8e, 0x6a]),
+        Some(&[0x89, 0x45]),
+        None,
+        None,
+        Some(&[0x8a, 0x90]),
+        Some(&[0x8d, 0x86]),
+        Some(&[0x8e, 0x69]),
+        None,
+        Some(&[0x99, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xdc]),
+        None,
+        Some(&[0x8b, 0x68]),
+        Some(&[0x8a, 0x65]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x87]),
+        Some(&[0x8b, 0x67]),
+        Some(&[0x92, 0xdd]),
+        Some(&[0x89, 0x44]),
+        Some(&[0x93, 0xaf]),
+        Some(&[0x96, 0xbc]),
+        Some(&[0x8d, 0x40]),
+        Some(&[0x97, 0x99]),
+        Some(&[0x93, 0x66]),
+        Some(&[0x8c, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x4e]),
+        None,
+        Some(&[0x99, 0xe5]),
+        None,
+        Some(&[0x8b, 0xe1]),
+        Some(&[0x96, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xdb]),
+        None,
+        None,
+        Some(&[0x99, 0xe4]),
+        None,
+        Some(&[0x8a, 0xdc]),
+        Some(&[0x99, 0xdf]),
+        Some(&[0x99, 0xe0]),
+        Some(&[0x99, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xe3]),
+        None,
+        Some(&[0x8b, 0x7a]),
+        Some(&[0x90, 0x81]),
+        None,
+        Some(&[0x95, 0xab]),
+        Some(&[0x99, 0xe1]),
+        Some(&[0x99, 0xdd]),
+        Some(&[0x8c, 0xe1]),
+        None,
+        Some(&[0x99, 0xde]),
+        None,
+        Some(&[0x98, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xf0]),
+        None,
+        Some(&[0x92, 0xe6]),
+        Some(&[0x8c, 0xe0]),
+        Some(&[0x8d, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xe6]),
+        None,
+        None,
+        Some(&[0x93, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xfc]),
+        None,
+        Some(&[0x8e, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xed]),
+        Some(&[0x99, 0xeb]),
+        None,
+        Some(&[0x96, 0xa1]),
+        None,
+        Some(&[0x99, 0xe8]),
+        Some(&[0x99, 0xf1]),
+        Some(&[0x99, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xef]),
+        Some(&[0x8c, 0xc4]),
+        Some(&[0x96, 0xbd]),
+        None,
+        None,
+        Some(&[0x99, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xf2]),
+        None,
+        Some(&[0x99, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xee]),
+        Some(&[0x98, 0x61]),
+        None,
+        Some(&[0x99, 0xe9]),
+        Some(&[0x99, 0xe7]),
+        Some(&[0x99, 0xf3]),
+        None,
+        Some(&[0x99, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xf6]),
+        None,
+        Some(&[0x9a, 0x42]),
+        Some(&[0x99, 0xf8]),
+        None,
+        None,
+        Some(&[0x99, 0xfc]),
+        None,
+        None,
+        Some(&[0x9a, 0x40]),
+        Some(&[0x99, 0xf9]),
+        None,
+        None,
+        Some(&[0x9a, 0x5d]),
+        None,
+        None,
+        Some(&[0x8d, 0xe7]),
+        Some(&[0x8a, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x99, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x44]),
+        Some(&[0x88, 0xf4]),
+        Some(&[0x9a, 0x43]),
+        None,
+        Some(&[0x88, 0xa3]),
+        Some(&[0x95, 0x69]),
+        Some(&[0x9a, 0x41]),
+        None,
+        Some(&[0x99, 0xfa]),
+        None,
+        None,
+        Some(&[0x99, 0xf5]),
+        Some(&[0x99, 0xfb]),
+        Some(&[0x8d, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xf5]),
+        Some(&[0x9a, 0x4e]),
+        None,
+        None,
+        Some(&[0x9a, 0x46]),
+        Some(&[0x9a, 0x47]),
+        None,
+        Some(&[0x8f, 0xa3]),
+        Some(&[0x96, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x4c]),
+        Some(&[0x9a, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x4d]),
+        None,
+        None,
+        Some(&[0x9a, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x53]),
+        None,
+        Some(&[0x8d, 0xb4]),
+        Some(&[0x90, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x48]),
+        Some(&[0x93, 0x82]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x49]),
+        None,
+        Some(&[0x88, 0xa0]),
+        None,
+    ];
+
+    const BASE_0X5528: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x53]),
+        Some(&[0x97, 0x42]),
+        None,
+        Some(&[0x8f, 0xa5]),
+        None,
+        Some(&[0x9a, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x58]),
+        Some(&[0x9a, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xc1]),
+        None,
+        Some(&[0x9a, 0x50]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xed]),
+        Some(&[0x9a, 0x55]),
+        Some(&[0x8f, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x52]),
+        None,
+        None,
+        Some(&[0x96, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x5b]),
+        None,
+        None,
+        Some(&[0x9a, 0x56]),
+        Some(&[0x9a, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x54]),
+        Some(&[0x9a, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x51]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5578: [Option<&'static [u8]>; 112] = [
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x60]),
+        Some(&[0x9a, 0x65]),
+        None,
+        Some(&[0x9a, 0x61]),
+        None,
+        Some(&[0x9a, 0x5c]),
+        None,
+        None,
+        Some(&[0x9a, 0x66]),
+        Some(&[0x91, 0x50]),
+        None,
+        None,
+        Some(&[0x9a, 0x68]),
+        None,
+        Some(&[0x8d, 0x41]),
+        Some(&[0x9a, 0x5e]),
+        Some(&[0x92, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x62]),
+        Some(&[0x9a, 0x5b]),
+        Some(&[0x8a, 0xab]),
+        None,
+        Some(&[0x8a, 0xec]),
+        Some(&[0x8a, 0x85]),
+        Some(&[0x9a, 0x63]),
+        Some(&[0x9a, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x96]),
+        Some(&[0x9a, 0x69]),
+        Some(&[0x9a, 0x67]),
+        Some(&[0x91, 0x72]),
+        Some(&[0x8b, 0x69]),
+        Some(&[0x8b, 0xaa]),
+        None,
+        Some(&[0x9a, 0x64]),
+        None,
+        Some(&[0x8b, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x6d]),
+        Some(&[0x9a, 0x6b]),
+        None,
+        Some(&[0x9a, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x6a]),
+        None,
+        Some(&[0x9a, 0x6e]),
+        None,
+        None,
+        Some(&[0x9a, 0x6c]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x6b]),
+        Some(&[0x9a, 0x6f]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X55F0: [Option<&'static [u8]>; 384] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x72]),
+        None,
+        Some(&[0x9a, 0x77]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x75]),
+        Some(&[0x9a, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x51]),
+        None,
+        None,
+        Some(&[0x89, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x71]),
+        None,
+        Some(&[0x9a, 0x73]),
+        Some(&[0x8f, 0xa6]),
+        Some(&[0x89, 0x52]),
+        None,
+        None,
+        Some(&[0x9a, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x82]),
+        None,
+        Some(&[0x8f, 0xfa]),
+        Some(&[0x9a, 0x7d]),
+        None,
+        Some(&[0x9a, 0x7b]),
+        None,
+        Some(&[0x9a, 0x7c]),
+        None,
+        Some(&[0x9a, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x58]),
+        None,
+        Some(&[0x9a, 0x78]),
+        None,
+        Some(&[0x9a, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x81]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xed]),
+        None,
+        Some(&[0x9a, 0x84]),
+        Some(&[0x9a, 0x80]),
+        Some(&[0x9a, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xd3]),
+        None,
+        Some(&[0x94, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x85]),
+        Some(&[0x8a, 0x64]),
+        None,
+        None,
+        Some(&[0x9a, 0x87]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x88]),
+        None,
+        Some(&[0x94, 0x58]),
+        None,
+        None,
+        Some(&[0x9a, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x8e]),
+        None,
+        Some(&[0x9a, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x93]),
+        Some(&[0x9a, 0x91]),
+        Some(&[0x9a, 0x8f]),
+        Some(&[0x9a, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x94]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x95]),
+        None,
+        None,
+        Some(&[0x9a, 0x96]),
+        None,
+        Some(&[0x9a, 0x97]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x98]),
+        Some(&[0x99, 0x64]),
+        None,
+        Some(&[0x8e, 0xfa]),
+        Some(&[0x8e, 0x6c]),
+        None,
+        None,
+        Some(&[0x89, 0xf1]),
+        None,
+        Some(&[0x88, 0xf6]),
+        None,
+        None,
+        Some(&[0x92, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x99]),
+        None,
+        Some(&[0x8d, 0xa2]),
+        None,
+        Some(&[0x88, 0xcd]),
+        Some(&[0x90, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x9a]),
+        Some(&[0x8c, 0xc5]),
+        None,
+        None,
+        Some(&[0x8d, 0x91]),
+        None,
+        Some(&[0x9a, 0x9c]),
+        Some(&[0x9a, 0x9b]),
+        None,
+        None,
+        Some(&[0x95, 0xde]),
+        Some(&[0x9a, 0x9d]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x9f]),
+        Some(&[0x9a, 0x9e]),
+        None,
+        Some(&[0x9a, 0xa0]),
+        None,
+        Some(&[0x9a, 0xa1]),
+        None,
+        Some(&[0x8c, 0x97]),
+        None,
+        None,
+        Some(&[0x89, 0x80]),
+        Some(&[0x9a, 0xa2]),
+        None,
+        None,
+        Some(&[0x9a, 0xa4]),
+        None,
+        Some(&[0x9a, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xa6]),
+        None,
+        None,
+        Some(&[0x93, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xa7]),
+        Some(&[0x88, 0xb3]),
+        Some(&[0x8d, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x5c]),
+        None,
+        None,
+        Some(&[0x92, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xa8]),
+        Some(&[0x9a, 0xa9]),
+        None,
+        None,
+        Some(&[0x9a, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xac]),
+        None,
+        Some(&[0x8d, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xcf]),
+        None,
+        None,
+        Some(&[0x96, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xaa]),
+        Some(&[0x9a, 0xad]),
+        Some(&[0x8d, 0xbf]),
+        Some(&[0x8d, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb1]),
+        None,
+        None,
+        Some(&[0x8d, 0xa3]),
+        None,
+        Some(&[0x92, 0x52]),
+        None,
+        None,
+        Some(&[0x9a, 0xae]),
+        Some(&[0x92, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5778: [Option<&'static [u8]>; 112] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb2]),
+        None,
+        None,
+        Some(&[0x90, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb0]),
+        Some(&[0x9a, 0xb3]),
+        None,
+        Some(&[0x8c, 0x5e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb5]),
+        None,
+        Some(&[0x8d, 0x43]),
+        Some(&[0x8a, 0x5f]),
+        Some(&[0x9a, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xb9]),
+        None,
+        None,
+        Some(&[0x9a, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xaf]),
+        None,
+        None,
+        Some(&[0x9a, 0xba]),
+        None,
+        None,
+        Some(&[0x9a, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x84]),
+        None,
+        None,
+        Some(&[0x8f, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xbd]),
+        Some(&[0x9a, 0xbe]),
+        Some(&[0x9a, 0xbc]),
+        None,
+        Some(&[0x9a, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x57]),
+        None,
+        None,
+        Some(&[0x88, 0xe6]),
+        Some(&[0x95, 0x75]),
+        None,
+        None,
+        Some(&[0x9a, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X57F0: [Option<&'static [u8]>; 608] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xfb]),
+        None,
+        None,
+        Some(&[0x8e, 0xb7]),
+        None,
+        Some(&[0x94, 0x7c]),
+        Some(&[0x8a, 0xee]),
+        None,
+        Some(&[0x8d, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x78]),
+        None,
+        Some(&[0x93, 0xb0]),
+        None,
+        None,
+        Some(&[0x8c, 0x98]),
+        Some(&[0x91, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xbf]),
+        Some(&[0x9a, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xc6]),
+        None,
+        None,
+        Some(&[0x92, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x9f]),
+        Some(&[0x89, 0x81]),
+        Some(&[0x95, 0xf1]),
+        None,
+        None,
+        Some(&[0x8f, 0xea]),
+        Some(&[0x93, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xe4]),
+        None,
+        None,
+        Some(&[0x9a, 0xcc]),
+        None,
+        None,
+        Some(&[0x95, 0xbb]),
+        Some(&[0x97, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xf2]),
+        Some(&[0x9a, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x59]),
+        Some(&[0x9a, 0xcb]),
+        None,
+        Some(&[0x93, 0x83]),
+        None,
+        None,
+        Some(&[0x93, 0x68]),
+        Some(&[0x93, 0x84]),
+        Some(&[0x94, 0xb7]),
+        Some(&[0x92, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x96]),
+        None,
+        Some(&[0x93, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xc9]),
+        None,
+        Some(&[0x9a, 0xc5]),
+        None,
+        None,
+        Some(&[0x90, 0x6f]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xab]),
+        None,
+        Some(&[0x9a, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xc4]),
+        None,
+        None,
+        Some(&[0x9a, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x6e]),
+        None,
+        None,
+        Some(&[0x9a, 0xd1]),
+        None,
+        None,
+        Some(&[0x9a, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xd5]),
+        Some(&[0x9a, 0xcf]),
+        Some(&[0x9a, 0xd2]),
+        Some(&[0x9a, 0xd4]),
+        None,
+        None,
+        Some(&[0x8d, 0xa4]),
+        None,
+        None,
+        Some(&[0x95, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xd7]),
+        None,
+        Some(&[0x92, 0x64]),
+        None,
+        None,
+        Some(&[0x89, 0xf3]),
+        None,
+        Some(&[0x8f, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xd9]),
+        None,
+        Some(&[0x9a, 0xd8]),
+        None,
+        Some(&[0x8d, 0x88]),
+        None,
+        Some(&[0x9a, 0xda]),
+        Some(&[0x9a, 0xdc]),
+        Some(&[0x9a, 0xdb]),
+        None,
+        None,
+        Some(&[0x9a, 0xde]),
+        None,
+        Some(&[0x9a, 0xd3]),
+        Some(&[0x9a, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xdf]),
+        Some(&[0x9a, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x6d]),
+        Some(&[0x90, 0x70]),
+        None,
+        Some(&[0x91, 0x73]),
+        Some(&[0x9a, 0xe1]),
+        Some(&[0x90, 0xba]),
+        Some(&[0x88, 0xeb]),
+        Some(&[0x94, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xd9]),
+        None,
+        Some(&[0x9a, 0xe3]),
+        Some(&[0x9a, 0xe2]),
+        Some(&[0x9a, 0xe4]),
+        Some(&[0x9a, 0xe5]),
+        Some(&[0x9a, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xcf]),
+        Some(&[0x9a, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xc4]),
+        Some(&[0x9a, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x5b]),
+        Some(&[0x8a, 0x4f]),
+        None,
+        Some(&[0x99, 0xc7]),
+        Some(&[0x8f, 0x67]),
+        Some(&[0x91, 0xbd]),
+        Some(&[0x9a, 0xea]),
+        Some(&[0x96, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xb2]),
+        None,
+        None,
+        Some(&[0x9a, 0xec]),
+        None,
+        Some(&[0x91, 0xe5]),
+        None,
+        Some(&[0x93, 0x56]),
+        Some(&[0x91, 0xbe]),
+        Some(&[0x95, 0x76]),
+        Some(&[0x9a, 0xed]),
+        Some(&[0x9a, 0xee]),
+        Some(&[0x89, 0x9b]),
+        None,
+        None,
+        Some(&[0x8e, 0xb8]),
+        Some(&[0x9a, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xce]),
+        Some(&[0x9a, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x82]),
+        None,
+        None,
+        Some(&[0x8a, 0xef]),
+        Some(&[0x93, 0xde]),
+        Some(&[0x95, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xf5]),
+        Some(&[0x91, 0x74]),
+        Some(&[0x9a, 0xf4]),
+        Some(&[0x8c, 0x5f]),
+        None,
+        None,
+        Some(&[0x96, 0x7a]),
+        Some(&[0x9a, 0xf3]),
+        None,
+        Some(&[0x93, 0x85]),
+        Some(&[0x9a, 0xf7]),
+        None,
+        Some(&[0x9a, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xf9]),
+        None,
+        Some(&[0x9a, 0xf8]),
+        None,
+        None,
+        Some(&[0x89, 0x9c]),
+        None,
+        Some(&[0x9a, 0xfa]),
+        Some(&[0x8f, 0xa7]),
+        Some(&[0x9a, 0xfc]),
+        Some(&[0x92, 0x44]),
+        None,
+        Some(&[0x9a, 0xfb]),
+        None,
+        Some(&[0x95, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x97]),
+        Some(&[0x93, 0x7a]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x40]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x41]),
+        Some(&[0x94, 0x40]),
+        Some(&[0x94, 0xdc]),
+        Some(&[0x96, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x44]),
+        None,
+        None,
+        Some(&[0x9b, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x57]),
+        None,
+        None,
+        Some(&[0x97, 0x64]),
+        None,
+        None,
+        Some(&[0x96, 0xad]),
+        None,
+        Some(&[0x9b, 0xaa]),
+        None,
+        Some(&[0x9b, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x45]),
+        None,
+        Some(&[0x91, 0xc3]),
+        None,
+        None,
+        Some(&[0x96, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x85]),
+        None,
+        Some(&[0x8d, 0xc8]),
+        None,
+        None,
+        Some(&[0x8f, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x47]),
+        None,
+        None,
+        Some(&[0x8e, 0x6f]),
+        None,
+        Some(&[0x8e, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xb7]),
+        Some(&[0x8c, 0xc6]),
+        None,
+        Some(&[0x90, 0xa9]),
+        Some(&[0x88, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x4b]),
+        Some(&[0x9b, 0x4c]),
+        None,
+        Some(&[0x9b, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x57]),
+        Some(&[0x8a, 0xad]),
+        None,
+        Some(&[0x9b, 0x48]),
+        None,
+        Some(&[0x96, 0xc3]),
+        Some(&[0x95, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x70]),
+        None,
+        Some(&[0x88, 0xd0]),
+        None,
+        Some(&[0x88, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xba]),
+        None,
+        Some(&[0x9b, 0x52]),
+        None,
+        Some(&[0x9b, 0x50]),
+        None,
+        None,
+        Some(&[0x9b, 0x4e]),
+        Some(&[0x90, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x56]),
+        Some(&[0x9b, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x53]),
+        Some(&[0x98, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x6b]),
+        None,
+        None,
+        Some(&[0x9b, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5A58: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        Some(&[0x8d, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x77]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x59]),
+        None,
+        Some(&[0x9b, 0x54]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5A90: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        Some(&[0x94, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x5a]),
+        Some(&[0x95, 0x51]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5AB8: [Option<&'static [u8]>; 56] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x5b]),
+        Some(&[0x9b, 0x5f]),
+        Some(&[0x9b, 0x5c]),
+        None,
+        None,
+        Some(&[0x89, 0xc5]),
+        Some(&[0x9b, 0x5e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xb9]),
+        None,
+        Some(&[0x9b, 0x5d]),
+        Some(&[0x8c, 0x99]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x64]),
+        Some(&[0x9b, 0x61]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x84]),
+        None,
+        Some(&[0x9b, 0x60]),
+        None,
+        None,
+        Some(&[0x9b, 0x62]),
+        None,
+        None,
+        Some(&[0x9b, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5AF8: [Option<&'static [u8]>; 392] = [
+        None,
+        None,
+        Some(&[0x9b, 0x65]),
+        Some(&[0x9b, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xf0]),
+        None,
+        Some(&[0x9b, 0x68]),
+        Some(&[0x9b, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x6c]),
+        None,
+        Some(&[0x92, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x64]),
+        None,
+        Some(&[0x9b, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x6e]),
+        None,
+        Some(&[0x9b, 0x71]),
+        None,
+        None,
+        Some(&[0x9b, 0x6f]),
+        None,
+        Some(&[0x9b, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x71]),
+        Some(&[0x9b, 0x72]),
+        None,
+        None,
+        Some(&[0x8d, 0x45]),
+        Some(&[0x9b, 0x73]),
+        None,
+        Some(&[0x8e, 0x9a]),
+        Some(&[0x91, 0xb6]),
+        None,
+        Some(&[0x9b, 0x74]),
+        Some(&[0x9b, 0x75]),
+        Some(&[0x8e, 0x79]),
+        Some(&[0x8d, 0x46]),
+        None,
+        Some(&[0x96, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x47]),
+        Some(&[0x8c, 0xc7]),
+        Some(&[0x9b, 0x76]),
+        Some(&[0x8a, 0x77]),
+        None,
+        None,
+        Some(&[0x9b, 0x77]),
+        None,
+        Some(&[0x91, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x78]),
+        Some(&[0x9b, 0xa1]),
+        None,
+        Some(&[0x9b, 0x79]),
+        None,
+        Some(&[0x9b, 0x7a]),
+        None,
+        None,
+        Some(&[0x9b, 0x7b]),
+        None,
+        Some(&[0x9b, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x7e]),
+        None,
+        None,
+        Some(&[0x9b, 0x80]),
+        None,
+        Some(&[0x91, 0xee]),
+        None,
+        Some(&[0x89, 0x46]),
+        Some(&[0x8e, 0xe7]),
+        Some(&[0x88, 0xc0]),
+        None,
+        Some(&[0x91, 0x76]),
+        Some(&[0x8a, 0xae]),
+        Some(&[0x8e, 0xb3]),
+        None,
+        Some(&[0x8d, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x86]),
+        None,
+        Some(&[0x8f, 0x40]),
+        Some(&[0x8a, 0xaf]),
+        Some(&[0x92, 0x88]),
+        Some(&[0x92, 0xe8]),
+        Some(&[0x88, 0xb6]),
+        Some(&[0x8b, 0x58]),
+        Some(&[0x95, 0xf3]),
+        None,
+        Some(&[0x8e, 0xc0]),
+        None,
+        None,
+        Some(&[0x8b, 0x71]),
+        Some(&[0x90, 0xe9]),
+        Some(&[0x8e, 0xba]),
+        Some(&[0x97, 0x47]),
+        Some(&[0x9b, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x7b]),
+        None,
+        Some(&[0x8d, 0xc9]),
+        None,
+        None,
+        Some(&[0x8a, 0x51]),
+        Some(&[0x89, 0x83]),
+        Some(&[0x8f, 0xaa]),
+        Some(&[0x89, 0xc6]),
+        None,
+        Some(&[0x9b, 0x82]),
+        Some(&[0x97, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x68]),
+        None,
+        None,
+        Some(&[0x8e, 0xe2]),
+        Some(&[0x9b, 0x83]),
+        Some(&[0x8a, 0xf1]),
+        Some(&[0x93, 0xd0]),
+        Some(&[0x96, 0xa7]),
+        Some(&[0x9b, 0x84]),
+        None,
+        Some(&[0x9b, 0x85]),
+        None,
+        None,
+        Some(&[0x95, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x87]),
+        None,
+        Some(&[0x8a, 0xa6]),
+        Some(&[0x8b, 0xf5]),
+        Some(&[0x9b, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xb0]),
+        None,
+        Some(&[0x90, 0x51]),
+        Some(&[0x9b, 0x8b]),
+        Some(&[0x8e, 0x40]),
+        None,
+        Some(&[0x89, 0xc7]),
+        Some(&[0x9b, 0x8a]),
+        None,
+        Some(&[0x9b, 0x88]),
+        Some(&[0x9b, 0x8c]),
+        Some(&[0x9b, 0x89]),
+        Some(&[0x94, 0x4a]),
+        Some(&[0x9e, 0xcb]),
+        Some(&[0x90, 0x52]),
+        None,
+        Some(&[0x9b, 0x8d]),
+        None,
+        None,
+        Some(&[0x97, 0xbe]),
+        None,
+        Some(&[0x9b, 0x8e]),
+        None,
+        None,
+        Some(&[0x9b, 0x90]),
+        None,
+        Some(&[0x92, 0x9e]),
+        Some(&[0x9b, 0x8f]),
+        None,
+        Some(&[0x90, 0xa1]),
+        None,
+        Some(&[0x8e, 0x9b]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xce]),
+        Some(&[0x8e, 0xf5]),
+        None,
+        Some(&[0x95, 0x95]),
+        Some(&[0x90, 0xea]),
+        None,
+        Some(&[0x8e, 0xcb]),
+        Some(&[0x9b, 0x91]),
+        Some(&[0x8f, 0xab]),
+        Some(&[0x9b, 0x92]),
+        Some(&[0x9b, 0x93]),
+        Some(&[0x88, 0xd1]),
+        Some(&[0x91, 0xb8]),
+        Some(&[0x90, 0x71]),
+        None,
+        Some(&[0x9b, 0x94]),
+        Some(&[0x93, 0xb1]),
+        Some(&[0x8f, 0xac]),
+        None,
+        Some(&[0x8f, 0xad]),
+        None,
+        Some(&[0x9b, 0x95]),
+        None,
+        None,
+        Some(&[0x90, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x96]),
+        None,
+        Some(&[0x9b, 0x97]),
+        None,
+        Some(&[0x96, 0xde]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0x99]),
+        Some(&[0x9b, 0x9a]),
+        Some(&[0x8e, 0xda]),
+        Some(&[0x90, 0x4b]),
+        Some(&[0x93, 0xf2]),
+        Some(&[0x90, 0x73]),
+        Some(&[0x94, 0xf6]),
+        Some(&[0x94, 0x41]),
+        Some(&[0x8b, 0xc7]),
+        Some(&[0x9b, 0x9b]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x8f]),
+        Some(&[0x9b, 0x9c]),
+        None,
+        Some(&[0x8b, 0xfc]),
+        None,
+        Some(&[0x93, 0xcd]),
+        Some(&[0x89, 0xae]),
+        None,
+        Some(&[0x8e, 0x72]),
+        Some(&[0x9b, 0x9d]),
+        Some(&[0x9b, 0xa0]),
+        Some(&[0x9b, 0x9f]),
+        Some(&[0x8b, 0xfb]),
+        None,
+        Some(&[0x9b, 0x9e]),
+        None,
+        Some(&[0x93, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xae]),
+        None,
+        Some(&[0x93, 0x6a]),
+        Some(&[0x8e, 0xc6]),
+        None,
+        None,
+        Some(&[0x91, 0x77]),
+        Some(&[0x97, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xa2]),
+        None,
+        Some(&[0x9b, 0xa3]),
+        Some(&[0x93, 0xd4]),
+        None,
+        Some(&[0x8e, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xa5]),
+        None,
+        None,
+        Some(&[0x9b, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5C88: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xf2]),
+        Some(&[0x9b, 0xa8]),
+        None,
+        None,
+        Some(&[0x9b, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x5a]),
+        Some(&[0x8a, 0xe2]),
+        None,
+        Some(&[0x9b, 0xab]),
+        Some(&[0x96, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xd0]),
+        None,
+        Some(&[0x8a, 0x78]),
+        None,
+        None,
+        Some(&[0x9b, 0xad]),
+        Some(&[0x9b, 0xaf]),
+        Some(&[0x8a, 0xdd]),
+        None,
+        None,
+        Some(&[0x9b, 0xac]),
+        Some(&[0x9b, 0xae]),
+        None,
+        Some(&[0x9b, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xb0]),
+        None,
+        Some(&[0x9b, 0xb2]),
+    ];
+
+    const BASE_0X5CD8: [Option<&'static [u8]>; 88] = [
+        None,
+        Some(&[0x9b, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xbb]),
+        Some(&[0x8b, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xe3]),
+        Some(&[0x9b, 0xb4]),
+        Some(&[0x9b, 0xb9]),
+        None,
+        None,
+        Some(&[0x9b, 0xb7]),
+        None,
+        Some(&[0x95, 0xf5]),
+        Some(&[0x95, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xb6]),
+        Some(&[0x8f, 0x73]),
+        None,
+        Some(&[0x9b, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x92]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xba]),
+        None,
+        None,
+        Some(&[0x8d, 0xe8]),
+        None,
+        None,
+        Some(&[0x9b, 0xc0]),
+        None,
+        None,
+        Some(&[0x9b, 0xc1]),
+        Some(&[0x9b, 0xbb]),
+        Some(&[0x8a, 0x52]),
+        Some(&[0x9b, 0xbc]),
+        Some(&[0x9b, 0xc5]),
+        Some(&[0x9b, 0xc4]),
+        Some(&[0x9b, 0xc3]),
+        Some(&[0x9b, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xbe]),
+        None,
+        None,
+        Some(&[0x9b, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X5D48: [Option<&'static [u8]>; 1168] = [
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xc9]),
+        Some(&[0x9b, 0xc6]),
+        None,
+        Some(&[0x9b, 0xc8]),
+        None,
+        Some(&[0x97, 0x92]),
+        None,
+        Some(&[0x9b, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x93]),
+        None,
+        None,
+        Some(&[0x9b, 0xca]),
+        None,
+        None,
+        Some(&[0x8d, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xcb]),
+        None,
+        None,
+        Some(&[0x9b, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xcf]),
+        None,
+        Some(&[0x9b, 0xce]),
+        None,
+        None,
+        Some(&[0x9b, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x88]),
+        Some(&[0x9b, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd2]),
+        None,
+        Some(&[0x9b, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd6]),
+        None,
+        None,
+        Some(&[0x97, 0xe4]),
+        None,
+        Some(&[0x9b, 0xd7]),
+        Some(&[0x9b, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xd8]),
+        None,
+        None,
+        Some(&[0x8a, 0xde]),
+        Some(&[0x9b, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xdb]),
+        Some(&[0x9b, 0xda]),
+        None,
+        None,
+        Some(&[0x9b, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xdd]),
+        None,
+        Some(&[0x90, 0xec]),
+        Some(&[0x8f, 0x42]),
+        None,
+        None,
+        Some(&[0x8f, 0x84]),
+        None,
+        Some(&[0x91, 0x83]),
+        None,
+        Some(&[0x8d, 0x48]),
+        Some(&[0x8d, 0xb6]),
+        Some(&[0x8d, 0x49]),
+        Some(&[0x8b, 0x90]),
+        None,
+        None,
+        Some(&[0x9b, 0xde]),
+        None,
+        None,
+        Some(&[0x8d, 0xb7]),
+        None,
+        None,
+        Some(&[0x8c, 0xc8]),
+        Some(&[0x9b, 0xdf]),
+        Some(&[0x96, 0xa4]),
+        Some(&[0x94, 0x62]),
+        Some(&[0x9b, 0xe0]),
+        None,
+        Some(&[0x8d, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xaa]),
+        None,
+        Some(&[0x92, 0x46]),
+        Some(&[0x8b, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x73]),
+        Some(&[0x95, 0x7a]),
+        None,
+        None,
+        Some(&[0x94, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xe1]),
+        Some(&[0x8a, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x9f]),
+        None,
+        None,
+        Some(&[0x9b, 0xe3]),
+        Some(&[0x9b, 0xe2]),
+        Some(&[0x9b, 0xe5]),
+        None,
+        Some(&[0x92, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x74]),
+        None,
+        Some(&[0x90, 0xc8]),
+        None,
+        Some(&[0x91, 0xd1]),
+        Some(&[0x8b, 0x41]),
+        None,
+        None,
+        Some(&[0x92, 0xa0]),
+        None,
+        None,
+        Some(&[0x9b, 0xe6]),
+        Some(&[0x9b, 0xe7]),
+        Some(&[0x8f, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x58]),
+        None,
+        None,
+        Some(&[0x9b, 0xea]),
+        None,
+        None,
+        Some(&[0x9b, 0xe9]),
+        Some(&[0x9b, 0xe8]),
+        Some(&[0x95, 0x9d]),
+        None,
+        Some(&[0x9b, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x79]),
+        None,
+        Some(&[0x9b, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xed]),
+        Some(&[0x96, 0x8b]),
+        None,
+        Some(&[0x9b, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xee]),
+        None,
+        Some(&[0x94, 0xa6]),
+        Some(&[0x9b, 0xef]),
+        Some(&[0x95, 0xbc]),
+        Some(&[0x9b, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xb1]),
+        Some(&[0x95, 0xbd]),
+        Some(&[0x94, 0x4e]),
+        Some(&[0x9b, 0xf2]),
+        Some(&[0x9b, 0xf3]),
+        None,
+        Some(&[0x8d, 0x4b]),
+        Some(&[0x8a, 0xb2]),
+        Some(&[0x9b, 0xf4]),
+        Some(&[0x8c, 0xb6]),
+        Some(&[0x97, 0x63]),
+        Some(&[0x97, 0x48]),
+        Some(&[0x8a, 0xf4]),
+        Some(&[0x9b, 0xf6]),
+        None,
+        Some(&[0x92, 0xa1]),
+        None,
+        Some(&[0x8d, 0x4c]),
+        Some(&[0x8f, 0xaf]),
+        None,
+        None,
+        Some(&[0x94, 0xdd]),
+        None,
+        None,
+        Some(&[0x8f, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xea]),
+        Some(&[0x95, 0xf7]),
+        Some(&[0x93, 0x58]),
+        None,
+        None,
+        Some(&[0x8d, 0x4d]),
+        None,
+        Some(&[0x95, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x78]),
+        Some(&[0x8d, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xc9]),
+        None,
+        Some(&[0x92, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xc1]),
+        Some(&[0x8f, 0x8e]),
+        Some(&[0x8d, 0x4e]),
+        Some(&[0x97, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xf8]),
+        Some(&[0x9b, 0xf9]),
+        Some(&[0x94, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xfa]),
+        Some(&[0x97, 0xf5]),
+        Some(&[0x98, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9b, 0xfc]),
+        Some(&[0x9b, 0xfb]),
+        None,
+        None,
+        Some(&[0x8a, 0x66]),
+        None,
+        None,
+        Some(&[0x9c, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x43]),
+        Some(&[0x9c, 0x44]),
+        None,
+        Some(&[0x9c, 0x42]),
+        None,
+        Some(&[0x95, 0x5f]),
+        Some(&[0x8f, 0xb1]),
+        Some(&[0x9c, 0x46]),
+        Some(&[0x9c, 0x45]),
+        Some(&[0x9c, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x47]),
+        Some(&[0x9c, 0x48]),
+        None,
+        None,
+        Some(&[0x9c, 0x49]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x4c]),
+        Some(&[0x9c, 0x4a]),
+        None,
+        Some(&[0x9c, 0x4b]),
+        Some(&[0x9c, 0x4d]),
+        None,
+        Some(&[0x89, 0x84]),
+        Some(&[0x92, 0xec]),
+        Some(&[0x9c, 0x4e]),
+        None,
+        Some(&[0x8c, 0x9a]),
+        Some(&[0x89, 0xf4]),
+        Some(&[0x94, 0x55]),
+        None,
+        Some(&[0x9c, 0x4f]),
+        Some(&[0x93, 0xf9]),
+        None,
+        Some(&[0x95, 0xd9]),
+        None,
+        Some(&[0x9c, 0x50]),
+        Some(&[0x98, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x51]),
+        Some(&[0x95, 0xbe]),
+        Some(&[0x9c, 0x54]),
+        Some(&[0x98, 0x9f]),
+        Some(&[0x98, 0xaf]),
+        None,
+        Some(&[0x8e, 0xae]),
+        Some(&[0x93, 0xf3]),
+        Some(&[0x9c, 0x55]),
+        None,
+        Some(&[0x8b, 0x7c]),
+        Some(&[0x92, 0xa2]),
+        Some(&[0x88, 0xf8]),
+        Some(&[0x9c, 0x56]),
+        Some(&[0x95, 0xa4]),
+        Some(&[0x8d, 0x4f]),
+        None,
+        None,
+        Some(&[0x92, 0x6f]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xed]),
+        Some(&[0x8c, 0xb7]),
+        Some(&[0x8c, 0xca]),
+        None,
+        Some(&[0x9c, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x58]),
+        None,
+        Some(&[0x9c, 0x5e]),
+        None,
+        Some(&[0x8e, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xa3]),
+        None,
+        Some(&[0x8b, 0xad]),
+        Some(&[0x9c, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x4a]),
+        None,
+        Some(&[0x92, 0x65]),
+        None,
+        None,
+        Some(&[0x9c, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x5b]),
+        None,
+        Some(&[0x8b, 0xae]),
+        None,
+        Some(&[0x9c, 0x5c]),
+        None,
+        Some(&[0x9c, 0x5d]),
+        None,
+        None,
+        Some(&[0x9c, 0x5f]),
+        None,
+        Some(&[0x93, 0x96]),
+        None,
+        None,
+        Some(&[0x9c, 0x60]),
+        Some(&[0x9c, 0x61]),
+        None,
+        Some(&[0x9c, 0x62]),
+        None,
+        None,
+        Some(&[0x9c, 0x53]),
+        Some(&[0x9c, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x63]),
+        Some(&[0x8c, 0x60]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x46]),
+        None,
+        None,
+        Some(&[0x8d, 0xca]),
+        Some(&[0x95, 0x56]),
+        Some(&[0x92, 0xa4]),
+        Some(&[0x95, 0x6a]),
+        Some(&[0x9c, 0x64]),
+        None,
+        None,
+        Some(&[0x8f, 0xb2]),
+        Some(&[0x89, 0x65]),
+        None,
+        Some(&[0x9c, 0x65]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x66]),
+        None,
+        Some(&[0x96, 0xf0]),
+        None,
+        None,
+        Some(&[0x94, 0xde]),
+        None,
+        None,
+        Some(&[0x9c, 0x69]),
+        Some(&[0x89, 0x9d]),
+        Some(&[0x90, 0xaa]),
+        Some(&[0x9c, 0x68]),
+        Some(&[0x9c, 0x67]),
+        Some(&[0x8c, 0x61]),
+        Some(&[0x91, 0xd2]),
+        None,
+        Some(&[0x9c, 0x6d]),
+        Some(&[0x9c, 0x6b]),
+        None,
+        Some(&[0x9c, 0x6a]),
+        Some(&[0x97, 0xa5]),
+        Some(&[0x8c, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x99]),
+        Some(&[0x9c, 0x6c]),
+        Some(&[0x93, 0x6b]),
+        Some(&[0x8f, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xbe]),
+        Some(&[0x9c, 0x70]),
+        Some(&[0x9c, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x6e]),
+        None,
+        Some(&[0x9c, 0x71]),
+        Some(&[0x8c, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x72]),
+        Some(&[0x95, 0x9c]),
+        Some(&[0x8f, 0x7a]),
+        None,
+        None,
+        Some(&[0x9c, 0x73]),
+        Some(&[0x94, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xbf]),
+        Some(&[0x92, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x4f]),
+        None,
+        None,
+        Some(&[0x9c, 0x74]),
+        Some(&[0x8b, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x53]),
+        None,
+        Some(&[0x95, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xf5]),
+        Some(&[0x94, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x75]),
+        Some(&[0x8e, 0x75]),
+        Some(&[0x96, 0x59]),
+        Some(&[0x96, 0x5a]),
+        None,
+        None,
+        Some(&[0x89, 0x9e]),
+        Some(&[0x9c, 0x7a]),
+        None,
+        None,
+        Some(&[0x92, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xab]),
+        Some(&[0x9c, 0x79]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x4f]),
+        None,
+        None,
+        Some(&[0x9c, 0x78]),
+        None,
+        None,
+        Some(&[0x9c, 0x76]),
+        None,
+        Some(&[0x8d, 0x9a]),
+        None,
+        Some(&[0x9c, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x83]),
+        Some(&[0x9c, 0x89]),
+        Some(&[0x9c, 0x81]),
+        None,
+        Some(&[0x93, 0x7b]),
+        None,
+        None,
+        Some(&[0x9c, 0x86]),
+        Some(&[0x95, 0x7c]),
+        None,
+        None,
+        Some(&[0x9c, 0x80]),
+        None,
+        Some(&[0x9c, 0x85]),
+        Some(&[0x97, 0xe5]),
+        Some(&[0x8e, 0x76]),
+        None,
+        None,
+        Some(&[0x91, 0xd3]),
+        Some(&[0x9c, 0x7d]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x7d]),
+        Some(&[0x9c, 0x88]),
+        Some(&[0x90, 0xab]),
+        Some(&[0x89, 0x85]),
+        Some(&[0x9c, 0x82]),
+        Some(&[0x89, 0xf6]),
+        Some(&[0x9c, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xaf]),
+        None,
+        Some(&[0x9c, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x8c]),
+        Some(&[0x9c, 0x96]),
+        Some(&[0x9c, 0x94]),
+        None,
+        None,
+        Some(&[0x9c, 0x91]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x90]),
+        Some(&[0x97, 0xf6]),
+        None,
+        Some(&[0x9c, 0x92]),
+        None,
+        None,
+        Some(&[0x8b, 0xb0]),
+        None,
+        Some(&[0x8d, 0x50]),
+        None,
+        None,
+        Some(&[0x8f, 0x9a]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x99]),
+        Some(&[0x9c, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x8f]),
+        Some(&[0x9c, 0x7e]),
+        None,
+        Some(&[0x89, 0xf8]),
+        Some(&[0x9c, 0x93]),
+        Some(&[0x9c, 0x95]),
+        Some(&[0x92, 0x70]),
+        None,
+        None,
+        Some(&[0x8d, 0xa6]),
+        Some(&[0x89, 0xb6]),
+        Some(&[0x9c, 0x8d]),
+        Some(&[0x9c, 0x98]),
+        Some(&[0x9c, 0x97]),
+        Some(&[0x8b, 0xb1]),
+        None,
+        Some(&[0x91, 0xa7]),
+        Some(&[0x8a, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x62]),
+        None,
+        Some(&[0x9c, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x9a]),
+        None,
+        Some(&[0x9c, 0x9d]),
+        Some(&[0x9c, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xbb]),
+        None,
+        Some(&[0x9c, 0xa5]),
+        Some(&[0x92, 0xee]),
+        Some(&[0x9c, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xa3]),
+        None,
+        Some(&[0x89, 0xf7]),
+        None,
+        Some(&[0x9c, 0xa1]),
+        Some(&[0x9c, 0xa2]),
+        None,
+        None,
+        Some(&[0x9c, 0x9e]),
+        Some(&[0x9c, 0xa0]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xe5]),
+        Some(&[0x97, 0x49]),
+        None,
+        None,
+        Some(&[0x8a, 0xb3]),
+        None,
+        None,
+        Some(&[0x89, 0x78]),
+        Some(&[0x9c, 0xa4]),
+        None,
+        Some(&[0x94, 0x59]),
+        Some(&[0x88, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xdf]),
+        Some(&[0x9c, 0x7b]),
+        Some(&[0x9c, 0xaa]),
+        Some(&[0x9c, 0xae]),
+        Some(&[0x96, 0xe3]),
+        None,
+        Some(&[0x9c, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x89]),
+        Some(&[0x9c, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xee]),
+        Some(&[0x9c, 0xad]),
+        Some(&[0x93, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x66]),
+        None,
+        Some(&[0x9c, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xaf]),
+        None,
+        Some(&[0x8d, 0x9b]),
+        None,
+        Some(&[0x90, 0xc9]),
+        None,
+        None,
+        Some(&[0x88, 0xd2]),
+        Some(&[0x9c, 0xa8]),
+        Some(&[0x9c, 0xa6]),
+        None,
+        Some(&[0x91, 0x79]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0x9c]),
+        Some(&[0x8e, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xc4]),
+        Some(&[0x9c, 0xbb]),
+        None,
+        Some(&[0x91, 0x7a]),
+        Some(&[0x9c, 0xb6]),
+        None,
+        Some(&[0x9c, 0xb3]),
+        Some(&[0x9c, 0xb4]),
+        None,
+        Some(&[0x8e, 0xe4]),
+        Some(&[0x9c, 0xb7]),
+        Some(&[0x9c, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xb5]),
+        Some(&[0x8f, 0x44]),
+        None,
+        Some(&[0x9c, 0xb8]),
+        None,
+        None,
+        Some(&[0x9c, 0xb2]),
+        None,
+        Some(&[0x96, 0xfa]),
+        Some(&[0x96, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xbc]),
+        Some(&[0x9c, 0xbd]),
+        Some(&[0x88, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xf0]),
+        Some(&[0x88, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xb4]),
+        None,
+        Some(&[0x9c, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xc1]),
+        Some(&[0x9c, 0xc0]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xc4]),
+        Some(&[0x9c, 0xc7]),
+        Some(&[0x9c, 0xbf]),
+        Some(&[0x9c, 0xc3]),
+        None,
+        None,
+        Some(&[0x9c, 0xc8]),
+        None,
+        Some(&[0x9c, 0xc9]),
+        None,
+        None,
+        Some(&[0x9c, 0xbe]),
+        Some(&[0x8e, 0x9c]),
+        None,
+        Some(&[0x9c, 0xc2]),
+        Some(&[0x91, 0xd4]),
+        Some(&[0x8d, 0x51]),
+        Some(&[0x9c, 0xb0]),
+        Some(&[0x90, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xd6]),
+        None,
+        Some(&[0x95, 0xe7]),
+        None,
+        None,
+        Some(&[0x9c, 0xcc]),
+        Some(&[0x9c, 0xcd]),
+        Some(&[0x9c, 0xce]),
+        None,
+        None,
+        Some(&[0x9c, 0xd5]),
+        None,
+        Some(&[0x9c, 0xd4]),
+        None,
+        None,
+        Some(&[0x96, 0x9d]),
+        Some(&[0x8a, 0xb5]),
+        None,
+        Some(&[0x9c, 0xd2]),
+        None,
+        Some(&[0x8c, 0x64]),
+        Some(&[0x8a, 0x53]),
+        None,
+        None,
+        Some(&[0x9c, 0xcf]),
+        None,
+        None,
+        Some(&[0x97, 0xb6]),
+        Some(&[0x9c, 0xd1]),
+        Some(&[0x88, 0xd4]),
+        Some(&[0x9c, 0xd3]),
+        None,
+        Some(&[0x9c, 0xca]),
+        Some(&[0x9c, 0xd0]),
+        Some(&[0x9c, 0xd7]),
+        Some(&[0x8c, 0x63]),
+        Some(&[0x9c, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xda]),
+        None,
+        None,
+        Some(&[0x9c, 0xde]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x9e]),
+        None,
+        Some(&[0x97, 0xf7]),
+        Some(&[0x9c, 0xdf]),
+        None,
+        None,
+        Some(&[0x9c, 0xdc]),
+        None,
+        Some(&[0x9c, 0xd9]),
+        None,
+        None,
+        Some(&[0x9c, 0xd8]),
+        Some(&[0x9c, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xae]),
+        None,
+        None,
+        Some(&[0x93, 0xb2]),
+        None,
+        Some(&[0x8c, 0x65]),
+        None,
+        Some(&[0x9c, 0xe0]),
+        Some(&[0x9c, 0xdb]),
+        None,
+        Some(&[0x9c, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x9b]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xe7]),
+        None,
+        None,
+        Some(&[0x9c, 0xe8]),
+        Some(&[0x8d, 0xa7]),
+        Some(&[0x9c, 0xe6]),
+        Some(&[0x9c, 0xe4]),
+        Some(&[0x9c, 0xe3]),
+        Some(&[0x9c, 0xea]),
+        Some(&[0x9c, 0xe2]),
+        Some(&[0x9c, 0xec]),
+        None,
+        None,
+        Some(&[0x89, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X61E0: [Option<&'static [u8]>; 808] = [
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xee]),
+        None,
+        None,
+        Some(&[0x9c, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xa6]),
+        None,
+        Some(&[0x9c, 0xf1]),
+        None,
+        Some(&[0x9c, 0xef]),
+        Some(&[0x9c, 0xe5]),
+        Some(&[0x8c, 0x9c]),
+        None,
+        Some(&[0x9c, 0xf0]),
+        None,
+        Some(&[0x9c, 0xf4]),
+        Some(&[0x9c, 0xf3]),
+        Some(&[0x9c, 0xf5]),
+        Some(&[0x9c, 0xf2]),
+        Some(&[0x9c, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9c, 0xf7]),
+        Some(&[0x9c, 0xf8]),
+        Some(&[0x95, 0xe8]),
+        None,
+        Some(&[0x9c, 0xfa]),
+        Some(&[0x9c, 0xf9]),
+        Some(&[0x8f, 0x5e]),
+        None,
+        Some(&[0x90, 0xac]),
+        Some(&[0x89, 0xe4]),
+        Some(&[0x89, 0xfa]),
+        None,
+        Some(&[0x9c, 0xfb]),
+        None,
+        Some(&[0x88, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xca]),
+        Some(&[0x9c, 0xfc]),
+        None,
+        Some(&[0xe6, 0xc1]),
+        Some(&[0x9d, 0x40]),
+        Some(&[0x8c, 0x81]),
+        None,
+        Some(&[0x9d, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x43]),
+        Some(&[0x8b, 0x59]),
+        Some(&[0x9d, 0x44]),
+        None,
+        Some(&[0x9d, 0x45]),
+        Some(&[0x9d, 0x46]),
+        Some(&[0x91, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xcb]),
+        None,
+        None,
+        Some(&[0x96, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x5b]),
+        Some(&[0x8f, 0x8a]),
+        Some(&[0x9d, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xee]),
+        Some(&[0xe7, 0xbb]),
+        Some(&[0x94, 0xe0]),
+        None,
+        Some(&[0x8e, 0xe8]),
+        None,
+        Some(&[0x8d, 0xcb]),
+        Some(&[0x9d, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xc5]),
+        None,
+        Some(&[0x95, 0xa5]),
+        None,
+        None,
+        Some(&[0x91, 0xef]),
+        None,
+        None,
+        Some(&[0x9d, 0x4b]),
+        None,
+        None,
+        Some(&[0x9d, 0x49]),
+        None,
+        Some(&[0x9d, 0x4c]),
+        None,
+        None,
+        Some(&[0x9d, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xaf]),
+        None,
+        None,
+        Some(&[0x88, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x7d]),
+        None,
+        None,
+        Some(&[0x94, 0xe1]),
+        None,
+        None,
+        Some(&[0x9d, 0x4e]),
+        None,
+        Some(&[0x9d, 0x51]),
+        Some(&[0x8f, 0xb3]),
+        Some(&[0x8b, 0x5a]),
+        None,
+        Some(&[0x9d, 0x4f]),
+        Some(&[0x9d, 0x56]),
+        Some(&[0x8f, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x50]),
+        Some(&[0x94, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x7d]),
+        Some(&[0x9d, 0x52]),
+        Some(&[0x9d, 0x53]),
+        Some(&[0x9d, 0x57]),
+        Some(&[0x93, 0x8a]),
+        Some(&[0x9d, 0x54]),
+        Some(&[0x8d, 0x52]),
+        Some(&[0x90, 0xdc]),
+        None,
+        None,
+        Some(&[0x9d, 0x65]),
+        Some(&[0x94, 0xb2]),
+        None,
+        Some(&[0x91, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xe2]),
+        Some(&[0x9d, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xef]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x95]),
+        None,
+        Some(&[0x9d, 0x5a]),
+        Some(&[0x89, 0x9f]),
+        Some(&[0x92, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x63]),
+        None,
+        None,
+        Some(&[0x92, 0x53]),
+        Some(&[0x9d, 0x5d]),
+        Some(&[0x9d, 0x64]),
+        Some(&[0x9d, 0x5f]),
+        Some(&[0x9d, 0x66]),
+        Some(&[0x9d, 0x62]),
+        None,
+        Some(&[0x9d, 0x61]),
+        Some(&[0x94, 0x8f]),
+        None,
+        Some(&[0x9d, 0x5b]),
+        Some(&[0x89, 0xfb]),
+        Some(&[0x9d, 0x59]),
+        Some(&[0x8b, 0x91]),
+        Some(&[0x91, 0xf1]),
+        Some(&[0x9d, 0x55]),
+        None,
+        None,
+        Some(&[0x9d, 0x58]),
+        Some(&[0x8d, 0x53]),
+        Some(&[0x90, 0xd9]),
+        None,
+        Some(&[0x8f, 0xb5]),
+        Some(&[0x9d, 0x60]),
+        Some(&[0x94, 0x71]),
+        None,
+        None,
+        Some(&[0x8b, 0x92]),
+        Some(&[0x8a, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x87]),
+        Some(&[0x90, 0x40]),
+        Some(&[0x9d, 0x68]),
+        Some(&[0x9d, 0x6d]),
+        None,
+        Some(&[0x9d, 0x69]),
+        None,
+        Some(&[0x8c, 0x9d]),
+        None,
+        Some(&[0x9d, 0x6e]),
+        Some(&[0x8e, 0x41]),
+        Some(&[0x8d, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x45]),
+        Some(&[0x9d, 0x5c]),
+        None,
+        Some(&[0x8e, 0x9d]),
+        Some(&[0x9d, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x77]),
+        Some(&[0x9d, 0x6c]),
+        Some(&[0x88, 0xc2]),
+        None,
+        None,
+        Some(&[0x9d, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x93]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x6a]),
+        Some(&[0x88, 0xa5]),
+        None,
+        None,
+        Some(&[0x8d, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xf0]),
+        None,
+        None,
+        Some(&[0x94, 0xd2]),
+        Some(&[0x9d, 0x70]),
+        Some(&[0x91, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xa8]),
+        None,
+        None,
+        Some(&[0x8e, 0x4a]),
+        Some(&[0x9d, 0x71]),
+        None,
+        Some(&[0x9d, 0x73]),
+        Some(&[0x9d, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xdf]),
+        None,
+        Some(&[0x92, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xf9]),
+        Some(&[0x8e, 0xcc]),
+        Some(&[0x9d, 0x80]),
+        None,
+        Some(&[0x9d, 0x7e]),
+        None,
+        None,
+        Some(&[0x90, 0x98]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x78]),
+        Some(&[0x8f, 0xb7]),
+        None,
+        None,
+        Some(&[0x93, 0xe6]),
+        Some(&[0x94, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x76]),
+        None,
+        None,
+        Some(&[0x91, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xf6]),
+        Some(&[0x9d, 0x7b]),
+        None,
+        None,
+        Some(&[0x8f, 0xb6]),
+        None,
+        Some(&[0x9d, 0x75]),
+        Some(&[0x9d, 0x7a]),
+        None,
+        None,
+        Some(&[0x94, 0x72]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x74]),
+        None,
+        Some(&[0x8c, 0x40]),
+        None,
+        None,
+        Some(&[0x8a, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x7c]),
+        Some(&[0x97, 0xa9]),
+        Some(&[0x8d, 0xcc]),
+        Some(&[0x92, 0x54]),
+        Some(&[0x9d, 0x79]),
+        None,
+        Some(&[0x90, 0xda]),
+        None,
+        Some(&[0x8d, 0x54]),
+        Some(&[0x90, 0x84]),
+        Some(&[0x89, 0x86]),
+        Some(&[0x91, 0x5b]),
+        Some(&[0x9d, 0x77]),
+        Some(&[0x8b, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x66]),
+        None,
+        Some(&[0x92, 0xcd]),
+        Some(&[0x9d, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x7e]),
+        None,
+        None,
+        Some(&[0x9d, 0x81]),
+        None,
+        Some(&[0x9d, 0x83]),
+        None,
+        None,
+        Some(&[0x91, 0xb5]),
+        Some(&[0x9d, 0x89]),
+        None,
+        Some(&[0x9d, 0x84]),
+        None,
+        None,
+        Some(&[0x9d, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x60]),
+        Some(&[0x92, 0xf1]),
+        None,
+        Some(&[0x9d, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x67]),
+        Some(&[0x8a, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xac]),
+        None,
+        Some(&[0x9d, 0x85]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x87]),
+        None,
+        Some(&[0x9d, 0x88]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xb9]),
+        None,
+        Some(&[0x9d, 0x93]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x8d]),
+        None,
+        None,
+        Some(&[0x9d, 0x8a]),
+        Some(&[0x9d, 0x91]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x8e]),
+        None,
+        Some(&[0x9d, 0x92]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xc0]),
+        Some(&[0x93, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x8b]),
+        None,
+        Some(&[0x9d, 0x8f]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x67]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xef]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x94]),
+        None,
+        Some(&[0x96, 0x80]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x95]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x96]),
+        None,
+        Some(&[0x96, 0xcc]),
+        None,
+        Some(&[0x90, 0xa0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x54]),
+        Some(&[0x9d, 0x9a]),
+        None,
+        Some(&[0x9d, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x50]),
+        Some(&[0x9d, 0x9b]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x9c]),
+        None,
+        Some(&[0x95, 0x8f]),
+        None,
+        Some(&[0x94, 0x64]),
+        Some(&[0x8e, 0x42]),
+        None,
+        Some(&[0x90, 0xef]),
+        None,
+        Some(&[0x96, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x68]),
+        None,
+        Some(&[0x9d, 0xa3]),
+        Some(&[0x9d, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x69]),
+        Some(&[0x9d, 0xa5]),
+        None,
+        None,
+        Some(&[0x9d, 0xa1]),
+        None,
+        Some(&[0x9d, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x80]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xa0]),
+        None,
+        Some(&[0x9d, 0x5e]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xa4]),
+        None,
+        Some(&[0x9d, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xa9]),
+        Some(&[0x9d, 0xaa]),
+        Some(&[0x93, 0x46]),
+        Some(&[0x9d, 0xac]),
+        None,
+        None,
+        Some(&[0x8e, 0x43]),
+        Some(&[0x9d, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x5b]),
+        None,
+        None,
+        Some(&[0x9d, 0xad]),
+        None,
+        Some(&[0x9d, 0xa6]),
+        Some(&[0x9d, 0xb1]),
+        None,
+        Some(&[0x9d, 0xb0]),
+        None,
+        Some(&[0x9d, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xb2]),
+        None,
+        None,
+        Some(&[0x9d, 0xb4]),
+        Some(&[0x8f, 0xef]),
+        None,
+        Some(&[0x9d, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xb7]),
+        None,
+        None,
+    ];
+
+    const BASE_0X6518: [Option<&'static [u8]>; 1760] = [
+        Some(&[0x9d, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xb6]),
+        Some(&[0x9d, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xb9]),
+        Some(&[0x9d, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0x98]),
+        Some(&[0x9d, 0xba]),
+        Some(&[0x9d, 0xae]),
+        None,
+        None,
+        Some(&[0x8e, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xbb]),
+        Some(&[0x9d, 0xbc]),
+        Some(&[0x9d, 0xbe]),
+        Some(&[0x9d, 0xbd]),
+        Some(&[0x9d, 0xbf]),
+        Some(&[0x89, 0xfc]),
+        None,
+        Some(&[0x8d, 0x55]),
+        None,
+        None,
+        Some(&[0x95, 0xfa]),
+        Some(&[0x90, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xcc]),
+        None,
+        None,
+        Some(&[0x9d, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xc4]),
+        None,
+        Some(&[0x95, 0x71]),
+        None,
+        Some(&[0x8b, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xc3]),
+        Some(&[0x9d, 0xc2]),
+        Some(&[0x94, 0x73]),
+        Some(&[0x9d, 0xc5]),
+        Some(&[0x8b, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xc7]),
+        Some(&[0x9d, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xb8]),
+        Some(&[0x8e, 0x55]),
+        None,
+        None,
+        Some(&[0x93, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x94]),
+        None,
+        Some(&[0x9d, 0xc8]),
+        None,
+        Some(&[0x90, 0xae]),
+        Some(&[0x93, 0x47]),
+        None,
+        Some(&[0x95, 0x7e]),
+        Some(&[0x9d, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xca]),
+        Some(&[0x9d, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xb6]),
+        Some(&[0x9b, 0x7c]),
+        Some(&[0x90, 0xc4]),
+        None,
+        None,
+        Some(&[0x95, 0x6b]),
+        None,
+        Some(&[0x8d, 0xd6]),
+        None,
+        Some(&[0x94, 0xe3]),
+        Some(&[0x94, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x6c]),
+        None,
+        Some(&[0x97, 0xbf]),
+        None,
+        Some(&[0x9d, 0xcd]),
+        Some(&[0x8e, 0xce]),
+        None,
+        None,
+        Some(&[0x9d, 0xce]),
+        None,
+        Some(&[0x88, 0xb4]),
+        None,
+        None,
+        Some(&[0x8b, 0xd2]),
+        Some(&[0x90, 0xcb]),
+        None,
+        Some(&[0x95, 0x80]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xcf]),
+        Some(&[0x8e, 0x61]),
+        Some(&[0x92, 0x66]),
+        None,
+        Some(&[0x8e, 0x7a]),
+        Some(&[0x90, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xd0]),
+        None,
+        Some(&[0x95, 0xfb]),
+        None,
+        None,
+        Some(&[0x89, 0x97]),
+        Some(&[0x8e, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xd3]),
+        None,
+        Some(&[0x9d, 0xd1]),
+        Some(&[0x9d, 0xd4]),
+        Some(&[0x97, 0xb7]),
+        Some(&[0x9d, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xf9]),
+        Some(&[0x9d, 0xd5]),
+        None,
+        None,
+        Some(&[0x91, 0xb0]),
+        None,
+        None,
+        Some(&[0x9d, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xf8]),
+        None,
+        Some(&[0x9d, 0xd8]),
+        None,
+        Some(&[0x9d, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xd9]),
+        Some(&[0x9d, 0xda]),
+        Some(&[0x8a, 0xf9]),
+        None,
+        None,
+        Some(&[0x93, 0xfa]),
+        Some(&[0x92, 0x55]),
+        Some(&[0x8b, 0x8c]),
+        Some(&[0x8e, 0x7c]),
+        Some(&[0x91, 0x81]),
+        None,
+        None,
+        Some(&[0x8f, 0x7b]),
+        Some(&[0x88, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa0]),
+        Some(&[0x9d, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x56]),
+        Some(&[0x9d, 0xde]),
+        None,
+        None,
+        Some(&[0x8d, 0xa9]),
+        Some(&[0x8f, 0xb8]),
+        None,
+        None,
+        Some(&[0x9d, 0xdd]),
+        None,
+        Some(&[0x8f, 0xb9]),
+        None,
+        Some(&[0x96, 0xbe]),
+        Some(&[0x8d, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xd5]),
+        Some(&[0x90, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xe4]),
+        None,
+        None,
+        Some(&[0x90, 0xaf]),
+        Some(&[0x89, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x74]),
+        None,
+        Some(&[0x96, 0x86]),
+        Some(&[0x8d, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xba]),
+        None,
+        Some(&[0x90, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xe3]),
+        Some(&[0x9d, 0xe1]),
+        Some(&[0x9d, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x8b]),
+        None,
+        None,
+        Some(&[0x9e, 0x45]),
+        None,
+        Some(&[0x9d, 0xe8]),
+        Some(&[0x8e, 0x9e]),
+        Some(&[0x8d, 0x57]),
+        Some(&[0x9d, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xe7]),
+        None,
+        Some(&[0x90, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xe5]),
+        None,
+        None,
+        Some(&[0x8e, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xea]),
+        Some(&[0x9d, 0xe9]),
+        Some(&[0x9d, 0xee]),
+        None,
+        None,
+        Some(&[0x9d, 0xef]),
+        None,
+        Some(&[0x9d, 0xeb]),
+        None,
+        Some(&[0x8a, 0x41]),
+        Some(&[0x9d, 0xec]),
+        Some(&[0x9d, 0xed]),
+        Some(&[0x94, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x81]),
+        Some(&[0x8c, 0x69]),
+        Some(&[0x9d, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xb0]),
+        None,
+        Some(&[0x8f, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xc5]),
+        None,
+        Some(&[0x9d, 0xf1]),
+        Some(&[0x9d, 0xf5]),
+        None,
+        None,
+        Some(&[0x89, 0xc9]),
+        Some(&[0x9d, 0xf2]),
+        Some(&[0x9d, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xf3]),
+        None,
+        None,
+        Some(&[0x8f, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x67]),
+        Some(&[0x88, 0xc3]),
+        Some(&[0x9d, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x62]),
+        None,
+        None,
+        Some(&[0x95, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x41]),
+        Some(&[0x9d, 0xf9]),
+        None,
+        None,
+        Some(&[0x9d, 0xfc]),
+        None,
+        Some(&[0x9d, 0xfb]),
+        None,
+        None,
+        Some(&[0x9d, 0xf8]),
+        None,
+        None,
+        Some(&[0x9e, 0x40]),
+        None,
+        None,
+        Some(&[0x93, 0xdc]),
+        None,
+        Some(&[0x9d, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x42]),
+        None,
+        None,
+        Some(&[0x8f, 0x8c]),
+        Some(&[0x9e, 0x43]),
+        None,
+        Some(&[0x97, 0x6a]),
+        Some(&[0x94, 0x98]),
+        None,
+        None,
+        Some(&[0x9e, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x46]),
+        None,
+        None,
+        Some(&[0x9e, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x48]),
+        None,
+        Some(&[0x8b, 0xc8]),
+        Some(&[0x89, 0x67]),
+        Some(&[0x8d, 0x58]),
+        Some(&[0x9e, 0x49]),
+        None,
+        Some(&[0x9e, 0x4a]),
+        Some(&[0x8f, 0x91]),
+        Some(&[0x91, 0x82]),
+        None,
+        None,
+        Some(&[0x99, 0xd6]),
+        Some(&[0x91, 0x5d]),
+        Some(&[0x91, 0x5c]),
+        Some(&[0x91, 0xd6]),
+        Some(&[0x8d, 0xc5]),
+        None,
+        None,
+        Some(&[0x98, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x8e]),
+        Some(&[0x97, 0x4c]),
+        None,
+        Some(&[0x95, 0xfc]),
+        None,
+        Some(&[0x95, 0x9e]),
+        None,
+        Some(&[0x9e, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xf1]),
+        Some(&[0x92, 0xbd]),
+        Some(&[0x9e, 0x4c]),
+        Some(&[0x98, 0x4e]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x5d]),
+        None,
+        Some(&[0x92, 0xa9]),
+        Some(&[0x9e, 0x4d]),
+        Some(&[0x8a, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x4e]),
+        Some(&[0x9e, 0x4f]),
+        Some(&[0x96, 0xd8]),
+        None,
+        Some(&[0x96, 0xa2]),
+        Some(&[0x96, 0x96]),
+        Some(&[0x96, 0x7b]),
+        Some(&[0x8e, 0x44]),
+        Some(&[0x9e, 0x51]),
+        None,
+        None,
+        Some(&[0x8e, 0xe9]),
+        None,
+        None,
+        Some(&[0x96, 0x70]),
+        None,
+        Some(&[0x9e, 0x53]),
+        Some(&[0x9e, 0x56]),
+        Some(&[0x9e, 0x55]),
+        None,
+        Some(&[0x8a, 0xf7]),
+        None,
+        None,
+        Some(&[0x8b, 0x80]),
+        None,
+        Some(&[0x9e, 0x52]),
+        None,
+        Some(&[0x9e, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x57]),
+        None,
+        None,
+        Some(&[0x90, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x9b]),
+        Some(&[0x88, 0xc7]),
+        Some(&[0x8d, 0xde]),
+        Some(&[0x91, 0xba]),
+        None,
+        Some(&[0x8e, 0xdb]),
+        None,
+        None,
+        Some(&[0x8f, 0xf1]),
+        None,
+        None,
+        Some(&[0x9e, 0x5a]),
+        None,
+        None,
+        Some(&[0x93, 0x6d]),
+        None,
+        Some(&[0x9e, 0x58]),
+        Some(&[0x91, 0xa9]),
+        Some(&[0x9e, 0x59]),
+        Some(&[0x8f, 0xf0]),
+        Some(&[0x96, 0xdb]),
+        Some(&[0x9e, 0x5b]),
+        Some(&[0x9e, 0x5c]),
+        Some(&[0x97, 0x88]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x61]),
+        None,
+        None,
+        Some(&[0x8d, 0x59]),
+        None,
+        Some(&[0x94, 0x74]),
+        Some(&[0x9e, 0x5e]),
+        Some(&[0x93, 0x8c]),
+        Some(&[0x9d, 0xdc]),
+        Some(&[0x9d, 0xe0]),
+        None,
+        Some(&[0x8b, 0x6e]),
+        None,
+        Some(&[0x94, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x60]),
+        None,
+        Some(&[0x8f, 0xbc]),
+        Some(&[0x94, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x66]),
+        None,
+        Some(&[0x94, 0xf8]),
+        None,
+        Some(&[0x9e, 0x5d]),
+        None,
+        Some(&[0x9e, 0x63]),
+        Some(&[0x9e, 0x62]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x8d]),
+        None,
+        Some(&[0x97, 0xd1]),
+        None,
+        None,
+        Some(&[0x96, 0x87]),
+        None,
+        Some(&[0x89, 0xca]),
+        Some(&[0x8e, 0x7d]),
+        None,
+        None,
+        Some(&[0x98, 0x67]),
+        Some(&[0x9e, 0x65]),
+        Some(&[0x90, 0x95]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x64]),
+        None,
+        None,
+        Some(&[0x9e, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x6b]),
+        Some(&[0x9e, 0x69]),
+        None,
+        Some(&[0x89, 0xcb]),
+        Some(&[0x9e, 0x67]),
+        Some(&[0x9e, 0x6d]),
+        Some(&[0x9e, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xc6]),
+        None,
+        None,
+        Some(&[0x95, 0xbf]),
+        None,
+        Some(&[0x9e, 0x75]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x41]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x74]),
+        Some(&[0x94, 0x90]),
+        Some(&[0x96, 0x5e]),
+        Some(&[0x8a, 0xb9]),
+        None,
+        Some(&[0x90, 0xf5]),
+        Some(&[0x8f, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xd1]),
+        None,
+        Some(&[0x97, 0x4d]),
+        None,
+        None,
+        Some(&[0x9e, 0x70]),
+        Some(&[0x9e, 0x6f]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x71]),
+        None,
+        Some(&[0x9e, 0x6e]),
+        None,
+        None,
+        Some(&[0x9e, 0x76]),
+        None,
+        Some(&[0x9e, 0x6c]),
+        None,
+        None,
+        Some(&[0x9e, 0x6a]),
+        None,
+        Some(&[0x9e, 0x72]),
+        Some(&[0x9e, 0x68]),
+        None,
+        Some(&[0x92, 0x8c]),
+        None,
+        Some(&[0x96, 0xf6]),
+        Some(&[0x8e, 0xc4]),
+        Some(&[0x8d, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xb8]),
+        None,
+        None,
+        Some(&[0x96, 0x8f]),
+        Some(&[0x8a, 0x60]),
+        None,
+        None,
+        Some(&[0x92, 0xcc]),
+        Some(&[0x93, 0xc8]),
+        Some(&[0x89, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xf0]),
+        None,
+        None,
+        Some(&[0x90, 0xb2]),
+        Some(&[0x8c, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x78]),
+        None,
+        None,
+        Some(&[0x8d, 0x5a]),
+        Some(&[0x8a, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x7a]),
+        Some(&[0x8a, 0x94]),
+        Some(&[0x9e, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x7d]),
+        None,
+        Some(&[0x90, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x6a]),
+        Some(&[0x8d, 0xaa]),
+        None,
+        None,
+        Some(&[0x8a, 0x69]),
+        Some(&[0x8d, 0xcd]),
+        None,
+        None,
+        Some(&[0x9e, 0x7b]),
+        Some(&[0x8c, 0x85]),
+        Some(&[0x8c, 0x6a]),
+        Some(&[0x93, 0x8d]),
+        None,
+        None,
+        Some(&[0x9e, 0x79]),
+        None,
+        Some(&[0x88, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x7c]),
+        Some(&[0x9e, 0x7e]),
+        None,
+        Some(&[0x8b, 0xcb]),
+        Some(&[0x8c, 0x4b]),
+        None,
+        Some(&[0x8a, 0xba]),
+        Some(&[0x8b, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x82]),
+        None,
+        None,
+        Some(&[0x8d, 0xf7]),
+        Some(&[0x96, 0x91]),
+        None,
+        Some(&[0x8e, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x83]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x8f]),
+        None,
+        Some(&[0x89, 0xb1]),
+        Some(&[0x9e, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x95]),
+        Some(&[0x9e, 0x85]),
+        None,
+        Some(&[0x97, 0xc0]),
+        None,
+        Some(&[0x9e, 0x8c]),
+        None,
+        Some(&[0x94, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x94]),
+        None,
+        Some(&[0x9e, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xb2]),
+        Some(&[0x9e, 0x89]),
+        None,
+        None,
+        Some(&[0x8d, 0x5b]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x8b]),
+        None,
+        Some(&[0x9e, 0x8a]),
+        None,
+        Some(&[0x9e, 0x86]),
+        Some(&[0x9e, 0x91]),
+        None,
+        Some(&[0x8f, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0xeb]),
+        Some(&[0x8c, 0xe6]),
+        Some(&[0x97, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x88]),
+        None,
+        Some(&[0x92, 0xf2]),
+        Some(&[0x8a, 0x42]),
+        Some(&[0x8d, 0xab]),
+        None,
+        Some(&[0x9e, 0x80]),
+        None,
+        Some(&[0x9e, 0x90]),
+        Some(&[0x8a, 0x81]),
+        None,
+        None,
+        Some(&[0x9e, 0x8e]),
+        Some(&[0x9e, 0x92]),
+        None,
+        Some(&[0x93, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xfc]),
+        None,
+        Some(&[0x9e, 0xb0]),
+        None,
+        None,
+        Some(&[0x96, 0xc7]),
+        Some(&[0x9e, 0x97]),
+        Some(&[0x8a, 0xfb]),
+        None,
+        Some(&[0x9e, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x5f]),
+        None,
+        Some(&[0x9e, 0x9f]),
+        Some(&[0x9e, 0xa1]),
+        None,
+        Some(&[0x9e, 0xa5]),
+        Some(&[0x9e, 0x99]),
+        None,
+        Some(&[0x92, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x8f]),
+        Some(&[0x9e, 0xa9]),
+        Some(&[0x9e, 0x9c]),
+        None,
+        Some(&[0x9e, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xa0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x58]),
+        Some(&[0x9e, 0xaa]),
+        None,
+        None,
+        Some(&[0x90, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xa8]),
+        Some(&[0x8a, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x6f]),
+        Some(&[0x9e, 0x96]),
+        None,
+        None,
+        Some(&[0x9e, 0xa4]),
+        Some(&[0x88, 0xd6]),
+        None,
+        None,
+        Some(&[0x9e, 0x98]),
+        None,
+        None,
+        Some(&[0x96, 0xb8]),
+        Some(&[0x9e, 0x9d]),
+        Some(&[0x90, 0x41]),
+        Some(&[0x92, 0xc5]),
+        Some(&[0x9e, 0x93]),
+        None,
+        None,
+        Some(&[0x9e, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x9a]),
+        Some(&[0x9e, 0xad]),
+        Some(&[0x8a, 0x91]),
+        Some(&[0x8c, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xaf]),
+        Some(&[0x9e, 0x9a]),
+        Some(&[0x9e, 0xae]),
+        None,
+        Some(&[0x9e, 0xa7]),
+        Some(&[0x9e, 0x9b]),
+        None,
+        Some(&[0x9e, 0xab]),
+        None,
+        Some(&[0x9e, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xcc]),
+        None,
+        Some(&[0x9e, 0xa2]),
+        None,
+        None,
+        Some(&[0x9e, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xbb]),
+        None,
+        Some(&[0x92, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x96]),
+        Some(&[0x9e, 0xb6]),
+        Some(&[0x91, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xbc]),
+        Some(&[0x91, 0x5e]),
+        None,
+        Some(&[0x9e, 0xb3]),
+        Some(&[0x9e, 0xc0]),
+        Some(&[0x9e, 0xbf]),
+        None,
+        Some(&[0x93, 0xed]),
+        Some(&[0x9e, 0xbe]),
+        Some(&[0x93, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xc2]),
+        Some(&[0x9e, 0xb5]),
+        None,
+        Some(&[0x8b, 0xc6]),
+        Some(&[0x9e, 0xb8]),
+        Some(&[0x8f, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x80]),
+        Some(&[0x9e, 0xba]),
+        Some(&[0x8b, 0xc9]),
+        None,
+        Some(&[0x9e, 0xb2]),
+        Some(&[0x9e, 0xb4]),
+        Some(&[0x9e, 0xb1]),
+        None,
+        None,
+        Some(&[0x98, 0x4f]),
+        Some(&[0x8a, 0x79]),
+        Some(&[0x9e, 0xb7]),
+        None,
+        None,
+        Some(&[0x9e, 0xc1]),
+        Some(&[0x8a, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x7c]),
+        None,
+        None,
+        Some(&[0x9e, 0xd2]),
+        None,
+        None,
+        Some(&[0x98, 0x50]),
+        Some(&[0x9e, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x59]),
+        Some(&[0x9e, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xc4]),
+        None,
+        None,
+        Some(&[0x9e, 0xe1]),
+        Some(&[0x9e, 0xc3]),
+        None,
+        Some(&[0x9e, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xce]),
+        None,
+        None,
+        Some(&[0x9e, 0xc9]),
+        Some(&[0x9e, 0xc6]),
+        None,
+        Some(&[0x9e, 0xc7]),
+        None,
+        Some(&[0x9e, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xa0]),
+        None,
+        None,
+        Some(&[0x9e, 0xcc]),
+        Some(&[0x8d, 0x5c]),
+        Some(&[0x92, 0xc6]),
+        Some(&[0x91, 0x84]),
+        Some(&[0x9e, 0xca]),
+        None,
+        Some(&[0x9e, 0xc5]),
+        None,
+        None,
+        Some(&[0x9e, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x6c]),
+        Some(&[0x96, 0x8a]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xcd]),
+        Some(&[0x9e, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xdf]),
+        Some(&[0x9e, 0xd8]),
+        None,
+        None,
+        Some(&[0x9e, 0xe5]),
+        None,
+        Some(&[0x9e, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xdd]),
+        None,
+        Some(&[0x92, 0xce]),
+        None,
+        Some(&[0x91, 0x85]),
+        None,
+        Some(&[0x9e, 0xdb]),
+        None,
+        None,
+        Some(&[0x9e, 0xd9]),
+        None,
+        None,
+        Some(&[0x9e, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xe6]),
+        Some(&[0x94, 0xf3]),
+        Some(&[0x9e, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xe7]),
+        Some(&[0x9e, 0xea]),
+        Some(&[0x9e, 0xe4]),
+        None,
+        None,
+        Some(&[0x92, 0x94]),
+        None,
+        Some(&[0x95, 0x57]),
+        None,
+        Some(&[0x9e, 0xda]),
+        None,
+        None,
+        Some(&[0x9e, 0xe2]),
+        Some(&[0x8f, 0xbe]),
+        None,
+        Some(&[0x96, 0xcd]),
+        Some(&[0x9e, 0xf6]),
+        Some(&[0x9e, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xa0]),
+        Some(&[0x89, 0xa1]),
+        Some(&[0x8a, 0x7e]),
+        None,
+        None,
+        Some(&[0x9e, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xbf]),
+        Some(&[0x9e, 0xee]),
+        None,
+        Some(&[0x9e, 0xf5]),
+        Some(&[0x8e, 0xf7]),
+        Some(&[0x8a, 0x92]),
+        None,
+        None,
+        Some(&[0x92, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xeb]),
+        None,
+        None,
+        Some(&[0x9e, 0xf0]),
+        Some(&[0x9e, 0xf4]),
+        None,
+        None,
+        Some(&[0x8b, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x6b]),
+        Some(&[0x9e, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x40]),
+        None,
+        Some(&[0x93, 0xc9]),
+        Some(&[0x9e, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x80]),
+        Some(&[0x92, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xf8]),
+        Some(&[0x8c, 0xe7]),
+        None,
+        Some(&[0x9e, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x40]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x77]),
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xf9]),
+        None,
+        Some(&[0x9e, 0xfb]),
+        Some(&[0x9e, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x4b]),
+        None,
+        Some(&[0x9f, 0x47]),
+        None,
+        Some(&[0x9e, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x45]),
+        None,
+        None,
+        Some(&[0x9f, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xe8]),
+        Some(&[0x9f, 0x44]),
+        Some(&[0x9f, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x49]),
+        None,
+        Some(&[0x98, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x4c]),
+        Some(&[0x8b, 0xf9]),
+        None,
+        None,
+        Some(&[0x9f, 0x48]),
+        Some(&[0x9f, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xa5]),
+        None,
+        Some(&[0x9f, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x51]),
+        Some(&[0x9f, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x93]),
+        Some(&[0x9f, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x54]),
+        None,
+        Some(&[0x9f, 0x55]),
+        Some(&[0x8c, 0x87]),
+        Some(&[0x8e, 0x9f]),
+        None,
+        Some(&[0x8b, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x57]),
+        Some(&[0x9f, 0x56]),
+        Some(&[0x9f, 0x59]),
+        Some(&[0x8b, 0x5c]),
+        None,
+        None,
+        Some(&[0x8b, 0xd4]),
+        Some(&[0x8a, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x5b]),
+        None,
+        Some(&[0x9f, 0x5d]),
+        None,
+        None,
+        Some(&[0x89, 0xcc]),
+        None,
+        Some(&[0x92, 0x56]),
+        None,
+        Some(&[0x9f, 0x5e]),
+        None,
+        None,
+        Some(&[0x8a, 0xbd]),
+        Some(&[0x9f, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x5f]),
+        None,
+        Some(&[0x9f, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x62]),
+        None,
+        Some(&[0x9f, 0x63]),
+        Some(&[0x8e, 0x7e]),
+        Some(&[0x90, 0xb3]),
+        Some(&[0x8d, 0x9f]),
+        None,
+        Some(&[0x95, 0x90]),
+        None,
+        None,
+        Some(&[0x95, 0xe0]),
+        Some(&[0x98, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x95]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xce]),
+        Some(&[0x97, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x64]),
+        Some(&[0x9f, 0x65]),
+        None,
+        Some(&[0x8e, 0x80]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x66]),
+        Some(&[0x9f, 0x67]),
+        None,
+        None,
+        Some(&[0x9f, 0x69]),
+        Some(&[0x9f, 0x68]),
+        None,
+        Some(&[0x96, 0x77]),
+        None,
+        None,
+        Some(&[0x8f, 0x7d]),
+        Some(&[0x8e, 0xea]),
+        Some(&[0x8e, 0x63]),
+        None,
+        Some(&[0x9f, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x6c]),
+        Some(&[0x90, 0x42]),
+        None,
+        Some(&[0x9f, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x6f]),
+        Some(&[0x9f, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x71]),
+        None,
+        Some(&[0x9f, 0x73]),
+        Some(&[0x9f, 0x72]),
+        Some(&[0x9f, 0x74]),
+        Some(&[0x89, 0xa3]),
+        Some(&[0x92, 0x69]),
+        None,
+        Some(&[0x9f, 0x75]),
+        None,
+        None,
+        Some(&[0x8e, 0x45]),
+        Some(&[0x8a, 0x6b]),
+        Some(&[0x9f, 0x76]),
+        None,
+        None,
+        Some(&[0x93, 0x61]),
+        Some(&[0x9a, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x42]),
+        Some(&[0x9f, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x78]),
+        None,
+        Some(&[0x95, 0xea]),
+        Some(&[0x96, 0x88]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xc5]),
+        Some(&[0x9f, 0x79]),
+        Some(&[0x94, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xf9]),
+        None,
+        None,
+        Some(&[0x96, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x7c]),
+        Some(&[0x9f, 0x7b]),
+        None,
+        None,
+        Some(&[0x9f, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6C08: [Option<&'static [u8]>; 240] = [
+        Some(&[0x9f, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x81]),
+        None,
+        Some(&[0x96, 0xaf]),
+        None,
+        Some(&[0x9f, 0x82]),
+        Some(&[0x9f, 0x83]),
+        None,
+        None,
+        Some(&[0x8b, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x86]),
+        Some(&[0x9f, 0x85]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x85]),
+        None,
+        None,
+        Some(&[0x95, 0x58]),
+        Some(&[0x89, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xc3]),
+        None,
+        Some(&[0x92, 0xf3]),
+        Some(&[0x8f, 0x60]),
+        Some(&[0x8b, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xc4]),
+        None,
+        Some(&[0x8e, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x88]),
+        None,
+        Some(&[0x8a, 0xbe]),
+        None,
+        None,
+        Some(&[0x89, 0x98]),
+        None,
+        None,
+        Some(&[0x93, 0xf0]),
+        Some(&[0x9f, 0x87]),
+        Some(&[0x8d, 0x5d]),
+        Some(&[0x92, 0x72]),
+        None,
+        Some(&[0x9f, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x91]),
+        None,
+        Some(&[0x9f, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xbf]),
+        None,
+        Some(&[0x8b, 0x82]),
+        Some(&[0x9f, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x88]),
+        None,
+        None,
+        Some(&[0x8b, 0x44]),
+        Some(&[0x9f, 0x90]),
+        None,
+        None,
+        Some(&[0x9f, 0x8e]),
+        Some(&[0x9f, 0x8b]),
+        Some(&[0x97, 0x80]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xd7]),
+        Some(&[0x9f, 0x8c]),
+        None,
+        None,
+        Some(&[0x9f, 0x94]),
+        None,
+        Some(&[0x9f, 0x93]),
+        Some(&[0x8c, 0x42]),
+        None,
+        None,
+        Some(&[0x89, 0xab]),
+        None,
+        None,
+        Some(&[0x8d, 0xb9]),
+        Some(&[0x9f, 0x8d]),
+        Some(&[0x9f, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x76]),
+        Some(&[0x91, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x97]),
+        None,
+        None,
+        Some(&[0x9f, 0x9c]),
+        None,
+        None,
+        Some(&[0x9f, 0x9d]),
+        None,
+        Some(&[0x89, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xa6]),
+        Some(&[0x96, 0xfb]),
+        Some(&[0x9f, 0x9f]),
+        Some(&[0x8e, 0xa1]),
+        Some(&[0x8f, 0xc0]),
+        Some(&[0x9f, 0x98]),
+        Some(&[0x9f, 0x9e]),
+        Some(&[0x89, 0x88]),
+        None,
+        Some(&[0x8b, 0xb5]),
+        None,
+        None,
+        Some(&[0x9f, 0x95]),
+        Some(&[0x9f, 0x9a]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xf2]),
+        Some(&[0x94, 0x91]),
+        None,
+        Some(&[0x94, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0x97]),
+        None,
+        Some(&[0x96, 0x40]),
+        None,
+        Some(&[0x9f, 0x99]),
+        None,
+        Some(&[0x9f, 0xa2]),
+        None,
+        Some(&[0x9f, 0xa0]),
+        None,
+        Some(&[0x9f, 0x9b]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x41]),
+        Some(&[0x94, 0x67]),
+        Some(&[0x8b, 0x83]),
+        None,
+        Some(&[0x93, 0x44]),
+        None,
+        None,
+        Some(&[0x92, 0x8d]),
+        None,
+        Some(&[0x9f, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xa1]),
+        Some(&[0x91, 0xd7]),
+        Some(&[0x9f, 0x96]),
+        None,
+        Some(&[0x89, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6D08: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x6d]),
+        Some(&[0x9f, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xf4]),
+        None,
+        Some(&[0x9f, 0xaa]),
+        None,
+        Some(&[0x97, 0x8c]),
+        None,
+        None,
+        Some(&[0x93, 0xb4]),
+        Some(&[0x9f, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x6b]),
+        Some(&[0x8d, 0x5e]),
+        Some(&[0x9f, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x46]),
+        Some(&[0x9f, 0xac]),
+        None,
+        Some(&[0x9f, 0xab]),
+        Some(&[0x9f, 0xa6]),
+        None,
+        Some(&[0x9f, 0xa9]),
+        None,
+        None,
+        Some(&[0x8a, 0x88]),
+        None,
+        Some(&[0x9f, 0xa8]),
+        Some(&[0x94, 0x68]),
+        None,
+        None,
+        Some(&[0x97, 0xac]),
+        None,
+        None,
+        Some(&[0x8f, 0xf2]),
+        Some(&[0x90, 0xf3]),
+        None,
+        None,
+    ];
+
+    const BASE_0X6D58: [Option<&'static [u8]>; 72] = [
+        None,
+        Some(&[0x9f, 0xb4]),
+        Some(&[0x9f, 0xb2]),
+        None,
+        Some(&[0x95, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xaf]),
+        Some(&[0x9f, 0xb1]),
+        None,
+        Some(&[0x89, 0x59]),
+        None,
+        None,
+        Some(&[0x8d, 0x5f]),
+        Some(&[0x98, 0x51]),
+        None,
+        Some(&[0x8a, 0x5c]),
+        None,
+        Some(&[0x95, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x81]),
+        None,
+        None,
+        Some(&[0x8a, 0x43]),
+        Some(&[0x90, 0x5a]),
+        Some(&[0x9f, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xb8]),
+        None,
+        None,
+        Some(&[0x8f, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x4f]),
+        None,
+        Some(&[0x9f, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xb0]),
+        None,
+        Some(&[0x9f, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xdc]),
+        None,
+        Some(&[0x93, 0x93]),
+        Some(&[0x93, 0xc0]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X6DA8: [Option<&'static [u8]>; 760] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x55]),
+        None,
+        None,
+        Some(&[0x89, 0x74]),
+        None,
+        None,
+        Some(&[0x9f, 0xbc]),
+        None,
+        None,
+        Some(&[0x9f, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xc6]),
+        Some(&[0x9f, 0xc0]),
+        Some(&[0x9f, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xd2]),
+        Some(&[0x9f, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x69]),
+        Some(&[0x9f, 0xc5]),
+        None,
+        None,
+        Some(&[0x9f, 0xca]),
+        None,
+        None,
+        Some(&[0x93, 0x91]),
+        Some(&[0x9f, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xc2]),
+        None,
+        None,
+        Some(&[0x92, 0x57]),
+        None,
+        None,
+        Some(&[0x9f, 0xc9]),
+        None,
+        Some(&[0x9f, 0xbe]),
+        None,
+        Some(&[0x9f, 0xc4]),
+        None,
+        Some(&[0x9f, 0xcb]),
+        Some(&[0x88, 0xfa]),
+        Some(&[0x9f, 0xc1]),
+        None,
+        Some(&[0x9f, 0xcc]),
+        None,
+        None,
+        Some(&[0x90, 0x5b]),
+        None,
+        Some(&[0x8f, 0x7e]),
+        None,
+        Some(&[0x95, 0xa3]),
+        None,
+        Some(&[0x8d, 0xac]),
+        None,
+        Some(&[0x9f, 0xb9]),
+        Some(&[0x9f, 0xc7]),
+        Some(&[0x93, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xb4]),
+        None,
+        Some(&[0x8a, 0x89]),
+        Some(&[0x8d, 0xcf]),
+        Some(&[0x8f, 0xc2]),
+        Some(&[0x9f, 0xbb]),
+        Some(&[0x8f, 0x61]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x6b]),
+        None,
+        Some(&[0x9f, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xd0]),
+        Some(&[0x8f, 0x8d]),
+        Some(&[0x8c, 0xb8]),
+        None,
+        Some(&[0x9f, 0xdf]),
+        None,
+        Some(&[0x9f, 0xd9]),
+        Some(&[0x8b, 0x94]),
+        Some(&[0x93, 0x6e]),
+        None,
+        Some(&[0x9f, 0xd4]),
+        Some(&[0x9f, 0xdd]),
+        Some(&[0x88, 0xad]),
+        Some(&[0x89, 0x51]),
+        None,
+        None,
+        Some(&[0x89, 0xb7]),
+        None,
+        Some(&[0x9f, 0xd6]),
+        Some(&[0x91, 0xaa]),
+        Some(&[0x9f, 0xcd]),
+        Some(&[0x9f, 0xcf]),
+        Some(&[0x8d, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xe0]),
+        None,
+        Some(&[0x9f, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xa9]),
+        None,
+        None,
+        Some(&[0x9f, 0xd8]),
+        Some(&[0x9f, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xce]),
+        None,
+        Some(&[0x8f, 0xc3]),
+        None,
+        None,
+        Some(&[0x92, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x4e]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xd5]),
+        None,
+        None,
+        Some(&[0x9f, 0xce]),
+        Some(&[0x93, 0x92]),
+        None,
+        None,
+        Some(&[0x9f, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x70]),
+        Some(&[0x8e, 0xbc]),
+        Some(&[0x96, 0x9e]),
+        None,
+        Some(&[0x9f, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xac]),
+        None,
+        None,
+        Some(&[0x9f, 0xed]),
+        Some(&[0x8c, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x80]),
+        None,
+        Some(&[0x9f, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xad]),
+        Some(&[0x8d, 0x61]),
+        None,
+        Some(&[0x9f, 0xf0]),
+        None,
+        None,
+        Some(&[0x88, 0xec]),
+        None,
+        None,
+        Some(&[0x9f, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xe8]),
+        None,
+        None,
+        Some(&[0x9f, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x6e]),
+        Some(&[0x9f, 0xe5]),
+        None,
+        None,
+        Some(&[0x93, 0x4d]),
+        None,
+        None,
+        Some(&[0x9f, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xef]),
+        None,
+        Some(&[0x9f, 0xe9]),
+        Some(&[0x96, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xe4]),
+        None,
+        Some(&[0x8e, 0xa0]),
+        Some(&[0x9f, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x8a]),
+        None,
+        Some(&[0x9f, 0xe6]),
+        Some(&[0x9f, 0xeb]),
+        Some(&[0x9f, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xea]),
+        Some(&[0x91, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xf4]),
+        None,
+        None,
+        Some(&[0x9f, 0xfa]),
+        None,
+        None,
+        Some(&[0x9f, 0xf8]),
+        None,
+        Some(&[0x93, 0x48]),
+        None,
+        None,
+        Some(&[0xe0, 0x42]),
+        Some(&[0x9f, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xf6]),
+        Some(&[0x9f, 0xde]),
+        None,
+        Some(&[0x8b, 0x99]),
+        Some(&[0x95, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xbd]),
+        None,
+        None,
+        Some(&[0x8d, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x52]),
+        None,
+        Some(&[0x9f, 0xf2]),
+        None,
+        Some(&[0xe0, 0x41]),
+        Some(&[0x89, 0x89]),
+        Some(&[0x91, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x99]),
+        None,
+        Some(&[0x8a, 0xbf]),
+        Some(&[0x97, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x9f]),
+        Some(&[0x92, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xf9]),
+        Some(&[0x9f, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x40]),
+        Some(&[0x9f, 0xf7]),
+        None,
+        Some(&[0x9f, 0xf1]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x4e]),
+        None,
+        None,
+        Some(&[0xe0, 0x49]),
+        Some(&[0x90, 0xf6]),
+        None,
+        None,
+        Some(&[0x8a, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x81]),
+        None,
+        Some(&[0xe0, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x4b]),
+        Some(&[0x92, 0xaa]),
+        Some(&[0xe0, 0x48]),
+        Some(&[0x92, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x6b]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x45]),
+        None,
+        Some(&[0xe0, 0x44]),
+        None,
+        Some(&[0xe0, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x47]),
+        Some(&[0xe0, 0x46]),
+        Some(&[0xe0, 0x4c]),
+        None,
+        Some(&[0x90, 0x9f]),
+        None,
+        Some(&[0xe0, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x4f]),
+        None,
+        None,
+        Some(&[0xe0, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x55]),
+        None,
+        Some(&[0xe0, 0x54]),
+        Some(&[0xe0, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x62]),
+        None,
+        Some(&[0xe0, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x83]),
+        Some(&[0x91, 0xf7]),
+        Some(&[0xe0, 0x51]),
+        Some(&[0x94, 0x5a]),
+        None,
+        None,
+        Some(&[0xe0, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x5d]),
+        Some(&[0xe0, 0x5b]),
+        None,
+        None,
+        Some(&[0xe0, 0x5e]),
+        None,
+        None,
+        Some(&[0xe0, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x5a]),
+        Some(&[0x8d, 0x8a]),
+        Some(&[0x94, 0x47]),
+        None,
+        None,
+        Some(&[0x9f, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x94]),
+        Some(&[0xe0, 0x5c]),
+        None,
+        Some(&[0xe0, 0x60]),
+        Some(&[0x91, 0xf3]),
+        None,
+        Some(&[0xe0, 0x5f]),
+        None,
+        Some(&[0xe0, 0x4a]),
+        None,
+        None,
+        Some(&[0xe8, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x64]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x68]),
+        None,
+        None,
+        Some(&[0xe0, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x62]),
+        None,
+        Some(&[0xe0, 0x63]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x67]),
+        None,
+        Some(&[0xe0, 0x65]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x6d]),
+        None,
+        None,
+        Some(&[0xe0, 0x6d]),
+        None,
+        Some(&[0xe0, 0x6a]),
+        Some(&[0xe0, 0x69]),
+        None,
+        Some(&[0xe0, 0x6c]),
+        Some(&[0x93, 0xd2]),
+        Some(&[0xe0, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x95]),
+        Some(&[0x91, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x6f]),
+        None,
+        Some(&[0xe0, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9f, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x94]),
+        Some(&[0x8a, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x84]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xdc]),
+        Some(&[0x8d, 0xd0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x46]),
+        Some(&[0x90, 0x86]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x8a]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X70A8: [Option<&'static [u8]>; 56] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x78]),
+        Some(&[0x92, 0x59]),
+        Some(&[0xe0, 0x7b]),
+        Some(&[0xe0, 0x76]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x79]),
+        Some(&[0x93, 0x5f]),
+        Some(&[0x88, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xf3]),
+        None,
+        None,
+        Some(&[0xe0, 0x7d]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x80]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x7e]),
+        None,
+        Some(&[0xe0, 0x7c]),
+    ];
+
+    const BASE_0X70F0: [Option<&'static [u8]>; 680] = [
+        None,
+        Some(&[0xe0, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x84]),
+        Some(&[0x95, 0xb0]),
+        None,
+        Some(&[0xe0, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xf9]),
+        None,
+        None,
+        Some(&[0xe0, 0x8a]),
+        None,
+        Some(&[0x90, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x86]),
+        Some(&[0xe0, 0x8b]),
+        None,
+        None,
+        Some(&[0x89, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x89]),
+        None,
+        Some(&[0x94, 0x81]),
+        Some(&[0xe0, 0x85]),
+        Some(&[0xe0, 0x88]),
+        Some(&[0x8f, 0xc6]),
+        None,
+        Some(&[0x94, 0xcf]),
+        None,
+        None,
+        Some(&[0xe0, 0x8c]),
+        None,
+        Some(&[0x8e, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x8f]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x87]),
+        None,
+        Some(&[0x8c, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x6f]),
+        Some(&[0xe0, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x91]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x94]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x95]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x95]),
+        Some(&[0xe0, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x99]),
+        None,
+        Some(&[0x97, 0xd3]),
+        None,
+        Some(&[0xe0, 0x96]),
+        None,
+        Some(&[0xe0, 0x98]),
+        Some(&[0x89, 0x8d]),
+        None,
+        Some(&[0xe0, 0x93]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9a, 0x7a]),
+        Some(&[0xe0, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x87]),
+        Some(&[0x8e, 0x57]),
+        Some(&[0xe0, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x9b]),
+        Some(&[0x90, 0x43]),
+        Some(&[0x99, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x9d]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0x9f]),
+        None,
+        Some(&[0xe0, 0x8e]),
+        Some(&[0xe0, 0x9e]),
+        None,
+        None,
+        Some(&[0xe0, 0xa0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa1]),
+        None,
+        None,
+        Some(&[0xe0, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xa4]),
+        None,
+        Some(&[0x92, 0xdc]),
+        None,
+        Some(&[0xe0, 0xa6]),
+        Some(&[0xe0, 0xa5]),
+        None,
+        None,
+        Some(&[0xe0, 0xa7]),
+        None,
+        Some(&[0xe0, 0xa8]),
+        None,
+        None,
+        Some(&[0x8e, 0xdd]),
+        Some(&[0x95, 0x83]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xea]),
+        Some(&[0xe0, 0xa9]),
+        Some(&[0xe0, 0xaa]),
+        Some(&[0x91, 0x75]),
+        Some(&[0x8e, 0xa2]),
+        Some(&[0xe0, 0xab]),
+        Some(&[0xe0, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xad]),
+        Some(&[0x95, 0xd0]),
+        Some(&[0x94, 0xc5]),
+        None,
+        None,
+        Some(&[0xe0, 0xae]),
+        Some(&[0x94, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xaf]),
+        Some(&[0x89, 0xe5]),
+        None,
+        Some(&[0x8b, 0x8d]),
+        None,
+        Some(&[0x96, 0xc4]),
+        None,
+        Some(&[0x96, 0xb4]),
+        None,
+        Some(&[0x89, 0xb2]),
+        Some(&[0x98, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x71]),
+        None,
+        Some(&[0x95, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xb5]),
+        None,
+        Some(&[0xe0, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xa1]),
+        Some(&[0xe0, 0xb1]),
+        None,
+        Some(&[0x8d, 0xd2]),
+        Some(&[0xe0, 0xb3]),
+        Some(&[0xe0, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x5d]),
+        None,
+        Some(&[0xe0, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xa2]),
+        None,
+        None,
+        Some(&[0x94, 0xc6]),
+        None,
+        None,
+        Some(&[0xe0, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xf3]),
+        None,
+        None,
+        Some(&[0xe0, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xb6]),
+        Some(&[0xe0, 0xbb]),
+        Some(&[0xe0, 0xbd]),
+        None,
+        Some(&[0xe0, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xbe]),
+        None,
+        Some(&[0x8c, 0xcf]),
+        None,
+        Some(&[0xe0, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xe7]),
+        None,
+        Some(&[0x91, 0x5f]),
+        None,
+        Some(&[0x8d, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xc1]),
+        Some(&[0xe0, 0xc2]),
+        Some(&[0xe0, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xeb]),
+        None,
+        None,
+        Some(&[0x93, 0xc6]),
+        Some(&[0x8b, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xc4]),
+        Some(&[0x92, 0x4b]),
+        Some(&[0xe0, 0xc3]),
+        None,
+        None,
+        Some(&[0x98, 0x54]),
+        Some(&[0x94, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xc9]),
+        Some(&[0xe0, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xd2]),
+        Some(&[0xe0, 0xc8]),
+        Some(&[0xe0, 0xca]),
+        None,
+        Some(&[0x97, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xcd]),
+        Some(&[0x92, 0x96]),
+        Some(&[0x94, 0x4c]),
+        None,
+        None,
+        Some(&[0x8c, 0xa3]),
+        Some(&[0xe0, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xcb]),
+        None,
+        Some(&[0x97, 0x50]),
+        Some(&[0x97, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xcf]),
+        Some(&[0x89, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x96]),
+        Some(&[0x8e, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd0]),
+        Some(&[0xe0, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x62]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd5]),
+        None,
+        Some(&[0xe0, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd6]),
+        None,
+        Some(&[0x8a, 0x6c]),
+        None,
+        None,
+        Some(&[0xe0, 0xd8]),
+        None,
+        None,
+        Some(&[0xe0, 0xd7]),
+        None,
+        Some(&[0xe0, 0xda]),
+        Some(&[0xe0, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xba]),
+        None,
+        None,
+        Some(&[0x97, 0xa6]),
+        None,
+        Some(&[0x8b, 0xca]),
+        None,
+        Some(&[0x89, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xe8]),
+        None,
+    ];
+
+    const BASE_0X73A8: [Option<&'static [u8]>; 104] = [
+        None,
+        Some(&[0x8a, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xe6]),
+        Some(&[0xe0, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xdf]),
+        None,
+        Some(&[0x89, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xdb]),
+        None,
+        Some(&[0x8e, 0x58]),
+        None,
+        None,
+        Some(&[0x92, 0xbf]),
+        Some(&[0xe0, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe2]),
+        None,
+        Some(&[0x8e, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x5d]),
+        None,
+        None,
+        Some(&[0x94, 0xc7]),
+        Some(&[0xe0, 0xe1]),
+        None,
+        None,
+        Some(&[0xe0, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x85]),
+        None,
+        Some(&[0xe0, 0xe4]),
+        Some(&[0x97, 0x9d]),
+        None,
+        None,
+        Some(&[0x97, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7420: [Option<&'static [u8]>; 40] = [
+        None,
+        None,
+        Some(&[0x91, 0xf4]),
+        None,
+        None,
+        Some(&[0xe0, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe8]),
+        Some(&[0x97, 0xd4]),
+        Some(&[0x8b, 0xd5]),
+        Some(&[0x94, 0xfa]),
+        Some(&[0x94, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xeb]),
+        None,
+        Some(&[0xe0, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7450: [Option<&'static [u8]>; 64] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xed]),
+        Some(&[0x8c, 0xe8]),
+        Some(&[0x89, 0x6c]),
+        Some(&[0xe0, 0xef]),
+        None,
+        Some(&[0x90, 0x90]),
+        Some(&[0xe0, 0xec]),
+        Some(&[0x97, 0xda]),
+        None,
+        None,
+        Some(&[0xe0, 0xf2]),
+        Some(&[0xea, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf0]),
+        Some(&[0xe0, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe5]),
+        Some(&[0xe0, 0xf1]),
+        None,
+        None,
+        Some(&[0x8d, 0xba]),
+        None,
+        None,
+        Some(&[0xe0, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7498: [Option<&'static [u8]>; 376] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x5a]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x40]),
+        None,
+        Some(&[0x95, 0x5a]),
+        Some(&[0xe1, 0x41]),
+        None,
+        None,
+        Some(&[0x8a, 0xa2]),
+        Some(&[0xe1, 0x42]),
+        None,
+        Some(&[0xe1, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x44]),
+        None,
+        Some(&[0xe1, 0x46]),
+        Some(&[0xe1, 0x47]),
+        Some(&[0xe1, 0x45]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x72]),
+        Some(&[0xe1, 0x49]),
+        Some(&[0xe1, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x4b]),
+        Some(&[0xe1, 0x4a]),
+        Some(&[0xe1, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x4d]),
+        Some(&[0xe1, 0x4f]),
+        Some(&[0xe1, 0x4e]),
+        None,
+        None,
+        Some(&[0x8d, 0x99]),
+        None,
+        Some(&[0xe1, 0x51]),
+        None,
+        Some(&[0xe1, 0x50]),
+        None,
+        None,
+        Some(&[0x8a, 0xc3]),
+        None,
+        Some(&[0x90, 0x72]),
+        None,
+        Some(&[0x93, 0x5b]),
+        None,
+        Some(&[0xe1, 0x52]),
+        Some(&[0x90, 0xb6]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x59]),
+        None,
+        Some(&[0x89, 0x99]),
+        Some(&[0xe1, 0x53]),
+        None,
+        Some(&[0x97, 0x70]),
+        None,
+        None,
+        Some(&[0x95, 0xe1]),
+        Some(&[0xe1, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x63]),
+        Some(&[0x97, 0x52]),
+        Some(&[0x8d, 0x62]),
+        Some(&[0x90, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x6a]),
+        Some(&[0x99, 0xb2]),
+        None,
+        Some(&[0x92, 0xac]),
+        Some(&[0x89, 0xe6]),
+        Some(&[0xe1, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x56]),
+        None,
+        Some(&[0xe1, 0x5b]),
+        None,
+        None,
+        Some(&[0xe1, 0x59]),
+        Some(&[0xe1, 0x58]),
+        Some(&[0x9d, 0xc0]),
+        Some(&[0x8a, 0x45]),
+        Some(&[0xe1, 0x57]),
+        None,
+        Some(&[0x88, 0xd8]),
+        None,
+        Some(&[0x94, 0xa8]),
+        None,
+        None,
+        Some(&[0x94, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xaf]),
+        Some(&[0xe1, 0x5c]),
+        Some(&[0xe1, 0x5a]),
+        Some(&[0x92, 0x7b]),
+        Some(&[0x90, 0xa4]),
+        None,
+        None,
+        Some(&[0x94, 0xa9]),
+        None,
+        Some(&[0x95, 0x4c]),
+        None,
+        Some(&[0xe1, 0x5e]),
+        Some(&[0x97, 0xaa]),
+        Some(&[0x8c, 0x6c]),
+        Some(&[0xe1, 0x5f]),
+        None,
+        Some(&[0xe1, 0x5d]),
+        Some(&[0x94, 0xd4]),
+        Some(&[0xe1, 0x60]),
+        None,
+        Some(&[0xe1, 0x61]),
+        None,
+        None,
+        Some(&[0x88, 0xd9]),
+        None,
+        None,
+        Some(&[0x8f, 0xf4]),
+        Some(&[0xe1, 0x66]),
+        None,
+        Some(&[0xe1, 0x63]),
+        Some(&[0x93, 0xeb]),
+        Some(&[0xe1, 0x62]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x45]),
+        None,
+        None,
+        Some(&[0xe1, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x64]),
+        Some(&[0xe1, 0x65]),
+        None,
+        Some(&[0xe1, 0x68]),
+        Some(&[0xe1, 0x67]),
+        Some(&[0x95, 0x44]),
+        None,
+        None,
+        Some(&[0x91, 0x61]),
+        Some(&[0x91, 0x60]),
+        None,
+        Some(&[0x8b, 0x5e]),
+        None,
+        None,
+        Some(&[0xe1, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x6b]),
+        None,
+        None,
+        Some(&[0xe1, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x6e]),
+        None,
+        Some(&[0xe1, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x76]),
+        Some(&[0x94, 0xe6]),
+        Some(&[0xe1, 0x70]),
+        None,
+        Some(&[0xe1, 0x72]),
+        None,
+        None,
+        Some(&[0xe1, 0x74]),
+        Some(&[0x90, 0x5d]),
+        None,
+        None,
+        Some(&[0xe1, 0x75]),
+        Some(&[0xe1, 0x73]),
+        Some(&[0x8e, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x6f]),
+        Some(&[0xe1, 0x71]),
+        None,
+        Some(&[0x95, 0x61]),
+        None,
+        Some(&[0x8f, 0xc7]),
+        None,
+        None,
+        Some(&[0xe1, 0x78]),
+        None,
+        None,
+        Some(&[0xe1, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x79]),
+        None,
+        Some(&[0x8e, 0xa4]),
+        Some(&[0x8d, 0xad]),
+        None,
+        None,
+        Some(&[0x93, 0x97]),
+        Some(&[0xe1, 0x7a]),
+        None,
+        Some(&[0x92, 0xc9]),
+        None,
+        None,
+        Some(&[0xe1, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x9f]),
+        Some(&[0xe1, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x82]),
+        None,
+        Some(&[0xe1, 0x84]),
+        Some(&[0xe1, 0x85]),
+        Some(&[0x92, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x83]),
+        None,
+        Some(&[0xe1, 0x80]),
+        None,
+        Some(&[0xe1, 0x7d]),
+        Some(&[0xe1, 0x7e]),
+        None,
+        Some(&[0xe1, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x88]),
+        None,
+        Some(&[0xe1, 0x86]),
+        None,
+        Some(&[0xe1, 0x87]),
+        None,
+        None,
+    ];
+
+    const BASE_0X7618: [Option<&'static [u8]>; 136] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x89]),
+        Some(&[0xe1, 0x8b]),
+        Some(&[0xe1, 0x8c]),
+        Some(&[0xe1, 0x8d]),
+        None,
+        Some(&[0xe1, 0x8e]),
+        None,
+        None,
+        Some(&[0xe1, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x91]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x94]),
+        Some(&[0xe1, 0x92]),
+        Some(&[0xe1, 0x93]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc8]),
+        None,
+        Some(&[0xe1, 0x96]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x95]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x97]),
+        Some(&[0xe1, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x9c]),
+        Some(&[0xe1, 0x99]),
+        Some(&[0xe1, 0x9a]),
+        Some(&[0xe1, 0x9b]),
+        None,
+        Some(&[0xe1, 0x9d]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x9e]),
+        None,
+        Some(&[0xe1, 0x9f]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa0]),
+        None,
+        Some(&[0xe1, 0xa1]),
+        None,
+        Some(&[0x94, 0xad]),
+        Some(&[0x93, 0x6f]),
+        Some(&[0xe1, 0xa2]),
+        Some(&[0x94, 0x92]),
+        Some(&[0x95, 0x53]),
+        None,
+        Some(&[0xe1, 0xa3]),
+        None,
+        None,
+        Some(&[0xe1, 0xa4]),
+        Some(&[0x93, 0x49]),
+        None,
+        Some(&[0x8a, 0x46]),
+        Some(&[0x8d, 0x63]),
+        Some(&[0xe1, 0xa5]),
+        None,
+        None,
+        Some(&[0xe1, 0xa6]),
+        None,
+        None,
+        Some(&[0xe1, 0xa7]),
+        None,
+        Some(&[0x8e, 0x48]),
+        None,
+        None,
+        Some(&[0xe1, 0xa9]),
+        None,
+        None,
+        Some(&[0xe1, 0xa8]),
+        None,
+        None,
+        Some(&[0xe1, 0xaa]),
+        Some(&[0xe1, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X76A8: [Option<&'static [u8]>; 160] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xe7]),
+        None,
+        Some(&[0xe1, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xad]),
+        None,
+        None,
+        Some(&[0xea, 0x89]),
+        Some(&[0xe1, 0xae]),
+        Some(&[0xe1, 0xaf]),
+        Some(&[0xe1, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x4d]),
+        None,
+        None,
+        Some(&[0xe1, 0xb1]),
+        Some(&[0x94, 0x75]),
+        None,
+        None,
+        Some(&[0x96, 0x7e]),
+        None,
+        Some(&[0x89, 0x6d]),
+        None,
+        Some(&[0x89, 0x76]),
+        None,
+        None,
+        Some(&[0xe1, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb4]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb3]),
+        Some(&[0x93, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xb7]),
+        Some(&[0x9f, 0x58]),
+        None,
+        Some(&[0xe1, 0xb5]),
+        Some(&[0x96, 0xbf]),
+        None,
+        Some(&[0xe1, 0xb6]),
+        None,
+        Some(&[0x8a, 0xc4]),
+        Some(&[0x94, 0xd5]),
+        Some(&[0xe1, 0xb7]),
+        None,
+        Some(&[0xe1, 0xb8]),
+        None,
+        None,
+        Some(&[0xe1, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xd3]),
+        None,
+        Some(&[0x92, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x8a]),
+        None,
+        None,
+        Some(&[0xe1, 0xbb]),
+        None,
+        None,
+        Some(&[0x8f, 0x82]),
+        None,
+        None,
+        Some(&[0x8f, 0xc8]),
+        None,
+        None,
+        Some(&[0xe1, 0xbe]),
+        None,
+        None,
+        Some(&[0xe1, 0xbd]),
+        Some(&[0xe1, 0xbc]),
+        Some(&[0x94, 0xfb]),
+        None,
+        Some(&[0x8a, 0xc5]),
+        Some(&[0x8c, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc4]),
+        None,
+        None,
+        Some(&[0xe1, 0xc1]),
+        Some(&[0x90, 0x5e]),
+        Some(&[0x96, 0xb0]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc0]),
+        Some(&[0xe1, 0xc2]),
+        Some(&[0xe1, 0xc3]),
+        None,
+        None,
+        Some(&[0xe1, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc5]),
+        Some(&[0xe1, 0xc6]),
+        None,
+        Some(&[0x92, 0xad]),
+        None,
+        Some(&[0x8a, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x85]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xc7]),
+    ];
+
+    const BASE_0X7758: [Option<&'static [u8]>; 240] = [
+        None,
+        None,
+        Some(&[0xe1, 0xc8]),
+        Some(&[0xe1, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x87]),
+        None,
+        Some(&[0x93, 0xc2]),
+        None,
+        Some(&[0xe1, 0xcc]),
+        Some(&[0x96, 0x72]),
+        None,
+        Some(&[0xe1, 0xc9]),
+        None,
+        None,
+        Some(&[0xe1, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xce]),
+        Some(&[0xe1, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd1]),
+        None,
+        None,
+        Some(&[0xe1, 0xd0]),
+        None,
+        None,
+        Some(&[0xe1, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd4]),
+        None,
+        Some(&[0xe1, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x75]),
+        Some(&[0x97, 0xc4]),
+        None,
+        None,
+        Some(&[0xe1, 0xd5]),
+        None,
+        None,
+        Some(&[0x93, 0xb5]),
+        None,
+        None,
+        Some(&[0xe1, 0xd6]),
+        None,
+        None,
+        Some(&[0xe1, 0xd7]),
+        None,
+        Some(&[0xe1, 0xdb]),
+        Some(&[0xe1, 0xd9]),
+        Some(&[0xe1, 0xda]),
+        None,
+        Some(&[0xe1, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xde]),
+        None,
+        None,
+        Some(&[0xe1, 0xdf]),
+        Some(&[0x96, 0xb5]),
+        Some(&[0xe1, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xee]),
+        Some(&[0xe1, 0xe1]),
+        None,
+        Some(&[0x92, 0x6d]),
+        None,
+        Some(&[0x94, 0x8a]),
+        None,
+        Some(&[0x8b, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x5a]),
+        Some(&[0xe1, 0xe2]),
+        Some(&[0x8b, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe5]),
+        None,
+        Some(&[0x8c, 0xa4]),
+        Some(&[0x8d, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x75]),
+        Some(&[0x8d, 0xd4]),
+        Some(&[0x8b, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x43]),
+        None,
+        Some(&[0x94, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe9]),
+        None,
+        None,
+    ];
+
+    const BASE_0X7858: [Option<&'static [u8]>; 1608] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xb0]),
+        Some(&[0x8d, 0x64]),
+        None,
+        None,
+        Some(&[0x8c, 0xa5]),
+        None,
+        None,
+        Some(&[0x94, 0xa1]),
+        None,
+        Some(&[0xe1, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xec]),
+        Some(&[0x92, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xef]),
+        Some(&[0x8a, 0x56]),
+        Some(&[0xe1, 0xea]),
+        None,
+        None,
+        Some(&[0x94, 0xe8]),
+        None,
+        Some(&[0x89, 0x4f]),
+        None,
+        Some(&[0x8d, 0xea]),
+        None,
+        Some(&[0x98, 0x71]),
+        None,
+        None,
+        Some(&[0xe1, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc9]),
+        None,
+        Some(&[0x90, 0xd7]),
+        Some(&[0xe1, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x6d]),
+        None,
+        Some(&[0xe1, 0xf9]),
+        None,
+        Some(&[0xe1, 0xf8]),
+        None,
+        None,
+        Some(&[0x8e, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xfa]),
+        Some(&[0xe1, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xfb]),
+        Some(&[0xe1, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xd6]),
+        Some(&[0xe1, 0xf4]),
+        None,
+        None,
+        Some(&[0xe1, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x40]),
+        Some(&[0x96, 0x81]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xfc]),
+        None,
+        None,
+        Some(&[0x88, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x62]),
+        None,
+        None,
+        Some(&[0xe2, 0x46]),
+        Some(&[0xe2, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe8]),
+        Some(&[0xe2, 0x49]),
+        Some(&[0xe2, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xa6]),
+        None,
+        Some(&[0x97, 0xe7]),
+        None,
+        Some(&[0x8e, 0xd0]),
+        None,
+        Some(&[0xe2, 0x4a]),
+        Some(&[0x8c, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x5f]),
+        Some(&[0x8b, 0x46]),
+        Some(&[0x8e, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x53]),
+        None,
+        None,
+        Some(&[0xe2, 0x50]),
+        None,
+        Some(&[0xe2, 0x4f]),
+        Some(&[0x91, 0x63]),
+        Some(&[0xe2, 0x4c]),
+        None,
+        None,
+        Some(&[0xe2, 0x4e]),
+        None,
+        None,
+        Some(&[0x8f, 0x6a]),
+        Some(&[0x90, 0x5f]),
+        Some(&[0xe2, 0x4d]),
+        Some(&[0xe2, 0x4b]),
+        None,
+        Some(&[0x94, 0x49]),
+        None,
+        None,
+        Some(&[0x8f, 0xcb]),
+        None,
+        None,
+        Some(&[0x95, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x98]),
+        None,
+        None,
+        Some(&[0xe2, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x52]),
+        Some(&[0xe2, 0x68]),
+        Some(&[0x8b, 0xd6]),
+        None,
+        None,
+        Some(&[0x98, 0x5c]),
+        Some(&[0x91, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x53]),
+        None,
+        None,
+        Some(&[0x89, 0xd0]),
+        Some(&[0x92, 0xf5]),
+        Some(&[0x95, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x9a]),
+        Some(&[0xe2, 0x55]),
+        None,
+        None,
+        Some(&[0xe2, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x58]),
+        None,
+        Some(&[0x94, 0x48]),
+        None,
+        None,
+        Some(&[0xe2, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x5a]),
+        Some(&[0xe2, 0x5b]),
+        None,
+        None,
+        Some(&[0x8b, 0xd7]),
+        Some(&[0x89, 0xd1]),
+        Some(&[0x93, 0xc3]),
+        Some(&[0x8f, 0x47]),
+        Some(&[0x8e, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x5c]),
+        None,
+        Some(&[0x8f, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xc8]),
+        Some(&[0x95, 0x62]),
+        None,
+        None,
+        Some(&[0xe2, 0x5d]),
+        None,
+        None,
+        Some(&[0x94, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x64]),
+        None,
+        Some(&[0xe2, 0x60]),
+        None,
+        Some(&[0xe2, 0x61]),
+        Some(&[0x94, 0x89]),
+        None,
+        Some(&[0x90, 0x60]),
+        Some(&[0xe2, 0x5e]),
+        None,
+        Some(&[0x92, 0x81]),
+        None,
+        None,
+        Some(&[0xe2, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x62]),
+        None,
+        None,
+        Some(&[0x92, 0xf6]),
+        None,
+        Some(&[0xe2, 0x63]),
+        Some(&[0x90, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xab]),
+        None,
+        None,
+        Some(&[0x95, 0x42]),
+        Some(&[0xe2, 0x64]),
+        Some(&[0xe2, 0x65]),
+        Some(&[0x92, 0x74]),
+        None,
+        Some(&[0x97, 0xc5]),
+        None,
+        None,
+        Some(&[0xe2, 0x67]),
+        Some(&[0xe2, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xed]),
+        None,
+        None,
+        Some(&[0xe2, 0x69]),
+        Some(&[0x88, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x6c]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x6a]),
+        Some(&[0x89, 0xd2]),
+        Some(&[0x8c, 0x6d]),
+        Some(&[0xe2, 0x6b]),
+        Some(&[0x8d, 0x65]),
+        Some(&[0x8d, 0x92]),
+        None,
+        Some(&[0x95, 0xe4]),
+        Some(&[0xe2, 0x6d]),
+        None,
+        None,
+        Some(&[0x96, 0x73]),
+        None,
+        None,
+        Some(&[0xe2, 0x6f]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xcf]),
+        Some(&[0x89, 0x6e]),
+        Some(&[0x89, 0xb8]),
+        Some(&[0x88, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x70]),
+        Some(&[0xe2, 0x71]),
+        Some(&[0x8f, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x72]),
+        None,
+        Some(&[0x8a, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x74]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x8a]),
+        None,
+        Some(&[0x8b, 0x86]),
+        None,
+        None,
+        Some(&[0xe2, 0x75]),
+        Some(&[0x8b, 0xf3]),
+        None,
+        None,
+        Some(&[0xe2, 0x76]),
+        None,
+        Some(&[0x90, 0xfa]),
+        None,
+        Some(&[0x93, 0xcb]),
+        None,
+        Some(&[0x90, 0xde]),
+        Some(&[0x8d, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x82]),
+        Some(&[0x91, 0x8b]),
+        None,
+        Some(&[0xe2, 0x79]),
+        Some(&[0xe2, 0x7b]),
+        Some(&[0xe2, 0x78]),
+        Some(&[0xe2, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x7c]),
+        Some(&[0x8c, 0x45]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x87]),
+        Some(&[0x97, 0x71]),
+        Some(&[0xe2, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x80]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x83]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x96]),
+        Some(&[0xe2, 0x82]),
+        Some(&[0xe2, 0x81]),
+        None,
+        Some(&[0xe2, 0x85]),
+        Some(&[0xe2, 0x7d]),
+        None,
+        Some(&[0xe2, 0x86]),
+        Some(&[0x97, 0xa7]),
+        None,
+        Some(&[0xe2, 0x87]),
+        None,
+        Some(&[0xe2, 0x88]),
+        None,
+        None,
+        Some(&[0x9a, 0xf2]),
+        Some(&[0xe2, 0x8a]),
+        None,
+        Some(&[0xe2, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x8b]),
+        Some(&[0xe2, 0x8c]),
+        None,
+        Some(&[0x97, 0xb3]),
+        Some(&[0xe2, 0x8d]),
+        None,
+        Some(&[0xe8, 0xed]),
+        Some(&[0x8f, 0xcd]),
+        Some(&[0xe2, 0x8e]),
+        Some(&[0xe2, 0x8f]),
+        Some(&[0x8f, 0x76]),
+        None,
+        Some(&[0x93, 0xb6]),
+        Some(&[0xe2, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x47]),
+        None,
+        None,
+        Some(&[0xe2, 0x91]),
+        None,
+        Some(&[0x92, 0x5b]),
+        Some(&[0xe2, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xa3]),
+        None,
+        Some(&[0x99, 0x5e]),
+        Some(&[0x92, 0x7c]),
+        Some(&[0x8e, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xc6]),
+        None,
+        None,
+        Some(&[0xe2, 0x93]),
+        None,
+        Some(&[0xe2, 0xa0]),
+        None,
+        Some(&[0xe2, 0x96]),
+        None,
+        Some(&[0x8b, 0x88]),
+        None,
+        Some(&[0xe2, 0x95]),
+        Some(&[0xe2, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x94]),
+        None,
+        Some(&[0x8f, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x98]),
+        Some(&[0xe2, 0x99]),
+        None,
+        Some(&[0x93, 0x4a]),
+        None,
+        None,
+        Some(&[0xe2, 0x9a]),
+        None,
+        Some(&[0x8a, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x79]),
+        Some(&[0x95, 0x84]),
+        None,
+        Some(&[0xe2, 0x9c]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x97]),
+        None,
+        Some(&[0xe2, 0x9b]),
+        Some(&[0xe2, 0x9d]),
+        None,
+        None,
+        Some(&[0x8d, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xa4]),
+        Some(&[0x95, 0x4d]),
+        None,
+        Some(&[0x94, 0xa4]),
+        Some(&[0x93, 0x99]),
+        None,
+        Some(&[0x8b, 0xd8]),
+        Some(&[0xe2, 0xa3]),
+        Some(&[0xe2, 0xa1]),
+        None,
+        Some(&[0x94, 0xb3]),
+        Some(&[0xe2, 0x9e]),
+        Some(&[0x92, 0x7d]),
+        Some(&[0x93, 0x9b]),
+        None,
+        Some(&[0x93, 0x9a]),
+        None,
+        Some(&[0x8d, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xa6]),
+        None,
+        Some(&[0xe2, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xab]),
+        None,
+        Some(&[0xe2, 0xac]),
+        None,
+        Some(&[0xe2, 0xa9]),
+        Some(&[0xe2, 0xaa]),
+        None,
+        None,
+        Some(&[0xe2, 0xa7]),
+        Some(&[0xe2, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xcd]),
+        Some(&[0x89, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xb3]),
+        None,
+        Some(&[0xe2, 0xb0]),
+        None,
+        Some(&[0xe2, 0xb5]),
+        None,
+        None,
+        Some(&[0xe2, 0xb4]),
+        None,
+        Some(&[0x94, 0x93]),
+        Some(&[0x96, 0xa5]),
+        None,
+        Some(&[0x8e, 0x5a]),
+        Some(&[0xe2, 0xae]),
+        Some(&[0xe2, 0xb7]),
+        Some(&[0xe2, 0xb2]),
+        None,
+        Some(&[0xe2, 0xb1]),
+        Some(&[0xe2, 0xad]),
+        None,
+        Some(&[0xe2, 0xaf]),
+        None,
+        Some(&[0x8a, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x5c]),
+        None,
+        None,
+        Some(&[0x90, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xa0]),
+        None,
+        None,
+        Some(&[0xe2, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xdf]),
+        Some(&[0xe2, 0xb9]),
+        None,
+        None,
+        Some(&[0x94, 0xcd]),
+        None,
+        Some(&[0xe2, 0xbd]),
+        Some(&[0x95, 0xd1]),
+        None,
+        Some(&[0x92, 0x7a]),
+        None,
+        Some(&[0xe2, 0xb8]),
+        Some(&[0xe2, 0xba]),
+        None,
+        None,
+        Some(&[0xe2, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xbe]),
+        None,
+        None,
+        Some(&[0x8e, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xc4]),
+        Some(&[0xe2, 0xc3]),
+        Some(&[0xe2, 0xc2]),
+        None,
+        None,
+        Some(&[0xe2, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xc8]),
+        None,
+        None,
+        Some(&[0xe2, 0xcc]),
+        Some(&[0xe2, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xc0]),
+        Some(&[0x99, 0xd3]),
+        Some(&[0xe2, 0xc7]),
+        Some(&[0xe2, 0xc1]),
+        None,
+        None,
+        Some(&[0xe2, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd0]),
+        None,
+        Some(&[0x8a, 0xc8]),
+        None,
+        Some(&[0xe2, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xce]),
+        None,
+        None,
+        Some(&[0xe2, 0xcf]),
+        Some(&[0xe2, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd1]),
+        Some(&[0x94, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd3]),
+        Some(&[0x97, 0xfa]),
+        Some(&[0x95, 0xeb]),
+        Some(&[0xe2, 0xd8]),
+        None,
+        None,
+        Some(&[0xe2, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd4]),
+        Some(&[0x90, 0xd0]),
+        None,
+        Some(&[0xe2, 0xd7]),
+        Some(&[0xe2, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd6]),
+        None,
+        Some(&[0xe2, 0xdd]),
+        None,
+        Some(&[0xe2, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdb]),
+        Some(&[0xe2, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdc]),
+        Some(&[0xe2, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc4]),
+        None,
+        Some(&[0xe2, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xe0]),
+        None,
+        None,
+        Some(&[0x8b, 0xcc]),
+        Some(&[0x8c, 0x48]),
+        Some(&[0xe2, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xb2]),
+        None,
+        Some(&[0x90, 0x88]),
+        None,
+        Some(&[0x96, 0xae]),
+        None,
+        None,
+        Some(&[0xe2, 0xe2]),
+        None,
+        Some(&[0x97, 0xb1]),
+        None,
+        None,
+        Some(&[0x94, 0x94]),
+        None,
+        Some(&[0x91, 0x65]),
+        Some(&[0x94, 0x53]),
+        None,
+        None,
+        Some(&[0x8f, 0x6c]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xbe]),
+        None,
+        Some(&[0xe2, 0xe7]),
+        Some(&[0xe2, 0xe5]),
+        None,
+        Some(&[0xe2, 0xe3]),
+        Some(&[0x8a, 0x9f]),
+        None,
+        Some(&[0x8f, 0xcf]),
+        Some(&[0xe2, 0xe8]),
+        None,
+        None,
+        Some(&[0xe2, 0xe6]),
+        None,
+        Some(&[0xe2, 0xe4]),
+        Some(&[0xe2, 0xec]),
+        None,
+        None,
+        Some(&[0xe2, 0xeb]),
+        Some(&[0xe2, 0xea]),
+        Some(&[0xe2, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xee]),
+        Some(&[0x90, 0xb8]),
+        None,
+        Some(&[0xe2, 0xef]),
+        None,
+        Some(&[0xe2, 0xf1]),
+        None,
+        None,
+        Some(&[0xe2, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf3]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x9c]),
+        None,
+        Some(&[0xe2, 0xf2]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf4]),
+        None,
+        Some(&[0x95, 0xb3]),
+        Some(&[0x91, 0x8c]),
+        Some(&[0x8d, 0x66]),
+        None,
+        Some(&[0xe2, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xc6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf7]),
+        None,
+        None,
+        Some(&[0xe2, 0xf8]),
+        None,
+        Some(&[0xe2, 0xf9]),
+        None,
+        Some(&[0xe2, 0xfa]),
+        None,
+        Some(&[0x8e, 0x85]),
+        None,
+        Some(&[0xe2, 0xfb]),
+        Some(&[0x8c, 0x6e]),
+        None,
+        None,
+        Some(&[0x8b, 0x8a]),
+        None,
+        Some(&[0x8b, 0x49]),
+        None,
+        Some(&[0xe3, 0x40]),
+        None,
+        Some(&[0x96, 0xf1]),
+        Some(&[0x8d, 0x67]),
+        Some(&[0xe2, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x43]),
+        Some(&[0x96, 0xe4]),
+        None,
+        Some(&[0x94, 0x5b]),
+        None,
+        None,
+        Some(&[0x95, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x83]),
+        Some(&[0xe3, 0x42]),
+        None,
+        Some(&[0x8e, 0xd1]),
+        Some(&[0x8d, 0x68]),
+        Some(&[0x8e, 0x86]),
+        Some(&[0x8b, 0x89]),
+        Some(&[0x95, 0xb4]),
+        Some(&[0xe3, 0x41]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x66]),
+        Some(&[0x96, 0x61]),
+        Some(&[0x8d, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x87]),
+        Some(&[0x92, 0xdb]),
+        None,
+        Some(&[0xe3, 0x46]),
+        Some(&[0x97, 0xdd]),
+        Some(&[0x8d, 0xd7]),
+        None,
+        Some(&[0xe3, 0x47]),
+        Some(&[0x90, 0x61]),
+        None,
+        Some(&[0xe3, 0x49]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xd0]),
+        Some(&[0x8d, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x48]),
+        None,
+        None,
+        Some(&[0x8f, 0x49]),
+        Some(&[0x8c, 0xbc]),
+        Some(&[0x91, 0x67]),
+        Some(&[0xe3, 0x44]),
+        Some(&[0xe3, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x45]),
+        Some(&[0x8c, 0x6f]),
+        None,
+        Some(&[0xe3, 0x4d]),
+        Some(&[0xe3, 0x51]),
+        Some(&[0x8c, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x55]),
+        None,
+        None,
+        Some(&[0x8d, 0x69]),
+        None,
+        None,
+        Some(&[0x97, 0x8d]),
+        Some(&[0x88, 0xba]),
+        Some(&[0xe3, 0x52]),
+        None,
+        None,
+        Some(&[0x8b, 0x8b]),
+        None,
+        Some(&[0xe3, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x50]),
+        None,
+        None,
+        Some(&[0x93, 0x9d]),
+        Some(&[0xe3, 0x4e]),
+        Some(&[0xe3, 0x4b]),
+        None,
+        Some(&[0x8a, 0x47]),
+        Some(&[0x90, 0xe2]),
+        None,
+        None,
+        Some(&[0x8c, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x70]),
+        Some(&[0x91, 0xb1]),
+        Some(&[0xe3, 0x58]),
+        Some(&[0x91, 0x8e]),
+        None,
+        None,
+        Some(&[0xe3, 0x65]),
+        None,
+        None,
+        Some(&[0xe3, 0x61]),
+        Some(&[0xe3, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x5f]),
+        Some(&[0x8e, 0xf8]),
+        Some(&[0x88, 0xdb]),
+        Some(&[0xe3, 0x5a]),
+        Some(&[0xe3, 0x62]),
+        Some(&[0xe3, 0x66]),
+        Some(&[0x8d, 0x6a]),
+        Some(&[0x96, 0xd4]),
+        None,
+        Some(&[0x92, 0xd4]),
+        Some(&[0xe3, 0x5c]),
+        None,
+        None,
+        Some(&[0xe3, 0x64]),
+        None,
+        Some(&[0xe3, 0x59]),
+        Some(&[0x92, 0x5d]),
+        None,
+        Some(&[0xe3, 0x5e]),
+        Some(&[0x88, 0xbb]),
+        Some(&[0x96, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x5d]),
+        None,
+        None,
+        Some(&[0x8b, 0xd9]),
+        Some(&[0x94, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x8d]),
+        None,
+        Some(&[0x97, 0xce]),
+        Some(&[0x8f, 0x8f]),
+        None,
+        None,
+        Some(&[0xe3, 0x8e]),
+        None,
+        None,
+        Some(&[0xe3, 0x67]),
+        None,
+        Some(&[0x90, 0xfc]),
+        None,
+        Some(&[0xe3, 0x63]),
+        Some(&[0xe3, 0x68]),
+        Some(&[0xe3, 0x6a]),
+        None,
+        Some(&[0x92, 0xf7]),
+        Some(&[0xe3, 0x6d]),
+        None,
+        None,
+        Some(&[0xe3, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xd2]),
+        Some(&[0x8a, 0xc9]),
+        None,
+        None,
+        Some(&[0x96, 0xc9]),
+        None,
+        None,
+        Some(&[0x88, 0xdc]),
+        None,
+        None,
+        Some(&[0xe3, 0x6c]),
+        None,
+        Some(&[0x97, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x8f]),
+        None,
+        None,
+        Some(&[0x93, 0xea]),
+        Some(&[0xe3, 0x6e]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x75]),
+        Some(&[0xe3, 0x6f]),
+        Some(&[0xe3, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x9b]),
+        None,
+        None,
+        Some(&[0x8e, 0xc8]),
+        Some(&[0xe3, 0x74]),
+        None,
+        Some(&[0xe3, 0x71]),
+        Some(&[0xe3, 0x77]),
+        Some(&[0xe3, 0x70]),
+        None,
+        None,
+        Some(&[0x8f, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x44]),
+        None,
+        None,
+        Some(&[0x8f, 0x6b]),
+        None,
+        None,
+        Some(&[0xe3, 0x73]),
+        Some(&[0xe3, 0x80]),
+        None,
+        None,
+        Some(&[0xe3, 0x7b]),
+        None,
+        Some(&[0xe3, 0x7e]),
+        None,
+        Some(&[0xe3, 0x7c]),
+        Some(&[0xe3, 0x81]),
+        Some(&[0xe3, 0x7a]),
+        None,
+        Some(&[0xe3, 0x60]),
+        Some(&[0x90, 0xd1]),
+        None,
+        None,
+        Some(&[0x94, 0xc9]),
+        None,
+        Some(&[0xe3, 0x7d]),
+        None,
+        None,
+        Some(&[0xe3, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x40]),
+        Some(&[0x8c, 0x71]),
+        None,
+        Some(&[0x8f, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x44]),
+        Some(&[0x91, 0x55]),
+        Some(&[0xe3, 0x84]),
+        None,
+        None,
+        Some(&[0xe3, 0x86]),
+        Some(&[0xe3, 0x87]),
+        None,
+        None,
+        Some(&[0xe3, 0x83]),
+        Some(&[0xe3, 0x85]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x79]),
+        Some(&[0xe3, 0x82]),
+        None,
+        Some(&[0xe3, 0x8a]),
+        Some(&[0xe3, 0x89]),
+        None,
+        None,
+        Some(&[0x96, 0x9a]),
+        None,
+        None,
+        Some(&[0x8c, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x88]),
+        None,
+        Some(&[0xe3, 0x8c]),
+        Some(&[0xe3, 0x8b]),
+        Some(&[0xe3, 0x8f]),
+        None,
+        Some(&[0xe3, 0x91]),
+        None,
+        None,
+        Some(&[0x8e, 0x5b]),
+        Some(&[0xe3, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x92]),
+        Some(&[0xe3, 0x93]),
+        None,
+        None,
+        Some(&[0xe3, 0x94]),
+        None,
+        Some(&[0xe3, 0x9a]),
+        Some(&[0x93, 0x5a]),
+        Some(&[0xe3, 0x96]),
+        None,
+        Some(&[0xe3, 0x95]),
+        Some(&[0xe3, 0x97]),
+        Some(&[0xe3, 0x98]),
+        None,
+        Some(&[0xe3, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x9b]),
+        Some(&[0xe3, 0x9c]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7F30: [Option<&'static [u8]>; 792] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xca]),
+        None,
+        Some(&[0xe3, 0x9d]),
+        None,
+        Some(&[0xe3, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xa0]),
+        Some(&[0xe3, 0xa1]),
+        Some(&[0xe3, 0xa2]),
+        None,
+        Some(&[0xe3, 0xa3]),
+        Some(&[0xe3, 0xa4]),
+        None,
+        None,
+        Some(&[0xe3, 0xa6]),
+        Some(&[0xe3, 0xa5]),
+        None,
+        None,
+        Some(&[0xe3, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xa8]),
+        Some(&[0xe3, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xac]),
+        Some(&[0xe3, 0xaa]),
+        Some(&[0xe3, 0xab]),
+        Some(&[0x8d, 0xdf]),
+        Some(&[0x8c, 0x72]),
+        None,
+        None,
+        Some(&[0x92, 0x75]),
+        None,
+        Some(&[0x94, 0xb1]),
+        None,
+        Some(&[0x8f, 0x90]),
+        None,
+        None,
+        Some(&[0x94, 0x6c]),
+        None,
+        Some(&[0x94, 0xeb]),
+        Some(&[0xe3, 0xad]),
+        Some(&[0x9c, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xae]),
+        Some(&[0xe3, 0xb0]),
+        None,
+        Some(&[0x97, 0x85]),
+        Some(&[0xe3, 0xaf]),
+        Some(&[0xe3, 0xb2]),
+        Some(&[0xe3, 0xb1]),
+        None,
+        Some(&[0x97, 0x72]),
+        None,
+        Some(&[0xe3, 0xb3]),
+        None,
+        Some(&[0x94, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xb7]),
+        None,
+        None,
+        Some(&[0xe3, 0xb6]),
+        Some(&[0xe3, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xb8]),
+        Some(&[0x8c, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x41]),
+        Some(&[0x8b, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xbc]),
+        Some(&[0xe3, 0xb9]),
+        None,
+        None,
+        Some(&[0xe3, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xbd]),
+        None,
+        Some(&[0xe3, 0xbe]),
+        Some(&[0xe3, 0xbb]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x48]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc0]),
+        Some(&[0xe3, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc2]),
+        None,
+        Some(&[0x97, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x4b]),
+        None,
+        Some(&[0xe3, 0xc4]),
+        Some(&[0xe3, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x89]),
+        Some(&[0xe3, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc6]),
+        None,
+        None,
+        Some(&[0xe3, 0xc7]),
+        None,
+        Some(&[0x8a, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xcb]),
+        None,
+        None,
+        Some(&[0xe3, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xc9]),
+        None,
+        Some(&[0x96, 0x7c]),
+        Some(&[0x97, 0x83]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x73]),
+        Some(&[0x98, 0x56]),
+        None,
+        Some(&[0x8d, 0x6c]),
+        Some(&[0xe3, 0xcc]),
+        Some(&[0x8e, 0xd2]),
+        Some(&[0xe3, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xcd]),
+        Some(&[0x8e, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xcf]),
+        None,
+        Some(&[0xe3, 0xce]),
+        None,
+        None,
+        Some(&[0x8d, 0x6b]),
+        None,
+        Some(&[0x96, 0xd5]),
+        Some(&[0xe3, 0xcf]),
+        Some(&[0xe3, 0xd0]),
+        None,
+        None,
+        Some(&[0xe3, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xa8]),
+        None,
+        None,
+        Some(&[0x96, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd5]),
+        None,
+        Some(&[0x92, 0x5e]),
+        None,
+        Some(&[0xe3, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xb9]),
+        None,
+        Some(&[0xe3, 0xd9]),
+        None,
+        Some(&[0xe3, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xb7]),
+        Some(&[0xe3, 0xdb]),
+        None,
+        Some(&[0x91, 0x8f]),
+        Some(&[0xe3, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xfc]),
+        Some(&[0xe3, 0xe0]),
+        None,
+        Some(&[0xe3, 0xdf]),
+        Some(&[0xe3, 0xde]),
+        Some(&[0x92, 0xae]),
+        None,
+        Some(&[0xe3, 0xe1]),
+        Some(&[0x90, 0x45]),
+        None,
+        Some(&[0xe3, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xe3]),
+        Some(&[0x98, 0x57]),
+        Some(&[0xe3, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xe5]),
+        Some(&[0xe3, 0xe7]),
+        Some(&[0xe3, 0xe6]),
+        Some(&[0x94, 0xa3]),
+        None,
+        Some(&[0x93, 0xf7]),
+        None,
+        Some(&[0x98, 0x5d]),
+        Some(&[0x94, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xe9]),
+        None,
+        None,
+        Some(&[0x8f, 0xd1]),
+        None,
+        Some(&[0x95, 0x49]),
+        None,
+        Some(&[0xe3, 0xea]),
+        Some(&[0xe3, 0xe8]),
+        None,
+        Some(&[0x8a, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xd2]),
+        Some(&[0x8e, 0x88]),
+        None,
+        None,
+        Some(&[0x94, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xa8]),
+        Some(&[0x96, 0x62]),
+        None,
+        Some(&[0xe3, 0xed]),
+        Some(&[0xe3, 0xeb]),
+        None,
+        Some(&[0x8d, 0x6d]),
+        None,
+        Some(&[0x8d, 0x6e]),
+        Some(&[0x88, 0xe7]),
+        None,
+        Some(&[0x8d, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xdd]),
+        Some(&[0xe3, 0xf2]),
+        None,
+        Some(&[0x92, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x77]),
+        None,
+        Some(&[0x91, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xf4]),
+        None,
+        None,
+        Some(&[0xe3, 0xf0]),
+        Some(&[0xe3, 0xf3]),
+        Some(&[0xe3, 0xee]),
+        None,
+        Some(&[0xe3, 0xf1]),
+        Some(&[0x96, 0x45]),
+        None,
+        None,
+        Some(&[0x8c, 0xd3]),
+        None,
+        None,
+        Some(&[0x88, 0xfb]),
+        Some(&[0xe3, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xf6]),
+        None,
+        Some(&[0xe3, 0xf7]),
+        None,
+        None,
+        Some(&[0x93, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x45]),
+        Some(&[0x94, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x89]),
+        None,
+        None,
+        Some(&[0x8b, 0xba]),
+        Some(&[0x90, 0xc6]),
+        Some(&[0x98, 0x65]),
+        Some(&[0x96, 0xac]),
+        Some(&[0xe3, 0xf5]),
+        Some(&[0x90, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x72]),
+        Some(&[0xe3, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xfb]),
+        None,
+        Some(&[0x92, 0x45]),
+        None,
+        Some(&[0x94, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xfc]),
+        None,
+        None,
+        Some(&[0x90, 0x74]),
+        None,
+        Some(&[0x95, 0x85]),
+        Some(&[0xe4, 0x44]),
+        None,
+        Some(&[0xe4, 0x43]),
+        Some(&[0x8d, 0x6f]),
+        Some(&[0x98, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x48]),
+        Some(&[0xe4, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xee]),
+        None,
+        None,
+        Some(&[0xe4, 0x47]),
+        None,
+        Some(&[0x8d, 0x98]),
+        Some(&[0xe4, 0x46]),
+        None,
+        None,
+        Some(&[0xe4, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xb0]),
+        Some(&[0x95, 0xa0]),
+        Some(&[0x91, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xda]),
+        Some(&[0xe4, 0x4e]),
+        None,
+        Some(&[0xe4, 0x4f]),
+        Some(&[0xe4, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x4c]),
+        None,
+        Some(&[0xe4, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x55]),
+        None,
+        Some(&[0xe4, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x86]),
+        None,
+        Some(&[0x96, 0x8c]),
+        Some(&[0x95, 0x47]),
+        None,
+        None,
+        Some(&[0xe4, 0x50]),
+        None,
+        None,
+        Some(&[0xe4, 0x53]),
+        Some(&[0xe4, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x63]),
+        Some(&[0xe4, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x57]),
+        None,
+        None,
+        Some(&[0x91, 0x56]),
+        None,
+        Some(&[0xe4, 0x58]),
+        None,
+        None,
+        Some(&[0xe4, 0x5a]),
+        None,
+        Some(&[0xe4, 0x5e]),
+        None,
+        None,
+        Some(&[0xe4, 0x5b]),
+        Some(&[0xe4, 0x59]),
+        Some(&[0x94, 0x5e]),
+        Some(&[0xe4, 0x5c]),
+        None,
+        Some(&[0xe4, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xb0]),
+        None,
+        Some(&[0xe4, 0x64]),
+        Some(&[0xe4, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x60]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x61]),
+        None,
+        Some(&[0x91, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x63]),
+        Some(&[0xe4, 0x62]),
+        Some(&[0xe4, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x66]),
+        Some(&[0xe4, 0x67]),
+        None,
+        None,
+        Some(&[0x90, 0x62]),
+        None,
+        Some(&[0x89, 0xe7]),
+        None,
+        Some(&[0xe4, 0x68]),
+        Some(&[0x97, 0xd5]),
+        None,
+        Some(&[0x8e, 0xa9]),
+        None,
+        None,
+        Some(&[0x8f, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x8a]),
+        Some(&[0x92, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x69]),
+        Some(&[0xe4, 0x6a]),
+        Some(&[0x89, 0x50]),
+        None,
+        Some(&[0xe4, 0x6b]),
+        None,
+        None,
+        Some(&[0xe4, 0x6c]),
+        Some(&[0xe4, 0x6d]),
+        None,
+        None,
+        Some(&[0xe4, 0x6e]),
+        None,
+        Some(&[0xe4, 0x6f]),
+        Some(&[0x8b, 0xbb]),
+        Some(&[0x9d, 0xa8]),
+        Some(&[0xe4, 0x70]),
+        None,
+        Some(&[0x90, 0xe3]),
+        Some(&[0xe4, 0x71]),
+        Some(&[0x8e, 0xc9]),
+        None,
+        Some(&[0xe4, 0x72]),
+        None,
+        Some(&[0x98, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x73]),
+        Some(&[0x95, 0xdc]),
+        Some(&[0x8a, 0xda]),
+        None,
+        None,
+        Some(&[0x91, 0x43]),
+        Some(&[0x8f, 0x77]),
+        None,
+        Some(&[0x95, 0x91]),
+        Some(&[0x8f, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x74]),
+        Some(&[0x8d, 0x71]),
+        Some(&[0xe4, 0x75]),
+        Some(&[0x94, 0xca]),
+        None,
+        Some(&[0xe4, 0x84]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x77]),
+        None,
+        Some(&[0x91, 0xc7]),
+        Some(&[0x94, 0x95]),
+        Some(&[0x8c, 0xbd]),
+        Some(&[0xe4, 0x76]),
+        Some(&[0x91, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xf8]),
+    ];
+
+    const BASE_0X8258: [Option<&'static [u8]>; 264] = [
+        Some(&[0xe4, 0x7a]),
+        Some(&[0xe4, 0x79]),
+        Some(&[0xe4, 0x7c]),
+        None,
+        None,
+        Some(&[0xe4, 0x7b]),
+        None,
+        Some(&[0xe4, 0x7d]),
+        None,
+        None,
+        Some(&[0xe4, 0x80]),
+        None,
+        Some(&[0xe4, 0x7e]),
+        None,
+        Some(&[0x8a, 0xcd]),
+        None,
+        Some(&[0xe4, 0x81]),
+        None,
+        Some(&[0xe4, 0x82]),
+        Some(&[0xe4, 0x83]),
+        None,
+        None,
+        Some(&[0x8d, 0xaf]),
+        Some(&[0x97, 0xc7]),
+        None,
+        Some(&[0xe4, 0x85]),
+        Some(&[0x90, 0x46]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x90]),
+        Some(&[0xe4, 0x86]),
+        Some(&[0xe4, 0x87]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x88]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xf0]),
+        None,
+        Some(&[0xe4, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xc5]),
+        None,
+        Some(&[0xe4, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x48]),
+        Some(&[0x88, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x8b]),
+        Some(&[0xe4, 0x8e]),
+        Some(&[0x94, 0x6d]),
+        None,
+        Some(&[0x90, 0x63]),
+        None,
+        Some(&[0x89, 0xd4]),
+        None,
+        Some(&[0x96, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x7c]),
+        Some(&[0x8b, 0xda]),
+        None,
+        Some(&[0xe4, 0x8d]),
+        None,
+        Some(&[0x89, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x91]),
+        Some(&[0xe4, 0x92]),
+        Some(&[0x97, 0xe8]),
+        Some(&[0x91, 0xdb]),
+        None,
+        None,
+        Some(&[0x95, 0x63]),
+        None,
+        Some(&[0xe4, 0x9e]),
+        None,
+        Some(&[0x89, 0xd5]),
+        Some(&[0xe4, 0x9c]),
+        None,
+        Some(&[0xe4, 0x9a]),
+        Some(&[0xe4, 0x91]),
+        None,
+        Some(&[0xe4, 0x8f]),
+        None,
+        Some(&[0xe4, 0x90]),
+        None,
+        Some(&[0x8e, 0xe1]),
+        Some(&[0x8b, 0xea]),
+        Some(&[0x92, 0x97]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x70]),
+        None,
+        Some(&[0xe4, 0x94]),
+        Some(&[0xe4, 0x93]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x99]),
+        Some(&[0xe4, 0x95]),
+        Some(&[0xe4, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xce]),
+        Some(&[0xe4, 0x97]),
+        Some(&[0x89, 0xd6]),
+        Some(&[0x8a, 0x9d]),
+        Some(&[0xe4, 0x9b]),
+        None,
+        None,
+        Some(&[0xe4, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa1]),
+        Some(&[0xe4, 0xaa]),
+        Some(&[0xe4, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xef]),
+        None,
+        None,
+        Some(&[0xe4, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa8]),
+        None,
+        Some(&[0xe4, 0xa3]),
+        Some(&[0xe4, 0xa2]),
+        None,
+        Some(&[0xe4, 0xa0]),
+        Some(&[0xe4, 0x9f]),
+        Some(&[0x92, 0x83]),
+        None,
+        Some(&[0x91, 0xf9]),
+        Some(&[0xe4, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa7]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x90]),
+        Some(&[0x8c, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x60]),
+        Some(&[0xe4, 0xa6]),
+        None,
+        Some(&[0x8d, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x91]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8370: [Option<&'static [u8]>; 368] = [
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xb8]),
+        None,
+        Some(&[0xe4, 0xb9]),
+        None,
+        Some(&[0x89, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xac]),
+        Some(&[0xe4, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xac]),
+        None,
+        Some(&[0xe4, 0xb4]),
+        None,
+        Some(&[0xe4, 0xbb]),
+        Some(&[0xe4, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x96]),
+        None,
+        None,
+        Some(&[0xe4, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xce]),
+        Some(&[0xe4, 0xaf]),
+        Some(&[0xe4, 0xba]),
+        None,
+        Some(&[0xe4, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xbc]),
+        None,
+        Some(&[0xe4, 0xae]),
+        Some(&[0x94, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x89]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x65]),
+        None,
+        Some(&[0x8b, 0xdb]),
+        None,
+        Some(&[0xe4, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xd9]),
+        None,
+        None,
+        Some(&[0x8f, 0xd2]),
+        None,
+        Some(&[0xe4, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xd8]),
+        None,
+        None,
+        Some(&[0x93, 0x70]),
+        Some(&[0xe4, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xec]),
+        None,
+        Some(&[0xe4, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xd8]),
+        Some(&[0x8c, 0xd4]),
+        Some(&[0x95, 0x48]),
+        Some(&[0xe4, 0xc9]),
+        None,
+        Some(&[0xe4, 0xbd]),
+        None,
+        None,
+        Some(&[0xe4, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd0]),
+        None,
+        Some(&[0xe4, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xc2]),
+        Some(&[0x93, 0xb8]),
+        None,
+        None,
+        Some(&[0xe4, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xc4]),
+        Some(&[0x96, 0x47]),
+        Some(&[0xe4, 0xca]),
+        Some(&[0x88, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xcc]),
+        None,
+        Some(&[0xe4, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x8b]),
+        Some(&[0xe4, 0xd2]),
+        None,
+        Some(&[0xe4, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xe0]),
+        None,
+        None,
+        Some(&[0xe4, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd3]),
+        Some(&[0x97, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xdc]),
+        None,
+        None,
+        Some(&[0x97, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x98]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x92]),
+        Some(&[0xe4, 0xe2]),
+        Some(&[0x93, 0x9f]),
+        None,
+        None,
+        Some(&[0x88, 0xaf]),
+        None,
+        None,
+        Some(&[0xe4, 0xdb]),
+        None,
+        Some(&[0xe4, 0xd7]),
+        Some(&[0x91, 0x92]),
+        Some(&[0xe4, 0xd1]),
+        Some(&[0xe4, 0xd9]),
+        Some(&[0xe4, 0xde]),
+        None,
+        Some(&[0x94, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xa8]),
+        None,
+        Some(&[0xe4, 0xd6]),
+        None,
+        Some(&[0xe4, 0xdf]),
+        Some(&[0x95, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xda]),
+        None,
+        Some(&[0xe4, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x4e]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xd6]),
+        None,
+        None,
+        Some(&[0x95, 0x66]),
+        None,
+        None,
+        Some(&[0xe4, 0xe5]),
+        None,
+        Some(&[0xe4, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xf6]),
+        Some(&[0xe4, 0xe3]),
+        None,
+        Some(&[0xe4, 0xe8]),
+        Some(&[0x91, 0x93]),
+        None,
+        None,
+        Some(&[0xe4, 0xe4]),
+        None,
+        Some(&[0xe4, 0xeb]),
+        None,
+        None,
+        Some(&[0x92, 0x7e]),
+        None,
+        Some(&[0xe4, 0xec]),
+        None,
+        None,
+        Some(&[0x97, 0x75]),
+        Some(&[0xe4, 0xe1]),
+        Some(&[0x8a, 0x57]),
+        None,
+        Some(&[0xe4, 0xe7]),
+        None,
+        None,
+        Some(&[0xe4, 0xea]),
+        Some(&[0x96, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xed]),
+        None,
+        None,
+        Some(&[0xe4, 0xe6]),
+        Some(&[0xe4, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X84E8: [Option<&'static [u8]>; 680] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x48]),
+        None,
+        Some(&[0x98, 0x40]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf8]),
+        None,
+        None,
+        Some(&[0xe4, 0xf0]),
+        Some(&[0x8e, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xcc]),
+        None,
+        Some(&[0x96, 0xa0]),
+        Some(&[0xe4, 0xf7]),
+        Some(&[0xe4, 0xf6]),
+        None,
+        Some(&[0xe4, 0xf2]),
+        Some(&[0xe4, 0xf3]),
+        None,
+        Some(&[0x89, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf5]),
+        None,
+        Some(&[0xe4, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf4]),
+        Some(&[0x88, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xa0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc1]),
+        None,
+        None,
+        Some(&[0xe4, 0xf9]),
+        Some(&[0xe5, 0x40]),
+        None,
+        Some(&[0x94, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xfc]),
+        Some(&[0x8f, 0xd4]),
+        Some(&[0x8e, 0xc7]),
+        Some(&[0xe5, 0x42]),
+        None,
+        None,
+        Some(&[0x8b, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x43]),
+        None,
+        Some(&[0x95, 0x99]),
+        Some(&[0xe4, 0xfb]),
+        None,
+        Some(&[0xe4, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x6e]),
+        Some(&[0x93, 0xa0]),
+        Some(&[0x95, 0x93]),
+        None,
+        None,
+        Some(&[0xe5, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x51]),
+        None,
+        Some(&[0xe5, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x96]),
+        None,
+        None,
+        Some(&[0xe5, 0x4e]),
+        Some(&[0xe5, 0x46]),
+        None,
+        Some(&[0xe5, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x52]),
+        Some(&[0xe5, 0x47]),
+        None,
+        None,
+        Some(&[0xe5, 0x4b]),
+        None,
+        None,
+        Some(&[0x89, 0x92]),
+        None,
+        Some(&[0x93, 0xe3]),
+        None,
+        Some(&[0xe5, 0x4c]),
+        Some(&[0xe5, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x45]),
+        None,
+        Some(&[0x91, 0x45]),
+        None,
+        Some(&[0xe5, 0x49]),
+        Some(&[0x8e, 0x46]),
+        Some(&[0x90, 0x64]),
+        Some(&[0x8c, 0x4f]),
+        Some(&[0x96, 0xf2]),
+        None,
+        Some(&[0x96, 0xf7]),
+        Some(&[0x8f, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x56]),
+        Some(&[0xe5, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x53]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x95]),
+        None,
+        Some(&[0xe5, 0x55]),
+        Some(&[0xe5, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x5b]),
+        Some(&[0xe5, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xa1]),
+        Some(&[0xe5, 0x5a]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xcb]),
+        Some(&[0xe5, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x93]),
+        None,
+        Some(&[0xe5, 0x5c]),
+        Some(&[0xe5, 0x61]),
+        Some(&[0x91, 0x94]),
+        None,
+        None,
+        Some(&[0xe5, 0x60]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x41]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x62]),
+        Some(&[0x91, 0x68]),
+        None,
+        None,
+        Some(&[0xe5, 0x5d]),
+        Some(&[0xe5, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x5e]),
+        None,
+        None,
+        Some(&[0x9f, 0x50]),
+        Some(&[0x9f, 0x41]),
+        None,
+        None,
+        Some(&[0xe5, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x96]),
+        None,
+        Some(&[0xe1, 0xba]),
+        Some(&[0xe5, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x67]),
+        Some(&[0x8c, 0xd5]),
+        None,
+        Some(&[0x8b, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x69]),
+        Some(&[0x99, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x95]),
+        None,
+        Some(&[0x97, 0xb8]),
+        None,
+        Some(&[0x8b, 0xf1]),
+        Some(&[0xe5, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x6b]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x8e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xf8]),
+        None,
+        Some(&[0x88, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xe1]),
+        Some(&[0xe5, 0x71]),
+        Some(&[0xe5, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x6d]),
+        None,
+        Some(&[0x8e, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x6e]),
+        Some(&[0x94, 0x61]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x6f]),
+        Some(&[0xe5, 0x70]),
+        Some(&[0xe5, 0x7a]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x74]),
+        Some(&[0xe5, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x75]),
+        None,
+        Some(&[0xe5, 0x76]),
+        Some(&[0x8e, 0xd6]),
+        None,
+        Some(&[0xe5, 0x78]),
+        None,
+        Some(&[0x92, 0x60]),
+        None,
+        Some(&[0x8c, 0x75]),
+        Some(&[0x8a, 0x61]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x5e]),
+        None,
+        Some(&[0xe5, 0x81]),
+        None,
+        None,
+        Some(&[0xe5, 0x7c]),
+        Some(&[0xe5, 0x80]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x7d]),
+        None,
+        None,
+        Some(&[0xe5, 0x7e]),
+        Some(&[0x95, 0x67]),
+        Some(&[0x94, 0xd8]),
+        Some(&[0xe5, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xfb]),
+        Some(&[0xe5, 0x8c]),
+        None,
+        Some(&[0xe5, 0x88]),
+        None,
+        None,
+        Some(&[0x89, 0xe9]),
+        None,
+        Some(&[0xe5, 0x86]),
+        None,
+        Some(&[0x96, 0x49]),
+        Some(&[0xe5, 0x87]),
+        None,
+        None,
+        Some(&[0xe5, 0x84]),
+        None,
+        Some(&[0xe5, 0x85]),
+        Some(&[0xe5, 0x8a]),
+        Some(&[0xe5, 0x8d]),
+        None,
+        None,
+        Some(&[0xe5, 0x8b]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x89]),
+        Some(&[0xe5, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x77]),
+        None,
+        Some(&[0xe5, 0x94]),
+        None,
+        Some(&[0x96, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x92]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x93]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x8e]),
+        None,
+        None,
+        Some(&[0xe5, 0x90]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x91]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xe4]),
+        None,
+        Some(&[0x98, 0x58]),
+        Some(&[0xe5, 0x98]),
+        None,
+        Some(&[0xe5, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x9f]),
+        None,
+        Some(&[0x90, 0x49]),
+        None,
+        Some(&[0xe5, 0x9b]),
+        None,
+        Some(&[0xe5, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x96]),
+        Some(&[0xe5, 0x95]),
+        None,
+        None,
+        Some(&[0xe5, 0xa0]),
+        None,
+        None,
+        Some(&[0x89, 0xda]),
+        None,
+        Some(&[0xe5, 0x9c]),
+        None,
+        Some(&[0xe5, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x9d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x9a]),
+        None,
+        Some(&[0x92, 0xb1]),
+        None,
+        Some(&[0xe5, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x88]),
+        None,
+        None,
+        Some(&[0xe5, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x5a]),
+        None,
+        None,
+    ];
+
+    const BASE_0X8798: [Option<&'static [u8]>; 560] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa4]),
+        None,
+        None,
+        Some(&[0xe5, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x86]),
+        Some(&[0xe5, 0xb1]),
+        None,
+        Some(&[0xe5, 0xa8]),
+        None,
+        None,
+        Some(&[0xe5, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xad]),
+        None,
+        Some(&[0xe5, 0xb0]),
+        Some(&[0xe5, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xaa]),
+        None,
+        Some(&[0xe5, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb2]),
+        None,
+        None,
+        Some(&[0xe5, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb8]),
+        Some(&[0xe5, 0xb9]),
+        None,
+        Some(&[0x8a, 0x49]),
+        None,
+        Some(&[0x8b, 0x61]),
+        None,
+        None,
+        Some(&[0xe5, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xb6]),
+        Some(&[0xe5, 0xba]),
+        Some(&[0xe5, 0xb5]),
+        None,
+        Some(&[0xe5, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xbe]),
+        Some(&[0xe5, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc0]),
+        Some(&[0xe5, 0xbf]),
+        Some(&[0xe5, 0x79]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc2]),
+        None,
+        None,
+        Some(&[0xe5, 0xc3]),
+        None,
+        Some(&[0xe5, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x8c]),
+        None,
+        Some(&[0xe5, 0xc7]),
+        None,
+        Some(&[0xe5, 0xc6]),
+        None,
+        Some(&[0x8f, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x73]),
+        Some(&[0x9f, 0xa5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xc8]),
+        Some(&[0x8f, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x58]),
+        None,
+        Some(&[0xe5, 0xc9]),
+        None,
+        Some(&[0x89, 0x71]),
+        None,
+        Some(&[0x8f, 0xd5]),
+        Some(&[0xe5, 0xca]),
+        None,
+        None,
+        Some(&[0x8d, 0x74]),
+        Some(&[0xe5, 0xcb]),
+        Some(&[0x88, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x5c]),
+        None,
+        None,
+        Some(&[0xe5, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x8a]),
+        None,
+        Some(&[0xe5, 0xd3]),
+        None,
+        None,
+        Some(&[0xe5, 0xd0]),
+        None,
+        Some(&[0x92, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd1]),
+        Some(&[0xe5, 0xce]),
+        Some(&[0x8b, 0xdc]),
+        None,
+        Some(&[0xe5, 0xcd]),
+        Some(&[0xe5, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x55]),
+        None,
+        None,
+        Some(&[0x91, 0xdc]),
+        None,
+        Some(&[0xe5, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd6]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xb3]),
+        Some(&[0xe5, 0xd5]),
+        None,
+        Some(&[0xe5, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd9]),
+        None,
+        Some(&[0xe5, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xed]),
+        None,
+        None,
+        Some(&[0xe5, 0xd7]),
+        None,
+        Some(&[0xe5, 0xdc]),
+        Some(&[0xe5, 0xde]),
+        None,
+        None,
+        Some(&[0x8c, 0xd1]),
+        Some(&[0xe5, 0xd2]),
+        None,
+        Some(&[0x88, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xdd]),
+        None,
+        Some(&[0x8d, 0xd9]),
+        Some(&[0x97, 0xf4]),
+        Some(&[0xe5, 0xdf]),
+        Some(&[0xe5, 0xe0]),
+        Some(&[0x91, 0x95]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xa0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe1]),
+        Some(&[0x97, 0x54]),
+        None,
+        None,
+        Some(&[0xe5, 0xe2]),
+        Some(&[0xe5, 0xe3]),
+        None,
+        None,
+        Some(&[0x95, 0xe2]),
+        Some(&[0xe5, 0xe4]),
+        None,
+        Some(&[0x8d, 0xbe]),
+        None,
+        Some(&[0x97, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xea]),
+        Some(&[0x8f, 0xd6]),
+        Some(&[0xe5, 0xe8]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x87]),
+        Some(&[0xe5, 0xe5]),
+        None,
+        None,
+        Some(&[0xe5, 0xe7]),
+        Some(&[0x90, 0xbb]),
+        Some(&[0x90, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xe6]),
+        None,
+        Some(&[0xe5, 0xeb]),
+        None,
+        None,
+        Some(&[0x95, 0xa1]),
+        None,
+        None,
+        Some(&[0xe5, 0xed]),
+        None,
+        Some(&[0xe5, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x8c]),
+        None,
+        Some(&[0x96, 0x4a]),
+        Some(&[0xe5, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xfa]),
+        Some(&[0xe5, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf2]),
+        Some(&[0xe5, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf7]),
+        None,
+        Some(&[0xe5, 0xf8]),
+        None,
+        None,
+        Some(&[0xe5, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf4]),
+        None,
+        Some(&[0xe5, 0xef]),
+        Some(&[0xe5, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xf9]),
+        Some(&[0xe8, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xfc]),
+        Some(&[0x8b, 0xdd]),
+        Some(&[0xe5, 0xfb]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x41]),
+        None,
+        Some(&[0xe6, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x43]),
+        None,
+        None,
+        Some(&[0xe6, 0x42]),
+        None,
+        Some(&[0xe6, 0x44]),
+        None,
+        None,
+        Some(&[0x8f, 0x50]),
+        None,
+        Some(&[0xe6, 0x45]),
+        None,
+        None,
+        Some(&[0xe6, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x47]),
+        Some(&[0x90, 0xbc]),
+        None,
+        Some(&[0x97, 0x76]),
+        None,
+        Some(&[0xe6, 0x48]),
+        None,
+        None,
+        Some(&[0x95, 0xa2]),
+        Some(&[0x94, 0x65]),
+        Some(&[0xe6, 0x49]),
+        None,
+        Some(&[0xe6, 0x4a]),
+        Some(&[0x8c, 0xa9]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x4b]),
+        None,
+        None,
+        Some(&[0x8e, 0x8b]),
+        Some(&[0x94, 0x60]),
+        Some(&[0xe6, 0x4c]),
+        None,
+        Some(&[0x8a, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x4f]),
+        Some(&[0x97, 0x97]),
+        None,
+        Some(&[0xe6, 0x4e]),
+        Some(&[0x90, 0x65]),
+        None,
+        Some(&[0xe6, 0x50]),
+        None,
+        None,
+        Some(&[0xe6, 0x51]),
+        None,
+        None,
+        Some(&[0xe6, 0x52]),
+        Some(&[0x8a, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x53]),
+        None,
+        None,
+        Some(&[0xe6, 0x54]),
+        None,
+        Some(&[0xe6, 0x55]),
+        Some(&[0xe6, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X89D0: [Option<&'static [u8]>; 464] = [
+        None,
+        None,
+        Some(&[0x8a, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x57]),
+        None,
+        Some(&[0xe6, 0x58]),
+        Some(&[0xe6, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xf0]),
+        None,
+        None,
+        Some(&[0x90, 0x47]),
+        Some(&[0xe6, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x5b]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xbe]),
+        None,
+        Some(&[0x92, 0xf9]),
+        Some(&[0xe6, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x76]),
+        None,
+        Some(&[0x90, 0x75]),
+        None,
+        Some(&[0xe6, 0x60]),
+        None,
+        Some(&[0x93, 0xa2]),
+        None,
+        Some(&[0xe6, 0x5f]),
+        None,
+        None,
+        Some(&[0x8c, 0x50]),
+        None,
+        None,
+        Some(&[0xe6, 0x5e]),
+        Some(&[0x91, 0xf5]),
+        Some(&[0x8b, 0x4c]),
+        None,
+        None,
+        Some(&[0xe6, 0x61]),
+        None,
+        Some(&[0xe6, 0x62]),
+        None,
+        Some(&[0x8f, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x8d]),
+        None,
+        Some(&[0xe6, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x4b]),
+        None,
+        None,
+        Some(&[0x90, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x96]),
+        None,
+        Some(&[0x96, 0xf3]),
+        Some(&[0x91, 0x69]),
+        None,
+        Some(&[0xe6, 0x64]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x66]),
+        Some(&[0x92, 0x90]),
+        Some(&[0x8f, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x68]),
+        None,
+        Some(&[0xe6, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xbc]),
+        Some(&[0x91, 0xc0]),
+        Some(&[0xe6, 0x67]),
+        None,
+        Some(&[0x8f, 0xd9]),
+        Some(&[0x95, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x66]),
+        None,
+        None,
+        Some(&[0x8e, 0x8c]),
+        None,
+        Some(&[0x89, 0x72]),
+        None,
+        Some(&[0xe6, 0x6d]),
+        Some(&[0x8c, 0x77]),
+        None,
+        None,
+        Some(&[0x8e, 0x8e]),
+        None,
+        None,
+        Some(&[0x8e, 0x8d]),
+        None,
+        Some(&[0x98, 0x6c]),
+        Some(&[0xe6, 0x6c]),
+        Some(&[0xe6, 0x6b]),
+        Some(&[0x91, 0x46]),
+        None,
+        Some(&[0x8b, 0x6c]),
+        Some(&[0x98, 0x62]),
+        Some(&[0x8a, 0x59]),
+        Some(&[0x8f, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x6a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x6f]),
+        None,
+        Some(&[0xe6, 0x70]),
+        Some(&[0xe6, 0x6e]),
+        None,
+        Some(&[0x8c, 0xd6]),
+        None,
+        Some(&[0x97, 0x5f]),
+        None,
+        None,
+        Some(&[0x8e, 0x8f]),
+        Some(&[0x94, 0x46]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x73]),
+        None,
+        Some(&[0x90, 0xbe]),
+        None,
+        Some(&[0x92, 0x61]),
+        None,
+        None,
+        Some(&[0x97, 0x55]),
+        None,
+        Some(&[0xe6, 0x76]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xea]),
+        None,
+        Some(&[0x90, 0xbd]),
+        Some(&[0xe6, 0x72]),
+        None,
+        Some(&[0xe6, 0x77]),
+        Some(&[0x8c, 0xeb]),
+        Some(&[0xe6, 0x74]),
+        Some(&[0xe6, 0x75]),
+        None,
+        Some(&[0xe6, 0x71]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xe0]),
+        Some(&[0x93, 0xc7]),
+        None,
+        None,
+        Some(&[0x92, 0x4e]),
+        None,
+        Some(&[0x89, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xee]),
+        None,
+        None,
+        Some(&[0x8b, 0x62]),
+        None,
+        None,
+        Some(&[0x92, 0xb2]),
+        None,
+        None,
+        Some(&[0xe6, 0x7a]),
+        None,
+        Some(&[0xe6, 0x78]),
+        None,
+        None,
+        Some(&[0x92, 0x6b]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xbf]),
+        Some(&[0x8a, 0xd0]),
+        Some(&[0xe6, 0x79]),
+        None,
+        Some(&[0x90, 0x7a]),
+        None,
+        None,
+        Some(&[0x97, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x7b]),
+        Some(&[0xe6, 0x87]),
+        Some(&[0x92, 0xb3]),
+        None,
+        Some(&[0xe6, 0x86]),
+        None,
+        Some(&[0xe6, 0x83]),
+        Some(&[0xe6, 0x8b]),
+        Some(&[0xe6, 0x84]),
+        None,
+        Some(&[0xe6, 0x80]),
+        None,
+        Some(&[0x92, 0xfa]),
+        Some(&[0xe6, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x7c]),
+        None,
+        Some(&[0x97, 0x40]),
+        Some(&[0x8e, 0x90]),
+        None,
+        None,
+        Some(&[0xe6, 0x81]),
+        None,
+        Some(&[0xe6, 0x7d]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x85]),
+        Some(&[0x8f, 0x94]),
+        None,
+        Some(&[0x8c, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xf8]),
+        None,
+        Some(&[0x96, 0x64]),
+        Some(&[0x89, 0x79]),
+        Some(&[0x88, 0xe0]),
+        None,
+        Some(&[0x93, 0xa3]),
+        None,
+        None,
+        Some(&[0xe6, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x88]),
+        None,
+        Some(&[0x93, 0xe4]),
+        None,
+        Some(&[0xe6, 0x8d]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x82]),
+        None,
+        Some(&[0xe6, 0x8c]),
+        Some(&[0xe6, 0x8e]),
+        None,
+        Some(&[0x8c, 0xaa]),
+        Some(&[0xe6, 0x8a]),
+        Some(&[0x8d, 0x75]),
+        None,
+        Some(&[0x8e, 0xd3]),
+        None,
+        None,
+        Some(&[0xe6, 0x8f]),
+        Some(&[0x97, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x92]),
+        None,
+        Some(&[0xe6, 0x95]),
+        None,
+        None,
+        Some(&[0xe6, 0x93]),
+        Some(&[0x95, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x94]),
+        None,
+        None,
+        Some(&[0xe6, 0x96]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x9a]),
+        None,
+        None,
+        Some(&[0xe6, 0x97]),
+        None,
+        Some(&[0xe6, 0x99]),
+        Some(&[0xe6, 0x98]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x9b]),
+        None,
+        Some(&[0x8e, 0xaf]),
+        None,
+        Some(&[0xe6, 0x9d]),
+        Some(&[0xe6, 0x9c]),
+        Some(&[0x95, 0x88]),
+        None,
+        None,
+        Some(&[0xe6, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0x9e]),
+        Some(&[0xe6, 0xa0]),
+        None,
+        None,
+        Some(&[0xe6, 0xa1]),
+        Some(&[0x8b, 0x63]),
+        Some(&[0xe3, 0xbf]),
+        Some(&[0x8f, 0xf7]),
+        None,
+        Some(&[0xe6, 0xa2]),
+        None,
+        None,
+        Some(&[0x8c, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xa3]),
+        None,
+        None,
+        Some(&[0xe6, 0xa4]),
+        None,
+        None,
+        Some(&[0x8e, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9d, 0xcc]),
+        None,
+        Some(&[0xe6, 0xa5]),
+        None,
+        Some(&[0xe6, 0xa6]),
+        None,
+        Some(&[0x8f, 0x51]),
+        None,
+        Some(&[0xe6, 0xa7]),
+        Some(&[0xe6, 0xa8]),
+        None,
+        None,
+        Some(&[0xe6, 0xa9]),
+        None,
+        None,
+        Some(&[0xe6, 0xaa]),
+        Some(&[0xe6, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8C30: [Option<&'static [u8]>; 232] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x4a]),
+        None,
+        None,
+        Some(&[0xe6, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xae]),
+        None,
+        Some(&[0xe6, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xa4]),
+        None,
+        Some(&[0xe6, 0xaf]),
+        None,
+        Some(&[0x96, 0x4c]),
+        None,
+        Some(&[0xe6, 0xb0]),
+        None,
+        Some(&[0xe6, 0xb1]),
+        None,
+        Some(&[0xe6, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xdb]),
+        Some(&[0xe6, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x8b]),
+        Some(&[0x98, 0xac]),
+        Some(&[0xe6, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb6]),
+        Some(&[0x95, 0x5e]),
+        Some(&[0xe6, 0xb7]),
+        None,
+        Some(&[0xe6, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb8]),
+        None,
+        None,
+        Some(&[0xe6, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xb9]),
+        Some(&[0xe6, 0xbb]),
+        None,
+        Some(&[0x96, 0x65]),
+        Some(&[0xe6, 0xbc]),
+        Some(&[0xe6, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xbe]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x4c]),
+        Some(&[0x92, 0xe5]),
+        None,
+        Some(&[0x95, 0x89]),
+        Some(&[0x8d, 0xe0]),
+        Some(&[0x8d, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x6e]),
+        Some(&[0x89, 0xdd]),
+        Some(&[0x94, 0xcc]),
+        Some(&[0xe6, 0xc3]),
+        Some(&[0x8a, 0xd1]),
+        Some(&[0x90, 0xd3]),
+        Some(&[0xe6, 0xc2]),
+        Some(&[0xe6, 0xc7]),
+        Some(&[0x92, 0x99]),
+        Some(&[0x96, 0xe1]),
+        None,
+        Some(&[0xe6, 0xc5]),
+        Some(&[0xe6, 0xc6]),
+        Some(&[0x8b, 0x4d]),
+        None,
+        Some(&[0xe6, 0xc8]),
+        Some(&[0x94, 0x83]),
+        Some(&[0x91, 0xdd]),
+        None,
+        None,
+        Some(&[0x94, 0xef]),
+        Some(&[0x93, 0x5c]),
+        Some(&[0xe6, 0xc4]),
+        None,
+        Some(&[0x96, 0x66]),
+        Some(&[0x89, 0xea]),
+        Some(&[0xe6, 0xca]),
+        Some(&[0x98, 0x47]),
+        Some(&[0x92, 0xc0]),
+        Some(&[0x98, 0x64]),
+        None,
+        None,
+        Some(&[0x8e, 0x91]),
+        Some(&[0xe6, 0xc9]),
+        None,
+        Some(&[0x91, 0xaf]),
+        None,
+        None,
+        Some(&[0xe6, 0xda]),
+        Some(&[0x91, 0x47]),
+        None,
+        None,
+        Some(&[0x93, 0xf6]),
+        None,
+        Some(&[0x95, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xcd]),
+        Some(&[0x8e, 0x5e]),
+        Some(&[0x8e, 0x92]),
+        None,
+        Some(&[0x8f, 0xdc]),
+        None,
+        Some(&[0x94, 0x85]),
+        None,
+        Some(&[0x8c, 0xab]),
+        Some(&[0xe6, 0xcc]),
+        Some(&[0xe6, 0xcb]),
+        None,
+        Some(&[0x95, 0x8a]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xbf]),
+        None,
+        None,
+        Some(&[0x93, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xcf]),
+        Some(&[0xe6, 0xd0]),
+        Some(&[0x8d, 0x77]),
+        Some(&[0xe6, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd1]),
+        Some(&[0xe6, 0xd2]),
+        None,
+        Some(&[0xe6, 0xd4]),
+        Some(&[0x91, 0xa1]),
+        None,
+        Some(&[0xe6, 0xd3]),
+        Some(&[0x8a, 0xe4]),
+        None,
+        Some(&[0xe6, 0xd6]),
+        None,
+        Some(&[0xe6, 0xd5]),
+        Some(&[0xe6, 0xd7]),
+        None,
+        None,
+        Some(&[0xe6, 0xd9]),
+        Some(&[0xe6, 0xdb]),
+        None,
+        Some(&[0xe6, 0xdc]),
+        None,
+    ];
+
+    const BASE_0X8D60: [Option<&'static [u8]>; 520] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xd4]),
+        None,
+        Some(&[0x8e, 0xcd]),
+        Some(&[0xe6, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x71]),
+        None,
+        Some(&[0xe6, 0xde]),
+        None,
+        None,
+        Some(&[0x91, 0x96]),
+        Some(&[0xe6, 0xdf]),
+        None,
+        Some(&[0xe6, 0xe0]),
+        Some(&[0x95, 0x8b]),
+        None,
+        None,
+        Some(&[0x8b, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x96]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xeb]),
+        Some(&[0xe6, 0xe9]),
+        None,
+        None,
+        Some(&[0xe6, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe8]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe7]),
+        Some(&[0xe6, 0xea]),
+        None,
+        Some(&[0x8b, 0x97]),
+        None,
+        Some(&[0xe6, 0xee]),
+        None,
+        Some(&[0x90, 0xd5]),
+        None,
+        Some(&[0xe6, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xd7]),
+        None,
+        Some(&[0xe6, 0xec]),
+        Some(&[0xe6, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x48]),
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xb5]),
+        None,
+        Some(&[0x91, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xf0]),
+        None,
+        None,
+        Some(&[0xe6, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xf1]),
+        Some(&[0xe6, 0xf2]),
+        Some(&[0x97, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xa5]),
+        Some(&[0xe6, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xf4]),
+        Some(&[0xe6, 0xf5]),
+        Some(&[0xe6, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xfa]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xfb]),
+        Some(&[0xe6, 0xf9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xf8]),
+        None,
+        Some(&[0x92, 0xfb]),
+        None,
+        None,
+        Some(&[0xe7, 0x40]),
+        Some(&[0xe7, 0x44]),
+        Some(&[0xe7, 0x41]),
+        Some(&[0xe6, 0xfc]),
+        None,
+        Some(&[0xe7, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xd6]),
+        Some(&[0xe7, 0x47]),
+        None,
+        None,
+        Some(&[0xe7, 0x49]),
+        Some(&[0xe7, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x4c]),
+        None,
+        Some(&[0x8f, 0x52]),
+        None,
+        Some(&[0xe7, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x4e]),
+        None,
+        None,
+        Some(&[0xe7, 0x51]),
+        Some(&[0xe7, 0x50]),
+        None,
+        Some(&[0xe7, 0x4f]),
+        None,
+        None,
+        Some(&[0xe7, 0x53]),
+        Some(&[0xe7, 0x52]),
+        None,
+        Some(&[0x96, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x55]),
+        None,
+        Some(&[0xe7, 0x54]),
+        Some(&[0xe7, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x58]),
+        Some(&[0x90, 0x67]),
+        Some(&[0xe7, 0x5a]),
+        None,
+        None,
+        Some(&[0x8b, 0xeb]),
+        Some(&[0xe7, 0x5b]),
+        Some(&[0xe7, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x5e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x5f]),
+        Some(&[0xe7, 0x5c]),
+        None,
+        Some(&[0xe7, 0x60]),
+        None,
+        Some(&[0x8e, 0xd4]),
+        Some(&[0xe7, 0x61]),
+        Some(&[0x8b, 0x4f]),
+        Some(&[0x8c, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x62]),
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xee]),
+        None,
+        None,
+        Some(&[0x93, 0x5d]),
+        Some(&[0xe7, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xb2]),
+        None,
+        None,
+        Some(&[0xe7, 0x65]),
+        Some(&[0xe7, 0x64]),
+        Some(&[0x8c, 0x79]),
+        Some(&[0xe7, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x72]),
+        None,
+        Some(&[0xe7, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0xda]),
+        Some(&[0xe7, 0x68]),
+        None,
+        Some(&[0xe7, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x6b]),
+        Some(&[0xe7, 0x6d]),
+        Some(&[0x95, 0xe3]),
+        Some(&[0xe7, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x6c]),
+        None,
+        Some(&[0xe7, 0x70]),
+        Some(&[0xe7, 0x6e]),
+        Some(&[0x8b, 0x50]),
+        None,
+        Some(&[0xe7, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x72]),
+        None,
+        None,
+        Some(&[0x94, 0x79]),
+        Some(&[0x97, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x53]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x41]),
+        Some(&[0xe7, 0x75]),
+        None,
+        Some(&[0xe7, 0x74]),
+        None,
+        None,
+        Some(&[0xe7, 0x78]),
+        Some(&[0x97, 0x60]),
+        None,
+        None,
+        Some(&[0xe7, 0x77]),
+        None,
+        Some(&[0x8a, 0x8d]),
+        Some(&[0xe7, 0x76]),
+        Some(&[0xe7, 0x7b]),
+        None,
+        None,
+        Some(&[0xe7, 0x7a]),
+        None,
+        None,
+        Some(&[0xe7, 0x79]),
+        Some(&[0x93, 0x51]),
+        Some(&[0xe7, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x7e]),
+        None,
+        None,
+        Some(&[0x8d, 0x8c]),
+        None,
+        Some(&[0x8c, 0x44]),
+        Some(&[0xe7, 0x80]),
+        Some(&[0xe7, 0x81]),
+        Some(&[0xe7, 0x82]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8F98: [Option<&'static [u8]>; 256] = [
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x68]),
+        Some(&[0xe7, 0x83]),
+        None,
+        Some(&[0x8e, 0xab]),
+        Some(&[0xe7, 0x84]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x85]),
+        None,
+        None,
+        None,
+        Some(&[0x99, 0x9f]),
+        Some(&[0x99, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x86]),
+        Some(&[0xe3, 0x90]),
+        Some(&[0xe7, 0x87]),
+        Some(&[0x92, 0x43]),
+        Some(&[0x90, 0x4a]),
+        Some(&[0x94, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x88]),
+        None,
+        None,
+        Some(&[0x95, 0xd3]),
+        Some(&[0x92, 0xd2]),
+        Some(&[0x8d, 0x9e]),
+        None,
+        None,
+        Some(&[0x92, 0x48]),
+        None,
+        None,
+        Some(&[0x89, 0x49]),
+        None,
+        Some(&[0x96, 0x98]),
+        Some(&[0x90, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x7d]),
+        None,
+        None,
+        Some(&[0x8b, 0xdf]),
+        None,
+        None,
+        Some(&[0x95, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x89]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x8b]),
+        None,
+        None,
+        Some(&[0xe7, 0x8a]),
+        Some(&[0x89, 0xde]),
+        None,
+        None,
+        Some(&[0x93, 0xf4]),
+        Some(&[0xe7, 0x8c]),
+        Some(&[0x94, 0x97]),
+        None,
+        Some(&[0x93, 0x52]),
+        None,
+        Some(&[0xe7, 0x8d]),
+        Some(&[0x8f, 0x71]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x8f]),
+        None,
+        None,
+        Some(&[0x96, 0xc0]),
+        Some(&[0xe7, 0x9e]),
+        Some(&[0xe7, 0x91]),
+        Some(&[0xe7, 0x92]),
+        None,
+        None,
+        Some(&[0x92, 0xc7]),
+        None,
+        None,
+        Some(&[0x91, 0xde]),
+        Some(&[0x91, 0x97]),
+        None,
+        Some(&[0x93, 0xa6]),
+        None,
+        Some(&[0xe7, 0x90]),
+        Some(&[0x8b, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x99]),
+        None,
+        Some(&[0xe7, 0x96]),
+        Some(&[0xe7, 0xa3]),
+        Some(&[0x93, 0xa7]),
+        Some(&[0x92, 0x80]),
+        Some(&[0xe7, 0x93]),
+        None,
+        Some(&[0x92, 0xfc]),
+        Some(&[0x93, 0x72]),
+        Some(&[0xe7, 0x94]),
+        Some(&[0xe7, 0x98]),
+        Some(&[0x90, 0x80]),
+        None,
+        Some(&[0x94, 0x87]),
+        Some(&[0x92, 0xca]),
+        None,
+        None,
+        Some(&[0x90, 0xc0]),
+        Some(&[0xe7, 0x97]),
+        Some(&[0x91, 0xac]),
+        Some(&[0x91, 0xa2]),
+        Some(&[0xe7, 0x95]),
+        Some(&[0x88, 0xa7]),
+        Some(&[0x98, 0x41]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x9a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xdf]),
+        None,
+        None,
+        Some(&[0x8f, 0x54]),
+        Some(&[0x90, 0x69]),
+        None,
+        None,
+        Some(&[0xe7, 0x9c]),
+        Some(&[0xe7, 0x9b]),
+        None,
+        Some(&[0x88, 0xed]),
+        Some(&[0xe7, 0x9d]),
+        None,
+        None,
+        Some(&[0x95, 0x4e]),
+        None,
+        Some(&[0xe7, 0xa5]),
+        None,
+        None,
+        Some(&[0x93, 0xd9]),
+        Some(&[0x90, 0x8b]),
+        None,
+        None,
+        Some(&[0x92, 0x78]),
+        None,
+        Some(&[0x8b, 0xf6]),
+        None,
+        Some(&[0xe7, 0xa4]),
+        Some(&[0x97, 0x56]),
+        Some(&[0x89, 0x5e]),
+        None,
+        Some(&[0x95, 0xd5]),
+        Some(&[0x89, 0xdf]),
+        Some(&[0xe7, 0x9f]),
+        Some(&[0xe7, 0xa0]),
+        Some(&[0xe7, 0xa1]),
+        Some(&[0xe7, 0xa2]),
+        Some(&[0x93, 0xb9]),
+        Some(&[0x92, 0x42]),
+        Some(&[0x88, 0xe1]),
+        Some(&[0xe7, 0xa6]),
+        None,
+        Some(&[0xe7, 0xa7]),
+        Some(&[0xea, 0xa1]),
+        None,
+        None,
+        Some(&[0x91, 0xbb]),
+        None,
+        Some(&[0xe7, 0xa8]),
+        None,
+        Some(&[0x89, 0x93]),
+        Some(&[0x91, 0x6b]),
+        None,
+        Some(&[0x8c, 0xad]),
+        None,
+        Some(&[0x97, 0x79]),
+        None,
+        None,
+        Some(&[0xe7, 0xa9]),
+        Some(&[0x93, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x98]),
+        Some(&[0x8e, 0xd5]),
+        Some(&[0xe7, 0xaa]),
+        None,
+        None,
+        Some(&[0xe7, 0xad]),
+        None,
+        None,
+        Some(&[0x8f, 0x85]),
+        Some(&[0xe7, 0xab]),
+        Some(&[0x91, 0x4a]),
+        Some(&[0x91, 0x49]),
+        None,
+        Some(&[0x88, 0xe2]),
+        None,
+        Some(&[0x97, 0xc9]),
+        Some(&[0xe7, 0xaf]),
+        None,
+        Some(&[0x94, 0xf0]),
+        Some(&[0xe7, 0xb1]),
+        Some(&[0xe7, 0xb0]),
+        Some(&[0xe7, 0xae]),
+        Some(&[0xe2, 0x84]),
+        Some(&[0x8a, 0xd2]),
+        None,
+        None,
+        Some(&[0xe7, 0x8e]),
+        None,
+        Some(&[0xe7, 0xb3]),
+        Some(&[0xe7, 0xb2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xb4]),
+        None,
+        Some(&[0x97, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X90A0: [Option<&'static [u8]>; 128] = [
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xdf]),
+        None,
+        None,
+        Some(&[0x96, 0x4d]),
+        None,
+        Some(&[0xe7, 0xb5]),
+        None,
+        Some(&[0x8e, 0xd7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xb6]),
+        None,
+        Some(&[0xe7, 0xb7]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xb8]),
+        None,
+        None,
+        Some(&[0x93, 0x40]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x53]),
+        Some(&[0xe7, 0xb9]),
+        None,
+        Some(&[0xe7, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x94]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0x58]),
+        None,
+        Some(&[0x8b, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9128: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x41]),
+        None,
+        None,
+        Some(&[0xe7, 0xc1]),
+        None,
+        Some(&[0xe7, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9148: [Option<&'static [u8]>; 344] = [
+        None,
+        Some(&[0x93, 0xd1]),
+        Some(&[0xe7, 0xc2]),
+        Some(&[0x8f, 0x55]),
+        Some(&[0x8e, 0xde]),
+        Some(&[0x94, 0x7a]),
+        Some(&[0x92, 0x91]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xf0]),
+        None,
+        Some(&[0x90, 0x8c]),
+        None,
+        Some(&[0xe7, 0xc3]),
+        None,
+        Some(&[0xe7, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x7c]),
+        Some(&[0xe7, 0xc5]),
+        None,
+        Some(&[0xe7, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xc7]),
+        Some(&[0x97, 0x8f]),
+        None,
+        Some(&[0x8f, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xc9]),
+        Some(&[0xe7, 0xc8]),
+        None,
+        Some(&[0x8d, 0x79]),
+        None,
+        Some(&[0x8d, 0x93]),
+        Some(&[0x8e, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x86]),
+        None,
+        Some(&[0xe7, 0xcb]),
+        None,
+        Some(&[0xe7, 0xca]),
+        None,
+        Some(&[0x91, 0xe7]),
+        None,
+        None,
+        Some(&[0x8c, 0xed]),
+        None,
+        Some(&[0x90, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xcd]),
+        None,
+        Some(&[0x8f, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xd0]),
+        Some(&[0xe7, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xd2]),
+        Some(&[0xe7, 0xd1]),
+        None,
+        None,
+        Some(&[0x8f, 0xf8]),
+        None,
+        Some(&[0xe7, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xd4]),
+        Some(&[0xe7, 0xd5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xce]),
+        Some(&[0x8d, 0xd1]),
+        Some(&[0x8e, 0xdf]),
+        Some(&[0xe7, 0xd6]),
+        None,
+        Some(&[0xe7, 0xd7]),
+        Some(&[0x97, 0xa2]),
+        Some(&[0x8f, 0x64]),
+        Some(&[0x96, 0xec]),
+        Some(&[0x97, 0xca]),
+        Some(&[0xe7, 0xd8]),
+        Some(&[0x8b, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xd9]),
+        None,
+        Some(&[0x93, 0x42]),
+        None,
+        None,
+        Some(&[0xe7, 0xdc]),
+        Some(&[0x8a, 0x98]),
+        Some(&[0x90, 0x6a]),
+        None,
+        Some(&[0xe7, 0xda]),
+        None,
+        Some(&[0xe7, 0xdb]),
+        None,
+        Some(&[0x92, 0xde]),
+        None,
+        None,
+        Some(&[0x96, 0x74]),
+        Some(&[0x8b, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xde]),
+        Some(&[0xe7, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xdd]),
+        None,
+        None,
+        Some(&[0xe7, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xdd]),
+        Some(&[0x8a, 0x62]),
+        None,
+        None,
+        Some(&[0xe7, 0xe5]),
+        None,
+        None,
+        Some(&[0xe7, 0xe2]),
+        Some(&[0xe7, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x6e]),
+        None,
+        None,
+        Some(&[0xe7, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xe9]),
+        None,
+        None,
+        Some(&[0x8c, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x53]),
+        Some(&[0xe7, 0xe8]),
+        None,
+        None,
+        Some(&[0xe7, 0xeb]),
+        Some(&[0xe7, 0xe9]),
+        None,
+        Some(&[0xe7, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xe7]),
+        None,
+        None,
+        Some(&[0xe7, 0xf4]),
+        Some(&[0x89, 0x94]),
+        None,
+        None,
+        Some(&[0xe7, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xab]),
+        None,
+        Some(&[0xe7, 0xea]),
+        None,
+        Some(&[0x8f, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x67]),
+        None,
+        Some(&[0x8b, 0xe2]),
+        None,
+        None,
+        Some(&[0x8f, 0x65]),
+        None,
+        Some(&[0x93, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x4c]),
+        None,
+        Some(&[0xe7, 0xf2]),
+        None,
+        Some(&[0xe7, 0xec]),
+        Some(&[0xe7, 0xf1]),
+        None,
+        Some(&[0x96, 0xc1]),
+        None,
+        Some(&[0x92, 0xb6]),
+        Some(&[0xe7, 0xf3]),
+        Some(&[0xe7, 0xf0]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X92A8: [Option<&'static [u8]>; 24] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf7]),
+        None,
+        Some(&[0xe7, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X92E0: [Option<&'static [u8]>; 272] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf8]),
+        Some(&[0x95, 0xdd]),
+        None,
+        None,
+        Some(&[0x89, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x65]),
+        Some(&[0x92, 0x92]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x98]),
+        None,
+        Some(&[0xe7, 0xfa]),
+        None,
+        Some(&[0x8d, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xf9]),
+        Some(&[0x90, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x8e]),
+        Some(&[0xe8, 0x40]),
+        Some(&[0xe8, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xf9]),
+        None,
+        Some(&[0xe8, 0x41]),
+        Some(&[0xe8, 0x43]),
+        None,
+        None,
+        Some(&[0x8b, 0xd1]),
+        None,
+        Some(&[0x95, 0x64]),
+        None,
+        None,
+        Some(&[0x8e, 0xe0]),
+        Some(&[0x98, 0x42]),
+        None,
+        Some(&[0xe7, 0xfc]),
+        Some(&[0x8d, 0xf6]),
+        None,
+        None,
+        Some(&[0x98, 0x5e]),
+        None,
+        None,
+        Some(&[0xe8, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x44]),
+        Some(&[0xe8, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xfb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xe7]),
+        None,
+        Some(&[0x93, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xd5]),
+        None,
+        Some(&[0xe8, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x62]),
+        Some(&[0xe8, 0x47]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x4c]),
+        None,
+        Some(&[0xe8, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x49]),
+        None,
+        Some(&[0x8f, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x99]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x4f]),
+        None,
+        Some(&[0x8d, 0xbd]),
+        Some(&[0x91, 0x99]),
+        None,
+        None,
+        Some(&[0x92, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x4d]),
+        Some(&[0xe8, 0x4e]),
+        Some(&[0x92, 0xc1]),
+        None,
+        Some(&[0xe8, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x58]),
+        Some(&[0x93, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x51]),
+        Some(&[0xe8, 0x52]),
+        Some(&[0xe8, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xbe]),
+        None,
+        None,
+        Some(&[0xe8, 0x5a]),
+        Some(&[0xe8, 0x54]),
+        None,
+        None,
+        Some(&[0xe8, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9400: [Option<&'static [u8]>; 136] = [
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x5e]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x60]),
+        None,
+        None,
+        Some(&[0xe8, 0x5d]),
+        Some(&[0xe8, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xe0]),
+        Some(&[0x93, 0xa8]),
+        Some(&[0xe8, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x62]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x63]),
+        Some(&[0xe8, 0x61]),
+        None,
+        Some(&[0x91, 0xf6]),
+        None,
+        Some(&[0xe8, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x66]),
+        None,
+        None,
+        Some(&[0xe8, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xd3]),
+        Some(&[0xe8, 0x67]),
+        Some(&[0x96, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x73]),
+        Some(&[0xe8, 0x69]),
+        None,
+        None,
+        Some(&[0xe8, 0x6c]),
+        None,
+        Some(&[0xe8, 0x6a]),
+        None,
+        Some(&[0xe8, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x6d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x70]),
+        None,
+        Some(&[0xe8, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x74]),
+        Some(&[0xe8, 0x72]),
+        Some(&[0xe8, 0x75]),
+        Some(&[0xe8, 0x77]),
+        None,
+        Some(&[0xe8, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9570: [Option<&'static [u8]>; 120] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xe5]),
+        None,
+        Some(&[0xe8, 0x78]),
+        Some(&[0x91, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x79]),
+        None,
+        Some(&[0x95, 0xc2]),
+        Some(&[0xe8, 0x7a]),
+        Some(&[0x8a, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x5b]),
+        None,
+        Some(&[0x8a, 0xd5]),
+        None,
+        Some(&[0x8a, 0xd4]),
+        Some(&[0xe8, 0x7b]),
+        None,
+        Some(&[0xe8, 0x7c]),
+        None,
+        Some(&[0xe8, 0x7d]),
+        Some(&[0xe8, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x80]),
+        None,
+        Some(&[0x8a, 0xd6]),
+        Some(&[0x8a, 0x74]),
+        Some(&[0x8d, 0x7d]),
+        Some(&[0x94, 0xb4]),
+        None,
+        Some(&[0xe8, 0x82]),
+        Some(&[0xe8, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x83]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x86]),
+        None,
+        Some(&[0xe8, 0x85]),
+        Some(&[0xe8, 0x84]),
+        None,
+        Some(&[0xe8, 0x87]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x8a]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xc5]),
+        None,
+        None,
+        Some(&[0xe8, 0x88]),
+        None,
+        Some(&[0xe8, 0x8c]),
+        Some(&[0xe8, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x8e]),
+        Some(&[0xe8, 0x8d]),
+        Some(&[0xe8, 0x8f]),
+        None,
+        Some(&[0x93, 0xac]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x91]),
+        Some(&[0xe8, 0x93]),
+        None,
+        None,
+        Some(&[0xe8, 0x92]),
+        None,
+        None,
+    ];
+
+    const BASE_0X9618: [Option<&'static [u8]>; 456] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x94]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x95]),
+        None,
+        Some(&[0x8d, 0xe3]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x96]),
+        Some(&[0xe8, 0x97]),
+        None,
+        None,
+        Some(&[0x96, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xa2]),
+        Some(&[0x91, 0xc9]),
+        None,
+        Some(&[0xe8, 0x98]),
+        None,
+        Some(&[0x95, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x9b]),
+        Some(&[0xe8, 0x99]),
+        Some(&[0x8d, 0x7e]),
+        None,
+        Some(&[0xe8, 0x9a]),
+        Some(&[0x8c, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xc3]),
+        Some(&[0xe8, 0x9d]),
+        Some(&[0xe8, 0x9f]),
+        Some(&[0xe8, 0x9e]),
+        Some(&[0xe8, 0xa0]),
+        None,
+        None,
+        Some(&[0x89, 0x40]),
+        Some(&[0x90, 0x77]),
+        Some(&[0x8f, 0x9c]),
+        Some(&[0x8a, 0xd7]),
+        Some(&[0xe8, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x86]),
+        None,
+        Some(&[0xe8, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x41]),
+        None,
+        Some(&[0xe8, 0xa2]),
+        Some(&[0x92, 0xc2]),
+        None,
+        Some(&[0x97, 0xcb]),
+        Some(&[0x93, 0xa9]),
+        Some(&[0xe8, 0x9c]),
+        Some(&[0x97, 0xa4]),
+        None,
+        Some(&[0x8c, 0xaf]),
+        None,
+        None,
+        Some(&[0x97, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xf7]),
+        Some(&[0x97, 0xb2]),
+        None,
+        Some(&[0x8c, 0x47]),
+        None,
+        Some(&[0x91, 0xe0]),
+        Some(&[0xe4, 0x40]),
+        None,
+        Some(&[0xe8, 0xa4]),
+        Some(&[0x8a, 0x4b]),
+        Some(&[0x90, 0x8f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x75]),
+        Some(&[0xe8, 0xa6]),
+        None,
+        Some(&[0xe8, 0xa7]),
+        Some(&[0xe8, 0xa5]),
+        Some(&[0x8c, 0x84]),
+        None,
+        Some(&[0x8d, 0xdb]),
+        Some(&[0x8f, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x42]),
+        None,
+        None,
+        Some(&[0x97, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xa9]),
+        Some(&[0xe7, 0xac]),
+        None,
+        Some(&[0xe8, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xac]),
+        Some(&[0xe8, 0xaa]),
+        Some(&[0xe8, 0xab]),
+        None,
+        Some(&[0xe8, 0xad]),
+        None,
+        Some(&[0xe8, 0xae]),
+        Some(&[0x97, 0xea]),
+        Some(&[0xe8, 0xaf]),
+        Some(&[0xe8, 0xb0]),
+        None,
+        Some(&[0x90, 0xc7]),
+        Some(&[0x94, 0xb9]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x9d]),
+        Some(&[0x8a, 0xe5]),
+        None,
+        None,
+        Some(&[0x97, 0x59]),
+        Some(&[0x89, 0xeb]),
+        Some(&[0x8f, 0x57]),
+        Some(&[0x8c, 0xd9]),
+        None,
+        Some(&[0xe8, 0xb3]),
+        None,
+        Some(&[0xe8, 0xb2]),
+        Some(&[0x8e, 0x93]),
+        Some(&[0xe8, 0xb4]),
+        Some(&[0xe8, 0xb1]),
+        None,
+        None,
+        Some(&[0x8e, 0x47]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xb8]),
+        Some(&[0xe5, 0xab]),
+        None,
+        None,
+        Some(&[0x99, 0xd4]),
+        None,
+        Some(&[0x90, 0x97]),
+        Some(&[0xe8, 0xb6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xa3]),
+        Some(&[0x93, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x4a]),
+        None,
+        Some(&[0x90, 0xe1]),
+        Some(&[0x8e, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0xb5]),
+        None,
+        Some(&[0x89, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xeb]),
+        Some(&[0x97, 0x8b]),
+        None,
+        Some(&[0xe8, 0xb9]),
+        None,
+        Some(&[0x93, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xba]),
+        None,
+        Some(&[0xe8, 0xbb]),
+        Some(&[0x90, 0x6b]),
+        Some(&[0xe8, 0xbc]),
+        None,
+        Some(&[0x97, 0xec]),
+        None,
+        None,
+        Some(&[0xe8, 0xb7]),
+        Some(&[0xe8, 0xbe]),
+        Some(&[0xe8, 0xc0]),
+        None,
+        Some(&[0xe8, 0xbf]),
+        None,
+        Some(&[0xe8, 0xbd]),
+        None,
+        None,
+        Some(&[0xe8, 0xc1]),
+        None,
+        None,
+        Some(&[0xe8, 0xc2]),
+        None,
+        None,
+        Some(&[0x91, 0x9a]),
+        None,
+        Some(&[0x89, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xc3]),
+        None,
+        None,
+        Some(&[0x96, 0xb6]),
+        None,
+        None,
+        Some(&[0xe8, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xc5]),
+        None,
+        Some(&[0x98, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x9e, 0x50]),
+        Some(&[0xe8, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xc7]),
+        Some(&[0xe8, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xcc]),
+        None,
+        Some(&[0xe8, 0xc9]),
+        None,
+        Some(&[0xe8, 0xca]),
+        None,
+        Some(&[0xe8, 0xcb]),
+        Some(&[0xe8, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x90, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0x96, 0xf5]),
+        None,
+        None,
+        Some(&[0x90, 0xc3]),
+        None,
+        None,
+        Some(&[0xe8, 0xce]),
+        None,
+        Some(&[0x94, 0xf1]),
+        None,
+        Some(&[0xe8, 0xcf]),
+        Some(&[0xea, 0x72]),
+        Some(&[0x96, 0xca]),
+        None,
+        Some(&[0xe8, 0xd0]),
+        None,
+        Some(&[0xe8, 0xd1]),
+        None,
+        Some(&[0xe8, 0xd2]),
+        Some(&[0x8a, 0x76]),
+        None,
+        Some(&[0xe8, 0xd4]),
+        None,
+        Some(&[0x90, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd5]),
+        None,
+        None,
+        Some(&[0x8c, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd6]),
+        Some(&[0xe8, 0xda]),
+        None,
+        Some(&[0xe8, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd9]),
+        None,
+        None,
+        Some(&[0x8a, 0x93]),
+        Some(&[0xe8, 0xd7]),
+        Some(&[0xe8, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xdc]),
+        None,
+        Some(&[0x88, 0xc6]),
+        None,
+        Some(&[0xe8, 0xdd]),
+        Some(&[0xe8, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x66]),
+        None,
+        None,
+        Some(&[0xe8, 0xe2]),
+        None,
+        None,
+        Some(&[0xe8, 0xe1]),
+        None,
+        Some(&[0xe8, 0xe0]),
+        None,
+        None,
+        Some(&[0xe6, 0x91]),
+        None,
+        Some(&[0x95, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe3]),
+        Some(&[0xe8, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe5]),
+        None,
+        None,
+        Some(&[0xe8, 0xe6]),
+        None,
+        Some(&[0xe8, 0xe7]),
+        None,
+        None,
+        Some(&[0xe8, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe9]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X97E8: [Option<&'static [u8]>; 144] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xea]),
+        Some(&[0x94, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xec]),
+        Some(&[0x89, 0xb9]),
+        None,
+        Some(&[0xe8, 0xef]),
+        Some(&[0xe8, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0xbf]),
+        None,
+        Some(&[0x95, 0xc5]),
+        Some(&[0x92, 0xb8]),
+        Some(&[0x8d, 0xa0]),
+        None,
+        Some(&[0x8d, 0x80]),
+        Some(&[0x8f, 0x87]),
+        None,
+        Some(&[0x90, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf1]),
+        None,
+        None,
+        Some(&[0xe8, 0xf0]),
+        Some(&[0x97, 0x61]),
+        Some(&[0x8a, 0xe6]),
+        Some(&[0x94, 0xd0]),
+        Some(&[0x93, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x9c]),
+        Some(&[0x97, 0xcc]),
+        None,
+        Some(&[0x8c, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf4]),
+        None,
+        None,
+        Some(&[0xe8, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x6a]),
+        Some(&[0x93, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x6f]),
+        None,
+        None,
+        Some(&[0xe8, 0xf5]),
+        Some(&[0xe8, 0xf2]),
+        None,
+        None,
+        Some(&[0x95, 0x70]),
+        Some(&[0x97, 0x8a]),
+        Some(&[0xe8, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xf9]),
+        Some(&[0x91, 0xe8]),
+        Some(&[0x8a, 0x7a]),
+        Some(&[0x8a, 0x7b]),
+        Some(&[0xe8, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xe7]),
+        Some(&[0x8c, 0xb0]),
+        None,
+        None,
+        Some(&[0x8a, 0xe8]),
+        None,
+        None,
+        Some(&[0x93, 0x5e]),
+        None,
+        None,
+        Some(&[0x97, 0xde]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xda]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xfa]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xfb]),
+        Some(&[0xe8, 0xfc]),
+        Some(&[0xe9, 0x40]),
+        None,
+        Some(&[0xe9, 0x42]),
+        Some(&[0xe9, 0x41]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X98A8: [Option<&'static [u8]>; 32] = [
+        Some(&[0x95, 0x97]),
+        None,
+        Some(&[0xe9, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x44]),
+        None,
+        Some(&[0xe9, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x48]),
+        Some(&[0xe9, 0x47]),
+        None,
+        Some(&[0xe9, 0x49]),
+        None,
+    ];
+
+    const BASE_0X98D8: [Option<&'static [u8]>; 128] = [
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xf2]),
+        Some(&[0xe3, 0xca]),
+        None,
+        None,
+        Some(&[0x90, 0x48]),
+        None,
+        None,
+        Some(&[0x8b, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x4a]),
+        None,
+        Some(&[0xe9, 0x4b]),
+        None,
+        Some(&[0x99, 0xaa]),
+        Some(&[0x9f, 0x5a]),
+        Some(&[0x94, 0xd1]),
+        None,
+        None,
+        Some(&[0x88, 0xf9]),
+        None,
+        Some(&[0x88, 0xb9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x94]),
+        Some(&[0x96, 0x4f]),
+        Some(&[0x8f, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x4c]),
+        None,
+        Some(&[0x96, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x4d]),
+        Some(&[0x97, 0x7b]),
+        None,
+        Some(&[0x89, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0x60]),
+        None,
+        Some(&[0xe9, 0x4e]),
+        Some(&[0x89, 0xec]),
+        Some(&[0xe9, 0x4f]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x52]),
+        Some(&[0xe9, 0x53]),
+        None,
+        Some(&[0xe9, 0x55]),
+        Some(&[0xe9, 0x51]),
+        None,
+        None,
+        Some(&[0xe9, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x56]),
+        None,
+        Some(&[0xe9, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x58]),
+        Some(&[0xe9, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x5a]),
+        None,
+        None,
+        Some(&[0xe9, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x5b]),
+        None,
+        Some(&[0xe9, 0x5e]),
+        Some(&[0xe9, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x5d]),
+        Some(&[0xe9, 0x5f]),
+        Some(&[0xe9, 0x60]),
+        None,
+        None,
+        Some(&[0xe9, 0x62]),
+        None,
+        Some(&[0x8b, 0xc0]),
+    ];
+
+    const BASE_0X9990: [Option<&'static [u8]>; 224] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8e, 0xf1]),
+        Some(&[0xe9, 0x63]),
+        Some(&[0xe9, 0x64]),
+        Some(&[0x8d, 0x81]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x65]),
+        None,
+        None,
+        Some(&[0x8a, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x6e]),
+        Some(&[0xe9, 0x66]),
+        Some(&[0xe9, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x79]),
+        Some(&[0x93, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x94, 0x9d]),
+        None,
+        None,
+        Some(&[0x91, 0xca]),
+        Some(&[0x89, 0x77]),
+        Some(&[0x8b, 0xec]),
+        None,
+        Some(&[0x8b, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0x93]),
+        Some(&[0xe9, 0x6d]),
+        Some(&[0x8b, 0xee]),
+        None,
+        None,
+        Some(&[0x89, 0xed]),
+        None,
+        None,
+        Some(&[0xe9, 0x6c]),
+        None,
+        None,
+        Some(&[0xe9, 0x6a]),
+        None,
+        Some(&[0xe9, 0x6b]),
+        None,
+        Some(&[0xe9, 0x69]),
+        None,
+        None,
+        Some(&[0xe9, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x6e]),
+        Some(&[0xe9, 0x6f]),
+        None,
+        None,
+        Some(&[0xe9, 0x70]),
+        Some(&[0xe9, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x73]),
+        None,
+        None,
+        Some(&[0xe9, 0x72]),
+        None,
+        None,
+        None,
+        Some(&[0x8f, 0x78]),
+        None,
+        Some(&[0xe9, 0x74]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8b, 0x52]),
+        Some(&[0xe9, 0x75]),
+        None,
+        None,
+        Some(&[0x91, 0x9b]),
+        Some(&[0x8c, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xcb]),
+        None,
+        None,
+        Some(&[0xe9, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x80]),
+        None,
+        Some(&[0xe9, 0x7d]),
+        None,
+        Some(&[0xe9, 0x7c]),
+        Some(&[0xe9, 0x7e]),
+        None,
+        Some(&[0xe9, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x81]),
+        None,
+        Some(&[0xe9, 0x84]),
+        None,
+        None,
+        Some(&[0x8b, 0xc1]),
+        Some(&[0xe9, 0x83]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x85]),
+        None,
+        None,
+        Some(&[0xe9, 0x86]),
+        None,
+        Some(&[0xe9, 0x88]),
+        Some(&[0xe9, 0x87]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x89]),
+        Some(&[0xe9, 0x8b]),
+        Some(&[0xe9, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9AA8: [Option<&'static [u8]>; 96] = [
+        Some(&[0x8d, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x8c]),
+        None,
+        None,
+        Some(&[0xe9, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x5b]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x8e]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x8f]),
+        None,
+        None,
+        None,
+        Some(&[0x90, 0x91]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x90]),
+        None,
+        Some(&[0xe9, 0x91]),
+        None,
+        Some(&[0xe9, 0x92]),
+        Some(&[0xe9, 0x93]),
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x82]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x94]),
+        Some(&[0xe9, 0x95]),
+        None,
+        None,
+        Some(&[0xe9, 0x96]),
+        Some(&[0xe9, 0x97]),
+        None,
+        None,
+        Some(&[0xe9, 0x98]),
+        None,
+        None,
+        None,
+        Some(&[0x94, 0xaf]),
+        Some(&[0xe9, 0x9a]),
+        None,
+        Some(&[0x95, 0x45]),
+        Some(&[0xe9, 0x9b]),
+        Some(&[0xe9, 0x99]),
+        None,
+        Some(&[0xe9, 0x9d]),
+        None,
+        None,
+        Some(&[0xe9, 0x9c]),
+        None,
+        None,
+        Some(&[0xe9, 0x9e]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x9f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa0]),
+        None,
+    ];
+
+    const BASE_0X9B18: [Option<&'static [u8]>; 72] = [
+        Some(&[0xe9, 0xa1]),
+        None,
+        Some(&[0xe9, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xa3]),
+        None,
+        None,
+        Some(&[0xe9, 0xa4]),
+        Some(&[0xe9, 0xa5]),
+        None,
+        Some(&[0xe9, 0xa6]),
+        None,
+        Some(&[0xe9, 0xa7]),
+        Some(&[0xe9, 0xa8]),
+        Some(&[0xe9, 0xa9]),
+        Some(&[0xe9, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xab]),
+        Some(&[0xe9, 0xac]),
+        None,
+        Some(&[0x9f, 0x54]),
+        Some(&[0xe9, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf6]),
+        Some(&[0x8b, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x40]),
+        Some(&[0x8d, 0xb0]),
+        Some(&[0xe9, 0xaf]),
+        Some(&[0xe9, 0xae]),
+        Some(&[0x96, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xb1]),
+        Some(&[0xe9, 0xb2]),
+        Some(&[0xe9, 0xb0]),
+        None,
+        Some(&[0xe9, 0xb3]),
+        None,
+        None,
+        Some(&[0x96, 0x82]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xb4]),
+        None,
+        Some(&[0x8b, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9B68: [Option<&'static [u8]>; 280] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x98, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x88, 0xbc]),
+        None,
+        None,
+        Some(&[0xe9, 0xb8]),
+        Some(&[0x95, 0xa9]),
+        Some(&[0xe9, 0xb6]),
+        None,
+        None,
+        Some(&[0xe9, 0xb9]),
+        Some(&[0xe9, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbb]),
+        Some(&[0xe9, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbd]),
+        None,
+        Some(&[0x96, 0x8e]),
+        Some(&[0x8e, 0x4c]),
+        None,
+        Some(&[0x8d, 0xf8]),
+        Some(&[0x91, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc2]),
+        None,
+        None,
+        Some(&[0x8c, 0xef]),
+        Some(&[0xe9, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc3]),
+        None,
+        Some(&[0xe9, 0xc4]),
+        Some(&[0xe9, 0xc5]),
+        None,
+        Some(&[0xe9, 0xc9]),
+        None,
+        Some(&[0x8e, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xca]),
+        Some(&[0xe9, 0xc7]),
+        Some(&[0xe9, 0xc6]),
+        Some(&[0xe9, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xce]),
+        Some(&[0xe9, 0xcd]),
+        Some(&[0xe9, 0xcc]),
+        None,
+        None,
+        Some(&[0x88, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xd8]),
+        None,
+        Some(&[0xe9, 0xd4]),
+        None,
+        Some(&[0xe9, 0xd5]),
+        Some(&[0xe9, 0xd1]),
+        Some(&[0xe9, 0xd7]),
+        None,
+        Some(&[0xe9, 0xd3]),
+        Some(&[0x8a, 0x82]),
+        None,
+        None,
+        Some(&[0x98, 0x6b]),
+        None,
+        Some(&[0xe9, 0xd6]),
+        Some(&[0xe9, 0xd2]),
+        Some(&[0xe9, 0xd0]),
+        Some(&[0xe9, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xdd]),
+        None,
+        None,
+        Some(&[0xe9, 0xdc]),
+        Some(&[0xe9, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x68]),
+        Some(&[0xe9, 0xd9]),
+        Some(&[0x88, 0xf1]),
+        Some(&[0xe9, 0xde]),
+        None,
+        Some(&[0xe9, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8a, 0x8f]),
+        Some(&[0xe9, 0xcb]),
+        Some(&[0x89, 0x56]),
+        None,
+        None,
+        Some(&[0xe9, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe1]),
+        Some(&[0xe9, 0xdf]),
+        Some(&[0x92, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xd8]),
+        None,
+        None,
+        Some(&[0xe9, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xe6]),
+        None,
+        Some(&[0xe9, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9CE0: [Option<&'static [u8]>; 288] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x92, 0xb9]),
+        None,
+        Some(&[0xe9, 0xe8]),
+        None,
+        Some(&[0x94, 0xb5]),
+        None,
+        Some(&[0xe9, 0xed]),
+        Some(&[0xe9, 0xe9]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xea]),
+        None,
+        None,
+        Some(&[0x96, 0x50]),
+        Some(&[0x96, 0xc2]),
+        None,
+        Some(&[0x93, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xee]),
+        None,
+        None,
+        Some(&[0xe9, 0xef]),
+        Some(&[0x93, 0xbc]),
+        Some(&[0xe9, 0xec]),
+        Some(&[0xe9, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xf7]),
+        None,
+        None,
+        Some(&[0xe9, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0x95]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xf3]),
+        None,
+        None,
+        Some(&[0xe9, 0xf1]),
+        None,
+        Some(&[0x8a, 0x9b]),
+        None,
+        Some(&[0xe9, 0xf0]),
+        Some(&[0x8e, 0xb0]),
+        Some(&[0x89, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8d, 0x83]),
+        None,
+        None,
+        Some(&[0xe9, 0xfa]),
+        Some(&[0xe9, 0xf9]),
+        None,
+        Some(&[0xe9, 0xf8]),
+        None,
+        None,
+        Some(&[0xe9, 0xf5]),
+        None,
+        Some(&[0xe9, 0xfb]),
+        None,
+        Some(&[0xe9, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x44]),
+        Some(&[0xea, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x45]),
+        None,
+        None,
+        Some(&[0x89, 0x4c]),
+        Some(&[0xea, 0x40]),
+        Some(&[0xea, 0x41]),
+        None,
+        Some(&[0x8d, 0x94]),
+        Some(&[0x96, 0xb7]),
+        None,
+        None,
+        Some(&[0xea, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x96, 0x51]),
+        None,
+        None,
+        Some(&[0xea, 0x4a]),
+        None,
+        None,
+        Some(&[0xea, 0x46]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x48]),
+        None,
+        Some(&[0xea, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x4e]),
+        None,
+        Some(&[0xea, 0x49]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xf2]),
+        None,
+        None,
+        Some(&[0xea, 0x4f]),
+        None,
+        Some(&[0x92, 0xdf]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x53]),
+        None,
+        Some(&[0xea, 0x54]),
+        Some(&[0xea, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x51]),
+        Some(&[0xea, 0x57]),
+        None,
+        Some(&[0xea, 0x50]),
+        None,
+        Some(&[0xea, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x5c]),
+        None,
+        Some(&[0xea, 0x5d]),
+        None,
+        None,
+        Some(&[0x98, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x5a]),
+        Some(&[0x91, 0xe9]),
+        Some(&[0x8d, 0xeb]),
+        None,
+        None,
+        Some(&[0xea, 0x5e]),
+        None,
+        None,
+    ];
+
+    const BASE_0X9E18: [Option<&'static [u8]>; 8] = [
+        None,
+        None,
+        Some(&[0xea, 0x5f]),
+        Some(&[0xea, 0x60]),
+        None,
+        None,
+        Some(&[0xea, 0x61]),
+        None,
+    ];
+
+    const BASE_0X9E70: [Option<&'static [u8]>; 264] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x62]),
+        None,
+        None,
+        Some(&[0x8c, 0xb2]),
+        Some(&[0xea, 0x63]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x64]),
+        None,
+        Some(&[0x8e, 0xad]),
+        None,
+        Some(&[0xea, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x66]),
+        None,
+        None,
+        Some(&[0xea, 0x67]),
+        Some(&[0xea, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x6b]),
+        Some(&[0xea, 0x69]),
+        Some(&[0x98, 0x5b]),
+        None,
+        Some(&[0xea, 0x6a]),
+        None,
+        Some(&[0x97, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x6c]),
+        None,
+        Some(&[0x97, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x6d]),
+        Some(&[0x94, 0x9e]),
+        None,
+        None,
+        Some(&[0xea, 0x6e]),
+        Some(&[0xea, 0x70]),
+        None,
+        None,
+        Some(&[0xea, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x6f]),
+        Some(&[0x8d, 0x8d]),
+        Some(&[0x96, 0xcb]),
+        Some(&[0x96, 0x83]),
+        Some(&[0x9b, 0xf5]),
+        None,
+        Some(&[0x9f, 0x80]),
+        Some(&[0x96, 0x9b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x89, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x73]),
+        Some(&[0x8b, 0x6f]),
+        Some(&[0xea, 0x74]),
+        Some(&[0xea, 0x75]),
+        Some(&[0xea, 0x76]),
+        None,
+        Some(&[0x8d, 0x95]),
+        None,
+        Some(&[0xea, 0x77]),
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xd2]),
+        Some(&[0x96, 0xd9]),
+        None,
+        Some(&[0x91, 0xe1]),
+        Some(&[0xea, 0x78]),
+        Some(&[0xea, 0x7a]),
+        Some(&[0xea, 0x79]),
+        None,
+        Some(&[0xea, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x7c]),
+        None,
+        None,
+        Some(&[0xea, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x80]),
+        None,
+        Some(&[0xea, 0x81]),
+        Some(&[0xea, 0x82]),
+        None,
+        Some(&[0xea, 0x83]),
+        None,
+        Some(&[0xea, 0x84]),
+        Some(&[0xea, 0x85]),
+        Some(&[0xea, 0x86]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x87]),
+        Some(&[0xea, 0x88]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x93, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x8c, 0xdb]),
+        None,
+        Some(&[0xea, 0x8a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x91, 0x6c]),
+        Some(&[0xea, 0x8b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x8c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x95, 0x40]),
+        None,
+        None,
+        Some(&[0xea, 0x8d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x8e]),
+        Some(&[0xe2, 0x56]),
+        None,
+        None,
+        Some(&[0xe6, 0xd8]),
+        Some(&[0xe8, 0xeb]),
+        None,
+        None,
+        Some(&[0xea, 0x8f]),
+        None,
+        Some(&[0xea, 0x90]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x92]),
+        Some(&[0xea, 0x93]),
+        Some(&[0xea, 0x94]),
+        Some(&[0x97, 0xee]),
+        Some(&[0xea, 0x91]),
+        None,
+        None,
+        Some(&[0xea, 0x95]),
+        Some(&[0xea, 0x96]),
+        None,
+        None,
+        Some(&[0xea, 0x98]),
+        None,
+        Some(&[0xea, 0x97]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x9a]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x9b]),
+        Some(&[0xea, 0x99]),
+    ];
+
+    const BASE_0X9F88: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0x97, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x9c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x9d]),
+        Some(&[0xe2, 0x73]),
+        None,
+        None,
+        Some(&[0xea, 0x9e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFE30: [Option<&'static [u8]>; 24] = [
+        Some(&[0xeb, 0x64]),
+        Some(&[0xeb, 0x5c]),
+        None,
+        Some(&[0xeb, 0x51]),
+        None,
+        Some(&[0xeb, 0x69]),
+        Some(&[0xeb, 0x6a]),
+        Some(&[0xeb, 0x6f]),
+        Some(&[0xeb, 0x70]),
+        Some(&[0xeb, 0x6b]),
+        Some(&[0xeb, 0x6c]),
+        Some(&[0xeb, 0x79]),
+        Some(&[0xeb, 0x7a]),
+        Some(&[0xeb, 0x73]),
+        Some(&[0xeb, 0x74]),
+        Some(&[0xeb, 0x71]),
+        Some(&[0xeb, 0x72]),
+        Some(&[0xeb, 0x75]),
+        Some(&[0xeb, 0x76]),
+        Some(&[0xeb, 0x77]),
+        Some(&[0xeb, 0x78]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFF00: [Option<&'static [u8]>; 160] = [
+        None,
+        Some(&[0x81, 0x49]),
+        None,
+        Some(&[0x81, 0x94]),
+        Some(&[0x81, 0x90]),
+        Some(&[0x81, 0x93]),
+        Some(&[0x81, 0x95]),
+        None,
+        Some(&[0x81, 0x69]),
+        Some(&[0x81, 0x6a]),
+        Some(&[0x81, 0x96]),
+        Some(&[0x81, 0x7b]),
+        Some(&[0x81, 0x43]),
+        None,
+        Some(&[0x81, 0x44]),
+        Some(&[0x81, 0x5e]),
+        Some(&[0x82, 0x4f]),
+        Some(&[0x82, 0x50]),
+        Some(&[0x82, 0x51]),
+        Some(&[0x82, 0x52]),
+        Some(&[0x82, 0x53]),
+        Some(&[0x82, 0x54]),
+        Some(&[0x82, 0x55]),
+        Some(&[0x82, 0x56]),
+        Some(&[0x82, 0x57]),
+        Some(&[0x82, 0x58]),
+        Some(&[0x81, 0x46]),
+        Some(&[0x81, 0x47]),
+        Some(&[0x81, 0x83]),
+        Some(&[0x81, 0x81]),
+        Some(&[0x81, 0x84]),
+        Some(&[0x81, 0x48]),
+        Some(&[0x81, 0x97]),
+        Some(&[0x82, 0x60]),
+        Some(&[0x82, 0x61]),
+        Some(&[0x82, 0x62]),
+        Some(&[0x82, 0x63]),
+        Some(&[0x82, 0x64]),
+        Some(&[0x82, 0x65]),
+        Some(&[0x82, 0x66]),
+        Some(&[0x82, 0x67]),
+        Some(&[0x82, 0x68]),
+        Some(&[0x82, 0x69]),
+        Some(&[0x82, 0x6a]),
+        Some(&[0x82, 0x6b]),
+        Some(&[0x82, 0x6c]),
+        Some(&[0x82, 0x6d]),
+        Some(&[0x82, 0x6e]),
+        Some(&[0x82, 0x6f]),
+        Some(&[0x82, 0x70]),
+        Some(&[0x82, 0x71]),
+        Some(&[0x82, 0x72]),
+        Some(&[0x82, 0x73]),
+        Some(&[0x82, 0x74]),
+        Some(&[0x82, 0x75]),
+        Some(&[0x82, 0x76]),
+        Some(&[0x82, 0x77]),
+        Some(&[0x82, 0x78]),
+        Some(&[0x82, 0x79]),
+        Some(&[0x81, 0x6d]),
+        Some(&[0x81, 0x5f]),
+        Some(&[0x81, 0x6e]),
+        Some(&[0x81, 0x4f]),
+        Some(&[0x81, 0x51]),
+        Some(&[0x81, 0x4d]),
+        Some(&[0x82, 0x81]),
+        Some(&[0x82, 0x82]),
+        Some(&[0x82, 0x83]),
+        Some(&[0x82, 0x84]),
+        Some(&[0x82, 0x85]),
+        Some(&[0x82, 0x86]),
+        Some(&[0x82, 0x87]),
+        Some(&[0x82, 0x88]),
+        Some(&[0x82, 0x89]),
+        Some(&[0x82, 0x8a]),
+        Some(&[0x82, 0x8b]),
+        Some(&[0x82, 0x8c]),
+        Some(&[0x82, 0x8d]),
+        Some(&[0x82, 0x8e]),
+        Some(&[0x82, 0x8f]),
+        Some(&[0x82, 0x90]),
+        Some(&[0x82, 0x91]),
+        Some(&[0x82, 0x92]),
+        Some(&[0x82, 0x93]),
+        Some(&[0x82, 0x94]),
+        Some(&[0x82, 0x95]),
+        Some(&[0x82, 0x96]),
+        Some(&[0x82, 0x97]),
+        Some(&[0x82, 0x98]),
+        Some(&[0x82, 0x99]),
+        Some(&[0x82, 0x9a]),
+        Some(&[0x81, 0x6f]),
+        Some(&[0x81, 0x62]),
+        Some(&[0x81, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xa1]),
+        Some(&[0xa2]),
+        Some(&[0xa3]),
+        Some(&[0xa4]),
+        Some(&[0xa5]),
+        Some(&[0xa6]),
+        Some(&[0xa7]),
+        Some(&[0xa8]),
+        Some(&[0xa9]),
+        Some(&[0xaa]),
+        Some(&[0xab]),
+        Some(&[0xac]),
+        Some(&[0xad]),
+        Some(&[0xae]),
+        Some(&[0xaf]),
+        Some(&[0xb0]),
+        Some(&[0xb1]),
+        Some(&[0xb2]),
+        Some(&[0xb3]),
+        Some(&[0xb4]),
+        Some(&[0xb5]),
+        Some(&[0xb6]),
+        Some(&[0xb7]),
+        Some(&[0xb8]),
+        Some(&[0xb9]),
+        Some(&[0xba]),
+        Some(&[0xbb]),
+        Some(&[0xbc]),
+        Some(&[0xbd]),
+        Some(&[0xbe]),
+        Some(&[0xbf]),
+        Some(&[0xc0]),
+        Some(&[0xc1]),
+        Some(&[0xc2]),
+        Some(&[0xc3]),
+        Some(&[0xc4]),
+        Some(&[0xc5]),
+        Some(&[0xc6]),
+        Some(&[0xc7]),
+        Some(&[0xc8]),
+        Some(&[0xc9]),
+        Some(&[0xca]),
+        Some(&[0xcb]),
+        Some(&[0xcc]),
+        Some(&[0xcd]),
+        Some(&[0xce]),
+        Some(&[0xcf]),
+        Some(&[0xd0]),
+        Some(&[0xd1]),
+        Some(&[0xd2]),
+        Some(&[0xd3]),
+        Some(&[0xd4]),
+        Some(&[0xd5]),
+        Some(&[0xd6]),
+        Some(&[0xd7]),
+        Some(&[0xd8]),
+        Some(&[0xd9]),
+        Some(&[0xda]),
+        Some(&[0xdb]),
+        Some(&[0xdc]),
+        Some(&[0xdd]),
+        Some(&[0xde]),
+        Some(&[0xdf]),
+    ];
+
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderMacJapanese<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                match *code_point {
+                    0x0000..=0x005b | 0x005d..=0x007f => Some(Ok(vec![*code_point as u8])),
+                    0x00a0..0x00b8 => {
+                        match Self::BASE_0X00A0[(*code_point as u32 - 0x00a0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0390..0x03d0 => {
+                        match Self::BASE_0X0390[(*code_point as u32 - 0x0390) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0400..0x0458 => {
+                        match Self::BASE_0X0400[(*code_point as u32 - 0x0400) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2010..0x2040 => {
+                        match Self::BASE_0X2010[(*code_point as u32 - 0x2010) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2100..0x2130 => {
+                        match Self::BASE_0X2100[(*code_point as u32 - 0x2100) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2160..0x2180 => {
+                        match Self::BASE_0X2160[(*code_point as u32 - 0x2160) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2190..0x2198 => {
+                        match Self::BASE_0X2190[(*code_point as u32 - 0x2190) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x21c0..0x21d8 => {
+                        match Self::BASE_0X21C0[(*code_point as u32 - 0x21c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x21e0..0x21f0 => {
+                        match Self::BASE_0X21E0[(*code_point as u32 - 0x21e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2200..0x2240 => {
+                        match Self::BASE_0X2200[(*code_point as u32 - 0x2200) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2250..0x2270 => {
+                        match Self::BASE_0X2250[(*code_point as u32 - 0x2250) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2280..0x2288 => {
+                        match Self::BASE_0X2280[(*code_point as u32 - 0x2280) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2460..0x24b8 => {
+                        match Self::BASE_0X2460[(*code_point as u32 - 0x2460) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2500..0x2550 => {
+                        match Self::BASE_0X2500[(*code_point as u32 - 0x2500) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x25b0..0x25d0 => {
+                        match Self::BASE_0X25B0[(*code_point as u32 - 0x25b0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2600..0x2620 => {
+                        match Self::BASE_0X2600[(*code_point as u32 - 0x2600) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2660..0x2670 => {
+                        match Self::BASE_0X2660[(*code_point as u32 - 0x2660) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2770..0x2780 => {
+                        match Self::BASE_0X2770[(*code_point as u32 - 0x2770) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3000..0x3028 => {
+                        match Self::BASE_0X3000[(*code_point as u32 - 0x3000) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3040..0x3100 => {
+                        match Self::BASE_0X3040[(*code_point as u32 - 0x3040) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3228..0x3248 => {
+                        match Self::BASE_0X3228[(*code_point as u32 - 0x3228) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3290..0x32b0 => {
+                        match Self::BASE_0X3290[(*code_point as u32 - 0x3290) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3300..0x3358 => {
+                        match Self::BASE_0X3300[(*code_point as u32 - 0x3300) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3378..0x33b8 => {
+                        match Self::BASE_0X3378[(*code_point as u32 - 0x3378) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x33c0..0x33d8 => {
+                        match Self::BASE_0X33C0[(*code_point as u32 - 0x33c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x4e00..0x4f20 => {
+                        match Self::BASE_0X4E00[(*code_point as u32 - 0x4e00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x4f28..0x50a0 => {
+                        match Self::BASE_0X4F28[(*code_point as u32 - 0x4f28) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x50a8..0x5388 => {
+                        match Self::BASE_0X50A8[(*code_point as u32 - 0x50a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5390..0x5518 => {
+                        match Self::BASE_0X5390[(*code_point as u32 - 0x5390) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5528..0x5568 => {
+                        match Self::BASE_0X5528[(*code_point as u32 - 0x5528) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5578..0x55e8 => {
+                        match Self::BASE_0X5578[(*code_point as u32 - 0x5578) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x55f0..0x5770 => {
+                        match Self::BASE_0X55F0[(*code_point as u32 - 0x55f0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5778..0x57e8 => {
+                        match Self::BASE_0X5778[(*code_point as u32 - 0x5778) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x57f0..0x5a50 => {
+                        match Self::BASE_0X57F0[(*code_point as u32 - 0x57f0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5a58..0x5a70 => {
+                        match Self::BASE_0X5A58[(*code_point as u32 - 0x5a58) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5a90..0x5aa0 => {
+                        match Self::BASE_0X5A90[(*code_point as u32 - 0x5a90) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5ab8..0x5af0 => {
+                        match Self::BASE_0X5AB8[(*code_point as u32 - 0x5ab8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5af8..0x5c80 => {
+                        match Self::BASE_0X5AF8[(*code_point as u32 - 0x5af8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5c88..0x5cc8 => {
+                        match Self::BASE_0X5C88[(*code_point as u32 - 0x5c88) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5cd8..0x5d30 => {
+                        match Self::BASE_0X5CD8[(*code_point as u32 - 0x5cd8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x5d48..0x61d8 => {
+                        match Self::BASE_0X5D48[(*code_point as u32 - 0x5d48) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x61e0..0x6508 => {
+                        match Self::BASE_0X61E0[(*code_point as u32 - 0x61e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6518..0x6bf8 => {
+                        match Self::BASE_0X6518[(*code_point as u32 - 0x6518) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6c08..0x6cf8 => {
+                        match Self::BASE_0X6C08[(*code_point as u32 - 0x6c08) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6d08..0x6d48 => {
+                        match Self::BASE_0X6D08[(*code_point as u32 - 0x6d08) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6d58..0x6da0 => {
+                        match Self::BASE_0X6D58[(*code_point as u32 - 0x6d58) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x6da8..0x70a0 => {
+                        match Self::BASE_0X6DA8[(*code_point as u32 - 0x6da8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x70a8..0x70e0 => {
+                        match Self::BASE_0X70A8[(*code_point as u32 - 0x70a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x70f0..0x7398 => {
+                        match Self::BASE_0X70F0[(*code_point as u32 - 0x70f0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x73a8..0x7410 => {
+                        match Self::BASE_0X73A8[(*code_point as u32 - 0x73a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7420..0x7448 => {
+                        match Self::BASE_0X7420[(*code_point as u32 - 0x7420) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7450..0x7490 => {
+                        match Self::BASE_0X7450[(*code_point as u32 - 0x7450) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7498..0x7610 => {
+                        match Self::BASE_0X7498[(*code_point as u32 - 0x7498) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7618..0x76a0 => {
+                        match Self::BASE_0X7618[(*code_point as u32 - 0x7618) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x76a8..0x7748 => {
+                        match Self::BASE_0X76A8[(*code_point as u32 - 0x76a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7758..0x7848 => {
+                        match Self::BASE_0X7758[(*code_point as u32 - 0x7758) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7858..0x7ea0 => {
+                        match Self::BASE_0X7858[(*code_point as u32 - 0x7858) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7f30..0x8248 => {
+                        match Self::BASE_0X7F30[(*code_point as u32 - 0x7f30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8258..0x8360 => {
+                        match Self::BASE_0X8258[(*code_point as u32 - 0x8258) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8370..0x84e0 => {
+                        match Self::BASE_0X8370[(*code_point as u32 - 0x8370) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x84e8..0x8790 => {
+                        match Self::BASE_0X84E8[(*code_point as u32 - 0x84e8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8798..0x89c8 => {
+                        match Self::BASE_0X8798[(*code_point as u32 - 0x8798) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x89d0..0x8ba0 => {
+                        match Self::BASE_0X89D0[(*code_point as u32 - 0x89d0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8c30..0x8d18 => {
+                        match Self::BASE_0X8C30[(*code_point as u32 - 0x8c30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8d60..0x8f68 => {
+                        match Self::BASE_0X8D60[(*code_point as u32 - 0x8d60) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8f98..0x9098 => {
+                        match Self::BASE_0X8F98[(*code_point as u32 - 0x8f98) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x90a0..0x9120 => {
+                        match Self::BASE_0X90A0[(*code_point as u32 - 0x90a0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9128..0x9138 => {
+                        match Self::BASE_0X9128[(*code_point as u32 - 0x9128) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9148..0x92a0 => {
+                        match Self::BASE_0X9148[(*code_point as u32 - 0x9148) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x92a8..0x92c0 => {
+                        match Self::BASE_0X92A8[(*code_point as u32 - 0x92a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x92e0..0x93f0 => {
+                        match Self::BASE_0X92E0[(*code_point as u32 - 0x92e0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9400..0x9488 => {
+                        match Self::BASE_0X9400[(*code_point as u32 - 0x9400) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9570..0x95e8 => {
+                        match Self::BASE_0X9570[(*code_point as u32 - 0x9570) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9618..0x97e0 => {
+                        match Self::BASE_0X9618[(*code_point as u32 - 0x9618) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x97e8..0x9878 => {
+                        match Self::BASE_0X97E8[(*code_point as u32 - 0x97e8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x98a8..0x98c8 => {
+                        match Self::BASE_0X98A8[(*code_point as u32 - 0x98a8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x98d8..0x9958 => {
+                        match Self::BASE_0X98D8[(*code_point as u32 - 0x98d8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9990..0x9a70 => {
+                        match Self::BASE_0X9990[(*code_point as u32 - 0x9990) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9aa8..0x9b08 => {
+                        match Self::BASE_0X9AA8[(*code_point as u32 - 0x9aa8) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9b18..0x9b60 => {
+                        match Self::BASE_0X9B18[(*code_point as u32 - 0x9b18) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9b68..0x9c80 => {
+                        match Self::BASE_0X9B68[(*code_point as u32 - 0x9b68) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9ce0..0x9e00 => {
+                        match Self::BASE_0X9CE0[(*code_point as u32 - 0x9ce0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9e18..0x9e20 => {
+                        match Self::BASE_0X9E18[(*code_point as u32 - 0x9e18) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9e70..0x9f78 => {
+                        match Self::BASE_0X9E70[(*code_point as u32 - 0x9e70) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9f88..0x9fa8 => {
+                        match Self::BASE_0X9F88[(*code_point as u32 - 0x9f88) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xfe30..0xfe48 => {
+                        match Self::BASE_0XFE30[(*code_point as u32 - 0xfe30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xff00..0xffa0 => {
+                        match Self::BASE_0XFF00[(*code_point as u32 - 0xff00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as MacJapanese",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x005c => Some(Ok(vec![0x80])),
+                    0x00d7 => Some(Ok(vec![0x81, 0x7e])),
+                    0x00f7 => Some(Ok(vec![0x81, 0x80])),
+                    0x22a5 => Some(Ok(vec![0x81, 0xdb])),
+                    0x22bf => Some(Ok(vec![0x88, 0x42])),
+                    0x2312 => Some(Ok(vec![0x81, 0xdc])),
+                    0x25a0 => Some(Ok(vec![0x81, 0xa1])),
+                    0x25a1 => Some(Ok(vec![0x81, 0xa0])),
+                    0x25ef => Some(Ok(vec![0x81, 0xfc])),
+                    0x2640 => Some(Ok(vec![0x81, 0x8a])),
+                    0x2642 => Some(Ok(vec![0x81, 0x89])),
+                    0x5a7f => Some(Ok(vec![0x96, 0xb9])),
+                    0x92cf => Some(Ok(vec![0xe7, 0xf5])),
+                    0x92d2 => Some(Ok(vec![0x96, 0x4e])),
+                    0xffe3 => Some(Ok(vec![0x81, 0x50])),
+                    0xffe5 => Some(Ok(vec![0x81, 0x8f])),
+                    _ => {
+                        return Some(Err(keramics_core::error_trace_new!(format!(
+                            "Unable to encode code point: U+{:04x} as MacJapanese",
+                            *code_point as u32
+                        ))));
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 8] = [b'K', b'e', b'r', b'a', b'm', b'i', b'c', b's'];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_code_point_sequence() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 2] = [0x85, 0x91];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x0030)));
+        assert_eq!(decoder.next(), Some(Ok(0x002e)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_unsupported_bytes() {
+        let byte_string: [u8; 2] = [0x81, 0x3f];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0x82, 0x3f];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0x83, 0x3f];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 1] = [0x81];
+
+        let mut decoder: DecoderMacJapanese = DecoderMacJapanese::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encode() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 8] = [0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73];
+
+        let mut encoder: EncoderMacJapanese = EncoderMacJapanese::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![b'K'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'e'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'r'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'a'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'm'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'i'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'c'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b's'])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0x00a1];
+
+        let mut encoder: EncoderMacJapanese = EncoderMacJapanese::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x0390];
+
+        let mut encoder: EncoderMacJapanese = EncoderMacJapanese::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x0400];
+
+        let mut encoder: EncoderMacJapanese = EncoderMacJapanese::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderMacJapanese = EncoderMacJapanese::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+    }
+}
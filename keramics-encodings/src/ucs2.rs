@@ -0,0 +1,181 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! UCS-2 encoding.
+//!
+//! Provides support for encoding and decoding little-endian UCS-2.
+//!
+//! UCS-2 has no surrogate pairs, 16-bit values in the surrogate range are
+//! passed through as-is, which is how Windows stores unpaired surrogates in
+//! for example file names.
+
+use keramics_core::ErrorTrace;
+
+/// UCS-2 decoder.
+pub struct DecoderUcs2<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderUcs2<'a> {
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderUcs2<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.bytes.get(self.byte_index..self.byte_index + 2) {
+            Some(slice) => {
+                self.byte_index += 2;
+
+                Some(Ok(u16::from_le_bytes([slice[0], slice[1]]) as u32))
+            }
+            None => match self.bytes.get(self.byte_index) {
+                Some(byte_value) => {
+                    // Skip the trailing byte.
+                    self.byte_index += 1;
+
+                    Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to decode UCS-2: 0x{:02x} as Unicode",
+                        *byte_value
+                    ))))
+                }
+                None => None,
+            },
+        }
+    }
+}
+
+/// UCS-2 encoder.
+pub struct EncoderUcs2<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderUcs2<'a> {
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderUcs2<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                if *code_point > 0xffff {
+                    return Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to encode code point: U+{:04x} as UCS-2",
+                        *code_point
+                    ))));
+                }
+                Some(Ok((*code_point as u16).to_le_bytes().to_vec()))
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 16] = [
+            0x4b, 0x00, 0x65, 0x00, 0x72, 0x00, 0x61, 0x00, 0x6d, 0x00, 0x69, 0x00, 0x63, 0x00,
+            0x73, 0x00,
+        ];
+
+        let mut decoder: DecoderUcs2 = DecoderUcs2::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        let byte_string: [u8; 2] = [0x00, 0xd8];
+
+        let mut decoder: DecoderUcs2 = DecoderUcs2::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0xd800)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_unsupported_bytes() {
+        let byte_string: [u8; 3] = [0x4b, 0x00, 0x65];
+
+        let mut decoder: DecoderUcs2 = DecoderUcs2::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn test_encode() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 2] = [0x4b, 0xffe5];
+
+        let mut encoder: EncoderUcs2 = EncoderUcs2::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![0x4b, 0x00])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0xe5, 0xff])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0x1f600];
+
+        let mut encoder: EncoderUcs2 = EncoderUcs2::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(encoder.next(), None);
+    }
+}
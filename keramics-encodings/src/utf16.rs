@@ -0,0 +1,561 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! UTF-16 encoding.
+//!
+//! Provides support for encoding and decoding UTF-16 (RFC 2781) in big-endian and little-endian.
+
+use keramics_core::ErrorTrace;
+
+/// UTF-16 big-endian decoder.
+pub struct DecoderUtf16BigEndian<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderUtf16BigEndian<'a> {
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+
+    /// Retrieves the 16-bit value at a specific byte index.
+    fn get_value_16bit(&self, byte_index: usize) -> Option<Result<u16, ErrorTrace>> {
+        match self.bytes.get(byte_index..byte_index + 2) {
+            Some(slice) => Some(Ok(u16::from_be_bytes([slice[0], slice[1]]))),
+            None => match self.bytes.get(byte_index) {
+                Some(byte_value) => Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-16 big-endian: 0x{:02x} as Unicode",
+                    *byte_value
+                )))),
+                None => None,
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderUtf16BigEndian<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        let value_16bit: u16 = match self.get_value_16bit(self.byte_index)? {
+            Ok(value_16bit) => value_16bit,
+            Err(error) => {
+                // Skip the trailing byte.
+                self.byte_index += 1;
+
+                return Some(Err(error));
+            }
+        };
+        if value_16bit >= 0xdc00 && value_16bit < 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-16 big-endian unpaired low surrogate: 0x{:04x}",
+                value_16bit
+            ))));
+        }
+        if value_16bit < 0xd800 || value_16bit >= 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Ok(value_16bit as u32));
+        }
+        let low_surrogate: u16 = match self.get_value_16bit(self.byte_index + 2) {
+            Some(Ok(value_16bit)) => value_16bit,
+            _ => {
+                // Skip the unpaired high surrogate only, since the following bytes can
+                // still be decoded.
+                self.byte_index += 2;
+
+                return Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-16 big-endian unpaired high surrogate: 0x{:04x}",
+                    value_16bit
+                ))));
+            }
+        };
+        if low_surrogate < 0xdc00 || low_surrogate >= 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-16 big-endian unpaired high surrogate: 0x{:04x} followed by: 0x{:04x}",
+                value_16bit, low_surrogate
+            ))));
+        }
+        self.byte_index += 4;
+
+        let code_point: u32 =
+            0x10000 + ((((value_16bit & 0x03ff) as u32) << 10) | ((low_surrogate & 0x03ff) as u32));
+
+        Some(Ok(code_point))
+    }
+}
+
+/// UTF-16 big-endian encoder.
+pub struct EncoderUtf16BigEndian<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderUtf16BigEndian<'a> {
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderUtf16BigEndian<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                if (*code_point >= 0xd800 && *code_point < 0xe000) || *code_point > 0x10ffff {
+                    return Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to encode code point: U+{:04x} as UTF-16 big-endian",
+                        *code_point
+                    ))));
+                }
+                if *code_point < 0x10000 {
+                    Some(Ok((*code_point as u16).to_be_bytes().to_vec()))
+                } else {
+                    let value_32bit: u32 = *code_point - 0x10000;
+                    let high_surrogate: u16 = 0xd800 | ((value_32bit >> 10) as u16);
+                    let low_surrogate: u16 = 0xdc00 | ((value_32bit & 0x03ff) as u16);
+
+                    let mut bytes: Vec<u8> = high_surrogate.to_be_bytes().to_vec();
+                    bytes.extend_from_slice(&low_surrogate.to_be_bytes());
+
+                    Some(Ok(bytes))
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+/// UTF-16 little-endian decoder.
+pub struct DecoderUtf16LittleEndian<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderUtf16LittleEndian<'a> {
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+
+    /// Retrieves the 16-bit value at a specific byte index.
+    fn get_value_16bit(&self, byte_index: usize) -> Option<Result<u16, ErrorTrace>> {
+        match self.bytes.get(byte_index..byte_index + 2) {
+            Some(slice) => Some(Ok(u16::from_le_bytes([slice[0], slice[1]]))),
+            None => match self.bytes.get(byte_index) {
+                Some(byte_value) => Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-16 little-endian: 0x{:02x} as Unicode",
+                    *byte_value
+                )))),
+                None => None,
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderUtf16LittleEndian<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        let value_16bit: u16 = match self.get_value_16bit(self.byte_index)? {
+            Ok(value_16bit) => value_16bit,
+            Err(error) => {
+                // Skip the trailing byte.
+                self.byte_index += 1;
+
+                return Some(Err(error));
+            }
+        };
+        if value_16bit >= 0xdc00 && value_16bit < 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-16 little-endian unpaired low surrogate: 0x{:04x}",
+                value_16bit
+            ))));
+        }
+        if value_16bit < 0xd800 || value_16bit >= 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Ok(value_16bit as u32));
+        }
+        let low_surrogate: u16 = match self.get_value_16bit(self.byte_index + 2) {
+            Some(Ok(value_16bit)) => value_16bit,
+            _ => {
+                // Skip the unpaired high surrogate only, since the following bytes can
+                // still be decoded.
+                self.byte_index += 2;
+
+                return Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-16 little-endian unpaired high surrogate: 0x{:04x}",
+                    value_16bit
+                ))));
+            }
+        };
+        if low_surrogate < 0xdc00 || low_surrogate >= 0xe000 {
+            self.byte_index += 2;
+
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-16 little-endian unpaired high surrogate: 0x{:04x} followed by: 0x{:04x}",
+                value_16bit, low_surrogate
+            ))));
+        }
+        self.byte_index += 4;
+
+        let code_point: u32 =
+            0x10000 + ((((value_16bit & 0x03ff) as u32) << 10) | ((low_surrogate & 0x03ff) as u32));
+
+        Some(Ok(code_point))
+    }
+}
+
+/// UTF-16 little-endian encoder.
+pub struct EncoderUtf16LittleEndian<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderUtf16LittleEndian<'a> {
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderUtf16LittleEndian<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                if (*code_point >= 0xd800 && *code_point < 0xe000) || *code_point > 0x10ffff {
+                    return Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to encode code point: U+{:04x} as UTF-16 little-endian",
+                        *code_point
+                    ))));
+                }
+                if *code_point < 0x10000 {
+                    Some(Ok((*code_point as u16).to_le_bytes().to_vec()))
+                } else {
+                    let value_32bit: u32 = *code_point - 0x10000;
+                    let high_surrogate: u16 = 0xd800 | ((value_32bit >> 10) as u16);
+                    let low_surrogate: u16 = 0xdc00 | ((value_32bit & 0x03ff) as u16);
+
+                    let mut bytes: Vec<u8> = high_surrogate.to_le_bytes().to_vec();
+                    bytes.extend_from_slice(&low_surrogate.to_le_bytes());
+
+                    Some(Ok(bytes))
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_be() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 16] = [
+            0x00, 0x4b, 0x00, 0x65, 0x00, 0x72, 0x00, 0x61, 0x00, 0x6d, 0x00, 0x69, 0x00, 0x63,
+            0x00, 0x73,
+        ];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        let byte_string: [u8; 4] = [0xd8, 0x3d, 0xde, 0x00];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x1f600)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_be_with_unsupported_bytes() {
+        let byte_string: [u8; 3] = [0x00, 0x4b, 0x65];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xd8, 0x3d];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0xd8, 0x3d, 0x00, 0x4b];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xde, 0x00];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_be_after_error() {
+        // Decoding continues after an unpaired low surrogate.
+        let byte_string: [u8; 6] = [0x00, 0x4b, 0xdc, 0x00, 0x00, 0x65];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), None);
+
+        // Decoding continues after an unpaired high surrogate and ends after a trailing
+        // byte.
+        let byte_string: [u8; 5] = [0xd8, 0x3d, 0x00, 0x4b, 0xd8];
+
+        let mut decoder: DecoderUtf16BigEndian = DecoderUtf16BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn test_encode_be() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 3] = [0x4b, 0xffe5, 0x1f600];
+
+        let mut encoder: EncoderUtf16BigEndian = EncoderUtf16BigEndian::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![0x00, 0x4b])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0xff, 0xe5])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0xd8, 0x3d, 0xde, 0x00])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_be_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderUtf16BigEndian = EncoderUtf16BigEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x110000];
+
+        let mut encoder: EncoderUtf16BigEndian = EncoderUtf16BigEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(encoder.next(), None);
+    }
+
+    #[test]
+    fn test_decode_le() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 16] = [
+            0x4b, 0x00, 0x65, 0x00, 0x72, 0x00, 0x61, 0x00, 0x6d, 0x00, 0x69, 0x00, 0x63, 0x00,
+            0x73, 0x00,
+        ];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        let byte_string: [u8; 4] = [0x3d, 0xd8, 0x00, 0xde];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x1f600)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_le_with_unsupported_bytes() {
+        let byte_string: [u8; 3] = [0x4b, 0x00, 0x65];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0x3d, 0xd8];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0x3d, 0xd8, 0x4b, 0x00];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0x00, 0xde];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_le_after_error() {
+        // Decoding continues after an unpaired low surrogate.
+        let byte_string: [u8; 6] = [0x4b, 0x00, 0x00, 0xdc, 0x65, 0x00];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), None);
+
+        // Decoding continues after an unpaired high surrogate and ends after a trailing
+        // byte.
+        let byte_string: [u8; 5] = [0x3d, 0xd8, 0x4b, 0x00, 0xd8];
+
+        let mut decoder: DecoderUtf16LittleEndian = DecoderUtf16LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn test_encode_le() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 3] = [0x4b, 0xffe5, 0x1f600];
+
+        let mut encoder: EncoderUtf16LittleEndian = EncoderUtf16LittleEndian::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![0x4b, 0x00])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0xe5, 0xff])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0x3d, 0xd8, 0x00, 0xde])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_le_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderUtf16LittleEndian = EncoderUtf16LittleEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x110000];
+
+        let mut encoder: EncoderUtf16LittleEndian = EncoderUtf16LittleEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(encoder.next(), None);
+    }
+}
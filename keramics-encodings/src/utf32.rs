@@ -0,0 +1,425 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! UTF-32 encoding.
+//!
+//! Provides support for encoding and decoding UTF-32 in big-endian and little-endian.
+
+use keramics_core::ErrorTrace;
+
+/// UTF-32 big-endian decoder.
+pub struct DecoderUtf32BigEndian<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderUtf32BigEndian<'a> {
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderUtf32BigEndian<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        let code_point: u32 = match self.bytes.get(self.byte_index..self.byte_index + 4) {
+            Some(slice) => u32::from_be_bytes([slice[0], slice[1], slice[2], slice[3]]),
+            None => {
+                let number_of_trailing_bytes: usize = self.bytes.len() - self.byte_index;
+
+                if number_of_trailing_bytes == 0 {
+                    return None;
+                }
+                // Skip the trailing bytes.
+                self.byte_index = self.bytes.len();
+
+                return Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-32 big-endian: {} trailing bytes as Unicode",
+                    number_of_trailing_bytes
+                ))));
+            }
+        };
+        self.byte_index += 4;
+
+        if (code_point >= 0xd800 && code_point < 0xe000) || code_point > 0x10ffff {
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-32 big-endian: 0x{:08x} as Unicode",
+                code_point
+            ))));
+        }
+        Some(Ok(code_point))
+    }
+}
+
+/// UTF-32 big-endian encoder.
+pub struct EncoderUtf32BigEndian<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderUtf32BigEndian<'a> {
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderUtf32BigEndian<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                if (*code_point >= 0xd800 && *code_point < 0xe000) || *code_point > 0x10ffff {
+                    return Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to encode code point: U+{:04x} as UTF-32 big-endian",
+                        *code_point
+                    ))));
+                }
+                Some(Ok(code_point.to_be_bytes().to_vec()))
+            }
+            None => None,
+        }
+    }
+}
+
+/// UTF-32 little-endian decoder.
+pub struct DecoderUtf32LittleEndian<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderUtf32LittleEndian<'a> {
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderUtf32LittleEndian<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        let code_point: u32 = match self.bytes.get(self.byte_index..self.byte_index + 4) {
+            Some(slice) => u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]),
+            None => {
+                let number_of_trailing_bytes: usize = self.bytes.len() - self.byte_index;
+
+                if number_of_trailing_bytes == 0 {
+                    return None;
+                }
+                // Skip the trailing bytes.
+                self.byte_index = self.bytes.len();
+
+                return Some(Err(keramics_core::error_trace_new!(format!(
+                    "Unable to decode UTF-32 little-endian: {} trailing bytes as Unicode",
+                    number_of_trailing_bytes
+                ))));
+            }
+        };
+        self.byte_index += 4;
+
+        if (code_point >= 0xd800 && code_point < 0xe000) || code_point > 0x10ffff {
+            return Some(Err(keramics_core::error_trace_new!(format!(
+                "Unable to decode UTF-32 little-endian: 0x{:08x} as Unicode",
+                code_point
+            ))));
+        }
+        Some(Ok(code_point))
+    }
+}
+
+/// UTF-32 little-endian encoder.
+pub struct EncoderUtf32LittleEndian<'a> {
+    /// Code points.
+    code_points: &'a [u32],
+
+    /// Code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderUtf32LittleEndian<'a> {
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderUtf32LittleEndian<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                if (*code_point >= 0xd800 && *code_point < 0xe000) || *code_point > 0x10ffff {
+                    return Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to encode code point: U+{:04x} as UTF-32 little-endian",
+                        *code_point
+                    ))));
+                }
+                Some(Ok(code_point.to_le_bytes().to_vec()))
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_be() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 32] = [
+            0x00, 0x00, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x65, 0x00, 0x00, 0x00, 0x72, 0x00, 0x00,
+            0x00, 0x61, 0x00, 0x00, 0x00, 0x6d, 0x00, 0x00, 0x00, 0x69, 0x00, 0x00, 0x00, 0x63,
+            0x00, 0x00, 0x00, 0x73,
+        ];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        let byte_string: [u8; 4] = [0x00, 0x01, 0xf6, 0x00];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x1f600)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_be_with_unsupported_bytes() {
+        let byte_string: [u8; 6] = [0x00, 0x00, 0x00, 0x4b, 0x65, 0x00];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0x00, 0x00, 0xd8, 0x00];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0x00, 0x11, 0x00, 0x00];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_be_after_error() {
+        // Decoding continues after an unsupported code point and ends after the trailing
+        // bytes.
+        let byte_string: [u8; 10] = [0x00, 0x00, 0xd8, 0x00, 0x00, 0x00, 0x00, 0x4b, 0x00, 0x00];
+
+        let mut decoder: DecoderUtf32BigEndian = DecoderUtf32BigEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn test_encode_be() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 2] = [0x4b, 0x1f600];
+
+        let mut encoder: EncoderUtf32BigEndian = EncoderUtf32BigEndian::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![0x00, 0x00, 0x00, 0x4b])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0x00, 0x01, 0xf6, 0x00])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_be_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0xdc00];
+
+        let mut encoder: EncoderUtf32BigEndian = EncoderUtf32BigEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x110000];
+
+        let mut encoder: EncoderUtf32BigEndian = EncoderUtf32BigEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(encoder.next(), None);
+    }
+
+    #[test]
+    fn test_decode_le() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 32] = [
+            0x4b, 0x00, 0x00, 0x00, 0x65, 0x00, 0x00, 0x00, 0x72, 0x00, 0x00, 0x00, 0x61, 0x00,
+            0x00, 0x00, 0x6d, 0x00, 0x00, 0x00, 0x69, 0x00, 0x00, 0x00, 0x63, 0x00, 0x00, 0x00,
+            0x73, 0x00, 0x00, 0x00,
+        ];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        let byte_string: [u8; 4] = [0x00, 0xf6, 0x01, 0x00];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x1f600)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_le_with_unsupported_bytes() {
+        let byte_string: [u8; 6] = [0x4b, 0x00, 0x00, 0x00, 0x65, 0x00];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0x00, 0xd8, 0x00, 0x00];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 4] = [0x00, 0x00, 0x11, 0x00];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_le_after_error() {
+        // Decoding continues after an unsupported code point and ends after the trailing
+        // bytes.
+        let byte_string: [u8; 10] = [0x00, 0xd8, 0x00, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        let mut decoder: DecoderUtf32LittleEndian = DecoderUtf32LittleEndian::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn test_encode_le() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 2] = [0x4b, 0x1f600];
+
+        let mut encoder: EncoderUtf32LittleEndian = EncoderUtf32LittleEndian::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![0x4b, 0x00, 0x00, 0x00])));
+        assert_eq!(encoder.next(), Some(Ok(vec![0x00, 0xf6, 0x01, 0x00])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_le_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0xdc00];
+
+        let mut encoder: EncoderUtf32LittleEndian = EncoderUtf32LittleEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        let code_points: [u32; 1] = [0x110000];
+
+        let mut encoder: EncoderUtf32LittleEndian = EncoderUtf32LittleEndian::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+
+        assert_eq!(encoder.next(), None);
+    }
+}
@@ -0,0 +1,39506 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Windows 950 (Traditional Chinese Big5) encoding.
+//!
+//! Provides support for encoding and decoding Windows 950.
+
+use keramics_core::ErrorTrace;
+
+/// Windows 950 decoder.
+pub struct DecoderWindows950<'a> {
+    /// Encoded byte sequence.
+    bytes: &'a [u8],
+
+    /// Encoded byte sequence index.
+    byte_index: usize,
+}
+
+impl<'a> DecoderWindows950<'a> {
+    const BASE_0XA140: [Option<u16>; 192] = [
+        Some(0x3000),
+        Some(0xff0c),
+        Some(0x3001),
+        Some(0x3002),
+        Some(0xff0e),
+        Some(0x2027),
+        Some(0xff1b),
+        Some(0xff1a),
+        Some(0xff1f),
+        Some(0xff01),
+        Some(0xfe30),
+        Some(0x2026),
+        Some(0x2025),
+        Some(0xfe50),
+        Some(0xfe51),
+        Some(0xfe52),
+        Some(0x00b7),
+        Some(0xfe54),
+        Some(0xfe55),
+        Some(0xfe56),
+        Some(0xfe57),
+        Some(0xff5c),
+        Some(0x2013),
+        Some(0xfe31),
+        Some(0x2014),
+        Some(0xfe33),
+        Some(0x2574),
+        Some(0xfe34),
+        Some(0xfe4f),
+        Some(0xff08),
+        Some(0xff09),
+        Some(0xfe35),
+        Some(0xfe36),
+        Some(0xff5b),
+        Some(0xff5d),
+        Some(0xfe37),
+        Some(0xfe38),
+        Some(0x3014),
+        Some(0x3015),
+        Some(0xfe39),
+        Some(0xfe3a),
+        Some(0x3010),
+        Some(0x3011),
+        Some(0xfe3b),
+        Some(0xfe3c),
+        Some(0x300a),
+        Some(0x300b),
+        Some(0xfe3d),
+        Some(0xfe3e),
+        Some(0x3008),
+        Some(0x3009),
+        Some(0xfe3f),
+        Some(0xfe40),
+        Some(0x300c),
+        Some(0x300d),
+        Some(0xfe41),
+        Some(0xfe42),
+        Some(0x300e),
+        Some(0x300f),
+        Some(0xfe43),
+        Some(0xfe44),
+        Some(0xfe59),
+        Some(0xfe5a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0xfe5b),
+        Some(0xfe5c),
+        Some(0xfe5d),
+        Some(0xfe5e),
+        Some(0x2018),
+        Some(0x2019),
+        Some(0x201c),
+        Some(0x201d),
+        Some(0x301d),
+        Some(0x301e),
+        Some(0x2035),
+        Some(0x2032),
+        Some(0xff03),
+        Some(0xff06),
+        Some(0xff0a),
+        Some(0x203b),
+        Some(0x00a7),
+        Some(0x3003),
+        Some(0x25cb),
+        Some(0x25cf),
+        Some(0x25b3),
+        Some(0x25b2),
+        Some(0x25ce),
+        Some(0x2606),
+        Some(0x2605),
+        Some(0x25c7),
+        Some(0x25c6),
+        Some(0x25a1),
+        Some(0x25a0),
+        Some(0x25bd),
+        Some(0x25bc),
+        Some(0x32a3),
+        Some(0x2105),
+        Some(0x00af),
+        Some(0xffe3),
+        Some(0xff3f),
+        Some(0x02cd),
+        Some(0xfe49),
+        Some(0xfe4a),
+        Some(0xfe4d),
+        Some(0xfe4e),
+        Some(0xfe4b),
+        Some(0xfe4c),
+        Some(0xfe5f),
+        Some(0xfe60),
+        Some(0xfe61),
+        Some(0xff0b),
+        Some(0xff0d),
+        Some(0x00d7),
+        Some(0x00f7),
+        Some(0x00b1),
+        Some(0x221a),
+        Some(0xff1c),
+        Some(0xff1e),
+        Some(0xff1d),
+        Some(0x2266),
+        Some(0x2267),
+        Some(0x2260),
+        Some(0x221e),
+        Some(0x2252),
+        Some(0x2261),
+        Some(0xfe62),
+        Some(0xfe63),
+        Some(0xfe64),
+        Some(0xfe65),
+        Some(0xfe66),
+        Some(0xff5e),
+        Some(0x2229),
+        Some(0x222a),
+        Some(0x22a5),
+        Some(0x2220),
+        Some(0x221f),
+        Some(0x22bf),
+        Some(0x33d2),
+        Some(0x33d1),
+        Some(0x222b),
+        Some(0x222e),
+        Some(0x2235),
+        Some(0x2234),
+        Some(0x2640),
+        Some(0x2642),
+        Some(0x2295),
+        Some(0x2299),
+        Some(0x2191),
+        Some(0x2193),
+        Some(0x2190),
+        Some(0x2192),
+        Some(0x2196),
+        Some(0x2197),
+        Some(0x2199),
+        Some(0x2198),
+        Some(0x2225),
+        Some(0x2223),
+        Some(0xff0f),
+        None,
+    ];
+
+    const BASE_0XA240: [Option<u16>; 192] = [
+        Some(0xff3c),
+        Some(0x2215),
+        Some(0xfe68),
+        Some(0xff04),
+        Some(0xffe5),
+        Some(0x3012),
+        Some(0xffe0),
+        Some(0xffe1),
+        Some(0xff05),
+        Some(0xff20),
+        Some(0x2103),
+        Some(0x2109),
+        Some(0xfe69),
+        Some(0xfe6a),
+        Some(0xfe6b),
+        Some(0x33d5),
+        Some(0x339c),
+        Some(0x339d),
+        Some(0x339e),
+        Some(0x33ce),
+        Some(0x33a1),
+        Some(0x338e),
+        Some(0x338f),
+        Some(0x33c4),
+        Some(0x00b0),
+        Some(0x5159),
+        Some(0x515b),
+        Some(0x515e),
+        Some(0x515d),
+        Some(0x5161),
+        Some(0x5163),
+        Some(0x55e7),
+        Some(0x74e9),
+        Some(0x7cce),
+        Some(0x2581),
+        Some(0x2582),
+        Some(0x2583),
+        Some(0x2584),
+        Some(0x2585),
+        Some(0x2586),
+        Some(0x2587),
+        Some(0x2588),
+        Some(0x258f),
+        Some(0x258e),
+        Some(0x258d),
+        Some(0x258c),
+        Some(0x258b),
+        Some(0x258a),
+        Some(0x2589),
+        Some(0x253c),
+        Some(0x2534),
+        Some(0x252c),
+        Some(0x2524),
+        Some(0x251c),
+        Some(0x2594),
+        Some(0x2500),
+        Some(0x2502),
+        Some(0x2595),
+        Some(0x250c),
+        Some(0x2510),
+        Some(0x2514),
+        Some(0x2518),
+        Some(0x256d),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x256e),
+        Some(0x2570),
+        Some(0x256f),
+        Some(0x2550),
+        Some(0x255e),
+        Some(0x256a),
+        Some(0x2561),
+        Some(0x25e2),
+        Some(0x25e3),
+        Some(0x25e5),
+        Some(0x25e4),
+        Some(0x2571),
+        Some(0x2572),
+        Some(0x2573),
+        Some(0xff10),
+        Some(0xff11),
+        Some(0xff12),
+        Some(0xff13),
+        Some(0xff14),
+        Some(0xff15),
+        Some(0xff16),
+        Some(0xff17),
+        Some(0xff18),
+        Some(0xff19),
+        Some(0x2160),
+        Some(0x2161),
+        Some(0x2162),
+        Some(0x2163),
+        Some(0x2164),
+        Some(0x2165),
+        Some(0x2166),
+        Some(0x2167),
+        Some(0x2168),
+        Some(0x2169),
+        Some(0x3021),
+        Some(0x3022),
+        Some(0x3023),
+        Some(0x3024),
+        Some(0x3025),
+        Some(0x3026),
+        Some(0x3027),
+        Some(0x3028),
+        Some(0x3029),
+        Some(0x5341),
+        Some(0x5344),
+        Some(0x5345),
+        Some(0xff21),
+        Some(0xff22),
+        Some(0xff23),
+        Some(0xff24),
+        Some(0xff25),
+        Some(0xff26),
+        Some(0xff27),
+        Some(0xff28),
+        Some(0xff29),
+        Some(0xff2a),
+        Some(0xff2b),
+        Some(0xff2c),
+        Some(0xff2d),
+        Some(0xff2e),
+        Some(0xff2f),
+        Some(0xff30),
+        Some(0xff31),
+        Some(0xff32),
+        Some(0xff33),
+        Some(0xff34),
+        Some(0xff35),
+        Some(0xff36),
+        Some(0xff37),
+        Some(0xff38),
+        Some(0xff39),
+        Some(0xff3a),
+        Some(0xff41),
+        Some(0xff42),
+        Some(0xff43),
+        Some(0xff44),
+        Some(0xff45),
+        Some(0xff46),
+        Some(0xff47),
+        Some(0xff48),
+        Some(0xff49),
+        Some(0xff4a),
+        Some(0xff4b),
+        Some(0xff4c),
+        Some(0xff4d),
+        Some(0xff4e),
+        Some(0xff4f),
+        Some(0xff50),
+        Some(0xff51),
+        Some(0xff52),
+        Some(0xff53),
+        Some(0xff54),
+        Some(0xff55),
+        Some(0xff56),
+        None,
+    ];
+
+    const BASE_0XA340: [Option<u16>; 162] = [
+        Some(0xff57),
+        Some(0xff58),
+        Some(0xff59),
+        Some(0xff5a),
+        Some(0x0391),
+        Some(0x0392),
+        Some(0x0393),
+        Some(0x0394),
+        Some(0x0395),
+        Some(0x0396),
+        Some(0x0397),
+        Some(0x0398),
+        Some(0x0399),
+        Some(0x039a),
+        Some(0x039b),
+        Some(0x039c),
+        Some(0x039d),
+        Some(0x039e),
+        Some(0x039f),
+        Some(0x03a0),
+        Some(0x03a1),
+        Some(0x03a3),
+        Some(0x03a4),
+        Some(0x03a5),
+        Some(0x03a6),
+        Some(0x03a7),
+        Some(0x03a8),
+        Some(0x03a9),
+        Some(0x03b1),
+        Some(0x03b2),
+        Some(0x03b3),
+        Some(0x03b4),
+        Some(0x03b5),
+        Some(0x03b6),
+        Some(0x03b7),
+        Some(0x03b8),
+        Some(0x03b9),
+        Some(0x03ba),
+        Some(0x03bb),
+        Some(0x03bc),
+        Some(0x03bd),
+        Some(0x03be),
+        Some(0x03bf),
+        Some(0x03c0),
+        Some(0x03c1),
+        Some(0x03c3),
+        Some(0x03c4),
+        Some(0x03c5),
+        Some(0x03c6),
+        Some(0x03c7),
+        Some(0x03c8),
+        Some(0x03c9),
+        Some(0x3105),
+        Some(0x3106),
+        Some(0x3107),
+        Some(0x3108),
+        Some(0x3109),
+        Some(0x310a),
+        Some(0x310b),
+        Some(0x310c),
+        Some(0x310d),
+        Some(0x310e),
+        Some(0x310f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x3110),
+        Some(0x3111),
+        Some(0x3112),
+        Some(0x3113),
+        Some(0x3114),
+        Some(0x3115),
+        Some(0x3116),
+        Some(0x3117),
+        Some(0x3118),
+        Some(0x3119),
+        Some(0x311a),
+        Some(0x311b),
+        Some(0x311c),
+        Some(0x311d),
+        Some(0x311e),
+        Some(0x311f),
+        Some(0x3120),
+        Some(0x3121),
+        Some(0x3122),
+        Some(0x3123),
+        Some(0x3124),
+        Some(0x3125),
+        Some(0x3126),
+        Some(0x3127),
+        Some(0x3128),
+        Some(0x3129),
+        Some(0x02d9),
+        Some(0x02c9),
+        Some(0x02ca),
+        Some(0x02c7),
+        Some(0x02cb),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x20ac),
+    ];
+
+    const BASE_0XA440: [Option<u16>; 192] = [
+        Some(0x4e00),
+        Some(0x4e59),
+        Some(0x4e01),
+        Some(0x4e03),
+        Some(0x4e43),
+        Some(0x4e5d),
+        Some(0x4e86),
+        Some(0x4e8c),
+        Some(0x4eba),
+        Some(0x513f),
+        Some(0x5165),
+        Some(0x516b),
+        Some(0x51e0),
+        Some(0x5200),
+        Some(0x5201),
+        Some(0x529b),
+        Some(0x5315),
+        Some(0x5341),
+        Some(0x535c),
+        Some(0x53c8),
+        Some(0x4e09),
+        Some(0x4e0b),
+        Some(0x4e08),
+        Some(0x4e0a),
+        Some(0x4e2b),
+        Some(0x4e38),
+        Some(0x51e1),
+        Some(0x4e45),
+        Some(0x4e48),
+        Some(0x4e5f),
+        Some(0x4e5e),
+        Some(0x4e8e),
+        Some(0x4ea1),
+        Some(0x5140),
+        Some(0x5203),
+        Some(0x52fa),
+        Some(0x5343),
+        Some(0x53c9),
+        Some(0x53e3),
+        Some(0x571f),
+        Some(0x58eb),
+        Some(0x5915),
+        Some(0x5927),
+        Some(0x5973),
+        Some(0x5b50),
+        Some(0x5b51),
+        Some(0x5b53),
+        Some(0x5bf8),
+        Some(0x5c0f),
+        Some(0x5c22),
+        Some(0x5c38),
+        Some(0x5c71),
+        Some(0x5ddd),
+        Some(0x5de5),
+        Some(0x5df1),
+        Some(0x5df2),
+        Some(0x5df3),
+        Some(0x5dfe),
+        Some(0x5e72),
+        Some(0x5efe),
+        Some(0x5f0b),
+        Some(0x5f13),
+        Some(0x624d),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x4e11),
+        Some(0x4e10),
+        Some(0x4e0d),
+        Some(0x4e2d),
+        Some(0x4e30),
+        Some(0x4e39),
+        Some(0x4e4b),
+        Some(0x5c39),
+        Some(0x4e88),
+        Some(0x4e91),
+        Some(0x4e95),
+        Some(0x4e92),
+        Some(0x4e94),
+        Some(0x4ea2),
+        Some(0x4ec1),
+        Some(0x4ec0),
+        Some(0x4ec3),
+        Some(0x4ec6),
+        Some(0x4ec7),
+        Some(0x4ecd),
+        Some(0x4eca),
+        Some(0x4ecb),
+        Some(0x4ec4),
+        Some(0x5143),
+        Some(0x5141),
+        Some(0x5167),
+        Some(0x516d),
+        Some(0x516e),
+        Some(0x516c),
+        Some(0x5197),
+        Some(0x51f6),
+        Some(0x5206),
+        Some(0x5207),
+        Some(0x5208),
+        Some(0x52fb),
+        Some(0x52fe),
+        Some(0x52ff),
+        Some(0x5316),
+        Some(0x5339),
+        Some(0x5348),
+        Some(0x5347),
+        Some(0x5345),
+        Some(0x535e),
+        Some(0x5384),
+        Some(0x53cb),
+        Some(0x53ca),
+        Some(0x53cd),
+        Some(0x58ec),
+        Some(0x5929),
+        Some(0x592b),
+        Some(0x592a),
+        Some(0x592d),
+        Some(0x5b54),
+        Some(0x5c11),
+        Some(0x5c24),
+        Some(0x5c3a),
+        Some(0x5c6f),
+        Some(0x5df4),
+        Some(0x5e7b),
+        Some(0x5eff),
+        Some(0x5f14),
+        Some(0x5f15),
+        Some(0x5fc3),
+        Some(0x6208),
+        Some(0x6236),
+        Some(0x624b),
+        Some(0x624e),
+        Some(0x652f),
+        Some(0x6587),
+        Some(0x6597),
+        Some(0x65a4),
+        Some(0x65b9),
+        Some(0x65e5),
+        Some(0x66f0),
+        Some(0x6708),
+        Some(0x6728),
+        Some(0x6b20),
+        Some(0x6b62),
+        Some(0x6b79),
+        Some(0x6bcb),
+        Some(0x6bd4),
+        Some(0x6bdb),
+        Some(0x6c0f),
+        Some(0x6c34),
+        Some(0x706b),
+        Some(0x722a),
+        Some(0x7236),
+        Some(0x723b),
+        Some(0x7247),
+        Some(0x7259),
+        Some(0x725b),
+        Some(0x72ac),
+        Some(0x738b),
+        Some(0x4e19),
+        None,
+    ];
+
+    const BASE_0XA540: [Option<u16>; 192] = [
+        Some(0x4e16),
+        Some(0x4e15),
+        Some(0x4e14),
+        Some(0x4e18),
+        Some(0x4e3b),
+        Some(0x4e4d),
+        Some(0x4e4f),
+        Some(0x4e4e),
+        Some(0x4ee5),
+        Some(0x4ed8),
+        Some(0x4ed4),
+        Some(0x4ed5),
+        Some(0x4ed6),
+        Some(0x4ed7),
+        Some(0x4ee3),
+        Some(0x4ee4),
+        Some(0x4ed9),
+        Some(0x4ede),
+        Some(0x5145),
+        Some(0x5144),
+        Some(0x5189),
+        Some(0x518a),
+        Some(0x51ac),
+        Some(0x51f9),
+        Some(0x51fa),
+        Some(0x51f8),
+        Some(0x520a),
+        Some(0x52a0),
+        Some(0x529f),
+        Some(0x5305),
+        Some(0x5306),
+        Some(0x5317),
+        Some(0x531d),
+        Some(0x4edf),
+        Some(0x534a),
+        Some(0x5349),
+        Some(0x5361),
+        Some(0x5360),
+        Some(0x536f),
+        Some(0x536e),
+        Some(0x53bb),
+        Some(0x53ef),
+        Some(0x53e4),
+        Some(0x53f3),
+        Some(0x53ec),
+        Some(0x53ee),
+        Some(0x53e9),
+        Some(0x53e8),
+        Some(0x53fc),
+        Some(0x53f8),
+        Some(0x53f5),
+        Some(0x53eb),
+        Some(0x53e6),
+        Some(0x53ea),
+        Some(0x53f2),
+        Some(0x53f1),
+        Some(0x53f0),
+        Some(0x53e5),
+        Some(0x53ed),
+        Some(0x53fb),
+        Some(0x56db),
+        Some(0x56da),
+        Some(0x5916),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x592e),
+        Some(0x5931),
+        Some(0x5974),
+        Some(0x5976),
+        Some(0x5b55),
+        Some(0x5b83),
+        Some(0x5c3c),
+        Some(0x5de8),
+        Some(0x5de7),
+        Some(0x5de6),
+        Some(0x5e02),
+        Some(0x5e03),
+        Some(0x5e73),
+        Some(0x5e7c),
+        Some(0x5f01),
+        Some(0x5f18),
+        Some(0x5f17),
+        Some(0x5fc5),
+        Some(0x620a),
+        Some(0x6253),
+        Some(0x6254),
+        Some(0x6252),
+        Some(0x6251),
+        Some(0x65a5),
+        Some(0x65e6),
+        Some(0x672e),
+        Some(0x672c),
+        Some(0x672a),
+        Some(0x672b),
+        Some(0x672d),
+        Some(0x6b63),
+        Some(0x6bcd),
+        Some(0x6c11),
+        Some(0x6c10),
+        Some(0x6c38),
+        Some(0x6c41),
+        Some(0x6c40),
+        Some(0x6c3e),
+        Some(0x72af),
+        Some(0x7384),
+        Some(0x7389),
+        Some(0x74dc),
+        Some(0x74e6),
+        Some(0x7518),
+        Some(0x751f),
+        Some(0x7528),
+        Some(0x7529),
+        Some(0x7530),
+        Some(0x7531),
+        Some(0x7532),
+        Some(0x7533),
+        Some(0x758b),
+        Some(0x767d),
+        Some(0x76ae),
+        Some(0x76bf),
+        Some(0x76ee),
+        Some(0x77db),
+        Some(0x77e2),
+        Some(0x77f3),
+        Some(0x793a),
+        Some(0x79be),
+        Some(0x7a74),
+        Some(0x7acb),
+        Some(0x4e1e),
+        Some(0x4e1f),
+        Some(0x4e52),
+        Some(0x4e53),
+        Some(0x4e69),
+        Some(0x4e99),
+        Some(0x4ea4),
+        Some(0x4ea6),
+        Some(0x4ea5),
+        Some(0x4eff),
+        Some(0x4f09),
+        Some(0x4f19),
+        Some(0x4f0a),
+        Some(0x4f15),
+        Some(0x4f0d),
+        Some(0x4f10),
+        Some(0x4f11),
+        Some(0x4f0f),
+        Some(0x4ef2),
+        Some(0x4ef6),
+        Some(0x4efb),
+        Some(0x4ef0),
+        Some(0x4ef3),
+        Some(0x4efd),
+        Some(0x4f01),
+        Some(0x4f0b),
+        Some(0x5149),
+        Some(0x5147),
+        Some(0x5146),
+        Some(0x5148),
+        Some(0x5168),
+        None,
+    ];
+
+    const BASE_0XA640: [Option<u16>; 192] = [
+        Some(0x5171),
+        Some(0x518d),
+        Some(0x51b0),
+        Some(0x5217),
+        Some(0x5211),
+        Some(0x5212),
+        Some(0x520e),
+        Some(0x5216),
+        Some(0x52a3),
+        Some(0x5308),
+        Some(0x5321),
+        Some(0x5320),
+        Some(0x5370),
+        Some(0x5371),
+        Some(0x5409),
+        Some(0x540f),
+        Some(0x540c),
+        Some(0x540a),
+        Some(0x5410),
+        Some(0x5401),
+        Some(0x540b),
+        Some(0x5404),
+        Some(0x5411),
+        Some(0x540d),
+        Some(0x5408),
+        Some(0x5403),
+        Some(0x540e),
+        Some(0x5406),
+        Some(0x5412),
+        Some(0x56e0),
+        Some(0x56de),
+        Some(0x56dd),
+        Some(0x5733),
+        Some(0x5730),
+        Some(0x5728),
+        Some(0x572d),
+        Some(0x572c),
+        Some(0x572f),
+        Some(0x5729),
+        Some(0x5919),
+        Some(0x591a),
+        Some(0x5937),
+        Some(0x5938),
+        Some(0x5984),
+        Some(0x5978),
+        Some(0x5983),
+        Some(0x597d),
+        Some(0x5979),
+        Some(0x5982),
+        Some(0x5981),
+        Some(0x5b57),
+        Some(0x5b58),
+        Some(0x5b87),
+        Some(0x5b88),
+        Some(0x5b85),
+        Some(0x5b89),
+        Some(0x5bfa),
+        Some(0x5c16),
+        Some(0x5c79),
+        Some(0x5dde),
+        Some(0x5e06),
+        Some(0x5e76),
+        Some(0x5e74),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5f0f),
+        Some(0x5f1b),
+        Some(0x5fd9),
+        Some(0x5fd6),
+        Some(0x620e),
+        Some(0x620c),
+        Some(0x620d),
+        Some(0x6210),
+        Some(0x6263),
+        Some(0x625b),
+        Some(0x6258),
+        Some(0x6536),
+        Some(0x65e9),
+        Some(0x65e8),
+        Some(0x65ec),
+        Some(0x65ed),
+        Some(0x66f2),
+        Some(0x66f3),
+        Some(0x6709),
+        Some(0x673d),
+        Some(0x6734),
+        Some(0x6731),
+        Some(0x6735),
+        Some(0x6b21),
+        Some(0x6b64),
+        Some(0x6b7b),
+        Some(0x6c16),
+        Some(0x6c5d),
+        Some(0x6c57),
+        Some(0x6c59),
+        Some(0x6c5f),
+        Some(0x6c60),
+        Some(0x6c50),
+        Some(0x6c55),
+        Some(0x6c61),
+        Some(0x6c5b),
+        Some(0x6c4d),
+        Some(0x6c4e),
+        Some(0x7070),
+        Some(0x725f),
+        Some(0x725d),
+        Some(0x767e),
+        Some(0x7af9),
+        Some(0x7c73),
+        Some(0x7cf8),
+        Some(0x7f36),
+        Some(0x7f8a),
+        Some(0x7fbd),
+        Some(0x8001),
+        Some(0x8003),
+        Some(0x800c),
+        Some(0x8012),
+        Some(0x8033),
+        Some(0x807f),
+        Some(0x8089),
+        Some(0x808b),
+        Some(0x808c),
+        Some(0x81e3),
+        Some(0x81ea),
+        Some(0x81f3),
+        Some(0x81fc),
+        Some(0x820c),
+        Some(0x821b),
+        Some(0x821f),
+        Some(0x826e),
+        Some(0x8272),
+        Some(0x827e),
+        Some(0x866b),
+        Some(0x8840),
+        Some(0x884c),
+        Some(0x8863),
+        Some(0x897f),
+        Some(0x9621),
+        Some(0x4e32),
+        Some(0x4ea8),
+        Some(0x4f4d),
+        Some(0x4f4f),
+        Some(0x4f47),
+        Some(0x4f57),
+        Some(0x4f5e),
+        Some(0x4f34),
+        Some(0x4f5b),
+        Some(0x4f55),
+        Some(0x4f30),
+        Some(0x4f50),
+        Some(0x4f51),
+        Some(0x4f3d),
+        Some(0x4f3a),
+        Some(0x4f38),
+        Some(0x4f43),
+        Some(0x4f54),
+        Some(0x4f3c),
+        Some(0x4f46),
+        Some(0x4f63),
+        None,
+    ];
+
+    const BASE_0XA740: [Option<u16>; 192] = [
+        Some(0x4f5c),
+        Some(0x4f60),
+        Some(0x4f2f),
+        Some(0x4f4e),
+        Some(0x4f36),
+        Some(0x4f59),
+        Some(0x4f5d),
+        Some(0x4f48),
+        Some(0x4f5a),
+        Some(0x514c),
+        Some(0x514b),
+        Some(0x514d),
+        Some(0x5175),
+        Some(0x51b6),
+        Some(0x51b7),
+        Some(0x5225),
+        Some(0x5224),
+        Some(0x5229),
+        Some(0x522a),
+        Some(0x5228),
+        Some(0x52ab),
+        Some(0x52a9),
+        Some(0x52aa),
+        Some(0x52ac),
+        Some(0x5323),
+        Some(0x5373),
+        Some(0x5375),
+        Some(0x541d),
+        Some(0x542d),
+        Some(0x541e),
+        Some(0x543e),
+        Some(0x5426),
+        Some(0x544e),
+        Some(0x5427),
+        Some(0x5446),
+        Some(0x5443),
+        Some(0x5433),
+        Some(0x5448),
+        Some(0x5442),
+        Some(0x541b),
+        Some(0x5429),
+        Some(0x544a),
+        Some(0x5439),
+        Some(0x543b),
+        Some(0x5438),
+        Some(0x542e),
+        Some(0x5435),
+        Some(0x5436),
+        Some(0x5420),
+        Some(0x543c),
+        Some(0x5440),
+        Some(0x5431),
+        Some(0x542b),
+        Some(0x541f),
+        Some(0x542c),
+        Some(0x56ea),
+        Some(0x56f0),
+        Some(0x56e4),
+        Some(0x56eb),
+        Some(0x574a),
+        Some(0x5751),
+        Some(0x5740),
+        Some(0x574d),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5747),
+        Some(0x574e),
+        Some(0x573e),
+        Some(0x5750),
+        Some(0x574f),
+        Some(0x573b),
+        Some(0x58ef),
+        Some(0x593e),
+        Some(0x599d),
+        Some(0x5992),
+        Some(0x59a8),
+        Some(0x599e),
+        Some(0x59a3),
+        Some(0x5999),
+        Some(0x5996),
+        Some(0x598d),
+        Some(0x59a4),
+        Some(0x5993),
+        Some(0x598a),
+        Some(0x59a5),
+        Some(0x5b5d),
+        Some(0x5b5c),
+        Some(0x5b5a),
+        Some(0x5b5b),
+        Some(0x5b8c),
+        Some(0x5b8b),
+        Some(0x5b8f),
+        Some(0x5c2c),
+        Some(0x5c40),
+        Some(0x5c41),
+        Some(0x5c3f),
+        Some(0x5c3e),
+        Some(0x5c90),
+        Some(0x5c91),
+        Some(0x5c94),
+        Some(0x5c8c),
+        Some(0x5deb),
+        Some(0x5e0c),
+        Some(0x5e8f),
+        Some(0x5e87),
+        Some(0x5e8a),
+        Some(0x5ef7),
+        Some(0x5f04),
+        Some(0x5f1f),
+        Some(0x5f64),
+        Some(0x5f62),
+        Some(0x5f77),
+        Some(0x5f79),
+        Some(0x5fd8),
+        Some(0x5fcc),
+        Some(0x5fd7),
+        Some(0x5fcd),
+        Some(0x5ff1),
+        Some(0x5feb),
+        Some(0x5ff8),
+        Some(0x5fea),
+        Some(0x6212),
+        Some(0x6211),
+        Some(0x6284),
+        Some(0x6297),
+        Some(0x6296),
+        Some(0x6280),
+        Some(0x6276),
+        Some(0x6289),
+        Some(0x626d),
+        Some(0x628a),
+        Some(0x627c),
+        Some(0x627e),
+        Some(0x6279),
+        Some(0x6273),
+        Some(0x6292),
+        Some(0x626f),
+        Some(0x6298),
+        Some(0x626e),
+        Some(0x6295),
+        Some(0x6293),
+        Some(0x6291),
+        Some(0x6286),
+        Some(0x6539),
+        Some(0x653b),
+        Some(0x6538),
+        Some(0x65f1),
+        Some(0x66f4),
+        Some(0x675f),
+        Some(0x674e),
+        Some(0x674f),
+        Some(0x6750),
+        Some(0x6751),
+        Some(0x675c),
+        Some(0x6756),
+        Some(0x675e),
+        Some(0x6749),
+        Some(0x6746),
+        Some(0x6760),
+        None,
+    ];
+
+    const BASE_0XA840: [Option<u16>; 192] = [
+        Some(0x6753),
+        Some(0x6757),
+        Some(0x6b65),
+        Some(0x6bcf),
+        Some(0x6c42),
+        Some(0x6c5e),
+        Some(0x6c99),
+        Some(0x6c81),
+        Some(0x6c88),
+        Some(0x6c89),
+        Some(0x6c85),
+        Some(0x6c9b),
+        Some(0x6c6a),
+        Some(0x6c7a),
+        Some(0x6c90),
+        Some(0x6c70),
+        Some(0x6c8c),
+        Some(0x6c68),
+        Some(0x6c96),
+        Some(0x6c92),
+        Some(0x6c7d),
+        Some(0x6c83),
+        Some(0x6c72),
+        Some(0x6c7e),
+        Some(0x6c74),
+        Some(0x6c86),
+        Some(0x6c76),
+        Some(0x6c8d),
+        Some(0x6c94),
+        Some(0x6c98),
+        Some(0x6c82),
+        Some(0x7076),
+        Some(0x707c),
+        Some(0x707d),
+        Some(0x7078),
+        Some(0x7262),
+        Some(0x7261),
+        Some(0x7260),
+        Some(0x72c4),
+        Some(0x72c2),
+        Some(0x7396),
+        Some(0x752c),
+        Some(0x752b),
+        Some(0x7537),
+        Some(0x7538),
+        Some(0x7682),
+        Some(0x76ef),
+        Some(0x77e3),
+        Some(0x79c1),
+        Some(0x79c0),
+        Some(0x79bf),
+        Some(0x7a76),
+        Some(0x7cfb),
+        Some(0x7f55),
+        Some(0x8096),
+        Some(0x8093),
+        Some(0x809d),
+        Some(0x8098),
+        Some(0x809b),
+        Some(0x809a),
+        Some(0x80b2),
+        Some(0x826f),
+        Some(0x8292),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x828b),
+        Some(0x828d),
+        Some(0x898b),
+        Some(0x89d2),
+        Some(0x8a00),
+        Some(0x8c37),
+        Some(0x8c46),
+        Some(0x8c55),
+        Some(0x8c9d),
+        Some(0x8d64),
+        Some(0x8d70),
+        Some(0x8db3),
+        Some(0x8eab),
+        Some(0x8eca),
+        Some(0x8f9b),
+        Some(0x8fb0),
+        Some(0x8fc2),
+        Some(0x8fc6),
+        Some(0x8fc5),
+        Some(0x8fc4),
+        Some(0x5de1),
+        Some(0x9091),
+        Some(0x90a2),
+        Some(0x90aa),
+        Some(0x90a6),
+        Some(0x90a3),
+        Some(0x9149),
+        Some(0x91c6),
+        Some(0x91cc),
+        Some(0x9632),
+        Some(0x962e),
+        Some(0x9631),
+        Some(0x962a),
+        Some(0x962c),
+        Some(0x4e26),
+        Some(0x4e56),
+        Some(0x4e73),
+        Some(0x4e8b),
+        Some(0x4e9b),
+        Some(0x4e9e),
+        Some(0x4eab),
+        Some(0x4eac),
+        Some(0x4f6f),
+        Some(0x4f9d),
+        Some(0x4f8d),
+        Some(0x4f73),
+        Some(0x4f7f),
+        Some(0x4f6c),
+        Some(0x4f9b),
+        Some(0x4f8b),
+        Some(0x4f86),
+        Some(0x4f83),
+        Some(0x4f70),
+        Some(0x4f75),
+        Some(0x4f88),
+        Some(0x4f69),
+        Some(0x4f7b),
+        Some(0x4f96),
+        Some(0x4f7e),
+        Some(0x4f8f),
+        Some(0x4f91),
+        Some(0x4f7a),
+        Some(0x5154),
+        Some(0x5152),
+        Some(0x5155),
+        Some(0x5169),
+        Some(0x5177),
+        Some(0x5176),
+        Some(0x5178),
+        Some(0x51bd),
+        Some(0x51fd),
+        Some(0x523b),
+        Some(0x5238),
+        Some(0x5237),
+        Some(0x523a),
+        Some(0x5230),
+        Some(0x522e),
+        Some(0x5236),
+        Some(0x5241),
+        Some(0x52be),
+        Some(0x52bb),
+        Some(0x5352),
+        Some(0x5354),
+        Some(0x5353),
+        Some(0x5351),
+        Some(0x5366),
+        Some(0x5377),
+        Some(0x5378),
+        Some(0x5379),
+        Some(0x53d6),
+        Some(0x53d4),
+        Some(0x53d7),
+        Some(0x5473),
+        Some(0x5475),
+        None,
+    ];
+
+    const BASE_0XA940: [Option<u16>; 192] = [
+        Some(0x5496),
+        Some(0x5478),
+        Some(0x5495),
+        Some(0x5480),
+        Some(0x547b),
+        Some(0x5477),
+        Some(0x5484),
+        Some(0x5492),
+        Some(0x5486),
+        Some(0x547c),
+        Some(0x5490),
+        Some(0x5471),
+        Some(0x5476),
+        Some(0x548c),
+        Some(0x549a),
+        Some(0x5462),
+        Some(0x5468),
+        Some(0x548b),
+        Some(0x547d),
+        Some(0x548e),
+        Some(0x56fa),
+        Some(0x5783),
+        Some(0x5777),
+        Some(0x576a),
+        Some(0x5769),
+        Some(0x5761),
+        Some(0x5766),
+        Some(0x5764),
+        Some(0x577c),
+        Some(0x591c),
+        Some(0x5949),
+        Some(0x5947),
+        Some(0x5948),
+        Some(0x5944),
+        Some(0x5954),
+        Some(0x59be),
+        Some(0x59bb),
+        Some(0x59d4),
+        Some(0x59b9),
+        Some(0x59ae),
+        Some(0x59d1),
+        Some(0x59c6),
+        Some(0x59d0),
+        Some(0x59cd),
+        Some(0x59cb),
+        Some(0x59d3),
+        Some(0x59ca),
+        Some(0x59af),
+        Some(0x59b3),
+        Some(0x59d2),
+        Some(0x59c5),
+        Some(0x5b5f),
+        Some(0x5b64),
+        Some(0x5b63),
+        Some(0x5b97),
+        Some(0x5b9a),
+        Some(0x5b98),
+        Some(0x5b9c),
+        Some(0x5b99),
+        Some(0x5b9b),
+        Some(0x5c1a),
+        Some(0x5c48),
+        Some(0x5c45),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5c46),
+        Some(0x5cb7),
+        Some(0x5ca1),
+        Some(0x5cb8),
+        Some(0x5ca9),
+        Some(0x5cab),
+        Some(0x5cb1),
+        Some(0x5cb3),
+        Some(0x5e18),
+        Some(0x5e1a),
+        Some(0x5e16),
+        Some(0x5e15),
+        Some(0x5e1b),
+        Some(0x5e11),
+        Some(0x5e78),
+        Some(0x5e9a),
+        Some(0x5e97),
+        Some(0x5e9c),
+        Some(0x5e95),
+        Some(0x5e96),
+        Some(0x5ef6),
+        Some(0x5f26),
+        Some(0x5f27),
+        Some(0x5f29),
+        Some(0x5f80),
+        Some(0x5f81),
+        Some(0x5f7f),
+        Some(0x5f7c),
+        Some(0x5fdd),
+        Some(0x5fe0),
+        Some(0x5ffd),
+        Some(0x5ff5),
+        Some(0x5fff),
+        Some(0x600f),
+        Some(0x6014),
+        Some(0x602f),
+        Some(0x6035),
+        Some(0x6016),
+        Some(0x602a),
+        Some(0x6015),
+        Some(0x6021),
+        Some(0x6027),
+        Some(0x6029),
+        Some(0x602b),
+        Some(0x601b),
+        Some(0x6216),
+        Some(0x6215),
+        Some(0x623f),
+        Some(0x623e),
+        Some(0x6240),
+        Some(0x627f),
+        Some(0x62c9),
+        Some(0x62cc),
+        Some(0x62c4),
+        Some(0x62bf),
+        Some(0x62c2),
+        Some(0x62b9),
+        Some(0x62d2),
+        Some(0x62db),
+        Some(0x62ab),
+        Some(0x62d3),
+        Some(0x62d4),
+        Some(0x62cb),
+        Some(0x62c8),
+        Some(0x62a8),
+        Some(0x62bd),
+        Some(0x62bc),
+        Some(0x62d0),
+        Some(0x62d9),
+        Some(0x62c7),
+        Some(0x62cd),
+        Some(0x62b5),
+        Some(0x62da),
+        Some(0x62b1),
+        Some(0x62d8),
+        Some(0x62d6),
+        Some(0x62d7),
+        Some(0x62c6),
+        Some(0x62ac),
+        Some(0x62ce),
+        Some(0x653e),
+        Some(0x65a7),
+        Some(0x65bc),
+        Some(0x65fa),
+        Some(0x6614),
+        Some(0x6613),
+        Some(0x660c),
+        Some(0x6606),
+        Some(0x6602),
+        Some(0x660e),
+        Some(0x6600),
+        Some(0x660f),
+        Some(0x6615),
+        Some(0x660a),
+        None,
+    ];
+
+    const BASE_0XAA40: [Option<u16>; 192] = [
+        Some(0x6607),
+        Some(0x670d),
+        Some(0x670b),
+        Some(0x676d),
+        Some(0x678b),
+        Some(0x6795),
+        Some(0x6771),
+        Some(0x679c),
+        Some(0x6773),
+        Some(0x6777),
+        Some(0x6787),
+        Some(0x679d),
+        Some(0x6797),
+        Some(0x676f),
+        Some(0x6770),
+        Some(0x677f),
+        Some(0x6789),
+        Some(0x677e),
+        Some(0x6790),
+        Some(0x6775),
+        Some(0x679a),
+        Some(0x6793),
+        Some(0x677c),
+        Some(0x676a),
+        Some(0x6772),
+        Some(0x6b23),
+        Some(0x6b66),
+        Some(0x6b67),
+        Some(0x6b7f),
+        Some(0x6c13),
+        Some(0x6c1b),
+        Some(0x6ce3),
+        Some(0x6ce8),
+        Some(0x6cf3),
+        Some(0x6cb1),
+        Some(0x6ccc),
+        Some(0x6ce5),
+        Some(0x6cb3),
+        Some(0x6cbd),
+        Some(0x6cbe),
+        Some(0x6cbc),
+        Some(0x6ce2),
+        Some(0x6cab),
+        Some(0x6cd5),
+        Some(0x6cd3),
+        Some(0x6cb8),
+        Some(0x6cc4),
+        Some(0x6cb9),
+        Some(0x6cc1),
+        Some(0x6cae),
+        Some(0x6cd7),
+        Some(0x6cc5),
+        Some(0x6cf1),
+        Some(0x6cbf),
+        Some(0x6cbb),
+        Some(0x6ce1),
+        Some(0x6cdb),
+        Some(0x6cca),
+        Some(0x6cac),
+        Some(0x6cef),
+        Some(0x6cdc),
+        Some(0x6cd6),
+        Some(0x6ce0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7095),
+        Some(0x708e),
+        Some(0x7092),
+        Some(0x708a),
+        Some(0x7099),
+        Some(0x722c),
+        Some(0x722d),
+        Some(0x7238),
+        Some(0x7248),
+        Some(0x7267),
+        Some(0x7269),
+        Some(0x72c0),
+        Some(0x72ce),
+        Some(0x72d9),
+        Some(0x72d7),
+        Some(0x72d0),
+        Some(0x73a9),
+        Some(0x73a8),
+        Some(0x739f),
+        Some(0x73ab),
+        Some(0x73a5),
+        Some(0x753d),
+        Some(0x759d),
+        Some(0x7599),
+        Some(0x759a),
+        Some(0x7684),
+        Some(0x76c2),
+        Some(0x76f2),
+        Some(0x76f4),
+        Some(0x77e5),
+        Some(0x77fd),
+        Some(0x793e),
+        Some(0x7940),
+        Some(0x7941),
+        Some(0x79c9),
+        Some(0x79c8),
+        Some(0x7a7a),
+        Some(0x7a79),
+        Some(0x7afa),
+        Some(0x7cfe),
+        Some(0x7f54),
+        Some(0x7f8c),
+        Some(0x7f8b),
+        Some(0x8005),
+        Some(0x80ba),
+        Some(0x80a5),
+        Some(0x80a2),
+        Some(0x80b1),
+        Some(0x80a1),
+        Some(0x80ab),
+        Some(0x80a9),
+        Some(0x80b4),
+        Some(0x80aa),
+        Some(0x80af),
+        Some(0x81e5),
+        Some(0x81fe),
+        Some(0x820d),
+        Some(0x82b3),
+        Some(0x829d),
+        Some(0x8299),
+        Some(0x82ad),
+        Some(0x82bd),
+        Some(0x829f),
+        Some(0x82b9),
+        Some(0x82b1),
+        Some(0x82ac),
+        Some(0x82a5),
+        Some(0x82af),
+        Some(0x82b8),
+        Some(0x82a3),
+        Some(0x82b0),
+        Some(0x82be),
+        Some(0x82b7),
+        Some(0x864e),
+        Some(0x8671),
+        Some(0x521d),
+        Some(0x8868),
+        Some(0x8ecb),
+        Some(0x8fce),
+        Some(0x8fd4),
+        Some(0x8fd1),
+        Some(0x90b5),
+        Some(0x90b8),
+        Some(0x90b1),
+        Some(0x90b6),
+        Some(0x91c7),
+        Some(0x91d1),
+        Some(0x9577),
+        Some(0x9580),
+        Some(0x961c),
+        Some(0x9640),
+        Some(0x963f),
+        Some(0x963b),
+        Some(0x9644),
+        None,
+    ];
+
+    const BASE_0XAB40: [Option<u16>; 192] = [
+        Some(0x9642),
+        Some(0x96b9),
+        Some(0x96e8),
+        Some(0x9752),
+        Some(0x975e),
+        Some(0x4e9f),
+        Some(0x4ead),
+        Some(0x4eae),
+        Some(0x4fe1),
+        Some(0x4fb5),
+        Some(0x4faf),
+        Some(0x4fbf),
+        Some(0x4fe0),
+        Some(0x4fd1),
+        Some(0x4fcf),
+        Some(0x4fdd),
+        Some(0x4fc3),
+        Some(0x4fb6),
+        Some(0x4fd8),
+        Some(0x4fdf),
+        Some(0x4fca),
+        Some(0x4fd7),
+        Some(0x4fae),
+        Some(0x4fd0),
+        Some(0x4fc4),
+        Some(0x4fc2),
+        Some(0x4fda),
+        Some(0x4fce),
+        Some(0x4fde),
+        Some(0x4fb7),
+        Some(0x5157),
+        Some(0x5192),
+        Some(0x5191),
+        Some(0x51a0),
+        Some(0x524e),
+        Some(0x5243),
+        Some(0x524a),
+        Some(0x524d),
+        Some(0x524c),
+        Some(0x524b),
+        Some(0x5247),
+        Some(0x52c7),
+        Some(0x52c9),
+        Some(0x52c3),
+        Some(0x52c1),
+        Some(0x530d),
+        Some(0x5357),
+        Some(0x537b),
+        Some(0x539a),
+        Some(0x53db),
+        Some(0x54ac),
+        Some(0x54c0),
+        Some(0x54a8),
+        Some(0x54ce),
+        Some(0x54c9),
+        Some(0x54b8),
+        Some(0x54a6),
+        Some(0x54b3),
+        Some(0x54c7),
+        Some(0x54c2),
+        Some(0x54bd),
+        Some(0x54aa),
+        Some(0x54c1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x54c4),
+        Some(0x54c8),
+        Some(0x54af),
+        Some(0x54ab),
+        Some(0x54b1),
+        Some(0x54bb),
+        Some(0x54a9),
+        Some(0x54a7),
+        Some(0x54bf),
+        Some(0x56ff),
+        Some(0x5782),
+        Some(0x578b),
+        Some(0x57a0),
+        Some(0x57a3),
+        Some(0x57a2),
+        Some(0x57ce),
+        Some(0x57ae),
+        Some(0x5793),
+        Some(0x5955),
+        Some(0x5951),
+        Some(0x594f),
+        Some(0x594e),
+        Some(0x5950),
+        Some(0x59dc),
+        Some(0x59d8),
+        Some(0x59ff),
+        Some(0x59e3),
+        Some(0x59e8),
+        Some(0x5a03),
+        Some(0x59e5),
+        Some(0x59ea),
+        Some(0x59da),
+        Some(0x59e6),
+        Some(0x5a01),
+        Some(0x59fb),
+        Some(0x5b69),
+        Some(0x5ba3),
+        Some(0x5ba6),
+        Some(0x5ba4),
+        Some(0x5ba2),
+        Some(0x5ba5),
+        Some(0x5c01),
+        Some(0x5c4e),
+        Some(0x5c4f),
+        Some(0x5c4d),
+        Some(0x5c4b),
+        Some(0x5cd9),
+        Some(0x5cd2),
+        Some(0x5df7),
+        Some(0x5e1d),
+        Some(0x5e25),
+        Some(0x5e1f),
+        Some(0x5e7d),
+        Some(0x5ea0),
+        Some(0x5ea6),
+        Some(0x5efa),
+        Some(0x5f08),
+        Some(0x5f2d),
+        Some(0x5f65),
+        Some(0x5f88),
+        Some(0x5f85),
+        Some(0x5f8a),
+        Some(0x5f8b),
+        Some(0x5f87),
+        Some(0x5f8c),
+        Some(0x5f89),
+        Some(0x6012),
+        Some(0x601d),
+        Some(0x6020),
+        Some(0x6025),
+        Some(0x600e),
+        Some(0x6028),
+        Some(0x604d),
+        Some(0x6070),
+        Some(0x6068),
+        Some(0x6062),
+        Some(0x6046),
+        Some(0x6043),
+        Some(0x606c),
+        Some(0x606b),
+        Some(0x606a),
+        Some(0x6064),
+        Some(0x6241),
+        Some(0x62dc),
+        Some(0x6316),
+        Some(0x6309),
+        Some(0x62fc),
+        Some(0x62ed),
+        Some(0x6301),
+        Some(0x62ee),
+        Some(0x62fd),
+        Some(0x6307),
+        Some(0x62f1),
+        Some(0x62f7),
+        None,
+    ];
+
+    const BASE_0XAC40: [Option<u16>; 192] = [
+        Some(0x62ef),
+        Some(0x62ec),
+        Some(0x62fe),
+        Some(0x62f4),
+        Some(0x6311),
+        Some(0x6302),
+        Some(0x653f),
+        Some(0x6545),
+        Some(0x65ab),
+        Some(0x65bd),
+        Some(0x65e2),
+        Some(0x6625),
+        Some(0x662d),
+        Some(0x6620),
+        Some(0x6627),
+        Some(0x662f),
+        Some(0x661f),
+        Some(0x6628),
+        Some(0x6631),
+        Some(0x6624),
+        Some(0x66f7),
+        Some(0x67ff),
+        Some(0x67d3),
+        Some(0x67f1),
+        Some(0x67d4),
+        Some(0x67d0),
+        Some(0x67ec),
+        Some(0x67b6),
+        Some(0x67af),
+        Some(0x67f5),
+        Some(0x67e9),
+        Some(0x67ef),
+        Some(0x67c4),
+        Some(0x67d1),
+        Some(0x67b4),
+        Some(0x67da),
+        Some(0x67e5),
+        Some(0x67b8),
+        Some(0x67cf),
+        Some(0x67de),
+        Some(0x67f3),
+        Some(0x67b0),
+        Some(0x67d9),
+        Some(0x67e2),
+        Some(0x67dd),
+        Some(0x67d2),
+        Some(0x6b6a),
+        Some(0x6b83),
+        Some(0x6b86),
+        Some(0x6bb5),
+        Some(0x6bd2),
+        Some(0x6bd7),
+        Some(0x6c1f),
+        Some(0x6cc9),
+        Some(0x6d0b),
+        Some(0x6d32),
+        Some(0x6d2a),
+        Some(0x6d41),
+        Some(0x6d25),
+        Some(0x6d0c),
+        Some(0x6d31),
+        Some(0x6d1e),
+        Some(0x6d17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6d3b),
+        Some(0x6d3d),
+        Some(0x6d3e),
+        Some(0x6d36),
+        Some(0x6d1b),
+        Some(0x6cf5),
+        Some(0x6d39),
+        Some(0x6d27),
+        Some(0x6d38),
+        Some(0x6d29),
+        Some(0x6d2e),
+        Some(0x6d35),
+        Some(0x6d0e),
+        Some(0x6d2b),
+        Some(0x70ab),
+        Some(0x70ba),
+        Some(0x70b3),
+        Some(0x70ac),
+        Some(0x70af),
+        Some(0x70ad),
+        Some(0x70b8),
+        Some(0x70ae),
+        Some(0x70a4),
+        Some(0x7230),
+        Some(0x7272),
+        Some(0x726f),
+        Some(0x7274),
+        Some(0x72e9),
+        Some(0x72e0),
+        Some(0x72e1),
+        Some(0x73b7),
+        Some(0x73ca),
+        Some(0x73bb),
+        Some(0x73b2),
+        Some(0x73cd),
+        Some(0x73c0),
+        Some(0x73b3),
+        Some(0x751a),
+        Some(0x752d),
+        Some(0x754f),
+        Some(0x754c),
+        Some(0x754e),
+        Some(0x754b),
+        Some(0x75ab),
+        Some(0x75a4),
+        Some(0x75a5),
+        Some(0x75a2),
+        Some(0x75a3),
+        Some(0x7678),
+        Some(0x7686),
+        Some(0x7687),
+        Some(0x7688),
+        Some(0x76c8),
+        Some(0x76c6),
+        Some(0x76c3),
+        Some(0x76c5),
+        Some(0x7701),
+        Some(0x76f9),
+        Some(0x76f8),
+        Some(0x7709),
+        Some(0x770b),
+        Some(0x76fe),
+        Some(0x76fc),
+        Some(0x7707),
+        Some(0x77dc),
+        Some(0x7802),
+        Some(0x7814),
+        Some(0x780c),
+        Some(0x780d),
+        Some(0x7946),
+        Some(0x7949),
+        Some(0x7948),
+        Some(0x7947),
+        Some(0x79b9),
+        Some(0x79ba),
+        Some(0x79d1),
+        Some(0x79d2),
+        Some(0x79cb),
+        Some(0x7a7f),
+        Some(0x7a81),
+        Some(0x7aff),
+        Some(0x7afd),
+        Some(0x7c7d),
+        Some(0x7d02),
+        Some(0x7d05),
+        Some(0x7d00),
+        Some(0x7d09),
+        Some(0x7d07),
+        Some(0x7d04),
+        Some(0x7d06),
+        Some(0x7f38),
+        Some(0x7f8e),
+        Some(0x7fbf),
+        Some(0x8004),
+        None,
+    ];
+
+    const BASE_0XAD40: [Option<u16>; 192] = [
+        Some(0x8010),
+        Some(0x800d),
+        Some(0x8011),
+        Some(0x8036),
+        Some(0x80d6),
+        Some(0x80e5),
+        Some(0x80da),
+        Some(0x80c3),
+        Some(0x80c4),
+        Some(0x80cc),
+        Some(0x80e1),
+        Some(0x80db),
+        Some(0x80ce),
+        Some(0x80de),
+        Some(0x80e4),
+        Some(0x80dd),
+        Some(0x81f4),
+        Some(0x8222),
+        Some(0x82e7),
+        Some(0x8303),
+        Some(0x8305),
+        Some(0x82e3),
+        Some(0x82db),
+        Some(0x82e6),
+        Some(0x8304),
+        Some(0x82e5),
+        Some(0x8302),
+        Some(0x8309),
+        Some(0x82d2),
+        Some(0x82d7),
+        Some(0x82f1),
+        Some(0x8301),
+        Some(0x82dc),
+        Some(0x82d4),
+        Some(0x82d1),
+        Some(0x82de),
+        Some(0x82d3),
+        Some(0x82df),
+        Some(0x82ef),
+        Some(0x8306),
+        Some(0x8650),
+        Some(0x8679),
+        Some(0x867b),
+        Some(0x867a),
+        Some(0x884d),
+        Some(0x886b),
+        Some(0x8981),
+        Some(0x89d4),
+        Some(0x8a08),
+        Some(0x8a02),
+        Some(0x8a03),
+        Some(0x8c9e),
+        Some(0x8ca0),
+        Some(0x8d74),
+        Some(0x8d73),
+        Some(0x8db4),
+        Some(0x8ecd),
+        Some(0x8ecc),
+        Some(0x8ff0),
+        Some(0x8fe6),
+        Some(0x8fe2),
+        Some(0x8fea),
+        Some(0x8fe5),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8fed),
+        Some(0x8feb),
+        Some(0x8fe4),
+        Some(0x8fe8),
+        Some(0x90ca),
+        Some(0x90ce),
+        Some(0x90c1),
+        Some(0x90c3),
+        Some(0x914b),
+        Some(0x914a),
+        Some(0x91cd),
+        Some(0x9582),
+        Some(0x9650),
+        Some(0x964b),
+        Some(0x964c),
+        Some(0x964d),
+        Some(0x9762),
+        Some(0x9769),
+        Some(0x97cb),
+        Some(0x97ed),
+        Some(0x97f3),
+        Some(0x9801),
+        Some(0x98a8),
+        Some(0x98db),
+        Some(0x98df),
+        Some(0x9996),
+        Some(0x9999),
+        Some(0x4e58),
+        Some(0x4eb3),
+        Some(0x500c),
+        Some(0x500d),
+        Some(0x5023),
+        Some(0x4fef),
+        Some(0x5026),
+        Some(0x5025),
+        Some(0x4ff8),
+        Some(0x5029),
+        Some(0x5016),
+        Some(0x5006),
+        Some(0x503c),
+        Some(0x501f),
+        Some(0x501a),
+        Some(0x5012),
+        Some(0x5011),
+        Some(0x4ffa),
+        Some(0x5000),
+        Some(0x5014),
+        Some(0x5028),
+        Some(0x4ff1),
+        Some(0x5021),
+        Some(0x500b),
+        Some(0x5019),
+        Some(0x5018),
+        Some(0x4ff3),
+        Some(0x4fee),
+        Some(0x502d),
+        Some(0x502a),
+        Some(0x4ffe),
+        Some(0x502b),
+        Some(0x5009),
+        Some(0x517c),
+        Some(0x51a4),
+        Some(0x51a5),
+        Some(0x51a2),
+        Some(0x51cd),
+        Some(0x51cc),
+        Some(0x51c6),
+        Some(0x51cb),
+        Some(0x5256),
+        Some(0x525c),
+        Some(0x5254),
+        Some(0x525b),
+        Some(0x525d),
+        Some(0x532a),
+        Some(0x537f),
+        Some(0x539f),
+        Some(0x539d),
+        Some(0x53df),
+        Some(0x54e8),
+        Some(0x5510),
+        Some(0x5501),
+        Some(0x5537),
+        Some(0x54fc),
+        Some(0x54e5),
+        Some(0x54f2),
+        Some(0x5506),
+        Some(0x54fa),
+        Some(0x5514),
+        Some(0x54e9),
+        Some(0x54ed),
+        Some(0x54e1),
+        Some(0x5509),
+        Some(0x54ee),
+        Some(0x54ea),
+        None,
+    ];
+
+    const BASE_0XAE40: [Option<u16>; 192] = [
+        Some(0x54e6),
+        Some(0x5527),
+        Some(0x5507),
+        Some(0x54fd),
+        Some(0x550f),
+        Some(0x5703),
+        Some(0x5704),
+        Some(0x57c2),
+        Some(0x57d4),
+        Some(0x57cb),
+        Some(0x57c3),
+        Some(0x5809),
+        Some(0x590f),
+        Some(0x5957),
+        Some(0x5958),
+        Some(0x595a),
+        Some(0x5a11),
+        Some(0x5a18),
+        Some(0x5a1c),
+        Some(0x5a1f),
+        Some(0x5a1b),
+        Some(0x5a13),
+        Some(0x59ec),
+        Some(0x5a20),
+        Some(0x5a23),
+        Some(0x5a29),
+        Some(0x5a25),
+        Some(0x5a0c),
+        Some(0x5a09),
+        Some(0x5b6b),
+        Some(0x5c58),
+        Some(0x5bb0),
+        Some(0x5bb3),
+        Some(0x5bb6),
+        Some(0x5bb4),
+        Some(0x5bae),
+        Some(0x5bb5),
+        Some(0x5bb9),
+        Some(0x5bb8),
+        Some(0x5c04),
+        Some(0x5c51),
+        Some(0x5c55),
+        Some(0x5c50),
+        Some(0x5ced),
+        Some(0x5cfd),
+        Some(0x5cfb),
+        Some(0x5cea),
+        Some(0x5ce8),
+        Some(0x5cf0),
+        Some(0x5cf6),
+        Some(0x5d01),
+        Some(0x5cf4),
+        Some(0x5dee),
+        Some(0x5e2d),
+        Some(0x5e2b),
+        Some(0x5eab),
+        Some(0x5ead),
+        Some(0x5ea7),
+        Some(0x5f31),
+        Some(0x5f92),
+        Some(0x5f91),
+        Some(0x5f90),
+        Some(0x6059),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6063),
+        Some(0x6065),
+        Some(0x6050),
+        Some(0x6055),
+        Some(0x606d),
+        Some(0x6069),
+        Some(0x606f),
+        Some(0x6084),
+        Some(0x609f),
+        Some(0x609a),
+        Some(0x608d),
+        Some(0x6094),
+        Some(0x608c),
+        Some(0x6085),
+        Some(0x6096),
+        Some(0x6247),
+        Some(0x62f3),
+        Some(0x6308),
+        Some(0x62ff),
+        Some(0x634e),
+        Some(0x633e),
+        Some(0x632f),
+        Some(0x6355),
+        Some(0x6342),
+        Some(0x6346),
+        Some(0x634f),
+        Some(0x6349),
+        Some(0x633a),
+        Some(0x6350),
+        Some(0x633d),
+        Some(0x632a),
+        Some(0x632b),
+        Some(0x6328),
+        Some(0x634d),
+        Some(0x634c),
+        Some(0x6548),
+        Some(0x6549),
+        Some(0x6599),
+        Some(0x65c1),
+        Some(0x65c5),
+        Some(0x6642),
+        Some(0x6649),
+        Some(0x664f),
+        Some(0x6643),
+        Some(0x6652),
+        Some(0x664c),
+        Some(0x6645),
+        Some(0x6641),
+        Some(0x66f8),
+        Some(0x6714),
+        Some(0x6715),
+        Some(0x6717),
+        Some(0x6821),
+        Some(0x6838),
+        Some(0x6848),
+        Some(0x6846),
+        Some(0x6853),
+        Some(0x6839),
+        Some(0x6842),
+        Some(0x6854),
+        Some(0x6829),
+        Some(0x68b3),
+        Some(0x6817),
+        Some(0x684c),
+        Some(0x6851),
+        Some(0x683d),
+        Some(0x67f4),
+        Some(0x6850),
+        Some(0x6840),
+        Some(0x683c),
+        Some(0x6843),
+        Some(0x682a),
+        Some(0x6845),
+        Some(0x6813),
+        Some(0x6818),
+        Some(0x6841),
+        Some(0x6b8a),
+        Some(0x6b89),
+        Some(0x6bb7),
+        Some(0x6c23),
+        Some(0x6c27),
+        Some(0x6c28),
+        Some(0x6c26),
+        Some(0x6c24),
+        Some(0x6cf0),
+        Some(0x6d6a),
+        Some(0x6d95),
+        Some(0x6d88),
+        Some(0x6d87),
+        Some(0x6d66),
+        Some(0x6d78),
+        Some(0x6d77),
+        Some(0x6d59),
+        Some(0x6d93),
+        None,
+    ];
+
+    const BASE_0XAF40: [Option<u16>; 192] = [
+        Some(0x6d6c),
+        Some(0x6d89),
+        Some(0x6d6e),
+        Some(0x6d5a),
+        Some(0x6d74),
+        Some(0x6d69),
+        Some(0x6d8c),
+        Some(0x6d8a),
+        Some(0x6d79),
+        Some(0x6d85),
+        Some(0x6d65),
+        Some(0x6d94),
+        Some(0x70ca),
+        Some(0x70d8),
+        Some(0x70e4),
+        Some(0x70d9),
+        Some(0x70c8),
+        Some(0x70cf),
+        Some(0x7239),
+        Some(0x7279),
+        Some(0x72fc),
+        Some(0x72f9),
+        Some(0x72fd),
+        Some(0x72f8),
+        Some(0x72f7),
+        Some(0x7386),
+        Some(0x73ed),
+        Some(0x7409),
+        Some(0x73ee),
+        Some(0x73e0),
+        Some(0x73ea),
+        Some(0x73de),
+        Some(0x7554),
+        Some(0x755d),
+        Some(0x755c),
+        Some(0x755a),
+        Some(0x7559),
+        Some(0x75be),
+        Some(0x75c5),
+        Some(0x75c7),
+        Some(0x75b2),
+        Some(0x75b3),
+        Some(0x75bd),
+        Some(0x75bc),
+        Some(0x75b9),
+        Some(0x75c2),
+        Some(0x75b8),
+        Some(0x768b),
+        Some(0x76b0),
+        Some(0x76ca),
+        Some(0x76cd),
+        Some(0x76ce),
+        Some(0x7729),
+        Some(0x771f),
+        Some(0x7720),
+        Some(0x7728),
+        Some(0x77e9),
+        Some(0x7830),
+        Some(0x7827),
+        Some(0x7838),
+        Some(0x781d),
+        Some(0x7834),
+        Some(0x7837),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7825),
+        Some(0x782d),
+        Some(0x7820),
+        Some(0x781f),
+        Some(0x7832),
+        Some(0x7955),
+        Some(0x7950),
+        Some(0x7960),
+        Some(0x795f),
+        Some(0x7956),
+        Some(0x795e),
+        Some(0x795d),
+        Some(0x7957),
+        Some(0x795a),
+        Some(0x79e4),
+        Some(0x79e3),
+        Some(0x79e7),
+        Some(0x79df),
+        Some(0x79e6),
+        Some(0x79e9),
+        Some(0x79d8),
+        Some(0x7a84),
+        Some(0x7a88),
+        Some(0x7ad9),
+        Some(0x7b06),
+        Some(0x7b11),
+        Some(0x7c89),
+        Some(0x7d21),
+        Some(0x7d17),
+        Some(0x7d0b),
+        Some(0x7d0a),
+        Some(0x7d20),
+        Some(0x7d22),
+        Some(0x7d14),
+        Some(0x7d10),
+        Some(0x7d15),
+        Some(0x7d1a),
+        Some(0x7d1c),
+        Some(0x7d0d),
+        Some(0x7d19),
+        Some(0x7d1b),
+        Some(0x7f3a),
+        Some(0x7f5f),
+        Some(0x7f94),
+        Some(0x7fc5),
+        Some(0x7fc1),
+        Some(0x8006),
+        Some(0x8018),
+        Some(0x8015),
+        Some(0x8019),
+        Some(0x8017),
+        Some(0x803d),
+        Some(0x803f),
+        Some(0x80f1),
+        Some(0x8102),
+        Some(0x80f0),
+        Some(0x8105),
+        Some(0x80ed),
+        Some(0x80f4),
+        Some(0x8106),
+        Some(0x80f8),
+        Some(0x80f3),
+        Some(0x8108),
+        Some(0x80fd),
+        Some(0x810a),
+        Some(0x80fc),
+        Some(0x80ef),
+        Some(0x81ed),
+        Some(0x81ec),
+        Some(0x8200),
+        Some(0x8210),
+        Some(0x822a),
+        Some(0x822b),
+        Some(0x8228),
+        Some(0x822c),
+        Some(0x82bb),
+        Some(0x832b),
+        Some(0x8352),
+        Some(0x8354),
+        Some(0x834a),
+        Some(0x8338),
+        Some(0x8350),
+        Some(0x8349),
+        Some(0x8335),
+        Some(0x8334),
+        Some(0x834f),
+        Some(0x8332),
+        Some(0x8339),
+        Some(0x8336),
+        Some(0x8317),
+        Some(0x8340),
+        Some(0x8331),
+        Some(0x8328),
+        Some(0x8343),
+        None,
+    ];
+
+    const BASE_0XB040: [Option<u16>; 192] = [
+        Some(0x8654),
+        Some(0x868a),
+        Some(0x86aa),
+        Some(0x8693),
+        Some(0x86a4),
+        Some(0x86a9),
+        Some(0x868c),
+        Some(0x86a3),
+        Some(0x869c),
+        Some(0x8870),
+        Some(0x8877),
+        Some(0x8881),
+        Some(0x8882),
+        Some(0x887d),
+        Some(0x8879),
+        Some(0x8a18),
+        Some(0x8a10),
+        Some(0x8a0e),
+        Some(0x8a0c),
+        Some(0x8a15),
+        Some(0x8a0a),
+        Some(0x8a17),
+        Some(0x8a13),
+        Some(0x8a16),
+        Some(0x8a0f),
+        Some(0x8a11),
+        Some(0x8c48),
+        Some(0x8c7a),
+        Some(0x8c79),
+        Some(0x8ca1),
+        Some(0x8ca2),
+        Some(0x8d77),
+        Some(0x8eac),
+        Some(0x8ed2),
+        Some(0x8ed4),
+        Some(0x8ecf),
+        Some(0x8fb1),
+        Some(0x9001),
+        Some(0x9006),
+        Some(0x8ff7),
+        Some(0x9000),
+        Some(0x8ffa),
+        Some(0x8ff4),
+        Some(0x9003),
+        Some(0x8ffd),
+        Some(0x9005),
+        Some(0x8ff8),
+        Some(0x9095),
+        Some(0x90e1),
+        Some(0x90dd),
+        Some(0x90e2),
+        Some(0x9152),
+        Some(0x914d),
+        Some(0x914c),
+        Some(0x91d8),
+        Some(0x91dd),
+        Some(0x91d7),
+        Some(0x91dc),
+        Some(0x91d9),
+        Some(0x9583),
+        Some(0x9662),
+        Some(0x9663),
+        Some(0x9661),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x965b),
+        Some(0x965d),
+        Some(0x9664),
+        Some(0x9658),
+        Some(0x965e),
+        Some(0x96bb),
+        Some(0x98e2),
+        Some(0x99ac),
+        Some(0x9aa8),
+        Some(0x9ad8),
+        Some(0x9b25),
+        Some(0x9b32),
+        Some(0x9b3c),
+        Some(0x4e7e),
+        Some(0x507a),
+        Some(0x507d),
+        Some(0x505c),
+        Some(0x5047),
+        Some(0x5043),
+        Some(0x504c),
+        Some(0x505a),
+        Some(0x5049),
+        Some(0x5065),
+        Some(0x5076),
+        Some(0x504e),
+        Some(0x5055),
+        Some(0x5075),
+        Some(0x5074),
+        Some(0x5077),
+        Some(0x504f),
+        Some(0x500f),
+        Some(0x506f),
+        Some(0x506d),
+        Some(0x515c),
+        Some(0x5195),
+        Some(0x51f0),
+        Some(0x526a),
+        Some(0x526f),
+        Some(0x52d2),
+        Some(0x52d9),
+        Some(0x52d8),
+        Some(0x52d5),
+        Some(0x5310),
+        Some(0x530f),
+        Some(0x5319),
+        Some(0x533f),
+        Some(0x5340),
+        Some(0x533e),
+        Some(0x53c3),
+        Some(0x66fc),
+        Some(0x5546),
+        Some(0x556a),
+        Some(0x5566),
+        Some(0x5544),
+        Some(0x555e),
+        Some(0x5561),
+        Some(0x5543),
+        Some(0x554a),
+        Some(0x5531),
+        Some(0x5556),
+        Some(0x554f),
+        Some(0x5555),
+        Some(0x552f),
+        Some(0x5564),
+        Some(0x5538),
+        Some(0x552e),
+        Some(0x555c),
+        Some(0x552c),
+        Some(0x5563),
+        Some(0x5533),
+        Some(0x5541),
+        Some(0x5557),
+        Some(0x5708),
+        Some(0x570b),
+        Some(0x5709),
+        Some(0x57df),
+        Some(0x5805),
+        Some(0x580a),
+        Some(0x5806),
+        Some(0x57e0),
+        Some(0x57e4),
+        Some(0x57fa),
+        Some(0x5802),
+        Some(0x5835),
+        Some(0x57f7),
+        Some(0x57f9),
+        Some(0x5920),
+        Some(0x5962),
+        Some(0x5a36),
+        Some(0x5a41),
+        Some(0x5a49),
+        Some(0x5a66),
+        Some(0x5a6a),
+        Some(0x5a40),
+        None,
+    ];
+
+    const BASE_0XB140: [Option<u16>; 192] = [
+        Some(0x5a3c),
+        Some(0x5a62),
+        Some(0x5a5a),
+        Some(0x5a46),
+        Some(0x5a4a),
+        Some(0x5b70),
+        Some(0x5bc7),
+        Some(0x5bc5),
+        Some(0x5bc4),
+        Some(0x5bc2),
+        Some(0x5bbf),
+        Some(0x5bc6),
+        Some(0x5c09),
+        Some(0x5c08),
+        Some(0x5c07),
+        Some(0x5c60),
+        Some(0x5c5c),
+        Some(0x5c5d),
+        Some(0x5d07),
+        Some(0x5d06),
+        Some(0x5d0e),
+        Some(0x5d1b),
+        Some(0x5d16),
+        Some(0x5d22),
+        Some(0x5d11),
+        Some(0x5d29),
+        Some(0x5d14),
+        Some(0x5d19),
+        Some(0x5d24),
+        Some(0x5d27),
+        Some(0x5d17),
+        Some(0x5de2),
+        Some(0x5e38),
+        Some(0x5e36),
+        Some(0x5e33),
+        Some(0x5e37),
+        Some(0x5eb7),
+        Some(0x5eb8),
+        Some(0x5eb6),
+        Some(0x5eb5),
+        Some(0x5ebe),
+        Some(0x5f35),
+        Some(0x5f37),
+        Some(0x5f57),
+        Some(0x5f6c),
+        Some(0x5f69),
+        Some(0x5f6b),
+        Some(0x5f97),
+        Some(0x5f99),
+        Some(0x5f9e),
+        Some(0x5f98),
+        Some(0x5fa1),
+        Some(0x5fa0),
+        Some(0x5f9c),
+        Some(0x607f),
+        Some(0x60a3),
+        Some(0x6089),
+        Some(0x60a0),
+        Some(0x60a8),
+        Some(0x60cb),
+        Some(0x60b4),
+        Some(0x60e6),
+        Some(0x60bd),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x60c5),
+        Some(0x60bb),
+        Some(0x60b5),
+        Some(0x60dc),
+        Some(0x60bc),
+        Some(0x60d8),
+        Some(0x60d5),
+        Some(0x60c6),
+        Some(0x60df),
+        Some(0x60b8),
+        Some(0x60da),
+        Some(0x60c7),
+        Some(0x621a),
+        Some(0x621b),
+        Some(0x6248),
+        Some(0x63a0),
+        Some(0x63a7),
+        Some(0x6372),
+        Some(0x6396),
+        Some(0x63a2),
+        Some(0x63a5),
+        Some(0x6377),
+        Some(0x6367),
+        Some(0x6398),
+        Some(0x63aa),
+        Some(0x6371),
+        Some(0x63a9),
+        Some(0x6389),
+        Some(0x6383),
+        Some(0x639b),
+        Some(0x636b),
+        Some(0x63a8),
+        Some(0x6384),
+        Some(0x6388),
+        Some(0x6399),
+        Some(0x63a1),
+        Some(0x63ac),
+        Some(0x6392),
+        Some(0x638f),
+        Some(0x6380),
+        Some(0x637b),
+        Some(0x6369),
+        Some(0x6368),
+        Some(0x637a),
+        Some(0x655d),
+        Some(0x6556),
+        Some(0x6551),
+        Some(0x6559),
+        Some(0x6557),
+        Some(0x555f),
+        Some(0x654f),
+        Some(0x6558),
+        Some(0x6555),
+        Some(0x6554),
+        Some(0x659c),
+        Some(0x659b),
+        Some(0x65ac),
+        Some(0x65cf),
+        Some(0x65cb),
+        Some(0x65cc),
+        Some(0x65ce),
+        Some(0x665d),
+        Some(0x665a),
+        Some(0x6664),
+        Some(0x6668),
+        Some(0x6666),
+        Some(0x665e),
+        Some(0x66f9),
+        Some(0x52d7),
+        Some(0x671b),
+        Some(0x6881),
+        Some(0x68af),
+        Some(0x68a2),
+        Some(0x6893),
+        Some(0x68b5),
+        Some(0x687f),
+        Some(0x6876),
+        Some(0x68b1),
+        Some(0x68a7),
+        Some(0x6897),
+        Some(0x68b0),
+        Some(0x6883),
+        Some(0x68c4),
+        Some(0x68ad),
+        Some(0x6886),
+        Some(0x6885),
+        Some(0x6894),
+        Some(0x689d),
+        Some(0x68a8),
+        Some(0x689f),
+        Some(0x68a1),
+        Some(0x6882),
+        Some(0x6b32),
+        Some(0x6bba),
+        None,
+    ];
+
+    const BASE_0XB240: [Option<u16>; 192] = [
+        Some(0x6beb),
+        Some(0x6bec),
+        Some(0x6c2b),
+        Some(0x6d8e),
+        Some(0x6dbc),
+        Some(0x6df3),
+        Some(0x6dd9),
+        Some(0x6db2),
+        Some(0x6de1),
+        Some(0x6dcc),
+        Some(0x6de4),
+        Some(0x6dfb),
+        Some(0x6dfa),
+        Some(0x6e05),
+        Some(0x6dc7),
+        Some(0x6dcb),
+        Some(0x6daf),
+        Some(0x6dd1),
+        Some(0x6dae),
+        Some(0x6dde),
+        Some(0x6df9),
+        Some(0x6db8),
+        Some(0x6df7),
+        Some(0x6df5),
+        Some(0x6dc5),
+        Some(0x6dd2),
+        Some(0x6e1a),
+        Some(0x6db5),
+        Some(0x6dda),
+        Some(0x6deb),
+        Some(0x6dd8),
+        Some(0x6dea),
+        Some(0x6df1),
+        Some(0x6dee),
+        Some(0x6de8),
+        Some(0x6dc6),
+        Some(0x6dc4),
+        Some(0x6daa),
+        Some(0x6dec),
+        Some(0x6dbf),
+        Some(0x6de6),
+        Some(0x70f9),
+        Some(0x7109),
+        Some(0x710a),
+        Some(0x70fd),
+        Some(0x70ef),
+        Some(0x723d),
+        Some(0x727d),
+        Some(0x7281),
+        Some(0x731c),
+        Some(0x731b),
+        Some(0x7316),
+        Some(0x7313),
+        Some(0x7319),
+        Some(0x7387),
+        Some(0x7405),
+        Some(0x740a),
+        Some(0x7403),
+        Some(0x7406),
+        Some(0x73fe),
+        Some(0x740d),
+        Some(0x74e0),
+        Some(0x74f6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x74f7),
+        Some(0x751c),
+        Some(0x7522),
+        Some(0x7565),
+        Some(0x7566),
+        Some(0x7562),
+        Some(0x7570),
+        Some(0x758f),
+        Some(0x75d4),
+        Some(0x75d5),
+        Some(0x75b5),
+        Some(0x75ca),
+        Some(0x75cd),
+        Some(0x768e),
+        Some(0x76d4),
+        Some(0x76d2),
+        Some(0x76db),
+        Some(0x7737),
+        Some(0x773e),
+        Some(0x773c),
+        Some(0x7736),
+        Some(0x7738),
+        Some(0x773a),
+        Some(0x786b),
+        Some(0x7843),
+        Some(0x784e),
+        Some(0x7965),
+        Some(0x7968),
+        Some(0x796d),
+        Some(0x79fb),
+        Some(0x7a92),
+        Some(0x7a95),
+        Some(0x7b20),
+        Some(0x7b28),
+        Some(0x7b1b),
+        Some(0x7b2c),
+        Some(0x7b26),
+        Some(0x7b19),
+        Some(0x7b1e),
+        Some(0x7b2e),
+        Some(0x7c92),
+        Some(0x7c97),
+        Some(0x7c95),
+        Some(0x7d46),
+        Some(0x7d43),
+        Some(0x7d71),
+        Some(0x7d2e),
+        Some(0x7d39),
+        Some(0x7d3c),
+        Some(0x7d40),
+        Some(0x7d30),
+        Some(0x7d33),
+        Some(0x7d44),
+        Some(0x7d2f),
+        Some(0x7d42),
+        Some(0x7d32),
+        Some(0x7d31),
+        Some(0x7f3d),
+        Some(0x7f9e),
+        Some(0x7f9a),
+        Some(0x7fcc),
+        Some(0x7fce),
+        Some(0x7fd2),
+        Some(0x801c),
+        Some(0x804a),
+        Some(0x8046),
+        Some(0x812f),
+        Some(0x8116),
+        Some(0x8123),
+        Some(0x812b),
+        Some(0x8129),
+        Some(0x8130),
+        Some(0x8124),
+        Some(0x8202),
+        Some(0x8235),
+        Some(0x8237),
+        Some(0x8236),
+        Some(0x8239),
+        Some(0x838e),
+        Some(0x839e),
+        Some(0x8398),
+        Some(0x8378),
+        Some(0x83a2),
+        Some(0x8396),
+        Some(0x83bd),
+        Some(0x83ab),
+        Some(0x8392),
+        Some(0x838a),
+        Some(0x8393),
+        Some(0x8389),
+        Some(0x83a0),
+        Some(0x8377),
+        Some(0x837b),
+        Some(0x837c),
+        None,
+    ];
+
+    const BASE_0XB340: [Option<u16>; 192] = [
+        Some(0x8386),
+        Some(0x83a7),
+        Some(0x8655),
+        Some(0x5f6a),
+        Some(0x86c7),
+        Some(0x86c0),
+        Some(0x86b6),
+        Some(0x86c4),
+        Some(0x86b5),
+        Some(0x86c6),
+        Some(0x86cb),
+        Some(0x86b1),
+        Some(0x86af),
+        Some(0x86c9),
+        Some(0x8853),
+        Some(0x889e),
+        Some(0x8888),
+        Some(0x88ab),
+        Some(0x8892),
+        Some(0x8896),
+        Some(0x888d),
+        Some(0x888b),
+        Some(0x8993),
+        Some(0x898f),
+        Some(0x8a2a),
+        Some(0x8a1d),
+        Some(0x8a23),
+        Some(0x8a25),
+        Some(0x8a31),
+        Some(0x8a2d),
+        Some(0x8a1f),
+        Some(0x8a1b),
+        Some(0x8a22),
+        Some(0x8c49),
+        Some(0x8c5a),
+        Some(0x8ca9),
+        Some(0x8cac),
+        Some(0x8cab),
+        Some(0x8ca8),
+        Some(0x8caa),
+        Some(0x8ca7),
+        Some(0x8d67),
+        Some(0x8d66),
+        Some(0x8dbe),
+        Some(0x8dba),
+        Some(0x8edb),
+        Some(0x8edf),
+        Some(0x9019),
+        Some(0x900d),
+        Some(0x901a),
+        Some(0x9017),
+        Some(0x9023),
+        Some(0x901f),
+        Some(0x901d),
+        Some(0x9010),
+        Some(0x9015),
+        Some(0x901e),
+        Some(0x9020),
+        Some(0x900f),
+        Some(0x9022),
+        Some(0x9016),
+        Some(0x901b),
+        Some(0x9014),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x90e8),
+        Some(0x90ed),
+        Some(0x90fd),
+        Some(0x9157),
+        Some(0x91ce),
+        Some(0x91f5),
+        Some(0x91e6),
+        Some(0x91e3),
+        Some(0x91e7),
+        Some(0x91ed),
+        Some(0x91e9),
+        Some(0x9589),
+        Some(0x966a),
+        Some(0x9675),
+        Some(0x9673),
+        Some(0x9678),
+        Some(0x9670),
+        Some(0x9674),
+        Some(0x9676),
+        Some(0x9677),
+        Some(0x966c),
+        Some(0x96c0),
+        Some(0x96ea),
+        Some(0x96e9),
+        Some(0x7ae0),
+        Some(0x7adf),
+        Some(0x9802),
+        Some(0x9803),
+        Some(0x9b5a),
+        Some(0x9ce5),
+        Some(0x9e75),
+        Some(0x9e7f),
+        Some(0x9ea5),
+        Some(0x9ebb),
+        Some(0x50a2),
+        Some(0x508d),
+        Some(0x5085),
+        Some(0x5099),
+        Some(0x5091),
+        Some(0x5080),
+        Some(0x5096),
+        Some(0x5098),
+        Some(0x509a),
+        Some(0x6700),
+        Some(0x51f1),
+        Some(0x5272),
+        Some(0x5274),
+        Some(0x5275),
+        Some(0x5269),
+        Some(0x52de),
+        Some(0x52dd),
+        Some(0x52db),
+        Some(0x535a),
+        Some(0x53a5),
+        Some(0x557b),
+        Some(0x5580),
+        Some(0x55a7),
+        Some(0x557c),
+        Some(0x558a),
+        Some(0x559d),
+        Some(0x5598),
+        Some(0x5582),
+        Some(0x559c),
+        Some(0x55aa),
+        Some(0x5594),
+        Some(0x5587),
+        Some(0x558b),
+        Some(0x5583),
+        Some(0x55b3),
+        Some(0x55ae),
+        Some(0x559f),
+        Some(0x553e),
+        Some(0x55b2),
+        Some(0x559a),
+        Some(0x55bb),
+        Some(0x55ac),
+        Some(0x55b1),
+        Some(0x557e),
+        Some(0x5589),
+        Some(0x55ab),
+        Some(0x5599),
+        Some(0x570d),
+        Some(0x582f),
+        Some(0x582a),
+        Some(0x5834),
+        Some(0x5824),
+        Some(0x5830),
+        Some(0x5831),
+        Some(0x5821),
+        Some(0x581d),
+        Some(0x5820),
+        Some(0x58f9),
+        Some(0x58fa),
+        Some(0x5960),
+        None,
+    ];
+
+    const BASE_0XB440: [Option<u16>; 192] = [
+        Some(0x5a77),
+        Some(0x5a9a),
+        Some(0x5a7f),
+        Some(0x5a92),
+        Some(0x5a9b),
+        Some(0x5aa7),
+        Some(0x5b73),
+        Some(0x5b71),
+        Some(0x5bd2),
+        Some(0x5bcc),
+        Some(0x5bd3),
+        Some(0x5bd0),
+        Some(0x5c0a),
+        Some(0x5c0b),
+        Some(0x5c31),
+        Some(0x5d4c),
+        Some(0x5d50),
+        Some(0x5d34),
+        Some(0x5d47),
+        Some(0x5dfd),
+        Some(0x5e45),
+        Some(0x5e3d),
+        Some(0x5e40),
+        Some(0x5e43),
+        Some(0x5e7e),
+        Some(0x5eca),
+        Some(0x5ec1),
+        Some(0x5ec2),
+        Some(0x5ec4),
+        Some(0x5f3c),
+        Some(0x5f6d),
+        Some(0x5fa9),
+        Some(0x5faa),
+        Some(0x5fa8),
+        Some(0x60d1),
+        Some(0x60e1),
+        Some(0x60b2),
+        Some(0x60b6),
+        Some(0x60e0),
+        Some(0x611c),
+        Some(0x6123),
+        Some(0x60fa),
+        Some(0x6115),
+        Some(0x60f0),
+        Some(0x60fb),
+        Some(0x60f4),
+        Some(0x6168),
+        Some(0x60f1),
+        Some(0x610e),
+        Some(0x60f6),
+        Some(0x6109),
+        Some(0x6100),
+        Some(0x6112),
+        Some(0x621f),
+        Some(0x6249),
+        Some(0x63a3),
+        Some(0x638c),
+        Some(0x63cf),
+        Some(0x63c0),
+        Some(0x63e9),
+        Some(0x63c9),
+        Some(0x63c6),
+        Some(0x63cd),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x63d2),
+        Some(0x63e3),
+        Some(0x63d0),
+        Some(0x63e1),
+        Some(0x63d6),
+        Some(0x63ed),
+        Some(0x63ee),
+        Some(0x6376),
+        Some(0x63f4),
+        Some(0x63ea),
+        Some(0x63db),
+        Some(0x6452),
+        Some(0x63da),
+        Some(0x63f9),
+        Some(0x655e),
+        Some(0x6566),
+        Some(0x6562),
+        Some(0x6563),
+        Some(0x6591),
+        Some(0x6590),
+        Some(0x65af),
+        Some(0x666e),
+        Some(0x6670),
+        Some(0x6674),
+        Some(0x6676),
+        Some(0x666f),
+        Some(0x6691),
+        Some(0x667a),
+        Some(0x667e),
+        Some(0x6677),
+        Some(0x66fe),
+        Some(0x66ff),
+        Some(0x671f),
+        Some(0x671d),
+        Some(0x68fa),
+        Some(0x68d5),
+        Some(0x68e0),
+        Some(0x68d8),
+        Some(0x68d7),
+        Some(0x6905),
+        Some(0x68df),
+        Some(0x68f5),
+        Some(0x68ee),
+        Some(0x68e7),
+        Some(0x68f9),
+        Some(0x68d2),
+        Some(0x68f2),
+        Some(0x68e3),
+        Some(0x68cb),
+        Some(0x68cd),
+        Some(0x690d),
+        Some(0x6912),
+        Some(0x690e),
+        Some(0x68c9),
+        Some(0x68da),
+        Some(0x696e),
+        Some(0x68fb),
+        Some(0x6b3e),
+        Some(0x6b3a),
+        Some(0x6b3d),
+        Some(0x6b98),
+        Some(0x6b96),
+        Some(0x6bbc),
+        Some(0x6bef),
+        Some(0x6c2e),
+        Some(0x6c2f),
+        Some(0x6c2c),
+        Some(0x6e2f),
+        Some(0x6e38),
+        Some(0x6e54),
+        Some(0x6e21),
+        Some(0x6e32),
+        Some(0x6e67),
+        Some(0x6e4a),
+        Some(0x6e20),
+        Some(0x6e25),
+        Some(0x6e23),
+        Some(0x6e1b),
+        Some(0x6e5b),
+        Some(0x6e58),
+        Some(0x6e24),
+        Some(0x6e56),
+        Some(0x6e6e),
+        Some(0x6e2d),
+        Some(0x6e26),
+        Some(0x6e6f),
+        Some(0x6e34),
+        Some(0x6e4d),
+        Some(0x6e3a),
+        Some(0x6e2c),
+        Some(0x6e43),
+        Some(0x6e1d),
+        Some(0x6e3e),
+        Some(0x6ecb),
+        None,
+    ];
+
+    const BASE_0XB540: [Option<u16>; 192] = [
+        Some(0x6e89),
+        Some(0x6e19),
+        Some(0x6e4e),
+        Some(0x6e63),
+        Some(0x6e44),
+        Some(0x6e72),
+        Some(0x6e69),
+        Some(0x6e5f),
+        Some(0x7119),
+        Some(0x711a),
+        Some(0x7126),
+        Some(0x7130),
+        Some(0x7121),
+        Some(0x7136),
+        Some(0x716e),
+        Some(0x711c),
+        Some(0x724c),
+        Some(0x7284),
+        Some(0x7280),
+        Some(0x7336),
+        Some(0x7325),
+        Some(0x7334),
+        Some(0x7329),
+        Some(0x743a),
+        Some(0x742a),
+        Some(0x7433),
+        Some(0x7422),
+        Some(0x7425),
+        Some(0x7435),
+        Some(0x7436),
+        Some(0x7434),
+        Some(0x742f),
+        Some(0x741b),
+        Some(0x7426),
+        Some(0x7428),
+        Some(0x7525),
+        Some(0x7526),
+        Some(0x756b),
+        Some(0x756a),
+        Some(0x75e2),
+        Some(0x75db),
+        Some(0x75e3),
+        Some(0x75d9),
+        Some(0x75d8),
+        Some(0x75de),
+        Some(0x75e0),
+        Some(0x767b),
+        Some(0x767c),
+        Some(0x7696),
+        Some(0x7693),
+        Some(0x76b4),
+        Some(0x76dc),
+        Some(0x774f),
+        Some(0x77ed),
+        Some(0x785d),
+        Some(0x786c),
+        Some(0x786f),
+        Some(0x7a0d),
+        Some(0x7a08),
+        Some(0x7a0b),
+        Some(0x7a05),
+        Some(0x7a00),
+        Some(0x7a98),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7a97),
+        Some(0x7a96),
+        Some(0x7ae5),
+        Some(0x7ae3),
+        Some(0x7b49),
+        Some(0x7b56),
+        Some(0x7b46),
+        Some(0x7b50),
+        Some(0x7b52),
+        Some(0x7b54),
+        Some(0x7b4d),
+        Some(0x7b4b),
+        Some(0x7b4f),
+        Some(0x7b51),
+        Some(0x7c9f),
+        Some(0x7ca5),
+        Some(0x7d5e),
+        Some(0x7d50),
+        Some(0x7d68),
+        Some(0x7d55),
+        Some(0x7d2b),
+        Some(0x7d6e),
+        Some(0x7d72),
+        Some(0x7d61),
+        Some(0x7d66),
+        Some(0x7d62),
+        Some(0x7d70),
+        Some(0x7d73),
+        Some(0x5584),
+        Some(0x7fd4),
+        Some(0x7fd5),
+        Some(0x800b),
+        Some(0x8052),
+        Some(0x8085),
+        Some(0x8155),
+        Some(0x8154),
+        Some(0x814b),
+        Some(0x8151),
+        Some(0x814e),
+        Some(0x8139),
+        Some(0x8146),
+        Some(0x813e),
+        Some(0x814c),
+        Some(0x8153),
+        Some(0x8174),
+        Some(0x8212),
+        Some(0x821c),
+        Some(0x83e9),
+        Some(0x8403),
+        Some(0x83f8),
+        Some(0x840d),
+        Some(0x83e0),
+        Some(0x83c5),
+        Some(0x840b),
+        Some(0x83c1),
+        Some(0x83ef),
+        Some(0x83f1),
+        Some(0x83f4),
+        Some(0x8457),
+        Some(0x840a),
+        Some(0x83f0),
+        Some(0x840c),
+        Some(0x83cc),
+        Some(0x83fd),
+        Some(0x83f2),
+        Some(0x83ca),
+        Some(0x8438),
+        Some(0x840e),
+        Some(0x8404),
+        Some(0x83dc),
+        Some(0x8407),
+        Some(0x83d4),
+        Some(0x83df),
+        Some(0x865b),
+        Some(0x86df),
+        Some(0x86d9),
+        Some(0x86ed),
+        Some(0x86d4),
+        Some(0x86db),
+        Some(0x86e4),
+        Some(0x86d0),
+        Some(0x86de),
+        Some(0x8857),
+        Some(0x88c1),
+        Some(0x88c2),
+        Some(0x88b1),
+        Some(0x8983),
+        Some(0x8996),
+        Some(0x8a3b),
+        Some(0x8a60),
+        Some(0x8a55),
+        Some(0x8a5e),
+        Some(0x8a3c),
+        Some(0x8a41),
+        None,
+    ];
+
+    const BASE_0XB640: [Option<u16>; 192] = [
+        Some(0x8a54),
+        Some(0x8a5b),
+        Some(0x8a50),
+        Some(0x8a46),
+        Some(0x8a34),
+        Some(0x8a3a),
+        Some(0x8a36),
+        Some(0x8a56),
+        Some(0x8c61),
+        Some(0x8c82),
+        Some(0x8caf),
+        Some(0x8cbc),
+        Some(0x8cb3),
+        Some(0x8cbd),
+        Some(0x8cc1),
+        Some(0x8cbb),
+        Some(0x8cc0),
+        Some(0x8cb4),
+        Some(0x8cb7),
+        Some(0x8cb6),
+        Some(0x8cbf),
+        Some(0x8cb8),
+        Some(0x8d8a),
+        Some(0x8d85),
+        Some(0x8d81),
+        Some(0x8dce),
+        Some(0x8ddd),
+        Some(0x8dcb),
+        Some(0x8dda),
+        Some(0x8dd1),
+        Some(0x8dcc),
+        Some(0x8ddb),
+        Some(0x8dc6),
+        Some(0x8efb),
+        Some(0x8ef8),
+        Some(0x8efc),
+        Some(0x8f9c),
+        Some(0x902e),
+        Some(0x9035),
+        Some(0x9031),
+        Some(0x9038),
+        Some(0x9032),
+        Some(0x9036),
+        Some(0x9102),
+        Some(0x90f5),
+        Some(0x9109),
+        Some(0x90fe),
+        Some(0x9163),
+        Some(0x9165),
+        Some(0x91cf),
+        Some(0x9214),
+        Some(0x9215),
+        Some(0x9223),
+        Some(0x9209),
+        Some(0x921e),
+        Some(0x920d),
+        Some(0x9210),
+        Some(0x9207),
+        Some(0x9211),
+        Some(0x9594),
+        Some(0x958f),
+        Some(0x958b),
+        Some(0x9591),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9593),
+        Some(0x9592),
+        Some(0x958e),
+        Some(0x968a),
+        Some(0x968e),
+        Some(0x968b),
+        Some(0x967d),
+        Some(0x9685),
+        Some(0x9686),
+        Some(0x968d),
+        Some(0x9672),
+        Some(0x9684),
+        Some(0x96c1),
+        Some(0x96c5),
+        Some(0x96c4),
+        Some(0x96c6),
+        Some(0x96c7),
+        Some(0x96ef),
+        Some(0x96f2),
+        Some(0x97cc),
+        Some(0x9805),
+        Some(0x9806),
+        Some(0x9808),
+        Some(0x98e7),
+        Some(0x98ea),
+        Some(0x98ef),
+        Some(0x98e9),
+        Some(0x98f2),
+        Some(0x98ed),
+        Some(0x99ae),
+        Some(0x99ad),
+        Some(0x9ec3),
+        Some(0x9ecd),
+        Some(0x9ed1),
+        Some(0x4e82),
+        Some(0x50ad),
+        Some(0x50b5),
+        Some(0x50b2),
+        Some(0x50b3),
+        Some(0x50c5),
+        Some(0x50be),
+        Some(0x50ac),
+        Some(0x50b7),
+        Some(0x50bb),
+        Some(0x50af),
+        Some(0x50c7),
+        Some(0x527f),
+        Some(0x5277),
+        Some(0x527d),
+        Some(0x52df),
+        Some(0x52e6),
+        Some(0x52e4),
+        Some(0x52e2),
+        Some(0x52e3),
+        Some(0x532f),
+        Some(0x55df),
+        Some(0x55e8),
+        Some(0x55d3),
+        Some(0x55e6),
+        Some(0x55ce),
+        Some(0x55dc),
+        Some(0x55c7),
+        Some(0x55d1),
+        Some(0x55e3),
+        Some(0x55e4),
+        Some(0x55ef),
+        Some(0x55da),
+        Some(0x55e1),
+        Some(0x55c5),
+        Some(0x55c6),
+        Some(0x55e5),
+        Some(0x55c9),
+        Some(0x5712),
+        Some(0x5713),
+        Some(0x585e),
+        Some(0x5851),
+        Some(0x5858),
+        Some(0x5857),
+        Some(0x585a),
+        Some(0x5854),
+        Some(0x586b),
+        Some(0x584c),
+        Some(0x586d),
+        Some(0x584a),
+        Some(0x5862),
+        Some(0x5852),
+        Some(0x584b),
+        Some(0x5967),
+        Some(0x5ac1),
+        Some(0x5ac9),
+        Some(0x5acc),
+        Some(0x5abe),
+        Some(0x5abd),
+        Some(0x5abc),
+        None,
+    ];
+
+    const BASE_0XB740: [Option<u16>; 192] = [
+        Some(0x5ab3),
+        Some(0x5ac2),
+        Some(0x5ab2),
+        Some(0x5d69),
+        Some(0x5d6f),
+        Some(0x5e4c),
+        Some(0x5e79),
+        Some(0x5ec9),
+        Some(0x5ec8),
+        Some(0x5f12),
+        Some(0x5f59),
+        Some(0x5fac),
+        Some(0x5fae),
+        Some(0x611a),
+        Some(0x610f),
+        Some(0x6148),
+        Some(0x611f),
+        Some(0x60f3),
+        Some(0x611b),
+        Some(0x60f9),
+        Some(0x6101),
+        Some(0x6108),
+        Some(0x614e),
+        Some(0x614c),
+        Some(0x6144),
+        Some(0x614d),
+        Some(0x613e),
+        Some(0x6134),
+        Some(0x6127),
+        Some(0x610d),
+        Some(0x6106),
+        Some(0x6137),
+        Some(0x6221),
+        Some(0x6222),
+        Some(0x6413),
+        Some(0x643e),
+        Some(0x641e),
+        Some(0x642a),
+        Some(0x642d),
+        Some(0x643d),
+        Some(0x642c),
+        Some(0x640f),
+        Some(0x641c),
+        Some(0x6414),
+        Some(0x640d),
+        Some(0x6436),
+        Some(0x6416),
+        Some(0x6417),
+        Some(0x6406),
+        Some(0x656c),
+        Some(0x659f),
+        Some(0x65b0),
+        Some(0x6697),
+        Some(0x6689),
+        Some(0x6687),
+        Some(0x6688),
+        Some(0x6696),
+        Some(0x6684),
+        Some(0x6698),
+        Some(0x668d),
+        Some(0x6703),
+        Some(0x6994),
+        Some(0x696d),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x695a),
+        Some(0x6977),
+        Some(0x6960),
+        Some(0x6954),
+        Some(0x6975),
+        Some(0x6930),
+        Some(0x6982),
+        Some(0x694a),
+        Some(0x6968),
+        Some(0x696b),
+        Some(0x695e),
+        Some(0x6953),
+        Some(0x6979),
+        Some(0x6986),
+        Some(0x695d),
+        Some(0x6963),
+        Some(0x695b),
+        Some(0x6b47),
+        Some(0x6b72),
+        Some(0x6bc0),
+        Some(0x6bbf),
+        Some(0x6bd3),
+        Some(0x6bfd),
+        Some(0x6ea2),
+        Some(0x6eaf),
+        Some(0x6ed3),
+        Some(0x6eb6),
+        Some(0x6ec2),
+        Some(0x6e90),
+        Some(0x6e9d),
+        Some(0x6ec7),
+        Some(0x6ec5),
+        Some(0x6ea5),
+        Some(0x6e98),
+        Some(0x6ebc),
+        Some(0x6eba),
+        Some(0x6eab),
+        Some(0x6ed1),
+        Some(0x6e96),
+        Some(0x6e9c),
+        Some(0x6ec4),
+        Some(0x6ed4),
+        Some(0x6eaa),
+        Some(0x6ea7),
+        Some(0x6eb4),
+        Some(0x714e),
+        Some(0x7159),
+        Some(0x7169),
+        Some(0x7164),
+        Some(0x7149),
+        Some(0x7167),
+        Some(0x715c),
+        Some(0x716c),
+        Some(0x7166),
+        Some(0x714c),
+        Some(0x7165),
+        Some(0x715e),
+        Some(0x7146),
+        Some(0x7168),
+        Some(0x7156),
+        Some(0x723a),
+        Some(0x7252),
+        Some(0x7337),
+        Some(0x7345),
+        Some(0x733f),
+        Some(0x733e),
+        Some(0x746f),
+        Some(0x745a),
+        Some(0x7455),
+        Some(0x745f),
+        Some(0x745e),
+        Some(0x7441),
+        Some(0x743f),
+        Some(0x7459),
+        Some(0x745b),
+        Some(0x745c),
+        Some(0x7576),
+        Some(0x7578),
+        Some(0x7600),
+        Some(0x75f0),
+        Some(0x7601),
+        Some(0x75f2),
+        Some(0x75f1),
+        Some(0x75fa),
+        Some(0x75ff),
+        Some(0x75f4),
+        Some(0x75f3),
+        Some(0x76de),
+        Some(0x76df),
+        Some(0x775b),
+        Some(0x776b),
+        Some(0x7766),
+        Some(0x775e),
+        Some(0x7763),
+        None,
+    ];
+
+    const BASE_0XB840: [Option<u16>; 192] = [
+        Some(0x7779),
+        Some(0x776a),
+        Some(0x776c),
+        Some(0x775c),
+        Some(0x7765),
+        Some(0x7768),
+        Some(0x7762),
+        Some(0x77ee),
+        Some(0x788e),
+        Some(0x78b0),
+        Some(0x7897),
+        Some(0x7898),
+        Some(0x788c),
+        Some(0x7889),
+        Some(0x787c),
+        Some(0x7891),
+        Some(0x7893),
+        Some(0x787f),
+        Some(0x797a),
+        Some(0x797f),
+        Some(0x7981),
+        Some(0x842c),
+        Some(0x79bd),
+        Some(0x7a1c),
+        Some(0x7a1a),
+        Some(0x7a20),
+        Some(0x7a14),
+        Some(0x7a1f),
+        Some(0x7a1e),
+        Some(0x7a9f),
+        Some(0x7aa0),
+        Some(0x7b77),
+        Some(0x7bc0),
+        Some(0x7b60),
+        Some(0x7b6e),
+        Some(0x7b67),
+        Some(0x7cb1),
+        Some(0x7cb3),
+        Some(0x7cb5),
+        Some(0x7d93),
+        Some(0x7d79),
+        Some(0x7d91),
+        Some(0x7d81),
+        Some(0x7d8f),
+        Some(0x7d5b),
+        Some(0x7f6e),
+        Some(0x7f69),
+        Some(0x7f6a),
+        Some(0x7f72),
+        Some(0x7fa9),
+        Some(0x7fa8),
+        Some(0x7fa4),
+        Some(0x8056),
+        Some(0x8058),
+        Some(0x8086),
+        Some(0x8084),
+        Some(0x8171),
+        Some(0x8170),
+        Some(0x8178),
+        Some(0x8165),
+        Some(0x816e),
+        Some(0x8173),
+        Some(0x816b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8179),
+        Some(0x817a),
+        Some(0x8166),
+        Some(0x8205),
+        Some(0x8247),
+        Some(0x8482),
+        Some(0x8477),
+        Some(0x843d),
+        Some(0x8431),
+        Some(0x8475),
+        Some(0x8466),
+        Some(0x846b),
+        Some(0x8449),
+        Some(0x846c),
+        Some(0x845b),
+        Some(0x843c),
+        Some(0x8435),
+        Some(0x8461),
+        Some(0x8463),
+        Some(0x8469),
+        Some(0x846d),
+        Some(0x8446),
+        Some(0x865e),
+        Some(0x865c),
+        Some(0x865f),
+        Some(0x86f9),
+        Some(0x8713),
+        Some(0x8708),
+        Some(0x8707),
+        Some(0x8700),
+        Some(0x86fe),
+        Some(0x86fb),
+        Some(0x8702),
+        Some(0x8703),
+        Some(0x8706),
+        Some(0x870a),
+        Some(0x8859),
+        Some(0x88df),
+        Some(0x88d4),
+        Some(0x88d9),
+        Some(0x88dc),
+        Some(0x88d8),
+        Some(0x88dd),
+        Some(0x88e1),
+        Some(0x88ca),
+        Some(0x88d5),
+        Some(0x88d2),
+        Some(0x899c),
+        Some(0x89e3),
+        Some(0x8a6b),
+        Some(0x8a72),
+        Some(0x8a73),
+        Some(0x8a66),
+        Some(0x8a69),
+        Some(0x8a70),
+        Some(0x8a87),
+        Some(0x8a7c),
+        Some(0x8a63),
+        Some(0x8aa0),
+        Some(0x8a71),
+        Some(0x8a85),
+        Some(0x8a6d),
+        Some(0x8a62),
+        Some(0x8a6e),
+        Some(0x8a6c),
+        Some(0x8a79),
+        Some(0x8a7b),
+        Some(0x8a3e),
+        Some(0x8a68),
+        Some(0x8c62),
+        Some(0x8c8a),
+        Some(0x8c89),
+        Some(0x8cca),
+        Some(0x8cc7),
+        Some(0x8cc8),
+        Some(0x8cc4),
+        Some(0x8cb2),
+        Some(0x8cc3),
+        Some(0x8cc2),
+        Some(0x8cc5),
+        Some(0x8de1),
+        Some(0x8ddf),
+        Some(0x8de8),
+        Some(0x8def),
+        Some(0x8df3),
+        Some(0x8dfa),
+        Some(0x8dea),
+        Some(0x8de4),
+        Some(0x8de6),
+        Some(0x8eb2),
+        Some(0x8f03),
+        Some(0x8f09),
+        Some(0x8efe),
+        Some(0x8f0a),
+        None,
+    ];
+
+    const BASE_0XB940: [Option<u16>; 192] = [
+        Some(0x8f9f),
+        Some(0x8fb2),
+        Some(0x904b),
+        Some(0x904a),
+        Some(0x9053),
+        Some(0x9042),
+        Some(0x9054),
+        Some(0x903c),
+        Some(0x9055),
+        Some(0x9050),
+        Some(0x9047),
+        Some(0x904f),
+        Some(0x904e),
+        Some(0x904d),
+        Some(0x9051),
+        Some(0x903e),
+        Some(0x9041),
+        Some(0x9112),
+        Some(0x9117),
+        Some(0x916c),
+        Some(0x916a),
+        Some(0x9169),
+        Some(0x91c9),
+        Some(0x9237),
+        Some(0x9257),
+        Some(0x9238),
+        Some(0x923d),
+        Some(0x9240),
+        Some(0x923e),
+        Some(0x925b),
+        Some(0x924b),
+        Some(0x9264),
+        Some(0x9251),
+        Some(0x9234),
+        Some(0x9249),
+        Some(0x924d),
+        Some(0x9245),
+        Some(0x9239),
+        Some(0x923f),
+        Some(0x925a),
+        Some(0x9598),
+        Some(0x9698),
+        Some(0x9694),
+        Some(0x9695),
+        Some(0x96cd),
+        Some(0x96cb),
+        Some(0x96c9),
+        Some(0x96ca),
+        Some(0x96f7),
+        Some(0x96fb),
+        Some(0x96f9),
+        Some(0x96f6),
+        Some(0x9756),
+        Some(0x9774),
+        Some(0x9776),
+        Some(0x9810),
+        Some(0x9811),
+        Some(0x9813),
+        Some(0x980a),
+        Some(0x9812),
+        Some(0x980c),
+        Some(0x98fc),
+        Some(0x98f4),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x98fd),
+        Some(0x98fe),
+        Some(0x99b3),
+        Some(0x99b1),
+        Some(0x99b4),
+        Some(0x9ae1),
+        Some(0x9ce9),
+        Some(0x9e82),
+        Some(0x9f0e),
+        Some(0x9f13),
+        Some(0x9f20),
+        Some(0x50e7),
+        Some(0x50ee),
+        Some(0x50e5),
+        Some(0x50d6),
+        Some(0x50ed),
+        Some(0x50da),
+        Some(0x50d5),
+        Some(0x50cf),
+        Some(0x50d1),
+        Some(0x50f1),
+        Some(0x50ce),
+        Some(0x50e9),
+        Some(0x5162),
+        Some(0x51f3),
+        Some(0x5283),
+        Some(0x5282),
+        Some(0x5331),
+        Some(0x53ad),
+        Some(0x55fe),
+        Some(0x5600),
+        Some(0x561b),
+        Some(0x5617),
+        Some(0x55fd),
+        Some(0x5614),
+        Some(0x5606),
+        Some(0x5609),
+        Some(0x560d),
+        Some(0x560e),
+        Some(0x55f7),
+        Some(0x5616),
+        Some(0x561f),
+        Some(0x5608),
+        Some(0x5610),
+        Some(0x55f6),
+        Some(0x5718),
+        Some(0x5716),
+        Some(0x5875),
+        Some(0x587e),
+        Some(0x5883),
+        Some(0x5893),
+        Some(0x588a),
+        Some(0x5879),
+        Some(0x5885),
+        Some(0x587d),
+        Some(0x58fd),
+        Some(0x5925),
+        Some(0x5922),
+        Some(0x5924),
+        Some(0x596a),
+        Some(0x5969),
+        Some(0x5ae1),
+        Some(0x5ae6),
+        Some(0x5ae9),
+        Some(0x5ad7),
+        Some(0x5ad6),
+        Some(0x5ad8),
+        Some(0x5ae3),
+        Some(0x5b75),
+        Some(0x5bde),
+        Some(0x5be7),
+        Some(0x5be1),
+        Some(0x5be5),
+        Some(0x5be6),
+        Some(0x5be8),
+        Some(0x5be2),
+        Some(0x5be4),
+        Some(0x5bdf),
+        Some(0x5c0d),
+        Some(0x5c62),
+        Some(0x5d84),
+        Some(0x5d87),
+        Some(0x5e5b),
+        Some(0x5e63),
+        Some(0x5e55),
+        Some(0x5e57),
+        Some(0x5e54),
+        Some(0x5ed3),
+        Some(0x5ed6),
+        Some(0x5f0a),
+        Some(0x5f46),
+        Some(0x5f70),
+        Some(0x5fb9),
+        Some(0x6147),
+        None,
+    ];
+
+    const BASE_0XBA40: [Option<u16>; 192] = [
+        Some(0x613f),
+        Some(0x614b),
+        Some(0x6177),
+        Some(0x6162),
+        Some(0x6163),
+        Some(0x615f),
+        Some(0x615a),
+        Some(0x6158),
+        Some(0x6175),
+        Some(0x622a),
+        Some(0x6487),
+        Some(0x6458),
+        Some(0x6454),
+        Some(0x64a4),
+        Some(0x6478),
+        Some(0x645f),
+        Some(0x647a),
+        Some(0x6451),
+        Some(0x6467),
+        Some(0x6434),
+        Some(0x646d),
+        Some(0x647b),
+        Some(0x6572),
+        Some(0x65a1),
+        Some(0x65d7),
+        Some(0x65d6),
+        Some(0x66a2),
+        Some(0x66a8),
+        Some(0x669d),
+        Some(0x699c),
+        Some(0x69a8),
+        Some(0x6995),
+        Some(0x69c1),
+        Some(0x69ae),
+        Some(0x69d3),
+        Some(0x69cb),
+        Some(0x699b),
+        Some(0x69b7),
+        Some(0x69bb),
+        Some(0x69ab),
+        Some(0x69b4),
+        Some(0x69d0),
+        Some(0x69cd),
+        Some(0x69ad),
+        Some(0x69cc),
+        Some(0x69a6),
+        Some(0x69c3),
+        Some(0x69a3),
+        Some(0x6b49),
+        Some(0x6b4c),
+        Some(0x6c33),
+        Some(0x6f33),
+        Some(0x6f14),
+        Some(0x6efe),
+        Some(0x6f13),
+        Some(0x6ef4),
+        Some(0x6f29),
+        Some(0x6f3e),
+        Some(0x6f20),
+        Some(0x6f2c),
+        Some(0x6f0f),
+        Some(0x6f02),
+        Some(0x6f22),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6eff),
+        Some(0x6eef),
+        Some(0x6f06),
+        Some(0x6f31),
+        Some(0x6f38),
+        Some(0x6f32),
+        Some(0x6f23),
+        Some(0x6f15),
+        Some(0x6f2b),
+        Some(0x6f2f),
+        Some(0x6f88),
+        Some(0x6f2a),
+        Some(0x6eec),
+        Some(0x6f01),
+        Some(0x6ef2),
+        Some(0x6ecc),
+        Some(0x6ef7),
+        Some(0x7194),
+        Some(0x7199),
+        Some(0x717d),
+        Some(0x718a),
+        Some(0x7184),
+        Some(0x7192),
+        Some(0x723e),
+        Some(0x7292),
+        Some(0x7296),
+        Some(0x7344),
+        Some(0x7350),
+        Some(0x7464),
+        Some(0x7463),
+        Some(0x746a),
+        Some(0x7470),
+        Some(0x746d),
+        Some(0x7504),
+        Some(0x7591),
+        Some(0x7627),
+        Some(0x760d),
+        Some(0x760b),
+        Some(0x7609),
+        Some(0x7613),
+        Some(0x76e1),
+        Some(0x76e3),
+        Some(0x7784),
+        Some(0x777d),
+        Some(0x777f),
+        Some(0x7761),
+        Some(0x78c1),
+        Some(0x789f),
+        Some(0x78a7),
+        Some(0x78b3),
+        Some(0x78a9),
+        Some(0x78a3),
+        Some(0x798e),
+        Some(0x798f),
+        Some(0x798d),
+        Some(0x7a2e),
+        Some(0x7a31),
+        Some(0x7aaa),
+        Some(0x7aa9),
+        Some(0x7aed),
+        Some(0x7aef),
+        Some(0x7ba1),
+        Some(0x7b95),
+        Some(0x7b8b),
+        Some(0x7b75),
+        Some(0x7b97),
+        Some(0x7b9d),
+        Some(0x7b94),
+        Some(0x7b8f),
+        Some(0x7bb8),
+        Some(0x7b87),
+        Some(0x7b84),
+        Some(0x7cb9),
+        Some(0x7cbd),
+        Some(0x7cbe),
+        Some(0x7dbb),
+        Some(0x7db0),
+        Some(0x7d9c),
+        Some(0x7dbd),
+        Some(0x7dbe),
+        Some(0x7da0),
+        Some(0x7dca),
+        Some(0x7db4),
+        Some(0x7db2),
+        Some(0x7db1),
+        Some(0x7dba),
+        Some(0x7da2),
+        Some(0x7dbf),
+        Some(0x7db5),
+        Some(0x7db8),
+        Some(0x7dad),
+        Some(0x7dd2),
+        Some(0x7dc7),
+        Some(0x7dac),
+        None,
+    ];
+
+    const BASE_0XBB40: [Option<u16>; 192] = [
+        Some(0x7f70),
+        Some(0x7fe0),
+        Some(0x7fe1),
+        Some(0x7fdf),
+        Some(0x805e),
+        Some(0x805a),
+        Some(0x8087),
+        Some(0x8150),
+        Some(0x8180),
+        Some(0x818f),
+        Some(0x8188),
+        Some(0x818a),
+        Some(0x817f),
+        Some(0x8182),
+        Some(0x81e7),
+        Some(0x81fa),
+        Some(0x8207),
+        Some(0x8214),
+        Some(0x821e),
+        Some(0x824b),
+        Some(0x84c9),
+        Some(0x84bf),
+        Some(0x84c6),
+        Some(0x84c4),
+        Some(0x8499),
+        Some(0x849e),
+        Some(0x84b2),
+        Some(0x849c),
+        Some(0x84cb),
+        Some(0x84b8),
+        Some(0x84c0),
+        Some(0x84d3),
+        Some(0x8490),
+        Some(0x84bc),
+        Some(0x84d1),
+        Some(0x84ca),
+        Some(0x873f),
+        Some(0x871c),
+        Some(0x873b),
+        Some(0x8722),
+        Some(0x8725),
+        Some(0x8734),
+        Some(0x8718),
+        Some(0x8755),
+        Some(0x8737),
+        Some(0x8729),
+        Some(0x88f3),
+        Some(0x8902),
+        Some(0x88f4),
+        Some(0x88f9),
+        Some(0x88f8),
+        Some(0x88fd),
+        Some(0x88e8),
+        Some(0x891a),
+        Some(0x88ef),
+        Some(0x8aa6),
+        Some(0x8a8c),
+        Some(0x8a9e),
+        Some(0x8aa3),
+        Some(0x8a8d),
+        Some(0x8aa1),
+        Some(0x8a93),
+        Some(0x8aa4),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8aaa),
+        Some(0x8aa5),
+        Some(0x8aa8),
+        Some(0x8a98),
+        Some(0x8a91),
+        Some(0x8a9a),
+        Some(0x8aa7),
+        Some(0x8c6a),
+        Some(0x8c8d),
+        Some(0x8c8c),
+        Some(0x8cd3),
+        Some(0x8cd1),
+        Some(0x8cd2),
+        Some(0x8d6b),
+        Some(0x8d99),
+        Some(0x8d95),
+        Some(0x8dfc),
+        Some(0x8f14),
+        Some(0x8f12),
+        Some(0x8f15),
+        Some(0x8f13),
+        Some(0x8fa3),
+        Some(0x9060),
+        Some(0x9058),
+        Some(0x905c),
+        Some(0x9063),
+        Some(0x9059),
+        Some(0x905e),
+        Some(0x9062),
+        Some(0x905d),
+        Some(0x905b),
+        Some(0x9119),
+        Some(0x9118),
+        Some(0x911e),
+        Some(0x9175),
+        Some(0x9178),
+        Some(0x9177),
+        Some(0x9174),
+        Some(0x9278),
+        Some(0x9280),
+        Some(0x9285),
+        Some(0x9298),
+        Some(0x9296),
+        Some(0x927b),
+        Some(0x9293),
+        Some(0x929c),
+        Some(0x92a8),
+        Some(0x927c),
+        Some(0x9291),
+        Some(0x95a1),
+        Some(0x95a8),
+        Some(0x95a9),
+        Some(0x95a3),
+        Some(0x95a5),
+        Some(0x95a4),
+        Some(0x9699),
+        Some(0x969c),
+        Some(0x969b),
+        Some(0x96cc),
+        Some(0x96d2),
+        Some(0x9700),
+        Some(0x977c),
+        Some(0x9785),
+        Some(0x97f6),
+        Some(0x9817),
+        Some(0x9818),
+        Some(0x98af),
+        Some(0x98b1),
+        Some(0x9903),
+        Some(0x9905),
+        Some(0x990c),
+        Some(0x9909),
+        Some(0x99c1),
+        Some(0x9aaf),
+        Some(0x9ab0),
+        Some(0x9ae6),
+        Some(0x9b41),
+        Some(0x9b42),
+        Some(0x9cf4),
+        Some(0x9cf6),
+        Some(0x9cf3),
+        Some(0x9ebc),
+        Some(0x9f3b),
+        Some(0x9f4a),
+        Some(0x5104),
+        Some(0x5100),
+        Some(0x50fb),
+        Some(0x50f5),
+        Some(0x50f9),
+        Some(0x5102),
+        Some(0x5108),
+        Some(0x5109),
+        Some(0x5105),
+        Some(0x51dc),
+        None,
+    ];
+
+    const BASE_0XBC40: [Option<u16>; 192] = [
+        Some(0x5287),
+        Some(0x5288),
+        Some(0x5289),
+        Some(0x528d),
+        Some(0x528a),
+        Some(0x52f0),
+        Some(0x53b2),
+        Some(0x562e),
+        Some(0x563b),
+        Some(0x5639),
+        Some(0x5632),
+        Some(0x563f),
+        Some(0x5634),
+        Some(0x5629),
+        Some(0x5653),
+        Some(0x564e),
+        Some(0x5657),
+        Some(0x5674),
+        Some(0x5636),
+        Some(0x562f),
+        Some(0x5630),
+        Some(0x5880),
+        Some(0x589f),
+        Some(0x589e),
+        Some(0x58b3),
+        Some(0x589c),
+        Some(0x58ae),
+        Some(0x58a9),
+        Some(0x58a6),
+        Some(0x596d),
+        Some(0x5b09),
+        Some(0x5afb),
+        Some(0x5b0b),
+        Some(0x5af5),
+        Some(0x5b0c),
+        Some(0x5b08),
+        Some(0x5bee),
+        Some(0x5bec),
+        Some(0x5be9),
+        Some(0x5beb),
+        Some(0x5c64),
+        Some(0x5c65),
+        Some(0x5d9d),
+        Some(0x5d94),
+        Some(0x5e62),
+        Some(0x5e5f),
+        Some(0x5e61),
+        Some(0x5ee2),
+        Some(0x5eda),
+        Some(0x5edf),
+        Some(0x5edd),
+        Some(0x5ee3),
+        Some(0x5ee0),
+        Some(0x5f48),
+        Some(0x5f71),
+        Some(0x5fb7),
+        Some(0x5fb5),
+        Some(0x6176),
+        Some(0x6167),
+        Some(0x616e),
+        Some(0x615d),
+        Some(0x6155),
+        Some(0x6182),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x617c),
+        Some(0x6170),
+        Some(0x616b),
+        Some(0x617e),
+        Some(0x61a7),
+        Some(0x6190),
+        Some(0x61ab),
+        Some(0x618e),
+        Some(0x61ac),
+        Some(0x619a),
+        Some(0x61a4),
+        Some(0x6194),
+        Some(0x61ae),
+        Some(0x622e),
+        Some(0x6469),
+        Some(0x646f),
+        Some(0x6479),
+        Some(0x649e),
+        Some(0x64b2),
+        Some(0x6488),
+        Some(0x6490),
+        Some(0x64b0),
+        Some(0x64a5),
+        Some(0x6493),
+        Some(0x6495),
+        Some(0x64a9),
+        Some(0x6492),
+        Some(0x64ae),
+        Some(0x64ad),
+        Some(0x64ab),
+        Some(0x649a),
+        Some(0x64ac),
+        Some(0x6499),
+        Some(0x64a2),
+        Some(0x64b3),
+        Some(0x6575),
+        Some(0x6577),
+        Some(0x6578),
+        Some(0x66ae),
+        Some(0x66ab),
+        Some(0x66b4),
+        Some(0x66b1),
+        Some(0x6a23),
+        Some(0x6a1f),
+        Some(0x69e8),
+        Some(0x6a01),
+        Some(0x6a1e),
+        Some(0x6a19),
+        Some(0x69fd),
+        Some(0x6a21),
+        Some(0x6a13),
+        Some(0x6a0a),
+        Some(0x69f3),
+        Some(0x6a02),
+        Some(0x6a05),
+        Some(0x69ed),
+        Some(0x6a11),
+        Some(0x6b50),
+        Some(0x6b4e),
+        Some(0x6ba4),
+        Some(0x6bc5),
+        Some(0x6bc6),
+        Some(0x6f3f),
+        Some(0x6f7c),
+        Some(0x6f84),
+        Some(0x6f51),
+        Some(0x6f66),
+        Some(0x6f54),
+        Some(0x6f86),
+        Some(0x6f6d),
+        Some(0x6f5b),
+        Some(0x6f78),
+        Some(0x6f6e),
+        Some(0x6f8e),
+        Some(0x6f7a),
+        Some(0x6f70),
+        Some(0x6f64),
+        Some(0x6f97),
+        Some(0x6f58),
+        Some(0x6ed5),
+        Some(0x6f6f),
+        Some(0x6f60),
+        Some(0x6f5f),
+        Some(0x719f),
+        Some(0x71ac),
+        Some(0x71b1),
+        Some(0x71a8),
+        Some(0x7256),
+        Some(0x729b),
+        Some(0x734e),
+        Some(0x7357),
+        Some(0x7469),
+        Some(0x748b),
+        Some(0x7483),
+        None,
+    ];
+
+    const BASE_0XBD40: [Option<u16>; 192] = [
+        Some(0x747e),
+        Some(0x7480),
+        Some(0x757f),
+        Some(0x7620),
+        Some(0x7629),
+        Some(0x761f),
+        Some(0x7624),
+        Some(0x7626),
+        Some(0x7621),
+        Some(0x7622),
+        Some(0x769a),
+        Some(0x76ba),
+        Some(0x76e4),
+        Some(0x778e),
+        Some(0x7787),
+        Some(0x778c),
+        Some(0x7791),
+        Some(0x778b),
+        Some(0x78cb),
+        Some(0x78c5),
+        Some(0x78ba),
+        Some(0x78ca),
+        Some(0x78be),
+        Some(0x78d5),
+        Some(0x78bc),
+        Some(0x78d0),
+        Some(0x7a3f),
+        Some(0x7a3c),
+        Some(0x7a40),
+        Some(0x7a3d),
+        Some(0x7a37),
+        Some(0x7a3b),
+        Some(0x7aaf),
+        Some(0x7aae),
+        Some(0x7bad),
+        Some(0x7bb1),
+        Some(0x7bc4),
+        Some(0x7bb4),
+        Some(0x7bc6),
+        Some(0x7bc7),
+        Some(0x7bc1),
+        Some(0x7ba0),
+        Some(0x7bcc),
+        Some(0x7cca),
+        Some(0x7de0),
+        Some(0x7df4),
+        Some(0x7def),
+        Some(0x7dfb),
+        Some(0x7dd8),
+        Some(0x7dec),
+        Some(0x7ddd),
+        Some(0x7de8),
+        Some(0x7de3),
+        Some(0x7dda),
+        Some(0x7dde),
+        Some(0x7de9),
+        Some(0x7d9e),
+        Some(0x7dd9),
+        Some(0x7df2),
+        Some(0x7df9),
+        Some(0x7f75),
+        Some(0x7f77),
+        Some(0x7faf),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7fe9),
+        Some(0x8026),
+        Some(0x819b),
+        Some(0x819c),
+        Some(0x819d),
+        Some(0x81a0),
+        Some(0x819a),
+        Some(0x8198),
+        Some(0x8517),
+        Some(0x853d),
+        Some(0x851a),
+        Some(0x84ee),
+        Some(0x852c),
+        Some(0x852d),
+        Some(0x8513),
+        Some(0x8511),
+        Some(0x8523),
+        Some(0x8521),
+        Some(0x8514),
+        Some(0x84ec),
+        Some(0x8525),
+        Some(0x84ff),
+        Some(0x8506),
+        Some(0x8782),
+        Some(0x8774),
+        Some(0x8776),
+        Some(0x8760),
+        Some(0x8766),
+        Some(0x8778),
+        Some(0x8768),
+        Some(0x8759),
+        Some(0x8757),
+        Some(0x874c),
+        Some(0x8753),
+        Some(0x885b),
+        Some(0x885d),
+        Some(0x8910),
+        Some(0x8907),
+        Some(0x8912),
+        Some(0x8913),
+        Some(0x8915),
+        Some(0x890a),
+        Some(0x8abc),
+        Some(0x8ad2),
+        Some(0x8ac7),
+        Some(0x8ac4),
+        Some(0x8a95),
+        Some(0x8acb),
+        Some(0x8af8),
+        Some(0x8ab2),
+        Some(0x8ac9),
+        Some(0x8ac2),
+        Some(0x8abf),
+        Some(0x8ab0),
+        Some(0x8ad6),
+        Some(0x8acd),
+        Some(0x8ab6),
+        Some(0x8ab9),
+        Some(0x8adb),
+        Some(0x8c4c),
+        Some(0x8c4e),
+        Some(0x8c6c),
+        Some(0x8ce0),
+        Some(0x8cde),
+        Some(0x8ce6),
+        Some(0x8ce4),
+        Some(0x8cec),
+        Some(0x8ced),
+        Some(0x8ce2),
+        Some(0x8ce3),
+        Some(0x8cdc),
+        Some(0x8cea),
+        Some(0x8ce1),
+        Some(0x8d6d),
+        Some(0x8d9f),
+        Some(0x8da3),
+        Some(0x8e2b),
+        Some(0x8e10),
+        Some(0x8e1d),
+        Some(0x8e22),
+        Some(0x8e0f),
+        Some(0x8e29),
+        Some(0x8e1f),
+        Some(0x8e21),
+        Some(0x8e1e),
+        Some(0x8eba),
+        Some(0x8f1d),
+        Some(0x8f1b),
+        Some(0x8f1f),
+        Some(0x8f29),
+        Some(0x8f26),
+        Some(0x8f2a),
+        Some(0x8f1c),
+        Some(0x8f1e),
+        None,
+    ];
+
+    const BASE_0XBE40: [Option<u16>; 192] = [
+        Some(0x8f25),
+        Some(0x9069),
+        Some(0x906e),
+        Some(0x9068),
+        Some(0x906d),
+        Some(0x9077),
+        Some(0x9130),
+        Some(0x912d),
+        Some(0x9127),
+        Some(0x9131),
+        Some(0x9187),
+        Some(0x9189),
+        Some(0x918b),
+        Some(0x9183),
+        Some(0x92c5),
+        Some(0x92bb),
+        Some(0x92b7),
+        Some(0x92ea),
+        Some(0x92ac),
+        Some(0x92e4),
+        Some(0x92c1),
+        Some(0x92b3),
+        Some(0x92bc),
+        Some(0x92d2),
+        Some(0x92c7),
+        Some(0x92f0),
+        Some(0x92b2),
+        Some(0x95ad),
+        Some(0x95b1),
+        Some(0x9704),
+        Some(0x9706),
+        Some(0x9707),
+        Some(0x9709),
+        Some(0x9760),
+        Some(0x978d),
+        Some(0x978b),
+        Some(0x978f),
+        Some(0x9821),
+        Some(0x982b),
+        Some(0x981c),
+        Some(0x98b3),
+        Some(0x990a),
+        Some(0x9913),
+        Some(0x9912),
+        Some(0x9918),
+        Some(0x99dd),
+        Some(0x99d0),
+        Some(0x99df),
+        Some(0x99db),
+        Some(0x99d1),
+        Some(0x99d5),
+        Some(0x99d2),
+        Some(0x99d9),
+        Some(0x9ab7),
+        Some(0x9aee),
+        Some(0x9aef),
+        Some(0x9b27),
+        Some(0x9b45),
+        Some(0x9b44),
+        Some(0x9b77),
+        Some(0x9b6f),
+        Some(0x9d06),
+        Some(0x9d09),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9d03),
+        Some(0x9ea9),
+        Some(0x9ebe),
+        Some(0x9ece),
+        Some(0x58a8),
+        Some(0x9f52),
+        Some(0x5112),
+        Some(0x5118),
+        Some(0x5114),
+        Some(0x5110),
+        Some(0x5115),
+        Some(0x5180),
+        Some(0x51aa),
+        Some(0x51dd),
+        Some(0x5291),
+        Some(0x5293),
+        Some(0x52f3),
+        Some(0x5659),
+        Some(0x566b),
+        Some(0x5679),
+        Some(0x5669),
+        Some(0x5664),
+        Some(0x5678),
+        Some(0x566a),
+        Some(0x5668),
+        Some(0x5665),
+        Some(0x5671),
+        Some(0x566f),
+        Some(0x566c),
+        Some(0x5662),
+        Some(0x5676),
+        Some(0x58c1),
+        Some(0x58be),
+        Some(0x58c7),
+        Some(0x58c5),
+        Some(0x596e),
+        Some(0x5b1d),
+        Some(0x5b34),
+        Some(0x5b78),
+        Some(0x5bf0),
+        Some(0x5c0e),
+        Some(0x5f4a),
+        Some(0x61b2),
+        Some(0x6191),
+        Some(0x61a9),
+        Some(0x618a),
+        Some(0x61cd),
+        Some(0x61b6),
+        Some(0x61be),
+        Some(0x61ca),
+        Some(0x61c8),
+        Some(0x6230),
+        Some(0x64c5),
+        Some(0x64c1),
+        Some(0x64cb),
+        Some(0x64bb),
+        Some(0x64bc),
+        Some(0x64da),
+        Some(0x64c4),
+        Some(0x64c7),
+        Some(0x64c2),
+        Some(0x64cd),
+        Some(0x64bf),
+        Some(0x64d2),
+        Some(0x64d4),
+        Some(0x64be),
+        Some(0x6574),
+        Some(0x66c6),
+        Some(0x66c9),
+        Some(0x66b9),
+        Some(0x66c4),
+        Some(0x66c7),
+        Some(0x66b8),
+        Some(0x6a3d),
+        Some(0x6a38),
+        Some(0x6a3a),
+        Some(0x6a59),
+        Some(0x6a6b),
+        Some(0x6a58),
+        Some(0x6a39),
+        Some(0x6a44),
+        Some(0x6a62),
+        Some(0x6a61),
+        Some(0x6a4b),
+        Some(0x6a47),
+        Some(0x6a35),
+        Some(0x6a5f),
+        Some(0x6a48),
+        Some(0x6b59),
+        Some(0x6b77),
+        Some(0x6c05),
+        Some(0x6fc2),
+        Some(0x6fb1),
+        Some(0x6fa1),
+        None,
+    ];
+
+    const BASE_0XBF40: [Option<u16>; 192] = [
+        Some(0x6fc3),
+        Some(0x6fa4),
+        Some(0x6fc1),
+        Some(0x6fa7),
+        Some(0x6fb3),
+        Some(0x6fc0),
+        Some(0x6fb9),
+        Some(0x6fb6),
+        Some(0x6fa6),
+        Some(0x6fa0),
+        Some(0x6fb4),
+        Some(0x71be),
+        Some(0x71c9),
+        Some(0x71d0),
+        Some(0x71d2),
+        Some(0x71c8),
+        Some(0x71d5),
+        Some(0x71b9),
+        Some(0x71ce),
+        Some(0x71d9),
+        Some(0x71dc),
+        Some(0x71c3),
+        Some(0x71c4),
+        Some(0x7368),
+        Some(0x749c),
+        Some(0x74a3),
+        Some(0x7498),
+        Some(0x749f),
+        Some(0x749e),
+        Some(0x74e2),
+        Some(0x750c),
+        Some(0x750d),
+        Some(0x7634),
+        Some(0x7638),
+        Some(0x763a),
+        Some(0x76e7),
+        Some(0x76e5),
+        Some(0x77a0),
+        Some(0x779e),
+        Some(0x779f),
+        Some(0x77a5),
+        Some(0x78e8),
+        Some(0x78da),
+        Some(0x78ec),
+        Some(0x78e7),
+        Some(0x79a6),
+        Some(0x7a4d),
+        Some(0x7a4e),
+        Some(0x7a46),
+        Some(0x7a4c),
+        Some(0x7a4b),
+        Some(0x7aba),
+        Some(0x7bd9),
+        Some(0x7c11),
+        Some(0x7bc9),
+        Some(0x7be4),
+        Some(0x7bdb),
+        Some(0x7be1),
+        Some(0x7be9),
+        Some(0x7be6),
+        Some(0x7cd5),
+        Some(0x7cd6),
+        Some(0x7e0a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7e11),
+        Some(0x7e08),
+        Some(0x7e1b),
+        Some(0x7e23),
+        Some(0x7e1e),
+        Some(0x7e1d),
+        Some(0x7e09),
+        Some(0x7e10),
+        Some(0x7f79),
+        Some(0x7fb2),
+        Some(0x7ff0),
+        Some(0x7ff1),
+        Some(0x7fee),
+        Some(0x8028),
+        Some(0x81b3),
+        Some(0x81a9),
+        Some(0x81a8),
+        Some(0x81fb),
+        Some(0x8208),
+        Some(0x8258),
+        Some(0x8259),
+        Some(0x854a),
+        Some(0x8559),
+        Some(0x8548),
+        Some(0x8568),
+        Some(0x8569),
+        Some(0x8543),
+        Some(0x8549),
+        Some(0x856d),
+        Some(0x856a),
+        Some(0x855e),
+        Some(0x8783),
+        Some(0x879f),
+        Some(0x879e),
+        Some(0x87a2),
+        Some(0x878d),
+        Some(0x8861),
+        Some(0x892a),
+        Some(0x8932),
+        Some(0x8925),
+        Some(0x892b),
+        Some(0x8921),
+        Some(0x89aa),
+        Some(0x89a6),
+        Some(0x8ae6),
+        Some(0x8afa),
+        Some(0x8aeb),
+        Some(0x8af1),
+        Some(0x8b00),
+        Some(0x8adc),
+        Some(0x8ae7),
+        Some(0x8aee),
+        Some(0x8afe),
+        Some(0x8b01),
+        Some(0x8b02),
+        Some(0x8af7),
+        Some(0x8aed),
+        Some(0x8af3),
+        Some(0x8af6),
+        Some(0x8afc),
+        Some(0x8c6b),
+        Some(0x8c6d),
+        Some(0x8c93),
+        Some(0x8cf4),
+        Some(0x8e44),
+        Some(0x8e31),
+        Some(0x8e34),
+        Some(0x8e42),
+        Some(0x8e39),
+        Some(0x8e35),
+        Some(0x8f3b),
+        Some(0x8f2f),
+        Some(0x8f38),
+        Some(0x8f33),
+        Some(0x8fa8),
+        Some(0x8fa6),
+        Some(0x9075),
+        Some(0x9074),
+        Some(0x9078),
+        Some(0x9072),
+        Some(0x907c),
+        Some(0x907a),
+        Some(0x9134),
+        Some(0x9192),
+        Some(0x9320),
+        Some(0x9336),
+        Some(0x92f8),
+        Some(0x9333),
+        Some(0x932f),
+        Some(0x9322),
+        Some(0x92fc),
+        Some(0x932b),
+        Some(0x9304),
+        Some(0x931a),
+        None,
+    ];
+
+    const BASE_0XC040: [Option<u16>; 192] = [
+        Some(0x9310),
+        Some(0x9326),
+        Some(0x9321),
+        Some(0x9315),
+        Some(0x932e),
+        Some(0x9319),
+        Some(0x95bb),
+        Some(0x96a7),
+        Some(0x96a8),
+        Some(0x96aa),
+        Some(0x96d5),
+        Some(0x970e),
+        Some(0x9711),
+        Some(0x9716),
+        Some(0x970d),
+        Some(0x9713),
+        Some(0x970f),
+        Some(0x975b),
+        Some(0x975c),
+        Some(0x9766),
+        Some(0x9798),
+        Some(0x9830),
+        Some(0x9838),
+        Some(0x983b),
+        Some(0x9837),
+        Some(0x982d),
+        Some(0x9839),
+        Some(0x9824),
+        Some(0x9910),
+        Some(0x9928),
+        Some(0x991e),
+        Some(0x991b),
+        Some(0x9921),
+        Some(0x991a),
+        Some(0x99ed),
+        Some(0x99e2),
+        Some(0x99f1),
+        Some(0x9ab8),
+        Some(0x9abc),
+        Some(0x9afb),
+        Some(0x9aed),
+        Some(0x9b28),
+        Some(0x9b91),
+        Some(0x9d15),
+        Some(0x9d23),
+        Some(0x9d26),
+        Some(0x9d28),
+        Some(0x9d12),
+        Some(0x9d1b),
+        Some(0x9ed8),
+        Some(0x9ed4),
+        Some(0x9f8d),
+        Some(0x9f9c),
+        Some(0x512a),
+        Some(0x511f),
+        Some(0x5121),
+        Some(0x5132),
+        Some(0x52f5),
+        Some(0x568e),
+        Some(0x5680),
+        Some(0x5690),
+        Some(0x5685),
+        Some(0x5687),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x568f),
+        Some(0x58d5),
+        Some(0x58d3),
+        Some(0x58d1),
+        Some(0x58ce),
+        Some(0x5b30),
+        Some(0x5b2a),
+        Some(0x5b24),
+        Some(0x5b7a),
+        Some(0x5c37),
+        Some(0x5c68),
+        Some(0x5dbc),
+        Some(0x5dba),
+        Some(0x5dbd),
+        Some(0x5db8),
+        Some(0x5e6b),
+        Some(0x5f4c),
+        Some(0x5fbd),
+        Some(0x61c9),
+        Some(0x61c2),
+        Some(0x61c7),
+        Some(0x61e6),
+        Some(0x61cb),
+        Some(0x6232),
+        Some(0x6234),
+        Some(0x64ce),
+        Some(0x64ca),
+        Some(0x64d8),
+        Some(0x64e0),
+        Some(0x64f0),
+        Some(0x64e6),
+        Some(0x64ec),
+        Some(0x64f1),
+        Some(0x64e2),
+        Some(0x64ed),
+        Some(0x6582),
+        Some(0x6583),
+        Some(0x66d9),
+        Some(0x66d6),
+        Some(0x6a80),
+        Some(0x6a94),
+        Some(0x6a84),
+        Some(0x6aa2),
+        Some(0x6a9c),
+        Some(0x6adb),
+        Some(0x6aa3),
+        Some(0x6a7e),
+        Some(0x6a97),
+        Some(0x6a90),
+        Some(0x6aa0),
+        Some(0x6b5c),
+        Some(0x6bae),
+        Some(0x6bda),
+        Some(0x6c08),
+        Some(0x6fd8),
+        Some(0x6ff1),
+        Some(0x6fdf),
+        Some(0x6fe0),
+        Some(0x6fdb),
+        Some(0x6fe4),
+        Some(0x6feb),
+        Some(0x6fef),
+        Some(0x6f80),
+        Some(0x6fec),
+        Some(0x6fe1),
+        Some(0x6fe9),
+        Some(0x6fd5),
+        Some(0x6fee),
+        Some(0x6ff0),
+        Some(0x71e7),
+        Some(0x71df),
+        Some(0x71ee),
+        Some(0x71e6),
+        Some(0x71e5),
+        Some(0x71ed),
+        Some(0x71ec),
+        Some(0x71f4),
+        Some(0x71e0),
+        Some(0x7235),
+        Some(0x7246),
+        Some(0x7370),
+        Some(0x7372),
+        Some(0x74a9),
+        Some(0x74b0),
+        Some(0x74a6),
+        Some(0x74a8),
+        Some(0x7646),
+        Some(0x7642),
+        Some(0x764c),
+        Some(0x76ea),
+        Some(0x77b3),
+        Some(0x77aa),
+        Some(0x77b0),
+        Some(0x77ac),
+        None,
+    ];
+
+    const BASE_0XC140: [Option<u16>; 192] = [
+        Some(0x77a7),
+        Some(0x77ad),
+        Some(0x77ef),
+        Some(0x78f7),
+        Some(0x78fa),
+        Some(0x78f4),
+        Some(0x78ef),
+        Some(0x7901),
+        Some(0x79a7),
+        Some(0x79aa),
+        Some(0x7a57),
+        Some(0x7abf),
+        Some(0x7c07),
+        Some(0x7c0d),
+        Some(0x7bfe),
+        Some(0x7bf7),
+        Some(0x7c0c),
+        Some(0x7be0),
+        Some(0x7ce0),
+        Some(0x7cdc),
+        Some(0x7cde),
+        Some(0x7ce2),
+        Some(0x7cdf),
+        Some(0x7cd9),
+        Some(0x7cdd),
+        Some(0x7e2e),
+        Some(0x7e3e),
+        Some(0x7e46),
+        Some(0x7e37),
+        Some(0x7e32),
+        Some(0x7e43),
+        Some(0x7e2b),
+        Some(0x7e3d),
+        Some(0x7e31),
+        Some(0x7e45),
+        Some(0x7e41),
+        Some(0x7e34),
+        Some(0x7e39),
+        Some(0x7e48),
+        Some(0x7e35),
+        Some(0x7e3f),
+        Some(0x7e2f),
+        Some(0x7f44),
+        Some(0x7ff3),
+        Some(0x7ffc),
+        Some(0x8071),
+        Some(0x8072),
+        Some(0x8070),
+        Some(0x806f),
+        Some(0x8073),
+        Some(0x81c6),
+        Some(0x81c3),
+        Some(0x81ba),
+        Some(0x81c2),
+        Some(0x81c0),
+        Some(0x81bf),
+        Some(0x81bd),
+        Some(0x81c9),
+        Some(0x81be),
+        Some(0x81e8),
+        Some(0x8209),
+        Some(0x8271),
+        Some(0x85aa),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8584),
+        Some(0x857e),
+        Some(0x859c),
+        Some(0x8591),
+        Some(0x8594),
+        Some(0x85af),
+        Some(0x859b),
+        Some(0x8587),
+        Some(0x85a8),
+        Some(0x858a),
+        Some(0x8667),
+        Some(0x87c0),
+        Some(0x87d1),
+        Some(0x87b3),
+        Some(0x87d2),
+        Some(0x87c6),
+        Some(0x87ab),
+        Some(0x87bb),
+        Some(0x87ba),
+        Some(0x87c8),
+        Some(0x87cb),
+        Some(0x893b),
+        Some(0x8936),
+        Some(0x8944),
+        Some(0x8938),
+        Some(0x893d),
+        Some(0x89ac),
+        Some(0x8b0e),
+        Some(0x8b17),
+        Some(0x8b19),
+        Some(0x8b1b),
+        Some(0x8b0a),
+        Some(0x8b20),
+        Some(0x8b1d),
+        Some(0x8b04),
+        Some(0x8b10),
+        Some(0x8c41),
+        Some(0x8c3f),
+        Some(0x8c73),
+        Some(0x8cfa),
+        Some(0x8cfd),
+        Some(0x8cfc),
+        Some(0x8cf8),
+        Some(0x8cfb),
+        Some(0x8da8),
+        Some(0x8e49),
+        Some(0x8e4b),
+        Some(0x8e48),
+        Some(0x8e4a),
+        Some(0x8f44),
+        Some(0x8f3e),
+        Some(0x8f42),
+        Some(0x8f45),
+        Some(0x8f3f),
+        Some(0x907f),
+        Some(0x907d),
+        Some(0x9084),
+        Some(0x9081),
+        Some(0x9082),
+        Some(0x9080),
+        Some(0x9139),
+        Some(0x91a3),
+        Some(0x919e),
+        Some(0x919c),
+        Some(0x934d),
+        Some(0x9382),
+        Some(0x9328),
+        Some(0x9375),
+        Some(0x934a),
+        Some(0x9365),
+        Some(0x934b),
+        Some(0x9318),
+        Some(0x937e),
+        Some(0x936c),
+        Some(0x935b),
+        Some(0x9370),
+        Some(0x935a),
+        Some(0x9354),
+        Some(0x95ca),
+        Some(0x95cb),
+        Some(0x95cc),
+        Some(0x95c8),
+        Some(0x95c6),
+        Some(0x96b1),
+        Some(0x96b8),
+        Some(0x96d6),
+        Some(0x971c),
+        Some(0x971e),
+        Some(0x97a0),
+        Some(0x97d3),
+        Some(0x9846),
+        Some(0x98b6),
+        Some(0x9935),
+        Some(0x9a01),
+        None,
+    ];
+
+    const BASE_0XC240: [Option<u16>; 192] = [
+        Some(0x99ff),
+        Some(0x9bae),
+        Some(0x9bab),
+        Some(0x9baa),
+        Some(0x9bad),
+        Some(0x9d3b),
+        Some(0x9d3f),
+        Some(0x9e8b),
+        Some(0x9ecf),
+        Some(0x9ede),
+        Some(0x9edc),
+        Some(0x9edd),
+        Some(0x9edb),
+        Some(0x9f3e),
+        Some(0x9f4b),
+        Some(0x53e2),
+        Some(0x5695),
+        Some(0x56ae),
+        Some(0x58d9),
+        Some(0x58d8),
+        Some(0x5b38),
+        Some(0x5f5d),
+        Some(0x61e3),
+        Some(0x6233),
+        Some(0x64f4),
+        Some(0x64f2),
+        Some(0x64fe),
+        Some(0x6506),
+        Some(0x64fa),
+        Some(0x64fb),
+        Some(0x64f7),
+        Some(0x65b7),
+        Some(0x66dc),
+        Some(0x6726),
+        Some(0x6ab3),
+        Some(0x6aac),
+        Some(0x6ac3),
+        Some(0x6abb),
+        Some(0x6ab8),
+        Some(0x6ac2),
+        Some(0x6aae),
+        Some(0x6aaf),
+        Some(0x6b5f),
+        Some(0x6b78),
+        Some(0x6baf),
+        Some(0x7009),
+        Some(0x700b),
+        Some(0x6ffe),
+        Some(0x7006),
+        Some(0x6ffa),
+        Some(0x7011),
+        Some(0x700f),
+        Some(0x71fb),
+        Some(0x71fc),
+        Some(0x71fe),
+        Some(0x71f8),
+        Some(0x7377),
+        Some(0x7375),
+        Some(0x74a7),
+        Some(0x74bf),
+        Some(0x7515),
+        Some(0x7656),
+        Some(0x7658),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7652),
+        Some(0x77bd),
+        Some(0x77bf),
+        Some(0x77bb),
+        Some(0x77bc),
+        Some(0x790e),
+        Some(0x79ae),
+        Some(0x7a61),
+        Some(0x7a62),
+        Some(0x7a60),
+        Some(0x7ac4),
+        Some(0x7ac5),
+        Some(0x7c2b),
+        Some(0x7c27),
+        Some(0x7c2a),
+        Some(0x7c1e),
+        Some(0x7c23),
+        Some(0x7c21),
+        Some(0x7ce7),
+        Some(0x7e54),
+        Some(0x7e55),
+        Some(0x7e5e),
+        Some(0x7e5a),
+        Some(0x7e61),
+        Some(0x7e52),
+        Some(0x7e59),
+        Some(0x7f48),
+        Some(0x7ff9),
+        Some(0x7ffb),
+        Some(0x8077),
+        Some(0x8076),
+        Some(0x81cd),
+        Some(0x81cf),
+        Some(0x820a),
+        Some(0x85cf),
+        Some(0x85a9),
+        Some(0x85cd),
+        Some(0x85d0),
+        Some(0x85c9),
+        Some(0x85b0),
+        Some(0x85ba),
+        Some(0x85b9),
+        Some(0x85a6),
+        Some(0x87ef),
+        Some(0x87ec),
+        Some(0x87f2),
+        Some(0x87e0),
+        Some(0x8986),
+        Some(0x89b2),
+        Some(0x89f4),
+        Some(0x8b28),
+        Some(0x8b39),
+        Some(0x8b2c),
+        Some(0x8b2b),
+        Some(0x8c50),
+        Some(0x8d05),
+        Some(0x8e59),
+        Some(0x8e63),
+        Some(0x8e66),
+        Some(0x8e64),
+        Some(0x8e5f),
+        Some(0x8e55),
+        Some(0x8ec0),
+        Some(0x8f49),
+        Some(0x8f4d),
+        Some(0x9087),
+        Some(0x9083),
+        Some(0x9088),
+        Some(0x91ab),
+        Some(0x91ac),
+        Some(0x91d0),
+        Some(0x9394),
+        Some(0x938a),
+        Some(0x9396),
+        Some(0x93a2),
+        Some(0x93b3),
+        Some(0x93ae),
+        Some(0x93ac),
+        Some(0x93b0),
+        Some(0x9398),
+        Some(0x939a),
+        Some(0x9397),
+        Some(0x95d4),
+        Some(0x95d6),
+        Some(0x95d0),
+        Some(0x95d5),
+        Some(0x96e2),
+        Some(0x96dc),
+        Some(0x96d9),
+        Some(0x96db),
+        Some(0x96de),
+        Some(0x9724),
+        Some(0x97a3),
+        Some(0x97a6),
+        None,
+    ];
+
+    const BASE_0XC340: [Option<u16>; 192] = [
+        Some(0x97ad),
+        Some(0x97f9),
+        Some(0x984d),
+        Some(0x984f),
+        Some(0x984c),
+        Some(0x984e),
+        Some(0x9853),
+        Some(0x98ba),
+        Some(0x993e),
+        Some(0x993f),
+        Some(0x993d),
+        Some(0x992e),
+        Some(0x99a5),
+        Some(0x9a0e),
+        Some(0x9ac1),
+        Some(0x9b03),
+        Some(0x9b06),
+        Some(0x9b4f),
+        Some(0x9b4e),
+        Some(0x9b4d),
+        Some(0x9bca),
+        Some(0x9bc9),
+        Some(0x9bfd),
+        Some(0x9bc8),
+        Some(0x9bc0),
+        Some(0x9d51),
+        Some(0x9d5d),
+        Some(0x9d60),
+        Some(0x9ee0),
+        Some(0x9f15),
+        Some(0x9f2c),
+        Some(0x5133),
+        Some(0x56a5),
+        Some(0x58de),
+        Some(0x58df),
+        Some(0x58e2),
+        Some(0x5bf5),
+        Some(0x9f90),
+        Some(0x5eec),
+        Some(0x61f2),
+        Some(0x61f7),
+        Some(0x61f6),
+        Some(0x61f5),
+        Some(0x6500),
+        Some(0x650f),
+        Some(0x66e0),
+        Some(0x66dd),
+        Some(0x6ae5),
+        Some(0x6add),
+        Some(0x6ada),
+        Some(0x6ad3),
+        Some(0x701b),
+        Some(0x701f),
+        Some(0x7028),
+        Some(0x701a),
+        Some(0x701d),
+        Some(0x7015),
+        Some(0x7018),
+        Some(0x7206),
+        Some(0x720d),
+        Some(0x7258),
+        Some(0x72a2),
+        Some(0x7378),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x737a),
+        Some(0x74bd),
+        Some(0x74ca),
+        Some(0x74e3),
+        Some(0x7587),
+        Some(0x7586),
+        Some(0x765f),
+        Some(0x7661),
+        Some(0x77c7),
+        Some(0x7919),
+        Some(0x79b1),
+        Some(0x7a6b),
+        Some(0x7a69),
+        Some(0x7c3e),
+        Some(0x7c3f),
+        Some(0x7c38),
+        Some(0x7c3d),
+        Some(0x7c37),
+        Some(0x7c40),
+        Some(0x7e6b),
+        Some(0x7e6d),
+        Some(0x7e79),
+        Some(0x7e69),
+        Some(0x7e6a),
+        Some(0x7f85),
+        Some(0x7e73),
+        Some(0x7fb6),
+        Some(0x7fb9),
+        Some(0x7fb8),
+        Some(0x81d8),
+        Some(0x85e9),
+        Some(0x85dd),
+        Some(0x85ea),
+        Some(0x85d5),
+        Some(0x85e4),
+        Some(0x85e5),
+        Some(0x85f7),
+        Some(0x87fb),
+        Some(0x8805),
+        Some(0x880d),
+        Some(0x87f9),
+        Some(0x87fe),
+        Some(0x8960),
+        Some(0x895f),
+        Some(0x8956),
+        Some(0x895e),
+        Some(0x8b41),
+        Some(0x8b5c),
+        Some(0x8b58),
+        Some(0x8b49),
+        Some(0x8b5a),
+        Some(0x8b4e),
+        Some(0x8b4f),
+        Some(0x8b46),
+        Some(0x8b59),
+        Some(0x8d08),
+        Some(0x8d0a),
+        Some(0x8e7c),
+        Some(0x8e72),
+        Some(0x8e87),
+        Some(0x8e76),
+        Some(0x8e6c),
+        Some(0x8e7a),
+        Some(0x8e74),
+        Some(0x8f54),
+        Some(0x8f4e),
+        Some(0x8fad),
+        Some(0x908a),
+        Some(0x908b),
+        Some(0x91b1),
+        Some(0x91ae),
+        Some(0x93e1),
+        Some(0x93d1),
+        Some(0x93df),
+        Some(0x93c3),
+        Some(0x93c8),
+        Some(0x93dc),
+        Some(0x93dd),
+        Some(0x93d6),
+        Some(0x93e2),
+        Some(0x93cd),
+        Some(0x93d8),
+        Some(0x93e4),
+        Some(0x93d7),
+        Some(0x93e8),
+        Some(0x95dc),
+        Some(0x96b4),
+        Some(0x96e3),
+        Some(0x972a),
+        Some(0x9727),
+        Some(0x9761),
+        Some(0x97dc),
+        Some(0x97fb),
+        Some(0x985e),
+        None,
+    ];
+
+    const BASE_0XC440: [Option<u16>; 192] = [
+        Some(0x9858),
+        Some(0x985b),
+        Some(0x98bc),
+        Some(0x9945),
+        Some(0x9949),
+        Some(0x9a16),
+        Some(0x9a19),
+        Some(0x9b0d),
+        Some(0x9be8),
+        Some(0x9be7),
+        Some(0x9bd6),
+        Some(0x9bdb),
+        Some(0x9d89),
+        Some(0x9d61),
+        Some(0x9d72),
+        Some(0x9d6a),
+        Some(0x9d6c),
+        Some(0x9e92),
+        Some(0x9e97),
+        Some(0x9e93),
+        Some(0x9eb4),
+        Some(0x52f8),
+        Some(0x56a8),
+        Some(0x56b7),
+        Some(0x56b6),
+        Some(0x56b4),
+        Some(0x56bc),
+        Some(0x58e4),
+        Some(0x5b40),
+        Some(0x5b43),
+        Some(0x5b7d),
+        Some(0x5bf6),
+        Some(0x5dc9),
+        Some(0x61f8),
+        Some(0x61fa),
+        Some(0x6518),
+        Some(0x6514),
+        Some(0x6519),
+        Some(0x66e6),
+        Some(0x6727),
+        Some(0x6aec),
+        Some(0x703e),
+        Some(0x7030),
+        Some(0x7032),
+        Some(0x7210),
+        Some(0x737b),
+        Some(0x74cf),
+        Some(0x7662),
+        Some(0x7665),
+        Some(0x7926),
+        Some(0x792a),
+        Some(0x792c),
+        Some(0x792b),
+        Some(0x7ac7),
+        Some(0x7af6),
+        Some(0x7c4c),
+        Some(0x7c43),
+        Some(0x7c4d),
+        Some(0x7cef),
+        Some(0x7cf0),
+        Some(0x8fae),
+        Some(0x7e7d),
+        Some(0x7e7c),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7e82),
+        Some(0x7f4c),
+        Some(0x8000),
+        Some(0x81da),
+        Some(0x8266),
+        Some(0x85fb),
+        Some(0x85f9),
+        Some(0x8611),
+        Some(0x85fa),
+        Some(0x8606),
+        Some(0x860b),
+        Some(0x8607),
+        Some(0x860a),
+        Some(0x8814),
+        Some(0x8815),
+        Some(0x8964),
+        Some(0x89ba),
+        Some(0x89f8),
+        Some(0x8b70),
+        Some(0x8b6c),
+        Some(0x8b66),
+        Some(0x8b6f),
+        Some(0x8b5f),
+        Some(0x8b6b),
+        Some(0x8d0f),
+        Some(0x8d0d),
+        Some(0x8e89),
+        Some(0x8e81),
+        Some(0x8e85),
+        Some(0x8e82),
+        Some(0x91b4),
+        Some(0x91cb),
+        Some(0x9418),
+        Some(0x9403),
+        Some(0x93fd),
+        Some(0x95e1),
+        Some(0x9730),
+        Some(0x98c4),
+        Some(0x9952),
+        Some(0x9951),
+        Some(0x99a8),
+        Some(0x9a2b),
+        Some(0x9a30),
+        Some(0x9a37),
+        Some(0x9a35),
+        Some(0x9c13),
+        Some(0x9c0d),
+        Some(0x9e79),
+        Some(0x9eb5),
+        Some(0x9ee8),
+        Some(0x9f2f),
+        Some(0x9f5f),
+        Some(0x9f63),
+        Some(0x9f61),
+        Some(0x5137),
+        Some(0x5138),
+        Some(0x56c1),
+        Some(0x56c0),
+        Some(0x56c2),
+        Some(0x5914),
+        Some(0x5c6c),
+        Some(0x5dcd),
+        Some(0x61fc),
+        Some(0x61fe),
+        Some(0x651d),
+        Some(0x651c),
+        Some(0x6595),
+        Some(0x66e9),
+        Some(0x6afb),
+        Some(0x6b04),
+        Some(0x6afa),
+        Some(0x6bb2),
+        Some(0x704c),
+        Some(0x721b),
+        Some(0x72a7),
+        Some(0x74d6),
+        Some(0x74d4),
+        Some(0x7669),
+        Some(0x77d3),
+        Some(0x7c50),
+        Some(0x7e8f),
+        Some(0x7e8c),
+        Some(0x7fbc),
+        Some(0x8617),
+        Some(0x862d),
+        Some(0x861a),
+        Some(0x8823),
+        Some(0x8822),
+        Some(0x8821),
+        Some(0x881f),
+        Some(0x896a),
+        Some(0x896c),
+        Some(0x89bd),
+        Some(0x8b74),
+        None,
+    ];
+
+    const BASE_0XC540: [Option<u16>; 192] = [
+        Some(0x8b77),
+        Some(0x8b7d),
+        Some(0x8d13),
+        Some(0x8e8a),
+        Some(0x8e8d),
+        Some(0x8e8b),
+        Some(0x8f5f),
+        Some(0x8faf),
+        Some(0x91ba),
+        Some(0x942e),
+        Some(0x9433),
+        Some(0x9435),
+        Some(0x943a),
+        Some(0x9438),
+        Some(0x9432),
+        Some(0x942b),
+        Some(0x95e2),
+        Some(0x9738),
+        Some(0x9739),
+        Some(0x9732),
+        Some(0x97ff),
+        Some(0x9867),
+        Some(0x9865),
+        Some(0x9957),
+        Some(0x9a45),
+        Some(0x9a43),
+        Some(0x9a40),
+        Some(0x9a3e),
+        Some(0x9acf),
+        Some(0x9b54),
+        Some(0x9b51),
+        Some(0x9c2d),
+        Some(0x9c25),
+        Some(0x9daf),
+        Some(0x9db4),
+        Some(0x9dc2),
+        Some(0x9db8),
+        Some(0x9e9d),
+        Some(0x9eef),
+        Some(0x9f19),
+        Some(0x9f5c),
+        Some(0x9f66),
+        Some(0x9f67),
+        Some(0x513c),
+        Some(0x513b),
+        Some(0x56c8),
+        Some(0x56ca),
+        Some(0x56c9),
+        Some(0x5b7f),
+        Some(0x5dd4),
+        Some(0x5dd2),
+        Some(0x5f4e),
+        Some(0x61ff),
+        Some(0x6524),
+        Some(0x6b0a),
+        Some(0x6b61),
+        Some(0x7051),
+        Some(0x7058),
+        Some(0x7380),
+        Some(0x74e4),
+        Some(0x758a),
+        Some(0x766e),
+        Some(0x766c),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x79b3),
+        Some(0x7c60),
+        Some(0x7c5f),
+        Some(0x807e),
+        Some(0x807d),
+        Some(0x81df),
+        Some(0x8972),
+        Some(0x896f),
+        Some(0x89fc),
+        Some(0x8b80),
+        Some(0x8d16),
+        Some(0x8d17),
+        Some(0x8e91),
+        Some(0x8e93),
+        Some(0x8f61),
+        Some(0x9148),
+        Some(0x9444),
+        Some(0x9451),
+        Some(0x9452),
+        Some(0x973d),
+        Some(0x973e),
+        Some(0x97c3),
+        Some(0x97c1),
+        Some(0x986b),
+        Some(0x9955),
+        Some(0x9a55),
+        Some(0x9a4d),
+        Some(0x9ad2),
+        Some(0x9b1a),
+        Some(0x9c49),
+        Some(0x9c31),
+        Some(0x9c3e),
+        Some(0x9c3b),
+        Some(0x9dd3),
+        Some(0x9dd7),
+        Some(0x9f34),
+        Some(0x9f6c),
+        Some(0x9f6a),
+        Some(0x9f94),
+        Some(0x56cc),
+        Some(0x5dd6),
+        Some(0x6200),
+        Some(0x6523),
+        Some(0x652b),
+        Some(0x652a),
+        Some(0x66ec),
+        Some(0x6b10),
+        Some(0x74da),
+        Some(0x7aca),
+        Some(0x7c64),
+        Some(0x7c63),
+        Some(0x7c65),
+        Some(0x7e93),
+        Some(0x7e96),
+        Some(0x7e94),
+        Some(0x81e2),
+        Some(0x8638),
+        Some(0x863f),
+        Some(0x8831),
+        Some(0x8b8a),
+        Some(0x9090),
+        Some(0x908f),
+        Some(0x9463),
+        Some(0x9460),
+        Some(0x9464),
+        Some(0x9768),
+        Some(0x986f),
+        Some(0x995c),
+        Some(0x9a5a),
+        Some(0x9a5b),
+        Some(0x9a57),
+        Some(0x9ad3),
+        Some(0x9ad4),
+        Some(0x9ad1),
+        Some(0x9c54),
+        Some(0x9c57),
+        Some(0x9c56),
+        Some(0x9de5),
+        Some(0x9e9f),
+        Some(0x9ef4),
+        Some(0x56d1),
+        Some(0x58e9),
+        Some(0x652c),
+        Some(0x705e),
+        Some(0x7671),
+        Some(0x7672),
+        Some(0x77d7),
+        Some(0x7f50),
+        Some(0x7f88),
+        Some(0x8836),
+        Some(0x8839),
+        Some(0x8862),
+        Some(0x8b93),
+        Some(0x8b92),
+        None,
+    ];
+
+    const BASE_0XC640: [Option<u16>; 192] = [
+        Some(0x8b96),
+        Some(0x8277),
+        Some(0x8d1b),
+        Some(0x91c0),
+        Some(0x946a),
+        Some(0x9742),
+        Some(0x9748),
+        Some(0x9744),
+        Some(0x97c6),
+        Some(0x9870),
+        Some(0x9a5f),
+        Some(0x9b22),
+        Some(0x9b58),
+        Some(0x9c5f),
+        Some(0x9df9),
+        Some(0x9dfa),
+        Some(0x9e7c),
+        Some(0x9e7d),
+        Some(0x9f07),
+        Some(0x9f77),
+        Some(0x9f72),
+        Some(0x5ef3),
+        Some(0x6b16),
+        Some(0x7063),
+        Some(0x7c6c),
+        Some(0x7c6e),
+        Some(0x883b),
+        Some(0x89c0),
+        Some(0x8ea1),
+        Some(0x91c1),
+        Some(0x9472),
+        Some(0x9470),
+        Some(0x9871),
+        Some(0x995e),
+        Some(0x9ad6),
+        Some(0x9b23),
+        Some(0x9ecc),
+        Some(0x7064),
+        Some(0x77da),
+        Some(0x8b9a),
+        Some(0x9477),
+        Some(0x97c9),
+        Some(0x9a62),
+        Some(0x9a65),
+        Some(0x7e9c),
+        Some(0x8b9c),
+        Some(0x8eaa),
+        Some(0x91c5),
+        Some(0x947d),
+        Some(0x947e),
+        Some(0x947c),
+        Some(0x9c77),
+        Some(0x9c78),
+        Some(0x9ef7),
+        Some(0x8c54),
+        Some(0x947f),
+        Some(0x9e1a),
+        Some(0x7228),
+        Some(0x9a6a),
+        Some(0x9b31),
+        Some(0x9e1b),
+        Some(0x9e1e),
+        Some(0x7c72),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30fe),
+        Some(0x309d),
+        Some(0x309e),
+        Some(0x3005),
+        Some(0x3041),
+        Some(0x3042),
+        Some(0x3043),
+        Some(0x3044),
+        Some(0x3045),
+        Some(0x3046),
+        Some(0x3047),
+        Some(0x3048),
+        Some(0x3049),
+        Some(0x304a),
+        Some(0x304b),
+        Some(0x304c),
+        Some(0x304d),
+        Some(0x304e),
+        Some(0x304f),
+        Some(0x3050),
+        Some(0x3051),
+        Some(0x3052),
+        Some(0x3053),
+        Some(0x3054),
+        Some(0x3055),
+        Some(0x3056),
+        Some(0x3057),
+        Some(0x3058),
+        Some(0x3059),
+        Some(0x305a),
+        Some(0x305b),
+        Some(0x305c),
+        Some(0x305d),
+        Some(0x305e),
+        Some(0x305f),
+        Some(0x3060),
+        Some(0x3061),
+        Some(0x3062),
+        Some(0x3063),
+        Some(0x3064),
+        Some(0x3065),
+        Some(0x3066),
+        Some(0x3067),
+        Some(0x3068),
+        Some(0x3069),
+        Some(0x306a),
+        Some(0x306b),
+        Some(0x306c),
+        Some(0x306d),
+        Some(0x306e),
+        Some(0x306f),
+        Some(0x3070),
+        Some(0x3071),
+        Some(0x3072),
+        Some(0x3073),
+        Some(0x3074),
+        Some(0x3075),
+        Some(0x3076),
+        Some(0x3077),
+        Some(0x3078),
+        Some(0x3079),
+        Some(0x307a),
+        Some(0x307b),
+        Some(0x307c),
+        Some(0x307d),
+        Some(0x307e),
+        Some(0x307f),
+        Some(0x3080),
+        Some(0x3081),
+        Some(0x3082),
+        Some(0x3083),
+        Some(0x3084),
+        Some(0x3085),
+        Some(0x3086),
+        Some(0x3087),
+        Some(0x3088),
+        Some(0x3089),
+        Some(0x308a),
+        Some(0x308b),
+        Some(0x308c),
+        Some(0x308d),
+        Some(0x308e),
+        Some(0x308f),
+        Some(0x3090),
+        Some(0x3091),
+        Some(0x3092),
+        Some(0x3093),
+        Some(0x30a1),
+        Some(0x30a2),
+        Some(0x30a3),
+        Some(0x30a4),
+        Some(0x30a5),
+        Some(0x30a6),
+        Some(0x30a7),
+        None,
+    ];
+
+    const BASE_0XC740: [Option<u16>; 192] = [
+        Some(0x30a8),
+        Some(0x30a9),
+        Some(0x30aa),
+        Some(0x30ab),
+        Some(0x30ac),
+        Some(0x30ad),
+        Some(0x30ae),
+        Some(0x30af),
+        Some(0x30b0),
+        Some(0x30b1),
+        Some(0x30b2),
+        Some(0x30b3),
+        Some(0x30b4),
+        Some(0x30b5),
+        Some(0x30b6),
+        Some(0x30b7),
+        Some(0x30b8),
+        Some(0x30b9),
+        Some(0x30ba),
+        Some(0x30bb),
+        Some(0x30bc),
+        Some(0x30bd),
+        Some(0x30be),
+        Some(0x30bf),
+        Some(0x30c0),
+        Some(0x30c1),
+        Some(0x30c2),
+        Some(0x30c3),
+        Some(0x30c4),
+        Some(0x30c5),
+        Some(0x30c6),
+        Some(0x30c7),
+        Some(0x30c8),
+        Some(0x30c9),
+        Some(0x30ca),
+        Some(0x30cb),
+        Some(0x30cc),
+        Some(0x30cd),
+        Some(0x30ce),
+        Some(0x30cf),
+        Some(0x30d0),
+        Some(0x30d1),
+        Some(0x30d2),
+        Some(0x30d3),
+        Some(0x30d4),
+        Some(0x30d5),
+        Some(0x30d6),
+        Some(0x30d7),
+        Some(0x30d8),
+        Some(0x30d9),
+        Some(0x30da),
+        Some(0x30db),
+        Some(0x30dc),
+        Some(0x30dd),
+        Some(0x30de),
+        Some(0x30df),
+        Some(0x30e0),
+        Some(0x30e1),
+        Some(0x30e2),
+        Some(0x30e3),
+        Some(0x30e4),
+        Some(0x30e5),
+        Some(0x30e6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x30e7),
+        Some(0x30e8),
+        Some(0x30e9),
+        Some(0x30ea),
+        Some(0x30eb),
+        Some(0x30ec),
+        Some(0x30ed),
+        Some(0x30ee),
+        Some(0x30ef),
+        Some(0x30f0),
+        Some(0x30f1),
+        Some(0x30f2),
+        Some(0x30f3),
+        Some(0x30f4),
+        Some(0x30f5),
+        Some(0x30f6),
+        Some(0x0414),
+        Some(0x0415),
+        Some(0x0401),
+        Some(0x0416),
+        Some(0x0417),
+        Some(0x0418),
+        Some(0x0419),
+        Some(0x041a),
+        Some(0x041b),
+        Some(0x041c),
+        Some(0x0423),
+        Some(0x0424),
+        Some(0x0425),
+        Some(0x0426),
+        Some(0x0427),
+        Some(0x0428),
+        Some(0x0429),
+        Some(0x042a),
+        Some(0x042b),
+        Some(0x042c),
+        Some(0x042d),
+        Some(0x042e),
+        Some(0x042f),
+        Some(0x0430),
+        Some(0x0431),
+        Some(0x0432),
+        Some(0x0433),
+        Some(0x0434),
+        Some(0x0435),
+        Some(0x0451),
+        Some(0x0436),
+        Some(0x0437),
+        Some(0x0438),
+        Some(0x0439),
+        Some(0x043a),
+        Some(0x043b),
+        Some(0x043c),
+        Some(0x043d),
+        Some(0x043e),
+        Some(0x043f),
+        Some(0x0440),
+        Some(0x0441),
+        Some(0x0442),
+        Some(0x0443),
+        Some(0x0444),
+        Some(0x0445),
+        Some(0x0446),
+        Some(0x0447),
+        Some(0x0448),
+        Some(0x0449),
+        Some(0x044a),
+        Some(0x044b),
+        Some(0x044c),
+        Some(0x044d),
+        Some(0x044e),
+        Some(0x044f),
+        Some(0x2460),
+        Some(0x2461),
+        Some(0x2462),
+        Some(0x2463),
+        Some(0x2464),
+        Some(0x2465),
+        Some(0x2466),
+        Some(0x2467),
+        Some(0x2468),
+        Some(0x2469),
+        Some(0x2474),
+        Some(0x2475),
+        Some(0x2476),
+        Some(0x2477),
+        Some(0x2478),
+        Some(0x2479),
+        Some(0x247a),
+        Some(0x247b),
+        Some(0x247c),
+        Some(0x247d),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XC940: [Option<u16>; 192] = [
+        Some(0x4e42),
+        Some(0x4e5c),
+        Some(0x51f5),
+        Some(0x531a),
+        Some(0x5382),
+        Some(0x4e07),
+        Some(0x4e0c),
+        Some(0x4e47),
+        Some(0x4e8d),
+        Some(0x56d7),
+        Some(0xfa0c),
+        Some(0x5c6e),
+        Some(0x5f73),
+        Some(0x4e0f),
+        Some(0x5187),
+        Some(0x4e0e),
+        Some(0x4e2e),
+        Some(0x4e93),
+        Some(0x4ec2),
+        Some(0x4ec9),
+        Some(0x4ec8),
+        Some(0x5198),
+        Some(0x52fc),
+        Some(0x536c),
+        Some(0x53b9),
+        Some(0x5720),
+        Some(0x5903),
+        Some(0x592c),
+        Some(0x5c10),
+        Some(0x5dff),
+        Some(0x65e1),
+        Some(0x6bb3),
+        Some(0x6bcc),
+        Some(0x6c14),
+        Some(0x723f),
+        Some(0x4e31),
+        Some(0x4e3c),
+        Some(0x4ee8),
+        Some(0x4edc),
+        Some(0x4ee9),
+        Some(0x4ee1),
+        Some(0x4edd),
+        Some(0x4eda),
+        Some(0x520c),
+        Some(0x531c),
+        Some(0x534c),
+        Some(0x5722),
+        Some(0x5723),
+        Some(0x5917),
+        Some(0x592f),
+        Some(0x5b81),
+        Some(0x5b84),
+        Some(0x5c12),
+        Some(0x5c3b),
+        Some(0x5c74),
+        Some(0x5c73),
+        Some(0x5e04),
+        Some(0x5e80),
+        Some(0x5e82),
+        Some(0x5fc9),
+        Some(0x6209),
+        Some(0x6250),
+        Some(0x6c15),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6c36),
+        Some(0x6c43),
+        Some(0x6c3f),
+        Some(0x6c3b),
+        Some(0x72ae),
+        Some(0x72b0),
+        Some(0x738a),
+        Some(0x79b8),
+        Some(0x808a),
+        Some(0x961e),
+        Some(0x4f0e),
+        Some(0x4f18),
+        Some(0x4f2c),
+        Some(0x4ef5),
+        Some(0x4f14),
+        Some(0x4ef1),
+        Some(0x4f00),
+        Some(0x4ef7),
+        Some(0x4f08),
+        Some(0x4f1d),
+        Some(0x4f02),
+        Some(0x4f05),
+        Some(0x4f22),
+        Some(0x4f13),
+        Some(0x4f04),
+        Some(0x4ef4),
+        Some(0x4f12),
+        Some(0x51b1),
+        Some(0x5213),
+        Some(0x5209),
+        Some(0x5210),
+        Some(0x52a6),
+        Some(0x5322),
+        Some(0x531f),
+        Some(0x534d),
+        Some(0x538a),
+        Some(0x5407),
+        Some(0x56e1),
+        Some(0x56df),
+        Some(0x572e),
+        Some(0x572a),
+        Some(0x5734),
+        Some(0x593c),
+        Some(0x5980),
+        Some(0x597c),
+        Some(0x5985),
+        Some(0x597b),
+        Some(0x597e),
+        Some(0x5977),
+        Some(0x597f),
+        Some(0x5b56),
+        Some(0x5c15),
+        Some(0x5c25),
+        Some(0x5c7c),
+        Some(0x5c7a),
+        Some(0x5c7b),
+        Some(0x5c7e),
+        Some(0x5ddf),
+        Some(0x5e75),
+        Some(0x5e84),
+        Some(0x5f02),
+        Some(0x5f1a),
+        Some(0x5f74),
+        Some(0x5fd5),
+        Some(0x5fd4),
+        Some(0x5fcf),
+        Some(0x625c),
+        Some(0x625e),
+        Some(0x6264),
+        Some(0x6261),
+        Some(0x6266),
+        Some(0x6262),
+        Some(0x6259),
+        Some(0x6260),
+        Some(0x625a),
+        Some(0x6265),
+        Some(0x65ef),
+        Some(0x65ee),
+        Some(0x673e),
+        Some(0x6739),
+        Some(0x6738),
+        Some(0x673b),
+        Some(0x673a),
+        Some(0x673f),
+        Some(0x673c),
+        Some(0x6733),
+        Some(0x6c18),
+        Some(0x6c46),
+        Some(0x6c52),
+        Some(0x6c5c),
+        Some(0x6c4f),
+        Some(0x6c4a),
+        Some(0x6c54),
+        Some(0x6c4b),
+        None,
+    ];
+
+    const BASE_0XCA40: [Option<u16>; 192] = [
+        Some(0x6c4c),
+        Some(0x7071),
+        Some(0x725e),
+        Some(0x72b4),
+        Some(0x72b5),
+        Some(0x738e),
+        Some(0x752a),
+        Some(0x767f),
+        Some(0x7a75),
+        Some(0x7f51),
+        Some(0x8278),
+        Some(0x827c),
+        Some(0x8280),
+        Some(0x827d),
+        Some(0x827f),
+        Some(0x864d),
+        Some(0x897e),
+        Some(0x9099),
+        Some(0x9097),
+        Some(0x9098),
+        Some(0x909b),
+        Some(0x9094),
+        Some(0x9622),
+        Some(0x9624),
+        Some(0x9620),
+        Some(0x9623),
+        Some(0x4f56),
+        Some(0x4f3b),
+        Some(0x4f62),
+        Some(0x4f49),
+        Some(0x4f53),
+        Some(0x4f64),
+        Some(0x4f3e),
+        Some(0x4f67),
+        Some(0x4f52),
+        Some(0x4f5f),
+        Some(0x4f41),
+        Some(0x4f58),
+        Some(0x4f2d),
+        Some(0x4f33),
+        Some(0x4f3f),
+        Some(0x4f61),
+        Some(0x518f),
+        Some(0x51b9),
+        Some(0x521c),
+        Some(0x521e),
+        Some(0x5221),
+        Some(0x52ad),
+        Some(0x52ae),
+        Some(0x5309),
+        Some(0x5363),
+        Some(0x5372),
+        Some(0x538e),
+        Some(0x538f),
+        Some(0x5430),
+        Some(0x5437),
+        Some(0x542a),
+        Some(0x5454),
+        Some(0x5445),
+        Some(0x5419),
+        Some(0x541c),
+        Some(0x5425),
+        Some(0x5418),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x543d),
+        Some(0x544f),
+        Some(0x5441),
+        Some(0x5428),
+        Some(0x5424),
+        Some(0x5447),
+        Some(0x56ee),
+        Some(0x56e7),
+        Some(0x56e5),
+        Some(0x5741),
+        Some(0x5745),
+        Some(0x574c),
+        Some(0x5749),
+        Some(0x574b),
+        Some(0x5752),
+        Some(0x5906),
+        Some(0x5940),
+        Some(0x59a6),
+        Some(0x5998),
+        Some(0x59a0),
+        Some(0x5997),
+        Some(0x598e),
+        Some(0x59a2),
+        Some(0x5990),
+        Some(0x598f),
+        Some(0x59a7),
+        Some(0x59a1),
+        Some(0x5b8e),
+        Some(0x5b92),
+        Some(0x5c28),
+        Some(0x5c2a),
+        Some(0x5c8d),
+        Some(0x5c8f),
+        Some(0x5c88),
+        Some(0x5c8b),
+        Some(0x5c89),
+        Some(0x5c92),
+        Some(0x5c8a),
+        Some(0x5c86),
+        Some(0x5c93),
+        Some(0x5c95),
+        Some(0x5de0),
+        Some(0x5e0a),
+        Some(0x5e0e),
+        Some(0x5e8b),
+        Some(0x5e89),
+        Some(0x5e8c),
+        Some(0x5e88),
+        Some(0x5e8d),
+        Some(0x5f05),
+        Some(0x5f1d),
+        Some(0x5f78),
+        Some(0x5f76),
+        Some(0x5fd2),
+        Some(0x5fd1),
+        Some(0x5fd0),
+        Some(0x5fed),
+        Some(0x5fe8),
+        Some(0x5fee),
+        Some(0x5ff3),
+        Some(0x5fe1),
+        Some(0x5fe4),
+        Some(0x5fe3),
+        Some(0x5ffa),
+        Some(0x5fef),
+        Some(0x5ff7),
+        Some(0x5ffb),
+        Some(0x6000),
+        Some(0x5ff4),
+        Some(0x623a),
+        Some(0x6283),
+        Some(0x628c),
+        Some(0x628e),
+        Some(0x628f),
+        Some(0x6294),
+        Some(0x6287),
+        Some(0x6271),
+        Some(0x627b),
+        Some(0x627a),
+        Some(0x6270),
+        Some(0x6281),
+        Some(0x6288),
+        Some(0x6277),
+        Some(0x627d),
+        Some(0x6272),
+        Some(0x6274),
+        Some(0x6537),
+        Some(0x65f0),
+        Some(0x65f4),
+        Some(0x65f3),
+        Some(0x65f2),
+        Some(0x65f5),
+        Some(0x6745),
+        Some(0x6747),
+        None,
+    ];
+
+    const BASE_0XCB40: [Option<u16>; 192] = [
+        Some(0x6759),
+        Some(0x6755),
+        Some(0x674c),
+        Some(0x6748),
+        Some(0x675d),
+        Some(0x674d),
+        Some(0x675a),
+        Some(0x674b),
+        Some(0x6bd0),
+        Some(0x6c19),
+        Some(0x6c1a),
+        Some(0x6c78),
+        Some(0x6c67),
+        Some(0x6c6b),
+        Some(0x6c84),
+        Some(0x6c8b),
+        Some(0x6c8f),
+        Some(0x6c71),
+        Some(0x6c6f),
+        Some(0x6c69),
+        Some(0x6c9a),
+        Some(0x6c6d),
+        Some(0x6c87),
+        Some(0x6c95),
+        Some(0x6c9c),
+        Some(0x6c66),
+        Some(0x6c73),
+        Some(0x6c65),
+        Some(0x6c7b),
+        Some(0x6c8e),
+        Some(0x7074),
+        Some(0x707a),
+        Some(0x7263),
+        Some(0x72bf),
+        Some(0x72bd),
+        Some(0x72c3),
+        Some(0x72c6),
+        Some(0x72c1),
+        Some(0x72ba),
+        Some(0x72c5),
+        Some(0x7395),
+        Some(0x7397),
+        Some(0x7393),
+        Some(0x7394),
+        Some(0x7392),
+        Some(0x753a),
+        Some(0x7539),
+        Some(0x7594),
+        Some(0x7595),
+        Some(0x7681),
+        Some(0x793d),
+        Some(0x8034),
+        Some(0x8095),
+        Some(0x8099),
+        Some(0x8090),
+        Some(0x8092),
+        Some(0x809c),
+        Some(0x8290),
+        Some(0x828f),
+        Some(0x8285),
+        Some(0x828e),
+        Some(0x8291),
+        Some(0x8293),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x828a),
+        Some(0x8283),
+        Some(0x8284),
+        Some(0x8c78),
+        Some(0x8fc9),
+        Some(0x8fbf),
+        Some(0x909f),
+        Some(0x90a1),
+        Some(0x90a5),
+        Some(0x909e),
+        Some(0x90a7),
+        Some(0x90a0),
+        Some(0x9630),
+        Some(0x9628),
+        Some(0x962f),
+        Some(0x962d),
+        Some(0x4e33),
+        Some(0x4f98),
+        Some(0x4f7c),
+        Some(0x4f85),
+        Some(0x4f7d),
+        Some(0x4f80),
+        Some(0x4f87),
+        Some(0x4f76),
+        Some(0x4f74),
+        Some(0x4f89),
+        Some(0x4f84),
+        Some(0x4f77),
+        Some(0x4f4c),
+        Some(0x4f97),
+        Some(0x4f6a),
+        Some(0x4f9a),
+        Some(0x4f79),
+        Some(0x4f81),
+        Some(0x4f78),
+        Some(0x4f90),
+        Some(0x4f9c),
+        Some(0x4f94),
+        Some(0x4f9e),
+        Some(0x4f92),
+        Some(0x4f82),
+        Some(0x4f95),
+        Some(0x4f6b),
+        Some(0x4f6e),
+        Some(0x519e),
+        Some(0x51bc),
+        Some(0x51be),
+        Some(0x5235),
+        Some(0x5232),
+        Some(0x5233),
+        Some(0x5246),
+        Some(0x5231),
+        Some(0x52bc),
+        Some(0x530a),
+        Some(0x530b),
+        Some(0x533c),
+        Some(0x5392),
+        Some(0x5394),
+        Some(0x5487),
+        Some(0x547f),
+        Some(0x5481),
+        Some(0x5491),
+        Some(0x5482),
+        Some(0x5488),
+        Some(0x546b),
+        Some(0x547a),
+        Some(0x547e),
+        Some(0x5465),
+        Some(0x546c),
+        Some(0x5474),
+        Some(0x5466),
+        Some(0x548d),
+        Some(0x546f),
+        Some(0x5461),
+        Some(0x5460),
+        Some(0x5498),
+        Some(0x5463),
+        Some(0x5467),
+        Some(0x5464),
+        Some(0x56f7),
+        Some(0x56f9),
+        Some(0x576f),
+        Some(0x5772),
+        Some(0x576d),
+        Some(0x576b),
+        Some(0x5771),
+        Some(0x5770),
+        Some(0x5776),
+        Some(0x5780),
+        Some(0x5775),
+        Some(0x577b),
+        Some(0x5773),
+        Some(0x5774),
+        Some(0x5762),
+        None,
+    ];
+
+    const BASE_0XCC40: [Option<u16>; 192] = [
+        Some(0x5768),
+        Some(0x577d),
+        Some(0x590c),
+        Some(0x5945),
+        Some(0x59b5),
+        Some(0x59ba),
+        Some(0x59cf),
+        Some(0x59ce),
+        Some(0x59b2),
+        Some(0x59cc),
+        Some(0x59c1),
+        Some(0x59b6),
+        Some(0x59bc),
+        Some(0x59c3),
+        Some(0x59d6),
+        Some(0x59b1),
+        Some(0x59bd),
+        Some(0x59c0),
+        Some(0x59c8),
+        Some(0x59b4),
+        Some(0x59c7),
+        Some(0x5b62),
+        Some(0x5b65),
+        Some(0x5b93),
+        Some(0x5b95),
+        Some(0x5c44),
+        Some(0x5c47),
+        Some(0x5cae),
+        Some(0x5ca4),
+        Some(0x5ca0),
+        Some(0x5cb5),
+        Some(0x5caf),
+        Some(0x5ca8),
+        Some(0x5cac),
+        Some(0x5c9f),
+        Some(0x5ca3),
+        Some(0x5cad),
+        Some(0x5ca2),
+        Some(0x5caa),
+        Some(0x5ca7),
+        Some(0x5c9d),
+        Some(0x5ca5),
+        Some(0x5cb6),
+        Some(0x5cb0),
+        Some(0x5ca6),
+        Some(0x5e17),
+        Some(0x5e14),
+        Some(0x5e19),
+        Some(0x5f28),
+        Some(0x5f22),
+        Some(0x5f23),
+        Some(0x5f24),
+        Some(0x5f54),
+        Some(0x5f82),
+        Some(0x5f7e),
+        Some(0x5f7d),
+        Some(0x5fde),
+        Some(0x5fe5),
+        Some(0x602d),
+        Some(0x6026),
+        Some(0x6019),
+        Some(0x6032),
+        Some(0x600b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6034),
+        Some(0x600a),
+        Some(0x6017),
+        Some(0x6033),
+        Some(0x601a),
+        Some(0x601e),
+        Some(0x602c),
+        Some(0x6022),
+        Some(0x600d),
+        Some(0x6010),
+        Some(0x602e),
+        Some(0x6013),
+        Some(0x6011),
+        Some(0x600c),
+        Some(0x6009),
+        Some(0x601c),
+        Some(0x6214),
+        Some(0x623d),
+        Some(0x62ad),
+        Some(0x62b4),
+        Some(0x62d1),
+        Some(0x62be),
+        Some(0x62aa),
+        Some(0x62b6),
+        Some(0x62ca),
+        Some(0x62ae),
+        Some(0x62b3),
+        Some(0x62af),
+        Some(0x62bb),
+        Some(0x62a9),
+        Some(0x62b0),
+        Some(0x62b8),
+        Some(0x653d),
+        Some(0x65a8),
+        Some(0x65bb),
+        Some(0x6609),
+        Some(0x65fc),
+        Some(0x6604),
+        Some(0x6612),
+        Some(0x6608),
+        Some(0x65fb),
+        Some(0x6603),
+        Some(0x660b),
+        Some(0x660d),
+        Some(0x6605),
+        Some(0x65fd),
+        Some(0x6611),
+        Some(0x6610),
+        Some(0x66f6),
+        Some(0x670a),
+        Some(0x6785),
+        Some(0x676c),
+        Some(0x678e),
+        Some(0x6792),
+        Some(0x6776),
+        Some(0x677b),
+        Some(0x6798),
+        Some(0x6786),
+        Some(0x6784),
+        Some(0x6774),
+        Some(0x678d),
+        Some(0x678c),
+        Some(0x677a),
+        Some(0x679f),
+        Some(0x6791),
+        Some(0x6799),
+        Some(0x6783),
+        Some(0x677d),
+        Some(0x6781),
+        Some(0x6778),
+        Some(0x6779),
+        Some(0x6794),
+        Some(0x6b25),
+        Some(0x6b80),
+        Some(0x6b7e),
+        Some(0x6bde),
+        Some(0x6c1d),
+        Some(0x6c93),
+        Some(0x6cec),
+        Some(0x6ceb),
+        Some(0x6cee),
+        Some(0x6cd9),
+        Some(0x6cb6),
+        Some(0x6cd4),
+        Some(0x6cad),
+        Some(0x6ce7),
+        Some(0x6cb7),
+        Some(0x6cd0),
+        Some(0x6cc2),
+        Some(0x6cba),
+        Some(0x6cc3),
+        Some(0x6cc6),
+        Some(0x6ced),
+        Some(0x6cf2),
+        None,
+    ];
+
+    const BASE_0XCD40: [Option<u16>; 192] = [
+        Some(0x6cd2),
+        Some(0x6cdd),
+        Some(0x6cb4),
+        Some(0x6c8a),
+        Some(0x6c9d),
+        Some(0x6c80),
+        Some(0x6cde),
+        Some(0x6cc0),
+        Some(0x6d30),
+        Some(0x6ccd),
+        Some(0x6cc7),
+        Some(0x6cb0),
+        Some(0x6cf9),
+        Some(0x6ccf),
+        Some(0x6ce9),
+        Some(0x6cd1),
+        Some(0x7094),
+        Some(0x7098),
+        Some(0x7085),
+        Some(0x7093),
+        Some(0x7086),
+        Some(0x7084),
+        Some(0x7091),
+        Some(0x7096),
+        Some(0x7082),
+        Some(0x709a),
+        Some(0x7083),
+        Some(0x726a),
+        Some(0x72d6),
+        Some(0x72cb),
+        Some(0x72d8),
+        Some(0x72c9),
+        Some(0x72dc),
+        Some(0x72d2),
+        Some(0x72d4),
+        Some(0x72da),
+        Some(0x72cc),
+        Some(0x72d1),
+        Some(0x73a4),
+        Some(0x73a1),
+        Some(0x73ad),
+        Some(0x73a6),
+        Some(0x73a2),
+        Some(0x73a0),
+        Some(0x73ac),
+        Some(0x739d),
+        Some(0x74dd),
+        Some(0x74e8),
+        Some(0x753f),
+        Some(0x7540),
+        Some(0x753e),
+        Some(0x758c),
+        Some(0x7598),
+        Some(0x76af),
+        Some(0x76f3),
+        Some(0x76f1),
+        Some(0x76f0),
+        Some(0x76f5),
+        Some(0x77f8),
+        Some(0x77fc),
+        Some(0x77f9),
+        Some(0x77fb),
+        Some(0x77fa),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x77f7),
+        Some(0x7942),
+        Some(0x793f),
+        Some(0x79c5),
+        Some(0x7a78),
+        Some(0x7a7b),
+        Some(0x7afb),
+        Some(0x7c75),
+        Some(0x7cfd),
+        Some(0x8035),
+        Some(0x808f),
+        Some(0x80ae),
+        Some(0x80a3),
+        Some(0x80b8),
+        Some(0x80b5),
+        Some(0x80ad),
+        Some(0x8220),
+        Some(0x82a0),
+        Some(0x82c0),
+        Some(0x82ab),
+        Some(0x829a),
+        Some(0x8298),
+        Some(0x829b),
+        Some(0x82b5),
+        Some(0x82a7),
+        Some(0x82ae),
+        Some(0x82bc),
+        Some(0x829e),
+        Some(0x82ba),
+        Some(0x82b4),
+        Some(0x82a8),
+        Some(0x82a1),
+        Some(0x82a9),
+        Some(0x82c2),
+        Some(0x82a4),
+        Some(0x82c3),
+        Some(0x82b6),
+        Some(0x82a2),
+        Some(0x8670),
+        Some(0x866f),
+        Some(0x866d),
+        Some(0x866e),
+        Some(0x8c56),
+        Some(0x8fd2),
+        Some(0x8fcb),
+        Some(0x8fd3),
+        Some(0x8fcd),
+        Some(0x8fd6),
+        Some(0x8fd5),
+        Some(0x8fd7),
+        Some(0x90b2),
+        Some(0x90b4),
+        Some(0x90af),
+        Some(0x90b3),
+        Some(0x90b0),
+        Some(0x9639),
+        Some(0x963d),
+        Some(0x963c),
+        Some(0x963a),
+        Some(0x9643),
+        Some(0x4fcd),
+        Some(0x4fc5),
+        Some(0x4fd3),
+        Some(0x4fb2),
+        Some(0x4fc9),
+        Some(0x4fcb),
+        Some(0x4fc1),
+        Some(0x4fd4),
+        Some(0x4fdc),
+        Some(0x4fd9),
+        Some(0x4fbb),
+        Some(0x4fb3),
+        Some(0x4fdb),
+        Some(0x4fc7),
+        Some(0x4fd6),
+        Some(0x4fba),
+        Some(0x4fc0),
+        Some(0x4fb9),
+        Some(0x4fec),
+        Some(0x5244),
+        Some(0x5249),
+        Some(0x52c0),
+        Some(0x52c2),
+        Some(0x533d),
+        Some(0x537c),
+        Some(0x5397),
+        Some(0x5396),
+        Some(0x5399),
+        Some(0x5398),
+        Some(0x54ba),
+        Some(0x54a1),
+        Some(0x54ad),
+        Some(0x54a5),
+        Some(0x54cf),
+        None,
+    ];
+
+    const BASE_0XCE40: [Option<u16>; 192] = [
+        Some(0x54c3),
+        Some(0x830d),
+        Some(0x54b7),
+        Some(0x54ae),
+        Some(0x54d6),
+        Some(0x54b6),
+        Some(0x54c5),
+        Some(0x54c6),
+        Some(0x54a0),
+        Some(0x5470),
+        Some(0x54bc),
+        Some(0x54a2),
+        Some(0x54be),
+        Some(0x5472),
+        Some(0x54de),
+        Some(0x54b0),
+        Some(0x57b5),
+        Some(0x579e),
+        Some(0x579f),
+        Some(0x57a4),
+        Some(0x578c),
+        Some(0x5797),
+        Some(0x579d),
+        Some(0x579b),
+        Some(0x5794),
+        Some(0x5798),
+        Some(0x578f),
+        Some(0x5799),
+        Some(0x57a5),
+        Some(0x579a),
+        Some(0x5795),
+        Some(0x58f4),
+        Some(0x590d),
+        Some(0x5953),
+        Some(0x59e1),
+        Some(0x59de),
+        Some(0x59ee),
+        Some(0x5a00),
+        Some(0x59f1),
+        Some(0x59dd),
+        Some(0x59fa),
+        Some(0x59fd),
+        Some(0x59fc),
+        Some(0x59f6),
+        Some(0x59e4),
+        Some(0x59f2),
+        Some(0x59f7),
+        Some(0x59db),
+        Some(0x59e9),
+        Some(0x59f3),
+        Some(0x59f5),
+        Some(0x59e0),
+        Some(0x59fe),
+        Some(0x59f4),
+        Some(0x59ed),
+        Some(0x5ba8),
+        Some(0x5c4c),
+        Some(0x5cd0),
+        Some(0x5cd8),
+        Some(0x5ccc),
+        Some(0x5cd7),
+        Some(0x5ccb),
+        Some(0x5cdb),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5cde),
+        Some(0x5cda),
+        Some(0x5cc9),
+        Some(0x5cc7),
+        Some(0x5cca),
+        Some(0x5cd6),
+        Some(0x5cd3),
+        Some(0x5cd4),
+        Some(0x5ccf),
+        Some(0x5cc8),
+        Some(0x5cc6),
+        Some(0x5cce),
+        Some(0x5cdf),
+        Some(0x5cf8),
+        Some(0x5df9),
+        Some(0x5e21),
+        Some(0x5e22),
+        Some(0x5e23),
+        Some(0x5e20),
+        Some(0x5e24),
+        Some(0x5eb0),
+        Some(0x5ea4),
+        Some(0x5ea2),
+        Some(0x5e9b),
+        Some(0x5ea3),
+        Some(0x5ea5),
+        Some(0x5f07),
+        Some(0x5f2e),
+        Some(0x5f56),
+        Some(0x5f86),
+        Some(0x6037),
+        Some(0x6039),
+        Some(0x6054),
+        Some(0x6072),
+        Some(0x605e),
+        Some(0x6045),
+        Some(0x6053),
+        Some(0x6047),
+        Some(0x6049),
+        Some(0x605b),
+        Some(0x604c),
+        Some(0x6040),
+        Some(0x6042),
+        Some(0x605f),
+        Some(0x6024),
+        Some(0x6044),
+        Some(0x6058),
+        Some(0x6066),
+        Some(0x606e),
+        Some(0x6242),
+        Some(0x6243),
+        Some(0x62cf),
+        Some(0x630d),
+        Some(0x630b),
+        Some(0x62f5),
+        Some(0x630e),
+        Some(0x6303),
+        Some(0x62eb),
+        Some(0x62f9),
+        Some(0x630f),
+        Some(0x630c),
+        Some(0x62f8),
+        Some(0x62f6),
+        Some(0x6300),
+        Some(0x6313),
+        Some(0x6314),
+        Some(0x62fa),
+        Some(0x6315),
+        Some(0x62fb),
+        Some(0x62f0),
+        Some(0x6541),
+        Some(0x6543),
+        Some(0x65aa),
+        Some(0x65bf),
+        Some(0x6636),
+        Some(0x6621),
+        Some(0x6632),
+        Some(0x6635),
+        Some(0x661c),
+        Some(0x6626),
+        Some(0x6622),
+        Some(0x6633),
+        Some(0x662b),
+        Some(0x663a),
+        Some(0x661d),
+        Some(0x6634),
+        Some(0x6639),
+        Some(0x662e),
+        Some(0x670f),
+        Some(0x6710),
+        Some(0x67c1),
+        Some(0x67f2),
+        Some(0x67c8),
+        Some(0x67ba),
+        None,
+    ];
+
+    const BASE_0XCF40: [Option<u16>; 192] = [
+        Some(0x67dc),
+        Some(0x67bb),
+        Some(0x67f8),
+        Some(0x67d8),
+        Some(0x67c0),
+        Some(0x67b7),
+        Some(0x67c5),
+        Some(0x67eb),
+        Some(0x67e4),
+        Some(0x67df),
+        Some(0x67b5),
+        Some(0x67cd),
+        Some(0x67b3),
+        Some(0x67f7),
+        Some(0x67f6),
+        Some(0x67ee),
+        Some(0x67e3),
+        Some(0x67c2),
+        Some(0x67b9),
+        Some(0x67ce),
+        Some(0x67e7),
+        Some(0x67f0),
+        Some(0x67b2),
+        Some(0x67fc),
+        Some(0x67c6),
+        Some(0x67ed),
+        Some(0x67cc),
+        Some(0x67ae),
+        Some(0x67e6),
+        Some(0x67db),
+        Some(0x67fa),
+        Some(0x67c9),
+        Some(0x67ca),
+        Some(0x67c3),
+        Some(0x67ea),
+        Some(0x67cb),
+        Some(0x6b28),
+        Some(0x6b82),
+        Some(0x6b84),
+        Some(0x6bb6),
+        Some(0x6bd6),
+        Some(0x6bd8),
+        Some(0x6be0),
+        Some(0x6c20),
+        Some(0x6c21),
+        Some(0x6d28),
+        Some(0x6d34),
+        Some(0x6d2d),
+        Some(0x6d1f),
+        Some(0x6d3c),
+        Some(0x6d3f),
+        Some(0x6d12),
+        Some(0x6d0a),
+        Some(0x6cda),
+        Some(0x6d33),
+        Some(0x6d04),
+        Some(0x6d19),
+        Some(0x6d3a),
+        Some(0x6d1a),
+        Some(0x6d11),
+        Some(0x6d00),
+        Some(0x6d1d),
+        Some(0x6d42),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6d01),
+        Some(0x6d18),
+        Some(0x6d37),
+        Some(0x6d03),
+        Some(0x6d0f),
+        Some(0x6d40),
+        Some(0x6d07),
+        Some(0x6d20),
+        Some(0x6d2c),
+        Some(0x6d08),
+        Some(0x6d22),
+        Some(0x6d09),
+        Some(0x6d10),
+        Some(0x70b7),
+        Some(0x709f),
+        Some(0x70be),
+        Some(0x70b1),
+        Some(0x70b0),
+        Some(0x70a1),
+        Some(0x70b4),
+        Some(0x70b5),
+        Some(0x70a9),
+        Some(0x7241),
+        Some(0x7249),
+        Some(0x724a),
+        Some(0x726c),
+        Some(0x7270),
+        Some(0x7273),
+        Some(0x726e),
+        Some(0x72ca),
+        Some(0x72e4),
+        Some(0x72e8),
+        Some(0x72eb),
+        Some(0x72df),
+        Some(0x72ea),
+        Some(0x72e6),
+        Some(0x72e3),
+        Some(0x7385),
+        Some(0x73cc),
+        Some(0x73c2),
+        Some(0x73c8),
+        Some(0x73c5),
+        Some(0x73b9),
+        Some(0x73b6),
+        Some(0x73b5),
+        Some(0x73b4),
+        Some(0x73eb),
+        Some(0x73bf),
+        Some(0x73c7),
+        Some(0x73be),
+        Some(0x73c3),
+        Some(0x73c6),
+        Some(0x73b8),
+        Some(0x73cb),
+        Some(0x74ec),
+        Some(0x74ee),
+        Some(0x752e),
+        Some(0x7547),
+        Some(0x7548),
+        Some(0x75a7),
+        Some(0x75aa),
+        Some(0x7679),
+        Some(0x76c4),
+        Some(0x7708),
+        Some(0x7703),
+        Some(0x7704),
+        Some(0x7705),
+        Some(0x770a),
+        Some(0x76f7),
+        Some(0x76fb),
+        Some(0x76fa),
+        Some(0x77e7),
+        Some(0x77e8),
+        Some(0x7806),
+        Some(0x7811),
+        Some(0x7812),
+        Some(0x7805),
+        Some(0x7810),
+        Some(0x780f),
+        Some(0x780e),
+        Some(0x7809),
+        Some(0x7803),
+        Some(0x7813),
+        Some(0x794a),
+        Some(0x794c),
+        Some(0x794b),
+        Some(0x7945),
+        Some(0x7944),
+        Some(0x79d5),
+        Some(0x79cd),
+        Some(0x79cf),
+        Some(0x79d6),
+        Some(0x79ce),
+        Some(0x7a80),
+        None,
+    ];
+
+    const BASE_0XD040: [Option<u16>; 192] = [
+        Some(0x7a7e),
+        Some(0x7ad1),
+        Some(0x7b00),
+        Some(0x7b01),
+        Some(0x7c7a),
+        Some(0x7c78),
+        Some(0x7c79),
+        Some(0x7c7f),
+        Some(0x7c80),
+        Some(0x7c81),
+        Some(0x7d03),
+        Some(0x7d08),
+        Some(0x7d01),
+        Some(0x7f58),
+        Some(0x7f91),
+        Some(0x7f8d),
+        Some(0x7fbe),
+        Some(0x8007),
+        Some(0x800e),
+        Some(0x800f),
+        Some(0x8014),
+        Some(0x8037),
+        Some(0x80d8),
+        Some(0x80c7),
+        Some(0x80e0),
+        Some(0x80d1),
+        Some(0x80c8),
+        Some(0x80c2),
+        Some(0x80d0),
+        Some(0x80c5),
+        Some(0x80e3),
+        Some(0x80d9),
+        Some(0x80dc),
+        Some(0x80ca),
+        Some(0x80d5),
+        Some(0x80c9),
+        Some(0x80cf),
+        Some(0x80d7),
+        Some(0x80e6),
+        Some(0x80cd),
+        Some(0x81ff),
+        Some(0x8221),
+        Some(0x8294),
+        Some(0x82d9),
+        Some(0x82fe),
+        Some(0x82f9),
+        Some(0x8307),
+        Some(0x82e8),
+        Some(0x8300),
+        Some(0x82d5),
+        Some(0x833a),
+        Some(0x82eb),
+        Some(0x82d6),
+        Some(0x82f4),
+        Some(0x82ec),
+        Some(0x82e1),
+        Some(0x82f2),
+        Some(0x82f5),
+        Some(0x830c),
+        Some(0x82fb),
+        Some(0x82f6),
+        Some(0x82f0),
+        Some(0x82ea),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x82e4),
+        Some(0x82e0),
+        Some(0x82fa),
+        Some(0x82f3),
+        Some(0x82ed),
+        Some(0x8677),
+        Some(0x8674),
+        Some(0x867c),
+        Some(0x8673),
+        Some(0x8841),
+        Some(0x884e),
+        Some(0x8867),
+        Some(0x886a),
+        Some(0x8869),
+        Some(0x89d3),
+        Some(0x8a04),
+        Some(0x8a07),
+        Some(0x8d72),
+        Some(0x8fe3),
+        Some(0x8fe1),
+        Some(0x8fee),
+        Some(0x8fe0),
+        Some(0x90f1),
+        Some(0x90bd),
+        Some(0x90bf),
+        Some(0x90d5),
+        Some(0x90c5),
+        Some(0x90be),
+        Some(0x90c7),
+        Some(0x90cb),
+        Some(0x90c8),
+        Some(0x91d4),
+        Some(0x91d3),
+        Some(0x9654),
+        Some(0x964f),
+        Some(0x9651),
+        Some(0x9653),
+        Some(0x964a),
+        Some(0x964e),
+        Some(0x501e),
+        Some(0x5005),
+        Some(0x5007),
+        Some(0x5013),
+        Some(0x5022),
+        Some(0x5030),
+        Some(0x501b),
+        Some(0x4ff5),
+        Some(0x4ff4),
+        Some(0x5033),
+        Some(0x5037),
+        Some(0x502c),
+        Some(0x4ff6),
+        Some(0x4ff7),
+        Some(0x5017),
+        Some(0x501c),
+        Some(0x5020),
+        Some(0x5027),
+        Some(0x5035),
+        Some(0x502f),
+        Some(0x5031),
+        Some(0x500e),
+        Some(0x515a),
+        Some(0x5194),
+        Some(0x5193),
+        Some(0x51ca),
+        Some(0x51c4),
+        Some(0x51c5),
+        Some(0x51c8),
+        Some(0x51ce),
+        Some(0x5261),
+        Some(0x525a),
+        Some(0x5252),
+        Some(0x525e),
+        Some(0x525f),
+        Some(0x5255),
+        Some(0x5262),
+        Some(0x52cd),
+        Some(0x530e),
+        Some(0x539e),
+        Some(0x5526),
+        Some(0x54e2),
+        Some(0x5517),
+        Some(0x5512),
+        Some(0x54e7),
+        Some(0x54f3),
+        Some(0x54e4),
+        Some(0x551a),
+        Some(0x54ff),
+        Some(0x5504),
+        Some(0x5508),
+        Some(0x54eb),
+        Some(0x5511),
+        Some(0x5505),
+        Some(0x54f1),
+        None,
+    ];
+
+    const BASE_0XD140: [Option<u16>; 192] = [
+        Some(0x550a),
+        Some(0x54fb),
+        Some(0x54f7),
+        Some(0x54f8),
+        Some(0x54e0),
+        Some(0x550e),
+        Some(0x5503),
+        Some(0x550b),
+        Some(0x5701),
+        Some(0x5702),
+        Some(0x57cc),
+        Some(0x5832),
+        Some(0x57d5),
+        Some(0x57d2),
+        Some(0x57ba),
+        Some(0x57c6),
+        Some(0x57bd),
+        Some(0x57bc),
+        Some(0x57b8),
+        Some(0x57b6),
+        Some(0x57bf),
+        Some(0x57c7),
+        Some(0x57d0),
+        Some(0x57b9),
+        Some(0x57c1),
+        Some(0x590e),
+        Some(0x594a),
+        Some(0x5a19),
+        Some(0x5a16),
+        Some(0x5a2d),
+        Some(0x5a2e),
+        Some(0x5a15),
+        Some(0x5a0f),
+        Some(0x5a17),
+        Some(0x5a0a),
+        Some(0x5a1e),
+        Some(0x5a33),
+        Some(0x5b6c),
+        Some(0x5ba7),
+        Some(0x5bad),
+        Some(0x5bac),
+        Some(0x5c03),
+        Some(0x5c56),
+        Some(0x5c54),
+        Some(0x5cec),
+        Some(0x5cff),
+        Some(0x5cee),
+        Some(0x5cf1),
+        Some(0x5cf7),
+        Some(0x5d00),
+        Some(0x5cf9),
+        Some(0x5e29),
+        Some(0x5e28),
+        Some(0x5ea8),
+        Some(0x5eae),
+        Some(0x5eaa),
+        Some(0x5eac),
+        Some(0x5f33),
+        Some(0x5f30),
+        Some(0x5f67),
+        Some(0x605d),
+        Some(0x605a),
+        Some(0x6067),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6041),
+        Some(0x60a2),
+        Some(0x6088),
+        Some(0x6080),
+        Some(0x6092),
+        Some(0x6081),
+        Some(0x609d),
+        Some(0x6083),
+        Some(0x6095),
+        Some(0x609b),
+        Some(0x6097),
+        Some(0x6087),
+        Some(0x609c),
+        Some(0x608e),
+        Some(0x6219),
+        Some(0x6246),
+        Some(0x62f2),
+        Some(0x6310),
+        Some(0x6356),
+        Some(0x632c),
+        Some(0x6344),
+        Some(0x6345),
+        Some(0x6336),
+        Some(0x6343),
+        Some(0x63e4),
+        Some(0x6339),
+        Some(0x634b),
+        Some(0x634a),
+        Some(0x633c),
+        Some(0x6329),
+        Some(0x6341),
+        Some(0x6334),
+        Some(0x6358),
+        Some(0x6354),
+        Some(0x6359),
+        Some(0x632d),
+        Some(0x6347),
+        Some(0x6333),
+        Some(0x635a),
+        Some(0x6351),
+        Some(0x6338),
+        Some(0x6357),
+        Some(0x6340),
+        Some(0x6348),
+        Some(0x654a),
+        Some(0x6546),
+        Some(0x65c6),
+        Some(0x65c3),
+        Some(0x65c4),
+        Some(0x65c2),
+        Some(0x664a),
+        Some(0x665f),
+        Some(0x6647),
+        Some(0x6651),
+        Some(0x6712),
+        Some(0x6713),
+        Some(0x681f),
+        Some(0x681a),
+        Some(0x6849),
+        Some(0x6832),
+        Some(0x6833),
+        Some(0x683b),
+        Some(0x684b),
+        Some(0x684f),
+        Some(0x6816),
+        Some(0x6831),
+        Some(0x681c),
+        Some(0x6835),
+        Some(0x682b),
+        Some(0x682d),
+        Some(0x682f),
+        Some(0x684e),
+        Some(0x6844),
+        Some(0x6834),
+        Some(0x681d),
+        Some(0x6812),
+        Some(0x6814),
+        Some(0x6826),
+        Some(0x6828),
+        Some(0x682e),
+        Some(0x684d),
+        Some(0x683a),
+        Some(0x6825),
+        Some(0x6820),
+        Some(0x6b2c),
+        Some(0x6b2f),
+        Some(0x6b2d),
+        Some(0x6b31),
+        Some(0x6b34),
+        Some(0x6b6d),
+        Some(0x8082),
+        Some(0x6b88),
+        Some(0x6be6),
+        Some(0x6be4),
+        None,
+    ];
+
+    const BASE_0XD240: [Option<u16>; 192] = [
+        Some(0x6be8),
+        Some(0x6be3),
+        Some(0x6be2),
+        Some(0x6be7),
+        Some(0x6c25),
+        Some(0x6d7a),
+        Some(0x6d63),
+        Some(0x6d64),
+        Some(0x6d76),
+        Some(0x6d0d),
+        Some(0x6d61),
+        Some(0x6d92),
+        Some(0x6d58),
+        Some(0x6d62),
+        Some(0x6d6d),
+        Some(0x6d6f),
+        Some(0x6d91),
+        Some(0x6d8d),
+        Some(0x6def),
+        Some(0x6d7f),
+        Some(0x6d86),
+        Some(0x6d5e),
+        Some(0x6d67),
+        Some(0x6d60),
+        Some(0x6d97),
+        Some(0x6d70),
+        Some(0x6d7c),
+        Some(0x6d5f),
+        Some(0x6d82),
+        Some(0x6d98),
+        Some(0x6d2f),
+        Some(0x6d68),
+        Some(0x6d8b),
+        Some(0x6d7e),
+        Some(0x6d80),
+        Some(0x6d84),
+        Some(0x6d16),
+        Some(0x6d83),
+        Some(0x6d7b),
+        Some(0x6d7d),
+        Some(0x6d75),
+        Some(0x6d90),
+        Some(0x70dc),
+        Some(0x70d3),
+        Some(0x70d1),
+        Some(0x70dd),
+        Some(0x70cb),
+        Some(0x7f39),
+        Some(0x70e2),
+        Some(0x70d7),
+        Some(0x70d2),
+        Some(0x70de),
+        Some(0x70e0),
+        Some(0x70d4),
+        Some(0x70cd),
+        Some(0x70c5),
+        Some(0x70c6),
+        Some(0x70c7),
+        Some(0x70da),
+        Some(0x70ce),
+        Some(0x70e1),
+        Some(0x7242),
+        Some(0x7278),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7277),
+        Some(0x7276),
+        Some(0x7300),
+        Some(0x72fa),
+        Some(0x72f4),
+        Some(0x72fe),
+        Some(0x72f6),
+        Some(0x72f3),
+        Some(0x72fb),
+        Some(0x7301),
+        Some(0x73d3),
+        Some(0x73d9),
+        Some(0x73e5),
+        Some(0x73d6),
+        Some(0x73bc),
+        Some(0x73e7),
+        Some(0x73e3),
+        Some(0x73e9),
+        Some(0x73dc),
+        Some(0x73d2),
+        Some(0x73db),
+        Some(0x73d4),
+        Some(0x73dd),
+        Some(0x73da),
+        Some(0x73d7),
+        Some(0x73d8),
+        Some(0x73e8),
+        Some(0x74de),
+        Some(0x74df),
+        Some(0x74f4),
+        Some(0x74f5),
+        Some(0x7521),
+        Some(0x755b),
+        Some(0x755f),
+        Some(0x75b0),
+        Some(0x75c1),
+        Some(0x75bb),
+        Some(0x75c4),
+        Some(0x75c0),
+        Some(0x75bf),
+        Some(0x75b6),
+        Some(0x75ba),
+        Some(0x768a),
+        Some(0x76c9),
+        Some(0x771d),
+        Some(0x771b),
+        Some(0x7710),
+        Some(0x7713),
+        Some(0x7712),
+        Some(0x7723),
+        Some(0x7711),
+        Some(0x7715),
+        Some(0x7719),
+        Some(0x771a),
+        Some(0x7722),
+        Some(0x7727),
+        Some(0x7823),
+        Some(0x782c),
+        Some(0x7822),
+        Some(0x7835),
+        Some(0x782f),
+        Some(0x7828),
+        Some(0x782e),
+        Some(0x782b),
+        Some(0x7821),
+        Some(0x7829),
+        Some(0x7833),
+        Some(0x782a),
+        Some(0x7831),
+        Some(0x7954),
+        Some(0x795b),
+        Some(0x794f),
+        Some(0x795c),
+        Some(0x7953),
+        Some(0x7952),
+        Some(0x7951),
+        Some(0x79eb),
+        Some(0x79ec),
+        Some(0x79e0),
+        Some(0x79ee),
+        Some(0x79ed),
+        Some(0x79ea),
+        Some(0x79dc),
+        Some(0x79de),
+        Some(0x79dd),
+        Some(0x7a86),
+        Some(0x7a89),
+        Some(0x7a85),
+        Some(0x7a8b),
+        Some(0x7a8c),
+        Some(0x7a8a),
+        Some(0x7a87),
+        Some(0x7ad8),
+        Some(0x7b10),
+        None,
+    ];
+
+    const BASE_0XD340: [Option<u16>; 192] = [
+        Some(0x7b04),
+        Some(0x7b13),
+        Some(0x7b05),
+        Some(0x7b0f),
+        Some(0x7b08),
+        Some(0x7b0a),
+        Some(0x7b0e),
+        Some(0x7b09),
+        Some(0x7b12),
+        Some(0x7c84),
+        Some(0x7c91),
+        Some(0x7c8a),
+        Some(0x7c8c),
+        Some(0x7c88),
+        Some(0x7c8d),
+        Some(0x7c85),
+        Some(0x7d1e),
+        Some(0x7d1d),
+        Some(0x7d11),
+        Some(0x7d0e),
+        Some(0x7d18),
+        Some(0x7d16),
+        Some(0x7d13),
+        Some(0x7d1f),
+        Some(0x7d12),
+        Some(0x7d0f),
+        Some(0x7d0c),
+        Some(0x7f5c),
+        Some(0x7f61),
+        Some(0x7f5e),
+        Some(0x7f60),
+        Some(0x7f5d),
+        Some(0x7f5b),
+        Some(0x7f96),
+        Some(0x7f92),
+        Some(0x7fc3),
+        Some(0x7fc2),
+        Some(0x7fc0),
+        Some(0x8016),
+        Some(0x803e),
+        Some(0x8039),
+        Some(0x80fa),
+        Some(0x80f2),
+        Some(0x80f9),
+        Some(0x80f5),
+        Some(0x8101),
+        Some(0x80fb),
+        Some(0x8100),
+        Some(0x8201),
+        Some(0x822f),
+        Some(0x8225),
+        Some(0x8333),
+        Some(0x832d),
+        Some(0x8344),
+        Some(0x8319),
+        Some(0x8351),
+        Some(0x8325),
+        Some(0x8356),
+        Some(0x833f),
+        Some(0x8341),
+        Some(0x8326),
+        Some(0x831c),
+        Some(0x8322),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8342),
+        Some(0x834e),
+        Some(0x831b),
+        Some(0x832a),
+        Some(0x8308),
+        Some(0x833c),
+        Some(0x834d),
+        Some(0x8316),
+        Some(0x8324),
+        Some(0x8320),
+        Some(0x8337),
+        Some(0x832f),
+        Some(0x8329),
+        Some(0x8347),
+        Some(0x8345),
+        Some(0x834c),
+        Some(0x8353),
+        Some(0x831e),
+        Some(0x832c),
+        Some(0x834b),
+        Some(0x8327),
+        Some(0x8348),
+        Some(0x8653),
+        Some(0x8652),
+        Some(0x86a2),
+        Some(0x86a8),
+        Some(0x8696),
+        Some(0x868d),
+        Some(0x8691),
+        Some(0x869e),
+        Some(0x8687),
+        Some(0x8697),
+        Some(0x8686),
+        Some(0x868b),
+        Some(0x869a),
+        Some(0x8685),
+        Some(0x86a5),
+        Some(0x8699),
+        Some(0x86a1),
+        Some(0x86a7),
+        Some(0x8695),
+        Some(0x8698),
+        Some(0x868e),
+        Some(0x869d),
+        Some(0x8690),
+        Some(0x8694),
+        Some(0x8843),
+        Some(0x8844),
+        Some(0x886d),
+        Some(0x8875),
+        Some(0x8876),
+        Some(0x8872),
+        Some(0x8880),
+        Some(0x8871),
+        Some(0x887f),
+        Some(0x886f),
+        Some(0x8883),
+        Some(0x887e),
+        Some(0x8874),
+        Some(0x887c),
+        Some(0x8a12),
+        Some(0x8c47),
+        Some(0x8c57),
+        Some(0x8c7b),
+        Some(0x8ca4),
+        Some(0x8ca3),
+        Some(0x8d76),
+        Some(0x8d78),
+        Some(0x8db5),
+        Some(0x8db7),
+        Some(0x8db6),
+        Some(0x8ed1),
+        Some(0x8ed3),
+        Some(0x8ffe),
+        Some(0x8ff5),
+        Some(0x9002),
+        Some(0x8fff),
+        Some(0x8ffb),
+        Some(0x9004),
+        Some(0x8ffc),
+        Some(0x8ff6),
+        Some(0x90d6),
+        Some(0x90e0),
+        Some(0x90d9),
+        Some(0x90da),
+        Some(0x90e3),
+        Some(0x90df),
+        Some(0x90e5),
+        Some(0x90d8),
+        Some(0x90db),
+        Some(0x90d7),
+        Some(0x90dc),
+        Some(0x90e4),
+        Some(0x9150),
+        None,
+    ];
+
+    const BASE_0XD440: [Option<u16>; 192] = [
+        Some(0x914e),
+        Some(0x914f),
+        Some(0x91d5),
+        Some(0x91e2),
+        Some(0x91da),
+        Some(0x965c),
+        Some(0x965f),
+        Some(0x96bc),
+        Some(0x98e3),
+        Some(0x9adf),
+        Some(0x9b2f),
+        Some(0x4e7f),
+        Some(0x5070),
+        Some(0x506a),
+        Some(0x5061),
+        Some(0x505e),
+        Some(0x5060),
+        Some(0x5053),
+        Some(0x504b),
+        Some(0x505d),
+        Some(0x5072),
+        Some(0x5048),
+        Some(0x504d),
+        Some(0x5041),
+        Some(0x505b),
+        Some(0x504a),
+        Some(0x5062),
+        Some(0x5015),
+        Some(0x5045),
+        Some(0x505f),
+        Some(0x5069),
+        Some(0x506b),
+        Some(0x5063),
+        Some(0x5064),
+        Some(0x5046),
+        Some(0x5040),
+        Some(0x506e),
+        Some(0x5073),
+        Some(0x5057),
+        Some(0x5051),
+        Some(0x51d0),
+        Some(0x526b),
+        Some(0x526d),
+        Some(0x526c),
+        Some(0x526e),
+        Some(0x52d6),
+        Some(0x52d3),
+        Some(0x532d),
+        Some(0x539c),
+        Some(0x5575),
+        Some(0x5576),
+        Some(0x553c),
+        Some(0x554d),
+        Some(0x5550),
+        Some(0x5534),
+        Some(0x552a),
+        Some(0x5551),
+        Some(0x5562),
+        Some(0x5536),
+        Some(0x5535),
+        Some(0x5530),
+        Some(0x5552),
+        Some(0x5545),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x550c),
+        Some(0x5532),
+        Some(0x5565),
+        Some(0x554e),
+        Some(0x5539),
+        Some(0x5548),
+        Some(0x552d),
+        Some(0x553b),
+        Some(0x5540),
+        Some(0x554b),
+        Some(0x570a),
+        Some(0x5707),
+        Some(0x57fb),
+        Some(0x5814),
+        Some(0x57e2),
+        Some(0x57f6),
+        Some(0x57dc),
+        Some(0x57f4),
+        Some(0x5800),
+        Some(0x57ed),
+        Some(0x57fd),
+        Some(0x5808),
+        Some(0x57f8),
+        Some(0x580b),
+        Some(0x57f3),
+        Some(0x57cf),
+        Some(0x5807),
+        Some(0x57ee),
+        Some(0x57e3),
+        Some(0x57f2),
+        Some(0x57e5),
+        Some(0x57ec),
+        Some(0x57e1),
+        Some(0x580e),
+        Some(0x57fc),
+        Some(0x5810),
+        Some(0x57e7),
+        Some(0x5801),
+        Some(0x580c),
+        Some(0x57f1),
+        Some(0x57e9),
+        Some(0x57f0),
+        Some(0x580d),
+        Some(0x5804),
+        Some(0x595c),
+        Some(0x5a60),
+        Some(0x5a58),
+        Some(0x5a55),
+        Some(0x5a67),
+        Some(0x5a5e),
+        Some(0x5a38),
+        Some(0x5a35),
+        Some(0x5a6d),
+        Some(0x5a50),
+        Some(0x5a5f),
+        Some(0x5a65),
+        Some(0x5a6c),
+        Some(0x5a53),
+        Some(0x5a64),
+        Some(0x5a57),
+        Some(0x5a43),
+        Some(0x5a5d),
+        Some(0x5a52),
+        Some(0x5a44),
+        Some(0x5a5b),
+        Some(0x5a48),
+        Some(0x5a8e),
+        Some(0x5a3e),
+        Some(0x5a4d),
+        Some(0x5a39),
+        Some(0x5a4c),
+        Some(0x5a70),
+        Some(0x5a69),
+        Some(0x5a47),
+        Some(0x5a51),
+        Some(0x5a56),
+        Some(0x5a42),
+        Some(0x5a5c),
+        Some(0x5b72),
+        Some(0x5b6e),
+        Some(0x5bc1),
+        Some(0x5bc0),
+        Some(0x5c59),
+        Some(0x5d1e),
+        Some(0x5d0b),
+        Some(0x5d1d),
+        Some(0x5d1a),
+        Some(0x5d20),
+        Some(0x5d0c),
+        Some(0x5d28),
+        Some(0x5d0d),
+        Some(0x5d26),
+        Some(0x5d25),
+        Some(0x5d0f),
+        None,
+    ];
+
+    const BASE_0XD540: [Option<u16>; 192] = [
+        Some(0x5d30),
+        Some(0x5d12),
+        Some(0x5d23),
+        Some(0x5d1f),
+        Some(0x5d2e),
+        Some(0x5e3e),
+        Some(0x5e34),
+        Some(0x5eb1),
+        Some(0x5eb4),
+        Some(0x5eb9),
+        Some(0x5eb2),
+        Some(0x5eb3),
+        Some(0x5f36),
+        Some(0x5f38),
+        Some(0x5f9b),
+        Some(0x5f96),
+        Some(0x5f9f),
+        Some(0x608a),
+        Some(0x6090),
+        Some(0x6086),
+        Some(0x60be),
+        Some(0x60b0),
+        Some(0x60ba),
+        Some(0x60d3),
+        Some(0x60d4),
+        Some(0x60cf),
+        Some(0x60e4),
+        Some(0x60d9),
+        Some(0x60dd),
+        Some(0x60c8),
+        Some(0x60b1),
+        Some(0x60db),
+        Some(0x60b7),
+        Some(0x60ca),
+        Some(0x60bf),
+        Some(0x60c3),
+        Some(0x60cd),
+        Some(0x60c0),
+        Some(0x6332),
+        Some(0x6365),
+        Some(0x638a),
+        Some(0x6382),
+        Some(0x637d),
+        Some(0x63bd),
+        Some(0x639e),
+        Some(0x63ad),
+        Some(0x639d),
+        Some(0x6397),
+        Some(0x63ab),
+        Some(0x638e),
+        Some(0x636f),
+        Some(0x6387),
+        Some(0x6390),
+        Some(0x636e),
+        Some(0x63af),
+        Some(0x6375),
+        Some(0x639c),
+        Some(0x636d),
+        Some(0x63ae),
+        Some(0x637c),
+        Some(0x63a4),
+        Some(0x633b),
+        Some(0x639f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6378),
+        Some(0x6385),
+        Some(0x6381),
+        Some(0x6391),
+        Some(0x638d),
+        Some(0x6370),
+        Some(0x6553),
+        Some(0x65cd),
+        Some(0x6665),
+        Some(0x6661),
+        Some(0x665b),
+        Some(0x6659),
+        Some(0x665c),
+        Some(0x6662),
+        Some(0x6718),
+        Some(0x6879),
+        Some(0x6887),
+        Some(0x6890),
+        Some(0x689c),
+        Some(0x686d),
+        Some(0x686e),
+        Some(0x68ae),
+        Some(0x68ab),
+        Some(0x6956),
+        Some(0x686f),
+        Some(0x68a3),
+        Some(0x68ac),
+        Some(0x68a9),
+        Some(0x6875),
+        Some(0x6874),
+        Some(0x68b2),
+        Some(0x688f),
+        Some(0x6877),
+        Some(0x6892),
+        Some(0x687c),
+        Some(0x686b),
+        Some(0x6872),
+        Some(0x68aa),
+        Some(0x6880),
+        Some(0x6871),
+        Some(0x687e),
+        Some(0x689b),
+        Some(0x6896),
+        Some(0x688b),
+        Some(0x68a0),
+        Some(0x6889),
+        Some(0x68a4),
+        Some(0x6878),
+        Some(0x687b),
+        Some(0x6891),
+        Some(0x688c),
+        Some(0x688a),
+        Some(0x687d),
+        Some(0x6b36),
+        Some(0x6b33),
+        Some(0x6b37),
+        Some(0x6b38),
+        Some(0x6b91),
+        Some(0x6b8f),
+        Some(0x6b8d),
+        Some(0x6b8e),
+        Some(0x6b8c),
+        Some(0x6c2a),
+        Some(0x6dc0),
+        Some(0x6dab),
+        Some(0x6db4),
+        Some(0x6db3),
+        Some(0x6e74),
+        Some(0x6dac),
+        Some(0x6de9),
+        Some(0x6de2),
+        Some(0x6db7),
+        Some(0x6df6),
+        Some(0x6dd4),
+        Some(0x6e00),
+        Some(0x6dc8),
+        Some(0x6de0),
+        Some(0x6ddf),
+        Some(0x6dd6),
+        Some(0x6dbe),
+        Some(0x6de5),
+        Some(0x6ddc),
+        Some(0x6ddd),
+        Some(0x6ddb),
+        Some(0x6df4),
+        Some(0x6dca),
+        Some(0x6dbd),
+        Some(0x6ded),
+        Some(0x6df0),
+        Some(0x6dba),
+        Some(0x6dd5),
+        Some(0x6dc2),
+        Some(0x6dcf),
+        Some(0x6dc9),
+        None,
+    ];
+
+    const BASE_0XD640: [Option<u16>; 192] = [
+        Some(0x6dd0),
+        Some(0x6df2),
+        Some(0x6dd3),
+        Some(0x6dfd),
+        Some(0x6dd7),
+        Some(0x6dcd),
+        Some(0x6de3),
+        Some(0x6dbb),
+        Some(0x70fa),
+        Some(0x710d),
+        Some(0x70f7),
+        Some(0x7117),
+        Some(0x70f4),
+        Some(0x710c),
+        Some(0x70f0),
+        Some(0x7104),
+        Some(0x70f3),
+        Some(0x7110),
+        Some(0x70fc),
+        Some(0x70ff),
+        Some(0x7106),
+        Some(0x7113),
+        Some(0x7100),
+        Some(0x70f8),
+        Some(0x70f6),
+        Some(0x710b),
+        Some(0x7102),
+        Some(0x710e),
+        Some(0x727e),
+        Some(0x727b),
+        Some(0x727c),
+        Some(0x727f),
+        Some(0x731d),
+        Some(0x7317),
+        Some(0x7307),
+        Some(0x7311),
+        Some(0x7318),
+        Some(0x730a),
+        Some(0x7308),
+        Some(0x72ff),
+        Some(0x730f),
+        Some(0x731e),
+        Some(0x7388),
+        Some(0x73f6),
+        Some(0x73f8),
+        Some(0x73f5),
+        Some(0x7404),
+        Some(0x7401),
+        Some(0x73fd),
+        Some(0x7407),
+        Some(0x7400),
+        Some(0x73fa),
+        Some(0x73fc),
+        Some(0x73ff),
+        Some(0x740c),
+        Some(0x740b),
+        Some(0x73f4),
+        Some(0x7408),
+        Some(0x7564),
+        Some(0x7563),
+        Some(0x75ce),
+        Some(0x75d2),
+        Some(0x75cf),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x75cb),
+        Some(0x75cc),
+        Some(0x75d1),
+        Some(0x75d0),
+        Some(0x768f),
+        Some(0x7689),
+        Some(0x76d3),
+        Some(0x7739),
+        Some(0x772f),
+        Some(0x772d),
+        Some(0x7731),
+        Some(0x7732),
+        Some(0x7734),
+        Some(0x7733),
+        Some(0x773d),
+        Some(0x7725),
+        Some(0x773b),
+        Some(0x7735),
+        Some(0x7848),
+        Some(0x7852),
+        Some(0x7849),
+        Some(0x784d),
+        Some(0x784a),
+        Some(0x784c),
+        Some(0x7826),
+        Some(0x7845),
+        Some(0x7850),
+        Some(0x7964),
+        Some(0x7967),
+        Some(0x7969),
+        Some(0x796a),
+        Some(0x7963),
+        Some(0x796b),
+        Some(0x7961),
+        Some(0x79bb),
+        Some(0x79fa),
+        Some(0x79f8),
+        Some(0x79f6),
+        Some(0x79f7),
+        Some(0x7a8f),
+        Some(0x7a94),
+        Some(0x7a90),
+        Some(0x7b35),
+        Some(0x7b47),
+        Some(0x7b34),
+        Some(0x7b25),
+        Some(0x7b30),
+        Some(0x7b22),
+        Some(0x7b24),
+        Some(0x7b33),
+        Some(0x7b18),
+        Some(0x7b2a),
+        Some(0x7b1d),
+        Some(0x7b31),
+        Some(0x7b2b),
+        Some(0x7b2d),
+        Some(0x7b2f),
+        Some(0x7b32),
+        Some(0x7b38),
+        Some(0x7b1a),
+        Some(0x7b23),
+        Some(0x7c94),
+        Some(0x7c98),
+        Some(0x7c96),
+        Some(0x7ca3),
+        Some(0x7d35),
+        Some(0x7d3d),
+        Some(0x7d38),
+        Some(0x7d36),
+        Some(0x7d3a),
+        Some(0x7d45),
+        Some(0x7d2c),
+        Some(0x7d29),
+        Some(0x7d41),
+        Some(0x7d47),
+        Some(0x7d3e),
+        Some(0x7d3f),
+        Some(0x7d4a),
+        Some(0x7d3b),
+        Some(0x7d28),
+        Some(0x7f63),
+        Some(0x7f95),
+        Some(0x7f9c),
+        Some(0x7f9d),
+        Some(0x7f9b),
+        Some(0x7fca),
+        Some(0x7fcb),
+        Some(0x7fcd),
+        Some(0x7fd0),
+        Some(0x7fd1),
+        Some(0x7fc7),
+        Some(0x7fcf),
+        Some(0x7fc9),
+        Some(0x801f),
+        None,
+    ];
+
+    const BASE_0XD740: [Option<u16>; 192] = [
+        Some(0x801e),
+        Some(0x801b),
+        Some(0x8047),
+        Some(0x8043),
+        Some(0x8048),
+        Some(0x8118),
+        Some(0x8125),
+        Some(0x8119),
+        Some(0x811b),
+        Some(0x812d),
+        Some(0x811f),
+        Some(0x812c),
+        Some(0x811e),
+        Some(0x8121),
+        Some(0x8115),
+        Some(0x8127),
+        Some(0x811d),
+        Some(0x8122),
+        Some(0x8211),
+        Some(0x8238),
+        Some(0x8233),
+        Some(0x823a),
+        Some(0x8234),
+        Some(0x8232),
+        Some(0x8274),
+        Some(0x8390),
+        Some(0x83a3),
+        Some(0x83a8),
+        Some(0x838d),
+        Some(0x837a),
+        Some(0x8373),
+        Some(0x83a4),
+        Some(0x8374),
+        Some(0x838f),
+        Some(0x8381),
+        Some(0x8395),
+        Some(0x8399),
+        Some(0x8375),
+        Some(0x8394),
+        Some(0x83a9),
+        Some(0x837d),
+        Some(0x8383),
+        Some(0x838c),
+        Some(0x839d),
+        Some(0x839b),
+        Some(0x83aa),
+        Some(0x838b),
+        Some(0x837e),
+        Some(0x83a5),
+        Some(0x83af),
+        Some(0x8388),
+        Some(0x8397),
+        Some(0x83b0),
+        Some(0x837f),
+        Some(0x83a6),
+        Some(0x8387),
+        Some(0x83ae),
+        Some(0x8376),
+        Some(0x839a),
+        Some(0x8659),
+        Some(0x8656),
+        Some(0x86bf),
+        Some(0x86b7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x86c2),
+        Some(0x86c1),
+        Some(0x86c5),
+        Some(0x86ba),
+        Some(0x86b0),
+        Some(0x86c8),
+        Some(0x86b9),
+        Some(0x86b3),
+        Some(0x86b8),
+        Some(0x86cc),
+        Some(0x86b4),
+        Some(0x86bb),
+        Some(0x86bc),
+        Some(0x86c3),
+        Some(0x86bd),
+        Some(0x86be),
+        Some(0x8852),
+        Some(0x8889),
+        Some(0x8895),
+        Some(0x88a8),
+        Some(0x88a2),
+        Some(0x88aa),
+        Some(0x889a),
+        Some(0x8891),
+        Some(0x88a1),
+        Some(0x889f),
+        Some(0x8898),
+        Some(0x88a7),
+        Some(0x8899),
+        Some(0x889b),
+        Some(0x8897),
+        Some(0x88a4),
+        Some(0x88ac),
+        Some(0x888c),
+        Some(0x8893),
+        Some(0x888e),
+        Some(0x8982),
+        Some(0x89d6),
+        Some(0x89d9),
+        Some(0x89d5),
+        Some(0x8a30),
+        Some(0x8a27),
+        Some(0x8a2c),
+        Some(0x8a1e),
+        Some(0x8c39),
+        Some(0x8c3b),
+        Some(0x8c5c),
+        Some(0x8c5d),
+        Some(0x8c7d),
+        Some(0x8ca5),
+        Some(0x8d7d),
+        Some(0x8d7b),
+        Some(0x8d79),
+        Some(0x8dbc),
+        Some(0x8dc2),
+        Some(0x8db9),
+        Some(0x8dbf),
+        Some(0x8dc1),
+        Some(0x8ed8),
+        Some(0x8ede),
+        Some(0x8edd),
+        Some(0x8edc),
+        Some(0x8ed7),
+        Some(0x8ee0),
+        Some(0x8ee1),
+        Some(0x9024),
+        Some(0x900b),
+        Some(0x9011),
+        Some(0x901c),
+        Some(0x900c),
+        Some(0x9021),
+        Some(0x90ef),
+        Some(0x90ea),
+        Some(0x90f0),
+        Some(0x90f4),
+        Some(0x90f2),
+        Some(0x90f3),
+        Some(0x90d4),
+        Some(0x90eb),
+        Some(0x90ec),
+        Some(0x90e9),
+        Some(0x9156),
+        Some(0x9158),
+        Some(0x915a),
+        Some(0x9153),
+        Some(0x9155),
+        Some(0x91ec),
+        Some(0x91f4),
+        Some(0x91f1),
+        Some(0x91f3),
+        Some(0x91f8),
+        Some(0x91e4),
+        Some(0x91f9),
+        Some(0x91ea),
+        None,
+    ];
+
+    const BASE_0XD840: [Option<u16>; 192] = [
+        Some(0x91eb),
+        Some(0x91f7),
+        Some(0x91e8),
+        Some(0x91ee),
+        Some(0x957a),
+        Some(0x9586),
+        Some(0x9588),
+        Some(0x967c),
+        Some(0x966d),
+        Some(0x966b),
+        Some(0x9671),
+        Some(0x966f),
+        Some(0x96bf),
+        Some(0x976a),
+        Some(0x9804),
+        Some(0x98e5),
+        Some(0x9997),
+        Some(0x509b),
+        Some(0x5095),
+        Some(0x5094),
+        Some(0x509e),
+        Some(0x508b),
+        Some(0x50a3),
+        Some(0x5083),
+        Some(0x508c),
+        Some(0x508e),
+        Some(0x509d),
+        Some(0x5068),
+        Some(0x509c),
+        Some(0x5092),
+        Some(0x5082),
+        Some(0x5087),
+        Some(0x515f),
+        Some(0x51d4),
+        Some(0x5312),
+        Some(0x5311),
+        Some(0x53a4),
+        Some(0x53a7),
+        Some(0x5591),
+        Some(0x55a8),
+        Some(0x55a5),
+        Some(0x55ad),
+        Some(0x5577),
+        Some(0x5645),
+        Some(0x55a2),
+        Some(0x5593),
+        Some(0x5588),
+        Some(0x558f),
+        Some(0x55b5),
+        Some(0x5581),
+        Some(0x55a3),
+        Some(0x5592),
+        Some(0x55a4),
+        Some(0x557d),
+        Some(0x558c),
+        Some(0x55a6),
+        Some(0x557f),
+        Some(0x5595),
+        Some(0x55a1),
+        Some(0x558e),
+        Some(0x570c),
+        Some(0x5829),
+        Some(0x5837),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5819),
+        Some(0x581e),
+        Some(0x5827),
+        Some(0x5823),
+        Some(0x5828),
+        Some(0x57f5),
+        Some(0x5848),
+        Some(0x5825),
+        Some(0x581c),
+        Some(0x581b),
+        Some(0x5833),
+        Some(0x583f),
+        Some(0x5836),
+        Some(0x582e),
+        Some(0x5839),
+        Some(0x5838),
+        Some(0x582d),
+        Some(0x582c),
+        Some(0x583b),
+        Some(0x5961),
+        Some(0x5aaf),
+        Some(0x5a94),
+        Some(0x5a9f),
+        Some(0x5a7a),
+        Some(0x5aa2),
+        Some(0x5a9e),
+        Some(0x5a78),
+        Some(0x5aa6),
+        Some(0x5a7c),
+        Some(0x5aa5),
+        Some(0x5aac),
+        Some(0x5a95),
+        Some(0x5aae),
+        Some(0x5a37),
+        Some(0x5a84),
+        Some(0x5a8a),
+        Some(0x5a97),
+        Some(0x5a83),
+        Some(0x5a8b),
+        Some(0x5aa9),
+        Some(0x5a7b),
+        Some(0x5a7d),
+        Some(0x5a8c),
+        Some(0x5a9c),
+        Some(0x5a8f),
+        Some(0x5a93),
+        Some(0x5a9d),
+        Some(0x5bea),
+        Some(0x5bcd),
+        Some(0x5bcb),
+        Some(0x5bd4),
+        Some(0x5bd1),
+        Some(0x5bca),
+        Some(0x5bce),
+        Some(0x5c0c),
+        Some(0x5c30),
+        Some(0x5d37),
+        Some(0x5d43),
+        Some(0x5d6b),
+        Some(0x5d41),
+        Some(0x5d4b),
+        Some(0x5d3f),
+        Some(0x5d35),
+        Some(0x5d51),
+        Some(0x5d4e),
+        Some(0x5d55),
+        Some(0x5d33),
+        Some(0x5d3a),
+        Some(0x5d52),
+        Some(0x5d3d),
+        Some(0x5d31),
+        Some(0x5d59),
+        Some(0x5d42),
+        Some(0x5d39),
+        Some(0x5d49),
+        Some(0x5d38),
+        Some(0x5d3c),
+        Some(0x5d32),
+        Some(0x5d36),
+        Some(0x5d40),
+        Some(0x5d45),
+        Some(0x5e44),
+        Some(0x5e41),
+        Some(0x5f58),
+        Some(0x5fa6),
+        Some(0x5fa5),
+        Some(0x5fab),
+        Some(0x60c9),
+        Some(0x60b9),
+        Some(0x60cc),
+        Some(0x60e2),
+        Some(0x60ce),
+        Some(0x60c4),
+        Some(0x6114),
+        None,
+    ];
+
+    const BASE_0XD940: [Option<u16>; 192] = [
+        Some(0x60f2),
+        Some(0x610a),
+        Some(0x6116),
+        Some(0x6105),
+        Some(0x60f5),
+        Some(0x6113),
+        Some(0x60f8),
+        Some(0x60fc),
+        Some(0x60fe),
+        Some(0x60c1),
+        Some(0x6103),
+        Some(0x6118),
+        Some(0x611d),
+        Some(0x6110),
+        Some(0x60ff),
+        Some(0x6104),
+        Some(0x610b),
+        Some(0x624a),
+        Some(0x6394),
+        Some(0x63b1),
+        Some(0x63b0),
+        Some(0x63ce),
+        Some(0x63e5),
+        Some(0x63e8),
+        Some(0x63ef),
+        Some(0x63c3),
+        Some(0x649d),
+        Some(0x63f3),
+        Some(0x63ca),
+        Some(0x63e0),
+        Some(0x63f6),
+        Some(0x63d5),
+        Some(0x63f2),
+        Some(0x63f5),
+        Some(0x6461),
+        Some(0x63df),
+        Some(0x63be),
+        Some(0x63dd),
+        Some(0x63dc),
+        Some(0x63c4),
+        Some(0x63d8),
+        Some(0x63d3),
+        Some(0x63c2),
+        Some(0x63c7),
+        Some(0x63cc),
+        Some(0x63cb),
+        Some(0x63c8),
+        Some(0x63f0),
+        Some(0x63d7),
+        Some(0x63d9),
+        Some(0x6532),
+        Some(0x6567),
+        Some(0x656a),
+        Some(0x6564),
+        Some(0x655c),
+        Some(0x6568),
+        Some(0x6565),
+        Some(0x658c),
+        Some(0x659d),
+        Some(0x659e),
+        Some(0x65ae),
+        Some(0x65d0),
+        Some(0x65d2),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x667c),
+        Some(0x666c),
+        Some(0x667b),
+        Some(0x6680),
+        Some(0x6671),
+        Some(0x6679),
+        Some(0x666a),
+        Some(0x6672),
+        Some(0x6701),
+        Some(0x690c),
+        Some(0x68d3),
+        Some(0x6904),
+        Some(0x68dc),
+        Some(0x692a),
+        Some(0x68ec),
+        Some(0x68ea),
+        Some(0x68f1),
+        Some(0x690f),
+        Some(0x68d6),
+        Some(0x68f7),
+        Some(0x68eb),
+        Some(0x68e4),
+        Some(0x68f6),
+        Some(0x6913),
+        Some(0x6910),
+        Some(0x68f3),
+        Some(0x68e1),
+        Some(0x6907),
+        Some(0x68cc),
+        Some(0x6908),
+        Some(0x6970),
+        Some(0x68b4),
+        Some(0x6911),
+        Some(0x68ef),
+        Some(0x68c6),
+        Some(0x6914),
+        Some(0x68f8),
+        Some(0x68d0),
+        Some(0x68fd),
+        Some(0x68fc),
+        Some(0x68e8),
+        Some(0x690b),
+        Some(0x690a),
+        Some(0x6917),
+        Some(0x68ce),
+        Some(0x68c8),
+        Some(0x68dd),
+        Some(0x68de),
+        Some(0x68e6),
+        Some(0x68f4),
+        Some(0x68d1),
+        Some(0x6906),
+        Some(0x68d4),
+        Some(0x68e9),
+        Some(0x6915),
+        Some(0x6925),
+        Some(0x68c7),
+        Some(0x6b39),
+        Some(0x6b3b),
+        Some(0x6b3f),
+        Some(0x6b3c),
+        Some(0x6b94),
+        Some(0x6b97),
+        Some(0x6b99),
+        Some(0x6b95),
+        Some(0x6bbd),
+        Some(0x6bf0),
+        Some(0x6bf2),
+        Some(0x6bf3),
+        Some(0x6c30),
+        Some(0x6dfc),
+        Some(0x6e46),
+        Some(0x6e47),
+        Some(0x6e1f),
+        Some(0x6e49),
+        Some(0x6e88),
+        Some(0x6e3c),
+        Some(0x6e3d),
+        Some(0x6e45),
+        Some(0x6e62),
+        Some(0x6e2b),
+        Some(0x6e3f),
+        Some(0x6e41),
+        Some(0x6e5d),
+        Some(0x6e73),
+        Some(0x6e1c),
+        Some(0x6e33),
+        Some(0x6e4b),
+        Some(0x6e40),
+        Some(0x6e51),
+        Some(0x6e3b),
+        Some(0x6e03),
+        Some(0x6e2e),
+        Some(0x6e5e),
+        None,
+    ];
+
+    const BASE_0XDA40: [Option<u16>; 192] = [
+        Some(0x6e68),
+        Some(0x6e5c),
+        Some(0x6e61),
+        Some(0x6e31),
+        Some(0x6e28),
+        Some(0x6e60),
+        Some(0x6e71),
+        Some(0x6e6b),
+        Some(0x6e39),
+        Some(0x6e22),
+        Some(0x6e30),
+        Some(0x6e53),
+        Some(0x6e65),
+        Some(0x6e27),
+        Some(0x6e78),
+        Some(0x6e64),
+        Some(0x6e77),
+        Some(0x6e55),
+        Some(0x6e79),
+        Some(0x6e52),
+        Some(0x6e66),
+        Some(0x6e35),
+        Some(0x6e36),
+        Some(0x6e5a),
+        Some(0x7120),
+        Some(0x711e),
+        Some(0x712f),
+        Some(0x70fb),
+        Some(0x712e),
+        Some(0x7131),
+        Some(0x7123),
+        Some(0x7125),
+        Some(0x7122),
+        Some(0x7132),
+        Some(0x711f),
+        Some(0x7128),
+        Some(0x713a),
+        Some(0x711b),
+        Some(0x724b),
+        Some(0x725a),
+        Some(0x7288),
+        Some(0x7289),
+        Some(0x7286),
+        Some(0x7285),
+        Some(0x728b),
+        Some(0x7312),
+        Some(0x730b),
+        Some(0x7330),
+        Some(0x7322),
+        Some(0x7331),
+        Some(0x7333),
+        Some(0x7327),
+        Some(0x7332),
+        Some(0x732d),
+        Some(0x7326),
+        Some(0x7323),
+        Some(0x7335),
+        Some(0x730c),
+        Some(0x742e),
+        Some(0x742c),
+        Some(0x7430),
+        Some(0x742b),
+        Some(0x7416),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x741a),
+        Some(0x7421),
+        Some(0x742d),
+        Some(0x7431),
+        Some(0x7424),
+        Some(0x7423),
+        Some(0x741d),
+        Some(0x7429),
+        Some(0x7420),
+        Some(0x7432),
+        Some(0x74fb),
+        Some(0x752f),
+        Some(0x756f),
+        Some(0x756c),
+        Some(0x75e7),
+        Some(0x75da),
+        Some(0x75e1),
+        Some(0x75e6),
+        Some(0x75dd),
+        Some(0x75df),
+        Some(0x75e4),
+        Some(0x75d7),
+        Some(0x7695),
+        Some(0x7692),
+        Some(0x76da),
+        Some(0x7746),
+        Some(0x7747),
+        Some(0x7744),
+        Some(0x774d),
+        Some(0x7745),
+        Some(0x774a),
+        Some(0x774e),
+        Some(0x774b),
+        Some(0x774c),
+        Some(0x77de),
+        Some(0x77ec),
+        Some(0x7860),
+        Some(0x7864),
+        Some(0x7865),
+        Some(0x785c),
+        Some(0x786d),
+        Some(0x7871),
+        Some(0x786a),
+        Some(0x786e),
+        Some(0x7870),
+        Some(0x7869),
+        Some(0x7868),
+        Some(0x785e),
+        Some(0x7862),
+        Some(0x7974),
+        Some(0x7973),
+        Some(0x7972),
+        Some(0x7970),
+        Some(0x7a02),
+        Some(0x7a0a),
+        Some(0x7a03),
+        Some(0x7a0c),
+        Some(0x7a04),
+        Some(0x7a99),
+        Some(0x7ae6),
+        Some(0x7ae4),
+        Some(0x7b4a),
+        Some(0x7b3b),
+        Some(0x7b44),
+        Some(0x7b48),
+        Some(0x7b4c),
+        Some(0x7b4e),
+        Some(0x7b40),
+        Some(0x7b58),
+        Some(0x7b45),
+        Some(0x7ca2),
+        Some(0x7c9e),
+        Some(0x7ca8),
+        Some(0x7ca1),
+        Some(0x7d58),
+        Some(0x7d6f),
+        Some(0x7d63),
+        Some(0x7d53),
+        Some(0x7d56),
+        Some(0x7d67),
+        Some(0x7d6a),
+        Some(0x7d4f),
+        Some(0x7d6d),
+        Some(0x7d5c),
+        Some(0x7d6b),
+        Some(0x7d52),
+        Some(0x7d54),
+        Some(0x7d69),
+        Some(0x7d51),
+        Some(0x7d5f),
+        Some(0x7d4e),
+        Some(0x7f3e),
+        Some(0x7f3f),
+        Some(0x7f65),
+        None,
+    ];
+
+    const BASE_0XDB40: [Option<u16>; 192] = [
+        Some(0x7f66),
+        Some(0x7fa2),
+        Some(0x7fa0),
+        Some(0x7fa1),
+        Some(0x7fd7),
+        Some(0x8051),
+        Some(0x804f),
+        Some(0x8050),
+        Some(0x80fe),
+        Some(0x80d4),
+        Some(0x8143),
+        Some(0x814a),
+        Some(0x8152),
+        Some(0x814f),
+        Some(0x8147),
+        Some(0x813d),
+        Some(0x814d),
+        Some(0x813a),
+        Some(0x81e6),
+        Some(0x81ee),
+        Some(0x81f7),
+        Some(0x81f8),
+        Some(0x81f9),
+        Some(0x8204),
+        Some(0x823c),
+        Some(0x823d),
+        Some(0x823f),
+        Some(0x8275),
+        Some(0x833b),
+        Some(0x83cf),
+        Some(0x83f9),
+        Some(0x8423),
+        Some(0x83c0),
+        Some(0x83e8),
+        Some(0x8412),
+        Some(0x83e7),
+        Some(0x83e4),
+        Some(0x83fc),
+        Some(0x83f6),
+        Some(0x8410),
+        Some(0x83c6),
+        Some(0x83c8),
+        Some(0x83eb),
+        Some(0x83e3),
+        Some(0x83bf),
+        Some(0x8401),
+        Some(0x83dd),
+        Some(0x83e5),
+        Some(0x83d8),
+        Some(0x83ff),
+        Some(0x83e1),
+        Some(0x83cb),
+        Some(0x83ce),
+        Some(0x83d6),
+        Some(0x83f5),
+        Some(0x83c9),
+        Some(0x8409),
+        Some(0x840f),
+        Some(0x83de),
+        Some(0x8411),
+        Some(0x8406),
+        Some(0x83c2),
+        Some(0x83f3),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x83d5),
+        Some(0x83fa),
+        Some(0x83c7),
+        Some(0x83d1),
+        Some(0x83ea),
+        Some(0x8413),
+        Some(0x83c3),
+        Some(0x83ec),
+        Some(0x83ee),
+        Some(0x83c4),
+        Some(0x83fb),
+        Some(0x83d7),
+        Some(0x83e2),
+        Some(0x841b),
+        Some(0x83db),
+        Some(0x83fe),
+        Some(0x86d8),
+        Some(0x86e2),
+        Some(0x86e6),
+        Some(0x86d3),
+        Some(0x86e3),
+        Some(0x86da),
+        Some(0x86ea),
+        Some(0x86dd),
+        Some(0x86eb),
+        Some(0x86dc),
+        Some(0x86ec),
+        Some(0x86e9),
+        Some(0x86d7),
+        Some(0x86e8),
+        Some(0x86d1),
+        Some(0x8848),
+        Some(0x8856),
+        Some(0x8855),
+        Some(0x88ba),
+        Some(0x88d7),
+        Some(0x88b9),
+        Some(0x88b8),
+        Some(0x88c0),
+        Some(0x88be),
+        Some(0x88b6),
+        Some(0x88bc),
+        Some(0x88b7),
+        Some(0x88bd),
+        Some(0x88b2),
+        Some(0x8901),
+        Some(0x88c9),
+        Some(0x8995),
+        Some(0x8998),
+        Some(0x8997),
+        Some(0x89dd),
+        Some(0x89da),
+        Some(0x89db),
+        Some(0x8a4e),
+        Some(0x8a4d),
+        Some(0x8a39),
+        Some(0x8a59),
+        Some(0x8a40),
+        Some(0x8a57),
+        Some(0x8a58),
+        Some(0x8a44),
+        Some(0x8a45),
+        Some(0x8a52),
+        Some(0x8a48),
+        Some(0x8a51),
+        Some(0x8a4a),
+        Some(0x8a4c),
+        Some(0x8a4f),
+        Some(0x8c5f),
+        Some(0x8c81),
+        Some(0x8c80),
+        Some(0x8cba),
+        Some(0x8cbe),
+        Some(0x8cb0),
+        Some(0x8cb9),
+        Some(0x8cb5),
+        Some(0x8d84),
+        Some(0x8d80),
+        Some(0x8d89),
+        Some(0x8dd8),
+        Some(0x8dd3),
+        Some(0x8dcd),
+        Some(0x8dc7),
+        Some(0x8dd6),
+        Some(0x8ddc),
+        Some(0x8dcf),
+        Some(0x8dd5),
+        Some(0x8dd9),
+        Some(0x8dc8),
+        Some(0x8dd7),
+        Some(0x8dc5),
+        Some(0x8eef),
+        Some(0x8ef7),
+        Some(0x8efa),
+        None,
+    ];
+
+    const BASE_0XDC40: [Option<u16>; 192] = [
+        Some(0x8ef9),
+        Some(0x8ee6),
+        Some(0x8eee),
+        Some(0x8ee5),
+        Some(0x8ef5),
+        Some(0x8ee7),
+        Some(0x8ee8),
+        Some(0x8ef6),
+        Some(0x8eeb),
+        Some(0x8ef1),
+        Some(0x8eec),
+        Some(0x8ef4),
+        Some(0x8ee9),
+        Some(0x902d),
+        Some(0x9034),
+        Some(0x902f),
+        Some(0x9106),
+        Some(0x912c),
+        Some(0x9104),
+        Some(0x90ff),
+        Some(0x90fc),
+        Some(0x9108),
+        Some(0x90f9),
+        Some(0x90fb),
+        Some(0x9101),
+        Some(0x9100),
+        Some(0x9107),
+        Some(0x9105),
+        Some(0x9103),
+        Some(0x9161),
+        Some(0x9164),
+        Some(0x915f),
+        Some(0x9162),
+        Some(0x9160),
+        Some(0x9201),
+        Some(0x920a),
+        Some(0x9225),
+        Some(0x9203),
+        Some(0x921a),
+        Some(0x9226),
+        Some(0x920f),
+        Some(0x920c),
+        Some(0x9200),
+        Some(0x9212),
+        Some(0x91ff),
+        Some(0x91fd),
+        Some(0x9206),
+        Some(0x9204),
+        Some(0x9227),
+        Some(0x9202),
+        Some(0x921c),
+        Some(0x9224),
+        Some(0x9219),
+        Some(0x9217),
+        Some(0x9205),
+        Some(0x9216),
+        Some(0x957b),
+        Some(0x958d),
+        Some(0x958c),
+        Some(0x9590),
+        Some(0x9687),
+        Some(0x967e),
+        Some(0x9688),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9689),
+        Some(0x9683),
+        Some(0x9680),
+        Some(0x96c2),
+        Some(0x96c8),
+        Some(0x96c3),
+        Some(0x96f1),
+        Some(0x96f0),
+        Some(0x976c),
+        Some(0x9770),
+        Some(0x976e),
+        Some(0x9807),
+        Some(0x98a9),
+        Some(0x98eb),
+        Some(0x9ce6),
+        Some(0x9ef9),
+        Some(0x4e83),
+        Some(0x4e84),
+        Some(0x4eb6),
+        Some(0x50bd),
+        Some(0x50bf),
+        Some(0x50c6),
+        Some(0x50ae),
+        Some(0x50c4),
+        Some(0x50ca),
+        Some(0x50b4),
+        Some(0x50c8),
+        Some(0x50c2),
+        Some(0x50b0),
+        Some(0x50c1),
+        Some(0x50ba),
+        Some(0x50b1),
+        Some(0x50cb),
+        Some(0x50c9),
+        Some(0x50b6),
+        Some(0x50b8),
+        Some(0x51d7),
+        Some(0x527a),
+        Some(0x5278),
+        Some(0x527b),
+        Some(0x527c),
+        Some(0x55c3),
+        Some(0x55db),
+        Some(0x55cc),
+        Some(0x55d0),
+        Some(0x55cb),
+        Some(0x55ca),
+        Some(0x55dd),
+        Some(0x55c0),
+        Some(0x55d4),
+        Some(0x55c4),
+        Some(0x55e9),
+        Some(0x55bf),
+        Some(0x55d2),
+        Some(0x558d),
+        Some(0x55cf),
+        Some(0x55d5),
+        Some(0x55e2),
+        Some(0x55d6),
+        Some(0x55c8),
+        Some(0x55f2),
+        Some(0x55cd),
+        Some(0x55d9),
+        Some(0x55c2),
+        Some(0x5714),
+        Some(0x5853),
+        Some(0x5868),
+        Some(0x5864),
+        Some(0x584f),
+        Some(0x584d),
+        Some(0x5849),
+        Some(0x586f),
+        Some(0x5855),
+        Some(0x584e),
+        Some(0x585d),
+        Some(0x5859),
+        Some(0x5865),
+        Some(0x585b),
+        Some(0x583d),
+        Some(0x5863),
+        Some(0x5871),
+        Some(0x58fc),
+        Some(0x5ac7),
+        Some(0x5ac4),
+        Some(0x5acb),
+        Some(0x5aba),
+        Some(0x5ab8),
+        Some(0x5ab1),
+        Some(0x5ab5),
+        Some(0x5ab0),
+        Some(0x5abf),
+        Some(0x5ac8),
+        Some(0x5abb),
+        Some(0x5ac6),
+        None,
+    ];
+
+    const BASE_0XDD40: [Option<u16>; 192] = [
+        Some(0x5ab7),
+        Some(0x5ac0),
+        Some(0x5aca),
+        Some(0x5ab4),
+        Some(0x5ab6),
+        Some(0x5acd),
+        Some(0x5ab9),
+        Some(0x5a90),
+        Some(0x5bd6),
+        Some(0x5bd8),
+        Some(0x5bd9),
+        Some(0x5c1f),
+        Some(0x5c33),
+        Some(0x5d71),
+        Some(0x5d63),
+        Some(0x5d4a),
+        Some(0x5d65),
+        Some(0x5d72),
+        Some(0x5d6c),
+        Some(0x5d5e),
+        Some(0x5d68),
+        Some(0x5d67),
+        Some(0x5d62),
+        Some(0x5df0),
+        Some(0x5e4f),
+        Some(0x5e4e),
+        Some(0x5e4a),
+        Some(0x5e4d),
+        Some(0x5e4b),
+        Some(0x5ec5),
+        Some(0x5ecc),
+        Some(0x5ec6),
+        Some(0x5ecb),
+        Some(0x5ec7),
+        Some(0x5f40),
+        Some(0x5faf),
+        Some(0x5fad),
+        Some(0x60f7),
+        Some(0x6149),
+        Some(0x614a),
+        Some(0x612b),
+        Some(0x6145),
+        Some(0x6136),
+        Some(0x6132),
+        Some(0x612e),
+        Some(0x6146),
+        Some(0x612f),
+        Some(0x614f),
+        Some(0x6129),
+        Some(0x6140),
+        Some(0x6220),
+        Some(0x9168),
+        Some(0x6223),
+        Some(0x6225),
+        Some(0x6224),
+        Some(0x63c5),
+        Some(0x63f1),
+        Some(0x63eb),
+        Some(0x6410),
+        Some(0x6412),
+        Some(0x6409),
+        Some(0x6420),
+        Some(0x6424),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6433),
+        Some(0x6443),
+        Some(0x641f),
+        Some(0x6415),
+        Some(0x6418),
+        Some(0x6439),
+        Some(0x6437),
+        Some(0x6422),
+        Some(0x6423),
+        Some(0x640c),
+        Some(0x6426),
+        Some(0x6430),
+        Some(0x6428),
+        Some(0x6441),
+        Some(0x6435),
+        Some(0x642f),
+        Some(0x640a),
+        Some(0x641a),
+        Some(0x6440),
+        Some(0x6425),
+        Some(0x6427),
+        Some(0x640b),
+        Some(0x63e7),
+        Some(0x641b),
+        Some(0x642e),
+        Some(0x6421),
+        Some(0x640e),
+        Some(0x656f),
+        Some(0x6592),
+        Some(0x65d3),
+        Some(0x6686),
+        Some(0x668c),
+        Some(0x6695),
+        Some(0x6690),
+        Some(0x668b),
+        Some(0x668a),
+        Some(0x6699),
+        Some(0x6694),
+        Some(0x6678),
+        Some(0x6720),
+        Some(0x6966),
+        Some(0x695f),
+        Some(0x6938),
+        Some(0x694e),
+        Some(0x6962),
+        Some(0x6971),
+        Some(0x693f),
+        Some(0x6945),
+        Some(0x696a),
+        Some(0x6939),
+        Some(0x6942),
+        Some(0x6957),
+        Some(0x6959),
+        Some(0x697a),
+        Some(0x6948),
+        Some(0x6949),
+        Some(0x6935),
+        Some(0x696c),
+        Some(0x6933),
+        Some(0x693d),
+        Some(0x6965),
+        Some(0x68f0),
+        Some(0x6978),
+        Some(0x6934),
+        Some(0x6969),
+        Some(0x6940),
+        Some(0x696f),
+        Some(0x6944),
+        Some(0x6976),
+        Some(0x6958),
+        Some(0x6941),
+        Some(0x6974),
+        Some(0x694c),
+        Some(0x693b),
+        Some(0x694b),
+        Some(0x6937),
+        Some(0x695c),
+        Some(0x694f),
+        Some(0x6951),
+        Some(0x6932),
+        Some(0x6952),
+        Some(0x692f),
+        Some(0x697b),
+        Some(0x693c),
+        Some(0x6b46),
+        Some(0x6b45),
+        Some(0x6b43),
+        Some(0x6b42),
+        Some(0x6b48),
+        Some(0x6b41),
+        Some(0x6b9b),
+        Some(0xfa0d),
+        Some(0x6bfb),
+        Some(0x6bfc),
+        None,
+    ];
+
+    const BASE_0XDE40: [Option<u16>; 192] = [
+        Some(0x6bf9),
+        Some(0x6bf7),
+        Some(0x6bf8),
+        Some(0x6e9b),
+        Some(0x6ed6),
+        Some(0x6ec8),
+        Some(0x6e8f),
+        Some(0x6ec0),
+        Some(0x6e9f),
+        Some(0x6e93),
+        Some(0x6e94),
+        Some(0x6ea0),
+        Some(0x6eb1),
+        Some(0x6eb9),
+        Some(0x6ec6),
+        Some(0x6ed2),
+        Some(0x6ebd),
+        Some(0x6ec1),
+        Some(0x6e9e),
+        Some(0x6ec9),
+        Some(0x6eb7),
+        Some(0x6eb0),
+        Some(0x6ecd),
+        Some(0x6ea6),
+        Some(0x6ecf),
+        Some(0x6eb2),
+        Some(0x6ebe),
+        Some(0x6ec3),
+        Some(0x6edc),
+        Some(0x6ed8),
+        Some(0x6e99),
+        Some(0x6e92),
+        Some(0x6e8e),
+        Some(0x6e8d),
+        Some(0x6ea4),
+        Some(0x6ea1),
+        Some(0x6ebf),
+        Some(0x6eb3),
+        Some(0x6ed0),
+        Some(0x6eca),
+        Some(0x6e97),
+        Some(0x6eae),
+        Some(0x6ea3),
+        Some(0x7147),
+        Some(0x7154),
+        Some(0x7152),
+        Some(0x7163),
+        Some(0x7160),
+        Some(0x7141),
+        Some(0x715d),
+        Some(0x7162),
+        Some(0x7172),
+        Some(0x7178),
+        Some(0x716a),
+        Some(0x7161),
+        Some(0x7142),
+        Some(0x7158),
+        Some(0x7143),
+        Some(0x714b),
+        Some(0x7170),
+        Some(0x715f),
+        Some(0x7150),
+        Some(0x7153),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7144),
+        Some(0x714d),
+        Some(0x715a),
+        Some(0x724f),
+        Some(0x728d),
+        Some(0x728c),
+        Some(0x7291),
+        Some(0x7290),
+        Some(0x728e),
+        Some(0x733c),
+        Some(0x7342),
+        Some(0x733b),
+        Some(0x733a),
+        Some(0x7340),
+        Some(0x734a),
+        Some(0x7349),
+        Some(0x7444),
+        Some(0x744a),
+        Some(0x744b),
+        Some(0x7452),
+        Some(0x7451),
+        Some(0x7457),
+        Some(0x7440),
+        Some(0x744f),
+        Some(0x7450),
+        Some(0x744e),
+        Some(0x7442),
+        Some(0x7446),
+        Some(0x744d),
+        Some(0x7454),
+        Some(0x74e1),
+        Some(0x74ff),
+        Some(0x74fe),
+        Some(0x74fd),
+        Some(0x751d),
+        Some(0x7579),
+        Some(0x7577),
+        Some(0x6983),
+        Some(0x75ef),
+        Some(0x760f),
+        Some(0x7603),
+        Some(0x75f7),
+        Some(0x75fe),
+        Some(0x75fc),
+        Some(0x75f9),
+        Some(0x75f8),
+        Some(0x7610),
+        Some(0x75fb),
+        Some(0x75f6),
+        Some(0x75ed),
+        Some(0x75f5),
+        Some(0x75fd),
+        Some(0x7699),
+        Some(0x76b5),
+        Some(0x76dd),
+        Some(0x7755),
+        Some(0x775f),
+        Some(0x7760),
+        Some(0x7752),
+        Some(0x7756),
+        Some(0x775a),
+        Some(0x7769),
+        Some(0x7767),
+        Some(0x7754),
+        Some(0x7759),
+        Some(0x776d),
+        Some(0x77e0),
+        Some(0x7887),
+        Some(0x789a),
+        Some(0x7894),
+        Some(0x788f),
+        Some(0x7884),
+        Some(0x7895),
+        Some(0x7885),
+        Some(0x7886),
+        Some(0x78a1),
+        Some(0x7883),
+        Some(0x7879),
+        Some(0x7899),
+        Some(0x7880),
+        Some(0x7896),
+        Some(0x787b),
+        Some(0x797c),
+        Some(0x7982),
+        Some(0x797d),
+        Some(0x7979),
+        Some(0x7a11),
+        Some(0x7a18),
+        Some(0x7a19),
+        Some(0x7a12),
+        Some(0x7a17),
+        Some(0x7a15),
+        Some(0x7a22),
+        Some(0x7a13),
+        None,
+    ];
+
+    const BASE_0XDF40: [Option<u16>; 192] = [
+        Some(0x7a1b),
+        Some(0x7a10),
+        Some(0x7aa3),
+        Some(0x7aa2),
+        Some(0x7a9e),
+        Some(0x7aeb),
+        Some(0x7b66),
+        Some(0x7b64),
+        Some(0x7b6d),
+        Some(0x7b74),
+        Some(0x7b69),
+        Some(0x7b72),
+        Some(0x7b65),
+        Some(0x7b73),
+        Some(0x7b71),
+        Some(0x7b70),
+        Some(0x7b61),
+        Some(0x7b78),
+        Some(0x7b76),
+        Some(0x7b63),
+        Some(0x7cb2),
+        Some(0x7cb4),
+        Some(0x7caf),
+        Some(0x7d88),
+        Some(0x7d86),
+        Some(0x7d80),
+        Some(0x7d8d),
+        Some(0x7d7f),
+        Some(0x7d85),
+        Some(0x7d7a),
+        Some(0x7d8e),
+        Some(0x7d7b),
+        Some(0x7d83),
+        Some(0x7d7c),
+        Some(0x7d8c),
+        Some(0x7d94),
+        Some(0x7d84),
+        Some(0x7d7d),
+        Some(0x7d92),
+        Some(0x7f6d),
+        Some(0x7f6b),
+        Some(0x7f67),
+        Some(0x7f68),
+        Some(0x7f6c),
+        Some(0x7fa6),
+        Some(0x7fa5),
+        Some(0x7fa7),
+        Some(0x7fdb),
+        Some(0x7fdc),
+        Some(0x8021),
+        Some(0x8164),
+        Some(0x8160),
+        Some(0x8177),
+        Some(0x815c),
+        Some(0x8169),
+        Some(0x815b),
+        Some(0x8162),
+        Some(0x8172),
+        Some(0x6721),
+        Some(0x815e),
+        Some(0x8176),
+        Some(0x8167),
+        Some(0x816f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8144),
+        Some(0x8161),
+        Some(0x821d),
+        Some(0x8249),
+        Some(0x8244),
+        Some(0x8240),
+        Some(0x8242),
+        Some(0x8245),
+        Some(0x84f1),
+        Some(0x843f),
+        Some(0x8456),
+        Some(0x8476),
+        Some(0x8479),
+        Some(0x848f),
+        Some(0x848d),
+        Some(0x8465),
+        Some(0x8451),
+        Some(0x8440),
+        Some(0x8486),
+        Some(0x8467),
+        Some(0x8430),
+        Some(0x844d),
+        Some(0x847d),
+        Some(0x845a),
+        Some(0x8459),
+        Some(0x8474),
+        Some(0x8473),
+        Some(0x845d),
+        Some(0x8507),
+        Some(0x845e),
+        Some(0x8437),
+        Some(0x843a),
+        Some(0x8434),
+        Some(0x847a),
+        Some(0x8443),
+        Some(0x8478),
+        Some(0x8432),
+        Some(0x8445),
+        Some(0x8429),
+        Some(0x83d9),
+        Some(0x844b),
+        Some(0x842f),
+        Some(0x8442),
+        Some(0x842d),
+        Some(0x845f),
+        Some(0x8470),
+        Some(0x8439),
+        Some(0x844e),
+        Some(0x844c),
+        Some(0x8452),
+        Some(0x846f),
+        Some(0x84c5),
+        Some(0x848e),
+        Some(0x843b),
+        Some(0x8447),
+        Some(0x8436),
+        Some(0x8433),
+        Some(0x8468),
+        Some(0x847e),
+        Some(0x8444),
+        Some(0x842b),
+        Some(0x8460),
+        Some(0x8454),
+        Some(0x846e),
+        Some(0x8450),
+        Some(0x870b),
+        Some(0x8704),
+        Some(0x86f7),
+        Some(0x870c),
+        Some(0x86fa),
+        Some(0x86d6),
+        Some(0x86f5),
+        Some(0x874d),
+        Some(0x86f8),
+        Some(0x870e),
+        Some(0x8709),
+        Some(0x8701),
+        Some(0x86f6),
+        Some(0x870d),
+        Some(0x8705),
+        Some(0x88d6),
+        Some(0x88cb),
+        Some(0x88cd),
+        Some(0x88ce),
+        Some(0x88de),
+        Some(0x88db),
+        Some(0x88da),
+        Some(0x88cc),
+        Some(0x88d0),
+        Some(0x8985),
+        Some(0x899b),
+        Some(0x89df),
+        Some(0x89e5),
+        Some(0x89e4),
+        None,
+    ];
+
+    const BASE_0XE040: [Option<u16>; 192] = [
+        Some(0x89e1),
+        Some(0x89e0),
+        Some(0x89e2),
+        Some(0x89dc),
+        Some(0x89e6),
+        Some(0x8a76),
+        Some(0x8a86),
+        Some(0x8a7f),
+        Some(0x8a61),
+        Some(0x8a3f),
+        Some(0x8a77),
+        Some(0x8a82),
+        Some(0x8a84),
+        Some(0x8a75),
+        Some(0x8a83),
+        Some(0x8a81),
+        Some(0x8a74),
+        Some(0x8a7a),
+        Some(0x8c3c),
+        Some(0x8c4b),
+        Some(0x8c4a),
+        Some(0x8c65),
+        Some(0x8c64),
+        Some(0x8c66),
+        Some(0x8c86),
+        Some(0x8c84),
+        Some(0x8c85),
+        Some(0x8ccc),
+        Some(0x8d68),
+        Some(0x8d69),
+        Some(0x8d91),
+        Some(0x8d8c),
+        Some(0x8d8e),
+        Some(0x8d8f),
+        Some(0x8d8d),
+        Some(0x8d93),
+        Some(0x8d94),
+        Some(0x8d90),
+        Some(0x8d92),
+        Some(0x8df0),
+        Some(0x8de0),
+        Some(0x8dec),
+        Some(0x8df1),
+        Some(0x8dee),
+        Some(0x8dd0),
+        Some(0x8de9),
+        Some(0x8de3),
+        Some(0x8de2),
+        Some(0x8de7),
+        Some(0x8df2),
+        Some(0x8deb),
+        Some(0x8df4),
+        Some(0x8f06),
+        Some(0x8eff),
+        Some(0x8f01),
+        Some(0x8f00),
+        Some(0x8f05),
+        Some(0x8f07),
+        Some(0x8f08),
+        Some(0x8f02),
+        Some(0x8f0b),
+        Some(0x9052),
+        Some(0x903f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9044),
+        Some(0x9049),
+        Some(0x903d),
+        Some(0x9110),
+        Some(0x910d),
+        Some(0x910f),
+        Some(0x9111),
+        Some(0x9116),
+        Some(0x9114),
+        Some(0x910b),
+        Some(0x910e),
+        Some(0x916e),
+        Some(0x916f),
+        Some(0x9248),
+        Some(0x9252),
+        Some(0x9230),
+        Some(0x923a),
+        Some(0x9266),
+        Some(0x9233),
+        Some(0x9265),
+        Some(0x925e),
+        Some(0x9283),
+        Some(0x922e),
+        Some(0x924a),
+        Some(0x9246),
+        Some(0x926d),
+        Some(0x926c),
+        Some(0x924f),
+        Some(0x9260),
+        Some(0x9267),
+        Some(0x926f),
+        Some(0x9236),
+        Some(0x9261),
+        Some(0x9270),
+        Some(0x9231),
+        Some(0x9254),
+        Some(0x9263),
+        Some(0x9250),
+        Some(0x9272),
+        Some(0x924e),
+        Some(0x9253),
+        Some(0x924c),
+        Some(0x9256),
+        Some(0x9232),
+        Some(0x959f),
+        Some(0x959c),
+        Some(0x959e),
+        Some(0x959b),
+        Some(0x9692),
+        Some(0x9693),
+        Some(0x9691),
+        Some(0x9697),
+        Some(0x96ce),
+        Some(0x96fa),
+        Some(0x96fd),
+        Some(0x96f8),
+        Some(0x96f5),
+        Some(0x9773),
+        Some(0x9777),
+        Some(0x9778),
+        Some(0x9772),
+        Some(0x980f),
+        Some(0x980d),
+        Some(0x980e),
+        Some(0x98ac),
+        Some(0x98f6),
+        Some(0x98f9),
+        Some(0x99af),
+        Some(0x99b2),
+        Some(0x99b0),
+        Some(0x99b5),
+        Some(0x9aad),
+        Some(0x9aab),
+        Some(0x9b5b),
+        Some(0x9cea),
+        Some(0x9ced),
+        Some(0x9ce7),
+        Some(0x9e80),
+        Some(0x9efd),
+        Some(0x50e6),
+        Some(0x50d4),
+        Some(0x50d7),
+        Some(0x50e8),
+        Some(0x50f3),
+        Some(0x50db),
+        Some(0x50ea),
+        Some(0x50dd),
+        Some(0x50e4),
+        Some(0x50d3),
+        Some(0x50ec),
+        Some(0x50f0),
+        Some(0x50ef),
+        Some(0x50e3),
+        Some(0x50e0),
+        None,
+    ];
+
+    const BASE_0XE140: [Option<u16>; 192] = [
+        Some(0x51d8),
+        Some(0x5280),
+        Some(0x5281),
+        Some(0x52e9),
+        Some(0x52eb),
+        Some(0x5330),
+        Some(0x53ac),
+        Some(0x5627),
+        Some(0x5615),
+        Some(0x560c),
+        Some(0x5612),
+        Some(0x55fc),
+        Some(0x560f),
+        Some(0x561c),
+        Some(0x5601),
+        Some(0x5613),
+        Some(0x5602),
+        Some(0x55fa),
+        Some(0x561d),
+        Some(0x5604),
+        Some(0x55ff),
+        Some(0x55f9),
+        Some(0x5889),
+        Some(0x587c),
+        Some(0x5890),
+        Some(0x5898),
+        Some(0x5886),
+        Some(0x5881),
+        Some(0x587f),
+        Some(0x5874),
+        Some(0x588b),
+        Some(0x587a),
+        Some(0x5887),
+        Some(0x5891),
+        Some(0x588e),
+        Some(0x5876),
+        Some(0x5882),
+        Some(0x5888),
+        Some(0x587b),
+        Some(0x5894),
+        Some(0x588f),
+        Some(0x58fe),
+        Some(0x596b),
+        Some(0x5adc),
+        Some(0x5aee),
+        Some(0x5ae5),
+        Some(0x5ad5),
+        Some(0x5aea),
+        Some(0x5ada),
+        Some(0x5aed),
+        Some(0x5aeb),
+        Some(0x5af3),
+        Some(0x5ae2),
+        Some(0x5ae0),
+        Some(0x5adb),
+        Some(0x5aec),
+        Some(0x5ade),
+        Some(0x5add),
+        Some(0x5ad9),
+        Some(0x5ae8),
+        Some(0x5adf),
+        Some(0x5b77),
+        Some(0x5be0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x5be3),
+        Some(0x5c63),
+        Some(0x5d82),
+        Some(0x5d80),
+        Some(0x5d7d),
+        Some(0x5d86),
+        Some(0x5d7a),
+        Some(0x5d81),
+        Some(0x5d77),
+        Some(0x5d8a),
+        Some(0x5d89),
+        Some(0x5d88),
+        Some(0x5d7e),
+        Some(0x5d7c),
+        Some(0x5d8d),
+        Some(0x5d79),
+        Some(0x5d7f),
+        Some(0x5e58),
+        Some(0x5e59),
+        Some(0x5e53),
+        Some(0x5ed8),
+        Some(0x5ed1),
+        Some(0x5ed7),
+        Some(0x5ece),
+        Some(0x5edc),
+        Some(0x5ed5),
+        Some(0x5ed9),
+        Some(0x5ed2),
+        Some(0x5ed4),
+        Some(0x5f44),
+        Some(0x5f43),
+        Some(0x5f6f),
+        Some(0x5fb6),
+        Some(0x612c),
+        Some(0x6128),
+        Some(0x6141),
+        Some(0x615e),
+        Some(0x6171),
+        Some(0x6173),
+        Some(0x6152),
+        Some(0x6153),
+        Some(0x6172),
+        Some(0x616c),
+        Some(0x6180),
+        Some(0x6174),
+        Some(0x6154),
+        Some(0x617a),
+        Some(0x615b),
+        Some(0x6165),
+        Some(0x613b),
+        Some(0x616a),
+        Some(0x6161),
+        Some(0x6156),
+        Some(0x6229),
+        Some(0x6227),
+        Some(0x622b),
+        Some(0x642b),
+        Some(0x644d),
+        Some(0x645b),
+        Some(0x645d),
+        Some(0x6474),
+        Some(0x6476),
+        Some(0x6472),
+        Some(0x6473),
+        Some(0x647d),
+        Some(0x6475),
+        Some(0x6466),
+        Some(0x64a6),
+        Some(0x644e),
+        Some(0x6482),
+        Some(0x645e),
+        Some(0x645c),
+        Some(0x644b),
+        Some(0x6453),
+        Some(0x6460),
+        Some(0x6450),
+        Some(0x647f),
+        Some(0x643f),
+        Some(0x646c),
+        Some(0x646b),
+        Some(0x6459),
+        Some(0x6465),
+        Some(0x6477),
+        Some(0x6573),
+        Some(0x65a0),
+        Some(0x66a1),
+        Some(0x66a0),
+        Some(0x669f),
+        Some(0x6705),
+        Some(0x6704),
+        Some(0x6722),
+        Some(0x69b1),
+        Some(0x69b6),
+        Some(0x69c9),
+        None,
+    ];
+
+    const BASE_0XE240: [Option<u16>; 192] = [
+        Some(0x69a0),
+        Some(0x69ce),
+        Some(0x6996),
+        Some(0x69b0),
+        Some(0x69ac),
+        Some(0x69bc),
+        Some(0x6991),
+        Some(0x6999),
+        Some(0x698e),
+        Some(0x69a7),
+        Some(0x698d),
+        Some(0x69a9),
+        Some(0x69be),
+        Some(0x69af),
+        Some(0x69bf),
+        Some(0x69c4),
+        Some(0x69bd),
+        Some(0x69a4),
+        Some(0x69d4),
+        Some(0x69b9),
+        Some(0x69ca),
+        Some(0x699a),
+        Some(0x69cf),
+        Some(0x69b3),
+        Some(0x6993),
+        Some(0x69aa),
+        Some(0x69a1),
+        Some(0x699e),
+        Some(0x69d9),
+        Some(0x6997),
+        Some(0x6990),
+        Some(0x69c2),
+        Some(0x69b5),
+        Some(0x69a5),
+        Some(0x69c6),
+        Some(0x6b4a),
+        Some(0x6b4d),
+        Some(0x6b4b),
+        Some(0x6b9e),
+        Some(0x6b9f),
+        Some(0x6ba0),
+        Some(0x6bc3),
+        Some(0x6bc4),
+        Some(0x6bfe),
+        Some(0x6ece),
+        Some(0x6ef5),
+        Some(0x6ef1),
+        Some(0x6f03),
+        Some(0x6f25),
+        Some(0x6ef8),
+        Some(0x6f37),
+        Some(0x6efb),
+        Some(0x6f2e),
+        Some(0x6f09),
+        Some(0x6f4e),
+        Some(0x6f19),
+        Some(0x6f1a),
+        Some(0x6f27),
+        Some(0x6f18),
+        Some(0x6f3b),
+        Some(0x6f12),
+        Some(0x6eed),
+        Some(0x6f0a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x6f36),
+        Some(0x6f73),
+        Some(0x6ef9),
+        Some(0x6eee),
+        Some(0x6f2d),
+        Some(0x6f40),
+        Some(0x6f30),
+        Some(0x6f3c),
+        Some(0x6f35),
+        Some(0x6eeb),
+        Some(0x6f07),
+        Some(0x6f0e),
+        Some(0x6f43),
+        Some(0x6f05),
+        Some(0x6efd),
+        Some(0x6ef6),
+        Some(0x6f39),
+        Some(0x6f1c),
+        Some(0x6efc),
+        Some(0x6f3a),
+        Some(0x6f1f),
+        Some(0x6f0d),
+        Some(0x6f1e),
+        Some(0x6f08),
+        Some(0x6f21),
+        Some(0x7187),
+        Some(0x7190),
+        Some(0x7189),
+        Some(0x7180),
+        Some(0x7185),
+        Some(0x7182),
+        Some(0x718f),
+        Some(0x717b),
+        Some(0x7186),
+        Some(0x7181),
+        Some(0x7197),
+        Some(0x7244),
+        Some(0x7253),
+        Some(0x7297),
+        Some(0x7295),
+        Some(0x7293),
+        Some(0x7343),
+        Some(0x734d),
+        Some(0x7351),
+        Some(0x734c),
+        Some(0x7462),
+        Some(0x7473),
+        Some(0x7471),
+        Some(0x7475),
+        Some(0x7472),
+        Some(0x7467),
+        Some(0x746e),
+        Some(0x7500),
+        Some(0x7502),
+        Some(0x7503),
+        Some(0x757d),
+        Some(0x7590),
+        Some(0x7616),
+        Some(0x7608),
+        Some(0x760c),
+        Some(0x7615),
+        Some(0x7611),
+        Some(0x760a),
+        Some(0x7614),
+        Some(0x76b8),
+        Some(0x7781),
+        Some(0x777c),
+        Some(0x7785),
+        Some(0x7782),
+        Some(0x776e),
+        Some(0x7780),
+        Some(0x776f),
+        Some(0x777e),
+        Some(0x7783),
+        Some(0x78b2),
+        Some(0x78aa),
+        Some(0x78b4),
+        Some(0x78ad),
+        Some(0x78a8),
+        Some(0x787e),
+        Some(0x78ab),
+        Some(0x789e),
+        Some(0x78a5),
+        Some(0x78a0),
+        Some(0x78ac),
+        Some(0x78a2),
+        Some(0x78a4),
+        Some(0x7998),
+        Some(0x798a),
+        Some(0x798b),
+        Some(0x7996),
+        Some(0x7995),
+        Some(0x7994),
+        Some(0x7993),
+        None,
+    ];
+
+    const BASE_0XE340: [Option<u16>; 192] = [
+        Some(0x7997),
+        Some(0x7988),
+        Some(0x7992),
+        Some(0x7990),
+        Some(0x7a2b),
+        Some(0x7a4a),
+        Some(0x7a30),
+        Some(0x7a2f),
+        Some(0x7a28),
+        Some(0x7a26),
+        Some(0x7aa8),
+        Some(0x7aab),
+        Some(0x7aac),
+        Some(0x7aee),
+        Some(0x7b88),
+        Some(0x7b9c),
+        Some(0x7b8a),
+        Some(0x7b91),
+        Some(0x7b90),
+        Some(0x7b96),
+        Some(0x7b8d),
+        Some(0x7b8c),
+        Some(0x7b9b),
+        Some(0x7b8e),
+        Some(0x7b85),
+        Some(0x7b98),
+        Some(0x5284),
+        Some(0x7b99),
+        Some(0x7ba4),
+        Some(0x7b82),
+        Some(0x7cbb),
+        Some(0x7cbf),
+        Some(0x7cbc),
+        Some(0x7cba),
+        Some(0x7da7),
+        Some(0x7db7),
+        Some(0x7dc2),
+        Some(0x7da3),
+        Some(0x7daa),
+        Some(0x7dc1),
+        Some(0x7dc0),
+        Some(0x7dc5),
+        Some(0x7d9d),
+        Some(0x7dce),
+        Some(0x7dc4),
+        Some(0x7dc6),
+        Some(0x7dcb),
+        Some(0x7dcc),
+        Some(0x7daf),
+        Some(0x7db9),
+        Some(0x7d96),
+        Some(0x7dbc),
+        Some(0x7d9f),
+        Some(0x7da6),
+        Some(0x7dae),
+        Some(0x7da9),
+        Some(0x7da1),
+        Some(0x7dc9),
+        Some(0x7f73),
+        Some(0x7fe2),
+        Some(0x7fe3),
+        Some(0x7fe5),
+        Some(0x7fde),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8024),
+        Some(0x805d),
+        Some(0x805c),
+        Some(0x8189),
+        Some(0x8186),
+        Some(0x8183),
+        Some(0x8187),
+        Some(0x818d),
+        Some(0x818c),
+        Some(0x818b),
+        Some(0x8215),
+        Some(0x8497),
+        Some(0x84a4),
+        Some(0x84a1),
+        Some(0x849f),
+        Some(0x84ba),
+        Some(0x84ce),
+        Some(0x84c2),
+        Some(0x84ac),
+        Some(0x84ae),
+        Some(0x84ab),
+        Some(0x84b9),
+        Some(0x84b4),
+        Some(0x84c1),
+        Some(0x84cd),
+        Some(0x84aa),
+        Some(0x849a),
+        Some(0x84b1),
+        Some(0x84d0),
+        Some(0x849d),
+        Some(0x84a7),
+        Some(0x84bb),
+        Some(0x84a2),
+        Some(0x8494),
+        Some(0x84c7),
+        Some(0x84cc),
+        Some(0x849b),
+        Some(0x84a9),
+        Some(0x84af),
+        Some(0x84a8),
+        Some(0x84d6),
+        Some(0x8498),
+        Some(0x84b6),
+        Some(0x84cf),
+        Some(0x84a0),
+        Some(0x84d7),
+        Some(0x84d4),
+        Some(0x84d2),
+        Some(0x84db),
+        Some(0x84b0),
+        Some(0x8491),
+        Some(0x8661),
+        Some(0x8733),
+        Some(0x8723),
+        Some(0x8728),
+        Some(0x876b),
+        Some(0x8740),
+        Some(0x872e),
+        Some(0x871e),
+        Some(0x8721),
+        Some(0x8719),
+        Some(0x871b),
+        Some(0x8743),
+        Some(0x872c),
+        Some(0x8741),
+        Some(0x873e),
+        Some(0x8746),
+        Some(0x8720),
+        Some(0x8732),
+        Some(0x872a),
+        Some(0x872d),
+        Some(0x873c),
+        Some(0x8712),
+        Some(0x873a),
+        Some(0x8731),
+        Some(0x8735),
+        Some(0x8742),
+        Some(0x8726),
+        Some(0x8727),
+        Some(0x8738),
+        Some(0x8724),
+        Some(0x871a),
+        Some(0x8730),
+        Some(0x8711),
+        Some(0x88f7),
+        Some(0x88e7),
+        Some(0x88f1),
+        Some(0x88f2),
+        Some(0x88fa),
+        Some(0x88fe),
+        Some(0x88ee),
+        Some(0x88fc),
+        Some(0x88f6),
+        Some(0x88fb),
+        None,
+    ];
+
+    const BASE_0XE440: [Option<u16>; 192] = [
+        Some(0x88f0),
+        Some(0x88ec),
+        Some(0x88eb),
+        Some(0x899d),
+        Some(0x89a1),
+        Some(0x899f),
+        Some(0x899e),
+        Some(0x89e9),
+        Some(0x89eb),
+        Some(0x89e8),
+        Some(0x8aab),
+        Some(0x8a99),
+        Some(0x8a8b),
+        Some(0x8a92),
+        Some(0x8a8f),
+        Some(0x8a96),
+        Some(0x8c3d),
+        Some(0x8c68),
+        Some(0x8c69),
+        Some(0x8cd5),
+        Some(0x8ccf),
+        Some(0x8cd7),
+        Some(0x8d96),
+        Some(0x8e09),
+        Some(0x8e02),
+        Some(0x8dff),
+        Some(0x8e0d),
+        Some(0x8dfd),
+        Some(0x8e0a),
+        Some(0x8e03),
+        Some(0x8e07),
+        Some(0x8e06),
+        Some(0x8e05),
+        Some(0x8dfe),
+        Some(0x8e00),
+        Some(0x8e04),
+        Some(0x8f10),
+        Some(0x8f11),
+        Some(0x8f0e),
+        Some(0x8f0d),
+        Some(0x9123),
+        Some(0x911c),
+        Some(0x9120),
+        Some(0x9122),
+        Some(0x911f),
+        Some(0x911d),
+        Some(0x911a),
+        Some(0x9124),
+        Some(0x9121),
+        Some(0x911b),
+        Some(0x917a),
+        Some(0x9172),
+        Some(0x9179),
+        Some(0x9173),
+        Some(0x92a5),
+        Some(0x92a4),
+        Some(0x9276),
+        Some(0x929b),
+        Some(0x927a),
+        Some(0x92a0),
+        Some(0x9294),
+        Some(0x92aa),
+        Some(0x928d),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x92a6),
+        Some(0x929a),
+        Some(0x92ab),
+        Some(0x9279),
+        Some(0x9297),
+        Some(0x927f),
+        Some(0x92a3),
+        Some(0x92ee),
+        Some(0x928e),
+        Some(0x9282),
+        Some(0x9295),
+        Some(0x92a2),
+        Some(0x927d),
+        Some(0x9288),
+        Some(0x92a1),
+        Some(0x928a),
+        Some(0x9286),
+        Some(0x928c),
+        Some(0x9299),
+        Some(0x92a7),
+        Some(0x927e),
+        Some(0x9287),
+        Some(0x92a9),
+        Some(0x929d),
+        Some(0x928b),
+        Some(0x922d),
+        Some(0x969e),
+        Some(0x96a1),
+        Some(0x96ff),
+        Some(0x9758),
+        Some(0x977d),
+        Some(0x977a),
+        Some(0x977e),
+        Some(0x9783),
+        Some(0x9780),
+        Some(0x9782),
+        Some(0x977b),
+        Some(0x9784),
+        Some(0x9781),
+        Some(0x977f),
+        Some(0x97ce),
+        Some(0x97cd),
+        Some(0x9816),
+        Some(0x98ad),
+        Some(0x98ae),
+        Some(0x9902),
+        Some(0x9900),
+        Some(0x9907),
+        Some(0x999d),
+        Some(0x999c),
+        Some(0x99c3),
+        Some(0x99b9),
+        Some(0x99bb),
+        Some(0x99ba),
+        Some(0x99c2),
+        Some(0x99bd),
+        Some(0x99c7),
+        Some(0x9ab1),
+        Some(0x9ae3),
+        Some(0x9ae7),
+        Some(0x9b3e),
+        Some(0x9b3f),
+        Some(0x9b60),
+        Some(0x9b61),
+        Some(0x9b5f),
+        Some(0x9cf1),
+        Some(0x9cf2),
+        Some(0x9cf5),
+        Some(0x9ea7),
+        Some(0x50ff),
+        Some(0x5103),
+        Some(0x5130),
+        Some(0x50f8),
+        Some(0x5106),
+        Some(0x5107),
+        Some(0x50f6),
+        Some(0x50fe),
+        Some(0x510b),
+        Some(0x510c),
+        Some(0x50fd),
+        Some(0x510a),
+        Some(0x528b),
+        Some(0x528c),
+        Some(0x52f1),
+        Some(0x52ef),
+        Some(0x5648),
+        Some(0x5642),
+        Some(0x564c),
+        Some(0x5635),
+        Some(0x5641),
+        Some(0x564a),
+        Some(0x5649),
+        Some(0x5646),
+        Some(0x5658),
+        None,
+    ];
+
+    const BASE_0XE540: [Option<u16>; 192] = [
+        Some(0x565a),
+        Some(0x5640),
+        Some(0x5633),
+        Some(0x563d),
+        Some(0x562c),
+        Some(0x563e),
+        Some(0x5638),
+        Some(0x562a),
+        Some(0x563a),
+        Some(0x571a),
+        Some(0x58ab),
+        Some(0x589d),
+        Some(0x58b1),
+        Some(0x58a0),
+        Some(0x58a3),
+        Some(0x58af),
+        Some(0x58ac),
+        Some(0x58a5),
+        Some(0x58a1),
+        Some(0x58ff),
+        Some(0x5aff),
+        Some(0x5af4),
+        Some(0x5afd),
+        Some(0x5af7),
+        Some(0x5af6),
+        Some(0x5b03),
+        Some(0x5af8),
+        Some(0x5b02),
+        Some(0x5af9),
+        Some(0x5b01),
+        Some(0x5b07),
+        Some(0x5b05),
+        Some(0x5b0f),
+        Some(0x5c67),
+        Some(0x5d99),
+        Some(0x5d97),
+        Some(0x5d9f),
+        Some(0x5d92),
+        Some(0x5da2),
+        Some(0x5d93),
+        Some(0x5d95),
+        Some(0x5da0),
+        Some(0x5d9c),
+        Some(0x5da1),
+        Some(0x5d9a),
+        Some(0x5d9e),
+        Some(0x5e69),
+        Some(0x5e5d),
+        Some(0x5e60),
+        Some(0x5e5c),
+        Some(0x7df3),
+        Some(0x5edb),
+        Some(0x5ede),
+        Some(0x5ee1),
+        Some(0x5f49),
+        Some(0x5fb2),
+        Some(0x618b),
+        Some(0x6183),
+        Some(0x6179),
+        Some(0x61b1),
+        Some(0x61b0),
+        Some(0x61a2),
+        Some(0x6189),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x619b),
+        Some(0x6193),
+        Some(0x61af),
+        Some(0x61ad),
+        Some(0x619f),
+        Some(0x6192),
+        Some(0x61aa),
+        Some(0x61a1),
+        Some(0x618d),
+        Some(0x6166),
+        Some(0x61b3),
+        Some(0x622d),
+        Some(0x646e),
+        Some(0x6470),
+        Some(0x6496),
+        Some(0x64a0),
+        Some(0x6485),
+        Some(0x6497),
+        Some(0x649c),
+        Some(0x648f),
+        Some(0x648b),
+        Some(0x648a),
+        Some(0x648c),
+        Some(0x64a3),
+        Some(0x649f),
+        Some(0x6468),
+        Some(0x64b1),
+        Some(0x6498),
+        Some(0x6576),
+        Some(0x657a),
+        Some(0x6579),
+        Some(0x657b),
+        Some(0x65b2),
+        Some(0x65b3),
+        Some(0x66b5),
+        Some(0x66b0),
+        Some(0x66a9),
+        Some(0x66b2),
+        Some(0x66b7),
+        Some(0x66aa),
+        Some(0x66af),
+        Some(0x6a00),
+        Some(0x6a06),
+        Some(0x6a17),
+        Some(0x69e5),
+        Some(0x69f8),
+        Some(0x6a15),
+        Some(0x69f1),
+        Some(0x69e4),
+        Some(0x6a20),
+        Some(0x69ff),
+        Some(0x69ec),
+        Some(0x69e2),
+        Some(0x6a1b),
+        Some(0x6a1d),
+        Some(0x69fe),
+        Some(0x6a27),
+        Some(0x69f2),
+        Some(0x69ee),
+        Some(0x6a14),
+        Some(0x69f7),
+        Some(0x69e7),
+        Some(0x6a40),
+        Some(0x6a08),
+        Some(0x69e6),
+        Some(0x69fb),
+        Some(0x6a0d),
+        Some(0x69fc),
+        Some(0x69eb),
+        Some(0x6a09),
+        Some(0x6a04),
+        Some(0x6a18),
+        Some(0x6a25),
+        Some(0x6a0f),
+        Some(0x69f6),
+        Some(0x6a26),
+        Some(0x6a07),
+        Some(0x69f4),
+        Some(0x6a16),
+        Some(0x6b51),
+        Some(0x6ba5),
+        Some(0x6ba3),
+        Some(0x6ba2),
+        Some(0x6ba6),
+        Some(0x6c01),
+        Some(0x6c00),
+        Some(0x6bff),
+        Some(0x6c02),
+        Some(0x6f41),
+        Some(0x6f26),
+        Some(0x6f7e),
+        Some(0x6f87),
+        Some(0x6fc6),
+        Some(0x6f92),
+        None,
+    ];
+
+    const BASE_0XE640: [Option<u16>; 192] = [
+        Some(0x6f8d),
+        Some(0x6f89),
+        Some(0x6f8c),
+        Some(0x6f62),
+        Some(0x6f4f),
+        Some(0x6f85),
+        Some(0x6f5a),
+        Some(0x6f96),
+        Some(0x6f76),
+        Some(0x6f6c),
+        Some(0x6f82),
+        Some(0x6f55),
+        Some(0x6f72),
+        Some(0x6f52),
+        Some(0x6f50),
+        Some(0x6f57),
+        Some(0x6f94),
+        Some(0x6f93),
+        Some(0x6f5d),
+        Some(0x6f00),
+        Some(0x6f61),
+        Some(0x6f6b),
+        Some(0x6f7d),
+        Some(0x6f67),
+        Some(0x6f90),
+        Some(0x6f53),
+        Some(0x6f8b),
+        Some(0x6f69),
+        Some(0x6f7f),
+        Some(0x6f95),
+        Some(0x6f63),
+        Some(0x6f77),
+        Some(0x6f6a),
+        Some(0x6f7b),
+        Some(0x71b2),
+        Some(0x71af),
+        Some(0x719b),
+        Some(0x71b0),
+        Some(0x71a0),
+        Some(0x719a),
+        Some(0x71a9),
+        Some(0x71b5),
+        Some(0x719d),
+        Some(0x71a5),
+        Some(0x719e),
+        Some(0x71a4),
+        Some(0x71a1),
+        Some(0x71aa),
+        Some(0x719c),
+        Some(0x71a7),
+        Some(0x71b3),
+        Some(0x7298),
+        Some(0x729a),
+        Some(0x7358),
+        Some(0x7352),
+        Some(0x735e),
+        Some(0x735f),
+        Some(0x7360),
+        Some(0x735d),
+        Some(0x735b),
+        Some(0x7361),
+        Some(0x735a),
+        Some(0x7359),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7362),
+        Some(0x7487),
+        Some(0x7489),
+        Some(0x748a),
+        Some(0x7486),
+        Some(0x7481),
+        Some(0x747d),
+        Some(0x7485),
+        Some(0x7488),
+        Some(0x747c),
+        Some(0x7479),
+        Some(0x7508),
+        Some(0x7507),
+        Some(0x757e),
+        Some(0x7625),
+        Some(0x761e),
+        Some(0x7619),
+        Some(0x761d),
+        Some(0x761c),
+        Some(0x7623),
+        Some(0x761a),
+        Some(0x7628),
+        Some(0x761b),
+        Some(0x769c),
+        Some(0x769d),
+        Some(0x769e),
+        Some(0x769b),
+        Some(0x778d),
+        Some(0x778f),
+        Some(0x7789),
+        Some(0x7788),
+        Some(0x78cd),
+        Some(0x78bb),
+        Some(0x78cf),
+        Some(0x78cc),
+        Some(0x78d1),
+        Some(0x78ce),
+        Some(0x78d4),
+        Some(0x78c8),
+        Some(0x78c3),
+        Some(0x78c4),
+        Some(0x78c9),
+        Some(0x799a),
+        Some(0x79a1),
+        Some(0x79a0),
+        Some(0x799c),
+        Some(0x79a2),
+        Some(0x799b),
+        Some(0x6b76),
+        Some(0x7a39),
+        Some(0x7ab2),
+        Some(0x7ab4),
+        Some(0x7ab3),
+        Some(0x7bb7),
+        Some(0x7bcb),
+        Some(0x7bbe),
+        Some(0x7bac),
+        Some(0x7bce),
+        Some(0x7baf),
+        Some(0x7bb9),
+        Some(0x7bca),
+        Some(0x7bb5),
+        Some(0x7cc5),
+        Some(0x7cc8),
+        Some(0x7ccc),
+        Some(0x7ccb),
+        Some(0x7df7),
+        Some(0x7ddb),
+        Some(0x7dea),
+        Some(0x7de7),
+        Some(0x7dd7),
+        Some(0x7de1),
+        Some(0x7e03),
+        Some(0x7dfa),
+        Some(0x7de6),
+        Some(0x7df6),
+        Some(0x7df1),
+        Some(0x7df0),
+        Some(0x7dee),
+        Some(0x7ddf),
+        Some(0x7f76),
+        Some(0x7fac),
+        Some(0x7fb0),
+        Some(0x7fad),
+        Some(0x7fed),
+        Some(0x7feb),
+        Some(0x7fea),
+        Some(0x7fec),
+        Some(0x7fe6),
+        Some(0x7fe8),
+        Some(0x8064),
+        Some(0x8067),
+        Some(0x81a3),
+        Some(0x819f),
+        None,
+    ];
+
+    const BASE_0XE740: [Option<u16>; 192] = [
+        Some(0x819e),
+        Some(0x8195),
+        Some(0x81a2),
+        Some(0x8199),
+        Some(0x8197),
+        Some(0x8216),
+        Some(0x824f),
+        Some(0x8253),
+        Some(0x8252),
+        Some(0x8250),
+        Some(0x824e),
+        Some(0x8251),
+        Some(0x8524),
+        Some(0x853b),
+        Some(0x850f),
+        Some(0x8500),
+        Some(0x8529),
+        Some(0x850e),
+        Some(0x8509),
+        Some(0x850d),
+        Some(0x851f),
+        Some(0x850a),
+        Some(0x8527),
+        Some(0x851c),
+        Some(0x84fb),
+        Some(0x852b),
+        Some(0x84fa),
+        Some(0x8508),
+        Some(0x850c),
+        Some(0x84f4),
+        Some(0x852a),
+        Some(0x84f2),
+        Some(0x8515),
+        Some(0x84f7),
+        Some(0x84eb),
+        Some(0x84f3),
+        Some(0x84fc),
+        Some(0x8512),
+        Some(0x84ea),
+        Some(0x84e9),
+        Some(0x8516),
+        Some(0x84fe),
+        Some(0x8528),
+        Some(0x851d),
+        Some(0x852e),
+        Some(0x8502),
+        Some(0x84fd),
+        Some(0x851e),
+        Some(0x84f6),
+        Some(0x8531),
+        Some(0x8526),
+        Some(0x84e7),
+        Some(0x84e8),
+        Some(0x84f0),
+        Some(0x84ef),
+        Some(0x84f9),
+        Some(0x8518),
+        Some(0x8520),
+        Some(0x8530),
+        Some(0x850b),
+        Some(0x8519),
+        Some(0x852f),
+        Some(0x8662),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8756),
+        Some(0x8763),
+        Some(0x8764),
+        Some(0x8777),
+        Some(0x87e1),
+        Some(0x8773),
+        Some(0x8758),
+        Some(0x8754),
+        Some(0x875b),
+        Some(0x8752),
+        Some(0x8761),
+        Some(0x875a),
+        Some(0x8751),
+        Some(0x875e),
+        Some(0x876d),
+        Some(0x876a),
+        Some(0x8750),
+        Some(0x874e),
+        Some(0x875f),
+        Some(0x875d),
+        Some(0x876f),
+        Some(0x876c),
+        Some(0x877a),
+        Some(0x876e),
+        Some(0x875c),
+        Some(0x8765),
+        Some(0x874f),
+        Some(0x877b),
+        Some(0x8775),
+        Some(0x8762),
+        Some(0x8767),
+        Some(0x8769),
+        Some(0x885a),
+        Some(0x8905),
+        Some(0x890c),
+        Some(0x8914),
+        Some(0x890b),
+        Some(0x8917),
+        Some(0x8918),
+        Some(0x8919),
+        Some(0x8906),
+        Some(0x8916),
+        Some(0x8911),
+        Some(0x890e),
+        Some(0x8909),
+        Some(0x89a2),
+        Some(0x89a4),
+        Some(0x89a3),
+        Some(0x89ed),
+        Some(0x89f0),
+        Some(0x89ec),
+        Some(0x8acf),
+        Some(0x8ac6),
+        Some(0x8ab8),
+        Some(0x8ad3),
+        Some(0x8ad1),
+        Some(0x8ad4),
+        Some(0x8ad5),
+        Some(0x8abb),
+        Some(0x8ad7),
+        Some(0x8abe),
+        Some(0x8ac0),
+        Some(0x8ac5),
+        Some(0x8ad8),
+        Some(0x8ac3),
+        Some(0x8aba),
+        Some(0x8abd),
+        Some(0x8ad9),
+        Some(0x8c3e),
+        Some(0x8c4d),
+        Some(0x8c8f),
+        Some(0x8ce5),
+        Some(0x8cdf),
+        Some(0x8cd9),
+        Some(0x8ce8),
+        Some(0x8cda),
+        Some(0x8cdd),
+        Some(0x8ce7),
+        Some(0x8da0),
+        Some(0x8d9c),
+        Some(0x8da1),
+        Some(0x8d9b),
+        Some(0x8e20),
+        Some(0x8e23),
+        Some(0x8e25),
+        Some(0x8e24),
+        Some(0x8e2e),
+        Some(0x8e15),
+        Some(0x8e1b),
+        Some(0x8e16),
+        Some(0x8e11),
+        Some(0x8e19),
+        Some(0x8e26),
+        Some(0x8e27),
+        None,
+    ];
+
+    const BASE_0XE840: [Option<u16>; 192] = [
+        Some(0x8e14),
+        Some(0x8e12),
+        Some(0x8e18),
+        Some(0x8e13),
+        Some(0x8e1c),
+        Some(0x8e17),
+        Some(0x8e1a),
+        Some(0x8f2c),
+        Some(0x8f24),
+        Some(0x8f18),
+        Some(0x8f1a),
+        Some(0x8f20),
+        Some(0x8f23),
+        Some(0x8f16),
+        Some(0x8f17),
+        Some(0x9073),
+        Some(0x9070),
+        Some(0x906f),
+        Some(0x9067),
+        Some(0x906b),
+        Some(0x912f),
+        Some(0x912b),
+        Some(0x9129),
+        Some(0x912a),
+        Some(0x9132),
+        Some(0x9126),
+        Some(0x912e),
+        Some(0x9185),
+        Some(0x9186),
+        Some(0x918a),
+        Some(0x9181),
+        Some(0x9182),
+        Some(0x9184),
+        Some(0x9180),
+        Some(0x92d0),
+        Some(0x92c3),
+        Some(0x92c4),
+        Some(0x92c0),
+        Some(0x92d9),
+        Some(0x92b6),
+        Some(0x92cf),
+        Some(0x92f1),
+        Some(0x92df),
+        Some(0x92d8),
+        Some(0x92e9),
+        Some(0x92d7),
+        Some(0x92dd),
+        Some(0x92cc),
+        Some(0x92ef),
+        Some(0x92c2),
+        Some(0x92e8),
+        Some(0x92ca),
+        Some(0x92c8),
+        Some(0x92ce),
+        Some(0x92e6),
+        Some(0x92cd),
+        Some(0x92d5),
+        Some(0x92c9),
+        Some(0x92e0),
+        Some(0x92de),
+        Some(0x92e7),
+        Some(0x92d1),
+        Some(0x92d3),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x92b5),
+        Some(0x92e1),
+        Some(0x92c6),
+        Some(0x92b4),
+        Some(0x957c),
+        Some(0x95ac),
+        Some(0x95ab),
+        Some(0x95ae),
+        Some(0x95b0),
+        Some(0x96a4),
+        Some(0x96a2),
+        Some(0x96d3),
+        Some(0x9705),
+        Some(0x9708),
+        Some(0x9702),
+        Some(0x975a),
+        Some(0x978a),
+        Some(0x978e),
+        Some(0x9788),
+        Some(0x97d0),
+        Some(0x97cf),
+        Some(0x981e),
+        Some(0x981d),
+        Some(0x9826),
+        Some(0x9829),
+        Some(0x9828),
+        Some(0x9820),
+        Some(0x981b),
+        Some(0x9827),
+        Some(0x98b2),
+        Some(0x9908),
+        Some(0x98fa),
+        Some(0x9911),
+        Some(0x9914),
+        Some(0x9916),
+        Some(0x9917),
+        Some(0x9915),
+        Some(0x99dc),
+        Some(0x99cd),
+        Some(0x99cf),
+        Some(0x99d3),
+        Some(0x99d4),
+        Some(0x99ce),
+        Some(0x99c9),
+        Some(0x99d6),
+        Some(0x99d8),
+        Some(0x99cb),
+        Some(0x99d7),
+        Some(0x99cc),
+        Some(0x9ab3),
+        Some(0x9aec),
+        Some(0x9aeb),
+        Some(0x9af3),
+        Some(0x9af2),
+        Some(0x9af1),
+        Some(0x9b46),
+        Some(0x9b43),
+        Some(0x9b67),
+        Some(0x9b74),
+        Some(0x9b71),
+        Some(0x9b66),
+        Some(0x9b76),
+        Some(0x9b75),
+        Some(0x9b70),
+        Some(0x9b68),
+        Some(0x9b64),
+        Some(0x9b6c),
+        Some(0x9cfc),
+        Some(0x9cfa),
+        Some(0x9cfd),
+        Some(0x9cff),
+        Some(0x9cf7),
+        Some(0x9d07),
+        Some(0x9d00),
+        Some(0x9cf9),
+        Some(0x9cfb),
+        Some(0x9d08),
+        Some(0x9d05),
+        Some(0x9d04),
+        Some(0x9e83),
+        Some(0x9ed3),
+        Some(0x9f0f),
+        Some(0x9f10),
+        Some(0x511c),
+        Some(0x5113),
+        Some(0x5117),
+        Some(0x511a),
+        Some(0x5111),
+        Some(0x51de),
+        Some(0x5334),
+        Some(0x53e1),
+        Some(0x5670),
+        Some(0x5660),
+        Some(0x566e),
+        None,
+    ];
+
+    const BASE_0XE940: [Option<u16>; 192] = [
+        Some(0x5673),
+        Some(0x5666),
+        Some(0x5663),
+        Some(0x566d),
+        Some(0x5672),
+        Some(0x565e),
+        Some(0x5677),
+        Some(0x571c),
+        Some(0x571b),
+        Some(0x58c8),
+        Some(0x58bd),
+        Some(0x58c9),
+        Some(0x58bf),
+        Some(0x58ba),
+        Some(0x58c2),
+        Some(0x58bc),
+        Some(0x58c6),
+        Some(0x5b17),
+        Some(0x5b19),
+        Some(0x5b1b),
+        Some(0x5b21),
+        Some(0x5b14),
+        Some(0x5b13),
+        Some(0x5b10),
+        Some(0x5b16),
+        Some(0x5b28),
+        Some(0x5b1a),
+        Some(0x5b20),
+        Some(0x5b1e),
+        Some(0x5bef),
+        Some(0x5dac),
+        Some(0x5db1),
+        Some(0x5da9),
+        Some(0x5da7),
+        Some(0x5db5),
+        Some(0x5db0),
+        Some(0x5dae),
+        Some(0x5daa),
+        Some(0x5da8),
+        Some(0x5db2),
+        Some(0x5dad),
+        Some(0x5daf),
+        Some(0x5db4),
+        Some(0x5e67),
+        Some(0x5e68),
+        Some(0x5e66),
+        Some(0x5e6f),
+        Some(0x5ee9),
+        Some(0x5ee7),
+        Some(0x5ee6),
+        Some(0x5ee8),
+        Some(0x5ee5),
+        Some(0x5f4b),
+        Some(0x5fbc),
+        Some(0x619d),
+        Some(0x61a8),
+        Some(0x6196),
+        Some(0x61c5),
+        Some(0x61b4),
+        Some(0x61c6),
+        Some(0x61c1),
+        Some(0x61cc),
+        Some(0x61ba),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x61bf),
+        Some(0x61b8),
+        Some(0x618c),
+        Some(0x64d7),
+        Some(0x64d6),
+        Some(0x64d0),
+        Some(0x64cf),
+        Some(0x64c9),
+        Some(0x64bd),
+        Some(0x6489),
+        Some(0x64c3),
+        Some(0x64db),
+        Some(0x64f3),
+        Some(0x64d9),
+        Some(0x6533),
+        Some(0x657f),
+        Some(0x657c),
+        Some(0x65a2),
+        Some(0x66c8),
+        Some(0x66be),
+        Some(0x66c0),
+        Some(0x66ca),
+        Some(0x66cb),
+        Some(0x66cf),
+        Some(0x66bd),
+        Some(0x66bb),
+        Some(0x66ba),
+        Some(0x66cc),
+        Some(0x6723),
+        Some(0x6a34),
+        Some(0x6a66),
+        Some(0x6a49),
+        Some(0x6a67),
+        Some(0x6a32),
+        Some(0x6a68),
+        Some(0x6a3e),
+        Some(0x6a5d),
+        Some(0x6a6d),
+        Some(0x6a76),
+        Some(0x6a5b),
+        Some(0x6a51),
+        Some(0x6a28),
+        Some(0x6a5a),
+        Some(0x6a3b),
+        Some(0x6a3f),
+        Some(0x6a41),
+        Some(0x6a6a),
+        Some(0x6a64),
+        Some(0x6a50),
+        Some(0x6a4f),
+        Some(0x6a54),
+        Some(0x6a6f),
+        Some(0x6a69),
+        Some(0x6a60),
+        Some(0x6a3c),
+        Some(0x6a5e),
+        Some(0x6a56),
+        Some(0x6a55),
+        Some(0x6a4d),
+        Some(0x6a4e),
+        Some(0x6a46),
+        Some(0x6b55),
+        Some(0x6b54),
+        Some(0x6b56),
+        Some(0x6ba7),
+        Some(0x6baa),
+        Some(0x6bab),
+        Some(0x6bc8),
+        Some(0x6bc7),
+        Some(0x6c04),
+        Some(0x6c03),
+        Some(0x6c06),
+        Some(0x6fad),
+        Some(0x6fcb),
+        Some(0x6fa3),
+        Some(0x6fc7),
+        Some(0x6fbc),
+        Some(0x6fce),
+        Some(0x6fc8),
+        Some(0x6f5e),
+        Some(0x6fc4),
+        Some(0x6fbd),
+        Some(0x6f9e),
+        Some(0x6fca),
+        Some(0x6fa8),
+        Some(0x7004),
+        Some(0x6fa5),
+        Some(0x6fae),
+        Some(0x6fba),
+        Some(0x6fac),
+        Some(0x6faa),
+        Some(0x6fcf),
+        Some(0x6fbf),
+        Some(0x6fb8),
+        None,
+    ];
+
+    const BASE_0XEA40: [Option<u16>; 192] = [
+        Some(0x6fa2),
+        Some(0x6fc9),
+        Some(0x6fab),
+        Some(0x6fcd),
+        Some(0x6faf),
+        Some(0x6fb2),
+        Some(0x6fb0),
+        Some(0x71c5),
+        Some(0x71c2),
+        Some(0x71bf),
+        Some(0x71b8),
+        Some(0x71d6),
+        Some(0x71c0),
+        Some(0x71c1),
+        Some(0x71cb),
+        Some(0x71d4),
+        Some(0x71ca),
+        Some(0x71c7),
+        Some(0x71cf),
+        Some(0x71bd),
+        Some(0x71d8),
+        Some(0x71bc),
+        Some(0x71c6),
+        Some(0x71da),
+        Some(0x71db),
+        Some(0x729d),
+        Some(0x729e),
+        Some(0x7369),
+        Some(0x7366),
+        Some(0x7367),
+        Some(0x736c),
+        Some(0x7365),
+        Some(0x736b),
+        Some(0x736a),
+        Some(0x747f),
+        Some(0x749a),
+        Some(0x74a0),
+        Some(0x7494),
+        Some(0x7492),
+        Some(0x7495),
+        Some(0x74a1),
+        Some(0x750b),
+        Some(0x7580),
+        Some(0x762f),
+        Some(0x762d),
+        Some(0x7631),
+        Some(0x763d),
+        Some(0x7633),
+        Some(0x763c),
+        Some(0x7635),
+        Some(0x7632),
+        Some(0x7630),
+        Some(0x76bb),
+        Some(0x76e6),
+        Some(0x779a),
+        Some(0x779d),
+        Some(0x77a1),
+        Some(0x779c),
+        Some(0x779b),
+        Some(0x77a2),
+        Some(0x77a3),
+        Some(0x7795),
+        Some(0x7799),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7797),
+        Some(0x78dd),
+        Some(0x78e9),
+        Some(0x78e5),
+        Some(0x78ea),
+        Some(0x78de),
+        Some(0x78e3),
+        Some(0x78db),
+        Some(0x78e1),
+        Some(0x78e2),
+        Some(0x78ed),
+        Some(0x78df),
+        Some(0x78e0),
+        Some(0x79a4),
+        Some(0x7a44),
+        Some(0x7a48),
+        Some(0x7a47),
+        Some(0x7ab6),
+        Some(0x7ab8),
+        Some(0x7ab5),
+        Some(0x7ab1),
+        Some(0x7ab7),
+        Some(0x7bde),
+        Some(0x7be3),
+        Some(0x7be7),
+        Some(0x7bdd),
+        Some(0x7bd5),
+        Some(0x7be5),
+        Some(0x7bda),
+        Some(0x7be8),
+        Some(0x7bf9),
+        Some(0x7bd4),
+        Some(0x7bea),
+        Some(0x7be2),
+        Some(0x7bdc),
+        Some(0x7beb),
+        Some(0x7bd8),
+        Some(0x7bdf),
+        Some(0x7cd2),
+        Some(0x7cd4),
+        Some(0x7cd7),
+        Some(0x7cd0),
+        Some(0x7cd1),
+        Some(0x7e12),
+        Some(0x7e21),
+        Some(0x7e17),
+        Some(0x7e0c),
+        Some(0x7e1f),
+        Some(0x7e20),
+        Some(0x7e13),
+        Some(0x7e0e),
+        Some(0x7e1c),
+        Some(0x7e15),
+        Some(0x7e1a),
+        Some(0x7e22),
+        Some(0x7e0b),
+        Some(0x7e0f),
+        Some(0x7e16),
+        Some(0x7e0d),
+        Some(0x7e14),
+        Some(0x7e25),
+        Some(0x7e24),
+        Some(0x7f43),
+        Some(0x7f7b),
+        Some(0x7f7c),
+        Some(0x7f7a),
+        Some(0x7fb1),
+        Some(0x7fef),
+        Some(0x802a),
+        Some(0x8029),
+        Some(0x806c),
+        Some(0x81b1),
+        Some(0x81a6),
+        Some(0x81ae),
+        Some(0x81b9),
+        Some(0x81b5),
+        Some(0x81ab),
+        Some(0x81b0),
+        Some(0x81ac),
+        Some(0x81b4),
+        Some(0x81b2),
+        Some(0x81b7),
+        Some(0x81a7),
+        Some(0x81f2),
+        Some(0x8255),
+        Some(0x8256),
+        Some(0x8257),
+        Some(0x8556),
+        Some(0x8545),
+        Some(0x856b),
+        Some(0x854d),
+        Some(0x8553),
+        Some(0x8561),
+        Some(0x8558),
+        None,
+    ];
+
+    const BASE_0XEB40: [Option<u16>; 192] = [
+        Some(0x8540),
+        Some(0x8546),
+        Some(0x8564),
+        Some(0x8541),
+        Some(0x8562),
+        Some(0x8544),
+        Some(0x8551),
+        Some(0x8547),
+        Some(0x8563),
+        Some(0x853e),
+        Some(0x855b),
+        Some(0x8571),
+        Some(0x854e),
+        Some(0x856e),
+        Some(0x8575),
+        Some(0x8555),
+        Some(0x8567),
+        Some(0x8560),
+        Some(0x858c),
+        Some(0x8566),
+        Some(0x855d),
+        Some(0x8554),
+        Some(0x8565),
+        Some(0x856c),
+        Some(0x8663),
+        Some(0x8665),
+        Some(0x8664),
+        Some(0x879b),
+        Some(0x878f),
+        Some(0x8797),
+        Some(0x8793),
+        Some(0x8792),
+        Some(0x8788),
+        Some(0x8781),
+        Some(0x8796),
+        Some(0x8798),
+        Some(0x8779),
+        Some(0x8787),
+        Some(0x87a3),
+        Some(0x8785),
+        Some(0x8790),
+        Some(0x8791),
+        Some(0x879d),
+        Some(0x8784),
+        Some(0x8794),
+        Some(0x879c),
+        Some(0x879a),
+        Some(0x8789),
+        Some(0x891e),
+        Some(0x8926),
+        Some(0x8930),
+        Some(0x892d),
+        Some(0x892e),
+        Some(0x8927),
+        Some(0x8931),
+        Some(0x8922),
+        Some(0x8929),
+        Some(0x8923),
+        Some(0x892f),
+        Some(0x892c),
+        Some(0x891f),
+        Some(0x89f1),
+        Some(0x8ae0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8ae2),
+        Some(0x8af2),
+        Some(0x8af4),
+        Some(0x8af5),
+        Some(0x8add),
+        Some(0x8b14),
+        Some(0x8ae4),
+        Some(0x8adf),
+        Some(0x8af0),
+        Some(0x8ac8),
+        Some(0x8ade),
+        Some(0x8ae1),
+        Some(0x8ae8),
+        Some(0x8aff),
+        Some(0x8aef),
+        Some(0x8afb),
+        Some(0x8c91),
+        Some(0x8c92),
+        Some(0x8c90),
+        Some(0x8cf5),
+        Some(0x8cee),
+        Some(0x8cf1),
+        Some(0x8cf0),
+        Some(0x8cf3),
+        Some(0x8d6c),
+        Some(0x8d6e),
+        Some(0x8da5),
+        Some(0x8da7),
+        Some(0x8e33),
+        Some(0x8e3e),
+        Some(0x8e38),
+        Some(0x8e40),
+        Some(0x8e45),
+        Some(0x8e36),
+        Some(0x8e3c),
+        Some(0x8e3d),
+        Some(0x8e41),
+        Some(0x8e30),
+        Some(0x8e3f),
+        Some(0x8ebd),
+        Some(0x8f36),
+        Some(0x8f2e),
+        Some(0x8f35),
+        Some(0x8f32),
+        Some(0x8f39),
+        Some(0x8f37),
+        Some(0x8f34),
+        Some(0x9076),
+        Some(0x9079),
+        Some(0x907b),
+        Some(0x9086),
+        Some(0x90fa),
+        Some(0x9133),
+        Some(0x9135),
+        Some(0x9136),
+        Some(0x9193),
+        Some(0x9190),
+        Some(0x9191),
+        Some(0x918d),
+        Some(0x918f),
+        Some(0x9327),
+        Some(0x931e),
+        Some(0x9308),
+        Some(0x931f),
+        Some(0x9306),
+        Some(0x930f),
+        Some(0x937a),
+        Some(0x9338),
+        Some(0x933c),
+        Some(0x931b),
+        Some(0x9323),
+        Some(0x9312),
+        Some(0x9301),
+        Some(0x9346),
+        Some(0x932d),
+        Some(0x930e),
+        Some(0x930d),
+        Some(0x92cb),
+        Some(0x931d),
+        Some(0x92fa),
+        Some(0x9325),
+        Some(0x9313),
+        Some(0x92f9),
+        Some(0x92f7),
+        Some(0x9334),
+        Some(0x9302),
+        Some(0x9324),
+        Some(0x92ff),
+        Some(0x9329),
+        Some(0x9339),
+        Some(0x9335),
+        Some(0x932a),
+        Some(0x9314),
+        Some(0x930c),
+        None,
+    ];
+
+    const BASE_0XEC40: [Option<u16>; 192] = [
+        Some(0x930b),
+        Some(0x92fe),
+        Some(0x9309),
+        Some(0x9300),
+        Some(0x92fb),
+        Some(0x9316),
+        Some(0x95bc),
+        Some(0x95cd),
+        Some(0x95be),
+        Some(0x95b9),
+        Some(0x95ba),
+        Some(0x95b6),
+        Some(0x95bf),
+        Some(0x95b5),
+        Some(0x95bd),
+        Some(0x96a9),
+        Some(0x96d4),
+        Some(0x970b),
+        Some(0x9712),
+        Some(0x9710),
+        Some(0x9799),
+        Some(0x9797),
+        Some(0x9794),
+        Some(0x97f0),
+        Some(0x97f8),
+        Some(0x9835),
+        Some(0x982f),
+        Some(0x9832),
+        Some(0x9924),
+        Some(0x991f),
+        Some(0x9927),
+        Some(0x9929),
+        Some(0x999e),
+        Some(0x99ee),
+        Some(0x99ec),
+        Some(0x99e5),
+        Some(0x99e4),
+        Some(0x99f0),
+        Some(0x99e3),
+        Some(0x99ea),
+        Some(0x99e9),
+        Some(0x99e7),
+        Some(0x9ab9),
+        Some(0x9abf),
+        Some(0x9ab4),
+        Some(0x9abb),
+        Some(0x9af6),
+        Some(0x9afa),
+        Some(0x9af9),
+        Some(0x9af7),
+        Some(0x9b33),
+        Some(0x9b80),
+        Some(0x9b85),
+        Some(0x9b87),
+        Some(0x9b7c),
+        Some(0x9b7e),
+        Some(0x9b7b),
+        Some(0x9b82),
+        Some(0x9b93),
+        Some(0x9b92),
+        Some(0x9b90),
+        Some(0x9b7a),
+        Some(0x9b95),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9b7d),
+        Some(0x9b88),
+        Some(0x9d25),
+        Some(0x9d17),
+        Some(0x9d20),
+        Some(0x9d1e),
+        Some(0x9d14),
+        Some(0x9d29),
+        Some(0x9d1d),
+        Some(0x9d18),
+        Some(0x9d22),
+        Some(0x9d10),
+        Some(0x9d19),
+        Some(0x9d1f),
+        Some(0x9e88),
+        Some(0x9e86),
+        Some(0x9e87),
+        Some(0x9eae),
+        Some(0x9ead),
+        Some(0x9ed5),
+        Some(0x9ed6),
+        Some(0x9efa),
+        Some(0x9f12),
+        Some(0x9f3d),
+        Some(0x5126),
+        Some(0x5125),
+        Some(0x5122),
+        Some(0x5124),
+        Some(0x5120),
+        Some(0x5129),
+        Some(0x52f4),
+        Some(0x5693),
+        Some(0x568c),
+        Some(0x568d),
+        Some(0x5686),
+        Some(0x5684),
+        Some(0x5683),
+        Some(0x567e),
+        Some(0x5682),
+        Some(0x567f),
+        Some(0x5681),
+        Some(0x58d6),
+        Some(0x58d4),
+        Some(0x58cf),
+        Some(0x58d2),
+        Some(0x5b2d),
+        Some(0x5b25),
+        Some(0x5b32),
+        Some(0x5b23),
+        Some(0x5b2c),
+        Some(0x5b27),
+        Some(0x5b26),
+        Some(0x5b2f),
+        Some(0x5b2e),
+        Some(0x5b7b),
+        Some(0x5bf1),
+        Some(0x5bf2),
+        Some(0x5db7),
+        Some(0x5e6c),
+        Some(0x5e6a),
+        Some(0x5fbe),
+        Some(0x5fbb),
+        Some(0x61c3),
+        Some(0x61b5),
+        Some(0x61bc),
+        Some(0x61e7),
+        Some(0x61e0),
+        Some(0x61e5),
+        Some(0x61e4),
+        Some(0x61e8),
+        Some(0x61de),
+        Some(0x64ef),
+        Some(0x64e9),
+        Some(0x64e3),
+        Some(0x64eb),
+        Some(0x64e4),
+        Some(0x64e8),
+        Some(0x6581),
+        Some(0x6580),
+        Some(0x65b6),
+        Some(0x65da),
+        Some(0x66d2),
+        Some(0x6a8d),
+        Some(0x6a96),
+        Some(0x6a81),
+        Some(0x6aa5),
+        Some(0x6a89),
+        Some(0x6a9f),
+        Some(0x6a9b),
+        Some(0x6aa1),
+        Some(0x6a9e),
+        Some(0x6a87),
+        Some(0x6a93),
+        Some(0x6a8e),
+        None,
+    ];
+
+    const BASE_0XED40: [Option<u16>; 192] = [
+        Some(0x6a95),
+        Some(0x6a83),
+        Some(0x6aa8),
+        Some(0x6aa4),
+        Some(0x6a91),
+        Some(0x6a7f),
+        Some(0x6aa6),
+        Some(0x6a9a),
+        Some(0x6a85),
+        Some(0x6a8c),
+        Some(0x6a92),
+        Some(0x6b5b),
+        Some(0x6bad),
+        Some(0x6c09),
+        Some(0x6fcc),
+        Some(0x6fa9),
+        Some(0x6ff4),
+        Some(0x6fd4),
+        Some(0x6fe3),
+        Some(0x6fdc),
+        Some(0x6fed),
+        Some(0x6fe7),
+        Some(0x6fe6),
+        Some(0x6fde),
+        Some(0x6ff2),
+        Some(0x6fdd),
+        Some(0x6fe2),
+        Some(0x6fe8),
+        Some(0x71e1),
+        Some(0x71f1),
+        Some(0x71e8),
+        Some(0x71f2),
+        Some(0x71e4),
+        Some(0x71f0),
+        Some(0x71e2),
+        Some(0x7373),
+        Some(0x736e),
+        Some(0x736f),
+        Some(0x7497),
+        Some(0x74b2),
+        Some(0x74ab),
+        Some(0x7490),
+        Some(0x74aa),
+        Some(0x74ad),
+        Some(0x74b1),
+        Some(0x74a5),
+        Some(0x74af),
+        Some(0x7510),
+        Some(0x7511),
+        Some(0x7512),
+        Some(0x750f),
+        Some(0x7584),
+        Some(0x7643),
+        Some(0x7648),
+        Some(0x7649),
+        Some(0x7647),
+        Some(0x76a4),
+        Some(0x76e9),
+        Some(0x77b5),
+        Some(0x77ab),
+        Some(0x77b2),
+        Some(0x77b7),
+        Some(0x77b6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x77b4),
+        Some(0x77b1),
+        Some(0x77a8),
+        Some(0x77f0),
+        Some(0x78f3),
+        Some(0x78fd),
+        Some(0x7902),
+        Some(0x78fb),
+        Some(0x78fc),
+        Some(0x78f2),
+        Some(0x7905),
+        Some(0x78f9),
+        Some(0x78fe),
+        Some(0x7904),
+        Some(0x79ab),
+        Some(0x79a8),
+        Some(0x7a5c),
+        Some(0x7a5b),
+        Some(0x7a56),
+        Some(0x7a58),
+        Some(0x7a54),
+        Some(0x7a5a),
+        Some(0x7abe),
+        Some(0x7ac0),
+        Some(0x7ac1),
+        Some(0x7c05),
+        Some(0x7c0f),
+        Some(0x7bf2),
+        Some(0x7c00),
+        Some(0x7bff),
+        Some(0x7bfb),
+        Some(0x7c0e),
+        Some(0x7bf4),
+        Some(0x7c0b),
+        Some(0x7bf3),
+        Some(0x7c02),
+        Some(0x7c09),
+        Some(0x7c03),
+        Some(0x7c01),
+        Some(0x7bf8),
+        Some(0x7bfd),
+        Some(0x7c06),
+        Some(0x7bf0),
+        Some(0x7bf1),
+        Some(0x7c10),
+        Some(0x7c0a),
+        Some(0x7ce8),
+        Some(0x7e2d),
+        Some(0x7e3c),
+        Some(0x7e42),
+        Some(0x7e33),
+        Some(0x9848),
+        Some(0x7e38),
+        Some(0x7e2a),
+        Some(0x7e49),
+        Some(0x7e40),
+        Some(0x7e47),
+        Some(0x7e29),
+        Some(0x7e4c),
+        Some(0x7e30),
+        Some(0x7e3b),
+        Some(0x7e36),
+        Some(0x7e44),
+        Some(0x7e3a),
+        Some(0x7f45),
+        Some(0x7f7f),
+        Some(0x7f7e),
+        Some(0x7f7d),
+        Some(0x7ff4),
+        Some(0x7ff2),
+        Some(0x802c),
+        Some(0x81bb),
+        Some(0x81c4),
+        Some(0x81cc),
+        Some(0x81ca),
+        Some(0x81c5),
+        Some(0x81c7),
+        Some(0x81bc),
+        Some(0x81e9),
+        Some(0x825b),
+        Some(0x825a),
+        Some(0x825c),
+        Some(0x8583),
+        Some(0x8580),
+        Some(0x858f),
+        Some(0x85a7),
+        Some(0x8595),
+        Some(0x85a0),
+        Some(0x858b),
+        Some(0x85a3),
+        Some(0x857b),
+        Some(0x85a4),
+        Some(0x859a),
+        Some(0x859e),
+        None,
+    ];
+
+    const BASE_0XEE40: [Option<u16>; 192] = [
+        Some(0x8577),
+        Some(0x857c),
+        Some(0x8589),
+        Some(0x85a1),
+        Some(0x857a),
+        Some(0x8578),
+        Some(0x8557),
+        Some(0x858e),
+        Some(0x8596),
+        Some(0x8586),
+        Some(0x858d),
+        Some(0x8599),
+        Some(0x859d),
+        Some(0x8581),
+        Some(0x85a2),
+        Some(0x8582),
+        Some(0x8588),
+        Some(0x8585),
+        Some(0x8579),
+        Some(0x8576),
+        Some(0x8598),
+        Some(0x8590),
+        Some(0x859f),
+        Some(0x8668),
+        Some(0x87be),
+        Some(0x87aa),
+        Some(0x87ad),
+        Some(0x87c5),
+        Some(0x87b0),
+        Some(0x87ac),
+        Some(0x87b9),
+        Some(0x87b5),
+        Some(0x87bc),
+        Some(0x87ae),
+        Some(0x87c9),
+        Some(0x87c3),
+        Some(0x87c2),
+        Some(0x87cc),
+        Some(0x87b7),
+        Some(0x87af),
+        Some(0x87c4),
+        Some(0x87ca),
+        Some(0x87b4),
+        Some(0x87b6),
+        Some(0x87bf),
+        Some(0x87b8),
+        Some(0x87bd),
+        Some(0x87de),
+        Some(0x87b2),
+        Some(0x8935),
+        Some(0x8933),
+        Some(0x893c),
+        Some(0x893e),
+        Some(0x8941),
+        Some(0x8952),
+        Some(0x8937),
+        Some(0x8942),
+        Some(0x89ad),
+        Some(0x89af),
+        Some(0x89ae),
+        Some(0x89f2),
+        Some(0x89f3),
+        Some(0x8b1e),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x8b18),
+        Some(0x8b16),
+        Some(0x8b11),
+        Some(0x8b05),
+        Some(0x8b0b),
+        Some(0x8b22),
+        Some(0x8b0f),
+        Some(0x8b12),
+        Some(0x8b15),
+        Some(0x8b07),
+        Some(0x8b0d),
+        Some(0x8b08),
+        Some(0x8b06),
+        Some(0x8b1c),
+        Some(0x8b13),
+        Some(0x8b1a),
+        Some(0x8c4f),
+        Some(0x8c70),
+        Some(0x8c72),
+        Some(0x8c71),
+        Some(0x8c6f),
+        Some(0x8c95),
+        Some(0x8c94),
+        Some(0x8cf9),
+        Some(0x8d6f),
+        Some(0x8e4e),
+        Some(0x8e4d),
+        Some(0x8e53),
+        Some(0x8e50),
+        Some(0x8e4c),
+        Some(0x8e47),
+        Some(0x8f43),
+        Some(0x8f40),
+        Some(0x9085),
+        Some(0x907e),
+        Some(0x9138),
+        Some(0x919a),
+        Some(0x91a2),
+        Some(0x919b),
+        Some(0x9199),
+        Some(0x919f),
+        Some(0x91a1),
+        Some(0x919d),
+        Some(0x91a0),
+        Some(0x93a1),
+        Some(0x9383),
+        Some(0x93af),
+        Some(0x9364),
+        Some(0x9356),
+        Some(0x9347),
+        Some(0x937c),
+        Some(0x9358),
+        Some(0x935c),
+        Some(0x9376),
+        Some(0x9349),
+        Some(0x9350),
+        Some(0x9351),
+        Some(0x9360),
+        Some(0x936d),
+        Some(0x938f),
+        Some(0x934c),
+        Some(0x936a),
+        Some(0x9379),
+        Some(0x9357),
+        Some(0x9355),
+        Some(0x9352),
+        Some(0x934f),
+        Some(0x9371),
+        Some(0x9377),
+        Some(0x937b),
+        Some(0x9361),
+        Some(0x935e),
+        Some(0x9363),
+        Some(0x9367),
+        Some(0x9380),
+        Some(0x934e),
+        Some(0x9359),
+        Some(0x95c7),
+        Some(0x95c0),
+        Some(0x95c9),
+        Some(0x95c3),
+        Some(0x95c5),
+        Some(0x95b7),
+        Some(0x96ae),
+        Some(0x96b0),
+        Some(0x96ac),
+        Some(0x9720),
+        Some(0x971f),
+        Some(0x9718),
+        Some(0x971d),
+        Some(0x9719),
+        Some(0x979a),
+        Some(0x97a1),
+        Some(0x979c),
+        None,
+    ];
+
+    const BASE_0XEF40: [Option<u16>; 192] = [
+        Some(0x979e),
+        Some(0x979d),
+        Some(0x97d5),
+        Some(0x97d4),
+        Some(0x97f1),
+        Some(0x9841),
+        Some(0x9844),
+        Some(0x984a),
+        Some(0x9849),
+        Some(0x9845),
+        Some(0x9843),
+        Some(0x9925),
+        Some(0x992b),
+        Some(0x992c),
+        Some(0x992a),
+        Some(0x9933),
+        Some(0x9932),
+        Some(0x992f),
+        Some(0x992d),
+        Some(0x9931),
+        Some(0x9930),
+        Some(0x9998),
+        Some(0x99a3),
+        Some(0x99a1),
+        Some(0x9a02),
+        Some(0x99fa),
+        Some(0x99f4),
+        Some(0x99f7),
+        Some(0x99f9),
+        Some(0x99f8),
+        Some(0x99f6),
+        Some(0x99fb),
+        Some(0x99fd),
+        Some(0x99fe),
+        Some(0x99fc),
+        Some(0x9a03),
+        Some(0x9abe),
+        Some(0x9afe),
+        Some(0x9afd),
+        Some(0x9b01),
+        Some(0x9afc),
+        Some(0x9b48),
+        Some(0x9b9a),
+        Some(0x9ba8),
+        Some(0x9b9e),
+        Some(0x9b9b),
+        Some(0x9ba6),
+        Some(0x9ba1),
+        Some(0x9ba5),
+        Some(0x9ba4),
+        Some(0x9b86),
+        Some(0x9ba2),
+        Some(0x9ba0),
+        Some(0x9baf),
+        Some(0x9d33),
+        Some(0x9d41),
+        Some(0x9d67),
+        Some(0x9d36),
+        Some(0x9d2e),
+        Some(0x9d2f),
+        Some(0x9d31),
+        Some(0x9d38),
+        Some(0x9d30),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9d45),
+        Some(0x9d42),
+        Some(0x9d43),
+        Some(0x9d3e),
+        Some(0x9d37),
+        Some(0x9d40),
+        Some(0x9d3d),
+        Some(0x7ff5),
+        Some(0x9d2d),
+        Some(0x9e8a),
+        Some(0x9e89),
+        Some(0x9e8d),
+        Some(0x9eb0),
+        Some(0x9ec8),
+        Some(0x9eda),
+        Some(0x9efb),
+        Some(0x9eff),
+        Some(0x9f24),
+        Some(0x9f23),
+        Some(0x9f22),
+        Some(0x9f54),
+        Some(0x9fa0),
+        Some(0x5131),
+        Some(0x512d),
+        Some(0x512e),
+        Some(0x5698),
+        Some(0x569c),
+        Some(0x5697),
+        Some(0x569a),
+        Some(0x569d),
+        Some(0x5699),
+        Some(0x5970),
+        Some(0x5b3c),
+        Some(0x5c69),
+        Some(0x5c6a),
+        Some(0x5dc0),
+        Some(0x5e6d),
+        Some(0x5e6e),
+        Some(0x61d8),
+        Some(0x61df),
+        Some(0x61ed),
+        Some(0x61ee),
+        Some(0x61f1),
+        Some(0x61ea),
+        Some(0x61f0),
+        Some(0x61eb),
+        Some(0x61d6),
+        Some(0x61e9),
+        Some(0x64ff),
+        Some(0x6504),
+        Some(0x64fd),
+        Some(0x64f8),
+        Some(0x6501),
+        Some(0x6503),
+        Some(0x64fc),
+        Some(0x6594),
+        Some(0x65db),
+        Some(0x66da),
+        Some(0x66db),
+        Some(0x66d8),
+        Some(0x6ac5),
+        Some(0x6ab9),
+        Some(0x6abd),
+        Some(0x6ae1),
+        Some(0x6ac6),
+        Some(0x6aba),
+        Some(0x6ab6),
+        Some(0x6ab7),
+        Some(0x6ac7),
+        Some(0x6ab4),
+        Some(0x6aad),
+        Some(0x6b5e),
+        Some(0x6bc9),
+        Some(0x6c0b),
+        Some(0x7007),
+        Some(0x700c),
+        Some(0x700d),
+        Some(0x7001),
+        Some(0x7005),
+        Some(0x7014),
+        Some(0x700e),
+        Some(0x6fff),
+        Some(0x7000),
+        Some(0x6ffb),
+        Some(0x7026),
+        Some(0x6ffc),
+        Some(0x6ff7),
+        Some(0x700a),
+        Some(0x7201),
+        Some(0x71ff),
+        Some(0x71f9),
+        Some(0x7203),
+        Some(0x71fd),
+        Some(0x7376),
+        None,
+    ];
+
+    const BASE_0XF040: [Option<u16>; 192] = [
+        Some(0x74b8),
+        Some(0x74c0),
+        Some(0x74b5),
+        Some(0x74c1),
+        Some(0x74be),
+        Some(0x74b6),
+        Some(0x74bb),
+        Some(0x74c2),
+        Some(0x7514),
+        Some(0x7513),
+        Some(0x765c),
+        Some(0x7664),
+        Some(0x7659),
+        Some(0x7650),
+        Some(0x7653),
+        Some(0x7657),
+        Some(0x765a),
+        Some(0x76a6),
+        Some(0x76bd),
+        Some(0x76ec),
+        Some(0x77c2),
+        Some(0x77ba),
+        Some(0x78ff),
+        Some(0x790c),
+        Some(0x7913),
+        Some(0x7914),
+        Some(0x7909),
+        Some(0x7910),
+        Some(0x7912),
+        Some(0x7911),
+        Some(0x79ad),
+        Some(0x79ac),
+        Some(0x7a5f),
+        Some(0x7c1c),
+        Some(0x7c29),
+        Some(0x7c19),
+        Some(0x7c20),
+        Some(0x7c1f),
+        Some(0x7c2d),
+        Some(0x7c1d),
+        Some(0x7c26),
+        Some(0x7c28),
+        Some(0x7c22),
+        Some(0x7c25),
+        Some(0x7c30),
+        Some(0x7e5c),
+        Some(0x7e50),
+        Some(0x7e56),
+        Some(0x7e63),
+        Some(0x7e58),
+        Some(0x7e62),
+        Some(0x7e5f),
+        Some(0x7e51),
+        Some(0x7e60),
+        Some(0x7e57),
+        Some(0x7e53),
+        Some(0x7fb5),
+        Some(0x7fb3),
+        Some(0x7ff7),
+        Some(0x7ff8),
+        Some(0x8075),
+        Some(0x81d1),
+        Some(0x81d2),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x81d0),
+        Some(0x825f),
+        Some(0x825e),
+        Some(0x85b4),
+        Some(0x85c6),
+        Some(0x85c0),
+        Some(0x85c3),
+        Some(0x85c2),
+        Some(0x85b3),
+        Some(0x85b5),
+        Some(0x85bd),
+        Some(0x85c7),
+        Some(0x85c4),
+        Some(0x85bf),
+        Some(0x85cb),
+        Some(0x85ce),
+        Some(0x85c8),
+        Some(0x85c5),
+        Some(0x85b1),
+        Some(0x85b6),
+        Some(0x85d2),
+        Some(0x8624),
+        Some(0x85b8),
+        Some(0x85b7),
+        Some(0x85be),
+        Some(0x8669),
+        Some(0x87e7),
+        Some(0x87e6),
+        Some(0x87e2),
+        Some(0x87db),
+        Some(0x87eb),
+        Some(0x87ea),
+        Some(0x87e5),
+        Some(0x87df),
+        Some(0x87f3),
+        Some(0x87e4),
+        Some(0x87d4),
+        Some(0x87dc),
+        Some(0x87d3),
+        Some(0x87ed),
+        Some(0x87d8),
+        Some(0x87e3),
+        Some(0x87a4),
+        Some(0x87d7),
+        Some(0x87d9),
+        Some(0x8801),
+        Some(0x87f4),
+        Some(0x87e8),
+        Some(0x87dd),
+        Some(0x8953),
+        Some(0x894b),
+        Some(0x894f),
+        Some(0x894c),
+        Some(0x8946),
+        Some(0x8950),
+        Some(0x8951),
+        Some(0x8949),
+        Some(0x8b2a),
+        Some(0x8b27),
+        Some(0x8b23),
+        Some(0x8b33),
+        Some(0x8b30),
+        Some(0x8b35),
+        Some(0x8b47),
+        Some(0x8b2f),
+        Some(0x8b3c),
+        Some(0x8b3e),
+        Some(0x8b31),
+        Some(0x8b25),
+        Some(0x8b37),
+        Some(0x8b26),
+        Some(0x8b36),
+        Some(0x8b2e),
+        Some(0x8b24),
+        Some(0x8b3b),
+        Some(0x8b3d),
+        Some(0x8b3a),
+        Some(0x8c42),
+        Some(0x8c75),
+        Some(0x8c99),
+        Some(0x8c98),
+        Some(0x8c97),
+        Some(0x8cfe),
+        Some(0x8d04),
+        Some(0x8d02),
+        Some(0x8d00),
+        Some(0x8e5c),
+        Some(0x8e62),
+        Some(0x8e60),
+        Some(0x8e57),
+        Some(0x8e56),
+        Some(0x8e5e),
+        Some(0x8e65),
+        Some(0x8e67),
+        None,
+    ];
+
+    const BASE_0XF140: [Option<u16>; 192] = [
+        Some(0x8e5b),
+        Some(0x8e5a),
+        Some(0x8e61),
+        Some(0x8e5d),
+        Some(0x8e69),
+        Some(0x8e54),
+        Some(0x8f46),
+        Some(0x8f47),
+        Some(0x8f48),
+        Some(0x8f4b),
+        Some(0x9128),
+        Some(0x913a),
+        Some(0x913b),
+        Some(0x913e),
+        Some(0x91a8),
+        Some(0x91a5),
+        Some(0x91a7),
+        Some(0x91af),
+        Some(0x91aa),
+        Some(0x93b5),
+        Some(0x938c),
+        Some(0x9392),
+        Some(0x93b7),
+        Some(0x939b),
+        Some(0x939d),
+        Some(0x9389),
+        Some(0x93a7),
+        Some(0x938e),
+        Some(0x93aa),
+        Some(0x939e),
+        Some(0x93a6),
+        Some(0x9395),
+        Some(0x9388),
+        Some(0x9399),
+        Some(0x939f),
+        Some(0x938d),
+        Some(0x93b1),
+        Some(0x9391),
+        Some(0x93b2),
+        Some(0x93a4),
+        Some(0x93a8),
+        Some(0x93b4),
+        Some(0x93a3),
+        Some(0x93a5),
+        Some(0x95d2),
+        Some(0x95d3),
+        Some(0x95d1),
+        Some(0x96b3),
+        Some(0x96d7),
+        Some(0x96da),
+        Some(0x5dc2),
+        Some(0x96df),
+        Some(0x96d8),
+        Some(0x96dd),
+        Some(0x9723),
+        Some(0x9722),
+        Some(0x9725),
+        Some(0x97ac),
+        Some(0x97ae),
+        Some(0x97a8),
+        Some(0x97ab),
+        Some(0x97a4),
+        Some(0x97aa),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x97a2),
+        Some(0x97a5),
+        Some(0x97d7),
+        Some(0x97d9),
+        Some(0x97d6),
+        Some(0x97d8),
+        Some(0x97fa),
+        Some(0x9850),
+        Some(0x9851),
+        Some(0x9852),
+        Some(0x98b8),
+        Some(0x9941),
+        Some(0x993c),
+        Some(0x993a),
+        Some(0x9a0f),
+        Some(0x9a0b),
+        Some(0x9a09),
+        Some(0x9a0d),
+        Some(0x9a04),
+        Some(0x9a11),
+        Some(0x9a0a),
+        Some(0x9a05),
+        Some(0x9a07),
+        Some(0x9a06),
+        Some(0x9ac0),
+        Some(0x9adc),
+        Some(0x9b08),
+        Some(0x9b04),
+        Some(0x9b05),
+        Some(0x9b29),
+        Some(0x9b35),
+        Some(0x9b4a),
+        Some(0x9b4c),
+        Some(0x9b4b),
+        Some(0x9bc7),
+        Some(0x9bc6),
+        Some(0x9bc3),
+        Some(0x9bbf),
+        Some(0x9bc1),
+        Some(0x9bb5),
+        Some(0x9bb8),
+        Some(0x9bd3),
+        Some(0x9bb6),
+        Some(0x9bc4),
+        Some(0x9bb9),
+        Some(0x9bbd),
+        Some(0x9d5c),
+        Some(0x9d53),
+        Some(0x9d4f),
+        Some(0x9d4a),
+        Some(0x9d5b),
+        Some(0x9d4b),
+        Some(0x9d59),
+        Some(0x9d56),
+        Some(0x9d4c),
+        Some(0x9d57),
+        Some(0x9d52),
+        Some(0x9d54),
+        Some(0x9d5f),
+        Some(0x9d58),
+        Some(0x9d5a),
+        Some(0x9e8e),
+        Some(0x9e8c),
+        Some(0x9edf),
+        Some(0x9f01),
+        Some(0x9f00),
+        Some(0x9f16),
+        Some(0x9f25),
+        Some(0x9f2b),
+        Some(0x9f2a),
+        Some(0x9f29),
+        Some(0x9f28),
+        Some(0x9f4c),
+        Some(0x9f55),
+        Some(0x5134),
+        Some(0x5135),
+        Some(0x5296),
+        Some(0x52f7),
+        Some(0x53b4),
+        Some(0x56ab),
+        Some(0x56ad),
+        Some(0x56a6),
+        Some(0x56a7),
+        Some(0x56aa),
+        Some(0x56ac),
+        Some(0x58da),
+        Some(0x58dd),
+        Some(0x58db),
+        Some(0x5912),
+        Some(0x5b3d),
+        Some(0x5b3e),
+        Some(0x5b3f),
+        Some(0x5dc3),
+        Some(0x5e70),
+        None,
+    ];
+
+    const BASE_0XF240: [Option<u16>; 192] = [
+        Some(0x5fbf),
+        Some(0x61fb),
+        Some(0x6507),
+        Some(0x6510),
+        Some(0x650d),
+        Some(0x6509),
+        Some(0x650c),
+        Some(0x650e),
+        Some(0x6584),
+        Some(0x65de),
+        Some(0x65dd),
+        Some(0x66de),
+        Some(0x6ae7),
+        Some(0x6ae0),
+        Some(0x6acc),
+        Some(0x6ad1),
+        Some(0x6ad9),
+        Some(0x6acb),
+        Some(0x6adf),
+        Some(0x6adc),
+        Some(0x6ad0),
+        Some(0x6aeb),
+        Some(0x6acf),
+        Some(0x6acd),
+        Some(0x6ade),
+        Some(0x6b60),
+        Some(0x6bb0),
+        Some(0x6c0c),
+        Some(0x7019),
+        Some(0x7027),
+        Some(0x7020),
+        Some(0x7016),
+        Some(0x702b),
+        Some(0x7021),
+        Some(0x7022),
+        Some(0x7023),
+        Some(0x7029),
+        Some(0x7017),
+        Some(0x7024),
+        Some(0x701c),
+        Some(0x702a),
+        Some(0x720c),
+        Some(0x720a),
+        Some(0x7207),
+        Some(0x7202),
+        Some(0x7205),
+        Some(0x72a5),
+        Some(0x72a6),
+        Some(0x72a4),
+        Some(0x72a3),
+        Some(0x72a1),
+        Some(0x74cb),
+        Some(0x74c5),
+        Some(0x74b7),
+        Some(0x74c3),
+        Some(0x7516),
+        Some(0x7660),
+        Some(0x77c9),
+        Some(0x77ca),
+        Some(0x77c4),
+        Some(0x77f1),
+        Some(0x791d),
+        Some(0x791b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x7921),
+        Some(0x791c),
+        Some(0x7917),
+        Some(0x791e),
+        Some(0x79b0),
+        Some(0x7a67),
+        Some(0x7a68),
+        Some(0x7c33),
+        Some(0x7c3c),
+        Some(0x7c39),
+        Some(0x7c2c),
+        Some(0x7c3b),
+        Some(0x7cec),
+        Some(0x7cea),
+        Some(0x7e76),
+        Some(0x7e75),
+        Some(0x7e78),
+        Some(0x7e70),
+        Some(0x7e77),
+        Some(0x7e6f),
+        Some(0x7e7a),
+        Some(0x7e72),
+        Some(0x7e74),
+        Some(0x7e68),
+        Some(0x7f4b),
+        Some(0x7f4a),
+        Some(0x7f83),
+        Some(0x7f86),
+        Some(0x7fb7),
+        Some(0x7ffd),
+        Some(0x7ffe),
+        Some(0x8078),
+        Some(0x81d7),
+        Some(0x81d5),
+        Some(0x8264),
+        Some(0x8261),
+        Some(0x8263),
+        Some(0x85eb),
+        Some(0x85f1),
+        Some(0x85ed),
+        Some(0x85d9),
+        Some(0x85e1),
+        Some(0x85e8),
+        Some(0x85da),
+        Some(0x85d7),
+        Some(0x85ec),
+        Some(0x85f2),
+        Some(0x85f8),
+        Some(0x85d8),
+        Some(0x85df),
+        Some(0x85e3),
+        Some(0x85dc),
+        Some(0x85d1),
+        Some(0x85f0),
+        Some(0x85e6),
+        Some(0x85ef),
+        Some(0x85de),
+        Some(0x85e2),
+        Some(0x8800),
+        Some(0x87fa),
+        Some(0x8803),
+        Some(0x87f6),
+        Some(0x87f7),
+        Some(0x8809),
+        Some(0x880c),
+        Some(0x880b),
+        Some(0x8806),
+        Some(0x87fc),
+        Some(0x8808),
+        Some(0x87ff),
+        Some(0x880a),
+        Some(0x8802),
+        Some(0x8962),
+        Some(0x895a),
+        Some(0x895b),
+        Some(0x8957),
+        Some(0x8961),
+        Some(0x895c),
+        Some(0x8958),
+        Some(0x895d),
+        Some(0x8959),
+        Some(0x8988),
+        Some(0x89b7),
+        Some(0x89b6),
+        Some(0x89f6),
+        Some(0x8b50),
+        Some(0x8b48),
+        Some(0x8b4a),
+        Some(0x8b40),
+        Some(0x8b53),
+        Some(0x8b56),
+        Some(0x8b54),
+        Some(0x8b4b),
+        Some(0x8b55),
+        None,
+    ];
+
+    const BASE_0XF340: [Option<u16>; 192] = [
+        Some(0x8b51),
+        Some(0x8b42),
+        Some(0x8b52),
+        Some(0x8b57),
+        Some(0x8c43),
+        Some(0x8c77),
+        Some(0x8c76),
+        Some(0x8c9a),
+        Some(0x8d06),
+        Some(0x8d07),
+        Some(0x8d09),
+        Some(0x8dac),
+        Some(0x8daa),
+        Some(0x8dad),
+        Some(0x8dab),
+        Some(0x8e6d),
+        Some(0x8e78),
+        Some(0x8e73),
+        Some(0x8e6a),
+        Some(0x8e6f),
+        Some(0x8e7b),
+        Some(0x8ec2),
+        Some(0x8f52),
+        Some(0x8f51),
+        Some(0x8f4f),
+        Some(0x8f50),
+        Some(0x8f53),
+        Some(0x8fb4),
+        Some(0x9140),
+        Some(0x913f),
+        Some(0x91b0),
+        Some(0x91ad),
+        Some(0x93de),
+        Some(0x93c7),
+        Some(0x93cf),
+        Some(0x93c2),
+        Some(0x93da),
+        Some(0x93d0),
+        Some(0x93f9),
+        Some(0x93ec),
+        Some(0x93cc),
+        Some(0x93d9),
+        Some(0x93a9),
+        Some(0x93e6),
+        Some(0x93ca),
+        Some(0x93d4),
+        Some(0x93ee),
+        Some(0x93e3),
+        Some(0x93d5),
+        Some(0x93c4),
+        Some(0x93ce),
+        Some(0x93c0),
+        Some(0x93d2),
+        Some(0x93e7),
+        Some(0x957d),
+        Some(0x95da),
+        Some(0x95db),
+        Some(0x96e1),
+        Some(0x9729),
+        Some(0x972b),
+        Some(0x972c),
+        Some(0x9728),
+        Some(0x9726),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x97b3),
+        Some(0x97b7),
+        Some(0x97b6),
+        Some(0x97dd),
+        Some(0x97de),
+        Some(0x97df),
+        Some(0x985c),
+        Some(0x9859),
+        Some(0x985d),
+        Some(0x9857),
+        Some(0x98bf),
+        Some(0x98bd),
+        Some(0x98bb),
+        Some(0x98be),
+        Some(0x9948),
+        Some(0x9947),
+        Some(0x9943),
+        Some(0x99a6),
+        Some(0x99a7),
+        Some(0x9a1a),
+        Some(0x9a15),
+        Some(0x9a25),
+        Some(0x9a1d),
+        Some(0x9a24),
+        Some(0x9a1b),
+        Some(0x9a22),
+        Some(0x9a20),
+        Some(0x9a27),
+        Some(0x9a23),
+        Some(0x9a1e),
+        Some(0x9a1c),
+        Some(0x9a14),
+        Some(0x9ac2),
+        Some(0x9b0b),
+        Some(0x9b0a),
+        Some(0x9b0e),
+        Some(0x9b0c),
+        Some(0x9b37),
+        Some(0x9bea),
+        Some(0x9beb),
+        Some(0x9be0),
+        Some(0x9bde),
+        Some(0x9be4),
+        Some(0x9be6),
+        Some(0x9be2),
+        Some(0x9bf0),
+        Some(0x9bd4),
+        Some(0x9bd7),
+        Some(0x9bec),
+        Some(0x9bdc),
+        Some(0x9bd9),
+        Some(0x9be5),
+        Some(0x9bd5),
+        Some(0x9be1),
+        Some(0x9bda),
+        Some(0x9d77),
+        Some(0x9d81),
+        Some(0x9d8a),
+        Some(0x9d84),
+        Some(0x9d88),
+        Some(0x9d71),
+        Some(0x9d80),
+        Some(0x9d78),
+        Some(0x9d86),
+        Some(0x9d8b),
+        Some(0x9d8c),
+        Some(0x9d7d),
+        Some(0x9d6b),
+        Some(0x9d74),
+        Some(0x9d75),
+        Some(0x9d70),
+        Some(0x9d69),
+        Some(0x9d85),
+        Some(0x9d73),
+        Some(0x9d7b),
+        Some(0x9d82),
+        Some(0x9d6f),
+        Some(0x9d79),
+        Some(0x9d7f),
+        Some(0x9d87),
+        Some(0x9d68),
+        Some(0x9e94),
+        Some(0x9e91),
+        Some(0x9ec0),
+        Some(0x9efc),
+        Some(0x9f2d),
+        Some(0x9f40),
+        Some(0x9f41),
+        Some(0x9f4d),
+        Some(0x9f56),
+        Some(0x9f57),
+        Some(0x9f58),
+        Some(0x5337),
+        Some(0x56b2),
+        None,
+    ];
+
+    const BASE_0XF440: [Option<u16>; 192] = [
+        Some(0x56b5),
+        Some(0x56b3),
+        Some(0x58e3),
+        Some(0x5b45),
+        Some(0x5dc6),
+        Some(0x5dc7),
+        Some(0x5eee),
+        Some(0x5eef),
+        Some(0x5fc0),
+        Some(0x5fc1),
+        Some(0x61f9),
+        Some(0x6517),
+        Some(0x6516),
+        Some(0x6515),
+        Some(0x6513),
+        Some(0x65df),
+        Some(0x66e8),
+        Some(0x66e3),
+        Some(0x66e4),
+        Some(0x6af3),
+        Some(0x6af0),
+        Some(0x6aea),
+        Some(0x6ae8),
+        Some(0x6af9),
+        Some(0x6af1),
+        Some(0x6aee),
+        Some(0x6aef),
+        Some(0x703c),
+        Some(0x7035),
+        Some(0x702f),
+        Some(0x7037),
+        Some(0x7034),
+        Some(0x7031),
+        Some(0x7042),
+        Some(0x7038),
+        Some(0x703f),
+        Some(0x703a),
+        Some(0x7039),
+        Some(0x7040),
+        Some(0x703b),
+        Some(0x7033),
+        Some(0x7041),
+        Some(0x7213),
+        Some(0x7214),
+        Some(0x72a8),
+        Some(0x737d),
+        Some(0x737c),
+        Some(0x74ba),
+        Some(0x76ab),
+        Some(0x76aa),
+        Some(0x76be),
+        Some(0x76ed),
+        Some(0x77cc),
+        Some(0x77ce),
+        Some(0x77cf),
+        Some(0x77cd),
+        Some(0x77f2),
+        Some(0x7925),
+        Some(0x7923),
+        Some(0x7927),
+        Some(0x7928),
+        Some(0x7924),
+        Some(0x7929),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x79b2),
+        Some(0x7a6e),
+        Some(0x7a6c),
+        Some(0x7a6d),
+        Some(0x7af7),
+        Some(0x7c49),
+        Some(0x7c48),
+        Some(0x7c4a),
+        Some(0x7c47),
+        Some(0x7c45),
+        Some(0x7cee),
+        Some(0x7e7b),
+        Some(0x7e7e),
+        Some(0x7e81),
+        Some(0x7e80),
+        Some(0x7fba),
+        Some(0x7fff),
+        Some(0x8079),
+        Some(0x81db),
+        Some(0x81d9),
+        Some(0x820b),
+        Some(0x8268),
+        Some(0x8269),
+        Some(0x8622),
+        Some(0x85ff),
+        Some(0x8601),
+        Some(0x85fe),
+        Some(0x861b),
+        Some(0x8600),
+        Some(0x85f6),
+        Some(0x8604),
+        Some(0x8609),
+        Some(0x8605),
+        Some(0x860c),
+        Some(0x85fd),
+        Some(0x8819),
+        Some(0x8810),
+        Some(0x8811),
+        Some(0x8817),
+        Some(0x8813),
+        Some(0x8816),
+        Some(0x8963),
+        Some(0x8966),
+        Some(0x89b9),
+        Some(0x89f7),
+        Some(0x8b60),
+        Some(0x8b6a),
+        Some(0x8b5d),
+        Some(0x8b68),
+        Some(0x8b63),
+        Some(0x8b65),
+        Some(0x8b67),
+        Some(0x8b6d),
+        Some(0x8dae),
+        Some(0x8e86),
+        Some(0x8e88),
+        Some(0x8e84),
+        Some(0x8f59),
+        Some(0x8f56),
+        Some(0x8f57),
+        Some(0x8f55),
+        Some(0x8f58),
+        Some(0x8f5a),
+        Some(0x908d),
+        Some(0x9143),
+        Some(0x9141),
+        Some(0x91b7),
+        Some(0x91b5),
+        Some(0x91b2),
+        Some(0x91b3),
+        Some(0x940b),
+        Some(0x9413),
+        Some(0x93fb),
+        Some(0x9420),
+        Some(0x940f),
+        Some(0x9414),
+        Some(0x93fe),
+        Some(0x9415),
+        Some(0x9410),
+        Some(0x9428),
+        Some(0x9419),
+        Some(0x940d),
+        Some(0x93f5),
+        Some(0x9400),
+        Some(0x93f7),
+        Some(0x9407),
+        Some(0x940e),
+        Some(0x9416),
+        Some(0x9412),
+        Some(0x93fa),
+        Some(0x9409),
+        Some(0x93f8),
+        Some(0x940a),
+        Some(0x93ff),
+        None,
+    ];
+
+    const BASE_0XF540: [Option<u16>; 192] = [
+        Some(0x93fc),
+        Some(0x940c),
+        Some(0x93f6),
+        Some(0x9411),
+        Some(0x9406),
+        Some(0x95de),
+        Some(0x95e0),
+        Some(0x95df),
+        Some(0x972e),
+        Some(0x972f),
+        Some(0x97b9),
+        Some(0x97bb),
+        Some(0x97fd),
+        Some(0x97fe),
+        Some(0x9860),
+        Some(0x9862),
+        Some(0x9863),
+        Some(0x985f),
+        Some(0x98c1),
+        Some(0x98c2),
+        Some(0x9950),
+        Some(0x994e),
+        Some(0x9959),
+        Some(0x994c),
+        Some(0x994b),
+        Some(0x9953),
+        Some(0x9a32),
+        Some(0x9a34),
+        Some(0x9a31),
+        Some(0x9a2c),
+        Some(0x9a2a),
+        Some(0x9a36),
+        Some(0x9a29),
+        Some(0x9a2e),
+        Some(0x9a38),
+        Some(0x9a2d),
+        Some(0x9ac7),
+        Some(0x9aca),
+        Some(0x9ac6),
+        Some(0x9b10),
+        Some(0x9b12),
+        Some(0x9b11),
+        Some(0x9c0b),
+        Some(0x9c08),
+        Some(0x9bf7),
+        Some(0x9c05),
+        Some(0x9c12),
+        Some(0x9bf8),
+        Some(0x9c40),
+        Some(0x9c07),
+        Some(0x9c0e),
+        Some(0x9c06),
+        Some(0x9c17),
+        Some(0x9c14),
+        Some(0x9c09),
+        Some(0x9d9f),
+        Some(0x9d99),
+        Some(0x9da4),
+        Some(0x9d9d),
+        Some(0x9d92),
+        Some(0x9d98),
+        Some(0x9d90),
+        Some(0x9d9b),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9da0),
+        Some(0x9d94),
+        Some(0x9d9c),
+        Some(0x9daa),
+        Some(0x9d97),
+        Some(0x9da1),
+        Some(0x9d9a),
+        Some(0x9da2),
+        Some(0x9da8),
+        Some(0x9d9e),
+        Some(0x9da3),
+        Some(0x9dbf),
+        Some(0x9da9),
+        Some(0x9d96),
+        Some(0x9da6),
+        Some(0x9da7),
+        Some(0x9e99),
+        Some(0x9e9b),
+        Some(0x9e9a),
+        Some(0x9ee5),
+        Some(0x9ee4),
+        Some(0x9ee7),
+        Some(0x9ee6),
+        Some(0x9f30),
+        Some(0x9f2e),
+        Some(0x9f5b),
+        Some(0x9f60),
+        Some(0x9f5e),
+        Some(0x9f5d),
+        Some(0x9f59),
+        Some(0x9f91),
+        Some(0x513a),
+        Some(0x5139),
+        Some(0x5298),
+        Some(0x5297),
+        Some(0x56c3),
+        Some(0x56bd),
+        Some(0x56be),
+        Some(0x5b48),
+        Some(0x5b47),
+        Some(0x5dcb),
+        Some(0x5dcf),
+        Some(0x5ef1),
+        Some(0x61fd),
+        Some(0x651b),
+        Some(0x6b02),
+        Some(0x6afc),
+        Some(0x6b03),
+        Some(0x6af8),
+        Some(0x6b00),
+        Some(0x7043),
+        Some(0x7044),
+        Some(0x704a),
+        Some(0x7048),
+        Some(0x7049),
+        Some(0x7045),
+        Some(0x7046),
+        Some(0x721d),
+        Some(0x721a),
+        Some(0x7219),
+        Some(0x737e),
+        Some(0x7517),
+        Some(0x766a),
+        Some(0x77d0),
+        Some(0x792d),
+        Some(0x7931),
+        Some(0x792f),
+        Some(0x7c54),
+        Some(0x7c53),
+        Some(0x7cf2),
+        Some(0x7e8a),
+        Some(0x7e87),
+        Some(0x7e88),
+        Some(0x7e8b),
+        Some(0x7e86),
+        Some(0x7e8d),
+        Some(0x7f4d),
+        Some(0x7fbb),
+        Some(0x8030),
+        Some(0x81dd),
+        Some(0x8618),
+        Some(0x862a),
+        Some(0x8626),
+        Some(0x861f),
+        Some(0x8623),
+        Some(0x861c),
+        Some(0x8619),
+        Some(0x8627),
+        Some(0x862e),
+        Some(0x8621),
+        Some(0x8620),
+        Some(0x8629),
+        Some(0x861e),
+        Some(0x8625),
+        None,
+    ];
+
+    const BASE_0XF640: [Option<u16>; 192] = [
+        Some(0x8829),
+        Some(0x881d),
+        Some(0x881b),
+        Some(0x8820),
+        Some(0x8824),
+        Some(0x881c),
+        Some(0x882b),
+        Some(0x884a),
+        Some(0x896d),
+        Some(0x8969),
+        Some(0x896e),
+        Some(0x896b),
+        Some(0x89fa),
+        Some(0x8b79),
+        Some(0x8b78),
+        Some(0x8b45),
+        Some(0x8b7a),
+        Some(0x8b7b),
+        Some(0x8d10),
+        Some(0x8d14),
+        Some(0x8daf),
+        Some(0x8e8e),
+        Some(0x8e8c),
+        Some(0x8f5e),
+        Some(0x8f5b),
+        Some(0x8f5d),
+        Some(0x9146),
+        Some(0x9144),
+        Some(0x9145),
+        Some(0x91b9),
+        Some(0x943f),
+        Some(0x943b),
+        Some(0x9436),
+        Some(0x9429),
+        Some(0x943d),
+        Some(0x943c),
+        Some(0x9430),
+        Some(0x9439),
+        Some(0x942a),
+        Some(0x9437),
+        Some(0x942c),
+        Some(0x9440),
+        Some(0x9431),
+        Some(0x95e5),
+        Some(0x95e4),
+        Some(0x95e3),
+        Some(0x9735),
+        Some(0x973a),
+        Some(0x97bf),
+        Some(0x97e1),
+        Some(0x9864),
+        Some(0x98c9),
+        Some(0x98c6),
+        Some(0x98c0),
+        Some(0x9958),
+        Some(0x9956),
+        Some(0x9a39),
+        Some(0x9a3d),
+        Some(0x9a46),
+        Some(0x9a44),
+        Some(0x9a42),
+        Some(0x9a41),
+        Some(0x9a3a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9a3f),
+        Some(0x9acd),
+        Some(0x9b15),
+        Some(0x9b17),
+        Some(0x9b18),
+        Some(0x9b16),
+        Some(0x9b3a),
+        Some(0x9b52),
+        Some(0x9c2b),
+        Some(0x9c1d),
+        Some(0x9c1c),
+        Some(0x9c2c),
+        Some(0x9c23),
+        Some(0x9c28),
+        Some(0x9c29),
+        Some(0x9c24),
+        Some(0x9c21),
+        Some(0x9db7),
+        Some(0x9db6),
+        Some(0x9dbc),
+        Some(0x9dc1),
+        Some(0x9dc7),
+        Some(0x9dca),
+        Some(0x9dcf),
+        Some(0x9dbe),
+        Some(0x9dc5),
+        Some(0x9dc3),
+        Some(0x9dbb),
+        Some(0x9db5),
+        Some(0x9dce),
+        Some(0x9db9),
+        Some(0x9dba),
+        Some(0x9dac),
+        Some(0x9dc8),
+        Some(0x9db1),
+        Some(0x9dad),
+        Some(0x9dcc),
+        Some(0x9db3),
+        Some(0x9dcd),
+        Some(0x9db2),
+        Some(0x9e7a),
+        Some(0x9e9c),
+        Some(0x9eeb),
+        Some(0x9eee),
+        Some(0x9eed),
+        Some(0x9f1b),
+        Some(0x9f18),
+        Some(0x9f1a),
+        Some(0x9f31),
+        Some(0x9f4e),
+        Some(0x9f65),
+        Some(0x9f64),
+        Some(0x9f92),
+        Some(0x4eb9),
+        Some(0x56c6),
+        Some(0x56c5),
+        Some(0x56cb),
+        Some(0x5971),
+        Some(0x5b4b),
+        Some(0x5b4c),
+        Some(0x5dd5),
+        Some(0x5dd1),
+        Some(0x5ef2),
+        Some(0x6521),
+        Some(0x6520),
+        Some(0x6526),
+        Some(0x6522),
+        Some(0x6b0b),
+        Some(0x6b08),
+        Some(0x6b09),
+        Some(0x6c0d),
+        Some(0x7055),
+        Some(0x7056),
+        Some(0x7057),
+        Some(0x7052),
+        Some(0x721e),
+        Some(0x721f),
+        Some(0x72a9),
+        Some(0x737f),
+        Some(0x74d8),
+        Some(0x74d5),
+        Some(0x74d9),
+        Some(0x74d7),
+        Some(0x766d),
+        Some(0x76ad),
+        Some(0x7935),
+        Some(0x79b4),
+        Some(0x7a70),
+        Some(0x7a71),
+        Some(0x7c57),
+        Some(0x7c5c),
+        Some(0x7c59),
+        Some(0x7c5b),
+        Some(0x7c5a),
+        None,
+    ];
+
+    const BASE_0XF740: [Option<u16>; 192] = [
+        Some(0x7cf4),
+        Some(0x7cf1),
+        Some(0x7e91),
+        Some(0x7f4f),
+        Some(0x7f87),
+        Some(0x81de),
+        Some(0x826b),
+        Some(0x8634),
+        Some(0x8635),
+        Some(0x8633),
+        Some(0x862c),
+        Some(0x8632),
+        Some(0x8636),
+        Some(0x882c),
+        Some(0x8828),
+        Some(0x8826),
+        Some(0x882a),
+        Some(0x8825),
+        Some(0x8971),
+        Some(0x89bf),
+        Some(0x89be),
+        Some(0x89fb),
+        Some(0x8b7e),
+        Some(0x8b84),
+        Some(0x8b82),
+        Some(0x8b86),
+        Some(0x8b85),
+        Some(0x8b7f),
+        Some(0x8d15),
+        Some(0x8e95),
+        Some(0x8e94),
+        Some(0x8e9a),
+        Some(0x8e92),
+        Some(0x8e90),
+        Some(0x8e96),
+        Some(0x8e97),
+        Some(0x8f60),
+        Some(0x8f62),
+        Some(0x9147),
+        Some(0x944c),
+        Some(0x9450),
+        Some(0x944a),
+        Some(0x944b),
+        Some(0x944f),
+        Some(0x9447),
+        Some(0x9445),
+        Some(0x9448),
+        Some(0x9449),
+        Some(0x9446),
+        Some(0x973f),
+        Some(0x97e3),
+        Some(0x986a),
+        Some(0x9869),
+        Some(0x98cb),
+        Some(0x9954),
+        Some(0x995b),
+        Some(0x9a4e),
+        Some(0x9a53),
+        Some(0x9a54),
+        Some(0x9a4c),
+        Some(0x9a4f),
+        Some(0x9a48),
+        Some(0x9a4a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9a49),
+        Some(0x9a52),
+        Some(0x9a50),
+        Some(0x9ad0),
+        Some(0x9b19),
+        Some(0x9b2b),
+        Some(0x9b3b),
+        Some(0x9b56),
+        Some(0x9b55),
+        Some(0x9c46),
+        Some(0x9c48),
+        Some(0x9c3f),
+        Some(0x9c44),
+        Some(0x9c39),
+        Some(0x9c33),
+        Some(0x9c41),
+        Some(0x9c3c),
+        Some(0x9c37),
+        Some(0x9c34),
+        Some(0x9c32),
+        Some(0x9c3d),
+        Some(0x9c36),
+        Some(0x9ddb),
+        Some(0x9dd2),
+        Some(0x9dde),
+        Some(0x9dda),
+        Some(0x9dcb),
+        Some(0x9dd0),
+        Some(0x9ddc),
+        Some(0x9dd1),
+        Some(0x9ddf),
+        Some(0x9de9),
+        Some(0x9dd9),
+        Some(0x9dd8),
+        Some(0x9dd6),
+        Some(0x9df5),
+        Some(0x9dd5),
+        Some(0x9ddd),
+        Some(0x9eb6),
+        Some(0x9ef0),
+        Some(0x9f35),
+        Some(0x9f33),
+        Some(0x9f32),
+        Some(0x9f42),
+        Some(0x9f6b),
+        Some(0x9f95),
+        Some(0x9fa2),
+        Some(0x513d),
+        Some(0x5299),
+        Some(0x58e8),
+        Some(0x58e7),
+        Some(0x5972),
+        Some(0x5b4d),
+        Some(0x5dd8),
+        Some(0x882f),
+        Some(0x5f4f),
+        Some(0x6201),
+        Some(0x6203),
+        Some(0x6204),
+        Some(0x6529),
+        Some(0x6525),
+        Some(0x6596),
+        Some(0x66eb),
+        Some(0x6b11),
+        Some(0x6b12),
+        Some(0x6b0f),
+        Some(0x6bca),
+        Some(0x705b),
+        Some(0x705a),
+        Some(0x7222),
+        Some(0x7382),
+        Some(0x7381),
+        Some(0x7383),
+        Some(0x7670),
+        Some(0x77d4),
+        Some(0x7c67),
+        Some(0x7c66),
+        Some(0x7e95),
+        Some(0x826c),
+        Some(0x863a),
+        Some(0x8640),
+        Some(0x8639),
+        Some(0x863c),
+        Some(0x8631),
+        Some(0x863b),
+        Some(0x863e),
+        Some(0x8830),
+        Some(0x8832),
+        Some(0x882e),
+        Some(0x8833),
+        Some(0x8976),
+        Some(0x8974),
+        Some(0x8973),
+        Some(0x89fe),
+        None,
+    ];
+
+    const BASE_0XF840: [Option<u16>; 192] = [
+        Some(0x8b8c),
+        Some(0x8b8e),
+        Some(0x8b8b),
+        Some(0x8b88),
+        Some(0x8c45),
+        Some(0x8d19),
+        Some(0x8e98),
+        Some(0x8f64),
+        Some(0x8f63),
+        Some(0x91bc),
+        Some(0x9462),
+        Some(0x9455),
+        Some(0x945d),
+        Some(0x9457),
+        Some(0x945e),
+        Some(0x97c4),
+        Some(0x97c5),
+        Some(0x9800),
+        Some(0x9a56),
+        Some(0x9a59),
+        Some(0x9b1e),
+        Some(0x9b1f),
+        Some(0x9b20),
+        Some(0x9c52),
+        Some(0x9c58),
+        Some(0x9c50),
+        Some(0x9c4a),
+        Some(0x9c4d),
+        Some(0x9c4b),
+        Some(0x9c55),
+        Some(0x9c59),
+        Some(0x9c4c),
+        Some(0x9c4e),
+        Some(0x9dfb),
+        Some(0x9df7),
+        Some(0x9def),
+        Some(0x9de3),
+        Some(0x9deb),
+        Some(0x9df8),
+        Some(0x9de4),
+        Some(0x9df6),
+        Some(0x9de1),
+        Some(0x9dee),
+        Some(0x9de6),
+        Some(0x9df2),
+        Some(0x9df0),
+        Some(0x9de2),
+        Some(0x9dec),
+        Some(0x9df4),
+        Some(0x9df3),
+        Some(0x9de8),
+        Some(0x9ded),
+        Some(0x9ec2),
+        Some(0x9ed0),
+        Some(0x9ef2),
+        Some(0x9ef3),
+        Some(0x9f06),
+        Some(0x9f1c),
+        Some(0x9f38),
+        Some(0x9f37),
+        Some(0x9f36),
+        Some(0x9f43),
+        Some(0x9f4f),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9f71),
+        Some(0x9f70),
+        Some(0x9f6e),
+        Some(0x9f6f),
+        Some(0x56d3),
+        Some(0x56cd),
+        Some(0x5b4e),
+        Some(0x5c6d),
+        Some(0x652d),
+        Some(0x66ed),
+        Some(0x66ee),
+        Some(0x6b13),
+        Some(0x705f),
+        Some(0x7061),
+        Some(0x705d),
+        Some(0x7060),
+        Some(0x7223),
+        Some(0x74db),
+        Some(0x74e5),
+        Some(0x77d5),
+        Some(0x7938),
+        Some(0x79b7),
+        Some(0x79b6),
+        Some(0x7c6a),
+        Some(0x7e97),
+        Some(0x7f89),
+        Some(0x826d),
+        Some(0x8643),
+        Some(0x8838),
+        Some(0x8837),
+        Some(0x8835),
+        Some(0x884b),
+        Some(0x8b94),
+        Some(0x8b95),
+        Some(0x8e9e),
+        Some(0x8e9f),
+        Some(0x8ea0),
+        Some(0x8e9d),
+        Some(0x91be),
+        Some(0x91bd),
+        Some(0x91c2),
+        Some(0x946b),
+        Some(0x9468),
+        Some(0x9469),
+        Some(0x96e5),
+        Some(0x9746),
+        Some(0x9743),
+        Some(0x9747),
+        Some(0x97c7),
+        Some(0x97e5),
+        Some(0x9a5e),
+        Some(0x9ad5),
+        Some(0x9b59),
+        Some(0x9c63),
+        Some(0x9c67),
+        Some(0x9c66),
+        Some(0x9c62),
+        Some(0x9c5e),
+        Some(0x9c60),
+        Some(0x9e02),
+        Some(0x9dfe),
+        Some(0x9e07),
+        Some(0x9e03),
+        Some(0x9e06),
+        Some(0x9e05),
+        Some(0x9e00),
+        Some(0x9e01),
+        Some(0x9e09),
+        Some(0x9dff),
+        Some(0x9dfd),
+        Some(0x9e04),
+        Some(0x9ea0),
+        Some(0x9f1e),
+        Some(0x9f46),
+        Some(0x9f74),
+        Some(0x9f75),
+        Some(0x9f76),
+        Some(0x56d4),
+        Some(0x652e),
+        Some(0x65b8),
+        Some(0x6b18),
+        Some(0x6b19),
+        Some(0x6b17),
+        Some(0x6b1a),
+        Some(0x7062),
+        Some(0x7226),
+        Some(0x72aa),
+        Some(0x77d8),
+        Some(0x77d9),
+        Some(0x7939),
+        Some(0x7c69),
+        Some(0x7c6b),
+        Some(0x7cf6),
+        Some(0x7e9a),
+        None,
+    ];
+
+    const BASE_0XF940: [Option<u16>; 192] = [
+        Some(0x7e98),
+        Some(0x7e9b),
+        Some(0x7e99),
+        Some(0x81e0),
+        Some(0x81e1),
+        Some(0x8646),
+        Some(0x8647),
+        Some(0x8648),
+        Some(0x8979),
+        Some(0x897a),
+        Some(0x897c),
+        Some(0x897b),
+        Some(0x89ff),
+        Some(0x8b98),
+        Some(0x8b99),
+        Some(0x8ea5),
+        Some(0x8ea4),
+        Some(0x8ea3),
+        Some(0x946e),
+        Some(0x946d),
+        Some(0x946f),
+        Some(0x9471),
+        Some(0x9473),
+        Some(0x9749),
+        Some(0x9872),
+        Some(0x995f),
+        Some(0x9c68),
+        Some(0x9c6e),
+        Some(0x9c6d),
+        Some(0x9e0b),
+        Some(0x9e0d),
+        Some(0x9e10),
+        Some(0x9e0f),
+        Some(0x9e12),
+        Some(0x9e11),
+        Some(0x9ea1),
+        Some(0x9ef5),
+        Some(0x9f09),
+        Some(0x9f47),
+        Some(0x9f78),
+        Some(0x9f7b),
+        Some(0x9f7a),
+        Some(0x9f79),
+        Some(0x571e),
+        Some(0x7066),
+        Some(0x7c6f),
+        Some(0x883c),
+        Some(0x8db2),
+        Some(0x8ea6),
+        Some(0x91c3),
+        Some(0x9474),
+        Some(0x9478),
+        Some(0x9476),
+        Some(0x9475),
+        Some(0x9a60),
+        Some(0x9c74),
+        Some(0x9c73),
+        Some(0x9c71),
+        Some(0x9c75),
+        Some(0x9e14),
+        Some(0x9e13),
+        Some(0x9ef6),
+        Some(0x9f0a),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0x9fa4),
+        Some(0x7068),
+        Some(0x7065),
+        Some(0x7cf7),
+        Some(0x866a),
+        Some(0x883e),
+        Some(0x883d),
+        Some(0x883f),
+        Some(0x8b9e),
+        Some(0x8c9c),
+        Some(0x8ea9),
+        Some(0x8ec9),
+        Some(0x974b),
+        Some(0x9873),
+        Some(0x9874),
+        Some(0x98cc),
+        Some(0x9961),
+        Some(0x99ab),
+        Some(0x9a64),
+        Some(0x9a66),
+        Some(0x9a67),
+        Some(0x9b24),
+        Some(0x9e15),
+        Some(0x9e17),
+        Some(0x9f48),
+        Some(0x6207),
+        Some(0x6b1e),
+        Some(0x7227),
+        Some(0x864c),
+        Some(0x8ea8),
+        Some(0x9482),
+        Some(0x9480),
+        Some(0x9481),
+        Some(0x9a69),
+        Some(0x9a68),
+        Some(0x9b2e),
+        Some(0x9e19),
+        Some(0x7229),
+        Some(0x864b),
+        Some(0x8b9f),
+        Some(0x9483),
+        Some(0x9c79),
+        Some(0x9eb7),
+        Some(0x7675),
+        Some(0x9a6b),
+        Some(0x9c7a),
+        Some(0x9e1d),
+        Some(0x7069),
+        Some(0x706a),
+        Some(0x9ea4),
+        Some(0x9f7e),
+        Some(0x9f49),
+        Some(0x9f98),
+        Some(0x7881),
+        Some(0x92b9),
+        Some(0x88cf),
+        Some(0x58bb),
+        Some(0x6052),
+        Some(0x7ca7),
+        Some(0x5afa),
+        Some(0x2554),
+        Some(0x2566),
+        Some(0x2557),
+        Some(0x2560),
+        Some(0x256c),
+        Some(0x2563),
+        Some(0x255a),
+        Some(0x2569),
+        Some(0x255d),
+        Some(0x2552),
+        Some(0x2564),
+        Some(0x2555),
+        Some(0x255e),
+        Some(0x256a),
+        Some(0x2561),
+        Some(0x2558),
+        Some(0x2567),
+        Some(0x255b),
+        Some(0x2553),
+        Some(0x2565),
+        Some(0x2556),
+        Some(0x255f),
+        Some(0x256b),
+        Some(0x2562),
+        Some(0x2559),
+        Some(0x2568),
+        Some(0x255c),
+        Some(0x2551),
+        Some(0x2550),
+        Some(0x256d),
+        Some(0x256e),
+        Some(0x2570),
+        Some(0x256f),
+        Some(0x2593),
+        None,
+    ];
+
+    const LOOKUP_TABLES_0XA1: [&'static [Option<u16>]; 2] =
+        [&Self::BASE_0XA140, &Self::BASE_0XA240];
+
+    const LOOKUP_TABLES_0XA4: [&'static [Option<u16>]; 36] = [
+        &Self::BASE_0XA440,
+        &Self::BASE_0XA540,
+        &Self::BASE_0XA640,
+        &Self::BASE_0XA740,
+        &Self::BASE_0XA840,
+        &Self::BASE_0XA940,
+        &Self::BASE_0XAA40,
+        &Self::BASE_0XAB40,
+        &Self::BASE_0XAC40,
+        &Self::BASE_0XAD40,
+        &Self::BASE_0XAE40,
+        &Self::BASE_0XAF40,
+        &Self::BASE_0XB040,
+        &Self::BASE_0XB140,
+        &Self::BASE_0XB240,
+        &Self::BASE_0XB340,
+        &Self::BASE_0XB440,
+        &Self::BASE_0XB540,
+        &Self::BASE_0XB640,
+        &Self::BASE_0XB740,
+        &Self::BASE_0XB840,
+        &Self::BASE_0XB940,
+        &Self::BASE_0XBA40,
+        &Self::BASE_0XBB40,
+        &Self::BASE_0XBC40,
+        &Self::BASE_0XBD40,
+        &Self::BASE_0XBE40,
+        &Self::BASE_0XBF40,
+        &Self::BASE_0XC040,
+        &Self::BASE_0XC140,
+        &Self::BASE_0XC240,
+        &Self::BASE_0XC340,
+        &Self::BASE_0XC440,
+        &Self::BASE_0XC540,
+        &Self::BASE_0XC640,
+        &Self::BASE_0XC740,
+    ];
+
+    const LOOKUP_TABLES_0XC9: [&'static [Option<u16>]; 49] = [
+        &Self::BASE_0XC940,
+        &Self::BASE_0XCA40,
+        &Self::BASE_0XCB40,
+        &Self::BASE_0XCC40,
+        &Self::BASE_0XCD40,
+        &Self::BASE_0XCE40,
+        &Self::BASE_0XCF40,
+        &Self::BASE_0XD040,
+        &Self::BASE_0XD140,
+        &Self::BASE_0XD240,
+        &Self::BASE_0XD340,
+        &Self::BASE_0XD440,
+        &Self::BASE_0XD540,
+        &Self::BASE_0XD640,
+        &Self::BASE_0XD740,
+        &Self::BASE_0XD840,
+        &Self::BASE_0XD940,
+        &Self::BASE_0XDA40,
+        &Self::BASE_0XDB40,
+        &Self::BASE_0XDC40,
+        &Self::BASE_0XDD40,
+        &Self::BASE_0XDE40,
+        &Self::BASE_0XDF40,
+        &Self::BASE_0XE040,
+        &Self::BASE_0XE140,
+        &Self::BASE_0XE240,
+        &Self::BASE_0XE340,
+        &Self::BASE_0XE440,
+        &Self::BASE_0XE540,
+        &Self::BASE_0XE640,
+        &Self::BASE_0XE740,
+        &Self::BASE_0XE840,
+        &Self::BASE_0XE940,
+        &Self::BASE_0XEA40,
+        &Self::BASE_0XEB40,
+        &Self::BASE_0XEC40,
+        &Self::BASE_0XED40,
+        &Self::BASE_0XEE40,
+        &Self::BASE_0XEF40,
+        &Self::BASE_0XF040,
+        &Self::BASE_0XF140,
+        &Self::BASE_0XF240,
+        &Self::BASE_0XF340,
+        &Self::BASE_0XF440,
+        &Self::BASE_0XF540,
+        &Self::BASE_0XF640,
+        &Self::BASE_0XF740,
+        &Self::BASE_0XF840,
+        &Self::BASE_0XF940,
+    ];
+
+    /// Creates a new decoder.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes,
+            byte_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DecoderWindows950<'a> {
+    type Item = Result<u32, ErrorTrace>;
+
+    /// Retrieves the next decoded Unicode code point.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.bytes.get(self.byte_index) {
+            Some(byte_value) => {
+                self.byte_index += 1;
+
+                let additional_byte_value: u8 = match *byte_value {
+                    0x81..=0xfe => match self.bytes.get(self.byte_index) {
+                        Some(value) => {
+                            self.byte_index += 1;
+
+                            *value
+                        }
+                        None => {
+                            return Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode Windows 950: 0x{:02x} as Unicode - missing additional byte",
+                                *byte_value
+                            ))));
+                        }
+                    },
+                    _ => 0,
+                };
+                match *byte_value {
+                    0x00..=0x7f => Some(Ok(*byte_value as u32)),
+                    0xa1..=0xa2 => {
+                        let lookup_table: &[Option<u16>] =
+                            Self::LOOKUP_TABLES_0XA1[(*byte_value - 0xa1) as usize];
+
+                        match additional_byte_value {
+                            0x40..=0xff => {
+                                match lookup_table[(additional_byte_value - 0x40) as usize] {
+                                    Some(code_point) => Some(Ok(code_point as u32)),
+                                    None => Some(Err(keramics_core::error_trace_new!(format!(
+                                        "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                        *byte_value, additional_byte_value
+                                    )))),
+                                }
+                            }
+                            _ => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                *byte_value, additional_byte_value
+                            )))),
+                        }
+                    }
+                    0xa3 => match additional_byte_value {
+                        0x40..0xe2 => {
+                            match Self::BASE_0XA340[(additional_byte_value - 0x40) as usize] {
+                                Some(code_point) => Some(Ok(code_point as u32)),
+                                None => Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                    *byte_value, additional_byte_value
+                                )))),
+                            }
+                        }
+                        _ => Some(Err(keramics_core::error_trace_new!(format!(
+                            "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                            *byte_value, additional_byte_value
+                        )))),
+                    },
+                    0xa4..=0xc7 => {
+                        let lookup_table: &[Option<u16>] =
+                            Self::LOOKUP_TABLES_0XA4[(*byte_value - 0xa4) as usize];
+
+                        match additional_byte_value {
+                            0x40..=0xff => {
+                                match lookup_table[(additional_byte_value - 0x40) as usize] {
+                                    Some(code_point) => Some(Ok(code_point as u32)),
+                                    None => Some(Err(keramics_core::error_trace_new!(format!(
+                                        "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                        *byte_value, additional_byte_value
+                                    )))),
+                                }
+                            }
+                            _ => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                *byte_value, additional_byte_value
+                            )))),
+                        }
+                    }
+                    0xc9..=0xf9 => {
+                        let lookup_table: &[Option<u16>] =
+                            Self::LOOKUP_TABLES_0XC9[(*byte_value - 0xc9) as usize];
+
+                        match additional_byte_value {
+                            0x40..=0xff => {
+                                match lookup_table[(additional_byte_value - 0x40) as usize] {
+                                    Some(code_point) => Some(Ok(code_point as u32)),
+                                    None => Some(Err(keramics_core::error_trace_new!(format!(
+                                        "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                        *byte_value, additional_byte_value
+                                    )))),
+                                }
+                            }
+                            _ => Some(Err(keramics_core::error_trace_new!(format!(
+                                "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                                *byte_value, additional_byte_value
+                            )))),
+                        }
+                    }
+                    _ => Some(Err(keramics_core::error_trace_new!(format!(
+                        "Unable to decode Windows 950: 0x{:02x}, 0x{:02x} as Unicode",
+                        *byte_value, additional_byte_value
+                    )))),
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+/// Windows 950 encoder.
+pub struct EncoderWindows950<'a> {
+    /// Unicode code points.
+    code_points: &'a [u32],
+
+    /// Unicode code point index.
+    code_point_index: usize,
+}
+
+impl<'a> EncoderWindows950<'a> {
+    const BASE_0X00A0: [Option<&'static [u8]>; 88] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xc2]),
+        Some(&[0xa2, 0x58]),
+        Some(&[0xa1, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0x50]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd2]),
+    ];
+
+    const BASE_0X02C0: [Option<&'static [u8]>; 32] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa3, 0xbe]),
+        None,
+        Some(&[0xa3, 0xbc]),
+        Some(&[0xa3, 0xbd]),
+        Some(&[0xa3, 0xbf]),
+        None,
+        Some(&[0xa1, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa3, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X0390: [Option<&'static [u8]>; 200] = [
+        None,
+        Some(&[0xa3, 0x44]),
+        Some(&[0xa3, 0x45]),
+        Some(&[0xa3, 0x46]),
+        Some(&[0xa3, 0x47]),
+        Some(&[0xa3, 0x48]),
+        Some(&[0xa3, 0x49]),
+        Some(&[0xa3, 0x4a]),
+        Some(&[0xa3, 0x4b]),
+        Some(&[0xa3, 0x4c]),
+        Some(&[0xa3, 0x4d]),
+        Some(&[0xa3, 0x4e]),
+        Some(&[0xa3, 0x4f]),
+        Some(&[0xa3, 0x50]),
+        Some(&[0xa3, 0x51]),
+        Some(&[0xa3, 0x52]),
+        Some(&[0xa3, 0x53]),
+        Some(&[0xa3, 0x54]),
+        None,
+        Some(&[0xa3, 0x55]),
+        Some(&[0xa3, 0x56]),
+        Some(&[0xa3, 0x57]),
+        Some(&[0xa3, 0x58]),
+        Some(&[0xa3, 0x59]),
+        Some(&[0xa3, 0x5a]),
+        Some(&[0xa3, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa3, 0x5c]),
+        Some(&[0xa3, 0x5d]),
+        Some(&[0xa3, 0x5e]),
+        Some(&[0xa3, 0x5f]),
+        Some(&[0xa3, 0x60]),
+        Some(&[0xa3, 0x61]),
+        Some(&[0xa3, 0x62]),
+        Some(&[0xa3, 0x63]),
+        Some(&[0xa3, 0x64]),
+        Some(&[0xa3, 0x65]),
+        Some(&[0xa3, 0x66]),
+        Some(&[0xa3, 0x67]),
+        Some(&[0xa3, 0x68]),
+        Some(&[0xa3, 0x69]),
+        Some(&[0xa3, 0x6a]),
+        Some(&[0xa3, 0x6b]),
+        Some(&[0xa3, 0x6c]),
+        None,
+        Some(&[0xa3, 0x6d]),
+        Some(&[0xa3, 0x6e]),
+        Some(&[0xa3, 0x6f]),
+        Some(&[0xa3, 0x70]),
+        Some(&[0xa3, 0x71]),
+        Some(&[0xa3, 0x72]),
+        Some(&[0xa3, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xb1]),
+        Some(&[0xc7, 0xb2]),
+        Some(&[0xc7, 0xb4]),
+        Some(&[0xc7, 0xb5]),
+        Some(&[0xc7, 0xb6]),
+        Some(&[0xc7, 0xb7]),
+        Some(&[0xc7, 0xb8]),
+        Some(&[0xc7, 0xb9]),
+        Some(&[0xc7, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xbb]),
+        Some(&[0xc7, 0xbc]),
+        Some(&[0xc7, 0xbd]),
+        Some(&[0xc7, 0xbe]),
+        Some(&[0xc7, 0xbf]),
+        Some(&[0xc7, 0xc0]),
+        Some(&[0xc7, 0xc1]),
+        Some(&[0xc7, 0xc2]),
+        Some(&[0xc7, 0xc3]),
+        Some(&[0xc7, 0xc4]),
+        Some(&[0xc7, 0xc5]),
+        Some(&[0xc7, 0xc6]),
+        Some(&[0xc7, 0xc7]),
+        Some(&[0xc7, 0xc8]),
+        Some(&[0xc7, 0xc9]),
+        Some(&[0xc7, 0xca]),
+        Some(&[0xc7, 0xcb]),
+        Some(&[0xc7, 0xcc]),
+        Some(&[0xc7, 0xcd]),
+        Some(&[0xc7, 0xcf]),
+        Some(&[0xc7, 0xd0]),
+        Some(&[0xc7, 0xd1]),
+        Some(&[0xc7, 0xd2]),
+        Some(&[0xc7, 0xd3]),
+        Some(&[0xc7, 0xd4]),
+        Some(&[0xc7, 0xd5]),
+        Some(&[0xc7, 0xd6]),
+        Some(&[0xc7, 0xd7]),
+        Some(&[0xc7, 0xd8]),
+        Some(&[0xc7, 0xd9]),
+        Some(&[0xc7, 0xda]),
+        Some(&[0xc7, 0xdb]),
+        Some(&[0xc7, 0xdc]),
+        Some(&[0xc7, 0xdd]),
+        Some(&[0xc7, 0xde]),
+        Some(&[0xc7, 0xdf]),
+        Some(&[0xc7, 0xe0]),
+        Some(&[0xc7, 0xe1]),
+        Some(&[0xc7, 0xe2]),
+        Some(&[0xc7, 0xe3]),
+        Some(&[0xc7, 0xe4]),
+        Some(&[0xc7, 0xe5]),
+        Some(&[0xc7, 0xe6]),
+        Some(&[0xc7, 0xe7]),
+        Some(&[0xc7, 0xe8]),
+        None,
+        Some(&[0xc7, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2010: [Option<&'static [u8]>; 48] = [
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0x56]),
+        Some(&[0xa1, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xa5]),
+        Some(&[0xa1, 0xa6]),
+        None,
+        None,
+        Some(&[0xa1, 0xa7]),
+        Some(&[0xa1, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0x4c]),
+        Some(&[0xa1, 0x4b]),
+        Some(&[0xa1, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xac]),
+        None,
+        None,
+        Some(&[0xa1, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2100: [Option<&'static [u8]>; 16] = [
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x4a]),
+        None,
+        Some(&[0xa1, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2160: [Option<&'static [u8]>; 64] = [
+        Some(&[0xa2, 0xb9]),
+        Some(&[0xa2, 0xba]),
+        Some(&[0xa2, 0xbb]),
+        Some(&[0xa2, 0xbc]),
+        Some(&[0xa2, 0xbd]),
+        Some(&[0xa2, 0xbe]),
+        Some(&[0xa2, 0xbf]),
+        Some(&[0xa2, 0xc0]),
+        Some(&[0xa2, 0xc1]),
+        Some(&[0xa2, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf6]),
+        Some(&[0xa1, 0xf4]),
+        Some(&[0xa1, 0xf7]),
+        Some(&[0xa1, 0xf5]),
+        None,
+        None,
+        Some(&[0xa1, 0xf8]),
+        Some(&[0xa1, 0xf9]),
+        Some(&[0xa1, 0xfb]),
+        Some(&[0xa1, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X2210: [Option<&'static [u8]>; 176] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xdb]),
+        Some(&[0xa1, 0xe8]),
+        Some(&[0xa1, 0xe7]),
+        None,
+        None,
+        Some(&[0xa1, 0xfd]),
+        None,
+        Some(&[0xa1, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xe4]),
+        Some(&[0xa1, 0xe5]),
+        Some(&[0xa1, 0xec]),
+        None,
+        None,
+        Some(&[0xa1, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xef]),
+        Some(&[0xa1, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xda]),
+        Some(&[0xa1, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xd8]),
+        Some(&[0xa1, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf2]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xe6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xe9]),
+    ];
+
+    const BASE_0X2460: [Option<&'static [u8]>; 32] = [
+        Some(&[0xc7, 0xe9]),
+        Some(&[0xc7, 0xea]),
+        Some(&[0xc7, 0xeb]),
+        Some(&[0xc7, 0xec]),
+        Some(&[0xc7, 0xed]),
+        Some(&[0xc7, 0xee]),
+        Some(&[0xc7, 0xef]),
+        Some(&[0xc7, 0xf0]),
+        Some(&[0xc7, 0xf1]),
+        Some(&[0xc7, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc7, 0xf3]),
+        Some(&[0xc7, 0xf4]),
+        Some(&[0xc7, 0xf5]),
+        Some(&[0xc7, 0xf6]),
+        Some(&[0xc7, 0xf7]),
+        Some(&[0xc7, 0xf8]),
+        Some(&[0xc7, 0xf9]),
+        Some(&[0xc7, 0xfa]),
+        Some(&[0xc7, 0xfb]),
+        Some(&[0xc7, 0xfc]),
+        None,
+        None,
+    ];
+
+    const BASE_0X2500: [Option<&'static [u8]>; 328] = [
+        Some(&[0xa2, 0x77]),
+        None,
+        Some(&[0xa2, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x7a]),
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x7d]),
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0xa4]),
+        Some(&[0xf9, 0xf8]),
+        Some(&[0xf9, 0xe6]),
+        Some(&[0xf9, 0xef]),
+        Some(&[0xf9, 0xdd]),
+        Some(&[0xf9, 0xe8]),
+        Some(&[0xf9, 0xf1]),
+        Some(&[0xf9, 0xdf]),
+        Some(&[0xf9, 0xec]),
+        Some(&[0xf9, 0xf5]),
+        Some(&[0xf9, 0xe3]),
+        Some(&[0xf9, 0xee]),
+        Some(&[0xf9, 0xf7]),
+        Some(&[0xf9, 0xe5]),
+        Some(&[0xa2, 0xa5]),
+        Some(&[0xf9, 0xf2]),
+        Some(&[0xf9, 0xe0]),
+        Some(&[0xa2, 0xa7]),
+        Some(&[0xf9, 0xf4]),
+        Some(&[0xf9, 0xe2]),
+        Some(&[0xf9, 0xe7]),
+        Some(&[0xf9, 0xf0]),
+        Some(&[0xf9, 0xde]),
+        Some(&[0xf9, 0xed]),
+        Some(&[0xf9, 0xf6]),
+        Some(&[0xf9, 0xe4]),
+        Some(&[0xa2, 0xa6]),
+        Some(&[0xf9, 0xf3]),
+        Some(&[0xf9, 0xe1]),
+        Some(&[0xa2, 0x7e]),
+        Some(&[0xa2, 0xa1]),
+        Some(&[0xa2, 0xa3]),
+        Some(&[0xa2, 0xa2]),
+        Some(&[0xa2, 0xac]),
+        Some(&[0xa2, 0xad]),
+        Some(&[0xa2, 0xae]),
+        Some(&[0xa1, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x62]),
+        Some(&[0xa2, 0x63]),
+        Some(&[0xa2, 0x64]),
+        Some(&[0xa2, 0x65]),
+        Some(&[0xa2, 0x66]),
+        Some(&[0xa2, 0x67]),
+        Some(&[0xa2, 0x68]),
+        Some(&[0xa2, 0x69]),
+        Some(&[0xa2, 0x70]),
+        Some(&[0xa2, 0x6f]),
+        Some(&[0xa2, 0x6e]),
+        Some(&[0xa2, 0x6d]),
+        Some(&[0xa2, 0x6c]),
+        Some(&[0xa2, 0x6b]),
+        Some(&[0xa2, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xf9, 0xfe]),
+        Some(&[0xa2, 0x76]),
+        Some(&[0xa2, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xbd]),
+        Some(&[0xa1, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xb6]),
+        Some(&[0xa1, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xbf]),
+        Some(&[0xa1, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xbb]),
+        Some(&[0xa1, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xb3]),
+        None,
+        None,
+        Some(&[0xa1, 0xb7]),
+        Some(&[0xa1, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0xa8]),
+        Some(&[0xa2, 0xa9]),
+        Some(&[0xa2, 0xab]),
+        Some(&[0xa2, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xb9]),
+        Some(&[0xa1, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xf0]),
+        None,
+        Some(&[0xa1, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3000: [Option<&'static [u8]>; 304] = [
+        Some(&[0xa1, 0x40]),
+        Some(&[0xa1, 0x42]),
+        Some(&[0xa1, 0x43]),
+        Some(&[0xa1, 0xb2]),
+        None,
+        Some(&[0xc6, 0xa4]),
+        None,
+        None,
+        Some(&[0xa1, 0x71]),
+        Some(&[0xa1, 0x72]),
+        Some(&[0xa1, 0x6d]),
+        Some(&[0xa1, 0x6e]),
+        Some(&[0xa1, 0x75]),
+        Some(&[0xa1, 0x76]),
+        Some(&[0xa1, 0x79]),
+        Some(&[0xa1, 0x7a]),
+        Some(&[0xa1, 0x69]),
+        Some(&[0xa1, 0x6a]),
+        Some(&[0xa2, 0x45]),
+        None,
+        Some(&[0xa1, 0x65]),
+        Some(&[0xa1, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xa9]),
+        Some(&[0xa1, 0xaa]),
+        None,
+        None,
+        Some(&[0xa2, 0xc3]),
+        Some(&[0xa2, 0xc4]),
+        Some(&[0xa2, 0xc5]),
+        Some(&[0xa2, 0xc6]),
+        Some(&[0xa2, 0xc7]),
+        Some(&[0xa2, 0xc8]),
+        Some(&[0xa2, 0xc9]),
+        Some(&[0xa2, 0xca]),
+        Some(&[0xa2, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa5]),
+        Some(&[0xc6, 0xa6]),
+        Some(&[0xc6, 0xa7]),
+        Some(&[0xc6, 0xa8]),
+        Some(&[0xc6, 0xa9]),
+        Some(&[0xc6, 0xaa]),
+        Some(&[0xc6, 0xab]),
+        Some(&[0xc6, 0xac]),
+        Some(&[0xc6, 0xad]),
+        Some(&[0xc6, 0xae]),
+        Some(&[0xc6, 0xaf]),
+        Some(&[0xc6, 0xb0]),
+        Some(&[0xc6, 0xb1]),
+        Some(&[0xc6, 0xb2]),
+        Some(&[0xc6, 0xb3]),
+        Some(&[0xc6, 0xb4]),
+        Some(&[0xc6, 0xb5]),
+        Some(&[0xc6, 0xb6]),
+        Some(&[0xc6, 0xb7]),
+        Some(&[0xc6, 0xb8]),
+        Some(&[0xc6, 0xb9]),
+        Some(&[0xc6, 0xba]),
+        Some(&[0xc6, 0xbb]),
+        Some(&[0xc6, 0xbc]),
+        Some(&[0xc6, 0xbd]),
+        Some(&[0xc6, 0xbe]),
+        Some(&[0xc6, 0xbf]),
+        Some(&[0xc6, 0xc0]),
+        Some(&[0xc6, 0xc1]),
+        Some(&[0xc6, 0xc2]),
+        Some(&[0xc6, 0xc3]),
+        Some(&[0xc6, 0xc4]),
+        Some(&[0xc6, 0xc5]),
+        Some(&[0xc6, 0xc6]),
+        Some(&[0xc6, 0xc7]),
+        Some(&[0xc6, 0xc8]),
+        Some(&[0xc6, 0xc9]),
+        Some(&[0xc6, 0xca]),
+        Some(&[0xc6, 0xcb]),
+        Some(&[0xc6, 0xcc]),
+        Some(&[0xc6, 0xcd]),
+        Some(&[0xc6, 0xce]),
+        Some(&[0xc6, 0xcf]),
+        Some(&[0xc6, 0xd0]),
+        Some(&[0xc6, 0xd1]),
+        Some(&[0xc6, 0xd2]),
+        Some(&[0xc6, 0xd3]),
+        Some(&[0xc6, 0xd4]),
+        Some(&[0xc6, 0xd5]),
+        Some(&[0xc6, 0xd6]),
+        Some(&[0xc6, 0xd7]),
+        Some(&[0xc6, 0xd8]),
+        Some(&[0xc6, 0xd9]),
+        Some(&[0xc6, 0xda]),
+        Some(&[0xc6, 0xdb]),
+        Some(&[0xc6, 0xdc]),
+        Some(&[0xc6, 0xdd]),
+        Some(&[0xc6, 0xde]),
+        Some(&[0xc6, 0xdf]),
+        Some(&[0xc6, 0xe0]),
+        Some(&[0xc6, 0xe1]),
+        Some(&[0xc6, 0xe2]),
+        Some(&[0xc6, 0xe3]),
+        Some(&[0xc6, 0xe4]),
+        Some(&[0xc6, 0xe5]),
+        Some(&[0xc6, 0xe6]),
+        Some(&[0xc6, 0xe7]),
+        Some(&[0xc6, 0xe8]),
+        Some(&[0xc6, 0xe9]),
+        Some(&[0xc6, 0xea]),
+        Some(&[0xc6, 0xeb]),
+        Some(&[0xc6, 0xec]),
+        Some(&[0xc6, 0xed]),
+        Some(&[0xc6, 0xee]),
+        Some(&[0xc6, 0xef]),
+        Some(&[0xc6, 0xf0]),
+        Some(&[0xc6, 0xf1]),
+        Some(&[0xc6, 0xf2]),
+        Some(&[0xc6, 0xf3]),
+        Some(&[0xc6, 0xf4]),
+        Some(&[0xc6, 0xf5]),
+        Some(&[0xc6, 0xf6]),
+        Some(&[0xc6, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa2]),
+        Some(&[0xc6, 0xa3]),
+        None,
+        None,
+        Some(&[0xc6, 0xf8]),
+        Some(&[0xc6, 0xf9]),
+        Some(&[0xc6, 0xfa]),
+        Some(&[0xc6, 0xfb]),
+        Some(&[0xc6, 0xfc]),
+        Some(&[0xc6, 0xfd]),
+        Some(&[0xc6, 0xfe]),
+        Some(&[0xc7, 0x40]),
+        Some(&[0xc7, 0x41]),
+        Some(&[0xc7, 0x42]),
+        Some(&[0xc7, 0x43]),
+        Some(&[0xc7, 0x44]),
+        Some(&[0xc7, 0x45]),
+        Some(&[0xc7, 0x46]),
+        Some(&[0xc7, 0x47]),
+        Some(&[0xc7, 0x48]),
+        Some(&[0xc7, 0x49]),
+        Some(&[0xc7, 0x4a]),
+        Some(&[0xc7, 0x4b]),
+        Some(&[0xc7, 0x4c]),
+        Some(&[0xc7, 0x4d]),
+        Some(&[0xc7, 0x4e]),
+        Some(&[0xc7, 0x4f]),
+        Some(&[0xc7, 0x50]),
+        Some(&[0xc7, 0x51]),
+        Some(&[0xc7, 0x52]),
+        Some(&[0xc7, 0x53]),
+        Some(&[0xc7, 0x54]),
+        Some(&[0xc7, 0x55]),
+        Some(&[0xc7, 0x56]),
+        Some(&[0xc7, 0x57]),
+        Some(&[0xc7, 0x58]),
+        Some(&[0xc7, 0x59]),
+        Some(&[0xc7, 0x5a]),
+        Some(&[0xc7, 0x5b]),
+        Some(&[0xc7, 0x5c]),
+        Some(&[0xc7, 0x5d]),
+        Some(&[0xc7, 0x5e]),
+        Some(&[0xc7, 0x5f]),
+        Some(&[0xc7, 0x60]),
+        Some(&[0xc7, 0x61]),
+        Some(&[0xc7, 0x62]),
+        Some(&[0xc7, 0x63]),
+        Some(&[0xc7, 0x64]),
+        Some(&[0xc7, 0x65]),
+        Some(&[0xc7, 0x66]),
+        Some(&[0xc7, 0x67]),
+        Some(&[0xc7, 0x68]),
+        Some(&[0xc7, 0x69]),
+        Some(&[0xc7, 0x6a]),
+        Some(&[0xc7, 0x6b]),
+        Some(&[0xc7, 0x6c]),
+        Some(&[0xc7, 0x6d]),
+        Some(&[0xc7, 0x6e]),
+        Some(&[0xc7, 0x6f]),
+        Some(&[0xc7, 0x70]),
+        Some(&[0xc7, 0x71]),
+        Some(&[0xc7, 0x72]),
+        Some(&[0xc7, 0x73]),
+        Some(&[0xc7, 0x74]),
+        Some(&[0xc7, 0x75]),
+        Some(&[0xc7, 0x76]),
+        Some(&[0xc7, 0x77]),
+        Some(&[0xc7, 0x78]),
+        Some(&[0xc7, 0x79]),
+        Some(&[0xc7, 0x7a]),
+        Some(&[0xc7, 0x7b]),
+        Some(&[0xc7, 0x7c]),
+        Some(&[0xc7, 0x7d]),
+        Some(&[0xc7, 0x7e]),
+        Some(&[0xc7, 0xa1]),
+        Some(&[0xc7, 0xa2]),
+        Some(&[0xc7, 0xa3]),
+        Some(&[0xc7, 0xa4]),
+        Some(&[0xc7, 0xa5]),
+        Some(&[0xc7, 0xa6]),
+        Some(&[0xc7, 0xa7]),
+        Some(&[0xc7, 0xa8]),
+        Some(&[0xc7, 0xa9]),
+        Some(&[0xc7, 0xaa]),
+        Some(&[0xc7, 0xab]),
+        Some(&[0xc7, 0xac]),
+        Some(&[0xc7, 0xad]),
+        Some(&[0xc7, 0xae]),
+        Some(&[0xc7, 0xaf]),
+        Some(&[0xc7, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa3, 0x74]),
+        Some(&[0xa3, 0x75]),
+        Some(&[0xa3, 0x76]),
+        Some(&[0xa3, 0x77]),
+        Some(&[0xa3, 0x78]),
+        Some(&[0xa3, 0x79]),
+        Some(&[0xa3, 0x7a]),
+        Some(&[0xa3, 0x7b]),
+        Some(&[0xa3, 0x7c]),
+        Some(&[0xa3, 0x7d]),
+        Some(&[0xa3, 0x7e]),
+        Some(&[0xa3, 0xa1]),
+        Some(&[0xa3, 0xa2]),
+        Some(&[0xa3, 0xa3]),
+        Some(&[0xa3, 0xa4]),
+        Some(&[0xa3, 0xa5]),
+        Some(&[0xa3, 0xa6]),
+        Some(&[0xa3, 0xa7]),
+        Some(&[0xa3, 0xa8]),
+        Some(&[0xa3, 0xa9]),
+        Some(&[0xa3, 0xaa]),
+        Some(&[0xa3, 0xab]),
+        Some(&[0xa3, 0xac]),
+        Some(&[0xa3, 0xad]),
+        Some(&[0xa3, 0xae]),
+        Some(&[0xa3, 0xaf]),
+        Some(&[0xa3, 0xb0]),
+        Some(&[0xa3, 0xb1]),
+        Some(&[0xa3, 0xb2]),
+        Some(&[0xa3, 0xb3]),
+        Some(&[0xa3, 0xb4]),
+        Some(&[0xa3, 0xb5]),
+        Some(&[0xa3, 0xb6]),
+        Some(&[0xa3, 0xb7]),
+        Some(&[0xa3, 0xb8]),
+        Some(&[0xa3, 0xb9]),
+        Some(&[0xa3, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X3388: [Option<&'static [u8]>; 80] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x55]),
+        Some(&[0xa2, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x50]),
+        Some(&[0xa2, 0x51]),
+        Some(&[0xa2, 0x52]),
+        None,
+        None,
+        Some(&[0xa2, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa2, 0x53]),
+        None,
+        None,
+        Some(&[0xa1, 0xeb]),
+        Some(&[0xa1, 0xea]),
+        None,
+        None,
+        Some(&[0xa2, 0x4f]),
+        None,
+        None,
+    ];
+
+    const BASE_0X4E00: [Option<&'static [u8]>; 12448] = [
+        Some(&[0xa4, 0x40]),
+        Some(&[0xa4, 0x42]),
+        None,
+        Some(&[0xa4, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x45]),
+        Some(&[0xa4, 0x56]),
+        Some(&[0xa4, 0x54]),
+        Some(&[0xa4, 0x57]),
+        Some(&[0xa4, 0x55]),
+        Some(&[0xc9, 0x46]),
+        Some(&[0xa4, 0xa3]),
+        Some(&[0xc9, 0x4f]),
+        Some(&[0xc9, 0x4d]),
+        Some(&[0xa4, 0xa2]),
+        Some(&[0xa4, 0xa1]),
+        None,
+        None,
+        Some(&[0xa5, 0x42]),
+        Some(&[0xa5, 0x41]),
+        Some(&[0xa5, 0x40]),
+        None,
+        Some(&[0xa5, 0x43]),
+        Some(&[0xa4, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xe0]),
+        Some(&[0xa5, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0x58]),
+        None,
+        Some(&[0xa4, 0xa4]),
+        Some(&[0xc9, 0x50]),
+        None,
+        Some(&[0xa4, 0xa5]),
+        Some(&[0xc9, 0x63]),
+        Some(&[0xa6, 0xea]),
+        Some(&[0xcb, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0x59]),
+        Some(&[0xa4, 0xa6]),
+        None,
+        Some(&[0xa5, 0x44]),
+        Some(&[0xc9, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x40]),
+        Some(&[0xa4, 0x44]),
+        None,
+        Some(&[0xa4, 0x5b]),
+        None,
+        Some(&[0xc9, 0x47]),
+        Some(&[0xa4, 0x5c]),
+        None,
+        None,
+        Some(&[0xa4, 0xa7]),
+        None,
+        Some(&[0xa5, 0x45]),
+        Some(&[0xa5, 0x47]),
+        Some(&[0xa5, 0x46]),
+        None,
+        None,
+        Some(&[0xa5, 0xe2]),
+        Some(&[0xa5, 0xe3]),
+        None,
+        None,
+        Some(&[0xa8, 0xc4]),
+        None,
+        Some(&[0xad, 0xbc]),
+        Some(&[0xa4, 0x41]),
+        None,
+        None,
+        Some(&[0xc9, 0x41]),
+        Some(&[0xa4, 0x45]),
+        Some(&[0xa4, 0x5e]),
+        Some(&[0xa4, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xae]),
+        Some(&[0xd4, 0x4b]),
+        None,
+        None,
+        Some(&[0xb6, 0xc3]),
+        Some(&[0xdc, 0xb1]),
+        Some(&[0xdc, 0xb2]),
+        None,
+        Some(&[0xa4, 0x46]),
+        None,
+        Some(&[0xa4, 0xa9]),
+        None,
+        None,
+        Some(&[0xa8, 0xc6]),
+        Some(&[0xa4, 0x47]),
+        Some(&[0xc9, 0x48]),
+        Some(&[0xa4, 0x5f]),
+        None,
+        None,
+        Some(&[0xa4, 0xaa]),
+        Some(&[0xa4, 0xac]),
+        Some(&[0xc9, 0x51]),
+        Some(&[0xa4, 0xad]),
+        Some(&[0xa4, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xe5]),
+        None,
+        Some(&[0xa8, 0xc7]),
+        None,
+        None,
+        Some(&[0xa8, 0xc8]),
+        Some(&[0xab, 0x45]),
+        None,
+        Some(&[0xa4, 0x60]),
+        Some(&[0xa4, 0xae]),
+        None,
+        Some(&[0xa5, 0xe6]),
+        Some(&[0xa5, 0xe8]),
+        Some(&[0xa5, 0xe7]),
+        None,
+        Some(&[0xa6, 0xeb]),
+        None,
+        None,
+        Some(&[0xa8, 0xc9]),
+        Some(&[0xa8, 0xca]),
+        Some(&[0xab, 0x46]),
+        Some(&[0xab, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xbd]),
+        None,
+        None,
+        Some(&[0xdc, 0xb3]),
+        None,
+        None,
+        Some(&[0xf6, 0xd6]),
+        Some(&[0xa4, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0xb0]),
+        Some(&[0xa4, 0xaf]),
+        Some(&[0xc9, 0x52]),
+        Some(&[0xa4, 0xb1]),
+        Some(&[0xa4, 0xb7]),
+        None,
+        Some(&[0xa4, 0xb2]),
+        Some(&[0xa4, 0xb3]),
+        Some(&[0xc9, 0x54]),
+        Some(&[0xc9, 0x53]),
+        Some(&[0xa4, 0xb5]),
+        Some(&[0xa4, 0xb6]),
+        None,
+        Some(&[0xa4, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0x4a]),
+        Some(&[0xa5, 0x4b]),
+        Some(&[0xa5, 0x4c]),
+        Some(&[0xa5, 0x4d]),
+        Some(&[0xa5, 0x49]),
+        Some(&[0xa5, 0x50]),
+        Some(&[0xc9, 0x6a]),
+        None,
+        Some(&[0xc9, 0x66]),
+        Some(&[0xc9, 0x69]),
+        Some(&[0xa5, 0x51]),
+        Some(&[0xa5, 0x61]),
+        None,
+        Some(&[0xc9, 0x68]),
+        None,
+        Some(&[0xa5, 0x4e]),
+        Some(&[0xa5, 0x4f]),
+        Some(&[0xa5, 0x48]),
+        None,
+        None,
+        Some(&[0xc9, 0x65]),
+        Some(&[0xc9, 0x67]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xf5]),
+        Some(&[0xc9, 0xb0]),
+        Some(&[0xa5, 0xf2]),
+        Some(&[0xa5, 0xf6]),
+        Some(&[0xc9, 0xba]),
+        Some(&[0xc9, 0xae]),
+        Some(&[0xa5, 0xf3]),
+        Some(&[0xc9, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0xf4]),
+        None,
+        Some(&[0xa5, 0xf7]),
+        None,
+        Some(&[0xa5, 0xe9]),
+        Some(&[0xc9, 0xb1]),
+        Some(&[0xa5, 0xf8]),
+        Some(&[0xc9, 0xb5]),
+        None,
+        Some(&[0xc9, 0xb9]),
+        Some(&[0xc9, 0xb6]),
+        None,
+        None,
+        Some(&[0xc9, 0xb3]),
+        Some(&[0xa5, 0xea]),
+        Some(&[0xa5, 0xec]),
+        Some(&[0xa5, 0xf9]),
+        None,
+        Some(&[0xa5, 0xee]),
+        Some(&[0xc9, 0xab]),
+        Some(&[0xa5, 0xf1]),
+        Some(&[0xa5, 0xef]),
+        Some(&[0xa5, 0xf0]),
+        Some(&[0xc9, 0xbb]),
+        Some(&[0xc9, 0xb8]),
+        Some(&[0xc9, 0xaf]),
+        Some(&[0xa5, 0xed]),
+        None,
+        None,
+        Some(&[0xc9, 0xac]),
+        Some(&[0xa5, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xad]),
+        Some(&[0xca, 0x66]),
+        None,
+        Some(&[0xa7, 0x42]),
+        Some(&[0xa6, 0xf4]),
+        None,
+        None,
+        Some(&[0xca, 0x67]),
+        Some(&[0xa6, 0xf1]),
+        None,
+        Some(&[0xa7, 0x44]),
+        None,
+        Some(&[0xa6, 0xf9]),
+        None,
+        Some(&[0xa6, 0xf8]),
+        Some(&[0xca, 0x5b]),
+        Some(&[0xa6, 0xfc]),
+        Some(&[0xa6, 0xf7]),
+        Some(&[0xca, 0x60]),
+        Some(&[0xca, 0x68]),
+        None,
+        Some(&[0xca, 0x64]),
+        None,
+        Some(&[0xa6, 0xfa]),
+        None,
+        None,
+        Some(&[0xa6, 0xfd]),
+        Some(&[0xa6, 0xee]),
+        Some(&[0xa7, 0x47]),
+        Some(&[0xca, 0x5d]),
+        None,
+        None,
+        Some(&[0xcb, 0xbd]),
+        Some(&[0xa6, 0xec]),
+        Some(&[0xa7, 0x43]),
+        Some(&[0xa6, 0xed]),
+        Some(&[0xa6, 0xf5]),
+        Some(&[0xa6, 0xf6]),
+        Some(&[0xca, 0x62]),
+        Some(&[0xca, 0x5e]),
+        Some(&[0xa6, 0xfb]),
+        Some(&[0xa6, 0xf3]),
+        Some(&[0xca, 0x5a]),
+        Some(&[0xa6, 0xef]),
+        Some(&[0xca, 0x65]),
+        Some(&[0xa7, 0x45]),
+        Some(&[0xa7, 0x48]),
+        Some(&[0xa6, 0xf2]),
+        Some(&[0xa7, 0x40]),
+        Some(&[0xa7, 0x46]),
+        Some(&[0xa6, 0xf0]),
+        Some(&[0xca, 0x63]),
+        Some(&[0xa7, 0x41]),
+        Some(&[0xca, 0x69]),
+        Some(&[0xca, 0x5c]),
+        Some(&[0xa6, 0xfe]),
+        Some(&[0xca, 0x5f]),
+        None,
+        None,
+        Some(&[0xca, 0x61]),
+        None,
+        Some(&[0xa8, 0xd8]),
+        Some(&[0xcb, 0xbf]),
+        Some(&[0xcb, 0xcb]),
+        Some(&[0xa8, 0xd0]),
+        None,
+        Some(&[0xcb, 0xcc]),
+        Some(&[0xa8, 0xcb]),
+        Some(&[0xa8, 0xd5]),
+        None,
+        None,
+        Some(&[0xa8, 0xce]),
+        Some(&[0xcb, 0xb9]),
+        Some(&[0xa8, 0xd6]),
+        Some(&[0xcb, 0xb8]),
+        Some(&[0xcb, 0xbc]),
+        Some(&[0xcb, 0xc3]),
+        Some(&[0xcb, 0xc1]),
+        Some(&[0xa8, 0xde]),
+        Some(&[0xa8, 0xd9]),
+        Some(&[0xcb, 0xb3]),
+        Some(&[0xcb, 0xb5]),
+        Some(&[0xa8, 0xdb]),
+        Some(&[0xa8, 0xcf]),
+        Some(&[0xcb, 0xb6]),
+        Some(&[0xcb, 0xc2]),
+        Some(&[0xcb, 0xc9]),
+        Some(&[0xa8, 0xd4]),
+        Some(&[0xcb, 0xbb]),
+        Some(&[0xcb, 0xb4]),
+        Some(&[0xa8, 0xd3]),
+        Some(&[0xcb, 0xb7]),
+        Some(&[0xa8, 0xd7]),
+        Some(&[0xcb, 0xba]),
+        None,
+        Some(&[0xa8, 0xd2]),
+        None,
+        Some(&[0xa8, 0xcd]),
+        None,
+        Some(&[0xa8, 0xdc]),
+        Some(&[0xcb, 0xc4]),
+        Some(&[0xa8, 0xdd]),
+        Some(&[0xcb, 0xc8]),
+        None,
+        Some(&[0xcb, 0xc6]),
+        Some(&[0xcb, 0xca]),
+        Some(&[0xa8, 0xda]),
+        Some(&[0xcb, 0xbe]),
+        Some(&[0xcb, 0xb2]),
+        None,
+        Some(&[0xcb, 0xc0]),
+        Some(&[0xa8, 0xd1]),
+        Some(&[0xcb, 0xc5]),
+        Some(&[0xa8, 0xcc]),
+        Some(&[0xcb, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x56]),
+        Some(&[0xab, 0x4a]),
+        None,
+        None,
+        Some(&[0xcd, 0xe0]),
+        Some(&[0xcd, 0xe8]),
+        None,
+        Some(&[0xab, 0x49]),
+        Some(&[0xab, 0x51]),
+        Some(&[0xab, 0x5d]),
+        None,
+        Some(&[0xcd, 0xee]),
+        Some(&[0xcd, 0xec]),
+        Some(&[0xcd, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x4b]),
+        Some(&[0xcd, 0xed]),
+        Some(&[0xcd, 0xe3]),
+        Some(&[0xab, 0x59]),
+        Some(&[0xab, 0x50]),
+        Some(&[0xab, 0x58]),
+        Some(&[0xcd, 0xde]),
+        None,
+        Some(&[0xcd, 0xea]),
+        None,
+        Some(&[0xcd, 0xe1]),
+        Some(&[0xab, 0x54]),
+        Some(&[0xcd, 0xe2]),
+        None,
+        Some(&[0xcd, 0xdd]),
+        Some(&[0xab, 0x5b]),
+        Some(&[0xab, 0x4e]),
+        Some(&[0xab, 0x57]),
+        Some(&[0xab, 0x4d]),
+        None,
+        Some(&[0xcd, 0xdf]),
+        Some(&[0xcd, 0xe4]),
+        None,
+        Some(&[0xcd, 0xeb]),
+        Some(&[0xab, 0x55]),
+        Some(&[0xab, 0x52]),
+        Some(&[0xcd, 0xe6]),
+        Some(&[0xab, 0x5a]),
+        Some(&[0xcd, 0xe9]),
+        Some(&[0xcd, 0xe5]),
+        Some(&[0xab, 0x4f]),
+        Some(&[0xab, 0x5c]),
+        Some(&[0xab, 0x53]),
+        Some(&[0xab, 0x4c]),
+        Some(&[0xab, 0x48]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xef]),
+        None,
+        Some(&[0xad, 0xd7]),
+        Some(&[0xad, 0xc1]),
+        None,
+        Some(&[0xad, 0xd1]),
+        None,
+        Some(&[0xad, 0xd6]),
+        Some(&[0xd0, 0xd0]),
+        Some(&[0xd0, 0xcf]),
+        Some(&[0xd0, 0xd4]),
+        Some(&[0xd0, 0xd5]),
+        Some(&[0xad, 0xc4]),
+        None,
+        Some(&[0xad, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xda]),
+        None,
+        Some(&[0xad, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xc9]),
+        Some(&[0xad, 0xc7]),
+        Some(&[0xd0, 0xca]),
+        None,
+        Some(&[0xad, 0xdc]),
+        None,
+        Some(&[0xad, 0xd3]),
+        Some(&[0xad, 0xbe]),
+        Some(&[0xad, 0xbf]),
+        Some(&[0xd0, 0xdd]),
+        Some(&[0xb0, 0xbf]),
+        None,
+        Some(&[0xad, 0xcc]),
+        Some(&[0xad, 0xcb]),
+        Some(&[0xd0, 0xcb]),
+        Some(&[0xad, 0xcf]),
+        Some(&[0xd4, 0x5b]),
+        Some(&[0xad, 0xc6]),
+        Some(&[0xd0, 0xd6]),
+        Some(&[0xad, 0xd5]),
+        Some(&[0xad, 0xd4]),
+        Some(&[0xad, 0xca]),
+        Some(&[0xd0, 0xce]),
+        Some(&[0xd0, 0xd7]),
+        None,
+        Some(&[0xd0, 0xc8]),
+        Some(&[0xad, 0xc9]),
+        Some(&[0xd0, 0xd8]),
+        Some(&[0xad, 0xd2]),
+        Some(&[0xd0, 0xcc]),
+        Some(&[0xad, 0xc0]),
+        None,
+        Some(&[0xad, 0xc3]),
+        Some(&[0xad, 0xc2]),
+        Some(&[0xd0, 0xd9]),
+        Some(&[0xad, 0xd0]),
+        Some(&[0xad, 0xc5]),
+        Some(&[0xad, 0xd9]),
+        Some(&[0xad, 0xdb]),
+        Some(&[0xd0, 0xd3]),
+        Some(&[0xad, 0xd8]),
+        None,
+        Some(&[0xd0, 0xdb]),
+        Some(&[0xd0, 0xcd]),
+        Some(&[0xd0, 0xdc]),
+        None,
+        Some(&[0xd0, 0xd1]),
+        None,
+        Some(&[0xd0, 0xda]),
+        None,
+        Some(&[0xd0, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xc8]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0x63]),
+        Some(&[0xd4, 0x57]),
+        None,
+        Some(&[0xb0, 0xb3]),
+        None,
+        Some(&[0xd4, 0x5c]),
+        Some(&[0xd4, 0x62]),
+        Some(&[0xb0, 0xb2]),
+        Some(&[0xd4, 0x55]),
+        Some(&[0xb0, 0xb6]),
+        Some(&[0xd4, 0x59]),
+        Some(&[0xd4, 0x52]),
+        Some(&[0xb0, 0xb4]),
+        Some(&[0xd4, 0x56]),
+        Some(&[0xb0, 0xb9]),
+        Some(&[0xb0, 0xbe]),
+        None,
+        Some(&[0xd4, 0x67]),
+        None,
+        Some(&[0xd4, 0x51]),
+        None,
+        Some(&[0xb0, 0xba]),
+        None,
+        Some(&[0xd4, 0x66]),
+        None,
+        None,
+        Some(&[0xb0, 0xb5]),
+        Some(&[0xd4, 0x58]),
+        Some(&[0xb0, 0xb1]),
+        Some(&[0xd4, 0x53]),
+        Some(&[0xd4, 0x4f]),
+        Some(&[0xd4, 0x5d]),
+        Some(&[0xd4, 0x50]),
+        Some(&[0xd4, 0x4e]),
+        Some(&[0xd4, 0x5a]),
+        Some(&[0xd4, 0x60]),
+        Some(&[0xd4, 0x61]),
+        Some(&[0xb0, 0xb7]),
+        None,
+        None,
+        Some(&[0xd8, 0x5b]),
+        Some(&[0xd4, 0x5e]),
+        Some(&[0xd4, 0x4d]),
+        Some(&[0xd4, 0x5f]),
+        None,
+        Some(&[0xb0, 0xc1]),
+        Some(&[0xd4, 0x64]),
+        Some(&[0xb0, 0xc0]),
+        Some(&[0xd4, 0x4c]),
+        None,
+        Some(&[0xd4, 0x54]),
+        Some(&[0xd4, 0x65]),
+        Some(&[0xb0, 0xbc]),
+        Some(&[0xb0, 0xbb]),
+        Some(&[0xb0, 0xb8]),
+        Some(&[0xb0, 0xbd]),
+        None,
+        None,
+        Some(&[0xb0, 0xaf]),
+        None,
+        None,
+        Some(&[0xb0, 0xb0]),
+        None,
+        None,
+        Some(&[0xb3, 0xc8]),
+        None,
+        Some(&[0xd8, 0x5e]),
+        Some(&[0xd8, 0x57]),
+        None,
+        Some(&[0xb3, 0xc5]),
+        None,
+        Some(&[0xd8, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0x55]),
+        Some(&[0xd8, 0x58]),
+        Some(&[0xb3, 0xc4]),
+        Some(&[0xd8, 0x59]),
+        None,
+        None,
+        Some(&[0xb3, 0xc7]),
+        Some(&[0xd8, 0x5d]),
+        None,
+        Some(&[0xd8, 0x53]),
+        Some(&[0xd8, 0x52]),
+        Some(&[0xb3, 0xc9]),
+        None,
+        Some(&[0xb3, 0xca]),
+        Some(&[0xb3, 0xc6]),
+        Some(&[0xb3, 0xcb]),
+        Some(&[0xd8, 0x51]),
+        Some(&[0xd8, 0x5c]),
+        Some(&[0xd8, 0x5a]),
+        Some(&[0xd8, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xc3]),
+        Some(&[0xd8, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xca]),
+        Some(&[0xb6, 0xc4]),
+        Some(&[0xdc, 0xb7]),
+        Some(&[0xb6, 0xcd]),
+        Some(&[0xdc, 0xbd]),
+        Some(&[0xdc, 0xc0]),
+        Some(&[0xb6, 0xc6]),
+        Some(&[0xb6, 0xc7]),
+        Some(&[0xdc, 0xba]),
+        Some(&[0xb6, 0xc5]),
+        Some(&[0xdc, 0xc3]),
+        Some(&[0xb6, 0xcb]),
+        Some(&[0xdc, 0xc4]),
+        None,
+        Some(&[0xdc, 0xbf]),
+        Some(&[0xb6, 0xcc]),
+        None,
+        Some(&[0xdc, 0xb4]),
+        Some(&[0xb6, 0xc9]),
+        Some(&[0xdc, 0xb5]),
+        None,
+        Some(&[0xdc, 0xbe]),
+        Some(&[0xdc, 0xbc]),
+        None,
+        Some(&[0xdc, 0xb8]),
+        Some(&[0xb6, 0xc8]),
+        Some(&[0xdc, 0xb6]),
+        Some(&[0xb6, 0xce]),
+        Some(&[0xdc, 0xbb]),
+        Some(&[0xdc, 0xc2]),
+        Some(&[0xdc, 0xb9]),
+        Some(&[0xdc, 0xc1]),
+        None,
+        None,
+        Some(&[0xb9, 0xb6]),
+        Some(&[0xb9, 0xb3]),
+        None,
+        Some(&[0xb9, 0xb4]),
+        None,
+        Some(&[0xe0, 0xf9]),
+        Some(&[0xe0, 0xf1]),
+        Some(&[0xb9, 0xb2]),
+        Some(&[0xb9, 0xaf]),
+        Some(&[0xe0, 0xf2]),
+        None,
+        None,
+        Some(&[0xb9, 0xb1]),
+        Some(&[0xe0, 0xf5]),
+        None,
+        Some(&[0xe0, 0xf7]),
+        None,
+        None,
+        Some(&[0xe0, 0xfe]),
+        None,
+        None,
+        Some(&[0xe0, 0xfd]),
+        Some(&[0xe0, 0xf8]),
+        Some(&[0xb9, 0xae]),
+        Some(&[0xe0, 0xf0]),
+        Some(&[0xb9, 0xac]),
+        Some(&[0xe0, 0xf3]),
+        Some(&[0xb9, 0xb7]),
+        Some(&[0xe0, 0xf6]),
+        None,
+        Some(&[0xe0, 0xfa]),
+        Some(&[0xb9, 0xb0]),
+        Some(&[0xb9, 0xad]),
+        Some(&[0xe0, 0xfc]),
+        Some(&[0xe0, 0xfb]),
+        Some(&[0xb9, 0xb5]),
+        None,
+        Some(&[0xe0, 0xf4]),
+        None,
+        Some(&[0xbb, 0xf8]),
+        Some(&[0xe4, 0xec]),
+        None,
+        Some(&[0xe4, 0xe9]),
+        Some(&[0xbb, 0xf9]),
+        None,
+        Some(&[0xbb, 0xf7]),
+        None,
+        Some(&[0xe4, 0xf0]),
+        Some(&[0xe4, 0xed]),
+        Some(&[0xe4, 0xe6]),
+        Some(&[0xbb, 0xf6]),
+        None,
+        Some(&[0xbb, 0xfa]),
+        Some(&[0xe4, 0xe7]),
+        Some(&[0xbb, 0xf5]),
+        Some(&[0xbb, 0xfd]),
+        Some(&[0xe4, 0xea]),
+        Some(&[0xe4, 0xeb]),
+        Some(&[0xbb, 0xfb]),
+        Some(&[0xbb, 0xfc]),
+        Some(&[0xe4, 0xf1]),
+        Some(&[0xe4, 0xee]),
+        Some(&[0xe4, 0xef]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xaa]),
+        Some(&[0xe8, 0xf8]),
+        Some(&[0xbe, 0xa7]),
+        Some(&[0xe8, 0xf5]),
+        Some(&[0xbe, 0xa9]),
+        Some(&[0xbe, 0xab]),
+        None,
+        Some(&[0xe8, 0xf6]),
+        Some(&[0xbe, 0xa8]),
+        None,
+        Some(&[0xe8, 0xf7]),
+        None,
+        Some(&[0xe8, 0xf4]),
+        None,
+        None,
+        Some(&[0xc0, 0x76]),
+        Some(&[0xec, 0xbd]),
+        Some(&[0xc0, 0x77]),
+        Some(&[0xec, 0xbb]),
+        None,
+        Some(&[0xec, 0xbc]),
+        Some(&[0xec, 0xba]),
+        Some(&[0xec, 0xb9]),
+        None,
+        None,
+        Some(&[0xec, 0xbe]),
+        Some(&[0xc0, 0x75]),
+        None,
+        None,
+        Some(&[0xef, 0xb8]),
+        Some(&[0xef, 0xb9]),
+        None,
+        Some(&[0xe4, 0xe8]),
+        Some(&[0xef, 0xb7]),
+        Some(&[0xc0, 0x78]),
+        Some(&[0xc3, 0x5f]),
+        Some(&[0xf1, 0xeb]),
+        Some(&[0xf1, 0xec]),
+        None,
+        Some(&[0xc4, 0xd7]),
+        Some(&[0xc4, 0xd8]),
+        Some(&[0xf5, 0xc1]),
+        Some(&[0xf5, 0xc0]),
+        Some(&[0xc5, 0x6c]),
+        Some(&[0xc5, 0x6b]),
+        Some(&[0xf7, 0xd0]),
+        None,
+        Some(&[0xa4, 0x49]),
+        Some(&[0xa4, 0x61]),
+        Some(&[0xa4, 0xb9]),
+        None,
+        Some(&[0xa4, 0xb8]),
+        Some(&[0xa5, 0x53]),
+        Some(&[0xa5, 0x52]),
+        Some(&[0xa5, 0xfc]),
+        Some(&[0xa5, 0xfb]),
+        Some(&[0xa5, 0xfd]),
+        Some(&[0xa5, 0xfa]),
+        None,
+        Some(&[0xa7, 0x4a]),
+        Some(&[0xa7, 0x49]),
+        Some(&[0xa7, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xe0]),
+        None,
+        Some(&[0xa8, 0xdf]),
+        Some(&[0xa8, 0xe1]),
+        None,
+        Some(&[0xab, 0x5e]),
+        None,
+        Some(&[0xa2, 0x59]),
+        Some(&[0xd0, 0xde]),
+        Some(&[0xa2, 0x5a]),
+        Some(&[0xb0, 0xc2]),
+        Some(&[0xa2, 0x5c]),
+        Some(&[0xa2, 0x5b]),
+        Some(&[0xd8, 0x60]),
+        None,
+        Some(&[0xa2, 0x5d]),
+        Some(&[0xb9, 0xb8]),
+        Some(&[0xa2, 0x5e]),
+        None,
+        Some(&[0xa4, 0x4a]),
+        None,
+        Some(&[0xa4, 0xba]),
+        Some(&[0xa5, 0xfe]),
+        Some(&[0xa8, 0xe2]),
+        None,
+        Some(&[0xa4, 0x4b]),
+        Some(&[0xa4, 0xbd]),
+        Some(&[0xa4, 0xbb]),
+        Some(&[0xa4, 0xbc]),
+        None,
+        None,
+        Some(&[0xa6, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0x4c]),
+        Some(&[0xa8, 0xe4]),
+        Some(&[0xa8, 0xe3]),
+        Some(&[0xa8, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x4e]),
+        None,
+        Some(&[0xa5, 0x54]),
+        Some(&[0xa5, 0x55]),
+        None,
+        None,
+        Some(&[0xa6, 0x41]),
+        None,
+        Some(&[0xca, 0x6a]),
+        None,
+        Some(&[0xab, 0x60]),
+        Some(&[0xab, 0x5f]),
+        Some(&[0xd0, 0xe0]),
+        Some(&[0xd0, 0xdf]),
+        Some(&[0xb0, 0xc3]),
+        None,
+        Some(&[0xa4, 0xbe]),
+        Some(&[0xc9, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xcd]),
+        None,
+        Some(&[0xab, 0x61]),
+        None,
+        Some(&[0xad, 0xe0]),
+        None,
+        Some(&[0xad, 0xde]),
+        Some(&[0xad, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xad]),
+        None,
+        Some(&[0xa5, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0x42]),
+        Some(&[0xc9, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0x4d]),
+        Some(&[0xa7, 0x4e]),
+        None,
+        Some(&[0xca, 0x6b]),
+        None,
+        None,
+        Some(&[0xcb, 0xce]),
+        Some(&[0xa8, 0xe6]),
+        Some(&[0xcb, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xe2]),
+        Some(&[0xd0, 0xe3]),
+        Some(&[0xad, 0xe3]),
+        None,
+        Some(&[0xd0, 0xe4]),
+        None,
+        Some(&[0xd0, 0xe1]),
+        Some(&[0xad, 0xe4]),
+        Some(&[0xad, 0xe2]),
+        Some(&[0xad, 0xe1]),
+        Some(&[0xd0, 0xe5]),
+        None,
+        Some(&[0xd4, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0x61]),
+        None,
+        None,
+        Some(&[0xdc, 0xc5]),
+        Some(&[0xe1, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xfe]),
+        Some(&[0xbe, 0xae]),
+        Some(&[0xe8, 0xf9]),
+        None,
+        Some(&[0xa4, 0x4c]),
+        Some(&[0xa4, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xc4]),
+        Some(&[0xb3, 0xcd]),
+        None,
+        Some(&[0xb9, 0xb9]),
+        None,
+        Some(&[0xc9, 0x42]),
+        Some(&[0xa4, 0xbf]),
+        None,
+        Some(&[0xa5, 0x59]),
+        Some(&[0xa5, 0x57]),
+        Some(&[0xa5, 0x58]),
+        None,
+        None,
+        Some(&[0xa8, 0xe7]),
+        None,
+        None,
+        Some(&[0xa4, 0x4d]),
+        Some(&[0xa4, 0x4e]),
+        None,
+        Some(&[0xa4, 0x62]),
+        None,
+        None,
+        Some(&[0xa4, 0xc0]),
+        Some(&[0xa4, 0xc1]),
+        Some(&[0xa4, 0xc2]),
+        Some(&[0xc9, 0xbe]),
+        Some(&[0xa5, 0x5a]),
+        None,
+        Some(&[0xc9, 0x6b]),
+        None,
+        Some(&[0xa6, 0x46]),
+        None,
+        Some(&[0xc9, 0xbf]),
+        Some(&[0xa6, 0x44]),
+        Some(&[0xa6, 0x45]),
+        Some(&[0xc9, 0xbd]),
+        None,
+        None,
+        Some(&[0xa6, 0x47]),
+        Some(&[0xa6, 0x43]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x6c]),
+        Some(&[0xaa, 0xec]),
+        Some(&[0xca, 0x6d]),
+        None,
+        None,
+        Some(&[0xca, 0x6e]),
+        None,
+        None,
+        Some(&[0xa7, 0x50]),
+        Some(&[0xa7, 0x4f]),
+        None,
+        None,
+        Some(&[0xa7, 0x53]),
+        Some(&[0xa7, 0x51]),
+        Some(&[0xa7, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xed]),
+        None,
+        Some(&[0xa8, 0xec]),
+        Some(&[0xcb, 0xd4]),
+        Some(&[0xcb, 0xd1]),
+        Some(&[0xcb, 0xd2]),
+        None,
+        Some(&[0xcb, 0xd0]),
+        Some(&[0xa8, 0xee]),
+        Some(&[0xa8, 0xea]),
+        Some(&[0xa8, 0xe9]),
+        None,
+        Some(&[0xa8, 0xeb]),
+        Some(&[0xa8, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xef]),
+        None,
+        Some(&[0xab, 0x63]),
+        Some(&[0xcd, 0xf0]),
+        None,
+        Some(&[0xcb, 0xd3]),
+        Some(&[0xab, 0x68]),
+        None,
+        Some(&[0xcd, 0xf1]),
+        Some(&[0xab, 0x64]),
+        Some(&[0xab, 0x67]),
+        Some(&[0xab, 0x66]),
+        Some(&[0xab, 0x65]),
+        Some(&[0xab, 0x62]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xe8]),
+        None,
+        Some(&[0xad, 0xe7]),
+        Some(&[0xd0, 0xeb]),
+        Some(&[0xad, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xe7]),
+        Some(&[0xad, 0xe8]),
+        Some(&[0xad, 0xe6]),
+        Some(&[0xad, 0xe9]),
+        Some(&[0xd0, 0xe9]),
+        Some(&[0xd0, 0xea]),
+        None,
+        Some(&[0xd0, 0xe6]),
+        Some(&[0xd0, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xd1]),
+        Some(&[0xb0, 0xc5]),
+        Some(&[0xd4, 0x69]),
+        Some(&[0xd4, 0x6b]),
+        Some(&[0xd4, 0x6a]),
+        Some(&[0xd4, 0x6c]),
+        Some(&[0xb0, 0xc6]),
+        None,
+        None,
+        Some(&[0xb3, 0xce]),
+        None,
+        Some(&[0xb3, 0xcf]),
+        Some(&[0xb3, 0xd0]),
+        None,
+        Some(&[0xb6, 0xd0]),
+        Some(&[0xdc, 0xc7]),
+        None,
+        Some(&[0xdc, 0xc6]),
+        Some(&[0xdc, 0xc8]),
+        Some(&[0xdc, 0xc9]),
+        Some(&[0xb6, 0xd1]),
+        None,
+        Some(&[0xb6, 0xcf]),
+        Some(&[0xe1, 0x41]),
+        Some(&[0xe1, 0x42]),
+        Some(&[0xb9, 0xbb]),
+        Some(&[0xb9, 0xba]),
+        Some(&[0xe3, 0x5a]),
+        None,
+        None,
+        Some(&[0xbc, 0x40]),
+        Some(&[0xbc, 0x41]),
+        Some(&[0xbc, 0x42]),
+        Some(&[0xbc, 0x44]),
+        Some(&[0xe4, 0xf2]),
+        Some(&[0xe4, 0xf3]),
+        Some(&[0xbc, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xaf]),
+        None,
+        Some(&[0xbe, 0xb0]),
+        None,
+        None,
+        Some(&[0xf1, 0xed]),
+        Some(&[0xf5, 0xc3]),
+        Some(&[0xf5, 0xc2]),
+        Some(&[0xf7, 0xd1]),
+        None,
+        Some(&[0xa4, 0x4f]),
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0x5c]),
+        Some(&[0xa5, 0x5b]),
+        None,
+        None,
+        Some(&[0xa6, 0x48]),
+        None,
+        None,
+        Some(&[0xc9, 0xc0]),
+        None,
+        None,
+        Some(&[0xa7, 0x55]),
+        Some(&[0xa7, 0x56]),
+        Some(&[0xa7, 0x54]),
+        Some(&[0xa7, 0x57]),
+        Some(&[0xca, 0x6f]),
+        Some(&[0xca, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xf1]),
+        Some(&[0xcb, 0xd5]),
+        None,
+        Some(&[0xa8, 0xf0]),
+        None,
+        Some(&[0xcd, 0xf2]),
+        Some(&[0xab, 0x6c]),
+        Some(&[0xcd, 0xf3]),
+        Some(&[0xab, 0x6b]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x69]),
+        None,
+        Some(&[0xab, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xc7]),
+        Some(&[0xd4, 0x6e]),
+        None,
+        Some(&[0xb0, 0xca]),
+        Some(&[0xd4, 0x6d]),
+        Some(&[0xb1, 0xe5]),
+        Some(&[0xb0, 0xc9]),
+        Some(&[0xb0, 0xc8]),
+        None,
+        Some(&[0xb3, 0xd4]),
+        None,
+        Some(&[0xb3, 0xd3]),
+        Some(&[0xb3, 0xd2]),
+        Some(&[0xb6, 0xd2]),
+        None,
+        None,
+        Some(&[0xb6, 0xd5]),
+        Some(&[0xb6, 0xd6]),
+        Some(&[0xb6, 0xd4]),
+        None,
+        Some(&[0xb6, 0xd3]),
+        None,
+        None,
+        Some(&[0xe1, 0x43]),
+        None,
+        Some(&[0xe1, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xf5]),
+        Some(&[0xbc, 0x45]),
+        Some(&[0xe4, 0xf4]),
+        None,
+        Some(&[0xbe, 0xb1]),
+        Some(&[0xec, 0xbf]),
+        Some(&[0xc0, 0x79]),
+        None,
+        Some(&[0xf1, 0xee]),
+        Some(&[0xc4, 0x55]),
+        None,
+        Some(&[0xa4, 0x63]),
+        Some(&[0xa4, 0xc3]),
+        Some(&[0xc9, 0x56]),
+        None,
+        Some(&[0xa4, 0xc4]),
+        Some(&[0xa4, 0xc5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa5, 0x5d]),
+        Some(&[0xa5, 0x5e]),
+        None,
+        Some(&[0xa6, 0x49]),
+        Some(&[0xca, 0x71]),
+        Some(&[0xcb, 0xd6]),
+        Some(&[0xcb, 0xd7]),
+        None,
+        Some(&[0xab, 0x6d]),
+        Some(&[0xd0, 0xee]),
+        Some(&[0xb0, 0xcc]),
+        Some(&[0xb0, 0xcb]),
+        Some(&[0xd8, 0x63]),
+        Some(&[0xd8, 0x62]),
+        None,
+        None,
+        Some(&[0xa4, 0x50]),
+        Some(&[0xa4, 0xc6]),
+        Some(&[0xa5, 0x5f]),
+        None,
+        Some(&[0xb0, 0xcd]),
+        Some(&[0xc9, 0x43]),
+        None,
+        Some(&[0xc9, 0x6c]),
+        Some(&[0xa5, 0x60]),
+        None,
+        Some(&[0xc9, 0xc2]),
+        Some(&[0xa6, 0x4b]),
+        Some(&[0xa6, 0x4a]),
+        Some(&[0xc9, 0xc1]),
+        Some(&[0xa7, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xea]),
+        None,
+        None,
+        Some(&[0xd4, 0x6f]),
+        None,
+        Some(&[0xb6, 0xd7]),
+        Some(&[0xe1, 0x45]),
+        Some(&[0xb9, 0xbc]),
+        None,
+        None,
+        Some(&[0xe8, 0xfa]),
+        None,
+        None,
+        Some(&[0xf3, 0xfd]),
+        None,
+        Some(&[0xa4, 0xc7]),
+        None,
+        None,
+        Some(&[0xcb, 0xd8]),
+        Some(&[0xcd, 0xf4]),
+        Some(&[0xb0, 0xd0]),
+        Some(&[0xb0, 0xce]),
+        Some(&[0xb0, 0xcf]),
+        Some(&[0xa4, 0x51]),
+        None,
+        Some(&[0xa4, 0x64]),
+        Some(&[0xa2, 0xcd]),
+        Some(&[0xa4, 0xca]),
+        None,
+        Some(&[0xa4, 0xc9]),
+        Some(&[0xa4, 0xc8]),
+        Some(&[0xa5, 0x63]),
+        Some(&[0xa5, 0x62]),
+        None,
+        Some(&[0xc9, 0x6d]),
+        Some(&[0xc9, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xf5]),
+        Some(&[0xa8, 0xf2]),
+        Some(&[0xa8, 0xf4]),
+        Some(&[0xa8, 0xf3]),
+        None,
+        None,
+        Some(&[0xab, 0x6e]),
+        None,
+        None,
+        Some(&[0xb3, 0xd5]),
+        None,
+        Some(&[0xa4, 0x52]),
+        None,
+        Some(&[0xa4, 0xcb]),
+        None,
+        Some(&[0xa5, 0x65]),
+        Some(&[0xa5, 0x64]),
+        None,
+        Some(&[0xca, 0x72]),
+        None,
+        None,
+        Some(&[0xa8, 0xf6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x57]),
+        None,
+        Some(&[0xa5, 0x67]),
+        Some(&[0xa5, 0x66]),
+        Some(&[0xa6, 0x4c]),
+        Some(&[0xa6, 0x4d]),
+        Some(&[0xca, 0x73]),
+        Some(&[0xa7, 0x59]),
+        None,
+        Some(&[0xa7, 0x5a]),
+        None,
+        Some(&[0xa8, 0xf7]),
+        Some(&[0xa8, 0xf8]),
+        Some(&[0xa8, 0xf9]),
+        None,
+        Some(&[0xab, 0x6f]),
+        Some(&[0xcd, 0xf5]),
+        None,
+        None,
+        Some(&[0xad, 0xeb]),
+        None,
+        None,
+        Some(&[0xc9, 0x44]),
+        None,
+        Some(&[0xa4, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x74]),
+        Some(&[0xca, 0x75]),
+        None,
+        None,
+        Some(&[0xcb, 0xd9]),
+        None,
+        Some(&[0xcb, 0xda]),
+        None,
+        Some(&[0xcd, 0xf7]),
+        Some(&[0xcd, 0xf6]),
+        Some(&[0xcd, 0xf9]),
+        Some(&[0xcd, 0xf8]),
+        Some(&[0xab, 0x70]),
+        None,
+        Some(&[0xd4, 0x70]),
+        Some(&[0xad, 0xed]),
+        Some(&[0xd0, 0xef]),
+        Some(&[0xad, 0xec]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0x64]),
+        Some(&[0xb3, 0xd6]),
+        None,
+        Some(&[0xd8, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x46]),
+        Some(&[0xb9, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0x46]),
+        None,
+        Some(&[0xf1, 0xef]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x58]),
+        None,
+        Some(&[0xa5, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0x53]),
+        Some(&[0xa4, 0x65]),
+        Some(&[0xa4, 0xce]),
+        Some(&[0xa4, 0xcd]),
+        None,
+        Some(&[0xa4, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xfb]),
+        None,
+        Some(&[0xa8, 0xfa]),
+        Some(&[0xa8, 0xfc]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x71]),
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xee]),
+        None,
+        Some(&[0xe8, 0xfb]),
+        Some(&[0xc2, 0x4f]),
+        Some(&[0xa4, 0x66]),
+        Some(&[0xa5, 0x6a]),
+        Some(&[0xa5, 0x79]),
+        Some(&[0xa5, 0x74]),
+        None,
+        Some(&[0xa5, 0x6f]),
+        Some(&[0xa5, 0x6e]),
+        Some(&[0xa5, 0x75]),
+        Some(&[0xa5, 0x73]),
+        Some(&[0xa5, 0x6c]),
+        Some(&[0xa5, 0x7a]),
+        Some(&[0xa5, 0x6d]),
+        Some(&[0xa5, 0x69]),
+        Some(&[0xa5, 0x78]),
+        Some(&[0xa5, 0x77]),
+        Some(&[0xa5, 0x76]),
+        Some(&[0xa5, 0x6b]),
+        None,
+        Some(&[0xa5, 0x72]),
+        None,
+        None,
+        Some(&[0xa5, 0x71]),
+        None,
+        None,
+        Some(&[0xa5, 0x7b]),
+        Some(&[0xa5, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0x53]),
+        None,
+        Some(&[0xa6, 0x59]),
+        Some(&[0xa6, 0x55]),
+        None,
+        Some(&[0xa6, 0x5b]),
+        Some(&[0xc9, 0xc5]),
+        Some(&[0xa6, 0x58]),
+        Some(&[0xa6, 0x4e]),
+        Some(&[0xa6, 0x51]),
+        Some(&[0xa6, 0x54]),
+        Some(&[0xa6, 0x50]),
+        Some(&[0xa6, 0x57]),
+        Some(&[0xa6, 0x5a]),
+        Some(&[0xa6, 0x4f]),
+        Some(&[0xa6, 0x52]),
+        Some(&[0xa6, 0x56]),
+        Some(&[0xa6, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x7e]),
+        Some(&[0xca, 0x7b]),
+        None,
+        Some(&[0xa7, 0x67]),
+        Some(&[0xca, 0x7c]),
+        Some(&[0xa7, 0x5b]),
+        Some(&[0xa7, 0x5d]),
+        Some(&[0xa7, 0x75]),
+        Some(&[0xa7, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xa5]),
+        Some(&[0xca, 0x7d]),
+        Some(&[0xa7, 0x5f]),
+        Some(&[0xa7, 0x61]),
+        Some(&[0xca, 0xa4]),
+        Some(&[0xa7, 0x68]),
+        Some(&[0xca, 0x78]),
+        Some(&[0xa7, 0x74]),
+        Some(&[0xa7, 0x76]),
+        Some(&[0xa7, 0x5c]),
+        Some(&[0xa7, 0x6d]),
+        None,
+        Some(&[0xca, 0x76]),
+        Some(&[0xa7, 0x73]),
+        None,
+        Some(&[0xa7, 0x64]),
+        None,
+        Some(&[0xa7, 0x6e]),
+        Some(&[0xa7, 0x6f]),
+        Some(&[0xca, 0x77]),
+        Some(&[0xa7, 0x6c]),
+        Some(&[0xa7, 0x6a]),
+        None,
+        Some(&[0xa7, 0x6b]),
+        Some(&[0xa7, 0x71]),
+        Some(&[0xca, 0xa1]),
+        Some(&[0xa7, 0x5e]),
+        None,
+        Some(&[0xa7, 0x72]),
+        Some(&[0xca, 0xa3]),
+        Some(&[0xa7, 0x66]),
+        Some(&[0xa7, 0x63]),
+        None,
+        Some(&[0xca, 0x7a]),
+        Some(&[0xa7, 0x62]),
+        Some(&[0xca, 0xa6]),
+        Some(&[0xa7, 0x65]),
+        None,
+        Some(&[0xa7, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0x60]),
+        Some(&[0xca, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xeb]),
+        Some(&[0xcb, 0xea]),
+        Some(&[0xa9, 0x4f]),
+        Some(&[0xcb, 0xed]),
+        Some(&[0xcb, 0xef]),
+        Some(&[0xcb, 0xe4]),
+        Some(&[0xcb, 0xe7]),
+        Some(&[0xcb, 0xee]),
+        Some(&[0xa9, 0x50]),
+        None,
+        None,
+        Some(&[0xcb, 0xe1]),
+        Some(&[0xcb, 0xe5]),
+        None,
+        None,
+        Some(&[0xcb, 0xe9]),
+        Some(&[0xce, 0x49]),
+        Some(&[0xa9, 0x4b]),
+        Some(&[0xce, 0x4d]),
+        Some(&[0xa8, 0xfd]),
+        Some(&[0xcb, 0xe6]),
+        Some(&[0xa8, 0xfe]),
+        Some(&[0xa9, 0x4c]),
+        Some(&[0xa9, 0x45]),
+        Some(&[0xa9, 0x41]),
+        None,
+        Some(&[0xcb, 0xe2]),
+        Some(&[0xa9, 0x44]),
+        Some(&[0xa9, 0x49]),
+        Some(&[0xa9, 0x52]),
+        Some(&[0xcb, 0xe3]),
+        Some(&[0xcb, 0xdc]),
+        Some(&[0xa9, 0x43]),
+        Some(&[0xcb, 0xdd]),
+        Some(&[0xcb, 0xdf]),
+        None,
+        Some(&[0xa9, 0x46]),
+        None,
+        Some(&[0xa9, 0x48]),
+        Some(&[0xcb, 0xdb]),
+        Some(&[0xcb, 0xe0]),
+        None,
+        None,
+        Some(&[0xa9, 0x51]),
+        Some(&[0xa9, 0x4d]),
+        Some(&[0xcb, 0xe8]),
+        Some(&[0xa9, 0x53]),
+        None,
+        Some(&[0xa9, 0x4a]),
+        Some(&[0xcb, 0xde]),
+        Some(&[0xa9, 0x47]),
+        None,
+        None,
+        Some(&[0xa9, 0x42]),
+        Some(&[0xa9, 0x40]),
+        None,
+        Some(&[0xcb, 0xec]),
+        None,
+        Some(&[0xa9, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0x48]),
+        Some(&[0xcd, 0xfb]),
+        Some(&[0xce, 0x4b]),
+        None,
+        None,
+        Some(&[0xcd, 0xfd]),
+        Some(&[0xab, 0x78]),
+        Some(&[0xab, 0xa8]),
+        Some(&[0xab, 0x74]),
+        Some(&[0xab, 0xa7]),
+        Some(&[0xab, 0x7d]),
+        Some(&[0xab, 0xa4]),
+        Some(&[0xab, 0x72]),
+        Some(&[0xcd, 0xfc]),
+        Some(&[0xce, 0x43]),
+        Some(&[0xab, 0xa3]),
+        Some(&[0xce, 0x4f]),
+        Some(&[0xab, 0xa5]),
+        None,
+        Some(&[0xab, 0x79]),
+        None,
+        None,
+        Some(&[0xce, 0x45]),
+        Some(&[0xce, 0x42]),
+        Some(&[0xab, 0x77]),
+        None,
+        Some(&[0xcd, 0xfa]),
+        Some(&[0xab, 0xa6]),
+        Some(&[0xce, 0x4a]),
+        Some(&[0xab, 0x7c]),
+        Some(&[0xce, 0x4c]),
+        Some(&[0xab, 0xa9]),
+        Some(&[0xab, 0x73]),
+        Some(&[0xab, 0x7e]),
+        Some(&[0xab, 0x7b]),
+        Some(&[0xce, 0x40]),
+        Some(&[0xab, 0xa1]),
+        Some(&[0xce, 0x46]),
+        Some(&[0xce, 0x47]),
+        Some(&[0xab, 0x7a]),
+        Some(&[0xab, 0xa2]),
+        Some(&[0xab, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x75]),
+        Some(&[0xcd, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0x4e]),
+        None,
+        Some(&[0xd1, 0x44]),
+        Some(&[0xad, 0xfb]),
+        Some(&[0xd0, 0xf1]),
+        None,
+        Some(&[0xd0, 0xf6]),
+        Some(&[0xad, 0xf4]),
+        Some(&[0xae, 0x40]),
+        Some(&[0xd0, 0xf4]),
+        Some(&[0xad, 0xef]),
+        Some(&[0xad, 0xf9]),
+        Some(&[0xad, 0xfe]),
+        Some(&[0xd0, 0xfb]),
+        None,
+        Some(&[0xad, 0xfa]),
+        Some(&[0xad, 0xfd]),
+        None,
+        None,
+        Some(&[0xd0, 0xfe]),
+        Some(&[0xad, 0xf5]),
+        Some(&[0xd0, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0x42]),
+        Some(&[0xd1, 0x43]),
+        None,
+        Some(&[0xad, 0xf7]),
+        Some(&[0xd1, 0x41]),
+        Some(&[0xad, 0xf3]),
+        Some(&[0xae, 0x43]),
+        None,
+        Some(&[0xd0, 0xf8]),
+        None,
+        Some(&[0xad, 0xf1]),
+        None,
+        Some(&[0xd1, 0x46]),
+        Some(&[0xd0, 0xf9]),
+        Some(&[0xd0, 0xfd]),
+        Some(&[0xad, 0xf6]),
+        Some(&[0xae, 0x42]),
+        Some(&[0xd0, 0xfa]),
+        Some(&[0xad, 0xfc]),
+        Some(&[0xd1, 0x40]),
+        Some(&[0xd1, 0x47]),
+        Some(&[0xd4, 0xa1]),
+        None,
+        Some(&[0xd1, 0x45]),
+        Some(&[0xae, 0x44]),
+        Some(&[0xad, 0xf0]),
+        Some(&[0xd0, 0xfc]),
+        Some(&[0xd0, 0xf3]),
+        None,
+        Some(&[0xad, 0xf8]),
+        None,
+        None,
+        Some(&[0xd0, 0xf2]),
+        None,
+        None,
+        Some(&[0xd0, 0xf7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xf0]),
+        Some(&[0xae, 0x41]),
+        None,
+        None,
+        Some(&[0xd4, 0x77]),
+        None,
+        Some(&[0xb0, 0xe4]),
+        Some(&[0xd4, 0xa7]),
+        Some(&[0xb0, 0xe2]),
+        Some(&[0xb0, 0xdf]),
+        Some(&[0xd4, 0x7c]),
+        Some(&[0xb0, 0xdb]),
+        Some(&[0xd4, 0xa2]),
+        Some(&[0xb0, 0xe6]),
+        Some(&[0xd4, 0x76]),
+        Some(&[0xd4, 0x7b]),
+        Some(&[0xd4, 0x7a]),
+        Some(&[0xad, 0xf2]),
+        Some(&[0xb0, 0xe1]),
+        Some(&[0xd4, 0xa5]),
+        None,
+        Some(&[0xd4, 0xa8]),
+        Some(&[0xd4, 0x73]),
+        None,
+        Some(&[0xb3, 0xe8]),
+        None,
+        Some(&[0xd4, 0xa9]),
+        Some(&[0xb0, 0xe7]),
+        None,
+        Some(&[0xb0, 0xd9]),
+        Some(&[0xb0, 0xd6]),
+        Some(&[0xd4, 0x7e]),
+        Some(&[0xb0, 0xd3]),
+        None,
+        Some(&[0xd4, 0xa6]),
+        None,
+        Some(&[0xb0, 0xda]),
+        Some(&[0xd4, 0xaa]),
+        None,
+        Some(&[0xd4, 0x74]),
+        Some(&[0xd4, 0xa4]),
+        Some(&[0xb0, 0xdd]),
+        Some(&[0xd4, 0x75]),
+        Some(&[0xd4, 0x78]),
+        Some(&[0xd4, 0x7d]),
+        None,
+        None,
+        Some(&[0xb0, 0xde]),
+        Some(&[0xb0, 0xdc]),
+        Some(&[0xb0, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xe3]),
+        None,
+        Some(&[0xb0, 0xd7]),
+        Some(&[0xb1, 0xd2]),
+        None,
+        Some(&[0xb0, 0xd8]),
+        Some(&[0xd4, 0x79]),
+        Some(&[0xb0, 0xe5]),
+        Some(&[0xb0, 0xe0]),
+        Some(&[0xd4, 0xa3]),
+        Some(&[0xb0, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0x71]),
+        Some(&[0xd4, 0x72]),
+        Some(&[0xd8, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xd7]),
+        Some(&[0xb3, 0xda]),
+        Some(&[0xd8, 0x75]),
+        Some(&[0xb3, 0xee]),
+        Some(&[0xd8, 0x78]),
+        Some(&[0xb3, 0xd8]),
+        Some(&[0xd8, 0x71]),
+        Some(&[0xb3, 0xde]),
+        Some(&[0xb3, 0xe4]),
+        Some(&[0xb5, 0xbd]),
+        None,
+        None,
+        Some(&[0xb3, 0xe2]),
+        Some(&[0xd8, 0x6e]),
+        Some(&[0xb3, 0xef]),
+        Some(&[0xb3, 0xdb]),
+        Some(&[0xb3, 0xe3]),
+        Some(&[0xd8, 0x76]),
+        Some(&[0xdc, 0xd7]),
+        Some(&[0xd8, 0x7b]),
+        Some(&[0xd8, 0x6f]),
+        None,
+        Some(&[0xd8, 0x66]),
+        Some(&[0xd8, 0x73]),
+        Some(&[0xd8, 0x6d]),
+        Some(&[0xb3, 0xe1]),
+        Some(&[0xd8, 0x79]),
+        None,
+        None,
+        Some(&[0xb3, 0xdd]),
+        Some(&[0xb3, 0xf1]),
+        Some(&[0xb3, 0xea]),
+        None,
+        Some(&[0xb3, 0xdf]),
+        Some(&[0xb3, 0xdc]),
+        None,
+        Some(&[0xb3, 0xe7]),
+        None,
+        Some(&[0xd8, 0x7a]),
+        Some(&[0xd8, 0x6c]),
+        Some(&[0xd8, 0x72]),
+        Some(&[0xd8, 0x74]),
+        Some(&[0xd8, 0x68]),
+        Some(&[0xd8, 0x77]),
+        Some(&[0xb3, 0xd9]),
+        Some(&[0xd8, 0x67]),
+        None,
+        Some(&[0xb3, 0xe0]),
+        Some(&[0xb3, 0xf0]),
+        Some(&[0xb3, 0xec]),
+        Some(&[0xd8, 0x69]),
+        Some(&[0xb3, 0xe6]),
+        None,
+        None,
+        Some(&[0xb3, 0xed]),
+        Some(&[0xb3, 0xe9]),
+        Some(&[0xb3, 0xe5]),
+        None,
+        Some(&[0xd8, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0xd5]),
+        Some(&[0xdc, 0xd1]),
+        None,
+        Some(&[0xdc, 0xe0]),
+        Some(&[0xdc, 0xca]),
+        Some(&[0xdc, 0xd3]),
+        Some(&[0xb6, 0xe5]),
+        Some(&[0xb6, 0xe6]),
+        Some(&[0xb6, 0xde]),
+        Some(&[0xdc, 0xdc]),
+        Some(&[0xb6, 0xe8]),
+        Some(&[0xdc, 0xcf]),
+        Some(&[0xdc, 0xce]),
+        Some(&[0xdc, 0xcc]),
+        Some(&[0xdc, 0xde]),
+        Some(&[0xb6, 0xdc]),
+        Some(&[0xdc, 0xd8]),
+        Some(&[0xdc, 0xcd]),
+        Some(&[0xb6, 0xdf]),
+        Some(&[0xdc, 0xd6]),
+        Some(&[0xb6, 0xda]),
+        Some(&[0xdc, 0xd2]),
+        Some(&[0xdc, 0xd9]),
+        Some(&[0xdc, 0xdb]),
+        None,
+        None,
+        Some(&[0xdc, 0xdf]),
+        Some(&[0xb6, 0xe3]),
+        Some(&[0xdc, 0xcb]),
+        Some(&[0xb6, 0xdd]),
+        Some(&[0xdc, 0xd0]),
+        None,
+        Some(&[0xb6, 0xd8]),
+        None,
+        Some(&[0xb6, 0xe4]),
+        Some(&[0xdc, 0xda]),
+        Some(&[0xb6, 0xe0]),
+        Some(&[0xb6, 0xe1]),
+        Some(&[0xb6, 0xe7]),
+        Some(&[0xb6, 0xdb]),
+        Some(&[0xa2, 0x5f]),
+        Some(&[0xb6, 0xd9]),
+        Some(&[0xdc, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xe2]),
+        None,
+        None,
+        Some(&[0xdc, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xcd]),
+        Some(&[0xb9, 0xc8]),
+        None,
+        Some(&[0xe1, 0x55]),
+        Some(&[0xe1, 0x51]),
+        None,
+        Some(&[0xe1, 0x4b]),
+        Some(&[0xb9, 0xc2]),
+        Some(&[0xb9, 0xbe]),
+        Some(&[0xe1, 0x54]),
+        Some(&[0xb9, 0xbf]),
+        Some(&[0xe1, 0x4e]),
+        Some(&[0xe1, 0x50]),
+        None,
+        Some(&[0xe1, 0x53]),
+        None,
+        Some(&[0xb9, 0xc4]),
+        None,
+        Some(&[0xb9, 0xcb]),
+        Some(&[0xb9, 0xc5]),
+        None,
+        None,
+        Some(&[0xe1, 0x49]),
+        Some(&[0xb9, 0xc6]),
+        Some(&[0xb9, 0xc7]),
+        Some(&[0xe1, 0x4c]),
+        Some(&[0xb9, 0xcc]),
+        None,
+        Some(&[0xe1, 0x4a]),
+        Some(&[0xe1, 0x4f]),
+        Some(&[0xb9, 0xc3]),
+        Some(&[0xe1, 0x48]),
+        Some(&[0xb9, 0xc9]),
+        Some(&[0xb9, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xc0]),
+        Some(&[0xe1, 0x4d]),
+        Some(&[0xe1, 0x52]),
+        None,
+        Some(&[0xb9, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x47]),
+        None,
+        Some(&[0xbc, 0x4d]),
+        Some(&[0xe5, 0x47]),
+        None,
+        Some(&[0xe5, 0x44]),
+        None,
+        Some(&[0xbc, 0x47]),
+        Some(&[0xbc, 0x53]),
+        Some(&[0xbc, 0x54]),
+        None,
+        Some(&[0xbc, 0x4a]),
+        Some(&[0xe5, 0x42]),
+        Some(&[0xbc, 0x4c]),
+        Some(&[0xe4, 0xf9]),
+        Some(&[0xbc, 0x52]),
+        None,
+        Some(&[0xe5, 0x46]),
+        Some(&[0xbc, 0x49]),
+        Some(&[0xe5, 0x48]),
+        Some(&[0xbc, 0x48]),
+        None,
+        Some(&[0xe5, 0x43]),
+        Some(&[0xe5, 0x45]),
+        Some(&[0xbc, 0x4b]),
+        Some(&[0xe5, 0x41]),
+        Some(&[0xe4, 0xfa]),
+        Some(&[0xe4, 0xf7]),
+        None,
+        None,
+        Some(&[0xd8, 0x6b]),
+        Some(&[0xe4, 0xfd]),
+        None,
+        Some(&[0xe4, 0xf6]),
+        Some(&[0xe4, 0xfc]),
+        Some(&[0xe4, 0xfb]),
+        None,
+        Some(&[0xe4, 0xf8]),
+        None,
+        Some(&[0xbc, 0x4f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0x4e]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0x50]),
+        Some(&[0xe4, 0xfe]),
+        Some(&[0xbe, 0xb2]),
+        Some(&[0xe5, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x45]),
+        None,
+        Some(&[0xe8, 0xfd]),
+        None,
+        Some(&[0xbe, 0xbe]),
+        Some(&[0xe9, 0x42]),
+        Some(&[0xbe, 0xb6]),
+        Some(&[0xbe, 0xba]),
+        Some(&[0xe9, 0x41]),
+        None,
+        Some(&[0xbe, 0xb9]),
+        Some(&[0xbe, 0xb5]),
+        Some(&[0xbe, 0xb8]),
+        Some(&[0xbe, 0xb3]),
+        Some(&[0xbe, 0xbd]),
+        Some(&[0xe9, 0x43]),
+        Some(&[0xe8, 0xfe]),
+        Some(&[0xbe, 0xbc]),
+        Some(&[0xe8, 0xfc]),
+        Some(&[0xbe, 0xbb]),
+        Some(&[0xe9, 0x44]),
+        Some(&[0xe9, 0x40]),
+        Some(&[0xbc, 0x51]),
+        None,
+        Some(&[0xbe, 0xbf]),
+        Some(&[0xe9, 0x46]),
+        Some(&[0xbe, 0xb7]),
+        Some(&[0xbe, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xc6]),
+        Some(&[0xec, 0xc8]),
+        Some(&[0xc0, 0x7b]),
+        Some(&[0xec, 0xc9]),
+        Some(&[0xec, 0xc7]),
+        Some(&[0xec, 0xc5]),
+        Some(&[0xec, 0xc4]),
+        Some(&[0xc0, 0x7d]),
+        Some(&[0xec, 0xc3]),
+        Some(&[0xc0, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xc1]),
+        Some(&[0xec, 0xc2]),
+        Some(&[0xc0, 0x7a]),
+        Some(&[0xc0, 0xa1]),
+        Some(&[0xc0, 0x7c]),
+        None,
+        None,
+        Some(&[0xec, 0xc0]),
+        None,
+        Some(&[0xc2, 0x50]),
+        None,
+        Some(&[0xef, 0xbc]),
+        Some(&[0xef, 0xba]),
+        Some(&[0xef, 0xbf]),
+        Some(&[0xef, 0xbd]),
+        None,
+        Some(&[0xef, 0xbb]),
+        Some(&[0xef, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0x60]),
+        Some(&[0xf1, 0xf2]),
+        Some(&[0xf1, 0xf3]),
+        Some(&[0xc4, 0x56]),
+        None,
+        Some(&[0xf1, 0xf4]),
+        Some(&[0xf1, 0xf0]),
+        Some(&[0xf1, 0xf5]),
+        Some(&[0xf1, 0xf1]),
+        Some(&[0xc2, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xfe]),
+        Some(&[0xf4, 0x41]),
+        Some(&[0xc4, 0x59]),
+        Some(&[0xf4, 0x40]),
+        Some(&[0xc4, 0x58]),
+        Some(&[0xc4, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0x5a]),
+        Some(&[0xf5, 0xc5]),
+        Some(&[0xf5, 0xc6]),
+        None,
+        Some(&[0xc4, 0xda]),
+        Some(&[0xc4, 0xd9]),
+        Some(&[0xc4, 0xdb]),
+        Some(&[0xf5, 0xc4]),
+        None,
+        Some(&[0xf6, 0xd8]),
+        Some(&[0xf6, 0xd7]),
+        None,
+        Some(&[0xc5, 0x6d]),
+        Some(&[0xc5, 0x6f]),
+        Some(&[0xc5, 0x6e]),
+        Some(&[0xf6, 0xd9]),
+        Some(&[0xc5, 0xc8]),
+        Some(&[0xf8, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xf1]),
+        None,
+        Some(&[0xf8, 0xa5]),
+        Some(&[0xf8, 0xee]),
+        None,
+        None,
+        Some(&[0xc9, 0x49]),
+        None,
+        None,
+        Some(&[0xa5, 0x7d]),
+        Some(&[0xa5, 0x7c]),
+        None,
+        Some(&[0xa6, 0x5f]),
+        Some(&[0xa6, 0x5e]),
+        Some(&[0xc9, 0xc7]),
+        Some(&[0xa6, 0x5d]),
+        Some(&[0xc9, 0xc6]),
+        None,
+        None,
+        Some(&[0xa7, 0x79]),
+        Some(&[0xca, 0xa9]),
+        None,
+        Some(&[0xca, 0xa8]),
+        None,
+        None,
+        Some(&[0xa7, 0x77]),
+        Some(&[0xa7, 0x7a]),
+        None,
+        None,
+        Some(&[0xca, 0xa7]),
+        None,
+        Some(&[0xa7, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xf0]),
+        None,
+        Some(&[0xcb, 0xf1]),
+        Some(&[0xa9, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xaa]),
+        None,
+        Some(&[0xd1, 0x48]),
+        Some(&[0xd1, 0x49]),
+        Some(&[0xae, 0x45]),
+        Some(&[0xae, 0x46]),
+        None,
+        None,
+        Some(&[0xd4, 0xac]),
+        Some(&[0xb0, 0xe9]),
+        Some(&[0xb0, 0xeb]),
+        Some(&[0xd4, 0xab]),
+        Some(&[0xb0, 0xea]),
+        Some(&[0xd8, 0x7c]),
+        Some(&[0xb3, 0xf2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xe9]),
+        Some(&[0xb6, 0xea]),
+        Some(&[0xdc, 0xe1]),
+        None,
+        Some(&[0xb9, 0xcf]),
+        None,
+        Some(&[0xb9, 0xce]),
+        None,
+        Some(&[0xe5, 0x49]),
+        Some(&[0xe9, 0x48]),
+        Some(&[0xe9, 0x47]),
+        None,
+        Some(&[0xf9, 0x6b]),
+        Some(&[0xa4, 0x67]),
+        Some(&[0xc9, 0x59]),
+        None,
+        Some(&[0xc9, 0x6e]),
+        Some(&[0xc9, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0x62]),
+        Some(&[0xa6, 0x66]),
+        Some(&[0xc9, 0xc9]),
+        None,
+        Some(&[0xa6, 0x64]),
+        Some(&[0xa6, 0x63]),
+        Some(&[0xc9, 0xc8]),
+        Some(&[0xa6, 0x65]),
+        Some(&[0xa6, 0x61]),
+        None,
+        None,
+        Some(&[0xa6, 0x60]),
+        Some(&[0xc9, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xa6]),
+        None,
+        None,
+        Some(&[0xa7, 0xa3]),
+        None,
+        Some(&[0xa7, 0x7d]),
+        Some(&[0xca, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xab]),
+        None,
+        Some(&[0xa7, 0xa1]),
+        None,
+        Some(&[0xca, 0xad]),
+        Some(&[0xa7, 0x7b]),
+        Some(&[0xca, 0xae]),
+        Some(&[0xca, 0xac]),
+        Some(&[0xa7, 0x7e]),
+        Some(&[0xa7, 0xa2]),
+        Some(&[0xa7, 0xa5]),
+        Some(&[0xa7, 0xa4]),
+        Some(&[0xa7, 0x7c]),
+        Some(&[0xca, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0x59]),
+        Some(&[0xcb, 0xfe]),
+        None,
+        Some(&[0xa9, 0x5b]),
+        None,
+        Some(&[0xa9, 0x5a]),
+        None,
+        Some(&[0xcc, 0x40]),
+        Some(&[0xa9, 0x58]),
+        Some(&[0xa9, 0x57]),
+        Some(&[0xcb, 0xf5]),
+        None,
+        Some(&[0xcb, 0xf4]),
+        None,
+        Some(&[0xcb, 0xf2]),
+        Some(&[0xcb, 0xf7]),
+        Some(&[0xcb, 0xf6]),
+        Some(&[0xcb, 0xf3]),
+        Some(&[0xcb, 0xfc]),
+        Some(&[0xcb, 0xfd]),
+        Some(&[0xcb, 0xfa]),
+        Some(&[0xcb, 0xf8]),
+        Some(&[0xa9, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xfb]),
+        Some(&[0xa9, 0x5c]),
+        Some(&[0xcc, 0x41]),
+        None,
+        None,
+        Some(&[0xcb, 0xf9]),
+        None,
+        Some(&[0xab, 0xab]),
+        Some(&[0xa9, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xac]),
+        Some(&[0xce, 0x54]),
+        None,
+        None,
+        Some(&[0xce, 0x5a]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xb2]),
+        Some(&[0xce, 0x58]),
+        Some(&[0xce, 0x5e]),
+        None,
+        Some(&[0xce, 0x55]),
+        Some(&[0xce, 0x59]),
+        Some(&[0xce, 0x5b]),
+        Some(&[0xce, 0x5d]),
+        Some(&[0xce, 0x57]),
+        None,
+        Some(&[0xce, 0x56]),
+        Some(&[0xce, 0x51]),
+        Some(&[0xce, 0x52]),
+        Some(&[0xab, 0xad]),
+        None,
+        Some(&[0xab, 0xaf]),
+        Some(&[0xab, 0xae]),
+        Some(&[0xce, 0x53]),
+        Some(&[0xce, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0x50]),
+        Some(&[0xd1, 0x53]),
+        None,
+        Some(&[0xd1, 0x52]),
+        Some(&[0xd1, 0x57]),
+        Some(&[0xd1, 0x4e]),
+        None,
+        Some(&[0xd1, 0x51]),
+        Some(&[0xd1, 0x50]),
+        None,
+        Some(&[0xd1, 0x54]),
+        None,
+        Some(&[0xd1, 0x58]),
+        Some(&[0xae, 0x47]),
+        Some(&[0xae, 0x4a]),
+        None,
+        None,
+        Some(&[0xd1, 0x4f]),
+        Some(&[0xd1, 0x55]),
+        None,
+        None,
+        None,
+        Some(&[0xae, 0x49]),
+        Some(&[0xd1, 0x4a]),
+        None,
+        Some(&[0xab, 0xb0]),
+        Some(&[0xd4, 0xba]),
+        Some(&[0xd1, 0x56]),
+        None,
+        Some(&[0xd1, 0x4d]),
+        None,
+        Some(&[0xae, 0x48]),
+        Some(&[0xd1, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xb1]),
+        None,
+        None,
+        Some(&[0xb0, 0xec]),
+        Some(&[0xb0, 0xf0]),
+        Some(&[0xd4, 0xc1]),
+        Some(&[0xd4, 0xaf]),
+        Some(&[0xd4, 0xbd]),
+        Some(&[0xb0, 0xf1]),
+        Some(&[0xd4, 0xbf]),
+        None,
+        Some(&[0xd4, 0xc5]),
+        None,
+        Some(&[0xd4, 0xc9]),
+        None,
+        None,
+        Some(&[0xd4, 0xc0]),
+        Some(&[0xd4, 0xb4]),
+        Some(&[0xd4, 0xbc]),
+        None,
+        Some(&[0xd4, 0xca]),
+        Some(&[0xd4, 0xc8]),
+        Some(&[0xd4, 0xbe]),
+        Some(&[0xd4, 0xb9]),
+        Some(&[0xd4, 0xb2]),
+        Some(&[0xd8, 0xa6]),
+        Some(&[0xd4, 0xb0]),
+        Some(&[0xb0, 0xf5]),
+        Some(&[0xd4, 0xb7]),
+        Some(&[0xb0, 0xf6]),
+        Some(&[0xb0, 0xf2]),
+        Some(&[0xd4, 0xad]),
+        Some(&[0xd4, 0xc3]),
+        Some(&[0xd4, 0xb5]),
+        None,
+        None,
+        Some(&[0xd4, 0xb3]),
+        Some(&[0xd4, 0xc6]),
+        Some(&[0xb0, 0xf3]),
+        None,
+        Some(&[0xd4, 0xcc]),
+        Some(&[0xb0, 0xed]),
+        Some(&[0xb0, 0xef]),
+        Some(&[0xd4, 0xbb]),
+        Some(&[0xd4, 0xb6]),
+        Some(&[0xae, 0x4b]),
+        Some(&[0xb0, 0xee]),
+        Some(&[0xd4, 0xb8]),
+        Some(&[0xd4, 0xc7]),
+        Some(&[0xd4, 0xcb]),
+        Some(&[0xd4, 0xc2]),
+        None,
+        Some(&[0xd4, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xa1]),
+        None,
+        Some(&[0xd8, 0xaa]),
+        Some(&[0xd8, 0xa9]),
+        Some(&[0xb3, 0xfa]),
+        Some(&[0xd8, 0xa2]),
+        None,
+        Some(&[0xb3, 0xfb]),
+        Some(&[0xb3, 0xf9]),
+        None,
+        Some(&[0xd8, 0xa4]),
+        Some(&[0xb3, 0xf6]),
+        Some(&[0xd8, 0xa8]),
+        None,
+        Some(&[0xd8, 0xa3]),
+        Some(&[0xd8, 0xa5]),
+        Some(&[0xd8, 0x7d]),
+        Some(&[0xb3, 0xf4]),
+        None,
+        Some(&[0xd8, 0xb2]),
+        Some(&[0xd8, 0xb1]),
+        Some(&[0xd8, 0xae]),
+        Some(&[0xb3, 0xf3]),
+        Some(&[0xb3, 0xf7]),
+        Some(&[0xb3, 0xf8]),
+        Some(&[0xd1, 0x4b]),
+        Some(&[0xd8, 0xab]),
+        Some(&[0xb3, 0xf5]),
+        Some(&[0xb0, 0xf4]),
+        Some(&[0xd8, 0xad]),
+        Some(&[0xd8, 0x7e]),
+        Some(&[0xd8, 0xb0]),
+        Some(&[0xd8, 0xaf]),
+        None,
+        Some(&[0xd8, 0xb3]),
+        None,
+        Some(&[0xdc, 0xef]),
+        None,
+        Some(&[0xd8, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xa7]),
+        Some(&[0xdc, 0xe7]),
+        Some(&[0xb6, 0xf4]),
+        Some(&[0xb6, 0xf7]),
+        Some(&[0xb6, 0xf2]),
+        Some(&[0xdc, 0xe6]),
+        Some(&[0xdc, 0xea]),
+        Some(&[0xdc, 0xe5]),
+        None,
+        Some(&[0xb6, 0xec]),
+        Some(&[0xb6, 0xf6]),
+        Some(&[0xdc, 0xe2]),
+        Some(&[0xb6, 0xf0]),
+        Some(&[0xdc, 0xe9]),
+        None,
+        Some(&[0xb6, 0xee]),
+        Some(&[0xb6, 0xed]),
+        Some(&[0xdc, 0xec]),
+        Some(&[0xb6, 0xef]),
+        Some(&[0xdc, 0xee]),
+        None,
+        Some(&[0xdc, 0xeb]),
+        Some(&[0xb6, 0xeb]),
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xf5]),
+        Some(&[0xdc, 0xf0]),
+        Some(&[0xdc, 0xe4]),
+        Some(&[0xdc, 0xed]),
+        None,
+        None,
+        Some(&[0xdc, 0xe3]),
+        None,
+        None,
+        Some(&[0xb6, 0xf1]),
+        None,
+        Some(&[0xb6, 0xf3]),
+        None,
+        Some(&[0xdc, 0xe8]),
+        None,
+        Some(&[0xdc, 0xf1]),
+        None,
+        None,
+        Some(&[0xe1, 0x5d]),
+        Some(&[0xb9, 0xd0]),
+        Some(&[0xe1, 0x63]),
+        None,
+        None,
+        Some(&[0xb9, 0xd5]),
+        Some(&[0xe1, 0x5f]),
+        Some(&[0xe1, 0x66]),
+        Some(&[0xe1, 0x57]),
+        Some(&[0xb9, 0xd7]),
+        Some(&[0xb9, 0xd1]),
+        Some(&[0xe1, 0x5c]),
+        Some(&[0xbc, 0x55]),
+        Some(&[0xe1, 0x5b]),
+        Some(&[0xe1, 0x64]),
+        Some(&[0xb9, 0xd2]),
+        None,
+        Some(&[0xb9, 0xd6]),
+        Some(&[0xe1, 0x5a]),
+        Some(&[0xe1, 0x60]),
+        Some(&[0xe1, 0x65]),
+        Some(&[0xe1, 0x56]),
+        Some(&[0xb9, 0xd4]),
+        Some(&[0xe1, 0x5e]),
+        None,
+        None,
+        Some(&[0xe1, 0x62]),
+        Some(&[0xe1, 0x68]),
+        Some(&[0xe1, 0x58]),
+        Some(&[0xe1, 0x61]),
+        None,
+        Some(&[0xb9, 0xd3]),
+        Some(&[0xe1, 0x67]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0x59]),
+        Some(&[0xe5, 0x4b]),
+        Some(&[0xbc, 0x57]),
+        Some(&[0xbc, 0x56]),
+        Some(&[0xe5, 0x4d]),
+        Some(&[0xe5, 0x52]),
+        None,
+        Some(&[0xe5, 0x4e]),
+        None,
+        Some(&[0xe5, 0x51]),
+        Some(&[0xbc, 0x5c]),
+        None,
+        Some(&[0xbe, 0xa5]),
+        Some(&[0xbc, 0x5b]),
+        None,
+        Some(&[0xe5, 0x4a]),
+        Some(&[0xe5, 0x50]),
+        None,
+        Some(&[0xbc, 0x5a]),
+        Some(&[0xe5, 0x4f]),
+        None,
+        Some(&[0xe5, 0x4c]),
+        None,
+        Some(&[0xbc, 0x58]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x4d]),
+        Some(&[0xf9, 0xd9]),
+        Some(&[0xe9, 0x4f]),
+        Some(&[0xe9, 0x4a]),
+        Some(&[0xbe, 0xc1]),
+        Some(&[0xe9, 0x4c]),
+        None,
+        Some(&[0xbe, 0xc0]),
+        Some(&[0xe9, 0x4e]),
+        None,
+        None,
+        Some(&[0xbe, 0xc3]),
+        Some(&[0xe9, 0x50]),
+        Some(&[0xbe, 0xc2]),
+        Some(&[0xe9, 0x49]),
+        Some(&[0xe9, 0x4b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xa5]),
+        Some(&[0xec, 0xcc]),
+        None,
+        Some(&[0xc0, 0xa4]),
+        Some(&[0xec, 0xcd]),
+        Some(&[0xc0, 0xa3]),
+        Some(&[0xec, 0xcb]),
+        Some(&[0xc0, 0xa2]),
+        Some(&[0xec, 0xca]),
+        None,
+        Some(&[0xc2, 0x53]),
+        Some(&[0xc2, 0x52]),
+        Some(&[0xf1, 0xf6]),
+        Some(&[0xf1, 0xf8]),
+        None,
+        Some(&[0xf1, 0xf7]),
+        Some(&[0xc3, 0x61]),
+        Some(&[0xc3, 0x62]),
+        None,
+        None,
+        Some(&[0xc3, 0x63]),
+        Some(&[0xf4, 0x42]),
+        Some(&[0xc4, 0x5b]),
+        None,
+        None,
+        Some(&[0xf7, 0xd3]),
+        Some(&[0xf7, 0xd2]),
+        Some(&[0xc5, 0xf2]),
+        None,
+        Some(&[0xa4, 0x68]),
+        Some(&[0xa4, 0xd0]),
+        None,
+        None,
+        Some(&[0xa7, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xfc]),
+        Some(&[0xb3, 0xfd]),
+        None,
+        Some(&[0xdc, 0xf2]),
+        Some(&[0xb9, 0xd8]),
+        Some(&[0xe1, 0x69]),
+        Some(&[0xe5, 0x53]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x5a]),
+        None,
+        None,
+        Some(&[0xca, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0x42]),
+        Some(&[0xce, 0x60]),
+        Some(&[0xd1, 0x59]),
+        Some(&[0xae, 0x4c]),
+        None,
+        None,
+        Some(&[0xf1, 0xf9]),
+        None,
+        Some(&[0xc4, 0xdc]),
+        Some(&[0xa4, 0x69]),
+        Some(&[0xa5, 0x7e]),
+        Some(&[0xc9, 0x70]),
+        None,
+        Some(&[0xa6, 0x67]),
+        Some(&[0xa6, 0x68]),
+        None,
+        Some(&[0xa9, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xf7]),
+        None,
+        Some(&[0xb9, 0xda]),
+        None,
+        Some(&[0xb9, 0xdb]),
+        Some(&[0xb9, 0xd9]),
+        None,
+        Some(&[0xa4, 0x6a]),
+        None,
+        Some(&[0xa4, 0xd1]),
+        Some(&[0xa4, 0xd3]),
+        Some(&[0xa4, 0xd2]),
+        Some(&[0xc9, 0x5b]),
+        Some(&[0xa4, 0xd4]),
+        Some(&[0xa5, 0xa1]),
+        Some(&[0xc9, 0x71]),
+        None,
+        Some(&[0xa5, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0x69]),
+        Some(&[0xa6, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xcb]),
+        None,
+        Some(&[0xa7, 0xa8]),
+        None,
+        Some(&[0xca, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0x61]),
+        Some(&[0xcc, 0x43]),
+        None,
+        Some(&[0xa9, 0x5f]),
+        Some(&[0xa9, 0x60]),
+        Some(&[0xa9, 0x5e]),
+        Some(&[0xd1, 0x5a]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xb6]),
+        Some(&[0xab, 0xb5]),
+        Some(&[0xab, 0xb7]),
+        Some(&[0xab, 0xb4]),
+        None,
+        Some(&[0xce, 0x61]),
+        Some(&[0xa9, 0x62]),
+        Some(&[0xab, 0xb3]),
+        None,
+        Some(&[0xae, 0x4d]),
+        Some(&[0xae, 0x4e]),
+        None,
+        Some(&[0xae, 0x4f]),
+        None,
+        Some(&[0xd4, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xfe]),
+        Some(&[0xd8, 0xb4]),
+        Some(&[0xb0, 0xf8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xf8]),
+        None,
+        Some(&[0xb9, 0xdd]),
+        Some(&[0xb9, 0xdc]),
+        Some(&[0xe1, 0x6a]),
+        None,
+        Some(&[0xbc, 0x5d]),
+        Some(&[0xbe, 0xc4]),
+        None,
+        Some(&[0xef, 0xc0]),
+        Some(&[0xf6, 0xda]),
+        Some(&[0xf7, 0xd4]),
+        Some(&[0xa4, 0x6b]),
+        Some(&[0xa5, 0xa3]),
+        None,
+        Some(&[0xa5, 0xa4]),
+        Some(&[0xc9, 0xd1]),
+        Some(&[0xa6, 0x6c]),
+        Some(&[0xa6, 0x6f]),
+        None,
+        Some(&[0xc9, 0xcf]),
+        Some(&[0xc9, 0xcd]),
+        Some(&[0xa6, 0x6e]),
+        Some(&[0xc9, 0xd0]),
+        Some(&[0xc9, 0xd2]),
+        Some(&[0xc9, 0xcc]),
+        Some(&[0xa6, 0x71]),
+        Some(&[0xa6, 0x70]),
+        Some(&[0xa6, 0x6d]),
+        Some(&[0xa6, 0x6b]),
+        Some(&[0xc9, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xb3]),
+        None,
+        None,
+        Some(&[0xa7, 0xb0]),
+        Some(&[0xca, 0xb6]),
+        Some(&[0xca, 0xb9]),
+        Some(&[0xca, 0xb8]),
+        None,
+        Some(&[0xa7, 0xaa]),
+        Some(&[0xa7, 0xb2]),
+        None,
+        None,
+        Some(&[0xa7, 0xaf]),
+        Some(&[0xca, 0xb5]),
+        Some(&[0xca, 0xb3]),
+        Some(&[0xa7, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xa9]),
+        Some(&[0xa7, 0xac]),
+        None,
+        Some(&[0xca, 0xb4]),
+        Some(&[0xca, 0xbb]),
+        Some(&[0xca, 0xb7]),
+        Some(&[0xa7, 0xad]),
+        Some(&[0xa7, 0xb1]),
+        Some(&[0xa7, 0xb4]),
+        Some(&[0xca, 0xb2]),
+        Some(&[0xca, 0xba]),
+        Some(&[0xa7, 0xab]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0x67]),
+        Some(&[0xa9, 0x6f]),
+        None,
+        Some(&[0xcc, 0x4f]),
+        Some(&[0xcc, 0x48]),
+        Some(&[0xa9, 0x70]),
+        Some(&[0xcc, 0x53]),
+        Some(&[0xcc, 0x44]),
+        Some(&[0xcc, 0x4b]),
+        None,
+        None,
+        Some(&[0xa9, 0x66]),
+        Some(&[0xcc, 0x45]),
+        Some(&[0xa9, 0x64]),
+        Some(&[0xcc, 0x4c]),
+        Some(&[0xcc, 0x50]),
+        Some(&[0xa9, 0x63]),
+        None,
+        Some(&[0xcc, 0x51]),
+        Some(&[0xcc, 0x4a]),
+        None,
+        Some(&[0xcc, 0x4d]),
+        None,
+        Some(&[0xa9, 0x72]),
+        Some(&[0xa9, 0x69]),
+        Some(&[0xcc, 0x54]),
+        Some(&[0xcc, 0x52]),
+        None,
+        Some(&[0xa9, 0x6e]),
+        Some(&[0xa9, 0x6c]),
+        Some(&[0xcc, 0x49]),
+        Some(&[0xa9, 0x6b]),
+        Some(&[0xcc, 0x47]),
+        Some(&[0xcc, 0x46]),
+        Some(&[0xa9, 0x6a]),
+        Some(&[0xa9, 0x68]),
+        Some(&[0xa9, 0x71]),
+        Some(&[0xa9, 0x6d]),
+        Some(&[0xa9, 0x65]),
+        None,
+        Some(&[0xcc, 0x4e]),
+        None,
+        Some(&[0xab, 0xb9]),
+        None,
+        Some(&[0xab, 0xc0]),
+        Some(&[0xce, 0x6f]),
+        Some(&[0xab, 0xb8]),
+        Some(&[0xce, 0x67]),
+        Some(&[0xce, 0x63]),
+        None,
+        Some(&[0xce, 0x73]),
+        Some(&[0xce, 0x62]),
+        None,
+        Some(&[0xab, 0xbb]),
+        Some(&[0xce, 0x6c]),
+        Some(&[0xab, 0xbe]),
+        Some(&[0xab, 0xc1]),
+        None,
+        Some(&[0xab, 0xbc]),
+        Some(&[0xce, 0x70]),
+        Some(&[0xab, 0xbf]),
+        None,
+        Some(&[0xae, 0x56]),
+        Some(&[0xce, 0x76]),
+        Some(&[0xce, 0x64]),
+        None,
+        None,
+        Some(&[0xce, 0x66]),
+        Some(&[0xce, 0x6d]),
+        Some(&[0xce, 0x71]),
+        Some(&[0xce, 0x75]),
+        Some(&[0xce, 0x72]),
+        Some(&[0xce, 0x6b]),
+        Some(&[0xce, 0x6e]),
+        None,
+        None,
+        Some(&[0xce, 0x68]),
+        Some(&[0xab, 0xc3]),
+        Some(&[0xce, 0x6a]),
+        Some(&[0xce, 0x69]),
+        Some(&[0xce, 0x74]),
+        Some(&[0xab, 0xba]),
+        Some(&[0xce, 0x65]),
+        Some(&[0xab, 0xc2]),
+        None,
+        Some(&[0xab, 0xbd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xae, 0x5c]),
+        Some(&[0xd1, 0x62]),
+        None,
+        Some(&[0xae, 0x5b]),
+        None,
+        None,
+        Some(&[0xd1, 0x60]),
+        None,
+        Some(&[0xae, 0x50]),
+        None,
+        Some(&[0xae, 0x55]),
+        None,
+        Some(&[0xd1, 0x5f]),
+        Some(&[0xd1, 0x5c]),
+        Some(&[0xd1, 0x61]),
+        Some(&[0xae, 0x51]),
+        Some(&[0xd1, 0x5b]),
+        None,
+        Some(&[0xae, 0x54]),
+        Some(&[0xae, 0x52]),
+        None,
+        Some(&[0xd1, 0x63]),
+        Some(&[0xae, 0x53]),
+        Some(&[0xae, 0x57]),
+        None,
+        None,
+        Some(&[0xae, 0x58]),
+        None,
+        Some(&[0xae, 0x5a]),
+        None,
+        None,
+        None,
+        Some(&[0xae, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0x5d]),
+        Some(&[0xd1, 0x5e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0x64]),
+        None,
+        Some(&[0xd4, 0xd4]),
+        Some(&[0xb0, 0xf9]),
+        Some(&[0xd8, 0xc2]),
+        Some(&[0xd4, 0xd3]),
+        Some(&[0xd4, 0xe6]),
+        None,
+        None,
+        Some(&[0xb1, 0x40]),
+        None,
+        Some(&[0xd4, 0xe4]),
+        None,
+        Some(&[0xb0, 0xfe]),
+        Some(&[0xb0, 0xfa]),
+        Some(&[0xd4, 0xed]),
+        Some(&[0xd4, 0xdd]),
+        Some(&[0xd4, 0xe0]),
+        None,
+        Some(&[0xb1, 0x43]),
+        Some(&[0xd4, 0xea]),
+        Some(&[0xd4, 0xe2]),
+        Some(&[0xb0, 0xfb]),
+        Some(&[0xb1, 0x44]),
+        None,
+        Some(&[0xd4, 0xe7]),
+        Some(&[0xd4, 0xe5]),
+        None,
+        None,
+        Some(&[0xd4, 0xd6]),
+        Some(&[0xd4, 0xeb]),
+        Some(&[0xd4, 0xdf]),
+        Some(&[0xd4, 0xda]),
+        None,
+        Some(&[0xd4, 0xd0]),
+        Some(&[0xd4, 0xec]),
+        Some(&[0xd4, 0xdc]),
+        Some(&[0xd4, 0xcf]),
+        None,
+        Some(&[0xb1, 0x42]),
+        Some(&[0xd4, 0xe1]),
+        Some(&[0xd4, 0xee]),
+        Some(&[0xd4, 0xde]),
+        Some(&[0xd4, 0xd2]),
+        Some(&[0xd4, 0xd7]),
+        Some(&[0xd4, 0xce]),
+        None,
+        Some(&[0xb1, 0x41]),
+        None,
+        Some(&[0xd4, 0xdb]),
+        Some(&[0xd4, 0xd8]),
+        Some(&[0xb0, 0xfc]),
+        Some(&[0xd4, 0xd1]),
+        None,
+        Some(&[0xd4, 0xe9]),
+        Some(&[0xb0, 0xfd]),
+        None,
+        Some(&[0xd4, 0xd9]),
+        Some(&[0xd4, 0xd5]),
+        None,
+        None,
+        Some(&[0xd4, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x40]),
+        Some(&[0xd8, 0xbb]),
+        None,
+        Some(&[0xd8, 0xb8]),
+        Some(&[0xd8, 0xc9]),
+        Some(&[0xd8, 0xbd]),
+        Some(&[0xd8, 0xca]),
+        None,
+        Some(&[0xb4, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xc6]),
+        Some(&[0xd8, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xc4]),
+        Some(&[0xd8, 0xc7]),
+        Some(&[0xd8, 0xcb]),
+        None,
+        Some(&[0xd4, 0xe3]),
+        Some(&[0xd8, 0xcd]),
+        Some(&[0xdd, 0x47]),
+        None,
+        Some(&[0xb4, 0x43]),
+        Some(&[0xd8, 0xce]),
+        Some(&[0xd8, 0xb6]),
+        Some(&[0xd8, 0xc0]),
+        None,
+        Some(&[0xd8, 0xc5]),
+        None,
+        None,
+        Some(&[0xb4, 0x41]),
+        Some(&[0xb4, 0x44]),
+        Some(&[0xd8, 0xcc]),
+        Some(&[0xd8, 0xcf]),
+        Some(&[0xd8, 0xba]),
+        Some(&[0xd8, 0xb7]),
+        None,
+        None,
+        Some(&[0xd8, 0xb9]),
+        None,
+        None,
+        Some(&[0xd8, 0xbe]),
+        Some(&[0xd8, 0xbc]),
+        Some(&[0xb4, 0x45]),
+        None,
+        Some(&[0xd8, 0xc8]),
+        None,
+        None,
+        Some(&[0xd8, 0xbf]),
+        None,
+        Some(&[0xd8, 0xc1]),
+        Some(&[0xd8, 0xb5]),
+        Some(&[0xdc, 0xfa]),
+        Some(&[0xdc, 0xf8]),
+        Some(&[0xb7, 0x42]),
+        Some(&[0xb7, 0x40]),
+        Some(&[0xdd, 0x43]),
+        Some(&[0xdc, 0xf9]),
+        Some(&[0xdd, 0x44]),
+        Some(&[0xdd, 0x40]),
+        Some(&[0xdc, 0xf7]),
+        Some(&[0xdd, 0x46]),
+        Some(&[0xdc, 0xf6]),
+        Some(&[0xdc, 0xfd]),
+        Some(&[0xb6, 0xfe]),
+        Some(&[0xb6, 0xfd]),
+        Some(&[0xb6, 0xfc]),
+        Some(&[0xdc, 0xfb]),
+        Some(&[0xdd, 0x41]),
+        Some(&[0xb6, 0xf9]),
+        Some(&[0xb7, 0x41]),
+        None,
+        Some(&[0xdc, 0xf4]),
+        None,
+        Some(&[0xdc, 0xfe]),
+        Some(&[0xdc, 0xf3]),
+        Some(&[0xdc, 0xfc]),
+        Some(&[0xb6, 0xfa]),
+        Some(&[0xdd, 0x42]),
+        Some(&[0xdc, 0xf5]),
+        Some(&[0xb6, 0xfb]),
+        Some(&[0xdd, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x6e]),
+        Some(&[0xb9, 0xe2]),
+        Some(&[0xb9, 0xe1]),
+        Some(&[0xb9, 0xe3]),
+        Some(&[0xe1, 0x7a]),
+        Some(&[0xe1, 0x70]),
+        Some(&[0xe1, 0x76]),
+        Some(&[0xe1, 0x6b]),
+        Some(&[0xe1, 0x79]),
+        Some(&[0xe1, 0x78]),
+        Some(&[0xe1, 0x7c]),
+        Some(&[0xe1, 0x75]),
+        Some(&[0xb9, 0xde]),
+        Some(&[0xe1, 0x74]),
+        Some(&[0xb9, 0xe4]),
+        None,
+        Some(&[0xe1, 0x6d]),
+        Some(&[0xb9, 0xdf]),
+        None,
+        Some(&[0xe1, 0x7b]),
+        Some(&[0xb9, 0xe0]),
+        Some(&[0xe1, 0x6f]),
+        Some(&[0xe1, 0x72]),
+        Some(&[0xe1, 0x77]),
+        Some(&[0xe1, 0x71]),
+        Some(&[0xe1, 0x6c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0x73]),
+        Some(&[0xe5, 0x55]),
+        Some(&[0xbc, 0x61]),
+        Some(&[0xe5, 0x58]),
+        Some(&[0xe5, 0x57]),
+        Some(&[0xe5, 0x5a]),
+        Some(&[0xe5, 0x5c]),
+        Some(&[0xf9, 0xdc]),
+        Some(&[0xbc, 0x5f]),
+        None,
+        Some(&[0xe5, 0x56]),
+        None,
+        Some(&[0xe5, 0x54]),
+        None,
+        Some(&[0xe5, 0x5d]),
+        Some(&[0xe5, 0x5b]),
+        Some(&[0xe5, 0x59]),
+        None,
+        Some(&[0xe5, 0x5f]),
+        None,
+        Some(&[0xe5, 0x5e]),
+        Some(&[0xbc, 0x63]),
+        Some(&[0xbc, 0x5e]),
+        None,
+        Some(&[0xbc, 0x60]),
+        Some(&[0xbc, 0x62]),
+        None,
+        None,
+        Some(&[0xe5, 0x60]),
+        Some(&[0xe9, 0x57]),
+        None,
+        None,
+        Some(&[0xe9, 0x56]),
+        Some(&[0xe9, 0x55]),
+        None,
+        Some(&[0xe9, 0x58]),
+        Some(&[0xe9, 0x51]),
+        None,
+        Some(&[0xe9, 0x52]),
+        Some(&[0xe9, 0x5a]),
+        Some(&[0xe9, 0x53]),
+        None,
+        Some(&[0xbe, 0xc5]),
+        Some(&[0xe9, 0x5c]),
+        None,
+        Some(&[0xe9, 0x5b]),
+        Some(&[0xe9, 0x54]),
+        None,
+        Some(&[0xec, 0xd1]),
+        Some(&[0xc0, 0xa8]),
+        Some(&[0xec, 0xcf]),
+        Some(&[0xec, 0xd4]),
+        Some(&[0xec, 0xd3]),
+        Some(&[0xe9, 0x59]),
+        None,
+        Some(&[0xc0, 0xa7]),
+        None,
+        Some(&[0xec, 0xd2]),
+        Some(&[0xec, 0xce]),
+        Some(&[0xec, 0xd6]),
+        Some(&[0xec, 0xd5]),
+        Some(&[0xc0, 0xa6]),
+        None,
+        Some(&[0xec, 0xd0]),
+        None,
+        Some(&[0xbe, 0xc6]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xc1]),
+        Some(&[0xf1, 0xfa]),
+        Some(&[0xf1, 0xfb]),
+        Some(&[0xf1, 0xfc]),
+        Some(&[0xc4, 0x5c]),
+        None,
+        None,
+        Some(&[0xc4, 0x5d]),
+        None,
+        Some(&[0xf4, 0x43]),
+        None,
+        Some(&[0xf5, 0xc8]),
+        Some(&[0xf5, 0xc7]),
+        None,
+        None,
+        Some(&[0xf6, 0xdb]),
+        Some(&[0xf6, 0xdc]),
+        Some(&[0xf7, 0xd5]),
+        Some(&[0xf8, 0xa7]),
+        None,
+        Some(&[0xa4, 0x6c]),
+        Some(&[0xa4, 0x6d]),
+        None,
+        Some(&[0xa4, 0x6e]),
+        Some(&[0xa4, 0xd5]),
+        Some(&[0xa5, 0xa5]),
+        Some(&[0xc9, 0xd3]),
+        Some(&[0xa6, 0x72]),
+        Some(&[0xa6, 0x73]),
+        None,
+        Some(&[0xa7, 0xb7]),
+        Some(&[0xa7, 0xb8]),
+        Some(&[0xa7, 0xb6]),
+        Some(&[0xa7, 0xb5]),
+        None,
+        Some(&[0xa9, 0x73]),
+        None,
+        None,
+        Some(&[0xcc, 0x55]),
+        Some(&[0xa9, 0x75]),
+        Some(&[0xa9, 0x74]),
+        Some(&[0xcc, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xc4]),
+        None,
+        Some(&[0xae, 0x5d]),
+        Some(&[0xd1, 0x65]),
+        None,
+        Some(&[0xd4, 0xf0]),
+        None,
+        Some(&[0xb1, 0x45]),
+        Some(&[0xb4, 0x47]),
+        Some(&[0xd4, 0xef]),
+        Some(&[0xb4, 0x46]),
+        None,
+        Some(&[0xb9, 0xe5]),
+        None,
+        Some(&[0xe1, 0x7d]),
+        Some(&[0xbe, 0xc7]),
+        None,
+        Some(&[0xc0, 0xa9]),
+        Some(&[0xec, 0xd7]),
+        None,
+        Some(&[0xc4, 0x5e]),
+        None,
+        Some(&[0xc5, 0x70]),
+        None,
+        Some(&[0xc9, 0x72]),
+        None,
+        Some(&[0xa5, 0xa6]),
+        Some(&[0xc9, 0x73]),
+        Some(&[0xa6, 0x76]),
+        None,
+        Some(&[0xa6, 0x74]),
+        Some(&[0xa6, 0x75]),
+        Some(&[0xa6, 0x77]),
+        None,
+        Some(&[0xa7, 0xba]),
+        Some(&[0xa7, 0xb9]),
+        None,
+        Some(&[0xca, 0xbc]),
+        Some(&[0xa7, 0xbb]),
+        None,
+        None,
+        Some(&[0xca, 0xbd]),
+        Some(&[0xcc, 0x57]),
+        None,
+        Some(&[0xcc, 0x58]),
+        None,
+        Some(&[0xa9, 0x76]),
+        Some(&[0xa9, 0x78]),
+        Some(&[0xa9, 0x7a]),
+        Some(&[0xa9, 0x77]),
+        Some(&[0xa9, 0x7b]),
+        Some(&[0xa9, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xc8]),
+        Some(&[0xab, 0xc5]),
+        Some(&[0xab, 0xc7]),
+        Some(&[0xab, 0xc9]),
+        Some(&[0xab, 0xc6]),
+        Some(&[0xd1, 0x66]),
+        Some(&[0xce, 0x77]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0x68]),
+        Some(&[0xd1, 0x67]),
+        Some(&[0xae, 0x63]),
+        None,
+        Some(&[0xae, 0x5f]),
+        None,
+        None,
+        Some(&[0xae, 0x60]),
+        Some(&[0xae, 0x62]),
+        Some(&[0xae, 0x64]),
+        Some(&[0xae, 0x61]),
+        None,
+        Some(&[0xae, 0x66]),
+        Some(&[0xae, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x4a]),
+        Some(&[0xd4, 0xf2]),
+        Some(&[0xd4, 0xf1]),
+        Some(&[0xb1, 0x49]),
+        None,
+        Some(&[0xb1, 0x48]),
+        Some(&[0xb1, 0x47]),
+        Some(&[0xb1, 0x4b]),
+        Some(&[0xb1, 0x46]),
+        None,
+        None,
+        Some(&[0xd8, 0xd5]),
+        Some(&[0xd8, 0xd2]),
+        Some(&[0xb4, 0x49]),
+        Some(&[0xd8, 0xd1]),
+        Some(&[0xd8, 0xd6]),
+        None,
+        Some(&[0xb4, 0x4b]),
+        Some(&[0xd8, 0xd4]),
+        Some(&[0xb4, 0x48]),
+        Some(&[0xb4, 0x4a]),
+        Some(&[0xd8, 0xd3]),
+        None,
+        Some(&[0xdd, 0x48]),
+        None,
+        Some(&[0xdd, 0x49]),
+        Some(&[0xdd, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xe6]),
+        Some(&[0xb9, 0xee]),
+        Some(&[0xe1, 0x7e]),
+        Some(&[0xb9, 0xe8]),
+        Some(&[0xb9, 0xec]),
+        Some(&[0xe1, 0xa1]),
+        Some(&[0xb9, 0xed]),
+        Some(&[0xb9, 0xe9]),
+        Some(&[0xb9, 0xea]),
+        Some(&[0xb9, 0xe7]),
+        Some(&[0xb9, 0xeb]),
+        Some(&[0xbc, 0x66]),
+        Some(&[0xd8, 0xd0]),
+        Some(&[0xbc, 0x67]),
+        Some(&[0xbc, 0x65]),
+        None,
+        Some(&[0xbc, 0x64]),
+        Some(&[0xe9, 0x5d]),
+        Some(&[0xbe, 0xc8]),
+        Some(&[0xec, 0xd8]),
+        Some(&[0xec, 0xd9]),
+        None,
+        None,
+        Some(&[0xc3, 0x64]),
+        Some(&[0xc4, 0x5f]),
+        None,
+        Some(&[0xa4, 0x6f]),
+        None,
+        Some(&[0xa6, 0x78]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xca]),
+        None,
+        Some(&[0xd1, 0x69]),
+        Some(&[0xae, 0x67]),
+        None,
+        None,
+        Some(&[0xb1, 0x4e]),
+        Some(&[0xb1, 0x4d]),
+        Some(&[0xb1, 0x4c]),
+        Some(&[0xb4, 0x4c]),
+        Some(&[0xb4, 0x4d]),
+        Some(&[0xd8, 0xd7]),
+        Some(&[0xb9, 0xef]),
+        Some(&[0xbe, 0xc9]),
+        Some(&[0xa4, 0x70]),
+        Some(&[0xc9, 0x5c]),
+        Some(&[0xa4, 0xd6]),
+        Some(&[0xc9, 0x74]),
+        None,
+        None,
+        Some(&[0xc9, 0xd4]),
+        Some(&[0xa6, 0x79]),
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0x4b]),
+        None,
+        None,
+        Some(&[0xa4, 0x71]),
+        None,
+        Some(&[0xa4, 0xd7]),
+        Some(&[0xc9, 0xd5]),
+        None,
+        None,
+        Some(&[0xca, 0xbe]),
+        None,
+        Some(&[0xca, 0xbf]),
+        None,
+        Some(&[0xa7, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xd8]),
+        Some(&[0xb4, 0x4e]),
+        None,
+        Some(&[0xdd, 0x4c]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xaa]),
+        Some(&[0xa4, 0x72]),
+        Some(&[0xa4, 0xa8]),
+        Some(&[0xa4, 0xd8]),
+        Some(&[0xc9, 0x75]),
+        Some(&[0xa5, 0xa7]),
+        None,
+        Some(&[0xa7, 0xc0]),
+        Some(&[0xa7, 0xbf]),
+        Some(&[0xa7, 0xbd]),
+        Some(&[0xa7, 0xbe]),
+        None,
+        None,
+        Some(&[0xcc, 0x59]),
+        Some(&[0xa9, 0x7e]),
+        Some(&[0xa9, 0xa1]),
+        Some(&[0xcc, 0x5a]),
+        Some(&[0xa9, 0x7d]),
+        None,
+        None,
+        Some(&[0xab, 0xce]),
+        Some(&[0xce, 0x78]),
+        Some(&[0xab, 0xcd]),
+        Some(&[0xab, 0xcb]),
+        Some(&[0xab, 0xcc]),
+        Some(&[0xae, 0x6a]),
+        Some(&[0xae, 0x68]),
+        None,
+        None,
+        Some(&[0xd1, 0x6b]),
+        Some(&[0xae, 0x69]),
+        Some(&[0xd1, 0x6a]),
+        None,
+        Some(&[0xae, 0x5e]),
+        Some(&[0xd4, 0xf3]),
+        None,
+        None,
+        Some(&[0xb1, 0x50]),
+        Some(&[0xb1, 0x51]),
+        None,
+        None,
+        Some(&[0xb1, 0x4f]),
+        None,
+        Some(&[0xb9, 0xf0]),
+        Some(&[0xe1, 0xa2]),
+        Some(&[0xbc, 0x68]),
+        Some(&[0xbc, 0x69]),
+        None,
+        Some(&[0xe5, 0x61]),
+        Some(&[0xc0, 0xab]),
+        Some(&[0xef, 0xc2]),
+        Some(&[0xef, 0xc3]),
+        None,
+        Some(&[0xc4, 0xdd]),
+        Some(&[0xf8, 0xa8]),
+        Some(&[0xc9, 0x4b]),
+        Some(&[0xa4, 0xd9]),
+        None,
+        Some(&[0xa4, 0x73]),
+        None,
+        Some(&[0xc9, 0x77]),
+        Some(&[0xc9, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0x7a]),
+        Some(&[0xc9, 0xd7]),
+        Some(&[0xc9, 0xd8]),
+        Some(&[0xc9, 0xd6]),
+        None,
+        Some(&[0xc9, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xc7]),
+        None,
+        Some(&[0xca, 0xc2]),
+        Some(&[0xca, 0xc4]),
+        Some(&[0xca, 0xc6]),
+        Some(&[0xca, 0xc3]),
+        Some(&[0xa7, 0xc4]),
+        Some(&[0xca, 0xc0]),
+        None,
+        Some(&[0xca, 0xc1]),
+        Some(&[0xa7, 0xc1]),
+        Some(&[0xa7, 0xc2]),
+        Some(&[0xca, 0xc5]),
+        Some(&[0xca, 0xc8]),
+        Some(&[0xa7, 0xc3]),
+        Some(&[0xca, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0x68]),
+        None,
+        Some(&[0xcc, 0x62]),
+        Some(&[0xcc, 0x5d]),
+        Some(&[0xa9, 0xa3]),
+        Some(&[0xcc, 0x65]),
+        Some(&[0xcc, 0x63]),
+        Some(&[0xcc, 0x5c]),
+        Some(&[0xcc, 0x69]),
+        Some(&[0xcc, 0x6c]),
+        Some(&[0xcc, 0x67]),
+        Some(&[0xcc, 0x60]),
+        Some(&[0xa9, 0xa5]),
+        Some(&[0xcc, 0x66]),
+        Some(&[0xa9, 0xa6]),
+        Some(&[0xcc, 0x61]),
+        Some(&[0xcc, 0x64]),
+        Some(&[0xcc, 0x5b]),
+        Some(&[0xcc, 0x5f]),
+        Some(&[0xcc, 0x6b]),
+        Some(&[0xa9, 0xa7]),
+        None,
+        Some(&[0xa9, 0xa8]),
+        None,
+        Some(&[0xcc, 0x5e]),
+        Some(&[0xcc, 0x6a]),
+        Some(&[0xa9, 0xa2]),
+        Some(&[0xa9, 0xa4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xab]),
+        Some(&[0xce, 0xa4]),
+        Some(&[0xce, 0xaa]),
+        Some(&[0xce, 0xa3]),
+        Some(&[0xce, 0xa5]),
+        Some(&[0xce, 0x7d]),
+        Some(&[0xce, 0x7b]),
+        None,
+        Some(&[0xce, 0xac]),
+        Some(&[0xce, 0xa9]),
+        Some(&[0xce, 0x79]),
+        None,
+        Some(&[0xab, 0xd0]),
+        Some(&[0xce, 0xa7]),
+        Some(&[0xce, 0xa8]),
+        None,
+        Some(&[0xce, 0xa6]),
+        Some(&[0xce, 0x7c]),
+        Some(&[0xce, 0x7a]),
+        Some(&[0xab, 0xcf]),
+        Some(&[0xce, 0xa2]),
+        Some(&[0xce, 0x7e]),
+        None,
+        None,
+        Some(&[0xce, 0xa1]),
+        Some(&[0xce, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xae, 0x6f]),
+        None,
+        Some(&[0xae, 0x6e]),
+        None,
+        Some(&[0xd1, 0x6c]),
+        Some(&[0xae, 0x6b]),
+        Some(&[0xd1, 0x6e]),
+        None,
+        Some(&[0xae, 0x70]),
+        Some(&[0xd1, 0x6f]),
+        None,
+        None,
+        Some(&[0xae, 0x73]),
+        None,
+        Some(&[0xae, 0x71]),
+        Some(&[0xd1, 0x70]),
+        Some(&[0xce, 0xae]),
+        Some(&[0xd1, 0x72]),
+        None,
+        Some(&[0xae, 0x6d]),
+        None,
+        Some(&[0xae, 0x6c]),
+        None,
+        Some(&[0xd1, 0x6d]),
+        Some(&[0xd1, 0x71]),
+        Some(&[0xae, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x53]),
+        Some(&[0xb1, 0x52]),
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0xf5]),
+        Some(&[0xd4, 0xf9]),
+        Some(&[0xd4, 0xfb]),
+        Some(&[0xb1, 0x54]),
+        Some(&[0xd4, 0xfe]),
+        None,
+        Some(&[0xb1, 0x58]),
+        Some(&[0xd5, 0x41]),
+        None,
+        Some(&[0xb1, 0x5a]),
+        None,
+        Some(&[0xb1, 0x56]),
+        Some(&[0xb1, 0x5e]),
+        None,
+        Some(&[0xb1, 0x5b]),
+        Some(&[0xd4, 0xf7]),
+        Some(&[0xb1, 0x55]),
+        None,
+        Some(&[0xd4, 0xf6]),
+        Some(&[0xd4, 0xf4]),
+        Some(&[0xd5, 0x43]),
+        Some(&[0xd4, 0xf8]),
+        None,
+        Some(&[0xb1, 0x57]),
+        Some(&[0xd5, 0x42]),
+        Some(&[0xb1, 0x5c]),
+        Some(&[0xd4, 0xfd]),
+        Some(&[0xd4, 0xfc]),
+        Some(&[0xb1, 0x5d]),
+        Some(&[0xd4, 0xfa]),
+        Some(&[0xb1, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0x44]),
+        None,
+        Some(&[0xd5, 0x40]),
+        Some(&[0xd8, 0xe7]),
+        Some(&[0xd8, 0xee]),
+        Some(&[0xd8, 0xe3]),
+        Some(&[0xb4, 0x51]),
+        Some(&[0xd8, 0xdf]),
+        Some(&[0xd8, 0xef]),
+        Some(&[0xd8, 0xd9]),
+        Some(&[0xd8, 0xec]),
+        Some(&[0xd8, 0xea]),
+        Some(&[0xd8, 0xe4]),
+        None,
+        Some(&[0xd8, 0xed]),
+        Some(&[0xd8, 0xe6]),
+        None,
+        Some(&[0xd8, 0xde]),
+        Some(&[0xd8, 0xf0]),
+        Some(&[0xd8, 0xdc]),
+        Some(&[0xd8, 0xe9]),
+        Some(&[0xd8, 0xda]),
+        None,
+        Some(&[0xd8, 0xf1]),
+        None,
+        Some(&[0xb4, 0x52]),
+        None,
+        Some(&[0xd8, 0xeb]),
+        Some(&[0xdd, 0x4f]),
+        Some(&[0xd8, 0xdd]),
+        Some(&[0xb4, 0x4f]),
+        None,
+        Some(&[0xd8, 0xe1]),
+        None,
+        Some(&[0xb4, 0x50]),
+        Some(&[0xd8, 0xe0]),
+        Some(&[0xd8, 0xe5]),
+        None,
+        None,
+        Some(&[0xd8, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0x53]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0x56]),
+        Some(&[0xdd, 0x4e]),
+        None,
+        Some(&[0xdd, 0x50]),
+        None,
+        Some(&[0xdd, 0x55]),
+        Some(&[0xdd, 0x54]),
+        Some(&[0xb7, 0x43]),
+        None,
+        Some(&[0xd8, 0xdb]),
+        Some(&[0xdd, 0x52]),
+        None,
+        None,
+        Some(&[0xb7, 0x44]),
+        None,
+        Some(&[0xdd, 0x4d]),
+        Some(&[0xdd, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xa9]),
+        None,
+        Some(&[0xe1, 0xb0]),
+        Some(&[0xe1, 0xa7]),
+        None,
+        Some(&[0xe1, 0xae]),
+        Some(&[0xe1, 0xa5]),
+        Some(&[0xe1, 0xad]),
+        Some(&[0xe1, 0xb1]),
+        Some(&[0xe1, 0xa4]),
+        Some(&[0xe1, 0xa8]),
+        Some(&[0xe1, 0xa3]),
+        None,
+        Some(&[0xb9, 0xf1]),
+        None,
+        Some(&[0xe1, 0xa6]),
+        Some(&[0xb9, 0xf2]),
+        Some(&[0xe1, 0xac]),
+        Some(&[0xe1, 0xab]),
+        Some(&[0xe1, 0xaa]),
+        None,
+        None,
+        Some(&[0xe1, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x65]),
+        Some(&[0xe5, 0x67]),
+        Some(&[0xbc, 0x6b]),
+        Some(&[0xe5, 0x68]),
+        None,
+        Some(&[0xe5, 0x63]),
+        None,
+        Some(&[0xe5, 0x62]),
+        Some(&[0xe5, 0x6c]),
+        None,
+        Some(&[0xe5, 0x6a]),
+        Some(&[0xbc, 0x6a]),
+        Some(&[0xe5, 0x6d]),
+        Some(&[0xe5, 0x64]),
+        Some(&[0xe5, 0x69]),
+        Some(&[0xe5, 0x6b]),
+        Some(&[0xe5, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0x61]),
+        Some(&[0xe9, 0x66]),
+        Some(&[0xe9, 0x60]),
+        Some(&[0xe9, 0x65]),
+        None,
+        Some(&[0xe9, 0x5e]),
+        Some(&[0xe9, 0x68]),
+        Some(&[0xe9, 0x64]),
+        Some(&[0xe9, 0x69]),
+        Some(&[0xe9, 0x63]),
+        Some(&[0xe9, 0x5f]),
+        Some(&[0xe9, 0x67]),
+        None,
+        Some(&[0xe9, 0x6a]),
+        Some(&[0xe9, 0x62]),
+        None,
+        Some(&[0xec, 0xda]),
+        Some(&[0xc0, 0xaf]),
+        None,
+        Some(&[0xc0, 0xad]),
+        None,
+        Some(&[0xc0, 0xac]),
+        Some(&[0xc0, 0xae]),
+        None,
+        None,
+        Some(&[0xef, 0xc4]),
+        None,
+        Some(&[0xf1, 0x72]),
+        Some(&[0xf1, 0xfd]),
+        None,
+        None,
+        Some(&[0xf4, 0x44]),
+        Some(&[0xf4, 0x45]),
+        None,
+        Some(&[0xc4, 0x60]),
+        None,
+        Some(&[0xf5, 0xc9]),
+        None,
+        Some(&[0xc4, 0xde]),
+        None,
+        Some(&[0xf5, 0xca]),
+        None,
+        Some(&[0xf6, 0xde]),
+        Some(&[0xc5, 0x72]),
+        None,
+        Some(&[0xc5, 0x71]),
+        Some(&[0xf6, 0xdd]),
+        Some(&[0xc5, 0xc9]),
+        None,
+        Some(&[0xf7, 0xd6]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0x74]),
+        Some(&[0xa6, 0x7b]),
+        Some(&[0xc9, 0xda]),
+        Some(&[0xca, 0xca]),
+        Some(&[0xa8, 0xb5]),
+        Some(&[0xb1, 0x5f]),
+        None,
+        None,
+        Some(&[0xa4, 0x75]),
+        Some(&[0xa5, 0xaa]),
+        Some(&[0xa5, 0xa9]),
+        Some(&[0xa5, 0xa8]),
+        None,
+        None,
+        Some(&[0xa7, 0xc5]),
+        None,
+        None,
+        Some(&[0xae, 0x74]),
+        None,
+        Some(&[0xdd, 0x57]),
+        Some(&[0xa4, 0x76]),
+        Some(&[0xa4, 0x77]),
+        Some(&[0xa4, 0x78]),
+        Some(&[0xa4, 0xda]),
+        None,
+        None,
+        Some(&[0xab, 0xd1]),
+        None,
+        Some(&[0xce, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x53]),
+        Some(&[0xa4, 0x79]),
+        Some(&[0xc9, 0x5d]),
+        None,
+        None,
+        Some(&[0xa5, 0xab]),
+        Some(&[0xa5, 0xac]),
+        Some(&[0xc9, 0x78]),
+        None,
+        Some(&[0xa6, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xcb]),
+        None,
+        Some(&[0xa7, 0xc6]),
+        None,
+        Some(&[0xca, 0xcc]),
+        None,
+        None,
+        Some(&[0xa9, 0xae]),
+        None,
+        None,
+        Some(&[0xcc, 0x6e]),
+        Some(&[0xa9, 0xac]),
+        Some(&[0xa9, 0xab]),
+        Some(&[0xcc, 0x6d]),
+        Some(&[0xa9, 0xa9]),
+        Some(&[0xcc, 0x6f]),
+        Some(&[0xa9, 0xaa]),
+        Some(&[0xa9, 0xad]),
+        None,
+        Some(&[0xab, 0xd2]),
+        None,
+        Some(&[0xab, 0xd4]),
+        Some(&[0xce, 0xb3]),
+        Some(&[0xce, 0xb0]),
+        Some(&[0xce, 0xb1]),
+        Some(&[0xce, 0xb2]),
+        Some(&[0xce, 0xb4]),
+        Some(&[0xab, 0xd3]),
+        None,
+        None,
+        Some(&[0xd1, 0x74]),
+        Some(&[0xd1, 0x73]),
+        None,
+        Some(&[0xae, 0x76]),
+        None,
+        Some(&[0xae, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x62]),
+        Some(&[0xd5, 0x46]),
+        None,
+        Some(&[0xb1, 0x61]),
+        Some(&[0xb1, 0x63]),
+        Some(&[0xb1, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x55]),
+        Some(&[0xd5, 0x45]),
+        None,
+        Some(&[0xb4, 0x56]),
+        Some(&[0xd8, 0xf3]),
+        None,
+        Some(&[0xb4, 0x57]),
+        Some(&[0xd8, 0xf2]),
+        Some(&[0xb4, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0x5a]),
+        Some(&[0xdd, 0x5c]),
+        Some(&[0xb7, 0x45]),
+        Some(&[0xdd, 0x5b]),
+        Some(&[0xdd, 0x59]),
+        Some(&[0xdd, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xb4]),
+        Some(&[0xb9, 0xf7]),
+        Some(&[0xb9, 0xf5]),
+        None,
+        Some(&[0xb9, 0xf6]),
+        Some(&[0xe1, 0xb2]),
+        Some(&[0xe1, 0xb3]),
+        None,
+        Some(&[0xb9, 0xf3]),
+        Some(&[0xe5, 0x71]),
+        Some(&[0xe5, 0x6f]),
+        None,
+        Some(&[0xbc, 0x6d]),
+        Some(&[0xe5, 0x70]),
+        Some(&[0xbc, 0x6e]),
+        Some(&[0xbc, 0x6c]),
+        Some(&[0xb9, 0xf4]),
+        None,
+        None,
+        Some(&[0xe9, 0x6d]),
+        Some(&[0xe9, 0x6b]),
+        Some(&[0xe9, 0x6c]),
+        Some(&[0xe5, 0x6e]),
+        Some(&[0xec, 0xdc]),
+        Some(&[0xc0, 0xb0]),
+        Some(&[0xec, 0xdb]),
+        Some(&[0xef, 0xc5]),
+        Some(&[0xef, 0xc6]),
+        Some(&[0xe9, 0x6e]),
+        Some(&[0xf1, 0xfe]),
+        None,
+        Some(&[0xa4, 0x7a]),
+        Some(&[0xa5, 0xad]),
+        Some(&[0xa6, 0x7e]),
+        Some(&[0xc9, 0xdb]),
+        Some(&[0xa6, 0x7d]),
+        None,
+        Some(&[0xa9, 0xaf]),
+        Some(&[0xb7, 0x46]),
+        None,
+        Some(&[0xa4, 0xdb]),
+        Some(&[0xa5, 0xae]),
+        Some(&[0xab, 0xd5]),
+        Some(&[0xb4, 0x58]),
+        None,
+        Some(&[0xc9, 0x79]),
+        None,
+        Some(&[0xc9, 0x7a]),
+        None,
+        Some(&[0xc9, 0xdc]),
+        None,
+        None,
+        Some(&[0xa7, 0xc8]),
+        Some(&[0xca, 0xd0]),
+        Some(&[0xca, 0xce]),
+        Some(&[0xa7, 0xc9]),
+        Some(&[0xca, 0xcd]),
+        Some(&[0xca, 0xcf]),
+        Some(&[0xca, 0xd1]),
+        None,
+        Some(&[0xa7, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0xb3]),
+        Some(&[0xa9, 0xb4]),
+        Some(&[0xa9, 0xb1]),
+        None,
+        None,
+        Some(&[0xa9, 0xb0]),
+        Some(&[0xce, 0xb8]),
+        Some(&[0xa9, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xd6]),
+        None,
+        Some(&[0xce, 0xb7]),
+        Some(&[0xce, 0xb9]),
+        Some(&[0xce, 0xb6]),
+        Some(&[0xce, 0xba]),
+        Some(&[0xab, 0xd7]),
+        Some(&[0xae, 0x79]),
+        Some(&[0xd1, 0x75]),
+        None,
+        Some(&[0xd1, 0x77]),
+        Some(&[0xae, 0x77]),
+        Some(&[0xd1, 0x78]),
+        Some(&[0xae, 0x78]),
+        Some(&[0xd1, 0x76]),
+        None,
+        Some(&[0xce, 0xb5]),
+        Some(&[0xd5, 0x47]),
+        Some(&[0xd5, 0x4a]),
+        Some(&[0xd5, 0x4b]),
+        Some(&[0xd5, 0x48]),
+        Some(&[0xb1, 0x67]),
+        Some(&[0xb1, 0x66]),
+        Some(&[0xb1, 0x64]),
+        Some(&[0xb1, 0x65]),
+        Some(&[0xd5, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x68]),
+        None,
+        None,
+        Some(&[0xb4, 0x5a]),
+        Some(&[0xb4, 0x5b]),
+        None,
+        Some(&[0xb4, 0x5c]),
+        Some(&[0xdd, 0x5d]),
+        Some(&[0xdd, 0x5f]),
+        Some(&[0xdd, 0x61]),
+        Some(&[0xb7, 0x48]),
+        Some(&[0xb7, 0x47]),
+        Some(&[0xb4, 0x59]),
+        Some(&[0xdd, 0x60]),
+        Some(&[0xdd, 0x5e]),
+        None,
+        Some(&[0xe1, 0xb8]),
+        None,
+        None,
+        Some(&[0xe1, 0xb6]),
+        Some(&[0xe1, 0xbc]),
+        Some(&[0xb9, 0xf8]),
+        Some(&[0xe1, 0xbd]),
+        Some(&[0xe1, 0xba]),
+        Some(&[0xb9, 0xf9]),
+        Some(&[0xe1, 0xb7]),
+        Some(&[0xe1, 0xb5]),
+        Some(&[0xe1, 0xbb]),
+        Some(&[0xbc, 0x70]),
+        Some(&[0xe5, 0x73]),
+        Some(&[0xe1, 0xb9]),
+        Some(&[0xbc, 0x72]),
+        Some(&[0xe5, 0x74]),
+        Some(&[0xbc, 0x71]),
+        Some(&[0xbc, 0x74]),
+        Some(&[0xe5, 0x75]),
+        Some(&[0xbc, 0x6f]),
+        Some(&[0xbc, 0x73]),
+        None,
+        Some(&[0xe9, 0x73]),
+        Some(&[0xe9, 0x71]),
+        Some(&[0xe9, 0x70]),
+        Some(&[0xe9, 0x72]),
+        Some(&[0xe9, 0x6f]),
+        None,
+        None,
+        Some(&[0xc3, 0x66]),
+        None,
+        Some(&[0xf4, 0x46]),
+        Some(&[0xf4, 0x47]),
+        None,
+        Some(&[0xf5, 0xcb]),
+        Some(&[0xf6, 0xdf]),
+        Some(&[0xc6, 0x55]),
+        None,
+        None,
+        Some(&[0xa9, 0xb5]),
+        Some(&[0xa7, 0xca]),
+        None,
+        None,
+        Some(&[0xab, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xa4, 0x7b]),
+        Some(&[0xa4, 0xdc]),
+        None,
+        Some(&[0xa5, 0xaf]),
+        Some(&[0xc9, 0xdd]),
+        None,
+        Some(&[0xa7, 0xcb]),
+        Some(&[0xca, 0xd2]),
+        None,
+        Some(&[0xce, 0xbb]),
+        Some(&[0xab, 0xd9]),
+        None,
+        Some(&[0xb9, 0xfa]),
+        Some(&[0xa4, 0x7c]),
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xa1]),
+        None,
+        None,
+        Some(&[0xb7, 0x49]),
+        Some(&[0xa4, 0x7d]),
+        Some(&[0xa4, 0xdd]),
+        Some(&[0xa4, 0xde]),
+        None,
+        Some(&[0xa5, 0xb1]),
+        Some(&[0xa5, 0xb0]),
+        None,
+        Some(&[0xc9, 0xde]),
+        Some(&[0xa6, 0xa2]),
+        None,
+        Some(&[0xca, 0xd3]),
+        None,
+        Some(&[0xa7, 0xcc]),
+        None,
+        None,
+        Some(&[0xcc, 0x71]),
+        Some(&[0xcc, 0x72]),
+        Some(&[0xcc, 0x73]),
+        None,
+        Some(&[0xa9, 0xb6]),
+        Some(&[0xa9, 0xb7]),
+        Some(&[0xcc, 0x70]),
+        Some(&[0xa9, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xda]),
+        Some(&[0xce, 0xbc]),
+        None,
+        Some(&[0xd1, 0x7a]),
+        Some(&[0xae, 0x7a]),
+        None,
+        Some(&[0xd1, 0x79]),
+        None,
+        Some(&[0xb1, 0x69]),
+        Some(&[0xd5, 0x4c]),
+        Some(&[0xb1, 0x6a]),
+        Some(&[0xd5, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0x62]),
+        None,
+        None,
+        Some(&[0xe1, 0xbf]),
+        Some(&[0xe1, 0xbe]),
+        None,
+        Some(&[0xb9, 0xfb]),
+        None,
+        Some(&[0xbc, 0x75]),
+        Some(&[0xe5, 0x76]),
+        Some(&[0xbe, 0xca]),
+        Some(&[0xe9, 0x74]),
+        Some(&[0xc0, 0xb1]),
+        None,
+        Some(&[0xc5, 0x73]),
+        Some(&[0xf7, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0x74]),
+        None,
+        Some(&[0xce, 0xbd]),
+        Some(&[0xb1, 0x6b]),
+        Some(&[0xd8, 0xf4]),
+        Some(&[0xb7, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xce]),
+        None,
+        Some(&[0xa7, 0xcd]),
+        Some(&[0xab, 0xdb]),
+        None,
+        Some(&[0xd1, 0x7b]),
+        None,
+        Some(&[0xb1, 0x6d]),
+        Some(&[0xb3, 0x43]),
+        Some(&[0xb1, 0x6e]),
+        Some(&[0xb1, 0x6c]),
+        Some(&[0xb4, 0x5e]),
+        None,
+        Some(&[0xe1, 0xc0]),
+        Some(&[0xb9, 0xfc]),
+        Some(&[0xbc, 0x76]),
+        None,
+        Some(&[0xc9, 0x4c]),
+        Some(&[0xc9, 0xdf]),
+        None,
+        Some(&[0xca, 0xd5]),
+        Some(&[0xa7, 0xcf]),
+        Some(&[0xca, 0xd4]),
+        Some(&[0xa7, 0xd0]),
+        None,
+        None,
+        Some(&[0xa9, 0xbc]),
+        Some(&[0xcc, 0x77]),
+        Some(&[0xcc, 0x76]),
+        Some(&[0xa9, 0xbb]),
+        Some(&[0xa9, 0xb9]),
+        Some(&[0xa9, 0xba]),
+        Some(&[0xcc, 0x75]),
+        None,
+        None,
+        Some(&[0xab, 0xdd]),
+        Some(&[0xce, 0xbe]),
+        Some(&[0xab, 0xe0]),
+        Some(&[0xab, 0xdc]),
+        Some(&[0xab, 0xe2]),
+        Some(&[0xab, 0xde]),
+        Some(&[0xab, 0xdf]),
+        Some(&[0xab, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xae, 0x7d]),
+        Some(&[0xae, 0x7c]),
+        Some(&[0xae, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0x4f]),
+        Some(&[0xb1, 0x6f]),
+        Some(&[0xb1, 0x72]),
+        Some(&[0xb1, 0x70]),
+        None,
+        Some(&[0xd5, 0x4e]),
+        Some(&[0xb1, 0x75]),
+        None,
+        Some(&[0xb1, 0x71]),
+        Some(&[0xd5, 0x50]),
+        Some(&[0xb1, 0x74]),
+        Some(&[0xb1, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0xf6]),
+        Some(&[0xd8, 0xf5]),
+        None,
+        Some(&[0xb4, 0x61]),
+        Some(&[0xb4, 0x5f]),
+        Some(&[0xb4, 0x60]),
+        Some(&[0xd8, 0xf7]),
+        Some(&[0xb7, 0x4b]),
+        Some(&[0xdd, 0x64]),
+        Some(&[0xb7, 0x4c]),
+        Some(&[0xdd, 0x63]),
+        None,
+        None,
+        Some(&[0xe5, 0x77]),
+        None,
+        None,
+        Some(&[0xbc, 0x78]),
+        Some(&[0xe1, 0xc1]),
+        Some(&[0xbc, 0x77]),
+        None,
+        Some(&[0xb9, 0xfd]),
+        None,
+        Some(&[0xec, 0xde]),
+        Some(&[0xe9, 0x75]),
+        Some(&[0xc0, 0xb2]),
+        Some(&[0xec, 0xdd]),
+        Some(&[0xf2, 0x40]),
+        Some(&[0xf4, 0x48]),
+        Some(&[0xf4, 0x49]),
+        None,
+        Some(&[0xa4, 0xdf]),
+        None,
+        Some(&[0xa5, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x7b]),
+        None,
+        None,
+        Some(&[0xa7, 0xd2]),
+        Some(&[0xa7, 0xd4]),
+        None,
+        Some(&[0xc9, 0xe2]),
+        Some(&[0xca, 0xd8]),
+        Some(&[0xca, 0xd7]),
+        Some(&[0xca, 0xd6]),
+        None,
+        Some(&[0xc9, 0xe1]),
+        Some(&[0xc9, 0xe0]),
+        Some(&[0xa6, 0xa4]),
+        Some(&[0xa7, 0xd3]),
+        Some(&[0xa7, 0xd1]),
+        Some(&[0xa6, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0xbd]),
+        Some(&[0xcc, 0x78]),
+        None,
+        Some(&[0xa9, 0xbe]),
+        Some(&[0xca, 0xdd]),
+        None,
+        Some(&[0xca, 0xdf]),
+        Some(&[0xca, 0xde]),
+        Some(&[0xcc, 0x79]),
+        None,
+        None,
+        Some(&[0xca, 0xda]),
+        None,
+        Some(&[0xa7, 0xd8]),
+        Some(&[0xa7, 0xd6]),
+        None,
+        Some(&[0xca, 0xd9]),
+        Some(&[0xca, 0xdb]),
+        Some(&[0xca, 0xe1]),
+        None,
+        Some(&[0xa7, 0xd5]),
+        None,
+        Some(&[0xca, 0xdc]),
+        Some(&[0xca, 0xe5]),
+        Some(&[0xa9, 0xc0]),
+        None,
+        Some(&[0xca, 0xe2]),
+        Some(&[0xa7, 0xd7]),
+        None,
+        Some(&[0xca, 0xe0]),
+        Some(&[0xca, 0xe3]),
+        None,
+        Some(&[0xa9, 0xbf]),
+        None,
+        Some(&[0xa9, 0xc1]),
+        Some(&[0xca, 0xe4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcc, 0xaf]),
+        Some(&[0xcc, 0xa2]),
+        Some(&[0xcc, 0x7e]),
+        Some(&[0xcc, 0xae]),
+        Some(&[0xcc, 0xa9]),
+        Some(&[0xab, 0xe7]),
+        Some(&[0xa9, 0xc2]),
+        Some(&[0xcc, 0xaa]),
+        Some(&[0xcc, 0xad]),
+        Some(&[0xab, 0xe3]),
+        Some(&[0xcc, 0xac]),
+        Some(&[0xa9, 0xc3]),
+        Some(&[0xa9, 0xc8]),
+        Some(&[0xa9, 0xc6]),
+        Some(&[0xcc, 0xa3]),
+        None,
+        Some(&[0xcc, 0x7c]),
+        Some(&[0xcc, 0xa5]),
+        Some(&[0xa9, 0xcd]),
+        Some(&[0xcc, 0xb0]),
+        Some(&[0xab, 0xe4]),
+        Some(&[0xcc, 0xa6]),
+        None,
+        Some(&[0xab, 0xe5]),
+        Some(&[0xa9, 0xc9]),
+        Some(&[0xcc, 0xa8]),
+        None,
+        Some(&[0xce, 0xcd]),
+        Some(&[0xab, 0xe6]),
+        Some(&[0xcc, 0x7b]),
+        Some(&[0xa9, 0xca]),
+        Some(&[0xab, 0xe8]),
+        Some(&[0xa9, 0xcb]),
+        Some(&[0xa9, 0xc7]),
+        Some(&[0xa9, 0xcc]),
+        Some(&[0xcc, 0xa7]),
+        Some(&[0xcc, 0x7a]),
+        Some(&[0xcc, 0xab]),
+        Some(&[0xa9, 0xc4]),
+        None,
+        None,
+        Some(&[0xcc, 0x7d]),
+        Some(&[0xcc, 0xa4]),
+        Some(&[0xcc, 0xa1]),
+        Some(&[0xa9, 0xc5]),
+        None,
+        Some(&[0xce, 0xbf]),
+        None,
+        Some(&[0xce, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xca]),
+        Some(&[0xd1, 0xa1]),
+        Some(&[0xce, 0xcb]),
+        Some(&[0xab, 0xee]),
+        Some(&[0xce, 0xce]),
+        Some(&[0xce, 0xc4]),
+        Some(&[0xab, 0xed]),
+        Some(&[0xce, 0xc6]),
+        None,
+        Some(&[0xce, 0xc7]),
+        None,
+        None,
+        Some(&[0xce, 0xc9]),
+        Some(&[0xab, 0xe9]),
+        None,
+        None,
+        Some(&[0xae, 0xa3]),
+        None,
+        Some(&[0xf9, 0xda]),
+        Some(&[0xce, 0xc5]),
+        Some(&[0xce, 0xc1]),
+        Some(&[0xae, 0xa4]),
+        None,
+        None,
+        Some(&[0xce, 0xcf]),
+        Some(&[0xae, 0x7e]),
+        Some(&[0xd1, 0x7d]),
+        Some(&[0xce, 0xc8]),
+        None,
+        Some(&[0xd1, 0x7c]),
+        Some(&[0xce, 0xc3]),
+        Some(&[0xce, 0xcc]),
+        None,
+        None,
+        Some(&[0xab, 0xec]),
+        Some(&[0xae, 0xa1]),
+        Some(&[0xab, 0xf2]),
+        Some(&[0xae, 0xa2]),
+        Some(&[0xce, 0xd0]),
+        Some(&[0xd1, 0x7e]),
+        Some(&[0xab, 0xeb]),
+        Some(&[0xae, 0xa6]),
+        Some(&[0xab, 0xf1]),
+        Some(&[0xab, 0xf0]),
+        Some(&[0xab, 0xef]),
+        Some(&[0xae, 0xa5]),
+        Some(&[0xce, 0xd1]),
+        Some(&[0xae, 0xa7]),
+        Some(&[0xab, 0xea]),
+        None,
+        Some(&[0xce, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x76]),
+        Some(&[0xd1, 0xa4]),
+        Some(&[0xd1, 0xa6]),
+        None,
+        Some(&[0xd1, 0xa8]),
+        Some(&[0xae, 0xa8]),
+        Some(&[0xae, 0xae]),
+        Some(&[0xd5, 0x53]),
+        Some(&[0xd1, 0xac]),
+        Some(&[0xd1, 0xa3]),
+        Some(&[0xb1, 0x78]),
+        Some(&[0xd5, 0x51]),
+        None,
+        Some(&[0xae, 0xad]),
+        Some(&[0xae, 0xab]),
+        Some(&[0xd1, 0xae]),
+        None,
+        Some(&[0xd5, 0x52]),
+        None,
+        Some(&[0xd1, 0xa5]),
+        None,
+        Some(&[0xae, 0xac]),
+        Some(&[0xd1, 0xa9]),
+        Some(&[0xae, 0xaf]),
+        Some(&[0xd1, 0xab]),
+        None,
+        None,
+        Some(&[0xae, 0xaa]),
+        Some(&[0xd1, 0xaa]),
+        Some(&[0xd1, 0xad]),
+        Some(&[0xd1, 0xa7]),
+        None,
+        Some(&[0xae, 0xa9]),
+        Some(&[0xb1, 0x79]),
+        None,
+        Some(&[0xd1, 0xa2]),
+        Some(&[0xb1, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0x55]),
+        Some(&[0xd5, 0x5e]),
+        Some(&[0xb4, 0x64]),
+        None,
+        Some(&[0xb1, 0x7c]),
+        Some(&[0xb1, 0xa3]),
+        Some(&[0xb4, 0x65]),
+        Some(&[0xd5, 0x60]),
+        Some(&[0xb1, 0xaa]),
+        Some(&[0xd8, 0xf9]),
+        Some(&[0xd5, 0x56]),
+        Some(&[0xb1, 0xa2]),
+        Some(&[0xb1, 0xa5]),
+        Some(&[0xb1, 0x7e]),
+        Some(&[0xd5, 0x54]),
+        Some(&[0xd5, 0x62]),
+        Some(&[0xd5, 0x65]),
+        Some(&[0xd9, 0x49]),
+        None,
+        Some(&[0xd5, 0x63]),
+        Some(&[0xd8, 0xfd]),
+        Some(&[0xb1, 0xa1]),
+        Some(&[0xb1, 0xa8]),
+        Some(&[0xb1, 0xac]),
+        Some(&[0xd5, 0x5d]),
+        Some(&[0xd8, 0xf8]),
+        Some(&[0xd5, 0x61]),
+        Some(&[0xb1, 0x7b]),
+        Some(&[0xd8, 0xfa]),
+        Some(&[0xd5, 0x64]),
+        Some(&[0xd8, 0xfc]),
+        Some(&[0xd5, 0x59]),
+        None,
+        Some(&[0xb4, 0x62]),
+        None,
+        Some(&[0xd5, 0x57]),
+        Some(&[0xd5, 0x58]),
+        Some(&[0xb1, 0xa7]),
+        None,
+        None,
+        Some(&[0xb1, 0xa6]),
+        Some(&[0xd5, 0x5b]),
+        Some(&[0xb1, 0xab]),
+        Some(&[0xd5, 0x5f]),
+        Some(&[0xb1, 0xa4]),
+        Some(&[0xd5, 0x5c]),
+        None,
+        Some(&[0xb1, 0xa9]),
+        Some(&[0xb4, 0x66]),
+        Some(&[0xb4, 0x63]),
+        Some(&[0xd8, 0xfb]),
+        None,
+        Some(&[0xd5, 0x5a]),
+        None,
+        Some(&[0xb1, 0x7d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x6b]),
+        Some(&[0xb4, 0x6f]),
+        Some(&[0xd9, 0x40]),
+        Some(&[0xb7, 0x51]),
+        Some(&[0xb4, 0x6d]),
+        Some(&[0xd9, 0x44]),
+        Some(&[0xb4, 0x71]),
+        Some(&[0xdd, 0x65]),
+        Some(&[0xd9, 0x46]),
+        Some(&[0xb7, 0x53]),
+        Some(&[0xb4, 0x69]),
+        Some(&[0xb4, 0x6c]),
+        Some(&[0xd9, 0x47]),
+        None,
+        Some(&[0xd9, 0x48]),
+        Some(&[0xd9, 0x4e]),
+        Some(&[0xb4, 0x73]),
+        Some(&[0xb7, 0x54]),
+        None,
+        Some(&[0xd9, 0x4a]),
+        Some(&[0xd9, 0x4f]),
+        Some(&[0xd9, 0x43]),
+        Some(&[0xb7, 0x5e]),
+        None,
+        Some(&[0xb7, 0x55]),
+        Some(&[0xb4, 0x72]),
+        Some(&[0xd9, 0x41]),
+        Some(&[0xd9, 0x50]),
+        None,
+        Some(&[0xb7, 0x5d]),
+        Some(&[0xb4, 0x70]),
+        Some(&[0xb7, 0x4e]),
+        Some(&[0xd9, 0x4d]),
+        None,
+        Some(&[0xb4, 0x74]),
+        Some(&[0xd9, 0x45]),
+        Some(&[0xd8, 0xfe]),
+        Some(&[0xb4, 0x6a]),
+        Some(&[0xd9, 0x42]),
+        None,
+        Some(&[0xd9, 0x4b]),
+        None,
+        Some(&[0xb7, 0x4d]),
+        Some(&[0xb7, 0x52]),
+        Some(&[0xb4, 0x67]),
+        Some(&[0xd9, 0x4c]),
+        None,
+        Some(&[0xb7, 0x50]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0x5c]),
+        Some(&[0xe1, 0xc3]),
+        Some(&[0xdd, 0x70]),
+        None,
+        Some(&[0xdd, 0x68]),
+        Some(&[0xe1, 0xc2]),
+        None,
+        Some(&[0xdd, 0x6c]),
+        Some(&[0xdd, 0x6e]),
+        None,
+        None,
+        Some(&[0xdd, 0x6b]),
+        None,
+        Some(&[0xb7, 0x5b]),
+        None,
+        Some(&[0xdd, 0x6a]),
+        Some(&[0xb7, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xd2]),
+        None,
+        None,
+        Some(&[0xb7, 0x5a]),
+        Some(&[0xba, 0x40]),
+        Some(&[0xdd, 0x71]),
+        Some(&[0xe1, 0xc4]),
+        None,
+        None,
+        Some(&[0xb7, 0x58]),
+        Some(&[0xdd, 0x69]),
+        Some(&[0xdd, 0x6d]),
+        Some(&[0xb9, 0xfe]),
+        Some(&[0xb7, 0x4f]),
+        Some(&[0xdd, 0x66]),
+        Some(&[0xdd, 0x67]),
+        Some(&[0xba, 0x41]),
+        Some(&[0xb7, 0x57]),
+        Some(&[0xb7, 0x59]),
+        Some(&[0xb7, 0x56]),
+        Some(&[0xdd, 0x6f]),
+        None,
+        None,
+        Some(&[0xe1, 0xc8]),
+        Some(&[0xe1, 0xc9]),
+        Some(&[0xe1, 0xce]),
+        Some(&[0xbc, 0x7d]),
+        Some(&[0xe1, 0xd5]),
+        None,
+        Some(&[0xba, 0x47]),
+        None,
+        Some(&[0xba, 0x46]),
+        Some(&[0xe1, 0xd0]),
+        None,
+        Some(&[0xbc, 0x7c]),
+        Some(&[0xe1, 0xc5]),
+        Some(&[0xba, 0x45]),
+        None,
+        Some(&[0xe1, 0xd4]),
+        Some(&[0xba, 0x43]),
+        Some(&[0xba, 0x44]),
+        None,
+        Some(&[0xe1, 0xd1]),
+        Some(&[0xe5, 0xaa]),
+        Some(&[0xbc, 0x7a]),
+        Some(&[0xb4, 0x6e]),
+        None,
+        Some(&[0xe1, 0xd3]),
+        Some(&[0xbc, 0xa3]),
+        Some(&[0xe1, 0xcb]),
+        None,
+        Some(&[0xbc, 0x7b]),
+        None,
+        Some(&[0xbc, 0xa2]),
+        Some(&[0xe1, 0xc6]),
+        Some(&[0xe1, 0xca]),
+        Some(&[0xe1, 0xc7]),
+        Some(&[0xe1, 0xcd]),
+        Some(&[0xba, 0x48]),
+        Some(&[0xbc, 0x79]),
+        Some(&[0xba, 0x42]),
+        None,
+        Some(&[0xe5, 0x7a]),
+        Some(&[0xe1, 0xcf]),
+        None,
+        Some(&[0xbc, 0xa1]),
+        None,
+        Some(&[0xbc, 0xa4]),
+        None,
+        Some(&[0xe1, 0xcc]),
+        None,
+        Some(&[0xbc, 0x7e]),
+        Some(&[0xe5, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0x7e]),
+        Some(&[0xbe, 0xce]),
+        Some(&[0xe5, 0x78]),
+        Some(&[0xe9, 0xa3]),
+        Some(&[0xe5, 0xa9]),
+        Some(&[0xbc, 0xa8]),
+        None,
+        Some(&[0xbc, 0xa6]),
+        Some(&[0xbe, 0xcc]),
+        Some(&[0xe5, 0xa6]),
+        Some(&[0xe5, 0xa2]),
+        Some(&[0xbc, 0xac]),
+        None,
+        Some(&[0xe9, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xaa]),
+        Some(&[0xe5, 0xa1]),
+        None,
+        Some(&[0xe9, 0x76]),
+        None,
+        Some(&[0xe5, 0xa5]),
+        None,
+        Some(&[0xe5, 0xa8]),
+        Some(&[0xe5, 0x7d]),
+        None,
+        Some(&[0xbc, 0xab]),
+        None,
+        None,
+        Some(&[0xbc, 0xa5]),
+        Some(&[0xe9, 0x77]),
+        Some(&[0xbe, 0xcd]),
+        Some(&[0xe5, 0xa7]),
+        Some(&[0xbc, 0xa7]),
+        Some(&[0xbc, 0xa9]),
+        Some(&[0xe5, 0xa4]),
+        Some(&[0xbc, 0xad]),
+        Some(&[0xe5, 0xa3]),
+        Some(&[0xe5, 0x7c]),
+        Some(&[0xe5, 0x7b]),
+        Some(&[0xbe, 0xcb]),
+        Some(&[0xe5, 0xab]),
+        Some(&[0xe9, 0x7a]),
+        Some(&[0xec, 0xe0]),
+        Some(&[0xbe, 0xd0]),
+        None,
+        Some(&[0xe9, 0xa2]),
+        None,
+        Some(&[0xe9, 0x7e]),
+        None,
+        Some(&[0xec, 0xe1]),
+        None,
+        Some(&[0xbe, 0xd1]),
+        Some(&[0xe9, 0xa1]),
+        None,
+        Some(&[0xe9, 0x7c]),
+        Some(&[0xc0, 0xb4]),
+        Some(&[0xec, 0xdf]),
+        None,
+        Some(&[0xe9, 0x79]),
+        Some(&[0xe9, 0x7b]),
+        Some(&[0xc0, 0xb5]),
+        Some(&[0xbe, 0xd3]),
+        Some(&[0xc0, 0xb3]),
+        Some(&[0xbe, 0xd2]),
+        Some(&[0xc0, 0xb7]),
+        Some(&[0xe9, 0x7d]),
+        Some(&[0xbe, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xcf]),
+        None,
+        Some(&[0xef, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xe7]),
+        Some(&[0xef, 0xc8]),
+        Some(&[0xec, 0xe3]),
+        None,
+        None,
+        Some(&[0xc2, 0x56]),
+        Some(&[0xec, 0xe5]),
+        Some(&[0xec, 0xe4]),
+        Some(&[0xc0, 0xb6]),
+        Some(&[0xec, 0xe2]),
+        Some(&[0xec, 0xe6]),
+        Some(&[0xef, 0xd0]),
+        Some(&[0xef, 0xcc]),
+        Some(&[0xef, 0xce]),
+        None,
+        Some(&[0xef, 0xc9]),
+        Some(&[0xef, 0xca]),
+        None,
+        Some(&[0xef, 0xcd]),
+        Some(&[0xef, 0xcb]),
+        Some(&[0xc3, 0x67]),
+        None,
+        None,
+        Some(&[0xc3, 0x6a]),
+        Some(&[0xc3, 0x69]),
+        Some(&[0xc3, 0x68]),
+        Some(&[0xc4, 0x61]),
+        Some(&[0xf4, 0x4a]),
+        Some(&[0xc4, 0x62]),
+        Some(&[0xf2, 0x41]),
+        Some(&[0xc4, 0xdf]),
+        Some(&[0xf5, 0xcc]),
+        Some(&[0xc4, 0xe0]),
+        Some(&[0xc5, 0x74]),
+        Some(&[0xc5, 0xca]),
+        Some(&[0xf7, 0xd9]),
+        None,
+        Some(&[0xf7, 0xda]),
+        Some(&[0xf7, 0xdb]),
+        None,
+        None,
+        Some(&[0xf9, 0xba]),
+        Some(&[0xa4, 0xe0]),
+        Some(&[0xc9, 0x7c]),
+        Some(&[0xa5, 0xb3]),
+        None,
+        Some(&[0xa6, 0xa6]),
+        Some(&[0xa6, 0xa7]),
+        Some(&[0xa6, 0xa5]),
+        None,
+        Some(&[0xa6, 0xa8]),
+        Some(&[0xa7, 0xda]),
+        Some(&[0xa7, 0xd9]),
+        None,
+        Some(&[0xcc, 0xb1]),
+        Some(&[0xa9, 0xcf]),
+        Some(&[0xa9, 0xce]),
+        None,
+        None,
+        Some(&[0xd1, 0xaf]),
+        Some(&[0xb1, 0xad]),
+        Some(&[0xb1, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0x75]),
+        Some(&[0xdd, 0x72]),
+        Some(&[0xb7, 0x60]),
+        Some(&[0xb7, 0x61]),
+        Some(&[0xdd, 0x74]),
+        Some(&[0xdd, 0x76]),
+        Some(&[0xdd, 0x75]),
+        None,
+        Some(&[0xe1, 0xd7]),
+        None,
+        Some(&[0xe1, 0xd6]),
+        Some(&[0xba, 0x49]),
+        Some(&[0xe1, 0xd8]),
+        None,
+        Some(&[0xe5, 0xac]),
+        Some(&[0xbc, 0xae]),
+        None,
+        Some(&[0xbe, 0xd4]),
+        None,
+        Some(&[0xc0, 0xb8]),
+        Some(&[0xc2, 0x57]),
+        Some(&[0xc0, 0xb9]),
+        None,
+        Some(&[0xa4, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xe6]),
+        None,
+        None,
+        Some(&[0xcc, 0xb2]),
+        Some(&[0xa9, 0xd1]),
+        Some(&[0xa9, 0xd0]),
+        Some(&[0xa9, 0xd2]),
+        Some(&[0xab, 0xf3]),
+        Some(&[0xce, 0xd2]),
+        Some(&[0xce, 0xd3]),
+        None,
+        None,
+        Some(&[0xd1, 0xb0]),
+        Some(&[0xae, 0xb0]),
+        Some(&[0xb1, 0xaf]),
+        Some(&[0xb4, 0x76]),
+        Some(&[0xd9, 0x51]),
+        Some(&[0xa4, 0xe2]),
+        None,
+        Some(&[0xa4, 0x7e]),
+        Some(&[0xa4, 0xe3]),
+        None,
+        Some(&[0xc9, 0x7d]),
+        Some(&[0xa5, 0xb7]),
+        Some(&[0xa5, 0xb6]),
+        Some(&[0xa5, 0xb4]),
+        Some(&[0xa5, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xab]),
+        Some(&[0xc9, 0xe9]),
+        Some(&[0xc9, 0xeb]),
+        Some(&[0xa6, 0xaa]),
+        Some(&[0xc9, 0xe3]),
+        None,
+        Some(&[0xc9, 0xe4]),
+        None,
+        Some(&[0xc9, 0xea]),
+        Some(&[0xc9, 0xe6]),
+        Some(&[0xc9, 0xe8]),
+        Some(&[0xa6, 0xa9]),
+        Some(&[0xc9, 0xe5]),
+        Some(&[0xc9, 0xec]),
+        Some(&[0xc9, 0xe7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa7, 0xe1]),
+        Some(&[0xa7, 0xea]),
+        Some(&[0xa7, 0xe8]),
+        Some(&[0xca, 0xf0]),
+        Some(&[0xca, 0xed]),
+        Some(&[0xca, 0xf5]),
+        Some(&[0xa7, 0xe6]),
+        Some(&[0xca, 0xf6]),
+        None,
+        Some(&[0xa7, 0xdf]),
+        Some(&[0xca, 0xf3]),
+        None,
+        Some(&[0xa7, 0xe5]),
+        Some(&[0xca, 0xef]),
+        Some(&[0xca, 0xee]),
+        Some(&[0xa7, 0xe3]),
+        Some(&[0xca, 0xf4]),
+        Some(&[0xa7, 0xe4]),
+        Some(&[0xa9, 0xd3]),
+        Some(&[0xa7, 0xde]),
+        Some(&[0xca, 0xf1]),
+        None,
+        Some(&[0xca, 0xe7]),
+        Some(&[0xa7, 0xdb]),
+        None,
+        Some(&[0xa7, 0xee]),
+        Some(&[0xca, 0xec]),
+        Some(&[0xca, 0xf2]),
+        Some(&[0xa7, 0xe0]),
+        Some(&[0xa7, 0xe2]),
+        None,
+        Some(&[0xca, 0xe8]),
+        None,
+        Some(&[0xca, 0xe9]),
+        Some(&[0xca, 0xea]),
+        None,
+        Some(&[0xa7, 0xed]),
+        Some(&[0xa7, 0xe7]),
+        Some(&[0xa7, 0xec]),
+        Some(&[0xca, 0xeb]),
+        Some(&[0xa7, 0xeb]),
+        Some(&[0xa7, 0xdd]),
+        Some(&[0xa7, 0xdc]),
+        Some(&[0xa7, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0xe1]),
+        Some(&[0xcc, 0xbe]),
+        Some(&[0xcc, 0xb7]),
+        Some(&[0xa9, 0xdc]),
+        Some(&[0xa9, 0xef]),
+        Some(&[0xcc, 0xb3]),
+        Some(&[0xcc, 0xba]),
+        Some(&[0xcc, 0xbc]),
+        Some(&[0xcc, 0xbf]),
+        Some(&[0xa9, 0xea]),
+        None,
+        Some(&[0xcc, 0xbb]),
+        Some(&[0xcc, 0xb4]),
+        Some(&[0xa9, 0xe8]),
+        Some(&[0xcc, 0xb8]),
+        None,
+        Some(&[0xcc, 0xc0]),
+        Some(&[0xa9, 0xd9]),
+        None,
+        Some(&[0xcc, 0xbd]),
+        Some(&[0xa9, 0xe3]),
+        Some(&[0xa9, 0xe2]),
+        Some(&[0xcc, 0xb6]),
+        Some(&[0xa9, 0xd7]),
+        None,
+        None,
+        Some(&[0xa9, 0xd8]),
+        None,
+        Some(&[0xa9, 0xd6]),
+        None,
+        Some(&[0xa9, 0xee]),
+        Some(&[0xa9, 0xe6]),
+        Some(&[0xa9, 0xe0]),
+        Some(&[0xa9, 0xd4]),
+        Some(&[0xcc, 0xb9]),
+        Some(&[0xa9, 0xdf]),
+        Some(&[0xa9, 0xd5]),
+        Some(&[0xa9, 0xe7]),
+        Some(&[0xa9, 0xf0]),
+        Some(&[0xce, 0xd4]),
+        Some(&[0xa9, 0xe4]),
+        Some(&[0xcc, 0xb5]),
+        Some(&[0xa9, 0xda]),
+        Some(&[0xa9, 0xdd]),
+        Some(&[0xa9, 0xde]),
+        None,
+        Some(&[0xa9, 0xec]),
+        Some(&[0xa9, 0xed]),
+        Some(&[0xa9, 0xeb]),
+        Some(&[0xa9, 0xe5]),
+        Some(&[0xa9, 0xe9]),
+        Some(&[0xa9, 0xdb]),
+        Some(&[0xab, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xda]),
+        Some(&[0xac, 0x41]),
+        Some(&[0xab, 0xf8]),
+        Some(&[0xab, 0xfa]),
+        Some(&[0xac, 0x40]),
+        Some(&[0xce, 0xe6]),
+        Some(&[0xab, 0xfd]),
+        Some(&[0xd1, 0xb1]),
+        Some(&[0xae, 0xb1]),
+        Some(&[0xac, 0x43]),
+        Some(&[0xce, 0xd7]),
+        Some(&[0xce, 0xdf]),
+        Some(&[0xab, 0xfe]),
+        Some(&[0xce, 0xde]),
+        Some(&[0xce, 0xdb]),
+        Some(&[0xce, 0xe3]),
+        Some(&[0xce, 0xe5]),
+        Some(&[0xab, 0xf7]),
+        Some(&[0xab, 0xfb]),
+        Some(&[0xac, 0x42]),
+        Some(&[0xae, 0xb3]),
+        Some(&[0xce, 0xe0]),
+        Some(&[0xab, 0xf9]),
+        Some(&[0xac, 0x45]),
+        Some(&[0xce, 0xd9]),
+        None,
+        None,
+        None,
+        Some(&[0xab, 0xfc]),
+        Some(&[0xae, 0xb2]),
+        Some(&[0xab, 0xf6]),
+        None,
+        Some(&[0xce, 0xd6]),
+        Some(&[0xce, 0xdd]),
+        Some(&[0xce, 0xd5]),
+        Some(&[0xce, 0xd8]),
+        Some(&[0xce, 0xdc]),
+        Some(&[0xd1, 0xb2]),
+        Some(&[0xac, 0x44]),
+        None,
+        Some(&[0xce, 0xe1]),
+        Some(&[0xce, 0xe2]),
+        Some(&[0xce, 0xe4]),
+        Some(&[0xab, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xae, 0xc1]),
+        Some(&[0xd1, 0xbe]),
+        Some(&[0xae, 0xbf]),
+        Some(&[0xae, 0xc0]),
+        Some(&[0xd1, 0xb4]),
+        Some(&[0xd1, 0xc4]),
+        None,
+        Some(&[0xae, 0xb6]),
+        None,
+        None,
+        Some(&[0xd5, 0x66]),
+        Some(&[0xd1, 0xc6]),
+        Some(&[0xd1, 0xc0]),
+        None,
+        Some(&[0xd1, 0xb7]),
+        None,
+        Some(&[0xd1, 0xc9]),
+        Some(&[0xd1, 0xba]),
+        Some(&[0xae, 0xbc]),
+        Some(&[0xd5, 0x7d]),
+        Some(&[0xd1, 0xbd]),
+        Some(&[0xae, 0xbe]),
+        Some(&[0xae, 0xb5]),
+        None,
+        Some(&[0xd1, 0xcb]),
+        Some(&[0xd1, 0xbf]),
+        Some(&[0xae, 0xb8]),
+        Some(&[0xd1, 0xb8]),
+        Some(&[0xd1, 0xb5]),
+        Some(&[0xd1, 0xb6]),
+        Some(&[0xae, 0xb9]),
+        Some(&[0xd1, 0xc5]),
+        Some(&[0xd1, 0xcc]),
+        Some(&[0xae, 0xbb]),
+        Some(&[0xd1, 0xbc]),
+        Some(&[0xd1, 0xbb]),
+        Some(&[0xae, 0xc3]),
+        Some(&[0xae, 0xc2]),
+        Some(&[0xae, 0xb4]),
+        Some(&[0xae, 0xba]),
+        Some(&[0xae, 0xbd]),
+        Some(&[0xd1, 0xc8]),
+        None,
+        None,
+        Some(&[0xd1, 0xc2]),
+        Some(&[0xae, 0xb7]),
+        Some(&[0xd1, 0xb3]),
+        Some(&[0xd1, 0xca]),
+        Some(&[0xd1, 0xc1]),
+        Some(&[0xd1, 0xc3]),
+        Some(&[0xd1, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0x67]),
+        None,
+        Some(&[0xb1, 0xb7]),
+        Some(&[0xb1, 0xcb]),
+        Some(&[0xb1, 0xca]),
+        None,
+        Some(&[0xb1, 0xbf]),
+        None,
+        Some(&[0xd5, 0x79]),
+        Some(&[0xd5, 0x75]),
+        Some(&[0xd5, 0x72]),
+        Some(&[0xd5, 0xa6]),
+        Some(&[0xb1, 0xba]),
+        Some(&[0xb1, 0xb2]),
+        None,
+        None,
+        Some(&[0xd5, 0x77]),
+        Some(&[0xb4, 0xa8]),
+        Some(&[0xb1, 0xb6]),
+        Some(&[0xd5, 0xa1]),
+        None,
+        Some(&[0xb1, 0xcc]),
+        Some(&[0xb1, 0xc9]),
+        Some(&[0xd5, 0x7b]),
+        Some(&[0xd5, 0x6a]),
+        None,
+        None,
+        Some(&[0xb1, 0xc8]),
+        Some(&[0xd5, 0xa3]),
+        Some(&[0xd5, 0x69]),
+        Some(&[0xb1, 0xbd]),
+        Some(&[0xb1, 0xc1]),
+        Some(&[0xd5, 0xa2]),
+        None,
+        Some(&[0xd5, 0x73]),
+        Some(&[0xb1, 0xc2]),
+        Some(&[0xb1, 0xbc]),
+        Some(&[0xd5, 0x68]),
+        None,
+        Some(&[0xb4, 0x78]),
+        Some(&[0xd5, 0xa5]),
+        Some(&[0xd5, 0x71]),
+        Some(&[0xb1, 0xc7]),
+        Some(&[0xd5, 0x74]),
+        Some(&[0xd5, 0xa4]),
+        Some(&[0xb1, 0xc6]),
+        None,
+        Some(&[0xd9, 0x52]),
+        None,
+        Some(&[0xb1, 0xb3]),
+        Some(&[0xd5, 0x6f]),
+        Some(&[0xb1, 0xb8]),
+        Some(&[0xb1, 0xc3]),
+        None,
+        Some(&[0xb1, 0xbe]),
+        Some(&[0xd5, 0x78]),
+        Some(&[0xd5, 0x6e]),
+        Some(&[0xd5, 0x6c]),
+        Some(&[0xd5, 0x7e]),
+        Some(&[0xb1, 0xb0]),
+        Some(&[0xb1, 0xc4]),
+        Some(&[0xb1, 0xb4]),
+        Some(&[0xb4, 0x77]),
+        Some(&[0xd5, 0x7c]),
+        Some(&[0xb1, 0xb5]),
+        None,
+        Some(&[0xb1, 0xb1]),
+        Some(&[0xb1, 0xc0]),
+        Some(&[0xb1, 0xbb]),
+        Some(&[0xb1, 0xb9]),
+        Some(&[0xd5, 0x70]),
+        Some(&[0xb1, 0xc5]),
+        Some(&[0xd5, 0x6d]),
+        Some(&[0xd5, 0x7a]),
+        Some(&[0xd5, 0x76]),
+        Some(&[0xd9, 0x54]),
+        Some(&[0xd9, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0x6b]),
+        Some(&[0xd9, 0x64]),
+        None,
+        Some(&[0xb4, 0x7a]),
+        None,
+        Some(&[0xd9, 0x6a]),
+        Some(&[0xd9, 0x59]),
+        Some(&[0xd9, 0x67]),
+        Some(&[0xdd, 0x77]),
+        Some(&[0xb4, 0x7d]),
+        Some(&[0xd9, 0x6b]),
+        Some(&[0xd9, 0x6e]),
+        Some(&[0xb4, 0x7c]),
+        Some(&[0xd9, 0x5c]),
+        Some(&[0xd9, 0x6d]),
+        Some(&[0xd9, 0x6c]),
+        Some(&[0xb4, 0x7e]),
+        Some(&[0xd9, 0x55]),
+        Some(&[0xb4, 0x79]),
+        Some(&[0xb4, 0xa3]),
+        None,
+        Some(&[0xb4, 0xa1]),
+        Some(&[0xd9, 0x69]),
+        None,
+        Some(&[0xd9, 0x5f]),
+        Some(&[0xb4, 0xa5]),
+        Some(&[0xd9, 0x70]),
+        Some(&[0xd9, 0x68]),
+        Some(&[0xd9, 0x71]),
+        Some(&[0xb4, 0xad]),
+        Some(&[0xb4, 0xab]),
+        Some(&[0xd9, 0x66]),
+        Some(&[0xd9, 0x65]),
+        None,
+        Some(&[0xd9, 0x63]),
+        Some(&[0xd9, 0x5d]),
+        Some(&[0xb4, 0xa4]),
+        None,
+        Some(&[0xb4, 0xa2]),
+        Some(&[0xd1, 0xb9]),
+        Some(&[0xd9, 0x56]),
+        None,
+        Some(&[0xdd, 0xb7]),
+        Some(&[0xd9, 0x57]),
+        Some(&[0xb4, 0x7b]),
+        Some(&[0xb4, 0xaa]),
+        Some(&[0xdd, 0x79]),
+        None,
+        Some(&[0xb4, 0xa6]),
+        Some(&[0xb4, 0xa7]),
+        Some(&[0xd9, 0x58]),
+        Some(&[0xd9, 0x6f]),
+        Some(&[0xdd, 0x78]),
+        Some(&[0xd9, 0x60]),
+        Some(&[0xd9, 0x5b]),
+        Some(&[0xb4, 0xa9]),
+        Some(&[0xd9, 0x61]),
+        Some(&[0xd9, 0x5e]),
+        None,
+        None,
+        Some(&[0xb4, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0x70]),
+        None,
+        None,
+        Some(&[0xdd, 0x7c]),
+        Some(&[0xdd, 0xb1]),
+        Some(&[0xdd, 0xb6]),
+        Some(&[0xdd, 0xaa]),
+        Some(&[0xb7, 0x6c]),
+        Some(&[0xdd, 0xbb]),
+        Some(&[0xb7, 0x69]),
+        Some(&[0xdd, 0x7a]),
+        None,
+        Some(&[0xdd, 0x7b]),
+        Some(&[0xb7, 0x62]),
+        Some(&[0xb7, 0x6b]),
+        Some(&[0xdd, 0xa4]),
+        Some(&[0xb7, 0x6e]),
+        Some(&[0xb7, 0x6f]),
+        Some(&[0xdd, 0xa5]),
+        None,
+        Some(&[0xdd, 0xb2]),
+        Some(&[0xdd, 0xb8]),
+        Some(&[0xb7, 0x6a]),
+        None,
+        Some(&[0xb7, 0x64]),
+        Some(&[0xdd, 0xa3]),
+        Some(&[0xdd, 0x7d]),
+        Some(&[0xdd, 0xba]),
+        Some(&[0xdd, 0xa8]),
+        Some(&[0xdd, 0xa9]),
+        Some(&[0xdd, 0x7e]),
+        Some(&[0xdd, 0xb4]),
+        Some(&[0xdd, 0xab]),
+        Some(&[0xdd, 0xb5]),
+        Some(&[0xdd, 0xad]),
+        None,
+        Some(&[0xb7, 0x65]),
+        Some(&[0xe1, 0xd9]),
+        Some(&[0xb7, 0x68]),
+        Some(&[0xb7, 0x66]),
+        Some(&[0xdd, 0xb9]),
+        Some(&[0xdd, 0xb0]),
+        Some(&[0xdd, 0xac]),
+        None,
+        None,
+        Some(&[0xdd, 0xa1]),
+        Some(&[0xba, 0x53]),
+        Some(&[0xdd, 0xaf]),
+        Some(&[0xb7, 0x6d]),
+        Some(&[0xdd, 0xa7]),
+        None,
+        Some(&[0xdd, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0x67]),
+        Some(&[0xb7, 0x63]),
+        Some(&[0xe1, 0xee]),
+        Some(&[0xdd, 0xb3]),
+        Some(&[0xdd, 0xae]),
+        None,
+        Some(&[0xdd, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xe9]),
+        None,
+        Some(&[0xe1, 0xda]),
+        Some(&[0xe1, 0xe5]),
+        None,
+        Some(&[0xe1, 0xec]),
+        Some(&[0xba, 0x51]),
+        Some(&[0xb4, 0xac]),
+        Some(&[0xe1, 0xea]),
+        Some(&[0xba, 0x4c]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0x4b]),
+        Some(&[0xe1, 0xf1]),
+        None,
+        Some(&[0xe1, 0xdb]),
+        Some(&[0xe1, 0xe8]),
+        Some(&[0xe1, 0xdc]),
+        Some(&[0xe1, 0xe7]),
+        Some(&[0xba, 0x4f]),
+        Some(&[0xe1, 0xeb]),
+        Some(&[0xd9, 0x62]),
+        None,
+        None,
+        None,
+        Some(&[0xe1, 0xf2]),
+        Some(&[0xe1, 0xe3]),
+        Some(&[0xba, 0x52]),
+        Some(&[0xe5, 0xba]),
+        Some(&[0xbc, 0xaf]),
+        None,
+        Some(&[0xe1, 0xf0]),
+        Some(&[0xe1, 0xef]),
+        Some(&[0xba, 0x54]),
+        Some(&[0xe5, 0xad]),
+        Some(&[0xbc, 0xb0]),
+        Some(&[0xe5, 0xae]),
+        None,
+        Some(&[0xe1, 0xdf]),
+        Some(&[0xe1, 0xe0]),
+        Some(&[0xe1, 0xdd]),
+        Some(&[0xe1, 0xe2]),
+        Some(&[0xe1, 0xde]),
+        Some(&[0xe1, 0xf3]),
+        Some(&[0xba, 0x4e]),
+        Some(&[0xbc, 0xb1]),
+        Some(&[0xba, 0x50]),
+        Some(&[0xba, 0x55]),
+        None,
+        Some(&[0xe1, 0xe1]),
+        None,
+        Some(&[0xe1, 0xed]),
+        None,
+        None,
+        Some(&[0xe1, 0xe6]),
+        None,
+        None,
+        Some(&[0xe5, 0xb1]),
+        None,
+        Some(&[0xba, 0x4a]),
+        Some(&[0xbc, 0xb4]),
+        Some(&[0xe9, 0xaa]),
+        Some(&[0xe5, 0xb6]),
+        Some(&[0xe5, 0xb5]),
+        Some(&[0xe5, 0xb7]),
+        None,
+        None,
+        Some(&[0xe5, 0xb4]),
+        Some(&[0xbc, 0xb5]),
+        None,
+        Some(&[0xbc, 0xbb]),
+        Some(&[0xbc, 0xb8]),
+        None,
+        Some(&[0xbc, 0xb9]),
+        Some(&[0xe5, 0xaf]),
+        Some(&[0xe5, 0xb2]),
+        Some(&[0xe5, 0xbc]),
+        Some(&[0xbc, 0xc1]),
+        Some(&[0xbc, 0xbf]),
+        None,
+        Some(&[0xe5, 0xb3]),
+        Some(&[0xd9, 0x5a]),
+        Some(&[0xbc, 0xb2]),
+        Some(&[0xe5, 0xb9]),
+        Some(&[0xe5, 0xb0]),
+        None,
+        Some(&[0xbc, 0xc2]),
+        Some(&[0xe5, 0xb8]),
+        Some(&[0xba, 0x4d]),
+        Some(&[0xbc, 0xb7]),
+        Some(&[0xe1, 0xe4]),
+        None,
+        None,
+        Some(&[0xbc, 0xba]),
+        None,
+        Some(&[0xbc, 0xbe]),
+        Some(&[0xbc, 0xc0]),
+        Some(&[0xbc, 0xbd]),
+        Some(&[0xbc, 0xbc]),
+        None,
+        Some(&[0xbc, 0xb6]),
+        Some(&[0xe5, 0xbb]),
+        Some(&[0xbc, 0xb3]),
+        Some(&[0xbc, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xd8]),
+        Some(&[0xbe, 0xd9]),
+        Some(&[0xe9, 0xa9]),
+        Some(&[0xbe, 0xe2]),
+        Some(&[0xbe, 0xdf]),
+        None,
+        Some(&[0xbe, 0xd6]),
+        Some(&[0xbe, 0xdd]),
+        Some(&[0xe9, 0xab]),
+        Some(&[0xbe, 0xdb]),
+        Some(&[0xbe, 0xd5]),
+        None,
+        Some(&[0xbe, 0xdc]),
+        None,
+        Some(&[0xe9, 0xa8]),
+        Some(&[0xc0, 0xbb]),
+        Some(&[0xbe, 0xd7]),
+        None,
+        Some(&[0xbe, 0xde]),
+        Some(&[0xc0, 0xba]),
+        Some(&[0xe9, 0xa7]),
+        Some(&[0xe9, 0xa6]),
+        None,
+        Some(&[0xbe, 0xe0]),
+        None,
+        Some(&[0xbe, 0xe1]),
+        None,
+        Some(&[0xe9, 0xa5]),
+        Some(&[0xe9, 0xa4]),
+        Some(&[0xc0, 0xbc]),
+        Some(&[0xe9, 0xae]),
+        Some(&[0xbe, 0xda]),
+        Some(&[0xe9, 0xac]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xbd]),
+        None,
+        Some(&[0xc0, 0xc2]),
+        Some(&[0xec, 0xea]),
+        Some(&[0xec, 0xec]),
+        None,
+        Some(&[0xc0, 0xbf]),
+        None,
+        Some(&[0xec, 0xed]),
+        Some(&[0xec, 0xe9]),
+        None,
+        Some(&[0xec, 0xeb]),
+        Some(&[0xc0, 0xc0]),
+        Some(&[0xc0, 0xc3]),
+        None,
+        Some(&[0xec, 0xe8]),
+        Some(&[0xc0, 0xbe]),
+        Some(&[0xc0, 0xc1]),
+        Some(&[0xc2, 0x59]),
+        Some(&[0xe9, 0xad]),
+        Some(&[0xc2, 0x58]),
+        None,
+        None,
+        Some(&[0xc2, 0x5e]),
+        Some(&[0xef, 0xd4]),
+        None,
+        Some(&[0xc2, 0x5c]),
+        Some(&[0xc2, 0x5d]),
+        Some(&[0xef, 0xd7]),
+        Some(&[0xef, 0xd3]),
+        Some(&[0xc2, 0x5a]),
+        Some(&[0xef, 0xd1]),
+        Some(&[0xc3, 0x6b]),
+        Some(&[0xef, 0xd5]),
+        None,
+        Some(&[0xef, 0xd6]),
+        Some(&[0xef, 0xd2]),
+        None,
+        Some(&[0xc2, 0x5b]),
+        Some(&[0xf2, 0x42]),
+        None,
+        Some(&[0xf2, 0x45]),
+        None,
+        None,
+        Some(&[0xf2, 0x46]),
+        Some(&[0xf2, 0x44]),
+        Some(&[0xf2, 0x47]),
+        Some(&[0xc3, 0x6c]),
+        Some(&[0xf2, 0x43]),
+        None,
+        None,
+        Some(&[0xf4, 0x4e]),
+        Some(&[0xc4, 0x64]),
+        Some(&[0xf4, 0x4d]),
+        Some(&[0xf4, 0x4c]),
+        Some(&[0xf4, 0x4b]),
+        Some(&[0xc4, 0x63]),
+        Some(&[0xc4, 0x65]),
+        None,
+        Some(&[0xf5, 0xcd]),
+        Some(&[0xc4, 0xe2]),
+        Some(&[0xc4, 0xe1]),
+        None,
+        None,
+        Some(&[0xf6, 0xe1]),
+        Some(&[0xf6, 0xe0]),
+        Some(&[0xf6, 0xe3]),
+        Some(&[0xc5, 0xcb]),
+        Some(&[0xc5, 0x75]),
+        Some(&[0xf7, 0xdd]),
+        Some(&[0xf6, 0xe2]),
+        None,
+        None,
+        Some(&[0xf7, 0xdc]),
+        Some(&[0xc5, 0xcd]),
+        Some(&[0xc5, 0xcc]),
+        Some(&[0xc5, 0xf3]),
+        Some(&[0xf8, 0xa9]),
+        Some(&[0xf8, 0xef]),
+        Some(&[0xa4, 0xe4]),
+        None,
+        None,
+        Some(&[0xd9, 0x72]),
+        Some(&[0xe9, 0xaf]),
+        None,
+        None,
+        Some(&[0xa6, 0xac]),
+        Some(&[0xca, 0xf7]),
+        Some(&[0xa7, 0xf1]),
+        Some(&[0xa7, 0xef]),
+        None,
+        Some(&[0xa7, 0xf0]),
+        None,
+        Some(&[0xcc, 0xc1]),
+        Some(&[0xa9, 0xf1]),
+        Some(&[0xac, 0x46]),
+        None,
+        Some(&[0xce, 0xe7]),
+        None,
+        Some(&[0xce, 0xe8]),
+        None,
+        Some(&[0xac, 0x47]),
+        Some(&[0xd1, 0xce]),
+        None,
+        Some(&[0xae, 0xc4]),
+        Some(&[0xae, 0xc5]),
+        Some(&[0xd1, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xd3]),
+        None,
+        Some(&[0xb1, 0xcf]),
+        None,
+        Some(&[0xd5, 0xa7]),
+        Some(&[0xb1, 0xd6]),
+        Some(&[0xb1, 0xd5]),
+        Some(&[0xb1, 0xce]),
+        Some(&[0xb1, 0xd1]),
+        Some(&[0xb1, 0xd4]),
+        Some(&[0xb1, 0xd0]),
+        None,
+        None,
+        Some(&[0xd9, 0x76]),
+        Some(&[0xb1, 0xcd]),
+        Some(&[0xb4, 0xaf]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xb1]),
+        Some(&[0xb4, 0xb2]),
+        Some(&[0xd9, 0x75]),
+        Some(&[0xd9, 0x78]),
+        Some(&[0xb4, 0xb0]),
+        Some(&[0xd9, 0x73]),
+        Some(&[0xd9, 0x77]),
+        None,
+        Some(&[0xd9, 0x74]),
+        None,
+        Some(&[0xb7, 0x71]),
+        None,
+        None,
+        Some(&[0xdd, 0xbc]),
+        None,
+        None,
+        Some(&[0xba, 0x56]),
+        Some(&[0xe1, 0xf4]),
+        Some(&[0xbe, 0xe3]),
+        Some(&[0xbc, 0xc4]),
+        Some(&[0xe5, 0xbd]),
+        Some(&[0xbc, 0xc5]),
+        Some(&[0xbc, 0xc6]),
+        Some(&[0xe5, 0xbf]),
+        Some(&[0xe5, 0xbe]),
+        Some(&[0xe5, 0xc0]),
+        Some(&[0xe9, 0xb1]),
+        None,
+        None,
+        Some(&[0xe9, 0xb0]),
+        Some(&[0xec, 0xef]),
+        Some(&[0xec, 0xee]),
+        Some(&[0xc0, 0xc4]),
+        Some(&[0xc0, 0xc5]),
+        Some(&[0xf2, 0x48]),
+        None,
+        None,
+        Some(&[0xa4, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0x79]),
+        None,
+        None,
+        None,
+        Some(&[0xb4, 0xb4]),
+        Some(&[0xb4, 0xb3]),
+        Some(&[0xdd, 0xbd]),
+        None,
+        Some(&[0xef, 0xd8]),
+        Some(&[0xc4, 0xe3]),
+        Some(&[0xf7, 0xde]),
+        Some(&[0xa4, 0xe6]),
+        None,
+        Some(&[0xae, 0xc6]),
+        None,
+        Some(&[0xb1, 0xd8]),
+        Some(&[0xb1, 0xd7]),
+        Some(&[0xd9, 0x7a]),
+        Some(&[0xd9, 0x7b]),
+        Some(&[0xb7, 0x72]),
+        Some(&[0xe1, 0xf5]),
+        Some(&[0xba, 0x57]),
+        Some(&[0xe9, 0xb2]),
+        None,
+        Some(&[0xa4, 0xe7]),
+        Some(&[0xa5, 0xb8]),
+        None,
+        Some(&[0xa9, 0xf2]),
+        Some(&[0xcc, 0xc2]),
+        None,
+        Some(&[0xce, 0xe9]),
+        Some(&[0xac, 0x48]),
+        Some(&[0xb1, 0xd9]),
+        None,
+        Some(&[0xd9, 0x7c]),
+        Some(&[0xb4, 0xb5]),
+        Some(&[0xb7, 0x73]),
+        None,
+        Some(&[0xe5, 0xc1]),
+        Some(&[0xe5, 0xc2]),
+        None,
+        None,
+        Some(&[0xec, 0xf0]),
+        Some(&[0xc2, 0x5f]),
+        Some(&[0xf8, 0xf0]),
+        Some(&[0xa4, 0xe8]),
+        None,
+        Some(&[0xcc, 0xc3]),
+        Some(&[0xa9, 0xf3]),
+        Some(&[0xac, 0x49]),
+        None,
+        Some(&[0xce, 0xea]),
+        None,
+        Some(&[0xae, 0xc7]),
+        Some(&[0xd1, 0xd2]),
+        Some(&[0xd1, 0xd0]),
+        Some(&[0xd1, 0xd1]),
+        Some(&[0xae, 0xc8]),
+        Some(&[0xd1, 0xcf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xdb]),
+        Some(&[0xb1, 0xdc]),
+        Some(&[0xd5, 0xa8]),
+        Some(&[0xb1, 0xdd]),
+        Some(&[0xb1, 0xda]),
+        Some(&[0xd9, 0x7d]),
+        None,
+        Some(&[0xd9, 0x7e]),
+        Some(&[0xdd, 0xbe]),
+        None,
+        None,
+        Some(&[0xba, 0x59]),
+        Some(&[0xba, 0x58]),
+        None,
+        None,
+        Some(&[0xec, 0xf1]),
+        Some(&[0xef, 0xd9]),
+        None,
+        Some(&[0xf2, 0x4a]),
+        Some(&[0xf2, 0x49]),
+        Some(&[0xf4, 0x4f]),
+        None,
+        Some(&[0xc9, 0x5e]),
+        Some(&[0xac, 0x4a]),
+        None,
+        None,
+        Some(&[0xa4, 0xe9]),
+        Some(&[0xa5, 0xb9]),
+        None,
+        Some(&[0xa6, 0xae]),
+        Some(&[0xa6, 0xad]),
+        None,
+        None,
+        Some(&[0xa6, 0xaf]),
+        Some(&[0xa6, 0xb0]),
+        Some(&[0xc9, 0xee]),
+        Some(&[0xc9, 0xed]),
+        Some(&[0xca, 0xf8]),
+        Some(&[0xa7, 0xf2]),
+        Some(&[0xca, 0xfb]),
+        Some(&[0xca, 0xfa]),
+        Some(&[0xca, 0xf9]),
+        Some(&[0xca, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa9, 0xf4]),
+        Some(&[0xcc, 0xc9]),
+        Some(&[0xcc, 0xc5]),
+        Some(&[0xcc, 0xce]),
+        None,
+        None,
+        Some(&[0xa9, 0xfb]),
+        None,
+        Some(&[0xa9, 0xf9]),
+        Some(&[0xcc, 0xca]),
+        Some(&[0xcc, 0xc6]),
+        Some(&[0xcc, 0xcd]),
+        Some(&[0xa9, 0xf8]),
+        Some(&[0xaa, 0x40]),
+        Some(&[0xcc, 0xc8]),
+        Some(&[0xcc, 0xc4]),
+        Some(&[0xa9, 0xfe]),
+        Some(&[0xcc, 0xcb]),
+        Some(&[0xa9, 0xf7]),
+        Some(&[0xcc, 0xcc]),
+        Some(&[0xa9, 0xfa]),
+        Some(&[0xa9, 0xfc]),
+        Some(&[0xcc, 0xd0]),
+        Some(&[0xcc, 0xcf]),
+        Some(&[0xcc, 0xc7]),
+        Some(&[0xa9, 0xf6]),
+        Some(&[0xa9, 0xf5]),
+        Some(&[0xa9, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xce, 0xef]),
+        Some(&[0xce, 0xf5]),
+        None,
+        Some(&[0xac, 0x50]),
+        Some(&[0xac, 0x4d]),
+        Some(&[0xce, 0xec]),
+        Some(&[0xce, 0xf1]),
+        None,
+        Some(&[0xac, 0x53]),
+        Some(&[0xac, 0x4b]),
+        Some(&[0xce, 0xf0]),
+        Some(&[0xac, 0x4e]),
+        Some(&[0xac, 0x51]),
+        None,
+        None,
+        Some(&[0xce, 0xf3]),
+        None,
+        Some(&[0xac, 0x4c]),
+        Some(&[0xce, 0xf8]),
+        Some(&[0xac, 0x4f]),
+        None,
+        Some(&[0xac, 0x52]),
+        Some(&[0xce, 0xed]),
+        Some(&[0xce, 0xf2]),
+        Some(&[0xce, 0xf6]),
+        Some(&[0xce, 0xee]),
+        Some(&[0xce, 0xeb]),
+        None,
+        None,
+        Some(&[0xce, 0xf7]),
+        Some(&[0xce, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xae, 0xd0]),
+        Some(&[0xae, 0xc9]),
+        Some(&[0xae, 0xcc]),
+        None,
+        Some(&[0xae, 0xcf]),
+        None,
+        Some(&[0xd1, 0xd5]),
+        None,
+        Some(&[0xae, 0xca]),
+        Some(&[0xd1, 0xd3]),
+        None,
+        Some(&[0xae, 0xce]),
+        None,
+        None,
+        Some(&[0xae, 0xcb]),
+        None,
+        Some(&[0xd1, 0xd6]),
+        Some(&[0xae, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xac]),
+        Some(&[0xb1, 0xdf]),
+        Some(&[0xd5, 0xab]),
+        Some(&[0xd5, 0xad]),
+        Some(&[0xb1, 0xde]),
+        Some(&[0xb1, 0xe3]),
+        Some(&[0xd1, 0xd4]),
+        None,
+        Some(&[0xd5, 0xaa]),
+        Some(&[0xd5, 0xae]),
+        None,
+        Some(&[0xb1, 0xe0]),
+        Some(&[0xd5, 0xa9]),
+        Some(&[0xb1, 0xe2]),
+        None,
+        Some(&[0xb1, 0xe1]),
+        None,
+        Some(&[0xd9, 0xa7]),
+        None,
+        Some(&[0xd9, 0xa2]),
+        None,
+        Some(&[0xb4, 0xb6]),
+        Some(&[0xb4, 0xba]),
+        Some(&[0xb4, 0xb7]),
+        Some(&[0xd9, 0xa5]),
+        Some(&[0xd9, 0xa8]),
+        None,
+        Some(&[0xb4, 0xb8]),
+        None,
+        Some(&[0xb4, 0xb9]),
+        Some(&[0xb4, 0xbe]),
+        Some(&[0xdd, 0xc7]),
+        Some(&[0xd9, 0xa6]),
+        Some(&[0xb4, 0xbc]),
+        Some(&[0xd9, 0xa3]),
+        Some(&[0xd9, 0xa1]),
+        None,
+        Some(&[0xb4, 0xbd]),
+        None,
+        Some(&[0xd9, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0x79]),
+        None,
+        Some(&[0xdd, 0xbf]),
+        Some(&[0xb7, 0x76]),
+        Some(&[0xb7, 0x77]),
+        Some(&[0xb7, 0x75]),
+        Some(&[0xdd, 0xc4]),
+        Some(&[0xdd, 0xc3]),
+        Some(&[0xdd, 0xc0]),
+        Some(&[0xb7, 0x7b]),
+        None,
+        None,
+        Some(&[0xdd, 0xc2]),
+        Some(&[0xb4, 0xbb]),
+        None,
+        None,
+        Some(&[0xdd, 0xc6]),
+        Some(&[0xdd, 0xc1]),
+        Some(&[0xb7, 0x78]),
+        Some(&[0xb7, 0x74]),
+        Some(&[0xb7, 0x7a]),
+        Some(&[0xdd, 0xc5]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0x5c]),
+        None,
+        Some(&[0xe1, 0xf8]),
+        Some(&[0xe1, 0xf7]),
+        Some(&[0xe1, 0xf6]),
+        Some(&[0xba, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0x5b]),
+        Some(&[0xe5, 0xc5]),
+        Some(&[0xe5, 0xc8]),
+        Some(&[0xbc, 0xc8]),
+        None,
+        None,
+        Some(&[0xbc, 0xc7]),
+        Some(&[0xe5, 0xc9]),
+        Some(&[0xe5, 0xc4]),
+        Some(&[0xbc, 0xca]),
+        Some(&[0xe5, 0xc6]),
+        None,
+        Some(&[0xbc, 0xc9]),
+        Some(&[0xe5, 0xc3]),
+        None,
+        Some(&[0xe5, 0xc7]),
+        Some(&[0xbe, 0xe9]),
+        Some(&[0xbe, 0xe6]),
+        Some(&[0xe9, 0xbb]),
+        Some(&[0xe9, 0xba]),
+        None,
+        Some(&[0xe9, 0xb9]),
+        Some(&[0xe9, 0xb4]),
+        None,
+        Some(&[0xe9, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0xe7]),
+        None,
+        Some(&[0xbe, 0xe4]),
+        Some(&[0xbe, 0xe8]),
+        Some(&[0xe9, 0xb3]),
+        Some(&[0xbe, 0xe5]),
+        Some(&[0xe9, 0xb6]),
+        Some(&[0xe9, 0xb7]),
+        Some(&[0xe9, 0xbc]),
+        None,
+        None,
+        Some(&[0xe9, 0xb8]),
+        None,
+        None,
+        Some(&[0xec, 0xf2]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xc7]),
+        None,
+        Some(&[0xef, 0xdc]),
+        Some(&[0xc0, 0xc6]),
+        Some(&[0xef, 0xda]),
+        Some(&[0xef, 0xdb]),
+        Some(&[0xc2, 0x60]),
+        Some(&[0xc3, 0x6e]),
+        Some(&[0xf2, 0x4b]),
+        None,
+        Some(&[0xc3, 0x6d]),
+        None,
+        None,
+        Some(&[0xf4, 0x51]),
+        Some(&[0xf4, 0x52]),
+        None,
+        Some(&[0xc4, 0x66]),
+        None,
+        Some(&[0xf4, 0x50]),
+        Some(&[0xc4, 0xe4]),
+        None,
+        Some(&[0xf7, 0xdf]),
+        Some(&[0xc5, 0xce]),
+        Some(&[0xf8, 0xaa]),
+        Some(&[0xf8, 0xab]),
+        None,
+        Some(&[0xa4, 0xea]),
+        None,
+        Some(&[0xa6, 0xb1]),
+        Some(&[0xa6, 0xb2]),
+        Some(&[0xa7, 0xf3]),
+        None,
+        Some(&[0xcc, 0xd1]),
+        Some(&[0xac, 0x54]),
+        Some(&[0xae, 0xd1]),
+        Some(&[0xb1, 0xe4]),
+        None,
+        None,
+        Some(&[0xb0, 0xd2]),
+        None,
+        Some(&[0xb4, 0xbf]),
+        Some(&[0xb4, 0xc0]),
+        Some(&[0xb3, 0xcc]),
+        Some(&[0xd9, 0xa9]),
+        None,
+        Some(&[0xb7, 0x7c]),
+        Some(&[0xe1, 0xfa]),
+        Some(&[0xe1, 0xf9]),
+        None,
+        None,
+        Some(&[0xa4, 0xeb]),
+        Some(&[0xa6, 0xb3]),
+        Some(&[0xcc, 0xd2]),
+        Some(&[0xaa, 0x42]),
+        None,
+        Some(&[0xaa, 0x41]),
+        None,
+        Some(&[0xce, 0xf9]),
+        Some(&[0xce, 0xfa]),
+        None,
+        Some(&[0xd1, 0xd7]),
+        Some(&[0xd1, 0xd8]),
+        Some(&[0xae, 0xd2]),
+        Some(&[0xae, 0xd3]),
+        None,
+        Some(&[0xae, 0xd4]),
+        Some(&[0xd5, 0xaf]),
+        None,
+        None,
+        Some(&[0xb1, 0xe6]),
+        None,
+        Some(&[0xb4, 0xc2]),
+        None,
+        Some(&[0xb4, 0xc1]),
+        Some(&[0xdd, 0xc8]),
+        Some(&[0xdf, 0x7a]),
+        Some(&[0xe1, 0xfb]),
+        Some(&[0xe9, 0xbd]),
+        None,
+        None,
+        Some(&[0xc2, 0x61]),
+        Some(&[0xc4, 0x67]),
+        Some(&[0xa4, 0xec]),
+        None,
+        Some(&[0xa5, 0xbc]),
+        Some(&[0xa5, 0xbd]),
+        Some(&[0xa5, 0xbb]),
+        Some(&[0xa5, 0xbe]),
+        Some(&[0xa5, 0xba]),
+        None,
+        None,
+        Some(&[0xa6, 0xb6]),
+        None,
+        Some(&[0xc9, 0xf6]),
+        Some(&[0xa6, 0xb5]),
+        Some(&[0xa6, 0xb7]),
+        None,
+        None,
+        Some(&[0xc9, 0xf1]),
+        Some(&[0xc9, 0xf0]),
+        Some(&[0xc9, 0xf3]),
+        Some(&[0xc9, 0xf2]),
+        Some(&[0xc9, 0xf5]),
+        Some(&[0xa6, 0xb4]),
+        Some(&[0xc9, 0xef]),
+        Some(&[0xc9, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xca, 0xfd]),
+        Some(&[0xa7, 0xfd]),
+        Some(&[0xca, 0xfe]),
+        Some(&[0xcb, 0x43]),
+        Some(&[0xa7, 0xfc]),
+        None,
+        Some(&[0xcb, 0x47]),
+        Some(&[0xcb, 0x42]),
+        Some(&[0xcb, 0x45]),
+        Some(&[0xa7, 0xf5]),
+        Some(&[0xa7, 0xf6]),
+        Some(&[0xa7, 0xf7]),
+        Some(&[0xa7, 0xf8]),
+        None,
+        Some(&[0xa8, 0x40]),
+        None,
+        Some(&[0xcb, 0x41]),
+        Some(&[0xa7, 0xfa]),
+        Some(&[0xa8, 0x41]),
+        None,
+        Some(&[0xcb, 0x40]),
+        Some(&[0xcb, 0x46]),
+        None,
+        Some(&[0xa7, 0xf9]),
+        Some(&[0xcb, 0x44]),
+        Some(&[0xa7, 0xfb]),
+        Some(&[0xa7, 0xf4]),
+        Some(&[0xa7, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0x57]),
+        None,
+        Some(&[0xcc, 0xd4]),
+        Some(&[0xaa, 0x43]),
+        None,
+        Some(&[0xaa, 0x4d]),
+        Some(&[0xaa, 0x4e]),
+        Some(&[0xaa, 0x46]),
+        Some(&[0xaa, 0x58]),
+        Some(&[0xaa, 0x48]),
+        Some(&[0xcc, 0xdc]),
+        Some(&[0xaa, 0x53]),
+        Some(&[0xcc, 0xd7]),
+        Some(&[0xaa, 0x49]),
+        Some(&[0xcc, 0xe6]),
+        Some(&[0xcc, 0xe7]),
+        Some(&[0xcc, 0xdf]),
+        Some(&[0xcc, 0xd8]),
+        Some(&[0xaa, 0x56]),
+        Some(&[0xcc, 0xe4]),
+        Some(&[0xaa, 0x51]),
+        Some(&[0xaa, 0x4f]),
+        None,
+        Some(&[0xcc, 0xe5]),
+        None,
+        Some(&[0xcc, 0xe3]),
+        Some(&[0xcc, 0xdb]),
+        Some(&[0xcc, 0xd3]),
+        Some(&[0xcc, 0xda]),
+        Some(&[0xaa, 0x4a]),
+        None,
+        Some(&[0xaa, 0x50]),
+        None,
+        Some(&[0xaa, 0x44]),
+        Some(&[0xcc, 0xde]),
+        Some(&[0xcc, 0xdd]),
+        Some(&[0xcc, 0xd5]),
+        None,
+        Some(&[0xaa, 0x52]),
+        Some(&[0xcc, 0xe1]),
+        Some(&[0xcc, 0xd6]),
+        Some(&[0xaa, 0x55]),
+        Some(&[0xcc, 0xe8]),
+        Some(&[0xaa, 0x45]),
+        None,
+        Some(&[0xaa, 0x4c]),
+        Some(&[0xcc, 0xd9]),
+        Some(&[0xcc, 0xe2]),
+        Some(&[0xaa, 0x54]),
+        None,
+        Some(&[0xaa, 0x47]),
+        Some(&[0xaa, 0x4b]),
+        None,
+        Some(&[0xcc, 0xe0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0x5b]),
+        Some(&[0xac, 0x5c]),
+        Some(&[0xac, 0x69]),
+        None,
+        Some(&[0xcf, 0x56]),
+        Some(&[0xcf, 0x4c]),
+        Some(&[0xac, 0x62]),
+        Some(&[0xcf, 0x4a]),
+        Some(&[0xac, 0x5b]),
+        Some(&[0xcf, 0x45]),
+        Some(&[0xac, 0x65]),
+        Some(&[0xcf, 0x52]),
+        Some(&[0xce, 0xfe]),
+        Some(&[0xcf, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0x44]),
+        Some(&[0xce, 0xfb]),
+        Some(&[0xcf, 0x51]),
+        Some(&[0xcf, 0x61]),
+        Some(&[0xac, 0x60]),
+        Some(&[0xcf, 0x46]),
+        Some(&[0xcf, 0x58]),
+        None,
+        Some(&[0xce, 0xfd]),
+        Some(&[0xcf, 0x5f]),
+        Some(&[0xcf, 0x60]),
+        Some(&[0xcf, 0x63]),
+        Some(&[0xcf, 0x5a]),
+        Some(&[0xcf, 0x4b]),
+        Some(&[0xcf, 0x53]),
+        Some(&[0xac, 0x66]),
+        Some(&[0xac, 0x59]),
+        Some(&[0xac, 0x61]),
+        Some(&[0xac, 0x6d]),
+        Some(&[0xac, 0x56]),
+        Some(&[0xac, 0x58]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0x43]),
+        Some(&[0xac, 0x6a]),
+        Some(&[0xac, 0x63]),
+        Some(&[0xcf, 0x5d]),
+        Some(&[0xcf, 0x40]),
+        Some(&[0xac, 0x6c]),
+        Some(&[0xac, 0x67]),
+        Some(&[0xcf, 0x49]),
+        None,
+        None,
+        Some(&[0xac, 0x6b]),
+        Some(&[0xcf, 0x50]),
+        Some(&[0xcf, 0x48]),
+        Some(&[0xac, 0x64]),
+        Some(&[0xcf, 0x5c]),
+        Some(&[0xcf, 0x54]),
+        None,
+        Some(&[0xac, 0x5e]),
+        Some(&[0xcf, 0x62]),
+        Some(&[0xcf, 0x47]),
+        Some(&[0xac, 0x5a]),
+        Some(&[0xcf, 0x59]),
+        Some(&[0xcf, 0x4f]),
+        Some(&[0xac, 0x5f]),
+        Some(&[0xcf, 0x55]),
+        Some(&[0xac, 0x57]),
+        Some(&[0xce, 0xfc]),
+        Some(&[0xac, 0x68]),
+        Some(&[0xae, 0xe3]),
+        Some(&[0xac, 0x5d]),
+        Some(&[0xcf, 0x4e]),
+        Some(&[0xcf, 0x4d]),
+        Some(&[0xcf, 0x42]),
+        None,
+        Some(&[0xcf, 0x5e]),
+        None,
+        Some(&[0xcf, 0x57]),
+        None,
+        None,
+        Some(&[0xac, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xec]),
+        Some(&[0xae, 0xea]),
+        Some(&[0xd1, 0xed]),
+        None,
+        Some(&[0xd1, 0xe1]),
+        Some(&[0xae, 0xdf]),
+        Some(&[0xae, 0xeb]),
+        None,
+        Some(&[0xd1, 0xda]),
+        None,
+        Some(&[0xd1, 0xe3]),
+        Some(&[0xd1, 0xeb]),
+        None,
+        Some(&[0xd1, 0xd9]),
+        Some(&[0xd1, 0xf4]),
+        Some(&[0xae, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf3]),
+        Some(&[0xd1, 0xee]),
+        None,
+        Some(&[0xd1, 0xef]),
+        Some(&[0xae, 0xdd]),
+        Some(&[0xae, 0xe8]),
+        Some(&[0xd1, 0xe5]),
+        None,
+        Some(&[0xd1, 0xe6]),
+        Some(&[0xd1, 0xf0]),
+        Some(&[0xd1, 0xe7]),
+        None,
+        Some(&[0xd1, 0xe2]),
+        Some(&[0xd1, 0xdc]),
+        Some(&[0xd1, 0xdd]),
+        Some(&[0xd1, 0xea]),
+        Some(&[0xd1, 0xe4]),
+        None,
+        None,
+        Some(&[0xae, 0xd6]),
+        Some(&[0xae, 0xda]),
+        Some(&[0xd1, 0xf2]),
+        Some(&[0xd1, 0xde]),
+        Some(&[0xae, 0xe6]),
+        Some(&[0xae, 0xe2]),
+        None,
+        None,
+        Some(&[0xae, 0xe5]),
+        Some(&[0xae, 0xec]),
+        Some(&[0xae, 0xdb]),
+        Some(&[0xae, 0xe7]),
+        Some(&[0xd1, 0xe9]),
+        Some(&[0xae, 0xe9]),
+        Some(&[0xae, 0xd8]),
+        None,
+        Some(&[0xae, 0xd7]),
+        Some(&[0xd1, 0xdb]),
+        None,
+        Some(&[0xd1, 0xdf]),
+        Some(&[0xae, 0xe0]),
+        Some(&[0xd1, 0xf1]),
+        Some(&[0xd1, 0xe8]),
+        Some(&[0xd1, 0xe0]),
+        Some(&[0xae, 0xe4]),
+        Some(&[0xae, 0xe1]),
+        None,
+        Some(&[0xae, 0xd9]),
+        Some(&[0xae, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xc4]),
+        None,
+        Some(&[0xd5, 0xb4]),
+        Some(&[0xd5, 0xb5]),
+        Some(&[0xd5, 0xb9]),
+        None,
+        Some(&[0xd5, 0xc8]),
+        Some(&[0xd5, 0xc5]),
+        None,
+        Some(&[0xd5, 0xbe]),
+        Some(&[0xd5, 0xbd]),
+        Some(&[0xb1, 0xed]),
+        Some(&[0xd5, 0xc1]),
+        Some(&[0xd5, 0xd0]),
+        Some(&[0xd5, 0xb0]),
+        None,
+        Some(&[0xd5, 0xd1]),
+        Some(&[0xd5, 0xc3]),
+        Some(&[0xd5, 0xd5]),
+        Some(&[0xd5, 0xc9]),
+        Some(&[0xb1, 0xec]),
+        Some(&[0xd5, 0xc7]),
+        Some(&[0xb1, 0xe7]),
+        Some(&[0xb1, 0xfc]),
+        Some(&[0xb1, 0xf2]),
+        None,
+        Some(&[0xb1, 0xf6]),
+        Some(&[0xb1, 0xf5]),
+        Some(&[0xd5, 0xb1]),
+        None,
+        Some(&[0xd5, 0xce]),
+        Some(&[0xd5, 0xd4]),
+        Some(&[0xd5, 0xcc]),
+        Some(&[0xd5, 0xd3]),
+        None,
+        None,
+        Some(&[0xd5, 0xc0]),
+        Some(&[0xd5, 0xb2]),
+        Some(&[0xd5, 0xd2]),
+        Some(&[0xd5, 0xc2]),
+        Some(&[0xb1, 0xea]),
+        Some(&[0xb1, 0xf7]),
+        None,
+        Some(&[0xd5, 0xcb]),
+        Some(&[0xb1, 0xf0]),
+        None,
+        None,
+        None,
+        Some(&[0xd5, 0xca]),
+        Some(&[0xd5, 0xb3]),
+        Some(&[0xb1, 0xf8]),
+        None,
+        Some(&[0xb1, 0xfa]),
+        Some(&[0xd5, 0xcd]),
+        Some(&[0xb1, 0xfb]),
+        Some(&[0xb1, 0xe9]),
+        Some(&[0xd5, 0xba]),
+        Some(&[0xd5, 0xcf]),
+        None,
+        None,
+        Some(&[0xb1, 0xef]),
+        Some(&[0xb1, 0xf9]),
+        Some(&[0xd5, 0xbc]),
+        Some(&[0xd5, 0xc6]),
+        Some(&[0xd5, 0xb7]),
+        Some(&[0xd5, 0xbb]),
+        Some(&[0xb1, 0xf4]),
+        Some(&[0xd5, 0xb6]),
+        Some(&[0xb1, 0xe8]),
+        Some(&[0xb1, 0xf1]),
+        Some(&[0xb1, 0xee]),
+        Some(&[0xd5, 0xbf]),
+        Some(&[0xae, 0xde]),
+        Some(&[0xd9, 0xc0]),
+        Some(&[0xb1, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb1, 0xf3]),
+        None,
+        Some(&[0xd9, 0xc3]),
+        Some(&[0xd9, 0xd9]),
+        Some(&[0xd9, 0xce]),
+        Some(&[0xb4, 0xd6]),
+        None,
+        Some(&[0xb4, 0xd1]),
+        Some(&[0xd9, 0xbd]),
+        Some(&[0xb4, 0xd2]),
+        Some(&[0xd9, 0xcd]),
+        None,
+        Some(&[0xd9, 0xc6]),
+        Some(&[0xd9, 0xd3]),
+        Some(&[0xb4, 0xce]),
+        Some(&[0xd9, 0xab]),
+        Some(&[0xd9, 0xd5]),
+        Some(&[0xb4, 0xc4]),
+        Some(&[0xd9, 0xb3]),
+        Some(&[0xb4, 0xc7]),
+        Some(&[0xb4, 0xc6]),
+        None,
+        Some(&[0xb4, 0xd7]),
+        None,
+        Some(&[0xd9, 0xad]),
+        Some(&[0xd9, 0xcf]),
+        Some(&[0xd9, 0xd0]),
+        Some(&[0xb4, 0xc9]),
+        Some(&[0xb4, 0xc5]),
+        Some(&[0xd9, 0xbb]),
+        None,
+        Some(&[0xb4, 0xd0]),
+        Some(&[0xd9, 0xb6]),
+        None,
+        Some(&[0xd9, 0xd1]),
+        Some(&[0xb4, 0xcc]),
+        Some(&[0xd9, 0xc9]),
+        Some(&[0xd9, 0xd6]),
+        Some(&[0xd9, 0xb0]),
+        Some(&[0xd9, 0xb5]),
+        Some(&[0xd9, 0xaf]),
+        None,
+        Some(&[0xb4, 0xcb]),
+        Some(&[0xd9, 0xc2]),
+        Some(&[0xdd, 0xde]),
+        Some(&[0xd9, 0xb1]),
+        Some(&[0xb4, 0xcf]),
+        Some(&[0xd9, 0xba]),
+        Some(&[0xd9, 0xd2]),
+        Some(&[0xb4, 0xca]),
+        Some(&[0xd9, 0xb7]),
+        Some(&[0xd9, 0xb4]),
+        Some(&[0xd9, 0xc5]),
+        Some(&[0xb4, 0xcd]),
+        Some(&[0xb4, 0xc3]),
+        Some(&[0xb4, 0xd9]),
+        Some(&[0xd9, 0xc8]),
+        Some(&[0xd9, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xac]),
+        Some(&[0xb4, 0xc8]),
+        Some(&[0xd9, 0xd4]),
+        Some(&[0xd9, 0xbc]),
+        Some(&[0xd9, 0xbe]),
+        None,
+        Some(&[0xd9, 0xcb]),
+        Some(&[0xd9, 0xca]),
+        Some(&[0xd9, 0xaa]),
+        Some(&[0xb4, 0xd3]),
+        Some(&[0xb4, 0xd5]),
+        Some(&[0xd9, 0xb2]),
+        Some(&[0xd9, 0xb9]),
+        Some(&[0xd9, 0xc1]),
+        Some(&[0xb4, 0xd4]),
+        Some(&[0xd9, 0xb8]),
+        Some(&[0xd9, 0xc4]),
+        Some(&[0xd9, 0xd7]),
+        None,
+        Some(&[0xd9, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdd, 0xf2]),
+        Some(&[0xb7, 0xa6]),
+        None,
+        Some(&[0xdd, 0xf0]),
+        Some(&[0xdd, 0xdb]),
+        Some(&[0xdd, 0xe0]),
+        Some(&[0xdd, 0xd9]),
+        None,
+        Some(&[0xdd, 0xec]),
+        Some(&[0xdd, 0xcb]),
+        Some(&[0xdd, 0xd2]),
+        None,
+        Some(&[0xdd, 0xea]),
+        Some(&[0xdd, 0xf4]),
+        Some(&[0xdd, 0xdc]),
+        None,
+        Some(&[0xdd, 0xcf]),
+        Some(&[0xdd, 0xe2]),
+        Some(&[0xdd, 0xe7]),
+        Some(&[0xdd, 0xd3]),
+        None,
+        Some(&[0xdd, 0xe4]),
+        Some(&[0xdd, 0xd0]),
+        None,
+        None,
+        Some(&[0xdd, 0xd7]),
+        Some(&[0xdd, 0xd8]),
+        Some(&[0xb7, 0xa8]),
+        Some(&[0xdd, 0xeb]),
+        Some(&[0xdd, 0xe9]),
+        None,
+        Some(&[0xdd, 0xcc]),
+        Some(&[0xdd, 0xee]),
+        None,
+        Some(&[0xdd, 0xef]),
+        Some(&[0xdd, 0xf1]),
+        Some(&[0xb7, 0xac]),
+        Some(&[0xb7, 0xa4]),
+        None,
+        Some(&[0xd5, 0xb8]),
+        Some(&[0xdd, 0xd4]),
+        Some(&[0xdd, 0xe6]),
+        Some(&[0xdd, 0xd5]),
+        Some(&[0xb7, 0xa1]),
+        Some(&[0xb7, 0xb1]),
+        Some(&[0xdd, 0xed]),
+        Some(&[0xb7, 0xaf]),
+        Some(&[0xb7, 0xab]),
+        Some(&[0xdd, 0xca]),
+        Some(&[0xb7, 0xa3]),
+        None,
+        Some(&[0xdd, 0xcd]),
+        Some(&[0xb7, 0xb0]),
+        None,
+        Some(&[0xdd, 0xdd]),
+        Some(&[0xdd, 0xc9]),
+        None,
+        Some(&[0xb7, 0xa9]),
+        Some(&[0xdd, 0xe1]),
+        Some(&[0xdd, 0xd1]),
+        Some(&[0xb7, 0xaa]),
+        Some(&[0xdd, 0xda]),
+        Some(&[0xb7, 0x7e]),
+        Some(&[0xb4, 0xd8]),
+        Some(&[0xdd, 0xe3]),
+        Some(&[0xd9, 0xbf]),
+        Some(&[0xdd, 0xce]),
+        None,
+        None,
+        Some(&[0xdd, 0xe8]),
+        Some(&[0xb7, 0xa5]),
+        Some(&[0xdd, 0xe5]),
+        Some(&[0xb7, 0xa2]),
+        Some(&[0xdd, 0xdf]),
+        Some(&[0xb7, 0xad]),
+        Some(&[0xdd, 0xd6]),
+        Some(&[0xdd, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xa7]),
+        Some(&[0xde, 0xc6]),
+        None,
+        None,
+        Some(&[0xb7, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x4a]),
+        Some(&[0xe2, 0x48]),
+        None,
+        Some(&[0xe2, 0x5e]),
+        Some(&[0xe2, 0x46]),
+        None,
+        Some(&[0xe2, 0x58]),
+        Some(&[0xb7, 0x7d]),
+        Some(&[0xba, 0x5f]),
+        Some(&[0xe2, 0x42]),
+        Some(&[0xe2, 0x5d]),
+        None,
+        Some(&[0xe2, 0x47]),
+        Some(&[0xe2, 0x55]),
+        Some(&[0xba, 0x64]),
+        Some(&[0xba, 0x5d]),
+        None,
+        Some(&[0xe2, 0x5b]),
+        None,
+        Some(&[0xe2, 0x40]),
+        Some(&[0xe2, 0x5a]),
+        None,
+        Some(&[0xba, 0x6f]),
+        Some(&[0xe2, 0x51]),
+        Some(&[0xe2, 0x61]),
+        Some(&[0xba, 0x6d]),
+        Some(&[0xe2, 0x49]),
+        Some(&[0xba, 0x5e]),
+        Some(&[0xe2, 0x4b]),
+        Some(&[0xe2, 0x59]),
+        Some(&[0xba, 0x67]),
+        Some(&[0xe2, 0x44]),
+        Some(&[0xba, 0x6b]),
+        Some(&[0xba, 0x61]),
+        Some(&[0xe2, 0x4d]),
+        Some(&[0xe2, 0x43]),
+        Some(&[0xe1, 0xfc]),
+        None,
+        Some(&[0xe2, 0x57]),
+        Some(&[0xba, 0x68]),
+        Some(&[0xe2, 0x60]),
+        Some(&[0xe1, 0xfd]),
+        Some(&[0xba, 0x65]),
+        None,
+        Some(&[0xe2, 0x53]),
+        None,
+        Some(&[0xba, 0x66]),
+        Some(&[0xe2, 0x45]),
+        Some(&[0xe2, 0x50]),
+        Some(&[0xe2, 0x4c]),
+        Some(&[0xe2, 0x4e]),
+        None,
+        Some(&[0xba, 0x60]),
+        Some(&[0xe2, 0x5f]),
+        Some(&[0xba, 0x6e]),
+        Some(&[0xe2, 0x4f]),
+        None,
+        Some(&[0xe2, 0x62]),
+        None,
+        None,
+        Some(&[0xe1, 0xfe]),
+        Some(&[0xe2, 0x54]),
+        Some(&[0xba, 0x63]),
+        Some(&[0xba, 0x6c]),
+        Some(&[0xba, 0x6a]),
+        Some(&[0xe2, 0x41]),
+        Some(&[0xe2, 0x56]),
+        Some(&[0xba, 0x69]),
+        None,
+        None,
+        Some(&[0xba, 0x62]),
+        Some(&[0xe2, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe5, 0xd5]),
+        None,
+        Some(&[0xe5, 0xd1]),
+        Some(&[0xe5, 0xcd]),
+        Some(&[0xe5, 0xe1]),
+        Some(&[0xe5, 0xde]),
+        Some(&[0xbc, 0xcd]),
+        None,
+        None,
+        Some(&[0xe5, 0xe5]),
+        Some(&[0xe5, 0xd4]),
+        Some(&[0xbc, 0xd8]),
+        Some(&[0xe5, 0xdb]),
+        None,
+        None,
+        Some(&[0xe5, 0xd0]),
+        Some(&[0xe5, 0xda]),
+        Some(&[0xbc, 0xd5]),
+        Some(&[0xe5, 0xee]),
+        None,
+        Some(&[0xe5, 0xeb]),
+        Some(&[0xe5, 0xdd]),
+        Some(&[0xe5, 0xce]),
+        None,
+        None,
+        Some(&[0xe5, 0xe2]),
+        Some(&[0xe5, 0xe4]),
+        Some(&[0xbc, 0xd1]),
+        Some(&[0xe5, 0xd8]),
+        Some(&[0xe5, 0xd3]),
+        Some(&[0xe5, 0xca]),
+        Some(&[0xbc, 0xce]),
+        Some(&[0xbc, 0xd6]),
+        None,
+        Some(&[0xe5, 0xe7]),
+        Some(&[0xbc, 0xd7]),
+        Some(&[0xe5, 0xcb]),
+        Some(&[0xe5, 0xed]),
+        Some(&[0xe5, 0xe0]),
+        Some(&[0xe5, 0xe6]),
+        Some(&[0xbc, 0xd4]),
+        None,
+        None,
+        Some(&[0xe5, 0xe3]),
+        None,
+        Some(&[0xe5, 0xea]),
+        None,
+        Some(&[0xbc, 0xd9]),
+        None,
+        Some(&[0xbc, 0xd3]),
+        Some(&[0xe5, 0xdc]),
+        Some(&[0xe5, 0xcf]),
+        Some(&[0xe5, 0xef]),
+        Some(&[0xe5, 0xcc]),
+        Some(&[0xe5, 0xe8]),
+        Some(&[0xbc, 0xd0]),
+        None,
+        Some(&[0xe5, 0xd6]),
+        None,
+        Some(&[0xe5, 0xd7]),
+        Some(&[0xbc, 0xcf]),
+        Some(&[0xbc, 0xcc]),
+        Some(&[0xe5, 0xd2]),
+        Some(&[0xbc, 0xd2]),
+        None,
+        Some(&[0xbc, 0xcb]),
+        None,
+        Some(&[0xe5, 0xe9]),
+        Some(&[0xe5, 0xec]),
+        Some(&[0xe5, 0xd9]),
+        Some(&[0xe9, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc2]),
+        None,
+        Some(&[0xe9, 0xbe]),
+        Some(&[0xbe, 0xf6]),
+        None,
+        None,
+        Some(&[0xbe, 0xeb]),
+        Some(&[0xbe, 0xf0]),
+        Some(&[0xbe, 0xec]),
+        Some(&[0xe9, 0xcc]),
+        Some(&[0xe9, 0xd7]),
+        Some(&[0xbe, 0xea]),
+        Some(&[0xe9, 0xc4]),
+        Some(&[0xe9, 0xcd]),
+        Some(&[0xe5, 0xdf]),
+        Some(&[0xe9, 0xce]),
+        None,
+        None,
+        Some(&[0xbe, 0xf1]),
+        None,
+        Some(&[0xe9, 0xdd]),
+        Some(&[0xbe, 0xf5]),
+        Some(&[0xbe, 0xf8]),
+        Some(&[0xe9, 0xc0]),
+        None,
+        Some(&[0xbe, 0xf4]),
+        None,
+        Some(&[0xe9, 0xdb]),
+        Some(&[0xe9, 0xdc]),
+        Some(&[0xe9, 0xd2]),
+        Some(&[0xe9, 0xd1]),
+        Some(&[0xe9, 0xc9]),
+        None,
+        None,
+        Some(&[0xe9, 0xd3]),
+        Some(&[0xe9, 0xda]),
+        Some(&[0xe9, 0xd9]),
+        None,
+        Some(&[0xbe, 0xef]),
+        Some(&[0xbe, 0xed]),
+        Some(&[0xe9, 0xcb]),
+        Some(&[0xe9, 0xc8]),
+        None,
+        Some(&[0xe9, 0xc5]),
+        Some(&[0xe9, 0xd8]),
+        Some(&[0xbe, 0xf7]),
+        Some(&[0xe9, 0xd6]),
+        Some(&[0xbe, 0xf3]),
+        Some(&[0xbe, 0xf2]),
+        None,
+        Some(&[0xe9, 0xd0]),
+        None,
+        Some(&[0xe9, 0xbf]),
+        Some(&[0xe9, 0xc1]),
+        Some(&[0xe9, 0xc3]),
+        Some(&[0xe9, 0xd5]),
+        Some(&[0xe9, 0xcf]),
+        Some(&[0xbe, 0xee]),
+        None,
+        Some(&[0xe9, 0xc6]),
+        None,
+        Some(&[0xe9, 0xd4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xc7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xcf]),
+        Some(&[0xed, 0x45]),
+        Some(&[0xc0, 0xc8]),
+        Some(&[0xec, 0xf5]),
+        None,
+        Some(&[0xed, 0x41]),
+        Some(&[0xc0, 0xca]),
+        Some(&[0xed, 0x48]),
+        None,
+        Some(&[0xec, 0xfc]),
+        None,
+        Some(&[0xec, 0xf7]),
+        None,
+        None,
+        Some(&[0xed, 0x49]),
+        Some(&[0xec, 0xf3]),
+        Some(&[0xec, 0xfe]),
+        None,
+        Some(&[0xc0, 0xd1]),
+        Some(&[0xed, 0x44]),
+        Some(&[0xed, 0x4a]),
+        Some(&[0xec, 0xfd]),
+        Some(&[0xc0, 0xc9]),
+        Some(&[0xed, 0x40]),
+        Some(&[0xec, 0xf4]),
+        Some(&[0xc0, 0xd0]),
+        None,
+        None,
+        Some(&[0xed, 0x47]),
+        Some(&[0xec, 0xf9]),
+        Some(&[0xc0, 0xcc]),
+        None,
+        Some(&[0xec, 0xfb]),
+        Some(&[0xec, 0xf8]),
+        Some(&[0xc0, 0xd2]),
+        Some(&[0xec, 0xfa]),
+        Some(&[0xc0, 0xcb]),
+        Some(&[0xc0, 0xce]),
+        Some(&[0xed, 0x43]),
+        Some(&[0xec, 0xf6]),
+        Some(&[0xed, 0x46]),
+        None,
+        Some(&[0xed, 0x42]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x63]),
+        Some(&[0xef, 0xe7]),
+        Some(&[0xc2, 0x68]),
+        Some(&[0xc2, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x62]),
+        Some(&[0xef, 0xe6]),
+        None,
+        Some(&[0xef, 0xe3]),
+        Some(&[0xef, 0xe4]),
+        Some(&[0xc2, 0x66]),
+        Some(&[0xef, 0xde]),
+        Some(&[0xef, 0xe2]),
+        Some(&[0xc2, 0x65]),
+        None,
+        Some(&[0xef, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x67]),
+        Some(&[0xc2, 0x64]),
+        None,
+        Some(&[0xef, 0xdd]),
+        Some(&[0xef, 0xe1]),
+        Some(&[0xef, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0x51]),
+        Some(&[0xf2, 0x4e]),
+        Some(&[0xf2, 0x57]),
+        None,
+        Some(&[0xf2, 0x56]),
+        Some(&[0xf2, 0x54]),
+        Some(&[0xf2, 0x4f]),
+        None,
+        Some(&[0xc3, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0x50]),
+        Some(&[0xc3, 0x71]),
+        Some(&[0xc0, 0xcd]),
+        Some(&[0xf2, 0x53]),
+        Some(&[0xc3, 0x70]),
+        Some(&[0xf2, 0x58]),
+        Some(&[0xf2, 0x52]),
+        Some(&[0xf2, 0x4d]),
+        Some(&[0xef, 0xe0]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0x6f]),
+        None,
+        Some(&[0xf2, 0x4c]),
+        Some(&[0xf4, 0x56]),
+        None,
+        Some(&[0xf4, 0x55]),
+        Some(&[0xf2, 0x55]),
+        Some(&[0xc4, 0x68]),
+        None,
+        Some(&[0xf4, 0x59]),
+        Some(&[0xf4, 0x5a]),
+        Some(&[0xf4, 0x54]),
+        Some(&[0xf4, 0x58]),
+        None,
+        Some(&[0xf4, 0x53]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd1]),
+        Some(&[0xf4, 0x57]),
+        Some(&[0xc4, 0xe7]),
+        Some(&[0xc4, 0xe5]),
+        Some(&[0xf5, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xd2]),
+        None,
+        Some(&[0xf5, 0xce]),
+        Some(&[0xf5, 0xd0]),
+        Some(&[0xc4, 0xe6]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xe5]),
+        Some(&[0xf6, 0xe6]),
+        Some(&[0xc5, 0x76]),
+        Some(&[0xf6, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xf7, 0xe2]),
+        Some(&[0xc5, 0xcf]),
+        Some(&[0xf7, 0xe0]),
+        Some(&[0xf7, 0xe1]),
+        Some(&[0xf8, 0xac]),
+        None,
+        None,
+        Some(&[0xc6, 0x56]),
+        Some(&[0xf8, 0xf3]),
+        Some(&[0xf8, 0xf1]),
+        Some(&[0xf8, 0xf2]),
+        Some(&[0xf8, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xf9, 0xbb]),
+        None,
+        Some(&[0xa4, 0xed]),
+        Some(&[0xa6, 0xb8]),
+        None,
+        Some(&[0xaa, 0x59]),
+        None,
+        Some(&[0xcc, 0xe9]),
+        None,
+        None,
+        Some(&[0xcf, 0x64]),
+        None,
+        None,
+        None,
+        Some(&[0xd1, 0xf5]),
+        Some(&[0xd1, 0xf7]),
+        None,
+        Some(&[0xd1, 0xf6]),
+        None,
+        Some(&[0xd1, 0xf8]),
+        Some(&[0xb1, 0xfd]),
+        Some(&[0xd5, 0xd7]),
+        Some(&[0xd1, 0xf9]),
+        None,
+        Some(&[0xd5, 0xd6]),
+        Some(&[0xd5, 0xd8]),
+        Some(&[0xd5, 0xd9]),
+        Some(&[0xd9, 0xda]),
+        Some(&[0xb4, 0xdb]),
+        Some(&[0xd9, 0xdb]),
+        Some(&[0xd9, 0xdd]),
+        Some(&[0xb4, 0xdc]),
+        Some(&[0xb4, 0xda]),
+        Some(&[0xd9, 0xdc]),
+        None,
+        Some(&[0xdd, 0xfa]),
+        Some(&[0xdd, 0xf8]),
+        Some(&[0xdd, 0xf7]),
+        None,
+        Some(&[0xdd, 0xf6]),
+        Some(&[0xdd, 0xf5]),
+        Some(&[0xb7, 0xb2]),
+        Some(&[0xdd, 0xf9]),
+        Some(&[0xba, 0x70]),
+        Some(&[0xe2, 0x63]),
+        Some(&[0xe2, 0x65]),
+        Some(&[0xba, 0x71]),
+        Some(&[0xe2, 0x64]),
+        Some(&[0xbc, 0xdb]),
+        None,
+        Some(&[0xbc, 0xda]),
+        Some(&[0xe5, 0xf0]),
+        None,
+        None,
+        Some(&[0xe9, 0xdf]),
+        Some(&[0xe9, 0xde]),
+        Some(&[0xe9, 0xe0]),
+        None,
+        None,
+        Some(&[0xbe, 0xf9]),
+        None,
+        Some(&[0xed, 0x4b]),
+        Some(&[0xc0, 0xd3]),
+        None,
+        Some(&[0xef, 0xe8]),
+        Some(&[0xc2, 0x6a]),
+        Some(&[0xf2, 0x59]),
+        Some(&[0xc5, 0x77]),
+        Some(&[0xa4, 0xee]),
+        Some(&[0xa5, 0xbf]),
+        Some(&[0xa6, 0xb9]),
+        Some(&[0xa8, 0x42]),
+        Some(&[0xaa, 0x5a]),
+        Some(&[0xaa, 0x5b]),
+        None,
+        None,
+        Some(&[0xac, 0x6e]),
+        None,
+        None,
+        Some(&[0xd1, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd1]),
+        Some(&[0xbe, 0xfa]),
+        Some(&[0xc2, 0x6b]),
+        Some(&[0xa4, 0xef]),
+        None,
+        Some(&[0xa6, 0xba]),
+        None,
+        None,
+        Some(&[0xcc, 0xeb]),
+        Some(&[0xaa, 0x5c]),
+        Some(&[0xcc, 0xea]),
+        None,
+        Some(&[0xcf, 0x65]),
+        Some(&[0xac, 0x6f]),
+        Some(&[0xcf, 0x66]),
+        None,
+        Some(&[0xac, 0x70]),
+        None,
+        Some(&[0xd1, 0xfc]),
+        Some(&[0xae, 0xee]),
+        Some(&[0xae, 0xed]),
+        None,
+        Some(&[0xd5, 0xde]),
+        Some(&[0xd5, 0xdc]),
+        Some(&[0xd5, 0xdd]),
+        Some(&[0xd5, 0xdb]),
+        None,
+        Some(&[0xd5, 0xda]),
+        None,
+        None,
+        Some(&[0xd9, 0xde]),
+        Some(&[0xd9, 0xe1]),
+        Some(&[0xb4, 0xde]),
+        Some(&[0xd9, 0xdf]),
+        Some(&[0xb4, 0xdd]),
+        Some(&[0xd9, 0xe0]),
+        None,
+        Some(&[0xdd, 0xfb]),
+        None,
+        None,
+        Some(&[0xe2, 0x66]),
+        Some(&[0xe2, 0x67]),
+        Some(&[0xe2, 0x68]),
+        None,
+        Some(&[0xe5, 0xf3]),
+        Some(&[0xe5, 0xf2]),
+        Some(&[0xbc, 0xdc]),
+        Some(&[0xe5, 0xf1]),
+        Some(&[0xe5, 0xf4]),
+        Some(&[0xe9, 0xe1]),
+        None,
+        None,
+        Some(&[0xe9, 0xe2]),
+        Some(&[0xe9, 0xe3]),
+        None,
+        Some(&[0xed, 0x4c]),
+        Some(&[0xc0, 0xd4]),
+        Some(&[0xc2, 0x6c]),
+        Some(&[0xf2, 0x5a]),
+        None,
+        Some(&[0xc4, 0xe8]),
+        Some(&[0xc9, 0x5f]),
+        None,
+        Some(&[0xac, 0x71]),
+        Some(&[0xcf, 0x67]),
+        Some(&[0xae, 0xef]),
+        None,
+        None,
+        Some(&[0xb1, 0xfe]),
+        None,
+        Some(&[0xb4, 0xdf]),
+        Some(&[0xd9, 0xe2]),
+        None,
+        Some(&[0xb7, 0xb5]),
+        Some(&[0xb7, 0xb4]),
+        None,
+        None,
+        Some(&[0xe2, 0x69]),
+        Some(&[0xe2, 0x6a]),
+        Some(&[0xbc, 0xdd]),
+        Some(&[0xbc, 0xde]),
+        Some(&[0xe9, 0xe5]),
+        Some(&[0xe9, 0xe4]),
+        Some(&[0xef, 0xe9]),
+        Some(&[0xf7, 0xe3]),
+        Some(&[0xa4, 0xf0]),
+        Some(&[0xc9, 0x60]),
+        Some(&[0xa5, 0xc0]),
+        None,
+        Some(&[0xa8, 0x43]),
+        Some(&[0xcb, 0x48]),
+        None,
+        Some(&[0xac, 0x72]),
+        Some(&[0xb7, 0xb6]),
+        Some(&[0xa4, 0xf1]),
+        None,
+        Some(&[0xcf, 0x68]),
+        Some(&[0xac, 0x73]),
+        Some(&[0xcf, 0x69]),
+        None,
+        Some(&[0xc0, 0xd5]),
+        Some(&[0xa4, 0xf2]),
+        None,
+        None,
+        Some(&[0xcc, 0xec]),
+        None,
+        Some(&[0xcf, 0x6a]),
+        None,
+        Some(&[0xd2, 0x42]),
+        Some(&[0xd2, 0x41]),
+        Some(&[0xd1, 0xfe]),
+        None,
+        Some(&[0xd1, 0xfd]),
+        Some(&[0xd2, 0x43]),
+        Some(&[0xd2, 0x40]),
+        None,
+        None,
+        Some(&[0xb2, 0x40]),
+        Some(&[0xb2, 0x41]),
+        None,
+        None,
+        Some(&[0xb4, 0xe0]),
+        Some(&[0xd9, 0xe3]),
+        None,
+        Some(&[0xd9, 0xe4]),
+        Some(&[0xd9, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0x41]),
+        Some(&[0xde, 0x42]),
+        Some(&[0xde, 0x40]),
+        None,
+        Some(&[0xdd, 0xfd]),
+        Some(&[0xdd, 0xfe]),
+        Some(&[0xb7, 0xb7]),
+        Some(&[0xe2, 0x6b]),
+        Some(&[0xe5, 0xf7]),
+        Some(&[0xe5, 0xf6]),
+        Some(&[0xe5, 0xf5]),
+        Some(&[0xe5, 0xf8]),
+        Some(&[0xe9, 0xe7]),
+        Some(&[0xe9, 0xe6]),
+        Some(&[0xbe, 0xfb]),
+        Some(&[0xe9, 0xe8]),
+        None,
+        Some(&[0xc0, 0xd6]),
+        Some(&[0xed, 0x4d]),
+        None,
+        Some(&[0xef, 0xea]),
+        Some(&[0xf2, 0x5b]),
+        Some(&[0xf6, 0xe7]),
+        None,
+        Some(&[0xa4, 0xf3]),
+        Some(&[0xa5, 0xc2]),
+        Some(&[0xa5, 0xc1]),
+        None,
+        Some(&[0xaa, 0x5d]),
+        Some(&[0xc9, 0x61]),
+        Some(&[0xc9, 0x7e]),
+        Some(&[0xa6, 0xbb]),
+        None,
+        Some(&[0xc9, 0xf7]),
+        Some(&[0xcb, 0x49]),
+        Some(&[0xcb, 0x4a]),
+        Some(&[0xaa, 0x5e]),
+        None,
+        Some(&[0xcc, 0xed]),
+        None,
+        Some(&[0xac, 0x74]),
+        Some(&[0xcf, 0x6b]),
+        Some(&[0xcf, 0x6c]),
+        None,
+        Some(&[0xae, 0xf0]),
+        Some(&[0xae, 0xf4]),
+        Some(&[0xd2, 0x44]),
+        Some(&[0xae, 0xf3]),
+        Some(&[0xae, 0xf1]),
+        Some(&[0xae, 0xf2]),
+        None,
+        Some(&[0xd5, 0xdf]),
+        Some(&[0xb2, 0x42]),
+        Some(&[0xb4, 0xe3]),
+        None,
+        Some(&[0xb4, 0xe1]),
+        Some(&[0xb4, 0xe2]),
+        Some(&[0xd9, 0xe6]),
+        None,
+        None,
+        Some(&[0xba, 0x72]),
+        Some(&[0xa4, 0xf4]),
+        None,
+        Some(&[0xc9, 0xa1]),
+        None,
+        Some(&[0xa5, 0xc3]),
+        None,
+        None,
+        Some(&[0xc9, 0xa4]),
+        None,
+        None,
+        Some(&[0xa5, 0xc6]),
+        Some(&[0xc9, 0xa3]),
+        Some(&[0xa5, 0xc5]),
+        Some(&[0xa5, 0xc4]),
+        Some(&[0xa8, 0x44]),
+        Some(&[0xc9, 0xa2]),
+        None,
+        None,
+        Some(&[0xc9, 0xf8]),
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0xfc]),
+        Some(&[0xc9, 0xfe]),
+        Some(&[0xca, 0x40]),
+        Some(&[0xa6, 0xc5]),
+        Some(&[0xa6, 0xc6]),
+        Some(&[0xc9, 0xfb]),
+        Some(&[0xa6, 0xc1]),
+        None,
+        Some(&[0xc9, 0xf9]),
+        None,
+        Some(&[0xc9, 0xfd]),
+        Some(&[0xa6, 0xc2]),
+        None,
+        Some(&[0xa6, 0xbd]),
+        None,
+        Some(&[0xa6, 0xbe]),
+        None,
+        Some(&[0xa6, 0xc4]),
+        Some(&[0xc9, 0xfa]),
+        Some(&[0xa6, 0xbc]),
+        Some(&[0xa8, 0x45]),
+        Some(&[0xa6, 0xbf]),
+        Some(&[0xa6, 0xc0]),
+        Some(&[0xa6, 0xc3]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0x5b]),
+        Some(&[0xcb, 0x59]),
+        Some(&[0xcb, 0x4c]),
+        Some(&[0xa8, 0x51]),
+        Some(&[0xcb, 0x53]),
+        Some(&[0xa8, 0x4c]),
+        Some(&[0xcb, 0x4d]),
+        None,
+        Some(&[0xcb, 0x55]),
+        None,
+        Some(&[0xcb, 0x52]),
+        Some(&[0xa8, 0x4f]),
+        Some(&[0xcb, 0x51]),
+        Some(&[0xa8, 0x56]),
+        Some(&[0xcb, 0x5a]),
+        Some(&[0xa8, 0x58]),
+        None,
+        Some(&[0xa8, 0x5a]),
+        None,
+        Some(&[0xcb, 0x4b]),
+        None,
+        Some(&[0xa8, 0x4d]),
+        Some(&[0xcb, 0x5c]),
+        None,
+        Some(&[0xa8, 0x54]),
+        Some(&[0xa8, 0x57]),
+        None,
+        Some(&[0xcd, 0x45]),
+        Some(&[0xa8, 0x47]),
+        Some(&[0xa8, 0x5e]),
+        Some(&[0xa8, 0x55]),
+        Some(&[0xcb, 0x4e]),
+        Some(&[0xa8, 0x4a]),
+        Some(&[0xa8, 0x59]),
+        Some(&[0xcb, 0x56]),
+        Some(&[0xa8, 0x48]),
+        Some(&[0xa8, 0x49]),
+        Some(&[0xcd, 0x43]),
+        Some(&[0xcb, 0x4f]),
+        Some(&[0xa8, 0x50]),
+        Some(&[0xa8, 0x5b]),
+        Some(&[0xcb, 0x5d]),
+        Some(&[0xcb, 0x50]),
+        Some(&[0xa8, 0x4e]),
+        None,
+        Some(&[0xa8, 0x53]),
+        Some(&[0xcc, 0xee]),
+        Some(&[0xa8, 0x5c]),
+        Some(&[0xcb, 0x57]),
+        Some(&[0xa8, 0x52]),
+        None,
+        Some(&[0xa8, 0x5d]),
+        Some(&[0xa8, 0x46]),
+        Some(&[0xcb, 0x54]),
+        Some(&[0xa8, 0x4b]),
+        Some(&[0xcb, 0x58]),
+        Some(&[0xcd, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0x6a]),
+        Some(&[0xaa, 0x7a]),
+        Some(&[0xcc, 0xf5]),
+        Some(&[0xaa, 0x71]),
+        None,
+        Some(&[0xcd, 0x4b]),
+        Some(&[0xaa, 0x62]),
+        None,
+        Some(&[0xaa, 0x65]),
+        Some(&[0xcd, 0x42]),
+        None,
+        Some(&[0xcc, 0xf3]),
+        Some(&[0xcc, 0xf7]),
+        Some(&[0xaa, 0x6d]),
+        Some(&[0xaa, 0x6f]),
+        Some(&[0xcc, 0xfa]),
+        Some(&[0xaa, 0x76]),
+        Some(&[0xaa, 0x68]),
+        Some(&[0xaa, 0x66]),
+        Some(&[0xaa, 0x67]),
+        Some(&[0xaa, 0x75]),
+        Some(&[0xcd, 0x47]),
+        Some(&[0xaa, 0x70]),
+        Some(&[0xcc, 0xf9]),
+        Some(&[0xcc, 0xfb]),
+        Some(&[0xaa, 0x6e]),
+        Some(&[0xaa, 0x73]),
+        Some(&[0xcc, 0xfc]),
+        Some(&[0xcd, 0x4a]),
+        None,
+        Some(&[0xac, 0x75]),
+        Some(&[0xaa, 0x79]),
+        None,
+        Some(&[0xaa, 0x63]),
+        Some(&[0xcd, 0x49]),
+        None,
+        Some(&[0xcd, 0x4d]),
+        Some(&[0xcc, 0xf8]),
+        Some(&[0xcd, 0x4f]),
+        Some(&[0xcd, 0x40]),
+        Some(&[0xaa, 0x6c]),
+        Some(&[0xcc, 0xf4]),
+        Some(&[0xaa, 0x6b]),
+        Some(&[0xaa, 0x7d]),
+        Some(&[0xaa, 0x72]),
+        None,
+        Some(&[0xcc, 0xf2]),
+        Some(&[0xcf, 0x75]),
+        Some(&[0xaa, 0x78]),
+        Some(&[0xaa, 0x7c]),
+        Some(&[0xcd, 0x41]),
+        Some(&[0xcd, 0x46]),
+        None,
+        Some(&[0xaa, 0x7e]),
+        Some(&[0xaa, 0x77]),
+        Some(&[0xaa, 0x69]),
+        Some(&[0xaa, 0x5f]),
+        None,
+        Some(&[0xaa, 0x64]),
+        None,
+        Some(&[0xcc, 0xf6]),
+        Some(&[0xaa, 0x60]),
+        Some(&[0xcd, 0x4e]),
+        None,
+        Some(&[0xcc, 0xf0]),
+        Some(&[0xcc, 0xef]),
+        Some(&[0xcc, 0xfd]),
+        Some(&[0xcc, 0xf1]),
+        Some(&[0xaa, 0x7b]),
+        Some(&[0xae, 0xf5]),
+        Some(&[0xaa, 0x74]),
+        Some(&[0xcc, 0xfe]),
+        Some(&[0xaa, 0x61]),
+        None,
+        Some(&[0xac, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0x4c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0x7c]),
+        Some(&[0xcf, 0xa1]),
+        None,
+        Some(&[0xcf, 0xa4]),
+        Some(&[0xcf, 0x77]),
+        None,
+        None,
+        Some(&[0xcf, 0xa7]),
+        Some(&[0xcf, 0xaa]),
+        Some(&[0xcf, 0xac]),
+        Some(&[0xcf, 0x74]),
+        Some(&[0xac, 0x76]),
+        Some(&[0xac, 0x7b]),
+        Some(&[0xd2, 0x49]),
+        Some(&[0xac, 0xad]),
+        Some(&[0xcf, 0xa5]),
+        Some(&[0xcf, 0xad]),
+        Some(&[0xcf, 0x7b]),
+        Some(&[0xcf, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0x64]),
+        Some(&[0xac, 0x7e]),
+        Some(&[0xcf, 0xa2]),
+        Some(&[0xcf, 0x78]),
+        Some(&[0xcf, 0x7a]),
+        Some(&[0xac, 0xa5]),
+        None,
+        Some(&[0xcf, 0x7d]),
+        Some(&[0xac, 0x7d]),
+        Some(&[0xcf, 0x70]),
+        Some(&[0xcf, 0xa8]),
+        None,
+        Some(&[0xcf, 0xab]),
+        None,
+        None,
+        Some(&[0xac, 0x7a]),
+        None,
+        Some(&[0xac, 0xa8]),
+        Some(&[0xcf, 0x6d]),
+        Some(&[0xac, 0xaa]),
+        Some(&[0xac, 0x78]),
+        Some(&[0xac, 0xae]),
+        Some(&[0xcf, 0xa9]),
+        Some(&[0xcf, 0x6f]),
+        Some(&[0xac, 0xab]),
+        Some(&[0xd2, 0x5e]),
+        Some(&[0xcd, 0x48]),
+        Some(&[0xac, 0x7c]),
+        Some(&[0xac, 0x77]),
+        Some(&[0xcf, 0x76]),
+        Some(&[0xcf, 0x6e]),
+        Some(&[0xac, 0xac]),
+        Some(&[0xac, 0xa4]),
+        Some(&[0xcf, 0xa3]),
+        Some(&[0xac, 0xa9]),
+        Some(&[0xac, 0xa7]),
+        Some(&[0xcf, 0x79]),
+        Some(&[0xac, 0xa1]),
+        Some(&[0xcf, 0x71]),
+        Some(&[0xac, 0xa2]),
+        Some(&[0xac, 0xa3]),
+        Some(&[0xcf, 0x72]),
+        Some(&[0xcf, 0xa6]),
+        Some(&[0xac, 0x79]),
+        Some(&[0xcf, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0x4c]),
+        Some(&[0xae, 0xfd]),
+        Some(&[0xaf, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0x55]),
+        Some(&[0xd2, 0x5b]),
+        Some(&[0xd2, 0x57]),
+        Some(&[0xd2, 0x4a]),
+        Some(&[0xd2, 0x4d]),
+        Some(&[0xd2, 0x46]),
+        Some(&[0xd2, 0x47]),
+        Some(&[0xaf, 0x4a]),
+        Some(&[0xae, 0xfa]),
+        Some(&[0xd2, 0x56]),
+        Some(&[0xd2, 0x5f]),
+        Some(&[0xaf, 0x45]),
+        Some(&[0xae, 0xf6]),
+        None,
+        Some(&[0xaf, 0x40]),
+        Some(&[0xd2, 0x4e]),
+        Some(&[0xaf, 0x42]),
+        Some(&[0xd2, 0x4f]),
+        Some(&[0xd2, 0x59]),
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0x44]),
+        Some(&[0xd2, 0x68]),
+        Some(&[0xd2, 0x48]),
+        Some(&[0xae, 0xfc]),
+        Some(&[0xae, 0xfb]),
+        Some(&[0xaf, 0x48]),
+        Some(&[0xd2, 0x45]),
+        Some(&[0xd2, 0x66]),
+        Some(&[0xd2, 0x5a]),
+        Some(&[0xd2, 0x67]),
+        Some(&[0xd2, 0x61]),
+        Some(&[0xd2, 0x53]),
+        Some(&[0xd2, 0x62]),
+        None,
+        Some(&[0xd2, 0x5c]),
+        Some(&[0xd2, 0x65]),
+        Some(&[0xd2, 0x63]),
+        Some(&[0xaf, 0x49]),
+        Some(&[0xd2, 0x54]),
+        Some(&[0xae, 0xf9]),
+        Some(&[0xae, 0xf8]),
+        Some(&[0xaf, 0x41]),
+        Some(&[0xaf, 0x47]),
+        Some(&[0xd2, 0x60]),
+        Some(&[0xaf, 0x46]),
+        Some(&[0xd2, 0x51]),
+        Some(&[0xb2, 0x43]),
+        None,
+        Some(&[0xd2, 0x69]),
+        Some(&[0xd2, 0x50]),
+        Some(&[0xd2, 0x4b]),
+        Some(&[0xae, 0xfe]),
+        Some(&[0xaf, 0x4b]),
+        Some(&[0xae, 0xf7]),
+        None,
+        Some(&[0xd2, 0x58]),
+        Some(&[0xd2, 0x5d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0x65]),
+        Some(&[0xd5, 0xe1]),
+        Some(&[0xd5, 0xe5]),
+        None,
+        Some(&[0xb2, 0x52]),
+        Some(&[0xb2, 0x50]),
+        None,
+        None,
+        Some(&[0xb2, 0x47]),
+        Some(&[0xd5, 0xe3]),
+        Some(&[0xd5, 0xe2]),
+        Some(&[0xb2, 0x5b]),
+        None,
+        Some(&[0xd5, 0xe8]),
+        Some(&[0xb2, 0x55]),
+        None,
+        Some(&[0xd5, 0xfa]),
+        Some(&[0xd6, 0x47]),
+        Some(&[0xb2, 0x44]),
+        Some(&[0xd5, 0xf7]),
+        Some(&[0xd5, 0xf0]),
+        Some(&[0xb2, 0x67]),
+        Some(&[0xd5, 0xe0]),
+        None,
+        Some(&[0xd5, 0xfc]),
+        None,
+        Some(&[0xb2, 0x64]),
+        Some(&[0xb2, 0x58]),
+        Some(&[0xb2, 0x63]),
+        Some(&[0xb2, 0x4e]),
+        Some(&[0xd5, 0xec]),
+        Some(&[0xd5, 0xfe]),
+        Some(&[0xd5, 0xf6]),
+        Some(&[0xb2, 0x4f]),
+        Some(&[0xb2, 0x49]),
+        Some(&[0xd6, 0x45]),
+        None,
+        Some(&[0xd5, 0xfd]),
+        Some(&[0xd6, 0x40]),
+        Some(&[0xb2, 0x51]),
+        Some(&[0xb2, 0x59]),
+        Some(&[0xd6, 0x42]),
+        Some(&[0xd5, 0xea]),
+        Some(&[0xd5, 0xfb]),
+        Some(&[0xd5, 0xef]),
+        Some(&[0xd6, 0x44]),
+        Some(&[0xb2, 0x5e]),
+        Some(&[0xb2, 0x46]),
+        Some(&[0xb2, 0x5c]),
+        Some(&[0xd5, 0xf4]),
+        Some(&[0xd5, 0xf2]),
+        Some(&[0xd5, 0xf3]),
+        Some(&[0xb2, 0x53]),
+        Some(&[0xd5, 0xee]),
+        Some(&[0xd5, 0xed]),
+        Some(&[0xb2, 0x48]),
+        Some(&[0xd5, 0xe7]),
+        Some(&[0xd6, 0x46]),
+        Some(&[0xb2, 0x4a]),
+        Some(&[0xd5, 0xf1]),
+        Some(&[0xb2, 0x68]),
+        None,
+        Some(&[0xb2, 0x62]),
+        Some(&[0xd5, 0xe6]),
+        Some(&[0xb2, 0x5f]),
+        Some(&[0xb2, 0x5d]),
+        Some(&[0xb2, 0x66]),
+        Some(&[0xd5, 0xf8]),
+        Some(&[0xb2, 0x61]),
+        Some(&[0xd2, 0x52]),
+        Some(&[0xd5, 0xf9]),
+        Some(&[0xb2, 0x60]),
+        Some(&[0xd6, 0x41]),
+        Some(&[0xb2, 0x45]),
+        Some(&[0xd5, 0xf5]),
+        Some(&[0xb2, 0x57]),
+        Some(&[0xd5, 0xe9]),
+        Some(&[0xb2, 0x56]),
+        None,
+        Some(&[0xb2, 0x54]),
+        Some(&[0xb2, 0x4c]),
+        Some(&[0xb2, 0x4b]),
+        Some(&[0xd9, 0xe7]),
+        Some(&[0xd6, 0x43]),
+        None,
+        None,
+        Some(&[0xd5, 0xeb]),
+        None,
+        None,
+        Some(&[0xd9, 0xfc]),
+        None,
+        Some(&[0xb2, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x41]),
+        Some(&[0xb2, 0x5a]),
+        Some(&[0xb4, 0xee]),
+        Some(&[0xd9, 0xf6]),
+        Some(&[0xb4, 0xfc]),
+        None,
+        Some(&[0xd9, 0xea]),
+        Some(&[0xb4, 0xeb]),
+        Some(&[0xb4, 0xe7]),
+        Some(&[0xda, 0x49]),
+        Some(&[0xb4, 0xed]),
+        Some(&[0xb4, 0xf1]),
+        Some(&[0xb4, 0xec]),
+        Some(&[0xb4, 0xf5]),
+        Some(&[0xda, 0x4d]),
+        Some(&[0xda, 0x44]),
+        None,
+        None,
+        Some(&[0xd9, 0xf1]),
+        Some(&[0xb4, 0xfa]),
+        Some(&[0xb4, 0xf4]),
+        Some(&[0xd9, 0xfd]),
+        Some(&[0xb4, 0xe4]),
+        Some(&[0xda, 0x4a]),
+        Some(&[0xda, 0x43]),
+        Some(&[0xb4, 0xe8]),
+        Some(&[0xd9, 0xf7]),
+        Some(&[0xb4, 0xf7]),
+        Some(&[0xda, 0x55]),
+        Some(&[0xda, 0x56]),
+        None,
+        Some(&[0xb4, 0xe5]),
+        Some(&[0xda, 0x48]),
+        Some(&[0xb4, 0xf9]),
+        Some(&[0xd9, 0xfb]),
+        Some(&[0xd9, 0xed]),
+        Some(&[0xd9, 0xee]),
+        Some(&[0xb4, 0xfd]),
+        Some(&[0xd9, 0xf2]),
+        Some(&[0xd9, 0xf9]),
+        Some(&[0xd9, 0xf3]),
+        None,
+        Some(&[0xb4, 0xfb]),
+        Some(&[0xb5, 0x44]),
+        Some(&[0xd9, 0xef]),
+        Some(&[0xd9, 0xe8]),
+        Some(&[0xd9, 0xe9]),
+        None,
+        Some(&[0xd9, 0xeb]),
+        Some(&[0xb4, 0xea]),
+        Some(&[0xd9, 0xf8]),
+        None,
+        Some(&[0xb4, 0xf8]),
+        Some(&[0xb5, 0x42]),
+        None,
+        None,
+        Some(&[0xd9, 0xfa]),
+        Some(&[0xda, 0x53]),
+        Some(&[0xda, 0x4b]),
+        Some(&[0xb4, 0xe6]),
+        Some(&[0xda, 0x51]),
+        Some(&[0xb4, 0xf2]),
+        None,
+        Some(&[0xb4, 0xf0]),
+        None,
+        Some(&[0xda, 0x57]),
+        Some(&[0xb4, 0xef]),
+        Some(&[0xda, 0x41]),
+        Some(&[0xd9, 0xf4]),
+        Some(&[0xd9, 0xfe]),
+        Some(&[0xb5, 0x47]),
+        Some(&[0xda, 0x45]),
+        Some(&[0xda, 0x42]),
+        Some(&[0xd9, 0xf0]),
+        Some(&[0xb5, 0x43]),
+        Some(&[0xda, 0x4f]),
+        Some(&[0xda, 0x4c]),
+        Some(&[0xda, 0x54]),
+        Some(&[0xb4, 0xe9]),
+        Some(&[0xda, 0x40]),
+        Some(&[0xb5, 0x46]),
+        None,
+        Some(&[0xda, 0x47]),
+        None,
+        None,
+        Some(&[0xb4, 0xf3]),
+        Some(&[0xb4, 0xf6]),
+        None,
+        Some(&[0xda, 0x46]),
+        Some(&[0xb5, 0x45]),
+        Some(&[0xd9, 0xf5]),
+        Some(&[0xd5, 0xe4]),
+        None,
+        None,
+        Some(&[0xda, 0x50]),
+        Some(&[0xda, 0x4e]),
+        Some(&[0xda, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd9, 0xec]),
+        Some(&[0xb5, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0x61]),
+        Some(&[0xde, 0x60]),
+        Some(&[0xde, 0x46]),
+        Some(&[0xb7, 0xbd]),
+        None,
+        Some(&[0xde, 0x5f]),
+        Some(&[0xde, 0x49]),
+        Some(&[0xde, 0x4a]),
+        None,
+        Some(&[0xb7, 0xc7]),
+        Some(&[0xde, 0x68]),
+        Some(&[0xb7, 0xc2]),
+        Some(&[0xde, 0x5e]),
+        None,
+        Some(&[0xde, 0x43]),
+        Some(&[0xb7, 0xc8]),
+        Some(&[0xb7, 0xbe]),
+        Some(&[0xde, 0x52]),
+        Some(&[0xde, 0x48]),
+        Some(&[0xde, 0x4b]),
+        Some(&[0xde, 0x63]),
+        Some(&[0xb7, 0xb8]),
+        Some(&[0xde, 0x6a]),
+        Some(&[0xde, 0x62]),
+        Some(&[0xb7, 0xc1]),
+        Some(&[0xde, 0x57]),
+        Some(&[0xb7, 0xcc]),
+        None,
+        None,
+        Some(&[0xb7, 0xcb]),
+        Some(&[0xb7, 0xc5]),
+        None,
+        None,
+        Some(&[0xde, 0x69]),
+        Some(&[0xb7, 0xb9]),
+        Some(&[0xde, 0x55]),
+        Some(&[0xde, 0x4c]),
+        Some(&[0xde, 0x59]),
+        Some(&[0xde, 0x65]),
+        Some(&[0xb7, 0xcd]),
+        None,
+        Some(&[0xb7, 0xbb]),
+        Some(&[0xde, 0x54]),
+        None,
+        Some(&[0xde, 0x4d]),
+        Some(&[0xb7, 0xc4]),
+        None,
+        Some(&[0xb7, 0xc3]),
+        Some(&[0xde, 0x50]),
+        Some(&[0xde, 0x5a]),
+        Some(&[0xde, 0x64]),
+        Some(&[0xde, 0x47]),
+        Some(&[0xde, 0x51]),
+        Some(&[0xb7, 0xbc]),
+        Some(&[0xde, 0x5b]),
+        Some(&[0xb7, 0xc9]),
+        Some(&[0xb7, 0xc0]),
+        Some(&[0xde, 0x4e]),
+        Some(&[0xb7, 0xbf]),
+        Some(&[0xde, 0x45]),
+        Some(&[0xde, 0x53]),
+        Some(&[0xde, 0x67]),
+        Some(&[0xb4, 0xfe]),
+        Some(&[0xba, 0xb0]),
+        Some(&[0xde, 0x56]),
+        Some(&[0xe2, 0x6c]),
+        Some(&[0xde, 0x58]),
+        Some(&[0xde, 0x66]),
+        Some(&[0xb7, 0xc6]),
+        Some(&[0xde, 0x4f]),
+        Some(&[0xb7, 0xba]),
+        Some(&[0xb7, 0xca]),
+        Some(&[0xbc, 0xf0]),
+        Some(&[0xde, 0x44]),
+        None,
+        Some(&[0xde, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xaa]),
+        Some(&[0xba, 0xad]),
+        Some(&[0xe2, 0x7d]),
+        Some(&[0xe2, 0xa4]),
+        Some(&[0xba, 0xa2]),
+        None,
+        Some(&[0xe2, 0x6e]),
+        Some(&[0xba, 0xaf]),
+        None,
+        Some(&[0xba, 0x77]),
+        Some(&[0xe2, 0x6d]),
+        Some(&[0xe2, 0xb0]),
+        Some(&[0xba, 0xb1]),
+        Some(&[0xe2, 0x71]),
+        Some(&[0xe2, 0xa3]),
+        None,
+        Some(&[0xe2, 0x73]),
+        Some(&[0xe2, 0xb3]),
+        Some(&[0xe2, 0xaf]),
+        Some(&[0xba, 0x75]),
+        Some(&[0xba, 0xa1]),
+        Some(&[0xe6, 0x53]),
+        Some(&[0xba, 0xae]),
+        Some(&[0xba, 0x7d]),
+        Some(&[0xe2, 0x6f]),
+        None,
+        Some(&[0xe2, 0xae]),
+        Some(&[0xba, 0xa3]),
+        Some(&[0xe2, 0xab]),
+        Some(&[0xe2, 0xb8]),
+        Some(&[0xe2, 0x75]),
+        Some(&[0xe2, 0x7e]),
+        None,
+        None,
+        Some(&[0xe2, 0xb6]),
+        Some(&[0xe2, 0xac]),
+        Some(&[0xba, 0x7c]),
+        None,
+        None,
+        Some(&[0xe2, 0x7c]),
+        Some(&[0xba, 0x76]),
+        Some(&[0xba, 0x74]),
+        Some(&[0xba, 0xa8]),
+        None,
+        None,
+        Some(&[0xe2, 0x7a]),
+        Some(&[0xe2, 0x77]),
+        Some(&[0xe2, 0x78]),
+        None,
+        Some(&[0xe2, 0xb2]),
+        None,
+        Some(&[0xe2, 0xb7]),
+        Some(&[0xe2, 0xb5]),
+        Some(&[0xba, 0x7a]),
+        Some(&[0xe2, 0xb9]),
+        Some(&[0xba, 0x7e]),
+        Some(&[0xba, 0xa7]),
+        None,
+        Some(&[0xe2, 0x70]),
+        Some(&[0xe5, 0xfa]),
+        Some(&[0xe2, 0x79]),
+        None,
+        Some(&[0xba, 0x78]),
+        Some(&[0xba, 0xac]),
+        Some(&[0xba, 0xa9]),
+        Some(&[0xba, 0x7b]),
+        Some(&[0xe2, 0xa5]),
+        Some(&[0xe2, 0x74]),
+        Some(&[0xba, 0xaa]),
+        Some(&[0xe2, 0xa7]),
+        Some(&[0xba, 0xa4]),
+        Some(&[0xba, 0xa6]),
+        Some(&[0xba, 0x73]),
+        None,
+        Some(&[0xe2, 0xa9]),
+        Some(&[0xe2, 0xa1]),
+        Some(&[0xe2, 0x72]),
+        Some(&[0xba, 0xa5]),
+        Some(&[0xe2, 0xb1]),
+        Some(&[0xe2, 0xb4]),
+        Some(&[0xe2, 0x7b]),
+        Some(&[0xe2, 0xa8]),
+        None,
+        Some(&[0xba, 0x79]),
+        Some(&[0xbc, 0xdf]),
+        Some(&[0xe2, 0xa6]),
+        Some(&[0xe5, 0xf9]),
+        None,
+        Some(&[0xe2, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0x76]),
+        Some(&[0xe6, 0x44]),
+        Some(&[0xe6, 0x4e]),
+        Some(&[0xbc, 0xe2]),
+        Some(&[0xe6, 0x4d]),
+        Some(&[0xe6, 0x59]),
+        Some(&[0xbc, 0xe4]),
+        Some(&[0xe6, 0x4b]),
+        None,
+        Some(&[0xe6, 0x4f]),
+        Some(&[0xbc, 0xef]),
+        None,
+        Some(&[0xe6, 0x46]),
+        Some(&[0xbc, 0xe7]),
+        None,
+        Some(&[0xe6, 0x52]),
+        Some(&[0xe9, 0xf0]),
+        Some(&[0xbc, 0xf3]),
+        Some(&[0xbc, 0xf2]),
+        Some(&[0xe6, 0x54]),
+        Some(&[0xe6, 0x43]),
+        Some(&[0xe6, 0x5e]),
+        Some(&[0xbc, 0xed]),
+        None,
+        Some(&[0xbc, 0xe3]),
+        Some(&[0xe6, 0x57]),
+        None,
+        Some(&[0xe6, 0x5b]),
+        Some(&[0xe6, 0x60]),
+        Some(&[0xe6, 0x55]),
+        Some(&[0xe6, 0x49]),
+        Some(&[0xbc, 0xe6]),
+        Some(&[0xbc, 0xe9]),
+        Some(&[0xbc, 0xf1]),
+        Some(&[0xbc, 0xec]),
+        None,
+        Some(&[0xe6, 0x4c]),
+        Some(&[0xe2, 0xa2]),
+        None,
+        None,
+        Some(&[0xe6, 0x48]),
+        Some(&[0xe6, 0x5f]),
+        Some(&[0xbc, 0xe8]),
+        None,
+        Some(&[0xbc, 0xeb]),
+        Some(&[0xe6, 0x61]),
+        Some(&[0xbc, 0xe0]),
+        Some(&[0xe6, 0x56]),
+        Some(&[0xe5, 0xfb]),
+        Some(&[0xe6, 0x5c]),
+        Some(&[0xc0, 0xdf]),
+        None,
+        Some(&[0xe6, 0x4a]),
+        None,
+        Some(&[0xbc, 0xe1]),
+        Some(&[0xe6, 0x45]),
+        Some(&[0xbc, 0xe5]),
+        Some(&[0xe5, 0xfc]),
+        Some(&[0xba, 0xab]),
+        Some(&[0xe6, 0x41]),
+        None,
+        Some(&[0xe6, 0x5a]),
+        Some(&[0xe6, 0x42]),
+        Some(&[0xe6, 0x40]),
+        Some(&[0xbc, 0xea]),
+        None,
+        Some(&[0xe6, 0x58]),
+        None,
+        Some(&[0xe5, 0xfe]),
+        Some(&[0xe6, 0x51]),
+        Some(&[0xe6, 0x50]),
+        Some(&[0xe6, 0x5d]),
+        Some(&[0xe6, 0x47]),
+        Some(&[0xbc, 0xee]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe9, 0xf3]),
+        None,
+        Some(&[0xbf, 0x49]),
+        Some(&[0xbe, 0xfe]),
+        Some(&[0xea, 0x40]),
+        Some(&[0xe9, 0xeb]),
+        Some(&[0xbf, 0x41]),
+        Some(&[0xe9, 0xf7]),
+        Some(&[0xbf, 0x48]),
+        Some(&[0xbf, 0x43]),
+        Some(&[0xe9, 0xf5]),
+        Some(&[0xed, 0x4f]),
+        Some(&[0xe9, 0xfb]),
+        Some(&[0xea, 0x42]),
+        Some(&[0xe9, 0xfa]),
+        Some(&[0xe9, 0xe9]),
+        Some(&[0xe9, 0xf8]),
+        Some(&[0xea, 0x44]),
+        Some(&[0xea, 0x46]),
+        Some(&[0xbe, 0xfd]),
+        Some(&[0xea, 0x45]),
+        Some(&[0xbf, 0x44]),
+        Some(&[0xbf, 0x4a]),
+        None,
+        Some(&[0xbf, 0x47]),
+        None,
+        Some(&[0xe9, 0xfe]),
+        Some(&[0xbf, 0x46]),
+        Some(&[0xe9, 0xf9]),
+        None,
+        Some(&[0xe9, 0xed]),
+        Some(&[0xe9, 0xf2]),
+        None,
+        Some(&[0xe9, 0xfd]),
+        Some(&[0xbf, 0x45]),
+        Some(&[0xbf, 0x42]),
+        Some(&[0xbe, 0xfc]),
+        Some(&[0xbf, 0x40]),
+        Some(&[0xe9, 0xf1]),
+        None,
+        Some(&[0xe5, 0xfd]),
+        Some(&[0xe9, 0xec]),
+        Some(&[0xe9, 0xef]),
+        Some(&[0xea, 0x41]),
+        Some(&[0xe9, 0xf4]),
+        Some(&[0xe9, 0xea]),
+        Some(&[0xed, 0x4e]),
+        Some(&[0xea, 0x43]),
+        Some(&[0xe9, 0xee]),
+        Some(&[0xe9, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0x51]),
+        Some(&[0xc0, 0xe3]),
+        None,
+        None,
+        Some(&[0xc0, 0xd7]),
+        None,
+        None,
+        Some(&[0xc0, 0xdb]),
+        Some(&[0xed, 0x53]),
+        Some(&[0xed, 0x59]),
+        Some(&[0xed, 0x57]),
+        Some(&[0xc0, 0xd9]),
+        Some(&[0xc0, 0xda]),
+        Some(&[0xc0, 0xe1]),
+        Some(&[0xed, 0x5a]),
+        Some(&[0xed, 0x52]),
+        Some(&[0xc0, 0xdc]),
+        None,
+        Some(&[0xed, 0x56]),
+        Some(&[0xed, 0x55]),
+        Some(&[0xed, 0x5b]),
+        Some(&[0xc0, 0xe2]),
+        None,
+        Some(&[0xc0, 0xdd]),
+        Some(&[0xc0, 0xe0]),
+        Some(&[0xed, 0x54]),
+        Some(&[0xc0, 0xe4]),
+        Some(&[0xc0, 0xde]),
+        Some(&[0xc0, 0xe5]),
+        Some(&[0xc0, 0xd8]),
+        Some(&[0xed, 0x58]),
+        None,
+        Some(&[0xed, 0x50]),
+        None,
+        None,
+        Some(&[0xef, 0xf7]),
+        None,
+        None,
+        Some(&[0xc2, 0x71]),
+        Some(&[0xef, 0xf4]),
+        Some(&[0xef, 0xf6]),
+        None,
+        Some(&[0xc2, 0x6f]),
+        Some(&[0xef, 0xf2]),
+        Some(&[0xef, 0xf3]),
+        Some(&[0xef, 0xee]),
+        None,
+        None,
+        Some(&[0xe9, 0xf6]),
+        Some(&[0xef, 0xef]),
+        Some(&[0xc2, 0x70]),
+        Some(&[0xef, 0xeb]),
+        None,
+        Some(&[0xc2, 0x6d]),
+        Some(&[0xef, 0xf8]),
+        Some(&[0xc2, 0x6e]),
+        Some(&[0xef, 0xec]),
+        Some(&[0xef, 0xed]),
+        Some(&[0xef, 0xf1]),
+        Some(&[0xc2, 0x73]),
+        None,
+        Some(&[0xc2, 0x72]),
+        None,
+        None,
+        Some(&[0xef, 0xf0]),
+        Some(&[0xc3, 0x78]),
+        Some(&[0xf2, 0x5f]),
+        Some(&[0xf2, 0x65]),
+        Some(&[0xc3, 0x79]),
+        Some(&[0xf2, 0x5c]),
+        Some(&[0xc3, 0x76]),
+        Some(&[0xc3, 0x73]),
+        Some(&[0xf2, 0x67]),
+        Some(&[0xc3, 0x77]),
+        None,
+        Some(&[0xc3, 0x74]),
+        Some(&[0xf2, 0x5e]),
+        Some(&[0xf2, 0x61]),
+        Some(&[0xf2, 0x62]),
+        Some(&[0xf2, 0x63]),
+        Some(&[0xf2, 0x66]),
+        None,
+        Some(&[0xef, 0xf5]),
+        Some(&[0xf2, 0x5d]),
+        Some(&[0xc3, 0x75]),
+        Some(&[0xf2, 0x64]),
+        Some(&[0xf2, 0x68]),
+        Some(&[0xf2, 0x60]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0x5d]),
+        Some(&[0xc4, 0x6a]),
+        Some(&[0xf4, 0x60]),
+        Some(&[0xc4, 0x6b]),
+        Some(&[0xf4, 0x68]),
+        Some(&[0xf4, 0x5f]),
+        Some(&[0xf4, 0x5c]),
+        None,
+        Some(&[0xf4, 0x5e]),
+        Some(&[0xf4, 0x62]),
+        Some(&[0xf4, 0x65]),
+        Some(&[0xf4, 0x64]),
+        Some(&[0xf4, 0x67]),
+        Some(&[0xf4, 0x5b]),
+        None,
+        Some(&[0xc4, 0x69]),
+        Some(&[0xf4, 0x63]),
+        Some(&[0xf4, 0x66]),
+        Some(&[0xf4, 0x69]),
+        Some(&[0xf4, 0x61]),
+        Some(&[0xf5, 0xd3]),
+        Some(&[0xf5, 0xd4]),
+        Some(&[0xf5, 0xd8]),
+        Some(&[0xf5, 0xd9]),
+        None,
+        Some(&[0xf5, 0xd6]),
+        Some(&[0xf5, 0xd7]),
+        Some(&[0xf5, 0xd5]),
+        None,
+        Some(&[0xc4, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0x78]),
+        Some(&[0xf6, 0xeb]),
+        None,
+        None,
+        Some(&[0xf6, 0xe8]),
+        Some(&[0xf6, 0xe9]),
+        Some(&[0xf6, 0xea]),
+        Some(&[0xc5, 0x79]),
+        None,
+        Some(&[0xf7, 0xe5]),
+        Some(&[0xf7, 0xe4]),
+        None,
+        Some(&[0xf8, 0xaf]),
+        Some(&[0xc5, 0xf4]),
+        Some(&[0xf8, 0xad]),
+        Some(&[0xf8, 0xb0]),
+        Some(&[0xf8, 0xae]),
+        Some(&[0xf8, 0xf5]),
+        Some(&[0xc6, 0x57]),
+        Some(&[0xc6, 0x65]),
+        Some(&[0xf9, 0xa3]),
+        Some(&[0xf9, 0x6c]),
+        None,
+        Some(&[0xf9, 0xa2]),
+        Some(&[0xf9, 0xd0]),
+        Some(&[0xf9, 0xd1]),
+        Some(&[0xa4, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xc7]),
+        Some(&[0xca, 0x41]),
+        None,
+        None,
+        Some(&[0xcb, 0x5e]),
+        None,
+        Some(&[0xa8, 0x5f]),
+        None,
+        Some(&[0xa8, 0x62]),
+        None,
+        Some(&[0xcb, 0x5f]),
+        None,
+        Some(&[0xa8, 0x60]),
+        Some(&[0xa8, 0x61]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0x58]),
+        Some(&[0xcd, 0x5a]),
+        Some(&[0xcd, 0x55]),
+        Some(&[0xcd, 0x52]),
+        Some(&[0xcd, 0x54]),
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xa4]),
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xa2]),
+        None,
+        None,
+        Some(&[0xcd, 0x56]),
+        Some(&[0xaa, 0xa3]),
+        Some(&[0xcd, 0x53]),
+        Some(&[0xcd, 0x50]),
+        Some(&[0xaa, 0xa1]),
+        Some(&[0xcd, 0x57]),
+        None,
+        Some(&[0xcd, 0x51]),
+        Some(&[0xaa, 0xa5]),
+        Some(&[0xcd, 0x59]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xaf]),
+        None,
+        Some(&[0xcf, 0xb3]),
+        None,
+        None,
+        Some(&[0xac, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xb6]),
+        None,
+        Some(&[0xac, 0xaf]),
+        Some(&[0xac, 0xb2]),
+        Some(&[0xac, 0xb4]),
+        Some(&[0xac, 0xb6]),
+        Some(&[0xac, 0xb3]),
+        Some(&[0xcf, 0xb2]),
+        Some(&[0xcf, 0xb1]),
+        None,
+        Some(&[0xac, 0xb1]),
+        Some(&[0xcf, 0xb4]),
+        Some(&[0xcf, 0xb5]),
+        None,
+        Some(&[0xcf, 0xae]),
+        Some(&[0xac, 0xb5]),
+        None,
+        Some(&[0xac, 0xb0]),
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0x77]),
+        Some(&[0xd2, 0x78]),
+        Some(&[0xd2, 0x79]),
+        Some(&[0xaf, 0x50]),
+        None,
+        Some(&[0xaf, 0x4c]),
+        Some(&[0xd2, 0x6e]),
+        None,
+        Some(&[0xd2, 0x76]),
+        Some(&[0xd2, 0x7b]),
+        Some(&[0xaf, 0x51]),
+        None,
+        Some(&[0xd2, 0x6c]),
+        Some(&[0xd2, 0x72]),
+        Some(&[0xd2, 0x6b]),
+        Some(&[0xd2, 0x75]),
+        None,
+        None,
+        Some(&[0xd2, 0x71]),
+        Some(&[0xaf, 0x4d]),
+        Some(&[0xaf, 0x4f]),
+        Some(&[0xd2, 0x7a]),
+        None,
+        Some(&[0xd2, 0x6a]),
+        Some(&[0xd2, 0x6d]),
+        Some(&[0xd2, 0x73]),
+        None,
+        Some(&[0xd2, 0x74]),
+        Some(&[0xd2, 0x7c]),
+        Some(&[0xd2, 0x70]),
+        None,
+        Some(&[0xaf, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0x6d]),
+        Some(&[0xd6, 0x4e]),
+        None,
+        None,
+        Some(&[0xd6, 0x50]),
+        Some(&[0xd6, 0x4c]),
+        None,
+        Some(&[0xd6, 0x58]),
+        Some(&[0xd6, 0x4a]),
+        Some(&[0xd6, 0x57]),
+        Some(&[0xb2, 0x69]),
+        Some(&[0xd6, 0x48]),
+        Some(&[0xda, 0x5b]),
+        Some(&[0xd6, 0x52]),
+        Some(&[0xb2, 0x6c]),
+        None,
+        Some(&[0xd6, 0x53]),
+        Some(&[0xd6, 0x56]),
+        None,
+        Some(&[0xd6, 0x5a]),
+        None,
+        Some(&[0xd6, 0x4f]),
+        None,
+        Some(&[0xd6, 0x54]),
+        None,
+        None,
+        Some(&[0xb2, 0x6a]),
+        Some(&[0xb2, 0x6b]),
+        Some(&[0xd6, 0x59]),
+        Some(&[0xd6, 0x4d]),
+        Some(&[0xd6, 0x49]),
+        Some(&[0xd6, 0x5b]),
+        None,
+        Some(&[0xd6, 0x51]),
+        None,
+        None,
+        Some(&[0xd6, 0x55]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0x4b]),
+        None,
+        Some(&[0xb5, 0x48]),
+        Some(&[0xb5, 0x49]),
+        Some(&[0xda, 0x65]),
+        Some(&[0xb5, 0x4f]),
+        None,
+        Some(&[0xda, 0x59]),
+        Some(&[0xda, 0x62]),
+        Some(&[0xda, 0x58]),
+        Some(&[0xb5, 0x4c]),
+        Some(&[0xda, 0x60]),
+        Some(&[0xda, 0x5e]),
+        None,
+        Some(&[0xda, 0x5f]),
+        Some(&[0xb5, 0x4a]),
+        None,
+        Some(&[0xda, 0x63]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0x5c]),
+        Some(&[0xda, 0x5a]),
+        Some(&[0xb5, 0x4b]),
+        Some(&[0xda, 0x5d]),
+        Some(&[0xda, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x4d]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0x70]),
+        Some(&[0xde, 0x77]),
+        Some(&[0xde, 0x79]),
+        Some(&[0xde, 0xa1]),
+        None,
+        Some(&[0xb7, 0xda]),
+        Some(&[0xde, 0x6b]),
+        None,
+        Some(&[0xb7, 0xd2]),
+        None,
+        Some(&[0xde, 0x7a]),
+        Some(&[0xb7, 0xd7]),
+        Some(&[0xde, 0xa2]),
+        Some(&[0xb7, 0xce]),
+        None,
+        Some(&[0xde, 0x7d]),
+        None,
+        Some(&[0xde, 0x6d]),
+        Some(&[0xde, 0x7e]),
+        Some(&[0xde, 0x6c]),
+        None,
+        Some(&[0xb7, 0xdc]),
+        None,
+        Some(&[0xde, 0x78]),
+        Some(&[0xb7, 0xcf]),
+        Some(&[0xde, 0xa3]),
+        None,
+        Some(&[0xb7, 0xd4]),
+        Some(&[0xde, 0x71]),
+        Some(&[0xb7, 0xd9]),
+        Some(&[0xde, 0x7c]),
+        Some(&[0xde, 0x6f]),
+        Some(&[0xde, 0x76]),
+        Some(&[0xde, 0x72]),
+        Some(&[0xde, 0x6e]),
+        Some(&[0xb7, 0xd1]),
+        Some(&[0xb7, 0xd8]),
+        Some(&[0xb7, 0xd6]),
+        Some(&[0xb7, 0xd3]),
+        Some(&[0xb7, 0xdb]),
+        Some(&[0xb7, 0xd0]),
+        Some(&[0xde, 0x75]),
+        None,
+        Some(&[0xb7, 0xd5]),
+        None,
+        Some(&[0xb5, 0x4e]),
+        None,
+        Some(&[0xde, 0x7b]),
+        None,
+        Some(&[0xde, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0x74]),
+        None,
+        None,
+        Some(&[0xe2, 0xc1]),
+        None,
+        Some(&[0xba, 0xb4]),
+        None,
+        None,
+        Some(&[0xe2, 0xbd]),
+        Some(&[0xe2, 0xc3]),
+        Some(&[0xe2, 0xbf]),
+        None,
+        Some(&[0xba, 0xb6]),
+        Some(&[0xe2, 0xbe]),
+        Some(&[0xe2, 0xc2]),
+        Some(&[0xe2, 0xba]),
+        None,
+        Some(&[0xe2, 0xbc]),
+        Some(&[0xba, 0xb5]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xc0]),
+        Some(&[0xe2, 0xbb]),
+        None,
+        Some(&[0xba, 0xb7]),
+        None,
+        Some(&[0xba, 0xb2]),
+        None,
+        None,
+        Some(&[0xe2, 0xc4]),
+        None,
+        Some(&[0xba, 0xb3]),
+        Some(&[0xe6, 0x67]),
+        Some(&[0xe6, 0x64]),
+        Some(&[0xe6, 0x70]),
+        Some(&[0xe6, 0x6a]),
+        Some(&[0xe6, 0x6c]),
+        Some(&[0xbc, 0xf4]),
+        Some(&[0xe6, 0x66]),
+        Some(&[0xe6, 0x6e]),
+        None,
+        None,
+        Some(&[0xe6, 0x6d]),
+        Some(&[0xe6, 0x6b]),
+        None,
+        Some(&[0xe6, 0x71]),
+        Some(&[0xbc, 0xf7]),
+        Some(&[0xe6, 0x68]),
+        Some(&[0xe6, 0x6f]),
+        None,
+        Some(&[0xbc, 0xf5]),
+        None,
+        None,
+        Some(&[0xe6, 0x63]),
+        Some(&[0xe6, 0x65]),
+        Some(&[0xbc, 0xf6]),
+        Some(&[0xe6, 0x62]),
+        Some(&[0xe6, 0x72]),
+        None,
+        Some(&[0xe6, 0x69]),
+        None,
+        None,
+        Some(&[0xea, 0x4a]),
+        Some(&[0xbf, 0x51]),
+        None,
+        None,
+        Some(&[0xea, 0x55]),
+        Some(&[0xea, 0x53]),
+        Some(&[0xbf, 0x4b]),
+        Some(&[0xea, 0x49]),
+        Some(&[0xea, 0x4c]),
+        Some(&[0xea, 0x4d]),
+        Some(&[0xea, 0x48]),
+        Some(&[0xbf, 0x55]),
+        Some(&[0xbf, 0x56]),
+        Some(&[0xea, 0x47]),
+        Some(&[0xea, 0x56]),
+        Some(&[0xea, 0x51]),
+        Some(&[0xbf, 0x4f]),
+        Some(&[0xbf, 0x4c]),
+        Some(&[0xea, 0x50]),
+        Some(&[0xea, 0x4e]),
+        None,
+        None,
+        Some(&[0xbf, 0x52]),
+        Some(&[0xea, 0x52]),
+        Some(&[0xbf, 0x4d]),
+        None,
+        Some(&[0xbf, 0x4e]),
+        None,
+        Some(&[0xea, 0x4f]),
+        Some(&[0xbf, 0x50]),
+        Some(&[0xea, 0x4b]),
+        None,
+        Some(&[0xea, 0x54]),
+        Some(&[0xbf, 0x53]),
+        Some(&[0xea, 0x57]),
+        Some(&[0xea, 0x58]),
+        Some(&[0xbf, 0x54]),
+        None,
+        None,
+        Some(&[0xc0, 0xe7]),
+        Some(&[0xc0, 0xee]),
+        Some(&[0xed, 0x5c]),
+        Some(&[0xed, 0x62]),
+        None,
+        Some(&[0xed, 0x60]),
+        Some(&[0xc0, 0xea]),
+        Some(&[0xc0, 0xe9]),
+        Some(&[0xc0, 0xe6]),
+        Some(&[0xed, 0x5e]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xec]),
+        Some(&[0xc0, 0xeb]),
+        Some(&[0xc0, 0xe8]),
+        None,
+        Some(&[0xed, 0x61]),
+        Some(&[0xed, 0x5d]),
+        Some(&[0xed, 0x5f]),
+        None,
+        Some(&[0xc0, 0xed]),
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0x77]),
+        Some(&[0xef, 0xfb]),
+        None,
+        Some(&[0xc2, 0x74]),
+        Some(&[0xc2, 0x75]),
+        Some(&[0xef, 0xfd]),
+        Some(&[0xc2, 0x76]),
+        Some(&[0xef, 0xfa]),
+        None,
+        Some(&[0xef, 0xf9]),
+        Some(&[0xf2, 0x6c]),
+        Some(&[0xef, 0xfc]),
+        None,
+        Some(&[0xf2, 0x6d]),
+        Some(&[0xc3, 0x7a]),
+        Some(&[0xf2, 0x6b]),
+        None,
+        None,
+        Some(&[0xf2, 0x6a]),
+        None,
+        Some(&[0xf2, 0x69]),
+        Some(&[0xc3, 0x7b]),
+        None,
+        None,
+        Some(&[0xc4, 0x6c]),
+        None,
+        None,
+        Some(&[0xf4, 0x6a]),
+        Some(&[0xf4, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xdc]),
+        Some(&[0xf5, 0xdb]),
+        Some(&[0xc4, 0xea]),
+        None,
+        Some(&[0xf5, 0xda]),
+        Some(&[0xf6, 0xec]),
+        Some(&[0xf6, 0xed]),
+        None,
+        None,
+        Some(&[0xf7, 0xe6]),
+        Some(&[0xf8, 0xb1]),
+        None,
+        None,
+        Some(&[0xf8, 0xf6]),
+        Some(&[0xf9, 0xbc]),
+        Some(&[0xc6, 0x79]),
+        Some(&[0xf9, 0xc6]),
+        Some(&[0xa4, 0xf6]),
+        None,
+        Some(&[0xaa, 0xa6]),
+        Some(&[0xaa, 0xa7]),
+        None,
+        None,
+        Some(&[0xac, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xef]),
+        Some(&[0xa4, 0xf7]),
+        None,
+        Some(&[0xaa, 0xa8]),
+        Some(&[0xaf, 0x52]),
+        Some(&[0xb7, 0xdd]),
+        Some(&[0xa4, 0xf8]),
+        None,
+        Some(&[0xb2, 0x6e]),
+        Some(&[0xba, 0xb8]),
+        Some(&[0xc9, 0x62]),
+        None,
+        Some(&[0xcf, 0xb7]),
+        Some(&[0xd2, 0x7d]),
+        None,
+        Some(&[0xe2, 0xc5]),
+        None,
+        Some(&[0xc0, 0xf0]),
+        Some(&[0xa4, 0xf9]),
+        Some(&[0xaa, 0xa9]),
+        Some(&[0xcf, 0xb8]),
+        Some(&[0xcf, 0xb9]),
+        Some(&[0xda, 0x66]),
+        Some(&[0xb5, 0x50]),
+        None,
+        None,
+        Some(&[0xde, 0xa4]),
+        None,
+        None,
+        Some(&[0xb7, 0xde]),
+        Some(&[0xe2, 0xc6]),
+        None,
+        None,
+        Some(&[0xbc, 0xf8]),
+        None,
+        Some(&[0xc3, 0x7c]),
+        Some(&[0xa4, 0xfa]),
+        Some(&[0xda, 0x67]),
+        Some(&[0xa4, 0xfb]),
+        None,
+        Some(&[0xa6, 0xc9]),
+        Some(&[0xca, 0x42]),
+        Some(&[0xa6, 0xc8]),
+        Some(&[0xa8, 0x65]),
+        Some(&[0xa8, 0x64]),
+        Some(&[0xa8, 0x63]),
+        Some(&[0xcb, 0x60]),
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xaa]),
+        None,
+        Some(&[0xaa, 0xab]),
+        Some(&[0xcd, 0x5b]),
+        None,
+        Some(&[0xcf, 0xba]),
+        None,
+        Some(&[0xcf, 0xbd]),
+        Some(&[0xac, 0xba]),
+        Some(&[0xcf, 0xbb]),
+        None,
+        Some(&[0xac, 0xb9]),
+        Some(&[0xcf, 0xbc]),
+        Some(&[0xac, 0xbb]),
+        None,
+        Some(&[0xd2, 0xa2]),
+        Some(&[0xd2, 0xa1]),
+        Some(&[0xd2, 0x7e]),
+        Some(&[0xaf, 0x53]),
+        None,
+        Some(&[0xd6, 0x5d]),
+        Some(&[0xd6, 0x5e]),
+        Some(&[0xb2, 0x6f]),
+        Some(&[0xd6, 0x5c]),
+        Some(&[0xd6, 0x5f]),
+        Some(&[0xb5, 0x52]),
+        Some(&[0xb2, 0x70]),
+        None,
+        None,
+        Some(&[0xb5, 0x51]),
+        Some(&[0xda, 0x6b]),
+        Some(&[0xda, 0x6a]),
+        None,
+        Some(&[0xda, 0x68]),
+        Some(&[0xda, 0x69]),
+        None,
+        Some(&[0xda, 0x6c]),
+        Some(&[0xde, 0xa6]),
+        Some(&[0xde, 0xa5]),
+        Some(&[0xde, 0xa9]),
+        None,
+        Some(&[0xde, 0xa8]),
+        Some(&[0xde, 0xa7]),
+        Some(&[0xba, 0xb9]),
+        Some(&[0xe2, 0xc9]),
+        None,
+        Some(&[0xe2, 0xc8]),
+        Some(&[0xba, 0xba]),
+        Some(&[0xe2, 0xc7]),
+        Some(&[0xe6, 0x73]),
+        None,
+        Some(&[0xe6, 0x74]),
+        Some(&[0xbc, 0xf9]),
+        None,
+        Some(&[0xea, 0x59]),
+        Some(&[0xea, 0x5a]),
+        None,
+        None,
+        Some(&[0xf2, 0x72]),
+        Some(&[0xc3, 0x7d]),
+        Some(&[0xf2, 0x71]),
+        Some(&[0xf2, 0x70]),
+        Some(&[0xf2, 0x6e]),
+        Some(&[0xf2, 0x6f]),
+        Some(&[0xc4, 0xeb]),
+        Some(&[0xf4, 0x6c]),
+        Some(&[0xf6, 0xee]),
+        Some(&[0xf8, 0xf7]),
+        None,
+        Some(&[0xa4, 0xfc]),
+        None,
+        Some(&[0xc9, 0xa5]),
+        Some(&[0xa5, 0xc7]),
+        Some(&[0xc9, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x43]),
+        Some(&[0xca, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0x66]),
+        None,
+        None,
+        Some(&[0xcb, 0x62]),
+        None,
+        Some(&[0xcb, 0x61]),
+        Some(&[0xaa, 0xac]),
+        Some(&[0xcb, 0x65]),
+        Some(&[0xa8, 0x67]),
+        Some(&[0xcb, 0x63]),
+        Some(&[0xa8, 0x66]),
+        Some(&[0xcb, 0x67]),
+        Some(&[0xcb, 0x64]),
+        None,
+        None,
+        Some(&[0xcd, 0x5f]),
+        Some(&[0xcf, 0xbe]),
+        Some(&[0xcd, 0x5d]),
+        Some(&[0xcd, 0x64]),
+        None,
+        Some(&[0xaa, 0xad]),
+        None,
+        Some(&[0xaa, 0xb0]),
+        Some(&[0xcd, 0x65]),
+        Some(&[0xcd, 0x61]),
+        None,
+        Some(&[0xcd, 0x62]),
+        None,
+        Some(&[0xcd, 0x5c]),
+        Some(&[0xaa, 0xaf]),
+        Some(&[0xcd, 0x5e]),
+        Some(&[0xaa, 0xae]),
+        Some(&[0xcd, 0x63]),
+        None,
+        Some(&[0xcd, 0x60]),
+        None,
+        None,
+        Some(&[0xcf, 0xc2]),
+        Some(&[0xac, 0xbd]),
+        Some(&[0xac, 0xbe]),
+        None,
+        Some(&[0xcf, 0xc5]),
+        Some(&[0xcf, 0xbf]),
+        None,
+        Some(&[0xcf, 0xc4]),
+        None,
+        Some(&[0xcf, 0xc0]),
+        Some(&[0xac, 0xbc]),
+        Some(&[0xcf, 0xc3]),
+        Some(&[0xcf, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xa8]),
+        Some(&[0xd2, 0xa5]),
+        None,
+        Some(&[0xd2, 0xa7]),
+        Some(&[0xaf, 0x58]),
+        Some(&[0xaf, 0x57]),
+        Some(&[0xaf, 0x55]),
+        Some(&[0xd2, 0xa4]),
+        Some(&[0xd2, 0xa9]),
+        Some(&[0xaf, 0x54]),
+        Some(&[0xaf, 0x56]),
+        Some(&[0xd2, 0xa6]),
+        Some(&[0xd6, 0x67]),
+        Some(&[0xd2, 0xa3]),
+        Some(&[0xd2, 0xaa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0x62]),
+        Some(&[0xd6, 0x66]),
+        None,
+        Some(&[0xd6, 0x65]),
+        Some(&[0xda, 0x6e]),
+        Some(&[0xda, 0x79]),
+        None,
+        None,
+        Some(&[0xd6, 0x68]),
+        None,
+        Some(&[0xd6, 0x63]),
+        Some(&[0xda, 0x6d]),
+        Some(&[0xb2, 0x74]),
+        None,
+        None,
+        Some(&[0xb2, 0x73]),
+        Some(&[0xd6, 0x61]),
+        Some(&[0xd6, 0x64]),
+        Some(&[0xb2, 0x75]),
+        None,
+        Some(&[0xb2, 0x72]),
+        Some(&[0xb2, 0x71]),
+        Some(&[0xd6, 0x60]),
+        Some(&[0xd6, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0x70]),
+        Some(&[0xda, 0x77]),
+        None,
+        Some(&[0xb5, 0x54]),
+        Some(&[0xda, 0x76]),
+        Some(&[0xda, 0x73]),
+        None,
+        Some(&[0xb5, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0x75]),
+        None,
+        None,
+        Some(&[0xda, 0x6f]),
+        Some(&[0xda, 0x71]),
+        Some(&[0xda, 0x74]),
+        Some(&[0xda, 0x72]),
+        Some(&[0xb5, 0x55]),
+        Some(&[0xda, 0x78]),
+        Some(&[0xb5, 0x53]),
+        Some(&[0xb7, 0xdf]),
+        None,
+        None,
+        Some(&[0xde, 0xad]),
+        Some(&[0xde, 0xac]),
+        Some(&[0xde, 0xaa]),
+        None,
+        Some(&[0xb7, 0xe2]),
+        Some(&[0xb7, 0xe1]),
+        Some(&[0xde, 0xae]),
+        None,
+        Some(&[0xde, 0xab]),
+        Some(&[0xe2, 0xca]),
+        Some(&[0xba, 0xbb]),
+        Some(&[0xb7, 0xe0]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb0]),
+        Some(&[0xde, 0xaf]),
+        None,
+        Some(&[0xe2, 0xcd]),
+        Some(&[0xe2, 0xcb]),
+        Some(&[0xbc, 0xfa]),
+        None,
+        Some(&[0xba, 0xbc]),
+        Some(&[0xe2, 0xcc]),
+        Some(&[0xe6, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbc, 0xfb]),
+        Some(&[0xe6, 0x75]),
+        Some(&[0xe6, 0x7e]),
+        Some(&[0xe6, 0x7d]),
+        Some(&[0xe6, 0x7b]),
+        None,
+        Some(&[0xe6, 0x7a]),
+        Some(&[0xe6, 0x77]),
+        Some(&[0xe6, 0x78]),
+        Some(&[0xe6, 0x79]),
+        Some(&[0xe6, 0x7c]),
+        Some(&[0xe6, 0xa1]),
+        None,
+        None,
+        Some(&[0xea, 0x5f]),
+        Some(&[0xea, 0x5c]),
+        Some(&[0xea, 0x5d]),
+        Some(&[0xbf, 0x57]),
+        Some(&[0xea, 0x5b]),
+        Some(&[0xea, 0x61]),
+        Some(&[0xea, 0x60]),
+        Some(&[0xea, 0x5e]),
+        None,
+        Some(&[0xed, 0x64]),
+        Some(&[0xed, 0x65]),
+        Some(&[0xc0, 0xf1]),
+        None,
+        Some(&[0xc0, 0xf2]),
+        Some(&[0xed, 0x63]),
+        None,
+        Some(&[0xc2, 0x79]),
+        Some(&[0xef, 0xfe]),
+        Some(&[0xc2, 0x78]),
+        Some(&[0xc3, 0x7e]),
+        None,
+        Some(&[0xc3, 0xa1]),
+        Some(&[0xc4, 0x6d]),
+        Some(&[0xf4, 0x6e]),
+        Some(&[0xf4, 0x6d]),
+        Some(&[0xf5, 0xdd]),
+        Some(&[0xf6, 0xef]),
+        Some(&[0xc5, 0x7a]),
+        Some(&[0xf7, 0xe8]),
+        Some(&[0xf7, 0xe7]),
+        Some(&[0xf7, 0xe9]),
+        Some(&[0xa5, 0xc8]),
+        Some(&[0xcf, 0xc6]),
+        Some(&[0xaf, 0x59]),
+        Some(&[0xb2, 0x76]),
+        Some(&[0xd6, 0x6a]),
+        Some(&[0xa5, 0xc9]),
+        Some(&[0xc9, 0xa7]),
+        Some(&[0xa4, 0xfd]),
+        None,
+        None,
+        Some(&[0xca, 0x45]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0x6c]),
+        Some(&[0xcb, 0x6a]),
+        Some(&[0xcb, 0x6b]),
+        Some(&[0xcb, 0x68]),
+        Some(&[0xa8, 0x68]),
+        Some(&[0xcb, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0x6d]),
+        None,
+        Some(&[0xaa, 0xb3]),
+        Some(&[0xcd, 0x6b]),
+        Some(&[0xcd, 0x67]),
+        Some(&[0xcd, 0x6a]),
+        None,
+        Some(&[0xcd, 0x66]),
+        Some(&[0xaa, 0xb5]),
+        Some(&[0xcd, 0x69]),
+        None,
+        Some(&[0xaa, 0xb2]),
+        Some(&[0xaa, 0xb1]),
+        None,
+        Some(&[0xaa, 0xb4]),
+        Some(&[0xcd, 0x6c]),
+        Some(&[0xcd, 0x68]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xac, 0xc2]),
+        Some(&[0xac, 0xc5]),
+        Some(&[0xcf, 0xce]),
+        Some(&[0xcf, 0xcd]),
+        Some(&[0xcf, 0xcc]),
+        Some(&[0xac, 0xbf]),
+        Some(&[0xcf, 0xd5]),
+        Some(&[0xcf, 0xcb]),
+        None,
+        Some(&[0xac, 0xc1]),
+        Some(&[0xd2, 0xaf]),
+        None,
+        Some(&[0xcf, 0xd2]),
+        Some(&[0xcf, 0xd0]),
+        Some(&[0xac, 0xc4]),
+        None,
+        Some(&[0xcf, 0xc8]),
+        Some(&[0xcf, 0xd3]),
+        None,
+        Some(&[0xcf, 0xca]),
+        Some(&[0xcf, 0xd4]),
+        Some(&[0xcf, 0xd1]),
+        Some(&[0xcf, 0xc9]),
+        None,
+        Some(&[0xac, 0xc0]),
+        Some(&[0xcf, 0xd6]),
+        Some(&[0xcf, 0xc7]),
+        Some(&[0xac, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xb4]),
+        Some(&[0xd2, 0xab]),
+        Some(&[0xd2, 0xb6]),
+        None,
+        Some(&[0xd2, 0xae]),
+        Some(&[0xd2, 0xb9]),
+        Some(&[0xd2, 0xba]),
+        Some(&[0xd2, 0xac]),
+        Some(&[0xd2, 0xb8]),
+        Some(&[0xd2, 0xb5]),
+        Some(&[0xd2, 0xb3]),
+        Some(&[0xd2, 0xb7]),
+        Some(&[0xaf, 0x5f]),
+        None,
+        Some(&[0xaf, 0x5d]),
+        None,
+        None,
+        Some(&[0xd2, 0xb1]),
+        None,
+        Some(&[0xd2, 0xad]),
+        None,
+        Some(&[0xd2, 0xb0]),
+        Some(&[0xd2, 0xbb]),
+        Some(&[0xd2, 0xb2]),
+        Some(&[0xaf, 0x5e]),
+        Some(&[0xcf, 0xcf]),
+        None,
+        Some(&[0xaf, 0x5a]),
+        Some(&[0xaf, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0x78]),
+        Some(&[0xd6, 0x6d]),
+        Some(&[0xd6, 0x6b]),
+        None,
+        Some(&[0xd6, 0x6c]),
+        None,
+        Some(&[0xd6, 0x73]),
+        None,
+        Some(&[0xd6, 0x74]),
+        Some(&[0xd6, 0x70]),
+        Some(&[0xb2, 0x7b]),
+        Some(&[0xd6, 0x75]),
+        Some(&[0xd6, 0x72]),
+        Some(&[0xd6, 0x6f]),
+        None,
+        Some(&[0xb2, 0x79]),
+        Some(&[0xd6, 0x6e]),
+        Some(&[0xb2, 0x77]),
+        Some(&[0xb2, 0x7a]),
+        Some(&[0xd6, 0x71]),
+        Some(&[0xd6, 0x79]),
+        Some(&[0xaf, 0x5b]),
+        Some(&[0xb2, 0x78]),
+        Some(&[0xd6, 0x77]),
+        Some(&[0xd6, 0x76]),
+        Some(&[0xb2, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xa1]),
+        Some(&[0xb5, 0x60]),
+        None,
+        Some(&[0xda, 0xa7]),
+        None,
+        None,
+        Some(&[0xda, 0xa9]),
+        Some(&[0xda, 0xa2]),
+        Some(&[0xb5, 0x5a]),
+        Some(&[0xda, 0xa6]),
+        Some(&[0xda, 0xa5]),
+        Some(&[0xb5, 0x5b]),
+        Some(&[0xb5, 0x61]),
+        None,
+        Some(&[0xb5, 0x62]),
+        Some(&[0xda, 0xa8]),
+        Some(&[0xb5, 0x58]),
+        Some(&[0xda, 0x7d]),
+        Some(&[0xda, 0x7b]),
+        Some(&[0xda, 0xa3]),
+        Some(&[0xda, 0x7a]),
+        Some(&[0xb5, 0x5f]),
+        Some(&[0xda, 0x7c]),
+        Some(&[0xda, 0xa4]),
+        Some(&[0xda, 0xaa]),
+        Some(&[0xb5, 0x59]),
+        Some(&[0xb5, 0x5e]),
+        Some(&[0xb5, 0x5c]),
+        Some(&[0xb5, 0x5d]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xe9]),
+        Some(&[0xde, 0xb7]),
+        Some(&[0xb7, 0xe8]),
+        Some(&[0xde, 0xbb]),
+        None,
+        Some(&[0xde, 0xb1]),
+        None,
+        Some(&[0xde, 0xbc]),
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xb2]),
+        Some(&[0xde, 0xb3]),
+        None,
+        Some(&[0xde, 0xbd]),
+        Some(&[0xde, 0xba]),
+        Some(&[0xde, 0xb8]),
+        Some(&[0xde, 0xb9]),
+        Some(&[0xde, 0xb5]),
+        Some(&[0xde, 0xb4]),
+        None,
+        Some(&[0xde, 0xbe]),
+        Some(&[0xb7, 0xe5]),
+        None,
+        Some(&[0xde, 0xb6]),
+        None,
+        Some(&[0xb7, 0xea]),
+        Some(&[0xb7, 0xe4]),
+        Some(&[0xb7, 0xeb]),
+        Some(&[0xb7, 0xec]),
+        None,
+        Some(&[0xb7, 0xe7]),
+        Some(&[0xb7, 0xe6]),
+        None,
+        None,
+        Some(&[0xe2, 0xce]),
+        Some(&[0xba, 0xbe]),
+        Some(&[0xba, 0xbd]),
+        None,
+        None,
+        Some(&[0xe2, 0xd3]),
+        None,
+        Some(&[0xbc, 0xfc]),
+        Some(&[0xba, 0xbf]),
+        None,
+        None,
+        Some(&[0xba, 0xc1]),
+        Some(&[0xe2, 0xd4]),
+        Some(&[0xb7, 0xe3]),
+        Some(&[0xba, 0xc0]),
+        Some(&[0xe2, 0xd0]),
+        Some(&[0xe2, 0xd2]),
+        Some(&[0xe2, 0xcf]),
+        None,
+        Some(&[0xe2, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xab]),
+        None,
+        None,
+        Some(&[0xe6, 0xaa]),
+        Some(&[0xe6, 0xa7]),
+        Some(&[0xbd, 0x40]),
+        Some(&[0xea, 0x62]),
+        Some(&[0xbd, 0x41]),
+        Some(&[0xe6, 0xa6]),
+        None,
+        Some(&[0xbc, 0xfe]),
+        None,
+        Some(&[0xe6, 0xa8]),
+        Some(&[0xe6, 0xa5]),
+        Some(&[0xe6, 0xa2]),
+        Some(&[0xe6, 0xa9]),
+        Some(&[0xe6, 0xa3]),
+        Some(&[0xe6, 0xa4]),
+        Some(&[0xbc, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0x69]),
+        None,
+        Some(&[0xea, 0x66]),
+        None,
+        Some(&[0xea, 0x65]),
+        Some(&[0xea, 0x67]),
+        None,
+        Some(&[0xed, 0x66]),
+        Some(&[0xbf, 0x5a]),
+        None,
+        Some(&[0xea, 0x63]),
+        None,
+        Some(&[0xbf, 0x58]),
+        None,
+        Some(&[0xbf, 0x5c]),
+        Some(&[0xbf, 0x5b]),
+        Some(&[0xea, 0x64]),
+        Some(&[0xea, 0x68]),
+        None,
+        Some(&[0xbf, 0x59]),
+        None,
+        Some(&[0xed, 0x6d]),
+        Some(&[0xc0, 0xf5]),
+        Some(&[0xc2, 0x7a]),
+        Some(&[0xc0, 0xf6]),
+        Some(&[0xc0, 0xf3]),
+        Some(&[0xed, 0x6a]),
+        Some(&[0xed, 0x68]),
+        None,
+        Some(&[0xed, 0x6b]),
+        None,
+        Some(&[0xed, 0x6e]),
+        Some(&[0xc0, 0xf4]),
+        Some(&[0xed, 0x6c]),
+        Some(&[0xed, 0x67]),
+        None,
+        None,
+        Some(&[0xf0, 0x42]),
+        Some(&[0xf0, 0x45]),
+        Some(&[0xf2, 0x75]),
+        Some(&[0xf0, 0x40]),
+        None,
+        Some(&[0xf4, 0x6f]),
+        Some(&[0xf0, 0x46]),
+        None,
+        Some(&[0xc3, 0xa2]),
+        Some(&[0xf0, 0x44]),
+        Some(&[0xc2, 0x7b]),
+        Some(&[0xf0, 0x41]),
+        Some(&[0xf0, 0x43]),
+        Some(&[0xf0, 0x47]),
+        Some(&[0xf2, 0x76]),
+        None,
+        Some(&[0xf2, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xa3]),
+        Some(&[0xf2, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xed]),
+        Some(&[0xf6, 0xf1]),
+        Some(&[0xc4, 0xec]),
+        Some(&[0xf6, 0xf3]),
+        Some(&[0xf6, 0xf0]),
+        Some(&[0xf6, 0xf2]),
+        Some(&[0xc5, 0xd0]),
+        Some(&[0xf8, 0xb2]),
+        Some(&[0xa5, 0xca]),
+        Some(&[0xcd, 0x6e]),
+        Some(&[0xd2, 0xbc]),
+        Some(&[0xd2, 0xbd]),
+        Some(&[0xb2, 0x7d]),
+        Some(&[0xde, 0xbf]),
+        Some(&[0xbf, 0x5d]),
+        Some(&[0xc3, 0xa4]),
+        Some(&[0xc5, 0x7b]),
+        Some(&[0xf8, 0xb3]),
+        Some(&[0xa5, 0xcb]),
+        None,
+        Some(&[0xcd, 0x6f]),
+        Some(&[0xa2, 0x60]),
+        None,
+        None,
+        Some(&[0xcf, 0xd7]),
+        None,
+        Some(&[0xcf, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xbe]),
+        Some(&[0xd2, 0xbf]),
+        Some(&[0xb2, 0x7e]),
+        Some(&[0xb2, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xab]),
+        None,
+        Some(&[0xde, 0xc2]),
+        Some(&[0xde, 0xc1]),
+        Some(&[0xde, 0xc0]),
+        Some(&[0xe2, 0xd5]),
+        None,
+        Some(&[0xe2, 0xd6]),
+        Some(&[0xe2, 0xd7]),
+        Some(&[0xba, 0xc2]),
+        None,
+        None,
+        Some(&[0xe6, 0xad]),
+        Some(&[0xe6, 0xac]),
+        None,
+        None,
+        Some(&[0xea, 0x69]),
+        Some(&[0xbf, 0x5e]),
+        Some(&[0xbf, 0x5f]),
+        None,
+        Some(&[0xed, 0x72]),
+        Some(&[0xed, 0x6f]),
+        Some(&[0xed, 0x70]),
+        Some(&[0xed, 0x71]),
+        Some(&[0xf0, 0x49]),
+        Some(&[0xf0, 0x48]),
+        Some(&[0xc2, 0x7c]),
+        Some(&[0xf2, 0x77]),
+        Some(&[0xf5, 0xde]),
+        Some(&[0xa5, 0xcc]),
+        None,
+        Some(&[0xac, 0xc6]),
+        None,
+        Some(&[0xb2, 0xa2]),
+        Some(&[0xde, 0xc3]),
+        None,
+        Some(&[0xa5, 0xcd]),
+        None,
+        Some(&[0xd2, 0xc0]),
+        Some(&[0xb2, 0xa3]),
+        None,
+        None,
+        Some(&[0xb5, 0x63]),
+        Some(&[0xb5, 0x64]),
+        None,
+        Some(&[0xa5, 0xce]),
+        Some(&[0xa5, 0xcf]),
+        Some(&[0xca, 0x46]),
+        Some(&[0xa8, 0x6a]),
+        Some(&[0xa8, 0x69]),
+        Some(&[0xac, 0xc7]),
+        Some(&[0xcf, 0xd9]),
+        Some(&[0xda, 0xac]),
+        Some(&[0xa5, 0xd0]),
+        Some(&[0xa5, 0xd1]),
+        Some(&[0xa5, 0xd2]),
+        Some(&[0xa5, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0x6b]),
+        Some(&[0xa8, 0x6c]),
+        Some(&[0xcb, 0x6e]),
+        Some(&[0xcb, 0x6d]),
+        None,
+        None,
+        Some(&[0xaa, 0xb6]),
+        Some(&[0xcd, 0x72]),
+        Some(&[0xcd, 0x70]),
+        Some(&[0xcd, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcf, 0xda]),
+        Some(&[0xcf, 0xdb]),
+        None,
+        None,
+        Some(&[0xac, 0xcb]),
+        Some(&[0xac, 0xc9]),
+        None,
+        Some(&[0xac, 0xca]),
+        Some(&[0xac, 0xc8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0x64]),
+        Some(&[0xaf, 0x63]),
+        Some(&[0xd2, 0xc1]),
+        Some(&[0xaf, 0x62]),
+        Some(&[0xaf, 0x61]),
+        None,
+        Some(&[0xd2, 0xc2]),
+        None,
+        None,
+        Some(&[0xb2, 0xa6]),
+        Some(&[0xd6, 0x7b]),
+        Some(&[0xd6, 0x7a]),
+        Some(&[0xb2, 0xa4]),
+        Some(&[0xb2, 0xa5]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x66]),
+        Some(&[0xb5, 0x65]),
+        Some(&[0xda, 0xae]),
+        None,
+        None,
+        Some(&[0xda, 0xad]),
+        Some(&[0xb2, 0xa7]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb7, 0xed]),
+        Some(&[0xde, 0xc5]),
+        Some(&[0xb7, 0xee]),
+        Some(&[0xde, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xd8]),
+        Some(&[0xe6, 0xae]),
+        Some(&[0xbd, 0x42]),
+        Some(&[0xea, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0x73]),
+        None,
+        Some(&[0xc3, 0xa6]),
+        Some(&[0xc3, 0xa5]),
+        None,
+        None,
+        Some(&[0xc5, 0x7c]),
+        Some(&[0xa5, 0xd4]),
+        Some(&[0xcd, 0x73]),
+        None,
+        None,
+        Some(&[0xb2, 0xa8]),
+        Some(&[0xe2, 0xd9]),
+        Some(&[0xba, 0xc3]),
+        None,
+        None,
+        Some(&[0xcb, 0x6f]),
+        Some(&[0xcb, 0x70]),
+        None,
+        None,
+        Some(&[0xcd, 0x74]),
+        Some(&[0xaa, 0xb8]),
+        Some(&[0xaa, 0xb9]),
+        None,
+        None,
+        Some(&[0xaa, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xac, 0xcf]),
+        Some(&[0xac, 0xd0]),
+        Some(&[0xac, 0xcd]),
+        Some(&[0xac, 0xce]),
+        None,
+        Some(&[0xcf, 0xdc]),
+        None,
+        None,
+        Some(&[0xcf, 0xdd]),
+        Some(&[0xac, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xc3]),
+        None,
+        Some(&[0xaf, 0x68]),
+        Some(&[0xaf, 0x69]),
+        None,
+        Some(&[0xb2, 0xab]),
+        Some(&[0xd2, 0xc9]),
+        None,
+        Some(&[0xaf, 0x6e]),
+        Some(&[0xaf, 0x6c]),
+        Some(&[0xd2, 0xca]),
+        Some(&[0xd2, 0xc5]),
+        Some(&[0xaf, 0x6b]),
+        Some(&[0xaf, 0x6a]),
+        Some(&[0xaf, 0x65]),
+        Some(&[0xd2, 0xc8]),
+        Some(&[0xd2, 0xc7]),
+        Some(&[0xd2, 0xc4]),
+        Some(&[0xaf, 0x6d]),
+        None,
+        Some(&[0xd2, 0xc6]),
+        Some(&[0xaf, 0x66]),
+        None,
+        Some(&[0xaf, 0x67]),
+        None,
+        None,
+        Some(&[0xb2, 0xac]),
+        Some(&[0xd6, 0xa1]),
+        Some(&[0xd6, 0xa2]),
+        Some(&[0xb2, 0xad]),
+        Some(&[0xd6, 0x7c]),
+        Some(&[0xd6, 0x7e]),
+        Some(&[0xd6, 0xa4]),
+        Some(&[0xd6, 0xa3]),
+        Some(&[0xd6, 0x7d]),
+        None,
+        Some(&[0xb2, 0xa9]),
+        Some(&[0xb2, 0xaa]),
+        None,
+        Some(&[0xda, 0xb6]),
+        Some(&[0xb5, 0x6b]),
+        Some(&[0xb5, 0x6a]),
+        Some(&[0xda, 0xb0]),
+        Some(&[0xb5, 0x68]),
+        None,
+        Some(&[0xda, 0xb3]),
+        Some(&[0xb5, 0x6c]),
+        Some(&[0xda, 0xb4]),
+        Some(&[0xb5, 0x6d]),
+        Some(&[0xda, 0xb1]),
+        Some(&[0xb5, 0x67]),
+        Some(&[0xb5, 0x69]),
+        Some(&[0xda, 0xb5]),
+        None,
+        Some(&[0xda, 0xb2]),
+        Some(&[0xda, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xd2]),
+        None,
+        Some(&[0xde, 0xc7]),
+        Some(&[0xb7, 0xf0]),
+        Some(&[0xb7, 0xf3]),
+        Some(&[0xb7, 0xf2]),
+        Some(&[0xb7, 0xf7]),
+        Some(&[0xb7, 0xf6]),
+        Some(&[0xde, 0xd3]),
+        Some(&[0xde, 0xd1]),
+        Some(&[0xde, 0xca]),
+        Some(&[0xde, 0xce]),
+        Some(&[0xde, 0xcd]),
+        Some(&[0xb7, 0xf4]),
+        Some(&[0xde, 0xd0]),
+        Some(&[0xde, 0xcc]),
+        Some(&[0xde, 0xd4]),
+        Some(&[0xde, 0xcb]),
+        Some(&[0xb7, 0xf5]),
+        Some(&[0xb7, 0xef]),
+        Some(&[0xb7, 0xf1]),
+        None,
+        Some(&[0xde, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xdb]),
+        Some(&[0xba, 0xc7]),
+        Some(&[0xe2, 0xdf]),
+        Some(&[0xba, 0xc6]),
+        Some(&[0xe2, 0xdc]),
+        Some(&[0xba, 0xc5]),
+        None,
+        Some(&[0xde, 0xc8]),
+        Some(&[0xde, 0xcf]),
+        Some(&[0xe2, 0xde]),
+        None,
+        Some(&[0xba, 0xc8]),
+        Some(&[0xe2, 0xe0]),
+        Some(&[0xe2, 0xdd]),
+        Some(&[0xe2, 0xda]),
+        None,
+        None,
+        Some(&[0xe6, 0xb1]),
+        Some(&[0xe6, 0xb5]),
+        Some(&[0xe6, 0xb7]),
+        Some(&[0xe6, 0xb3]),
+        Some(&[0xe6, 0xb2]),
+        Some(&[0xe6, 0xb0]),
+        Some(&[0xbd, 0x45]),
+        Some(&[0xbd, 0x43]),
+        Some(&[0xbd, 0x48]),
+        Some(&[0xbd, 0x49]),
+        Some(&[0xe6, 0xb4]),
+        Some(&[0xbd, 0x46]),
+        Some(&[0xe6, 0xaf]),
+        Some(&[0xbd, 0x47]),
+        Some(&[0xba, 0xc4]),
+        Some(&[0xe6, 0xb6]),
+        Some(&[0xbd, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x6c]),
+        None,
+        Some(&[0xea, 0x6b]),
+        Some(&[0xea, 0x73]),
+        Some(&[0xea, 0x6d]),
+        Some(&[0xea, 0x72]),
+        Some(&[0xea, 0x6f]),
+        Some(&[0xbf, 0x60]),
+        Some(&[0xea, 0x71]),
+        None,
+        None,
+        Some(&[0xbf, 0x61]),
+        None,
+        Some(&[0xbf, 0x62]),
+        None,
+        Some(&[0xea, 0x70]),
+        Some(&[0xea, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0xf8]),
+        Some(&[0xed, 0x74]),
+        None,
+        None,
+        Some(&[0xc0, 0xf7]),
+        Some(&[0xed, 0x77]),
+        Some(&[0xed, 0x75]),
+        Some(&[0xed, 0x76]),
+        None,
+        None,
+        Some(&[0xc0, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0x4d]),
+        None,
+        Some(&[0xc2, 0xa1]),
+        Some(&[0xf0, 0x4e]),
+        None,
+        None,
+        Some(&[0xc2, 0x7d]),
+        Some(&[0xf0, 0x4f]),
+        Some(&[0xc2, 0x7e]),
+        Some(&[0xf0, 0x4c]),
+        Some(&[0xf0, 0x50]),
+        None,
+        Some(&[0xf0, 0x4a]),
+        None,
+        None,
+        Some(&[0xc3, 0xa7]),
+        Some(&[0xf2, 0x78]),
+        Some(&[0xc3, 0xa8]),
+        Some(&[0xc4, 0x6f]),
+        None,
+        Some(&[0xf0, 0x4b]),
+        Some(&[0xc4, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xee]),
+        Some(&[0xf5, 0xdf]),
+        None,
+        Some(&[0xc5, 0x7e]),
+        Some(&[0xf6, 0xf4]),
+        Some(&[0xc5, 0x7d]),
+        None,
+        Some(&[0xf7, 0xea]),
+        Some(&[0xc5, 0xf5]),
+        Some(&[0xc5, 0xf6]),
+        None,
+        None,
+        Some(&[0xf9, 0xcc]),
+        None,
+        None,
+        Some(&[0xac, 0xd1]),
+        Some(&[0xcf, 0xde]),
+        None,
+        Some(&[0xb5, 0x6e]),
+        Some(&[0xb5, 0x6f]),
+        Some(&[0xa5, 0xd5]),
+        Some(&[0xa6, 0xca]),
+        Some(&[0xca, 0x47]),
+        None,
+        Some(&[0xcb, 0x71]),
+        Some(&[0xa8, 0x6d]),
+        None,
+        Some(&[0xaa, 0xba]),
+        None,
+        Some(&[0xac, 0xd2]),
+        Some(&[0xac, 0xd3]),
+        Some(&[0xac, 0xd4]),
+        Some(&[0xd6, 0xa6]),
+        Some(&[0xd2, 0xcb]),
+        Some(&[0xaf, 0x6f]),
+        None,
+        None,
+        Some(&[0xb2, 0xae]),
+        Some(&[0xd6, 0xa5]),
+        None,
+        None,
+        Some(&[0xda, 0xb8]),
+        Some(&[0xb5, 0x71]),
+        None,
+        Some(&[0xda, 0xb7]),
+        Some(&[0xb5, 0x70]),
+        None,
+        None,
+        Some(&[0xde, 0xd5]),
+        Some(&[0xbd, 0x4a]),
+        Some(&[0xe6, 0xbb]),
+        Some(&[0xe6, 0xb8]),
+        Some(&[0xe6, 0xb9]),
+        Some(&[0xe6, 0xba]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0x78]),
+        None,
+        Some(&[0xf0, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0x71]),
+        Some(&[0xf4, 0x70]),
+        None,
+        Some(&[0xf6, 0xf5]),
+        Some(&[0xa5, 0xd6]),
+        Some(&[0xcd, 0x75]),
+        Some(&[0xaf, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x72]),
+        Some(&[0xde, 0xd6]),
+        None,
+        None,
+        Some(&[0xe2, 0xe1]),
+        None,
+        Some(&[0xbd, 0x4b]),
+        Some(&[0xea, 0x74]),
+        None,
+        Some(&[0xf0, 0x52]),
+        Some(&[0xf4, 0x72]),
+        Some(&[0xa5, 0xd7]),
+        None,
+        None,
+        Some(&[0xaa, 0xbb]),
+        Some(&[0xac, 0xd7]),
+        Some(&[0xcf, 0xdf]),
+        Some(&[0xac, 0xd8]),
+        Some(&[0xac, 0xd6]),
+        None,
+        Some(&[0xac, 0xd5]),
+        Some(&[0xd2, 0xcc]),
+        Some(&[0xaf, 0x71]),
+        None,
+        None,
+        Some(&[0xaf, 0x72]),
+        Some(&[0xaf, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xb0]),
+        Some(&[0xd6, 0xa7]),
+        Some(&[0xb2, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xb9]),
+        Some(&[0xb2, 0xb1]),
+        Some(&[0xb5, 0x73]),
+        Some(&[0xde, 0xd7]),
+        Some(&[0xb7, 0xf8]),
+        Some(&[0xb7, 0xf9]),
+        None,
+        Some(&[0xba, 0xc9]),
+        None,
+        Some(&[0xba, 0xca]),
+        Some(&[0xbd, 0x4c]),
+        Some(&[0xbf, 0x64]),
+        Some(&[0xea, 0x75]),
+        Some(&[0xbf, 0x63]),
+        None,
+        Some(&[0xed, 0x79]),
+        Some(&[0xc0, 0xfa]),
+        None,
+        Some(&[0xf0, 0x53]),
+        Some(&[0xf4, 0x73]),
+        Some(&[0xa5, 0xd8]),
+        Some(&[0xa8, 0x6e]),
+        Some(&[0xcd, 0x78]),
+        Some(&[0xcd, 0x77]),
+        Some(&[0xaa, 0xbc]),
+        Some(&[0xcd, 0x76]),
+        Some(&[0xaa, 0xbd]),
+        Some(&[0xcd, 0x79]),
+        None,
+        Some(&[0xcf, 0xe5]),
+        Some(&[0xac, 0xdb]),
+        Some(&[0xac, 0xda]),
+        Some(&[0xcf, 0xe7]),
+        Some(&[0xcf, 0xe6]),
+        Some(&[0xac, 0xdf]),
+        None,
+        Some(&[0xac, 0xde]),
+        None,
+        None,
+        Some(&[0xac, 0xd9]),
+        None,
+        Some(&[0xcf, 0xe1]),
+        Some(&[0xcf, 0xe2]),
+        Some(&[0xcf, 0xe3]),
+        None,
+        Some(&[0xac, 0xe0]),
+        Some(&[0xcf, 0xe0]),
+        Some(&[0xac, 0xdc]),
+        Some(&[0xcf, 0xe4]),
+        Some(&[0xac, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xcf]),
+        Some(&[0xd2, 0xd3]),
+        Some(&[0xd2, 0xd1]),
+        Some(&[0xd2, 0xd0]),
+        None,
+        Some(&[0xd2, 0xd4]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xd5]),
+        Some(&[0xd2, 0xd6]),
+        Some(&[0xd2, 0xce]),
+        None,
+        Some(&[0xd2, 0xcd]),
+        None,
+        Some(&[0xaf, 0x75]),
+        Some(&[0xaf, 0x76]),
+        None,
+        Some(&[0xd2, 0xd7]),
+        Some(&[0xd2, 0xd2]),
+        None,
+        Some(&[0xd6, 0xb0]),
+        None,
+        Some(&[0xd2, 0xd8]),
+        Some(&[0xaf, 0x77]),
+        Some(&[0xaf, 0x74]),
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xaa]),
+        None,
+        Some(&[0xd6, 0xa9]),
+        None,
+        Some(&[0xd6, 0xab]),
+        Some(&[0xd6, 0xac]),
+        Some(&[0xd6, 0xae]),
+        Some(&[0xd6, 0xad]),
+        Some(&[0xd6, 0xb2]),
+        Some(&[0xb2, 0xb5]),
+        Some(&[0xb2, 0xb2]),
+        Some(&[0xb2, 0xb6]),
+        Some(&[0xd6, 0xa8]),
+        Some(&[0xb2, 0xb7]),
+        Some(&[0xd6, 0xb1]),
+        Some(&[0xb2, 0xb4]),
+        Some(&[0xd6, 0xaf]),
+        Some(&[0xb2, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xbc]),
+        Some(&[0xda, 0xbe]),
+        Some(&[0xda, 0xba]),
+        Some(&[0xda, 0xbb]),
+        None,
+        None,
+        Some(&[0xda, 0xbf]),
+        Some(&[0xda, 0xc1]),
+        Some(&[0xda, 0xc2]),
+        Some(&[0xda, 0xbd]),
+        Some(&[0xda, 0xc0]),
+        Some(&[0xb5, 0x74]),
+        None,
+        None,
+        Some(&[0xde, 0xdb]),
+        None,
+        Some(&[0xde, 0xe0]),
+        Some(&[0xde, 0xd8]),
+        Some(&[0xde, 0xdc]),
+        None,
+        None,
+        Some(&[0xde, 0xe1]),
+        Some(&[0xde, 0xdd]),
+        Some(&[0xb7, 0xfa]),
+        Some(&[0xb8, 0x43]),
+        None,
+        Some(&[0xb7, 0xfd]),
+        Some(&[0xde, 0xd9]),
+        Some(&[0xde, 0xda]),
+        Some(&[0xba, 0xce]),
+        Some(&[0xb8, 0x46]),
+        Some(&[0xb7, 0xfe]),
+        None,
+        Some(&[0xb8, 0x44]),
+        Some(&[0xb7, 0xfc]),
+        Some(&[0xde, 0xdf]),
+        Some(&[0xb8, 0x45]),
+        Some(&[0xde, 0xde]),
+        Some(&[0xb8, 0x41]),
+        Some(&[0xb7, 0xfb]),
+        Some(&[0xb8, 0x42]),
+        Some(&[0xde, 0xe2]),
+        Some(&[0xe2, 0xe6]),
+        Some(&[0xe2, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0x40]),
+        None,
+        None,
+        Some(&[0xe2, 0xe3]),
+        Some(&[0xba, 0xcc]),
+        Some(&[0xe2, 0xe9]),
+        Some(&[0xba, 0xcd]),
+        Some(&[0xe2, 0xe7]),
+        Some(&[0xe2, 0xe2]),
+        Some(&[0xe2, 0xe5]),
+        Some(&[0xe2, 0xea]),
+        Some(&[0xba, 0xcb]),
+        Some(&[0xe2, 0xe4]),
+        None,
+        Some(&[0xbd, 0x4e]),
+        Some(&[0xe6, 0xbf]),
+        Some(&[0xe6, 0xbe]),
+        None,
+        Some(&[0xbd, 0x51]),
+        Some(&[0xbd, 0x4f]),
+        Some(&[0xe6, 0xbc]),
+        Some(&[0xbd, 0x4d]),
+        Some(&[0xe6, 0xbd]),
+        None,
+        Some(&[0xbd, 0x50]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0x7d]),
+        None,
+        Some(&[0xea, 0xa1]),
+        None,
+        Some(&[0xea, 0x7e]),
+        Some(&[0xea, 0x76]),
+        Some(&[0xea, 0x7a]),
+        Some(&[0xea, 0x79]),
+        Some(&[0xea, 0x77]),
+        Some(&[0xbf, 0x66]),
+        Some(&[0xbf, 0x67]),
+        Some(&[0xbf, 0x65]),
+        Some(&[0xea, 0x78]),
+        Some(&[0xea, 0x7b]),
+        Some(&[0xea, 0x7c]),
+        None,
+        Some(&[0xbf, 0x68]),
+        None,
+        Some(&[0xc1, 0x40]),
+        Some(&[0xed, 0xa3]),
+        None,
+        Some(&[0xc0, 0xfc]),
+        Some(&[0xed, 0x7b]),
+        Some(&[0xc0, 0xfe]),
+        Some(&[0xc1, 0x41]),
+        None,
+        None,
+        Some(&[0xc0, 0xfd]),
+        Some(&[0xed, 0xa2]),
+        Some(&[0xed, 0x7c]),
+        Some(&[0xc0, 0xfb]),
+        Some(&[0xed, 0xa1]),
+        Some(&[0xed, 0x7a]),
+        Some(&[0xed, 0x7e]),
+        Some(&[0xed, 0x7d]),
+        None,
+        None,
+        Some(&[0xf0, 0x55]),
+        Some(&[0xc2, 0xa4]),
+        Some(&[0xc2, 0xa5]),
+        Some(&[0xc2, 0xa2]),
+        None,
+        Some(&[0xc2, 0xa3]),
+        None,
+        None,
+        Some(&[0xf0, 0x54]),
+        None,
+        Some(&[0xf2, 0x7b]),
+        None,
+        None,
+        Some(&[0xc3, 0xa9]),
+        None,
+        Some(&[0xf2, 0x79]),
+        Some(&[0xf2, 0x7a]),
+        None,
+        Some(&[0xf4, 0x74]),
+        Some(&[0xf4, 0x77]),
+        Some(&[0xf4, 0x75]),
+        Some(&[0xf4, 0x76]),
+        Some(&[0xf5, 0xe0]),
+        None,
+        None,
+        Some(&[0xc4, 0xef]),
+        Some(&[0xf7, 0xeb]),
+        Some(&[0xf8, 0xb4]),
+        None,
+        Some(&[0xc5, 0xf7]),
+        Some(&[0xf8, 0xf8]),
+        Some(&[0xf8, 0xf9]),
+        Some(&[0xc6, 0x66]),
+        Some(&[0xa5, 0xd9]),
+        Some(&[0xac, 0xe1]),
+        None,
+        Some(&[0xda, 0xc3]),
+        None,
+        Some(&[0xde, 0xe3]),
+        None,
+        Some(&[0xa5, 0xda]),
+        Some(&[0xa8, 0x6f]),
+        None,
+        Some(&[0xaa, 0xbe]),
+        None,
+        Some(&[0xcf, 0xe8]),
+        Some(&[0xcf, 0xe9]),
+        Some(&[0xaf, 0x78]),
+        None,
+        None,
+        Some(&[0xda, 0xc4]),
+        Some(&[0xb5, 0x75]),
+        Some(&[0xb8, 0x47]),
+        Some(&[0xc1, 0x42]),
+        Some(&[0xed, 0xa4]),
+        Some(&[0xf2, 0x7c]),
+        Some(&[0xf4, 0x78]),
+        Some(&[0xa5, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xa1]),
+        Some(&[0xcd, 0x7a]),
+        Some(&[0xcd, 0x7c]),
+        Some(&[0xcd, 0x7e]),
+        Some(&[0xcd, 0x7d]),
+        Some(&[0xcd, 0x7b]),
+        Some(&[0xaa, 0xbf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xac, 0xe2]),
+        Some(&[0xcf, 0xf2]),
+        None,
+        Some(&[0xcf, 0xed]),
+        Some(&[0xcf, 0xea]),
+        None,
+        None,
+        Some(&[0xcf, 0xf1]),
+        None,
+        None,
+        Some(&[0xac, 0xe4]),
+        Some(&[0xac, 0xe5]),
+        Some(&[0xcf, 0xf0]),
+        Some(&[0xcf, 0xef]),
+        Some(&[0xcf, 0xee]),
+        Some(&[0xcf, 0xeb]),
+        Some(&[0xcf, 0xec]),
+        Some(&[0xcf, 0xf3]),
+        Some(&[0xac, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0x7c]),
+        None,
+        Some(&[0xaf, 0xa4]),
+        Some(&[0xaf, 0xa3]),
+        Some(&[0xd2, 0xe1]),
+        Some(&[0xd2, 0xdb]),
+        Some(&[0xd2, 0xd9]),
+        None,
+        Some(&[0xaf, 0xa1]),
+        Some(&[0xd6, 0xb9]),
+        Some(&[0xaf, 0x7a]),
+        Some(&[0xd2, 0xde]),
+        Some(&[0xd2, 0xe2]),
+        Some(&[0xd2, 0xe4]),
+        Some(&[0xd2, 0xe0]),
+        Some(&[0xd2, 0xda]),
+        Some(&[0xaf, 0xa2]),
+        Some(&[0xd2, 0xdf]),
+        Some(&[0xd2, 0xdd]),
+        Some(&[0xaf, 0x79]),
+        Some(&[0xd2, 0xe5]),
+        Some(&[0xaf, 0xa5]),
+        Some(&[0xd2, 0xe3]),
+        Some(&[0xaf, 0x7d]),
+        Some(&[0xd2, 0xdc]),
+        None,
+        Some(&[0xaf, 0x7e]),
+        Some(&[0xaf, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xb9]),
+        None,
+        Some(&[0xd6, 0xba]),
+        None,
+        None,
+        Some(&[0xd6, 0xb3]),
+        Some(&[0xd6, 0xb5]),
+        Some(&[0xd6, 0xb7]),
+        None,
+        Some(&[0xd6, 0xb8]),
+        Some(&[0xd6, 0xb6]),
+        Some(&[0xb2, 0xba]),
+        None,
+        Some(&[0xd6, 0xbb]),
+        None,
+        Some(&[0xd6, 0xb4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xc8]),
+        Some(&[0xb5, 0x76]),
+        Some(&[0xda, 0xd0]),
+        None,
+        Some(&[0xda, 0xc5]),
+        None,
+        Some(&[0xda, 0xd1]),
+        None,
+        Some(&[0xda, 0xc6]),
+        Some(&[0xda, 0xc7]),
+        None,
+        None,
+        Some(&[0xda, 0xcf]),
+        Some(&[0xda, 0xce]),
+        Some(&[0xda, 0xcb]),
+        Some(&[0xb2, 0xb8]),
+        Some(&[0xb5, 0x77]),
+        Some(&[0xda, 0xc9]),
+        Some(&[0xda, 0xcc]),
+        Some(&[0xb5, 0x78]),
+        Some(&[0xda, 0xcd]),
+        Some(&[0xda, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xee]),
+        None,
+        Some(&[0xde, 0xf2]),
+        Some(&[0xb8, 0x4e]),
+        None,
+        Some(&[0xe2, 0xf0]),
+        Some(&[0xb8, 0x51]),
+        Some(&[0xde, 0xf0]),
+        Some(&[0xf9, 0xd6]),
+        None,
+        Some(&[0xde, 0xed]),
+        Some(&[0xde, 0xe8]),
+        Some(&[0xde, 0xea]),
+        Some(&[0xde, 0xeb]),
+        Some(&[0xde, 0xe4]),
+        None,
+        Some(&[0xb8, 0x4d]),
+        None,
+        None,
+        Some(&[0xb8, 0x4c]),
+        None,
+        Some(&[0xb8, 0x48]),
+        Some(&[0xde, 0xe7]),
+        None,
+        Some(&[0xb8, 0x4f]),
+        None,
+        Some(&[0xb8, 0x50]),
+        Some(&[0xde, 0xe6]),
+        Some(&[0xde, 0xe9]),
+        Some(&[0xde, 0xf1]),
+        Some(&[0xb8, 0x4a]),
+        Some(&[0xb8, 0x4b]),
+        Some(&[0xde, 0xef]),
+        Some(&[0xde, 0xe5]),
+        None,
+        None,
+        None,
+        Some(&[0xe2, 0xf2]),
+        Some(&[0xba, 0xd0]),
+        Some(&[0xe2, 0xf4]),
+        Some(&[0xde, 0xec]),
+        Some(&[0xe2, 0xf6]),
+        Some(&[0xba, 0xd4]),
+        Some(&[0xe2, 0xf7]),
+        Some(&[0xe2, 0xf3]),
+        None,
+        Some(&[0xba, 0xd1]),
+        Some(&[0xe2, 0xef]),
+        Some(&[0xba, 0xd3]),
+        Some(&[0xe2, 0xec]),
+        Some(&[0xe2, 0xf1]),
+        Some(&[0xe2, 0xf5]),
+        Some(&[0xe2, 0xee]),
+        None,
+        None,
+        Some(&[0xb8, 0x49]),
+        None,
+        Some(&[0xe2, 0xeb]),
+        Some(&[0xba, 0xd2]),
+        Some(&[0xe2, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0x54]),
+        Some(&[0xe6, 0xc1]),
+        Some(&[0xbd, 0x58]),
+        None,
+        Some(&[0xbd, 0x56]),
+        None,
+        None,
+        Some(&[0xba, 0xcf]),
+        None,
+        Some(&[0xe6, 0xc8]),
+        Some(&[0xe6, 0xc9]),
+        Some(&[0xbd, 0x53]),
+        None,
+        None,
+        Some(&[0xe6, 0xc7]),
+        Some(&[0xe6, 0xca]),
+        Some(&[0xbd, 0x55]),
+        Some(&[0xbd, 0x52]),
+        Some(&[0xe6, 0xc3]),
+        Some(&[0xe6, 0xc0]),
+        Some(&[0xe6, 0xc5]),
+        Some(&[0xe6, 0xc2]),
+        Some(&[0xbd, 0x59]),
+        Some(&[0xe6, 0xc4]),
+        None,
+        None,
+        Some(&[0xe6, 0xc6]),
+        Some(&[0xbd, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0x6a]),
+        Some(&[0xea, 0xa8]),
+        None,
+        Some(&[0xea, 0xa2]),
+        Some(&[0xea, 0xa6]),
+        Some(&[0xea, 0xac]),
+        Some(&[0xea, 0xad]),
+        Some(&[0xea, 0xa9]),
+        Some(&[0xea, 0xaa]),
+        Some(&[0xea, 0xa7]),
+        None,
+        Some(&[0xea, 0xa4]),
+        None,
+        Some(&[0xbf, 0x6c]),
+        Some(&[0xbf, 0x69]),
+        Some(&[0xea, 0xa3]),
+        Some(&[0xea, 0xa5]),
+        None,
+        Some(&[0xbf, 0x6b]),
+        Some(&[0xea, 0xab]),
+        None,
+        Some(&[0xc1, 0x46]),
+        None,
+        None,
+        Some(&[0xed, 0xaa]),
+        Some(&[0xed, 0xa5]),
+        Some(&[0xc1, 0x45]),
+        None,
+        None,
+        Some(&[0xc1, 0x43]),
+        None,
+        Some(&[0xed, 0xac]),
+        Some(&[0xc1, 0x44]),
+        Some(&[0xed, 0xa8]),
+        Some(&[0xed, 0xa9]),
+        Some(&[0xed, 0xa6]),
+        Some(&[0xed, 0xad]),
+        Some(&[0xf0, 0x56]),
+        None,
+        Some(&[0xc1, 0x47]),
+        Some(&[0xed, 0xa7]),
+        None,
+        Some(&[0xed, 0xae]),
+        Some(&[0xed, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0x5a]),
+        None,
+        None,
+        Some(&[0xf0, 0x57]),
+        None,
+        Some(&[0xc2, 0xa6]),
+        None,
+        Some(&[0xf0, 0x5b]),
+        Some(&[0xf0, 0x5d]),
+        Some(&[0xf0, 0x5c]),
+        Some(&[0xf0, 0x58]),
+        Some(&[0xf0, 0x59]),
+        None,
+        None,
+        Some(&[0xf2, 0xa3]),
+        None,
+        Some(&[0xc3, 0xaa]),
+        None,
+        Some(&[0xf2, 0x7e]),
+        Some(&[0xf2, 0xa2]),
+        Some(&[0xf2, 0x7d]),
+        Some(&[0xf2, 0xa4]),
+        None,
+        None,
+        Some(&[0xf2, 0xa1]),
+        None,
+        Some(&[0xf4, 0x7a]),
+        Some(&[0xf4, 0x7d]),
+        Some(&[0xf4, 0x79]),
+        Some(&[0xc4, 0x71]),
+        Some(&[0xf4, 0x7b]),
+        Some(&[0xf4, 0x7c]),
+        Some(&[0xf4, 0x7e]),
+        Some(&[0xc4, 0x72]),
+        Some(&[0xc4, 0x74]),
+        Some(&[0xc4, 0x73]),
+        Some(&[0xf5, 0xe1]),
+        None,
+        Some(&[0xf5, 0xe3]),
+        None,
+        Some(&[0xf5, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xf6]),
+        None,
+        None,
+        Some(&[0xf8, 0xb5]),
+        Some(&[0xf8, 0xfa]),
+        Some(&[0xa5, 0xdc]),
+        None,
+        None,
+        Some(&[0xcb, 0x72]),
+        Some(&[0xaa, 0xc0]),
+        Some(&[0xcd, 0xa3]),
+        Some(&[0xaa, 0xc1]),
+        Some(&[0xaa, 0xc2]),
+        Some(&[0xcd, 0xa2]),
+        None,
+        Some(&[0xcf, 0xf8]),
+        Some(&[0xcf, 0xf7]),
+        Some(&[0xac, 0xe6]),
+        Some(&[0xac, 0xe9]),
+        Some(&[0xac, 0xe8]),
+        Some(&[0xac, 0xe7]),
+        Some(&[0xcf, 0xf4]),
+        Some(&[0xcf, 0xf6]),
+        Some(&[0xcf, 0xf5]),
+        None,
+        None,
+        Some(&[0xd2, 0xe8]),
+        Some(&[0xaf, 0xa7]),
+        Some(&[0xd2, 0xec]),
+        Some(&[0xd2, 0xeb]),
+        Some(&[0xd2, 0xea]),
+        Some(&[0xd2, 0xe6]),
+        Some(&[0xaf, 0xa6]),
+        Some(&[0xaf, 0xaa]),
+        Some(&[0xaf, 0xad]),
+        None,
+        None,
+        Some(&[0xaf, 0xae]),
+        Some(&[0xd2, 0xe7]),
+        Some(&[0xd2, 0xe9]),
+        Some(&[0xaf, 0xac]),
+        Some(&[0xaf, 0xab]),
+        Some(&[0xaf, 0xa9]),
+        Some(&[0xaf, 0xa8]),
+        Some(&[0xd6, 0xc2]),
+        None,
+        Some(&[0xd6, 0xc0]),
+        Some(&[0xd6, 0xbc]),
+        Some(&[0xb2, 0xbb]),
+        None,
+        Some(&[0xd6, 0xbd]),
+        Some(&[0xb2, 0xbc]),
+        Some(&[0xd6, 0xbe]),
+        Some(&[0xd6, 0xbf]),
+        Some(&[0xd6, 0xc1]),
+        None,
+        Some(&[0xb2, 0xbd]),
+        None,
+        None,
+        Some(&[0xda, 0xd5]),
+        None,
+        Some(&[0xda, 0xd4]),
+        Some(&[0xda, 0xd3]),
+        Some(&[0xda, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xde, 0xf6]),
+        Some(&[0xb8, 0x52]),
+        None,
+        Some(&[0xde, 0xf3]),
+        Some(&[0xde, 0xf5]),
+        None,
+        Some(&[0xb8, 0x53]),
+        None,
+        Some(&[0xb8, 0x54]),
+        Some(&[0xde, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x41]),
+        None,
+        Some(&[0xe2, 0xf9]),
+        Some(&[0xe2, 0xfa]),
+        None,
+        Some(&[0xba, 0xd7]),
+        Some(&[0xba, 0xd5]),
+        Some(&[0xba, 0xd6]),
+        Some(&[0xe3, 0x43]),
+        None,
+        Some(&[0xe3, 0x42]),
+        Some(&[0xe2, 0xfe]),
+        Some(&[0xe2, 0xfd]),
+        Some(&[0xe2, 0xfc]),
+        Some(&[0xe2, 0xfb]),
+        Some(&[0xe3, 0x40]),
+        Some(&[0xe2, 0xf8]),
+        None,
+        Some(&[0xe6, 0xcb]),
+        Some(&[0xe6, 0xd0]),
+        Some(&[0xe6, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xcd]),
+        Some(&[0xe6, 0xcc]),
+        Some(&[0xe6, 0xcf]),
+        None,
+        Some(&[0xea, 0xae]),
+        None,
+        Some(&[0xbf, 0x6d]),
+        Some(&[0xc1, 0x48]),
+        Some(&[0xed, 0xb0]),
+        None,
+        Some(&[0xc1, 0x49]),
+        Some(&[0xed, 0xaf]),
+        Some(&[0xf0, 0x5f]),
+        Some(&[0xf0, 0x5e]),
+        Some(&[0xc2, 0xa7]),
+        None,
+        Some(&[0xf2, 0xa5]),
+        Some(&[0xc3, 0xab]),
+        Some(&[0xf4, 0xa1]),
+        Some(&[0xc5, 0xa1]),
+        Some(&[0xf6, 0xf7]),
+        None,
+        Some(&[0xf8, 0xb7]),
+        Some(&[0xf8, 0xb6]),
+        Some(&[0xc9, 0xa8]),
+        Some(&[0xac, 0xea]),
+        Some(&[0xac, 0xeb]),
+        Some(&[0xd6, 0xc3]),
+        None,
+        Some(&[0xb8, 0x56]),
+        Some(&[0xa5, 0xdd]),
+        Some(&[0xa8, 0x72]),
+        Some(&[0xa8, 0x71]),
+        Some(&[0xa8, 0x70]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xa4]),
+        None,
+        None,
+        Some(&[0xaa, 0xc4]),
+        Some(&[0xaa, 0xc3]),
+        None,
+        Some(&[0xac, 0xee]),
+        None,
+        Some(&[0xcf, 0xfa]),
+        Some(&[0xcf, 0xfd]),
+        Some(&[0xcf, 0xfb]),
+        None,
+        Some(&[0xac, 0xec]),
+        Some(&[0xac, 0xed]),
+        None,
+        None,
+        Some(&[0xcf, 0xf9]),
+        Some(&[0xcf, 0xfc]),
+        None,
+        Some(&[0xaf, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xf3]),
+        Some(&[0xd2, 0xf5]),
+        Some(&[0xd2, 0xf4]),
+        Some(&[0xaf, 0xb2]),
+        Some(&[0xd2, 0xef]),
+        None,
+        None,
+        Some(&[0xaf, 0xb0]),
+        Some(&[0xaf, 0xaf]),
+        None,
+        Some(&[0xaf, 0xb3]),
+        Some(&[0xaf, 0xb1]),
+        None,
+        Some(&[0xaf, 0xb4]),
+        Some(&[0xd2, 0xf2]),
+        Some(&[0xd2, 0xed]),
+        Some(&[0xd2, 0xee]),
+        Some(&[0xd2, 0xf1]),
+        Some(&[0xd2, 0xf0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xc6]),
+        Some(&[0xd6, 0xc7]),
+        Some(&[0xd6, 0xc5]),
+        None,
+        Some(&[0xd6, 0xc4]),
+        Some(&[0xb2, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0x7d]),
+        None,
+        Some(&[0xda, 0xd6]),
+        Some(&[0xda, 0xd8]),
+        Some(&[0xda, 0xda]),
+        Some(&[0xb5, 0x7c]),
+        None,
+        None,
+        Some(&[0xb5, 0x7a]),
+        None,
+        Some(&[0xda, 0xd7]),
+        Some(&[0xb5, 0x7b]),
+        Some(&[0xda, 0xd9]),
+        Some(&[0xb5, 0x79]),
+        None,
+        None,
+        Some(&[0xdf, 0x41]),
+        Some(&[0xde, 0xf7]),
+        Some(&[0xde, 0xfa]),
+        Some(&[0xde, 0xfe]),
+        Some(&[0xb8, 0x5a]),
+        Some(&[0xde, 0xfc]),
+        None,
+        Some(&[0xde, 0xfb]),
+        Some(&[0xde, 0xf8]),
+        Some(&[0xde, 0xf9]),
+        Some(&[0xb8, 0x58]),
+        Some(&[0xdf, 0x40]),
+        Some(&[0xb8, 0x57]),
+        None,
+        Some(&[0xb8, 0x5c]),
+        Some(&[0xb8, 0x5b]),
+        Some(&[0xb8, 0x59]),
+        None,
+        Some(&[0xde, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x49]),
+        None,
+        Some(&[0xe3, 0x48]),
+        None,
+        None,
+        Some(&[0xe3, 0x44]),
+        None,
+        None,
+        Some(&[0xba, 0xd8]),
+        Some(&[0xe3, 0x47]),
+        Some(&[0xe3, 0x46]),
+        Some(&[0xba, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0x5e]),
+        None,
+        Some(&[0xe6, 0xd2]),
+        None,
+        Some(&[0xbd, 0x5f]),
+        Some(&[0xbd, 0x5b]),
+        Some(&[0xbd, 0x5d]),
+        None,
+        Some(&[0xbd, 0x5a]),
+        Some(&[0xbd, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xaf]),
+        None,
+        Some(&[0xbf, 0x70]),
+        Some(&[0xea, 0xb1]),
+        Some(&[0xea, 0xb0]),
+        None,
+        Some(&[0xe3, 0x45]),
+        Some(&[0xbf, 0x72]),
+        Some(&[0xbf, 0x71]),
+        Some(&[0xbf, 0x6e]),
+        Some(&[0xbf, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xb5]),
+        None,
+        Some(&[0xed, 0xb3]),
+        Some(&[0xc1, 0x4a]),
+        Some(&[0xed, 0xb4]),
+        None,
+        Some(&[0xed, 0xb6]),
+        Some(&[0xed, 0xb2]),
+        Some(&[0xed, 0xb1]),
+        None,
+        None,
+        Some(&[0xf0, 0x60]),
+        Some(&[0xc2, 0xaa]),
+        Some(&[0xc2, 0xa8]),
+        Some(&[0xc2, 0xa9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xa6]),
+        Some(&[0xf2, 0xa7]),
+        Some(&[0xc3, 0xad]),
+        None,
+        Some(&[0xc3, 0xac]),
+        Some(&[0xf4, 0xa3]),
+        Some(&[0xf4, 0xa4]),
+        Some(&[0xf4, 0xa2]),
+        None,
+        Some(&[0xf6, 0xf8]),
+        Some(&[0xf6, 0xf9]),
+        None,
+        None,
+        Some(&[0xa5, 0xde]),
+        Some(&[0xca, 0x48]),
+        Some(&[0xa8, 0x73]),
+        None,
+        Some(&[0xcd, 0xa5]),
+        Some(&[0xaa, 0xc6]),
+        Some(&[0xaa, 0xc5]),
+        Some(&[0xcd, 0xa6]),
+        None,
+        None,
+        Some(&[0xd0, 0x40]),
+        Some(&[0xac, 0xef]),
+        Some(&[0xcf, 0xfe]),
+        Some(&[0xac, 0xf0]),
+        None,
+        None,
+        Some(&[0xaf, 0xb6]),
+        Some(&[0xd2, 0xf8]),
+        Some(&[0xd2, 0xf6]),
+        Some(&[0xd2, 0xfc]),
+        Some(&[0xaf, 0xb7]),
+        Some(&[0xd2, 0xf7]),
+        Some(&[0xd2, 0xfb]),
+        Some(&[0xd2, 0xf9]),
+        Some(&[0xd2, 0xfa]),
+        None,
+        None,
+        Some(&[0xd6, 0xc8]),
+        Some(&[0xd6, 0xca]),
+        None,
+        Some(&[0xb2, 0xbf]),
+        None,
+        Some(&[0xd6, 0xc9]),
+        Some(&[0xb2, 0xc0]),
+        Some(&[0xb5, 0xa2]),
+        Some(&[0xb5, 0xa1]),
+        Some(&[0xb5, 0x7e]),
+        Some(&[0xda, 0xdb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x44]),
+        Some(&[0xb8, 0x5d]),
+        Some(&[0xb8, 0x5e]),
+        None,
+        Some(&[0xdf, 0x43]),
+        Some(&[0xdf, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x4a]),
+        Some(&[0xba, 0xdb]),
+        Some(&[0xba, 0xda]),
+        Some(&[0xe3, 0x4b]),
+        Some(&[0xe3, 0x4c]),
+        None,
+        Some(&[0xbd, 0x61]),
+        Some(&[0xbd, 0x60]),
+        None,
+        Some(&[0xea, 0xb5]),
+        Some(&[0xe6, 0xd3]),
+        Some(&[0xe6, 0xd5]),
+        Some(&[0xe6, 0xd4]),
+        Some(&[0xea, 0xb4]),
+        Some(&[0xea, 0xb2]),
+        Some(&[0xea, 0xb6]),
+        Some(&[0xea, 0xb3]),
+        None,
+        Some(&[0xbf, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xb7]),
+        Some(&[0xc1, 0x4b]),
+        Some(&[0xed, 0xb8]),
+        Some(&[0xed, 0xb9]),
+        None,
+        None,
+        Some(&[0xc2, 0xab]),
+        Some(&[0xc2, 0xac]),
+        None,
+        Some(&[0xc4, 0x75]),
+        None,
+        None,
+        Some(&[0xc5, 0xd1]),
+        Some(&[0xa5, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd2, 0xfd]),
+        Some(&[0xaf, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xba]),
+        Some(&[0xb3, 0xb9]),
+        None,
+        None,
+        Some(&[0xb5, 0xa4]),
+        Some(&[0xda, 0xdd]),
+        Some(&[0xb5, 0xa3]),
+        Some(&[0xda, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x45]),
+        None,
+        Some(&[0xba, 0xdc]),
+        Some(&[0xe3, 0x4d]),
+        Some(&[0xba, 0xdd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0x76]),
+        Some(&[0xf4, 0xa5]),
+        None,
+        Some(&[0xa6, 0xcb]),
+        Some(&[0xaa, 0xc7]),
+        Some(&[0xcd, 0xa7]),
+        None,
+        Some(&[0xac, 0xf2]),
+        None,
+        Some(&[0xac, 0xf1]),
+        Some(&[0xd0, 0x42]),
+        Some(&[0xd0, 0x43]),
+        None,
+        None,
+        Some(&[0xd3, 0x40]),
+        Some(&[0xd3, 0x42]),
+        Some(&[0xaf, 0xb9]),
+        None,
+        Some(&[0xd3, 0x44]),
+        Some(&[0xd3, 0x47]),
+        Some(&[0xd3, 0x45]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0x46]),
+        Some(&[0xd3, 0x43]),
+        Some(&[0xd2, 0xfe]),
+        Some(&[0xaf, 0xba]),
+        Some(&[0xd3, 0x48]),
+        Some(&[0xd3, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xd3]),
+        Some(&[0xb2, 0xc6]),
+        Some(&[0xd6, 0xdc]),
+        Some(&[0xb2, 0xc3]),
+        None,
+        Some(&[0xd6, 0xd5]),
+        Some(&[0xb2, 0xc7]),
+        None,
+        Some(&[0xb2, 0xc1]),
+        None,
+        Some(&[0xd6, 0xd0]),
+        Some(&[0xd6, 0xdd]),
+        Some(&[0xd6, 0xd1]),
+        Some(&[0xd6, 0xce]),
+        Some(&[0xb2, 0xc5]),
+        None,
+        Some(&[0xb2, 0xc2]),
+        None,
+        Some(&[0xd6, 0xd4]),
+        Some(&[0xd6, 0xd7]),
+        Some(&[0xb2, 0xc4]),
+        Some(&[0xd6, 0xd8]),
+        Some(&[0xb2, 0xc8]),
+        Some(&[0xd6, 0xd9]),
+        Some(&[0xd6, 0xcf]),
+        Some(&[0xd6, 0xd6]),
+        Some(&[0xd6, 0xda]),
+        Some(&[0xd6, 0xd2]),
+        Some(&[0xd6, 0xcd]),
+        Some(&[0xd6, 0xcb]),
+        None,
+        None,
+        Some(&[0xd6, 0xdb]),
+        None,
+        None,
+        Some(&[0xda, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xe0]),
+        Some(&[0xda, 0xe6]),
+        Some(&[0xb5, 0xa7]),
+        Some(&[0xd6, 0xcc]),
+        Some(&[0xda, 0xe1]),
+        Some(&[0xb5, 0xa5]),
+        Some(&[0xda, 0xde]),
+        Some(&[0xb5, 0xac]),
+        Some(&[0xda, 0xe2]),
+        Some(&[0xb5, 0xab]),
+        Some(&[0xda, 0xe3]),
+        Some(&[0xb5, 0xad]),
+        Some(&[0xb5, 0xa8]),
+        Some(&[0xb5, 0xae]),
+        Some(&[0xb5, 0xa9]),
+        None,
+        Some(&[0xb5, 0xaa]),
+        None,
+        Some(&[0xb5, 0xa6]),
+        None,
+        Some(&[0xda, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0x61]),
+        Some(&[0xdf, 0x50]),
+        None,
+        Some(&[0xdf, 0x53]),
+        Some(&[0xdf, 0x47]),
+        Some(&[0xdf, 0x4c]),
+        Some(&[0xdf, 0x46]),
+        Some(&[0xb8, 0x63]),
+        None,
+        Some(&[0xdf, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x48]),
+        Some(&[0xb8, 0x62]),
+        None,
+        Some(&[0xdf, 0x4f]),
+        Some(&[0xdf, 0x4e]),
+        Some(&[0xdf, 0x4b]),
+        Some(&[0xdf, 0x4d]),
+        Some(&[0xdf, 0x49]),
+        Some(&[0xba, 0xe1]),
+        Some(&[0xdf, 0x52]),
+        Some(&[0xb8, 0x5f]),
+        Some(&[0xdf, 0x51]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0x5d]),
+        None,
+        Some(&[0xba, 0xe8]),
+        Some(&[0xe3, 0x58]),
+        None,
+        Some(&[0xba, 0xe7]),
+        Some(&[0xe3, 0x4e]),
+        None,
+        Some(&[0xe3, 0x50]),
+        Some(&[0xba, 0xe0]),
+        Some(&[0xe3, 0x55]),
+        Some(&[0xe3, 0x54]),
+        Some(&[0xe3, 0x57]),
+        Some(&[0xba, 0xe5]),
+        Some(&[0xe3, 0x52]),
+        Some(&[0xe3, 0x51]),
+        None,
+        None,
+        Some(&[0xba, 0xe4]),
+        Some(&[0xba, 0xdf]),
+        Some(&[0xe3, 0x53]),
+        Some(&[0xba, 0xe2]),
+        Some(&[0xe3, 0x59]),
+        Some(&[0xe3, 0x5b]),
+        None,
+        Some(&[0xe3, 0x56]),
+        Some(&[0xe3, 0x4f]),
+        Some(&[0xba, 0xe3]),
+        None,
+        None,
+        Some(&[0xbd, 0x69]),
+        Some(&[0xba, 0xde]),
+        None,
+        None,
+        Some(&[0xe3, 0x5c]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd9]),
+        Some(&[0xbd, 0x62]),
+        None,
+        Some(&[0xe6, 0xdb]),
+        None,
+        Some(&[0xbd, 0x63]),
+        None,
+        None,
+        Some(&[0xbd, 0x65]),
+        Some(&[0xe6, 0xde]),
+        None,
+        Some(&[0xe6, 0xd6]),
+        Some(&[0xba, 0xe6]),
+        Some(&[0xe6, 0xdc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xd8]),
+        None,
+        Some(&[0xb8, 0x60]),
+        Some(&[0xbd, 0x68]),
+        None,
+        None,
+        Some(&[0xbd, 0x64]),
+        None,
+        Some(&[0xbd, 0x66]),
+        Some(&[0xbd, 0x67]),
+        None,
+        Some(&[0xbf, 0x76]),
+        Some(&[0xe6, 0xdd]),
+        Some(&[0xe6, 0xd7]),
+        Some(&[0xbd, 0x6a]),
+        None,
+        Some(&[0xe6, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xc0]),
+        Some(&[0xea, 0xbb]),
+        None,
+        None,
+        Some(&[0xea, 0xc5]),
+        Some(&[0xbf, 0x74]),
+        Some(&[0xea, 0xbd]),
+        Some(&[0xbf, 0x78]),
+        Some(&[0xea, 0xc3]),
+        Some(&[0xea, 0xba]),
+        Some(&[0xea, 0xb7]),
+        Some(&[0xea, 0xc6]),
+        Some(&[0xc1, 0x51]),
+        Some(&[0xbf, 0x79]),
+        Some(&[0xea, 0xc2]),
+        Some(&[0xea, 0xb8]),
+        Some(&[0xbf, 0x77]),
+        Some(&[0xea, 0xbc]),
+        Some(&[0xbf, 0x7b]),
+        Some(&[0xea, 0xb9]),
+        Some(&[0xea, 0xbe]),
+        Some(&[0xbf, 0x7a]),
+        Some(&[0xea, 0xc1]),
+        Some(&[0xea, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xcb]),
+        Some(&[0xed, 0xcc]),
+        Some(&[0xed, 0xbc]),
+        Some(&[0xed, 0xc3]),
+        Some(&[0xed, 0xc1]),
+        None,
+        None,
+        Some(&[0xc1, 0x4f]),
+        Some(&[0xed, 0xc8]),
+        Some(&[0xea, 0xbf]),
+        None,
+        Some(&[0xed, 0xbf]),
+        None,
+        Some(&[0xed, 0xc9]),
+        Some(&[0xc1, 0x4e]),
+        Some(&[0xed, 0xbe]),
+        Some(&[0xed, 0xbd]),
+        Some(&[0xed, 0xc7]),
+        Some(&[0xed, 0xc4]),
+        Some(&[0xed, 0xc6]),
+        None,
+        Some(&[0xed, 0xba]),
+        Some(&[0xed, 0xca]),
+        Some(&[0xc1, 0x4c]),
+        None,
+        Some(&[0xed, 0xc5]),
+        Some(&[0xed, 0xce]),
+        Some(&[0xed, 0xc2]),
+        Some(&[0xc1, 0x50]),
+        Some(&[0xc1, 0x4d]),
+        Some(&[0xed, 0xc0]),
+        Some(&[0xed, 0xbb]),
+        Some(&[0xed, 0xcd]),
+        Some(&[0xbf, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0x63]),
+        None,
+        None,
+        Some(&[0xf0, 0x61]),
+        Some(&[0xf0, 0x67]),
+        Some(&[0xc2, 0xb0]),
+        Some(&[0xf0, 0x65]),
+        Some(&[0xf0, 0x64]),
+        Some(&[0xc2, 0xb2]),
+        Some(&[0xf0, 0x6a]),
+        Some(&[0xc2, 0xb1]),
+        None,
+        Some(&[0xf0, 0x6b]),
+        Some(&[0xf0, 0x68]),
+        Some(&[0xc2, 0xae]),
+        Some(&[0xf0, 0x69]),
+        Some(&[0xf0, 0x62]),
+        Some(&[0xc2, 0xaf]),
+        Some(&[0xc2, 0xad]),
+        Some(&[0xf2, 0xab]),
+        Some(&[0xf0, 0x66]),
+        None,
+        None,
+        Some(&[0xf0, 0x6c]),
+        None,
+        None,
+        Some(&[0xf2, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xb2]),
+        Some(&[0xc3, 0xb0]),
+        Some(&[0xf2, 0xaa]),
+        None,
+        Some(&[0xf2, 0xac]),
+        Some(&[0xf2, 0xa9]),
+        Some(&[0xc3, 0xb1]),
+        Some(&[0xc3, 0xae]),
+        Some(&[0xc3, 0xaf]),
+        Some(&[0xc3, 0xb3]),
+        None,
+        None,
+        Some(&[0xc4, 0x78]),
+        None,
+        Some(&[0xf4, 0xaa]),
+        None,
+        Some(&[0xf4, 0xa9]),
+        Some(&[0xf4, 0xa7]),
+        Some(&[0xf4, 0xa6]),
+        Some(&[0xf4, 0xa8]),
+        None,
+        Some(&[0xc4, 0x77]),
+        Some(&[0xc4, 0x79]),
+        None,
+        None,
+        Some(&[0xc4, 0xf0]),
+        None,
+        None,
+        Some(&[0xf5, 0xe5]),
+        Some(&[0xf5, 0xe4]),
+        None,
+        None,
+        Some(&[0xf6, 0xfa]),
+        None,
+        Some(&[0xf6, 0xfc]),
+        Some(&[0xf6, 0xfe]),
+        Some(&[0xf6, 0xfd]),
+        Some(&[0xf6, 0xfb]),
+        None,
+        None,
+        Some(&[0xc5, 0xa3]),
+        Some(&[0xc5, 0xa2]),
+        None,
+        None,
+        Some(&[0xc5, 0xd3]),
+        Some(&[0xc5, 0xd2]),
+        Some(&[0xc5, 0xd4]),
+        Some(&[0xf7, 0xed]),
+        Some(&[0xf7, 0xec]),
+        None,
+        Some(&[0xf8, 0xfb]),
+        Some(&[0xf8, 0xb8]),
+        Some(&[0xf8, 0xfc]),
+        Some(&[0xc6, 0x58]),
+        None,
+        Some(&[0xc6, 0x59]),
+        Some(&[0xf9, 0x6d]),
+        None,
+        None,
+        Some(&[0xc6, 0x7e]),
+        Some(&[0xa6, 0xcc]),
+        None,
+        Some(&[0xcd, 0xa8]),
+        None,
+        None,
+        Some(&[0xd0, 0x45]),
+        Some(&[0xd0, 0x46]),
+        Some(&[0xd0, 0x44]),
+        None,
+        None,
+        Some(&[0xac, 0xf3]),
+        None,
+        Some(&[0xd0, 0x47]),
+        Some(&[0xd0, 0x48]),
+        Some(&[0xd0, 0x49]),
+        None,
+        None,
+        Some(&[0xd3, 0x49]),
+        Some(&[0xd3, 0x4f]),
+        None,
+        None,
+        Some(&[0xd3, 0x4d]),
+        Some(&[0xaf, 0xbb]),
+        Some(&[0xd3, 0x4b]),
+        None,
+        Some(&[0xd3, 0x4c]),
+        Some(&[0xd3, 0x4e]),
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0x4a]),
+        Some(&[0xb2, 0xc9]),
+        None,
+        Some(&[0xd6, 0xde]),
+        Some(&[0xb2, 0xcb]),
+        Some(&[0xd6, 0xe0]),
+        Some(&[0xb2, 0xca]),
+        Some(&[0xd6, 0xdf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xe8]),
+        Some(&[0xb5, 0xaf]),
+        None,
+        Some(&[0xda, 0xea]),
+        Some(&[0xda, 0xe7]),
+        Some(&[0xd6, 0xe1]),
+        None,
+        Some(&[0xb5, 0xb0]),
+        None,
+        Some(&[0xf9, 0xdb]),
+        Some(&[0xda, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x56]),
+        None,
+        Some(&[0xb8, 0x64]),
+        Some(&[0xdf, 0x54]),
+        Some(&[0xb8, 0x65]),
+        Some(&[0xdf, 0x55]),
+        Some(&[0xb8, 0x66]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xe9]),
+        Some(&[0xe3, 0x61]),
+        Some(&[0xe3, 0x5e]),
+        Some(&[0xe3, 0x60]),
+        Some(&[0xba, 0xea]),
+        Some(&[0xba, 0xeb]),
+        Some(&[0xe3, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xdf]),
+        None,
+        None,
+        Some(&[0xe6, 0xe0]),
+        None,
+        Some(&[0xbd, 0x6b]),
+        Some(&[0xe6, 0xe2]),
+        Some(&[0xe6, 0xe1]),
+        None,
+        Some(&[0xa2, 0x61]),
+        None,
+        Some(&[0xea, 0xca]),
+        Some(&[0xea, 0xcb]),
+        Some(&[0xea, 0xc7]),
+        None,
+        Some(&[0xea, 0xc8]),
+        Some(&[0xbf, 0x7c]),
+        Some(&[0xbf, 0x7d]),
+        Some(&[0xea, 0xc9]),
+        None,
+        Some(&[0xc1, 0x57]),
+        None,
+        None,
+        Some(&[0xc1, 0x53]),
+        Some(&[0xc1, 0x58]),
+        Some(&[0xc1, 0x54]),
+        Some(&[0xc1, 0x56]),
+        Some(&[0xc1, 0x52]),
+        None,
+        Some(&[0xc1, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc2, 0xb3]),
+        Some(&[0xed, 0xcf]),
+        None,
+        Some(&[0xf2, 0xae]),
+        None,
+        Some(&[0xf2, 0xad]),
+        None,
+        Some(&[0xf4, 0xab]),
+        Some(&[0xc4, 0x7a]),
+        Some(&[0xc4, 0x7b]),
+        Some(&[0xf7, 0x41]),
+        Some(&[0xf5, 0xe6]),
+        None,
+        Some(&[0xf7, 0x40]),
+        None,
+        Some(&[0xf8, 0xfd]),
+        Some(&[0xf9, 0xa4]),
+        Some(&[0xa6, 0xcd]),
+        None,
+        None,
+        Some(&[0xa8, 0x74]),
+        None,
+        Some(&[0xcd, 0xa9]),
+        Some(&[0xaa, 0xc8]),
+        None,
+        Some(&[0xac, 0xf6]),
+        Some(&[0xd0, 0x4c]),
+        Some(&[0xac, 0xf4]),
+        Some(&[0xd0, 0x4a]),
+        Some(&[0xac, 0xf9]),
+        Some(&[0xac, 0xf5]),
+        Some(&[0xac, 0xfa]),
+        Some(&[0xac, 0xf8]),
+        Some(&[0xd0, 0x4b]),
+        Some(&[0xac, 0xf7]),
+        Some(&[0xaf, 0xbf]),
+        Some(&[0xaf, 0xbe]),
+        Some(&[0xd3, 0x5a]),
+        Some(&[0xaf, 0xc7]),
+        Some(&[0xd3, 0x53]),
+        Some(&[0xd3, 0x59]),
+        Some(&[0xaf, 0xc3]),
+        Some(&[0xd3, 0x52]),
+        Some(&[0xd3, 0x58]),
+        Some(&[0xd3, 0x56]),
+        Some(&[0xaf, 0xc2]),
+        Some(&[0xaf, 0xc4]),
+        Some(&[0xd3, 0x55]),
+        Some(&[0xaf, 0xbd]),
+        Some(&[0xd3, 0x54]),
+        Some(&[0xaf, 0xc8]),
+        Some(&[0xaf, 0xc5]),
+        Some(&[0xaf, 0xc9]),
+        Some(&[0xaf, 0xc6]),
+        Some(&[0xd3, 0x51]),
+        Some(&[0xd3, 0x50]),
+        Some(&[0xd3, 0x57]),
+        Some(&[0xaf, 0xc0]),
+        Some(&[0xaf, 0xbc]),
+        Some(&[0xaf, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd6, 0xf0]),
+        Some(&[0xd6, 0xe9]),
+        None,
+        Some(&[0xb5, 0xb5]),
+        Some(&[0xd6, 0xe8]),
+        None,
+        Some(&[0xb2, 0xcf]),
+        Some(&[0xb2, 0xd6]),
+        Some(&[0xb2, 0xd3]),
+        Some(&[0xb2, 0xd9]),
+        Some(&[0xb2, 0xd8]),
+        Some(&[0xb2, 0xd4]),
+        None,
+        Some(&[0xd6, 0xe2]),
+        Some(&[0xd6, 0xe5]),
+        None,
+        Some(&[0xd6, 0xe4]),
+        Some(&[0xb2, 0xd0]),
+        Some(&[0xd6, 0xe6]),
+        Some(&[0xd6, 0xef]),
+        Some(&[0xb2, 0xd1]),
+        Some(&[0xd6, 0xe3]),
+        Some(&[0xd6, 0xec]),
+        Some(&[0xd6, 0xed]),
+        Some(&[0xb2, 0xd2]),
+        Some(&[0xd6, 0xea]),
+        Some(&[0xb2, 0xd7]),
+        Some(&[0xb2, 0xcd]),
+        Some(&[0xb2, 0xd5]),
+        Some(&[0xd6, 0xe7]),
+        Some(&[0xb2, 0xcc]),
+        Some(&[0xd6, 0xeb]),
+        None,
+        None,
+        Some(&[0xd6, 0xee]),
+        None,
+        None,
+        None,
+        Some(&[0xda, 0xfb]),
+        Some(&[0xda, 0xf2]),
+        Some(&[0xb5, 0xb2]),
+        Some(&[0xda, 0xf9]),
+        Some(&[0xda, 0xf6]),
+        Some(&[0xda, 0xee]),
+        Some(&[0xda, 0xf7]),
+        Some(&[0xb5, 0xb4]),
+        Some(&[0xda, 0xef]),
+        None,
+        Some(&[0xda, 0xeb]),
+        None,
+        None,
+        Some(&[0xb8, 0x6c]),
+        Some(&[0xda, 0xf4]),
+        None,
+        Some(&[0xb5, 0xb1]),
+        Some(&[0xda, 0xfa]),
+        None,
+        Some(&[0xb5, 0xb8]),
+        Some(&[0xb5, 0xba]),
+        Some(&[0xda, 0xed]),
+        None,
+        None,
+        Some(&[0xb5, 0xb9]),
+        Some(&[0xda, 0xf0]),
+        Some(&[0xb5, 0xb3]),
+        Some(&[0xda, 0xf8]),
+        Some(&[0xda, 0xf1]),
+        Some(&[0xda, 0xf5]),
+        None,
+        Some(&[0xda, 0xf3]),
+        Some(&[0xb5, 0xb6]),
+        Some(&[0xda, 0xec]),
+        Some(&[0xb5, 0xbb]),
+        Some(&[0xb2, 0xce]),
+        Some(&[0xb5, 0xb7]),
+        Some(&[0xb5, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0x68]),
+        Some(&[0xdf, 0x5d]),
+        Some(&[0xdf, 0x5f]),
+        Some(&[0xdf, 0x61]),
+        Some(&[0xdf, 0x65]),
+        None,
+        Some(&[0xdf, 0x5b]),
+        Some(&[0xdf, 0x59]),
+        Some(&[0xb8, 0x6a]),
+        None,
+        Some(&[0xdf, 0x60]),
+        Some(&[0xdf, 0x64]),
+        Some(&[0xdf, 0x5c]),
+        Some(&[0xdf, 0x58]),
+        None,
+        Some(&[0xdf, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x62]),
+        Some(&[0xdf, 0x5a]),
+        Some(&[0xdf, 0x5e]),
+        Some(&[0xb8, 0x6b]),
+        None,
+        Some(&[0xb8, 0x69]),
+        Some(&[0xdf, 0x66]),
+        Some(&[0xb8, 0x67]),
+        Some(&[0xdf, 0x63]),
+        None,
+        Some(&[0xe3, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xee]),
+        Some(&[0xe3, 0x6a]),
+        Some(&[0xbd, 0x78]),
+        Some(&[0xe3, 0x74]),
+        Some(&[0xba, 0xf1]),
+        Some(&[0xe3, 0x78]),
+        Some(&[0xba, 0xf7]),
+        Some(&[0xe3, 0x65]),
+        None,
+        None,
+        Some(&[0xe3, 0x75]),
+        Some(&[0xe3, 0x62]),
+        None,
+        Some(&[0xe3, 0x77]),
+        Some(&[0xe3, 0x66]),
+        None,
+        Some(&[0xba, 0xfe]),
+        Some(&[0xba, 0xfb]),
+        Some(&[0xe3, 0x76]),
+        Some(&[0xe3, 0x70]),
+        Some(&[0xba, 0xed]),
+        Some(&[0xba, 0xf5]),
+        Some(&[0xba, 0xf4]),
+        None,
+        Some(&[0xba, 0xf3]),
+        Some(&[0xba, 0xf9]),
+        None,
+        Some(&[0xe3, 0x63]),
+        Some(&[0xba, 0xfa]),
+        Some(&[0xe3, 0x71]),
+        Some(&[0xba, 0xf6]),
+        Some(&[0xba, 0xec]),
+        Some(&[0xe3, 0x73]),
+        Some(&[0xba, 0xef]),
+        Some(&[0xba, 0xf0]),
+        Some(&[0xba, 0xf8]),
+        Some(&[0xe3, 0x68]),
+        Some(&[0xe3, 0x67]),
+        Some(&[0xe3, 0x64]),
+        None,
+        Some(&[0xe3, 0x6c]),
+        Some(&[0xe3, 0x69]),
+        Some(&[0xe3, 0x6d]),
+        Some(&[0xba, 0xfd]),
+        None,
+        Some(&[0xe3, 0x79]),
+        Some(&[0xba, 0xf2]),
+        Some(&[0xe3, 0x6e]),
+        Some(&[0xe3, 0x6f]),
+        None,
+        Some(&[0xe3, 0x6b]),
+        None,
+        None,
+        None,
+        Some(&[0xba, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe7]),
+        Some(&[0xbd, 0x70]),
+        Some(&[0xbd, 0x79]),
+        Some(&[0xbd, 0x75]),
+        Some(&[0xe6, 0xe4]),
+        None,
+        Some(&[0xbd, 0x72]),
+        Some(&[0xbd, 0x76]),
+        Some(&[0xe6, 0xf0]),
+        Some(&[0xbd, 0x6c]),
+        Some(&[0xe6, 0xe8]),
+        None,
+        Some(&[0xbd, 0x74]),
+        None,
+        None,
+        Some(&[0xe6, 0xeb]),
+        Some(&[0xe6, 0xe6]),
+        Some(&[0xbd, 0x73]),
+        Some(&[0xbd, 0x77]),
+        Some(&[0xe6, 0xe5]),
+        None,
+        Some(&[0xbd, 0x71]),
+        None,
+        Some(&[0xe6, 0xef]),
+        Some(&[0xbd, 0x6e]),
+        Some(&[0xe6, 0xee]),
+        Some(&[0xe6, 0xed]),
+        Some(&[0xbd, 0x7a]),
+        Some(&[0xe5, 0x72]),
+        Some(&[0xbd, 0x6d]),
+        None,
+        Some(&[0xe6, 0xec]),
+        Some(&[0xe6, 0xe3]),
+        None,
+        Some(&[0xbd, 0x7b]),
+        Some(&[0xe6, 0xea]),
+        Some(&[0xbd, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xe9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xa2]),
+        Some(&[0xbf, 0xa7]),
+        Some(&[0xbf, 0x7e]),
+        Some(&[0xea, 0xd8]),
+        Some(&[0xea, 0xcf]),
+        Some(&[0xea, 0xdb]),
+        Some(&[0xea, 0xd3]),
+        Some(&[0xea, 0xd9]),
+        Some(&[0xbf, 0xa8]),
+        Some(&[0xbf, 0xa1]),
+        Some(&[0xea, 0xcc]),
+        Some(&[0xea, 0xd2]),
+        Some(&[0xea, 0xdc]),
+        Some(&[0xea, 0xd5]),
+        Some(&[0xea, 0xda]),
+        Some(&[0xea, 0xce]),
+        None,
+        None,
+        Some(&[0xea, 0xd6]),
+        Some(&[0xbf, 0xa3]),
+        Some(&[0xea, 0xd4]),
+        Some(&[0xbf, 0xa6]),
+        Some(&[0xbf, 0xa5]),
+        Some(&[0xea, 0xd0]),
+        Some(&[0xea, 0xd1]),
+        Some(&[0xea, 0xcd]),
+        Some(&[0xea, 0xd7]),
+        Some(&[0xbf, 0xa4]),
+        Some(&[0xea, 0xde]),
+        Some(&[0xea, 0xdd]),
+        None,
+        None,
+        None,
+        Some(&[0xed, 0xda]),
+        Some(&[0xed, 0xd6]),
+        Some(&[0xc1, 0x5f]),
+        None,
+        Some(&[0xed, 0xd0]),
+        Some(&[0xc1, 0x59]),
+        Some(&[0xc1, 0x69]),
+        Some(&[0xed, 0xdc]),
+        Some(&[0xc1, 0x61]),
+        Some(&[0xc1, 0x5d]),
+        Some(&[0xed, 0xd3]),
+        Some(&[0xc1, 0x64]),
+        Some(&[0xc1, 0x67]),
+        Some(&[0xed, 0xde]),
+        Some(&[0xc1, 0x5c]),
+        Some(&[0xed, 0xd5]),
+        Some(&[0xc1, 0x65]),
+        Some(&[0xed, 0xe0]),
+        Some(&[0xed, 0xdd]),
+        Some(&[0xed, 0xd1]),
+        Some(&[0xc1, 0x60]),
+        Some(&[0xc1, 0x5a]),
+        Some(&[0xc1, 0x68]),
+        Some(&[0xed, 0xd8]),
+        Some(&[0xc1, 0x63]),
+        Some(&[0xed, 0xd2]),
+        Some(&[0xc1, 0x5e]),
+        Some(&[0xed, 0xdf]),
+        Some(&[0xc1, 0x62]),
+        Some(&[0xc1, 0x5b]),
+        Some(&[0xed, 0xd9]),
+        Some(&[0xc1, 0x66]),
+        Some(&[0xed, 0xd7]),
+        None,
+        None,
+        Some(&[0xed, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xf0, 0x6e]),
+        Some(&[0xf0, 0x74]),
+        Some(&[0xc2, 0xb9]),
+        Some(&[0xf0, 0x77]),
+        Some(&[0xc2, 0xb4]),
+        Some(&[0xc2, 0xb5]),
+        Some(&[0xf0, 0x6f]),
+        Some(&[0xf0, 0x76]),
+        Some(&[0xf0, 0x71]),
+        Some(&[0xc2, 0xba]),
+        Some(&[0xc2, 0xb7]),
+        None,
+        Some(&[0xf0, 0x6d]),
+        None,
+        Some(&[0xc2, 0xb6]),
+        Some(&[0xf0, 0x73]),
+        Some(&[0xf0, 0x75]),
+        Some(&[0xc2, 0xb8]),
+        Some(&[0xf0, 0x72]),
+        Some(&[0xf0, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xb8]),
+        Some(&[0xc3, 0xb7]),
+        Some(&[0xc3, 0xb8]),
+        Some(&[0xc3, 0xb4]),
+        None,
+        Some(&[0xc3, 0xb5]),
+        None,
+        Some(&[0xf2, 0xb4]),
+        Some(&[0xf2, 0xb2]),
+        None,
+        Some(&[0xf2, 0xb6]),
+        Some(&[0xc3, 0xba]),
+        Some(&[0xf2, 0xb7]),
+        Some(&[0xf2, 0xb0]),
+        Some(&[0xf2, 0xaf]),
+        Some(&[0xf2, 0xb3]),
+        Some(&[0xf2, 0xb1]),
+        Some(&[0xc3, 0xb6]),
+        Some(&[0xf2, 0xb5]),
+        Some(&[0xf4, 0xac]),
+        Some(&[0xc4, 0x7e]),
+        Some(&[0xc4, 0x7d]),
+        Some(&[0xf4, 0xad]),
+        None,
+        Some(&[0xf4, 0xaf]),
+        Some(&[0xf4, 0xae]),
+        Some(&[0xc4, 0xa1]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xeb]),
+        Some(&[0xf5, 0xe8]),
+        Some(&[0xf5, 0xe9]),
+        None,
+        Some(&[0xf5, 0xe7]),
+        Some(&[0xf5, 0xea]),
+        Some(&[0xc4, 0xf2]),
+        Some(&[0xf5, 0xec]),
+        None,
+        Some(&[0xc4, 0xf1]),
+        None,
+        Some(&[0xf7, 0x42]),
+        None,
+        Some(&[0xc5, 0xd5]),
+        Some(&[0xc5, 0xd7]),
+        Some(&[0xf7, 0xee]),
+        Some(&[0xc5, 0xd6]),
+        Some(&[0xf8, 0xb9]),
+        Some(&[0xf9, 0x40]),
+        Some(&[0xf9, 0x42]),
+        Some(&[0xf8, 0xfe]),
+        Some(&[0xf9, 0x41]),
+        Some(&[0xc6, 0x6c]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X7F30: [Option<&'static [u8]>; 3184] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xce]),
+        None,
+        Some(&[0xac, 0xfb]),
+        Some(&[0xd2, 0x6f]),
+        Some(&[0xaf, 0xca]),
+        None,
+        None,
+        Some(&[0xb2, 0xda]),
+        Some(&[0xda, 0xfc]),
+        Some(&[0xda, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xdf]),
+        Some(&[0xc1, 0x6a]),
+        Some(&[0xed, 0xe1]),
+        None,
+        None,
+        Some(&[0xc2, 0xbb]),
+        None,
+        Some(&[0xf2, 0xba]),
+        Some(&[0xf2, 0xb9]),
+        Some(&[0xc4, 0xa2]),
+        Some(&[0xf5, 0xed]),
+        None,
+        Some(&[0xf7, 0x43]),
+        Some(&[0xc5, 0xf8]),
+        Some(&[0xca, 0x49]),
+        None,
+        None,
+        Some(&[0xaa, 0xc9]),
+        Some(&[0xa8, 0x75]),
+        None,
+        None,
+        Some(&[0xd0, 0x4d]),
+        None,
+        None,
+        Some(&[0xd3, 0x60]),
+        Some(&[0xd3, 0x5b]),
+        Some(&[0xd3, 0x5f]),
+        Some(&[0xd3, 0x5d]),
+        Some(&[0xaf, 0xcb]),
+        Some(&[0xd3, 0x5e]),
+        Some(&[0xd3, 0x5c]),
+        None,
+        Some(&[0xd6, 0xf1]),
+        None,
+        Some(&[0xda, 0xfe]),
+        Some(&[0xdb, 0x40]),
+        Some(&[0xdf, 0x69]),
+        Some(&[0xdf, 0x6a]),
+        Some(&[0xb8, 0x6e]),
+        Some(&[0xb8, 0x6f]),
+        Some(&[0xdf, 0x68]),
+        Some(&[0xdf, 0x6b]),
+        Some(&[0xdf, 0x67]),
+        Some(&[0xb8, 0x6d]),
+        None,
+        Some(&[0xbb, 0x40]),
+        None,
+        Some(&[0xb8, 0x70]),
+        Some(&[0xe3, 0x7a]),
+        None,
+        Some(&[0xbd, 0x7c]),
+        Some(&[0xe6, 0xf1]),
+        Some(&[0xbd, 0x7d]),
+        None,
+        Some(&[0xbf, 0xa9]),
+        Some(&[0xea, 0xe2]),
+        Some(&[0xea, 0xe0]),
+        Some(&[0xea, 0xe1]),
+        Some(&[0xed, 0xe4]),
+        Some(&[0xed, 0xe3]),
+        Some(&[0xed, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xbb]),
+        None,
+        Some(&[0xc3, 0xb9]),
+        Some(&[0xf2, 0xbc]),
+        Some(&[0xf7, 0x44]),
+        Some(&[0xc5, 0xf9]),
+        Some(&[0xf8, 0xba]),
+        Some(&[0xa6, 0xcf]),
+        Some(&[0xaa, 0xcb]),
+        Some(&[0xaa, 0xca]),
+        Some(&[0xd0, 0x4f]),
+        Some(&[0xac, 0xfc]),
+        None,
+        None,
+        Some(&[0xd0, 0x4e]),
+        Some(&[0xd3, 0x62]),
+        None,
+        Some(&[0xaf, 0xcc]),
+        Some(&[0xd6, 0xf2]),
+        Some(&[0xd3, 0x61]),
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xdc]),
+        Some(&[0xd6, 0xf5]),
+        Some(&[0xd6, 0xf3]),
+        Some(&[0xd6, 0xf4]),
+        Some(&[0xb2, 0xdb]),
+        None,
+        Some(&[0xdb, 0x42]),
+        Some(&[0xdb, 0x43]),
+        Some(&[0xdb, 0x41]),
+        None,
+        Some(&[0xb8, 0x73]),
+        Some(&[0xdf, 0x6d]),
+        Some(&[0xdf, 0x6c]),
+        Some(&[0xdf, 0x6e]),
+        Some(&[0xb8, 0x72]),
+        Some(&[0xb8, 0x71]),
+        None,
+        None,
+        Some(&[0xe6, 0xf2]),
+        Some(&[0xe6, 0xf4]),
+        None,
+        Some(&[0xbd, 0x7e]),
+        Some(&[0xe6, 0xf3]),
+        Some(&[0xea, 0xe3]),
+        Some(&[0xbf, 0xaa]),
+        Some(&[0xf0, 0x79]),
+        None,
+        Some(&[0xf0, 0x78]),
+        Some(&[0xc3, 0xbb]),
+        Some(&[0xf2, 0xbd]),
+        Some(&[0xc3, 0xbd]),
+        Some(&[0xc3, 0xbc]),
+        Some(&[0xf4, 0xb0]),
+        Some(&[0xf5, 0xee]),
+        Some(&[0xc4, 0xf3]),
+        Some(&[0xa6, 0xd0]),
+        Some(&[0xd0, 0x50]),
+        Some(&[0xac, 0xfd]),
+        Some(&[0xd3, 0x65]),
+        Some(&[0xaf, 0xce]),
+        Some(&[0xd3, 0x64]),
+        Some(&[0xd3, 0x63]),
+        None,
+        Some(&[0xaf, 0xcd]),
+        None,
+        Some(&[0xd6, 0xfb]),
+        None,
+        Some(&[0xd6, 0xfd]),
+        Some(&[0xd6, 0xf6]),
+        Some(&[0xd6, 0xf7]),
+        Some(&[0xb2, 0xdd]),
+        Some(&[0xd6, 0xf8]),
+        Some(&[0xb2, 0xde]),
+        Some(&[0xd6, 0xfc]),
+        Some(&[0xd6, 0xf9]),
+        Some(&[0xd6, 0xfa]),
+        Some(&[0xb2, 0xdf]),
+        None,
+        Some(&[0xb5, 0xbe]),
+        Some(&[0xb5, 0xbf]),
+        None,
+        Some(&[0xdb, 0x44]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x6f]),
+        Some(&[0xdf, 0x70]),
+        None,
+        Some(&[0xe3, 0x7e]),
+        Some(&[0xbb, 0x43]),
+        Some(&[0xbb, 0x41]),
+        Some(&[0xbb, 0x42]),
+        Some(&[0xe3, 0x7b]),
+        Some(&[0xe3, 0x7c]),
+        None,
+        Some(&[0xe3, 0x7d]),
+        Some(&[0xe6, 0xf9]),
+        None,
+        Some(&[0xe6, 0xfa]),
+        Some(&[0xbd, 0xa1]),
+        Some(&[0xe6, 0xf7]),
+        Some(&[0xe6, 0xf6]),
+        Some(&[0xe6, 0xf8]),
+        Some(&[0xe6, 0xf5]),
+        Some(&[0xbf, 0xad]),
+        Some(&[0xea, 0xe4]),
+        Some(&[0xbf, 0xab]),
+        Some(&[0xbf, 0xac]),
+        Some(&[0xed, 0xe6]),
+        Some(&[0xc1, 0x6b]),
+        Some(&[0xed, 0xe5]),
+        Some(&[0xef, 0xa8]),
+        None,
+        Some(&[0xf0, 0x7a]),
+        Some(&[0xf0, 0x7b]),
+        Some(&[0xc2, 0xbc]),
+        None,
+        Some(&[0xc2, 0xbd]),
+        Some(&[0xc1, 0x6c]),
+        Some(&[0xf2, 0xbe]),
+        Some(&[0xf2, 0xbf]),
+        Some(&[0xf4, 0xb1]),
+        Some(&[0xc4, 0xa3]),
+        Some(&[0xa6, 0xd1]),
+        None,
+        Some(&[0xa6, 0xd2]),
+        Some(&[0xac, 0xfe]),
+        Some(&[0xaa, 0xcc]),
+        Some(&[0xaf, 0xcf]),
+        Some(&[0xd0, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xc0]),
+        Some(&[0xa6, 0xd3]),
+        Some(&[0xad, 0x41]),
+        Some(&[0xd0, 0x52]),
+        Some(&[0xd0, 0x53]),
+        Some(&[0xad, 0x40]),
+        Some(&[0xad, 0x42]),
+        Some(&[0xa6, 0xd4]),
+        None,
+        Some(&[0xd0, 0x54]),
+        Some(&[0xaf, 0xd1]),
+        Some(&[0xd3, 0x66]),
+        Some(&[0xaf, 0xd3]),
+        Some(&[0xaf, 0xd0]),
+        Some(&[0xaf, 0xd2]),
+        None,
+        Some(&[0xd7, 0x41]),
+        Some(&[0xb2, 0xe0]),
+        None,
+        Some(&[0xd7, 0x40]),
+        Some(&[0xd6, 0xfe]),
+        None,
+        Some(&[0xdf, 0x71]),
+        None,
+        None,
+        Some(&[0xe3, 0xa1]),
+        None,
+        Some(&[0xbd, 0xa2]),
+        None,
+        Some(&[0xbf, 0xae]),
+        Some(&[0xea, 0xe6]),
+        Some(&[0xea, 0xe5]),
+        None,
+        Some(&[0xed, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xef]),
+        None,
+        None,
+        Some(&[0xa6, 0xd5]),
+        Some(&[0xcb, 0x73]),
+        Some(&[0xcd, 0xaa]),
+        Some(&[0xad, 0x43]),
+        Some(&[0xd0, 0x55]),
+        None,
+        Some(&[0xd3, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0xd4]),
+        Some(&[0xd3, 0x67]),
+        Some(&[0xaf, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0x43]),
+        None,
+        None,
+        Some(&[0xb2, 0xe2]),
+        Some(&[0xd7, 0x42]),
+        Some(&[0xd7, 0x44]),
+        None,
+        Some(&[0xb2, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0x46]),
+        Some(&[0xdb, 0x47]),
+        Some(&[0xdb, 0x45]),
+        Some(&[0xb5, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0x74]),
+        None,
+        Some(&[0xb8, 0x75]),
+        None,
+        Some(&[0xbb, 0x45]),
+        None,
+        Some(&[0xe3, 0xa3]),
+        Some(&[0xe3, 0xa2]),
+        Some(&[0xbb, 0x44]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe6, 0xfb]),
+        None,
+        None,
+        Some(&[0xe6, 0xfc]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xe7]),
+        None,
+        None,
+        Some(&[0xc1, 0x70]),
+        Some(&[0xc1, 0x6f]),
+        Some(&[0xc1, 0x6d]),
+        Some(&[0xc1, 0x6e]),
+        Some(&[0xc1, 0x71]),
+        None,
+        Some(&[0xf0, 0x7c]),
+        Some(&[0xc2, 0xbf]),
+        Some(&[0xc2, 0xbe]),
+        Some(&[0xf2, 0xc0]),
+        Some(&[0xf4, 0xb2]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xa5]),
+        Some(&[0xc5, 0xa4]),
+        Some(&[0xa6, 0xd6]),
+        None,
+        None,
+        Some(&[0xd1, 0xfb]),
+        None,
+        Some(&[0xb8, 0x77]),
+        Some(&[0xb5, 0xc2]),
+        Some(&[0xb8, 0x76]),
+        Some(&[0xbb, 0x46]),
+        None,
+        Some(&[0xa6, 0xd7]),
+        Some(&[0xc9, 0xa9]),
+        Some(&[0xa6, 0xd8]),
+        Some(&[0xa6, 0xd9]),
+        None,
+        None,
+        Some(&[0xcd, 0xab]),
+        Some(&[0xcb, 0x76]),
+        None,
+        Some(&[0xcb, 0x77]),
+        Some(&[0xa8, 0x77]),
+        None,
+        Some(&[0xcb, 0x74]),
+        Some(&[0xa8, 0x76]),
+        None,
+        Some(&[0xa8, 0x79]),
+        Some(&[0xcb, 0x75]),
+        Some(&[0xa8, 0x7b]),
+        Some(&[0xa8, 0x7a]),
+        Some(&[0xcb, 0x78]),
+        Some(&[0xa8, 0x78]),
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xd1]),
+        Some(&[0xaa, 0xcf]),
+        Some(&[0xcd, 0xad]),
+        None,
+        Some(&[0xaa, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xd3]),
+        Some(&[0xaa, 0xd5]),
+        Some(&[0xaa, 0xd2]),
+        None,
+        Some(&[0xcd, 0xb0]),
+        Some(&[0xcd, 0xac]),
+        Some(&[0xaa, 0xd6]),
+        None,
+        Some(&[0xaa, 0xd0]),
+        Some(&[0xa8, 0x7c]),
+        None,
+        Some(&[0xaa, 0xd4]),
+        Some(&[0xcd, 0xaf]),
+        None,
+        None,
+        Some(&[0xcd, 0xae]),
+        None,
+        Some(&[0xaa, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0x5b]),
+        Some(&[0xad, 0x47]),
+        Some(&[0xad, 0x48]),
+        Some(&[0xd0, 0x5d]),
+        None,
+        Some(&[0xd0, 0x57]),
+        Some(&[0xd0, 0x5a]),
+        Some(&[0xd0, 0x63]),
+        Some(&[0xd0, 0x61]),
+        None,
+        Some(&[0xad, 0x49]),
+        Some(&[0xd0, 0x67]),
+        Some(&[0xad, 0x4c]),
+        Some(&[0xd0, 0x64]),
+        Some(&[0xd0, 0x5c]),
+        Some(&[0xd0, 0x59]),
+        None,
+        None,
+        Some(&[0xdb, 0x49]),
+        Some(&[0xd0, 0x62]),
+        Some(&[0xad, 0x44]),
+        Some(&[0xd0, 0x65]),
+        Some(&[0xd0, 0x56]),
+        Some(&[0xd0, 0x5f]),
+        Some(&[0xad, 0x46]),
+        Some(&[0xad, 0x4b]),
+        Some(&[0xd0, 0x60]),
+        Some(&[0xad, 0x4f]),
+        Some(&[0xad, 0x4d]),
+        None,
+        Some(&[0xd0, 0x58]),
+        Some(&[0xad, 0x4a]),
+        None,
+        Some(&[0xd0, 0x5e]),
+        Some(&[0xad, 0x4e]),
+        Some(&[0xad, 0x45]),
+        Some(&[0xd0, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaf, 0xda]),
+        None,
+        Some(&[0xaf, 0xe3]),
+        Some(&[0xaf, 0xd8]),
+        Some(&[0xaf, 0xd6]),
+        Some(&[0xd3, 0x6a]),
+        Some(&[0xaf, 0xde]),
+        Some(&[0xaf, 0xdb]),
+        Some(&[0xd3, 0x6c]),
+        None,
+        None,
+        Some(&[0xaf, 0xdd]),
+        Some(&[0xd3, 0x6b]),
+        Some(&[0xd3, 0x69]),
+        Some(&[0xd3, 0x6e]),
+        Some(&[0xaf, 0xe2]),
+        Some(&[0xaf, 0xe0]),
+        Some(&[0xdb, 0x48]),
+        None,
+        Some(&[0xd3, 0x6f]),
+        Some(&[0xd3, 0x6d]),
+        Some(&[0xaf, 0xd7]),
+        None,
+        None,
+        Some(&[0xaf, 0xd9]),
+        Some(&[0xaf, 0xdc]),
+        None,
+        Some(&[0xaf, 0xdf]),
+        None,
+        Some(&[0xaf, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0x4e]),
+        Some(&[0xb2, 0xe4]),
+        None,
+        Some(&[0xd7, 0x45]),
+        Some(&[0xd7, 0x47]),
+        None,
+        Some(&[0xd7, 0x48]),
+        None,
+        Some(&[0xd7, 0x50]),
+        Some(&[0xd7, 0x4c]),
+        Some(&[0xd7, 0x4a]),
+        None,
+        Some(&[0xd7, 0x4d]),
+        Some(&[0xd7, 0x51]),
+        Some(&[0xb2, 0xe5]),
+        Some(&[0xb2, 0xe9]),
+        Some(&[0xd7, 0x46]),
+        None,
+        Some(&[0xd7, 0x4f]),
+        None,
+        Some(&[0xb2, 0xe7]),
+        None,
+        Some(&[0xb2, 0xe6]),
+        Some(&[0xd7, 0x4b]),
+        Some(&[0xd7, 0x49]),
+        None,
+        Some(&[0xb2, 0xe3]),
+        Some(&[0xb2, 0xe8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xc8]),
+        Some(&[0xdb, 0x51]),
+        None,
+        None,
+        Some(&[0xdb, 0x4f]),
+        Some(&[0xb5, 0xca]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0x4a]),
+        Some(&[0xdf, 0xa1]),
+        None,
+        Some(&[0xb5, 0xc9]),
+        Some(&[0xdb, 0x4e]),
+        None,
+        None,
+        Some(&[0xdb, 0x4b]),
+        Some(&[0xb5, 0xc5]),
+        Some(&[0xb5, 0xcb]),
+        Some(&[0xdb, 0x50]),
+        Some(&[0xb5, 0xc7]),
+        Some(&[0xdb, 0x4d]),
+        Some(&[0xbb, 0x47]),
+        Some(&[0xb5, 0xc6]),
+        Some(&[0xdb, 0x4c]),
+        Some(&[0xb5, 0xcc]),
+        Some(&[0xb5, 0xc4]),
+        Some(&[0xb5, 0xc3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0x77]),
+        Some(&[0xdf, 0x75]),
+        None,
+        Some(&[0xdf, 0x7b]),
+        None,
+        Some(&[0xdf, 0x73]),
+        Some(&[0xdf, 0xa2]),
+        Some(&[0xdf, 0x78]),
+        None,
+        Some(&[0xdf, 0x72]),
+        Some(&[0xb8, 0x7b]),
+        Some(&[0xb8, 0xa3]),
+        Some(&[0xdf, 0x7d]),
+        None,
+        Some(&[0xdf, 0x76]),
+        None,
+        Some(&[0xb8, 0x7e]),
+        None,
+        None,
+        Some(&[0xb8, 0x7c]),
+        Some(&[0xdf, 0x7e]),
+        Some(&[0xb8, 0x79]),
+        Some(&[0xb8, 0x78]),
+        Some(&[0xdf, 0x79]),
+        Some(&[0xb8, 0x7d]),
+        Some(&[0xb5, 0xcd]),
+        None,
+        Some(&[0xdf, 0x7c]),
+        Some(&[0xdf, 0x74]),
+        Some(&[0xb8, 0x7a]),
+        Some(&[0xb8, 0xa1]),
+        Some(&[0xb8, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0x4c]),
+        Some(&[0xbb, 0x48]),
+        None,
+        Some(&[0xbb, 0x4d]),
+        Some(&[0xe3, 0xa6]),
+        None,
+        None,
+        Some(&[0xe3, 0xa5]),
+        Some(&[0xe3, 0xa7]),
+        Some(&[0xbb, 0x4a]),
+        Some(&[0xe3, 0xa4]),
+        Some(&[0xbb, 0x4b]),
+        Some(&[0xe3, 0xaa]),
+        Some(&[0xe3, 0xa9]),
+        Some(&[0xe3, 0xa8]),
+        None,
+        Some(&[0xbb, 0x49]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x41]),
+        None,
+        Some(&[0xe7, 0x44]),
+        Some(&[0xbd, 0xa8]),
+        Some(&[0xe7, 0x43]),
+        Some(&[0xbd, 0xa7]),
+        Some(&[0xbd, 0xa3]),
+        Some(&[0xbd, 0xa4]),
+        Some(&[0xbd, 0xa5]),
+        Some(&[0xe7, 0x40]),
+        Some(&[0xe6, 0xfe]),
+        Some(&[0xbd, 0xa6]),
+        None,
+        Some(&[0xe7, 0x42]),
+        Some(&[0xe6, 0xfd]),
+        None,
+        None,
+        Some(&[0xea, 0xe9]),
+        Some(&[0xea, 0xf3]),
+        Some(&[0xbf, 0xb1]),
+        Some(&[0xbf, 0xb0]),
+        None,
+        Some(&[0xea, 0xed]),
+        Some(&[0xea, 0xef]),
+        None,
+        Some(&[0xea, 0xea]),
+        None,
+        Some(&[0xea, 0xee]),
+        Some(&[0xea, 0xe8]),
+        Some(&[0xea, 0xf1]),
+        Some(&[0xbf, 0xaf]),
+        Some(&[0xea, 0xf0]),
+        Some(&[0xea, 0xec]),
+        None,
+        Some(&[0xea, 0xf2]),
+        None,
+        Some(&[0xea, 0xeb]),
+        Some(&[0xc1, 0x74]),
+        Some(&[0xed, 0xe8]),
+        Some(&[0xed, 0xee]),
+        Some(&[0xc1, 0x78]),
+        Some(&[0xc1, 0x7a]),
+        Some(&[0xc1, 0x77]),
+        Some(&[0xc1, 0x76]),
+        None,
+        Some(&[0xc1, 0x75]),
+        Some(&[0xc1, 0x73]),
+        Some(&[0xed, 0xe9]),
+        Some(&[0xed, 0xec]),
+        Some(&[0xc1, 0x72]),
+        Some(&[0xed, 0xed]),
+        None,
+        Some(&[0xc1, 0x79]),
+        Some(&[0xed, 0xeb]),
+        None,
+        Some(&[0xed, 0xea]),
+        Some(&[0xc2, 0xc0]),
+        None,
+        Some(&[0xc2, 0xc1]),
+        Some(&[0xf0, 0xa1]),
+        Some(&[0xf0, 0x7d]),
+        Some(&[0xf0, 0x7e]),
+        None,
+        None,
+        Some(&[0xf2, 0xc2]),
+        None,
+        Some(&[0xf2, 0xc1]),
+        Some(&[0xc3, 0xbe]),
+        Some(&[0xf4, 0xb4]),
+        Some(&[0xc4, 0xa4]),
+        Some(&[0xf4, 0xb3]),
+        None,
+        Some(&[0xf5, 0xf0]),
+        Some(&[0xf7, 0x45]),
+        Some(&[0xc5, 0xa6]),
+        Some(&[0xf9, 0x43]),
+        Some(&[0xf9, 0x44]),
+        Some(&[0xc5, 0xd8]),
+        Some(&[0xa6, 0xda]),
+        None,
+        Some(&[0xaa, 0xd7]),
+        Some(&[0xdb, 0x52]),
+        Some(&[0xbb, 0x4e]),
+        Some(&[0xc1, 0x7b]),
+        Some(&[0xed, 0xef]),
+        Some(&[0xa6, 0xdb]),
+        None,
+        Some(&[0xaf, 0xe5]),
+        Some(&[0xaf, 0xe4]),
+        Some(&[0xdb, 0x53]),
+        None,
+        None,
+        None,
+        Some(&[0xea, 0xf4]),
+        Some(&[0xa6, 0xdc]),
+        Some(&[0xad, 0x50]),
+        None,
+        None,
+        Some(&[0xdb, 0x54]),
+        Some(&[0xdb, 0x55]),
+        Some(&[0xdb, 0x56]),
+        Some(&[0xbb, 0x4f]),
+        Some(&[0xbf, 0xb2]),
+        Some(&[0xa6, 0xdd]),
+        None,
+        Some(&[0xaa, 0xd8]),
+        Some(&[0xd0, 0x68]),
+        Some(&[0xaf, 0xe6]),
+        Some(&[0xd3, 0x70]),
+        Some(&[0xb2, 0xea]),
+        None,
+        Some(&[0xdb, 0x57]),
+        Some(&[0xb8, 0xa4]),
+        None,
+        Some(&[0xbb, 0x50]),
+        Some(&[0xbf, 0xb3]),
+        Some(&[0xc1, 0x7c]),
+        Some(&[0xc2, 0xc2]),
+        Some(&[0xf4, 0xb5]),
+        Some(&[0xa6, 0xde]),
+        Some(&[0xaa, 0xd9]),
+        None,
+        None,
+        Some(&[0xaf, 0xe7]),
+        Some(&[0xd7, 0x52]),
+        Some(&[0xb5, 0xce]),
+        None,
+        Some(&[0xbb, 0x51]),
+        Some(&[0xe3, 0xab]),
+        Some(&[0xe7, 0x45]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa6, 0xdf]),
+        Some(&[0xb5, 0xcf]),
+        Some(&[0xdf, 0xa3]),
+        Some(&[0xbb, 0x52]),
+        Some(&[0xa6, 0xe0]),
+        Some(&[0xcd, 0xb1]),
+        Some(&[0xd0, 0x69]),
+        Some(&[0xad, 0x51]),
+        None,
+        None,
+        Some(&[0xd3, 0x72]),
+        None,
+        None,
+        Some(&[0xaf, 0xea]),
+        None,
+        Some(&[0xaf, 0xe8]),
+        Some(&[0xaf, 0xe9]),
+        Some(&[0xaf, 0xeb]),
+        None,
+        None,
+        Some(&[0xd3, 0x71]),
+        None,
+        None,
+        Some(&[0xd7, 0x57]),
+        Some(&[0xd7, 0x54]),
+        Some(&[0xd7, 0x56]),
+        Some(&[0xb2, 0xeb]),
+        Some(&[0xb2, 0xed]),
+        Some(&[0xb2, 0xec]),
+        Some(&[0xd7, 0x53]),
+        Some(&[0xb2, 0xee]),
+        Some(&[0xd7, 0x55]),
+        None,
+        Some(&[0xdb, 0x58]),
+        Some(&[0xdb, 0x59]),
+        None,
+        Some(&[0xdb, 0x5a]),
+        Some(&[0xdf, 0xa6]),
+        None,
+        Some(&[0xdf, 0xa7]),
+        None,
+        Some(&[0xdf, 0xa5]),
+        Some(&[0xdf, 0xa8]),
+        None,
+        Some(&[0xb8, 0xa5]),
+        None,
+        Some(&[0xdf, 0xa4]),
+        None,
+        Some(&[0xbb, 0x53]),
+        None,
+        None,
+        Some(&[0xe7, 0x4a]),
+        Some(&[0xe7, 0x46]),
+        Some(&[0xe7, 0x49]),
+        Some(&[0xe7, 0x4b]),
+        Some(&[0xe7, 0x48]),
+        Some(&[0xe7, 0x47]),
+        None,
+        Some(&[0xea, 0xf5]),
+        Some(&[0xea, 0xf6]),
+        Some(&[0xea, 0xf7]),
+        Some(&[0xbf, 0xb4]),
+        Some(&[0xbf, 0xb5]),
+        Some(&[0xed, 0xf1]),
+        Some(&[0xed, 0xf0]),
+        Some(&[0xed, 0xf2]),
+        None,
+        Some(&[0xf0, 0xa3]),
+        Some(&[0xf0, 0xa2]),
+        None,
+        Some(&[0xf2, 0xc4]),
+        None,
+        Some(&[0xf2, 0xc5]),
+        Some(&[0xf2, 0xc3]),
+        None,
+        Some(&[0xc4, 0xa5]),
+        None,
+        Some(&[0xf4, 0xb6]),
+        Some(&[0xf4, 0xb7]),
+        None,
+        Some(&[0xf7, 0x46]),
+        Some(&[0xf7, 0xef]),
+        Some(&[0xf8, 0xbb]),
+        Some(&[0xa6, 0xe1]),
+        Some(&[0xa8, 0x7d]),
+        None,
+        Some(&[0xc1, 0x7d]),
+        Some(&[0xa6, 0xe2]),
+        None,
+        Some(&[0xd7, 0x58]),
+        Some(&[0xdb, 0x5b]),
+        None,
+        Some(&[0xc6, 0x41]),
+        Some(&[0xca, 0x4a]),
+        None,
+        None,
+        None,
+        Some(&[0xca, 0x4b]),
+        Some(&[0xca, 0x4d]),
+        Some(&[0xa6, 0xe3]),
+        Some(&[0xca, 0x4e]),
+        Some(&[0xca, 0x4c]),
+        None,
+        None,
+        Some(&[0xcb, 0xa2]),
+        Some(&[0xcb, 0xa3]),
+        Some(&[0xcb, 0x7b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xa1]),
+        Some(&[0xa8, 0xa1]),
+        None,
+        Some(&[0xa8, 0xa2]),
+        Some(&[0xcb, 0x7c]),
+        Some(&[0xcb, 0x7a]),
+        Some(&[0xcb, 0x79]),
+        Some(&[0xcb, 0x7d]),
+        Some(&[0xa8, 0x7e]),
+        Some(&[0xcb, 0x7e]),
+        Some(&[0xd0, 0x6a]),
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xb6]),
+        Some(&[0xaa, 0xdc]),
+        Some(&[0xcd, 0xb5]),
+        Some(&[0xcd, 0xb7]),
+        None,
+        Some(&[0xaa, 0xdb]),
+        Some(&[0xcd, 0xbc]),
+        Some(&[0xaa, 0xdf]),
+        Some(&[0xcd, 0xb2]),
+        Some(&[0xcd, 0xc0]),
+        Some(&[0xcd, 0xc6]),
+        Some(&[0xaa, 0xe6]),
+        Some(&[0xcd, 0xc3]),
+        Some(&[0xaa, 0xe3]),
+        None,
+        Some(&[0xcd, 0xb9]),
+        Some(&[0xcd, 0xbf]),
+        Some(&[0xcd, 0xc1]),
+        None,
+        Some(&[0xcd, 0xb4]),
+        Some(&[0xaa, 0xe2]),
+        Some(&[0xaa, 0xdd]),
+        Some(&[0xcd, 0xba]),
+        Some(&[0xaa, 0xe4]),
+        Some(&[0xaa, 0xe7]),
+        Some(&[0xaa, 0xe1]),
+        None,
+        Some(&[0xaa, 0xda]),
+        Some(&[0xcd, 0xbe]),
+        Some(&[0xcd, 0xb8]),
+        Some(&[0xcd, 0xc5]),
+        Some(&[0xaa, 0xe9]),
+        Some(&[0xaa, 0xe5]),
+        Some(&[0xaa, 0xe0]),
+        Some(&[0xcd, 0xbd]),
+        Some(&[0xaf, 0xec]),
+        Some(&[0xcd, 0xbb]),
+        Some(&[0xaa, 0xde]),
+        Some(&[0xaa, 0xe8]),
+        None,
+        Some(&[0xcd, 0xb3]),
+        None,
+        Some(&[0xcd, 0xc2]),
+        Some(&[0xcd, 0xc4]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0x62]),
+        Some(&[0xad, 0x5c]),
+        Some(&[0xad, 0x64]),
+        Some(&[0xad, 0x61]),
+        Some(&[0xd0, 0x71]),
+        Some(&[0xd0, 0x74]),
+        Some(&[0xad, 0x5d]),
+        None,
+        Some(&[0xd0, 0x6b]),
+        None,
+        Some(&[0xad, 0x56]),
+        Some(&[0xad, 0x60]),
+        None,
+        Some(&[0xad, 0x63]),
+        Some(&[0xad, 0x65]),
+        Some(&[0xd0, 0xa2]),
+        Some(&[0xd0, 0x77]),
+        None,
+        Some(&[0xad, 0x55]),
+        Some(&[0xd0, 0xa1]),
+        Some(&[0xad, 0x59]),
+        Some(&[0xad, 0x57]),
+        Some(&[0xad, 0x52]),
+        Some(&[0xd0, 0x6f]),
+        None,
+        Some(&[0xd0, 0x7e]),
+        Some(&[0xd0, 0x73]),
+        Some(&[0xd0, 0x76]),
+        Some(&[0xd0, 0xa5]),
+        None,
+        Some(&[0xad, 0x66]),
+        Some(&[0xd0, 0x7d]),
+        Some(&[0xad, 0x5e]),
+        Some(&[0xd0, 0x78]),
+        Some(&[0xd0, 0xa4]),
+        Some(&[0xd0, 0x75]),
+        Some(&[0xd0, 0x79]),
+        Some(&[0xd0, 0x7c]),
+        None,
+        None,
+        Some(&[0xd0, 0x6d]),
+        Some(&[0xd0, 0xa3]),
+        Some(&[0xd0, 0x7b]),
+        None,
+        None,
+        Some(&[0xd0, 0x6c]),
+        None,
+        Some(&[0xd0, 0x70]),
+        Some(&[0xad, 0x5f]),
+        Some(&[0xad, 0x5a]),
+        Some(&[0xad, 0x53]),
+        Some(&[0xad, 0x58]),
+        Some(&[0xad, 0x54]),
+        Some(&[0xad, 0x67]),
+        Some(&[0xd0, 0x6e]),
+        Some(&[0xd3, 0xa5]),
+        Some(&[0xad, 0x5b]),
+        None,
+        None,
+        Some(&[0xd0, 0x7a]),
+        Some(&[0xce, 0x41]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xa8]),
+        Some(&[0xaf, 0xfa]),
+        None,
+        Some(&[0xd3, 0x76]),
+        None,
+        Some(&[0xd3, 0xa3]),
+        Some(&[0xd3, 0x7d]),
+        None,
+        Some(&[0xd3, 0xb2]),
+        None,
+        Some(&[0xd3, 0xaa]),
+        None,
+        Some(&[0xd3, 0x7e]),
+        None,
+        Some(&[0xd3, 0xa9]),
+        Some(&[0xd3, 0x78]),
+        Some(&[0xd3, 0x7c]),
+        Some(&[0xd3, 0xb5]),
+        Some(&[0xaf, 0xfd]),
+        Some(&[0xd3, 0xad]),
+        Some(&[0xd3, 0xa4]),
+        Some(&[0xaf, 0xed]),
+        Some(&[0xd3, 0xb3]),
+        Some(&[0xd3, 0x74]),
+        None,
+        Some(&[0xd3, 0xac]),
+        None,
+        Some(&[0xaf, 0xfc]),
+        Some(&[0xaf, 0xf7]),
+        Some(&[0xd3, 0x73]),
+        Some(&[0xaf, 0xf5]),
+        Some(&[0xaf, 0xf4]),
+        Some(&[0xaf, 0xf9]),
+        Some(&[0xd3, 0xab]),
+        Some(&[0xaf, 0xf1]),
+        Some(&[0xaf, 0xf8]),
+        Some(&[0xd0, 0x72]),
+        Some(&[0xdb, 0x5c]),
+        Some(&[0xd3, 0xa6]),
+        None,
+        None,
+        Some(&[0xd3, 0x7a]),
+        Some(&[0xaf, 0xfb]),
+        Some(&[0xd3, 0x7b]),
+        Some(&[0xd3, 0xa1]),
+        Some(&[0xaf, 0xfe]),
+        Some(&[0xd3, 0x75]),
+        Some(&[0xd3, 0xaf]),
+        None,
+        Some(&[0xd3, 0xae]),
+        Some(&[0xd3, 0xb6]),
+        Some(&[0xaf, 0xf3]),
+        Some(&[0xaf, 0xf0]),
+        Some(&[0xd3, 0xb4]),
+        Some(&[0xd3, 0xb0]),
+        Some(&[0xd3, 0xa7]),
+        Some(&[0xd3, 0xa2]),
+        Some(&[0xaf, 0xf6]),
+        Some(&[0xaf, 0xf2]),
+        Some(&[0xd3, 0x77]),
+        Some(&[0xaf, 0xee]),
+        Some(&[0xd3, 0xb1]),
+        Some(&[0xaf, 0xef]),
+        None,
+        Some(&[0xd3, 0x79]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0x5e]),
+        Some(&[0xd7, 0x60]),
+        Some(&[0xd7, 0x65]),
+        Some(&[0xd7, 0x79]),
+        Some(&[0xb2, 0xfc]),
+        Some(&[0xb2, 0xf2]),
+        None,
+        Some(&[0xd7, 0x5d]),
+        Some(&[0xb2, 0xfd]),
+        Some(&[0xb2, 0xfe]),
+        Some(&[0xd7, 0x68]),
+        Some(&[0xd7, 0x6f]),
+        Some(&[0xd7, 0x75]),
+        None,
+        Some(&[0xd7, 0x62]),
+        None,
+        Some(&[0xd7, 0x69]),
+        None,
+        None,
+        Some(&[0xb3, 0x40]),
+        Some(&[0xd7, 0x77]),
+        Some(&[0xd7, 0x72]),
+        Some(&[0xb2, 0xfa]),
+        Some(&[0xb2, 0xf8]),
+        Some(&[0xd7, 0x6e]),
+        Some(&[0xd7, 0x6a]),
+        Some(&[0xd7, 0x5c]),
+        Some(&[0xb2, 0xef]),
+        Some(&[0xd7, 0x61]),
+        Some(&[0xd7, 0x59]),
+        None,
+        Some(&[0xb2, 0xf7]),
+        Some(&[0xb2, 0xf9]),
+        Some(&[0xd7, 0x66]),
+        Some(&[0xd7, 0x63]),
+        Some(&[0xb2, 0xf4]),
+        Some(&[0xd7, 0x73]),
+        Some(&[0xb2, 0xf1]),
+        Some(&[0xd7, 0x64]),
+        Some(&[0xd7, 0x7a]),
+        Some(&[0xd7, 0x6c]),
+        None,
+        Some(&[0xd7, 0x6b]),
+        Some(&[0xb2, 0xf0]),
+        None,
+        Some(&[0xb2, 0xfb]),
+        None,
+        Some(&[0xb2, 0xf3]),
+        Some(&[0xd7, 0x5a]),
+        Some(&[0xd7, 0x5f]),
+        Some(&[0xd7, 0x70]),
+        Some(&[0xd7, 0x76]),
+        Some(&[0xb3, 0x41]),
+        Some(&[0xd7, 0x5b]),
+        Some(&[0xd7, 0x67]),
+        Some(&[0xd7, 0x6d]),
+        Some(&[0xb2, 0xf6]),
+        None,
+        None,
+        Some(&[0xd7, 0x78]),
+        Some(&[0xd7, 0x71]),
+        Some(&[0xd7, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb2, 0xf5]),
+        None,
+        Some(&[0xdb, 0x6c]),
+        Some(&[0xdb, 0x60]),
+        Some(&[0xb5, 0xd7]),
+        Some(&[0xdb, 0x7d]),
+        Some(&[0xdb, 0xa7]),
+        Some(&[0xdb, 0xaa]),
+        Some(&[0xb5, 0xd5]),
+        Some(&[0xdb, 0x68]),
+        Some(&[0xdb, 0xa3]),
+        Some(&[0xdb, 0x69]),
+        Some(&[0xdb, 0x77]),
+        Some(&[0xb5, 0xe2]),
+        Some(&[0xdb, 0x73]),
+        Some(&[0xb5, 0xdf]),
+        None,
+        Some(&[0xdb, 0x74]),
+        Some(&[0xdb, 0x5d]),
+        None,
+        Some(&[0xdb, 0xa4]),
+        None,
+        None,
+        Some(&[0xb5, 0xe8]),
+        Some(&[0xdb, 0xa1]),
+        Some(&[0xdb, 0x75]),
+        Some(&[0xdb, 0xac]),
+        Some(&[0xdb, 0x70]),
+        Some(&[0xdf, 0xc8]),
+        None,
+        Some(&[0xdb, 0xaf]),
+        Some(&[0xb5, 0xe6]),
+        Some(&[0xdb, 0x6e]),
+        Some(&[0xdb, 0x7a]),
+        Some(&[0xb5, 0xe9]),
+        Some(&[0xb5, 0xd4]),
+        Some(&[0xdb, 0x72]),
+        Some(&[0xdb, 0xad]),
+        Some(&[0xdb, 0x6b]),
+        Some(&[0xdb, 0x64]),
+        Some(&[0xdb, 0x6f]),
+        None,
+        Some(&[0xdb, 0x63]),
+        Some(&[0xdb, 0x61]),
+        Some(&[0xb5, 0xd0]),
+        Some(&[0xdb, 0xa5]),
+        Some(&[0xdb, 0x6a]),
+        Some(&[0xdb, 0xa8]),
+        None,
+        Some(&[0xdb, 0xa9]),
+        Some(&[0xb5, 0xd8]),
+        Some(&[0xb5, 0xdd]),
+        Some(&[0xb5, 0xd9]),
+        Some(&[0xb5, 0xe1]),
+        Some(&[0xdb, 0x7e]),
+        Some(&[0xb5, 0xda]),
+        Some(&[0xdb, 0x76]),
+        Some(&[0xdb, 0x66]),
+        None,
+        Some(&[0xb5, 0xd2]),
+        Some(&[0xdb, 0x5e]),
+        Some(&[0xdb, 0xa2]),
+        Some(&[0xdb, 0xab]),
+        Some(&[0xdb, 0x65]),
+        Some(&[0xb5, 0xe0]),
+        Some(&[0xdb, 0xb0]),
+        Some(&[0xdb, 0x71]),
+        None,
+        Some(&[0xdb, 0x6d]),
+        None,
+        Some(&[0xb5, 0xd1]),
+        Some(&[0xb5, 0xe5]),
+        None,
+        Some(&[0xdb, 0x7c]),
+        Some(&[0xb5, 0xe7]),
+        None,
+        Some(&[0xdb, 0x78]),
+        Some(&[0xb5, 0xdc]),
+        Some(&[0xb5, 0xd6]),
+        Some(&[0xb5, 0xde]),
+        Some(&[0xb5, 0xd3]),
+        Some(&[0xb5, 0xe4]),
+        Some(&[0xdb, 0x79]),
+        Some(&[0xdb, 0x67]),
+        Some(&[0xdb, 0x7b]),
+        Some(&[0xdb, 0x62]),
+        Some(&[0xdb, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xae]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0x5f]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xc7]),
+        None,
+        Some(&[0xdf, 0xdd]),
+        Some(&[0xb8, 0x55]),
+        Some(&[0xdf, 0xcc]),
+        None,
+        Some(&[0xdf, 0xca]),
+        Some(&[0xdf, 0xb5]),
+        Some(&[0xb8, 0xa9]),
+        Some(&[0xdf, 0xc5]),
+        Some(&[0xdf, 0xd9]),
+        Some(&[0xdf, 0xc1]),
+        Some(&[0xb8, 0xb1]),
+        Some(&[0xdf, 0xd8]),
+        Some(&[0xdf, 0xbf]),
+        Some(&[0xb5, 0xe3]),
+        Some(&[0xdf, 0xcf]),
+        Some(&[0xdf, 0xc0]),
+        Some(&[0xdf, 0xd6]),
+        Some(&[0xb8, 0xb0]),
+        Some(&[0xb8, 0xa8]),
+        None,
+        Some(&[0xdf, 0xaa]),
+        Some(&[0xdf, 0xb2]),
+        None,
+        Some(&[0xdf, 0xcb]),
+        Some(&[0xdf, 0xc3]),
+        Some(&[0xdf, 0xdc]),
+        Some(&[0xdf, 0xc6]),
+        Some(&[0xb8, 0xb6]),
+        Some(&[0xdf, 0xd7]),
+        None,
+        Some(&[0xb8, 0xad]),
+        None,
+        Some(&[0xdf, 0xc9]),
+        Some(&[0xdf, 0xd1]),
+        Some(&[0xdf, 0xb6]),
+        Some(&[0xdf, 0xd0]),
+        None,
+        Some(&[0xdf, 0xe1]),
+        Some(&[0xdf, 0xb1]),
+        Some(&[0xdf, 0xd2]),
+        None,
+        Some(&[0xdf, 0xdf]),
+        None,
+        Some(&[0xdf, 0xab]),
+        Some(&[0xb5, 0xdb]),
+        None,
+        Some(&[0xdf, 0xb9]),
+        Some(&[0xdf, 0xb8]),
+        Some(&[0xb8, 0xaf]),
+        None,
+        Some(&[0xdf, 0xbc]),
+        Some(&[0xdf, 0xbe]),
+        Some(&[0xdf, 0xcd]),
+        Some(&[0xdf, 0xde]),
+        Some(&[0xb8, 0xb2]),
+        None,
+        Some(&[0xb8, 0xb3]),
+        None,
+        Some(&[0xdf, 0xb0]),
+        Some(&[0xb8, 0xab]),
+        Some(&[0xdf, 0xb4]),
+        Some(&[0xdf, 0xda]),
+        Some(&[0xb8, 0xb4]),
+        None,
+        Some(&[0xb8, 0xac]),
+        Some(&[0xb8, 0xae]),
+        Some(&[0xb8, 0xb5]),
+        Some(&[0xdf, 0xe0]),
+        Some(&[0xdf, 0xd3]),
+        Some(&[0xdf, 0xce]),
+        None,
+        None,
+        Some(&[0xdf, 0xbb]),
+        Some(&[0xdf, 0xba]),
+        Some(&[0xb8, 0xaa]),
+        Some(&[0xdf, 0xac]),
+        Some(&[0xb8, 0xa7]),
+        Some(&[0xdf, 0xc4]),
+        Some(&[0xdf, 0xad]),
+        Some(&[0xdf, 0xc2]),
+        None,
+        None,
+        Some(&[0xdf, 0xb7]),
+        Some(&[0xdf, 0xdb]),
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xb3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xaf]),
+        Some(&[0xdf, 0xd5]),
+        Some(&[0xdf, 0xae]),
+        Some(&[0xbb, 0x60]),
+        Some(&[0xe3, 0xd3]),
+        None,
+        None,
+        Some(&[0xe3, 0xc2]),
+        None,
+        None,
+        Some(&[0xe3, 0xac]),
+        Some(&[0xe3, 0xca]),
+        Some(&[0xbb, 0x58]),
+        Some(&[0xe3, 0xbb]),
+        Some(&[0xe3, 0xc5]),
+        Some(&[0xbb, 0x5b]),
+        Some(&[0xe3, 0xbe]),
+        Some(&[0xbb, 0x59]),
+        Some(&[0xe3, 0xaf]),
+        Some(&[0xe3, 0xcd]),
+        Some(&[0xe3, 0xae]),
+        Some(&[0xe3, 0xc1]),
+        None,
+        Some(&[0xe3, 0xad]),
+        None,
+        None,
+        Some(&[0xe3, 0xbf]),
+        Some(&[0xe3, 0xc8]),
+        Some(&[0xe3, 0xc6]),
+        Some(&[0xe3, 0xba]),
+        Some(&[0xe3, 0xb5]),
+        Some(&[0xe3, 0xb3]),
+        None,
+        Some(&[0xe3, 0xb4]),
+        Some(&[0xe3, 0xc7]),
+        Some(&[0xe3, 0xd2]),
+        Some(&[0xe3, 0xbc]),
+        Some(&[0xbb, 0x5a]),
+        None,
+        Some(&[0xe3, 0xb7]),
+        None,
+        Some(&[0xe3, 0xcb]),
+        None,
+        Some(&[0xbb, 0x5d]),
+        Some(&[0xe3, 0xb6]),
+        Some(&[0xe3, 0xb0]),
+        Some(&[0xe3, 0xc0]),
+        Some(&[0xbb, 0x61]),
+        None,
+        None,
+        Some(&[0xbb, 0x55]),
+        Some(&[0xbb, 0x5e]),
+        Some(&[0xe3, 0xb8]),
+        Some(&[0xe3, 0xb2]),
+        None,
+        Some(&[0xbb, 0x57]),
+        Some(&[0xdf, 0xd4]),
+        Some(&[0xbb, 0x56]),
+        Some(&[0xe3, 0xc3]),
+        None,
+        Some(&[0xbb, 0x54]),
+        Some(&[0xbb, 0x63]),
+        Some(&[0xbb, 0x5c]),
+        Some(&[0xe3, 0xc4]),
+        Some(&[0xe3, 0xb9]),
+        Some(&[0xe3, 0xb1]),
+        Some(&[0xe3, 0xcc]),
+        Some(&[0xe3, 0xbd]),
+        Some(&[0xbb, 0x62]),
+        Some(&[0xe3, 0xd0]),
+        Some(&[0xbb, 0x5f]),
+        Some(&[0xe3, 0xcf]),
+        None,
+        Some(&[0xe3, 0xc9]),
+        Some(&[0xe3, 0xce]),
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xd1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x73]),
+        Some(&[0xe7, 0x74]),
+        Some(&[0xe7, 0x67]),
+        Some(&[0xe7, 0x66]),
+        Some(&[0xe7, 0x62]),
+        Some(&[0xbd, 0xb4]),
+        None,
+        Some(&[0xbd, 0xac]),
+        Some(&[0xe7, 0x76]),
+        Some(&[0xe7, 0x75]),
+        Some(&[0xdf, 0xa9]),
+        Some(&[0xe7, 0x5f]),
+        Some(&[0xe7, 0x63]),
+        Some(&[0xe7, 0x5d]),
+        None,
+        Some(&[0xe7, 0x70]),
+        Some(&[0xe7, 0x61]),
+        None,
+        Some(&[0xe7, 0x77]),
+        Some(&[0xe7, 0x5a]),
+        Some(&[0xe7, 0x58]),
+        Some(&[0xe7, 0x64]),
+        Some(&[0xe7, 0x6e]),
+        Some(&[0xe7, 0x69]),
+        Some(&[0xbd, 0xb6]),
+        Some(&[0xe7, 0x4f]),
+        None,
+        Some(&[0xe7, 0x6d]),
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xb7]),
+        Some(&[0xdf, 0xbd]),
+        Some(&[0xe7, 0x5b]),
+        Some(&[0xe7, 0x52]),
+        Some(&[0xe7, 0x55]),
+        Some(&[0xe7, 0x7b]),
+        Some(&[0xe7, 0x5c]),
+        Some(&[0xe7, 0x53]),
+        Some(&[0xe7, 0x51]),
+        Some(&[0xe7, 0x4e]),
+        None,
+        Some(&[0xbd, 0xb0]),
+        Some(&[0xe7, 0x65]),
+        Some(&[0xbd, 0xaf]),
+        Some(&[0xbd, 0xb3]),
+        Some(&[0xe7, 0x60]),
+        Some(&[0xe7, 0x68]),
+        Some(&[0xbd, 0xa9]),
+        Some(&[0xe7, 0x78]),
+        Some(&[0xe7, 0x7c]),
+        Some(&[0xbd, 0xab]),
+        None,
+        Some(&[0xe7, 0x57]),
+        Some(&[0xe7, 0x6b]),
+        Some(&[0xe7, 0x6f]),
+        Some(&[0xe7, 0x54]),
+        Some(&[0xe7, 0x79]),
+        Some(&[0xbd, 0xb2]),
+        None,
+        Some(&[0xbd, 0xb1]),
+        Some(&[0xe7, 0x4c]),
+        Some(&[0xbd, 0xb5]),
+        Some(&[0xe7, 0x72]),
+        Some(&[0xe7, 0x56]),
+        Some(&[0xe7, 0x6a]),
+        Some(&[0xe7, 0x50]),
+        Some(&[0xe7, 0x5e]),
+        Some(&[0xe7, 0x59]),
+        Some(&[0xbd, 0xad]),
+        Some(&[0xbd, 0xae]),
+        Some(&[0xe7, 0x6c]),
+        Some(&[0xe7, 0x7d]),
+        Some(&[0xe7, 0x7a]),
+        Some(&[0xe7, 0x71]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0x4d]),
+        None,
+        Some(&[0xbd, 0xaa]),
+        Some(&[0xeb, 0x49]),
+        None,
+        Some(&[0xeb, 0x40]),
+        Some(&[0xeb, 0x43]),
+        None,
+        Some(&[0xbf, 0xbb]),
+        Some(&[0xeb, 0x45]),
+        Some(&[0xea, 0xf9]),
+        Some(&[0xeb, 0x41]),
+        Some(&[0xeb, 0x47]),
+        Some(&[0xbf, 0xb8]),
+        Some(&[0xbf, 0xbc]),
+        Some(&[0xbf, 0xb6]),
+        None,
+        None,
+        Some(&[0xea, 0xfb]),
+        Some(&[0xeb, 0x4c]),
+        None,
+        None,
+        Some(&[0xeb, 0x46]),
+        None,
+        Some(&[0xea, 0xfc]),
+        Some(&[0xeb, 0x55]),
+        Some(&[0xeb, 0x4f]),
+        Some(&[0xea, 0xf8]),
+        Some(&[0xee, 0x46]),
+        Some(&[0xea, 0xfe]),
+        Some(&[0xbf, 0xb7]),
+        None,
+        Some(&[0xeb, 0x4a]),
+        None,
+        Some(&[0xeb, 0x54]),
+        Some(&[0xbf, 0xbf]),
+        None,
+        Some(&[0xeb, 0x51]),
+        Some(&[0xea, 0xfd]),
+        Some(&[0xeb, 0x44]),
+        Some(&[0xeb, 0x48]),
+        Some(&[0xeb, 0x42]),
+        Some(&[0xeb, 0x56]),
+        Some(&[0xeb, 0x53]),
+        Some(&[0xeb, 0x50]),
+        Some(&[0xbf, 0xb9]),
+        Some(&[0xbf, 0xba]),
+        Some(&[0xbf, 0xbe]),
+        Some(&[0xea, 0xfa]),
+        Some(&[0xeb, 0x57]),
+        Some(&[0xbf, 0xbd]),
+        Some(&[0xeb, 0x4d]),
+        None,
+        None,
+        Some(&[0xeb, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0x4e]),
+        Some(&[0xee, 0x53]),
+        Some(&[0xee, 0x40]),
+        Some(&[0xee, 0x45]),
+        Some(&[0xee, 0x52]),
+        Some(&[0xee, 0x44]),
+        Some(&[0xed, 0xfb]),
+        Some(&[0xee, 0x41]),
+        None,
+        Some(&[0xc1, 0xa2]),
+        None,
+        Some(&[0xed, 0xf4]),
+        Some(&[0xee, 0x4d]),
+        Some(&[0xee, 0x4f]),
+        Some(&[0xed, 0xf3]),
+        Some(&[0xc1, 0xa1]),
+        Some(&[0xee, 0x51]),
+        Some(&[0xee, 0x49]),
+        Some(&[0xc1, 0xa8]),
+        Some(&[0xee, 0x50]),
+        Some(&[0xee, 0x42]),
+        Some(&[0xc1, 0xaa]),
+        Some(&[0xed, 0xf9]),
+        Some(&[0xeb, 0x52]),
+        Some(&[0xee, 0x4a]),
+        Some(&[0xee, 0x47]),
+        Some(&[0xed, 0xf5]),
+        Some(&[0xee, 0x55]),
+        Some(&[0xc1, 0xa4]),
+        None,
+        None,
+        Some(&[0xc1, 0xa5]),
+        Some(&[0xed, 0xf7]),
+        Some(&[0xee, 0x48]),
+        None,
+        Some(&[0xee, 0x54]),
+        Some(&[0xee, 0x4b]),
+        Some(&[0xed, 0xfd]),
+        Some(&[0xc1, 0xa7]),
+        Some(&[0xc1, 0xa3]),
+        Some(&[0xee, 0x4c]),
+        Some(&[0xed, 0xfe]),
+        Some(&[0xee, 0x56]),
+        Some(&[0xed, 0xf8]),
+        Some(&[0xee, 0x43]),
+        Some(&[0xee, 0x4e]),
+        Some(&[0xed, 0xfa]),
+        Some(&[0xed, 0xfc]),
+        None,
+        Some(&[0xc2, 0xcb]),
+        Some(&[0xed, 0xf6]),
+        Some(&[0xc1, 0xa9]),
+        Some(&[0xc2, 0xc4]),
+        Some(&[0xc1, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xa6]),
+        Some(&[0xc2, 0xc8]),
+        Some(&[0xf0, 0xb3]),
+        None,
+        Some(&[0xf0, 0xa9]),
+        Some(&[0xf0, 0xa4]),
+        Some(&[0xf0, 0xaa]),
+        Some(&[0xf0, 0xb4]),
+        Some(&[0xf0, 0xb8]),
+        Some(&[0xf0, 0xb7]),
+        Some(&[0xc2, 0xca]),
+        Some(&[0xc2, 0xc9]),
+        None,
+        None,
+        Some(&[0xf0, 0xab]),
+        Some(&[0xf0, 0xb9]),
+        Some(&[0xf0, 0xae]),
+        Some(&[0xf0, 0xa6]),
+        None,
+        Some(&[0xf0, 0xa8]),
+        Some(&[0xf0, 0xa7]),
+        Some(&[0xf0, 0xad]),
+        Some(&[0xf0, 0xb2]),
+        Some(&[0xf0, 0xa5]),
+        Some(&[0xf0, 0xac]),
+        Some(&[0xf0, 0xb1]),
+        Some(&[0xc2, 0xc7]),
+        None,
+        Some(&[0xf0, 0xaf]),
+        None,
+        Some(&[0xc2, 0xc5]),
+        Some(&[0xf0, 0xb0]),
+        Some(&[0xc2, 0xc3]),
+        Some(&[0xc2, 0xc6]),
+        Some(&[0xf2, 0xd5]),
+        Some(&[0xf0, 0xb5]),
+        None,
+        None,
+        Some(&[0xc3, 0xc2]),
+        None,
+        Some(&[0xf2, 0xcd]),
+        Some(&[0xf2, 0xd1]),
+        Some(&[0xf2, 0xc9]),
+        Some(&[0xf2, 0xcc]),
+        None,
+        Some(&[0xf2, 0xd4]),
+        Some(&[0xc3, 0xc0]),
+        Some(&[0xf2, 0xd9]),
+        Some(&[0xf2, 0xd2]),
+        None,
+        Some(&[0xf2, 0xca]),
+        Some(&[0xf2, 0xda]),
+        Some(&[0xf2, 0xd3]),
+        Some(&[0xc3, 0xc3]),
+        Some(&[0xc3, 0xc4]),
+        Some(&[0xf2, 0xd7]),
+        None,
+        Some(&[0xf2, 0xcb]),
+        Some(&[0xc3, 0xbf]),
+        Some(&[0xc3, 0xc1]),
+        Some(&[0xf2, 0xc6]),
+        Some(&[0xf2, 0xce]),
+        Some(&[0xf2, 0xc8]),
+        None,
+        Some(&[0xf2, 0xd8]),
+        Some(&[0xf2, 0xd6]),
+        Some(&[0xf2, 0xc7]),
+        Some(&[0xf2, 0xcf]),
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xbe]),
+        Some(&[0xc3, 0xc5]),
+        Some(&[0xf2, 0xd0]),
+        Some(&[0xc4, 0xa7]),
+        Some(&[0xc4, 0xa9]),
+        Some(&[0xc4, 0xa6]),
+        None,
+        Some(&[0xf4, 0xc3]),
+        Some(&[0xf4, 0xbb]),
+        Some(&[0xf4, 0xb9]),
+        Some(&[0xf4, 0xbd]),
+        Some(&[0xf4, 0xba]),
+        None,
+        None,
+        Some(&[0xf4, 0xbf]),
+        Some(&[0xf4, 0xc1]),
+        Some(&[0xc4, 0xaa]),
+        Some(&[0xc4, 0xac]),
+        None,
+        Some(&[0xf4, 0xc0]),
+        Some(&[0xc4, 0xad]),
+        Some(&[0xc4, 0xab]),
+        Some(&[0xf4, 0xc2]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xf4]),
+        Some(&[0xf5, 0xf1]),
+        Some(&[0xf5, 0xf7]),
+        Some(&[0xc4, 0xf6]),
+        Some(&[0xf4, 0xbc]),
+        Some(&[0xf5, 0xf6]),
+        None,
+        Some(&[0xf5, 0xfd]),
+        Some(&[0xf5, 0xf4]),
+        Some(&[0xf5, 0xfb]),
+        Some(&[0xf5, 0xfa]),
+        Some(&[0xf4, 0xb8]),
+        Some(&[0xf5, 0xf5]),
+        Some(&[0xf0, 0xb6]),
+        Some(&[0xf5, 0xfe]),
+        Some(&[0xf5, 0xf3]),
+        Some(&[0xf5, 0xf8]),
+        None,
+        Some(&[0xf5, 0xfc]),
+        Some(&[0xf5, 0xf2]),
+        None,
+        Some(&[0xf7, 0x4a]),
+        Some(&[0xc4, 0xf5]),
+        Some(&[0xf5, 0xf9]),
+        None,
+        None,
+        Some(&[0xf7, 0xf4]),
+        Some(&[0xf7, 0x4b]),
+        Some(&[0xf7, 0x49]),
+        Some(&[0xf7, 0x47]),
+        Some(&[0xf7, 0x48]),
+        Some(&[0xf7, 0x4c]),
+        None,
+        Some(&[0xc5, 0xd9]),
+        Some(&[0xf7, 0xf2]),
+        Some(&[0xf7, 0xf0]),
+        Some(&[0xf7, 0xf5]),
+        Some(&[0xf7, 0xf3]),
+        None,
+        Some(&[0xf7, 0xf6]),
+        Some(&[0xc5, 0xda]),
+        Some(&[0xf7, 0xf1]),
+        None,
+        None,
+        Some(&[0xf8, 0xbc]),
+        None,
+        None,
+        Some(&[0xf9, 0x45]),
+        Some(&[0xf9, 0x46]),
+        Some(&[0xf9, 0x47]),
+        None,
+        None,
+        Some(&[0xf9, 0xc7]),
+        Some(&[0xf9, 0xbd]),
+        Some(&[0xca, 0x4f]),
+        Some(&[0xaa, 0xea]),
+        None,
+        Some(&[0xad, 0x68]),
+        None,
+        Some(&[0xd3, 0xb8]),
+        Some(&[0xd3, 0xb7]),
+        Some(&[0xb0, 0x40]),
+        Some(&[0xb3, 0x42]),
+        Some(&[0xd7, 0x7c]),
+        None,
+        None,
+        Some(&[0xd7, 0x7b]),
+        None,
+        Some(&[0xb5, 0xea]),
+        Some(&[0xb8, 0xb8]),
+        None,
+        Some(&[0xb8, 0xb7]),
+        Some(&[0xb8, 0xb9]),
+        None,
+        Some(&[0xe3, 0xd4]),
+        Some(&[0xe7, 0x7e]),
+        Some(&[0xeb, 0x58]),
+        Some(&[0xeb, 0x5a]),
+        Some(&[0xeb, 0x59]),
+        None,
+        Some(&[0xc1, 0xab]),
+        Some(&[0xee, 0x57]),
+        Some(&[0xf0, 0xba]),
+        Some(&[0xf9, 0xa5]),
+        Some(&[0xa6, 0xe4]),
+        None,
+        Some(&[0xcd, 0xc9]),
+        Some(&[0xcd, 0xca]),
+        Some(&[0xcd, 0xc8]),
+        Some(&[0xcd, 0xc7]),
+        Some(&[0xaa, 0xeb]),
+        None,
+        Some(&[0xd0, 0xa9]),
+        Some(&[0xd0, 0xa7]),
+        None,
+        None,
+        Some(&[0xd0, 0xa6]),
+        None,
+        Some(&[0xad, 0x69]),
+        Some(&[0xad, 0x6b]),
+        Some(&[0xad, 0x6a]),
+        Some(&[0xd0, 0xa8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd3, 0xc4]),
+        Some(&[0xd3, 0xc1]),
+        Some(&[0xd3, 0xbf]),
+        None,
+        None,
+        Some(&[0xb0, 0x41]),
+        Some(&[0xd3, 0xc2]),
+        Some(&[0xb0, 0x46]),
+        Some(&[0xd3, 0xbc]),
+        Some(&[0xd3, 0xcb]),
+        None,
+        Some(&[0xd3, 0xcd]),
+        Some(&[0xd3, 0xbd]),
+        None,
+        Some(&[0xb0, 0x43]),
+        Some(&[0xd3, 0xce]),
+        Some(&[0xd3, 0xc9]),
+        Some(&[0xd3, 0xbb]),
+        Some(&[0xd3, 0xc0]),
+        Some(&[0xd3, 0xca]),
+        Some(&[0xd3, 0xc6]),
+        Some(&[0xd3, 0xc3]),
+        None,
+        Some(&[0xb0, 0x48]),
+        Some(&[0xd3, 0xcc]),
+        Some(&[0xd3, 0xbe]),
+        None,
+        None,
+        Some(&[0xd3, 0xc7]),
+        Some(&[0xd3, 0xb9]),
+        Some(&[0xb0, 0x47]),
+        Some(&[0xb0, 0x44]),
+        Some(&[0xd3, 0xc5]),
+        None,
+        Some(&[0xd3, 0xc8]),
+        Some(&[0xd3, 0xba]),
+        Some(&[0xb0, 0x45]),
+        Some(&[0xb0, 0x42]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0x4c]),
+        Some(&[0xd7, 0xa5]),
+        Some(&[0xb3, 0x4b]),
+        None,
+        Some(&[0xd7, 0xa8]),
+        Some(&[0xd7, 0xab]),
+        Some(&[0xb3, 0x48]),
+        Some(&[0xb3, 0x46]),
+        Some(&[0xd7, 0x7e]),
+        Some(&[0xd7, 0xa9]),
+        Some(&[0xd7, 0xa7]),
+        Some(&[0xd7, 0xa4]),
+        Some(&[0xd7, 0xac]),
+        Some(&[0xd7, 0xad]),
+        Some(&[0xd7, 0xaf]),
+        Some(&[0xd7, 0xb0]),
+        Some(&[0xd7, 0x7d]),
+        Some(&[0xb3, 0x45]),
+        Some(&[0xd7, 0xa2]),
+        Some(&[0xd7, 0xa1]),
+        Some(&[0xd7, 0xae]),
+        Some(&[0xb3, 0x47]),
+        Some(&[0xd7, 0xa3]),
+        Some(&[0xb3, 0x49]),
+        Some(&[0xb3, 0x44]),
+        Some(&[0xd7, 0xa6]),
+        Some(&[0xb3, 0x4d]),
+        None,
+        Some(&[0xb3, 0x4a]),
+        Some(&[0xd7, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xf1]),
+        Some(&[0xdb, 0xbf]),
+        None,
+        Some(&[0xdb, 0xb4]),
+        Some(&[0xb5, 0xee]),
+        None,
+        Some(&[0xdf, 0xe7]),
+        Some(&[0xdb, 0xbd]),
+        Some(&[0xdb, 0xb1]),
+        Some(&[0xb5, 0xec]),
+        Some(&[0xdb, 0xb6]),
+        Some(&[0xb5, 0xef]),
+        Some(&[0xdb, 0xba]),
+        Some(&[0xdb, 0xb8]),
+        Some(&[0xb5, 0xf2]),
+        Some(&[0xb5, 0xeb]),
+        None,
+        None,
+        Some(&[0xdb, 0xb2]),
+        Some(&[0xdb, 0xb5]),
+        Some(&[0xb5, 0xf0]),
+        None,
+        Some(&[0xdb, 0xb3]),
+        None,
+        Some(&[0xdb, 0xbe]),
+        Some(&[0xdb, 0xbc]),
+        Some(&[0xdb, 0xb7]),
+        Some(&[0xdb, 0xb9]),
+        Some(&[0xdb, 0xbb]),
+        Some(&[0xb5, 0xed]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdf, 0xe8]),
+        Some(&[0xdf, 0xee]),
+        Some(&[0xdf, 0xe4]),
+        Some(&[0xdf, 0xea]),
+        Some(&[0xb8, 0xba]),
+        Some(&[0xdf, 0xe6]),
+        Some(&[0xb8, 0xc0]),
+        None,
+        None,
+        Some(&[0xb8, 0xbf]),
+        None,
+        Some(&[0xb8, 0xbe]),
+        Some(&[0xdf, 0xed]),
+        Some(&[0xb8, 0xc1]),
+        Some(&[0xb8, 0xc2]),
+        Some(&[0xdf, 0xe3]),
+        Some(&[0xdf, 0xf0]),
+        Some(&[0xb8, 0xc3]),
+        Some(&[0xb8, 0xbd]),
+        Some(&[0xb8, 0xbc]),
+        Some(&[0xdf, 0xec]),
+        Some(&[0xb8, 0xc4]),
+        Some(&[0xdf, 0xe2]),
+        Some(&[0xdf, 0xe5]),
+        Some(&[0xdf, 0xef]),
+        Some(&[0xdf, 0xeb]),
+        None,
+        None,
+        Some(&[0xe3, 0xf4]),
+        Some(&[0xe3, 0xe9]),
+        Some(&[0xb8, 0xbb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0x6a]),
+        Some(&[0xe3, 0xdd]),
+        Some(&[0xe3, 0xf2]),
+        Some(&[0xe3, 0xde]),
+        Some(&[0xbb, 0x65]),
+        None,
+        Some(&[0xe3, 0xdb]),
+        None,
+        Some(&[0xe3, 0xe4]),
+        Some(&[0xe3, 0xdc]),
+        Some(&[0xbb, 0x67]),
+        Some(&[0xe3, 0xd6]),
+        Some(&[0xe3, 0xf1]),
+        Some(&[0xbb, 0x68]),
+        Some(&[0xe3, 0xee]),
+        Some(&[0xe3, 0xef]),
+        Some(&[0xe3, 0xd7]),
+        Some(&[0xbb, 0x6d]),
+        Some(&[0xe3, 0xe6]),
+        None,
+        Some(&[0xe3, 0xe0]),
+        Some(&[0xe3, 0xe7]),
+        Some(&[0xe3, 0xda]),
+        None,
+        Some(&[0xe3, 0xf3]),
+        Some(&[0xe3, 0xeb]),
+        Some(&[0xe3, 0xe5]),
+        Some(&[0xe3, 0xd5]),
+        Some(&[0xbb, 0x69]),
+        Some(&[0xe3, 0xec]),
+        None,
+        Some(&[0xbb, 0x6c]),
+        Some(&[0xe3, 0xf0]),
+        None,
+        Some(&[0xe3, 0xea]),
+        Some(&[0xbb, 0x66]),
+        Some(&[0xe3, 0xe8]),
+        None,
+        Some(&[0xe3, 0xe2]),
+        Some(&[0xbb, 0x64]),
+        Some(&[0xe3, 0xd9]),
+        Some(&[0xe3, 0xe1]),
+        Some(&[0xe3, 0xed]),
+        Some(&[0xe3, 0xdf]),
+        None,
+        None,
+        Some(&[0xe3, 0xe3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xc1]),
+        Some(&[0xdf, 0xe9]),
+        Some(&[0xe7, 0xb2]),
+        Some(&[0xe7, 0xbb]),
+        Some(&[0xe7, 0xb1]),
+        Some(&[0xe7, 0xad]),
+        Some(&[0xe7, 0xaa]),
+        Some(&[0xbd, 0xc2]),
+        Some(&[0xe7, 0xa8]),
+        Some(&[0xbb, 0x6b]),
+        Some(&[0xe7, 0xa1]),
+        Some(&[0xbd, 0xc0]),
+        Some(&[0xe7, 0xa7]),
+        Some(&[0xbd, 0xbf]),
+        Some(&[0xe7, 0xac]),
+        Some(&[0xe7, 0xa9]),
+        Some(&[0xe7, 0xb9]),
+        Some(&[0xe7, 0xb4]),
+        Some(&[0xe7, 0xae]),
+        Some(&[0xe7, 0xb3]),
+        Some(&[0xbd, 0xbb]),
+        Some(&[0xe7, 0xab]),
+        Some(&[0xe7, 0xbe]),
+        Some(&[0xe7, 0xa2]),
+        Some(&[0xe7, 0xa3]),
+        Some(&[0xe7, 0xba]),
+        Some(&[0xbd, 0xbc]),
+        Some(&[0xe7, 0xbf]),
+        Some(&[0xbd, 0xbe]),
+        Some(&[0xe7, 0xc0]),
+        Some(&[0xe7, 0xb0]),
+        Some(&[0xe3, 0xd8]),
+        Some(&[0xe7, 0xb6]),
+        Some(&[0xe7, 0xaf]),
+        Some(&[0xe7, 0xb8]),
+        Some(&[0xe7, 0xb5]),
+        None,
+        None,
+        None,
+        Some(&[0xe7, 0xa6]),
+        Some(&[0xbd, 0xb9]),
+        Some(&[0xe7, 0xbd]),
+        Some(&[0xbd, 0xba]),
+        Some(&[0xe7, 0xa4]),
+        Some(&[0xbd, 0xbd]),
+        Some(&[0xeb, 0x64]),
+        Some(&[0xe7, 0xb7]),
+        Some(&[0xe7, 0xbc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0x61]),
+        Some(&[0xbd, 0xb8]),
+        Some(&[0xbf, 0xc0]),
+        Some(&[0xeb, 0x6b]),
+        Some(&[0xeb, 0x67]),
+        None,
+        Some(&[0xeb, 0x65]),
+        Some(&[0xeb, 0x60]),
+        Some(&[0xeb, 0x6f]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xc4]),
+        None,
+        Some(&[0xeb, 0x5c]),
+        Some(&[0xeb, 0x68]),
+        Some(&[0xeb, 0x69]),
+        Some(&[0xeb, 0x5f]),
+        Some(&[0xeb, 0x5e]),
+        Some(&[0xeb, 0x6c]),
+        None,
+        Some(&[0xeb, 0x62]),
+        Some(&[0xeb, 0x5d]),
+        Some(&[0xeb, 0x63]),
+        None,
+        Some(&[0xeb, 0x6e]),
+        Some(&[0xeb, 0x5b]),
+        Some(&[0xeb, 0x6d]),
+        Some(&[0xeb, 0x6a]),
+        Some(&[0xbf, 0xc2]),
+        Some(&[0xbf, 0xc1]),
+        None,
+        None,
+        Some(&[0xbf, 0xc3]),
+        Some(&[0xeb, 0x66]),
+        Some(&[0xf0, 0xcb]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0x59]),
+        Some(&[0xc1, 0xb1]),
+        Some(&[0xee, 0x5d]),
+        Some(&[0xee, 0x5a]),
+        Some(&[0xee, 0x61]),
+        Some(&[0xee, 0x67]),
+        Some(&[0xee, 0x5c]),
+        None,
+        Some(&[0xee, 0x70]),
+        Some(&[0xc1, 0xae]),
+        Some(&[0xee, 0x6a]),
+        Some(&[0xee, 0x5f]),
+        Some(&[0xee, 0x6b]),
+        Some(&[0xee, 0x66]),
+        Some(&[0xee, 0x6d]),
+        Some(&[0xee, 0x5e]),
+        Some(&[0xc1, 0xb3]),
+        Some(&[0xc1, 0xb2]),
+        Some(&[0xee, 0x60]),
+        Some(&[0xee, 0x6e]),
+        Some(&[0xee, 0x58]),
+        Some(&[0xee, 0x6c]),
+        Some(&[0xc1, 0xac]),
+        None,
+        Some(&[0xee, 0x64]),
+        Some(&[0xee, 0x63]),
+        Some(&[0xee, 0x68]),
+        Some(&[0xee, 0x5b]),
+        Some(&[0xc1, 0xb0]),
+        None,
+        Some(&[0xc1, 0xb4]),
+        Some(&[0xee, 0x62]),
+        Some(&[0xee, 0x69]),
+        Some(&[0xc1, 0xb5]),
+        Some(&[0xee, 0x65]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xad]),
+        Some(&[0xc1, 0xaf]),
+        Some(&[0xf0, 0xc7]),
+        Some(&[0xf0, 0xc5]),
+        None,
+        None,
+        Some(&[0xf0, 0xcc]),
+        Some(&[0xf0, 0xc9]),
+        Some(&[0xf0, 0xcd]),
+        None,
+        Some(&[0xf0, 0xbe]),
+        Some(&[0xf0, 0xc6]),
+        Some(&[0xf0, 0xd1]),
+        Some(&[0xee, 0x6f]),
+        Some(&[0xf0, 0xc2]),
+        Some(&[0xc2, 0xcf]),
+        Some(&[0xe7, 0xa5]),
+        Some(&[0xf0, 0xbd]),
+        Some(&[0xf0, 0xca]),
+        Some(&[0xf0, 0xc4]),
+        Some(&[0xf0, 0xc1]),
+        Some(&[0xf0, 0xbc]),
+        Some(&[0xf0, 0xbb]),
+        Some(&[0xf0, 0xd0]),
+        None,
+        Some(&[0xf0, 0xc0]),
+        Some(&[0xf0, 0xbf]),
+        Some(&[0xc2, 0xcd]),
+        Some(&[0xf0, 0xc8]),
+        None,
+        Some(&[0xc2, 0xcc]),
+        None,
+        None,
+        Some(&[0xc2, 0xce]),
+        Some(&[0xf0, 0xc3]),
+        Some(&[0xf0, 0xcf]),
+        None,
+        Some(&[0xf2, 0xde]),
+        Some(&[0xf2, 0xdf]),
+        None,
+        Some(&[0xc3, 0xc9]),
+        Some(&[0xf2, 0xdc]),
+        Some(&[0xc3, 0xc6]),
+        Some(&[0xf2, 0xe4]),
+        None,
+        Some(&[0xc3, 0xca]),
+        Some(&[0xf2, 0xe6]),
+        Some(&[0xf2, 0xdb]),
+        Some(&[0xf0, 0xce]),
+        Some(&[0xf2, 0xe8]),
+        Some(&[0xf2, 0xdd]),
+        None,
+        Some(&[0xc3, 0xc7]),
+        Some(&[0xf2, 0xe3]),
+        None,
+        Some(&[0xf2, 0xe5]),
+        Some(&[0xf2, 0xe0]),
+        Some(&[0xf2, 0xe7]),
+        Some(&[0xf2, 0xe2]),
+        Some(&[0xf2, 0xe1]),
+        Some(&[0xc3, 0xc8]),
+        None,
+        None,
+        Some(&[0xf4, 0xc5]),
+        Some(&[0xf4, 0xc6]),
+        None,
+        Some(&[0xf4, 0xc8]),
+        Some(&[0xc4, 0xae]),
+        Some(&[0xc4, 0xaf]),
+        Some(&[0xf4, 0xc9]),
+        Some(&[0xf4, 0xc7]),
+        None,
+        Some(&[0xf4, 0xc4]),
+        None,
+        Some(&[0xf6, 0x42]),
+        Some(&[0xf6, 0x45]),
+        Some(&[0xf6, 0x41]),
+        None,
+        Some(&[0xc4, 0xfa]),
+        Some(&[0xf6, 0x43]),
+        Some(&[0xc4, 0xf9]),
+        Some(&[0xc4, 0xf8]),
+        Some(&[0xc4, 0xf7]),
+        Some(&[0xf6, 0x44]),
+        Some(&[0xf7, 0x51]),
+        Some(&[0xf7, 0x4f]),
+        None,
+        Some(&[0xf7, 0x4e]),
+        Some(&[0xf6, 0x40]),
+        Some(&[0xf7, 0x50]),
+        Some(&[0xf6, 0x46]),
+        Some(&[0xf7, 0x4d]),
+        None,
+        Some(&[0xf7, 0xf9]),
+        Some(&[0xf7, 0xd7]),
+        Some(&[0xf7, 0xf7]),
+        Some(&[0xc5, 0xdb]),
+        Some(&[0xf7, 0xf8]),
+        Some(&[0xf7, 0xfa]),
+        None,
+        Some(&[0xf8, 0xbf]),
+        Some(&[0xc5, 0xfa]),
+        Some(&[0xf8, 0xbe]),
+        Some(&[0xf8, 0xbd]),
+        Some(&[0xc5, 0xfb]),
+        None,
+        Some(&[0xc6, 0x5a]),
+        Some(&[0xf9, 0x6e]),
+        Some(&[0xf9, 0xa7]),
+        Some(&[0xf9, 0xa6]),
+        Some(&[0xf9, 0xa8]),
+        Some(&[0xa6, 0xe5]),
+        Some(&[0xd0, 0xaa]),
+        None,
+        Some(&[0xd3, 0xcf]),
+        Some(&[0xd3, 0xd0]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc0]),
+        None,
+        Some(&[0xf6, 0x47]),
+        Some(&[0xf8, 0xc0]),
+        Some(&[0xa6, 0xe6]),
+        Some(&[0xad, 0x6c]),
+        Some(&[0xd0, 0xab]),
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xb1]),
+        Some(&[0xb3, 0x4e]),
+        None,
+        Some(&[0xdb, 0xc2]),
+        Some(&[0xdb, 0xc1]),
+        Some(&[0xb5, 0xf3]),
+        None,
+        Some(&[0xb8, 0xc5]),
+        Some(&[0xe7, 0xc1]),
+        Some(&[0xbd, 0xc3]),
+        None,
+        Some(&[0xbd, 0xc4]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xc5]),
+        Some(&[0xc5, 0xfc]),
+        Some(&[0xa6, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xac]),
+        Some(&[0xaa, 0xed]),
+        Some(&[0xd0, 0xae]),
+        Some(&[0xd0, 0xad]),
+        Some(&[0xad, 0x6d]),
+        None,
+        Some(&[0xd3, 0xd1]),
+        None,
+        Some(&[0xd3, 0xd8]),
+        Some(&[0xb0, 0x49]),
+        Some(&[0xd3, 0xd6]),
+        Some(&[0xd3, 0xd4]),
+        None,
+        Some(&[0xd3, 0xdb]),
+        Some(&[0xd3, 0xd2]),
+        Some(&[0xd3, 0xd3]),
+        Some(&[0xb0, 0x4a]),
+        None,
+        Some(&[0xb0, 0x4e]),
+        None,
+        None,
+        Some(&[0xd3, 0xdc]),
+        Some(&[0xb0, 0x4d]),
+        Some(&[0xd3, 0xda]),
+        Some(&[0xd3, 0xd7]),
+        Some(&[0xd3, 0xd5]),
+        Some(&[0xb0, 0x4b]),
+        Some(&[0xb0, 0x4c]),
+        Some(&[0xd3, 0xd9]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0x50]),
+        Some(&[0xd7, 0xb2]),
+        None,
+        Some(&[0xb3, 0x55]),
+        Some(&[0xd7, 0xc2]),
+        Some(&[0xb3, 0x54]),
+        Some(&[0xd7, 0xc4]),
+        None,
+        None,
+        Some(&[0xd7, 0xb8]),
+        Some(&[0xb3, 0x52]),
+        Some(&[0xd7, 0xc3]),
+        None,
+        Some(&[0xd7, 0xb3]),
+        Some(&[0xb3, 0x53]),
+        Some(&[0xd7, 0xbf]),
+        Some(&[0xd7, 0xbb]),
+        Some(&[0xd7, 0xbd]),
+        Some(&[0xd7, 0xb7]),
+        Some(&[0xd7, 0xbe]),
+        None,
+        None,
+        Some(&[0xb3, 0x4f]),
+        Some(&[0xd7, 0xba]),
+        None,
+        Some(&[0xd7, 0xb9]),
+        Some(&[0xd7, 0xb5]),
+        None,
+        Some(&[0xd7, 0xc0]),
+        None,
+        None,
+        Some(&[0xd7, 0xbc]),
+        Some(&[0xd7, 0xb4]),
+        None,
+        Some(&[0xd7, 0xb6]),
+        Some(&[0xb3, 0x51]),
+        Some(&[0xd7, 0xc1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb5, 0xf6]),
+        Some(&[0xdb, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xc9]),
+        Some(&[0xdb, 0xcb]),
+        Some(&[0xdb, 0xc6]),
+        Some(&[0xdb, 0xc5]),
+        Some(&[0xdb, 0xc3]),
+        None,
+        Some(&[0xdb, 0xca]),
+        Some(&[0xdb, 0xcc]),
+        Some(&[0xdb, 0xc8]),
+        None,
+        Some(&[0xdb, 0xc7]),
+        Some(&[0xb5, 0xf4]),
+        Some(&[0xb5, 0xf5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xcf]),
+        Some(&[0xb8, 0xcd]),
+        Some(&[0xdf, 0xf2]),
+        Some(&[0xdf, 0xf8]),
+        Some(&[0xdf, 0xf3]),
+        Some(&[0xdf, 0xf4]),
+        Some(&[0xf9, 0xd8]),
+        Some(&[0xdf, 0xf9]),
+        None,
+        Some(&[0xb8, 0xcf]),
+        None,
+        Some(&[0xb8, 0xc7]),
+        Some(&[0xb8, 0xce]),
+        Some(&[0xdf, 0xf1]),
+        Some(&[0xdb, 0xc4]),
+        Some(&[0xb8, 0xca]),
+        Some(&[0xb8, 0xc8]),
+        Some(&[0xdf, 0xf7]),
+        Some(&[0xdf, 0xf6]),
+        Some(&[0xb8, 0xc9]),
+        Some(&[0xb8, 0xcb]),
+        Some(&[0xdf, 0xf5]),
+        Some(&[0xb8, 0xc6]),
+        None,
+        Some(&[0xb8, 0xcc]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe3, 0xf6]),
+        Some(&[0xbb, 0x74]),
+        None,
+        None,
+        Some(&[0xe4, 0x42]),
+        Some(&[0xe4, 0x41]),
+        None,
+        Some(&[0xe3, 0xfb]),
+        Some(&[0xbb, 0x76]),
+        Some(&[0xe4, 0x40]),
+        Some(&[0xe3, 0xf7]),
+        Some(&[0xe3, 0xf8]),
+        Some(&[0xbb, 0x6e]),
+        Some(&[0xbb, 0x70]),
+        None,
+        Some(&[0xe3, 0xfd]),
+        Some(&[0xe3, 0xf5]),
+        Some(&[0xbb, 0x72]),
+        Some(&[0xbb, 0x71]),
+        Some(&[0xe3, 0xf9]),
+        Some(&[0xe3, 0xfe]),
+        Some(&[0xe3, 0xfc]),
+        Some(&[0xbb, 0x73]),
+        Some(&[0xe3, 0xfa]),
+        None,
+        None,
+        Some(&[0xdb, 0xce]),
+        Some(&[0xbb, 0x6f]),
+        None,
+        None,
+        Some(&[0xe7, 0xc2]),
+        Some(&[0xe7, 0xc9]),
+        Some(&[0xbd, 0xc6]),
+        None,
+        Some(&[0xe7, 0xcd]),
+        Some(&[0xbd, 0xca]),
+        Some(&[0xe7, 0xc5]),
+        Some(&[0xe7, 0xc3]),
+        None,
+        Some(&[0xe7, 0xcc]),
+        None,
+        Some(&[0xbd, 0xc5]),
+        Some(&[0xe7, 0xcb]),
+        Some(&[0xbd, 0xc7]),
+        Some(&[0xbd, 0xc8]),
+        Some(&[0xe7, 0xc4]),
+        Some(&[0xbd, 0xc9]),
+        Some(&[0xe7, 0xca]),
+        Some(&[0xe7, 0xc6]),
+        Some(&[0xe7, 0xc7]),
+        Some(&[0xe7, 0xc8]),
+        Some(&[0xbb, 0x75]),
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0x70]),
+        Some(&[0xeb, 0x7c]),
+        None,
+        Some(&[0xbf, 0xca]),
+        Some(&[0xeb, 0x77]),
+        Some(&[0xeb, 0x79]),
+        None,
+        Some(&[0xbf, 0xc8]),
+        Some(&[0xeb, 0x71]),
+        Some(&[0xeb, 0x75]),
+        None,
+        Some(&[0xeb, 0x78]),
+        Some(&[0xbf, 0xc6]),
+        Some(&[0xbf, 0xc9]),
+        Some(&[0xeb, 0x7b]),
+        Some(&[0xeb, 0x73]),
+        Some(&[0xeb, 0x74]),
+        Some(&[0xeb, 0x7a]),
+        Some(&[0xeb, 0x72]),
+        Some(&[0xeb, 0x76]),
+        Some(&[0xbf, 0xc7]),
+        Some(&[0xee, 0x72]),
+        None,
+        Some(&[0xee, 0x71]),
+        Some(&[0xc1, 0xb7]),
+        Some(&[0xee, 0x77]),
+        Some(&[0xc1, 0xb9]),
+        None,
+        None,
+        Some(&[0xc1, 0xb6]),
+        Some(&[0xee, 0x73]),
+        Some(&[0xc1, 0xba]),
+        Some(&[0xee, 0x74]),
+        None,
+        None,
+        Some(&[0xee, 0x75]),
+        Some(&[0xee, 0x78]),
+        None,
+        Some(&[0xc1, 0xb8]),
+        None,
+        Some(&[0xf0, 0xd6]),
+        None,
+        None,
+        Some(&[0xf0, 0xd9]),
+        None,
+        Some(&[0xf0, 0xd3]),
+        Some(&[0xf0, 0xd5]),
+        None,
+        None,
+        Some(&[0xf0, 0xd4]),
+        Some(&[0xf0, 0xd7]),
+        Some(&[0xf0, 0xd8]),
+        Some(&[0xee, 0x76]),
+        Some(&[0xf0, 0xd2]),
+        None,
+        None,
+        Some(&[0xc3, 0xcd]),
+        Some(&[0xf2, 0xec]),
+        Some(&[0xf2, 0xef]),
+        Some(&[0xf2, 0xf1]),
+        Some(&[0xf2, 0xea]),
+        Some(&[0xf2, 0xeb]),
+        Some(&[0xf2, 0xee]),
+        Some(&[0xf2, 0xf0]),
+        Some(&[0xc3, 0xce]),
+        Some(&[0xc3, 0xcc]),
+        Some(&[0xc3, 0xcb]),
+        Some(&[0xf2, 0xed]),
+        Some(&[0xf2, 0xe9]),
+        Some(&[0xf4, 0xca]),
+        Some(&[0xc4, 0xb0]),
+        None,
+        Some(&[0xf4, 0xcb]),
+        None,
+        None,
+        Some(&[0xf6, 0x49]),
+        Some(&[0xc4, 0xfb]),
+        Some(&[0xf6, 0x4b]),
+        Some(&[0xc4, 0xfc]),
+        Some(&[0xf6, 0x48]),
+        Some(&[0xf6, 0x4a]),
+        Some(&[0xc5, 0xa8]),
+        None,
+        Some(&[0xf7, 0x52]),
+        Some(&[0xc5, 0xa7]),
+        Some(&[0xf7, 0xfd]),
+        Some(&[0xf7, 0xfc]),
+        None,
+        Some(&[0xf7, 0xfb]),
+        None,
+        None,
+        Some(&[0xf9, 0x48]),
+        Some(&[0xf9, 0x49]),
+        Some(&[0xf9, 0x4b]),
+        Some(&[0xf9, 0x4a]),
+        None,
+        Some(&[0xca, 0x50]),
+        Some(&[0xa6, 0xe8]),
+        None,
+        Some(&[0xad, 0x6e]),
+        Some(&[0xd7, 0xc5]),
+        Some(&[0xb5, 0xf7]),
+        None,
+        Some(&[0xdf, 0xfa]),
+        Some(&[0xc2, 0xd0]),
+        None,
+        Some(&[0xf2, 0xf2]),
+        None,
+        None,
+        Some(&[0xa8, 0xa3]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0x56]),
+        None,
+        Some(&[0xdb, 0xd0]),
+        Some(&[0xb5, 0xf8]),
+        Some(&[0xdb, 0xd2]),
+        Some(&[0xdb, 0xd1]),
+        None,
+        None,
+        Some(&[0xdf, 0xfb]),
+        Some(&[0xb8, 0xd0]),
+        Some(&[0xe4, 0x43]),
+        Some(&[0xe4, 0x46]),
+        Some(&[0xe4, 0x45]),
+        None,
+        Some(&[0xe4, 0x44]),
+        Some(&[0xe7, 0xce]),
+        Some(&[0xe7, 0xd0]),
+        Some(&[0xe7, 0xcf]),
+        None,
+        Some(&[0xbf, 0xcc]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xcb]),
+        None,
+        Some(&[0xc1, 0xbb]),
+        Some(&[0xee, 0x79]),
+        Some(&[0xee, 0x7b]),
+        Some(&[0xee, 0x7a]),
+        None,
+        None,
+        Some(&[0xc2, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xf2, 0xf4]),
+        Some(&[0xf2, 0xf3]),
+        None,
+        Some(&[0xf4, 0xcc]),
+        Some(&[0xc4, 0xb1]),
+        None,
+        None,
+        Some(&[0xc4, 0xfd]),
+        Some(&[0xf7, 0x54]),
+        Some(&[0xf7, 0x53]),
+        Some(&[0xc6, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa4]),
+        Some(&[0xd0, 0xaf]),
+        Some(&[0xad, 0x6f]),
+        Some(&[0xd7, 0xc8]),
+        Some(&[0xd7, 0xc6]),
+        None,
+        None,
+        Some(&[0xd7, 0xc7]),
+        Some(&[0xdb, 0xd4]),
+        Some(&[0xdb, 0xd5]),
+        Some(&[0xe0, 0x43]),
+        Some(&[0xdb, 0xd3]),
+        None,
+        Some(&[0xdf, 0xfc]),
+        Some(&[0xe0, 0x41]),
+        Some(&[0xe0, 0x40]),
+        Some(&[0xe0, 0x42]),
+        Some(&[0xb8, 0xd1]),
+        Some(&[0xdf, 0xfe]),
+        Some(&[0xdf, 0xfd]),
+        Some(&[0xe0, 0x44]),
+        None,
+        Some(&[0xe4, 0x49]),
+        Some(&[0xe4, 0x47]),
+        None,
+        Some(&[0xe4, 0x48]),
+        Some(&[0xe7, 0xd3]),
+        Some(&[0xe7, 0xd1]),
+        None,
+        None,
+        Some(&[0xe7, 0xd2]),
+        Some(&[0xeb, 0x7d]),
+        Some(&[0xee, 0x7c]),
+        Some(&[0xee, 0x7d]),
+        Some(&[0xc2, 0xd2]),
+        None,
+        Some(&[0xf2, 0xf5]),
+        Some(&[0xf4, 0xcd]),
+        Some(&[0xc4, 0xb2]),
+        None,
+        Some(&[0xf6, 0x4c]),
+        Some(&[0xf7, 0x55]),
+        Some(&[0xc5, 0xa9]),
+        None,
+        Some(&[0xf7, 0xfe]),
+        Some(&[0xf9, 0x4c]),
+        Some(&[0xa8, 0xa5]),
+        None,
+        Some(&[0xad, 0x71]),
+        Some(&[0xad, 0x72]),
+        Some(&[0xd0, 0xb0]),
+        None,
+        None,
+        Some(&[0xd0, 0xb1]),
+        Some(&[0xad, 0x70]),
+        None,
+        Some(&[0xb0, 0x54]),
+        None,
+        Some(&[0xb0, 0x52]),
+        None,
+        Some(&[0xb0, 0x51]),
+        Some(&[0xb0, 0x58]),
+        Some(&[0xb0, 0x50]),
+        Some(&[0xb0, 0x59]),
+        Some(&[0xd3, 0xdd]),
+        Some(&[0xb0, 0x56]),
+        None,
+        Some(&[0xb0, 0x53]),
+        Some(&[0xb0, 0x57]),
+        Some(&[0xb0, 0x55]),
+        Some(&[0xb0, 0x4f]),
+        None,
+        None,
+        Some(&[0xb3, 0x5f]),
+        None,
+        Some(&[0xb3, 0x59]),
+        Some(&[0xd7, 0xcc]),
+        Some(&[0xb3, 0x5e]),
+        None,
+        None,
+        Some(&[0xb3, 0x60]),
+        Some(&[0xb3, 0x5a]),
+        None,
+        Some(&[0xb3, 0x5b]),
+        None,
+        Some(&[0xd7, 0xca]),
+        None,
+        None,
+        Some(&[0xb3, 0x58]),
+        None,
+        Some(&[0xd7, 0xcb]),
+        Some(&[0xb3, 0x5d]),
+        None,
+        None,
+        Some(&[0xd7, 0xc9]),
+        Some(&[0xb3, 0x5c]),
+        None,
+        None,
+        Some(&[0xb6, 0x44]),
+        None,
+        Some(&[0xb6, 0x46]),
+        None,
+        None,
+        Some(&[0xdb, 0xd8]),
+        Some(&[0xb6, 0x45]),
+        Some(&[0xb5, 0xf9]),
+        Some(&[0xb5, 0xfd]),
+        None,
+        Some(&[0xb8, 0xe4]),
+        Some(&[0xe0, 0x49]),
+        Some(&[0xdb, 0xda]),
+        Some(&[0xb5, 0xfe]),
+        None,
+        None,
+        Some(&[0xdb, 0xdd]),
+        Some(&[0xdb, 0xde]),
+        Some(&[0xb6, 0x43]),
+        None,
+        Some(&[0xdb, 0xe0]),
+        None,
+        Some(&[0xdb, 0xe2]),
+        None,
+        Some(&[0xdb, 0xe3]),
+        Some(&[0xdb, 0xd7]),
+        Some(&[0xdb, 0xd6]),
+        Some(&[0xdb, 0xe4]),
+        Some(&[0xb6, 0x42]),
+        Some(&[0xdb, 0xe1]),
+        Some(&[0xdb, 0xdf]),
+        None,
+        Some(&[0xb6, 0x40]),
+        Some(&[0xb5, 0xfb]),
+        Some(&[0xb6, 0x47]),
+        Some(&[0xdb, 0xdb]),
+        Some(&[0xdb, 0xdc]),
+        Some(&[0xdb, 0xd9]),
+        None,
+        Some(&[0xb6, 0x41]),
+        None,
+        None,
+        Some(&[0xb5, 0xfc]),
+        None,
+        Some(&[0xb5, 0xfa]),
+        Some(&[0xe0, 0x48]),
+        Some(&[0xb8, 0xdf]),
+        Some(&[0xb8, 0xda]),
+        None,
+        None,
+        Some(&[0xb8, 0xd5]),
+        None,
+        Some(&[0xb8, 0xe5]),
+        Some(&[0xb8, 0xd6]),
+        None,
+        Some(&[0xb8, 0xd2]),
+        Some(&[0xb8, 0xe1]),
+        Some(&[0xb8, 0xde]),
+        Some(&[0xb8, 0xe0]),
+        None,
+        Some(&[0xb8, 0xd7]),
+        Some(&[0xb8, 0xdc]),
+        Some(&[0xb8, 0xd3]),
+        Some(&[0xb8, 0xd4]),
+        Some(&[0xe0, 0x50]),
+        Some(&[0xe0, 0x4d]),
+        Some(&[0xe0, 0x45]),
+        Some(&[0xe0, 0x4a]),
+        None,
+        Some(&[0xb8, 0xe2]),
+        Some(&[0xe0, 0x51]),
+        Some(&[0xb8, 0xe3]),
+        Some(&[0xb8, 0xd9]),
+        None,
+        None,
+        Some(&[0xe0, 0x47]),
+        None,
+        Some(&[0xe0, 0x4f]),
+        Some(&[0xe0, 0x4b]),
+        Some(&[0xe0, 0x4e]),
+        Some(&[0xe0, 0x4c]),
+        Some(&[0xb8, 0xdd]),
+        Some(&[0xe0, 0x46]),
+        Some(&[0xb8, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x4c]),
+        Some(&[0xbb, 0x78]),
+        Some(&[0xbb, 0x7b]),
+        None,
+        Some(&[0xe4, 0x4e]),
+        None,
+        Some(&[0xbb, 0xa5]),
+        Some(&[0xe4, 0x4d]),
+        Some(&[0xbb, 0x7d]),
+        None,
+        Some(&[0xbd, 0xcf]),
+        Some(&[0xe4, 0x4f]),
+        None,
+        Some(&[0xbb, 0xa4]),
+        Some(&[0xe4, 0x4b]),
+        Some(&[0xbb, 0xa6]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0x79]),
+        None,
+        Some(&[0xb8, 0xdb]),
+        Some(&[0xbb, 0x7c]),
+        None,
+        Some(&[0xbb, 0x7a]),
+        Some(&[0xbb, 0x7e]),
+        Some(&[0xbb, 0xa2]),
+        Some(&[0xbb, 0x77]),
+        Some(&[0xbb, 0xa7]),
+        Some(&[0xbb, 0xa3]),
+        None,
+        Some(&[0xbb, 0xa1]),
+        Some(&[0xe4, 0x4a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xd6]),
+        None,
+        Some(&[0xbd, 0xd2]),
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xd9]),
+        None,
+        Some(&[0xe7, 0xd6]),
+        Some(&[0xbd, 0xda]),
+        Some(&[0xe7, 0xe2]),
+        Some(&[0xe7, 0xdb]),
+        Some(&[0xbd, 0xcb]),
+        Some(&[0xe7, 0xe3]),
+        Some(&[0xe7, 0xdd]),
+        Some(&[0xbd, 0xd5]),
+        Some(&[0xe7, 0xde]),
+        None,
+        Some(&[0xbd, 0xd4]),
+        Some(&[0xe7, 0xe1]),
+        Some(&[0xbd, 0xce]),
+        Some(&[0xe7, 0xdf]),
+        Some(&[0xe7, 0xd5]),
+        Some(&[0xbd, 0xcd]),
+        Some(&[0xeb, 0xaa]),
+        Some(&[0xbd, 0xd3]),
+        None,
+        Some(&[0xbd, 0xd0]),
+        None,
+        Some(&[0xbd, 0xd8]),
+        None,
+        Some(&[0xe7, 0xd4]),
+        None,
+        Some(&[0xe7, 0xd8]),
+        Some(&[0xbd, 0xcc]),
+        Some(&[0xe7, 0xd7]),
+        Some(&[0xe7, 0xd9]),
+        Some(&[0xe7, 0xda]),
+        Some(&[0xbd, 0xd7]),
+        Some(&[0xe7, 0xdc]),
+        Some(&[0xe7, 0xe0]),
+        Some(&[0xe7, 0xe4]),
+        None,
+        Some(&[0xbd, 0xdb]),
+        Some(&[0xbf, 0xd2]),
+        Some(&[0xeb, 0xa5]),
+        Some(&[0xeb, 0xab]),
+        Some(&[0xeb, 0xa8]),
+        Some(&[0xeb, 0x7e]),
+        Some(&[0xeb, 0xac]),
+        Some(&[0xeb, 0xa1]),
+        None,
+        Some(&[0xeb, 0xa7]),
+        None,
+        Some(&[0xbf, 0xcd]),
+        Some(&[0xbf, 0xd3]),
+        Some(&[0xeb, 0xad]),
+        None,
+        None,
+        Some(&[0xbf, 0xcf]),
+        None,
+        Some(&[0xbf, 0xd9]),
+        Some(&[0xbf, 0xd4]),
+        Some(&[0xeb, 0xaf]),
+        Some(&[0xeb, 0xa9]),
+        Some(&[0xbf, 0xd0]),
+        Some(&[0xeb, 0xa2]),
+        Some(&[0xbf, 0xda]),
+        Some(&[0xeb, 0xa3]),
+        Some(&[0xeb, 0xa4]),
+        Some(&[0xbf, 0xdb]),
+        Some(&[0xbf, 0xd8]),
+        Some(&[0xbd, 0xd1]),
+        None,
+        Some(&[0xbf, 0xce]),
+        Some(&[0xeb, 0xb0]),
+        Some(&[0xbf, 0xdc]),
+        None,
+        Some(&[0xbf, 0xd5]),
+        Some(&[0xeb, 0xae]),
+        Some(&[0xbf, 0xd1]),
+        Some(&[0xbf, 0xd6]),
+        Some(&[0xbf, 0xd7]),
+        None,
+        Some(&[0xc1, 0xc3]),
+        Some(&[0xee, 0xa4]),
+        Some(&[0xee, 0xad]),
+        Some(&[0xee, 0xaa]),
+        Some(&[0xee, 0xac]),
+        None,
+        Some(&[0xc1, 0xc0]),
+        Some(&[0xee, 0xa5]),
+        None,
+        Some(&[0xee, 0xab]),
+        Some(&[0xc1, 0xbc]),
+        Some(&[0xee, 0xa7]),
+        Some(&[0xc1, 0xc4]),
+        Some(&[0xee, 0xa3]),
+        Some(&[0xee, 0xa8]),
+        Some(&[0xee, 0xaf]),
+        Some(&[0xeb, 0xa6]),
+        Some(&[0xee, 0xa9]),
+        Some(&[0xee, 0xa2]),
+        Some(&[0xc1, 0xbd]),
+        Some(&[0xee, 0xa1]),
+        Some(&[0xc1, 0xbe]),
+        Some(&[0xee, 0xb0]),
+        Some(&[0xc1, 0xbf]),
+        Some(&[0xee, 0xae]),
+        Some(&[0xc1, 0xc2]),
+        Some(&[0xee, 0x7e]),
+        None,
+        Some(&[0xc1, 0xc1]),
+        None,
+        Some(&[0xee, 0xa6]),
+        Some(&[0xf0, 0xdc]),
+        Some(&[0xf0, 0xea]),
+        Some(&[0xf0, 0xe5]),
+        Some(&[0xf0, 0xe7]),
+        Some(&[0xf0, 0xdb]),
+        Some(&[0xc2, 0xd3]),
+        None,
+        Some(&[0xf0, 0xda]),
+        Some(&[0xc2, 0xd6]),
+        Some(&[0xc2, 0xd5]),
+        None,
+        Some(&[0xf0, 0xe9]),
+        Some(&[0xf0, 0xe1]),
+        Some(&[0xf0, 0xde]),
+        Some(&[0xf0, 0xe4]),
+        None,
+        Some(&[0xf0, 0xdd]),
+        None,
+        Some(&[0xf0, 0xdf]),
+        Some(&[0xf0, 0xe8]),
+        Some(&[0xf0, 0xe6]),
+        None,
+        Some(&[0xc2, 0xd4]),
+        Some(&[0xf0, 0xed]),
+        Some(&[0xf0, 0xeb]),
+        Some(&[0xf0, 0xe2]),
+        Some(&[0xf0, 0xec]),
+        Some(&[0xf0, 0xe3]),
+        None,
+        Some(&[0xf2, 0xf9]),
+        Some(&[0xc3, 0xcf]),
+        Some(&[0xf3, 0x41]),
+        None,
+        None,
+        Some(&[0xf6, 0x4f]),
+        Some(&[0xc3, 0xd6]),
+        Some(&[0xf0, 0xe0]),
+        Some(&[0xf2, 0xf7]),
+        Some(&[0xc3, 0xd2]),
+        Some(&[0xf2, 0xf8]),
+        Some(&[0xf2, 0xfd]),
+        None,
+        None,
+        Some(&[0xc3, 0xd4]),
+        Some(&[0xc3, 0xd5]),
+        Some(&[0xf2, 0xf6]),
+        Some(&[0xf3, 0x40]),
+        Some(&[0xf3, 0x42]),
+        Some(&[0xf2, 0xfa]),
+        Some(&[0xf2, 0xfc]),
+        Some(&[0xf2, 0xfe]),
+        Some(&[0xf2, 0xfb]),
+        Some(&[0xf3, 0x43]),
+        Some(&[0xc3, 0xd1]),
+        Some(&[0xc3, 0xd7]),
+        Some(&[0xc3, 0xd3]),
+        None,
+        Some(&[0xc3, 0xd0]),
+        Some(&[0xf4, 0xd0]),
+        None,
+        Some(&[0xc4, 0xb7]),
+        Some(&[0xf4, 0xce]),
+        None,
+        None,
+        Some(&[0xf4, 0xd2]),
+        None,
+        Some(&[0xf4, 0xd3]),
+        Some(&[0xc4, 0xb5]),
+        Some(&[0xf4, 0xd4]),
+        Some(&[0xf4, 0xd1]),
+        None,
+        Some(&[0xf4, 0xcf]),
+        Some(&[0xc4, 0xb8]),
+        Some(&[0xc4, 0xb4]),
+        Some(&[0xf4, 0xd5]),
+        None,
+        Some(&[0xc4, 0xb6]),
+        Some(&[0xc4, 0xb3]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xfe]),
+        None,
+        None,
+        Some(&[0xc5, 0x40]),
+        Some(&[0xf6, 0x4e]),
+        Some(&[0xf6, 0x4d]),
+        Some(&[0xf6, 0x50]),
+        Some(&[0xf6, 0x51]),
+        None,
+        Some(&[0xc5, 0x41]),
+        Some(&[0xf7, 0x56]),
+        Some(&[0xf7, 0x5b]),
+        Some(&[0xc5, 0xaa]),
+        None,
+        Some(&[0xf7, 0x58]),
+        None,
+        Some(&[0xf7, 0x57]),
+        Some(&[0xf7, 0x5a]),
+        Some(&[0xf7, 0x59]),
+        None,
+        Some(&[0xf8, 0x43]),
+        None,
+        Some(&[0xc5, 0xdc]),
+        Some(&[0xf8, 0x42]),
+        Some(&[0xf8, 0x40]),
+        None,
+        Some(&[0xf8, 0x41]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xfe]),
+        Some(&[0xc5, 0xfd]),
+        Some(&[0xf8, 0xc1]),
+        Some(&[0xf8, 0xc2]),
+        Some(&[0xc6, 0x40]),
+        None,
+        Some(&[0xf9, 0x4d]),
+        Some(&[0xf9, 0x4e]),
+        Some(&[0xc6, 0x67]),
+        None,
+        Some(&[0xc6, 0x6d]),
+        None,
+        Some(&[0xf9, 0xa9]),
+        Some(&[0xf9, 0xc8]),
+    ];
+
+    const BASE_0X8C30: [Option<&'static [u8]>; 240] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xa6]),
+        None,
+        Some(&[0xd7, 0xcd]),
+        None,
+        Some(&[0xd7, 0xce]),
+        Some(&[0xe0, 0x52]),
+        Some(&[0xe4, 0x50]),
+        Some(&[0xe7, 0xe5]),
+        Some(&[0xc1, 0xc6]),
+        None,
+        Some(&[0xc1, 0xc5]),
+        Some(&[0xf0, 0xee]),
+        Some(&[0xf3, 0x44]),
+        None,
+        Some(&[0xf8, 0x44]),
+        Some(&[0xa8, 0xa7]),
+        Some(&[0xd3, 0xde]),
+        Some(&[0xb0, 0x5a]),
+        Some(&[0xb3, 0x61]),
+        Some(&[0xe0, 0x54]),
+        Some(&[0xe0, 0x53]),
+        Some(&[0xbd, 0xdc]),
+        Some(&[0xe7, 0xe6]),
+        Some(&[0xbd, 0xdd]),
+        Some(&[0xee, 0xb1]),
+        Some(&[0xc2, 0xd7]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0x76]),
+        Some(&[0xa8, 0xa8]),
+        Some(&[0xcd, 0xcb]),
+        Some(&[0xd3, 0xdf]),
+        None,
+        None,
+        Some(&[0xb3, 0x62]),
+        None,
+        Some(&[0xd7, 0xcf]),
+        Some(&[0xd7, 0xd0]),
+        None,
+        Some(&[0xdb, 0xe5]),
+        None,
+        Some(&[0xb6, 0x48]),
+        Some(&[0xb8, 0xe6]),
+        None,
+        Some(&[0xe0, 0x56]),
+        Some(&[0xe0, 0x55]),
+        Some(&[0xe0, 0x57]),
+        None,
+        Some(&[0xe4, 0x51]),
+        Some(&[0xe4, 0x52]),
+        Some(&[0xbb, 0xa8]),
+        Some(&[0xbf, 0xdd]),
+        Some(&[0xbd, 0xde]),
+        Some(&[0xbf, 0xde]),
+        None,
+        Some(&[0xee, 0xb5]),
+        Some(&[0xee, 0xb2]),
+        Some(&[0xee, 0xb4]),
+        Some(&[0xee, 0xb3]),
+        Some(&[0xc1, 0xc7]),
+        None,
+        Some(&[0xf0, 0xef]),
+        Some(&[0xf3, 0x46]),
+        Some(&[0xf3, 0x45]),
+        Some(&[0xcb, 0xa4]),
+        Some(&[0xb0, 0x5c]),
+        Some(&[0xb0, 0x5b]),
+        Some(&[0xd3, 0xe0]),
+        None,
+        Some(&[0xd7, 0xd1]),
+        None,
+        None,
+        Some(&[0xdb, 0xe7]),
+        Some(&[0xdb, 0xe6]),
+        Some(&[0xb6, 0x49]),
+        None,
+        Some(&[0xe0, 0x59]),
+        Some(&[0xe0, 0x5a]),
+        Some(&[0xe0, 0x58]),
+        None,
+        None,
+        Some(&[0xb8, 0xe8]),
+        Some(&[0xb8, 0xe7]),
+        None,
+        Some(&[0xbb, 0xaa]),
+        Some(&[0xbb, 0xa9]),
+        None,
+        Some(&[0xe7, 0xe7]),
+        Some(&[0xeb, 0xb3]),
+        Some(&[0xeb, 0xb1]),
+        Some(&[0xeb, 0xb2]),
+        Some(&[0xbf, 0xdf]),
+        Some(&[0xee, 0xb7]),
+        Some(&[0xee, 0xb6]),
+        None,
+        Some(&[0xf0, 0xf2]),
+        Some(&[0xf0, 0xf1]),
+        Some(&[0xf0, 0xf0]),
+        Some(&[0xf3, 0x47]),
+        None,
+        Some(&[0xf9, 0xaa]),
+        Some(&[0xa8, 0xa9]),
+        Some(&[0xad, 0x73]),
+        None,
+        Some(&[0xad, 0x74]),
+        Some(&[0xb0, 0x5d]),
+        Some(&[0xb0, 0x5e]),
+        Some(&[0xd3, 0xe2]),
+        Some(&[0xd3, 0xe1]),
+        Some(&[0xd7, 0xd2]),
+        None,
+        Some(&[0xb3, 0x68]),
+        Some(&[0xb3, 0x66]),
+        Some(&[0xb3, 0x63]),
+        Some(&[0xb3, 0x67]),
+        Some(&[0xb3, 0x65]),
+        Some(&[0xb3, 0x64]),
+        None,
+        None,
+        Some(&[0xb6, 0x4a]),
+        Some(&[0xdb, 0xea]),
+        None,
+        Some(&[0xb8, 0xed]),
+        Some(&[0xb6, 0x4c]),
+        Some(&[0xb6, 0x51]),
+        Some(&[0xdb, 0xec]),
+        Some(&[0xb6, 0x53]),
+        Some(&[0xb6, 0x52]),
+        Some(&[0xb6, 0x55]),
+        Some(&[0xdb, 0xeb]),
+        Some(&[0xdb, 0xe8]),
+        Some(&[0xb6, 0x4f]),
+        Some(&[0xb6, 0x4b]),
+        Some(&[0xb6, 0x4d]),
+        Some(&[0xdb, 0xe9]),
+        Some(&[0xb6, 0x54]),
+        Some(&[0xb6, 0x50]),
+        Some(&[0xb6, 0x4e]),
+        Some(&[0xb8, 0xef]),
+        Some(&[0xb8, 0xee]),
+        Some(&[0xb8, 0xec]),
+        Some(&[0xb8, 0xf0]),
+        None,
+        Some(&[0xb8, 0xea]),
+        Some(&[0xb8, 0xeb]),
+        None,
+        Some(&[0xb8, 0xe9]),
+        None,
+        Some(&[0xe0, 0x5b]),
+        None,
+        None,
+        Some(&[0xe4, 0x54]),
+        None,
+        Some(&[0xbb, 0xac]),
+        Some(&[0xbb, 0xad]),
+        Some(&[0xbb, 0xab]),
+        None,
+        Some(&[0xe4, 0x53]),
+        None,
+        Some(&[0xe4, 0x55]),
+        None,
+        Some(&[0xe7, 0xea]),
+        Some(&[0xe7, 0xec]),
+        None,
+        Some(&[0xbd, 0xe7]),
+        Some(&[0xe7, 0xed]),
+        Some(&[0xbd, 0xe0]),
+        Some(&[0xe7, 0xe9]),
+        Some(&[0xbd, 0xdf]),
+        Some(&[0xbd, 0xe9]),
+        Some(&[0xbd, 0xe5]),
+        Some(&[0xbd, 0xe6]),
+        Some(&[0xbd, 0xe2]),
+        Some(&[0xe7, 0xe8]),
+        Some(&[0xbd, 0xe1]),
+        Some(&[0xe7, 0xee]),
+        Some(&[0xe7, 0xeb]),
+        None,
+        Some(&[0xbd, 0xe8]),
+        None,
+        Some(&[0xbd, 0xe3]),
+        Some(&[0xbd, 0xe4]),
+        Some(&[0xeb, 0xb5]),
+        None,
+        Some(&[0xeb, 0xb7]),
+        Some(&[0xeb, 0xb6]),
+        None,
+        Some(&[0xeb, 0xb8]),
+        Some(&[0xbf, 0xe0]),
+        Some(&[0xeb, 0xb4]),
+        None,
+        None,
+        Some(&[0xc1, 0xcb]),
+        Some(&[0xee, 0xb8]),
+        Some(&[0xc1, 0xc8]),
+        Some(&[0xc1, 0xcc]),
+        Some(&[0xc1, 0xca]),
+        Some(&[0xc1, 0xc9]),
+        Some(&[0xf0, 0xf3]),
+        None,
+        Some(&[0xf0, 0xf6]),
+        None,
+        Some(&[0xf0, 0xf5]),
+        None,
+        Some(&[0xf0, 0xf4]),
+        Some(&[0xc2, 0xd8]),
+        Some(&[0xf3, 0x48]),
+        Some(&[0xf3, 0x49]),
+        Some(&[0xc3, 0xd8]),
+        Some(&[0xf3, 0x4a]),
+        Some(&[0xc3, 0xd9]),
+        None,
+        None,
+        Some(&[0xc4, 0xba]),
+        None,
+        Some(&[0xc4, 0xb9]),
+        Some(&[0xf6, 0x52]),
+        None,
+        None,
+        Some(&[0xc5, 0x42]),
+        Some(&[0xf6, 0x53]),
+        Some(&[0xf7, 0x5c]),
+        Some(&[0xc5, 0xab]),
+        Some(&[0xc5, 0xac]),
+        None,
+        Some(&[0xf8, 0x45]),
+        None,
+        Some(&[0xc6, 0x42]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X8D60: [Option<&'static [u8]>; 1832] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xaa]),
+        None,
+        Some(&[0xb3, 0x6a]),
+        Some(&[0xb3, 0x69]),
+        Some(&[0xe0, 0x5c]),
+        Some(&[0xe0, 0x5d]),
+        None,
+        Some(&[0xbb, 0xae]),
+        Some(&[0xeb, 0xb9]),
+        Some(&[0xbd, 0xea]),
+        Some(&[0xeb, 0xba]),
+        Some(&[0xee, 0xb9]),
+        Some(&[0xa8, 0xab]),
+        None,
+        Some(&[0xd0, 0xb2]),
+        Some(&[0xad, 0x76]),
+        Some(&[0xad, 0x75]),
+        None,
+        Some(&[0xd3, 0xe3]),
+        Some(&[0xb0, 0x5f]),
+        Some(&[0xd3, 0xe4]),
+        Some(&[0xd7, 0xd5]),
+        None,
+        Some(&[0xd7, 0xd4]),
+        None,
+        Some(&[0xd7, 0xd3]),
+        None,
+        None,
+        Some(&[0xdb, 0xee]),
+        Some(&[0xb6, 0x58]),
+        None,
+        None,
+        Some(&[0xdb, 0xed]),
+        Some(&[0xb6, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xdb, 0xef]),
+        Some(&[0xb6, 0x56]),
+        None,
+        Some(&[0xe0, 0x5f]),
+        Some(&[0xe0, 0x62]),
+        Some(&[0xe0, 0x60]),
+        Some(&[0xe0, 0x61]),
+        Some(&[0xe0, 0x65]),
+        Some(&[0xe0, 0x5e]),
+        Some(&[0xe0, 0x66]),
+        Some(&[0xe0, 0x63]),
+        Some(&[0xe0, 0x64]),
+        Some(&[0xbb, 0xb0]),
+        Some(&[0xe4, 0x56]),
+        None,
+        None,
+        Some(&[0xbb, 0xaf]),
+        None,
+        Some(&[0xe7, 0xf2]),
+        Some(&[0xe7, 0xf0]),
+        None,
+        None,
+        Some(&[0xbd, 0xeb]),
+        Some(&[0xe7, 0xef]),
+        Some(&[0xe7, 0xf1]),
+        None,
+        Some(&[0xbd, 0xec]),
+        None,
+        Some(&[0xeb, 0xbb]),
+        None,
+        Some(&[0xeb, 0xbc]),
+        Some(&[0xc1, 0xcd]),
+        None,
+        Some(&[0xf3, 0x4c]),
+        Some(&[0xf3, 0x4e]),
+        Some(&[0xf3, 0x4b]),
+        Some(&[0xf3, 0x4d]),
+        Some(&[0xf4, 0xd6]),
+        Some(&[0xf6, 0x54]),
+        None,
+        None,
+        Some(&[0xf9, 0x6f]),
+        Some(&[0xa8, 0xac]),
+        Some(&[0xad, 0x77]),
+        Some(&[0xd3, 0xe5]),
+        Some(&[0xd3, 0xe7]),
+        Some(&[0xd3, 0xe6]),
+        None,
+        Some(&[0xd7, 0xd8]),
+        Some(&[0xb3, 0x6c]),
+        None,
+        Some(&[0xd7, 0xd6]),
+        None,
+        Some(&[0xb3, 0x6b]),
+        Some(&[0xd7, 0xd9]),
+        None,
+        Some(&[0xd7, 0xda]),
+        Some(&[0xd7, 0xd7]),
+        None,
+        None,
+        Some(&[0xdb, 0xfb]),
+        Some(&[0xb6, 0x60]),
+        Some(&[0xdb, 0xf3]),
+        Some(&[0xdb, 0xf9]),
+        None,
+        None,
+        Some(&[0xb6, 0x5b]),
+        Some(&[0xb6, 0x5e]),
+        Some(&[0xdb, 0xf2]),
+        Some(&[0xb6, 0x59]),
+        Some(&[0xdb, 0xf6]),
+        Some(&[0xe0, 0x6c]),
+        Some(&[0xb6, 0x5d]),
+        None,
+        Some(&[0xdb, 0xf1]),
+        None,
+        Some(&[0xdb, 0xf7]),
+        Some(&[0xdb, 0xf4]),
+        Some(&[0xdb, 0xfa]),
+        Some(&[0xdb, 0xf0]),
+        Some(&[0xdb, 0xf8]),
+        Some(&[0xb6, 0x5c]),
+        Some(&[0xb6, 0x5f]),
+        Some(&[0xdb, 0xf5]),
+        Some(&[0xb6, 0x5a]),
+        None,
+        Some(&[0xb8, 0xf2]),
+        Some(&[0xe0, 0x68]),
+        Some(&[0xb8, 0xf1]),
+        Some(&[0xe0, 0x6f]),
+        Some(&[0xe0, 0x6e]),
+        Some(&[0xb8, 0xf8]),
+        None,
+        Some(&[0xb8, 0xf9]),
+        Some(&[0xe0, 0x70]),
+        Some(&[0xb8, 0xf3]),
+        Some(&[0xe0, 0x6d]),
+        Some(&[0xb8, 0xf7]),
+        Some(&[0xe0, 0x72]),
+        Some(&[0xe0, 0x69]),
+        None,
+        Some(&[0xe0, 0x6b]),
+        Some(&[0xb8, 0xf4]),
+        Some(&[0xe0, 0x67]),
+        Some(&[0xe0, 0x6a]),
+        Some(&[0xe0, 0x71]),
+        Some(&[0xb8, 0xf5]),
+        Some(&[0xe0, 0x73]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xf6]),
+        None,
+        Some(&[0xbb, 0xb1]),
+        Some(&[0xe4, 0x5b]),
+        Some(&[0xe4, 0x61]),
+        Some(&[0xe4, 0x59]),
+        Some(&[0xe4, 0x62]),
+        None,
+        Some(&[0xe4, 0x58]),
+        Some(&[0xe4, 0x5d]),
+        Some(&[0xe4, 0x63]),
+        Some(&[0xe4, 0x60]),
+        Some(&[0xe4, 0x5f]),
+        Some(&[0xe4, 0x5e]),
+        None,
+        Some(&[0xe4, 0x57]),
+        Some(&[0xe4, 0x5c]),
+        None,
+        None,
+        Some(&[0xe4, 0x5a]),
+        None,
+        Some(&[0xbd, 0xf1]),
+        Some(&[0xbd, 0xee]),
+        Some(&[0xe7, 0xfb]),
+        Some(&[0xe8, 0x41]),
+        Some(&[0xe8, 0x43]),
+        Some(&[0xe8, 0x40]),
+        Some(&[0xe7, 0xf8]),
+        Some(&[0xe7, 0xfa]),
+        Some(&[0xe8, 0x45]),
+        Some(&[0xe8, 0x42]),
+        Some(&[0xe7, 0xfc]),
+        Some(&[0xe8, 0x46]),
+        Some(&[0xe7, 0xf9]),
+        Some(&[0xe8, 0x44]),
+        Some(&[0xbd, 0xef]),
+        Some(&[0xbd, 0xf5]),
+        Some(&[0xbd, 0xf3]),
+        Some(&[0xe7, 0xf3]),
+        Some(&[0xbd, 0xf4]),
+        Some(&[0xbd, 0xf0]),
+        Some(&[0xe7, 0xf4]),
+        Some(&[0xe7, 0xf6]),
+        Some(&[0xe7, 0xf5]),
+        Some(&[0xe7, 0xfd]),
+        Some(&[0xe7, 0xfe]),
+        None,
+        Some(&[0xbd, 0xf2]),
+        None,
+        Some(&[0xbd, 0xed]),
+        None,
+        None,
+        Some(&[0xe7, 0xf7]),
+        None,
+        Some(&[0xeb, 0xc6]),
+        Some(&[0xbf, 0xe2]),
+        None,
+        Some(&[0xeb, 0xbd]),
+        Some(&[0xbf, 0xe3]),
+        Some(&[0xbf, 0xe6]),
+        Some(&[0xeb, 0xc2]),
+        None,
+        Some(&[0xeb, 0xbf]),
+        Some(&[0xbf, 0xe5]),
+        None,
+        None,
+        Some(&[0xeb, 0xc3]),
+        Some(&[0xeb, 0xc4]),
+        Some(&[0xeb, 0xbe]),
+        Some(&[0xeb, 0xc7]),
+        Some(&[0xeb, 0xc0]),
+        Some(&[0xeb, 0xc5]),
+        Some(&[0xbf, 0xe4]),
+        None,
+        Some(&[0xbf, 0xe1]),
+        Some(&[0xeb, 0xc1]),
+        None,
+        Some(&[0xee, 0xbf]),
+        Some(&[0xc1, 0xd0]),
+        Some(&[0xc1, 0xce]),
+        Some(&[0xc1, 0xd1]),
+        Some(&[0xc1, 0xcf]),
+        Some(&[0xee, 0xbe]),
+        Some(&[0xee, 0xbb]),
+        Some(&[0xee, 0xba]),
+        None,
+        Some(&[0xee, 0xbd]),
+        None,
+        None,
+        Some(&[0xee, 0xbc]),
+        Some(&[0xf1, 0x45]),
+        Some(&[0xc2, 0xde]),
+        Some(&[0xf0, 0xfb]),
+        Some(&[0xf0, 0xfa]),
+        None,
+        Some(&[0xc2, 0xd9]),
+        Some(&[0xf1, 0x41]),
+        Some(&[0xf1, 0x40]),
+        Some(&[0xf0, 0xf7]),
+        Some(&[0xf1, 0x43]),
+        Some(&[0xf0, 0xfc]),
+        Some(&[0xc2, 0xdd]),
+        Some(&[0xf0, 0xf9]),
+        Some(&[0xf1, 0x42]),
+        Some(&[0xf0, 0xf8]),
+        Some(&[0xc2, 0xda]),
+        Some(&[0xc2, 0xdc]),
+        Some(&[0xf0, 0xfd]),
+        Some(&[0xc2, 0xdb]),
+        Some(&[0xf0, 0xfe]),
+        None,
+        Some(&[0xf1, 0x44]),
+        Some(&[0xf3, 0x52]),
+        None,
+        Some(&[0xc3, 0xde]),
+        Some(&[0xf3, 0x4f]),
+        None,
+        Some(&[0xf3, 0x53]),
+        None,
+        None,
+        Some(&[0xc3, 0xdb]),
+        Some(&[0xf3, 0x51]),
+        Some(&[0xc3, 0xe0]),
+        None,
+        Some(&[0xc3, 0xdd]),
+        None,
+        Some(&[0xf3, 0x50]),
+        None,
+        Some(&[0xc3, 0xdf]),
+        Some(&[0xf3, 0x54]),
+        Some(&[0xc3, 0xda]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xbc]),
+        Some(&[0xc4, 0xbe]),
+        None,
+        Some(&[0xf4, 0xd9]),
+        Some(&[0xc4, 0xbd]),
+        Some(&[0xf4, 0xd7]),
+        Some(&[0xc3, 0xdc]),
+        Some(&[0xf4, 0xd8]),
+        Some(&[0xc4, 0xbb]),
+        Some(&[0xc5, 0x43]),
+        Some(&[0xc5, 0x45]),
+        Some(&[0xf6, 0x56]),
+        Some(&[0xc5, 0x44]),
+        Some(&[0xf6, 0x55]),
+        None,
+        Some(&[0xf7, 0x61]),
+        Some(&[0xc5, 0xad]),
+        Some(&[0xf7, 0x60]),
+        Some(&[0xc5, 0xae]),
+        Some(&[0xf7, 0x5e]),
+        Some(&[0xf7, 0x5d]),
+        Some(&[0xf7, 0x62]),
+        Some(&[0xf7, 0x63]),
+        Some(&[0xf8, 0x46]),
+        None,
+        Some(&[0xf7, 0x5f]),
+        None,
+        None,
+        Some(&[0xf8, 0xc6]),
+        Some(&[0xf8, 0xc3]),
+        Some(&[0xf8, 0xc4]),
+        Some(&[0xf8, 0xc5]),
+        Some(&[0xc6, 0x5c]),
+        None,
+        Some(&[0xf9, 0x51]),
+        Some(&[0xf9, 0x50]),
+        Some(&[0xf9, 0x4f]),
+        Some(&[0xf9, 0x70]),
+        None,
+        Some(&[0xf9, 0xbe]),
+        Some(&[0xf9, 0xab]),
+        Some(&[0xc6, 0x6e]),
+        Some(&[0xa8, 0xad]),
+        Some(&[0xb0, 0x60]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb8, 0xfa]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbd, 0xf6]),
+        None,
+        None,
+        Some(&[0xeb, 0xc8]),
+        None,
+        None,
+        Some(&[0xc2, 0xdf]),
+        None,
+        Some(&[0xf3, 0x55]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf9, 0xac]),
+        Some(&[0xa8, 0xae]),
+        Some(&[0xaa, 0xee]),
+        Some(&[0xad, 0x79]),
+        Some(&[0xad, 0x78]),
+        None,
+        Some(&[0xb0, 0x63]),
+        None,
+        Some(&[0xd3, 0xe8]),
+        Some(&[0xb0, 0x61]),
+        Some(&[0xd3, 0xe9]),
+        Some(&[0xb0, 0x62]),
+        None,
+        None,
+        Some(&[0xd7, 0xdf]),
+        Some(&[0xd7, 0xdb]),
+        None,
+        None,
+        Some(&[0xb3, 0x6d]),
+        Some(&[0xd7, 0xde]),
+        Some(&[0xd7, 0xdd]),
+        Some(&[0xd7, 0xdc]),
+        Some(&[0xb3, 0x6e]),
+        Some(&[0xd7, 0xe0]),
+        Some(&[0xd7, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0x43]),
+        Some(&[0xdc, 0x41]),
+        Some(&[0xdc, 0x45]),
+        Some(&[0xdc, 0x46]),
+        Some(&[0xdc, 0x4c]),
+        None,
+        Some(&[0xdc, 0x48]),
+        Some(&[0xdc, 0x4a]),
+        None,
+        Some(&[0xdc, 0x42]),
+        Some(&[0xdb, 0xfc]),
+        None,
+        Some(&[0xdc, 0x49]),
+        None,
+        None,
+        Some(&[0xdc, 0x4b]),
+        Some(&[0xdc, 0x44]),
+        Some(&[0xdc, 0x47]),
+        Some(&[0xdb, 0xfd]),
+        Some(&[0xb6, 0x62]),
+        Some(&[0xdc, 0x40]),
+        Some(&[0xdb, 0xfe]),
+        Some(&[0xb6, 0x61]),
+        Some(&[0xb6, 0x63]),
+        None,
+        Some(&[0xb8, 0xfd]),
+        Some(&[0xe0, 0x75]),
+        Some(&[0xe0, 0x77]),
+        Some(&[0xe0, 0x76]),
+        Some(&[0xe0, 0x7b]),
+        Some(&[0xb8, 0xfb]),
+        None,
+        Some(&[0xe0, 0x78]),
+        Some(&[0xe0, 0x74]),
+        Some(&[0xe0, 0x79]),
+        Some(&[0xe0, 0x7a]),
+        Some(&[0xb8, 0xfc]),
+        Some(&[0xb8, 0xfe]),
+        Some(&[0xe0, 0x7c]),
+        None,
+        Some(&[0xe4, 0x67]),
+        Some(&[0xe4, 0x66]),
+        None,
+        Some(&[0xe4, 0x64]),
+        Some(&[0xe4, 0x65]),
+        Some(&[0xbb, 0xb3]),
+        Some(&[0xbb, 0xb5]),
+        Some(&[0xbb, 0xb2]),
+        Some(&[0xbb, 0xb4]),
+        Some(&[0xe8, 0x4d]),
+        Some(&[0xe8, 0x4e]),
+        Some(&[0xe8, 0x49]),
+        None,
+        Some(&[0xe8, 0x4a]),
+        Some(&[0xbd, 0xf8]),
+        Some(&[0xbd, 0xfd]),
+        Some(&[0xbd, 0xf7]),
+        Some(&[0xbd, 0xfe]),
+        Some(&[0xbd, 0xf9]),
+        Some(&[0xe8, 0x4b]),
+        None,
+        None,
+        Some(&[0xe8, 0x4c]),
+        Some(&[0xe8, 0x48]),
+        Some(&[0xbe, 0x40]),
+        Some(&[0xbd, 0xfb]),
+        None,
+        None,
+        Some(&[0xbd, 0xfa]),
+        Some(&[0xbd, 0xfc]),
+        None,
+        Some(&[0xe8, 0x47]),
+        None,
+        Some(&[0xeb, 0xca]),
+        Some(&[0xbf, 0xe8]),
+        None,
+        None,
+        Some(&[0xeb, 0xcc]),
+        Some(&[0xbf, 0xea]),
+        Some(&[0xeb, 0xcf]),
+        Some(&[0xeb, 0xcb]),
+        Some(&[0xeb, 0xc9]),
+        Some(&[0xeb, 0xce]),
+        Some(&[0xbf, 0xe9]),
+        Some(&[0xeb, 0xcd]),
+        None,
+        Some(&[0xbf, 0xe7]),
+        None,
+        None,
+        Some(&[0xc1, 0xd3]),
+        Some(&[0xc1, 0xd6]),
+        Some(&[0xee, 0xc1]),
+        None,
+        Some(&[0xc1, 0xd4]),
+        Some(&[0xee, 0xc0]),
+        Some(&[0xc1, 0xd2]),
+        Some(&[0xc1, 0xd5]),
+        Some(&[0xf1, 0x46]),
+        Some(&[0xf1, 0x47]),
+        Some(&[0xf1, 0x48]),
+        Some(&[0xc2, 0xe0]),
+        None,
+        Some(&[0xf1, 0x49]),
+        None,
+        Some(&[0xc2, 0xe1]),
+        Some(&[0xc3, 0xe2]),
+        Some(&[0xf3, 0x58]),
+        Some(&[0xf3, 0x59]),
+        Some(&[0xf3, 0x57]),
+        Some(&[0xf3, 0x56]),
+        Some(&[0xf3, 0x5a]),
+        Some(&[0xc3, 0xe1]),
+        Some(&[0xf4, 0xdd]),
+        Some(&[0xf4, 0xdb]),
+        Some(&[0xf4, 0xdc]),
+        Some(&[0xf4, 0xde]),
+        Some(&[0xf4, 0xda]),
+        Some(&[0xf4, 0xdf]),
+        Some(&[0xf6, 0x58]),
+        None,
+        Some(&[0xf6, 0x59]),
+        Some(&[0xf6, 0x57]),
+        Some(&[0xc5, 0x46]),
+        Some(&[0xf7, 0x64]),
+        Some(&[0xc5, 0xaf]),
+        Some(&[0xf7, 0x65]),
+        Some(&[0xf8, 0x48]),
+        Some(&[0xf8, 0x47]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa8, 0xaf]),
+        Some(&[0xb6, 0x64]),
+        None,
+        None,
+        Some(&[0xb9, 0x40]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xb6]),
+        None,
+        None,
+        Some(&[0xbf, 0xec]),
+        None,
+        Some(&[0xbf, 0xeb]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0xe3]),
+        Some(&[0xc4, 0x7c]),
+        Some(&[0xc5, 0x47]),
+        Some(&[0xa8, 0xb0]),
+        Some(&[0xb0, 0x64]),
+        Some(&[0xb9, 0x41]),
+        None,
+        Some(&[0xf3, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xa6]),
+        None,
+        None,
+        Some(&[0xa8, 0xb1]),
+        None,
+        Some(&[0xa8, 0xb4]),
+        Some(&[0xa8, 0xb3]),
+        Some(&[0xa8, 0xb2]),
+        None,
+        None,
+        Some(&[0xcb, 0xa5]),
+        None,
+        Some(&[0xcd, 0xcd]),
+        None,
+        Some(&[0xcd, 0xcf]),
+        Some(&[0xaa, 0xef]),
+        None,
+        None,
+        Some(&[0xaa, 0xf1]),
+        Some(&[0xcd, 0xcc]),
+        Some(&[0xcd, 0xce]),
+        Some(&[0xaa, 0xf0]),
+        Some(&[0xcd, 0xd1]),
+        Some(&[0xcd, 0xd0]),
+        Some(&[0xcd, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb6]),
+        Some(&[0xd0, 0xb4]),
+        Some(&[0xad, 0x7c]),
+        Some(&[0xd0, 0xb3]),
+        Some(&[0xad, 0xa3]),
+        Some(&[0xad, 0x7e]),
+        Some(&[0xad, 0x7b]),
+        None,
+        Some(&[0xad, 0xa4]),
+        None,
+        Some(&[0xad, 0x7d]),
+        Some(&[0xad, 0xa2]),
+        None,
+        Some(&[0xad, 0xa1]),
+        Some(&[0xd0, 0xb5]),
+        None,
+        Some(&[0xad, 0x7a]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0x6a]),
+        Some(&[0xd3, 0xeb]),
+        Some(&[0xd3, 0xf1]),
+        Some(&[0xb0, 0x67]),
+        Some(&[0xb0, 0x6e]),
+        None,
+        Some(&[0xb0, 0x69]),
+        Some(&[0xd3, 0xee]),
+        Some(&[0xd3, 0xf0]),
+        Some(&[0xb0, 0x6c]),
+        Some(&[0xd3, 0xea]),
+        Some(&[0xd3, 0xed]),
+        Some(&[0xb0, 0x68]),
+        Some(&[0xb0, 0x65]),
+        Some(&[0xd3, 0xec]),
+        Some(&[0xb0, 0x6b]),
+        Some(&[0xd3, 0xef]),
+        Some(&[0xb0, 0x6d]),
+        Some(&[0xb0, 0x66]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xe3]),
+        Some(&[0xd7, 0xe6]),
+        Some(&[0xb3, 0x70]),
+        None,
+        Some(&[0xb3, 0x7a]),
+        Some(&[0xb3, 0x76]),
+        Some(&[0xd7, 0xe4]),
+        None,
+        None,
+        Some(&[0xb3, 0x7e]),
+        Some(&[0xb3, 0x77]),
+        Some(&[0xb3, 0x7c]),
+        Some(&[0xb3, 0x72]),
+        None,
+        Some(&[0xb3, 0x6f]),
+        Some(&[0xb3, 0x71]),
+        Some(&[0xb3, 0x7d]),
+        Some(&[0xd7, 0xe5]),
+        Some(&[0xb3, 0x75]),
+        Some(&[0xb3, 0x78]),
+        Some(&[0xb3, 0x74]),
+        Some(&[0xb3, 0x79]),
+        Some(&[0xd7, 0xe7]),
+        Some(&[0xb3, 0x7b]),
+        Some(&[0xb3, 0x73]),
+        Some(&[0xd7, 0xe2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0x4d]),
+        Some(&[0xb6, 0x65]),
+        Some(&[0xdc, 0x4f]),
+        None,
+        Some(&[0xb6, 0x67]),
+        Some(&[0xb6, 0x69]),
+        None,
+        Some(&[0xdc, 0x4e]),
+        Some(&[0xb6, 0x66]),
+        Some(&[0xb6, 0x6a]),
+        None,
+        Some(&[0xb6, 0x68]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0x47]),
+        Some(&[0xe0, 0xa3]),
+        Some(&[0xb9, 0x4f]),
+        Some(&[0xe0, 0x7e]),
+        None,
+        Some(&[0xb9, 0x50]),
+        Some(&[0xb9, 0x45]),
+        None,
+        Some(&[0xe0, 0xa1]),
+        None,
+        None,
+        Some(&[0xb9, 0x4a]),
+        None,
+        Some(&[0xe0, 0xa2]),
+        Some(&[0xb9, 0x43]),
+        Some(&[0xb9, 0x42]),
+        None,
+        Some(&[0xb9, 0x4d]),
+        Some(&[0xb9, 0x4c]),
+        Some(&[0xb9, 0x4b]),
+        Some(&[0xb9, 0x49]),
+        Some(&[0xb9, 0x4e]),
+        Some(&[0xe0, 0x7d]),
+        Some(&[0xb9, 0x44]),
+        Some(&[0xb9, 0x46]),
+        Some(&[0xb9, 0x48]),
+        None,
+        None,
+        Some(&[0xbb, 0xb8]),
+        Some(&[0xbb, 0xbb]),
+        None,
+        Some(&[0xbb, 0xbf]),
+        Some(&[0xbb, 0xb9]),
+        Some(&[0xbb, 0xbe]),
+        Some(&[0xbb, 0xbc]),
+        None,
+        Some(&[0xbb, 0xb7]),
+        None,
+        Some(&[0xbb, 0xbd]),
+        Some(&[0xbb, 0xba]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x52]),
+        Some(&[0xbe, 0x43]),
+        Some(&[0xbe, 0x41]),
+        None,
+        Some(&[0xe8, 0x53]),
+        None,
+        Some(&[0xbe, 0x44]),
+        Some(&[0xbe, 0x42]),
+        Some(&[0xe8, 0x51]),
+        Some(&[0xe8, 0x50]),
+        None,
+        Some(&[0xbf, 0xf0]),
+        Some(&[0xe8, 0x4f]),
+        Some(&[0xbf, 0xee]),
+        Some(&[0xbf, 0xed]),
+        Some(&[0xeb, 0xd0]),
+        Some(&[0xbe, 0x45]),
+        Some(&[0xbf, 0xef]),
+        Some(&[0xeb, 0xd1]),
+        Some(&[0xbf, 0xf2]),
+        Some(&[0xeb, 0xd2]),
+        Some(&[0xbf, 0xf1]),
+        Some(&[0xc1, 0xd8]),
+        Some(&[0xee, 0xc3]),
+        Some(&[0xc1, 0xd7]),
+        Some(&[0xc1, 0xdc]),
+        Some(&[0xc1, 0xda]),
+        Some(&[0xc1, 0xdb]),
+        Some(&[0xc2, 0xe3]),
+        Some(&[0xc1, 0xd9]),
+        Some(&[0xee, 0xc2]),
+        Some(&[0xeb, 0xd3]),
+        Some(&[0xc2, 0xe2]),
+        Some(&[0xc2, 0xe4]),
+        None,
+        Some(&[0xc3, 0xe4]),
+        Some(&[0xc3, 0xe5]),
+        None,
+        Some(&[0xf4, 0xe0]),
+        None,
+        Some(&[0xc5, 0xde]),
+        Some(&[0xc5, 0xdd]),
+        Some(&[0xa8, 0xb6]),
+        None,
+        None,
+        Some(&[0xca, 0x55]),
+        Some(&[0xb0, 0x6f]),
+        None,
+        Some(&[0xca, 0x52]),
+        Some(&[0xca, 0x53]),
+        Some(&[0xca, 0x51]),
+        None,
+        Some(&[0xca, 0x54]),
+        None,
+        None,
+        Some(&[0xcb, 0xaa]),
+        Some(&[0xcb, 0xa7]),
+        Some(&[0xcb, 0xac]),
+        Some(&[0xcb, 0xa8]),
+        Some(&[0xa8, 0xb7]),
+        Some(&[0xa8, 0xba]),
+        None,
+        Some(&[0xcb, 0xa9]),
+        Some(&[0xa8, 0xb9]),
+        Some(&[0xcb, 0xab]),
+        None,
+        None,
+        Some(&[0xa8, 0xb8]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xd5]),
+        Some(&[0xcd, 0xd7]),
+        Some(&[0xaa, 0xf4]),
+        Some(&[0xcd, 0xd3]),
+        Some(&[0xcd, 0xd6]),
+        Some(&[0xcd, 0xd4]),
+        Some(&[0xaa, 0xf2]),
+        Some(&[0xaa, 0xf5]),
+        None,
+        Some(&[0xaa, 0xf3]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xb8]),
+        Some(&[0xd0, 0xbc]),
+        Some(&[0xd0, 0xb9]),
+        None,
+        Some(&[0xad, 0xa7]),
+        None,
+        Some(&[0xad, 0xa8]),
+        None,
+        Some(&[0xd0, 0xbb]),
+        None,
+        Some(&[0xd0, 0xbd]),
+        Some(&[0xd0, 0xbf]),
+        None,
+        Some(&[0xad, 0xa5]),
+        Some(&[0xd0, 0xbe]),
+        None,
+        None,
+        Some(&[0xad, 0xa6]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd7, 0xee]),
+        Some(&[0xd0, 0xba]),
+        Some(&[0xd3, 0xf2]),
+        Some(&[0xd3, 0xfb]),
+        Some(&[0xd3, 0xf9]),
+        Some(&[0xd3, 0xf4]),
+        Some(&[0xd3, 0xf5]),
+        Some(&[0xd3, 0xfa]),
+        Some(&[0xd3, 0xfc]),
+        Some(&[0xb0, 0x71]),
+        None,
+        Some(&[0xd3, 0xf7]),
+        Some(&[0xd3, 0xf3]),
+        Some(&[0xb0, 0x70]),
+        Some(&[0xb0, 0x72]),
+        Some(&[0xd3, 0xf6]),
+        Some(&[0xd3, 0xfd]),
+        Some(&[0xd3, 0xf8]),
+        None,
+        None,
+        Some(&[0xb3, 0xa1]),
+        Some(&[0xd7, 0xf1]),
+        Some(&[0xd7, 0xe9]),
+        Some(&[0xd7, 0xef]),
+        Some(&[0xd7, 0xf0]),
+        Some(&[0xb3, 0xa2]),
+        None,
+        Some(&[0xd7, 0xe8]),
+        Some(&[0xd7, 0xea]),
+        Some(&[0xd0, 0xb7]),
+        Some(&[0xd7, 0xec]),
+        Some(&[0xd7, 0xed]),
+        Some(&[0xd7, 0xeb]),
+        Some(&[0xb6, 0x6c]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0x56]),
+        Some(&[0xeb, 0xd4]),
+        Some(&[0xdc, 0x57]),
+        Some(&[0xdc, 0x54]),
+        Some(&[0xb3, 0xa3]),
+        Some(&[0xb6, 0x6e]),
+        Some(&[0xdc, 0x53]),
+        Some(&[0xdc, 0x59]),
+        Some(&[0xdc, 0x58]),
+        Some(&[0xb6, 0x6b]),
+        Some(&[0xdc, 0x5c]),
+        Some(&[0xdc, 0x52]),
+        Some(&[0xdc, 0x5b]),
+        Some(&[0xdc, 0x50]),
+        Some(&[0xdc, 0x5a]),
+        Some(&[0xdc, 0x55]),
+        Some(&[0xb6, 0x6d]),
+        None,
+        Some(&[0xe0, 0xaa]),
+        None,
+        Some(&[0xe0, 0xa5]),
+        Some(&[0xe0, 0xab]),
+        Some(&[0xe0, 0xa6]),
+        Some(&[0xe0, 0xa4]),
+        Some(&[0xe0, 0xa7]),
+        Some(&[0xb9, 0x51]),
+        None,
+        Some(&[0xe0, 0xa9]),
+        None,
+        Some(&[0xe0, 0xa8]),
+        Some(&[0xb9, 0x52]),
+        Some(&[0xbb, 0xc1]),
+        Some(&[0xbb, 0xc0]),
+        Some(&[0xe4, 0x6e]),
+        Some(&[0xe4, 0x71]),
+        Some(&[0xe4, 0x69]),
+        Some(&[0xe4, 0x6d]),
+        Some(&[0xbb, 0xc2]),
+        Some(&[0xe4, 0x6c]),
+        Some(&[0xe4, 0x6a]),
+        Some(&[0xe4, 0x70]),
+        Some(&[0xe4, 0x6b]),
+        Some(&[0xe4, 0x68]),
+        Some(&[0xe4, 0x6f]),
+        None,
+        Some(&[0xe8, 0x59]),
+        Some(&[0xbe, 0x48]),
+        Some(&[0xf1, 0x4a]),
+        Some(&[0xe8, 0x56]),
+        Some(&[0xe8, 0x57]),
+        Some(&[0xe8, 0x55]),
+        Some(&[0xdc, 0x51]),
+        Some(&[0xbe, 0x47]),
+        Some(&[0xe8, 0x5a]),
+        Some(&[0xe8, 0x54]),
+        Some(&[0xbe, 0x46]),
+        Some(&[0xbe, 0x49]),
+        Some(&[0xe8, 0x58]),
+        Some(&[0xeb, 0xd5]),
+        Some(&[0xbf, 0xf3]),
+        Some(&[0xeb, 0xd6]),
+        Some(&[0xeb, 0xd7]),
+        None,
+        Some(&[0xee, 0xc4]),
+        Some(&[0xc1, 0xdd]),
+        Some(&[0xf1, 0x4b]),
+        Some(&[0xf1, 0x4c]),
+        None,
+        None,
+        Some(&[0xf1, 0x4d]),
+        Some(&[0xf3, 0x5d]),
+        Some(&[0xf3, 0x5c]),
+        Some(&[0xf4, 0xe2]),
+        None,
+        Some(&[0xf4, 0xe1]),
+        Some(&[0xf6, 0x5b]),
+        Some(&[0xf6, 0x5c]),
+        Some(&[0xf6, 0x5a]),
+        Some(&[0xf7, 0x66]),
+        Some(&[0xc5, 0xb0]),
+        Some(&[0xa8, 0xbb]),
+        Some(&[0xad, 0xaa]),
+        Some(&[0xad, 0xa9]),
+        Some(&[0xb0, 0x75]),
+        Some(&[0xb0, 0x74]),
+        Some(&[0xd4, 0x40]),
+        Some(&[0xd4, 0x41]),
+        Some(&[0xd3, 0xfe]),
+        None,
+        Some(&[0xb0, 0x73]),
+        Some(&[0xd7, 0xf5]),
+        None,
+        Some(&[0xd7, 0xf6]),
+        Some(&[0xd7, 0xf2]),
+        Some(&[0xb3, 0xa4]),
+        Some(&[0xd7, 0xf3]),
+        None,
+        Some(&[0xd7, 0xf4]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0x5f]),
+        Some(&[0xdc, 0x61]),
+        Some(&[0xdc, 0x5d]),
+        Some(&[0xdc, 0x60]),
+        Some(&[0xb6, 0x6f]),
+        Some(&[0xdc, 0x5e]),
+        Some(&[0xb6, 0x70]),
+        None,
+        None,
+        Some(&[0xdd, 0x73]),
+        Some(&[0xb9, 0x55]),
+        Some(&[0xb9, 0x54]),
+        None,
+        Some(&[0xb9, 0x53]),
+        None,
+        Some(&[0xe0, 0xac]),
+        Some(&[0xe0, 0xad]),
+        None,
+        None,
+        Some(&[0xe4, 0x73]),
+        Some(&[0xe4, 0x75]),
+        Some(&[0xbb, 0xc6]),
+        Some(&[0xbb, 0xc3]),
+        None,
+        Some(&[0xbb, 0xc5]),
+        Some(&[0xbb, 0xc4]),
+        Some(&[0xe4, 0x74]),
+        Some(&[0xe4, 0x72]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x61]),
+        Some(&[0xe8, 0x5e]),
+        Some(&[0xe8, 0x5f]),
+        Some(&[0xbe, 0x4d]),
+        Some(&[0xe8, 0x60]),
+        Some(&[0xe8, 0x5b]),
+        Some(&[0xe8, 0x5c]),
+        Some(&[0xbe, 0x4a]),
+        None,
+        Some(&[0xbe, 0x4b]),
+        Some(&[0xe8, 0x5d]),
+        Some(&[0xbe, 0x4c]),
+        None,
+        Some(&[0xeb, 0xdb]),
+        None,
+        Some(&[0xeb, 0xdc]),
+        Some(&[0xeb, 0xd9]),
+        Some(&[0xeb, 0xda]),
+        Some(&[0xbf, 0xf4]),
+        Some(&[0xeb, 0xd8]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xee, 0xc8]),
+        Some(&[0xee, 0xc5]),
+        Some(&[0xee, 0xc7]),
+        Some(&[0xc1, 0xe0]),
+        Some(&[0xee, 0xcb]),
+        Some(&[0xc1, 0xdf]),
+        Some(&[0xee, 0xc9]),
+        Some(&[0xee, 0xcc]),
+        Some(&[0xee, 0xca]),
+        Some(&[0xee, 0xc6]),
+        Some(&[0xc1, 0xde]),
+        None,
+        Some(&[0xf1, 0x4f]),
+        None,
+        Some(&[0xf1, 0x50]),
+        Some(&[0xf1, 0x4e]),
+        None,
+        Some(&[0xf1, 0x52]),
+        Some(&[0xc2, 0xe5]),
+        Some(&[0xc2, 0xe6]),
+        Some(&[0xf3, 0x5f]),
+        Some(&[0xc3, 0xe7]),
+        Some(&[0xf1, 0x51]),
+        Some(&[0xf3, 0x5e]),
+        Some(&[0xc3, 0xe6]),
+        Some(&[0xf4, 0xe5]),
+        Some(&[0xf4, 0xe6]),
+        Some(&[0xc4, 0xbf]),
+        Some(&[0xf4, 0xe4]),
+        None,
+        Some(&[0xf4, 0xe3]),
+        None,
+        Some(&[0xf6, 0x5d]),
+        Some(&[0xc5, 0x48]),
+        None,
+        Some(&[0xf8, 0x49]),
+        Some(&[0xf8, 0xc8]),
+        Some(&[0xf8, 0xc7]),
+        None,
+        Some(&[0xc6, 0x43]),
+        Some(&[0xc6, 0x5d]),
+        Some(&[0xf8, 0xc9]),
+        Some(&[0xf9, 0x71]),
+        None,
+        Some(&[0xc6, 0x6f]),
+        Some(&[0xa8, 0xbc]),
+        Some(&[0xaa, 0xf6]),
+        None,
+        Some(&[0xb9, 0x56]),
+        None,
+        Some(&[0xc4, 0xc0]),
+        Some(&[0xa8, 0xbd]),
+        Some(&[0xad, 0xab]),
+        Some(&[0xb3, 0xa5]),
+        Some(&[0xb6, 0x71]),
+        Some(&[0xc2, 0xe7]),
+        Some(&[0xaa, 0xf7]),
+        None,
+        Some(&[0xd0, 0xc1]),
+        Some(&[0xd0, 0xc0]),
+        Some(&[0xd4, 0x42]),
+        None,
+        Some(&[0xb0, 0x78]),
+        Some(&[0xb0, 0x76]),
+        Some(&[0xb0, 0x7a]),
+        Some(&[0xd4, 0x44]),
+        None,
+        Some(&[0xb0, 0x79]),
+        Some(&[0xb0, 0x77]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd4, 0x43]),
+        Some(&[0xb3, 0xa8]),
+        Some(&[0xd7, 0xfc]),
+        None,
+        Some(&[0xb3, 0xa7]),
+        Some(&[0xb3, 0xa9]),
+        Some(&[0xd8, 0x42]),
+        Some(&[0xb3, 0xab]),
+        Some(&[0xd7, 0xfe]),
+        Some(&[0xd8, 0x40]),
+        Some(&[0xd7, 0xf7]),
+        Some(&[0xb3, 0xaa]),
+        Some(&[0xd8, 0x43]),
+        None,
+        None,
+        Some(&[0xd7, 0xf9]),
+        None,
+        Some(&[0xd7, 0xfa]),
+        Some(&[0xd7, 0xf8]),
+        Some(&[0xb3, 0xa6]),
+        None,
+        Some(&[0xd8, 0x41]),
+        Some(&[0xd7, 0xfb]),
+        Some(&[0xd7, 0xfd]),
+        None,
+        None,
+        None,
+        Some(&[0xdc, 0x6d]),
+        None,
+        Some(&[0xdc, 0x6c]),
+        Some(&[0xdc, 0x6a]),
+        Some(&[0xdc, 0x62]),
+        Some(&[0xdc, 0x71]),
+        Some(&[0xdc, 0x65]),
+        Some(&[0xdc, 0x6f]),
+        Some(&[0xdc, 0x76]),
+        Some(&[0xdc, 0x6e]),
+        Some(&[0xb6, 0x79]),
+        None,
+        Some(&[0xb6, 0x75]),
+        Some(&[0xdc, 0x63]),
+        None,
+        Some(&[0xdc, 0x69]),
+        Some(&[0xb6, 0x77]),
+        None,
+        Some(&[0xdc, 0x68]),
+        Some(&[0xb6, 0x78]),
+        Some(&[0xb6, 0x7a]),
+        Some(&[0xdc, 0x6b]),
+        None,
+        Some(&[0xb6, 0x72]),
+        Some(&[0xb6, 0x73]),
+        Some(&[0xdc, 0x77]),
+        Some(&[0xdc, 0x75]),
+        None,
+        Some(&[0xdc, 0x74]),
+        Some(&[0xdc, 0x66]),
+        None,
+        Some(&[0xdc, 0x72]),
+        None,
+        Some(&[0xb6, 0x76]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0x74]),
+        Some(&[0xdc, 0x73]),
+        Some(&[0xdc, 0x64]),
+        Some(&[0xdc, 0x67]),
+        Some(&[0xdc, 0x70]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xba]),
+        Some(&[0xe0, 0xb7]),
+        None,
+        Some(&[0xe0, 0xb0]),
+        Some(&[0xe0, 0xc3]),
+        Some(&[0xe0, 0xcc]),
+        Some(&[0xe0, 0xb3]),
+        Some(&[0xb9, 0x61]),
+        None,
+        Some(&[0xe0, 0xc0]),
+        Some(&[0xb9, 0x57]),
+        Some(&[0xb9, 0x59]),
+        Some(&[0xb9, 0x65]),
+        Some(&[0xe0, 0xb1]),
+        None,
+        None,
+        Some(&[0xb9, 0x5a]),
+        Some(&[0xb9, 0x5c]),
+        Some(&[0xb9, 0x66]),
+        Some(&[0xb9, 0x5b]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0x64]),
+        Some(&[0xe0, 0xb9]),
+        None,
+        Some(&[0xe0, 0xae]),
+        Some(&[0xb9, 0x62]),
+        Some(&[0xe0, 0xb8]),
+        Some(&[0xb9, 0x5e]),
+        Some(&[0xe0, 0xca]),
+        Some(&[0xb9, 0x63]),
+        Some(&[0xe0, 0xc8]),
+        Some(&[0xe0, 0xbc]),
+        Some(&[0xe0, 0xc6]),
+        Some(&[0xb9, 0x60]),
+        Some(&[0xe0, 0xaf]),
+        Some(&[0xe0, 0xc9]),
+        Some(&[0xe0, 0xc4]),
+        None,
+        Some(&[0xe0, 0xcb]),
+        Some(&[0xb9, 0x58]),
+        None,
+        None,
+        Some(&[0xb9, 0x67]),
+        Some(&[0xb9, 0x5d]),
+        None,
+        None,
+        Some(&[0xe0, 0xb5]),
+        None,
+        Some(&[0xe0, 0xbd]),
+        Some(&[0xe0, 0xc1]),
+        None,
+        Some(&[0xe0, 0xc5]),
+        Some(&[0xb9, 0x5f]),
+        Some(&[0xe0, 0xb4]),
+        Some(&[0xe0, 0xb2]),
+        Some(&[0xe0, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xe0, 0xbb]),
+        Some(&[0xe0, 0xba]),
+        None,
+        Some(&[0xe0, 0xbf]),
+        Some(&[0xe0, 0xc2]),
+        None,
+        Some(&[0xe0, 0xc7]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0x78]),
+        None,
+        Some(&[0xbb, 0xc7]),
+        Some(&[0xe4, 0xa4]),
+        Some(&[0xe4, 0x7a]),
+        Some(&[0xbb, 0xcc]),
+        Some(&[0xbb, 0xd0]),
+        Some(&[0xe4, 0xad]),
+        Some(&[0xe4, 0xb5]),
+        Some(&[0xe4, 0xa6]),
+        Some(&[0xbb, 0xc8]),
+        None,
+        Some(&[0xe4, 0xaa]),
+        Some(&[0xe0, 0xb6]),
+        None,
+        Some(&[0xbb, 0xc9]),
+        Some(&[0xe4, 0xb1]),
+        Some(&[0xe4, 0xb6]),
+        Some(&[0xe4, 0xae]),
+        None,
+        Some(&[0xe4, 0xb0]),
+        Some(&[0xe4, 0xb9]),
+        Some(&[0xe4, 0xb2]),
+        Some(&[0xe4, 0x7e]),
+        Some(&[0xe4, 0xa9]),
+        None,
+        None,
+        Some(&[0xbb, 0xd1]),
+        None,
+        Some(&[0xbb, 0xcd]),
+        Some(&[0xe4, 0x7c]),
+        Some(&[0xe4, 0xab]),
+        Some(&[0xbb, 0xcb]),
+        Some(&[0xe4, 0xa5]),
+        Some(&[0xbb, 0xca]),
+        Some(&[0xe4, 0xb3]),
+        Some(&[0xe4, 0xa2]),
+        Some(&[0xe4, 0x79]),
+        Some(&[0xbb, 0xce]),
+        Some(&[0xe4, 0xb8]),
+        None,
+        None,
+        Some(&[0xe4, 0x7b]),
+        Some(&[0xe4, 0xaf]),
+        Some(&[0xe4, 0xac]),
+        Some(&[0xe4, 0xa7]),
+        Some(&[0xe4, 0x77]),
+        Some(&[0xe4, 0x76]),
+        Some(&[0xe4, 0xa1]),
+        Some(&[0xe4, 0xb4]),
+        Some(&[0xbb, 0xcf]),
+        Some(&[0xe4, 0xb7]),
+        Some(&[0xe4, 0x7d]),
+        Some(&[0xe4, 0xa3]),
+        Some(&[0xbe, 0x52]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xbe, 0x5a]),
+        Some(&[0xbe, 0x55]),
+        Some(&[0xe8, 0xa4]),
+        Some(&[0xe8, 0xa1]),
+        Some(&[0xe8, 0x67]),
+        Some(&[0xbe, 0x50]),
+        None,
+        Some(&[0xf9, 0xd7]),
+        None,
+        Some(&[0xbe, 0x4f]),
+        Some(&[0xbe, 0x56]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x65]),
+        Some(&[0xbe, 0x54]),
+        Some(&[0xe8, 0x71]),
+        Some(&[0xe8, 0x63]),
+        Some(&[0xe8, 0x64]),
+        Some(&[0xbe, 0x4e]),
+        Some(&[0xe8, 0xa3]),
+        Some(&[0xbe, 0x58]),
+        Some(&[0xe8, 0x74]),
+        Some(&[0xe8, 0x79]),
+        Some(&[0xe8, 0x73]),
+        Some(&[0xeb, 0xee]),
+        Some(&[0xe8, 0x6f]),
+        Some(&[0xe8, 0x77]),
+        Some(&[0xe8, 0x75]),
+        Some(&[0xe8, 0x68]),
+        Some(&[0xe8, 0x62]),
+        Some(&[0xe8, 0x7d]),
+        Some(&[0xbe, 0x57]),
+        Some(&[0xe8, 0x7e]),
+        None,
+        Some(&[0xe8, 0x78]),
+        None,
+        Some(&[0xe8, 0x6d]),
+        Some(&[0xe8, 0x6b]),
+        Some(&[0xe8, 0x66]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0x6e]),
+        Some(&[0xe8, 0x7b]),
+        Some(&[0xe8, 0x6a]),
+        Some(&[0xe8, 0x7a]),
+        Some(&[0xe8, 0xa2]),
+        None,
+        None,
+        Some(&[0xbe, 0x53]),
+        None,
+        Some(&[0xe8, 0x76]),
+        Some(&[0xe8, 0x7c]),
+        Some(&[0xe8, 0x72]),
+        Some(&[0xe8, 0x6c]),
+        Some(&[0xbe, 0x51]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xa8]),
+        Some(&[0xe8, 0x70]),
+        Some(&[0xbe, 0x59]),
+        Some(&[0xe8, 0x69]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xf4]),
+        Some(&[0xbf, 0xf7]),
+        Some(&[0xeb, 0xf3]),
+        Some(&[0xeb, 0xf0]),
+        Some(&[0xec, 0x44]),
+        Some(&[0xbf, 0xfb]),
+        None,
+        Some(&[0xec, 0x41]),
+        Some(&[0xeb, 0xf8]),
+        Some(&[0xec, 0x43]),
+        Some(&[0xeb, 0xe9]),
+        Some(&[0xeb, 0xf6]),
+        None,
+        Some(&[0xbf, 0xfd]),
+        None,
+        Some(&[0xeb, 0xe1]),
+        None,
+        Some(&[0xeb, 0xdf]),
+        Some(&[0xec, 0x42]),
+        None,
+        Some(&[0xec, 0x40]),
+        Some(&[0xeb, 0xfe]),
+        Some(&[0xeb, 0xed]),
+        Some(&[0xeb, 0xec]),
+        Some(&[0xeb, 0xe2]),
+        Some(&[0xc0, 0x40]),
+        None,
+        Some(&[0xeb, 0xe8]),
+        Some(&[0xeb, 0xf2]),
+        Some(&[0xeb, 0xfd]),
+        Some(&[0xc0, 0x43]),
+        Some(&[0xec, 0x45]),
+        None,
+        Some(&[0xc1, 0xe8]),
+        Some(&[0xc0, 0x45]),
+        Some(&[0xbf, 0xfe]),
+        Some(&[0xeb, 0xe6]),
+        None,
+        Some(&[0xeb, 0xef]),
+        Some(&[0xeb, 0xde]),
+        Some(&[0xeb, 0xe0]),
+        Some(&[0xbf, 0xf5]),
+        Some(&[0xc0, 0x42]),
+        Some(&[0xbf, 0xfa]),
+        Some(&[0xeb, 0xe7]),
+        Some(&[0xeb, 0xf7]),
+        Some(&[0xeb, 0xf1]),
+        Some(&[0xc0, 0x41]),
+        Some(&[0xeb, 0xdd]),
+        Some(&[0xc1, 0xe3]),
+        Some(&[0xeb, 0xf9]),
+        Some(&[0xeb, 0xfc]),
+        Some(&[0xbf, 0xfc]),
+        None,
+        Some(&[0xeb, 0xeb]),
+        Some(&[0xc0, 0x44]),
+        Some(&[0xbf, 0xf9]),
+        None,
+        None,
+        None,
+        Some(&[0xbf, 0xf8]),
+        Some(&[0xeb, 0xf5]),
+        Some(&[0xeb, 0xfb]),
+        Some(&[0xbf, 0xf6]),
+        None,
+        Some(&[0xeb, 0xe4]),
+        Some(&[0xeb, 0xfa]),
+        None,
+        None,
+        Some(&[0xeb, 0xe5]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xeb, 0xea]),
+        Some(&[0xee, 0xd2]),
+        None,
+        Some(&[0xee, 0xd7]),
+        Some(&[0xc1, 0xe5]),
+        Some(&[0xc1, 0xe7]),
+        Some(&[0xee, 0xdd]),
+        Some(&[0xc1, 0xe1]),
+        Some(&[0xee, 0xec]),
+        Some(&[0xee, 0xe3]),
+        Some(&[0xee, 0xd8]),
+        Some(&[0xee, 0xd9]),
+        Some(&[0xee, 0xe2]),
+        None,
+        Some(&[0xc1, 0xee]),
+        Some(&[0xee, 0xe1]),
+        Some(&[0xee, 0xd1]),
+        Some(&[0xee, 0xe0]),
+        Some(&[0xee, 0xd4]),
+        Some(&[0xee, 0xed]),
+        Some(&[0xc1, 0xed]),
+        Some(&[0xc1, 0xeb]),
+        Some(&[0xee, 0xd5]),
+        None,
+        Some(&[0xee, 0xe8]),
+        None,
+        Some(&[0xee, 0xda]),
+        Some(&[0xee, 0xe7]),
+        None,
+        Some(&[0xee, 0xe9]),
+        Some(&[0xee, 0xd0]),
+        Some(&[0xc1, 0xe6]),
+        None,
+        Some(&[0xee, 0xea]),
+        None,
+        None,
+        Some(&[0xee, 0xde]),
+        None,
+        Some(&[0xc1, 0xea]),
+        Some(&[0xee, 0xdb]),
+        None,
+        None,
+        Some(&[0xc1, 0xec]),
+        Some(&[0xee, 0xe4]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xe4]),
+        Some(&[0xee, 0xd6]),
+        Some(&[0xee, 0xe5]),
+        None,
+        Some(&[0xee, 0xdf]),
+        Some(&[0xeb, 0xe3]),
+        Some(&[0xee, 0xe6]),
+        Some(&[0xee, 0xd3]),
+        None,
+        Some(&[0xc1, 0xe9]),
+        None,
+        Some(&[0xee, 0xeb]),
+        None,
+        Some(&[0xc1, 0xe2]),
+        Some(&[0xee, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0x60]),
+        Some(&[0xf1, 0x59]),
+        Some(&[0xc2, 0xe9]),
+        None,
+        Some(&[0xf1, 0x54]),
+        Some(&[0xf1, 0x63]),
+        Some(&[0xf1, 0x5b]),
+        Some(&[0xee, 0xdc]),
+        None,
+        Some(&[0xf1, 0x65]),
+        Some(&[0xf1, 0x55]),
+        None,
+        Some(&[0xc2, 0xe8]),
+        Some(&[0xf1, 0x5f]),
+        Some(&[0xc2, 0xea]),
+        Some(&[0xc2, 0xf2]),
+        Some(&[0xc2, 0xf0]),
+        Some(&[0xf1, 0x61]),
+        Some(&[0xc2, 0xf1]),
+        Some(&[0xf1, 0x57]),
+        None,
+        Some(&[0xf1, 0x58]),
+        Some(&[0xf1, 0x5d]),
+        Some(&[0xf1, 0x62]),
+        None,
+        Some(&[0xee, 0xcd]),
+        Some(&[0xc2, 0xeb]),
+        Some(&[0xf1, 0x6a]),
+        Some(&[0xf1, 0x67]),
+        Some(&[0xf1, 0x6b]),
+        Some(&[0xf1, 0x5e]),
+        Some(&[0xf1, 0x5a]),
+        Some(&[0xf1, 0x68]),
+        Some(&[0xf3, 0x6a]),
+        Some(&[0xf1, 0x5c]),
+        None,
+        Some(&[0xc2, 0xee]),
+        None,
+        Some(&[0xc2, 0xed]),
+        Some(&[0xee, 0xcf]),
+        Some(&[0xc2, 0xef]),
+        Some(&[0xf1, 0x64]),
+        Some(&[0xf1, 0x66]),
+        Some(&[0xc2, 0xec]),
+        Some(&[0xf1, 0x69]),
+        Some(&[0xf1, 0x53]),
+        None,
+        Some(&[0xf1, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0x73]),
+        None,
+        Some(&[0xf3, 0x63]),
+        Some(&[0xc3, 0xeb]),
+        Some(&[0xf3, 0x71]),
+        None,
+        None,
+        Some(&[0xf3, 0x61]),
+        Some(&[0xc3, 0xec]),
+        None,
+        Some(&[0xf3, 0x6c]),
+        None,
+        Some(&[0xf3, 0x68]),
+        Some(&[0xc3, 0xf1]),
+        Some(&[0xf3, 0x72]),
+        Some(&[0xf3, 0x62]),
+        Some(&[0xf3, 0x65]),
+        Some(&[0xc3, 0xe9]),
+        Some(&[0xf3, 0x74]),
+        None,
+        Some(&[0xf3, 0x6d]),
+        Some(&[0xf3, 0x70]),
+        Some(&[0xc3, 0xef]),
+        Some(&[0xc3, 0xf4]),
+        Some(&[0xc3, 0xf2]),
+        Some(&[0xf3, 0x69]),
+        Some(&[0xf3, 0x64]),
+        None,
+        Some(&[0xc3, 0xed]),
+        Some(&[0xc3, 0xee]),
+        Some(&[0xf3, 0x60]),
+        Some(&[0xc3, 0xea]),
+        None,
+        Some(&[0xc3, 0xe8]),
+        Some(&[0xc3, 0xf0]),
+        Some(&[0xf3, 0x6f]),
+        Some(&[0xc3, 0xf3]),
+        None,
+        Some(&[0xf3, 0x6b]),
+        Some(&[0xf3, 0x75]),
+        Some(&[0xc3, 0xf5]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0x67]),
+        None,
+        Some(&[0xf3, 0x6e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xf3]),
+        Some(&[0xf5, 0x42]),
+        Some(&[0xf4, 0xf5]),
+        Some(&[0xf4, 0xfc]),
+        Some(&[0xf3, 0x66]),
+        Some(&[0xf4, 0xfa]),
+        Some(&[0xf4, 0xe9]),
+        Some(&[0xf5, 0x40]),
+        Some(&[0xc4, 0xc3]),
+        Some(&[0xf4, 0xed]),
+        Some(&[0xf4, 0xfe]),
+        Some(&[0xf4, 0xf4]),
+        None,
+        None,
+        Some(&[0xc4, 0xc2]),
+        None,
+        None,
+        Some(&[0xf5, 0x44]),
+        Some(&[0xf4, 0xf6]),
+        None,
+        Some(&[0xf4, 0xfb]),
+        Some(&[0xf4, 0xfd]),
+        Some(&[0xf4, 0xe7]),
+        Some(&[0xf5, 0x41]),
+        Some(&[0xf4, 0xf2]),
+        Some(&[0xf4, 0xf7]),
+        Some(&[0xf4, 0xeb]),
+        Some(&[0xf4, 0xef]),
+        Some(&[0xf5, 0x43]),
+        Some(&[0xf4, 0xf9]),
+        Some(&[0xf4, 0xe8]),
+        Some(&[0xf4, 0xec]),
+        Some(&[0xf4, 0xee]),
+        Some(&[0xf4, 0xf8]),
+        None,
+        Some(&[0xc4, 0xc1]),
+        Some(&[0xf4, 0xf1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xea]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf4, 0xf0]),
+        Some(&[0xf6, 0x61]),
+        Some(&[0xf6, 0x66]),
+        Some(&[0xc5, 0x4f]),
+        Some(&[0xf6, 0x68]),
+        None,
+        Some(&[0xc5, 0x49]),
+        None,
+        Some(&[0xf6, 0x64]),
+        Some(&[0xf6, 0x6a]),
+        Some(&[0xc5, 0x4e]),
+        Some(&[0xc5, 0x4a]),
+        None,
+        Some(&[0xc5, 0x4b]),
+        Some(&[0xf6, 0x60]),
+        Some(&[0xf6, 0x67]),
+        Some(&[0xc5, 0x4d]),
+        Some(&[0xf6, 0x65]),
+        Some(&[0xc5, 0x4c]),
+        Some(&[0xf6, 0x5f]),
+        Some(&[0xf6, 0x63]),
+        Some(&[0xf6, 0x62]),
+        None,
+        Some(&[0xf6, 0x5e]),
+        Some(&[0xf6, 0x69]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xb1]),
+        Some(&[0xf7, 0x6d]),
+        Some(&[0xf7, 0x70]),
+        Some(&[0xf7, 0x6c]),
+        Some(&[0xf7, 0x6e]),
+        Some(&[0xf7, 0x6f]),
+        Some(&[0xf7, 0x69]),
+        Some(&[0xf7, 0x6a]),
+        Some(&[0xf7, 0x67]),
+        None,
+        None,
+        Some(&[0xf7, 0x6b]),
+        Some(&[0xf7, 0x68]),
+        Some(&[0xc5, 0xb2]),
+        Some(&[0xc5, 0xb3]),
+        None,
+        None,
+        Some(&[0xf8, 0x4b]),
+        None,
+        Some(&[0xf8, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf8, 0x4c]),
+        Some(&[0xf8, 0x4e]),
+        None,
+        Some(&[0xc5, 0xe0]),
+        None,
+        Some(&[0xf8, 0x4a]),
+        Some(&[0xc5, 0xdf]),
+        Some(&[0xc5, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xf8, 0xcb]),
+        Some(&[0xf8, 0xcc]),
+        Some(&[0xc6, 0x44]),
+        Some(&[0xf8, 0xca]),
+        None,
+        Some(&[0xf9, 0x53]),
+        Some(&[0xf9, 0x52]),
+        Some(&[0xf9, 0x54]),
+        Some(&[0xc6, 0x5f]),
+        Some(&[0xf9, 0x55]),
+        Some(&[0xc6, 0x5e]),
+        Some(&[0xf9, 0x56]),
+        Some(&[0xf9, 0x72]),
+        Some(&[0xf9, 0x75]),
+        Some(&[0xf9, 0x74]),
+        Some(&[0xc6, 0x68]),
+        Some(&[0xf9, 0x73]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0x72]),
+        Some(&[0xc6, 0x70]),
+        Some(&[0xc6, 0x71]),
+        Some(&[0xc6, 0x77]),
+        Some(&[0xf9, 0xc0]),
+        Some(&[0xf9, 0xc1]),
+        Some(&[0xf9, 0xbf]),
+        Some(&[0xf9, 0xc9]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9570: [Option<&'static [u8]>; 1808] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xf8]),
+        None,
+        None,
+        Some(&[0xd8, 0x44]),
+        Some(&[0xdc, 0x78]),
+        Some(&[0xe8, 0xa5]),
+        Some(&[0xf3, 0x76]),
+        None,
+        None,
+        Some(&[0xaa, 0xf9]),
+        None,
+        Some(&[0xad, 0xac]),
+        Some(&[0xb0, 0x7b]),
+        None,
+        None,
+        Some(&[0xd8, 0x45]),
+        None,
+        Some(&[0xd8, 0x46]),
+        Some(&[0xb3, 0xac]),
+        None,
+        Some(&[0xb6, 0x7d]),
+        Some(&[0xdc, 0x7a]),
+        Some(&[0xdc, 0x79]),
+        Some(&[0xb6, 0xa3]),
+        Some(&[0xb6, 0x7c]),
+        Some(&[0xdc, 0x7b]),
+        Some(&[0xb6, 0x7e]),
+        Some(&[0xb6, 0xa2]),
+        Some(&[0xb6, 0xa1]),
+        Some(&[0xb6, 0x7b]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0x68]),
+        None,
+        None,
+        Some(&[0xe0, 0xd0]),
+        Some(&[0xe0, 0xce]),
+        None,
+        Some(&[0xe0, 0xcf]),
+        Some(&[0xe0, 0xcd]),
+        None,
+        Some(&[0xbb, 0xd2]),
+        None,
+        Some(&[0xbb, 0xd5]),
+        Some(&[0xbb, 0xd7]),
+        Some(&[0xbb, 0xd6]),
+        None,
+        None,
+        Some(&[0xbb, 0xd3]),
+        Some(&[0xbb, 0xd4]),
+        None,
+        Some(&[0xe8, 0xa7]),
+        Some(&[0xe8, 0xa6]),
+        Some(&[0xbe, 0x5b]),
+        Some(&[0xe8, 0xa8]),
+        None,
+        Some(&[0xe8, 0xa9]),
+        Some(&[0xbe, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0x4d]),
+        Some(&[0xec, 0x4b]),
+        Some(&[0xee, 0xf3]),
+        None,
+        Some(&[0xec, 0x49]),
+        Some(&[0xec, 0x4a]),
+        Some(&[0xc0, 0x46]),
+        Some(&[0xec, 0x46]),
+        Some(&[0xec, 0x4e]),
+        Some(&[0xec, 0x48]),
+        Some(&[0xec, 0x4c]),
+        Some(&[0xee, 0xef]),
+        None,
+        None,
+        Some(&[0xee, 0xf1]),
+        None,
+        Some(&[0xee, 0xf2]),
+        Some(&[0xc1, 0xf3]),
+        Some(&[0xee, 0xee]),
+        Some(&[0xc1, 0xf2]),
+        Some(&[0xee, 0xf0]),
+        Some(&[0xc1, 0xef]),
+        Some(&[0xc1, 0xf0]),
+        Some(&[0xc1, 0xf1]),
+        Some(&[0xec, 0x47]),
+        None,
+        None,
+        Some(&[0xc2, 0xf5]),
+        Some(&[0xf1, 0x6e]),
+        Some(&[0xf1, 0x6c]),
+        Some(&[0xf1, 0x6d]),
+        Some(&[0xc2, 0xf3]),
+        Some(&[0xc2, 0xf6]),
+        Some(&[0xc2, 0xf4]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0x77]),
+        Some(&[0xf3, 0x78]),
+        Some(&[0xc3, 0xf6]),
+        None,
+        Some(&[0xf5, 0x45]),
+        Some(&[0xf5, 0x47]),
+        Some(&[0xf5, 0x46]),
+        Some(&[0xc4, 0xc4]),
+        Some(&[0xc5, 0x50]),
+        Some(&[0xf6, 0x6d]),
+        Some(&[0xf6, 0x6c]),
+        Some(&[0xf6, 0x6b]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xaa, 0xfa]),
+        None,
+        Some(&[0xc9, 0xaa]),
+        None,
+        Some(&[0xca, 0x58]),
+        Some(&[0xa6, 0xe9]),
+        Some(&[0xca, 0x56]),
+        Some(&[0xca, 0x59]),
+        Some(&[0xca, 0x57]),
+        None,
+        None,
+        None,
+        Some(&[0xcb, 0xae]),
+        None,
+        Some(&[0xa8, 0xc1]),
+        None,
+        Some(&[0xa8, 0xc2]),
+        Some(&[0xcb, 0xb0]),
+        Some(&[0xa8, 0xbf]),
+        Some(&[0xcb, 0xaf]),
+        Some(&[0xcb, 0xad]),
+        Some(&[0xa8, 0xc0]),
+        Some(&[0xa8, 0xbe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xcd, 0xd8]),
+        Some(&[0xcd, 0xdb]),
+        Some(&[0xaa, 0xfd]),
+        Some(&[0xcd, 0xda]),
+        Some(&[0xcd, 0xd9]),
+        None,
+        Some(&[0xaa, 0xfc]),
+        Some(&[0xaa, 0xfb]),
+        None,
+        Some(&[0xab, 0x40]),
+        Some(&[0xcd, 0xdc]),
+        Some(&[0xaa, 0xfe]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xd0, 0xc6]),
+        Some(&[0xad, 0xae]),
+        Some(&[0xad, 0xaf]),
+        Some(&[0xad, 0xb0]),
+        Some(&[0xd0, 0xc7]),
+        Some(&[0xd0, 0xc3]),
+        Some(&[0xad, 0xad]),
+        Some(&[0xd0, 0xc4]),
+        None,
+        Some(&[0xd0, 0xc5]),
+        Some(&[0xd0, 0xc2]),
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xa4]),
+        None,
+        None,
+        Some(&[0xb0, 0xa1]),
+        Some(&[0xd4, 0x45]),
+        Some(&[0xb0, 0xa2]),
+        Some(&[0xb0, 0xa5]),
+        Some(&[0xd4, 0x46]),
+        None,
+        Some(&[0xb0, 0x7e]),
+        Some(&[0xb0, 0x7c]),
+        Some(&[0xb0, 0x7d]),
+        Some(&[0xb0, 0xa3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xad]),
+        Some(&[0xd8, 0x49]),
+        Some(&[0xb3, 0xb5]),
+        Some(&[0xd8, 0x48]),
+        None,
+        Some(&[0xd8, 0x4b]),
+        Some(&[0xb3, 0xb1]),
+        Some(&[0xd8, 0x4a]),
+        Some(&[0xb6, 0xab]),
+        Some(&[0xb3, 0xaf]),
+        Some(&[0xb3, 0xb2]),
+        Some(&[0xb3, 0xae]),
+        Some(&[0xb3, 0xb3]),
+        Some(&[0xb3, 0xb4]),
+        Some(&[0xb3, 0xb0]),
+        None,
+        None,
+        None,
+        Some(&[0xd8, 0x47]),
+        Some(&[0xb6, 0xa7]),
+        Some(&[0xdc, 0x7d]),
+        None,
+        Some(&[0xdc, 0xa3]),
+        None,
+        None,
+        Some(&[0xdc, 0xa2]),
+        Some(&[0xb6, 0xac]),
+        Some(&[0xb6, 0xa8]),
+        Some(&[0xb6, 0xa9]),
+        Some(&[0xdc, 0x7c]),
+        Some(&[0xdc, 0x7e]),
+        Some(&[0xdc, 0xa1]),
+        Some(&[0xb6, 0xa4]),
+        Some(&[0xb6, 0xa6]),
+        None,
+        Some(&[0xb6, 0xaa]),
+        Some(&[0xb6, 0xa5]),
+        None,
+        None,
+        Some(&[0xe0, 0xd3]),
+        Some(&[0xe0, 0xd1]),
+        Some(&[0xe0, 0xd2]),
+        Some(&[0xb9, 0x6a]),
+        Some(&[0xb9, 0x6b]),
+        None,
+        Some(&[0xe0, 0xd4]),
+        Some(&[0xb9, 0x69]),
+        Some(&[0xbb, 0xd8]),
+        None,
+        Some(&[0xbb, 0xda]),
+        Some(&[0xbb, 0xd9]),
+        None,
+        Some(&[0xe4, 0xbb]),
+        None,
+        None,
+        Some(&[0xe4, 0xbc]),
+        Some(&[0xe8, 0xab]),
+        None,
+        Some(&[0xe8, 0xaa]),
+        None,
+        None,
+        Some(&[0xc0, 0x47]),
+        Some(&[0xc0, 0x48]),
+        Some(&[0xec, 0x4f]),
+        Some(&[0xc0, 0x49]),
+        None,
+        Some(&[0xee, 0xf6]),
+        None,
+        Some(&[0xee, 0xf4]),
+        None,
+        Some(&[0xee, 0xf5]),
+        Some(&[0xc1, 0xf4]),
+        None,
+        Some(&[0xf1, 0x6f]),
+        Some(&[0xc3, 0xf7]),
+        None,
+        None,
+        None,
+        Some(&[0xc1, 0xf5]),
+        Some(&[0xab, 0x41]),
+        None,
+        Some(&[0xb0, 0xa6]),
+        Some(&[0xd4, 0x47]),
+        None,
+        None,
+        Some(&[0xd8, 0x4c]),
+        Some(&[0xb3, 0xb6]),
+        Some(&[0xb6, 0xad]),
+        Some(&[0xdc, 0xa4]),
+        Some(&[0xdc, 0xa6]),
+        Some(&[0xb6, 0xaf]),
+        Some(&[0xb6, 0xae]),
+        Some(&[0xb6, 0xb0]),
+        Some(&[0xb6, 0xb1]),
+        Some(&[0xdc, 0xa5]),
+        Some(&[0xb9, 0x6e]),
+        Some(&[0xb9, 0x6f]),
+        Some(&[0xb9, 0x6d]),
+        Some(&[0xbb, 0xdb]),
+        Some(&[0xb9, 0x6c]),
+        Some(&[0xe0, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xdc]),
+        Some(&[0xe8, 0xac]),
+        Some(&[0xec, 0x50]),
+        Some(&[0xc0, 0x4a]),
+        Some(&[0xc1, 0xf6]),
+        Some(&[0xf1, 0x70]),
+        Some(&[0xf1, 0x74]),
+        Some(&[0xc2, 0xf9]),
+        Some(&[0xf1, 0x71]),
+        Some(&[0xc2, 0xfa]),
+        Some(&[0xc2, 0xf8]),
+        Some(&[0xf1, 0x75]),
+        Some(&[0xc2, 0xfb]),
+        Some(&[0xf1, 0x73]),
+        None,
+        Some(&[0xf3, 0x79]),
+        Some(&[0xc2, 0xf7]),
+        Some(&[0xc3, 0xf8]),
+        None,
+        Some(&[0xf8, 0xcd]),
+        None,
+        None,
+        Some(&[0xab, 0x42]),
+        Some(&[0xb3, 0xb8]),
+        Some(&[0xb3, 0xb7]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb6, 0xb2]),
+        Some(&[0xdc, 0xa8]),
+        Some(&[0xdc, 0xa7]),
+        Some(&[0xb6, 0xb3]),
+        None,
+        None,
+        Some(&[0xe0, 0xd9]),
+        Some(&[0xb9, 0x73]),
+        Some(&[0xb9, 0x70]),
+        Some(&[0xe0, 0xd8]),
+        Some(&[0xb9, 0x72]),
+        Some(&[0xe0, 0xd6]),
+        Some(&[0xb9, 0x71]),
+        None,
+        Some(&[0xe0, 0xd7]),
+        None,
+        Some(&[0xe4, 0xbd]),
+        Some(&[0xbb, 0xdd]),
+        None,
+        Some(&[0xe8, 0xaf]),
+        None,
+        Some(&[0xbe, 0x5d]),
+        Some(&[0xe8, 0xad]),
+        Some(&[0xbe, 0x5e]),
+        Some(&[0xbe, 0x5f]),
+        Some(&[0xe8, 0xae]),
+        Some(&[0xbe, 0x60]),
+        None,
+        Some(&[0xec, 0x51]),
+        None,
+        Some(&[0xc0, 0x4e]),
+        Some(&[0xc0, 0x4b]),
+        Some(&[0xc0, 0x50]),
+        Some(&[0xec, 0x53]),
+        Some(&[0xc0, 0x4c]),
+        Some(&[0xec, 0x52]),
+        Some(&[0xc0, 0x4f]),
+        None,
+        None,
+        Some(&[0xc0, 0x4d]),
+        None,
+        Some(&[0xee, 0xf9]),
+        Some(&[0xee, 0xfb]),
+        None,
+        None,
+        Some(&[0xc1, 0xf7]),
+        Some(&[0xee, 0xfa]),
+        Some(&[0xc1, 0xf8]),
+        Some(&[0xee, 0xf8]),
+        Some(&[0xee, 0xf7]),
+        None,
+        Some(&[0xf1, 0x77]),
+        Some(&[0xf1, 0x76]),
+        Some(&[0xc2, 0xfc]),
+        Some(&[0xf1, 0x78]),
+        Some(&[0xf3, 0x7e]),
+        Some(&[0xc3, 0xfa]),
+        Some(&[0xf3, 0x7d]),
+        Some(&[0xf3, 0x7a]),
+        Some(&[0xc3, 0xf9]),
+        Some(&[0xf3, 0x7b]),
+        Some(&[0xf3, 0x7c]),
+        None,
+        Some(&[0xf5, 0x48]),
+        Some(&[0xf5, 0x49]),
+        Some(&[0xc4, 0xc5]),
+        None,
+        Some(&[0xc5, 0x53]),
+        None,
+        None,
+        Some(&[0xf6, 0x6e]),
+        None,
+        None,
+        Some(&[0xc5, 0x51]),
+        Some(&[0xc5, 0x52]),
+        Some(&[0xf6, 0x6f]),
+        None,
+        None,
+        Some(&[0xc5, 0xb4]),
+        Some(&[0xc5, 0xb5]),
+        Some(&[0xf7, 0x71]),
+        None,
+        None,
+        Some(&[0xc6, 0x45]),
+        Some(&[0xf8, 0xcf]),
+        Some(&[0xc6, 0x47]),
+        None,
+        Some(&[0xf8, 0xce]),
+        Some(&[0xf8, 0xd0]),
+        Some(&[0xc6, 0x46]),
+        Some(&[0xf9, 0x57]),
+        None,
+        Some(&[0xf9, 0xad]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xab, 0x43]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0x74]),
+        None,
+        Some(&[0xe4, 0xbe]),
+        None,
+        Some(&[0xe8, 0xb0]),
+        Some(&[0xc0, 0x51]),
+        Some(&[0xc0, 0x52]),
+        None,
+        Some(&[0xab, 0x44]),
+        None,
+        Some(&[0xbe, 0x61]),
+        Some(&[0xc3, 0xfb]),
+        Some(&[0xad, 0xb1]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0x53]),
+        None,
+        Some(&[0xc5, 0xe2]),
+        Some(&[0xad, 0xb2]),
+        Some(&[0xd8, 0x4d]),
+        None,
+        Some(&[0xdc, 0xa9]),
+        None,
+        Some(&[0xdc, 0xab]),
+        None,
+        Some(&[0xdc, 0xaa]),
+        None,
+        Some(&[0xe0, 0xdd]),
+        Some(&[0xe0, 0xda]),
+        Some(&[0xb9, 0x75]),
+        None,
+        Some(&[0xb9, 0x76]),
+        Some(&[0xe0, 0xdb]),
+        Some(&[0xe0, 0xdc]),
+        None,
+        Some(&[0xe4, 0xc0]),
+        Some(&[0xe4, 0xc5]),
+        Some(&[0xbb, 0xde]),
+        Some(&[0xe4, 0xbf]),
+        Some(&[0xe4, 0xc1]),
+        Some(&[0xe4, 0xc8]),
+        Some(&[0xe4, 0xc3]),
+        Some(&[0xe4, 0xc7]),
+        Some(&[0xe4, 0xc4]),
+        Some(&[0xe4, 0xc2]),
+        Some(&[0xe4, 0xc6]),
+        Some(&[0xbb, 0xdf]),
+        None,
+        None,
+        Some(&[0xe8, 0xb3]),
+        None,
+        Some(&[0xe8, 0xb1]),
+        Some(&[0xbe, 0x63]),
+        None,
+        Some(&[0xbe, 0x62]),
+        Some(&[0xe8, 0xb2]),
+        Some(&[0xbe, 0x64]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0x56]),
+        None,
+        None,
+        Some(&[0xec, 0x55]),
+        Some(&[0xc0, 0x54]),
+        Some(&[0xec, 0x54]),
+        Some(&[0xee, 0xfc]),
+        None,
+        Some(&[0xee, 0xfe]),
+        Some(&[0xef, 0x41]),
+        Some(&[0xef, 0x40]),
+        None,
+        Some(&[0xc1, 0xf9]),
+        Some(&[0xee, 0xfd]),
+        Some(&[0xf1, 0xa1]),
+        Some(&[0xc2, 0xfd]),
+        Some(&[0xf1, 0x7d]),
+        Some(&[0xf1, 0xa2]),
+        Some(&[0xc2, 0xfe]),
+        None,
+        Some(&[0xf1, 0x7b]),
+        None,
+        Some(&[0xf1, 0x7e]),
+        Some(&[0xf1, 0x7c]),
+        Some(&[0xf1, 0x79]),
+        Some(&[0xc3, 0x40]),
+        Some(&[0xf1, 0x7a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xa1]),
+        None,
+        None,
+        Some(&[0xf3, 0xa3]),
+        Some(&[0xf3, 0xa2]),
+        None,
+        Some(&[0xf5, 0x4a]),
+        None,
+        Some(&[0xf5, 0x4b]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0x70]),
+        None,
+        Some(&[0xc5, 0xb7]),
+        None,
+        Some(&[0xc5, 0xb6]),
+        Some(&[0xf8, 0x4f]),
+        Some(&[0xf8, 0x50]),
+        Some(&[0xc6, 0x48]),
+        Some(&[0xf8, 0xd1]),
+        None,
+        Some(&[0xc6, 0x69]),
+        None,
+        Some(&[0xad, 0xb3]),
+        Some(&[0xb6, 0xb4]),
+        Some(&[0xe4, 0xca]),
+        Some(&[0xe4, 0xc9]),
+        Some(&[0xe8, 0xb5]),
+        Some(&[0xe8, 0xb4]),
+        None,
+        None,
+        Some(&[0xc1, 0xfa]),
+        Some(&[0xef, 0x43]),
+        Some(&[0xef, 0x42]),
+        Some(&[0xf1, 0xa5]),
+        Some(&[0xf1, 0xa3]),
+        Some(&[0xf1, 0xa6]),
+        Some(&[0xf1, 0xa4]),
+        None,
+        None,
+        Some(&[0xc3, 0xfc]),
+        Some(&[0xf3, 0xa4]),
+        Some(&[0xf3, 0xa5]),
+        Some(&[0xf3, 0xa6]),
+        None,
+        Some(&[0xf6, 0x71]),
+        None,
+        Some(&[0xf7, 0x72]),
+        None,
+        Some(&[0xf8, 0xd2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xb4]),
+        None,
+        None,
+        Some(&[0xec, 0x57]),
+        Some(&[0xef, 0x44]),
+        None,
+        Some(&[0xad, 0xb5]),
+        None,
+        None,
+        Some(&[0xbb, 0xe0]),
+        None,
+        Some(&[0xec, 0x58]),
+        Some(&[0xc3, 0x41]),
+        Some(&[0xf1, 0xa7]),
+        Some(&[0xc3, 0xfd]),
+        None,
+        Some(&[0xf5, 0x4c]),
+        Some(&[0xf5, 0x4d]),
+        Some(&[0xc5, 0x54]),
+        Some(&[0xf8, 0x51]),
+        Some(&[0xad, 0xb6]),
+        Some(&[0xb3, 0xbb]),
+        Some(&[0xb3, 0xbc]),
+        Some(&[0xd8, 0x4e]),
+        Some(&[0xb6, 0xb5]),
+        Some(&[0xb6, 0xb6]),
+        Some(&[0xdc, 0xac]),
+        Some(&[0xb6, 0xb7]),
+        None,
+        Some(&[0xb9, 0x7a]),
+        None,
+        Some(&[0xb9, 0x7c]),
+        Some(&[0xe0, 0xdf]),
+        Some(&[0xe0, 0xe0]),
+        Some(&[0xe0, 0xde]),
+        Some(&[0xb9, 0x77]),
+        Some(&[0xb9, 0x78]),
+        Some(&[0xb9, 0x7b]),
+        Some(&[0xb9, 0x79]),
+        None,
+        None,
+        Some(&[0xe4, 0xcb]),
+        Some(&[0xbb, 0xe1]),
+        Some(&[0xbb, 0xe2]),
+        None,
+        None,
+        Some(&[0xe8, 0xbc]),
+        Some(&[0xbe, 0x67]),
+        Some(&[0xe8, 0xb7]),
+        Some(&[0xe8, 0xb6]),
+        None,
+        Some(&[0xe8, 0xbb]),
+        Some(&[0xbe, 0x65]),
+        None,
+        None,
+        Some(&[0xc0, 0x5b]),
+        None,
+        Some(&[0xe8, 0xb8]),
+        Some(&[0xe8, 0xbd]),
+        Some(&[0xe8, 0xba]),
+        Some(&[0xe8, 0xb9]),
+        None,
+        Some(&[0xbe, 0x66]),
+        None,
+        Some(&[0xc0, 0x59]),
+        None,
+        Some(&[0xec, 0x5a]),
+        Some(&[0xc0, 0x55]),
+        None,
+        Some(&[0xec, 0x5b]),
+        None,
+        None,
+        Some(&[0xec, 0x59]),
+        None,
+        Some(&[0xc0, 0x58]),
+        Some(&[0xc0, 0x56]),
+        Some(&[0xc0, 0x5a]),
+        None,
+        Some(&[0xc0, 0x57]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0x45]),
+        None,
+        Some(&[0xef, 0x4a]),
+        Some(&[0xef, 0x46]),
+        Some(&[0xef, 0x49]),
+        Some(&[0xc1, 0xfb]),
+        None,
+        Some(&[0xed, 0xd4]),
+        Some(&[0xef, 0x48]),
+        Some(&[0xef, 0x47]),
+        None,
+        Some(&[0xc3, 0x44]),
+        Some(&[0xc3, 0x42]),
+        Some(&[0xc3, 0x45]),
+        Some(&[0xc3, 0x43]),
+        Some(&[0xf1, 0xa8]),
+        Some(&[0xf1, 0xa9]),
+        Some(&[0xf1, 0xaa]),
+        Some(&[0xc3, 0x46]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xaa]),
+        Some(&[0xc4, 0x40]),
+        Some(&[0xf3, 0xa8]),
+        None,
+        Some(&[0xc4, 0x41]),
+        Some(&[0xf3, 0xa7]),
+        Some(&[0xf3, 0xa9]),
+        Some(&[0xc3, 0xfe]),
+        Some(&[0xf5, 0x51]),
+        Some(&[0xf5, 0x4e]),
+        None,
+        Some(&[0xf5, 0x4f]),
+        Some(&[0xf5, 0x50]),
+        Some(&[0xf6, 0x72]),
+        Some(&[0xc5, 0x56]),
+        None,
+        Some(&[0xc5, 0x55]),
+        None,
+        Some(&[0xf7, 0x74]),
+        Some(&[0xf7, 0x73]),
+        Some(&[0xc5, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xe3]),
+        Some(&[0xc6, 0x49]),
+        Some(&[0xc6, 0x60]),
+        Some(&[0xf9, 0x58]),
+        Some(&[0xf9, 0xae]),
+        Some(&[0xf9, 0xaf]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xb7]),
+        Some(&[0xdc, 0xad]),
+        None,
+        None,
+        Some(&[0xe0, 0xe1]),
+        Some(&[0xe4, 0xcc]),
+        Some(&[0xe4, 0xcd]),
+        Some(&[0xbb, 0xe3]),
+        None,
+        Some(&[0xbb, 0xe4]),
+        Some(&[0xe8, 0xbe]),
+        Some(&[0xbe, 0x68]),
+        None,
+        None,
+        Some(&[0xc1, 0xfc]),
+        None,
+        Some(&[0xf1, 0xab]),
+        None,
+        Some(&[0xc3, 0x47]),
+        Some(&[0xf3, 0xad]),
+        Some(&[0xc4, 0x42]),
+        Some(&[0xf3, 0xac]),
+        Some(&[0xf3, 0xae]),
+        Some(&[0xf3, 0xab]),
+        Some(&[0xf6, 0x75]),
+        Some(&[0xf5, 0x52]),
+        Some(&[0xf5, 0x53]),
+        None,
+        Some(&[0xc4, 0xc6]),
+        None,
+        Some(&[0xf6, 0x74]),
+        None,
+        None,
+        Some(&[0xf6, 0x73]),
+        None,
+        Some(&[0xf7, 0x75]),
+        Some(&[0xf9, 0xb0]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xb8]),
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xb9]),
+        None,
+        None,
+        Some(&[0xb0, 0xa7]),
+        Some(&[0xd4, 0x48]),
+        None,
+        Some(&[0xd8, 0x4f]),
+        None,
+        Some(&[0xb6, 0xb8]),
+        None,
+        Some(&[0xb6, 0xbb]),
+        Some(&[0xb6, 0xb9]),
+        Some(&[0xdc, 0xae]),
+        None,
+        Some(&[0xb6, 0xbd]),
+        None,
+        Some(&[0xb6, 0xba]),
+        None,
+        None,
+        Some(&[0xb6, 0xbc]),
+        None,
+        Some(&[0xb9, 0x7e]),
+        None,
+        Some(&[0xe0, 0xe2]),
+        None,
+        None,
+        Some(&[0xe0, 0xe3]),
+        Some(&[0xe8, 0xc0]),
+        None,
+        Some(&[0xb9, 0x7d]),
+        Some(&[0xb9, 0xa1]),
+        Some(&[0xb9, 0xa2]),
+        None,
+        Some(&[0xe4, 0xcf]),
+        None,
+        Some(&[0xe4, 0xce]),
+        Some(&[0xbb, 0xe5]),
+        None,
+        Some(&[0xbb, 0xe6]),
+        None,
+        Some(&[0xe4, 0xd0]),
+        Some(&[0xe8, 0xbf]),
+        Some(&[0xbb, 0xe8]),
+        Some(&[0xbe, 0x69]),
+        None,
+        Some(&[0xbb, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0x5c]),
+        Some(&[0xe8, 0xc1]),
+        Some(&[0xbe, 0x6b]),
+        Some(&[0xbe, 0x6a]),
+        Some(&[0xe8, 0xc2]),
+        Some(&[0xe8, 0xc5]),
+        Some(&[0xe8, 0xc3]),
+        Some(&[0xe8, 0xc4]),
+        Some(&[0xbe, 0x6c]),
+        None,
+        Some(&[0xc0, 0x61]),
+        Some(&[0xc0, 0x5f]),
+        None,
+        None,
+        Some(&[0xc0, 0x5e]),
+        Some(&[0xec, 0x5d]),
+        None,
+        Some(&[0xc0, 0x60]),
+        None,
+        None,
+        Some(&[0xec, 0x5c]),
+        Some(&[0xef, 0x4b]),
+        None,
+        Some(&[0xec, 0x5e]),
+        Some(&[0xc0, 0x5d]),
+        Some(&[0xec, 0x5f]),
+        Some(&[0xef, 0x4e]),
+        Some(&[0xef, 0x4c]),
+        Some(&[0xef, 0x4d]),
+        Some(&[0xef, 0x52]),
+        Some(&[0xc3, 0x4b]),
+        Some(&[0xef, 0x51]),
+        Some(&[0xef, 0x54]),
+        Some(&[0xef, 0x53]),
+        Some(&[0xef, 0x50]),
+        Some(&[0xef, 0x4f]),
+        None,
+        Some(&[0xc1, 0xfd]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xae]),
+        None,
+        Some(&[0xf1, 0xad]),
+        Some(&[0xc3, 0x4a]),
+        Some(&[0xc3, 0x48]),
+        Some(&[0xc3, 0x49]),
+        None,
+        Some(&[0xf1, 0xac]),
+        None,
+        Some(&[0xf3, 0xb1]),
+        None,
+        Some(&[0xc4, 0x43]),
+        None,
+        Some(&[0xf3, 0xb0]),
+        Some(&[0xf3, 0xaf]),
+        Some(&[0xc4, 0x44]),
+        None,
+        Some(&[0xf5, 0x58]),
+        Some(&[0xf5, 0x57]),
+        None,
+        Some(&[0xf5, 0x55]),
+        None,
+        Some(&[0xf5, 0x54]),
+        Some(&[0xc4, 0xc8]),
+        Some(&[0xc4, 0xc7]),
+        Some(&[0xf5, 0x59]),
+        Some(&[0xf7, 0x76]),
+        Some(&[0xc5, 0xb9]),
+        Some(&[0xf6, 0x77]),
+        Some(&[0xc5, 0x57]),
+        Some(&[0xf6, 0x76]),
+        Some(&[0xf5, 0x56]),
+        None,
+        Some(&[0xf7, 0x77]),
+        Some(&[0xc5, 0xe4]),
+        None,
+        Some(&[0xc6, 0x61]),
+        Some(&[0xf9, 0x59]),
+        None,
+        Some(&[0xf9, 0xb1]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xad, 0xba]),
+        Some(&[0xd8, 0x50]),
+        Some(&[0xef, 0x55]),
+        Some(&[0xad, 0xbb]),
+        None,
+        None,
+        Some(&[0xe4, 0xd2]),
+        Some(&[0xe4, 0xd1]),
+        Some(&[0xec, 0x60]),
+        None,
+        None,
+        Some(&[0xef, 0x57]),
+        None,
+        Some(&[0xef, 0x56]),
+        None,
+        Some(&[0xc3, 0x4c]),
+        Some(&[0xf3, 0xb2]),
+        Some(&[0xf3, 0xb3]),
+        Some(&[0xc4, 0xc9]),
+        None,
+        None,
+        Some(&[0xf9, 0xb2]),
+        Some(&[0xb0, 0xa8]),
+        Some(&[0xb6, 0xbf]),
+        Some(&[0xb6, 0xbe]),
+        Some(&[0xe0, 0xe4]),
+        Some(&[0xe0, 0xe6]),
+        Some(&[0xb9, 0xa4]),
+        Some(&[0xe0, 0xe5]),
+        Some(&[0xb9, 0xa3]),
+        Some(&[0xb9, 0xa5]),
+        Some(&[0xe0, 0xe7]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd4]),
+        Some(&[0xe4, 0xd6]),
+        Some(&[0xe4, 0xd5]),
+        None,
+        Some(&[0xe4, 0xd8]),
+        None,
+        None,
+        None,
+        Some(&[0xbb, 0xe9]),
+        Some(&[0xe4, 0xd7]),
+        Some(&[0xe4, 0xd3]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xd9]),
+        None,
+        Some(&[0xe8, 0xcc]),
+        None,
+        Some(&[0xe8, 0xcf]),
+        Some(&[0xe8, 0xd1]),
+        Some(&[0xe8, 0xc7]),
+        Some(&[0xe8, 0xcb]),
+        Some(&[0xe8, 0xc8]),
+        Some(&[0xbe, 0x6e]),
+        Some(&[0xbe, 0x71]),
+        Some(&[0xbe, 0x73]),
+        Some(&[0xe8, 0xc9]),
+        Some(&[0xe8, 0xca]),
+        Some(&[0xbe, 0x72]),
+        Some(&[0xe8, 0xcd]),
+        Some(&[0xe8, 0xd0]),
+        Some(&[0xe8, 0xce]),
+        Some(&[0xbe, 0x74]),
+        None,
+        Some(&[0xbe, 0x70]),
+        Some(&[0xe8, 0xc6]),
+        Some(&[0xbe, 0x6d]),
+        None,
+        Some(&[0xbe, 0x6f]),
+        None,
+        None,
+        Some(&[0xc0, 0x63]),
+        Some(&[0xec, 0x66]),
+        Some(&[0xec, 0x64]),
+        Some(&[0xec, 0x63]),
+        None,
+        Some(&[0xec, 0x69]),
+        None,
+        Some(&[0xec, 0x68]),
+        Some(&[0xec, 0x67]),
+        None,
+        Some(&[0xec, 0x62]),
+        Some(&[0xc0, 0x62]),
+        Some(&[0xec, 0x61]),
+        None,
+        Some(&[0xec, 0x65]),
+        Some(&[0xc0, 0x64]),
+        None,
+        None,
+        Some(&[0xef, 0x5a]),
+        None,
+        Some(&[0xef, 0x5e]),
+        Some(&[0xef, 0x5b]),
+        Some(&[0xef, 0x5d]),
+        Some(&[0xef, 0x5c]),
+        Some(&[0xef, 0x59]),
+        Some(&[0xef, 0x5f]),
+        Some(&[0xef, 0x62]),
+        Some(&[0xef, 0x60]),
+        Some(&[0xef, 0x61]),
+        Some(&[0xc2, 0x40]),
+        None,
+        Some(&[0xc1, 0xfe]),
+        Some(&[0xef, 0x58]),
+        Some(&[0xef, 0x63]),
+        Some(&[0xf1, 0xb3]),
+        Some(&[0xf1, 0xb6]),
+        Some(&[0xf1, 0xb8]),
+        Some(&[0xf1, 0xb7]),
+        None,
+        Some(&[0xf1, 0xb1]),
+        Some(&[0xf1, 0xb5]),
+        Some(&[0xf1, 0xb0]),
+        None,
+        Some(&[0xf1, 0xb2]),
+        Some(&[0xc3, 0x4d]),
+        Some(&[0xf1, 0xaf]),
+        None,
+        Some(&[0xf1, 0xb4]),
+        None,
+        None,
+        Some(&[0xf3, 0xc0]),
+        Some(&[0xf3, 0xb5]),
+        Some(&[0xc4, 0x45]),
+        None,
+        None,
+        Some(&[0xc4, 0x46]),
+        Some(&[0xf3, 0xb4]),
+        Some(&[0xf3, 0xb9]),
+        Some(&[0xf3, 0xbf]),
+        Some(&[0xf3, 0xb7]),
+        Some(&[0xf3, 0xbe]),
+        None,
+        Some(&[0xf3, 0xbb]),
+        None,
+        Some(&[0xf3, 0xba]),
+        Some(&[0xf3, 0xbd]),
+        Some(&[0xf3, 0xb8]),
+        Some(&[0xf3, 0xb6]),
+        None,
+        Some(&[0xf3, 0xbc]),
+        None,
+        Some(&[0xf5, 0x60]),
+        Some(&[0xf5, 0x5e]),
+        Some(&[0xc4, 0xca]),
+        Some(&[0xf5, 0x5d]),
+        Some(&[0xf5, 0x63]),
+        Some(&[0xf5, 0x61]),
+        None,
+        Some(&[0xc4, 0xcb]),
+        Some(&[0xf5, 0x5c]),
+        Some(&[0xf5, 0x5a]),
+        None,
+        Some(&[0xf5, 0x5b]),
+        Some(&[0xc4, 0xcd]),
+        Some(&[0xf5, 0x5f]),
+        Some(&[0xc4, 0xcc]),
+        Some(&[0xf5, 0x62]),
+        Some(&[0xf6, 0x78]),
+        Some(&[0xf6, 0x7e]),
+        None,
+        None,
+        Some(&[0xf6, 0x79]),
+        Some(&[0xc5, 0x5b]),
+        Some(&[0xf6, 0xa1]),
+        Some(&[0xc5, 0x5a]),
+        Some(&[0xf6, 0x7d]),
+        Some(&[0xf6, 0x7c]),
+        Some(&[0xc5, 0x59]),
+        Some(&[0xf6, 0x7b]),
+        Some(&[0xc5, 0x58]),
+        Some(&[0xf6, 0x7a]),
+        None,
+        Some(&[0xf7, 0x7d]),
+        Some(&[0xf7, 0xa1]),
+        Some(&[0xf7, 0x7e]),
+        None,
+        Some(&[0xf7, 0x7b]),
+        Some(&[0xc5, 0xbb]),
+        Some(&[0xf7, 0x78]),
+        Some(&[0xf7, 0x7c]),
+        Some(&[0xf7, 0xa3]),
+        None,
+        Some(&[0xf7, 0xa2]),
+        Some(&[0xf7, 0x79]),
+        Some(&[0xf7, 0x7a]),
+        Some(&[0xc5, 0xba]),
+        Some(&[0xf8, 0x52]),
+        Some(&[0xc5, 0xe7]),
+        None,
+        Some(&[0xf8, 0x53]),
+        Some(&[0xc5, 0xe5]),
+        Some(&[0xc5, 0xe6]),
+        None,
+        None,
+        Some(&[0xf8, 0xd3]),
+        Some(&[0xc6, 0x4a]),
+        Some(&[0xf9, 0x76]),
+        None,
+        Some(&[0xc6, 0x6a]),
+        None,
+        Some(&[0xf9, 0xb3]),
+        Some(&[0xc6, 0x6b]),
+        Some(&[0xf9, 0xb4]),
+        Some(&[0xf9, 0xb5]),
+        Some(&[0xf9, 0xc3]),
+        Some(&[0xf9, 0xc2]),
+        Some(&[0xc6, 0x7a]),
+        Some(&[0xf9, 0xcd]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb0, 0xa9]),
+        None,
+        None,
+        Some(&[0xe0, 0xe9]),
+        None,
+        Some(&[0xe0, 0xe8]),
+        None,
+        Some(&[0xbb, 0xea]),
+        Some(&[0xbb, 0xeb]),
+        Some(&[0xe4, 0xda]),
+        None,
+        Some(&[0xe8, 0xd2]),
+        Some(&[0xec, 0x6c]),
+        None,
+        None,
+        Some(&[0xbe, 0x75]),
+        Some(&[0xc0, 0x65]),
+        Some(&[0xec, 0x6a]),
+        None,
+        Some(&[0xec, 0x6d]),
+        Some(&[0xc0, 0x66]),
+        None,
+        Some(&[0xef, 0x64]),
+        Some(&[0xec, 0x6b]),
+        Some(&[0xf1, 0xb9]),
+        Some(&[0xc3, 0x4e]),
+        Some(&[0xf3, 0xc1]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0x66]),
+        Some(&[0xf5, 0x64]),
+        None,
+        None,
+        Some(&[0xf5, 0x65]),
+        None,
+        None,
+        Some(&[0xf6, 0xa2]),
+        None,
+        Some(&[0xc5, 0x5c]),
+        Some(&[0xf7, 0xa4]),
+        Some(&[0xc5, 0xea]),
+        Some(&[0xc5, 0xbc]),
+        Some(&[0xc5, 0xe8]),
+        Some(&[0xc5, 0xe9]),
+        Some(&[0xf8, 0xd4]),
+        Some(&[0xc6, 0x62]),
+        None,
+        Some(&[0xb0, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xba]),
+        None,
+        None,
+        Some(&[0xd4, 0x49]),
+        None,
+        Some(&[0xb9, 0xa6]),
+        None,
+        Some(&[0xe4, 0xdb]),
+        None,
+        None,
+        Some(&[0xbb, 0xec]),
+        Some(&[0xe4, 0xdc]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xd4]),
+        Some(&[0xe8, 0xd3]),
+        Some(&[0xc0, 0x68]),
+        Some(&[0xbe, 0x76]),
+        Some(&[0xbe, 0x77]),
+        None,
+        Some(&[0xe8, 0xd7]),
+        Some(&[0xe8, 0xd6]),
+        Some(&[0xe8, 0xd5]),
+        None,
+        None,
+        Some(&[0xec, 0x6e]),
+        Some(&[0xec, 0x71]),
+        None,
+        Some(&[0xec, 0x70]),
+        Some(&[0xec, 0x6f]),
+        Some(&[0xc0, 0x67]),
+        Some(&[0xef, 0x68]),
+        Some(&[0xef, 0x66]),
+        Some(&[0xef, 0x65]),
+        None,
+        None,
+        Some(&[0xef, 0x67]),
+        None,
+        Some(&[0xc3, 0x4f]),
+        Some(&[0xf1, 0xbc]),
+        Some(&[0xf1, 0xbd]),
+        Some(&[0xc3, 0x50]),
+        None,
+        Some(&[0xf1, 0xbb]),
+        None,
+        Some(&[0xf3, 0xc3]),
+        Some(&[0xf3, 0xc2]),
+        Some(&[0xf3, 0xc5]),
+        Some(&[0xc4, 0x47]),
+        Some(&[0xf3, 0xc4]),
+        None,
+        Some(&[0xf5, 0x67]),
+        Some(&[0xf5, 0x69]),
+        Some(&[0xf5, 0x68]),
+        None,
+        None,
+        Some(&[0xf6, 0xa3]),
+        Some(&[0xf6, 0xa6]),
+        Some(&[0xf6, 0xa4]),
+        Some(&[0xf6, 0xa5]),
+        Some(&[0xf7, 0xa5]),
+        Some(&[0xc5, 0xbd]),
+        None,
+        None,
+        None,
+        Some(&[0xf8, 0x54]),
+        Some(&[0xf8, 0x55]),
+        Some(&[0xf8, 0x56]),
+        None,
+        Some(&[0xc6, 0x4b]),
+        Some(&[0xc6, 0x63]),
+        Some(&[0xf9, 0xb6]),
+        Some(&[0xb0, 0xab]),
+        None,
+        Some(&[0xbe, 0x78]),
+        Some(&[0xc0, 0x69]),
+        Some(&[0xf1, 0xbe]),
+        None,
+        Some(&[0xf7, 0xa6]),
+        None,
+        None,
+        Some(&[0xf9, 0xc4]),
+        Some(&[0xd4, 0x4a]),
+        None,
+        Some(&[0xc6, 0x7b]),
+        Some(&[0xb0, 0xac]),
+        Some(&[0xec, 0x72]),
+        None,
+        Some(&[0xf1, 0xbf]),
+        None,
+        Some(&[0xf3, 0xc6]),
+        None,
+        None,
+        Some(&[0xf6, 0xa7]),
+        Some(&[0xf7, 0xa7]),
+        Some(&[0xb0, 0xad]),
+        None,
+        Some(&[0xe4, 0xdd]),
+        Some(&[0xe4, 0xde]),
+        None,
+        Some(&[0xbb, 0xed]),
+        Some(&[0xbb, 0xee]),
+        Some(&[0xe8, 0xd9]),
+        Some(&[0xbe, 0x7a]),
+        Some(&[0xbe, 0x79]),
+        Some(&[0xe8, 0xd8]),
+        None,
+        Some(&[0xef, 0x69]),
+        None,
+        Some(&[0xf1, 0xc0]),
+        Some(&[0xf1, 0xc2]),
+        Some(&[0xf1, 0xc1]),
+        Some(&[0xc3, 0x53]),
+        Some(&[0xc3, 0x52]),
+        Some(&[0xc3, 0x51]),
+        None,
+        Some(&[0xc5, 0x5e]),
+        Some(&[0xf6, 0xa8]),
+        None,
+        Some(&[0xc5, 0x5d]),
+        Some(&[0xf7, 0xa9]),
+        Some(&[0xf7, 0xa8]),
+        None,
+        Some(&[0xc6, 0x4c]),
+        Some(&[0xf8, 0xd5]),
+        Some(&[0xb3, 0xbd]),
+        Some(&[0xe0, 0xea]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xe1]),
+        Some(&[0xe4, 0xdf]),
+        Some(&[0xe4, 0xe0]),
+        None,
+        None,
+        Some(&[0xe8, 0xe2]),
+        None,
+        Some(&[0xe8, 0xdd]),
+        Some(&[0xe8, 0xda]),
+        Some(&[0xe8, 0xe1]),
+        None,
+        None,
+        None,
+        Some(&[0xe8, 0xe3]),
+        None,
+        None,
+        Some(&[0xbe, 0x7c]),
+        Some(&[0xe8, 0xe0]),
+        Some(&[0xe8, 0xdc]),
+        None,
+        None,
+        Some(&[0xe8, 0xdb]),
+        Some(&[0xe8, 0xdf]),
+        Some(&[0xe8, 0xde]),
+        Some(&[0xbe, 0x7b]),
+        None,
+        None,
+        Some(&[0xec, 0x7d]),
+        Some(&[0xec, 0x78]),
+        Some(&[0xec, 0x76]),
+        Some(&[0xec, 0xa1]),
+        Some(&[0xec, 0x77]),
+        None,
+        Some(&[0xec, 0x73]),
+        None,
+        Some(&[0xec, 0x79]),
+        None,
+        None,
+        Some(&[0xec, 0x74]),
+        Some(&[0xef, 0x72]),
+        Some(&[0xec, 0x75]),
+        Some(&[0xec, 0xa2]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0x7c]),
+        Some(&[0xc0, 0x6a]),
+        Some(&[0xec, 0x7b]),
+        Some(&[0xec, 0x7a]),
+        None,
+        Some(&[0xec, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0x6a]),
+        Some(&[0xef, 0x6d]),
+        None,
+        None,
+        Some(&[0xef, 0x6c]),
+        None,
+        Some(&[0xef, 0x74]),
+        Some(&[0xef, 0x6f]),
+        Some(&[0xef, 0x73]),
+        None,
+        Some(&[0xef, 0x71]),
+        Some(&[0xef, 0x70]),
+        Some(&[0xef, 0x6e]),
+        None,
+        Some(&[0xef, 0x6b]),
+        None,
+        Some(&[0xc2, 0x43]),
+        Some(&[0xc2, 0x42]),
+        None,
+        Some(&[0xc2, 0x44]),
+        Some(&[0xc2, 0x41]),
+        Some(&[0xef, 0x75]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xc8]),
+        Some(&[0xf1, 0xcb]),
+        None,
+        Some(&[0xf1, 0xc9]),
+        Some(&[0xf1, 0xcd]),
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xce]),
+        None,
+        Some(&[0xf1, 0xc6]),
+        Some(&[0xc3, 0x58]),
+        Some(&[0xf1, 0xc7]),
+        None,
+        Some(&[0xf1, 0xc5]),
+        Some(&[0xf1, 0xcc]),
+        None,
+        Some(&[0xf1, 0xc4]),
+        Some(&[0xf1, 0xc3]),
+        Some(&[0xc3, 0x57]),
+        Some(&[0xc3, 0x55]),
+        Some(&[0xc3, 0x54]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xca]),
+        Some(&[0xf3, 0xcf]),
+        Some(&[0xf3, 0xd5]),
+        Some(&[0xc4, 0x4a]),
+        Some(&[0xf3, 0xd0]),
+        None,
+        Some(&[0xf3, 0xd3]),
+        Some(&[0xf3, 0xd7]),
+        Some(&[0xc4, 0x4b]),
+        Some(&[0xf3, 0xd2]),
+        None,
+        Some(&[0xf3, 0xca]),
+        None,
+        Some(&[0xf3, 0xc9]),
+        Some(&[0xf3, 0xd6]),
+        Some(&[0xf3, 0xcd]),
+        None,
+        Some(&[0xf3, 0xcb]),
+        Some(&[0xf3, 0xd4]),
+        Some(&[0xf3, 0xcc]),
+        Some(&[0xc4, 0x49]),
+        Some(&[0xc4, 0x48]),
+        None,
+        Some(&[0xf3, 0xc7]),
+        Some(&[0xf3, 0xc8]),
+        Some(&[0xf3, 0xd1]),
+        None,
+        None,
+        None,
+        Some(&[0xf3, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0x6c]),
+        Some(&[0xf5, 0x6f]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc3, 0x56]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0x6d]),
+        Some(&[0xf5, 0x73]),
+        Some(&[0xf5, 0x71]),
+        Some(&[0xf5, 0x6b]),
+        Some(&[0xf5, 0x76]),
+        None,
+        Some(&[0xf5, 0x6a]),
+        None,
+        Some(&[0xc4, 0xcf]),
+        Some(&[0xf5, 0x72]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0x6e]),
+        Some(&[0xc4, 0xce]),
+        Some(&[0xf5, 0x75]),
+        None,
+        None,
+        Some(&[0xf5, 0x74]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xab]),
+        Some(&[0xf6, 0xaa]),
+        None,
+        None,
+        None,
+        Some(&[0xf6, 0xb1]),
+        None,
+        Some(&[0xf6, 0xad]),
+        Some(&[0xf6, 0xb0]),
+        Some(&[0xc5, 0x60]),
+        None,
+        None,
+        Some(&[0xf6, 0xae]),
+        Some(&[0xf6, 0xaf]),
+        None,
+        Some(&[0xf6, 0xa9]),
+        Some(&[0xf6, 0xac]),
+        Some(&[0xc5, 0x5f]),
+        None,
+        None,
+        None,
+        Some(&[0xc5, 0xbf]),
+        Some(&[0xf7, 0xb4]),
+        Some(&[0xf7, 0xaf]),
+        Some(&[0xf7, 0xb3]),
+        None,
+        Some(&[0xf7, 0xb6]),
+        Some(&[0xf7, 0xb2]),
+        None,
+        Some(&[0xf7, 0xae]),
+        None,
+        Some(&[0xc5, 0xc1]),
+        Some(&[0xf7, 0xb1]),
+        Some(&[0xf7, 0xb5]),
+        Some(&[0xc5, 0xc0]),
+        Some(&[0xf7, 0xac]),
+        Some(&[0xf5, 0x70]),
+        Some(&[0xf7, 0xb0]),
+        None,
+        None,
+        Some(&[0xf7, 0xad]),
+        None,
+        Some(&[0xf7, 0xaa]),
+        None,
+        Some(&[0xf7, 0xab]),
+        Some(&[0xc5, 0xbe]),
+        Some(&[0xf8, 0x5a]),
+        Some(&[0xf8, 0x5c]),
+        Some(&[0xf8, 0x5f]),
+        Some(&[0xf8, 0x5b]),
+        Some(&[0xf8, 0x60]),
+        None,
+        Some(&[0xf8, 0x59]),
+        None,
+        Some(&[0xf8, 0x57]),
+        None,
+        Some(&[0xc5, 0xeb]),
+        Some(&[0xf8, 0x5d]),
+        Some(&[0xc5, 0xed]),
+        Some(&[0xc5, 0xec]),
+        Some(&[0xf8, 0x58]),
+        Some(&[0xf8, 0x5e]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf8, 0xda]),
+        Some(&[0xc6, 0x4d]),
+        Some(&[0xf8, 0xdb]),
+        None,
+        Some(&[0xf8, 0xd9]),
+        Some(&[0xf8, 0xd6]),
+        None,
+        None,
+        Some(&[0xf8, 0xd8]),
+        Some(&[0xf8, 0xd7]),
+        Some(&[0xf9, 0x5a]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf9, 0x5c]),
+        Some(&[0xf9, 0x5b]),
+        None,
+        None,
+        Some(&[0xf9, 0x79]),
+        None,
+        Some(&[0xf9, 0x78]),
+        Some(&[0xf9, 0x77]),
+        Some(&[0xf9, 0x7a]),
+        None,
+        Some(&[0xc6, 0x73]),
+        Some(&[0xc6, 0x74]),
+        Some(&[0xf9, 0xca]),
+        Some(&[0xf9, 0xce]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0X9CE0: [Option<&'static [u8]>; 320] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xbe]),
+        Some(&[0xdc, 0xaf]),
+        Some(&[0xe0, 0xed]),
+        None,
+        Some(&[0xb9, 0xa7]),
+        Some(&[0xe0, 0xeb]),
+        None,
+        None,
+        Some(&[0xe0, 0xec]),
+        None,
+        None,
+        None,
+        Some(&[0xe4, 0xe2]),
+        Some(&[0xe4, 0xe3]),
+        Some(&[0xbb, 0xf1]),
+        Some(&[0xbb, 0xef]),
+        Some(&[0xe4, 0xe4]),
+        Some(&[0xbb, 0xf0]),
+        Some(&[0xe8, 0xe8]),
+        None,
+        Some(&[0xe8, 0xeb]),
+        Some(&[0xe8, 0xe5]),
+        Some(&[0xe8, 0xec]),
+        Some(&[0xe8, 0xe4]),
+        Some(&[0xe8, 0xe6]),
+        None,
+        Some(&[0xe8, 0xe7]),
+        Some(&[0xe8, 0xea]),
+        None,
+        None,
+        Some(&[0xbe, 0xa1]),
+        Some(&[0xe8, 0xef]),
+        Some(&[0xe8, 0xee]),
+        Some(&[0xbe, 0x7d]),
+        Some(&[0xe8, 0xe9]),
+        Some(&[0xe8, 0xed]),
+        Some(&[0xbe, 0x7e]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xac]),
+        None,
+        Some(&[0xc0, 0x6f]),
+        None,
+        Some(&[0xec, 0xa7]),
+        Some(&[0xc0, 0x6b]),
+        None,
+        Some(&[0xec, 0xa4]),
+        Some(&[0xec, 0xaa]),
+        Some(&[0xec, 0xad]),
+        None,
+        Some(&[0xc0, 0x70]),
+        None,
+        Some(&[0xec, 0xa9]),
+        Some(&[0xec, 0xa6]),
+        Some(&[0xec, 0xae]),
+        Some(&[0xec, 0xa5]),
+        None,
+        Some(&[0xec, 0xab]),
+        Some(&[0xc0, 0x6c]),
+        None,
+        Some(&[0xec, 0xa3]),
+        Some(&[0xc0, 0x6d]),
+        None,
+        Some(&[0xc0, 0x6e]),
+        Some(&[0xec, 0xa8]),
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xa9]),
+        Some(&[0xef, 0x7a]),
+        Some(&[0xef, 0x7b]),
+        Some(&[0xef, 0x7e]),
+        Some(&[0xef, 0x7c]),
+        None,
+        Some(&[0xef, 0x76]),
+        None,
+        None,
+        Some(&[0xef, 0x79]),
+        Some(&[0xef, 0xa5]),
+        Some(&[0xef, 0x7d]),
+        None,
+        None,
+        Some(&[0xc2, 0x45]),
+        None,
+        Some(&[0xef, 0xa7]),
+        Some(&[0xef, 0xa4]),
+        Some(&[0xc2, 0x46]),
+        Some(&[0xef, 0xa6]),
+        Some(&[0xef, 0x77]),
+        Some(&[0xef, 0xa2]),
+        Some(&[0xef, 0xa3]),
+        None,
+        Some(&[0xef, 0xa1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf1, 0xd2]),
+        Some(&[0xf1, 0xd4]),
+        Some(&[0xf1, 0xd7]),
+        None,
+        None,
+        Some(&[0xf1, 0xd1]),
+        None,
+        Some(&[0xc3, 0x59]),
+        Some(&[0xf1, 0xd9]),
+        Some(&[0xf1, 0xd0]),
+        Some(&[0xf1, 0xda]),
+        None,
+        Some(&[0xf1, 0xd6]),
+        Some(&[0xf1, 0xd8]),
+        Some(&[0xf1, 0xdc]),
+        Some(&[0xf1, 0xd5]),
+        Some(&[0xf1, 0xdd]),
+        Some(&[0xf1, 0xd3]),
+        Some(&[0xf1, 0xcf]),
+        Some(&[0xc3, 0x5a]),
+        None,
+        Some(&[0xf1, 0xdb]),
+        Some(&[0xc3, 0x5b]),
+        Some(&[0xc4, 0x4d]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0x78]),
+        Some(&[0xf3, 0xf1]),
+        Some(&[0xf3, 0xe8]),
+        Some(&[0xc4, 0x4f]),
+        Some(&[0xf3, 0xe4]),
+        Some(&[0xc4, 0x50]),
+        None,
+        None,
+        Some(&[0xf3, 0xed]),
+        Some(&[0xf3, 0xe7]),
+        Some(&[0xf3, 0xdd]),
+        Some(&[0xc4, 0x4e]),
+        Some(&[0xf3, 0xea]),
+        Some(&[0xf3, 0xe5]),
+        Some(&[0xf3, 0xe6]),
+        None,
+        Some(&[0xf3, 0xd8]),
+        Some(&[0xf3, 0xdf]),
+        Some(&[0xf3, 0xee]),
+        None,
+        Some(&[0xf3, 0xeb]),
+        None,
+        Some(&[0xf3, 0xe3]),
+        None,
+        Some(&[0xf3, 0xef]),
+        Some(&[0xf3, 0xde]),
+        Some(&[0xf3, 0xd9]),
+        Some(&[0xf3, 0xec]),
+        None,
+        Some(&[0xf3, 0xdb]),
+        Some(&[0xf3, 0xe9]),
+        Some(&[0xf3, 0xe0]),
+        Some(&[0xf3, 0xf0]),
+        Some(&[0xf3, 0xdc]),
+        Some(&[0xc4, 0x4c]),
+        Some(&[0xf3, 0xda]),
+        Some(&[0xf3, 0xe1]),
+        Some(&[0xf3, 0xe2]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0x7d]),
+        None,
+        Some(&[0xf5, 0x7b]),
+        None,
+        Some(&[0xf5, 0xa2]),
+        None,
+        Some(&[0xf5, 0xae]),
+        Some(&[0xf5, 0xa5]),
+        Some(&[0xf5, 0x7c]),
+        Some(&[0xf5, 0x78]),
+        Some(&[0xf5, 0xa7]),
+        Some(&[0xf5, 0x7e]),
+        Some(&[0xf5, 0xa3]),
+        Some(&[0xf5, 0x7a]),
+        Some(&[0xf5, 0xaa]),
+        Some(&[0xf5, 0x77]),
+        Some(&[0xf5, 0xa1]),
+        Some(&[0xf5, 0xa6]),
+        Some(&[0xf5, 0xa8]),
+        Some(&[0xf5, 0xab]),
+        Some(&[0xf5, 0x79]),
+        None,
+        Some(&[0xf5, 0xaf]),
+        Some(&[0xf5, 0xb0]),
+        Some(&[0xf5, 0xa9]),
+        Some(&[0xf5, 0xad]),
+        Some(&[0xf5, 0xa4]),
+        None,
+        Some(&[0xf6, 0xc1]),
+        Some(&[0xf6, 0xc4]),
+        None,
+        Some(&[0xc5, 0x61]),
+        None,
+        Some(&[0xf6, 0xc3]),
+        Some(&[0xf6, 0xc8]),
+        Some(&[0xf6, 0xc6]),
+        Some(&[0xc5, 0x62]),
+        Some(&[0xf6, 0xbd]),
+        Some(&[0xf6, 0xb3]),
+        Some(&[0xf6, 0xb2]),
+        Some(&[0xc5, 0x64]),
+        Some(&[0xf6, 0xbf]),
+        Some(&[0xf6, 0xc0]),
+        Some(&[0xf6, 0xbc]),
+        Some(&[0xf6, 0xb4]),
+        None,
+        Some(&[0xf6, 0xb9]),
+        Some(&[0xf5, 0xac]),
+        None,
+        Some(&[0xf6, 0xb5]),
+        Some(&[0xc5, 0x63]),
+        Some(&[0xf6, 0xbb]),
+        None,
+        Some(&[0xf6, 0xba]),
+        None,
+        Some(&[0xf6, 0xb6]),
+        Some(&[0xf6, 0xc2]),
+        None,
+        Some(&[0xf6, 0xb7]),
+        Some(&[0xf7, 0xbb]),
+        Some(&[0xf6, 0xc5]),
+        Some(&[0xf6, 0xc7]),
+        Some(&[0xf6, 0xbe]),
+        Some(&[0xf6, 0xb8]),
+        Some(&[0xf7, 0xbc]),
+        Some(&[0xf7, 0xbe]),
+        Some(&[0xf7, 0xb8]),
+        Some(&[0xc5, 0xc2]),
+        None,
+        Some(&[0xf7, 0xc5]),
+        Some(&[0xf7, 0xc3]),
+        Some(&[0xc5, 0xc3]),
+        Some(&[0xf7, 0xc2]),
+        Some(&[0xf7, 0xc1]),
+        Some(&[0xf7, 0xba]),
+        Some(&[0xf7, 0xb7]),
+        Some(&[0xf7, 0xbd]),
+        Some(&[0xf7, 0xc6]),
+        Some(&[0xf7, 0xb9]),
+        Some(&[0xf7, 0xbf]),
+        None,
+        Some(&[0xf8, 0x69]),
+        Some(&[0xf8, 0x6e]),
+        Some(&[0xf8, 0x64]),
+        Some(&[0xf8, 0x67]),
+        Some(&[0xc5, 0xee]),
+        Some(&[0xf8, 0x6b]),
+        None,
+        Some(&[0xf8, 0x72]),
+        Some(&[0xf7, 0xc0]),
+        None,
+        Some(&[0xf8, 0x65]),
+        Some(&[0xf8, 0x6f]),
+        Some(&[0xf8, 0x73]),
+        Some(&[0xf8, 0x6a]),
+        Some(&[0xf8, 0x63]),
+        Some(&[0xf8, 0x6d]),
+        None,
+        Some(&[0xf8, 0x6c]),
+        Some(&[0xf8, 0x71]),
+        Some(&[0xf8, 0x70]),
+        Some(&[0xf7, 0xc4]),
+        Some(&[0xf8, 0x68]),
+        Some(&[0xf8, 0x62]),
+        Some(&[0xf8, 0x66]),
+        Some(&[0xc6, 0x4e]),
+        Some(&[0xc6, 0x4f]),
+        Some(&[0xf8, 0x61]),
+        None,
+        Some(&[0xf8, 0xe6]),
+        Some(&[0xf8, 0xdd]),
+        Some(&[0xf8, 0xe5]),
+        Some(&[0xf8, 0xe2]),
+        Some(&[0xf8, 0xe3]),
+        Some(&[0xf8, 0xdc]),
+        Some(&[0xf8, 0xdf]),
+        Some(&[0xf8, 0xe7]),
+        Some(&[0xf8, 0xe1]),
+        Some(&[0xf8, 0xe0]),
+        Some(&[0xf8, 0xde]),
+        None,
+        Some(&[0xf8, 0xe4]),
+        None,
+        Some(&[0xf9, 0x5d]),
+        None,
+        Some(&[0xf9, 0x5e]),
+        None,
+        Some(&[0xf9, 0x60]),
+        Some(&[0xf9, 0x5f]),
+        Some(&[0xf9, 0x62]),
+        Some(&[0xf9, 0x61]),
+        Some(&[0xf9, 0x7c]),
+        Some(&[0xf9, 0x7b]),
+        Some(&[0xf9, 0xb7]),
+        None,
+        Some(&[0xf9, 0xb8]),
+        None,
+        Some(&[0xf9, 0xc5]),
+        Some(&[0xc6, 0x78]),
+        Some(&[0xc6, 0x7c]),
+        None,
+        Some(&[0xf9, 0xcf]),
+        Some(&[0xc6, 0x7d]),
+        None,
+    ];
+
+    const BASE_0X9E70: [Option<&'static [u8]>; 312] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xbf]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0xd0]),
+        Some(&[0xf6, 0xc9]),
+        None,
+        Some(&[0xc6, 0x50]),
+        Some(&[0xc6, 0x51]),
+        None,
+        Some(&[0xb3, 0xc0]),
+        Some(&[0xe0, 0xee]),
+        None,
+        Some(&[0xb9, 0xa8]),
+        Some(&[0xe8, 0xf0]),
+        None,
+        None,
+        Some(&[0xec, 0xb0]),
+        Some(&[0xec, 0xb1]),
+        Some(&[0xec, 0xaf]),
+        Some(&[0xef, 0xab]),
+        Some(&[0xef, 0xaa]),
+        Some(&[0xc2, 0x47]),
+        Some(&[0xf1, 0xdf]),
+        Some(&[0xef, 0xac]),
+        Some(&[0xf1, 0xde]),
+        None,
+        None,
+        Some(&[0xf3, 0xf3]),
+        Some(&[0xc4, 0x51]),
+        Some(&[0xc4, 0x53]),
+        Some(&[0xf3, 0xf2]),
+        None,
+        None,
+        Some(&[0xc4, 0x52]),
+        None,
+        Some(&[0xf5, 0xb1]),
+        Some(&[0xf5, 0xb3]),
+        Some(&[0xf5, 0xb2]),
+        Some(&[0xf6, 0xca]),
+        Some(&[0xc5, 0x65]),
+        None,
+        Some(&[0xc5, 0xef]),
+        Some(&[0xf8, 0xe8]),
+        Some(&[0xf9, 0x63]),
+        None,
+        None,
+        Some(&[0xf9, 0xd2]),
+        Some(&[0xb3, 0xc1]),
+        None,
+        Some(&[0xe4, 0xe5]),
+        None,
+        Some(&[0xbe, 0xa2]),
+        None,
+        None,
+        None,
+        Some(&[0xec, 0xb3]),
+        Some(&[0xec, 0xb2]),
+        None,
+        Some(&[0xef, 0xad]),
+        None,
+        None,
+        None,
+        Some(&[0xc4, 0x54]),
+        Some(&[0xc4, 0xd1]),
+        Some(&[0xf7, 0xc7]),
+        Some(&[0xf9, 0xcb]),
+        None,
+        None,
+        None,
+        Some(&[0xb3, 0xc2]),
+        Some(&[0xbb, 0xf2]),
+        None,
+        Some(&[0xbe, 0xa3]),
+        None,
+        Some(&[0xf3, 0xf4]),
+        None,
+        Some(&[0xf8, 0x74]),
+        Some(&[0xb6, 0xc0]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xae]),
+        None,
+        None,
+        None,
+        Some(&[0xc6, 0x64]),
+        Some(&[0xb6, 0xc1]),
+        Some(&[0xbe, 0xa4]),
+        Some(&[0xc2, 0x48]),
+        Some(&[0xf8, 0x75]),
+        Some(&[0xb6, 0xc2]),
+        None,
+        Some(&[0xe8, 0xf1]),
+        Some(&[0xc0, 0x72]),
+        Some(&[0xec, 0xb4]),
+        Some(&[0xec, 0xb5]),
+        None,
+        Some(&[0xc0, 0x71]),
+        None,
+        Some(&[0xef, 0xaf]),
+        Some(&[0xc2, 0x4c]),
+        Some(&[0xc2, 0x4a]),
+        Some(&[0xc2, 0x4b]),
+        Some(&[0xc2, 0x49]),
+        Some(&[0xf1, 0xe0]),
+        Some(&[0xc3, 0x5c]),
+        None,
+        None,
+        None,
+        Some(&[0xf5, 0xb5]),
+        Some(&[0xf5, 0xb4]),
+        Some(&[0xf5, 0xb7]),
+        Some(&[0xf5, 0xb6]),
+        Some(&[0xc4, 0xd2]),
+        None,
+        None,
+        Some(&[0xf6, 0xcb]),
+        None,
+        Some(&[0xf6, 0xcd]),
+        Some(&[0xf6, 0xcc]),
+        Some(&[0xc5, 0x66]),
+        Some(&[0xf7, 0xc8]),
+        None,
+        Some(&[0xf8, 0x76]),
+        Some(&[0xf8, 0x77]),
+        Some(&[0xc5, 0xf0]),
+        Some(&[0xf9, 0x64]),
+        Some(&[0xf9, 0x7d]),
+        Some(&[0xc6, 0x75]),
+        None,
+        Some(&[0xdc, 0xb0]),
+        Some(&[0xec, 0xb6]),
+        Some(&[0xef, 0xb0]),
+        Some(&[0xf3, 0xf5]),
+        Some(&[0xe0, 0xef]),
+        None,
+        Some(&[0xef, 0xb1]),
+        Some(&[0xf1, 0xe2]),
+        Some(&[0xf1, 0xe1]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xf8, 0x78]),
+        Some(&[0xc6, 0x52]),
+        None,
+        Some(&[0xf9, 0x65]),
+        Some(&[0xf9, 0x7e]),
+        None,
+        None,
+        None,
+        Some(&[0xb9, 0xa9]),
+        Some(&[0xe8, 0xf2]),
+        Some(&[0xe8, 0xf3]),
+        None,
+        Some(&[0xec, 0xb7]),
+        Some(&[0xb9, 0xaa]),
+        None,
+        Some(&[0xc3, 0x5d]),
+        Some(&[0xf1, 0xe3]),
+        None,
+        Some(&[0xf6, 0xcf]),
+        Some(&[0xc5, 0x67]),
+        Some(&[0xf6, 0xd0]),
+        Some(&[0xf6, 0xce]),
+        Some(&[0xf8, 0x79]),
+        None,
+        Some(&[0xf8, 0xe9]),
+        None,
+        Some(&[0xb9, 0xab]),
+        None,
+        Some(&[0xef, 0xb4]),
+        Some(&[0xef, 0xb3]),
+        Some(&[0xef, 0xb2]),
+        Some(&[0xf1, 0xe4]),
+        None,
+        None,
+        Some(&[0xf1, 0xe8]),
+        Some(&[0xf1, 0xe7]),
+        Some(&[0xf1, 0xe6]),
+        Some(&[0xf1, 0xe5]),
+        Some(&[0xc3, 0x5e]),
+        Some(&[0xf3, 0xf6]),
+        Some(&[0xf5, 0xb9]),
+        Some(&[0xc4, 0xd3]),
+        Some(&[0xf5, 0xb8]),
+        Some(&[0xf6, 0xd1]),
+        Some(&[0xf7, 0xcb]),
+        Some(&[0xf7, 0xca]),
+        Some(&[0xc5, 0xc4]),
+        Some(&[0xf7, 0xc9]),
+        Some(&[0xf8, 0x7c]),
+        Some(&[0xf8, 0x7b]),
+        Some(&[0xf8, 0x7a]),
+        None,
+        None,
+        Some(&[0xbb, 0xf3]),
+        None,
+        Some(&[0xec, 0xb8]),
+        Some(&[0xc2, 0x4d]),
+        None,
+        Some(&[0xf3, 0xf7]),
+        Some(&[0xf3, 0xf8]),
+        Some(&[0xf7, 0xcc]),
+        Some(&[0xf8, 0x7d]),
+        None,
+        None,
+        Some(&[0xf8, 0xea]),
+        Some(&[0xf9, 0x66]),
+        Some(&[0xf9, 0xb9]),
+        Some(&[0xf9, 0xd4]),
+        Some(&[0xbb, 0xf4]),
+        Some(&[0xc2, 0x4e]),
+        Some(&[0xf1, 0xe9]),
+        Some(&[0xf3, 0xf9]),
+        Some(&[0xf6, 0xd2]),
+        Some(&[0xf8, 0x7e]),
+        None,
+        None,
+        Some(&[0xbe, 0xa6]),
+        None,
+        Some(&[0xef, 0xb5]),
+        Some(&[0xf1, 0xea]),
+        Some(&[0xf3, 0xfa]),
+        Some(&[0xf3, 0xfb]),
+        Some(&[0xf3, 0xfc]),
+        Some(&[0xf5, 0xbe]),
+        None,
+        Some(&[0xf5, 0xba]),
+        Some(&[0xc5, 0x68]),
+        Some(&[0xf5, 0xbd]),
+        Some(&[0xf5, 0xbc]),
+        Some(&[0xc4, 0xd4]),
+        Some(&[0xf5, 0xbb]),
+        Some(&[0xc4, 0xd6]),
+        None,
+        Some(&[0xc4, 0xd5]),
+        Some(&[0xf6, 0xd4]),
+        Some(&[0xf6, 0xd3]),
+        Some(&[0xc5, 0x69]),
+        Some(&[0xc5, 0x6a]),
+        None,
+        None,
+        Some(&[0xc5, 0xc6]),
+        Some(&[0xf7, 0xcd]),
+        Some(&[0xc5, 0xc5]),
+        None,
+        Some(&[0xf8, 0xa3]),
+        Some(&[0xf8, 0xa4]),
+        Some(&[0xf8, 0xa2]),
+        Some(&[0xf8, 0xa1]),
+        Some(&[0xc6, 0x54]),
+        None,
+        Some(&[0xf8, 0xeb]),
+        Some(&[0xf8, 0xec]),
+        Some(&[0xf8, 0xed]),
+        Some(&[0xc6, 0x53]),
+        Some(&[0xf9, 0x67]),
+        Some(&[0xf9, 0x6a]),
+        Some(&[0xf9, 0x69]),
+        Some(&[0xf9, 0x68]),
+        None,
+        None,
+        Some(&[0xf9, 0xd3]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0x73]),
+        None,
+        None,
+        Some(&[0xc3, 0x65]),
+        Some(&[0xf5, 0xbf]),
+        Some(&[0xf6, 0xd5]),
+        None,
+        Some(&[0xc5, 0xc7]),
+        Some(&[0xf7, 0xce]),
+        None,
+        None,
+        Some(&[0xf9, 0xd5]),
+        None,
+        None,
+        None,
+        Some(&[0xc0, 0x74]),
+        None,
+        None,
+        None,
+        Some(&[0xef, 0xb6]),
+        None,
+        Some(&[0xf7, 0xcf]),
+        None,
+        Some(&[0xf9, 0xa1]),
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFA08: [Option<&'static [u8]>; 8] = [
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xc9, 0x4a]),
+        Some(&[0xdd, 0xfc]),
+        None,
+        None,
+    ];
+
+    const BASE_0XFE30: [Option<&'static [u8]>; 64] = [
+        Some(&[0xa1, 0x4a]),
+        Some(&[0xa1, 0x57]),
+        None,
+        Some(&[0xa1, 0x59]),
+        Some(&[0xa1, 0x5b]),
+        Some(&[0xa1, 0x5f]),
+        Some(&[0xa1, 0x60]),
+        Some(&[0xa1, 0x63]),
+        Some(&[0xa1, 0x64]),
+        Some(&[0xa1, 0x67]),
+        Some(&[0xa1, 0x68]),
+        Some(&[0xa1, 0x6b]),
+        Some(&[0xa1, 0x6c]),
+        Some(&[0xa1, 0x6f]),
+        Some(&[0xa1, 0x70]),
+        Some(&[0xa1, 0x73]),
+        Some(&[0xa1, 0x74]),
+        Some(&[0xa1, 0x77]),
+        Some(&[0xa1, 0x78]),
+        Some(&[0xa1, 0x7b]),
+        Some(&[0xa1, 0x7c]),
+        None,
+        None,
+        None,
+        None,
+        Some(&[0xa1, 0xc6]),
+        Some(&[0xa1, 0xc7]),
+        Some(&[0xa1, 0xca]),
+        Some(&[0xa1, 0xcb]),
+        Some(&[0xa1, 0xc8]),
+        Some(&[0xa1, 0xc9]),
+        Some(&[0xa1, 0x5c]),
+        Some(&[0xa1, 0x4d]),
+        Some(&[0xa1, 0x4e]),
+        Some(&[0xa1, 0x4f]),
+        None,
+        Some(&[0xa1, 0x51]),
+        Some(&[0xa1, 0x52]),
+        Some(&[0xa1, 0x53]),
+        Some(&[0xa1, 0x54]),
+        None,
+        Some(&[0xa1, 0x7d]),
+        Some(&[0xa1, 0x7e]),
+        Some(&[0xa1, 0xa1]),
+        Some(&[0xa1, 0xa2]),
+        Some(&[0xa1, 0xa3]),
+        Some(&[0xa1, 0xa4]),
+        Some(&[0xa1, 0xcc]),
+        Some(&[0xa1, 0xcd]),
+        Some(&[0xa1, 0xce]),
+        Some(&[0xa1, 0xde]),
+        Some(&[0xa1, 0xdf]),
+        Some(&[0xa1, 0xe0]),
+        Some(&[0xa1, 0xe1]),
+        Some(&[0xa1, 0xe2]),
+        None,
+        Some(&[0xa2, 0x42]),
+        Some(&[0xa2, 0x4c]),
+        Some(&[0xa2, 0x4d]),
+        Some(&[0xa2, 0x4e]),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    const BASE_0XFF00: [Option<&'static [u8]>; 96] = [
+        None,
+        Some(&[0xa1, 0x49]),
+        None,
+        Some(&[0xa1, 0xad]),
+        Some(&[0xa2, 0x43]),
+        Some(&[0xa2, 0x48]),
+        Some(&[0xa1, 0xae]),
+        None,
+        Some(&[0xa1, 0x5d]),
+        Some(&[0xa1, 0x5e]),
+        Some(&[0xa1, 0xaf]),
+        Some(&[0xa1, 0xcf]),
+        Some(&[0xa1, 0x41]),
+        Some(&[0xa1, 0xd0]),
+        Some(&[0xa1, 0x44]),
+        Some(&[0xa1, 0xfe]),
+        Some(&[0xa2, 0xaf]),
+        Some(&[0xa2, 0xb0]),
+        Some(&[0xa2, 0xb1]),
+        Some(&[0xa2, 0xb2]),
+        Some(&[0xa2, 0xb3]),
+        Some(&[0xa2, 0xb4]),
+        Some(&[0xa2, 0xb5]),
+        Some(&[0xa2, 0xb6]),
+        Some(&[0xa2, 0xb7]),
+        Some(&[0xa2, 0xb8]),
+        Some(&[0xa1, 0x47]),
+        Some(&[0xa1, 0x46]),
+        Some(&[0xa1, 0xd5]),
+        Some(&[0xa1, 0xd7]),
+        Some(&[0xa1, 0xd6]),
+        Some(&[0xa1, 0x48]),
+        Some(&[0xa2, 0x49]),
+        Some(&[0xa2, 0xcf]),
+        Some(&[0xa2, 0xd0]),
+        Some(&[0xa2, 0xd1]),
+        Some(&[0xa2, 0xd2]),
+        Some(&[0xa2, 0xd3]),
+        Some(&[0xa2, 0xd4]),
+        Some(&[0xa2, 0xd5]),
+        Some(&[0xa2, 0xd6]),
+        Some(&[0xa2, 0xd7]),
+        Some(&[0xa2, 0xd8]),
+        Some(&[0xa2, 0xd9]),
+        Some(&[0xa2, 0xda]),
+        Some(&[0xa2, 0xdb]),
+        Some(&[0xa2, 0xdc]),
+        Some(&[0xa2, 0xdd]),
+        Some(&[0xa2, 0xde]),
+        Some(&[0xa2, 0xdf]),
+        Some(&[0xa2, 0xe0]),
+        Some(&[0xa2, 0xe1]),
+        Some(&[0xa2, 0xe2]),
+        Some(&[0xa2, 0xe3]),
+        Some(&[0xa2, 0xe4]),
+        Some(&[0xa2, 0xe5]),
+        Some(&[0xa2, 0xe6]),
+        Some(&[0xa2, 0xe7]),
+        Some(&[0xa2, 0xe8]),
+        None,
+        Some(&[0xa2, 0x40]),
+        None,
+        None,
+        Some(&[0xa1, 0xc4]),
+        None,
+        Some(&[0xa2, 0xe9]),
+        Some(&[0xa2, 0xea]),
+        Some(&[0xa2, 0xeb]),
+        Some(&[0xa2, 0xec]),
+        Some(&[0xa2, 0xed]),
+        Some(&[0xa2, 0xee]),
+        Some(&[0xa2, 0xef]),
+        Some(&[0xa2, 0xf0]),
+        Some(&[0xa2, 0xf1]),
+        Some(&[0xa2, 0xf2]),
+        Some(&[0xa2, 0xf3]),
+        Some(&[0xa2, 0xf4]),
+        Some(&[0xa2, 0xf5]),
+        Some(&[0xa2, 0xf6]),
+        Some(&[0xa2, 0xf7]),
+        Some(&[0xa2, 0xf8]),
+        Some(&[0xa2, 0xf9]),
+        Some(&[0xa2, 0xfa]),
+        Some(&[0xa2, 0xfb]),
+        Some(&[0xa2, 0xfc]),
+        Some(&[0xa2, 0xfd]),
+        Some(&[0xa2, 0xfe]),
+        Some(&[0xa3, 0x40]),
+        Some(&[0xa3, 0x41]),
+        Some(&[0xa3, 0x42]),
+        Some(&[0xa3, 0x43]),
+        Some(&[0xa1, 0x61]),
+        Some(&[0xa1, 0x55]),
+        Some(&[0xa1, 0x62]),
+        Some(&[0xa1, 0xe3]),
+        None,
+    ];
+
+    const BASE_0XFFE0: [Option<&'static [u8]>; 8] = [
+        Some(&[0xa2, 0x46]),
+        Some(&[0xa2, 0x47]),
+        None,
+        Some(&[0xa1, 0xc3]),
+        None,
+        Some(&[0xa2, 0x44]),
+        None,
+        None,
+    ];
+
+    /// Creates a new encoder.
+    pub fn new(code_points: &'a [u32]) -> Self {
+        Self {
+            code_points: code_points,
+            code_point_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for EncoderWindows950<'a> {
+    type Item = Result<Vec<u8>, ErrorTrace>;
+
+    /// Retrieves the next encoded byte sequence.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.code_points.get(self.code_point_index) {
+            Some(code_point) => {
+                self.code_point_index += 1;
+
+                match *code_point {
+                    0x0000..0x0080 => Some(Ok(vec![*code_point as u8])),
+                    0x00a0..0x00f8 => {
+                        match Self::BASE_0X00A0[(*code_point as u32 - 0x00a0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x02c0..0x02e0 => {
+                        match Self::BASE_0X02C0[(*code_point as u32 - 0x02c0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x0390..0x0458 => {
+                        match Self::BASE_0X0390[(*code_point as u32 - 0x0390) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2010..0x2040 => {
+                        match Self::BASE_0X2010[(*code_point as u32 - 0x2010) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2100..0x2110 => {
+                        match Self::BASE_0X2100[(*code_point as u32 - 0x2100) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2160..0x21a0 => {
+                        match Self::BASE_0X2160[(*code_point as u32 - 0x2160) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2210..0x22c0 => {
+                        match Self::BASE_0X2210[(*code_point as u32 - 0x2210) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2460..0x2480 => {
+                        match Self::BASE_0X2460[(*code_point as u32 - 0x2460) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x2500..0x2648 => {
+                        match Self::BASE_0X2500[(*code_point as u32 - 0x2500) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3000..0x3130 => {
+                        match Self::BASE_0X3000[(*code_point as u32 - 0x3000) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x3388..0x33d8 => {
+                        match Self::BASE_0X3388[(*code_point as u32 - 0x3388) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x4e00..0x7ea0 => {
+                        match Self::BASE_0X4E00[(*code_point as u32 - 0x4e00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x7f30..0x8ba0 => {
+                        match Self::BASE_0X7F30[(*code_point as u32 - 0x7f30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8c30..0x8d20 => {
+                        match Self::BASE_0X8C30[(*code_point as u32 - 0x8c30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x8d60..0x9488 => {
+                        match Self::BASE_0X8D60[(*code_point as u32 - 0x8d60) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9570..0x9c80 => {
+                        match Self::BASE_0X9570[(*code_point as u32 - 0x9570) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9ce0..0x9e20 => {
+                        match Self::BASE_0X9CE0[(*code_point as u32 - 0x9ce0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x9e70..0x9fa8 => {
+                        match Self::BASE_0X9E70[(*code_point as u32 - 0x9e70) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xfa08..0xfa10 => {
+                        match Self::BASE_0XFA08[(*code_point as u32 - 0xfa08) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xfe30..0xfe70 => {
+                        match Self::BASE_0XFE30[(*code_point as u32 - 0xfe30) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xff00..0xff60 => {
+                        match Self::BASE_0XFF00[(*code_point as u32 - 0xff00) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0xffe0..0xffe8 => {
+                        match Self::BASE_0XFFE0[(*code_point as u32 - 0xffe0) as usize] {
+                            Some(bytes) => Some(Ok(bytes.to_vec())),
+                            None => {
+                                return Some(Err(keramics_core::error_trace_new!(format!(
+                                    "Unable to encode code point: U+{:04x} as Windows 950",
+                                    *code_point as u32
+                                ))));
+                            }
+                        }
+                    }
+                    0x20ac => Some(Ok(vec![0xa3, 0xe1])),
+                    0x32a3 => Some(Ok(vec![0xa1, 0xc0])),
+                    _ => {
+                        return Some(Err(keramics_core::error_trace_new!(format!(
+                            "Unable to encode code point: U+{:04x} as Windows 950",
+                            *code_point as u32
+                        ))));
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), ErrorTrace> {
+        let byte_string: [u8; 8] = [b'K', b'e', b'r', b'a', b'm', b'i', b'c', b's'];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        assert_eq!(decoder.next(), Some(Ok(0x4b)));
+        assert_eq!(decoder.next(), Some(Ok(0x65)));
+        assert_eq!(decoder.next(), Some(Ok(0x72)));
+        assert_eq!(decoder.next(), Some(Ok(0x61)));
+        assert_eq!(decoder.next(), Some(Ok(0x6d)));
+        assert_eq!(decoder.next(), Some(Ok(0x69)));
+        assert_eq!(decoder.next(), Some(Ok(0x63)));
+        assert_eq!(decoder.next(), Some(Ok(0x73)));
+        assert_eq!(decoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_unsupported_bytes() {
+        let byte_string: [u8; 2] = [0xa1, 0x3f];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xa2, 0x3f];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xa3, 0x3f];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 2] = [0xa4, 0x3f];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+
+        let byte_string: [u8; 1] = [0x81];
+
+        let mut decoder: DecoderWindows950 = DecoderWindows950::new(&byte_string);
+
+        let result: Result<u32, ErrorTrace> = decoder.next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encode() -> Result<(), ErrorTrace> {
+        let code_points: [u32; 8] = [0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73];
+
+        let mut encoder: EncoderWindows950 = EncoderWindows950::new(&code_points);
+
+        assert_eq!(encoder.next(), Some(Ok(vec![b'K'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'e'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'r'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'a'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'm'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'i'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b'c'])));
+        assert_eq!(encoder.next(), Some(Ok(vec![b's'])));
+        assert_eq!(encoder.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_unsupported_code_point() {
+        let code_points: [u32; 1] = [0xd800];
+
+        let mut encoder: EncoderWindows950 = EncoderWindows950::new(&code_points);
+
+        let result: Result<Vec<u8>, ErrorTrace> = encoder.next().unwrap();
+        assert!(result.is_err());
+    }
+}
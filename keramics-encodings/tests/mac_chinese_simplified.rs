@@ -0,0 +1,7680 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encodings::{DecoderMacChineseSimplified, EncoderMacChineseSimplified};
+
+const MAC_CHINESE_SIMPLIFIED_TEST_VECTOR: [(u32, &'static [u8], bool); 7615] = [
+    (0x0000, &[0x00], false),
+    (0x0001, &[0x01], false),
+    (0x0002, &[0x02], false),
+    (0x0003, &[0x03], false),
+    (0x0004, &[0x04], false),
+    (0x0005, &[0x05], false),
+    (0x0006, &[0x06], false),
+    (0x0007, &[0x07], false),
+    (0x0008, &[0x08], false),
+    (0x0009, &[0x09], false),
+    (0x000a, &[0x0a], false),
+    (0x000b, &[0x0b], false),
+    (0x000c, &[0x0c], false),
+    (0x000d, &[0x0d], false),
+    (0x000e, &[0x0e], false),
+    (0x000f, &[0x0f], false),
+    (0x0010, &[0x10], false),
+    (0x0011, &[0x11], false),
+    (0x0012, &[0x12], false),
+    (0x0013, &[0x13], false),
+    (0x0014, &[0x14], false),
+    (0x0015, &[0x15], false),
+    (0x0016, &[0x16], false),
+    (0x0017, &[0x17], false),
+    (0x0018, &[0x18], false),
+    (0x0019, &[0x19], false),
+    (0x001a, &[0x1a], false),
+    (0x001b, &[0x1b], false),
+    (0x001c, &[0x1c], false),
+    (0x001d, &[0x1d], false),
+    (0x001e, &[0x1e], false),
+    (0x001f, &[0x1f], false),
+    (0x0020, &[0x20], false),
+    (0x0021, &[0x21], false),
+    (0x0022, &[0x22], false),
+    (0x0023, &[0x23], false),
+    (0x0024, &[0x24], false),
+    (0x0025, &[0x25], false),
+    (0x0026, &[0x26], false),
+    (0x0027, &[0x27], false),
+    (0x0028, &[0x28], false),
+    (0x0029, &[0x29], false),
+    (0x002a, &[0x2a], false),
+    (0x002b, &[0x2b], false),
+    (0x002c, &[0x2c], false),
+    (0x002d, &[0x2d], false),
+    (0x002e, &[0x2e], false),
+    (0x002f, &[0x2f], false),
+    (0x0030, &[0x30], false),
+    (0x0031, &[0x31], false),
+    (0x0032, &[0x32], false),
+    (0x0033, &[0x33], false),
+    (0x0034, &[0x34], false),
+    (0x0035, &[0x35], false),
+    (0x0036, &[0x36], false),
+    (0x0037, &[0x37], false),
+    (0x0038, &[0x38], false),
+    (0x0039, &[0x39], false),
+    (0x003a, &[0x3a], false),
+    (0x003b, &[0x3b], false),
+    (0x003c, &[0x3c], false),
+    (0x003d, &[0x3d], false),
+    (0x003e, &[0x3e], false),
+    (0x003f, &[0x3f], false),
+    (0x0040, &[0x40], false),
+    (0x0041, &[0x41], false),
+    (0x0042, &[0x42], false),
+    (0x0043, &[0x43], false),
+    (0x0044, &[0x44], false),
+    (0x0045, &[0x45], false),
+    (0x0046, &[0x46], false),
+    (0x0047, &[0x47], false),
+    (0x0048, &[0x48], false),
+    (0x0049, &[0x49], false),
+    (0x004a, &[0x4a], false),
+    (0x004b, &[0x4b], false),
+    (0x004c, &[0x4c], false),
+    (0x004d, &[0x4d], false),
+    (0x004e, &[0x4e], false),
+    (0x004f, &[0x4f], false),
+    (0x0050, &[0x50], false),
+    (0x0051, &[0x51], false),
+    (0x0052, &[0x52], false),
+    (0x0053, &[0x53], false),
+    (0x0054, &[0x54], false),
+    (0x0055, &[0x55], false),
+    (0x0056, &[0x56], false),
+    (0x0057, &[0x57], false),
+    (0x0058, &[0x58], false),
+    (0x0059, &[0x59], false),
+    (0x005a, &[0x5a], false),
+    (0x005b, &[0x5b], false),
+    (0x005c, &[0x5c], false),
+    (0x005d, &[0x5d], false),
+    (0x005e, &[0x5e], false),
+    (0x005f, &[0x5f], false),
+    (0x0060, &[0x60], false),
+    (0x0061, &[0x61], false),
+    (0x0062, &[0x62], false),
+    (0x0063, &[0x63], false),
+    (0x0064, &[0x64], false),
+    (0x0065, &[0x65], false),
+    (0x0066, &[0x66], false),
+    (0x0067, &[0x67], false),
+    (0x0068, &[0x68], false),
+    (0x0069, &[0x69], false),
+    (0x006a, &[0x6a], false),
+    (0x006b, &[0x6b], false),
+    (0x006c, &[0x6c], false),
+    (0x006d, &[0x6d], false),
+    (0x006e, &[0x6e], false),
+    (0x006f, &[0x6f], false),
+    (0x0070, &[0x70], false),
+    (0x0071, &[0x71], false),
+    (0x0072, &[0x72], false),
+    (0x0073, &[0x73], false),
+    (0x0074, &[0x74], false),
+    (0x0075, &[0x75], false),
+    (0x0076, &[0x76], false),
+    (0x0077, &[0x77], false),
+    (0x0078, &[0x78], false),
+    (0x0079, &[0x79], false),
+    (0x007a, &[0x7a], false),
+    (0x007b, &[0x7b], false),
+    (0x007c, &[0x7c], false),
+    (0x007d, &[0x7d], false),
+    (0x007e, &[0x7e], false),
+    (0x007f, &[0x7f], false),
+    (0x00fc, &[0x80], true),
+    (0xf880, &[0x81], false),
+    (0xf881, &[0x82], false),
+    (0x00a0, &[0xa0], false),
+    (0x00a9, &[0xfd], false),
+    (0x2122, &[0xfe], false),
+    (0x2026, &[0xff], false),
+    (0x3000, &[0xa1, 0xa1], false),
+    (0x3001, &[0xa1, 0xa2], false),
+    (0x3002, &[0xa1, 0xa3], false),
+    (0x00b7, &[0xa1, 0xa4], false),
+    (0x02c9, &[0xa1, 0xa5], false),
+    (0x02c7, &[0xa1, 0xa6], false),
+    (0x00a8, &[0xa1, 0xa7], false),
+    (0x3003, &[0xa1, 0xa8], false),
+    (0x3005, &[0xa1, 0xa9], false),
+    (0x2014, &[0xa1, 0xaa], false),
+    (0x301c, &[0xa1, 0xab], false),
+    (0x2016, &[0xa1, 0xac], false),
+    (0x22ef, &[0xa1, 0xad], false),
+    (0x2018, &[0xa1, 0xae], false),
+    (0x2019, &[0xa1, 0xaf], false),
+    (0x201c, &[0xa1, 0xb0], false),
+    (0x201d, &[0xa1, 0xb1], false),
+    (0x3014, &[0xa1, 0xb2], false),
+    (0x3015, &[0xa1, 0xb3], false),
+    (0x3008, &[0xa1, 0xb4], false),
+    (0x3009, &[0xa1, 0xb5], false),
+    (0x300a, &[0xa1, 0xb6], false),
+    (0x300b, &[0xa1, 0xb7], false),
+    (0x300c, &[0xa1, 0xb8], false),
+    (0x300d, &[0xa1, 0xb9], false),
+    (0x300e, &[0xa1, 0xba], false),
+    (0x300f, &[0xa1, 0xbb], false),
+    (0x3016, &[0xa1, 0xbc], false),
+    (0x3017, &[0xa1, 0xbd], false),
+    (0x3010, &[0xa1, 0xbe], false),
+    (0x3011, &[0xa1, 0xbf], false),
+    (0x00b1, &[0xa1, 0xc0], false),
+    (0x00d7, &[0xa1, 0xc1], false),
+    (0x00f7, &[0xa1, 0xc2], false),
+    (0x2236, &[0xa1, 0xc3], false),
+    (0x2227, &[0xa1, 0xc4], false),
+    (0x2228, &[0xa1, 0xc5], false),
+    (0x2211, &[0xa1, 0xc6], false),
+    (0x220f, &[0xa1, 0xc7], false),
+    (0x222a, &[0xa1, 0xc8], false),
+    (0x2229, &[0xa1, 0xc9], false),
+    (0x2208, &[0xa1, 0xca], false),
+    (0x2237, &[0xa1, 0xcb], false),
+    (0x221a, &[0xa1, 0xcc], false),
+    (0x22a5, &[0xa1, 0xcd], false),
+    (0x2225, &[0xa1, 0xce], false),
+    (0x2220, &[0xa1, 0xcf], false),
+    (0x2312, &[0xa1, 0xd0], false),
+    (0x2299, &[0xa1, 0xd1], false),
+    (0x222b, &[0xa1, 0xd2], false),
+    (0x222e, &[0xa1, 0xd3], false),
+    (0x2261, &[0xa1, 0xd4], false),
+    (0x224c, &[0xa1, 0xd5], false),
+    (0x2248, &[0xa1, 0xd6], false),
+    (0x223d, &[0xa1, 0xd7], false),
+    (0x221d, &[0xa1, 0xd8], false),
+    (0x2260, &[0xa1, 0xd9], false),
+    (0x226e, &[0xa1, 0xda], false),
+    (0x226f, &[0xa1, 0xdb], false),
+    (0x2264, &[0xa1, 0xdc], false),
+    (0x2265, &[0xa1, 0xdd], false),
+    (0x221e, &[0xa1, 0xde], false),
+    (0x2235, &[0xa1, 0xdf], false),
+    (0x2234, &[0xa1, 0xe0], false),
+    (0x2642, &[0xa1, 0xe1], false),
+    (0x2640, &[0xa1, 0xe2], false),
+    (0x00b0, &[0xa1, 0xe3], false),
+    (0x2032, &[0xa1, 0xe4], false),
+    (0x2033, &[0xa1, 0xe5], false),
+    (0x2103, &[0xa1, 0xe6], false),
+    (0xff04, &[0xa1, 0xe7], false),
+    (0x00a4, &[0xa1, 0xe8], false),
+    (0x00a2, &[0xa1, 0xe9], false),
+    (0x00a3, &[0xa1, 0xea], false),
+    (0x2030, &[0xa1, 0xeb], false),
+    (0x00a7, &[0xa1, 0xec], false),
+    (0x2116, &[0xa1, 0xed], false),
+    (0x2606, &[0xa1, 0xee], false),
+    (0x2605, &[0xa1, 0xef], false),
+    (0x25cb, &[0xa1, 0xf0], false),
+    (0x25cf, &[0xa1, 0xf1], false),
+    (0x25ce, &[0xa1, 0xf2], false),
+    (0x25c7, &[0xa1, 0xf3], false),
+    (0x25c6, &[0xa1, 0xf4], false),
+    (0x25a1, &[0xa1, 0xf5], false),
+    (0x25a0, &[0xa1, 0xf6], false),
+    (0x25b3, &[0xa1, 0xf7], false),
+    (0x25b2, &[0xa1, 0xf8], false),
+    (0x203b, &[0xa1, 0xf9], false),
+    (0x2192, &[0xa1, 0xfa], false),
+    (0x2190, &[0xa1, 0xfb], false),
+    (0x2191, &[0xa1, 0xfc], false),
+    (0x2193, &[0xa1, 0xfd], false),
+    (0x3013, &[0xa1, 0xfe], false),
+    (0x2488, &[0xa2, 0xb1], false),
+    (0x2489, &[0xa2, 0xb2], false),
+    (0x248a, &[0xa2, 0xb3], false),
+    (0x248b, &[0xa2, 0xb4], false),
+    (0x248c, &[0xa2, 0xb5], false),
+    (0x248d, &[0xa2, 0xb6], false),
+    (0x248e, &[0xa2, 0xb7], false),
+    (0x248f, &[0xa2, 0xb8], false),
+    (0x2490, &[0xa2, 0xb9], false),
+    (0x2491, &[0xa2, 0xba], false),
+    (0x2492, &[0xa2, 0xbb], false),
+    (0x2493, &[0xa2, 0xbc], false),
+    (0x2494, &[0xa2, 0xbd], false),
+    (0x2495, &[0xa2, 0xbe], false),
+    (0x2496, &[0xa2, 0xbf], false),
+    (0x2497, &[0xa2, 0xc0], false),
+    (0x2498, &[0xa2, 0xc1], false),
+    (0x2499, &[0xa2, 0xc2], false),
+    (0x249a, &[0xa2, 0xc3], false),
+    (0x249b, &[0xa2, 0xc4], false),
+    (0x2474, &[0xa2, 0xc5], false),
+    (0x2475, &[0xa2, 0xc6], false),
+    (0x2476, &[0xa2, 0xc7], false),
+    (0x2477, &[0xa2, 0xc8], false),
+    (0x2478, &[0xa2, 0xc9], false),
+    (0x2479, &[0xa2, 0xca], false),
+    (0x247a, &[0xa2, 0xcb], false),
+    (0x247b, &[0xa2, 0xcc], false),
+    (0x247c, &[0xa2, 0xcd], false),
+    (0x247d, &[0xa2, 0xce], false),
+    (0x247e, &[0xa2, 0xcf], false),
+    (0x247f, &[0xa2, 0xd0], false),
+    (0x2480, &[0xa2, 0xd1], false),
+    (0x2481, &[0xa2, 0xd2], false),
+    (0x2482, &[0xa2, 0xd3], false),
+    (0x2483, &[0xa2, 0xd4], false),
+    (0x2484, &[0xa2, 0xd5], false),
+    (0x2485, &[0xa2, 0xd6], false),
+    (0x2486, &[0xa2, 0xd7], false),
+    (0x2487, &[0xa2, 0xd8], false),
+    (0x2460, &[0xa2, 0xd9], false),
+    (0x2461, &[0xa2, 0xda], false),
+    (0x2462, &[0xa2, 0xdb], false),
+    (0x2463, &[0xa2, 0xdc], false),
+    (0x2464, &[0xa2, 0xdd], false),
+    (0x2465, &[0xa2, 0xde], false),
+    (0x2466, &[0xa2, 0xdf], false),
+    (0x2467, &[0xa2, 0xe0], false),
+    (0x2468, &[0xa2, 0xe1], false),
+    (0x2469, &[0xa2, 0xe2], false),
+    (0x3220, &[0xa2, 0xe5], false),
+    (0x3221, &[0xa2, 0xe6], false),
+    (0x3222, &[0xa2, 0xe7], false),
+    (0x3223, &[0xa2, 0xe8], false),
+    (0x3224, &[0xa2, 0xe9], false),
+    (0x3225, &[0xa2, 0xea], false),
+    (0x3226, &[0xa2, 0xeb], false),
+    (0x3227, &[0xa2, 0xec], false),
+    (0x3228, &[0xa2, 0xed], false),
+    (0x3229, &[0xa2, 0xee], false),
+    (0x2160, &[0xa2, 0xf1], false),
+    (0x2161, &[0xa2, 0xf2], false),
+    (0x2162, &[0xa2, 0xf3], false),
+    (0x2163, &[0xa2, 0xf4], false),
+    (0x2164, &[0xa2, 0xf5], false),
+    (0x2165, &[0xa2, 0xf6], false),
+    (0x2166, &[0xa2, 0xf7], false),
+    (0x2167, &[0xa2, 0xf8], false),
+    (0x2168, &[0xa2, 0xf9], false),
+    (0x2169, &[0xa2, 0xfa], false),
+    (0x216a, &[0xa2, 0xfb], false),
+    (0x216b, &[0xa2, 0xfc], false),
+    (0xff01, &[0xa3, 0xa1], false),
+    (0xff02, &[0xa3, 0xa2], false),
+    (0xff03, &[0xa3, 0xa3], false),
+    (0x00a5, &[0xa3, 0xa4], false),
+    (0xff05, &[0xa3, 0xa5], false),
+    (0xff06, &[0xa3, 0xa6], false),
+    (0xff07, &[0xa3, 0xa7], false),
+    (0xff08, &[0xa3, 0xa8], false),
+    (0xff09, &[0xa3, 0xa9], false),
+    (0xff0a, &[0xa3, 0xaa], false),
+    (0xff0b, &[0xa3, 0xab], false),
+    (0xff0c, &[0xa3, 0xac], false),
+    (0xff0d, &[0xa3, 0xad], false),
+    (0xff0e, &[0xa3, 0xae], false),
+    (0xff0f, &[0xa3, 0xaf], false),
+    (0xff10, &[0xa3, 0xb0], false),
+    (0xff11, &[0xa3, 0xb1], false),
+    (0xff12, &[0xa3, 0xb2], false),
+    (0xff13, &[0xa3, 0xb3], false),
+    (0xff14, &[0xa3, 0xb4], false),
+    (0xff15, &[0xa3, 0xb5], false),
+    (0xff16, &[0xa3, 0xb6], false),
+    (0xff17, &[0xa3, 0xb7], false),
+    (0xff18, &[0xa3, 0xb8], false),
+    (0xff19, &[0xa3, 0xb9], false),
+    (0xff1a, &[0xa3, 0xba], false),
+    (0xff1b, &[0xa3, 0xbb], false),
+    (0xff1c, &[0xa3, 0xbc], false),
+    (0xff1d, &[0xa3, 0xbd], false),
+    (0xff1e, &[0xa3, 0xbe], false),
+    (0xff1f, &[0xa3, 0xbf], false),
+    (0xff20, &[0xa3, 0xc0], false),
+    (0xff21, &[0xa3, 0xc1], false),
+    (0xff22, &[0xa3, 0xc2], false),
+    (0xff23, &[0xa3, 0xc3], false),
+    (0xff24, &[0xa3, 0xc4], false),
+    (0xff25, &[0xa3, 0xc5], false),
+    (0xff26, &[0xa3, 0xc6], false),
+    (0xff27, &[0xa3, 0xc7], false),
+    (0xff28, &[0xa3, 0xc8], false),
+    (0xff29, &[0xa3, 0xc9], false),
+    (0xff2a, &[0xa3, 0xca], false),
+    (0xff2b, &[0xa3, 0xcb], false),
+    (0xff2c, &[0xa3, 0xcc], false),
+    (0xff2d, &[0xa3, 0xcd], false),
+    (0xff2e, &[0xa3, 0xce], false),
+    (0xff2f, &[0xa3, 0xcf], false),
+    (0xff30, &[0xa3, 0xd0], false),
+    (0xff31, &[0xa3, 0xd1], false),
+    (0xff32, &[0xa3, 0xd2], false),
+    (0xff33, &[0xa3, 0xd3], false),
+    (0xff34, &[0xa3, 0xd4], false),
+    (0xff35, &[0xa3, 0xd5], false),
+    (0xff36, &[0xa3, 0xd6], false),
+    (0xff37, &[0xa3, 0xd7], false),
+    (0xff38, &[0xa3, 0xd8], false),
+    (0xff39, &[0xa3, 0xd9], false),
+    (0xff3a, &[0xa3, 0xda], false),
+    (0xff3b, &[0xa3, 0xdb], false),
+    (0xff3c, &[0xa3, 0xdc], false),
+    (0xff3d, &[0xa3, 0xdd], false),
+    (0xff3e, &[0xa3, 0xde], false),
+    (0xff3f, &[0xa3, 0xdf], false),
+    (0xff40, &[0xa3, 0xe0], false),
+    (0xff41, &[0xa3, 0xe1], false),
+    (0xff42, &[0xa3, 0xe2], false),
+    (0xff43, &[0xa3, 0xe3], false),
+    (0xff44, &[0xa3, 0xe4], false),
+    (0xff45, &[0xa3, 0xe5], false),
+    (0xff46, &[0xa3, 0xe6], false),
+    (0xff47, &[0xa3, 0xe7], false),
+    (0xff48, &[0xa3, 0xe8], false),
+    (0xff49, &[0xa3, 0xe9], false),
+    (0xff4a, &[0xa3, 0xea], false),
+    (0xff4b, &[0xa3, 0xeb], false),
+    (0xff4c, &[0xa3, 0xec], false),
+    (0xff4d, &[0xa3, 0xed], false),
+    (0xff4e, &[0xa3, 0xee], false),
+    (0xff4f, &[0xa3, 0xef], false),
+    (0xff50, &[0xa3, 0xf0], false),
+    (0xff51, &[0xa3, 0xf1], false),
+    (0xff52, &[0xa3, 0xf2], false),
+    (0xff53, &[0xa3, 0xf3], false),
+    (0xff54, &[0xa3, 0xf4], false),
+    (0xff55, &[0xa3, 0xf5], false),
+    (0xff56, &[0xa3, 0xf6], false),
+    (0xff57, &[0xa3, 0xf7], false),
+    (0xff58, &[0xa3, 0xf8], false),
+    (0xff59, &[0xa3, 0xf9], false),
+    (0xff5a, &[0xa3, 0xfa], false),
+    (0xff5b, &[0xa3, 0xfb], false),
+    (0xff5c, &[0xa3, 0xfc], false),
+    (0xff5d, &[0xa3, 0xfd], false),
+    (0x203e, &[0xa3, 0xfe], false),
+    (0x3041, &[0xa4, 0xa1], false),
+    (0x3042, &[0xa4, 0xa2], false),
+    (0x3043, &[0xa4, 0xa3], false),
+    (0x3044, &[0xa4, 0xa4], false),
+    (0x3045, &[0xa4, 0xa5], false),
+    (0x3046, &[0xa4, 0xa6], false),
+    (0x3047, &[0xa4, 0xa7], false),
+    (0x3048, &[0xa4, 0xa8], false),
+    (0x3049, &[0xa4, 0xa9], false),
+    (0x304a, &[0xa4, 0xaa], false),
+    (0x304b, &[0xa4, 0xab], false),
+    (0x304c, &[0xa4, 0xac], false),
+    (0x304d, &[0xa4, 0xad], false),
+    (0x304e, &[0xa4, 0xae], false),
+    (0x304f, &[0xa4, 0xaf], false),
+    (0x3050, &[0xa4, 0xb0], false),
+    (0x3051, &[0xa4, 0xb1], false),
+    (0x3052, &[0xa4, 0xb2], false),
+    (0x3053, &[0xa4, 0xb3], false),
+    (0x3054, &[0xa4, 0xb4], false),
+    (0x3055, &[0xa4, 0xb5], false),
+    (0x3056, &[0xa4, 0xb6], false),
+    (0x3057, &[0xa4, 0xb7], false),
+    (0x3058, &[0xa4, 0xb8], false),
+    (0x3059, &[0xa4, 0xb9], false),
+    (0x305a, &[0xa4, 0xba], false),
+    (0x305b, &[0xa4, 0xbb], false),
+    (0x305c, &[0xa4, 0xbc], false),
+    (0x305d, &[0xa4, 0xbd], false),
+    (0x305e, &[0xa4, 0xbe], false),
+    (0x305f, &[0xa4, 0xbf], false),
+    (0x3060, &[0xa4, 0xc0], false),
+    (0x3061, &[0xa4, 0xc1], false),
+    (0x3062, &[0xa4, 0xc2], false),
+    (0x3063, &[0xa4, 0xc3], false),
+    (0x3064, &[0xa4, 0xc4], false),
+    (0x3065, &[0xa4, 0xc5], false),
+    (0x3066, &[0xa4, 0xc6], false),
+    (0x3067, &[0xa4, 0xc7], false),
+    (0x3068, &[0xa4, 0xc8], false),
+    (0x3069, &[0xa4, 0xc9], false),
+    (0x306a, &[0xa4, 0xca], false),
+    (0x306b, &[0xa4, 0xcb], false),
+    (0x306c, &[0xa4, 0xcc], false),
+    (0x306d, &[0xa4, 0xcd], false),
+    (0x306e, &[0xa4, 0xce], false),
+    (0x306f, &[0xa4, 0xcf], false),
+    (0x3070, &[0xa4, 0xd0], false),
+    (0x3071, &[0xa4, 0xd1], false),
+    (0x3072, &[0xa4, 0xd2], false),
+    (0x3073, &[0xa4, 0xd3], false),
+    (0x3074, &[0xa4, 0xd4], false),
+    (0x3075, &[0xa4, 0xd5], false),
+    (0x3076, &[0xa4, 0xd6], false),
+    (0x3077, &[0xa4, 0xd7], false),
+    (0x3078, &[0xa4, 0xd8], false),
+    (0x3079, &[0xa4, 0xd9], false),
+    (0x307a, &[0xa4, 0xda], false),
+    (0x307b, &[0xa4, 0xdb], false),
+    (0x307c, &[0xa4, 0xdc], false),
+    (0x307d, &[0xa4, 0xdd], false),
+    (0x307e, &[0xa4, 0xde], false),
+    (0x307f, &[0xa4, 0xdf], false),
+    (0x3080, &[0xa4, 0xe0], false),
+    (0x3081, &[0xa4, 0xe1], false),
+    (0x3082, &[0xa4, 0xe2], false),
+    (0x3083, &[0xa4, 0xe3], false),
+    (0x3084, &[0xa4, 0xe4], false),
+    (0x3085, &[0xa4, 0xe5], false),
+    (0x3086, &[0xa4, 0xe6], false),
+    (0x3087, &[0xa4, 0xe7], false),
+    (0x3088, &[0xa4, 0xe8], false),
+    (0x3089, &[0xa4, 0xe9], false),
+    (0x308a, &[0xa4, 0xea], false),
+    (0x308b, &[0xa4, 0xeb], false),
+    (0x308c, &[0xa4, 0xec], false),
+    (0x308d, &[0xa4, 0xed], false),
+    (0x308e, &[0xa4, 0xee], false),
+    (0x308f, &[0xa4, 0xef], false),
+    (0x3090, &[0xa4, 0xf0], false),
+    (0x3091, &[0xa4, 0xf1], false),
+    (0x3092, &[0xa4, 0xf2], false),
+    (0x3093, &[0xa4, 0xf3], false),
+    (0x30a1, &[0xa5, 0xa1], false),
+    (0x30a2, &[0xa5, 0xa2], false),
+    (0x30a3, &[0xa5, 0xa3], false),
+    (0x30a4, &[0xa5, 0xa4], false),
+    (0x30a5, &[0xa5, 0xa5], false),
+    (0x30a6, &[0xa5, 0xa6], false),
+    (0x30a7, &[0xa5, 0xa7], false),
+    (0x30a8, &[0xa5, 0xa8], false),
+    (0x30a9, &[0xa5, 0xa9], false),
+    (0x30aa, &[0xa5, 0xaa], false),
+    (0x30ab, &[0xa5, 0xab], false),
+    (0x30ac, &[0xa5, 0xac], false),
+    (0x30ad, &[0xa5, 0xad], false),
+    (0x30ae, &[0xa5, 0xae], false),
+    (0x30af, &[0xa5, 0xaf], false),
+    (0x30b0, &[0xa5, 0xb0], false),
+    (0x30b1, &[0xa5, 0xb1], false),
+    (0x30b2, &[0xa5, 0xb2], false),
+    (0x30b3, &[0xa5, 0xb3], false),
+    (0x30b4, &[0xa5, 0xb4], false),
+    (0x30b5, &[0xa5, 0xb5], false),
+    (0x30b6, &[0xa5, 0xb6], false),
+    (0x30b7, &[0xa5, 0xb7], false),
+    (0x30b8, &[0xa5, 0xb8], false),
+    (0x30b9, &[0xa5, 0xb9], false),
+    (0x30ba, &[0xa5, 0xba], false),
+    (0x30bb, &[0xa5, 0xbb], false),
+    (0x30bc, &[0xa5, 0xbc], false),
+    (0x30bd, &[0xa5, 0xbd], false),
+    (0x30be, &[0xa5, 0xbe], false),
+    (0x30bf, &[0xa5, 0xbf], false),
+    (0x30c0, &[0xa5, 0xc0], false),
+    (0x30c1, &[0xa5, 0xc1], false),
+    (0x30c2, &[0xa5, 0xc2], false),
+    (0x30c3, &[0xa5, 0xc3], false),
+    (0x30c4, &[0xa5, 0xc4], false),
+    (0x30c5, &[0xa5, 0xc5], false),
+    (0x30c6, &[0xa5, 0xc6], false),
+    (0x30c7, &[0xa5, 0xc7], false),
+    (0x30c8, &[0xa5, 0xc8], false),
+    (0x30c9, &[0xa5, 0xc9], false),
+    (0x30ca, &[0xa5, 0xca], false),
+    (0x30cb, &[0xa5, 0xcb], false),
+    (0x30cc, &[0xa5, 0xcc], false),
+    (0x30cd, &[0xa5, 0xcd], false),
+    (0x30ce, &[0xa5, 0xce], false),
+    (0x30cf, &[0xa5, 0xcf], false),
+    (0x30d0, &[0xa5, 0xd0], false),
+    (0x30d1, &[0xa5, 0xd1], false),
+    (0x30d2, &[0xa5, 0xd2], false),
+    (0x30d3, &[0xa5, 0xd3], false),
+    (0x30d4, &[0xa5, 0xd4], false),
+    (0x30d5, &[0xa5, 0xd5], false),
+    (0x30d6, &[0xa5, 0xd6], false),
+    (0x30d7, &[0xa5, 0xd7], false),
+    (0x30d8, &[0xa5, 0xd8], false),
+    (0x30d9, &[0xa5, 0xd9], false),
+    (0x30da, &[0xa5, 0xda], false),
+    (0x30db, &[0xa5, 0xdb], false),
+    (0x30dc, &[0xa5, 0xdc], false),
+    (0x30dd, &[0xa5, 0xdd], false),
+    (0x30de, &[0xa5, 0xde], false),
+    (0x30df, &[0xa5, 0xdf], false),
+    (0x30e0, &[0xa5, 0xe0], false),
+    (0x30e1, &[0xa5, 0xe1], false),
+    (0x30e2, &[0xa5, 0xe2], false),
+    (0x30e3, &[0xa5, 0xe3], false),
+    (0x30e4, &[0xa5, 0xe4], false),
+    (0x30e5, &[0xa5, 0xe5], false),
+    (0x30e6, &[0xa5, 0xe6], false),
+    (0x30e7, &[0xa5, 0xe7], false),
+    (0x30e8, &[0xa5, 0xe8], false),
+    (0x30e9, &[0xa5, 0xe9], false),
+    (0x30ea, &[0xa5, 0xea], false),
+    (0x30eb, &[0xa5, 0xeb], false),
+    (0x30ec, &[0xa5, 0xec], false),
+    (0x30ed, &[0xa5, 0xed], false),
+    (0x30ee, &[0xa5, 0xee], false),
+    (0x30ef, &[0xa5, 0xef], false),
+    (0x30f0, &[0xa5, 0xf0], false),
+    (0x30f1, &[0xa5, 0xf1], false),
+    (0x30f2, &[0xa5, 0xf2], false),
+    (0x30f3, &[0xa5, 0xf3], false),
+    (0x30f4, &[0xa5, 0xf4], false),
+    (0x30f5, &[0xa5, 0xf5], false),
+    (0x30f6, &[0xa5, 0xf6], false),
+    (0x0391, &[0xa6, 0xa1], false),
+    (0x0392, &[0xa6, 0xa2], false),
+    (0x0393, &[0xa6, 0xa3], false),
+    (0x0394, &[0xa6, 0xa4], false),
+    (0x0395, &[0xa6, 0xa5], false),
+    (0x0396, &[0xa6, 0xa6], false),
+    (0x0397, &[0xa6, 0xa7], false),
+    (0x0398, &[0xa6, 0xa8], false),
+    (0x0399, &[0xa6, 0xa9], false),
+    (0x039a, &[0xa6, 0xaa], false),
+    (0x039b, &[0xa6, 0xab], false),
+    (0x039c, &[0xa6, 0xac], false),
+    (0x039d, &[0xa6, 0xad], false),
+    (0x039e, &[0xa6, 0xae], false),
+    (0x039f, &[0xa6, 0xaf], false),
+    (0x03a0, &[0xa6, 0xb0], false),
+    (0x03a1, &[0xa6, 0xb1], false),
+    (0x03a3, &[0xa6, 0xb2], false),
+    (0x03a4, &[0xa6, 0xb3], false),
+    (0x03a5, &[0xa6, 0xb4], false),
+    (0x03a6, &[0xa6, 0xb5], false),
+    (0x03a7, &[0xa6, 0xb6], false),
+    (0x03a8, &[0xa6, 0xb7], false),
+    (0x03a9, &[0xa6, 0xb8], false),
+    (0x03b1, &[0xa6, 0xc1], false),
+    (0x03b2, &[0xa6, 0xc2], false),
+    (0x03b3, &[0xa6, 0xc3], false),
+    (0x03b4, &[0xa6, 0xc4], false),
+    (0x03b5, &[0xa6, 0xc5], false),
+    (0x03b6, &[0xa6, 0xc6], false),
+    (0x03b7, &[0xa6, 0xc7], false),
+    (0x03b8, &[0xa6, 0xc8], false),
+    (0x03b9, &[0xa6, 0xc9], false),
+    (0x03ba, &[0xa6, 0xca], false),
+    (0x03bb, &[0xa6, 0xcb], false),
+    (0x03bc, &[0xa6, 0xcc], false),
+    (0x03bd, &[0xa6, 0xcd], false),
+    (0x03be, &[0xa6, 0xce], false),
+    (0x03bf, &[0xa6, 0xcf], false),
+    (0x03c0, &[0xa6, 0xd0], false),
+    (0x03c1, &[0xa6, 0xd1], false),
+    (0x03c3, &[0xa6, 0xd2], false),
+    (0x03c4, &[0xa6, 0xd3], false),
+    (0x03c5, &[0xa6, 0xd4], false),
+    (0x03c6, &[0xa6, 0xd5], false),
+    (0x03c7, &[0xa6, 0xd6], false),
+    (0x03c8, &[0xa6, 0xd7], false),
+    (0x03c9, &[0xa6, 0xd8], false),
+    (0xff0c, &[0xa6, 0xd9], true),
+    (0x3002, &[0xa6, 0xda], true),
+    (0x3001, &[0xa6, 0xdb], true),
+    (0xff1a, &[0xa6, 0xdc], true),
+    (0xff1b, &[0xa6, 0xdd], true),
+    (0xff01, &[0xa6, 0xde], true),
+    (0xff1f, &[0xa6, 0xdf], true),
+    (0xfe35, &[0xa6, 0xe0], false),
+    (0xfe36, &[0xa6, 0xe1], false),
+    (0xfe39, &[0xa6, 0xe2], false),
+    (0xfe3a, &[0xa6, 0xe3], false),
+    (0xfe3f, &[0xa6, 0xe4], false),
+    (0xfe40, &[0xa6, 0xe5], false),
+    (0xfe3d, &[0xa6, 0xe6], false),
+    (0xfe3e, &[0xa6, 0xe7], false),
+    (0xfe41, &[0xa6, 0xe8], false),
+    (0xfe42, &[0xa6, 0xe9], false),
+    (0xfe43, &[0xa6, 0xea], false),
+    (0xfe44, &[0xa6, 0xeb], false),
+    (0x3016, &[0xa6, 0xec], true),
+    (0x3017, &[0xa6, 0xed], true),
+    (0xfe3b, &[0xa6, 0xee], false),
+    (0xfe3c, &[0xa6, 0xef], false),
+    (0xfe37, &[0xa6, 0xf0], false),
+    (0xfe38, &[0xa6, 0xf1], false),
+    (0xfe31, &[0xa6, 0xf2], false),
+    (0x22ef, &[0xa6, 0xf3], true),
+    (0xfe33, &[0xa6, 0xf4], false),
+    (0xfe34, &[0xa6, 0xf5], false),
+    (0x0410, &[0xa7, 0xa1], false),
+    (0x0411, &[0xa7, 0xa2], false),
+    (0x0412, &[0xa7, 0xa3], false),
+    (0x0413, &[0xa7, 0xa4], false),
+    (0x0414, &[0xa7, 0xa5], false),
+    (0x0415, &[0xa7, 0xa6], false),
+    (0x0401, &[0xa7, 0xa7], false),
+    (0x0416, &[0xa7, 0xa8], false),
+    (0x0417, &[0xa7, 0xa9], false),
+    (0x0418, &[0xa7, 0xaa], false),
+    (0x0419, &[0xa7, 0xab], false),
+    (0x041a, &[0xa7, 0xac], false),
+    (0x041b, &[0xa7, 0xad], false),
+    (0x041c, &[0xa7, 0xae], false),
+    (0x041d, &[0xa7, 0xaf], false),
+    (0x041e, &[0xa7, 0xb0], false),
+    (0x041f, &[0xa7, 0xb1], false),
+    (0x0420, &[0xa7, 0xb2], false),
+    (0x0421, &[0xa7, 0xb3], false),
+    (0x0422, &[0xa7, 0xb4], false),
+    (0x0423, &[0xa7, 0xb5], false),
+    (0x0424, &[0xa7, 0xb6], false),
+    (0x0425, &[0xa7, 0xb7], false),
+    (0x0426, &[0xa7, 0xb8], false),
+    (0x0427, &[0xa7, 0xb9], false),
+    (0x0428, &[0xa7, 0xba], false),
+    (0x0429, &[0xa7, 0xbb], false),
+    (0x042a, &[0xa7, 0xbc], false),
+    (0x042b, &[0xa7, 0xbd], false),
+    (0x042c, &[0xa7, 0xbe], false),
+    (0x042d, &[0xa7, 0xbf], false),
+    (0x042e, &[0xa7, 0xc0], false),
+    (0x042f, &[0xa7, 0xc1], false),
+    (0x0430, &[0xa7, 0xd1], false),
+    (0x0431, &[0xa7, 0xd2], false),
+    (0x0432, &[0xa7, 0xd3], false),
+    (0x0433, &[0xa7, 0xd4], false),
+    (0x0434, &[0xa7, 0xd5], false),
+    (0x0435, &[0xa7, 0xd6], false),
+    (0x0451, &[0xa7, 0xd7], false),
+    (0x0436, &[0xa7, 0xd8], false),
+    (0x0437, &[0xa7, 0xd9], false),
+    (0x0438, &[0xa7, 0xda], false),
+    (0x0439, &[0xa7, 0xdb], false),
+    (0x043a, &[0xa7, 0xdc], false),
+    (0x043b, &[0xa7, 0xdd], false),
+    (0x043c, &[0xa7, 0xde], false),
+    (0x043d, &[0xa7, 0xdf], false),
+    (0x043e, &[0xa7, 0xe0], false),
+    (0x043f, &[0xa7, 0xe1], false),
+    (0x0440, &[0xa7, 0xe2], false),
+    (0x0441, &[0xa7, 0xe3], false),
+    (0x0442, &[0xa7, 0xe4], false),
+    (0x0443, &[0xa7, 0xe5], false),
+    (0x0444, &[0xa7, 0xe6], false),
+    (0x0445, &[0xa7, 0xe7], false),
+    (0x0446, &[0xa7, 0xe8], false),
+    (0x0447, &[0xa7, 0xe9], false),
+    (0x0448, &[0xa7, 0xea], false),
+    (0x0449, &[0xa7, 0xeb], false),
+    (0x044a, &[0xa7, 0xec], false),
+    (0x044b, &[0xa7, 0xed], false),
+    (0x044c, &[0xa7, 0xee], false),
+    (0x044d, &[0xa7, 0xef], false),
+    (0x044e, &[0xa7, 0xf0], false),
+    (0x044f, &[0xa7, 0xf1], false),
+    (0x0101, &[0xa8, 0xa1], false),
+    (0x00e1, &[0xa8, 0xa2], false),
+    (0x01ce, &[0xa8, 0xa3], false),
+    (0x00e0, &[0xa8, 0xa4], false),
+    (0x0113, &[0xa8, 0xa5], false),
+    (0x00e9, &[0xa8, 0xa6], false),
+    (0x011b, &[0xa8, 0xa7], false),
+    (0x00e8, &[0xa8, 0xa8], false),
+    (0x012b, &[0xa8, 0xa9], false),
+    (0x00ed, &[0xa8, 0xaa], false),
+    (0x01d0, &[0xa8, 0xab], false),
+    (0x00ec, &[0xa8, 0xac], false),
+    (0x014d, &[0xa8, 0xad], false),
+    (0x00f3, &[0xa8, 0xae], false),
+    (0x01d2, &[0xa8, 0xaf], false),
+    (0x00f2, &[0xa8, 0xb0], false),
+    (0x016b, &[0xa8, 0xb1], false),
+    (0x00fa, &[0xa8, 0xb2], false),
+    (0x01d4, &[0xa8, 0xb3], false),
+    (0x00f9, &[0xa8, 0xb4], false),
+    (0x01d6, &[0xa8, 0xb5], false),
+    (0x01d8, &[0xa8, 0xb6], false),
+    (0x01da, &[0xa8, 0xb7], false),
+    (0x01dc, &[0xa8, 0xb8], false),
+    (0x00fc, &[0xa8, 0xb9], false),
+    (0x00ea, &[0xa8, 0xba], false),
+    (0x0251, &[0xa8, 0xbb], false),
+    (0x1e3f, &[0xa8, 0xbc], false),
+    (0x0144, &[0xa8, 0xbd], false),
+    (0x0148, &[0xa8, 0xbe], false),
+    (0x01f9, &[0xa8, 0xbf], false),
+    (0x0261, &[0xa8, 0xc0], false),
+    (0x3105, &[0xa8, 0xc5], false),
+    (0x3106, &[0xa8, 0xc6], false),
+    (0x3107, &[0xa8, 0xc7], false),
+    (0x3108, &[0xa8, 0xc8], false),
+    (0x3109, &[0xa8, 0xc9], false),
+    (0x310a, &[0xa8, 0xca], false),
+    (0x310b, &[0xa8, 0xcb], false),
+    (0x310c, &[0xa8, 0xcc], false),
+    (0x310d, &[0xa8, 0xcd], false),
+    (0x310e, &[0xa8, 0xce], false),
+    (0x310f, &[0xa8, 0xcf], false),
+    (0x3110, &[0xa8, 0xd0], false),
+    (0x3111, &[0xa8, 0xd1], false),
+    (0x3112, &[0xa8, 0xd2], false),
+    (0x3113, &[0xa8, 0xd3], false),
+    (0x3114, &[0xa8, 0xd4], false),
+    (0x3115, &[0xa8, 0xd5], false),
+    (0x3116, &[0xa8, 0xd6], false),
+    (0x3117, &[0xa8, 0xd7], false),
+    (0x3118, &[0xa8, 0xd8], false),
+    (0x3119, &[0xa8, 0xd9], false),
+    (0x311a, &[0xa8, 0xda], false),
+    (0x311b, &[0xa8, 0xdb], false),
+    (0x311c, &[0xa8, 0xdc], false),
+    (0x311d, &[0xa8, 0xdd], false),
+    (0x311e, &[0xa8, 0xde], false),
+    (0x311f, &[0xa8, 0xdf], false),
+    (0x3120, &[0xa8, 0xe0], false),
+    (0x3121, &[0xa8, 0xe1], false),
+    (0x3122, &[0xa8, 0xe2], false),
+    (0x3123, &[0xa8, 0xe3], false),
+    (0x3124, &[0xa8, 0xe4], false),
+    (0x3125, &[0xa8, 0xe5], false),
+    (0x3126, &[0xa8, 0xe6], false),
+    (0x3127, &[0xa8, 0xe7], false),
+    (0x3128, &[0xa8, 0xe8], false),
+    (0x3129, &[0xa8, 0xe9], false),
+    (0x2500, &[0xa9, 0xa4], false),
+    (0x2501, &[0xa9, 0xa5], false),
+    (0x2502, &[0xa9, 0xa6], false),
+    (0x2503, &[0xa9, 0xa7], false),
+    (0x2504, &[0xa9, 0xa8], false),
+    (0x2505, &[0xa9, 0xa9], false),
+    (0x2506, &[0xa9, 0xaa], false),
+    (0x2507, &[0xa9, 0xab], false),
+    (0x2508, &[0xa9, 0xac], false),
+    (0x2509, &[0xa9, 0xad], false),
+    (0x250a, &[0xa9, 0xae], false),
+    (0x250b, &[0xa9, 0xaf], false),
+    (0x250c, &[0xa9, 0xb0], false),
+    (0x250d, &[0xa9, 0xb1], false),
+    (0x250e, &[0xa9, 0xb2], false),
+    (0x250f, &[0xa9, 0xb3], false),
+    (0x2510, &[0xa9, 0xb4], false),
+    (0x2511, &[0xa9, 0xb5], false),
+    (0x2512, &[0xa9, 0xb6], false),
+    (0x2513, &[0xa9, 0xb7], false),
+    (0x2514, &[0xa9, 0xb8], false),
+    (0x2515, &[0xa9, 0xb9], false),
+    (0x2516, &[0xa9, 0xba], false),
+    (0x2517, &[0xa9, 0xbb], false),
+    (0x2518, &[0xa9, 0xbc], false),
+    (0x2519, &[0xa9, 0xbd], false),
+    (0x251a, &[0xa9, 0xbe], false),
+    (0x251b, &[0xa9, 0xbf], false),
+    (0x251c, &[0xa9, 0xc0], false),
+    (0x251d, &[0xa9, 0xc1], false),
+    (0x251e, &[0xa9, 0xc2], false),
+    (0x251f, &[0xa9, 0xc3], false),
+    (0x2520, &[0xa9, 0xc4], false),
+    (0x2521, &[0xa9, 0xc5], false),
+    (0x2522, &[0xa9, 0xc6], false),
+    (0x2523, &[0xa9, 0xc7], false),
+    (0x2524, &[0xa9, 0xc8], false),
+    (0x2525, &[0xa9, 0xc9], false),
+    (0x2526, &[0xa9, 0xca], false),
+    (0x2527, &[0xa9, 0xcb], false),
+    (0x2528, &[0xa9, 0xcc], false),
+    (0x2529, &[0xa9, 0xcd], false),
+    (0x252a, &[0xa9, 0xce], false),
+    (0x252b, &[0xa9, 0xcf], false),
+    (0x252c, &[0xa9, 0xd0], false),
+    (0x252d, &[0xa9, 0xd1], false),
+    (0x252e, &[0xa9, 0xd2], false),
+    (0x252f, &[0xa9, 0xd3], false),
+    (0x2530, &[0xa9, 0xd4], false),
+    (0x2531, &[0xa9, 0xd5], false),
+    (0x2532, &[0xa9, 0xd6], false),
+    (0x2533, &[0xa9, 0xd7], false),
+    (0x2534, &[0xa9, 0xd8], false),
+    (0x2535, &[0xa9, 0xd9], false),
+    (0x2536, &[0xa9, 0xda], false),
+    (0x2537, &[0xa9, 0xdb], false),
+    (0x2538, &[0xa9, 0xdc], false),
+    (0x2539, &[0xa9, 0xdd], false),
+    (0x253a, &[0xa9, 0xde], false),
+    (0x253b, &[0xa9, 0xdf], false),
+    (0x253c, &[0xa9, 0xe0], false),
+    (0x253d, &[0xa9, 0xe1], false),
+    (0x253e, &[0xa9, 0xe2], false),
+    (0x253f, &[0xa9, 0xe3], false),
+    (0x2540, &[0xa9, 0xe4], false),
+    (0x2541, &[0xa9, 0xe5], false),
+    (0x2542, &[0xa9, 0xe6], false),
+    (0x2543, &[0xa9, 0xe7], false),
+    (0x2544, &[0xa9, 0xe8], false),
+    (0x2545, &[0xa9, 0xe9], false),
+    (0x2546, &[0xa9, 0xea], false),
+    (0x2547, &[0xa9, 0xeb], false),
+    (0x2548, &[0xa9, 0xec], false),
+    (0x2549, &[0xa9, 0xed], false),
+    (0x254a, &[0xa9, 0xee], false),
+    (0x254b, &[0xa9, 0xef], false),
+    (0x554a, &[0xb0, 0xa1], false),
+    (0x963f, &[0xb0, 0xa2], false),
+    (0x57c3, &[0xb0, 0xa3], false),
+    (0x6328, &[0xb0, 0xa4], false),
+    (0x54ce, &[0xb0, 0xa5], false),
+    (0x5509, &[0xb0, 0xa6], false),
+    (0x54c0, &[0xb0, 0xa7], false),
+    (0x7691, &[0xb0, 0xa8], false),
+    (0x764c, &[0xb0, 0xa9], false),
+    (0x853c, &[0xb0, 0xaa], false),
+    (0x77ee, &[0xb0, 0xab], false),
+    (0x827e, &[0xb0, 0xac], false),
+    (0x788d, &[0xb0, 0xad], false),
+    (0x7231, &[0xb0, 0xae], false),
+    (0x9698, &[0xb0, 0xaf], false),
+    (0x978d, &[0xb0, 0xb0], false),
+    (0x6c28, &[0xb0, 0xb1], false),
+    (0x5b89, &[0xb0, 0xb2], false),
+    (0x4ffa, &[0xb0, 0xb3], false),
+    (0x6309, &[0xb0, 0xb4], false),
+    (0x6697, &[0xb0, 0xb5], false),
+    (0x5cb8, &[0xb0, 0xb6], false),
+    (0x80fa, &[0xb0, 0xb7], false),
+    (0x6848, &[0xb0, 0xb8], false),
+    (0x80ae, &[0xb0, 0xb9], false),
+    (0x6602, &[0xb0, 0xba], false),
+    (0x76ce, &[0xb0, 0xbb], false),
+    (0x51f9, &[0xb0, 0xbc], false),
+    (0x6556, &[0xb0, 0xbd], false),
+    (0x71ac, &[0xb0, 0xbe], false),
+    (0x7ff1, &[0xb0, 0xbf], false),
+    (0x8884, &[0xb0, 0xc0], false),
+    (0x50b2, &[0xb0, 0xc1], false),
+    (0x5965, &[0xb0, 0xc2], false),
+    (0x61ca, &[0xb0, 0xc3], false),
+    (0x6fb3, &[0xb0, 0xc4], false),
+    (0x82ad, &[0xb0, 0xc5], false),
+    (0x634c, &[0xb0, 0xc6], false),
+    (0x6252, &[0xb0, 0xc7], false),
+    (0x53ed, &[0xb0, 0xc8], false),
+    (0x5427, &[0xb0, 0xc9], false),
+    (0x7b06, &[0xb0, 0xca], false),
+    (0x516b, &[0xb0, 0xcb], false),
+    (0x75a4, &[0xb0, 0xcc], false),
+    (0x5df4, &[0xb0, 0xcd], false),
+    (0x62d4, &[0xb0, 0xce], false),
+    (0x8dcb, &[0xb0, 0xcf], false),
+    (0x9776, &[0xb0, 0xd0], false),
+    (0x628a, &[0xb0, 0xd1], false),
+    (0x8019, &[0xb0, 0xd2], false),
+    (0x575d, &[0xb0, 0xd3], false),
+    (0x9738, &[0xb0, 0xd4], false),
+    (0x7f62, &[0xb0, 0xd5], false),
+    (0x7238, &[0xb0, 0xd6], false),
+    (0x767d, &[0xb0, 0xd7], false),
+    (0x67cf, &[0xb0, 0xd8], false),
+    (0x767e, &[0xb0, 0xd9], false),
+    (0x6446, &[0xb0, 0xda], false),
+    (0x4f70, &[0xb0, 0xdb], false),
+    (0x8d25, &[0xb0, 0xdc], false),
+    (0x62dc, &[0xb0, 0xdd], false),
+    (0x7a17, &[0xb0, 0xde], false),
+    (0x6591, &[0xb0, 0xdf], false),
+    (0x73ed, &[0xb0, 0xe0], false),
+    (0x642c, &[0xb0, 0xe1], false),
+    (0x6273, &[0xb0, 0xe2], false),
+    (0x822c, &[0xb0, 0xe3], false),
+    (0x9881, &[0xb0, 0xe4], false),
+    (0x677f, &[0xb0, 0xe5], false),
+    (0x7248, &[0xb0, 0xe6], false),
+    (0x626e, &[0xb0, 0xe7], false),
+    (0x62cc, &[0xb0, 0xe8], false),
+    (0x4f34, &[0xb0, 0xe9], false),
+    (0x74e3, &[0xb0, 0xea], false),
+    (0x534a, &[0xb0, 0xeb], false),
+    (0x529e, &[0xb0, 0xec], false),
+    (0x7eca, &[0xb0, 0xed], false),
+    (0x90a6, &[0xb0, 0xee], false),
+    (0x5e2e, &[0xb0, 0xef], false),
+    (0x6886, &[0xb0, 0xf0], false),
+    (0x699c, &[0xb0, 0xf1], false),
+    (0x8180, &[0xb0, 0xf2], false),
+    (0x7ed1, &[0xb0, 0xf3], false),
+    (0x68d2, &[0xb0, 0xf4], false),
+    (0x78c5, &[0xb0, 0xf5], false),
+    (0x868c, &[0xb0, 0xf6], false),
+    (0x9551, &[0xb0, 0xf7], false),
+    (0x508d, &[0xb0, 0xf8], false),
+    (0x8c24, &[0xb0, 0xf9], false),
+    (0x82de, &[0xb0, 0xfa], false),
+    (0x80de, &[0xb0, 0xfb], false),
+    (0x5305, &[0xb0, 0xfc], false),
+    (0x8912, &[0xb0, 0xfd], false),
+    (0x5265, &[0xb0, 0xfe], false),
+    (0x8584, &[0xb1, 0xa1], false),
+    (0x96f9, &[0xb1, 0xa2], false),
+    (0x4fdd, &[0xb1, 0xa3], false),
+    (0x5821, &[0xb1, 0xa4], false),
+    (0x9971, &[0xb1, 0xa5], false),
+    (0x5b9d, &[0xb1, 0xa6], false),
+    (0x62b1, &[0xb1, 0xa7], false),
+    (0x62a5, &[0xb1, 0xa8], false),
+    (0x66b4, &[0xb1, 0xa9], false),
+    (0x8c79, &[0xb1, 0xaa], false),
+    (0x9c8d, &[0xb1, 0xab], false),
+    (0x7206, &[0xb1, 0xac], false),
+    (0x676f, &[0xb1, 0xad], false),
+    (0x7891, &[0xb1, 0xae], false),
+    (0x60b2, &[0xb1, 0xaf], false),
+    (0x5351, &[0xb1, 0xb0], false),
+    (0x5317, &[0xb1, 0xb1], false),
+    (0x8f88, &[0xb1, 0xb2], false),
+    (0x80cc, &[0xb1, 0xb3], false),
+    (0x8d1d, &[0xb1, 0xb4], false),
+    (0x94a1, &[0xb1, 0xb5], false),
+    (0x500d, &[0xb1, 0xb6], false),
+    (0x72c8, &[0xb1, 0xb7], false),
+    (0x5907, &[0xb1, 0xb8], false),
+    (0x60eb, &[0xb1, 0xb9], false),
+    (0x7119, &[0xb1, 0xba], false),
+    (0x88ab, &[0xb1, 0xbb], false),
+    (0x5954, &[0xb1, 0xbc], false),
+    (0x82ef, &[0xb1, 0xbd], false),
+    (0x672c, &[0xb1, 0xbe], false),
+    (0x7b28, &[0xb1, 0xbf], false),
+    (0x5d29, &[0xb1, 0xc0], false),
+    (0x7ef7, &[0xb1, 0xc1], false),
+    (0x752d, &[0xb1, 0xc2], false),
+    (0x6cf5, &[0xb1, 0xc3], false),
+    (0x8e66, &[0xb1, 0xc4], false),
+    (0x8ff8, &[0xb1, 0xc5], false),
+    (0x903c, &[0xb1, 0xc6], false),
+    (0x9f3b, &[0xb1, 0xc7], false),
+    (0x6bd4, &[0xb1, 0xc8], false),
+    (0x9119, &[0xb1, 0xc9], false),
+    (0x7b14, &[0xb1, 0xca], false),
+    (0x5f7c, &[0xb1, 0xcb], false),
+    (0x78a7, &[0xb1, 0xcc], false),
+    (0x84d6, &[0xb1, 0xcd], false),
+    (0x853d, &[0xb1, 0xce], false),
+    (0x6bd5, &[0xb1, 0xcf], false),
+    (0x6bd9, &[0xb1, 0xd0], false),
+    (0x6bd6, &[0xb1, 0xd1], false),
+    (0x5e01, &[0xb1, 0xd2], false),
+    (0x5e87, &[0xb1, 0xd3], false),
+    (0x75f9, &[0xb1, 0xd4], false),
+    (0x95ed, &[0xb1, 0xd5], false),
+    (0x655d, &[0xb1, 0xd6], false),
+    (0x5f0a, &[0xb1, 0xd7], false),
+    (0x5fc5, &[0xb1, 0xd8], false),
+    (0x8f9f, &[0xb1, 0xd9], false),
+    (0x58c1, &[0xb1, 0xda], false),
+    (0x81c2, &[0xb1, 0xdb], false),
+    (0x907f, &[0xb1, 0xdc], false),
+    (0x965b, &[0xb1, 0xdd], false),
+    (0x97ad, &[0xb1, 0xde], false),
+    (0x8fb9, &[0xb1, 0xdf], false),
+    (0x7f16, &[0xb1, 0xe0], false),
+    (0x8d2c, &[0xb1, 0xe1], false),
+    (0x6241, &[0xb1, 0xe2], false),
+    (0x4fbf, &[0xb1, 0xe3], false),
+    (0x53d8, &[0xb1, 0xe4], false),
+    (0x535e, &[0xb1, 0xe5], false),
+    (0x8fa8, &[0xb1, 0xe6], false),
+    (0x8fa9, &[0xb1, 0xe7], false),
+    (0x8fab, &[0xb1, 0xe8], false),
+    (0x904d, &[0xb1, 0xe9], false),
+    (0x6807, &[0xb1, 0xea], false),
+    (0x5f6a, &[0xb1, 0xeb], false),
+    (0x8198, &[0xb1, 0xec], false),
+    (0x8868, &[0xb1, 0xed], false),
+    (0x9cd6, &[0xb1, 0xee], false),
+    (0x618b, &[0xb1, 0xef], false),
+    (0x522b, &[0xb1, 0xf0], false),
+    (0x762a, &[0xb1, 0xf1], false),
+    (0x5f6c, &[0xb1, 0xf2], false),
+    (0x658c, &[0xb1, 0xf3], false),
+    (0x6fd2, &[0xb1, 0xf4], false),
+    (0x6ee8, &[0xb1, 0xf5], false),
+    (0x5bbe, &[0xb1, 0xf6], false),
+    (0x6448, &[0xb1, 0xf7], false),
+    (0x5175, &[0xb1, 0xf8], false),
+    (0x51b0, &[0xb1, 0xf9], false),
+    (0x67c4, &[0xb1, 0xfa], false),
+    (0x4e19, &[0xb1, 0xfb], false),
+    (0x79c9, &[0xb1, 0xfc], false),
+    (0x997c, &[0xb1, 0xfd], false),
+    (0x70b3, &[0xb1, 0xfe], false),
+    (0x75c5, &[0xb2, 0xa1], false),
+    (0x5e76, &[0xb2, 0xa2], false),
+    (0x73bb, &[0xb2, 0xa3], false),
+    (0x83e0, &[0xb2, 0xa4], false),
+    (0x64ad, &[0xb2, 0xa5], false),
+    (0x62e8, &[0xb2, 0xa6], false),
+    (0x94b5, &[0xb2, 0xa7], false),
+    (0x6ce2, &[0xb2, 0xa8], false),
+    (0x535a, &[0xb2, 0xa9], false),
+    (0x52c3, &[0xb2, 0xaa], false),
+    (0x640f, &[0xb2, 0xab], false),
+    (0x94c2, &[0xb2, 0xac], false),
+    (0x7b94, &[0xb2, 0xad], false),
+    (0x4f2f, &[0xb2, 0xae], false),
+    (0x5e1b, &[0xb2, 0xaf], false),
+    (0x8236, &[0xb2, 0xb0], false),
+    (0x8116, &[0xb2, 0xb1], false),
+    (0x818a, &[0xb2, 0xb2], false),
+    (0x6e24, &[0xb2, 0xb3], false),
+    (0x6cca, &[0xb2, 0xb4], false),
+    (0x9a73, &[0xb2, 0xb5], false),
+    (0x6355, &[0xb2, 0xb6], false),
+    (0x535c, &[0xb2, 0xb7], false),
+    (0x54fa, &[0xb2, 0xb8], false),
+    (0x8865, &[0xb2, 0xb9], false),
+    (0x57e0, &[0xb2, 0xba], false),
+    (0x4e0d, &[0xb2, 0xbb], false),
+    (0x5e03, &[0xb2, 0xbc], false),
+    (0x6b65, &[0xb2, 0xbd], false),
+    (0x7c3f, &[0xb2, 0xbe], false),
+    (0x90e8, &[0xb2, 0xbf], false),
+    (0x6016, &[0xb2, 0xc0], false),
+    (0x64e6, &[0xb2, 0xc1], false),
+    (0x731c, &[0xb2, 0xc2], false),
+    (0x88c1, &[0xb2, 0xc3], false),
+    (0x6750, &[0xb2, 0xc4], false),
+    (0x624d, &[0xb2, 0xc5], false),
+    (0x8d22, &[0xb2, 0xc6], false),
+    (0x776c, &[0xb2, 0xc7], false),
+    (0x8e29, &[0xb2, 0xc8], false),
+    (0x91c7, &[0xb2, 0xc9], false),
+    (0x5f69, &[0xb2, 0xca], false),
+    (0x83dc, &[0xb2, 0xcb], false),
+    (0x8521, &[0xb2, 0xcc], false),
+    (0x9910, &[0xb2, 0xcd], false),
+    (0x53c2, &[0xb2, 0xce], false),
+    (0x8695, &[0xb2, 0xcf], false),
+    (0x6b8b, &[0xb2, 0xd0], false),
+    (0x60ed, &[0xb2, 0xd1], false),
+    (0x60e8, &[0xb2, 0xd2], false),
+    (0x707f, &[0xb2, 0xd3], false),
+    (0x82cd, &[0xb2, 0xd4], false),
+    (0x8231, &[0xb2, 0xd5], false),
+    (0x4ed3, &[0xb2, 0xd6], false),
+    (0x6ca7, &[0xb2, 0xd7], false),
+    (0x85cf, &[0xb2, 0xd8], false),
+    (0x64cd, &[0xb2, 0xd9], false),
+    (0x7cd9, &[0xb2, 0xda], false),
+    (0x69fd, &[0xb2, 0xdb], false),
+    (0x66f9, &[0xb2, 0xdc], false),
+    (0x8349, &[0xb2, 0xdd], false),
+    (0x5395, &[0xb2, 0xde], false),
+    (0x7b56, &[0xb2, 0xdf], false),
+    (0x4fa7, &[0xb2, 0xe0], false),
+    (0x518c, &[0xb2, 0xe1], false),
+    (0x6d4b, &[0xb2, 0xe2], false),
+    (0x5c42, &[0xb2, 0xe3], false),
+    (0x8e6d, &[0xb2, 0xe4], false),
+    (0x63d2, &[0xb2, 0xe5], false),
+    (0x53c9, &[0xb2, 0xe6], false),
+    (0x832c, &[0xb2, 0xe7], false),
+    (0x8336, &[0xb2, 0xe8], false),
+    (0x67e5, &[0xb2, 0xe9], false),
+    (0x78b4, &[0xb2, 0xea], false),
+    (0x643d, &[0xb2, 0xeb], false),
+    (0x5bdf, &[0xb2, 0xec], false),
+    (0x5c94, &[0xb2, 0xed], false),
+    (0x5dee, &[0xb2, 0xee], false),
+    (0x8be7, &[0xb2, 0xef], false),
+    (0x62c6, &[0xb2, 0xf0], false),
+    (0x67f4, &[0xb2, 0xf1], false),
+    (0x8c7a, &[0xb2, 0xf2], false),
+    (0x6400, &[0xb2, 0xf3], false),
+    (0x63ba, &[0xb2, 0xf4], false),
+    (0x8749, &[0xb2, 0xf5], false),
+    (0x998b, &[0xb2, 0xf6], false),
+    (0x8c17, &[0xb2, 0xf7], false),
+    (0x7f20, &[0xb2, 0xf8], false),
+    (0x94f2, &[0xb2, 0xf9], false),
+    (0x4ea7, &[0xb2, 0xfa], false),
+    (0x9610, &[0xb2, 0xfb], false),
+    (0x98a4, &[0xb2, 0xfc], false),
+    (0x660c, &[0xb2, 0xfd], false),
+    (0x7316, &[0xb2, 0xfe], false),
+    (0x573a, &[0xb3, 0xa1], false),
+    (0x5c1d, &[0xb3, 0xa2], false),
+    (0x5e38, &[0xb3, 0xa3], false),
+    (0x957f, &[0xb3, 0xa4], false),
+    (0x507f, &[0xb3, 0xa5], false),
+    (0x80a0, &[0xb3, 0xa6], false),
+    (0x5382, &[0xb3, 0xa7], false),
+    (0x655e, &[0xb3, 0xa8], false),
+    (0x7545, &[0xb3, 0xa9], false),
+    (0x5531, &[0xb3, 0xaa], false),
+    (0x5021, &[0xb3, 0xab], false),
+    (0x8d85, &[0xb3, 0xac], false),
+    (0x6284, &[0xb3, 0xad], false),
+    (0x949e, &[0xb3, 0xae], false),
+    (0x671d, &[0xb3, 0xaf], false),
+    (0x5632, &[0xb3, 0xb0], false),
+    (0x6f6e, &[0xb3, 0xb1], false),
+    (0x5de2, &[0xb3, 0xb2], false),
+    (0x5435, &[0xb3, 0xb3], false),
+    (0x7092, &[0xb3, 0xb4], false),
+    (0x8f66, &[0xb3, 0xb5], false),
+    (0x626f, &[0xb3, 0xb6], false),
+    (0x64a4, &[0xb3, 0xb7], false),
+    (0x63a3, &[0xb3, 0xb8], false),
+    (0x5f7b, &[0xb3, 0xb9], false),
+    (0x6f88, &[0xb3, 0xba], false),
+    (0x90f4, &[0xb3, 0xbb], false),
+    (0x81e3, &[0xb3, 0xbc], false),
+    (0x8fb0, &[0xb3, 0xbd], false),
+    (0x5c18, &[0xb3, 0xbe], false),
+    (0x6668, &[0xb3, 0xbf], false),
+    (0x5ff1, &[0xb3, 0xc0], false),
+    (0x6c89, &[0xb3, 0xc1], false),
+    (0x9648, &[0xb3, 0xc2], false),
+    (0x8d81, &[0xb3, 0xc3], false),
+    (0x886c, &[0xb3, 0xc4], false),
+    (0x6491, &[0xb3, 0xc5], false),
+    (0x79f0, &[0xb3, 0xc6], false),
+    (0x57ce, &[0xb3, 0xc7], false),
+    (0x6a59, &[0xb3, 0xc8], false),
+    (0x6210, &[0xb3, 0xc9], false),
+    (0x5448, &[0xb3, 0xca], false),
+    (0x4e58, &[0xb3, 0xcb], false),
+    (0x7a0b, &[0xb3, 0xcc], false),
+    (0x60e9, &[0xb3, 0xcd], false),
+    (0x6f84, &[0xb3, 0xce], false),
+    (0x8bda, &[0xb3, 0xcf], false),
+    (0x627f, &[0xb3, 0xd0], false),
+    (0x901e, &[0xb3, 0xd1], false),
+    (0x9a8b, &[0xb3, 0xd2], false),
+    (0x79e4, &[0xb3, 0xd3], false),
+    (0x5403, &[0xb3, 0xd4], false),
+    (0x75f4, &[0xb3, 0xd5], false),
+    (0x6301, &[0xb3, 0xd6], false),
+    (0x5319, &[0xb3, 0xd7], false),
+    (0x6c60, &[0xb3, 0xd8], false),
+    (0x8fdf, &[0xb3, 0xd9], false),
+    (0x5f1b, &[0xb3, 0xda], false),
+    (0x9a70, &[0xb3, 0xdb], false),
+    (0x803b, &[0xb3, 0xdc], false),
+    (0x9f7f, &[0xb3, 0xdd], false),
+    (0x4f88, &[0xb3, 0xde], false),
+    (0x5c3a, &[0xb3, 0xdf], false),
+    (0x8d64, &[0xb3, 0xe0], false),
+    (0x7fc5, &[0xb3, 0xe1], false),
+    (0x65a5, &[0xb3, 0xe2], false),
+    (0x70bd, &[0xb3, 0xe3], false),
+    (0x5145, &[0xb3, 0xe4], false),
+    (0x51b2, &[0xb3, 0xe5], false),
+    (0x866b, &[0xb3, 0xe6], false),
+    (0x5d07, &[0xb3, 0xe7], false),
+    (0x5ba0, &[0xb3, 0xe8], false),
+    (0x62bd, &[0xb3, 0xe9], false),
+    (0x916c, &[0xb3, 0xea], false),
+    (0x7574, &[0xb3, 0xeb], false),
+    (0x8e0c, &[0xb3, 0xec], false),
+    (0x7a20, &[0xb3, 0xed], false),
+    (0x6101, &[0xb3, 0xee], false),
+    (0x7b79, &[0xb3, 0xef], false),
+    (0x4ec7, &[0xb3, 0xf0], false),
+    (0x7ef8, &[0xb3, 0xf1], false),
+    (0x7785, &[0xb3, 0xf2], false),
+    (0x4e11, &[0xb3, 0xf3], false),
+    (0x81ed, &[0xb3, 0xf4], false),
+    (0x521d, &[0xb3, 0xf5], false),
+    (0x51fa, &[0xb3, 0xf6], false),
+    (0x6a71, &[0xb3, 0xf7], false),
+    (0x53a8, &[0xb3, 0xf8], false),
+    (0x8e87, &[0xb3, 0xf9], false),
+    (0x9504, &[0xb3, 0xfa], false),
+    (0x96cf, &[0xb3, 0xfb], false),
+    (0x6ec1, &[0xb3, 0xfc], false),
+    (0x9664, &[0xb3, 0xfd], false),
+    (0x695a, &[0xb3, 0xfe], false),
+    (0x7840, &[0xb4, 0xa1], false),
+    (0x50a8, &[0xb4, 0xa2], false),
+    (0x77d7, &[0xb4, 0xa3], false),
+    (0x6410, &[0xb4, 0xa4], false),
+    (0x89e6, &[0xb4, 0xa5], false),
+    (0x5904, &[0xb4, 0xa6], false),
+    (0x63e3, &[0xb4, 0xa7], false),
+    (0x5ddd, &[0xb4, 0xa8], false),
+    (0x7a7f, &[0xb4, 0xa9], false),
+    (0x693d, &[0xb4, 0xaa], false),
+    (0x4f20, &[0xb4, 0xab], false),
+    (0x8239, &[0xb4, 0xac], false),
+    (0x5598, &[0xb4, 0xad], false),
+    (0x4e32, &[0xb4, 0xae], false),
+    (0x75ae, &[0xb4, 0xaf], false),
+    (0x7a97, &[0xb4, 0xb0], false),
+    (0x5e62, &[0xb4, 0xb1], false),
+    (0x5e8a, &[0xb4, 0xb2], false),
+    (0x95ef, &[0xb4, 0xb3], false),
+    (0x521b, &[0xb4, 0xb4], false),
+    (0x5439, &[0xb4, 0xb5], false),
+    (0x708a, &[0xb4, 0xb6], false),
+    (0x6376, &[0xb4, 0xb7], false),
+    (0x9524, &[0xb4, 0xb8], false),
+    (0x5782, &[0xb4, 0xb9], false),
+    (0x6625, &[0xb4, 0xba], false),
+    (0x693f, &[0xb4, 0xbb], false),
+    (0x9187, &[0xb4, 0xbc], false),
+    (0x5507, &[0xb4, 0xbd], false),
+    (0x6df3, &[0xb4, 0xbe], false),
+    (0x7eaf, &[0xb4, 0xbf], false),
+    (0x8822, &[0xb4, 0xc0], false),
+    (0x6233, &[0xb4, 0xc1], false),
+    (0x7ef0, &[0xb4, 0xc2], false),
+    (0x75b5, &[0xb4, 0xc3], false),
+    (0x8328, &[0xb4, 0xc4], false),
+    (0x78c1, &[0xb4, 0xc5], false),
+    (0x96cc, &[0xb4, 0xc6], false),
+    (0x8f9e, &[0xb4, 0xc7], false),
+    (0x6148, &[0xb4, 0xc8], false),
+    (0x74f7, &[0xb4, 0xc9], false),
+    (0x8bcd, &[0xb4, 0xca], false),
+    (0x6b64, &[0xb4, 0xcb], false),
+    (0x523a, &[0xb4, 0xcc], false),
+    (0x8d50, &[0xb4, 0xcd], false),
+    (0x6b21, &[0xb4, 0xce], false),
+    (0x806a, &[0xb4, 0xcf], false),
+    (0x8471, &[0xb4, 0xd0], false),
+    (0x56f1, &[0xb4, 0xd1], false),
+    (0x5306, &[0xb4, 0xd2], false),
+    (0x4ece, &[0xb4, 0xd3], false),
+    (0x4e1b, &[0xb4, 0xd4], false),
+    (0x51d1, &[0xb4, 0xd5], false),
+    (0x7c97, &[0xb4, 0xd6], false),
+    (0x918b, &[0xb4, 0xd7], false),
+    (0x7c07, &[0xb4, 0xd8], false),
+    (0x4fc3, &[0xb4, 0xd9], false),
+    (0x8e7f, &[0xb4, 0xda], false),
+    (0x7be1, &[0xb4, 0xdb], false),
+    (0x7a9c, &[0xb4, 0xdc], false),
+    (0x6467, &[0xb4, 0xdd], false),
+    (0x5d14, &[0xb4, 0xde], false),
+    (0x50ac, &[0xb4, 0xdf], false),
+    (0x8106, &[0xb4, 0xe0], false),
+    (0x7601, &[0xb4, 0xe1], false),
+    (0x7cb9, &[0xb4, 0xe2], false),
+    (0x6dec, &[0xb4, 0xe3], false),
+    (0x7fe0, &[0xb4, 0xe4], false),
+    (0x6751, &[0xb4, 0xe5], false),
+    (0x5b58, &[0xb4, 0xe6], false),
+    (0x5bf8, &[0xb4, 0xe7], false),
+    (0x78cb, &[0xb4, 0xe8], false),
+    (0x64ae, &[0xb4, 0xe9], false),
+    (0x6413, &[0xb4, 0xea], false),
+    (0x63aa, &[0xb4, 0xeb], false),
+    (0x632b, &[0xb4, 0xec], false),
+    (0x9519, &[0xb4, 0xed], false),
+    (0x642d, &[0xb4, 0xee], false),
+    (0x8fbe, &[0xb4, 0xef], false),
+    (0x7b54, &[0xb4, 0xf0], false),
+    (0x7629, &[0xb4, 0xf1], false),
+    (0x6253, &[0xb4, 0xf2], false),
+    (0x5927, &[0xb4, 0xf3], false),
+    (0x5446, &[0xb4, 0xf4], false),
+    (0x6b79, &[0xb4, 0xf5], false),
+    (0x50a3, &[0xb4, 0xf6], false),
+    (0x6234, &[0xb4, 0xf7], false),
+    (0x5e26, &[0xb4, 0xf8], false),
+    (0x6b86, &[0xb4, 0xf9], false),
+    (0x4ee3, &[0xb4, 0xfa], false),
+    (0x8d37, &[0xb4, 0xfb], false),
+    (0x888b, &[0xb4, 0xfc], false),
+    (0x5f85, &[0xb4, 0xfd], false),
+    (0x902e, &[0xb4, 0xfe], false),
+    (0x6020, &[0xb5, 0xa1], false),
+    (0x803d, &[0xb5, 0xa2], false),
+    (0x62c5, &[0xb5, 0xa3], false),
+    (0x4e39, &[0xb5, 0xa4], false),
+    (0x5355, &[0xb5, 0xa5], false),
+    (0x90f8, &[0xb5, 0xa6], false),
+    (0x63b8, &[0xb5, 0xa7], false),
+    (0x80c6, &[0xb5, 0xa8], false),
+    (0x65e6, &[0xb5, 0xa9], false),
+    (0x6c2e, &[0xb5, 0xaa], false),
+    (0x4f46, &[0xb5, 0xab], false),
+    (0x60ee, &[0xb5, 0xac], false),
+    (0x6de1, &[0xb5, 0xad], false),
+    (0x8bde, &[0xb5, 0xae], false),
+    (0x5f39, &[0xb5, 0xaf], false),
+    (0x86cb, &[0xb5, 0xb0], false),
+    (0x5f53, &[0xb5, 0xb1], false),
+    (0x6321, &[0xb5, 0xb2], false),
+    (0x515a, &[0xb5, 0xb3], false),
+    (0x8361, &[0xb5, 0xb4], false),
+    (0x6863, &[0xb5, 0xb5], false),
+    (0x5200, &[0xb5, 0xb6], false),
+    (0x6363, &[0xb5, 0xb7], false),
+    (0x8e48, &[0xb5, 0xb8], false),
+    (0x5012, &[0xb5, 0xb9], false),
+    (0x5c9b, &[0xb5, 0xba], false),
+    (0x7977, &[0xb5, 0xbb], false),
+    (0x5bfc, &[0xb5, 0xbc], false),
+    (0x5230, &[0xb5, 0xbd], false),
+    (0x7a3b, &[0xb5, 0xbe], false),
+    (0x60bc, &[0xb5, 0xbf], false),
+    (0x9053, &[0xb5, 0xc0], false),
+    (0x76d7, &[0xb5, 0xc1], false),
+    (0x5fb7, &[0xb5, 0xc2], false),
+    (0x5f97, &[0xb5, 0xc3], false),
+    (0x7684, &[0xb5, 0xc4], false),
+    (0x8e6c, &[0xb5, 0xc5], false),
+    (0x706f, &[0xb5, 0xc6], false),
+    (0x767b, &[0xb5, 0xc7], false),
+    (0x7b49, &[0xb5, 0xc8], false),
+    (0x77aa, &[0xb5, 0xc9], false),
+    (0x51f3, &[0xb5, 0xca], false),
+    (0x9093, &[0xb5, 0xcb], false),
+    (0x5824, &[0xb5, 0xcc], false),
+    (0x4f4e, &[0xb5, 0xcd], false),
+    (0x6ef4, &[0xb5, 0xce], false),
+    (0x8fea, &[0xb5, 0xcf], false),
+    (0x654c, &[0xb5, 0xd0], false),
+    (0x7b1b, &[0xb5, 0xd1], false),
+    (0x72c4, &[0xb5, 0xd2], false),
+    (0x6da4, &[0xb5, 0xd3], false),
+    (0x7fdf, &[0xb5, 0xd4], false),
+    (0x5ae1, &[0xb5, 0xd5], false),
+    (0x62b5, &[0xb5, 0xd6], false),
+    (0x5e95, &[0xb5, 0xd7], false),
+    (0x5730, &[0xb5, 0xd8], false),
+    (0x8482, &[0xb5, 0xd9], false),
+    (0x7b2c, &[0xb5, 0xda], false),
+    (0x5e1d, &[0xb5, 0xdb], false),
+    (0x5f1f, &[0xb5, 0xdc], false),
+    (0x9012, &[0xb5, 0xdd], false),
+    (0x7f14, &[0xb5, 0xde], false),
+    (0x98a0, &[0xb5, 0xdf], false),
+    (0x6382, &[0xb5, 0xe0], false),
+    (0x6ec7, &[0xb5, 0xe1], false),
+    (0x7898, &[0xb5, 0xe2], false),
+    (0x70b9, &[0xb5, 0xe3], false),
+    (0x5178, &[0xb5, 0xe4], false),
+    (0x975b, &[0xb5, 0xe5], false),
+    (0x57ab, &[0xb5, 0xe6], false),
+    (0x7535, &[0xb5, 0xe7], false),
+    (0x4f43, &[0xb5, 0xe8], false),
+    (0x7538, &[0xb5, 0xe9], false),
+    (0x5e97, &[0xb5, 0xea], false),
+    (0x60e6, &[0xb5, 0xeb], false),
+    (0x5960, &[0xb5, 0xec], false),
+    (0x6dc0, &[0xb5, 0xed], false),
+    (0x6bbf, &[0xb5, 0xee], false),
+    (0x7889, &[0xb5, 0xef], false),
+    (0x53fc, &[0xb5, 0xf0], false),
+    (0x96d5, &[0xb5, 0xf1], false),
+    (0x51cb, &[0xb5, 0xf2], false),
+    (0x5201, &[0xb5, 0xf3], false),
+    (0x6389, &[0xb5, 0xf4], false),
+    (0x540a, &[0xb5, 0xf5], false),
+    (0x9493, &[0xb5, 0xf6], false),
+    (0x8c03, &[0xb5, 0xf7], false),
+    (0x8dcc, &[0xb5, 0xf8], false),
+    (0x7239, &[0xb5, 0xf9], false),
+    (0x789f, &[0xb5, 0xfa], false),
+    (0x8776, &[0xb5, 0xfb], false),
+    (0x8fed, &[0xb5, 0xfc], false),
+    (0x8c0d, &[0xb5, 0xfd], false),
+    (0x53e0, &[0xb5, 0xfe], false),
+    (0x4e01, &[0xb6, 0xa1], false),
+    (0x76ef, &[0xb6, 0xa2], false),
+    (0x53ee, &[0xb6, 0xa3], false),
+    (0x9489, &[0xb6, 0xa4], false),
+    (0x9876, &[0xb6, 0xa5], false),
+    (0x9f0e, &[0xb6, 0xa6], false),
+    (0x952d, &[0xb6, 0xa7], false),
+    (0x5b9a, &[0xb6, 0xa8], false),
+    (0x8ba2, &[0xb6, 0xa9], false),
+    (0x4e22, &[0xb6, 0xaa], false),
+    (0x4e1c, &[0xb6, 0xab], false),
+    (0x51ac, &[0xb6, 0xac], false),
+    (0x8463, &[0xb6, 0xad], false),
+    (0x61c2, &[0xb6, 0xae], false),
+    (0x52a8, &[0xb6, 0xaf], false),
+    (0x680b, &[0xb6, 0xb0], false),
+    (0x4f97, &[0xb6, 0xb1], false),
+    (0x606b, &[0xb6, 0xb2], false),
+    (0x51bb, &[0xb6, 0xb3], false),
+    (0x6d1e, &[0xb6, 0xb4], false),
+    (0x515c, &[0xb6, 0xb5], false),
+    (0x6296, &[0xb6, 0xb6], false),
+    (0x6597, &[0xb6, 0xb7], false),
+    (0x9661, &[0xb6, 0xb8], false),
+    (0x8c46, &[0xb6, 0xb9], false),
+    (0x9017, &[0xb6, 0xba], false),
+    (0x75d8, &[0xb6, 0xbb], false),
+    (0x90fd, &[0xb6, 0xbc], false),
+    (0x7763, &[0xb6, 0xbd], false),
+    (0x6bd2, &[0xb6, 0xbe], false),
+    (0x728a, &[0xb6, 0xbf], false),
+    (0x72ec, &[0xb6, 0xc0], false),
+    (0x8bfb, &[0xb6, 0xc1], false),
+    (0x5835, &[0xb6, 0xc2], false),
+    (0x7779, &[0xb6, 0xc3], false),
+    (0x8d4c, &[0xb6, 0xc4], false),
+    (0x675c, &[0xb6, 0xc5], false),
+    (0x9540, &[0xb6, 0xc6], false),
+    (0x809a, &[0xb6, 0xc7], false),
+    (0x5ea6, &[0xb6, 0xc8], false),
+    (0x6e21, &[0xb6, 0xc9], false),
+    (0x5992, &[0xb6, 0xca], false),
+    (0x7aef, &[0xb6, 0xcb], false),
+    (0x77ed, &[0xb6, 0xcc], false),
+    (0x953b, &[0xb6, 0xcd], false),
+    (0x6bb5, &[0xb6, 0xce], false),
+    (0x65ad, &[0xb6, 0xcf], false),
+    (0x7f0e, &[0xb6, 0xd0], false),
+    (0x5806, &[0xb6, 0xd1], false),
+    (0x5151, &[0xb6, 0xd2], false),
+    (0x961f, &[0xb6, 0xd3], false),
+    (0x5bf9, &[0xb6, 0xd4], false),
+    (0x58a9, &[0xb6, 0xd5], false),
+    (0x5428, &[0xb6, 0xd6], false),
+    (0x8e72, &[0xb6, 0xd7], false),
+    (0x6566, &[0xb6, 0xd8], false),
+    (0x987f, &[0xb6, 0xd9], false),
+    (0x56e4, &[0xb6, 0xda], false),
+    (0x949d, &[0xb6, 0xdb], false),
+    (0x76fe, &[0xb6, 0xdc], false),
+    (0x9041, &[0xb6, 0xdd], false),
+    (0x6387, &[0xb6, 0xde], false),
+    (0x54c6, &[0xb6, 0xdf], false),
+    (0x591a, &[0xb6, 0xe0], false),
+    (0x593a, &[0xb6, 0xe1], false),
+    (0x579b, &[0xb6, 0xe2], false),
+    (0x8eb2, &[0xb6, 0xe3], false),
+    (0x6735, &[0xb6, 0xe4], false),
+    (0x8dfa, &[0xb6, 0xe5], false),
+    (0x8235, &[0xb6, 0xe6], false),
+    (0x5241, &[0xb6, 0xe7], false),
+    (0x60f0, &[0xb6, 0xe8], false),
+    (0x5815, &[0xb6, 0xe9], false),
+    (0x86fe, &[0xb6, 0xea], false),
+    (0x5ce8, &[0xb6, 0xeb], false),
+    (0x9e45, &[0xb6, 0xec], false),
+    (0x4fc4, &[0xb6, 0xed], false),
+    (0x989d, &[0xb6, 0xee], false),
+    (0x8bb9, &[0xb6, 0xef], false),
+    (0x5a25, &[0xb6, 0xf0], false),
+    (0x6076, &[0xb6, 0xf1], false),
+    (0x5384, &[0xb6, 0xf2], false),
+    (0x627c, &[0xb6, 0xf3], false),
+    (0x904f, &[0xb6, 0xf4], false),
+    (0x9102, &[0xb6, 0xf5], false),
+    (0x997f, &[0xb6, 0xf6], false),
+    (0x6069, &[0xb6, 0xf7], false),
+    (0x800c, &[0xb6, 0xf8], false),
+    (0x513f, &[0xb6, 0xf9], false),
+    (0x8033, &[0xb6, 0xfa], false),
+    (0x5c14, &[0xb6, 0xfb], false),
+    (0x9975, &[0xb6, 0xfc], false),
+    (0x6d31, &[0xb6, 0xfd], false),
+    (0x4e8c, &[0xb6, 0xfe], false),
+    (0x8d30, &[0xb7, 0xa1], false),
+    (0x53d1, &[0xb7, 0xa2], false),
+    (0x7f5a, &[0xb7, 0xa3], false),
+    (0x7b4f, &[0xb7, 0xa4], false),
+    (0x4f10, &[0xb7, 0xa5], false),
+    (0x4e4f, &[0xb7, 0xa6], false),
+    (0x9600, &[0xb7, 0xa7], false),
+    (0x6cd5, &[0xb7, 0xa8], false),
+    (0x73d0, &[0xb7, 0xa9], false),
+    (0x85e9, &[0xb7, 0xaa], false),
+    (0x5e06, &[0xb7, 0xab], false),
+    (0x756a, &[0xb7, 0xac], false),
+    (0x7ffb, &[0xb7, 0xad], false),
+    (0x6a0a, &[0xb7, 0xae], false),
+    (0x77fe, &[0xb7, 0xaf], false),
+    (0x9492, &[0xb7, 0xb0], false),
+    (0x7e41, &[0xb7, 0xb1], false),
+    (0x51e1, &[0xb7, 0xb2], false),
+    (0x70e6, &[0xb7, 0xb3], false),
+    (0x53cd, &[0xb7, 0xb4], false),
+    (0x8fd4, &[0xb7, 0xb5], false),
+    (0x8303, &[0xb7, 0xb6], false),
+    (0x8d29, &[0xb7, 0xb7], false),
+    (0x72af, &[0xb7, 0xb8], false),
+    (0x996d, &[0xb7, 0xb9], false),
+    (0x6cdb, &[0xb7, 0xba], false),
+    (0x574a, &[0xb7, 0xbb], false),
+    (0x82b3, &[0xb7, 0xbc], false),
+    (0x65b9, &[0xb7, 0xbd], false),
+    (0x80aa, &[0xb7, 0xbe], false),
+    (0x623f, &[0xb7, 0xbf], false),
+    (0x9632, &[0xb7, 0xc0], false),
+    (0x59a8, &[0xb7, 0xc1], false),
+    (0x4eff, &[0xb7, 0xc2], false),
+    (0x8bbf, &[0xb7, 0xc3], false),
+    (0x7eba, &[0xb7, 0xc4], false),
+    (0x653e, &[0xb7, 0xc5], false),
+    (0x83f2, &[0xb7, 0xc6], false),
+    (0x975e, &[0xb7, 0xc7], false),
+    (0x5561, &[0xb7, 0xc8], false),
+    (0x98de, &[0xb7, 0xc9], false),
+    (0x80a5, &[0xb7, 0xca], false),
+    (0x532a, &[0xb7, 0xcb], false),
+    (0x8bfd, &[0xb7, 0xcc], false),
+    (0x5420, &[0xb7, 0xcd], false),
+    (0x80ba, &[0xb7, 0xce], false),
+    (0x5e9f, &[0xb7, 0xcf], false),
+    (0x6cb8, &[0xb7, 0xd0], false),
+    (0x8d39, &[0xb7, 0xd1], false),
+    (0x82ac, &[0xb7, 0xd2], false),
+    (0x915a, &[0xb7, 0xd3], false),
+    (0x5429, &[0xb7, 0xd4], false),
+    (0x6c1b, &[0xb7, 0xd5], false),
+    (0x5206, &[0xb7, 0xd6], false),
+    (0x7eb7, &[0xb7, 0xd7], false),
+    (0x575f, &[0xb7, 0xd8], false),
+    (0x711a, &[0xb7, 0xd9], false),
+    (0x6c7e, &[0xb7, 0xda], false),
+    (0x7c89, &[0xb7, 0xdb], false),
+    (0x594b, &[0xb7, 0xdc], false),
+    (0x4efd, &[0xb7, 0xdd], false),
+    (0x5fff, &[0xb7, 0xde], false),
+    (0x6124, &[0xb7, 0xdf], false),
+    (0x7caa, &[0xb7, 0xe0], false),
+    (0x4e30, &[0xb7, 0xe1], false),
+    (0x5c01, &[0xb7, 0xe2], false),
+    (0x67ab, &[0xb7, 0xe3], false),
+    (0x8702, &[0xb7, 0xe4], false),
+    (0x5cf0, &[0xb7, 0xe5], false),
+    (0x950b, &[0xb7, 0xe6], false),
+    (0x98ce, &[0xb7, 0xe7], false),
+    (0x75af, &[0xb7, 0xe8], false),
+    (0x70fd, &[0xb7, 0xe9], false),
+    (0x9022, &[0xb7, 0xea], false),
+    (0x51af, &[0xb7, 0xeb], false),
+    (0x7f1d, &[0xb7, 0xec], false),
+    (0x8bbd, &[0xb7, 0xed], false),
+    (0x5949, &[0xb7, 0xee], false),
+    (0x51e4, &[0xb7, 0xef], false),
+    (0x4f5b, &[0xb7, 0xf0], false),
+    (0x5426, &[0xb7, 0xf1], false),
+    (0x592b, &[0xb7, 0xf2], false),
+    (0x6577, &[0xb7, 0xf3], false),
+    (0x80a4, &[0xb7, 0xf4], false),
+    (0x5b75, &[0xb7, 0xf5], false),
+    (0x6276, &[0xb7, 0xf6], false),
+    (0x62c2, &[0xb7, 0xf7], false),
+    (0x8f90, &[0xb7, 0xf8], false),
+    (0x5e45, &[0xb7, 0xf9], false),
+    (0x6c1f, &[0xb7, 0xfa], false),
+    (0x7b26, &[0xb7, 0xfb], false),
+    (0x4f0f, &[0xb7, 0xfc], false),
+    (0x4fd8, &[0xb7, 0xfd], false),
+    (0x670d, &[0xb7, 0xfe], false),
+    (0x6d6e, &[0xb8, 0xa1], false),
+    (0x6daa, &[0xb8, 0xa2], false),
+    (0x798f, &[0xb8, 0xa3], false),
+    (0x88b1, &[0xb8, 0xa4], false),
+    (0x5f17, &[0xb8, 0xa5], false),
+    (0x752b, &[0xb8, 0xa6], false),
+    (0x629a, &[0xb8, 0xa7], false),
+    (0x8f85, &[0xb8, 0xa8], false),
+    (0x4fef, &[0xb8, 0xa9], false),
+    (0x91dc, &[0xb8, 0xaa], false),
+    (0x65a7, &[0xb8, 0xab], false),
+    (0x812f, &[0xb8, 0xac], false),
+    (0x8151, &[0xb8, 0xad], false),
+    (0x5e9c, &[0xb8, 0xae], false),
+    (0x8150, &[0xb8, 0xaf], false),
+    (0x8d74, &[0xb8, 0xb0], false),
+    (0x526f, &[0xb8, 0xb1], false),
+    (0x8986, &[0xb8, 0xb2], false),
+    (0x8d4b, &[0xb8, 0xb3], false),
+    (0x590d, &[0xb8, 0xb4], false),
+    (0x5085, &[0xb8, 0xb5], false),
+    (0x4ed8, &[0xb8, 0xb6], false),
+    (0x961c, &[0xb8, 0xb7], false),
+    (0x7236, &[0xb8, 0xb8], false),
+    (0x8179, &[0xb8, 0xb9], false),
+    (0x8d1f, &[0xb8, 0xba], false),
+    (0x5bcc, &[0xb8, 0xbb], false),
+    (0x8ba3, &[0xb8, 0xbc], false),
+    (0x9644, &[0xb8, 0xbd], false),
+    (0x5987, &[0xb8, 0xbe], false),
+    (0x7f1a, &[0xb8, 0xbf], false),
+    (0x5490, &[0xb8, 0xc0], false),
+    (0x5676, &[0xb8, 0xc1], false),
+    (0x560e, &[0xb8, 0xc2], false),
+    (0x8be5, &[0xb8, 0xc3], false),
+    (0x6539, &[0xb8, 0xc4], false),
+    (0x6982, &[0xb8, 0xc5], false),
+    (0x9499, &[0xb8, 0xc6], false),
+    (0x76d6, &[0xb8, 0xc7], false),
+    (0x6e89, &[0xb8, 0xc8], false),
+    (0x5e72, &[0xb8, 0xc9], false),
+    (0x7518, &[0xb8, 0xca], false),
+    (0x6746, &[0xb8, 0xcb], false),
+    (0x67d1, &[0xb8, 0xcc], false),
+    (0x7aff, &[0xb8, 0xcd], false),
+    (0x809d, &[0xb8, 0xce], false),
+    (0x8d76, &[0xb8, 0xcf], false),
+    (0x611f, &[0xb8, 0xd0], false),
+    (0x79c6, &[0xb8, 0xd1], false),
+    (0x6562, &[0xb8, 0xd2], false),
+    (0x8d63, &[0xb8, 0xd3], false),
+    (0x5188, &[0xb8, 0xd4], false),
+    (0x521a, &[0xb8, 0xd5], false),
+    (0x94a2, &[0xb8, 0xd6], false),
+    (0x7f38, &[0xb8, 0xd7], false),
+    (0x809b, &[0xb8, 0xd8], false),
+    (0x7eb2, &[0xb8, 0xd9], false),
+    (0x5c97, &[0xb8, 0xda], false),
+    (0x6e2f, &[0xb8, 0xdb], false),
+    (0x6760, &[0xb8, 0xdc], false),
+    (0x7bd9, &[0xb8, 0xdd], false),
+    (0x768b, &[0xb8, 0xde], false),
+    (0x9ad8, &[0xb8, 0xdf], false),
+    (0x818f, &[0xb8, 0xe0], false),
+    (0x7f94, &[0xb8, 0xe1], false),
+    (0x7cd5, &[0xb8, 0xe2], false),
+    (0x641e, &[0xb8, 0xe3], false),
+    (0x9550, &[0xb8, 0xe4], false),
+    (0x7a3f, &[0xb8, 0xe5], false),
+    (0x544a, &[0xb8, 0xe6], false),
+    (0x54e5, &[0xb8, 0xe7], false),
+    (0x6b4c, &[0xb8, 0xe8], false),
+    (0x6401, &[0xb8, 0xe9], false),
+    (0x6208, &[0xb8, 0xea], false),
+    (0x9e3d, &[0xb8, 0xeb], false),
+    (0x80f3, &[0xb8, 0xec], false),
+    (0x7599, &[0xb8, 0xed], false),
+    (0x5272, &[0xb8, 0xee], false),
+    (0x9769, &[0xb8, 0xef], false),
+    (0x845b, &[0xb8, 0xf0], false),
+    (0x683c, &[0xb8, 0xf1], false),
+    (0x86e4, &[0xb8, 0xf2], false),
+    (0x9601, &[0xb8, 0xf3], false),
+    (0x9694, &[0xb8, 0xf4], false),
+    (0x94ec, &[0xb8, 0xf5], false),
+    (0x4e2a, &[0xb8, 0xf6], false),
+    (0x5404, &[0xb8, 0xf7], false),
+    (0x7ed9, &[0xb8, 0xf8], false),
+    (0x6839, &[0xb8, 0xf9], false),
+    (0x8ddf, &[0xb8, 0xfa], false),
+    (0x8015, &[0xb8, 0xfb], false),
+    (0x66f4, &[0xb8, 0xfc], false),
+    (0x5e9a, &[0xb8, 0xfd], false),
+    (0x7fb9, &[0xb8, 0xfe], false),
+    (0x57c2, &[0xb9, 0xa1], false),
+    (0x803f, &[0xb9, 0xa2], false),
+    (0x6897, &[0xb9, 0xa3], false),
+    (0x5de5, &[0xb9, 0xa4], false),
+    (0x653b, &[0xb9, 0xa5], false),
+    (0x529f, &[0xb9, 0xa6], false),
+    (0x606d, &[0xb9, 0xa7], false),
+    (0x9f9a, &[0xb9, 0xa8], false),
+    (0x4f9b, &[0xb9, 0xa9], false),
+    (0x8eac, &[0xb9, 0xaa], false),
+    (0x516c, &[0xb9, 0xab], false),
+    (0x5bab, &[0xb9, 0xac], false),
+    (0x5f13, &[0xb9, 0xad], false),
+    (0x5de9, &[0xb9, 0xae], false),
+    (0x6c5e, &[0xb9, 0xaf], false),
+    (0x62f1, &[0xb9, 0xb0], false),
+    (0x8d21, &[0xb9, 0xb1], false),
+    (0x5171, &[0xb9, 0xb2], false),
+    (0x94a9, &[0xb9, 0xb3], false),
+    (0x52fe, &[0xb9, 0xb4], false),
+    (0x6c9f, &[0xb9, 0xb5], false),
+    (0x82df, &[0xb9, 0xb6], false),
+    (0x72d7, &[0xb9, 0xb7], false),
+    (0x57a2, &[0xb9, 0xb8], false),
+    (0x6784, &[0xb9, 0xb9], false),
+    (0x8d2d, &[0xb9, 0xba], false),
+    (0x591f, &[0xb9, 0xbb], false),
+    (0x8f9c, &[0xb9, 0xbc], false),
+    (0x83c7, &[0xb9, 0xbd], false),
+    (0x5495, &[0xb9, 0xbe], false),
+    (0x7b8d, &[0xb9, 0xbf], false),
+    (0x4f30, &[0xb9, 0xc0], false),
+    (0x6cbd, &[0xb9, 0xc1], false),
+    (0x5b64, &[0xb9, 0xc2], false),
+    (0x59d1, &[0xb9, 0xc3], false),
+    (0x9f13, &[0xb9, 0xc4], false),
+    (0x53e4, &[0xb9, 0xc5], false),
+    (0x86ca, &[0xb9, 0xc6], false),
+    (0x9aa8, &[0xb9, 0xc7], false),
+    (0x8c37, &[0xb9, 0xc8], false),
+    (0x80a1, &[0xb9, 0xc9], false),
+    (0x6545, &[0xb9, 0xca], false),
+    (0x987e, &[0xb9, 0xcb], false),
+    (0x56fa, &[0xb9, 0xcc], false),
+    (0x96c7, &[0xb9, 0xcd], false),
+    (0x522e, &[0xb9, 0xce], false),
+    (0x74dc, &[0xb9, 0xcf], false),
+    (0x5250, &[0xb9, 0xd0], false),
+    (0x5be1, &[0xb9, 0xd1], false),
+    (0x6302, &[0xb9, 0xd2], false),
+    (0x8902, &[0xb9, 0xd3], false),
+    (0x4e56, &[0xb9, 0xd4], false),
+    (0x62d0, &[0xb9, 0xd5], false),
+    (0x602a, &[0xb9, 0xd6], false),
+    (0x68fa, &[0xb9, 0xd7], false),
+    (0x5173, &[0xb9, 0xd8], false),
+    (0x5b98, &[0xb9, 0xd9], false),
+    (0x51a0, &[0xb9, 0xda], false),
+    (0x89c2, &[0xb9, 0xdb], false),
+    (0x7ba1, &[0xb9, 0xdc], false),
+    (0x9986, &[0xb9, 0xdd], false),
+    (0x7f50, &[0xb9, 0xde], false),
+    (0x60ef, &[0xb9, 0xdf], false),
+    (0x704c, &[0xb9, 0xe0], false),
+    (0x8d2f, &[0xb9, 0xe1], false),
+    (0x5149, &[0xb9, 0xe2], false),
+    (0x5e7f, &[0xb9, 0xe3], false),
+    (0x901b, &[0xb9, 0xe4], false),
+    (0x7470, &[0xb9, 0xe5], false),
+    (0x89c4, &[0xb9, 0xe6], false),
+    (0x572d, &[0xb9, 0xe7], false),
+    (0x7845, &[0xb9, 0xe8], false),
+    (0x5f52, &[0xb9, 0xe9], false),
+    (0x9f9f, &[0xb9, 0xea], false),
+    (0x95fa, &[0xb9, 0xeb], false),
+    (0x8f68, &[0xb9, 0xec], false),
+    (0x9b3c, &[0xb9, 0xed], false),
+    (0x8be1, &[0xb9, 0xee], false),
+    (0x7678, &[0xb9, 0xef], false),
+    (0x6842, &[0xb9, 0xf0], false),
+    (0x67dc, &[0xb9, 0xf1], false),
+    (0x8dea, &[0xb9, 0xf2], false),
+    (0x8d35, &[0xb9, 0xf3], false),
+    (0x523d, &[0xb9, 0xf4], false),
+    (0x8f8a, &[0xb9, 0xf5], false),
+    (0x6eda, &[0xb9, 0xf6], false),
+    (0x68cd, &[0xb9, 0xf7], false),
+    (0x9505, &[0xb9, 0xf8], false),
+    (0x90ed, &[0xb9, 0xf9], false),
+    (0x56fd, &[0xb9, 0xfa], false),
+    (0x679c, &[0xb9, 0xfb], false),
+    (0x88f9, &[0xb9, 0xfc], false),
+    (0x8fc7, &[0xb9, 0xfd], false),
+    (0x54c8, &[0xb9, 0xfe], false),
+    (0x9ab8, &[0xba, 0xa1], false),
+    (0x5b69, &[0xba, 0xa2], false),
+    (0x6d77, &[0xba, 0xa3], false),
+    (0x6c26, &[0xba, 0xa4], false),
+    (0x4ea5, &[0xba, 0xa5], false),
+    (0x5bb3, &[0xba, 0xa6], false),
+    (0x9a87, &[0xba, 0xa7], false),
+    (0x9163, &[0xba, 0xa8], false),
+    (0x61a8, &[0xba, 0xa9], false),
+    (0x90af, &[0xba, 0xaa], false),
+    (0x97e9, &[0xba, 0xab], false),
+    (0x542b, &[0xba, 0xac], false),
+    (0x6db5, &[0xba, 0xad], false),
+    (0x5bd2, &[0xba, 0xae], false),
+    (0x51fd, &[0xba, 0xaf], false),
+    (0x558a, &[0xba, 0xb0], false),
+    (0x7f55, &[0xba, 0xb1], false),
+    (0x7ff0, &[0xba, 0xb2], false),
+    (0x64bc, &[0xba, 0xb3], false),
+    (0x634d, &[0xba, 0xb4], false),
+    (0x65f1, &[0xba, 0xb5], false),
+    (0x61be, &[0xba, 0xb6], false),
+    (0x608d, &[0xba, 0xb7], false),
+    (0x710a, &[0xba, 0xb8], false),
+    (0x6c57, &[0xba, 0xb9], false),
+    (0x6c49, &[0xba, 0xba], false),
+    (0x592f, &[0xba, 0xbb], false),
+    (0x676d, &[0xba, 0xbc], false),
+    (0x822a, &[0xba, 0xbd], false),
+    (0x58d5, &[0xba, 0xbe], false),
+    (0x568e, &[0xba, 0xbf], false),
+    (0x8c6a, &[0xba, 0xc0], false),
+    (0x6beb, &[0xba, 0xc1], false),
+    (0x90dd, &[0xba, 0xc2], false),
+    (0x597d, &[0xba, 0xc3], false),
+    (0x8017, &[0xba, 0xc4], false),
+    (0x53f7, &[0xba, 0xc5], false),
+    (0x6d69, &[0xba, 0xc6], false),
+    (0x5475, &[0xba, 0xc7], false),
+    (0x559d, &[0xba, 0xc8], false),
+    (0x8377, &[0xba, 0xc9], false),
+    (0x83cf, &[0xba, 0xca], false),
+    (0x6838, &[0xba, 0xcb], false),
+    (0x79be, &[0xba, 0xcc], false),
+    (0x548c, &[0xba, 0xcd], false),
+    (0x4f55, &[0xba, 0xce], false),
+    (0x5408, &[0xba, 0xcf], false),
+    (0x76d2, &[0xba, 0xd0], false),
+    (0x8c89, &[0xba, 0xd1], false),
+    (0x9602, &[0xba, 0xd2], false),
+    (0x6cb3, &[0xba, 0xd3], false),
+    (0x6db8, &[0xba, 0xd4], false),
+    (0x8d6b, &[0xba, 0xd5], false),
+    (0x8910, &[0xba, 0xd6], false),
+    (0x9e64, &[0xba, 0xd7], false),
+    (0x8d3a, &[0xba, 0xd8], false),
+    (0x563f, &[0xba, 0xd9], false),
+    (0x9ed1, &[0xba, 0xda], false),
+    (0x75d5, &[0xba, 0xdb], false),
+    (0x5f88, &[0xba, 0xdc], false),
+    (0x72e0, &[0xba, 0xdd], false),
+    (0x6068, &[0xba, 0xde], false),
+    (0x54fc, &[0xba, 0xdf], false),
+    (0x4ea8, &[0xba, 0xe0], false),
+    (0x6a2a, &[0xba, 0xe1], false),
+    (0x8861, &[0xba, 0xe2], false),
+    (0x6052, &[0xba, 0xe3], false),
+    (0x8f70, &[0xba, 0xe4], false),
+    (0x54c4, &[0xba, 0xe5], false),
+    (0x70d8, &[0xba, 0xe6], false),
+    (0x8679, &[0xba, 0xe7], false),
+    (0x9e3f, &[0xba, 0xe8], false),
+    (0x6d2a, &[0xba, 0xe9], false),
+    (0x5b8f, &[0xba, 0xea], false),
+    (0x5f18, &[0xba, 0xeb], false),
+    (0x7ea2, &[0xba, 0xec], false),
+    (0x5589, &[0xba, 0xed], false),
+    (0x4faf, &[0xba, 0xee], false),
+    (0x7334, &[0xba, 0xef], false),
+    (0x543c, &[0xba, 0xf0], false),
+    (0x539a, &[0xba, 0xf1], false),
+    (0x5019, &[0xba, 0xf2], false),
+    (0x540e, &[0xba, 0xf3], false),
+    (0x547c, &[0xba, 0xf4], false),
+    (0x4e4e, &[0xba, 0xf5], false),
+    (0x5ffd, &[0xba, 0xf6], false),
+    (0x745a, &[0xba, 0xf7], false),
+    (0x58f6, &[0xba, 0xf8], false),
+    (0x846b, &[0xba, 0xf9], false),
+    (0x80e1, &[0xba, 0xfa], false),
+    (0x8774, &[0xba, 0xfb], false),
+    (0x72d0, &[0xba, 0xfc], false),
+    (0x7cca, &[0xba, 0xfd], false),
+    (0x6e56, &[0xba, 0xfe], false),
+    (0x5f27, &[0xbb, 0xa1], false),
+    (0x864e, &[0xbb, 0xa2], false),
+    (0x552c, &[0xbb, 0xa3], false),
+    (0x62a4, &[0xbb, 0xa4], false),
+    (0x4e92, &[0xbb, 0xa5], false),
+    (0x6caa, &[0xbb, 0xa6], false),
+    (0x6237, &[0xbb, 0xa7], false),
+    (0x82b1, &[0xbb, 0xa8], false),
+    (0x54d7, &[0xbb, 0xa9], false),
+    (0x534e, &[0xbb, 0xaa], false),
+    (0x733e, &[0xbb, 0xab], false),
+    (0x6ed1, &[0xbb, 0xac], false),
+    (0x753b, &[0xbb, 0xad], false),
+    (0x5212, &[0xbb, 0xae], false),
+    (0x5316, &[0xbb, 0xaf], false),
+    (0x8bdd, &[0xbb, 0xb0], false),
+    (0x69d0, &[0xbb, 0xb1], false),
+    (0x5f8a, &[0xbb, 0xb2], false),
+    (0x6000, &[0xbb, 0xb3], false),
+    (0x6dee, &[0xbb, 0xb4], false),
+    (0x574f, &[0xbb, 0xb5], false),
+    (0x6b22, &[0xbb, 0xb6], false),
+    (0x73af, &[0xbb, 0xb7], false),
+    (0x6853, &[0xbb, 0xb8], false),
+    (0x8fd8, &[0xbb, 0xb9], false),
+    (0x7f13, &[0xbb, 0xba], false),
+    (0x6362, &[0xbb, 0xbb], false),
+    (0x60a3, &[0xbb, 0xbc], false),
+    (0x5524, &[0xbb, 0xbd], false),
+    (0x75ea, &[0xbb, 0xbe], false),
+    (0x8c62, &[0xbb, 0xbf], false),
+    (0x7115, &[0xbb, 0xc0], false),
+    (0x6da3, &[0xbb, 0xc1], false),
+    (0x5ba6, &[0xbb, 0xc2], false),
+    (0x5e7b, &[0xbb, 0xc3], false),
+    (0x8352, &[0xbb, 0xc4], false),
+    (0x614c, &[0xbb, 0xc5], false),
+    (0x9ec4, &[0xbb, 0xc6], false),
+    (0x78fa, &[0xbb, 0xc7], false),
+    (0x8757, &[0xbb, 0xc8], false),
+    (0x7c27, &[0xbb, 0xc9], false),
+    (0x7687, &[0xbb, 0xca], false),
+    (0x51f0, &[0xbb, 0xcb], false),
+    (0x60f6, &[0xbb, 0xcc], false),
+    (0x714c, &[0xbb, 0xcd], false),
+    (0x6643, &[0xbb, 0xce], false),
+    (0x5e4c, &[0xbb, 0xcf], false),
+    (0x604d, &[0xbb, 0xd0], false),
+    (0x8c0e, &[0xbb, 0xd1], false),
+    (0x7070, &[0xbb, 0xd2], false),
+    (0x6325, &[0xbb, 0xd3], false),
+    (0x8f89, &[0xbb, 0xd4], false),
+    (0x5fbd, &[0xbb, 0xd5], false),
+    (0x6062, &[0xbb, 0xd6], false),
+    (0x86d4, &[0xbb, 0xd7], false),
+    (0x56de, &[0xbb, 0xd8], false),
+    (0x6bc1, &[0xbb, 0xd9], false),
+    (0x6094, &[0xbb, 0xda], false),
+    (0x6167, &[0xbb, 0xdb], false),
+    (0x5349, &[0xbb, 0xdc], false),
+    (0x60e0, &[0xbb, 0xdd], false),
+    (0x6666, &[0xbb, 0xde], false),
+    (0x8d3f, &[0xbb, 0xdf], false),
+    (0x79fd, &[0xbb, 0xe0], false),
+    (0x4f1a, &[0xbb, 0xe1], false),
+    (0x70e9, &[0xbb, 0xe2], false),
+    (0x6c47, &[0xbb, 0xe3], false),
+    (0x8bb3, &[0xbb, 0xe4], false),
+    (0x8bf2, &[0xbb, 0xe5], false),
+    (0x7ed8, &[0xbb, 0xe6], false),
+    (0x8364, &[0xbb, 0xe7], false),
+    (0x660f, &[0xbb, 0xe8], false),
+    (0x5a5a, &[0xbb, 0xe9], false),
+    (0x9b42, &[0xbb, 0xea], false),
+    (0x6d51, &[0xbb, 0xeb], false),
+    (0x6df7, &[0xbb, 0xec], false),
+    (0x8c41, &[0xbb, 0xed], false),
+    (0x6d3b, &[0xbb, 0xee], false),
+    (0x4f19, &[0xbb, 0xef], false),
+    (0x706b, &[0xbb, 0xf0], false),
+    (0x83b7, &[0xbb, 0xf1], false),
+    (0x6216, &[0xbb, 0xf2], false),
+    (0x60d1, &[0xbb, 0xf3], false),
+    (0x970d, &[0xbb, 0xf4], false),
+    (0x8d27, &[0xbb, 0xf5], false),
+    (0x7978, &[0xbb, 0xf6], false),
+    (0x51fb, &[0xbb, 0xf7], false),
+    (0x573e, &[0xbb, 0xf8], false),
+    (0x57fa, &[0xbb, 0xf9], false),
+    (0x673a, &[0xbb, 0xfa], false),
+    (0x7578, &[0xbb, 0xfb], false),
+    (0x7a3d, &[0xbb, 0xfc], false),
+    (0x79ef, &[0xbb, 0xfd], false),
+    (0x7b95, &[0xbb, 0xfe], false),
+    (0x808c, &[0xbc, 0xa1], false),
+    (0x9965, &[0xbc, 0xa2], false),
+    (0x8ff9, &[0xbc, 0xa3], false),
+    (0x6fc0, &[0xbc, 0xa4], false),
+    (0x8ba5, &[0xbc, 0xa5], false),
+    (0x9e21, &[0xbc, 0xa6], false),
+    (0x59ec, &[0xbc, 0xa7], false),
+    (0x7ee9, &[0xbc, 0xa8], false),
+    (0x7f09, &[0xbc, 0xa9], false),
+    (0x5409, &[0xbc, 0xaa], false),
+    (0x6781, &[0xbc, 0xab], false),
+    (0x68d8, &[0xbc, 0xac], false),
+    (0x8f91, &[0xbc, 0xad], false),
+    (0x7c4d, &[0xbc, 0xae], false),
+    (0x96c6, &[0xbc, 0xaf], false),
+    (0x53ca, &[0xbc, 0xb0], false),
+    (0x6025, &[0xbc, 0xb1], false),
+    (0x75be, &[0xbc, 0xb2], false),
+    (0x6c72, &[0xbc, 0xb3], false),
+    (0x5373, &[0xbc, 0xb4], false),
+    (0x5ac9, &[0xbc, 0xb5], false),
+    (0x7ea7, &[0xbc, 0xb6], false),
+    (0x6324, &[0xbc, 0xb7], false),
+    (0x51e0, &[0xbc, 0xb8], false),
+    (0x810a, &[0xbc, 0xb9], false),
+    (0x5df1, &[0xbc, 0xba], false),
+    (0x84df, &[0xbc, 0xbb], false),
+    (0x6280, &[0xbc, 0xbc], false),
+    (0x5180, &[0xbc, 0xbd], false),
+    (0x5b63, &[0xbc, 0xbe], false),
+    (0x4f0e, &[0xbc, 0xbf], false),
+    (0x796d, &[0xbc, 0xc0], false),
+    (0x5242, &[0xbc, 0xc1], false),
+    (0x60b8, &[0xbc, 0xc2], false),
+    (0x6d4e, &[0xbc, 0xc3], false),
+    (0x5bc4, &[0xbc, 0xc4], false),
+    (0x5bc2, &[0xbc, 0xc5], false),
+    (0x8ba1, &[0xbc, 0xc6], false),
+    (0x8bb0, &[0xbc, 0xc7], false),
+    (0x65e2, &[0xbc, 0xc8], false),
+    (0x5fcc, &[0xbc, 0xc9], false),
+    (0x9645, &[0xbc, 0xca], false),
+    (0x5993, &[0xbc, 0xcb], false),
+    (0x7ee7, &[0xbc, 0xcc], false),
+    (0x7eaa, &[0xbc, 0xcd], false),
+    (0x5609, &[0xbc, 0xce], false),
+    (0x67b7, &[0xbc, 0xcf], false),
+    (0x5939, &[0xbc, 0xd0], false),
+    (0x4f73, &[0xbc, 0xd1], false),
+    (0x5bb6, &[0xbc, 0xd2], false),
+    (0x52a0, &[0xbc, 0xd3], false),
+    (0x835a, &[0xbc, 0xd4], false),
+    (0x988a, &[0xbc, 0xd5], false),
+    (0x8d3e, &[0xbc, 0xd6], false),
+    (0x7532, &[0xbc, 0xd7], false),
+    (0x94be, &[0xbc, 0xd8], false),
+    (0x5047, &[0xbc, 0xd9], false),
+    (0x7a3c, &[0xbc, 0xda], false),
+    (0x4ef7, &[0xbc, 0xdb], false),
+    (0x67b6, &[0xbc, 0xdc], false),
+    (0x9a7e, &[0xbc, 0xdd], false),
+    (0x5ac1, &[0xbc, 0xde], false),
+    (0x6b7c, &[0xbc, 0xdf], false),
+    (0x76d1, &[0xbc, 0xe0], false),
+    (0x575a, &[0xbc, 0xe1], false),
+    (0x5c16, &[0xbc, 0xe2], false),
+    (0x7b3a, &[0xbc, 0xe3], false),
+    (0x95f4, &[0xbc, 0xe4], false),
+    (0x714e, &[0xbc, 0xe5], false),
+    (0x517c, &[0xbc, 0xe6], false),
+    (0x80a9, &[0xbc, 0xe7], false),
+    (0x8270, &[0xbc, 0xe8], false),
+    (0x5978, &[0xbc, 0xe9], false),
+    (0x7f04, &[0xbc, 0xea], false),
+    (0x8327, &[0xbc, 0xeb], false),
+    (0x68c0, &[0xbc, 0xec], false),
+    (0x67ec, &[0xbc, 0xed], false),
+    (0x78b1, &[0xbc, 0xee], false),
+    (0x7877, &[0xbc, 0xef], false),
+    (0x62e3, &[0xbc, 0xf0], false),
+    (0x6361, &[0xbc, 0xf1], false),
+    (0x7b80, &[0xbc, 0xf2], false),
+    (0x4fed, &[0xbc, 0xf3], false),
+    (0x526a, &[0xbc, 0xf4], false),
+    (0x51cf, &[0xbc, 0xf5], false),
+    (0x8350, &[0xbc, 0xf6], false),
+    (0x69db, &[0xbc, 0xf7], false),
+    (0x9274, &[0xbc, 0xf8], false),
+    (0x8df5, &[0xbc, 0xf9], false),
+    (0x8d31, &[0xbc, 0xfa], false),
+    (0x89c1, &[0xbc, 0xfb], false),
+    (0x952e, &[0xbc, 0xfc], false),
+    (0x7bad, &[0xbc, 0xfd], false),
+    (0x4ef6, &[0xbc, 0xfe], false),
+    (0x5065, &[0xbd, 0xa1], false),
+    (0x8230, &[0xbd, 0xa2], false),
+    (0x5251, &[0xbd, 0xa3], false),
+    (0x996f, &[0xbd, 0xa4], false),
+    (0x6e10, &[0xbd, 0xa5], false),
+    (0x6e85, &[0xbd, 0xa6], false),
+    (0x6da7, &[0xbd, 0xa7], false),
+    (0x5efa, &[0xbd, 0xa8], false),
+    (0x50f5, &[0xbd, 0xa9], false),
+    (0x59dc, &[0xbd, 0xaa], false),
+    (0x5c06, &[0xbd, 0xab], false),
+    (0x6d46, &[0xbd, 0xac], false),
+    (0x6c5f, &[0xbd, 0xad], false),
+    (0x7586, &[0xbd, 0xae], false),
+    (0x848b, &[0xbd, 0xaf], false),
+    (0x6868, &[0xbd, 0xb0], false),
+    (0x5956, &[0xbd, 0xb1], false),
+    (0x8bb2, &[0xbd, 0xb2], false),
+    (0x5320, &[0xbd, 0xb3], false),
+    (0x9171, &[0xbd, 0xb4], false),
+    (0x964d, &[0xbd, 0xb5], false),
+    (0x8549, &[0xbd, 0xb6], false),
+    (0x6912, &[0xbd, 0xb7], false),
+    (0x7901, &[0xbd, 0xb8], false),
+    (0x7126, &[0xbd, 0xb9], false),
+    (0x80f6, &[0xbd, 0xba], false),
+    (0x4ea4, &[0xbd, 0xbb], false),
+    (0x90ca, &[0xbd, 0xbc], false),
+    (0x6d47, &[0xbd, 0xbd], false),
+    (0x9a84, &[0xbd, 0xbe], false),
+    (0x5a07, &[0xbd, 0xbf], false),
+    (0x56bc, &[0xbd, 0xc0], false),
+    (0x6405, &[0xbd, 0xc1], false),
+    (0x94f0, &[0xbd, 0xc2], false),
+    (0x77eb, &[0xbd, 0xc3], false),
+    (0x4fa5, &[0xbd, 0xc4], false),
+    (0x811a, &[0xbd, 0xc5], false),
+    (0x72e1, &[0xbd, 0xc6], false),
+    (0x89d2, &[0xbd, 0xc7], false),
+    (0x997a, &[0xbd, 0xc8], false),
+    (0x7f34, &[0xbd, 0xc9], false),
+    (0x7ede, &[0xbd, 0xca], false),
+    (0x527f, &[0xbd, 0xcb], false),
+    (0x6559, &[0xbd, 0xcc], false),
+    (0x9175, &[0xbd, 0xcd], false),
+    (0x8f7f, &[0xbd, 0xce], false),
+    (0x8f83, &[0xbd, 0xcf], false),
+    (0x53eb, &[0xbd, 0xd0], false),
+    (0x7a96, &[0xbd, 0xd1], false),
+    (0x63ed, &[0xbd, 0xd2], false),
+    (0x63a5, &[0xbd, 0xd3], false),
+    (0x7686, &[0xbd, 0xd4], false),
+    (0x79f8, &[0xbd, 0xd5], false),
+    (0x8857, &[0xbd, 0xd6], false),
+    (0x9636, &[0xbd, 0xd7], false),
+    (0x622a, &[0xbd, 0xd8], false),
+    (0x52ab, &[0xbd, 0xd9], false),
+    (0x8282, &[0xbd, 0xda], false),
+    (0x6854, &[0xbd, 0xdb], false),
+    (0x6770, &[0xbd, 0xdc], false),
+    (0x6377, &[0xbd, 0xdd], false),
+    (0x776b, &[0xbd, 0xde], false),
+    (0x7aed, &[0xbd, 0xdf], false),
+    (0x6d01, &[0xbd, 0xe0], false),
+    (0x7ed3, &[0xbd, 0xe1], false),
+    (0x89e3, &[0xbd, 0xe2], false),
+    (0x59d0, &[0xbd, 0xe3], false),
+    (0x6212, &[0xbd, 0xe4], false),
+    (0x85c9, &[0xbd, 0xe5], false),
+    (0x82a5, &[0xbd, 0xe6], false),
+    (0x754c, &[0xbd, 0xe7], false),
+    (0x501f, &[0xbd, 0xe8], false),
+    (0x4ecb, &[0xbd, 0xe9], false),
+    (0x75a5, &[0xbd, 0xea], false),
+    (0x8beb, &[0xbd, 0xeb], false),
+    (0x5c4a, &[0xbd, 0xec], false),
+    (0x5dfe, &[0xbd, 0xed], false),
+    (0x7b4b, &[0xbd, 0xee], false),
+    (0x65a4, &[0xbd, 0xef], false),
+    (0x91d1, &[0xbd, 0xf0], false),
+    (0x4eca, &[0xbd, 0xf1], false),
+    (0x6d25, &[0xbd, 0xf2], false),
+    (0x895f, &[0xbd, 0xf3], false),
+    (0x7d27, &[0xbd, 0xf4], false),
+    (0x9526, &[0xbd, 0xf5], false),
+    (0x4ec5, &[0xbd, 0xf6], false),
+    (0x8c28, &[0xbd, 0xf7], false),
+    (0x8fdb, &[0xbd, 0xf8], false),
+    (0x9773, &[0xbd, 0xf9], false),
+    (0x664b, &[0xbd, 0xfa], false),
+    (0x7981, &[0xbd, 0xfb], false),
+    (0x8fd1, &[0xbd, 0xfc], false),
+    (0x70ec, &[0xbd, 0xfd], false),
+    (0x6d78, &[0xbd, 0xfe], false),
+    (0x5c3d, &[0xbe, 0xa1], false),
+    (0x52b2, &[0xbe, 0xa2], false),
+    (0x8346, &[0xbe, 0xa3], false),
+    (0x5162, &[0xbe, 0xa4], false),
+    (0x830e, &[0xbe, 0xa5], false),
+    (0x775b, &[0xbe, 0xa6], false),
+    (0x6676, &[0xbe, 0xa7], false),
+    (0x9cb8, &[0xbe, 0xa8], false),
+    (0x4eac, &[0xbe, 0xa9], false),
+    (0x60ca, &[0xbe, 0xaa], false),
+    (0x7cbe, &[0xbe, 0xab], false),
+    (0x7cb3, &[0xbe, 0xac], false),
+    (0x7ecf, &[0xbe, 0xad], false),
+    (0x4e95, &[0xbe, 0xae], false),
+    (0x8b66, &[0xbe, 0xaf], false),
+    (0x666f, &[0xbe, 0xb0], false),
+    (0x9888, &[0xbe, 0xb1], false),
+    (0x9759, &[0xbe, 0xb2], false),
+    (0x5883, &[0xbe, 0xb3], false),
+    (0x656c, &[0xbe, 0xb4], false),
+    (0x955c, &[0xbe, 0xb5], false),
+    (0x5f84, &[0xbe, 0xb6], false),
+    (0x75c9, &[0xbe, 0xb7], false),
+    (0x9756, &[0xbe, 0xb8], false),
+    (0x7adf, &[0xbe, 0xb9], false),
+    (0x7ade, &[0xbe, 0xba], false),
+    (0x51c0, &[0xbe, 0xbb], false),
+    (0x70af, &[0xbe, 0xbc], false),
+    (0x7a98, &[0xbe, 0xbd], false),
+    (0x63ea, &[0xbe, 0xbe], false),
+    (0x7a76, &[0xbe, 0xbf], false),
+    (0x7ea0, &[0xbe, 0xc0], false),
+    (0x7396, &[0xbe, 0xc1], false),
+    (0x97ed, &[0xbe, 0xc2], false),
+    (0x4e45, &[0xbe, 0xc3], false),
+    (0x7078, &[0xbe, 0xc4], false),
+    (0x4e5d, &[0xbe, 0xc5], false),
+    (0x9152, &[0xbe, 0xc6], false),
+    (0x53a9, &[0xbe, 0xc7], false),
+    (0x6551, &[0xbe, 0xc8], false),
+    (0x65e7, &[0xbe, 0xc9], false),
+    (0x81fc, &[0xbe, 0xca], false),
+    (0x8205, &[0xbe, 0xcb], false),
+    (0x548e, &[0xbe, 0xcc], false),
+    (0x5c31, &[0xbe, 0xcd], false),
+    (0x759a, &[0xbe, 0xce], false),
+    (0x97a0, &[0xbe, 0xcf], false),
+    (0x62d8, &[0xbe, 0xd0], false),
+    (0x72d9, &[0xbe, 0xd1], false),
+    (0x75bd, &[0xbe, 0xd2], false),
+    (0x5c45, &[0xbe, 0xd3], false),
+    (0x9a79, &[0xbe, 0xd4], false),
+    (0x83ca, &[0xbe, 0xd5], false),
+    (0x5c40, &[0xbe, 0xd6], false),
+    (0x5480, &[0xbe, 0xd7], false),
+    (0x77e9, &[0xbe, 0xd8], false),
+    (0x4e3e, &[0xbe, 0xd9], false),
+    (0x6cae, &[0xbe, 0xda], false),
+    (0x805a, &[0xbe, 0xdb], false),
+    (0x62d2, &[0xbe, 0xdc], false),
+    (0x636e, &[0xbe, 0xdd], false),
+    (0x5de8, &[0xbe, 0xde], false),
+    (0x5177, &[0xbe, 0xdf], false),
+    (0x8ddd, &[0xbe, 0xe0], false),
+    (0x8e1e, &[0xbe, 0xe1], false),
+    (0x952f, &[0xbe, 0xe2], false),
+    (0x4ff1, &[0xbe, 0xe3], false),
+    (0x53e5, &[0xbe, 0xe4], false),
+    (0x60e7, &[0xbe, 0xe5], false),
+    (0x70ac, &[0xbe, 0xe6], false),
+    (0x5267, &[0xbe, 0xe7], false),
+    (0x6350, &[0xbe, 0xe8], false),
+    (0x9e43, &[0xbe, 0xe9], false),
+    (0x5a1f, &[0xbe, 0xea], false),
+    (0x5026, &[0xbe, 0xeb], false),
+    (0x7737, &[0xbe, 0xec], false),
+    (0x5377, &[0xbe, 0xed], false),
+    (0x7ee2, &[0xbe, 0xee], false),
+    (0x6485, &[0xbe, 0xef], false),
+    (0x652b, &[0xbe, 0xf0], false),
+    (0x6289, &[0xbe, 0xf1], false),
+    (0x6398, &[0xbe, 0xf2], false),
+    (0x5014, &[0xbe, 0xf3], false),
+    (0x7235, &[0xbe, 0xf4], false),
+    (0x89c9, &[0xbe, 0xf5], false),
+    (0x51b3, &[0xbe, 0xf6], false),
+    (0x8bc0, &[0xbe, 0xf7], false),
+    (0x7edd, &[0xbe, 0xf8], false),
+    (0x5747, &[0xbe, 0xf9], false),
+    (0x83cc, &[0xbe, 0xfa], false),
+    (0x94a7, &[0xbe, 0xfb], false),
+    (0x519b, &[0xbe, 0xfc], false),
+    (0x541b, &[0xbe, 0xfd], false),
+    (0x5cfb, &[0xbe, 0xfe], false),
+    (0x4fca, &[0xbf, 0xa1], false),
+    (0x7ae3, &[0xbf, 0xa2], false),
+    (0x6d5a, &[0xbf, 0xa3], false),
+    (0x90e1, &[0xbf, 0xa4], false),
+    (0x9a8f, &[0xbf, 0xa5], false),
+    (0x5580, &[0xbf, 0xa6], false),
+    (0x5496, &[0xbf, 0xa7], false),
+    (0x5361, &[0xbf, 0xa8], false),
+    (0x54af, &[0xbf, 0xa9], false),
+    (0x5f00, &[0xbf, 0xaa], false),
+    (0x63e9, &[0xbf, 0xab], false),
+    (0x6977, &[0xbf, 0xac], false),
+    (0x51ef, &[0xbf, 0xad], false),
+    (0x6168, &[0xbf, 0xae], false),
+    (0x520a, &[0xbf, 0xaf], false),
+    (0x582a, &[0xbf, 0xb0], false),
+    (0x52d8, &[0xbf, 0xb1], false),
+    (0x574e, &[0xbf, 0xb2], false),
+    (0x780d, &[0xbf, 0xb3], false),
+    (0x770b, &[0xbf, 0xb4], false),
+    (0x5eb7, &[0xbf, 0xb5], false),
+    (0x6177, &[0xbf, 0xb6], false),
+    (0x7ce0, &[0xbf, 0xb7], false),
+    (0x625b, &[0xbf, 0xb8], false),
+    (0x6297, &[0xbf, 0xb9], false),
+    (0x4ea2, &[0xbf, 0xba], false),
+    (0x7095, &[0xbf, 0xbb], false),
+    (0x8003, &[0xbf, 0xbc], false),
+    (0x62f7, &[0xbf, 0xbd], false),
+    (0x70e4, &[0xbf, 0xbe], false),
+    (0x9760, &[0xbf, 0xbf], false),
+    (0x5777, &[0xbf, 0xc0], false),
+    (0x82db, &[0xbf, 0xc1], false),
+    (0x67ef, &[0xbf, 0xc2], false),
+    (0x68f5, &[0xbf, 0xc3], false),
+    (0x78d5, &[0xbf, 0xc4], false),
+    (0x9897, &[0xbf, 0xc5], false),
+    (0x79d1, &[0xbf, 0xc6], false),
+    (0x58f3, &[0xbf, 0xc7], false),
+    (0x54b3, &[0xbf, 0xc8], false),
+    (0x53ef, &[0xbf, 0xc9], false),
+    (0x6e34, &[0xbf, 0xca], false),
+    (0x514b, &[0xbf, 0xcb], false),
+    (0x523b, &[0xbf, 0xcc], false),
+    (0x5ba2, &[0xbf, 0xcd], false),
+    (0x8bfe, &[0xbf, 0xce], false),
+    (0x80af, &[0xbf, 0xcf], false),
+    (0x5543, &[0xbf, 0xd0], false),
+    (0x57a6, &[0xbf, 0xd1], false),
+    (0x6073, &[0xbf, 0xd2], false),
+    (0x5751, &[0xbf, 0xd3], false),
+    (0x542d, &[0xbf, 0xd4], false),
+    (0x7a7a, &[0xbf, 0xd5], false),
+    (0x6050, &[0xbf, 0xd6], false),
+    (0x5b54, &[0xbf, 0xd7], false),
+    (0x63a7, &[0xbf, 0xd8], false),
+    (0x62a0, &[0xbf, 0xd9], false),
+    (0x53e3, &[0xbf, 0xda], false),
+    (0x6263, &[0xbf, 0xdb], false),
+    (0x5bc7, &[0xbf, 0xdc], false),
+    (0x67af, &[0xbf, 0xdd], false),
+    (0x54ed, &[0xbf, 0xde], false),
+    (0x7a9f, &[0xbf, 0xdf], false),
+    (0x82e6, &[0xbf, 0xe0], false),
+    (0x9177, &[0xbf, 0xe1], false),
+    (0x5e93, &[0xbf, 0xe2], false),
+    (0x88e4, &[0xbf, 0xe3], false),
+    (0x5938, &[0xbf, 0xe4], false),
+    (0x57ae, &[0xbf, 0xe5], false),
+    (0x630e, &[0xbf, 0xe6], false),
+    (0x8de8, &[0xbf, 0xe7], false),
+    (0x80ef, &[0xbf, 0xe8], false),
+    (0x5757, &[0xbf, 0xe9], false),
+    (0x7b77, &[0xbf, 0xea], false),
+    (0x4fa9, &[0xbf, 0xeb], false),
+    (0x5feb, &[0xbf, 0xec], false),
+    (0x5bbd, &[0xbf, 0xed], false),
+    (0x6b3e, &[0xbf, 0xee], false),
+    (0x5321, &[0xbf, 0xef], false),
+    (0x7b50, &[0xbf, 0xf0], false),
+    (0x72c2, &[0xbf, 0xf1], false),
+    (0x6846, &[0xbf, 0xf2], false),
+    (0x77ff, &[0xbf, 0xf3], false),
+    (0x7736, &[0xbf, 0xf4], false),
+    (0x65f7, &[0xbf, 0xf5], false),
+    (0x51b5, &[0xbf, 0xf6], false),
+    (0x4e8f, &[0xbf, 0xf7], false),
+    (0x76d4, &[0xbf, 0xf8], false),
+    (0x5cbf, &[0xbf, 0xf9], false),
+    (0x7aa5, &[0xbf, 0xfa], false),
+    (0x8475, &[0xbf, 0xfb], false),
+    (0x594e, &[0xbf, 0xfc], false),
+    (0x9b41, &[0xbf, 0xfd], false),
+    (0x5080, &[0xbf, 0xfe], false),
+    (0x9988, &[0xc0, 0xa1], false),
+    (0x6127, &[0xc0, 0xa2], false),
+    (0x6e83, &[0xc0, 0xa3], false),
+    (0x5764, &[0xc0, 0xa4], false),
+    (0x6606, &[0xc0, 0xa5], false),
+    (0x6346, &[0xc0, 0xa6], false),
+    (0x56f0, &[0xc0, 0xa7], false),
+    (0x62ec, &[0xc0, 0xa8], false),
+    (0x6269, &[0xc0, 0xa9], false),
+    (0x5ed3, &[0xc0, 0xaa], false),
+    (0x9614, &[0xc0, 0xab], false),
+    (0x5783, &[0xc0, 0xac], false),
+    (0x62c9, &[0xc0, 0xad], false),
+    (0x5587, &[0xc0, 0xae], false),
+    (0x8721, &[0xc0, 0xaf], false),
+    (0x814a, &[0xc0, 0xb0], false),
+    (0x8fa3, &[0xc0, 0xb1], false),
+    (0x5566, &[0xc0, 0xb2], false),
+    (0x83b1, &[0xc0, 0xb3], false),
+    (0x6765, &[0xc0, 0xb4], false),
+    (0x8d56, &[0xc0, 0xb5], false),
+    (0x84dd, &[0xc0, 0xb6], false),
+    (0x5a6a, &[0xc0, 0xb7], false),
+    (0x680f, &[0xc0, 0xb8], false),
+    (0x62e6, &[0xc0, 0xb9], false),
+    (0x7bee, &[0xc0, 0xba], false),
+    (0x9611, &[0xc0, 0xbb], false),
+    (0x5170, &[0xc0, 0xbc], false),
+    (0x6f9c, &[0xc0, 0xbd], false),
+    (0x8c30, &[0xc0, 0xbe], false),
+    (0x63fd, &[0xc0, 0xbf], false),
+    (0x89c8, &[0xc0, 0xc0], false),
+    (0x61d2, &[0xc0, 0xc1], false),
+    (0x7f06, &[0xc0, 0xc2], false),
+    (0x70c2, &[0xc0, 0xc3], false),
+    (0x6ee5, &[0xc0, 0xc4], false),
+    (0x7405, &[0xc0, 0xc5], false),
+    (0x6994, &[0xc0, 0xc6], false),
+    (0x72fc, &[0xc0, 0xc7], false),
+    (0x5eca, &[0xc0, 0xc8], false),
+    (0x90ce, &[0xc0, 0xc9], false),
+    (0x6717, &[0xc0, 0xca], false),
+    (0x6d6a, &[0xc0, 0xcb], false),
+    (0x635e, &[0xc0, 0xcc], false),
+    (0x52b3, &[0xc0, 0xcd], false),
+    (0x7262, &[0xc0, 0xce], false),
+    (0x8001, &[0xc0, 0xcf], false),
+    (0x4f6c, &[0xc0, 0xd0], false),
+    (0x59e5, &[0xc0, 0xd1], false),
+    (0x916a, &[0xc0, 0xd2], false),
+    (0x70d9, &[0xc0, 0xd3], false),
+    (0x6d9d, &[0xc0, 0xd4], false),
+    (0x52d2, &[0xc0, 0xd5], false),
+    (0x4e50, &[0xc0, 0xd6], false),
+    (0x96f7, &[0xc0, 0xd7], false),
+    (0x956d, &[0xc0, 0xd8], false),
+    (0x857e, &[0xc0, 0xd9], false),
+    (0x78ca, &[0xc0, 0xda], false),
+    (0x7d2f, &[0xc0, 0xdb], false),
+    (0x5121, &[0xc0, 0xdc], false),
+    (0x5792, &[0xc0, 0xdd], false),
+    (0x64c2, &[0xc0, 0xde], false),
+    (0x808b, &[0xc0, 0xdf], false),
+    (0x7c7b, &[0xc0, 0xe0], false),
+    (0x6cea, &[0xc0, 0xe1], false),
+    (0x68f1, &[0xc0, 0xe2], false),
+    (0x695e, &[0xc0, 0xe3], false),
+    (0x51b7, &[0xc0, 0xe4], false),
+    (0x5398, &[0xc0, 0xe5], false),
+    (0x68a8, &[0xc0, 0xe6], false),
+    (0x7281, &[0xc0, 0xe7], false),
+    (0x9ece, &[0xc0, 0xe8], false),
+    (0x7bf1, &[0xc0, 0xe9], false),
+    (0x72f8, &[0xc0, 0xea], false),
+    (0x79bb, &[0xc0, 0xeb], false),
+    (0x6f13, &[0xc0, 0xec], false),
+    (0x7406, &[0xc0, 0xed], false),
+    (0x674e, &[0xc0, 0xee], false),
+    (0x91cc, &[0xc0, 0xef], false),
+    (0x9ca4, &[0xc0, 0xf0], false),
+    (0x793c, &[0xc0, 0xf1], false),
+    (0x8389, &[0xc0, 0xf2], false),
+    (0x8354, &[0xc0, 0xf3], false),
+    (0x540f, &[0xc0, 0xf4], false),
+    (0x6817, &[0xc0, 0xf5], false),
+    (0x4e3d, &[0xc0, 0xf6], false),
+    (0x5389, &[0xc0, 0xf7], false),
+    (0x52b1, &[0xc0, 0xf8], false),
+    (0x783e, &[0xc0, 0xf9], false),
+    (0x5386, &[0xc0, 0xfa], false),
+    (0x5229, &[0xc0, 0xfb], false),
+    (0x5088, &[0xc0, 0xfc], false),
+    (0x4f8b, &[0xc0, 0xfd], false),
+    (0x4fd0, &[0xc0, 0xfe], false),
+    (0x75e2, &[0xc1, 0xa1], false),
+    (0x7acb, &[0xc1, 0xa2], false),
+    (0x7c92, &[0xc1, 0xa3], false),
+    (0x6ca5, &[0xc1, 0xa4], false),
+    (0x96b6, &[0xc1, 0xa5], false),
+    (0x529b, &[0xc1, 0xa6], false),
+    (0x7483, &[0xc1, 0xa7], false),
+    (0x54e9, &[0xc1, 0xa8], false),
+    (0x4fe9, &[0xc1, 0xa9], false),
+    (0x8054, &[0xc1, 0xaa], false),
+    (0x83b2, &[0xc1, 0xab], false),
+    (0x8fde, &[0xc1, 0xac], false),
+    (0x9570, &[0xc1, 0xad], false),
+    (0x5ec9, &[0xc1, 0xae], false),
+    (0x601c, &[0xc1, 0xaf], false),
+    (0x6d9f, &[0xc1, 0xb0], false),
+    (0x5e18, &[0xc1, 0xb1], false),
+    (0x655b, &[0xc1, 0xb2], false),
+    (0x8138, &[0xc1, 0xb3], false),
+    (0x94fe, &[0xc1, 0xb4], false),
+    (0x604b, &[0xc1, 0xb5], false),
+    (0x70bc, &[0xc1, 0xb6], false),
+    (0x7ec3, &[0xc1, 0xb7], false),
+    (0x7cae, &[0xc1, 0xb8], false),
+    (0x51c9, &[0xc1, 0xb9], false),
+    (0x6881, &[0xc1, 0xba], false),
+    (0x7cb1, &[0xc1, 0xbb], false),
+    (0x826f, &[0xc1, 0xbc], false),
+    (0x4e24, &[0xc1, 0xbd], false),
+    (0x8f86, &[0xc1, 0xbe], false),
+    (0x91cf, &[0xc1, 0xbf], false),
+    (0x667e, &[0xc1, 0xc0], false),
+    (0x4eae, &[0xc1, 0xc1], false),
+    (0x8c05, &[0xc1, 0xc2], false),
+    (0x64a9, &[0xc1, 0xc3], false),
+    (0x804a, &[0xc1, 0xc4], false),
+    (0x50da, &[0xc1, 0xc5], false),
+    (0x7597, &[0xc1, 0xc6], false),
+    (0x71ce, &[0xc1, 0xc7], false),
+    (0x5be5, &[0xc1, 0xc8], false),
+    (0x8fbd, &[0xc1, 0xc9], false),
+    (0x6f66, &[0xc1, 0xca], false),
+    (0x4e86, &[0xc1, 0xcb], false),
+    (0x6482, &[0xc1, 0xcc], false),
+    (0x9563, &[0xc1, 0xcd], false),
+    (0x5ed6, &[0xc1, 0xce], false),
+    (0x6599, &[0xc1, 0xcf], false),
+    (0x5217, &[0xc1, 0xd0], false),
+    (0x88c2, &[0xc1, 0xd1], false),
+    (0x70c8, &[0xc1, 0xd2], false),
+    (0x52a3, &[0xc1, 0xd3], false),
+    (0x730e, &[0xc1, 0xd4], false),
+    (0x7433, &[0xc1, 0xd5], false),
+    (0x6797, &[0xc1, 0xd6], false),
+    (0x78f7, &[0xc1, 0xd7], false),
+    (0x9716, &[0xc1, 0xd8], false),
+    (0x4e34, &[0xc1, 0xd9], false),
+    (0x90bb, &[0xc1, 0xda], false),
+    (0x9cde, &[0xc1, 0xdb], false),
+    (0x6dcb, &[0xc1, 0xdc], false),
+    (0x51db, &[0xc1, 0xdd], false),
+    (0x8d41, &[0xc1, 0xde], false),
+    (0x541d, &[0xc1, 0xdf], false),
+    (0x62ce, &[0xc1, 0xe0], false),
+    (0x73b2, &[0xc1, 0xe1], false),
+    (0x83f1, &[0xc1, 0xe2], false),
+    (0x96f6, &[0xc1, 0xe3], false),
+    (0x9f84, &[0xc1, 0xe4], false),
+    (0x94c3, &[0xc1, 0xe5], false),
+    (0x4f36, &[0xc1, 0xe6], false),
+    (0x7f9a, &[0xc1, 0xe7], false),
+    (0x51cc, &[0xc1, 0xe8], false),
+    (0x7075, &[0xc1, 0xe9], false),
+    (0x9675, &[0xc1, 0xea], false),
+    (0x5cad, &[0xc1, 0xeb], false),
+    (0x9886, &[0xc1, 0xec], false),
+    (0x53e6, &[0xc1, 0xed], false),
+    (0x4ee4, &[0xc1, 0xee], false),
+    (0x6e9c, &[0xc1, 0xef], false),
+    (0x7409, &[0xc1, 0xf0], false),
+    (0x69b4, &[0xc1, 0xf1], false),
+    (0x786b, &[0xc1, 0xf2], false),
+    (0x998f, &[0xc1, 0xf3], false),
+    (0x7559, &[0xc1, 0xf4], false),
+    (0x5218, &[0xc1, 0xf5], false),
+    (0x7624, &[0xc1, 0xf6], false),
+    (0x6d41, &[0xc1, 0xf7], false),
+    (0x67f3, &[0xc1, 0xf8], false),
+    (0x516d, &[0xc1, 0xf9], false),
+    (0x9f99, &[0xc1, 0xfa], false),
+    (0x804b, &[0xc1, 0xfb], false),
+    (0x5499, &[0xc1, 0xfc], false),
+    (0x7b3c, &[0xc1, 0xfd], false),
+    (0x7abf, &[0xc1, 0xfe], false),
+    (0x9686, &[0xc2, 0xa1], false),
+    (0x5784, &[0xc2, 0xa2], false),
+    (0x62e2, &[0xc2, 0xa3], false),
+    (0x9647, &[0xc2, 0xa4], false),
+    (0x697c, &[0xc2, 0xa5], false),
+    (0x5a04, &[0xc2, 0xa6], false),
+    (0x6402, &[0xc2, 0xa7], false),
+    (0x7bd3, &[0xc2, 0xa8], false),
+    (0x6f0f, &[0xc2, 0xa9], false),
+    (0x964b, &[0xc2, 0xaa], false),
+    (0x82a6, &[0xc2, 0xab], false),
+    (0x5362, &[0xc2, 0xac], false),
+    (0x9885, &[0xc2, 0xad], false),
+    (0x5e90, &[0xc2, 0xae], false),
+    (0x7089, &[0xc2, 0xaf], false),
+    (0x63b3, &[0xc2, 0xb0], false),
+    (0x5364, &[0xc2, 0xb1], false),
+    (0x864f, &[0xc2, 0xb2], false),
+    (0x9c81, &[0xc2, 0xb3], false),
+    (0x9e93, &[0xc2, 0xb4], false),
+    (0x788c, &[0xc2, 0xb5], false),
+    (0x9732, &[0xc2, 0xb6], false),
+    (0x8def, &[0xc2, 0xb7], false),
+    (0x8d42, &[0xc2, 0xb8], false),
+    (0x9e7f, &[0xc2, 0xb9], false),
+    (0x6f5e, &[0xc2, 0xba], false),
+    (0x7984, &[0xc2, 0xbb], false),
+    (0x5f55, &[0xc2, 0xbc], false),
+    (0x9646, &[0xc2, 0xbd], false),
+    (0x622e, &[0xc2, 0xbe], false),
+    (0x9a74, &[0xc2, 0xbf], false),
+    (0x5415, &[0xc2, 0xc0], false),
+    (0x94dd, &[0xc2, 0xc1], false),
+    (0x4fa3, &[0xc2, 0xc2], false),
+    (0x65c5, &[0xc2, 0xc3], false),
+    (0x5c65, &[0xc2, 0xc4], false),
+    (0x5c61, &[0xc2, 0xc5], false),
+    (0x7f15, &[0xc2, 0xc6], false),
+    (0x8651, &[0xc2, 0xc7], false),
+    (0x6c2f, &[0xc2, 0xc8], false),
+    (0x5f8b, &[0xc2, 0xc9], false),
+    (0x7387, &[0xc2, 0xca], false),
+    (0x6ee4, &[0xc2, 0xcb], false),
+    (0x7eff, &[0xc2, 0xcc], false),
+    (0x5ce6, &[0xc2, 0xcd], false),
+    (0x631b, &[0xc2, 0xce], false),
+    (0x5b6a, &[0xc2, 0xcf], false),
+    (0x6ee6, &[0xc2, 0xd0], false),
+    (0x5375, &[0xc2, 0xd1], false),
+    (0x4e71, &[0xc2, 0xd2], false),
+    (0x63a0, &[0xc2, 0xd3], false),
+    (0x7565, &[0xc2, 0xd4], false),
+    (0x62a1, &[0xc2, 0xd5], false),
+    (0x8f6e, &[0xc2, 0xd6], false),
+    (0x4f26, &[0xc2, 0xd7], false),
+    (0x4ed1, &[0xc2, 0xd8], false),
+    (0x6ca6, &[0xc2, 0xd9], false),
+    (0x7eb6, &[0xc2, 0xda], false),
+    (0x8bba, &[0xc2, 0xdb], false),
+    (0x841d, &[0xc2, 0xdc], false),
+    (0x87ba, &[0xc2, 0xdd], false),
+    (0x7f57, &[0xc2, 0xde], false),
+    (0x903b, &[0xc2, 0xdf], false),
+    (0x9523, &[0xc2, 0xe0], false),
+    (0x7ba9, &[0xc2, 0xe1], false),
+    (0x9aa1, &[0xc2, 0xe2], false),
+    (0x88f8, &[0xc2, 0xe3], false),
+    (0x843d, &[0xc2, 0xe4], false),
+    (0x6d1b, &[0xc2, 0xe5], false),
+    (0x9a86, &[0xc2, 0xe6], false),
+    (0x7edc, &[0xc2, 0xe7], false),
+    (0x5988, &[0xc2, 0xe8], false),
+    (0x9ebb, &[0xc2, 0xe9], false),
+    (0x739b, &[0xc2, 0xea], false),
+    (0x7801, &[0xc2, 0xeb], false),
+    (0x8682, &[0xc2, 0xec], false),
+    (0x9a6c, &[0xc2, 0xed], false),
+    (0x9a82, &[0xc2, 0xee], false),
+    (0x561b, &[0xc2, 0xef], false),
+    (0x5417, &[0xc2, 0xf0], false),
+    (0x57cb, &[0xc2, 0xf1], false),
+    (0x4e70, &[0xc2, 0xf2], false),
+    (0x9ea6, &[0xc2, 0xf3], false),
+    (0x5356, &[0xc2, 0xf4], false),
+    (0x8fc8, &[0xc2, 0xf5], false),
+    (0x8109, &[0xc2, 0xf6], false),
+    (0x7792, &[0xc2, 0xf7], false),
+    (0x9992, &[0xc2, 0xf8], false),
+    (0x86ee, &[0xc2, 0xf9], false),
+    (0x6ee1, &[0xc2, 0xfa], false),
+    (0x8513, &[0xc2, 0xfb], false),
+    (0x66fc, &[0xc2, 0xfc], false),
+    (0x6162, &[0xc2, 0xfd], false),
+    (0x6f2b, &[0xc2, 0xfe], false),
+    (0x8c29, &[0xc3, 0xa1], false),
+    (0x8292, &[0xc3, 0xa2], false),
+    (0x832b, &[0xc3, 0xa3], false),
+    (0x76f2, &[0xc3, 0xa4], false),
+    (0x6c13, &[0xc3, 0xa5], false),
+    (0x5fd9, &[0xc3, 0xa6], false),
+    (0x83bd, &[0xc3, 0xa7], false),
+    (0x732b, &[0xc3, 0xa8], false),
+    (0x8305, &[0xc3, 0xa9], false),
+    (0x951a, &[0xc3, 0xaa], false),
+    (0x6bdb, &[0xc3, 0xab], false),
+    (0x77db, &[0xc3, 0xac], false),
+    (0x94c6, &[0xc3, 0xad], false),
+    (0x536f, &[0xc3, 0xae], false),
+    (0x8302, &[0xc3, 0xaf], false),
+    (0x5192, &[0xc3, 0xb0], false),
+    (0x5e3d, &[0xc3, 0xb1], false),
+    (0x8c8c, &[0xc3, 0xb2], false),
+    (0x8d38, &[0xc3, 0xb3], false),
+    (0x4e48, &[0xc3, 0xb4], false),
+    (0x73ab, &[0xc3, 0xb5], false),
+    (0x679a, &[0xc3, 0xb6], false),
+    (0x6885, &[0xc3, 0xb7], false),
+    (0x9176, &[0xc3, 0xb8], false),
+    (0x9709, &[0xc3, 0xb9], false),
+    (0x7164, &[0xc3, 0xba], false),
+    (0x6ca1, &[0xc3, 0xbb], false),
+    (0x7709, &[0xc3, 0xbc], false),
+    (0x5a92, &[0xc3, 0xbd], false),
+    (0x9541, &[0xc3, 0xbe], false),
+    (0x6bcf, &[0xc3, 0xbf], false),
+    (0x7f8e, &[0xc3, 0xc0], false),
+    (0x6627, &[0xc3, 0xc1], false),
+    (0x5bd0, &[0xc3, 0xc2], false),
+    (0x59b9, &[0xc3, 0xc3], false),
+    (0x5a9a, &[0xc3, 0xc4], false),
+    (0x95e8, &[0xc3, 0xc5], false),
+    (0x95f7, &[0xc3, 0xc6], false),
+    (0x4eec, &[0xc3, 0xc7], false),
+    (0x840c, &[0xc3, 0xc8], false),
+    (0x8499, &[0xc3, 0xc9], false),
+    (0x6aac, &[0xc3, 0xca], false),
+    (0x76df, &[0xc3, 0xcb], false),
+    (0x9530, &[0xc3, 0xcc], false),
+    (0x731b, &[0xc3, 0xcd], false),
+    (0x68a6, &[0xc3, 0xce], false),
+    (0x5b5f, &[0xc3, 0xcf], false),
+    (0x772f, &[0xc3, 0xd0], false),
+    (0x919a, &[0xc3, 0xd1], false),
+    (0x9761, &[0xc3, 0xd2], false),
+    (0x7cdc, &[0xc3, 0xd3], false),
+    (0x8ff7, &[0xc3, 0xd4], false),
+    (0x8c1c, &[0xc3, 0xd5], false),
+    (0x5f25, &[0xc3, 0xd6], false),
+    (0x7c73, &[0xc3, 0xd7], false),
+    (0x79d8, &[0xc3, 0xd8], false),
+    (0x89c5, &[0xc3, 0xd9], false),
+    (0x6ccc, &[0xc3, 0xda], false),
+    (0x871c, &[0xc3, 0xdb], false),
+    (0x5bc6, &[0xc3, 0xdc], false),
+    (0x5e42, &[0xc3, 0xdd], false),
+    (0x68c9, &[0xc3, 0xde], false),
+    (0x7720, &[0xc3, 0xdf], false),
+    (0x7ef5, &[0xc3, 0xe0], false),
+    (0x5195, &[0xc3, 0xe1], false),
+    (0x514d, &[0xc3, 0xe2], false),
+    (0x52c9, &[0xc3, 0xe3], false),
+    (0x5a29, &[0xc3, 0xe4], false),
+    (0x7f05, &[0xc3, 0xe5], false),
+    (0x9762, &[0xc3, 0xe6], false),
+    (0x82d7, &[0xc3, 0xe7], false),
+    (0x63cf, &[0xc3, 0xe8], false),
+    (0x7784, &[0xc3, 0xe9], false),
+    (0x85d0, &[0xc3, 0xea], false),
+    (0x79d2, &[0xc3, 0xeb], false),
+    (0x6e3a, &[0xc3, 0xec], false),
+    (0x5e99, &[0xc3, 0xed], false),
+    (0x5999, &[0xc3, 0xee], false),
+    (0x8511, &[0xc3, 0xef], false),
+    (0x706d, &[0xc3, 0xf0], false),
+    (0x6c11, &[0xc3, 0xf1], false),
+    (0x62bf, &[0xc3, 0xf2], false),
+    (0x76bf, &[0xc3, 0xf3], false),
+    (0x654f, &[0xc3, 0xf4], false),
+    (0x60af, &[0xc3, 0xf5], false),
+    (0x95fd, &[0xc3, 0xf6], false),
+    (0x660e, &[0xc3, 0xf7], false),
+    (0x879f, &[0xc3, 0xf8], false),
+    (0x9e23, &[0xc3, 0xf9], false),
+    (0x94ed, &[0xc3, 0xfa], false),
+    (0x540d, &[0xc3, 0xfb], false),
+    (0x547d, &[0xc3, 0xfc], false),
+    (0x8c2c, &[0xc3, 0xfd], false),
+    (0x6478, &[0xc3, 0xfe], false),
+    (0x6479, &[0xc4, 0xa1], false),
+    (0x8611, &[0xc4, 0xa2], false),
+    (0x6a21, &[0xc4, 0xa3], false),
+    (0x819c, &[0xc4, 0xa4], false),
+    (0x78e8, &[0xc4, 0xa5], false),
+    (0x6469, &[0xc4, 0xa6], false),
+    (0x9b54, &[0xc4, 0xa7], false),
+    (0x62b9, &[0xc4, 0xa8], false),
+    (0x672b, &[0xc4, 0xa9], false),
+    (0x83ab, &[0xc4, 0xaa], false),
+    (0x58a8, &[0xc4, 0xab], false),
+    (0x9ed8, &[0xc4, 0xac], false),
+    (0x6cab, &[0xc4, 0xad], false),
+    (0x6f20, &[0xc4, 0xae], false),
+    (0x5bde, &[0xc4, 0xaf], false),
+    (0x964c, &[0xc4, 0xb0], false),
+    (0x8c0b, &[0xc4, 0xb1], false),
+    (0x725f, &[0xc4, 0xb2], false),
+    (0x67d0, &[0xc4, 0xb3], false),
+    (0x62c7, &[0xc4, 0xb4], false),
+    (0x7261, &[0xc4, 0xb5], false),
+    (0x4ea9, &[0xc4, 0xb6], false),
+    (0x59c6, &[0xc4, 0xb7], false),
+    (0x6bcd, &[0xc4, 0xb8], false),
+    (0x5893, &[0xc4, 0xb9], false),
+    (0x66ae, &[0xc4, 0xba], false),
+    (0x5e55, &[0xc4, 0xbb], false),
+    (0x52df, &[0xc4, 0xbc], false),
+    (0x6155, &[0xc4, 0xbd], false),
+    (0x6728, &[0xc4, 0xbe], false),
+    (0x76ee, &[0xc4, 0xbf], false),
+    (0x7766, &[0xc4, 0xc0], false),
+    (0x7267, &[0xc4, 0xc1], false),
+    (0x7a46, &[0xc4, 0xc2], false),
+    (0x62ff, &[0xc4, 0xc3], false),
+    (0x54ea, &[0xc4, 0xc4], false),
+    (0x5450, &[0xc4, 0xc5], false),
+    (0x94a0, &[0xc4, 0xc6], false),
+    (0x90a3, &[0xc4, 0xc7], false),
+    (0x5a1c, &[0xc4, 0xc8], false),
+    (0x7eb3, &[0xc4, 0xc9], false),
+    (0x6c16, &[0xc4, 0xca], false),
+    (0x4e43, &[0xc4, 0xcb], false),
+    (0x5976, &[0xc4, 0xcc], false),
+    (0x8010, &[0xc4, 0xcd], false),
+    (0x5948, &[0xc4, 0xce], false),
+    (0x5357, &[0xc4, 0xcf], false),
+    (0x7537, &[0xc4, 0xd0], false),
+    (0x96be, &[0xc4, 0xd1], false),
+    (0x56ca, &[0xc4, 0xd2], false),
+    (0x6320, &[0xc4, 0xd3], false),
+    (0x8111, &[0xc4, 0xd4], false),
+    (0x607c, &[0xc4, 0xd5], false),
+    (0x95f9, &[0xc4, 0xd6], false),
+    (0x6dd6, &[0xc4, 0xd7], false),
+    (0x5462, &[0xc4, 0xd8], false),
+    (0x9981, &[0xc4, 0xd9], false),
+    (0x5185, &[0xc4, 0xda], false),
+    (0x5ae9, &[0xc4, 0xdb], false),
+    (0x80fd, &[0xc4, 0xdc], false),
+    (0x59ae, &[0xc4, 0xdd], false),
+    (0x9713, &[0xc4, 0xde], false),
+    (0x502a, &[0xc4, 0xdf], false),
+    (0x6ce5, &[0xc4, 0xe0], false),
+    (0x5c3c, &[0xc4, 0xe1], false),
+    (0x62df, &[0xc4, 0xe2], false),
+    (0x4f60, &[0xc4, 0xe3], false),
+    (0x533f, &[0xc4, 0xe4], false),
+    (0x817b, &[0xc4, 0xe5], false),
+    (0x9006, &[0xc4, 0xe6], false),
+    (0x6eba, &[0xc4, 0xe7], false),
+    (0x852b, &[0xc4, 0xe8], false),
+    (0x62c8, &[0xc4, 0xe9], false),
+    (0x5e74, &[0xc4, 0xea], false),
+    (0x78be, &[0xc4, 0xeb], false),
+    (0x64b5, &[0xc4, 0xec], false),
+    (0x637b, &[0xc4, 0xed], false),
+    (0x5ff5, &[0xc4, 0xee], false),
+    (0x5a18, &[0xc4, 0xef], false),
+    (0x917f, &[0xc4, 0xf0], false),
+    (0x9e1f, &[0xc4, 0xf1], false),
+    (0x5c3f, &[0xc4, 0xf2], false),
+    (0x634f, &[0xc4, 0xf3], false),
+    (0x8042, &[0xc4, 0xf4], false),
+    (0x5b7d, &[0xc4, 0xf5], false),
+    (0x556e, &[0xc4, 0xf6], false),
+    (0x954a, &[0xc4, 0xf7], false),
+    (0x954d, &[0xc4, 0xf8], false),
+    (0x6d85, &[0xc4, 0xf9], false),
+    (0x60a8, &[0xc4, 0xfa], false),
+    (0x67e0, &[0xc4, 0xfb], false),
+    (0x72de, &[0xc4, 0xfc], false),
+    (0x51dd, &[0xc4, 0xfd], false),
+    (0x5b81, &[0xc4, 0xfe], false),
+    (0x62e7, &[0xc5, 0xa1], false),
+    (0x6cde, &[0xc5, 0xa2], false),
+    (0x725b, &[0xc5, 0xa3], false),
+    (0x626d, &[0xc5, 0xa4], false),
+    (0x94ae, &[0xc5, 0xa5], false),
+    (0x7ebd, &[0xc5, 0xa6], false),
+    (0x8113, &[0xc5, 0xa7], false),
+    (0x6d53, &[0xc5, 0xa8], false),
+    (0x519c, &[0xc5, 0xa9], false),
+    (0x5f04, &[0xc5, 0xaa], false),
+    (0x5974, &[0xc5, 0xab], false),
+    (0x52aa, &[0xc5, 0xac], false),
+    (0x6012, &[0xc5, 0xad], false),
+    (0x5973, &[0xc5, 0xae], false),
+    (0x6696, &[0xc5, 0xaf], false),
+    (0x8650, &[0xc5, 0xb0], false),
+    (0x759f, &[0xc5, 0xb1], false),
+    (0x632a, &[0xc5, 0xb2], false),
+    (0x61e6, &[0xc5, 0xb3], false),
+    (0x7cef, &[0xc5, 0xb4], false),
+    (0x8bfa, &[0xc5, 0xb5], false),
+    (0x54e6, &[0xc5, 0xb6], false),
+    (0x6b27, &[0xc5, 0xb7], false),
+    (0x9e25, &[0xc5, 0xb8], false),
+    (0x6bb4, &[0xc5, 0xb9], false),
+    (0x85d5, &[0xc5, 0xba], false),
+    (0x5455, &[0xc5, 0xbb], false),
+    (0x5076, &[0xc5, 0xbc], false),
+    (0x6ca4, &[0xc5, 0xbd], false),
+    (0x556a, &[0xc5, 0xbe], false),
+    (0x8db4, &[0xc5, 0xbf], false),
+    (0x722c, &[0xc5, 0xc0], false),
+    (0x5e15, &[0xc5, 0xc1], false),
+    (0x6015, &[0xc5, 0xc2], false),
+    (0x7436, &[0xc5, 0xc3], false),
+    (0x62cd, &[0xc5, 0xc4], false),
+    (0x6392, &[0xc5, 0xc5], false),
+    (0x724c, &[0xc5, 0xc6], false),
+    (0x5f98, &[0xc5, 0xc7], false),
+    (0x6e43, &[0xc5, 0xc8], false),
+    (0x6d3e, &[0xc5, 0xc9], false),
+    (0x6500, &[0xc5, 0xca], false),
+    (0x6f58, &[0xc5, 0xcb], false),
+    (0x76d8, &[0xc5, 0xcc], false),
+    (0x78d0, &[0xc5, 0xcd], false),
+    (0x76fc, &[0xc5, 0xce], false),
+    (0x7554, &[0xc5, 0xcf], false),
+    (0x5224, &[0xc5, 0xd0], false),
+    (0x53db, &[0xc5, 0xd1], false),
+    (0x4e53, &[0xc5, 0xd2], false),
+    (0x5e9e, &[0xc5, 0xd3], false),
+    (0x65c1, &[0xc5, 0xd4], false),
+    (0x802a, &[0xc5, 0xd5], false),
+    (0x80d6, &[0xc5, 0xd6], false),
+    (0x629b, &[0xc5, 0xd7], false),
+    (0x5486, &[0xc5, 0xd8], false),
+    (0x5228, &[0xc5, 0xd9], false),
+    (0x70ae, &[0xc5, 0xda], false),
+    (0x888d, &[0xc5, 0xdb], false),
+    (0x8dd1, &[0xc5, 0xdc], false),
+    (0x6ce1, &[0xc5, 0xdd], false),
+    (0x5478, &[0xc5, 0xde], false),
+    (0x80da, &[0xc5, 0xdf], false),
+    (0x57f9, &[0xc5, 0xe0], false),
+    (0x88f4, &[0xc5, 0xe1], false),
+    (0x8d54, &[0xc5, 0xe2], false),
+    (0x966a, &[0xc5, 0xe3], false),
+    (0x914d, &[0xc5, 0xe4], false),
+    (0x4f69, &[0xc5, 0xe5], false),
+    (0x6c9b, &[0xc5, 0xe6], false),
+    (0x55b7, &[0xc5, 0xe7], false),
+    (0x76c6, &[0xc5, 0xe8], false),
+    (0x7830, &[0xc5, 0xe9], false),
+    (0x62a8, &[0xc5, 0xea], false),
+    (0x70f9, &[0xc5, 0xeb], false),
+    (0x6f8e, &[0xc5, 0xec], false),
+    (0x5f6d, &[0xc5, 0xed], false),
+    (0x84ec, &[0xc5, 0xee], false),
+    (0x68da, &[0xc5, 0xef], false),
+    (0x787c, &[0xc5, 0xf0], false),
+    (0x7bf7, &[0xc5, 0xf1], false),
+    (0x81a8, &[0xc5, 0xf2], false),
+    (0x670b, &[0xc5, 0xf3], false),
+    (0x9e4f, &[0xc5, 0xf4], false),
+    (0x6367, &[0xc5, 0xf5], false),
+    (0x78b0, &[0xc5, 0xf6], false),
+    (0x576f, &[0xc5, 0xf7], false),
+    (0x7812, &[0xc5, 0xf8], false),
+    (0x9739, &[0xc5, 0xf9], false),
+    (0x6279, &[0xc5, 0xfa], false),
+    (0x62ab, &[0xc5, 0xfb], false),
+    (0x5288, &[0xc5, 0xfc], false),
+    (0x7435, &[0xc5, 0xfd], false),
+    (0x6bd7, &[0xc5, 0xfe], false),
+    (0x5564, &[0xc6, 0xa1], false),
+    (0x813e, &[0xc6, 0xa2], false),
+    (0x75b2, &[0xc6, 0xa3], false),
+    (0x76ae, &[0xc6, 0xa4], false),
+    (0x5339, &[0xc6, 0xa5], false),
+    (0x75de, &[0xc6, 0xa6], false),
+    (0x50fb, &[0xc6, 0xa7], false),
+    (0x5c41, &[0xc6, 0xa8], false),
+    (0x8b6c, &[0xc6, 0xa9], false),
+    (0x7bc7, &[0xc6, 0xaa], false),
+    (0x504f, &[0xc6, 0xab], false),
+    (0x7247, &[0xc6, 0xac], false),
+    (0x9a97, &[0xc6, 0xad], false),
+    (0x98d8, &[0xc6, 0xae], false),
+    (0x6f02, &[0xc6, 0xaf], false),
+    (0x74e2, &[0xc6, 0xb0], false),
+    (0x7968, &[0xc6, 0xb1], false),
+    (0x6487, &[0xc6, 0xb2], false),
+    (0x77a5, &[0xc6, 0xb3], false),
+    (0x62fc, &[0xc6, 0xb4], false),
+    (0x9891, &[0xc6, 0xb5], false),
+    (0x8d2b, &[0xc6, 0xb6], false),
+    (0x54c1, &[0xc6, 0xb7], false),
+    (0x8058, &[0xc6, 0xb8], false),
+    (0x4e52, &[0xc6, 0xb9], false),
+    (0x576a, &[0xc6, 0xba], false),
+    (0x82f9, &[0xc6, 0xbb], false),
+    (0x840d, &[0xc6, 0xbc], false),
+    (0x5e73, &[0xc6, 0xbd], false),
+    (0x51ed, &[0xc6, 0xbe], false),
+    (0x74f6, &[0xc6, 0xbf], false),
+    (0x8bc4, &[0xc6, 0xc0], false),
+    (0x5c4f, &[0xc6, 0xc1], false),
+    (0x5761, &[0xc6, 0xc2], false),
+    (0x6cfc, &[0xc6, 0xc3], false),
+    (0x9887, &[0xc6, 0xc4], false),
+    (0x5a46, &[0xc6, 0xc5], false),
+    (0x7834, &[0xc6, 0xc6], false),
+    (0x9b44, &[0xc6, 0xc7], false),
+    (0x8feb, &[0xc6, 0xc8], false),
+    (0x7c95, &[0xc6, 0xc9], false),
+    (0x5256, &[0xc6, 0xca], false),
+    (0x6251, &[0xc6, 0xcb], false),
+    (0x94fa, &[0xc6, 0xcc], false),
+    (0x4ec6, &[0xc6, 0xcd], false),
+    (0x8386, &[0xc6, 0xce], false),
+    (0x8461, &[0xc6, 0xcf], false),
+    (0x83e9, &[0xc6, 0xd0], false),
+    (0x84b2, &[0xc6, 0xd1], false),
+    (0x57d4, &[0xc6, 0xd2], false),
+    (0x6734, &[0xc6, 0xd3], false),
+    (0x5703, &[0xc6, 0xd4], false),
+    (0x666e, &[0xc6, 0xd5], false),
+    (0x6d66, &[0xc6, 0xd6], false),
+    (0x8c31, &[0xc6, 0xd7], false),
+    (0x66dd, &[0xc6, 0xd8], false),
+    (0x7011, &[0xc6, 0xd9], false),
+    (0x671f, &[0xc6, 0xda], false),
+    (0x6b3a, &[0xc6, 0xdb], false),
+    (0x6816, &[0xc6, 0xdc], false),
+    (0x621a, &[0xc6, 0xdd], false),
+    (0x59bb, &[0xc6, 0xde], false),
+    (0x4e03, &[0xc6, 0xdf], false),
+    (0x51c4, &[0xc6, 0xe0], false),
+    (0x6f06, &[0xc6, 0xe1], false),
+    (0x67d2, &[0xc6, 0xe2], false),
+    (0x6c8f, &[0xc6, 0xe3], false),
+    (0x5176, &[0xc6, 0xe4], false),
+    (0x68cb, &[0xc6, 0xe5], false),
+    (0x5947, &[0xc6, 0xe6], false),
+    (0x6b67, &[0xc6, 0xe7], false),
+    (0x7566, &[0xc6, 0xe8], false),
+    (0x5d0e, &[0xc6, 0xe9], false),
+    (0x8110, &[0xc6, 0xea], false),
+    (0x9f50, &[0xc6, 0xeb], false),
+    (0x65d7, &[0xc6, 0xec], false),
+    (0x7948, &[0xc6, 0xed], false),
+    (0x7941, &[0xc6, 0xee], false),
+    (0x9a91, &[0xc6, 0xef], false),
+    (0x8d77, &[0xc6, 0xf0], false),
+    (0x5c82, &[0xc6, 0xf1], false),
+    (0x4e5e, &[0xc6, 0xf2], false),
+    (0x4f01, &[0xc6, 0xf3], false),
+    (0x542f, &[0xc6, 0xf4], false),
+    (0x5951, &[0xc6, 0xf5], false),
+    (0x780c, &[0xc6, 0xf6], false),
+    (0x5668, &[0xc6, 0xf7], false),
+    (0x6c14, &[0xc6, 0xf8], false),
+    (0x8fc4, &[0xc6, 0xf9], false),
+    (0x5f03, &[0xc6, 0xfa], false),
+    (0x6c7d, &[0xc6, 0xfb], false),
+    (0x6ce3, &[0xc6, 0xfc], false),
+    (0x8bab, &[0xc6, 0xfd], false),
+    (0x6390, &[0xc6, 0xfe], false),
+    (0x6070, &[0xc7, 0xa1], false),
+    (0x6d3d, &[0xc7, 0xa2], false),
+    (0x7275, &[0xc7, 0xa3], false),
+    (0x6266, &[0xc7, 0xa4], false),
+    (0x948e, &[0xc7, 0xa5], false),
+    (0x94c5, &[0xc7, 0xa6], false),
+    (0x5343, &[0xc7, 0xa7], false),
+    (0x8fc1, &[0xc7, 0xa8], false),
+    (0x7b7e, &[0xc7, 0xa9], false),
+    (0x4edf, &[0xc7, 0xaa], false),
+    (0x8c26, &[0xc7, 0xab], false),
+    (0x4e7e, &[0xc7, 0xac], false),
+    (0x9ed4, &[0xc7, 0xad], false),
+    (0x94b1, &[0xc7, 0xae], false),
+    (0x94b3, &[0xc7, 0xaf], false),
+    (0x524d, &[0xc7, 0xb0], false),
+    (0x6f5c, &[0xc7, 0xb1], false),
+    (0x9063, &[0xc7, 0xb2], false),
+    (0x6d45, &[0xc7, 0xb3], false),
+    (0x8c34, &[0xc7, 0xb4], false),
+    (0x5811, &[0xc7, 0xb5], false),
+    (0x5d4c, &[0xc7, 0xb6], false),
+    (0x6b20, &[0xc7, 0xb7], false),
+    (0x6b49, &[0xc7, 0xb8], false),
+    (0x67aa, &[0xc7, 0xb9], false),
+    (0x545b, &[0xc7, 0xba], false),
+    (0x8154, &[0xc7, 0xbb], false),
+    (0x7f8c, &[0xc7, 0xbc], false),
+    (0x5899, &[0xc7, 0xbd], false),
+    (0x8537, &[0xc7, 0xbe], false),
+    (0x5f3a, &[0xc7, 0xbf], false),
+    (0x62a2, &[0xc7, 0xc0], false),
+    (0x6a47, &[0xc7, 0xc1], false),
+    (0x9539, &[0xc7, 0xc2], false),
+    (0x6572, &[0xc7, 0xc3], false),
+    (0x6084, &[0xc7, 0xc4], false),
+    (0x6865, &[0xc7, 0xc5], false),
+    (0x77a7, &[0xc7, 0xc6], false),
+    (0x4e54, &[0xc7, 0xc7], false),
+    (0x4fa8, &[0xc7, 0xc8], false),
+    (0x5de7, &[0xc7, 0xc9], false),
+    (0x9798, &[0xc7, 0xca], false),
+    (0x64ac, &[0xc7, 0xcb], false),
+    (0x7fd8, &[0xc7, 0xcc], false),
+    (0x5ced, &[0xc7, 0xcd], false),
+    (0x4fcf, &[0xc7, 0xce], false),
+    (0x7a8d, &[0xc7, 0xcf], false),
+    (0x5207, &[0xc7, 0xd0], false),
+    (0x8304, &[0xc7, 0xd1], false),
+    (0x4e14, &[0xc7, 0xd2], false),
+    (0x602f, &[0xc7, 0xd3], false),
+    (0x7a83, &[0xc7, 0xd4], false),
+    (0x94a6, &[0xc7, 0xd5], false),
+    (0x4fb5, &[0xc7, 0xd6], false),
+    (0x4eb2, &[0xc7, 0xd7], false),
+    (0x79e6, &[0xc7, 0xd8], false),
+    (0x7434, &[0xc7, 0xd9], false),
+    (0x52e4, &[0xc7, 0xda], false),
+    (0x82b9, &[0xc7, 0xdb], false),
+    (0x64d2, &[0xc7, 0xdc], false),
+    (0x79bd, &[0xc7, 0xdd], false),
+    (0x5bdd, &[0xc7, 0xde], false),
+    (0x6c81, &[0xc7, 0xdf], false),
+    (0x9752, &[0xc7, 0xe0], false),
+    (0x8f7b, &[0xc7, 0xe1], false),
+    (0x6c22, &[0xc7, 0xe2], false),
+    (0x503e, &[0xc7, 0xe3], false),
+    (0x537f, &[0xc7, 0xe4], false),
+    (0x6e05, &[0xc7, 0xe5], false),
+    (0x64ce, &[0xc7, 0xe6], false),
+    (0x6674, &[0xc7, 0xe7], false),
+    (0x6c30, &[0xc7, 0xe8], false),
+    (0x60c5, &[0xc7, 0xe9], false),
+    (0x9877, &[0xc7, 0xea], false),
+    (0x8bf7, &[0xc7, 0xeb], false),
+    (0x5e86, &[0xc7, 0xec], false),
+    (0x743c, &[0xc7, 0xed], false),
+    (0x7a77, &[0xc7, 0xee], false),
+    (0x79cb, &[0xc7, 0xef], false),
+    (0x4e18, &[0xc7, 0xf0], false),
+    (0x90b1, &[0xc7, 0xf1], false),
+    (0x7403, &[0xc7, 0xf2], false),
+    (0x6c42, &[0xc7, 0xf3], false),
+    (0x56da, &[0xc7, 0xf4], false),
+    (0x914b, &[0xc7, 0xf5], false),
+    (0x6cc5, &[0xc7, 0xf6], false),
+    (0x8d8b, &[0xc7, 0xf7], false),
+    (0x533a, &[0xc7, 0xf8], false),
+    (0x86c6, &[0xc7, 0xf9], false),
+    (0x66f2, &[0xc7, 0xfa], false),
+    (0x8eaf, &[0xc7, 0xfb], false),
+    (0x5c48, &[0xc7, 0xfc], false),
+    (0x9a71, &[0xc7, 0xfd], false),
+    (0x6e20, &[0xc7, 0xfe], false),
+    (0x53d6, &[0xc8, 0xa1], false),
+    (0x5a36, &[0xc8, 0xa2], false),
+    (0x9f8b, &[0xc8, 0xa3], false),
+    (0x8da3, &[0xc8, 0xa4], false),
+    (0x53bb, &[0xc8, 0xa5], false),
+    (0x5708, &[0xc8, 0xa6], false),
+    (0x98a7, &[0xc8, 0xa7], false),
+    (0x6743, &[0xc8, 0xa8], false),
+    (0x919b, &[0xc8, 0xa9], false),
+    (0x6cc9, &[0xc8, 0xaa], false),
+    (0x5168, &[0xc8, 0xab], false),
+    (0x75ca, &[0xc8, 0xac], false),
+    (0x62f3, &[0xc8, 0xad], false),
+    (0x72ac, &[0xc8, 0xae], false),
+    (0x5238, &[0xc8, 0xaf], false),
+    (0x529d, &[0xc8, 0xb0], false),
+    (0x7f3a, &[0xc8, 0xb1], false),
+    (0x7094, &[0xc8, 0xb2], false),
+    (0x7638, &[0xc8, 0xb3], false),
+    (0x5374, &[0xc8, 0xb4], false),
+    (0x9e4a, &[0xc8, 0xb5], false),
+    (0x69b7, &[0xc8, 0xb6], false),
+    (0x786e, &[0xc8, 0xb7], false),
+    (0x96c0, &[0xc8, 0xb8], false),
+    (0x88d9, &[0xc8, 0xb9], false),
+    (0x7fa4, &[0xc8, 0xba], false),
+    (0x7136, &[0xc8, 0xbb], false),
+    (0x71c3, &[0xc8, 0xbc], false),
+    (0x5189, &[0xc8, 0xbd], false),
+    (0x67d3, &[0xc8, 0xbe], false),
+    (0x74e4, &[0xc8, 0xbf], false),
+    (0x58e4, &[0xc8, 0xc0], false),
+    (0x6518, &[0xc8, 0xc1], false),
+    (0x56b7, &[0xc8, 0xc2], false),
+    (0x8ba9, &[0xc8, 0xc3], false),
+    (0x9976, &[0xc8, 0xc4], false),
+    (0x6270, &[0xc8, 0xc5], false),
+    (0x7ed5, &[0xc8, 0xc6], false),
+    (0x60f9, &[0xc8, 0xc7], false),
+    (0x70ed, &[0xc8, 0xc8], false),
+    (0x58ec, &[0xc8, 0xc9], false),
+    (0x4ec1, &[0xc8, 0xca], false),
+    (0x4eba, &[0xc8, 0xcb], false),
+    (0x5fcd, &[0xc8, 0xcc], false),
+    (0x97e7, &[0xc8, 0xcd], false),
+    (0x4efb, &[0xc8, 0xce], false),
+    (0x8ba4, &[0xc8, 0xcf], false),
+    (0x5203, &[0xc8, 0xd0], false),
+    (0x598a, &[0xc8, 0xd1], false),
+    (0x7eab, &[0xc8, 0xd2], false),
+    (0x6254, &[0xc8, 0xd3], false),
+    (0x4ecd, &[0xc8, 0xd4], false),
+    (0x65e5, &[0xc8, 0xd5], false),
+    (0x620e, &[0xc8, 0xd6], false),
+    (0x8338, &[0xc8, 0xd7], false),
+    (0x84c9, &[0xc8, 0xd8], false),
+    (0x8363, &[0xc8, 0xd9], false),
+    (0x878d, &[0xc8, 0xda], false),
+    (0x7194, &[0xc8, 0xdb], false),
+    (0x6eb6, &[0xc8, 0xdc], false),
+    (0x5bb9, &[0xc8, 0xdd], false),
+    (0x7ed2, &[0xc8, 0xde], false),
+    (0x5197, &[0xc8, 0xdf], false),
+    (0x63c9, &[0xc8, 0xe0], false),
+    (0x67d4, &[0xc8, 0xe1], false),
+    (0x8089, &[0xc8, 0xe2], false),
+    (0x8339, &[0xc8, 0xe3], false),
+    (0x8815, &[0xc8, 0xe4], false),
+    (0x5112, &[0xc8, 0xe5], false),
+    (0x5b7a, &[0xc8, 0xe6], false),
+    (0x5982, &[0xc8, 0xe7], false),
+    (0x8fb1, &[0xc8, 0xe8], false),
+    (0x4e73, &[0xc8, 0xe9], false),
+    (0x6c5d, &[0xc8, 0xea], false),
+    (0x5165, &[0xc8, 0xeb], false),
+    (0x8925, &[0xc8, 0xec], false),
+    (0x8f6f, &[0xc8, 0xed], false),
+    (0x962e, &[0xc8, 0xee], false),
+    (0x854a, &[0xc8, 0xef], false),
+    (0x745e, &[0xc8, 0xf0], false),
+    (0x9510, &[0xc8, 0xf1], false),
+    (0x95f0, &[0xc8, 0xf2], false),
+    (0x6da6, &[0xc8, 0xf3], false),
+    (0x82e5, &[0xc8, 0xf4], false),
+    (0x5f31, &[0xc8, 0xf5], false),
+    (0x6492, &[0xc8, 0xf6], false),
+    (0x6d12, &[0xc8, 0xf7], false),
+    (0x8428, &[0xc8, 0xf8], false),
+    (0x816e, &[0xc8, 0xf9], false),
+    (0x9cc3, &[0xc8, 0xfa], false),
+    (0x585e, &[0xc8, 0xfb], false),
+    (0x8d5b, &[0xc8, 0xfc], false),
+    (0x4e09, &[0xc8, 0xfd], false),
+    (0x53c1, &[0xc8, 0xfe], false),
+    (0x4f1e, &[0xc9, 0xa1], false),
+    (0x6563, &[0xc9, 0xa2], false),
+    (0x6851, &[0xc9, 0xa3], false),
+    (0x55d3, &[0xc9, 0xa4], false),
+    (0x4e27, &[0xc9, 0xa5], false),
+    (0x6414, &[0xc9, 0xa6], false),
+    (0x9a9a, &[0xc9, 0xa7], false),
+    (0x626b, &[0xc9, 0xa8], false),
+    (0x5ac2, &[0xc9, 0xa9], false),
+    (0x745f, &[0xc9, 0xaa], false),
+    (0x8272, &[0xc9, 0xab], false),
+    (0x6da9, &[0xc9, 0xac], false),
+    (0x68ee, &[0xc9, 0xad], false),
+    (0x50e7, &[0xc9, 0xae], false),
+    (0x838e, &[0xc9, 0xaf], false),
+    (0x7802, &[0xc9, 0xb0], false),
+    (0x6740, &[0xc9, 0xb1], false),
+    (0x5239, &[0xc9, 0xb2], false),
+    (0x6c99, &[0xc9, 0xb3], false),
+    (0x7eb1, &[0xc9, 0xb4], false),
+    (0x50bb, &[0xc9, 0xb5], false),
+    (0x5565, &[0xc9, 0xb6], false),
+    (0x715e, &[0xc9, 0xb7], false),
+    (0x7b5b, &[0xc9, 0xb8], false),
+    (0x6652, &[0xc9, 0xb9], false),
+    (0x73ca, &[0xc9, 0xba], false),
+    (0x82eb, &[0xc9, 0xbb], false),
+    (0x6749, &[0xc9, 0xbc], false),
+    (0x5c71, &[0xc9, 0xbd], false),
+    (0x5220, &[0xc9, 0xbe], false),
+    (0x717d, &[0xc9, 0xbf], false),
+    (0x886b, &[0xc9, 0xc0], false),
+    (0x95ea, &[0xc9, 0xc1], false),
+    (0x9655, &[0xc9, 0xc2], false),
+    (0x64c5, &[0xc9, 0xc3], false),
+    (0x8d61, &[0xc9, 0xc4], false),
+    (0x81b3, &[0xc9, 0xc5], false),
+    (0x5584, &[0xc9, 0xc6], false),
+    (0x6c55, &[0xc9, 0xc7], false),
+    (0x6247, &[0xc9, 0xc8], false),
+    (0x7f2e, &[0xc9, 0xc9], false),
+    (0x5892, &[0xc9, 0xca], false),
+    (0x4f24, &[0xc9, 0xcb], false),
+    (0x5546, &[0xc9, 0xcc], false),
+    (0x8d4f, &[0xc9, 0xcd], false),
+    (0x664c, &[0xc9, 0xce], false),
+    (0x4e0a, &[0xc9, 0xcf], false),
+    (0x5c1a, &[0xc9, 0xd0], false),
+    (0x88f3, &[0xc9, 0xd1], false),
+    (0x68a2, &[0xc9, 0xd2], false),
+    (0x634e, &[0xc9, 0xd3], false),
+    (0x7a0d, &[0xc9, 0xd4], false),
+    (0x70e7, &[0xc9, 0xd5], false),
+    (0x828d, &[0xc9, 0xd6], false),
+    (0x52fa, &[0xc9, 0xd7], false),
+    (0x97f6, &[0xc9, 0xd8], false),
+    (0x5c11, &[0xc9, 0xd9], false),
+    (0x54e8, &[0xc9, 0xda], false),
+    (0x90b5, &[0xc9, 0xdb], false),
+    (0x7ecd, &[0xc9, 0xdc], false),
+    (0x5962, &[0xc9, 0xdd], false),
+    (0x8d4a, &[0xc9, 0xde], false),
+    (0x86c7, &[0xc9, 0xdf], false),
+    (0x820c, &[0xc9, 0xe0], false),
+    (0x820d, &[0xc9, 0xe1], false),
+    (0x8d66, &[0xc9, 0xe2], false),
+    (0x6444, &[0xc9, 0xe3], false),
+    (0x5c04, &[0xc9, 0xe4], false),
+    (0x6151, &[0xc9, 0xe5], false),
+    (0x6d89, &[0xc9, 0xe6], false),
+    (0x793e, &[0xc9, 0xe7], false),
+    (0x8bbe, &[0xc9, 0xe8], false),
+    (0x7837, &[0xc9, 0xe9], false),
+    (0x7533, &[0xc9, 0xea], false),
+    (0x547b, &[0xc9, 0xeb], false),
+    (0x4f38, &[0xc9, 0xec], false),
+    (0x8eab, &[0xc9, 0xed], false),
+    (0x6df1, &[0xc9, 0xee], false),
+    (0x5a20, &[0xc9, 0xef], false),
+    (0x7ec5, &[0xc9, 0xf0], false),
+    (0x795e, &[0xc9, 0xf1], false),
+    (0x6c88, &[0xc9, 0xf2], false),
+    (0x5ba1, &[0xc9, 0xf3], false),
+    (0x5a76, &[0xc9, 0xf4], false),
+    (0x751a, &[0xc9, 0xf5], false),
+    (0x80be, &[0xc9, 0xf6], false),
+    (0x614e, &[0xc9, 0xf7], false),
+    (0x6e17, &[0xc9, 0xf8], false),
+    (0x58f0, &[0xc9, 0xf9], false),
+    (0x751f, &[0xc9, 0xfa], false),
+    (0x7525, &[0xc9, 0xfb], false),
+    (0x7272, &[0xc9, 0xfc], false),
+    (0x5347, &[0xc9, 0xfd], false),
+    (0x7ef3, &[0xc9, 0xfe], false),
+    (0x7701, &[0xca, 0xa1], false),
+    (0x76db, &[0xca, 0xa2], false),
+    (0x5269, &[0xca, 0xa3], false),
+    (0x80dc, &[0xca, 0xa4], false),
+    (0x5723, &[0xca, 0xa5], false),
+    (0x5e08, &[0xca, 0xa6], false),
+    (0x5931, &[0xca, 0xa7], false),
+    (0x72ee, &[0xca, 0xa8], false),
+    (0x65bd, &[0xca, 0xa9], false),
+    (0x6e7f, &[0xca, 0xaa], false),
+    (0x8bd7, &[0xca, 0xab], false),
+    (0x5c38, &[0xca, 0xac], false),
+    (0x8671, &[0xca, 0xad], false),
+    (0x5341, &[0xca, 0xae], false),
+    (0x77f3, &[0xca, 0xaf], false),
+    (0x62fe, &[0xca, 0xb0], false),
+    (0x65f6, &[0xca, 0xb1], false),
+    (0x4ec0, &[0xca, 0xb2], false),
+    (0x98df, &[0xca, 0xb3], false),
+    (0x8680, &[0xca, 0xb4], false),
+    (0x5b9e, &[0xca, 0xb5], false),
+    (0x8bc6, &[0xca, 0xb6], false),
+    (0x53f2, &[0xca, 0xb7], false),
+    (0x77e2, &[0xca, 0xb8], false),
+    (0x4f7f, &[0xca, 0xb9], false),
+    (0x5c4e, &[0xca, 0xba], false),
+    (0x9a76, &[0xca, 0xbb], false),
+    (0x59cb, &[0xca, 0xbc], false),
+    (0x5f0f, &[0xca, 0xbd], false),
+    (0x793a, &[0xca, 0xbe], false),
+    (0x58eb, &[0xca, 0xbf], false),
+    (0x4e16, &[0xca, 0xc0], false),
+    (0x67ff, &[0xca, 0xc1], false),
+    (0x4e8b, &[0xca, 0xc2], false),
+    (0x62ed, &[0xca, 0xc3], false),
+    (0x8a93, &[0xca, 0xc4], false),
+    (0x901d, &[0xca, 0xc5], false),
+    (0x52bf, &[0xca, 0xc6], false),
+    (0x662f, &[0xca, 0xc7], false),
+    (0x55dc, &[0xca, 0xc8], false),
+    (0x566c, &[0xca, 0xc9], false),
+    (0x9002, &[0xca, 0xca], false),
+    (0x4ed5, &[0xca, 0xcb], false),
+    (0x4f8d, &[0xca, 0xcc], false),
+    (0x91ca, &[0xca, 0xcd], false),
+    (0x9970, &[0xca, 0xce], false),
+    (0x6c0f, &[0xca, 0xcf], false),
+    (0x5e02, &[0xca, 0xd0], false),
+    (0x6043, &[0xca, 0xd1], false),
+    (0x5ba4, &[0xca, 0xd2], false),
+    (0x89c6, &[0xca, 0xd3], false),
+    (0x8bd5, &[0xca, 0xd4], false),
+    (0x6536, &[0xca, 0xd5], false),
+    (0x624b, &[0xca, 0xd6], false),
+    (0x9996, &[0xca, 0xd7], false),
+    (0x5b88, &[0xca, 0xd8], false),
+    (0x5bff, &[0xca, 0xd9], false),
+    (0x6388, &[0xca, 0xda], false),
+    (0x552e, &[0xca, 0xdb], false),
+    (0x53d7, &[0xca, 0xdc], false),
+    (0x7626, &[0xca, 0xdd], false),
+    (0x517d, &[0xca, 0xde], false),
+    (0x852c, &[0xca, 0xdf], false),
+    (0x67a2, &[0xca, 0xe0], false),
+    (0x68b3, &[0xca, 0xe1], false),
+    (0x6b8a, &[0xca, 0xe2], false),
+    (0x6292, &[0xca, 0xe3], false),
+    (0x8f93, &[0xca, 0xe4], false),
+    (0x53d4, &[0xca, 0xe5], false),
+    (0x8212, &[0xca, 0xe6], false),
+    (0x6dd1, &[0xca, 0xe7], false),
+    (0x758f, &[0xca, 0xe8], false),
+    (0x4e66, &[0xca, 0xe9], false),
+    (0x8d4e, &[0xca, 0xea], false),
+    (0x5b70, &[0xca, 0xeb], false),
+    (0x719f, &[0xca, 0xec], false),
+    (0x85af, &[0xca, 0xed], false),
+    (0x6691, &[0xca, 0xee], false),
+    (0x66d9, &[0xca, 0xef], false),
+    (0x7f72, &[0xca, 0xf0], false),
+    (0x8700, &[0xca, 0xf1], false),
+    (0x9ecd, &[0xca, 0xf2], false),
+    (0x9f20, &[0xca, 0xf3], false),
+    (0x5c5e, &[0xca, 0xf4], false),
+    (0x672f, &[0xca, 0xf5], false),
+    (0x8ff0, &[0xca, 0xf6], false),
+    (0x6811, &[0xca, 0xf7], false),
+    (0x675f, &[0xca, 0xf8], false),
+    (0x620d, &[0xca, 0xf9], false),
+    (0x7ad6, &[0xca, 0xfa], false),
+    (0x5885, &[0xca, 0xfb], false),
+    (0x5eb6, &[0xca, 0xfc], false),
+    (0x6570, &[0xca, 0xfd], false),
+    (0x6f31, &[0xca, 0xfe], false),
+    (0x6055, &[0xcb, 0xa1], false),
+    (0x5237, &[0xcb, 0xa2], false),
+    (0x800d, &[0xcb, 0xa3], false),
+    (0x6454, &[0xcb, 0xa4], false),
+    (0x8870, &[0xcb, 0xa5], false),
+    (0x7529, &[0xcb, 0xa6], false),
+    (0x5e05, &[0xcb, 0xa7], false),
+    (0x6813, &[0xcb, 0xa8], false),
+    (0x62f4, &[0xcb, 0xa9], false),
+    (0x971c, &[0xcb, 0xaa], false),
+    (0x53cc, &[0xcb, 0xab], false),
+    (0x723d, &[0xcb, 0xac], false),
+    (0x8c01, &[0xcb, 0xad], false),
+    (0x6c34, &[0xcb, 0xae], false),
+    (0x7761, &[0xcb, 0xaf], false),
+    (0x7a0e, &[0xcb, 0xb0], false),
+    (0x542e, &[0xcb, 0xb1], false),
+    (0x77ac, &[0xcb, 0xb2], false),
+    (0x987a, &[0xcb, 0xb3], false),
+    (0x821c, &[0xcb, 0xb4], false),
+    (0x8bf4, &[0xcb, 0xb5], false),
+    (0x7855, &[0xcb, 0xb6], false),
+    (0x6714, &[0xcb, 0xb7], false),
+    (0x70c1, &[0xcb, 0xb8], false),
+    (0x65af, &[0xcb, 0xb9], false),
+    (0x6495, &[0xcb, 0xba], false),
+    (0x5636, &[0xcb, 0xbb], false),
+    (0x601d, &[0xcb, 0xbc], false),
+    (0x79c1, &[0xcb, 0xbd], false),
+    (0x53f8, &[0xcb, 0xbe], false),
+    (0x4e1d, &[0xcb, 0xbf], false),
+    (0x6b7b, &[0xcb, 0xc0], false),
+    (0x8086, &[0xcb, 0xc1], false),
+    (0x5bfa, &[0xcb, 0xc2], false),
+    (0x55e3, &[0xcb, 0xc3], false),
+    (0x56db, &[0xcb, 0xc4], false),
+    (0x4f3a, &[0xcb, 0xc5], false),
+    (0x4f3c, &[0xcb, 0xc6], false),
+    (0x9972, &[0xcb, 0xc7], false),
+    (0x5df3, &[0xcb, 0xc8], false),
+    (0x677e, &[0xcb, 0xc9], false),
+    (0x8038, &[0xcb, 0xca], false),
+    (0x6002, &[0xcb, 0xcb], false),
+    (0x9882, &[0xcb, 0xcc], false),
+    (0x9001, &[0xcb, 0xcd], false),
+    (0x5b8b, &[0xcb, 0xce], false),
+    (0x8bbc, &[0xcb, 0xcf], false),
+    (0x8bf5, &[0xcb, 0xd0], false),
+    (0x641c, &[0xcb, 0xd1], false),
+    (0x8258, &[0xcb, 0xd2], false),
+    (0x64de, &[0xcb, 0xd3], false),
+    (0x55fd, &[0xcb, 0xd4], false),
+    (0x82cf, &[0xcb, 0xd5], false),
+    (0x9165, &[0xcb, 0xd6], false),
+    (0x4fd7, &[0xcb, 0xd7], false),
+    (0x7d20, &[0xcb, 0xd8], false),
+    (0x901f, &[0xcb, 0xd9], false),
+    (0x7c9f, &[0xcb, 0xda], false),
+    (0x50f3, &[0xcb, 0xdb], false),
+    (0x5851, &[0xcb, 0xdc], false),
+    (0x6eaf, &[0xcb, 0xdd], false),
+    (0x5bbf, &[0xcb, 0xde], false),
+    (0x8bc9, &[0xcb, 0xdf], false),
+    (0x8083, &[0xcb, 0xe0], false),
+    (0x9178, &[0xcb, 0xe1], false),
+    (0x849c, &[0xcb, 0xe2], false),
+    (0x7b97, &[0xcb, 0xe3], false),
+    (0x867d, &[0xcb, 0xe4], false),
+    (0x968b, &[0xcb, 0xe5], false),
+    (0x968f, &[0xcb, 0xe6], false),
+    (0x7ee5, &[0xcb, 0xe7], false),
+    (0x9ad3, &[0xcb, 0xe8], false),
+    (0x788e, &[0xcb, 0xe9], false),
+    (0x5c81, &[0xcb, 0xea], false),
+    (0x7a57, &[0xcb, 0xeb], false),
+    (0x9042, &[0xcb, 0xec], false),
+    (0x96a7, &[0xcb, 0xed], false),
+    (0x795f, &[0xcb, 0xee], false),
+    (0x5b59, &[0xcb, 0xef], false),
+    (0x635f, &[0xcb, 0xf0], false),
+    (0x7b0b, &[0xcb, 0xf1], false),
+    (0x84d1, &[0xcb, 0xf2], false),
+    (0x68ad, &[0xcb, 0xf3], false),
+    (0x5506, &[0xcb, 0xf4], false),
+    (0x7f29, &[0xcb, 0xf5], false),
+    (0x7410, &[0xcb, 0xf6], false),
+    (0x7d22, &[0xcb, 0xf7], false),
+    (0x9501, &[0xcb, 0xf8], false),
+    (0x6240, &[0xcb, 0xf9], false),
+    (0x584c, &[0xcb, 0xfa], false),
+    (0x4ed6, &[0xcb, 0xfb], false),
+    (0x5b83, &[0xcb, 0xfc], false),
+    (0x5979, &[0xcb, 0xfd], false),
+    (0x5854, &[0xcb, 0xfe], false),
+    (0x736d, &[0xcc, 0xa1], false),
+    (0x631e, &[0xcc, 0xa2], false),
+    (0x8e4b, &[0xcc, 0xa3], false),
+    (0x8e0f, &[0xcc, 0xa4], false),
+    (0x80ce, &[0xcc, 0xa5], false),
+    (0x82d4, &[0xcc, 0xa6], false),
+    (0x62ac, &[0xcc, 0xa7], false),
+    (0x53f0, &[0xcc, 0xa8], false),
+    (0x6cf0, &[0xcc, 0xa9], false),
+    (0x915e, &[0xcc, 0xaa], false),
+    (0x592a, &[0xcc, 0xab], false),
+    (0x6001, &[0xcc, 0xac], false),
+    (0x6c70, &[0xcc, 0xad], false),
+    (0x574d, &[0xcc, 0xae], false),
+    (0x644a, &[0xcc, 0xaf], false),
+    (0x8d2a, &[0xcc, 0xb0], false),
+    (0x762b, &[0xcc, 0xb1], false),
+    (0x6ee9, &[0xcc, 0xb2], false),
+    (0x575b, &[0xcc, 0xb3], false),
+    (0x6a80, &[0xcc, 0xb4], false),
+    (0x75f0, &[0xcc, 0xb5], false),
+    (0x6f6d, &[0xcc, 0xb6], false),
+    (0x8c2d, &[0xcc, 0xb7], false),
+    (0x8c08, &[0xcc, 0xb8], false),
+    (0x5766, &[0xcc, 0xb9], false),
+    (0x6bef, &[0xcc, 0xba], false),
+    (0x8892, &[0xcc, 0xbb], false),
+    (0x78b3, &[0xcc, 0xbc], false),
+    (0x63a2, &[0xcc, 0xbd], false),
+    (0x53f9, &[0xcc, 0xbe], false),
+    (0x70ad, &[0xcc, 0xbf], false),
+    (0x6c64, &[0xcc, 0xc0], false),
+    (0x5858, &[0xcc, 0xc1], false),
+    (0x642a, &[0xcc, 0xc2], false),
+    (0x5802, &[0xcc, 0xc3], false),
+    (0x68e0, &[0xcc, 0xc4], false),
+    (0x819b, &[0xcc, 0xc5], false),
+    (0x5510, &[0xcc, 0xc6], false),
+    (0x7cd6, &[0xcc, 0xc7], false),
+    (0x5018, &[0xcc, 0xc8], false),
+    (0x8eba, &[0xcc, 0xc9], false),
+    (0x6dcc, &[0xcc, 0xca], false),
+    (0x8d9f, &[0xcc, 0xcb], false),
+    (0x70eb, &[0xcc, 0xcc], false),
+    (0x638f, &[0xcc, 0xcd], false),
+    (0x6d9b, &[0xcc, 0xce], false),
+    (0x6ed4, &[0xcc, 0xcf], false),
+    (0x7ee6, &[0xcc, 0xd0], false),
+    (0x8404, &[0xcc, 0xd1], false),
+    (0x6843, &[0xcc, 0xd2], false),
+    (0x9003, &[0xcc, 0xd3], false),
+    (0x6dd8, &[0xcc, 0xd4], false),
+    (0x9676, &[0xcc, 0xd5], false),
+    (0x8ba8, &[0xcc, 0xd6], false),
+    (0x5957, &[0xcc, 0xd7], false),
+    (0x7279, &[0xcc, 0xd8], false),
+    (0x85e4, &[0xcc, 0xd9], false),
+    (0x817e, &[0xcc, 0xda], false),
+    (0x75bc, &[0xcc, 0xdb], false),
+    (0x8a8a, &[0xcc, 0xdc], false),
+    (0x68af, &[0xcc, 0xdd], false),
+    (0x5254, &[0xcc, 0xde], false),
+    (0x8e22, &[0xcc, 0xdf], false),
+    (0x9511, &[0xcc, 0xe0], false),
+    (0x63d0, &[0xcc, 0xe1], false),
+    (0x9898, &[0xcc, 0xe2], false),
+    (0x8e44, &[0xcc, 0xe3], false),
+    (0x557c, &[0xcc, 0xe4], false),
+    (0x4f53, &[0xcc, 0xe5], false),
+    (0x66ff, &[0xcc, 0xe6], false),
+    (0x568f, &[0xcc, 0xe7], false),
+    (0x60d5, &[0xcc, 0xe8], false),
+    (0x6d95, &[0xcc, 0xe9], false),
+    (0x5243, &[0xcc, 0xea], false),
+    (0x5c49, &[0xcc, 0xeb], false),
+    (0x5929, &[0xcc, 0xec], false),
+    (0x6dfb, &[0xcc, 0xed], false),
+    (0x586b, &[0xcc, 0xee], false),
+    (0x7530, &[0xcc, 0xef], false),
+    (0x751c, &[0xcc, 0xf0], false),
+    (0x606c, &[0xcc, 0xf1], false),
+    (0x8214, &[0xcc, 0xf2], false),
+    (0x8146, &[0xcc, 0xf3], false),
+    (0x6311, &[0xcc, 0xf4], false),
+    (0x6761, &[0xcc, 0xf5], false),
+    (0x8fe2, &[0xcc, 0xf6], false),
+    (0x773a, &[0xcc, 0xf7], false),
+    (0x8df3, &[0xcc, 0xf8], false),
+    (0x8d34, &[0xcc, 0xf9], false),
+    (0x94c1, &[0xcc, 0xfa], false),
+    (0x5e16, &[0xcc, 0xfb], false),
+    (0x5385, &[0xcc, 0xfc], false),
+    (0x542c, &[0xcc, 0xfd], false),
+    (0x70c3, &[0xcc, 0xfe], false),
+    (0x6c40, &[0xcd, 0xa1], false),
+    (0x5ef7, &[0xcd, 0xa2], false),
+    (0x505c, &[0xcd, 0xa3], false),
+    (0x4ead, &[0xcd, 0xa4], false),
+    (0x5ead, &[0xcd, 0xa5], false),
+    (0x633a, &[0xcd, 0xa6], false),
+    (0x8247, &[0xcd, 0xa7], false),
+    (0x901a, &[0xcd, 0xa8], false),
+    (0x6850, &[0xcd, 0xa9], false),
+    (0x916e, &[0xcd, 0xaa], false),
+    (0x77b3, &[0xcd, 0xab], false),
+    (0x540c, &[0xcd, 0xac], false),
+    (0x94dc, &[0xcd, 0xad], false),
+    (0x5f64, &[0xcd, 0xae], false),
+    (0x7ae5, &[0xcd, 0xaf], false),
+    (0x6876, &[0xcd, 0xb0], false),
+    (0x6345, &[0xcd, 0xb1], false),
+    (0x7b52, &[0xcd, 0xb2], false),
+    (0x7edf, &[0xcd, 0xb3], false),
+    (0x75db, &[0xcd, 0xb4], false),
+    (0x5077, &[0xcd, 0xb5], false),
+    (0x6295, &[0xcd, 0xb6], false),
+    (0x5934, &[0xcd, 0xb7], false),
+    (0x900f, &[0xcd, 0xb8], false),
+    (0x51f8, &[0xcd, 0xb9], false),
+    (0x79c3, &[0xcd, 0xba], false),
+    (0x7a81, &[0xcd, 0xbb], false),
+    (0x56fe, &[0xcd, 0xbc], false),
+    (0x5f92, &[0xcd, 0xbd], false),
+    (0x9014, &[0xcd, 0xbe], false),
+    (0x6d82, &[0xcd, 0xbf], false),
+    (0x5c60, &[0xcd, 0xc0], false),
+    (0x571f, &[0xcd, 0xc1], false),
+    (0x5410, &[0xcd, 0xc2], false),
+    (0x5154, &[0xcd, 0xc3], false),
+    (0x6e4d, &[0xcd, 0xc4], false),
+    (0x56e2, &[0xcd, 0xc5], false),
+    (0x63a8, &[0xcd, 0xc6], false),
+    (0x9893, &[0xcd, 0xc7], false),
+    (0x817f, &[0xcd, 0xc8], false),
+    (0x8715, &[0xcd, 0xc9], false),
+    (0x892a, &[0xcd, 0xca], false),
+    (0x9000, &[0xcd, 0xcb], false),
+    (0x541e, &[0xcd, 0xcc], false),
+    (0x5c6f, &[0xcd, 0xcd], false),
+    (0x81c0, &[0xcd, 0xce], false),
+    (0x62d6, &[0xcd, 0xcf], false),
+    (0x6258, &[0xcd, 0xd0], false),
+    (0x8131, &[0xcd, 0xd1], false),
+    (0x9e35, &[0xcd, 0xd2], false),
+    (0x9640, &[0xcd, 0xd3], false),
+    (0x9a6e, &[0xcd, 0xd4], false),
+    (0x9a7c, &[0xcd, 0xd5], false),
+    (0x692d, &[0xcd, 0xd6], false),
+    (0x59a5, &[0xcd, 0xd7], false),
+    (0x62d3, &[0xcd, 0xd8], false),
+    (0x553e, &[0xcd, 0xd9], false),
+    (0x6316, &[0xcd, 0xda], false),
+    (0x54c7, &[0xcd, 0xdb], false),
+    (0x86d9, &[0xcd, 0xdc], false),
+    (0x6d3c, &[0xcd, 0xdd], false),
+    (0x5a03, &[0xcd, 0xde], false),
+    (0x74e6, &[0xcd, 0xdf], false),
+    (0x889c, &[0xcd, 0xe0], false),
+    (0x6b6a, &[0xcd, 0xe1], false),
+    (0x5916, &[0xcd, 0xe2], false),
+    (0x8c4c, &[0xcd, 0xe3], false),
+    (0x5f2f, &[0xcd, 0xe4], false),
+    (0x6e7e, &[0xcd, 0xe5], false),
+    (0x73a9, &[0xcd, 0xe6], false),
+    (0x987d, &[0xcd, 0xe7], false),
+    (0x4e38, &[0xcd, 0xe8], false),
+    (0x70f7, &[0xcd, 0xe9], false),
+    (0x5b8c, &[0xcd, 0xea], false),
+    (0x7897, &[0xcd, 0xeb], false),
+    (0x633d, &[0xcd, 0xec], false),
+    (0x665a, &[0xcd, 0xed], false),
+    (0x7696, &[0xcd, 0xee], false),
+    (0x60cb, &[0xcd, 0xef], false),
+    (0x5b9b, &[0xcd, 0xf0], false),
+    (0x5a49, &[0xcd, 0xf1], false),
+    (0x4e07, &[0xcd, 0xf2], false),
+    (0x8155, &[0xcd, 0xf3], false),
+    (0x6c6a, &[0xcd, 0xf4], false),
+    (0x738b, &[0xcd, 0xf5], false),
+    (0x4ea1, &[0xcd, 0xf6], false),
+    (0x6789, &[0xcd, 0xf7], false),
+    (0x7f51, &[0xcd, 0xf8], false),
+    (0x5f80, &[0xcd, 0xf9], false),
+    (0x65fa, &[0xcd, 0xfa], false),
+    (0x671b, &[0xcd, 0xfb], false),
+    (0x5fd8, &[0xcd, 0xfc], false),
+    (0x5984, &[0xcd, 0xfd], false),
+    (0x5a01, &[0xcd, 0xfe], false),
+    (0x5dcd, &[0xce, 0xa1], false),
+    (0x5fae, &[0xce, 0xa2], false),
+    (0x5371, &[0xce, 0xa3], false),
+    (0x97e6, &[0xce, 0xa4], false),
+    (0x8fdd, &[0xce, 0xa5], false),
+    (0x6845, &[0xce, 0xa6], false),
+    (0x56f4, &[0xce, 0xa7], false),
+    (0x552f, &[0xce, 0xa8], false),
+    (0x60df, &[0xce, 0xa9], false),
+    (0x4e3a, &[0xce, 0xaa], false),
+    (0x6f4d, &[0xce, 0xab], false),
+    (0x7ef4, &[0xce, 0xac], false),
+    (0x82c7, &[0xce, 0xad], false),
+    (0x840e, &[0xce, 0xae], false),
+    (0x59d4, &[0xce, 0xaf], false),
+    (0x4f1f, &[0xce, 0xb0], false),
+    (0x4f2a, &[0xce, 0xb1], false),
+    (0x5c3e, &[0xce, 0xb2], false),
+    (0x7eac, &[0xce, 0xb3], false),
+    (0x672a, &[0xce, 0xb4], false),
+    (0x851a, &[0xce, 0xb5], false),
+    (0x5473, &[0xce, 0xb6], false),
+    (0x754f, &[0xce, 0xb7], false),
+    (0x80c3, &[0xce, 0xb8], false),
+    (0x5582, &[0xce, 0xb9], false),
+    (0x9b4f, &[0xce, 0xba], false),
+    (0x4f4d, &[0xce, 0xbb], false),
+    (0x6e2d, &[0xce, 0xbc], false),
+    (0x8c13, &[0xce, 0xbd], false),
+    (0x5c09, &[0xce, 0xbe], false),
+    (0x6170, &[0xce, 0xbf], false),
+    (0x536b, &[0xce, 0xc0], false),
+    (0x761f, &[0xce, 0xc1], false),
+    (0x6e29, &[0xce, 0xc2], false),
+    (0x868a, &[0xce, 0xc3], false),
+    (0x6587, &[0xce, 0xc4], false),
+    (0x95fb, &[0xce, 0xc5], false),
+    (0x7eb9, &[0xce, 0xc6], false),
+    (0x543b, &[0xce, 0xc7], false),
+    (0x7a33, &[0xce, 0xc8], false),
+    (0x7d0a, &[0xce, 0xc9], false),
+    (0x95ee, &[0xce, 0xca], false),
+    (0x55e1, &[0xce, 0xcb], false),
+    (0x7fc1, &[0xce, 0xcc], false),
+    (0x74ee, &[0xce, 0xcd], false),
+    (0x631d, &[0xce, 0xce], false),
+    (0x8717, &[0xce, 0xcf], false),
+    (0x6da1, &[0xce, 0xd0], false),
+    (0x7a9d, &[0xce, 0xd1], false),
+    (0x6211, &[0xce, 0xd2], false),
+    (0x65a1, &[0xce, 0xd3], false),
+    (0x5367, &[0xce, 0xd4], false),
+    (0x63e1, &[0xce, 0xd5], false),
+    (0x6c83, &[0xce, 0xd6], false),
+    (0x5deb, &[0xce, 0xd7], false),
+    (0x545c, &[0xce, 0xd8], false),
+    (0x94a8, &[0xce, 0xd9], false),
+    (0x4e4c, &[0xce, 0xda], false),
+    (0x6c61, &[0xce, 0xdb], false),
+    (0x8bec, &[0xce, 0xdc], false),
+    (0x5c4b, &[0xce, 0xdd], false),
+    (0x65e0, &[0xce, 0xde], false),
+    (0x829c, &[0xce, 0xdf], false),
+    (0x68a7, &[0xce, 0xe0], false),
+    (0x543e, &[0xce, 0xe1], false),
+    (0x5434, &[0xce, 0xe2], false),
+    (0x6bcb, &[0xce, 0xe3], false),
+    (0x6b66, &[0xce, 0xe4], false),
+    (0x4e94, &[0xce, 0xe5], false),
+    (0x6342, &[0xce, 0xe6], false),
+    (0x5348, &[0xce, 0xe7], false),
+    (0x821e, &[0xce, 0xe8], false),
+    (0x4f0d, &[0xce, 0xe9], false),
+    (0x4fae, &[0xce, 0xea], false),
+    (0x575e, &[0xce, 0xeb], false),
+    (0x620a, &[0xce, 0xec], false),
+    (0x96fe, &[0xce, 0xed], false),
+    (0x6664, &[0xce, 0xee], false),
+    (0x7269, &[0xce, 0xef], false),
+    (0x52ff, &[0xce, 0xf0], false),
+    (0x52a1, &[0xce, 0xf1], false),
+    (0x609f, &[0xce, 0xf2], false),
+    (0x8bef, &[0xce, 0xf3], false),
+    (0x6614, &[0xce, 0xf4], false),
+    (0x7199, &[0xce, 0xf5], false),
+    (0x6790, &[0xce, 0xf6], false),
+    (0x897f, &[0xce, 0xf7], false),
+    (0x7852, &[0xce, 0xf8], false),
+    (0x77fd, &[0xce, 0xf9], false),
+    (0x6670, &[0xce, 0xfa], false),
+    (0x563b, &[0xce, 0xfb], false),
+    (0x5438, &[0xce, 0xfc], false),
+    (0x9521, &[0xce, 0xfd], false),
+    (0x727a, &[0xce, 0xfe], false),
+    (0x7a00, &[0xcf, 0xa1], false),
+    (0x606f, &[0xcf, 0xa2], false),
+    (0x5e0c, &[0xcf, 0xa3], false),
+    (0x6089, &[0xcf, 0xa4], false),
+    (0x819d, &[0xcf, 0xa5], false),
+    (0x5915, &[0xcf, 0xa6], false),
+    (0x60dc, &[0xcf, 0xa7], false),
+    (0x7184, &[0xcf, 0xa8], false),
+    (0x70ef, &[0xcf, 0xa9], false),
+    (0x6eaa, &[0xcf, 0xaa], false),
+    (0x6c50, &[0xcf, 0xab], false),
+    (0x7280, &[0xcf, 0xac], false),
+    (0x6a84, &[0xcf, 0xad], false),
+    (0x88ad, &[0xcf, 0xae], false),
+    (0x5e2d, &[0xcf, 0xaf], false),
+    (0x4e60, &[0xcf, 0xb0], false),
+    (0x5ab3, &[0xcf, 0xb1], false),
+    (0x559c, &[0xcf, 0xb2], false),
+    (0x94e3, &[0xcf, 0xb3], false),
+    (0x6d17, &[0xcf, 0xb4], false),
+    (0x7cfb, &[0xcf, 0xb5], false),
+    (0x9699, &[0xcf, 0xb6], false),
+    (0x620f, &[0xcf, 0xb7], false),
+    (0x7ec6, &[0xcf, 0xb8], false),
+    (0x778e, &[0xcf, 0xb9], false),
+    (0x867e, &[0xcf, 0xba], false),
+    (0x5323, &[0xcf, 0xbb], false),
+    (0x971e, &[0xcf, 0xbc], false),
+    (0x8f96, &[0xcf, 0xbd], false),
+    (0x6687, &[0xcf, 0xbe], false),
+    (0x5ce1, &[0xcf, 0xbf], false),
+    (0x4fa0, &[0xcf, 0xc0], false),
+    (0x72ed, &[0xcf, 0xc1], false),
+    (0x4e0b, &[0xcf, 0xc2], false),
+    (0x53a6, &[0xcf, 0xc3], false),
+    (0x590f, &[0xcf, 0xc4], false),
+    (0x5413, &[0xcf, 0xc5], false),
+    (0x6380, &[0xcf, 0xc6], false),
+    (0x9528, &[0xcf, 0xc7], false),
+    (0x5148, &[0xcf, 0xc8], false),
+    (0x4ed9, &[0xcf, 0xc9], false),
+    (0x9c9c, &[0xcf, 0xca], false),
+    (0x7ea4, &[0xcf, 0xcb], false),
+    (0x54b8, &[0xcf, 0xcc], false),
+    (0x8d24, &[0xcf, 0xcd], false),
+    (0x8854, &[0xcf, 0xce], false),
+    (0x8237, &[0xcf, 0xcf], false),
+    (0x95f2, &[0xcf, 0xd0], false),
+    (0x6d8e, &[0xcf, 0xd1], false),
+    (0x5f26, &[0xcf, 0xd2], false),
+    (0x5acc, &[0xcf, 0xd3], false),
+    (0x663e, &[0xcf, 0xd4], false),
+    (0x9669, &[0xcf, 0xd5], false),
+    (0x73b0, &[0xcf, 0xd6], false),
+    (0x732e, &[0xcf, 0xd7], false),
+    (0x53bf, &[0xcf, 0xd8], false),
+    (0x817a, &[0xcf, 0xd9], false),
+    (0x9985, &[0xcf, 0xda], false),
+    (0x7fa1, &[0xcf, 0xdb], false),
+    (0x5baa, &[0xcf, 0xdc], false),
+    (0x9677, &[0xcf, 0xdd], false),
+    (0x9650, &[0xcf, 0xde], false),
+    (0x7ebf, &[0xcf, 0xdf], false),
+    (0x76f8, &[0xcf, 0xe0], false),
+    (0x53a2, &[0xcf, 0xe1], false),
+    (0x9576, &[0xcf, 0xe2], false),
+    (0x9999, &[0xcf, 0xe3], false),
+    (0x7bb1, &[0xcf, 0xe4], false),
+    (0x8944, &[0xcf, 0xe5], false),
+    (0x6e58, &[0xcf, 0xe6], false),
+    (0x4e61, &[0xcf, 0xe7], false),
+    (0x7fd4, &[0xcf, 0xe8], false),
+    (0x7965, &[0xcf, 0xe9], false),
+    (0x8be6, &[0xcf, 0xea], false),
+    (0x60f3, &[0xcf, 0xeb], false),
+    (0x54cd, &[0xcf, 0xec], false),
+    (0x4eab, &[0xcf, 0xed], false),
+    (0x9879, &[0xcf, 0xee], false),
+    (0x5df7, &[0xcf, 0xef], false),
+    (0x6a61, &[0xcf, 0xf0], false),
+    (0x50cf, &[0xcf, 0xf1], false),
+    (0x5411, &[0xcf, 0xf2], false),
+    (0x8c61, &[0xcf, 0xf3], false),
+    (0x8427, &[0xcf, 0xf4], false),
+    (0x785d, &[0xcf, 0xf5], false),
+    (0x9704, &[0xcf, 0xf6], false),
+    (0x524a, &[0xcf, 0xf7], false),
+    (0x54ee, &[0xcf, 0xf8], false),
+    (0x56a3, &[0xcf, 0xf9], false),
+    (0x9500, &[0xcf, 0xfa], false),
+    (0x6d88, &[0xcf, 0xfb], false),
+    (0x5bb5, &[0xcf, 0xfc], false),
+    (0x6dc6, &[0xcf, 0xfd], false),
+    (0x6653, &[0xcf, 0xfe], false),
+    (0x5c0f, &[0xd0, 0xa1], false),
+    (0x5b5d, &[0xd0, 0xa2], false),
+    (0x6821, &[0xd0, 0xa3], false),
+    (0x8096, &[0xd0, 0xa4], false),
+    (0x5578, &[0xd0, 0xa5], false),
+    (0x7b11, &[0xd0, 0xa6], false),
+    (0x6548, &[0xd0, 0xa7], false),
+    (0x6954, &[0xd0, 0xa8], false),
+    (0x4e9b, &[0xd0, 0xa9], false),
+    (0x6b47, &[0xd0, 0xaa], false),
+    (0x874e, &[0xd0, 0xab], false),
+    (0x978b, &[0xd0, 0xac], false),
+    (0x534f, &[0xd0, 0xad], false),
+    (0x631f, &[0xd0, 0xae], false),
+    (0x643a, &[0xd0, 0xaf], false),
+    (0x90aa, &[0xd0, 0xb0], false),
+    (0x659c, &[0xd0, 0xb1], false),
+    (0x80c1, &[0xd0, 0xb2], false),
+    (0x8c10, &[0xd0, 0xb3], false),
+    (0x5199, &[0xd0, 0xb4], false),
+    (0x68b0, &[0xd0, 0xb5], false),
+    (0x5378, &[0xd0, 0xb6], false),
+    (0x87f9, &[0xd0, 0xb7], false),
+    (0x61c8, &[0xd0, 0xb8], false),
+    (0x6cc4, &[0xd0, 0xb9], false),
+    (0x6cfb, &[0xd0, 0xba], false),
+    (0x8c22, &[0xd0, 0xbb], false),
+    (0x5c51, &[0xd0, 0xbc], false),
+    (0x85aa, &[0xd0, 0xbd], false),
+    (0x82af, &[0xd0, 0xbe], false),
+    (0x950c, &[0xd0, 0xbf], false),
+    (0x6b23, &[0xd0, 0xc0], false),
+    (0x8f9b, &[0xd0, 0xc1], false),
+    (0x65b0, &[0xd0, 0xc2], false),
+    (0x5ffb, &[0xd0, 0xc3], false),
+    (0x5fc3, &[0xd0, 0xc4], false),
+    (0x4fe1, &[0xd0, 0xc5], false),
+    (0x8845, &[0xd0, 0xc6], false),
+    (0x661f, &[0xd0, 0xc7], false),
+    (0x8165, &[0xd0, 0xc8], false),
+    (0x7329, &[0xd0, 0xc9], false),
+    (0x60fa, &[0xd0, 0xca], false),
+    (0x5174, &[0xd0, 0xcb], false),
+    (0x5211, &[0xd0, 0xcc], false),
+    (0x578b, &[0xd0, 0xcd], false),
+    (0x5f62, &[0xd0, 0xce], false),
+    (0x90a2, &[0xd0, 0xcf], false),
+    (0x884c, &[0xd0, 0xd0], false),
+    (0x9192, &[0xd0, 0xd1], false),
+    (0x5e78, &[0xd0, 0xd2], false),
+    (0x674f, &[0xd0, 0xd3], false),
+    (0x6027, &[0xd0, 0xd4], false),
+    (0x59d3, &[0xd0, 0xd5], false),
+    (0x5144, &[0xd0, 0xd6], false),
+    (0x51f6, &[0xd0, 0xd7], false),
+    (0x80f8, &[0xd0, 0xd8], false),
+    (0x5308, &[0xd0, 0xd9], false),
+    (0x6c79, &[0xd0, 0xda], false),
+    (0x96c4, &[0xd0, 0xdb], false),
+    (0x718a, &[0xd0, 0xdc], false),
+    (0x4f11, &[0xd0, 0xdd], false),
+    (0x4fee, &[0xd0, 0xde], false),
+    (0x7f9e, &[0xd0, 0xdf], false),
+    (0x673d, &[0xd0, 0xe0], false),
+    (0x55c5, &[0xd0, 0xe1], false),
+    (0x9508, &[0xd0, 0xe2], false),
+    (0x79c0, &[0xd0, 0xe3], false),
+    (0x8896, &[0xd0, 0xe4], false),
+    (0x7ee3, &[0xd0, 0xe5], false),
+    (0x589f, &[0xd0, 0xe6], false),
+    (0x620c, &[0xd0, 0xe7], false),
+    (0x9700, &[0xd0, 0xe8], false),
+    (0x865a, &[0xd0, 0xe9], false),
+    (0x5618, &[0xd0, 0xea], false),
+    (0x987b, &[0xd0, 0xeb], false),
+    (0x5f90, &[0xd0, 0xec], false),
+    (0x8bb8, &[0xd0, 0xed], false),
+    (0x84c4, &[0xd0, 0xee], false),
+    (0x9157, &[0xd0, 0xef], false),
+    (0x53d9, &[0xd0, 0xf0], false),
+    (0x65ed, &[0xd0, 0xf1], false),
+    (0x5e8f, &[0xd0, 0xf2], false),
+    (0x755c, &[0xd0, 0xf3], false),
+    (0x6064, &[0xd0, 0xf4], false),
+    (0x7d6e, &[0xd0, 0xf5], false),
+    (0x5a7f, &[0xd0, 0xf6], false),
+    (0x7eea, &[0xd0, 0xf7], false),
+    (0x7eed, &[0xd0, 0xf8], false),
+    (0x8f69, &[0xd0, 0xf9], false),
+    (0x55a7, &[0xd0, 0xfa], false),
+    (0x5ba3, &[0xd0, 0xfb], false),
+    (0x60ac, &[0xd0, 0xfc], false),
+    (0x65cb, &[0xd0, 0xfd], false),
+    (0x7384, &[0xd0, 0xfe], false),
+    (0x9009, &[0xd1, 0xa1], false),
+    (0x7663, &[0xd1, 0xa2], false),
+    (0x7729, &[0xd1, 0xa3], false),
+    (0x7eda, &[0xd1, 0xa4], false),
+    (0x9774, &[0xd1, 0xa5], false),
+    (0x859b, &[0xd1, 0xa6], false),
+    (0x5b66, &[0xd1, 0xa7], false),
+    (0x7a74, &[0xd1, 0xa8], false),
+    (0x96ea, &[0xd1, 0xa9], false),
+    (0x8840, &[0xd1, 0xaa], false),
+    (0x52cb, &[0xd1, 0xab], false),
+    (0x718f, &[0xd1, 0xac], false),
+    (0x5faa, &[0xd1, 0xad], false),
+    (0x65ec, &[0xd1, 0xae], false),
+    (0x8be2, &[0xd1, 0xaf], false),
+    (0x5bfb, &[0xd1, 0xb0], false),
+    (0x9a6f, &[0xd1, 0xb1], false),
+    (0x5de1, &[0xd1, 0xb2], false),
+    (0x6b89, &[0xd1, 0xb3], false),
+    (0x6c5b, &[0xd1, 0xb4], false),
+    (0x8bad, &[0xd1, 0xb5], false),
+    (0x8baf, &[0xd1, 0xb6], false),
+    (0x900a, &[0xd1, 0xb7], false),
+    (0x8fc5, &[0xd1, 0xb8], false),
+    (0x538b, &[0xd1, 0xb9], false),
+    (0x62bc, &[0xd1, 0xba], false),
+    (0x9e26, &[0xd1, 0xbb], false),
+    (0x9e2d, &[0xd1, 0xbc], false),
+    (0x5440, &[0xd1, 0xbd], false),
+    (0x4e2b, &[0xd1, 0xbe], false),
+    (0x82bd, &[0xd1, 0xbf], false),
+    (0x7259, &[0xd1, 0xc0], false),
+    (0x869c, &[0xd1, 0xc1], false),
+    (0x5d16, &[0xd1, 0xc2], false),
+    (0x8859, &[0xd1, 0xc3], false),
+    (0x6daf, &[0xd1, 0xc4], false),
+    (0x96c5, &[0xd1, 0xc5], false),
+    (0x54d1, &[0xd1, 0xc6], false),
+    (0x4e9a, &[0xd1, 0xc7], false),
+    (0x8bb6, &[0xd1, 0xc8], false),
+    (0x7109, &[0xd1, 0xc9], false),
+    (0x54bd, &[0xd1, 0xca], false),
+    (0x9609, &[0xd1, 0xcb], false),
+    (0x70df, &[0xd1, 0xcc], false),
+    (0x6df9, &[0xd1, 0xcd], false),
+    (0x76d0, &[0xd1, 0xce], false),
+    (0x4e25, &[0xd1, 0xcf], false),
+    (0x7814, &[0xd1, 0xd0], false),
+    (0x8712, &[0xd1, 0xd1], false),
+    (0x5ca9, &[0xd1, 0xd2], false),
+    (0x5ef6, &[0xd1, 0xd3], false),
+    (0x8a00, &[0xd1, 0xd4], false),
+    (0x989c, &[0xd1, 0xd5], false),
+    (0x960e, &[0xd1, 0xd6], false),
+    (0x708e, &[0xd1, 0xd7], false),
+    (0x6cbf, &[0xd1, 0xd8], false),
+    (0x5944, &[0xd1, 0xd9], false),
+    (0x63a9, &[0xd1, 0xda], false),
+    (0x773c, &[0xd1, 0xdb], false),
+    (0x884d, &[0xd1, 0xdc], false),
+    (0x6f14, &[0xd1, 0xdd], false),
+    (0x8273, &[0xd1, 0xde], false),
+    (0x5830, &[0xd1, 0xdf], false),
+    (0x71d5, &[0xd1, 0xe0], false),
+    (0x538c, &[0xd1, 0xe1], false),
+    (0x781a, &[0xd1, 0xe2], false),
+    (0x96c1, &[0xd1, 0xe3], false),
+    (0x5501, &[0xd1, 0xe4], false),
+    (0x5f66, &[0xd1, 0xe5], false),
+    (0x7130, &[0xd1, 0xe6], false),
+    (0x5bb4, &[0xd1, 0xe7], false),
+    (0x8c1a, &[0xd1, 0xe8], false),
+    (0x9a8c, &[0xd1, 0xe9], false),
+    (0x6b83, &[0xd1, 0xea], false),
+    (0x592e, &[0xd1, 0xeb], false),
+    (0x9e2f, &[0xd1, 0xec], false),
+    (0x79e7, &[0xd1, 0xed], false),
+    (0x6768, &[0xd1, 0xee], false),
+    (0x626c, &[0xd1, 0xef], false),
+    (0x4f6f, &[0xd1, 0xf0], false),
+    (0x75a1, &[0xd1, 0xf1], false),
+    (0x7f8a, &[0xd1, 0xf2], false),
+    (0x6d0b, &[0xd1, 0xf3], false),
+    (0x9633, &[0xd1, 0xf4], false),
+    (0x6c27, &[0xd1, 0xf5], false),
+    (0x4ef0, &[0xd1, 0xf6], false),
+    (0x75d2, &[0xd1, 0xf7], false),
+    (0x517b, &[0xd1, 0xf8], false),
+    (0x6837, &[0xd1, 0xf9], false),
+    (0x6f3e, &[0xd1, 0xfa], false),
+    (0x9080, &[0xd1, 0xfb], false),
+    (0x8170, &[0xd1, 0xfc], false),
+    (0x5996, &[0xd1, 0xfd], false),
+    (0x7476, &[0xd1, 0xfe], false),
+    (0x6447, &[0xd2, 0xa1], false),
+    (0x5c27, &[0xd2, 0xa2], false),
+    (0x9065, &[0xd2, 0xa3], false),
+    (0x7a91, &[0xd2, 0xa4], false),
+    (0x8c23, &[0xd2, 0xa5], false),
+    (0x59da, &[0xd2, 0xa6], false),
+    (0x54ac, &[0xd2, 0xa7], false),
+    (0x8200, &[0xd2, 0xa8], false),
+    (0x836f, &[0xd2, 0xa9], false),
+    (0x8981, &[0xd2, 0xaa], false),
+    (0x8000, &[0xd2, 0xab], false),
+    (0x6930, &[0xd2, 0xac], false),
+    (0x564e, &[0xd2, 0xad], false),
+    (0x8036, &[0xd2, 0xae], false),
+    (0x7237, &[0xd2, 0xaf], false),
+    (0x91ce, &[0xd2, 0xb0], false),
+    (0x51b6, &[0xd2, 0xb1], false),
+    (0x4e5f, &[0xd2, 0xb2], false),
+    (0x9875, &[0xd2, 0xb3], false),
+    (0x6396, &[0xd2, 0xb4], false),
+    (0x4e1a, &[0xd2, 0xb5], false),
+    (0x53f6, &[0xd2, 0xb6], false),
+    (0x66f3, &[0xd2, 0xb7], false),
+    (0x814b, &[0xd2, 0xb8], false),
+    (0x591c, &[0xd2, 0xb9], false),
+    (0x6db2, &[0xd2, 0xba], false),
+    (0x4e00, &[0xd2, 0xbb], false),
+    (0x58f9, &[0xd2, 0xbc], false),
+    (0x533b, &[0xd2, 0xbd], false),
+    (0x63d6, &[0xd2, 0xbe], false),
+    (0x94f1, &[0xd2, 0xbf], false),
+    (0x4f9d, &[0xd2, 0xc0], false),
+    (0x4f0a, &[0xd2, 0xc1], false),
+    (0x8863, &[0xd2, 0xc2], false),
+    (0x9890, &[0xd2, 0xc3], false),
+    (0x5937, &[0xd2, 0xc4], false),
+    (0x9057, &[0xd2, 0xc5], false),
+    (0x79fb, &[0xd2, 0xc6], false),
+    (0x4eea, &[0xd2, 0xc7], false),
+    (0x80f0, &[0xd2, 0xc8], false),
+    (0x7591, &[0xd2, 0xc9], false),
+    (0x6c82, &[0xd2, 0xca], false),
+    (0x5b9c, &[0xd2, 0xcb], false),
+    (0x59e8, &[0xd2, 0xcc], false),
+    (0x5f5d, &[0xd2, 0xcd], false),
+    (0x6905, &[0xd2, 0xce], false),
+    (0x8681, &[0xd2, 0xcf], false),
+    (0x501a, &[0xd2, 0xd0], false),
+    (0x5df2, &[0xd2, 0xd1], false),
+    (0x4e59, &[0xd2, 0xd2], false),
+    (0x77e3, &[0xd2, 0xd3], false),
+    (0x4ee5, &[0xd2, 0xd4], false),
+    (0x827a, &[0xd2, 0xd5], false),
+    (0x6291, &[0xd2, 0xd6], false),
+    (0x6613, &[0xd2, 0xd7], false),
+    (0x9091, &[0xd2, 0xd8], false),
+    (0x5c79, &[0xd2, 0xd9], false),
+    (0x4ebf, &[0xd2, 0xda], false),
+    (0x5f79, &[0xd2, 0xdb], false),
+    (0x81c6, &[0xd2, 0xdc], false),
+    (0x9038, &[0xd2, 0xdd], false),
+    (0x8084, &[0xd2, 0xde], false),
+    (0x75ab, &[0xd2, 0xdf], false),
+    (0x4ea6, &[0xd2, 0xe0], false),
+    (0x88d4, &[0xd2, 0xe1], false),
+    (0x610f, &[0xd2, 0xe2], false),
+    (0x6bc5, &[0xd2, 0xe3], false),
+    (0x5fc6, &[0xd2, 0xe4], false),
+    (0x4e49, &[0xd2, 0xe5], false),
+    (0x76ca, &[0xd2, 0xe6], false),
+    (0x6ea2, &[0xd2, 0xe7], false),
+    (0x8be3, &[0xd2, 0xe8], false),
+    (0x8bae, &[0xd2, 0xe9], false),
+    (0x8c0a, &[0xd2, 0xea], false),
+    (0x8bd1, &[0xd2, 0xeb], false),
+    (0x5f02, &[0xd2, 0xec], false),
+    (0x7ffc, &[0xd2, 0xed], false),
+    (0x7fcc, &[0xd2, 0xee], false),
+    (0x7ece, &[0xd2, 0xef], false),
+    (0x8335, &[0xd2, 0xf0], false),
+    (0x836b, &[0xd2, 0xf1], false),
+    (0x56e0, &[0xd2, 0xf2], false),
+    (0x6bb7, &[0xd2, 0xf3], false),
+    (0x97f3, &[0xd2, 0xf4], false),
+    (0x9634, &[0xd2, 0xf5], false),
+    (0x59fb, &[0xd2, 0xf6], false),
+    (0x541f, &[0xd2, 0xf7], false),
+    (0x94f6, &[0xd2, 0xf8], false),
+    (0x6deb, &[0xd2, 0xf9], false),
+    (0x5bc5, &[0xd2, 0xfa], false),
+    (0x996e, &[0xd2, 0xfb], false),
+    (0x5c39, &[0xd2, 0xfc], false),
+    (0x5f15, &[0xd2, 0xfd], false),
+    (0x9690, &[0xd2, 0xfe], false),
+    (0x5370, &[0xd3, 0xa1], false),
+    (0x82f1, &[0xd3, 0xa2], false),
+    (0x6a31, &[0xd3, 0xa3], false),
+    (0x5a74, &[0xd3, 0xa4], false),
+    (0x9e70, &[0xd3, 0xa5], false),
+    (0x5e94, &[0xd3, 0xa6], false),
+    (0x7f28, &[0xd3, 0xa7], false),
+    (0x83b9, &[0xd3, 0xa8], false),
+    (0x8424, &[0xd3, 0xa9], false),
+    (0x8425, &[0xd3, 0xaa], false),
+    (0x8367, &[0xd3, 0xab], false),
+    (0x8747, &[0xd3, 0xac], false),
+    (0x8fce, &[0xd3, 0xad], false),
+    (0x8d62, &[0xd3, 0xae], false),
+    (0x76c8, &[0xd3, 0xaf], false),
+    (0x5f71, &[0xd3, 0xb0], false),
+    (0x9896, &[0xd3, 0xb1], false),
+    (0x786c, &[0xd3, 0xb2], false),
+    (0x6620, &[0xd3, 0xb3], false),
+    (0x54df, &[0xd3, 0xb4], false),
+    (0x62e5, &[0xd3, 0xb5], false),
+    (0x4f63, &[0xd3, 0xb6], false),
+    (0x81c3, &[0xd3, 0xb7], false),
+    (0x75c8, &[0xd3, 0xb8], false),
+    (0x5eb8, &[0xd3, 0xb9], false),
+    (0x96cd, &[0xd3, 0xba], false),
+    (0x8e0a, &[0xd3, 0xbb], false),
+    (0x86f9, &[0xd3, 0xbc], false),
+    (0x548f, &[0xd3, 0xbd], false),
+    (0x6cf3, &[0xd3, 0xbe], false),
+    (0x6d8c, &[0xd3, 0xbf], false),
+    (0x6c38, &[0xd3, 0xc0], false),
+    (0x607f, &[0xd3, 0xc1], false),
+    (0x52c7, &[0xd3, 0xc2], false),
+    (0x7528, &[0xd3, 0xc3], false),
+    (0x5e7d, &[0xd3, 0xc4], false),
+    (0x4f18, &[0xd3, 0xc5], false),
+    (0x60a0, &[0xd3, 0xc6], false),
+    (0x5fe7, &[0xd3, 0xc7], false),
+    (0x5c24, &[0xd3, 0xc8], false),
+    (0x7531, &[0xd3, 0xc9], false),
+    (0x90ae, &[0xd3, 0xca], false),
+    (0x94c0, &[0xd3, 0xcb], false),
+    (0x72b9, &[0xd3, 0xcc], false),
+    (0x6cb9, &[0xd3, 0xcd], false),
+    (0x6e38, &[0xd3, 0xce], false),
+    (0x9149, &[0xd3, 0xcf], false),
+    (0x6709, &[0xd3, 0xd0], false),
+    (0x53cb, &[0xd3, 0xd1], false),
+    (0x53f3, &[0xd3, 0xd2], false),
+    (0x4f51, &[0xd3, 0xd3], false),
+    (0x91c9, &[0xd3, 0xd4], false),
+    (0x8bf1, &[0xd3, 0xd5], false),
+    (0x53c8, &[0xd3, 0xd6], false),
+    (0x5e7c, &[0xd3, 0xd7], false),
+    (0x8fc2, &[0xd3, 0xd8], false),
+    (0x6de4, &[0xd3, 0xd9], false),
+    (0x4e8e, &[0xd3, 0xda], false),
+    (0x76c2, &[0xd3, 0xdb], false),
+    (0x6986, &[0xd3, 0xdc], false),
+    (0x865e, &[0xd3, 0xdd], false),
+    (0x611a, &[0xd3, 0xde], false),
+    (0x8206, &[0xd3, 0xdf], false),
+    (0x4f59, &[0xd3, 0xe0], false),
+    (0x4fde, &[0xd3, 0xe1], false),
+    (0x903e, &[0xd3, 0xe2], false),
+    (0x9c7c, &[0xd3, 0xe3], false),
+    (0x6109, &[0xd3, 0xe4], false),
+    (0x6e1d, &[0xd3, 0xe5], false),
+    (0x6e14, &[0xd3, 0xe6], false),
+    (0x9685, &[0xd3, 0xe7], false),
+    (0x4e88, &[0xd3, 0xe8], false),
+    (0x5a31, &[0xd3, 0xe9], false),
+    (0x96e8, &[0xd3, 0xea], false),
+    (0x4e0e, &[0xd3, 0xeb], false),
+    (0x5c7f, &[0xd3, 0xec], false),
+    (0x79b9, &[0xd3, 0xed], false),
+    (0x5b87, &[0xd3, 0xee], false),
+    (0x8bed, &[0xd3, 0xef], false),
+    (0x7fbd, &[0xd3, 0xf0], false),
+    (0x7389, &[0xd3, 0xf1], false),
+    (0x57df, &[0xd3, 0xf2], false),
+    (0x828b, &[0xd3, 0xf3], false),
+    (0x90c1, &[0xd3, 0xf4], false),
+    (0x5401, &[0xd3, 0xf5], false),
+    (0x9047, &[0xd3, 0xf6], false),
+    (0x55bb, &[0xd3, 0xf7], false),
+    (0x5cea, &[0xd3, 0xf8], false),
+    (0x5fa1, &[0xd3, 0xf9], false),
+    (0x6108, &[0xd3, 0xfa], false),
+    (0x6b32, &[0xd3, 0xfb], false),
+    (0x72f1, &[0xd3, 0xfc], false),
+    (0x80b2, &[0xd3, 0xfd], false),
+    (0x8a89, &[0xd3, 0xfe], false),
+    (0x6d74, &[0xd4, 0xa1], false),
+    (0x5bd3, &[0xd4, 0xa2], false),
+    (0x88d5, &[0xd4, 0xa3], false),
+    (0x9884, &[0xd4, 0xa4], false),
+    (0x8c6b, &[0xd4, 0xa5], false),
+    (0x9a6d, &[0xd4, 0xa6], false),
+    (0x9e33, &[0xd4, 0xa7], false),
+    (0x6e0a, &[0xd4, 0xa8], false),
+    (0x51a4, &[0xd4, 0xa9], false),
+    (0x5143, &[0xd4, 0xaa], false),
+    (0x57a3, &[0xd4, 0xab], false),
+    (0x8881, &[0xd4, 0xac], false),
+    (0x539f, &[0xd4, 0xad], false),
+    (0x63f4, &[0xd4, 0xae], false),
+    (0x8f95, &[0xd4, 0xaf], false),
+    (0x56ed, &[0xd4, 0xb0], false),
+    (0x5458, &[0xd4, 0xb1], false),
+    (0x5706, &[0xd4, 0xb2], false),
+    (0x733f, &[0xd4, 0xb3], false),
+    (0x6e90, &[0xd4, 0xb4], false),
+    (0x7f18, &[0xd4, 0xb5], false),
+    (0x8fdc, &[0xd4, 0xb6], false),
+    (0x82d1, &[0xd4, 0xb7], false),
+    (0x613f, &[0xd4, 0xb8], false),
+    (0x6028, &[0xd4, 0xb9], false),
+    (0x9662, &[0xd4, 0xba], false),
+    (0x66f0, &[0xd4, 0xbb], false),
+    (0x7ea6, &[0xd4, 0xbc], false),
+    (0x8d8a, &[0xd4, 0xbd], false),
+    (0x8dc3, &[0xd4, 0xbe], false),
+    (0x94a5, &[0xd4, 0xbf], false),
+    (0x5cb3, &[0xd4, 0xc0], false),
+    (0x7ca4, &[0xd4, 0xc1], false),
+    (0x6708, &[0xd4, 0xc2], false),
+    (0x60a6, &[0xd4, 0xc3], false),
+    (0x9605, &[0xd4, 0xc4], false),
+    (0x8018, &[0xd4, 0xc5], false),
+    (0x4e91, &[0xd4, 0xc6], false),
+    (0x90e7, &[0xd4, 0xc7], false),
+    (0x5300, &[0xd4, 0xc8], false),
+    (0x9668, &[0xd4, 0xc9], false),
+    (0x5141, &[0xd4, 0xca], false),
+    (0x8fd0, &[0xd4, 0xcb], false),
+    (0x8574, &[0xd4, 0xcc], false),
+    (0x915d, &[0xd4, 0xcd], false),
+    (0x6655, &[0xd4, 0xce], false),
+    (0x97f5, &[0xd4, 0xcf], false),
+    (0x5b55, &[0xd4, 0xd0], false),
+    (0x531d, &[0xd4, 0xd1], false),
+    (0x7838, &[0xd4, 0xd2], false),
+    (0x6742, &[0xd4, 0xd3], false),
+    (0x683d, &[0xd4, 0xd4], false),
+    (0x54c9, &[0xd4, 0xd5], false),
+    (0x707e, &[0xd4, 0xd6], false),
+    (0x5bb0, &[0xd4, 0xd7], false),
+    (0x8f7d, &[0xd4, 0xd8], false),
+    (0x518d, &[0xd4, 0xd9], false),
+    (0x5728, &[0xd4, 0xda], false),
+    (0x54b1, &[0xd4, 0xdb], false),
+    (0x6512, &[0xd4, 0xdc], false),
+    (0x6682, &[0xd4, 0xdd], false),
+    (0x8d5e, &[0xd4, 0xde], false),
+    (0x8d43, &[0xd4, 0xdf], false),
+    (0x810f, &[0xd4, 0xe0], false),
+    (0x846c, &[0xd4, 0xe1], false),
+    (0x906d, &[0xd4, 0xe2], false),
+    (0x7cdf, &[0xd4, 0xe3], false),
+    (0x51ff, &[0xd4, 0xe4], false),
+    (0x85fb, &[0xd4, 0xe5], false),
+    (0x67a3, &[0xd4, 0xe6], false),
+    (0x65e9, &[0xd4, 0xe7], false),
+    (0x6fa1, &[0xd4, 0xe8], false),
+    (0x86a4, &[0xd4, 0xe9], false),
+    (0x8e81, &[0xd4, 0xea], false),
+    (0x566a, &[0xd4, 0xeb], false),
+    (0x9020, &[0xd4, 0xec], false),
+    (0x7682, &[0xd4, 0xed], false),
+    (0x7076, &[0xd4, 0xee], false),
+    (0x71e5, &[0xd4, 0xef], false),
+    (0x8d23, &[0xd4, 0xf0], false),
+    (0x62e9, &[0xd4, 0xf1], false),
+    (0x5219, &[0xd4, 0xf2], false),
+    (0x6cfd, &[0xd4, 0xf3], false),
+    (0x8d3c, &[0xd4, 0xf4], false),
+    (0x600e, &[0xd4, 0xf5], false),
+    (0x589e, &[0xd4, 0xf6], false),
+    (0x618e, &[0xd4, 0xf7], false),
+    (0x66fe, &[0xd4, 0xf8], false),
+    (0x8d60, &[0xd4, 0xf9], false),
+    (0x624e, &[0xd4, 0xfa], false),
+    (0x55b3, &[0xd4, 0xfb], false),
+    (0x6e23, &[0xd4, 0xfc], false),
+    (0x672d, &[0xd4, 0xfd], false),
+    (0x8f67, &[0xd4, 0xfe], false),
+    (0x94e1, &[0xd5, 0xa1], false),
+    (0x95f8, &[0xd5, 0xa2], false),
+    (0x7728, &[0xd5, 0xa3], false),
+    (0x6805, &[0xd5, 0xa4], false),
+    (0x69a8, &[0xd5, 0xa5], false),
+    (0x548b, &[0xd5, 0xa6], false),
+    (0x4e4d, &[0xd5, 0xa7], false),
+    (0x70b8, &[0xd5, 0xa8], false),
+    (0x8bc8, &[0xd5, 0xa9], false),
+    (0x6458, &[0xd5, 0xaa], false),
+    (0x658b, &[0xd5, 0xab], false),
+    (0x5b85, &[0xd5, 0xac], false),
+    (0x7a84, &[0xd5, 0xad], false),
+    (0x503a, &[0xd5, 0xae], false),
+    (0x5be8, &[0xd5, 0xaf], false),
+    (0x77bb, &[0xd5, 0xb0], false),
+    (0x6be1, &[0xd5, 0xb1], false),
+    (0x8a79, &[0xd5, 0xb2], false),
+    (0x7c98, &[0xd5, 0xb3], false),
+    (0x6cbe, &[0xd5, 0xb4], false),
+    (0x76cf, &[0xd5, 0xb5], false),
+    (0x65a9, &[0xd5, 0xb6], false),
+    (0x8f97, &[0xd5, 0xb7], false),
+    (0x5d2d, &[0xd5, 0xb8], false),
+    (0x5c55, &[0xd5, 0xb9], false),
+    (0x8638, &[0xd5, 0xba], false),
+    (0x6808, &[0xd5, 0xbb], false),
+    (0x5360, &[0xd5, 0xbc], false),
+    (0x6218, &[0xd5, 0xbd], false),
+    (0x7ad9, &[0xd5, 0xbe], false),
+    (0x6e5b, &[0xd5, 0xbf], false),
+    (0x7efd, &[0xd5, 0xc0], false),
+    (0x6a1f, &[0xd5, 0xc1], false),
+    (0x7ae0, &[0xd5, 0xc2], false),
+    (0x5f70, &[0xd5, 0xc3], false),
+    (0x6f33, &[0xd5, 0xc4], false),
+    (0x5f20, &[0xd5, 0xc5], false),
+    (0x638c, &[0xd5, 0xc6], false),
+    (0x6da8, &[0xd5, 0xc7], false),
+    (0x6756, &[0xd5, 0xc8], false),
+    (0x4e08, &[0xd5, 0xc9], false),
+    (0x5e10, &[0xd5, 0xca], false),
+    (0x8d26, &[0xd5, 0xcb], false),
+    (0x4ed7, &[0xd5, 0xcc], false),
+    (0x80c0, &[0xd5, 0xcd], false),
+    (0x7634, &[0xd5, 0xce], false),
+    (0x969c, &[0xd5, 0xcf], false),
+    (0x62db, &[0xd5, 0xd0], false),
+    (0x662d, &[0xd5, 0xd1], false),
+    (0x627e, &[0xd5, 0xd2], false),
+    (0x6cbc, &[0xd5, 0xd3], false),
+    (0x8d75, &[0xd5, 0xd4], false),
+    (0x7167, &[0xd5, 0xd5], false),
+    (0x7f69, &[0xd5, 0xd6], false),
+    (0x5146, &[0xd5, 0xd7], false),
+    (0x8087, &[0xd5, 0xd8], false),
+    (0x53ec, &[0xd5, 0xd9], false),
+    (0x906e, &[0xd5, 0xda], false),
+    (0x6298, &[0xd5, 0xdb], false),
+    (0x54f2, &[0xd5, 0xdc], false),
+    (0x86f0, &[0xd5, 0xdd], false),
+    (0x8f99, &[0xd5, 0xde], false),
+    (0x8005, &[0xd5, 0xdf], false),
+    (0x9517, &[0xd5, 0xe0], false),
+    (0x8517, &[0xd5, 0xe1], false),
+    (0x8fd9, &[0xd5, 0xe2], false),
+    (0x6d59, &[0xd5, 0xe3], false),
+    (0x73cd, &[0xd5, 0xe4], false),
+    (0x659f, &[0xd5, 0xe5], false),
+    (0x771f, &[0xd5, 0xe6], false),
+    (0x7504, &[0xd5, 0xe7], false),
+    (0x7827, &[0xd5, 0xe8], false),
+    (0x81fb, &[0xd5, 0xe9], false),
+    (0x8d1e, &[0xd5, 0xea], false),
+    (0x9488, &[0xd5, 0xeb], false),
+    (0x4fa6, &[0xd5, 0xec], false),
+    (0x6795, &[0xd5, 0xed], false),
+    (0x75b9, &[0xd5, 0xee], false),
+    (0x8bca, &[0xd5, 0xef], false),
+    (0x9707, &[0xd5, 0xf0], false),
+    (0x632f, &[0xd5, 0xf1], false),
+    (0x9547, &[0xd5, 0xf2], false),
+    (0x9635, &[0xd5, 0xf3], false),
+    (0x84b8, &[0xd5, 0xf4], false),
+    (0x6323, &[0xd5, 0xf5], false),
+    (0x7741, &[0xd5, 0xf6], false),
+    (0x5f81, &[0xd5, 0xf7], false),
+    (0x72f0, &[0xd5, 0xf8], false),
+    (0x4e89, &[0xd5, 0xf9], false),
+    (0x6014, &[0xd5, 0xfa], false),
+    (0x6574, &[0xd5, 0xfb], false),
+    (0x62ef, &[0xd5, 0xfc], false),
+    (0x6b63, &[0xd5, 0xfd], false),
+    (0x653f, &[0xd5, 0xfe], false),
+    (0x5e27, &[0xd6, 0xa1], false),
+    (0x75c7, &[0xd6, 0xa2], false),
+    (0x90d1, &[0xd6, 0xa3], false),
+    (0x8bc1, &[0xd6, 0xa4], false),
+    (0x829d, &[0xd6, 0xa5], false),
+    (0x679d, &[0xd6, 0xa6], false),
+    (0x652f, &[0xd6, 0xa7], false),
+    (0x5431, &[0xd6, 0xa8], false),
+    (0x8718, &[0xd6, 0xa9], false),
+    (0x77e5, &[0xd6, 0xaa], false),
+    (0x80a2, &[0xd6, 0xab], false),
+    (0x8102, &[0xd6, 0xac], false),
+    (0x6c41, &[0xd6, 0xad], false),
+    (0x4e4b, &[0xd6, 0xae], false),
+    (0x7ec7, &[0xd6, 0xaf], false),
+    (0x804c, &[0xd6, 0xb0], false),
+    (0x76f4, &[0xd6, 0xb1], false),
+    (0x690d, &[0xd6, 0xb2], false),
+    (0x6b96, &[0xd6, 0xb3], false),
+    (0x6267, &[0xd6, 0xb4], false),
+    (0x503c, &[0xd6, 0xb5], false),
+    (0x4f84, &[0xd6, 0xb6], false),
+    (0x5740, &[0xd6, 0xb7], false),
+    (0x6307, &[0xd6, 0xb8], false),
+    (0x6b62, &[0xd6, 0xb9], false),
+    (0x8dbe, &[0xd6, 0xba], false),
+    (0x53ea, &[0xd6, 0xbb], false),
+    (0x65e8, &[0xd6, 0xbc], false),
+    (0x7eb8, &[0xd6, 0xbd], false),
+    (0x5fd7, &[0xd6, 0xbe], false),
+    (0x631a, &[0xd6, 0xbf], false),
+    (0x63b7, &[0xd6, 0xc0], false),
+    (0x81f3, &[0xd6, 0xc1], false),
+    (0x81f4, &[0xd6, 0xc2], false),
+    (0x7f6e, &[0xd6, 0xc3], false),
+    (0x5e1c, &[0xd6, 0xc4], false),
+    (0x5cd9, &[0xd6, 0xc5], false),
+    (0x5236, &[0xd6, 0xc6], false),
+    (0x667a, &[0xd6, 0xc7], false),
+    (0x79e9, &[0xd6, 0xc8], false),
+    (0x7a1a, &[0xd6, 0xc9], false),
+    (0x8d28, &[0xd6, 0xca], false),
+    (0x7099, &[0xd6, 0xcb], false),
+    (0x75d4, &[0xd6, 0xcc], false),
+    (0x6ede, &[0xd6, 0xcd], false),
+    (0x6cbb, &[0xd6, 0xce], false),
+    (0x7a92, &[0xd6, 0xcf], false),
+    (0x4e2d, &[0xd6, 0xd0], false),
+    (0x76c5, &[0xd6, 0xd1], false),
+    (0x5fe0, &[0xd6, 0xd2], false),
+    (0x949f, &[0xd6, 0xd3], false),
+    (0x8877, &[0xd6, 0xd4], false),
+    (0x7ec8, &[0xd6, 0xd5], false),
+    (0x79cd, &[0xd6, 0xd6], false),
+    (0x80bf, &[0xd6, 0xd7], false),
+    (0x91cd, &[0xd6, 0xd8], false),
+    (0x4ef2, &[0xd6, 0xd9], false),
+    (0x4f17, &[0xd6, 0xda], false),
+    (0x821f, &[0xd6, 0xdb], false),
+    (0x5468, &[0xd6, 0xdc], false),
+    (0x5dde, &[0xd6, 0xdd], false),
+    (0x6d32, &[0xd6, 0xde], false),
+    (0x8bcc, &[0xd6, 0xdf], false),
+    (0x7ca5, &[0xd6, 0xe0], false),
+    (0x8f74, &[0xd6, 0xe1], false),
+    (0x8098, &[0xd6, 0xe2], false),
+    (0x5e1a, &[0xd6, 0xe3], false),
+    (0x5492, &[0xd6, 0xe4], false),
+    (0x76b1, &[0xd6, 0xe5], false),
+    (0x5b99, &[0xd6, 0xe6], false),
+    (0x663c, &[0xd6, 0xe7], false),
+    (0x9aa4, &[0xd6, 0xe8], false),
+    (0x73e0, &[0xd6, 0xe9], false),
+    (0x682a, &[0xd6, 0xea], false),
+    (0x86db, &[0xd6, 0xeb], false),
+    (0x6731, &[0xd6, 0xec], false),
+    (0x732a, &[0xd6, 0xed], false),
+    (0x8bf8, &[0xd6, 0xee], false),
+    (0x8bdb, &[0xd6, 0xef], false),
+    (0x9010, &[0xd6, 0xf0], false),
+    (0x7af9, &[0xd6, 0xf1], false),
+    (0x70db, &[0xd6, 0xf2], false),
+    (0x716e, &[0xd6, 0xf3], false),
+    (0x62c4, &[0xd6, 0xf4], false),
+    (0x77a9, &[0xd6, 0xf5], false),
+    (0x5631, &[0xd6, 0xf6], false),
+    (0x4e3b, &[0xd6, 0xf7], false),
+    (0x8457, &[0xd6, 0xf8], false),
+    (0x67f1, &[0xd6, 0xf9], false),
+    (0x52a9, &[0xd6, 0xfa], false),
+    (0x86c0, &[0xd6, 0xfb], false),
+    (0x8d2e, &[0xd6, 0xfc], false),
+    (0x94f8, &[0xd6, 0xfd], false),
+    (0x7b51, &[0xd6, 0xfe], false),
+    (0x4f4f, &[0xd7, 0xa1], false),
+    (0x6ce8, &[0xd7, 0xa2], false),
+    (0x795d, &[0xd7, 0xa3], false),
+    (0x9a7b, &[0xd7, 0xa4], false),
+    (0x6293, &[0xd7, 0xa5], false),
+    (0x722a, &[0xd7, 0xa6], false),
+    (0x62fd, &[0xd7, 0xa7], false),
+    (0x4e13, &[0xd7, 0xa8], false),
+    (0x7816, &[0xd7, 0xa9], false),
+    (0x8f6c, &[0xd7, 0xaa], false),
+    (0x64b0, &[0xd7, 0xab], false),
+    (0x8d5a, &[0xd7, 0xac], false),
+    (0x7bc6, &[0xd7, 0xad], false),
+    (0x6869, &[0xd7, 0xae], false),
+    (0x5e84, &[0xd7, 0xaf], false),
+    (0x88c5, &[0xd7, 0xb0], false),
+    (0x5986, &[0xd7, 0xb1], false),
+    (0x649e, &[0xd7, 0xb2], false),
+    (0x58ee, &[0xd7, 0xb3], false),
+    (0x72b6, &[0xd7, 0xb4], false),
+    (0x690e, &[0xd7, 0xb5], false),
+    (0x9525, &[0xd7, 0xb6], false),
+    (0x8ffd, &[0xd7, 0xb7], false),
+    (0x8d58, &[0xd7, 0xb8], false),
+    (0x5760, &[0xd7, 0xb9], false),
+    (0x7f00, &[0xd7, 0xba], false),
+    (0x8c06, &[0xd7, 0xbb], false),
+    (0x51c6, &[0xd7, 0xbc], false),
+    (0x6349, &[0xd7, 0xbd], false),
+    (0x62d9, &[0xd7, 0xbe], false),
+    (0x5353, &[0xd7, 0xbf], false),
+    (0x684c, &[0xd7, 0xc0], false),
+    (0x7422, &[0xd7, 0xc1], false),
+    (0x8301, &[0xd7, 0xc2], false),
+    (0x914c, &[0xd7, 0xc3], false),
+    (0x5544, &[0xd7, 0xc4], false),
+    (0x7740, &[0xd7, 0xc5], false),
+    (0x707c, &[0xd7, 0xc6], false),
+    (0x6d4a, &[0xd7, 0xc7], false),
+    (0x5179, &[0xd7, 0xc8], false),
+    (0x54a8, &[0xd7, 0xc9], false),
+    (0x8d44, &[0xd7, 0xca], false),
+    (0x59ff, &[0xd7, 0xcb], false),
+    (0x6ecb, &[0xd7, 0xcc], false),
+    (0x6dc4, &[0xd7, 0xcd], false),
+    (0x5b5c, &[0xd7, 0xce], false),
+    (0x7d2b, &[0xd7, 0xcf], false),
+    (0x4ed4, &[0xd7, 0xd0], false),
+    (0x7c7d, &[0xd7, 0xd1], false),
+    (0x6ed3, &[0xd7, 0xd2], false),
+    (0x5b50, &[0xd7, 0xd3], false),
+    (0x81ea, &[0xd7, 0xd4], false),
+    (0x6e0d, &[0xd7, 0xd5], false),
+    (0x5b57, &[0xd7, 0xd6], false),
+    (0x9b03, &[0xd7, 0xd7], false),
+    (0x68d5, &[0xd7, 0xd8], false),
+    (0x8e2a, &[0xd7, 0xd9], false),
+    (0x5b97, &[0xd7, 0xda], false),
+    (0x7efc, &[0xd7, 0xdb], false),
+    (0x603b, &[0xd7, 0xdc], false),
+    (0x7eb5, &[0xd7, 0xdd], false),
+    (0x90b9, &[0xd7, 0xde], false),
+    (0x8d70, &[0xd7, 0xdf], false),
+    (0x594f, &[0xd7, 0xe0], false),
+    (0x63cd, &[0xd7, 0xe1], false),
+    (0x79df, &[0xd7, 0xe2], false),
+    (0x8db3, &[0xd7, 0xe3], false),
+    (0x5352, &[0xd7, 0xe4], false),
+    (0x65cf, &[0xd7, 0xe5], false),
+    (0x7956, &[0xd7, 0xe6], false),
+    (0x8bc5, &[0xd7, 0xe7], false),
+    (0x963b, &[0xd7, 0xe8], false),
+    (0x7ec4, &[0xd7, 0xe9], false),
+    (0x94bb, &[0xd7, 0xea], false),
+    (0x7e82, &[0xd7, 0xeb], false),
+    (0x5634, &[0xd7, 0xec], false),
+    (0x9189, &[0xd7, 0xed], false),
+    (0x6700, &[0xd7, 0xee], false),
+    (0x7f6a, &[0xd7, 0xef], false),
+    (0x5c0a, &[0xd7, 0xf0], false),
+    (0x9075, &[0xd7, 0xf1], false),
+    (0x6628, &[0xd7, 0xf2], false),
+    (0x5de6, &[0xd7, 0xf3], false),
+    (0x4f50, &[0xd7, 0xf4], false),
+    (0x67de, &[0xd7, 0xf5], false),
+    (0x505a, &[0xd7, 0xf6], false),
+    (0x4f5c, &[0xd7, 0xf7], false),
+    (0x5750, &[0xd7, 0xf8], false),
+    (0x5ea7, &[0xd7, 0xf9], false),
+    (0x4e8d, &[0xd8, 0xa1], false),
+    (0x4e0c, &[0xd8, 0xa2], false),
+    (0x5140, &[0xd8, 0xa3], false),
+    (0x4e10, &[0xd8, 0xa4], false),
+    (0x5eff, &[0xd8, 0xa5], false),
+    (0x5345, &[0xd8, 0xa6], false),
+    (0x4e15, &[0xd8, 0xa7], false),
+    (0x4e98, &[0xd8, 0xa8], false),
+    (0x4e1e, &[0xd8, 0xa9], false),
+    (0x9b32, &[0xd8, 0xaa], false),
+    (0x5b6c, &[0xd8, 0xab], false),
+    (0x5669, &[0xd8, 0xac], false),
+    (0x4e28, &[0xd8, 0xad], false),
+    (0x79ba, &[0xd8, 0xae], false),
+    (0x4e3f, &[0xd8, 0xaf], false),
+    (0x5315, &[0xd8, 0xb0], false),
+    (0x4e47, &[0xd8, 0xb1], false),
+    (0x592d, &[0xd8, 0xb2], false),
+    (0x723b, &[0xd8, 0xb3], false),
+    (0x536e, &[0xd8, 0xb4], false),
+    (0x6c10, &[0xd8, 0xb5], false),
+    (0x56df, &[0xd8, 0xb6], false),
+    (0x80e4, &[0xd8, 0xb7], false),
+    (0x9997, &[0xd8, 0xb8], false),
+    (0x6bd3, &[0xd8, 0xb9], false),
+    (0x777e, &[0xd8, 0xba], false),
+    (0x9f17, &[0xd8, 0xbb], false),
+    (0x4e36, &[0xd8, 0xbc], false),
+    (0x4e9f, &[0xd8, 0xbd], false),
+    (0x9f10, &[0xd8, 0xbe], false),
+    (0x4e5c, &[0xd8, 0xbf], false),
+    (0x4e69, &[0xd8, 0xc0], false),
+    (0x4e93, &[0xd8, 0xc1], false),
+    (0x8288, &[0xd8, 0xc2], false),
+    (0x5b5b, &[0xd8, 0xc3], false),
+    (0x556c, &[0xd8, 0xc4], false),
+    (0x560f, &[0xd8, 0xc5], false),
+    (0x4ec4, &[0xd8, 0xc6], false),
+    (0x538d, &[0xd8, 0xc7], false),
+    (0x539d, &[0xd8, 0xc8], false),
+    (0x53a3, &[0xd8, 0xc9], false),
+    (0x53a5, &[0xd8, 0xca], false),
+    (0x53ae, &[0xd8, 0xcb], false),
+    (0x9765, &[0xd8, 0xcc], false),
+    (0x8d5d, &[0xd8, 0xcd], false),
+    (0x531a, &[0xd8, 0xce], false),
+    (0x53f5, &[0xd8, 0xcf], false),
+    (0x5326, &[0xd8, 0xd0], false),
+    (0x532e, &[0xd8, 0xd1], false),
+    (0x533e, &[0xd8, 0xd2], false),
+    (0x8d5c, &[0xd8, 0xd3], false),
+    (0x5366, &[0xd8, 0xd4], false),
+    (0x5363, &[0xd8, 0xd5], false),
+    (0x5202, &[0xd8, 0xd6], false),
+    (0x5208, &[0xd8, 0xd7], false),
+    (0x520e, &[0xd8, 0xd8], false),
+    (0x522d, &[0xd8, 0xd9], false),
+    (0x5233, &[0xd8, 0xda], false),
+    (0x523f, &[0xd8, 0xdb], false),
+    (0x5240, &[0xd8, 0xdc], false),
+    (0x524c, &[0xd8, 0xdd], false),
+    (0x525e, &[0xd8, 0xde], false),
+    (0x5261, &[0xd8, 0xdf], false),
+    (0x525c, &[0xd8, 0xe0], false),
+    (0x84af, &[0xd8, 0xe1], false),
+    (0x527d, &[0xd8, 0xe2], false),
+    (0x5282, &[0xd8, 0xe3], false),
+    (0x5281, &[0xd8, 0xe4], false),
+    (0x5290, &[0xd8, 0xe5], false),
+    (0x5293, &[0xd8, 0xe6], false),
+    (0x5182, &[0xd8, 0xe7], false),
+    (0x7f54, &[0xd8, 0xe8], false),
+    (0x4ebb, &[0xd8, 0xe9], false),
+    (0x4ec3, &[0xd8, 0xea], false),
+    (0x4ec9, &[0xd8, 0xeb], false),
+    (0x4ec2, &[0xd8, 0xec], false),
+    (0x4ee8, &[0xd8, 0xed], false),
+    (0x4ee1, &[0xd8, 0xee], false),
+    (0x4eeb, &[0xd8, 0xef], false),
+    (0x4ede, &[0xd8, 0xf0], false),
+    (0x4f1b, &[0xd8, 0xf1], false),
+    (0x4ef3, &[0xd8, 0xf2], false),
+    (0x4f22, &[0xd8, 0xf3], false),
+    (0x4f64, &[0xd8, 0xf4], false),
+    (0x4ef5, &[0xd8, 0xf5], false),
+    (0x4f25, &[0xd8, 0xf6], false),
+    (0x4f27, &[0xd8, 0xf7], false),
+    (0x4f09, &[0xd8, 0xf8], false),
+    (0x4f2b, &[0xd8, 0xf9], false),
+    (0x4f5e, &[0xd8, 0xfa], false),
+    (0x4f67, &[0xd8, 0xfb], false),
+    (0x6538, &[0xd8, 0xfc], false),
+    (0x4f5a, &[0xd8, 0xfd], false),
+    (0x4f5d, &[0xd8, 0xfe], false),
+    (0x4f5f, &[0xd9, 0xa1], false),
+    (0x4f57, &[0xd9, 0xa2], false),
+    (0x4f32, &[0xd9, 0xa3], false),
+    (0x4f3d, &[0xd9, 0xa4], false),
+    (0x4f76, &[0xd9, 0xa5], false),
+    (0x4f74, &[0xd9, 0xa6], false),
+    (0x4f91, &[0xd9, 0xa7], false),
+    (0x4f89, &[0xd9, 0xa8], false),
+    (0x4f83, &[0xd9, 0xa9], false),
+    (0x4f8f, &[0xd9, 0xaa], false),
+    (0x4f7e, &[0xd9, 0xab], false),
+    (0x4f7b, &[0xd9, 0xac], false),
+    (0x4faa, &[0xd9, 0xad], false),
+    (0x4f7c, &[0xd9, 0xae], false),
+    (0x4fac, &[0xd9, 0xaf], false),
+    (0x4f94, &[0xd9, 0xb0], false),
+    (0x4fe6, &[0xd9, 0xb1], false),
+    (0x4fe8, &[0xd9, 0xb2], false),
+    (0x4fea, &[0xd9, 0xb3], false),
+    (0x4fc5, &[0xd9, 0xb4], false),
+    (0x4fda, &[0xd9, 0xb5], false),
+    (0x4fe3, &[0xd9, 0xb6], false),
+    (0x4fdc, &[0xd9, 0xb7], false),
+    (0x4fd1, &[0xd9, 0xb8], false),
+    (0x4fdf, &[0xd9, 0xb9], false),
+    (0x4ff8, &[0xd9, 0xba], false),
+    (0x5029, &[0xd9, 0xbb], false),
+    (0x504c, &[0xd9, 0xbc], false),
+    (0x4ff3, &[0xd9, 0xbd], false),
+    (0x502c, &[0xd9, 0xbe], false),
+    (0x500f, &[0xd9, 0xbf], false),
+    (0x502e, &[0xd9, 0xc0], false),
+    (0x502d, &[0xd9, 0xc1], false),
+    (0x4ffe, &[0xd9, 0xc2], false),
+    (0x501c, &[0xd9, 0xc3], false),
+    (0x500c, &[0xd9, 0xc4], false),
+    (0x5025, &[0xd9, 0xc5], false),
+    (0x5028, &[0xd9, 0xc6], false),
+    (0x507e, &[0xd9, 0xc7], false),
+    (0x5043, &[0xd9, 0xc8], false),
+    (0x5055, &[0xd9, 0xc9], false),
+    (0x5048, &[0xd9, 0xca], false),
+    (0x504e, &[0xd9, 0xcb], false),
+    (0x506c, &[0xd9, 0xcc], false),
+    (0x507b, &[0xd9, 0xcd], false),
+    (0x50a5, &[0xd9, 0xce], false),
+    (0x50a7, &[0xd9, 0xcf], false),
+    (0x50a9, &[0xd9, 0xd0], false),
+    (0x50ba, &[0xd9, 0xd1], false),
+    (0x50d6, &[0xd9, 0xd2], false),
+    (0x5106, &[0xd9, 0xd3], false),
+    (0x50ed, &[0xd9, 0xd4], false),
+    (0x50ec, &[0xd9, 0xd5], false),
+    (0x50e6, &[0xd9, 0xd6], false),
+    (0x50ee, &[0xd9, 0xd7], false),
+    (0x5107, &[0xd9, 0xd8], false),
+    (0x510b, &[0xd9, 0xd9], false),
+    (0x4edd, &[0xd9, 0xda], false),
+    (0x6c3d, &[0xd9, 0xdb], false),
+    (0x4f58, &[0xd9, 0xdc], false),
+    (0x4f65, &[0xd9, 0xdd], false),
+    (0x4fce, &[0xd9, 0xde], false),
+    (0x9fa0, &[0xd9, 0xdf], false),
+    (0x6c46, &[0xd9, 0xe0], false),
+    (0x7c74, &[0xd9, 0xe1], false),
+    (0x516e, &[0xd9, 0xe2], false),
+    (0x5dfd, &[0xd9, 0xe3], false),
+    (0x9ec9, &[0xd9, 0xe4], false),
+    (0x9998, &[0xd9, 0xe5], false),
+    (0x5181, &[0xd9, 0xe6], false),
+    (0x5914, &[0xd9, 0xe7], false),
+    (0x52f9, &[0xd9, 0xe8], false),
+    (0x530d, &[0xd9, 0xe9], false),
+    (0x8a07, &[0xd9, 0xea], false),
+    (0x5310, &[0xd9, 0xeb], false),
+    (0x51eb, &[0xd9, 0xec], false),
+    (0x5919, &[0xd9, 0xed], false),
+    (0x5155, &[0xd9, 0xee], false),
+    (0x4ea0, &[0xd9, 0xef], false),
+    (0x5156, &[0xd9, 0xf0], false),
+    (0x4eb3, &[0xd9, 0xf1], false),
+    (0x886e, &[0xd9, 0xf2], false),
+    (0x88a4, &[0xd9, 0xf3], false),
+    (0x4eb5, &[0xd9, 0xf4], false),
+    (0x8114, &[0xd9, 0xf5], false),
+    (0x88d2, &[0xd9, 0xf6], false),
+    (0x7980, &[0xd9, 0xf7], false),
+    (0x5b34, &[0xd9, 0xf8], false),
+    (0x8803, &[0xd9, 0xf9], false),
+    (0x7fb8, &[0xd9, 0xfa], false),
+    (0x51ab, &[0xd9, 0xfb], false),
+    (0x51b1, &[0xd9, 0xfc], false),
+    (0x51bd, &[0xd9, 0xfd], false),
+    (0x51bc, &[0xd9, 0xfe], false),
+    (0x51c7, &[0xda, 0xa1], false),
+    (0x5196, &[0xda, 0xa2], false),
+    (0x51a2, &[0xda, 0xa3], false),
+    (0x51a5, &[0xda, 0xa4], false),
+    (0x8ba0, &[0xda, 0xa5], false),
+    (0x8ba6, &[0xda, 0xa6], false),
+    (0x8ba7, &[0xda, 0xa7], false),
+    (0x8baa, &[0xda, 0xa8], false),
+    (0x8bb4, &[0xda, 0xa9], false),
+    (0x8bb5, &[0xda, 0xaa], false),
+    (0x8bb7, &[0xda, 0xab], false),
+    (0x8bc2, &[0xda, 0xac], false),
+    (0x8bc3, &[0xda, 0xad], false),
+    (0x8bcb, &[0xda, 0xae], false),
+    (0x8bcf, &[0xda, 0xaf], false),
+    (0x8bce, &[0xda, 0xb0], false),
+    (0x8bd2, &[0xda, 0xb1], false),
+    (0x8bd3, &[0xda, 0xb2], false),
+    (0x8bd4, &[0xda, 0xb3], false),
+    (0x8bd6, &[0xda, 0xb4], false),
+    (0x8bd8, &[0xda, 0xb5], false),
+    (0x8bd9, &[0xda, 0xb6], false),
+    (0x8bdc, &[0xda, 0xb7], false),
+    (0x8bdf, &[0xda, 0xb8], false),
+    (0x8be0, &[0xda, 0xb9], false),
+    (0x8be4, &[0xda, 0xba], false),
+    (0x8be8, &[0xda, 0xbb], false),
+    (0x8be9, &[0xda, 0xbc], false),
+    (0x8bee, &[0xda, 0xbd], false),
+    (0x8bf0, &[0xda, 0xbe], false),
+    (0x8bf3, &[0xda, 0xbf], false),
+    (0x8bf6, &[0xda, 0xc0], false),
+    (0x8bf9, &[0xda, 0xc1], false),
+    (0x8bfc, &[0xda, 0xc2], false),
+    (0x8bff, &[0xda, 0xc3], false),
+    (0x8c00, &[0xda, 0xc4], false),
+    (0x8c02, &[0xda, 0xc5], false),
+    (0x8c04, &[0xda, 0xc6], false),
+    (0x8c07, &[0xda, 0xc7], false),
+    (0x8c0c, &[0xda, 0xc8], false),
+    (0x8c0f, &[0xda, 0xc9], false),
+    (0x8c11, &[0xda, 0xca], false),
+    (0x8c12, &[0xda, 0xcb], false),
+    (0x8c14, &[0xda, 0xcc], false),
+    (0x8c15, &[0xda, 0xcd], false),
+    (0x8c16, &[0xda, 0xce], false),
+    (0x8c19, &[0xda, 0xcf], false),
+    (0x8c1b, &[0xda, 0xd0], false),
+    (0x8c18, &[0xda, 0xd1], false),
+    (0x8c1d, &[0xda, 0xd2], false),
+    (0x8c1f, &[0xda, 0xd3], false),
+    (0x8c20, &[0xda, 0xd4], false),
+    (0x8c21, &[0xda, 0xd5], false),
+    (0x8c25, &[0xda, 0xd6], false),
+    (0x8c27, &[0xda, 0xd7], false),
+    (0x8c2a, &[0xda, 0xd8], false),
+    (0x8c2b, &[0xda, 0xd9], false),
+    (0x8c2e, &[0xda, 0xda], false),
+    (0x8c2f, &[0xda, 0xdb], false),
+    (0x8c32, &[0xda, 0xdc], false),
+    (0x8c33, &[0xda, 0xdd], false),
+    (0x8c35, &[0xda, 0xde], false),
+    (0x8c36, &[0xda, 0xdf], false),
+    (0x5369, &[0xda, 0xe0], false),
+    (0x537a, &[0xda, 0xe1], false),
+    (0x961d, &[0xda, 0xe2], false),
+    (0x9622, &[0xda, 0xe3], false),
+    (0x9621, &[0xda, 0xe4], false),
+    (0x9631, &[0xda, 0xe5], false),
+    (0x962a, &[0xda, 0xe6], false),
+    (0x963d, &[0xda, 0xe7], false),
+    (0x963c, &[0xda, 0xe8], false),
+    (0x9642, &[0xda, 0xe9], false),
+    (0x9649, &[0xda, 0xea], false),
+    (0x9654, &[0xda, 0xeb], false),
+    (0x965f, &[0xda, 0xec], false),
+    (0x9667, &[0xda, 0xed], false),
+    (0x966c, &[0xda, 0xee], false),
+    (0x9672, &[0xda, 0xef], false),
+    (0x9674, &[0xda, 0xf0], false),
+    (0x9688, &[0xda, 0xf1], false),
+    (0x968d, &[0xda, 0xf2], false),
+    (0x9697, &[0xda, 0xf3], false),
+    (0x96b0, &[0xda, 0xf4], false),
+    (0x9097, &[0xda, 0xf5], false),
+    (0x909b, &[0xda, 0xf6], false),
+    (0x909d, &[0xda, 0xf7], false),
+    (0x9099, &[0xda, 0xf8], false),
+    (0x90ac, &[0xda, 0xf9], false),
+    (0x90a1, &[0xda, 0xfa], false),
+    (0x90b4, &[0xda, 0xfb], false),
+    (0x90b3, &[0xda, 0xfc], false),
+    (0x90b6, &[0xda, 0xfd], false),
+    (0x90ba, &[0xda, 0xfe], false),
+    (0x90b8, &[0xdb, 0xa1], false),
+    (0x90b0, &[0xdb, 0xa2], false),
+    (0x90cf, &[0xdb, 0xa3], false),
+    (0x90c5, &[0xdb, 0xa4], false),
+    (0x90be, &[0xdb, 0xa5], false),
+    (0x90d0, &[0xdb, 0xa6], false),
+    (0x90c4, &[0xdb, 0xa7], false),
+    (0x90c7, &[0xdb, 0xa8], false),
+    (0x90d3, &[0xdb, 0xa9], false),
+    (0x90e6, &[0xdb, 0xaa], false),
+    (0x90e2, &[0xdb, 0xab], false),
+    (0x90dc, &[0xdb, 0xac], false),
+    (0x90d7, &[0xdb, 0xad], false),
+    (0x90db, &[0xdb, 0xae], false),
+    (0x90eb, &[0xdb, 0xaf], false),
+    (0x90ef, &[0xdb, 0xb0], false),
+    (0x90fe, &[0xdb, 0xb1], false),
+    (0x9104, &[0xdb, 0xb2], false),
+    (0x9122, &[0xdb, 0xb3], false),
+    (0x911e, &[0xdb, 0xb4], false),
+    (0x9123, &[0xdb, 0xb5], false),
+    (0x9131, &[0xdb, 0xb6], false),
+    (0x912f, &[0xdb, 0xb7], false),
+    (0x9139, &[0xdb, 0xb8], false),
+    (0x9143, &[0xdb, 0xb9], false),
+    (0x9146, &[0xdb, 0xba], false),
+    (0x520d, &[0xdb, 0xbb], false),
+    (0x5942, &[0xdb, 0xbc], false),
+    (0x52a2, &[0xdb, 0xbd], false),
+    (0x52ac, &[0xdb, 0xbe], false),
+    (0x52ad, &[0xdb, 0xbf], false),
+    (0x52be, &[0xdb, 0xc0], false),
+    (0x54ff, &[0xdb, 0xc1], false),
+    (0x52d0, &[0xdb, 0xc2], false),
+    (0x52d6, &[0xdb, 0xc3], false),
+    (0x52f0, &[0xdb, 0xc4], false),
+    (0x53df, &[0xdb, 0xc5], false),
+    (0x71ee, &[0xdb, 0xc6], false),
+    (0x77cd, &[0xdb, 0xc7], false),
+    (0x5ef4, &[0xdb, 0xc8], false),
+    (0x51f5, &[0xdb, 0xc9], false),
+    (0x51fc, &[0xdb, 0xca], false),
+    (0x9b2f, &[0xdb, 0xcb], false),
+    (0x53b6, &[0xdb, 0xcc], false),
+    (0x5f01, &[0xdb, 0xcd], false),
+    (0x755a, &[0xdb, 0xce], false),
+    (0x5def, &[0xdb, 0xcf], false),
+    (0x574c, &[0xdb, 0xd0], false),
+    (0x57a9, &[0xdb, 0xd1], false),
+    (0x57a1, &[0xdb, 0xd2], false),
+    (0x587e, &[0xdb, 0xd3], false),
+    (0x58bc, &[0xdb, 0xd4], false),
+    (0x58c5, &[0xdb, 0xd5], false),
+    (0x58d1, &[0xdb, 0xd6], false),
+    (0x5729, &[0xdb, 0xd7], false),
+    (0x572c, &[0xdb, 0xd8], false),
+    (0x572a, &[0xdb, 0xd9], false),
+    (0x5733, &[0xdb, 0xda], false),
+    (0x5739, &[0xdb, 0xdb], false),
+    (0x572e, &[0xdb, 0xdc], false),
+    (0x572f, &[0xdb, 0xdd], false),
+    (0x575c, &[0xdb, 0xde], false),
+    (0x573b, &[0xdb, 0xdf], false),
+    (0x5742, &[0xdb, 0xe0], false),
+    (0x5769, &[0xdb, 0xe1], false),
+    (0x5785, &[0xdb, 0xe2], false),
+    (0x576b, &[0xdb, 0xe3], false),
+    (0x5786, &[0xdb, 0xe4], false),
+    (0x577c, &[0xdb, 0xe5], false),
+    (0x577b, &[0xdb, 0xe6], false),
+    (0x5768, &[0xdb, 0xe7], false),
+    (0x576d, &[0xdb, 0xe8], false),
+    (0x5776, &[0xdb, 0xe9], false),
+    (0x5773, &[0xdb, 0xea], false),
+    (0x57ad, &[0xdb, 0xeb], false),
+    (0x57a4, &[0xdb, 0xec], false),
+    (0x578c, &[0xdb, 0xed], false),
+    (0x57b2, &[0xdb, 0xee], false),
+    (0x57cf, &[0xdb, 0xef], false),
+    (0x57a7, &[0xdb, 0xf0], false),
+    (0x57b4, &[0xdb, 0xf1], false),
+    (0x5793, &[0xdb, 0xf2], false),
+    (0x57a0, &[0xdb, 0xf3], false),
+    (0x57d5, &[0xdb, 0xf4], false),
+    (0x57d8, &[0xdb, 0xf5], false),
+    (0x57da, &[0xdb, 0xf6], false),
+    (0x57d9, &[0xdb, 0xf7], false),
+    (0x57d2, &[0xdb, 0xf8], false),
+    (0x57b8, &[0xdb, 0xf9], false),
+    (0x57f4, &[0xdb, 0xfa], false),
+    (0x57ef, &[0xdb, 0xfb], false),
+    (0x57f8, &[0xdb, 0xfc], false),
+    (0x57e4, &[0xdb, 0xfd], false),
+    (0x57dd, &[0xdb, 0xfe], false),
+    (0x580b, &[0xdc, 0xa1], false),
+    (0x580d, &[0xdc, 0xa2], false),
+    (0x57fd, &[0xdc, 0xa3], false),
+    (0x57ed, &[0xdc, 0xa4], false),
+    (0x5800, &[0xdc, 0xa5], false),
+    (0x581e, &[0xdc, 0xa6], false),
+    (0x5819, &[0xdc, 0xa7], false),
+    (0x5844, &[0xdc, 0xa8], false),
+    (0x5820, &[0xdc, 0xa9], false),
+    (0x5865, &[0xdc, 0xaa], false),
+    (0x586c, &[0xdc, 0xab], false),
+    (0x5881, &[0xdc, 0xac], false),
+    (0x5889, &[0xdc, 0xad], false),
+    (0x589a, &[0xdc, 0xae], false),
+    (0x5880, &[0xdc, 0xaf], false),
+    (0x99a8, &[0xdc, 0xb0], false),
+    (0x9f19, &[0xdc, 0xb1], false),
+    (0x61ff, &[0xdc, 0xb2], false),
+    (0x8279, &[0xdc, 0xb3], false),
+    (0x827d, &[0xdc, 0xb4], false),
+    (0x827f, &[0xdc, 0xb5], false),
+    (0x828f, &[0xdc, 0xb6], false),
+    (0x828a, &[0xdc, 0xb7], false),
+    (0x82a8, &[0xdc, 0xb8], false),
+    (0x8284, &[0xdc, 0xb9], false),
+    (0x828e, &[0xdc, 0xba], false),
+    (0x8291, &[0xdc, 0xbb], false),
+    (0x8297, &[0xdc, 0xbc], false),
+    (0x8299, &[0xdc, 0xbd], false),
+    (0x82ab, &[0xdc, 0xbe], false),
+    (0x82b8, &[0xdc, 0xbf], false),
+    (0x82be, &[0xdc, 0xc0], false),
+    (0x82b0, &[0xdc, 0xc1], false),
+    (0x82c8, &[0xdc, 0xc2], false),
+    (0x82ca, &[0xdc, 0xc3], false),
+    (0x82e3, &[0xdc, 0xc4], false),
+    (0x8298, &[0xdc, 0xc5], false),
+    (0x82b7, &[0xdc, 0xc6], false),
+    (0x82ae, &[0xdc, 0xc7], false),
+    (0x82cb, &[0xdc, 0xc8], false),
+    (0x82cc, &[0xdc, 0xc9], false),
+    (0x82c1, &[0xdc, 0xca], false),
+    (0x82a9, &[0xdc, 0xcb], false),
+    (0x82b4, &[0xdc, 0xcc], false),
+    (0x82a1, &[0xdc, 0xcd], false),
+    (0x82aa, &[0xdc, 0xce], false),
+    (0x829f, &[0xdc, 0xcf], false),
+    (0x82c4, &[0xdc, 0xd0], false),
+    (0x82ce, &[0xdc, 0xd1], false),
+    (0x82a4, &[0xdc, 0xd2], false),
+    (0x82e1, &[0xdc, 0xd3], false),
+    (0x8309, &[0xdc, 0xd4], false),
+    (0x82f7, &[0xdc, 0xd5], false),
+    (0x82e4, &[0xdc, 0xd6], false),
+    (0x830f, &[0xdc, 0xd7], false),
+    (0x8307, &[0xdc, 0xd8], false),
+    (0x82dc, &[0xdc, 0xd9], false),
+    (0x82f4, &[0xdc, 0xda], false),
+    (0x82d2, &[0xdc, 0xdb], false),
+    (0x82d8, &[0xdc, 0xdc], false),
+    (0x830c, &[0xdc, 0xdd], false),
+    (0x82fb, &[0xdc, 0xde], false),
+    (0x82d3, &[0xdc, 0xdf], false),
+    (0x8311, &[0xdc, 0xe0], false),
+    (0x831a, &[0xdc, 0xe1], false),
+    (0x8306, &[0xdc, 0xe2], false),
+    (0x8314, &[0xdc, 0xe3], false),
+    (0x8315, &[0xdc, 0xe4], false),
+    (0x82e0, &[0xdc, 0xe5], false),
+    (0x82d5, &[0xdc, 0xe6], false),
+    (0x831c, &[0xdc, 0xe7], false),
+    (0x8351, &[0xdc, 0xe8], false),
+    (0x835b, &[0xdc, 0xe9], false),
+    (0x835c, &[0xdc, 0xea], false),
+    (0x8308, &[0xdc, 0xeb], false),
+    (0x8392, &[0xdc, 0xec], false),
+    (0x833c, &[0xdc, 0xed], false),
+    (0x8334, &[0xdc, 0xee], false),
+    (0x8331, &[0xdc, 0xef], false),
+    (0x839b, &[0xdc, 0xf0], false),
+    (0x835e, &[0xdc, 0xf1], false),
+    (0x832f, &[0xdc, 0xf2], false),
+    (0x834f, &[0xdc, 0xf3], false),
+    (0x8347, &[0xdc, 0xf4], false),
+    (0x8343, &[0xdc, 0xf5], false),
+    (0x835f, &[0xdc, 0xf6], false),
+    (0x8340, &[0xdc, 0xf7], false),
+    (0x8317, &[0xdc, 0xf8], false),
+    (0x8360, &[0xdc, 0xf9], false),
+    (0x832d, &[0xdc, 0xfa], false),
+    (0x833a, &[0xdc, 0xfb], false),
+    (0x8333, &[0xdc, 0xfc], false),
+    (0x8366, &[0xdc, 0xfd], false),
+    (0x8365, &[0xdc, 0xfe], false),
+    (0x8368, &[0xdd, 0xa1], false),
+    (0x831b, &[0xdd, 0xa2], false),
+    (0x8369, &[0xdd, 0xa3], false),
+    (0x836c, &[0xdd, 0xa4], false),
+    (0x836a, &[0xdd, 0xa5], false),
+    (0x836d, &[0xdd, 0xa6], false),
+    (0x836e, &[0xdd, 0xa7], false),
+    (0x83b0, &[0xdd, 0xa8], false),
+    (0x8378, &[0xdd, 0xa9], false),
+    (0x83b3, &[0xdd, 0xaa], false),
+    (0x83b4, &[0xdd, 0xab], false),
+    (0x83a0, &[0xdd, 0xac], false),
+    (0x83aa, &[0xdd, 0xad], false),
+    (0x8393, &[0xdd, 0xae], false),
+    (0x839c, &[0xdd, 0xaf], false),
+    (0x8385, &[0xdd, 0xb0], false),
+    (0x837c, &[0xdd, 0xb1], false),
+    (0x83b6, &[0xdd, 0xb2], false),
+    (0x83a9, &[0xdd, 0xb3], false),
+    (0x837d, &[0xdd, 0xb4], false),
+    (0x83b8, &[0xdd, 0xb5], false),
+    (0x837b, &[0xdd, 0xb6], false),
+    (0x8398, &[0xdd, 0xb7], false),
+    (0x839e, &[0xdd, 0xb8], false),
+    (0x83a8, &[0xdd, 0xb9], false),
+    (0x83ba, &[0xdd, 0xba], false),
+    (0x83bc, &[0xdd, 0xbb], false),
+    (0x83c1, &[0xdd, 0xbc], false),
+    (0x8401, &[0xdd, 0xbd], false),
+    (0x83e5, &[0xdd, 0xbe], false),
+    (0x83d8, &[0xdd, 0xbf], false),
+    (0x5807, &[0xdd, 0xc0], false),
+    (0x8418, &[0xdd, 0xc1], false),
+    (0x840b, &[0xdd, 0xc2], false),
+    (0x83dd, &[0xdd, 0xc3], false),
+    (0x83fd, &[0xdd, 0xc4], false),
+    (0x83d6, &[0xdd, 0xc5], false),
+    (0x841c, &[0xdd, 0xc6], false),
+    (0x8438, &[0xdd, 0xc7], false),
+    (0x8411, &[0xdd, 0xc8], false),
+    (0x8406, &[0xdd, 0xc9], false),
+    (0x83d4, &[0xdd, 0xca], false),
+    (0x83df, &[0xdd, 0xcb], false),
+    (0x840f, &[0xdd, 0xcc], false),
+    (0x8403, &[0xdd, 0xcd], false),
+    (0x83f8, &[0xdd, 0xce], false),
+    (0x83f9, &[0xdd, 0xcf], false),
+    (0x83ea, &[0xdd, 0xd0], false),
+    (0x83c5, &[0xdd, 0xd1], false),
+    (0x83c0, &[0xdd, 0xd2], false),
+    (0x8426, &[0xdd, 0xd3], false),
+    (0x83f0, &[0xdd, 0xd4], false),
+    (0x83e1, &[0xdd, 0xd5], false),
+    (0x845c, &[0xdd, 0xd6], false),
+    (0x8451, &[0xdd, 0xd7], false),
+    (0x845a, &[0xdd, 0xd8], false),
+    (0x8459, &[0xdd, 0xd9], false),
+    (0x8473, &[0xdd, 0xda], false),
+    (0x8487, &[0xdd, 0xdb], false),
+    (0x8488, &[0xdd, 0xdc], false),
+    (0x847a, &[0xdd, 0xdd], false),
+    (0x8489, &[0xdd, 0xde], false),
+    (0x8478, &[0xdd, 0xdf], false),
+    (0x843c, &[0xdd, 0xe0], false),
+    (0x8446, &[0xdd, 0xe1], false),
+    (0x8469, &[0xdd, 0xe2], false),
+    (0x8476, &[0xdd, 0xe3], false),
+    (0x848c, &[0xdd, 0xe4], false),
+    (0x848e, &[0xdd, 0xe5], false),
+    (0x8431, &[0xdd, 0xe6], false),
+    (0x846d, &[0xdd, 0xe7], false),
+    (0x84c1, &[0xdd, 0xe8], false),
+    (0x84cd, &[0xdd, 0xe9], false),
+    (0x84d0, &[0xdd, 0xea], false),
+    (0x84e6, &[0xdd, 0xeb], false),
+    (0x84bd, &[0xdd, 0xec], false),
+    (0x84d3, &[0xdd, 0xed], false),
+    (0x84ca, &[0xdd, 0xee], false),
+    (0x84bf, &[0xdd, 0xef], false),
+    (0x84ba, &[0xdd, 0xf0], false),
+    (0x84e0, &[0xdd, 0xf1], false),
+    (0x84a1, &[0xdd, 0xf2], false),
+    (0x84b9, &[0xdd, 0xf3], false),
+    (0x84b4, &[0xdd, 0xf4], false),
+    (0x8497, &[0xdd, 0xf5], false),
+    (0x84e5, &[0xdd, 0xf6], false),
+    (0x84e3, &[0xdd, 0xf7], false),
+    (0x850c, &[0xdd, 0xf8], false),
+    (0x750d, &[0xdd, 0xf9], false),
+    (0x8538, &[0xdd, 0xfa], false),
+    (0x84f0, &[0xdd, 0xfb], false),
+    (0x8539, &[0xdd, 0xfc], false),
+    (0x851f, &[0xdd, 0xfd], false),
+    (0x853a, &[0xdd, 0xfe], false),
+    (0x8556, &[0xde, 0xa1], false),
+    (0x853b, &[0xde, 0xa2], false),
+    (0x84ff, &[0xde, 0xa3], false),
+    (0x84fc, &[0xde, 0xa4], false),
+    (0x8559, &[0xde, 0xa5], false),
+    (0x8548, &[0xde, 0xa6], false),
+    (0x8568, &[0xde, 0xa7], false),
+    (0x8564, &[0xde, 0xa8], false),
+    (0x855e, &[0xde, 0xa9], false),
+    (0x857a, &[0xde, 0xaa], false),
+    (0x77a2, &[0xde, 0xab], false),
+    (0x8543, &[0xde, 0xac], false),
+    (0x8572, &[0xde, 0xad], false),
+    (0x857b, &[0xde, 0xae], false),
+    (0x85a4, &[0xde, 0xaf], false),
+    (0x85a8, &[0xde, 0xb0], false),
+    (0x8587, &[0xde, 0xb1], false),
+    (0x858f, &[0xde, 0xb2], false),
+    (0x8579, &[0xde, 0xb3], false),
+    (0x85ae, &[0xde, 0xb4], false),
+    (0x859c, &[0xde, 0xb5], false),
+    (0x8585, &[0xde, 0xb6], false),
+    (0x85b9, &[0xde, 0xb7], false),
+    (0x85b7, &[0xde, 0xb8], false),
+    (0x85b0, &[0xde, 0xb9], false),
+    (0x85d3, &[0xde, 0xba], false),
+    (0x85c1, &[0xde, 0xbb], false),
+    (0x85dc, &[0xde, 0xbc], false),
+    (0x85ff, &[0xde, 0xbd], false),
+    (0x8627, &[0xde, 0xbe], false),
+    (0x8605, &[0xde, 0xbf], false),
+    (0x8629, &[0xde, 0xc0], false),
+    (0x8616, &[0xde, 0xc1], false),
+    (0x863c, &[0xde, 0xc2], false),
+    (0x5efe, &[0xde, 0xc3], false),
+    (0x5f08, &[0xde, 0xc4], false),
+    (0x593c, &[0xde, 0xc5], false),
+    (0x5941, &[0xde, 0xc6], false),
+    (0x8037, &[0xde, 0xc7], false),
+    (0x5955, &[0xde, 0xc8], false),
+    (0x595a, &[0xde, 0xc9], false),
+    (0x5958, &[0xde, 0xca], false),
+    (0x530f, &[0xde, 0xcb], false),
+    (0x5c22, &[0xde, 0xcc], false),
+    (0x5c25, &[0xde, 0xcd], false),
+    (0x5c2c, &[0xde, 0xce], false),
+    (0x5c34, &[0xde, 0xcf], false),
+    (0x624c, &[0xde, 0xd0], false),
+    (0x626a, &[0xde, 0xd1], false),
+    (0x629f, &[0xde, 0xd2], false),
+    (0x62bb, &[0xde, 0xd3], false),
+    (0x62ca, &[0xde, 0xd4], false),
+    (0x62da, &[0xde, 0xd5], false),
+    (0x62d7, &[0xde, 0xd6], false),
+    (0x62ee, &[0xde, 0xd7], false),
+    (0x6322, &[0xde, 0xd8], false),
+    (0x62f6, &[0xde, 0xd9], false),
+    (0x6339, &[0xde, 0xda], false),
+    (0x634b, &[0xde, 0xdb], false),
+    (0x6343, &[0xde, 0xdc], false),
+    (0x63ad, &[0xde, 0xdd], false),
+    (0x63f6, &[0xde, 0xde], false),
+    (0x6371, &[0xde, 0xdf], false),
+    (0x637a, &[0xde, 0xe0], false),
+    (0x638e, &[0xde, 0xe1], false),
+    (0x63b4, &[0xde, 0xe2], false),
+    (0x636d, &[0xde, 0xe3], false),
+    (0x63ac, &[0xde, 0xe4], false),
+    (0x638a, &[0xde, 0xe5], false),
+    (0x6369, &[0xde, 0xe6], false),
+    (0x63ae, &[0xde, 0xe7], false),
+    (0x63bc, &[0xde, 0xe8], false),
+    (0x63f2, &[0xde, 0xe9], false),
+    (0x63f8, &[0xde, 0xea], false),
+    (0x63e0, &[0xde, 0xeb], false),
+    (0x63ff, &[0xde, 0xec], false),
+    (0x63c4, &[0xde, 0xed], false),
+    (0x63de, &[0xde, 0xee], false),
+    (0x63ce, &[0xde, 0xef], false),
+    (0x6452, &[0xde, 0xf0], false),
+    (0x63c6, &[0xde, 0xf1], false),
+    (0x63be, &[0xde, 0xf2], false),
+    (0x6445, &[0xde, 0xf3], false),
+    (0x6441, &[0xde, 0xf4], false),
+    (0x640b, &[0xde, 0xf5], false),
+    (0x641b, &[0xde, 0xf6], false),
+    (0x6420, &[0xde, 0xf7], false),
+    (0x640c, &[0xde, 0xf8], false),
+    (0x6426, &[0xde, 0xf9], false),
+    (0x6421, &[0xde, 0xfa], false),
+    (0x645e, &[0xde, 0xfb], false),
+    (0x6484, &[0xde, 0xfc], false),
+    (0x646d, &[0xde, 0xfd], false),
+    (0x6496, &[0xde, 0xfe], false),
+    (0x647a, &[0xdf, 0xa1], false),
+    (0x64b7, &[0xdf, 0xa2], false),
+    (0x64b8, &[0xdf, 0xa3], false),
+    (0x6499, &[0xdf, 0xa4], false),
+    (0x64ba, &[0xdf, 0xa5], false),
+    (0x64c0, &[0xdf, 0xa6], false),
+    (0x64d0, &[0xdf, 0xa7], false),
+    (0x64d7, &[0xdf, 0xa8], false),
+    (0x64e4, &[0xdf, 0xa9], false),
+    (0x64e2, &[0xdf, 0xaa], false),
+    (0x6509, &[0xdf, 0xab], false),
+    (0x6525, &[0xdf, 0xac], false),
+    (0x652e, &[0xdf, 0xad], false),
+    (0x5f0b, &[0xdf, 0xae], false),
+    (0x5fd2, &[0xdf, 0xaf], false),
+    (0x7519, &[0xdf, 0xb0], false),
+    (0x5f11, &[0xdf, 0xb1], false),
+    (0x535f, &[0xdf, 0xb2], false),
+    (0x53f1, &[0xdf, 0xb3], false),
+    (0x53fd, &[0xdf, 0xb4], false),
+    (0x53e9, &[0xdf, 0xb5], false),
+    (0x53e8, &[0xdf, 0xb6], false),
+    (0x53fb, &[0xdf, 0xb7], false),
+    (0x5412, &[0xdf, 0xb8], false),
+    (0x5416, &[0xdf, 0xb9], false),
+    (0x5406, &[0xdf, 0xba], false),
+    (0x544b, &[0xdf, 0xbb], false),
+    (0x5452, &[0xdf, 0xbc], false),
+    (0x5453, &[0xdf, 0xbd], false),
+    (0x5454, &[0xdf, 0xbe], false),
+    (0x5456, &[0xdf, 0xbf], false),
+    (0x5443, &[0xdf, 0xc0], false),
+    (0x5421, &[0xdf, 0xc1], false),
+    (0x5457, &[0xdf, 0xc2], false),
+    (0x5459, &[0xdf, 0xc3], false),
+    (0x5423, &[0xdf, 0xc4], false),
+    (0x5432, &[0xdf, 0xc5], false),
+    (0x5482, &[0xdf, 0xc6], false),
+    (0x5494, &[0xdf, 0xc7], false),
+    (0x5477, &[0xdf, 0xc8], false),
+    (0x5471, &[0xdf, 0xc9], false),
+    (0x5464, &[0xdf, 0xca], false),
+    (0x549a, &[0xdf, 0xcb], false),
+    (0x549b, &[0xdf, 0xcc], false),
+    (0x5484, &[0xdf, 0xcd], false),
+    (0x5476, &[0xdf, 0xce], false),
+    (0x5466, &[0xdf, 0xcf], false),
+    (0x549d, &[0xdf, 0xd0], false),
+    (0x54d0, &[0xdf, 0xd1], false),
+    (0x54ad, &[0xdf, 0xd2], false),
+    (0x54c2, &[0xdf, 0xd3], false),
+    (0x54b4, &[0xdf, 0xd4], false),
+    (0x54d2, &[0xdf, 0xd5], false),
+    (0x54a7, &[0xdf, 0xd6], false),
+    (0x54a6, &[0xdf, 0xd7], false),
+    (0x54d3, &[0xdf, 0xd8], false),
+    (0x54d4, &[0xdf, 0xd9], false),
+    (0x5472, &[0xdf, 0xda], false),
+    (0x54a3, &[0xdf, 0xdb], false),
+    (0x54d5, &[0xdf, 0xdc], false),
+    (0x54bb, &[0xdf, 0xdd], false),
+    (0x54bf, &[0xdf, 0xde], false),
+    (0x54cc, &[0xdf, 0xdf], false),
+    (0x54d9, &[0xdf, 0xe0], false),
+    (0x54da, &[0xdf, 0xe1], false),
+    (0x54dc, &[0xdf, 0xe2], false),
+    (0x54a9, &[0xdf, 0xe3], false),
+    (0x54aa, &[0xdf, 0xe4], false),
+    (0x54a4, &[0xdf, 0xe5], false),
+    (0x54dd, &[0xdf, 0xe6], false),
+    (0x54cf, &[0xdf, 0xe7], false),
+    (0x54de, &[0xdf, 0xe8], false),
+    (0x551b, &[0xdf, 0xe9], false),
+    (0x54e7, &[0xdf, 0xea], false),
+    (0x5520, &[0xdf, 0xeb], false),
+    (0x54fd, &[0xdf, 0xec], false),
+    (0x5514, &[0xdf, 0xed], false),
+    (0x54f3, &[0xdf, 0xee], false),
+    (0x5522, &[0xdf, 0xef], false),
+    (0x5523, &[0xdf, 0xf0], false),
+    (0x550f, &[0xdf, 0xf1], false),
+    (0x5511, &[0xdf, 0xf2], false),
+    (0x5527, &[0xdf, 0xf3], false),
+    (0x552a, &[0xdf, 0xf4], false),
+    (0x5567, &[0xdf, 0xf5], false),
+    (0x558f, &[0xdf, 0xf6], false),
+    (0x55b5, &[0xdf, 0xf7], false),
+    (0x5549, &[0xdf, 0xf8], false),
+    (0x556d, &[0xdf, 0xf9], false),
+    (0x5541, &[0xdf, 0xfa], false),
+    (0x5555, &[0xdf, 0xfb], false),
+    (0x553f, &[0xdf, 0xfc], false),
+    (0x5550, &[0xdf, 0xfd], false),
+    (0x553c, &[0xdf, 0xfe], false),
+    (0x5537, &[0xe0, 0xa1], false),
+    (0x5556, &[0xe0, 0xa2], false),
+    (0x5575, &[0xe0, 0xa3], false),
+    (0x5576, &[0xe0, 0xa4], false),
+    (0x5577, &[0xe0, 0xa5], false),
+    (0x5533, &[0xe0, 0xa6], false),
+    (0x5530, &[0xe0, 0xa7], false),
+    (0x555c, &[0xe0, 0xa8], false),
+    (0x558b, &[0xe0, 0xa9], false),
+    (0x55d2, &[0xe0, 0xaa], false),
+    (0x5583, &[0xe0, 0xab], false),
+    (0x55b1, &[0xe0, 0xac], false),
+    (0x55b9, &[0xe0, 0xad], false),
+    (0x5588, &[0xe0, 0xae], false),
+    (0x5581, &[0xe0, 0xaf], false),
+    (0x559f, &[0xe0, 0xb0], false),
+    (0x557e, &[0xe0, 0xb1], false),
+    (0x55d6, &[0xe0, 0xb2], false),
+    (0x5591, &[0xe0, 0xb3], false),
+    (0x557b, &[0xe0, 0xb4], false),
+    (0x55df, &[0xe0, 0xb5], false),
+    (0x55bd, &[0xe0, 0xb6], false),
+    (0x55be, &[0xe0, 0xb7], false),
+    (0x5594, &[0xe0, 0xb8], false),
+    (0x5599, &[0xe0, 0xb9], false),
+    (0x55ea, &[0xe0, 0xba], false),
+    (0x55f7, &[0xe0, 0xbb], false),
+    (0x55c9, &[0xe0, 0xbc], false),
+    (0x561f, &[0xe0, 0xbd], false),
+    (0x55d1, &[0xe0, 0xbe], false),
+    (0x55eb, &[0xe0, 0xbf], false),
+    (0x55ec, &[0xe0, 0xc0], false),
+    (0x55d4, &[0xe0, 0xc1], false),
+    (0x55e6, &[0xe0, 0xc2], false),
+    (0x55dd, &[0xe0, 0xc3], false),
+    (0x55c4, &[0xe0, 0xc4], false),
+    (0x55ef, &[0xe0, 0xc5], false),
+    (0x55e5, &[0xe0, 0xc6], false),
+    (0x55f2, &[0xe0, 0xc7], false),
+    (0x55f3, &[0xe0, 0xc8], false),
+    (0x55cc, &[0xe0, 0xc9], false),
+    (0x55cd, &[0xe0, 0xca], false),
+    (0x55e8, &[0xe0, 0xcb], false),
+    (0x55f5, &[0xe0, 0xcc], false),
+    (0x55e4, &[0xe0, 0xcd], false),
+    (0x8f94, &[0xe0, 0xce], false),
+    (0x561e, &[0xe0, 0xcf], false),
+    (0x5608, &[0xe0, 0xd0], false),
+    (0x560c, &[0xe0, 0xd1], false),
+    (0x5601, &[0xe0, 0xd2], false),
+    (0x5624, &[0xe0, 0xd3], false),
+    (0x5623, &[0xe0, 0xd4], false),
+    (0x55fe, &[0xe0, 0xd5], false),
+    (0x5600, &[0xe0, 0xd6], false),
+    (0x5627, &[0xe0, 0xd7], false),
+    (0x562d, &[0xe0, 0xd8], false),
+    (0x5658, &[0xe0, 0xd9], false),
+    (0x5639, &[0xe0, 0xda], false),
+    (0x5657, &[0xe0, 0xdb], false),
+    (0x562c, &[0xe0, 0xdc], false),
+    (0x564d, &[0xe0, 0xdd], false),
+    (0x5662, &[0xe0, 0xde], false),
+    (0x5659, &[0xe0, 0xdf], false),
+    (0x565c, &[0xe0, 0xe0], false),
+    (0x564c, &[0xe0, 0xe1], false),
+    (0x5654, &[0xe0, 0xe2], false),
+    (0x5686, &[0xe0, 0xe3], false),
+    (0x5664, &[0xe0, 0xe4], false),
+    (0x5671, &[0xe0, 0xe5], false),
+    (0x566b, &[0xe0, 0xe6], false),
+    (0x567b, &[0xe0, 0xe7], false),
+    (0x567c, &[0xe0, 0xe8], false),
+    (0x5685, &[0xe0, 0xe9], false),
+    (0x5693, &[0xe0, 0xea], false),
+    (0x56af, &[0xe0, 0xeb], false),
+    (0x56d4, &[0xe0, 0xec], false),
+    (0x56d7, &[0xe0, 0xed], false),
+    (0x56dd, &[0xe0, 0xee], false),
+    (0x56e1, &[0xe0, 0xef], false),
+    (0x56f5, &[0xe0, 0xf0], false),
+    (0x56eb, &[0xe0, 0xf1], false),
+    (0x56f9, &[0xe0, 0xf2], false),
+    (0x56ff, &[0xe0, 0xf3], false),
+    (0x5704, &[0xe0, 0xf4], false),
+    (0x570a, &[0xe0, 0xf5], false),
+    (0x5709, &[0xe0, 0xf6], false),
+    (0x571c, &[0xe0, 0xf7], false),
+    (0x5e0f, &[0xe0, 0xf8], false),
+    (0x5e19, &[0xe0, 0xf9], false),
+    (0x5e14, &[0xe0, 0xfa], false),
+    (0x5e11, &[0xe0, 0xfb], false),
+    (0x5e31, &[0xe0, 0xfc], false),
+    (0x5e3b, &[0xe0, 0xfd], false),
+    (0x5e3c, &[0xe0, 0xfe], false),
+    (0x5e37, &[0xe1, 0xa1], false),
+    (0x5e44, &[0xe1, 0xa2], false),
+    (0x5e54, &[0xe1, 0xa3], false),
+    (0x5e5b, &[0xe1, 0xa4], false),
+    (0x5e5e, &[0xe1, 0xa5], false),
+    (0x5e61, &[0xe1, 0xa6], false),
+    (0x5c8c, &[0xe1, 0xa7], false),
+    (0x5c7a, &[0xe1, 0xa8], false),
+    (0x5c8d, &[0xe1, 0xa9], false),
+    (0x5c90, &[0xe1, 0xaa], false),
+    (0x5c96, &[0xe1, 0xab], false),
+    (0x5c88, &[0xe1, 0xac], false),
+    (0x5c98, &[0xe1, 0xad], false),
+    (0x5c99, &[0xe1, 0xae], false),
+    (0x5c91, &[0xe1, 0xaf], false),
+    (0x5c9a, &[0xe1, 0xb0], false),
+    (0x5c9c, &[0xe1, 0xb1], false),
+    (0x5cb5, &[0xe1, 0xb2], false),
+    (0x5ca2, &[0xe1, 0xb3], false),
+    (0x5cbd, &[0xe1, 0xb4], false),
+    (0x5cac, &[0xe1, 0xb5], false),
+    (0x5cab, &[0xe1, 0xb6], false),
+    (0x5cb1, &[0xe1, 0xb7], false),
+    (0x5ca3, &[0xe1, 0xb8], false),
+    (0x5cc1, &[0xe1, 0xb9], false),
+    (0x5cb7, &[0xe1, 0xba], false),
+    (0x5cc4, &[0xe1, 0xbb], false),
+    (0x5cd2, &[0xe1, 0xbc], false),
+    (0x5ce4, &[0xe1, 0xbd], false),
+    (0x5ccb, &[0xe1, 0xbe], false),
+    (0x5ce5, &[0xe1, 0xbf], false),
+    (0x5d02, &[0xe1, 0xc0], false),
+    (0x5d03, &[0xe1, 0xc1], false),
+    (0x5d27, &[0xe1, 0xc2], false),
+    (0x5d26, &[0xe1, 0xc3], false),
+    (0x5d2e, &[0xe1, 0xc4], false),
+    (0x5d24, &[0xe1, 0xc5], false),
+    (0x5d1e, &[0xe1, 0xc6], false),
+    (0x5d06, &[0xe1, 0xc7], false),
+    (0x5d1b, &[0xe1, 0xc8], false),
+    (0x5d58, &[0xe1, 0xc9], false),
+    (0x5d3e, &[0xe1, 0xca], false),
+    (0x5d34, &[0xe1, 0xcb], false),
+    (0x5d3d, &[0xe1, 0xcc], false),
+    (0x5d6c, &[0xe1, 0xcd], false),
+    (0x5d5b, &[0xe1, 0xce], false),
+    (0x5d6f, &[0xe1, 0xcf], false),
+    (0x5d5d, &[0xe1, 0xd0], false),
+    (0x5d6b, &[0xe1, 0xd1], false),
+    (0x5d4b, &[0xe1, 0xd2], false),
+    (0x5d4a, &[0xe1, 0xd3], false),
+    (0x5d69, &[0xe1, 0xd4], false),
+    (0x5d74, &[0xe1, 0xd5], false),
+    (0x5d82, &[0xe1, 0xd6], false),
+    (0x5d99, &[0xe1, 0xd7], false),
+    (0x5d9d, &[0xe1, 0xd8], false),
+    (0x8c73, &[0xe1, 0xd9], false),
+    (0x5db7, &[0xe1, 0xda], false),
+    (0x5dc5, &[0xe1, 0xdb], false),
+    (0x5f73, &[0xe1, 0xdc], false),
+    (0x5f77, &[0xe1, 0xdd], false),
+    (0x5f82, &[0xe1, 0xde], false),
+    (0x5f87, &[0xe1, 0xdf], false),
+    (0x5f89, &[0xe1, 0xe0], false),
+    (0x5f8c, &[0xe1, 0xe1], false),
+    (0x5f95, &[0xe1, 0xe2], false),
+    (0x5f99, &[0xe1, 0xe3], false),
+    (0x5f9c, &[0xe1, 0xe4], false),
+    (0x5fa8, &[0xe1, 0xe5], false),
+    (0x5fad, &[0xe1, 0xe6], false),
+    (0x5fb5, &[0xe1, 0xe7], false),
+    (0x5fbc, &[0xe1, 0xe8], false),
+    (0x8862, &[0xe1, 0xe9], false),
+    (0x5f61, &[0xe1, 0xea], false),
+    (0x72ad, &[0xe1, 0xeb], false),
+    (0x72b0, &[0xe1, 0xec], false),
+    (0x72b4, &[0xe1, 0xed], false),
+    (0x72b7, &[0xe1, 0xee], false),
+    (0x72b8, &[0xe1, 0xef], false),
+    (0x72c3, &[0xe1, 0xf0], false),
+    (0x72c1, &[0xe1, 0xf1], false),
+    (0x72ce, &[0xe1, 0xf2], false),
+    (0x72cd, &[0xe1, 0xf3], false),
+    (0x72d2, &[0xe1, 0xf4], false),
+    (0x72e8, &[0xe1, 0xf5], false),
+    (0x72ef, &[0xe1, 0xf6], false),
+    (0x72e9, &[0xe1, 0xf7], false),
+    (0x72f2, &[0xe1, 0xf8], false),
+    (0x72f4, &[0xe1, 0xf9], false),
+    (0x72f7, &[0xe1, 0xfa], false),
+    (0x7301, &[0xe1, 0xfb], false),
+    (0x72f3, &[0xe1, 0xfc], false),
+    (0x7303, &[0xe1, 0xfd], false),
+    (0x72fa, &[0xe1, 0xfe], false),
+    (0x72fb, &[0xe2, 0xa1], false),
+    (0x7317, &[0xe2, 0xa2], false),
+    (0x7313, &[0xe2, 0xa3], false),
+    (0x7321, &[0xe2, 0xa4], false),
+    (0x730a, &[0xe2, 0xa5], false),
+    (0x731e, &[0xe2, 0xa6], false),
+    (0x731d, &[0xe2, 0xa7], false),
+    (0x7315, &[0xe2, 0xa8], false),
+    (0x7322, &[0xe2, 0xa9], false),
+    (0x7339, &[0xe2, 0xaa], false),
+    (0x7325, &[0xe2, 0xab], false),
+    (0x732c, &[0xe2, 0xac], false),
+    (0x7338, &[0xe2, 0xad], false),
+    (0x7331, &[0xe2, 0xae], false),
+    (0x7350, &[0xe2, 0xaf], false),
+    (0x734d, &[0xe2, 0xb0], false),
+    (0x7357, &[0xe2, 0xb1], false),
+    (0x7360, &[0xe2, 0xb2], false),
+    (0x736c, &[0xe2, 0xb3], false),
+    (0x736f, &[0xe2, 0xb4], false),
+    (0x737e, &[0xe2, 0xb5], false),
+    (0x821b, &[0xe2, 0xb6], false),
+    (0x5925, &[0xe2, 0xb7], false),
+    (0x98e7, &[0xe2, 0xb8], false),
+    (0x5924, &[0xe2, 0xb9], false),
+    (0x5902, &[0xe2, 0xba], false),
+    (0x9963, &[0xe2, 0xbb], false),
+    (0x9967, &[0xe2, 0xbc], false),
+    (0x9968, &[0xe2, 0xbd], false),
+    (0x9969, &[0xe2, 0xbe], false),
+    (0x996a, &[0xe2, 0xbf], false),
+    (0x996b, &[0xe2, 0xc0], false),
+    (0x996c, &[0xe2, 0xc1], false),
+    (0x9974, &[0xe2, 0xc2], false),
+    (0x9977, &[0xe2, 0xc3], false),
+    (0x997d, &[0xe2, 0xc4], false),
+    (0x9980, &[0xe2, 0xc5], false),
+    (0x9984, &[0xe2, 0xc6], false),
+    (0x9987, &[0xe2, 0xc7], false),
+    (0x998a, &[0xe2, 0xc8], false),
+    (0x998d, &[0xe2, 0xc9], false),
+    (0x9990, &[0xe2, 0xca], false),
+    (0x9991, &[0xe2, 0xcb], false),
+    (0x9993, &[0xe2, 0xcc], false),
+    (0x9994, &[0xe2, 0xcd], false),
+    (0x9995, &[0xe2, 0xce], false),
+    (0x5e80, &[0xe2, 0xcf], false),
+    (0x5e91, &[0xe2, 0xd0], false),
+    (0x5e8b, &[0xe2, 0xd1], false),
+    (0x5e96, &[0xe2, 0xd2], false),
+    (0x5ea5, &[0xe2, 0xd3], false),
+    (0x5ea0, &[0xe2, 0xd4], false),
+    (0x5eb9, &[0xe2, 0xd5], false),
+    (0x5eb5, &[0xe2, 0xd6], false),
+    (0x5ebe, &[0xe2, 0xd7], false),
+    (0x5eb3, &[0xe2, 0xd8], false),
+    (0x8d53, &[0xe2, 0xd9], false),
+    (0x5ed2, &[0xe2, 0xda], false),
+    (0x5ed1, &[0xe2, 0xdb], false),
+    (0x5edb, &[0xe2, 0xdc], false),
+    (0x5ee8, &[0xe2, 0xdd], false),
+    (0x5eea, &[0xe2, 0xde], false),
+    (0x81ba, &[0xe2, 0xdf], false),
+    (0x5fc4, &[0xe2, 0xe0], false),
+    (0x5fc9, &[0xe2, 0xe1], false),
+    (0x5fd6, &[0xe2, 0xe2], false),
+    (0x5fcf, &[0xe2, 0xe3], false),
+    (0x6003, &[0xe2, 0xe4], false),
+    (0x5fee, &[0xe2, 0xe5], false),
+    (0x6004, &[0xe2, 0xe6], false),
+    (0x5fe1, &[0xe2, 0xe7], false),
+    (0x5fe4, &[0xe2, 0xe8], false),
+    (0x5ffe, &[0xe2, 0xe9], false),
+    (0x6005, &[0xe2, 0xea], false),
+    (0x6006, &[0xe2, 0xeb], false),
+    (0x5fea, &[0xe2, 0xec], false),
+    (0x5fed, &[0xe2, 0xed], false),
+    (0x5ff8, &[0xe2, 0xee], false),
+    (0x6019, &[0xe2, 0xef], false),
+    (0x6035, &[0xe2, 0xf0], false),
+    (0x6026, &[0xe2, 0xf1], false),
+    (0x601b, &[0xe2, 0xf2], false),
+    (0x600f, &[0xe2, 0xf3], false),
+    (0x600d, &[0xe2, 0xf4], false),
+    (0x6029, &[0xe2, 0xf5], false),
+    (0x602b, &[0xe2, 0xf6], false),
+    (0x600a, &[0xe2, 0xf7], false),
+    (0x603f, &[0xe2, 0xf8], false),
+    (0x6021, &[0xe2, 0xf9], false),
+    (0x6078, &[0xe2, 0xfa], false),
+    (0x6079, &[0xe2, 0xfb], false),
+    (0x607b, &[0xe2, 0xfc], false),
+    (0x607a, &[0xe2, 0xfd], false),
+    (0x6042, &[0xe2, 0xfe], false),
+    (0x606a, &[0xe3, 0xa1], false),
+    (0x607d, &[0xe3, 0xa2], false),
+    (0x6096, &[0xe3, 0xa3], false),
+    (0x609a, &[0xe3, 0xa4], false),
+    (0x60ad, &[0xe3, 0xa5], false),
+    (0x609d, &[0xe3, 0xa6], false),
+    (0x6083, &[0xe3, 0xa7], false),
+    (0x6092, &[0xe3, 0xa8], false),
+    (0x608c, &[0xe3, 0xa9], false),
+    (0x609b, &[0xe3, 0xaa], false),
+    (0x60ec, &[0xe3, 0xab], false),
+    (0x60bb, &[0xe3, 0xac], false),
+    (0x60b1, &[0xe3, 0xad], false),
+    (0x60dd, &[0xe3, 0xae], false),
+    (0x60d8, &[0xe3, 0xaf], false),
+    (0x60c6, &[0xe3, 0xb0], false),
+    (0x60da, &[0xe3, 0xb1], false),
+    (0x60b4, &[0xe3, 0xb2], false),
+    (0x6120, &[0xe3, 0xb3], false),
+    (0x6126, &[0xe3, 0xb4], false),
+    (0x6115, &[0xe3, 0xb5], false),
+    (0x6123, &[0xe3, 0xb6], false),
+    (0x60f4, &[0xe3, 0xb7], false),
+    (0x6100, &[0xe3, 0xb8], false),
+    (0x610e, &[0xe3, 0xb9], false),
+    (0x612b, &[0xe3, 0xba], false),
+    (0x614a, &[0xe3, 0xbb], false),
+    (0x6175, &[0xe3, 0xbc], false),
+    (0x61ac, &[0xe3, 0xbd], false),
+    (0x6194, &[0xe3, 0xbe], false),
+    (0x61a7, &[0xe3, 0xbf], false),
+    (0x61b7, &[0xe3, 0xc0], false),
+    (0x61d4, &[0xe3, 0xc1], false),
+    (0x61f5, &[0xe3, 0xc2], false),
+    (0x5fdd, &[0xe3, 0xc3], false),
+    (0x96b3, &[0xe3, 0xc4], false),
+    (0x95e9, &[0xe3, 0xc5], false),
+    (0x95eb, &[0xe3, 0xc6], false),
+    (0x95f1, &[0xe3, 0xc7], false),
+    (0x95f3, &[0xe3, 0xc8], false),
+    (0x95f5, &[0xe3, 0xc9], false),
+    (0x95f6, &[0xe3, 0xca], false),
+    (0x95fc, &[0xe3, 0xcb], false),
+    (0x95fe, &[0xe3, 0xcc], false),
+    (0x9603, &[0xe3, 0xcd], false),
+    (0x9604, &[0xe3, 0xce], false),
+    (0x9606, &[0xe3, 0xcf], false),
+    (0x9608, &[0xe3, 0xd0], false),
+    (0x960a, &[0xe3, 0xd1], false),
+    (0x960b, &[0xe3, 0xd2], false),
+    (0x960c, &[0xe3, 0xd3], false),
+    (0x960d, &[0xe3, 0xd4], false),
+    (0x960f, &[0xe3, 0xd5], false),
+    (0x9612, &[0xe3, 0xd6], false),
+    (0x9615, &[0xe3, 0xd7], false),
+    (0x9616, &[0xe3, 0xd8], false),
+    (0x9617, &[0xe3, 0xd9], false),
+    (0x9619, &[0xe3, 0xda], false),
+    (0x961a, &[0xe3, 0xdb], false),
+    (0x4e2c, &[0xe3, 0xdc], false),
+    (0x723f, &[0xe3, 0xdd], false),
+    (0x6215, &[0xe3, 0xde], false),
+    (0x6c35, &[0xe3, 0xdf], false),
+    (0x6c54, &[0xe3, 0xe0], false),
+    (0x6c5c, &[0xe3, 0xe1], false),
+    (0x6c4a, &[0xe3, 0xe2], false),
+    (0x6ca3, &[0xe3, 0xe3], false),
+    (0x6c85, &[0xe3, 0xe4], false),
+    (0x6c90, &[0xe3, 0xe5], false),
+    (0x6c94, &[0xe3, 0xe6], false),
+    (0x6c8c, &[0xe3, 0xe7], false),
+    (0x6c68, &[0xe3, 0xe8], false),
+    (0x6c69, &[0xe3, 0xe9], false),
+    (0x6c74, &[0xe3, 0xea], false),
+    (0x6c76, &[0xe3, 0xeb], false),
+    (0x6c86, &[0xe3, 0xec], false),
+    (0x6ca9, &[0xe3, 0xed], false),
+    (0x6cd0, &[0xe3, 0xee], false),
+    (0x6cd4, &[0xe3, 0xef], false),
+    (0x6cad, &[0xe3, 0xf0], false),
+    (0x6cf7, &[0xe3, 0xf1], false),
+    (0x6cf8, &[0xe3, 0xf2], false),
+    (0x6cf1, &[0xe3, 0xf3], false),
+    (0x6cd7, &[0xe3, 0xf4], false),
+    (0x6cb2, &[0xe3, 0xf5], false),
+    (0x6ce0, &[0xe3, 0xf6], false),
+    (0x6cd6, &[0xe3, 0xf7], false),
+    (0x6cfa, &[0xe3, 0xf8], false),
+    (0x6ceb, &[0xe3, 0xf9], false),
+    (0x6cee, &[0xe3, 0xfa], false),
+    (0x6cb1, &[0xe3, 0xfb], false),
+    (0x6cd3, &[0xe3, 0xfc], false),
+    (0x6cef, &[0xe3, 0xfd], false),
+    (0x6cfe, &[0xe3, 0xfe], false),
+    (0x6d39, &[0xe4, 0xa1], false),
+    (0x6d27, &[0xe4, 0xa2], false),
+    (0x6d0c, &[0xe4, 0xa3], false),
+    (0x6d43, &[0xe4, 0xa4], false),
+    (0x6d48, &[0xe4, 0xa5], false),
+    (0x6d07, &[0xe4, 0xa6], false),
+    (0x6d04, &[0xe4, 0xa7], false),
+    (0x6d19, &[0xe4, 0xa8], false),
+    (0x6d0e, &[0xe4, 0xa9], false),
+    (0x6d2b, &[0xe4, 0xaa], false),
+    (0x6d4d, &[0xe4, 0xab], false),
+    (0x6d2e, &[0xe4, 0xac], false),
+    (0x6d35, &[0xe4, 0xad], false),
+    (0x6d1a, &[0xe4, 0xae], false),
+    (0x6d4f, &[0xe4, 0xaf], false),
+    (0x6d52, &[0xe4, 0xb0], false),
+    (0x6d54, &[0xe4, 0xb1], false),
+    (0x6d33, &[0xe4, 0xb2], false),
+    (0x6d91, &[0xe4, 0xb3], false),
+    (0x6d6f, &[0xe4, 0xb4], false),
+    (0x6d9e, &[0xe4, 0xb5], false),
+    (0x6da0, &[0xe4, 0xb6], false),
+    (0x6d5e, &[0xe4, 0xb7], false),
+    (0x6d93, &[0xe4, 0xb8], false),
+    (0x6d94, &[0xe4, 0xb9], false),
+    (0x6d5c, &[0xe4, 0xba], false),
+    (0x6d60, &[0xe4, 0xbb], false),
+    (0x6d7c, &[0xe4, 0xbc], false),
+    (0x6d63, &[0xe4, 0xbd], false),
+    (0x6e1a, &[0xe4, 0xbe], false),
+    (0x6dc7, &[0xe4, 0xbf], false),
+    (0x6dc5, &[0xe4, 0xc0], false),
+    (0x6dde, &[0xe4, 0xc1], false),
+    (0x6e0e, &[0xe4, 0xc2], false),
+    (0x6dbf, &[0xe4, 0xc3], false),
+    (0x6de0, &[0xe4, 0xc4], false),
+    (0x6e11, &[0xe4, 0xc5], false),
+    (0x6de6, &[0xe4, 0xc6], false),
+    (0x6ddd, &[0xe4, 0xc7], false),
+    (0x6dd9, &[0xe4, 0xc8], false),
+    (0x6e16, &[0xe4, 0xc9], false),
+    (0x6dab, &[0xe4, 0xca], false),
+    (0x6e0c, &[0xe4, 0xcb], false),
+    (0x6dae, &[0xe4, 0xcc], false),
+    (0x6e2b, &[0xe4, 0xcd], false),
+    (0x6e6e, &[0xe4, 0xce], false),
+    (0x6e4e, &[0xe4, 0xcf], false),
+    (0x6e6b, &[0xe4, 0xd0], false),
+    (0x6eb2, &[0xe4, 0xd1], false),
+    (0x6e5f, &[0xe4, 0xd2], false),
+    (0x6e86, &[0xe4, 0xd3], false),
+    (0x6e53, &[0xe4, 0xd4], false),
+    (0x6e54, &[0xe4, 0xd5], false),
+    (0x6e32, &[0xe4, 0xd6], false),
+    (0x6e25, &[0xe4, 0xd7], false),
+    (0x6e44, &[0xe4, 0xd8], false),
+    (0x6edf, &[0xe4, 0xd9], false),
+    (0x6eb1, &[0xe4, 0xda], false),
+    (0x6e98, &[0xe4, 0xdb], false),
+    (0x6ee0, &[0xe4, 0xdc], false),
+    (0x6f2d, &[0xe4, 0xdd], false),
+    (0x6ee2, &[0xe4, 0xde], false),
+    (0x6ea5, &[0xe4, 0xdf], false),
+    (0x6ea7, &[0xe4, 0xe0], false),
+    (0x6ebd, &[0xe4, 0xe1], false),
+    (0x6ebb, &[0xe4, 0xe2], false),
+    (0x6eb7, &[0xe4, 0xe3], false),
+    (0x6ed7, &[0xe4, 0xe4], false),
+    (0x6eb4, &[0xe4, 0xe5], false),
+    (0x6ecf, &[0xe4, 0xe6], false),
+    (0x6e8f, &[0xe4, 0xe7], false),
+    (0x6ec2, &[0xe4, 0xe8], false),
+    (0x6e9f, &[0xe4, 0xe9], false),
+    (0x6f62, &[0xe4, 0xea], false),
+    (0x6f46, &[0xe4, 0xeb], false),
+    (0x6f47, &[0xe4, 0xec], false),
+    (0x6f24, &[0xe4, 0xed], false),
+    (0x6f15, &[0xe4, 0xee], false),
+    (0x6ef9, &[0xe4, 0xef], false),
+    (0x6f2f, &[0xe4, 0xf0], false),
+    (0x6f36, &[0xe4, 0xf1], false),
+    (0x6f4b, &[0xe4, 0xf2], false),
+    (0x6f74, &[0xe4, 0xf3], false),
+    (0x6f2a, &[0xe4, 0xf4], false),
+    (0x6f09, &[0xe4, 0xf5], false),
+    (0x6f29, &[0xe4, 0xf6], false),
+    (0x6f89, &[0xe4, 0xf7], false),
+    (0x6f8d, &[0xe4, 0xf8], false),
+    (0x6f8c, &[0xe4, 0xf9], false),
+    (0x6f78, &[0xe4, 0xfa], false),
+    (0x6f72, &[0xe4, 0xfb], false),
+    (0x6f7c, &[0xe4, 0xfc], false),
+    (0x6f7a, &[0xe4, 0xfd], false),
+    (0x6fd1, &[0xe4, 0xfe], false),
+    (0x6fc9, &[0xe5, 0xa1], false),
+    (0x6fa7, &[0xe5, 0xa2], false),
+    (0x6fb9, &[0xe5, 0xa3], false),
+    (0x6fb6, &[0xe5, 0xa4], false),
+    (0x6fc2, &[0xe5, 0xa5], false),
+    (0x6fe1, &[0xe5, 0xa6], false),
+    (0x6fee, &[0xe5, 0xa7], false),
+    (0x6fde, &[0xe5, 0xa8], false),
+    (0x6fe0, &[0xe5, 0xa9], false),
+    (0x6fef, &[0xe5, 0xaa], false),
+    (0x701a, &[0xe5, 0xab], false),
+    (0x7023, &[0xe5, 0xac], false),
+    (0x701b, &[0xe5, 0xad], false),
+    (0x7039, &[0xe5, 0xae], false),
+    (0x7035, &[0xe5, 0xaf], false),
+    (0x704f, &[0xe5, 0xb0], false),
+    (0x705e, &[0xe5, 0xb1], false),
+    (0x5b80, &[0xe5, 0xb2], false),
+    (0x5b84, &[0xe5, 0xb3], false),
+    (0x5b95, &[0xe5, 0xb4], false),
+    (0x5b93, &[0xe5, 0xb5], false),
+    (0x5ba5, &[0xe5, 0xb6], false),
+    (0x5bb8, &[0xe5, 0xb7], false),
+    (0x752f, &[0xe5, 0xb8], false),
+    (0x9a9e, &[0xe5, 0xb9], false),
+    (0x6434, &[0xe5, 0xba], false),
+    (0x5be4, &[0xe5, 0xbb], false),
+    (0x5bee, &[0xe5, 0xbc], false),
+    (0x8930, &[0xe5, 0xbd], false),
+    (0x5bf0, &[0xe5, 0xbe], false),
+    (0x8e47, &[0xe5, 0xbf], false),
+    (0x8b07, &[0xe5, 0xc0], false),
+    (0x8fb6, &[0xe5, 0xc1], false),
+    (0x8fd3, &[0xe5, 0xc2], false),
+    (0x8fd5, &[0xe5, 0xc3], false),
+    (0x8fe5, &[0xe5, 0xc4], false),
+    (0x8fee, &[0xe5, 0xc5], false),
+    (0x8fe4, &[0xe5, 0xc6], false),
+    (0x8fe9, &[0xe5, 0xc7], false),
+    (0x8fe6, &[0xe5, 0xc8], false),
+    (0x8ff3, &[0xe5, 0xc9], false),
+    (0x8fe8, &[0xe5, 0xca], false),
+    (0x9005, &[0xe5, 0xcb], false),
+    (0x9004, &[0xe5, 0xcc], false),
+    (0x900b, &[0xe5, 0xcd], false),
+    (0x9026, &[0xe5, 0xce], false),
+    (0x9011, &[0xe5, 0xcf], false),
+    (0x900d, &[0xe5, 0xd0], false),
+    (0x9016, &[0xe5, 0xd1], false),
+    (0x9021, &[0xe5, 0xd2], false),
+    (0x9035, &[0xe5, 0xd3], false),
+    (0x9036, &[0xe5, 0xd4], false),
+    (0x902d, &[0xe5, 0xd5], false),
+    (0x902f, &[0xe5, 0xd6], false),
+    (0x9044, &[0xe5, 0xd7], false),
+    (0x9051, &[0xe5, 0xd8], false),
+    (0x9052, &[0xe5, 0xd9], false),
+    (0x9050, &[0xe5, 0xda], false),
+    (0x9068, &[0xe5, 0xdb], false),
+    (0x9058, &[0xe5, 0xdc], false),
+    (0x9062, &[0xe5, 0xdd], false),
+    (0x905b, &[0xe5, 0xde], false),
+    (0x66b9, &[0xe5, 0xdf], false),
+    (0x9074, &[0xe5, 0xe0], false),
+    (0x907d, &[0xe5, 0xe1], false),
+    (0x9082, &[0xe5, 0xe2], false),
+    (0x9088, &[0xe5, 0xe3], false),
+    (0x9083, &[0xe5, 0xe4], false),
+    (0x908b, &[0xe5, 0xe5], false),
+    (0x5f50, &[0xe5, 0xe6], false),
+    (0x5f57, &[0xe5, 0xe7], false),
+    (0x5f56, &[0xe5, 0xe8], false),
+    (0x5f58, &[0xe5, 0xe9], false),
+    (0x5c3b, &[0xe5, 0xea], false),
+    (0x54ab, &[0xe5, 0xeb], false),
+    (0x5c50, &[0xe5, 0xec], false),
+    (0x5c59, &[0xe5, 0xed], false),
+    (0x5b71, &[0xe5, 0xee], false),
+    (0x5c63, &[0xe5, 0xef], false),
+    (0x5c66, &[0xe5, 0xf0], false),
+    (0x7fbc, &[0xe5, 0xf1], false),
+    (0x5f2a, &[0xe5, 0xf2], false),
+    (0x5f29, &[0xe5, 0xf3], false),
+    (0x5f2d, &[0xe5, 0xf4], false),
+    (0x8274, &[0xe5, 0xf5], false),
+    (0x5f3c, &[0xe5, 0xf6], false),
+    (0x9b3b, &[0xe5, 0xf7], false),
+    (0x5c6e, &[0xe5, 0xf8], false),
+    (0x5981, &[0xe5, 0xf9], false),
+    (0x5983, &[0xe5, 0xfa], false),
+    (0x598d, &[0xe5, 0xfb], false),
+    (0x59a9, &[0xe5, 0xfc], false),
+    (0x59aa, &[0xe5, 0xfd], false),
+    (0x59a3, &[0xe5, 0xfe], false),
+    (0x5997, &[0xe6, 0xa1], false),
+    (0x59ca, &[0xe6, 0xa2], false),
+    (0x59ab, &[0xe6, 0xa3], false),
+    (0x599e, &[0xe6, 0xa4], false),
+    (0x59a4, &[0xe6, 0xa5], false),
+    (0x59d2, &[0xe6, 0xa6], false),
+    (0x59b2, &[0xe6, 0xa7], false),
+    (0x59af, &[0xe6, 0xa8], false),
+    (0x59d7, &[0xe6, 0xa9], false),
+    (0x59be, &[0xe6, 0xaa], false),
+    (0x5a05, &[0xe6, 0xab], false),
+    (0x5a06, &[0xe6, 0xac], false),
+    (0x59dd, &[0xe6, 0xad], false),
+    (0x5a08, &[0xe6, 0xae], false),
+    (0x59e3, &[0xe6, 0xaf], false),
+    (0x59d8, &[0xe6, 0xb0], false),
+    (0x59f9, &[0xe6, 0xb1], false),
+    (0x5a0c, &[0xe6, 0xb2], false),
+    (0x5a09, &[0xe6, 0xb3], false),
+    (0x5a32, &[0xe6, 0xb4], false),
+    (0x5a34, &[0xe6, 0xb5], false),
+    (0x5a11, &[0xe6, 0xb6], false),
+    (0x5a23, &[0xe6, 0xb7], false),
+    (0x5a13, &[0xe6, 0xb8], false),
+    (0x5a40, &[0xe6, 0xb9], false),
+    (0x5a67, &[0xe6, 0xba], false),
+    (0x5a4a, &[0xe6, 0xbb], false),
+    (0x5a55, &[0xe6, 0xbc], false),
+    (0x5a3c, &[0xe6, 0xbd], false),
+    (0x5a62, &[0xe6, 0xbe], false),
+    (0x5a75, &[0xe6, 0xbf], false),
+    (0x80ec, &[0xe6, 0xc0], false),
+    (0x5aaa, &[0xe6, 0xc1], false),
+    (0x5a9b, &[0xe6, 0xc2], false),
+    (0x5a77, &[0xe6, 0xc3], false),
+    (0x5a7a, &[0xe6, 0xc4], false),
+    (0x5abe, &[0xe6, 0xc5], false),
+    (0x5aeb, &[0xe6, 0xc6], false),
+    (0x5ab2, &[0xe6, 0xc7], false),
+    (0x5ad2, &[0xe6, 0xc8], false),
+    (0x5ad4, &[0xe6, 0xc9], false),
+    (0x5ab8, &[0xe6, 0xca], false),
+    (0x5ae0, &[0xe6, 0xcb], false),
+    (0x5ae3, &[0xe6, 0xcc], false),
+    (0x5af1, &[0xe6, 0xcd], false),
+    (0x5ad6, &[0xe6, 0xce], false),
+    (0x5ae6, &[0xe6, 0xcf], false),
+    (0x5ad8, &[0xe6, 0xd0], false),
+    (0x5adc, &[0xe6, 0xd1], false),
+    (0x5b09, &[0xe6, 0xd2], false),
+    (0x5b17, &[0xe6, 0xd3], false),
+    (0x5b16, &[0xe6, 0xd4], false),
+    (0x5b32, &[0xe6, 0xd5], false),
+    (0x5b37, &[0xe6, 0xd6], false),
+    (0x5b40, &[0xe6, 0xd7], false),
+    (0x5c15, &[0xe6, 0xd8], false),
+    (0x5c1c, &[0xe6, 0xd9], false),
+    (0x5b5a, &[0xe6, 0xda], false),
+    (0x5b65, &[0xe6, 0xdb], false),
+    (0x5b73, &[0xe6, 0xdc], false),
+    (0x5b51, &[0xe6, 0xdd], false),
+    (0x5b53, &[0xe6, 0xde], false),
+    (0x5b62, &[0xe6, 0xdf], false),
+    (0x9a75, &[0xe6, 0xe0], false),
+    (0x9a77, &[0xe6, 0xe1], false),
+    (0x9a78, &[0xe6, 0xe2], false),
+    (0x9a7a, &[0xe6, 0xe3], false),
+    (0x9a7f, &[0xe6, 0xe4], false),
+    (0x9a7d, &[0xe6, 0xe5], false),
+    (0x9a80, &[0xe6, 0xe6], false),
+    (0x9a81, &[0xe6, 0xe7], false),
+    (0x9a85, &[0xe6, 0xe8], false),
+    (0x9a88, &[0xe6, 0xe9], false),
+    (0x9a8a, &[0xe6, 0xea], false),
+    (0x9a90, &[0xe6, 0xeb], false),
+    (0x9a92, &[0xe6, 0xec], false),
+    (0x9a93, &[0xe6, 0xed], false),
+    (0x9a96, &[0xe6, 0xee], false),
+    (0x9a98, &[0xe6, 0xef], false),
+    (0x9a9b, &[0xe6, 0xf0], false),
+    (0x9a9c, &[0xe6, 0xf1], false),
+    (0x9a9d, &[0xe6, 0xf2], false),
+    (0x9a9f, &[0xe6, 0xf3], false),
+    (0x9aa0, &[0xe6, 0xf4], false),
+    (0x9aa2, &[0xe6, 0xf5], false),
+    (0x9aa3, &[0xe6, 0xf6], false),
+    (0x9aa5, &[0xe6, 0xf7], false),
+    (0x9aa7, &[0xe6, 0xf8], false),
+    (0x7e9f, &[0xe6, 0xf9], false),
+    (0x7ea1, &[0xe6, 0xfa], false),
+    (0x7ea3, &[0xe6, 0xfb], false),
+    (0x7ea5, &[0xe6, 0xfc], false),
+    (0x7ea8, &[0xe6, 0xfd], false),
+    (0x7ea9, &[0xe6, 0xfe], false),
+    (0x7ead, &[0xe7, 0xa1], false),
+    (0x7eb0, &[0xe7, 0xa2], false),
+    (0x7ebe, &[0xe7, 0xa3], false),
+    (0x7ec0, &[0xe7, 0xa4], false),
+    (0x7ec1, &[0xe7, 0xa5], false),
+    (0x7ec2, &[0xe7, 0xa6], false),
+    (0x7ec9, &[0xe7, 0xa7], false),
+    (0x7ecb, &[0xe7, 0xa8], false),
+    (0x7ecc, &[0xe7, 0xa9], false),
+    (0x7ed0, &[0xe7, 0xaa], false),
+    (0x7ed4, &[0xe7, 0xab], false),
+    (0x7ed7, &[0xe7, 0xac], false),
+    (0x7edb, &[0xe7, 0xad], false),
+    (0x7ee0, &[0xe7, 0xae], false),
+    (0x7ee1, &[0xe7, 0xaf], false),
+    (0x7ee8, &[0xe7, 0xb0], false),
+    (0x7eeb, &[0xe7, 0xb1], false),
+    (0x7eee, &[0xe7, 0xb2], false),
+    (0x7eef, &[0xe7, 0xb3], false),
+    (0x7ef1, &[0xe7, 0xb4], false),
+    (0x7ef2, &[0xe7, 0xb5], false),
+    (0x7f0d, &[0xe7, 0xb6], false),
+    (0x7ef6, &[0xe7, 0xb7], false),
+    (0x7efa, &[0xe7, 0xb8], false),
+    (0x7efb, &[0xe7, 0xb9], false),
+    (0x7efe, &[0xe7, 0xba], false),
+    (0x7f01, &[0xe7, 0xbb], false),
+    (0x7f02, &[0xe7, 0xbc], false),
+    (0x7f03, &[0xe7, 0xbd], false),
+    (0x7f07, &[0xe7, 0xbe], false),
+    (0x7f08, &[0xe7, 0xbf], false),
+    (0x7f0b, &[0xe7, 0xc0], false),
+    (0x7f0c, &[0xe7, 0xc1], false),
+    (0x7f0f, &[0xe7, 0xc2], false),
+    (0x7f11, &[0xe7, 0xc3], false),
+    (0x7f12, &[0xe7, 0xc4], false),
+    (0x7f17, &[0xe7, 0xc5], false),
+    (0x7f19, &[0xe7, 0xc6], false),
+    (0x7f1c, &[0xe7, 0xc7], false),
+    (0x7f1b, &[0xe7, 0xc8], false),
+    (0x7f1f, &[0xe7, 0xc9], false),
+    (0x7f21, &[0xe7, 0xca], false),
+    (0x7f22, &[0xe7, 0xcb], false),
+    (0x7f23, &[0xe7, 0xcc], false),
+    (0x7f24, &[0xe7, 0xcd], false),
+    (0x7f25, &[0xe7, 0xce], false),
+    (0x7f26, &[0xe7, 0xcf], false),
+    (0x7f27, &[0xe7, 0xd0], false),
+    (0x7f2a, &[0xe7, 0xd1], false),
+    (0x7f2b, &[0xe7, 0xd2], false),
+    (0x7f2c, &[0xe7, 0xd3], false),
+    (0x7f2d, &[0xe7, 0xd4], false),
+    (0x7f2f, &[0xe7, 0xd5], false),
+    (0x7f30, &[0xe7, 0xd6], false),
+    (0x7f31, &[0xe7, 0xd7], false),
+    (0x7f32, &[0xe7, 0xd8], false),
+    (0x7f33, &[0xe7, 0xd9], false),
+    (0x7f35, &[0xe7, 0xda], false),
+    (0x5e7a, &[0xe7, 0xdb], false),
+    (0x757f, &[0xe7, 0xdc], false),
+    (0x5ddb, &[0xe7, 0xdd], false),
+    (0x753e, &[0xe7, 0xde], false),
+    (0x9095, &[0xe7, 0xdf], false),
+    (0x738e, &[0xe7, 0xe0], false),
+    (0x7391, &[0xe7, 0xe1], false),
+    (0x73ae, &[0xe7, 0xe2], false),
+    (0x73a2, &[0xe7, 0xe3], false),
+    (0x739f, &[0xe7, 0xe4], false),
+    (0x73cf, &[0xe7, 0xe5], false),
+    (0x73c2, &[0xe7, 0xe6], false),
+    (0x73d1, &[0xe7, 0xe7], false),
+    (0x73b7, &[0xe7, 0xe8], false),
+    (0x73b3, &[0xe7, 0xe9], false),
+    (0x73c0, &[0xe7, 0xea], false),
+    (0x73c9, &[0xe7, 0xeb], false),
+    (0x73c8, &[0xe7, 0xec], false),
+    (0x73e5, &[0xe7, 0xed], false),
+    (0x73d9, &[0xe7, 0xee], false),
+    (0x987c, &[0xe7, 0xef], false),
+    (0x740a, &[0xe7, 0xf0], false),
+    (0x73e9, &[0xe7, 0xf1], false),
+    (0x73e7, &[0xe7, 0xf2], false),
+    (0x73de, &[0xe7, 0xf3], false),
+    (0x73ba, &[0xe7, 0xf4], false),
+    (0x73f2, &[0xe7, 0xf5], false),
+    (0x740f, &[0xe7, 0xf6], false),
+    (0x742a, &[0xe7, 0xf7], false),
+    (0x745b, &[0xe7, 0xf8], false),
+    (0x7426, &[0xe7, 0xf9], false),
+    (0x7425, &[0xe7, 0xfa], false),
+    (0x7428, &[0xe7, 0xfb], false),
+    (0x7430, &[0xe7, 0xfc], false),
+    (0x742e, &[0xe7, 0xfd], false),
+    (0x742c, &[0xe7, 0xfe], false),
+    (0x741b, &[0xe8, 0xa1], false),
+    (0x741a, &[0xe8, 0xa2], false),
+    (0x7441, &[0xe8, 0xa3], false),
+    (0x745c, &[0xe8, 0xa4], false),
+    (0x7457, &[0xe8, 0xa5], false),
+    (0x7455, &[0xe8, 0xa6], false),
+    (0x7459, &[0xe8, 0xa7], false),
+    (0x7477, &[0xe8, 0xa8], false),
+    (0x746d, &[0xe8, 0xa9], false),
+    (0x747e, &[0xe8, 0xaa], false),
+    (0x749c, &[0xe8, 0xab], false),
+    (0x748e, &[0xe8, 0xac], false),
+    (0x7480, &[0xe8, 0xad], false),
+    (0x7481, &[0xe8, 0xae], false),
+    (0x7487, &[0xe8, 0xaf], false),
+    (0x748b, &[0xe8, 0xb0], false),
+    (0x749e, &[0xe8, 0xb1], false),
+    (0x74a8, &[0xe8, 0xb2], false),
+    (0x74a9, &[0xe8, 0xb3], false),
+    (0x7490, &[0xe8, 0xb4], false),
+    (0x74a7, &[0xe8, 0xb5], false),
+    (0x74d2, &[0xe8, 0xb6], false),
+    (0x74ba, &[0xe8, 0xb7], false),
+    (0x97ea, &[0xe8, 0xb8], false),
+    (0x97eb, &[0xe8, 0xb9], false),
+    (0x97ec, &[0xe8, 0xba], false),
+    (0x674c, &[0xe8, 0xbb], false),
+    (0x6753, &[0xe8, 0xbc], false),
+    (0x675e, &[0xe8, 0xbd], false),
+    (0x6748, &[0xe8, 0xbe], false),
+    (0x6769, &[0xe8, 0xbf], false),
+    (0x67a5, &[0xe8, 0xc0], false),
+    (0x6787, &[0xe8, 0xc1], false),
+    (0x676a, &[0xe8, 0xc2], false),
+    (0x6773, &[0xe8, 0xc3], false),
+    (0x6798, &[0xe8, 0xc4], false),
+    (0x67a7, &[0xe8, 0xc5], false),
+    (0x6775, &[0xe8, 0xc6], false),
+    (0x67a8, &[0xe8, 0xc7], false),
+    (0x679e, &[0xe8, 0xc8], false),
+    (0x67ad, &[0xe8, 0xc9], false),
+    (0x678b, &[0xe8, 0xca], false),
+    (0x6777, &[0xe8, 0xcb], false),
+    (0x677c, &[0xe8, 0xcc], false),
+    (0x67f0, &[0xe8, 0xcd], false),
+    (0x6809, &[0xe8, 0xce], false),
+    (0x67d8, &[0xe8, 0xcf], false),
+    (0x680a, &[0xe8, 0xd0], false),
+    (0x67e9, &[0xe8, 0xd1], false),
+    (0x67b0, &[0xe8, 0xd2], false),
+    (0x680c, &[0xe8, 0xd3], false),
+    (0x67d9, &[0xe8, 0xd4], false),
+    (0x67b5, &[0xe8, 0xd5], false),
+    (0x67da, &[0xe8, 0xd6], false),
+    (0x67b3, &[0xe8, 0xd7], false),
+    (0x67dd, &[0xe8, 0xd8], false),
+    (0x6800, &[0xe8, 0xd9], false),
+    (0x67c3, &[0xe8, 0xda], false),
+    (0x67b8, &[0xe8, 0xdb], false),
+    (0x67e2, &[0xe8, 0xdc], false),
+    (0x680e, &[0xe8, 0xdd], false),
+    (0x67c1, &[0xe8, 0xde], false),
+    (0x67fd, &[0xe8, 0xdf], false),
+    (0x6832, &[0xe8, 0xe0], false),
+    (0x6833, &[0xe8, 0xe1], false),
+    (0x6860, &[0xe8, 0xe2], false),
+    (0x6861, &[0xe8, 0xe3], false),
+    (0x684e, &[0xe8, 0xe4], false),
+    (0x6862, &[0xe8, 0xe5], false),
+    (0x6844, &[0xe8, 0xe6], false),
+    (0x6864, &[0xe8, 0xe7], false),
+    (0x6883, &[0xe8, 0xe8], false),
+    (0x681d, &[0xe8, 0xe9], false),
+    (0x6855, &[0xe8, 0xea], false),
+    (0x6866, &[0xe8, 0xeb], false),
+    (0x6841, &[0xe8, 0xec], false),
+    (0x6867, &[0xe8, 0xed], false),
+    (0x6840, &[0xe8, 0xee], false),
+    (0x683e, &[0xe8, 0xef], false),
+    (0x684a, &[0xe8, 0xf0], false),
+    (0x6849, &[0xe8, 0xf1], false),
+    (0x6829, &[0xe8, 0xf2], false),
+    (0x68b5, &[0xe8, 0xf3], false),
+    (0x688f, &[0xe8, 0xf4], false),
+    (0x6874, &[0xe8, 0xf5], false),
+    (0x6877, &[0xe8, 0xf6], false),
+    (0x6893, &[0xe8, 0xf7], false),
+    (0x686b, &[0xe8, 0xf8], false),
+    (0x68c2, &[0xe8, 0xf9], false),
+    (0x696e, &[0xe8, 0xfa], false),
+    (0x68fc, &[0xe8, 0xfb], false),
+    (0x691f, &[0xe8, 0xfc], false),
+    (0x6920, &[0xe8, 0xfd], false),
+    (0x68f9, &[0xe8, 0xfe], false),
+    (0x6924, &[0xe9, 0xa1], false),
+    (0x68f0, &[0xe9, 0xa2], false),
+    (0x690b, &[0xe9, 0xa3], false),
+    (0x6901, &[0xe9, 0xa4], false),
+    (0x6957, &[0xe9, 0xa5], false),
+    (0x68e3, &[0xe9, 0xa6], false),
+    (0x6910, &[0xe9, 0xa7], false),
+    (0x6971, &[0xe9, 0xa8], false),
+    (0x6939, &[0xe9, 0xa9], false),
+    (0x6960, &[0xe9, 0xaa], false),
+    (0x6942, &[0xe9, 0xab], false),
+    (0x695d, &[0xe9, 0xac], false),
+    (0x6984, &[0xe9, 0xad], false),
+    (0x696b, &[0xe9, 0xae], false),
+    (0x6980, &[0xe9, 0xaf], false),
+    (0x6998, &[0xe9, 0xb0], false),
+    (0x6978, &[0xe9, 0xb1], false),
+    (0x6934, &[0xe9, 0xb2], false),
+    (0x69cc, &[0xe9, 0xb3], false),
+    (0x6987, &[0xe9, 0xb4], false),
+    (0x6988, &[0xe9, 0xb5], false),
+    (0x69ce, &[0xe9, 0xb6], false),
+    (0x6989, &[0xe9, 0xb7], false),
+    (0x6966, &[0xe9, 0xb8], false),
+    (0x6963, &[0xe9, 0xb9], false),
+    (0x6979, &[0xe9, 0xba], false),
+    (0x699b, &[0xe9, 0xbb], false),
+    (0x69a7, &[0xe9, 0xbc], false),
+    (0x69bb, &[0xe9, 0xbd], false),
+    (0x69ab, &[0xe9, 0xbe], false),
+    (0x69ad, &[0xe9, 0xbf], false),
+    (0x69d4, &[0xe9, 0xc0], false),
+    (0x69b1, &[0xe9, 0xc1], false),
+    (0x69c1, &[0xe9, 0xc2], false),
+    (0x69ca, &[0xe9, 0xc3], false),
+    (0x69df, &[0xe9, 0xc4], false),
+    (0x6995, &[0xe9, 0xc5], false),
+    (0x69e0, &[0xe9, 0xc6], false),
+    (0x698d, &[0xe9, 0xc7], false),
+    (0x69ff, &[0xe9, 0xc8], false),
+    (0x6a2f, &[0xe9, 0xc9], false),
+    (0x69ed, &[0xe9, 0xca], false),
+    (0x6a17, &[0xe9, 0xcb], false),
+    (0x6a18, &[0xe9, 0xcc], false),
+    (0x6a65, &[0xe9, 0xcd], false),
+    (0x69f2, &[0xe9, 0xce], false),
+    (0x6a44, &[0xe9, 0xcf], false),
+    (0x6a3e, &[0xe9, 0xd0], false),
+    (0x6aa0, &[0xe9, 0xd1], false),
+    (0x6a50, &[0xe9, 0xd2], false),
+    (0x6a5b, &[0xe9, 0xd3], false),
+    (0x6a35, &[0xe9, 0xd4], false),
+    (0x6a8e, &[0xe9, 0xd5], false),
+    (0x6a79, &[0xe9, 0xd6], false),
+    (0x6a3d, &[0xe9, 0xd7], false),
+    (0x6a28, &[0xe9, 0xd8], false),
+    (0x6a58, &[0xe9, 0xd9], false),
+    (0x6a7c, &[0xe9, 0xda], false),
+    (0x6a91, &[0xe9, 0xdb], false),
+    (0x6a90, &[0xe9, 0xdc], false),
+    (0x6aa9, &[0xe9, 0xdd], false),
+    (0x6a97, &[0xe9, 0xde], false),
+    (0x6aab, &[0xe9, 0xdf], false),
+    (0x7337, &[0xe9, 0xe0], false),
+    (0x7352, &[0xe9, 0xe1], false),
+    (0x6b81, &[0xe9, 0xe2], false),
+    (0x6b82, &[0xe9, 0xe3], false),
+    (0x6b87, &[0xe9, 0xe4], false),
+    (0x6b84, &[0xe9, 0xe5], false),
+    (0x6b92, &[0xe9, 0xe6], false),
+    (0x6b93, &[0xe9, 0xe7], false),
+    (0x6b8d, &[0xe9, 0xe8], false),
+    (0x6b9a, &[0xe9, 0xe9], false),
+    (0x6b9b, &[0xe9, 0xea], false),
+    (0x6ba1, &[0xe9, 0xeb], false),
+    (0x6baa, &[0xe9, 0xec], false),
+    (0x8f6b, &[0xe9, 0xed], false),
+    (0x8f6d, &[0xe9, 0xee], false),
+    (0x8f71, &[0xe9, 0xef], false),
+    (0x8f72, &[0xe9, 0xf0], false),
+    (0x8f73, &[0xe9, 0xf1], false),
+    (0x8f75, &[0xe9, 0xf2], false),
+    (0x8f76, &[0xe9, 0xf3], false),
+    (0x8f78, &[0xe9, 0xf4], false),
+    (0x8f77, &[0xe9, 0xf5], false),
+    (0x8f79, &[0xe9, 0xf6], false),
+    (0x8f7a, &[0xe9, 0xf7], false),
+    (0x8f7c, &[0xe9, 0xf8], false),
+    (0x8f7e, &[0xe9, 0xf9], false),
+    (0x8f81, &[0xe9, 0xfa], false),
+    (0x8f82, &[0xe9, 0xfb], false),
+    (0x8f84, &[0xe9, 0xfc], false),
+    (0x8f87, &[0xe9, 0xfd], false),
+    (0x8f8b, &[0xe9, 0xfe], false),
+    (0x8f8d, &[0xea, 0xa1], false),
+    (0x8f8e, &[0xea, 0xa2], false),
+    (0x8f8f, &[0xea, 0xa3], false),
+    (0x8f98, &[0xea, 0xa4], false),
+    (0x8f9a, &[0xea, 0xa5], false),
+    (0x8ece, &[0xea, 0xa6], false),
+    (0x620b, &[0xea, 0xa7], false),
+    (0x6217, &[0xea, 0xa8], false),
+    (0x621b, &[0xea, 0xa9], false),
+    (0x621f, &[0xea, 0xaa], false),
+    (0x6222, &[0xea, 0xab], false),
+    (0x6221, &[0xea, 0xac], false),
+    (0x6225, &[0xea, 0xad], false),
+    (0x6224, &[0xea, 0xae], false),
+    (0x622c, &[0xea, 0xaf], false),
+    (0x81e7, &[0xea, 0xb0], false),
+    (0x74ef, &[0xea, 0xb1], false),
+    (0x74f4, &[0xea, 0xb2], false),
+    (0x74ff, &[0xea, 0xb3], false),
+    (0x750f, &[0xea, 0xb4], false),
+    (0x7511, &[0xea, 0xb5], false),
+    (0x7513, &[0xea, 0xb6], false),
+    (0x6534, &[0xea, 0xb7], false),
+    (0x65ee, &[0xea, 0xb8], false),
+    (0x65ef, &[0xea, 0xb9], false),
+    (0x65f0, &[0xea, 0xba], false),
+    (0x660a, &[0xea, 0xbb], false),
+    (0x6619, &[0xea, 0xbc], false),
+    (0x6772, &[0xea, 0xbd], false),
+    (0x6603, &[0xea, 0xbe], false),
+    (0x6615, &[0xea, 0xbf], false),
+    (0x6600, &[0xea, 0xc0], false),
+    (0x7085, &[0xea, 0xc1], false),
+    (0x66f7, &[0xea, 0xc2], false),
+    (0x661d, &[0xea, 0xc3], false),
+    (0x6634, &[0xea, 0xc4], false),
+    (0x6631, &[0xea, 0xc5], false),
+    (0x6636, &[0xea, 0xc6], false),
+    (0x6635, &[0xea, 0xc7], false),
+    (0x8006, &[0xea, 0xc8], false),
+    (0x665f, &[0xea, 0xc9], false),
+    (0x6654, &[0xea, 0xca], false),
+    (0x6641, &[0xea, 0xcb], false),
+    (0x664f, &[0xea, 0xcc], false),
+    (0x6656, &[0xea, 0xcd], false),
+    (0x6661, &[0xea, 0xce], false),
+    (0x6657, &[0xea, 0xcf], false),
+    (0x6677, &[0xea, 0xd0], false),
+    (0x6684, &[0xea, 0xd1], false),
+    (0x668c, &[0xea, 0xd2], false),
+    (0x66a7, &[0xea, 0xd3], false),
+    (0x669d, &[0xea, 0xd4], false),
+    (0x66be, &[0xea, 0xd5], false),
+    (0x66db, &[0xea, 0xd6], false),
+    (0x66dc, &[0xea, 0xd7], false),
+    (0x66e6, &[0xea, 0xd8], false),
+    (0x66e9, &[0xea, 0xd9], false),
+    (0x8d32, &[0xea, 0xda], false),
+    (0x8d33, &[0xea, 0xdb], false),
+    (0x8d36, &[0xea, 0xdc], false),
+    (0x8d3b, &[0xea, 0xdd], false),
+    (0x8d3d, &[0xea, 0xde], false),
+    (0x8d40, &[0xea, 0xdf], false),
+    (0x8d45, &[0xea, 0xe0], false),
+    (0x8d46, &[0xea, 0xe1], false),
+    (0x8d48, &[0xea, 0xe2], false),
+    (0x8d49, &[0xea, 0xe3], false),
+    (0x8d47, &[0xea, 0xe4], false),
+    (0x8d4d, &[0xea, 0xe5], false),
+    (0x8d55, &[0xea, 0xe6], false),
+    (0x8d59, &[0xea, 0xe7], false),
+    (0x89c7, &[0xea, 0xe8], false),
+    (0x89ca, &[0xea, 0xe9], false),
+    (0x89cb, &[0xea, 0xea], false),
+    (0x89cc, &[0xea, 0xeb], false),
+    (0x89ce, &[0xea, 0xec], false),
+    (0x89cf, &[0xea, 0xed], false),
+    (0x89d0, &[0xea, 0xee], false),
+    (0x89d1, &[0xea, 0xef], false),
+    (0x726e, &[0xea, 0xf0], false),
+    (0x729f, &[0xea, 0xf1], false),
+    (0x725d, &[0xea, 0xf2], false),
+    (0x7266, &[0xea, 0xf3], false),
+    (0x726f, &[0xea, 0xf4], false),
+    (0x727e, &[0xea, 0xf5], false),
+    (0x727f, &[0xea, 0xf6], false),
+    (0x7284, &[0xea, 0xf7], false),
+    (0x728b, &[0xea, 0xf8], false),
+    (0x728d, &[0xea, 0xf9], false),
+    (0x728f, &[0xea, 0xfa], false),
+    (0x7292, &[0xea, 0xfb], false),
+    (0x6308, &[0xea, 0xfc], false),
+    (0x6332, &[0xea, 0xfd], false),
+    (0x63b0, &[0xea, 0xfe], false),
+    (0x643f, &[0xeb, 0xa1], false),
+    (0x64d8, &[0xeb, 0xa2], false),
+    (0x8004, &[0xeb, 0xa3], false),
+    (0x6bea, &[0xeb, 0xa4], false),
+    (0x6bf3, &[0xeb, 0xa5], false),
+    (0x6bfd, &[0xeb, 0xa6], false),
+    (0x6bf5, &[0xeb, 0xa7], false),
+    (0x6bf9, &[0xeb, 0xa8], false),
+    (0x6c05, &[0xeb, 0xa9], false),
+    (0x6c07, &[0xeb, 0xaa], false),
+    (0x6c06, &[0xeb, 0xab], false),
+    (0x6c0d, &[0xeb, 0xac], false),
+    (0x6c15, &[0xeb, 0xad], false),
+    (0x6c18, &[0xeb, 0xae], false),
+    (0x6c19, &[0xeb, 0xaf], false),
+    (0x6c1a, &[0xeb, 0xb0], false),
+    (0x6c21, &[0xeb, 0xb1], false),
+    (0x6c29, &[0xeb, 0xb2], false),
+    (0x6c24, &[0xeb, 0xb3], false),
+    (0x6c2a, &[0xeb, 0xb4], false),
+    (0x6c32, &[0xeb, 0xb5], false),
+    (0x6535, &[0xeb, 0xb6], false),
+    (0x6555, &[0xeb, 0xb7], false),
+    (0x656b, &[0xeb, 0xb8], false),
+    (0x724d, &[0xeb, 0xb9], false),
+    (0x7252, &[0xeb, 0xba], false),
+    (0x7256, &[0xeb, 0xbb], false),
+    (0x7230, &[0xeb, 0xbc], false),
+    (0x8662, &[0xeb, 0xbd], false),
+    (0x5216, &[0xeb, 0xbe], false),
+    (0x809f, &[0xeb, 0xbf], false),
+    (0x809c, &[0xeb, 0xc0], false),
+    (0x8093, &[0xeb, 0xc1], false),
+    (0x80bc, &[0xeb, 0xc2], false),
+    (0x670a, &[0xeb, 0xc3], false),
+    (0x80bd, &[0xeb, 0xc4], false),
+    (0x80b1, &[0xeb, 0xc5], false),
+    (0x80ab, &[0xeb, 0xc6], false),
+    (0x80ad, &[0xeb, 0xc7], false),
+    (0x80b4, &[0xeb, 0xc8], false),
+    (0x80b7, &[0xeb, 0xc9], false),
+    (0x80e7, &[0xeb, 0xca], false),
+    (0x80e8, &[0xeb, 0xcb], false),
+    (0x80e9, &[0xeb, 0xcc], false),
+    (0x80ea, &[0xeb, 0xcd], false),
+    (0x80db, &[0xeb, 0xce], false),
+    (0x80c2, &[0xeb, 0xcf], false),
+    (0x80c4, &[0xeb, 0xd0], false),
+    (0x80d9, &[0xeb, 0xd1], false),
+    (0x80cd, &[0xeb, 0xd2], false),
+    (0x80d7, &[0xeb, 0xd3], false),
+    (0x6710, &[0xeb, 0xd4], false),
+    (0x80dd, &[0xeb, 0xd5], false),
+    (0x80eb, &[0xeb, 0xd6], false),
+    (0x80f1, &[0xeb, 0xd7], false),
+    (0x80f4, &[0xeb, 0xd8], false),
+    (0x80ed, &[0xeb, 0xd9], false),
+    (0x810d, &[0xeb, 0xda], false),
+    (0x810e, &[0xeb, 0xdb], false),
+    (0x80f2, &[0xeb, 0xdc], false),
+    (0x80fc, &[0xeb, 0xdd], false),
+    (0x6715, &[0xeb, 0xde], false),
+    (0x8112, &[0xeb, 0xdf], false),
+    (0x8c5a, &[0xeb, 0xe0], false),
+    (0x8136, &[0xeb, 0xe1], false),
+    (0x811e, &[0xeb, 0xe2], false),
+    (0x812c, &[0xeb, 0xe3], false),
+    (0x8118, &[0xeb, 0xe4], false),
+    (0x8132, &[0xeb, 0xe5], false),
+    (0x8148, &[0xeb, 0xe6], false),
+    (0x814c, &[0xeb, 0xe7], false),
+    (0x8153, &[0xeb, 0xe8], false),
+    (0x8174, &[0xeb, 0xe9], false),
+    (0x8159, &[0xeb, 0xea], false),
+    (0x815a, &[0xeb, 0xeb], false),
+    (0x8171, &[0xeb, 0xec], false),
+    (0x8160, &[0xeb, 0xed], false),
+    (0x8169, &[0xeb, 0xee], false),
+    (0x817c, &[0xeb, 0xef], false),
+    (0x817d, &[0xeb, 0xf0], false),
+    (0x816d, &[0xeb, 0xf1], false),
+    (0x8167, &[0xeb, 0xf2], false),
+    (0x584d, &[0xeb, 0xf3], false),
+    (0x5ab5, &[0xeb, 0xf4], false),
+    (0x8188, &[0xeb, 0xf5], false),
+    (0x8182, &[0xeb, 0xf6], false),
+    (0x8191, &[0xeb, 0xf7], false),
+    (0x6ed5, &[0xeb, 0xf8], false),
+    (0x81a3, &[0xeb, 0xf9], false),
+    (0x81aa, &[0xeb, 0xfa], false),
+    (0x81cc, &[0xeb, 0xfb], false),
+    (0x6726, &[0xeb, 0xfc], false),
+    (0x81ca, &[0xeb, 0xfd], false),
+    (0x81bb, &[0xeb, 0xfe], false),
+    (0x81c1, &[0xec, 0xa1], false),
+    (0x81a6, &[0xec, 0xa2], false),
+    (0x6b24, &[0xec, 0xa3], false),
+    (0x6b37, &[0xec, 0xa4], false),
+    (0x6b39, &[0xec, 0xa5], false),
+    (0x6b43, &[0xec, 0xa6], false),
+    (0x6b46, &[0xec, 0xa7], false),
+    (0x6b59, &[0xec, 0xa8], false),
+    (0x98d1, &[0xec, 0xa9], false),
+    (0x98d2, &[0xec, 0xaa], false),
+    (0x98d3, &[0xec, 0xab], false),
+    (0x98d5, &[0xec, 0xac], false),
+    (0x98d9, &[0xec, 0xad], false),
+    (0x98da, &[0xec, 0xae], false),
+    (0x6bb3, &[0xec, 0xaf], false),
+    (0x5f40, &[0xec, 0xb0], false),
+    (0x6bc2, &[0xec, 0xb1], false),
+    (0x89f3, &[0xec, 0xb2], false),
+    (0x6590, &[0xec, 0xb3], false),
+    (0x9f51, &[0xec, 0xb4], false),
+    (0x6593, &[0xec, 0xb5], false),
+    (0x65bc, &[0xec, 0xb6], false),
+    (0x65c6, &[0xec, 0xb7], false),
+    (0x65c4, &[0xec, 0xb8], false),
+    (0x65c3, &[0xec, 0xb9], false),
+    (0x65cc, &[0xec, 0xba], false),
+    (0x65ce, &[0xec, 0xbb], false),
+    (0x65d2, &[0xec, 0xbc], false),
+    (0x65d6, &[0xec, 0xbd], false),
+    (0x7080, &[0xec, 0xbe], false),
+    (0x709c, &[0xec, 0xbf], false),
+    (0x7096, &[0xec, 0xc0], false),
+    (0x709d, &[0xec, 0xc1], false),
+    (0x70bb, &[0xec, 0xc2], false),
+    (0x70c0, &[0xec, 0xc3], false),
+    (0x70b7, &[0xec, 0xc4], false),
+    (0x70ab, &[0xec, 0xc5], false),
+    (0x70b1, &[0xec, 0xc6], false),
+    (0x70e8, &[0xec, 0xc7], false),
+    (0x70ca, &[0xec, 0xc8], false),
+    (0x7110, &[0xec, 0xc9], false),
+    (0x7113, &[0xec, 0xca], false),
+    (0x7116, &[0xec, 0xcb], false),
+    (0x712f, &[0xec, 0xcc], false),
+    (0x7131, &[0xec, 0xcd], false),
+    (0x7173, &[0xec, 0xce], false),
+    (0x715c, &[0xec, 0xcf], false),
+    (0x7168, &[0xec, 0xd0], false),
+    (0x7145, &[0xec, 0xd1], false),
+    (0x7172, &[0xec, 0xd2], false),
+    (0x714a, &[0xec, 0xd3], false),
+    (0x7178, &[0xec, 0xd4], false),
+    (0x717a, &[0xec, 0xd5], false),
+    (0x7198, &[0xec, 0xd6], false),
+    (0x71b3, &[0xec, 0xd7], false),
+    (0x71b5, &[0xec, 0xd8], false),
+    (0x71a8, &[0xec, 0xd9], false),
+    (0x71a0, &[0xec, 0xda], false),
+    (0x71e0, &[0xec, 0xdb], false),
+    (0x71d4, &[0xec, 0xdc], false),
+    (0x71e7, &[0xec, 0xdd], false),
+    (0x71f9, &[0xec, 0xde], false),
+    (0x721d, &[0xec, 0xdf], false),
+    (0x7228, &[0xec, 0xe0], false),
+    (0x706c, &[0xec, 0xe1], false),
+    (0x7118, &[0xec, 0xe2], false),
+    (0x7166, &[0xec, 0xe3], false),
+    (0x71b9, &[0xec, 0xe4], false),
+    (0x623e, &[0xec, 0xe5], false),
+    (0x623d, &[0xec, 0xe6], false),
+    (0x6243, &[0xec, 0xe7], false),
+    (0x6248, &[0xec, 0xe8], false),
+    (0x6249, &[0xec, 0xe9], false),
+    (0x793b, &[0xec, 0xea], false),
+    (0x7940, &[0xec, 0xeb], false),
+    (0x7946, &[0xec, 0xec], false),
+    (0x7949, &[0xec, 0xed], false),
+    (0x795b, &[0xec, 0xee], false),
+    (0x795c, &[0xec, 0xef], false),
+    (0x7953, &[0xec, 0xf0], false),
+    (0x795a, &[0xec, 0xf1], false),
+    (0x7962, &[0xec, 0xf2], false),
+    (0x7957, &[0xec, 0xf3], false),
+    (0x7960, &[0xec, 0xf4], false),
+    (0x796f, &[0xec, 0xf5], false),
+    (0x7967, &[0xec, 0xf6], false),
+    (0x797a, &[0xec, 0xf7], false),
+    (0x7985, &[0xec, 0xf8], false),
+    (0x798a, &[0xec, 0xf9], false),
+    (0x799a, &[0xec, 0xfa], false),
+    (0x79a7, &[0xec, 0xfb], false),
+    (0x79b3, &[0xec, 0xfc], false),
+    (0x5fd1, &[0xec, 0xfd], false),
+    (0x5fd0, &[0xec, 0xfe], false),
+    (0x603c, &[0xed, 0xa1], false),
+    (0x605d, &[0xed, 0xa2], false),
+    (0x605a, &[0xed, 0xa3], false),
+    (0x6067, &[0xed, 0xa4], false),
+    (0x6041, &[0xed, 0xa5], false),
+    (0x6059, &[0xed, 0xa6], false),
+    (0x6063, &[0xed, 0xa7], false),
+    (0x60ab, &[0xed, 0xa8], false),
+    (0x6106, &[0xed, 0xa9], false),
+    (0x610d, &[0xed, 0xaa], false),
+    (0x615d, &[0xed, 0xab], false),
+    (0x61a9, &[0xed, 0xac], false),
+    (0x619d, &[0xed, 0xad], false),
+    (0x61cb, &[0xed, 0xae], false),
+    (0x61d1, &[0xed, 0xaf], false),
+    (0x6206, &[0xed, 0xb0], false),
+    (0x8080, &[0xed, 0xb1], false),
+    (0x807f, &[0xed, 0xb2], false),
+    (0x6c93, &[0xed, 0xb3], false),
+    (0x6cf6, &[0xed, 0xb4], false),
+    (0x6dfc, &[0xed, 0xb5], false),
+    (0x77f6, &[0xed, 0xb6], false),
+    (0x77f8, &[0xed, 0xb7], false),
+    (0x7800, &[0xed, 0xb8], false),
+    (0x7809, &[0xed, 0xb9], false),
+    (0x7817, &[0xed, 0xba], false),
+    (0x7818, &[0xed, 0xbb], false),
+    (0x7811, &[0xed, 0xbc], false),
+    (0x65ab, &[0xed, 0xbd], false),
+    (0x782d, &[0xed, 0xbe], false),
+    (0x781c, &[0xed, 0xbf], false),
+    (0x781d, &[0xed, 0xc0], false),
+    (0x7839, &[0xed, 0xc1], false),
+    (0x783a, &[0xed, 0xc2], false),
+    (0x783b, &[0xed, 0xc3], false),
+    (0x781f, &[0xed, 0xc4], false),
+    (0x783c, &[0xed, 0xc5], false),
+    (0x7825, &[0xed, 0xc6], false),
+    (0x782c, &[0xed, 0xc7], false),
+    (0x7823, &[0xed, 0xc8], false),
+    (0x7829, &[0xed, 0xc9], false),
+    (0x784e, &[0xed, 0xca], false),
+    (0x786d, &[0xed, 0xcb], false),
+    (0x7856, &[0xed, 0xcc], false),
+    (0x7857, &[0xed, 0xcd], false),
+    (0x7826, &[0xed, 0xce], false),
+    (0x7850, &[0xed, 0xcf], false),
+    (0x7847, &[0xed, 0xd0], false),
+    (0x784c, &[0xed, 0xd1], false),
+    (0x786a, &[0xed, 0xd2], false),
+    (0x789b, &[0xed, 0xd3], false),
+    (0x7893, &[0xed, 0xd4], false),
+    (0x789a, &[0xed, 0xd5], false),
+    (0x7887, &[0xed, 0xd6], false),
+    (0x789c, &[0xed, 0xd7], false),
+    (0x78a1, &[0xed, 0xd8], false),
+    (0x78a3, &[0xed, 0xd9], false),
+    (0x78b2, &[0xed, 0xda], false),
+    (0x78b9, &[0xed, 0xdb], false),
+    (0x78a5, &[0xed, 0xdc], false),
+    (0x78d4, &[0xed, 0xdd], false),
+    (0x78d9, &[0xed, 0xde], false),
+    (0x78c9, &[0xed, 0xdf], false),
+    (0x78ec, &[0xed, 0xe0], false),
+    (0x78f2, &[0xed, 0xe1], false),
+    (0x7905, &[0xed, 0xe2], false),
+    (0x78f4, &[0xed, 0xe3], false),
+    (0x7913, &[0xed, 0xe4], false),
+    (0x7924, &[0xed, 0xe5], false),
+    (0x791e, &[0xed, 0xe6], false),
+    (0x7934, &[0xed, 0xe7], false),
+    (0x9f9b, &[0xed, 0xe8], false),
+    (0x9ef9, &[0xed, 0xe9], false),
+    (0x9efb, &[0xed, 0xea], false),
+    (0x9efc, &[0xed, 0xeb], false),
+    (0x76f1, &[0xed, 0xec], false),
+    (0x7704, &[0xed, 0xed], false),
+    (0x770d, &[0xed, 0xee], false),
+    (0x76f9, &[0xed, 0xef], false),
+    (0x7707, &[0xed, 0xf0], false),
+    (0x7708, &[0xed, 0xf1], false),
+    (0x771a, &[0xed, 0xf2], false),
+    (0x7722, &[0xed, 0xf3], false),
+    (0x7719, &[0xed, 0xf4], false),
+    (0x772d, &[0xed, 0xf5], false),
+    (0x7726, &[0xed, 0xf6], false),
+    (0x7735, &[0xed, 0xf7], false),
+    (0x7738, &[0xed, 0xf8], false),
+    (0x7750, &[0xed, 0xf9], false),
+    (0x7751, &[0xed, 0xfa], false),
+    (0x7747, &[0xed, 0xfb], false),
+    (0x7743, &[0xed, 0xfc], false),
+    (0x775a, &[0xed, 0xfd], false),
+    (0x7768, &[0xed, 0xfe], false),
+    (0x7762, &[0xee, 0xa1], false),
+    (0x7765, &[0xee, 0xa2], false),
+    (0x777f, &[0xee, 0xa3], false),
+    (0x778d, &[0xee, 0xa4], false),
+    (0x777d, &[0xee, 0xa5], false),
+    (0x7780, &[0xee, 0xa6], false),
+    (0x778c, &[0xee, 0xa7], false),
+    (0x7791, &[0xee, 0xa8], false),
+    (0x779f, &[0xee, 0xa9], false),
+    (0x77a0, &[0xee, 0xaa], false),
+    (0x77b0, &[0xee, 0xab], false),
+    (0x77b5, &[0xee, 0xac], false),
+    (0x77bd, &[0xee, 0xad], false),
+    (0x753a, &[0xee, 0xae], false),
+    (0x7540, &[0xee, 0xaf], false),
+    (0x754e, &[0xee, 0xb0], false),
+    (0x754b, &[0xee, 0xb1], false),
+    (0x7548, &[0xee, 0xb2], false),
+    (0x755b, &[0xee, 0xb3], false),
+    (0x7572, &[0xee, 0xb4], false),
+    (0x7579, &[0xee, 0xb5], false),
+    (0x7583, &[0xee, 0xb6], false),
+    (0x7f58, &[0xee, 0xb7], false),
+    (0x7f61, &[0xee, 0xb8], false),
+    (0x7f5f, &[0xee, 0xb9], false),
+    (0x8a48, &[0xee, 0xba], false),
+    (0x7f68, &[0xee, 0xbb], false),
+    (0x7f74, &[0xee, 0xbc], false),
+    (0x7f71, &[0xee, 0xbd], false),
+    (0x7f79, &[0xee, 0xbe], false),
+    (0x7f81, &[0xee, 0xbf], false),
+    (0x7f7e, &[0xee, 0xc0], false),
+    (0x76cd, &[0xee, 0xc1], false),
+    (0x76e5, &[0xee, 0xc2], false),
+    (0x8832, &[0xee, 0xc3], false),
+    (0x9485, &[0xee, 0xc4], false),
+    (0x9486, &[0xee, 0xc5], false),
+    (0x9487, &[0xee, 0xc6], false),
+    (0x948b, &[0xee, 0xc7], false),
+    (0x948a, &[0xee, 0xc8], false),
+    (0x948c, &[0xee, 0xc9], false),
+    (0x948d, &[0xee, 0xca], false),
+    (0x948f, &[0xee, 0xcb], false),
+    (0x9490, &[0xee, 0xcc], false),
+    (0x9494, &[0xee, 0xcd], false),
+    (0x9497, &[0xee, 0xce], false),
+    (0x9495, &[0xee, 0xcf], false),
+    (0x949a, &[0xee, 0xd0], false),
+    (0x949b, &[0xee, 0xd1], false),
+    (0x949c, &[0xee, 0xd2], false),
+    (0x94a3, &[0xee, 0xd3], false),
+    (0x94a4, &[0xee, 0xd4], false),
+    (0x94ab, &[0xee, 0xd5], false),
+    (0x94aa, &[0xee, 0xd6], false),
+    (0x94ad, &[0xee, 0xd7], false),
+    (0x94ac, &[0xee, 0xd8], false),
+    (0x94af, &[0xee, 0xd9], false),
+    (0x94b0, &[0xee, 0xda], false),
+    (0x94b2, &[0xee, 0xdb], false),
+    (0x94b4, &[0xee, 0xdc], false),
+    (0x94b6, &[0xee, 0xdd], false),
+    (0x94b7, &[0xee, 0xde], false),
+    (0x94b8, &[0xee, 0xdf], false),
+    (0x94b9, &[0xee, 0xe0], false),
+    (0x94ba, &[0xee, 0xe1], false),
+    (0x94bc, &[0xee, 0xe2], false),
+    (0x94bd, &[0xee, 0xe3], false),
+    (0x94bf, &[0xee, 0xe4], false),
+    (0x94c4, &[0xee, 0xe5], false),
+    (0x94c8, &[0xee, 0xe6], false),
+    (0x94c9, &[0xee, 0xe7], false),
+    (0x94ca, &[0xee, 0xe8], false),
+    (0x94cb, &[0xee, 0xe9], false),
+    (0x94cc, &[0xee, 0xea], false),
+    (0x94cd, &[0xee, 0xeb], false),
+    (0x94ce, &[0xee, 0xec], false),
+    (0x94d0, &[0xee, 0xed], false),
+    (0x94d1, &[0xee, 0xee], false),
+    (0x94d2, &[0xee, 0xef], false),
+    (0x94d5, &[0xee, 0xf0], false),
+    (0x94d6, &[0xee, 0xf1], false),
+    (0x94d7, &[0xee, 0xf2], false),
+    (0x94d9, &[0xee, 0xf3], false),
+    (0x94d8, &[0xee, 0xf4], false),
+    (0x94db, &[0xee, 0xf5], false),
+    (0x94de, &[0xee, 0xf6], false),
+    (0x94df, &[0xee, 0xf7], false),
+    (0x94e0, &[0xee, 0xf8], false),
+    (0x94e2, &[0xee, 0xf9], false),
+    (0x94e4, &[0xee, 0xfa], false),
+    (0x94e5, &[0xee, 0xfb], false),
+    (0x94e7, &[0xee, 0xfc], false),
+    (0x94e8, &[0xee, 0xfd], false),
+    (0x94ea, &[0xee, 0xfe], false),
+    (0x94e9, &[0xef, 0xa1], false),
+    (0x94eb, &[0xef, 0xa2], false),
+    (0x94ee, &[0xef, 0xa3], false),
+    (0x94ef, &[0xef, 0xa4], false),
+    (0x94f3, &[0xef, 0xa5], false),
+    (0x94f4, &[0xef, 0xa6], false),
+    (0x94f5, &[0xef, 0xa7], false),
+    (0x94f7, &[0xef, 0xa8], false),
+    (0x94f9, &[0xef, 0xa9], false),
+    (0x94fc, &[0xef, 0xaa], false),
+    (0x94fd, &[0xef, 0xab], false),
+    (0x94ff, &[0xef, 0xac], false),
+    (0x9503, &[0xef, 0xad], false),
+    (0x9502, &[0xef, 0xae], false),
+    (0x9506, &[0xef, 0xaf], false),
+    (0x9507, &[0xef, 0xb0], false),
+    (0x9509, &[0xef, 0xb1], false),
+    (0x950a, &[0xef, 0xb2], false),
+    (0x950d, &[0xef, 0xb3], false),
+    (0x950e, &[0xef, 0xb4], false),
+    (0x950f, &[0xef, 0xb5], false),
+    (0x9512, &[0xef, 0xb6], false),
+    (0x9513, &[0xef, 0xb7], false),
+    (0x9514, &[0xef, 0xb8], false),
+    (0x9515, &[0xef, 0xb9], false),
+    (0x9516, &[0xef, 0xba], false),
+    (0x9518, &[0xef, 0xbb], false),
+    (0x951b, &[0xef, 0xbc], false),
+    (0x951d, &[0xef, 0xbd], false),
+    (0x951e, &[0xef, 0xbe], false),
+    (0x951f, &[0xef, 0xbf], false),
+    (0x9522, &[0xef, 0xc0], false),
+    (0x952a, &[0xef, 0xc1], false),
+    (0x952b, &[0xef, 0xc2], false),
+    (0x9529, &[0xef, 0xc3], false),
+    (0x952c, &[0xef, 0xc4], false),
+    (0x9531, &[0xef, 0xc5], false),
+    (0x9532, &[0xef, 0xc6], false),
+    (0x9534, &[0xef, 0xc7], false),
+    (0x9536, &[0xef, 0xc8], false),
+    (0x9537, &[0xef, 0xc9], false),
+    (0x9538, &[0xef, 0xca], false),
+    (0x953c, &[0xef, 0xcb], false),
+    (0x953e, &[0xef, 0xcc], false),
+    (0x953f, &[0xef, 0xcd], false),
+    (0x9542, &[0xef, 0xce], false),
+    (0x9535, &[0xef, 0xcf], false),
+    (0x9544, &[0xef, 0xd0], false),
+    (0x9545, &[0xef, 0xd1], false),
+    (0x9546, &[0xef, 0xd2], false),
+    (0x9549, &[0xef, 0xd3], false),
+    (0x954c, &[0xef, 0xd4], false),
+    (0x954e, &[0xef, 0xd5], false),
+    (0x954f, &[0xef, 0xd6], false),
+    (0x9552, &[0xef, 0xd7], false),
+    (0x9553, &[0xef, 0xd8], false),
+    (0x9554, &[0xef, 0xd9], false),
+    (0x9556, &[0xef, 0xda], false),
+    (0x9557, &[0xef, 0xdb], false),
+    (0x9558, &[0xef, 0xdc], false),
+    (0x9559, &[0xef, 0xdd], false),
+    (0x955b, &[0xef, 0xde], false),
+    (0x955e, &[0xef, 0xdf], false),
+    (0x955f, &[0xef, 0xe0], false),
+    (0x955d, &[0xef, 0xe1], false),
+    (0x9561, &[0xef, 0xe2], false),
+    (0x9562, &[0xef, 0xe3], false),
+    (0x9564, &[0xef, 0xe4], false),
+    (0x9565, &[0xef, 0xe5], false),
+    (0x9566, &[0xef, 0xe6], false),
+    (0x9567, &[0xef, 0xe7], false),
+    (0x9568, &[0xef, 0xe8], false),
+    (0x9569, &[0xef, 0xe9], false),
+    (0x956a, &[0xef, 0xea], false),
+    (0x956b, &[0xef, 0xeb], false),
+    (0x956c, &[0xef, 0xec], false),
+    (0x956f, &[0xef, 0xed], false),
+    (0x9571, &[0xef, 0xee], false),
+    (0x9572, &[0xef, 0xef], false),
+    (0x9573, &[0xef, 0xf0], false),
+    (0x953a, &[0xef, 0xf1], false),
+    (0x77e7, &[0xef, 0xf2], false),
+    (0x77ec, &[0xef, 0xf3], false),
+    (0x96c9, &[0xef, 0xf4], false),
+    (0x79d5, &[0xef, 0xf5], false),
+    (0x79ed, &[0xef, 0xf6], false),
+    (0x79e3, &[0xef, 0xf7], false),
+    (0x79eb, &[0xef, 0xf8], false),
+    (0x7a06, &[0xef, 0xf9], false),
+    (0x5d47, &[0xef, 0xfa], false),
+    (0x7a03, &[0xef, 0xfb], false),
+    (0x7a02, &[0xef, 0xfc], false),
+    (0x7a1e, &[0xef, 0xfd], false),
+    (0x7a14, &[0xef, 0xfe], false),
+    (0x7a39, &[0xf0, 0xa1], false),
+    (0x7a37, &[0xf0, 0xa2], false),
+    (0x7a51, &[0xf0, 0xa3], false),
+    (0x9ecf, &[0xf0, 0xa4], false),
+    (0x99a5, &[0xf0, 0xa5], false),
+    (0x7a70, &[0xf0, 0xa6], false),
+    (0x7688, &[0xf0, 0xa7], false),
+    (0x768e, &[0xf0, 0xa8], false),
+    (0x7693, &[0xf0, 0xa9], false),
+    (0x7699, &[0xf0, 0xaa], false),
+    (0x76a4, &[0xf0, 0xab], false),
+    (0x74de, &[0xf0, 0xac], false),
+    (0x74e0, &[0xf0, 0xad], false),
+    (0x752c, &[0xf0, 0xae], false),
+    (0x9e20, &[0xf0, 0xaf], false),
+    (0x9e22, &[0xf0, 0xb0], false),
+    (0x9e28, &[0xf0, 0xb1], false),
+    (0x9e29, &[0xf0, 0xb2], false),
+    (0x9e2a, &[0xf0, 0xb3], false),
+    (0x9e2b, &[0xf0, 0xb4], false),
+    (0x9e2c, &[0xf0, 0xb5], false),
+    (0x9e32, &[0xf0, 0xb6], false),
+    (0x9e31, &[0xf0, 0xb7], false),
+    (0x9e36, &[0xf0, 0xb8], false),
+    (0x9e38, &[0xf0, 0xb9], false),
+    (0x9e37, &[0xf0, 0xba], false),
+    (0x9e39, &[0xf0, 0xbb], false),
+    (0x9e3a, &[0xf0, 0xbc], false),
+    (0x9e3e, &[0xf0, 0xbd], false),
+    (0x9e41, &[0xf0, 0xbe], false),
+    (0x9e42, &[0xf0, 0xbf], false),
+    (0x9e44, &[0xf0, 0xc0], false),
+    (0x9e46, &[0xf0, 0xc1], false),
+    (0x9e47, &[0xf0, 0xc2], false),
+    (0x9e48, &[0xf0, 0xc3], false),
+    (0x9e49, &[0xf0, 0xc4], false),
+    (0x9e4b, &[0xf0, 0xc5], false),
+    (0x9e4c, &[0xf0, 0xc6], false),
+    (0x9e4e, &[0xf0, 0xc7], false),
+    (0x9e51, &[0xf0, 0xc8], false),
+    (0x9e55, &[0xf0, 0xc9], false),
+    (0x9e57, &[0xf0, 0xca], false),
+    (0x9e5a, &[0xf0, 0xcb], false),
+    (0x9e5b, &[0xf0, 0xcc], false),
+    (0x9e5c, &[0xf0, 0xcd], false),
+    (0x9e5e, &[0xf0, 0xce], false),
+    (0x9e63, &[0xf0, 0xcf], false),
+    (0x9e66, &[0xf0, 0xd0], false),
+    (0x9e67, &[0xf0, 0xd1], false),
+    (0x9e68, &[0xf0, 0xd2], false),
+    (0x9e69, &[0xf0, 0xd3], false),
+    (0x9e6a, &[0xf0, 0xd4], false),
+    (0x9e6b, &[0xf0, 0xd5], false),
+    (0x9e6c, &[0xf0, 0xd6], false),
+    (0x9e71, &[0xf0, 0xd7], false),
+    (0x9e6d, &[0xf0, 0xd8], false),
+    (0x9e73, &[0xf0, 0xd9], false),
+    (0x7592, &[0xf0, 0xda], false),
+    (0x7594, &[0xf0, 0xdb], false),
+    (0x7596, &[0xf0, 0xdc], false),
+    (0x75a0, &[0xf0, 0xdd], false),
+    (0x759d, &[0xf0, 0xde], false),
+    (0x75ac, &[0xf0, 0xdf], false),
+    (0x75a3, &[0xf0, 0xe0], false),
+    (0x75b3, &[0xf0, 0xe1], false),
+    (0x75b4, &[0xf0, 0xe2], false),
+    (0x75b8, &[0xf0, 0xe3], false),
+    (0x75c4, &[0xf0, 0xe4], false),
+    (0x75b1, &[0xf0, 0xe5], false),
+    (0x75b0, &[0xf0, 0xe6], false),
+    (0x75c3, &[0xf0, 0xe7], false),
+    (0x75c2, &[0xf0, 0xe8], false),
+    (0x75d6, &[0xf0, 0xe9], false),
+    (0x75cd, &[0xf0, 0xea], false),
+    (0x75e3, &[0xf0, 0xeb], false),
+    (0x75e8, &[0xf0, 0xec], false),
+    (0x75e6, &[0xf0, 0xed], false),
+    (0x75e4, &[0xf0, 0xee], false),
+    (0x75eb, &[0xf0, 0xef], false),
+    (0x75e7, &[0xf0, 0xf0], false),
+    (0x7603, &[0xf0, 0xf1], false),
+    (0x75f1, &[0xf0, 0xf2], false),
+    (0x75fc, &[0xf0, 0xf3], false),
+    (0x75ff, &[0xf0, 0xf4], false),
+    (0x7610, &[0xf0, 0xf5], false),
+    (0x7600, &[0xf0, 0xf6], false),
+    (0x7605, &[0xf0, 0xf7], false),
+    (0x760c, &[0xf0, 0xf8], false),
+    (0x7617, &[0xf0, 0xf9], false),
+    (0x760a, &[0xf0, 0xfa], false),
+    (0x7625, &[0xf0, 0xfb], false),
+    (0x7618, &[0xf0, 0xfc], false),
+    (0x7615, &[0xf0, 0xfd], false),
+    (0x7619, &[0xf0, 0xfe], false),
+    (0x761b, &[0xf1, 0xa1], false),
+    (0x763c, &[0xf1, 0xa2], false),
+    (0x7622, &[0xf1, 0xa3], false),
+    (0x7620, &[0xf1, 0xa4], false),
+    (0x7640, &[0xf1, 0xa5], false),
+    (0x762d, &[0xf1, 0xa6], false),
+    (0x7630, &[0xf1, 0xa7], false),
+    (0x763f, &[0xf1, 0xa8], false),
+    (0x7635, &[0xf1, 0xa9], false),
+    (0x7643, &[0xf1, 0xaa], false),
+    (0x763e, &[0xf1, 0xab], false),
+    (0x7633, &[0xf1, 0xac], false),
+    (0x764d, &[0xf1, 0xad], false),
+    (0x765e, &[0xf1, 0xae], false),
+    (0x7654, &[0xf1, 0xaf], false),
+    (0x765c, &[0xf1, 0xb0], false),
+    (0x7656, &[0xf1, 0xb1], false),
+    (0x766b, &[0xf1, 0xb2], false),
+    (0x766f, &[0xf1, 0xb3], false),
+    (0x7fca, &[0xf1, 0xb4], false),
+    (0x7ae6, &[0xf1, 0xb5], false),
+    (0x7a78, &[0xf1, 0xb6], false),
+    (0x7a79, &[0xf1, 0xb7], false),
+    (0x7a80, &[0xf1, 0xb8], false),
+    (0x7a86, &[0xf1, 0xb9], false),
+    (0x7a88, &[0xf1, 0xba], false),
+    (0x7a95, &[0xf1, 0xbb], false),
+    (0x7aa6, &[0xf1, 0xbc], false),
+    (0x7aa0, &[0xf1, 0xbd], false),
+    (0x7aac, &[0xf1, 0xbe], false),
+    (0x7aa8, &[0xf1, 0xbf], false),
+    (0x7aad, &[0xf1, 0xc0], false),
+    (0x7ab3, &[0xf1, 0xc1], false),
+    (0x8864, &[0xf1, 0xc2], false),
+    (0x8869, &[0xf1, 0xc3], false),
+    (0x8872, &[0xf1, 0xc4], false),
+    (0x887d, &[0xf1, 0xc5], false),
+    (0x887f, &[0xf1, 0xc6], false),
+    (0x8882, &[0xf1, 0xc7], false),
+    (0x88a2, &[0xf1, 0xc8], false),
+    (0x88c6, &[0xf1, 0xc9], false),
+    (0x88b7, &[0xf1, 0xca], false),
+    (0x88bc, &[0xf1, 0xcb], false),
+    (0x88c9, &[0xf1, 0xcc], false),
+    (0x88e2, &[0xf1, 0xcd], false),
+    (0x88ce, &[0xf1, 0xce], false),
+    (0x88e3, &[0xf1, 0xcf], false),
+    (0x88e5, &[0xf1, 0xd0], false),
+    (0x88f1, &[0xf1, 0xd1], false),
+    (0x891a, &[0xf1, 0xd2], false),
+    (0x88fc, &[0xf1, 0xd3], false),
+    (0x88e8, &[0xf1, 0xd4], false),
+    (0x88fe, &[0xf1, 0xd5], false),
+    (0x88f0, &[0xf1, 0xd6], false),
+    (0x8921, &[0xf1, 0xd7], false),
+    (0x8919, &[0xf1, 0xd8], false),
+    (0x8913, &[0xf1, 0xd9], false),
+    (0x891b, &[0xf1, 0xda], false),
+    (0x890a, &[0xf1, 0xdb], false),
+    (0x8934, &[0xf1, 0xdc], false),
+    (0x892b, &[0xf1, 0xdd], false),
+    (0x8936, &[0xf1, 0xde], false),
+    (0x8941, &[0xf1, 0xdf], false),
+    (0x8966, &[0xf1, 0xe0], false),
+    (0x897b, &[0xf1, 0xe1], false),
+    (0x758b, &[0xf1, 0xe2], false),
+    (0x80e5, &[0xf1, 0xe3], false),
+    (0x76b2, &[0xf1, 0xe4], false),
+    (0x76b4, &[0xf1, 0xe5], false),
+    (0x77dc, &[0xf1, 0xe6], false),
+    (0x8012, &[0xf1, 0xe7], false),
+    (0x8014, &[0xf1, 0xe8], false),
+    (0x8016, &[0xf1, 0xe9], false),
+    (0x801c, &[0xf1, 0xea], false),
+    (0x8020, &[0xf1, 0xeb], false),
+    (0x8022, &[0xf1, 0xec], false),
+    (0x8025, &[0xf1, 0xed], false),
+    (0x8026, &[0xf1, 0xee], false),
+    (0x8027, &[0xf1, 0xef], false),
+    (0x8029, &[0xf1, 0xf0], false),
+    (0x8028, &[0xf1, 0xf1], false),
+    (0x8031, &[0xf1, 0xf2], false),
+    (0x800b, &[0xf1, 0xf3], false),
+    (0x8035, &[0xf1, 0xf4], false),
+    (0x8043, &[0xf1, 0xf5], false),
+    (0x8046, &[0xf1, 0xf6], false),
+    (0x804d, &[0xf1, 0xf7], false),
+    (0x8052, &[0xf1, 0xf8], false),
+    (0x8069, &[0xf1, 0xf9], false),
+    (0x8071, &[0xf1, 0xfa], false),
+    (0x8983, &[0xf1, 0xfb], false),
+    (0x9878, &[0xf1, 0xfc], false),
+    (0x9880, &[0xf1, 0xfd], false),
+    (0x9883, &[0xf1, 0xfe], false),
+    (0x9889, &[0xf2, 0xa1], false),
+    (0x988c, &[0xf2, 0xa2], false),
+    (0x988d, &[0xf2, 0xa3], false),
+    (0x988f, &[0xf2, 0xa4], false),
+    (0x9894, &[0xf2, 0xa5], false),
+    (0x989a, &[0xf2, 0xa6], false),
+    (0x989b, &[0xf2, 0xa7], false),
+    (0x989e, &[0xf2, 0xa8], false),
+    (0x989f, &[0xf2, 0xa9], false),
+    (0x98a1, &[0xf2, 0xaa], false),
+    (0x98a2, &[0xf2, 0xab], false),
+    (0x98a5, &[0xf2, 0xac], false),
+    (0x98a6, &[0xf2, 0xad], false),
+    (0x864d, &[0xf2, 0xae], false),
+    (0x8654, &[0xf2, 0xaf], false),
+    (0x866c, &[0xf2, 0xb0], false),
+    (0x866e, &[0xf2, 0xb1], false),
+    (0x867f, &[0xf2, 0xb2], false),
+    (0x867a, &[0xf2, 0xb3], false),
+    (0x867c, &[0xf2, 0xb4], false),
+    (0x867b, &[0xf2, 0xb5], false),
+    (0x86a8, &[0xf2, 0xb6], false),
+    (0x868d, &[0xf2, 0xb7], false),
+    (0x868b, &[0xf2, 0xb8], false),
+    (0x86ac, &[0xf2, 0xb9], false),
+    (0x869d, &[0xf2, 0xba], false),
+    (0x86a7, &[0xf2, 0xbb], false),
+    (0x86a3, &[0xf2, 0xbc], false),
+    (0x86aa, &[0xf2, 0xbd], false),
+    (0x8693, &[0xf2, 0xbe], false),
+    (0x86a9, &[0xf2, 0xbf], false),
+    (0x86b6, &[0xf2, 0xc0], false),
+    (0x86c4, &[0xf2, 0xc1], false),
+    (0x86b5, &[0xf2, 0xc2], false),
+    (0x86ce, &[0xf2, 0xc3], false),
+    (0x86b0, &[0xf2, 0xc4], false),
+    (0x86ba, &[0xf2, 0xc5], false),
+    (0x86b1, &[0xf2, 0xc6], false),
+    (0x86af, &[0xf2, 0xc7], false),
+    (0x86c9, &[0xf2, 0xc8], false),
+    (0x86cf, &[0xf2, 0xc9], false),
+    (0x86b4, &[0xf2, 0xca], false),
+    (0x86e9, &[0xf2, 0xcb], false),
+    (0x86f1, &[0xf2, 0xcc], false),
+    (0x86f2, &[0xf2, 0xcd], false),
+    (0x86ed, &[0xf2, 0xce], false),
+    (0x86f3, &[0xf2, 0xcf], false),
+    (0x86d0, &[0xf2, 0xd0], false),
+    (0x8713, &[0xf2, 0xd1], false),
+    (0x86de, &[0xf2, 0xd2], false),
+    (0x86f4, &[0xf2, 0xd3], false),
+    (0x86df, &[0xf2, 0xd4], false),
+    (0x86d8, &[0xf2, 0xd5], false),
+    (0x86d1, &[0xf2, 0xd6], false),
+    (0x8703, &[0xf2, 0xd7], false),
+    (0x8707, &[0xf2, 0xd8], false),
+    (0x86f8, &[0xf2, 0xd9], false),
+    (0x8708, &[0xf2, 0xda], false),
+    (0x870a, &[0xf2, 0xdb], false),
+    (0x870d, &[0xf2, 0xdc], false),
+    (0x8709, &[0xf2, 0xdd], false),
+    (0x8723, &[0xf2, 0xde], false),
+    (0x873b, &[0xf2, 0xdf], false),
+    (0x871e, &[0xf2, 0xe0], false),
+    (0x8725, &[0xf2, 0xe1], false),
+    (0x872e, &[0xf2, 0xe2], false),
+    (0x871a, &[0xf2, 0xe3], false),
+    (0x873e, &[0xf2, 0xe4], false),
+    (0x8748, &[0xf2, 0xe5], false),
+    (0x8734, &[0xf2, 0xe6], false),
+    (0x8731, &[0xf2, 0xe7], false),
+    (0x8729, &[0xf2, 0xe8], false),
+    (0x8737, &[0xf2, 0xe9], false),
+    (0x873f, &[0xf2, 0xea], false),
+    (0x8782, &[0xf2, 0xeb], false),
+    (0x8722, &[0xf2, 0xec], false),
+    (0x877d, &[0xf2, 0xed], false),
+    (0x877e, &[0xf2, 0xee], false),
+    (0x877b, &[0xf2, 0xef], false),
+    (0x8760, &[0xf2, 0xf0], false),
+    (0x8770, &[0xf2, 0xf1], false),
+    (0x874c, &[0xf2, 0xf2], false),
+    (0x876e, &[0xf2, 0xf3], false),
+    (0x878b, &[0xf2, 0xf4], false),
+    (0x8753, &[0xf2, 0xf5], false),
+    (0x8763, &[0xf2, 0xf6], false),
+    (0x877c, &[0xf2, 0xf7], false),
+    (0x8764, &[0xf2, 0xf8], false),
+    (0x8759, &[0xf2, 0xf9], false),
+    (0x8765, &[0xf2, 0xfa], false),
+    (0x8793, &[0xf2, 0xfb], false),
+    (0x87af, &[0xf2, 0xfc], false),
+    (0x87a8, &[0xf2, 0xfd], false),
+    (0x87d2, &[0xf2, 0xfe], false),
+    (0x87c6, &[0xf3, 0xa1], false),
+    (0x8788, &[0xf3, 0xa2], false),
+    (0x8785, &[0xf3, 0xa3], false),
+    (0x87ad, &[0xf3, 0xa4], false),
+    (0x8797, &[0xf3, 0xa5], false),
+    (0x8783, &[0xf3, 0xa6], false),
+    (0x87ab, &[0xf3, 0xa7], false),
+    (0x87e5, &[0xf3, 0xa8], false),
+    (0x87ac, &[0xf3, 0xa9], false),
+    (0x87b5, &[0xf3, 0xaa], false),
+    (0x87b3, &[0xf3, 0xab], false),
+    (0x87cb, &[0xf3, 0xac], false),
+    (0x87d3, &[0xf3, 0xad], false),
+    (0x87bd, &[0xf3, 0xae], false),
+    (0x87d1, &[0xf3, 0xaf], false),
+    (0x87c0, &[0xf3, 0xb0], false),
+    (0x87ca, &[0xf3, 0xb1], false),
+    (0x87db, &[0xf3, 0xb2], false),
+    (0x87ea, &[0xf3, 0xb3], false),
+    (0x87e0, &[0xf3, 0xb4], false),
+    (0x87ee, &[0xf3, 0xb5], false),
+    (0x8816, &[0xf3, 0xb6], false),
+    (0x8813, &[0xf3, 0xb7], false),
+    (0x87fe, &[0xf3, 0xb8], false),
+    (0x880a, &[0xf3, 0xb9], false),
+    (0x881b, &[0xf3, 0xba], false),
+    (0x8821, &[0xf3, 0xbb], false),
+    (0x8839, &[0xf3, 0xbc], false),
+    (0x883c, &[0xf3, 0xbd], false),
+    (0x7f36, &[0xf3, 0xbe], false),
+    (0x7f42, &[0xf3, 0xbf], false),
+    (0x7f44, &[0xf3, 0xc0], false),
+    (0x7f45, &[0xf3, 0xc1], false),
+    (0x8210, &[0xf3, 0xc2], false),
+    (0x7afa, &[0xf3, 0xc3], false),
+    (0x7afd, &[0xf3, 0xc4], false),
+    (0x7b08, &[0xf3, 0xc5], false),
+    (0x7b03, &[0xf3, 0xc6], false),
+    (0x7b04, &[0xf3, 0xc7], false),
+    (0x7b15, &[0xf3, 0xc8], false),
+    (0x7b0a, &[0xf3, 0xc9], false),
+    (0x7b2b, &[0xf3, 0xca], false),
+    (0x7b0f, &[0xf3, 0xcb], false),
+    (0x7b47, &[0xf3, 0xcc], false),
+    (0x7b38, &[0xf3, 0xcd], false),
+    (0x7b2a, &[0xf3, 0xce], false),
+    (0x7b19, &[0xf3, 0xcf], false),
+    (0x7b2e, &[0xf3, 0xd0], false),
+    (0x7b31, &[0xf3, 0xd1], false),
+    (0x7b20, &[0xf3, 0xd2], false),
+    (0x7b25, &[0xf3, 0xd3], false),
+    (0x7b24, &[0xf3, 0xd4], false),
+    (0x7b33, &[0xf3, 0xd5], false),
+    (0x7b3e, &[0xf3, 0xd6], false),
+    (0x7b1e, &[0xf3, 0xd7], false),
+    (0x7b58, &[0xf3, 0xd8], false),
+    (0x7b5a, &[0xf3, 0xd9], false),
+    (0x7b45, &[0xf3, 0xda], false),
+    (0x7b75, &[0xf3, 0xdb], false),
+    (0x7b4c, &[0xf3, 0xdc], false),
+    (0x7b5d, &[0xf3, 0xdd], false),
+    (0x7b60, &[0xf3, 0xde], false),
+    (0x7b6e, &[0xf3, 0xdf], false),
+    (0x7b7b, &[0xf3, 0xe0], false),
+    (0x7b62, &[0xf3, 0xe1], false),
+    (0x7b72, &[0xf3, 0xe2], false),
+    (0x7b71, &[0xf3, 0xe3], false),
+    (0x7b90, &[0xf3, 0xe4], false),
+    (0x7ba6, &[0xf3, 0xe5], false),
+    (0x7ba7, &[0xf3, 0xe6], false),
+    (0x7bb8, &[0xf3, 0xe7], false),
+    (0x7bac, &[0xf3, 0xe8], false),
+    (0x7b9d, &[0xf3, 0xe9], false),
+    (0x7ba8, &[0xf3, 0xea], false),
+    (0x7b85, &[0xf3, 0xeb], false),
+    (0x7baa, &[0xf3, 0xec], false),
+    (0x7b9c, &[0xf3, 0xed], false),
+    (0x7ba2, &[0xf3, 0xee], false),
+    (0x7bab, &[0xf3, 0xef], false),
+    (0x7bb4, &[0xf3, 0xf0], false),
+    (0x7bd1, &[0xf3, 0xf1], false),
+    (0x7bc1, &[0xf3, 0xf2], false),
+    (0x7bcc, &[0xf3, 0xf3], false),
+    (0x7bdd, &[0xf3, 0xf4], false),
+    (0x7bda, &[0xf3, 0xf5], false),
+    (0x7be5, &[0xf3, 0xf6], false),
+    (0x7be6, &[0xf3, 0xf7], false),
+    (0x7bea, &[0xf3, 0xf8], false),
+    (0x7c0c, &[0xf3, 0xf9], false),
+    (0x7bfe, &[0xf3, 0xfa], false),
+    (0x7bfc, &[0xf3, 0xfb], false),
+    (0x7c0f, &[0xf3, 0xfc], false),
+    (0x7c16, &[0xf3, 0xfd], false),
+    (0x7c0b, &[0xf3, 0xfe], false),
+    (0x7c1f, &[0xf4, 0xa1], false),
+    (0x7c2a, &[0xf4, 0xa2], false),
+    (0x7c26, &[0xf4, 0xa3], false),
+    (0x7c38, &[0xf4, 0xa4], false),
+    (0x7c41, &[0xf4, 0xa5], false),
+    (0x7c40, &[0xf4, 0xa6], false),
+    (0x81fe, &[0xf4, 0xa7], false),
+    (0x8201, &[0xf4, 0xa8], false),
+    (0x8202, &[0xf4, 0xa9], false),
+    (0x8204, &[0xf4, 0xaa], false),
+    (0x81ec, &[0xf4, 0xab], false),
+    (0x8844, &[0xf4, 0xac], false),
+    (0x8221, &[0xf4, 0xad], false),
+    (0x8222, &[0xf4, 0xae], false),
+    (0x8223, &[0xf4, 0xaf], false),
+    (0x822d, &[0xf4, 0xb0], false),
+    (0x822f, &[0xf4, 0xb1], false),
+    (0x8228, &[0xf4, 0xb2], false),
+    (0x822b, &[0xf4, 0xb3], false),
+    (0x8238, &[0xf4, 0xb4], false),
+    (0x823b, &[0xf4, 0xb5], false),
+    (0x8233, &[0xf4, 0xb6], false),
+    (0x8234, &[0xf4, 0xb7], false),
+    (0x823e, &[0xf4, 0xb8], false),
+    (0x8244, &[0xf4, 0xb9], false),
+    (0x8249, &[0xf4, 0xba], false),
+    (0x824b, &[0xf4, 0xbb], false),
+    (0x824f, &[0xf4, 0xbc], false),
+    (0x825a, &[0xf4, 0xbd], false),
+    (0x825f, &[0xf4, 0xbe], false),
+    (0x8268, &[0xf4, 0xbf], false),
+    (0x887e, &[0xf4, 0xc0], false),
+    (0x8885, &[0xf4, 0xc1], false),
+    (0x8888, &[0xf4, 0xc2], false),
+    (0x88d8, &[0xf4, 0xc3], false),
+    (0x88df, &[0xf4, 0xc4], false),
+    (0x895e, &[0xf4, 0xc5], false),
+    (0x7f9d, &[0xf4, 0xc6], false),
+    (0x7f9f, &[0xf4, 0xc7], false),
+    (0x7fa7, &[0xf4, 0xc8], false),
+    (0x7faf, &[0xf4, 0xc9], false),
+    (0x7fb0, &[0xf4, 0xca], false),
+    (0x7fb2, &[0xf4, 0xcb], false),
+    (0x7c7c, &[0xf4, 0xcc], false),
+    (0x6549, &[0xf4, 0xcd], false),
+    (0x7c91, &[0xf4, 0xce], false),
+    (0x7c9d, &[0xf4, 0xcf], false),
+    (0x7c9c, &[0xf4, 0xd0], false),
+    (0x7c9e, &[0xf4, 0xd1], false),
+    (0x7ca2, &[0xf4, 0xd2], false),
+    (0x7cb2, &[0xf4, 0xd3], false),
+    (0x7cbc, &[0xf4, 0xd4], false),
+    (0x7cbd, &[0xf4, 0xd5], false),
+    (0x7cc1, &[0xf4, 0xd6], false),
+    (0x7cc7, &[0xf4, 0xd7], false),
+    (0x7ccc, &[0xf4, 0xd8], false),
+    (0x7ccd, &[0xf4, 0xd9], false),
+    (0x7cc8, &[0xf4, 0xda], false),
+    (0x7cc5, &[0xf4, 0xdb], false),
+    (0x7cd7, &[0xf4, 0xdc], false),
+    (0x7ce8, &[0xf4, 0xdd], false),
+    (0x826e, &[0xf4, 0xde], false),
+    (0x66a8, &[0xf4, 0xdf], false),
+    (0x7fbf, &[0xf4, 0xe0], false),
+    (0x7fce, &[0xf4, 0xe1], false),
+    (0x7fd5, &[0xf4, 0xe2], false),
+    (0x7fe5, &[0xf4, 0xe3], false),
+    (0x7fe1, &[0xf4, 0xe4], false),
+    (0x7fe6, &[0xf4, 0xe5], false),
+    (0x7fe9, &[0xf4, 0xe6], false),
+    (0x7fee, &[0xf4, 0xe7], false),
+    (0x7ff3, &[0xf4, 0xe8], false),
+    (0x7cf8, &[0xf4, 0xe9], false),
+    (0x7d77, &[0xf4, 0xea], false),
+    (0x7da6, &[0xf4, 0xeb], false),
+    (0x7dae, &[0xf4, 0xec], false),
+    (0x7e47, &[0xf4, 0xed], false),
+    (0x7e9b, &[0xf4, 0xee], false),
+    (0x9eb8, &[0xf4, 0xef], false),
+    (0x9eb4, &[0xf4, 0xf0], false),
+    (0x8d73, &[0xf4, 0xf1], false),
+    (0x8d84, &[0xf4, 0xf2], false),
+    (0x8d94, &[0xf4, 0xf3], false),
+    (0x8d91, &[0xf4, 0xf4], false),
+    (0x8db1, &[0xf4, 0xf5], false),
+    (0x8d67, &[0xf4, 0xf6], false),
+    (0x8d6d, &[0xf4, 0xf7], false),
+    (0x8c47, &[0xf4, 0xf8], false),
+    (0x8c49, &[0xf4, 0xf9], false),
+    (0x914a, &[0xf4, 0xfa], false),
+    (0x9150, &[0xf4, 0xfb], false),
+    (0x914e, &[0xf4, 0xfc], false),
+    (0x914f, &[0xf4, 0xfd], false),
+    (0x9164, &[0xf4, 0xfe], false),
+    (0x9162, &[0xf5, 0xa1], false),
+    (0x9161, &[0xf5, 0xa2], false),
+    (0x9170, &[0xf5, 0xa3], false),
+    (0x9169, &[0xf5, 0xa4], false),
+    (0x916f, &[0xf5, 0xa5], false),
+    (0x917d, &[0xf5, 0xa6], false),
+    (0x917e, &[0xf5, 0xa7], false),
+    (0x9172, &[0xf5, 0xa8], false),
+    (0x9174, &[0xf5, 0xa9], false),
+    (0x9179, &[0xf5, 0xaa], false),
+    (0x918c, &[0xf5, 0xab], false),
+    (0x9185, &[0xf5, 0xac], false),
+    (0x9190, &[0xf5, 0xad], false),
+    (0x918d, &[0xf5, 0xae], false),
+    (0x9191, &[0xf5, 0xaf], false),
+    (0x91a2, &[0xf5, 0xb0], false),
+    (0x91a3, &[0xf5, 0xb1], false),
+    (0x91aa, &[0xf5, 0xb2], false),
+    (0x91ad, &[0xf5, 0xb3], false),
+    (0x91ae, &[0xf5, 0xb4], false),
+    (0x91af, &[0xf5, 0xb5], false),
+    (0x91b5, &[0xf5, 0xb6], false),
+    (0x91b4, &[0xf5, 0xb7], false),
+    (0x91ba, &[0xf5, 0xb8], false),
+    (0x8c55, &[0xf5, 0xb9], false),
+    (0x9e7e, &[0xf5, 0xba], false),
+    (0x8db8, &[0xf5, 0xbb], false),
+    (0x8deb, &[0xf5, 0xbc], false),
+    (0x8e05, &[0xf5, 0xbd], false),
+    (0x8e59, &[0xf5, 0xbe], false),
+    (0x8e69, &[0xf5, 0xbf], false),
+    (0x8db5, &[0xf5, 0xc0], false),
+    (0x8dbf, &[0xf5, 0xc1], false),
+    (0x8dbc, &[0xf5, 0xc2], false),
+    (0x8dba, &[0xf5, 0xc3], false),
+    (0x8dc4, &[0xf5, 0xc4], false),
+    (0x8dd6, &[0xf5, 0xc5], false),
+    (0x8dd7, &[0xf5, 0xc6], false),
+    (0x8dda, &[0xf5, 0xc7], false),
+    (0x8dde, &[0xf5, 0xc8], false),
+    (0x8dce, &[0xf5, 0xc9], false),
+    (0x8dcf, &[0xf5, 0xca], false),
+    (0x8ddb, &[0xf5, 0xcb], false),
+    (0x8dc6, &[0xf5, 0xcc], false),
+    (0x8dec, &[0xf5, 0xcd], false),
+    (0x8df7, &[0xf5, 0xce], false),
+    (0x8df8, &[0xf5, 0xcf], false),
+    (0x8de3, &[0xf5, 0xd0], false),
+    (0x8df9, &[0xf5, 0xd1], false),
+    (0x8dfb, &[0xf5, 0xd2], false),
+    (0x8de4, &[0xf5, 0xd3], false),
+    (0x8e09, &[0xf5, 0xd4], false),
+    (0x8dfd, &[0xf5, 0xd5], false),
+    (0x8e14, &[0xf5, 0xd6], false),
+    (0x8e1d, &[0xf5, 0xd7], false),
+    (0x8e1f, &[0xf5, 0xd8], false),
+    (0x8e2c, &[0xf5, 0xd9], false),
+    (0x8e2e, &[0xf5, 0xda], false),
+    (0x8e23, &[0xf5, 0xdb], false),
+    (0x8e2f, &[0xf5, 0xdc], false),
+    (0x8e3a, &[0xf5, 0xdd], false),
+    (0x8e40, &[0xf5, 0xde], false),
+    (0x8e39, &[0xf5, 0xdf], false),
+    (0x8e35, &[0xf5, 0xe0], false),
+    (0x8e3d, &[0xf5, 0xe1], false),
+    (0x8e31, &[0xf5, 0xe2], false),
+    (0x8e49, &[0xf5, 0xe3], false),
+    (0x8e41, &[0xf5, 0xe4], false),
+    (0x8e42, &[0xf5, 0xe5], false),
+    (0x8e51, &[0xf5, 0xe6], false),
+    (0x8e52, &[0xf5, 0xe7], false),
+    (0x8e4a, &[0xf5, 0xe8], false),
+    (0x8e70, &[0xf5, 0xe9], false),
+    (0x8e76, &[0xf5, 0xea], false),
+    (0x8e7c, &[0xf5, 0xeb], false),
+    (0x8e6f, &[0xf5, 0xec], false),
+    (0x8e74, &[0xf5, 0xed], false),
+    (0x8e85, &[0xf5, 0xee], false),
+    (0x8e8f, &[0xf5, 0xef], false),
+    (0x8e94, &[0xf5, 0xf0], false),
+    (0x8e90, &[0xf5, 0xf1], false),
+    (0x8e9c, &[0xf5, 0xf2], false),
+    (0x8e9e, &[0xf5, 0xf3], false),
+    (0x8c78, &[0xf5, 0xf4], false),
+    (0x8c82, &[0xf5, 0xf5], false),
+    (0x8c8a, &[0xf5, 0xf6], false),
+    (0x8c85, &[0xf5, 0xf7], false),
+    (0x8c98, &[0xf5, 0xf8], false),
+    (0x8c94, &[0xf5, 0xf9], false),
+    (0x659b, &[0xf5, 0xfa], false),
+    (0x89d6, &[0xf5, 0xfb], false),
+    (0x89de, &[0xf5, 0xfc], false),
+    (0x89da, &[0xf5, 0xfd], false),
+    (0x89dc, &[0xf5, 0xfe], false),
+    (0x89e5, &[0xf6, 0xa1], false),
+    (0x89eb, &[0xf6, 0xa2], false),
+    (0x89ef, &[0xf6, 0xa3], false),
+    (0x8a3e, &[0xf6, 0xa4], false),
+    (0x8b26, &[0xf6, 0xa5], false),
+    (0x9753, &[0xf6, 0xa6], false),
+    (0x96e9, &[0xf6, 0xa7], false),
+    (0x96f3, &[0xf6, 0xa8], false),
+    (0x96ef, &[0xf6, 0xa9], false),
+    (0x9706, &[0xf6, 0xaa], false),
+    (0x9701, &[0xf6, 0xab], false),
+    (0x9708, &[0xf6, 0xac], false),
+    (0x970f, &[0xf6, 0xad], false),
+    (0x970e, &[0xf6, 0xae], false),
+    (0x972a, &[0xf6, 0xaf], false),
+    (0x972d, &[0xf6, 0xb0], false),
+    (0x9730, &[0xf6, 0xb1], false),
+    (0x973e, &[0xf6, 0xb2], false),
+    (0x9f80, &[0xf6, 0xb3], false),
+    (0x9f83, &[0xf6, 0xb4], false),
+    (0x9f85, &[0xf6, 0xb5], false),
+    (0x9f86, &[0xf6, 0xb6], false),
+    (0x9f87, &[0xf6, 0xb7], false),
+    (0x9f88, &[0xf6, 0xb8], false),
+    (0x9f89, &[0xf6, 0xb9], false),
+    (0x9f8a, &[0xf6, 0xba], false),
+    (0x9f8c, &[0xf6, 0xbb], false),
+    (0x9efe, &[0xf6, 0xbc], false),
+    (0x9f0b, &[0xf6, 0xbd], false),
+    (0x9f0d, &[0xf6, 0xbe], false),
+    (0x96b9, &[0xf6, 0xbf], false),
+    (0x96bc, &[0xf6, 0xc0], false),
+    (0x96bd, &[0xf6, 0xc1], false),
+    (0x96ce, &[0xf6, 0xc2], false),
+    (0x96d2, &[0xf6, 0xc3], false),
+    (0x77bf, &[0xf6, 0xc4], false),
+    (0x96e0, &[0xf6, 0xc5], false),
+    (0x928e, &[0xf6, 0xc6], false),
+    (0x92ae, &[0xf6, 0xc7], false),
+    (0x92c8, &[0xf6, 0xc8], false),
+    (0x933e, &[0xf6, 0xc9], false),
+    (0x936a, &[0xf6, 0xca], false),
+    (0x93ca, &[0xf6, 0xcb], false),
+    (0x938f, &[0xf6, 0xcc], false),
+    (0x943e, &[0xf6, 0xcd], false),
+    (0x946b, &[0xf6, 0xce], false),
+    (0x9c7f, &[0xf6, 0xcf], false),
+    (0x9c82, &[0xf6, 0xd0], false),
+    (0x9c85, &[0xf6, 0xd1], false),
+    (0x9c86, &[0xf6, 0xd2], false),
+    (0x9c87, &[0xf6, 0xd3], false),
+    (0x9c88, &[0xf6, 0xd4], false),
+    (0x7a23, &[0xf6, 0xd5], false),
+    (0x9c8b, &[0xf6, 0xd6], false),
+    (0x9c8e, &[0xf6, 0xd7], false),
+    (0x9c90, &[0xf6, 0xd8], false),
+    (0x9c91, &[0xf6, 0xd9], false),
+    (0x9c92, &[0xf6, 0xda], false),
+    (0x9c94, &[0xf6, 0xdb], false),
+    (0x9c95, &[0xf6, 0xdc], false),
+    (0x9c9a, &[0xf6, 0xdd], false),
+    (0x9c9b, &[0xf6, 0xde], false),
+    (0x9c9e, &[0xf6, 0xdf], false),
+    (0x9c9f, &[0xf6, 0xe0], false),
+    (0x9ca0, &[0xf6, 0xe1], false),
+    (0x9ca1, &[0xf6, 0xe2], false),
+    (0x9ca2, &[0xf6, 0xe3], false),
+    (0x9ca3, &[0xf6, 0xe4], false),
+    (0x9ca5, &[0xf6, 0xe5], false),
+    (0x9ca6, &[0xf6, 0xe6], false),
+    (0x9ca7, &[0xf6, 0xe7], false),
+    (0x9ca8, &[0xf6, 0xe8], false),
+    (0x9ca9, &[0xf6, 0xe9], false),
+    (0x9cab, &[0xf6, 0xea], false),
+    (0x9cad, &[0xf6, 0xeb], false),
+    (0x9cae, &[0xf6, 0xec], false),
+    (0x9cb0, &[0xf6, 0xed], false),
+    (0x9cb1, &[0xf6, 0xee], false),
+    (0x9cb2, &[0xf6, 0xef], false),
+    (0x9cb3, &[0xf6, 0xf0], false),
+    (0x9cb4, &[0xf6, 0xf1], false),
+    (0x9cb5, &[0xf6, 0xf2], false),
+    (0x9cb6, &[0xf6, 0xf3], false),
+    (0x9cb7, &[0xf6, 0xf4], false),
+    (0x9cba, &[0xf6, 0xf5], false),
+    (0x9cbb, &[0xf6, 0xf6], false),
+    (0x9cbc, &[0xf6, 0xf7], false),
+    (0x9cbd, &[0xf6, 0xf8], false),
+    (0x9cc4, &[0xf6, 0xf9], false),
+    (0x9cc5, &[0xf6, 0xfa], false),
+    (0x9cc6, &[0xf6, 0xfb], false),
+    (0x9cc7, &[0xf6, 0xfc], false),
+    (0x9cca, &[0xf6, 0xfd], false),
+    (0x9ccb, &[0xf6, 0xfe], false),
+    (0x9ccc, &[0xf7, 0xa1], false),
+    (0x9ccd, &[0xf7, 0xa2], false),
+    (0x9cce, &[0xf7, 0xa3], false),
+    (0x9ccf, &[0xf7, 0xa4], false),
+    (0x9cd0, &[0xf7, 0xa5], false),
+    (0x9cd3, &[0xf7, 0xa6], false),
+    (0x9cd4, &[0xf7, 0xa7], false),
+    (0x9cd5, &[0xf7, 0xa8], false),
+    (0x9cd7, &[0xf7, 0xa9], false),
+    (0x9cd8, &[0xf7, 0xaa], false),
+    (0x9cd9, &[0xf7, 0xab], false),
+    (0x9cdc, &[0xf7, 0xac], false),
+    (0x9cdd, &[0xf7, 0xad], false),
+    (0x9cdf, &[0xf7, 0xae], false),
+    (0x9ce2, &[0xf7, 0xaf], false),
+    (0x977c, &[0xf7, 0xb0], false),
+    (0x9785, &[0xf7, 0xb1], false),
+    (0x9791, &[0xf7, 0xb2], false),
+    (0x9792, &[0xf7, 0xb3], false),
+    (0x9794, &[0xf7, 0xb4], false),
+    (0x97af, &[0xf7, 0xb5], false),
+    (0x97ab, &[0xf7, 0xb6], false),
+    (0x97a3, &[0xf7, 0xb7], false),
+    (0x97b2, &[0xf7, 0xb8], false),
+    (0x97b4, &[0xf7, 0xb9], false),
+    (0x9ab1, &[0xf7, 0xba], false),
+    (0x9ab0, &[0xf7, 0xbb], false),
+    (0x9ab7, &[0xf7, 0xbc], false),
+    (0x9e58, &[0xf7, 0xbd], false),
+    (0x9ab6, &[0xf7, 0xbe], false),
+    (0x9aba, &[0xf7, 0xbf], false),
+    (0x9abc, &[0xf7, 0xc0], false),
+    (0x9ac1, &[0xf7, 0xc1], false),
+    (0x9ac0, &[0xf7, 0xc2], false),
+    (0x9ac5, &[0xf7, 0xc3], false),
+    (0x9ac2, &[0xf7, 0xc4], false),
+    (0x9acb, &[0xf7, 0xc5], false),
+    (0x9acc, &[0xf7, 0xc6], false),
+    (0x9ad1, &[0xf7, 0xc7], false),
+    (0x9b45, &[0xf7, 0xc8], false),
+    (0x9b43, &[0xf7, 0xc9], false),
+    (0x9b47, &[0xf7, 0xca], false),
+    (0x9b49, &[0xf7, 0xcb], false),
+    (0x9b48, &[0xf7, 0xcc], false),
+    (0x9b4d, &[0xf7, 0xcd], false),
+    (0x9b51, &[0xf7, 0xce], false),
+    (0x98e8, &[0xf7, 0xcf], false),
+    (0x990d, &[0xf7, 0xd0], false),
+    (0x992e, &[0xf7, 0xd1], false),
+    (0x9955, &[0xf7, 0xd2], false),
+    (0x9954, &[0xf7, 0xd3], false),
+    (0x9adf, &[0xf7, 0xd4], false),
+    (0x9ae1, &[0xf7, 0xd5], false),
+    (0x9ae6, &[0xf7, 0xd6], false),
+    (0x9aef, &[0xf7, 0xd7], false),
+    (0x9aeb, &[0xf7, 0xd8], false),
+    (0x9afb, &[0xf7, 0xd9], false),
+    (0x9aed, &[0xf7, 0xda], false),
+    (0x9af9, &[0xf7, 0xdb], false),
+    (0x9b08, &[0xf7, 0xdc], false),
+    (0x9b0f, &[0xf7, 0xdd], false),
+    (0x9b13, &[0xf7, 0xde], false),
+    (0x9b1f, &[0xf7, 0xdf], false),
+    (0x9b23, &[0xf7, 0xe0], false),
+    (0x9ebd, &[0xf7, 0xe1], false),
+    (0x9ebe, &[0xf7, 0xe2], false),
+    (0x7e3b, &[0xf7, 0xe3], false),
+    (0x9e82, &[0xf7, 0xe4], false),
+    (0x9e87, &[0xf7, 0xe5], false),
+    (0x9e88, &[0xf7, 0xe6], false),
+    (0x9e8b, &[0xf7, 0xe7], false),
+    (0x9e92, &[0xf7, 0xe8], false),
+    (0x93d6, &[0xf7, 0xe9], false),
+    (0x9e9d, &[0xf7, 0xea], false),
+    (0x9e9f, &[0xf7, 0xeb], false),
+    (0x9edb, &[0xf7, 0xec], false),
+    (0x9edc, &[0xf7, 0xed], false),
+    (0x9edd, &[0xf7, 0xee], false),
+    (0x9ee0, &[0xf7, 0xef], false),
+    (0x9edf, &[0xf7, 0xf0], false),
+    (0x9ee2, &[0xf7, 0xf1], false),
+    (0x9ee9, &[0xf7, 0xf2], false),
+    (0x9ee7, &[0xf7, 0xf3], false),
+    (0x9ee5, &[0xf7, 0xf4], false),
+    (0x9eea, &[0xf7, 0xf5], false),
+    (0x9eef, &[0xf7, 0xf6], false),
+    (0x9f22, &[0xf7, 0xf7], false),
+    (0x9f2c, &[0xf7, 0xf8], false),
+    (0x9f2f, &[0xf7, 0xf9], false),
+    (0x9f39, &[0xf7, 0xfa], false),
+    (0x9f37, &[0xf7, 0xfb], false),
+    (0x9f3d, &[0xf7, 0xfc], false),
+    (0x9f3e, &[0xf7, 0xfd], false),
+    (0x9f44, &[0xf7, 0xfe], false),
+];
+
+#[test]
+fn decode() -> Result<(), ErrorTrace> {
+    for (expected_code_point, test_byte_string, _) in MAC_CHINESE_SIMPLIFIED_TEST_VECTOR.iter() {
+        let mut decoder: DecoderMacChineseSimplified =
+            DecoderMacChineseSimplified::new(test_byte_string);
+
+        let test_code_point: u32 = match decoder.next() {
+            Some(Ok(code_point)) => code_point,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to decode MacChineseSimplified as code_point: U+{:04x}",
+                    *expected_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_code_point, *expected_code_point);
+    }
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<(), ErrorTrace> {
+    for (test_code_point, expected_byte_string, is_duplicate) in
+        MAC_CHINESE_SIMPLIFIED_TEST_VECTOR.iter()
+    {
+        let code_points: [u32; 1] = [*test_code_point];
+        let mut encoder: EncoderMacChineseSimplified =
+            EncoderMacChineseSimplified::new(&code_points);
+
+        let test_byte_string: Vec<u8> = match encoder.next() {
+            Some(Ok(byte_string)) => byte_string,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to encode code point: U+{:04x} as MacChineseSimplified",
+                    *test_code_point as u32
+                )));
+            }
+        };
+        if !is_duplicate {
+            assert_eq!(test_byte_string, *expected_byte_string);
+        }
+    }
+    Ok(())
+}
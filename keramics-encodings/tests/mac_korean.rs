@@ -0,0 +1,9023 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encodings::{DecoderMacKorean, EncoderMacKorean};
+
+const MAC_KOREAN_TEST_VECTOR: [(u32, &'static [u8], bool); 8962] = [
+    (0x0000, &[0x00], false),
+    (0x0001, &[0x01], false),
+    (0x0002, &[0x02], false),
+    (0x0003, &[0x03], false),
+    (0x0004, &[0x04], false),
+    (0x0005, &[0x05], false),
+    (0x0006, &[0x06], false),
+    (0x0007, &[0x07], false),
+    (0x0008, &[0x08], false),
+    (0x0009, &[0x09], false),
+    (0x000a, &[0x0a], false),
+    (0x000b, &[0x0b], false),
+    (0x000c, &[0x0c], false),
+    (0x000d, &[0x0d], false),
+    (0x000e, &[0x0e], false),
+    (0x000f, &[0x0f], false),
+    (0x0010, &[0x10], false),
+    (0x0011, &[0x11], false),
+    (0x0012, &[0x12], false),
+    (0x0013, &[0x13], false),
+    (0x0014, &[0x14], false),
+    (0x0015, &[0x15], false),
+    (0x0016, &[0x16], false),
+    (0x0017, &[0x17], false),
+    (0x0018, &[0x18], false),
+    (0x0019, &[0x19], false),
+    (0x001a, &[0x1a], false),
+    (0x001b, &[0x1b], false),
+    (0x001c, &[0x1c], false),
+    (0x001d, &[0x1d], false),
+    (0x001e, &[0x1e], false),
+    (0x001f, &[0x1f], false),
+    (0x0020, &[0x20], false),
+    (0x0021, &[0x21], false),
+    (0x0022, &[0x22], false),
+    (0x0023, &[0x23], false),
+    (0x0024, &[0x24], false),
+    (0x0025, &[0x25], false),
+    (0x0026, &[0x26], false),
+    (0x0027, &[0x27], false),
+    (0x0028, &[0x28], false),
+    (0x0029, &[0x29], false),
+    (0x002a, &[0x2a], false),
+    (0x002b, &[0x2b], false),
+    (0x002c, &[0x2c], false),
+    (0x002d, &[0x2d], false),
+    (0x002e, &[0x2e], false),
+    (0x002f, &[0x2f], false),
+    (0x0030, &[0x30], false),
+    (0x0031, &[0x31], false),
+    (0x0032, &[0x32], false),
+    (0x0033, &[0x33], false),
+    (0x0034, &[0x34], false),
+    (0x0035, &[0x35], false),
+    (0x0036, &[0x36], false),
+    (0x0037, &[0x37], false),
+    (0x0038, &[0x38], false),
+    (0x0039, &[0x39], false),
+    (0x003a, &[0x3a], false),
+    (0x003b, &[0x3b], false),
+    (0x003c, &[0x3c], false),
+    (0x003d, &[0x3d], false),
+    (0x003e, &[0x3e], false),
+    (0x003f, &[0x3f], false),
+    (0x0040, &[0x40], false),
+    (0x0041, &[0x41], false),
+    (0x0042, &[0x42], false),
+    (0x0043, &[0x43], false),
+    (0x0044, &[0x44], false),
+    (0x0045, &[0x45], false),
+    (0x0046, &[0x46], false),
+    (0x0047, &[0x47], false),
+    (0x0048, &[0x48], false),
+    (0x0049, &[0x49], false),
+    (0x004a, &[0x4a], false),
+    (0x004b, &[0x4b], false),
+    (0x004c, &[0x4c], false),
+    (0x004d, &[0x4d], false),
+    (0x004e, &[0x4e], false),
+    (0x004f, &[0x4f], false),
+    (0x0050, &[0x50], false),
+    (0x0051, &[0x51], false),
+    (0x0052, &[0x52], false),
+    (0x0053, &[0x53], false),
+    (0x0054, &[0x54], false),
+    (0x0055, &[0x55], false),
+    (0x0056, &[0x56], false),
+    (0x0057, &[0x57], false),
+    (0x0058, &[0x58], false),
+    (0x0059, &[0x59], false),
+    (0x005a, &[0x5a], false),
+    (0x005b, &[0x5b], false),
+    (0x005c, &[0x5c], false),
+    (0x005d, &[0x5d], false),
+    (0x005e, &[0x5e], false),
+    (0x005f, &[0x5f], false),
+    (0x0060, &[0x60], false),
+    (0x0061, &[0x61], false),
+    (0x0062, &[0x62], false),
+    (0x0063, &[0x63], false),
+    (0x0064, &[0x64], false),
+    (0x0065, &[0x65], false),
+    (0x0066, &[0x66], false),
+    (0x0067, &[0x67], false),
+    (0x0068, &[0x68], false),
+    (0x0069, &[0x69], false),
+    (0x006a, &[0x6a], false),
+    (0x006b, &[0x6b], false),
+    (0x006c, &[0x6c], false),
+    (0x006d, &[0x6d], false),
+    (0x006e, &[0x6e], false),
+    (0x006f, &[0x6f], false),
+    (0x0070, &[0x70], false),
+    (0x0071, &[0x71], false),
+    (0x0072, &[0x72], false),
+    (0x0073, &[0x73], false),
+    (0x0074, &[0x74], false),
+    (0x0075, &[0x75], false),
+    (0x0076, &[0x76], false),
+    (0x0077, &[0x77], false),
+    (0x0078, &[0x78], false),
+    (0x0079, &[0x79], false),
+    (0x007a, &[0x7a], false),
+    (0x007b, &[0x7b], false),
+    (0x007c, &[0x7c], false),
+    (0x007d, &[0x7d], false),
+    (0x007e, &[0x7e], false),
+    (0x007f, &[0x7f], false),
+    (0x00a0, &[0x80], false),
+    (0x20a9, &[0x81], false),
+    (0x2013, &[0x82], true),
+    (0x00a9, &[0x83], false),
+    (0xff3f, &[0x84], true),
+    (0x2026, &[0xff], true),
+    (0x300c, &[0xa1, 0x41], true),
+    (0x300d, &[0xa1, 0x42], true),
+    (0x300c, &[0xa1, 0x43], true),
+    (0x300d, &[0xa1, 0x44], true),
+    (0x300c, &[0xa1, 0x45], true),
+    (0x300d, &[0xa1, 0x46], true),
+    (0x300e, &[0xa1, 0x47], true),
+    (0x300f, &[0xa1, 0x48], true),
+    (0x300a, &[0xa1, 0x49], true),
+    (0x300b, &[0xa1, 0x4a], true),
+    (0x3008, &[0xa1, 0x4b], true),
+    (0x3009, &[0xa1, 0x4c], true),
+    (0xfe59, &[0xa1, 0x4d], false),
+    (0xfe5a, &[0xa1, 0x4e], false),
+    (0xfe59, &[0xa1, 0x4f], true),
+    (0xfe5a, &[0xa1, 0x50], true),
+    (0x2985, &[0xa1, 0x51], true),
+    (0x2986, &[0xa1, 0x52], true),
+    (0x2985, &[0xa1, 0x53], true),
+    (0x2986, &[0xa1, 0x54], true),
+    (0x2985, &[0xa1, 0x55], true),
+    (0x2986, &[0xa1, 0x56], true),
+    (0x0028, &[0xa1, 0x57], true),
+    (0x0029, &[0xa1, 0x58], true),
+    (0x2985, &[0xa1, 0x59], false),
+    (0x2986, &[0xa1, 0x5a], false),
+    (0x3010, &[0xa1, 0x5b], true),
+    (0x3011, &[0xa1, 0x5c], true),
+    (0x3016, &[0xa1, 0x5d], false),
+    (0x3017, &[0xa1, 0x5e], false),
+    (0x3018, &[0xa1, 0x5f], false),
+    (0x3019, &[0xa1, 0x60], false),
+    (0x005b, &[0xa1, 0x61], true),
+    (0x005d, &[0xa1, 0x62], true),
+    (0x005b, &[0xa1, 0x63], true),
+    (0x005d, &[0xa1, 0x64], true),
+    (0x2985, &[0xa1, 0x65], true),
+    (0x2986, &[0xa1, 0x66], true),
+    (0x2020, &[0xa1, 0x67], true),
+    (0x2021, &[0xa1, 0x68], true),
+    (0x2020, &[0xa1, 0x69], true),
+    (0x2021, &[0xa1, 0x6a], true),
+    (0x2020, &[0xa1, 0x6b], true),
+    (0x002a, &[0xa1, 0x6c], true),
+    (0x2051, &[0xa1, 0x6d], false),
+    (0xf840, &[0xa1, 0x6e], false),
+    (0x201f, &[0xa1, 0x6f], false),
+    (0x201b, &[0xa1, 0x70], false),
+    (0x207a, &[0xa1, 0x71], false),
+    (0x207b, &[0xa1, 0x72], false),
+    (0x00d7, &[0xa1, 0x73], true),
+    (0x221e, &[0xa1, 0x74], true),
+    (0x223d, &[0xa1, 0x75], true),
+    (0x00b1, &[0xa1, 0x76], true),
+    (0x2213, &[0xa1, 0x77], true),
+    (0x003e, &[0xa1, 0x78], true),
+    (0x003c, &[0xa1, 0x79], true),
+    (0x207c, &[0xa1, 0x7a], false),
+    (0x2260, &[0xa1, 0x7b], true),
+    (0x207d, &[0xa1, 0x7c], false),
+    (0x207e, &[0xa1, 0x7d], false),
+    (0x007b, &[0xa1, 0x81], true),
+    (0x007d, &[0xa1, 0x82], true),
+    (0x005b, &[0xa1, 0x83], true),
+    (0x005d, &[0xa1, 0x84], true),
+    (0x2229, &[0xa1, 0x85], true),
+    (0x222a, &[0xa1, 0x86], true),
+    (0x2282, &[0xa1, 0x87], true),
+    (0x2208, &[0xa1, 0x88], true),
+    (0x2211, &[0xa1, 0x89], true),
+    (0x0021, &[0xa1, 0x8a], true),
+    (0x3007, &[0xa1, 0x8b], false),
+    (0x4e00, &[0xa1, 0x8c], true),
+    (0x4e8c, &[0xa1, 0x8d], true),
+    (0x4e09, &[0xa1, 0x8e], true),
+    (0x56db, &[0xa1, 0x8f], true),
+    (0x4e94, &[0xa1, 0x90], true),
+    (0x516d, &[0xa1, 0x91], true),
+    (0x4e03, &[0xa1, 0x92], true),
+    (0x516b, &[0xa1, 0x93], true),
+    (0x4e5d, &[0xa1, 0x94], true),
+    (0x5341, &[0xa1, 0x95], true),
+    (0x204c, &[0xa1, 0x96], false),
+    (0x204d, &[0xa1, 0x97], false),
+    (0x02bc, &[0xa1, 0x98], false),
+    (0x2997, &[0xa1, 0x99], false),
+    (0x2998, &[0xa1, 0x9a], false),
+    (0xff0a, &[0xa1, 0x9c], true),
+    (0x2051, &[0xa1, 0x9d], true),
+    (0xff0a, &[0xa1, 0x9e], true),
+    (0xff0a, &[0xa1, 0x9f], true),
+    (0x2051, &[0xa1, 0xa0], true),
+    (0x3000, &[0xa1, 0xa1], false),
+    (0x3001, &[0xa1, 0xa2], false),
+    (0x3002, &[0xa1, 0xa3], false),
+    (0x00b7, &[0xa1, 0xa4], false),
+    (0x2025, &[0xa1, 0xa5], false),
+    (0x2026, &[0xa1, 0xa6], false),
+    (0x00a8, &[0xa1, 0xa7], false),
+    (0x3003, &[0xa1, 0xa8], false),
+    (0x2013, &[0xa1, 0xa9], false),
+    (0x2014, &[0xa1, 0xaa], false),
+    (0x2016, &[0xa1, 0xab], false),
+    (0xff3c, &[0xa1, 0xac], false),
+    (0x301c, &[0xa1, 0xad], false),
+    (0x2018, &[0xa1, 0xae], false),
+    (0x2019, &[0xa1, 0xaf], false),
+    (0x201c, &[0xa1, 0xb0], false),
+    (0x201d, &[0xa1, 0xb1], false),
+    (0x3014, &[0xa1, 0xb2], false),
+    (0x3015, &[0xa1, 0xb3], false),
+    (0x3008, &[0xa1, 0xb4], false),
+    (0x3009, &[0xa1, 0xb5], false),
+    (0x300a, &[0xa1, 0xb6], false),
+    (0x300b, &[0xa1, 0xb7], false),
+    (0x300c, &[0xa1, 0xb8], false),
+    (0x300d, &[0xa1, 0xb9], false),
+    (0x300e, &[0xa1, 0xba], false),
+    (0x300f, &[0xa1, 0xbb], false),
+    (0x3010, &[0xa1, 0xbc], false),
+    (0x3011, &[0xa1, 0xbd], false),
+    (0x00b1, &[0xa1, 0xbe], false),
+    (0x00d7, &[0xa1, 0xbf], false),
+    (0x00f7, &[0xa1, 0xc0], false),
+    (0x2260, &[0xa1, 0xc1], false),
+    (0x2264, &[0xa1, 0xc2], false),
+    (0x2265, &[0xa1, 0xc3], false),
+    (0x221e, &[0xa1, 0xc4], false),
+    (0x2234, &[0xa1, 0xc5], false),
+    (0x00b0, &[0xa1, 0xc6], false),
+    (0x2032, &[0xa1, 0xc7], false),
+    (0x2033, &[0xa1, 0xc8], false),
+    (0x2103, &[0xa1, 0xc9], false),
+    (0x212b, &[0xa1, 0xca], false),
+    (0x00a2, &[0xa1, 0xcb], false),
+    (0x00a3, &[0xa1, 0xcc], false),
+    (0x00a5, &[0xa1, 0xcd], false),
+    (0x2642, &[0xa1, 0xce], false),
+    (0x2640, &[0xa1, 0xcf], false),
+    (0x2220, &[0xa1, 0xd0], false),
+    (0x22a5, &[0xa1, 0xd1], false),
+    (0x2312, &[0xa1, 0xd2], false),
+    (0x2202, &[0xa1, 0xd3], false),
+    (0x2207, &[0xa1, 0xd4], false),
+    (0x2261, &[0xa1, 0xd5], false),
+    (0x2252, &[0xa1, 0xd6], false),
+    (0x00a7, &[0xa1, 0xd7], false),
+    (0x203b, &[0xa1, 0xd8], false),
+    (0x2606, &[0xa1, 0xd9], false),
+    (0x2605, &[0xa1, 0xda], false),
+    (0x25cb, &[0xa1, 0xdb], false),
+    (0x25cf, &[0xa1, 0xdc], false),
+    (0x25ce, &[0xa1, 0xdd], false),
+    (0x25c7, &[0xa1, 0xde], false),
+    (0x25c6, &[0xa1, 0xdf], false),
+    (0x25a1, &[0xa1, 0xe0], false),
+    (0x25a0, &[0xa1, 0xe1], false),
+    (0x25b3, &[0xa1, 0xe2], false),
+    (0x25b2, &[0xa1, 0xe3], false),
+    (0x25bd, &[0xa1, 0xe4], false),
+    (0x25bc, &[0xa1, 0xe5], false),
+    (0x2192, &[0xa1, 0xe6], false),
+    (0x2190, &[0xa1, 0xe7], false),
+    (0x2191, &[0xa1, 0xe8], false),
+    (0x2193, &[0xa1, 0xe9], false),
+    (0x2194, &[0xa1, 0xea], false),
+    (0x3013, &[0xa1, 0xeb], false),
+    (0x226a, &[0xa1, 0xec], false),
+    (0x226b, &[0xa1, 0xed], false),
+    (0x221a, &[0xa1, 0xee], false),
+    (0x223d, &[0xa1, 0xef], false),
+    (0x221d, &[0xa1, 0xf0], false),
+    (0x2235, &[0xa1, 0xf1], false),
+    (0x222b, &[0xa1, 0xf2], false),
+    (0x222c, &[0xa1, 0xf3], false),
+    (0x2208, &[0xa1, 0xf4], false),
+    (0x220b, &[0xa1, 0xf5], false),
+    (0x2286, &[0xa1, 0xf6], false),
+    (0x2287, &[0xa1, 0xf7], false),
+    (0x2282, &[0xa1, 0xf8], false),
+    (0x2283, &[0xa1, 0xf9], false),
+    (0x222a, &[0xa1, 0xfa], false),
+    (0x2229, &[0xa1, 0xfb], false),
+    (0x2227, &[0xa1, 0xfc], false),
+    (0x2228, &[0xa1, 0xfd], false),
+    (0x00ac, &[0xa1, 0xfe], false),
+    (0x2985, &[0xa2, 0x41], true),
+    (0x2986, &[0xa2, 0x42], true),
+    (0x2985, &[0xa2, 0x43], true),
+    (0x2986, &[0xa2, 0x44], true),
+    (0xfe59, &[0xa2, 0x45], true),
+    (0xfe5a, &[0xa2, 0x46], true),
+    (0x3016, &[0xa2, 0x47], true),
+    (0x3017, &[0xa2, 0x48], true),
+    (0x3010, &[0xa2, 0x49], true),
+    (0x3011, &[0xa2, 0x4a], true),
+    (0x0028, &[0xa2, 0x4b], true),
+    (0x0029, &[0xa2, 0x4c], true),
+    (0x21d2, &[0xa2, 0xa1], false),
+    (0x21d4, &[0xa2, 0xa2], false),
+    (0x2200, &[0xa2, 0xa3], false),
+    (0x2203, &[0xa2, 0xa4], false),
+    (0x00b4, &[0xa2, 0xa5], false),
+    (0x02dc, &[0xa2, 0xa6], false),
+    (0x02c7, &[0xa2, 0xa7], false),
+    (0x02d8, &[0xa2, 0xa8], false),
+    (0x02dd, &[0xa2, 0xa9], false),
+    (0x02da, &[0xa2, 0xaa], false),
+    (0x02d9, &[0xa2, 0xab], false),
+    (0x00b8, &[0xa2, 0xac], false),
+    (0x02db, &[0xa2, 0xad], false),
+    (0x00a1, &[0xa2, 0xae], false),
+    (0x00bf, &[0xa2, 0xaf], false),
+    (0x02d0, &[0xa2, 0xb0], false),
+    (0x222e, &[0xa2, 0xb1], false),
+    (0x2211, &[0xa2, 0xb2], false),
+    (0x220f, &[0xa2, 0xb3], false),
+    (0x00a4, &[0xa2, 0xb4], false),
+    (0x2109, &[0xa2, 0xb5], false),
+    (0x2030, &[0xa2, 0xb6], false),
+    (0x25c1, &[0xa2, 0xb7], false),
+    (0x25c0, &[0xa2, 0xb8], false),
+    (0x25b7, &[0xa2, 0xb9], false),
+    (0x25b6, &[0xa2, 0xba], false),
+    (0x2664, &[0xa2, 0xbb], false),
+    (0x2660, &[0xa2, 0xbc], false),
+    (0x2661, &[0xa2, 0xbd], false),
+    (0x2665, &[0xa2, 0xbe], false),
+    (0x2667, &[0xa2, 0xbf], false),
+    (0x2663, &[0xa2, 0xc0], false),
+    (0x25c9, &[0xa2, 0xc1], false),
+    (0x25c8, &[0xa2, 0xc2], false),
+    (0x25a3, &[0xa2, 0xc3], false),
+    (0x25d0, &[0xa2, 0xc4], false),
+    (0x25d1, &[0xa2, 0xc5], false),
+    (0x2592, &[0xa2, 0xc6], false),
+    (0x25a4, &[0xa2, 0xc7], false),
+    (0x25a5, &[0xa2, 0xc8], false),
+    (0x25a8, &[0xa2, 0xc9], false),
+    (0x25a7, &[0xa2, 0xca], false),
+    (0x25a6, &[0xa2, 0xcb], false),
+    (0x25a9, &[0xa2, 0xcc], false),
+    (0x2668, &[0xa2, 0xcd], false),
+    (0x260f, &[0xa2, 0xce], false),
+    (0x260e, &[0xa2, 0xcf], false),
+    (0x261c, &[0xa2, 0xd0], false),
+    (0x261e, &[0xa2, 0xd1], false),
+    (0x00b6, &[0xa2, 0xd2], false),
+    (0x2020, &[0xa2, 0xd3], false),
+    (0x2021, &[0xa2, 0xd4], false),
+    (0x2195, &[0xa2, 0xd5], false),
+    (0x2197, &[0xa2, 0xd6], false),
+    (0x2199, &[0xa2, 0xd7], false),
+    (0x2196, &[0xa2, 0xd8], false),
+    (0x2198, &[0xa2, 0xd9], false),
+    (0x266d, &[0xa2, 0xda], false),
+    (0x2669, &[0xa2, 0xdb], false),
+    (0x266a, &[0xa2, 0xdc], false),
+    (0x266c, &[0xa2, 0xdd], false),
+    (0x327f, &[0xa2, 0xde], false),
+    (0x321c, &[0xa2, 0xdf], false),
+    (0x2116, &[0xa2, 0xe0], false),
+    (0x33c7, &[0xa2, 0xe1], false),
+    (0x2122, &[0xa2, 0xe2], false),
+    (0x33c2, &[0xa2, 0xe3], false),
+    (0x33d8, &[0xa2, 0xe4], false),
+    (0x2121, &[0xa2, 0xe5], false),
+    (0x00b6, &[0xa2, 0xfa], true),
+    (0x2016, &[0xa2, 0xfb], true),
+    (0x2016, &[0xa2, 0xfc], true),
+    (0x22ee, &[0xa2, 0xfd], false),
+    (0x2237, &[0xa2, 0xfe], false),
+    (0x278a, &[0xa3, 0x55], false),
+    (0x278b, &[0xa3, 0x56], false),
+    (0x278c, &[0xa3, 0x57], false),
+    (0x278d, &[0xa3, 0x58], false),
+    (0x278e, &[0xa3, 0x59], false),
+    (0x278f, &[0xa3, 0x5a], false),
+    (0x2790, &[0xa3, 0x5b], false),
+    (0x2791, &[0xa3, 0x5c], false),
+    (0x2792, &[0xa3, 0x5d], false),
+    (0x2793, &[0xa3, 0x5e], false),
+    (0x24eb, &[0xa3, 0x5f], true),
+    (0x24ec, &[0xa3, 0x60], true),
+    (0x24ed, &[0xa3, 0x61], true),
+    (0x24ee, &[0xa3, 0x62], true),
+    (0x24ef, &[0xa3, 0x63], true),
+    (0x24f0, &[0xa3, 0x64], true),
+    (0x24f1, &[0xa3, 0x65], true),
+    (0x24f2, &[0xa3, 0x66], true),
+    (0x24f3, &[0xa3, 0x67], true),
+    (0x24f4, &[0xa3, 0x68], true),
+    (0x24b6, &[0xa3, 0x86], false),
+    (0x24b7, &[0xa3, 0x87], false),
+    (0x24b8, &[0xa3, 0x88], false),
+    (0x24b9, &[0xa3, 0x89], false),
+    (0x24ba, &[0xa3, 0x8a], false),
+    (0x24bb, &[0xa3, 0x8b], false),
+    (0x24bc, &[0xa3, 0x8c], false),
+    (0x24bd, &[0xa3, 0x8d], false),
+    (0x24be, &[0xa3, 0x8e], false),
+    (0x24bf, &[0xa3, 0x8f], false),
+    (0x24c0, &[0xa3, 0x90], false),
+    (0x24c1, &[0xa3, 0x91], false),
+    (0x24c2, &[0xa3, 0x92], false),
+    (0x24c3, &[0xa3, 0x93], false),
+    (0x24c4, &[0xa3, 0x94], false),
+    (0x24c5, &[0xa3, 0x95], false),
+    (0x24c6, &[0xa3, 0x96], false),
+    (0x24c7, &[0xa3, 0x97], false),
+    (0x24c8, &[0xa3, 0x98], false),
+    (0x24c9, &[0xa3, 0x99], false),
+    (0x24ca, &[0xa3, 0x9a], false),
+    (0x24cb, &[0xa3, 0x9b], false),
+    (0x24cc, &[0xa3, 0x9c], false),
+    (0x24cd, &[0xa3, 0x9d], false),
+    (0x24ce, &[0xa3, 0x9e], false),
+    (0x24cf, &[0xa3, 0x9f], false),
+    (0xff01, &[0xa3, 0xa1], false),
+    (0xff02, &[0xa3, 0xa2], false),
+    (0xff03, &[0xa3, 0xa3], false),
+    (0xff04, &[0xa3, 0xa4], false),
+    (0xff05, &[0xa3, 0xa5], false),
+    (0xff06, &[0xa3, 0xa6], false),
+    (0xff07, &[0xa3, 0xa7], false),
+    (0xff08, &[0xa3, 0xa8], false),
+    (0xff09, &[0xa3, 0xa9], false),
+    (0xff0a, &[0xa3, 0xaa], false),
+    (0xff0b, &[0xa3, 0xab], false),
+    (0xff0c, &[0xa3, 0xac], false),
+    (0xff0d, &[0xa3, 0xad], false),
+    (0xff0e, &[0xa3, 0xae], false),
+    (0xff0f, &[0xa3, 0xaf], false),
+    (0xff10, &[0xa3, 0xb0], false),
+    (0xff11, &[0xa3, 0xb1], false),
+    (0xff12, &[0xa3, 0xb2], false),
+    (0xff13, &[0xa3, 0xb3], false),
+    (0xff14, &[0xa3, 0xb4], false),
+    (0xff15, &[0xa3, 0xb5], false),
+    (0xff16, &[0xa3, 0xb6], false),
+    (0xff17, &[0xa3, 0xb7], false),
+    (0xff18, &[0xa3, 0xb8], false),
+    (0xff19, &[0xa3, 0xb9], false),
+    (0xff1a, &[0xa3, 0xba], false),
+    (0xff1b, &[0xa3, 0xbb], false),
+    (0xff1c, &[0xa3, 0xbc], false),
+    (0xff1d, &[0xa3, 0xbd], false),
+    (0xff1e, &[0xa3, 0xbe], false),
+    (0xff1f, &[0xa3, 0xbf], false),
+    (0xff20, &[0xa3, 0xc0], false),
+    (0xff21, &[0xa3, 0xc1], false),
+    (0xff22, &[0xa3, 0xc2], false),
+    (0xff23, &[0xa3, 0xc3], false),
+    (0xff24, &[0xa3, 0xc4], false),
+    (0xff25, &[0xa3, 0xc5], false),
+    (0xff26, &[0xa3, 0xc6], false),
+    (0xff27, &[0xa3, 0xc7], false),
+    (0xff28, &[0xa3, 0xc8], false),
+    (0xff29, &[0xa3, 0xc9], false),
+    (0xff2a, &[0xa3, 0xca], false),
+    (0xff2b, &[0xa3, 0xcb], false),
+    (0xff2c, &[0xa3, 0xcc], false),
+    (0xff2d, &[0xa3, 0xcd], false),
+    (0xff2e, &[0xa3, 0xce], false),
+    (0xff2f, &[0xa3, 0xcf], false),
+    (0xff30, &[0xa3, 0xd0], false),
+    (0xff31, &[0xa3, 0xd1], false),
+    (0xff32, &[0xa3, 0xd2], false),
+    (0xff33, &[0xa3, 0xd3], false),
+    (0xff34, &[0xa3, 0xd4], false),
+    (0xff35, &[0xa3, 0xd5], false),
+    (0xff36, &[0xa3, 0xd6], false),
+    (0xff37, &[0xa3, 0xd7], false),
+    (0xff38, &[0xa3, 0xd8], false),
+    (0xff39, &[0xa3, 0xd9], false),
+    (0xff3a, &[0xa3, 0xda], false),
+    (0xff3b, &[0xa3, 0xdb], false),
+    (0xffe6, &[0xa3, 0xdc], false),
+    (0xff3d, &[0xa3, 0xdd], false),
+    (0xff3e, &[0xa3, 0xde], false),
+    (0xff3f, &[0xa3, 0xdf], false),
+    (0xff40, &[0xa3, 0xe0], false),
+    (0xff41, &[0xa3, 0xe1], false),
+    (0xff42, &[0xa3, 0xe2], false),
+    (0xff43, &[0xa3, 0xe3], false),
+    (0xff44, &[0xa3, 0xe4], false),
+    (0xff45, &[0xa3, 0xe5], false),
+    (0xff46, &[0xa3, 0xe6], false),
+    (0xff47, &[0xa3, 0xe7], false),
+    (0xff48, &[0xa3, 0xe8], false),
+    (0xff49, &[0xa3, 0xe9], false),
+    (0xff4a, &[0xa3, 0xea], false),
+    (0xff4b, &[0xa3, 0xeb], false),
+    (0xff4c, &[0xa3, 0xec], false),
+    (0xff4d, &[0xa3, 0xed], false),
+    (0xff4e, &[0xa3, 0xee], false),
+    (0xff4f, &[0xa3, 0xef], false),
+    (0xff50, &[0xa3, 0xf0], false),
+    (0xff51, &[0xa3, 0xf1], false),
+    (0xff52, &[0xa3, 0xf2], false),
+    (0xff53, &[0xa3, 0xf3], false),
+    (0xff54, &[0xa3, 0xf4], false),
+    (0xff55, &[0xa3, 0xf5], false),
+    (0xff56, &[0xa3, 0xf6], false),
+    (0xff57, &[0xa3, 0xf7], false),
+    (0xff58, &[0xa3, 0xf8], false),
+    (0xff59, &[0xa3, 0xf9], false),
+    (0xff5a, &[0xa3, 0xfa], false),
+    (0xff5b, &[0xa3, 0xfb], false),
+    (0xff5c, &[0xa3, 0xfc], false),
+    (0xff5d, &[0xa3, 0xfd], false),
+    (0x203e, &[0xa3, 0xfe], false),
+    (0x278a, &[0xa4, 0x69], true),
+    (0x278b, &[0xa4, 0x6a], true),
+    (0x278c, &[0xa4, 0x6b], true),
+    (0x278d, &[0xa4, 0x6c], true),
+    (0x278e, &[0xa4, 0x6d], true),
+    (0x278f, &[0xa4, 0x6e], true),
+    (0x2790, &[0xa4, 0x6f], true),
+    (0x2791, &[0xa4, 0x70], true),
+    (0x2792, &[0xa4, 0x71], true),
+    (0x2793, &[0xa4, 0x72], true),
+    (0x24eb, &[0xa4, 0x73], true),
+    (0x24ec, &[0xa4, 0x74], true),
+    (0x24ed, &[0xa4, 0x75], true),
+    (0x24ee, &[0xa4, 0x76], true),
+    (0x24ef, &[0xa4, 0x77], true),
+    (0x24f0, &[0xa4, 0x78], true),
+    (0x24f1, &[0xa4, 0x79], true),
+    (0x24f2, &[0xa4, 0x7a], true),
+    (0x24f3, &[0xa4, 0x7b], true),
+    (0x24f4, &[0xa4, 0x7c], true),
+    (0x2a26, &[0xa4, 0x7d], false),
+    (0x227a, &[0xa4, 0x81], false),
+    (0x227b, &[0xa4, 0x82], false),
+    (0x22ce, &[0xa4, 0x83], false),
+    (0x22cf, &[0xa4, 0x84], false),
+    (0x2280, &[0xa4, 0x85], false),
+    (0x2281, &[0xa4, 0x86], false),
+    (0x2270, &[0xa4, 0x87], false),
+    (0x2271, &[0xa4, 0x88], false),
+    (0x2272, &[0xa4, 0x89], false),
+    (0x2273, &[0xa4, 0x8a], false),
+    (0x2ac5, &[0xa4, 0x8b], false),
+    (0x2acb, &[0xa4, 0x8c], false),
+    (0x2ac6, &[0xa4, 0x8d], false),
+    (0x2acc, &[0xa4, 0x8e], false),
+    (0x2276, &[0xa4, 0x8f], false),
+    (0x2277, &[0xa4, 0x90], false),
+    (0x2279, &[0xa4, 0x91], false),
+    (0x22da, &[0xa4, 0x92], false),
+    (0x22db, &[0xa4, 0x93], false),
+    (0x2a8b, &[0xa4, 0x94], false),
+    (0x2a8c, &[0xa4, 0x95], false),
+    (0x2a91, &[0xa4, 0x96], false),
+    (0x2a92, &[0xa4, 0x97], false),
+    (0x2222, &[0xa4, 0x98], true),
+    (0x2245, &[0xa4, 0x99], false),
+    (0x2243, &[0xa4, 0x9a], false),
+    (0x2248, &[0xa4, 0x9b], false),
+    (0x29a3, &[0xa4, 0x9c], false),
+    (0x22a4, &[0xa4, 0x9d], false),
+    (0x3131, &[0xa4, 0xa1], false),
+    (0x3132, &[0xa4, 0xa2], false),
+    (0x3133, &[0xa4, 0xa3], false),
+    (0x3134, &[0xa4, 0xa4], false),
+    (0x3135, &[0xa4, 0xa5], false),
+    (0x3136, &[0xa4, 0xa6], false),
+    (0x3137, &[0xa4, 0xa7], false),
+    (0x3138, &[0xa4, 0xa8], false),
+    (0x3139, &[0xa4, 0xa9], false),
+    (0x313a, &[0xa4, 0xaa], false),
+    (0x313b, &[0xa4, 0xab], false),
+    (0x313c, &[0xa4, 0xac], false),
+    (0x313d, &[0xa4, 0xad], false),
+    (0x313e, &[0xa4, 0xae], false),
+    (0x313f, &[0xa4, 0xaf], false),
+    (0x3140, &[0xa4, 0xb0], false),
+    (0x3141, &[0xa4, 0xb1], false),
+    (0x3142, &[0xa4, 0xb2], false),
+    (0x3143, &[0xa4, 0xb3], false),
+    (0x3144, &[0xa4, 0xb4], false),
+    (0x3145, &[0xa4, 0xb5], false),
+    (0x3146, &[0xa4, 0xb6], false),
+    (0x3147, &[0xa4, 0xb7], false),
+    (0x3148, &[0xa4, 0xb8], false),
+    (0x3149, &[0xa4, 0xb9], false),
+    (0x314a, &[0xa4, 0xba], false),
+    (0x314b, &[0xa4, 0xbb], false),
+    (0x314c, &[0xa4, 0xbc], false),
+    (0x314d, &[0xa4, 0xbd], false),
+    (0x314e, &[0xa4, 0xbe], false),
+    (0x314f, &[0xa4, 0xbf], false),
+    (0x3150, &[0xa4, 0xc0], false),
+    (0x3151, &[0xa4, 0xc1], false),
+    (0x3152, &[0xa4, 0xc2], false),
+    (0x3153, &[0xa4, 0xc3], false),
+    (0x3154, &[0xa4, 0xc4], false),
+    (0x3155, &[0xa4, 0xc5], false),
+    (0x3156, &[0xa4, 0xc6], false),
+    (0x3157, &[0xa4, 0xc7], false),
+    (0x3158, &[0xa4, 0xc8], false),
+    (0x3159, &[0xa4, 0xc9], false),
+    (0x315a, &[0xa4, 0xca], false),
+    (0x315b, &[0xa4, 0xcb], false),
+    (0x315c, &[0xa4, 0xcc], false),
+    (0x315d, &[0xa4, 0xcd], false),
+    (0x315e, &[0xa4, 0xce], false),
+    (0x315f, &[0xa4, 0xcf], false),
+    (0x3160, &[0xa4, 0xd0], false),
+    (0x3161, &[0xa4, 0xd1], false),
+    (0x3162, &[0xa4, 0xd2], false),
+    (0x3163, &[0xa4, 0xd3], false),
+    (0x3164, &[0xa4, 0xd4], false),
+    (0x3165, &[0xa4, 0xd5], false),
+    (0x3166, &[0xa4, 0xd6], false),
+    (0x3167, &[0xa4, 0xd7], false),
+    (0x3168, &[0xa4, 0xd8], false),
+    (0x3169, &[0xa4, 0xd9], false),
+    (0x316a, &[0xa4, 0xda], false),
+    (0x316b, &[0xa4, 0xdb], false),
+    (0x316c, &[0xa4, 0xdc], false),
+    (0x316d, &[0xa4, 0xdd], false),
+    (0x316e, &[0xa4, 0xde], false),
+    (0x316f, &[0xa4, 0xdf], false),
+    (0x3170, &[0xa4, 0xe0], false),
+    (0x3171, &[0xa4, 0xe1], false),
+    (0x3172, &[0xa4, 0xe2], false),
+    (0x3173, &[0xa4, 0xe3], false),
+    (0x3174, &[0xa4, 0xe4], false),
+    (0x3175, &[0xa4, 0xe5], false),
+    (0x3176, &[0xa4, 0xe6], false),
+    (0x3177, &[0xa4, 0xe7], false),
+    (0x3178, &[0xa4, 0xe8], false),
+    (0x3179, &[0xa4, 0xe9], false),
+    (0x317a, &[0xa4, 0xea], false),
+    (0x317b, &[0xa4, 0xeb], false),
+    (0x317c, &[0xa4, 0xec], false),
+    (0x317d, &[0xa4, 0xed], false),
+    (0x317e, &[0xa4, 0xee], false),
+    (0x317f, &[0xa4, 0xef], false),
+    (0x3180, &[0xa4, 0xf0], false),
+    (0x3181, &[0xa4, 0xf1], false),
+    (0x3182, &[0xa4, 0xf2], false),
+    (0x3183, &[0xa4, 0xf3], false),
+    (0x3184, &[0xa4, 0xf4], false),
+    (0x3185, &[0xa4, 0xf5], false),
+    (0x3186, &[0xa4, 0xf6], false),
+    (0x3187, &[0xa4, 0xf7], false),
+    (0x3188, &[0xa4, 0xf8], false),
+    (0x3189, &[0xa4, 0xf9], false),
+    (0x318a, &[0xa4, 0xfa], false),
+    (0x318b, &[0xa4, 0xfb], false),
+    (0x318c, &[0xa4, 0xfc], false),
+    (0x318d, &[0xa4, 0xfd], false),
+    (0x318e, &[0xa4, 0xfe], false),
+    (0x24ea, &[0xa5, 0x4b], false),
+    (0x2460, &[0xa5, 0x4c], true),
+    (0x2461, &[0xa5, 0x4d], true),
+    (0x2462, &[0xa5, 0x4e], true),
+    (0x2463, &[0xa5, 0x4f], true),
+    (0x2464, &[0xa5, 0x50], true),
+    (0x2465, &[0xa5, 0x51], true),
+    (0x2466, &[0xa5, 0x52], true),
+    (0x2467, &[0xa5, 0x53], true),
+    (0x2468, &[0xa5, 0x54], true),
+    (0x2170, &[0xa5, 0xa1], false),
+    (0x2171, &[0xa5, 0xa2], false),
+    (0x2172, &[0xa5, 0xa3], false),
+    (0x2173, &[0xa5, 0xa4], false),
+    (0x2174, &[0xa5, 0xa5], false),
+    (0x2175, &[0xa5, 0xa6], false),
+    (0x2176, &[0xa5, 0xa7], false),
+    (0x2177, &[0xa5, 0xa8], false),
+    (0x2178, &[0xa5, 0xa9], false),
+    (0x2179, &[0xa5, 0xaa], false),
+    (0x2160, &[0xa5, 0xb0], false),
+    (0x2161, &[0xa5, 0xb1], false),
+    (0x2162, &[0xa5, 0xb2], false),
+    (0x2163, &[0xa5, 0xb3], false),
+    (0x2164, &[0xa5, 0xb4], false),
+    (0x2165, &[0xa5, 0xb5], false),
+    (0x2166, &[0xa5, 0xb6], false),
+    (0x2167, &[0xa5, 0xb7], false),
+    (0x2168, &[0xa5, 0xb8], false),
+    (0x2169, &[0xa5, 0xb9], false),
+    (0x0391, &[0xa5, 0xc1], false),
+    (0x0392, &[0xa5, 0xc2], false),
+    (0x0393, &[0xa5, 0xc3], false),
+    (0x0394, &[0xa5, 0xc4], false),
+    (0x0395, &[0xa5, 0xc5], false),
+    (0x0396, &[0xa5, 0xc6], false),
+    (0x0397, &[0xa5, 0xc7], false),
+    (0x0398, &[0xa5, 0xc8], false),
+    (0x0399, &[0xa5, 0xc9], false),
+    (0x039a, &[0xa5, 0xca], false),
+    (0x039b, &[0xa5, 0xcb], false),
+    (0x039c, &[0xa5, 0xcc], false),
+    (0x039d, &[0xa5, 0xcd], false),
+    (0x039e, &[0xa5, 0xce], false),
+    (0x039f, &[0xa5, 0xcf], false),
+    (0x03a0, &[0xa5, 0xd0], false),
+    (0x03a1, &[0xa5, 0xd1], false),
+    (0x03a3, &[0xa5, 0xd2], false),
+    (0x03a4, &[0xa5, 0xd3], false),
+    (0x03a5, &[0xa5, 0xd4], false),
+    (0x03a6, &[0xa5, 0xd5], false),
+    (0x03a7, &[0xa5, 0xd6], false),
+    (0x03a8, &[0xa5, 0xd7], false),
+    (0x03a9, &[0xa5, 0xd8], false),
+    (0xff01, &[0xa5, 0xda], true),
+    (0x3002, &[0xa5, 0xdb], true),
+    (0x2032, &[0xa5, 0xdc], true),
+    (0x2033, &[0xa5, 0xdd], true),
+    (0x2034, &[0xa5, 0xde], false),
+    (0x03b1, &[0xa5, 0xe1], false),
+    (0x03b2, &[0xa5, 0xe2], false),
+    (0x03b3, &[0xa5, 0xe3], false),
+    (0x03b4, &[0xa5, 0xe4], false),
+    (0x03b5, &[0xa5, 0xe5], false),
+    (0x03b6, &[0xa5, 0xe6], false),
+    (0x03b7, &[0xa5, 0xe7], false),
+    (0x03b8, &[0xa5, 0xe8], false),
+    (0x03b9, &[0xa5, 0xe9], false),
+    (0x03ba, &[0xa5, 0xea], false),
+    (0x03bb, &[0xa5, 0xeb], false),
+    (0x03bc, &[0xa5, 0xec], false),
+    (0x03bd, &[0xa5, 0xed], false),
+    (0x03be, &[0xa5, 0xee], false),
+    (0x03bf, &[0xa5, 0xef], false),
+    (0x03c0, &[0xa5, 0xf0], false),
+    (0x03c1, &[0xa5, 0xf1], false),
+    (0x03c3, &[0xa5, 0xf2], false),
+    (0x03c4, &[0xa5, 0xf3], false),
+    (0x03c5, &[0xa5, 0xf4], false),
+    (0x03c6, &[0xa5, 0xf5], false),
+    (0x03c7, &[0xa5, 0xf6], false),
+    (0x03c8, &[0xa5, 0xf7], false),
+    (0x03c9, &[0xa5, 0xf8], false),
+    (0x3257, &[0xa5, 0xf9], true),
+    (0x3258, &[0xa5, 0xfa], true),
+    (0x3259, &[0xa5, 0xfb], true),
+    (0x325a, &[0xa5, 0xfc], true),
+    (0xf83d, &[0xa6, 0x41], true),
+    (0xf83d, &[0xa6, 0x42], false),
+    (0x2020, &[0xa6, 0x43], true),
+    (0x00a7, &[0xa6, 0x47], true),
+    (0x266f, &[0xa6, 0x48], false),
+    (0xff0a, &[0xa6, 0x49], true),
+    (0xff0a, &[0xa6, 0x4a], true),
+    (0x2051, &[0xa6, 0x4b], true),
+    (0x2042, &[0xa6, 0x4d], false),
+    (0x204e, &[0xa6, 0x4e], false),
+    (0x2051, &[0xa6, 0x4f], true),
+    (0x2042, &[0xa6, 0x51], true),
+    (0x273d, &[0xa6, 0x52], false),
+    (0x2731, &[0xa6, 0x53], false),
+    (0x2747, &[0xa6, 0x54], false),
+    (0x2022, &[0xa6, 0x55], false),
+    (0xf805, &[0xa6, 0x58], false),
+    (0x2039, &[0xa6, 0x5a], false),
+    (0x203a, &[0xa6, 0x5b], false),
+    (0x00ab, &[0xa6, 0x5c], false),
+    (0x00bb, &[0xa6, 0x5d], false),
+    (0x261c, &[0xa6, 0x5e], true),
+    (0x261e, &[0xa6, 0x5f], true),
+    (0xf806, &[0xa6, 0x63], false),
+    (0x29c8, &[0xa6, 0x64], false),
+    (0x29be, &[0xa6, 0x68], false),
+    (0x271a, &[0xa6, 0x6c], false),
+    (0x2716, &[0xa6, 0x6d], false),
+    (0x29bf, &[0xa6, 0x6e], false),
+    (0x25ef, &[0xa6, 0x6f], false),
+    (0x25ef, &[0xa6, 0x70], true),
+    (0x2610, &[0xa6, 0x71], true),
+    (0x2723, &[0xa6, 0x72], false),
+    (0x2756, &[0xa6, 0x73], false),
+    (0xf80a, &[0xa6, 0x74], false),
+    (0x25cc, &[0xa6, 0x75], false),
+    (0x2610, &[0xa6, 0x76], true),
+    (0x2610, &[0xa6, 0x77], false),
+    (0x25a2, &[0xa6, 0x78], false),
+    (0x2723, &[0xa6, 0x79], true),
+    (0x2756, &[0xa6, 0x7a], true),
+    (0x273f, &[0xa6, 0x7b], true),
+    (0x273f, &[0xa6, 0x7c], false),
+    (0x3013, &[0xa6, 0x7d], true),
+    (0xf809, &[0xa6, 0x81], false),
+    (0x274d, &[0xa6, 0x83], false),
+    (0x25cd, &[0xa6, 0x84], false),
+    (0xf80b, &[0xa6, 0x86], true),
+    (0x2720, &[0xa6, 0x87], true),
+    (0x2720, &[0xa6, 0x88], false),
+    (0x25c8, &[0xa6, 0x89], true),
+    (0x25a8, &[0xa6, 0x8a], true),
+    (0x2741, &[0xa6, 0x8d], false),
+    (0x2756, &[0xa6, 0x8e], true),
+    (0xf808, &[0xa6, 0x8f], false),
+    (0x20a9, &[0xa6, 0x90], true),
+    (0xf809, &[0xa6, 0x91], true),
+    (0x534d, &[0xa6, 0x92], true),
+    (0x262f, &[0xa6, 0x93], false),
+    (0xf80b, &[0xa6, 0x96], false),
+    (0x262f, &[0xa6, 0x97], true),
+    (0x262f, &[0xa6, 0x98], true),
+    (0x2740, &[0xa6, 0x99], false),
+    (0xf80c, &[0xa6, 0x9a], false),
+    (0x3020, &[0xa6, 0x9e], false),
+    (0xf807, &[0xa6, 0x9f], false),
+    (0x2500, &[0xa6, 0xa1], false),
+    (0x2502, &[0xa6, 0xa2], false),
+    (0x250c, &[0xa6, 0xa3], false),
+    (0x2510, &[0xa6, 0xa4], false),
+    (0x2518, &[0xa6, 0xa5], false),
+    (0x2514, &[0xa6, 0xa6], false),
+    (0x251c, &[0xa6, 0xa7], false),
+    (0x252c, &[0xa6, 0xa8], false),
+    (0x2524, &[0xa6, 0xa9], false),
+    (0x2534, &[0xa6, 0xaa], false),
+    (0x253c, &[0xa6, 0xab], false),
+    (0x2501, &[0xa6, 0xac], false),
+    (0x2503, &[0xa6, 0xad], false),
+    (0x250f, &[0xa6, 0xae], false),
+    (0x2513, &[0xa6, 0xaf], false),
+    (0x251b, &[0xa6, 0xb0], false),
+    (0x2517, &[0xa6, 0xb1], false),
+    (0x2523, &[0xa6, 0xb2], false),
+    (0x2533, &[0xa6, 0xb3], false),
+    (0x252b, &[0xa6, 0xb4], false),
+    (0x253b, &[0xa6, 0xb5], false),
+    (0x254b, &[0xa6, 0xb6], false),
+    (0x2520, &[0xa6, 0xb7], false),
+    (0x252f, &[0xa6, 0xb8], false),
+    (0x2528, &[0xa6, 0xb9], false),
+    (0x2537, &[0xa6, 0xba], false),
+    (0x253f, &[0xa6, 0xbb], false),
+    (0x251d, &[0xa6, 0xbc], false),
+    (0x2530, &[0xa6, 0xbd], false),
+    (0x2525, &[0xa6, 0xbe], false),
+    (0x2538, &[0xa6, 0xbf], false),
+    (0x2542, &[0xa6, 0xc0], false),
+    (0x2512, &[0xa6, 0xc1], false),
+    (0x2511, &[0xa6, 0xc2], false),
+    (0x251a, &[0xa6, 0xc3], false),
+    (0x2519, &[0xa6, 0xc4], false),
+    (0x2516, &[0xa6, 0xc5], false),
+    (0x2515, &[0xa6, 0xc6], false),
+    (0x250e, &[0xa6, 0xc7], false),
+    (0x250d, &[0xa6, 0xc8], false),
+    (0x251e, &[0xa6, 0xc9], false),
+    (0x251f, &[0xa6, 0xca], false),
+    (0x2521, &[0xa6, 0xcb], false),
+    (0x2522, &[0xa6, 0xcc], false),
+    (0x2526, &[0xa6, 0xcd], false),
+    (0x2527, &[0xa6, 0xce], false),
+    (0x2529, &[0xa6, 0xcf], false),
+    (0x252a, &[0xa6, 0xd0], false),
+    (0x252d, &[0xa6, 0xd1], false),
+    (0x252e, &[0xa6, 0xd2], false),
+    (0x2531, &[0xa6, 0xd3], false),
+    (0x2532, &[0xa6, 0xd4], false),
+    (0x2535, &[0xa6, 0xd5], false),
+    (0x2536, &[0xa6, 0xd6], false),
+    (0x2539, &[0xa6, 0xd7], false),
+    (0x253a, &[0xa6, 0xd8], false),
+    (0x253d, &[0xa6, 0xd9], false),
+    (0x253e, &[0xa6, 0xda], false),
+    (0x2540, &[0xa6, 0xdb], false),
+    (0x2541, &[0xa6, 0xdc], false),
+    (0x2543, &[0xa6, 0xdd], false),
+    (0x2544, &[0xa6, 0xde], false),
+    (0x2545, &[0xa6, 0xdf], false),
+    (0x2546, &[0xa6, 0xe0], false),
+    (0x2547, &[0xa6, 0xe1], false),
+    (0x2548, &[0xa6, 0xe2], false),
+    (0x2549, &[0xa6, 0xe3], false),
+    (0x254a, &[0xa6, 0xe4], false),
+    (0x2776, &[0xa6, 0xe5], false),
+    (0x2777, &[0xa6, 0xe6], false),
+    (0x2778, &[0xa6, 0xe7], false),
+    (0x2779, &[0xa6, 0xe8], false),
+    (0x277a, &[0xa6, 0xe9], false),
+    (0x277b, &[0xa6, 0xea], false),
+    (0x277c, &[0xa6, 0xeb], false),
+    (0x277d, &[0xa6, 0xec], false),
+    (0x277e, &[0xa6, 0xed], false),
+    (0x277f, &[0xa6, 0xee], false),
+    (0x24eb, &[0xa6, 0xef], false),
+    (0x24ec, &[0xa6, 0xf0], false),
+    (0x24ed, &[0xa6, 0xf1], false),
+    (0x24ee, &[0xa6, 0xf2], false),
+    (0x24ef, &[0xa6, 0xf3], false),
+    (0x24f0, &[0xa6, 0xf4], false),
+    (0x24f1, &[0xa6, 0xf5], false),
+    (0x24f2, &[0xa6, 0xf6], false),
+    (0x24f3, &[0xa6, 0xf7], false),
+    (0x24f4, &[0xa6, 0xf8], false),
+    (0x3251, &[0xa6, 0xf9], true),
+    (0x3252, &[0xa6, 0xfa], true),
+    (0x3253, &[0xa6, 0xfb], true),
+    (0x3254, &[0xa6, 0xfc], true),
+    (0x3255, &[0xa6, 0xfd], true),
+    (0x3256, &[0xa6, 0xfe], true),
+    (0x2642, &[0xa7, 0x41], true),
+    (0x3012, &[0xa7, 0x42], false),
+    (0x3036, &[0xa7, 0x43], false),
+    (0x25cb, &[0xa7, 0x44], true),
+    (0x25b3, &[0xa7, 0x45], true),
+    (0x25fb, &[0xa7, 0x46], false),
+    (0xf84c, &[0xa7, 0x47], false),
+    (0x2394, &[0xa7, 0x48], false),
+    (0x25ad, &[0xa7, 0x49], true),
+    (0x25ad, &[0xa7, 0x4a], false),
+    (0xf84d, &[0xa7, 0x4b], false),
+    (0xf84e, &[0xa7, 0x4c], false),
+    (0xf84f, &[0xa7, 0x4d], false),
+    (0x25c7, &[0xa7, 0x4e], true),
+    (0x51f9, &[0xa7, 0x4f], true),
+    (0x51f8, &[0xa7, 0x50], true),
+    (0x2206, &[0xa7, 0x51], false),
+    (0x2206, &[0xa7, 0x52], true),
+    (0x221f, &[0xa7, 0x53], false),
+    (0x222a, &[0xa7, 0x54], true),
+    (0x2225, &[0xa7, 0x55], false),
+    (0x2226, &[0xa7, 0x56], false),
+    (0x2229, &[0xa7, 0x57], true),
+    (0x2253, &[0xa7, 0x58], false),
+    (0x2251, &[0xa7, 0x59], false),
+    (0x2266, &[0xa7, 0x5a], false),
+    (0x2267, &[0xa7, 0x5b], false),
+    (0x2213, &[0xa7, 0x5c], false),
+    (0x2295, &[0xa7, 0x5d], false),
+    (0x2296, &[0xa7, 0x5e], false),
+    (0x2297, &[0xa7, 0x5f], false),
+    (0x2a38, &[0xa7, 0x60], false),
+    (0x2314, &[0xa7, 0x61], false),
+    (0x2262, &[0xa7, 0x64], false),
+    (0x25b1, &[0xa7, 0x66], false),
+    (0x2222, &[0xa7, 0x68], false),
+    (0x2250, &[0xa7, 0x69], false),
+    (0x03d5, &[0xa7, 0x6a], false),
+    (0x2ae8, &[0xa7, 0x6b], false),
+    (0x22a3, &[0xa7, 0x6c], false),
+    (0x226e, &[0xa7, 0x6f], false),
+    (0x226f, &[0xa7, 0x70], false),
+    (0x2285, &[0xa7, 0x71], false),
+    (0x2284, &[0xa7, 0x72], false),
+    (0x2209, &[0xa7, 0x73], false),
+    (0x220c, &[0xa7, 0x74], false),
+    (0x22bb, &[0xa7, 0x75], false),
+    (0x22bc, &[0xa7, 0x76], false),
+    (0x225a, &[0xa7, 0x77], false),
+    (0x2306, &[0xa7, 0x78], false),
+    (0x2314, &[0xa7, 0x7a], true),
+    (0x2a72, &[0xa7, 0x7b], false),
+    (0x329e, &[0xa7, 0x82], false),
+    (0x329e, &[0xa7, 0x83], true),
+    (0x203c, &[0xa7, 0x84], false),
+    (0x2049, &[0xa7, 0x85], false),
+    (0x203c, &[0xa7, 0x86], true),
+    (0x2047, &[0xa7, 0x87], false),
+    (0x25c7, &[0xa7, 0x88], true),
+    (0x25c7, &[0xa7, 0x89], true),
+    (0x25c7, &[0xa7, 0x8a], true),
+    (0x25c6, &[0xa7, 0x8b], true),
+    (0x25a1, &[0xa7, 0x8c], true),
+    (0x25a1, &[0xa7, 0x8d], true),
+    (0x25a1, &[0xa7, 0x8e], true),
+    (0x2588, &[0xa7, 0x8f], false),
+    (0x25e6, &[0xa7, 0x90], false),
+    (0x25cb, &[0xa7, 0x91], true),
+    (0x25cb, &[0xa7, 0x92], true),
+    (0x25cf, &[0xa7, 0x93], true),
+    (0x25bf, &[0xa7, 0x94], false),
+    (0x25b5, &[0xa7, 0x95], false),
+    (0x25b9, &[0xa7, 0x96], false),
+    (0x25c3, &[0xa7, 0x97], false),
+    (0x2666, &[0xa7, 0x98], false),
+    (0x2981, &[0xa7, 0x99], false),
+    (0x25fc, &[0xa7, 0x9a], false),
+    (0x25ca, &[0xa7, 0x9c], false),
+    (0x3231, &[0xa7, 0x9d], false),
+    (0x3239, &[0xa7, 0x9e], false),
+    (0x33cb, &[0xa7, 0x9f], false),
+    (0x3395, &[0xa7, 0xa1], false),
+    (0x3396, &[0xa7, 0xa2], false),
+    (0x3397, &[0xa7, 0xa3], false),
+    (0x2113, &[0xa7, 0xa4], false),
+    (0x3398, &[0xa7, 0xa5], false),
+    (0x33c4, &[0xa7, 0xa6], false),
+    (0x33a3, &[0xa7, 0xa7], false),
+    (0x33a4, &[0xa7, 0xa8], false),
+    (0x33a5, &[0xa7, 0xa9], false),
+    (0x33a6, &[0xa7, 0xaa], false),
+    (0x3399, &[0xa7, 0xab], false),
+    (0x339a, &[0xa7, 0xac], false),
+    (0x339b, &[0xa7, 0xad], false),
+    (0x339c, &[0xa7, 0xae], false),
+    (0x339d, &[0xa7, 0xaf], false),
+    (0x339e, &[0xa7, 0xb0], false),
+    (0x339f, &[0xa7, 0xb1], false),
+    (0x33a0, &[0xa7, 0xb2], false),
+    (0x33a1, &[0xa7, 0xb3], false),
+    (0x33a2, &[0xa7, 0xb4], false),
+    (0x33ca, &[0xa7, 0xb5], false),
+    (0x338d, &[0xa7, 0xb6], false),
+    (0x338e, &[0xa7, 0xb7], false),
+    (0x338f, &[0xa7, 0xb8], false),
+    (0x33cf, &[0xa7, 0xb9], false),
+    (0x3388, &[0xa7, 0xba], false),
+    (0x3389, &[0xa7, 0xbb], false),
+    (0x33c8, &[0xa7, 0xbc], false),
+    (0x33a7, &[0xa7, 0xbd], false),
+    (0x33a8, &[0xa7, 0xbe], false),
+    (0x33b0, &[0xa7, 0xbf], false),
+    (0x33b1, &[0xa7, 0xc0], false),
+    (0x33b2, &[0xa7, 0xc1], false),
+    (0x33b3, &[0xa7, 0xc2], false),
+    (0x33b4, &[0xa7, 0xc3], false),
+    (0x33b5, &[0xa7, 0xc4], false),
+    (0x33b6, &[0xa7, 0xc5], false),
+    (0x33b7, &[0xa7, 0xc6], false),
+    (0x33b8, &[0xa7, 0xc7], false),
+    (0x33b9, &[0xa7, 0xc8], false),
+    (0x3380, &[0xa7, 0xc9], false),
+    (0x3381, &[0xa7, 0xca], false),
+    (0x3382, &[0xa7, 0xcb], false),
+    (0x3383, &[0xa7, 0xcc], false),
+    (0x3384, &[0xa7, 0xcd], false),
+    (0x33ba, &[0xa7, 0xce], false),
+    (0x33bb, &[0xa7, 0xcf], false),
+    (0x33bc, &[0xa7, 0xd0], false),
+    (0x33bd, &[0xa7, 0xd1], false),
+    (0x33be, &[0xa7, 0xd2], false),
+    (0x33bf, &[0xa7, 0xd3], false),
+    (0x3390, &[0xa7, 0xd4], false),
+    (0x3391, &[0xa7, 0xd5], false),
+    (0x3392, &[0xa7, 0xd6], false),
+    (0x3393, &[0xa7, 0xd7], false),
+    (0x3394, &[0xa7, 0xd8], false),
+    (0x2126, &[0xa7, 0xd9], false),
+    (0x33c0, &[0xa7, 0xda], false),
+    (0x33c1, &[0xa7, 0xdb], false),
+    (0x338a, &[0xa7, 0xdc], false),
+    (0x338b, &[0xa7, 0xdd], false),
+    (0x338c, &[0xa7, 0xde], false),
+    (0x33d6, &[0xa7, 0xdf], false),
+    (0x33c5, &[0xa7, 0xe0], false),
+    (0x33ad, &[0xa7, 0xe1], false),
+    (0x33ae, &[0xa7, 0xe2], false),
+    (0x33af, &[0xa7, 0xe3], false),
+    (0x33db, &[0xa7, 0xe4], false),
+    (0x33a9, &[0xa7, 0xe5], false),
+    (0x33aa, &[0xa7, 0xe6], false),
+    (0x33ab, &[0xa7, 0xe7], false),
+    (0x33ac, &[0xa7, 0xe8], false),
+    (0x33dd, &[0xa7, 0xe9], false),
+    (0x33d0, &[0xa7, 0xea], false),
+    (0x33d3, &[0xa7, 0xeb], false),
+    (0x33c3, &[0xa7, 0xec], false),
+    (0x33c9, &[0xa7, 0xed], false),
+    (0x33dc, &[0xa7, 0xee], false),
+    (0x33c6, &[0xa7, 0xef], false),
+    (0x246f, &[0xa7, 0xf0], false),
+    (0x2470, &[0xa7, 0xf1], false),
+    (0x2471, &[0xa7, 0xf2], false),
+    (0x2472, &[0xa7, 0xf3], false),
+    (0x2473, &[0xa7, 0xf4], false),
+    (0x3251, &[0xa7, 0xf5], false),
+    (0x3252, &[0xa7, 0xf6], false),
+    (0x3253, &[0xa7, 0xf7], false),
+    (0x3254, &[0xa7, 0xf8], false),
+    (0x3255, &[0xa7, 0xf9], false),
+    (0x3256, &[0xa7, 0xfa], false),
+    (0x3257, &[0xa7, 0xfb], false),
+    (0x3258, &[0xa7, 0xfc], false),
+    (0x3259, &[0xa7, 0xfd], false),
+    (0x325a, &[0xa7, 0xfe], false),
+    (0x2192, &[0xa8, 0x41], true),
+    (0x2190, &[0xa8, 0x42], true),
+    (0x2191, &[0xa8, 0x43], true),
+    (0x2193, &[0xa8, 0x44], true),
+    (0x2196, &[0xa8, 0x45], true),
+    (0x2197, &[0xa8, 0x46], true),
+    (0x2198, &[0xa8, 0x47], true),
+    (0x2199, &[0xa8, 0x48], true),
+    (0x21d0, &[0xa8, 0x49], false),
+    (0x21cf, &[0xa8, 0x4a], false),
+    (0x21cd, &[0xa8, 0x4b], false),
+    (0x21d4, &[0xa8, 0x4c], true),
+    (0x2192, &[0xa8, 0x4d], true),
+    (0x2190, &[0xa8, 0x4e], true),
+    (0x2191, &[0xa8, 0x4f], true),
+    (0x2193, &[0xa8, 0x50], true),
+    (0x2194, &[0xa8, 0x51], true),
+    (0x2195, &[0xa8, 0x52], true),
+    (0x2190, &[0xa8, 0x53], true),
+    (0x2192, &[0xa8, 0x54], true),
+    (0x2191, &[0xa8, 0x55], true),
+    (0x2193, &[0xa8, 0x56], true),
+    (0x27b2, &[0xa8, 0x5c], false),
+    (0x2190, &[0xa8, 0x5f], true),
+    (0x279c, &[0xa8, 0x60], false),
+    (0x2191, &[0xa8, 0x61], true),
+    (0x2193, &[0xa8, 0x62], true),
+    (0x2190, &[0xa8, 0x63], true),
+    (0x2192, &[0xa8, 0x64], true),
+    (0x2191, &[0xa8, 0x65], true),
+    (0x2193, &[0xa8, 0x66], true),
+    (0xf846, &[0xa8, 0x67], false),
+    (0xf847, &[0xa8, 0x68], false),
+    (0x2190, &[0xa8, 0x69], true),
+    (0x279b, &[0xa8, 0x6a], false),
+    (0x2190, &[0xa8, 0x6b], true),
+    (0x2192, &[0xa8, 0x6c], true),
+    (0x2191, &[0xa8, 0x6d], true),
+    (0x2193, &[0xa8, 0x6e], true),
+    (0x2962, &[0xa8, 0x6f], false),
+    (0x2964, &[0xa8, 0x70], false),
+    (0x2963, &[0xa8, 0x71], false),
+    (0x2965, &[0xa8, 0x72], false),
+    (0x21e6, &[0xa8, 0x73], true),
+    (0x27a1, &[0xa8, 0x74], false),
+    (0x21e7, &[0xa8, 0x75], true),
+    (0x21e9, &[0xa8, 0x76], true),
+    (0x21e6, &[0xa8, 0x77], true),
+    (0x279e, &[0xa8, 0x78], false),
+    (0x21e7, &[0xa8, 0x79], true),
+    (0x21e9, &[0xa8, 0x7a], true),
+    (0x21b2, &[0xa8, 0x7b], false),
+    (0x21b1, &[0xa8, 0x7c], false),
+    (0x21bb, &[0xa8, 0x7d], false),
+    (0x21b4, &[0xa8, 0x81], false),
+    (0x21b0, &[0xa8, 0x82], false),
+    (0x21b3, &[0xa8, 0x83], false),
+    (0x2939, &[0xa8, 0x84], true),
+    (0x2934, &[0xa8, 0x85], true),
+    (0x2936, &[0xa8, 0x86], false),
+    (0x21b1, &[0xa8, 0x87], true),
+    (0x21bb, &[0xa8, 0x88], true),
+    (0x2935, &[0xa8, 0x89], false),
+    (0x21b0, &[0xa8, 0x8a], true),
+    (0x2937, &[0xa8, 0x8b], false),
+    (0x2939, &[0xa8, 0x8c], false),
+    (0x2934, &[0xa8, 0x8d], false),
+    (0x21e6, &[0xa8, 0x8e], true),
+    (0x21e8, &[0xa8, 0x8f], true),
+    (0x21e7, &[0xa8, 0x90], true),
+    (0x21e9, &[0xa8, 0x91], true),
+    (0x21bc, &[0xa8, 0x92], false),
+    (0x21c0, &[0xa8, 0x93], false),
+    (0xf841, &[0xa8, 0x94], false),
+    (0x21d4, &[0xa8, 0x95], true),
+    (0x21e8, &[0xa8, 0x96], true),
+    (0x21e6, &[0xa8, 0x97], true),
+    (0x21c0, &[0xa8, 0x98], true),
+    (0x21bc, &[0xa8, 0x99], true),
+    (0x21d2, &[0xa8, 0x9a], true),
+    (0x21d0, &[0xa8, 0x9b], true),
+    (0xf849, &[0xa8, 0x9c], false),
+    (0xf848, &[0xa8, 0x9d], false),
+    (0x21c4, &[0xa8, 0x9e], false),
+    (0x21c5, &[0xa8, 0x9f], false),
+    (0x00c6, &[0xa8, 0xa1], false),
+    (0x00d0, &[0xa8, 0xa2], false),
+    (0x00aa, &[0xa8, 0xa3], false),
+    (0x0126, &[0xa8, 0xa4], false),
+    (0x0132, &[0xa8, 0xa6], false),
+    (0x013f, &[0xa8, 0xa8], false),
+    (0x0141, &[0xa8, 0xa9], false),
+    (0x00d8, &[0xa8, 0xaa], false),
+    (0x0152, &[0xa8, 0xab], false),
+    (0x00ba, &[0xa8, 0xac], false),
+    (0x00de, &[0xa8, 0xad], false),
+    (0x0166, &[0xa8, 0xae], false),
+    (0x014a, &[0xa8, 0xaf], false),
+    (0x3260, &[0xa8, 0xb1], false),
+    (0x3261, &[0xa8, 0xb2], false),
+    (0x3262, &[0xa8, 0xb3], false),
+    (0x3263, &[0xa8, 0xb4], false),
+    (0x3264, &[0xa8, 0xb5], false),
+    (0x3265, &[0xa8, 0xb6], false),
+    (0x3266, &[0xa8, 0xb7], false),
+    (0x3267, &[0xa8, 0xb8], false),
+    (0x3268, &[0xa8, 0xb9], false),
+    (0x3269, &[0xa8, 0xba], false),
+    (0x326a, &[0xa8, 0xbb], false),
+    (0x326b, &[0xa8, 0xbc], false),
+    (0x326c, &[0xa8, 0xbd], false),
+    (0x326d, &[0xa8, 0xbe], false),
+    (0x326e, &[0xa8, 0xbf], false),
+    (0x326f, &[0xa8, 0xc0], false),
+    (0x3270, &[0xa8, 0xc1], false),
+    (0x3271, &[0xa8, 0xc2], false),
+    (0x3272, &[0xa8, 0xc3], false),
+    (0x3273, &[0xa8, 0xc4], false),
+    (0x3274, &[0xa8, 0xc5], false),
+    (0x3275, &[0xa8, 0xc6], false),
+    (0x3276, &[0xa8, 0xc7], false),
+    (0x3277, &[0xa8, 0xc8], false),
+    (0x3278, &[0xa8, 0xc9], false),
+    (0x3279, &[0xa8, 0xca], false),
+    (0x327a, &[0xa8, 0xcb], false),
+    (0x327b, &[0xa8, 0xcc], false),
+    (0x24d0, &[0xa8, 0xcd], false),
+    (0x24d1, &[0xa8, 0xce], false),
+    (0x24d2, &[0xa8, 0xcf], false),
+    (0x24d3, &[0xa8, 0xd0], false),
+    (0x24d4, &[0xa8, 0xd1], false),
+    (0x24d5, &[0xa8, 0xd2], false),
+    (0x24d6, &[0xa8, 0xd3], false),
+    (0x24d7, &[0xa8, 0xd4], false),
+    (0x24d8, &[0xa8, 0xd5], false),
+    (0x24d9, &[0xa8, 0xd6], false),
+    (0x24da, &[0xa8, 0xd7], false),
+    (0x24db, &[0xa8, 0xd8], false),
+    (0x24dc, &[0xa8, 0xd9], false),
+    (0x24dd, &[0xa8, 0xda], false),
+    (0x24de, &[0xa8, 0xdb], false),
+    (0x24df, &[0xa8, 0xdc], false),
+    (0x24e0, &[0xa8, 0xdd], false),
+    (0x24e1, &[0xa8, 0xde], false),
+    (0x24e2, &[0xa8, 0xdf], false),
+    (0x24e3, &[0xa8, 0xe0], false),
+    (0x24e4, &[0xa8, 0xe1], false),
+    (0x24e5, &[0xa8, 0xe2], false),
+    (0x24e6, &[0xa8, 0xe3], false),
+    (0x24e7, &[0xa8, 0xe4], false),
+    (0x24e8, &[0xa8, 0xe5], false),
+    (0x24e9, &[0xa8, 0xe6], false),
+    (0x2460, &[0xa8, 0xe7], false),
+    (0x2461, &[0xa8, 0xe8], false),
+    (0x2462, &[0xa8, 0xe9], false),
+    (0x2463, &[0xa8, 0xea], false),
+    (0x2464, &[0xa8, 0xeb], false),
+    (0x2465, &[0xa8, 0xec], false),
+    (0x2466, &[0xa8, 0xed], false),
+    (0x2467, &[0xa8, 0xee], false),
+    (0x2468, &[0xa8, 0xef], false),
+    (0x2469, &[0xa8, 0xf0], false),
+    (0x246a, &[0xa8, 0xf1], false),
+    (0x246b, &[0xa8, 0xf2], false),
+    (0x246c, &[0xa8, 0xf3], false),
+    (0x246d, &[0xa8, 0xf4], false),
+    (0x246e, &[0xa8, 0xf5], false),
+    (0x00bd, &[0xa8, 0xf6], false),
+    (0x2153, &[0xa8, 0xf7], false),
+    (0x2154, &[0xa8, 0xf8], false),
+    (0x00bc, &[0xa8, 0xf9], false),
+    (0x00be, &[0xa8, 0xfa], false),
+    (0x215b, &[0xa8, 0xfb], false),
+    (0x215c, &[0xa8, 0xfc], false),
+    (0x215d, &[0xa8, 0xfd], false),
+    (0x215e, &[0xa8, 0xfe], false),
+    (0x00e6, &[0xa9, 0xa1], false),
+    (0x0111, &[0xa9, 0xa2], false),
+    (0x00f0, &[0xa9, 0xa3], false),
+    (0x0127, &[0xa9, 0xa4], false),
+    (0x0131, &[0xa9, 0xa5], false),
+    (0x0133, &[0xa9, 0xa6], false),
+    (0x0138, &[0xa9, 0xa7], false),
+    (0x0140, &[0xa9, 0xa8], false),
+    (0x0142, &[0xa9, 0xa9], false),
+    (0x00f8, &[0xa9, 0xaa], false),
+    (0x0153, &[0xa9, 0xab], false),
+    (0x00df, &[0xa9, 0xac], false),
+    (0x00fe, &[0xa9, 0xad], false),
+    (0x0167, &[0xa9, 0xae], false),
+    (0x014b, &[0xa9, 0xaf], false),
+    (0x0149, &[0xa9, 0xb0], false),
+    (0x3200, &[0xa9, 0xb1], false),
+    (0x3201, &[0xa9, 0xb2], false),
+    (0x3202, &[0xa9, 0xb3], false),
+    (0x3203, &[0xa9, 0xb4], false),
+    (0x3204, &[0xa9, 0xb5], false),
+    (0x3205, &[0xa9, 0xb6], false),
+    (0x3206, &[0xa9, 0xb7], false),
+    (0x3207, &[0xa9, 0xb8], false),
+    (0x3208, &[0xa9, 0xb9], false),
+    (0x3209, &[0xa9, 0xba], false),
+    (0x320a, &[0xa9, 0xbb], false),
+    (0x320b, &[0xa9, 0xbc], false),
+    (0x320c, &[0xa9, 0xbd], false),
+    (0x320d, &[0xa9, 0xbe], false),
+    (0x320e, &[0xa9, 0xbf], false),
+    (0x320f, &[0xa9, 0xc0], false),
+    (0x3210, &[0xa9, 0xc1], false),
+    (0x3211, &[0xa9, 0xc2], false),
+    (0x3212, &[0xa9, 0xc3], false),
+    (0x3213, &[0xa9, 0xc4], false),
+    (0x3214, &[0xa9, 0xc5], false),
+    (0x3215, &[0xa9, 0xc6], false),
+    (0x3216, &[0xa9, 0xc7], false),
+    (0x3217, &[0xa9, 0xc8], false),
+    (0x3218, &[0xa9, 0xc9], false),
+    (0x3219, &[0xa9, 0xca], false),
+    (0x321a, &[0xa9, 0xcb], false),
+    (0x321b, &[0xa9, 0xcc], false),
+    (0x249c, &[0xa9, 0xcd], false),
+    (0x249d, &[0xa9, 0xce], false),
+    (0x249e, &[0xa9, 0xcf], false),
+    (0x249f, &[0xa9, 0xd0], false),
+    (0x24a0, &[0xa9, 0xd1], false),
+    (0x24a1, &[0xa9, 0xd2], false),
+    (0x24a2, &[0xa9, 0xd3], false),
+    (0x24a3, &[0xa9, 0xd4], false),
+    (0x24a4, &[0xa9, 0xd5], false),
+    (0x24a5, &[0xa9, 0xd6], false),
+    (0x24a6, &[0xa9, 0xd7], false),
+    (0x24a7, &[0xa9, 0xd8], false),
+    (0x24a8, &[0xa9, 0xd9], false),
+    (0x24a9, &[0xa9, 0xda], false),
+    (0x24aa, &[0xa9, 0xdb], false),
+    (0x24ab, &[0xa9, 0xdc], false),
+    (0x24ac, &[0xa9, 0xdd], false),
+    (0x24ad, &[0xa9, 0xde], false),
+    (0x24ae, &[0xa9, 0xdf], false),
+    (0x24af, &[0xa9, 0xe0], false),
+    (0x24b0, &[0xa9, 0xe1], false),
+    (0x24b1, &[0xa9, 0xe2], false),
+    (0x24b2, &[0xa9, 0xe3], false),
+    (0x24b3, &[0xa9, 0xe4], false),
+    (0x24b4, &[0xa9, 0xe5], false),
+    (0x24b5, &[0xa9, 0xe6], false),
+    (0x2474, &[0xa9, 0xe7], false),
+    (0x2475, &[0xa9, 0xe8], false),
+    (0x2476, &[0xa9, 0xe9], false),
+    (0x2477, &[0xa9, 0xea], false),
+    (0x2478, &[0xa9, 0xeb], false),
+    (0x2479, &[0xa9, 0xec], false),
+    (0x247a, &[0xa9, 0xed], false),
+    (0x247b, &[0xa9, 0xee], false),
+    (0x247c, &[0xa9, 0xef], false),
+    (0x247d, &[0xa9, 0xf0], false),
+    (0x247e, &[0xa9, 0xf1], false),
+    (0x247f, &[0xa9, 0xf2], false),
+    (0x2480, &[0xa9, 0xf3], false),
+    (0x2481, &[0xa9, 0xf4], false),
+    (0x2482, &[0xa9, 0xf5], false),
+    (0x00b9, &[0xa9, 0xf6], false),
+    (0x00b2, &[0xa9, 0xf7], false),
+    (0x00b3, &[0xa9, 0xf8], false),
+    (0x2074, &[0xa9, 0xf9], false),
+    (0x207f, &[0xa9, 0xfa], false),
+    (0x2081, &[0xa9, 0xfb], false),
+    (0x2082, &[0xa9, 0xfc], false),
+    (0x2083, &[0xa9, 0xfd], false),
+    (0x2084, &[0xa9, 0xfe], false),
+    (0x3041, &[0xaa, 0xa1], false),
+    (0x3042, &[0xaa, 0xa2], false),
+    (0x3043, &[0xaa, 0xa3], false),
+    (0x3044, &[0xaa, 0xa4], false),
+    (0x3045, &[0xaa, 0xa5], false),
+    (0x3046, &[0xaa, 0xa6], false),
+    (0x3047, &[0xaa, 0xa7], false),
+    (0x3048, &[0xaa, 0xa8], false),
+    (0x3049, &[0xaa, 0xa9], false),
+    (0x304a, &[0xaa, 0xaa], false),
+    (0x304b, &[0xaa, 0xab], false),
+    (0x304c, &[0xaa, 0xac], false),
+    (0x304d, &[0xaa, 0xad], false),
+    (0x304e, &[0xaa, 0xae], false),
+    (0x304f, &[0xaa, 0xaf], false),
+    (0x3050, &[0xaa, 0xb0], false),
+    (0x3051, &[0xaa, 0xb1], false),
+    (0x3052, &[0xaa, 0xb2], false),
+    (0x3053, &[0xaa, 0xb3], false),
+    (0x3054, &[0xaa, 0xb4], false),
+    (0x3055, &[0xaa, 0xb5], false),
+    (0x3056, &[0xaa, 0xb6], false),
+    (0x3057, &[0xaa, 0xb7], false),
+    (0x3058, &[0xaa, 0xb8], false),
+    (0x3059, &[0xaa, 0xb9], false),
+    (0x305a, &[0xaa, 0xba], false),
+    (0x305b, &[0xaa, 0xbb], false),
+    (0x305c, &[0xaa, 0xbc], false),
+    (0x305d, &[0xaa, 0xbd], false),
+    (0x305e, &[0xaa, 0xbe], false),
+    (0x305f, &[0xaa, 0xbf], false),
+    (0x3060, &[0xaa, 0xc0], false),
+    (0x3061, &[0xaa, 0xc1], false),
+    (0x3062, &[0xaa, 0xc2], false),
+    (0x3063, &[0xaa, 0xc3], false),
+    (0x3064, &[0xaa, 0xc4], false),
+    (0x3065, &[0xaa, 0xc5], false),
+    (0x3066, &[0xaa, 0xc6], false),
+    (0x3067, &[0xaa, 0xc7], false),
+    (0x3068, &[0xaa, 0xc8], false),
+    (0x3069, &[0xaa, 0xc9], false),
+    (0x306a, &[0xaa, 0xca], false),
+    (0x306b, &[0xaa, 0xcb], false),
+    (0x306c, &[0xaa, 0xcc], false),
+    (0x306d, &[0xaa, 0xcd], false),
+    (0x306e, &[0xaa, 0xce], false),
+    (0x306f, &[0xaa, 0xcf], false),
+    (0x3070, &[0xaa, 0xd0], false),
+    (0x3071, &[0xaa, 0xd1], false),
+    (0x3072, &[0xaa, 0xd2], false),
+    (0x3073, &[0xaa, 0xd3], false),
+    (0x3074, &[0xaa, 0xd4], false),
+    (0x3075, &[0xaa, 0xd5], false),
+    (0x3076, &[0xaa, 0xd6], false),
+    (0x3077, &[0xaa, 0xd7], false),
+    (0x3078, &[0xaa, 0xd8], false),
+    (0x3079, &[0xaa, 0xd9], false),
+    (0x307a, &[0xaa, 0xda], false),
+    (0x307b, &[0xaa, 0xdb], false),
+    (0x307c, &[0xaa, 0xdc], false),
+    (0x307d, &[0xaa, 0xdd], false),
+    (0x307e, &[0xaa, 0xde], false),
+    (0x307f, &[0xaa, 0xdf], false),
+    (0x3080, &[0xaa, 0xe0], false),
+    (0x3081, &[0xaa, 0xe1], false),
+    (0x3082, &[0xaa, 0xe2], false),
+    (0x3083, &[0xaa, 0xe3], false),
+    (0x3084, &[0xaa, 0xe4], false),
+    (0x3085, &[0xaa, 0xe5], false),
+    (0x3086, &[0xaa, 0xe6], false),
+    (0x3087, &[0xaa, 0xe7], false),
+    (0x3088, &[0xaa, 0xe8], false),
+    (0x3089, &[0xaa, 0xe9], false),
+    (0x308a, &[0xaa, 0xea], false),
+    (0x308b, &[0xaa, 0xeb], false),
+    (0x308c, &[0xaa, 0xec], false),
+    (0x308d, &[0xaa, 0xed], false),
+    (0x308e, &[0xaa, 0xee], false),
+    (0x308f, &[0xaa, 0xef], false),
+    (0x3090, &[0xaa, 0xf0], false),
+    (0x3091, &[0xaa, 0xf1], false),
+    (0x3092, &[0xaa, 0xf2], false),
+    (0x3093, &[0xaa, 0xf3], false),
+    (0x2483, &[0xaa, 0xf4], false),
+    (0x2484, &[0xaa, 0xf5], false),
+    (0x2485, &[0xaa, 0xf6], false),
+    (0x2486, &[0xaa, 0xf7], false),
+    (0x2487, &[0xaa, 0xf8], false),
+    (0x3294, &[0xab, 0x5c], false),
+    (0x32a5, &[0xab, 0x6c], false),
+    (0x30a1, &[0xab, 0xa1], false),
+    (0x30a2, &[0xab, 0xa2], false),
+    (0x30a3, &[0xab, 0xa3], false),
+    (0x30a4, &[0xab, 0xa4], false),
+    (0x30a5, &[0xab, 0xa5], false),
+    (0x30a6, &[0xab, 0xa6], false),
+    (0x30a7, &[0xab, 0xa7], false),
+    (0x30a8, &[0xab, 0xa8], false),
+    (0x30a9, &[0xab, 0xa9], false),
+    (0x30aa, &[0xab, 0xaa], false),
+    (0x30ab, &[0xab, 0xab], false),
+    (0x30ac, &[0xab, 0xac], false),
+    (0x30ad, &[0xab, 0xad], false),
+    (0x30ae, &[0xab, 0xae], false),
+    (0x30af, &[0xab, 0xaf], false),
+    (0x30b0, &[0xab, 0xb0], false),
+    (0x30b1, &[0xab, 0xb1], false),
+    (0x30b2, &[0xab, 0xb2], false),
+    (0x30b3, &[0xab, 0xb3], false),
+    (0x30b4, &[0xab, 0xb4], false),
+    (0x30b5, &[0xab, 0xb5], false),
+    (0x30b6, &[0xab, 0xb6], false),
+    (0x30b7, &[0xab, 0xb7], false),
+    (0x30b8, &[0xab, 0xb8], false),
+    (0x30b9, &[0xab, 0xb9], false),
+    (0x30ba, &[0xab, 0xba], false),
+    (0x30bb, &[0xab, 0xbb], false),
+    (0x30bc, &[0xab, 0xbc], false),
+    (0x30bd, &[0xab, 0xbd], false),
+    (0x30be, &[0xab, 0xbe], false),
+    (0x30bf, &[0xab, 0xbf], false),
+    (0x30c0, &[0xab, 0xc0], false),
+    (0x30c1, &[0xab, 0xc1], false),
+    (0x30c2, &[0xab, 0xc2], false),
+    (0x30c3, &[0xab, 0xc3], false),
+    (0x30c4, &[0xab, 0xc4], false),
+    (0x30c5, &[0xab, 0xc5], false),
+    (0x30c6, &[0xab, 0xc6], false),
+    (0x30c7, &[0xab, 0xc7], false),
+    (0x30c8, &[0xab, 0xc8], false),
+    (0x30c9, &[0xab, 0xc9], false),
+    (0x30ca, &[0xab, 0xca], false),
+    (0x30cb, &[0xab, 0xcb], false),
+    (0x30cc, &[0xab, 0xcc], false),
+    (0x30cd, &[0xab, 0xcd], false),
+    (0x30ce, &[0xab, 0xce], false),
+    (0x30cf, &[0xab, 0xcf], false),
+    (0x30d0, &[0xab, 0xd0], false),
+    (0x30d1, &[0xab, 0xd1], false),
+    (0x30d2, &[0xab, 0xd2], false),
+    (0x30d3, &[0xab, 0xd3], false),
+    (0x30d4, &[0xab, 0xd4], false),
+    (0x30d5, &[0xab, 0xd5], false),
+    (0x30d6, &[0xab, 0xd6], false),
+    (0x30d7, &[0xab, 0xd7], false),
+    (0x30d8, &[0xab, 0xd8], false),
+    (0x30d9, &[0xab, 0xd9], false),
+    (0x30da, &[0xab, 0xda], false),
+    (0x30db, &[0xab, 0xdb], false),
+    (0x30dc, &[0xab, 0xdc], false),
+    (0x30dd, &[0xab, 0xdd], false),
+    (0x30de, &[0xab, 0xde], false),
+    (0x30df, &[0xab, 0xdf], false),
+    (0x30e0, &[0xab, 0xe0], false),
+    (0x30e1, &[0xab, 0xe1], false),
+    (0x30e2, &[0xab, 0xe2], false),
+    (0x30e3, &[0xab, 0xe3], false),
+    (0x30e4, &[0xab, 0xe4], false),
+    (0x30e5, &[0xab, 0xe5], false),
+    (0x30e6, &[0xab, 0xe6], false),
+    (0x30e7, &[0xab, 0xe7], false),
+    (0x30e8, &[0xab, 0xe8], false),
+    (0x30e9, &[0xab, 0xe9], false),
+    (0x30ea, &[0xab, 0xea], false),
+    (0x30eb, &[0xab, 0xeb], false),
+    (0x30ec, &[0xab, 0xec], false),
+    (0x30ed, &[0xab, 0xed], false),
+    (0x30ee, &[0xab, 0xee], false),
+    (0x30ef, &[0xab, 0xef], false),
+    (0x30f0, &[0xab, 0xf0], false),
+    (0x30f1, &[0xab, 0xf1], false),
+    (0x30f2, &[0xab, 0xf2], false),
+    (0x30f3, &[0xab, 0xf3], false),
+    (0x30f4, &[0xab, 0xf4], false),
+    (0x30f5, &[0xab, 0xf5], false),
+    (0x30f6, &[0xab, 0xf6], false),
+    (0x21f0, &[0xac, 0x41], false),
+    (0xf843, &[0xac, 0x42], false),
+    (0x27b5, &[0xac, 0x43], false),
+    (0x2964, &[0xac, 0x44], true),
+    (0x2962, &[0xac, 0x45], true),
+    (0x21e8, &[0xac, 0x46], true),
+    (0x21e6, &[0xac, 0x47], true),
+    (0x27a4, &[0xac, 0x48], false),
+    (0xf844, &[0xac, 0x49], false),
+    (0xf84b, &[0xac, 0x4a], false),
+    (0xf84a, &[0xac, 0x4b], false),
+    (0x21c0, &[0xac, 0x4c], true),
+    (0x21bc, &[0xac, 0x4d], true),
+    (0x21e8, &[0xac, 0x4e], true),
+    (0x21e6, &[0xac, 0x4f], true),
+    (0x21b6, &[0xac, 0x50], false),
+    (0x21b7, &[0xac, 0x51], false),
+    (0x219d, &[0xac, 0x52], false),
+    (0x219c, &[0xac, 0x53], false),
+    (0xf842, &[0xac, 0x54], false),
+    (0x2190, &[0xac, 0x55], true),
+    (0x2192, &[0xac, 0x56], true),
+    (0x2191, &[0xac, 0x57], true),
+    (0x2193, &[0xac, 0x58], true),
+    (0x21e6, &[0xac, 0x59], true),
+    (0x21e8, &[0xac, 0x5a], true),
+    (0x21e7, &[0xac, 0x5b], true),
+    (0x21e9, &[0xac, 0x5c], true),
+    (0x2190, &[0xac, 0x5d], true),
+    (0x2794, &[0xac, 0x5e], false),
+    (0xf845, &[0xac, 0x5f], false),
+    (0x2191, &[0xac, 0x60], true),
+    (0x2193, &[0xac, 0x61], true),
+    (0x2190, &[0xac, 0x62], true),
+    (0x2192, &[0xac, 0x63], true),
+    (0x2191, &[0xac, 0x64], true),
+    (0x2193, &[0xac, 0x65], true),
+    (0x2190, &[0xac, 0x66], true),
+    (0x2192, &[0xac, 0x67], true),
+    (0x2191, &[0xac, 0x68], true),
+    (0x2193, &[0xac, 0x69], true),
+    (0x21e0, &[0xac, 0x6a], false),
+    (0x21e2, &[0xac, 0x6b], false),
+    (0x21e1, &[0xac, 0x6c], false),
+    (0x21e3, &[0xac, 0x6d], false),
+    (0x21e6, &[0xac, 0x6e], true),
+    (0x21e8, &[0xac, 0x6f], true),
+    (0x21e7, &[0xac, 0x70], true),
+    (0x21e9, &[0xac, 0x71], true),
+    (0x21e6, &[0xac, 0x72], false),
+    (0x21e8, &[0xac, 0x73], false),
+    (0x21e7, &[0xac, 0x74], false),
+    (0x21e9, &[0xac, 0x75], false),
+    (0x2936, &[0xac, 0x76], true),
+    (0x21b1, &[0xac, 0x77], true),
+    (0x21bb, &[0xac, 0x78], true),
+    (0x2935, &[0xac, 0x79], true),
+    (0x21b0, &[0xac, 0x7a], true),
+    (0x2937, &[0xac, 0x7b], true),
+    (0x2939, &[0xac, 0x7c], true),
+    (0x2934, &[0xac, 0x7d], true),
+    (0x2936, &[0xac, 0x81], true),
+    (0x21b1, &[0xac, 0x82], true),
+    (0x21bb, &[0xac, 0x83], true),
+    (0x2935, &[0xac, 0x84], true),
+    (0x21b0, &[0xac, 0x85], true),
+    (0x2937, &[0xac, 0x86], true),
+    (0x2939, &[0xac, 0x87], true),
+    (0x2934, &[0xac, 0x88], true),
+    (0x2190, &[0xac, 0x89], true),
+    (0x2192, &[0xac, 0x8a], true),
+    (0x2191, &[0xac, 0x8b], true),
+    (0x2193, &[0xac, 0x8c], true),
+    (0x261d, &[0xac, 0x8d], false),
+    (0x261f, &[0xac, 0x8e], false),
+    (0x261d, &[0xac, 0x8f], true),
+    (0x261f, &[0xac, 0x90], true),
+    (0x0410, &[0xac, 0xa1], false),
+    (0x0411, &[0xac, 0xa2], false),
+    (0x0412, &[0xac, 0xa3], false),
+    (0x0413, &[0xac, 0xa4], false),
+    (0x0414, &[0xac, 0xa5], false),
+    (0x0415, &[0xac, 0xa6], false),
+    (0x0401, &[0xac, 0xa7], false),
+    (0x0416, &[0xac, 0xa8], false),
+    (0x0417, &[0xac, 0xa9], false),
+    (0x0418, &[0xac, 0xaa], false),
+    (0x0419, &[0xac, 0xab], false),
+    (0x041a, &[0xac, 0xac], false),
+    (0x041b, &[0xac, 0xad], false),
+    (0x041c, &[0xac, 0xae], false),
+    (0x041d, &[0xac, 0xaf], false),
+    (0x041e, &[0xac, 0xb0], false),
+    (0x041f, &[0xac, 0xb1], false),
+    (0x0420, &[0xac, 0xb2], false),
+    (0x0421, &[0xac, 0xb3], false),
+    (0x0422, &[0xac, 0xb4], false),
+    (0x0423, &[0xac, 0xb5], false),
+    (0x0424, &[0xac, 0xb6], false),
+    (0x0425, &[0xac, 0xb7], false),
+    (0x0426, &[0xac, 0xb8], false),
+    (0x0427, &[0xac, 0xb9], false),
+    (0x0428, &[0xac, 0xba], false),
+    (0x0429, &[0xac, 0xbb], false),
+    (0x042a, &[0xac, 0xbc], false),
+    (0x042b, &[0xac, 0xbd], false),
+    (0x042c, &[0xac, 0xbe], false),
+    (0x042d, &[0xac, 0xbf], false),
+    (0x042e, &[0xac, 0xc0], false),
+    (0x042f, &[0xac, 0xc1], false),
+    (0x0430, &[0xac, 0xd1], false),
+    (0x0431, &[0xac, 0xd2], false),
+    (0x0432, &[0xac, 0xd3], false),
+    (0x0433, &[0xac, 0xd4], false),
+    (0x0434, &[0xac, 0xd5], false),
+    (0x0435, &[0xac, 0xd6], false),
+    (0x0451, &[0xac, 0xd7], false),
+    (0x0436, &[0xac, 0xd8], false),
+    (0x0437, &[0xac, 0xd9], false),
+    (0x0438, &[0xac, 0xda], false),
+    (0x0439, &[0xac, 0xdb], false),
+    (0x043a, &[0xac, 0xdc], false),
+    (0x043b, &[0xac, 0xdd], false),
+    (0x043c, &[0xac, 0xde], false),
+    (0x043d, &[0xac, 0xdf], false),
+    (0x043e, &[0xac, 0xe0], false),
+    (0x043f, &[0xac, 0xe1], false),
+    (0x0440, &[0xac, 0xe2], false),
+    (0x0441, &[0xac, 0xe3], false),
+    (0x0442, &[0xac, 0xe4], false),
+    (0x0443, &[0xac, 0xe5], false),
+    (0x0444, &[0xac, 0xe6], false),
+    (0x0445, &[0xac, 0xe7], false),
+    (0x0446, &[0xac, 0xe8], false),
+    (0x0447, &[0xac, 0xe9], false),
+    (0x0448, &[0xac, 0xea], false),
+    (0x0449, &[0xac, 0xeb], false),
+    (0x044a, &[0xac, 0xec], false),
+    (0x044b, &[0xac, 0xed], false),
+    (0x044c, &[0xac, 0xee], false),
+    (0x044d, &[0xac, 0xef], false),
+    (0x044e, &[0xac, 0xf0], false),
+    (0x044f, &[0xac, 0xf1], false),
+    (0x3290, &[0xad, 0x70], false),
+    (0x328a, &[0xad, 0x71], false),
+    (0x328b, &[0xad, 0x72], false),
+    (0x328c, &[0xad, 0x73], false),
+    (0x328d, &[0xad, 0x74], false),
+    (0x328e, &[0xad, 0x75], false),
+    (0x328f, &[0xad, 0x76], false),
+    (0x300c, &[0xad, 0xa1], true),
+    (0x300d, &[0xad, 0xa2], true),
+    (0x300e, &[0xad, 0xa3], true),
+    (0x300f, &[0xad, 0xa4], true),
+    (0x21e8, &[0xad, 0xa5], true),
+    (0x21e6, &[0xad, 0xa6], true),
+    (0x21e7, &[0xad, 0xa7], true),
+    (0x21e9, &[0xad, 0xa8], true),
+    (0x301e, &[0xad, 0xa9], false),
+    (0x301f, &[0xad, 0xaa], false),
+    (0x2036, &[0xad, 0xab], false),
+    (0x2033, &[0xad, 0xac], true),
+    (0x2035, &[0xad, 0xad], false),
+    (0x2032, &[0xad, 0xae], true),
+    (0x21e7, &[0xad, 0xaf], true),
+    (0x0021, &[0xad, 0xb0], true),
+    (0xac00, &[0xb0, 0xa1], false),
+    (0xac01, &[0xb0, 0xa2], false),
+    (0xac04, &[0xb0, 0xa3], false),
+    (0xac07, &[0xb0, 0xa4], false),
+    (0xac08, &[0xb0, 0xa5], false),
+    (0xac09, &[0xb0, 0xa6], false),
+    (0xac0a, &[0xb0, 0xa7], false),
+    (0xac10, &[0xb0, 0xa8], false),
+    (0xac11, &[0xb0, 0xa9], false),
+    (0xac12, &[0xb0, 0xaa], false),
+    (0xac13, &[0xb0, 0xab], false),
+    (0xac14, &[0xb0, 0xac], false),
+    (0xac15, &[0xb0, 0xad], false),
+    (0xac16, &[0xb0, 0xae], false),
+    (0xac17, &[0xb0, 0xaf], false),
+    (0xac19, &[0xb0, 0xb0], false),
+    (0xac1a, &[0xb0, 0xb1], false),
+    (0xac1b, &[0xb0, 0xb2], false),
+    (0xac1c, &[0xb0, 0xb3], false),
+    (0xac1d, &[0xb0, 0xb4], false),
+    (0xac20, &[0xb0, 0xb5], false),
+    (0xac24, &[0xb0, 0xb6], false),
+    (0xac2c, &[0xb0, 0xb7], false),
+    (0xac2d, &[0xb0, 0xb8], false),
+    (0xac2f, &[0xb0, 0xb9], false),
+    (0xac30, &[0xb0, 0xba], false),
+    (0xac31, &[0xb0, 0xbb], false),
+    (0xac38, &[0xb0, 0xbc], false),
+    (0xac39, &[0xb0, 0xbd], false),
+    (0xac3c, &[0xb0, 0xbe], false),
+    (0xac40, &[0xb0, 0xbf], false),
+    (0xac4b, &[0xb0, 0xc0], false),
+    (0xac4d, &[0xb0, 0xc1], false),
+    (0xac54, &[0xb0, 0xc2], false),
+    (0xac58, &[0xb0, 0xc3], false),
+    (0xac5c, &[0xb0, 0xc4], false),
+    (0xac70, &[0xb0, 0xc5], false),
+    (0xac71, &[0xb0, 0xc6], false),
+    (0xac74, &[0xb0, 0xc7], false),
+    (0xac77, &[0xb0, 0xc8], false),
+    (0xac78, &[0xb0, 0xc9], false),
+    (0xac7a, &[0xb0, 0xca], false),
+    (0xac80, &[0xb0, 0xcb], false),
+    (0xac81, &[0xb0, 0xcc], false),
+    (0xac83, &[0xb0, 0xcd], false),
+    (0xac84, &[0xb0, 0xce], false),
+    (0xac85, &[0xb0, 0xcf], false),
+    (0xac86, &[0xb0, 0xd0], false),
+    (0xac89, &[0xb0, 0xd1], false),
+    (0xac8a, &[0xb0, 0xd2], false),
+    (0xac8b, &[0xb0, 0xd3], false),
+    (0xac8c, &[0xb0, 0xd4], false),
+    (0xac90, &[0xb0, 0xd5], false),
+    (0xac94, &[0xb0, 0xd6], false),
+    (0xac9c, &[0xb0, 0xd7], false),
+    (0xac9d, &[0xb0, 0xd8], false),
+    (0xac9f, &[0xb0, 0xd9], false),
+    (0xaca0, &[0xb0, 0xda], false),
+    (0xaca1, &[0xb0, 0xdb], false),
+    (0xaca8, &[0xb0, 0xdc], false),
+    (0xaca9, &[0xb0, 0xdd], false),
+    (0xacaa, &[0xb0, 0xde], false),
+    (0xacac, &[0xb0, 0xdf], false),
+    (0xacaf, &[0xb0, 0xe0], false),
+    (0xacb0, &[0xb0, 0xe1], false),
+    (0xacb8, &[0xb0, 0xe2], false),
+    (0xacb9, &[0xb0, 0xe3], false),
+    (0xacbb, &[0xb0, 0xe4], false),
+    (0xacbc, &[0xb0, 0xe5], false),
+    (0xacbd, &[0xb0, 0xe6], false),
+    (0xacc1, &[0xb0, 0xe7], false),
+    (0xacc4, &[0xb0, 0xe8], false),
+    (0xacc8, &[0xb0, 0xe9], false),
+    (0xaccc, &[0xb0, 0xea], false),
+    (0xacd5, &[0xb0, 0xeb], false),
+    (0xacd7, &[0xb0, 0xec], false),
+    (0xace0, &[0xb0, 0xed], false),
+    (0xace1, &[0xb0, 0xee], false),
+    (0xace4, &[0xb0, 0xef], false),
+    (0xace7, &[0xb0, 0xf0], false),
+    (0xace8, &[0xb0, 0xf1], false),
+    (0xacea, &[0xb0, 0xf2], false),
+    (0xacec, &[0xb0, 0xf3], false),
+    (0xacef, &[0xb0, 0xf4], false),
+    (0xacf0, &[0xb0, 0xf5], false),
+    (0xacf1, &[0xb0, 0xf6], false),
+    (0xacf3, &[0xb0, 0xf7], false),
+    (0xacf5, &[0xb0, 0xf8], false),
+    (0xacf6, &[0xb0, 0xf9], false),
+    (0xacfc, &[0xb0, 0xfa], false),
+    (0xacfd, &[0xb0, 0xfb], false),
+    (0xad00, &[0xb0, 0xfc], false),
+    (0xad04, &[0xb0, 0xfd], false),
+    (0xad06, &[0xb0, 0xfe], false),
+    (0xad0c, &[0xb1, 0xa1], false),
+    (0xad0d, &[0xb1, 0xa2], false),
+    (0xad0f, &[0xb1, 0xa3], false),
+    (0xad11, &[0xb1, 0xa4], false),
+    (0xad18, &[0xb1, 0xa5], false),
+    (0xad1c, &[0xb1, 0xa6], false),
+    (0xad20, &[0xb1, 0xa7], false),
+    (0xad29, &[0xb1, 0xa8], false),
+    (0xad2c, &[0xb1, 0xa9], false),
+    (0xad2d, &[0xb1, 0xaa], false),
+    (0xad34, &[0xb1, 0xab], false),
+    (0xad35, &[0xb1, 0xac], false),
+    (0xad38, &[0xb1, 0xad], false),
+    (0xad3c, &[0xb1, 0xae], false),
+    (0xad44, &[0xb1, 0xaf], false),
+    (0xad45, &[0xb1, 0xb0], false),
+    (0xad47, &[0xb1, 0xb1], false),
+    (0xad49, &[0xb1, 0xb2], false),
+    (0xad50, &[0xb1, 0xb3], false),
+    (0xad54, &[0xb1, 0xb4], false),
+    (0xad58, &[0xb1, 0xb5], false),
+    (0xad61, &[0xb1, 0xb6], false),
+    (0xad63, &[0xb1, 0xb7], false),
+    (0xad6c, &[0xb1, 0xb8], false),
+    (0xad6d, &[0xb1, 0xb9], false),
+    (0xad70, &[0xb1, 0xba], false),
+    (0xad73, &[0xb1, 0xbb], false),
+    (0xad74, &[0xb1, 0xbc], false),
+    (0xad75, &[0xb1, 0xbd], false),
+    (0xad76, &[0xb1, 0xbe], false),
+    (0xad7b, &[0xb1, 0xbf], false),
+    (0xad7c, &[0xb1, 0xc0], false),
+    (0xad7d, &[0xb1, 0xc1], false),
+    (0xad7f, &[0xb1, 0xc2], false),
+    (0xad81, &[0xb1, 0xc3], false),
+    (0xad82, &[0xb1, 0xc4], false),
+    (0xad88, &[0xb1, 0xc5], false),
+    (0xad89, &[0xb1, 0xc6], false),
+    (0xad8c, &[0xb1, 0xc7], false),
+    (0xad90, &[0xb1, 0xc8], false),
+    (0xad9c, &[0xb1, 0xc9], false),
+    (0xad9d, &[0xb1, 0xca], false),
+    (0xada4, &[0xb1, 0xcb], false),
+    (0xadb7, &[0xb1, 0xcc], false),
+    (0xadc0, &[0xb1, 0xcd], false),
+    (0xadc1, &[0xb1, 0xce], false),
+    (0xadc4, &[0xb1, 0xcf], false),
+    (0xadc8, &[0xb1, 0xd0], false),
+    (0xadd0, &[0xb1, 0xd1], false),
+    (0xadd1, &[0xb1, 0xd2], false),
+    (0xadd3, &[0xb1, 0xd3], false),
+    (0xaddc, &[0xb1, 0xd4], false),
+    (0xade0, &[0xb1, 0xd5], false),
+    (0xade4, &[0xb1, 0xd6], false),
+    (0xadf8, &[0xb1, 0xd7], false),
+    (0xadf9, &[0xb1, 0xd8], false),
+    (0xadfc, &[0xb1, 0xd9], false),
+    (0xadff, &[0xb1, 0xda], false),
+    (0xae00, &[0xb1, 0xdb], false),
+    (0xae01, &[0xb1, 0xdc], false),
+    (0xae08, &[0xb1, 0xdd], false),
+    (0xae09, &[0xb1, 0xde], false),
+    (0xae0b, &[0xb1, 0xdf], false),
+    (0xae0d, &[0xb1, 0xe0], false),
+    (0xae14, &[0xb1, 0xe1], false),
+    (0xae30, &[0xb1, 0xe2], false),
+    (0xae31, &[0xb1, 0xe3], false),
+    (0xae34, &[0xb1, 0xe4], false),
+    (0xae37, &[0xb1, 0xe5], false),
+    (0xae38, &[0xb1, 0xe6], false),
+    (0xae3a, &[0xb1, 0xe7], false),
+    (0xae40, &[0xb1, 0xe8], false),
+    (0xae41, &[0xb1, 0xe9], false),
+    (0xae43, &[0xb1, 0xea], false),
+    (0xae45, &[0xb1, 0xeb], false),
+    (0xae46, &[0xb1, 0xec], false),
+    (0xae4a, &[0xb1, 0xed], false),
+    (0xae4c, &[0xb1, 0xee], false),
+    (0xae4d, &[0xb1, 0xef], false),
+    (0xae4e, &[0xb1, 0xf0], false),
+    (0xae50, &[0xb1, 0xf1], false),
+    (0xae54, &[0xb1, 0xf2], false),
+    (0xae56, &[0xb1, 0xf3], false),
+    (0xae5c, &[0xb1, 0xf4], false),
+    (0xae5d, &[0xb1, 0xf5], false),
+    (0xae5f, &[0xb1, 0xf6], false),
+    (0xae60, &[0xb1, 0xf7], false),
+    (0xae61, &[0xb1, 0xf8], false),
+    (0xae65, &[0xb1, 0xf9], false),
+    (0xae68, &[0xb1, 0xfa], false),
+    (0xae69, &[0xb1, 0xfb], false),
+    (0xae6c, &[0xb1, 0xfc], false),
+    (0xae70, &[0xb1, 0xfd], false),
+    (0xae78, &[0xb1, 0xfe], false),
+    (0xae79, &[0xb2, 0xa1], false),
+    (0xae7b, &[0xb2, 0xa2], false),
+    (0xae7c, &[0xb2, 0xa3], false),
+    (0xae7d, &[0xb2, 0xa4], false),
+    (0xae84, &[0xb2, 0xa5], false),
+    (0xae85, &[0xb2, 0xa6], false),
+    (0xae8c, &[0xb2, 0xa7], false),
+    (0xaebc, &[0xb2, 0xa8], false),
+    (0xaebd, &[0xb2, 0xa9], false),
+    (0xaebe, &[0xb2, 0xaa], false),
+    (0xaec0, &[0xb2, 0xab], false),
+    (0xaec4, &[0xb2, 0xac], false),
+    (0xaecc, &[0xb2, 0xad], false),
+    (0xaecd, &[0xb2, 0xae], false),
+    (0xaecf, &[0xb2, 0xaf], false),
+    (0xaed0, &[0xb2, 0xb0], false),
+    (0xaed1, &[0xb2, 0xb1], false),
+    (0xaed8, &[0xb2, 0xb2], false),
+    (0xaed9, &[0xb2, 0xb3], false),
+    (0xaedc, &[0xb2, 0xb4], false),
+    (0xaee8, &[0xb2, 0xb5], false),
+    (0xaeeb, &[0xb2, 0xb6], false),
+    (0xaeed, &[0xb2, 0xb7], false),
+    (0xaef4, &[0xb2, 0xb8], false),
+    (0xaef8, &[0xb2, 0xb9], false),
+    (0xaefc, &[0xb2, 0xba], false),
+    (0xaf07, &[0xb2, 0xbb], false),
+    (0xaf08, &[0xb2, 0xbc], false),
+    (0xaf0d, &[0xb2, 0xbd], false),
+    (0xaf10, &[0xb2, 0xbe], false),
+    (0xaf2c, &[0xb2, 0xbf], false),
+    (0xaf2d, &[0xb2, 0xc0], false),
+    (0xaf30, &[0xb2, 0xc1], false),
+    (0xaf32, &[0xb2, 0xc2], false),
+    (0xaf34, &[0xb2, 0xc3], false),
+    (0xaf3c, &[0xb2, 0xc4], false),
+    (0xaf3d, &[0xb2, 0xc5], false),
+    (0xaf3f, &[0xb2, 0xc6], false),
+    (0xaf41, &[0xb2, 0xc7], false),
+    (0xaf42, &[0xb2, 0xc8], false),
+    (0xaf43, &[0xb2, 0xc9], false),
+    (0xaf48, &[0xb2, 0xca], false),
+    (0xaf49, &[0xb2, 0xcb], false),
+    (0xaf50, &[0xb2, 0xcc], false),
+    (0xaf5c, &[0xb2, 0xcd], false),
+    (0xaf5d, &[0xb2, 0xce], false),
+    (0xaf64, &[0xb2, 0xcf], false),
+    (0xaf65, &[0xb2, 0xd0], false),
+    (0xaf79, &[0xb2, 0xd1], false),
+    (0xaf80, &[0xb2, 0xd2], false),
+    (0xaf84, &[0xb2, 0xd3], false),
+    (0xaf88, &[0xb2, 0xd4], false),
+    (0xaf90, &[0xb2, 0xd5], false),
+    (0xaf91, &[0xb2, 0xd6], false),
+    (0xaf95, &[0xb2, 0xd7], false),
+    (0xaf9c, &[0xb2, 0xd8], false),
+    (0xafb8, &[0xb2, 0xd9], false),
+    (0xafb9, &[0xb2, 0xda], false),
+    (0xafbc, &[0xb2, 0xdb], false),
+    (0xafc0, &[0xb2, 0xdc], false),
+    (0xafc7, &[0xb2, 0xdd], false),
+    (0xafc8, &[0xb2, 0xde], false),
+    (0xafc9, &[0xb2, 0xdf], false),
+    (0xafcb, &[0xb2, 0xe0], false),
+    (0xafcd, &[0xb2, 0xe1], false),
+    (0xafce, &[0xb2, 0xe2], false),
+    (0xafd4, &[0xb2, 0xe3], false),
+    (0xafdc, &[0xb2, 0xe4], false),
+    (0xafe8, &[0xb2, 0xe5], false),
+    (0xafe9, &[0xb2, 0xe6], false),
+    (0xaff0, &[0xb2, 0xe7], false),
+    (0xaff1, &[0xb2, 0xe8], false),
+    (0xaff4, &[0xb2, 0xe9], false),
+    (0xaff8, &[0xb2, 0xea], false),
+    (0xb000, &[0xb2, 0xeb], false),
+    (0xb001, &[0xb2, 0xec], false),
+    (0xb004, &[0xb2, 0xed], false),
+    (0xb00c, &[0xb2, 0xee], false),
+    (0xb010, &[0xb2, 0xef], false),
+    (0xb014, &[0xb2, 0xf0], false),
+    (0xb01c, &[0xb2, 0xf1], false),
+    (0xb01d, &[0xb2, 0xf2], false),
+    (0xb028, &[0xb2, 0xf3], false),
+    (0xb044, &[0xb2, 0xf4], false),
+    (0xb045, &[0xb2, 0xf5], false),
+    (0xb048, &[0xb2, 0xf6], false),
+    (0xb04a, &[0xb2, 0xf7], false),
+    (0xb04c, &[0xb2, 0xf8], false),
+    (0xb04e, &[0xb2, 0xf9], false),
+    (0xb053, &[0xb2, 0xfa], false),
+    (0xb054, &[0xb2, 0xfb], false),
+    (0xb055, &[0xb2, 0xfc], false),
+    (0xb057, &[0xb2, 0xfd], false),
+    (0xb059, &[0xb2, 0xfe], false),
+    (0xb05d, &[0xb3, 0xa1], false),
+    (0xb07c, &[0xb3, 0xa2], false),
+    (0xb07d, &[0xb3, 0xa3], false),
+    (0xb080, &[0xb3, 0xa4], false),
+    (0xb084, &[0xb3, 0xa5], false),
+    (0xb08c, &[0xb3, 0xa6], false),
+    (0xb08d, &[0xb3, 0xa7], false),
+    (0xb08f, &[0xb3, 0xa8], false),
+    (0xb091, &[0xb3, 0xa9], false),
+    (0xb098, &[0xb3, 0xaa], false),
+    (0xb099, &[0xb3, 0xab], false),
+    (0xb09a, &[0xb3, 0xac], false),
+    (0xb09c, &[0xb3, 0xad], false),
+    (0xb09f, &[0xb3, 0xae], false),
+    (0xb0a0, &[0xb3, 0xaf], false),
+    (0xb0a1, &[0xb3, 0xb0], false),
+    (0xb0a2, &[0xb3, 0xb1], false),
+    (0xb0a8, &[0xb3, 0xb2], false),
+    (0xb0a9, &[0xb3, 0xb3], false),
+    (0xb0ab, &[0xb3, 0xb4], false),
+    (0xb0ac, &[0xb3, 0xb5], false),
+    (0xb0ad, &[0xb3, 0xb6], false),
+    (0xb0ae, &[0xb3, 0xb7], false),
+    (0xb0af, &[0xb3, 0xb8], false),
+    (0xb0b1, &[0xb3, 0xb9], false),
+    (0xb0b3, &[0xb3, 0xba], false),
+    (0xb0b4, &[0xb3, 0xbb], false),
+    (0xb0b5, &[0xb3, 0xbc], false),
+    (0xb0b8, &[0xb3, 0xbd], false),
+    (0xb0bc, &[0xb3, 0xbe], false),
+    (0xb0c4, &[0xb3, 0xbf], false),
+    (0xb0c5, &[0xb3, 0xc0], false),
+    (0xb0c7, &[0xb3, 0xc1], false),
+    (0xb0c8, &[0xb3, 0xc2], false),
+    (0xb0c9, &[0xb3, 0xc3], false),
+    (0xb0d0, &[0xb3, 0xc4], false),
+    (0xb0d1, &[0xb3, 0xc5], false),
+    (0xb0d4, &[0xb3, 0xc6], false),
+    (0xb0d8, &[0xb3, 0xc7], false),
+    (0xb0e0, &[0xb3, 0xc8], false),
+    (0xb0e5, &[0xb3, 0xc9], false),
+    (0xb108, &[0xb3, 0xca], false),
+    (0xb109, &[0xb3, 0xcb], false),
+    (0xb10b, &[0xb3, 0xcc], false),
+    (0xb10c, &[0xb3, 0xcd], false),
+    (0xb110, &[0xb3, 0xce], false),
+    (0xb112, &[0xb3, 0xcf], false),
+    (0xb113, &[0xb3, 0xd0], false),
+    (0xb118, &[0xb3, 0xd1], false),
+    (0xb119, &[0xb3, 0xd2], false),
+    (0xb11b, &[0xb3, 0xd3], false),
+    (0xb11c, &[0xb3, 0xd4], false),
+    (0xb11d, &[0xb3, 0xd5], false),
+    (0xb123, &[0xb3, 0xd6], false),
+    (0xb124, &[0xb3, 0xd7], false),
+    (0xb125, &[0xb3, 0xd8], false),
+    (0xb128, &[0xb3, 0xd9], false),
+    (0xb12c, &[0xb3, 0xda], false),
+    (0xb134, &[0xb3, 0xdb], false),
+    (0xb135, &[0xb3, 0xdc], false),
+    (0xb137, &[0xb3, 0xdd], false),
+    (0xb138, &[0xb3, 0xde], false),
+    (0xb139, &[0xb3, 0xdf], false),
+    (0xb140, &[0xb3, 0xe0], false),
+    (0xb141, &[0xb3, 0xe1], false),
+    (0xb144, &[0xb3, 0xe2], false),
+    (0xb148, &[0xb3, 0xe3], false),
+    (0xb150, &[0xb3, 0xe4], false),
+    (0xb151, &[0xb3, 0xe5], false),
+    (0xb154, &[0xb3, 0xe6], false),
+    (0xb155, &[0xb3, 0xe7], false),
+    (0xb158, &[0xb3, 0xe8], false),
+    (0xb15c, &[0xb3, 0xe9], false),
+    (0xb160, &[0xb3, 0xea], false),
+    (0xb178, &[0xb3, 0xeb], false),
+    (0xb179, &[0xb3, 0xec], false),
+    (0xb17c, &[0xb3, 0xed], false),
+    (0xb180, &[0xb3, 0xee], false),
+    (0xb182, &[0xb3, 0xef], false),
+    (0xb188, &[0xb3, 0xf0], false),
+    (0xb189, &[0xb3, 0xf1], false),
+    (0xb18b, &[0xb3, 0xf2], false),
+    (0xb18d, &[0xb3, 0xf3], false),
+    (0xb192, &[0xb3, 0xf4], false),
+    (0xb193, &[0xb3, 0xf5], false),
+    (0xb194, &[0xb3, 0xf6], false),
+    (0xb198, &[0xb3, 0xf7], false),
+    (0xb19c, &[0xb3, 0xf8], false),
+    (0xb1a8, &[0xb3, 0xf9], false),
+    (0xb1cc, &[0xb3, 0xfa], false),
+    (0xb1d0, &[0xb3, 0xfb], false),
+    (0xb1d4, &[0xb3, 0xfc], false),
+    (0xb1dc, &[0xb3, 0xfd], false),
+    (0xb1dd, &[0xb3, 0xfe], false),
+    (0xb1df, &[0xb4, 0xa1], false),
+    (0xb1e8, &[0xb4, 0xa2], false),
+    (0xb1e9, &[0xb4, 0xa3], false),
+    (0xb1ec, &[0xb4, 0xa4], false),
+    (0xb1f0, &[0xb4, 0xa5], false),
+    (0xb1f9, &[0xb4, 0xa6], false),
+    (0xb1fb, &[0xb4, 0xa7], false),
+    (0xb1fd, &[0xb4, 0xa8], false),
+    (0xb204, &[0xb4, 0xa9], false),
+    (0xb205, &[0xb4, 0xaa], false),
+    (0xb208, &[0xb4, 0xab], false),
+    (0xb20b, &[0xb4, 0xac], false),
+    (0xb20c, &[0xb4, 0xad], false),
+    (0xb214, &[0xb4, 0xae], false),
+    (0xb215, &[0xb4, 0xaf], false),
+    (0xb217, &[0xb4, 0xb0], false),
+    (0xb219, &[0xb4, 0xb1], false),
+    (0xb220, &[0xb4, 0xb2], false),
+    (0xb234, &[0xb4, 0xb3], false),
+    (0xb23c, &[0xb4, 0xb4], false),
+    (0xb258, &[0xb4, 0xb5], false),
+    (0xb25c, &[0xb4, 0xb6], false),
+    (0xb260, &[0xb4, 0xb7], false),
+    (0xb268, &[0xb4, 0xb8], false),
+    (0xb269, &[0xb4, 0xb9], false),
+    (0xb274, &[0xb4, 0xba], false),
+    (0xb275, &[0xb4, 0xbb], false),
+    (0xb27c, &[0xb4, 0xbc], false),
+    (0xb284, &[0xb4, 0xbd], false),
+    (0xb285, &[0xb4, 0xbe], false),
+    (0xb289, &[0xb4, 0xbf], false),
+    (0xb290, &[0xb4, 0xc0], false),
+    (0xb291, &[0xb4, 0xc1], false),
+    (0xb294, &[0xb4, 0xc2], false),
+    (0xb298, &[0xb4, 0xc3], false),
+    (0xb299, &[0xb4, 0xc4], false),
+    (0xb29a, &[0xb4, 0xc5], false),
+    (0xb2a0, &[0xb4, 0xc6], false),
+    (0xb2a1, &[0xb4, 0xc7], false),
+    (0xb2a3, &[0xb4, 0xc8], false),
+    (0xb2a5, &[0xb4, 0xc9], false),
+    (0xb2a6, &[0xb4, 0xca], false),
+    (0xb2aa, &[0xb4, 0xcb], false),
+    (0xb2ac, &[0xb4, 0xcc], false),
+    (0xb2b0, &[0xb4, 0xcd], false),
+    (0xb2b4, &[0xb4, 0xce], false),
+    (0xb2c8, &[0xb4, 0xcf], false),
+    (0xb2c9, &[0xb4, 0xd0], false),
+    (0xb2cc, &[0xb4, 0xd1], false),
+    (0xb2d0, &[0xb4, 0xd2], false),
+    (0xb2d2, &[0xb4, 0xd3], false),
+    (0xb2d8, &[0xb4, 0xd4], false),
+    (0xb2d9, &[0xb4, 0xd5], false),
+    (0xb2db, &[0xb4, 0xd6], false),
+    (0xb2dd, &[0xb4, 0xd7], false),
+    (0xb2e2, &[0xb4, 0xd8], false),
+    (0xb2e4, &[0xb4, 0xd9], false),
+    (0xb2e5, &[0xb4, 0xda], false),
+    (0xb2e6, &[0xb4, 0xdb], false),
+    (0xb2e8, &[0xb4, 0xdc], false),
+    (0xb2eb, &[0xb4, 0xdd], false),
+    (0xb2ec, &[0xb4, 0xde], false),
+    (0xb2ed, &[0xb4, 0xdf], false),
+    (0xb2ee, &[0xb4, 0xe0], false),
+    (0xb2ef, &[0xb4, 0xe1], false),
+    (0xb2f3, &[0xb4, 0xe2], false),
+    (0xb2f4, &[0xb4, 0xe3], false),
+    (0xb2f5, &[0xb4, 0xe4], false),
+    (0xb2f7, &[0xb4, 0xe5], false),
+    (0xb2f8, &[0xb4, 0xe6], false),
+    (0xb2f9, &[0xb4, 0xe7], false),
+    (0xb2fa, &[0xb4, 0xe8], false),
+    (0xb2fb, &[0xb4, 0xe9], false),
+    (0xb2ff, &[0xb4, 0xea], false),
+    (0xb300, &[0xb4, 0xeb], false),
+    (0xb301, &[0xb4, 0xec], false),
+    (0xb304, &[0xb4, 0xed], false),
+    (0xb308, &[0xb4, 0xee], false),
+    (0xb310, &[0xb4, 0xef], false),
+    (0xb311, &[0xb4, 0xf0], false),
+    (0xb313, &[0xb4, 0xf1], false),
+    (0xb314, &[0xb4, 0xf2], false),
+    (0xb315, &[0xb4, 0xf3], false),
+    (0xb31c, &[0xb4, 0xf4], false),
+    (0xb354, &[0xb4, 0xf5], false),
+    (0xb355, &[0xb4, 0xf6], false),
+    (0xb356, &[0xb4, 0xf7], false),
+    (0xb358, &[0xb4, 0xf8], false),
+    (0xb35b, &[0xb4, 0xf9], false),
+    (0xb35c, &[0xb4, 0xfa], false),
+    (0xb35e, &[0xb4, 0xfb], false),
+    (0xb35f, &[0xb4, 0xfc], false),
+    (0xb364, &[0xb4, 0xfd], false),
+    (0xb365, &[0xb4, 0xfe], false),
+    (0xb367, &[0xb5, 0xa1], false),
+    (0xb369, &[0xb5, 0xa2], false),
+    (0xb36b, &[0xb5, 0xa3], false),
+    (0xb36e, &[0xb5, 0xa4], false),
+    (0xb370, &[0xb5, 0xa5], false),
+    (0xb371, &[0xb5, 0xa6], false),
+    (0xb374, &[0xb5, 0xa7], false),
+    (0xb378, &[0xb5, 0xa8], false),
+    (0xb380, &[0xb5, 0xa9], false),
+    (0xb381, &[0xb5, 0xaa], false),
+    (0xb383, &[0xb5, 0xab], false),
+    (0xb384, &[0xb5, 0xac], false),
+    (0xb385, &[0xb5, 0xad], false),
+    (0xb38c, &[0xb5, 0xae], false),
+    (0xb390, &[0xb5, 0xaf], false),
+    (0xb394, &[0xb5, 0xb0], false),
+    (0xb3a0, &[0xb5, 0xb1], false),
+    (0xb3a1, &[0xb5, 0xb2], false),
+    (0xb3a8, &[0xb5, 0xb3], false),
+    (0xb3ac, &[0xb5, 0xb4], false),
+    (0xb3c4, &[0xb5, 0xb5], false),
+    (0xb3c5, &[0xb5, 0xb6], false),
+    (0xb3c8, &[0xb5, 0xb7], false),
+    (0xb3cb, &[0xb5, 0xb8], false),
+    (0xb3cc, &[0xb5, 0xb9], false),
+    (0xb3ce, &[0xb5, 0xba], false),
+    (0xb3d0, &[0xb5, 0xbb], false),
+    (0xb3d4, &[0xb5, 0xbc], false),
+    (0xb3d5, &[0xb5, 0xbd], false),
+    (0xb3d7, &[0xb5, 0xbe], false),
+    (0xb3d9, &[0xb5, 0xbf], false),
+    (0xb3db, &[0xb5, 0xc0], false),
+    (0xb3dd, &[0xb5, 0xc1], false),
+    (0xb3e0, &[0xb5, 0xc2], false),
+    (0xb3e4, &[0xb5, 0xc3], false),
+    (0xb3e8, &[0xb5, 0xc4], false),
+    (0xb3fc, &[0xb5, 0xc5], false),
+    (0xb410, &[0xb5, 0xc6], false),
+    (0xb418, &[0xb5, 0xc7], false),
+    (0xb41c, &[0xb5, 0xc8], false),
+    (0xb420, &[0xb5, 0xc9], false),
+    (0xb428, &[0xb5, 0xca], false),
+    (0xb429, &[0xb5, 0xcb], false),
+    (0xb42b, &[0xb5, 0xcc], false),
+    (0xb434, &[0xb5, 0xcd], false),
+    (0xb450, &[0xb5, 0xce], false),
+    (0xb451, &[0xb5, 0xcf], false),
+    (0xb454, &[0xb5, 0xd0], false),
+    (0xb458, &[0xb5, 0xd1], false),
+    (0xb460, &[0xb5, 0xd2], false),
+    (0xb461, &[0xb5, 0xd3], false),
+    (0xb463, &[0xb5, 0xd4], false),
+    (0xb465, &[0xb5, 0xd5], false),
+    (0xb46c, &[0xb5, 0xd6], false),
+    (0xb480, &[0xb5, 0xd7], false),
+    (0xb488, &[0xb5, 0xd8], false),
+    (0xb49d, &[0xb5, 0xd9], false),
+    (0xb4a4, &[0xb5, 0xda], false),
+    (0xb4a8, &[0xb5, 0xdb], false),
+    (0xb4ac, &[0xb5, 0xdc], false),
+    (0xb4b5, &[0xb5, 0xdd], false),
+    (0xb4b7, &[0xb5, 0xde], false),
+    (0xb4b9, &[0xb5, 0xdf], false),
+    (0xb4c0, &[0xb5, 0xe0], false),
+    (0xb4c4, &[0xb5, 0xe1], false),
+    (0xb4c8, &[0xb5, 0xe2], false),
+    (0xb4d0, &[0xb5, 0xe3], false),
+    (0xb4d5, &[0xb5, 0xe4], false),
+    (0xb4dc, &[0xb5, 0xe5], false),
+    (0xb4dd, &[0xb5, 0xe6], false),
+    (0xb4e0, &[0xb5, 0xe7], false),
+    (0xb4e3, &[0xb5, 0xe8], false),
+    (0xb4e4, &[0xb5, 0xe9], false),
+    (0xb4e6, &[0xb5, 0xea], false),
+    (0xb4ec, &[0xb5, 0xeb], false),
+    (0xb4ed, &[0xb5, 0xec], false),
+    (0xb4ef, &[0xb5, 0xed], false),
+    (0xb4f1, &[0xb5, 0xee], false),
+    (0xb4f8, &[0xb5, 0xef], false),
+    (0xb514, &[0xb5, 0xf0], false),
+    (0xb515, &[0xb5, 0xf1], false),
+    (0xb518, &[0xb5, 0xf2], false),
+    (0xb51b, &[0xb5, 0xf3], false),
+    (0xb51c, &[0xb5, 0xf4], false),
+    (0xb524, &[0xb5, 0xf5], false),
+    (0xb525, &[0xb5, 0xf6], false),
+    (0xb527, &[0xb5, 0xf7], false),
+    (0xb528, &[0xb5, 0xf8], false),
+    (0xb529, &[0xb5, 0xf9], false),
+    (0xb52a, &[0xb5, 0xfa], false),
+    (0xb530, &[0xb5, 0xfb], false),
+    (0xb531, &[0xb5, 0xfc], false),
+    (0xb534, &[0xb5, 0xfd], false),
+    (0xb538, &[0xb5, 0xfe], false),
+    (0xb540, &[0xb6, 0xa1], false),
+    (0xb541, &[0xb6, 0xa2], false),
+    (0xb543, &[0xb6, 0xa3], false),
+    (0xb544, &[0xb6, 0xa4], false),
+    (0xb545, &[0xb6, 0xa5], false),
+    (0xb54b, &[0xb6, 0xa6], false),
+    (0xb54c, &[0xb6, 0xa7], false),
+    (0xb54d, &[0xb6, 0xa8], false),
+    (0xb550, &[0xb6, 0xa9], false),
+    (0xb554, &[0xb6, 0xaa], false),
+    (0xb55c, &[0xb6, 0xab], false),
+    (0xb55d, &[0xb6, 0xac], false),
+    (0xb55f, &[0xb6, 0xad], false),
+    (0xb560, &[0xb6, 0xae], false),
+    (0xb561, &[0xb6, 0xaf], false),
+    (0xb5a0, &[0xb6, 0xb0], false),
+    (0xb5a1, &[0xb6, 0xb1], false),
+    (0xb5a4, &[0xb6, 0xb2], false),
+    (0xb5a8, &[0xb6, 0xb3], false),
+    (0xb5aa, &[0xb6, 0xb4], false),
+    (0xb5ab, &[0xb6, 0xb5], false),
+    (0xb5b0, &[0xb6, 0xb6], false),
+    (0xb5b1, &[0xb6, 0xb7], false),
+    (0xb5b3, &[0xb6, 0xb8], false),
+    (0xb5b4, &[0xb6, 0xb9], false),
+    (0xb5b5, &[0xb6, 0xba], false),
+    (0xb5bb, &[0xb6, 0xbb], false),
+    (0xb5bc, &[0xb6, 0xbc], false),
+    (0xb5bd, &[0xb6, 0xbd], false),
+    (0xb5c0, &[0xb6, 0xbe], false),
+    (0xb5c4, &[0xb6, 0xbf], false),
+    (0xb5cc, &[0xb6, 0xc0], false),
+    (0xb5cd, &[0xb6, 0xc1], false),
+    (0xb5cf, &[0xb6, 0xc2], false),
+    (0xb5d0, &[0xb6, 0xc3], false),
+    (0xb5d1, &[0xb6, 0xc4], false),
+    (0xb5d8, &[0xb6, 0xc5], false),
+    (0xb5ec, &[0xb6, 0xc6], false),
+    (0xb610, &[0xb6, 0xc7], false),
+    (0xb611, &[0xb6, 0xc8], false),
+    (0xb614, &[0xb6, 0xc9], false),
+    (0xb618, &[0xb6, 0xca], false),
+    (0xb625, &[0xb6, 0xcb], false),
+    (0xb62c, &[0xb6, 0xcc], false),
+    (0xb634, &[0xb6, 0xcd], false),
+    (0xb648, &[0xb6, 0xce], false),
+    (0xb664, &[0xb6, 0xcf], false),
+    (0xb668, &[0xb6, 0xd0], false),
+    (0xb69c, &[0xb6, 0xd1], false),
+    (0xb69d, &[0xb6, 0xd2], false),
+    (0xb6a0, &[0xb6, 0xd3], false),
+    (0xb6a4, &[0xb6, 0xd4], false),
+    (0xb6ab, &[0xb6, 0xd5], false),
+    (0xb6ac, &[0xb6, 0xd6], false),
+    (0xb6b1, &[0xb6, 0xd7], false),
+    (0xb6d4, &[0xb6, 0xd8], false),
+    (0xb6f0, &[0xb6, 0xd9], false),
+    (0xb6f4, &[0xb6, 0xda], false),
+    (0xb6f8, &[0xb6, 0xdb], false),
+    (0xb700, &[0xb6, 0xdc], false),
+    (0xb701, &[0xb6, 0xdd], false),
+    (0xb705, &[0xb6, 0xde], false),
+    (0xb728, &[0xb6, 0xdf], false),
+    (0xb729, &[0xb6, 0xe0], false),
+    (0xb72c, &[0xb6, 0xe1], false),
+    (0xb72f, &[0xb6, 0xe2], false),
+    (0xb730, &[0xb6, 0xe3], false),
+    (0xb738, &[0xb6, 0xe4], false),
+    (0xb739, &[0xb6, 0xe5], false),
+    (0xb73b, &[0xb6, 0xe6], false),
+    (0xb744, &[0xb6, 0xe7], false),
+    (0xb748, &[0xb6, 0xe8], false),
+    (0xb74c, &[0xb6, 0xe9], false),
+    (0xb754, &[0xb6, 0xea], false),
+    (0xb755, &[0xb6, 0xeb], false),
+    (0xb760, &[0xb6, 0xec], false),
+    (0xb764, &[0xb6, 0xed], false),
+    (0xb768, &[0xb6, 0xee], false),
+    (0xb770, &[0xb6, 0xef], false),
+    (0xb771, &[0xb6, 0xf0], false),
+    (0xb773, &[0xb6, 0xf1], false),
+    (0xb775, &[0xb6, 0xf2], false),
+    (0xb77c, &[0xb6, 0xf3], false),
+    (0xb77d, &[0xb6, 0xf4], false),
+    (0xb780, &[0xb6, 0xf5], false),
+    (0xb784, &[0xb6, 0xf6], false),
+    (0xb78c, &[0xb6, 0xf7], false),
+    (0xb78d, &[0xb6, 0xf8], false),
+    (0xb78f, &[0xb6, 0xf9], false),
+    (0xb790, &[0xb6, 0xfa], false),
+    (0xb791, &[0xb6, 0xfb], false),
+    (0xb792, &[0xb6, 0xfc], false),
+    (0xb796, &[0xb6, 0xfd], false),
+    (0xb797, &[0xb6, 0xfe], false),
+    (0xb798, &[0xb7, 0xa1], false),
+    (0xb799, &[0xb7, 0xa2], false),
+    (0xb79c, &[0xb7, 0xa3], false),
+    (0xb7a0, &[0xb7, 0xa4], false),
+    (0xb7a8, &[0xb7, 0xa5], false),
+    (0xb7a9, &[0xb7, 0xa6], false),
+    (0xb7ab, &[0xb7, 0xa7], false),
+    (0xb7ac, &[0xb7, 0xa8], false),
+    (0xb7ad, &[0xb7, 0xa9], false),
+    (0xb7b4, &[0xb7, 0xaa], false),
+    (0xb7b5, &[0xb7, 0xab], false),
+    (0xb7b8, &[0xb7, 0xac], false),
+    (0xb7c7, &[0xb7, 0xad], false),
+    (0xb7c9, &[0xb7, 0xae], false),
+    (0xb7ec, &[0xb7, 0xaf], false),
+    (0xb7ed, &[0xb7, 0xb0], false),
+    (0xb7f0, &[0xb7, 0xb1], false),
+    (0xb7f4, &[0xb7, 0xb2], false),
+    (0xb7fc, &[0xb7, 0xb3], false),
+    (0xb7fd, &[0xb7, 0xb4], false),
+    (0xb7ff, &[0xb7, 0xb5], false),
+    (0xb800, &[0xb7, 0xb6], false),
+    (0xb801, &[0xb7, 0xb7], false),
+    (0xb807, &[0xb7, 0xb8], false),
+    (0xb808, &[0xb7, 0xb9], false),
+    (0xb809, &[0xb7, 0xba], false),
+    (0xb80c, &[0xb7, 0xbb], false),
+    (0xb810, &[0xb7, 0xbc], false),
+    (0xb818, &[0xb7, 0xbd], false),
+    (0xb819, &[0xb7, 0xbe], false),
+    (0xb81b, &[0xb7, 0xbf], false),
+    (0xb81d, &[0xb7, 0xc0], false),
+    (0xb824, &[0xb7, 0xc1], false),
+    (0xb825, &[0xb7, 0xc2], false),
+    (0xb828, &[0xb7, 0xc3], false),
+    (0xb82c, &[0xb7, 0xc4], false),
+    (0xb834, &[0xb7, 0xc5], false),
+    (0xb835, &[0xb7, 0xc6], false),
+    (0xb837, &[0xb7, 0xc7], false),
+    (0xb838, &[0xb7, 0xc8], false),
+    (0xb839, &[0xb7, 0xc9], false),
+    (0xb840, &[0xb7, 0xca], false),
+    (0xb844, &[0xb7, 0xcb], false),
+    (0xb851, &[0xb7, 0xcc], false),
+    (0xb853, &[0xb7, 0xcd], false),
+    (0xb85c, &[0xb7, 0xce], false),
+    (0xb85d, &[0xb7, 0xcf], false),
+    (0xb860, &[0xb7, 0xd0], false),
+    (0xb864, &[0xb7, 0xd1], false),
+    (0xb86c, &[0xb7, 0xd2], false),
+    (0xb86d, &[0xb7, 0xd3], false),
+    (0xb86f, &[0xb7, 0xd4], false),
+    (0xb871, &[0xb7, 0xd5], false),
+    (0xb878, &[0xb7, 0xd6], false),
+    (0xb87c, &[0xb7, 0xd7], false),
+    (0xb88d, &[0xb7, 0xd8], false),
+    (0xb8a8, &[0xb7, 0xd9], false),
+    (0xb8b0, &[0xb7, 0xda], false),
+    (0xb8b4, &[0xb7, 0xdb], false),
+    (0xb8b8, &[0xb7, 0xdc], false),
+    (0xb8c0, &[0xb7, 0xdd], false),
+    (0xb8c1, &[0xb7, 0xde], false),
+    (0xb8c3, &[0xb7, 0xdf], false),
+    (0xb8c5, &[0xb7, 0xe0], false),
+    (0xb8cc, &[0xb7, 0xe1], false),
+    (0xb8d0, &[0xb7, 0xe2], false),
+    (0xb8d4, &[0xb7, 0xe3], false),
+    (0xb8dd, &[0xb7, 0xe4], false),
+    (0xb8df, &[0xb7, 0xe5], false),
+    (0xb8e1, &[0xb7, 0xe6], false),
+    (0xb8e8, &[0xb7, 0xe7], false),
+    (0xb8e9, &[0xb7, 0xe8], false),
+    (0xb8ec, &[0xb7, 0xe9], false),
+    (0xb8f0, &[0xb7, 0xea], false),
+    (0xb8f8, &[0xb7, 0xeb], false),
+    (0xb8f9, &[0xb7, 0xec], false),
+    (0xb8fb, &[0xb7, 0xed], false),
+    (0xb8fd, &[0xb7, 0xee], false),
+    (0xb904, &[0xb7, 0xef], false),
+    (0xb918, &[0xb7, 0xf0], false),
+    (0xb920, &[0xb7, 0xf1], false),
+    (0xb93c, &[0xb7, 0xf2], false),
+    (0xb93d, &[0xb7, 0xf3], false),
+    (0xb940, &[0xb7, 0xf4], false),
+    (0xb944, &[0xb7, 0xf5], false),
+    (0xb94c, &[0xb7, 0xf6], false),
+    (0xb94f, &[0xb7, 0xf7], false),
+    (0xb951, &[0xb7, 0xf8], false),
+    (0xb958, &[0xb7, 0xf9], false),
+    (0xb959, &[0xb7, 0xfa], false),
+    (0xb95c, &[0xb7, 0xfb], false),
+    (0xb960, &[0xb7, 0xfc], false),
+    (0xb968, &[0xb7, 0xfd], false),
+    (0xb969, &[0xb7, 0xfe], false),
+    (0xb96b, &[0xb8, 0xa1], false),
+    (0xb96d, &[0xb8, 0xa2], false),
+    (0xb974, &[0xb8, 0xa3], false),
+    (0xb975, &[0xb8, 0xa4], false),
+    (0xb978, &[0xb8, 0xa5], false),
+    (0xb97c, &[0xb8, 0xa6], false),
+    (0xb984, &[0xb8, 0xa7], false),
+    (0xb985, &[0xb8, 0xa8], false),
+    (0xb987, &[0xb8, 0xa9], false),
+    (0xb989, &[0xb8, 0xaa], false),
+    (0xb98a, &[0xb8, 0xab], false),
+    (0xb98d, &[0xb8, 0xac], false),
+    (0xb98e, &[0xb8, 0xad], false),
+    (0xb9ac, &[0xb8, 0xae], false),
+    (0xb9ad, &[0xb8, 0xaf], false),
+    (0xb9b0, &[0xb8, 0xb0], false),
+    (0xb9b4, &[0xb8, 0xb1], false),
+    (0xb9bc, &[0xb8, 0xb2], false),
+    (0xb9bd, &[0xb8, 0xb3], false),
+    (0xb9bf, &[0xb8, 0xb4], false),
+    (0xb9c1, &[0xb8, 0xb5], false),
+    (0xb9c8, &[0xb8, 0xb6], false),
+    (0xb9c9, &[0xb8, 0xb7], false),
+    (0xb9cc, &[0xb8, 0xb8], false),
+    (0xb9ce, &[0xb8, 0xb9], false),
+    (0xb9cf, &[0xb8, 0xba], false),
+    (0xb9d0, &[0xb8, 0xbb], false),
+    (0xb9d1, &[0xb8, 0xbc], false),
+    (0xb9d2, &[0xb8, 0xbd], false),
+    (0xb9d8, &[0xb8, 0xbe], false),
+    (0xb9d9, &[0xb8, 0xbf], false),
+    (0xb9db, &[0xb8, 0xc0], false),
+    (0xb9dd, &[0xb8, 0xc1], false),
+    (0xb9de, &[0xb8, 0xc2], false),
+    (0xb9e1, &[0xb8, 0xc3], false),
+    (0xb9e3, &[0xb8, 0xc4], false),
+    (0xb9e4, &[0xb8, 0xc5], false),
+    (0xb9e5, &[0xb8, 0xc6], false),
+    (0xb9e8, &[0xb8, 0xc7], false),
+    (0xb9ec, &[0xb8, 0xc8], false),
+    (0xb9f4, &[0xb8, 0xc9], false),
+    (0xb9f5, &[0xb8, 0xca], false),
+    (0xb9f7, &[0xb8, 0xcb], false),
+    (0xb9f8, &[0xb8, 0xcc], false),
+    (0xb9f9, &[0xb8, 0xcd], false),
+    (0xb9fa, &[0xb8, 0xce], false),
+    (0xba00, &[0xb8, 0xcf], false),
+    (0xba01, &[0xb8, 0xd0], false),
+    (0xba08, &[0xb8, 0xd1], false),
+    (0xba15, &[0xb8, 0xd2], false),
+    (0xba38, &[0xb8, 0xd3], false),
+    (0xba39, &[0xb8, 0xd4], false),
+    (0xba3c, &[0xb8, 0xd5], false),
+    (0xba40, &[0xb8, 0xd6], false),
+    (0xba42, &[0xb8, 0xd7], false),
+    (0xba48, &[0xb8, 0xd8], false),
+    (0xba49, &[0xb8, 0xd9], false),
+    (0xba4b, &[0xb8, 0xda], false),
+    (0xba4d, &[0xb8, 0xdb], false),
+    (0xba4e, &[0xb8, 0xdc], false),
+    (0xba53, &[0xb8, 0xdd], false),
+    (0xba54, &[0xb8, 0xde], false),
+    (0xba55, &[0xb8, 0xdf], false),
+    (0xba58, &[0xb8, 0xe0], false),
+    (0xba5c, &[0xb8, 0xe1], false),
+    (0xba64, &[0xb8, 0xe2], false),
+    (0xba65, &[0xb8, 0xe3], false),
+    (0xba67, &[0xb8, 0xe4], false),
+    (0xba68, &[0xb8, 0xe5], false),
+    (0xba69, &[0xb8, 0xe6], false),
+    (0xba70, &[0xb8, 0xe7], false),
+    (0xba71, &[0xb8, 0xe8], false),
+    (0xba74, &[0xb8, 0xe9], false),
+    (0xba78, &[0xb8, 0xea], false),
+    (0xba83, &[0xb8, 0xeb], false),
+    (0xba84, &[0xb8, 0xec], false),
+    (0xba85, &[0xb8, 0xed], false),
+    (0xba87, &[0xb8, 0xee], false),
+    (0xba8c, &[0xb8, 0xef], false),
+    (0xbaa8, &[0xb8, 0xf0], false),
+    (0xbaa9, &[0xb8, 0xf1], false),
+    (0xbaab, &[0xb8, 0xf2], false),
+    (0xbaac, &[0xb8, 0xf3], false),
+    (0xbab0, &[0xb8, 0xf4], false),
+    (0xbab2, &[0xb8, 0xf5], false),
+    (0xbab8, &[0xb8, 0xf6], false),
+    (0xbab9, &[0xb8, 0xf7], false),
+    (0xbabb, &[0xb8, 0xf8], false),
+    (0xbabd, &[0xb8, 0xf9], false),
+    (0xbac4, &[0xb8, 0xfa], false),
+    (0xbac8, &[0xb8, 0xfb], false),
+    (0xbad8, &[0xb8, 0xfc], false),
+    (0xbad9, &[0xb8, 0xfd], false),
+    (0xbafc, &[0xb8, 0xfe], false),
+    (0xbb00, &[0xb9, 0xa1], false),
+    (0xbb04, &[0xb9, 0xa2], false),
+    (0xbb0d, &[0xb9, 0xa3], false),
+    (0xbb0f, &[0xb9, 0xa4], false),
+    (0xbb11, &[0xb9, 0xa5], false),
+    (0xbb18, &[0xb9, 0xa6], false),
+    (0xbb1c, &[0xb9, 0xa7], false),
+    (0xbb20, &[0xb9, 0xa8], false),
+    (0xbb29, &[0xb9, 0xa9], false),
+    (0xbb2b, &[0xb9, 0xaa], false),
+    (0xbb34, &[0xb9, 0xab], false),
+    (0xbb35, &[0xb9, 0xac], false),
+    (0xbb36, &[0xb9, 0xad], false),
+    (0xbb38, &[0xb9, 0xae], false),
+    (0xbb3b, &[0xb9, 0xaf], false),
+    (0xbb3c, &[0xb9, 0xb0], false),
+    (0xbb3d, &[0xb9, 0xb1], false),
+    (0xbb3e, &[0xb9, 0xb2], false),
+    (0xbb44, &[0xb9, 0xb3], false),
+    (0xbb45, &[0xb9, 0xb4], false),
+    (0xbb47, &[0xb9, 0xb5], false),
+    (0xbb49, &[0xb9, 0xb6], false),
+    (0xbb4d, &[0xb9, 0xb7], false),
+    (0xbb4f, &[0xb9, 0xb8], false),
+    (0xbb50, &[0xb9, 0xb9], false),
+    (0xbb54, &[0xb9, 0xba], false),
+    (0xbb58, &[0xb9, 0xbb], false),
+    (0xbb61, &[0xb9, 0xbc], false),
+    (0xbb63, &[0xb9, 0xbd], false),
+    (0xbb6c, &[0xb9, 0xbe], false),
+    (0xbb88, &[0xb9, 0xbf], false),
+    (0xbb8c, &[0xb9, 0xc0], false),
+    (0xbb90, &[0xb9, 0xc1], false),
+    (0xbba4, &[0xb9, 0xc2], false),
+    (0xbba8, &[0xb9, 0xc3], false),
+    (0xbbac, &[0xb9, 0xc4], false),
+    (0xbbb4, &[0xb9, 0xc5], false),
+    (0xbbb7, &[0xb9, 0xc6], false),
+    (0xbbc0, &[0xb9, 0xc7], false),
+    (0xbbc4, &[0xb9, 0xc8], false),
+    (0xbbc8, &[0xb9, 0xc9], false),
+    (0xbbd0, &[0xb9, 0xca], false),
+    (0xbbd3, &[0xb9, 0xcb], false),
+    (0xbbf8, &[0xb9, 0xcc], false),
+    (0xbbf9, &[0xb9, 0xcd], false),
+    (0xbbfc, &[0xb9, 0xce], false),
+    (0xbbff, &[0xb9, 0xcf], false),
+    (0xbc00, &[0xb9, 0xd0], false),
+    (0xbc02, &[0xb9, 0xd1], false),
+    (0xbc08, &[0xb9, 0xd2], false),
+    (0xbc09, &[0xb9, 0xd3], false),
+    (0xbc0b, &[0xb9, 0xd4], false),
+    (0xbc0c, &[0xb9, 0xd5], false),
+    (0xbc0d, &[0xb9, 0xd6], false),
+    (0xbc0f, &[0xb9, 0xd7], false),
+    (0xbc11, &[0xb9, 0xd8], false),
+    (0xbc14, &[0xb9, 0xd9], false),
+    (0xbc15, &[0xb9, 0xda], false),
+    (0xbc16, &[0xb9, 0xdb], false),
+    (0xbc17, &[0xb9, 0xdc], false),
+    (0xbc18, &[0xb9, 0xdd], false),
+    (0xbc1b, &[0xb9, 0xde], false),
+    (0xbc1c, &[0xb9, 0xdf], false),
+    (0xbc1d, &[0xb9, 0xe0], false),
+    (0xbc1e, &[0xb9, 0xe1], false),
+    (0xbc1f, &[0xb9, 0xe2], false),
+    (0xbc24, &[0xb9, 0xe3], false),
+    (0xbc25, &[0xb9, 0xe4], false),
+    (0xbc27, &[0xb9, 0xe5], false),
+    (0xbc29, &[0xb9, 0xe6], false),
+    (0xbc2d, &[0xb9, 0xe7], false),
+    (0xbc30, &[0xb9, 0xe8], false),
+    (0xbc31, &[0xb9, 0xe9], false),
+    (0xbc34, &[0xb9, 0xea], false),
+    (0xbc38, &[0xb9, 0xeb], false),
+    (0xbc40, &[0xb9, 0xec], false),
+    (0xbc41, &[0xb9, 0xed], false),
+    (0xbc43, &[0xb9, 0xee], false),
+    (0xbc44, &[0xb9, 0xef], false),
+    (0xbc45, &[0xb9, 0xf0], false),
+    (0xbc49, &[0xb9, 0xf1], false),
+    (0xbc4c, &[0xb9, 0xf2], false),
+    (0xbc4d, &[0xb9, 0xf3], false),
+    (0xbc50, &[0xb9, 0xf4], false),
+    (0xbc5d, &[0xb9, 0xf5], false),
+    (0xbc84, &[0xb9, 0xf6], false),
+    (0xbc85, &[0xb9, 0xf7], false),
+    (0xbc88, &[0xb9, 0xf8], false),
+    (0xbc8b, &[0xb9, 0xf9], false),
+    (0xbc8c, &[0xb9, 0xfa], false),
+    (0xbc8e, &[0xb9, 0xfb], false),
+    (0xbc94, &[0xb9, 0xfc], false),
+    (0xbc95, &[0xb9, 0xfd], false),
+    (0xbc97, &[0xb9, 0xfe], false),
+    (0xbc99, &[0xba, 0xa1], false),
+    (0xbc9a, &[0xba, 0xa2], false),
+    (0xbca0, &[0xba, 0xa3], false),
+    (0xbca1, &[0xba, 0xa4], false),
+    (0xbca4, &[0xba, 0xa5], false),
+    (0xbca7, &[0xba, 0xa6], false),
+    (0xbca8, &[0xba, 0xa7], false),
+    (0xbcb0, &[0xba, 0xa8], false),
+    (0xbcb1, &[0xba, 0xa9], false),
+    (0xbcb3, &[0xba, 0xaa], false),
+    (0xbcb4, &[0xba, 0xab], false),
+    (0xbcb5, &[0xba, 0xac], false),
+    (0xbcbc, &[0xba, 0xad], false),
+    (0xbcbd, &[0xba, 0xae], false),
+    (0xbcc0, &[0xba, 0xaf], false),
+    (0xbcc4, &[0xba, 0xb0], false),
+    (0xbccd, &[0xba, 0xb1], false),
+    (0xbccf, &[0xba, 0xb2], false),
+    (0xbcd0, &[0xba, 0xb3], false),
+    (0xbcd1, &[0xba, 0xb4], false),
+    (0xbcd5, &[0xba, 0xb5], false),
+    (0xbcd8, &[0xba, 0xb6], false),
+    (0xbcdc, &[0xba, 0xb7], false),
+    (0xbcf4, &[0xba, 0xb8], false),
+    (0xbcf5, &[0xba, 0xb9], false),
+    (0xbcf6, &[0xba, 0xba], false),
+    (0xbcf8, &[0xba, 0xbb], false),
+    (0xbcfc, &[0xba, 0xbc], false),
+    (0xbd04, &[0xba, 0xbd], false),
+    (0xbd05, &[0xba, 0xbe], false),
+    (0xbd07, &[0xba, 0xbf], false),
+    (0xbd09, &[0xba, 0xc0], false),
+    (0xbd10, &[0xba, 0xc1], false),
+    (0xbd14, &[0xba, 0xc2], false),
+    (0xbd24, &[0xba, 0xc3], false),
+    (0xbd2c, &[0xba, 0xc4], false),
+    (0xbd40, &[0xba, 0xc5], false),
+    (0xbd48, &[0xba, 0xc6], false),
+    (0xbd49, &[0xba, 0xc7], false),
+    (0xbd4c, &[0xba, 0xc8], false),
+    (0xbd50, &[0xba, 0xc9], false),
+    (0xbd58, &[0xba, 0xca], false),
+    (0xbd59, &[0xba, 0xcb], false),
+    (0xbd64, &[0xba, 0xcc], false),
+    (0xbd68, &[0xba, 0xcd], false),
+    (0xbd80, &[0xba, 0xce], false),
+    (0xbd81, &[0xba, 0xcf], false),
+    (0xbd84, &[0xba, 0xd0], false),
+    (0xbd87, &[0xba, 0xd1], false),
+    (0xbd88, &[0xba, 0xd2], false),
+    (0xbd89, &[0xba, 0xd3], false),
+    (0xbd8a, &[0xba, 0xd4], false),
+    (0xbd90, &[0xba, 0xd5], false),
+    (0xbd91, &[0xba, 0xd6], false),
+    (0xbd93, &[0xba, 0xd7], false),
+    (0xbd95, &[0xba, 0xd8], false),
+    (0xbd99, &[0xba, 0xd9], false),
+    (0xbd9a, &[0xba, 0xda], false),
+    (0xbd9c, &[0xba, 0xdb], false),
+    (0xbda4, &[0xba, 0xdc], false),
+    (0xbdb0, &[0xba, 0xdd], false),
+    (0xbdb8, &[0xba, 0xde], false),
+    (0xbdd4, &[0xba, 0xdf], false),
+    (0xbdd5, &[0xba, 0xe0], false),
+    (0xbdd8, &[0xba, 0xe1], false),
+    (0xbddc, &[0xba, 0xe2], false),
+    (0xbde9, &[0xba, 0xe3], false),
+    (0xbdf0, &[0xba, 0xe4], false),
+    (0xbdf4, &[0xba, 0xe5], false),
+    (0xbdf8, &[0xba, 0xe6], false),
+    (0xbe00, &[0xba, 0xe7], false),
+    (0xbe03, &[0xba, 0xe8], false),
+    (0xbe05, &[0xba, 0xe9], false),
+    (0xbe0c, &[0xba, 0xea], false),
+    (0xbe0d, &[0xba, 0xeb], false),
+    (0xbe10, &[0xba, 0xec], false),
+    (0xbe14, &[0xba, 0xed], false),
+    (0xbe1c, &[0xba, 0xee], false),
+    (0xbe1d, &[0xba, 0xef], false),
+    (0xbe1f, &[0xba, 0xf0], false),
+    (0xbe44, &[0xba, 0xf1], false),
+    (0xbe45, &[0xba, 0xf2], false),
+    (0xbe48, &[0xba, 0xf3], false),
+    (0xbe4c, &[0xba, 0xf4], false),
+    (0xbe4e, &[0xba, 0xf5], false),
+    (0xbe54, &[0xba, 0xf6], false),
+    (0xbe55, &[0xba, 0xf7], false),
+    (0xbe57, &[0xba, 0xf8], false),
+    (0xbe59, &[0xba, 0xf9], false),
+    (0xbe5a, &[0xba, 0xfa], false),
+    (0xbe5b, &[0xba, 0xfb], false),
+    (0xbe60, &[0xba, 0xfc], false),
+    (0xbe61, &[0xba, 0xfd], false),
+    (0xbe64, &[0xba, 0xfe], false),
+    (0xbe68, &[0xbb, 0xa1], false),
+    (0xbe6a, &[0xbb, 0xa2], false),
+    (0xbe70, &[0xbb, 0xa3], false),
+    (0xbe71, &[0xbb, 0xa4], false),
+    (0xbe73, &[0xbb, 0xa5], false),
+    (0xbe74, &[0xbb, 0xa6], false),
+    (0xbe75, &[0xbb, 0xa7], false),
+    (0xbe7b, &[0xbb, 0xa8], false),
+    (0xbe7c, &[0xbb, 0xa9], false),
+    (0xbe7d, &[0xbb, 0xaa], false),
+    (0xbe80, &[0xbb, 0xab], false),
+    (0xbe84, &[0xbb, 0xac], false),
+    (0xbe8c, &[0xbb, 0xad], false),
+    (0xbe8d, &[0xbb, 0xae], false),
+    (0xbe8f, &[0xbb, 0xaf], false),
+    (0xbe90, &[0xbb, 0xb0], false),
+    (0xbe91, &[0xbb, 0xb1], false),
+    (0xbe98, &[0xbb, 0xb2], false),
+    (0xbe99, &[0xbb, 0xb3], false),
+    (0xbea8, &[0xbb, 0xb4], false),
+    (0xbed0, &[0xbb, 0xb5], false),
+    (0xbed1, &[0xbb, 0xb6], false),
+    (0xbed4, &[0xbb, 0xb7], false),
+    (0xbed7, &[0xbb, 0xb8], false),
+    (0xbed8, &[0xbb, 0xb9], false),
+    (0xbee0, &[0xbb, 0xba], false),
+    (0xbee3, &[0xbb, 0xbb], false),
+    (0xbee4, &[0xbb, 0xbc], false),
+    (0xbee5, &[0xbb, 0xbd], false),
+    (0xbeec, &[0xbb, 0xbe], false),
+    (0xbf01, &[0xbb, 0xbf], false),
+    (0xbf08, &[0xbb, 0xc0], false),
+    (0xbf09, &[0xbb, 0xc1], false),
+    (0xbf18, &[0xbb, 0xc2], false),
+    (0xbf19, &[0xbb, 0xc3], false),
+    (0xbf1b, &[0xbb, 0xc4], false),
+    (0xbf1c, &[0xbb, 0xc5], false),
+    (0xbf1d, &[0xbb, 0xc6], false),
+    (0xbf40, &[0xbb, 0xc7], false),
+    (0xbf41, &[0xbb, 0xc8], false),
+    (0xbf44, &[0xbb, 0xc9], false),
+    (0xbf48, &[0xbb, 0xca], false),
+    (0xbf50, &[0xbb, 0xcb], false),
+    (0xbf51, &[0xbb, 0xcc], false),
+    (0xbf55, &[0xbb, 0xcd], false),
+    (0xbf94, &[0xbb, 0xce], false),
+    (0xbfb0, &[0xbb, 0xcf], false),
+    (0xbfc5, &[0xbb, 0xd0], false),
+    (0xbfcc, &[0xbb, 0xd1], false),
+    (0xbfcd, &[0xbb, 0xd2], false),
+    (0xbfd0, &[0xbb, 0xd3], false),
+    (0xbfd4, &[0xbb, 0xd4], false),
+    (0xbfdc, &[0xbb, 0xd5], false),
+    (0xbfdf, &[0xbb, 0xd6], false),
+    (0xbfe1, &[0xbb, 0xd7], false),
+    (0xc03c, &[0xbb, 0xd8], false),
+    (0xc051, &[0xbb, 0xd9], false),
+    (0xc058, &[0xbb, 0xda], false),
+    (0xc05c, &[0xbb, 0xdb], false),
+    (0xc060, &[0xbb, 0xdc], false),
+    (0xc068, &[0xbb, 0xdd], false),
+    (0xc069, &[0xbb, 0xde], false),
+    (0xc090, &[0xbb, 0xdf], false),
+    (0xc091, &[0xbb, 0xe0], false),
+    (0xc094, &[0xbb, 0xe1], false),
+    (0xc098, &[0xbb, 0xe2], false),
+    (0xc0a0, &[0xbb, 0xe3], false),
+    (0xc0a1, &[0xbb, 0xe4], false),
+    (0xc0a3, &[0xbb, 0xe5], false),
+    (0xc0a5, &[0xbb, 0xe6], false),
+    (0xc0ac, &[0xbb, 0xe7], false),
+    (0xc0ad, &[0xbb, 0xe8], false),
+    (0xc0af, &[0xbb, 0xe9], false),
+    (0xc0b0, &[0xbb, 0xea], false),
+    (0xc0b3, &[0xbb, 0xeb], false),
+    (0xc0b4, &[0xbb, 0xec], false),
+    (0xc0b5, &[0xbb, 0xed], false),
+    (0xc0b6, &[0xbb, 0xee], false),
+    (0xc0bc, &[0xbb, 0xef], false),
+    (0xc0bd, &[0xbb, 0xf0], false),
+    (0xc0bf, &[0xbb, 0xf1], false),
+    (0xc0c0, &[0xbb, 0xf2], false),
+    (0xc0c1, &[0xbb, 0xf3], false),
+    (0xc0c5, &[0xbb, 0xf4], false),
+    (0xc0c8, &[0xbb, 0xf5], false),
+    (0xc0c9, &[0xbb, 0xf6], false),
+    (0xc0cc, &[0xbb, 0xf7], false),
+    (0xc0d0, &[0xbb, 0xf8], false),
+    (0xc0d8, &[0xbb, 0xf9], false),
+    (0xc0d9, &[0xbb, 0xfa], false),
+    (0xc0db, &[0xbb, 0xfb], false),
+    (0xc0dc, &[0xbb, 0xfc], false),
+    (0xc0dd, &[0xbb, 0xfd], false),
+    (0xc0e4, &[0xbb, 0xfe], false),
+    (0xc0e5, &[0xbc, 0xa1], false),
+    (0xc0e8, &[0xbc, 0xa2], false),
+    (0xc0ec, &[0xbc, 0xa3], false),
+    (0xc0f4, &[0xbc, 0xa4], false),
+    (0xc0f5, &[0xbc, 0xa5], false),
+    (0xc0f7, &[0xbc, 0xa6], false),
+    (0xc0f9, &[0xbc, 0xa7], false),
+    (0xc100, &[0xbc, 0xa8], false),
+    (0xc104, &[0xbc, 0xa9], false),
+    (0xc108, &[0xbc, 0xaa], false),
+    (0xc110, &[0xbc, 0xab], false),
+    (0xc115, &[0xbc, 0xac], false),
+    (0xc11c, &[0xbc, 0xad], false),
+    (0xc11d, &[0xbc, 0xae], false),
+    (0xc11e, &[0xbc, 0xaf], false),
+    (0xc11f, &[0xbc, 0xb0], false),
+    (0xc120, &[0xbc, 0xb1], false),
+    (0xc123, &[0xbc, 0xb2], false),
+    (0xc124, &[0xbc, 0xb3], false),
+    (0xc126, &[0xbc, 0xb4], false),
+    (0xc127, &[0xbc, 0xb5], false),
+    (0xc12c, &[0xbc, 0xb6], false),
+    (0xc12d, &[0xbc, 0xb7], false),
+    (0xc12f, &[0xbc, 0xb8], false),
+    (0xc130, &[0xbc, 0xb9], false),
+    (0xc131, &[0xbc, 0xba], false),
+    (0xc136, &[0xbc, 0xbb], false),
+    (0xc138, &[0xbc, 0xbc], false),
+    (0xc139, &[0xbc, 0xbd], false),
+    (0xc13c, &[0xbc, 0xbe], false),
+    (0xc140, &[0xbc, 0xbf], false),
+    (0xc148, &[0xbc, 0xc0], false),
+    (0xc149, &[0xbc, 0xc1], false),
+    (0xc14b, &[0xbc, 0xc2], false),
+    (0xc14c, &[0xbc, 0xc3], false),
+    (0xc14d, &[0xbc, 0xc4], false),
+    (0xc154, &[0xbc, 0xc5], false),
+    (0xc155, &[0xbc, 0xc6], false),
+    (0xc158, &[0xbc, 0xc7], false),
+    (0xc15c, &[0xbc, 0xc8], false),
+    (0xc164, &[0xbc, 0xc9], false),
+    (0xc165, &[0xbc, 0xca], false),
+    (0xc167, &[0xbc, 0xcb], false),
+    (0xc168, &[0xbc, 0xcc], false),
+    (0xc169, &[0xbc, 0xcd], false),
+    (0xc170, &[0xbc, 0xce], false),
+    (0xc174, &[0xbc, 0xcf], false),
+    (0xc178, &[0xbc, 0xd0], false),
+    (0xc185, &[0xbc, 0xd1], false),
+    (0xc18c, &[0xbc, 0xd2], false),
+    (0xc18d, &[0xbc, 0xd3], false),
+    (0xc18e, &[0xbc, 0xd4], false),
+    (0xc190, &[0xbc, 0xd5], false),
+    (0xc194, &[0xbc, 0xd6], false),
+    (0xc196, &[0xbc, 0xd7], false),
+    (0xc19c, &[0xbc, 0xd8], false),
+    (0xc19d, &[0xbc, 0xd9], false),
+    (0xc19f, &[0xbc, 0xda], false),
+    (0xc1a1, &[0xbc, 0xdb], false),
+    (0xc1a5, &[0xbc, 0xdc], false),
+    (0xc1a8, &[0xbc, 0xdd], false),
+    (0xc1a9, &[0xbc, 0xde], false),
+    (0xc1ac, &[0xbc, 0xdf], false),
+    (0xc1b0, &[0xbc, 0xe0], false),
+    (0xc1bd, &[0xbc, 0xe1], false),
+    (0xc1c4, &[0xbc, 0xe2], false),
+    (0xc1c8, &[0xbc, 0xe3], false),
+    (0xc1cc, &[0xbc, 0xe4], false),
+    (0xc1d4, &[0xbc, 0xe5], false),
+    (0xc1d7, &[0xbc, 0xe6], false),
+    (0xc1d8, &[0xbc, 0xe7], false),
+    (0xc1e0, &[0xbc, 0xe8], false),
+    (0xc1e4, &[0xbc, 0xe9], false),
+    (0xc1e8, &[0xbc, 0xea], false),
+    (0xc1f0, &[0xbc, 0xeb], false),
+    (0xc1f1, &[0xbc, 0xec], false),
+    (0xc1f3, &[0xbc, 0xed], false),
+    (0xc1fc, &[0xbc, 0xee], false),
+    (0xc1fd, &[0xbc, 0xef], false),
+    (0xc200, &[0xbc, 0xf0], false),
+    (0xc204, &[0xbc, 0xf1], false),
+    (0xc20c, &[0xbc, 0xf2], false),
+    (0xc20d, &[0xbc, 0xf3], false),
+    (0xc20f, &[0xbc, 0xf4], false),
+    (0xc211, &[0xbc, 0xf5], false),
+    (0xc218, &[0xbc, 0xf6], false),
+    (0xc219, &[0xbc, 0xf7], false),
+    (0xc21c, &[0xbc, 0xf8], false),
+    (0xc21f, &[0xbc, 0xf9], false),
+    (0xc220, &[0xbc, 0xfa], false),
+    (0xc228, &[0xbc, 0xfb], false),
+    (0xc229, &[0xbc, 0xfc], false),
+    (0xc22b, &[0xbc, 0xfd], false),
+    (0xc22d, &[0xbc, 0xfe], false),
+    (0xc22f, &[0xbd, 0xa1], false),
+    (0xc231, &[0xbd, 0xa2], false),
+    (0xc232, &[0xbd, 0xa3], false),
+    (0xc234, &[0xbd, 0xa4], false),
+    (0xc248, &[0xbd, 0xa5], false),
+    (0xc250, &[0xbd, 0xa6], false),
+    (0xc251, &[0xbd, 0xa7], false),
+    (0xc254, &[0xbd, 0xa8], false),
+    (0xc258, &[0xbd, 0xa9], false),
+    (0xc260, &[0xbd, 0xaa], false),
+    (0xc265, &[0xbd, 0xab], false),
+    (0xc26c, &[0xbd, 0xac], false),
+    (0xc26d, &[0xbd, 0xad], false),
+    (0xc270, &[0xbd, 0xae], false),
+    (0xc274, &[0xbd, 0xaf], false),
+    (0xc27c, &[0xbd, 0xb0], false),
+    (0xc27d, &[0xbd, 0xb1], false),
+    (0xc27f, &[0xbd, 0xb2], false),
+    (0xc281, &[0xbd, 0xb3], false),
+    (0xc288, &[0xbd, 0xb4], false),
+    (0xc289, &[0xbd, 0xb5], false),
+    (0xc290, &[0xbd, 0xb6], false),
+    (0xc298, &[0xbd, 0xb7], false),
+    (0xc29b, &[0xbd, 0xb8], false),
+    (0xc29d, &[0xbd, 0xb9], false),
+    (0xc2a4, &[0xbd, 0xba], false),
+    (0xc2a5, &[0xbd, 0xbb], false),
+    (0xc2a8, &[0xbd, 0xbc], false),
+    (0xc2ac, &[0xbd, 0xbd], false),
+    (0xc2ad, &[0xbd, 0xbe], false),
+    (0xc2b4, &[0xbd, 0xbf], false),
+    (0xc2b5, &[0xbd, 0xc0], false),
+    (0xc2b7, &[0xbd, 0xc1], false),
+    (0xc2b9, &[0xbd, 0xc2], false),
+    (0xc2dc, &[0xbd, 0xc3], false),
+    (0xc2dd, &[0xbd, 0xc4], false),
+    (0xc2e0, &[0xbd, 0xc5], false),
+    (0xc2e3, &[0xbd, 0xc6], false),
+    (0xc2e4, &[0xbd, 0xc7], false),
+    (0xc2eb, &[0xbd, 0xc8], false),
+    (0xc2ec, &[0xbd, 0xc9], false),
+    (0xc2ed, &[0xbd, 0xca], false),
+    (0xc2ef, &[0xbd, 0xcb], false),
+    (0xc2f1, &[0xbd, 0xcc], false),
+    (0xc2f6, &[0xbd, 0xcd], false),
+    (0xc2f8, &[0xbd, 0xce], false),
+    (0xc2f9, &[0xbd, 0xcf], false),
+    (0xc2fb, &[0xbd, 0xd0], false),
+    (0xc2fc, &[0xbd, 0xd1], false),
+    (0xc300, &[0xbd, 0xd2], false),
+    (0xc308, &[0xbd, 0xd3], false),
+    (0xc309, &[0xbd, 0xd4], false),
+    (0xc30c, &[0xbd, 0xd5], false),
+    (0xc30d, &[0xbd, 0xd6], false),
+    (0xc313, &[0xbd, 0xd7], false),
+    (0xc314, &[0xbd, 0xd8], false),
+    (0xc315, &[0xbd, 0xd9], false),
+    (0xc318, &[0xbd, 0xda], false),
+    (0xc31c, &[0xbd, 0xdb], false),
+    (0xc324, &[0xbd, 0xdc], false),
+    (0xc325, &[0xbd, 0xdd], false),
+    (0xc328, &[0xbd, 0xde], false),
+    (0xc329, &[0xbd, 0xdf], false),
+    (0xc345, &[0xbd, 0xe0], false),
+    (0xc368, &[0xbd, 0xe1], false),
+    (0xc369, &[0xbd, 0xe2], false),
+    (0xc36c, &[0xbd, 0xe3], false),
+    (0xc370, &[0xbd, 0xe4], false),
+    (0xc372, &[0xbd, 0xe5], false),
+    (0xc378, &[0xbd, 0xe6], false),
+    (0xc379, &[0xbd, 0xe7], false),
+    (0xc37c, &[0xbd, 0xe8], false),
+    (0xc37d, &[0xbd, 0xe9], false),
+    (0xc384, &[0xbd, 0xea], false),
+    (0xc388, &[0xbd, 0xeb], false),
+    (0xc38c, &[0xbd, 0xec], false),
+    (0xc3c0, &[0xbd, 0xed], false),
+    (0xc3d8, &[0xbd, 0xee], false),
+    (0xc3d9, &[0xbd, 0xef], false),
+    (0xc3dc, &[0xbd, 0xf0], false),
+    (0xc3df, &[0xbd, 0xf1], false),
+    (0xc3e0, &[0xbd, 0xf2], false),
+    (0xc3e2, &[0xbd, 0xf3], false),
+    (0xc3e8, &[0xbd, 0xf4], false),
+    (0xc3e9, &[0xbd, 0xf5], false),
+    (0xc3ed, &[0xbd, 0xf6], false),
+    (0xc3f4, &[0xbd, 0xf7], false),
+    (0xc3f5, &[0xbd, 0xf8], false),
+    (0xc3f8, &[0xbd, 0xf9], false),
+    (0xc408, &[0xbd, 0xfa], false),
+    (0xc410, &[0xbd, 0xfb], false),
+    (0xc424, &[0xbd, 0xfc], false),
+    (0xc42c, &[0xbd, 0xfd], false),
+    (0xc430, &[0xbd, 0xfe], false),
+    (0xc434, &[0xbe, 0xa1], false),
+    (0xc43c, &[0xbe, 0xa2], false),
+    (0xc43d, &[0xbe, 0xa3], false),
+    (0xc448, &[0xbe, 0xa4], false),
+    (0xc464, &[0xbe, 0xa5], false),
+    (0xc465, &[0xbe, 0xa6], false),
+    (0xc468, &[0xbe, 0xa7], false),
+    (0xc46c, &[0xbe, 0xa8], false),
+    (0xc474, &[0xbe, 0xa9], false),
+    (0xc475, &[0xbe, 0xaa], false),
+    (0xc479, &[0xbe, 0xab], false),
+    (0xc480, &[0xbe, 0xac], false),
+    (0xc494, &[0xbe, 0xad], false),
+    (0xc49c, &[0xbe, 0xae], false),
+    (0xc4b8, &[0xbe, 0xaf], false),
+    (0xc4bc, &[0xbe, 0xb0], false),
+    (0xc4e9, &[0xbe, 0xb1], false),
+    (0xc4f0, &[0xbe, 0xb2], false),
+    (0xc4f1, &[0xbe, 0xb3], false),
+    (0xc4f4, &[0xbe, 0xb4], false),
+    (0xc4f8, &[0xbe, 0xb5], false),
+    (0xc4fa, &[0xbe, 0xb6], false),
+    (0xc4ff, &[0xbe, 0xb7], false),
+    (0xc500, &[0xbe, 0xb8], false),
+    (0xc501, &[0xbe, 0xb9], false),
+    (0xc50c, &[0xbe, 0xba], false),
+    (0xc510, &[0xbe, 0xbb], false),
+    (0xc514, &[0xbe, 0xbc], false),
+    (0xc51c, &[0xbe, 0xbd], false),
+    (0xc528, &[0xbe, 0xbe], false),
+    (0xc529, &[0xbe, 0xbf], false),
+    (0xc52c, &[0xbe, 0xc0], false),
+    (0xc530, &[0xbe, 0xc1], false),
+    (0xc538, &[0xbe, 0xc2], false),
+    (0xc539, &[0xbe, 0xc3], false),
+    (0xc53b, &[0xbe, 0xc4], false),
+    (0xc53d, &[0xbe, 0xc5], false),
+    (0xc544, &[0xbe, 0xc6], false),
+    (0xc545, &[0xbe, 0xc7], false),
+    (0xc548, &[0xbe, 0xc8], false),
+    (0xc549, &[0xbe, 0xc9], false),
+    (0xc54a, &[0xbe, 0xca], false),
+    (0xc54c, &[0xbe, 0xcb], false),
+    (0xc54d, &[0xbe, 0xcc], false),
+    (0xc54e, &[0xbe, 0xcd], false),
+    (0xc553, &[0xbe, 0xce], false),
+    (0xc554, &[0xbe, 0xcf], false),
+    (0xc555, &[0xbe, 0xd0], false),
+    (0xc557, &[0xbe, 0xd1], false),
+    (0xc558, &[0xbe, 0xd2], false),
+    (0xc559, &[0xbe, 0xd3], false),
+    (0xc55d, &[0xbe, 0xd4], false),
+    (0xc55e, &[0xbe, 0xd5], false),
+    (0xc560, &[0xbe, 0xd6], false),
+    (0xc561, &[0xbe, 0xd7], false),
+    (0xc564, &[0xbe, 0xd8], false),
+    (0xc568, &[0xbe, 0xd9], false),
+    (0xc570, &[0xbe, 0xda], false),
+    (0xc571, &[0xbe, 0xdb], false),
+    (0xc573, &[0xbe, 0xdc], false),
+    (0xc574, &[0xbe, 0xdd], false),
+    (0xc575, &[0xbe, 0xde], false),
+    (0xc57c, &[0xbe, 0xdf], false),
+    (0xc57d, &[0xbe, 0xe0], false),
+    (0xc580, &[0xbe, 0xe1], false),
+    (0xc584, &[0xbe, 0xe2], false),
+    (0xc587, &[0xbe, 0xe3], false),
+    (0xc58c, &[0xbe, 0xe4], false),
+    (0xc58d, &[0xbe, 0xe5], false),
+    (0xc58f, &[0xbe, 0xe6], false),
+    (0xc591, &[0xbe, 0xe7], false),
+    (0xc595, &[0xbe, 0xe8], false),
+    (0xc597, &[0xbe, 0xe9], false),
+    (0xc598, &[0xbe, 0xea], false),
+    (0xc59c, &[0xbe, 0xeb], false),
+    (0xc5a0, &[0xbe, 0xec], false),
+    (0xc5a9, &[0xbe, 0xed], false),
+    (0xc5b4, &[0xbe, 0xee], false),
+    (0xc5b5, &[0xbe, 0xef], false),
+    (0xc5b8, &[0xbe, 0xf0], false),
+    (0xc5b9, &[0xbe, 0xf1], false),
+    (0xc5bb, &[0xbe, 0xf2], false),
+    (0xc5bc, &[0xbe, 0xf3], false),
+    (0xc5bd, &[0xbe, 0xf4], false),
+    (0xc5be, &[0xbe, 0xf5], false),
+    (0xc5c4, &[0xbe, 0xf6], false),
+    (0xc5c5, &[0xbe, 0xf7], false),
+    (0xc5c6, &[0xbe, 0xf8], false),
+    (0xc5c7, &[0xbe, 0xf9], false),
+    (0xc5c8, &[0xbe, 0xfa], false),
+    (0xc5c9, &[0xbe, 0xfb], false),
+    (0xc5ca, &[0xbe, 0xfc], false),
+    (0xc5cc, &[0xbe, 0xfd], false),
+    (0xc5ce, &[0xbe, 0xfe], false),
+    (0xc5d0, &[0xbf, 0xa1], false),
+    (0xc5d1, &[0xbf, 0xa2], false),
+    (0xc5d4, &[0xbf, 0xa3], false),
+    (0xc5d8, &[0xbf, 0xa4], false),
+    (0xc5e0, &[0xbf, 0xa5], false),
+    (0xc5e1, &[0xbf, 0xa6], false),
+    (0xc5e3, &[0xbf, 0xa7], false),
+    (0xc5e5, &[0xbf, 0xa8], false),
+    (0xc5ec, &[0xbf, 0xa9], false),
+    (0xc5ed, &[0xbf, 0xaa], false),
+    (0xc5ee, &[0xbf, 0xab], false),
+    (0xc5f0, &[0xbf, 0xac], false),
+    (0xc5f4, &[0xbf, 0xad], false),
+    (0xc5f6, &[0xbf, 0xae], false),
+    (0xc5f7, &[0xbf, 0xaf], false),
+    (0xc5fc, &[0xbf, 0xb0], false),
+    (0xc5fd, &[0xbf, 0xb1], false),
+    (0xc5fe, &[0xbf, 0xb2], false),
+    (0xc5ff, &[0xbf, 0xb3], false),
+    (0xc600, &[0xbf, 0xb4], false),
+    (0xc601, &[0xbf, 0xb5], false),
+    (0xc605, &[0xbf, 0xb6], false),
+    (0xc606, &[0xbf, 0xb7], false),
+    (0xc607, &[0xbf, 0xb8], false),
+    (0xc608, &[0xbf, 0xb9], false),
+    (0xc60c, &[0xbf, 0xba], false),
+    (0xc610, &[0xbf, 0xbb], false),
+    (0xc618, &[0xbf, 0xbc], false),
+    (0xc619, &[0xbf, 0xbd], false),
+    (0xc61b, &[0xbf, 0xbe], false),
+    (0xc61c, &[0xbf, 0xbf], false),
+    (0xc624, &[0xbf, 0xc0], false),
+    (0xc625, &[0xbf, 0xc1], false),
+    (0xc628, &[0xbf, 0xc2], false),
+    (0xc62c, &[0xbf, 0xc3], false),
+    (0xc62d, &[0xbf, 0xc4], false),
+    (0xc62e, &[0xbf, 0xc5], false),
+    (0xc630, &[0xbf, 0xc6], false),
+    (0xc633, &[0xbf, 0xc7], false),
+    (0xc634, &[0xbf, 0xc8], false),
+    (0xc635, &[0xbf, 0xc9], false),
+    (0xc637, &[0xbf, 0xca], false),
+    (0xc639, &[0xbf, 0xcb], false),
+    (0xc63b, &[0xbf, 0xcc], false),
+    (0xc640, &[0xbf, 0xcd], false),
+    (0xc641, &[0xbf, 0xce], false),
+    (0xc644, &[0xbf, 0xcf], false),
+    (0xc648, &[0xbf, 0xd0], false),
+    (0xc650, &[0xbf, 0xd1], false),
+    (0xc651, &[0xbf, 0xd2], false),
+    (0xc653, &[0xbf, 0xd3], false),
+    (0xc654, &[0xbf, 0xd4], false),
+    (0xc655, &[0xbf, 0xd5], false),
+    (0xc65c, &[0xbf, 0xd6], false),
+    (0xc65d, &[0xbf, 0xd7], false),
+    (0xc660, &[0xbf, 0xd8], false),
+    (0xc66c, &[0xbf, 0xd9], false),
+    (0xc66f, &[0xbf, 0xda], false),
+    (0xc671, &[0xbf, 0xdb], false),
+    (0xc678, &[0xbf, 0xdc], false),
+    (0xc679, &[0xbf, 0xdd], false),
+    (0xc67c, &[0xbf, 0xde], false),
+    (0xc680, &[0xbf, 0xdf], false),
+    (0xc688, &[0xbf, 0xe0], false),
+    (0xc689, &[0xbf, 0xe1], false),
+    (0xc68b, &[0xbf, 0xe2], false),
+    (0xc68d, &[0xbf, 0xe3], false),
+    (0xc694, &[0xbf, 0xe4], false),
+    (0xc695, &[0xbf, 0xe5], false),
+    (0xc698, &[0xbf, 0xe6], false),
+    (0xc69c, &[0xbf, 0xe7], false),
+    (0xc6a4, &[0xbf, 0xe8], false),
+    (0xc6a5, &[0xbf, 0xe9], false),
+    (0xc6a7, &[0xbf, 0xea], false),
+    (0xc6a9, &[0xbf, 0xeb], false),
+    (0xc6b0, &[0xbf, 0xec], false),
+    (0xc6b1, &[0xbf, 0xed], false),
+    (0xc6b4, &[0xbf, 0xee], false),
+    (0xc6b8, &[0xbf, 0xef], false),
+    (0xc6b9, &[0xbf, 0xf0], false),
+    (0xc6ba, &[0xbf, 0xf1], false),
+    (0xc6c0, &[0xbf, 0xf2], false),
+    (0xc6c1, &[0xbf, 0xf3], false),
+    (0xc6c3, &[0xbf, 0xf4], false),
+    (0xc6c5, &[0xbf, 0xf5], false),
+    (0xc6cc, &[0xbf, 0xf6], false),
+    (0xc6cd, &[0xbf, 0xf7], false),
+    (0xc6d0, &[0xbf, 0xf8], false),
+    (0xc6d4, &[0xbf, 0xf9], false),
+    (0xc6dc, &[0xbf, 0xfa], false),
+    (0xc6dd, &[0xbf, 0xfb], false),
+    (0xc6e0, &[0xbf, 0xfc], false),
+    (0xc6e1, &[0xbf, 0xfd], false),
+    (0xc6e8, &[0xbf, 0xfe], false),
+    (0xc6e9, &[0xc0, 0xa1], false),
+    (0xc6ec, &[0xc0, 0xa2], false),
+    (0xc6f0, &[0xc0, 0xa3], false),
+    (0xc6f8, &[0xc0, 0xa4], false),
+    (0xc6f9, &[0xc0, 0xa5], false),
+    (0xc6fd, &[0xc0, 0xa6], false),
+    (0xc704, &[0xc0, 0xa7], false),
+    (0xc705, &[0xc0, 0xa8], false),
+    (0xc708, &[0xc0, 0xa9], false),
+    (0xc70c, &[0xc0, 0xaa], false),
+    (0xc714, &[0xc0, 0xab], false),
+    (0xc715, &[0xc0, 0xac], false),
+    (0xc717, &[0xc0, 0xad], false),
+    (0xc719, &[0xc0, 0xae], false),
+    (0xc720, &[0xc0, 0xaf], false),
+    (0xc721, &[0xc0, 0xb0], false),
+    (0xc724, &[0xc0, 0xb1], false),
+    (0xc728, &[0xc0, 0xb2], false),
+    (0xc730, &[0xc0, 0xb3], false),
+    (0xc731, &[0xc0, 0xb4], false),
+    (0xc733, &[0xc0, 0xb5], false),
+    (0xc735, &[0xc0, 0xb6], false),
+    (0xc737, &[0xc0, 0xb7], false),
+    (0xc73c, &[0xc0, 0xb8], false),
+    (0xc73d, &[0xc0, 0xb9], false),
+    (0xc740, &[0xc0, 0xba], false),
+    (0xc744, &[0xc0, 0xbb], false),
+    (0xc74a, &[0xc0, 0xbc], false),
+    (0xc74c, &[0xc0, 0xbd], false),
+    (0xc74d, &[0xc0, 0xbe], false),
+    (0xc74f, &[0xc0, 0xbf], false),
+    (0xc751, &[0xc0, 0xc0], false),
+    (0xc752, &[0xc0, 0xc1], false),
+    (0xc753, &[0xc0, 0xc2], false),
+    (0xc754, &[0xc0, 0xc3], false),
+    (0xc755, &[0xc0, 0xc4], false),
+    (0xc756, &[0xc0, 0xc5], false),
+    (0xc757, &[0xc0, 0xc6], false),
+    (0xc758, &[0xc0, 0xc7], false),
+    (0xc75c, &[0xc0, 0xc8], false),
+    (0xc760, &[0xc0, 0xc9], false),
+    (0xc768, &[0xc0, 0xca], false),
+    (0xc76b, &[0xc0, 0xcb], false),
+    (0xc774, &[0xc0, 0xcc], false),
+    (0xc775, &[0xc0, 0xcd], false),
+    (0xc778, &[0xc0, 0xce], false),
+    (0xc77c, &[0xc0, 0xcf], false),
+    (0xc77d, &[0xc0, 0xd0], false),
+    (0xc77e, &[0xc0, 0xd1], false),
+    (0xc783, &[0xc0, 0xd2], false),
+    (0xc784, &[0xc0, 0xd3], false),
+    (0xc785, &[0xc0, 0xd4], false),
+    (0xc787, &[0xc0, 0xd5], false),
+    (0xc788, &[0xc0, 0xd6], false),
+    (0xc789, &[0xc0, 0xd7], false),
+    (0xc78a, &[0xc0, 0xd8], false),
+    (0xc78e, &[0xc0, 0xd9], false),
+    (0xc790, &[0xc0, 0xda], false),
+    (0xc791, &[0xc0, 0xdb], false),
+    (0xc794, &[0xc0, 0xdc], false),
+    (0xc796, &[0xc0, 0xdd], false),
+    (0xc797, &[0xc0, 0xde], false),
+    (0xc798, &[0xc0, 0xdf], false),
+    (0xc79a, &[0xc0, 0xe0], false),
+    (0xc7a0, &[0xc0, 0xe1], false),
+    (0xc7a1, &[0xc0, 0xe2], false),
+    (0xc7a3, &[0xc0, 0xe3], false),
+    (0xc7a4, &[0xc0, 0xe4], false),
+    (0xc7a5, &[0xc0, 0xe5], false),
+    (0xc7a6, &[0xc0, 0xe6], false),
+    (0xc7ac, &[0xc0, 0xe7], false),
+    (0xc7ad, &[0xc0, 0xe8], false),
+    (0xc7b0, &[0xc0, 0xe9], false),
+    (0xc7b4, &[0xc0, 0xea], false),
+    (0xc7bc, &[0xc0, 0xeb], false),
+    (0xc7bd, &[0xc0, 0xec], false),
+    (0xc7bf, &[0xc0, 0xed], false),
+    (0xc7c0, &[0xc0, 0xee], false),
+    (0xc7c1, &[0xc0, 0xef], false),
+    (0xc7c8, &[0xc0, 0xf0], false),
+    (0xc7c9, &[0xc0, 0xf1], false),
+    (0xc7cc, &[0xc0, 0xf2], false),
+    (0xc7ce, &[0xc0, 0xf3], false),
+    (0xc7d0, &[0xc0, 0xf4], false),
+    (0xc7d8, &[0xc0, 0xf5], false),
+    (0xc7dd, &[0xc0, 0xf6], false),
+    (0xc7e4, &[0xc0, 0xf7], false),
+    (0xc7e8, &[0xc0, 0xf8], false),
+    (0xc7ec, &[0xc0, 0xf9], false),
+    (0xc800, &[0xc0, 0xfa], false),
+    (0xc801, &[0xc0, 0xfb], false),
+    (0xc804, &[0xc0, 0xfc], false),
+    (0xc808, &[0xc0, 0xfd], false),
+    (0xc80a, &[0xc0, 0xfe], false),
+    (0xc810, &[0xc1, 0xa1], false),
+    (0xc811, &[0xc1, 0xa2], false),
+    (0xc813, &[0xc1, 0xa3], false),
+    (0xc815, &[0xc1, 0xa4], false),
+    (0xc816, &[0xc1, 0xa5], false),
+    (0xc81c, &[0xc1, 0xa6], false),
+    (0xc81d, &[0xc1, 0xa7], false),
+    (0xc820, &[0xc1, 0xa8], false),
+    (0xc824, &[0xc1, 0xa9], false),
+    (0xc82c, &[0xc1, 0xaa], false),
+    (0xc82d, &[0xc1, 0xab], false),
+    (0xc82f, &[0xc1, 0xac], false),
+    (0xc831, &[0xc1, 0xad], false),
+    (0xc838, &[0xc1, 0xae], false),
+    (0xc83c, &[0xc1, 0xaf], false),
+    (0xc840, &[0xc1, 0xb0], false),
+    (0xc848, &[0xc1, 0xb1], false),
+    (0xc849, &[0xc1, 0xb2], false),
+    (0xc84c, &[0xc1, 0xb3], false),
+    (0xc84d, &[0xc1, 0xb4], false),
+    (0xc854, &[0xc1, 0xb5], false),
+    (0xc870, &[0xc1, 0xb6], false),
+    (0xc871, &[0xc1, 0xb7], false),
+    (0xc874, &[0xc1, 0xb8], false),
+    (0xc878, &[0xc1, 0xb9], false),
+    (0xc87a, &[0xc1, 0xba], false),
+    (0xc880, &[0xc1, 0xbb], false),
+    (0xc881, &[0xc1, 0xbc], false),
+    (0xc883, &[0xc1, 0xbd], false),
+    (0xc885, &[0xc1, 0xbe], false),
+    (0xc886, &[0xc1, 0xbf], false),
+    (0xc887, &[0xc1, 0xc0], false),
+    (0xc88b, &[0xc1, 0xc1], false),
+    (0xc88c, &[0xc1, 0xc2], false),
+    (0xc88d, &[0xc1, 0xc3], false),
+    (0xc894, &[0xc1, 0xc4], false),
+    (0xc89d, &[0xc1, 0xc5], false),
+    (0xc89f, &[0xc1, 0xc6], false),
+    (0xc8a1, &[0xc1, 0xc7], false),
+    (0xc8a8, &[0xc1, 0xc8], false),
+    (0xc8bc, &[0xc1, 0xc9], false),
+    (0xc8bd, &[0xc1, 0xca], false),
+    (0xc8c4, &[0xc1, 0xcb], false),
+    (0xc8c8, &[0xc1, 0xcc], false),
+    (0xc8cc, &[0xc1, 0xcd], false),
+    (0xc8d4, &[0xc1, 0xce], false),
+    (0xc8d5, &[0xc1, 0xcf], false),
+    (0xc8d7, &[0xc1, 0xd0], false),
+    (0xc8d9, &[0xc1, 0xd1], false),
+    (0xc8e0, &[0xc1, 0xd2], false),
+    (0xc8e1, &[0xc1, 0xd3], false),
+    (0xc8e4, &[0xc1, 0xd4], false),
+    (0xc8f5, &[0xc1, 0xd5], false),
+    (0xc8fc, &[0xc1, 0xd6], false),
+    (0xc8fd, &[0xc1, 0xd7], false),
+    (0xc900, &[0xc1, 0xd8], false),
+    (0xc904, &[0xc1, 0xd9], false),
+    (0xc905, &[0xc1, 0xda], false),
+    (0xc906, &[0xc1, 0xdb], false),
+    (0xc90c, &[0xc1, 0xdc], false),
+    (0xc90d, &[0xc1, 0xdd], false),
+    (0xc90f, &[0xc1, 0xde], false),
+    (0xc911, &[0xc1, 0xdf], false),
+    (0xc918, &[0xc1, 0xe0], false),
+    (0xc92c, &[0xc1, 0xe1], false),
+    (0xc934, &[0xc1, 0xe2], false),
+    (0xc950, &[0xc1, 0xe3], false),
+    (0xc951, &[0xc1, 0xe4], false),
+    (0xc954, &[0xc1, 0xe5], false),
+    (0xc958, &[0xc1, 0xe6], false),
+    (0xc960, &[0xc1, 0xe7], false),
+    (0xc961, &[0xc1, 0xe8], false),
+    (0xc963, &[0xc1, 0xe9], false),
+    (0xc96c, &[0xc1, 0xea], false),
+    (0xc970, &[0xc1, 0xeb], false),
+    (0xc974, &[0xc1, 0xec], false),
+    (0xc97c, &[0xc1, 0xed], false),
+    (0xc988, &[0xc1, 0xee], false),
+    (0xc989, &[0xc1, 0xef], false),
+    (0xc98c, &[0xc1, 0xf0], false),
+    (0xc990, &[0xc1, 0xf1], false),
+    (0xc998, &[0xc1, 0xf2], false),
+    (0xc999, &[0xc1, 0xf3], false),
+    (0xc99b, &[0xc1, 0xf4], false),
+    (0xc99d, &[0xc1, 0xf5], false),
+    (0xc9c0, &[0xc1, 0xf6], false),
+    (0xc9c1, &[0xc1, 0xf7], false),
+    (0xc9c4, &[0xc1, 0xf8], false),
+    (0xc9c7, &[0xc1, 0xf9], false),
+    (0xc9c8, &[0xc1, 0xfa], false),
+    (0xc9ca, &[0xc1, 0xfb], false),
+    (0xc9d0, &[0xc1, 0xfc], false),
+    (0xc9d1, &[0xc1, 0xfd], false),
+    (0xc9d3, &[0xc1, 0xfe], false),
+    (0xc9d5, &[0xc2, 0xa1], false),
+    (0xc9d6, &[0xc2, 0xa2], false),
+    (0xc9d9, &[0xc2, 0xa3], false),
+    (0xc9da, &[0xc2, 0xa4], false),
+    (0xc9dc, &[0xc2, 0xa5], false),
+    (0xc9dd, &[0xc2, 0xa6], false),
+    (0xc9e0, &[0xc2, 0xa7], false),
+    (0xc9e2, &[0xc2, 0xa8], false),
+    (0xc9e4, &[0xc2, 0xa9], false),
+    (0xc9e7, &[0xc2, 0xaa], false),
+    (0xc9ec, &[0xc2, 0xab], false),
+    (0xc9ed, &[0xc2, 0xac], false),
+    (0xc9ef, &[0xc2, 0xad], false),
+    (0xc9f0, &[0xc2, 0xae], false),
+    (0xc9f1, &[0xc2, 0xaf], false),
+    (0xc9f8, &[0xc2, 0xb0], false),
+    (0xc9f9, &[0xc2, 0xb1], false),
+    (0xc9fc, &[0xc2, 0xb2], false),
+    (0xca00, &[0xc2, 0xb3], false),
+    (0xca08, &[0xc2, 0xb4], false),
+    (0xca09, &[0xc2, 0xb5], false),
+    (0xca0b, &[0xc2, 0xb6], false),
+    (0xca0c, &[0xc2, 0xb7], false),
+    (0xca0d, &[0xc2, 0xb8], false),
+    (0xca14, &[0xc2, 0xb9], false),
+    (0xca18, &[0xc2, 0xba], false),
+    (0xca29, &[0xc2, 0xbb], false),
+    (0xca4c, &[0xc2, 0xbc], false),
+    (0xca4d, &[0xc2, 0xbd], false),
+    (0xca50, &[0xc2, 0xbe], false),
+    (0xca54, &[0xc2, 0xbf], false),
+    (0xca5c, &[0xc2, 0xc0], false),
+    (0xca5d, &[0xc2, 0xc1], false),
+    (0xca5f, &[0xc2, 0xc2], false),
+    (0xca60, &[0xc2, 0xc3], false),
+    (0xca61, &[0xc2, 0xc4], false),
+    (0xca68, &[0xc2, 0xc5], false),
+    (0xca7d, &[0xc2, 0xc6], false),
+    (0xca84, &[0xc2, 0xc7], false),
+    (0xca98, &[0xc2, 0xc8], false),
+    (0xcabc, &[0xc2, 0xc9], false),
+    (0xcabd, &[0xc2, 0xca], false),
+    (0xcac0, &[0xc2, 0xcb], false),
+    (0xcac4, &[0xc2, 0xcc], false),
+    (0xcacc, &[0xc2, 0xcd], false),
+    (0xcacd, &[0xc2, 0xce], false),
+    (0xcacf, &[0xc2, 0xcf], false),
+    (0xcad1, &[0xc2, 0xd0], false),
+    (0xcad3, &[0xc2, 0xd1], false),
+    (0xcad8, &[0xc2, 0xd2], false),
+    (0xcad9, &[0xc2, 0xd3], false),
+    (0xcae0, &[0xc2, 0xd4], false),
+    (0xcaec, &[0xc2, 0xd5], false),
+    (0xcaf4, &[0xc2, 0xd6], false),
+    (0xcb08, &[0xc2, 0xd7], false),
+    (0xcb10, &[0xc2, 0xd8], false),
+    (0xcb14, &[0xc2, 0xd9], false),
+    (0xcb18, &[0xc2, 0xda], false),
+    (0xcb20, &[0xc2, 0xdb], false),
+    (0xcb21, &[0xc2, 0xdc], false),
+    (0xcb41, &[0xc2, 0xdd], false),
+    (0xcb48, &[0xc2, 0xde], false),
+    (0xcb49, &[0xc2, 0xdf], false),
+    (0xcb4c, &[0xc2, 0xe0], false),
+    (0xcb50, &[0xc2, 0xe1], false),
+    (0xcb58, &[0xc2, 0xe2], false),
+    (0xcb59, &[0xc2, 0xe3], false),
+    (0xcb5d, &[0xc2, 0xe4], false),
+    (0xcb64, &[0xc2, 0xe5], false),
+    (0xcb78, &[0xc2, 0xe6], false),
+    (0xcb79, &[0xc2, 0xe7], false),
+    (0xcb9c, &[0xc2, 0xe8], false),
+    (0xcbb8, &[0xc2, 0xe9], false),
+    (0xcbd4, &[0xc2, 0xea], false),
+    (0xcbe4, &[0xc2, 0xeb], false),
+    (0xcbe7, &[0xc2, 0xec], false),
+    (0xcbe9, &[0xc2, 0xed], false),
+    (0xcc0c, &[0xc2, 0xee], false),
+    (0xcc0d, &[0xc2, 0xef], false),
+    (0xcc10, &[0xc2, 0xf0], false),
+    (0xcc14, &[0xc2, 0xf1], false),
+    (0xcc1c, &[0xc2, 0xf2], false),
+    (0xcc1d, &[0xc2, 0xf3], false),
+    (0xcc21, &[0xc2, 0xf4], false),
+    (0xcc22, &[0xc2, 0xf5], false),
+    (0xcc27, &[0xc2, 0xf6], false),
+    (0xcc28, &[0xc2, 0xf7], false),
+    (0xcc29, &[0xc2, 0xf8], false),
+    (0xcc2c, &[0xc2, 0xf9], false),
+    (0xcc2e, &[0xc2, 0xfa], false),
+    (0xcc30, &[0xc2, 0xfb], false),
+    (0xcc38, &[0xc2, 0xfc], false),
+    (0xcc39, &[0xc2, 0xfd], false),
+    (0xcc3b, &[0xc2, 0xfe], false),
+    (0xcc3c, &[0xc3, 0xa1], false),
+    (0xcc3d, &[0xc3, 0xa2], false),
+    (0xcc3e, &[0xc3, 0xa3], false),
+    (0xcc44, &[0xc3, 0xa4], false),
+    (0xcc45, &[0xc3, 0xa5], false),
+    (0xcc48, &[0xc3, 0xa6], false),
+    (0xcc4c, &[0xc3, 0xa7], false),
+    (0xcc54, &[0xc3, 0xa8], false),
+    (0xcc55, &[0xc3, 0xa9], false),
+    (0xcc57, &[0xc3, 0xaa], false),
+    (0xcc58, &[0xc3, 0xab], false),
+    (0xcc59, &[0xc3, 0xac], false),
+    (0xcc60, &[0xc3, 0xad], false),
+    (0xcc64, &[0xc3, 0xae], false),
+    (0xcc66, &[0xc3, 0xaf], false),
+    (0xcc68, &[0xc3, 0xb0], false),
+    (0xcc70, &[0xc3, 0xb1], false),
+    (0xcc75, &[0xc3, 0xb2], false),
+    (0xcc98, &[0xc3, 0xb3], false),
+    (0xcc99, &[0xc3, 0xb4], false),
+    (0xcc9c, &[0xc3, 0xb5], false),
+    (0xcca0, &[0xc3, 0xb6], false),
+    (0xcca8, &[0xc3, 0xb7], false),
+    (0xcca9, &[0xc3, 0xb8], false),
+    (0xccab, &[0xc3, 0xb9], false),
+    (0xccac, &[0xc3, 0xba], false),
+    (0xccad, &[0xc3, 0xbb], false),
+    (0xccb4, &[0xc3, 0xbc], false),
+    (0xccb5, &[0xc3, 0xbd], false),
+    (0xccb8, &[0xc3, 0xbe], false),
+    (0xccbc, &[0xc3, 0xbf], false),
+    (0xccc4, &[0xc3, 0xc0], false),
+    (0xccc5, &[0xc3, 0xc1], false),
+    (0xccc7, &[0xc3, 0xc2], false),
+    (0xccc9, &[0xc3, 0xc3], false),
+    (0xccd0, &[0xc3, 0xc4], false),
+    (0xccd4, &[0xc3, 0xc5], false),
+    (0xcce4, &[0xc3, 0xc6], false),
+    (0xccec, &[0xc3, 0xc7], false),
+    (0xccf0, &[0xc3, 0xc8], false),
+    (0xcd01, &[0xc3, 0xc9], false),
+    (0xcd08, &[0xc3, 0xca], false),
+    (0xcd09, &[0xc3, 0xcb], false),
+    (0xcd0c, &[0xc3, 0xcc], false),
+    (0xcd10, &[0xc3, 0xcd], false),
+    (0xcd18, &[0xc3, 0xce], false),
+    (0xcd19, &[0xc3, 0xcf], false),
+    (0xcd1b, &[0xc3, 0xd0], false),
+    (0xcd1d, &[0xc3, 0xd1], false),
+    (0xcd24, &[0xc3, 0xd2], false),
+    (0xcd28, &[0xc3, 0xd3], false),
+    (0xcd2c, &[0xc3, 0xd4], false),
+    (0xcd39, &[0xc3, 0xd5], false),
+    (0xcd5c, &[0xc3, 0xd6], false),
+    (0xcd60, &[0xc3, 0xd7], false),
+    (0xcd64, &[0xc3, 0xd8], false),
+    (0xcd6c, &[0xc3, 0xd9], false),
+    (0xcd6d, &[0xc3, 0xda], false),
+    (0xcd6f, &[0xc3, 0xdb], false),
+    (0xcd71, &[0xc3, 0xdc], false),
+    (0xcd78, &[0xc3, 0xdd], false),
+    (0xcd88, &[0xc3, 0xde], false),
+    (0xcd94, &[0xc3, 0xdf], false),
+    (0xcd95, &[0xc3, 0xe0], false),
+    (0xcd98, &[0xc3, 0xe1], false),
+    (0xcd9c, &[0xc3, 0xe2], false),
+    (0xcda4, &[0xc3, 0xe3], false),
+    (0xcda5, &[0xc3, 0xe4], false),
+    (0xcda7, &[0xc3, 0xe5], false),
+    (0xcda9, &[0xc3, 0xe6], false),
+    (0xcdb0, &[0xc3, 0xe7], false),
+    (0xcdc4, &[0xc3, 0xe8], false),
+    (0xcdcc, &[0xc3, 0xe9], false),
+    (0xcdd0, &[0xc3, 0xea], false),
+    (0xcde8, &[0xc3, 0xeb], false),
+    (0xcdec, &[0xc3, 0xec], false),
+    (0xcdf0, &[0xc3, 0xed], false),
+    (0xcdf8, &[0xc3, 0xee], false),
+    (0xcdf9, &[0xc3, 0xef], false),
+    (0xcdfb, &[0xc3, 0xf0], false),
+    (0xcdfd, &[0xc3, 0xf1], false),
+    (0xce04, &[0xc3, 0xf2], false),
+    (0xce08, &[0xc3, 0xf3], false),
+    (0xce0c, &[0xc3, 0xf4], false),
+    (0xce14, &[0xc3, 0xf5], false),
+    (0xce19, &[0xc3, 0xf6], false),
+    (0xce20, &[0xc3, 0xf7], false),
+    (0xce21, &[0xc3, 0xf8], false),
+    (0xce24, &[0xc3, 0xf9], false),
+    (0xce28, &[0xc3, 0xfa], false),
+    (0xce30, &[0xc3, 0xfb], false),
+    (0xce31, &[0xc3, 0xfc], false),
+    (0xce33, &[0xc3, 0xfd], false),
+    (0xce35, &[0xc3, 0xfe], false),
+    (0xce58, &[0xc4, 0xa1], false),
+    (0xce59, &[0xc4, 0xa2], false),
+    (0xce5c, &[0xc4, 0xa3], false),
+    (0xce5f, &[0xc4, 0xa4], false),
+    (0xce60, &[0xc4, 0xa5], false),
+    (0xce61, &[0xc4, 0xa6], false),
+    (0xce68, &[0xc4, 0xa7], false),
+    (0xce69, &[0xc4, 0xa8], false),
+    (0xce6b, &[0xc4, 0xa9], false),
+    (0xce6d, &[0xc4, 0xaa], false),
+    (0xce74, &[0xc4, 0xab], false),
+    (0xce75, &[0xc4, 0xac], false),
+    (0xce78, &[0xc4, 0xad], false),
+    (0xce7c, &[0xc4, 0xae], false),
+    (0xce84, &[0xc4, 0xaf], false),
+    (0xce85, &[0xc4, 0xb0], false),
+    (0xce87, &[0xc4, 0xb1], false),
+    (0xce89, &[0xc4, 0xb2], false),
+    (0xce90, &[0xc4, 0xb3], false),
+    (0xce91, &[0xc4, 0xb4], false),
+    (0xce94, &[0xc4, 0xb5], false),
+    (0xce98, &[0xc4, 0xb6], false),
+    (0xcea0, &[0xc4, 0xb7], false),
+    (0xcea1, &[0xc4, 0xb8], false),
+    (0xcea3, &[0xc4, 0xb9], false),
+    (0xcea4, &[0xc4, 0xba], false),
+    (0xcea5, &[0xc4, 0xbb], false),
+    (0xceac, &[0xc4, 0xbc], false),
+    (0xcead, &[0xc4, 0xbd], false),
+    (0xcec1, &[0xc4, 0xbe], false),
+    (0xcee4, &[0xc4, 0xbf], false),
+    (0xcee5, &[0xc4, 0xc0], false),
+    (0xcee8, &[0xc4, 0xc1], false),
+    (0xceeb, &[0xc4, 0xc2], false),
+    (0xceec, &[0xc4, 0xc3], false),
+    (0xcef4, &[0xc4, 0xc4], false),
+    (0xcef5, &[0xc4, 0xc5], false),
+    (0xcef7, &[0xc4, 0xc6], false),
+    (0xcef8, &[0xc4, 0xc7], false),
+    (0xcef9, &[0xc4, 0xc8], false),
+    (0xcf00, &[0xc4, 0xc9], false),
+    (0xcf01, &[0xc4, 0xca], false),
+    (0xcf04, &[0xc4, 0xcb], false),
+    (0xcf08, &[0xc4, 0xcc], false),
+    (0xcf10, &[0xc4, 0xcd], false),
+    (0xcf11, &[0xc4, 0xce], false),
+    (0xcf13, &[0xc4, 0xcf], false),
+    (0xcf15, &[0xc4, 0xd0], false),
+    (0xcf1c, &[0xc4, 0xd1], false),
+    (0xcf20, &[0xc4, 0xd2], false),
+    (0xcf24, &[0xc4, 0xd3], false),
+    (0xcf2c, &[0xc4, 0xd4], false),
+    (0xcf2d, &[0xc4, 0xd5], false),
+    (0xcf2f, &[0xc4, 0xd6], false),
+    (0xcf30, &[0xc4, 0xd7], false),
+    (0xcf31, &[0xc4, 0xd8], false),
+    (0xcf38, &[0xc4, 0xd9], false),
+    (0xcf54, &[0xc4, 0xda], false),
+    (0xcf55, &[0xc4, 0xdb], false),
+    (0xcf58, &[0xc4, 0xdc], false),
+    (0xcf5c, &[0xc4, 0xdd], false),
+    (0xcf64, &[0xc4, 0xde], false),
+    (0xcf65, &[0xc4, 0xdf], false),
+    (0xcf67, &[0xc4, 0xe0], false),
+    (0xcf69, &[0xc4, 0xe1], false),
+    (0xcf70, &[0xc4, 0xe2], false),
+    (0xcf71, &[0xc4, 0xe3], false),
+    (0xcf74, &[0xc4, 0xe4], false),
+    (0xcf78, &[0xc4, 0xe5], false),
+    (0xcf80, &[0xc4, 0xe6], false),
+    (0xcf85, &[0xc4, 0xe7], false),
+    (0xcf8c, &[0xc4, 0xe8], false),
+    (0xcfa1, &[0xc4, 0xe9], false),
+    (0xcfa8, &[0xc4, 0xea], false),
+    (0xcfb0, &[0xc4, 0xeb], false),
+    (0xcfc4, &[0xc4, 0xec], false),
+    (0xcfe0, &[0xc4, 0xed], false),
+    (0xcfe1, &[0xc4, 0xee], false),
+    (0xcfe4, &[0xc4, 0xef], false),
+    (0xcfe8, &[0xc4, 0xf0], false),
+    (0xcff0, &[0xc4, 0xf1], false),
+    (0xcff1, &[0xc4, 0xf2], false),
+    (0xcff3, &[0xc4, 0xf3], false),
+    (0xcff5, &[0xc4, 0xf4], false),
+    (0xcffc, &[0xc4, 0xf5], false),
+    (0xd000, &[0xc4, 0xf6], false),
+    (0xd004, &[0xc4, 0xf7], false),
+    (0xd011, &[0xc4, 0xf8], false),
+    (0xd018, &[0xc4, 0xf9], false),
+    (0xd02d, &[0xc4, 0xfa], false),
+    (0xd034, &[0xc4, 0xfb], false),
+    (0xd035, &[0xc4, 0xfc], false),
+    (0xd038, &[0xc4, 0xfd], false),
+    (0xd03c, &[0xc4, 0xfe], false),
+    (0xd044, &[0xc5, 0xa1], false),
+    (0xd045, &[0xc5, 0xa2], false),
+    (0xd047, &[0xc5, 0xa3], false),
+    (0xd049, &[0xc5, 0xa4], false),
+    (0xd050, &[0xc5, 0xa5], false),
+    (0xd054, &[0xc5, 0xa6], false),
+    (0xd058, &[0xc5, 0xa7], false),
+    (0xd060, &[0xc5, 0xa8], false),
+    (0xd06c, &[0xc5, 0xa9], false),
+    (0xd06d, &[0xc5, 0xaa], false),
+    (0xd070, &[0xc5, 0xab], false),
+    (0xd074, &[0xc5, 0xac], false),
+    (0xd07c, &[0xc5, 0xad], false),
+    (0xd07d, &[0xc5, 0xae], false),
+    (0xd081, &[0xc5, 0xaf], false),
+    (0xd0a4, &[0xc5, 0xb0], false),
+    (0xd0a5, &[0xc5, 0xb1], false),
+    (0xd0a8, &[0xc5, 0xb2], false),
+    (0xd0ac, &[0xc5, 0xb3], false),
+    (0xd0b4, &[0xc5, 0xb4], false),
+    (0xd0b5, &[0xc5, 0xb5], false),
+    (0xd0b7, &[0xc5, 0xb6], false),
+    (0xd0b9, &[0xc5, 0xb7], false),
+    (0xd0c0, &[0xc5, 0xb8], false),
+    (0xd0c1, &[0xc5, 0xb9], false),
+    (0xd0c4, &[0xc5, 0xba], false),
+    (0xd0c8, &[0xc5, 0xbb], false),
+    (0xd0c9, &[0xc5, 0xbc], false),
+    (0xd0d0, &[0xc5, 0xbd], false),
+    (0xd0d1, &[0xc5, 0xbe], false),
+    (0xd0d3, &[0xc5, 0xbf], false),
+    (0xd0d4, &[0xc5, 0xc0], false),
+    (0xd0d5, &[0xc5, 0xc1], false),
+    (0xd0dc, &[0xc5, 0xc2], false),
+    (0xd0dd, &[0xc5, 0xc3], false),
+    (0xd0e0, &[0xc5, 0xc4], false),
+    (0xd0e4, &[0xc5, 0xc5], false),
+    (0xd0ec, &[0xc5, 0xc6], false),
+    (0xd0ed, &[0xc5, 0xc7], false),
+    (0xd0ef, &[0xc5, 0xc8], false),
+    (0xd0f0, &[0xc5, 0xc9], false),
+    (0xd0f1, &[0xc5, 0xca], false),
+    (0xd0f8, &[0xc5, 0xcb], false),
+    (0xd10d, &[0xc5, 0xcc], false),
+    (0xd130, &[0xc5, 0xcd], false),
+    (0xd131, &[0xc5, 0xce], false),
+    (0xd134, &[0xc5, 0xcf], false),
+    (0xd138, &[0xc5, 0xd0], false),
+    (0xd13a, &[0xc5, 0xd1], false),
+    (0xd140, &[0xc5, 0xd2], false),
+    (0xd141, &[0xc5, 0xd3], false),
+    (0xd143, &[0xc5, 0xd4], false),
+    (0xd144, &[0xc5, 0xd5], false),
+    (0xd145, &[0xc5, 0xd6], false),
+    (0xd14c, &[0xc5, 0xd7], false),
+    (0xd14d, &[0xc5, 0xd8], false),
+    (0xd150, &[0xc5, 0xd9], false),
+    (0xd154, &[0xc5, 0xda], false),
+    (0xd15c, &[0xc5, 0xdb], false),
+    (0xd15d, &[0xc5, 0xdc], false),
+    (0xd15f, &[0xc5, 0xdd], false),
+    (0xd161, &[0xc5, 0xde], false),
+    (0xd168, &[0xc5, 0xdf], false),
+    (0xd16c, &[0xc5, 0xe0], false),
+    (0xd17c, &[0xc5, 0xe1], false),
+    (0xd184, &[0xc5, 0xe2], false),
+    (0xd188, &[0xc5, 0xe3], false),
+    (0xd1a0, &[0xc5, 0xe4], false),
+    (0xd1a1, &[0xc5, 0xe5], false),
+    (0xd1a4, &[0xc5, 0xe6], false),
+    (0xd1a8, &[0xc5, 0xe7], false),
+    (0xd1b0, &[0xc5, 0xe8], false),
+    (0xd1b1, &[0xc5, 0xe9], false),
+    (0xd1b3, &[0xc5, 0xea], false),
+    (0xd1b5, &[0xc5, 0xeb], false),
+    (0xd1ba, &[0xc5, 0xec], false),
+    (0xd1bc, &[0xc5, 0xed], false),
+    (0xd1c0, &[0xc5, 0xee], false),
+    (0xd1d8, &[0xc5, 0xef], false),
+    (0xd1f4, &[0xc5, 0xf0], false),
+    (0xd1f8, &[0xc5, 0xf1], false),
+    (0xd207, &[0xc5, 0xf2], false),
+    (0xd209, &[0xc5, 0xf3], false),
+    (0xd210, &[0xc5, 0xf4], false),
+    (0xd22c, &[0xc5, 0xf5], false),
+    (0xd22d, &[0xc5, 0xf6], false),
+    (0xd230, &[0xc5, 0xf7], false),
+    (0xd234, &[0xc5, 0xf8], false),
+    (0xd23c, &[0xc5, 0xf9], false),
+    (0xd23d, &[0xc5, 0xfa], false),
+    (0xd23f, &[0xc5, 0xfb], false),
+    (0xd241, &[0xc5, 0xfc], false),
+    (0xd248, &[0xc5, 0xfd], false),
+    (0xd25c, &[0xc5, 0xfe], false),
+    (0xd264, &[0xc6, 0xa1], false),
+    (0xd280, &[0xc6, 0xa2], false),
+    (0xd281, &[0xc6, 0xa3], false),
+    (0xd284, &[0xc6, 0xa4], false),
+    (0xd288, &[0xc6, 0xa5], false),
+    (0xd290, &[0xc6, 0xa6], false),
+    (0xd291, &[0xc6, 0xa7], false),
+    (0xd295, &[0xc6, 0xa8], false),
+    (0xd29c, &[0xc6, 0xa9], false),
+    (0xd2a0, &[0xc6, 0xaa], false),
+    (0xd2a4, &[0xc6, 0xab], false),
+    (0xd2ac, &[0xc6, 0xac], false),
+    (0xd2b1, &[0xc6, 0xad], false),
+    (0xd2b8, &[0xc6, 0xae], false),
+    (0xd2b9, &[0xc6, 0xaf], false),
+    (0xd2bc, &[0xc6, 0xb0], false),
+    (0xd2bf, &[0xc6, 0xb1], false),
+    (0xd2c0, &[0xc6, 0xb2], false),
+    (0xd2c2, &[0xc6, 0xb3], false),
+    (0xd2c8, &[0xc6, 0xb4], false),
+    (0xd2c9, &[0xc6, 0xb5], false),
+    (0xd2cb, &[0xc6, 0xb6], false),
+    (0xd2d4, &[0xc6, 0xb7], false),
+    (0xd2d8, &[0xc6, 0xb8], false),
+    (0xd2dc, &[0xc6, 0xb9], false),
+    (0xd2e4, &[0xc6, 0xba], false),
+    (0xd2e5, &[0xc6, 0xbb], false),
+    (0xd2f0, &[0xc6, 0xbc], false),
+    (0xd2f1, &[0xc6, 0xbd], false),
+    (0xd2f4, &[0xc6, 0xbe], false),
+    (0xd2f8, &[0xc6, 0xbf], false),
+    (0xd300, &[0xc6, 0xc0], false),
+    (0xd301, &[0xc6, 0xc1], false),
+    (0xd303, &[0xc6, 0xc2], false),
+    (0xd305, &[0xc6, 0xc3], false),
+    (0xd30c, &[0xc6, 0xc4], false),
+    (0xd30d, &[0xc6, 0xc5], false),
+    (0xd30e, &[0xc6, 0xc6], false),
+    (0xd310, &[0xc6, 0xc7], false),
+    (0xd314, &[0xc6, 0xc8], false),
+    (0xd316, &[0xc6, 0xc9], false),
+    (0xd31c, &[0xc6, 0xca], false),
+    (0xd31d, &[0xc6, 0xcb], false),
+    (0xd31f, &[0xc6, 0xcc], false),
+    (0xd320, &[0xc6, 0xcd], false),
+    (0xd321, &[0xc6, 0xce], false),
+    (0xd325, &[0xc6, 0xcf], false),
+    (0xd328, &[0xc6, 0xd0], false),
+    (0xd329, &[0xc6, 0xd1], false),
+    (0xd32c, &[0xc6, 0xd2], false),
+    (0xd330, &[0xc6, 0xd3], false),
+    (0xd338, &[0xc6, 0xd4], false),
+    (0xd339, &[0xc6, 0xd5], false),
+    (0xd33b, &[0xc6, 0xd6], false),
+    (0xd33c, &[0xc6, 0xd7], false),
+    (0xd33d, &[0xc6, 0xd8], false),
+    (0xd344, &[0xc6, 0xd9], false),
+    (0xd345, &[0xc6, 0xda], false),
+    (0xd37c, &[0xc6, 0xdb], false),
+    (0xd37d, &[0xc6, 0xdc], false),
+    (0xd380, &[0xc6, 0xdd], false),
+    (0xd384, &[0xc6, 0xde], false),
+    (0xd38c, &[0xc6, 0xdf], false),
+    (0xd38d, &[0xc6, 0xe0], false),
+    (0xd38f, &[0xc6, 0xe1], false),
+    (0xd390, &[0xc6, 0xe2], false),
+    (0xd391, &[0xc6, 0xe3], false),
+    (0xd398, &[0xc6, 0xe4], false),
+    (0xd399, &[0xc6, 0xe5], false),
+    (0xd39c, &[0xc6, 0xe6], false),
+    (0xd3a0, &[0xc6, 0xe7], false),
+    (0xd3a8, &[0xc6, 0xe8], false),
+    (0xd3a9, &[0xc6, 0xe9], false),
+    (0xd3ab, &[0xc6, 0xea], false),
+    (0xd3ad, &[0xc6, 0xeb], false),
+    (0xd3b4, &[0xc6, 0xec], false),
+    (0xd3b8, &[0xc6, 0xed], false),
+    (0xd3bc, &[0xc6, 0xee], false),
+    (0xd3c4, &[0xc6, 0xef], false),
+    (0xd3c5, &[0xc6, 0xf0], false),
+    (0xd3c8, &[0xc6, 0xf1], false),
+    (0xd3c9, &[0xc6, 0xf2], false),
+    (0xd3d0, &[0xc6, 0xf3], false),
+    (0xd3d8, &[0xc6, 0xf4], false),
+    (0xd3e1, &[0xc6, 0xf5], false),
+    (0xd3e3, &[0xc6, 0xf6], false),
+    (0xd3ec, &[0xc6, 0xf7], false),
+    (0xd3ed, &[0xc6, 0xf8], false),
+    (0xd3f0, &[0xc6, 0xf9], false),
+    (0xd3f4, &[0xc6, 0xfa], false),
+    (0xd3fc, &[0xc6, 0xfb], false),
+    (0xd3fd, &[0xc6, 0xfc], false),
+    (0xd3ff, &[0xc6, 0xfd], false),
+    (0xd401, &[0xc6, 0xfe], false),
+    (0xd408, &[0xc7, 0xa1], false),
+    (0xd41d, &[0xc7, 0xa2], false),
+    (0xd440, &[0xc7, 0xa3], false),
+    (0xd444, &[0xc7, 0xa4], false),
+    (0xd45c, &[0xc7, 0xa5], false),
+    (0xd460, &[0xc7, 0xa6], false),
+    (0xd464, &[0xc7, 0xa7], false),
+    (0xd46d, &[0xc7, 0xa8], false),
+    (0xd46f, &[0xc7, 0xa9], false),
+    (0xd478, &[0xc7, 0xaa], false),
+    (0xd479, &[0xc7, 0xab], false),
+    (0xd47c, &[0xc7, 0xac], false),
+    (0xd47f, &[0xc7, 0xad], false),
+    (0xd480, &[0xc7, 0xae], false),
+    (0xd482, &[0xc7, 0xaf], false),
+    (0xd488, &[0xc7, 0xb0], false),
+    (0xd489, &[0xc7, 0xb1], false),
+    (0xd48b, &[0xc7, 0xb2], false),
+    (0xd48d, &[0xc7, 0xb3], false),
+    (0xd494, &[0xc7, 0xb4], false),
+    (0xd4a9, &[0xc7, 0xb5], false),
+    (0xd4cc, &[0xc7, 0xb6], false),
+    (0xd4d0, &[0xc7, 0xb7], false),
+    (0xd4d4, &[0xc7, 0xb8], false),
+    (0xd4dc, &[0xc7, 0xb9], false),
+    (0xd4df, &[0xc7, 0xba], false),
+    (0xd4e8, &[0xc7, 0xbb], false),
+    (0xd4ec, &[0xc7, 0xbc], false),
+    (0xd4f0, &[0xc7, 0xbd], false),
+    (0xd4f8, &[0xc7, 0xbe], false),
+    (0xd4fb, &[0xc7, 0xbf], false),
+    (0xd4fd, &[0xc7, 0xc0], false),
+    (0xd504, &[0xc7, 0xc1], false),
+    (0xd508, &[0xc7, 0xc2], false),
+    (0xd50c, &[0xc7, 0xc3], false),
+    (0xd514, &[0xc7, 0xc4], false),
+    (0xd515, &[0xc7, 0xc5], false),
+    (0xd517, &[0xc7, 0xc6], false),
+    (0xd53c, &[0xc7, 0xc7], false),
+    (0xd53d, &[0xc7, 0xc8], false),
+    (0xd540, &[0xc7, 0xc9], false),
+    (0xd544, &[0xc7, 0xca], false),
+    (0xd54c, &[0xc7, 0xcb], false),
+    (0xd54d, &[0xc7, 0xcc], false),
+    (0xd54f, &[0xc7, 0xcd], false),
+    (0xd551, &[0xc7, 0xce], false),
+    (0xd558, &[0xc7, 0xcf], false),
+    (0xd559, &[0xc7, 0xd0], false),
+    (0xd55c, &[0xc7, 0xd1], false),
+    (0xd560, &[0xc7, 0xd2], false),
+    (0xd565, &[0xc7, 0xd3], false),
+    (0xd568, &[0xc7, 0xd4], false),
+    (0xd569, &[0xc7, 0xd5], false),
+    (0xd56b, &[0xc7, 0xd6], false),
+    (0xd56d, &[0xc7, 0xd7], false),
+    (0xd574, &[0xc7, 0xd8], false),
+    (0xd575, &[0xc7, 0xd9], false),
+    (0xd578, &[0xc7, 0xda], false),
+    (0xd57c, &[0xc7, 0xdb], false),
+    (0xd584, &[0xc7, 0xdc], false),
+    (0xd585, &[0xc7, 0xdd], false),
+    (0xd587, &[0xc7, 0xde], false),
+    (0xd588, &[0xc7, 0xdf], false),
+    (0xd589, &[0xc7, 0xe0], false),
+    (0xd590, &[0xc7, 0xe1], false),
+    (0xd5a5, &[0xc7, 0xe2], false),
+    (0xd5c8, &[0xc7, 0xe3], false),
+    (0xd5c9, &[0xc7, 0xe4], false),
+    (0xd5cc, &[0xc7, 0xe5], false),
+    (0xd5d0, &[0xc7, 0xe6], false),
+    (0xd5d2, &[0xc7, 0xe7], false),
+    (0xd5d8, &[0xc7, 0xe8], false),
+    (0xd5d9, &[0xc7, 0xe9], false),
+    (0xd5db, &[0xc7, 0xea], false),
+    (0xd5dd, &[0xc7, 0xeb], false),
+    (0xd5e4, &[0xc7, 0xec], false),
+    (0xd5e5, &[0xc7, 0xed], false),
+    (0xd5e8, &[0xc7, 0xee], false),
+    (0xd5ec, &[0xc7, 0xef], false),
+    (0xd5f4, &[0xc7, 0xf0], false),
+    (0xd5f5, &[0xc7, 0xf1], false),
+    (0xd5f7, &[0xc7, 0xf2], false),
+    (0xd5f9, &[0xc7, 0xf3], false),
+    (0xd600, &[0xc7, 0xf4], false),
+    (0xd601, &[0xc7, 0xf5], false),
+    (0xd604, &[0xc7, 0xf6], false),
+    (0xd608, &[0xc7, 0xf7], false),
+    (0xd610, &[0xc7, 0xf8], false),
+    (0xd611, &[0xc7, 0xf9], false),
+    (0xd613, &[0xc7, 0xfa], false),
+    (0xd614, &[0xc7, 0xfb], false),
+    (0xd615, &[0xc7, 0xfc], false),
+    (0xd61c, &[0xc7, 0xfd], false),
+    (0xd620, &[0xc7, 0xfe], false),
+    (0xd624, &[0xc8, 0xa1], false),
+    (0xd62d, &[0xc8, 0xa2], false),
+    (0xd638, &[0xc8, 0xa3], false),
+    (0xd639, &[0xc8, 0xa4], false),
+    (0xd63c, &[0xc8, 0xa5], false),
+    (0xd640, &[0xc8, 0xa6], false),
+    (0xd645, &[0xc8, 0xa7], false),
+    (0xd648, &[0xc8, 0xa8], false),
+    (0xd649, &[0xc8, 0xa9], false),
+    (0xd64b, &[0xc8, 0xaa], false),
+    (0xd64d, &[0xc8, 0xab], false),
+    (0xd651, &[0xc8, 0xac], false),
+    (0xd654, &[0xc8, 0xad], false),
+    (0xd655, &[0xc8, 0xae], false),
+    (0xd658, &[0xc8, 0xaf], false),
+    (0xd65c, &[0xc8, 0xb0], false),
+    (0xd667, &[0xc8, 0xb1], false),
+    (0xd669, &[0xc8, 0xb2], false),
+    (0xd670, &[0xc8, 0xb3], false),
+    (0xd671, &[0xc8, 0xb4], false),
+    (0xd674, &[0xc8, 0xb5], false),
+    (0xd683, &[0xc8, 0xb6], false),
+    (0xd685, &[0xc8, 0xb7], false),
+    (0xd68c, &[0xc8, 0xb8], false),
+    (0xd68d, &[0xc8, 0xb9], false),
+    (0xd690, &[0xc8, 0xba], false),
+    (0xd694, &[0xc8, 0xbb], false),
+    (0xd69d, &[0xc8, 0xbc], false),
+    (0xd69f, &[0xc8, 0xbd], false),
+    (0xd6a1, &[0xc8, 0xbe], false),
+    (0xd6a8, &[0xc8, 0xbf], false),
+    (0xd6ac, &[0xc8, 0xc0], false),
+    (0xd6b0, &[0xc8, 0xc1], false),
+    (0xd6b9, &[0xc8, 0xc2], false),
+    (0xd6bb, &[0xc8, 0xc3], false),
+    (0xd6c4, &[0xc8, 0xc4], false),
+    (0xd6c5, &[0xc8, 0xc5], false),
+    (0xd6c8, &[0xc8, 0xc6], false),
+    (0xd6cc, &[0xc8, 0xc7], false),
+    (0xd6d1, &[0xc8, 0xc8], false),
+    (0xd6d4, &[0xc8, 0xc9], false),
+    (0xd6d7, &[0xc8, 0xca], false),
+    (0xd6d9, &[0xc8, 0xcb], false),
+    (0xd6e0, &[0xc8, 0xcc], false),
+    (0xd6e4, &[0xc8, 0xcd], false),
+    (0xd6e8, &[0xc8, 0xce], false),
+    (0xd6f0, &[0xc8, 0xcf], false),
+    (0xd6f5, &[0xc8, 0xd0], false),
+    (0xd6fc, &[0xc8, 0xd1], false),
+    (0xd6fd, &[0xc8, 0xd2], false),
+    (0xd700, &[0xc8, 0xd3], false),
+    (0xd704, &[0xc8, 0xd4], false),
+    (0xd711, &[0xc8, 0xd5], false),
+    (0xd718, &[0xc8, 0xd6], false),
+    (0xd719, &[0xc8, 0xd7], false),
+    (0xd71c, &[0xc8, 0xd8], false),
+    (0xd720, &[0xc8, 0xd9], false),
+    (0xd728, &[0xc8, 0xda], false),
+    (0xd729, &[0xc8, 0xdb], false),
+    (0xd72b, &[0xc8, 0xdc], false),
+    (0xd72d, &[0xc8, 0xdd], false),
+    (0xd734, &[0xc8, 0xde], false),
+    (0xd735, &[0xc8, 0xdf], false),
+    (0xd738, &[0xc8, 0xe0], false),
+    (0xd73c, &[0xc8, 0xe1], false),
+    (0xd744, &[0xc8, 0xe2], false),
+    (0xd747, &[0xc8, 0xe3], false),
+    (0xd749, &[0xc8, 0xe4], false),
+    (0xd750, &[0xc8, 0xe5], false),
+    (0xd751, &[0xc8, 0xe6], false),
+    (0xd754, &[0xc8, 0xe7], false),
+    (0xd756, &[0xc8, 0xe8], false),
+    (0xd757, &[0xc8, 0xe9], false),
+    (0xd758, &[0xc8, 0xea], false),
+    (0xd759, &[0xc8, 0xeb], false),
+    (0xd760, &[0xc8, 0xec], false),
+    (0xd761, &[0xc8, 0xed], false),
+    (0xd763, &[0xc8, 0xee], false),
+    (0xd765, &[0xc8, 0xef], false),
+    (0xd769, &[0xc8, 0xf0], false),
+    (0xd76c, &[0xc8, 0xf1], false),
+    (0xd770, &[0xc8, 0xf2], false),
+    (0xd774, &[0xc8, 0xf3], false),
+    (0xd77c, &[0xc8, 0xf4], false),
+    (0xd77d, &[0xc8, 0xf5], false),
+    (0xd781, &[0xc8, 0xf6], false),
+    (0xd788, &[0xc8, 0xf7], false),
+    (0xd789, &[0xc8, 0xf8], false),
+    (0xd78c, &[0xc8, 0xf9], false),
+    (0xd790, &[0xc8, 0xfa], false),
+    (0xd798, &[0xc8, 0xfb], false),
+    (0xd799, &[0xc8, 0xfc], false),
+    (0xd79b, &[0xc8, 0xfd], false),
+    (0xd79d, &[0xc8, 0xfe], false),
+    (0x4f3d, &[0xca, 0xa1], false),
+    (0x4f73, &[0xca, 0xa2], false),
+    (0x5047, &[0xca, 0xa3], false),
+    (0x50f9, &[0xca, 0xa4], false),
+    (0x52a0, &[0xca, 0xa5], false),
+    (0x53ef, &[0xca, 0xa6], false),
+    (0x5475, &[0xca, 0xa7], false),
+    (0x54e5, &[0xca, 0xa8], false),
+    (0x5609, &[0xca, 0xa9], false),
+    (0x5ac1, &[0xca, 0xaa], false),
+    (0x5bb6, &[0xca, 0xab], false),
+    (0x6687, &[0xca, 0xac], false),
+    (0x67b6, &[0xca, 0xad], false),
+    (0x67b7, &[0xca, 0xae], false),
+    (0x67ef, &[0xca, 0xaf], false),
+    (0x6b4c, &[0xca, 0xb0], false),
+    (0x73c2, &[0xca, 0xb1], false),
+    (0x75c2, &[0xca, 0xb2], false),
+    (0x7a3c, &[0xca, 0xb3], false),
+    (0x82db, &[0xca, 0xb4], false),
+    (0x8304, &[0xca, 0xb5], false),
+    (0x8857, &[0xca, 0xb6], false),
+    (0x8888, &[0xca, 0xb7], false),
+    (0x8a36, &[0xca, 0xb8], false),
+    (0x8cc8, &[0xca, 0xb9], false),
+    (0x8dcf, &[0xca, 0xba], false),
+    (0x8efb, &[0xca, 0xbb], false),
+    (0x8fe6, &[0xca, 0xbc], false),
+    (0x99d5, &[0xca, 0xbd], false),
+    (0x523b, &[0xca, 0xbe], false),
+    (0x5374, &[0xca, 0xbf], false),
+    (0x5404, &[0xca, 0xc0], false),
+    (0x606a, &[0xca, 0xc1], false),
+    (0x6164, &[0xca, 0xc2], false),
+    (0x6bbc, &[0xca, 0xc3], false),
+    (0x73cf, &[0xca, 0xc4], false),
+    (0x811a, &[0xca, 0xc5], false),
+    (0x89ba, &[0xca, 0xc6], false),
+    (0x89d2, &[0xca, 0xc7], false),
+    (0x95a3, &[0xca, 0xc8], false),
+    (0x4f83, &[0xca, 0xc9], false),
+    (0x520a, &[0xca, 0xca], false),
+    (0x58be, &[0xca, 0xcb], false),
+    (0x5978, &[0xca, 0xcc], false),
+    (0x59e6, &[0xca, 0xcd], false),
+    (0x5e72, &[0xca, 0xce], false),
+    (0x5e79, &[0xca, 0xcf], false),
+    (0x61c7, &[0xca, 0xd0], false),
+    (0x63c0, &[0xca, 0xd1], false),
+    (0x6746, &[0xca, 0xd2], false),
+    (0x67ec, &[0xca, 0xd3], false),
+    (0x687f, &[0xca, 0xd4], false),
+    (0x6f97, &[0xca, 0xd5], false),
+    (0x764e, &[0xca, 0xd6], false),
+    (0x770b, &[0xca, 0xd7], false),
+    (0x78f5, &[0xca, 0xd8], false),
+    (0x7a08, &[0xca, 0xd9], false),
+    (0x7aff, &[0xca, 0xda], false),
+    (0x7c21, &[0xca, 0xdb], false),
+    (0x809d, &[0xca, 0xdc], false),
+    (0x826e, &[0xca, 0xdd], false),
+    (0x8271, &[0xca, 0xde], false),
+    (0x8aeb, &[0xca, 0xdf], false),
+    (0x9593, &[0xca, 0xe0], false),
+    (0x4e6b, &[0xca, 0xe1], false),
+    (0x559d, &[0xca, 0xe2], false),
+    (0x66f7, &[0xca, 0xe3], false),
+    (0x6e34, &[0xca, 0xe4], false),
+    (0x78a3, &[0xca, 0xe5], false),
+    (0x7aed, &[0xca, 0xe6], false),
+    (0x845b, &[0xca, 0xe7], false),
+    (0x8910, &[0xca, 0xe8], false),
+    (0x874e, &[0xca, 0xe9], false),
+    (0x97a8, &[0xca, 0xea], false),
+    (0x52d8, &[0xca, 0xeb], false),
+    (0x574e, &[0xca, 0xec], false),
+    (0x582a, &[0xca, 0xed], false),
+    (0x5d4c, &[0xca, 0xee], false),
+    (0x611f, &[0xca, 0xef], false),
+    (0x61be, &[0xca, 0xf0], false),
+    (0x6221, &[0xca, 0xf1], false),
+    (0x6562, &[0xca, 0xf2], false),
+    (0x67d1, &[0xca, 0xf3], false),
+    (0x6a44, &[0xca, 0xf4], false),
+    (0x6e1b, &[0xca, 0xf5], false),
+    (0x7518, &[0xca, 0xf6], false),
+    (0x75b3, &[0xca, 0xf7], false),
+    (0x76e3, &[0xca, 0xf8], false),
+    (0x77b0, &[0xca, 0xf9], false),
+    (0x7d3a, &[0xca, 0xfa], false),
+    (0x90af, &[0xca, 0xfb], false),
+    (0x9451, &[0xca, 0xfc], false),
+    (0x9452, &[0xca, 0xfd], false),
+    (0x9f95, &[0xca, 0xfe], false),
+    (0x5323, &[0xcb, 0xa1], false),
+    (0x5cac, &[0xcb, 0xa2], false),
+    (0x7532, &[0xcb, 0xa3], false),
+    (0x80db, &[0xcb, 0xa4], false),
+    (0x9240, &[0xcb, 0xa5], false),
+    (0x9598, &[0xcb, 0xa6], false),
+    (0x525b, &[0xcb, 0xa7], false),
+    (0x5808, &[0xcb, 0xa8], false),
+    (0x59dc, &[0xcb, 0xa9], false),
+    (0x5ca1, &[0xcb, 0xaa], false),
+    (0x5d17, &[0xcb, 0xab], false),
+    (0x5eb7, &[0xcb, 0xac], false),
+    (0x5f3a, &[0xcb, 0xad], false),
+    (0x5f4a, &[0xcb, 0xae], false),
+    (0x6177, &[0xcb, 0xaf], false),
+    (0x6c5f, &[0xcb, 0xb0], false),
+    (0x757a, &[0xcb, 0xb1], false),
+    (0x7586, &[0xcb, 0xb2], false),
+    (0x7ce0, &[0xcb, 0xb3], false),
+    (0x7d73, &[0xcb, 0xb4], false),
+    (0x7db1, &[0xcb, 0xb5], false),
+    (0x7f8c, &[0xcb, 0xb6], false),
+    (0x8154, &[0xcb, 0xb7], false),
+    (0x8221, &[0xcb, 0xb8], false),
+    (0x8591, &[0xcb, 0xb9], false),
+    (0x8941, &[0xcb, 0xba], false),
+    (0x8b1b, &[0xcb, 0xbb], false),
+    (0x92fc, &[0xcb, 0xbc], false),
+    (0x964d, &[0xcb, 0xbd], false),
+    (0x9c47, &[0xcb, 0xbe], false),
+    (0x4ecb, &[0xcb, 0xbf], false),
+    (0x4ef7, &[0xcb, 0xc0], false),
+    (0x500b, &[0xcb, 0xc1], false),
+    (0x51f1, &[0xcb, 0xc2], false),
+    (0x584f, &[0xcb, 0xc3], false),
+    (0x6137, &[0xcb, 0xc4], false),
+    (0x613e, &[0xcb, 0xc5], false),
+    (0x6168, &[0xcb, 0xc6], false),
+    (0x6539, &[0xcb, 0xc7], false),
+    (0x69ea, &[0xcb, 0xc8], false),
+    (0x6f11, &[0xcb, 0xc9], false),
+    (0x75a5, &[0xcb, 0xca], false),
+    (0x7686, &[0xcb, 0xcb], false),
+    (0x76d6, &[0xcb, 0xcc], false),
+    (0x7b87, &[0xcb, 0xcd], false),
+    (0x82a5, &[0xcb, 0xce], false),
+    (0x84cb, &[0xcb, 0xcf], false),
+    (0xf900, &[0xcb, 0xd0], false),
+    (0x93a7, &[0xcb, 0xd1], false),
+    (0x958b, &[0xcb, 0xd2], false),
+    (0x5580, &[0xcb, 0xd3], false),
+    (0x5ba2, &[0xcb, 0xd4], false),
+    (0x5751, &[0xcb, 0xd5], false),
+    (0xf901, &[0xcb, 0xd6], false),
+    (0x7cb3, &[0xcb, 0xd7], false),
+    (0x7fb9, &[0xcb, 0xd8], false),
+    (0x91b5, &[0xcb, 0xd9], false),
+    (0x5028, &[0xcb, 0xda], false),
+    (0x53bb, &[0xcb, 0xdb], false),
+    (0x5c45, &[0xcb, 0xdc], false),
+    (0x5de8, &[0xcb, 0xdd], false),
+    (0x62d2, &[0xcb, 0xde], false),
+    (0x636e, &[0xcb, 0xdf], false),
+    (0x64da, &[0xcb, 0xe0], false),
+    (0x64e7, &[0xcb, 0xe1], false),
+    (0x6e20, &[0xcb, 0xe2], false),
+    (0x70ac, &[0xcb, 0xe3], false),
+    (0x795b, &[0xcb, 0xe4], false),
+    (0x8ddd, &[0xcb, 0xe5], false),
+    (0x8e1e, &[0xcb, 0xe6], false),
+    (0xf902, &[0xcb, 0xe7], false),
+    (0x907d, &[0xcb, 0xe8], false),
+    (0x9245, &[0xcb, 0xe9], false),
+    (0x92f8, &[0xcb, 0xea], false),
+    (0x4e7e, &[0xcb, 0xeb], false),
+    (0x4ef6, &[0xcb, 0xec], false),
+    (0x5065, &[0xcb, 0xed], false),
+    (0x5dfe, &[0xcb, 0xee], false),
+    (0x5efa, &[0xcb, 0xef], false),
+    (0x6106, &[0xcb, 0xf0], false),
+    (0x6957, &[0xcb, 0xf1], false),
+    (0x8171, &[0xcb, 0xf2], false),
+    (0x8654, &[0xcb, 0xf3], false),
+    (0x8e47, &[0xcb, 0xf4], false),
+    (0x9375, &[0xcb, 0xf5], false),
+    (0x9a2b, &[0xcb, 0xf6], false),
+    (0x4e5e, &[0xcb, 0xf7], false),
+    (0x5091, &[0xcb, 0xf8], false),
+    (0x6770, &[0xcb, 0xf9], false),
+    (0x6840, &[0xcb, 0xfa], false),
+    (0x5109, &[0xcb, 0xfb], false),
+    (0x528d, &[0xcb, 0xfc], false),
+    (0x5292, &[0xcb, 0xfd], false),
+    (0x6aa2, &[0xcb, 0xfe], false),
+    (0x77bc, &[0xcc, 0xa1], false),
+    (0x9210, &[0xcc, 0xa2], false),
+    (0x9ed4, &[0xcc, 0xa3], false),
+    (0x52ab, &[0xcc, 0xa4], false),
+    (0x602f, &[0xcc, 0xa5], false),
+    (0x8ff2, &[0xcc, 0xa6], false),
+    (0x5048, &[0xcc, 0xa7], false),
+    (0x61a9, &[0xcc, 0xa8], false),
+    (0x63ed, &[0xcc, 0xa9], false),
+    (0x64ca, &[0xcc, 0xaa], false),
+    (0x683c, &[0xcc, 0xab], false),
+    (0x6a84, &[0xcc, 0xac], false),
+    (0x6fc0, &[0xcc, 0xad], false),
+    (0x8188, &[0xcc, 0xae], false),
+    (0x89a1, &[0xcc, 0xaf], false),
+    (0x9694, &[0xcc, 0xb0], false),
+    (0x5805, &[0xcc, 0xb1], false),
+    (0x727d, &[0xcc, 0xb2], false),
+    (0x72ac, &[0xcc, 0xb3], false),
+    (0x7504, &[0xcc, 0xb4], false),
+    (0x7d79, &[0xcc, 0xb5], false),
+    (0x7e6d, &[0xcc, 0xb6], false),
+    (0x80a9, &[0xcc, 0xb7], false),
+    (0x898b, &[0xcc, 0xb8], false),
+    (0x8b74, &[0xcc, 0xb9], false),
+    (0x9063, &[0xcc, 0xba], false),
+    (0x9d51, &[0xcc, 0xbb], false),
+    (0x6289, &[0xcc, 0xbc], false),
+    (0x6c7a, &[0xcc, 0xbd], false),
+    (0x6f54, &[0xcc, 0xbe], false),
+    (0x7d50, &[0xcc, 0xbf], false),
+    (0x7f3a, &[0xcc, 0xc0], false),
+    (0x8a23, &[0xcc, 0xc1], false),
+    (0x517c, &[0xcc, 0xc2], false),
+    (0x614a, &[0xcc, 0xc3], false),
+    (0x7b9d, &[0xcc, 0xc4], false),
+    (0x8b19, &[0xcc, 0xc5], false),
+    (0x9257, &[0xcc, 0xc6], false),
+    (0x938c, &[0xcc, 0xc7], false),
+    (0x4eac, &[0xcc, 0xc8], false),
+    (0x4fd3, &[0xcc, 0xc9], false),
+    (0x501e, &[0xcc, 0xca], false),
+    (0x50be, &[0xcc, 0xcb], false),
+    (0x5106, &[0xcc, 0xcc], false),
+    (0x52c1, &[0xcc, 0xcd], false),
+    (0x52cd, &[0xcc, 0xce], false),
+    (0x537f, &[0xcc, 0xcf], false),
+    (0x5770, &[0xcc, 0xd0], false),
+    (0x5883, &[0xcc, 0xd1], false),
+    (0x5e9a, &[0xcc, 0xd2], false),
+    (0x5f91, &[0xcc, 0xd3], false),
+    (0x6176, &[0xcc, 0xd4], false),
+    (0x61ac, &[0xcc, 0xd5], false),
+    (0x64ce, &[0xcc, 0xd6], false),
+    (0x656c, &[0xcc, 0xd7], false),
+    (0x666f, &[0xcc, 0xd8], false),
+    (0x66bb, &[0xcc, 0xd9], false),
+    (0x66f4, &[0xcc, 0xda], false),
+    (0x6897, &[0xcc, 0xdb], false),
+    (0x6d87, &[0xcc, 0xdc], false),
+    (0x7085, &[0xcc, 0xdd], false),
+    (0x70f1, &[0xcc, 0xde], false),
+    (0x749f, &[0xcc, 0xdf], false),
+    (0x74a5, &[0xcc, 0xe0], false),
+    (0x74ca, &[0xcc, 0xe1], false),
+    (0x75d9, &[0xcc, 0xe2], false),
+    (0x786c, &[0xcc, 0xe3], false),
+    (0x78ec, &[0xcc, 0xe4], false),
+    (0x7adf, &[0xcc, 0xe5], false),
+    (0x7af6, &[0xcc, 0xe6], false),
+    (0x7d45, &[0xcc, 0xe7], false),
+    (0x7d93, &[0xcc, 0xe8], false),
+    (0x8015, &[0xcc, 0xe9], false),
+    (0x803f, &[0xcc, 0xea], false),
+    (0x811b, &[0xcc, 0xeb], false),
+    (0x8396, &[0xcc, 0xec], false),
+    (0x8b66, &[0xcc, 0xed], false),
+    (0x8f15, &[0xcc, 0xee], false),
+    (0x9015, &[0xcc, 0xef], false),
+    (0x93e1, &[0xcc, 0xf0], false),
+    (0x9803, &[0xcc, 0xf1], false),
+    (0x9838, &[0xcc, 0xf2], false),
+    (0x9a5a, &[0xcc, 0xf3], false),
+    (0x9be8, &[0xcc, 0xf4], false),
+    (0x4fc2, &[0xcc, 0xf5], false),
+    (0x5553, &[0xcc, 0xf6], false),
+    (0x583a, &[0xcc, 0xf7], false),
+    (0x5951, &[0xcc, 0xf8], false),
+    (0x5b63, &[0xcc, 0xf9], false),
+    (0x5c46, &[0xcc, 0xfa], false),
+    (0x60b8, &[0xcc, 0xfb], false),
+    (0x6212, &[0xcc, 0xfc], false),
+    (0x6842, &[0xcc, 0xfd], false),
+    (0x68b0, &[0xcc, 0xfe], false),
+    (0x68e8, &[0xcd, 0xa1], false),
+    (0x6eaa, &[0xcd, 0xa2], false),
+    (0x754c, &[0xcd, 0xa3], false),
+    (0x7678, &[0xcd, 0xa4], false),
+    (0x78ce, &[0xcd, 0xa5], false),
+    (0x7a3d, &[0xcd, 0xa6], false),
+    (0x7cfb, &[0xcd, 0xa7], false),
+    (0x7e6b, &[0xcd, 0xa8], false),
+    (0x7e7c, &[0xcd, 0xa9], false),
+    (0x8a08, &[0xcd, 0xaa], false),
+    (0x8aa1, &[0xcd, 0xab], false),
+    (0x8c3f, &[0xcd, 0xac], false),
+    (0x968e, &[0xcd, 0xad], false),
+    (0x9dc4, &[0xcd, 0xae], false),
+    (0x53e4, &[0xcd, 0xaf], false),
+    (0x53e9, &[0xcd, 0xb0], false),
+    (0x544a, &[0xcd, 0xb1], false),
+    (0x5471, &[0xcd, 0xb2], false),
+    (0x56fa, &[0xcd, 0xb3], false),
+    (0x59d1, &[0xcd, 0xb4], false),
+    (0x5b64, &[0xcd, 0xb5], false),
+    (0x5c3b, &[0xcd, 0xb6], false),
+    (0x5eab, &[0xcd, 0xb7], false),
+    (0x62f7, &[0xcd, 0xb8], false),
+    (0x6537, &[0xcd, 0xb9], false),
+    (0x6545, &[0xcd, 0xba], false),
+    (0x6572, &[0xcd, 0xbb], false),
+    (0x66a0, &[0xcd, 0xbc], false),
+    (0x67af, &[0xcd, 0xbd], false),
+    (0x69c1, &[0xcd, 0xbe], false),
+    (0x6cbd, &[0xcd, 0xbf], false),
+    (0x75fc, &[0xcd, 0xc0], false),
+    (0x7690, &[0xcd, 0xc1], false),
+    (0x777e, &[0xcd, 0xc2], false),
+    (0x7a3f, &[0xcd, 0xc3], false),
+    (0x7f94, &[0xcd, 0xc4], false),
+    (0x8003, &[0xcd, 0xc5], false),
+    (0x80a1, &[0xcd, 0xc6], false),
+    (0x818f, &[0xcd, 0xc7], false),
+    (0x82e6, &[0xcd, 0xc8], false),
+    (0x82fd, &[0xcd, 0xc9], false),
+    (0x83f0, &[0xcd, 0xca], false),
+    (0x85c1, &[0xcd, 0xcb], false),
+    (0x8831, &[0xcd, 0xcc], false),
+    (0x88b4, &[0xcd, 0xcd], false),
+    (0x8aa5, &[0xcd, 0xce], false),
+    (0xf903, &[0xcd, 0xcf], false),
+    (0x8f9c, &[0xcd, 0xd0], false),
+    (0x932e, &[0xcd, 0xd1], false),
+    (0x96c7, &[0xcd, 0xd2], false),
+    (0x9867, &[0xcd, 0xd3], false),
+    (0x9ad8, &[0xcd, 0xd4], false),
+    (0x9f13, &[0xcd, 0xd5], false),
+    (0x54ed, &[0xcd, 0xd6], false),
+    (0x659b, &[0xcd, 0xd7], false),
+    (0x66f2, &[0xcd, 0xd8], false),
+    (0x688f, &[0xcd, 0xd9], false),
+    (0x7a40, &[0xcd, 0xda], false),
+    (0x8c37, &[0xcd, 0xdb], false),
+    (0x9d60, &[0xcd, 0xdc], false),
+    (0x56f0, &[0xcd, 0xdd], false),
+    (0x5764, &[0xcd, 0xde], false),
+    (0x5d11, &[0xcd, 0xdf], false),
+    (0x6606, &[0xcd, 0xe0], false),
+    (0x68b1, &[0xcd, 0xe1], false),
+    (0x68cd, &[0xcd, 0xe2], false),
+    (0x6efe, &[0xcd, 0xe3], false),
+    (0x7428, &[0xcd, 0xe4], false),
+    (0x889e, &[0xcd, 0xe5], false),
+    (0x9be4, &[0xcd, 0xe6], false),
+    (0x6c68, &[0xcd, 0xe7], false),
+    (0xf904, &[0xcd, 0xe8], false),
+    (0x9aa8, &[0xcd, 0xe9], false),
+    (0x4f9b, &[0xcd, 0xea], false),
+    (0x516c, &[0xcd, 0xeb], false),
+    (0x5171, &[0xcd, 0xec], false),
+    (0x529f, &[0xcd, 0xed], false),
+    (0x5b54, &[0xcd, 0xee], false),
+    (0x5de5, &[0xcd, 0xef], false),
+    (0x6050, &[0xcd, 0xf0], false),
+    (0x606d, &[0xcd, 0xf1], false),
+    (0x62f1, &[0xcd, 0xf2], false),
+    (0x63a7, &[0xcd, 0xf3], false),
+    (0x653b, &[0xcd, 0xf4], false),
+    (0x73d9, &[0xcd, 0xf5], false),
+    (0x7a7a, &[0xcd, 0xf6], false),
+    (0x86a3, &[0xcd, 0xf7], false),
+    (0x8ca2, &[0xcd, 0xf8], false),
+    (0x978f, &[0xcd, 0xf9], false),
+    (0x4e32, &[0xcd, 0xfa], false),
+    (0x5be1, &[0xcd, 0xfb], false),
+    (0x6208, &[0xcd, 0xfc], false),
+    (0x679c, &[0xcd, 0xfd], false),
+    (0x74dc, &[0xcd, 0xfe], false),
+    (0x79d1, &[0xce, 0xa1], false),
+    (0x83d3, &[0xce, 0xa2], false),
+    (0x8a87, &[0xce, 0xa3], false),
+    (0x8ab2, &[0xce, 0xa4], false),
+    (0x8de8, &[0xce, 0xa5], false),
+    (0x904e, &[0xce, 0xa6], false),
+    (0x934b, &[0xce, 0xa7], false),
+    (0x9846, &[0xce, 0xa8], false),
+    (0x5ed3, &[0xce, 0xa9], false),
+    (0x69e8, &[0xce, 0xaa], false),
+    (0x85ff, &[0xce, 0xab], false),
+    (0x90ed, &[0xce, 0xac], false),
+    (0xf905, &[0xce, 0xad], false),
+    (0x51a0, &[0xce, 0xae], false),
+    (0x5b98, &[0xce, 0xaf], false),
+    (0x5bec, &[0xce, 0xb0], false),
+    (0x6163, &[0xce, 0xb1], false),
+    (0x68fa, &[0xce, 0xb2], false),
+    (0x6b3e, &[0xce, 0xb3], false),
+    (0x704c, &[0xce, 0xb4], false),
+    (0x742f, &[0xce, 0xb5], false),
+    (0x74d8, &[0xce, 0xb6], false),
+    (0x7ba1, &[0xce, 0xb7], false),
+    (0x7f50, &[0xce, 0xb8], false),
+    (0x83c5, &[0xce, 0xb9], false),
+    (0x89c0, &[0xce, 0xba], false),
+    (0x8cab, &[0xce, 0xbb], false),
+    (0x95dc, &[0xce, 0xbc], false),
+    (0x9928, &[0xce, 0xbd], false),
+    (0x522e, &[0xce, 0xbe], false),
+    (0x605d, &[0xce, 0xbf], false),
+    (0x62ec, &[0xce, 0xc0], false),
+    (0x9002, &[0xce, 0xc1], false),
+    (0x4f8a, &[0xce, 0xc2], false),
+    (0x5149, &[0xce, 0xc3], false),
+    (0x5321, &[0xce, 0xc4], false),
+    (0x58d9, &[0xce, 0xc5], false),
+    (0x5ee3, &[0xce, 0xc6], false),
+    (0x66e0, &[0xce, 0xc7], false),
+    (0x6d38, &[0xce, 0xc8], false),
+    (0x709a, &[0xce, 0xc9], false),
+    (0x72c2, &[0xce, 0xca], false),
+    (0x73d6, &[0xce, 0xcb], false),
+    (0x7b50, &[0xce, 0xcc], false),
+    (0x80f1, &[0xce, 0xcd], false),
+    (0x945b, &[0xce, 0xce], false),
+    (0x5366, &[0xce, 0xcf], false),
+    (0x639b, &[0xce, 0xd0], false),
+    (0x7f6b, &[0xce, 0xd1], false),
+    (0x4e56, &[0xce, 0xd2], false),
+    (0x5080, &[0xce, 0xd3], false),
+    (0x584a, &[0xce, 0xd4], false),
+    (0x58de, &[0xce, 0xd5], false),
+    (0x602a, &[0xce, 0xd6], false),
+    (0x6127, &[0xce, 0xd7], false),
+    (0x62d0, &[0xce, 0xd8], false),
+    (0x69d0, &[0xce, 0xd9], false),
+    (0x9b41, &[0xce, 0xda], false),
+    (0x5b8f, &[0xce, 0xdb], false),
+    (0x7d18, &[0xce, 0xdc], false),
+    (0x80b1, &[0xce, 0xdd], false),
+    (0x8f5f, &[0xce, 0xde], false),
+    (0x4ea4, &[0xce, 0xdf], false),
+    (0x50d1, &[0xce, 0xe0], false),
+    (0x54ac, &[0xce, 0xe1], false),
+    (0x55ac, &[0xce, 0xe2], false),
+    (0x5b0c, &[0xce, 0xe3], false),
+    (0x5da0, &[0xce, 0xe4], false),
+    (0x5de7, &[0xce, 0xe5], false),
+    (0x652a, &[0xce, 0xe6], false),
+    (0x654e, &[0xce, 0xe7], false),
+    (0x6821, &[0xce, 0xe8], false),
+    (0x6a4b, &[0xce, 0xe9], false),
+    (0x72e1, &[0xce, 0xea], false),
+    (0x768e, &[0xce, 0xeb], false),
+    (0x77ef, &[0xce, 0xec], false),
+    (0x7d5e, &[0xce, 0xed], false),
+    (0x7ff9, &[0xce, 0xee], false),
+    (0x81a0, &[0xce, 0xef], false),
+    (0x854e, &[0xce, 0xf0], false),
+    (0x86df, &[0xce, 0xf1], false),
+    (0x8f03, &[0xce, 0xf2], false),
+    (0x8f4e, &[0xce, 0xf3], false),
+    (0x90ca, &[0xce, 0xf4], false),
+    (0x9903, &[0xce, 0xf5], false),
+    (0x9a55, &[0xce, 0xf6], false),
+    (0x9bab, &[0xce, 0xf7], false),
+    (0x4e18, &[0xce, 0xf8], false),
+    (0x4e45, &[0xce, 0xf9], false),
+    (0x4e5d, &[0xce, 0xfa], false),
+    (0x4ec7, &[0xce, 0xfb], false),
+    (0x4ff1, &[0xce, 0xfc], false),
+    (0x5177, &[0xce, 0xfd], false),
+    (0x52fe, &[0xce, 0xfe], false),
+    (0x5340, &[0xcf, 0xa1], false),
+    (0x53e3, &[0xcf, 0xa2], false),
+    (0x53e5, &[0xcf, 0xa3], false),
+    (0x548e, &[0xcf, 0xa4], false),
+    (0x5614, &[0xcf, 0xa5], false),
+    (0x5775, &[0xcf, 0xa6], false),
+    (0x57a2, &[0xcf, 0xa7], false),
+    (0x5bc7, &[0xcf, 0xa8], false),
+    (0x5d87, &[0xcf, 0xa9], false),
+    (0x5ed0, &[0xcf, 0xaa], false),
+    (0x61fc, &[0xcf, 0xab], false),
+    (0x62d8, &[0xcf, 0xac], false),
+    (0x6551, &[0xcf, 0xad], false),
+    (0x67b8, &[0xcf, 0xae], false),
+    (0x67e9, &[0xcf, 0xaf], false),
+    (0x69cb, &[0xcf, 0xb0], false),
+    (0x6b50, &[0xcf, 0xb1], false),
+    (0x6bc6, &[0xcf, 0xb2], false),
+    (0x6bec, &[0xcf, 0xb3], false),
+    (0x6c42, &[0xcf, 0xb4], false),
+    (0x6e9d, &[0xcf, 0xb5], false),
+    (0x7078, &[0xcf, 0xb6], false),
+    (0x72d7, &[0xcf, 0xb7], false),
+    (0x7396, &[0xcf, 0xb8], false),
+    (0x7403, &[0xcf, 0xb9], false),
+    (0x77bf, &[0xcf, 0xba], false),
+    (0x77e9, &[0xcf, 0xbb], false),
+    (0x7a76, &[0xcf, 0xbc], false),
+    (0x7d7f, &[0xcf, 0xbd], false),
+    (0x8009, &[0xcf, 0xbe], false),
+    (0x81fc, &[0xcf, 0xbf], false),
+    (0x8205, &[0xcf, 0xc0], false),
+    (0x820a, &[0xcf, 0xc1], false),
+    (0x82df, &[0xcf, 0xc2], false),
+    (0x8862, &[0xcf, 0xc3], false),
+    (0x8b33, &[0xcf, 0xc4], false),
+    (0x8cfc, &[0xcf, 0xc5], false),
+    (0x8ec0, &[0xcf, 0xc6], false),
+    (0x9011, &[0xcf, 0xc7], false),
+    (0x90b1, &[0xcf, 0xc8], false),
+    (0x9264, &[0xcf, 0xc9], false),
+    (0x92b6, &[0xcf, 0xca], false),
+    (0x99d2, &[0xcf, 0xcb], false),
+    (0x9a45, &[0xcf, 0xcc], false),
+    (0x9ce9, &[0xcf, 0xcd], false),
+    (0x9dd7, &[0xcf, 0xce], false),
+    (0x9f9c, &[0xcf, 0xcf], false),
+    (0x570b, &[0xcf, 0xd0], false),
+    (0x5c40, &[0xcf, 0xd1], false),
+    (0x83ca, &[0xcf, 0xd2], false),
+    (0x97a0, &[0xcf, 0xd3], false),
+    (0x97ab, &[0xcf, 0xd4], false),
+    (0x9eb4, &[0xcf, 0xd5], false),
+    (0x541b, &[0xcf, 0xd6], false),
+    (0x7a98, &[0xcf, 0xd7], false),
+    (0x7fa4, &[0xcf, 0xd8], false),
+    (0x88d9, &[0xcf, 0xd9], false),
+    (0x8ecd, &[0xcf, 0xda], false),
+    (0x90e1, &[0xcf, 0xdb], false),
+    (0x5800, &[0xcf, 0xdc], false),
+    (0x5c48, &[0xcf, 0xdd], false),
+    (0x6398, &[0xcf, 0xde], false),
+    (0x7a9f, &[0xcf, 0xdf], false),
+    (0x5bae, &[0xcf, 0xe0], false),
+    (0x5f13, &[0xcf, 0xe1], false),
+    (0x7a79, &[0xcf, 0xe2], false),
+    (0x7aae, &[0xcf, 0xe3], false),
+    (0x828e, &[0xcf, 0xe4], false),
+    (0x8eac, &[0xcf, 0xe5], false),
+    (0x5026, &[0xcf, 0xe6], false),
+    (0x5238, &[0xcf, 0xe7], false),
+    (0x52f8, &[0xcf, 0xe8], false),
+    (0x5377, &[0xcf, 0xe9], false),
+    (0x5708, &[0xcf, 0xea], false),
+    (0x62f3, &[0xcf, 0xeb], false),
+    (0x6372, &[0xcf, 0xec], false),
+    (0x6b0a, &[0xcf, 0xed], false),
+    (0x6dc3, &[0xcf, 0xee], false),
+    (0x7737, &[0xcf, 0xef], false),
+    (0x53a5, &[0xcf, 0xf0], false),
+    (0x7357, &[0xcf, 0xf1], false),
+    (0x8568, &[0xcf, 0xf2], false),
+    (0x8e76, &[0xcf, 0xf3], false),
+    (0x95d5, &[0xcf, 0xf4], false),
+    (0x673a, &[0xcf, 0xf5], false),
+    (0x6ac3, &[0xcf, 0xf6], false),
+    (0x6f70, &[0xcf, 0xf7], false),
+    (0x8a6d, &[0xcf, 0xf8], false),
+    (0x8ecc, &[0xcf, 0xf9], false),
+    (0x994b, &[0xcf, 0xfa], false),
+    (0xf906, &[0xcf, 0xfb], false),
+    (0x6677, &[0xcf, 0xfc], false),
+    (0x6b78, &[0xcf, 0xfd], false),
+    (0x8cb4, &[0xcf, 0xfe], false),
+    (0x9b3c, &[0xd0, 0xa1], false),
+    (0xf907, &[0xd0, 0xa2], false),
+    (0x53eb, &[0xd0, 0xa3], false),
+    (0x572d, &[0xd0, 0xa4], false),
+    (0x594e, &[0xd0, 0xa5], false),
+    (0x63c6, &[0xd0, 0xa6], false),
+    (0x69fb, &[0xd0, 0xa7], false),
+    (0x73ea, &[0xd0, 0xa8], false),
+    (0x7845, &[0xd0, 0xa9], false),
+    (0x7aba, &[0xd0, 0xaa], false),
+    (0x7ac5, &[0xd0, 0xab], false),
+    (0x7cfe, &[0xd0, 0xac], false),
+    (0x8475, &[0xd0, 0xad], false),
+    (0x898f, &[0xd0, 0xae], false),
+    (0x8d73, &[0xd0, 0xaf], false),
+    (0x9035, &[0xd0, 0xb0], false),
+    (0x95a8, &[0xd0, 0xb1], false),
+    (0x52fb, &[0xd0, 0xb2], false),
+    (0x5747, &[0xd0, 0xb3], false),
+    (0x7547, &[0xd0, 0xb4], false),
+    (0x7b60, &[0xd0, 0xb5], false),
+    (0x83cc, &[0xd0, 0xb6], false),
+    (0x921e, &[0xd0, 0xb7], false),
+    (0xf908, &[0xd0, 0xb8], false),
+    (0x6a58, &[0xd0, 0xb9], false),
+    (0x514b, &[0xd0, 0xba], false),
+    (0x524b, &[0xd0, 0xbb], false),
+    (0x5287, &[0xd0, 0xbc], false),
+    (0x621f, &[0xd0, 0xbd], false),
+    (0x68d8, &[0xd0, 0xbe], false),
+    (0x6975, &[0xd0, 0xbf], false),
+    (0x9699, &[0xd0, 0xc0], false),
+    (0x50c5, &[0xd0, 0xc1], false),
+    (0x52a4, &[0xd0, 0xc2], false),
+    (0x52e4, &[0xd0, 0xc3], false),
+    (0x61c3, &[0xd0, 0xc4], false),
+    (0x65a4, &[0xd0, 0xc5], false),
+    (0x6839, &[0xd0, 0xc6], false),
+    (0x69ff, &[0xd0, 0xc7], false),
+    (0x747e, &[0xd0, 0xc8], false),
+    (0x7b4b, &[0xd0, 0xc9], false),
+    (0x82b9, &[0xd0, 0xca], false),
+    (0x83eb, &[0xd0, 0xcb], false),
+    (0x89b2, &[0xd0, 0xcc], false),
+    (0x8b39, &[0xd0, 0xcd], false),
+    (0x8fd1, &[0xd0, 0xce], false),
+    (0x9949, &[0xd0, 0xcf], false),
+    (0xf909, &[0xd0, 0xd0], false),
+    (0x4eca, &[0xd0, 0xd1], false),
+    (0x5997, &[0xd0, 0xd2], false),
+    (0x64d2, &[0xd0, 0xd3], false),
+    (0x6611, &[0xd0, 0xd4], false),
+    (0x6a8e, &[0xd0, 0xd5], false),
+    (0x7434, &[0xd0, 0xd6], false),
+    (0x7981, &[0xd0, 0xd7], false),
+    (0x79bd, &[0xd0, 0xd8], false),
+    (0x82a9, &[0xd0, 0xd9], false),
+    (0x887e, &[0xd0, 0xda], false),
+    (0x887f, &[0xd0, 0xdb], false),
+    (0x895f, &[0xd0, 0xdc], false),
+    (0xf90a, &[0xd0, 0xdd], false),
+    (0x9326, &[0xd0, 0xde], false),
+    (0x4f0b, &[0xd0, 0xdf], false),
+    (0x53ca, &[0xd0, 0xe0], false),
+    (0x6025, &[0xd0, 0xe1], false),
+    (0x6271, &[0xd0, 0xe2], false),
+    (0x6c72, &[0xd0, 0xe3], false),
+    (0x7d1a, &[0xd0, 0xe4], false),
+    (0x7d66, &[0xd0, 0xe5], false),
+    (0x4e98, &[0xd0, 0xe6], false),
+    (0x5162, &[0xd0, 0xe7], false),
+    (0x77dc, &[0xd0, 0xe8], false),
+    (0x80af, &[0xd0, 0xe9], false),
+    (0x4f01, &[0xd0, 0xea], false),
+    (0x4f0e, &[0xd0, 0xeb], false),
+    (0x5176, &[0xd0, 0xec], false),
+    (0x5180, &[0xd0, 0xed], false),
+    (0x55dc, &[0xd0, 0xee], false),
+    (0x5668, &[0xd0, 0xef], false),
+    (0x573b, &[0xd0, 0xf0], false),
+    (0x57fa, &[0xd0, 0xf1], false),
+    (0x57fc, &[0xd0, 0xf2], false),
+    (0x5914, &[0xd0, 0xf3], false),
+    (0x5947, &[0xd0, 0xf4], false),
+    (0x5993, &[0xd0, 0xf5], false),
+    (0x5bc4, &[0xd0, 0xf6], false),
+    (0x5c90, &[0xd0, 0xf7], false),
+    (0x5d0e, &[0xd0, 0xf8], false),
+    (0x5df1, &[0xd0, 0xf9], false),
+    (0x5e7e, &[0xd0, 0xfa], false),
+    (0x5fcc, &[0xd0, 0xfb], false),
+    (0x6280, &[0xd0, 0xfc], false),
+    (0x65d7, &[0xd0, 0xfd], false),
+    (0x65e3, &[0xd0, 0xfe], false),
+    (0x671e, &[0xd1, 0xa1], false),
+    (0x671f, &[0xd1, 0xa2], false),
+    (0x675e, &[0xd1, 0xa3], false),
+    (0x68cb, &[0xd1, 0xa4], false),
+    (0x68c4, &[0xd1, 0xa5], false),
+    (0x6a5f, &[0xd1, 0xa6], false),
+    (0x6b3a, &[0xd1, 0xa7], false),
+    (0x6c23, &[0xd1, 0xa8], false),
+    (0x6c7d, &[0xd1, 0xa9], false),
+    (0x6c82, &[0xd1, 0xaa], false),
+    (0x6dc7, &[0xd1, 0xab], false),
+    (0x7398, &[0xd1, 0xac], false),
+    (0x7426, &[0xd1, 0xad], false),
+    (0x742a, &[0xd1, 0xae], false),
+    (0x7482, &[0xd1, 0xaf], false),
+    (0x74a3, &[0xd1, 0xb0], false),
+    (0x7578, &[0xd1, 0xb1], false),
+    (0x757f, &[0xd1, 0xb2], false),
+    (0x7881, &[0xd1, 0xb3], false),
+    (0x78ef, &[0xd1, 0xb4], false),
+    (0x7941, &[0xd1, 0xb5], false),
+    (0x7947, &[0xd1, 0xb6], false),
+    (0x7948, &[0xd1, 0xb7], false),
+    (0x797a, &[0xd1, 0xb8], false),
+    (0x7b95, &[0xd1, 0xb9], false),
+    (0x7d00, &[0xd1, 0xba], false),
+    (0x7dba, &[0xd1, 0xbb], false),
+    (0x7f88, &[0xd1, 0xbc], false),
+    (0x8006, &[0xd1, 0xbd], false),
+    (0x802d, &[0xd1, 0xbe], false),
+    (0x808c, &[0xd1, 0xbf], false),
+    (0x8a18, &[0xd1, 0xc0], false),
+    (0x8b4f, &[0xd1, 0xc1], false),
+    (0x8c48, &[0xd1, 0xc2], false),
+    (0x8d77, &[0xd1, 0xc3], false),
+    (0x9321, &[0xd1, 0xc4], false),
+    (0x9324, &[0xd1, 0xc5], false),
+    (0x98e2, &[0xd1, 0xc6], false),
+    (0x9951, &[0xd1, 0xc7], false),
+    (0x9a0e, &[0xd1, 0xc8], false),
+    (0x9a0f, &[0xd1, 0xc9], false),
+    (0x9a65, &[0xd1, 0xca], false),
+    (0x9e92, &[0xd1, 0xcb], false),
+    (0x7dca, &[0xd1, 0xcc], false),
+    (0x4f76, &[0xd1, 0xcd], false),
+    (0x5409, &[0xd1, 0xce], false),
+    (0x62ee, &[0xd1, 0xcf], false),
+    (0x6854, &[0xd1, 0xd0], false),
+    (0x91d1, &[0xd1, 0xd1], false),
+    (0x55ab, &[0xd1, 0xd2], false),
+    (0x513a, &[0xd1, 0xd3], false),
+    (0xf90b, &[0xd1, 0xd4], false),
+    (0xf90c, &[0xd1, 0xd5], false),
+    (0x5a1c, &[0xd1, 0xd6], false),
+    (0x61e6, &[0xd1, 0xd7], false),
+    (0xf90d, &[0xd1, 0xd8], false),
+    (0x62cf, &[0xd1, 0xd9], false),
+    (0x62ff, &[0xd1, 0xda], false),
+    (0xf90e, &[0xd1, 0xdb], false),
+    (0xf90f, &[0xd1, 0xdc], false),
+    (0xf910, &[0xd1, 0xdd], false),
+    (0xf911, &[0xd1, 0xde], false),
+    (0xf912, &[0xd1, 0xdf], false),
+    (0xf913, &[0xd1, 0xe0], false),
+    (0x90a3, &[0xd1, 0xe1], false),
+    (0xf914, &[0xd1, 0xe2], false),
+    (0xf915, &[0xd1, 0xe3], false),
+    (0xf916, &[0xd1, 0xe4], false),
+    (0xf917, &[0xd1, 0xe5], false),
+    (0xf918, &[0xd1, 0xe6], false),
+    (0x8afe, &[0xd1, 0xe7], false),
+    (0xf919, &[0xd1, 0xe8], false),
+    (0xf91a, &[0xd1, 0xe9], false),
+    (0xf91b, &[0xd1, 0xea], false),
+    (0xf91c, &[0xd1, 0xeb], false),
+    (0x6696, &[0xd1, 0xec], false),
+    (0xf91d, &[0xd1, 0xed], false),
+    (0x7156, &[0xd1, 0xee], false),
+    (0xf91e, &[0xd1, 0xef], false),
+    (0xf91f, &[0xd1, 0xf0], false),
+    (0x96e3, &[0xd1, 0xf1], false),
+    (0xf920, &[0xd1, 0xf2], false),
+    (0x634f, &[0xd1, 0xf3], false),
+    (0x637a, &[0xd1, 0xf4], false),
+    (0x5357, &[0xd1, 0xf5], false),
+    (0xf921, &[0xd1, 0xf6], false),
+    (0x678f, &[0xd1, 0xf7], false),
+    (0x6960, &[0xd1, 0xf8], false),
+    (0x6e73, &[0xd1, 0xf9], false),
+    (0xf922, &[0xd1, 0xfa], false),
+    (0x7537, &[0xd1, 0xfb], false),
+    (0xf923, &[0xd1, 0xfc], false),
+    (0xf924, &[0xd1, 0xfd], false),
+    (0xf925, &[0xd1, 0xfe], false),
+    (0x7d0d, &[0xd2, 0xa1], false),
+    (0xf926, &[0xd2, 0xa2], false),
+    (0xf927, &[0xd2, 0xa3], false),
+    (0x8872, &[0xd2, 0xa4], false),
+    (0x56ca, &[0xd2, 0xa5], false),
+    (0x5a18, &[0xd2, 0xa6], false),
+    (0xf928, &[0xd2, 0xa7], false),
+    (0xf929, &[0xd2, 0xa8], false),
+    (0xf92a, &[0xd2, 0xa9], false),
+    (0xf92b, &[0xd2, 0xaa], false),
+    (0xf92c, &[0xd2, 0xab], false),
+    (0x4e43, &[0xd2, 0xac], false),
+    (0xf92d, &[0xd2, 0xad], false),
+    (0x5167, &[0xd2, 0xae], false),
+    (0x5948, &[0xd2, 0xaf], false),
+    (0x67f0, &[0xd2, 0xb0], false),
+    (0x8010, &[0xd2, 0xb1], false),
+    (0xf92e, &[0xd2, 0xb2], false),
+    (0x5973, &[0xd2, 0xb3], false),
+    (0x5e74, &[0xd2, 0xb4], false),
+    (0x649a, &[0xd2, 0xb5], false),
+    (0x79ca, &[0xd2, 0xb6], false),
+    (0x5ff5, &[0xd2, 0xb7], false),
+    (0x606c, &[0xd2, 0xb8], false),
+    (0x62c8, &[0xd2, 0xb9], false),
+    (0x637b, &[0xd2, 0xba], false),
+    (0x5be7, &[0xd2, 0xbb], false),
+    (0x5bd7, &[0xd2, 0xbc], false),
+    (0x52aa, &[0xd2, 0xbd], false),
+    (0xf92f, &[0xd2, 0xbe], false),
+    (0x5974, &[0xd2, 0xbf], false),
+    (0x5f29, &[0xd2, 0xc0], false),
+    (0x6012, &[0xd2, 0xc1], false),
+    (0xf930, &[0xd2, 0xc2], false),
+    (0xf931, &[0xd2, 0xc3], false),
+    (0xf932, &[0xd2, 0xc4], false),
+    (0x7459, &[0xd2, 0xc5], false),
+    (0xf933, &[0xd2, 0xc6], false),
+    (0xf934, &[0xd2, 0xc7], false),
+    (0xf935, &[0xd2, 0xc8], false),
+    (0xf936, &[0xd2, 0xc9], false),
+    (0xf937, &[0xd2, 0xca], false),
+    (0xf938, &[0xd2, 0xcb], false),
+    (0x99d1, &[0xd2, 0xcc], false),
+    (0xf939, &[0xd2, 0xcd], false),
+    (0xf93a, &[0xd2, 0xce], false),
+    (0xf93b, &[0xd2, 0xcf], false),
+    (0xf93c, &[0xd2, 0xd0], false),
+    (0xf93d, &[0xd2, 0xd1], false),
+    (0xf93e, &[0xd2, 0xd2], false),
+    (0xf93f, &[0xd2, 0xd3], false),
+    (0xf940, &[0xd2, 0xd4], false),
+    (0xf941, &[0xd2, 0xd5], false),
+    (0xf942, &[0xd2, 0xd6], false),
+    (0xf943, &[0xd2, 0xd7], false),
+    (0x6fc3, &[0xd2, 0xd8], false),
+    (0xf944, &[0xd2, 0xd9], false),
+    (0xf945, &[0xd2, 0xda], false),
+    (0x81bf, &[0xd2, 0xdb], false),
+    (0x8fb2, &[0xd2, 0xdc], false),
+    (0x60f1, &[0xd2, 0xdd], false),
+    (0xf946, &[0xd2, 0xde], false),
+    (0xf947, &[0xd2, 0xdf], false),
+    (0x8166, &[0xd2, 0xe0], false),
+    (0xf948, &[0xd2, 0xe1], false),
+    (0xf949, &[0xd2, 0xe2], false),
+    (0x5c3f, &[0xd2, 0xe3], false),
+    (0xf94a, &[0xd2, 0xe4], false),
+    (0xf94b, &[0xd2, 0xe5], false),
+    (0xf94c, &[0xd2, 0xe6], false),
+    (0xf94d, &[0xd2, 0xe7], false),
+    (0xf94e, &[0xd2, 0xe8], false),
+    (0xf94f, &[0xd2, 0xe9], false),
+    (0xf950, &[0xd2, 0xea], false),
+    (0xf951, &[0xd2, 0xeb], false),
+    (0x5ae9, &[0xd2, 0xec], false),
+    (0x8a25, &[0xd2, 0xed], false),
+    (0x677b, &[0xd2, 0xee], false),
+    (0x7d10, &[0xd2, 0xef], false),
+    (0xf952, &[0xd2, 0xf0], false),
+    (0xf953, &[0xd2, 0xf1], false),
+    (0xf954, &[0xd2, 0xf2], false),
+    (0xf955, &[0xd2, 0xf3], false),
+    (0xf956, &[0xd2, 0xf4], false),
+    (0xf957, &[0xd2, 0xf5], false),
+    (0x80fd, &[0xd2, 0xf6], false),
+    (0xf958, &[0xd2, 0xf7], false),
+    (0xf959, &[0xd2, 0xf8], false),
+    (0x5c3c, &[0xd2, 0xf9], false),
+    (0x6ce5, &[0xd2, 0xfa], false),
+    (0x533f, &[0xd2, 0xfb], false),
+    (0x6eba, &[0xd2, 0xfc], false),
+    (0x591a, &[0xd2, 0xfd], false),
+    (0x8336, &[0xd2, 0xfe], false),
+    (0x4e39, &[0xd3, 0xa1], false),
+    (0x4eb6, &[0xd3, 0xa2], false),
+    (0x4f46, &[0xd3, 0xa3], false),
+    (0x55ae, &[0xd3, 0xa4], false),
+    (0x5718, &[0xd3, 0xa5], false),
+    (0x58c7, &[0xd3, 0xa6], false),
+    (0x5f56, &[0xd3, 0xa7], false),
+    (0x65b7, &[0xd3, 0xa8], false),
+    (0x65e6, &[0xd3, 0xa9], false),
+    (0x6a80, &[0xd3, 0xaa], false),
+    (0x6bb5, &[0xd3, 0xab], false),
+    (0x6e4d, &[0xd3, 0xac], false),
+    (0x77ed, &[0xd3, 0xad], false),
+    (0x7aef, &[0xd3, 0xae], false),
+    (0x7c1e, &[0xd3, 0xaf], false),
+    (0x7dde, &[0xd3, 0xb0], false),
+    (0x86cb, &[0xd3, 0xb1], false),
+    (0x8892, &[0xd3, 0xb2], false),
+    (0x9132, &[0xd3, 0xb3], false),
+    (0x935b, &[0xd3, 0xb4], false),
+    (0x64bb, &[0xd3, 0xb5], false),
+    (0x6fbe, &[0xd3, 0xb6], false),
+    (0x737a, &[0xd3, 0xb7], false),
+    (0x75b8, &[0xd3, 0xb8], false),
+    (0x9054, &[0xd3, 0xb9], false),
+    (0x5556, &[0xd3, 0xba], false),
+    (0x574d, &[0xd3, 0xbb], false),
+    (0x61ba, &[0xd3, 0xbc], false),
+    (0x64d4, &[0xd3, 0xbd], false),
+    (0x66c7, &[0xd3, 0xbe], false),
+    (0x6de1, &[0xd3, 0xbf], false),
+    (0x6e5b, &[0xd3, 0xc0], false),
+    (0x6f6d, &[0xd3, 0xc1], false),
+    (0x6fb9, &[0xd3, 0xc2], false),
+    (0x75f0, &[0xd3, 0xc3], false),
+    (0x8043, &[0xd3, 0xc4], false),
+    (0x81bd, &[0xd3, 0xc5], false),
+    (0x8541, &[0xd3, 0xc6], false),
+    (0x8983, &[0xd3, 0xc7], false),
+    (0x8ac7, &[0xd3, 0xc8], false),
+    (0x8b5a, &[0xd3, 0xc9], false),
+    (0x931f, &[0xd3, 0xca], false),
+    (0x6c93, &[0xd3, 0xcb], false),
+    (0x7553, &[0xd3, 0xcc], false),
+    (0x7b54, &[0xd3, 0xcd], false),
+    (0x8e0f, &[0xd3, 0xce], false),
+    (0x905d, &[0xd3, 0xcf], false),
+    (0x5510, &[0xd3, 0xd0], false),
+    (0x5802, &[0xd3, 0xd1], false),
+    (0x5858, &[0xd3, 0xd2], false),
+    (0x5e62, &[0xd3, 0xd3], false),
+    (0x6207, &[0xd3, 0xd4], false),
+    (0x649e, &[0xd3, 0xd5], false),
+    (0x68e0, &[0xd3, 0xd6], false),
+    (0x7576, &[0xd3, 0xd7], false),
+    (0x7cd6, &[0xd3, 0xd8], false),
+    (0x87b3, &[0xd3, 0xd9], false),
+    (0x9ee8, &[0xd3, 0xda], false),
+    (0x4ee3, &[0xd3, 0xdb], false),
+    (0x5788, &[0xd3, 0xdc], false),
+    (0x576e, &[0xd3, 0xdd], false),
+    (0x5927, &[0xd3, 0xde], false),
+    (0x5c0d, &[0xd3, 0xdf], false),
+    (0x5cb1, &[0xd3, 0xe0], false),
+    (0x5e36, &[0xd3, 0xe1], false),
+    (0x5f85, &[0xd3, 0xe2], false),
+    (0x6234, &[0xd3, 0xe3], false),
+    (0x64e1, &[0xd3, 0xe4], false),
+    (0x73b3, &[0xd3, 0xe5], false),
+    (0x81fa, &[0xd3, 0xe6], false),
+    (0x888b, &[0xd3, 0xe7], false),
+    (0x8cb8, &[0xd3, 0xe8], false),
+    (0x968a, &[0xd3, 0xe9], false),
+    (0x9edb, &[0xd3, 0xea], false),
+    (0x5b85, &[0xd3, 0xeb], false),
+    (0x5fb7, &[0xd3, 0xec], false),
+    (0x60b3, &[0xd3, 0xed], false),
+    (0x5012, &[0xd3, 0xee], false),
+    (0x5200, &[0xd3, 0xef], false),
+    (0x5230, &[0xd3, 0xf0], false),
+    (0x5716, &[0xd3, 0xf1], false),
+    (0x5835, &[0xd3, 0xf2], false),
+    (0x5857, &[0xd3, 0xf3], false),
+    (0x5c0e, &[0xd3, 0xf4], false),
+    (0x5c60, &[0xd3, 0xf5], false),
+    (0x5cf6, &[0xd3, 0xf6], false),
+    (0x5d8b, &[0xd3, 0xf7], false),
+    (0x5ea6, &[0xd3, 0xf8], false),
+    (0x5f92, &[0xd3, 0xf9], false),
+    (0x60bc, &[0xd3, 0xfa], false),
+    (0x6311, &[0xd3, 0xfb], false),
+    (0x6389, &[0xd3, 0xfc], false),
+    (0x6417, &[0xd3, 0xfd], false),
+    (0x6843, &[0xd3, 0xfe], false),
+    (0x68f9, &[0xd4, 0xa1], false),
+    (0x6ac2, &[0xd4, 0xa2], false),
+    (0x6dd8, &[0xd4, 0xa3], false),
+    (0x6e21, &[0xd4, 0xa4], false),
+    (0x6ed4, &[0xd4, 0xa5], false),
+    (0x6fe4, &[0xd4, 0xa6], false),
+    (0x71fe, &[0xd4, 0xa7], false),
+    (0x76dc, &[0xd4, 0xa8], false),
+    (0x7779, &[0xd4, 0xa9], false),
+    (0x79b1, &[0xd4, 0xaa], false),
+    (0x7a3b, &[0xd4, 0xab], false),
+    (0x8404, &[0xd4, 0xac], false),
+    (0x89a9, &[0xd4, 0xad], false),
+    (0x8ced, &[0xd4, 0xae], false),
+    (0x8df3, &[0xd4, 0xaf], false),
+    (0x8e48, &[0xd4, 0xb0], false),
+    (0x9003, &[0xd4, 0xb1], false),
+    (0x9014, &[0xd4, 0xb2], false),
+    (0x9053, &[0xd4, 0xb3], false),
+    (0x90fd, &[0xd4, 0xb4], false),
+    (0x934d, &[0xd4, 0xb5], false),
+    (0x9676, &[0xd4, 0xb6], false),
+    (0x97dc, &[0xd4, 0xb7], false),
+    (0x6bd2, &[0xd4, 0xb8], false),
+    (0x7006, &[0xd4, 0xb9], false),
+    (0x7258, &[0xd4, 0xba], false),
+    (0x72a2, &[0xd4, 0xbb], false),
+    (0x7368, &[0xd4, 0xbc], false),
+    (0x7763, &[0xd4, 0xbd], false),
+    (0x79bf, &[0xd4, 0xbe], false),
+    (0x7be4, &[0xd4, 0xbf], false),
+    (0x7e9b, &[0xd4, 0xc0], false),
+    (0x8b80, &[0xd4, 0xc1], false),
+    (0x58a9, &[0xd4, 0xc2], false),
+    (0x60c7, &[0xd4, 0xc3], false),
+    (0x6566, &[0xd4, 0xc4], false),
+    (0x65fd, &[0xd4, 0xc5], false),
+    (0x66be, &[0xd4, 0xc6], false),
+    (0x6c8c, &[0xd4, 0xc7], false),
+    (0x711e, &[0xd4, 0xc8], false),
+    (0x71c9, &[0xd4, 0xc9], false),
+    (0x8c5a, &[0xd4, 0xca], false),
+    (0x9813, &[0xd4, 0xcb], false),
+    (0x4e6d, &[0xd4, 0xcc], false),
+    (0x7a81, &[0xd4, 0xcd], false),
+    (0x4edd, &[0xd4, 0xce], false),
+    (0x51ac, &[0xd4, 0xcf], false),
+    (0x51cd, &[0xd4, 0xd0], false),
+    (0x52d5, &[0xd4, 0xd1], false),
+    (0x540c, &[0xd4, 0xd2], false),
+    (0x61a7, &[0xd4, 0xd3], false),
+    (0x6771, &[0xd4, 0xd4], false),
+    (0x6850, &[0xd4, 0xd5], false),
+    (0x68df, &[0xd4, 0xd6], false),
+    (0x6d1e, &[0xd4, 0xd7], false),
+    (0x6f7c, &[0xd4, 0xd8], false),
+    (0x75bc, &[0xd4, 0xd9], false),
+    (0x77b3, &[0xd4, 0xda], false),
+    (0x7ae5, &[0xd4, 0xdb], false),
+    (0x80f4, &[0xd4, 0xdc], false),
+    (0x8463, &[0xd4, 0xdd], false),
+    (0x9285, &[0xd4, 0xde], false),
+    (0x515c, &[0xd4, 0xdf], false),
+    (0x6597, &[0xd4, 0xe0], false),
+    (0x675c, &[0xd4, 0xe1], false),
+    (0x6793, &[0xd4, 0xe2], false),
+    (0x75d8, &[0xd4, 0xe3], false),
+    (0x7ac7, &[0xd4, 0xe4], false),
+    (0x8373, &[0xd4, 0xe5], false),
+    (0xf95a, &[0xd4, 0xe6], false),
+    (0x8c46, &[0xd4, 0xe7], false),
+    (0x9017, &[0xd4, 0xe8], false),
+    (0x982d, &[0xd4, 0xe9], false),
+    (0x5c6f, &[0xd4, 0xea], false),
+    (0x81c0, &[0xd4, 0xeb], false),
+    (0x829a, &[0xd4, 0xec], false),
+    (0x9041, &[0xd4, 0xed], false),
+    (0x906f, &[0xd4, 0xee], false),
+    (0x920d, &[0xd4, 0xef], false),
+    (0x5f97, &[0xd4, 0xf0], false),
+    (0x5d9d, &[0xd4, 0xf1], false),
+    (0x6a59, &[0xd4, 0xf2], false),
+    (0x71c8, &[0xd4, 0xf3], false),
+    (0x767b, &[0xd4, 0xf4], false),
+    (0x7b49, &[0xd4, 0xf5], false),
+    (0x85e4, &[0xd4, 0xf6], false),
+    (0x8b04, &[0xd4, 0xf7], false),
+    (0x9127, &[0xd4, 0xf8], false),
+    (0x9a30, &[0xd4, 0xf9], false),
+    (0x5587, &[0xd4, 0xfa], false),
+    (0x61f6, &[0xd4, 0xfb], false),
+    (0xf95b, &[0xd4, 0xfc], false),
+    (0x7669, &[0xd4, 0xfd], false),
+    (0x7f85, &[0xd4, 0xfe], false),
+    (0x863f, &[0xd5, 0xa1], false),
+    (0x87ba, &[0xd5, 0xa2], false),
+    (0x88f8, &[0xd5, 0xa3], false),
+    (0x908f, &[0xd5, 0xa4], false),
+    (0xf95c, &[0xd5, 0xa5], false),
+    (0x6d1b, &[0xd5, 0xa6], false),
+    (0x70d9, &[0xd5, 0xa7], false),
+    (0x73de, &[0xd5, 0xa8], false),
+    (0x7d61, &[0xd5, 0xa9], false),
+    (0x843d, &[0xd5, 0xaa], false),
+    (0xf95d, &[0xd5, 0xab], false),
+    (0x916a, &[0xd5, 0xac], false),
+    (0x99f1, &[0xd5, 0xad], false),
+    (0xf95e, &[0xd5, 0xae], false),
+    (0x4e82, &[0xd5, 0xaf], false),
+    (0x5375, &[0xd5, 0xb0], false),
+    (0x6b04, &[0xd5, 0xb1], false),
+    (0x6b12, &[0xd5, 0xb2], false),
+    (0x703e, &[0xd5, 0xb3], false),
+    (0x721b, &[0xd5, 0xb4], false),
+    (0x862d, &[0xd5, 0xb5], false),
+    (0x9e1e, &[0xd5, 0xb6], false),
+    (0x524c, &[0xd5, 0xb7], false),
+    (0x8fa3, &[0xd5, 0xb8], false),
+    (0x5d50, &[0xd5, 0xb9], false),
+    (0x64e5, &[0xd5, 0xba], false),
+    (0x652c, &[0xd5, 0xbb], false),
+    (0x6b16, &[0xd5, 0xbc], false),
+    (0x6feb, &[0xd5, 0xbd], false),
+    (0x7c43, &[0xd5, 0xbe], false),
+    (0x7e9c, &[0xd5, 0xbf], false),
+    (0x85cd, &[0xd5, 0xc0], false),
+    (0x8964, &[0xd5, 0xc1], false),
+    (0x89bd, &[0xd5, 0xc2], false),
+    (0x62c9, &[0xd5, 0xc3], false),
+    (0x81d8, &[0xd5, 0xc4], false),
+    (0x881f, &[0xd5, 0xc5], false),
+    (0x5eca, &[0xd5, 0xc6], false),
+    (0x6717, &[0xd5, 0xc7], false),
+    (0x6d6a, &[0xd5, 0xc8], false),
+    (0x72fc, &[0xd5, 0xc9], false),
+    (0x7405, &[0xd5, 0xca], false),
+    (0x746f, &[0xd5, 0xcb], false),
+    (0x8782, &[0xd5, 0xcc], false),
+    (0x90de, &[0xd5, 0xcd], false),
+    (0x4f86, &[0xd5, 0xce], false),
+    (0x5d0d, &[0xd5, 0xcf], false),
+    (0x5fa0, &[0xd5, 0xd0], false),
+    (0x840a, &[0xd5, 0xd1], false),
+    (0x51b7, &[0xd5, 0xd2], false),
+    (0x63a0, &[0xd5, 0xd3], false),
+    (0x7565, &[0xd5, 0xd4], false),
+    (0x4eae, &[0xd5, 0xd5], false),
+    (0x5006, &[0xd5, 0xd6], false),
+    (0x5169, &[0xd5, 0xd7], false),
+    (0x51c9, &[0xd5, 0xd8], false),
+    (0x6881, &[0xd5, 0xd9], false),
+    (0x6a11, &[0xd5, 0xda], false),
+    (0x7cae, &[0xd5, 0xdb], false),
+    (0x7cb1, &[0xd5, 0xdc], false),
+    (0x7ce7, &[0xd5, 0xdd], false),
+    (0x826f, &[0xd5, 0xde], false),
+    (0x8ad2, &[0xd5, 0xdf], false),
+    (0x8f1b, &[0xd5, 0xe0], false),
+    (0x91cf, &[0xd5, 0xe1], false),
+    (0x4fb6, &[0xd5, 0xe2], false),
+    (0x5137, &[0xd5, 0xe3], false),
+    (0x52f5, &[0xd5, 0xe4], false),
+    (0x5442, &[0xd5, 0xe5], false),
+    (0x5eec, &[0xd5, 0xe6], false),
+    (0x616e, &[0xd5, 0xe7], false),
+    (0x623e, &[0xd5, 0xe8], false),
+    (0x65c5, &[0xd5, 0xe9], false),
+    (0x6ada, &[0xd5, 0xea], false),
+    (0x6ffe, &[0xd5, 0xeb], false),
+    (0x792a, &[0xd5, 0xec], false),
+    (0x85dc, &[0xd5, 0xed], false),
+    (0x8823, &[0xd5, 0xee], false),
+    (0x95ad, &[0xd5, 0xef], false),
+    (0x9a62, &[0xd5, 0xf0], false),
+    (0x9a6a, &[0xd5, 0xf1], false),
+    (0x9e97, &[0xd5, 0xf2], false),
+    (0x9ece, &[0xd5, 0xf3], false),
+    (0x529b, &[0xd5, 0xf4], false),
+    (0x66c6, &[0xd5, 0xf5], false),
+    (0x6b77, &[0xd5, 0xf6], false),
+    (0x701d, &[0xd5, 0xf7], false),
+    (0x792b, &[0xd5, 0xf8], false),
+    (0x8f62, &[0xd5, 0xf9], false),
+    (0x9742, &[0xd5, 0xfa], false),
+    (0x6190, &[0xd5, 0xfb], false),
+    (0x6200, &[0xd5, 0xfc], false),
+    (0x6523, &[0xd5, 0xfd], false),
+    (0x6f23, &[0xd5, 0xfe], false),
+    (0x7149, &[0xd6, 0xa1], false),
+    (0x7489, &[0xd6, 0xa2], false),
+    (0x7df4, &[0xd6, 0xa3], false),
+    (0x806f, &[0xd6, 0xa4], false),
+    (0x84ee, &[0xd6, 0xa5], false),
+    (0x8f26, &[0xd6, 0xa6], false),
+    (0x9023, &[0xd6, 0xa7], false),
+    (0x934a, &[0xd6, 0xa8], false),
+    (0x51bd, &[0xd6, 0xa9], false),
+    (0x5217, &[0xd6, 0xaa], false),
+    (0x52a3, &[0xd6, 0xab], false),
+    (0x6d0c, &[0xd6, 0xac], false),
+    (0x70c8, &[0xd6, 0xad], false),
+    (0x88c2, &[0xd6, 0xae], false),
+    (0x5ec9, &[0xd6, 0xaf], false),
+    (0x6582, &[0xd6, 0xb0], false),
+    (0x6bae, &[0xd6, 0xb1], false),
+    (0x6fc2, &[0xd6, 0xb2], false),
+    (0x7c3e, &[0xd6, 0xb3], false),
+    (0x7375, &[0xd6, 0xb4], false),
+    (0x4ee4, &[0xd6, 0xb5], false),
+    (0x4f36, &[0xd6, 0xb6], false),
+    (0x56f9, &[0xd6, 0xb7], false),
+    (0xf95f, &[0xd6, 0xb8], false),
+    (0x5cba, &[0xd6, 0xb9], false),
+    (0x5dba, &[0xd6, 0xba], false),
+    (0x601c, &[0xd6, 0xbb], false),
+    (0x73b2, &[0xd6, 0xbc], false),
+    (0x7b2d, &[0xd6, 0xbd], false),
+    (0x7f9a, &[0xd6, 0xbe], false),
+    (0x7fce, &[0xd6, 0xbf], false),
+    (0x8046, &[0xd6, 0xc0], false),
+    (0x901e, &[0xd6, 0xc1], false),
+    (0x9234, &[0xd6, 0xc2], false),
+    (0x96f6, &[0xd6, 0xc3], false),
+    (0x9748, &[0xd6, 0xc4], false),
+    (0x9818, &[0xd6, 0xc5], false),
+    (0x9f61, &[0xd6, 0xc6], false),
+    (0x4f8b, &[0xd6, 0xc7], false),
+    (0x6fa7, &[0xd6, 0xc8], false),
+    (0x79ae, &[0xd6, 0xc9], false),
+    (0x91b4, &[0xd6, 0xca], false),
+    (0x96b7, &[0xd6, 0xcb], false),
+    (0x52de, &[0xd6, 0xcc], false),
+    (0xf960, &[0xd6, 0xcd], false),
+    (0x6488, &[0xd6, 0xce], false),
+    (0x64c4, &[0xd6, 0xcf], false),
+    (0x6ad3, &[0xd6, 0xd0], false),
+    (0x6f5e, &[0xd6, 0xd1], false),
+    (0x7018, &[0xd6, 0xd2], false),
+    (0x7210, &[0xd6, 0xd3], false),
+    (0x76e7, &[0xd6, 0xd4], false),
+    (0x8001, &[0xd6, 0xd5], false),
+    (0x8606, &[0xd6, 0xd6], false),
+    (0x865c, &[0xd6, 0xd7], false),
+    (0x8def, &[0xd6, 0xd8], false),
+    (0x8f05, &[0xd6, 0xd9], false),
+    (0x9732, &[0xd6, 0xda], false),
+    (0x9b6f, &[0xd6, 0xdb], false),
+    (0x9dfa, &[0xd6, 0xdc], false),
+    (0x9e75, &[0xd6, 0xdd], false),
+    (0x788c, &[0xd6, 0xde], false),
+    (0x797f, &[0xd6, 0xdf], false),
+    (0x7da0, &[0xd6, 0xe0], false),
+    (0x83c9, &[0xd6, 0xe1], false),
+    (0x9304, &[0xd6, 0xe2], false),
+    (0x9e7f, &[0xd6, 0xe3], false),
+    (0x9e93, &[0xd6, 0xe4], false),
+    (0x8ad6, &[0xd6, 0xe5], false),
+    (0x58df, &[0xd6, 0xe6], false),
+    (0x5f04, &[0xd6, 0xe7], false),
+    (0x6727, &[0xd6, 0xe8], false),
+    (0x7027, &[0xd6, 0xe9], false),
+    (0x74cf, &[0xd6, 0xea], false),
+    (0x7c60, &[0xd6, 0xeb], false),
+    (0x807e, &[0xd6, 0xec], false),
+    (0x5121, &[0xd6, 0xed], false),
+    (0x7028, &[0xd6, 0xee], false),
+    (0x7262, &[0xd6, 0xef], false),
+    (0x78ca, &[0xd6, 0xf0], false),
+    (0x8cc2, &[0xd6, 0xf1], false),
+    (0x8cda, &[0xd6, 0xf2], false),
+    (0x8cf4, &[0xd6, 0xf3], false),
+    (0x96f7, &[0xd6, 0xf4], false),
+    (0x4e86, &[0xd6, 0xf5], false),
+    (0x50da, &[0xd6, 0xf6], false),
+    (0x5bee, &[0xd6, 0xf7], false),
+    (0x5ed6, &[0xd6, 0xf8], false),
+    (0x6599, &[0xd6, 0xf9], false),
+    (0x71ce, &[0xd6, 0xfa], false),
+    (0x7642, &[0xd6, 0xfb], false),
+    (0x77ad, &[0xd6, 0xfc], false),
+    (0x804a, &[0xd6, 0xfd], false),
+    (0x84fc, &[0xd6, 0xfe], false),
+    (0x907c, &[0xd7, 0xa1], false),
+    (0x9b27, &[0xd7, 0xa2], false),
+    (0x9f8d, &[0xd7, 0xa3], false),
+    (0x58d8, &[0xd7, 0xa4], false),
+    (0x5a41, &[0xd7, 0xa5], false),
+    (0x5c62, &[0xd7, 0xa6], false),
+    (0x6a13, &[0xd7, 0xa7], false),
+    (0x6dda, &[0xd7, 0xa8], false),
+    (0x6f0f, &[0xd7, 0xa9], false),
+    (0x763b, &[0xd7, 0xaa], false),
+    (0x7d2f, &[0xd7, 0xab], false),
+    (0x7e37, &[0xd7, 0xac], false),
+    (0x851e, &[0xd7, 0xad], false),
+    (0x8938, &[0xd7, 0xae], false),
+    (0x93e4, &[0xd7, 0xaf], false),
+    (0x964b, &[0xd7, 0xb0], false),
+    (0x5289, &[0xd7, 0xb1], false),
+    (0x65d2, &[0xd7, 0xb2], false),
+    (0x67f3, &[0xd7, 0xb3], false),
+    (0x69b4, &[0xd7, 0xb4], false),
+    (0x6d41, &[0xd7, 0xb5], false),
+    (0x6e9c, &[0xd7, 0xb6], false),
+    (0x700f, &[0xd7, 0xb7], false),
+    (0x7409, &[0xd7, 0xb8], false),
+    (0x7460, &[0xd7, 0xb9], false),
+    (0x7559, &[0xd7, 0xba], false),
+    (0x7624, &[0xd7, 0xbb], false),
+    (0x786b, &[0xd7, 0xbc], false),
+    (0x8b2c, &[0xd7, 0xbd], false),
+    (0x985e, &[0xd7, 0xbe], false),
+    (0x516d, &[0xd7, 0xbf], false),
+    (0x622e, &[0xd7, 0xc0], false),
+    (0x9678, &[0xd7, 0xc1], false),
+    (0x4f96, &[0xd7, 0xc2], false),
+    (0x502b, &[0xd7, 0xc3], false),
+    (0x5d19, &[0xd7, 0xc4], false),
+    (0x6dea, &[0xd7, 0xc5], false),
+    (0x7db8, &[0xd7, 0xc6], false),
+    (0x8f2a, &[0xd7, 0xc7], false),
+    (0x5f8b, &[0xd7, 0xc8], false),
+    (0x6144, &[0xd7, 0xc9], false),
+    (0x6817, &[0xd7, 0xca], false),
+    (0xf961, &[0xd7, 0xcb], false),
+    (0x9686, &[0xd7, 0xcc], false),
+    (0x52d2, &[0xd7, 0xcd], false),
+    (0x808b, &[0xd7, 0xce], false),
+    (0x51dc, &[0xd7, 0xcf], false),
+    (0x51cc, &[0xd7, 0xd0], false),
+    (0x695e, &[0xd7, 0xd1], false),
+    (0x7a1c, &[0xd7, 0xd2], false),
+    (0x7dbe, &[0xd7, 0xd3], false),
+    (0x83f1, &[0xd7, 0xd4], false),
+    (0x9675, &[0xd7, 0xd5], false),
+    (0x4fda, &[0xd7, 0xd6], false),
+    (0x5229, &[0xd7, 0xd7], false),
+    (0x5398, &[0xd7, 0xd8], false),
+    (0x540f, &[0xd7, 0xd9], false),
+    (0x550e, &[0xd7, 0xda], false),
+    (0x5c65, &[0xd7, 0xdb], false),
+    (0x60a7, &[0xd7, 0xdc], false),
+    (0x674e, &[0xd7, 0xdd], false),
+    (0x68a8, &[0xd7, 0xde], false),
+    (0x6d6c, &[0xd7, 0xdf], false),
+    (0x7281, &[0xd7, 0xe0], false),
+    (0x72f8, &[0xd7, 0xe1], false),
+    (0x7406, &[0xd7, 0xe2], false),
+    (0x7483, &[0xd7, 0xe3], false),
+    (0xf962, &[0xd7, 0xe4], false),
+    (0x75e2, &[0xd7, 0xe5], false),
+    (0x7c6c, &[0xd7, 0xe6], false),
+    (0x7f79, &[0xd7, 0xe7], false),
+    (0x7fb8, &[0xd7, 0xe8], false),
+    (0x8389, &[0xd7, 0xe9], false),
+    (0x88cf, &[0xd7, 0xea], false),
+    (0x88e1, &[0xd7, 0xeb], false),
+    (0x91cc, &[0xd7, 0xec], false),
+    (0x91d0, &[0xd7, 0xed], false),
+    (0x96e2, &[0xd7, 0xee], false),
+    (0x9bc9, &[0xd7, 0xef], false),
+    (0x541d, &[0xd7, 0xf0], false),
+    (0x6f7e, &[0xd7, 0xf1], false),
+    (0x71d0, &[0xd7, 0xf2], false),
+    (0x7498, &[0xd7, 0xf3], false),
+    (0x85fa, &[0xd7, 0xf4], false),
+    (0x8eaa, &[0xd7, 0xf5], false),
+    (0x96a3, &[0xd7, 0xf6], false),
+    (0x9c57, &[0xd7, 0xf7], false),
+    (0x9e9f, &[0xd7, 0xf8], false),
+    (0x6797, &[0xd7, 0xf9], false),
+    (0x6dcb, &[0xd7, 0xfa], false),
+    (0x7433, &[0xd7, 0xfb], false),
+    (0x81e8, &[0xd7, 0xfc], false),
+    (0x9716, &[0xd7, 0xfd], false),
+    (0x782c, &[0xd7, 0xfe], false),
+    (0x7acb, &[0xd8, 0xa1], false),
+    (0x7b20, &[0xd8, 0xa2], false),
+    (0x7c92, &[0xd8, 0xa3], false),
+    (0x6469, &[0xd8, 0xa4], false),
+    (0x746a, &[0xd8, 0xa5], false),
+    (0x75f2, &[0xd8, 0xa6], false),
+    (0x78bc, &[0xd8, 0xa7], false),
+    (0x78e8, &[0xd8, 0xa8], false),
+    (0x99ac, &[0xd8, 0xa9], false),
+    (0x9b54, &[0xd8, 0xaa], false),
+    (0x9ebb, &[0xd8, 0xab], false),
+    (0x5bde, &[0xd8, 0xac], false),
+    (0x5e55, &[0xd8, 0xad], false),
+    (0x6f20, &[0xd8, 0xae], false),
+    (0x819c, &[0xd8, 0xaf], false),
+    (0x83ab, &[0xd8, 0xb0], false),
+    (0x9088, &[0xd8, 0xb1], false),
+    (0x4e07, &[0xd8, 0xb2], false),
+    (0x534d, &[0xd8, 0xb3], false),
+    (0x5a29, &[0xd8, 0xb4], false),
+    (0x5dd2, &[0xd8, 0xb5], false),
+    (0x5f4e, &[0xd8, 0xb6], false),
+    (0x6162, &[0xd8, 0xb7], false),
+    (0x633d, &[0xd8, 0xb8], false),
+    (0x6669, &[0xd8, 0xb9], false),
+    (0x66fc, &[0xd8, 0xba], false),
+    (0x6eff, &[0xd8, 0xbb], false),
+    (0x6f2b, &[0xd8, 0xbc], false),
+    (0x7063, &[0xd8, 0xbd], false),
+    (0x779e, &[0xd8, 0xbe], false),
+    (0x842c, &[0xd8, 0xbf], false),
+    (0x8513, &[0xd8, 0xc0], false),
+    (0x883b, &[0xd8, 0xc1], false),
+    (0x8f13, &[0xd8, 0xc2], false),
+    (0x9945, &[0xd8, 0xc3], false),
+    (0x9c3b, &[0xd8, 0xc4], false),
+    (0x551c, &[0xd8, 0xc5], false),
+    (0x62b9, &[0xd8, 0xc6], false),
+    (0x672b, &[0xd8, 0xc7], false),
+    (0x6cab, &[0xd8, 0xc8], false),
+    (0x8309, &[0xd8, 0xc9], false),
+    (0x896a, &[0xd8, 0xca], false),
+    (0x977a, &[0xd8, 0xcb], false),
+    (0x4ea1, &[0xd8, 0xcc], false),
+    (0x5984, &[0xd8, 0xcd], false),
+    (0x5fd8, &[0xd8, 0xce], false),
+    (0x5fd9, &[0xd8, 0xcf], false),
+    (0x671b, &[0xd8, 0xd0], false),
+    (0x7db2, &[0xd8, 0xd1], false),
+    (0x7f54, &[0xd8, 0xd2], false),
+    (0x8292, &[0xd8, 0xd3], false),
+    (0x832b, &[0xd8, 0xd4], false),
+    (0x83bd, &[0xd8, 0xd5], false),
+    (0x8f1e, &[0xd8, 0xd6], false),
+    (0x9099, &[0xd8, 0xd7], false),
+    (0x57cb, &[0xd8, 0xd8], false),
+    (0x59b9, &[0xd8, 0xd9], false),
+    (0x5a92, &[0xd8, 0xda], false),
+    (0x5bd0, &[0xd8, 0xdb], false),
+    (0x6627, &[0xd8, 0xdc], false),
+    (0x679a, &[0xd8, 0xdd], false),
+    (0x6885, &[0xd8, 0xde], false),
+    (0x6bcf, &[0xd8, 0xdf], false),
+    (0x7164, &[0xd8, 0xe0], false),
+    (0x7f75, &[0xd8, 0xe1], false),
+    (0x8cb7, &[0xd8, 0xe2], false),
+    (0x8ce3, &[0xd8, 0xe3], false),
+    (0x9081, &[0xd8, 0xe4], false),
+    (0x9b45, &[0xd8, 0xe5], false),
+    (0x8108, &[0xd8, 0xe6], false),
+    (0x8c8a, &[0xd8, 0xe7], false),
+    (0x964c, &[0xd8, 0xe8], false),
+    (0x9a40, &[0xd8, 0xe9], false),
+    (0x9ea5, &[0xd8, 0xea], false),
+    (0x5b5f, &[0xd8, 0xeb], false),
+    (0x6c13, &[0xd8, 0xec], false),
+    (0x731b, &[0xd8, 0xed], false),
+    (0x76f2, &[0xd8, 0xee], false),
+    (0x76df, &[0xd8, 0xef], false),
+    (0x840c, &[0xd8, 0xf0], false),
+    (0x51aa, &[0xd8, 0xf1], false),
+    (0x8993, &[0xd8, 0xf2], false),
+    (0x514d, &[0xd8, 0xf3], false),
+    (0x5195, &[0xd8, 0xf4], false),
+    (0x52c9, &[0xd8, 0xf5], false),
+    (0x68c9, &[0xd8, 0xf6], false),
+    (0x6c94, &[0xd8, 0xf7], false),
+    (0x7704, &[0xd8, 0xf8], false),
+    (0x7720, &[0xd8, 0xf9], false),
+    (0x7dbf, &[0xd8, 0xfa], false),
+    (0x7dec, &[0xd8, 0xfb], false),
+    (0x9762, &[0xd8, 0xfc], false),
+    (0x9eb5, &[0xd8, 0xfd], false),
+    (0x6ec5, &[0xd8, 0xfe], false),
+    (0x8511, &[0xd9, 0xa1], false),
+    (0x51a5, &[0xd9, 0xa2], false),
+    (0x540d, &[0xd9, 0xa3], false),
+    (0x547d, &[0xd9, 0xa4], false),
+    (0x660e, &[0xd9, 0xa5], false),
+    (0x669d, &[0xd9, 0xa6], false),
+    (0x6927, &[0xd9, 0xa7], false),
+    (0x6e9f, &[0xd9, 0xa8], false),
+    (0x76bf, &[0xd9, 0xa9], false),
+    (0x7791, &[0xd9, 0xaa], false),
+    (0x8317, &[0xd9, 0xab], false),
+    (0x84c2, &[0xd9, 0xac], false),
+    (0x879f, &[0xd9, 0xad], false),
+    (0x9169, &[0xd9, 0xae], false),
+    (0x9298, &[0xd9, 0xaf], false),
+    (0x9cf4, &[0xd9, 0xb0], false),
+    (0x8882, &[0xd9, 0xb1], false),
+    (0x4fae, &[0xd9, 0xb2], false),
+    (0x5192, &[0xd9, 0xb3], false),
+    (0x52df, &[0xd9, 0xb4], false),
+    (0x59c6, &[0xd9, 0xb5], false),
+    (0x5e3d, &[0xd9, 0xb6], false),
+    (0x6155, &[0xd9, 0xb7], false),
+    (0x6478, &[0xd9, 0xb8], false),
+    (0x6479, &[0xd9, 0xb9], false),
+    (0x66ae, &[0xd9, 0xba], false),
+    (0x67d0, &[0xd9, 0xbb], false),
+    (0x6a21, &[0xd9, 0xbc], false),
+    (0x6bcd, &[0xd9, 0xbd], false),
+    (0x6bdb, &[0xd9, 0xbe], false),
+    (0x725f, &[0xd9, 0xbf], false),
+    (0x7261, &[0xd9, 0xc0], false),
+    (0x7441, &[0xd9, 0xc1], false),
+    (0x7738, &[0xd9, 0xc2], false),
+    (0x77db, &[0xd9, 0xc3], false),
+    (0x8017, &[0xd9, 0xc4], false),
+    (0x82bc, &[0xd9, 0xc5], false),
+    (0x8305, &[0xd9, 0xc6], false),
+    (0x8b00, &[0xd9, 0xc7], false),
+    (0x8b28, &[0xd9, 0xc8], false),
+    (0x8c8c, &[0xd9, 0xc9], false),
+    (0x6728, &[0xd9, 0xca], false),
+    (0x6c90, &[0xd9, 0xcb], false),
+    (0x7267, &[0xd9, 0xcc], false),
+    (0x76ee, &[0xd9, 0xcd], false),
+    (0x7766, &[0xd9, 0xce], false),
+    (0x7a46, &[0xd9, 0xcf], false),
+    (0x9da9, &[0xd9, 0xd0], false),
+    (0x6b7f, &[0xd9, 0xd1], false),
+    (0x6c92, &[0xd9, 0xd2], false),
+    (0x5922, &[0xd9, 0xd3], false),
+    (0x6726, &[0xd9, 0xd4], false),
+    (0x8499, &[0xd9, 0xd5], false),
+    (0x536f, &[0xd9, 0xd6], false),
+    (0x5893, &[0xd9, 0xd7], false),
+    (0x5999, &[0xd9, 0xd8], false),
+    (0x5edf, &[0xd9, 0xd9], false),
+    (0x63cf, &[0xd9, 0xda], false),
+    (0x6634, &[0xd9, 0xdb], false),
+    (0x6773, &[0xd9, 0xdc], false),
+    (0x6e3a, &[0xd9, 0xdd], false),
+    (0x732b, &[0xd9, 0xde], false),
+    (0x7ad7, &[0xd9, 0xdf], false),
+    (0x82d7, &[0xd9, 0xe0], false),
+    (0x9328, &[0xd9, 0xe1], false),
+    (0x52d9, &[0xd9, 0xe2], false),
+    (0x5deb, &[0xd9, 0xe3], false),
+    (0x61ae, &[0xd9, 0xe4], false),
+    (0x61cb, &[0xd9, 0xe5], false),
+    (0x620a, &[0xd9, 0xe6], false),
+    (0x62c7, &[0xd9, 0xe7], false),
+    (0x64ab, &[0xd9, 0xe8], false),
+    (0x65e0, &[0xd9, 0xe9], false),
+    (0x6959, &[0xd9, 0xea], false),
+    (0x6b66, &[0xd9, 0xeb], false),
+    (0x6bcb, &[0xd9, 0xec], false),
+    (0x7121, &[0xd9, 0xed], false),
+    (0x73f7, &[0xd9, 0xee], false),
+    (0x755d, &[0xd9, 0xef], false),
+    (0x7e46, &[0xd9, 0xf0], false),
+    (0x821e, &[0xd9, 0xf1], false),
+    (0x8302, &[0xd9, 0xf2], false),
+    (0x856a, &[0xd9, 0xf3], false),
+    (0x8aa3, &[0xd9, 0xf4], false),
+    (0x8cbf, &[0xd9, 0xf5], false),
+    (0x9727, &[0xd9, 0xf6], false),
+    (0x9d61, &[0xd9, 0xf7], false),
+    (0x58a8, &[0xd9, 0xf8], false),
+    (0x9ed8, &[0xd9, 0xf9], false),
+    (0x5011, &[0xd9, 0xfa], false),
+    (0x520e, &[0xd9, 0xfb], false),
+    (0x543b, &[0xd9, 0xfc], false),
+    (0x554f, &[0xd9, 0xfd], false),
+    (0x6587, &[0xd9, 0xfe], false),
+    (0x6c76, &[0xda, 0xa1], false),
+    (0x7d0a, &[0xda, 0xa2], false),
+    (0x7d0b, &[0xda, 0xa3], false),
+    (0x805e, &[0xda, 0xa4], false),
+    (0x868a, &[0xda, 0xa5], false),
+    (0x9580, &[0xda, 0xa6], false),
+    (0x96ef, &[0xda, 0xa7], false),
+    (0x52ff, &[0xda, 0xa8], false),
+    (0x6c95, &[0xda, 0xa9], false),
+    (0x7269, &[0xda, 0xaa], false),
+    (0x5473, &[0xda, 0xab], false),
+    (0x5a9a, &[0xda, 0xac], false),
+    (0x5c3e, &[0xda, 0xad], false),
+    (0x5d4b, &[0xda, 0xae], false),
+    (0x5f4c, &[0xda, 0xaf], false),
+    (0x5fae, &[0xda, 0xb0], false),
+    (0x672a, &[0xda, 0xb1], false),
+    (0x68b6, &[0xda, 0xb2], false),
+    (0x6963, &[0xda, 0xb3], false),
+    (0x6e3c, &[0xda, 0xb4], false),
+    (0x6e44, &[0xda, 0xb5], false),
+    (0x7709, &[0xda, 0xb6], false),
+    (0x7c73, &[0xda, 0xb7], false),
+    (0x7f8e, &[0xda, 0xb8], false),
+    (0x8587, &[0xda, 0xb9], false),
+    (0x8b0e, &[0xda, 0xba], false),
+    (0x8ff7, &[0xda, 0xbb], false),
+    (0x9761, &[0xda, 0xbc], false),
+    (0x9ef4, &[0xda, 0xbd], false),
+    (0x5cb7, &[0xda, 0xbe], false),
+    (0x60b6, &[0xda, 0xbf], false),
+    (0x610d, &[0xda, 0xc0], false),
+    (0x61ab, &[0xda, 0xc1], false),
+    (0x654f, &[0xda, 0xc2], false),
+    (0x65fb, &[0xda, 0xc3], false),
+    (0x65fc, &[0xda, 0xc4], false),
+    (0x6c11, &[0xda, 0xc5], false),
+    (0x6cef, &[0xda, 0xc6], false),
+    (0x739f, &[0xda, 0xc7], false),
+    (0x73c9, &[0xda, 0xc8], false),
+    (0x7de1, &[0xda, 0xc9], false),
+    (0x9594, &[0xda, 0xca], false),
+    (0x5bc6, &[0xda, 0xcb], false),
+    (0x871c, &[0xda, 0xcc], false),
+    (0x8b10, &[0xda, 0xcd], false),
+    (0x525d, &[0xda, 0xce], false),
+    (0x535a, &[0xda, 0xcf], false),
+    (0x62cd, &[0xda, 0xd0], false),
+    (0x640f, &[0xda, 0xd1], false),
+    (0x64b2, &[0xda, 0xd2], false),
+    (0x6734, &[0xda, 0xd3], false),
+    (0x6a38, &[0xda, 0xd4], false),
+    (0x6cca, &[0xda, 0xd5], false),
+    (0x73c0, &[0xda, 0xd6], false),
+    (0x749e, &[0xda, 0xd7], false),
+    (0x7b94, &[0xda, 0xd8], false),
+    (0x7c95, &[0xda, 0xd9], false),
+    (0x7e1b, &[0xda, 0xda], false),
+    (0x818a, &[0xda, 0xdb], false),
+    (0x8236, &[0xda, 0xdc], false),
+    (0x8584, &[0xda, 0xdd], false),
+    (0x8feb, &[0xda, 0xde], false),
+    (0x96f9, &[0xda, 0xdf], false),
+    (0x99c1, &[0xda, 0xe0], false),
+    (0x4f34, &[0xda, 0xe1], false),
+    (0x534a, &[0xda, 0xe2], false),
+    (0x53cd, &[0xda, 0xe3], false),
+    (0x53db, &[0xda, 0xe4], false),
+    (0x62cc, &[0xda, 0xe5], false),
+    (0x642c, &[0xda, 0xe6], false),
+    (0x6500, &[0xda, 0xe7], false),
+    (0x6591, &[0xda, 0xe8], false),
+    (0x69c3, &[0xda, 0xe9], false),
+    (0x6cee, &[0xda, 0xea], false),
+    (0x6f58, &[0xda, 0xeb], false),
+    (0x73ed, &[0xda, 0xec], false),
+    (0x7554, &[0xda, 0xed], false),
+    (0x7622, &[0xda, 0xee], false),
+    (0x76e4, &[0xda, 0xef], false),
+    (0x76fc, &[0xda, 0xf0], false),
+    (0x78d0, &[0xda, 0xf1], false),
+    (0x78fb, &[0xda, 0xf2], false),
+    (0x792c, &[0xda, 0xf3], false),
+    (0x7d46, &[0xda, 0xf4], false),
+    (0x822c, &[0xda, 0xf5], false),
+    (0x87e0, &[0xda, 0xf6], false),
+    (0x8fd4, &[0xda, 0xf7], false),
+    (0x9812, &[0xda, 0xf8], false),
+    (0x98ef, &[0xda, 0xf9], false),
+    (0x52c3, &[0xda, 0xfa], false),
+    (0x62d4, &[0xda, 0xfb], false),
+    (0x64a5, &[0xda, 0xfc], false),
+    (0x6e24, &[0xda, 0xfd], false),
+    (0x6f51, &[0xda, 0xfe], false),
+    (0x767c, &[0xdb, 0xa1], false),
+    (0x8dcb, &[0xdb, 0xa2], false),
+    (0x91b1, &[0xdb, 0xa3], false),
+    (0x9262, &[0xdb, 0xa4], false),
+    (0x9aee, &[0xdb, 0xa5], false),
+    (0x9b43, &[0xdb, 0xa6], false),
+    (0x5023, &[0xdb, 0xa7], false),
+    (0x508d, &[0xdb, 0xa8], false),
+    (0x574a, &[0xdb, 0xa9], false),
+    (0x59a8, &[0xdb, 0xaa], false),
+    (0x5c28, &[0xdb, 0xab], false),
+    (0x5e47, &[0xdb, 0xac], false),
+    (0x5f77, &[0xdb, 0xad], false),
+    (0x623f, &[0xdb, 0xae], false),
+    (0x653e, &[0xdb, 0xaf], false),
+    (0x65b9, &[0xdb, 0xb0], false),
+    (0x65c1, &[0xdb, 0xb1], false),
+    (0x6609, &[0xdb, 0xb2], false),
+    (0x678b, &[0xdb, 0xb3], false),
+    (0x699c, &[0xdb, 0xb4], false),
+    (0x6ec2, &[0xdb, 0xb5], false),
+    (0x78c5, &[0xdb, 0xb6], false),
+    (0x7d21, &[0xdb, 0xb7], false),
+    (0x80aa, &[0xdb, 0xb8], false),
+    (0x8180, &[0xdb, 0xb9], false),
+    (0x822b, &[0xdb, 0xba], false),
+    (0x82b3, &[0xdb, 0xbb], false),
+    (0x84a1, &[0xdb, 0xbc], false),
+    (0x868c, &[0xdb, 0xbd], false),
+    (0x8a2a, &[0xdb, 0xbe], false),
+    (0x8b17, &[0xdb, 0xbf], false),
+    (0x90a6, &[0xdb, 0xc0], false),
+    (0x9632, &[0xdb, 0xc1], false),
+    (0x9f90, &[0xdb, 0xc2], false),
+    (0x500d, &[0xdb, 0xc3], false),
+    (0x4ff3, &[0xdb, 0xc4], false),
+    (0xf963, &[0xdb, 0xc5], false),
+    (0x57f9, &[0xdb, 0xc6], false),
+    (0x5f98, &[0xdb, 0xc7], false),
+    (0x62dc, &[0xdb, 0xc8], false),
+    (0x6392, &[0xdb, 0xc9], false),
+    (0x676f, &[0xdb, 0xca], false),
+    (0x6e43, &[0xdb, 0xcb], false),
+    (0x7119, &[0xdb, 0xcc], false),
+    (0x76c3, &[0xdb, 0xcd], false),
+    (0x80cc, &[0xdb, 0xce], false),
+    (0x80da, &[0xdb, 0xcf], false),
+    (0x88f4, &[0xdb, 0xd0], false),
+    (0x88f5, &[0xdb, 0xd1], false),
+    (0x8919, &[0xdb, 0xd2], false),
+    (0x8ce0, &[0xdb, 0xd3], false),
+    (0x8f29, &[0xdb, 0xd4], false),
+    (0x914d, &[0xdb, 0xd5], false),
+    (0x966a, &[0xdb, 0xd6], false),
+    (0x4f2f, &[0xdb, 0xd7], false),
+    (0x4f70, &[0xdb, 0xd8], false),
+    (0x5e1b, &[0xdb, 0xd9], false),
+    (0x67cf, &[0xdb, 0xda], false),
+    (0x6822, &[0xdb, 0xdb], false),
+    (0x767d, &[0xdb, 0xdc], false),
+    (0x767e, &[0xdb, 0xdd], false),
+    (0x9b44, &[0xdb, 0xde], false),
+    (0x5e61, &[0xdb, 0xdf], false),
+    (0x6a0a, &[0xdb, 0xe0], false),
+    (0x7169, &[0xdb, 0xe1], false),
+    (0x71d4, &[0xdb, 0xe2], false),
+    (0x756a, &[0xdb, 0xe3], false),
+    (0xf964, &[0xdb, 0xe4], false),
+    (0x7e41, &[0xdb, 0xe5], false),
+    (0x8543, &[0xdb, 0xe6], false),
+    (0x85e9, &[0xdb, 0xe7], false),
+    (0x98dc, &[0xdb, 0xe8], false),
+    (0x4f10, &[0xdb, 0xe9], false),
+    (0x7b4f, &[0xdb, 0xea], false),
+    (0x7f70, &[0xdb, 0xeb], false),
+    (0x95a5, &[0xdb, 0xec], false),
+    (0x51e1, &[0xdb, 0xed], false),
+    (0x5e06, &[0xdb, 0xee], false),
+    (0x68b5, &[0xdb, 0xef], false),
+    (0x6c3e, &[0xdb, 0xf0], false),
+    (0x6c4e, &[0xdb, 0xf1], false),
+    (0x6cdb, &[0xdb, 0xf2], false),
+    (0x72af, &[0xdb, 0xf3], false),
+    (0x7bc4, &[0xdb, 0xf4], false),
+    (0x8303, &[0xdb, 0xf5], false),
+    (0x6cd5, &[0xdb, 0xf6], false),
+    (0x743a, &[0xdb, 0xf7], false),
+    (0x50fb, &[0xdb, 0xf8], false),
+    (0x5288, &[0xdb, 0xf9], false),
+    (0x58c1, &[0xdb, 0xfa], false),
+    (0x64d8, &[0xdb, 0xfb], false),
+    (0x6a97, &[0xdb, 0xfc], false),
+    (0x74a7, &[0xdb, 0xfd], false),
+    (0x7656, &[0xdb, 0xfe], false),
+    (0x78a7, &[0xdc, 0xa1], false),
+    (0x8617, &[0xdc, 0xa2], false),
+    (0x95e2, &[0xdc, 0xa3], false),
+    (0x9739, &[0xdc, 0xa4], false),
+    (0xf965, &[0xdc, 0xa5], false),
+    (0x535e, &[0xdc, 0xa6], false),
+    (0x5f01, &[0xdc, 0xa7], false),
+    (0x8b8a, &[0xdc, 0xa8], false),
+    (0x8fa8, &[0xdc, 0xa9], false),
+    (0x8faf, &[0xdc, 0xaa], false),
+    (0x908a, &[0xdc, 0xab], false),
+    (0x5225, &[0xdc, 0xac], false),
+    (0x77a5, &[0xdc, 0xad], false),
+    (0x9c49, &[0xdc, 0xae], false),
+    (0x9f08, &[0xdc, 0xaf], false),
+    (0x4e19, &[0xdc, 0xb0], false),
+    (0x5002, &[0xdc, 0xb1], false),
+    (0x5175, &[0xdc, 0xb2], false),
+    (0x5c5b, &[0xdc, 0xb3], false),
+    (0x5e77, &[0xdc, 0xb4], false),
+    (0x661e, &[0xdc, 0xb5], false),
+    (0x663a, &[0xdc, 0xb6], false),
+    (0x67c4, &[0xdc, 0xb7], false),
+    (0x68c5, &[0xdc, 0xb8], false),
+    (0x70b3, &[0xdc, 0xb9], false),
+    (0x7501, &[0xdc, 0xba], false),
+    (0x75c5, &[0xdc, 0xbb], false),
+    (0x79c9, &[0xdc, 0xbc], false),
+    (0x7add, &[0xdc, 0xbd], false),
+    (0x8f27, &[0xdc, 0xbe], false),
+    (0x9920, &[0xdc, 0xbf], false),
+    (0x9a08, &[0xdc, 0xc0], false),
+    (0x4fdd, &[0xdc, 0xc1], false),
+    (0x5821, &[0xdc, 0xc2], false),
+    (0x5831, &[0xdc, 0xc3], false),
+    (0x5bf6, &[0xdc, 0xc4], false),
+    (0x666e, &[0xdc, 0xc5], false),
+    (0x6b65, &[0xdc, 0xc6], false),
+    (0x6d11, &[0xdc, 0xc7], false),
+    (0x6e7a, &[0xdc, 0xc8], false),
+    (0x6f7d, &[0xdc, 0xc9], false),
+    (0x73e4, &[0xdc, 0xca], false),
+    (0x752b, &[0xdc, 0xcb], false),
+    (0x83e9, &[0xdc, 0xcc], false),
+    (0x88dc, &[0xdc, 0xcd], false),
+    (0x8913, &[0xdc, 0xce], false),
+    (0x8b5c, &[0xdc, 0xcf], false),
+    (0x8f14, &[0xdc, 0xd0], false),
+    (0x4f0f, &[0xdc, 0xd1], false),
+    (0x50d5, &[0xdc, 0xd2], false),
+    (0x5310, &[0xdc, 0xd3], false),
+    (0x535c, &[0xdc, 0xd4], false),
+    (0x5b93, &[0xdc, 0xd5], false),
+    (0x5fa9, &[0xdc, 0xd6], false),
+    (0x670d, &[0xdc, 0xd7], false),
+    (0x798f, &[0xdc, 0xd8], false),
+    (0x8179, &[0xdc, 0xd9], false),
+    (0x832f, &[0xdc, 0xda], false),
+    (0x8514, &[0xdc, 0xdb], false),
+    (0x8907, &[0xdc, 0xdc], false),
+    (0x8986, &[0xdc, 0xdd], false),
+    (0x8f39, &[0xdc, 0xde], false),
+    (0x8f3b, &[0xdc, 0xdf], false),
+    (0x99a5, &[0xdc, 0xe0], false),
+    (0x9c12, &[0xdc, 0xe1], false),
+    (0x672c, &[0xdc, 0xe2], false),
+    (0x4e76, &[0xdc, 0xe3], false),
+    (0x4ff8, &[0xdc, 0xe4], false),
+    (0x5949, &[0xdc, 0xe5], false),
+    (0x5c01, &[0xdc, 0xe6], false),
+    (0x5cef, &[0xdc, 0xe7], false),
+    (0x5cf0, &[0xdc, 0xe8], false),
+    (0x6367, &[0xdc, 0xe9], false),
+    (0x68d2, &[0xdc, 0xea], false),
+    (0x70fd, &[0xdc, 0xeb], false),
+    (0x71a2, &[0xdc, 0xec], false),
+    (0x742b, &[0xdc, 0xed], false),
+    (0x7e2b, &[0xdc, 0xee], false),
+    (0x84ec, &[0xdc, 0xef], false),
+    (0x8702, &[0xdc, 0xf0], false),
+    (0x9022, &[0xdc, 0xf1], false),
+    (0x92d2, &[0xdc, 0xf2], false),
+    (0x9cf3, &[0xdc, 0xf3], false),
+    (0x4e0d, &[0xdc, 0xf4], false),
+    (0x4ed8, &[0xdc, 0xf5], false),
+    (0x4fef, &[0xdc, 0xf6], false),
+    (0x5085, &[0xdc, 0xf7], false),
+    (0x5256, &[0xdc, 0xf8], false),
+    (0x526f, &[0xdc, 0xf9], false),
+    (0x5426, &[0xdc, 0xfa], false),
+    (0x5490, &[0xdc, 0xfb], false),
+    (0x57e0, &[0xdc, 0xfc], false),
+    (0x592b, &[0xdc, 0xfd], false),
+    (0x5a66, &[0xdc, 0xfe], false),
+    (0x5b5a, &[0xdd, 0xa1], false),
+    (0x5b75, &[0xdd, 0xa2], false),
+    (0x5bcc, &[0xdd, 0xa3], false),
+    (0x5e9c, &[0xdd, 0xa4], false),
+    (0xf966, &[0xdd, 0xa5], false),
+    (0x6276, &[0xdd, 0xa6], false),
+    (0x6577, &[0xdd, 0xa7], false),
+    (0x65a7, &[0xdd, 0xa8], false),
+    (0x6d6e, &[0xdd, 0xa9], false),
+    (0x6ea5, &[0xdd, 0xaa], false),
+    (0x7236, &[0xdd, 0xab], false),
+    (0x7b26, &[0xdd, 0xac], false),
+    (0x7c3f, &[0xdd, 0xad], false),
+    (0x7f36, &[0xdd, 0xae], false),
+    (0x8150, &[0xdd, 0xaf], false),
+    (0x8151, &[0xdd, 0xb0], false),
+    (0x819a, &[0xdd, 0xb1], false),
+    (0x8240, &[0xdd, 0xb2], false),
+    (0x8299, &[0xdd, 0xb3], false),
+    (0x83a9, &[0xdd, 0xb4], false),
+    (0x8a03, &[0xdd, 0xb5], false),
+    (0x8ca0, &[0xdd, 0xb6], false),
+    (0x8ce6, &[0xdd, 0xb7], false),
+    (0x8cfb, &[0xdd, 0xb8], false),
+    (0x8d74, &[0xdd, 0xb9], false),
+    (0x8dba, &[0xdd, 0xba], false),
+    (0x90e8, &[0xdd, 0xbb], false),
+    (0x91dc, &[0xdd, 0xbc], false),
+    (0x961c, &[0xdd, 0xbd], false),
+    (0x9644, &[0xdd, 0xbe], false),
+    (0x99d9, &[0xdd, 0xbf], false),
+    (0x9ce7, &[0xdd, 0xc0], false),
+    (0x5317, &[0xdd, 0xc1], false),
+    (0x5206, &[0xdd, 0xc2], false),
+    (0x5429, &[0xdd, 0xc3], false),
+    (0x5674, &[0xdd, 0xc4], false),
+    (0x58b3, &[0xdd, 0xc5], false),
+    (0x5954, &[0xdd, 0xc6], false),
+    (0x596e, &[0xdd, 0xc7], false),
+    (0x5fff, &[0xdd, 0xc8], false),
+    (0x61a4, &[0xdd, 0xc9], false),
+    (0x626e, &[0xdd, 0xca], false),
+    (0x6610, &[0xdd, 0xcb], false),
+    (0x6c7e, &[0xdd, 0xcc], false),
+    (0x711a, &[0xdd, 0xcd], false),
+    (0x76c6, &[0xdd, 0xce], false),
+    (0x7c89, &[0xdd, 0xcf], false),
+    (0x7cde, &[0xdd, 0xd0], false),
+    (0x7d1b, &[0xdd, 0xd1], false),
+    (0x82ac, &[0xdd, 0xd2], false),
+    (0x8cc1, &[0xdd, 0xd3], false),
+    (0x96f0, &[0xdd, 0xd4], false),
+    (0xf967, &[0xdd, 0xd5], false),
+    (0x4f5b, &[0xdd, 0xd6], false),
+    (0x5f17, &[0xdd, 0xd7], false),
+    (0x5f7f, &[0xdd, 0xd8], false),
+    (0x62c2, &[0xdd, 0xd9], false),
+    (0x5d29, &[0xdd, 0xda], false),
+    (0x670b, &[0xdd, 0xdb], false),
+    (0x68da, &[0xdd, 0xdc], false),
+    (0x787c, &[0xdd, 0xdd], false),
+    (0x7e43, &[0xdd, 0xde], false),
+    (0x9d6c, &[0xdd, 0xdf], false),
+    (0x4e15, &[0xdd, 0xe0], false),
+    (0x5099, &[0xdd, 0xe1], false),
+    (0x5315, &[0xdd, 0xe2], false),
+    (0x532a, &[0xdd, 0xe3], false),
+    (0x5351, &[0xdd, 0xe4], false),
+    (0x5983, &[0xdd, 0xe5], false),
+    (0x5a62, &[0xdd, 0xe6], false),
+    (0x5e87, &[0xdd, 0xe7], false),
+    (0x60b2, &[0xdd, 0xe8], false),
+    (0x618a, &[0xdd, 0xe9], false),
+    (0x6249, &[0xdd, 0xea], false),
+    (0x6279, &[0xdd, 0xeb], false),
+    (0x6590, &[0xdd, 0xec], false),
+    (0x6787, &[0xdd, 0xed], false),
+    (0x69a7, &[0xdd, 0xee], false),
+    (0x6bd4, &[0xdd, 0xef], false),
+    (0x6bd6, &[0xdd, 0xf0], false),
+    (0x6bd7, &[0xdd, 0xf1], false),
+    (0x6bd8, &[0xdd, 0xf2], false),
+    (0x6cb8, &[0xdd, 0xf3], false),
+    (0xf968, &[0xdd, 0xf4], false),
+    (0x7435, &[0xdd, 0xf5], false),
+    (0x75fa, &[0xdd, 0xf6], false),
+    (0x7812, &[0xdd, 0xf7], false),
+    (0x7891, &[0xdd, 0xf8], false),
+    (0x79d5, &[0xdd, 0xf9], false),
+    (0x79d8, &[0xdd, 0xfa], false),
+    (0x7c83, &[0xdd, 0xfb], false),
+    (0x7dcb, &[0xdd, 0xfc], false),
+    (0x7fe1, &[0xdd, 0xfd], false),
+    (0x80a5, &[0xdd, 0xfe], false),
+    (0x813e, &[0xde, 0xa1], false),
+    (0x81c2, &[0xde, 0xa2], false),
+    (0x83f2, &[0xde, 0xa3], false),
+    (0x871a, &[0xde, 0xa4], false),
+    (0x88e8, &[0xde, 0xa5], false),
+    (0x8ab9, &[0xde, 0xa6], false),
+    (0x8b6c, &[0xde, 0xa7], false),
+    (0x8cbb, &[0xde, 0xa8], false),
+    (0x9119, &[0xde, 0xa9], false),
+    (0x975e, &[0xde, 0xaa], false),
+    (0x98db, &[0xde, 0xab], false),
+    (0x9f3b, &[0xde, 0xac], false),
+    (0x56ac, &[0xde, 0xad], false),
+    (0x5b2a, &[0xde, 0xae], false),
+    (0x5f6c, &[0xde, 0xaf], false),
+    (0x658c, &[0xde, 0xb0], false),
+    (0x6ab3, &[0xde, 0xb1], false),
+    (0x6baf, &[0xde, 0xb2], false),
+    (0x6d5c, &[0xde, 0xb3], false),
+    (0x6ff1, &[0xde, 0xb4], false),
+    (0x7015, &[0xde, 0xb5], false),
+    (0x725d, &[0xde, 0xb6], false),
+    (0x73ad, &[0xde, 0xb7], false),
+    (0x8ca7, &[0xde, 0xb8], false),
+    (0x8cd3, &[0xde, 0xb9], false),
+    (0x983b, &[0xde, 0xba], false),
+    (0x6191, &[0xde, 0xbb], false),
+    (0x6c37, &[0xde, 0xbc], false),
+    (0x8058, &[0xde, 0xbd], false),
+    (0x9a01, &[0xde, 0xbe], false),
+    (0x4e4d, &[0xde, 0xbf], false),
+    (0x4e8b, &[0xde, 0xc0], false),
+    (0x4e9b, &[0xde, 0xc1], false),
+    (0x4ed5, &[0xde, 0xc2], false),
+    (0x4f3a, &[0xde, 0xc3], false),
+    (0x4f3c, &[0xde, 0xc4], false),
+    (0x4f7f, &[0xde, 0xc5], false),
+    (0x4fdf, &[0xde, 0xc6], false),
+    (0x50ff, &[0xde, 0xc7], false),
+    (0x53f2, &[0xde, 0xc8], false),
+    (0x53f8, &[0xde, 0xc9], false),
+    (0x5506, &[0xde, 0xca], false),
+    (0x55e3, &[0xde, 0xcb], false),
+    (0x56db, &[0xde, 0xcc], false),
+    (0x58eb, &[0xde, 0xcd], false),
+    (0x5962, &[0xde, 0xce], false),
+    (0x5a11, &[0xde, 0xcf], false),
+    (0x5beb, &[0xde, 0xd0], false),
+    (0x5bfa, &[0xde, 0xd1], false),
+    (0x5c04, &[0xde, 0xd2], false),
+    (0x5df3, &[0xde, 0xd3], false),
+    (0x5e2b, &[0xde, 0xd4], false),
+    (0x5f99, &[0xde, 0xd5], false),
+    (0x601d, &[0xde, 0xd6], false),
+    (0x6368, &[0xde, 0xd7], false),
+    (0x659c, &[0xde, 0xd8], false),
+    (0x65af, &[0xde, 0xd9], false),
+    (0x67f6, &[0xde, 0xda], false),
+    (0x67fb, &[0xde, 0xdb], false),
+    (0x68ad, &[0xde, 0xdc], false),
+    (0x6b7b, &[0xde, 0xdd], false),
+    (0x6c99, &[0xde, 0xde], false),
+    (0x6cd7, &[0xde, 0xdf], false),
+    (0x6e23, &[0xde, 0xe0], false),
+    (0x7009, &[0xde, 0xe1], false),
+    (0x7345, &[0xde, 0xe2], false),
+    (0x7802, &[0xde, 0xe3], false),
+    (0x793e, &[0xde, 0xe4], false),
+    (0x7940, &[0xde, 0xe5], false),
+    (0x7960, &[0xde, 0xe6], false),
+    (0x79c1, &[0xde, 0xe7], false),
+    (0x7be9, &[0xde, 0xe8], false),
+    (0x7d17, &[0xde, 0xe9], false),
+    (0x7d72, &[0xde, 0xea], false),
+    (0x8086, &[0xde, 0xeb], false),
+    (0x820d, &[0xde, 0xec], false),
+    (0x838e, &[0xde, 0xed], false),
+    (0x84d1, &[0xde, 0xee], false),
+    (0x86c7, &[0xde, 0xef], false),
+    (0x88df, &[0xde, 0xf0], false),
+    (0x8a50, &[0xde, 0xf1], false),
+    (0x8a5e, &[0xde, 0xf2], false),
+    (0x8b1d, &[0xde, 0xf3], false),
+    (0x8cdc, &[0xde, 0xf4], false),
+    (0x8d66, &[0xde, 0xf5], false),
+    (0x8fad, &[0xde, 0xf6], false),
+    (0x90aa, &[0xde, 0xf7], false),
+    (0x98fc, &[0xde, 0xf8], false),
+    (0x99df, &[0xde, 0xf9], false),
+    (0x9e9d, &[0xde, 0xfa], false),
+    (0x524a, &[0xde, 0xfb], false),
+    (0xf969, &[0xde, 0xfc], false),
+    (0x6714, &[0xde, 0xfd], false),
+    (0xf96a, &[0xde, 0xfe], false),
+    (0x5098, &[0xdf, 0xa1], false),
+    (0x522a, &[0xdf, 0xa2], false),
+    (0x5c71, &[0xdf, 0xa3], false),
+    (0x6563, &[0xdf, 0xa4], false),
+    (0x6c55, &[0xdf, 0xa5], false),
+    (0x73ca, &[0xdf, 0xa6], false),
+    (0x7523, &[0xdf, 0xa7], false),
+    (0x759d, &[0xdf, 0xa8], false),
+    (0x7b97, &[0xdf, 0xa9], false),
+    (0x849c, &[0xdf, 0xaa], false),
+    (0x9178, &[0xdf, 0xab], false),
+    (0x9730, &[0xdf, 0xac], false),
+    (0x4e77, &[0xdf, 0xad], false),
+    (0x6492, &[0xdf, 0xae], false),
+    (0x6bba, &[0xdf, 0xaf], false),
+    (0x715e, &[0xdf, 0xb0], false),
+    (0x85a9, &[0xdf, 0xb1], false),
+    (0x4e09, &[0xdf, 0xb2], false),
+    (0xf96b, &[0xdf, 0xb3], false),
+    (0x6749, &[0xdf, 0xb4], false),
+    (0x68ee, &[0xdf, 0xb5], false),
+    (0x6e17, &[0xdf, 0xb6], false),
+    (0x829f, &[0xdf, 0xb7], false),
+    (0x8518, &[0xdf, 0xb8], false),
+    (0x886b, &[0xdf, 0xb9], false),
+    (0x63f7, &[0xdf, 0xba], false),
+    (0x6f81, &[0xdf, 0xbb], false),
+    (0x9212, &[0xdf, 0xbc], false),
+    (0x98af, &[0xdf, 0xbd], false),
+    (0x4e0a, &[0xdf, 0xbe], false),
+    (0x50b7, &[0xdf, 0xbf], false),
+    (0x50cf, &[0xdf, 0xc0], false),
+    (0x511f, &[0xdf, 0xc1], false),
+    (0x5546, &[0xdf, 0xc2], false),
+    (0x55aa, &[0xdf, 0xc3], false),
+    (0x5617, &[0xdf, 0xc4], false),
+    (0x5b40, &[0xdf, 0xc5], false),
+    (0x5c19, &[0xdf, 0xc6], false),
+    (0x5ce0, &[0xdf, 0xc7], false),
+    (0x5e38, &[0xdf, 0xc8], false),
+    (0x5e8a, &[0xdf, 0xc9], false),
+    (0x5ea0, &[0xdf, 0xca], false),
+    (0x5ec2, &[0xdf, 0xcb], false),
+    (0x60f3, &[0xdf, 0xcc], false),
+    (0x6851, &[0xdf, 0xcd], false),
+    (0x6a61, &[0xdf, 0xce], false),
+    (0x6e58, &[0xdf, 0xcf], false),
+    (0x723d, &[0xdf, 0xd0], false),
+    (0x7240, &[0xdf, 0xd1], false),
+    (0x72c0, &[0xdf, 0xd2], false),
+    (0x76f8, &[0xdf, 0xd3], false),
+    (0x7965, &[0xdf, 0xd4], false),
+    (0x7bb1, &[0xdf, 0xd5], false),
+    (0x7fd4, &[0xdf, 0xd6], false),
+    (0x88f3, &[0xdf, 0xd7], false),
+    (0x89f4, &[0xdf, 0xd8], false),
+    (0x8a73, &[0xdf, 0xd9], false),
+    (0x8c61, &[0xdf, 0xda], false),
+    (0x8cde, &[0xdf, 0xdb], false),
+    (0x971c, &[0xdf, 0xdc], false),
+    (0x585e, &[0xdf, 0xdd], false),
+    (0x74bd, &[0xdf, 0xde], false),
+    (0x8cfd, &[0xdf, 0xdf], false),
+    (0x55c7, &[0xdf, 0xe0], false),
+    (0xf96c, &[0xdf, 0xe1], false),
+    (0x7a61, &[0xdf, 0xe2], false),
+    (0x7d22, &[0xdf, 0xe3], false),
+    (0x8272, &[0xdf, 0xe4], false),
+    (0x7272, &[0xdf, 0xe5], false),
+    (0x751f, &[0xdf, 0xe6], false),
+    (0x7525, &[0xdf, 0xe7], false),
+    (0xf96d, &[0xdf, 0xe8], false),
+    (0x7b19, &[0xdf, 0xe9], false),
+    (0x5885, &[0xdf, 0xea], false),
+    (0x58fb, &[0xdf, 0xeb], false),
+    (0x5dbc, &[0xdf, 0xec], false),
+    (0x5e8f, &[0xdf, 0xed], false),
+    (0x5eb6, &[0xdf, 0xee], false),
+    (0x5f90, &[0xdf, 0xef], false),
+    (0x6055, &[0xdf, 0xf0], false),
+    (0x6292, &[0xdf, 0xf1], false),
+    (0x637f, &[0xdf, 0xf2], false),
+    (0x654d, &[0xdf, 0xf3], false),
+    (0x6691, &[0xdf, 0xf4], false),
+    (0x66d9, &[0xdf, 0xf5], false),
+    (0x66f8, &[0xdf, 0xf6], false),
+    (0x6816, &[0xdf, 0xf7], false),
+    (0x68f2, &[0xdf, 0xf8], false),
+    (0x7280, &[0xdf, 0xf9], false),
+    (0x745e, &[0xdf, 0xfa], false),
+    (0x7b6e, &[0xdf, 0xfb], false),
+    (0x7d6e, &[0xdf, 0xfc], false),
+    (0x7dd6, &[0xdf, 0xfd], false),
+    (0x7f72, &[0xdf, 0xfe], false),
+    (0x80e5, &[0xe0, 0xa1], false),
+    (0x8212, &[0xe0, 0xa2], false),
+    (0x85af, &[0xe0, 0xa3], false),
+    (0x897f, &[0xe0, 0xa4], false),
+    (0x8a93, &[0xe0, 0xa5], false),
+    (0x901d, &[0xe0, 0xa6], false),
+    (0x92e4, &[0xe0, 0xa7], false),
+    (0x9ecd, &[0xe0, 0xa8], false),
+    (0x9f20, &[0xe0, 0xa9], false),
+    (0x5915, &[0xe0, 0xaa], false),
+    (0x596d, &[0xe0, 0xab], false),
+    (0x5e2d, &[0xe0, 0xac], false),
+    (0x60dc, &[0xe0, 0xad], false),
+    (0x6614, &[0xe0, 0xae], false),
+    (0x6673, &[0xe0, 0xaf], false),
+    (0x6790, &[0xe0, 0xb0], false),
+    (0x6c50, &[0xe0, 0xb1], false),
+    (0x6dc5, &[0xe0, 0xb2], false),
+    (0x6f5f, &[0xe0, 0xb3], false),
+    (0x77f3, &[0xe0, 0xb4], false),
+    (0x78a9, &[0xe0, 0xb5], false),
+    (0x84c6, &[0xe0, 0xb6], false),
+    (0x91cb, &[0xe0, 0xb7], false),
+    (0x932b, &[0xe0, 0xb8], false),
+    (0x4ed9, &[0xe0, 0xb9], false),
+    (0x50ca, &[0xe0, 0xba], false),
+    (0x5148, &[0xe0, 0xbb], false),
+    (0x5584, &[0xe0, 0xbc], false),
+    (0x5b0b, &[0xe0, 0xbd], false),
+    (0x5ba3, &[0xe0, 0xbe], false),
+    (0x6247, &[0xe0, 0xbf], false),
+    (0x657e, &[0xe0, 0xc0], false),
+    (0x65cb, &[0xe0, 0xc1], false),
+    (0x6e32, &[0xe0, 0xc2], false),
+    (0x717d, &[0xe0, 0xc3], false),
+    (0x7401, &[0xe0, 0xc4], false),
+    (0x7444, &[0xe0, 0xc5], false),
+    (0x7487, &[0xe0, 0xc6], false),
+    (0x74bf, &[0xe0, 0xc7], false),
+    (0x766c, &[0xe0, 0xc8], false),
+    (0x79aa, &[0xe0, 0xc9], false),
+    (0x7dda, &[0xe0, 0xca], false),
+    (0x7e55, &[0xe0, 0xcb], false),
+    (0x7fa8, &[0xe0, 0xcc], false),
+    (0x817a, &[0xe0, 0xcd], false),
+    (0x81b3, &[0xe0, 0xce], false),
+    (0x8239, &[0xe0, 0xcf], false),
+    (0x861a, &[0xe0, 0xd0], false),
+    (0x87ec, &[0xe0, 0xd1], false),
+    (0x8a75, &[0xe0, 0xd2], false),
+    (0x8de3, &[0xe0, 0xd3], false),
+    (0x9078, &[0xe0, 0xd4], false),
+    (0x9291, &[0xe0, 0xd5], false),
+    (0x9425, &[0xe0, 0xd6], false),
+    (0x994d, &[0xe0, 0xd7], false),
+    (0x9bae, &[0xe0, 0xd8], false),
+    (0x5368, &[0xe0, 0xd9], false),
+    (0x5c51, &[0xe0, 0xda], false),
+    (0x6954, &[0xe0, 0xdb], false),
+    (0x6cc4, &[0xe0, 0xdc], false),
+    (0x6d29, &[0xe0, 0xdd], false),
+    (0x6e2b, &[0xe0, 0xde], false),
+    (0x820c, &[0xe0, 0xdf], false),
+    (0x859b, &[0xe0, 0xe0], false),
+    (0x893b, &[0xe0, 0xe1], false),
+    (0x8a2d, &[0xe0, 0xe2], false),
+    (0x8aaa, &[0xe0, 0xe3], false),
+    (0x96ea, &[0xe0, 0xe4], false),
+    (0x9f67, &[0xe0, 0xe5], false),
+    (0x5261, &[0xe0, 0xe6], false),
+    (0x66b9, &[0xe0, 0xe7], false),
+    (0x6bb2, &[0xe0, 0xe8], false),
+    (0x7e96, &[0xe0, 0xe9], false),
+    (0x87fe, &[0xe0, 0xea], false),
+    (0x8d0d, &[0xe0, 0xeb], false),
+    (0x9583, &[0xe0, 0xec], false),
+    (0x965d, &[0xe0, 0xed], false),
+    (0x651d, &[0xe0, 0xee], false),
+    (0x6d89, &[0xe0, 0xef], false),
+    (0x71ee, &[0xe0, 0xf0], false),
+    (0xf96e, &[0xe0, 0xf1], false),
+    (0x57ce, &[0xe0, 0xf2], false),
+    (0x59d3, &[0xe0, 0xf3], false),
+    (0x5bac, &[0xe0, 0xf4], false),
+    (0x6027, &[0xe0, 0xf5], false),
+    (0x60fa, &[0xe0, 0xf6], false),
+    (0x6210, &[0xe0, 0xf7], false),
+    (0x661f, &[0xe0, 0xf8], false),
+    (0x665f, &[0xe0, 0xf9], false),
+    (0x7329, &[0xe0, 0xfa], false),
+    (0x73f9, &[0xe0, 0xfb], false),
+    (0x76db, &[0xe0, 0xfc], false),
+    (0x7701, &[0xe0, 0xfd], false),
+    (0x7b6c, &[0xe0, 0xfe], false),
+    (0x8056, &[0xe1, 0xa1], false),
+    (0x8072, &[0xe1, 0xa2], false),
+    (0x8165, &[0xe1, 0xa3], false),
+    (0x8aa0, &[0xe1, 0xa4], false),
+    (0x9192, &[0xe1, 0xa5], false),
+    (0x4e16, &[0xe1, 0xa6], false),
+    (0x52e2, &[0xe1, 0xa7], false),
+    (0x6b72, &[0xe1, 0xa8], false),
+    (0x6d17, &[0xe1, 0xa9], false),
+    (0x7a05, &[0xe1, 0xaa], false),
+    (0x7b39, &[0xe1, 0xab], false),
+    (0x7d30, &[0xe1, 0xac], false),
+    (0xf96f, &[0xe1, 0xad], false),
+    (0x8cb0, &[0xe1, 0xae], false),
+    (0x53ec, &[0xe1, 0xaf], false),
+    (0x562f, &[0xe1, 0xb0], false),
+    (0x5851, &[0xe1, 0xb1], false),
+    (0x5bb5, &[0xe1, 0xb2], false),
+    (0x5c0f, &[0xe1, 0xb3], false),
+    (0x5c11, &[0xe1, 0xb4], false),
+    (0x5de2, &[0xe1, 0xb5], false),
+    (0x6240, &[0xe1, 0xb6], false),
+    (0x6383, &[0xe1, 0xb7], false),
+    (0x6414, &[0xe1, 0xb8], false),
+    (0x662d, &[0xe1, 0xb9], false),
+    (0x68b3, &[0xe1, 0xba], false),
+    (0x6cbc, &[0xe1, 0xbb], false),
+    (0x6d88, &[0xe1, 0xbc], false),
+    (0x6eaf, &[0xe1, 0xbd], false),
+    (0x701f, &[0xe1, 0xbe], false),
+    (0x70a4, &[0xe1, 0xbf], false),
+    (0x71d2, &[0xe1, 0xc0], false),
+    (0x7526, &[0xe1, 0xc1], false),
+    (0x758f, &[0xe1, 0xc2], false),
+    (0x758e, &[0xe1, 0xc3], false),
+    (0x7619, &[0xe1, 0xc4], false),
+    (0x7b11, &[0xe1, 0xc5], false),
+    (0x7be0, &[0xe1, 0xc6], false),
+    (0x7c2b, &[0xe1, 0xc7], false),
+    (0x7d20, &[0xe1, 0xc8], false),
+    (0x7d39, &[0xe1, 0xc9], false),
+    (0x852c, &[0xe1, 0xca], false),
+    (0x856d, &[0xe1, 0xcb], false),
+    (0x8607, &[0xe1, 0xcc], false),
+    (0x8a34, &[0xe1, 0xcd], false),
+    (0x900d, &[0xe1, 0xce], false),
+    (0x9061, &[0xe1, 0xcf], false),
+    (0x90b5, &[0xe1, 0xd0], false),
+    (0x92b7, &[0xe1, 0xd1], false),
+    (0x97f6, &[0xe1, 0xd2], false),
+    (0x9a37, &[0xe1, 0xd3], false),
+    (0x4fd7, &[0xe1, 0xd4], false),
+    (0x5c6c, &[0xe1, 0xd5], false),
+    (0x675f, &[0xe1, 0xd6], false),
+    (0x6d91, &[0xe1, 0xd7], false),
+    (0x7c9f, &[0xe1, 0xd8], false),
+    (0x7e8c, &[0xe1, 0xd9], false),
+    (0x8b16, &[0xe1, 0xda], false),
+    (0x8d16, &[0xe1, 0xdb], false),
+    (0x901f, &[0xe1, 0xdc], false),
+    (0x5b6b, &[0xe1, 0xdd], false),
+    (0x5dfd, &[0xe1, 0xde], false),
+    (0x640d, &[0xe1, 0xdf], false),
+    (0x84c0, &[0xe1, 0xe0], false),
+    (0x905c, &[0xe1, 0xe1], false),
+    (0x98e1, &[0xe1, 0xe2], false),
+    (0x7387, &[0xe1, 0xe3], false),
+    (0x5b8b, &[0xe1, 0xe4], false),
+    (0x609a, &[0xe1, 0xe5], false),
+    (0x677e, &[0xe1, 0xe6], false),
+    (0x6dde, &[0xe1, 0xe7], false),
+    (0x8a1f, &[0xe1, 0xe8], false),
+    (0x8aa6, &[0xe1, 0xe9], false),
+    (0x9001, &[0xe1, 0xea], false),
+    (0x980c, &[0xe1, 0xeb], false),
+    (0x5237, &[0xe1, 0xec], false),
+    (0xf970, &[0xe1, 0xed], false),
+    (0x7051, &[0xe1, 0xee], false),
+    (0x788e, &[0xe1, 0xef], false),
+    (0x9396, &[0xe1, 0xf0], false),
+    (0x8870, &[0xe1, 0xf1], false),
+    (0x91d7, &[0xe1, 0xf2], false),
+    (0x4fee, &[0xe1, 0xf3], false),
+    (0x53d7, &[0xe1, 0xf4], false),
+    (0x55fd, &[0xe1, 0xf5], false),
+    (0x56da, &[0xe1, 0xf6], false),
+    (0x5782, &[0xe1, 0xf7], false),
+    (0x58fd, &[0xe1, 0xf8], false),
+    (0x5ac2, &[0xe1, 0xf9], false),
+    (0x5b88, &[0xe1, 0xfa], false),
+    (0x5cab, &[0xe1, 0xfb], false),
+    (0x5cc0, &[0xe1, 0xfc], false),
+    (0x5e25, &[0xe1, 0xfd], false),
+    (0x6101, &[0xe1, 0xfe], false),
+    (0x620d, &[0xe2, 0xa1], false),
+    (0x624b, &[0xe2, 0xa2], false),
+    (0x6388, &[0xe2, 0xa3], false),
+    (0x641c, &[0xe2, 0xa4], false),
+    (0x6536, &[0xe2, 0xa5], false),
+    (0x6578, &[0xe2, 0xa6], false),
+    (0x6a39, &[0xe2, 0xa7], false),
+    (0x6b8a, &[0xe2, 0xa8], false),
+    (0x6c34, &[0xe2, 0xa9], false),
+    (0x6d19, &[0xe2, 0xaa], false),
+    (0x6f31, &[0xe2, 0xab], false),
+    (0x71e7, &[0xe2, 0xac], false),
+    (0x72e9, &[0xe2, 0xad], false),
+    (0x7378, &[0xe2, 0xae], false),
+    (0x7407, &[0xe2, 0xaf], false),
+    (0x74b2, &[0xe2, 0xb0], false),
+    (0x7626, &[0xe2, 0xb1], false),
+    (0x7761, &[0xe2, 0xb2], false),
+    (0x79c0, &[0xe2, 0xb3], false),
+    (0x7a57, &[0xe2, 0xb4], false),
+    (0x7aea, &[0xe2, 0xb5], false),
+    (0x7cb9, &[0xe2, 0xb6], false),
+    (0x7d8f, &[0xe2, 0xb7], false),
+    (0x7dac, &[0xe2, 0xb8], false),
+    (0x7e61, &[0xe2, 0xb9], false),
+    (0x7f9e, &[0xe2, 0xba], false),
+    (0x8129, &[0xe2, 0xbb], false),
+    (0x8331, &[0xe2, 0xbc], false),
+    (0x8490, &[0xe2, 0xbd], false),
+    (0x84da, &[0xe2, 0xbe], false),
+    (0x85ea, &[0xe2, 0xbf], false),
+    (0x8896, &[0xe2, 0xc0], false),
+    (0x8ab0, &[0xe2, 0xc1], false),
+    (0x8b90, &[0xe2, 0xc2], false),
+    (0x8f38, &[0xe2, 0xc3], false),
+    (0x9042, &[0xe2, 0xc4], false),
+    (0x9083, &[0xe2, 0xc5], false),
+    (0x916c, &[0xe2, 0xc6], false),
+    (0x9296, &[0xe2, 0xc7], false),
+    (0x92b9, &[0xe2, 0xc8], false),
+    (0x968b, &[0xe2, 0xc9], false),
+    (0x96a7, &[0xe2, 0xca], false),
+    (0x96a8, &[0xe2, 0xcb], false),
+    (0x96d6, &[0xe2, 0xcc], false),
+    (0x9700, &[0xe2, 0xcd], false),
+    (0x9808, &[0xe2, 0xce], false),
+    (0x9996, &[0xe2, 0xcf], false),
+    (0x9ad3, &[0xe2, 0xd0], false),
+    (0x9b1a, &[0xe2, 0xd1], false),
+    (0x53d4, &[0xe2, 0xd2], false),
+    (0x587e, &[0xe2, 0xd3], false),
+    (0x5919, &[0xe2, 0xd4], false),
+    (0x5b70, &[0xe2, 0xd5], false),
+    (0x5bbf, &[0xe2, 0xd6], false),
+    (0x6dd1, &[0xe2, 0xd7], false),
+    (0x6f5a, &[0xe2, 0xd8], false),
+    (0x719f, &[0xe2, 0xd9], false),
+    (0x7421, &[0xe2, 0xda], false),
+    (0x74b9, &[0xe2, 0xdb], false),
+    (0x8085, &[0xe2, 0xdc], false),
+    (0x83fd, &[0xe2, 0xdd], false),
+    (0x5de1, &[0xe2, 0xde], false),
+    (0x5f87, &[0xe2, 0xdf], false),
+    (0x5faa, &[0xe2, 0xe0], false),
+    (0x6042, &[0xe2, 0xe1], false),
+    (0x65ec, &[0xe2, 0xe2], false),
+    (0x6812, &[0xe2, 0xe3], false),
+    (0x696f, &[0xe2, 0xe4], false),
+    (0x6a53, &[0xe2, 0xe5], false),
+    (0x6b89, &[0xe2, 0xe6], false),
+    (0x6d35, &[0xe2, 0xe7], false),
+    (0x6df3, &[0xe2, 0xe8], false),
+    (0x73e3, &[0xe2, 0xe9], false),
+    (0x76fe, &[0xe2, 0xea], false),
+    (0x77ac, &[0xe2, 0xeb], false),
+    (0x7b4d, &[0xe2, 0xec], false),
+    (0x7d14, &[0xe2, 0xed], false),
+    (0x8123, &[0xe2, 0xee], false),
+    (0x821c, &[0xe2, 0xef], false),
+    (0x8340, &[0xe2, 0xf0], false),
+    (0x84f4, &[0xe2, 0xf1], false),
+    (0x8563, &[0xe2, 0xf2], false),
+    (0x8a62, &[0xe2, 0xf3], false),
+    (0x8ac4, &[0xe2, 0xf4], false),
+    (0x9187, &[0xe2, 0xf5], false),
+    (0x931e, &[0xe2, 0xf6], false),
+    (0x9806, &[0xe2, 0xf7], false),
+    (0x99b4, &[0xe2, 0xf8], false),
+    (0x620c, &[0xe2, 0xf9], false),
+    (0x8853, &[0xe2, 0xfa], false),
+    (0x8ff0, &[0xe2, 0xfb], false),
+    (0x9265, &[0xe2, 0xfc], false),
+    (0x5d07, &[0xe2, 0xfd], false),
+    (0x5d27, &[0xe2, 0xfe], false),
+    (0x5d69, &[0xe3, 0xa1], false),
+    (0x745f, &[0xe3, 0xa2], false),
+    (0x819d, &[0xe3, 0xa3], false),
+    (0x8768, &[0xe3, 0xa4], false),
+    (0x6fd5, &[0xe3, 0xa5], false),
+    (0x62fe, &[0xe3, 0xa6], false),
+    (0x7fd2, &[0xe3, 0xa7], false),
+    (0x8936, &[0xe3, 0xa8], false),
+    (0x8972, &[0xe3, 0xa9], false),
+    (0x4e1e, &[0xe3, 0xaa], false),
+    (0x4e58, &[0xe3, 0xab], false),
+    (0x50e7, &[0xe3, 0xac], false),
+    (0x52dd, &[0xe3, 0xad], false),
+    (0x5347, &[0xe3, 0xae], false),
+    (0x627f, &[0xe3, 0xaf], false),
+    (0x6607, &[0xe3, 0xb0], false),
+    (0x7e69, &[0xe3, 0xb1], false),
+    (0x8805, &[0xe3, 0xb2], false),
+    (0x965e, &[0xe3, 0xb3], false),
+    (0x4f8d, &[0xe3, 0xb4], false),
+    (0x5319, &[0xe3, 0xb5], false),
+    (0x5636, &[0xe3, 0xb6], false),
+    (0x59cb, &[0xe3, 0xb7], false),
+    (0x5aa4, &[0xe3, 0xb8], false),
+    (0x5c38, &[0xe3, 0xb9], false),
+    (0x5c4e, &[0xe3, 0xba], false),
+    (0x5c4d, &[0xe3, 0xbb], false),
+    (0x5e02, &[0xe3, 0xbc], false),
+    (0x5f11, &[0xe3, 0xbd], false),
+    (0x6043, &[0xe3, 0xbe], false),
+    (0x65bd, &[0xe3, 0xbf], false),
+    (0x662f, &[0xe3, 0xc0], false),
+    (0x6642, &[0xe3, 0xc1], false),
+    (0x67be, &[0xe3, 0xc2], false),
+    (0x67f4, &[0xe3, 0xc3], false),
+    (0x731c, &[0xe3, 0xc4], false),
+    (0x77e2, &[0xe3, 0xc5], false),
+    (0x793a, &[0xe3, 0xc6], false),
+    (0x7fc5, &[0xe3, 0xc7], false),
+    (0x8494, &[0xe3, 0xc8], false),
+    (0x84cd, &[0xe3, 0xc9], false),
+    (0x8996, &[0xe3, 0xca], false),
+    (0x8a66, &[0xe3, 0xcb], false),
+    (0x8a69, &[0xe3, 0xcc], false),
+    (0x8ae1, &[0xe3, 0xcd], false),
+    (0x8c55, &[0xe3, 0xce], false),
+    (0x8c7a, &[0xe3, 0xcf], false),
+    (0x57f4, &[0xe3, 0xd0], false),
+    (0x5bd4, &[0xe3, 0xd1], false),
+    (0x5f0f, &[0xe3, 0xd2], false),
+    (0x606f, &[0xe3, 0xd3], false),
+    (0x62ed, &[0xe3, 0xd4], false),
+    (0x690d, &[0xe3, 0xd5], false),
+    (0x6b96, &[0xe3, 0xd6], false),
+    (0x6e5c, &[0xe3, 0xd7], false),
+    (0x7184, &[0xe3, 0xd8], false),
+    (0x7bd2, &[0xe3, 0xd9], false),
+    (0x8755, &[0xe3, 0xda], false),
+    (0x8b58, &[0xe3, 0xdb], false),
+    (0x8efe, &[0xe3, 0xdc], false),
+    (0x98df, &[0xe3, 0xdd], false),
+    (0x98fe, &[0xe3, 0xde], false),
+    (0x4f38, &[0xe3, 0xdf], false),
+    (0x4f81, &[0xe3, 0xe0], false),
+    (0x4fe1, &[0xe3, 0xe1], false),
+    (0x547b, &[0xe3, 0xe2], false),
+    (0x5a20, &[0xe3, 0xe3], false),
+    (0x5bb8, &[0xe3, 0xe4], false),
+    (0x613c, &[0xe3, 0xe5], false),
+    (0x65b0, &[0xe3, 0xe6], false),
+    (0x6668, &[0xe3, 0xe7], false),
+    (0x71fc, &[0xe3, 0xe8], false),
+    (0x7533, &[0xe3, 0xe9], false),
+    (0x795e, &[0xe3, 0xea], false),
+    (0x7d33, &[0xe3, 0xeb], false),
+    (0x814e, &[0xe3, 0xec], false),
+    (0x81e3, &[0xe3, 0xed], false),
+    (0x8398, &[0xe3, 0xee], false),
+    (0x85aa, &[0xe3, 0xef], false),
+    (0x85ce, &[0xe3, 0xf0], false),
+    (0x8703, &[0xe3, 0xf1], false),
+    (0x8a0a, &[0xe3, 0xf2], false),
+    (0x8eab, &[0xe3, 0xf3], false),
+    (0x8f9b, &[0xe3, 0xf4], false),
+    (0xf971, &[0xe3, 0xf5], false),
+    (0x8fc5, &[0xe3, 0xf6], false),
+    (0x5931, &[0xe3, 0xf7], false),
+    (0x5ba4, &[0xe3, 0xf8], false),
+    (0x5be6, &[0xe3, 0xf9], false),
+    (0x6089, &[0xe3, 0xfa], false),
+    (0x5be9, &[0xe3, 0xfb], false),
+    (0x5c0b, &[0xe3, 0xfc], false),
+    (0x5fc3, &[0xe3, 0xfd], false),
+    (0x6c81, &[0xe3, 0xfe], false),
+    (0xf972, &[0xe4, 0xa1], false),
+    (0x6df1, &[0xe4, 0xa2], false),
+    (0x700b, &[0xe4, 0xa3], false),
+    (0x751a, &[0xe4, 0xa4], false),
+    (0x82af, &[0xe4, 0xa5], false),
+    (0x8af6, &[0xe4, 0xa6], false),
+    (0x4ec0, &[0xe4, 0xa7], false),
+    (0x5341, &[0xe4, 0xa8], false),
+    (0xf973, &[0xe4, 0xa9], false),
+    (0x96d9, &[0xe4, 0xaa], false),
+    (0x6c0f, &[0xe4, 0xab], false),
+    (0x4e9e, &[0xe4, 0xac], false),
+    (0x4fc4, &[0xe4, 0xad], false),
+    (0x5152, &[0xe4, 0xae], false),
+    (0x555e, &[0xe4, 0xaf], false),
+    (0x5a25, &[0xe4, 0xb0], false),
+    (0x5ce8, &[0xe4, 0xb1], false),
+    (0x6211, &[0xe4, 0xb2], false),
+    (0x7259, &[0xe4, 0xb3], false),
+    (0x82bd, &[0xe4, 0xb4], false),
+    (0x83aa, &[0xe4, 0xb5], false),
+    (0x86fe, &[0xe4, 0xb6], false),
+    (0x8859, &[0xe4, 0xb7], false),
+    (0x8a1d, &[0xe4, 0xb8], false),
+    (0x963f, &[0xe4, 0xb9], false),
+    (0x96c5, &[0xe4, 0xba], false),
+    (0x9913, &[0xe4, 0xbb], false),
+    (0x9d09, &[0xe4, 0xbc], false),
+    (0x9d5d, &[0xe4, 0xbd], false),
+    (0x580a, &[0xe4, 0xbe], false),
+    (0x5cb3, &[0xe4, 0xbf], false),
+    (0x5dbd, &[0xe4, 0xc0], false),
+    (0x5e44, &[0xe4, 0xc1], false),
+    (0x60e1, &[0xe4, 0xc2], false),
+    (0x6115, &[0xe4, 0xc3], false),
+    (0x63e1, &[0xe4, 0xc4], false),
+    (0x6a02, &[0xe4, 0xc5], false),
+    (0x6e25, &[0xe4, 0xc6], false),
+    (0x9102, &[0xe4, 0xc7], false),
+    (0x9354, &[0xe4, 0xc8], false),
+    (0x984e, &[0xe4, 0xc9], false),
+    (0x9c10, &[0xe4, 0xca], false),
+    (0x9f77, &[0xe4, 0xcb], false),
+    (0x5b89, &[0xe4, 0xcc], false),
+    (0x5cb8, &[0xe4, 0xcd], false),
+    (0x6309, &[0xe4, 0xce], false),
+    (0x664f, &[0xe4, 0xcf], false),
+    (0x6848, &[0xe4, 0xd0], false),
+    (0x773c, &[0xe4, 0xd1], false),
+    (0x96c1, &[0xe4, 0xd2], false),
+    (0x978d, &[0xe4, 0xd3], false),
+    (0x9854, &[0xe4, 0xd4], false),
+    (0x9b9f, &[0xe4, 0xd5], false),
+    (0x65a1, &[0xe4, 0xd6], false),
+    (0x8b01, &[0xe4, 0xd7], false),
+    (0x8ecb, &[0xe4, 0xd8], false),
+    (0x95bc, &[0xe4, 0xd9], false),
+    (0x5535, &[0xe4, 0xda], false),
+    (0x5ca9, &[0xe4, 0xdb], false),
+    (0x5dd6, &[0xe4, 0xdc], false),
+    (0x5eb5, &[0xe4, 0xdd], false),
+    (0x6697, &[0xe4, 0xde], false),
+    (0x764c, &[0xe4, 0xdf], false),
+    (0x83f4, &[0xe4, 0xe0], false),
+    (0x95c7, &[0xe4, 0xe1], false),
+    (0x58d3, &[0xe4, 0xe2], false),
+    (0x62bc, &[0xe4, 0xe3], false),
+    (0x72ce, &[0xe4, 0xe4], false),
+    (0x9d28, &[0xe4, 0xe5], false),
+    (0x4ef0, &[0xe4, 0xe6], false),
+    (0x592e, &[0xe4, 0xe7], false),
+    (0x600f, &[0xe4, 0xe8], false),
+    (0x663b, &[0xe4, 0xe9], false),
+    (0x6b83, &[0xe4, 0xea], false),
+    (0x79e7, &[0xe4, 0xeb], false),
+    (0x9d26, &[0xe4, 0xec], false),
+    (0x5393, &[0xe4, 0xed], false),
+    (0x54c0, &[0xe4, 0xee], false),
+    (0x57c3, &[0xe4, 0xef], false),
+    (0x5d16, &[0xe4, 0xf0], false),
+    (0x611b, &[0xe4, 0xf1], false),
+    (0x66d6, &[0xe4, 0xf2], false),
+    (0x6daf, &[0xe4, 0xf3], false),
+    (0x788d, &[0xe4, 0xf4], false),
+    (0x827e, &[0xe4, 0xf5], false),
+    (0x9698, &[0xe4, 0xf6], false),
+    (0x9744, &[0xe4, 0xf7], false),
+    (0x5384, &[0xe4, 0xf8], false),
+    (0x627c, &[0xe4, 0xf9], false),
+    (0x6396, &[0xe4, 0xfa], false),
+    (0x6db2, &[0xe4, 0xfb], false),
+    (0x7e0a, &[0xe4, 0xfc], false),
+    (0x814b, &[0xe4, 0xfd], false),
+    (0x984d, &[0xe4, 0xfe], false),
+    (0x6afb, &[0xe5, 0xa1], false),
+    (0x7f4c, &[0xe5, 0xa2], false),
+    (0x9daf, &[0xe5, 0xa3], false),
+    (0x9e1a, &[0xe5, 0xa4], false),
+    (0x4e5f, &[0xe5, 0xa5], false),
+    (0x503b, &[0xe5, 0xa6], false),
+    (0x51b6, &[0xe5, 0xa7], false),
+    (0x591c, &[0xe5, 0xa8], false),
+    (0x60f9, &[0xe5, 0xa9], false),
+    (0x63f6, &[0xe5, 0xaa], false),
+    (0x6930, &[0xe5, 0xab], false),
+    (0x723a, &[0xe5, 0xac], false),
+    (0x8036, &[0xe5, 0xad], false),
+    (0xf974, &[0xe5, 0xae], false),
+    (0x91ce, &[0xe5, 0xaf], false),
+    (0x5f31, &[0xe5, 0xb0], false),
+    (0xf975, &[0xe5, 0xb1], false),
+    (0xf976, &[0xe5, 0xb2], false),
+    (0x7d04, &[0xe5, 0xb3], false),
+    (0x82e5, &[0xe5, 0xb4], false),
+    (0x846f, &[0xe5, 0xb5], false),
+    (0x84bb, &[0xe5, 0xb6], false),
+    (0x85e5, &[0xe5, 0xb7], false),
+    (0x8e8d, &[0xe5, 0xb8], false),
+    (0xf977, &[0xe5, 0xb9], false),
+    (0x4f6f, &[0xe5, 0xba], false),
+    (0xf978, &[0xe5, 0xbb], false),
+    (0xf979, &[0xe5, 0xbc], false),
+    (0x58e4, &[0xe5, 0xbd], false),
+    (0x5b43, &[0xe5, 0xbe], false),
+    (0x6059, &[0xe5, 0xbf], false),
+    (0x63da, &[0xe5, 0xc0], false),
+    (0x6518, &[0xe5, 0xc1], false),
+    (0x656d, &[0xe5, 0xc2], false),
+    (0x6698, &[0xe5, 0xc3], false),
+    (0xf97a, &[0xe5, 0xc4], false),
+    (0x694a, &[0xe5, 0xc5], false),
+    (0x6a23, &[0xe5, 0xc6], false),
+    (0x6d0b, &[0xe5, 0xc7], false),
+    (0x7001, &[0xe5, 0xc8], false),
+    (0x716c, &[0xe5, 0xc9], false),
+    (0x75d2, &[0xe5, 0xca], false),
+    (0x760d, &[0xe5, 0xcb], false),
+    (0x79b3, &[0xe5, 0xcc], false),
+    (0x7a70, &[0xe5, 0xcd], false),
+    (0xf97b, &[0xe5, 0xce], false),
+    (0x7f8a, &[0xe5, 0xcf], false),
+    (0xf97c, &[0xe5, 0xd0], false),
+    (0x8944, &[0xe5, 0xd1], false),
+    (0xf97d, &[0xe5, 0xd2], false),
+    (0x8b93, &[0xe5, 0xd3], false),
+    (0x91c0, &[0xe5, 0xd4], false),
+    (0x967d, &[0xe5, 0xd5], false),
+    (0xf97e, &[0xe5, 0xd6], false),
+    (0x990a, &[0xe5, 0xd7], false),
+    (0x5704, &[0xe5, 0xd8], false),
+    (0x5fa1, &[0xe5, 0xd9], false),
+    (0x65bc, &[0xe5, 0xda], false),
+    (0x6f01, &[0xe5, 0xdb], false),
+    (0x7600, &[0xe5, 0xdc], false),
+    (0x79a6, &[0xe5, 0xdd], false),
+    (0x8a9e, &[0xe5, 0xde], false),
+    (0x99ad, &[0xe5, 0xdf], false),
+    (0x9b5a, &[0xe5, 0xe0], false),
+    (0x9f6c, &[0xe5, 0xe1], false),
+    (0x5104, &[0xe5, 0xe2], false),
+    (0x61b6, &[0xe5, 0xe3], false),
+    (0x6291, &[0xe5, 0xe4], false),
+    (0x6a8d, &[0xe5, 0xe5], false),
+    (0x81c6, &[0xe5, 0xe6], false),
+    (0x5043, &[0xe5, 0xe7], false),
+    (0x5830, &[0xe5, 0xe8], false),
+    (0x5f66, &[0xe5, 0xe9], false),
+    (0x7109, &[0xe5, 0xea], false),
+    (0x8a00, &[0xe5, 0xeb], false),
+    (0x8afa, &[0xe5, 0xec], false),
+    (0x5b7c, &[0xe5, 0xed], false),
+    (0x8616, &[0xe5, 0xee], false),
+    (0x4ffa, &[0xe5, 0xef], false),
+    (0x513c, &[0xe5, 0xf0], false),
+    (0x56b4, &[0xe5, 0xf1], false),
+    (0x5944, &[0xe5, 0xf2], false),
+    (0x63a9, &[0xe5, 0xf3], false),
+    (0x6df9, &[0xe5, 0xf4], false),
+    (0x5daa, &[0xe5, 0xf5], false),
+    (0x696d, &[0xe5, 0xf6], false),
+    (0x5186, &[0xe5, 0xf7], false),
+    (0x4e88, &[0xe5, 0xf8], false),
+    (0x4f59, &[0xe5, 0xf9], false),
+    (0xf97f, &[0xe5, 0xfa], false),
+    (0xf980, &[0xe5, 0xfb], false),
+    (0xf981, &[0xe5, 0xfc], false),
+    (0x5982, &[0xe5, 0xfd], false),
+    (0xf982, &[0xe5, 0xfe], false),
+    (0xf983, &[0xe6, 0xa1], false),
+    (0x6b5f, &[0xe6, 0xa2], false),
+    (0x6c5d, &[0xe6, 0xa3], false),
+    (0xf984, &[0xe6, 0xa4], false),
+    (0x74b5, &[0xe6, 0xa5], false),
+    (0x7916, &[0xe6, 0xa6], false),
+    (0xf985, &[0xe6, 0xa7], false),
+    (0x8207, &[0xe6, 0xa8], false),
+    (0x8245, &[0xe6, 0xa9], false),
+    (0x8339, &[0xe6, 0xaa], false),
+    (0x8f3f, &[0xe6, 0xab], false),
+    (0x8f5d, &[0xe6, 0xac], false),
+    (0xf986, &[0xe6, 0xad], false),
+    (0x9918, &[0xe6, 0xae], false),
+    (0xf987, &[0xe6, 0xaf], false),
+    (0xf988, &[0xe6, 0xb0], false),
+    (0xf989, &[0xe6, 0xb1], false),
+    (0x4ea6, &[0xe6, 0xb2], false),
+    (0xf98a, &[0xe6, 0xb3], false),
+    (0x57df, &[0xe6, 0xb4], false),
+    (0x5f79, &[0xe6, 0xb5], false),
+    (0x6613, &[0xe6, 0xb6], false),
+    (0xf98b, &[0xe6, 0xb7], false),
+    (0xf98c, &[0xe6, 0xb8], false),
+    (0x75ab, &[0xe6, 0xb9], false),
+    (0x7e79, &[0xe6, 0xba], false),
+    (0x8b6f, &[0xe6, 0xbb], false),
+    (0xf98d, &[0xe6, 0xbc], false),
+    (0x9006, &[0xe6, 0xbd], false),
+    (0x9a5b, &[0xe6, 0xbe], false),
+    (0x56a5, &[0xe6, 0xbf], false),
+    (0x5827, &[0xe6, 0xc0], false),
+    (0x59f8, &[0xe6, 0xc1], false),
+    (0x5a1f, &[0xe6, 0xc2], false),
+    (0x5bb4, &[0xe6, 0xc3], false),
+    (0xf98e, &[0xe6, 0xc4], false),
+    (0x5ef6, &[0xe6, 0xc5], false),
+    (0xf98f, &[0xe6, 0xc6], false),
+    (0xf990, &[0xe6, 0xc7], false),
+    (0x6350, &[0xe6, 0xc8], false),
+    (0x633b, &[0xe6, 0xc9], false),
+    (0xf991, &[0xe6, 0xca], false),
+    (0x693d, &[0xe6, 0xcb], false),
+    (0x6c87, &[0xe6, 0xcc], false),
+    (0x6cbf, &[0xe6, 0xcd], false),
+    (0x6d8e, &[0xe6, 0xce], false),
+    (0x6d93, &[0xe6, 0xcf], false),
+    (0x6df5, &[0xe6, 0xd0], false),
+    (0x6f14, &[0xe6, 0xd1], false),
+    (0xf992, &[0xe6, 0xd2], false),
+    (0x70df, &[0xe6, 0xd3], false),
+    (0x7136, &[0xe6, 0xd4], false),
+    (0x7159, &[0xe6, 0xd5], false),
+    (0xf993, &[0xe6, 0xd6], false),
+    (0x71c3, &[0xe6, 0xd7], false),
+    (0x71d5, &[0xe6, 0xd8], false),
+    (0xf994, &[0xe6, 0xd9], false),
+    (0x784f, &[0xe6, 0xda], false),
+    (0x786f, &[0xe6, 0xdb], false),
+    (0xf995, &[0xe6, 0xdc], false),
+    (0x7b75, &[0xe6, 0xdd], false),
+    (0x7de3, &[0xe6, 0xde], false),
+    (0xf996, &[0xe6, 0xdf], false),
+    (0x7e2f, &[0xe6, 0xe0], false),
+    (0xf997, &[0xe6, 0xe1], false),
+    (0x884d, &[0xe6, 0xe2], false),
+    (0x8edf, &[0xe6, 0xe3], false),
+    (0xf998, &[0xe6, 0xe4], false),
+    (0xf999, &[0xe6, 0xe5], false),
+    (0xf99a, &[0xe6, 0xe6], false),
+    (0x925b, &[0xe6, 0xe7], false),
+    (0xf99b, &[0xe6, 0xe8], false),
+    (0x9cf6, &[0xe6, 0xe9], false),
+    (0xf99c, &[0xe6, 0xea], false),
+    (0xf99d, &[0xe6, 0xeb], false),
+    (0xf99e, &[0xe6, 0xec], false),
+    (0x6085, &[0xe6, 0xed], false),
+    (0x6d85, &[0xe6, 0xee], false),
+    (0xf99f, &[0xe6, 0xef], false),
+    (0x71b1, &[0xe6, 0xf0], false),
+    (0xf9a0, &[0xe6, 0xf1], false),
+    (0xf9a1, &[0xe6, 0xf2], false),
+    (0x95b1, &[0xe6, 0xf3], false),
+    (0x53ad, &[0xe6, 0xf4], false),
+    (0xf9a2, &[0xe6, 0xf5], false),
+    (0xf9a3, &[0xe6, 0xf6], false),
+    (0xf9a4, &[0xe6, 0xf7], false),
+    (0x67d3, &[0xe6, 0xf8], false),
+    (0xf9a5, &[0xe6, 0xf9], false),
+    (0x708e, &[0xe6, 0xfa], false),
+    (0x7130, &[0xe6, 0xfb], false),
+    (0x7430, &[0xe6, 0xfc], false),
+    (0x8276, &[0xe6, 0xfd], false),
+    (0x82d2, &[0xe6, 0xfe], false),
+    (0xf9a6, &[0xe7, 0xa1], false),
+    (0x95bb, &[0xe7, 0xa2], false),
+    (0x9ae5, &[0xe7, 0xa3], false),
+    (0x9e7d, &[0xe7, 0xa4], false),
+    (0x66c4, &[0xe7, 0xa5], false),
+    (0xf9a7, &[0xe7, 0xa6], false),
+    (0x71c1, &[0xe7, 0xa7], false),
+    (0x8449, &[0xe7, 0xa8], false),
+    (0xf9a8, &[0xe7, 0xa9], false),
+    (0xf9a9, &[0xe7, 0xaa], false),
+    (0x584b, &[0xe7, 0xab], false),
+    (0xf9aa, &[0xe7, 0xac], false),
+    (0xf9ab, &[0xe7, 0xad], false),
+    (0x5db8, &[0xe7, 0xae], false),
+    (0x5f71, &[0xe7, 0xaf], false),
+    (0xf9ac, &[0xe7, 0xb0], false),
+    (0x6620, &[0xe7, 0xb1], false),
+    (0x668e, &[0xe7, 0xb2], false),
+    (0x6979, &[0xe7, 0xb3], false),
+    (0x69ae, &[0xe7, 0xb4], false),
+    (0x6c38, &[0xe7, 0xb5], false),
+    (0x6cf3, &[0xe7, 0xb6], false),
+    (0x6e36, &[0xe7, 0xb7], false),
+    (0x6f41, &[0xe7, 0xb8], false),
+    (0x6fda, &[0xe7, 0xb9], false),
+    (0x701b, &[0xe7, 0xba], false),
+    (0x702f, &[0xe7, 0xbb], false),
+    (0x7150, &[0xe7, 0xbc], false),
+    (0x71df, &[0xe7, 0xbd], false),
+    (0x7370, &[0xe7, 0xbe], false),
+    (0xf9ad, &[0xe7, 0xbf], false),
+    (0x745b, &[0xe7, 0xc0], false),
+    (0xf9ae, &[0xe7, 0xc1], false),
+    (0x74d4, &[0xe7, 0xc2], false),
+    (0x76c8, &[0xe7, 0xc3], false),
+    (0x7a4e, &[0xe7, 0xc4], false),
+    (0x7e93, &[0xe7, 0xc5], false),
+    (0xf9af, &[0xe7, 0xc6], false),
+    (0xf9b0, &[0xe7, 0xc7], false),
+    (0x82f1, &[0xe7, 0xc8], false),
+    (0x8a60, &[0xe7, 0xc9], false),
+    (0x8fce, &[0xe7, 0xca], false),
+    (0xf9b1, &[0xe7, 0xcb], false),
+    (0x9348, &[0xe7, 0xcc], false),
+    (0xf9b2, &[0xe7, 0xcd], false),
+    (0x9719, &[0xe7, 0xce], false),
+    (0xf9b3, &[0xe7, 0xcf], false),
+    (0xf9b4, &[0xe7, 0xd0], false),
+    (0x4e42, &[0xe7, 0xd1], false),
+    (0x502a, &[0xe7, 0xd2], false),
+    (0xf9b5, &[0xe7, 0xd3], false),
+    (0x5208, &[0xe7, 0xd4], false),
+    (0x53e1, &[0xe7, 0xd5], false),
+    (0x66f3, &[0xe7, 0xd6], false),
+    (0x6c6d, &[0xe7, 0xd7], false),
+    (0x6fca, &[0xe7, 0xd8], false),
+    (0x730a, &[0xe7, 0xd9], false),
+    (0x777f, &[0xe7, 0xda], false),
+    (0x7a62, &[0xe7, 0xdb], false),
+    (0x82ae, &[0xe7, 0xdc], false),
+    (0x85dd, &[0xe7, 0xdd], false),
+    (0x8602, &[0xe7, 0xde], false),
+    (0xf9b6, &[0xe7, 0xdf], false),
+    (0x88d4, &[0xe7, 0xe0], false),
+    (0x8a63, &[0xe7, 0xe1], false),
+    (0x8b7d, &[0xe7, 0xe2], false),
+    (0x8c6b, &[0xe7, 0xe3], false),
+    (0xf9b7, &[0xe7, 0xe4], false),
+    (0x92b3, &[0xe7, 0xe5], false),
+    (0xf9b8, &[0xe7, 0xe6], false),
+    (0x9713, &[0xe7, 0xe7], false),
+    (0x9810, &[0xe7, 0xe8], false),
+    (0x4e94, &[0xe7, 0xe9], false),
+    (0x4f0d, &[0xe7, 0xea], false),
+    (0x4fc9, &[0xe7, 0xeb], false),
+    (0x50b2, &[0xe7, 0xec], false),
+    (0x5348, &[0xe7, 0xed], false),
+    (0x543e, &[0xe7, 0xee], false),
+    (0x5433, &[0xe7, 0xef], false),
+    (0x55da, &[0xe7, 0xf0], false),
+    (0x5862, &[0xe7, 0xf1], false),
+    (0x58ba, &[0xe7, 0xf2], false),
+    (0x5967, &[0xe7, 0xf3], false),
+    (0x5a1b, &[0xe7, 0xf4], false),
+    (0x5be4, &[0xe7, 0xf5], false),
+    (0x609f, &[0xe7, 0xf6], false),
+    (0xf9b9, &[0xe7, 0xf7], false),
+    (0x61ca, &[0xe7, 0xf8], false),
+    (0x6556, &[0xe7, 0xf9], false),
+    (0x65ff, &[0xe7, 0xfa], false),
+    (0x6664, &[0xe7, 0xfb], false),
+    (0x68a7, &[0xe7, 0xfc], false),
+    (0x6c5a, &[0xe7, 0xfd], false),
+    (0x6fb3, &[0xe7, 0xfe], false),
+    (0x70cf, &[0xe8, 0xa1], false),
+    (0x71ac, &[0xe8, 0xa2], false),
+    (0x7352, &[0xe8, 0xa3], false),
+    (0x7b7d, &[0xe8, 0xa4], false),
+    (0x8708, &[0xe8, 0xa5], false),
+    (0x8aa4, &[0xe8, 0xa6], false),
+    (0x9c32, &[0xe8, 0xa7], false),
+    (0x9f07, &[0xe8, 0xa8], false),
+    (0x5c4b, &[0xe8, 0xa9], false),
+    (0x6c83, &[0xe8, 0xaa], false),
+    (0x7344, &[0xe8, 0xab], false),
+    (0x7389, &[0xe8, 0xac], false),
+    (0x923a, &[0xe8, 0xad], false),
+    (0x6eab, &[0xe8, 0xae], false),
+    (0x7465, &[0xe8, 0xaf], false),
+    (0x761f, &[0xe8, 0xb0], false),
+    (0x7a69, &[0xe8, 0xb1], false),
+    (0x7e15, &[0xe8, 0xb2], false),
+    (0x860a, &[0xe8, 0xb3], false),
+    (0x5140, &[0xe8, 0xb4], false),
+    (0x58c5, &[0xe8, 0xb5], false),
+    (0x64c1, &[0xe8, 0xb6], false),
+    (0x74ee, &[0xe8, 0xb7], false),
+    (0x7515, &[0xe8, 0xb8], false),
+    (0x7670, &[0xe8, 0xb9], false),
+    (0x7fc1, &[0xe8, 0xba], false),
+    (0x9095, &[0xe8, 0xbb], false),
+    (0x96cd, &[0xe8, 0xbc], false),
+    (0x9954, &[0xe8, 0xbd], false),
+    (0x6e26, &[0xe8, 0xbe], false),
+    (0x74e6, &[0xe8, 0xbf], false),
+    (0x7aa9, &[0xe8, 0xc0], false),
+    (0x7aaa, &[0xe8, 0xc1], false),
+    (0x81e5, &[0xe8, 0xc2], false),
+    (0x86d9, &[0xe8, 0xc3], false),
+    (0x8778, &[0xe8, 0xc4], false),
+    (0x8a1b, &[0xe8, 0xc5], false),
+    (0x5a49, &[0xe8, 0xc6], false),
+    (0x5b8c, &[0xe8, 0xc7], false),
+    (0x5b9b, &[0xe8, 0xc8], false),
+    (0x68a1, &[0xe8, 0xc9], false),
+    (0x6900, &[0xe8, 0xca], false),
+    (0x6d63, &[0xe8, 0xcb], false),
+    (0x73a9, &[0xe8, 0xcc], false),
+    (0x7413, &[0xe8, 0xcd], false),
+    (0x742c, &[0xe8, 0xce], false),
+    (0x7897, &[0xe8, 0xcf], false),
+    (0x7de9, &[0xe8, 0xd0], false),
+    (0x7feb, &[0xe8, 0xd1], false),
+    (0x8118, &[0xe8, 0xd2], false),
+    (0x8155, &[0xe8, 0xd3], false),
+    (0x839e, &[0xe8, 0xd4], false),
+    (0x8c4c, &[0xe8, 0xd5], false),
+    (0x962e, &[0xe8, 0xd6], false),
+    (0x9811, &[0xe8, 0xd7], false),
+    (0x66f0, &[0xe8, 0xd8], false),
+    (0x5f80, &[0xe8, 0xd9], false),
+    (0x65fa, &[0xe8, 0xda], false),
+    (0x6789, &[0xe8, 0xdb], false),
+    (0x6c6a, &[0xe8, 0xdc], false),
+    (0x738b, &[0xe8, 0xdd], false),
+    (0x502d, &[0xe8, 0xde], false),
+    (0x5a03, &[0xe8, 0xdf], false),
+    (0x6b6a, &[0xe8, 0xe0], false),
+    (0x77ee, &[0xe8, 0xe1], false),
+    (0x5916, &[0xe8, 0xe2], false),
+    (0x5d6c, &[0xe8, 0xe3], false),
+    (0x5dcd, &[0xe8, 0xe4], false),
+    (0x7325, &[0xe8, 0xe5], false),
+    (0x754f, &[0xe8, 0xe6], false),
+    (0xf9ba, &[0xe8, 0xe7], false),
+    (0xf9bb, &[0xe8, 0xe8], false),
+    (0x50e5, &[0xe8, 0xe9], false),
+    (0x51f9, &[0xe8, 0xea], false),
+    (0x582f, &[0xe8, 0xeb], false),
+    (0x592d, &[0xe8, 0xec], false),
+    (0x5996, &[0xe8, 0xed], false),
+    (0x59da, &[0xe8, 0xee], false),
+    (0x5be5, &[0xe8, 0xef], false),
+    (0xf9bc, &[0xe8, 0xf0], false),
+    (0xf9bd, &[0xe8, 0xf1], false),
+    (0x5da2, &[0xe8, 0xf2], false),
+    (0x62d7, &[0xe8, 0xf3], false),
+    (0x6416, &[0xe8, 0xf4], false),
+    (0x6493, &[0xe8, 0xf5], false),
+    (0x64fe, &[0xe8, 0xf6], false),
+    (0xf9be, &[0xe8, 0xf7], false),
+    (0x66dc, &[0xe8, 0xf8], false),
+    (0xf9bf, &[0xe8, 0xf9], false),
+    (0x6a48, &[0xe8, 0xfa], false),
+    (0xf9c0, &[0xe8, 0xfb], false),
+    (0x71ff, &[0xe8, 0xfc], false),
+    (0x7464, &[0xe8, 0xfd], false),
+    (0xf9c1, &[0xe8, 0xfe], false),
+    (0x7a88, &[0xe9, 0xa1], false),
+    (0x7aaf, &[0xe9, 0xa2], false),
+    (0x7e47, &[0xe9, 0xa3], false),
+    (0x7e5e, &[0xe9, 0xa4], false),
+    (0x8000, &[0xe9, 0xa5], false),
+    (0x8170, &[0xe9, 0xa6], false),
+    (0xf9c2, &[0xe9, 0xa7], false),
+    (0x87ef, &[0xe9, 0xa8], false),
+    (0x8981, &[0xe9, 0xa9], false),
+    (0x8b20, &[0xe9, 0xaa], false),
+    (0x9059, &[0xe9, 0xab], false),
+    (0xf9c3, &[0xe9, 0xac], false),
+    (0x9080, &[0xe9, 0xad], false),
+    (0x9952, &[0xe9, 0xae], false),
+    (0x617e, &[0xe9, 0xaf], false),
+    (0x6b32, &[0xe9, 0xb0], false),
+    (0x6d74, &[0xe9, 0xb1], false),
+    (0x7e1f, &[0xe9, 0xb2], false),
+    (0x8925, &[0xe9, 0xb3], false),
+    (0x8fb1, &[0xe9, 0xb4], false),
+    (0x4fd1, &[0xe9, 0xb5], false),
+    (0x50ad, &[0xe9, 0xb6], false),
+    (0x5197, &[0xe9, 0xb7], false),
+    (0x52c7, &[0xe9, 0xb8], false),
+    (0x57c7, &[0xe9, 0xb9], false),
+    (0x5889, &[0xe9, 0xba], false),
+    (0x5bb9, &[0xe9, 0xbb], false),
+    (0x5eb8, &[0xe9, 0xbc], false),
+    (0x6142, &[0xe9, 0xbd], false),
+    (0x6995, &[0xe9, 0xbe], false),
+    (0x6d8c, &[0xe9, 0xbf], false),
+    (0x6e67, &[0xe9, 0xc0], false),
+    (0x6eb6, &[0xe9, 0xc1], false),
+    (0x7194, &[0xe9, 0xc2], false),
+    (0x7462, &[0xe9, 0xc3], false),
+    (0x7528, &[0xe9, 0xc4], false),
+    (0x752c, &[0xe9, 0xc5], false),
+    (0x8073, &[0xe9, 0xc6], false),
+    (0x8338, &[0xe9, 0xc7], false),
+    (0x84c9, &[0xe9, 0xc8], false),
+    (0x8e0a, &[0xe9, 0xc9], false),
+    (0x9394, &[0xe9, 0xca], false),
+    (0x93de, &[0xe9, 0xcb], false),
+    (0xf9c4, &[0xe9, 0xcc], false),
+    (0x4e8e, &[0xe9, 0xcd], false),
+    (0x4f51, &[0xe9, 0xce], false),
+    (0x5076, &[0xe9, 0xcf], false),
+    (0x512a, &[0xe9, 0xd0], false),
+    (0x53c8, &[0xe9, 0xd1], false),
+    (0x53cb, &[0xe9, 0xd2], false),
+    (0x53f3, &[0xe9, 0xd3], false),
+    (0x5b87, &[0xe9, 0xd4], false),
+    (0x5bd3, &[0xe9, 0xd5], false),
+    (0x5c24, &[0xe9, 0xd6], false),
+    (0x611a, &[0xe9, 0xd7], false),
+    (0x6182, &[0xe9, 0xd8], false),
+    (0x65f4, &[0xe9, 0xd9], false),
+    (0x725b, &[0xe9, 0xda], false),
+    (0x7397, &[0xe9, 0xdb], false),
+    (0x7440, &[0xe9, 0xdc], false),
+    (0x76c2, &[0xe9, 0xdd], false),
+    (0x7950, &[0xe9, 0xde], false),
+    (0x7991, &[0xe9, 0xdf], false),
+    (0x79b9, &[0xe9, 0xe0], false),
+    (0x7d06, &[0xe9, 0xe1], false),
+    (0x7fbd, &[0xe9, 0xe2], false),
+    (0x828b, &[0xe9, 0xe3], false),
+    (0x85d5, &[0xe9, 0xe4], false),
+    (0x865e, &[0xe9, 0xe5], false),
+    (0x8fc2, &[0xe9, 0xe6], false),
+    (0x9047, &[0xe9, 0xe7], false),
+    (0x90f5, &[0xe9, 0xe8], false),
+    (0x91ea, &[0xe9, 0xe9], false),
+    (0x9685, &[0xe9, 0xea], false),
+    (0x96e8, &[0xe9, 0xeb], false),
+    (0x96e9, &[0xe9, 0xec], false),
+    (0x52d6, &[0xe9, 0xed], false),
+    (0x5f67, &[0xe9, 0xee], false),
+    (0x65ed, &[0xe9, 0xef], false),
+    (0x6631, &[0xe9, 0xf0], false),
+    (0x682f, &[0xe9, 0xf1], false),
+    (0x715c, &[0xe9, 0xf2], false),
+    (0x7a36, &[0xe9, 0xf3], false),
+    (0x90c1, &[0xe9, 0xf4], false),
+    (0x980a, &[0xe9, 0xf5], false),
+    (0x4e91, &[0xe9, 0xf6], false),
+    (0xf9c5, &[0xe9, 0xf7], false),
+    (0x6a52, &[0xe9, 0xf8], false),
+    (0x6b9e, &[0xe9, 0xf9], false),
+    (0x6f90, &[0xe9, 0xfa], false),
+    (0x7189, &[0xe9, 0xfb], false),
+    (0x8018, &[0xe9, 0xfc], false),
+    (0x82b8, &[0xe9, 0xfd], false),
+    (0x8553, &[0xe9, 0xfe], false),
+    (0x904b, &[0xea, 0xa1], false),
+    (0x9695, &[0xea, 0xa2], false),
+    (0x96f2, &[0xea, 0xa3], false),
+    (0x97fb, &[0xea, 0xa4], false),
+    (0x851a, &[0xea, 0xa5], false),
+    (0x9b31, &[0xea, 0xa6], false),
+    (0x4e90, &[0xea, 0xa7], false),
+    (0x718a, &[0xea, 0xa8], false),
+    (0x96c4, &[0xea, 0xa9], false),
+    (0x5143, &[0xea, 0xaa], false),
+    (0x539f, &[0xea, 0xab], false),
+    (0x54e1, &[0xea, 0xac], false),
+    (0x5713, &[0xea, 0xad], false),
+    (0x5712, &[0xea, 0xae], false),
+    (0x57a3, &[0xea, 0xaf], false),
+    (0x5a9b, &[0xea, 0xb0], false),
+    (0x5ac4, &[0xea, 0xb1], false),
+    (0x5bc3, &[0xea, 0xb2], false),
+    (0x6028, &[0xea, 0xb3], false),
+    (0x613f, &[0xea, 0xb4], false),
+    (0x63f4, &[0xea, 0xb5], false),
+    (0x6c85, &[0xea, 0xb6], false),
+    (0x6d39, &[0xea, 0xb7], false),
+    (0x6e72, &[0xea, 0xb8], false),
+    (0x6e90, &[0xea, 0xb9], false),
+    (0x7230, &[0xea, 0xba], false),
+    (0x733f, &[0xea, 0xbb], false),
+    (0x7457, &[0xea, 0xbc], false),
+    (0x82d1, &[0xea, 0xbd], false),
+    (0x8881, &[0xea, 0xbe], false),
+    (0x8f45, &[0xea, 0xbf], false),
+    (0x9060, &[0xea, 0xc0], false),
+    (0xf9c6, &[0xea, 0xc1], false),
+    (0x9662, &[0xea, 0xc2], false),
+    (0x9858, &[0xea, 0xc3], false),
+    (0x9d1b, &[0xea, 0xc4], false),
+    (0x6708, &[0xea, 0xc5], false),
+    (0x8d8a, &[0xea, 0xc6], false),
+    (0x925e, &[0xea, 0xc7], false),
+    (0x4f4d, &[0xea, 0xc8], false),
+    (0x5049, &[0xea, 0xc9], false),
+    (0x50de, &[0xea, 0xca], false),
+    (0x5371, &[0xea, 0xcb], false),
+    (0x570d, &[0xea, 0xcc], false),
+    (0x59d4, &[0xea, 0xcd], false),
+    (0x5a01, &[0xea, 0xce], false),
+    (0x5c09, &[0xea, 0xcf], false),
+    (0x6170, &[0xea, 0xd0], false),
+    (0x6690, &[0xea, 0xd1], false),
+    (0x6e2d, &[0xea, 0xd2], false),
+    (0x7232, &[0xea, 0xd3], false),
+    (0x744b, &[0xea, 0xd4], false),
+    (0x7def, &[0xea, 0xd5], false),
+    (0x80c3, &[0xea, 0xd6], false),
+    (0x840e, &[0xea, 0xd7], false),
+    (0x8466, &[0xea, 0xd8], false),
+    (0x853f, &[0xea, 0xd9], false),
+    (0x875f, &[0xea, 0xda], false),
+    (0x885b, &[0xea, 0xdb], false),
+    (0x8918, &[0xea, 0xdc], false),
+    (0x8b02, &[0xea, 0xdd], false),
+    (0x9055, &[0xea, 0xde], false),
+    (0x97cb, &[0xea, 0xdf], false),
+    (0x9b4f, &[0xea, 0xe0], false),
+    (0x4e73, &[0xea, 0xe1], false),
+    (0x4f91, &[0xea, 0xe2], false),
+    (0x5112, &[0xea, 0xe3], false),
+    (0x516a, &[0xea, 0xe4], false),
+    (0xf9c7, &[0xea, 0xe5], false),
+    (0x552f, &[0xea, 0xe6], false),
+    (0x55a9, &[0xea, 0xe7], false),
+    (0x5b7a, &[0xea, 0xe8], false),
+    (0x5ba5, &[0xea, 0xe9], false),
+    (0x5e7c, &[0xea, 0xea], false),
+    (0x5e7d, &[0xea, 0xeb], false),
+    (0x5ebe, &[0xea, 0xec], false),
+    (0x60a0, &[0xea, 0xed], false),
+    (0x60df, &[0xea, 0xee], false),
+    (0x6108, &[0xea, 0xef], false),
+    (0x6109, &[0xea, 0xf0], false),
+    (0x63c4, &[0xea, 0xf1], false),
+    (0x6538, &[0xea, 0xf2], false),
+    (0x6709, &[0xea, 0xf3], false),
+    (0xf9c8, &[0xea, 0xf4], false),
+    (0x67d4, &[0xea, 0xf5], false),
+    (0x67da, &[0xea, 0xf6], false),
+    (0xf9c9, &[0xea, 0xf7], false),
+    (0x6961, &[0xea, 0xf8], false),
+    (0x6962, &[0xea, 0xf9], false),
+    (0x6cb9, &[0xea, 0xfa], false),
+    (0x6d27, &[0xea, 0xfb], false),
+    (0xf9ca, &[0xea, 0xfc], false),
+    (0x6e38, &[0xea, 0xfd], false),
+    (0xf9cb, &[0xea, 0xfe], false),
+    (0x6fe1, &[0xeb, 0xa1], false),
+    (0x7336, &[0xeb, 0xa2], false),
+    (0x7337, &[0xeb, 0xa3], false),
+    (0xf9cc, &[0xeb, 0xa4], false),
+    (0x745c, &[0xeb, 0xa5], false),
+    (0x7531, &[0xeb, 0xa6], false),
+    (0xf9cd, &[0xeb, 0xa7], false),
+    (0x7652, &[0xeb, 0xa8], false),
+    (0xf9ce, &[0xeb, 0xa9], false),
+    (0xf9cf, &[0xeb, 0xaa], false),
+    (0x7dad, &[0xeb, 0xab], false),
+    (0x81fe, &[0xeb, 0xac], false),
+    (0x8438, &[0xeb, 0xad], false),
+    (0x88d5, &[0xeb, 0xae], false),
+    (0x8a98, &[0xeb, 0xaf], false),
+    (0x8adb, &[0xeb, 0xb0], false),
+    (0x8aed, &[0xeb, 0xb1], false),
+    (0x8e30, &[0xeb, 0xb2], false),
+    (0x8e42, &[0xeb, 0xb3], false),
+    (0x904a, &[0xeb, 0xb4], false),
+    (0x903e, &[0xeb, 0xb5], false),
+    (0x907a, &[0xeb, 0xb6], false),
+    (0x9149, &[0xeb, 0xb7], false),
+    (0x91c9, &[0xeb, 0xb8], false),
+    (0x936e, &[0xeb, 0xb9], false),
+    (0xf9d0, &[0xeb, 0xba], false),
+    (0xf9d1, &[0xeb, 0xbb], false),
+    (0x5809, &[0xeb, 0xbc], false),
+    (0xf9d2, &[0xeb, 0xbd], false),
+    (0x6bd3, &[0xeb, 0xbe], false),
+    (0x8089, &[0xeb, 0xbf], false),
+    (0x80b2, &[0xeb, 0xc0], false),
+    (0xf9d3, &[0xeb, 0xc1], false),
+    (0xf9d4, &[0xeb, 0xc2], false),
+    (0x5141, &[0xeb, 0xc3], false),
+    (0x596b, &[0xeb, 0xc4], false),
+    (0x5c39, &[0xeb, 0xc5], false),
+    (0xf9d5, &[0xeb, 0xc6], false),
+    (0xf9d6, &[0xeb, 0xc7], false),
+    (0x6f64, &[0xeb, 0xc8], false),
+    (0x73a7, &[0xeb, 0xc9], false),
+    (0x80e4, &[0xeb, 0xca], false),
+    (0x8d07, &[0xeb, 0xcb], false),
+    (0xf9d7, &[0xeb, 0xcc], false),
+    (0x9217, &[0xeb, 0xcd], false),
+    (0x958f, &[0xeb, 0xce], false),
+    (0xf9d8, &[0xeb, 0xcf], false),
+    (0xf9d9, &[0xeb, 0xd0], false),
+    (0xf9da, &[0xeb, 0xd1], false),
+    (0xf9db, &[0xeb, 0xd2], false),
+    (0x807f, &[0xeb, 0xd3], false),
+    (0x620e, &[0xeb, 0xd4], false),
+    (0x701c, &[0xeb, 0xd5], false),
+    (0x7d68, &[0xeb, 0xd6], false),
+    (0x878d, &[0xeb, 0xd7], false),
+    (0xf9dc, &[0xeb, 0xd8], false),
+    (0x57a0, &[0xeb, 0xd9], false),
+    (0x6069, &[0xeb, 0xda], false),
+    (0x6147, &[0xeb, 0xdb], false),
+    (0x6bb7, &[0xeb, 0xdc], false),
+    (0x8abe, &[0xeb, 0xdd], false),
+    (0x9280, &[0xeb, 0xde], false),
+    (0x96b1, &[0xeb, 0xdf], false),
+    (0x4e59, &[0xeb, 0xe0], false),
+    (0x541f, &[0xeb, 0xe1], false),
+    (0x6deb, &[0xeb, 0xe2], false),
+    (0x852d, &[0xeb, 0xe3], false),
+    (0x9670, &[0xeb, 0xe4], false),
+    (0x97f3, &[0xeb, 0xe5], false),
+    (0x98ee, &[0xeb, 0xe6], false),
+    (0x63d6, &[0xeb, 0xe7], false),
+    (0x6ce3, &[0xeb, 0xe8], false),
+    (0x9091, &[0xeb, 0xe9], false),
+    (0x51dd, &[0xeb, 0xea], false),
+    (0x61c9, &[0xeb, 0xeb], false),
+    (0x81ba, &[0xeb, 0xec], false),
+    (0x9df9, &[0xeb, 0xed], false),
+    (0x4f9d, &[0xeb, 0xee], false),
+    (0x501a, &[0xeb, 0xef], false),
+    (0x5100, &[0xeb, 0xf0], false),
+    (0x5b9c, &[0xeb, 0xf1], false),
+    (0x610f, &[0xeb, 0xf2], false),
+    (0x61ff, &[0xeb, 0xf3], false),
+    (0x64ec, &[0xeb, 0xf4], false),
+    (0x6905, &[0xeb, 0xf5], false),
+    (0x6bc5, &[0xeb, 0xf6], false),
+    (0x7591, &[0xeb, 0xf7], false),
+    (0x77e3, &[0xeb, 0xf8], false),
+    (0x7fa9, &[0xeb, 0xf9], false),
+    (0x8264, &[0xeb, 0xfa], false),
+    (0x858f, &[0xeb, 0xfb], false),
+    (0x87fb, &[0xeb, 0xfc], false),
+    (0x8863, &[0xeb, 0xfd], false),
+    (0x8abc, &[0xeb, 0xfe], false),
+    (0x8b70, &[0xec, 0xa1], false),
+    (0x91ab, &[0xec, 0xa2], false),
+    (0x4e8c, &[0xec, 0xa3], false),
+    (0x4ee5, &[0xec, 0xa4], false),
+    (0x4f0a, &[0xec, 0xa5], false),
+    (0xf9dd, &[0xec, 0xa6], false),
+    (0xf9de, &[0xec, 0xa7], false),
+    (0x5937, &[0xec, 0xa8], false),
+    (0x59e8, &[0xec, 0xa9], false),
+    (0xf9df, &[0xec, 0xaa], false),
+    (0x5df2, &[0xec, 0xab], false),
+    (0x5f1b, &[0xec, 0xac], false),
+    (0x5f5b, &[0xec, 0xad], false),
+    (0x6021, &[0xec, 0xae], false),
+    (0xf9e0, &[0xec, 0xaf], false),
+    (0xf9e1, &[0xec, 0xb0], false),
+    (0xf9e2, &[0xec, 0xb1], false),
+    (0xf9e3, &[0xec, 0xb2], false),
+    (0x723e, &[0xec, 0xb3], false),
+    (0x73e5, &[0xec, 0xb4], false),
+    (0xf9e4, &[0xec, 0xb5], false),
+    (0x7570, &[0xec, 0xb6], false),
+    (0x75cd, &[0xec, 0xb7], false),
+    (0xf9e5, &[0xec, 0xb8], false),
+    (0x79fb, &[0xec, 0xb9], false),
+    (0xf9e6, &[0xec, 0xba], false),
+    (0x800c, &[0xec, 0xbb], false),
+    (0x8033, &[0xec, 0xbc], false),
+    (0x8084, &[0xec, 0xbd], false),
+    (0x82e1, &[0xec, 0xbe], false),
+    (0x8351, &[0xec, 0xbf], false),
+    (0xf9e7, &[0xec, 0xc0], false),
+    (0xf9e8, &[0xec, 0xc1], false),
+    (0x8cbd, &[0xec, 0xc2], false),
+    (0x8cb3, &[0xec, 0xc3], false),
+    (0x9087, &[0xec, 0xc4], false),
+    (0xf9e9, &[0xec, 0xc5], false),
+    (0xf9ea, &[0xec, 0xc6], false),
+    (0x98f4, &[0xec, 0xc7], false),
+    (0x990c, &[0xec, 0xc8], false),
+    (0xf9eb, &[0xec, 0xc9], false),
+    (0xf9ec, &[0xec, 0xca], false),
+    (0x7037, &[0xec, 0xcb], false),
+    (0x76ca, &[0xec, 0xcc], false),
+    (0x7fca, &[0xec, 0xcd], false),
+    (0x7fcc, &[0xec, 0xce], false),
+    (0x7ffc, &[0xec, 0xcf], false),
+    (0x8b1a, &[0xec, 0xd0], false),
+    (0x4eba, &[0xec, 0xd1], false),
+    (0x4ec1, &[0xec, 0xd2], false),
+    (0x5203, &[0xec, 0xd3], false),
+    (0x5370, &[0xec, 0xd4], false),
+    (0xf9ed, &[0xec, 0xd5], false),
+    (0x54bd, &[0xec, 0xd6], false),
+    (0x56e0, &[0xec, 0xd7], false),
+    (0x59fb, &[0xec, 0xd8], false),
+    (0x5bc5, &[0xec, 0xd9], false),
+    (0x5f15, &[0xec, 0xda], false),
+    (0x5fcd, &[0xec, 0xdb], false),
+    (0x6e6e, &[0xec, 0xdc], false),
+    (0xf9ee, &[0xec, 0xdd], false),
+    (0xf9ef, &[0xec, 0xde], false),
+    (0x7d6a, &[0xec, 0xdf], false),
+    (0x8335, &[0xec, 0xe0], false),
+    (0xf9f0, &[0xec, 0xe1], false),
+    (0x8693, &[0xec, 0xe2], false),
+    (0x8a8d, &[0xec, 0xe3], false),
+    (0xf9f1, &[0xec, 0xe4], false),
+    (0x976d, &[0xec, 0xe5], false),
+    (0x9777, &[0xec, 0xe6], false),
+    (0xf9f2, &[0xec, 0xe7], false),
+    (0xf9f3, &[0xec, 0xe8], false),
+    (0x4e00, &[0xec, 0xe9], false),
+    (0x4f5a, &[0xec, 0xea], false),
+    (0x4f7e, &[0xec, 0xeb], false),
+    (0x58f9, &[0xec, 0xec], false),
+    (0x65e5, &[0xec, 0xed], false),
+    (0x6ea2, &[0xec, 0xee], false),
+    (0x9038, &[0xec, 0xef], false),
+    (0x93b0, &[0xec, 0xf0], false),
+    (0x99b9, &[0xec, 0xf1], false),
+    (0x4efb, &[0xec, 0xf2], false),
+    (0x58ec, &[0xec, 0xf3], false),
+    (0x598a, &[0xec, 0xf4], false),
+    (0x59d9, &[0xec, 0xf5], false),
+    (0x6041, &[0xec, 0xf6], false),
+    (0xf9f4, &[0xec, 0xf7], false),
+    (0xf9f5, &[0xec, 0xf8], false),
+    (0x7a14, &[0xec, 0xf9], false),
+    (0xf9f6, &[0xec, 0xfa], false),
+    (0x834f, &[0xec, 0xfb], false),
+    (0x8cc3, &[0xec, 0xfc], false),
+    (0x5165, &[0xec, 0xfd], false),
+    (0x5344, &[0xec, 0xfe], false),
+    (0xf9f7, &[0xed, 0xa1], false),
+    (0xf9f8, &[0xed, 0xa2], false),
+    (0xf9f9, &[0xed, 0xa3], false),
+    (0x4ecd, &[0xed, 0xa4], false),
+    (0x5269, &[0xed, 0xa5], false),
+    (0x5b55, &[0xed, 0xa6], false),
+    (0x82bf, &[0xed, 0xa7], false),
+    (0x4ed4, &[0xed, 0xa8], false),
+    (0x523a, &[0xed, 0xa9], false),
+    (0x54a8, &[0xed, 0xaa], false),
+    (0x59c9, &[0xed, 0xab], false),
+    (0x59ff, &[0xed, 0xac], false),
+    (0x5b50, &[0xed, 0xad], false),
+    (0x5b57, &[0xed, 0xae], false),
+    (0x5b5c, &[0xed, 0xaf], false),
+    (0x6063, &[0xed, 0xb0], false),
+    (0x6148, &[0xed, 0xb1], false),
+    (0x6ecb, &[0xed, 0xb2], false),
+    (0x7099, &[0xed, 0xb3], false),
+    (0x716e, &[0xed, 0xb4], false),
+    (0x7386, &[0xed, 0xb5], false),
+    (0x74f7, &[0xed, 0xb6], false),
+    (0x75b5, &[0xed, 0xb7], false),
+    (0x78c1, &[0xed, 0xb8], false),
+    (0x7d2b, &[0xed, 0xb9], false),
+    (0x8005, &[0xed, 0xba], false),
+    (0x81ea, &[0xed, 0xbb], false),
+    (0x8328, &[0xed, 0xbc], false),
+    (0x8517, &[0xed, 0xbd], false),
+    (0x85c9, &[0xed, 0xbe], false),
+    (0x8aee, &[0xed, 0xbf], false),
+    (0x8cc7, &[0xed, 0xc0], false),
+    (0x96cc, &[0xed, 0xc1], false),
+    (0x4f5c, &[0xed, 0xc2], false),
+    (0x52fa, &[0xed, 0xc3], false),
+    (0x56bc, &[0xed, 0xc4], false),
+    (0x65ab, &[0xed, 0xc5], false),
+    (0x6628, &[0xed, 0xc6], false),
+    (0x707c, &[0xed, 0xc7], false),
+    (0x70b8, &[0xed, 0xc8], false),
+    (0x7235, &[0xed, 0xc9], false),
+    (0x7dbd, &[0xed, 0xca], false),
+    (0x828d, &[0xed, 0xcb], false),
+    (0x914c, &[0xed, 0xcc], false),
+    (0x96c0, &[0xed, 0xcd], false),
+    (0x9d72, &[0xed, 0xce], false),
+    (0x5b71, &[0xed, 0xcf], false),
+    (0x68e7, &[0xed, 0xd0], false),
+    (0x6b98, &[0xed, 0xd1], false),
+    (0x6f7a, &[0xed, 0xd2], false),
+    (0x76de, &[0xed, 0xd3], false),
+    (0x5c91, &[0xed, 0xd4], false),
+    (0x66ab, &[0xed, 0xd5], false),
+    (0x6f5b, &[0xed, 0xd6], false),
+    (0x7bb4, &[0xed, 0xd7], false),
+    (0x7c2a, &[0xed, 0xd8], false),
+    (0x8836, &[0xed, 0xd9], false),
+    (0x96dc, &[0xed, 0xda], false),
+    (0x4e08, &[0xed, 0xdb], false),
+    (0x4ed7, &[0xed, 0xdc], false),
+    (0x5320, &[0xed, 0xdd], false),
+    (0x5834, &[0xed, 0xde], false),
+    (0x58bb, &[0xed, 0xdf], false),
+    (0x58ef, &[0xed, 0xe0], false),
+    (0x596c, &[0xed, 0xe1], false),
+    (0x5c07, &[0xed, 0xe2], false),
+    (0x5e33, &[0xed, 0xe3], false),
+    (0x5e84, &[0xed, 0xe4], false),
+    (0x5f35, &[0xed, 0xe5], false),
+    (0x638c, &[0xed, 0xe6], false),
+    (0x66b2, &[0xed, 0xe7], false),
+    (0x6756, &[0xed, 0xe8], false),
+    (0x6a1f, &[0xed, 0xe9], false),
+    (0x6aa3, &[0xed, 0xea], false),
+    (0x6b0c, &[0xed, 0xeb], false),
+    (0x6f3f, &[0xed, 0xec], false),
+    (0x7246, &[0xed, 0xed], false),
+    (0xf9fa, &[0xed, 0xee], false),
+    (0x7350, &[0xed, 0xef], false),
+    (0x748b, &[0xed, 0xf0], false),
+    (0x7ae0, &[0xed, 0xf1], false),
+    (0x7ca7, &[0xed, 0xf2], false),
+    (0x8178, &[0xed, 0xf3], false),
+    (0x81df, &[0xed, 0xf4], false),
+    (0x81e7, &[0xed, 0xf5], false),
+    (0x838a, &[0xed, 0xf6], false),
+    (0x846c, &[0xed, 0xf7], false),
+    (0x8523, &[0xed, 0xf8], false),
+    (0x8594, &[0xed, 0xf9], false),
+    (0x85cf, &[0xed, 0xfa], false),
+    (0x88dd, &[0xed, 0xfb], false),
+    (0x8d13, &[0xed, 0xfc], false),
+    (0x91ac, &[0xed, 0xfd], false),
+    (0x9577, &[0xed, 0xfe], false),
+    (0x969c, &[0xee, 0xa1], false),
+    (0x518d, &[0xee, 0xa2], false),
+    (0x54c9, &[0xee, 0xa3], false),
+    (0x5728, &[0xee, 0xa4], false),
+    (0x5bb0, &[0xee, 0xa5], false),
+    (0x624d, &[0xee, 0xa6], false),
+    (0x6750, &[0xee, 0xa7], false),
+    (0x683d, &[0xee, 0xa8], false),
+    (0x6893, &[0xee, 0xa9], false),
+    (0x6e3d, &[0xee, 0xaa], false),
+    (0x6ed3, &[0xee, 0xab], false),
+    (0x707d, &[0xee, 0xac], false),
+    (0x7e21, &[0xee, 0xad], false),
+    (0x88c1, &[0xee, 0xae], false),
+    (0x8ca1, &[0xee, 0xaf], false),
+    (0x8f09, &[0xee, 0xb0], false),
+    (0x9f4b, &[0xee, 0xb1], false),
+    (0x9f4e, &[0xee, 0xb2], false),
+    (0x722d, &[0xee, 0xb3], false),
+    (0x7b8f, &[0xee, 0xb4], false),
+    (0x8acd, &[0xee, 0xb5], false),
+    (0x931a, &[0xee, 0xb6], false),
+    (0x4f47, &[0xee, 0xb7], false),
+    (0x4f4e, &[0xee, 0xb8], false),
+    (0x5132, &[0xee, 0xb9], false),
+    (0x5480, &[0xee, 0xba], false),
+    (0x59d0, &[0xee, 0xbb], false),
+    (0x5e95, &[0xee, 0xbc], false),
+    (0x62b5, &[0xee, 0xbd], false),
+    (0x6775, &[0xee, 0xbe], false),
+    (0x696e, &[0xee, 0xbf], false),
+    (0x6a17, &[0xee, 0xc0], false),
+    (0x6cae, &[0xee, 0xc1], false),
+    (0x6e1a, &[0xee, 0xc2], false),
+    (0x72d9, &[0xee, 0xc3], false),
+    (0x732a, &[0xee, 0xc4], false),
+    (0x75bd, &[0xee, 0xc5], false),
+    (0x7bb8, &[0xee, 0xc6], false),
+    (0x7d35, &[0xee, 0xc7], false),
+    (0x82e7, &[0xee, 0xc8], false),
+    (0x83f9, &[0xee, 0xc9], false),
+    (0x8457, &[0xee, 0xca], false),
+    (0x85f7, &[0xee, 0xcb], false),
+    (0x8a5b, &[0xee, 0xcc], false),
+    (0x8caf, &[0xee, 0xcd], false),
+    (0x8e87, &[0xee, 0xce], false),
+    (0x9019, &[0xee, 0xcf], false),
+    (0x90b8, &[0xee, 0xd0], false),
+    (0x96ce, &[0xee, 0xd1], false),
+    (0x9f5f, &[0xee, 0xd2], false),
+    (0x52e3, &[0xee, 0xd3], false),
+    (0x540a, &[0xee, 0xd4], false),
+    (0x5ae1, &[0xee, 0xd5], false),
+    (0x5bc2, &[0xee, 0xd6], false),
+    (0x6458, &[0xee, 0xd7], false),
+    (0x6575, &[0xee, 0xd8], false),
+    (0x6ef4, &[0xee, 0xd9], false),
+    (0x72c4, &[0xee, 0xda], false),
+    (0xf9fb, &[0xee, 0xdb], false),
+    (0x7684, &[0xee, 0xdc], false),
+    (0x7a4d, &[0xee, 0xdd], false),
+    (0x7b1b, &[0xee, 0xde], false),
+    (0x7c4d, &[0xee, 0xdf], false),
+    (0x7e3e, &[0xee, 0xe0], false),
+    (0x7fdf, &[0xee, 0xe1], false),
+    (0x837b, &[0xee, 0xe2], false),
+    (0x8b2b, &[0xee, 0xe3], false),
+    (0x8cca, &[0xee, 0xe4], false),
+    (0x8d64, &[0xee, 0xe5], false),
+    (0x8de1, &[0xee, 0xe6], false),
+    (0x8e5f, &[0xee, 0xe7], false),
+    (0x8fea, &[0xee, 0xe8], false),
+    (0x8ff9, &[0xee, 0xe9], false),
+    (0x9069, &[0xee, 0xea], false),
+    (0x93d1, &[0xee, 0xeb], false),
+    (0x4f43, &[0xee, 0xec], false),
+    (0x4f7a, &[0xee, 0xed], false),
+    (0x50b3, &[0xee, 0xee], false),
+    (0x5168, &[0xee, 0xef], false),
+    (0x5178, &[0xee, 0xf0], false),
+    (0x524d, &[0xee, 0xf1], false),
+    (0x526a, &[0xee, 0xf2], false),
+    (0x5861, &[0xee, 0xf3], false),
+    (0x587c, &[0xee, 0xf4], false),
+    (0x5960, &[0xee, 0xf5], false),
+    (0x5c08, &[0xee, 0xf6], false),
+    (0x5c55, &[0xee, 0xf7], false),
+    (0x5edb, &[0xee, 0xf8], false),
+    (0x609b, &[0xee, 0xf9], false),
+    (0x6230, &[0xee, 0xfa], false),
+    (0x6813, &[0xee, 0xfb], false),
+    (0x6bbf, &[0xee, 0xfc], false),
+    (0x6c08, &[0xee, 0xfd], false),
+    (0x6fb1, &[0xee, 0xfe], false),
+    (0x714e, &[0xef, 0xa1], false),
+    (0x7420, &[0xef, 0xa2], false),
+    (0x7530, &[0xef, 0xa3], false),
+    (0x7538, &[0xef, 0xa4], false),
+    (0x7551, &[0xef, 0xa5], false),
+    (0x7672, &[0xef, 0xa6], false),
+    (0x7b4c, &[0xef, 0xa7], false),
+    (0x7b8b, &[0xef, 0xa8], false),
+    (0x7bad, &[0xef, 0xa9], false),
+    (0x7bc6, &[0xef, 0xaa], false),
+    (0x7e8f, &[0xef, 0xab], false),
+    (0x8a6e, &[0xef, 0xac], false),
+    (0x8f3e, &[0xef, 0xad], false),
+    (0x8f49, &[0xef, 0xae], false),
+    (0x923f, &[0xef, 0xaf], false),
+    (0x9293, &[0xef, 0xb0], false),
+    (0x9322, &[0xef, 0xb1], false),
+    (0x942b, &[0xef, 0xb2], false),
+    (0x96fb, &[0xef, 0xb3], false),
+    (0x985a, &[0xef, 0xb4], false),
+    (0x986b, &[0xef, 0xb5], false),
+    (0x991e, &[0xef, 0xb6], false),
+    (0x5207, &[0xef, 0xb7], false),
+    (0x622a, &[0xef, 0xb8], false),
+    (0x6298, &[0xef, 0xb9], false),
+    (0x6d59, &[0xef, 0xba], false),
+    (0x7664, &[0xef, 0xbb], false),
+    (0x7aca, &[0xef, 0xbc], false),
+    (0x7bc0, &[0xef, 0xbd], false),
+    (0x7d76, &[0xef, 0xbe], false),
+    (0x5360, &[0xef, 0xbf], false),
+    (0x5cbe, &[0xef, 0xc0], false),
+    (0x5e97, &[0xef, 0xc1], false),
+    (0x6f38, &[0xef, 0xc2], false),
+    (0x70b9, &[0xef, 0xc3], false),
+    (0x7c98, &[0xef, 0xc4], false),
+    (0x9711, &[0xef, 0xc5], false),
+    (0x9b8e, &[0xef, 0xc6], false),
+    (0x9ede, &[0xef, 0xc7], false),
+    (0x63a5, &[0xef, 0xc8], false),
+    (0x647a, &[0xef, 0xc9], false),
+    (0x8776, &[0xef, 0xca], false),
+    (0x4e01, &[0xef, 0xcb], false),
+    (0x4e95, &[0xef, 0xcc], false),
+    (0x4ead, &[0xef, 0xcd], false),
+    (0x505c, &[0xef, 0xce], false),
+    (0x5075, &[0xef, 0xcf], false),
+    (0x5448, &[0xef, 0xd0], false),
+    (0x59c3, &[0xef, 0xd1], false),
+    (0x5b9a, &[0xef, 0xd2], false),
+    (0x5e40, &[0xef, 0xd3], false),
+    (0x5ead, &[0xef, 0xd4], false),
+    (0x5ef7, &[0xef, 0xd5], false),
+    (0x5f81, &[0xef, 0xd6], false),
+    (0x60c5, &[0xef, 0xd7], false),
+    (0x633a, &[0xef, 0xd8], false),
+    (0x653f, &[0xef, 0xd9], false),
+    (0x6574, &[0xef, 0xda], false),
+    (0x65cc, &[0xef, 0xdb], false),
+    (0x6676, &[0xef, 0xdc], false),
+    (0x6678, &[0xef, 0xdd], false),
+    (0x67fe, &[0xef, 0xde], false),
+    (0x6968, &[0xef, 0xdf], false),
+    (0x6a89, &[0xef, 0xe0], false),
+    (0x6b63, &[0xef, 0xe1], false),
+    (0x6c40, &[0xef, 0xe2], false),
+    (0x6dc0, &[0xef, 0xe3], false),
+    (0x6de8, &[0xef, 0xe4], false),
+    (0x6e1f, &[0xef, 0xe5], false),
+    (0x6e5e, &[0xef, 0xe6], false),
+    (0x701e, &[0xef, 0xe7], false),
+    (0x70a1, &[0xef, 0xe8], false),
+    (0x738e, &[0xef, 0xe9], false),
+    (0x73fd, &[0xef, 0xea], false),
+    (0x753a, &[0xef, 0xeb], false),
+    (0x775b, &[0xef, 0xec], false),
+    (0x7887, &[0xef, 0xed], false),
+    (0x798e, &[0xef, 0xee], false),
+    (0x7a0b, &[0xef, 0xef], false),
+    (0x7a7d, &[0xef, 0xf0], false),
+    (0x7cbe, &[0xef, 0xf1], false),
+    (0x7d8e, &[0xef, 0xf2], false),
+    (0x8247, &[0xef, 0xf3], false),
+    (0x8a02, &[0xef, 0xf4], false),
+    (0x8aea, &[0xef, 0xf5], false),
+    (0x8c9e, &[0xef, 0xf6], false),
+    (0x912d, &[0xef, 0xf7], false),
+    (0x914a, &[0xef, 0xf8], false),
+    (0x91d8, &[0xef, 0xf9], false),
+    (0x9266, &[0xef, 0xfa], false),
+    (0x92cc, &[0xef, 0xfb], false),
+    (0x9320, &[0xef, 0xfc], false),
+    (0x9706, &[0xef, 0xfd], false),
+    (0x9756, &[0xef, 0xfe], false),
+    (0x975c, &[0xf0, 0xa1], false),
+    (0x9802, &[0xf0, 0xa2], false),
+    (0x9f0e, &[0xf0, 0xa3], false),
+    (0x5236, &[0xf0, 0xa4], false),
+    (0x5291, &[0xf0, 0xa5], false),
+    (0x557c, &[0xf0, 0xa6], false),
+    (0x5824, &[0xf0, 0xa7], false),
+    (0x5e1d, &[0xf0, 0xa8], false),
+    (0x5f1f, &[0xf0, 0xa9], false),
+    (0x608c, &[0xf0, 0xaa], false),
+    (0x63d0, &[0xf0, 0xab], false),
+    (0x68af, &[0xf0, 0xac], false),
+    (0x6fdf, &[0xf0, 0xad], false),
+    (0x796d, &[0xf0, 0xae], false),
+    (0x7b2c, &[0xf0, 0xaf], false),
+    (0x81cd, &[0xf0, 0xb0], false),
+    (0x85ba, &[0xf0, 0xb1], false),
+    (0x88fd, &[0xf0, 0xb2], false),
+    (0x8af8, &[0xf0, 0xb3], false),
+    (0x8e44, &[0xf0, 0xb4], false),
+    (0x918d, &[0xf0, 0xb5], false),
+    (0x9664, &[0xf0, 0xb6], false),
+    (0x969b, &[0xf0, 0xb7], false),
+    (0x973d, &[0xf0, 0xb8], false),
+    (0x984c, &[0xf0, 0xb9], false),
+    (0x9f4a, &[0xf0, 0xba], false),
+    (0x4fce, &[0xf0, 0xbb], false),
+    (0x5146, &[0xf0, 0xbc], false),
+    (0x51cb, &[0xf0, 0xbd], false),
+    (0x52a9, &[0xf0, 0xbe], false),
+    (0x5632, &[0xf0, 0xbf], false),
+    (0x5f14, &[0xf0, 0xc0], false),
+    (0x5f6b, &[0xf0, 0xc1], false),
+    (0x63aa, &[0xf0, 0xc2], false),
+    (0x64cd, &[0xf0, 0xc3], false),
+    (0x65e9, &[0xf0, 0xc4], false),
+    (0x6641, &[0xf0, 0xc5], false),
+    (0x66fa, &[0xf0, 0xc6], false),
+    (0x66f9, &[0xf0, 0xc7], false),
+    (0x671d, &[0xf0, 0xc8], false),
+    (0x689d, &[0xf0, 0xc9], false),
+    (0x68d7, &[0xf0, 0xca], false),
+    (0x69fd, &[0xf0, 0xcb], false),
+    (0x6f15, &[0xf0, 0xcc], false),
+    (0x6f6e, &[0xf0, 0xcd], false),
+    (0x7167, &[0xf0, 0xce], false),
+    (0x71e5, &[0xf0, 0xcf], false),
+    (0x722a, &[0xf0, 0xd0], false),
+    (0x74aa, &[0xf0, 0xd1], false),
+    (0x773a, &[0xf0, 0xd2], false),
+    (0x7956, &[0xf0, 0xd3], false),
+    (0x795a, &[0xf0, 0xd4], false),
+    (0x79df, &[0xf0, 0xd5], false),
+    (0x7a20, &[0xf0, 0xd6], false),
+    (0x7a95, &[0xf0, 0xd7], false),
+    (0x7c97, &[0xf0, 0xd8], false),
+    (0x7cdf, &[0xf0, 0xd9], false),
+    (0x7d44, &[0xf0, 0xda], false),
+    (0x7e70, &[0xf0, 0xdb], false),
+    (0x8087, &[0xf0, 0xdc], false),
+    (0x85fb, &[0xf0, 0xdd], false),
+    (0x86a4, &[0xf0, 0xde], false),
+    (0x8a54, &[0xf0, 0xdf], false),
+    (0x8abf, &[0xf0, 0xe0], false),
+    (0x8d99, &[0xf0, 0xe1], false),
+    (0x8e81, &[0xf0, 0xe2], false),
+    (0x9020, &[0xf0, 0xe3], false),
+    (0x906d, &[0xf0, 0xe4], false),
+    (0x91e3, &[0xf0, 0xe5], false),
+    (0x963b, &[0xf0, 0xe6], false),
+    (0x96d5, &[0xf0, 0xe7], false),
+    (0x9ce5, &[0xf0, 0xe8], false),
+    (0x65cf, &[0xf0, 0xe9], false),
+    (0x7c07, &[0xf0, 0xea], false),
+    (0x8db3, &[0xf0, 0xeb], false),
+    (0x93c3, &[0xf0, 0xec], false),
+    (0x5b58, &[0xf0, 0xed], false),
+    (0x5c0a, &[0xf0, 0xee], false),
+    (0x5352, &[0xf0, 0xef], false),
+    (0x62d9, &[0xf0, 0xf0], false),
+    (0x731d, &[0xf0, 0xf1], false),
+    (0x5027, &[0xf0, 0xf2], false),
+    (0x5b97, &[0xf0, 0xf3], false),
+    (0x5f9e, &[0xf0, 0xf4], false),
+    (0x60b0, &[0xf0, 0xf5], false),
+    (0x616b, &[0xf0, 0xf6], false),
+    (0x68d5, &[0xf0, 0xf7], false),
+    (0x6dd9, &[0xf0, 0xf8], false),
+    (0x742e, &[0xf0, 0xf9], false),
+    (0x7a2e, &[0xf0, 0xfa], false),
+    (0x7d42, &[0xf0, 0xfb], false),
+    (0x7d9c, &[0xf0, 0xfc], false),
+    (0x7e31, &[0xf0, 0xfd], false),
+    (0x816b, &[0xf0, 0xfe], false),
+    (0x8e2a, &[0xf1, 0xa1], false),
+    (0x8e35, &[0xf1, 0xa2], false),
+    (0x937e, &[0xf1, 0xa3], false),
+    (0x9418, &[0xf1, 0xa4], false),
+    (0x4f50, &[0xf1, 0xa5], false),
+    (0x5750, &[0xf1, 0xa6], false),
+    (0x5de6, &[0xf1, 0xa7], false),
+    (0x5ea7, &[0xf1, 0xa8], false),
+    (0x632b, &[0xf1, 0xa9], false),
+    (0x7f6a, &[0xf1, 0xaa], false),
+    (0x4e3b, &[0xf1, 0xab], false),
+    (0x4f4f, &[0xf1, 0xac], false),
+    (0x4f8f, &[0xf1, 0xad], false),
+    (0x505a, &[0xf1, 0xae], false),
+    (0x59dd, &[0xf1, 0xaf], false),
+    (0x80c4, &[0xf1, 0xb0], false),
+    (0x546a, &[0xf1, 0xb1], false),
+    (0x5468, &[0xf1, 0xb2], false),
+    (0x55fe, &[0xf1, 0xb3], false),
+    (0x594f, &[0xf1, 0xb4], false),
+    (0x5b99, &[0xf1, 0xb5], false),
+    (0x5dde, &[0xf1, 0xb6], false),
+    (0x5eda, &[0xf1, 0xb7], false),
+    (0x665d, &[0xf1, 0xb8], false),
+    (0x6731, &[0xf1, 0xb9], false),
+    (0x67f1, &[0xf1, 0xba], false),
+    (0x682a, &[0xf1, 0xbb], false),
+    (0x6ce8, &[0xf1, 0xbc], false),
+    (0x6d32, &[0xf1, 0xbd], false),
+    (0x6e4a, &[0xf1, 0xbe], false),
+    (0x6f8d, &[0xf1, 0xbf], false),
+    (0x70b7, &[0xf1, 0xc0], false),
+    (0x73e0, &[0xf1, 0xc1], false),
+    (0x7587, &[0xf1, 0xc2], false),
+    (0x7c4c, &[0xf1, 0xc3], false),
+    (0x7d02, &[0xf1, 0xc4], false),
+    (0x7d2c, &[0xf1, 0xc5], false),
+    (0x7da2, &[0xf1, 0xc6], false),
+    (0x821f, &[0xf1, 0xc7], false),
+    (0x86db, &[0xf1, 0xc8], false),
+    (0x8a3b, &[0xf1, 0xc9], false),
+    (0x8a85, &[0xf1, 0xca], false),
+    (0x8d70, &[0xf1, 0xcb], false),
+    (0x8e8a, &[0xf1, 0xcc], false),
+    (0x8f33, &[0xf1, 0xcd], false),
+    (0x9031, &[0xf1, 0xce], false),
+    (0x914e, &[0xf1, 0xcf], false),
+    (0x9152, &[0xf1, 0xd0], false),
+    (0x9444, &[0xf1, 0xd1], false),
+    (0x99d0, &[0xf1, 0xd2], false),
+    (0x7af9, &[0xf1, 0xd3], false),
+    (0x7ca5, &[0xf1, 0xd4], false),
+    (0x4fca, &[0xf1, 0xd5], false),
+    (0x5101, &[0xf1, 0xd6], false),
+    (0x51c6, &[0xf1, 0xd7], false),
+    (0x57c8, &[0xf1, 0xd8], false),
+    (0x5bef, &[0xf1, 0xd9], false),
+    (0x5cfb, &[0xf1, 0xda], false),
+    (0x6659, &[0xf1, 0xdb], false),
+    (0x6a3d, &[0xf1, 0xdc], false),
+    (0x6d5a, &[0xf1, 0xdd], false),
+    (0x6e96, &[0xf1, 0xde], false),
+    (0x6fec, &[0xf1, 0xdf], false),
+    (0x710c, &[0xf1, 0xe0], false),
+    (0x756f, &[0xf1, 0xe1], false),
+    (0x7ae3, &[0xf1, 0xe2], false),
+    (0x8822, &[0xf1, 0xe3], false),
+    (0x9021, &[0xf1, 0xe4], false),
+    (0x9075, &[0xf1, 0xe5], false),
+    (0x96cb, &[0xf1, 0xe6], false),
+    (0x99ff, &[0xf1, 0xe7], false),
+    (0x8301, &[0xf1, 0xe8], false),
+    (0x4e2d, &[0xf1, 0xe9], false),
+    (0x4ef2, &[0xf1, 0xea], false),
+    (0x8846, &[0xf1, 0xeb], false),
+    (0x91cd, &[0xf1, 0xec], false),
+    (0x537d, &[0xf1, 0xed], false),
+    (0x6adb, &[0xf1, 0xee], false),
+    (0x696b, &[0xf1, 0xef], false),
+    (0x6c41, &[0xf1, 0xf0], false),
+    (0x847a, &[0xf1, 0xf1], false),
+    (0x589e, &[0xf1, 0xf2], false),
+    (0x618e, &[0xf1, 0xf3], false),
+    (0x66fe, &[0xf1, 0xf4], false),
+    (0x62ef, &[0xf1, 0xf5], false),
+    (0x70dd, &[0xf1, 0xf6], false),
+    (0x7511, &[0xf1, 0xf7], false),
+    (0x75c7, &[0xf1, 0xf8], false),
+    (0x7e52, &[0xf1, 0xf9], false),
+    (0x84b8, &[0xf1, 0xfa], false),
+    (0x8b49, &[0xf1, 0xfb], false),
+    (0x8d08, &[0xf1, 0xfc], false),
+    (0x4e4b, &[0xf1, 0xfd], false),
+    (0x53ea, &[0xf1, 0xfe], false),
+    (0x54ab, &[0xf2, 0xa1], false),
+    (0x5730, &[0xf2, 0xa2], false),
+    (0x5740, &[0xf2, 0xa3], false),
+    (0x5fd7, &[0xf2, 0xa4], false),
+    (0x6301, &[0xf2, 0xa5], false),
+    (0x6307, &[0xf2, 0xa6], false),
+    (0x646f, &[0xf2, 0xa7], false),
+    (0x652f, &[0xf2, 0xa8], false),
+    (0x65e8, &[0xf2, 0xa9], false),
+    (0x667a, &[0xf2, 0xaa], false),
+    (0x679d, &[0xf2, 0xab], false),
+    (0x67b3, &[0xf2, 0xac], false),
+    (0x6b62, &[0xf2, 0xad], false),
+    (0x6c60, &[0xf2, 0xae], false),
+    (0x6c9a, &[0xf2, 0xaf], false),
+    (0x6f2c, &[0xf2, 0xb0], false),
+    (0x77e5, &[0xf2, 0xb1], false),
+    (0x7825, &[0xf2, 0xb2], false),
+    (0x7949, &[0xf2, 0xb3], false),
+    (0x7957, &[0xf2, 0xb4], false),
+    (0x7d19, &[0xf2, 0xb5], false),
+    (0x80a2, &[0xf2, 0xb6], false),
+    (0x8102, &[0xf2, 0xb7], false),
+    (0x81f3, &[0xf2, 0xb8], false),
+    (0x829d, &[0xf2, 0xb9], false),
+    (0x82b7, &[0xf2, 0xba], false),
+    (0x8718, &[0xf2, 0xbb], false),
+    (0x8a8c, &[0xf2, 0xbc], false),
+    (0xf9fc, &[0xf2, 0xbd], false),
+    (0x8d04, &[0xf2, 0xbe], false),
+    (0x8dbe, &[0xf2, 0xbf], false),
+    (0x9072, &[0xf2, 0xc0], false),
+    (0x76f4, &[0xf2, 0xc1], false),
+    (0x7a19, &[0xf2, 0xc2], false),
+    (0x7a37, &[0xf2, 0xc3], false),
+    (0x7e54, &[0xf2, 0xc4], false),
+    (0x8077, &[0xf2, 0xc5], false),
+    (0x5507, &[0xf2, 0xc6], false),
+    (0x55d4, &[0xf2, 0xc7], false),
+    (0x5875, &[0xf2, 0xc8], false),
+    (0x632f, &[0xf2, 0xc9], false),
+    (0x6422, &[0xf2, 0xca], false),
+    (0x6649, &[0xf2, 0xcb], false),
+    (0x664b, &[0xf2, 0xcc], false),
+    (0x686d, &[0xf2, 0xcd], false),
+    (0x699b, &[0xf2, 0xce], false),
+    (0x6b84, &[0xf2, 0xcf], false),
+    (0x6d25, &[0xf2, 0xd0], false),
+    (0x6eb1, &[0xf2, 0xd1], false),
+    (0x73cd, &[0xf2, 0xd2], false),
+    (0x7468, &[0xf2, 0xd3], false),
+    (0x74a1, &[0xf2, 0xd4], false),
+    (0x755b, &[0xf2, 0xd5], false),
+    (0x75b9, &[0xf2, 0xd6], false),
+    (0x76e1, &[0xf2, 0xd7], false),
+    (0x771e, &[0xf2, 0xd8], false),
+    (0x778b, &[0xf2, 0xd9], false),
+    (0x79e6, &[0xf2, 0xda], false),
+    (0x7e09, &[0xf2, 0xdb], false),
+    (0x7e1d, &[0xf2, 0xdc], false),
+    (0x81fb, &[0xf2, 0xdd], false),
+    (0x852f, &[0xf2, 0xde], false),
+    (0x8897, &[0xf2, 0xdf], false),
+    (0x8a3a, &[0xf2, 0xe0], false),
+    (0x8cd1, &[0xf2, 0xe1], false),
+    (0x8eeb, &[0xf2, 0xe2], false),
+    (0x8fb0, &[0xf2, 0xe3], false),
+    (0x9032, &[0xf2, 0xe4], false),
+    (0x93ad, &[0xf2, 0xe5], false),
+    (0x9663, &[0xf2, 0xe6], false),
+    (0x9673, &[0xf2, 0xe7], false),
+    (0x9707, &[0xf2, 0xe8], false),
+    (0x4f84, &[0xf2, 0xe9], false),
+    (0x53f1, &[0xf2, 0xea], false),
+    (0x59ea, &[0xf2, 0xeb], false),
+    (0x5ac9, &[0xf2, 0xec], false),
+    (0x5e19, &[0xf2, 0xed], false),
+    (0x684e, &[0xf2, 0xee], false),
+    (0x74c6, &[0xf2, 0xef], false),
+    (0x75be, &[0xf2, 0xf0], false),
+    (0x79e9, &[0xf2, 0xf1], false),
+    (0x7a92, &[0xf2, 0xf2], false),
+    (0x81a3, &[0xf2, 0xf3], false),
+    (0x86ed, &[0xf2, 0xf4], false),
+    (0x8cea, &[0xf2, 0xf5], false),
+    (0x8dcc, &[0xf2, 0xf6], false),
+    (0x8fed, &[0xf2, 0xf7], false),
+    (0x659f, &[0xf2, 0xf8], false),
+    (0x6715, &[0xf2, 0xf9], false),
+    (0xf9fd, &[0xf2, 0xfa], false),
+    (0x57f7, &[0xf2, 0xfb], false),
+    (0x6f57, &[0xf2, 0xfc], false),
+    (0x7ddd, &[0xf2, 0xfd], false),
+    (0x8f2f, &[0xf2, 0xfe], false),
+    (0x93f6, &[0xf3, 0xa1], false),
+    (0x96c6, &[0xf3, 0xa2], false),
+    (0x5fb5, &[0xf3, 0xa3], false),
+    (0x61f2, &[0xf3, 0xa4], false),
+    (0x6f84, &[0xf3, 0xa5], false),
+    (0x4e14, &[0xf3, 0xa6], false),
+    (0x4f98, &[0xf3, 0xa7], false),
+    (0x501f, &[0xf3, 0xa8], false),
+    (0x53c9, &[0xf3, 0xa9], false),
+    (0x55df, &[0xf3, 0xaa], false),
+    (0x5d6f, &[0xf3, 0xab], false),
+    (0x5dee, &[0xf3, 0xac], false),
+    (0x6b21, &[0xf3, 0xad], false),
+    (0x6b64, &[0xf3, 0xae], false),
+    (0x78cb, &[0xf3, 0xaf], false),
+    (0x7b9a, &[0xf3, 0xb0], false),
+    (0xf9fe, &[0xf3, 0xb1], false),
+    (0x8e49, &[0xf3, 0xb2], false),
+    (0x8eca, &[0xf3, 0xb3], false),
+    (0x906e, &[0xf3, 0xb4], false),
+    (0x6349, &[0xf3, 0xb5], false),
+    (0x643e, &[0xf3, 0xb6], false),
+    (0x7740, &[0xf3, 0xb7], false),
+    (0x7a84, &[0xf3, 0xb8], false),
+    (0x932f, &[0xf3, 0xb9], false),
+    (0x947f, &[0xf3, 0xba], false),
+    (0x9f6a, &[0xf3, 0xbb], false),
+    (0x64b0, &[0xf3, 0xbc], false),
+    (0x6faf, &[0xf3, 0xbd], false),
+    (0x71e6, &[0xf3, 0xbe], false),
+    (0x74a8, &[0xf3, 0xbf], false),
+    (0x74da, &[0xf3, 0xc0], false),
+    (0x7ac4, &[0xf3, 0xc1], false),
+    (0x7c12, &[0xf3, 0xc2], false),
+    (0x7e82, &[0xf3, 0xc3], false),
+    (0x7cb2, &[0xf3, 0xc4], false),
+    (0x7e98, &[0xf3, 0xc5], false),
+    (0x8b9a, &[0xf3, 0xc6], false),
+    (0x8d0a, &[0xf3, 0xc7], false),
+    (0x947d, &[0xf3, 0xc8], false),
+    (0x9910, &[0xf3, 0xc9], false),
+    (0x994c, &[0xf3, 0xca], false),
+    (0x5239, &[0xf3, 0xcb], false),
+    (0x5bdf, &[0xf3, 0xcc], false),
+    (0x64e6, &[0xf3, 0xcd], false),
+    (0x672d, &[0xf3, 0xce], false),
+    (0x7d2e, &[0xf3, 0xcf], false),
+    (0x50ed, &[0xf3, 0xd0], false),
+    (0x53c3, &[0xf3, 0xd1], false),
+    (0x5879, &[0xf3, 0xd2], false),
+    (0x6158, &[0xf3, 0xd3], false),
+    (0x6159, &[0xf3, 0xd4], false),
+    (0x61fa, &[0xf3, 0xd5], false),
+    (0x65ac, &[0xf3, 0xd6], false),
+    (0x7ad9, &[0xf3, 0xd7], false),
+    (0x8b92, &[0xf3, 0xd8], false),
+    (0x8b96, &[0xf3, 0xd9], false),
+    (0x5009, &[0xf3, 0xda], false),
+    (0x5021, &[0xf3, 0xdb], false),
+    (0x5275, &[0xf3, 0xdc], false),
+    (0x5531, &[0xf3, 0xdd], false),
+    (0x5a3c, &[0xf3, 0xde], false),
+    (0x5ee0, &[0xf3, 0xdf], false),
+    (0x5f70, &[0xf3, 0xe0], false),
+    (0x6134, &[0xf3, 0xe1], false),
+    (0x655e, &[0xf3, 0xe2], false),
+    (0x660c, &[0xf3, 0xe3], false),
+    (0x6636, &[0xf3, 0xe4], false),
+    (0x66a2, &[0xf3, 0xe5], false),
+    (0x69cd, &[0xf3, 0xe6], false),
+    (0x6ec4, &[0xf3, 0xe7], false),
+    (0x6f32, &[0xf3, 0xe8], false),
+    (0x7316, &[0xf3, 0xe9], false),
+    (0x7621, &[0xf3, 0xea], false),
+    (0x7a93, &[0xf3, 0xeb], false),
+    (0x8139, &[0xf3, 0xec], false),
+    (0x8259, &[0xf3, 0xed], false),
+    (0x83d6, &[0xf3, 0xee], false),
+    (0x84bc, &[0xf3, 0xef], false),
+    (0x50b5, &[0xf3, 0xf0], false),
+    (0x57f0, &[0xf3, 0xf1], false),
+    (0x5bc0, &[0xf3, 0xf2], false),
+    (0x5be8, &[0xf3, 0xf3], false),
+    (0x5f69, &[0xf3, 0xf4], false),
+    (0x63a1, &[0xf3, 0xf5], false),
+    (0x7826, &[0xf3, 0xf6], false),
+    (0x7db5, &[0xf3, 0xf7], false),
+    (0x83dc, &[0xf3, 0xf8], false),
+    (0x8521, &[0xf3, 0xf9], false),
+    (0x91c7, &[0xf3, 0xfa], false),
+    (0x91f5, &[0xf3, 0xfb], false),
+    (0x518a, &[0xf3, 0xfc], false),
+    (0x67f5, &[0xf3, 0xfd], false),
+    (0x7b56, &[0xf3, 0xfe], false),
+    (0x8cac, &[0xf4, 0xa1], false),
+    (0x51c4, &[0xf4, 0xa2], false),
+    (0x59bb, &[0xf4, 0xa3], false),
+    (0x60bd, &[0xf4, 0xa4], false),
+    (0x8655, &[0xf4, 0xa5], false),
+    (0x501c, &[0xf4, 0xa6], false),
+    (0xf9ff, &[0xf4, 0xa7], false),
+    (0x5254, &[0xf4, 0xa8], false),
+    (0x5c3a, &[0xf4, 0xa9], false),
+    (0x617d, &[0xf4, 0xaa], false),
+    (0x621a, &[0xf4, 0xab], false),
+    (0x62d3, &[0xf4, 0xac], false),
+    (0x64f2, &[0xf4, 0xad], false),
+    (0x65a5, &[0xf4, 0xae], false),
+    (0x6ecc, &[0xf4, 0xaf], false),
+    (0x7620, &[0xf4, 0xb0], false),
+    (0x810a, &[0xf4, 0xb1], false),
+    (0x8e60, &[0xf4, 0xb2], false),
+    (0x965f, &[0xf4, 0xb3], false),
+    (0x96bb, &[0xf4, 0xb4], false),
+    (0x4edf, &[0xf4, 0xb5], false),
+    (0x5343, &[0xf4, 0xb6], false),
+    (0x5598, &[0xf4, 0xb7], false),
+    (0x5929, &[0xf4, 0xb8], false),
+    (0x5ddd, &[0xf4, 0xb9], false),
+    (0x64c5, &[0xf4, 0xba], false),
+    (0x6cc9, &[0xf4, 0xbb], false),
+    (0x6dfa, &[0xf4, 0xbc], false),
+    (0x7394, &[0xf4, 0xbd], false),
+    (0x7a7f, &[0xf4, 0xbe], false),
+    (0x821b, &[0xf4, 0xbf], false),
+    (0x85a6, &[0xf4, 0xc0], false),
+    (0x8ce4, &[0xf4, 0xc1], false),
+    (0x8e10, &[0xf4, 0xc2], false),
+    (0x9077, &[0xf4, 0xc3], false),
+    (0x91e7, &[0xf4, 0xc4], false),
+    (0x95e1, &[0xf4, 0xc5], false),
+    (0x9621, &[0xf4, 0xc6], false),
+    (0x97c6, &[0xf4, 0xc7], false),
+    (0x51f8, &[0xf4, 0xc8], false),
+    (0x54f2, &[0xf4, 0xc9], false),
+    (0x5586, &[0xf4, 0xca], false),
+    (0x5fb9, &[0xf4, 0xcb], false),
+    (0x64a4, &[0xf4, 0xcc], false),
+    (0x6f88, &[0xf4, 0xcd], false),
+    (0x7db4, &[0xf4, 0xce], false),
+    (0x8f1f, &[0xf4, 0xcf], false),
+    (0x8f4d, &[0xf4, 0xd0], false),
+    (0x9435, &[0xf4, 0xd1], false),
+    (0x50c9, &[0xf4, 0xd2], false),
+    (0x5c16, &[0xf4, 0xd3], false),
+    (0x6cbe, &[0xf4, 0xd4], false),
+    (0x6dfb, &[0xf4, 0xd5], false),
+    (0x751b, &[0xf4, 0xd6], false),
+    (0x77bb, &[0xf4, 0xd7], false),
+    (0x7c3d, &[0xf4, 0xd8], false),
+    (0x7c64, &[0xf4, 0xd9], false),
+    (0x8a79, &[0xf4, 0xda], false),
+    (0x8ac2, &[0xf4, 0xdb], false),
+    (0x581e, &[0xf4, 0xdc], false),
+    (0x59be, &[0xf4, 0xdd], false),
+    (0x5e16, &[0xf4, 0xde], false),
+    (0x6377, &[0xf4, 0xdf], false),
+    (0x7252, &[0xf4, 0xe0], false),
+    (0x758a, &[0xf4, 0xe1], false),
+    (0x776b, &[0xf4, 0xe2], false),
+    (0x8adc, &[0xf4, 0xe3], false),
+    (0x8cbc, &[0xf4, 0xe4], false),
+    (0x8f12, &[0xf4, 0xe5], false),
+    (0x5ef3, &[0xf4, 0xe6], false),
+    (0x6674, &[0xf4, 0xe7], false),
+    (0x6df8, &[0xf4, 0xe8], false),
+    (0x807d, &[0xf4, 0xe9], false),
+    (0x83c1, &[0xf4, 0xea], false),
+    (0x8acb, &[0xf4, 0xeb], false),
+    (0x9751, &[0xf4, 0xec], false),
+    (0x9bd6, &[0xf4, 0xed], false),
+    (0xfa00, &[0xf4, 0xee], false),
+    (0x5243, &[0xf4, 0xef], false),
+    (0x66ff, &[0xf4, 0xf0], false),
+    (0x6d95, &[0xf4, 0xf1], false),
+    (0x6eef, &[0xf4, 0xf2], false),
+    (0x7de0, &[0xf4, 0xf3], false),
+    (0x8ae6, &[0xf4, 0xf4], false),
+    (0x902e, &[0xf4, 0xf5], false),
+    (0x905e, &[0xf4, 0xf6], false),
+    (0x9ad4, &[0xf4, 0xf7], false),
+    (0x521d, &[0xf4, 0xf8], false),
+    (0x527f, &[0xf4, 0xf9], false),
+    (0x54e8, &[0xf4, 0xfa], false),
+    (0x6194, &[0xf4, 0xfb], false),
+    (0x6284, &[0xf4, 0xfc], false),
+    (0x62db, &[0xf4, 0xfd], false),
+    (0x68a2, &[0xf4, 0xfe], false),
+    (0x6912, &[0xf5, 0xa1], false),
+    (0x695a, &[0xf5, 0xa2], false),
+    (0x6a35, &[0xf5, 0xa3], false),
+    (0x7092, &[0xf5, 0xa4], false),
+    (0x7126, &[0xf5, 0xa5], false),
+    (0x785d, &[0xf5, 0xa6], false),
+    (0x7901, &[0xf5, 0xa7], false),
+    (0x790e, &[0xf5, 0xa8], false),
+    (0x79d2, &[0xf5, 0xa9], false),
+    (0x7a0d, &[0xf5, 0xaa], false),
+    (0x8096, &[0xf5, 0xab], false),
+    (0x8278, &[0xf5, 0xac], false),
+    (0x82d5, &[0xf5, 0xad], false),
+    (0x8349, &[0xf5, 0xae], false),
+    (0x8549, &[0xf5, 0xaf], false),
+    (0x8c82, &[0xf5, 0xb0], false),
+    (0x8d85, &[0xf5, 0xb1], false),
+    (0x9162, &[0xf5, 0xb2], false),
+    (0x918b, &[0xf5, 0xb3], false),
+    (0x91ae, &[0xf5, 0xb4], false),
+    (0x4fc3, &[0xf5, 0xb5], false),
+    (0x56d1, &[0xf5, 0xb6], false),
+    (0x71ed, &[0xf5, 0xb7], false),
+    (0x77d7, &[0xf5, 0xb8], false),
+    (0x8700, &[0xf5, 0xb9], false),
+    (0x89f8, &[0xf5, 0xba], false),
+    (0x5bf8, &[0xf5, 0xbb], false),
+    (0x5fd6, &[0xf5, 0xbc], false),
+    (0x6751, &[0xf5, 0xbd], false),
+    (0x90a8, &[0xf5, 0xbe], false),
+    (0x53e2, &[0xf5, 0xbf], false),
+    (0x585a, &[0xf5, 0xc0], false),
+    (0x5bf5, &[0xf5, 0xc1], false),
+    (0x60a4, &[0xf5, 0xc2], false),
+    (0x6181, &[0xf5, 0xc3], false),
+    (0x6460, &[0xf5, 0xc4], false),
+    (0x7e3d, &[0xf5, 0xc5], false),
+    (0x8070, &[0xf5, 0xc6], false),
+    (0x8525, &[0xf5, 0xc7], false),
+    (0x9283, &[0xf5, 0xc8], false),
+    (0x64ae, &[0xf5, 0xc9], false),
+    (0x50ac, &[0xf5, 0xca], false),
+    (0x5d14, &[0xf5, 0xcb], false),
+    (0x6700, &[0xf5, 0xcc], false),
+    (0x589c, &[0xf5, 0xcd], false),
+    (0x62bd, &[0xf5, 0xce], false),
+    (0x63a8, &[0xf5, 0xcf], false),
+    (0x690e, &[0xf5, 0xd0], false),
+    (0x6978, &[0xf5, 0xd1], false),
+    (0x6a1e, &[0xf5, 0xd2], false),
+    (0x6e6b, &[0xf5, 0xd3], false),
+    (0x76ba, &[0xf5, 0xd4], false),
+    (0x79cb, &[0xf5, 0xd5], false),
+    (0x82bb, &[0xf5, 0xd6], false),
+    (0x8429, &[0xf5, 0xd7], false),
+    (0x8acf, &[0xf5, 0xd8], false),
+    (0x8da8, &[0xf5, 0xd9], false),
+    (0x8ffd, &[0xf5, 0xda], false),
+    (0x9112, &[0xf5, 0xdb], false),
+    (0x914b, &[0xf5, 0xdc], false),
+    (0x919c, &[0xf5, 0xdd], false),
+    (0x9310, &[0xf5, 0xde], false),
+    (0x9318, &[0xf5, 0xdf], false),
+    (0x939a, &[0xf5, 0xe0], false),
+    (0x96db, &[0xf5, 0xe1], false),
+    (0x9a36, &[0xf5, 0xe2], false),
+    (0x9c0d, &[0xf5, 0xe3], false),
+    (0x4e11, &[0xf5, 0xe4], false),
+    (0x755c, &[0xf5, 0xe5], false),
+    (0x795d, &[0xf5, 0xe6], false),
+    (0x7afa, &[0xf5, 0xe7], false),
+    (0x7b51, &[0xf5, 0xe8], false),
+    (0x7bc9, &[0xf5, 0xe9], false),
+    (0x7e2e, &[0xf5, 0xea], false),
+    (0x84c4, &[0xf5, 0xeb], false),
+    (0x8e59, &[0xf5, 0xec], false),
+    (0x8e74, &[0xf5, 0xed], false),
+    (0x8ef8, &[0xf5, 0xee], false),
+    (0x9010, &[0xf5, 0xef], false),
+    (0x6625, &[0xf5, 0xf0], false),
+    (0x693f, &[0xf5, 0xf1], false),
+    (0x7443, &[0xf5, 0xf2], false),
+    (0x51fa, &[0xf5, 0xf3], false),
+    (0x672e, &[0xf5, 0xf4], false),
+    (0x9edc, &[0xf5, 0xf5], false),
+    (0x5145, &[0xf5, 0xf6], false),
+    (0x5fe0, &[0xf5, 0xf7], false),
+    (0x6c96, &[0xf5, 0xf8], false),
+    (0x87f2, &[0xf5, 0xf9], false),
+    (0x885d, &[0xf5, 0xfa], false),
+    (0x8877, &[0xf5, 0xfb], false),
+    (0x60b4, &[0xf5, 0xfc], false),
+    (0x81b5, &[0xf5, 0xfd], false),
+    (0x8403, &[0xf5, 0xfe], false),
+    (0x8d05, &[0xf6, 0xa1], false),
+    (0x53d6, &[0xf6, 0xa2], false),
+    (0x5439, &[0xf6, 0xa3], false),
+    (0x5634, &[0xf6, 0xa4], false),
+    (0x5a36, &[0xf6, 0xa5], false),
+    (0x5c31, &[0xf6, 0xa6], false),
+    (0x708a, &[0xf6, 0xa7], false),
+    (0x7fe0, &[0xf6, 0xa8], false),
+    (0x805a, &[0xf6, 0xa9], false),
+    (0x8106, &[0xf6, 0xaa], false),
+    (0x81ed, &[0xf6, 0xab], false),
+    (0x8da3, &[0xf6, 0xac], false),
+    (0x9189, &[0xf6, 0xad], false),
+    (0x9a5f, &[0xf6, 0xae], false),
+    (0x9df2, &[0xf6, 0xaf], false),
+    (0x5074, &[0xf6, 0xb0], false),
+    (0x4ec4, &[0xf6, 0xb1], false),
+    (0x53a0, &[0xf6, 0xb2], false),
+    (0x60fb, &[0xf6, 0xb3], false),
+    (0x6e2c, &[0xf6, 0xb4], false),
+    (0x5c64, &[0xf6, 0xb5], false),
+    (0x4f88, &[0xf6, 0xb6], false),
+    (0x5024, &[0xf6, 0xb7], false),
+    (0x55e4, &[0xf6, 0xb8], false),
+    (0x5cd9, &[0xf6, 0xb9], false),
+    (0x5e5f, &[0xf6, 0xba], false),
+    (0x6065, &[0xf6, 0xbb], false),
+    (0x6894, &[0xf6, 0xbc], false),
+    (0x6cbb, &[0xf6, 0xbd], false),
+    (0x6dc4, &[0xf6, 0xbe], false),
+    (0x71be, &[0xf6, 0xbf], false),
+    (0x75d4, &[0xf6, 0xc0], false),
+    (0x75f4, &[0xf6, 0xc1], false),
+    (0x7661, &[0xf6, 0xc2], false),
+    (0x7a1a, &[0xf6, 0xc3], false),
+    (0x7a49, &[0xf6, 0xc4], false),
+    (0x7dc7, &[0xf6, 0xc5], false),
+    (0x7dfb, &[0xf6, 0xc6], false),
+    (0x7f6e, &[0xf6, 0xc7], false),
+    (0x81f4, &[0xf6, 0xc8], false),
+    (0x86a9, &[0xf6, 0xc9], false),
+    (0x8f1c, &[0xf6, 0xca], false),
+    (0x96c9, &[0xf6, 0xcb], false),
+    (0x99b3, &[0xf6, 0xcc], false),
+    (0x9f52, &[0xf6, 0xcd], false),
+    (0x5247, &[0xf6, 0xce], false),
+    (0x52c5, &[0xf6, 0xcf], false),
+    (0x98ed, &[0xf6, 0xd0], false),
+    (0x89aa, &[0xf6, 0xd1], false),
+    (0x4e03, &[0xf6, 0xd2], false),
+    (0x67d2, &[0xf6, 0xd3], false),
+    (0x6f06, &[0xf6, 0xd4], false),
+    (0x4fb5, &[0xf6, 0xd5], false),
+    (0x5be2, &[0xf6, 0xd6], false),
+    (0x6795, &[0xf6, 0xd7], false),
+    (0x6c88, &[0xf6, 0xd8], false),
+    (0x6d78, &[0xf6, 0xd9], false),
+    (0x741b, &[0xf6, 0xda], false),
+    (0x7827, &[0xf6, 0xdb], false),
+    (0x91dd, &[0xf6, 0xdc], false),
+    (0x937c, &[0xf6, 0xdd], false),
+    (0x87c4, &[0xf6, 0xde], false),
+    (0x79e4, &[0xf6, 0xdf], false),
+    (0x7a31, &[0xf6, 0xe0], false),
+    (0x5feb, &[0xf6, 0xe1], false),
+    (0x4ed6, &[0xf6, 0xe2], false),
+    (0x54a4, &[0xf6, 0xe3], false),
+    (0x553e, &[0xf6, 0xe4], false),
+    (0x58ae, &[0xf6, 0xe5], false),
+    (0x59a5, &[0xf6, 0xe6], false),
+    (0x60f0, &[0xf6, 0xe7], false),
+    (0x6253, &[0xf6, 0xe8], false),
+    (0x62d6, &[0xf6, 0xe9], false),
+    (0x6736, &[0xf6, 0xea], false),
+    (0x6955, &[0xf6, 0xeb], false),
+    (0x8235, &[0xf6, 0xec], false),
+    (0x9640, &[0xf6, 0xed], false),
+    (0x99b1, &[0xf6, 0xee], false),
+    (0x99dd, &[0xf6, 0xef], false),
+    (0x502c, &[0xf6, 0xf0], false),
+    (0x5353, &[0xf6, 0xf1], false),
+    (0x5544, &[0xf6, 0xf2], false),
+    (0x577c, &[0xf6, 0xf3], false),
+    (0xfa01, &[0xf6, 0xf4], false),
+    (0x6258, &[0xf6, 0xf5], false),
+    (0xfa02, &[0xf6, 0xf6], false),
+    (0x64e2, &[0xf6, 0xf7], false),
+    (0x666b, &[0xf6, 0xf8], false),
+    (0x67dd, &[0xf6, 0xf9], false),
+    (0x6fc1, &[0xf6, 0xfa], false),
+    (0x6fef, &[0xf6, 0xfb], false),
+    (0x7422, &[0xf6, 0xfc], false),
+    (0x7438, &[0xf6, 0xfd], false),
+    (0x8a17, &[0xf6, 0xfe], false),
+    (0x9438, &[0xf7, 0xa1], false),
+    (0x5451, &[0xf7, 0xa2], false),
+    (0x5606, &[0xf7, 0xa3], false),
+    (0x5766, &[0xf7, 0xa4], false),
+    (0x5f48, &[0xf7, 0xa5], false),
+    (0x619a, &[0xf7, 0xa6], false),
+    (0x6b4e, &[0xf7, 0xa7], false),
+    (0x7058, &[0xf7, 0xa8], false),
+    (0x70ad, &[0xf7, 0xa9], false),
+    (0x7dbb, &[0xf7, 0xaa], false),
+    (0x8a95, &[0xf7, 0xab], false),
+    (0x596a, &[0xf7, 0xac], false),
+    (0x812b, &[0xf7, 0xad], false),
+    (0x63a2, &[0xf7, 0xae], false),
+    (0x7708, &[0xf7, 0xaf], false),
+    (0x803d, &[0xf7, 0xb0], false),
+    (0x8caa, &[0xf7, 0xb1], false),
+    (0x5854, &[0xf7, 0xb2], false),
+    (0x642d, &[0xf7, 0xb3], false),
+    (0x69bb, &[0xf7, 0xb4], false),
+    (0x5b95, &[0xf7, 0xb5], false),
+    (0x5e11, &[0xf7, 0xb6], false),
+    (0x6e6f, &[0xf7, 0xb7], false),
+    (0xfa03, &[0xf7, 0xb8], false),
+    (0x8569, &[0xf7, 0xb9], false),
+    (0x514c, &[0xf7, 0xba], false),
+    (0x53f0, &[0xf7, 0xbb], false),
+    (0x592a, &[0xf7, 0xbc], false),
+    (0x6020, &[0xf7, 0xbd], false),
+    (0x614b, &[0xf7, 0xbe], false),
+    (0x6b86, &[0xf7, 0xbf], false),
+    (0x6c70, &[0xf7, 0xc0], false),
+    (0x6cf0, &[0xf7, 0xc1], false),
+    (0x7b1e, &[0xf7, 0xc2], false),
+    (0x80ce, &[0xf7, 0xc3], false),
+    (0x82d4, &[0xf7, 0xc4], false),
+    (0x8dc6, &[0xf7, 0xc5], false),
+    (0x90b0, &[0xf7, 0xc6], false),
+    (0x98b1, &[0xf7, 0xc7], false),
+    (0xfa04, &[0xf7, 0xc8], false),
+    (0x64c7, &[0xf7, 0xc9], false),
+    (0x6fa4, &[0xf7, 0xca], false),
+    (0x6491, &[0xf7, 0xcb], false),
+    (0x6504, &[0xf7, 0xcc], false),
+    (0x514e, &[0xf7, 0xcd], false),
+    (0x5410, &[0xf7, 0xce], false),
+    (0x571f, &[0xf7, 0xcf], false),
+    (0x8a0e, &[0xf7, 0xd0], false),
+    (0x615f, &[0xf7, 0xd1], false),
+    (0x6876, &[0xf7, 0xd2], false),
+    (0xfa05, &[0xf7, 0xd3], false),
+    (0x75db, &[0xf7, 0xd4], false),
+    (0x7b52, &[0xf7, 0xd5], false),
+    (0x7d71, &[0xf7, 0xd6], false),
+    (0x901a, &[0xf7, 0xd7], false),
+    (0x5806, &[0xf7, 0xd8], false),
+    (0x69cc, &[0xf7, 0xd9], false),
+    (0x817f, &[0xf7, 0xda], false),
+    (0x892a, &[0xf7, 0xdb], false),
+    (0x9000, &[0xf7, 0xdc], false),
+    (0x9839, &[0xf7, 0xdd], false),
+    (0x5078, &[0xf7, 0xde], false),
+    (0x5957, &[0xf7, 0xdf], false),
+    (0x59ac, &[0xf7, 0xe0], false),
+    (0x6295, &[0xf7, 0xe1], false),
+    (0x900f, &[0xf7, 0xe2], false),
+    (0x9b2a, &[0xf7, 0xe3], false),
+    (0x615d, &[0xf7, 0xe4], false),
+    (0x7279, &[0xf7, 0xe5], false),
+    (0x95d6, &[0xf7, 0xe6], false),
+    (0x5761, &[0xf7, 0xe7], false),
+    (0x5a46, &[0xf7, 0xe8], false),
+    (0x5df4, &[0xf7, 0xe9], false),
+    (0x628a, &[0xf7, 0xea], false),
+    (0x64ad, &[0xf7, 0xeb], false),
+    (0x64fa, &[0xf7, 0xec], false),
+    (0x6777, &[0xf7, 0xed], false),
+    (0x6ce2, &[0xf7, 0xee], false),
+    (0x6d3e, &[0xf7, 0xef], false),
+    (0x722c, &[0xf7, 0xf0], false),
+    (0x7436, &[0xf7, 0xf1], false),
+    (0x7834, &[0xf7, 0xf2], false),
+    (0x7f77, &[0xf7, 0xf3], false),
+    (0x82ad, &[0xf7, 0xf4], false),
+    (0x8ddb, &[0xf7, 0xf5], false),
+    (0x9817, &[0xf7, 0xf6], false),
+    (0x5224, &[0xf7, 0xf7], false),
+    (0x5742, &[0xf7, 0xf8], false),
+    (0x677f, &[0xf7, 0xf9], false),
+    (0x7248, &[0xf7, 0xfa], false),
+    (0x74e3, &[0xf7, 0xfb], false),
+    (0x8ca9, &[0xf7, 0xfc], false),
+    (0x8fa6, &[0xf7, 0xfd], false),
+    (0x9211, &[0xf7, 0xfe], false),
+    (0x962a, &[0xf8, 0xa1], false),
+    (0x516b, &[0xf8, 0xa2], false),
+    (0x53ed, &[0xf8, 0xa3], false),
+    (0x634c, &[0xf8, 0xa4], false),
+    (0x4f69, &[0xf8, 0xa5], false),
+    (0x5504, &[0xf8, 0xa6], false),
+    (0x6096, &[0xf8, 0xa7], false),
+    (0x6557, &[0xf8, 0xa8], false),
+    (0x6c9b, &[0xf8, 0xa9], false),
+    (0x6d7f, &[0xf8, 0xaa], false),
+    (0x724c, &[0xf8, 0xab], false),
+    (0x72fd, &[0xf8, 0xac], false),
+    (0x7a17, &[0xf8, 0xad], false),
+    (0x8987, &[0xf8, 0xae], false),
+    (0x8c9d, &[0xf8, 0xaf], false),
+    (0x5f6d, &[0xf8, 0xb0], false),
+    (0x6f8e, &[0xf8, 0xb1], false),
+    (0x70f9, &[0xf8, 0xb2], false),
+    (0x81a8, &[0xf8, 0xb3], false),
+    (0x610e, &[0xf8, 0xb4], false),
+    (0x4fbf, &[0xf8, 0xb5], false),
+    (0x504f, &[0xf8, 0xb6], false),
+    (0x6241, &[0xf8, 0xb7], false),
+    (0x7247, &[0xf8, 0xb8], false),
+    (0x7bc7, &[0xf8, 0xb9], false),
+    (0x7de8, &[0xf8, 0xba], false),
+    (0x7fe9, &[0xf8, 0xbb], false),
+    (0x904d, &[0xf8, 0xbc], false),
+    (0x97ad, &[0xf8, 0xbd], false),
+    (0x9a19, &[0xf8, 0xbe], false),
+    (0x8cb6, &[0xf8, 0xbf], false),
+    (0x576a, &[0xf8, 0xc0], false),
+    (0x5e73, &[0xf8, 0xc1], false),
+    (0x67b0, &[0xf8, 0xc2], false),
+    (0x840d, &[0xf8, 0xc3], false),
+    (0x8a55, &[0xf8, 0xc4], false),
+    (0x5420, &[0xf8, 0xc5], false),
+    (0x5b16, &[0xf8, 0xc6], false),
+    (0x5e63, &[0xf8, 0xc7], false),
+    (0x5ee2, &[0xf8, 0xc8], false),
+    (0x5f0a, &[0xf8, 0xc9], false),
+    (0x6583, &[0xf8, 0xca], false),
+    (0x80ba, &[0xf8, 0xcb], false),
+    (0x853d, &[0xf8, 0xcc], false),
+    (0x9589, &[0xf8, 0xcd], false),
+    (0x965b, &[0xf8, 0xce], false),
+    (0x4f48, &[0xf8, 0xcf], false),
+    (0x5305, &[0xf8, 0xd0], false),
+    (0x530d, &[0xf8, 0xd1], false),
+    (0x530f, &[0xf8, 0xd2], false),
+    (0x5486, &[0xf8, 0xd3], false),
+    (0x54fa, &[0xf8, 0xd4], false),
+    (0x5703, &[0xf8, 0xd5], false),
+    (0x5e03, &[0xf8, 0xd6], false),
+    (0x6016, &[0xf8, 0xd7], false),
+    (0x629b, &[0xf8, 0xd8], false),
+    (0x62b1, &[0xf8, 0xd9], false),
+    (0x6355, &[0xf8, 0xda], false),
+    (0xfa06, &[0xf8, 0xdb], false),
+    (0x6ce1, &[0xf8, 0xdc], false),
+    (0x6d66, &[0xf8, 0xdd], false),
+    (0x75b1, &[0xf8, 0xde], false),
+    (0x7832, &[0xf8, 0xdf], false),
+    (0x80de, &[0xf8, 0xe0], false),
+    (0x812f, &[0xf8, 0xe1], false),
+    (0x82de, &[0xf8, 0xe2], false),
+    (0x8461, &[0xf8, 0xe3], false),
+    (0x84b2, &[0xf8, 0xe4], false),
+    (0x888d, &[0xf8, 0xe5], false),
+    (0x8912, &[0xf8, 0xe6], false),
+    (0x900b, &[0xf8, 0xe7], false),
+    (0x92ea, &[0xf8, 0xe8], false),
+    (0x98fd, &[0xf8, 0xe9], false),
+    (0x9b91, &[0xf8, 0xea], false),
+    (0x5e45, &[0xf8, 0xeb], false),
+    (0x66b4, &[0xf8, 0xec], false),
+    (0x66dd, &[0xf8, 0xed], false),
+    (0x7011, &[0xf8, 0xee], false),
+    (0x7206, &[0xf8, 0xef], false),
+    (0xfa07, &[0xf8, 0xf0], false),
+    (0x4ff5, &[0xf8, 0xf1], false),
+    (0x527d, &[0xf8, 0xf2], false),
+    (0x5f6a, &[0xf8, 0xf3], false),
+    (0x6153, &[0xf8, 0xf4], false),
+    (0x6753, &[0xf8, 0xf5], false),
+    (0x6a19, &[0xf8, 0xf6], false),
+    (0x6f02, &[0xf8, 0xf7], false),
+    (0x74e2, &[0xf8, 0xf8], false),
+    (0x7968, &[0xf8, 0xf9], false),
+    (0x8868, &[0xf8, 0xfa], false),
+    (0x8c79, &[0xf8, 0xfb], false),
+    (0x98c7, &[0xf8, 0xfc], false),
+    (0x98c4, &[0xf8, 0xfd], false),
+    (0x9a43, &[0xf8, 0xfe], false),
+    (0x54c1, &[0xf9, 0xa1], false),
+    (0x7a1f, &[0xf9, 0xa2], false),
+    (0x6953, &[0xf9, 0xa3], false),
+    (0x8af7, &[0xf9, 0xa4], false),
+    (0x8c4a, &[0xf9, 0xa5], false),
+    (0x98a8, &[0xf9, 0xa6], false),
+    (0x99ae, &[0xf9, 0xa7], false),
+    (0x5f7c, &[0xf9, 0xa8], false),
+    (0x62ab, &[0xf9, 0xa9], false),
+    (0x75b2, &[0xf9, 0xaa], false),
+    (0x76ae, &[0xf9, 0xab], false),
+    (0x88ab, &[0xf9, 0xac], false),
+    (0x907f, &[0xf9, 0xad], false),
+    (0x9642, &[0xf9, 0xae], false),
+    (0x5339, &[0xf9, 0xaf], false),
+    (0x5f3c, &[0xf9, 0xb0], false),
+    (0x5fc5, &[0xf9, 0xb1], false),
+    (0x6ccc, &[0xf9, 0xb2], false),
+    (0x73cc, &[0xf9, 0xb3], false),
+    (0x7562, &[0xf9, 0xb4], false),
+    (0x758b, &[0xf9, 0xb5], false),
+    (0x7b46, &[0xf9, 0xb6], false),
+    (0x82fe, &[0xf9, 0xb7], false),
+    (0x999d, &[0xf9, 0xb8], false),
+    (0x4e4f, &[0xf9, 0xb9], false),
+    (0x903c, &[0xf9, 0xba], false),
+    (0x4e0b, &[0xf9, 0xbb], false),
+    (0x4f55, &[0xf9, 0xbc], false),
+    (0x53a6, &[0xf9, 0xbd], false),
+    (0x590f, &[0xf9, 0xbe], false),
+    (0x5ec8, &[0xf9, 0xbf], false),
+    (0x6630, &[0xf9, 0xc0], false),
+    (0x6cb3, &[0xf9, 0xc1], false),
+    (0x7455, &[0xf9, 0xc2], false),
+    (0x8377, &[0xf9, 0xc3], false),
+    (0x8766, &[0xf9, 0xc4], false),
+    (0x8cc0, &[0xf9, 0xc5], false),
+    (0x9050, &[0xf9, 0xc6], false),
+    (0x971e, &[0xf9, 0xc7], false),
+    (0x9c15, &[0xf9, 0xc8], false),
+    (0x58d1, &[0xf9, 0xc9], false),
+    (0x5b78, &[0xf9, 0xca], false),
+    (0x8650, &[0xf9, 0xcb], false),
+    (0x8b14, &[0xf9, 0xcc], false),
+    (0x9db4, &[0xf9, 0xcd], false),
+    (0x5bd2, &[0xf9, 0xce], false),
+    (0x6068, &[0xf9, 0xcf], false),
+    (0x608d, &[0xf9, 0xd0], false),
+    (0x65f1, &[0xf9, 0xd1], false),
+    (0x6c57, &[0xf9, 0xd2], false),
+    (0x6f22, &[0xf9, 0xd3], false),
+    (0x6fa3, &[0xf9, 0xd4], false),
+    (0x701a, &[0xf9, 0xd5], false),
+    (0x7f55, &[0xf9, 0xd6], false),
+    (0x7ff0, &[0xf9, 0xd7], false),
+    (0x9591, &[0xf9, 0xd8], false),
+    (0x9592, &[0xf9, 0xd9], false),
+    (0x9650, &[0xf9, 0xda], false),
+    (0x97d3, &[0xf9, 0xdb], false),
+    (0x5272, &[0xf9, 0xdc], false),
+    (0x8f44, &[0xf9, 0xdd], false),
+    (0x51fd, &[0xf9, 0xde], false),
+    (0x542b, &[0xf9, 0xdf], false),
+    (0x54b8, &[0xf9, 0xe0], false),
+    (0x5563, &[0xf9, 0xe1], false),
+    (0x558a, &[0xf9, 0xe2], false),
+    (0x6abb, &[0xf9, 0xe3], false),
+    (0x6db5, &[0xf9, 0xe4], false),
+    (0x7dd8, &[0xf9, 0xe5], false),
+    (0x8266, &[0xf9, 0xe6], false),
+    (0x929c, &[0xf9, 0xe7], false),
+    (0x9677, &[0xf9, 0xe8], false),
+    (0x9e79, &[0xf9, 0xe9], false),
+    (0x5408, &[0xf9, 0xea], false),
+    (0x54c8, &[0xf9, 0xeb], false),
+    (0x76d2, &[0xf9, 0xec], false),
+    (0x86e4, &[0xf9, 0xed], false),
+    (0x95a4, &[0xf9, 0xee], false),
+    (0x95d4, &[0xf9, 0xef], false),
+    (0x965c, &[0xf9, 0xf0], false),
+    (0x4ea2, &[0xf9, 0xf1], false),
+    (0x4f09, &[0xf9, 0xf2], false),
+    (0x59ee, &[0xf9, 0xf3], false),
+    (0x5ae6, &[0xf9, 0xf4], false),
+    (0x5df7, &[0xf9, 0xf5], false),
+    (0x6052, &[0xf9, 0xf6], false),
+    (0x6297, &[0xf9, 0xf7], false),
+    (0x676d, &[0xf9, 0xf8], false),
+    (0x6841, &[0xf9, 0xf9], false),
+    (0x6c86, &[0xf9, 0xfa], false),
+    (0x6e2f, &[0xf9, 0xfb], false),
+    (0x7f38, &[0xf9, 0xfc], false),
+    (0x809b, &[0xf9, 0xfd], false),
+    (0x822a, &[0xf9, 0xfe], false),
+    (0xfa08, &[0xfa, 0xa1], false),
+    (0xfa09, &[0xfa, 0xa2], false),
+    (0x9805, &[0xfa, 0xa3], false),
+    (0x4ea5, &[0xfa, 0xa4], false),
+    (0x5055, &[0xfa, 0xa5], false),
+    (0x54b3, &[0xfa, 0xa6], false),
+    (0x5793, &[0xfa, 0xa7], false),
+    (0x595a, &[0xfa, 0xa8], false),
+    (0x5b69, &[0xfa, 0xa9], false),
+    (0x5bb3, &[0xfa, 0xaa], false),
+    (0x61c8, &[0xfa, 0xab], false),
+    (0x6977, &[0xfa, 0xac], false),
+    (0x6d77, &[0xfa, 0xad], false),
+    (0x7023, &[0xfa, 0xae], false),
+    (0x87f9, &[0xfa, 0xaf], false),
+    (0x89e3, &[0xfa, 0xb0], false),
+    (0x8a72, &[0xfa, 0xb1], false),
+    (0x8ae7, &[0xfa, 0xb2], false),
+    (0x9082, &[0xfa, 0xb3], false),
+    (0x99ed, &[0xfa, 0xb4], false),
+    (0x9ab8, &[0xfa, 0xb5], false),
+    (0x52be, &[0xfa, 0xb6], false),
+    (0x6838, &[0xfa, 0xb7], false),
+    (0x5016, &[0xfa, 0xb8], false),
+    (0x5e78, &[0xfa, 0xb9], false),
+    (0x674f, &[0xfa, 0xba], false),
+    (0x8347, &[0xfa, 0xbb], false),
+    (0x884c, &[0xfa, 0xbc], false),
+    (0x4eab, &[0xfa, 0xbd], false),
+    (0x5411, &[0xfa, 0xbe], false),
+    (0x56ae, &[0xfa, 0xbf], false),
+    (0x73e6, &[0xfa, 0xc0], false),
+    (0x9115, &[0xfa, 0xc1], false),
+    (0x97ff, &[0xfa, 0xc2], false),
+    (0x9909, &[0xfa, 0xc3], false),
+    (0x9957, &[0xfa, 0xc4], false),
+    (0x9999, &[0xfa, 0xc5], false),
+    (0x5653, &[0xfa, 0xc6], false),
+    (0x589f, &[0xfa, 0xc7], false),
+    (0x865b, &[0xfa, 0xc8], false),
+    (0x8a31, &[0xfa, 0xc9], false),
+    (0x61b2, &[0xfa, 0xca], false),
+    (0x6af6, &[0xfa, 0xcb], false),
+    (0x737b, &[0xfa, 0xcc], false),
+    (0x8ed2, &[0xfa, 0xcd], false),
+    (0x6b47, &[0xfa, 0xce], false),
+    (0x96aa, &[0xfa, 0xcf], false),
+    (0x9a57, &[0xfa, 0xd0], false),
+    (0x5955, &[0xfa, 0xd1], false),
+    (0x7200, &[0xfa, 0xd2], false),
+    (0x8d6b, &[0xfa, 0xd3], false),
+    (0x9769, &[0xfa, 0xd4], false),
+    (0x4fd4, &[0xfa, 0xd5], false),
+    (0x5cf4, &[0xfa, 0xd6], false),
+    (0x5f26, &[0xfa, 0xd7], false),
+    (0x61f8, &[0xfa, 0xd8], false),
+    (0x665b, &[0xfa, 0xd9], false),
+    (0x6ceb, &[0xfa, 0xda], false),
+    (0x70ab, &[0xfa, 0xdb], false),
+    (0x7384, &[0xfa, 0xdc], false),
+    (0x73b9, &[0xfa, 0xdd], false),
+    (0x73fe, &[0xfa, 0xde], false),
+    (0x7729, &[0xfa, 0xdf], false),
+    (0x774d, &[0xfa, 0xe0], false),
+    (0x7d43, &[0xfa, 0xe1], false),
+    (0x7d62, &[0xfa, 0xe2], false),
+    (0x7e23, &[0xfa, 0xe3], false),
+    (0x8237, &[0xfa, 0xe4], false),
+    (0x8852, &[0xfa, 0xe5], false),
+    (0xfa0a, &[0xfa, 0xe6], false),
+    (0x8ce2, &[0xfa, 0xe7], false),
+    (0x9249, &[0xfa, 0xe8], false),
+    (0x986f, &[0xfa, 0xe9], false),
+    (0x5b51, &[0xfa, 0xea], false),
+    (0x7a74, &[0xfa, 0xeb], false),
+    (0x8840, &[0xfa, 0xec], false),
+    (0x9801, &[0xfa, 0xed], false),
+    (0x5acc, &[0xfa, 0xee], false),
+    (0x4fe0, &[0xfa, 0xef], false),
+    (0x5354, &[0xfa, 0xf0], false),
+    (0x593e, &[0xfa, 0xf1], false),
+    (0x5cfd, &[0xfa, 0xf2], false),
+    (0x633e, &[0xfa, 0xf3], false),
+    (0x6d79, &[0xfa, 0xf4], false),
+    (0x72f9, &[0xfa, 0xf5], false),
+    (0x8105, &[0xfa, 0xf6], false),
+    (0x8107, &[0xfa, 0xf7], false),
+    (0x83a2, &[0xfa, 0xf8], false),
+    (0x92cf, &[0xfa, 0xf9], false),
+    (0x9830, &[0xfa, 0xfa], false),
+    (0x4ea8, &[0xfa, 0xfb], false),
+    (0x5144, &[0xfa, 0xfc], false),
+    (0x5211, &[0xfa, 0xfd], false),
+    (0x578b, &[0xfa, 0xfe], false),
+    (0x5f62, &[0xfb, 0xa1], false),
+    (0x6cc2, &[0xfb, 0xa2], false),
+    (0x6ece, &[0xfb, 0xa3], false),
+    (0x7005, &[0xfb, 0xa4], false),
+    (0x7050, &[0xfb, 0xa5], false),
+    (0x70af, &[0xfb, 0xa6], false),
+    (0x7192, &[0xfb, 0xa7], false),
+    (0x73e9, &[0xfb, 0xa8], false),
+    (0x7469, &[0xfb, 0xa9], false),
+    (0x834a, &[0xfb, 0xaa], false),
+    (0x87a2, &[0xfb, 0xab], false),
+    (0x8861, &[0xfb, 0xac], false),
+    (0x9008, &[0xfb, 0xad], false),
+    (0x90a2, &[0xfb, 0xae], false),
+    (0x93a3, &[0xfb, 0xaf], false),
+    (0x99a8, &[0xfb, 0xb0], false),
+    (0x516e, &[0xfb, 0xb1], false),
+    (0x5f57, &[0xfb, 0xb2], false),
+    (0x60e0, &[0xfb, 0xb3], false),
+    (0x6167, &[0xfb, 0xb4], false),
+    (0x66b3, &[0xfb, 0xb5], false),
+    (0x8559, &[0xfb, 0xb6], false),
+    (0x8e4a, &[0xfb, 0xb7], false),
+    (0x91af, &[0xfb, 0xb8], false),
+    (0x978b, &[0xfb, 0xb9], false),
+    (0x4e4e, &[0xfb, 0xba], false),
+    (0x4e92, &[0xfb, 0xbb], false),
+    (0x547c, &[0xfb, 0xbc], false),
+    (0x58d5, &[0xfb, 0xbd], false),
+    (0x58fa, &[0xfb, 0xbe], false),
+    (0x597d, &[0xfb, 0xbf], false),
+    (0x5cb5, &[0xfb, 0xc0], false),
+    (0x5f27, &[0xfb, 0xc1], false),
+    (0x6236, &[0xfb, 0xc2], false),
+    (0x6248, &[0xfb, 0xc3], false),
+    (0x660a, &[0xfb, 0xc4], false),
+    (0x6667, &[0xfb, 0xc5], false),
+    (0x6beb, &[0xfb, 0xc6], false),
+    (0x6d69, &[0xfb, 0xc7], false),
+    (0x6dcf, &[0xfb, 0xc8], false),
+    (0x6e56, &[0xfb, 0xc9], false),
+    (0x6ef8, &[0xfb, 0xca], false),
+    (0x6f94, &[0xfb, 0xcb], false),
+    (0x6fe0, &[0xfb, 0xcc], false),
+    (0x6fe9, &[0xfb, 0xcd], false),
+    (0x705d, &[0xfb, 0xce], false),
+    (0x72d0, &[0xfb, 0xcf], false),
+    (0x7425, &[0xfb, 0xd0], false),
+    (0x745a, &[0xfb, 0xd1], false),
+    (0x74e0, &[0xfb, 0xd2], false),
+    (0x7693, &[0xfb, 0xd3], false),
+    (0x795c, &[0xfb, 0xd4], false),
+    (0x7cca, &[0xfb, 0xd5], false),
+    (0x7e1e, &[0xfb, 0xd6], false),
+    (0x80e1, &[0xfb, 0xd7], false),
+    (0x82a6, &[0xfb, 0xd8], false),
+    (0x846b, &[0xfb, 0xd9], false),
+    (0x84bf, &[0xfb, 0xda], false),
+    (0x864e, &[0xfb, 0xdb], false),
+    (0x865f, &[0xfb, 0xdc], false),
+    (0x8774, &[0xfb, 0xdd], false),
+    (0x8b77, &[0xfb, 0xde], false),
+    (0x8c6a, &[0xfb, 0xdf], false),
+    (0x93ac, &[0xfb, 0xe0], false),
+    (0x9800, &[0xfb, 0xe1], false),
+    (0x9865, &[0xfb, 0xe2], false),
+    (0x60d1, &[0xfb, 0xe3], false),
+    (0x6216, &[0xfb, 0xe4], false),
+    (0x9177, &[0xfb, 0xe5], false),
+    (0x5a5a, &[0xfb, 0xe6], false),
+    (0x660f, &[0xfb, 0xe7], false),
+    (0x6df7, &[0xfb, 0xe8], false),
+    (0x6e3e, &[0xfb, 0xe9], false),
+    (0x743f, &[0xfb, 0xea], false),
+    (0x9b42, &[0xfb, 0xeb], false),
+    (0x5ffd, &[0xfb, 0xec], false),
+    (0x60da, &[0xfb, 0xed], false),
+    (0x7b0f, &[0xfb, 0xee], false),
+    (0x54c4, &[0xfb, 0xef], false),
+    (0x5f18, &[0xfb, 0xf0], false),
+    (0x6c5e, &[0xfb, 0xf1], false),
+    (0x6cd3, &[0xfb, 0xf2], false),
+    (0x6d2a, &[0xfb, 0xf3], false),
+    (0x70d8, &[0xfb, 0xf4], false),
+    (0x7d05, &[0xfb, 0xf5], false),
+    (0x8679, &[0xfb, 0xf6], false),
+    (0x8a0c, &[0xfb, 0xf7], false),
+    (0x9d3b, &[0xfb, 0xf8], false),
+    (0x5316, &[0xfb, 0xf9], false),
+    (0x548c, &[0xfb, 0xfa], false),
+    (0x5b05, &[0xfb, 0xfb], false),
+    (0x6a3a, &[0xfb, 0xfc], false),
+    (0x706b, &[0xfb, 0xfd], false),
+    (0x7575, &[0xfb, 0xfe], false),
+    (0x798d, &[0xfc, 0xa1], false),
+    (0x79be, &[0xfc, 0xa2], false),
+    (0x82b1, &[0xfc, 0xa3], false),
+    (0x83ef, &[0xfc, 0xa4], false),
+    (0x8a71, &[0xfc, 0xa5], false),
+    (0x8b41, &[0xfc, 0xa6], false),
+    (0x8ca8, &[0xfc, 0xa7], false),
+    (0x9774, &[0xfc, 0xa8], false),
+    (0xfa0b, &[0xfc, 0xa9], false),
+    (0x64f4, &[0xfc, 0xaa], false),
+    (0x652b, &[0xfc, 0xab], false),
+    (0x78ba, &[0xfc, 0xac], false),
+    (0x78bb, &[0xfc, 0xad], false),
+    (0x7a6b, &[0xfc, 0xae], false),
+    (0x4e38, &[0xfc, 0xaf], false),
+    (0x559a, &[0xfc, 0xb0], false),
+    (0x5950, &[0xfc, 0xb1], false),
+    (0x5ba6, &[0xfc, 0xb2], false),
+    (0x5e7b, &[0xfc, 0xb3], false),
+    (0x60a3, &[0xfc, 0xb4], false),
+    (0x63db, &[0xfc, 0xb5], false),
+    (0x6b61, &[0xfc, 0xb6], false),
+    (0x6665, &[0xfc, 0xb7], false),
+    (0x6853, &[0xfc, 0xb8], false),
+    (0x6e19, &[0xfc, 0xb9], false),
+    (0x7165, &[0xfc, 0xba], false),
+    (0x74b0, &[0xfc, 0xbb], false),
+    (0x7d08, &[0xfc, 0xbc], false),
+    (0x9084, &[0xfc, 0xbd], false),
+    (0x9a69, &[0xfc, 0xbe], false),
+    (0x9c25, &[0xfc, 0xbf], false),
+    (0x6d3b, &[0xfc, 0xc0], false),
+    (0x6ed1, &[0xfc, 0xc1], false),
+    (0x733e, &[0xfc, 0xc2], false),
+    (0x8c41, &[0xfc, 0xc3], false),
+    (0x95ca, &[0xfc, 0xc4], false),
+    (0x51f0, &[0xfc, 0xc5], false),
+    (0x5e4c, &[0xfc, 0xc6], false),
+    (0x5fa8, &[0xfc, 0xc7], false),
+    (0x604d, &[0xfc, 0xc8], false),
+    (0x60f6, &[0xfc, 0xc9], false),
+    (0x6130, &[0xfc, 0xca], false),
+    (0x614c, &[0xfc, 0xcb], false),
+    (0x6643, &[0xfc, 0xcc], false),
+    (0x6644, &[0xfc, 0xcd], false),
+    (0x69a5, &[0xfc, 0xce], false),
+    (0x6cc1, &[0xfc, 0xcf], false),
+    (0x6e5f, &[0xfc, 0xd0], false),
+    (0x6ec9, &[0xfc, 0xd1], false),
+    (0x6f62, &[0xfc, 0xd2], false),
+    (0x714c, &[0xfc, 0xd3], false),
+    (0x749c, &[0xfc, 0xd4], false),
+    (0x7687, &[0xfc, 0xd5], false),
+    (0x7bc1, &[0xfc, 0xd6], false),
+    (0x7c27, &[0xfc, 0xd7], false),
+    (0x8352, &[0xfc, 0xd8], false),
+    (0x8757, &[0xfc, 0xd9], false),
+    (0x9051, &[0xfc, 0xda], false),
+    (0x968d, &[0xfc, 0xdb], false),
+    (0x9ec3, &[0xfc, 0xdc], false),
+    (0x532f, &[0xfc, 0xdd], false),
+    (0x56de, &[0xfc, 0xde], false),
+    (0x5efb, &[0xfc, 0xdf], false),
+    (0x5f8a, &[0xfc, 0xe0], false),
+    (0x6062, &[0xfc, 0xe1], false),
+    (0x6094, &[0xfc, 0xe2], false),
+    (0x61f7, &[0xfc, 0xe3], false),
+    (0x6666, &[0xfc, 0xe4], false),
+    (0x6703, &[0xfc, 0xe5], false),
+    (0x6a9c, &[0xfc, 0xe6], false),
+    (0x6dee, &[0xfc, 0xe7], false),
+    (0x6fae, &[0xfc, 0xe8], false),
+    (0x7070, &[0xfc, 0xe9], false),
+    (0x736a, &[0xfc, 0xea], false),
+    (0x7e6a, &[0xfc, 0xeb], false),
+    (0x81be, &[0xfc, 0xec], false),
+    (0x8334, &[0xfc, 0xed], false),
+    (0x86d4, &[0xfc, 0xee], false),
+    (0x8aa8, &[0xfc, 0xef], false),
+    (0x8cc4, &[0xfc, 0xf0], false),
+    (0x5283, &[0xfc, 0xf1], false),
+    (0x7372, &[0xfc, 0xf2], false),
+    (0x5b96, &[0xfc, 0xf3], false),
+    (0x6a6b, &[0xfc, 0xf4], false),
+    (0x9404, &[0xfc, 0xf5], false),
+    (0x54ee, &[0xfc, 0xf6], false),
+    (0x5686, &[0xfc, 0xf7], false),
+    (0x5b5d, &[0xfc, 0xf8], false),
+    (0x6548, &[0xfc, 0xf9], false),
+    (0x6585, &[0xfc, 0xfa], false),
+    (0x66c9, &[0xfc, 0xfb], false),
+    (0x689f, &[0xfc, 0xfc], false),
+    (0x6d8d, &[0xfc, 0xfd], false),
+    (0x6dc6, &[0xfc, 0xfe], false),
+    (0x723b, &[0xfd, 0xa1], false),
+    (0x80b4, &[0xfd, 0xa2], false),
+    (0x9175, &[0xfd, 0xa3], false),
+    (0x9a4d, &[0xfd, 0xa4], false),
+    (0x4faf, &[0xfd, 0xa5], false),
+    (0x5019, &[0xfd, 0xa6], false),
+    (0x539a, &[0xfd, 0xa7], false),
+    (0x540e, &[0xfd, 0xa8], false),
+    (0x543c, &[0xfd, 0xa9], false),
+    (0x5589, &[0xfd, 0xaa], false),
+    (0x55c5, &[0xfd, 0xab], false),
+    (0x5e3f, &[0xfd, 0xac], false),
+    (0x5f8c, &[0xfd, 0xad], false),
+    (0x673d, &[0xfd, 0xae], false),
+    (0x7166, &[0xfd, 0xaf], false),
+    (0x73dd, &[0xfd, 0xb0], false),
+    (0x9005, &[0xfd, 0xb1], false),
+    (0x52db, &[0xfd, 0xb2], false),
+    (0x52f3, &[0xfd, 0xb3], false),
+    (0x5864, &[0xfd, 0xb4], false),
+    (0x58ce, &[0xfd, 0xb5], false),
+    (0x7104, &[0xfd, 0xb6], false),
+    (0x718f, &[0xfd, 0xb7], false),
+    (0x71fb, &[0xfd, 0xb8], false),
+    (0x85b0, &[0xfd, 0xb9], false),
+    (0x8a13, &[0xfd, 0xba], false),
+    (0x6688, &[0xfd, 0xbb], false),
+    (0x85a8, &[0xfd, 0xbc], false),
+    (0x55a7, &[0xfd, 0xbd], false),
+    (0x6684, &[0xfd, 0xbe], false),
+    (0x714a, &[0xfd, 0xbf], false),
+    (0x8431, &[0xfd, 0xc0], false),
+    (0x5349, &[0xfd, 0xc1], false),
+    (0x5599, &[0xfd, 0xc2], false),
+    (0x6bc1, &[0xfd, 0xc3], false),
+    (0x5f59, &[0xfd, 0xc4], false),
+    (0x5fbd, &[0xfd, 0xc5], false),
+    (0x63ee, &[0xfd, 0xc6], false),
+    (0x6689, &[0xfd, 0xc7], false),
+    (0x7147, &[0xfd, 0xc8], false),
+    (0x8af1, &[0xfd, 0xc9], false),
+    (0x8f1d, &[0xfd, 0xca], false),
+    (0x9ebe, &[0xfd, 0xcb], false),
+    (0x4f11, &[0xfd, 0xcc], false),
+    (0x643a, &[0xfd, 0xcd], false),
+    (0x70cb, &[0xfd, 0xce], false),
+    (0x7566, &[0xfd, 0xcf], false),
+    (0x8667, &[0xfd, 0xd0], false),
+    (0x6064, &[0xfd, 0xd1], false),
+    (0x8b4e, &[0xfd, 0xd2], false),
+    (0x9df8, &[0xfd, 0xd3], false),
+    (0x5147, &[0xfd, 0xd4], false),
+    (0x51f6, &[0xfd, 0xd5], false),
+    (0x5308, &[0xfd, 0xd6], false),
+    (0x6d36, &[0xfd, 0xd7], false),
+    (0x80f8, &[0xfd, 0xd8], false),
+    (0x9ed1, &[0xfd, 0xd9], false),
+    (0x6615, &[0xfd, 0xda], false),
+    (0x6b23, &[0xfd, 0xdb], false),
+    (0x7098, &[0xfd, 0xdc], false),
+    (0x75d5, &[0xfd, 0xdd], false),
+    (0x5403, &[0xfd, 0xde], false),
+    (0x5c79, &[0xfd, 0xdf], false),
+    (0x7d07, &[0xfd, 0xe0], false),
+    (0x8a16, &[0xfd, 0xe1], false),
+    (0x6b20, &[0xfd, 0xe2], false),
+    (0x6b3d, &[0xfd, 0xe3], false),
+    (0x6b46, &[0xfd, 0xe4], false),
+    (0x5438, &[0xfd, 0xe5], false),
+    (0x6070, &[0xfd, 0xe6], false),
+    (0x6d3d, &[0xfd, 0xe7], false),
+    (0x7fd5, &[0xfd, 0xe8], false),
+    (0x8208, &[0xfd, 0xe9], false),
+    (0x50d6, &[0xfd, 0xea], false),
+    (0x51de, &[0xfd, 0xeb], false),
+    (0x559c, &[0xfd, 0xec], false),
+    (0x566b, &[0xfd, 0xed], false),
+    (0x56cd, &[0xfd, 0xee], false),
+    (0x59ec, &[0xfd, 0xef], false),
+    (0x5b09, &[0xfd, 0xf0], false),
+    (0x5e0c, &[0xfd, 0xf1], false),
+    (0x6199, &[0xfd, 0xf2], false),
+    (0x6198, &[0xfd, 0xf3], false),
+    (0x6231, &[0xfd, 0xf4], false),
+    (0x665e, &[0xfd, 0xf5], false),
+    (0x66e6, &[0xfd, 0xf6], false),
+    (0x7199, &[0xfd, 0xf7], false),
+    (0x71b9, &[0xfd, 0xf8], false),
+    (0x71ba, &[0xfd, 0xf9], false),
+    (0x72a7, &[0xfd, 0xfa], false),
+    (0x79a7, &[0xfd, 0xfb], false),
+    (0x7a00, &[0xfd, 0xfc], false),
+    (0x7fb2, &[0xfd, 0xfd], false),
+    (0x8a70, &[0xfd, 0xfe], false),
+];
+
+#[test]
+fn decode() -> Result<(), ErrorTrace> {
+    for (expected_code_point, test_byte_string, _) in MAC_KOREAN_TEST_VECTOR.iter() {
+        let mut decoder: DecoderMacKorean = DecoderMacKorean::new(test_byte_string);
+
+        let test_code_point: u32 = match decoder.next() {
+            Some(Ok(code_point)) => code_point,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to decode MacKorean as code_point: U+{:04x}",
+                    *expected_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_code_point, *expected_code_point);
+    }
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<(), ErrorTrace> {
+    for (test_code_point, expected_byte_string, is_duplicate) in MAC_KOREAN_TEST_VECTOR.iter() {
+        let code_points: [u32; 1] = [*test_code_point];
+        let mut encoder: EncoderMacKorean = EncoderMacKorean::new(&code_points);
+
+        let test_byte_string: Vec<u8> = match encoder.next() {
+            Some(Ok(byte_string)) => byte_string,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to encode code point: U+{:04x} as MacKorean",
+                    *test_code_point as u32
+                )));
+            }
+        };
+        if !is_duplicate {
+            assert_eq!(test_byte_string, *expected_byte_string);
+        }
+    }
+    Ok(())
+}
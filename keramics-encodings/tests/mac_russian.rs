@@ -0,0 +1,315 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encodings::{DecoderMacRussian, EncoderMacRussian};
+
+const MAC_RUSSIAN_TEST_VECTOR: [(u32, &'static [u8]); 256] = [
+    (0x0000, &[0x00]),
+    (0x0001, &[0x01]),
+    (0x0002, &[0x02]),
+    (0x0003, &[0x03]),
+    (0x0004, &[0x04]),
+    (0x0005, &[0x05]),
+    (0x0006, &[0x06]),
+    (0x0007, &[0x07]),
+    (0x0008, &[0x08]),
+    (0x0009, &[0x09]),
+    (0x000a, &[0x0a]),
+    (0x000b, &[0x0b]),
+    (0x000c, &[0x0c]),
+    (0x000d, &[0x0d]),
+    (0x000e, &[0x0e]),
+    (0x000f, &[0x0f]),
+    (0x0010, &[0x10]),
+    (0x0011, &[0x11]),
+    (0x0012, &[0x12]),
+    (0x0013, &[0x13]),
+    (0x0014, &[0x14]),
+    (0x0015, &[0x15]),
+    (0x0016, &[0x16]),
+    (0x0017, &[0x17]),
+    (0x0018, &[0x18]),
+    (0x0019, &[0x19]),
+    (0x001a, &[0x1a]),
+    (0x001b, &[0x1b]),
+    (0x001c, &[0x1c]),
+    (0x001d, &[0x1d]),
+    (0x001e, &[0x1e]),
+    (0x001f, &[0x1f]),
+    (0x0020, &[0x20]),
+    (0x0021, &[0x21]),
+    (0x0022, &[0x22]),
+    (0x0023, &[0x23]),
+    (0x0024, &[0x24]),
+    (0x0025, &[0x25]),
+    (0x0026, &[0x26]),
+    (0x0027, &[0x27]),
+    (0x0028, &[0x28]),
+    (0x0029, &[0x29]),
+    (0x002a, &[0x2a]),
+    (0x002b, &[0x2b]),
+    (0x002c, &[0x2c]),
+    (0x002d, &[0x2d]),
+    (0x002e, &[0x2e]),
+    (0x002f, &[0x2f]),
+    (0x0030, &[0x30]),
+    (0x0031, &[0x31]),
+    (0x0032, &[0x32]),
+    (0x0033, &[0x33]),
+    (0x0034, &[0x34]),
+    (0x0035, &[0x35]),
+    (0x0036, &[0x36]),
+    (0x0037, &[0x37]),
+    (0x0038, &[0x38]),
+    (0x0039, &[0x39]),
+    (0x003a, &[0x3a]),
+    (0x003b, &[0x3b]),
+    (0x003c, &[0x3c]),
+    (0x003d, &[0x3d]),
+    (0x003e, &[0x3e]),
+    (0x003f, &[0x3f]),
+    (0x0040, &[0x40]),
+    (0x0041, &[0x41]),
+    (0x0042, &[0x42]),
+    (0x0043, &[0x43]),
+    (0x0044, &[0x44]),
+    (0x0045, &[0x45]),
+    (0x0046, &[0x46]),
+    (0x0047, &[0x47]),
+    (0x0048, &[0x48]),
+    (0x0049, &[0x49]),
+    (0x004a, &[0x4a]),
+    (0x004b, &[0x4b]),
+    (0x004c, &[0x4c]),
+    (0x004d, &[0x4d]),
+    (0x004e, &[0x4e]),
+    (0x004f, &[0x4f]),
+    (0x0050, &[0x50]),
+    (0x0051, &[0x51]),
+    (0x0052, &[0x52]),
+    (0x0053, &[0x53]),
+    (0x0054, &[0x54]),
+    (0x0055, &[0x55]),
+    (0x0056, &[0x56]),
+    (0x0057, &[0x57]),
+    (0x0058, &[0x58]),
+    (0x0059, &[0x59]),
+    (0x005a, &[0x5a]),
+    (0x005b, &[0x5b]),
+    (0x005c, &[0x5c]),
+    (0x005d, &[0x5d]),
+    (0x005e, &[0x5e]),
+    (0x005f, &[0x5f]),
+    (0x0060, &[0x60]),
+    (0x0061, &[0x61]),
+    (0x0062, &[0x62]),
+    (0x0063, &[0x63]),
+    (0x0064, &[0x64]),
+    (0x0065, &[0x65]),
+    (0x0066, &[0x66]),
+    (0x0067, &[0x67]),
+    (0x0068, &[0x68]),
+    (0x0069, &[0x69]),
+    (0x006a, &[0x6a]),
+    (0x006b, &[0x6b]),
+    (0x006c, &[0x6c]),
+    (0x006d, &[0x6d]),
+    (0x006e, &[0x6e]),
+    (0x006f, &[0x6f]),
+    (0x0070, &[0x70]),
+    (0x0071, &[0x71]),
+    (0x0072, &[0x72]),
+    (0x0073, &[0x73]),
+    (0x0074, &[0x74]),
+    (0x0075, &[0x75]),
+    (0x0076, &[0x76]),
+    (0x0077, &[0x77]),
+    (0x0078, &[0x78]),
+    (0x0079, &[0x79]),
+    (0x007a, &[0x7a]),
+    (0x007b, &[0x7b]),
+    (0x007c, &[0x7c]),
+    (0x007d, &[0x7d]),
+    (0x007e, &[0x7e]),
+    (0x007f, &[0x7f]),
+    (0x0410, &[0x80]),
+    (0x0411, &[0x81]),
+    (0x0412, &[0x82]),
+    (0x0413, &[0x83]),
+    (0x0414, &[0x84]),
+    (0x0415, &[0x85]),
+    (0x0416, &[0x86]),
+    (0x0417, &[0x87]),
+    (0x0418, &[0x88]),
+    (0x0419, &[0x89]),
+    (0x041a, &[0x8a]),
+    (0x041b, &[0x8b]),
+    (0x041c, &[0x8c]),
+    (0x041d, &[0x8d]),
+    (0x041e, &[0x8e]),
+    (0x041f, &[0x8f]),
+    (0x0420, &[0x90]),
+    (0x0421, &[0x91]),
+    (0x0422, &[0x92]),
+    (0x0423, &[0x93]),
+    (0x0424, &[0x94]),
+    (0x0425, &[0x95]),
+    (0x0426, &[0x96]),
+    (0x0427, &[0x97]),
+    (0x0428, &[0x98]),
+    (0x0429, &[0x99]),
+    (0x042a, &[0x9a]),
+    (0x042b, &[0x9b]),
+    (0x042c, &[0x9c]),
+    (0x042d, &[0x9d]),
+    (0x042e, &[0x9e]),
+    (0x042f, &[0x9f]),
+    (0x2020, &[0xa0]),
+    (0x00b0, &[0xa1]),
+    (0x00a2, &[0xa2]),
+    (0x00a3, &[0xa3]),
+    (0x00a7, &[0xa4]),
+    (0x2022, &[0xa5]),
+    (0x00b6, &[0xa6]),
+    (0x0406, &[0xa7]),
+    (0x00ae, &[0xa8]),
+    (0x00a9, &[0xa9]),
+    (0x2122, &[0xaa]),
+    (0x0402, &[0xab]),
+    (0x0452, &[0xac]),
+    (0x2260, &[0xad]),
+    (0x0403, &[0xae]),
+    (0x0453, &[0xaf]),
+    (0x221e, &[0xb0]),
+    (0x00b1, &[0xb1]),
+    (0x2264, &[0xb2]),
+    (0x2265, &[0xb3]),
+    (0x0456, &[0xb4]),
+    (0x00b5, &[0xb5]),
+    (0x2202, &[0xb6]),
+    (0x0408, &[0xb7]),
+    (0x0404, &[0xb8]),
+    (0x0454, &[0xb9]),
+    (0x0407, &[0xba]),
+    (0x0457, &[0xbb]),
+    (0x0409, &[0xbc]),
+    (0x0459, &[0xbd]),
+    (0x040a, &[0xbe]),
+    (0x045a, &[0xbf]),
+    (0x0458, &[0xc0]),
+    (0x0405, &[0xc1]),
+    (0x00ac, &[0xc2]),
+    (0x221a, &[0xc3]),
+    (0x0192, &[0xc4]),
+    (0x2248, &[0xc5]),
+    (0x2206, &[0xc6]),
+    (0x00ab, &[0xc7]),
+    (0x00bb, &[0xc8]),
+    (0x2026, &[0xc9]),
+    (0x00a0, &[0xca]),
+    (0x040b, &[0xcb]),
+    (0x045b, &[0xcc]),
+    (0x040c, &[0xcd]),
+    (0x045c, &[0xce]),
+    (0x0455, &[0xcf]),
+    (0x2013, &[0xd0]),
+    (0x2014, &[0xd1]),
+    (0x201c, &[0xd2]),
+    (0x201d, &[0xd3]),
+    (0x2018, &[0xd4]),
+    (0x2019, &[0xd5]),
+    (0x00f7, &[0xd6]),
+    (0x201e, &[0xd7]),
+    (0x040e, &[0xd8]),
+    (0x045e, &[0xd9]),
+    (0x040f, &[0xda]),
+    (0x045f, &[0xdb]),
+    (0x2116, &[0xdc]),
+    (0x0401, &[0xdd]),
+    (0x0451, &[0xde]),
+    (0x044f, &[0xdf]),
+    (0x0430, &[0xe0]),
+    (0x0431, &[0xe1]),
+    (0x0432, &[0xe2]),
+    (0x0433, &[0xe3]),
+    (0x0434, &[0xe4]),
+    (0x0435, &[0xe5]),
+    (0x0436, &[0xe6]),
+    (0x0437, &[0xe7]),
+    (0x0438, &[0xe8]),
+    (0x0439, &[0xe9]),
+    (0x043a, &[0xea]),
+    (0x043b, &[0xeb]),
+    (0x043c, &[0xec]),
+    (0x043d, &[0xed]),
+    (0x043e, &[0xee]),
+    (0x043f, &[0xef]),
+    (0x0440, &[0xf0]),
+    (0x0441, &[0xf1]),
+    (0x0442, &[0xf2]),
+    (0x0443, &[0xf3]),
+    (0x0444, &[0xf4]),
+    (0x0445, &[0xf5]),
+    (0x0446, &[0xf6]),
+    (0x0447, &[0xf7]),
+    (0x0448, &[0xf8]),
+    (0x0449, &[0xf9]),
+    (0x044a, &[0xfa]),
+    (0x044b, &[0xfb]),
+    (0x044c, &[0xfc]),
+    (0x044d, &[0xfd]),
+    (0x044e, &[0xfe]),
+    (0x00a4, &[0xff]),
+];
+
+#[test]
+fn decode() -> Result<(), ErrorTrace> {
+    for (expected_code_point, test_byte_string) in MAC_RUSSIAN_TEST_VECTOR.iter() {
+        let mut decoder: DecoderMacRussian = DecoderMacRussian::new(test_byte_string);
+
+        let test_code_point: u32 = match decoder.next() {
+            Some(Ok(code_point)) => code_point,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to decode MacRussian as code_point: U+{:04x}",
+                    *expected_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_code_point, *expected_code_point);
+    }
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<(), ErrorTrace> {
+    for (test_code_point, expected_byte_string) in MAC_RUSSIAN_TEST_VECTOR.iter() {
+        let code_points: [u32; 1] = [*test_code_point];
+        let mut encoder: EncoderMacRussian = EncoderMacRussian::new(&code_points);
+
+        let test_byte_string: Vec<u8> = match encoder.next() {
+            Some(Ok(byte_string)) => byte_string,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to encode code point: U+{:04x} as MacRussian",
+                    *test_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_byte_string, *expected_byte_string);
+    }
+    Ok(())
+}
@@ -0,0 +1,311 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encodings::{DecoderMacThai, EncoderMacThai};
+
+const MAC_THAI_TEST_VECTOR: [(u32, &'static [u8], bool); 250] = [
+    (0x0000, &[0x00], false),
+    (0x0001, &[0x01], false),
+    (0x0002, &[0x02], false),
+    (0x0003, &[0x03], false),
+    (0x0004, &[0x04], false),
+    (0x0005, &[0x05], false),
+    (0x0006, &[0x06], false),
+    (0x0007, &[0x07], false),
+    (0x0008, &[0x08], false),
+    (0x0009, &[0x09], false),
+    (0x000a, &[0x0a], false),
+    (0x000b, &[0x0b], false),
+    (0x000c, &[0x0c], false),
+    (0x000d, &[0x0d], false),
+    (0x000e, &[0x0e], false),
+    (0x000f, &[0x0f], false),
+    (0x0010, &[0x10], false),
+    (0x0011, &[0x11], false),
+    (0x0012, &[0x12], false),
+    (0x0013, &[0x13], false),
+    (0x0014, &[0x14], false),
+    (0x0015, &[0x15], false),
+    (0x0016, &[0x16], false),
+    (0x0017, &[0x17], false),
+    (0x0018, &[0x18], false),
+    (0x0019, &[0x19], false),
+    (0x001a, &[0x1a], false),
+    (0x001b, &[0x1b], false),
+    (0x001c, &[0x1c], false),
+    (0x001d, &[0x1d], false),
+    (0x001e, &[0x1e], false),
+    (0x001f, &[0x1f], false),
+    (0x0020, &[0x20], false),
+    (0x0021, &[0x21], false),
+    (0x0022, &[0x22], false),
+    (0x0023, &[0x23], false),
+    (0x0024, &[0x24], false),
+    (0x0025, &[0x25], false),
+    (0x0026, &[0x26], false),
+    (0x0027, &[0x27], false),
+    (0x0028, &[0x28], false),
+    (0x0029, &[0x29], false),
+    (0x002a, &[0x2a], false),
+    (0x002b, &[0x2b], false),
+    (0x002c, &[0x2c], false),
+    (0x002d, &[0x2d], false),
+    (0x002e, &[0x2e], false),
+    (0x002f, &[0x2f], false),
+    (0x0030, &[0x30], false),
+    (0x0031, &[0x31], false),
+    (0x0032, &[0x32], false),
+    (0x0033, &[0x33], false),
+    (0x0034, &[0x34], false),
+    (0x0035, &[0x35], false),
+    (0x0036, &[0x36], false),
+    (0x0037, &[0x37], false),
+    (0x0038, &[0x38], false),
+    (0x0039, &[0x39], false),
+    (0x003a, &[0x3a], false),
+    (0x003b, &[0x3b], false),
+    (0x003c, &[0x3c], false),
+    (0x003d, &[0x3d], false),
+    (0x003e, &[0x3e], false),
+    (0x003f, &[0x3f], false),
+    (0x0040, &[0x40], false),
+    (0x0041, &[0x41], false),
+    (0x0042, &[0x42], false),
+    (0x0043, &[0x43], false),
+    (0x0044, &[0x44], false),
+    (0x0045, &[0x45], false),
+    (0x0046, &[0x46], false),
+    (0x0047, &[0x47], false),
+    (0x0048, &[0x48], false),
+    (0x0049, &[0x49], false),
+    (0x004a, &[0x4a], false),
+    (0x004b, &[0x4b], false),
+    (0x004c, &[0x4c], false),
+    (0x004d, &[0x4d], false),
+    (0x004e, &[0x4e], false),
+    (0x004f, &[0x4f], false),
+    (0x0050, &[0x50], false),
+    (0x0051, &[0x51], false),
+    (0x0052, &[0x52], false),
+    (0x0053, &[0x53], false),
+    (0x0054, &[0x54], false),
+    (0x0055, &[0x55], false),
+    (0x0056, &[0x56], false),
+    (0x0057, &[0x57], false),
+    (0x0058, &[0x58], false),
+    (0x0059, &[0x59], false),
+    (0x005a, &[0x5a], false),
+    (0x005b, &[0x5b], false),
+    (0x005c, &[0x5c], false),
+    (0x005d, &[0x5d], false),
+    (0x005e, &[0x5e], false),
+    (0x005f, &[0x5f], false),
+    (0x0060, &[0x60], false),
+    (0x0061, &[0x61], false),
+    (0x0062, &[0x62], false),
+    (0x0063, &[0x63], false),
+    (0x0064, &[0x64], false),
+    (0x0065, &[0x65], false),
+    (0x0066, &[0x66], false),
+    (0x0067, &[0x67], false),
+    (0x0068, &[0x68], false),
+    (0x0069, &[0x69], false),
+    (0x006a, &[0x6a], false),
+    (0x006b, &[0x6b], false),
+    (0x006c, &[0x6c], false),
+    (0x006d, &[0x6d], false),
+    (0x006e, &[0x6e], false),
+    (0x006f, &[0x6f], false),
+    (0x0070, &[0x70], false),
+    (0x0071, &[0x71], false),
+    (0x0072, &[0x72], false),
+    (0x0073, &[0x73], false),
+    (0x0074, &[0x74], false),
+    (0x0075, &[0x75], false),
+    (0x0076, &[0x76], false),
+    (0x0077, &[0x77], false),
+    (0x0078, &[0x78], false),
+    (0x0079, &[0x79], false),
+    (0x007a, &[0x7a], false),
+    (0x007b, &[0x7b], false),
+    (0x007c, &[0x7c], false),
+    (0x007d, &[0x7d], false),
+    (0x007e, &[0x7e], false),
+    (0x007f, &[0x7f], false),
+    (0x00ab, &[0x80], false),
+    (0x00bb, &[0x81], false),
+    (0x2026, &[0x82], false),
+    (0x0e48, &[0x83], true),
+    (0x0e49, &[0x84], true),
+    (0x0e4a, &[0x85], true),
+    (0x0e4b, &[0x86], true),
+    (0x0e4c, &[0x87], true),
+    (0x0e48, &[0x88], true),
+    (0x0e49, &[0x89], true),
+    (0x0e4a, &[0x8a], true),
+    (0x0e4b, &[0x8b], true),
+    (0x0e4c, &[0x8c], true),
+    (0x201c, &[0x8d], false),
+    (0x201d, &[0x8e], false),
+    (0x0e4d, &[0x8f], true),
+    (0x2022, &[0x91], false),
+    (0x0e31, &[0x92], true),
+    (0x0e47, &[0x93], true),
+    (0x0e34, &[0x94], true),
+    (0x0e35, &[0x95], true),
+    (0x0e36, &[0x96], true),
+    (0x0e37, &[0x97], true),
+    (0x0e48, &[0x98], true),
+    (0x0e49, &[0x99], true),
+    (0x0e4a, &[0x9a], true),
+    (0x0e4b, &[0x9b], true),
+    (0x0e4c, &[0x9c], true),
+    (0x2018, &[0x9d], false),
+    (0x2019, &[0x9e], false),
+    (0x00a0, &[0xa0], false),
+    (0x0e01, &[0xa1], false),
+    (0x0e02, &[0xa2], false),
+    (0x0e03, &[0xa3], false),
+    (0x0e04, &[0xa4], false),
+    (0x0e05, &[0xa5], false),
+    (0x0e06, &[0xa6], false),
+    (0x0e07, &[0xa7], false),
+    (0x0e08, &[0xa8], false),
+    (0x0e09, &[0xa9], false),
+    (0x0e0a, &[0xaa], false),
+    (0x0e0b, &[0xab], false),
+    (0x0e0c, &[0xac], false),
+    (0x0e0d, &[0xad], false),
+    (0x0e0e, &[0xae], false),
+    (0x0e0f, &[0xaf], false),
+    (0x0e10, &[0xb0], false),
+    (0x0e11, &[0xb1], false),
+    (0x0e12, &[0xb2], false),
+    (0x0e13, &[0xb3], false),
+    (0x0e14, &[0xb4], false),
+    (0x0e15, &[0xb5], false),
+    (0x0e16, &[0xb6], false),
+    (0x0e17, &[0xb7], false),
+    (0x0e18, &[0xb8], false),
+    (0x0e19, &[0xb9], false),
+    (0x0e1a, &[0xba], false),
+    (0x0e1b, &[0xbb], false),
+    (0x0e1c, &[0xbc], false),
+    (0x0e1d, &[0xbd], false),
+    (0x0e1e, &[0xbe], false),
+    (0x0e1f, &[0xbf], false),
+    (0x0e20, &[0xc0], false),
+    (0x0e21, &[0xc1], false),
+    (0x0e22, &[0xc2], false),
+    (0x0e23, &[0xc3], false),
+    (0x0e24, &[0xc4], false),
+    (0x0e25, &[0xc5], false),
+    (0x0e26, &[0xc6], false),
+    (0x0e27, &[0xc7], false),
+    (0x0e28, &[0xc8], false),
+    (0x0e29, &[0xc9], false),
+    (0x0e2a, &[0xca], false),
+    (0x0e2b, &[0xcb], false),
+    (0x0e2c, &[0xcc], false),
+    (0x0e2d, &[0xcd], false),
+    (0x0e2e, &[0xce], false),
+    (0x0e2f, &[0xcf], false),
+    (0x0e30, &[0xd0], false),
+    (0x0e31, &[0xd1], false),
+    (0x0e32, &[0xd2], false),
+    (0x0e33, &[0xd3], false),
+    (0x0e34, &[0xd4], false),
+    (0x0e35, &[0xd5], false),
+    (0x0e36, &[0xd6], false),
+    (0x0e37, &[0xd7], false),
+    (0x0e38, &[0xd8], false),
+    (0x0e39, &[0xd9], false),
+    (0x0e3a, &[0xda], false),
+    (0x2060, &[0xdb], false),
+    (0x200b, &[0xdc], false),
+    (0x2013, &[0xdd], false),
+    (0x2014, &[0xde], false),
+    (0x0e3f, &[0xdf], false),
+    (0x0e40, &[0xe0], false),
+    (0x0e41, &[0xe1], false),
+    (0x0e42, &[0xe2], false),
+    (0x0e43, &[0xe3], false),
+    (0x0e44, &[0xe4], false),
+    (0x0e45, &[0xe5], false),
+    (0x0e46, &[0xe6], false),
+    (0x0e47, &[0xe7], false),
+    (0x0e48, &[0xe8], false),
+    (0x0e49, &[0xe9], false),
+    (0x0e4a, &[0xea], false),
+    (0x0e4b, &[0xeb], false),
+    (0x0e4c, &[0xec], false),
+    (0x0e4d, &[0xed], false),
+    (0x2122, &[0xee], false),
+    (0x0e4f, &[0xef], false),
+    (0x0e50, &[0xf0], false),
+    (0x0e51, &[0xf1], false),
+    (0x0e52, &[0xf2], false),
+    (0x0e53, &[0xf3], false),
+    (0x0e54, &[0xf4], false),
+    (0x0e55, &[0xf5], false),
+    (0x0e56, &[0xf6], false),
+    (0x0e57, &[0xf7], false),
+    (0x0e58, &[0xf8], false),
+    (0x0e59, &[0xf9], false),
+    (0x00ae, &[0xfa], false),
+    (0x00a9, &[0xfb], false),
+];
+
+#[test]
+fn decode() -> Result<(), ErrorTrace> {
+    for (expected_code_point, test_byte_string, _) in MAC_THAI_TEST_VECTOR.iter() {
+        let mut decoder: DecoderMacThai = DecoderMacThai::new(test_byte_string);
+
+        let test_code_point: u32 = match decoder.next() {
+            Some(Ok(code_point)) => code_point,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to decode MacThai as code_point: U+{:04x}",
+                    *expected_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_code_point, *expected_code_point);
+    }
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<(), ErrorTrace> {
+    for (test_code_point, expected_byte_string, is_duplicate) in MAC_THAI_TEST_VECTOR.iter() {
+        let code_points: [u32; 1] = [*test_code_point];
+        let mut encoder: EncoderMacThai = EncoderMacThai::new(&code_points);
+
+        let test_byte_string: Vec<u8> = match encoder.next() {
+            Some(Ok(byte_string)) => byte_string,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to encode code point: U+{:04x} as MacThai",
+                    *test_code_point as u32
+                )));
+            }
+        };
+        if !is_duplicate {
+            assert_eq!(test_byte_string, *expected_byte_string);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,17237 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encodings::{DecoderWindows949, EncoderWindows949};
+
+const WINDOWS_949_TEST_VECTOR: [(u32, &'static [u8], bool); 17176] = [
+    (0x0000, &[0x00], false),
+    (0x0001, &[0x01], false),
+    (0x0002, &[0x02], false),
+    (0x0003, &[0x03], false),
+    (0x0004, &[0x04], false),
+    (0x0005, &[0x05], false),
+    (0x0006, &[0x06], false),
+    (0x0007, &[0x07], false),
+    (0x0008, &[0x08], false),
+    (0x0009, &[0x09], false),
+    (0x000a, &[0x0a], false),
+    (0x000b, &[0x0b], false),
+    (0x000c, &[0x0c], false),
+    (0x000d, &[0x0d], false),
+    (0x000e, &[0x0e], false),
+    (0x000f, &[0x0f], false),
+    (0x0010, &[0x10], false),
+    (0x0011, &[0x11], false),
+    (0x0012, &[0x12], false),
+    (0x0013, &[0x13], false),
+    (0x0014, &[0x14], false),
+    (0x0015, &[0x15], false),
+    (0x0016, &[0x16], false),
+    (0x0017, &[0x17], false),
+    (0x0018, &[0x18], false),
+    (0x0019, &[0x19], false),
+    (0x001a, &[0x1a], false),
+    (0x001b, &[0x1b], false),
+    (0x001c, &[0x1c], false),
+    (0x001d, &[0x1d], false),
+    (0x001e, &[0x1e], false),
+    (0x001f, &[0x1f], false),
+    (0x0020, &[0x20], false),
+    (0x0021, &[0x21], false),
+    (0x0022, &[0x22], false),
+    (0x0023, &[0x23], false),
+    (0x0024, &[0x24], false),
+    (0x0025, &[0x25], false),
+    (0x0026, &[0x26], false),
+    (0x0027, &[0x27], false),
+    (0x0028, &[0x28], false),
+    (0x0029, &[0x29], false),
+    (0x002a, &[0x2a], false),
+    (0x002b, &[0x2b], false),
+    (0x002c, &[0x2c], false),
+    (0x002d, &[0x2d], false),
+    (0x002e, &[0x2e], false),
+    (0x002f, &[0x2f], false),
+    (0x0030, &[0x30], false),
+    (0x0031, &[0x31], false),
+    (0x0032, &[0x32], false),
+    (0x0033, &[0x33], false),
+    (0x0034, &[0x34], false),
+    (0x0035, &[0x35], false),
+    (0x0036, &[0x36], false),
+    (0x0037, &[0x37], false),
+    (0x0038, &[0x38], false),
+    (0x0039, &[0x39], false),
+    (0x003a, &[0x3a], false),
+    (0x003b, &[0x3b], false),
+    (0x003c, &[0x3c], false),
+    (0x003d, &[0x3d], false),
+    (0x003e, &[0x3e], false),
+    (0x003f, &[0x3f], false),
+    (0x0040, &[0x40], false),
+    (0x0041, &[0x41], false),
+    (0x0042, &[0x42], false),
+    (0x0043, &[0x43], false),
+    (0x0044, &[0x44], false),
+    (0x0045, &[0x45], false),
+    (0x0046, &[0x46], false),
+    (0x0047, &[0x47], false),
+    (0x0048, &[0x48], false),
+    (0x0049, &[0x49], false),
+    (0x004a, &[0x4a], false),
+    (0x004b, &[0x4b], false),
+    (0x004c, &[0x4c], false),
+    (0x004d, &[0x4d], false),
+    (0x004e, &[0x4e], false),
+    (0x004f, &[0x4f], false),
+    (0x0050, &[0x50], false),
+    (0x0051, &[0x51], false),
+    (0x0052, &[0x52], false),
+    (0x0053, &[0x53], false),
+    (0x0054, &[0x54], false),
+    (0x0055, &[0x55], false),
+    (0x0056, &[0x56], false),
+    (0x0057, &[0x57], false),
+    (0x0058, &[0x58], false),
+    (0x0059, &[0x59], false),
+    (0x005a, &[0x5a], false),
+    (0x005b, &[0x5b], false),
+    (0x005c, &[0x5c], false),
+    (0x005d, &[0x5d], false),
+    (0x005e, &[0x5e], false),
+    (0x005f, &[0x5f], false),
+    (0x0060, &[0x60], false),
+    (0x0061, &[0x61], false),
+    (0x0062, &[0x62], false),
+    (0x0063, &[0x63], false),
+    (0x0064, &[0x64], false),
+    (0x0065, &[0x65], false),
+    (0x0066, &[0x66], false),
+    (0x0067, &[0x67], false),
+    (0x0068, &[0x68], false),
+    (0x0069, &[0x69], false),
+    (0x006a, &[0x6a], false),
+    (0x006b, &[0x6b], false),
+    (0x006c, &[0x6c], false),
+    (0x006d, &[0x6d], false),
+    (0x006e, &[0x6e], false),
+    (0x006f, &[0x6f], false),
+    (0x0070, &[0x70], false),
+    (0x0071, &[0x71], false),
+    (0x0072, &[0x72], false),
+    (0x0073, &[0x73], false),
+    (0x0074, &[0x74], false),
+    (0x0075, &[0x75], false),
+    (0x0076, &[0x76], false),
+    (0x0077, &[0x77], false),
+    (0x0078, &[0x78], false),
+    (0x0079, &[0x79], false),
+    (0x007a, &[0x7a], false),
+    (0x007b, &[0x7b], false),
+    (0x007c, &[0x7c], false),
+    (0x007d, &[0x7d], false),
+    (0x007e, &[0x7e], false),
+    (0x007f, &[0x7f], false),
+    (0xac02, &[0x81, 0x41], false),
+    (0xac03, &[0x81, 0x42], false),
+    (0xac05, &[0x81, 0x43], false),
+    (0xac06, &[0x81, 0x44], false),
+    (0xac0b, &[0x81, 0x45], false),
+    (0xac0c, &[0x81, 0x46], false),
+    (0xac0d, &[0x81, 0x47], false),
+    (0xac0e, &[0x81, 0x48], false),
+    (0xac0f, &[0x81, 0x49], false),
+    (0xac18, &[0x81, 0x4a], false),
+    (0xac1e, &[0x81, 0x4b], false),
+    (0xac1f, &[0x81, 0x4c], false),
+    (0xac21, &[0x81, 0x4d], false),
+    (0xac22, &[0x81, 0x4e], false),
+    (0xac23, &[0x81, 0x4f], false),
+    (0xac25, &[0x81, 0x50], false),
+    (0xac26, &[0x81, 0x51], false),
+    (0xac27, &[0x81, 0x52], false),
+    (0xac28, &[0x81, 0x53], false),
+    (0xac29, &[0x81, 0x54], false),
+    (0xac2a, &[0x81, 0x55], false),
+    (0xac2b, &[0x81, 0x56], false),
+    (0xac2e, &[0x81, 0x57], false),
+    (0xac32, &[0x81, 0x58], false),
+    (0xac33, &[0x81, 0x59], false),
+    (0xac34, &[0x81, 0x5a], false),
+    (0xac35, &[0x81, 0x61], false),
+    (0xac36, &[0x81, 0x62], false),
+    (0xac37, &[0x81, 0x63], false),
+    (0xac3a, &[0x81, 0x64], false),
+    (0xac3b, &[0x81, 0x65], false),
+    (0xac3d, &[0x81, 0x66], false),
+    (0xac3e, &[0x81, 0x67], false),
+    (0xac3f, &[0x81, 0x68], false),
+    (0xac41, &[0x81, 0x69], false),
+    (0xac42, &[0x81, 0x6a], false),
+    (0xac43, &[0x81, 0x6b], false),
+    (0xac44, &[0x81, 0x6c], false),
+    (0xac45, &[0x81, 0x6d], false),
+    (0xac46, &[0x81, 0x6e], false),
+    (0xac47, &[0x81, 0x6f], false),
+    (0xac48, &[0x81, 0x70], false),
+    (0xac49, &[0x81, 0x71], false),
+    (0xac4a, &[0x81, 0x72], false),
+    (0xac4c, &[0x81, 0x73], false),
+    (0xac4e, &[0x81, 0x74], false),
+    (0xac4f, &[0x81, 0x75], false),
+    (0xac50, &[0x81, 0x76], false),
+    (0xac51, &[0x81, 0x77], false),
+    (0xac52, &[0x81, 0x78], false),
+    (0xac53, &[0x81, 0x79], false),
+    (0xac55, &[0x81, 0x7a], false),
+    (0xac56, &[0x81, 0x81], false),
+    (0xac57, &[0x81, 0x82], false),
+    (0xac59, &[0x81, 0x83], false),
+    (0xac5a, &[0x81, 0x84], false),
+    (0xac5b, &[0x81, 0x85], false),
+    (0xac5d, &[0x81, 0x86], false),
+    (0xac5e, &[0x81, 0x87], false),
+    (0xac5f, &[0x81, 0x88], false),
+    (0xac60, &[0x81, 0x89], false),
+    (0xac61, &[0x81, 0x8a], false),
+    (0xac62, &[0x81, 0x8b], false),
+    (0xac63, &[0x81, 0x8c], false),
+    (0xac64, &[0x81, 0x8d], false),
+    (0xac65, &[0x81, 0x8e], false),
+    (0xac66, &[0x81, 0x8f], false),
+    (0xac67, &[0x81, 0x90], false),
+    (0xac68, &[0x81, 0x91], false),
+    (0xac69, &[0x81, 0x92], false),
+    (0xac6a, &[0x81, 0x93], false),
+    (0xac6b, &[0x81, 0x94], false),
+    (0xac6c, &[0x81, 0x95], false),
+    (0xac6d, &[0x81, 0x96], false),
+    (0xac6e, &[0x81, 0x97], false),
+    (0xac6f, &[0x81, 0x98], false),
+    (0xac72, &[0x81, 0x99], false),
+    (0xac73, &[0x81, 0x9a], false),
+    (0xac75, &[0x81, 0x9b], false),
+    (0xac76, &[0x81, 0x9c], false),
+    (0xac79, &[0x81, 0x9d], false),
+    (0xac7b, &[0x81, 0x9e], false),
+    (0xac7c, &[0x81, 0x9f], false),
+    (0xac7d, &[0x81, 0xa0], false),
+    (0xac7e, &[0x81, 0xa1], false),
+    (0xac7f, &[0x81, 0xa2], false),
+    (0xac82, &[0x81, 0xa3], false),
+    (0xac87, &[0x81, 0xa4], false),
+    (0xac88, &[0x81, 0xa5], false),
+    (0xac8d, &[0x81, 0xa6], false),
+    (0xac8e, &[0x81, 0xa7], false),
+    (0xac8f, &[0x81, 0xa8], false),
+    (0xac91, &[0x81, 0xa9], false),
+    (0xac92, &[0x81, 0xaa], false),
+    (0xac93, &[0x81, 0xab], false),
+    (0xac95, &[0x81, 0xac], false),
+    (0xac96, &[0x81, 0xad], false),
+    (0xac97, &[0x81, 0xae], false),
+    (0xac98, &[0x81, 0xaf], false),
+    (0xac99, &[0x81, 0xb0], false),
+    (0xac9a, &[0x81, 0xb1], false),
+    (0xac9b, &[0x81, 0xb2], false),
+    (0xac9e, &[0x81, 0xb3], false),
+    (0xaca2, &[0x81, 0xb4], false),
+    (0xaca3, &[0x81, 0xb5], false),
+    (0xaca4, &[0x81, 0xb6], false),
+    (0xaca5, &[0x81, 0xb7], false),
+    (0xaca6, &[0x81, 0xb8], false),
+    (0xaca7, &[0x81, 0xb9], false),
+    (0xacab, &[0x81, 0xba], false),
+    (0xacad, &[0x81, 0xbb], false),
+    (0xacae, &[0x81, 0xbc], false),
+    (0xacb1, &[0x81, 0xbd], false),
+    (0xacb2, &[0x81, 0xbe], false),
+    (0xacb3, &[0x81, 0xbf], false),
+    (0xacb4, &[0x81, 0xc0], false),
+    (0xacb5, &[0x81, 0xc1], false),
+    (0xacb6, &[0x81, 0xc2], false),
+    (0xacb7, &[0x81, 0xc3], false),
+    (0xacba, &[0x81, 0xc4], false),
+    (0xacbe, &[0x81, 0xc5], false),
+    (0xacbf, &[0x81, 0xc6], false),
+    (0xacc0, &[0x81, 0xc7], false),
+    (0xacc2, &[0x81, 0xc8], false),
+    (0xacc3, &[0x81, 0xc9], false),
+    (0xacc5, &[0x81, 0xca], false),
+    (0xacc6, &[0x81, 0xcb], false),
+    (0xacc7, &[0x81, 0xcc], false),
+    (0xacc9, &[0x81, 0xcd], false),
+    (0xacca, &[0x81, 0xce], false),
+    (0xaccb, &[0x81, 0xcf], false),
+    (0xaccd, &[0x81, 0xd0], false),
+    (0xacce, &[0x81, 0xd1], false),
+    (0xaccf, &[0x81, 0xd2], false),
+    (0xacd0, &[0x81, 0xd3], false),
+    (0xacd1, &[0x81, 0xd4], false),
+    (0xacd2, &[0x81, 0xd5], false),
+    (0xacd3, &[0x81, 0xd6], false),
+    (0xacd4, &[0x81, 0xd7], false),
+    (0xacd6, &[0x81, 0xd8], false),
+    (0xacd8, &[0x81, 0xd9], false),
+    (0xacd9, &[0x81, 0xda], false),
+    (0xacda, &[0x81, 0xdb], false),
+    (0xacdb, &[0x81, 0xdc], false),
+    (0xacdc, &[0x81, 0xdd], false),
+    (0xacdd, &[0x81, 0xde], false),
+    (0xacde, &[0x81, 0xdf], false),
+    (0xacdf, &[0x81, 0xe0], false),
+    (0xace2, &[0x81, 0xe1], false),
+    (0xace3, &[0x81, 0xe2], false),
+    (0xace5, &[0x81, 0xe3], false),
+    (0xace6, &[0x81, 0xe4], false),
+    (0xace9, &[0x81, 0xe5], false),
+    (0xaceb, &[0x81, 0xe6], false),
+    (0xaced, &[0x81, 0xe7], false),
+    (0xacee, &[0x81, 0xe8], false),
+    (0xacf2, &[0x81, 0xe9], false),
+    (0xacf4, &[0x81, 0xea], false),
+    (0xacf7, &[0x81, 0xeb], false),
+    (0xacf8, &[0x81, 0xec], false),
+    (0xacf9, &[0x81, 0xed], false),
+    (0xacfa, &[0x81, 0xee], false),
+    (0xacfb, &[0x81, 0xef], false),
+    (0xacfe, &[0x81, 0xf0], false),
+    (0xacff, &[0x81, 0xf1], false),
+    (0xad01, &[0x81, 0xf2], false),
+    (0xad02, &[0x81, 0xf3], false),
+    (0xad03, &[0x81, 0xf4], false),
+    (0xad05, &[0x81, 0xf5], false),
+    (0xad07, &[0x81, 0xf6], false),
+    (0xad08, &[0x81, 0xf7], false),
+    (0xad09, &[0x81, 0xf8], false),
+    (0xad0a, &[0x81, 0xf9], false),
+    (0xad0b, &[0x81, 0xfa], false),
+    (0xad0e, &[0x81, 0xfb], false),
+    (0xad10, &[0x81, 0xfc], false),
+    (0xad12, &[0x81, 0xfd], false),
+    (0xad13, &[0x81, 0xfe], false),
+    (0xad14, &[0x82, 0x41], false),
+    (0xad15, &[0x82, 0x42], false),
+    (0xad16, &[0x82, 0x43], false),
+    (0xad17, &[0x82, 0x44], false),
+    (0xad19, &[0x82, 0x45], false),
+    (0xad1a, &[0x82, 0x46], false),
+    (0xad1b, &[0x82, 0x47], false),
+    (0xad1d, &[0x82, 0x48], false),
+    (0xad1e, &[0x82, 0x49], false),
+    (0xad1f, &[0x82, 0x4a], false),
+    (0xad21, &[0x82, 0x4b], false),
+    (0xad22, &[0x82, 0x4c], false),
+    (0xad23, &[0x82, 0x4d], false),
+    (0xad24, &[0x82, 0x4e], false),
+    (0xad25, &[0x82, 0x4f], false),
+    (0xad26, &[0x82, 0x50], false),
+    (0xad27, &[0x82, 0x51], false),
+    (0xad28, &[0x82, 0x52], false),
+    (0xad2a, &[0x82, 0x53], false),
+    (0xad2b, &[0x82, 0x54], false),
+    (0xad2e, &[0x82, 0x55], false),
+    (0xad2f, &[0x82, 0x56], false),
+    (0xad30, &[0x82, 0x57], false),
+    (0xad31, &[0x82, 0x58], false),
+    (0xad32, &[0x82, 0x59], false),
+    (0xad33, &[0x82, 0x5a], false),
+    (0xad36, &[0x82, 0x61], false),
+    (0xad37, &[0x82, 0x62], false),
+    (0xad39, &[0x82, 0x63], false),
+    (0xad3a, &[0x82, 0x64], false),
+    (0xad3b, &[0x82, 0x65], false),
+    (0xad3d, &[0x82, 0x66], false),
+    (0xad3e, &[0x82, 0x67], false),
+    (0xad3f, &[0x82, 0x68], false),
+    (0xad40, &[0x82, 0x69], false),
+    (0xad41, &[0x82, 0x6a], false),
+    (0xad42, &[0x82, 0x6b], false),
+    (0xad43, &[0x82, 0x6c], false),
+    (0xad46, &[0x82, 0x6d], false),
+    (0xad48, &[0x82, 0x6e], false),
+    (0xad4a, &[0x82, 0x6f], false),
+    (0xad4b, &[0x82, 0x70], false),
+    (0xad4c, &[0x82, 0x71], false),
+    (0xad4d, &[0x82, 0x72], false),
+    (0xad4e, &[0x82, 0x73], false),
+    (0xad4f, &[0x82, 0x74], false),
+    (0xad51, &[0x82, 0x75], false),
+    (0xad52, &[0x82, 0x76], false),
+    (0xad53, &[0x82, 0x77], false),
+    (0xad55, &[0x82, 0x78], false),
+    (0xad56, &[0x82, 0x79], false),
+    (0xad57, &[0x82, 0x7a], false),
+    (0xad59, &[0x82, 0x81], false),
+    (0xad5a, &[0x82, 0x82], false),
+    (0xad5b, &[0x82, 0x83], false),
+    (0xad5c, &[0x82, 0x84], false),
+    (0xad5d, &[0x82, 0x85], false),
+    (0xad5e, &[0x82, 0x86], false),
+    (0xad5f, &[0x82, 0x87], false),
+    (0xad60, &[0x82, 0x88], false),
+    (0xad62, &[0x82, 0x89], false),
+    (0xad64, &[0x82, 0x8a], false),
+    (0xad65, &[0x82, 0x8b], false),
+    (0xad66, &[0x82, 0x8c], false),
+    (0xad67, &[0x82, 0x8d], false),
+    (0xad68, &[0x82, 0x8e], false),
+    (0xad69, &[0x82, 0x8f], false),
+    (0xad6a, &[0x82, 0x90], false),
+    (0xad6b, &[0x82, 0x91], false),
+    (0xad6e, &[0x82, 0x92], false),
+    (0xad6f, &[0x82, 0x93], false),
+    (0xad71, &[0x82, 0x94], false),
+    (0xad72, &[0x82, 0x95], false),
+    (0xad77, &[0x82, 0x96], false),
+    (0xad78, &[0x82, 0x97], false),
+    (0xad79, &[0x82, 0x98], false),
+    (0xad7a, &[0x82, 0x99], false),
+    (0xad7e, &[0x82, 0x9a], false),
+    (0xad80, &[0x82, 0x9b], false),
+    (0xad83, &[0x82, 0x9c], false),
+    (0xad84, &[0x82, 0x9d], false),
+    (0xad85, &[0x82, 0x9e], false),
+    (0xad86, &[0x82, 0x9f], false),
+    (0xad87, &[0x82, 0xa0], false),
+    (0xad8a, &[0x82, 0xa1], false),
+    (0xad8b, &[0x82, 0xa2], false),
+    (0xad8d, &[0x82, 0xa3], false),
+    (0xad8e, &[0x82, 0xa4], false),
+    (0xad8f, &[0x82, 0xa5], false),
+    (0xad91, &[0x82, 0xa6], false),
+    (0xad92, &[0x82, 0xa7], false),
+    (0xad93, &[0x82, 0xa8], false),
+    (0xad94, &[0x82, 0xa9], false),
+    (0xad95, &[0x82, 0xaa], false),
+    (0xad96, &[0x82, 0xab], false),
+    (0xad97, &[0x82, 0xac], false),
+    (0xad98, &[0x82, 0xad], false),
+    (0xad99, &[0x82, 0xae], false),
+    (0xad9a, &[0x82, 0xaf], false),
+    (0xad9b, &[0x82, 0xb0], false),
+    (0xad9e, &[0x82, 0xb1], false),
+    (0xad9f, &[0x82, 0xb2], false),
+    (0xada0, &[0x82, 0xb3], false),
+    (0xada1, &[0x82, 0xb4], false),
+    (0xada2, &[0x82, 0xb5], false),
+    (0xada3, &[0x82, 0xb6], false),
+    (0xada5, &[0x82, 0xb7], false),
+    (0xada6, &[0x82, 0xb8], false),
+    (0xada7, &[0x82, 0xb9], false),
+    (0xada8, &[0x82, 0xba], false),
+    (0xada9, &[0x82, 0xbb], false),
+    (0xadaa, &[0x82, 0xbc], false),
+    (0xadab, &[0x82, 0xbd], false),
+    (0xadac, &[0x82, 0xbe], false),
+    (0xadad, &[0x82, 0xbf], false),
+    (0xadae, &[0x82, 0xc0], false),
+    (0xadaf, &[0x82, 0xc1], false),
+    (0xadb0, &[0x82, 0xc2], false),
+    (0xadb1, &[0x82, 0xc3], false),
+    (0xadb2, &[0x82, 0xc4], false),
+    (0xadb3, &[0x82, 0xc5], false),
+    (0xadb4, &[0x82, 0xc6], false),
+    (0xadb5, &[0x82, 0xc7], false),
+    (0xadb6, &[0x82, 0xc8], false),
+    (0xadb8, &[0x82, 0xc9], false),
+    (0xadb9, &[0x82, 0xca], false),
+    (0xadba, &[0x82, 0xcb], false),
+    (0xadbb, &[0x82, 0xcc], false),
+    (0xadbc, &[0x82, 0xcd], false),
+    (0xadbd, &[0x82, 0xce], false),
+    (0xadbe, &[0x82, 0xcf], false),
+    (0xadbf, &[0x82, 0xd0], false),
+    (0xadc2, &[0x82, 0xd1], false),
+    (0xadc3, &[0x82, 0xd2], false),
+    (0xadc5, &[0x82, 0xd3], false),
+    (0xadc6, &[0x82, 0xd4], false),
+    (0xadc7, &[0x82, 0xd5], false),
+    (0xadc9, &[0x82, 0xd6], false),
+    (0xadca, &[0x82, 0xd7], false),
+    (0xadcb, &[0x82, 0xd8], false),
+    (0xadcc, &[0x82, 0xd9], false),
+    (0xadcd, &[0x82, 0xda], false),
+    (0xadce, &[0x82, 0xdb], false),
+    (0xadcf, &[0x82, 0xdc], false),
+    (0xadd2, &[0x82, 0xdd], false),
+    (0xadd4, &[0x82, 0xde], false),
+    (0xadd5, &[0x82, 0xdf], false),
+    (0xadd6, &[0x82, 0xe0], false),
+    (0xadd7, &[0x82, 0xe1], false),
+    (0xadd8, &[0x82, 0xe2], false),
+    (0xadd9, &[0x82, 0xe3], false),
+    (0xadda, &[0x82, 0xe4], false),
+    (0xaddb, &[0x82, 0xe5], false),
+    (0xaddd, &[0x82, 0xe6], false),
+    (0xadde, &[0x82, 0xe7], false),
+    (0xaddf, &[0x82, 0xe8], false),
+    (0xade1, &[0x82, 0xe9], false),
+    (0xade2, &[0x82, 0xea], false),
+    (0xade3, &[0x82, 0xeb], false),
+    (0xade5, &[0x82, 0xec], false),
+    (0xade6, &[0x82, 0xed], false),
+    (0xade7, &[0x82, 0xee], false),
+    (0xade8, &[0x82, 0xef], false),
+    (0xade9, &[0x82, 0xf0], false),
+    (0xadea, &[0x82, 0xf1], false),
+    (0xadeb, &[0x82, 0xf2], false),
+    (0xadec, &[0x82, 0xf3], false),
+    (0xaded, &[0x82, 0xf4], false),
+    (0xadee, &[0x82, 0xf5], false),
+    (0xadef, &[0x82, 0xf6], false),
+    (0xadf0, &[0x82, 0xf7], false),
+    (0xadf1, &[0x82, 0xf8], false),
+    (0xadf2, &[0x82, 0xf9], false),
+    (0xadf3, &[0x82, 0xfa], false),
+    (0xadf4, &[0x82, 0xfb], false),
+    (0xadf5, &[0x82, 0xfc], false),
+    (0xadf6, &[0x82, 0xfd], false),
+    (0xadf7, &[0x82, 0xfe], false),
+    (0xadfa, &[0x83, 0x41], false),
+    (0xadfb, &[0x83, 0x42], false),
+    (0xadfd, &[0x83, 0x43], false),
+    (0xadfe, &[0x83, 0x44], false),
+    (0xae02, &[0x83, 0x45], false),
+    (0xae03, &[0x83, 0x46], false),
+    (0xae04, &[0x83, 0x47], false),
+    (0xae05, &[0x83, 0x48], false),
+    (0xae06, &[0x83, 0x49], false),
+    (0xae07, &[0x83, 0x4a], false),
+    (0xae0a, &[0x83, 0x4b], false),
+    (0xae0c, &[0x83, 0x4c], false),
+    (0xae0e, &[0x83, 0x4d], false),
+    (0xae0f, &[0x83, 0x4e], false),
+    (0xae10, &[0x83, 0x4f], false),
+    (0xae11, &[0x83, 0x50], false),
+    (0xae12, &[0x83, 0x51], false),
+    (0xae13, &[0x83, 0x52], false),
+    (0xae15, &[0x83, 0x53], false),
+    (0xae16, &[0x83, 0x54], false),
+    (0xae17, &[0x83, 0x55], false),
+    (0xae18, &[0x83, 0x56], false),
+    (0xae19, &[0x83, 0x57], false),
+    (0xae1a, &[0x83, 0x58], false),
+    (0xae1b, &[0x83, 0x59], false),
+    (0xae1c, &[0x83, 0x5a], false),
+    (0xae1d, &[0x83, 0x61], false),
+    (0xae1e, &[0x83, 0x62], false),
+    (0xae1f, &[0x83, 0x63], false),
+    (0xae20, &[0x83, 0x64], false),
+    (0xae21, &[0x83, 0x65], false),
+    (0xae22, &[0x83, 0x66], false),
+    (0xae23, &[0x83, 0x67], false),
+    (0xae24, &[0x83, 0x68], false),
+    (0xae25, &[0x83, 0x69], false),
+    (0xae26, &[0x83, 0x6a], false),
+    (0xae27, &[0x83, 0x6b], false),
+    (0xae28, &[0x83, 0x6c], false),
+    (0xae29, &[0x83, 0x6d], false),
+    (0xae2a, &[0x83, 0x6e], false),
+    (0xae2b, &[0x83, 0x6f], false),
+    (0xae2c, &[0x83, 0x70], false),
+    (0xae2d, &[0x83, 0x71], false),
+    (0xae2e, &[0x83, 0x72], false),
+    (0xae2f, &[0x83, 0x73], false),
+    (0xae32, &[0x83, 0x74], false),
+    (0xae33, &[0x83, 0x75], false),
+    (0xae35, &[0x83, 0x76], false),
+    (0xae36, &[0x83, 0x77], false),
+    (0xae39, &[0x83, 0x78], false),
+    (0xae3b, &[0x83, 0x79], false),
+    (0xae3c, &[0x83, 0x7a], false),
+    (0xae3d, &[0x83, 0x81], false),
+    (0xae3e, &[0x83, 0x82], false),
+    (0xae3f, &[0x83, 0x83], false),
+    (0xae42, &[0x83, 0x84], false),
+    (0xae44, &[0x83, 0x85], false),
+    (0xae47, &[0x83, 0x86], false),
+    (0xae48, &[0x83, 0x87], false),
+    (0xae49, &[0x83, 0x88], false),
+    (0xae4b, &[0x83, 0x89], false),
+    (0xae4f, &[0x83, 0x8a], false),
+    (0xae51, &[0x83, 0x8b], false),
+    (0xae52, &[0x83, 0x8c], false),
+    (0xae53, &[0x83, 0x8d], false),
+    (0xae55, &[0x83, 0x8e], false),
+    (0xae57, &[0x83, 0x8f], false),
+    (0xae58, &[0x83, 0x90], false),
+    (0xae59, &[0x83, 0x91], false),
+    (0xae5a, &[0x83, 0x92], false),
+    (0xae5b, &[0x83, 0x93], false),
+    (0xae5e, &[0x83, 0x94], false),
+    (0xae62, &[0x83, 0x95], false),
+    (0xae63, &[0x83, 0x96], false),
+    (0xae64, &[0x83, 0x97], false),
+    (0xae66, &[0x83, 0x98], false),
+    (0xae67, &[0x83, 0x99], false),
+    (0xae6a, &[0x83, 0x9a], false),
+    (0xae6b, &[0x83, 0x9b], false),
+    (0xae6d, &[0x83, 0x9c], false),
+    (0xae6e, &[0x83, 0x9d], false),
+    (0xae6f, &[0x83, 0x9e], false),
+    (0xae71, &[0x83, 0x9f], false),
+    (0xae72, &[0x83, 0xa0], false),
+    (0xae73, &[0x83, 0xa1], false),
+    (0xae74, &[0x83, 0xa2], false),
+    (0xae75, &[0x83, 0xa3], false),
+    (0xae76, &[0x83, 0xa4], false),
+    (0xae77, &[0x83, 0xa5], false),
+    (0xae7a, &[0x83, 0xa6], false),
+    (0xae7e, &[0x83, 0xa7], false),
+    (0xae7f, &[0x83, 0xa8], false),
+    (0xae80, &[0x83, 0xa9], false),
+    (0xae81, &[0x83, 0xaa], false),
+    (0xae82, &[0x83, 0xab], false),
+    (0xae83, &[0x83, 0xac], false),
+    (0xae86, &[0x83, 0xad], false),
+    (0xae87, &[0x83, 0xae], false),
+    (0xae88, &[0x83, 0xaf], false),
+    (0xae89, &[0x83, 0xb0], false),
+    (0xae8a, &[0x83, 0xb1], false),
+    (0xae8b, &[0x83, 0xb2], false),
+    (0xae8d, &[0x83, 0xb3], false),
+    (0xae8e, &[0x83, 0xb4], false),
+    (0xae8f, &[0x83, 0xb5], false),
+    (0xae90, &[0x83, 0xb6], false),
+    (0xae91, &[0x83, 0xb7], false),
+    (0xae92, &[0x83, 0xb8], false),
+    (0xae93, &[0x83, 0xb9], false),
+    (0xae94, &[0x83, 0xba], false),
+    (0xae95, &[0x83, 0xbb], false),
+    (0xae96, &[0x83, 0xbc], false),
+    (0xae97, &[0x83, 0xbd], false),
+    (0xae98, &[0x83, 0xbe], false),
+    (0xae99, &[0x83, 0xbf], false),
+    (0xae9a, &[0x83, 0xc0], false),
+    (0xae9b, &[0x83, 0xc1], false),
+    (0xae9c, &[0x83, 0xc2], false),
+    (0xae9d, &[0x83, 0xc3], false),
+    (0xae9e, &[0x83, 0xc4], false),
+    (0xae9f, &[0x83, 0xc5], false),
+    (0xaea0, &[0x83, 0xc6], false),
+    (0xaea1, &[0x83, 0xc7], false),
+    (0xaea2, &[0x83, 0xc8], false),
+    (0xaea3, &[0x83, 0xc9], false),
+    (0xaea4, &[0x83, 0xca], false),
+    (0xaea5, &[0x83, 0xcb], false),
+    (0xaea6, &[0x83, 0xcc], false),
+    (0xaea7, &[0x83, 0xcd], false),
+    (0xaea8, &[0x83, 0xce], false),
+    (0xaea9, &[0x83, 0xcf], false),
+    (0xaeaa, &[0x83, 0xd0], false),
+    (0xaeab, &[0x83, 0xd1], false),
+    (0xaeac, &[0x83, 0xd2], false),
+    (0xaead, &[0x83, 0xd3], false),
+    (0xaeae, &[0x83, 0xd4], false),
+    (0xaeaf, &[0x83, 0xd5], false),
+    (0xaeb0, &[0x83, 0xd6], false),
+    (0xaeb1, &[0x83, 0xd7], false),
+    (0xaeb2, &[0x83, 0xd8], false),
+    (0xaeb3, &[0x83, 0xd9], false),
+    (0xaeb4, &[0x83, 0xda], false),
+    (0xaeb5, &[0x83, 0xdb], false),
+    (0xaeb6, &[0x83, 0xdc], false),
+    (0xaeb7, &[0x83, 0xdd], false),
+    (0xaeb8, &[0x83, 0xde], false),
+    (0xaeb9, &[0x83, 0xdf], false),
+    (0xaeba, &[0x83, 0xe0], false),
+    (0xaebb, &[0x83, 0xe1], false),
+    (0xaebf, &[0x83, 0xe2], false),
+    (0xaec1, &[0x83, 0xe3], false),
+    (0xaec2, &[0x83, 0xe4], false),
+    (0xaec3, &[0x83, 0xe5], false),
+    (0xaec5, &[0x83, 0xe6], false),
+    (0xaec6, &[0x83, 0xe7], false),
+    (0xaec7, &[0x83, 0xe8], false),
+    (0xaec8, &[0x83, 0xe9], false),
+    (0xaec9, &[0x83, 0xea], false),
+    (0xaeca, &[0x83, 0xeb], false),
+    (0xaecb, &[0x83, 0xec], false),
+    (0xaece, &[0x83, 0xed], false),
+    (0xaed2, &[0x83, 0xee], false),
+    (0xaed3, &[0x83, 0xef], false),
+    (0xaed4, &[0x83, 0xf0], false),
+    (0xaed5, &[0x83, 0xf1], false),
+    (0xaed6, &[0x83, 0xf2], false),
+    (0xaed7, &[0x83, 0xf3], false),
+    (0xaeda, &[0x83, 0xf4], false),
+    (0xaedb, &[0x83, 0xf5], false),
+    (0xaedd, &[0x83, 0xf6], false),
+    (0xaede, &[0x83, 0xf7], false),
+    (0xaedf, &[0x83, 0xf8], false),
+    (0xaee0, &[0x83, 0xf9], false),
+    (0xaee1, &[0x83, 0xfa], false),
+    (0xaee2, &[0x83, 0xfb], false),
+    (0xaee3, &[0x83, 0xfc], false),
+    (0xaee4, &[0x83, 0xfd], false),
+    (0xaee5, &[0x83, 0xfe], false),
+    (0xaee6, &[0x84, 0x41], false),
+    (0xaee7, &[0x84, 0x42], false),
+    (0xaee9, &[0x84, 0x43], false),
+    (0xaeea, &[0x84, 0x44], false),
+    (0xaeec, &[0x84, 0x45], false),
+    (0xaeee, &[0x84, 0x46], false),
+    (0xaeef, &[0x84, 0x47], false),
+    (0xaef0, &[0x84, 0x48], false),
+    (0xaef1, &[0x84, 0x49], false),
+    (0xaef2, &[0x84, 0x4a], false),
+    (0xaef3, &[0x84, 0x4b], false),
+    (0xaef5, &[0x84, 0x4c], false),
+    (0xaef6, &[0x84, 0x4d], false),
+    (0xaef7, &[0x84, 0x4e], false),
+    (0xaef9, &[0x84, 0x4f], false),
+    (0xaefa, &[0x84, 0x50], false),
+    (0xaefb, &[0x84, 0x51], false),
+    (0xaefd, &[0x84, 0x52], false),
+    (0xaefe, &[0x84, 0x53], false),
+    (0xaeff, &[0x84, 0x54], false),
+    (0xaf00, &[0x84, 0x55], false),
+    (0xaf01, &[0x84, 0x56], false),
+    (0xaf02, &[0x84, 0x57], false),
+    (0xaf03, &[0x84, 0x58], false),
+    (0xaf04, &[0x84, 0x59], false),
+    (0xaf05, &[0x84, 0x5a], false),
+    (0xaf06, &[0x84, 0x61], false),
+    (0xaf09, &[0x84, 0x62], false),
+    (0xaf0a, &[0x84, 0x63], false),
+    (0xaf0b, &[0x84, 0x64], false),
+    (0xaf0c, &[0x84, 0x65], false),
+    (0xaf0e, &[0x84, 0x66], false),
+    (0xaf0f, &[0x84, 0x67], false),
+    (0xaf11, &[0x84, 0x68], false),
+    (0xaf12, &[0x84, 0x69], false),
+    (0xaf13, &[0x84, 0x6a], false),
+    (0xaf14, &[0x84, 0x6b], false),
+    (0xaf15, &[0x84, 0x6c], false),
+    (0xaf16, &[0x84, 0x6d], false),
+    (0xaf17, &[0x84, 0x6e], false),
+    (0xaf18, &[0x84, 0x6f], false),
+    (0xaf19, &[0x84, 0x70], false),
+    (0xaf1a, &[0x84, 0x71], false),
+    (0xaf1b, &[0x84, 0x72], false),
+    (0xaf1c, &[0x84, 0x73], false),
+    (0xaf1d, &[0x84, 0x74], false),
+    (0xaf1e, &[0x84, 0x75], false),
+    (0xaf1f, &[0x84, 0x76], false),
+    (0xaf20, &[0x84, 0x77], false),
+    (0xaf21, &[0x84, 0x78], false),
+    (0xaf22, &[0x84, 0x79], false),
+    (0xaf23, &[0x84, 0x7a], false),
+    (0xaf24, &[0x84, 0x81], false),
+    (0xaf25, &[0x84, 0x82], false),
+    (0xaf26, &[0x84, 0x83], false),
+    (0xaf27, &[0x84, 0x84], false),
+    (0xaf28, &[0x84, 0x85], false),
+    (0xaf29, &[0x84, 0x86], false),
+    (0xaf2a, &[0x84, 0x87], false),
+    (0xaf2b, &[0x84, 0x88], false),
+    (0xaf2e, &[0x84, 0x89], false),
+    (0xaf2f, &[0x84, 0x8a], false),
+    (0xaf31, &[0x84, 0x8b], false),
+    (0xaf33, &[0x84, 0x8c], false),
+    (0xaf35, &[0x84, 0x8d], false),
+    (0xaf36, &[0x84, 0x8e], false),
+    (0xaf37, &[0x84, 0x8f], false),
+    (0xaf38, &[0x84, 0x90], false),
+    (0xaf39, &[0x84, 0x91], false),
+    (0xaf3a, &[0x84, 0x92], false),
+    (0xaf3b, &[0x84, 0x93], false),
+    (0xaf3e, &[0x84, 0x94], false),
+    (0xaf40, &[0x84, 0x95], false),
+    (0xaf44, &[0x84, 0x96], false),
+    (0xaf45, &[0x84, 0x97], false),
+    (0xaf46, &[0x84, 0x98], false),
+    (0xaf47, &[0x84, 0x99], false),
+    (0xaf4a, &[0x84, 0x9a], false),
+    (0xaf4b, &[0x84, 0x9b], false),
+    (0xaf4c, &[0x84, 0x9c], false),
+    (0xaf4d, &[0x84, 0x9d], false),
+    (0xaf4e, &[0x84, 0x9e], false),
+    (0xaf4f, &[0x84, 0x9f], false),
+    (0xaf51, &[0x84, 0xa0], false),
+    (0xaf52, &[0x84, 0xa1], false),
+    (0xaf53, &[0x84, 0xa2], false),
+    (0xaf54, &[0x84, 0xa3], false),
+    (0xaf55, &[0x84, 0xa4], false),
+    (0xaf56, &[0x84, 0xa5], false),
+    (0xaf57, &[0x84, 0xa6], false),
+    (0xaf58, &[0x84, 0xa7], false),
+    (0xaf59, &[0x84, 0xa8], false),
+    (0xaf5a, &[0x84, 0xa9], false),
+    (0xaf5b, &[0x84, 0xaa], false),
+    (0xaf5e, &[0x84, 0xab], false),
+    (0xaf5f, &[0x84, 0xac], false),
+    (0xaf60, &[0x84, 0xad], false),
+    (0xaf61, &[0x84, 0xae], false),
+    (0xaf62, &[0x84, 0xaf], false),
+    (0xaf63, &[0x84, 0xb0], false),
+    (0xaf66, &[0x84, 0xb1], false),
+    (0xaf67, &[0x84, 0xb2], false),
+    (0xaf68, &[0x84, 0xb3], false),
+    (0xaf69, &[0x84, 0xb4], false),
+    (0xaf6a, &[0x84, 0xb5], false),
+    (0xaf6b, &[0x84, 0xb6], false),
+    (0xaf6c, &[0x84, 0xb7], false),
+    (0xaf6d, &[0x84, 0xb8], false),
+    (0xaf6e, &[0x84, 0xb9], false),
+    (0xaf6f, &[0x84, 0xba], false),
+    (0xaf70, &[0x84, 0xbb], false),
+    (0xaf71, &[0x84, 0xbc], false),
+    (0xaf72, &[0x84, 0xbd], false),
+    (0xaf73, &[0x84, 0xbe], false),
+    (0xaf74, &[0x84, 0xbf], false),
+    (0xaf75, &[0x84, 0xc0], false),
+    (0xaf76, &[0x84, 0xc1], false),
+    (0xaf77, &[0x84, 0xc2], false),
+    (0xaf78, &[0x84, 0xc3], false),
+    (0xaf7a, &[0x84, 0xc4], false),
+    (0xaf7b, &[0x84, 0xc5], false),
+    (0xaf7c, &[0x84, 0xc6], false),
+    (0xaf7d, &[0x84, 0xc7], false),
+    (0xaf7e, &[0x84, 0xc8], false),
+    (0xaf7f, &[0x84, 0xc9], false),
+    (0xaf81, &[0x84, 0xca], false),
+    (0xaf82, &[0x84, 0xcb], false),
+    (0xaf83, &[0x84, 0xcc], false),
+    (0xaf85, &[0x84, 0xcd], false),
+    (0xaf86, &[0x84, 0xce], false),
+    (0xaf87, &[0x84, 0xcf], false),
+    (0xaf89, &[0x84, 0xd0], false),
+    (0xaf8a, &[0x84, 0xd1], false),
+    (0xaf8b, &[0x84, 0xd2], false),
+    (0xaf8c, &[0x84, 0xd3], false),
+    (0xaf8d, &[0x84, 0xd4], false),
+    (0xaf8e, &[0x84, 0xd5], false),
+    (0xaf8f, &[0x84, 0xd6], false),
+    (0xaf92, &[0x84, 0xd7], false),
+    (0xaf93, &[0x84, 0xd8], false),
+    (0xaf94, &[0x84, 0xd9], false),
+    (0xaf96, &[0x84, 0xda], false),
+    (0xaf97, &[0x84, 0xdb], false),
+    (0xaf98, &[0x84, 0xdc], false),
+    (0xaf99, &[0x84, 0xdd], false),
+    (0xaf9a, &[0x84, 0xde], false),
+    (0xaf9b, &[0x84, 0xdf], false),
+    (0xaf9d, &[0x84, 0xe0], false),
+    (0xaf9e, &[0x84, 0xe1], false),
+    (0xaf9f, &[0x84, 0xe2], false),
+    (0xafa0, &[0x84, 0xe3], false),
+    (0xafa1, &[0x84, 0xe4], false),
+    (0xafa2, &[0x84, 0xe5], false),
+    (0xafa3, &[0x84, 0xe6], false),
+    (0xafa4, &[0x84, 0xe7], false),
+    (0xafa5, &[0x84, 0xe8], false),
+    (0xafa6, &[0x84, 0xe9], false),
+    (0xafa7, &[0x84, 0xea], false),
+    (0xafa8, &[0x84, 0xeb], false),
+    (0xafa9, &[0x84, 0xec], false),
+    (0xafaa, &[0x84, 0xed], false),
+    (0xafab, &[0x84, 0xee], false),
+    (0xafac, &[0x84, 0xef], false),
+    (0xafad, &[0x84, 0xf0], false),
+    (0xafae, &[0x84, 0xf1], false),
+    (0xafaf, &[0x84, 0xf2], false),
+    (0xafb0, &[0x84, 0xf3], false),
+    (0xafb1, &[0x84, 0xf4], false),
+    (0xafb2, &[0x84, 0xf5], false),
+    (0xafb3, &[0x84, 0xf6], false),
+    (0xafb4, &[0x84, 0xf7], false),
+    (0xafb5, &[0x84, 0xf8], false),
+    (0xafb6, &[0x84, 0xf9], false),
+    (0xafb7, &[0x84, 0xfa], false),
+    (0xafba, &[0x84, 0xfb], false),
+    (0xafbb, &[0x84, 0xfc], false),
+    (0xafbd, &[0x84, 0xfd], false),
+    (0xafbe, &[0x84, 0xfe], false),
+    (0xafbf, &[0x85, 0x41], false),
+    (0xafc1, &[0x85, 0x42], false),
+    (0xafc2, &[0x85, 0x43], false),
+    (0xafc3, &[0x85, 0x44], false),
+    (0xafc4, &[0x85, 0x45], false),
+    (0xafc5, &[0x85, 0x46], false),
+    (0xafc6, &[0x85, 0x47], false),
+    (0xafca, &[0x85, 0x48], false),
+    (0xafcc, &[0x85, 0x49], false),
+    (0xafcf, &[0x85, 0x4a], false),
+    (0xafd0, &[0x85, 0x4b], false),
+    (0xafd1, &[0x85, 0x4c], false),
+    (0xafd2, &[0x85, 0x4d], false),
+    (0xafd3, &[0x85, 0x4e], false),
+    (0xafd5, &[0x85, 0x4f], false),
+    (0xafd6, &[0x85, 0x50], false),
+    (0xafd7, &[0x85, 0x51], false),
+    (0xafd8, &[0x85, 0x52], false),
+    (0xafd9, &[0x85, 0x53], false),
+    (0xafda, &[0x85, 0x54], false),
+    (0xafdb, &[0x85, 0x55], false),
+    (0xafdd, &[0x85, 0x56], false),
+    (0xafde, &[0x85, 0x57], false),
+    (0xafdf, &[0x85, 0x58], false),
+    (0xafe0, &[0x85, 0x59], false),
+    (0xafe1, &[0x85, 0x5a], false),
+    (0xafe2, &[0x85, 0x61], false),
+    (0xafe3, &[0x85, 0x62], false),
+    (0xafe4, &[0x85, 0x63], false),
+    (0xafe5, &[0x85, 0x64], false),
+    (0xafe6, &[0x85, 0x65], false),
+    (0xafe7, &[0x85, 0x66], false),
+    (0xafea, &[0x85, 0x67], false),
+    (0xafeb, &[0x85, 0x68], false),
+    (0xafec, &[0x85, 0x69], false),
+    (0xafed, &[0x85, 0x6a], false),
+    (0xafee, &[0x85, 0x6b], false),
+    (0xafef, &[0x85, 0x6c], false),
+    (0xaff2, &[0x85, 0x6d], false),
+    (0xaff3, &[0x85, 0x6e], false),
+    (0xaff5, &[0x85, 0x6f], false),
+    (0xaff6, &[0x85, 0x70], false),
+    (0xaff7, &[0x85, 0x71], false),
+    (0xaff9, &[0x85, 0x72], false),
+    (0xaffa, &[0x85, 0x73], false),
+    (0xaffb, &[0x85, 0x74], false),
+    (0xaffc, &[0x85, 0x75], false),
+    (0xaffd, &[0x85, 0x76], false),
+    (0xaffe, &[0x85, 0x77], false),
+    (0xafff, &[0x85, 0x78], false),
+    (0xb002, &[0x85, 0x79], false),
+    (0xb003, &[0x85, 0x7a], false),
+    (0xb005, &[0x85, 0x81], false),
+    (0xb006, &[0x85, 0x82], false),
+    (0xb007, &[0x85, 0x83], false),
+    (0xb008, &[0x85, 0x84], false),
+    (0xb009, &[0x85, 0x85], false),
+    (0xb00a, &[0x85, 0x86], false),
+    (0xb00b, &[0x85, 0x87], false),
+    (0xb00d, &[0x85, 0x88], false),
+    (0xb00e, &[0x85, 0x89], false),
+    (0xb00f, &[0x85, 0x8a], false),
+    (0xb011, &[0x85, 0x8b], false),
+    (0xb012, &[0x85, 0x8c], false),
+    (0xb013, &[0x85, 0x8d], false),
+    (0xb015, &[0x85, 0x8e], false),
+    (0xb016, &[0x85, 0x8f], false),
+    (0xb017, &[0x85, 0x90], false),
+    (0xb018, &[0x85, 0x91], false),
+    (0xb019, &[0x85, 0x92], false),
+    (0xb01a, &[0x85, 0x93], false),
+    (0xb01b, &[0x85, 0x94], false),
+    (0xb01e, &[0x85, 0x95], false),
+    (0xb01f, &[0x85, 0x96], false),
+    (0xb020, &[0x85, 0x97], false),
+    (0xb021, &[0x85, 0x98], false),
+    (0xb022, &[0x85, 0x99], false),
+    (0xb023, &[0x85, 0x9a], false),
+    (0xb024, &[0x85, 0x9b], false),
+    (0xb025, &[0x85, 0x9c], false),
+    (0xb026, &[0x85, 0x9d], false),
+    (0xb027, &[0x85, 0x9e], false),
+    (0xb029, &[0x85, 0x9f], false),
+    (0xb02a, &[0x85, 0xa0], false),
+    (0xb02b, &[0x85, 0xa1], false),
+    (0xb02c, &[0x85, 0xa2], false),
+    (0xb02d, &[0x85, 0xa3], false),
+    (0xb02e, &[0x85, 0xa4], false),
+    (0xb02f, &[0x85, 0xa5], false),
+    (0xb030, &[0x85, 0xa6], false),
+    (0xb031, &[0x85, 0xa7], false),
+    (0xb032, &[0x85, 0xa8], false),
+    (0xb033, &[0x85, 0xa9], false),
+    (0xb034, &[0x85, 0xaa], false),
+    (0xb035, &[0x85, 0xab], false),
+    (0xb036, &[0x85, 0xac], false),
+    (0xb037, &[0x85, 0xad], false),
+    (0xb038, &[0x85, 0xae], false),
+    (0xb039, &[0x85, 0xaf], false),
+    (0xb03a, &[0x85, 0xb0], false),
+    (0xb03b, &[0x85, 0xb1], false),
+    (0xb03c, &[0x85, 0xb2], false),
+    (0xb03d, &[0x85, 0xb3], false),
+    (0xb03e, &[0x85, 0xb4], false),
+    (0xb03f, &[0x85, 0xb5], false),
+    (0xb040, &[0x85, 0xb6], false),
+    (0xb041, &[0x85, 0xb7], false),
+    (0xb042, &[0x85, 0xb8], false),
+    (0xb043, &[0x85, 0xb9], false),
+    (0xb046, &[0x85, 0xba], false),
+    (0xb047, &[0x85, 0xbb], false),
+    (0xb049, &[0x85, 0xbc], false),
+    (0xb04b, &[0x85, 0xbd], false),
+    (0xb04d, &[0x85, 0xbe], false),
+    (0xb04f, &[0x85, 0xbf], false),
+    (0xb050, &[0x85, 0xc0], false),
+    (0xb051, &[0x85, 0xc1], false),
+    (0xb052, &[0x85, 0xc2], false),
+    (0xb056, &[0x85, 0xc3], false),
+    (0xb058, &[0x85, 0xc4], false),
+    (0xb05a, &[0x85, 0xc5], false),
+    (0xb05b, &[0x85, 0xc6], false),
+    (0xb05c, &[0x85, 0xc7], false),
+    (0xb05e, &[0x85, 0xc8], false),
+    (0xb05f, &[0x85, 0xc9], false),
+    (0xb060, &[0x85, 0xca], false),
+    (0xb061, &[0x85, 0xcb], false),
+    (0xb062, &[0x85, 0xcc], false),
+    (0xb063, &[0x85, 0xcd], false),
+    (0xb064, &[0x85, 0xce], false),
+    (0xb065, &[0x85, 0xcf], false),
+    (0xb066, &[0x85, 0xd0], false),
+    (0xb067, &[0x85, 0xd1], false),
+    (0xb068, &[0x85, 0xd2], false),
+    (0xb069, &[0x85, 0xd3], false),
+    (0xb06a, &[0x85, 0xd4], false),
+    (0xb06b, &[0x85, 0xd5], false),
+    (0xb06c, &[0x85, 0xd6], false),
+    (0xb06d, &[0x85, 0xd7], false),
+    (0xb06e, &[0x85, 0xd8], false),
+    (0xb06f, &[0x85, 0xd9], false),
+    (0xb070, &[0x85, 0xda], false),
+    (0xb071, &[0x85, 0xdb], false),
+    (0xb072, &[0x85, 0xdc], false),
+    (0xb073, &[0x85, 0xdd], false),
+    (0xb074, &[0x85, 0xde], false),
+    (0xb075, &[0x85, 0xdf], false),
+    (0xb076, &[0x85, 0xe0], false),
+    (0xb077, &[0x85, 0xe1], false),
+    (0xb078, &[0x85, 0xe2], false),
+    (0xb079, &[0x85, 0xe3], false),
+    (0xb07a, &[0x85, 0xe4], false),
+    (0xb07b, &[0x85, 0xe5], false),
+    (0xb07e, &[0x85, 0xe6], false),
+    (0xb07f, &[0x85, 0xe7], false),
+    (0xb081, &[0x85, 0xe8], false),
+    (0xb082, &[0x85, 0xe9], false),
+    (0xb083, &[0x85, 0xea], false),
+    (0xb085, &[0x85, 0xeb], false),
+    (0xb086, &[0x85, 0xec], false),
+    (0xb087, &[0x85, 0xed], false),
+    (0xb088, &[0x85, 0xee], false),
+    (0xb089, &[0x85, 0xef], false),
+    (0xb08a, &[0x85, 0xf0], false),
+    (0xb08b, &[0x85, 0xf1], false),
+    (0xb08e, &[0x85, 0xf2], false),
+    (0xb090, &[0x85, 0xf3], false),
+    (0xb092, &[0x85, 0xf4], false),
+    (0xb093, &[0x85, 0xf5], false),
+    (0xb094, &[0x85, 0xf6], false),
+    (0xb095, &[0x85, 0xf7], false),
+    (0xb096, &[0x85, 0xf8], false),
+    (0xb097, &[0x85, 0xf9], false),
+    (0xb09b, &[0x85, 0xfa], false),
+    (0xb09d, &[0x85, 0xfb], false),
+    (0xb09e, &[0x85, 0xfc], false),
+    (0xb0a3, &[0x85, 0xfd], false),
+    (0xb0a4, &[0x85, 0xfe], false),
+    (0xb0a5, &[0x86, 0x41], false),
+    (0xb0a6, &[0x86, 0x42], false),
+    (0xb0a7, &[0x86, 0x43], false),
+    (0xb0aa, &[0x86, 0x44], false),
+    (0xb0b0, &[0x86, 0x45], false),
+    (0xb0b2, &[0x86, 0x46], false),
+    (0xb0b6, &[0x86, 0x47], false),
+    (0xb0b7, &[0x86, 0x48], false),
+    (0xb0b9, &[0x86, 0x49], false),
+    (0xb0ba, &[0x86, 0x4a], false),
+    (0xb0bb, &[0x86, 0x4b], false),
+    (0xb0bd, &[0x86, 0x4c], false),
+    (0xb0be, &[0x86, 0x4d], false),
+    (0xb0bf, &[0x86, 0x4e], false),
+    (0xb0c0, &[0x86, 0x4f], false),
+    (0xb0c1, &[0x86, 0x50], false),
+    (0xb0c2, &[0x86, 0x51], false),
+    (0xb0c3, &[0x86, 0x52], false),
+    (0xb0c6, &[0x86, 0x53], false),
+    (0xb0ca, &[0x86, 0x54], false),
+    (0xb0cb, &[0x86, 0x55], false),
+    (0xb0cc, &[0x86, 0x56], false),
+    (0xb0cd, &[0x86, 0x57], false),
+    (0xb0ce, &[0x86, 0x58], false),
+    (0xb0cf, &[0x86, 0x59], false),
+    (0xb0d2, &[0x86, 0x5a], false),
+    (0xb0d3, &[0x86, 0x61], false),
+    (0xb0d5, &[0x86, 0x62], false),
+    (0xb0d6, &[0x86, 0x63], false),
+    (0xb0d7, &[0x86, 0x64], false),
+    (0xb0d9, &[0x86, 0x65], false),
+    (0xb0da, &[0x86, 0x66], false),
+    (0xb0db, &[0x86, 0x67], false),
+    (0xb0dc, &[0x86, 0x68], false),
+    (0xb0dd, &[0x86, 0x69], false),
+    (0xb0de, &[0x86, 0x6a], false),
+    (0xb0df, &[0x86, 0x6b], false),
+    (0xb0e1, &[0x86, 0x6c], false),
+    (0xb0e2, &[0x86, 0x6d], false),
+    (0xb0e3, &[0x86, 0x6e], false),
+    (0xb0e4, &[0x86, 0x6f], false),
+    (0xb0e6, &[0x86, 0x70], false),
+    (0xb0e7, &[0x86, 0x71], false),
+    (0xb0e8, &[0x86, 0x72], false),
+    (0xb0e9, &[0x86, 0x73], false),
+    (0xb0ea, &[0x86, 0x74], false),
+    (0xb0eb, &[0x86, 0x75], false),
+    (0xb0ec, &[0x86, 0x76], false),
+    (0xb0ed, &[0x86, 0x77], false),
+    (0xb0ee, &[0x86, 0x78], false),
+    (0xb0ef, &[0x86, 0x79], false),
+    (0xb0f0, &[0x86, 0x7a], false),
+    (0xb0f1, &[0x86, 0x81], false),
+    (0xb0f2, &[0x86, 0x82], false),
+    (0xb0f3, &[0x86, 0x83], false),
+    (0xb0f4, &[0x86, 0x84], false),
+    (0xb0f5, &[0x86, 0x85], false),
+    (0xb0f6, &[0x86, 0x86], false),
+    (0xb0f7, &[0x86, 0x87], false),
+    (0xb0f8, &[0x86, 0x88], false),
+    (0xb0f9, &[0x86, 0x89], false),
+    (0xb0fa, &[0x86, 0x8a], false),
+    (0xb0fb, &[0x86, 0x8b], false),
+    (0xb0fc, &[0x86, 0x8c], false),
+    (0xb0fd, &[0x86, 0x8d], false),
+    (0xb0fe, &[0x86, 0x8e], false),
+    (0xb0ff, &[0x86, 0x8f], false),
+    (0xb100, &[0x86, 0x90], false),
+    (0xb101, &[0x86, 0x91], false),
+    (0xb102, &[0x86, 0x92], false),
+    (0xb103, &[0x86, 0x93], false),
+    (0xb104, &[0x86, 0x94], false),
+    (0xb105, &[0x86, 0x95], false),
+    (0xb106, &[0x86, 0x96], false),
+    (0xb107, &[0x86, 0x97], false),
+    (0xb10a, &[0x86, 0x98], false),
+    (0xb10d, &[0x86, 0x99], false),
+    (0xb10e, &[0x86, 0x9a], false),
+    (0xb10f, &[0x86, 0x9b], false),
+    (0xb111, &[0x86, 0x9c], false),
+    (0xb114, &[0x86, 0x9d], false),
+    (0xb115, &[0x86, 0x9e], false),
+    (0xb116, &[0x86, 0x9f], false),
+    (0xb117, &[0x86, 0xa0], false),
+    (0xb11a, &[0x86, 0xa1], false),
+    (0xb11e, &[0x86, 0xa2], false),
+    (0xb11f, &[0x86, 0xa3], false),
+    (0xb120, &[0x86, 0xa4], false),
+    (0xb121, &[0x86, 0xa5], false),
+    (0xb122, &[0x86, 0xa6], false),
+    (0xb126, &[0x86, 0xa7], false),
+    (0xb127, &[0x86, 0xa8], false),
+    (0xb129, &[0x86, 0xa9], false),
+    (0xb12a, &[0x86, 0xaa], false),
+    (0xb12b, &[0x86, 0xab], false),
+    (0xb12d, &[0x86, 0xac], false),
+    (0xb12e, &[0x86, 0xad], false),
+    (0xb12f, &[0x86, 0xae], false),
+    (0xb130, &[0x86, 0xaf], false),
+    (0xb131, &[0x86, 0xb0], false),
+    (0xb132, &[0x86, 0xb1], false),
+    (0xb133, &[0x86, 0xb2], false),
+    (0xb136, &[0x86, 0xb3], false),
+    (0xb13a, &[0x86, 0xb4], false),
+    (0xb13b, &[0x86, 0xb5], false),
+    (0xb13c, &[0x86, 0xb6], false),
+    (0xb13d, &[0x86, 0xb7], false),
+    (0xb13e, &[0x86, 0xb8], false),
+    (0xb13f, &[0x86, 0xb9], false),
+    (0xb142, &[0x86, 0xba], false),
+    (0xb143, &[0x86, 0xbb], false),
+    (0xb145, &[0x86, 0xbc], false),
+    (0xb146, &[0x86, 0xbd], false),
+    (0xb147, &[0x86, 0xbe], false),
+    (0xb149, &[0x86, 0xbf], false),
+    (0xb14a, &[0x86, 0xc0], false),
+    (0xb14b, &[0x86, 0xc1], false),
+    (0xb14c, &[0x86, 0xc2], false),
+    (0xb14d, &[0x86, 0xc3], false),
+    (0xb14e, &[0x86, 0xc4], false),
+    (0xb14f, &[0x86, 0xc5], false),
+    (0xb152, &[0x86, 0xc6], false),
+    (0xb153, &[0x86, 0xc7], false),
+    (0xb156, &[0x86, 0xc8], false),
+    (0xb157, &[0x86, 0xc9], false),
+    (0xb159, &[0x86, 0xca], false),
+    (0xb15a, &[0x86, 0xcb], false),
+    (0xb15b, &[0x86, 0xcc], false),
+    (0xb15d, &[0x86, 0xcd], false),
+    (0xb15e, &[0x86, 0xce], false),
+    (0xb15f, &[0x86, 0xcf], false),
+    (0xb161, &[0x86, 0xd0], false),
+    (0xb162, &[0x86, 0xd1], false),
+    (0xb163, &[0x86, 0xd2], false),
+    (0xb164, &[0x86, 0xd3], false),
+    (0xb165, &[0x86, 0xd4], false),
+    (0xb166, &[0x86, 0xd5], false),
+    (0xb167, &[0x86, 0xd6], false),
+    (0xb168, &[0x86, 0xd7], false),
+    (0xb169, &[0x86, 0xd8], false),
+    (0xb16a, &[0x86, 0xd9], false),
+    (0xb16b, &[0x86, 0xda], false),
+    (0xb16c, &[0x86, 0xdb], false),
+    (0xb16d, &[0x86, 0xdc], false),
+    (0xb16e, &[0x86, 0xdd], false),
+    (0xb16f, &[0x86, 0xde], false),
+    (0xb170, &[0x86, 0xdf], false),
+    (0xb171, &[0x86, 0xe0], false),
+    (0xb172, &[0x86, 0xe1], false),
+    (0xb173, &[0x86, 0xe2], false),
+    (0xb174, &[0x86, 0xe3], false),
+    (0xb175, &[0x86, 0xe4], false),
+    (0xb176, &[0x86, 0xe5], false),
+    (0xb177, &[0x86, 0xe6], false),
+    (0xb17a, &[0x86, 0xe7], false),
+    (0xb17b, &[0x86, 0xe8], false),
+    (0xb17d, &[0x86, 0xe9], false),
+    (0xb17e, &[0x86, 0xea], false),
+    (0xb17f, &[0x86, 0xeb], false),
+    (0xb181, &[0x86, 0xec], false),
+    (0xb183, &[0x86, 0xed], false),
+    (0xb184, &[0x86, 0xee], false),
+    (0xb185, &[0x86, 0xef], false),
+    (0xb186, &[0x86, 0xf0], false),
+    (0xb187, &[0x86, 0xf1], false),
+    (0xb18a, &[0x86, 0xf2], false),
+    (0xb18c, &[0x86, 0xf3], false),
+    (0xb18e, &[0x86, 0xf4], false),
+    (0xb18f, &[0x86, 0xf5], false),
+    (0xb190, &[0x86, 0xf6], false),
+    (0xb191, &[0x86, 0xf7], false),
+    (0xb195, &[0x86, 0xf8], false),
+    (0xb196, &[0x86, 0xf9], false),
+    (0xb197, &[0x86, 0xfa], false),
+    (0xb199, &[0x86, 0xfb], false),
+    (0xb19a, &[0x86, 0xfc], false),
+    (0xb19b, &[0x86, 0xfd], false),
+    (0xb19d, &[0x86, 0xfe], false),
+    (0xb19e, &[0x87, 0x41], false),
+    (0xb19f, &[0x87, 0x42], false),
+    (0xb1a0, &[0x87, 0x43], false),
+    (0xb1a1, &[0x87, 0x44], false),
+    (0xb1a2, &[0x87, 0x45], false),
+    (0xb1a3, &[0x87, 0x46], false),
+    (0xb1a4, &[0x87, 0x47], false),
+    (0xb1a5, &[0x87, 0x48], false),
+    (0xb1a6, &[0x87, 0x49], false),
+    (0xb1a7, &[0x87, 0x4a], false),
+    (0xb1a9, &[0x87, 0x4b], false),
+    (0xb1aa, &[0x87, 0x4c], false),
+    (0xb1ab, &[0x87, 0x4d], false),
+    (0xb1ac, &[0x87, 0x4e], false),
+    (0xb1ad, &[0x87, 0x4f], false),
+    (0xb1ae, &[0x87, 0x50], false),
+    (0xb1af, &[0x87, 0x51], false),
+    (0xb1b0, &[0x87, 0x52], false),
+    (0xb1b1, &[0x87, 0x53], false),
+    (0xb1b2, &[0x87, 0x54], false),
+    (0xb1b3, &[0x87, 0x55], false),
+    (0xb1b4, &[0x87, 0x56], false),
+    (0xb1b5, &[0x87, 0x57], false),
+    (0xb1b6, &[0x87, 0x58], false),
+    (0xb1b7, &[0x87, 0x59], false),
+    (0xb1b8, &[0x87, 0x5a], false),
+    (0xb1b9, &[0x87, 0x61], false),
+    (0xb1ba, &[0x87, 0x62], false),
+    (0xb1bb, &[0x87, 0x63], false),
+    (0xb1bc, &[0x87, 0x64], false),
+    (0xb1bd, &[0x87, 0x65], false),
+    (0xb1be, &[0x87, 0x66], false),
+    (0xb1bf, &[0x87, 0x67], false),
+    (0xb1c0, &[0x87, 0x68], false),
+    (0xb1c1, &[0x87, 0x69], false),
+    (0xb1c2, &[0x87, 0x6a], false),
+    (0xb1c3, &[0x87, 0x6b], false),
+    (0xb1c4, &[0x87, 0x6c], false),
+    (0xb1c5, &[0x87, 0x6d], false),
+    (0xb1c6, &[0x87, 0x6e], false),
+    (0xb1c7, &[0x87, 0x6f], false),
+    (0xb1c8, &[0x87, 0x70], false),
+    (0xb1c9, &[0x87, 0x71], false),
+    (0xb1ca, &[0x87, 0x72], false),
+    (0xb1cb, &[0x87, 0x73], false),
+    (0xb1cd, &[0x87, 0x74], false),
+    (0xb1ce, &[0x87, 0x75], false),
+    (0xb1cf, &[0x87, 0x76], false),
+    (0xb1d1, &[0x87, 0x77], false),
+    (0xb1d2, &[0x87, 0x78], false),
+    (0xb1d3, &[0x87, 0x79], false),
+    (0xb1d5, &[0x87, 0x7a], false),
+    (0xb1d6, &[0x87, 0x81], false),
+    (0xb1d7, &[0x87, 0x82], false),
+    (0xb1d8, &[0x87, 0x83], false),
+    (0xb1d9, &[0x87, 0x84], false),
+    (0xb1da, &[0x87, 0x85], false),
+    (0xb1db, &[0x87, 0x86], false),
+    (0xb1de, &[0x87, 0x87], false),
+    (0xb1e0, &[0x87, 0x88], false),
+    (0xb1e1, &[0x87, 0x89], false),
+    (0xb1e2, &[0x87, 0x8a], false),
+    (0xb1e3, &[0x87, 0x8b], false),
+    (0xb1e4, &[0x87, 0x8c], false),
+    (0xb1e5, &[0x87, 0x8d], false),
+    (0xb1e6, &[0x87, 0x8e], false),
+    (0xb1e7, &[0x87, 0x8f], false),
+    (0xb1ea, &[0x87, 0x90], false),
+    (0xb1eb, &[0x87, 0x91], false),
+    (0xb1ed, &[0x87, 0x92], false),
+    (0xb1ee, &[0x87, 0x93], false),
+    (0xb1ef, &[0x87, 0x94], false),
+    (0xb1f1, &[0x87, 0x95], false),
+    (0xb1f2, &[0x87, 0x96], false),
+    (0xb1f3, &[0x87, 0x97], false),
+    (0xb1f4, &[0x87, 0x98], false),
+    (0xb1f5, &[0x87, 0x99], false),
+    (0xb1f6, &[0x87, 0x9a], false),
+    (0xb1f7, &[0x87, 0x9b], false),
+    (0xb1f8, &[0x87, 0x9c], false),
+    (0xb1fa, &[0x87, 0x9d], false),
+    (0xb1fc, &[0x87, 0x9e], false),
+    (0xb1fe, &[0x87, 0x9f], false),
+    (0xb1ff, &[0x87, 0xa0], false),
+    (0xb200, &[0x87, 0xa1], false),
+    (0xb201, &[0x87, 0xa2], false),
+    (0xb202, &[0x87, 0xa3], false),
+    (0xb203, &[0x87, 0xa4], false),
+    (0xb206, &[0x87, 0xa5], false),
+    (0xb207, &[0x87, 0xa6], false),
+    (0xb209, &[0x87, 0xa7], false),
+    (0xb20a, &[0x87, 0xa8], false),
+    (0xb20d, &[0x87, 0xa9], false),
+    (0xb20e, &[0x87, 0xaa], false),
+    (0xb20f, &[0x87, 0xab], false),
+    (0xb210, &[0x87, 0xac], false),
+    (0xb211, &[0x87, 0xad], false),
+    (0xb212, &[0x87, 0xae], false),
+    (0xb213, &[0x87, 0xaf], false),
+    (0xb216, &[0x87, 0xb0], false),
+    (0xb218, &[0x87, 0xb1], false),
+    (0xb21a, &[0x87, 0xb2], false),
+    (0xb21b, &[0x87, 0xb3], false),
+    (0xb21c, &[0x87, 0xb4], false),
+    (0xb21d, &[0x87, 0xb5], false),
+    (0xb21e, &[0x87, 0xb6], false),
+    (0xb21f, &[0x87, 0xb7], false),
+    (0xb221, &[0x87, 0xb8], false),
+    (0xb222, &[0x87, 0xb9], false),
+    (0xb223, &[0x87, 0xba], false),
+    (0xb224, &[0x87, 0xbb], false),
+    (0xb225, &[0x87, 0xbc], false),
+    (0xb226, &[0x87, 0xbd], false),
+    (0xb227, &[0x87, 0xbe], false),
+    (0xb228, &[0x87, 0xbf], false),
+    (0xb229, &[0x87, 0xc0], false),
+    (0xb22a, &[0x87, 0xc1], false),
+    (0xb22b, &[0x87, 0xc2], false),
+    (0xb22c, &[0x87, 0xc3], false),
+    (0xb22d, &[0x87, 0xc4], false),
+    (0xb22e, &[0x87, 0xc5], false),
+    (0xb22f, &[0x87, 0xc6], false),
+    (0xb230, &[0x87, 0xc7], false),
+    (0xb231, &[0x87, 0xc8], false),
+    (0xb232, &[0x87, 0xc9], false),
+    (0xb233, &[0x87, 0xca], false),
+    (0xb235, &[0x87, 0xcb], false),
+    (0xb236, &[0x87, 0xcc], false),
+    (0xb237, &[0x87, 0xcd], false),
+    (0xb238, &[0x87, 0xce], false),
+    (0xb239, &[0x87, 0xcf], false),
+    (0xb23a, &[0x87, 0xd0], false),
+    (0xb23b, &[0x87, 0xd1], false),
+    (0xb23d, &[0x87, 0xd2], false),
+    (0xb23e, &[0x87, 0xd3], false),
+    (0xb23f, &[0x87, 0xd4], false),
+    (0xb240, &[0x87, 0xd5], false),
+    (0xb241, &[0x87, 0xd6], false),
+    (0xb242, &[0x87, 0xd7], false),
+    (0xb243, &[0x87, 0xd8], false),
+    (0xb244, &[0x87, 0xd9], false),
+    (0xb245, &[0x87, 0xda], false),
+    (0xb246, &[0x87, 0xdb], false),
+    (0xb247, &[0x87, 0xdc], false),
+    (0xb248, &[0x87, 0xdd], false),
+    (0xb249, &[0x87, 0xde], false),
+    (0xb24a, &[0x87, 0xdf], false),
+    (0xb24b, &[0x87, 0xe0], false),
+    (0xb24c, &[0x87, 0xe1], false),
+    (0xb24d, &[0x87, 0xe2], false),
+    (0xb24e, &[0x87, 0xe3], false),
+    (0xb24f, &[0x87, 0xe4], false),
+    (0xb250, &[0x87, 0xe5], false),
+    (0xb251, &[0x87, 0xe6], false),
+    (0xb252, &[0x87, 0xe7], false),
+    (0xb253, &[0x87, 0xe8], false),
+    (0xb254, &[0x87, 0xe9], false),
+    (0xb255, &[0x87, 0xea], false),
+    (0xb256, &[0x87, 0xeb], false),
+    (0xb257, &[0x87, 0xec], false),
+    (0xb259, &[0x87, 0xed], false),
+    (0xb25a, &[0x87, 0xee], false),
+    (0xb25b, &[0x87, 0xef], false),
+    (0xb25d, &[0x87, 0xf0], false),
+    (0xb25e, &[0x87, 0xf1], false),
+    (0xb25f, &[0x87, 0xf2], false),
+    (0xb261, &[0x87, 0xf3], false),
+    (0xb262, &[0x87, 0xf4], false),
+    (0xb263, &[0x87, 0xf5], false),
+    (0xb264, &[0x87, 0xf6], false),
+    (0xb265, &[0x87, 0xf7], false),
+    (0xb266, &[0x87, 0xf8], false),
+    (0xb267, &[0x87, 0xf9], false),
+    (0xb26a, &[0x87, 0xfa], false),
+    (0xb26b, &[0x87, 0xfb], false),
+    (0xb26c, &[0x87, 0xfc], false),
+    (0xb26d, &[0x87, 0xfd], false),
+    (0xb26e, &[0x87, 0xfe], false),
+    (0xb26f, &[0x88, 0x41], false),
+    (0xb270, &[0x88, 0x42], false),
+    (0xb271, &[0x88, 0x43], false),
+    (0xb272, &[0x88, 0x44], false),
+    (0xb273, &[0x88, 0x45], false),
+    (0xb276, &[0x88, 0x46], false),
+    (0xb277, &[0x88, 0x47], false),
+    (0xb278, &[0x88, 0x48], false),
+    (0xb279, &[0x88, 0x49], false),
+    (0xb27a, &[0x88, 0x4a], false),
+    (0xb27b, &[0x88, 0x4b], false),
+    (0xb27d, &[0x88, 0x4c], false),
+    (0xb27e, &[0x88, 0x4d], false),
+    (0xb27f, &[0x88, 0x4e], false),
+    (0xb280, &[0x88, 0x4f], false),
+    (0xb281, &[0x88, 0x50], false),
+    (0xb282, &[0x88, 0x51], false),
+    (0xb283, &[0x88, 0x52], false),
+    (0xb286, &[0x88, 0x53], false),
+    (0xb287, &[0x88, 0x54], false),
+    (0xb288, &[0x88, 0x55], false),
+    (0xb28a, &[0x88, 0x56], false),
+    (0xb28b, &[0x88, 0x57], false),
+    (0xb28c, &[0x88, 0x58], false),
+    (0xb28d, &[0x88, 0x59], false),
+    (0xb28e, &[0x88, 0x5a], false),
+    (0xb28f, &[0x88, 0x61], false),
+    (0xb292, &[0x88, 0x62], false),
+    (0xb293, &[0x88, 0x63], false),
+    (0xb295, &[0x88, 0x64], false),
+    (0xb296, &[0x88, 0x65], false),
+    (0xb297, &[0x88, 0x66], false),
+    (0xb29b, &[0x88, 0x67], false),
+    (0xb29c, &[0x88, 0x68], false),
+    (0xb29d, &[0x88, 0x69], false),
+    (0xb29e, &[0x88, 0x6a], false),
+    (0xb29f, &[0x88, 0x6b], false),
+    (0xb2a2, &[0x88, 0x6c], false),
+    (0xb2a4, &[0x88, 0x6d], false),
+    (0xb2a7, &[0x88, 0x6e], false),
+    (0xb2a8, &[0x88, 0x6f], false),
+    (0xb2a9, &[0x88, 0x70], false),
+    (0xb2ab, &[0x88, 0x71], false),
+    (0xb2ad, &[0x88, 0x72], false),
+    (0xb2ae, &[0x88, 0x73], false),
+    (0xb2af, &[0x88, 0x74], false),
+    (0xb2b1, &[0x88, 0x75], false),
+    (0xb2b2, &[0x88, 0x76], false),
+    (0xb2b3, &[0x88, 0x77], false),
+    (0xb2b5, &[0x88, 0x78], false),
+    (0xb2b6, &[0x88, 0x79], false),
+    (0xb2b7, &[0x88, 0x7a], false),
+    (0xb2b8, &[0x88, 0x81], false),
+    (0xb2b9, &[0x88, 0x82], false),
+    (0xb2ba, &[0x88, 0x83], false),
+    (0xb2bb, &[0x88, 0x84], false),
+    (0xb2bc, &[0x88, 0x85], false),
+    (0xb2bd, &[0x88, 0x86], false),
+    (0xb2be, &[0x88, 0x87], false),
+    (0xb2bf, &[0x88, 0x88], false),
+    (0xb2c0, &[0x88, 0x89], false),
+    (0xb2c1, &[0x88, 0x8a], false),
+    (0xb2c2, &[0x88, 0x8b], false),
+    (0xb2c3, &[0x88, 0x8c], false),
+    (0xb2c4, &[0x88, 0x8d], false),
+    (0xb2c5, &[0x88, 0x8e], false),
+    (0xb2c6, &[0x88, 0x8f], false),
+    (0xb2c7, &[0x88, 0x90], false),
+    (0xb2ca, &[0x88, 0x91], false),
+    (0xb2cb, &[0x88, 0x92], false),
+    (0xb2cd, &[0x88, 0x93], false),
+    (0xb2ce, &[0x88, 0x94], false),
+    (0xb2cf, &[0x88, 0x95], false),
+    (0xb2d1, &[0x88, 0x96], false),
+    (0xb2d3, &[0x88, 0x97], false),
+    (0xb2d4, &[0x88, 0x98], false),
+    (0xb2d5, &[0x88, 0x99], false),
+    (0xb2d6, &[0x88, 0x9a], false),
+    (0xb2d7, &[0x88, 0x9b], false),
+    (0xb2da, &[0x88, 0x9c], false),
+    (0xb2dc, &[0x88, 0x9d], false),
+    (0xb2de, &[0x88, 0x9e], false),
+    (0xb2df, &[0x88, 0x9f], false),
+    (0xb2e0, &[0x88, 0xa0], false),
+    (0xb2e1, &[0x88, 0xa1], false),
+    (0xb2e3, &[0x88, 0xa2], false),
+    (0xb2e7, &[0x88, 0xa3], false),
+    (0xb2e9, &[0x88, 0xa4], false),
+    (0xb2ea, &[0x88, 0xa5], false),
+    (0xb2f0, &[0x88, 0xa6], false),
+    (0xb2f1, &[0x88, 0xa7], false),
+    (0xb2f2, &[0x88, 0xa8], false),
+    (0xb2f6, &[0x88, 0xa9], false),
+    (0xb2fc, &[0x88, 0xaa], false),
+    (0xb2fd, &[0x88, 0xab], false),
+    (0xb2fe, &[0x88, 0xac], false),
+    (0xb302, &[0x88, 0xad], false),
+    (0xb303, &[0x88, 0xae], false),
+    (0xb305, &[0x88, 0xaf], false),
+    (0xb306, &[0x88, 0xb0], false),
+    (0xb307, &[0x88, 0xb1], false),
+    (0xb309, &[0x88, 0xb2], false),
+    (0xb30a, &[0x88, 0xb3], false),
+    (0xb30b, &[0x88, 0xb4], false),
+    (0xb30c, &[0x88, 0xb5], false),
+    (0xb30d, &[0x88, 0xb6], false),
+    (0xb30e, &[0x88, 0xb7], false),
+    (0xb30f, &[0x88, 0xb8], false),
+    (0xb312, &[0x88, 0xb9], false),
+    (0xb316, &[0x88, 0xba], false),
+    (0xb317, &[0x88, 0xbb], false),
+    (0xb318, &[0x88, 0xbc], false),
+    (0xb319, &[0x88, 0xbd], false),
+    (0xb31a, &[0x88, 0xbe], false),
+    (0xb31b, &[0x88, 0xbf], false),
+    (0xb31d, &[0x88, 0xc0], false),
+    (0xb31e, &[0x88, 0xc1], false),
+    (0xb31f, &[0x88, 0xc2], false),
+    (0xb320, &[0x88, 0xc3], false),
+    (0xb321, &[0x88, 0xc4], false),
+    (0xb322, &[0x88, 0xc5], false),
+    (0xb323, &[0x88, 0xc6], false),
+    (0xb324, &[0x88, 0xc7], false),
+    (0xb325, &[0x88, 0xc8], false),
+    (0xb326, &[0x88, 0xc9], false),
+    (0xb327, &[0x88, 0xca], false),
+    (0xb328, &[0x88, 0xcb], false),
+    (0xb329, &[0x88, 0xcc], false),
+    (0xb32a, &[0x88, 0xcd], false),
+    (0xb32b, &[0x88, 0xce], false),
+    (0xb32c, &[0x88, 0xcf], false),
+    (0xb32d, &[0x88, 0xd0], false),
+    (0xb32e, &[0x88, 0xd1], false),
+    (0xb32f, &[0x88, 0xd2], false),
+    (0xb330, &[0x88, 0xd3], false),
+    (0xb331, &[0x88, 0xd4], false),
+    (0xb332, &[0x88, 0xd5], false),
+    (0xb333, &[0x88, 0xd6], false),
+    (0xb334, &[0x88, 0xd7], false),
+    (0xb335, &[0x88, 0xd8], false),
+    (0xb336, &[0x88, 0xd9], false),
+    (0xb337, &[0x88, 0xda], false),
+    (0xb338, &[0x88, 0xdb], false),
+    (0xb339, &[0x88, 0xdc], false),
+    (0xb33a, &[0x88, 0xdd], false),
+    (0xb33b, &[0x88, 0xde], false),
+    (0xb33c, &[0x88, 0xdf], false),
+    (0xb33d, &[0x88, 0xe0], false),
+    (0xb33e, &[0x88, 0xe1], false),
+    (0xb33f, &[0x88, 0xe2], false),
+    (0xb340, &[0x88, 0xe3], false),
+    (0xb341, &[0x88, 0xe4], false),
+    (0xb342, &[0x88, 0xe5], false),
+    (0xb343, &[0x88, 0xe6], false),
+    (0xb344, &[0x88, 0xe7], false),
+    (0xb345, &[0x88, 0xe8], false),
+    (0xb346, &[0x88, 0xe9], false),
+    (0xb347, &[0x88, 0xea], false),
+    (0xb348, &[0x88, 0xeb], false),
+    (0xb349, &[0x88, 0xec], false),
+    (0xb34a, &[0x88, 0xed], false),
+    (0xb34b, &[0x88, 0xee], false),
+    (0xb34c, &[0x88, 0xef], false),
+    (0xb34d, &[0x88, 0xf0], false),
+    (0xb34e, &[0x88, 0xf1], false),
+    (0xb34f, &[0x88, 0xf2], false),
+    (0xb350, &[0x88, 0xf3], false),
+    (0xb351, &[0x88, 0xf4], false),
+    (0xb352, &[0x88, 0xf5], false),
+    (0xb353, &[0x88, 0xf6], false),
+    (0xb357, &[0x88, 0xf7], false),
+    (0xb359, &[0x88, 0xf8], false),
+    (0xb35a, &[0x88, 0xf9], false),
+    (0xb35d, &[0x88, 0xfa], false),
+    (0xb360, &[0x88, 0xfb], false),
+    (0xb361, &[0x88, 0xfc], false),
+    (0xb362, &[0x88, 0xfd], false),
+    (0xb363, &[0x88, 0xfe], false),
+    (0xb366, &[0x89, 0x41], false),
+    (0xb368, &[0x89, 0x42], false),
+    (0xb36a, &[0x89, 0x43], false),
+    (0xb36c, &[0x89, 0x44], false),
+    (0xb36d, &[0x89, 0x45], false),
+    (0xb36f, &[0x89, 0x46], false),
+    (0xb372, &[0x89, 0x47], false),
+    (0xb373, &[0x89, 0x48], false),
+    (0xb375, &[0x89, 0x49], false),
+    (0xb376, &[0x89, 0x4a], false),
+    (0xb377, &[0x89, 0x4b], false),
+    (0xb379, &[0x89, 0x4c], false),
+    (0xb37a, &[0x89, 0x4d], false),
+    (0xb37b, &[0x89, 0x4e], false),
+    (0xb37c, &[0x89, 0x4f], false),
+    (0xb37d, &[0x89, 0x50], false),
+    (0xb37e, &[0x89, 0x51], false),
+    (0xb37f, &[0x89, 0x52], false),
+    (0xb382, &[0x89, 0x53], false),
+    (0xb386, &[0x89, 0x54], false),
+    (0xb387, &[0x89, 0x55], false),
+    (0xb388, &[0x89, 0x56], false),
+    (0xb389, &[0x89, 0x57], false),
+    (0xb38a, &[0x89, 0x58], false),
+    (0xb38b, &[0x89, 0x59], false),
+    (0xb38d, &[0x89, 0x5a], false),
+    (0xb38e, &[0x89, 0x61], false),
+    (0xb38f, &[0x89, 0x62], false),
+    (0xb391, &[0x89, 0x63], false),
+    (0xb392, &[0x89, 0x64], false),
+    (0xb393, &[0x89, 0x65], false),
+    (0xb395, &[0x89, 0x66], false),
+    (0xb396, &[0x89, 0x67], false),
+    (0xb397, &[0x89, 0x68], false),
+    (0xb398, &[0x89, 0x69], false),
+    (0xb399, &[0x89, 0x6a], false),
+    (0xb39a, &[0x89, 0x6b], false),
+    (0xb39b, &[0x89, 0x6c], false),
+    (0xb39c, &[0x89, 0x6d], false),
+    (0xb39d, &[0x89, 0x6e], false),
+    (0xb39e, &[0x89, 0x6f], false),
+    (0xb39f, &[0x89, 0x70], false),
+    (0xb3a2, &[0x89, 0x71], false),
+    (0xb3a3, &[0x89, 0x72], false),
+    (0xb3a4, &[0x89, 0x73], false),
+    (0xb3a5, &[0x89, 0x74], false),
+    (0xb3a6, &[0x89, 0x75], false),
+    (0xb3a7, &[0x89, 0x76], false),
+    (0xb3a9, &[0x89, 0x77], false),
+    (0xb3aa, &[0x89, 0x78], false),
+    (0xb3ab, &[0x89, 0x79], false),
+    (0xb3ad, &[0x89, 0x7a], false),
+    (0xb3ae, &[0x89, 0x81], false),
+    (0xb3af, &[0x89, 0x82], false),
+    (0xb3b0, &[0x89, 0x83], false),
+    (0xb3b1, &[0x89, 0x84], false),
+    (0xb3b2, &[0x89, 0x85], false),
+    (0xb3b3, &[0x89, 0x86], false),
+    (0xb3b4, &[0x89, 0x87], false),
+    (0xb3b5, &[0x89, 0x88], false),
+    (0xb3b6, &[0x89, 0x89], false),
+    (0xb3b7, &[0x89, 0x8a], false),
+    (0xb3b8, &[0x89, 0x8b], false),
+    (0xb3b9, &[0x89, 0x8c], false),
+    (0xb3ba, &[0x89, 0x8d], false),
+    (0xb3bb, &[0x89, 0x8e], false),
+    (0xb3bc, &[0x89, 0x8f], false),
+    (0xb3bd, &[0x89, 0x90], false),
+    (0xb3be, &[0x89, 0x91], false),
+    (0xb3bf, &[0x89, 0x92], false),
+    (0xb3c0, &[0x89, 0x93], false),
+    (0xb3c1, &[0x89, 0x94], false),
+    (0xb3c2, &[0x89, 0x95], false),
+    (0xb3c3, &[0x89, 0x96], false),
+    (0xb3c6, &[0x89, 0x97], false),
+    (0xb3c7, &[0x89, 0x98], false),
+    (0xb3c9, &[0x89, 0x99], false),
+    (0xb3ca, &[0x89, 0x9a], false),
+    (0xb3cd, &[0x89, 0x9b], false),
+    (0xb3cf, &[0x89, 0x9c], false),
+    (0xb3d1, &[0x89, 0x9d], false),
+    (0xb3d2, &[0x89, 0x9e], false),
+    (0xb3d3, &[0x89, 0x9f], false),
+    (0xb3d6, &[0x89, 0xa0], false),
+    (0xb3d8, &[0x89, 0xa1], false),
+    (0xb3da, &[0x89, 0xa2], false),
+    (0xb3dc, &[0x89, 0xa3], false),
+    (0xb3de, &[0x89, 0xa4], false),
+    (0xb3df, &[0x89, 0xa5], false),
+    (0xb3e1, &[0x89, 0xa6], false),
+    (0xb3e2, &[0x89, 0xa7], false),
+    (0xb3e3, &[0x89, 0xa8], false),
+    (0xb3e5, &[0x89, 0xa9], false),
+    (0xb3e6, &[0x89, 0xaa], false),
+    (0xb3e7, &[0x89, 0xab], false),
+    (0xb3e9, &[0x89, 0xac], false),
+    (0xb3ea, &[0x89, 0xad], false),
+    (0xb3eb, &[0x89, 0xae], false),
+    (0xb3ec, &[0x89, 0xaf], false),
+    (0xb3ed, &[0x89, 0xb0], false),
+    (0xb3ee, &[0x89, 0xb1], false),
+    (0xb3ef, &[0x89, 0xb2], false),
+    (0xb3f0, &[0x89, 0xb3], false),
+    (0xb3f1, &[0x89, 0xb4], false),
+    (0xb3f2, &[0x89, 0xb5], false),
+    (0xb3f3, &[0x89, 0xb6], false),
+    (0xb3f4, &[0x89, 0xb7], false),
+    (0xb3f5, &[0x89, 0xb8], false),
+    (0xb3f6, &[0x89, 0xb9], false),
+    (0xb3f7, &[0x89, 0xba], false),
+    (0xb3f8, &[0x89, 0xbb], false),
+    (0xb3f9, &[0x89, 0xbc], false),
+    (0xb3fa, &[0x89, 0xbd], false),
+    (0xb3fb, &[0x89, 0xbe], false),
+    (0xb3fd, &[0x89, 0xbf], false),
+    (0xb3fe, &[0x89, 0xc0], false),
+    (0xb3ff, &[0x89, 0xc1], false),
+    (0xb400, &[0x89, 0xc2], false),
+    (0xb401, &[0x89, 0xc3], false),
+    (0xb402, &[0x89, 0xc4], false),
+    (0xb403, &[0x89, 0xc5], false),
+    (0xb404, &[0x89, 0xc6], false),
+    (0xb405, &[0x89, 0xc7], false),
+    (0xb406, &[0x89, 0xc8], false),
+    (0xb407, &[0x89, 0xc9], false),
+    (0xb408, &[0x89, 0xca], false),
+    (0xb409, &[0x89, 0xcb], false),
+    (0xb40a, &[0x89, 0xcc], false),
+    (0xb40b, &[0x89, 0xcd], false),
+    (0xb40c, &[0x89, 0xce], false),
+    (0xb40d, &[0x89, 0xcf], false),
+    (0xb40e, &[0x89, 0xd0], false),
+    (0xb40f, &[0x89, 0xd1], false),
+    (0xb411, &[0x89, 0xd2], false),
+    (0xb412, &[0x89, 0xd3], false),
+    (0xb413, &[0x89, 0xd4], false),
+    (0xb414, &[0x89, 0xd5], false),
+    (0xb415, &[0x89, 0xd6], false),
+    (0xb416, &[0x89, 0xd7], false),
+    (0xb417, &[0x89, 0xd8], false),
+    (0xb419, &[0x89, 0xd9], false),
+    (0xb41a, &[0x89, 0xda], false),
+    (0xb41b, &[0x89, 0xdb], false),
+    (0xb41d, &[0x89, 0xdc], false),
+    (0xb41e, &[0x89, 0xdd], false),
+    (0xb41f, &[0x89, 0xde], false),
+    (0xb421, &[0x89, 0xdf], false),
+    (0xb422, &[0x89, 0xe0], false),
+    (0xb423, &[0x89, 0xe1], false),
+    (0xb424, &[0x89, 0xe2], false),
+    (0xb425, &[0x89, 0xe3], false),
+    (0xb426, &[0x89, 0xe4], false),
+    (0xb427, &[0x89, 0xe5], false),
+    (0xb42a, &[0x89, 0xe6], false),
+    (0xb42c, &[0x89, 0xe7], false),
+    (0xb42d, &[0x89, 0xe8], false),
+    (0xb42e, &[0x89, 0xe9], false),
+    (0xb42f, &[0x89, 0xea], false),
+    (0xb430, &[0x89, 0xeb], false),
+    (0xb431, &[0x89, 0xec], false),
+    (0xb432, &[0x89, 0xed], false),
+    (0xb433, &[0x89, 0xee], false),
+    (0xb435, &[0x89, 0xef], false),
+    (0xb436, &[0x89, 0xf0], false),
+    (0xb437, &[0x89, 0xf1], false),
+    (0xb438, &[0x89, 0xf2], false),
+    (0xb439, &[0x89, 0xf3], false),
+    (0xb43a, &[0x89, 0xf4], false),
+    (0xb43b, &[0x89, 0xf5], false),
+    (0xb43c, &[0x89, 0xf6], false),
+    (0xb43d, &[0x89, 0xf7], false),
+    (0xb43e, &[0x89, 0xf8], false),
+    (0xb43f, &[0x89, 0xf9], false),
+    (0xb440, &[0x89, 0xfa], false),
+    (0xb441, &[0x89, 0xfb], false),
+    (0xb442, &[0x89, 0xfc], false),
+    (0xb443, &[0x89, 0xfd], false),
+    (0xb444, &[0x89, 0xfe], false),
+    (0xb445, &[0x8a, 0x41], false),
+    (0xb446, &[0x8a, 0x42], false),
+    (0xb447, &[0x8a, 0x43], false),
+    (0xb448, &[0x8a, 0x44], false),
+    (0xb449, &[0x8a, 0x45], false),
+    (0xb44a, &[0x8a, 0x46], false),
+    (0xb44b, &[0x8a, 0x47], false),
+    (0xb44c, &[0x8a, 0x48], false),
+    (0xb44d, &[0x8a, 0x49], false),
+    (0xb44e, &[0x8a, 0x4a], false),
+    (0xb44f, &[0x8a, 0x4b], false),
+    (0xb452, &[0x8a, 0x4c], false),
+    (0xb453, &[0x8a, 0x4d], false),
+    (0xb455, &[0x8a, 0x4e], false),
+    (0xb456, &[0x8a, 0x4f], false),
+    (0xb457, &[0x8a, 0x50], false),
+    (0xb459, &[0x8a, 0x51], false),
+    (0xb45a, &[0x8a, 0x52], false),
+    (0xb45b, &[0x8a, 0x53], false),
+    (0xb45c, &[0x8a, 0x54], false),
+    (0xb45d, &[0x8a, 0x55], false),
+    (0xb45e, &[0x8a, 0x56], false),
+    (0xb45f, &[0x8a, 0x57], false),
+    (0xb462, &[0x8a, 0x58], false),
+    (0xb464, &[0x8a, 0x59], false),
+    (0xb466, &[0x8a, 0x5a], false),
+    (0xb467, &[0x8a, 0x61], false),
+    (0xb468, &[0x8a, 0x62], false),
+    (0xb469, &[0x8a, 0x63], false),
+    (0xb46a, &[0x8a, 0x64], false),
+    (0xb46b, &[0x8a, 0x65], false),
+    (0xb46d, &[0x8a, 0x66], false),
+    (0xb46e, &[0x8a, 0x67], false),
+    (0xb46f, &[0x8a, 0x68], false),
+    (0xb470, &[0x8a, 0x69], false),
+    (0xb471, &[0x8a, 0x6a], false),
+    (0xb472, &[0x8a, 0x6b], false),
+    (0xb473, &[0x8a, 0x6c], false),
+    (0xb474, &[0x8a, 0x6d], false),
+    (0xb475, &[0x8a, 0x6e], false),
+    (0xb476, &[0x8a, 0x6f], false),
+    (0xb477, &[0x8a, 0x70], false),
+    (0xb478, &[0x8a, 0x71], false),
+    (0xb479, &[0x8a, 0x72], false),
+    (0xb47a, &[0x8a, 0x73], false),
+    (0xb47b, &[0x8a, 0x74], false),
+    (0xb47c, &[0x8a, 0x75], false),
+    (0xb47d, &[0x8a, 0x76], false),
+    (0xb47e, &[0x8a, 0x77], false),
+    (0xb47f, &[0x8a, 0x78], false),
+    (0xb481, &[0x8a, 0x79], false),
+    (0xb482, &[0x8a, 0x7a], false),
+    (0xb483, &[0x8a, 0x81], false),
+    (0xb484, &[0x8a, 0x82], false),
+    (0xb485, &[0x8a, 0x83], false),
+    (0xb486, &[0x8a, 0x84], false),
+    (0xb487, &[0x8a, 0x85], false),
+    (0xb489, &[0x8a, 0x86], false),
+    (0xb48a, &[0x8a, 0x87], false),
+    (0xb48b, &[0x8a, 0x88], false),
+    (0xb48c, &[0x8a, 0x89], false),
+    (0xb48d, &[0x8a, 0x8a], false),
+    (0xb48e, &[0x8a, 0x8b], false),
+    (0xb48f, &[0x8a, 0x8c], false),
+    (0xb490, &[0x8a, 0x8d], false),
+    (0xb491, &[0x8a, 0x8e], false),
+    (0xb492, &[0x8a, 0x8f], false),
+    (0xb493, &[0x8a, 0x90], false),
+    (0xb494, &[0x8a, 0x91], false),
+    (0xb495, &[0x8a, 0x92], false),
+    (0xb496, &[0x8a, 0x93], false),
+    (0xb497, &[0x8a, 0x94], false),
+    (0xb498, &[0x8a, 0x95], false),
+    (0xb499, &[0x8a, 0x96], false),
+    (0xb49a, &[0x8a, 0x97], false),
+    (0xb49b, &[0x8a, 0x98], false),
+    (0xb49c, &[0x8a, 0x99], false),
+    (0xb49e, &[0x8a, 0x9a], false),
+    (0xb49f, &[0x8a, 0x9b], false),
+    (0xb4a0, &[0x8a, 0x9c], false),
+    (0xb4a1, &[0x8a, 0x9d], false),
+    (0xb4a2, &[0x8a, 0x9e], false),
+    (0xb4a3, &[0x8a, 0x9f], false),
+    (0xb4a5, &[0x8a, 0xa0], false),
+    (0xb4a6, &[0x8a, 0xa1], false),
+    (0xb4a7, &[0x8a, 0xa2], false),
+    (0xb4a9, &[0x8a, 0xa3], false),
+    (0xb4aa, &[0x8a, 0xa4], false),
+    (0xb4ab, &[0x8a, 0xa5], false),
+    (0xb4ad, &[0x8a, 0xa6], false),
+    (0xb4ae, &[0x8a, 0xa7], false),
+    (0xb4af, &[0x8a, 0xa8], false),
+    (0xb4b0, &[0x8a, 0xa9], false),
+    (0xb4b1, &[0x8a, 0xaa], false),
+    (0xb4b2, &[0x8a, 0xab], false),
+    (0xb4b3, &[0x8a, 0xac], false),
+    (0xb4b4, &[0x8a, 0xad], false),
+    (0xb4b6, &[0x8a, 0xae], false),
+    (0xb4b8, &[0x8a, 0xaf], false),
+    (0xb4ba, &[0x8a, 0xb0], false),
+    (0xb4bb, &[0x8a, 0xb1], false),
+    (0xb4bc, &[0x8a, 0xb2], false),
+    (0xb4bd, &[0x8a, 0xb3], false),
+    (0xb4be, &[0x8a, 0xb4], false),
+    (0xb4bf, &[0x8a, 0xb5], false),
+    (0xb4c1, &[0x8a, 0xb6], false),
+    (0xb4c2, &[0x8a, 0xb7], false),
+    (0xb4c3, &[0x8a, 0xb8], false),
+    (0xb4c5, &[0x8a, 0xb9], false),
+    (0xb4c6, &[0x8a, 0xba], false),
+    (0xb4c7, &[0x8a, 0xbb], false),
+    (0xb4c9, &[0x8a, 0xbc], false),
+    (0xb4ca, &[0x8a, 0xbd], false),
+    (0xb4cb, &[0x8a, 0xbe], false),
+    (0xb4cc, &[0x8a, 0xbf], false),
+    (0xb4cd, &[0x8a, 0xc0], false),
+    (0xb4ce, &[0x8a, 0xc1], false),
+    (0xb4cf, &[0x8a, 0xc2], false),
+    (0xb4d1, &[0x8a, 0xc3], false),
+    (0xb4d2, &[0x8a, 0xc4], false),
+    (0xb4d3, &[0x8a, 0xc5], false),
+    (0xb4d4, &[0x8a, 0xc6], false),
+    (0xb4d6, &[0x8a, 0xc7], false),
+    (0xb4d7, &[0x8a, 0xc8], false),
+    (0xb4d8, &[0x8a, 0xc9], false),
+    (0xb4d9, &[0x8a, 0xca], false),
+    (0xb4da, &[0x8a, 0xcb], false),
+    (0xb4db, &[0x8a, 0xcc], false),
+    (0xb4de, &[0x8a, 0xcd], false),
+    (0xb4df, &[0x8a, 0xce], false),
+    (0xb4e1, &[0x8a, 0xcf], false),
+    (0xb4e2, &[0x8a, 0xd0], false),
+    (0xb4e5, &[0x8a, 0xd1], false),
+    (0xb4e7, &[0x8a, 0xd2], false),
+    (0xb4e8, &[0x8a, 0xd3], false),
+    (0xb4e9, &[0x8a, 0xd4], false),
+    (0xb4ea, &[0x8a, 0xd5], false),
+    (0xb4eb, &[0x8a, 0xd6], false),
+    (0xb4ee, &[0x8a, 0xd7], false),
+    (0xb4f0, &[0x8a, 0xd8], false),
+    (0xb4f2, &[0x8a, 0xd9], false),
+    (0xb4f3, &[0x8a, 0xda], false),
+    (0xb4f4, &[0x8a, 0xdb], false),
+    (0xb4f5, &[0x8a, 0xdc], false),
+    (0xb4f6, &[0x8a, 0xdd], false),
+    (0xb4f7, &[0x8a, 0xde], false),
+    (0xb4f9, &[0x8a, 0xdf], false),
+    (0xb4fa, &[0x8a, 0xe0], false),
+    (0xb4fb, &[0x8a, 0xe1], false),
+    (0xb4fc, &[0x8a, 0xe2], false),
+    (0xb4fd, &[0x8a, 0xe3], false),
+    (0xb4fe, &[0x8a, 0xe4], false),
+    (0xb4ff, &[0x8a, 0xe5], false),
+    (0xb500, &[0x8a, 0xe6], false),
+    (0xb501, &[0x8a, 0xe7], false),
+    (0xb502, &[0x8a, 0xe8], false),
+    (0xb503, &[0x8a, 0xe9], false),
+    (0xb504, &[0x8a, 0xea], false),
+    (0xb505, &[0x8a, 0xeb], false),
+    (0xb506, &[0x8a, 0xec], false),
+    (0xb507, &[0x8a, 0xed], false),
+    (0xb508, &[0x8a, 0xee], false),
+    (0xb509, &[0x8a, 0xef], false),
+    (0xb50a, &[0x8a, 0xf0], false),
+    (0xb50b, &[0x8a, 0xf1], false),
+    (0xb50c, &[0x8a, 0xf2], false),
+    (0xb50d, &[0x8a, 0xf3], false),
+    (0xb50e, &[0x8a, 0xf4], false),
+    (0xb50f, &[0x8a, 0xf5], false),
+    (0xb510, &[0x8a, 0xf6], false),
+    (0xb511, &[0x8a, 0xf7], false),
+    (0xb512, &[0x8a, 0xf8], false),
+    (0xb513, &[0x8a, 0xf9], false),
+    (0xb516, &[0x8a, 0xfa], false),
+    (0xb517, &[0x8a, 0xfb], false),
+    (0xb519, &[0x8a, 0xfc], false),
+    (0xb51a, &[0x8a, 0xfd], false),
+    (0xb51d, &[0x8a, 0xfe], false),
+    (0xb51e, &[0x8b, 0x41], false),
+    (0xb51f, &[0x8b, 0x42], false),
+    (0xb520, &[0x8b, 0x43], false),
+    (0xb521, &[0x8b, 0x44], false),
+    (0xb522, &[0x8b, 0x45], false),
+    (0xb523, &[0x8b, 0x46], false),
+    (0xb526, &[0x8b, 0x47], false),
+    (0xb52b, &[0x8b, 0x48], false),
+    (0xb52c, &[0x8b, 0x49], false),
+    (0xb52d, &[0x8b, 0x4a], false),
+    (0xb52e, &[0x8b, 0x4b], false),
+    (0xb52f, &[0x8b, 0x4c], false),
+    (0xb532, &[0x8b, 0x4d], false),
+    (0xb533, &[0x8b, 0x4e], false),
+    (0xb535, &[0x8b, 0x4f], false),
+    (0xb536, &[0x8b, 0x50], false),
+    (0xb537, &[0x8b, 0x51], false),
+    (0xb539, &[0x8b, 0x52], false),
+    (0xb53a, &[0x8b, 0x53], false),
+    (0xb53b, &[0x8b, 0x54], false),
+    (0xb53c, &[0x8b, 0x55], false),
+    (0xb53d, &[0x8b, 0x56], false),
+    (0xb53e, &[0x8b, 0x57], false),
+    (0xb53f, &[0x8b, 0x58], false),
+    (0xb542, &[0x8b, 0x59], false),
+    (0xb546, &[0x8b, 0x5a], false),
+    (0xb547, &[0x8b, 0x61], false),
+    (0xb548, &[0x8b, 0x62], false),
+    (0xb549, &[0x8b, 0x63], false),
+    (0xb54a, &[0x8b, 0x64], false),
+    (0xb54e, &[0x8b, 0x65], false),
+    (0xb54f, &[0x8b, 0x66], false),
+    (0xb551, &[0x8b, 0x67], false),
+    (0xb552, &[0x8b, 0x68], false),
+    (0xb553, &[0x8b, 0x69], false),
+    (0xb555, &[0x8b, 0x6a], false),
+    (0xb556, &[0x8b, 0x6b], false),
+    (0xb557, &[0x8b, 0x6c], false),
+    (0xb558, &[0x8b, 0x6d], false),
+    (0xb559, &[0x8b, 0x6e], false),
+    (0xb55a, &[0x8b, 0x6f], false),
+    (0xb55b, &[0x8b, 0x70], false),
+    (0xb55e, &[0x8b, 0x71], false),
+    (0xb562, &[0x8b, 0x72], false),
+    (0xb563, &[0x8b, 0x73], false),
+    (0xb564, &[0x8b, 0x74], false),
+    (0xb565, &[0x8b, 0x75], false),
+    (0xb566, &[0x8b, 0x76], false),
+    (0xb567, &[0x8b, 0x77], false),
+    (0xb568, &[0x8b, 0x78], false),
+    (0xb569, &[0x8b, 0x79], false),
+    (0xb56a, &[0x8b, 0x7a], false),
+    (0xb56b, &[0x8b, 0x81], false),
+    (0xb56c, &[0x8b, 0x82], false),
+    (0xb56d, &[0x8b, 0x83], false),
+    (0xb56e, &[0x8b, 0x84], false),
+    (0xb56f, &[0x8b, 0x85], false),
+    (0xb570, &[0x8b, 0x86], false),
+    (0xb571, &[0x8b, 0x87], false),
+    (0xb572, &[0x8b, 0x88], false),
+    (0xb573, &[0x8b, 0x89], false),
+    (0xb574, &[0x8b, 0x8a], false),
+    (0xb575, &[0x8b, 0x8b], false),
+    (0xb576, &[0x8b, 0x8c], false),
+    (0xb577, &[0x8b, 0x8d], false),
+    (0xb578, &[0x8b, 0x8e], false),
+    (0xb579, &[0x8b, 0x8f], false),
+    (0xb57a, &[0x8b, 0x90], false),
+    (0xb57b, &[0x8b, 0x91], false),
+    (0xb57c, &[0x8b, 0x92], false),
+    (0xb57d, &[0x8b, 0x93], false),
+    (0xb57e, &[0x8b, 0x94], false),
+    (0xb57f, &[0x8b, 0x95], false),
+    (0xb580, &[0x8b, 0x96], false),
+    (0xb581, &[0x8b, 0x97], false),
+    (0xb582, &[0x8b, 0x98], false),
+    (0xb583, &[0x8b, 0x99], false),
+    (0xb584, &[0x8b, 0x9a], false),
+    (0xb585, &[0x8b, 0x9b], false),
+    (0xb586, &[0x8b, 0x9c], false),
+    (0xb587, &[0x8b, 0x9d], false),
+    (0xb588, &[0x8b, 0x9e], false),
+    (0xb589, &[0x8b, 0x9f], false),
+    (0xb58a, &[0x8b, 0xa0], false),
+    (0xb58b, &[0x8b, 0xa1], false),
+    (0xb58c, &[0x8b, 0xa2], false),
+    (0xb58d, &[0x8b, 0xa3], false),
+    (0xb58e, &[0x8b, 0xa4], false),
+    (0xb58f, &[0x8b, 0xa5], false),
+    (0xb590, &[0x8b, 0xa6], false),
+    (0xb591, &[0x8b, 0xa7], false),
+    (0xb592, &[0x8b, 0xa8], false),
+    (0xb593, &[0x8b, 0xa9], false),
+    (0xb594, &[0x8b, 0xaa], false),
+    (0xb595, &[0x8b, 0xab], false),
+    (0xb596, &[0x8b, 0xac], false),
+    (0xb597, &[0x8b, 0xad], false),
+    (0xb598, &[0x8b, 0xae], false),
+    (0xb599, &[0x8b, 0xaf], false),
+    (0xb59a, &[0x8b, 0xb0], false),
+    (0xb59b, &[0x8b, 0xb1], false),
+    (0xb59c, &[0x8b, 0xb2], false),
+    (0xb59d, &[0x8b, 0xb3], false),
+    (0xb59e, &[0x8b, 0xb4], false),
+    (0xb59f, &[0x8b, 0xb5], false),
+    (0xb5a2, &[0x8b, 0xb6], false),
+    (0xb5a3, &[0x8b, 0xb7], false),
+    (0xb5a5, &[0x8b, 0xb8], false),
+    (0xb5a6, &[0x8b, 0xb9], false),
+    (0xb5a7, &[0x8b, 0xba], false),
+    (0xb5a9, &[0x8b, 0xbb], false),
+    (0xb5ac, &[0x8b, 0xbc], false),
+    (0xb5ad, &[0x8b, 0xbd], false),
+    (0xb5ae, &[0x8b, 0xbe], false),
+    (0xb5af, &[0x8b, 0xbf], false),
+    (0xb5b2, &[0x8b, 0xc0], false),
+    (0xb5b6, &[0x8b, 0xc1], false),
+    (0xb5b7, &[0x8b, 0xc2], false),
+    (0xb5b8, &[0x8b, 0xc3], false),
+    (0xb5b9, &[0x8b, 0xc4], false),
+    (0xb5ba, &[0x8b, 0xc5], false),
+    (0xb5be, &[0x8b, 0xc6], false),
+    (0xb5bf, &[0x8b, 0xc7], false),
+    (0xb5c1, &[0x8b, 0xc8], false),
+    (0xb5c2, &[0x8b, 0xc9], false),
+    (0xb5c3, &[0x8b, 0xca], false),
+    (0xb5c5, &[0x8b, 0xcb], false),
+    (0xb5c6, &[0x8b, 0xcc], false),
+    (0xb5c7, &[0x8b, 0xcd], false),
+    (0xb5c8, &[0x8b, 0xce], false),
+    (0xb5c9, &[0x8b, 0xcf], false),
+    (0xb5ca, &[0x8b, 0xd0], false),
+    (0xb5cb, &[0x8b, 0xd1], false),
+    (0xb5ce, &[0x8b, 0xd2], false),
+    (0xb5d2, &[0x8b, 0xd3], false),
+    (0xb5d3, &[0x8b, 0xd4], false),
+    (0xb5d4, &[0x8b, 0xd5], false),
+    (0xb5d5, &[0x8b, 0xd6], false),
+    (0xb5d6, &[0x8b, 0xd7], false),
+    (0xb5d7, &[0x8b, 0xd8], false),
+    (0xb5d9, &[0x8b, 0xd9], false),
+    (0xb5da, &[0x8b, 0xda], false),
+    (0xb5db, &[0x8b, 0xdb], false),
+    (0xb5dc, &[0x8b, 0xdc], false),
+    (0xb5dd, &[0x8b, 0xdd], false),
+    (0xb5de, &[0x8b, 0xde], false),
+    (0xb5df, &[0x8b, 0xdf], false),
+    (0xb5e0, &[0x8b, 0xe0], false),
+    (0xb5e1, &[0x8b, 0xe1], false),
+    (0xb5e2, &[0x8b, 0xe2], false),
+    (0xb5e3, &[0x8b, 0xe3], false),
+    (0xb5e4, &[0x8b, 0xe4], false),
+    (0xb5e5, &[0x8b, 0xe5], false),
+    (0xb5e6, &[0x8b, 0xe6], false),
+    (0xb5e7, &[0x8b, 0xe7], false),
+    (0xb5e8, &[0x8b, 0xe8], false),
+    (0xb5e9, &[0x8b, 0xe9], false),
+    (0xb5ea, &[0x8b, 0xea], false),
+    (0xb5eb, &[0x8b, 0xeb], false),
+    (0xb5ed, &[0x8b, 0xec], false),
+    (0xb5ee, &[0x8b, 0xed], false),
+    (0xb5ef, &[0x8b, 0xee], false),
+    (0xb5f0, &[0x8b, 0xef], false),
+    (0xb5f1, &[0x8b, 0xf0], false),
+    (0xb5f2, &[0x8b, 0xf1], false),
+    (0xb5f3, &[0x8b, 0xf2], false),
+    (0xb5f4, &[0x8b, 0xf3], false),
+    (0xb5f5, &[0x8b, 0xf4], false),
+    (0xb5f6, &[0x8b, 0xf5], false),
+    (0xb5f7, &[0x8b, 0xf6], false),
+    (0xb5f8, &[0x8b, 0xf7], false),
+    (0xb5f9, &[0x8b, 0xf8], false),
+    (0xb5fa, &[0x8b, 0xf9], false),
+    (0xb5fb, &[0x8b, 0xfa], false),
+    (0xb5fc, &[0x8b, 0xfb], false),
+    (0xb5fd, &[0x8b, 0xfc], false),
+    (0xb5fe, &[0x8b, 0xfd], false),
+    (0xb5ff, &[0x8b, 0xfe], false),
+    (0xb600, &[0x8c, 0x41], false),
+    (0xb601, &[0x8c, 0x42], false),
+    (0xb602, &[0x8c, 0x43], false),
+    (0xb603, &[0x8c, 0x44], false),
+    (0xb604, &[0x8c, 0x45], false),
+    (0xb605, &[0x8c, 0x46], false),
+    (0xb606, &[0x8c, 0x47], false),
+    (0xb607, &[0x8c, 0x48], false),
+    (0xb608, &[0x8c, 0x49], false),
+    (0xb609, &[0x8c, 0x4a], false),
+    (0xb60a, &[0x8c, 0x4b], false),
+    (0xb60b, &[0x8c, 0x4c], false),
+    (0xb60c, &[0x8c, 0x4d], false),
+    (0xb60d, &[0x8c, 0x4e], false),
+    (0xb60e, &[0x8c, 0x4f], false),
+    (0xb60f, &[0x8c, 0x50], false),
+    (0xb612, &[0x8c, 0x51], false),
+    (0xb613, &[0x8c, 0x52], false),
+    (0xb615, &[0x8c, 0x53], false),
+    (0xb616, &[0x8c, 0x54], false),
+    (0xb617, &[0x8c, 0x55], false),
+    (0xb619, &[0x8c, 0x56], false),
+    (0xb61a, &[0x8c, 0x57], false),
+    (0xb61b, &[0x8c, 0x58], false),
+    (0xb61c, &[0x8c, 0x59], false),
+    (0xb61d, &[0x8c, 0x5a], false),
+    (0xb61e, &[0x8c, 0x61], false),
+    (0xb61f, &[0x8c, 0x62], false),
+    (0xb620, &[0x8c, 0x63], false),
+    (0xb621, &[0x8c, 0x64], false),
+    (0xb622, &[0x8c, 0x65], false),
+    (0xb623, &[0x8c, 0x66], false),
+    (0xb624, &[0x8c, 0x67], false),
+    (0xb626, &[0x8c, 0x68], false),
+    (0xb627, &[0x8c, 0x69], false),
+    (0xb628, &[0x8c, 0x6a], false),
+    (0xb629, &[0x8c, 0x6b], false),
+    (0xb62a, &[0x8c, 0x6c], false),
+    (0xb62b, &[0x8c, 0x6d], false),
+    (0xb62d, &[0x8c, 0x6e], false),
+    (0xb62e, &[0x8c, 0x6f], false),
+    (0xb62f, &[0x8c, 0x70], false),
+    (0xb630, &[0x8c, 0x71], false),
+    (0xb631, &[0x8c, 0x72], false),
+    (0xb632, &[0x8c, 0x73], false),
+    (0xb633, &[0x8c, 0x74], false),
+    (0xb635, &[0x8c, 0x75], false),
+    (0xb636, &[0x8c, 0x76], false),
+    (0xb637, &[0x8c, 0x77], false),
+    (0xb638, &[0x8c, 0x78], false),
+    (0xb639, &[0x8c, 0x79], false),
+    (0xb63a, &[0x8c, 0x7a], false),
+    (0xb63b, &[0x8c, 0x81], false),
+    (0xb63c, &[0x8c, 0x82], false),
+    (0xb63d, &[0x8c, 0x83], false),
+    (0xb63e, &[0x8c, 0x84], false),
+    (0xb63f, &[0x8c, 0x85], false),
+    (0xb640, &[0x8c, 0x86], false),
+    (0xb641, &[0x8c, 0x87], false),
+    (0xb642, &[0x8c, 0x88], false),
+    (0xb643, &[0x8c, 0x89], false),
+    (0xb644, &[0x8c, 0x8a], false),
+    (0xb645, &[0x8c, 0x8b], false),
+    (0xb646, &[0x8c, 0x8c], false),
+    (0xb647, &[0x8c, 0x8d], false),
+    (0xb649, &[0x8c, 0x8e], false),
+    (0xb64a, &[0x8c, 0x8f], false),
+    (0xb64b, &[0x8c, 0x90], false),
+    (0xb64c, &[0x8c, 0x91], false),
+    (0xb64d, &[0x8c, 0x92], false),
+    (0xb64e, &[0x8c, 0x93], false),
+    (0xb64f, &[0x8c, 0x94], false),
+    (0xb650, &[0x8c, 0x95], false),
+    (0xb651, &[0x8c, 0x96], false),
+    (0xb652, &[0x8c, 0x97], false),
+    (0xb653, &[0x8c, 0x98], false),
+    (0xb654, &[0x8c, 0x99], false),
+    (0xb655, &[0x8c, 0x9a], false),
+    (0xb656, &[0x8c, 0x9b], false),
+    (0xb657, &[0x8c, 0x9c], false),
+    (0xb658, &[0x8c, 0x9d], false),
+    (0xb659, &[0x8c, 0x9e], false),
+    (0xb65a, &[0x8c, 0x9f], false),
+    (0xb65b, &[0x8c, 0xa0], false),
+    (0xb65c, &[0x8c, 0xa1], false),
+    (0xb65d, &[0x8c, 0xa2], false),
+    (0xb65e, &[0x8c, 0xa3], false),
+    (0xb65f, &[0x8c, 0xa4], false),
+    (0xb660, &[0x8c, 0xa5], false),
+    (0xb661, &[0x8c, 0xa6], false),
+    (0xb662, &[0x8c, 0xa7], false),
+    (0xb663, &[0x8c, 0xa8], false),
+    (0xb665, &[0x8c, 0xa9], false),
+    (0xb666, &[0x8c, 0xaa], false),
+    (0xb667, &[0x8c, 0xab], false),
+    (0xb669, &[0x8c, 0xac], false),
+    (0xb66a, &[0x8c, 0xad], false),
+    (0xb66b, &[0x8c, 0xae], false),
+    (0xb66c, &[0x8c, 0xaf], false),
+    (0xb66d, &[0x8c, 0xb0], false),
+    (0xb66e, &[0x8c, 0xb1], false),
+    (0xb66f, &[0x8c, 0xb2], false),
+    (0xb670, &[0x8c, 0xb3], false),
+    (0xb671, &[0x8c, 0xb4], false),
+    (0xb672, &[0x8c, 0xb5], false),
+    (0xb673, &[0x8c, 0xb6], false),
+    (0xb674, &[0x8c, 0xb7], false),
+    (0xb675, &[0x8c, 0xb8], false),
+    (0xb676, &[0x8c, 0xb9], false),
+    (0xb677, &[0x8c, 0xba], false),
+    (0xb678, &[0x8c, 0xbb], false),
+    (0xb679, &[0x8c, 0xbc], false),
+    (0xb67a, &[0x8c, 0xbd], false),
+    (0xb67b, &[0x8c, 0xbe], false),
+    (0xb67c, &[0x8c, 0xbf], false),
+    (0xb67d, &[0x8c, 0xc0], false),
+    (0xb67e, &[0x8c, 0xc1], false),
+    (0xb67f, &[0x8c, 0xc2], false),
+    (0xb680, &[0x8c, 0xc3], false),
+    (0xb681, &[0x8c, 0xc4], false),
+    (0xb682, &[0x8c, 0xc5], false),
+    (0xb683, &[0x8c, 0xc6], false),
+    (0xb684, &[0x8c, 0xc7], false),
+    (0xb685, &[0x8c, 0xc8], false),
+    (0xb686, &[0x8c, 0xc9], false),
+    (0xb687, &[0x8c, 0xca], false),
+    (0xb688, &[0x8c, 0xcb], false),
+    (0xb689, &[0x8c, 0xcc], false),
+    (0xb68a, &[0x8c, 0xcd], false),
+    (0xb68b, &[0x8c, 0xce], false),
+    (0xb68c, &[0x8c, 0xcf], false),
+    (0xb68d, &[0x8c, 0xd0], false),
+    (0xb68e, &[0x8c, 0xd1], false),
+    (0xb68f, &[0x8c, 0xd2], false),
+    (0xb690, &[0x8c, 0xd3], false),
+    (0xb691, &[0x8c, 0xd4], false),
+    (0xb692, &[0x8c, 0xd5], false),
+    (0xb693, &[0x8c, 0xd6], false),
+    (0xb694, &[0x8c, 0xd7], false),
+    (0xb695, &[0x8c, 0xd8], false),
+    (0xb696, &[0x8c, 0xd9], false),
+    (0xb697, &[0x8c, 0xda], false),
+    (0xb698, &[0x8c, 0xdb], false),
+    (0xb699, &[0x8c, 0xdc], false),
+    (0xb69a, &[0x8c, 0xdd], false),
+    (0xb69b, &[0x8c, 0xde], false),
+    (0xb69e, &[0x8c, 0xdf], false),
+    (0xb69f, &[0x8c, 0xe0], false),
+    (0xb6a1, &[0x8c, 0xe1], false),
+    (0xb6a2, &[0x8c, 0xe2], false),
+    (0xb6a3, &[0x8c, 0xe3], false),
+    (0xb6a5, &[0x8c, 0xe4], false),
+    (0xb6a6, &[0x8c, 0xe5], false),
+    (0xb6a7, &[0x8c, 0xe6], false),
+    (0xb6a8, &[0x8c, 0xe7], false),
+    (0xb6a9, &[0x8c, 0xe8], false),
+    (0xb6aa, &[0x8c, 0xe9], false),
+    (0xb6ad, &[0x8c, 0xea], false),
+    (0xb6ae, &[0x8c, 0xeb], false),
+    (0xb6af, &[0x8c, 0xec], false),
+    (0xb6b0, &[0x8c, 0xed], false),
+    (0xb6b2, &[0x8c, 0xee], false),
+    (0xb6b3, &[0x8c, 0xef], false),
+    (0xb6b4, &[0x8c, 0xf0], false),
+    (0xb6b5, &[0x8c, 0xf1], false),
+    (0xb6b6, &[0x8c, 0xf2], false),
+    (0xb6b7, &[0x8c, 0xf3], false),
+    (0xb6b8, &[0x8c, 0xf4], false),
+    (0xb6b9, &[0x8c, 0xf5], false),
+    (0xb6ba, &[0x8c, 0xf6], false),
+    (0xb6bb, &[0x8c, 0xf7], false),
+    (0xb6bc, &[0x8c, 0xf8], false),
+    (0xb6bd, &[0x8c, 0xf9], false),
+    (0xb6be, &[0x8c, 0xfa], false),
+    (0xb6bf, &[0x8c, 0xfb], false),
+    (0xb6c0, &[0x8c, 0xfc], false),
+    (0xb6c1, &[0x8c, 0xfd], false),
+    (0xb6c2, &[0x8c, 0xfe], false),
+    (0xb6c3, &[0x8d, 0x41], false),
+    (0xb6c4, &[0x8d, 0x42], false),
+    (0xb6c5, &[0x8d, 0x43], false),
+    (0xb6c6, &[0x8d, 0x44], false),
+    (0xb6c7, &[0x8d, 0x45], false),
+    (0xb6c8, &[0x8d, 0x46], false),
+    (0xb6c9, &[0x8d, 0x47], false),
+    (0xb6ca, &[0x8d, 0x48], false),
+    (0xb6cb, &[0x8d, 0x49], false),
+    (0xb6cc, &[0x8d, 0x4a], false),
+    (0xb6cd, &[0x8d, 0x4b], false),
+    (0xb6ce, &[0x8d, 0x4c], false),
+    (0xb6cf, &[0x8d, 0x4d], false),
+    (0xb6d0, &[0x8d, 0x4e], false),
+    (0xb6d1, &[0x8d, 0x4f], false),
+    (0xb6d2, &[0x8d, 0x50], false),
+    (0xb6d3, &[0x8d, 0x51], false),
+    (0xb6d5, &[0x8d, 0x52], false),
+    (0xb6d6, &[0x8d, 0x53], false),
+    (0xb6d7, &[0x8d, 0x54], false),
+    (0xb6d8, &[0x8d, 0x55], false),
+    (0xb6d9, &[0x8d, 0x56], false),
+    (0xb6da, &[0x8d, 0x57], false),
+    (0xb6db, &[0x8d, 0x58], false),
+    (0xb6dc, &[0x8d, 0x59], false),
+    (0xb6dd, &[0x8d, 0x5a], false),
+    (0xb6de, &[0x8d, 0x61], false),
+    (0xb6df, &[0x8d, 0x62], false),
+    (0xb6e0, &[0x8d, 0x63], false),
+    (0xb6e1, &[0x8d, 0x64], false),
+    (0xb6e2, &[0x8d, 0x65], false),
+    (0xb6e3, &[0x8d, 0x66], false),
+    (0xb6e4, &[0x8d, 0x67], false),
+    (0xb6e5, &[0x8d, 0x68], false),
+    (0xb6e6, &[0x8d, 0x69], false),
+    (0xb6e7, &[0x8d, 0x6a], false),
+    (0xb6e8, &[0x8d, 0x6b], false),
+    (0xb6e9, &[0x8d, 0x6c], false),
+    (0xb6ea, &[0x8d, 0x6d], false),
+    (0xb6eb, &[0x8d, 0x6e], false),
+    (0xb6ec, &[0x8d, 0x6f], false),
+    (0xb6ed, &[0x8d, 0x70], false),
+    (0xb6ee, &[0x8d, 0x71], false),
+    (0xb6ef, &[0x8d, 0x72], false),
+    (0xb6f1, &[0x8d, 0x73], false),
+    (0xb6f2, &[0x8d, 0x74], false),
+    (0xb6f3, &[0x8d, 0x75], false),
+    (0xb6f5, &[0x8d, 0x76], false),
+    (0xb6f6, &[0x8d, 0x77], false),
+    (0xb6f7, &[0x8d, 0x78], false),
+    (0xb6f9, &[0x8d, 0x79], false),
+    (0xb6fa, &[0x8d, 0x7a], false),
+    (0xb6fb, &[0x8d, 0x81], false),
+    (0xb6fc, &[0x8d, 0x82], false),
+    (0xb6fd, &[0x8d, 0x83], false),
+    (0xb6fe, &[0x8d, 0x84], false),
+    (0xb6ff, &[0x8d, 0x85], false),
+    (0xb702, &[0x8d, 0x86], false),
+    (0xb703, &[0x8d, 0x87], false),
+    (0xb704, &[0x8d, 0x88], false),
+    (0xb706, &[0x8d, 0x89], false),
+    (0xb707, &[0x8d, 0x8a], false),
+    (0xb708, &[0x8d, 0x8b], false),
+    (0xb709, &[0x8d, 0x8c], false),
+    (0xb70a, &[0x8d, 0x8d], false),
+    (0xb70b, &[0x8d, 0x8e], false),
+    (0xb70c, &[0x8d, 0x8f], false),
+    (0xb70d, &[0x8d, 0x90], false),
+    (0xb70e, &[0x8d, 0x91], false),
+    (0xb70f, &[0x8d, 0x92], false),
+    (0xb710, &[0x8d, 0x93], false),
+    (0xb711, &[0x8d, 0x94], false),
+    (0xb712, &[0x8d, 0x95], false),
+    (0xb713, &[0x8d, 0x96], false),
+    (0xb714, &[0x8d, 0x97], false),
+    (0xb715, &[0x8d, 0x98], false),
+    (0xb716, &[0x8d, 0x99], false),
+    (0xb717, &[0x8d, 0x9a], false),
+    (0xb718, &[0x8d, 0x9b], false),
+    (0xb719, &[0x8d, 0x9c], false),
+    (0xb71a, &[0x8d, 0x9d], false),
+    (0xb71b, &[0x8d, 0x9e], false),
+    (0xb71c, &[0x8d, 0x9f], false),
+    (0xb71d, &[0x8d, 0xa0], false),
+    (0xb71e, &[0x8d, 0xa1], false),
+    (0xb71f, &[0x8d, 0xa2], false),
+    (0xb720, &[0x8d, 0xa3], false),
+    (0xb721, &[0x8d, 0xa4], false),
+    (0xb722, &[0x8d, 0xa5], false),
+    (0xb723, &[0x8d, 0xa6], false),
+    (0xb724, &[0x8d, 0xa7], false),
+    (0xb725, &[0x8d, 0xa8], false),
+    (0xb726, &[0x8d, 0xa9], false),
+    (0xb727, &[0x8d, 0xaa], false),
+    (0xb72a, &[0x8d, 0xab], false),
+    (0xb72b, &[0x8d, 0xac], false),
+    (0xb72d, &[0x8d, 0xad], false),
+    (0xb72e, &[0x8d, 0xae], false),
+    (0xb731, &[0x8d, 0xaf], false),
+    (0xb732, &[0x8d, 0xb0], false),
+    (0xb733, &[0x8d, 0xb1], false),
+    (0xb734, &[0x8d, 0xb2], false),
+    (0xb735, &[0x8d, 0xb3], false),
+    (0xb736, &[0x8d, 0xb4], false),
+    (0xb737, &[0x8d, 0xb5], false),
+    (0xb73a, &[0x8d, 0xb6], false),
+    (0xb73c, &[0x8d, 0xb7], false),
+    (0xb73d, &[0x8d, 0xb8], false),
+    (0xb73e, &[0x8d, 0xb9], false),
+    (0xb73f, &[0x8d, 0xba], false),
+    (0xb740, &[0x8d, 0xbb], false),
+    (0xb741, &[0x8d, 0xbc], false),
+    (0xb742, &[0x8d, 0xbd], false),
+    (0xb743, &[0x8d, 0xbe], false),
+    (0xb745, &[0x8d, 0xbf], false),
+    (0xb746, &[0x8d, 0xc0], false),
+    (0xb747, &[0x8d, 0xc1], false),
+    (0xb749, &[0x8d, 0xc2], false),
+    (0xb74a, &[0x8d, 0xc3], false),
+    (0xb74b, &[0x8d, 0xc4], false),
+    (0xb74d, &[0x8d, 0xc5], false),
+    (0xb74e, &[0x8d, 0xc6], false),
+    (0xb74f, &[0x8d, 0xc7], false),
+    (0xb750, &[0x8d, 0xc8], false),
+    (0xb751, &[0x8d, 0xc9], false),
+    (0xb752, &[0x8d, 0xca], false),
+    (0xb753, &[0x8d, 0xcb], false),
+    (0xb756, &[0x8d, 0xcc], false),
+    (0xb757, &[0x8d, 0xcd], false),
+    (0xb758, &[0x8d, 0xce], false),
+    (0xb759, &[0x8d, 0xcf], false),
+    (0xb75a, &[0x8d, 0xd0], false),
+    (0xb75b, &[0x8d, 0xd1], false),
+    (0xb75c, &[0x8d, 0xd2], false),
+    (0xb75d, &[0x8d, 0xd3], false),
+    (0xb75e, &[0x8d, 0xd4], false),
+    (0xb75f, &[0x8d, 0xd5], false),
+    (0xb761, &[0x8d, 0xd6], false),
+    (0xb762, &[0x8d, 0xd7], false),
+    (0xb763, &[0x8d, 0xd8], false),
+    (0xb765, &[0x8d, 0xd9], false),
+    (0xb766, &[0x8d, 0xda], false),
+    (0xb767, &[0x8d, 0xdb], false),
+    (0xb769, &[0x8d, 0xdc], false),
+    (0xb76a, &[0x8d, 0xdd], false),
+    (0xb76b, &[0x8d, 0xde], false),
+    (0xb76c, &[0x8d, 0xdf], false),
+    (0xb76d, &[0x8d, 0xe0], false),
+    (0xb76e, &[0x8d, 0xe1], false),
+    (0xb76f, &[0x8d, 0xe2], false),
+    (0xb772, &[0x8d, 0xe3], false),
+    (0xb774, &[0x8d, 0xe4], false),
+    (0xb776, &[0x8d, 0xe5], false),
+    (0xb777, &[0x8d, 0xe6], false),
+    (0xb778, &[0x8d, 0xe7], false),
+    (0xb779, &[0x8d, 0xe8], false),
+    (0xb77a, &[0x8d, 0xe9], false),
+    (0xb77b, &[0x8d, 0xea], false),
+    (0xb77e, &[0x8d, 0xeb], false),
+    (0xb77f, &[0x8d, 0xec], false),
+    (0xb781, &[0x8d, 0xed], false),
+    (0xb782, &[0x8d, 0xee], false),
+    (0xb783, &[0x8d, 0xef], false),
+    (0xb785, &[0x8d, 0xf0], false),
+    (0xb786, &[0x8d, 0xf1], false),
+    (0xb787, &[0x8d, 0xf2], false),
+    (0xb788, &[0x8d, 0xf3], false),
+    (0xb789, &[0x8d, 0xf4], false),
+    (0xb78a, &[0x8d, 0xf5], false),
+    (0xb78b, &[0x8d, 0xf6], false),
+    (0xb78e, &[0x8d, 0xf7], false),
+    (0xb793, &[0x8d, 0xf8], false),
+    (0xb794, &[0x8d, 0xf9], false),
+    (0xb795, &[0x8d, 0xfa], false),
+    (0xb79a, &[0x8d, 0xfb], false),
+    (0xb79b, &[0x8d, 0xfc], false),
+    (0xb79d, &[0x8d, 0xfd], false),
+    (0xb79e, &[0x8d, 0xfe], false),
+    (0xb79f, &[0x8e, 0x41], false),
+    (0xb7a1, &[0x8e, 0x42], false),
+    (0xb7a2, &[0x8e, 0x43], false),
+    (0xb7a3, &[0x8e, 0x44], false),
+    (0xb7a4, &[0x8e, 0x45], false),
+    (0xb7a5, &[0x8e, 0x46], false),
+    (0xb7a6, &[0x8e, 0x47], false),
+    (0xb7a7, &[0x8e, 0x48], false),
+    (0xb7aa, &[0x8e, 0x49], false),
+    (0xb7ae, &[0x8e, 0x4a], false),
+    (0xb7af, &[0x8e, 0x4b], false),
+    (0xb7b0, &[0x8e, 0x4c], false),
+    (0xb7b1, &[0x8e, 0x4d], false),
+    (0xb7b2, &[0x8e, 0x4e], false),
+    (0xb7b3, &[0x8e, 0x4f], false),
+    (0xb7b6, &[0x8e, 0x50], false),
+    (0xb7b7, &[0x8e, 0x51], false),
+    (0xb7b9, &[0x8e, 0x52], false),
+    (0xb7ba, &[0x8e, 0x53], false),
+    (0xb7bb, &[0x8e, 0x54], false),
+    (0xb7bc, &[0x8e, 0x55], false),
+    (0xb7bd, &[0x8e, 0x56], false),
+    (0xb7be, &[0x8e, 0x57], false),
+    (0xb7bf, &[0x8e, 0x58], false),
+    (0xb7c0, &[0x8e, 0x59], false),
+    (0xb7c1, &[0x8e, 0x5a], false),
+    (0xb7c2, &[0x8e, 0x61], false),
+    (0xb7c3, &[0x8e, 0x62], false),
+    (0xb7c4, &[0x8e, 0x63], false),
+    (0xb7c5, &[0x8e, 0x64], false),
+    (0xb7c6, &[0x8e, 0x65], false),
+    (0xb7c8, &[0x8e, 0x66], false),
+    (0xb7ca, &[0x8e, 0x67], false),
+    (0xb7cb, &[0x8e, 0x68], false),
+    (0xb7cc, &[0x8e, 0x69], false),
+    (0xb7cd, &[0x8e, 0x6a], false),
+    (0xb7ce, &[0x8e, 0x6b], false),
+    (0xb7cf, &[0x8e, 0x6c], false),
+    (0xb7d0, &[0x8e, 0x6d], false),
+    (0xb7d1, &[0x8e, 0x6e], false),
+    (0xb7d2, &[0x8e, 0x6f], false),
+    (0xb7d3, &[0x8e, 0x70], false),
+    (0xb7d4, &[0x8e, 0x71], false),
+    (0xb7d5, &[0x8e, 0x72], false),
+    (0xb7d6, &[0x8e, 0x73], false),
+    (0xb7d7, &[0x8e, 0x74], false),
+    (0xb7d8, &[0x8e, 0x75], false),
+    (0xb7d9, &[0x8e, 0x76], false),
+    (0xb7da, &[0x8e, 0x77], false),
+    (0xb7db, &[0x8e, 0x78], false),
+    (0xb7dc, &[0x8e, 0x79], false),
+    (0xb7dd, &[0x8e, 0x7a], false),
+    (0xb7de, &[0x8e, 0x81], false),
+    (0xb7df, &[0x8e, 0x82], false),
+    (0xb7e0, &[0x8e, 0x83], false),
+    (0xb7e1, &[0x8e, 0x84], false),
+    (0xb7e2, &[0x8e, 0x85], false),
+    (0xb7e3, &[0x8e, 0x86], false),
+    (0xb7e4, &[0x8e, 0x87], false),
+    (0xb7e5, &[0x8e, 0x88], false),
+    (0xb7e6, &[0x8e, 0x89], false),
+    (0xb7e7, &[0x8e, 0x8a], false),
+    (0xb7e8, &[0x8e, 0x8b], false),
+    (0xb7e9, &[0x8e, 0x8c], false),
+    (0xb7ea, &[0x8e, 0x8d], false),
+    (0xb7eb, &[0x8e, 0x8e], false),
+    (0xb7ee, &[0x8e, 0x8f], false),
+    (0xb7ef, &[0x8e, 0x90], false),
+    (0xb7f1, &[0x8e, 0x91], false),
+    (0xb7f2, &[0x8e, 0x92], false),
+    (0xb7f3, &[0x8e, 0x93], false),
+    (0xb7f5, &[0x8e, 0x94], false),
+    (0xb7f6, &[0x8e, 0x95], false),
+    (0xb7f7, &[0x8e, 0x96], false),
+    (0xb7f8, &[0x8e, 0x97], false),
+    (0xb7f9, &[0x8e, 0x98], false),
+    (0xb7fa, &[0x8e, 0x99], false),
+    (0xb7fb, &[0x8e, 0x9a], false),
+    (0xb7fe, &[0x8e, 0x9b], false),
+    (0xb802, &[0x8e, 0x9c], false),
+    (0xb803, &[0x8e, 0x9d], false),
+    (0xb804, &[0x8e, 0x9e], false),
+    (0xb805, &[0x8e, 0x9f], false),
+    (0xb806, &[0x8e, 0xa0], false),
+    (0xb80a, &[0x8e, 0xa1], false),
+    (0xb80b, &[0x8e, 0xa2], false),
+    (0xb80d, &[0x8e, 0xa3], false),
+    (0xb80e, &[0x8e, 0xa4], false),
+    (0xb80f, &[0x8e, 0xa5], false),
+    (0xb811, &[0x8e, 0xa6], false),
+    (0xb812, &[0x8e, 0xa7], false),
+    (0xb813, &[0x8e, 0xa8], false),
+    (0xb814, &[0x8e, 0xa9], false),
+    (0xb815, &[0x8e, 0xaa], false),
+    (0xb816, &[0x8e, 0xab], false),
+    (0xb817, &[0x8e, 0xac], false),
+    (0xb81a, &[0x8e, 0xad], false),
+    (0xb81c, &[0x8e, 0xae], false),
+    (0xb81e, &[0x8e, 0xaf], false),
+    (0xb81f, &[0x8e, 0xb0], false),
+    (0xb820, &[0x8e, 0xb1], false),
+    (0xb821, &[0x8e, 0xb2], false),
+    (0xb822, &[0x8e, 0xb3], false),
+    (0xb823, &[0x8e, 0xb4], false),
+    (0xb826, &[0x8e, 0xb5], false),
+    (0xb827, &[0x8e, 0xb6], false),
+    (0xb829, &[0x8e, 0xb7], false),
+    (0xb82a, &[0x8e, 0xb8], false),
+    (0xb82b, &[0x8e, 0xb9], false),
+    (0xb82d, &[0x8e, 0xba], false),
+    (0xb82e, &[0x8e, 0xbb], false),
+    (0xb82f, &[0x8e, 0xbc], false),
+    (0xb830, &[0x8e, 0xbd], false),
+    (0xb831, &[0x8e, 0xbe], false),
+    (0xb832, &[0x8e, 0xbf], false),
+    (0xb833, &[0x8e, 0xc0], false),
+    (0xb836, &[0x8e, 0xc1], false),
+    (0xb83a, &[0x8e, 0xc2], false),
+    (0xb83b, &[0x8e, 0xc3], false),
+    (0xb83c, &[0x8e, 0xc4], false),
+    (0xb83d, &[0x8e, 0xc5], false),
+    (0xb83e, &[0x8e, 0xc6], false),
+    (0xb83f, &[0x8e, 0xc7], false),
+    (0xb841, &[0x8e, 0xc8], false),
+    (0xb842, &[0x8e, 0xc9], false),
+    (0xb843, &[0x8e, 0xca], false),
+    (0xb845, &[0x8e, 0xcb], false),
+    (0xb846, &[0x8e, 0xcc], false),
+    (0xb847, &[0x8e, 0xcd], false),
+    (0xb848, &[0x8e, 0xce], false),
+    (0xb849, &[0x8e, 0xcf], false),
+    (0xb84a, &[0x8e, 0xd0], false),
+    (0xb84b, &[0x8e, 0xd1], false),
+    (0xb84c, &[0x8e, 0xd2], false),
+    (0xb84d, &[0x8e, 0xd3], false),
+    (0xb84e, &[0x8e, 0xd4], false),
+    (0xb84f, &[0x8e, 0xd5], false),
+    (0xb850, &[0x8e, 0xd6], false),
+    (0xb852, &[0x8e, 0xd7], false),
+    (0xb854, &[0x8e, 0xd8], false),
+    (0xb855, &[0x8e, 0xd9], false),
+    (0xb856, &[0x8e, 0xda], false),
+    (0xb857, &[0x8e, 0xdb], false),
+    (0xb858, &[0x8e, 0xdc], false),
+    (0xb859, &[0x8e, 0xdd], false),
+    (0xb85a, &[0x8e, 0xde], false),
+    (0xb85b, &[0x8e, 0xdf], false),
+    (0xb85e, &[0x8e, 0xe0], false),
+    (0xb85f, &[0x8e, 0xe1], false),
+    (0xb861, &[0x8e, 0xe2], false),
+    (0xb862, &[0x8e, 0xe3], false),
+    (0xb863, &[0x8e, 0xe4], false),
+    (0xb865, &[0x8e, 0xe5], false),
+    (0xb866, &[0x8e, 0xe6], false),
+    (0xb867, &[0x8e, 0xe7], false),
+    (0xb868, &[0x8e, 0xe8], false),
+    (0xb869, &[0x8e, 0xe9], false),
+    (0xb86a, &[0x8e, 0xea], false),
+    (0xb86b, &[0x8e, 0xeb], false),
+    (0xb86e, &[0x8e, 0xec], false),
+    (0xb870, &[0x8e, 0xed], false),
+    (0xb872, &[0x8e, 0xee], false),
+    (0xb873, &[0x8e, 0xef], false),
+    (0xb874, &[0x8e, 0xf0], false),
+    (0xb875, &[0x8e, 0xf1], false),
+    (0xb876, &[0x8e, 0xf2], false),
+    (0xb877, &[0x8e, 0xf3], false),
+    (0xb879, &[0x8e, 0xf4], false),
+    (0xb87a, &[0x8e, 0xf5], false),
+    (0xb87b, &[0x8e, 0xf6], false),
+    (0xb87d, &[0x8e, 0xf7], false),
+    (0xb87e, &[0x8e, 0xf8], false),
+    (0xb87f, &[0x8e, 0xf9], false),
+    (0xb880, &[0x8e, 0xfa], false),
+    (0xb881, &[0x8e, 0xfb], false),
+    (0xb882, &[0x8e, 0xfc], false),
+    (0xb883, &[0x8e, 0xfd], false),
+    (0xb884, &[0x8e, 0xfe], false),
+    (0xb885, &[0x8f, 0x41], false),
+    (0xb886, &[0x8f, 0x42], false),
+    (0xb887, &[0x8f, 0x43], false),
+    (0xb888, &[0x8f, 0x44], false),
+    (0xb889, &[0x8f, 0x45], false),
+    (0xb88a, &[0x8f, 0x46], false),
+    (0xb88b, &[0x8f, 0x47], false),
+    (0xb88c, &[0x8f, 0x48], false),
+    (0xb88e, &[0x8f, 0x49], false),
+    (0xb88f, &[0x8f, 0x4a], false),
+    (0xb890, &[0x8f, 0x4b], false),
+    (0xb891, &[0x8f, 0x4c], false),
+    (0xb892, &[0x8f, 0x4d], false),
+    (0xb893, &[0x8f, 0x4e], false),
+    (0xb894, &[0x8f, 0x4f], false),
+    (0xb895, &[0x8f, 0x50], false),
+    (0xb896, &[0x8f, 0x51], false),
+    (0xb897, &[0x8f, 0x52], false),
+    (0xb898, &[0x8f, 0x53], false),
+    (0xb899, &[0x8f, 0x54], false),
+    (0xb89a, &[0x8f, 0x55], false),
+    (0xb89b, &[0x8f, 0x56], false),
+    (0xb89c, &[0x8f, 0x57], false),
+    (0xb89d, &[0x8f, 0x58], false),
+    (0xb89e, &[0x8f, 0x59], false),
+    (0xb89f, &[0x8f, 0x5a], false),
+    (0xb8a0, &[0x8f, 0x61], false),
+    (0xb8a1, &[0x8f, 0x62], false),
+    (0xb8a2, &[0x8f, 0x63], false),
+    (0xb8a3, &[0x8f, 0x64], false),
+    (0xb8a4, &[0x8f, 0x65], false),
+    (0xb8a5, &[0x8f, 0x66], false),
+    (0xb8a6, &[0x8f, 0x67], false),
+    (0xb8a7, &[0x8f, 0x68], false),
+    (0xb8a9, &[0x8f, 0x69], false),
+    (0xb8aa, &[0x8f, 0x6a], false),
+    (0xb8ab, &[0x8f, 0x6b], false),
+    (0xb8ac, &[0x8f, 0x6c], false),
+    (0xb8ad, &[0x8f, 0x6d], false),
+    (0xb8ae, &[0x8f, 0x6e], false),
+    (0xb8af, &[0x8f, 0x6f], false),
+    (0xb8b1, &[0x8f, 0x70], false),
+    (0xb8b2, &[0x8f, 0x71], false),
+    (0xb8b3, &[0x8f, 0x72], false),
+    (0xb8b5, &[0x8f, 0x73], false),
+    (0xb8b6, &[0x8f, 0x74], false),
+    (0xb8b7, &[0x8f, 0x75], false),
+    (0xb8b9, &[0x8f, 0x76], false),
+    (0xb8ba, &[0x8f, 0x77], false),
+    (0xb8bb, &[0x8f, 0x78], false),
+    (0xb8bc, &[0x8f, 0x79], false),
+    (0xb8bd, &[0x8f, 0x7a], false),
+    (0xb8be, &[0x8f, 0x81], false),
+    (0xb8bf, &[0x8f, 0x82], false),
+    (0xb8c2, &[0x8f, 0x83], false),
+    (0xb8c4, &[0x8f, 0x84], false),
+    (0xb8c6, &[0x8f, 0x85], false),
+    (0xb8c7, &[0x8f, 0x86], false),
+    (0xb8c8, &[0x8f, 0x87], false),
+    (0xb8c9, &[0x8f, 0x88], false),
+    (0xb8ca, &[0x8f, 0x89], false),
+    (0xb8cb, &[0x8f, 0x8a], false),
+    (0xb8cd, &[0x8f, 0x8b], false),
+    (0xb8ce, &[0x8f, 0x8c], false),
+    (0xb8cf, &[0x8f, 0x8d], false),
+    (0xb8d1, &[0x8f, 0x8e], false),
+    (0xb8d2, &[0x8f, 0x8f], false),
+    (0xb8d3, &[0x8f, 0x90], false),
+    (0xb8d5, &[0x8f, 0x91], false),
+    (0xb8d6, &[0x8f, 0x92], false),
+    (0xb8d7, &[0x8f, 0x93], false),
+    (0xb8d8, &[0x8f, 0x94], false),
+    (0xb8d9, &[0x8f, 0x95], false),
+    (0xb8da, &[0x8f, 0x96], false),
+    (0xb8db, &[0x8f, 0x97], false),
+    (0xb8dc, &[0x8f, 0x98], false),
+    (0xb8de, &[0x8f, 0x99], false),
+    (0xb8e0, &[0x8f, 0x9a], false),
+    (0xb8e2, &[0x8f, 0x9b], false),
+    (0xb8e3, &[0x8f, 0x9c], false),
+    (0xb8e4, &[0x8f, 0x9d], false),
+    (0xb8e5, &[0x8f, 0x9e], false),
+    (0xb8e6, &[0x8f, 0x9f], false),
+    (0xb8e7, &[0x8f, 0xa0], false),
+    (0xb8ea, &[0x8f, 0xa1], false),
+    (0xb8eb, &[0x8f, 0xa2], false),
+    (0xb8ed, &[0x8f, 0xa3], false),
+    (0xb8ee, &[0x8f, 0xa4], false),
+    (0xb8ef, &[0x8f, 0xa5], false),
+    (0xb8f1, &[0x8f, 0xa6], false),
+    (0xb8f2, &[0x8f, 0xa7], false),
+    (0xb8f3, &[0x8f, 0xa8], false),
+    (0xb8f4, &[0x8f, 0xa9], false),
+    (0xb8f5, &[0x8f, 0xaa], false),
+    (0xb8f6, &[0x8f, 0xab], false),
+    (0xb8f7, &[0x8f, 0xac], false),
+    (0xb8fa, &[0x8f, 0xad], false),
+    (0xb8fc, &[0x8f, 0xae], false),
+    (0xb8fe, &[0x8f, 0xaf], false),
+    (0xb8ff, &[0x8f, 0xb0], false),
+    (0xb900, &[0x8f, 0xb1], false),
+    (0xb901, &[0x8f, 0xb2], false),
+    (0xb902, &[0x8f, 0xb3], false),
+    (0xb903, &[0x8f, 0xb4], false),
+    (0xb905, &[0x8f, 0xb5], false),
+    (0xb906, &[0x8f, 0xb6], false),
+    (0xb907, &[0x8f, 0xb7], false),
+    (0xb908, &[0x8f, 0xb8], false),
+    (0xb909, &[0x8f, 0xb9], false),
+    (0xb90a, &[0x8f, 0xba], false),
+    (0xb90b, &[0x8f, 0xbb], false),
+    (0xb90c, &[0x8f, 0xbc], false),
+    (0xb90d, &[0x8f, 0xbd], false),
+    (0xb90e, &[0x8f, 0xbe], false),
+    (0xb90f, &[0x8f, 0xbf], false),
+    (0xb910, &[0x8f, 0xc0], false),
+    (0xb911, &[0x8f, 0xc1], false),
+    (0xb912, &[0x8f, 0xc2], false),
+    (0xb913, &[0x8f, 0xc3], false),
+    (0xb914, &[0x8f, 0xc4], false),
+    (0xb915, &[0x8f, 0xc5], false),
+    (0xb916, &[0x8f, 0xc6], false),
+    (0xb917, &[0x8f, 0xc7], false),
+    (0xb919, &[0x8f, 0xc8], false),
+    (0xb91a, &[0x8f, 0xc9], false),
+    (0xb91b, &[0x8f, 0xca], false),
+    (0xb91c, &[0x8f, 0xcb], false),
+    (0xb91d, &[0x8f, 0xcc], false),
+    (0xb91e, &[0x8f, 0xcd], false),
+    (0xb91f, &[0x8f, 0xce], false),
+    (0xb921, &[0x8f, 0xcf], false),
+    (0xb922, &[0x8f, 0xd0], false),
+    (0xb923, &[0x8f, 0xd1], false),
+    (0xb924, &[0x8f, 0xd2], false),
+    (0xb925, &[0x8f, 0xd3], false),
+    (0xb926, &[0x8f, 0xd4], false),
+    (0xb927, &[0x8f, 0xd5], false),
+    (0xb928, &[0x8f, 0xd6], false),
+    (0xb929, &[0x8f, 0xd7], false),
+    (0xb92a, &[0x8f, 0xd8], false),
+    (0xb92b, &[0x8f, 0xd9], false),
+    (0xb92c, &[0x8f, 0xda], false),
+    (0xb92d, &[0x8f, 0xdb], false),
+    (0xb92e, &[0x8f, 0xdc], false),
+    (0xb92f, &[0x8f, 0xdd], false),
+    (0xb930, &[0x8f, 0xde], false),
+    (0xb931, &[0x8f, 0xdf], false),
+    (0xb932, &[0x8f, 0xe0], false),
+    (0xb933, &[0x8f, 0xe1], false),
+    (0xb934, &[0x8f, 0xe2], false),
+    (0xb935, &[0x8f, 0xe3], false),
+    (0xb936, &[0x8f, 0xe4], false),
+    (0xb937, &[0x8f, 0xe5], false),
+    (0xb938, &[0x8f, 0xe6], false),
+    (0xb939, &[0x8f, 0xe7], false),
+    (0xb93a, &[0x8f, 0xe8], false),
+    (0xb93b, &[0x8f, 0xe9], false),
+    (0xb93e, &[0x8f, 0xea], false),
+    (0xb93f, &[0x8f, 0xeb], false),
+    (0xb941, &[0x8f, 0xec], false),
+    (0xb942, &[0x8f, 0xed], false),
+    (0xb943, &[0x8f, 0xee], false),
+    (0xb945, &[0x8f, 0xef], false),
+    (0xb946, &[0x8f, 0xf0], false),
+    (0xb947, &[0x8f, 0xf1], false),
+    (0xb948, &[0x8f, 0xf2], false),
+    (0xb949, &[0x8f, 0xf3], false),
+    (0xb94a, &[0x8f, 0xf4], false),
+    (0xb94b, &[0x8f, 0xf5], false),
+    (0xb94d, &[0x8f, 0xf6], false),
+    (0xb94e, &[0x8f, 0xf7], false),
+    (0xb950, &[0x8f, 0xf8], false),
+    (0xb952, &[0x8f, 0xf9], false),
+    (0xb953, &[0x8f, 0xfa], false),
+    (0xb954, &[0x8f, 0xfb], false),
+    (0xb955, &[0x8f, 0xfc], false),
+    (0xb956, &[0x8f, 0xfd], false),
+    (0xb957, &[0x8f, 0xfe], false),
+    (0xb95a, &[0x90, 0x41], false),
+    (0xb95b, &[0x90, 0x42], false),
+    (0xb95d, &[0x90, 0x43], false),
+    (0xb95e, &[0x90, 0x44], false),
+    (0xb95f, &[0x90, 0x45], false),
+    (0xb961, &[0x90, 0x46], false),
+    (0xb962, &[0x90, 0x47], false),
+    (0xb963, &[0x90, 0x48], false),
+    (0xb964, &[0x90, 0x49], false),
+    (0xb965, &[0x90, 0x4a], false),
+    (0xb966, &[0x90, 0x4b], false),
+    (0xb967, &[0x90, 0x4c], false),
+    (0xb96a, &[0x90, 0x4d], false),
+    (0xb96c, &[0x90, 0x4e], false),
+    (0xb96e, &[0x90, 0x4f], false),
+    (0xb96f, &[0x90, 0x50], false),
+    (0xb970, &[0x90, 0x51], false),
+    (0xb971, &[0x90, 0x52], false),
+    (0xb972, &[0x90, 0x53], false),
+    (0xb973, &[0x90, 0x54], false),
+    (0xb976, &[0x90, 0x55], false),
+    (0xb977, &[0x90, 0x56], false),
+    (0xb979, &[0x90, 0x57], false),
+    (0xb97a, &[0x90, 0x58], false),
+    (0xb97b, &[0x90, 0x59], false),
+    (0xb97d, &[0x90, 0x5a], false),
+    (0xb97e, &[0x90, 0x61], false),
+    (0xb97f, &[0x90, 0x62], false),
+    (0xb980, &[0x90, 0x63], false),
+    (0xb981, &[0x90, 0x64], false),
+    (0xb982, &[0x90, 0x65], false),
+    (0xb983, &[0x90, 0x66], false),
+    (0xb986, &[0x90, 0x67], false),
+    (0xb988, &[0x90, 0x68], false),
+    (0xb98b, &[0x90, 0x69], false),
+    (0xb98c, &[0x90, 0x6a], false),
+    (0xb98f, &[0x90, 0x6b], false),
+    (0xb990, &[0x90, 0x6c], false),
+    (0xb991, &[0x90, 0x6d], false),
+    (0xb992, &[0x90, 0x6e], false),
+    (0xb993, &[0x90, 0x6f], false),
+    (0xb994, &[0x90, 0x70], false),
+    (0xb995, &[0x90, 0x71], false),
+    (0xb996, &[0x90, 0x72], false),
+    (0xb997, &[0x90, 0x73], false),
+    (0xb998, &[0x90, 0x74], false),
+    (0xb999, &[0x90, 0x75], false),
+    (0xb99a, &[0x90, 0x76], false),
+    (0xb99b, &[0x90, 0x77], false),
+    (0xb99c, &[0x90, 0x78], false),
+    (0xb99d, &[0x90, 0x79], false),
+    (0xb99e, &[0x90, 0x7a], false),
+    (0xb99f, &[0x90, 0x81], false),
+    (0xb9a0, &[0x90, 0x82], false),
+    (0xb9a1, &[0x90, 0x83], false),
+    (0xb9a2, &[0x90, 0x84], false),
+    (0xb9a3, &[0x90, 0x85], false),
+    (0xb9a4, &[0x90, 0x86], false),
+    (0xb9a5, &[0x90, 0x87], false),
+    (0xb9a6, &[0x90, 0x88], false),
+    (0xb9a7, &[0x90, 0x89], false),
+    (0xb9a8, &[0x90, 0x8a], false),
+    (0xb9a9, &[0x90, 0x8b], false),
+    (0xb9aa, &[0x90, 0x8c], false),
+    (0xb9ab, &[0x90, 0x8d], false),
+    (0xb9ae, &[0x90, 0x8e], false),
+    (0xb9af, &[0x90, 0x8f], false),
+    (0xb9b1, &[0x90, 0x90], false),
+    (0xb9b2, &[0x90, 0x91], false),
+    (0xb9b3, &[0x90, 0x92], false),
+    (0xb9b5, &[0x90, 0x93], false),
+    (0xb9b6, &[0x90, 0x94], false),
+    (0xb9b7, &[0x90, 0x95], false),
+    (0xb9b8, &[0x90, 0x96], false),
+    (0xb9b9, &[0x90, 0x97], false),
+    (0xb9ba, &[0x90, 0x98], false),
+    (0xb9bb, &[0x90, 0x99], false),
+    (0xb9be, &[0x90, 0x9a], false),
+    (0xb9c0, &[0x90, 0x9b], false),
+    (0xb9c2, &[0x90, 0x9c], false),
+    (0xb9c3, &[0x90, 0x9d], false),
+    (0xb9c4, &[0x90, 0x9e], false),
+    (0xb9c5, &[0x90, 0x9f], false),
+    (0xb9c6, &[0x90, 0xa0], false),
+    (0xb9c7, &[0x90, 0xa1], false),
+    (0xb9ca, &[0x90, 0xa2], false),
+    (0xb9cb, &[0x90, 0xa3], false),
+    (0xb9cd, &[0x90, 0xa4], false),
+    (0xb9d3, &[0x90, 0xa5], false),
+    (0xb9d4, &[0x90, 0xa6], false),
+    (0xb9d5, &[0x90, 0xa7], false),
+    (0xb9d6, &[0x90, 0xa8], false),
+    (0xb9d7, &[0x90, 0xa9], false),
+    (0xb9da, &[0x90, 0xaa], false),
+    (0xb9dc, &[0x90, 0xab], false),
+    (0xb9df, &[0x90, 0xac], false),
+    (0xb9e0, &[0x90, 0xad], false),
+    (0xb9e2, &[0x90, 0xae], false),
+    (0xb9e6, &[0x90, 0xaf], false),
+    (0xb9e7, &[0x90, 0xb0], false),
+    (0xb9e9, &[0x90, 0xb1], false),
+    (0xb9ea, &[0x90, 0xb2], false),
+    (0xb9eb, &[0x90, 0xb3], false),
+    (0xb9ed, &[0x90, 0xb4], false),
+    (0xb9ee, &[0x90, 0xb5], false),
+    (0xb9ef, &[0x90, 0xb6], false),
+    (0xb9f0, &[0x90, 0xb7], false),
+    (0xb9f1, &[0x90, 0xb8], false),
+    (0xb9f2, &[0x90, 0xb9], false),
+    (0xb9f3, &[0x90, 0xba], false),
+    (0xb9f6, &[0x90, 0xbb], false),
+    (0xb9fb, &[0x90, 0xbc], false),
+    (0xb9fc, &[0x90, 0xbd], false),
+    (0xb9fd, &[0x90, 0xbe], false),
+    (0xb9fe, &[0x90, 0xbf], false),
+    (0xb9ff, &[0x90, 0xc0], false),
+    (0xba02, &[0x90, 0xc1], false),
+    (0xba03, &[0x90, 0xc2], false),
+    (0xba04, &[0x90, 0xc3], false),
+    (0xba05, &[0x90, 0xc4], false),
+    (0xba06, &[0x90, 0xc5], false),
+    (0xba07, &[0x90, 0xc6], false),
+    (0xba09, &[0x90, 0xc7], false),
+    (0xba0a, &[0x90, 0xc8], false),
+    (0xba0b, &[0x90, 0xc9], false),
+    (0xba0c, &[0x90, 0xca], false),
+    (0xba0d, &[0x90, 0xcb], false),
+    (0xba0e, &[0x90, 0xcc], false),
+    (0xba0f, &[0x90, 0xcd], false),
+    (0xba10, &[0x90, 0xce], false),
+    (0xba11, &[0x90, 0xcf], false),
+    (0xba12, &[0x90, 0xd0], false),
+    (0xba13, &[0x90, 0xd1], false),
+    (0xba14, &[0x90, 0xd2], false),
+    (0xba16, &[0x90, 0xd3], false),
+    (0xba17, &[0x90, 0xd4], false),
+    (0xba18, &[0x90, 0xd5], false),
+    (0xba19, &[0x90, 0xd6], false),
+    (0xba1a, &[0x90, 0xd7], false),
+    (0xba1b, &[0x90, 0xd8], false),
+    (0xba1c, &[0x90, 0xd9], false),
+    (0xba1d, &[0x90, 0xda], false),
+    (0xba1e, &[0x90, 0xdb], false),
+    (0xba1f, &[0x90, 0xdc], false),
+    (0xba20, &[0x90, 0xdd], false),
+    (0xba21, &[0x90, 0xde], false),
+    (0xba22, &[0x90, 0xdf], false),
+    (0xba23, &[0x90, 0xe0], false),
+    (0xba24, &[0x90, 0xe1], false),
+    (0xba25, &[0x90, 0xe2], false),
+    (0xba26, &[0x90, 0xe3], false),
+    (0xba27, &[0x90, 0xe4], false),
+    (0xba28, &[0x90, 0xe5], false),
+    (0xba29, &[0x90, 0xe6], false),
+    (0xba2a, &[0x90, 0xe7], false),
+    (0xba2b, &[0x90, 0xe8], false),
+    (0xba2c, &[0x90, 0xe9], false),
+    (0xba2d, &[0x90, 0xea], false),
+    (0xba2e, &[0x90, 0xeb], false),
+    (0xba2f, &[0x90, 0xec], false),
+    (0xba30, &[0x90, 0xed], false),
+    (0xba31, &[0x90, 0xee], false),
+    (0xba32, &[0x90, 0xef], false),
+    (0xba33, &[0x90, 0xf0], false),
+    (0xba34, &[0x90, 0xf1], false),
+    (0xba35, &[0x90, 0xf2], false),
+    (0xba36, &[0x90, 0xf3], false),
+    (0xba37, &[0x90, 0xf4], false),
+    (0xba3a, &[0x90, 0xf5], false),
+    (0xba3b, &[0x90, 0xf6], false),
+    (0xba3d, &[0x90, 0xf7], false),
+    (0xba3e, &[0x90, 0xf8], false),
+    (0xba3f, &[0x90, 0xf9], false),
+    (0xba41, &[0x90, 0xfa], false),
+    (0xba43, &[0x90, 0xfb], false),
+    (0xba44, &[0x90, 0xfc], false),
+    (0xba45, &[0x90, 0xfd], false),
+    (0xba46, &[0x90, 0xfe], false),
+    (0xba47, &[0x91, 0x41], false),
+    (0xba4a, &[0x91, 0x42], false),
+    (0xba4c, &[0x91, 0x43], false),
+    (0xba4f, &[0x91, 0x44], false),
+    (0xba50, &[0x91, 0x45], false),
+    (0xba51, &[0x91, 0x46], false),
+    (0xba52, &[0x91, 0x47], false),
+    (0xba56, &[0x91, 0x48], false),
+    (0xba57, &[0x91, 0x49], false),
+    (0xba59, &[0x91, 0x4a], false),
+    (0xba5a, &[0x91, 0x4b], false),
+    (0xba5b, &[0x91, 0x4c], false),
+    (0xba5d, &[0x91, 0x4d], false),
+    (0xba5e, &[0x91, 0x4e], false),
+    (0xba5f, &[0x91, 0x4f], false),
+    (0xba60, &[0x91, 0x50], false),
+    (0xba61, &[0x91, 0x51], false),
+    (0xba62, &[0x91, 0x52], false),
+    (0xba63, &[0x91, 0x53], false),
+    (0xba66, &[0x91, 0x54], false),
+    (0xba6a, &[0x91, 0x55], false),
+    (0xba6b, &[0x91, 0x56], false),
+    (0xba6c, &[0x91, 0x57], false),
+    (0xba6d, &[0x91, 0x58], false),
+    (0xba6e, &[0x91, 0x59], false),
+    (0xba6f, &[0x91, 0x5a], false),
+    (0xba72, &[0x91, 0x61], false),
+    (0xba73, &[0x91, 0x62], false),
+    (0xba75, &[0x91, 0x63], false),
+    (0xba76, &[0x91, 0x64], false),
+    (0xba77, &[0x91, 0x65], false),
+    (0xba79, &[0x91, 0x66], false),
+    (0xba7a, &[0x91, 0x67], false),
+    (0xba7b, &[0x91, 0x68], false),
+    (0xba7c, &[0x91, 0x69], false),
+    (0xba7d, &[0x91, 0x6a], false),
+    (0xba7e, &[0x91, 0x6b], false),
+    (0xba7f, &[0x91, 0x6c], false),
+    (0xba80, &[0x91, 0x6d], false),
+    (0xba81, &[0x91, 0x6e], false),
+    (0xba82, &[0x91, 0x6f], false),
+    (0xba86, &[0x91, 0x70], false),
+    (0xba88, &[0x91, 0x71], false),
+    (0xba89, &[0x91, 0x72], false),
+    (0xba8a, &[0x91, 0x73], false),
+    (0xba8b, &[0x91, 0x74], false),
+    (0xba8d, &[0x91, 0x75], false),
+    (0xba8e, &[0x91, 0x76], false),
+    (0xba8f, &[0x91, 0x77], false),
+    (0xba90, &[0x91, 0x78], false),
+    (0xba91, &[0x91, 0x79], false),
+    (0xba92, &[0x91, 0x7a], false),
+    (0xba93, &[0x91, 0x81], false),
+    (0xba94, &[0x91, 0x82], false),
+    (0xba95, &[0x91, 0x83], false),
+    (0xba96, &[0x91, 0x84], false),
+    (0xba97, &[0x91, 0x85], false),
+    (0xba98, &[0x91, 0x86], false),
+    (0xba99, &[0x91, 0x87], false),
+    (0xba9a, &[0x91, 0x88], false),
+    (0xba9b, &[0x91, 0x89], false),
+    (0xba9c, &[0x91, 0x8a], false),
+    (0xba9d, &[0x91, 0x8b], false),
+    (0xba9e, &[0x91, 0x8c], false),
+    (0xba9f, &[0x91, 0x8d], false),
+    (0xbaa0, &[0x91, 0x8e], false),
+    (0xbaa1, &[0x91, 0x8f], false),
+    (0xbaa2, &[0x91, 0x90], false),
+    (0xbaa3, &[0x91, 0x91], false),
+    (0xbaa4, &[0x91, 0x92], false),
+    (0xbaa5, &[0x91, 0x93], false),
+    (0xbaa6, &[0x91, 0x94], false),
+    (0xbaa7, &[0x91, 0x95], false),
+    (0xbaaa, &[0x91, 0x96], false),
+    (0xbaad, &[0x91, 0x97], false),
+    (0xbaae, &[0x91, 0x98], false),
+    (0xbaaf, &[0x91, 0x99], false),
+    (0xbab1, &[0x91, 0x9a], false),
+    (0xbab3, &[0x91, 0x9b], false),
+    (0xbab4, &[0x91, 0x9c], false),
+    (0xbab5, &[0x91, 0x9d], false),
+    (0xbab6, &[0x91, 0x9e], false),
+    (0xbab7, &[0x91, 0x9f], false),
+    (0xbaba, &[0x91, 0xa0], false),
+    (0xbabc, &[0x91, 0xa1], false),
+    (0xbabe, &[0x91, 0xa2], false),
+    (0xbabf, &[0x91, 0xa3], false),
+    (0xbac0, &[0x91, 0xa4], false),
+    (0xbac1, &[0x91, 0xa5], false),
+    (0xbac2, &[0x91, 0xa6], false),
+    (0xbac3, &[0x91, 0xa7], false),
+    (0xbac5, &[0x91, 0xa8], false),
+    (0xbac6, &[0x91, 0xa9], false),
+    (0xbac7, &[0x91, 0xaa], false),
+    (0xbac9, &[0x91, 0xab], false),
+    (0xbaca, &[0x91, 0xac], false),
+    (0xbacb, &[0x91, 0xad], false),
+    (0xbacc, &[0x91, 0xae], false),
+    (0xbacd, &[0x91, 0xaf], false),
+    (0xbace, &[0x91, 0xb0], false),
+    (0xbacf, &[0x91, 0xb1], false),
+    (0xbad0, &[0x91, 0xb2], false),
+    (0xbad1, &[0x91, 0xb3], false),
+    (0xbad2, &[0x91, 0xb4], false),
+    (0xbad3, &[0x91, 0xb5], false),
+    (0xbad4, &[0x91, 0xb6], false),
+    (0xbad5, &[0x91, 0xb7], false),
+    (0xbad6, &[0x91, 0xb8], false),
+    (0xbad7, &[0x91, 0xb9], false),
+    (0xbada, &[0x91, 0xba], false),
+    (0xbadb, &[0x91, 0xbb], false),
+    (0xbadc, &[0x91, 0xbc], false),
+    (0xbadd, &[0x91, 0xbd], false),
+    (0xbade, &[0x91, 0xbe], false),
+    (0xbadf, &[0x91, 0xbf], false),
+    (0xbae0, &[0x91, 0xc0], false),
+    (0xbae1, &[0x91, 0xc1], false),
+    (0xbae2, &[0x91, 0xc2], false),
+    (0xbae3, &[0x91, 0xc3], false),
+    (0xbae4, &[0x91, 0xc4], false),
+    (0xbae5, &[0x91, 0xc5], false),
+    (0xbae6, &[0x91, 0xc6], false),
+    (0xbae7, &[0x91, 0xc7], false),
+    (0xbae8, &[0x91, 0xc8], false),
+    (0xbae9, &[0x91, 0xc9], false),
+    (0xbaea, &[0x91, 0xca], false),
+    (0xbaeb, &[0x91, 0xcb], false),
+    (0xbaec, &[0x91, 0xcc], false),
+    (0xbaed, &[0x91, 0xcd], false),
+    (0xbaee, &[0x91, 0xce], false),
+    (0xbaef, &[0x91, 0xcf], false),
+    (0xbaf0, &[0x91, 0xd0], false),
+    (0xbaf1, &[0x91, 0xd1], false),
+    (0xbaf2, &[0x91, 0xd2], false),
+    (0xbaf3, &[0x91, 0xd3], false),
+    (0xbaf4, &[0x91, 0xd4], false),
+    (0xbaf5, &[0x91, 0xd5], false),
+    (0xbaf6, &[0x91, 0xd6], false),
+    (0xbaf7, &[0x91, 0xd7], false),
+    (0xbaf8, &[0x91, 0xd8], false),
+    (0xbaf9, &[0x91, 0xd9], false),
+    (0xbafa, &[0x91, 0xda], false),
+    (0xbafb, &[0x91, 0xdb], false),
+    (0xbafd, &[0x91, 0xdc], false),
+    (0xbafe, &[0x91, 0xdd], false),
+    (0xbaff, &[0x91, 0xde], false),
+    (0xbb01, &[0x91, 0xdf], false),
+    (0xbb02, &[0x91, 0xe0], false),
+    (0xbb03, &[0x91, 0xe1], false),
+    (0xbb05, &[0x91, 0xe2], false),
+    (0xbb06, &[0x91, 0xe3], false),
+    (0xbb07, &[0x91, 0xe4], false),
+    (0xbb08, &[0x91, 0xe5], false),
+    (0xbb09, &[0x91, 0xe6], false),
+    (0xbb0a, &[0x91, 0xe7], false),
+    (0xbb0b, &[0x91, 0xe8], false),
+    (0xbb0c, &[0x91, 0xe9], false),
+    (0xbb0e, &[0x91, 0xea], false),
+    (0xbb10, &[0x91, 0xeb], false),
+    (0xbb12, &[0x91, 0xec], false),
+    (0xbb13, &[0x91, 0xed], false),
+    (0xbb14, &[0x91, 0xee], false),
+    (0xbb15, &[0x91, 0xef], false),
+    (0xbb16, &[0x91, 0xf0], false),
+    (0xbb17, &[0x91, 0xf1], false),
+    (0xbb19, &[0x91, 0xf2], false),
+    (0xbb1a, &[0x91, 0xf3], false),
+    (0xbb1b, &[0x91, 0xf4], false),
+    (0xbb1d, &[0x91, 0xf5], false),
+    (0xbb1e, &[0x91, 0xf6], false),
+    (0xbb1f, &[0x91, 0xf7], false),
+    (0xbb21, &[0x91, 0xf8], false),
+    (0xbb22, &[0x91, 0xf9], false),
+    (0xbb23, &[0x91, 0xfa], false),
+    (0xbb24, &[0x91, 0xfb], false),
+    (0xbb25, &[0x91, 0xfc], false),
+    (0xbb26, &[0x91, 0xfd], false),
+    (0xbb27, &[0x91, 0xfe], false),
+    (0xbb28, &[0x92, 0x41], false),
+    (0xbb2a, &[0x92, 0x42], false),
+    (0xbb2c, &[0x92, 0x43], false),
+    (0xbb2d, &[0x92, 0x44], false),
+    (0xbb2e, &[0x92, 0x45], false),
+    (0xbb2f, &[0x92, 0x46], false),
+    (0xbb30, &[0x92, 0x47], false),
+    (0xbb31, &[0x92, 0x48], false),
+    (0xbb32, &[0x92, 0x49], false),
+    (0xbb33, &[0x92, 0x4a], false),
+    (0xbb37, &[0x92, 0x4b], false),
+    (0xbb39, &[0x92, 0x4c], false),
+    (0xbb3a, &[0x92, 0x4d], false),
+    (0xbb3f, &[0x92, 0x4e], false),
+    (0xbb40, &[0x92, 0x4f], false),
+    (0xbb41, &[0x92, 0x50], false),
+    (0xbb42, &[0x92, 0x51], false),
+    (0xbb43, &[0x92, 0x52], false),
+    (0xbb46, &[0x92, 0x53], false),
+    (0xbb48, &[0x92, 0x54], false),
+    (0xbb4a, &[0x92, 0x55], false),
+    (0xbb4b, &[0x92, 0x56], false),
+    (0xbb4c, &[0x92, 0x57], false),
+    (0xbb4e, &[0x92, 0x58], false),
+    (0xbb51, &[0x92, 0x59], false),
+    (0xbb52, &[0x92, 0x5a], false),
+    (0xbb53, &[0x92, 0x61], false),
+    (0xbb55, &[0x92, 0x62], false),
+    (0xbb56, &[0x92, 0x63], false),
+    (0xbb57, &[0x92, 0x64], false),
+    (0xbb59, &[0x92, 0x65], false),
+    (0xbb5a, &[0x92, 0x66], false),
+    (0xbb5b, &[0x92, 0x67], false),
+    (0xbb5c, &[0x92, 0x68], false),
+    (0xbb5d, &[0x92, 0x69], false),
+    (0xbb5e, &[0x92, 0x6a], false),
+    (0xbb5f, &[0x92, 0x6b], false),
+    (0xbb60, &[0x92, 0x6c], false),
+    (0xbb62, &[0x92, 0x6d], false),
+    (0xbb64, &[0x92, 0x6e], false),
+    (0xbb65, &[0x92, 0x6f], false),
+    (0xbb66, &[0x92, 0x70], false),
+    (0xbb67, &[0x92, 0x71], false),
+    (0xbb68, &[0x92, 0x72], false),
+    (0xbb69, &[0x92, 0x73], false),
+    (0xbb6a, &[0x92, 0x74], false),
+    (0xbb6b, &[0x92, 0x75], false),
+    (0xbb6d, &[0x92, 0x76], false),
+    (0xbb6e, &[0x92, 0x77], false),
+    (0xbb6f, &[0x92, 0x78], false),
+    (0xbb70, &[0x92, 0x79], false),
+    (0xbb71, &[0x92, 0x7a], false),
+    (0xbb72, &[0x92, 0x81], false),
+    (0xbb73, &[0x92, 0x82], false),
+    (0xbb74, &[0x92, 0x83], false),
+    (0xbb75, &[0x92, 0x84], false),
+    (0xbb76, &[0x92, 0x85], false),
+    (0xbb77, &[0x92, 0x86], false),
+    (0xbb78, &[0x92, 0x87], false),
+    (0xbb79, &[0x92, 0x88], false),
+    (0xbb7a, &[0x92, 0x89], false),
+    (0xbb7b, &[0x92, 0x8a], false),
+    (0xbb7c, &[0x92, 0x8b], false),
+    (0xbb7d, &[0x92, 0x8c], false),
+    (0xbb7e, &[0x92, 0x8d], false),
+    (0xbb7f, &[0x92, 0x8e], false),
+    (0xbb80, &[0x92, 0x8f], false),
+    (0xbb81, &[0x92, 0x90], false),
+    (0xbb82, &[0x92, 0x91], false),
+    (0xbb83, &[0x92, 0x92], false),
+    (0xbb84, &[0x92, 0x93], false),
+    (0xbb85, &[0x92, 0x94], false),
+    (0xbb86, &[0x92, 0x95], false),
+    (0xbb87, &[0x92, 0x96], false),
+    (0xbb89, &[0x92, 0x97], false),
+    (0xbb8a, &[0x92, 0x98], false),
+    (0xbb8b, &[0x92, 0x99], false),
+    (0xbb8d, &[0x92, 0x9a], false),
+    (0xbb8e, &[0x92, 0x9b], false),
+    (0xbb8f, &[0x92, 0x9c], false),
+    (0xbb91, &[0x92, 0x9d], false),
+    (0xbb92, &[0x92, 0x9e], false),
+    (0xbb93, &[0x92, 0x9f], false),
+    (0xbb94, &[0x92, 0xa0], false),
+    (0xbb95, &[0x92, 0xa1], false),
+    (0xbb96, &[0x92, 0xa2], false),
+    (0xbb97, &[0x92, 0xa3], false),
+    (0xbb98, &[0x92, 0xa4], false),
+    (0xbb99, &[0x92, 0xa5], false),
+    (0xbb9a, &[0x92, 0xa6], false),
+    (0xbb9b, &[0x92, 0xa7], false),
+    (0xbb9c, &[0x92, 0xa8], false),
+    (0xbb9d, &[0x92, 0xa9], false),
+    (0xbb9e, &[0x92, 0xaa], false),
+    (0xbb9f, &[0x92, 0xab], false),
+    (0xbba0, &[0x92, 0xac], false),
+    (0xbba1, &[0x92, 0xad], false),
+    (0xbba2, &[0x92, 0xae], false),
+    (0xbba3, &[0x92, 0xaf], false),
+    (0xbba5, &[0x92, 0xb0], false),
+    (0xbba6, &[0x92, 0xb1], false),
+    (0xbba7, &[0x92, 0xb2], false),
+    (0xbba9, &[0x92, 0xb3], false),
+    (0xbbaa, &[0x92, 0xb4], false),
+    (0xbbab, &[0x92, 0xb5], false),
+    (0xbbad, &[0x92, 0xb6], false),
+    (0xbbae, &[0x92, 0xb7], false),
+    (0xbbaf, &[0x92, 0xb8], false),
+    (0xbbb0, &[0x92, 0xb9], false),
+    (0xbbb1, &[0x92, 0xba], false),
+    (0xbbb2, &[0x92, 0xbb], false),
+    (0xbbb3, &[0x92, 0xbc], false),
+    (0xbbb5, &[0x92, 0xbd], false),
+    (0xbbb6, &[0x92, 0xbe], false),
+    (0xbbb8, &[0x92, 0xbf], false),
+    (0xbbb9, &[0x92, 0xc0], false),
+    (0xbbba, &[0x92, 0xc1], false),
+    (0xbbbb, &[0x92, 0xc2], false),
+    (0xbbbc, &[0x92, 0xc3], false),
+    (0xbbbd, &[0x92, 0xc4], false),
+    (0xbbbe, &[0x92, 0xc5], false),
+    (0xbbbf, &[0x92, 0xc6], false),
+    (0xbbc1, &[0x92, 0xc7], false),
+    (0xbbc2, &[0x92, 0xc8], false),
+    (0xbbc3, &[0x92, 0xc9], false),
+    (0xbbc5, &[0x92, 0xca], false),
+    (0xbbc6, &[0x92, 0xcb], false),
+    (0xbbc7, &[0x92, 0xcc], false),
+    (0xbbc9, &[0x92, 0xcd], false),
+    (0xbbca, &[0x92, 0xce], false),
+    (0xbbcb, &[0x92, 0xcf], false),
+    (0xbbcc, &[0x92, 0xd0], false),
+    (0xbbcd, &[0x92, 0xd1], false),
+    (0xbbce, &[0x92, 0xd2], false),
+    (0xbbcf, &[0x92, 0xd3], false),
+    (0xbbd1, &[0x92, 0xd4], false),
+    (0xbbd2, &[0x92, 0xd5], false),
+    (0xbbd4, &[0x92, 0xd6], false),
+    (0xbbd5, &[0x92, 0xd7], false),
+    (0xbbd6, &[0x92, 0xd8], false),
+    (0xbbd7, &[0x92, 0xd9], false),
+    (0xbbd8, &[0x92, 0xda], false),
+    (0xbbd9, &[0x92, 0xdb], false),
+    (0xbbda, &[0x92, 0xdc], false),
+    (0xbbdb, &[0x92, 0xdd], false),
+    (0xbbdc, &[0x92, 0xde], false),
+    (0xbbdd, &[0x92, 0xdf], false),
+    (0xbbde, &[0x92, 0xe0], false),
+    (0xbbdf, &[0x92, 0xe1], false),
+    (0xbbe0, &[0x92, 0xe2], false),
+    (0xbbe1, &[0x92, 0xe3], false),
+    (0xbbe2, &[0x92, 0xe4], false),
+    (0xbbe3, &[0x92, 0xe5], false),
+    (0xbbe4, &[0x92, 0xe6], false),
+    (0xbbe5, &[0x92, 0xe7], false),
+    (0xbbe6, &[0x92, 0xe8], false),
+    (0xbbe7, &[0x92, 0xe9], false),
+    (0xbbe8, &[0x92, 0xea], false),
+    (0xbbe9, &[0x92, 0xeb], false),
+    (0xbbea, &[0x92, 0xec], false),
+    (0xbbeb, &[0x92, 0xed], false),
+    (0xbbec, &[0x92, 0xee], false),
+    (0xbbed, &[0x92, 0xef], false),
+    (0xbbee, &[0x92, 0xf0], false),
+    (0xbbef, &[0x92, 0xf1], false),
+    (0xbbf0, &[0x92, 0xf2], false),
+    (0xbbf1, &[0x92, 0xf3], false),
+    (0xbbf2, &[0x92, 0xf4], false),
+    (0xbbf3, &[0x92, 0xf5], false),
+    (0xbbf4, &[0x92, 0xf6], false),
+    (0xbbf5, &[0x92, 0xf7], false),
+    (0xbbf6, &[0x92, 0xf8], false),
+    (0xbbf7, &[0x92, 0xf9], false),
+    (0xbbfa, &[0x92, 0xfa], false),
+    (0xbbfb, &[0x92, 0xfb], false),
+    (0xbbfd, &[0x92, 0xfc], false),
+    (0xbbfe, &[0x92, 0xfd], false),
+    (0xbc01, &[0x92, 0xfe], false),
+    (0xbc03, &[0x93, 0x41], false),
+    (0xbc04, &[0x93, 0x42], false),
+    (0xbc05, &[0x93, 0x43], false),
+    (0xbc06, &[0x93, 0x44], false),
+    (0xbc07, &[0x93, 0x45], false),
+    (0xbc0a, &[0x93, 0x46], false),
+    (0xbc0e, &[0x93, 0x47], false),
+    (0xbc10, &[0x93, 0x48], false),
+    (0xbc12, &[0x93, 0x49], false),
+    (0xbc13, &[0x93, 0x4a], false),
+    (0xbc19, &[0x93, 0x4b], false),
+    (0xbc1a, &[0x93, 0x4c], false),
+    (0xbc20, &[0x93, 0x4d], false),
+    (0xbc21, &[0x93, 0x4e], false),
+    (0xbc22, &[0x93, 0x4f], false),
+    (0xbc23, &[0x93, 0x50], false),
+    (0xbc26, &[0x93, 0x51], false),
+    (0xbc28, &[0x93, 0x52], false),
+    (0xbc2a, &[0x93, 0x53], false),
+    (0xbc2b, &[0x93, 0x54], false),
+    (0xbc2c, &[0x93, 0x55], false),
+    (0xbc2e, &[0x93, 0x56], false),
+    (0xbc2f, &[0x93, 0x57], false),
+    (0xbc32, &[0x93, 0x58], false),
+    (0xbc33, &[0x93, 0x59], false),
+    (0xbc35, &[0x93, 0x5a], false),
+    (0xbc36, &[0x93, 0x61], false),
+    (0xbc37, &[0x93, 0x62], false),
+    (0xbc39, &[0x93, 0x63], false),
+    (0xbc3a, &[0x93, 0x64], false),
+    (0xbc3b, &[0x93, 0x65], false),
+    (0xbc3c, &[0x93, 0x66], false),
+    (0xbc3d, &[0x93, 0x67], false),
+    (0xbc3e, &[0x93, 0x68], false),
+    (0xbc3f, &[0x93, 0x69], false),
+    (0xbc42, &[0x93, 0x6a], false),
+    (0xbc46, &[0x93, 0x6b], false),
+    (0xbc47, &[0x93, 0x6c], false),
+    (0xbc48, &[0x93, 0x6d], false),
+    (0xbc4a, &[0x93, 0x6e], false),
+    (0xbc4b, &[0x93, 0x6f], false),
+    (0xbc4e, &[0x93, 0x70], false),
+    (0xbc4f, &[0x93, 0x71], false),
+    (0xbc51, &[0x93, 0x72], false),
+    (0xbc52, &[0x93, 0x73], false),
+    (0xbc53, &[0x93, 0x74], false),
+    (0xbc54, &[0x93, 0x75], false),
+    (0xbc55, &[0x93, 0x76], false),
+    (0xbc56, &[0x93, 0x77], false),
+    (0xbc57, &[0x93, 0x78], false),
+    (0xbc58, &[0x93, 0x79], false),
+    (0xbc59, &[0x93, 0x7a], false),
+    (0xbc5a, &[0x93, 0x81], false),
+    (0xbc5b, &[0x93, 0x82], false),
+    (0xbc5c, &[0x93, 0x83], false),
+    (0xbc5e, &[0x93, 0x84], false),
+    (0xbc5f, &[0x93, 0x85], false),
+    (0xbc60, &[0x93, 0x86], false),
+    (0xbc61, &[0x93, 0x87], false),
+    (0xbc62, &[0x93, 0x88], false),
+    (0xbc63, &[0x93, 0x89], false),
+    (0xbc64, &[0x93, 0x8a], false),
+    (0xbc65, &[0x93, 0x8b], false),
+    (0xbc66, &[0x93, 0x8c], false),
+    (0xbc67, &[0x93, 0x8d], false),
+    (0xbc68, &[0x93, 0x8e], false),
+    (0xbc69, &[0x93, 0x8f], false),
+    (0xbc6a, &[0x93, 0x90], false),
+    (0xbc6b, &[0x93, 0x91], false),
+    (0xbc6c, &[0x93, 0x92], false),
+    (0xbc6d, &[0x93, 0x93], false),
+    (0xbc6e, &[0x93, 0x94], false),
+    (0xbc6f, &[0x93, 0x95], false),
+    (0xbc70, &[0x93, 0x96], false),
+    (0xbc71, &[0x93, 0x97], false),
+    (0xbc72, &[0x93, 0x98], false),
+    (0xbc73, &[0x93, 0x99], false),
+    (0xbc74, &[0x93, 0x9a], false),
+    (0xbc75, &[0x93, 0x9b], false),
+    (0xbc76, &[0x93, 0x9c], false),
+    (0xbc77, &[0x93, 0x9d], false),
+    (0xbc78, &[0x93, 0x9e], false),
+    (0xbc79, &[0x93, 0x9f], false),
+    (0xbc7a, &[0x93, 0xa0], false),
+    (0xbc7b, &[0x93, 0xa1], false),
+    (0xbc7c, &[0x93, 0xa2], false),
+    (0xbc7d, &[0x93, 0xa3], false),
+    (0xbc7e, &[0x93, 0xa4], false),
+    (0xbc7f, &[0x93, 0xa5], false),
+    (0xbc80, &[0x93, 0xa6], false),
+    (0xbc81, &[0x93, 0xa7], false),
+    (0xbc82, &[0x93, 0xa8], false),
+    (0xbc83, &[0x93, 0xa9], false),
+    (0xbc86, &[0x93, 0xaa], false),
+    (0xbc87, &[0x93, 0xab], false),
+    (0xbc89, &[0x93, 0xac], false),
+    (0xbc8a, &[0x93, 0xad], false),
+    (0xbc8d, &[0x93, 0xae], false),
+    (0xbc8f, &[0x93, 0xaf], false),
+    (0xbc90, &[0x93, 0xb0], false),
+    (0xbc91, &[0x93, 0xb1], false),
+    (0xbc92, &[0x93, 0xb2], false),
+    (0xbc93, &[0x93, 0xb3], false),
+    (0xbc96, &[0x93, 0xb4], false),
+    (0xbc98, &[0x93, 0xb5], false),
+    (0xbc9b, &[0x93, 0xb6], false),
+    (0xbc9c, &[0x93, 0xb7], false),
+    (0xbc9d, &[0x93, 0xb8], false),
+    (0xbc9e, &[0x93, 0xb9], false),
+    (0xbc9f, &[0x93, 0xba], false),
+    (0xbca2, &[0x93, 0xbb], false),
+    (0xbca3, &[0x93, 0xbc], false),
+    (0xbca5, &[0x93, 0xbd], false),
+    (0xbca6, &[0x93, 0xbe], false),
+    (0xbca9, &[0x93, 0xbf], false),
+    (0xbcaa, &[0x93, 0xc0], false),
+    (0xbcab, &[0x93, 0xc1], false),
+    (0xbcac, &[0x93, 0xc2], false),
+    (0xbcad, &[0x93, 0xc3], false),
+    (0xbcae, &[0x93, 0xc4], false),
+    (0xbcaf, &[0x93, 0xc5], false),
+    (0xbcb2, &[0x93, 0xc6], false),
+    (0xbcb6, &[0x93, 0xc7], false),
+    (0xbcb7, &[0x93, 0xc8], false),
+    (0xbcb8, &[0x93, 0xc9], false),
+    (0xbcb9, &[0x93, 0xca], false),
+    (0xbcba, &[0x93, 0xcb], false),
+    (0xbcbb, &[0x93, 0xcc], false),
+    (0xbcbe, &[0x93, 0xcd], false),
+    (0xbcbf, &[0x93, 0xce], false),
+    (0xbcc1, &[0x93, 0xcf], false),
+    (0xbcc2, &[0x93, 0xd0], false),
+    (0xbcc3, &[0x93, 0xd1], false),
+    (0xbcc5, &[0x93, 0xd2], false),
+    (0xbcc6, &[0x93, 0xd3], false),
+    (0xbcc7, &[0x93, 0xd4], false),
+    (0xbcc8, &[0x93, 0xd5], false),
+    (0xbcc9, &[0x93, 0xd6], false),
+    (0xbcca, &[0x93, 0xd7], false),
+    (0xbccb, &[0x93, 0xd8], false),
+    (0xbccc, &[0x93, 0xd9], false),
+    (0xbcce, &[0x93, 0xda], false),
+    (0xbcd2, &[0x93, 0xdb], false),
+    (0xbcd3, &[0x93, 0xdc], false),
+    (0xbcd4, &[0x93, 0xdd], false),
+    (0xbcd6, &[0x93, 0xde], false),
+    (0xbcd7, &[0x93, 0xdf], false),
+    (0xbcd9, &[0x93, 0xe0], false),
+    (0xbcda, &[0x93, 0xe1], false),
+    (0xbcdb, &[0x93, 0xe2], false),
+    (0xbcdd, &[0x93, 0xe3], false),
+    (0xbcde, &[0x93, 0xe4], false),
+    (0xbcdf, &[0x93, 0xe5], false),
+    (0xbce0, &[0x93, 0xe6], false),
+    (0xbce1, &[0x93, 0xe7], false),
+    (0xbce2, &[0x93, 0xe8], false),
+    (0xbce3, &[0x93, 0xe9], false),
+    (0xbce4, &[0x93, 0xea], false),
+    (0xbce5, &[0x93, 0xeb], false),
+    (0xbce6, &[0x93, 0xec], false),
+    (0xbce7, &[0x93, 0xed], false),
+    (0xbce8, &[0x93, 0xee], false),
+    (0xbce9, &[0x93, 0xef], false),
+    (0xbcea, &[0x93, 0xf0], false),
+    (0xbceb, &[0x93, 0xf1], false),
+    (0xbcec, &[0x93, 0xf2], false),
+    (0xbced, &[0x93, 0xf3], false),
+    (0xbcee, &[0x93, 0xf4], false),
+    (0xbcef, &[0x93, 0xf5], false),
+    (0xbcf0, &[0x93, 0xf6], false),
+    (0xbcf1, &[0x93, 0xf7], false),
+    (0xbcf2, &[0x93, 0xf8], false),
+    (0xbcf3, &[0x93, 0xf9], false),
+    (0xbcf7, &[0x93, 0xfa], false),
+    (0xbcf9, &[0x93, 0xfb], false),
+    (0xbcfa, &[0x93, 0xfc], false),
+    (0xbcfb, &[0x93, 0xfd], false),
+    (0xbcfd, &[0x93, 0xfe], false),
+    (0xbcfe, &[0x94, 0x41], false),
+    (0xbcff, &[0x94, 0x42], false),
+    (0xbd00, &[0x94, 0x43], false),
+    (0xbd01, &[0x94, 0x44], false),
+    (0xbd02, &[0x94, 0x45], false),
+    (0xbd03, &[0x94, 0x46], false),
+    (0xbd06, &[0x94, 0x47], false),
+    (0xbd08, &[0x94, 0x48], false),
+    (0xbd0a, &[0x94, 0x49], false),
+    (0xbd0b, &[0x94, 0x4a], false),
+    (0xbd0c, &[0x94, 0x4b], false),
+    (0xbd0d, &[0x94, 0x4c], false),
+    (0xbd0e, &[0x94, 0x4d], false),
+    (0xbd0f, &[0x94, 0x4e], false),
+    (0xbd11, &[0x94, 0x4f], false),
+    (0xbd12, &[0x94, 0x50], false),
+    (0xbd13, &[0x94, 0x51], false),
+    (0xbd15, &[0x94, 0x52], false),
+    (0xbd16, &[0x94, 0x53], false),
+    (0xbd17, &[0x94, 0x54], false),
+    (0xbd18, &[0x94, 0x55], false),
+    (0xbd19, &[0x94, 0x56], false),
+    (0xbd1a, &[0x94, 0x57], false),
+    (0xbd1b, &[0x94, 0x58], false),
+    (0xbd1c, &[0x94, 0x59], false),
+    (0xbd1d, &[0x94, 0x5a], false),
+    (0xbd1e, &[0x94, 0x61], false),
+    (0xbd1f, &[0x94, 0x62], false),
+    (0xbd20, &[0x94, 0x63], false),
+    (0xbd21, &[0x94, 0x64], false),
+    (0xbd22, &[0x94, 0x65], false),
+    (0xbd23, &[0x94, 0x66], false),
+    (0xbd25, &[0x94, 0x67], false),
+    (0xbd26, &[0x94, 0x68], false),
+    (0xbd27, &[0x94, 0x69], false),
+    (0xbd28, &[0x94, 0x6a], false),
+    (0xbd29, &[0x94, 0x6b], false),
+    (0xbd2a, &[0x94, 0x6c], false),
+    (0xbd2b, &[0x94, 0x6d], false),
+    (0xbd2d, &[0x94, 0x6e], false),
+    (0xbd2e, &[0x94, 0x6f], false),
+    (0xbd2f, &[0x94, 0x70], false),
+    (0xbd30, &[0x94, 0x71], false),
+    (0xbd31, &[0x94, 0x72], false),
+    (0xbd32, &[0x94, 0x73], false),
+    (0xbd33, &[0x94, 0x74], false),
+    (0xbd34, &[0x94, 0x75], false),
+    (0xbd35, &[0x94, 0x76], false),
+    (0xbd36, &[0x94, 0x77], false),
+    (0xbd37, &[0x94, 0x78], false),
+    (0xbd38, &[0x94, 0x79], false),
+    (0xbd39, &[0x94, 0x7a], false),
+    (0xbd3a, &[0x94, 0x81], false),
+    (0xbd3b, &[0x94, 0x82], false),
+    (0xbd3c, &[0x94, 0x83], false),
+    (0xbd3d, &[0x94, 0x84], false),
+    (0xbd3e, &[0x94, 0x85], false),
+    (0xbd3f, &[0x94, 0x86], false),
+    (0xbd41, &[0x94, 0x87], false),
+    (0xbd42, &[0x94, 0x88], false),
+    (0xbd43, &[0x94, 0x89], false),
+    (0xbd44, &[0x94, 0x8a], false),
+    (0xbd45, &[0x94, 0x8b], false),
+    (0xbd46, &[0x94, 0x8c], false),
+    (0xbd47, &[0x94, 0x8d], false),
+    (0xbd4a, &[0x94, 0x8e], false),
+    (0xbd4b, &[0x94, 0x8f], false),
+    (0xbd4d, &[0x94, 0x90], false),
+    (0xbd4e, &[0x94, 0x91], false),
+    (0xbd4f, &[0x94, 0x92], false),
+    (0xbd51, &[0x94, 0x93], false),
+    (0xbd52, &[0x94, 0x94], false),
+    (0xbd53, &[0x94, 0x95], false),
+    (0xbd54, &[0x94, 0x96], false),
+    (0xbd55, &[0x94, 0x97], false),
+    (0xbd56, &[0x94, 0x98], false),
+    (0xbd57, &[0x94, 0x99], false),
+    (0xbd5a, &[0x94, 0x9a], false),
+    (0xbd5b, &[0x94, 0x9b], false),
+    (0xbd5c, &[0x94, 0x9c], false),
+    (0xbd5d, &[0x94, 0x9d], false),
+    (0xbd5e, &[0x94, 0x9e], false),
+    (0xbd5f, &[0x94, 0x9f], false),
+    (0xbd60, &[0x94, 0xa0], false),
+    (0xbd61, &[0x94, 0xa1], false),
+    (0xbd62, &[0x94, 0xa2], false),
+    (0xbd63, &[0x94, 0xa3], false),
+    (0xbd65, &[0x94, 0xa4], false),
+    (0xbd66, &[0x94, 0xa5], false),
+    (0xbd67, &[0x94, 0xa6], false),
+    (0xbd69, &[0x94, 0xa7], false),
+    (0xbd6a, &[0x94, 0xa8], false),
+    (0xbd6b, &[0x94, 0xa9], false),
+    (0xbd6c, &[0x94, 0xaa], false),
+    (0xbd6d, &[0x94, 0xab], false),
+    (0xbd6e, &[0x94, 0xac], false),
+    (0xbd6f, &[0x94, 0xad], false),
+    (0xbd70, &[0x94, 0xae], false),
+    (0xbd71, &[0x94, 0xaf], false),
+    (0xbd72, &[0x94, 0xb0], false),
+    (0xbd73, &[0x94, 0xb1], false),
+    (0xbd74, &[0x94, 0xb2], false),
+    (0xbd75, &[0x94, 0xb3], false),
+    (0xbd76, &[0x94, 0xb4], false),
+    (0xbd77, &[0x94, 0xb5], false),
+    (0xbd78, &[0x94, 0xb6], false),
+    (0xbd79, &[0x94, 0xb7], false),
+    (0xbd7a, &[0x94, 0xb8], false),
+    (0xbd7b, &[0x94, 0xb9], false),
+    (0xbd7c, &[0x94, 0xba], false),
+    (0xbd7d, &[0x94, 0xbb], false),
+    (0xbd7e, &[0x94, 0xbc], false),
+    (0xbd7f, &[0x94, 0xbd], false),
+    (0xbd82, &[0x94, 0xbe], false),
+    (0xbd83, &[0x94, 0xbf], false),
+    (0xbd85, &[0x94, 0xc0], false),
+    (0xbd86, &[0x94, 0xc1], false),
+    (0xbd8b, &[0x94, 0xc2], false),
+    (0xbd8c, &[0x94, 0xc3], false),
+    (0xbd8d, &[0x94, 0xc4], false),
+    (0xbd8e, &[0x94, 0xc5], false),
+    (0xbd8f, &[0x94, 0xc6], false),
+    (0xbd92, &[0x94, 0xc7], false),
+    (0xbd94, &[0x94, 0xc8], false),
+    (0xbd96, &[0x94, 0xc9], false),
+    (0xbd97, &[0x94, 0xca], false),
+    (0xbd98, &[0x94, 0xcb], false),
+    (0xbd9b, &[0x94, 0xcc], false),
+    (0xbd9d, &[0x94, 0xcd], false),
+    (0xbd9e, &[0x94, 0xce], false),
+    (0xbd9f, &[0x94, 0xcf], false),
+    (0xbda0, &[0x94, 0xd0], false),
+    (0xbda1, &[0x94, 0xd1], false),
+    (0xbda2, &[0x94, 0xd2], false),
+    (0xbda3, &[0x94, 0xd3], false),
+    (0xbda5, &[0x94, 0xd4], false),
+    (0xbda6, &[0x94, 0xd5], false),
+    (0xbda7, &[0x94, 0xd6], false),
+    (0xbda8, &[0x94, 0xd7], false),
+    (0xbda9, &[0x94, 0xd8], false),
+    (0xbdaa, &[0x94, 0xd9], false),
+    (0xbdab, &[0x94, 0xda], false),
+    (0xbdac, &[0x94, 0xdb], false),
+    (0xbdad, &[0x94, 0xdc], false),
+    (0xbdae, &[0x94, 0xdd], false),
+    (0xbdaf, &[0x94, 0xde], false),
+    (0xbdb1, &[0x94, 0xdf], false),
+    (0xbdb2, &[0x94, 0xe0], false),
+    (0xbdb3, &[0x94, 0xe1], false),
+    (0xbdb4, &[0x94, 0xe2], false),
+    (0xbdb5, &[0x94, 0xe3], false),
+    (0xbdb6, &[0x94, 0xe4], false),
+    (0xbdb7, &[0x94, 0xe5], false),
+    (0xbdb9, &[0x94, 0xe6], false),
+    (0xbdba, &[0x94, 0xe7], false),
+    (0xbdbb, &[0x94, 0xe8], false),
+    (0xbdbc, &[0x94, 0xe9], false),
+    (0xbdbd, &[0x94, 0xea], false),
+    (0xbdbe, &[0x94, 0xeb], false),
+    (0xbdbf, &[0x94, 0xec], false),
+    (0xbdc0, &[0x94, 0xed], false),
+    (0xbdc1, &[0x94, 0xee], false),
+    (0xbdc2, &[0x94, 0xef], false),
+    (0xbdc3, &[0x94, 0xf0], false),
+    (0xbdc4, &[0x94, 0xf1], false),
+    (0xbdc5, &[0x94, 0xf2], false),
+    (0xbdc6, &[0x94, 0xf3], false),
+    (0xbdc7, &[0x94, 0xf4], false),
+    (0xbdc8, &[0x94, 0xf5], false),
+    (0xbdc9, &[0x94, 0xf6], false),
+    (0xbdca, &[0x94, 0xf7], false),
+    (0xbdcb, &[0x94, 0xf8], false),
+    (0xbdcc, &[0x94, 0xf9], false),
+    (0xbdcd, &[0x94, 0xfa], false),
+    (0xbdce, &[0x94, 0xfb], false),
+    (0xbdcf, &[0x94, 0xfc], false),
+    (0xbdd0, &[0x94, 0xfd], false),
+    (0xbdd1, &[0x94, 0xfe], false),
+    (0xbdd2, &[0x95, 0x41], false),
+    (0xbdd3, &[0x95, 0x42], false),
+    (0xbdd6, &[0x95, 0x43], false),
+    (0xbdd7, &[0x95, 0x44], false),
+    (0xbdd9, &[0x95, 0x45], false),
+    (0xbdda, &[0x95, 0x46], false),
+    (0xbddb, &[0x95, 0x47], false),
+    (0xbddd, &[0x95, 0x48], false),
+    (0xbdde, &[0x95, 0x49], false),
+    (0xbddf, &[0x95, 0x4a], false),
+    (0xbde0, &[0x95, 0x4b], false),
+    (0xbde1, &[0x95, 0x4c], false),
+    (0xbde2, &[0x95, 0x4d], false),
+    (0xbde3, &[0x95, 0x4e], false),
+    (0xbde4, &[0x95, 0x4f], false),
+    (0xbde5, &[0x95, 0x50], false),
+    (0xbde6, &[0x95, 0x51], false),
+    (0xbde7, &[0x95, 0x52], false),
+    (0xbde8, &[0x95, 0x53], false),
+    (0xbdea, &[0x95, 0x54], false),
+    (0xbdeb, &[0x95, 0x55], false),
+    (0xbdec, &[0x95, 0x56], false),
+    (0xbded, &[0x95, 0x57], false),
+    (0xbdee, &[0x95, 0x58], false),
+    (0xbdef, &[0x95, 0x59], false),
+    (0xbdf1, &[0x95, 0x5a], false),
+    (0xbdf2, &[0x95, 0x61], false),
+    (0xbdf3, &[0x95, 0x62], false),
+    (0xbdf5, &[0x95, 0x63], false),
+    (0xbdf6, &[0x95, 0x64], false),
+    (0xbdf7, &[0x95, 0x65], false),
+    (0xbdf9, &[0x95, 0x66], false),
+    (0xbdfa, &[0x95, 0x67], false),
+    (0xbdfb, &[0x95, 0x68], false),
+    (0xbdfc, &[0x95, 0x69], false),
+    (0xbdfd, &[0x95, 0x6a], false),
+    (0xbdfe, &[0x95, 0x6b], false),
+    (0xbdff, &[0x95, 0x6c], false),
+    (0xbe01, &[0x95, 0x6d], false),
+    (0xbe02, &[0x95, 0x6e], false),
+    (0xbe04, &[0x95, 0x6f], false),
+    (0xbe06, &[0x95, 0x70], false),
+    (0xbe07, &[0x95, 0x71], false),
+    (0xbe08, &[0x95, 0x72], false),
+    (0xbe09, &[0x95, 0x73], false),
+    (0xbe0a, &[0x95, 0x74], false),
+    (0xbe0b, &[0x95, 0x75], false),
+    (0xbe0e, &[0x95, 0x76], false),
+    (0xbe0f, &[0x95, 0x77], false),
+    (0xbe11, &[0x95, 0x78], false),
+    (0xbe12, &[0x95, 0x79], false),
+    (0xbe13, &[0x95, 0x7a], false),
+    (0xbe15, &[0x95, 0x81], false),
+    (0xbe16, &[0x95, 0x82], false),
+    (0xbe17, &[0x95, 0x83], false),
+    (0xbe18, &[0x95, 0x84], false),
+    (0xbe19, &[0x95, 0x85], false),
+    (0xbe1a, &[0x95, 0x86], false),
+    (0xbe1b, &[0x95, 0x87], false),
+    (0xbe1e, &[0x95, 0x88], false),
+    (0xbe20, &[0x95, 0x89], false),
+    (0xbe21, &[0x95, 0x8a], false),
+    (0xbe22, &[0x95, 0x8b], false),
+    (0xbe23, &[0x95, 0x8c], false),
+    (0xbe24, &[0x95, 0x8d], false),
+    (0xbe25, &[0x95, 0x8e], false),
+    (0xbe26, &[0x95, 0x8f], false),
+    (0xbe27, &[0x95, 0x90], false),
+    (0xbe28, &[0x95, 0x91], false),
+    (0xbe29, &[0x95, 0x92], false),
+    (0xbe2a, &[0x95, 0x93], false),
+    (0xbe2b, &[0x95, 0x94], false),
+    (0xbe2c, &[0x95, 0x95], false),
+    (0xbe2d, &[0x95, 0x96], false),
+    (0xbe2e, &[0x95, 0x97], false),
+    (0xbe2f, &[0x95, 0x98], false),
+    (0xbe30, &[0x95, 0x99], false),
+    (0xbe31, &[0x95, 0x9a], false),
+    (0xbe32, &[0x95, 0x9b], false),
+    (0xbe33, &[0x95, 0x9c], false),
+    (0xbe34, &[0x95, 0x9d], false),
+    (0xbe35, &[0x95, 0x9e], false),
+    (0xbe36, &[0x95, 0x9f], false),
+    (0xbe37, &[0x95, 0xa0], false),
+    (0xbe38, &[0x95, 0xa1], false),
+    (0xbe39, &[0x95, 0xa2], false),
+    (0xbe3a, &[0x95, 0xa3], false),
+    (0xbe3b, &[0x95, 0xa4], false),
+    (0xbe3c, &[0x95, 0xa5], false),
+    (0xbe3d, &[0x95, 0xa6], false),
+    (0xbe3e, &[0x95, 0xa7], false),
+    (0xbe3f, &[0x95, 0xa8], false),
+    (0xbe40, &[0x95, 0xa9], false),
+    (0xbe41, &[0x95, 0xaa], false),
+    (0xbe42, &[0x95, 0xab], false),
+    (0xbe43, &[0x95, 0xac], false),
+    (0xbe46, &[0x95, 0xad], false),
+    (0xbe47, &[0x95, 0xae], false),
+    (0xbe49, &[0x95, 0xaf], false),
+    (0xbe4a, &[0x95, 0xb0], false),
+    (0xbe4b, &[0x95, 0xb1], false),
+    (0xbe4d, &[0x95, 0xb2], false),
+    (0xbe4f, &[0x95, 0xb3], false),
+    (0xbe50, &[0x95, 0xb4], false),
+    (0xbe51, &[0x95, 0xb5], false),
+    (0xbe52, &[0x95, 0xb6], false),
+    (0xbe53, &[0x95, 0xb7], false),
+    (0xbe56, &[0x95, 0xb8], false),
+    (0xbe58, &[0x95, 0xb9], false),
+    (0xbe5c, &[0x95, 0xba], false),
+    (0xbe5d, &[0x95, 0xbb], false),
+    (0xbe5e, &[0x95, 0xbc], false),
+    (0xbe5f, &[0x95, 0xbd], false),
+    (0xbe62, &[0x95, 0xbe], false),
+    (0xbe63, &[0x95, 0xbf], false),
+    (0xbe65, &[0x95, 0xc0], false),
+    (0xbe66, &[0x95, 0xc1], false),
+    (0xbe67, &[0x95, 0xc2], false),
+    (0xbe69, &[0x95, 0xc3], false),
+    (0xbe6b, &[0x95, 0xc4], false),
+    (0xbe6c, &[0x95, 0xc5], false),
+    (0xbe6d, &[0x95, 0xc6], false),
+    (0xbe6e, &[0x95, 0xc7], false),
+    (0xbe6f, &[0x95, 0xc8], false),
+    (0xbe72, &[0x95, 0xc9], false),
+    (0xbe76, &[0x95, 0xca], false),
+    (0xbe77, &[0x95, 0xcb], false),
+    (0xbe78, &[0x95, 0xcc], false),
+    (0xbe79, &[0x95, 0xcd], false),
+    (0xbe7a, &[0x95, 0xce], false),
+    (0xbe7e, &[0x95, 0xcf], false),
+    (0xbe7f, &[0x95, 0xd0], false),
+    (0xbe81, &[0x95, 0xd1], false),
+    (0xbe82, &[0x95, 0xd2], false),
+    (0xbe83, &[0x95, 0xd3], false),
+    (0xbe85, &[0x95, 0xd4], false),
+    (0xbe86, &[0x95, 0xd5], false),
+    (0xbe87, &[0x95, 0xd6], false),
+    (0xbe88, &[0x95, 0xd7], false),
+    (0xbe89, &[0x95, 0xd8], false),
+    (0xbe8a, &[0x95, 0xd9], false),
+    (0xbe8b, &[0x95, 0xda], false),
+    (0xbe8e, &[0x95, 0xdb], false),
+    (0xbe92, &[0x95, 0xdc], false),
+    (0xbe93, &[0x95, 0xdd], false),
+    (0xbe94, &[0x95, 0xde], false),
+    (0xbe95, &[0x95, 0xdf], false),
+    (0xbe96, &[0x95, 0xe0], false),
+    (0xbe97, &[0x95, 0xe1], false),
+    (0xbe9a, &[0x95, 0xe2], false),
+    (0xbe9b, &[0x95, 0xe3], false),
+    (0xbe9c, &[0x95, 0xe4], false),
+    (0xbe9d, &[0x95, 0xe5], false),
+    (0xbe9e, &[0x95, 0xe6], false),
+    (0xbe9f, &[0x95, 0xe7], false),
+    (0xbea0, &[0x95, 0xe8], false),
+    (0xbea1, &[0x95, 0xe9], false),
+    (0xbea2, &[0x95, 0xea], false),
+    (0xbea3, &[0x95, 0xeb], false),
+    (0xbea4, &[0x95, 0xec], false),
+    (0xbea5, &[0x95, 0xed], false),
+    (0xbea6, &[0x95, 0xee], false),
+    (0xbea7, &[0x95, 0xef], false),
+    (0xbea9, &[0x95, 0xf0], false),
+    (0xbeaa, &[0x95, 0xf1], false),
+    (0xbeab, &[0x95, 0xf2], false),
+    (0xbeac, &[0x95, 0xf3], false),
+    (0xbead, &[0x95, 0xf4], false),
+    (0xbeae, &[0x95, 0xf5], false),
+    (0xbeaf, &[0x95, 0xf6], false),
+    (0xbeb0, &[0x95, 0xf7], false),
+    (0xbeb1, &[0x95, 0xf8], false),
+    (0xbeb2, &[0x95, 0xf9], false),
+    (0xbeb3, &[0x95, 0xfa], false),
+    (0xbeb4, &[0x95, 0xfb], false),
+    (0xbeb5, &[0x95, 0xfc], false),
+    (0xbeb6, &[0x95, 0xfd], false),
+    (0xbeb7, &[0x95, 0xfe], false),
+    (0xbeb8, &[0x96, 0x41], false),
+    (0xbeb9, &[0x96, 0x42], false),
+    (0xbeba, &[0x96, 0x43], false),
+    (0xbebb, &[0x96, 0x44], false),
+    (0xbebc, &[0x96, 0x45], false),
+    (0xbebd, &[0x96, 0x46], false),
+    (0xbebe, &[0x96, 0x47], false),
+    (0xbebf, &[0x96, 0x48], false),
+    (0xbec0, &[0x96, 0x49], false),
+    (0xbec1, &[0x96, 0x4a], false),
+    (0xbec2, &[0x96, 0x4b], false),
+    (0xbec3, &[0x96, 0x4c], false),
+    (0xbec4, &[0x96, 0x4d], false),
+    (0xbec5, &[0x96, 0x4e], false),
+    (0xbec6, &[0x96, 0x4f], false),
+    (0xbec7, &[0x96, 0x50], false),
+    (0xbec8, &[0x96, 0x51], false),
+    (0xbec9, &[0x96, 0x52], false),
+    (0xbeca, &[0x96, 0x53], false),
+    (0xbecb, &[0x96, 0x54], false),
+    (0xbecc, &[0x96, 0x55], false),
+    (0xbecd, &[0x96, 0x56], false),
+    (0xbece, &[0x96, 0x57], false),
+    (0xbecf, &[0x96, 0x58], false),
+    (0xbed2, &[0x96, 0x59], false),
+    (0xbed3, &[0x96, 0x5a], false),
+    (0xbed5, &[0x96, 0x61], false),
+    (0xbed6, &[0x96, 0x62], false),
+    (0xbed9, &[0x96, 0x63], false),
+    (0xbeda, &[0x96, 0x64], false),
+    (0xbedb, &[0x96, 0x65], false),
+    (0xbedc, &[0x96, 0x66], false),
+    (0xbedd, &[0x96, 0x67], false),
+    (0xbede, &[0x96, 0x68], false),
+    (0xbedf, &[0x96, 0x69], false),
+    (0xbee1, &[0x96, 0x6a], false),
+    (0xbee2, &[0x96, 0x6b], false),
+    (0xbee6, &[0x96, 0x6c], false),
+    (0xbee7, &[0x96, 0x6d], false),
+    (0xbee8, &[0x96, 0x6e], false),
+    (0xbee9, &[0x96, 0x6f], false),
+    (0xbeea, &[0x96, 0x70], false),
+    (0xbeeb, &[0x96, 0x71], false),
+    (0xbeed, &[0x96, 0x72], false),
+    (0xbeee, &[0x96, 0x73], false),
+    (0xbeef, &[0x96, 0x74], false),
+    (0xbef0, &[0x96, 0x75], false),
+    (0xbef1, &[0x96, 0x76], false),
+    (0xbef2, &[0x96, 0x77], false),
+    (0xbef3, &[0x96, 0x78], false),
+    (0xbef4, &[0x96, 0x79], false),
+    (0xbef5, &[0x96, 0x7a], false),
+    (0xbef6, &[0x96, 0x81], false),
+    (0xbef7, &[0x96, 0x82], false),
+    (0xbef8, &[0x96, 0x83], false),
+    (0xbef9, &[0x96, 0x84], false),
+    (0xbefa, &[0x96, 0x85], false),
+    (0xbefb, &[0x96, 0x86], false),
+    (0xbefc, &[0x96, 0x87], false),
+    (0xbefd, &[0x96, 0x88], false),
+    (0xbefe, &[0x96, 0x89], false),
+    (0xbeff, &[0x96, 0x8a], false),
+    (0xbf00, &[0x96, 0x8b], false),
+    (0xbf02, &[0x96, 0x8c], false),
+    (0xbf03, &[0x96, 0x8d], false),
+    (0xbf04, &[0x96, 0x8e], false),
+    (0xbf05, &[0x96, 0x8f], false),
+    (0xbf06, &[0x96, 0x90], false),
+    (0xbf07, &[0x96, 0x91], false),
+    (0xbf0a, &[0x96, 0x92], false),
+    (0xbf0b, &[0x96, 0x93], false),
+    (0xbf0c, &[0x96, 0x94], false),
+    (0xbf0d, &[0x96, 0x95], false),
+    (0xbf0e, &[0x96, 0x96], false),
+    (0xbf0f, &[0x96, 0x97], false),
+    (0xbf10, &[0x96, 0x98], false),
+    (0xbf11, &[0x96, 0x99], false),
+    (0xbf12, &[0x96, 0x9a], false),
+    (0xbf13, &[0x96, 0x9b], false),
+    (0xbf14, &[0x96, 0x9c], false),
+    (0xbf15, &[0x96, 0x9d], false),
+    (0xbf16, &[0x96, 0x9e], false),
+    (0xbf17, &[0x96, 0x9f], false),
+    (0xbf1a, &[0x96, 0xa0], false),
+    (0xbf1e, &[0x96, 0xa1], false),
+    (0xbf1f, &[0x96, 0xa2], false),
+    (0xbf20, &[0x96, 0xa3], false),
+    (0xbf21, &[0x96, 0xa4], false),
+    (0xbf22, &[0x96, 0xa5], false),
+    (0xbf23, &[0x96, 0xa6], false),
+    (0xbf24, &[0x96, 0xa7], false),
+    (0xbf25, &[0x96, 0xa8], false),
+    (0xbf26, &[0x96, 0xa9], false),
+    (0xbf27, &[0x96, 0xaa], false),
+    (0xbf28, &[0x96, 0xab], false),
+    (0xbf29, &[0x96, 0xac], false),
+    (0xbf2a, &[0x96, 0xad], false),
+    (0xbf2b, &[0x96, 0xae], false),
+    (0xbf2c, &[0x96, 0xaf], false),
+    (0xbf2d, &[0x96, 0xb0], false),
+    (0xbf2e, &[0x96, 0xb1], false),
+    (0xbf2f, &[0x96, 0xb2], false),
+    (0xbf30, &[0x96, 0xb3], false),
+    (0xbf31, &[0x96, 0xb4], false),
+    (0xbf32, &[0x96, 0xb5], false),
+    (0xbf33, &[0x96, 0xb6], false),
+    (0xbf34, &[0x96, 0xb7], false),
+    (0xbf35, &[0x96, 0xb8], false),
+    (0xbf36, &[0x96, 0xb9], false),
+    (0xbf37, &[0x96, 0xba], false),
+    (0xbf38, &[0x96, 0xbb], false),
+    (0xbf39, &[0x96, 0xbc], false),
+    (0xbf3a, &[0x96, 0xbd], false),
+    (0xbf3b, &[0x96, 0xbe], false),
+    (0xbf3c, &[0x96, 0xbf], false),
+    (0xbf3d, &[0x96, 0xc0], false),
+    (0xbf3e, &[0x96, 0xc1], false),
+    (0xbf3f, &[0x96, 0xc2], false),
+    (0xbf42, &[0x96, 0xc3], false),
+    (0xbf43, &[0x96, 0xc4], false),
+    (0xbf45, &[0x96, 0xc5], false),
+    (0xbf46, &[0x96, 0xc6], false),
+    (0xbf47, &[0x96, 0xc7], false),
+    (0xbf49, &[0x96, 0xc8], false),
+    (0xbf4a, &[0x96, 0xc9], false),
+    (0xbf4b, &[0x96, 0xca], false),
+    (0xbf4c, &[0x96, 0xcb], false),
+    (0xbf4d, &[0x96, 0xcc], false),
+    (0xbf4e, &[0x96, 0xcd], false),
+    (0xbf4f, &[0x96, 0xce], false),
+    (0xbf52, &[0x96, 0xcf], false),
+    (0xbf53, &[0x96, 0xd0], false),
+    (0xbf54, &[0x96, 0xd1], false),
+    (0xbf56, &[0x96, 0xd2], false),
+    (0xbf57, &[0x96, 0xd3], false),
+    (0xbf58, &[0x96, 0xd4], false),
+    (0xbf59, &[0x96, 0xd5], false),
+    (0xbf5a, &[0x96, 0xd6], false),
+    (0xbf5b, &[0x96, 0xd7], false),
+    (0xbf5c, &[0x96, 0xd8], false),
+    (0xbf5d, &[0x96, 0xd9], false),
+    (0xbf5e, &[0x96, 0xda], false),
+    (0xbf5f, &[0x96, 0xdb], false),
+    (0xbf60, &[0x96, 0xdc], false),
+    (0xbf61, &[0x96, 0xdd], false),
+    (0xbf62, &[0x96, 0xde], false),
+    (0xbf63, &[0x96, 0xdf], false),
+    (0xbf64, &[0x96, 0xe0], false),
+    (0xbf65, &[0x96, 0xe1], false),
+    (0xbf66, &[0x96, 0xe2], false),
+    (0xbf67, &[0x96, 0xe3], false),
+    (0xbf68, &[0x96, 0xe4], false),
+    (0xbf69, &[0x96, 0xe5], false),
+    (0xbf6a, &[0x96, 0xe6], false),
+    (0xbf6b, &[0x96, 0xe7], false),
+    (0xbf6c, &[0x96, 0xe8], false),
+    (0xbf6d, &[0x96, 0xe9], false),
+    (0xbf6e, &[0x96, 0xea], false),
+    (0xbf6f, &[0x96, 0xeb], false),
+    (0xbf70, &[0x96, 0xec], false),
+    (0xbf71, &[0x96, 0xed], false),
+    (0xbf72, &[0x96, 0xee], false),
+    (0xbf73, &[0x96, 0xef], false),
+    (0xbf74, &[0x96, 0xf0], false),
+    (0xbf75, &[0x96, 0xf1], false),
+    (0xbf76, &[0x96, 0xf2], false),
+    (0xbf77, &[0x96, 0xf3], false),
+    (0xbf78, &[0x96, 0xf4], false),
+    (0xbf79, &[0x96, 0xf5], false),
+    (0xbf7a, &[0x96, 0xf6], false),
+    (0xbf7b, &[0x96, 0xf7], false),
+    (0xbf7c, &[0x96, 0xf8], false),
+    (0xbf7d, &[0x96, 0xf9], false),
+    (0xbf7e, &[0x96, 0xfa], false),
+    (0xbf7f, &[0x96, 0xfb], false),
+    (0xbf80, &[0x96, 0xfc], false),
+    (0xbf81, &[0x96, 0xfd], false),
+    (0xbf82, &[0x96, 0xfe], false),
+    (0xbf83, &[0x97, 0x41], false),
+    (0xbf84, &[0x97, 0x42], false),
+    (0xbf85, &[0x97, 0x43], false),
+    (0xbf86, &[0x97, 0x44], false),
+    (0xbf87, &[0x97, 0x45], false),
+    (0xbf88, &[0x97, 0x46], false),
+    (0xbf89, &[0x97, 0x47], false),
+    (0xbf8a, &[0x97, 0x48], false),
+    (0xbf8b, &[0x97, 0x49], false),
+    (0xbf8c, &[0x97, 0x4a], false),
+    (0xbf8d, &[0x97, 0x4b], false),
+    (0xbf8e, &[0x97, 0x4c], false),
+    (0xbf8f, &[0x97, 0x4d], false),
+    (0xbf90, &[0x97, 0x4e], false),
+    (0xbf91, &[0x97, 0x4f], false),
+    (0xbf92, &[0x97, 0x50], false),
+    (0xbf93, &[0x97, 0x51], false),
+    (0xbf95, &[0x97, 0x52], false),
+    (0xbf96, &[0x97, 0x53], false),
+    (0xbf97, &[0x97, 0x54], false),
+    (0xbf98, &[0x97, 0x55], false),
+    (0xbf99, &[0x97, 0x56], false),
+    (0xbf9a, &[0x97, 0x57], false),
+    (0xbf9b, &[0x97, 0x58], false),
+    (0xbf9c, &[0x97, 0x59], false),
+    (0xbf9d, &[0x97, 0x5a], false),
+    (0xbf9e, &[0x97, 0x61], false),
+    (0xbf9f, &[0x97, 0x62], false),
+    (0xbfa0, &[0x97, 0x63], false),
+    (0xbfa1, &[0x97, 0x64], false),
+    (0xbfa2, &[0x97, 0x65], false),
+    (0xbfa3, &[0x97, 0x66], false),
+    (0xbfa4, &[0x97, 0x67], false),
+    (0xbfa5, &[0x97, 0x68], false),
+    (0xbfa6, &[0x97, 0x69], false),
+    (0xbfa7, &[0x97, 0x6a], false),
+    (0xbfa8, &[0x97, 0x6b], false),
+    (0xbfa9, &[0x97, 0x6c], false),
+    (0xbfaa, &[0x97, 0x6d], false),
+    (0xbfab, &[0x97, 0x6e], false),
+    (0xbfac, &[0x97, 0x6f], false),
+    (0xbfad, &[0x97, 0x70], false),
+    (0xbfae, &[0x97, 0x71], false),
+    (0xbfaf, &[0x97, 0x72], false),
+    (0xbfb1, &[0x97, 0x73], false),
+    (0xbfb2, &[0x97, 0x74], false),
+    (0xbfb3, &[0x97, 0x75], false),
+    (0xbfb4, &[0x97, 0x76], false),
+    (0xbfb5, &[0x97, 0x77], false),
+    (0xbfb6, &[0x97, 0x78], false),
+    (0xbfb7, &[0x97, 0x79], false),
+    (0xbfb8, &[0x97, 0x7a], false),
+    (0xbfb9, &[0x97, 0x81], false),
+    (0xbfba, &[0x97, 0x82], false),
+    (0xbfbb, &[0x97, 0x83], false),
+    (0xbfbc, &[0x97, 0x84], false),
+    (0xbfbd, &[0x97, 0x85], false),
+    (0xbfbe, &[0x97, 0x86], false),
+    (0xbfbf, &[0x97, 0x87], false),
+    (0xbfc0, &[0x97, 0x88], false),
+    (0xbfc1, &[0x97, 0x89], false),
+    (0xbfc2, &[0x97, 0x8a], false),
+    (0xbfc3, &[0x97, 0x8b], false),
+    (0xbfc4, &[0x97, 0x8c], false),
+    (0xbfc6, &[0x97, 0x8d], false),
+    (0xbfc7, &[0x97, 0x8e], false),
+    (0xbfc8, &[0x97, 0x8f], false),
+    (0xbfc9, &[0x97, 0x90], false),
+    (0xbfca, &[0x97, 0x91], false),
+    (0xbfcb, &[0x97, 0x92], false),
+    (0xbfce, &[0x97, 0x93], false),
+    (0xbfcf, &[0x97, 0x94], false),
+    (0xbfd1, &[0x97, 0x95], false),
+    (0xbfd2, &[0x97, 0x96], false),
+    (0xbfd3, &[0x97, 0x97], false),
+    (0xbfd5, &[0x97, 0x98], false),
+    (0xbfd6, &[0x97, 0x99], false),
+    (0xbfd7, &[0x97, 0x9a], false),
+    (0xbfd8, &[0x97, 0x9b], false),
+    (0xbfd9, &[0x97, 0x9c], false),
+    (0xbfda, &[0x97, 0x9d], false),
+    (0xbfdb, &[0x97, 0x9e], false),
+    (0xbfdd, &[0x97, 0x9f], false),
+    (0xbfde, &[0x97, 0xa0], false),
+    (0xbfe0, &[0x97, 0xa1], false),
+    (0xbfe2, &[0x97, 0xa2], false),
+    (0xbfe3, &[0x97, 0xa3], false),
+    (0xbfe4, &[0x97, 0xa4], false),
+    (0xbfe5, &[0x97, 0xa5], false),
+    (0xbfe6, &[0x97, 0xa6], false),
+    (0xbfe7, &[0x97, 0xa7], false),
+    (0xbfe8, &[0x97, 0xa8], false),
+    (0xbfe9, &[0x97, 0xa9], false),
+    (0xbfea, &[0x97, 0xaa], false),
+    (0xbfeb, &[0x97, 0xab], false),
+    (0xbfec, &[0x97, 0xac], false),
+    (0xbfed, &[0x97, 0xad], false),
+    (0xbfee, &[0x97, 0xae], false),
+    (0xbfef, &[0x97, 0xaf], false),
+    (0xbff0, &[0x97, 0xb0], false),
+    (0xbff1, &[0x97, 0xb1], false),
+    (0xbff2, &[0x97, 0xb2], false),
+    (0xbff3, &[0x97, 0xb3], false),
+    (0xbff4, &[0x97, 0xb4], false),
+    (0xbff5, &[0x97, 0xb5], false),
+    (0xbff6, &[0x97, 0xb6], false),
+    (0xbff7, &[0x97, 0xb7], false),
+    (0xbff8, &[0x97, 0xb8], false),
+    (0xbff9, &[0x97, 0xb9], false),
+    (0xbffa, &[0x97, 0xba], false),
+    (0xbffb, &[0x97, 0xbb], false),
+    (0xbffc, &[0x97, 0xbc], false),
+    (0xbffd, &[0x97, 0xbd], false),
+    (0xbffe, &[0x97, 0xbe], false),
+    (0xbfff, &[0x97, 0xbf], false),
+    (0xc000, &[0x97, 0xc0], false),
+    (0xc001, &[0x97, 0xc1], false),
+    (0xc002, &[0x97, 0xc2], false),
+    (0xc003, &[0x97, 0xc3], false),
+    (0xc004, &[0x97, 0xc4], false),
+    (0xc005, &[0x97, 0xc5], false),
+    (0xc006, &[0x97, 0xc6], false),
+    (0xc007, &[0x97, 0xc7], false),
+    (0xc008, &[0x97, 0xc8], false),
+    (0xc009, &[0x97, 0xc9], false),
+    (0xc00a, &[0x97, 0xca], false),
+    (0xc00b, &[0x97, 0xcb], false),
+    (0xc00c, &[0x97, 0xcc], false),
+    (0xc00d, &[0x97, 0xcd], false),
+    (0xc00e, &[0x97, 0xce], false),
+    (0xc00f, &[0x97, 0xcf], false),
+    (0xc010, &[0x97, 0xd0], false),
+    (0xc011, &[0x97, 0xd1], false),
+    (0xc012, &[0x97, 0xd2], false),
+    (0xc013, &[0x97, 0xd3], false),
+    (0xc014, &[0x97, 0xd4], false),
+    (0xc015, &[0x97, 0xd5], false),
+    (0xc016, &[0x97, 0xd6], false),
+    (0xc017, &[0x97, 0xd7], false),
+    (0xc018, &[0x97, 0xd8], false),
+    (0xc019, &[0x97, 0xd9], false),
+    (0xc01a, &[0x97, 0xda], false),
+    (0xc01b, &[0x97, 0xdb], false),
+    (0xc01c, &[0x97, 0xdc], false),
+    (0xc01d, &[0x97, 0xdd], false),
+    (0xc01e, &[0x97, 0xde], false),
+    (0xc01f, &[0x97, 0xdf], false),
+    (0xc020, &[0x97, 0xe0], false),
+    (0xc021, &[0x97, 0xe1], false),
+    (0xc022, &[0x97, 0xe2], false),
+    (0xc023, &[0x97, 0xe3], false),
+    (0xc024, &[0x97, 0xe4], false),
+    (0xc025, &[0x97, 0xe5], false),
+    (0xc026, &[0x97, 0xe6], false),
+    (0xc027, &[0x97, 0xe7], false),
+    (0xc028, &[0x97, 0xe8], false),
+    (0xc029, &[0x97, 0xe9], false),
+    (0xc02a, &[0x97, 0xea], false),
+    (0xc02b, &[0x97, 0xeb], false),
+    (0xc02c, &[0x97, 0xec], false),
+    (0xc02d, &[0x97, 0xed], false),
+    (0xc02e, &[0x97, 0xee], false),
+    (0xc02f, &[0x97, 0xef], false),
+    (0xc030, &[0x97, 0xf0], false),
+    (0xc031, &[0x97, 0xf1], false),
+    (0xc032, &[0x97, 0xf2], false),
+    (0xc033, &[0x97, 0xf3], false),
+    (0xc034, &[0x97, 0xf4], false),
+    (0xc035, &[0x97, 0xf5], false),
+    (0xc036, &[0x97, 0xf6], false),
+    (0xc037, &[0x97, 0xf7], false),
+    (0xc038, &[0x97, 0xf8], false),
+    (0xc039, &[0x97, 0xf9], false),
+    (0xc03a, &[0x97, 0xfa], false),
+    (0xc03b, &[0x97, 0xfb], false),
+    (0xc03d, &[0x97, 0xfc], false),
+    (0xc03e, &[0x97, 0xfd], false),
+    (0xc03f, &[0x97, 0xfe], false),
+    (0xc040, &[0x98, 0x41], false),
+    (0xc041, &[0x98, 0x42], false),
+    (0xc042, &[0x98, 0x43], false),
+    (0xc043, &[0x98, 0x44], false),
+    (0xc044, &[0x98, 0x45], false),
+    (0xc045, &[0x98, 0x46], false),
+    (0xc046, &[0x98, 0x47], false),
+    (0xc047, &[0x98, 0x48], false),
+    (0xc048, &[0x98, 0x49], false),
+    (0xc049, &[0x98, 0x4a], false),
+    (0xc04a, &[0x98, 0x4b], false),
+    (0xc04b, &[0x98, 0x4c], false),
+    (0xc04c, &[0x98, 0x4d], false),
+    (0xc04d, &[0x98, 0x4e], false),
+    (0xc04e, &[0x98, 0x4f], false),
+    (0xc04f, &[0x98, 0x50], false),
+    (0xc050, &[0x98, 0x51], false),
+    (0xc052, &[0x98, 0x52], false),
+    (0xc053, &[0x98, 0x53], false),
+    (0xc054, &[0x98, 0x54], false),
+    (0xc055, &[0x98, 0x55], false),
+    (0xc056, &[0x98, 0x56], false),
+    (0xc057, &[0x98, 0x57], false),
+    (0xc059, &[0x98, 0x58], false),
+    (0xc05a, &[0x98, 0x59], false),
+    (0xc05b, &[0x98, 0x5a], false),
+    (0xc05d, &[0x98, 0x61], false),
+    (0xc05e, &[0x98, 0x62], false),
+    (0xc05f, &[0x98, 0x63], false),
+    (0xc061, &[0x98, 0x64], false),
+    (0xc062, &[0x98, 0x65], false),
+    (0xc063, &[0x98, 0x66], false),
+    (0xc064, &[0x98, 0x67], false),
+    (0xc065, &[0x98, 0x68], false),
+    (0xc066, &[0x98, 0x69], false),
+    (0xc067, &[0x98, 0x6a], false),
+    (0xc06a, &[0x98, 0x6b], false),
+    (0xc06b, &[0x98, 0x6c], false),
+    (0xc06c, &[0x98, 0x6d], false),
+    (0xc06d, &[0x98, 0x6e], false),
+    (0xc06e, &[0x98, 0x6f], false),
+    (0xc06f, &[0x98, 0x70], false),
+    (0xc070, &[0x98, 0x71], false),
+    (0xc071, &[0x98, 0x72], false),
+    (0xc072, &[0x98, 0x73], false),
+    (0xc073, &[0x98, 0x74], false),
+    (0xc074, &[0x98, 0x75], false),
+    (0xc075, &[0x98, 0x76], false),
+    (0xc076, &[0x98, 0x77], false),
+    (0xc077, &[0x98, 0x78], false),
+    (0xc078, &[0x98, 0x79], false),
+    (0xc079, &[0x98, 0x7a], false),
+    (0xc07a, &[0x98, 0x81], false),
+    (0xc07b, &[0x98, 0x82], false),
+    (0xc07c, &[0x98, 0x83], false),
+    (0xc07d, &[0x98, 0x84], false),
+    (0xc07e, &[0x98, 0x85], false),
+    (0xc07f, &[0x98, 0x86], false),
+    (0xc080, &[0x98, 0x87], false),
+    (0xc081, &[0x98, 0x88], false),
+    (0xc082, &[0x98, 0x89], false),
+    (0xc083, &[0x98, 0x8a], false),
+    (0xc084, &[0x98, 0x8b], false),
+    (0xc085, &[0x98, 0x8c], false),
+    (0xc086, &[0x98, 0x8d], false),
+    (0xc087, &[0x98, 0x8e], false),
+    (0xc088, &[0x98, 0x8f], false),
+    (0xc089, &[0x98, 0x90], false),
+    (0xc08a, &[0x98, 0x91], false),
+    (0xc08b, &[0x98, 0x92], false),
+    (0xc08c, &[0x98, 0x93], false),
+    (0xc08d, &[0x98, 0x94], false),
+    (0xc08e, &[0x98, 0x95], false),
+    (0xc08f, &[0x98, 0x96], false),
+    (0xc092, &[0x98, 0x97], false),
+    (0xc093, &[0x98, 0x98], false),
+    (0xc095, &[0x98, 0x99], false),
+    (0xc096, &[0x98, 0x9a], false),
+    (0xc097, &[0x98, 0x9b], false),
+    (0xc099, &[0x98, 0x9c], false),
+    (0xc09a, &[0x98, 0x9d], false),
+    (0xc09b, &[0x98, 0x9e], false),
+    (0xc09c, &[0x98, 0x9f], false),
+    (0xc09d, &[0x98, 0xa0], false),
+    (0xc09e, &[0x98, 0xa1], false),
+    (0xc09f, &[0x98, 0xa2], false),
+    (0xc0a2, &[0x98, 0xa3], false),
+    (0xc0a4, &[0x98, 0xa4], false),
+    (0xc0a6, &[0x98, 0xa5], false),
+    (0xc0a7, &[0x98, 0xa6], false),
+    (0xc0a8, &[0x98, 0xa7], false),
+    (0xc0a9, &[0x98, 0xa8], false),
+    (0xc0aa, &[0x98, 0xa9], false),
+    (0xc0ab, &[0x98, 0xaa], false),
+    (0xc0ae, &[0x98, 0xab], false),
+    (0xc0b1, &[0x98, 0xac], false),
+    (0xc0b2, &[0x98, 0xad], false),
+    (0xc0b7, &[0x98, 0xae], false),
+    (0xc0b8, &[0x98, 0xaf], false),
+    (0xc0b9, &[0x98, 0xb0], false),
+    (0xc0ba, &[0x98, 0xb1], false),
+    (0xc0bb, &[0x98, 0xb2], false),
+    (0xc0be, &[0x98, 0xb3], false),
+    (0xc0c2, &[0x98, 0xb4], false),
+    (0xc0c3, &[0x98, 0xb5], false),
+    (0xc0c4, &[0x98, 0xb6], false),
+    (0xc0c6, &[0x98, 0xb7], false),
+    (0xc0c7, &[0x98, 0xb8], false),
+    (0xc0ca, &[0x98, 0xb9], false),
+    (0xc0cb, &[0x98, 0xba], false),
+    (0xc0cd, &[0x98, 0xbb], false),
+    (0xc0ce, &[0x98, 0xbc], false),
+    (0xc0cf, &[0x98, 0xbd], false),
+    (0xc0d1, &[0x98, 0xbe], false),
+    (0xc0d2, &[0x98, 0xbf], false),
+    (0xc0d3, &[0x98, 0xc0], false),
+    (0xc0d4, &[0x98, 0xc1], false),
+    (0xc0d5, &[0x98, 0xc2], false),
+    (0xc0d6, &[0x98, 0xc3], false),
+    (0xc0d7, &[0x98, 0xc4], false),
+    (0xc0da, &[0x98, 0xc5], false),
+    (0xc0de, &[0x98, 0xc6], false),
+    (0xc0df, &[0x98, 0xc7], false),
+    (0xc0e0, &[0x98, 0xc8], false),
+    (0xc0e1, &[0x98, 0xc9], false),
+    (0xc0e2, &[0x98, 0xca], false),
+    (0xc0e3, &[0x98, 0xcb], false),
+    (0xc0e6, &[0x98, 0xcc], false),
+    (0xc0e7, &[0x98, 0xcd], false),
+    (0xc0e9, &[0x98, 0xce], false),
+    (0xc0ea, &[0x98, 0xcf], false),
+    (0xc0eb, &[0x98, 0xd0], false),
+    (0xc0ed, &[0x98, 0xd1], false),
+    (0xc0ee, &[0x98, 0xd2], false),
+    (0xc0ef, &[0x98, 0xd3], false),
+    (0xc0f0, &[0x98, 0xd4], false),
+    (0xc0f1, &[0x98, 0xd5], false),
+    (0xc0f2, &[0x98, 0xd6], false),
+    (0xc0f3, &[0x98, 0xd7], false),
+    (0xc0f6, &[0x98, 0xd8], false),
+    (0xc0f8, &[0x98, 0xd9], false),
+    (0xc0fa, &[0x98, 0xda], false),
+    (0xc0fb, &[0x98, 0xdb], false),
+    (0xc0fc, &[0x98, 0xdc], false),
+    (0xc0fd, &[0x98, 0xdd], false),
+    (0xc0fe, &[0x98, 0xde], false),
+    (0xc0ff, &[0x98, 0xdf], false),
+    (0xc101, &[0x98, 0xe0], false),
+    (0xc102, &[0x98, 0xe1], false),
+    (0xc103, &[0x98, 0xe2], false),
+    (0xc105, &[0x98, 0xe3], false),
+    (0xc106, &[0x98, 0xe4], false),
+    (0xc107, &[0x98, 0xe5], false),
+    (0xc109, &[0x98, 0xe6], false),
+    (0xc10a, &[0x98, 0xe7], false),
+    (0xc10b, &[0x98, 0xe8], false),
+    (0xc10c, &[0x98, 0xe9], false),
+    (0xc10d, &[0x98, 0xea], false),
+    (0xc10e, &[0x98, 0xeb], false),
+    (0xc10f, &[0x98, 0xec], false),
+    (0xc111, &[0x98, 0xed], false),
+    (0xc112, &[0x98, 0xee], false),
+    (0xc113, &[0x98, 0xef], false),
+    (0xc114, &[0x98, 0xf0], false),
+    (0xc116, &[0x98, 0xf1], false),
+    (0xc117, &[0x98, 0xf2], false),
+    (0xc118, &[0x98, 0xf3], false),
+    (0xc119, &[0x98, 0xf4], false),
+    (0xc11a, &[0x98, 0xf5], false),
+    (0xc11b, &[0x98, 0xf6], false),
+    (0xc121, &[0x98, 0xf7], false),
+    (0xc122, &[0x98, 0xf8], false),
+    (0xc125, &[0x98, 0xf9], false),
+    (0xc128, &[0x98, 0xfa], false),
+    (0xc129, &[0x98, 0xfb], false),
+    (0xc12a, &[0x98, 0xfc], false),
+    (0xc12b, &[0x98, 0xfd], false),
+    (0xc12e, &[0x98, 0xfe], false),
+    (0xc132, &[0x99, 0x41], false),
+    (0xc133, &[0x99, 0x42], false),
+    (0xc134, &[0x99, 0x43], false),
+    (0xc135, &[0x99, 0x44], false),
+    (0xc137, &[0x99, 0x45], false),
+    (0xc13a, &[0x99, 0x46], false),
+    (0xc13b, &[0x99, 0x47], false),
+    (0xc13d, &[0x99, 0x48], false),
+    (0xc13e, &[0x99, 0x49], false),
+    (0xc13f, &[0x99, 0x4a], false),
+    (0xc141, &[0x99, 0x4b], false),
+    (0xc142, &[0x99, 0x4c], false),
+    (0xc143, &[0x99, 0x4d], false),
+    (0xc144, &[0x99, 0x4e], false),
+    (0xc145, &[0x99, 0x4f], false),
+    (0xc146, &[0x99, 0x50], false),
+    (0xc147, &[0x99, 0x51], false),
+    (0xc14a, &[0x99, 0x52], false),
+    (0xc14e, &[0x99, 0x53], false),
+    (0xc14f, &[0x99, 0x54], false),
+    (0xc150, &[0x99, 0x55], false),
+    (0xc151, &[0x99, 0x56], false),
+    (0xc152, &[0x99, 0x57], false),
+    (0xc153, &[0x99, 0x58], false),
+    (0xc156, &[0x99, 0x59], false),
+    (0xc157, &[0x99, 0x5a], false),
+    (0xc159, &[0x99, 0x61], false),
+    (0xc15a, &[0x99, 0x62], false),
+    (0xc15b, &[0x99, 0x63], false),
+    (0xc15d, &[0x99, 0x64], false),
+    (0xc15e, &[0x99, 0x65], false),
+    (0xc15f, &[0x99, 0x66], false),
+    (0xc160, &[0x99, 0x67], false),
+    (0xc161, &[0x99, 0x68], false),
+    (0xc162, &[0x99, 0x69], false),
+    (0xc163, &[0x99, 0x6a], false),
+    (0xc166, &[0x99, 0x6b], false),
+    (0xc16a, &[0x99, 0x6c], false),
+    (0xc16b, &[0x99, 0x6d], false),
+    (0xc16c, &[0x99, 0x6e], false),
+    (0xc16d, &[0x99, 0x6f], false),
+    (0xc16e, &[0x99, 0x70], false),
+    (0xc16f, &[0x99, 0x71], false),
+    (0xc171, &[0x99, 0x72], false),
+    (0xc172, &[0x99, 0x73], false),
+    (0xc173, &[0x99, 0x74], false),
+    (0xc175, &[0x99, 0x75], false),
+    (0xc176, &[0x99, 0x76], false),
+    (0xc177, &[0x99, 0x77], false),
+    (0xc179, &[0x99, 0x78], false),
+    (0xc17a, &[0x99, 0x79], false),
+    (0xc17b, &[0x99, 0x7a], false),
+    (0xc17c, &[0x99, 0x81], false),
+    (0xc17d, &[0x99, 0x82], false),
+    (0xc17e, &[0x99, 0x83], false),
+    (0xc17f, &[0x99, 0x84], false),
+    (0xc180, &[0x99, 0x85], false),
+    (0xc181, &[0x99, 0x86], false),
+    (0xc182, &[0x99, 0x87], false),
+    (0xc183, &[0x99, 0x88], false),
+    (0xc184, &[0x99, 0x89], false),
+    (0xc186, &[0x99, 0x8a], false),
+    (0xc187, &[0x99, 0x8b], false),
+    (0xc188, &[0x99, 0x8c], false),
+    (0xc189, &[0x99, 0x8d], false),
+    (0xc18a, &[0x99, 0x8e], false),
+    (0xc18b, &[0x99, 0x8f], false),
+    (0xc18f, &[0x99, 0x90], false),
+    (0xc191, &[0x99, 0x91], false),
+    (0xc192, &[0x99, 0x92], false),
+    (0xc193, &[0x99, 0x93], false),
+    (0xc195, &[0x99, 0x94], false),
+    (0xc197, &[0x99, 0x95], false),
+    (0xc198, &[0x99, 0x96], false),
+    (0xc199, &[0x99, 0x97], false),
+    (0xc19a, &[0x99, 0x98], false),
+    (0xc19b, &[0x99, 0x99], false),
+    (0xc19e, &[0x99, 0x9a], false),
+    (0xc1a0, &[0x99, 0x9b], false),
+    (0xc1a2, &[0x99, 0x9c], false),
+    (0xc1a3, &[0x99, 0x9d], false),
+    (0xc1a4, &[0x99, 0x9e], false),
+    (0xc1a6, &[0x99, 0x9f], false),
+    (0xc1a7, &[0x99, 0xa0], false),
+    (0xc1aa, &[0x99, 0xa1], false),
+    (0xc1ab, &[0x99, 0xa2], false),
+    (0xc1ad, &[0x99, 0xa3], false),
+    (0xc1ae, &[0x99, 0xa4], false),
+    (0xc1af, &[0x99, 0xa5], false),
+    (0xc1b1, &[0x99, 0xa6], false),
+    (0xc1b2, &[0x99, 0xa7], false),
+    (0xc1b3, &[0x99, 0xa8], false),
+    (0xc1b4, &[0x99, 0xa9], false),
+    (0xc1b5, &[0x99, 0xaa], false),
+    (0xc1b6, &[0x99, 0xab], false),
+    (0xc1b7, &[0x99, 0xac], false),
+    (0xc1b8, &[0x99, 0xad], false),
+    (0xc1b9, &[0x99, 0xae], false),
+    (0xc1ba, &[0x99, 0xaf], false),
+    (0xc1bb, &[0x99, 0xb0], false),
+    (0xc1bc, &[0x99, 0xb1], false),
+    (0xc1be, &[0x99, 0xb2], false),
+    (0xc1bf, &[0x99, 0xb3], false),
+    (0xc1c0, &[0x99, 0xb4], false),
+    (0xc1c1, &[0x99, 0xb5], false),
+    (0xc1c2, &[0x99, 0xb6], false),
+    (0xc1c3, &[0x99, 0xb7], false),
+    (0xc1c5, &[0x99, 0xb8], false),
+    (0xc1c6, &[0x99, 0xb9], false),
+    (0xc1c7, &[0x99, 0xba], false),
+    (0xc1c9, &[0x99, 0xbb], false),
+    (0xc1ca, &[0x99, 0xbc], false),
+    (0xc1cb, &[0x99, 0xbd], false),
+    (0xc1cd, &[0x99, 0xbe], false),
+    (0xc1ce, &[0x99, 0xbf], false),
+    (0xc1cf, &[0x99, 0xc0], false),
+    (0xc1d0, &[0x99, 0xc1], false),
+    (0xc1d1, &[0x99, 0xc2], false),
+    (0xc1d2, &[0x99, 0xc3], false),
+    (0xc1d3, &[0x99, 0xc4], false),
+    (0xc1d5, &[0x99, 0xc5], false),
+    (0xc1d6, &[0x99, 0xc6], false),
+    (0xc1d9, &[0x99, 0xc7], false),
+    (0xc1da, &[0x99, 0xc8], false),
+    (0xc1db, &[0x99, 0xc9], false),
+    (0xc1dc, &[0x99, 0xca], false),
+    (0xc1dd, &[0x99, 0xcb], false),
+    (0xc1de, &[0x99, 0xcc], false),
+    (0xc1df, &[0x99, 0xcd], false),
+    (0xc1e1, &[0x99, 0xce], false),
+    (0xc1e2, &[0x99, 0xcf], false),
+    (0xc1e3, &[0x99, 0xd0], false),
+    (0xc1e5, &[0x99, 0xd1], false),
+    (0xc1e6, &[0x99, 0xd2], false),
+    (0xc1e7, &[0x99, 0xd3], false),
+    (0xc1e9, &[0x99, 0xd4], false),
+    (0xc1ea, &[0x99, 0xd5], false),
+    (0xc1eb, &[0x99, 0xd6], false),
+    (0xc1ec, &[0x99, 0xd7], false),
+    (0xc1ed, &[0x99, 0xd8], false),
+    (0xc1ee, &[0x99, 0xd9], false),
+    (0xc1ef, &[0x99, 0xda], false),
+    (0xc1f2, &[0x99, 0xdb], false),
+    (0xc1f4, &[0x99, 0xdc], false),
+    (0xc1f5, &[0x99, 0xdd], false),
+    (0xc1f6, &[0x99, 0xde], false),
+    (0xc1f7, &[0x99, 0xdf], false),
+    (0xc1f8, &[0x99, 0xe0], false),
+    (0xc1f9, &[0x99, 0xe1], false),
+    (0xc1fa, &[0x99, 0xe2], false),
+    (0xc1fb, &[0x99, 0xe3], false),
+    (0xc1fe, &[0x99, 0xe4], false),
+    (0xc1ff, &[0x99, 0xe5], false),
+    (0xc201, &[0x99, 0xe6], false),
+    (0xc202, &[0x99, 0xe7], false),
+    (0xc203, &[0x99, 0xe8], false),
+    (0xc205, &[0x99, 0xe9], false),
+    (0xc206, &[0x99, 0xea], false),
+    (0xc207, &[0x99, 0xeb], false),
+    (0xc208, &[0x99, 0xec], false),
+    (0xc209, &[0x99, 0xed], false),
+    (0xc20a, &[0x99, 0xee], false),
+    (0xc20b, &[0x99, 0xef], false),
+    (0xc20e, &[0x99, 0xf0], false),
+    (0xc210, &[0x99, 0xf1], false),
+    (0xc212, &[0x99, 0xf2], false),
+    (0xc213, &[0x99, 0xf3], false),
+    (0xc214, &[0x99, 0xf4], false),
+    (0xc215, &[0x99, 0xf5], false),
+    (0xc216, &[0x99, 0xf6], false),
+    (0xc217, &[0x99, 0xf7], false),
+    (0xc21a, &[0x99, 0xf8], false),
+    (0xc21b, &[0x99, 0xf9], false),
+    (0xc21d, &[0x99, 0xfa], false),
+    (0xc21e, &[0x99, 0xfb], false),
+    (0xc221, &[0x99, 0xfc], false),
+    (0xc222, &[0x99, 0xfd], false),
+    (0xc223, &[0x99, 0xfe], false),
+    (0xc224, &[0x9a, 0x41], false),
+    (0xc225, &[0x9a, 0x42], false),
+    (0xc226, &[0x9a, 0x43], false),
+    (0xc227, &[0x9a, 0x44], false),
+    (0xc22a, &[0x9a, 0x45], false),
+    (0xc22c, &[0x9a, 0x46], false),
+    (0xc22e, &[0x9a, 0x47], false),
+    (0xc230, &[0x9a, 0x48], false),
+    (0xc233, &[0x9a, 0x49], false),
+    (0xc235, &[0x9a, 0x4a], false),
+    (0xc236, &[0x9a, 0x4b], false),
+    (0xc237, &[0x9a, 0x4c], false),
+    (0xc238, &[0x9a, 0x4d], false),
+    (0xc239, &[0x9a, 0x4e], false),
+    (0xc23a, &[0x9a, 0x4f], false),
+    (0xc23b, &[0x9a, 0x50], false),
+    (0xc23c, &[0x9a, 0x51], false),
+    (0xc23d, &[0x9a, 0x52], false),
+    (0xc23e, &[0x9a, 0x53], false),
+    (0xc23f, &[0x9a, 0x54], false),
+    (0xc240, &[0x9a, 0x55], false),
+    (0xc241, &[0x9a, 0x56], false),
+    (0xc242, &[0x9a, 0x57], false),
+    (0xc243, &[0x9a, 0x58], false),
+    (0xc244, &[0x9a, 0x59], false),
+    (0xc245, &[0x9a, 0x5a], false),
+    (0xc246, &[0x9a, 0x61], false),
+    (0xc247, &[0x9a, 0x62], false),
+    (0xc249, &[0x9a, 0x63], false),
+    (0xc24a, &[0x9a, 0x64], false),
+    (0xc24b, &[0x9a, 0x65], false),
+    (0xc24c, &[0x9a, 0x66], false),
+    (0xc24d, &[0x9a, 0x67], false),
+    (0xc24e, &[0x9a, 0x68], false),
+    (0xc24f, &[0x9a, 0x69], false),
+    (0xc252, &[0x9a, 0x6a], false),
+    (0xc253, &[0x9a, 0x6b], false),
+    (0xc255, &[0x9a, 0x6c], false),
+    (0xc256, &[0x9a, 0x6d], false),
+    (0xc257, &[0x9a, 0x6e], false),
+    (0xc259, &[0x9a, 0x6f], false),
+    (0xc25a, &[0x9a, 0x70], false),
+    (0xc25b, &[0x9a, 0x71], false),
+    (0xc25c, &[0x9a, 0x72], false),
+    (0xc25d, &[0x9a, 0x73], false),
+    (0xc25e, &[0x9a, 0x74], false),
+    (0xc25f, &[0x9a, 0x75], false),
+    (0xc261, &[0x9a, 0x76], false),
+    (0xc262, &[0x9a, 0x77], false),
+    (0xc263, &[0x9a, 0x78], false),
+    (0xc264, &[0x9a, 0x79], false),
+    (0xc266, &[0x9a, 0x7a], false),
+    (0xc267, &[0x9a, 0x81], false),
+    (0xc268, &[0x9a, 0x82], false),
+    (0xc269, &[0x9a, 0x83], false),
+    (0xc26a, &[0x9a, 0x84], false),
+    (0xc26b, &[0x9a, 0x85], false),
+    (0xc26e, &[0x9a, 0x86], false),
+    (0xc26f, &[0x9a, 0x87], false),
+    (0xc271, &[0x9a, 0x88], false),
+    (0xc272, &[0x9a, 0x89], false),
+    (0xc273, &[0x9a, 0x8a], false),
+    (0xc275, &[0x9a, 0x8b], false),
+    (0xc276, &[0x9a, 0x8c], false),
+    (0xc277, &[0x9a, 0x8d], false),
+    (0xc278, &[0x9a, 0x8e], false),
+    (0xc279, &[0x9a, 0x8f], false),
+    (0xc27a, &[0x9a, 0x90], false),
+    (0xc27b, &[0x9a, 0x91], false),
+    (0xc27e, &[0x9a, 0x92], false),
+    (0xc280, &[0x9a, 0x93], false),
+    (0xc282, &[0x9a, 0x94], false),
+    (0xc283, &[0x9a, 0x95], false),
+    (0xc284, &[0x9a, 0x96], false),
+    (0xc285, &[0x9a, 0x97], false),
+    (0xc286, &[0x9a, 0x98], false),
+    (0xc287, &[0x9a, 0x99], false),
+    (0xc28a, &[0x9a, 0x9a], false),
+    (0xc28b, &[0x9a, 0x9b], false),
+    (0xc28c, &[0x9a, 0x9c], false),
+    (0xc28d, &[0x9a, 0x9d], false),
+    (0xc28e, &[0x9a, 0x9e], false),
+    (0xc28f, &[0x9a, 0x9f], false),
+    (0xc291, &[0x9a, 0xa0], false),
+    (0xc292, &[0x9a, 0xa1], false),
+    (0xc293, &[0x9a, 0xa2], false),
+    (0xc294, &[0x9a, 0xa3], false),
+    (0xc295, &[0x9a, 0xa4], false),
+    (0xc296, &[0x9a, 0xa5], false),
+    (0xc297, &[0x9a, 0xa6], false),
+    (0xc299, &[0x9a, 0xa7], false),
+    (0xc29a, &[0x9a, 0xa8], false),
+    (0xc29c, &[0x9a, 0xa9], false),
+    (0xc29e, &[0x9a, 0xaa], false),
+    (0xc29f, &[0x9a, 0xab], false),
+    (0xc2a0, &[0x9a, 0xac], false),
+    (0xc2a1, &[0x9a, 0xad], false),
+    (0xc2a2, &[0x9a, 0xae], false),
+    (0xc2a3, &[0x9a, 0xaf], false),
+    (0xc2a6, &[0x9a, 0xb0], false),
+    (0xc2a7, &[0x9a, 0xb1], false),
+    (0xc2a9, &[0x9a, 0xb2], false),
+    (0xc2aa, &[0x9a, 0xb3], false),
+    (0xc2ab, &[0x9a, 0xb4], false),
+    (0xc2ae, &[0x9a, 0xb5], false),
+    (0xc2af, &[0x9a, 0xb6], false),
+    (0xc2b0, &[0x9a, 0xb7], false),
+    (0xc2b1, &[0x9a, 0xb8], false),
+    (0xc2b2, &[0x9a, 0xb9], false),
+    (0xc2b3, &[0x9a, 0xba], false),
+    (0xc2b6, &[0x9a, 0xbb], false),
+    (0xc2b8, &[0x9a, 0xbc], false),
+    (0xc2ba, &[0x9a, 0xbd], false),
+    (0xc2bb, &[0x9a, 0xbe], false),
+    (0xc2bc, &[0x9a, 0xbf], false),
+    (0xc2bd, &[0x9a, 0xc0], false),
+    (0xc2be, &[0x9a, 0xc1], false),
+    (0xc2bf, &[0x9a, 0xc2], false),
+    (0xc2c0, &[0x9a, 0xc3], false),
+    (0xc2c1, &[0x9a, 0xc4], false),
+    (0xc2c2, &[0x9a, 0xc5], false),
+    (0xc2c3, &[0x9a, 0xc6], false),
+    (0xc2c4, &[0x9a, 0xc7], false),
+    (0xc2c5, &[0x9a, 0xc8], false),
+    (0xc2c6, &[0x9a, 0xc9], false),
+    (0xc2c7, &[0x9a, 0xca], false),
+    (0xc2c8, &[0x9a, 0xcb], false),
+    (0xc2c9, &[0x9a, 0xcc], false),
+    (0xc2ca, &[0x9a, 0xcd], false),
+    (0xc2cb, &[0x9a, 0xce], false),
+    (0xc2cc, &[0x9a, 0xcf], false),
+    (0xc2cd, &[0x9a, 0xd0], false),
+    (0xc2ce, &[0x9a, 0xd1], false),
+    (0xc2cf, &[0x9a, 0xd2], false),
+    (0xc2d0, &[0x9a, 0xd3], false),
+    (0xc2d1, &[0x9a, 0xd4], false),
+    (0xc2d2, &[0x9a, 0xd5], false),
+    (0xc2d3, &[0x9a, 0xd6], false),
+    (0xc2d4, &[0x9a, 0xd7], false),
+    (0xc2d5, &[0x9a, 0xd8], false),
+    (0xc2d6, &[0x9a, 0xd9], false),
+    (0xc2d7, &[0x9a, 0xda], false),
+    (0xc2d8, &[0x9a, 0xdb], false),
+    (0xc2d9, &[0x9a, 0xdc], false),
+    (0xc2da, &[0x9a, 0xdd], false),
+    (0xc2db, &[0x9a, 0xde], false),
+    (0xc2de, &[0x9a, 0xdf], false),
+    (0xc2df, &[0x9a, 0xe0], false),
+    (0xc2e1, &[0x9a, 0xe1], false),
+    (0xc2e2, &[0x9a, 0xe2], false),
+    (0xc2e5, &[0x9a, 0xe3], false),
+    (0xc2e6, &[0x9a, 0xe4], false),
+    (0xc2e7, &[0x9a, 0xe5], false),
+    (0xc2e8, &[0x9a, 0xe6], false),
+    (0xc2e9, &[0x9a, 0xe7], false),
+    (0xc2ea, &[0x9a, 0xe8], false),
+    (0xc2ee, &[0x9a, 0xe9], false),
+    (0xc2f0, &[0x9a, 0xea], false),
+    (0xc2f2, &[0x9a, 0xeb], false),
+    (0xc2f3, &[0x9a, 0xec], false),
+    (0xc2f4, &[0x9a, 0xed], false),
+    (0xc2f5, &[0x9a, 0xee], false),
+    (0xc2f7, &[0x9a, 0xef], false),
+    (0xc2fa, &[0x9a, 0xf0], false),
+    (0xc2fd, &[0x9a, 0xf1], false),
+    (0xc2fe, &[0x9a, 0xf2], false),
+    (0xc2ff, &[0x9a, 0xf3], false),
+    (0xc301, &[0x9a, 0xf4], false),
+    (0xc302, &[0x9a, 0xf5], false),
+    (0xc303, &[0x9a, 0xf6], false),
+    (0xc304, &[0x9a, 0xf7], false),
+    (0xc305, &[0x9a, 0xf8], false),
+    (0xc306, &[0x9a, 0xf9], false),
+    (0xc307, &[0x9a, 0xfa], false),
+    (0xc30a, &[0x9a, 0xfb], false),
+    (0xc30b, &[0x9a, 0xfc], false),
+    (0xc30e, &[0x9a, 0xfd], false),
+    (0xc30f, &[0x9a, 0xfe], false),
+    (0xc310, &[0x9b, 0x41], false),
+    (0xc311, &[0x9b, 0x42], false),
+    (0xc312, &[0x9b, 0x43], false),
+    (0xc316, &[0x9b, 0x44], false),
+    (0xc317, &[0x9b, 0x45], false),
+    (0xc319, &[0x9b, 0x46], false),
+    (0xc31a, &[0x9b, 0x47], false),
+    (0xc31b, &[0x9b, 0x48], false),
+    (0xc31d, &[0x9b, 0x49], false),
+    (0xc31e, &[0x9b, 0x4a], false),
+    (0xc31f, &[0x9b, 0x4b], false),
+    (0xc320, &[0x9b, 0x4c], false),
+    (0xc321, &[0x9b, 0x4d], false),
+    (0xc322, &[0x9b, 0x4e], false),
+    (0xc323, &[0x9b, 0x4f], false),
+    (0xc326, &[0x9b, 0x50], false),
+    (0xc327, &[0x9b, 0x51], false),
+    (0xc32a, &[0x9b, 0x52], false),
+    (0xc32b, &[0x9b, 0x53], false),
+    (0xc32c, &[0x9b, 0x54], false),
+    (0xc32d, &[0x9b, 0x55], false),
+    (0xc32e, &[0x9b, 0x56], false),
+    (0xc32f, &[0x9b, 0x57], false),
+    (0xc330, &[0x9b, 0x58], false),
+    (0xc331, &[0x9b, 0x59], false),
+    (0xc332, &[0x9b, 0x5a], false),
+    (0xc333, &[0x9b, 0x61], false),
+    (0xc334, &[0x9b, 0x62], false),
+    (0xc335, &[0x9b, 0x63], false),
+    (0xc336, &[0x9b, 0x64], false),
+    (0xc337, &[0x9b, 0x65], false),
+    (0xc338, &[0x9b, 0x66], false),
+    (0xc339, &[0x9b, 0x67], false),
+    (0xc33a, &[0x9b, 0x68], false),
+    (0xc33b, &[0x9b, 0x69], false),
+    (0xc33c, &[0x9b, 0x6a], false),
+    (0xc33d, &[0x9b, 0x6b], false),
+    (0xc33e, &[0x9b, 0x6c], false),
+    (0xc33f, &[0x9b, 0x6d], false),
+    (0xc340, &[0x9b, 0x6e], false),
+    (0xc341, &[0x9b, 0x6f], false),
+    (0xc342, &[0x9b, 0x70], false),
+    (0xc343, &[0x9b, 0x71], false),
+    (0xc344, &[0x9b, 0x72], false),
+    (0xc346, &[0x9b, 0x73], false),
+    (0xc347, &[0x9b, 0x74], false),
+    (0xc348, &[0x9b, 0x75], false),
+    (0xc349, &[0x9b, 0x76], false),
+    (0xc34a, &[0x9b, 0x77], false),
+    (0xc34b, &[0x9b, 0x78], false),
+    (0xc34c, &[0x9b, 0x79], false),
+    (0xc34d, &[0x9b, 0x7a], false),
+    (0xc34e, &[0x9b, 0x81], false),
+    (0xc34f, &[0x9b, 0x82], false),
+    (0xc350, &[0x9b, 0x83], false),
+    (0xc351, &[0x9b, 0x84], false),
+    (0xc352, &[0x9b, 0x85], false),
+    (0xc353, &[0x9b, 0x86], false),
+    (0xc354, &[0x9b, 0x87], false),
+    (0xc355, &[0x9b, 0x88], false),
+    (0xc356, &[0x9b, 0x89], false),
+    (0xc357, &[0x9b, 0x8a], false),
+    (0xc358, &[0x9b, 0x8b], false),
+    (0xc359, &[0x9b, 0x8c], false),
+    (0xc35a, &[0x9b, 0x8d], false),
+    (0xc35b, &[0x9b, 0x8e], false),
+    (0xc35c, &[0x9b, 0x8f], false),
+    (0xc35d, &[0x9b, 0x90], false),
+    (0xc35e, &[0x9b, 0x91], false),
+    (0xc35f, &[0x9b, 0x92], false),
+    (0xc360, &[0x9b, 0x93], false),
+    (0xc361, &[0x9b, 0x94], false),
+    (0xc362, &[0x9b, 0x95], false),
+    (0xc363, &[0x9b, 0x96], false),
+    (0xc364, &[0x9b, 0x97], false),
+    (0xc365, &[0x9b, 0x98], false),
+    (0xc366, &[0x9b, 0x99], false),
+    (0xc367, &[0x9b, 0x9a], false),
+    (0xc36a, &[0x9b, 0x9b], false),
+    (0xc36b, &[0x9b, 0x9c], false),
+    (0xc36d, &[0x9b, 0x9d], false),
+    (0xc36e, &[0x9b, 0x9e], false),
+    (0xc36f, &[0x9b, 0x9f], false),
+    (0xc371, &[0x9b, 0xa0], false),
+    (0xc373, &[0x9b, 0xa1], false),
+    (0xc374, &[0x9b, 0xa2], false),
+    (0xc375, &[0x9b, 0xa3], false),
+    (0xc376, &[0x9b, 0xa4], false),
+    (0xc377, &[0x9b, 0xa5], false),
+    (0xc37a, &[0x9b, 0xa6], false),
+    (0xc37b, &[0x9b, 0xa7], false),
+    (0xc37e, &[0x9b, 0xa8], false),
+    (0xc37f, &[0x9b, 0xa9], false),
+    (0xc380, &[0x9b, 0xaa], false),
+    (0xc381, &[0x9b, 0xab], false),
+    (0xc382, &[0x9b, 0xac], false),
+    (0xc383, &[0x9b, 0xad], false),
+    (0xc385, &[0x9b, 0xae], false),
+    (0xc386, &[0x9b, 0xaf], false),
+    (0xc387, &[0x9b, 0xb0], false),
+    (0xc389, &[0x9b, 0xb1], false),
+    (0xc38a, &[0x9b, 0xb2], false),
+    (0xc38b, &[0x9b, 0xb3], false),
+    (0xc38d, &[0x9b, 0xb4], false),
+    (0xc38e, &[0x9b, 0xb5], false),
+    (0xc38f, &[0x9b, 0xb6], false),
+    (0xc390, &[0x9b, 0xb7], false),
+    (0xc391, &[0x9b, 0xb8], false),
+    (0xc392, &[0x9b, 0xb9], false),
+    (0xc393, &[0x9b, 0xba], false),
+    (0xc394, &[0x9b, 0xbb], false),
+    (0xc395, &[0x9b, 0xbc], false),
+    (0xc396, &[0x9b, 0xbd], false),
+    (0xc397, &[0x9b, 0xbe], false),
+    (0xc398, &[0x9b, 0xbf], false),
+    (0xc399, &[0x9b, 0xc0], false),
+    (0xc39a, &[0x9b, 0xc1], false),
+    (0xc39b, &[0x9b, 0xc2], false),
+    (0xc39c, &[0x9b, 0xc3], false),
+    (0xc39d, &[0x9b, 0xc4], false),
+    (0xc39e, &[0x9b, 0xc5], false),
+    (0xc39f, &[0x9b, 0xc6], false),
+    (0xc3a0, &[0x9b, 0xc7], false),
+    (0xc3a1, &[0x9b, 0xc8], false),
+    (0xc3a2, &[0x9b, 0xc9], false),
+    (0xc3a3, &[0x9b, 0xca], false),
+    (0xc3a4, &[0x9b, 0xcb], false),
+    (0xc3a5, &[0x9b, 0xcc], false),
+    (0xc3a6, &[0x9b, 0xcd], false),
+    (0xc3a7, &[0x9b, 0xce], false),
+    (0xc3a8, &[0x9b, 0xcf], false),
+    (0xc3a9, &[0x9b, 0xd0], false),
+    (0xc3aa, &[0x9b, 0xd1], false),
+    (0xc3ab, &[0x9b, 0xd2], false),
+    (0xc3ac, &[0x9b, 0xd3], false),
+    (0xc3ad, &[0x9b, 0xd4], false),
+    (0xc3ae, &[0x9b, 0xd5], false),
+    (0xc3af, &[0x9b, 0xd6], false),
+    (0xc3b0, &[0x9b, 0xd7], false),
+    (0xc3b1, &[0x9b, 0xd8], false),
+    (0xc3b2, &[0x9b, 0xd9], false),
+    (0xc3b3, &[0x9b, 0xda], false),
+    (0xc3b4, &[0x9b, 0xdb], false),
+    (0xc3b5, &[0x9b, 0xdc], false),
+    (0xc3b6, &[0x9b, 0xdd], false),
+    (0xc3b7, &[0x9b, 0xde], false),
+    (0xc3b8, &[0x9b, 0xdf], false),
+    (0xc3b9, &[0x9b, 0xe0], false),
+    (0xc3ba, &[0x9b, 0xe1], false),
+    (0xc3bb, &[0x9b, 0xe2], false),
+    (0xc3bc, &[0x9b, 0xe3], false),
+    (0xc3bd, &[0x9b, 0xe4], false),
+    (0xc3be, &[0x9b, 0xe5], false),
+    (0xc3bf, &[0x9b, 0xe6], false),
+    (0xc3c1, &[0x9b, 0xe7], false),
+    (0xc3c2, &[0x9b, 0xe8], false),
+    (0xc3c3, &[0x9b, 0xe9], false),
+    (0xc3c4, &[0x9b, 0xea], false),
+    (0xc3c5, &[0x9b, 0xeb], false),
+    (0xc3c6, &[0x9b, 0xec], false),
+    (0xc3c7, &[0x9b, 0xed], false),
+    (0xc3c8, &[0x9b, 0xee], false),
+    (0xc3c9, &[0x9b, 0xef], false),
+    (0xc3ca, &[0x9b, 0xf0], false),
+    (0xc3cb, &[0x9b, 0xf1], false),
+    (0xc3cc, &[0x9b, 0xf2], false),
+    (0xc3cd, &[0x9b, 0xf3], false),
+    (0xc3ce, &[0x9b, 0xf4], false),
+    (0xc3cf, &[0x9b, 0xf5], false),
+    (0xc3d0, &[0x9b, 0xf6], false),
+    (0xc3d1, &[0x9b, 0xf7], false),
+    (0xc3d2, &[0x9b, 0xf8], false),
+    (0xc3d3, &[0x9b, 0xf9], false),
+    (0xc3d4, &[0x9b, 0xfa], false),
+    (0xc3d5, &[0x9b, 0xfb], false),
+    (0xc3d6, &[0x9b, 0xfc], false),
+    (0xc3d7, &[0x9b, 0xfd], false),
+    (0xc3da, &[0x9b, 0xfe], false),
+    (0xc3db, &[0x9c, 0x41], false),
+    (0xc3dd, &[0x9c, 0x42], false),
+    (0xc3de, &[0x9c, 0x43], false),
+    (0xc3e1, &[0x9c, 0x44], false),
+    (0xc3e3, &[0x9c, 0x45], false),
+    (0xc3e4, &[0x9c, 0x46], false),
+    (0xc3e5, &[0x9c, 0x47], false),
+    (0xc3e6, &[0x9c, 0x48], false),
+    (0xc3e7, &[0x9c, 0x49], false),
+    (0xc3ea, &[0x9c, 0x4a], false),
+    (0xc3eb, &[0x9c, 0x4b], false),
+    (0xc3ec, &[0x9c, 0x4c], false),
+    (0xc3ee, &[0x9c, 0x4d], false),
+    (0xc3ef, &[0x9c, 0x4e], false),
+    (0xc3f0, &[0x9c, 0x4f], false),
+    (0xc3f1, &[0x9c, 0x50], false),
+    (0xc3f2, &[0x9c, 0x51], false),
+    (0xc3f3, &[0x9c, 0x52], false),
+    (0xc3f6, &[0x9c, 0x53], false),
+    (0xc3f7, &[0x9c, 0x54], false),
+    (0xc3f9, &[0x9c, 0x55], false),
+    (0xc3fa, &[0x9c, 0x56], false),
+    (0xc3fb, &[0x9c, 0x57], false),
+    (0xc3fc, &[0x9c, 0x58], false),
+    (0xc3fd, &[0x9c, 0x59], false),
+    (0xc3fe, &[0x9c, 0x5a], false),
+    (0xc3ff, &[0x9c, 0x61], false),
+    (0xc400, &[0x9c, 0x62], false),
+    (0xc401, &[0x9c, 0x63], false),
+    (0xc402, &[0x9c, 0x64], false),
+    (0xc403, &[0x9c, 0x65], false),
+    (0xc404, &[0x9c, 0x66], false),
+    (0xc405, &[0x9c, 0x67], false),
+    (0xc406, &[0x9c, 0x68], false),
+    (0xc407, &[0x9c, 0x69], false),
+    (0xc409, &[0x9c, 0x6a], false),
+    (0xc40a, &[0x9c, 0x6b], false),
+    (0xc40b, &[0x9c, 0x6c], false),
+    (0xc40c, &[0x9c, 0x6d], false),
+    (0xc40d, &[0x9c, 0x6e], false),
+    (0xc40e, &[0x9c, 0x6f], false),
+    (0xc40f, &[0x9c, 0x70], false),
+    (0xc411, &[0x9c, 0x71], false),
+    (0xc412, &[0x9c, 0x72], false),
+    (0xc413, &[0x9c, 0x73], false),
+    (0xc414, &[0x9c, 0x74], false),
+    (0xc415, &[0x9c, 0x75], false),
+    (0xc416, &[0x9c, 0x76], false),
+    (0xc417, &[0x9c, 0x77], false),
+    (0xc418, &[0x9c, 0x78], false),
+    (0xc419, &[0x9c, 0x79], false),
+    (0xc41a, &[0x9c, 0x7a], false),
+    (0xc41b, &[0x9c, 0x81], false),
+    (0xc41c, &[0x9c, 0x82], false),
+    (0xc41d, &[0x9c, 0x83], false),
+    (0xc41e, &[0x9c, 0x84], false),
+    (0xc41f, &[0x9c, 0x85], false),
+    (0xc420, &[0x9c, 0x86], false),
+    (0xc421, &[0x9c, 0x87], false),
+    (0xc422, &[0x9c, 0x88], false),
+    (0xc423, &[0x9c, 0x89], false),
+    (0xc425, &[0x9c, 0x8a], false),
+    (0xc426, &[0x9c, 0x8b], false),
+    (0xc427, &[0x9c, 0x8c], false),
+    (0xc428, &[0x9c, 0x8d], false),
+    (0xc429, &[0x9c, 0x8e], false),
+    (0xc42a, &[0x9c, 0x8f], false),
+    (0xc42b, &[0x9c, 0x90], false),
+    (0xc42d, &[0x9c, 0x91], false),
+    (0xc42e, &[0x9c, 0x92], false),
+    (0xc42f, &[0x9c, 0x93], false),
+    (0xc431, &[0x9c, 0x94], false),
+    (0xc432, &[0x9c, 0x95], false),
+    (0xc433, &[0x9c, 0x96], false),
+    (0xc435, &[0x9c, 0x97], false),
+    (0xc436, &[0x9c, 0x98], false),
+    (0xc437, &[0x9c, 0x99], false),
+    (0xc438, &[0x9c, 0x9a], false),
+    (0xc439, &[0x9c, 0x9b], false),
+    (0xc43a, &[0x9c, 0x9c], false),
+    (0xc43b, &[0x9c, 0x9d], false),
+    (0xc43e, &[0x9c, 0x9e], false),
+    (0xc43f, &[0x9c, 0x9f], false),
+    (0xc440, &[0x9c, 0xa0], false),
+    (0xc441, &[0x9c, 0xa1], false),
+    (0xc442, &[0x9c, 0xa2], false),
+    (0xc443, &[0x9c, 0xa3], false),
+    (0xc444, &[0x9c, 0xa4], false),
+    (0xc445, &[0x9c, 0xa5], false),
+    (0xc446, &[0x9c, 0xa6], false),
+    (0xc447, &[0x9c, 0xa7], false),
+    (0xc449, &[0x9c, 0xa8], false),
+    (0xc44a, &[0x9c, 0xa9], false),
+    (0xc44b, &[0x9c, 0xaa], false),
+    (0xc44c, &[0x9c, 0xab], false),
+    (0xc44d, &[0x9c, 0xac], false),
+    (0xc44e, &[0x9c, 0xad], false),
+    (0xc44f, &[0x9c, 0xae], false),
+    (0xc450, &[0x9c, 0xaf], false),
+    (0xc451, &[0x9c, 0xb0], false),
+    (0xc452, &[0x9c, 0xb1], false),
+    (0xc453, &[0x9c, 0xb2], false),
+    (0xc454, &[0x9c, 0xb3], false),
+    (0xc455, &[0x9c, 0xb4], false),
+    (0xc456, &[0x9c, 0xb5], false),
+    (0xc457, &[0x9c, 0xb6], false),
+    (0xc458, &[0x9c, 0xb7], false),
+    (0xc459, &[0x9c, 0xb8], false),
+    (0xc45a, &[0x9c, 0xb9], false),
+    (0xc45b, &[0x9c, 0xba], false),
+    (0xc45c, &[0x9c, 0xbb], false),
+    (0xc45d, &[0x9c, 0xbc], false),
+    (0xc45e, &[0x9c, 0xbd], false),
+    (0xc45f, &[0x9c, 0xbe], false),
+    (0xc460, &[0x9c, 0xbf], false),
+    (0xc461, &[0x9c, 0xc0], false),
+    (0xc462, &[0x9c, 0xc1], false),
+    (0xc463, &[0x9c, 0xc2], false),
+    (0xc466, &[0x9c, 0xc3], false),
+    (0xc467, &[0x9c, 0xc4], false),
+    (0xc469, &[0x9c, 0xc5], false),
+    (0xc46a, &[0x9c, 0xc6], false),
+    (0xc46b, &[0x9c, 0xc7], false),
+    (0xc46d, &[0x9c, 0xc8], false),
+    (0xc46e, &[0x9c, 0xc9], false),
+    (0xc46f, &[0x9c, 0xca], false),
+    (0xc470, &[0x9c, 0xcb], false),
+    (0xc471, &[0x9c, 0xcc], false),
+    (0xc472, &[0x9c, 0xcd], false),
+    (0xc473, &[0x9c, 0xce], false),
+    (0xc476, &[0x9c, 0xcf], false),
+    (0xc477, &[0x9c, 0xd0], false),
+    (0xc478, &[0x9c, 0xd1], false),
+    (0xc47a, &[0x9c, 0xd2], false),
+    (0xc47b, &[0x9c, 0xd3], false),
+    (0xc47c, &[0x9c, 0xd4], false),
+    (0xc47d, &[0x9c, 0xd5], false),
+    (0xc47e, &[0x9c, 0xd6], false),
+    (0xc47f, &[0x9c, 0xd7], false),
+    (0xc481, &[0x9c, 0xd8], false),
+    (0xc482, &[0x9c, 0xd9], false),
+    (0xc483, &[0x9c, 0xda], false),
+    (0xc484, &[0x9c, 0xdb], false),
+    (0xc485, &[0x9c, 0xdc], false),
+    (0xc486, &[0x9c, 0xdd], false),
+    (0xc487, &[0x9c, 0xde], false),
+    (0xc488, &[0x9c, 0xdf], false),
+    (0xc489, &[0x9c, 0xe0], false),
+    (0xc48a, &[0x9c, 0xe1], false),
+    (0xc48b, &[0x9c, 0xe2], false),
+    (0xc48c, &[0x9c, 0xe3], false),
+    (0xc48d, &[0x9c, 0xe4], false),
+    (0xc48e, &[0x9c, 0xe5], false),
+    (0xc48f, &[0x9c, 0xe6], false),
+    (0xc490, &[0x9c, 0xe7], false),
+    (0xc491, &[0x9c, 0xe8], false),
+    (0xc492, &[0x9c, 0xe9], false),
+    (0xc493, &[0x9c, 0xea], false),
+    (0xc495, &[0x9c, 0xeb], false),
+    (0xc496, &[0x9c, 0xec], false),
+    (0xc497, &[0x9c, 0xed], false),
+    (0xc498, &[0x9c, 0xee], false),
+    (0xc499, &[0x9c, 0xef], false),
+    (0xc49a, &[0x9c, 0xf0], false),
+    (0xc49b, &[0x9c, 0xf1], false),
+    (0xc49d, &[0x9c, 0xf2], false),
+    (0xc49e, &[0x9c, 0xf3], false),
+    (0xc49f, &[0x9c, 0xf4], false),
+    (0xc4a0, &[0x9c, 0xf5], false),
+    (0xc4a1, &[0x9c, 0xf6], false),
+    (0xc4a2, &[0x9c, 0xf7], false),
+    (0xc4a3, &[0x9c, 0xf8], false),
+    (0xc4a4, &[0x9c, 0xf9], false),
+    (0xc4a5, &[0x9c, 0xfa], false),
+    (0xc4a6, &[0x9c, 0xfb], false),
+    (0xc4a7, &[0x9c, 0xfc], false),
+    (0xc4a8, &[0x9c, 0xfd], false),
+    (0xc4a9, &[0x9c, 0xfe], false),
+    (0xc4aa, &[0x9d, 0x41], false),
+    (0xc4ab, &[0x9d, 0x42], false),
+    (0xc4ac, &[0x9d, 0x43], false),
+    (0xc4ad, &[0x9d, 0x44], false),
+    (0xc4ae, &[0x9d, 0x45], false),
+    (0xc4af, &[0x9d, 0x46], false),
+    (0xc4b0, &[0x9d, 0x47], false),
+    (0xc4b1, &[0x9d, 0x48], false),
+    (0xc4b2, &[0x9d, 0x49], false),
+    (0xc4b3, &[0x9d, 0x4a], false),
+    (0xc4b4, &[0x9d, 0x4b], false),
+    (0xc4b5, &[0x9d, 0x4c], false),
+    (0xc4b6, &[0x9d, 0x4d], false),
+    (0xc4b7, &[0x9d, 0x4e], false),
+    (0xc4b9, &[0x9d, 0x4f], false),
+    (0xc4ba, &[0x9d, 0x50], false),
+    (0xc4bb, &[0x9d, 0x51], false),
+    (0xc4bd, &[0x9d, 0x52], false),
+    (0xc4be, &[0x9d, 0x53], false),
+    (0xc4bf, &[0x9d, 0x54], false),
+    (0xc4c0, &[0x9d, 0x55], false),
+    (0xc4c1, &[0x9d, 0x56], false),
+    (0xc4c2, &[0x9d, 0x57], false),
+    (0xc4c3, &[0x9d, 0x58], false),
+    (0xc4c4, &[0x9d, 0x59], false),
+    (0xc4c5, &[0x9d, 0x5a], false),
+    (0xc4c6, &[0x9d, 0x61], false),
+    (0xc4c7, &[0x9d, 0x62], false),
+    (0xc4c8, &[0x9d, 0x63], false),
+    (0xc4c9, &[0x9d, 0x64], false),
+    (0xc4ca, &[0x9d, 0x65], false),
+    (0xc4cb, &[0x9d, 0x66], false),
+    (0xc4cc, &[0x9d, 0x67], false),
+    (0xc4cd, &[0x9d, 0x68], false),
+    (0xc4ce, &[0x9d, 0x69], false),
+    (0xc4cf, &[0x9d, 0x6a], false),
+    (0xc4d0, &[0x9d, 0x6b], false),
+    (0xc4d1, &[0x9d, 0x6c], false),
+    (0xc4d2, &[0x9d, 0x6d], false),
+    (0xc4d3, &[0x9d, 0x6e], false),
+    (0xc4d4, &[0x9d, 0x6f], false),
+    (0xc4d5, &[0x9d, 0x70], false),
+    (0xc4d6, &[0x9d, 0x71], false),
+    (0xc4d7, &[0x9d, 0x72], false),
+    (0xc4d8, &[0x9d, 0x73], false),
+    (0xc4d9, &[0x9d, 0x74], false),
+    (0xc4da, &[0x9d, 0x75], false),
+    (0xc4db, &[0x9d, 0x76], false),
+    (0xc4dc, &[0x9d, 0x77], false),
+    (0xc4dd, &[0x9d, 0x78], false),
+    (0xc4de, &[0x9d, 0x79], false),
+    (0xc4df, &[0x9d, 0x7a], false),
+    (0xc4e0, &[0x9d, 0x81], false),
+    (0xc4e1, &[0x9d, 0x82], false),
+    (0xc4e2, &[0x9d, 0x83], false),
+    (0xc4e3, &[0x9d, 0x84], false),
+    (0xc4e4, &[0x9d, 0x85], false),
+    (0xc4e5, &[0x9d, 0x86], false),
+    (0xc4e6, &[0x9d, 0x87], false),
+    (0xc4e7, &[0x9d, 0x88], false),
+    (0xc4e8, &[0x9d, 0x89], false),
+    (0xc4ea, &[0x9d, 0x8a], false),
+    (0xc4eb, &[0x9d, 0x8b], false),
+    (0xc4ec, &[0x9d, 0x8c], false),
+    (0xc4ed, &[0x9d, 0x8d], false),
+    (0xc4ee, &[0x9d, 0x8e], false),
+    (0xc4ef, &[0x9d, 0x8f], false),
+    (0xc4f2, &[0x9d, 0x90], false),
+    (0xc4f3, &[0x9d, 0x91], false),
+    (0xc4f5, &[0x9d, 0x92], false),
+    (0xc4f6, &[0x9d, 0x93], false),
+    (0xc4f7, &[0x9d, 0x94], false),
+    (0xc4f9, &[0x9d, 0x95], false),
+    (0xc4fb, &[0x9d, 0x96], false),
+    (0xc4fc, &[0x9d, 0x97], false),
+    (0xc4fd, &[0x9d, 0x98], false),
+    (0xc4fe, &[0x9d, 0x99], false),
+    (0xc502, &[0x9d, 0x9a], false),
+    (0xc503, &[0x9d, 0x9b], false),
+    (0xc504, &[0x9d, 0x9c], false),
+    (0xc505, &[0x9d, 0x9d], false),
+    (0xc506, &[0x9d, 0x9e], false),
+    (0xc507, &[0x9d, 0x9f], false),
+    (0xc508, &[0x9d, 0xa0], false),
+    (0xc509, &[0x9d, 0xa1], false),
+    (0xc50a, &[0x9d, 0xa2], false),
+    (0xc50b, &[0x9d, 0xa3], false),
+    (0xc50d, &[0x9d, 0xa4], false),
+    (0xc50e, &[0x9d, 0xa5], false),
+    (0xc50f, &[0x9d, 0xa6], false),
+    (0xc511, &[0x9d, 0xa7], false),
+    (0xc512, &[0x9d, 0xa8], false),
+    (0xc513, &[0x9d, 0xa9], false),
+    (0xc515, &[0x9d, 0xaa], false),
+    (0xc516, &[0x9d, 0xab], false),
+    (0xc517, &[0x9d, 0xac], false),
+    (0xc518, &[0x9d, 0xad], false),
+    (0xc519, &[0x9d, 0xae], false),
+    (0xc51a, &[0x9d, 0xaf], false),
+    (0xc51b, &[0x9d, 0xb0], false),
+    (0xc51d, &[0x9d, 0xb1], false),
+    (0xc51e, &[0x9d, 0xb2], false),
+    (0xc51f, &[0x9d, 0xb3], false),
+    (0xc520, &[0x9d, 0xb4], false),
+    (0xc521, &[0x9d, 0xb5], false),
+    (0xc522, &[0x9d, 0xb6], false),
+    (0xc523, &[0x9d, 0xb7], false),
+    (0xc524, &[0x9d, 0xb8], false),
+    (0xc525, &[0x9d, 0xb9], false),
+    (0xc526, &[0x9d, 0xba], false),
+    (0xc527, &[0x9d, 0xbb], false),
+    (0xc52a, &[0x9d, 0xbc], false),
+    (0xc52b, &[0x9d, 0xbd], false),
+    (0xc52d, &[0x9d, 0xbe], false),
+    (0xc52e, &[0x9d, 0xbf], false),
+    (0xc52f, &[0x9d, 0xc0], false),
+    (0xc531, &[0x9d, 0xc1], false),
+    (0xc532, &[0x9d, 0xc2], false),
+    (0xc533, &[0x9d, 0xc3], false),
+    (0xc534, &[0x9d, 0xc4], false),
+    (0xc535, &[0x9d, 0xc5], false),
+    (0xc536, &[0x9d, 0xc6], false),
+    (0xc537, &[0x9d, 0xc7], false),
+    (0xc53a, &[0x9d, 0xc8], false),
+    (0xc53c, &[0x9d, 0xc9], false),
+    (0xc53e, &[0x9d, 0xca], false),
+    (0xc53f, &[0x9d, 0xcb], false),
+    (0xc540, &[0x9d, 0xcc], false),
+    (0xc541, &[0x9d, 0xcd], false),
+    (0xc542, &[0x9d, 0xce], false),
+    (0xc543, &[0x9d, 0xcf], false),
+    (0xc546, &[0x9d, 0xd0], false),
+    (0xc547, &[0x9d, 0xd1], false),
+    (0xc54b, &[0x9d, 0xd2], false),
+    (0xc54f, &[0x9d, 0xd3], false),
+    (0xc550, &[0x9d, 0xd4], false),
+    (0xc551, &[0x9d, 0xd5], false),
+    (0xc552, &[0x9d, 0xd6], false),
+    (0xc556, &[0x9d, 0xd7], false),
+    (0xc55a, &[0x9d, 0xd8], false),
+    (0xc55b, &[0x9d, 0xd9], false),
+    (0xc55c, &[0x9d, 0xda], false),
+    (0xc55f, &[0x9d, 0xdb], false),
+    (0xc562, &[0x9d, 0xdc], false),
+    (0xc563, &[0x9d, 0xdd], false),
+    (0xc565, &[0x9d, 0xde], false),
+    (0xc566, &[0x9d, 0xdf], false),
+    (0xc567, &[0x9d, 0xe0], false),
+    (0xc569, &[0x9d, 0xe1], false),
+    (0xc56a, &[0x9d, 0xe2], false),
+    (0xc56b, &[0x9d, 0xe3], false),
+    (0xc56c, &[0x9d, 0xe4], false),
+    (0xc56d, &[0x9d, 0xe5], false),
+    (0xc56e, &[0x9d, 0xe6], false),
+    (0xc56f, &[0x9d, 0xe7], false),
+    (0xc572, &[0x9d, 0xe8], false),
+    (0xc576, &[0x9d, 0xe9], false),
+    (0xc577, &[0x9d, 0xea], false),
+    (0xc578, &[0x9d, 0xeb], false),
+    (0xc579, &[0x9d, 0xec], false),
+    (0xc57a, &[0x9d, 0xed], false),
+    (0xc57b, &[0x9d, 0xee], false),
+    (0xc57e, &[0x9d, 0xef], false),
+    (0xc57f, &[0x9d, 0xf0], false),
+    (0xc581, &[0x9d, 0xf1], false),
+    (0xc582, &[0x9d, 0xf2], false),
+    (0xc583, &[0x9d, 0xf3], false),
+    (0xc585, &[0x9d, 0xf4], false),
+    (0xc586, &[0x9d, 0xf5], false),
+    (0xc588, &[0x9d, 0xf6], false),
+    (0xc589, &[0x9d, 0xf7], false),
+    (0xc58a, &[0x9d, 0xf8], false),
+    (0xc58b, &[0x9d, 0xf9], false),
+    (0xc58e, &[0x9d, 0xfa], false),
+    (0xc590, &[0x9d, 0xfb], false),
+    (0xc592, &[0x9d, 0xfc], false),
+    (0xc593, &[0x9d, 0xfd], false),
+    (0xc594, &[0x9d, 0xfe], false),
+    (0xc596, &[0x9e, 0x41], false),
+    (0xc599, &[0x9e, 0x42], false),
+    (0xc59a, &[0x9e, 0x43], false),
+    (0xc59b, &[0x9e, 0x44], false),
+    (0xc59d, &[0x9e, 0x45], false),
+    (0xc59e, &[0x9e, 0x46], false),
+    (0xc59f, &[0x9e, 0x47], false),
+    (0xc5a1, &[0x9e, 0x48], false),
+    (0xc5a2, &[0x9e, 0x49], false),
+    (0xc5a3, &[0x9e, 0x4a], false),
+    (0xc5a4, &[0x9e, 0x4b], false),
+    (0xc5a5, &[0x9e, 0x4c], false),
+    (0xc5a6, &[0x9e, 0x4d], false),
+    (0xc5a7, &[0x9e, 0x4e], false),
+    (0xc5a8, &[0x9e, 0x4f], false),
+    (0xc5aa, &[0x9e, 0x50], false),
+    (0xc5ab, &[0x9e, 0x51], false),
+    (0xc5ac, &[0x9e, 0x52], false),
+    (0xc5ad, &[0x9e, 0x53], false),
+    (0xc5ae, &[0x9e, 0x54], false),
+    (0xc5af, &[0x9e, 0x55], false),
+    (0xc5b0, &[0x9e, 0x56], false),
+    (0xc5b1, &[0x9e, 0x57], false),
+    (0xc5b2, &[0x9e, 0x58], false),
+    (0xc5b3, &[0x9e, 0x59], false),
+    (0xc5b6, &[0x9e, 0x5a], false),
+    (0xc5b7, &[0x9e, 0x61], false),
+    (0xc5ba, &[0x9e, 0x62], false),
+    (0xc5bf, &[0x9e, 0x63], false),
+    (0xc5c0, &[0x9e, 0x64], false),
+    (0xc5c1, &[0x9e, 0x65], false),
+    (0xc5c2, &[0x9e, 0x66], false),
+    (0xc5c3, &[0x9e, 0x67], false),
+    (0xc5cb, &[0x9e, 0x68], false),
+    (0xc5cd, &[0x9e, 0x69], false),
+    (0xc5cf, &[0x9e, 0x6a], false),
+    (0xc5d2, &[0x9e, 0x6b], false),
+    (0xc5d3, &[0x9e, 0x6c], false),
+    (0xc5d5, &[0x9e, 0x6d], false),
+    (0xc5d6, &[0x9e, 0x6e], false),
+    (0xc5d7, &[0x9e, 0x6f], false),
+    (0xc5d9, &[0x9e, 0x70], false),
+    (0xc5da, &[0x9e, 0x71], false),
+    (0xc5db, &[0x9e, 0x72], false),
+    (0xc5dc, &[0x9e, 0x73], false),
+    (0xc5dd, &[0x9e, 0x74], false),
+    (0xc5de, &[0x9e, 0x75], false),
+    (0xc5df, &[0x9e, 0x76], false),
+    (0xc5e2, &[0x9e, 0x77], false),
+    (0xc5e4, &[0x9e, 0x78], false),
+    (0xc5e6, &[0x9e, 0x79], false),
+    (0xc5e7, &[0x9e, 0x7a], false),
+    (0xc5e8, &[0x9e, 0x81], false),
+    (0xc5e9, &[0x9e, 0x82], false),
+    (0xc5ea, &[0x9e, 0x83], false),
+    (0xc5eb, &[0x9e, 0x84], false),
+    (0xc5ef, &[0x9e, 0x85], false),
+    (0xc5f1, &[0x9e, 0x86], false),
+    (0xc5f2, &[0x9e, 0x87], false),
+    (0xc5f3, &[0x9e, 0x88], false),
+    (0xc5f5, &[0x9e, 0x89], false),
+    (0xc5f8, &[0x9e, 0x8a], false),
+    (0xc5f9, &[0x9e, 0x8b], false),
+    (0xc5fa, &[0x9e, 0x8c], false),
+    (0xc5fb, &[0x9e, 0x8d], false),
+    (0xc602, &[0x9e, 0x8e], false),
+    (0xc603, &[0x9e, 0x8f], false),
+    (0xc604, &[0x9e, 0x90], false),
+    (0xc609, &[0x9e, 0x91], false),
+    (0xc60a, &[0x9e, 0x92], false),
+    (0xc60b, &[0x9e, 0x93], false),
+    (0xc60d, &[0x9e, 0x94], false),
+    (0xc60e, &[0x9e, 0x95], false),
+    (0xc60f, &[0x9e, 0x96], false),
+    (0xc611, &[0x9e, 0x97], false),
+    (0xc612, &[0x9e, 0x98], false),
+    (0xc613, &[0x9e, 0x99], false),
+    (0xc614, &[0x9e, 0x9a], false),
+    (0xc615, &[0x9e, 0x9b], false),
+    (0xc616, &[0x9e, 0x9c], false),
+    (0xc617, &[0x9e, 0x9d], false),
+    (0xc61a, &[0x9e, 0x9e], false),
+    (0xc61d, &[0x9e, 0x9f], false),
+    (0xc61e, &[0x9e, 0xa0], false),
+    (0xc61f, &[0x9e, 0xa1], false),
+    (0xc620, &[0x9e, 0xa2], false),
+    (0xc621, &[0x9e, 0xa3], false),
+    (0xc622, &[0x9e, 0xa4], false),
+    (0xc623, &[0x9e, 0xa5], false),
+    (0xc626, &[0x9e, 0xa6], false),
+    (0xc627, &[0x9e, 0xa7], false),
+    (0xc629, &[0x9e, 0xa8], false),
+    (0xc62a, &[0x9e, 0xa9], false),
+    (0xc62b, &[0x9e, 0xaa], false),
+    (0xc62f, &[0x9e, 0xab], false),
+    (0xc631, &[0x9e, 0xac], false),
+    (0xc632, &[0x9e, 0xad], false),
+    (0xc636, &[0x9e, 0xae], false),
+    (0xc638, &[0x9e, 0xaf], false),
+    (0xc63a, &[0x9e, 0xb0], false),
+    (0xc63c, &[0x9e, 0xb1], false),
+    (0xc63d, &[0x9e, 0xb2], false),
+    (0xc63e, &[0x9e, 0xb3], false),
+    (0xc63f, &[0x9e, 0xb4], false),
+    (0xc642, &[0x9e, 0xb5], false),
+    (0xc643, &[0x9e, 0xb6], false),
+    (0xc645, &[0x9e, 0xb7], false),
+    (0xc646, &[0x9e, 0xb8], false),
+    (0xc647, &[0x9e, 0xb9], false),
+    (0xc649, &[0x9e, 0xba], false),
+    (0xc64a, &[0x9e, 0xbb], false),
+    (0xc64b, &[0x9e, 0xbc], false),
+    (0xc64c, &[0x9e, 0xbd], false),
+    (0xc64d, &[0x9e, 0xbe], false),
+    (0xc64e, &[0x9e, 0xbf], false),
+    (0xc64f, &[0x9e, 0xc0], false),
+    (0xc652, &[0x9e, 0xc1], false),
+    (0xc656, &[0x9e, 0xc2], false),
+    (0xc657, &[0x9e, 0xc3], false),
+    (0xc658, &[0x9e, 0xc4], false),
+    (0xc659, &[0x9e, 0xc5], false),
+    (0xc65a, &[0x9e, 0xc6], false),
+    (0xc65b, &[0x9e, 0xc7], false),
+    (0xc65e, &[0x9e, 0xc8], false),
+    (0xc65f, &[0x9e, 0xc9], false),
+    (0xc661, &[0x9e, 0xca], false),
+    (0xc662, &[0x9e, 0xcb], false),
+    (0xc663, &[0x9e, 0xcc], false),
+    (0xc664, &[0x9e, 0xcd], false),
+    (0xc665, &[0x9e, 0xce], false),
+    (0xc666, &[0x9e, 0xcf], false),
+    (0xc667, &[0x9e, 0xd0], false),
+    (0xc668, &[0x9e, 0xd1], false),
+    (0xc669, &[0x9e, 0xd2], false),
+    (0xc66a, &[0x9e, 0xd3], false),
+    (0xc66b, &[0x9e, 0xd4], false),
+    (0xc66d, &[0x9e, 0xd5], false),
+    (0xc66e, &[0x9e, 0xd6], false),
+    (0xc670, &[0x9e, 0xd7], false),
+    (0xc672, &[0x9e, 0xd8], false),
+    (0xc673, &[0x9e, 0xd9], false),
+    (0xc674, &[0x9e, 0xda], false),
+    (0xc675, &[0x9e, 0xdb], false),
+    (0xc676, &[0x9e, 0xdc], false),
+    (0xc677, &[0x9e, 0xdd], false),
+    (0xc67a, &[0x9e, 0xde], false),
+    (0xc67b, &[0x9e, 0xdf], false),
+    (0xc67d, &[0x9e, 0xe0], false),
+    (0xc67e, &[0x9e, 0xe1], false),
+    (0xc67f, &[0x9e, 0xe2], false),
+    (0xc681, &[0x9e, 0xe3], false),
+    (0xc682, &[0x9e, 0xe4], false),
+    (0xc683, &[0x9e, 0xe5], false),
+    (0xc684, &[0x9e, 0xe6], false),
+    (0xc685, &[0x9e, 0xe7], false),
+    (0xc686, &[0x9e, 0xe8], false),
+    (0xc687, &[0x9e, 0xe9], false),
+    (0xc68a, &[0x9e, 0xea], false),
+    (0xc68c, &[0x9e, 0xeb], false),
+    (0xc68e, &[0x9e, 0xec], false),
+    (0xc68f, &[0x9e, 0xed], false),
+    (0xc690, &[0x9e, 0xee], false),
+    (0xc691, &[0x9e, 0xef], false),
+    (0xc692, &[0x9e, 0xf0], false),
+    (0xc693, &[0x9e, 0xf1], false),
+    (0xc696, &[0x9e, 0xf2], false),
+    (0xc697, &[0x9e, 0xf3], false),
+    (0xc699, &[0x9e, 0xf4], false),
+    (0xc69a, &[0x9e, 0xf5], false),
+    (0xc69b, &[0x9e, 0xf6], false),
+    (0xc69d, &[0x9e, 0xf7], false),
+    (0xc69e, &[0x9e, 0xf8], false),
+    (0xc69f, &[0x9e, 0xf9], false),
+    (0xc6a0, &[0x9e, 0xfa], false),
+    (0xc6a1, &[0x9e, 0xfb], false),
+    (0xc6a2, &[0x9e, 0xfc], false),
+    (0xc6a3, &[0x9e, 0xfd], false),
+    (0xc6a6, &[0x9e, 0xfe], false),
+    (0xc6a8, &[0x9f, 0x41], false),
+    (0xc6aa, &[0x9f, 0x42], false),
+    (0xc6ab, &[0x9f, 0x43], false),
+    (0xc6ac, &[0x9f, 0x44], false),
+    (0xc6ad, &[0x9f, 0x45], false),
+    (0xc6ae, &[0x9f, 0x46], false),
+    (0xc6af, &[0x9f, 0x47], false),
+    (0xc6b2, &[0x9f, 0x48], false),
+    (0xc6b3, &[0x9f, 0x49], false),
+    (0xc6b5, &[0x9f, 0x4a], false),
+    (0xc6b6, &[0x9f, 0x4b], false),
+    (0xc6b7, &[0x9f, 0x4c], false),
+    (0xc6bb, &[0x9f, 0x4d], false),
+    (0xc6bc, &[0x9f, 0x4e], false),
+    (0xc6bd, &[0x9f, 0x4f], false),
+    (0xc6be, &[0x9f, 0x50], false),
+    (0xc6bf, &[0x9f, 0x51], false),
+    (0xc6c2, &[0x9f, 0x52], false),
+    (0xc6c4, &[0x9f, 0x53], false),
+    (0xc6c6, &[0x9f, 0x54], false),
+    (0xc6c7, &[0x9f, 0x55], false),
+    (0xc6c8, &[0x9f, 0x56], false),
+    (0xc6c9, &[0x9f, 0x57], false),
+    (0xc6ca, &[0x9f, 0x58], false),
+    (0xc6cb, &[0x9f, 0x59], false),
+    (0xc6ce, &[0x9f, 0x5a], false),
+    (0xc6cf, &[0x9f, 0x61], false),
+    (0xc6d1, &[0x9f, 0x62], false),
+    (0xc6d2, &[0x9f, 0x63], false),
+    (0xc6d3, &[0x9f, 0x64], false),
+    (0xc6d5, &[0x9f, 0x65], false),
+    (0xc6d6, &[0x9f, 0x66], false),
+    (0xc6d7, &[0x9f, 0x67], false),
+    (0xc6d8, &[0x9f, 0x68], false),
+    (0xc6d9, &[0x9f, 0x69], false),
+    (0xc6da, &[0x9f, 0x6a], false),
+    (0xc6db, &[0x9f, 0x6b], false),
+    (0xc6de, &[0x9f, 0x6c], false),
+    (0xc6df, &[0x9f, 0x6d], false),
+    (0xc6e2, &[0x9f, 0x6e], false),
+    (0xc6e3, &[0x9f, 0x6f], false),
+    (0xc6e4, &[0x9f, 0x70], false),
+    (0xc6e5, &[0x9f, 0x71], false),
+    (0xc6e6, &[0x9f, 0x72], false),
+    (0xc6e7, &[0x9f, 0x73], false),
+    (0xc6ea, &[0x9f, 0x74], false),
+    (0xc6eb, &[0x9f, 0x75], false),
+    (0xc6ed, &[0x9f, 0x76], false),
+    (0xc6ee, &[0x9f, 0x77], false),
+    (0xc6ef, &[0x9f, 0x78], false),
+    (0xc6f1, &[0x9f, 0x79], false),
+    (0xc6f2, &[0x9f, 0x7a], false),
+    (0xc6f3, &[0x9f, 0x81], false),
+    (0xc6f4, &[0x9f, 0x82], false),
+    (0xc6f5, &[0x9f, 0x83], false),
+    (0xc6f6, &[0x9f, 0x84], false),
+    (0xc6f7, &[0x9f, 0x85], false),
+    (0xc6fa, &[0x9f, 0x86], false),
+    (0xc6fb, &[0x9f, 0x87], false),
+    (0xc6fc, &[0x9f, 0x88], false),
+    (0xc6fe, &[0x9f, 0x89], false),
+    (0xc6ff, &[0x9f, 0x8a], false),
+    (0xc700, &[0x9f, 0x8b], false),
+    (0xc701, &[0x9f, 0x8c], false),
+    (0xc702, &[0x9f, 0x8d], false),
+    (0xc703, &[0x9f, 0x8e], false),
+    (0xc706, &[0x9f, 0x8f], false),
+    (0xc707, &[0x9f, 0x90], false),
+    (0xc709, &[0x9f, 0x91], false),
+    (0xc70a, &[0x9f, 0x92], false),
+    (0xc70b, &[0x9f, 0x93], false),
+    (0xc70d, &[0x9f, 0x94], false),
+    (0xc70e, &[0x9f, 0x95], false),
+    (0xc70f, &[0x9f, 0x96], false),
+    (0xc710, &[0x9f, 0x97], false),
+    (0xc711, &[0x9f, 0x98], false),
+    (0xc712, &[0x9f, 0x99], false),
+    (0xc713, &[0x9f, 0x9a], false),
+    (0xc716, &[0x9f, 0x9b], false),
+    (0xc718, &[0x9f, 0x9c], false),
+    (0xc71a, &[0x9f, 0x9d], false),
+    (0xc71b, &[0x9f, 0x9e], false),
+    (0xc71c, &[0x9f, 0x9f], false),
+    (0xc71d, &[0x9f, 0xa0], false),
+    (0xc71e, &[0x9f, 0xa1], false),
+    (0xc71f, &[0x9f, 0xa2], false),
+    (0xc722, &[0x9f, 0xa3], false),
+    (0xc723, &[0x9f, 0xa4], false),
+    (0xc725, &[0x9f, 0xa5], false),
+    (0xc726, &[0x9f, 0xa6], false),
+    (0xc727, &[0x9f, 0xa7], false),
+    (0xc729, &[0x9f, 0xa8], false),
+    (0xc72a, &[0x9f, 0xa9], false),
+    (0xc72b, &[0x9f, 0xaa], false),
+    (0xc72c, &[0x9f, 0xab], false),
+    (0xc72d, &[0x9f, 0xac], false),
+    (0xc72e, &[0x9f, 0xad], false),
+    (0xc72f, &[0x9f, 0xae], false),
+    (0xc732, &[0x9f, 0xaf], false),
+    (0xc734, &[0x9f, 0xb0], false),
+    (0xc736, &[0x9f, 0xb1], false),
+    (0xc738, &[0x9f, 0xb2], false),
+    (0xc739, &[0x9f, 0xb3], false),
+    (0xc73a, &[0x9f, 0xb4], false),
+    (0xc73b, &[0x9f, 0xb5], false),
+    (0xc73e, &[0x9f, 0xb6], false),
+    (0xc73f, &[0x9f, 0xb7], false),
+    (0xc741, &[0x9f, 0xb8], false),
+    (0xc742, &[0x9f, 0xb9], false),
+    (0xc743, &[0x9f, 0xba], false),
+    (0xc745, &[0x9f, 0xbb], false),
+    (0xc746, &[0x9f, 0xbc], false),
+    (0xc747, &[0x9f, 0xbd], false),
+    (0xc748, &[0x9f, 0xbe], false),
+    (0xc749, &[0x9f, 0xbf], false),
+    (0xc74b, &[0x9f, 0xc0], false),
+    (0xc74e, &[0x9f, 0xc1], false),
+    (0xc750, &[0x9f, 0xc2], false),
+    (0xc759, &[0x9f, 0xc3], false),
+    (0xc75a, &[0x9f, 0xc4], false),
+    (0xc75b, &[0x9f, 0xc5], false),
+    (0xc75d, &[0x9f, 0xc6], false),
+    (0xc75e, &[0x9f, 0xc7], false),
+    (0xc75f, &[0x9f, 0xc8], false),
+    (0xc761, &[0x9f, 0xc9], false),
+    (0xc762, &[0x9f, 0xca], false),
+    (0xc763, &[0x9f, 0xcb], false),
+    (0xc764, &[0x9f, 0xcc], false),
+    (0xc765, &[0x9f, 0xcd], false),
+    (0xc766, &[0x9f, 0xce], false),
+    (0xc767, &[0x9f, 0xcf], false),
+    (0xc769, &[0x9f, 0xd0], false),
+    (0xc76a, &[0x9f, 0xd1], false),
+    (0xc76c, &[0x9f, 0xd2], false),
+    (0xc76d, &[0x9f, 0xd3], false),
+    (0xc76e, &[0x9f, 0xd4], false),
+    (0xc76f, &[0x9f, 0xd5], false),
+    (0xc770, &[0x9f, 0xd6], false),
+    (0xc771, &[0x9f, 0xd7], false),
+    (0xc772, &[0x9f, 0xd8], false),
+    (0xc773, &[0x9f, 0xd9], false),
+    (0xc776, &[0x9f, 0xda], false),
+    (0xc777, &[0x9f, 0xdb], false),
+    (0xc779, &[0x9f, 0xdc], false),
+    (0xc77a, &[0x9f, 0xdd], false),
+    (0xc77b, &[0x9f, 0xde], false),
+    (0xc77f, &[0x9f, 0xdf], false),
+    (0xc780, &[0x9f, 0xe0], false),
+    (0xc781, &[0x9f, 0xe1], false),
+    (0xc782, &[0x9f, 0xe2], false),
+    (0xc786, &[0x9f, 0xe3], false),
+    (0xc78b, &[0x9f, 0xe4], false),
+    (0xc78c, &[0x9f, 0xe5], false),
+    (0xc78d, &[0x9f, 0xe6], false),
+    (0xc78f, &[0x9f, 0xe7], false),
+    (0xc792, &[0x9f, 0xe8], false),
+    (0xc793, &[0x9f, 0xe9], false),
+    (0xc795, &[0x9f, 0xea], false),
+    (0xc799, &[0x9f, 0xeb], false),
+    (0xc79b, &[0x9f, 0xec], false),
+    (0xc79c, &[0x9f, 0xed], false),
+    (0xc79d, &[0x9f, 0xee], false),
+    (0xc79e, &[0x9f, 0xef], false),
+    (0xc79f, &[0x9f, 0xf0], false),
+    (0xc7a2, &[0x9f, 0xf1], false),
+    (0xc7a7, &[0x9f, 0xf2], false),
+    (0xc7a8, &[0x9f, 0xf3], false),
+    (0xc7a9, &[0x9f, 0xf4], false),
+    (0xc7aa, &[0x9f, 0xf5], false),
+    (0xc7ab, &[0x9f, 0xf6], false),
+    (0xc7ae, &[0x9f, 0xf7], false),
+    (0xc7af, &[0x9f, 0xf8], false),
+    (0xc7b1, &[0x9f, 0xf9], false),
+    (0xc7b2, &[0x9f, 0xfa], false),
+    (0xc7b3, &[0x9f, 0xfb], false),
+    (0xc7b5, &[0x9f, 0xfc], false),
+    (0xc7b6, &[0x9f, 0xfd], false),
+    (0xc7b7, &[0x9f, 0xfe], false),
+    (0xc7b8, &[0xa0, 0x41], false),
+    (0xc7b9, &[0xa0, 0x42], false),
+    (0xc7ba, &[0xa0, 0x43], false),
+    (0xc7bb, &[0xa0, 0x44], false),
+    (0xc7be, &[0xa0, 0x45], false),
+    (0xc7c2, &[0xa0, 0x46], false),
+    (0xc7c3, &[0xa0, 0x47], false),
+    (0xc7c4, &[0xa0, 0x48], false),
+    (0xc7c5, &[0xa0, 0x49], false),
+    (0xc7c6, &[0xa0, 0x4a], false),
+    (0xc7c7, &[0xa0, 0x4b], false),
+    (0xc7ca, &[0xa0, 0x4c], false),
+    (0xc7cb, &[0xa0, 0x4d], false),
+    (0xc7cd, &[0xa0, 0x4e], false),
+    (0xc7cf, &[0xa0, 0x4f], false),
+    (0xc7d1, &[0xa0, 0x50], false),
+    (0xc7d2, &[0xa0, 0x51], false),
+    (0xc7d3, &[0xa0, 0x52], false),
+    (0xc7d4, &[0xa0, 0x53], false),
+    (0xc7d5, &[0xa0, 0x54], false),
+    (0xc7d6, &[0xa0, 0x55], false),
+    (0xc7d7, &[0xa0, 0x56], false),
+    (0xc7d9, &[0xa0, 0x57], false),
+    (0xc7da, &[0xa0, 0x58], false),
+    (0xc7db, &[0xa0, 0x59], false),
+    (0xc7dc, &[0xa0, 0x5a], false),
+    (0xc7de, &[0xa0, 0x61], false),
+    (0xc7df, &[0xa0, 0x62], false),
+    (0xc7e0, &[0xa0, 0x63], false),
+    (0xc7e1, &[0xa0, 0x64], false),
+    (0xc7e2, &[0xa0, 0x65], false),
+    (0xc7e3, &[0xa0, 0x66], false),
+    (0xc7e5, &[0xa0, 0x67], false),
+    (0xc7e6, &[0xa0, 0x68], false),
+    (0xc7e7, &[0xa0, 0x69], false),
+    (0xc7e9, &[0xa0, 0x6a], false),
+    (0xc7ea, &[0xa0, 0x6b], false),
+    (0xc7eb, &[0xa0, 0x6c], false),
+    (0xc7ed, &[0xa0, 0x6d], false),
+    (0xc7ee, &[0xa0, 0x6e], false),
+    (0xc7ef, &[0xa0, 0x6f], false),
+    (0xc7f0, &[0xa0, 0x70], false),
+    (0xc7f1, &[0xa0, 0x71], false),
+    (0xc7f2, &[0xa0, 0x72], false),
+    (0xc7f3, &[0xa0, 0x73], false),
+    (0xc7f4, &[0xa0, 0x74], false),
+    (0xc7f5, &[0xa0, 0x75], false),
+    (0xc7f6, &[0xa0, 0x76], false),
+    (0xc7f7, &[0xa0, 0x77], false),
+    (0xc7f8, &[0xa0, 0x78], false),
+    (0xc7f9, &[0xa0, 0x79], false),
+    (0xc7fa, &[0xa0, 0x7a], false),
+    (0xc7fb, &[0xa0, 0x81], false),
+    (0xc7fc, &[0xa0, 0x82], false),
+    (0xc7fd, &[0xa0, 0x83], false),
+    (0xc7fe, &[0xa0, 0x84], false),
+    (0xc7ff, &[0xa0, 0x85], false),
+    (0xc802, &[0xa0, 0x86], false),
+    (0xc803, &[0xa0, 0x87], false),
+    (0xc805, &[0xa0, 0x88], false),
+    (0xc806, &[0xa0, 0x89], false),
+    (0xc807, &[0xa0, 0x8a], false),
+    (0xc809, &[0xa0, 0x8b], false),
+    (0xc80b, &[0xa0, 0x8c], false),
+    (0xc80c, &[0xa0, 0x8d], false),
+    (0xc80d, &[0xa0, 0x8e], false),
+    (0xc80e, &[0xa0, 0x8f], false),
+    (0xc80f, &[0xa0, 0x90], false),
+    (0xc812, &[0xa0, 0x91], false),
+    (0xc814, &[0xa0, 0x92], false),
+    (0xc817, &[0xa0, 0x93], false),
+    (0xc818, &[0xa0, 0x94], false),
+    (0xc819, &[0xa0, 0x95], false),
+    (0xc81a, &[0xa0, 0x96], false),
+    (0xc81b, &[0xa0, 0x97], false),
+    (0xc81e, &[0xa0, 0x98], false),
+    (0xc81f, &[0xa0, 0x99], false),
+    (0xc821, &[0xa0, 0x9a], false),
+    (0xc822, &[0xa0, 0x9b], false),
+    (0xc823, &[0xa0, 0x9c], false),
+    (0xc825, &[0xa0, 0x9d], false),
+    (0xc826, &[0xa0, 0x9e], false),
+    (0xc827, &[0xa0, 0x9f], false),
+    (0xc828, &[0xa0, 0xa0], false),
+    (0xc829, &[0xa0, 0xa1], false),
+    (0xc82a, &[0xa0, 0xa2], false),
+    (0xc82b, &[0xa0, 0xa3], false),
+    (0xc82e, &[0xa0, 0xa4], false),
+    (0xc830, &[0xa0, 0xa5], false),
+    (0xc832, &[0xa0, 0xa6], false),
+    (0xc833, &[0xa0, 0xa7], false),
+    (0xc834, &[0xa0, 0xa8], false),
+    (0xc835, &[0xa0, 0xa9], false),
+    (0xc836, &[0xa0, 0xaa], false),
+    (0xc837, &[0xa0, 0xab], false),
+    (0xc839, &[0xa0, 0xac], false),
+    (0xc83a, &[0xa0, 0xad], false),
+    (0xc83b, &[0xa0, 0xae], false),
+    (0xc83d, &[0xa0, 0xaf], false),
+    (0xc83e, &[0xa0, 0xb0], false),
+    (0xc83f, &[0xa0, 0xb1], false),
+    (0xc841, &[0xa0, 0xb2], false),
+    (0xc842, &[0xa0, 0xb3], false),
+    (0xc843, &[0xa0, 0xb4], false),
+    (0xc844, &[0xa0, 0xb5], false),
+    (0xc845, &[0xa0, 0xb6], false),
+    (0xc846, &[0xa0, 0xb7], false),
+    (0xc847, &[0xa0, 0xb8], false),
+    (0xc84a, &[0xa0, 0xb9], false),
+    (0xc84b, &[0xa0, 0xba], false),
+    (0xc84e, &[0xa0, 0xbb], false),
+    (0xc84f, &[0xa0, 0xbc], false),
+    (0xc850, &[0xa0, 0xbd], false),
+    (0xc851, &[0xa0, 0xbe], false),
+    (0xc852, &[0xa0, 0xbf], false),
+    (0xc853, &[0xa0, 0xc0], false),
+    (0xc855, &[0xa0, 0xc1], false),
+    (0xc856, &[0xa0, 0xc2], false),
+    (0xc857, &[0xa0, 0xc3], false),
+    (0xc858, &[0xa0, 0xc4], false),
+    (0xc859, &[0xa0, 0xc5], false),
+    (0xc85a, &[0xa0, 0xc6], false),
+    (0xc85b, &[0xa0, 0xc7], false),
+    (0xc85c, &[0xa0, 0xc8], false),
+    (0xc85d, &[0xa0, 0xc9], false),
+    (0xc85e, &[0xa0, 0xca], false),
+    (0xc85f, &[0xa0, 0xcb], false),
+    (0xc860, &[0xa0, 0xcc], false),
+    (0xc861, &[0xa0, 0xcd], false),
+    (0xc862, &[0xa0, 0xce], false),
+    (0xc863, &[0xa0, 0xcf], false),
+    (0xc864, &[0xa0, 0xd0], false),
+    (0xc865, &[0xa0, 0xd1], false),
+    (0xc866, &[0xa0, 0xd2], false),
+    (0xc867, &[0xa0, 0xd3], false),
+    (0xc868, &[0xa0, 0xd4], false),
+    (0xc869, &[0xa0, 0xd5], false),
+    (0xc86a, &[0xa0, 0xd6], false),
+    (0xc86b, &[0xa0, 0xd7], false),
+    (0xc86c, &[0xa0, 0xd8], false),
+    (0xc86d, &[0xa0, 0xd9], false),
+    (0xc86e, &[0xa0, 0xda], false),
+    (0xc86f, &[0xa0, 0xdb], false),
+    (0xc872, &[0xa0, 0xdc], false),
+    (0xc873, &[0xa0, 0xdd], false),
+    (0xc875, &[0xa0, 0xde], false),
+    (0xc876, &[0xa0, 0xdf], false),
+    (0xc877, &[0xa0, 0xe0], false),
+    (0xc879, &[0xa0, 0xe1], false),
+    (0xc87b, &[0xa0, 0xe2], false),
+    (0xc87c, &[0xa0, 0xe3], false),
+    (0xc87d, &[0xa0, 0xe4], false),
+    (0xc87e, &[0xa0, 0xe5], false),
+    (0xc87f, &[0xa0, 0xe6], false),
+    (0xc882, &[0xa0, 0xe7], false),
+    (0xc884, &[0xa0, 0xe8], false),
+    (0xc888, &[0xa0, 0xe9], false),
+    (0xc889, &[0xa0, 0xea], false),
+    (0xc88a, &[0xa0, 0xeb], false),
+    (0xc88e, &[0xa0, 0xec], false),
+    (0xc88f, &[0xa0, 0xed], false),
+    (0xc890, &[0xa0, 0xee], false),
+    (0xc891, &[0xa0, 0xef], false),
+    (0xc892, &[0xa0, 0xf0], false),
+    (0xc893, &[0xa0, 0xf1], false),
+    (0xc895, &[0xa0, 0xf2], false),
+    (0xc896, &[0xa0, 0xf3], false),
+    (0xc897, &[0xa0, 0xf4], false),
+    (0xc898, &[0xa0, 0xf5], false),
+    (0xc899, &[0xa0, 0xf6], false),
+    (0xc89a, &[0xa0, 0xf7], false),
+    (0xc89b, &[0xa0, 0xf8], false),
+    (0xc89c, &[0xa0, 0xf9], false),
+    (0xc89e, &[0xa0, 0xfa], false),
+    (0xc8a0, &[0xa0, 0xfb], false),
+    (0xc8a2, &[0xa0, 0xfc], false),
+    (0xc8a3, &[0xa0, 0xfd], false),
+    (0xc8a4, &[0xa0, 0xfe], false),
+    (0xc8a5, &[0xa1, 0x41], false),
+    (0xc8a6, &[0xa1, 0x42], false),
+    (0xc8a7, &[0xa1, 0x43], false),
+    (0xc8a9, &[0xa1, 0x44], false),
+    (0xc8aa, &[0xa1, 0x45], false),
+    (0xc8ab, &[0xa1, 0x46], false),
+    (0xc8ac, &[0xa1, 0x47], false),
+    (0xc8ad, &[0xa1, 0x48], false),
+    (0xc8ae, &[0xa1, 0x49], false),
+    (0xc8af, &[0xa1, 0x4a], false),
+    (0xc8b0, &[0xa1, 0x4b], false),
+    (0xc8b1, &[0xa1, 0x4c], false),
+    (0xc8b2, &[0xa1, 0x4d], false),
+    (0xc8b3, &[0xa1, 0x4e], false),
+    (0xc8b4, &[0xa1, 0x4f], false),
+    (0xc8b5, &[0xa1, 0x50], false),
+    (0xc8b6, &[0xa1, 0x51], false),
+    (0xc8b7, &[0xa1, 0x52], false),
+    (0xc8b8, &[0xa1, 0x53], false),
+    (0xc8b9, &[0xa1, 0x54], false),
+    (0xc8ba, &[0xa1, 0x55], false),
+    (0xc8bb, &[0xa1, 0x56], false),
+    (0xc8be, &[0xa1, 0x57], false),
+    (0xc8bf, &[0xa1, 0x58], false),
+    (0xc8c0, &[0xa1, 0x59], false),
+    (0xc8c1, &[0xa1, 0x5a], false),
+    (0xc8c2, &[0xa1, 0x61], false),
+    (0xc8c3, &[0xa1, 0x62], false),
+    (0xc8c5, &[0xa1, 0x63], false),
+    (0xc8c6, &[0xa1, 0x64], false),
+    (0xc8c7, &[0xa1, 0x65], false),
+    (0xc8c9, &[0xa1, 0x66], false),
+    (0xc8ca, &[0xa1, 0x67], false),
+    (0xc8cb, &[0xa1, 0x68], false),
+    (0xc8cd, &[0xa1, 0x69], false),
+    (0xc8ce, &[0xa1, 0x6a], false),
+    (0xc8cf, &[0xa1, 0x6b], false),
+    (0xc8d0, &[0xa1, 0x6c], false),
+    (0xc8d1, &[0xa1, 0x6d], false),
+    (0xc8d2, &[0xa1, 0x6e], false),
+    (0xc8d3, &[0xa1, 0x6f], false),
+    (0xc8d6, &[0xa1, 0x70], false),
+    (0xc8d8, &[0xa1, 0x71], false),
+    (0xc8da, &[0xa1, 0x72], false),
+    (0xc8db, &[0xa1, 0x73], false),
+    (0xc8dc, &[0xa1, 0x74], false),
+    (0xc8dd, &[0xa1, 0x75], false),
+    (0xc8de, &[0xa1, 0x76], false),
+    (0xc8df, &[0xa1, 0x77], false),
+    (0xc8e2, &[0xa1, 0x78], false),
+    (0xc8e3, &[0xa1, 0x79], false),
+    (0xc8e5, &[0xa1, 0x7a], false),
+    (0xc8e6, &[0xa1, 0x81], false),
+    (0xc8e7, &[0xa1, 0x82], false),
+    (0xc8e8, &[0xa1, 0x83], false),
+    (0xc8e9, &[0xa1, 0x84], false),
+    (0xc8ea, &[0xa1, 0x85], false),
+    (0xc8eb, &[0xa1, 0x86], false),
+    (0xc8ec, &[0xa1, 0x87], false),
+    (0xc8ed, &[0xa1, 0x88], false),
+    (0xc8ee, &[0xa1, 0x89], false),
+    (0xc8ef, &[0xa1, 0x8a], false),
+    (0xc8f0, &[0xa1, 0x8b], false),
+    (0xc8f1, &[0xa1, 0x8c], false),
+    (0xc8f2, &[0xa1, 0x8d], false),
+    (0xc8f3, &[0xa1, 0x8e], false),
+    (0xc8f4, &[0xa1, 0x8f], false),
+    (0xc8f6, &[0xa1, 0x90], false),
+    (0xc8f7, &[0xa1, 0x91], false),
+    (0xc8f8, &[0xa1, 0x92], false),
+    (0xc8f9, &[0xa1, 0x93], false),
+    (0xc8fa, &[0xa1, 0x94], false),
+    (0xc8fb, &[0xa1, 0x95], false),
+    (0xc8fe, &[0xa1, 0x96], false),
+    (0xc8ff, &[0xa1, 0x97], false),
+    (0xc901, &[0xa1, 0x98], false),
+    (0xc902, &[0xa1, 0x99], false),
+    (0xc903, &[0xa1, 0x9a], false),
+    (0xc907, &[0xa1, 0x9b], false),
+    (0xc908, &[0xa1, 0x9c], false),
+    (0xc909, &[0xa1, 0x9d], false),
+    (0xc90a, &[0xa1, 0x9e], false),
+    (0xc90b, &[0xa1, 0x9f], false),
+    (0xc90e, &[0xa1, 0xa0], false),
+    (0x3000, &[0xa1, 0xa1], false),
+    (0x3001, &[0xa1, 0xa2], false),
+    (0x3002, &[0xa1, 0xa3], false),
+    (0x00b7, &[0xa1, 0xa4], false),
+    (0x2025, &[0xa1, 0xa5], false),
+    (0x2026, &[0xa1, 0xa6], false),
+    (0x00a8, &[0xa1, 0xa7], false),
+    (0x3003, &[0xa1, 0xa8], false),
+    (0x00ad, &[0xa1, 0xa9], false),
+    (0x2015, &[0xa1, 0xaa], false),
+    (0x2225, &[0xa1, 0xab], false),
+    (0xff3c, &[0xa1, 0xac], false),
+    (0x223c, &[0xa1, 0xad], false),
+    (0x2018, &[0xa1, 0xae], false),
+    (0x2019, &[0xa1, 0xaf], false),
+    (0x201c, &[0xa1, 0xb0], false),
+    (0x201d, &[0xa1, 0xb1], false),
+    (0x3014, &[0xa1, 0xb2], false),
+    (0x3015, &[0xa1, 0xb3], false),
+    (0x3008, &[0xa1, 0xb4], false),
+    (0x3009, &[0xa1, 0xb5], false),
+    (0x300a, &[0xa1, 0xb6], false),
+    (0x300b, &[0xa1, 0xb7], false),
+    (0x300c, &[0xa1, 0xb8], false),
+    (0x300d, &[0xa1, 0xb9], false),
+    (0x300e, &[0xa1, 0xba], false),
+    (0x300f, &[0xa1, 0xbb], false),
+    (0x3010, &[0xa1, 0xbc], false),
+    (0x3011, &[0xa1, 0xbd], false),
+    (0x00b1, &[0xa1, 0xbe], false),
+    (0x00d7, &[0xa1, 0xbf], false),
+    (0x00f7, &[0xa1, 0xc0], false),
+    (0x2260, &[0xa1, 0xc1], false),
+    (0x2264, &[0xa1, 0xc2], false),
+    (0x2265, &[0xa1, 0xc3], false),
+    (0x221e, &[0xa1, 0xc4], false),
+    (0x2234, &[0xa1, 0xc5], false),
+    (0x00b0, &[0xa1, 0xc6], false),
+    (0x2032, &[0xa1, 0xc7], false),
+    (0x2033, &[0xa1, 0xc8], false),
+    (0x2103, &[0xa1, 0xc9], false),
+    (0x212b, &[0xa1, 0xca], false),
+    (0xffe0, &[0xa1, 0xcb], false),
+    (0xffe1, &[0xa1, 0xcc], false),
+    (0xffe5, &[0xa1, 0xcd], false),
+    (0x2642, &[0xa1, 0xce], false),
+    (0x2640, &[0xa1, 0xcf], false),
+    (0x2220, &[0xa1, 0xd0], false),
+    (0x22a5, &[0xa1, 0xd1], false),
+    (0x2312, &[0xa1, 0xd2], false),
+    (0x2202, &[0xa1, 0xd3], false),
+    (0x2207, &[0xa1, 0xd4], false),
+    (0x2261, &[0xa1, 0xd5], false),
+    (0x2252, &[0xa1, 0xd6], false),
+    (0x00a7, &[0xa1, 0xd7], false),
+    (0x203b, &[0xa1, 0xd8], false),
+    (0x2606, &[0xa1, 0xd9], false),
+    (0x2605, &[0xa1, 0xda], false),
+    (0x25cb, &[0xa1, 0xdb], false),
+    (0x25cf, &[0xa1, 0xdc], false),
+    (0x25ce, &[0xa1, 0xdd], false),
+    (0x25c7, &[0xa1, 0xde], false),
+    (0x25c6, &[0xa1, 0xdf], false),
+    (0x25a1, &[0xa1, 0xe0], false),
+    (0x25a0, &[0xa1, 0xe1], false),
+    (0x25b3, &[0xa1, 0xe2], false),
+    (0x25b2, &[0xa1, 0xe3], false),
+    (0x25bd, &[0xa1, 0xe4], false),
+    (0x25bc, &[0xa1, 0xe5], false),
+    (0x2192, &[0xa1, 0xe6], false),
+    (0x2190, &[0xa1, 0xe7], false),
+    (0x2191, &[0xa1, 0xe8], false),
+    (0x2193, &[0xa1, 0xe9], false),
+    (0x2194, &[0xa1, 0xea], false),
+    (0x3013, &[0xa1, 0xeb], false),
+    (0x226a, &[0xa1, 0xec], false),
+    (0x226b, &[0xa1, 0xed], false),
+    (0x221a, &[0xa1, 0xee], false),
+    (0x223d, &[0xa1, 0xef], false),
+    (0x221d, &[0xa1, 0xf0], false),
+    (0x2235, &[0xa1, 0xf1], false),
+    (0x222b, &[0xa1, 0xf2], false),
+    (0x222c, &[0xa1, 0xf3], false),
+    (0x2208, &[0xa1, 0xf4], false),
+    (0x220b, &[0xa1, 0xf5], false),
+    (0x2286, &[0xa1, 0xf6], false),
+    (0x2287, &[0xa1, 0xf7], false),
+    (0x2282, &[0xa1, 0xf8], false),
+    (0x2283, &[0xa1, 0xf9], false),
+    (0x222a, &[0xa1, 0xfa], false),
+    (0x2229, &[0xa1, 0xfb], false),
+    (0x2227, &[0xa1, 0xfc], false),
+    (0x2228, &[0xa1, 0xfd], false),
+    (0xffe2, &[0xa1, 0xfe], false),
+    (0xc910, &[0xa2, 0x41], false),
+    (0xc912, &[0xa2, 0x42], false),
+    (0xc913, &[0xa2, 0x43], false),
+    (0xc914, &[0xa2, 0x44], false),
+    (0xc915, &[0xa2, 0x45], false),
+    (0xc916, &[0xa2, 0x46], false),
+    (0xc917, &[0xa2, 0x47], false),
+    (0xc919, &[0xa2, 0x48], false),
+    (0xc91a, &[0xa2, 0x49], false),
+    (0xc91b, &[0xa2, 0x4a], false),
+    (0xc91c, &[0xa2, 0x4b], false),
+    (0xc91d, &[0xa2, 0x4c], false),
+    (0xc91e, &[0xa2, 0x4d], false),
+    (0xc91f, &[0xa2, 0x4e], false),
+    (0xc920, &[0xa2, 0x4f], false),
+    (0xc921, &[0xa2, 0x50], false),
+    (0xc922, &[0xa2, 0x51], false),
+    (0xc923, &[0xa2, 0x52], false),
+    (0xc924, &[0xa2, 0x53], false),
+    (0xc925, &[0xa2, 0x54], false),
+    (0xc926, &[0xa2, 0x55], false),
+    (0xc927, &[0xa2, 0x56], false),
+    (0xc928, &[0xa2, 0x57], false),
+    (0xc929, &[0xa2, 0x58], false),
+    (0xc92a, &[0xa2, 0x59], false),
+    (0xc92b, &[0xa2, 0x5a], false),
+    (0xc92d, &[0xa2, 0x61], false),
+    (0xc92e, &[0xa2, 0x62], false),
+    (0xc92f, &[0xa2, 0x63], false),
+    (0xc930, &[0xa2, 0x64], false),
+    (0xc931, &[0xa2, 0x65], false),
+    (0xc932, &[0xa2, 0x66], false),
+    (0xc933, &[0xa2, 0x67], false),
+    (0xc935, &[0xa2, 0x68], false),
+    (0xc936, &[0xa2, 0x69], false),
+    (0xc937, &[0xa2, 0x6a], false),
+    (0xc938, &[0xa2, 0x6b], false),
+    (0xc939, &[0xa2, 0x6c], false),
+    (0xc93a, &[0xa2, 0x6d], false),
+    (0xc93b, &[0xa2, 0x6e], false),
+    (0xc93c, &[0xa2, 0x6f], false),
+    (0xc93d, &[0xa2, 0x70], false),
+    (0xc93e, &[0xa2, 0x71], false),
+    (0xc93f, &[0xa2, 0x72], false),
+    (0xc940, &[0xa2, 0x73], false),
+    (0xc941, &[0xa2, 0x74], false),
+    (0xc942, &[0xa2, 0x75], false),
+    (0xc943, &[0xa2, 0x76], false),
+    (0xc944, &[0xa2, 0x77], false),
+    (0xc945, &[0xa2, 0x78], false),
+    (0xc946, &[0xa2, 0x79], false),
+    (0xc947, &[0xa2, 0x7a], false),
+    (0xc948, &[0xa2, 0x81], false),
+    (0xc949, &[0xa2, 0x82], false),
+    (0xc94a, &[0xa2, 0x83], false),
+    (0xc94b, &[0xa2, 0x84], false),
+    (0xc94c, &[0xa2, 0x85], false),
+    (0xc94d, &[0xa2, 0x86], false),
+    (0xc94e, &[0xa2, 0x87], false),
+    (0xc94f, &[0xa2, 0x88], false),
+    (0xc952, &[0xa2, 0x89], false),
+    (0xc953, &[0xa2, 0x8a], false),
+    (0xc955, &[0xa2, 0x8b], false),
+    (0xc956, &[0xa2, 0x8c], false),
+    (0xc957, &[0xa2, 0x8d], false),
+    (0xc959, &[0xa2, 0x8e], false),
+    (0xc95a, &[0xa2, 0x8f], false),
+    (0xc95b, &[0xa2, 0x90], false),
+    (0xc95c, &[0xa2, 0x91], false),
+    (0xc95d, &[0xa2, 0x92], false),
+    (0xc95e, &[0xa2, 0x93], false),
+    (0xc95f, &[0xa2, 0x94], false),
+    (0xc962, &[0xa2, 0x95], false),
+    (0xc964, &[0xa2, 0x96], false),
+    (0xc965, &[0xa2, 0x97], false),
+    (0xc966, &[0xa2, 0x98], false),
+    (0xc967, &[0xa2, 0x99], false),
+    (0xc968, &[0xa2, 0x9a], false),
+    (0xc969, &[0xa2, 0x9b], false),
+    (0xc96a, &[0xa2, 0x9c], false),
+    (0xc96b, &[0xa2, 0x9d], false),
+    (0xc96d, &[0xa2, 0x9e], false),
+    (0xc96e, &[0xa2, 0x9f], false),
+    (0xc96f, &[0xa2, 0xa0], false),
+    (0x21d2, &[0xa2, 0xa1], false),
+    (0x21d4, &[0xa2, 0xa2], false),
+    (0x2200, &[0xa2, 0xa3], false),
+    (0x2203, &[0xa2, 0xa4], false),
+    (0x00b4, &[0xa2, 0xa5], false),
+    (0xff5e, &[0xa2, 0xa6], false),
+    (0x02c7, &[0xa2, 0xa7], false),
+    (0x02d8, &[0xa2, 0xa8], false),
+    (0x02dd, &[0xa2, 0xa9], false),
+    (0x02da, &[0xa2, 0xaa], false),
+    (0x02d9, &[0xa2, 0xab], false),
+    (0x00b8, &[0xa2, 0xac], false),
+    (0x02db, &[0xa2, 0xad], false),
+    (0x00a1, &[0xa2, 0xae], false),
+    (0x00bf, &[0xa2, 0xaf], false),
+    (0x02d0, &[0xa2, 0xb0], false),
+    (0x222e, &[0xa2, 0xb1], false),
+    (0x2211, &[0xa2, 0xb2], false),
+    (0x220f, &[0xa2, 0xb3], false),
+    (0x00a4, &[0xa2, 0xb4], false),
+    (0x2109, &[0xa2, 0xb5], false),
+    (0x2030, &[0xa2, 0xb6], false),
+    (0x25c1, &[0xa2, 0xb7], false),
+    (0x25c0, &[0xa2, 0xb8], false),
+    (0x25b7, &[0xa2, 0xb9], false),
+    (0x25b6, &[0xa2, 0xba], false),
+    (0x2664, &[0xa2, 0xbb], false),
+    (0x2660, &[0xa2, 0xbc], false),
+    (0x2661, &[0xa2, 0xbd], false),
+    (0x2665, &[0xa2, 0xbe], false),
+    (0x2667, &[0xa2, 0xbf], false),
+    (0x2663, &[0xa2, 0xc0], false),
+    (0x2299, &[0xa2, 0xc1], false),
+    (0x25c8, &[0xa2, 0xc2], false),
+    (0x25a3, &[0xa2, 0xc3], false),
+    (0x25d0, &[0xa2, 0xc4], false),
+    (0x25d1, &[0xa2, 0xc5], false),
+    (0x2592, &[0xa2, 0xc6], false),
+    (0x25a4, &[0xa2, 0xc7], false),
+    (0x25a5, &[0xa2, 0xc8], false),
+    (0x25a8, &[0xa2, 0xc9], false),
+    (0x25a7, &[0xa2, 0xca], false),
+    (0x25a6, &[0xa2, 0xcb], false),
+    (0x25a9, &[0xa2, 0xcc], false),
+    (0x2668, &[0xa2, 0xcd], false),
+    (0x260f, &[0xa2, 0xce], false),
+    (0x260e, &[0xa2, 0xcf], false),
+    (0x261c, &[0xa2, 0xd0], false),
+    (0x261e, &[0xa2, 0xd1], false),
+    (0x00b6, &[0xa2, 0xd2], false),
+    (0x2020, &[0xa2, 0xd3], false),
+    (0x2021, &[0xa2, 0xd4], false),
+    (0x2195, &[0xa2, 0xd5], false),
+    (0x2197, &[0xa2, 0xd6], false),
+    (0x2199, &[0xa2, 0xd7], false),
+    (0x2196, &[0xa2, 0xd8], false),
+    (0x2198, &[0xa2, 0xd9], false),
+    (0x266d, &[0xa2, 0xda], false),
+    (0x2669, &[0xa2, 0xdb], false),
+    (0x266a, &[0xa2, 0xdc], false),
+    (0x266c, &[0xa2, 0xdd], false),
+    (0x327f, &[0xa2, 0xde], false),
+    (0x321c, &[0xa2, 0xdf], false),
+    (0x2116, &[0xa2, 0xe0], false),
+    (0x33c7, &[0xa2, 0xe1], false),
+    (0x2122, &[0xa2, 0xe2], false),
+    (0x33c2, &[0xa2, 0xe3], false),
+    (0x33d8, &[0xa2, 0xe4], false),
+    (0x2121, &[0xa2, 0xe5], false),
+    (0x20ac, &[0xa2, 0xe6], false),
+    (0x00ae, &[0xa2, 0xe7], false),
+    (0xc971, &[0xa3, 0x41], false),
+    (0xc972, &[0xa3, 0x42], false),
+    (0xc973, &[0xa3, 0x43], false),
+    (0xc975, &[0xa3, 0x44], false),
+    (0xc976, &[0xa3, 0x45], false),
+    (0xc977, &[0xa3, 0x46], false),
+    (0xc978, &[0xa3, 0x47], false),
+    (0xc979, &[0xa3, 0x48], false),
+    (0xc97a, &[0xa3, 0x49], false),
+    (0xc97b, &[0xa3, 0x4a], false),
+    (0xc97d, &[0xa3, 0x4b], false),
+    (0xc97e, &[0xa3, 0x4c], false),
+    (0xc97f, &[0xa3, 0x4d], false),
+    (0xc980, &[0xa3, 0x4e], false),
+    (0xc981, &[0xa3, 0x4f], false),
+    (0xc982, &[0xa3, 0x50], false),
+    (0xc983, &[0xa3, 0x51], false),
+    (0xc984, &[0xa3, 0x52], false),
+    (0xc985, &[0xa3, 0x53], false),
+    (0xc986, &[0xa3, 0x54], false),
+    (0xc987, &[0xa3, 0x55], false),
+    (0xc98a, &[0xa3, 0x56], false),
+    (0xc98b, &[0xa3, 0x57], false),
+    (0xc98d, &[0xa3, 0x58], false),
+    (0xc98e, &[0xa3, 0x59], false),
+    (0xc98f, &[0xa3, 0x5a], false),
+    (0xc991, &[0xa3, 0x61], false),
+    (0xc992, &[0xa3, 0x62], false),
+    (0xc993, &[0xa3, 0x63], false),
+    (0xc994, &[0xa3, 0x64], false),
+    (0xc995, &[0xa3, 0x65], false),
+    (0xc996, &[0xa3, 0x66], false),
+    (0xc997, &[0xa3, 0x67], false),
+    (0xc99a, &[0xa3, 0x68], false),
+    (0xc99c, &[0xa3, 0x69], false),
+    (0xc99e, &[0xa3, 0x6a], false),
+    (0xc99f, &[0xa3, 0x6b], false),
+    (0xc9a0, &[0xa3, 0x6c], false),
+    (0xc9a1, &[0xa3, 0x6d], false),
+    (0xc9a2, &[0xa3, 0x6e], false),
+    (0xc9a3, &[0xa3, 0x6f], false),
+    (0xc9a4, &[0xa3, 0x70], false),
+    (0xc9a5, &[0xa3, 0x71], false),
+    (0xc9a6, &[0xa3, 0x72], false),
+    (0xc9a7, &[0xa3, 0x73], false),
+    (0xc9a8, &[0xa3, 0x74], false),
+    (0xc9a9, &[0xa3, 0x75], false),
+    (0xc9aa, &[0xa3, 0x76], false),
+    (0xc9ab, &[0xa3, 0x77], false),
+    (0xc9ac, &[0xa3, 0x78], false),
+    (0xc9ad, &[0xa3, 0x79], false),
+    (0xc9ae, &[0xa3, 0x7a], false),
+    (0xc9af, &[0xa3, 0x81], false),
+    (0xc9b0, &[0xa3, 0x82], false),
+    (0xc9b1, &[0xa3, 0x83], false),
+    (0xc9b2, &[0xa3, 0x84], false),
+    (0xc9b3, &[0xa3, 0x85], false),
+    (0xc9b4, &[0xa3, 0x86], false),
+    (0xc9b5, &[0xa3, 0x87], false),
+    (0xc9b6, &[0xa3, 0x88], false),
+    (0xc9b7, &[0xa3, 0x89], false),
+    (0xc9b8, &[0xa3, 0x8a], false),
+    (0xc9b9, &[0xa3, 0x8b], false),
+    (0xc9ba, &[0xa3, 0x8c], false),
+    (0xc9bb, &[0xa3, 0x8d], false),
+    (0xc9bc, &[0xa3, 0x8e], false),
+    (0xc9bd, &[0xa3, 0x8f], false),
+    (0xc9be, &[0xa3, 0x90], false),
+    (0xc9bf, &[0xa3, 0x91], false),
+    (0xc9c2, &[0xa3, 0x92], false),
+    (0xc9c3, &[0xa3, 0x93], false),
+    (0xc9c5, &[0xa3, 0x94], false),
+    (0xc9c6, &[0xa3, 0x95], false),
+    (0xc9c9, &[0xa3, 0x96], false),
+    (0xc9cb, &[0xa3, 0x97], false),
+    (0xc9cc, &[0xa3, 0x98], false),
+    (0xc9cd, &[0xa3, 0x99], false),
+    (0xc9ce, &[0xa3, 0x9a], false),
+    (0xc9cf, &[0xa3, 0x9b], false),
+    (0xc9d2, &[0xa3, 0x9c], false),
+    (0xc9d4, &[0xa3, 0x9d], false),
+    (0xc9d7, &[0xa3, 0x9e], false),
+    (0xc9d8, &[0xa3, 0x9f], false),
+    (0xc9db, &[0xa3, 0xa0], false),
+    (0xff01, &[0xa3, 0xa1], false),
+    (0xff02, &[0xa3, 0xa2], false),
+    (0xff03, &[0xa3, 0xa3], false),
+    (0xff04, &[0xa3, 0xa4], false),
+    (0xff05, &[0xa3, 0xa5], false),
+    (0xff06, &[0xa3, 0xa6], false),
+    (0xff07, &[0xa3, 0xa7], false),
+    (0xff08, &[0xa3, 0xa8], false),
+    (0xff09, &[0xa3, 0xa9], false),
+    (0xff0a, &[0xa3, 0xaa], false),
+    (0xff0b, &[0xa3, 0xab], false),
+    (0xff0c, &[0xa3, 0xac], false),
+    (0xff0d, &[0xa3, 0xad], false),
+    (0xff0e, &[0xa3, 0xae], false),
+    (0xff0f, &[0xa3, 0xaf], false),
+    (0xff10, &[0xa3, 0xb0], false),
+    (0xff11, &[0xa3, 0xb1], false),
+    (0xff12, &[0xa3, 0xb2], false),
+    (0xff13, &[0xa3, 0xb3], false),
+    (0xff14, &[0xa3, 0xb4], false),
+    (0xff15, &[0xa3, 0xb5], false),
+    (0xff16, &[0xa3, 0xb6], false),
+    (0xff17, &[0xa3, 0xb7], false),
+    (0xff18, &[0xa3, 0xb8], false),
+    (0xff19, &[0xa3, 0xb9], false),
+    (0xff1a, &[0xa3, 0xba], false),
+    (0xff1b, &[0xa3, 0xbb], false),
+    (0xff1c, &[0xa3, 0xbc], false),
+    (0xff1d, &[0xa3, 0xbd], false),
+    (0xff1e, &[0xa3, 0xbe], false),
+    (0xff1f, &[0xa3, 0xbf], false),
+    (0xff20, &[0xa3, 0xc0], false),
+    (0xff21, &[0xa3, 0xc1], false),
+    (0xff22, &[0xa3, 0xc2], false),
+    (0xff23, &[0xa3, 0xc3], false),
+    (0xff24, &[0xa3, 0xc4], false),
+    (0xff25, &[0xa3, 0xc5], false),
+    (0xff26, &[0xa3, 0xc6], false),
+    (0xff27, &[0xa3, 0xc7], false),
+    (0xff28, &[0xa3, 0xc8], false),
+    (0xff29, &[0xa3, 0xc9], false),
+    (0xff2a, &[0xa3, 0xca], false),
+    (0xff2b, &[0xa3, 0xcb], false),
+    (0xff2c, &[0xa3, 0xcc], false),
+    (0xff2d, &[0xa3, 0xcd], false),
+    (0xff2e, &[0xa3, 0xce], false),
+    (0xff2f, &[0xa3, 0xcf], false),
+    (0xff30, &[0xa3, 0xd0], false),
+    (0xff31, &[0xa3, 0xd1], false),
+    (0xff32, &[0xa3, 0xd2], false),
+    (0xff33, &[0xa3, 0xd3], false),
+    (0xff34, &[0xa3, 0xd4], false),
+    (0xff35, &[0xa3, 0xd5], false),
+    (0xff36, &[0xa3, 0xd6], false),
+    (0xff37, &[0xa3, 0xd7], false),
+    (0xff38, &[0xa3, 0xd8], false),
+    (0xff39, &[0xa3, 0xd9], false),
+    (0xff3a, &[0xa3, 0xda], false),
+    (0xff3b, &[0xa3, 0xdb], false),
+    (0xffe6, &[0xa3, 0xdc], false),
+    (0xff3d, &[0xa3, 0xdd], false),
+    (0xff3e, &[0xa3, 0xde], false),
+    (0xff3f, &[0xa3, 0xdf], false),
+    (0xff40, &[0xa3, 0xe0], false),
+    (0xff41, &[0xa3, 0xe1], false),
+    (0xff42, &[0xa3, 0xe2], false),
+    (0xff43, &[0xa3, 0xe3], false),
+    (0xff44, &[0xa3, 0xe4], false),
+    (0xff45, &[0xa3, 0xe5], false),
+    (0xff46, &[0xa3, 0xe6], false),
+    (0xff47, &[0xa3, 0xe7], false),
+    (0xff48, &[0xa3, 0xe8], false),
+    (0xff49, &[0xa3, 0xe9], false),
+    (0xff4a, &[0xa3, 0xea], false),
+    (0xff4b, &[0xa3, 0xeb], false),
+    (0xff4c, &[0xa3, 0xec], false),
+    (0xff4d, &[0xa3, 0xed], false),
+    (0xff4e, &[0xa3, 0xee], false),
+    (0xff4f, &[0xa3, 0xef], false),
+    (0xff50, &[0xa3, 0xf0], false),
+    (0xff51, &[0xa3, 0xf1], false),
+    (0xff52, &[0xa3, 0xf2], false),
+    (0xff53, &[0xa3, 0xf3], false),
+    (0xff54, &[0xa3, 0xf4], false),
+    (0xff55, &[0xa3, 0xf5], false),
+    (0xff56, &[0xa3, 0xf6], false),
+    (0xff57, &[0xa3, 0xf7], false),
+    (0xff58, &[0xa3, 0xf8], false),
+    (0xff59, &[0xa3, 0xf9], false),
+    (0xff5a, &[0xa3, 0xfa], false),
+    (0xff5b, &[0xa3, 0xfb], false),
+    (0xff5c, &[0xa3, 0xfc], false),
+    (0xff5d, &[0xa3, 0xfd], false),
+    (0xffe3, &[0xa3, 0xfe], false),
+    (0xc9de, &[0xa4, 0x41], false),
+    (0xc9df, &[0xa4, 0x42], false),
+    (0xc9e1, &[0xa4, 0x43], false),
+    (0xc9e3, &[0xa4, 0x44], false),
+    (0xc9e5, &[0xa4, 0x45], false),
+    (0xc9e6, &[0xa4, 0x46], false),
+    (0xc9e8, &[0xa4, 0x47], false),
+    (0xc9e9, &[0xa4, 0x48], false),
+    (0xc9ea, &[0xa4, 0x49], false),
+    (0xc9eb, &[0xa4, 0x4a], false),
+    (0xc9ee, &[0xa4, 0x4b], false),
+    (0xc9f2, &[0xa4, 0x4c], false),
+    (0xc9f3, &[0xa4, 0x4d], false),
+    (0xc9f4, &[0xa4, 0x4e], false),
+    (0xc9f5, &[0xa4, 0x4f], false),
+    (0xc9f6, &[0xa4, 0x50], false),
+    (0xc9f7, &[0xa4, 0x51], false),
+    (0xc9fa, &[0xa4, 0x52], false),
+    (0xc9fb, &[0xa4, 0x53], false),
+    (0xc9fd, &[0xa4, 0x54], false),
+    (0xc9fe, &[0xa4, 0x55], false),
+    (0xc9ff, &[0xa4, 0x56], false),
+    (0xca01, &[0xa4, 0x57], false),
+    (0xca02, &[0xa4, 0x58], false),
+    (0xca03, &[0xa4, 0x59], false),
+    (0xca04, &[0xa4, 0x5a], false),
+    (0xca05, &[0xa4, 0x61], false),
+    (0xca06, &[0xa4, 0x62], false),
+    (0xca07, &[0xa4, 0x63], false),
+    (0xca0a, &[0xa4, 0x64], false),
+    (0xca0e, &[0xa4, 0x65], false),
+    (0xca0f, &[0xa4, 0x66], false),
+    (0xca10, &[0xa4, 0x67], false),
+    (0xca11, &[0xa4, 0x68], false),
+    (0xca12, &[0xa4, 0x69], false),
+    (0xca13, &[0xa4, 0x6a], false),
+    (0xca15, &[0xa4, 0x6b], false),
+    (0xca16, &[0xa4, 0x6c], false),
+    (0xca17, &[0xa4, 0x6d], false),
+    (0xca19, &[0xa4, 0x6e], false),
+    (0xca1a, &[0xa4, 0x6f], false),
+    (0xca1b, &[0xa4, 0x70], false),
+    (0xca1c, &[0xa4, 0x71], false),
+    (0xca1d, &[0xa4, 0x72], false),
+    (0xca1e, &[0xa4, 0x73], false),
+    (0xca1f, &[0xa4, 0x74], false),
+    (0xca20, &[0xa4, 0x75], false),
+    (0xca21, &[0xa4, 0x76], false),
+    (0xca22, &[0xa4, 0x77], false),
+    (0xca23, &[0xa4, 0x78], false),
+    (0xca24, &[0xa4, 0x79], false),
+    (0xca25, &[0xa4, 0x7a], false),
+    (0xca26, &[0xa4, 0x81], false),
+    (0xca27, &[0xa4, 0x82], false),
+    (0xca28, &[0xa4, 0x83], false),
+    (0xca2a, &[0xa4, 0x84], false),
+    (0xca2b, &[0xa4, 0x85], false),
+    (0xca2c, &[0xa4, 0x86], false),
+    (0xca2d, &[0xa4, 0x87], false),
+    (0xca2e, &[0xa4, 0x88], false),
+    (0xca2f, &[0xa4, 0x89], false),
+    (0xca30, &[0xa4, 0x8a], false),
+    (0xca31, &[0xa4, 0x8b], false),
+    (0xca32, &[0xa4, 0x8c], false),
+    (0xca33, &[0xa4, 0x8d], false),
+    (0xca34, &[0xa4, 0x8e], false),
+    (0xca35, &[0xa4, 0x8f], false),
+    (0xca36, &[0xa4, 0x90], false),
+    (0xca37, &[0xa4, 0x91], false),
+    (0xca38, &[0xa4, 0x92], false),
+    (0xca39, &[0xa4, 0x93], false),
+    (0xca3a, &[0xa4, 0x94], false),
+    (0xca3b, &[0xa4, 0x95], false),
+    (0xca3c, &[0xa4, 0x96], false),
+    (0xca3d, &[0xa4, 0x97], false),
+    (0xca3e, &[0xa4, 0x98], false),
+    (0xca3f, &[0xa4, 0x99], false),
+    (0xca40, &[0xa4, 0x9a], false),
+    (0xca41, &[0xa4, 0x9b], false),
+    (0xca42, &[0xa4, 0x9c], false),
+    (0xca43, &[0xa4, 0x9d], false),
+    (0xca44, &[0xa4, 0x9e], false),
+    (0xca45, &[0xa4, 0x9f], false),
+    (0xca46, &[0xa4, 0xa0], false),
+    (0x3131, &[0xa4, 0xa1], false),
+    (0x3132, &[0xa4, 0xa2], false),
+    (0x3133, &[0xa4, 0xa3], false),
+    (0x3134, &[0xa4, 0xa4], false),
+    (0x3135, &[0xa4, 0xa5], false),
+    (0x3136, &[0xa4, 0xa6], false),
+    (0x3137, &[0xa4, 0xa7], false),
+    (0x3138, &[0xa4, 0xa8], false),
+    (0x3139, &[0xa4, 0xa9], false),
+    (0x313a, &[0xa4, 0xaa], false),
+    (0x313b, &[0xa4, 0xab], false),
+    (0x313c, &[0xa4, 0xac], false),
+    (0x313d, &[0xa4, 0xad], false),
+    (0x313e, &[0xa4, 0xae], false),
+    (0x313f, &[0xa4, 0xaf], false),
+    (0x3140, &[0xa4, 0xb0], false),
+    (0x3141, &[0xa4, 0xb1], false),
+    (0x3142, &[0xa4, 0xb2], false),
+    (0x3143, &[0xa4, 0xb3], false),
+    (0x3144, &[0xa4, 0xb4], false),
+    (0x3145, &[0xa4, 0xb5], false),
+    (0x3146, &[0xa4, 0xb6], false),
+    (0x3147, &[0xa4, 0xb7], false),
+    (0x3148, &[0xa4, 0xb8], false),
+    (0x3149, &[0xa4, 0xb9], false),
+    (0x314a, &[0xa4, 0xba], false),
+    (0x314b, &[0xa4, 0xbb], false),
+    (0x314c, &[0xa4, 0xbc], false),
+    (0x314d, &[0xa4, 0xbd], false),
+    (0x314e, &[0xa4, 0xbe], false),
+    (0x314f, &[0xa4, 0xbf], false),
+    (0x3150, &[0xa4, 0xc0], false),
+    (0x3151, &[0xa4, 0xc1], false),
+    (0x3152, &[0xa4, 0xc2], false),
+    (0x3153, &[0xa4, 0xc3], false),
+    (0x3154, &[0xa4, 0xc4], false),
+    (0x3155, &[0xa4, 0xc5], false),
+    (0x3156, &[0xa4, 0xc6], false),
+    (0x3157, &[0xa4, 0xc7], false),
+    (0x3158, &[0xa4, 0xc8], false),
+    (0x3159, &[0xa4, 0xc9], false),
+    (0x315a, &[0xa4, 0xca], false),
+    (0x315b, &[0xa4, 0xcb], false),
+    (0x315c, &[0xa4, 0xcc], false),
+    (0x315d, &[0xa4, 0xcd], false),
+    (0x315e, &[0xa4, 0xce], false),
+    (0x315f, &[0xa4, 0xcf], false),
+    (0x3160, &[0xa4, 0xd0], false),
+    (0x3161, &[0xa4, 0xd1], false),
+    (0x3162, &[0xa4, 0xd2], false),
+    (0x3163, &[0xa4, 0xd3], false),
+    (0x3164, &[0xa4, 0xd4], false),
+    (0x3165, &[0xa4, 0xd5], false),
+    (0x3166, &[0xa4, 0xd6], false),
+    (0x3167, &[0xa4, 0xd7], false),
+    (0x3168, &[0xa4, 0xd8], false),
+    (0x3169, &[0xa4, 0xd9], false),
+    (0x316a, &[0xa4, 0xda], false),
+    (0x316b, &[0xa4, 0xdb], false),
+    (0x316c, &[0xa4, 0xdc], false),
+    (0x316d, &[0xa4, 0xdd], false),
+    (0x316e, &[0xa4, 0xde], false),
+    (0x316f, &[0xa4, 0xdf], false),
+    (0x3170, &[0xa4, 0xe0], false),
+    (0x3171, &[0xa4, 0xe1], false),
+    (0x3172, &[0xa4, 0xe2], false),
+    (0x3173, &[0xa4, 0xe3], false),
+    (0x3174, &[0xa4, 0xe4], false),
+    (0x3175, &[0xa4, 0xe5], false),
+    (0x3176, &[0xa4, 0xe6], false),
+    (0x3177, &[0xa4, 0xe7], false),
+    (0x3178, &[0xa4, 0xe8], false),
+    (0x3179, &[0xa4, 0xe9], false),
+    (0x317a, &[0xa4, 0xea], false),
+    (0x317b, &[0xa4, 0xeb], false),
+    (0x317c, &[0xa4, 0xec], false),
+    (0x317d, &[0xa4, 0xed], false),
+    (0x317e, &[0xa4, 0xee], false),
+    (0x317f, &[0xa4, 0xef], false),
+    (0x3180, &[0xa4, 0xf0], false),
+    (0x3181, &[0xa4, 0xf1], false),
+    (0x3182, &[0xa4, 0xf2], false),
+    (0x3183, &[0xa4, 0xf3], false),
+    (0x3184, &[0xa4, 0xf4], false),
+    (0x3185, &[0xa4, 0xf5], false),
+    (0x3186, &[0xa4, 0xf6], false),
+    (0x3187, &[0xa4, 0xf7], false),
+    (0x3188, &[0xa4, 0xf8], false),
+    (0x3189, &[0xa4, 0xf9], false),
+    (0x318a, &[0xa4, 0xfa], false),
+    (0x318b, &[0xa4, 0xfb], false),
+    (0x318c, &[0xa4, 0xfc], false),
+    (0x318d, &[0xa4, 0xfd], false),
+    (0x318e, &[0xa4, 0xfe], false),
+    (0xca47, &[0xa5, 0x41], false),
+    (0xca48, &[0xa5, 0x42], false),
+    (0xca49, &[0xa5, 0x43], false),
+    (0xca4a, &[0xa5, 0x44], false),
+    (0xca4b, &[0xa5, 0x45], false),
+    (0xca4e, &[0xa5, 0x46], false),
+    (0xca4f, &[0xa5, 0x47], false),
+    (0xca51, &[0xa5, 0x48], false),
+    (0xca52, &[0xa5, 0x49], false),
+    (0xca53, &[0xa5, 0x4a], false),
+    (0xca55, &[0xa5, 0x4b], false),
+    (0xca56, &[0xa5, 0x4c], false),
+    (0xca57, &[0xa5, 0x4d], false),
+    (0xca58, &[0xa5, 0x4e], false),
+    (0xca59, &[0xa5, 0x4f], false),
+    (0xca5a, &[0xa5, 0x50], false),
+    (0xca5b, &[0xa5, 0x51], false),
+    (0xca5e, &[0xa5, 0x52], false),
+    (0xca62, &[0xa5, 0x53], false),
+    (0xca63, &[0xa5, 0x54], false),
+    (0xca64, &[0xa5, 0x55], false),
+    (0xca65, &[0xa5, 0x56], false),
+    (0xca66, &[0xa5, 0x57], false),
+    (0xca67, &[0xa5, 0x58], false),
+    (0xca69, &[0xa5, 0x59], false),
+    (0xca6a, &[0xa5, 0x5a], false),
+    (0xca6b, &[0xa5, 0x61], false),
+    (0xca6c, &[0xa5, 0x62], false),
+    (0xca6d, &[0xa5, 0x63], false),
+    (0xca6e, &[0xa5, 0x64], false),
+    (0xca6f, &[0xa5, 0x65], false),
+    (0xca70, &[0xa5, 0x66], false),
+    (0xca71, &[0xa5, 0x67], false),
+    (0xca72, &[0xa5, 0x68], false),
+    (0xca73, &[0xa5, 0x69], false),
+    (0xca74, &[0xa5, 0x6a], false),
+    (0xca75, &[0xa5, 0x6b], false),
+    (0xca76, &[0xa5, 0x6c], false),
+    (0xca77, &[0xa5, 0x6d], false),
+    (0xca78, &[0xa5, 0x6e], false),
+    (0xca79, &[0xa5, 0x6f], false),
+    (0xca7a, &[0xa5, 0x70], false),
+    (0xca7b, &[0xa5, 0x71], false),
+    (0xca7c, &[0xa5, 0x72], false),
+    (0xca7e, &[0xa5, 0x73], false),
+    (0xca7f, &[0xa5, 0x74], false),
+    (0xca80, &[0xa5, 0x75], false),
+    (0xca81, &[0xa5, 0x76], false),
+    (0xca82, &[0xa5, 0x77], false),
+    (0xca83, &[0xa5, 0x78], false),
+    (0xca85, &[0xa5, 0x79], false),
+    (0xca86, &[0xa5, 0x7a], false),
+    (0xca87, &[0xa5, 0x81], false),
+    (0xca88, &[0xa5, 0x82], false),
+    (0xca89, &[0xa5, 0x83], false),
+    (0xca8a, &[0xa5, 0x84], false),
+    (0xca8b, &[0xa5, 0x85], false),
+    (0xca8c, &[0xa5, 0x86], false),
+    (0xca8d, &[0xa5, 0x87], false),
+    (0xca8e, &[0xa5, 0x88], false),
+    (0xca8f, &[0xa5, 0x89], false),
+    (0xca90, &[0xa5, 0x8a], false),
+    (0xca91, &[0xa5, 0x8b], false),
+    (0xca92, &[0xa5, 0x8c], false),
+    (0xca93, &[0xa5, 0x8d], false),
+    (0xca94, &[0xa5, 0x8e], false),
+    (0xca95, &[0xa5, 0x8f], false),
+    (0xca96, &[0xa5, 0x90], false),
+    (0xca97, &[0xa5, 0x91], false),
+    (0xca99, &[0xa5, 0x92], false),
+    (0xca9a, &[0xa5, 0x93], false),
+    (0xca9b, &[0xa5, 0x94], false),
+    (0xca9c, &[0xa5, 0x95], false),
+    (0xca9d, &[0xa5, 0x96], false),
+    (0xca9e, &[0xa5, 0x97], false),
+    (0xca9f, &[0xa5, 0x98], false),
+    (0xcaa0, &[0xa5, 0x99], false),
+    (0xcaa1, &[0xa5, 0x9a], false),
+    (0xcaa2, &[0xa5, 0x9b], false),
+    (0xcaa3, &[0xa5, 0x9c], false),
+    (0xcaa4, &[0xa5, 0x9d], false),
+    (0xcaa5, &[0xa5, 0x9e], false),
+    (0xcaa6, &[0xa5, 0x9f], false),
+    (0xcaa7, &[0xa5, 0xa0], false),
+    (0x2170, &[0xa5, 0xa1], false),
+    (0x2171, &[0xa5, 0xa2], false),
+    (0x2172, &[0xa5, 0xa3], false),
+    (0x2173, &[0xa5, 0xa4], false),
+    (0x2174, &[0xa5, 0xa5], false),
+    (0x2175, &[0xa5, 0xa6], false),
+    (0x2176, &[0xa5, 0xa7], false),
+    (0x2177, &[0xa5, 0xa8], false),
+    (0x2178, &[0xa5, 0xa9], false),
+    (0x2179, &[0xa5, 0xaa], false),
+    (0x2160, &[0xa5, 0xb0], false),
+    (0x2161, &[0xa5, 0xb1], false),
+    (0x2162, &[0xa5, 0xb2], false),
+    (0x2163, &[0xa5, 0xb3], false),
+    (0x2164, &[0xa5, 0xb4], false),
+    (0x2165, &[0xa5, 0xb5], false),
+    (0x2166, &[0xa5, 0xb6], false),
+    (0x2167, &[0xa5, 0xb7], false),
+    (0x2168, &[0xa5, 0xb8], false),
+    (0x2169, &[0xa5, 0xb9], false),
+    (0x0391, &[0xa5, 0xc1], false),
+    (0x0392, &[0xa5, 0xc2], false),
+    (0x0393, &[0xa5, 0xc3], false),
+    (0x0394, &[0xa5, 0xc4], false),
+    (0x0395, &[0xa5, 0xc5], false),
+    (0x0396, &[0xa5, 0xc6], false),
+    (0x0397, &[0xa5, 0xc7], false),
+    (0x0398, &[0xa5, 0xc8], false),
+    (0x0399, &[0xa5, 0xc9], false),
+    (0x039a, &[0xa5, 0xca], false),
+    (0x039b, &[0xa5, 0xcb], false),
+    (0x039c, &[0xa5, 0xcc], false),
+    (0x039d, &[0xa5, 0xcd], false),
+    (0x039e, &[0xa5, 0xce], false),
+    (0x039f, &[0xa5, 0xcf], false),
+    (0x03a0, &[0xa5, 0xd0], false),
+    (0x03a1, &[0xa5, 0xd1], false),
+    (0x03a3, &[0xa5, 0xd2], false),
+    (0x03a4, &[0xa5, 0xd3], false),
+    (0x03a5, &[0xa5, 0xd4], false),
+    (0x03a6, &[0xa5, 0xd5], false),
+    (0x03a7, &[0xa5, 0xd6], false),
+    (0x03a8, &[0xa5, 0xd7], false),
+    (0x03a9, &[0xa5, 0xd8], false),
+    (0x03b1, &[0xa5, 0xe1], false),
+    (0x03b2, &[0xa5, 0xe2], false),
+    (0x03b3, &[0xa5, 0xe3], false),
+    (0x03b4, &[0xa5, 0xe4], false),
+    (0x03b5, &[0xa5, 0xe5], false),
+    (0x03b6, &[0xa5, 0xe6], false),
+    (0x03b7, &[0xa5, 0xe7], false),
+    (0x03b8, &[0xa5, 0xe8], false),
+    (0x03b9, &[0xa5, 0xe9], false),
+    (0x03ba, &[0xa5, 0xea], false),
+    (0x03bb, &[0xa5, 0xeb], false),
+    (0x03bc, &[0xa5, 0xec], false),
+    (0x03bd, &[0xa5, 0xed], false),
+    (0x03be, &[0xa5, 0xee], false),
+    (0x03bf, &[0xa5, 0xef], false),
+    (0x03c0, &[0xa5, 0xf0], false),
+    (0x03c1, &[0xa5, 0xf1], false),
+    (0x03c3, &[0xa5, 0xf2], false),
+    (0x03c4, &[0xa5, 0xf3], false),
+    (0x03c5, &[0xa5, 0xf4], false),
+    (0x03c6, &[0xa5, 0xf5], false),
+    (0x03c7, &[0xa5, 0xf6], false),
+    (0x03c8, &[0xa5, 0xf7], false),
+    (0x03c9, &[0xa5, 0xf8], false),
+    (0xcaa8, &[0xa6, 0x41], false),
+    (0xcaa9, &[0xa6, 0x42], false),
+    (0xcaaa, &[0xa6, 0x43], false),
+    (0xcaab, &[0xa6, 0x44], false),
+    (0xcaac, &[0xa6, 0x45], false),
+    (0xcaad, &[0xa6, 0x46], false),
+    (0xcaae, &[0xa6, 0x47], false),
+    (0xcaaf, &[0xa6, 0x48], false),
+    (0xcab0, &[0xa6, 0x49], false),
+    (0xcab1, &[0xa6, 0x4a], false),
+    (0xcab2, &[0xa6, 0x4b], false),
+    (0xcab3, &[0xa6, 0x4c], false),
+    (0xcab4, &[0xa6, 0x4d], false),
+    (0xcab5, &[0xa6, 0x4e], false),
+    (0xcab6, &[0xa6, 0x4f], false),
+    (0xcab7, &[0xa6, 0x50], false),
+    (0xcab8, &[0xa6, 0x51], false),
+    (0xcab9, &[0xa6, 0x52], false),
+    (0xcaba, &[0xa6, 0x53], false),
+    (0xcabb, &[0xa6, 0x54], false),
+    (0xcabe, &[0xa6, 0x55], false),
+    (0xcabf, &[0xa6, 0x56], false),
+    (0xcac1, &[0xa6, 0x57], false),
+    (0xcac2, &[0xa6, 0x58], false),
+    (0xcac3, &[0xa6, 0x59], false),
+    (0xcac5, &[0xa6, 0x5a], false),
+    (0xcac6, &[0xa6, 0x61], false),
+    (0xcac7, &[0xa6, 0x62], false),
+    (0xcac8, &[0xa6, 0x63], false),
+    (0xcac9, &[0xa6, 0x64], false),
+    (0xcaca, &[0xa6, 0x65], false),
+    (0xcacb, &[0xa6, 0x66], false),
+    (0xcace, &[0xa6, 0x67], false),
+    (0xcad0, &[0xa6, 0x68], false),
+    (0xcad2, &[0xa6, 0x69], false),
+    (0xcad4, &[0xa6, 0x6a], false),
+    (0xcad5, &[0xa6, 0x6b], false),
+    (0xcad6, &[0xa6, 0x6c], false),
+    (0xcad7, &[0xa6, 0x6d], false),
+    (0xcada, &[0xa6, 0x6e], false),
+    (0xcadb, &[0xa6, 0x6f], false),
+    (0xcadc, &[0xa6, 0x70], false),
+    (0xcadd, &[0xa6, 0x71], false),
+    (0xcade, &[0xa6, 0x72], false),
+    (0xcadf, &[0xa6, 0x73], false),
+    (0xcae1, &[0xa6, 0x74], false),
+    (0xcae2, &[0xa6, 0x75], false),
+    (0xcae3, &[0xa6, 0x76], false),
+    (0xcae4, &[0xa6, 0x77], false),
+    (0xcae5, &[0xa6, 0x78], false),
+    (0xcae6, &[0xa6, 0x79], false),
+    (0xcae7, &[0xa6, 0x7a], false),
+    (0xcae8, &[0xa6, 0x81], false),
+    (0xcae9, &[0xa6, 0x82], false),
+    (0xcaea, &[0xa6, 0x83], false),
+    (0xcaeb, &[0xa6, 0x84], false),
+    (0xcaed, &[0xa6, 0x85], false),
+    (0xcaee, &[0xa6, 0x86], false),
+    (0xcaef, &[0xa6, 0x87], false),
+    (0xcaf0, &[0xa6, 0x88], false),
+    (0xcaf1, &[0xa6, 0x89], false),
+    (0xcaf2, &[0xa6, 0x8a], false),
+    (0xcaf3, &[0xa6, 0x8b], false),
+    (0xcaf5, &[0xa6, 0x8c], false),
+    (0xcaf6, &[0xa6, 0x8d], false),
+    (0xcaf7, &[0xa6, 0x8e], false),
+    (0xcaf8, &[0xa6, 0x8f], false),
+    (0xcaf9, &[0xa6, 0x90], false),
+    (0xcafa, &[0xa6, 0x91], false),
+    (0xcafb, &[0xa6, 0x92], false),
+    (0xcafc, &[0xa6, 0x93], false),
+    (0xcafd, &[0xa6, 0x94], false),
+    (0xcafe, &[0xa6, 0x95], false),
+    (0xcaff, &[0xa6, 0x96], false),
+    (0xcb00, &[0xa6, 0x97], false),
+    (0xcb01, &[0xa6, 0x98], false),
+    (0xcb02, &[0xa6, 0x99], false),
+    (0xcb03, &[0xa6, 0x9a], false),
+    (0xcb04, &[0xa6, 0x9b], false),
+    (0xcb05, &[0xa6, 0x9c], false),
+    (0xcb06, &[0xa6, 0x9d], false),
+    (0xcb07, &[0xa6, 0x9e], false),
+    (0xcb09, &[0xa6, 0x9f], false),
+    (0xcb0a, &[0xa6, 0xa0], false),
+    (0x2500, &[0xa6, 0xa1], false),
+    (0x2502, &[0xa6, 0xa2], false),
+    (0x250c, &[0xa6, 0xa3], false),
+    (0x2510, &[0xa6, 0xa4], false),
+    (0x2518, &[0xa6, 0xa5], false),
+    (0x2514, &[0xa6, 0xa6], false),
+    (0x251c, &[0xa6, 0xa7], false),
+    (0x252c, &[0xa6, 0xa8], false),
+    (0x2524, &[0xa6, 0xa9], false),
+    (0x2534, &[0xa6, 0xaa], false),
+    (0x253c, &[0xa6, 0xab], false),
+    (0x2501, &[0xa6, 0xac], false),
+    (0x2503, &[0xa6, 0xad], false),
+    (0x250f, &[0xa6, 0xae], false),
+    (0x2513, &[0xa6, 0xaf], false),
+    (0x251b, &[0xa6, 0xb0], false),
+    (0x2517, &[0xa6, 0xb1], false),
+    (0x2523, &[0xa6, 0xb2], false),
+    (0x2533, &[0xa6, 0xb3], false),
+    (0x252b, &[0xa6, 0xb4], false),
+    (0x253b, &[0xa6, 0xb5], false),
+    (0x254b, &[0xa6, 0xb6], false),
+    (0x2520, &[0xa6, 0xb7], false),
+    (0x252f, &[0xa6, 0xb8], false),
+    (0x2528, &[0xa6, 0xb9], false),
+    (0x2537, &[0xa6, 0xba], false),
+    (0x253f, &[0xa6, 0xbb], false),
+    (0x251d, &[0xa6, 0xbc], false),
+    (0x2530, &[0xa6, 0xbd], false),
+    (0x2525, &[0xa6, 0xbe], false),
+    (0x2538, &[0xa6, 0xbf], false),
+    (0x2542, &[0xa6, 0xc0], false),
+    (0x2512, &[0xa6, 0xc1], false),
+    (0x2511, &[0xa6, 0xc2], false),
+    (0x251a, &[0xa6, 0xc3], false),
+    (0x2519, &[0xa6, 0xc4], false),
+    (0x2516, &[0xa6, 0xc5], false),
+    (0x2515, &[0xa6, 0xc6], false),
+    (0x250e, &[0xa6, 0xc7], false),
+    (0x250d, &[0xa6, 0xc8], false),
+    (0x251e, &[0xa6, 0xc9], false),
+    (0x251f, &[0xa6, 0xca], false),
+    (0x2521, &[0xa6, 0xcb], false),
+    (0x2522, &[0xa6, 0xcc], false),
+    (0x2526, &[0xa6, 0xcd], false),
+    (0x2527, &[0xa6, 0xce], false),
+    (0x2529, &[0xa6, 0xcf], false),
+    (0x252a, &[0xa6, 0xd0], false),
+    (0x252d, &[0xa6, 0xd1], false),
+    (0x252e, &[0xa6, 0xd2], false),
+    (0x2531, &[0xa6, 0xd3], false),
+    (0x2532, &[0xa6, 0xd4], false),
+    (0x2535, &[0xa6, 0xd5], false),
+    (0x2536, &[0xa6, 0xd6], false),
+    (0x2539, &[0xa6, 0xd7], false),
+    (0x253a, &[0xa6, 0xd8], false),
+    (0x253d, &[0xa6, 0xd9], false),
+    (0x253e, &[0xa6, 0xda], false),
+    (0x2540, &[0xa6, 0xdb], false),
+    (0x2541, &[0xa6, 0xdc], false),
+    (0x2543, &[0xa6, 0xdd], false),
+    (0x2544, &[0xa6, 0xde], false),
+    (0x2545, &[0xa6, 0xdf], false),
+    (0x2546, &[0xa6, 0xe0], false),
+    (0x2547, &[0xa6, 0xe1], false),
+    (0x2548, &[0xa6, 0xe2], false),
+    (0x2549, &[0xa6, 0xe3], false),
+    (0x254a, &[0xa6, 0xe4], false),
+    (0xcb0b, &[0xa7, 0x41], false),
+    (0xcb0c, &[0xa7, 0x42], false),
+    (0xcb0d, &[0xa7, 0x43], false),
+    (0xcb0e, &[0xa7, 0x44], false),
+    (0xcb0f, &[0xa7, 0x45], false),
+    (0xcb11, &[0xa7, 0x46], false),
+    (0xcb12, &[0xa7, 0x47], false),
+    (0xcb13, &[0xa7, 0x48], false),
+    (0xcb15, &[0xa7, 0x49], false),
+    (0xcb16, &[0xa7, 0x4a], false),
+    (0xcb17, &[0xa7, 0x4b], false),
+    (0xcb19, &[0xa7, 0x4c], false),
+    (0xcb1a, &[0xa7, 0x4d], false),
+    (0xcb1b, &[0xa7, 0x4e], false),
+    (0xcb1c, &[0xa7, 0x4f], false),
+    (0xcb1d, &[0xa7, 0x50], false),
+    (0xcb1e, &[0xa7, 0x51], false),
+    (0xcb1f, &[0xa7, 0x52], false),
+    (0xcb22, &[0xa7, 0x53], false),
+    (0xcb23, &[0xa7, 0x54], false),
+    (0xcb24, &[0xa7, 0x55], false),
+    (0xcb25, &[0xa7, 0x56], false),
+    (0xcb26, &[0xa7, 0x57], false),
+    (0xcb27, &[0xa7, 0x58], false),
+    (0xcb28, &[0xa7, 0x59], false),
+    (0xcb29, &[0xa7, 0x5a], false),
+    (0xcb2a, &[0xa7, 0x61], false),
+    (0xcb2b, &[0xa7, 0x62], false),
+    (0xcb2c, &[0xa7, 0x63], false),
+    (0xcb2d, &[0xa7, 0x64], false),
+    (0xcb2e, &[0xa7, 0x65], false),
+    (0xcb2f, &[0xa7, 0x66], false),
+    (0xcb30, &[0xa7, 0x67], false),
+    (0xcb31, &[0xa7, 0x68], false),
+    (0xcb32, &[0xa7, 0x69], false),
+    (0xcb33, &[0xa7, 0x6a], false),
+    (0xcb34, &[0xa7, 0x6b], false),
+    (0xcb35, &[0xa7, 0x6c], false),
+    (0xcb36, &[0xa7, 0x6d], false),
+    (0xcb37, &[0xa7, 0x6e], false),
+    (0xcb38, &[0xa7, 0x6f], false),
+    (0xcb39, &[0xa7, 0x70], false),
+    (0xcb3a, &[0xa7, 0x71], false),
+    (0xcb3b, &[0xa7, 0x72], false),
+    (0xcb3c, &[0xa7, 0x73], false),
+    (0xcb3d, &[0xa7, 0x74], false),
+    (0xcb3e, &[0xa7, 0x75], false),
+    (0xcb3f, &[0xa7, 0x76], false),
+    (0xcb40, &[0xa7, 0x77], false),
+    (0xcb42, &[0xa7, 0x78], false),
+    (0xcb43, &[0xa7, 0x79], false),
+    (0xcb44, &[0xa7, 0x7a], false),
+    (0xcb45, &[0xa7, 0x81], false),
+    (0xcb46, &[0xa7, 0x82], false),
+    (0xcb47, &[0xa7, 0x83], false),
+    (0xcb4a, &[0xa7, 0x84], false),
+    (0xcb4b, &[0xa7, 0x85], false),
+    (0xcb4d, &[0xa7, 0x86], false),
+    (0xcb4e, &[0xa7, 0x87], false),
+    (0xcb4f, &[0xa7, 0x88], false),
+    (0xcb51, &[0xa7, 0x89], false),
+    (0xcb52, &[0xa7, 0x8a], false),
+    (0xcb53, &[0xa7, 0x8b], false),
+    (0xcb54, &[0xa7, 0x8c], false),
+    (0xcb55, &[0xa7, 0x8d], false),
+    (0xcb56, &[0xa7, 0x8e], false),
+    (0xcb57, &[0xa7, 0x8f], false),
+    (0xcb5a, &[0xa7, 0x90], false),
+    (0xcb5b, &[0xa7, 0x91], false),
+    (0xcb5c, &[0xa7, 0x92], false),
+    (0xcb5e, &[0xa7, 0x93], false),
+    (0xcb5f, &[0xa7, 0x94], false),
+    (0xcb60, &[0xa7, 0x95], false),
+    (0xcb61, &[0xa7, 0x96], false),
+    (0xcb62, &[0xa7, 0x97], false),
+    (0xcb63, &[0xa7, 0x98], false),
+    (0xcb65, &[0xa7, 0x99], false),
+    (0xcb66, &[0xa7, 0x9a], false),
+    (0xcb67, &[0xa7, 0x9b], false),
+    (0xcb68, &[0xa7, 0x9c], false),
+    (0xcb69, &[0xa7, 0x9d], false),
+    (0xcb6a, &[0xa7, 0x9e], false),
+    (0xcb6b, &[0xa7, 0x9f], false),
+    (0xcb6c, &[0xa7, 0xa0], false),
+    (0x3395, &[0xa7, 0xa1], false),
+    (0x3396, &[0xa7, 0xa2], false),
+    (0x3397, &[0xa7, 0xa3], false),
+    (0x2113, &[0xa7, 0xa4], false),
+    (0x3398, &[0xa7, 0xa5], false),
+    (0x33c4, &[0xa7, 0xa6], false),
+    (0x33a3, &[0xa7, 0xa7], false),
+    (0x33a4, &[0xa7, 0xa8], false),
+    (0x33a5, &[0xa7, 0xa9], false),
+    (0x33a6, &[0xa7, 0xaa], false),
+    (0x3399, &[0xa7, 0xab], false),
+    (0x339a, &[0xa7, 0xac], false),
+    (0x339b, &[0xa7, 0xad], false),
+    (0x339c, &[0xa7, 0xae], false),
+    (0x339d, &[0xa7, 0xaf], false),
+    (0x339e, &[0xa7, 0xb0], false),
+    (0x339f, &[0xa7, 0xb1], false),
+    (0x33a0, &[0xa7, 0xb2], false),
+    (0x33a1, &[0xa7, 0xb3], false),
+    (0x33a2, &[0xa7, 0xb4], false),
+    (0x33ca, &[0xa7, 0xb5], false),
+    (0x338d, &[0xa7, 0xb6], false),
+    (0x338e, &[0xa7, 0xb7], false),
+    (0x338f, &[0xa7, 0xb8], false),
+    (0x33cf, &[0xa7, 0xb9], false),
+    (0x3388, &[0xa7, 0xba], false),
+    (0x3389, &[0xa7, 0xbb], false),
+    (0x33c8, &[0xa7, 0xbc], false),
+    (0x33a7, &[0xa7, 0xbd], false),
+    (0x33a8, &[0xa7, 0xbe], false),
+    (0x33b0, &[0xa7, 0xbf], false),
+    (0x33b1, &[0xa7, 0xc0], false),
+    (0x33b2, &[0xa7, 0xc1], false),
+    (0x33b3, &[0xa7, 0xc2], false),
+    (0x33b4, &[0xa7, 0xc3], false),
+    (0x33b5, &[0xa7, 0xc4], false),
+    (0x33b6, &[0xa7, 0xc5], false),
+    (0x33b7, &[0xa7, 0xc6], false),
+    (0x33b8, &[0xa7, 0xc7], false),
+    (0x33b9, &[0xa7, 0xc8], false),
+    (0x3380, &[0xa7, 0xc9], false),
+    (0x3381, &[0xa7, 0xca], false),
+    (0x3382, &[0xa7, 0xcb], false),
+    (0x3383, &[0xa7, 0xcc], false),
+    (0x3384, &[0xa7, 0xcd], false),
+    (0x33ba, &[0xa7, 0xce], false),
+    (0x33bb, &[0xa7, 0xcf], false),
+    (0x33bc, &[0xa7, 0xd0], false),
+    (0x33bd, &[0xa7, 0xd1], false),
+    (0x33be, &[0xa7, 0xd2], false),
+    (0x33bf, &[0xa7, 0xd3], false),
+    (0x3390, &[0xa7, 0xd4], false),
+    (0x3391, &[0xa7, 0xd5], false),
+    (0x3392, &[0xa7, 0xd6], false),
+    (0x3393, &[0xa7, 0xd7], false),
+    (0x3394, &[0xa7, 0xd8], false),
+    (0x2126, &[0xa7, 0xd9], false),
+    (0x33c0, &[0xa7, 0xda], false),
+    (0x33c1, &[0xa7, 0xdb], false),
+    (0x338a, &[0xa7, 0xdc], false),
+    (0x338b, &[0xa7, 0xdd], false),
+    (0x338c, &[0xa7, 0xde], false),
+    (0x33d6, &[0xa7, 0xdf], false),
+    (0x33c5, &[0xa7, 0xe0], false),
+    (0x33ad, &[0xa7, 0xe1], false),
+    (0x33ae, &[0xa7, 0xe2], false),
+    (0x33af, &[0xa7, 0xe3], false),
+    (0x33db, &[0xa7, 0xe4], false),
+    (0x33a9, &[0xa7, 0xe5], false),
+    (0x33aa, &[0xa7, 0xe6], false),
+    (0x33ab, &[0xa7, 0xe7], false),
+    (0x33ac, &[0xa7, 0xe8], false),
+    (0x33dd, &[0xa7, 0xe9], false),
+    (0x33d0, &[0xa7, 0xea], false),
+    (0x33d3, &[0xa7, 0xeb], false),
+    (0x33c3, &[0xa7, 0xec], false),
+    (0x33c9, &[0xa7, 0xed], false),
+    (0x33dc, &[0xa7, 0xee], false),
+    (0x33c6, &[0xa7, 0xef], false),
+    (0xcb6d, &[0xa8, 0x41], false),
+    (0xcb6e, &[0xa8, 0x42], false),
+    (0xcb6f, &[0xa8, 0x43], false),
+    (0xcb70, &[0xa8, 0x44], false),
+    (0xcb71, &[0xa8, 0x45], false),
+    (0xcb72, &[0xa8, 0x46], false),
+    (0xcb73, &[0xa8, 0x47], false),
+    (0xcb74, &[0xa8, 0x48], false),
+    (0xcb75, &[0xa8, 0x49], false),
+    (0xcb76, &[0xa8, 0x4a], false),
+    (0xcb77, &[0xa8, 0x4b], false),
+    (0xcb7a, &[0xa8, 0x4c], false),
+    (0xcb7b, &[0xa8, 0x4d], false),
+    (0xcb7c, &[0xa8, 0x4e], false),
+    (0xcb7d, &[0xa8, 0x4f], false),
+    (0xcb7e, &[0xa8, 0x50], false),
+    (0xcb7f, &[0xa8, 0x51], false),
+    (0xcb80, &[0xa8, 0x52], false),
+    (0xcb81, &[0xa8, 0x53], false),
+    (0xcb82, &[0xa8, 0x54], false),
+    (0xcb83, &[0xa8, 0x55], false),
+    (0xcb84, &[0xa8, 0x56], false),
+    (0xcb85, &[0xa8, 0x57], false),
+    (0xcb86, &[0xa8, 0x58], false),
+    (0xcb87, &[0xa8, 0x59], false),
+    (0xcb88, &[0xa8, 0x5a], false),
+    (0xcb89, &[0xa8, 0x61], false),
+    (0xcb8a, &[0xa8, 0x62], false),
+    (0xcb8b, &[0xa8, 0x63], false),
+    (0xcb8c, &[0xa8, 0x64], false),
+    (0xcb8d, &[0xa8, 0x65], false),
+    (0xcb8e, &[0xa8, 0x66], false),
+    (0xcb8f, &[0xa8, 0x67], false),
+    (0xcb90, &[0xa8, 0x68], false),
+    (0xcb91, &[0xa8, 0x69], false),
+    (0xcb92, &[0xa8, 0x6a], false),
+    (0xcb93, &[0xa8, 0x6b], false),
+    (0xcb94, &[0xa8, 0x6c], false),
+    (0xcb95, &[0xa8, 0x6d], false),
+    (0xcb96, &[0xa8, 0x6e], false),
+    (0xcb97, &[0xa8, 0x6f], false),
+    (0xcb98, &[0xa8, 0x70], false),
+    (0xcb99, &[0xa8, 0x71], false),
+    (0xcb9a, &[0xa8, 0x72], false),
+    (0xcb9b, &[0xa8, 0x73], false),
+    (0xcb9d, &[0xa8, 0x74], false),
+    (0xcb9e, &[0xa8, 0x75], false),
+    (0xcb9f, &[0xa8, 0x76], false),
+    (0xcba0, &[0xa8, 0x77], false),
+    (0xcba1, &[0xa8, 0x78], false),
+    (0xcba2, &[0xa8, 0x79], false),
+    (0xcba3, &[0xa8, 0x7a], false),
+    (0xcba4, &[0xa8, 0x81], false),
+    (0xcba5, &[0xa8, 0x82], false),
+    (0xcba6, &[0xa8, 0x83], false),
+    (0xcba7, &[0xa8, 0x84], false),
+    (0xcba8, &[0xa8, 0x85], false),
+    (0xcba9, &[0xa8, 0x86], false),
+    (0xcbaa, &[0xa8, 0x87], false),
+    (0xcbab, &[0xa8, 0x88], false),
+    (0xcbac, &[0xa8, 0x89], false),
+    (0xcbad, &[0xa8, 0x8a], false),
+    (0xcbae, &[0xa8, 0x8b], false),
+    (0xcbaf, &[0xa8, 0x8c], false),
+    (0xcbb0, &[0xa8, 0x8d], false),
+    (0xcbb1, &[0xa8, 0x8e], false),
+    (0xcbb2, &[0xa8, 0x8f], false),
+    (0xcbb3, &[0xa8, 0x90], false),
+    (0xcbb4, &[0xa8, 0x91], false),
+    (0xcbb5, &[0xa8, 0x92], false),
+    (0xcbb6, &[0xa8, 0x93], false),
+    (0xcbb7, &[0xa8, 0x94], false),
+    (0xcbb9, &[0xa8, 0x95], false),
+    (0xcbba, &[0xa8, 0x96], false),
+    (0xcbbb, &[0xa8, 0x97], false),
+    (0xcbbc, &[0xa8, 0x98], false),
+    (0xcbbd, &[0xa8, 0x99], false),
+    (0xcbbe, &[0xa8, 0x9a], false),
+    (0xcbbf, &[0xa8, 0x9b], false),
+    (0xcbc0, &[0xa8, 0x9c], false),
+    (0xcbc1, &[0xa8, 0x9d], false),
+    (0xcbc2, &[0xa8, 0x9e], false),
+    (0xcbc3, &[0xa8, 0x9f], false),
+    (0xcbc4, &[0xa8, 0xa0], false),
+    (0x00c6, &[0xa8, 0xa1], false),
+    (0x00d0, &[0xa8, 0xa2], false),
+    (0x00aa, &[0xa8, 0xa3], false),
+    (0x0126, &[0xa8, 0xa4], false),
+    (0x0132, &[0xa8, 0xa6], false),
+    (0x013f, &[0xa8, 0xa8], false),
+    (0x0141, &[0xa8, 0xa9], false),
+    (0x00d8, &[0xa8, 0xaa], false),
+    (0x0152, &[0xa8, 0xab], false),
+    (0x00ba, &[0xa8, 0xac], false),
+    (0x00de, &[0xa8, 0xad], false),
+    (0x0166, &[0xa8, 0xae], false),
+    (0x014a, &[0xa8, 0xaf], false),
+    (0x3260, &[0xa8, 0xb1], false),
+    (0x3261, &[0xa8, 0xb2], false),
+    (0x3262, &[0xa8, 0xb3], false),
+    (0x3263, &[0xa8, 0xb4], false),
+    (0x3264, &[0xa8, 0xb5], false),
+    (0x3265, &[0xa8, 0xb6], false),
+    (0x3266, &[0xa8, 0xb7], false),
+    (0x3267, &[0xa8, 0xb8], false),
+    (0x3268, &[0xa8, 0xb9], false),
+    (0x3269, &[0xa8, 0xba], false),
+    (0x326a, &[0xa8, 0xbb], false),
+    (0x326b, &[0xa8, 0xbc], false),
+    (0x326c, &[0xa8, 0xbd], false),
+    (0x326d, &[0xa8, 0xbe], false),
+    (0x326e, &[0xa8, 0xbf], false),
+    (0x326f, &[0xa8, 0xc0], false),
+    (0x3270, &[0xa8, 0xc1], false),
+    (0x3271, &[0xa8, 0xc2], false),
+    (0x3272, &[0xa8, 0xc3], false),
+    (0x3273, &[0xa8, 0xc4], false),
+    (0x3274, &[0xa8, 0xc5], false),
+    (0x3275, &[0xa8, 0xc6], false),
+    (0x3276, &[0xa8, 0xc7], false),
+    (0x3277, &[0xa8, 0xc8], false),
+    (0x3278, &[0xa8, 0xc9], false),
+    (0x3279, &[0xa8, 0xca], false),
+    (0x327a, &[0xa8, 0xcb], false),
+    (0x327b, &[0xa8, 0xcc], false),
+    (0x24d0, &[0xa8, 0xcd], false),
+    (0x24d1, &[0xa8, 0xce], false),
+    (0x24d2, &[0xa8, 0xcf], false),
+    (0x24d3, &[0xa8, 0xd0], false),
+    (0x24d4, &[0xa8, 0xd1], false),
+    (0x24d5, &[0xa8, 0xd2], false),
+    (0x24d6, &[0xa8, 0xd3], false),
+    (0x24d7, &[0xa8, 0xd4], false),
+    (0x24d8, &[0xa8, 0xd5], false),
+    (0x24d9, &[0xa8, 0xd6], false),
+    (0x24da, &[0xa8, 0xd7], false),
+    (0x24db, &[0xa8, 0xd8], false),
+    (0x24dc, &[0xa8, 0xd9], false),
+    (0x24dd, &[0xa8, 0xda], false),
+    (0x24de, &[0xa8, 0xdb], false),
+    (0x24df, &[0xa8, 0xdc], false),
+    (0x24e0, &[0xa8, 0xdd], false),
+    (0x24e1, &[0xa8, 0xde], false),
+    (0x24e2, &[0xa8, 0xdf], false),
+    (0x24e3, &[0xa8, 0xe0], false),
+    (0x24e4, &[0xa8, 0xe1], false),
+    (0x24e5, &[0xa8, 0xe2], false),
+    (0x24e6, &[0xa8, 0xe3], false),
+    (0x24e7, &[0xa8, 0xe4], false),
+    (0x24e8, &[0xa8, 0xe5], false),
+    (0x24e9, &[0xa8, 0xe6], false),
+    (0x2460, &[0xa8, 0xe7], false),
+    (0x2461, &[0xa8, 0xe8], false),
+    (0x2462, &[0xa8, 0xe9], false),
+    (0x2463, &[0xa8, 0xea], false),
+    (0x2464, &[0xa8, 0xeb], false),
+    (0x2465, &[0xa8, 0xec], false),
+    (0x2466, &[0xa8, 0xed], false),
+    (0x2467, &[0xa8, 0xee], false),
+    (0x2468, &[0xa8, 0xef], false),
+    (0x2469, &[0xa8, 0xf0], false),
+    (0x246a, &[0xa8, 0xf1], false),
+    (0x246b, &[0xa8, 0xf2], false),
+    (0x246c, &[0xa8, 0xf3], false),
+    (0x246d, &[0xa8, 0xf4], false),
+    (0x246e, &[0xa8, 0xf5], false),
+    (0x00bd, &[0xa8, 0xf6], false),
+    (0x2153, &[0xa8, 0xf7], false),
+    (0x2154, &[0xa8, 0xf8], false),
+    (0x00bc, &[0xa8, 0xf9], false),
+    (0x00be, &[0xa8, 0xfa], false),
+    (0x215b, &[0xa8, 0xfb], false),
+    (0x215c, &[0xa8, 0xfc], false),
+    (0x215d, &[0xa8, 0xfd], false),
+    (0x215e, &[0xa8, 0xfe], false),
+    (0xcbc5, &[0xa9, 0x41], false),
+    (0xcbc6, &[0xa9, 0x42], false),
+    (0xcbc7, &[0xa9, 0x43], false),
+    (0xcbc8, &[0xa9, 0x44], false),
+    (0xcbc9, &[0xa9, 0x45], false),
+    (0xcbca, &[0xa9, 0x46], false),
+    (0xcbcb, &[0xa9, 0x47], false),
+    (0xcbcc, &[0xa9, 0x48], false),
+    (0xcbcd, &[0xa9, 0x49], false),
+    (0xcbce, &[0xa9, 0x4a], false),
+    (0xcbcf, &[0xa9, 0x4b], false),
+    (0xcbd0, &[0xa9, 0x4c], false),
+    (0xcbd1, &[0xa9, 0x4d], false),
+    (0xcbd2, &[0xa9, 0x4e], false),
+    (0xcbd3, &[0xa9, 0x4f], false),
+    (0xcbd5, &[0xa9, 0x50], false),
+    (0xcbd6, &[0xa9, 0x51], false),
+    (0xcbd7, &[0xa9, 0x52], false),
+    (0xcbd8, &[0xa9, 0x53], false),
+    (0xcbd9, &[0xa9, 0x54], false),
+    (0xcbda, &[0xa9, 0x55], false),
+    (0xcbdb, &[0xa9, 0x56], false),
+    (0xcbdc, &[0xa9, 0x57], false),
+    (0xcbdd, &[0xa9, 0x58], false),
+    (0xcbde, &[0xa9, 0x59], false),
+    (0xcbdf, &[0xa9, 0x5a], false),
+    (0xcbe0, &[0xa9, 0x61], false),
+    (0xcbe1, &[0xa9, 0x62], false),
+    (0xcbe2, &[0xa9, 0x63], false),
+    (0xcbe3, &[0xa9, 0x64], false),
+    (0xcbe5, &[0xa9, 0x65], false),
+    (0xcbe6, &[0xa9, 0x66], false),
+    (0xcbe8, &[0xa9, 0x67], false),
+    (0xcbea, &[0xa9, 0x68], false),
+    (0xcbeb, &[0xa9, 0x69], false),
+    (0xcbec, &[0xa9, 0x6a], false),
+    (0xcbed, &[0xa9, 0x6b], false),
+    (0xcbee, &[0xa9, 0x6c], false),
+    (0xcbef, &[0xa9, 0x6d], false),
+    (0xcbf0, &[0xa9, 0x6e], false),
+    (0xcbf1, &[0xa9, 0x6f], false),
+    (0xcbf2, &[0xa9, 0x70], false),
+    (0xcbf3, &[0xa9, 0x71], false),
+    (0xcbf4, &[0xa9, 0x72], false),
+    (0xcbf5, &[0xa9, 0x73], false),
+    (0xcbf6, &[0xa9, 0x74], false),
+    (0xcbf7, &[0xa9, 0x75], false),
+    (0xcbf8, &[0xa9, 0x76], false),
+    (0xcbf9, &[0xa9, 0x77], false),
+    (0xcbfa, &[0xa9, 0x78], false),
+    (0xcbfb, &[0xa9, 0x79], false),
+    (0xcbfc, &[0xa9, 0x7a], false),
+    (0xcbfd, &[0xa9, 0x81], false),
+    (0xcbfe, &[0xa9, 0x82], false),
+    (0xcbff, &[0xa9, 0x83], false),
+    (0xcc00, &[0xa9, 0x84], false),
+    (0xcc01, &[0xa9, 0x85], false),
+    (0xcc02, &[0xa9, 0x86], false),
+    (0xcc03, &[0xa9, 0x87], false),
+    (0xcc04, &[0xa9, 0x88], false),
+    (0xcc05, &[0xa9, 0x89], false),
+    (0xcc06, &[0xa9, 0x8a], false),
+    (0xcc07, &[0xa9, 0x8b], false),
+    (0xcc08, &[0xa9, 0x8c], false),
+    (0xcc09, &[0xa9, 0x8d], false),
+    (0xcc0a, &[0xa9, 0x8e], false),
+    (0xcc0b, &[0xa9, 0x8f], false),
+    (0xcc0e, &[0xa9, 0x90], false),
+    (0xcc0f, &[0xa9, 0x91], false),
+    (0xcc11, &[0xa9, 0x92], false),
+    (0xcc12, &[0xa9, 0x93], false),
+    (0xcc13, &[0xa9, 0x94], false),
+    (0xcc15, &[0xa9, 0x95], false),
+    (0xcc16, &[0xa9, 0x96], false),
+    (0xcc17, &[0xa9, 0x97], false),
+    (0xcc18, &[0xa9, 0x98], false),
+    (0xcc19, &[0xa9, 0x99], false),
+    (0xcc1a, &[0xa9, 0x9a], false),
+    (0xcc1b, &[0xa9, 0x9b], false),
+    (0xcc1e, &[0xa9, 0x9c], false),
+    (0xcc1f, &[0xa9, 0x9d], false),
+    (0xcc20, &[0xa9, 0x9e], false),
+    (0xcc23, &[0xa9, 0x9f], false),
+    (0xcc24, &[0xa9, 0xa0], false),
+    (0x00e6, &[0xa9, 0xa1], false),
+    (0x0111, &[0xa9, 0xa2], false),
+    (0x00f0, &[0xa9, 0xa3], false),
+    (0x0127, &[0xa9, 0xa4], false),
+    (0x0131, &[0xa9, 0xa5], false),
+    (0x0133, &[0xa9, 0xa6], false),
+    (0x0138, &[0xa9, 0xa7], false),
+    (0x0140, &[0xa9, 0xa8], false),
+    (0x0142, &[0xa9, 0xa9], false),
+    (0x00f8, &[0xa9, 0xaa], false),
+    (0x0153, &[0xa9, 0xab], false),
+    (0x00df, &[0xa9, 0xac], false),
+    (0x00fe, &[0xa9, 0xad], false),
+    (0x0167, &[0xa9, 0xae], false),
+    (0x014b, &[0xa9, 0xaf], false),
+    (0x0149, &[0xa9, 0xb0], false),
+    (0x3200, &[0xa9, 0xb1], false),
+    (0x3201, &[0xa9, 0xb2], false),
+    (0x3202, &[0xa9, 0xb3], false),
+    (0x3203, &[0xa9, 0xb4], false),
+    (0x3204, &[0xa9, 0xb5], false),
+    (0x3205, &[0xa9, 0xb6], false),
+    (0x3206, &[0xa9, 0xb7], false),
+    (0x3207, &[0xa9, 0xb8], false),
+    (0x3208, &[0xa9, 0xb9], false),
+    (0x3209, &[0xa9, 0xba], false),
+    (0x320a, &[0xa9, 0xbb], false),
+    (0x320b, &[0xa9, 0xbc], false),
+    (0x320c, &[0xa9, 0xbd], false),
+    (0x320d, &[0xa9, 0xbe], false),
+    (0x320e, &[0xa9, 0xbf], false),
+    (0x320f, &[0xa9, 0xc0], false),
+    (0x3210, &[0xa9, 0xc1], false),
+    (0x3211, &[0xa9, 0xc2], false),
+    (0x3212, &[0xa9, 0xc3], false),
+    (0x3213, &[0xa9, 0xc4], false),
+    (0x3214, &[0xa9, 0xc5], false),
+    (0x3215, &[0xa9, 0xc6], false),
+    (0x3216, &[0xa9, 0xc7], false),
+    (0x3217, &[0xa9, 0xc8], false),
+    (0x3218, &[0xa9, 0xc9], false),
+    (0x3219, &[0xa9, 0xca], false),
+    (0x321a, &[0xa9, 0xcb], false),
+    (0x321b, &[0xa9, 0xcc], false),
+    (0x249c, &[0xa9, 0xcd], false),
+    (0x249d, &[0xa9, 0xce], false),
+    (0x249e, &[0xa9, 0xcf], false),
+    (0x249f, &[0xa9, 0xd0], false),
+    (0x24a0, &[0xa9, 0xd1], false),
+    (0x24a1, &[0xa9, 0xd2], false),
+    (0x24a2, &[0xa9, 0xd3], false),
+    (0x24a3, &[0xa9, 0xd4], false),
+    (0x24a4, &[0xa9, 0xd5], false),
+    (0x24a5, &[0xa9, 0xd6], false),
+    (0x24a6, &[0xa9, 0xd7], false),
+    (0x24a7, &[0xa9, 0xd8], false),
+    (0x24a8, &[0xa9, 0xd9], false),
+    (0x24a9, &[0xa9, 0xda], false),
+    (0x24aa, &[0xa9, 0xdb], false),
+    (0x24ab, &[0xa9, 0xdc], false),
+    (0x24ac, &[0xa9, 0xdd], false),
+    (0x24ad, &[0xa9, 0xde], false),
+    (0x24ae, &[0xa9, 0xdf], false),
+    (0x24af, &[0xa9, 0xe0], false),
+    (0x24b0, &[0xa9, 0xe1], false),
+    (0x24b1, &[0xa9, 0xe2], false),
+    (0x24b2, &[0xa9, 0xe3], false),
+    (0x24b3, &[0xa9, 0xe4], false),
+    (0x24b4, &[0xa9, 0xe5], false),
+    (0x24b5, &[0xa9, 0xe6], false),
+    (0x2474, &[0xa9, 0xe7], false),
+    (0x2475, &[0xa9, 0xe8], false),
+    (0x2476, &[0xa9, 0xe9], false),
+    (0x2477, &[0xa9, 0xea], false),
+    (0x2478, &[0xa9, 0xeb], false),
+    (0x2479, &[0xa9, 0xec], false),
+    (0x247a, &[0xa9, 0xed], false),
+    (0x247b, &[0xa9, 0xee], false),
+    (0x247c, &[0xa9, 0xef], false),
+    (0x247d, &[0xa9, 0xf0], false),
+    (0x247e, &[0xa9, 0xf1], false),
+    (0x247f, &[0xa9, 0xf2], false),
+    (0x2480, &[0xa9, 0xf3], false),
+    (0x2481, &[0xa9, 0xf4], false),
+    (0x2482, &[0xa9, 0xf5], false),
+    (0x00b9, &[0xa9, 0xf6], false),
+    (0x00b2, &[0xa9, 0xf7], false),
+    (0x00b3, &[0xa9, 0xf8], false),
+    (0x2074, &[0xa9, 0xf9], false),
+    (0x207f, &[0xa9, 0xfa], false),
+    (0x2081, &[0xa9, 0xfb], false),
+    (0x2082, &[0xa9, 0xfc], false),
+    (0x2083, &[0xa9, 0xfd], false),
+    (0x2084, &[0xa9, 0xfe], false),
+    (0xcc25, &[0xaa, 0x41], false),
+    (0xcc26, &[0xaa, 0x42], false),
+    (0xcc2a, &[0xaa, 0x43], false),
+    (0xcc2b, &[0xaa, 0x44], false),
+    (0xcc2d, &[0xaa, 0x45], false),
+    (0xcc2f, &[0xaa, 0x46], false),
+    (0xcc31, &[0xaa, 0x47], false),
+    (0xcc32, &[0xaa, 0x48], false),
+    (0xcc33, &[0xaa, 0x49], false),
+    (0xcc34, &[0xaa, 0x4a], false),
+    (0xcc35, &[0xaa, 0x4b], false),
+    (0xcc36, &[0xaa, 0x4c], false),
+    (0xcc37, &[0xaa, 0x4d], false),
+    (0xcc3a, &[0xaa, 0x4e], false),
+    (0xcc3f, &[0xaa, 0x4f], false),
+    (0xcc40, &[0xaa, 0x50], false),
+    (0xcc41, &[0xaa, 0x51], false),
+    (0xcc42, &[0xaa, 0x52], false),
+    (0xcc43, &[0xaa, 0x53], false),
+    (0xcc46, &[0xaa, 0x54], false),
+    (0xcc47, &[0xaa, 0x55], false),
+    (0xcc49, &[0xaa, 0x56], false),
+    (0xcc4a, &[0xaa, 0x57], false),
+    (0xcc4b, &[0xaa, 0x58], false),
+    (0xcc4d, &[0xaa, 0x59], false),
+    (0xcc4e, &[0xaa, 0x5a], false),
+    (0xcc4f, &[0xaa, 0x61], false),
+    (0xcc50, &[0xaa, 0x62], false),
+    (0xcc51, &[0xaa, 0x63], false),
+    (0xcc52, &[0xaa, 0x64], false),
+    (0xcc53, &[0xaa, 0x65], false),
+    (0xcc56, &[0xaa, 0x66], false),
+    (0xcc5a, &[0xaa, 0x67], false),
+    (0xcc5b, &[0xaa, 0x68], false),
+    (0xcc5c, &[0xaa, 0x69], false),
+    (0xcc5d, &[0xaa, 0x6a], false),
+    (0xcc5e, &[0xaa, 0x6b], false),
+    (0xcc5f, &[0xaa, 0x6c], false),
+    (0xcc61, &[0xaa, 0x6d], false),
+    (0xcc62, &[0xaa, 0x6e], false),
+    (0xcc63, &[0xaa, 0x6f], false),
+    (0xcc65, &[0xaa, 0x70], false),
+    (0xcc67, &[0xaa, 0x71], false),
+    (0xcc69, &[0xaa, 0x72], false),
+    (0xcc6a, &[0xaa, 0x73], false),
+    (0xcc6b, &[0xaa, 0x74], false),
+    (0xcc6c, &[0xaa, 0x75], false),
+    (0xcc6d, &[0xaa, 0x76], false),
+    (0xcc6e, &[0xaa, 0x77], false),
+    (0xcc6f, &[0xaa, 0x78], false),
+    (0xcc71, &[0xaa, 0x79], false),
+    (0xcc72, &[0xaa, 0x7a], false),
+    (0xcc73, &[0xaa, 0x81], false),
+    (0xcc74, &[0xaa, 0x82], false),
+    (0xcc76, &[0xaa, 0x83], false),
+    (0xcc77, &[0xaa, 0x84], false),
+    (0xcc78, &[0xaa, 0x85], false),
+    (0xcc79, &[0xaa, 0x86], false),
+    (0xcc7a, &[0xaa, 0x87], false),
+    (0xcc7b, &[0xaa, 0x88], false),
+    (0xcc7c, &[0xaa, 0x89], false),
+    (0xcc7d, &[0xaa, 0x8a], false),
+    (0xcc7e, &[0xaa, 0x8b], false),
+    (0xcc7f, &[0xaa, 0x8c], false),
+    (0xcc80, &[0xaa, 0x8d], false),
+    (0xcc81, &[0xaa, 0x8e], false),
+    (0xcc82, &[0xaa, 0x8f], false),
+    (0xcc83, &[0xaa, 0x90], false),
+    (0xcc84, &[0xaa, 0x91], false),
+    (0xcc85, &[0xaa, 0x92], false),
+    (0xcc86, &[0xaa, 0x93], false),
+    (0xcc87, &[0xaa, 0x94], false),
+    (0xcc88, &[0xaa, 0x95], false),
+    (0xcc89, &[0xaa, 0x96], false),
+    (0xcc8a, &[0xaa, 0x97], false),
+    (0xcc8b, &[0xaa, 0x98], false),
+    (0xcc8c, &[0xaa, 0x99], false),
+    (0xcc8d, &[0xaa, 0x9a], false),
+    (0xcc8e, &[0xaa, 0x9b], false),
+    (0xcc8f, &[0xaa, 0x9c], false),
+    (0xcc90, &[0xaa, 0x9d], false),
+    (0xcc91, &[0xaa, 0x9e], false),
+    (0xcc92, &[0xaa, 0x9f], false),
+    (0xcc93, &[0xaa, 0xa0], false),
+    (0x3041, &[0xaa, 0xa1], false),
+    (0x3042, &[0xaa, 0xa2], false),
+    (0x3043, &[0xaa, 0xa3], false),
+    (0x3044, &[0xaa, 0xa4], false),
+    (0x3045, &[0xaa, 0xa5], false),
+    (0x3046, &[0xaa, 0xa6], false),
+    (0x3047, &[0xaa, 0xa7], false),
+    (0x3048, &[0xaa, 0xa8], false),
+    (0x3049, &[0xaa, 0xa9], false),
+    (0x304a, &[0xaa, 0xaa], false),
+    (0x304b, &[0xaa, 0xab], false),
+    (0x304c, &[0xaa, 0xac], false),
+    (0x304d, &[0xaa, 0xad], false),
+    (0x304e, &[0xaa, 0xae], false),
+    (0x304f, &[0xaa, 0xaf], false),
+    (0x3050, &[0xaa, 0xb0], false),
+    (0x3051, &[0xaa, 0xb1], false),
+    (0x3052, &[0xaa, 0xb2], false),
+    (0x3053, &[0xaa, 0xb3], false),
+    (0x3054, &[0xaa, 0xb4], false),
+    (0x3055, &[0xaa, 0xb5], false),
+    (0x3056, &[0xaa, 0xb6], false),
+    (0x3057, &[0xaa, 0xb7], false),
+    (0x3058, &[0xaa, 0xb8], false),
+    (0x3059, &[0xaa, 0xb9], false),
+    (0x305a, &[0xaa, 0xba], false),
+    (0x305b, &[0xaa, 0xbb], false),
+    (0x305c, &[0xaa, 0xbc], false),
+    (0x305d, &[0xaa, 0xbd], false),
+    (0x305e, &[0xaa, 0xbe], false),
+    (0x305f, &[0xaa, 0xbf], false),
+    (0x3060, &[0xaa, 0xc0], false),
+    (0x3061, &[0xaa, 0xc1], false),
+    (0x3062, &[0xaa, 0xc2], false),
+    (0x3063, &[0xaa, 0xc3], false),
+    (0x3064, &[0xaa, 0xc4], false),
+    (0x3065, &[0xaa, 0xc5], false),
+    (0x3066, &[0xaa, 0xc6], false),
+    (0x3067, &[0xaa, 0xc7], false),
+    (0x3068, &[0xaa, 0xc8], false),
+    (0x3069, &[0xaa, 0xc9], false),
+    (0x306a, &[0xaa, 0xca], false),
+    (0x306b, &[0xaa, 0xcb], false),
+    (0x306c, &[0xaa, 0xcc], false),
+    (0x306d, &[0xaa, 0xcd], false),
+    (0x306e, &[0xaa, 0xce], false),
+    (0x306f, &[0xaa, 0xcf], false),
+    (0x3070, &[0xaa, 0xd0], false),
+    (0x3071, &[0xaa, 0xd1], false),
+    (0x3072, &[0xaa, 0xd2], false),
+    (0x3073, &[0xaa, 0xd3], false),
+    (0x3074, &[0xaa, 0xd4], false),
+    (0x3075, &[0xaa, 0xd5], false),
+    (0x3076, &[0xaa, 0xd6], false),
+    (0x3077, &[0xaa, 0xd7], false),
+    (0x3078, &[0xaa, 0xd8], false),
+    (0x3079, &[0xaa, 0xd9], false),
+    (0x307a, &[0xaa, 0xda], false),
+    (0x307b, &[0xaa, 0xdb], false),
+    (0x307c, &[0xaa, 0xdc], false),
+    (0x307d, &[0xaa, 0xdd], false),
+    (0x307e, &[0xaa, 0xde], false),
+    (0x307f, &[0xaa, 0xdf], false),
+    (0x3080, &[0xaa, 0xe0], false),
+    (0x3081, &[0xaa, 0xe1], false),
+    (0x3082, &[0xaa, 0xe2], false),
+    (0x3083, &[0xaa, 0xe3], false),
+    (0x3084, &[0xaa, 0xe4], false),
+    (0x3085, &[0xaa, 0xe5], false),
+    (0x3086, &[0xaa, 0xe6], false),
+    (0x3087, &[0xaa, 0xe7], false),
+    (0x3088, &[0xaa, 0xe8], false),
+    (0x3089, &[0xaa, 0xe9], false),
+    (0x308a, &[0xaa, 0xea], false),
+    (0x308b, &[0xaa, 0xeb], false),
+    (0x308c, &[0xaa, 0xec], false),
+    (0x308d, &[0xaa, 0xed], false),
+    (0x308e, &[0xaa, 0xee], false),
+    (0x308f, &[0xaa, 0xef], false),
+    (0x3090, &[0xaa, 0xf0], false),
+    (0x3091, &[0xaa, 0xf1], false),
+    (0x3092, &[0xaa, 0xf2], false),
+    (0x3093, &[0xaa, 0xf3], false),
+    (0xcc94, &[0xab, 0x41], false),
+    (0xcc95, &[0xab, 0x42], false),
+    (0xcc96, &[0xab, 0x43], false),
+    (0xcc97, &[0xab, 0x44], false),
+    (0xcc9a, &[0xab, 0x45], false),
+    (0xcc9b, &[0xab, 0x46], false),
+    (0xcc9d, &[0xab, 0x47], false),
+    (0xcc9e, &[0xab, 0x48], false),
+    (0xcc9f, &[0xab, 0x49], false),
+    (0xcca1, &[0xab, 0x4a], false),
+    (0xcca2, &[0xab, 0x4b], false),
+    (0xcca3, &[0xab, 0x4c], false),
+    (0xcca4, &[0xab, 0x4d], false),
+    (0xcca5, &[0xab, 0x4e], false),
+    (0xcca6, &[0xab, 0x4f], false),
+    (0xcca7, &[0xab, 0x50], false),
+    (0xccaa, &[0xab, 0x51], false),
+    (0xccae, &[0xab, 0x52], false),
+    (0xccaf, &[0xab, 0x53], false),
+    (0xccb0, &[0xab, 0x54], false),
+    (0xccb1, &[0xab, 0x55], false),
+    (0xccb2, &[0xab, 0x56], false),
+    (0xccb3, &[0xab, 0x57], false),
+    (0xccb6, &[0xab, 0x58], false),
+    (0xccb7, &[0xab, 0x59], false),
+    (0xccb9, &[0xab, 0x5a], false),
+    (0xccba, &[0xab, 0x61], false),
+    (0xccbb, &[0xab, 0x62], false),
+    (0xccbd, &[0xab, 0x63], false),
+    (0xccbe, &[0xab, 0x64], false),
+    (0xccbf, &[0xab, 0x65], false),
+    (0xccc0, &[0xab, 0x66], false),
+    (0xccc1, &[0xab, 0x67], false),
+    (0xccc2, &[0xab, 0x68], false),
+    (0xccc3, &[0xab, 0x69], false),
+    (0xccc6, &[0xab, 0x6a], false),
+    (0xccc8, &[0xab, 0x6b], false),
+    (0xccca, &[0xab, 0x6c], false),
+    (0xcccb, &[0xab, 0x6d], false),
+    (0xcccc, &[0xab, 0x6e], false),
+    (0xcccd, &[0xab, 0x6f], false),
+    (0xccce, &[0xab, 0x70], false),
+    (0xcccf, &[0xab, 0x71], false),
+    (0xccd1, &[0xab, 0x72], false),
+    (0xccd2, &[0xab, 0x73], false),
+    (0xccd3, &[0xab, 0x74], false),
+    (0xccd5, &[0xab, 0x75], false),
+    (0xccd6, &[0xab, 0x76], false),
+    (0xccd7, &[0xab, 0x77], false),
+    (0xccd8, &[0xab, 0x78], false),
+    (0xccd9, &[0xab, 0x79], false),
+    (0xccda, &[0xab, 0x7a], false),
+    (0xccdb, &[0xab, 0x81], false),
+    (0xccdc, &[0xab, 0x82], false),
+    (0xccdd, &[0xab, 0x83], false),
+    (0xccde, &[0xab, 0x84], false),
+    (0xccdf, &[0xab, 0x85], false),
+    (0xcce0, &[0xab, 0x86], false),
+    (0xcce1, &[0xab, 0x87], false),
+    (0xcce2, &[0xab, 0x88], false),
+    (0xcce3, &[0xab, 0x89], false),
+    (0xcce5, &[0xab, 0x8a], false),
+    (0xcce6, &[0xab, 0x8b], false),
+    (0xcce7, &[0xab, 0x8c], false),
+    (0xcce8, &[0xab, 0x8d], false),
+    (0xcce9, &[0xab, 0x8e], false),
+    (0xccea, &[0xab, 0x8f], false),
+    (0xcceb, &[0xab, 0x90], false),
+    (0xcced, &[0xab, 0x91], false),
+    (0xccee, &[0xab, 0x92], false),
+    (0xccef, &[0xab, 0x93], false),
+    (0xccf1, &[0xab, 0x94], false),
+    (0xccf2, &[0xab, 0x95], false),
+    (0xccf3, &[0xab, 0x96], false),
+    (0xccf4, &[0xab, 0x97], false),
+    (0xccf5, &[0xab, 0x98], false),
+    (0xccf6, &[0xab, 0x99], false),
+    (0xccf7, &[0xab, 0x9a], false),
+    (0xccf8, &[0xab, 0x9b], false),
+    (0xccf9, &[0xab, 0x9c], false),
+    (0xccfa, &[0xab, 0x9d], false),
+    (0xccfb, &[0xab, 0x9e], false),
+    (0xccfc, &[0xab, 0x9f], false),
+    (0xccfd, &[0xab, 0xa0], false),
+    (0x30a1, &[0xab, 0xa1], false),
+    (0x30a2, &[0xab, 0xa2], false),
+    (0x30a3, &[0xab, 0xa3], false),
+    (0x30a4, &[0xab, 0xa4], false),
+    (0x30a5, &[0xab, 0xa5], false),
+    (0x30a6, &[0xab, 0xa6], false),
+    (0x30a7, &[0xab, 0xa7], false),
+    (0x30a8, &[0xab, 0xa8], false),
+    (0x30a9, &[0xab, 0xa9], false),
+    (0x30aa, &[0xab, 0xaa], false),
+    (0x30ab, &[0xab, 0xab], false),
+    (0x30ac, &[0xab, 0xac], false),
+    (0x30ad, &[0xab, 0xad], false),
+    (0x30ae, &[0xab, 0xae], false),
+    (0x30af, &[0xab, 0xaf], false),
+    (0x30b0, &[0xab, 0xb0], false),
+    (0x30b1, &[0xab, 0xb1], false),
+    (0x30b2, &[0xab, 0xb2], false),
+    (0x30b3, &[0xab, 0xb3], false),
+    (0x30b4, &[0xab, 0xb4], false),
+    (0x30b5, &[0xab, 0xb5], false),
+    (0x30b6, &[0xab, 0xb6], false),
+    (0x30b7, &[0xab, 0xb7], false),
+    (0x30b8, &[0xab, 0xb8], false),
+    (0x30b9, &[0xab, 0xb9], false),
+    (0x30ba, &[0xab, 0xba], false),
+    (0x30bb, &[0xab, 0xbb], false),
+    (0x30bc, &[0xab, 0xbc], false),
+    (0x30bd, &[0xab, 0xbd], false),
+    (0x30be, &[0xab, 0xbe], false),
+    (0x30bf, &[0xab, 0xbf], false),
+    (0x30c0, &[0xab, 0xc0], false),
+    (0x30c1, &[0xab, 0xc1], false),
+    (0x30c2, &[0xab, 0xc2], false),
+    (0x30c3, &[0xab, 0xc3], false),
+    (0x30c4, &[0xab, 0xc4], false),
+    (0x30c5, &[0xab, 0xc5], false),
+    (0x30c6, &[0xab, 0xc6], false),
+    (0x30c7, &[0xab, 0xc7], false),
+    (0x30c8, &[0xab, 0xc8], false),
+    (0x30c9, &[0xab, 0xc9], false),
+    (0x30ca, &[0xab, 0xca], false),
+    (0x30cb, &[0xab, 0xcb], false),
+    (0x30cc, &[0xab, 0xcc], false),
+    (0x30cd, &[0xab, 0xcd], false),
+    (0x30ce, &[0xab, 0xce], false),
+    (0x30cf, &[0xab, 0xcf], false),
+    (0x30d0, &[0xab, 0xd0], false),
+    (0x30d1, &[0xab, 0xd1], false),
+    (0x30d2, &[0xab, 0xd2], false),
+    (0x30d3, &[0xab, 0xd3], false),
+    (0x30d4, &[0xab, 0xd4], false),
+    (0x30d5, &[0xab, 0xd5], false),
+    (0x30d6, &[0xab, 0xd6], false),
+    (0x30d7, &[0xab, 0xd7], false),
+    (0x30d8, &[0xab, 0xd8], false),
+    (0x30d9, &[0xab, 0xd9], false),
+    (0x30da, &[0xab, 0xda], false),
+    (0x30db, &[0xab, 0xdb], false),
+    (0x30dc, &[0xab, 0xdc], false),
+    (0x30dd, &[0xab, 0xdd], false),
+    (0x30de, &[0xab, 0xde], false),
+    (0x30df, &[0xab, 0xdf], false),
+    (0x30e0, &[0xab, 0xe0], false),
+    (0x30e1, &[0xab, 0xe1], false),
+    (0x30e2, &[0xab, 0xe2], false),
+    (0x30e3, &[0xab, 0xe3], false),
+    (0x30e4, &[0xab, 0xe4], false),
+    (0x30e5, &[0xab, 0xe5], false),
+    (0x30e6, &[0xab, 0xe6], false),
+    (0x30e7, &[0xab, 0xe7], false),
+    (0x30e8, &[0xab, 0xe8], false),
+    (0x30e9, &[0xab, 0xe9], false),
+    (0x30ea, &[0xab, 0xea], false),
+    (0x30eb, &[0xab, 0xeb], false),
+    (0x30ec, &[0xab, 0xec], false),
+    (0x30ed, &[0xab, 0xed], false),
+    (0x30ee, &[0xab, 0xee], false),
+    (0x30ef, &[0xab, 0xef], false),
+    (0x30f0, &[0xab, 0xf0], false),
+    (0x30f1, &[0xab, 0xf1], false),
+    (0x30f2, &[0xab, 0xf2], false),
+    (0x30f3, &[0xab, 0xf3], false),
+    (0x30f4, &[0xab, 0xf4], false),
+    (0x30f5, &[0xab, 0xf5], false),
+    (0x30f6, &[0xab, 0xf6], false),
+    (0xccfe, &[0xac, 0x41], false),
+    (0xccff, &[0xac, 0x42], false),
+    (0xcd00, &[0xac, 0x43], false),
+    (0xcd02, &[0xac, 0x44], false),
+    (0xcd03, &[0xac, 0x45], false),
+    (0xcd04, &[0xac, 0x46], false),
+    (0xcd05, &[0xac, 0x47], false),
+    (0xcd06, &[0xac, 0x48], false),
+    (0xcd07, &[0xac, 0x49], false),
+    (0xcd0a, &[0xac, 0x4a], false),
+    (0xcd0b, &[0xac, 0x4b], false),
+    (0xcd0d, &[0xac, 0x4c], false),
+    (0xcd0e, &[0xac, 0x4d], false),
+    (0xcd0f, &[0xac, 0x4e], false),
+    (0xcd11, &[0xac, 0x4f], false),
+    (0xcd12, &[0xac, 0x50], false),
+    (0xcd13, &[0xac, 0x51], false),
+    (0xcd14, &[0xac, 0x52], false),
+    (0xcd15, &[0xac, 0x53], false),
+    (0xcd16, &[0xac, 0x54], false),
+    (0xcd17, &[0xac, 0x55], false),
+    (0xcd1a, &[0xac, 0x56], false),
+    (0xcd1c, &[0xac, 0x57], false),
+    (0xcd1e, &[0xac, 0x58], false),
+    (0xcd1f, &[0xac, 0x59], false),
+    (0xcd20, &[0xac, 0x5a], false),
+    (0xcd21, &[0xac, 0x61], false),
+    (0xcd22, &[0xac, 0x62], false),
+    (0xcd23, &[0xac, 0x63], false),
+    (0xcd25, &[0xac, 0x64], false),
+    (0xcd26, &[0xac, 0x65], false),
+    (0xcd27, &[0xac, 0x66], false),
+    (0xcd29, &[0xac, 0x67], false),
+    (0xcd2a, &[0xac, 0x68], false),
+    (0xcd2b, &[0xac, 0x69], false),
+    (0xcd2d, &[0xac, 0x6a], false),
+    (0xcd2e, &[0xac, 0x6b], false),
+    (0xcd2f, &[0xac, 0x6c], false),
+    (0xcd30, &[0xac, 0x6d], false),
+    (0xcd31, &[0xac, 0x6e], false),
+    (0xcd32, &[0xac, 0x6f], false),
+    (0xcd33, &[0xac, 0x70], false),
+    (0xcd34, &[0xac, 0x71], false),
+    (0xcd35, &[0xac, 0x72], false),
+    (0xcd36, &[0xac, 0x73], false),
+    (0xcd37, &[0xac, 0x74], false),
+    (0xcd38, &[0xac, 0x75], false),
+    (0xcd3a, &[0xac, 0x76], false),
+    (0xcd3b, &[0xac, 0x77], false),
+    (0xcd3c, &[0xac, 0x78], false),
+    (0xcd3d, &[0xac, 0x79], false),
+    (0xcd3e, &[0xac, 0x7a], false),
+    (0xcd3f, &[0xac, 0x81], false),
+    (0xcd40, &[0xac, 0x82], false),
+    (0xcd41, &[0xac, 0x83], false),
+    (0xcd42, &[0xac, 0x84], false),
+    (0xcd43, &[0xac, 0x85], false),
+    (0xcd44, &[0xac, 0x86], false),
+    (0xcd45, &[0xac, 0x87], false),
+    (0xcd46, &[0xac, 0x88], false),
+    (0xcd47, &[0xac, 0x89], false),
+    (0xcd48, &[0xac, 0x8a], false),
+    (0xcd49, &[0xac, 0x8b], false),
+    (0xcd4a, &[0xac, 0x8c], false),
+    (0xcd4b, &[0xac, 0x8d], false),
+    (0xcd4c, &[0xac, 0x8e], false),
+    (0xcd4d, &[0xac, 0x8f], false),
+    (0xcd4e, &[0xac, 0x90], false),
+    (0xcd4f, &[0xac, 0x91], false),
+    (0xcd50, &[0xac, 0x92], false),
+    (0xcd51, &[0xac, 0x93], false),
+    (0xcd52, &[0xac, 0x94], false),
+    (0xcd53, &[0xac, 0x95], false),
+    (0xcd54, &[0xac, 0x96], false),
+    (0xcd55, &[0xac, 0x97], false),
+    (0xcd56, &[0xac, 0x98], false),
+    (0xcd57, &[0xac, 0x99], false),
+    (0xcd58, &[0xac, 0x9a], false),
+    (0xcd59, &[0xac, 0x9b], false),
+    (0xcd5a, &[0xac, 0x9c], false),
+    (0xcd5b, &[0xac, 0x9d], false),
+    (0xcd5d, &[0xac, 0x9e], false),
+    (0xcd5e, &[0xac, 0x9f], false),
+    (0xcd5f, &[0xac, 0xa0], false),
+    (0x0410, &[0xac, 0xa1], false),
+    (0x0411, &[0xac, 0xa2], false),
+    (0x0412, &[0xac, 0xa3], false),
+    (0x0413, &[0xac, 0xa4], false),
+    (0x0414, &[0xac, 0xa5], false),
+    (0x0415, &[0xac, 0xa6], false),
+    (0x0401, &[0xac, 0xa7], false),
+    (0x0416, &[0xac, 0xa8], false),
+    (0x0417, &[0xac, 0xa9], false),
+    (0x0418, &[0xac, 0xaa], false),
+    (0x0419, &[0xac, 0xab], false),
+    (0x041a, &[0xac, 0xac], false),
+    (0x041b, &[0xac, 0xad], false),
+    (0x041c, &[0xac, 0xae], false),
+    (0x041d, &[0xac, 0xaf], false),
+    (0x041e, &[0xac, 0xb0], false),
+    (0x041f, &[0xac, 0xb1], false),
+    (0x0420, &[0xac, 0xb2], false),
+    (0x0421, &[0xac, 0xb3], false),
+    (0x0422, &[0xac, 0xb4], false),
+    (0x0423, &[0xac, 0xb5], false),
+    (0x0424, &[0xac, 0xb6], false),
+    (0x0425, &[0xac, 0xb7], false),
+    (0x0426, &[0xac, 0xb8], false),
+    (0x0427, &[0xac, 0xb9], false),
+    (0x0428, &[0xac, 0xba], false),
+    (0x0429, &[0xac, 0xbb], false),
+    (0x042a, &[0xac, 0xbc], false),
+    (0x042b, &[0xac, 0xbd], false),
+    (0x042c, &[0xac, 0xbe], false),
+    (0x042d, &[0xac, 0xbf], false),
+    (0x042e, &[0xac, 0xc0], false),
+    (0x042f, &[0xac, 0xc1], false),
+    (0x0430, &[0xac, 0xd1], false),
+    (0x0431, &[0xac, 0xd2], false),
+    (0x0432, &[0xac, 0xd3], false),
+    (0x0433, &[0xac, 0xd4], false),
+    (0x0434, &[0xac, 0xd5], false),
+    (0x0435, &[0xac, 0xd6], false),
+    (0x0451, &[0xac, 0xd7], false),
+    (0x0436, &[0xac, 0xd8], false),
+    (0x0437, &[0xac, 0xd9], false),
+    (0x0438, &[0xac, 0xda], false),
+    (0x0439, &[0xac, 0xdb], false),
+    (0x043a, &[0xac, 0xdc], false),
+    (0x043b, &[0xac, 0xdd], false),
+    (0x043c, &[0xac, 0xde], false),
+    (0x043d, &[0xac, 0xdf], false),
+    (0x043e, &[0xac, 0xe0], false),
+    (0x043f, &[0xac, 0xe1], false),
+    (0x0440, &[0xac, 0xe2], false),
+    (0x0441, &[0xac, 0xe3], false),
+    (0x0442, &[0xac, 0xe4], false),
+    (0x0443, &[0xac, 0xe5], false),
+    (0x0444, &[0xac, 0xe6], false),
+    (0x0445, &[0xac, 0xe7], false),
+    (0x0446, &[0xac, 0xe8], false),
+    (0x0447, &[0xac, 0xe9], false),
+    (0x0448, &[0xac, 0xea], false),
+    (0x0449, &[0xac, 0xeb], false),
+    (0x044a, &[0xac, 0xec], false),
+    (0x044b, &[0xac, 0xed], false),
+    (0x044c, &[0xac, 0xee], false),
+    (0x044d, &[0xac, 0xef], false),
+    (0x044e, &[0xac, 0xf0], false),
+    (0x044f, &[0xac, 0xf1], false),
+    (0xcd61, &[0xad, 0x41], false),
+    (0xcd62, &[0xad, 0x42], false),
+    (0xcd63, &[0xad, 0x43], false),
+    (0xcd65, &[0xad, 0x44], false),
+    (0xcd66, &[0xad, 0x45], false),
+    (0xcd67, &[0xad, 0x46], false),
+    (0xcd68, &[0xad, 0x47], false),
+    (0xcd69, &[0xad, 0x48], false),
+    (0xcd6a, &[0xad, 0x49], false),
+    (0xcd6b, &[0xad, 0x4a], false),
+    (0xcd6e, &[0xad, 0x4b], false),
+    (0xcd70, &[0xad, 0x4c], false),
+    (0xcd72, &[0xad, 0x4d], false),
+    (0xcd73, &[0xad, 0x4e], false),
+    (0xcd74, &[0xad, 0x4f], false),
+    (0xcd75, &[0xad, 0x50], false),
+    (0xcd76, &[0xad, 0x51], false),
+    (0xcd77, &[0xad, 0x52], false),
+    (0xcd79, &[0xad, 0x53], false),
+    (0xcd7a, &[0xad, 0x54], false),
+    (0xcd7b, &[0xad, 0x55], false),
+    (0xcd7c, &[0xad, 0x56], false),
+    (0xcd7d, &[0xad, 0x57], false),
+    (0xcd7e, &[0xad, 0x58], false),
+    (0xcd7f, &[0xad, 0x59], false),
+    (0xcd80, &[0xad, 0x5a], false),
+    (0xcd81, &[0xad, 0x61], false),
+    (0xcd82, &[0xad, 0x62], false),
+    (0xcd83, &[0xad, 0x63], false),
+    (0xcd84, &[0xad, 0x64], false),
+    (0xcd85, &[0xad, 0x65], false),
+    (0xcd86, &[0xad, 0x66], false),
+    (0xcd87, &[0xad, 0x67], false),
+    (0xcd89, &[0xad, 0x68], false),
+    (0xcd8a, &[0xad, 0x69], false),
+    (0xcd8b, &[0xad, 0x6a], false),
+    (0xcd8c, &[0xad, 0x6b], false),
+    (0xcd8d, &[0xad, 0x6c], false),
+    (0xcd8e, &[0xad, 0x6d], false),
+    (0xcd8f, &[0xad, 0x6e], false),
+    (0xcd90, &[0xad, 0x6f], false),
+    (0xcd91, &[0xad, 0x70], false),
+    (0xcd92, &[0xad, 0x71], false),
+    (0xcd93, &[0xad, 0x72], false),
+    (0xcd96, &[0xad, 0x73], false),
+    (0xcd97, &[0xad, 0x74], false),
+    (0xcd99, &[0xad, 0x75], false),
+    (0xcd9a, &[0xad, 0x76], false),
+    (0xcd9b, &[0xad, 0x77], false),
+    (0xcd9d, &[0xad, 0x78], false),
+    (0xcd9e, &[0xad, 0x79], false),
+    (0xcd9f, &[0xad, 0x7a], false),
+    (0xcda0, &[0xad, 0x81], false),
+    (0xcda1, &[0xad, 0x82], false),
+    (0xcda2, &[0xad, 0x83], false),
+    (0xcda3, &[0xad, 0x84], false),
+    (0xcda6, &[0xad, 0x85], false),
+    (0xcda8, &[0xad, 0x86], false),
+    (0xcdaa, &[0xad, 0x87], false),
+    (0xcdab, &[0xad, 0x88], false),
+    (0xcdac, &[0xad, 0x89], false),
+    (0xcdad, &[0xad, 0x8a], false),
+    (0xcdae, &[0xad, 0x8b], false),
+    (0xcdaf, &[0xad, 0x8c], false),
+    (0xcdb1, &[0xad, 0x8d], false),
+    (0xcdb2, &[0xad, 0x8e], false),
+    (0xcdb3, &[0xad, 0x8f], false),
+    (0xcdb4, &[0xad, 0x90], false),
+    (0xcdb5, &[0xad, 0x91], false),
+    (0xcdb6, &[0xad, 0x92], false),
+    (0xcdb7, &[0xad, 0x93], false),
+    (0xcdb8, &[0xad, 0x94], false),
+    (0xcdb9, &[0xad, 0x95], false),
+    (0xcdba, &[0xad, 0x96], false),
+    (0xcdbb, &[0xad, 0x97], false),
+    (0xcdbc, &[0xad, 0x98], false),
+    (0xcdbd, &[0xad, 0x99], false),
+    (0xcdbe, &[0xad, 0x9a], false),
+    (0xcdbf, &[0xad, 0x9b], false),
+    (0xcdc0, &[0xad, 0x9c], false),
+    (0xcdc1, &[0xad, 0x9d], false),
+    (0xcdc2, &[0xad, 0x9e], false),
+    (0xcdc3, &[0xad, 0x9f], false),
+    (0xcdc5, &[0xad, 0xa0], false),
+    (0xcdc6, &[0xae, 0x41], false),
+    (0xcdc7, &[0xae, 0x42], false),
+    (0xcdc8, &[0xae, 0x43], false),
+    (0xcdc9, &[0xae, 0x44], false),
+    (0xcdca, &[0xae, 0x45], false),
+    (0xcdcb, &[0xae, 0x46], false),
+    (0xcdcd, &[0xae, 0x47], false),
+    (0xcdce, &[0xae, 0x48], false),
+    (0xcdcf, &[0xae, 0x49], false),
+    (0xcdd1, &[0xae, 0x4a], false),
+    (0xcdd2, &[0xae, 0x4b], false),
+    (0xcdd3, &[0xae, 0x4c], false),
+    (0xcdd4, &[0xae, 0x4d], false),
+    (0xcdd5, &[0xae, 0x4e], false),
+    (0xcdd6, &[0xae, 0x4f], false),
+    (0xcdd7, &[0xae, 0x50], false),
+    (0xcdd8, &[0xae, 0x51], false),
+    (0xcdd9, &[0xae, 0x52], false),
+    (0xcdda, &[0xae, 0x53], false),
+    (0xcddb, &[0xae, 0x54], false),
+    (0xcddc, &[0xae, 0x55], false),
+    (0xcddd, &[0xae, 0x56], false),
+    (0xcdde, &[0xae, 0x57], false),
+    (0xcddf, &[0xae, 0x58], false),
+    (0xcde0, &[0xae, 0x59], false),
+    (0xcde1, &[0xae, 0x5a], false),
+    (0xcde2, &[0xae, 0x61], false),
+    (0xcde3, &[0xae, 0x62], false),
+    (0xcde4, &[0xae, 0x63], false),
+    (0xcde5, &[0xae, 0x64], false),
+    (0xcde6, &[0xae, 0x65], false),
+    (0xcde7, &[0xae, 0x66], false),
+    (0xcde9, &[0xae, 0x67], false),
+    (0xcdea, &[0xae, 0x68], false),
+    (0xcdeb, &[0xae, 0x69], false),
+    (0xcded, &[0xae, 0x6a], false),
+    (0xcdee, &[0xae, 0x6b], false),
+    (0xcdef, &[0xae, 0x6c], false),
+    (0xcdf1, &[0xae, 0x6d], false),
+    (0xcdf2, &[0xae, 0x6e], false),
+    (0xcdf3, &[0xae, 0x6f], false),
+    (0xcdf4, &[0xae, 0x70], false),
+    (0xcdf5, &[0xae, 0x71], false),
+    (0xcdf6, &[0xae, 0x72], false),
+    (0xcdf7, &[0xae, 0x73], false),
+    (0xcdfa, &[0xae, 0x74], false),
+    (0xcdfc, &[0xae, 0x75], false),
+    (0xcdfe, &[0xae, 0x76], false),
+    (0xcdff, &[0xae, 0x77], false),
+    (0xce00, &[0xae, 0x78], false),
+    (0xce01, &[0xae, 0x79], false),
+    (0xce02, &[0xae, 0x7a], false),
+    (0xce03, &[0xae, 0x81], false),
+    (0xce05, &[0xae, 0x82], false),
+    (0xce06, &[0xae, 0x83], false),
+    (0xce07, &[0xae, 0x84], false),
+    (0xce09, &[0xae, 0x85], false),
+    (0xce0a, &[0xae, 0x86], false),
+    (0xce0b, &[0xae, 0x87], false),
+    (0xce0d, &[0xae, 0x88], false),
+    (0xce0e, &[0xae, 0x89], false),
+    (0xce0f, &[0xae, 0x8a], false),
+    (0xce10, &[0xae, 0x8b], false),
+    (0xce11, &[0xae, 0x8c], false),
+    (0xce12, &[0xae, 0x8d], false),
+    (0xce13, &[0xae, 0x8e], false),
+    (0xce15, &[0xae, 0x8f], false),
+    (0xce16, &[0xae, 0x90], false),
+    (0xce17, &[0xae, 0x91], false),
+    (0xce18, &[0xae, 0x92], false),
+    (0xce1a, &[0xae, 0x93], false),
+    (0xce1b, &[0xae, 0x94], false),
+    (0xce1c, &[0xae, 0x95], false),
+    (0xce1d, &[0xae, 0x96], false),
+    (0xce1e, &[0xae, 0x97], false),
+    (0xce1f, &[0xae, 0x98], false),
+    (0xce22, &[0xae, 0x99], false),
+    (0xce23, &[0xae, 0x9a], false),
+    (0xce25, &[0xae, 0x9b], false),
+    (0xce26, &[0xae, 0x9c], false),
+    (0xce27, &[0xae, 0x9d], false),
+    (0xce29, &[0xae, 0x9e], false),
+    (0xce2a, &[0xae, 0x9f], false),
+    (0xce2b, &[0xae, 0xa0], false),
+    (0xce2c, &[0xaf, 0x41], false),
+    (0xce2d, &[0xaf, 0x42], false),
+    (0xce2e, &[0xaf, 0x43], false),
+    (0xce2f, &[0xaf, 0x44], false),
+    (0xce32, &[0xaf, 0x45], false),
+    (0xce34, &[0xaf, 0x46], false),
+    (0xce36, &[0xaf, 0x47], false),
+    (0xce37, &[0xaf, 0x48], false),
+    (0xce38, &[0xaf, 0x49], false),
+    (0xce39, &[0xaf, 0x4a], false),
+    (0xce3a, &[0xaf, 0x4b], false),
+    (0xce3b, &[0xaf, 0x4c], false),
+    (0xce3c, &[0xaf, 0x4d], false),
+    (0xce3d, &[0xaf, 0x4e], false),
+    (0xce3e, &[0xaf, 0x4f], false),
+    (0xce3f, &[0xaf, 0x50], false),
+    (0xce40, &[0xaf, 0x51], false),
+    (0xce41, &[0xaf, 0x52], false),
+    (0xce42, &[0xaf, 0x53], false),
+    (0xce43, &[0xaf, 0x54], false),
+    (0xce44, &[0xaf, 0x55], false),
+    (0xce45, &[0xaf, 0x56], false),
+    (0xce46, &[0xaf, 0x57], false),
+    (0xce47, &[0xaf, 0x58], false),
+    (0xce48, &[0xaf, 0x59], false),
+    (0xce49, &[0xaf, 0x5a], false),
+    (0xce4a, &[0xaf, 0x61], false),
+    (0xce4b, &[0xaf, 0x62], false),
+    (0xce4c, &[0xaf, 0x63], false),
+    (0xce4d, &[0xaf, 0x64], false),
+    (0xce4e, &[0xaf, 0x65], false),
+    (0xce4f, &[0xaf, 0x66], false),
+    (0xce50, &[0xaf, 0x67], false),
+    (0xce51, &[0xaf, 0x68], false),
+    (0xce52, &[0xaf, 0x69], false),
+    (0xce53, &[0xaf, 0x6a], false),
+    (0xce54, &[0xaf, 0x6b], false),
+    (0xce55, &[0xaf, 0x6c], false),
+    (0xce56, &[0xaf, 0x6d], false),
+    (0xce57, &[0xaf, 0x6e], false),
+    (0xce5a, &[0xaf, 0x6f], false),
+    (0xce5b, &[0xaf, 0x70], false),
+    (0xce5d, &[0xaf, 0x71], false),
+    (0xce5e, &[0xaf, 0x72], false),
+    (0xce62, &[0xaf, 0x73], false),
+    (0xce63, &[0xaf, 0x74], false),
+    (0xce64, &[0xaf, 0x75], false),
+    (0xce65, &[0xaf, 0x76], false),
+    (0xce66, &[0xaf, 0x77], false),
+    (0xce67, &[0xaf, 0x78], false),
+    (0xce6a, &[0xaf, 0x79], false),
+    (0xce6c, &[0xaf, 0x7a], false),
+    (0xce6e, &[0xaf, 0x81], false),
+    (0xce6f, &[0xaf, 0x82], false),
+    (0xce70, &[0xaf, 0x83], false),
+    (0xce71, &[0xaf, 0x84], false),
+    (0xce72, &[0xaf, 0x85], false),
+    (0xce73, &[0xaf, 0x86], false),
+    (0xce76, &[0xaf, 0x87], false),
+    (0xce77, &[0xaf, 0x88], false),
+    (0xce79, &[0xaf, 0x89], false),
+    (0xce7a, &[0xaf, 0x8a], false),
+    (0xce7b, &[0xaf, 0x8b], false),
+    (0xce7d, &[0xaf, 0x8c], false),
+    (0xce7e, &[0xaf, 0x8d], false),
+    (0xce7f, &[0xaf, 0x8e], false),
+    (0xce80, &[0xaf, 0x8f], false),
+    (0xce81, &[0xaf, 0x90], false),
+    (0xce82, &[0xaf, 0x91], false),
+    (0xce83, &[0xaf, 0x92], false),
+    (0xce86, &[0xaf, 0x93], false),
+    (0xce88, &[0xaf, 0x94], false),
+    (0xce8a, &[0xaf, 0x95], false),
+    (0xce8b, &[0xaf, 0x96], false),
+    (0xce8c, &[0xaf, 0x97], false),
+    (0xce8d, &[0xaf, 0x98], false),
+    (0xce8e, &[0xaf, 0x99], false),
+    (0xce8f, &[0xaf, 0x9a], false),
+    (0xce92, &[0xaf, 0x9b], false),
+    (0xce93, &[0xaf, 0x9c], false),
+    (0xce95, &[0xaf, 0x9d], false),
+    (0xce96, &[0xaf, 0x9e], false),
+    (0xce97, &[0xaf, 0x9f], false),
+    (0xce99, &[0xaf, 0xa0], false),
+    (0xce9a, &[0xb0, 0x41], false),
+    (0xce9b, &[0xb0, 0x42], false),
+    (0xce9c, &[0xb0, 0x43], false),
+    (0xce9d, &[0xb0, 0x44], false),
+    (0xce9e, &[0xb0, 0x45], false),
+    (0xce9f, &[0xb0, 0x46], false),
+    (0xcea2, &[0xb0, 0x47], false),
+    (0xcea6, &[0xb0, 0x48], false),
+    (0xcea7, &[0xb0, 0x49], false),
+    (0xcea8, &[0xb0, 0x4a], false),
+    (0xcea9, &[0xb0, 0x4b], false),
+    (0xceaa, &[0xb0, 0x4c], false),
+    (0xceab, &[0xb0, 0x4d], false),
+    (0xceae, &[0xb0, 0x4e], false),
+    (0xceaf, &[0xb0, 0x4f], false),
+    (0xceb0, &[0xb0, 0x50], false),
+    (0xceb1, &[0xb0, 0x51], false),
+    (0xceb2, &[0xb0, 0x52], false),
+    (0xceb3, &[0xb0, 0x53], false),
+    (0xceb4, &[0xb0, 0x54], false),
+    (0xceb5, &[0xb0, 0x55], false),
+    (0xceb6, &[0xb0, 0x56], false),
+    (0xceb7, &[0xb0, 0x57], false),
+    (0xceb8, &[0xb0, 0x58], false),
+    (0xceb9, &[0xb0, 0x59], false),
+    (0xceba, &[0xb0, 0x5a], false),
+    (0xcebb, &[0xb0, 0x61], false),
+    (0xcebc, &[0xb0, 0x62], false),
+    (0xcebd, &[0xb0, 0x63], false),
+    (0xcebe, &[0xb0, 0x64], false),
+    (0xcebf, &[0xb0, 0x65], false),
+    (0xcec0, &[0xb0, 0x66], false),
+    (0xcec2, &[0xb0, 0x67], false),
+    (0xcec3, &[0xb0, 0x68], false),
+    (0xcec4, &[0xb0, 0x69], false),
+    (0xcec5, &[0xb0, 0x6a], false),
+    (0xcec6, &[0xb0, 0x6b], false),
+    (0xcec7, &[0xb0, 0x6c], false),
+    (0xcec8, &[0xb0, 0x6d], false),
+    (0xcec9, &[0xb0, 0x6e], false),
+    (0xceca, &[0xb0, 0x6f], false),
+    (0xcecb, &[0xb0, 0x70], false),
+    (0xcecc, &[0xb0, 0x71], false),
+    (0xcecd, &[0xb0, 0x72], false),
+    (0xcece, &[0xb0, 0x73], false),
+    (0xcecf, &[0xb0, 0x74], false),
+    (0xced0, &[0xb0, 0x75], false),
+    (0xced1, &[0xb0, 0x76], false),
+    (0xced2, &[0xb0, 0x77], false),
+    (0xced3, &[0xb0, 0x78], false),
+    (0xced4, &[0xb0, 0x79], false),
+    (0xced5, &[0xb0, 0x7a], false),
+    (0xced6, &[0xb0, 0x81], false),
+    (0xced7, &[0xb0, 0x82], false),
+    (0xced8, &[0xb0, 0x83], false),
+    (0xced9, &[0xb0, 0x84], false),
+    (0xceda, &[0xb0, 0x85], false),
+    (0xcedb, &[0xb0, 0x86], false),
+    (0xcedc, &[0xb0, 0x87], false),
+    (0xcedd, &[0xb0, 0x88], false),
+    (0xcede, &[0xb0, 0x89], false),
+    (0xcedf, &[0xb0, 0x8a], false),
+    (0xcee0, &[0xb0, 0x8b], false),
+    (0xcee1, &[0xb0, 0x8c], false),
+    (0xcee2, &[0xb0, 0x8d], false),
+    (0xcee3, &[0xb0, 0x8e], false),
+    (0xcee6, &[0xb0, 0x8f], false),
+    (0xcee7, &[0xb0, 0x90], false),
+    (0xcee9, &[0xb0, 0x91], false),
+    (0xceea, &[0xb0, 0x92], false),
+    (0xceed, &[0xb0, 0x93], false),
+    (0xceee, &[0xb0, 0x94], false),
+    (0xceef, &[0xb0, 0x95], false),
+    (0xcef0, &[0xb0, 0x96], false),
+    (0xcef1, &[0xb0, 0x97], false),
+    (0xcef2, &[0xb0, 0x98], false),
+    (0xcef3, &[0xb0, 0x99], false),
+    (0xcef6, &[0xb0, 0x9a], false),
+    (0xcefa, &[0xb0, 0x9b], false),
+    (0xcefb, &[0xb0, 0x9c], false),
+    (0xcefc, &[0xb0, 0x9d], false),
+    (0xcefd, &[0xb0, 0x9e], false),
+    (0xcefe, &[0xb0, 0x9f], false),
+    (0xceff, &[0xb0, 0xa0], false),
+    (0xac00, &[0xb0, 0xa1], false),
+    (0xac01, &[0xb0, 0xa2], false),
+    (0xac04, &[0xb0, 0xa3], false),
+    (0xac07, &[0xb0, 0xa4], false),
+    (0xac08, &[0xb0, 0xa5], false),
+    (0xac09, &[0xb0, 0xa6], false),
+    (0xac0a, &[0xb0, 0xa7], false),
+    (0xac10, &[0xb0, 0xa8], false),
+    (0xac11, &[0xb0, 0xa9], false),
+    (0xac12, &[0xb0, 0xaa], false),
+    (0xac13, &[0xb0, 0xab], false),
+    (0xac14, &[0xb0, 0xac], false),
+    (0xac15, &[0xb0, 0xad], false),
+    (0xac16, &[0xb0, 0xae], false),
+    (0xac17, &[0xb0, 0xaf], false),
+    (0xac19, &[0xb0, 0xb0], false),
+    (0xac1a, &[0xb0, 0xb1], false),
+    (0xac1b, &[0xb0, 0xb2], false),
+    (0xac1c, &[0xb0, 0xb3], false),
+    (0xac1d, &[0xb0, 0xb4], false),
+    (0xac20, &[0xb0, 0xb5], false),
+    (0xac24, &[0xb0, 0xb6], false),
+    (0xac2c, &[0xb0, 0xb7], false),
+    (0xac2d, &[0xb0, 0xb8], false),
+    (0xac2f, &[0xb0, 0xb9], false),
+    (0xac30, &[0xb0, 0xba], false),
+    (0xac31, &[0xb0, 0xbb], false),
+    (0xac38, &[0xb0, 0xbc], false),
+    (0xac39, &[0xb0, 0xbd], false),
+    (0xac3c, &[0xb0, 0xbe], false),
+    (0xac40, &[0xb0, 0xbf], false),
+    (0xac4b, &[0xb0, 0xc0], false),
+    (0xac4d, &[0xb0, 0xc1], false),
+    (0xac54, &[0xb0, 0xc2], false),
+    (0xac58, &[0xb0, 0xc3], false),
+    (0xac5c, &[0xb0, 0xc4], false),
+    (0xac70, &[0xb0, 0xc5], false),
+    (0xac71, &[0xb0, 0xc6], false),
+    (0xac74, &[0xb0, 0xc7], false),
+    (0xac77, &[0xb0, 0xc8], false),
+    (0xac78, &[0xb0, 0xc9], false),
+    (0xac7a, &[0xb0, 0xca], false),
+    (0xac80, &[0xb0, 0xcb], false),
+    (0xac81, &[0xb0, 0xcc], false),
+    (0xac83, &[0xb0, 0xcd], false),
+    (0xac84, &[0xb0, 0xce], false),
+    (0xac85, &[0xb0, 0xcf], false),
+    (0xac86, &[0xb0, 0xd0], false),
+    (0xac89, &[0xb0, 0xd1], false),
+    (0xac8a, &[0xb0, 0xd2], false),
+    (0xac8b, &[0xb0, 0xd3], false),
+    (0xac8c, &[0xb0, 0xd4], false),
+    (0xac90, &[0xb0, 0xd5], false),
+    (0xac94, &[0xb0, 0xd6], false),
+    (0xac9c, &[0xb0, 0xd7], false),
+    (0xac9d, &[0xb0, 0xd8], false),
+    (0xac9f, &[0xb0, 0xd9], false),
+    (0xaca0, &[0xb0, 0xda], false),
+    (0xaca1, &[0xb0, 0xdb], false),
+    (0xaca8, &[0xb0, 0xdc], false),
+    (0xaca9, &[0xb0, 0xdd], false),
+    (0xacaa, &[0xb0, 0xde], false),
+    (0xacac, &[0xb0, 0xdf], false),
+    (0xacaf, &[0xb0, 0xe0], false),
+    (0xacb0, &[0xb0, 0xe1], false),
+    (0xacb8, &[0xb0, 0xe2], false),
+    (0xacb9, &[0xb0, 0xe3], false),
+    (0xacbb, &[0xb0, 0xe4], false),
+    (0xacbc, &[0xb0, 0xe5], false),
+    (0xacbd, &[0xb0, 0xe6], false),
+    (0xacc1, &[0xb0, 0xe7], false),
+    (0xacc4, &[0xb0, 0xe8], false),
+    (0xacc8, &[0xb0, 0xe9], false),
+    (0xaccc, &[0xb0, 0xea], false),
+    (0xacd5, &[0xb0, 0xeb], false),
+    (0xacd7, &[0xb0, 0xec], false),
+    (0xace0, &[0xb0, 0xed], false),
+    (0xace1, &[0xb0, 0xee], false),
+    (0xace4, &[0xb0, 0xef], false),
+    (0xace7, &[0xb0, 0xf0], false),
+    (0xace8, &[0xb0, 0xf1], false),
+    (0xacea, &[0xb0, 0xf2], false),
+    (0xacec, &[0xb0, 0xf3], false),
+    (0xacef, &[0xb0, 0xf4], false),
+    (0xacf0, &[0xb0, 0xf5], false),
+    (0xacf1, &[0xb0, 0xf6], false),
+    (0xacf3, &[0xb0, 0xf7], false),
+    (0xacf5, &[0xb0, 0xf8], false),
+    (0xacf6, &[0xb0, 0xf9], false),
+    (0xacfc, &[0xb0, 0xfa], false),
+    (0xacfd, &[0xb0, 0xfb], false),
+    (0xad00, &[0xb0, 0xfc], false),
+    (0xad04, &[0xb0, 0xfd], false),
+    (0xad06, &[0xb0, 0xfe], false),
+    (0xcf02, &[0xb1, 0x41], false),
+    (0xcf03, &[0xb1, 0x42], false),
+    (0xcf05, &[0xb1, 0x43], false),
+    (0xcf06, &[0xb1, 0x44], false),
+    (0xcf07, &[0xb1, 0x45], false),
+    (0xcf09, &[0xb1, 0x46], false),
+    (0xcf0a, &[0xb1, 0x47], false),
+    (0xcf0b, &[0xb1, 0x48], false),
+    (0xcf0c, &[0xb1, 0x49], false),
+    (0xcf0d, &[0xb1, 0x4a], false),
+    (0xcf0e, &[0xb1, 0x4b], false),
+    (0xcf0f, &[0xb1, 0x4c], false),
+    (0xcf12, &[0xb1, 0x4d], false),
+    (0xcf14, &[0xb1, 0x4e], false),
+    (0xcf16, &[0xb1, 0x4f], false),
+    (0xcf17, &[0xb1, 0x50], false),
+    (0xcf18, &[0xb1, 0x51], false),
+    (0xcf19, &[0xb1, 0x52], false),
+    (0xcf1a, &[0xb1, 0x53], false),
+    (0xcf1b, &[0xb1, 0x54], false),
+    (0xcf1d, &[0xb1, 0x55], false),
+    (0xcf1e, &[0xb1, 0x56], false),
+    (0xcf1f, &[0xb1, 0x57], false),
+    (0xcf21, &[0xb1, 0x58], false),
+    (0xcf22, &[0xb1, 0x59], false),
+    (0xcf23, &[0xb1, 0x5a], false),
+    (0xcf25, &[0xb1, 0x61], false),
+    (0xcf26, &[0xb1, 0x62], false),
+    (0xcf27, &[0xb1, 0x63], false),
+    (0xcf28, &[0xb1, 0x64], false),
+    (0xcf29, &[0xb1, 0x65], false),
+    (0xcf2a, &[0xb1, 0x66], false),
+    (0xcf2b, &[0xb1, 0x67], false),
+    (0xcf2e, &[0xb1, 0x68], false),
+    (0xcf32, &[0xb1, 0x69], false),
+    (0xcf33, &[0xb1, 0x6a], false),
+    (0xcf34, &[0xb1, 0x6b], false),
+    (0xcf35, &[0xb1, 0x6c], false),
+    (0xcf36, &[0xb1, 0x6d], false),
+    (0xcf37, &[0xb1, 0x6e], false),
+    (0xcf39, &[0xb1, 0x6f], false),
+    (0xcf3a, &[0xb1, 0x70], false),
+    (0xcf3b, &[0xb1, 0x71], false),
+    (0xcf3c, &[0xb1, 0x72], false),
+    (0xcf3d, &[0xb1, 0x73], false),
+    (0xcf3e, &[0xb1, 0x74], false),
+    (0xcf3f, &[0xb1, 0x75], false),
+    (0xcf40, &[0xb1, 0x76], false),
+    (0xcf41, &[0xb1, 0x77], false),
+    (0xcf42, &[0xb1, 0x78], false),
+    (0xcf43, &[0xb1, 0x79], false),
+    (0xcf44, &[0xb1, 0x7a], false),
+    (0xcf45, &[0xb1, 0x81], false),
+    (0xcf46, &[0xb1, 0x82], false),
+    (0xcf47, &[0xb1, 0x83], false),
+    (0xcf48, &[0xb1, 0x84], false),
+    (0xcf49, &[0xb1, 0x85], false),
+    (0xcf4a, &[0xb1, 0x86], false),
+    (0xcf4b, &[0xb1, 0x87], false),
+    (0xcf4c, &[0xb1, 0x88], false),
+    (0xcf4d, &[0xb1, 0x89], false),
+    (0xcf4e, &[0xb1, 0x8a], false),
+    (0xcf4f, &[0xb1, 0x8b], false),
+    (0xcf50, &[0xb1, 0x8c], false),
+    (0xcf51, &[0xb1, 0x8d], false),
+    (0xcf52, &[0xb1, 0x8e], false),
+    (0xcf53, &[0xb1, 0x8f], false),
+    (0xcf56, &[0xb1, 0x90], false),
+    (0xcf57, &[0xb1, 0x91], false),
+    (0xcf59, &[0xb1, 0x92], false),
+    (0xcf5a, &[0xb1, 0x93], false),
+    (0xcf5b, &[0xb1, 0x94], false),
+    (0xcf5d, &[0xb1, 0x95], false),
+    (0xcf5e, &[0xb1, 0x96], false),
+    (0xcf5f, &[0xb1, 0x97], false),
+    (0xcf60, &[0xb1, 0x98], false),
+    (0xcf61, &[0xb1, 0x99], false),
+    (0xcf62, &[0xb1, 0x9a], false),
+    (0xcf63, &[0xb1, 0x9b], false),
+    (0xcf66, &[0xb1, 0x9c], false),
+    (0xcf68, &[0xb1, 0x9d], false),
+    (0xcf6a, &[0xb1, 0x9e], false),
+    (0xcf6b, &[0xb1, 0x9f], false),
+    (0xcf6c, &[0xb1, 0xa0], false),
+    (0xad0c, &[0xb1, 0xa1], false),
+    (0xad0d, &[0xb1, 0xa2], false),
+    (0xad0f, &[0xb1, 0xa3], false),
+    (0xad11, &[0xb1, 0xa4], false),
+    (0xad18, &[0xb1, 0xa5], false),
+    (0xad1c, &[0xb1, 0xa6], false),
+    (0xad20, &[0xb1, 0xa7], false),
+    (0xad29, &[0xb1, 0xa8], false),
+    (0xad2c, &[0xb1, 0xa9], false),
+    (0xad2d, &[0xb1, 0xaa], false),
+    (0xad34, &[0xb1, 0xab], false),
+    (0xad35, &[0xb1, 0xac], false),
+    (0xad38, &[0xb1, 0xad], false),
+    (0xad3c, &[0xb1, 0xae], false),
+    (0xad44, &[0xb1, 0xaf], false),
+    (0xad45, &[0xb1, 0xb0], false),
+    (0xad47, &[0xb1, 0xb1], false),
+    (0xad49, &[0xb1, 0xb2], false),
+    (0xad50, &[0xb1, 0xb3], false),
+    (0xad54, &[0xb1, 0xb4], false),
+    (0xad58, &[0xb1, 0xb5], false),
+    (0xad61, &[0xb1, 0xb6], false),
+    (0xad63, &[0xb1, 0xb7], false),
+    (0xad6c, &[0xb1, 0xb8], false),
+    (0xad6d, &[0xb1, 0xb9], false),
+    (0xad70, &[0xb1, 0xba], false),
+    (0xad73, &[0xb1, 0xbb], false),
+    (0xad74, &[0xb1, 0xbc], false),
+    (0xad75, &[0xb1, 0xbd], false),
+    (0xad76, &[0xb1, 0xbe], false),
+    (0xad7b, &[0xb1, 0xbf], false),
+    (0xad7c, &[0xb1, 0xc0], false),
+    (0xad7d, &[0xb1, 0xc1], false),
+    (0xad7f, &[0xb1, 0xc2], false),
+    (0xad81, &[0xb1, 0xc3], false),
+    (0xad82, &[0xb1, 0xc4], false),
+    (0xad88, &[0xb1, 0xc5], false),
+    (0xad89, &[0xb1, 0xc6], false),
+    (0xad8c, &[0xb1, 0xc7], false),
+    (0xad90, &[0xb1, 0xc8], false),
+    (0xad9c, &[0xb1, 0xc9], false),
+    (0xad9d, &[0xb1, 0xca], false),
+    (0xada4, &[0xb1, 0xcb], false),
+    (0xadb7, &[0xb1, 0xcc], false),
+    (0xadc0, &[0xb1, 0xcd], false),
+    (0xadc1, &[0xb1, 0xce], false),
+    (0xadc4, &[0xb1, 0xcf], false),
+    (0xadc8, &[0xb1, 0xd0], false),
+    (0xadd0, &[0xb1, 0xd1], false),
+    (0xadd1, &[0xb1, 0xd2], false),
+    (0xadd3, &[0xb1, 0xd3], false),
+    (0xaddc, &[0xb1, 0xd4], false),
+    (0xade0, &[0xb1, 0xd5], false),
+    (0xade4, &[0xb1, 0xd6], false),
+    (0xadf8, &[0xb1, 0xd7], false),
+    (0xadf9, &[0xb1, 0xd8], false),
+    (0xadfc, &[0xb1, 0xd9], false),
+    (0xadff, &[0xb1, 0xda], false),
+    (0xae00, &[0xb1, 0xdb], false),
+    (0xae01, &[0xb1, 0xdc], false),
+    (0xae08, &[0xb1, 0xdd], false),
+    (0xae09, &[0xb1, 0xde], false),
+    (0xae0b, &[0xb1, 0xdf], false),
+    (0xae0d, &[0xb1, 0xe0], false),
+    (0xae14, &[0xb1, 0xe1], false),
+    (0xae30, &[0xb1, 0xe2], false),
+    (0xae31, &[0xb1, 0xe3], false),
+    (0xae34, &[0xb1, 0xe4], false),
+    (0xae37, &[0xb1, 0xe5], false),
+    (0xae38, &[0xb1, 0xe6], false),
+    (0xae3a, &[0xb1, 0xe7], false),
+    (0xae40, &[0xb1, 0xe8], false),
+    (0xae41, &[0xb1, 0xe9], false),
+    (0xae43, &[0xb1, 0xea], false),
+    (0xae45, &[0xb1, 0xeb], false),
+    (0xae46, &[0xb1, 0xec], false),
+    (0xae4a, &[0xb1, 0xed], false),
+    (0xae4c, &[0xb1, 0xee], false),
+    (0xae4d, &[0xb1, 0xef], false),
+    (0xae4e, &[0xb1, 0xf0], false),
+    (0xae50, &[0xb1, 0xf1], false),
+    (0xae54, &[0xb1, 0xf2], false),
+    (0xae56, &[0xb1, 0xf3], false),
+    (0xae5c, &[0xb1, 0xf4], false),
+    (0xae5d, &[0xb1, 0xf5], false),
+    (0xae5f, &[0xb1, 0xf6], false),
+    (0xae60, &[0xb1, 0xf7], false),
+    (0xae61, &[0xb1, 0xf8], false),
+    (0xae65, &[0xb1, 0xf9], false),
+    (0xae68, &[0xb1, 0xfa], false),
+    (0xae69, &[0xb1, 0xfb], false),
+    (0xae6c, &[0xb1, 0xfc], false),
+    (0xae70, &[0xb1, 0xfd], false),
+    (0xae78, &[0xb1, 0xfe], false),
+    (0xcf6d, &[0xb2, 0x41], false),
+    (0xcf6e, &[0xb2, 0x42], false),
+    (0xcf6f, &[0xb2, 0x43], false),
+    (0xcf72, &[0xb2, 0x44], false),
+    (0xcf73, &[0xb2, 0x45], false),
+    (0xcf75, &[0xb2, 0x46], false),
+    (0xcf76, &[0xb2, 0x47], false),
+    (0xcf77, &[0xb2, 0x48], false),
+    (0xcf79, &[0xb2, 0x49], false),
+    (0xcf7a, &[0xb2, 0x4a], false),
+    (0xcf7b, &[0xb2, 0x4b], false),
+    (0xcf7c, &[0xb2, 0x4c], false),
+    (0xcf7d, &[0xb2, 0x4d], false),
+    (0xcf7e, &[0xb2, 0x4e], false),
+    (0xcf7f, &[0xb2, 0x4f], false),
+    (0xcf81, &[0xb2, 0x50], false),
+    (0xcf82, &[0xb2, 0x51], false),
+    (0xcf83, &[0xb2, 0x52], false),
+    (0xcf84, &[0xb2, 0x53], false),
+    (0xcf86, &[0xb2, 0x54], false),
+    (0xcf87, &[0xb2, 0x55], false),
+    (0xcf88, &[0xb2, 0x56], false),
+    (0xcf89, &[0xb2, 0x57], false),
+    (0xcf8a, &[0xb2, 0x58], false),
+    (0xcf8b, &[0xb2, 0x59], false),
+    (0xcf8d, &[0xb2, 0x5a], false),
+    (0xcf8e, &[0xb2, 0x61], false),
+    (0xcf8f, &[0xb2, 0x62], false),
+    (0xcf90, &[0xb2, 0x63], false),
+    (0xcf91, &[0xb2, 0x64], false),
+    (0xcf92, &[0xb2, 0x65], false),
+    (0xcf93, &[0xb2, 0x66], false),
+    (0xcf94, &[0xb2, 0x67], false),
+    (0xcf95, &[0xb2, 0x68], false),
+    (0xcf96, &[0xb2, 0x69], false),
+    (0xcf97, &[0xb2, 0x6a], false),
+    (0xcf98, &[0xb2, 0x6b], false),
+    (0xcf99, &[0xb2, 0x6c], false),
+    (0xcf9a, &[0xb2, 0x6d], false),
+    (0xcf9b, &[0xb2, 0x6e], false),
+    (0xcf9c, &[0xb2, 0x6f], false),
+    (0xcf9d, &[0xb2, 0x70], false),
+    (0xcf9e, &[0xb2, 0x71], false),
+    (0xcf9f, &[0xb2, 0x72], false),
+    (0xcfa0, &[0xb2, 0x73], false),
+    (0xcfa2, &[0xb2, 0x74], false),
+    (0xcfa3, &[0xb2, 0x75], false),
+    (0xcfa4, &[0xb2, 0x76], false),
+    (0xcfa5, &[0xb2, 0x77], false),
+    (0xcfa6, &[0xb2, 0x78], false),
+    (0xcfa7, &[0xb2, 0x79], false),
+    (0xcfa9, &[0xb2, 0x7a], false),
+    (0xcfaa, &[0xb2, 0x81], false),
+    (0xcfab, &[0xb2, 0x82], false),
+    (0xcfac, &[0xb2, 0x83], false),
+    (0xcfad, &[0xb2, 0x84], false),
+    (0xcfae, &[0xb2, 0x85], false),
+    (0xcfaf, &[0xb2, 0x86], false),
+    (0xcfb1, &[0xb2, 0x87], false),
+    (0xcfb2, &[0xb2, 0x88], false),
+    (0xcfb3, &[0xb2, 0x89], false),
+    (0xcfb4, &[0xb2, 0x8a], false),
+    (0xcfb5, &[0xb2, 0x8b], false),
+    (0xcfb6, &[0xb2, 0x8c], false),
+    (0xcfb7, &[0xb2, 0x8d], false),
+    (0xcfb8, &[0xb2, 0x8e], false),
+    (0xcfb9, &[0xb2, 0x8f], false),
+    (0xcfba, &[0xb2, 0x90], false),
+    (0xcfbb, &[0xb2, 0x91], false),
+    (0xcfbc, &[0xb2, 0x92], false),
+    (0xcfbd, &[0xb2, 0x93], false),
+    (0xcfbe, &[0xb2, 0x94], false),
+    (0xcfbf, &[0xb2, 0x95], false),
+    (0xcfc0, &[0xb2, 0x96], false),
+    (0xcfc1, &[0xb2, 0x97], false),
+    (0xcfc2, &[0xb2, 0x98], false),
+    (0xcfc3, &[0xb2, 0x99], false),
+    (0xcfc5, &[0xb2, 0x9a], false),
+    (0xcfc6, &[0xb2, 0x9b], false),
+    (0xcfc7, &[0xb2, 0x9c], false),
+    (0xcfc8, &[0xb2, 0x9d], false),
+    (0xcfc9, &[0xb2, 0x9e], false),
+    (0xcfca, &[0xb2, 0x9f], false),
+    (0xcfcb, &[0xb2, 0xa0], false),
+    (0xae79, &[0xb2, 0xa1], false),
+    (0xae7b, &[0xb2, 0xa2], false),
+    (0xae7c, &[0xb2, 0xa3], false),
+    (0xae7d, &[0xb2, 0xa4], false),
+    (0xae84, &[0xb2, 0xa5], false),
+    (0xae85, &[0xb2, 0xa6], false),
+    (0xae8c, &[0xb2, 0xa7], false),
+    (0xaebc, &[0xb2, 0xa8], false),
+    (0xaebd, &[0xb2, 0xa9], false),
+    (0xaebe, &[0xb2, 0xaa], false),
+    (0xaec0, &[0xb2, 0xab], false),
+    (0xaec4, &[0xb2, 0xac], false),
+    (0xaecc, &[0xb2, 0xad], false),
+    (0xaecd, &[0xb2, 0xae], false),
+    (0xaecf, &[0xb2, 0xaf], false),
+    (0xaed0, &[0xb2, 0xb0], false),
+    (0xaed1, &[0xb2, 0xb1], false),
+    (0xaed8, &[0xb2, 0xb2], false),
+    (0xaed9, &[0xb2, 0xb3], false),
+    (0xaedc, &[0xb2, 0xb4], false),
+    (0xaee8, &[0xb2, 0xb5], false),
+    (0xaeeb, &[0xb2, 0xb6], false),
+    (0xaeed, &[0xb2, 0xb7], false),
+    (0xaef4, &[0xb2, 0xb8], false),
+    (0xaef8, &[0xb2, 0xb9], false),
+    (0xaefc, &[0xb2, 0xba], false),
+    (0xaf07, &[0xb2, 0xbb], false),
+    (0xaf08, &[0xb2, 0xbc], false),
+    (0xaf0d, &[0xb2, 0xbd], false),
+    (0xaf10, &[0xb2, 0xbe], false),
+    (0xaf2c, &[0xb2, 0xbf], false),
+    (0xaf2d, &[0xb2, 0xc0], false),
+    (0xaf30, &[0xb2, 0xc1], false),
+    (0xaf32, &[0xb2, 0xc2], false),
+    (0xaf34, &[0xb2, 0xc3], false),
+    (0xaf3c, &[0xb2, 0xc4], false),
+    (0xaf3d, &[0xb2, 0xc5], false),
+    (0xaf3f, &[0xb2, 0xc6], false),
+    (0xaf41, &[0xb2, 0xc7], false),
+    (0xaf42, &[0xb2, 0xc8], false),
+    (0xaf43, &[0xb2, 0xc9], false),
+    (0xaf48, &[0xb2, 0xca], false),
+    (0xaf49, &[0xb2, 0xcb], false),
+    (0xaf50, &[0xb2, 0xcc], false),
+    (0xaf5c, &[0xb2, 0xcd], false),
+    (0xaf5d, &[0xb2, 0xce], false),
+    (0xaf64, &[0xb2, 0xcf], false),
+    (0xaf65, &[0xb2, 0xd0], false),
+    (0xaf79, &[0xb2, 0xd1], false),
+    (0xaf80, &[0xb2, 0xd2], false),
+    (0xaf84, &[0xb2, 0xd3], false),
+    (0xaf88, &[0xb2, 0xd4], false),
+    (0xaf90, &[0xb2, 0xd5], false),
+    (0xaf91, &[0xb2, 0xd6], false),
+    (0xaf95, &[0xb2, 0xd7], false),
+    (0xaf9c, &[0xb2, 0xd8], false),
+    (0xafb8, &[0xb2, 0xd9], false),
+    (0xafb9, &[0xb2, 0xda], false),
+    (0xafbc, &[0xb2, 0xdb], false),
+    (0xafc0, &[0xb2, 0xdc], false),
+    (0xafc7, &[0xb2, 0xdd], false),
+    (0xafc8, &[0xb2, 0xde], false),
+    (0xafc9, &[0xb2, 0xdf], false),
+    (0xafcb, &[0xb2, 0xe0], false),
+    (0xafcd, &[0xb2, 0xe1], false),
+    (0xafce, &[0xb2, 0xe2], false),
+    (0xafd4, &[0xb2, 0xe3], false),
+    (0xafdc, &[0xb2, 0xe4], false),
+    (0xafe8, &[0xb2, 0xe5], false),
+    (0xafe9, &[0xb2, 0xe6], false),
+    (0xaff0, &[0xb2, 0xe7], false),
+    (0xaff1, &[0xb2, 0xe8], false),
+    (0xaff4, &[0xb2, 0xe9], false),
+    (0xaff8, &[0xb2, 0xea], false),
+    (0xb000, &[0xb2, 0xeb], false),
+    (0xb001, &[0xb2, 0xec], false),
+    (0xb004, &[0xb2, 0xed], false),
+    (0xb00c, &[0xb2, 0xee], false),
+    (0xb010, &[0xb2, 0xef], false),
+    (0xb014, &[0xb2, 0xf0], false),
+    (0xb01c, &[0xb2, 0xf1], false),
+    (0xb01d, &[0xb2, 0xf2], false),
+    (0xb028, &[0xb2, 0xf3], false),
+    (0xb044, &[0xb2, 0xf4], false),
+    (0xb045, &[0xb2, 0xf5], false),
+    (0xb048, &[0xb2, 0xf6], false),
+    (0xb04a, &[0xb2, 0xf7], false),
+    (0xb04c, &[0xb2, 0xf8], false),
+    (0xb04e, &[0xb2, 0xf9], false),
+    (0xb053, &[0xb2, 0xfa], false),
+    (0xb054, &[0xb2, 0xfb], false),
+    (0xb055, &[0xb2, 0xfc], false),
+    (0xb057, &[0xb2, 0xfd], false),
+    (0xb059, &[0xb2, 0xfe], false),
+    (0xcfcc, &[0xb3, 0x41], false),
+    (0xcfcd, &[0xb3, 0x42], false),
+    (0xcfce, &[0xb3, 0x43], false),
+    (0xcfcf, &[0xb3, 0x44], false),
+    (0xcfd0, &[0xb3, 0x45], false),
+    (0xcfd1, &[0xb3, 0x46], false),
+    (0xcfd2, &[0xb3, 0x47], false),
+    (0xcfd3, &[0xb3, 0x48], false),
+    (0xcfd4, &[0xb3, 0x49], false),
+    (0xcfd5, &[0xb3, 0x4a], false),
+    (0xcfd6, &[0xb3, 0x4b], false),
+    (0xcfd7, &[0xb3, 0x4c], false),
+    (0xcfd8, &[0xb3, 0x4d], false),
+    (0xcfd9, &[0xb3, 0x4e], false),
+    (0xcfda, &[0xb3, 0x4f], false),
+    (0xcfdb, &[0xb3, 0x50], false),
+    (0xcfdc, &[0xb3, 0x51], false),
+    (0xcfdd, &[0xb3, 0x52], false),
+    (0xcfde, &[0xb3, 0x53], false),
+    (0xcfdf, &[0xb3, 0x54], false),
+    (0xcfe2, &[0xb3, 0x55], false),
+    (0xcfe3, &[0xb3, 0x56], false),
+    (0xcfe5, &[0xb3, 0x57], false),
+    (0xcfe6, &[0xb3, 0x58], false),
+    (0xcfe7, &[0xb3, 0x59], false),
+    (0xcfe9, &[0xb3, 0x5a], false),
+    (0xcfea, &[0xb3, 0x61], false),
+    (0xcfeb, &[0xb3, 0x62], false),
+    (0xcfec, &[0xb3, 0x63], false),
+    (0xcfed, &[0xb3, 0x64], false),
+    (0xcfee, &[0xb3, 0x65], false),
+    (0xcfef, &[0xb3, 0x66], false),
+    (0xcff2, &[0xb3, 0x67], false),
+    (0xcff4, &[0xb3, 0x68], false),
+    (0xcff6, &[0xb3, 0x69], false),
+    (0xcff7, &[0xb3, 0x6a], false),
+    (0xcff8, &[0xb3, 0x6b], false),
+    (0xcff9, &[0xb3, 0x6c], false),
+    (0xcffa, &[0xb3, 0x6d], false),
+    (0xcffb, &[0xb3, 0x6e], false),
+    (0xcffd, &[0xb3, 0x6f], false),
+    (0xcffe, &[0xb3, 0x70], false),
+    (0xcfff, &[0xb3, 0x71], false),
+    (0xd001, &[0xb3, 0x72], false),
+    (0xd002, &[0xb3, 0x73], false),
+    (0xd003, &[0xb3, 0x74], false),
+    (0xd005, &[0xb3, 0x75], false),
+    (0xd006, &[0xb3, 0x76], false),
+    (0xd007, &[0xb3, 0x77], false),
+    (0xd008, &[0xb3, 0x78], false),
+    (0xd009, &[0xb3, 0x79], false),
+    (0xd00a, &[0xb3, 0x7a], false),
+    (0xd00b, &[0xb3, 0x81], false),
+    (0xd00c, &[0xb3, 0x82], false),
+    (0xd00d, &[0xb3, 0x83], false),
+    (0xd00e, &[0xb3, 0x84], false),
+    (0xd00f, &[0xb3, 0x85], false),
+    (0xd010, &[0xb3, 0x86], false),
+    (0xd012, &[0xb3, 0x87], false),
+    (0xd013, &[0xb3, 0x88], false),
+    (0xd014, &[0xb3, 0x89], false),
+    (0xd015, &[0xb3, 0x8a], false),
+    (0xd016, &[0xb3, 0x8b], false),
+    (0xd017, &[0xb3, 0x8c], false),
+    (0xd019, &[0xb3, 0x8d], false),
+    (0xd01a, &[0xb3, 0x8e], false),
+    (0xd01b, &[0xb3, 0x8f], false),
+    (0xd01c, &[0xb3, 0x90], false),
+    (0xd01d, &[0xb3, 0x91], false),
+    (0xd01e, &[0xb3, 0x92], false),
+    (0xd01f, &[0xb3, 0x93], false),
+    (0xd020, &[0xb3, 0x94], false),
+    (0xd021, &[0xb3, 0x95], false),
+    (0xd022, &[0xb3, 0x96], false),
+    (0xd023, &[0xb3, 0x97], false),
+    (0xd024, &[0xb3, 0x98], false),
+    (0xd025, &[0xb3, 0x99], false),
+    (0xd026, &[0xb3, 0x9a], false),
+    (0xd027, &[0xb3, 0x9b], false),
+    (0xd028, &[0xb3, 0x9c], false),
+    (0xd029, &[0xb3, 0x9d], false),
+    (0xd02a, &[0xb3, 0x9e], false),
+    (0xd02b, &[0xb3, 0x9f], false),
+    (0xd02c, &[0xb3, 0xa0], false),
+    (0xb05d, &[0xb3, 0xa1], false),
+    (0xb07c, &[0xb3, 0xa2], false),
+    (0xb07d, &[0xb3, 0xa3], false),
+    (0xb080, &[0xb3, 0xa4], false),
+    (0xb084, &[0xb3, 0xa5], false),
+    (0xb08c, &[0xb3, 0xa6], false),
+    (0xb08d, &[0xb3, 0xa7], false),
+    (0xb08f, &[0xb3, 0xa8], false),
+    (0xb091, &[0xb3, 0xa9], false),
+    (0xb098, &[0xb3, 0xaa], false),
+    (0xb099, &[0xb3, 0xab], false),
+    (0xb09a, &[0xb3, 0xac], false),
+    (0xb09c, &[0xb3, 0xad], false),
+    (0xb09f, &[0xb3, 0xae], false),
+    (0xb0a0, &[0xb3, 0xaf], false),
+    (0xb0a1, &[0xb3, 0xb0], false),
+    (0xb0a2, &[0xb3, 0xb1], false),
+    (0xb0a8, &[0xb3, 0xb2], false),
+    (0xb0a9, &[0xb3, 0xb3], false),
+    (0xb0ab, &[0xb3, 0xb4], false),
+    (0xb0ac, &[0xb3, 0xb5], false),
+    (0xb0ad, &[0xb3, 0xb6], false),
+    (0xb0ae, &[0xb3, 0xb7], false),
+    (0xb0af, &[0xb3, 0xb8], false),
+    (0xb0b1, &[0xb3, 0xb9], false),
+    (0xb0b3, &[0xb3, 0xba], false),
+    (0xb0b4, &[0xb3, 0xbb], false),
+    (0xb0b5, &[0xb3, 0xbc], false),
+    (0xb0b8, &[0xb3, 0xbd], false),
+    (0xb0bc, &[0xb3, 0xbe], false),
+    (0xb0c4, &[0xb3, 0xbf], false),
+    (0xb0c5, &[0xb3, 0xc0], false),
+    (0xb0c7, &[0xb3, 0xc1], false),
+    (0xb0c8, &[0xb3, 0xc2], false),
+    (0xb0c9, &[0xb3, 0xc3], false),
+    (0xb0d0, &[0xb3, 0xc4], false),
+    (0xb0d1, &[0xb3, 0xc5], false),
+    (0xb0d4, &[0xb3, 0xc6], false),
+    (0xb0d8, &[0xb3, 0xc7], false),
+    (0xb0e0, &[0xb3, 0xc8], false),
+    (0xb0e5, &[0xb3, 0xc9], false),
+    (0xb108, &[0xb3, 0xca], false),
+    (0xb109, &[0xb3, 0xcb], false),
+    (0xb10b, &[0xb3, 0xcc], false),
+    (0xb10c, &[0xb3, 0xcd], false),
+    (0xb110, &[0xb3, 0xce], false),
+    (0xb112, &[0xb3, 0xcf], false),
+    (0xb113, &[0xb3, 0xd0], false),
+    (0xb118, &[0xb3, 0xd1], false),
+    (0xb119, &[0xb3, 0xd2], false),
+    (0xb11b, &[0xb3, 0xd3], false),
+    (0xb11c, &[0xb3, 0xd4], false),
+    (0xb11d, &[0xb3, 0xd5], false),
+    (0xb123, &[0xb3, 0xd6], false),
+    (0xb124, &[0xb3, 0xd7], false),
+    (0xb125, &[0xb3, 0xd8], false),
+    (0xb128, &[0xb3, 0xd9], false),
+    (0xb12c, &[0xb3, 0xda], false),
+    (0xb134, &[0xb3, 0xdb], false),
+    (0xb135, &[0xb3, 0xdc], false),
+    (0xb137, &[0xb3, 0xdd], false),
+    (0xb138, &[0xb3, 0xde], false),
+    (0xb139, &[0xb3, 0xdf], false),
+    (0xb140, &[0xb3, 0xe0], false),
+    (0xb141, &[0xb3, 0xe1], false),
+    (0xb144, &[0xb3, 0xe2], false),
+    (0xb148, &[0xb3, 0xe3], false),
+    (0xb150, &[0xb3, 0xe4], false),
+    (0xb151, &[0xb3, 0xe5], false),
+    (0xb154, &[0xb3, 0xe6], false),
+    (0xb155, &[0xb3, 0xe7], false),
+    (0xb158, &[0xb3, 0xe8], false),
+    (0xb15c, &[0xb3, 0xe9], false),
+    (0xb160, &[0xb3, 0xea], false),
+    (0xb178, &[0xb3, 0xeb], false),
+    (0xb179, &[0xb3, 0xec], false),
+    (0xb17c, &[0xb3, 0xed], false),
+    (0xb180, &[0xb3, 0xee], false),
+    (0xb182, &[0xb3, 0xef], false),
+    (0xb188, &[0xb3, 0xf0], false),
+    (0xb189, &[0xb3, 0xf1], false),
+    (0xb18b, &[0xb3, 0xf2], false),
+    (0xb18d, &[0xb3, 0xf3], false),
+    (0xb192, &[0xb3, 0xf4], false),
+    (0xb193, &[0xb3, 0xf5], false),
+    (0xb194, &[0xb3, 0xf6], false),
+    (0xb198, &[0xb3, 0xf7], false),
+    (0xb19c, &[0xb3, 0xf8], false),
+    (0xb1a8, &[0xb3, 0xf9], false),
+    (0xb1cc, &[0xb3, 0xfa], false),
+    (0xb1d0, &[0xb3, 0xfb], false),
+    (0xb1d4, &[0xb3, 0xfc], false),
+    (0xb1dc, &[0xb3, 0xfd], false),
+    (0xb1dd, &[0xb3, 0xfe], false),
+    (0xd02e, &[0xb4, 0x41], false),
+    (0xd02f, &[0xb4, 0x42], false),
+    (0xd030, &[0xb4, 0x43], false),
+    (0xd031, &[0xb4, 0x44], false),
+    (0xd032, &[0xb4, 0x45], false),
+    (0xd033, &[0xb4, 0x46], false),
+    (0xd036, &[0xb4, 0x47], false),
+    (0xd037, &[0xb4, 0x48], false),
+    (0xd039, &[0xb4, 0x49], false),
+    (0xd03a, &[0xb4, 0x4a], false),
+    (0xd03b, &[0xb4, 0x4b], false),
+    (0xd03d, &[0xb4, 0x4c], false),
+    (0xd03e, &[0xb4, 0x4d], false),
+    (0xd03f, &[0xb4, 0x4e], false),
+    (0xd040, &[0xb4, 0x4f], false),
+    (0xd041, &[0xb4, 0x50], false),
+    (0xd042, &[0xb4, 0x51], false),
+    (0xd043, &[0xb4, 0x52], false),
+    (0xd046, &[0xb4, 0x53], false),
+    (0xd048, &[0xb4, 0x54], false),
+    (0xd04a, &[0xb4, 0x55], false),
+    (0xd04b, &[0xb4, 0x56], false),
+    (0xd04c, &[0xb4, 0x57], false),
+    (0xd04d, &[0xb4, 0x58], false),
+    (0xd04e, &[0xb4, 0x59], false),
+    (0xd04f, &[0xb4, 0x5a], false),
+    (0xd051, &[0xb4, 0x61], false),
+    (0xd052, &[0xb4, 0x62], false),
+    (0xd053, &[0xb4, 0x63], false),
+    (0xd055, &[0xb4, 0x64], false),
+    (0xd056, &[0xb4, 0x65], false),
+    (0xd057, &[0xb4, 0x66], false),
+    (0xd059, &[0xb4, 0x67], false),
+    (0xd05a, &[0xb4, 0x68], false),
+    (0xd05b, &[0xb4, 0x69], false),
+    (0xd05c, &[0xb4, 0x6a], false),
+    (0xd05d, &[0xb4, 0x6b], false),
+    (0xd05e, &[0xb4, 0x6c], false),
+    (0xd05f, &[0xb4, 0x6d], false),
+    (0xd061, &[0xb4, 0x6e], false),
+    (0xd062, &[0xb4, 0x6f], false),
+    (0xd063, &[0xb4, 0x70], false),
+    (0xd064, &[0xb4, 0x71], false),
+    (0xd065, &[0xb4, 0x72], false),
+    (0xd066, &[0xb4, 0x73], false),
+    (0xd067, &[0xb4, 0x74], false),
+    (0xd068, &[0xb4, 0x75], false),
+    (0xd069, &[0xb4, 0x76], false),
+    (0xd06a, &[0xb4, 0x77], false),
+    (0xd06b, &[0xb4, 0x78], false),
+    (0xd06e, &[0xb4, 0x79], false),
+    (0xd06f, &[0xb4, 0x7a], false),
+    (0xd071, &[0xb4, 0x81], false),
+    (0xd072, &[0xb4, 0x82], false),
+    (0xd073, &[0xb4, 0x83], false),
+    (0xd075, &[0xb4, 0x84], false),
+    (0xd076, &[0xb4, 0x85], false),
+    (0xd077, &[0xb4, 0x86], false),
+    (0xd078, &[0xb4, 0x87], false),
+    (0xd079, &[0xb4, 0x88], false),
+    (0xd07a, &[0xb4, 0x89], false),
+    (0xd07b, &[0xb4, 0x8a], false),
+    (0xd07e, &[0xb4, 0x8b], false),
+    (0xd07f, &[0xb4, 0x8c], false),
+    (0xd080, &[0xb4, 0x8d], false),
+    (0xd082, &[0xb4, 0x8e], false),
+    (0xd083, &[0xb4, 0x8f], false),
+    (0xd084, &[0xb4, 0x90], false),
+    (0xd085, &[0xb4, 0x91], false),
+    (0xd086, &[0xb4, 0x92], false),
+    (0xd087, &[0xb4, 0x93], false),
+    (0xd088, &[0xb4, 0x94], false),
+    (0xd089, &[0xb4, 0x95], false),
+    (0xd08a, &[0xb4, 0x96], false),
+    (0xd08b, &[0xb4, 0x97], false),
+    (0xd08c, &[0xb4, 0x98], false),
+    (0xd08d, &[0xb4, 0x99], false),
+    (0xd08e, &[0xb4, 0x9a], false),
+    (0xd08f, &[0xb4, 0x9b], false),
+    (0xd090, &[0xb4, 0x9c], false),
+    (0xd091, &[0xb4, 0x9d], false),
+    (0xd092, &[0xb4, 0x9e], false),
+    (0xd093, &[0xb4, 0x9f], false),
+    (0xd094, &[0xb4, 0xa0], false),
+    (0xb1df, &[0xb4, 0xa1], false),
+    (0xb1e8, &[0xb4, 0xa2], false),
+    (0xb1e9, &[0xb4, 0xa3], false),
+    (0xb1ec, &[0xb4, 0xa4], false),
+    (0xb1f0, &[0xb4, 0xa5], false),
+    (0xb1f9, &[0xb4, 0xa6], false),
+    (0xb1fb, &[0xb4, 0xa7], false),
+    (0xb1fd, &[0xb4, 0xa8], false),
+    (0xb204, &[0xb4, 0xa9], false),
+    (0xb205, &[0xb4, 0xaa], false),
+    (0xb208, &[0xb4, 0xab], false),
+    (0xb20b, &[0xb4, 0xac], false),
+    (0xb20c, &[0xb4, 0xad], false),
+    (0xb214, &[0xb4, 0xae], false),
+    (0xb215, &[0xb4, 0xaf], false),
+    (0xb217, &[0xb4, 0xb0], false),
+    (0xb219, &[0xb4, 0xb1], false),
+    (0xb220, &[0xb4, 0xb2], false),
+    (0xb234, &[0xb4, 0xb3], false),
+    (0xb23c, &[0xb4, 0xb4], false),
+    (0xb258, &[0xb4, 0xb5], false),
+    (0xb25c, &[0xb4, 0xb6], false),
+    (0xb260, &[0xb4, 0xb7], false),
+    (0xb268, &[0xb4, 0xb8], false),
+    (0xb269, &[0xb4, 0xb9], false),
+    (0xb274, &[0xb4, 0xba], false),
+    (0xb275, &[0xb4, 0xbb], false),
+    (0xb27c, &[0xb4, 0xbc], false),
+    (0xb284, &[0xb4, 0xbd], false),
+    (0xb285, &[0xb4, 0xbe], false),
+    (0xb289, &[0xb4, 0xbf], false),
+    (0xb290, &[0xb4, 0xc0], false),
+    (0xb291, &[0xb4, 0xc1], false),
+    (0xb294, &[0xb4, 0xc2], false),
+    (0xb298, &[0xb4, 0xc3], false),
+    (0xb299, &[0xb4, 0xc4], false),
+    (0xb29a, &[0xb4, 0xc5], false),
+    (0xb2a0, &[0xb4, 0xc6], false),
+    (0xb2a1, &[0xb4, 0xc7], false),
+    (0xb2a3, &[0xb4, 0xc8], false),
+    (0xb2a5, &[0xb4, 0xc9], false),
+    (0xb2a6, &[0xb4, 0xca], false),
+    (0xb2aa, &[0xb4, 0xcb], false),
+    (0xb2ac, &[0xb4, 0xcc], false),
+    (0xb2b0, &[0xb4, 0xcd], false),
+    (0xb2b4, &[0xb4, 0xce], false),
+    (0xb2c8, &[0xb4, 0xcf], false),
+    (0xb2c9, &[0xb4, 0xd0], false),
+    (0xb2cc, &[0xb4, 0xd1], false),
+    (0xb2d0, &[0xb4, 0xd2], false),
+    (0xb2d2, &[0xb4, 0xd3], false),
+    (0xb2d8, &[0xb4, 0xd4], false),
+    (0xb2d9, &[0xb4, 0xd5], false),
+    (0xb2db, &[0xb4, 0xd6], false),
+    (0xb2dd, &[0xb4, 0xd7], false),
+    (0xb2e2, &[0xb4, 0xd8], false),
+    (0xb2e4, &[0xb4, 0xd9], false),
+    (0xb2e5, &[0xb4, 0xda], false),
+    (0xb2e6, &[0xb4, 0xdb], false),
+    (0xb2e8, &[0xb4, 0xdc], false),
+    (0xb2eb, &[0xb4, 0xdd], false),
+    (0xb2ec, &[0xb4, 0xde], false),
+    (0xb2ed, &[0xb4, 0xdf], false),
+    (0xb2ee, &[0xb4, 0xe0], false),
+    (0xb2ef, &[0xb4, 0xe1], false),
+    (0xb2f3, &[0xb4, 0xe2], false),
+    (0xb2f4, &[0xb4, 0xe3], false),
+    (0xb2f5, &[0xb4, 0xe4], false),
+    (0xb2f7, &[0xb4, 0xe5], false),
+    (0xb2f8, &[0xb4, 0xe6], false),
+    (0xb2f9, &[0xb4, 0xe7], false),
+    (0xb2fa, &[0xb4, 0xe8], false),
+    (0xb2fb, &[0xb4, 0xe9], false),
+    (0xb2ff, &[0xb4, 0xea], false),
+    (0xb300, &[0xb4, 0xeb], false),
+    (0xb301, &[0xb4, 0xec], false),
+    (0xb304, &[0xb4, 0xed], false),
+    (0xb308, &[0xb4, 0xee], false),
+    (0xb310, &[0xb4, 0xef], false),
+    (0xb311, &[0xb4, 0xf0], false),
+    (0xb313, &[0xb4, 0xf1], false),
+    (0xb314, &[0xb4, 0xf2], false),
+    (0xb315, &[0xb4, 0xf3], false),
+    (0xb31c, &[0xb4, 0xf4], false),
+    (0xb354, &[0xb4, 0xf5], false),
+    (0xb355, &[0xb4, 0xf6], false),
+    (0xb356, &[0xb4, 0xf7], false),
+    (0xb358, &[0xb4, 0xf8], false),
+    (0xb35b, &[0xb4, 0xf9], false),
+    (0xb35c, &[0xb4, 0xfa], false),
+    (0xb35e, &[0xb4, 0xfb], false),
+    (0xb35f, &[0xb4, 0xfc], false),
+    (0xb364, &[0xb4, 0xfd], false),
+    (0xb365, &[0xb4, 0xfe], false),
+    (0xd095, &[0xb5, 0x41], false),
+    (0xd096, &[0xb5, 0x42], false),
+    (0xd097, &[0xb5, 0x43], false),
+    (0xd098, &[0xb5, 0x44], false),
+    (0xd099, &[0xb5, 0x45], false),
+    (0xd09a, &[0xb5, 0x46], false),
+    (0xd09b, &[0xb5, 0x47], false),
+    (0xd09c, &[0xb5, 0x48], false),
+    (0xd09d, &[0xb5, 0x49], false),
+    (0xd09e, &[0xb5, 0x4a], false),
+    (0xd09f, &[0xb5, 0x4b], false),
+    (0xd0a0, &[0xb5, 0x4c], false),
+    (0xd0a1, &[0xb5, 0x4d], false),
+    (0xd0a2, &[0xb5, 0x4e], false),
+    (0xd0a3, &[0xb5, 0x4f], false),
+    (0xd0a6, &[0xb5, 0x50], false),
+    (0xd0a7, &[0xb5, 0x51], false),
+    (0xd0a9, &[0xb5, 0x52], false),
+    (0xd0aa, &[0xb5, 0x53], false),
+    (0xd0ab, &[0xb5, 0x54], false),
+    (0xd0ad, &[0xb5, 0x55], false),
+    (0xd0ae, &[0xb5, 0x56], false),
+    (0xd0af, &[0xb5, 0x57], false),
+    (0xd0b0, &[0xb5, 0x58], false),
+    (0xd0b1, &[0xb5, 0x59], false),
+    (0xd0b2, &[0xb5, 0x5a], false),
+    (0xd0b3, &[0xb5, 0x61], false),
+    (0xd0b6, &[0xb5, 0x62], false),
+    (0xd0b8, &[0xb5, 0x63], false),
+    (0xd0ba, &[0xb5, 0x64], false),
+    (0xd0bb, &[0xb5, 0x65], false),
+    (0xd0bc, &[0xb5, 0x66], false),
+    (0xd0bd, &[0xb5, 0x67], false),
+    (0xd0be, &[0xb5, 0x68], false),
+    (0xd0bf, &[0xb5, 0x69], false),
+    (0xd0c2, &[0xb5, 0x6a], false),
+    (0xd0c3, &[0xb5, 0x6b], false),
+    (0xd0c5, &[0xb5, 0x6c], false),
+    (0xd0c6, &[0xb5, 0x6d], false),
+    (0xd0c7, &[0xb5, 0x6e], false),
+    (0xd0ca, &[0xb5, 0x6f], false),
+    (0xd0cb, &[0xb5, 0x70], false),
+    (0xd0cc, &[0xb5, 0x71], false),
+    (0xd0cd, &[0xb5, 0x72], false),
+    (0xd0ce, &[0xb5, 0x73], false),
+    (0xd0cf, &[0xb5, 0x74], false),
+    (0xd0d2, &[0xb5, 0x75], false),
+    (0xd0d6, &[0xb5, 0x76], false),
+    (0xd0d7, &[0xb5, 0x77], false),
+    (0xd0d8, &[0xb5, 0x78], false),
+    (0xd0d9, &[0xb5, 0x79], false),
+    (0xd0da, &[0xb5, 0x7a], false),
+    (0xd0db, &[0xb5, 0x81], false),
+    (0xd0de, &[0xb5, 0x82], false),
+    (0xd0df, &[0xb5, 0x83], false),
+    (0xd0e1, &[0xb5, 0x84], false),
+    (0xd0e2, &[0xb5, 0x85], false),
+    (0xd0e3, &[0xb5, 0x86], false),
+    (0xd0e5, &[0xb5, 0x87], false),
+    (0xd0e6, &[0xb5, 0x88], false),
+    (0xd0e7, &[0xb5, 0x89], false),
+    (0xd0e8, &[0xb5, 0x8a], false),
+    (0xd0e9, &[0xb5, 0x8b], false),
+    (0xd0ea, &[0xb5, 0x8c], false),
+    (0xd0eb, &[0xb5, 0x8d], false),
+    (0xd0ee, &[0xb5, 0x8e], false),
+    (0xd0f2, &[0xb5, 0x8f], false),
+    (0xd0f3, &[0xb5, 0x90], false),
+    (0xd0f4, &[0xb5, 0x91], false),
+    (0xd0f5, &[0xb5, 0x92], false),
+    (0xd0f6, &[0xb5, 0x93], false),
+    (0xd0f7, &[0xb5, 0x94], false),
+    (0xd0f9, &[0xb5, 0x95], false),
+    (0xd0fa, &[0xb5, 0x96], false),
+    (0xd0fb, &[0xb5, 0x97], false),
+    (0xd0fc, &[0xb5, 0x98], false),
+    (0xd0fd, &[0xb5, 0x99], false),
+    (0xd0fe, &[0xb5, 0x9a], false),
+    (0xd0ff, &[0xb5, 0x9b], false),
+    (0xd100, &[0xb5, 0x9c], false),
+    (0xd101, &[0xb5, 0x9d], false),
+    (0xd102, &[0xb5, 0x9e], false),
+    (0xd103, &[0xb5, 0x9f], false),
+    (0xd104, &[0xb5, 0xa0], false),
+    (0xb367, &[0xb5, 0xa1], false),
+    (0xb369, &[0xb5, 0xa2], false),
+    (0xb36b, &[0xb5, 0xa3], false),
+    (0xb36e, &[0xb5, 0xa4], false),
+    (0xb370, &[0xb5, 0xa5], false),
+    (0xb371, &[0xb5, 0xa6], false),
+    (0xb374, &[0xb5, 0xa7], false),
+    (0xb378, &[0xb5, 0xa8], false),
+    (0xb380, &[0xb5, 0xa9], false),
+    (0xb381, &[0xb5, 0xaa], false),
+    (0xb383, &[0xb5, 0xab], false),
+    (0xb384, &[0xb5, 0xac], false),
+    (0xb385, &[0xb5, 0xad], false),
+    (0xb38c, &[0xb5, 0xae], false),
+    (0xb390, &[0xb5, 0xaf], false),
+    (0xb394, &[0xb5, 0xb0], false),
+    (0xb3a0, &[0xb5, 0xb1], false),
+    (0xb3a1, &[0xb5, 0xb2], false),
+    (0xb3a8, &[0xb5, 0xb3], false),
+    (0xb3ac, &[0xb5, 0xb4], false),
+    (0xb3c4, &[0xb5, 0xb5], false),
+    (0xb3c5, &[0xb5, 0xb6], false),
+    (0xb3c8, &[0xb5, 0xb7], false),
+    (0xb3cb, &[0xb5, 0xb8], false),
+    (0xb3cc, &[0xb5, 0xb9], false),
+    (0xb3ce, &[0xb5, 0xba], false),
+    (0xb3d0, &[0xb5, 0xbb], false),
+    (0xb3d4, &[0xb5, 0xbc], false),
+    (0xb3d5, &[0xb5, 0xbd], false),
+    (0xb3d7, &[0xb5, 0xbe], false),
+    (0xb3d9, &[0xb5, 0xbf], false),
+    (0xb3db, &[0xb5, 0xc0], false),
+    (0xb3dd, &[0xb5, 0xc1], false),
+    (0xb3e0, &[0xb5, 0xc2], false),
+    (0xb3e4, &[0xb5, 0xc3], false),
+    (0xb3e8, &[0xb5, 0xc4], false),
+    (0xb3fc, &[0xb5, 0xc5], false),
+    (0xb410, &[0xb5, 0xc6], false),
+    (0xb418, &[0xb5, 0xc7], false),
+    (0xb41c, &[0xb5, 0xc8], false),
+    (0xb420, &[0xb5, 0xc9], false),
+    (0xb428, &[0xb5, 0xca], false),
+    (0xb429, &[0xb5, 0xcb], false),
+    (0xb42b, &[0xb5, 0xcc], false),
+    (0xb434, &[0xb5, 0xcd], false),
+    (0xb450, &[0xb5, 0xce], false),
+    (0xb451, &[0xb5, 0xcf], false),
+    (0xb454, &[0xb5, 0xd0], false),
+    (0xb458, &[0xb5, 0xd1], false),
+    (0xb460, &[0xb5, 0xd2], false),
+    (0xb461, &[0xb5, 0xd3], false),
+    (0xb463, &[0xb5, 0xd4], false),
+    (0xb465, &[0xb5, 0xd5], false),
+    (0xb46c, &[0xb5, 0xd6], false),
+    (0xb480, &[0xb5, 0xd7], false),
+    (0xb488, &[0xb5, 0xd8], false),
+    (0xb49d, &[0xb5, 0xd9], false),
+    (0xb4a4, &[0xb5, 0xda], false),
+    (0xb4a8, &[0xb5, 0xdb], false),
+    (0xb4ac, &[0xb5, 0xdc], false),
+    (0xb4b5, &[0xb5, 0xdd], false),
+    (0xb4b7, &[0xb5, 0xde], false),
+    (0xb4b9, &[0xb5, 0xdf], false),
+    (0xb4c0, &[0xb5, 0xe0], false),
+    (0xb4c4, &[0xb5, 0xe1], false),
+    (0xb4c8, &[0xb5, 0xe2], false),
+    (0xb4d0, &[0xb5, 0xe3], false),
+    (0xb4d5, &[0xb5, 0xe4], false),
+    (0xb4dc, &[0xb5, 0xe5], false),
+    (0xb4dd, &[0xb5, 0xe6], false),
+    (0xb4e0, &[0xb5, 0xe7], false),
+    (0xb4e3, &[0xb5, 0xe8], false),
+    (0xb4e4, &[0xb5, 0xe9], false),
+    (0xb4e6, &[0xb5, 0xea], false),
+    (0xb4ec, &[0xb5, 0xeb], false),
+    (0xb4ed, &[0xb5, 0xec], false),
+    (0xb4ef, &[0xb5, 0xed], false),
+    (0xb4f1, &[0xb5, 0xee], false),
+    (0xb4f8, &[0xb5, 0xef], false),
+    (0xb514, &[0xb5, 0xf0], false),
+    (0xb515, &[0xb5, 0xf1], false),
+    (0xb518, &[0xb5, 0xf2], false),
+    (0xb51b, &[0xb5, 0xf3], false),
+    (0xb51c, &[0xb5, 0xf4], false),
+    (0xb524, &[0xb5, 0xf5], false),
+    (0xb525, &[0xb5, 0xf6], false),
+    (0xb527, &[0xb5, 0xf7], false),
+    (0xb528, &[0xb5, 0xf8], false),
+    (0xb529, &[0xb5, 0xf9], false),
+    (0xb52a, &[0xb5, 0xfa], false),
+    (0xb530, &[0xb5, 0xfb], false),
+    (0xb531, &[0xb5, 0xfc], false),
+    (0xb534, &[0xb5, 0xfd], false),
+    (0xb538, &[0xb5, 0xfe], false),
+    (0xd105, &[0xb6, 0x41], false),
+    (0xd106, &[0xb6, 0x42], false),
+    (0xd107, &[0xb6, 0x43], false),
+    (0xd108, &[0xb6, 0x44], false),
+    (0xd109, &[0xb6, 0x45], false),
+    (0xd10a, &[0xb6, 0x46], false),
+    (0xd10b, &[0xb6, 0x47], false),
+    (0xd10c, &[0xb6, 0x48], false),
+    (0xd10e, &[0xb6, 0x49], false),
+    (0xd10f, &[0xb6, 0x4a], false),
+    (0xd110, &[0xb6, 0x4b], false),
+    (0xd111, &[0xb6, 0x4c], false),
+    (0xd112, &[0xb6, 0x4d], false),
+    (0xd113, &[0xb6, 0x4e], false),
+    (0xd114, &[0xb6, 0x4f], false),
+    (0xd115, &[0xb6, 0x50], false),
+    (0xd116, &[0xb6, 0x51], false),
+    (0xd117, &[0xb6, 0x52], false),
+    (0xd118, &[0xb6, 0x53], false),
+    (0xd119, &[0xb6, 0x54], false),
+    (0xd11a, &[0xb6, 0x55], false),
+    (0xd11b, &[0xb6, 0x56], false),
+    (0xd11c, &[0xb6, 0x57], false),
+    (0xd11d, &[0xb6, 0x58], false),
+    (0xd11e, &[0xb6, 0x59], false),
+    (0xd11f, &[0xb6, 0x5a], false),
+    (0xd120, &[0xb6, 0x61], false),
+    (0xd121, &[0xb6, 0x62], false),
+    (0xd122, &[0xb6, 0x63], false),
+    (0xd123, &[0xb6, 0x64], false),
+    (0xd124, &[0xb6, 0x65], false),
+    (0xd125, &[0xb6, 0x66], false),
+    (0xd126, &[0xb6, 0x67], false),
+    (0xd127, &[0xb6, 0x68], false),
+    (0xd128, &[0xb6, 0x69], false),
+    (0xd129, &[0xb6, 0x6a], false),
+    (0xd12a, &[0xb6, 0x6b], false),
+    (0xd12b, &[0xb6, 0x6c], false),
+    (0xd12c, &[0xb6, 0x6d], false),
+    (0xd12d, &[0xb6, 0x6e], false),
+    (0xd12e, &[0xb6, 0x6f], false),
+    (0xd12f, &[0xb6, 0x70], false),
+    (0xd132, &[0xb6, 0x71], false),
+    (0xd133, &[0xb6, 0x72], false),
+    (0xd135, &[0xb6, 0x73], false),
+    (0xd136, &[0xb6, 0x74], false),
+    (0xd137, &[0xb6, 0x75], false),
+    (0xd139, &[0xb6, 0x76], false),
+    (0xd13b, &[0xb6, 0x77], false),
+    (0xd13c, &[0xb6, 0x78], false),
+    (0xd13d, &[0xb6, 0x79], false),
+    (0xd13e, &[0xb6, 0x7a], false),
+    (0xd13f, &[0xb6, 0x81], false),
+    (0xd142, &[0xb6, 0x82], false),
+    (0xd146, &[0xb6, 0x83], false),
+    (0xd147, &[0xb6, 0x84], false),
+    (0xd148, &[0xb6, 0x85], false),
+    (0xd149, &[0xb6, 0x86], false),
+    (0xd14a, &[0xb6, 0x87], false),
+    (0xd14b, &[0xb6, 0x88], false),
+    (0xd14e, &[0xb6, 0x89], false),
+    (0xd14f, &[0xb6, 0x8a], false),
+    (0xd151, &[0xb6, 0x8b], false),
+    (0xd152, &[0xb6, 0x8c], false),
+    (0xd153, &[0xb6, 0x8d], false),
+    (0xd155, &[0xb6, 0x8e], false),
+    (0xd156, &[0xb6, 0x8f], false),
+    (0xd157, &[0xb6, 0x90], false),
+    (0xd158, &[0xb6, 0x91], false),
+    (0xd159, &[0xb6, 0x92], false),
+    (0xd15a, &[0xb6, 0x93], false),
+    (0xd15b, &[0xb6, 0x94], false),
+    (0xd15e, &[0xb6, 0x95], false),
+    (0xd160, &[0xb6, 0x96], false),
+    (0xd162, &[0xb6, 0x97], false),
+    (0xd163, &[0xb6, 0x98], false),
+    (0xd164, &[0xb6, 0x99], false),
+    (0xd165, &[0xb6, 0x9a], false),
+    (0xd166, &[0xb6, 0x9b], false),
+    (0xd167, &[0xb6, 0x9c], false),
+    (0xd169, &[0xb6, 0x9d], false),
+    (0xd16a, &[0xb6, 0x9e], false),
+    (0xd16b, &[0xb6, 0x9f], false),
+    (0xd16d, &[0xb6, 0xa0], false),
+    (0xb540, &[0xb6, 0xa1], false),
+    (0xb541, &[0xb6, 0xa2], false),
+    (0xb543, &[0xb6, 0xa3], false),
+    (0xb544, &[0xb6, 0xa4], false),
+    (0xb545, &[0xb6, 0xa5], false),
+    (0xb54b, &[0xb6, 0xa6], false),
+    (0xb54c, &[0xb6, 0xa7], false),
+    (0xb54d, &[0xb6, 0xa8], false),
+    (0xb550, &[0xb6, 0xa9], false),
+    (0xb554, &[0xb6, 0xaa], false),
+    (0xb55c, &[0xb6, 0xab], false),
+    (0xb55d, &[0xb6, 0xac], false),
+    (0xb55f, &[0xb6, 0xad], false),
+    (0xb560, &[0xb6, 0xae], false),
+    (0xb561, &[0xb6, 0xaf], false),
+    (0xb5a0, &[0xb6, 0xb0], false),
+    (0xb5a1, &[0xb6, 0xb1], false),
+    (0xb5a4, &[0xb6, 0xb2], false),
+    (0xb5a8, &[0xb6, 0xb3], false),
+    (0xb5aa, &[0xb6, 0xb4], false),
+    (0xb5ab, &[0xb6, 0xb5], false),
+    (0xb5b0, &[0xb6, 0xb6], false),
+    (0xb5b1, &[0xb6, 0xb7], false),
+    (0xb5b3, &[0xb6, 0xb8], false),
+    (0xb5b4, &[0xb6, 0xb9], false),
+    (0xb5b5, &[0xb6, 0xba], false),
+    (0xb5bb, &[0xb6, 0xbb], false),
+    (0xb5bc, &[0xb6, 0xbc], false),
+    (0xb5bd, &[0xb6, 0xbd], false),
+    (0xb5c0, &[0xb6, 0xbe], false),
+    (0xb5c4, &[0xb6, 0xbf], false),
+    (0xb5cc, &[0xb6, 0xc0], false),
+    (0xb5cd, &[0xb6, 0xc1], false),
+    (0xb5cf, &[0xb6, 0xc2], false),
+    (0xb5d0, &[0xb6, 0xc3], false),
+    (0xb5d1, &[0xb6, 0xc4], false),
+    (0xb5d8, &[0xb6, 0xc5], false),
+    (0xb5ec, &[0xb6, 0xc6], false),
+    (0xb610, &[0xb6, 0xc7], false),
+    (0xb611, &[0xb6, 0xc8], false),
+    (0xb614, &[0xb6, 0xc9], false),
+    (0xb618, &[0xb6, 0xca], false),
+    (0xb625, &[0xb6, 0xcb], false),
+    (0xb62c, &[0xb6, 0xcc], false),
+    (0xb634, &[0xb6, 0xcd], false),
+    (0xb648, &[0xb6, 0xce], false),
+    (0xb664, &[0xb6, 0xcf], false),
+    (0xb668, &[0xb6, 0xd0], false),
+    (0xb69c, &[0xb6, 0xd1], false),
+    (0xb69d, &[0xb6, 0xd2], false),
+    (0xb6a0, &[0xb6, 0xd3], false),
+    (0xb6a4, &[0xb6, 0xd4], false),
+    (0xb6ab, &[0xb6, 0xd5], false),
+    (0xb6ac, &[0xb6, 0xd6], false),
+    (0xb6b1, &[0xb6, 0xd7], false),
+    (0xb6d4, &[0xb6, 0xd8], false),
+    (0xb6f0, &[0xb6, 0xd9], false),
+    (0xb6f4, &[0xb6, 0xda], false),
+    (0xb6f8, &[0xb6, 0xdb], false),
+    (0xb700, &[0xb6, 0xdc], false),
+    (0xb701, &[0xb6, 0xdd], false),
+    (0xb705, &[0xb6, 0xde], false),
+    (0xb728, &[0xb6, 0xdf], false),
+    (0xb729, &[0xb6, 0xe0], false),
+    (0xb72c, &[0xb6, 0xe1], false),
+    (0xb72f, &[0xb6, 0xe2], false),
+    (0xb730, &[0xb6, 0xe3], false),
+    (0xb738, &[0xb6, 0xe4], false),
+    (0xb739, &[0xb6, 0xe5], false),
+    (0xb73b, &[0xb6, 0xe6], false),
+    (0xb744, &[0xb6, 0xe7], false),
+    (0xb748, &[0xb6, 0xe8], false),
+    (0xb74c, &[0xb6, 0xe9], false),
+    (0xb754, &[0xb6, 0xea], false),
+    (0xb755, &[0xb6, 0xeb], false),
+    (0xb760, &[0xb6, 0xec], false),
+    (0xb764, &[0xb6, 0xed], false),
+    (0xb768, &[0xb6, 0xee], false),
+    (0xb770, &[0xb6, 0xef], false),
+    (0xb771, &[0xb6, 0xf0], false),
+    (0xb773, &[0xb6, 0xf1], false),
+    (0xb775, &[0xb6, 0xf2], false),
+    (0xb77c, &[0xb6, 0xf3], false),
+    (0xb77d, &[0xb6, 0xf4], false),
+    (0xb780, &[0xb6, 0xf5], false),
+    (0xb784, &[0xb6, 0xf6], false),
+    (0xb78c, &[0xb6, 0xf7], false),
+    (0xb78d, &[0xb6, 0xf8], false),
+    (0xb78f, &[0xb6, 0xf9], false),
+    (0xb790, &[0xb6, 0xfa], false),
+    (0xb791, &[0xb6, 0xfb], false),
+    (0xb792, &[0xb6, 0xfc], false),
+    (0xb796, &[0xb6, 0xfd], false),
+    (0xb797, &[0xb6, 0xfe], false),
+    (0xd16e, &[0xb7, 0x41], false),
+    (0xd16f, &[0xb7, 0x42], false),
+    (0xd170, &[0xb7, 0x43], false),
+    (0xd171, &[0xb7, 0x44], false),
+    (0xd172, &[0xb7, 0x45], false),
+    (0xd173, &[0xb7, 0x46], false),
+    (0xd174, &[0xb7, 0x47], false),
+    (0xd175, &[0xb7, 0x48], false),
+    (0xd176, &[0xb7, 0x49], false),
+    (0xd177, &[0xb7, 0x4a], false),
+    (0xd178, &[0xb7, 0x4b], false),
+    (0xd179, &[0xb7, 0x4c], false),
+    (0xd17a, &[0xb7, 0x4d], false),
+    (0xd17b, &[0xb7, 0x4e], false),
+    (0xd17d, &[0xb7, 0x4f], false),
+    (0xd17e, &[0xb7, 0x50], false),
+    (0xd17f, &[0xb7, 0x51], false),
+    (0xd180, &[0xb7, 0x52], false),
+    (0xd181, &[0xb7, 0x53], false),
+    (0xd182, &[0xb7, 0x54], false),
+    (0xd183, &[0xb7, 0x55], false),
+    (0xd185, &[0xb7, 0x56], false),
+    (0xd186, &[0xb7, 0x57], false),
+    (0xd187, &[0xb7, 0x58], false),
+    (0xd189, &[0xb7, 0x59], false),
+    (0xd18a, &[0xb7, 0x5a], false),
+    (0xd18b, &[0xb7, 0x61], false),
+    (0xd18c, &[0xb7, 0x62], false),
+    (0xd18d, &[0xb7, 0x63], false),
+    (0xd18e, &[0xb7, 0x64], false),
+    (0xd18f, &[0xb7, 0x65], false),
+    (0xd190, &[0xb7, 0x66], false),
+    (0xd191, &[0xb7, 0x67], false),
+    (0xd192, &[0xb7, 0x68], false),
+    (0xd193, &[0xb7, 0x69], false),
+    (0xd194, &[0xb7, 0x6a], false),
+    (0xd195, &[0xb7, 0x6b], false),
+    (0xd196, &[0xb7, 0x6c], false),
+    (0xd197, &[0xb7, 0x6d], false),
+    (0xd198, &[0xb7, 0x6e], false),
+    (0xd199, &[0xb7, 0x6f], false),
+    (0xd19a, &[0xb7, 0x70], false),
+    (0xd19b, &[0xb7, 0x71], false),
+    (0xd19c, &[0xb7, 0x72], false),
+    (0xd19d, &[0xb7, 0x73], false),
+    (0xd19e, &[0xb7, 0x74], false),
+    (0xd19f, &[0xb7, 0x75], false),
+    (0xd1a2, &[0xb7, 0x76], false),
+    (0xd1a3, &[0xb7, 0x77], false),
+    (0xd1a5, &[0xb7, 0x78], false),
+    (0xd1a6, &[0xb7, 0x79], false),
+    (0xd1a7, &[0xb7, 0x7a], false),
+    (0xd1a9, &[0xb7, 0x81], false),
+    (0xd1aa, &[0xb7, 0x82], false),
+    (0xd1ab, &[0xb7, 0x83], false),
+    (0xd1ac, &[0xb7, 0x84], false),
+    (0xd1ad, &[0xb7, 0x85], false),
+    (0xd1ae, &[0xb7, 0x86], false),
+    (0xd1af, &[0xb7, 0x87], false),
+    (0xd1b2, &[0xb7, 0x88], false),
+    (0xd1b4, &[0xb7, 0x89], false),
+    (0xd1b6, &[0xb7, 0x8a], false),
+    (0xd1b7, &[0xb7, 0x8b], false),
+    (0xd1b8, &[0xb7, 0x8c], false),
+    (0xd1b9, &[0xb7, 0x8d], false),
+    (0xd1bb, &[0xb7, 0x8e], false),
+    (0xd1bd, &[0xb7, 0x8f], false),
+    (0xd1be, &[0xb7, 0x90], false),
+    (0xd1bf, &[0xb7, 0x91], false),
+    (0xd1c1, &[0xb7, 0x92], false),
+    (0xd1c2, &[0xb7, 0x93], false),
+    (0xd1c3, &[0xb7, 0x94], false),
+    (0xd1c4, &[0xb7, 0x95], false),
+    (0xd1c5, &[0xb7, 0x96], false),
+    (0xd1c6, &[0xb7, 0x97], false),
+    (0xd1c7, &[0xb7, 0x98], false),
+    (0xd1c8, &[0xb7, 0x99], false),
+    (0xd1c9, &[0xb7, 0x9a], false),
+    (0xd1ca, &[0xb7, 0x9b], false),
+    (0xd1cb, &[0xb7, 0x9c], false),
+    (0xd1cc, &[0xb7, 0x9d], false),
+    (0xd1cd, &[0xb7, 0x9e], false),
+    (0xd1ce, &[0xb7, 0x9f], false),
+    (0xd1cf, &[0xb7, 0xa0], false),
+    (0xb798, &[0xb7, 0xa1], false),
+    (0xb799, &[0xb7, 0xa2], false),
+    (0xb79c, &[0xb7, 0xa3], false),
+    (0xb7a0, &[0xb7, 0xa4], false),
+    (0xb7a8, &[0xb7, 0xa5], false),
+    (0xb7a9, &[0xb7, 0xa6], false),
+    (0xb7ab, &[0xb7, 0xa7], false),
+    (0xb7ac, &[0xb7, 0xa8], false),
+    (0xb7ad, &[0xb7, 0xa9], false),
+    (0xb7b4, &[0xb7, 0xaa], false),
+    (0xb7b5, &[0xb7, 0xab], false),
+    (0xb7b8, &[0xb7, 0xac], false),
+    (0xb7c7, &[0xb7, 0xad], false),
+    (0xb7c9, &[0xb7, 0xae], false),
+    (0xb7ec, &[0xb7, 0xaf], false),
+    (0xb7ed, &[0xb7, 0xb0], false),
+    (0xb7f0, &[0xb7, 0xb1], false),
+    (0xb7f4, &[0xb7, 0xb2], false),
+    (0xb7fc, &[0xb7, 0xb3], false),
+    (0xb7fd, &[0xb7, 0xb4], false),
+    (0xb7ff, &[0xb7, 0xb5], false),
+    (0xb800, &[0xb7, 0xb6], false),
+    (0xb801, &[0xb7, 0xb7], false),
+    (0xb807, &[0xb7, 0xb8], false),
+    (0xb808, &[0xb7, 0xb9], false),
+    (0xb809, &[0xb7, 0xba], false),
+    (0xb80c, &[0xb7, 0xbb], false),
+    (0xb810, &[0xb7, 0xbc], false),
+    (0xb818, &[0xb7, 0xbd], false),
+    (0xb819, &[0xb7, 0xbe], false),
+    (0xb81b, &[0xb7, 0xbf], false),
+    (0xb81d, &[0xb7, 0xc0], false),
+    (0xb824, &[0xb7, 0xc1], false),
+    (0xb825, &[0xb7, 0xc2], false),
+    (0xb828, &[0xb7, 0xc3], false),
+    (0xb82c, &[0xb7, 0xc4], false),
+    (0xb834, &[0xb7, 0xc5], false),
+    (0xb835, &[0xb7, 0xc6], false),
+    (0xb837, &[0xb7, 0xc7], false),
+    (0xb838, &[0xb7, 0xc8], false),
+    (0xb839, &[0xb7, 0xc9], false),
+    (0xb840, &[0xb7, 0xca], false),
+    (0xb844, &[0xb7, 0xcb], false),
+    (0xb851, &[0xb7, 0xcc], false),
+    (0xb853, &[0xb7, 0xcd], false),
+    (0xb85c, &[0xb7, 0xce], false),
+    (0xb85d, &[0xb7, 0xcf], false),
+    (0xb860, &[0xb7, 0xd0], false),
+    (0xb864, &[0xb7, 0xd1], false),
+    (0xb86c, &[0xb7, 0xd2], false),
+    (0xb86d, &[0xb7, 0xd3], false),
+    (0xb86f, &[0xb7, 0xd4], false),
+    (0xb871, &[0xb7, 0xd5], false),
+    (0xb878, &[0xb7, 0xd6], false),
+    (0xb87c, &[0xb7, 0xd7], false),
+    (0xb88d, &[0xb7, 0xd8], false),
+    (0xb8a8, &[0xb7, 0xd9], false),
+    (0xb8b0, &[0xb7, 0xda], false),
+    (0xb8b4, &[0xb7, 0xdb], false),
+    (0xb8b8, &[0xb7, 0xdc], false),
+    (0xb8c0, &[0xb7, 0xdd], false),
+    (0xb8c1, &[0xb7, 0xde], false),
+    (0xb8c3, &[0xb7, 0xdf], false),
+    (0xb8c5, &[0xb7, 0xe0], false),
+    (0xb8cc, &[0xb7, 0xe1], false),
+    (0xb8d0, &[0xb7, 0xe2], false),
+    (0xb8d4, &[0xb7, 0xe3], false),
+    (0xb8dd, &[0xb7, 0xe4], false),
+    (0xb8df, &[0xb7, 0xe5], false),
+    (0xb8e1, &[0xb7, 0xe6], false),
+    (0xb8e8, &[0xb7, 0xe7], false),
+    (0xb8e9, &[0xb7, 0xe8], false),
+    (0xb8ec, &[0xb7, 0xe9], false),
+    (0xb8f0, &[0xb7, 0xea], false),
+    (0xb8f8, &[0xb7, 0xeb], false),
+    (0xb8f9, &[0xb7, 0xec], false),
+    (0xb8fb, &[0xb7, 0xed], false),
+    (0xb8fd, &[0xb7, 0xee], false),
+    (0xb904, &[0xb7, 0xef], false),
+    (0xb918, &[0xb7, 0xf0], false),
+    (0xb920, &[0xb7, 0xf1], false),
+    (0xb93c, &[0xb7, 0xf2], false),
+    (0xb93d, &[0xb7, 0xf3], false),
+    (0xb940, &[0xb7, 0xf4], false),
+    (0xb944, &[0xb7, 0xf5], false),
+    (0xb94c, &[0xb7, 0xf6], false),
+    (0xb94f, &[0xb7, 0xf7], false),
+    (0xb951, &[0xb7, 0xf8], false),
+    (0xb958, &[0xb7, 0xf9], false),
+    (0xb959, &[0xb7, 0xfa], false),
+    (0xb95c, &[0xb7, 0xfb], false),
+    (0xb960, &[0xb7, 0xfc], false),
+    (0xb968, &[0xb7, 0xfd], false),
+    (0xb969, &[0xb7, 0xfe], false),
+    (0xd1d0, &[0xb8, 0x41], false),
+    (0xd1d1, &[0xb8, 0x42], false),
+    (0xd1d2, &[0xb8, 0x43], false),
+    (0xd1d3, &[0xb8, 0x44], false),
+    (0xd1d4, &[0xb8, 0x45], false),
+    (0xd1d5, &[0xb8, 0x46], false),
+    (0xd1d6, &[0xb8, 0x47], false),
+    (0xd1d7, &[0xb8, 0x48], false),
+    (0xd1d9, &[0xb8, 0x49], false),
+    (0xd1da, &[0xb8, 0x4a], false),
+    (0xd1db, &[0xb8, 0x4b], false),
+    (0xd1dc, &[0xb8, 0x4c], false),
+    (0xd1dd, &[0xb8, 0x4d], false),
+    (0xd1de, &[0xb8, 0x4e], false),
+    (0xd1df, &[0xb8, 0x4f], false),
+    (0xd1e0, &[0xb8, 0x50], false),
+    (0xd1e1, &[0xb8, 0x51], false),
+    (0xd1e2, &[0xb8, 0x52], false),
+    (0xd1e3, &[0xb8, 0x53], false),
+    (0xd1e4, &[0xb8, 0x54], false),
+    (0xd1e5, &[0xb8, 0x55], false),
+    (0xd1e6, &[0xb8, 0x56], false),
+    (0xd1e7, &[0xb8, 0x57], false),
+    (0xd1e8, &[0xb8, 0x58], false),
+    (0xd1e9, &[0xb8, 0x59], false),
+    (0xd1ea, &[0xb8, 0x5a], false),
+    (0xd1eb, &[0xb8, 0x61], false),
+    (0xd1ec, &[0xb8, 0x62], false),
+    (0xd1ed, &[0xb8, 0x63], false),
+    (0xd1ee, &[0xb8, 0x64], false),
+    (0xd1ef, &[0xb8, 0x65], false),
+    (0xd1f0, &[0xb8, 0x66], false),
+    (0xd1f1, &[0xb8, 0x67], false),
+    (0xd1f2, &[0xb8, 0x68], false),
+    (0xd1f3, &[0xb8, 0x69], false),
+    (0xd1f5, &[0xb8, 0x6a], false),
+    (0xd1f6, &[0xb8, 0x6b], false),
+    (0xd1f7, &[0xb8, 0x6c], false),
+    (0xd1f9, &[0xb8, 0x6d], false),
+    (0xd1fa, &[0xb8, 0x6e], false),
+    (0xd1fb, &[0xb8, 0x6f], false),
+    (0xd1fc, &[0xb8, 0x70], false),
+    (0xd1fd, &[0xb8, 0x71], false),
+    (0xd1fe, &[0xb8, 0x72], false),
+    (0xd1ff, &[0xb8, 0x73], false),
+    (0xd200, &[0xb8, 0x74], false),
+    (0xd201, &[0xb8, 0x75], false),
+    (0xd202, &[0xb8, 0x76], false),
+    (0xd203, &[0xb8, 0x77], false),
+    (0xd204, &[0xb8, 0x78], false),
+    (0xd205, &[0xb8, 0x79], false),
+    (0xd206, &[0xb8, 0x7a], false),
+    (0xd208, &[0xb8, 0x81], false),
+    (0xd20a, &[0xb8, 0x82], false),
+    (0xd20b, &[0xb8, 0x83], false),
+    (0xd20c, &[0xb8, 0x84], false),
+    (0xd20d, &[0xb8, 0x85], false),
+    (0xd20e, &[0xb8, 0x86], false),
+    (0xd20f, &[0xb8, 0x87], false),
+    (0xd211, &[0xb8, 0x88], false),
+    (0xd212, &[0xb8, 0x89], false),
+    (0xd213, &[0xb8, 0x8a], false),
+    (0xd214, &[0xb8, 0x8b], false),
+    (0xd215, &[0xb8, 0x8c], false),
+    (0xd216, &[0xb8, 0x8d], false),
+    (0xd217, &[0xb8, 0x8e], false),
+    (0xd218, &[0xb8, 0x8f], false),
+    (0xd219, &[0xb8, 0x90], false),
+    (0xd21a, &[0xb8, 0x91], false),
+    (0xd21b, &[0xb8, 0x92], false),
+    (0xd21c, &[0xb8, 0x93], false),
+    (0xd21d, &[0xb8, 0x94], false),
+    (0xd21e, &[0xb8, 0x95], false),
+    (0xd21f, &[0xb8, 0x96], false),
+    (0xd220, &[0xb8, 0x97], false),
+    (0xd221, &[0xb8, 0x98], false),
+    (0xd222, &[0xb8, 0x99], false),
+    (0xd223, &[0xb8, 0x9a], false),
+    (0xd224, &[0xb8, 0x9b], false),
+    (0xd225, &[0xb8, 0x9c], false),
+    (0xd226, &[0xb8, 0x9d], false),
+    (0xd227, &[0xb8, 0x9e], false),
+    (0xd228, &[0xb8, 0x9f], false),
+    (0xd229, &[0xb8, 0xa0], false),
+    (0xb96b, &[0xb8, 0xa1], false),
+    (0xb96d, &[0xb8, 0xa2], false),
+    (0xb974, &[0xb8, 0xa3], false),
+    (0xb975, &[0xb8, 0xa4], false),
+    (0xb978, &[0xb8, 0xa5], false),
+    (0xb97c, &[0xb8, 0xa6], false),
+    (0xb984, &[0xb8, 0xa7], false),
+    (0xb985, &[0xb8, 0xa8], false),
+    (0xb987, &[0xb8, 0xa9], false),
+    (0xb989, &[0xb8, 0xaa], false),
+    (0xb98a, &[0xb8, 0xab], false),
+    (0xb98d, &[0xb8, 0xac], false),
+    (0xb98e, &[0xb8, 0xad], false),
+    (0xb9ac, &[0xb8, 0xae], false),
+    (0xb9ad, &[0xb8, 0xaf], false),
+    (0xb9b0, &[0xb8, 0xb0], false),
+    (0xb9b4, &[0xb8, 0xb1], false),
+    (0xb9bc, &[0xb8, 0xb2], false),
+    (0xb9bd, &[0xb8, 0xb3], false),
+    (0xb9bf, &[0xb8, 0xb4], false),
+    (0xb9c1, &[0xb8, 0xb5], false),
+    (0xb9c8, &[0xb8, 0xb6], false),
+    (0xb9c9, &[0xb8, 0xb7], false),
+    (0xb9cc, &[0xb8, 0xb8], false),
+    (0xb9ce, &[0xb8, 0xb9], false),
+    (0xb9cf, &[0xb8, 0xba], false),
+    (0xb9d0, &[0xb8, 0xbb], false),
+    (0xb9d1, &[0xb8, 0xbc], false),
+    (0xb9d2, &[0xb8, 0xbd], false),
+    (0xb9d8, &[0xb8, 0xbe], false),
+    (0xb9d9, &[0xb8, 0xbf], false),
+    (0xb9db, &[0xb8, 0xc0], false),
+    (0xb9dd, &[0xb8, 0xc1], false),
+    (0xb9de, &[0xb8, 0xc2], false),
+    (0xb9e1, &[0xb8, 0xc3], false),
+    (0xb9e3, &[0xb8, 0xc4], false),
+    (0xb9e4, &[0xb8, 0xc5], false),
+    (0xb9e5, &[0xb8, 0xc6], false),
+    (0xb9e8, &[0xb8, 0xc7], false),
+    (0xb9ec, &[0xb8, 0xc8], false),
+    (0xb9f4, &[0xb8, 0xc9], false),
+    (0xb9f5, &[0xb8, 0xca], false),
+    (0xb9f7, &[0xb8, 0xcb], false),
+    (0xb9f8, &[0xb8, 0xcc], false),
+    (0xb9f9, &[0xb8, 0xcd], false),
+    (0xb9fa, &[0xb8, 0xce], false),
+    (0xba00, &[0xb8, 0xcf], false),
+    (0xba01, &[0xb8, 0xd0], false),
+    (0xba08, &[0xb8, 0xd1], false),
+    (0xba15, &[0xb8, 0xd2], false),
+    (0xba38, &[0xb8, 0xd3], false),
+    (0xba39, &[0xb8, 0xd4], false),
+    (0xba3c, &[0xb8, 0xd5], false),
+    (0xba40, &[0xb8, 0xd6], false),
+    (0xba42, &[0xb8, 0xd7], false),
+    (0xba48, &[0xb8, 0xd8], false),
+    (0xba49, &[0xb8, 0xd9], false),
+    (0xba4b, &[0xb8, 0xda], false),
+    (0xba4d, &[0xb8, 0xdb], false),
+    (0xba4e, &[0xb8, 0xdc], false),
+    (0xba53, &[0xb8, 0xdd], false),
+    (0xba54, &[0xb8, 0xde], false),
+    (0xba55, &[0xb8, 0xdf], false),
+    (0xba58, &[0xb8, 0xe0], false),
+    (0xba5c, &[0xb8, 0xe1], false),
+    (0xba64, &[0xb8, 0xe2], false),
+    (0xba65, &[0xb8, 0xe3], false),
+    (0xba67, &[0xb8, 0xe4], false),
+    (0xba68, &[0xb8, 0xe5], false),
+    (0xba69, &[0xb8, 0xe6], false),
+    (0xba70, &[0xb8, 0xe7], false),
+    (0xba71, &[0xb8, 0xe8], false),
+    (0xba74, &[0xb8, 0xe9], false),
+    (0xba78, &[0xb8, 0xea], false),
+    (0xba83, &[0xb8, 0xeb], false),
+    (0xba84, &[0xb8, 0xec], false),
+    (0xba85, &[0xb8, 0xed], false),
+    (0xba87, &[0xb8, 0xee], false),
+    (0xba8c, &[0xb8, 0xef], false),
+    (0xbaa8, &[0xb8, 0xf0], false),
+    (0xbaa9, &[0xb8, 0xf1], false),
+    (0xbaab, &[0xb8, 0xf2], false),
+    (0xbaac, &[0xb8, 0xf3], false),
+    (0xbab0, &[0xb8, 0xf4], false),
+    (0xbab2, &[0xb8, 0xf5], false),
+    (0xbab8, &[0xb8, 0xf6], false),
+    (0xbab9, &[0xb8, 0xf7], false),
+    (0xbabb, &[0xb8, 0xf8], false),
+    (0xbabd, &[0xb8, 0xf9], false),
+    (0xbac4, &[0xb8, 0xfa], false),
+    (0xbac8, &[0xb8, 0xfb], false),
+    (0xbad8, &[0xb8, 0xfc], false),
+    (0xbad9, &[0xb8, 0xfd], false),
+    (0xbafc, &[0xb8, 0xfe], false),
+    (0xd22a, &[0xb9, 0x41], false),
+    (0xd22b, &[0xb9, 0x42], false),
+    (0xd22e, &[0xb9, 0x43], false),
+    (0xd22f, &[0xb9, 0x44], false),
+    (0xd231, &[0xb9, 0x45], false),
+    (0xd232, &[0xb9, 0x46], false),
+    (0xd233, &[0xb9, 0x47], false),
+    (0xd235, &[0xb9, 0x48], false),
+    (0xd236, &[0xb9, 0x49], false),
+    (0xd237, &[0xb9, 0x4a], false),
+    (0xd238, &[0xb9, 0x4b], false),
+    (0xd239, &[0xb9, 0x4c], false),
+    (0xd23a, &[0xb9, 0x4d], false),
+    (0xd23b, &[0xb9, 0x4e], false),
+    (0xd23e, &[0xb9, 0x4f], false),
+    (0xd240, &[0xb9, 0x50], false),
+    (0xd242, &[0xb9, 0x51], false),
+    (0xd243, &[0xb9, 0x52], false),
+    (0xd244, &[0xb9, 0x53], false),
+    (0xd245, &[0xb9, 0x54], false),
+    (0xd246, &[0xb9, 0x55], false),
+    (0xd247, &[0xb9, 0x56], false),
+    (0xd249, &[0xb9, 0x57], false),
+    (0xd24a, &[0xb9, 0x58], false),
+    (0xd24b, &[0xb9, 0x59], false),
+    (0xd24c, &[0xb9, 0x5a], false),
+    (0xd24d, &[0xb9, 0x61], false),
+    (0xd24e, &[0xb9, 0x62], false),
+    (0xd24f, &[0xb9, 0x63], false),
+    (0xd250, &[0xb9, 0x64], false),
+    (0xd251, &[0xb9, 0x65], false),
+    (0xd252, &[0xb9, 0x66], false),
+    (0xd253, &[0xb9, 0x67], false),
+    (0xd254, &[0xb9, 0x68], false),
+    (0xd255, &[0xb9, 0x69], false),
+    (0xd256, &[0xb9, 0x6a], false),
+    (0xd257, &[0xb9, 0x6b], false),
+    (0xd258, &[0xb9, 0x6c], false),
+    (0xd259, &[0xb9, 0x6d], false),
+    (0xd25a, &[0xb9, 0x6e], false),
+    (0xd25b, &[0xb9, 0x6f], false),
+    (0xd25d, &[0xb9, 0x70], false),
+    (0xd25e, &[0xb9, 0x71], false),
+    (0xd25f, &[0xb9, 0x72], false),
+    (0xd260, &[0xb9, 0x73], false),
+    (0xd261, &[0xb9, 0x74], false),
+    (0xd262, &[0xb9, 0x75], false),
+    (0xd263, &[0xb9, 0x76], false),
+    (0xd265, &[0xb9, 0x77], false),
+    (0xd266, &[0xb9, 0x78], false),
+    (0xd267, &[0xb9, 0x79], false),
+    (0xd268, &[0xb9, 0x7a], false),
+    (0xd269, &[0xb9, 0x81], false),
+    (0xd26a, &[0xb9, 0x82], false),
+    (0xd26b, &[0xb9, 0x83], false),
+    (0xd26c, &[0xb9, 0x84], false),
+    (0xd26d, &[0xb9, 0x85], false),
+    (0xd26e, &[0xb9, 0x86], false),
+    (0xd26f, &[0xb9, 0x87], false),
+    (0xd270, &[0xb9, 0x88], false),
+    (0xd271, &[0xb9, 0x89], false),
+    (0xd272, &[0xb9, 0x8a], false),
+    (0xd273, &[0xb9, 0x8b], false),
+    (0xd274, &[0xb9, 0x8c], false),
+    (0xd275, &[0xb9, 0x8d], false),
+    (0xd276, &[0xb9, 0x8e], false),
+    (0xd277, &[0xb9, 0x8f], false),
+    (0xd278, &[0xb9, 0x90], false),
+    (0xd279, &[0xb9, 0x91], false),
+    (0xd27a, &[0xb9, 0x92], false),
+    (0xd27b, &[0xb9, 0x93], false),
+    (0xd27c, &[0xb9, 0x94], false),
+    (0xd27d, &[0xb9, 0x95], false),
+    (0xd27e, &[0xb9, 0x96], false),
+    (0xd27f, &[0xb9, 0x97], false),
+    (0xd282, &[0xb9, 0x98], false),
+    (0xd283, &[0xb9, 0x99], false),
+    (0xd285, &[0xb9, 0x9a], false),
+    (0xd286, &[0xb9, 0x9b], false),
+    (0xd287, &[0xb9, 0x9c], false),
+    (0xd289, &[0xb9, 0x9d], false),
+    (0xd28a, &[0xb9, 0x9e], false),
+    (0xd28b, &[0xb9, 0x9f], false),
+    (0xd28c, &[0xb9, 0xa0], false),
+    (0xbb00, &[0xb9, 0xa1], false),
+    (0xbb04, &[0xb9, 0xa2], false),
+    (0xbb0d, &[0xb9, 0xa3], false),
+    (0xbb0f, &[0xb9, 0xa4], false),
+    (0xbb11, &[0xb9, 0xa5], false),
+    (0xbb18, &[0xb9, 0xa6], false),
+    (0xbb1c, &[0xb9, 0xa7], false),
+    (0xbb20, &[0xb9, 0xa8], false),
+    (0xbb29, &[0xb9, 0xa9], false),
+    (0xbb2b, &[0xb9, 0xaa], false),
+    (0xbb34, &[0xb9, 0xab], false),
+    (0xbb35, &[0xb9, 0xac], false),
+    (0xbb36, &[0xb9, 0xad], false),
+    (0xbb38, &[0xb9, 0xae], false),
+    (0xbb3b, &[0xb9, 0xaf], false),
+    (0xbb3c, &[0xb9, 0xb0], false),
+    (0xbb3d, &[0xb9, 0xb1], false),
+    (0xbb3e, &[0xb9, 0xb2], false),
+    (0xbb44, &[0xb9, 0xb3], false),
+    (0xbb45, &[0xb9, 0xb4], false),
+    (0xbb47, &[0xb9, 0xb5], false),
+    (0xbb49, &[0xb9, 0xb6], false),
+    (0xbb4d, &[0xb9, 0xb7], false),
+    (0xbb4f, &[0xb9, 0xb8], false),
+    (0xbb50, &[0xb9, 0xb9], false),
+    (0xbb54, &[0xb9, 0xba], false),
+    (0xbb58, &[0xb9, 0xbb], false),
+    (0xbb61, &[0xb9, 0xbc], false),
+    (0xbb63, &[0xb9, 0xbd], false),
+    (0xbb6c, &[0xb9, 0xbe], false),
+    (0xbb88, &[0xb9, 0xbf], false),
+    (0xbb8c, &[0xb9, 0xc0], false),
+    (0xbb90, &[0xb9, 0xc1], false),
+    (0xbba4, &[0xb9, 0xc2], false),
+    (0xbba8, &[0xb9, 0xc3], false),
+    (0xbbac, &[0xb9, 0xc4], false),
+    (0xbbb4, &[0xb9, 0xc5], false),
+    (0xbbb7, &[0xb9, 0xc6], false),
+    (0xbbc0, &[0xb9, 0xc7], false),
+    (0xbbc4, &[0xb9, 0xc8], false),
+    (0xbbc8, &[0xb9, 0xc9], false),
+    (0xbbd0, &[0xb9, 0xca], false),
+    (0xbbd3, &[0xb9, 0xcb], false),
+    (0xbbf8, &[0xb9, 0xcc], false),
+    (0xbbf9, &[0xb9, 0xcd], false),
+    (0xbbfc, &[0xb9, 0xce], false),
+    (0xbbff, &[0xb9, 0xcf], false),
+    (0xbc00, &[0xb9, 0xd0], false),
+    (0xbc02, &[0xb9, 0xd1], false),
+    (0xbc08, &[0xb9, 0xd2], false),
+    (0xbc09, &[0xb9, 0xd3], false),
+    (0xbc0b, &[0xb9, 0xd4], false),
+    (0xbc0c, &[0xb9, 0xd5], false),
+    (0xbc0d, &[0xb9, 0xd6], false),
+    (0xbc0f, &[0xb9, 0xd7], false),
+    (0xbc11, &[0xb9, 0xd8], false),
+    (0xbc14, &[0xb9, 0xd9], false),
+    (0xbc15, &[0xb9, 0xda], false),
+    (0xbc16, &[0xb9, 0xdb], false),
+    (0xbc17, &[0xb9, 0xdc], false),
+    (0xbc18, &[0xb9, 0xdd], false),
+    (0xbc1b, &[0xb9, 0xde], false),
+    (0xbc1c, &[0xb9, 0xdf], false),
+    (0xbc1d, &[0xb9, 0xe0], false),
+    (0xbc1e, &[0xb9, 0xe1], false),
+    (0xbc1f, &[0xb9, 0xe2], false),
+    (0xbc24, &[0xb9, 0xe3], false),
+    (0xbc25, &[0xb9, 0xe4], false),
+    (0xbc27, &[0xb9, 0xe5], false),
+    (0xbc29, &[0xb9, 0xe6], false),
+    (0xbc2d, &[0xb9, 0xe7], false),
+    (0xbc30, &[0xb9, 0xe8], false),
+    (0xbc31, &[0xb9, 0xe9], false),
+    (0xbc34, &[0xb9, 0xea], false),
+    (0xbc38, &[0xb9, 0xeb], false),
+    (0xbc40, &[0xb9, 0xec], false),
+    (0xbc41, &[0xb9, 0xed], false),
+    (0xbc43, &[0xb9, 0xee], false),
+    (0xbc44, &[0xb9, 0xef], false),
+    (0xbc45, &[0xb9, 0xf0], false),
+    (0xbc49, &[0xb9, 0xf1], false),
+    (0xbc4c, &[0xb9, 0xf2], false),
+    (0xbc4d, &[0xb9, 0xf3], false),
+    (0xbc50, &[0xb9, 0xf4], false),
+    (0xbc5d, &[0xb9, 0xf5], false),
+    (0xbc84, &[0xb9, 0xf6], false),
+    (0xbc85, &[0xb9, 0xf7], false),
+    (0xbc88, &[0xb9, 0xf8], false),
+    (0xbc8b, &[0xb9, 0xf9], false),
+    (0xbc8c, &[0xb9, 0xfa], false),
+    (0xbc8e, &[0xb9, 0xfb], false),
+    (0xbc94, &[0xb9, 0xfc], false),
+    (0xbc95, &[0xb9, 0xfd], false),
+    (0xbc97, &[0xb9, 0xfe], false),
+    (0xd28d, &[0xba, 0x41], false),
+    (0xd28e, &[0xba, 0x42], false),
+    (0xd28f, &[0xba, 0x43], false),
+    (0xd292, &[0xba, 0x44], false),
+    (0xd293, &[0xba, 0x45], false),
+    (0xd294, &[0xba, 0x46], false),
+    (0xd296, &[0xba, 0x47], false),
+    (0xd297, &[0xba, 0x48], false),
+    (0xd298, &[0xba, 0x49], false),
+    (0xd299, &[0xba, 0x4a], false),
+    (0xd29a, &[0xba, 0x4b], false),
+    (0xd29b, &[0xba, 0x4c], false),
+    (0xd29d, &[0xba, 0x4d], false),
+    (0xd29e, &[0xba, 0x4e], false),
+    (0xd29f, &[0xba, 0x4f], false),
+    (0xd2a1, &[0xba, 0x50], false),
+    (0xd2a2, &[0xba, 0x51], false),
+    (0xd2a3, &[0xba, 0x52], false),
+    (0xd2a5, &[0xba, 0x53], false),
+    (0xd2a6, &[0xba, 0x54], false),
+    (0xd2a7, &[0xba, 0x55], false),
+    (0xd2a8, &[0xba, 0x56], false),
+    (0xd2a9, &[0xba, 0x57], false),
+    (0xd2aa, &[0xba, 0x58], false),
+    (0xd2ab, &[0xba, 0x59], false),
+    (0xd2ad, &[0xba, 0x5a], false),
+    (0xd2ae, &[0xba, 0x61], false),
+    (0xd2af, &[0xba, 0x62], false),
+    (0xd2b0, &[0xba, 0x63], false),
+    (0xd2b2, &[0xba, 0x64], false),
+    (0xd2b3, &[0xba, 0x65], false),
+    (0xd2b4, &[0xba, 0x66], false),
+    (0xd2b5, &[0xba, 0x67], false),
+    (0xd2b6, &[0xba, 0x68], false),
+    (0xd2b7, &[0xba, 0x69], false),
+    (0xd2ba, &[0xba, 0x6a], false),
+    (0xd2bb, &[0xba, 0x6b], false),
+    (0xd2bd, &[0xba, 0x6c], false),
+    (0xd2be, &[0xba, 0x6d], false),
+    (0xd2c1, &[0xba, 0x6e], false),
+    (0xd2c3, &[0xba, 0x6f], false),
+    (0xd2c4, &[0xba, 0x70], false),
+    (0xd2c5, &[0xba, 0x71], false),
+    (0xd2c6, &[0xba, 0x72], false),
+    (0xd2c7, &[0xba, 0x73], false),
+    (0xd2ca, &[0xba, 0x74], false),
+    (0xd2cc, &[0xba, 0x75], false),
+    (0xd2cd, &[0xba, 0x76], false),
+    (0xd2ce, &[0xba, 0x77], false),
+    (0xd2cf, &[0xba, 0x78], false),
+    (0xd2d0, &[0xba, 0x79], false),
+    (0xd2d1, &[0xba, 0x7a], false),
+    (0xd2d2, &[0xba, 0x81], false),
+    (0xd2d3, &[0xba, 0x82], false),
+    (0xd2d5, &[0xba, 0x83], false),
+    (0xd2d6, &[0xba, 0x84], false),
+    (0xd2d7, &[0xba, 0x85], false),
+    (0xd2d9, &[0xba, 0x86], false),
+    (0xd2da, &[0xba, 0x87], false),
+    (0xd2db, &[0xba, 0x88], false),
+    (0xd2dd, &[0xba, 0x89], false),
+    (0xd2de, &[0xba, 0x8a], false),
+    (0xd2df, &[0xba, 0x8b], false),
+    (0xd2e0, &[0xba, 0x8c], false),
+    (0xd2e1, &[0xba, 0x8d], false),
+    (0xd2e2, &[0xba, 0x8e], false),
+    (0xd2e3, &[0xba, 0x8f], false),
+    (0xd2e6, &[0xba, 0x90], false),
+    (0xd2e7, &[0xba, 0x91], false),
+    (0xd2e8, &[0xba, 0x92], false),
+    (0xd2e9, &[0xba, 0x93], false),
+    (0xd2ea, &[0xba, 0x94], false),
+    (0xd2eb, &[0xba, 0x95], false),
+    (0xd2ec, &[0xba, 0x96], false),
+    (0xd2ed, &[0xba, 0x97], false),
+    (0xd2ee, &[0xba, 0x98], false),
+    (0xd2ef, &[0xba, 0x99], false),
+    (0xd2f2, &[0xba, 0x9a], false),
+    (0xd2f3, &[0xba, 0x9b], false),
+    (0xd2f5, &[0xba, 0x9c], false),
+    (0xd2f6, &[0xba, 0x9d], false),
+    (0xd2f7, &[0xba, 0x9e], false),
+    (0xd2f9, &[0xba, 0x9f], false),
+    (0xd2fa, &[0xba, 0xa0], false),
+    (0xbc99, &[0xba, 0xa1], false),
+    (0xbc9a, &[0xba, 0xa2], false),
+    (0xbca0, &[0xba, 0xa3], false),
+    (0xbca1, &[0xba, 0xa4], false),
+    (0xbca4, &[0xba, 0xa5], false),
+    (0xbca7, &[0xba, 0xa6], false),
+    (0xbca8, &[0xba, 0xa7], false),
+    (0xbcb0, &[0xba, 0xa8], false),
+    (0xbcb1, &[0xba, 0xa9], false),
+    (0xbcb3, &[0xba, 0xaa], false),
+    (0xbcb4, &[0xba, 0xab], false),
+    (0xbcb5, &[0xba, 0xac], false),
+    (0xbcbc, &[0xba, 0xad], false),
+    (0xbcbd, &[0xba, 0xae], false),
+    (0xbcc0, &[0xba, 0xaf], false),
+    (0xbcc4, &[0xba, 0xb0], false),
+    (0xbccd, &[0xba, 0xb1], false),
+    (0xbccf, &[0xba, 0xb2], false),
+    (0xbcd0, &[0xba, 0xb3], false),
+    (0xbcd1, &[0xba, 0xb4], false),
+    (0xbcd5, &[0xba, 0xb5], false),
+    (0xbcd8, &[0xba, 0xb6], false),
+    (0xbcdc, &[0xba, 0xb7], false),
+    (0xbcf4, &[0xba, 0xb8], false),
+    (0xbcf5, &[0xba, 0xb9], false),
+    (0xbcf6, &[0xba, 0xba], false),
+    (0xbcf8, &[0xba, 0xbb], false),
+    (0xbcfc, &[0xba, 0xbc], false),
+    (0xbd04, &[0xba, 0xbd], false),
+    (0xbd05, &[0xba, 0xbe], false),
+    (0xbd07, &[0xba, 0xbf], false),
+    (0xbd09, &[0xba, 0xc0], false),
+    (0xbd10, &[0xba, 0xc1], false),
+    (0xbd14, &[0xba, 0xc2], false),
+    (0xbd24, &[0xba, 0xc3], false),
+    (0xbd2c, &[0xba, 0xc4], false),
+    (0xbd40, &[0xba, 0xc5], false),
+    (0xbd48, &[0xba, 0xc6], false),
+    (0xbd49, &[0xba, 0xc7], false),
+    (0xbd4c, &[0xba, 0xc8], false),
+    (0xbd50, &[0xba, 0xc9], false),
+    (0xbd58, &[0xba, 0xca], false),
+    (0xbd59, &[0xba, 0xcb], false),
+    (0xbd64, &[0xba, 0xcc], false),
+    (0xbd68, &[0xba, 0xcd], false),
+    (0xbd80, &[0xba, 0xce], false),
+    (0xbd81, &[0xba, 0xcf], false),
+    (0xbd84, &[0xba, 0xd0], false),
+    (0xbd87, &[0xba, 0xd1], false),
+    (0xbd88, &[0xba, 0xd2], false),
+    (0xbd89, &[0xba, 0xd3], false),
+    (0xbd8a, &[0xba, 0xd4], false),
+    (0xbd90, &[0xba, 0xd5], false),
+    (0xbd91, &[0xba, 0xd6], false),
+    (0xbd93, &[0xba, 0xd7], false),
+    (0xbd95, &[0xba, 0xd8], false),
+    (0xbd99, &[0xba, 0xd9], false),
+    (0xbd9a, &[0xba, 0xda], false),
+    (0xbd9c, &[0xba, 0xdb], false),
+    (0xbda4, &[0xba, 0xdc], false),
+    (0xbdb0, &[0xba, 0xdd], false),
+    (0xbdb8, &[0xba, 0xde], false),
+    (0xbdd4, &[0xba, 0xdf], false),
+    (0xbdd5, &[0xba, 0xe0], false),
+    (0xbdd8, &[0xba, 0xe1], false),
+    (0xbddc, &[0xba, 0xe2], false),
+    (0xbde9, &[0xba, 0xe3], false),
+    (0xbdf0, &[0xba, 0xe4], false),
+    (0xbdf4, &[0xba, 0xe5], false),
+    (0xbdf8, &[0xba, 0xe6], false),
+    (0xbe00, &[0xba, 0xe7], false),
+    (0xbe03, &[0xba, 0xe8], false),
+    (0xbe05, &[0xba, 0xe9], false),
+    (0xbe0c, &[0xba, 0xea], false),
+    (0xbe0d, &[0xba, 0xeb], false),
+    (0xbe10, &[0xba, 0xec], false),
+    (0xbe14, &[0xba, 0xed], false),
+    (0xbe1c, &[0xba, 0xee], false),
+    (0xbe1d, &[0xba, 0xef], false),
+    (0xbe1f, &[0xba, 0xf0], false),
+    (0xbe44, &[0xba, 0xf1], false),
+    (0xbe45, &[0xba, 0xf2], false),
+    (0xbe48, &[0xba, 0xf3], false),
+    (0xbe4c, &[0xba, 0xf4], false),
+    (0xbe4e, &[0xba, 0xf5], false),
+    (0xbe54, &[0xba, 0xf6], false),
+    (0xbe55, &[0xba, 0xf7], false),
+    (0xbe57, &[0xba, 0xf8], false),
+    (0xbe59, &[0xba, 0xf9], false),
+    (0xbe5a, &[0xba, 0xfa], false),
+    (0xbe5b, &[0xba, 0xfb], false),
+    (0xbe60, &[0xba, 0xfc], false),
+    (0xbe61, &[0xba, 0xfd], false),
+    (0xbe64, &[0xba, 0xfe], false),
+    (0xd2fb, &[0xbb, 0x41], false),
+    (0xd2fc, &[0xbb, 0x42], false),
+    (0xd2fd, &[0xbb, 0x43], false),
+    (0xd2fe, &[0xbb, 0x44], false),
+    (0xd2ff, &[0xbb, 0x45], false),
+    (0xd302, &[0xbb, 0x46], false),
+    (0xd304, &[0xbb, 0x47], false),
+    (0xd306, &[0xbb, 0x48], false),
+    (0xd307, &[0xbb, 0x49], false),
+    (0xd308, &[0xbb, 0x4a], false),
+    (0xd309, &[0xbb, 0x4b], false),
+    (0xd30a, &[0xbb, 0x4c], false),
+    (0xd30b, &[0xbb, 0x4d], false),
+    (0xd30f, &[0xbb, 0x4e], false),
+    (0xd311, &[0xbb, 0x4f], false),
+    (0xd312, &[0xbb, 0x50], false),
+    (0xd313, &[0xbb, 0x51], false),
+    (0xd315, &[0xbb, 0x52], false),
+    (0xd317, &[0xbb, 0x53], false),
+    (0xd318, &[0xbb, 0x54], false),
+    (0xd319, &[0xbb, 0x55], false),
+    (0xd31a, &[0xbb, 0x56], false),
+    (0xd31b, &[0xbb, 0x57], false),
+    (0xd31e, &[0xbb, 0x58], false),
+    (0xd322, &[0xbb, 0x59], false),
+    (0xd323, &[0xbb, 0x5a], false),
+    (0xd324, &[0xbb, 0x61], false),
+    (0xd326, &[0xbb, 0x62], false),
+    (0xd327, &[0xbb, 0x63], false),
+    (0xd32a, &[0xbb, 0x64], false),
+    (0xd32b, &[0xbb, 0x65], false),
+    (0xd32d, &[0xbb, 0x66], false),
+    (0xd32e, &[0xbb, 0x67], false),
+    (0xd32f, &[0xbb, 0x68], false),
+    (0xd331, &[0xbb, 0x69], false),
+    (0xd332, &[0xbb, 0x6a], false),
+    (0xd333, &[0xbb, 0x6b], false),
+    (0xd334, &[0xbb, 0x6c], false),
+    (0xd335, &[0xbb, 0x6d], false),
+    (0xd336, &[0xbb, 0x6e], false),
+    (0xd337, &[0xbb, 0x6f], false),
+    (0xd33a, &[0xbb, 0x70], false),
+    (0xd33e, &[0xbb, 0x71], false),
+    (0xd33f, &[0xbb, 0x72], false),
+    (0xd340, &[0xbb, 0x73], false),
+    (0xd341, &[0xbb, 0x74], false),
+    (0xd342, &[0xbb, 0x75], false),
+    (0xd343, &[0xbb, 0x76], false),
+    (0xd346, &[0xbb, 0x77], false),
+    (0xd347, &[0xbb, 0x78], false),
+    (0xd348, &[0xbb, 0x79], false),
+    (0xd349, &[0xbb, 0x7a], false),
+    (0xd34a, &[0xbb, 0x81], false),
+    (0xd34b, &[0xbb, 0x82], false),
+    (0xd34c, &[0xbb, 0x83], false),
+    (0xd34d, &[0xbb, 0x84], false),
+    (0xd34e, &[0xbb, 0x85], false),
+    (0xd34f, &[0xbb, 0x86], false),
+    (0xd350, &[0xbb, 0x87], false),
+    (0xd351, &[0xbb, 0x88], false),
+    (0xd352, &[0xbb, 0x89], false),
+    (0xd353, &[0xbb, 0x8a], false),
+    (0xd354, &[0xbb, 0x8b], false),
+    (0xd355, &[0xbb, 0x8c], false),
+    (0xd356, &[0xbb, 0x8d], false),
+    (0xd357, &[0xbb, 0x8e], false),
+    (0xd358, &[0xbb, 0x8f], false),
+    (0xd359, &[0xbb, 0x90], false),
+    (0xd35a, &[0xbb, 0x91], false),
+    (0xd35b, &[0xbb, 0x92], false),
+    (0xd35c, &[0xbb, 0x93], false),
+    (0xd35d, &[0xbb, 0x94], false),
+    (0xd35e, &[0xbb, 0x95], false),
+    (0xd35f, &[0xbb, 0x96], false),
+    (0xd360, &[0xbb, 0x97], false),
+    (0xd361, &[0xbb, 0x98], false),
+    (0xd362, &[0xbb, 0x99], false),
+    (0xd363, &[0xbb, 0x9a], false),
+    (0xd364, &[0xbb, 0x9b], false),
+    (0xd365, &[0xbb, 0x9c], false),
+    (0xd366, &[0xbb, 0x9d], false),
+    (0xd367, &[0xbb, 0x9e], false),
+    (0xd368, &[0xbb, 0x9f], false),
+    (0xd369, &[0xbb, 0xa0], false),
+    (0xbe68, &[0xbb, 0xa1], false),
+    (0xbe6a, &[0xbb, 0xa2], false),
+    (0xbe70, &[0xbb, 0xa3], false),
+    (0xbe71, &[0xbb, 0xa4], false),
+    (0xbe73, &[0xbb, 0xa5], false),
+    (0xbe74, &[0xbb, 0xa6], false),
+    (0xbe75, &[0xbb, 0xa7], false),
+    (0xbe7b, &[0xbb, 0xa8], false),
+    (0xbe7c, &[0xbb, 0xa9], false),
+    (0xbe7d, &[0xbb, 0xaa], false),
+    (0xbe80, &[0xbb, 0xab], false),
+    (0xbe84, &[0xbb, 0xac], false),
+    (0xbe8c, &[0xbb, 0xad], false),
+    (0xbe8d, &[0xbb, 0xae], false),
+    (0xbe8f, &[0xbb, 0xaf], false),
+    (0xbe90, &[0xbb, 0xb0], false),
+    (0xbe91, &[0xbb, 0xb1], false),
+    (0xbe98, &[0xbb, 0xb2], false),
+    (0xbe99, &[0xbb, 0xb3], false),
+    (0xbea8, &[0xbb, 0xb4], false),
+    (0xbed0, &[0xbb, 0xb5], false),
+    (0xbed1, &[0xbb, 0xb6], false),
+    (0xbed4, &[0xbb, 0xb7], false),
+    (0xbed7, &[0xbb, 0xb8], false),
+    (0xbed8, &[0xbb, 0xb9], false),
+    (0xbee0, &[0xbb, 0xba], false),
+    (0xbee3, &[0xbb, 0xbb], false),
+    (0xbee4, &[0xbb, 0xbc], false),
+    (0xbee5, &[0xbb, 0xbd], false),
+    (0xbeec, &[0xbb, 0xbe], false),
+    (0xbf01, &[0xbb, 0xbf], false),
+    (0xbf08, &[0xbb, 0xc0], false),
+    (0xbf09, &[0xbb, 0xc1], false),
+    (0xbf18, &[0xbb, 0xc2], false),
+    (0xbf19, &[0xbb, 0xc3], false),
+    (0xbf1b, &[0xbb, 0xc4], false),
+    (0xbf1c, &[0xbb, 0xc5], false),
+    (0xbf1d, &[0xbb, 0xc6], false),
+    (0xbf40, &[0xbb, 0xc7], false),
+    (0xbf41, &[0xbb, 0xc8], false),
+    (0xbf44, &[0xbb, 0xc9], false),
+    (0xbf48, &[0xbb, 0xca], false),
+    (0xbf50, &[0xbb, 0xcb], false),
+    (0xbf51, &[0xbb, 0xcc], false),
+    (0xbf55, &[0xbb, 0xcd], false),
+    (0xbf94, &[0xbb, 0xce], false),
+    (0xbfb0, &[0xbb, 0xcf], false),
+    (0xbfc5, &[0xbb, 0xd0], false),
+    (0xbfcc, &[0xbb, 0xd1], false),
+    (0xbfcd, &[0xbb, 0xd2], false),
+    (0xbfd0, &[0xbb, 0xd3], false),
+    (0xbfd4, &[0xbb, 0xd4], false),
+    (0xbfdc, &[0xbb, 0xd5], false),
+    (0xbfdf, &[0xbb, 0xd6], false),
+    (0xbfe1, &[0xbb, 0xd7], false),
+    (0xc03c, &[0xbb, 0xd8], false),
+    (0xc051, &[0xbb, 0xd9], false),
+    (0xc058, &[0xbb, 0xda], false),
+    (0xc05c, &[0xbb, 0xdb], false),
+    (0xc060, &[0xbb, 0xdc], false),
+    (0xc068, &[0xbb, 0xdd], false),
+    (0xc069, &[0xbb, 0xde], false),
+    (0xc090, &[0xbb, 0xdf], false),
+    (0xc091, &[0xbb, 0xe0], false),
+    (0xc094, &[0xbb, 0xe1], false),
+    (0xc098, &[0xbb, 0xe2], false),
+    (0xc0a0, &[0xbb, 0xe3], false),
+    (0xc0a1, &[0xbb, 0xe4], false),
+    (0xc0a3, &[0xbb, 0xe5], false),
+    (0xc0a5, &[0xbb, 0xe6], false),
+    (0xc0ac, &[0xbb, 0xe7], false),
+    (0xc0ad, &[0xbb, 0xe8], false),
+    (0xc0af, &[0xbb, 0xe9], false),
+    (0xc0b0, &[0xbb, 0xea], false),
+    (0xc0b3, &[0xbb, 0xeb], false),
+    (0xc0b4, &[0xbb, 0xec], false),
+    (0xc0b5, &[0xbb, 0xed], false),
+    (0xc0b6, &[0xbb, 0xee], false),
+    (0xc0bc, &[0xbb, 0xef], false),
+    (0xc0bd, &[0xbb, 0xf0], false),
+    (0xc0bf, &[0xbb, 0xf1], false),
+    (0xc0c0, &[0xbb, 0xf2], false),
+    (0xc0c1, &[0xbb, 0xf3], false),
+    (0xc0c5, &[0xbb, 0xf4], false),
+    (0xc0c8, &[0xbb, 0xf5], false),
+    (0xc0c9, &[0xbb, 0xf6], false),
+    (0xc0cc, &[0xbb, 0xf7], false),
+    (0xc0d0, &[0xbb, 0xf8], false),
+    (0xc0d8, &[0xbb, 0xf9], false),
+    (0xc0d9, &[0xbb, 0xfa], false),
+    (0xc0db, &[0xbb, 0xfb], false),
+    (0xc0dc, &[0xbb, 0xfc], false),
+    (0xc0dd, &[0xbb, 0xfd], false),
+    (0xc0e4, &[0xbb, 0xfe], false),
+    (0xd36a, &[0xbc, 0x41], false),
+    (0xd36b, &[0xbc, 0x42], false),
+    (0xd36c, &[0xbc, 0x43], false),
+    (0xd36d, &[0xbc, 0x44], false),
+    (0xd36e, &[0xbc, 0x45], false),
+    (0xd36f, &[0xbc, 0x46], false),
+    (0xd370, &[0xbc, 0x47], false),
+    (0xd371, &[0xbc, 0x48], false),
+    (0xd372, &[0xbc, 0x49], false),
+    (0xd373, &[0xbc, 0x4a], false),
+    (0xd374, &[0xbc, 0x4b], false),
+    (0xd375, &[0xbc, 0x4c], false),
+    (0xd376, &[0xbc, 0x4d], false),
+    (0xd377, &[0xbc, 0x4e], false),
+    (0xd378, &[0xbc, 0x4f], false),
+    (0xd379, &[0xbc, 0x50], false),
+    (0xd37a, &[0xbc, 0x51], false),
+    (0xd37b, &[0xbc, 0x52], false),
+    (0xd37e, &[0xbc, 0x53], false),
+    (0xd37f, &[0xbc, 0x54], false),
+    (0xd381, &[0xbc, 0x55], false),
+    (0xd382, &[0xbc, 0x56], false),
+    (0xd383, &[0xbc, 0x57], false),
+    (0xd385, &[0xbc, 0x58], false),
+    (0xd386, &[0xbc, 0x59], false),
+    (0xd387, &[0xbc, 0x5a], false),
+    (0xd388, &[0xbc, 0x61], false),
+    (0xd389, &[0xbc, 0x62], false),
+    (0xd38a, &[0xbc, 0x63], false),
+    (0xd38b, &[0xbc, 0x64], false),
+    (0xd38e, &[0xbc, 0x65], false),
+    (0xd392, &[0xbc, 0x66], false),
+    (0xd393, &[0xbc, 0x67], false),
+    (0xd394, &[0xbc, 0x68], false),
+    (0xd395, &[0xbc, 0x69], false),
+    (0xd396, &[0xbc, 0x6a], false),
+    (0xd397, &[0xbc, 0x6b], false),
+    (0xd39a, &[0xbc, 0x6c], false),
+    (0xd39b, &[0xbc, 0x6d], false),
+    (0xd39d, &[0xbc, 0x6e], false),
+    (0xd39e, &[0xbc, 0x6f], false),
+    (0xd39f, &[0xbc, 0x70], false),
+    (0xd3a1, &[0xbc, 0x71], false),
+    (0xd3a2, &[0xbc, 0x72], false),
+    (0xd3a3, &[0xbc, 0x73], false),
+    (0xd3a4, &[0xbc, 0x74], false),
+    (0xd3a5, &[0xbc, 0x75], false),
+    (0xd3a6, &[0xbc, 0x76], false),
+    (0xd3a7, &[0xbc, 0x77], false),
+    (0xd3aa, &[0xbc, 0x78], false),
+    (0xd3ac, &[0xbc, 0x79], false),
+    (0xd3ae, &[0xbc, 0x7a], false),
+    (0xd3af, &[0xbc, 0x81], false),
+    (0xd3b0, &[0xbc, 0x82], false),
+    (0xd3b1, &[0xbc, 0x83], false),
+    (0xd3b2, &[0xbc, 0x84], false),
+    (0xd3b3, &[0xbc, 0x85], false),
+    (0xd3b5, &[0xbc, 0x86], false),
+    (0xd3b6, &[0xbc, 0x87], false),
+    (0xd3b7, &[0xbc, 0x88], false),
+    (0xd3b9, &[0xbc, 0x89], false),
+    (0xd3ba, &[0xbc, 0x8a], false),
+    (0xd3bb, &[0xbc, 0x8b], false),
+    (0xd3bd, &[0xbc, 0x8c], false),
+    (0xd3be, &[0xbc, 0x8d], false),
+    (0xd3bf, &[0xbc, 0x8e], false),
+    (0xd3c0, &[0xbc, 0x8f], false),
+    (0xd3c1, &[0xbc, 0x90], false),
+    (0xd3c2, &[0xbc, 0x91], false),
+    (0xd3c3, &[0xbc, 0x92], false),
+    (0xd3c6, &[0xbc, 0x93], false),
+    (0xd3c7, &[0xbc, 0x94], false),
+    (0xd3ca, &[0xbc, 0x95], false),
+    (0xd3cb, &[0xbc, 0x96], false),
+    (0xd3cc, &[0xbc, 0x97], false),
+    (0xd3cd, &[0xbc, 0x98], false),
+    (0xd3ce, &[0xbc, 0x99], false),
+    (0xd3cf, &[0xbc, 0x9a], false),
+    (0xd3d1, &[0xbc, 0x9b], false),
+    (0xd3d2, &[0xbc, 0x9c], false),
+    (0xd3d3, &[0xbc, 0x9d], false),
+    (0xd3d4, &[0xbc, 0x9e], false),
+    (0xd3d5, &[0xbc, 0x9f], false),
+    (0xd3d6, &[0xbc, 0xa0], false),
+    (0xc0e5, &[0xbc, 0xa1], false),
+    (0xc0e8, &[0xbc, 0xa2], false),
+    (0xc0ec, &[0xbc, 0xa3], false),
+    (0xc0f4, &[0xbc, 0xa4], false),
+    (0xc0f5, &[0xbc, 0xa5], false),
+    (0xc0f7, &[0xbc, 0xa6], false),
+    (0xc0f9, &[0xbc, 0xa7], false),
+    (0xc100, &[0xbc, 0xa8], false),
+    (0xc104, &[0xbc, 0xa9], false),
+    (0xc108, &[0xbc, 0xaa], false),
+    (0xc110, &[0xbc, 0xab], false),
+    (0xc115, &[0xbc, 0xac], false),
+    (0xc11c, &[0xbc, 0xad], false),
+    (0xc11d, &[0xbc, 0xae], false),
+    (0xc11e, &[0xbc, 0xaf], false),
+    (0xc11f, &[0xbc, 0xb0], false),
+    (0xc120, &[0xbc, 0xb1], false),
+    (0xc123, &[0xbc, 0xb2], false),
+    (0xc124, &[0xbc, 0xb3], false),
+    (0xc126, &[0xbc, 0xb4], false),
+    (0xc127, &[0xbc, 0xb5], false),
+    (0xc12c, &[0xbc, 0xb6], false),
+    (0xc12d, &[0xbc, 0xb7], false),
+    (0xc12f, &[0xbc, 0xb8], false),
+    (0xc130, &[0xbc, 0xb9], false),
+    (0xc131, &[0xbc, 0xba], false),
+    (0xc136, &[0xbc, 0xbb], false),
+    (0xc138, &[0xbc, 0xbc], false),
+    (0xc139, &[0xbc, 0xbd], false),
+    (0xc13c, &[0xbc, 0xbe], false),
+    (0xc140, &[0xbc, 0xbf], false),
+    (0xc148, &[0xbc, 0xc0], false),
+    (0xc149, &[0xbc, 0xc1], false),
+    (0xc14b, &[0xbc, 0xc2], false),
+    (0xc14c, &[0xbc, 0xc3], false),
+    (0xc14d, &[0xbc, 0xc4], false),
+    (0xc154, &[0xbc, 0xc5], false),
+    (0xc155, &[0xbc, 0xc6], false),
+    (0xc158, &[0xbc, 0xc7], false),
+    (0xc15c, &[0xbc, 0xc8], false),
+    (0xc164, &[0xbc, 0xc9], false),
+    (0xc165, &[0xbc, 0xca], false),
+    (0xc167, &[0xbc, 0xcb], false),
+    (0xc168, &[0xbc, 0xcc], false),
+    (0xc169, &[0xbc, 0xcd], false),
+    (0xc170, &[0xbc, 0xce], false),
+    (0xc174, &[0xbc, 0xcf], false),
+    (0xc178, &[0xbc, 0xd0], false),
+    (0xc185, &[0xbc, 0xd1], false),
+    (0xc18c, &[0xbc, 0xd2], false),
+    (0xc18d, &[0xbc, 0xd3], false),
+    (0xc18e, &[0xbc, 0xd4], false),
+    (0xc190, &[0xbc, 0xd5], false),
+    (0xc194, &[0xbc, 0xd6], false),
+    (0xc196, &[0xbc, 0xd7], false),
+    (0xc19c, &[0xbc, 0xd8], false),
+    (0xc19d, &[0xbc, 0xd9], false),
+    (0xc19f, &[0xbc, 0xda], false),
+    (0xc1a1, &[0xbc, 0xdb], false),
+    (0xc1a5, &[0xbc, 0xdc], false),
+    (0xc1a8, &[0xbc, 0xdd], false),
+    (0xc1a9, &[0xbc, 0xde], false),
+    (0xc1ac, &[0xbc, 0xdf], false),
+    (0xc1b0, &[0xbc, 0xe0], false),
+    (0xc1bd, &[0xbc, 0xe1], false),
+    (0xc1c4, &[0xbc, 0xe2], false),
+    (0xc1c8, &[0xbc, 0xe3], false),
+    (0xc1cc, &[0xbc, 0xe4], false),
+    (0xc1d4, &[0xbc, 0xe5], false),
+    (0xc1d7, &[0xbc, 0xe6], false),
+    (0xc1d8, &[0xbc, 0xe7], false),
+    (0xc1e0, &[0xbc, 0xe8], false),
+    (0xc1e4, &[0xbc, 0xe9], false),
+    (0xc1e8, &[0xbc, 0xea], false),
+    (0xc1f0, &[0xbc, 0xeb], false),
+    (0xc1f1, &[0xbc, 0xec], false),
+    (0xc1f3, &[0xbc, 0xed], false),
+    (0xc1fc, &[0xbc, 0xee], false),
+    (0xc1fd, &[0xbc, 0xef], false),
+    (0xc200, &[0xbc, 0xf0], false),
+    (0xc204, &[0xbc, 0xf1], false),
+    (0xc20c, &[0xbc, 0xf2], false),
+    (0xc20d, &[0xbc, 0xf3], false),
+    (0xc20f, &[0xbc, 0xf4], false),
+    (0xc211, &[0xbc, 0xf5], false),
+    (0xc218, &[0xbc, 0xf6], false),
+    (0xc219, &[0xbc, 0xf7], false),
+    (0xc21c, &[0xbc, 0xf8], false),
+    (0xc21f, &[0xbc, 0xf9], false),
+    (0xc220, &[0xbc, 0xfa], false),
+    (0xc228, &[0xbc, 0xfb], false),
+    (0xc229, &[0xbc, 0xfc], false),
+    (0xc22b, &[0xbc, 0xfd], false),
+    (0xc22d, &[0xbc, 0xfe], false),
+    (0xd3d7, &[0xbd, 0x41], false),
+    (0xd3d9, &[0xbd, 0x42], false),
+    (0xd3da, &[0xbd, 0x43], false),
+    (0xd3db, &[0xbd, 0x44], false),
+    (0xd3dc, &[0xbd, 0x45], false),
+    (0xd3dd, &[0xbd, 0x46], false),
+    (0xd3de, &[0xbd, 0x47], false),
+    (0xd3df, &[0xbd, 0x48], false),
+    (0xd3e0, &[0xbd, 0x49], false),
+    (0xd3e2, &[0xbd, 0x4a], false),
+    (0xd3e4, &[0xbd, 0x4b], false),
+    (0xd3e5, &[0xbd, 0x4c], false),
+    (0xd3e6, &[0xbd, 0x4d], false),
+    (0xd3e7, &[0xbd, 0x4e], false),
+    (0xd3e8, &[0xbd, 0x4f], false),
+    (0xd3e9, &[0xbd, 0x50], false),
+    (0xd3ea, &[0xbd, 0x51], false),
+    (0xd3eb, &[0xbd, 0x52], false),
+    (0xd3ee, &[0xbd, 0x53], false),
+    (0xd3ef, &[0xbd, 0x54], false),
+    (0xd3f1, &[0xbd, 0x55], false),
+    (0xd3f2, &[0xbd, 0x56], false),
+    (0xd3f3, &[0xbd, 0x57], false),
+    (0xd3f5, &[0xbd, 0x58], false),
+    (0xd3f6, &[0xbd, 0x59], false),
+    (0xd3f7, &[0xbd, 0x5a], false),
+    (0xd3f8, &[0xbd, 0x61], false),
+    (0xd3f9, &[0xbd, 0x62], false),
+    (0xd3fa, &[0xbd, 0x63], false),
+    (0xd3fb, &[0xbd, 0x64], false),
+    (0xd3fe, &[0xbd, 0x65], false),
+    (0xd400, &[0xbd, 0x66], false),
+    (0xd402, &[0xbd, 0x67], false),
+    (0xd403, &[0xbd, 0x68], false),
+    (0xd404, &[0xbd, 0x69], false),
+    (0xd405, &[0xbd, 0x6a], false),
+    (0xd406, &[0xbd, 0x6b], false),
+    (0xd407, &[0xbd, 0x6c], false),
+    (0xd409, &[0xbd, 0x6d], false),
+    (0xd40a, &[0xbd, 0x6e], false),
+    (0xd40b, &[0xbd, 0x6f], false),
+    (0xd40c, &[0xbd, 0x70], false),
+    (0xd40d, &[0xbd, 0x71], false),
+    (0xd40e, &[0xbd, 0x72], false),
+    (0xd40f, &[0xbd, 0x73], false),
+    (0xd410, &[0xbd, 0x74], false),
+    (0xd411, &[0xbd, 0x75], false),
+    (0xd412, &[0xbd, 0x76], false),
+    (0xd413, &[0xbd, 0x77], false),
+    (0xd414, &[0xbd, 0x78], false),
+    (0xd415, &[0xbd, 0x79], false),
+    (0xd416, &[0xbd, 0x7a], false),
+    (0xd417, &[0xbd, 0x81], false),
+    (0xd418, &[0xbd, 0x82], false),
+    (0xd419, &[0xbd, 0x83], false),
+    (0xd41a, &[0xbd, 0x84], false),
+    (0xd41b, &[0xbd, 0x85], false),
+    (0xd41c, &[0xbd, 0x86], false),
+    (0xd41e, &[0xbd, 0x87], false),
+    (0xd41f, &[0xbd, 0x88], false),
+    (0xd420, &[0xbd, 0x89], false),
+    (0xd421, &[0xbd, 0x8a], false),
+    (0xd422, &[0xbd, 0x8b], false),
+    (0xd423, &[0xbd, 0x8c], false),
+    (0xd424, &[0xbd, 0x8d], false),
+    (0xd425, &[0xbd, 0x8e], false),
+    (0xd426, &[0xbd, 0x8f], false),
+    (0xd427, &[0xbd, 0x90], false),
+    (0xd428, &[0xbd, 0x91], false),
+    (0xd429, &[0xbd, 0x92], false),
+    (0xd42a, &[0xbd, 0x93], false),
+    (0xd42b, &[0xbd, 0x94], false),
+    (0xd42c, &[0xbd, 0x95], false),
+    (0xd42d, &[0xbd, 0x96], false),
+    (0xd42e, &[0xbd, 0x97], false),
+    (0xd42f, &[0xbd, 0x98], false),
+    (0xd430, &[0xbd, 0x99], false),
+    (0xd431, &[0xbd, 0x9a], false),
+    (0xd432, &[0xbd, 0x9b], false),
+    (0xd433, &[0xbd, 0x9c], false),
+    (0xd434, &[0xbd, 0x9d], false),
+    (0xd435, &[0xbd, 0x9e], false),
+    (0xd436, &[0xbd, 0x9f], false),
+    (0xd437, &[0xbd, 0xa0], false),
+    (0xc22f, &[0xbd, 0xa1], false),
+    (0xc231, &[0xbd, 0xa2], false),
+    (0xc232, &[0xbd, 0xa3], false),
+    (0xc234, &[0xbd, 0xa4], false),
+    (0xc248, &[0xbd, 0xa5], false),
+    (0xc250, &[0xbd, 0xa6], false),
+    (0xc251, &[0xbd, 0xa7], false),
+    (0xc254, &[0xbd, 0xa8], false),
+    (0xc258, &[0xbd, 0xa9], false),
+    (0xc260, &[0xbd, 0xaa], false),
+    (0xc265, &[0xbd, 0xab], false),
+    (0xc26c, &[0xbd, 0xac], false),
+    (0xc26d, &[0xbd, 0xad], false),
+    (0xc270, &[0xbd, 0xae], false),
+    (0xc274, &[0xbd, 0xaf], false),
+    (0xc27c, &[0xbd, 0xb0], false),
+    (0xc27d, &[0xbd, 0xb1], false),
+    (0xc27f, &[0xbd, 0xb2], false),
+    (0xc281, &[0xbd, 0xb3], false),
+    (0xc288, &[0xbd, 0xb4], false),
+    (0xc289, &[0xbd, 0xb5], false),
+    (0xc290, &[0xbd, 0xb6], false),
+    (0xc298, &[0xbd, 0xb7], false),
+    (0xc29b, &[0xbd, 0xb8], false),
+    (0xc29d, &[0xbd, 0xb9], false),
+    (0xc2a4, &[0xbd, 0xba], false),
+    (0xc2a5, &[0xbd, 0xbb], false),
+    (0xc2a8, &[0xbd, 0xbc], false),
+    (0xc2ac, &[0xbd, 0xbd], false),
+    (0xc2ad, &[0xbd, 0xbe], false),
+    (0xc2b4, &[0xbd, 0xbf], false),
+    (0xc2b5, &[0xbd, 0xc0], false),
+    (0xc2b7, &[0xbd, 0xc1], false),
+    (0xc2b9, &[0xbd, 0xc2], false),
+    (0xc2dc, &[0xbd, 0xc3], false),
+    (0xc2dd, &[0xbd, 0xc4], false),
+    (0xc2e0, &[0xbd, 0xc5], false),
+    (0xc2e3, &[0xbd, 0xc6], false),
+    (0xc2e4, &[0xbd, 0xc7], false),
+    (0xc2eb, &[0xbd, 0xc8], false),
+    (0xc2ec, &[0xbd, 0xc9], false),
+    (0xc2ed, &[0xbd, 0xca], false),
+    (0xc2ef, &[0xbd, 0xcb], false),
+    (0xc2f1, &[0xbd, 0xcc], false),
+    (0xc2f6, &[0xbd, 0xcd], false),
+    (0xc2f8, &[0xbd, 0xce], false),
+    (0xc2f9, &[0xbd, 0xcf], false),
+    (0xc2fb, &[0xbd, 0xd0], false),
+    (0xc2fc, &[0xbd, 0xd1], false),
+    (0xc300, &[0xbd, 0xd2], false),
+    (0xc308, &[0xbd, 0xd3], false),
+    (0xc309, &[0xbd, 0xd4], false),
+    (0xc30c, &[0xbd, 0xd5], false),
+    (0xc30d, &[0xbd, 0xd6], false),
+    (0xc313, &[0xbd, 0xd7], false),
+    (0xc314, &[0xbd, 0xd8], false),
+    (0xc315, &[0xbd, 0xd9], false),
+    (0xc318, &[0xbd, 0xda], false),
+    (0xc31c, &[0xbd, 0xdb], false),
+    (0xc324, &[0xbd, 0xdc], false),
+    (0xc325, &[0xbd, 0xdd], false),
+    (0xc328, &[0xbd, 0xde], false),
+    (0xc329, &[0xbd, 0xdf], false),
+    (0xc345, &[0xbd, 0xe0], false),
+    (0xc368, &[0xbd, 0xe1], false),
+    (0xc369, &[0xbd, 0xe2], false),
+    (0xc36c, &[0xbd, 0xe3], false),
+    (0xc370, &[0xbd, 0xe4], false),
+    (0xc372, &[0xbd, 0xe5], false),
+    (0xc378, &[0xbd, 0xe6], false),
+    (0xc379, &[0xbd, 0xe7], false),
+    (0xc37c, &[0xbd, 0xe8], false),
+    (0xc37d, &[0xbd, 0xe9], false),
+    (0xc384, &[0xbd, 0xea], false),
+    (0xc388, &[0xbd, 0xeb], false),
+    (0xc38c, &[0xbd, 0xec], false),
+    (0xc3c0, &[0xbd, 0xed], false),
+    (0xc3d8, &[0xbd, 0xee], false),
+    (0xc3d9, &[0xbd, 0xef], false),
+    (0xc3dc, &[0xbd, 0xf0], false),
+    (0xc3df, &[0xbd, 0xf1], false),
+    (0xc3e0, &[0xbd, 0xf2], false),
+    (0xc3e2, &[0xbd, 0xf3], false),
+    (0xc3e8, &[0xbd, 0xf4], false),
+    (0xc3e9, &[0xbd, 0xf5], false),
+    (0xc3ed, &[0xbd, 0xf6], false),
+    (0xc3f4, &[0xbd, 0xf7], false),
+    (0xc3f5, &[0xbd, 0xf8], false),
+    (0xc3f8, &[0xbd, 0xf9], false),
+    (0xc408, &[0xbd, 0xfa], false),
+    (0xc410, &[0xbd, 0xfb], false),
+    (0xc424, &[0xbd, 0xfc], false),
+    (0xc42c, &[0xbd, 0xfd], false),
+    (0xc430, &[0xbd, 0xfe], false),
+    (0xd438, &[0xbe, 0x41], false),
+    (0xd439, &[0xbe, 0x42], false),
+    (0xd43a, &[0xbe, 0x43], false),
+    (0xd43b, &[0xbe, 0x44], false),
+    (0xd43c, &[0xbe, 0x45], false),
+    (0xd43d, &[0xbe, 0x46], false),
+    (0xd43e, &[0xbe, 0x47], false),
+    (0xd43f, &[0xbe, 0x48], false),
+    (0xd441, &[0xbe, 0x49], false),
+    (0xd442, &[0xbe, 0x4a], false),
+    (0xd443, &[0xbe, 0x4b], false),
+    (0xd445, &[0xbe, 0x4c], false),
+    (0xd446, &[0xbe, 0x4d], false),
+    (0xd447, &[0xbe, 0x4e], false),
+    (0xd448, &[0xbe, 0x4f], false),
+    (0xd449, &[0xbe, 0x50], false),
+    (0xd44a, &[0xbe, 0x51], false),
+    (0xd44b, &[0xbe, 0x52], false),
+    (0xd44c, &[0xbe, 0x53], false),
+    (0xd44d, &[0xbe, 0x54], false),
+    (0xd44e, &[0xbe, 0x55], false),
+    (0xd44f, &[0xbe, 0x56], false),
+    (0xd450, &[0xbe, 0x57], false),
+    (0xd451, &[0xbe, 0x58], false),
+    (0xd452, &[0xbe, 0x59], false),
+    (0xd453, &[0xbe, 0x5a], false),
+    (0xd454, &[0xbe, 0x61], false),
+    (0xd455, &[0xbe, 0x62], false),
+    (0xd456, &[0xbe, 0x63], false),
+    (0xd457, &[0xbe, 0x64], false),
+    (0xd458, &[0xbe, 0x65], false),
+    (0xd459, &[0xbe, 0x66], false),
+    (0xd45a, &[0xbe, 0x67], false),
+    (0xd45b, &[0xbe, 0x68], false),
+    (0xd45d, &[0xbe, 0x69], false),
+    (0xd45e, &[0xbe, 0x6a], false),
+    (0xd45f, &[0xbe, 0x6b], false),
+    (0xd461, &[0xbe, 0x6c], false),
+    (0xd462, &[0xbe, 0x6d], false),
+    (0xd463, &[0xbe, 0x6e], false),
+    (0xd465, &[0xbe, 0x6f], false),
+    (0xd466, &[0xbe, 0x70], false),
+    (0xd467, &[0xbe, 0x71], false),
+    (0xd468, &[0xbe, 0x72], false),
+    (0xd469, &[0xbe, 0x73], false),
+    (0xd46a, &[0xbe, 0x74], false),
+    (0xd46b, &[0xbe, 0x75], false),
+    (0xd46c, &[0xbe, 0x76], false),
+    (0xd46e, &[0xbe, 0x77], false),
+    (0xd470, &[0xbe, 0x78], false),
+    (0xd471, &[0xbe, 0x79], false),
+    (0xd472, &[0xbe, 0x7a], false),
+    (0xd473, &[0xbe, 0x81], false),
+    (0xd474, &[0xbe, 0x82], false),
+    (0xd475, &[0xbe, 0x83], false),
+    (0xd476, &[0xbe, 0x84], false),
+    (0xd477, &[0xbe, 0x85], false),
+    (0xd47a, &[0xbe, 0x86], false),
+    (0xd47b, &[0xbe, 0x87], false),
+    (0xd47d, &[0xbe, 0x88], false),
+    (0xd47e, &[0xbe, 0x89], false),
+    (0xd481, &[0xbe, 0x8a], false),
+    (0xd483, &[0xbe, 0x8b], false),
+    (0xd484, &[0xbe, 0x8c], false),
+    (0xd485, &[0xbe, 0x8d], false),
+    (0xd486, &[0xbe, 0x8e], false),
+    (0xd487, &[0xbe, 0x8f], false),
+    (0xd48a, &[0xbe, 0x90], false),
+    (0xd48c, &[0xbe, 0x91], false),
+    (0xd48e, &[0xbe, 0x92], false),
+    (0xd48f, &[0xbe, 0x93], false),
+    (0xd490, &[0xbe, 0x94], false),
+    (0xd491, &[0xbe, 0x95], false),
+    (0xd492, &[0xbe, 0x96], false),
+    (0xd493, &[0xbe, 0x97], false),
+    (0xd495, &[0xbe, 0x98], false),
+    (0xd496, &[0xbe, 0x99], false),
+    (0xd497, &[0xbe, 0x9a], false),
+    (0xd498, &[0xbe, 0x9b], false),
+    (0xd499, &[0xbe, 0x9c], false),
+    (0xd49a, &[0xbe, 0x9d], false),
+    (0xd49b, &[0xbe, 0x9e], false),
+    (0xd49c, &[0xbe, 0x9f], false),
+    (0xd49d, &[0xbe, 0xa0], false),
+    (0xc434, &[0xbe, 0xa1], false),
+    (0xc43c, &[0xbe, 0xa2], false),
+    (0xc43d, &[0xbe, 0xa3], false),
+    (0xc448, &[0xbe, 0xa4], false),
+    (0xc464, &[0xbe, 0xa5], false),
+    (0xc465, &[0xbe, 0xa6], false),
+    (0xc468, &[0xbe, 0xa7], false),
+    (0xc46c, &[0xbe, 0xa8], false),
+    (0xc474, &[0xbe, 0xa9], false),
+    (0xc475, &[0xbe, 0xaa], false),
+    (0xc479, &[0xbe, 0xab], false),
+    (0xc480, &[0xbe, 0xac], false),
+    (0xc494, &[0xbe, 0xad], false),
+    (0xc49c, &[0xbe, 0xae], false),
+    (0xc4b8, &[0xbe, 0xaf], false),
+    (0xc4bc, &[0xbe, 0xb0], false),
+    (0xc4e9, &[0xbe, 0xb1], false),
+    (0xc4f0, &[0xbe, 0xb2], false),
+    (0xc4f1, &[0xbe, 0xb3], false),
+    (0xc4f4, &[0xbe, 0xb4], false),
+    (0xc4f8, &[0xbe, 0xb5], false),
+    (0xc4fa, &[0xbe, 0xb6], false),
+    (0xc4ff, &[0xbe, 0xb7], false),
+    (0xc500, &[0xbe, 0xb8], false),
+    (0xc501, &[0xbe, 0xb9], false),
+    (0xc50c, &[0xbe, 0xba], false),
+    (0xc510, &[0xbe, 0xbb], false),
+    (0xc514, &[0xbe, 0xbc], false),
+    (0xc51c, &[0xbe, 0xbd], false),
+    (0xc528, &[0xbe, 0xbe], false),
+    (0xc529, &[0xbe, 0xbf], false),
+    (0xc52c, &[0xbe, 0xc0], false),
+    (0xc530, &[0xbe, 0xc1], false),
+    (0xc538, &[0xbe, 0xc2], false),
+    (0xc539, &[0xbe, 0xc3], false),
+    (0xc53b, &[0xbe, 0xc4], false),
+    (0xc53d, &[0xbe, 0xc5], false),
+    (0xc544, &[0xbe, 0xc6], false),
+    (0xc545, &[0xbe, 0xc7], false),
+    (0xc548, &[0xbe, 0xc8], false),
+    (0xc549, &[0xbe, 0xc9], false),
+    (0xc54a, &[0xbe, 0xca], false),
+    (0xc54c, &[0xbe, 0xcb], false),
+    (0xc54d, &[0xbe, 0xcc], false),
+    (0xc54e, &[0xbe, 0xcd], false),
+    (0xc553, &[0xbe, 0xce], false),
+    (0xc554, &[0xbe, 0xcf], false),
+    (0xc555, &[0xbe, 0xd0], false),
+    (0xc557, &[0xbe, 0xd1], false),
+    (0xc558, &[0xbe, 0xd2], false),
+    (0xc559, &[0xbe, 0xd3], false),
+    (0xc55d, &[0xbe, 0xd4], false),
+    (0xc55e, &[0xbe, 0xd5], false),
+    (0xc560, &[0xbe, 0xd6], false),
+    (0xc561, &[0xbe, 0xd7], false),
+    (0xc564, &[0xbe, 0xd8], false),
+    (0xc568, &[0xbe, 0xd9], false),
+    (0xc570, &[0xbe, 0xda], false),
+    (0xc571, &[0xbe, 0xdb], false),
+    (0xc573, &[0xbe, 0xdc], false),
+    (0xc574, &[0xbe, 0xdd], false),
+    (0xc575, &[0xbe, 0xde], false),
+    (0xc57c, &[0xbe, 0xdf], false),
+    (0xc57d, &[0xbe, 0xe0], false),
+    (0xc580, &[0xbe, 0xe1], false),
+    (0xc584, &[0xbe, 0xe2], false),
+    (0xc587, &[0xbe, 0xe3], false),
+    (0xc58c, &[0xbe, 0xe4], false),
+    (0xc58d, &[0xbe, 0xe5], false),
+    (0xc58f, &[0xbe, 0xe6], false),
+    (0xc591, &[0xbe, 0xe7], false),
+    (0xc595, &[0xbe, 0xe8], false),
+    (0xc597, &[0xbe, 0xe9], false),
+    (0xc598, &[0xbe, 0xea], false),
+    (0xc59c, &[0xbe, 0xeb], false),
+    (0xc5a0, &[0xbe, 0xec], false),
+    (0xc5a9, &[0xbe, 0xed], false),
+    (0xc5b4, &[0xbe, 0xee], false),
+    (0xc5b5, &[0xbe, 0xef], false),
+    (0xc5b8, &[0xbe, 0xf0], false),
+    (0xc5b9, &[0xbe, 0xf1], false),
+    (0xc5bb, &[0xbe, 0xf2], false),
+    (0xc5bc, &[0xbe, 0xf3], false),
+    (0xc5bd, &[0xbe, 0xf4], false),
+    (0xc5be, &[0xbe, 0xf5], false),
+    (0xc5c4, &[0xbe, 0xf6], false),
+    (0xc5c5, &[0xbe, 0xf7], false),
+    (0xc5c6, &[0xbe, 0xf8], false),
+    (0xc5c7, &[0xbe, 0xf9], false),
+    (0xc5c8, &[0xbe, 0xfa], false),
+    (0xc5c9, &[0xbe, 0xfb], false),
+    (0xc5ca, &[0xbe, 0xfc], false),
+    (0xc5cc, &[0xbe, 0xfd], false),
+    (0xc5ce, &[0xbe, 0xfe], false),
+    (0xd49e, &[0xbf, 0x41], false),
+    (0xd49f, &[0xbf, 0x42], false),
+    (0xd4a0, &[0xbf, 0x43], false),
+    (0xd4a1, &[0xbf, 0x44], false),
+    (0xd4a2, &[0xbf, 0x45], false),
+    (0xd4a3, &[0xbf, 0x46], false),
+    (0xd4a4, &[0xbf, 0x47], false),
+    (0xd4a5, &[0xbf, 0x48], false),
+    (0xd4a6, &[0xbf, 0x49], false),
+    (0xd4a7, &[0xbf, 0x4a], false),
+    (0xd4a8, &[0xbf, 0x4b], false),
+    (0xd4aa, &[0xbf, 0x4c], false),
+    (0xd4ab, &[0xbf, 0x4d], false),
+    (0xd4ac, &[0xbf, 0x4e], false),
+    (0xd4ad, &[0xbf, 0x4f], false),
+    (0xd4ae, &[0xbf, 0x50], false),
+    (0xd4af, &[0xbf, 0x51], false),
+    (0xd4b0, &[0xbf, 0x52], false),
+    (0xd4b1, &[0xbf, 0x53], false),
+    (0xd4b2, &[0xbf, 0x54], false),
+    (0xd4b3, &[0xbf, 0x55], false),
+    (0xd4b4, &[0xbf, 0x56], false),
+    (0xd4b5, &[0xbf, 0x57], false),
+    (0xd4b6, &[0xbf, 0x58], false),
+    (0xd4b7, &[0xbf, 0x59], false),
+    (0xd4b8, &[0xbf, 0x5a], false),
+    (0xd4b9, &[0xbf, 0x61], false),
+    (0xd4ba, &[0xbf, 0x62], false),
+    (0xd4bb, &[0xbf, 0x63], false),
+    (0xd4bc, &[0xbf, 0x64], false),
+    (0xd4bd, &[0xbf, 0x65], false),
+    (0xd4be, &[0xbf, 0x66], false),
+    (0xd4bf, &[0xbf, 0x67], false),
+    (0xd4c0, &[0xbf, 0x68], false),
+    (0xd4c1, &[0xbf, 0x69], false),
+    (0xd4c2, &[0xbf, 0x6a], false),
+    (0xd4c3, &[0xbf, 0x6b], false),
+    (0xd4c4, &[0xbf, 0x6c], false),
+    (0xd4c5, &[0xbf, 0x6d], false),
+    (0xd4c6, &[0xbf, 0x6e], false),
+    (0xd4c7, &[0xbf, 0x6f], false),
+    (0xd4c8, &[0xbf, 0x70], false),
+    (0xd4c9, &[0xbf, 0x71], false),
+    (0xd4ca, &[0xbf, 0x72], false),
+    (0xd4cb, &[0xbf, 0x73], false),
+    (0xd4cd, &[0xbf, 0x74], false),
+    (0xd4ce, &[0xbf, 0x75], false),
+    (0xd4cf, &[0xbf, 0x76], false),
+    (0xd4d1, &[0xbf, 0x77], false),
+    (0xd4d2, &[0xbf, 0x78], false),
+    (0xd4d3, &[0xbf, 0x79], false),
+    (0xd4d5, &[0xbf, 0x7a], false),
+    (0xd4d6, &[0xbf, 0x81], false),
+    (0xd4d7, &[0xbf, 0x82], false),
+    (0xd4d8, &[0xbf, 0x83], false),
+    (0xd4d9, &[0xbf, 0x84], false),
+    (0xd4da, &[0xbf, 0x85], false),
+    (0xd4db, &[0xbf, 0x86], false),
+    (0xd4dd, &[0xbf, 0x87], false),
+    (0xd4de, &[0xbf, 0x88], false),
+    (0xd4e0, &[0xbf, 0x89], false),
+    (0xd4e1, &[0xbf, 0x8a], false),
+    (0xd4e2, &[0xbf, 0x8b], false),
+    (0xd4e3, &[0xbf, 0x8c], false),
+    (0xd4e4, &[0xbf, 0x8d], false),
+    (0xd4e5, &[0xbf, 0x8e], false),
+    (0xd4e6, &[0xbf, 0x8f], false),
+    (0xd4e7, &[0xbf, 0x90], false),
+    (0xd4e9, &[0xbf, 0x91], false),
+    (0xd4ea, &[0xbf, 0x92], false),
+    (0xd4eb, &[0xbf, 0x93], false),
+    (0xd4ed, &[0xbf, 0x94], false),
+    (0xd4ee, &[0xbf, 0x95], false),
+    (0xd4ef, &[0xbf, 0x96], false),
+    (0xd4f1, &[0xbf, 0x97], false),
+    (0xd4f2, &[0xbf, 0x98], false),
+    (0xd4f3, &[0xbf, 0x99], false),
+    (0xd4f4, &[0xbf, 0x9a], false),
+    (0xd4f5, &[0xbf, 0x9b], false),
+    (0xd4f6, &[0xbf, 0x9c], false),
+    (0xd4f7, &[0xbf, 0x9d], false),
+    (0xd4f9, &[0xbf, 0x9e], false),
+    (0xd4fa, &[0xbf, 0x9f], false),
+    (0xd4fc, &[0xbf, 0xa0], false),
+    (0xc5d0, &[0xbf, 0xa1], false),
+    (0xc5d1, &[0xbf, 0xa2], false),
+    (0xc5d4, &[0xbf, 0xa3], false),
+    (0xc5d8, &[0xbf, 0xa4], false),
+    (0xc5e0, &[0xbf, 0xa5], false),
+    (0xc5e1, &[0xbf, 0xa6], false),
+    (0xc5e3, &[0xbf, 0xa7], false),
+    (0xc5e5, &[0xbf, 0xa8], false),
+    (0xc5ec, &[0xbf, 0xa9], false),
+    (0xc5ed, &[0xbf, 0xaa], false),
+    (0xc5ee, &[0xbf, 0xab], false),
+    (0xc5f0, &[0xbf, 0xac], false),
+    (0xc5f4, &[0xbf, 0xad], false),
+    (0xc5f6, &[0xbf, 0xae], false),
+    (0xc5f7, &[0xbf, 0xaf], false),
+    (0xc5fc, &[0xbf, 0xb0], false),
+    (0xc5fd, &[0xbf, 0xb1], false),
+    (0xc5fe, &[0xbf, 0xb2], false),
+    (0xc5ff, &[0xbf, 0xb3], false),
+    (0xc600, &[0xbf, 0xb4], false),
+    (0xc601, &[0xbf, 0xb5], false),
+    (0xc605, &[0xbf, 0xb6], false),
+    (0xc606, &[0xbf, 0xb7], false),
+    (0xc607, &[0xbf, 0xb8], false),
+    (0xc608, &[0xbf, 0xb9], false),
+    (0xc60c, &[0xbf, 0xba], false),
+    (0xc610, &[0xbf, 0xbb], false),
+    (0xc618, &[0xbf, 0xbc], false),
+    (0xc619, &[0xbf, 0xbd], false),
+    (0xc61b, &[0xbf, 0xbe], false),
+    (0xc61c, &[0xbf, 0xbf], false),
+    (0xc624, &[0xbf, 0xc0], false),
+    (0xc625, &[0xbf, 0xc1], false),
+    (0xc628, &[0xbf, 0xc2], false),
+    (0xc62c, &[0xbf, 0xc3], false),
+    (0xc62d, &[0xbf, 0xc4], false),
+    (0xc62e, &[0xbf, 0xc5], false),
+    (0xc630, &[0xbf, 0xc6], false),
+    (0xc633, &[0xbf, 0xc7], false),
+    (0xc634, &[0xbf, 0xc8], false),
+    (0xc635, &[0xbf, 0xc9], false),
+    (0xc637, &[0xbf, 0xca], false),
+    (0xc639, &[0xbf, 0xcb], false),
+    (0xc63b, &[0xbf, 0xcc], false),
+    (0xc640, &[0xbf, 0xcd], false),
+    (0xc641, &[0xbf, 0xce], false),
+    (0xc644, &[0xbf, 0xcf], false),
+    (0xc648, &[0xbf, 0xd0], false),
+    (0xc650, &[0xbf, 0xd1], false),
+    (0xc651, &[0xbf, 0xd2], false),
+    (0xc653, &[0xbf, 0xd3], false),
+    (0xc654, &[0xbf, 0xd4], false),
+    (0xc655, &[0xbf, 0xd5], false),
+    (0xc65c, &[0xbf, 0xd6], false),
+    (0xc65d, &[0xbf, 0xd7], false),
+    (0xc660, &[0xbf, 0xd8], false),
+    (0xc66c, &[0xbf, 0xd9], false),
+    (0xc66f, &[0xbf, 0xda], false),
+    (0xc671, &[0xbf, 0xdb], false),
+    (0xc678, &[0xbf, 0xdc], false),
+    (0xc679, &[0xbf, 0xdd], false),
+    (0xc67c, &[0xbf, 0xde], false),
+    (0xc680, &[0xbf, 0xdf], false),
+    (0xc688, &[0xbf, 0xe0], false),
+    (0xc689, &[0xbf, 0xe1], false),
+    (0xc68b, &[0xbf, 0xe2], false),
+    (0xc68d, &[0xbf, 0xe3], false),
+    (0xc694, &[0xbf, 0xe4], false),
+    (0xc695, &[0xbf, 0xe5], false),
+    (0xc698, &[0xbf, 0xe6], false),
+    (0xc69c, &[0xbf, 0xe7], false),
+    (0xc6a4, &[0xbf, 0xe8], false),
+    (0xc6a5, &[0xbf, 0xe9], false),
+    (0xc6a7, &[0xbf, 0xea], false),
+    (0xc6a9, &[0xbf, 0xeb], false),
+    (0xc6b0, &[0xbf, 0xec], false),
+    (0xc6b1, &[0xbf, 0xed], false),
+    (0xc6b4, &[0xbf, 0xee], false),
+    (0xc6b8, &[0xbf, 0xef], false),
+    (0xc6b9, &[0xbf, 0xf0], false),
+    (0xc6ba, &[0xbf, 0xf1], false),
+    (0xc6c0, &[0xbf, 0xf2], false),
+    (0xc6c1, &[0xbf, 0xf3], false),
+    (0xc6c3, &[0xbf, 0xf4], false),
+    (0xc6c5, &[0xbf, 0xf5], false),
+    (0xc6cc, &[0xbf, 0xf6], false),
+    (0xc6cd, &[0xbf, 0xf7], false),
+    (0xc6d0, &[0xbf, 0xf8], false),
+    (0xc6d4, &[0xbf, 0xf9], false),
+    (0xc6dc, &[0xbf, 0xfa], false),
+    (0xc6dd, &[0xbf, 0xfb], false),
+    (0xc6e0, &[0xbf, 0xfc], false),
+    (0xc6e1, &[0xbf, 0xfd], false),
+    (0xc6e8, &[0xbf, 0xfe], false),
+    (0xd4fe, &[0xc0, 0x41], false),
+    (0xd4ff, &[0xc0, 0x42], false),
+    (0xd500, &[0xc0, 0x43], false),
+    (0xd501, &[0xc0, 0x44], false),
+    (0xd502, &[0xc0, 0x45], false),
+    (0xd503, &[0xc0, 0x46], false),
+    (0xd505, &[0xc0, 0x47], false),
+    (0xd506, &[0xc0, 0x48], false),
+    (0xd507, &[0xc0, 0x49], false),
+    (0xd509, &[0xc0, 0x4a], false),
+    (0xd50a, &[0xc0, 0x4b], false),
+    (0xd50b, &[0xc0, 0x4c], false),
+    (0xd50d, &[0xc0, 0x4d], false),
+    (0xd50e, &[0xc0, 0x4e], false),
+    (0xd50f, &[0xc0, 0x4f], false),
+    (0xd510, &[0xc0, 0x50], false),
+    (0xd511, &[0xc0, 0x51], false),
+    (0xd512, &[0xc0, 0x52], false),
+    (0xd513, &[0xc0, 0x53], false),
+    (0xd516, &[0xc0, 0x54], false),
+    (0xd518, &[0xc0, 0x55], false),
+    (0xd519, &[0xc0, 0x56], false),
+    (0xd51a, &[0xc0, 0x57], false),
+    (0xd51b, &[0xc0, 0x58], false),
+    (0xd51c, &[0xc0, 0x59], false),
+    (0xd51d, &[0xc0, 0x5a], false),
+    (0xd51e, &[0xc0, 0x61], false),
+    (0xd51f, &[0xc0, 0x62], false),
+    (0xd520, &[0xc0, 0x63], false),
+    (0xd521, &[0xc0, 0x64], false),
+    (0xd522, &[0xc0, 0x65], false),
+    (0xd523, &[0xc0, 0x66], false),
+    (0xd524, &[0xc0, 0x67], false),
+    (0xd525, &[0xc0, 0x68], false),
+    (0xd526, &[0xc0, 0x69], false),
+    (0xd527, &[0xc0, 0x6a], false),
+    (0xd528, &[0xc0, 0x6b], false),
+    (0xd529, &[0xc0, 0x6c], false),
+    (0xd52a, &[0xc0, 0x6d], false),
+    (0xd52b, &[0xc0, 0x6e], false),
+    (0xd52c, &[0xc0, 0x6f], false),
+    (0xd52d, &[0xc0, 0x70], false),
+    (0xd52e, &[0xc0, 0x71], false),
+    (0xd52f, &[0xc0, 0x72], false),
+    (0xd530, &[0xc0, 0x73], false),
+    (0xd531, &[0xc0, 0x74], false),
+    (0xd532, &[0xc0, 0x75], false),
+    (0xd533, &[0xc0, 0x76], false),
+    (0xd534, &[0xc0, 0x77], false),
+    (0xd535, &[0xc0, 0x78], false),
+    (0xd536, &[0xc0, 0x79], false),
+    (0xd537, &[0xc0, 0x7a], false),
+    (0xd538, &[0xc0, 0x81], false),
+    (0xd539, &[0xc0, 0x82], false),
+    (0xd53a, &[0xc0, 0x83], false),
+    (0xd53b, &[0xc0, 0x84], false),
+    (0xd53e, &[0xc0, 0x85], false),
+    (0xd53f, &[0xc0, 0x86], false),
+    (0xd541, &[0xc0, 0x87], false),
+    (0xd542, &[0xc0, 0x88], false),
+    (0xd543, &[0xc0, 0x89], false),
+    (0xd545, &[0xc0, 0x8a], false),
+    (0xd546, &[0xc0, 0x8b], false),
+    (0xd547, &[0xc0, 0x8c], false),
+    (0xd548, &[0xc0, 0x8d], false),
+    (0xd549, &[0xc0, 0x8e], false),
+    (0xd54a, &[0xc0, 0x8f], false),
+    (0xd54b, &[0xc0, 0x90], false),
+    (0xd54e, &[0xc0, 0x91], false),
+    (0xd550, &[0xc0, 0x92], false),
+    (0xd552, &[0xc0, 0x93], false),
+    (0xd553, &[0xc0, 0x94], false),
+    (0xd554, &[0xc0, 0x95], false),
+    (0xd555, &[0xc0, 0x96], false),
+    (0xd556, &[0xc0, 0x97], false),
+    (0xd557, &[0xc0, 0x98], false),
+    (0xd55a, &[0xc0, 0x99], false),
+    (0xd55b, &[0xc0, 0x9a], false),
+    (0xd55d, &[0xc0, 0x9b], false),
+    (0xd55e, &[0xc0, 0x9c], false),
+    (0xd55f, &[0xc0, 0x9d], false),
+    (0xd561, &[0xc0, 0x9e], false),
+    (0xd562, &[0xc0, 0x9f], false),
+    (0xd563, &[0xc0, 0xa0], false),
+    (0xc6e9, &[0xc0, 0xa1], false),
+    (0xc6ec, &[0xc0, 0xa2], false),
+    (0xc6f0, &[0xc0, 0xa3], false),
+    (0xc6f8, &[0xc0, 0xa4], false),
+    (0xc6f9, &[0xc0, 0xa5], false),
+    (0xc6fd, &[0xc0, 0xa6], false),
+    (0xc704, &[0xc0, 0xa7], false),
+    (0xc705, &[0xc0, 0xa8], false),
+    (0xc708, &[0xc0, 0xa9], false),
+    (0xc70c, &[0xc0, 0xaa], false),
+    (0xc714, &[0xc0, 0xab], false),
+    (0xc715, &[0xc0, 0xac], false),
+    (0xc717, &[0xc0, 0xad], false),
+    (0xc719, &[0xc0, 0xae], false),
+    (0xc720, &[0xc0, 0xaf], false),
+    (0xc721, &[0xc0, 0xb0], false),
+    (0xc724, &[0xc0, 0xb1], false),
+    (0xc728, &[0xc0, 0xb2], false),
+    (0xc730, &[0xc0, 0xb3], false),
+    (0xc731, &[0xc0, 0xb4], false),
+    (0xc733, &[0xc0, 0xb5], false),
+    (0xc735, &[0xc0, 0xb6], false),
+    (0xc737, &[0xc0, 0xb7], false),
+    (0xc73c, &[0xc0, 0xb8], false),
+    (0xc73d, &[0xc0, 0xb9], false),
+    (0xc740, &[0xc0, 0xba], false),
+    (0xc744, &[0xc0, 0xbb], false),
+    (0xc74a, &[0xc0, 0xbc], false),
+    (0xc74c, &[0xc0, 0xbd], false),
+    (0xc74d, &[0xc0, 0xbe], false),
+    (0xc74f, &[0xc0, 0xbf], false),
+    (0xc751, &[0xc0, 0xc0], false),
+    (0xc752, &[0xc0, 0xc1], false),
+    (0xc753, &[0xc0, 0xc2], false),
+    (0xc754, &[0xc0, 0xc3], false),
+    (0xc755, &[0xc0, 0xc4], false),
+    (0xc756, &[0xc0, 0xc5], false),
+    (0xc757, &[0xc0, 0xc6], false),
+    (0xc758, &[0xc0, 0xc7], false),
+    (0xc75c, &[0xc0, 0xc8], false),
+    (0xc760, &[0xc0, 0xc9], false),
+    (0xc768, &[0xc0, 0xca], false),
+    (0xc76b, &[0xc0, 0xcb], false),
+    (0xc774, &[0xc0, 0xcc], false),
+    (0xc775, &[0xc0, 0xcd], false),
+    (0xc778, &[0xc0, 0xce], false),
+    (0xc77c, &[0xc0, 0xcf], false),
+    (0xc77d, &[0xc0, 0xd0], false),
+    (0xc77e, &[0xc0, 0xd1], false),
+    (0xc783, &[0xc0, 0xd2], false),
+    (0xc784, &[0xc0, 0xd3], false),
+    (0xc785, &[0xc0, 0xd4], false),
+    (0xc787, &[0xc0, 0xd5], false),
+    (0xc788, &[0xc0, 0xd6], false),
+    (0xc789, &[0xc0, 0xd7], false),
+    (0xc78a, &[0xc0, 0xd8], false),
+    (0xc78e, &[0xc0, 0xd9], false),
+    (0xc790, &[0xc0, 0xda], false),
+    (0xc791, &[0xc0, 0xdb], false),
+    (0xc794, &[0xc0, 0xdc], false),
+    (0xc796, &[0xc0, 0xdd], false),
+    (0xc797, &[0xc0, 0xde], false),
+    (0xc798, &[0xc0, 0xdf], false),
+    (0xc79a, &[0xc0, 0xe0], false),
+    (0xc7a0, &[0xc0, 0xe1], false),
+    (0xc7a1, &[0xc0, 0xe2], false),
+    (0xc7a3, &[0xc0, 0xe3], false),
+    (0xc7a4, &[0xc0, 0xe4], false),
+    (0xc7a5, &[0xc0, 0xe5], false),
+    (0xc7a6, &[0xc0, 0xe6], false),
+    (0xc7ac, &[0xc0, 0xe7], false),
+    (0xc7ad, &[0xc0, 0xe8], false),
+    (0xc7b0, &[0xc0, 0xe9], false),
+    (0xc7b4, &[0xc0, 0xea], false),
+    (0xc7bc, &[0xc0, 0xeb], false),
+    (0xc7bd, &[0xc0, 0xec], false),
+    (0xc7bf, &[0xc0, 0xed], false),
+    (0xc7c0, &[0xc0, 0xee], false),
+    (0xc7c1, &[0xc0, 0xef], false),
+    (0xc7c8, &[0xc0, 0xf0], false),
+    (0xc7c9, &[0xc0, 0xf1], false),
+    (0xc7cc, &[0xc0, 0xf2], false),
+    (0xc7ce, &[0xc0, 0xf3], false),
+    (0xc7d0, &[0xc0, 0xf4], false),
+    (0xc7d8, &[0xc0, 0xf5], false),
+    (0xc7dd, &[0xc0, 0xf6], false),
+    (0xc7e4, &[0xc0, 0xf7], false),
+    (0xc7e8, &[0xc0, 0xf8], false),
+    (0xc7ec, &[0xc0, 0xf9], false),
+    (0xc800, &[0xc0, 0xfa], false),
+    (0xc801, &[0xc0, 0xfb], false),
+    (0xc804, &[0xc0, 0xfc], false),
+    (0xc808, &[0xc0, 0xfd], false),
+    (0xc80a, &[0xc0, 0xfe], false),
+    (0xd564, &[0xc1, 0x41], false),
+    (0xd566, &[0xc1, 0x42], false),
+    (0xd567, &[0xc1, 0x43], false),
+    (0xd56a, &[0xc1, 0x44], false),
+    (0xd56c, &[0xc1, 0x45], false),
+    (0xd56e, &[0xc1, 0x46], false),
+    (0xd56f, &[0xc1, 0x47], false),
+    (0xd570, &[0xc1, 0x48], false),
+    (0xd571, &[0xc1, 0x49], false),
+    (0xd572, &[0xc1, 0x4a], false),
+    (0xd573, &[0xc1, 0x4b], false),
+    (0xd576, &[0xc1, 0x4c], false),
+    (0xd577, &[0xc1, 0x4d], false),
+    (0xd579, &[0xc1, 0x4e], false),
+    (0xd57a, &[0xc1, 0x4f], false),
+    (0xd57b, &[0xc1, 0x50], false),
+    (0xd57d, &[0xc1, 0x51], false),
+    (0xd57e, &[0xc1, 0x52], false),
+    (0xd57f, &[0xc1, 0x53], false),
+    (0xd580, &[0xc1, 0x54], false),
+    (0xd581, &[0xc1, 0x55], false),
+    (0xd582, &[0xc1, 0x56], false),
+    (0xd583, &[0xc1, 0x57], false),
+    (0xd586, &[0xc1, 0x58], false),
+    (0xd58a, &[0xc1, 0x59], false),
+    (0xd58b, &[0xc1, 0x5a], false),
+    (0xd58c, &[0xc1, 0x61], false),
+    (0xd58d, &[0xc1, 0x62], false),
+    (0xd58e, &[0xc1, 0x63], false),
+    (0xd58f, &[0xc1, 0x64], false),
+    (0xd591, &[0xc1, 0x65], false),
+    (0xd592, &[0xc1, 0x66], false),
+    (0xd593, &[0xc1, 0x67], false),
+    (0xd594, &[0xc1, 0x68], false),
+    (0xd595, &[0xc1, 0x69], false),
+    (0xd596, &[0xc1, 0x6a], false),
+    (0xd597, &[0xc1, 0x6b], false),
+    (0xd598, &[0xc1, 0x6c], false),
+    (0xd599, &[0xc1, 0x6d], false),
+    (0xd59a, &[0xc1, 0x6e], false),
+    (0xd59b, &[0xc1, 0x6f], false),
+    (0xd59c, &[0xc1, 0x70], false),
+    (0xd59d, &[0xc1, 0x71], false),
+    (0xd59e, &[0xc1, 0x72], false),
+    (0xd59f, &[0xc1, 0x73], false),
+    (0xd5a0, &[0xc1, 0x74], false),
+    (0xd5a1, &[0xc1, 0x75], false),
+    (0xd5a2, &[0xc1, 0x76], false),
+    (0xd5a3, &[0xc1, 0x77], false),
+    (0xd5a4, &[0xc1, 0x78], false),
+    (0xd5a6, &[0xc1, 0x79], false),
+    (0xd5a7, &[0xc1, 0x7a], false),
+    (0xd5a8, &[0xc1, 0x81], false),
+    (0xd5a9, &[0xc1, 0x82], false),
+    (0xd5aa, &[0xc1, 0x83], false),
+    (0xd5ab, &[0xc1, 0x84], false),
+    (0xd5ac, &[0xc1, 0x85], false),
+    (0xd5ad, &[0xc1, 0x86], false),
+    (0xd5ae, &[0xc1, 0x87], false),
+    (0xd5af, &[0xc1, 0x88], false),
+    (0xd5b0, &[0xc1, 0x89], false),
+    (0xd5b1, &[0xc1, 0x8a], false),
+    (0xd5b2, &[0xc1, 0x8b], false),
+    (0xd5b3, &[0xc1, 0x8c], false),
+    (0xd5b4, &[0xc1, 0x8d], false),
+    (0xd5b5, &[0xc1, 0x8e], false),
+    (0xd5b6, &[0xc1, 0x8f], false),
+    (0xd5b7, &[0xc1, 0x90], false),
+    (0xd5b8, &[0xc1, 0x91], false),
+    (0xd5b9, &[0xc1, 0x92], false),
+    (0xd5ba, &[0xc1, 0x93], false),
+    (0xd5bb, &[0xc1, 0x94], false),
+    (0xd5bc, &[0xc1, 0x95], false),
+    (0xd5bd, &[0xc1, 0x96], false),
+    (0xd5be, &[0xc1, 0x97], false),
+    (0xd5bf, &[0xc1, 0x98], false),
+    (0xd5c0, &[0xc1, 0x99], false),
+    (0xd5c1, &[0xc1, 0x9a], false),
+    (0xd5c2, &[0xc1, 0x9b], false),
+    (0xd5c3, &[0xc1, 0x9c], false),
+    (0xd5c4, &[0xc1, 0x9d], false),
+    (0xd5c5, &[0xc1, 0x9e], false),
+    (0xd5c6, &[0xc1, 0x9f], false),
+    (0xd5c7, &[0xc1, 0xa0], false),
+    (0xc810, &[0xc1, 0xa1], false),
+    (0xc811, &[0xc1, 0xa2], false),
+    (0xc813, &[0xc1, 0xa3], false),
+    (0xc815, &[0xc1, 0xa4], false),
+    (0xc816, &[0xc1, 0xa5], false),
+    (0xc81c, &[0xc1, 0xa6], false),
+    (0xc81d, &[0xc1, 0xa7], false),
+    (0xc820, &[0xc1, 0xa8], false),
+    (0xc824, &[0xc1, 0xa9], false),
+    (0xc82c, &[0xc1, 0xaa], false),
+    (0xc82d, &[0xc1, 0xab], false),
+    (0xc82f, &[0xc1, 0xac], false),
+    (0xc831, &[0xc1, 0xad], false),
+    (0xc838, &[0xc1, 0xae], false),
+    (0xc83c, &[0xc1, 0xaf], false),
+    (0xc840, &[0xc1, 0xb0], false),
+    (0xc848, &[0xc1, 0xb1], false),
+    (0xc849, &[0xc1, 0xb2], false),
+    (0xc84c, &[0xc1, 0xb3], false),
+    (0xc84d, &[0xc1, 0xb4], false),
+    (0xc854, &[0xc1, 0xb5], false),
+    (0xc870, &[0xc1, 0xb6], false),
+    (0xc871, &[0xc1, 0xb7], false),
+    (0xc874, &[0xc1, 0xb8], false),
+    (0xc878, &[0xc1, 0xb9], false),
+    (0xc87a, &[0xc1, 0xba], false),
+    (0xc880, &[0xc1, 0xbb], false),
+    (0xc881, &[0xc1, 0xbc], false),
+    (0xc883, &[0xc1, 0xbd], false),
+    (0xc885, &[0xc1, 0xbe], false),
+    (0xc886, &[0xc1, 0xbf], false),
+    (0xc887, &[0xc1, 0xc0], false),
+    (0xc88b, &[0xc1, 0xc1], false),
+    (0xc88c, &[0xc1, 0xc2], false),
+    (0xc88d, &[0xc1, 0xc3], false),
+    (0xc894, &[0xc1, 0xc4], false),
+    (0xc89d, &[0xc1, 0xc5], false),
+    (0xc89f, &[0xc1, 0xc6], false),
+    (0xc8a1, &[0xc1, 0xc7], false),
+    (0xc8a8, &[0xc1, 0xc8], false),
+    (0xc8bc, &[0xc1, 0xc9], false),
+    (0xc8bd, &[0xc1, 0xca], false),
+    (0xc8c4, &[0xc1, 0xcb], false),
+    (0xc8c8, &[0xc1, 0xcc], false),
+    (0xc8cc, &[0xc1, 0xcd], false),
+    (0xc8d4, &[0xc1, 0xce], false),
+    (0xc8d5, &[0xc1, 0xcf], false),
+    (0xc8d7, &[0xc1, 0xd0], false),
+    (0xc8d9, &[0xc1, 0xd1], false),
+    (0xc8e0, &[0xc1, 0xd2], false),
+    (0xc8e1, &[0xc1, 0xd3], false),
+    (0xc8e4, &[0xc1, 0xd4], false),
+    (0xc8f5, &[0xc1, 0xd5], false),
+    (0xc8fc, &[0xc1, 0xd6], false),
+    (0xc8fd, &[0xc1, 0xd7], false),
+    (0xc900, &[0xc1, 0xd8], false),
+    (0xc904, &[0xc1, 0xd9], false),
+    (0xc905, &[0xc1, 0xda], false),
+    (0xc906, &[0xc1, 0xdb], false),
+    (0xc90c, &[0xc1, 0xdc], false),
+    (0xc90d, &[0xc1, 0xdd], false),
+    (0xc90f, &[0xc1, 0xde], false),
+    (0xc911, &[0xc1, 0xdf], false),
+    (0xc918, &[0xc1, 0xe0], false),
+    (0xc92c, &[0xc1, 0xe1], false),
+    (0xc934, &[0xc1, 0xe2], false),
+    (0xc950, &[0xc1, 0xe3], false),
+    (0xc951, &[0xc1, 0xe4], false),
+    (0xc954, &[0xc1, 0xe5], false),
+    (0xc958, &[0xc1, 0xe6], false),
+    (0xc960, &[0xc1, 0xe7], false),
+    (0xc961, &[0xc1, 0xe8], false),
+    (0xc963, &[0xc1, 0xe9], false),
+    (0xc96c, &[0xc1, 0xea], false),
+    (0xc970, &[0xc1, 0xeb], false),
+    (0xc974, &[0xc1, 0xec], false),
+    (0xc97c, &[0xc1, 0xed], false),
+    (0xc988, &[0xc1, 0xee], false),
+    (0xc989, &[0xc1, 0xef], false),
+    (0xc98c, &[0xc1, 0xf0], false),
+    (0xc990, &[0xc1, 0xf1], false),
+    (0xc998, &[0xc1, 0xf2], false),
+    (0xc999, &[0xc1, 0xf3], false),
+    (0xc99b, &[0xc1, 0xf4], false),
+    (0xc99d, &[0xc1, 0xf5], false),
+    (0xc9c0, &[0xc1, 0xf6], false),
+    (0xc9c1, &[0xc1, 0xf7], false),
+    (0xc9c4, &[0xc1, 0xf8], false),
+    (0xc9c7, &[0xc1, 0xf9], false),
+    (0xc9c8, &[0xc1, 0xfa], false),
+    (0xc9ca, &[0xc1, 0xfb], false),
+    (0xc9d0, &[0xc1, 0xfc], false),
+    (0xc9d1, &[0xc1, 0xfd], false),
+    (0xc9d3, &[0xc1, 0xfe], false),
+    (0xd5ca, &[0xc2, 0x41], false),
+    (0xd5cb, &[0xc2, 0x42], false),
+    (0xd5cd, &[0xc2, 0x43], false),
+    (0xd5ce, &[0xc2, 0x44], false),
+    (0xd5cf, &[0xc2, 0x45], false),
+    (0xd5d1, &[0xc2, 0x46], false),
+    (0xd5d3, &[0xc2, 0x47], false),
+    (0xd5d4, &[0xc2, 0x48], false),
+    (0xd5d5, &[0xc2, 0x49], false),
+    (0xd5d6, &[0xc2, 0x4a], false),
+    (0xd5d7, &[0xc2, 0x4b], false),
+    (0xd5da, &[0xc2, 0x4c], false),
+    (0xd5dc, &[0xc2, 0x4d], false),
+    (0xd5de, &[0xc2, 0x4e], false),
+    (0xd5df, &[0xc2, 0x4f], false),
+    (0xd5e0, &[0xc2, 0x50], false),
+    (0xd5e1, &[0xc2, 0x51], false),
+    (0xd5e2, &[0xc2, 0x52], false),
+    (0xd5e3, &[0xc2, 0x53], false),
+    (0xd5e6, &[0xc2, 0x54], false),
+    (0xd5e7, &[0xc2, 0x55], false),
+    (0xd5e9, &[0xc2, 0x56], false),
+    (0xd5ea, &[0xc2, 0x57], false),
+    (0xd5eb, &[0xc2, 0x58], false),
+    (0xd5ed, &[0xc2, 0x59], false),
+    (0xd5ee, &[0xc2, 0x5a], false),
+    (0xd5ef, &[0xc2, 0x61], false),
+    (0xd5f0, &[0xc2, 0x62], false),
+    (0xd5f1, &[0xc2, 0x63], false),
+    (0xd5f2, &[0xc2, 0x64], false),
+    (0xd5f3, &[0xc2, 0x65], false),
+    (0xd5f6, &[0xc2, 0x66], false),
+    (0xd5f8, &[0xc2, 0x67], false),
+    (0xd5fa, &[0xc2, 0x68], false),
+    (0xd5fb, &[0xc2, 0x69], false),
+    (0xd5fc, &[0xc2, 0x6a], false),
+    (0xd5fd, &[0xc2, 0x6b], false),
+    (0xd5fe, &[0xc2, 0x6c], false),
+    (0xd5ff, &[0xc2, 0x6d], false),
+    (0xd602, &[0xc2, 0x6e], false),
+    (0xd603, &[0xc2, 0x6f], false),
+    (0xd605, &[0xc2, 0x70], false),
+    (0xd606, &[0xc2, 0x71], false),
+    (0xd607, &[0xc2, 0x72], false),
+    (0xd609, &[0xc2, 0x73], false),
+    (0xd60a, &[0xc2, 0x74], false),
+    (0xd60b, &[0xc2, 0x75], false),
+    (0xd60c, &[0xc2, 0x76], false),
+    (0xd60d, &[0xc2, 0x77], false),
+    (0xd60e, &[0xc2, 0x78], false),
+    (0xd60f, &[0xc2, 0x79], false),
+    (0xd612, &[0xc2, 0x7a], false),
+    (0xd616, &[0xc2, 0x81], false),
+    (0xd617, &[0xc2, 0x82], false),
+    (0xd618, &[0xc2, 0x83], false),
+    (0xd619, &[0xc2, 0x84], false),
+    (0xd61a, &[0xc2, 0x85], false),
+    (0xd61b, &[0xc2, 0x86], false),
+    (0xd61d, &[0xc2, 0x87], false),
+    (0xd61e, &[0xc2, 0x88], false),
+    (0xd61f, &[0xc2, 0x89], false),
+    (0xd621, &[0xc2, 0x8a], false),
+    (0xd622, &[0xc2, 0x8b], false),
+    (0xd623, &[0xc2, 0x8c], false),
+    (0xd625, &[0xc2, 0x8d], false),
+    (0xd626, &[0xc2, 0x8e], false),
+    (0xd627, &[0xc2, 0x8f], false),
+    (0xd628, &[0xc2, 0x90], false),
+    (0xd629, &[0xc2, 0x91], false),
+    (0xd62a, &[0xc2, 0x92], false),
+    (0xd62b, &[0xc2, 0x93], false),
+    (0xd62c, &[0xc2, 0x94], false),
+    (0xd62e, &[0xc2, 0x95], false),
+    (0xd62f, &[0xc2, 0x96], false),
+    (0xd630, &[0xc2, 0x97], false),
+    (0xd631, &[0xc2, 0x98], false),
+    (0xd632, &[0xc2, 0x99], false),
+    (0xd633, &[0xc2, 0x9a], false),
+    (0xd634, &[0xc2, 0x9b], false),
+    (0xd635, &[0xc2, 0x9c], false),
+    (0xd636, &[0xc2, 0x9d], false),
+    (0xd637, &[0xc2, 0x9e], false),
+    (0xd63a, &[0xc2, 0x9f], false),
+    (0xd63b, &[0xc2, 0xa0], false),
+    (0xc9d5, &[0xc2, 0xa1], false),
+    (0xc9d6, &[0xc2, 0xa2], false),
+    (0xc9d9, &[0xc2, 0xa3], false),
+    (0xc9da, &[0xc2, 0xa4], false),
+    (0xc9dc, &[0xc2, 0xa5], false),
+    (0xc9dd, &[0xc2, 0xa6], false),
+    (0xc9e0, &[0xc2, 0xa7], false),
+    (0xc9e2, &[0xc2, 0xa8], false),
+    (0xc9e4, &[0xc2, 0xa9], false),
+    (0xc9e7, &[0xc2, 0xaa], false),
+    (0xc9ec, &[0xc2, 0xab], false),
+    (0xc9ed, &[0xc2, 0xac], false),
+    (0xc9ef, &[0xc2, 0xad], false),
+    (0xc9f0, &[0xc2, 0xae], false),
+    (0xc9f1, &[0xc2, 0xaf], false),
+    (0xc9f8, &[0xc2, 0xb0], false),
+    (0xc9f9, &[0xc2, 0xb1], false),
+    (0xc9fc, &[0xc2, 0xb2], false),
+    (0xca00, &[0xc2, 0xb3], false),
+    (0xca08, &[0xc2, 0xb4], false),
+    (0xca09, &[0xc2, 0xb5], false),
+    (0xca0b, &[0xc2, 0xb6], false),
+    (0xca0c, &[0xc2, 0xb7], false),
+    (0xca0d, &[0xc2, 0xb8], false),
+    (0xca14, &[0xc2, 0xb9], false),
+    (0xca18, &[0xc2, 0xba], false),
+    (0xca29, &[0xc2, 0xbb], false),
+    (0xca4c, &[0xc2, 0xbc], false),
+    (0xca4d, &[0xc2, 0xbd], false),
+    (0xca50, &[0xc2, 0xbe], false),
+    (0xca54, &[0xc2, 0xbf], false),
+    (0xca5c, &[0xc2, 0xc0], false),
+    (0xca5d, &[0xc2, 0xc1], false),
+    (0xca5f, &[0xc2, 0xc2], false),
+    (0xca60, &[0xc2, 0xc3], false),
+    (0xca61, &[0xc2, 0xc4], false),
+    (0xca68, &[0xc2, 0xc5], false),
+    (0xca7d, &[0xc2, 0xc6], false),
+    (0xca84, &[0xc2, 0xc7], false),
+    (0xca98, &[0xc2, 0xc8], false),
+    (0xcabc, &[0xc2, 0xc9], false),
+    (0xcabd, &[0xc2, 0xca], false),
+    (0xcac0, &[0xc2, 0xcb], false),
+    (0xcac4, &[0xc2, 0xcc], false),
+    (0xcacc, &[0xc2, 0xcd], false),
+    (0xcacd, &[0xc2, 0xce], false),
+    (0xcacf, &[0xc2, 0xcf], false),
+    (0xcad1, &[0xc2, 0xd0], false),
+    (0xcad3, &[0xc2, 0xd1], false),
+    (0xcad8, &[0xc2, 0xd2], false),
+    (0xcad9, &[0xc2, 0xd3], false),
+    (0xcae0, &[0xc2, 0xd4], false),
+    (0xcaec, &[0xc2, 0xd5], false),
+    (0xcaf4, &[0xc2, 0xd6], false),
+    (0xcb08, &[0xc2, 0xd7], false),
+    (0xcb10, &[0xc2, 0xd8], false),
+    (0xcb14, &[0xc2, 0xd9], false),
+    (0xcb18, &[0xc2, 0xda], false),
+    (0xcb20, &[0xc2, 0xdb], false),
+    (0xcb21, &[0xc2, 0xdc], false),
+    (0xcb41, &[0xc2, 0xdd], false),
+    (0xcb48, &[0xc2, 0xde], false),
+    (0xcb49, &[0xc2, 0xdf], false),
+    (0xcb4c, &[0xc2, 0xe0], false),
+    (0xcb50, &[0xc2, 0xe1], false),
+    (0xcb58, &[0xc2, 0xe2], false),
+    (0xcb59, &[0xc2, 0xe3], false),
+    (0xcb5d, &[0xc2, 0xe4], false),
+    (0xcb64, &[0xc2, 0xe5], false),
+    (0xcb78, &[0xc2, 0xe6], false),
+    (0xcb79, &[0xc2, 0xe7], false),
+    (0xcb9c, &[0xc2, 0xe8], false),
+    (0xcbb8, &[0xc2, 0xe9], false),
+    (0xcbd4, &[0xc2, 0xea], false),
+    (0xcbe4, &[0xc2, 0xeb], false),
+    (0xcbe7, &[0xc2, 0xec], false),
+    (0xcbe9, &[0xc2, 0xed], false),
+    (0xcc0c, &[0xc2, 0xee], false),
+    (0xcc0d, &[0xc2, 0xef], false),
+    (0xcc10, &[0xc2, 0xf0], false),
+    (0xcc14, &[0xc2, 0xf1], false),
+    (0xcc1c, &[0xc2, 0xf2], false),
+    (0xcc1d, &[0xc2, 0xf3], false),
+    (0xcc21, &[0xc2, 0xf4], false),
+    (0xcc22, &[0xc2, 0xf5], false),
+    (0xcc27, &[0xc2, 0xf6], false),
+    (0xcc28, &[0xc2, 0xf7], false),
+    (0xcc29, &[0xc2, 0xf8], false),
+    (0xcc2c, &[0xc2, 0xf9], false),
+    (0xcc2e, &[0xc2, 0xfa], false),
+    (0xcc30, &[0xc2, 0xfb], false),
+    (0xcc38, &[0xc2, 0xfc], false),
+    (0xcc39, &[0xc2, 0xfd], false),
+    (0xcc3b, &[0xc2, 0xfe], false),
+    (0xd63d, &[0xc3, 0x41], false),
+    (0xd63e, &[0xc3, 0x42], false),
+    (0xd63f, &[0xc3, 0x43], false),
+    (0xd641, &[0xc3, 0x44], false),
+    (0xd642, &[0xc3, 0x45], false),
+    (0xd643, &[0xc3, 0x46], false),
+    (0xd644, &[0xc3, 0x47], false),
+    (0xd646, &[0xc3, 0x48], false),
+    (0xd647, &[0xc3, 0x49], false),
+    (0xd64a, &[0xc3, 0x4a], false),
+    (0xd64c, &[0xc3, 0x4b], false),
+    (0xd64e, &[0xc3, 0x4c], false),
+    (0xd64f, &[0xc3, 0x4d], false),
+    (0xd650, &[0xc3, 0x4e], false),
+    (0xd652, &[0xc3, 0x4f], false),
+    (0xd653, &[0xc3, 0x50], false),
+    (0xd656, &[0xc3, 0x51], false),
+    (0xd657, &[0xc3, 0x52], false),
+    (0xd659, &[0xc3, 0x53], false),
+    (0xd65a, &[0xc3, 0x54], false),
+    (0xd65b, &[0xc3, 0x55], false),
+    (0xd65d, &[0xc3, 0x56], false),
+    (0xd65e, &[0xc3, 0x57], false),
+    (0xd65f, &[0xc3, 0x58], false),
+    (0xd660, &[0xc3, 0x59], false),
+    (0xd661, &[0xc3, 0x5a], false),
+    (0xd662, &[0xc3, 0x61], false),
+    (0xd663, &[0xc3, 0x62], false),
+    (0xd664, &[0xc3, 0x63], false),
+    (0xd665, &[0xc3, 0x64], false),
+    (0xd666, &[0xc3, 0x65], false),
+    (0xd668, &[0xc3, 0x66], false),
+    (0xd66a, &[0xc3, 0x67], false),
+    (0xd66b, &[0xc3, 0x68], false),
+    (0xd66c, &[0xc3, 0x69], false),
+    (0xd66d, &[0xc3, 0x6a], false),
+    (0xd66e, &[0xc3, 0x6b], false),
+    (0xd66f, &[0xc3, 0x6c], false),
+    (0xd672, &[0xc3, 0x6d], false),
+    (0xd673, &[0xc3, 0x6e], false),
+    (0xd675, &[0xc3, 0x6f], false),
+    (0xd676, &[0xc3, 0x70], false),
+    (0xd677, &[0xc3, 0x71], false),
+    (0xd678, &[0xc3, 0x72], false),
+    (0xd679, &[0xc3, 0x73], false),
+    (0xd67a, &[0xc3, 0x74], false),
+    (0xd67b, &[0xc3, 0x75], false),
+    (0xd67c, &[0xc3, 0x76], false),
+    (0xd67d, &[0xc3, 0x77], false),
+    (0xd67e, &[0xc3, 0x78], false),
+    (0xd67f, &[0xc3, 0x79], false),
+    (0xd680, &[0xc3, 0x7a], false),
+    (0xd681, &[0xc3, 0x81], false),
+    (0xd682, &[0xc3, 0x82], false),
+    (0xd684, &[0xc3, 0x83], false),
+    (0xd686, &[0xc3, 0x84], false),
+    (0xd687, &[0xc3, 0x85], false),
+    (0xd688, &[0xc3, 0x86], false),
+    (0xd689, &[0xc3, 0x87], false),
+    (0xd68a, &[0xc3, 0x88], false),
+    (0xd68b, &[0xc3, 0x89], false),
+    (0xd68e, &[0xc3, 0x8a], false),
+    (0xd68f, &[0xc3, 0x8b], false),
+    (0xd691, &[0xc3, 0x8c], false),
+    (0xd692, &[0xc3, 0x8d], false),
+    (0xd693, &[0xc3, 0x8e], false),
+    (0xd695, &[0xc3, 0x8f], false),
+    (0xd696, &[0xc3, 0x90], false),
+    (0xd697, &[0xc3, 0x91], false),
+    (0xd698, &[0xc3, 0x92], false),
+    (0xd699, &[0xc3, 0x93], false),
+    (0xd69a, &[0xc3, 0x94], false),
+    (0xd69b, &[0xc3, 0x95], false),
+    (0xd69c, &[0xc3, 0x96], false),
+    (0xd69e, &[0xc3, 0x97], false),
+    (0xd6a0, &[0xc3, 0x98], false),
+    (0xd6a2, &[0xc3, 0x99], false),
+    (0xd6a3, &[0xc3, 0x9a], false),
+    (0xd6a4, &[0xc3, 0x9b], false),
+    (0xd6a5, &[0xc3, 0x9c], false),
+    (0xd6a6, &[0xc3, 0x9d], false),
+    (0xd6a7, &[0xc3, 0x9e], false),
+    (0xd6a9, &[0xc3, 0x9f], false),
+    (0xd6aa, &[0xc3, 0xa0], false),
+    (0xcc3c, &[0xc3, 0xa1], false),
+    (0xcc3d, &[0xc3, 0xa2], false),
+    (0xcc3e, &[0xc3, 0xa3], false),
+    (0xcc44, &[0xc3, 0xa4], false),
+    (0xcc45, &[0xc3, 0xa5], false),
+    (0xcc48, &[0xc3, 0xa6], false),
+    (0xcc4c, &[0xc3, 0xa7], false),
+    (0xcc54, &[0xc3, 0xa8], false),
+    (0xcc55, &[0xc3, 0xa9], false),
+    (0xcc57, &[0xc3, 0xaa], false),
+    (0xcc58, &[0xc3, 0xab], false),
+    (0xcc59, &[0xc3, 0xac], false),
+    (0xcc60, &[0xc3, 0xad], false),
+    (0xcc64, &[0xc3, 0xae], false),
+    (0xcc66, &[0xc3, 0xaf], false),
+    (0xcc68, &[0xc3, 0xb0], false),
+    (0xcc70, &[0xc3, 0xb1], false),
+    (0xcc75, &[0xc3, 0xb2], false),
+    (0xcc98, &[0xc3, 0xb3], false),
+    (0xcc99, &[0xc3, 0xb4], false),
+    (0xcc9c, &[0xc3, 0xb5], false),
+    (0xcca0, &[0xc3, 0xb6], false),
+    (0xcca8, &[0xc3, 0xb7], false),
+    (0xcca9, &[0xc3, 0xb8], false),
+    (0xccab, &[0xc3, 0xb9], false),
+    (0xccac, &[0xc3, 0xba], false),
+    (0xccad, &[0xc3, 0xbb], false),
+    (0xccb4, &[0xc3, 0xbc], false),
+    (0xccb5, &[0xc3, 0xbd], false),
+    (0xccb8, &[0xc3, 0xbe], false),
+    (0xccbc, &[0xc3, 0xbf], false),
+    (0xccc4, &[0xc3, 0xc0], false),
+    (0xccc5, &[0xc3, 0xc1], false),
+    (0xccc7, &[0xc3, 0xc2], false),
+    (0xccc9, &[0xc3, 0xc3], false),
+    (0xccd0, &[0xc3, 0xc4], false),
+    (0xccd4, &[0xc3, 0xc5], false),
+    (0xcce4, &[0xc3, 0xc6], false),
+    (0xccec, &[0xc3, 0xc7], false),
+    (0xccf0, &[0xc3, 0xc8], false),
+    (0xcd01, &[0xc3, 0xc9], false),
+    (0xcd08, &[0xc3, 0xca], false),
+    (0xcd09, &[0xc3, 0xcb], false),
+    (0xcd0c, &[0xc3, 0xcc], false),
+    (0xcd10, &[0xc3, 0xcd], false),
+    (0xcd18, &[0xc3, 0xce], false),
+    (0xcd19, &[0xc3, 0xcf], false),
+    (0xcd1b, &[0xc3, 0xd0], false),
+    (0xcd1d, &[0xc3, 0xd1], false),
+    (0xcd24, &[0xc3, 0xd2], false),
+    (0xcd28, &[0xc3, 0xd3], false),
+    (0xcd2c, &[0xc3, 0xd4], false),
+    (0xcd39, &[0xc3, 0xd5], false),
+    (0xcd5c, &[0xc3, 0xd6], false),
+    (0xcd60, &[0xc3, 0xd7], false),
+    (0xcd64, &[0xc3, 0xd8], false),
+    (0xcd6c, &[0xc3, 0xd9], false),
+    (0xcd6d, &[0xc3, 0xda], false),
+    (0xcd6f, &[0xc3, 0xdb], false),
+    (0xcd71, &[0xc3, 0xdc], false),
+    (0xcd78, &[0xc3, 0xdd], false),
+    (0xcd88, &[0xc3, 0xde], false),
+    (0xcd94, &[0xc3, 0xdf], false),
+    (0xcd95, &[0xc3, 0xe0], false),
+    (0xcd98, &[0xc3, 0xe1], false),
+    (0xcd9c, &[0xc3, 0xe2], false),
+    (0xcda4, &[0xc3, 0xe3], false),
+    (0xcda5, &[0xc3, 0xe4], false),
+    (0xcda7, &[0xc3, 0xe5], false),
+    (0xcda9, &[0xc3, 0xe6], false),
+    (0xcdb0, &[0xc3, 0xe7], false),
+    (0xcdc4, &[0xc3, 0xe8], false),
+    (0xcdcc, &[0xc3, 0xe9], false),
+    (0xcdd0, &[0xc3, 0xea], false),
+    (0xcde8, &[0xc3, 0xeb], false),
+    (0xcdec, &[0xc3, 0xec], false),
+    (0xcdf0, &[0xc3, 0xed], false),
+    (0xcdf8, &[0xc3, 0xee], false),
+    (0xcdf9, &[0xc3, 0xef], false),
+    (0xcdfb, &[0xc3, 0xf0], false),
+    (0xcdfd, &[0xc3, 0xf1], false),
+    (0xce04, &[0xc3, 0xf2], false),
+    (0xce08, &[0xc3, 0xf3], false),
+    (0xce0c, &[0xc3, 0xf4], false),
+    (0xce14, &[0xc3, 0xf5], false),
+    (0xce19, &[0xc3, 0xf6], false),
+    (0xce20, &[0xc3, 0xf7], false),
+    (0xce21, &[0xc3, 0xf8], false),
+    (0xce24, &[0xc3, 0xf9], false),
+    (0xce28, &[0xc3, 0xfa], false),
+    (0xce30, &[0xc3, 0xfb], false),
+    (0xce31, &[0xc3, 0xfc], false),
+    (0xce33, &[0xc3, 0xfd], false),
+    (0xce35, &[0xc3, 0xfe], false),
+    (0xd6ab, &[0xc4, 0x41], false),
+    (0xd6ad, &[0xc4, 0x42], false),
+    (0xd6ae, &[0xc4, 0x43], false),
+    (0xd6af, &[0xc4, 0x44], false),
+    (0xd6b1, &[0xc4, 0x45], false),
+    (0xd6b2, &[0xc4, 0x46], false),
+    (0xd6b3, &[0xc4, 0x47], false),
+    (0xd6b4, &[0xc4, 0x48], false),
+    (0xd6b5, &[0xc4, 0x49], false),
+    (0xd6b6, &[0xc4, 0x4a], false),
+    (0xd6b7, &[0xc4, 0x4b], false),
+    (0xd6b8, &[0xc4, 0x4c], false),
+    (0xd6ba, &[0xc4, 0x4d], false),
+    (0xd6bc, &[0xc4, 0x4e], false),
+    (0xd6bd, &[0xc4, 0x4f], false),
+    (0xd6be, &[0xc4, 0x50], false),
+    (0xd6bf, &[0xc4, 0x51], false),
+    (0xd6c0, &[0xc4, 0x52], false),
+    (0xd6c1, &[0xc4, 0x53], false),
+    (0xd6c2, &[0xc4, 0x54], false),
+    (0xd6c3, &[0xc4, 0x55], false),
+    (0xd6c6, &[0xc4, 0x56], false),
+    (0xd6c7, &[0xc4, 0x57], false),
+    (0xd6c9, &[0xc4, 0x58], false),
+    (0xd6ca, &[0xc4, 0x59], false),
+    (0xd6cb, &[0xc4, 0x5a], false),
+    (0xd6cd, &[0xc4, 0x61], false),
+    (0xd6ce, &[0xc4, 0x62], false),
+    (0xd6cf, &[0xc4, 0x63], false),
+    (0xd6d0, &[0xc4, 0x64], false),
+    (0xd6d2, &[0xc4, 0x65], false),
+    (0xd6d3, &[0xc4, 0x66], false),
+    (0xd6d5, &[0xc4, 0x67], false),
+    (0xd6d6, &[0xc4, 0x68], false),
+    (0xd6d8, &[0xc4, 0x69], false),
+    (0xd6da, &[0xc4, 0x6a], false),
+    (0xd6db, &[0xc4, 0x6b], false),
+    (0xd6dc, &[0xc4, 0x6c], false),
+    (0xd6dd, &[0xc4, 0x6d], false),
+    (0xd6de, &[0xc4, 0x6e], false),
+    (0xd6df, &[0xc4, 0x6f], false),
+    (0xd6e1, &[0xc4, 0x70], false),
+    (0xd6e2, &[0xc4, 0x71], false),
+    (0xd6e3, &[0xc4, 0x72], false),
+    (0xd6e5, &[0xc4, 0x73], false),
+    (0xd6e6, &[0xc4, 0x74], false),
+    (0xd6e7, &[0xc4, 0x75], false),
+    (0xd6e9, &[0xc4, 0x76], false),
+    (0xd6ea, &[0xc4, 0x77], false),
+    (0xd6eb, &[0xc4, 0x78], false),
+    (0xd6ec, &[0xc4, 0x79], false),
+    (0xd6ed, &[0xc4, 0x7a], false),
+    (0xd6ee, &[0xc4, 0x81], false),
+    (0xd6ef, &[0xc4, 0x82], false),
+    (0xd6f1, &[0xc4, 0x83], false),
+    (0xd6f2, &[0xc4, 0x84], false),
+    (0xd6f3, &[0xc4, 0x85], false),
+    (0xd6f4, &[0xc4, 0x86], false),
+    (0xd6f6, &[0xc4, 0x87], false),
+    (0xd6f7, &[0xc4, 0x88], false),
+    (0xd6f8, &[0xc4, 0x89], false),
+    (0xd6f9, &[0xc4, 0x8a], false),
+    (0xd6fa, &[0xc4, 0x8b], false),
+    (0xd6fb, &[0xc4, 0x8c], false),
+    (0xd6fe, &[0xc4, 0x8d], false),
+    (0xd6ff, &[0xc4, 0x8e], false),
+    (0xd701, &[0xc4, 0x8f], false),
+    (0xd702, &[0xc4, 0x90], false),
+    (0xd703, &[0xc4, 0x91], false),
+    (0xd705, &[0xc4, 0x92], false),
+    (0xd706, &[0xc4, 0x93], false),
+    (0xd707, &[0xc4, 0x94], false),
+    (0xd708, &[0xc4, 0x95], false),
+    (0xd709, &[0xc4, 0x96], false),
+    (0xd70a, &[0xc4, 0x97], false),
+    (0xd70b, &[0xc4, 0x98], false),
+    (0xd70c, &[0xc4, 0x99], false),
+    (0xd70d, &[0xc4, 0x9a], false),
+    (0xd70e, &[0xc4, 0x9b], false),
+    (0xd70f, &[0xc4, 0x9c], false),
+    (0xd710, &[0xc4, 0x9d], false),
+    (0xd712, &[0xc4, 0x9e], false),
+    (0xd713, &[0xc4, 0x9f], false),
+    (0xd714, &[0xc4, 0xa0], false),
+    (0xce58, &[0xc4, 0xa1], false),
+    (0xce59, &[0xc4, 0xa2], false),
+    (0xce5c, &[0xc4, 0xa3], false),
+    (0xce5f, &[0xc4, 0xa4], false),
+    (0xce60, &[0xc4, 0xa5], false),
+    (0xce61, &[0xc4, 0xa6], false),
+    (0xce68, &[0xc4, 0xa7], false),
+    (0xce69, &[0xc4, 0xa8], false),
+    (0xce6b, &[0xc4, 0xa9], false),
+    (0xce6d, &[0xc4, 0xaa], false),
+    (0xce74, &[0xc4, 0xab], false),
+    (0xce75, &[0xc4, 0xac], false),
+    (0xce78, &[0xc4, 0xad], false),
+    (0xce7c, &[0xc4, 0xae], false),
+    (0xce84, &[0xc4, 0xaf], false),
+    (0xce85, &[0xc4, 0xb0], false),
+    (0xce87, &[0xc4, 0xb1], false),
+    (0xce89, &[0xc4, 0xb2], false),
+    (0xce90, &[0xc4, 0xb3], false),
+    (0xce91, &[0xc4, 0xb4], false),
+    (0xce94, &[0xc4, 0xb5], false),
+    (0xce98, &[0xc4, 0xb6], false),
+    (0xcea0, &[0xc4, 0xb7], false),
+    (0xcea1, &[0xc4, 0xb8], false),
+    (0xcea3, &[0xc4, 0xb9], false),
+    (0xcea4, &[0xc4, 0xba], false),
+    (0xcea5, &[0xc4, 0xbb], false),
+    (0xceac, &[0xc4, 0xbc], false),
+    (0xcead, &[0xc4, 0xbd], false),
+    (0xcec1, &[0xc4, 0xbe], false),
+    (0xcee4, &[0xc4, 0xbf], false),
+    (0xcee5, &[0xc4, 0xc0], false),
+    (0xcee8, &[0xc4, 0xc1], false),
+    (0xceeb, &[0xc4, 0xc2], false),
+    (0xceec, &[0xc4, 0xc3], false),
+    (0xcef4, &[0xc4, 0xc4], false),
+    (0xcef5, &[0xc4, 0xc5], false),
+    (0xcef7, &[0xc4, 0xc6], false),
+    (0xcef8, &[0xc4, 0xc7], false),
+    (0xcef9, &[0xc4, 0xc8], false),
+    (0xcf00, &[0xc4, 0xc9], false),
+    (0xcf01, &[0xc4, 0xca], false),
+    (0xcf04, &[0xc4, 0xcb], false),
+    (0xcf08, &[0xc4, 0xcc], false),
+    (0xcf10, &[0xc4, 0xcd], false),
+    (0xcf11, &[0xc4, 0xce], false),
+    (0xcf13, &[0xc4, 0xcf], false),
+    (0xcf15, &[0xc4, 0xd0], false),
+    (0xcf1c, &[0xc4, 0xd1], false),
+    (0xcf20, &[0xc4, 0xd2], false),
+    (0xcf24, &[0xc4, 0xd3], false),
+    (0xcf2c, &[0xc4, 0xd4], false),
+    (0xcf2d, &[0xc4, 0xd5], false),
+    (0xcf2f, &[0xc4, 0xd6], false),
+    (0xcf30, &[0xc4, 0xd7], false),
+    (0xcf31, &[0xc4, 0xd8], false),
+    (0xcf38, &[0xc4, 0xd9], false),
+    (0xcf54, &[0xc4, 0xda], false),
+    (0xcf55, &[0xc4, 0xdb], false),
+    (0xcf58, &[0xc4, 0xdc], false),
+    (0xcf5c, &[0xc4, 0xdd], false),
+    (0xcf64, &[0xc4, 0xde], false),
+    (0xcf65, &[0xc4, 0xdf], false),
+    (0xcf67, &[0xc4, 0xe0], false),
+    (0xcf69, &[0xc4, 0xe1], false),
+    (0xcf70, &[0xc4, 0xe2], false),
+    (0xcf71, &[0xc4, 0xe3], false),
+    (0xcf74, &[0xc4, 0xe4], false),
+    (0xcf78, &[0xc4, 0xe5], false),
+    (0xcf80, &[0xc4, 0xe6], false),
+    (0xcf85, &[0xc4, 0xe7], false),
+    (0xcf8c, &[0xc4, 0xe8], false),
+    (0xcfa1, &[0xc4, 0xe9], false),
+    (0xcfa8, &[0xc4, 0xea], false),
+    (0xcfb0, &[0xc4, 0xeb], false),
+    (0xcfc4, &[0xc4, 0xec], false),
+    (0xcfe0, &[0xc4, 0xed], false),
+    (0xcfe1, &[0xc4, 0xee], false),
+    (0xcfe4, &[0xc4, 0xef], false),
+    (0xcfe8, &[0xc4, 0xf0], false),
+    (0xcff0, &[0xc4, 0xf1], false),
+    (0xcff1, &[0xc4, 0xf2], false),
+    (0xcff3, &[0xc4, 0xf3], false),
+    (0xcff5, &[0xc4, 0xf4], false),
+    (0xcffc, &[0xc4, 0xf5], false),
+    (0xd000, &[0xc4, 0xf6], false),
+    (0xd004, &[0xc4, 0xf7], false),
+    (0xd011, &[0xc4, 0xf8], false),
+    (0xd018, &[0xc4, 0xf9], false),
+    (0xd02d, &[0xc4, 0xfa], false),
+    (0xd034, &[0xc4, 0xfb], false),
+    (0xd035, &[0xc4, 0xfc], false),
+    (0xd038, &[0xc4, 0xfd], false),
+    (0xd03c, &[0xc4, 0xfe], false),
+    (0xd715, &[0xc5, 0x41], false),
+    (0xd716, &[0xc5, 0x42], false),
+    (0xd717, &[0xc5, 0x43], false),
+    (0xd71a, &[0xc5, 0x44], false),
+    (0xd71b, &[0xc5, 0x45], false),
+    (0xd71d, &[0xc5, 0x46], false),
+    (0xd71e, &[0xc5, 0x47], false),
+    (0xd71f, &[0xc5, 0x48], false),
+    (0xd721, &[0xc5, 0x49], false),
+    (0xd722, &[0xc5, 0x4a], false),
+    (0xd723, &[0xc5, 0x4b], false),
+    (0xd724, &[0xc5, 0x4c], false),
+    (0xd725, &[0xc5, 0x4d], false),
+    (0xd726, &[0xc5, 0x4e], false),
+    (0xd727, &[0xc5, 0x4f], false),
+    (0xd72a, &[0xc5, 0x50], false),
+    (0xd72c, &[0xc5, 0x51], false),
+    (0xd72e, &[0xc5, 0x52], false),
+    (0xd72f, &[0xc5, 0x53], false),
+    (0xd730, &[0xc5, 0x54], false),
+    (0xd731, &[0xc5, 0x55], false),
+    (0xd732, &[0xc5, 0x56], false),
+    (0xd733, &[0xc5, 0x57], false),
+    (0xd736, &[0xc5, 0x58], false),
+    (0xd737, &[0xc5, 0x59], false),
+    (0xd739, &[0xc5, 0x5a], false),
+    (0xd73a, &[0xc5, 0x61], false),
+    (0xd73b, &[0xc5, 0x62], false),
+    (0xd73d, &[0xc5, 0x63], false),
+    (0xd73e, &[0xc5, 0x64], false),
+    (0xd73f, &[0xc5, 0x65], false),
+    (0xd740, &[0xc5, 0x66], false),
+    (0xd741, &[0xc5, 0x67], false),
+    (0xd742, &[0xc5, 0x68], false),
+    (0xd743, &[0xc5, 0x69], false),
+    (0xd745, &[0xc5, 0x6a], false),
+    (0xd746, &[0xc5, 0x6b], false),
+    (0xd748, &[0xc5, 0x6c], false),
+    (0xd74a, &[0xc5, 0x6d], false),
+    (0xd74b, &[0xc5, 0x6e], false),
+    (0xd74c, &[0xc5, 0x6f], false),
+    (0xd74d, &[0xc5, 0x70], false),
+    (0xd74e, &[0xc5, 0x71], false),
+    (0xd74f, &[0xc5, 0x72], false),
+    (0xd752, &[0xc5, 0x73], false),
+    (0xd753, &[0xc5, 0x74], false),
+    (0xd755, &[0xc5, 0x75], false),
+    (0xd75a, &[0xc5, 0x76], false),
+    (0xd75b, &[0xc5, 0x77], false),
+    (0xd75c, &[0xc5, 0x78], false),
+    (0xd75d, &[0xc5, 0x79], false),
+    (0xd75e, &[0xc5, 0x7a], false),
+    (0xd75f, &[0xc5, 0x81], false),
+    (0xd762, &[0xc5, 0x82], false),
+    (0xd764, &[0xc5, 0x83], false),
+    (0xd766, &[0xc5, 0x84], false),
+    (0xd767, &[0xc5, 0x85], false),
+    (0xd768, &[0xc5, 0x86], false),
+    (0xd76a, &[0xc5, 0x87], false),
+    (0xd76b, &[0xc5, 0x88], false),
+    (0xd76d, &[0xc5, 0x89], false),
+    (0xd76e, &[0xc5, 0x8a], false),
+    (0xd76f, &[0xc5, 0x8b], false),
+    (0xd771, &[0xc5, 0x8c], false),
+    (0xd772, &[0xc5, 0x8d], false),
+    (0xd773, &[0xc5, 0x8e], false),
+    (0xd775, &[0xc5, 0x8f], false),
+    (0xd776, &[0xc5, 0x90], false),
+    (0xd777, &[0xc5, 0x91], false),
+    (0xd778, &[0xc5, 0x92], false),
+    (0xd779, &[0xc5, 0x93], false),
+    (0xd77a, &[0xc5, 0x94], false),
+    (0xd77b, &[0xc5, 0x95], false),
+    (0xd77e, &[0xc5, 0x96], false),
+    (0xd77f, &[0xc5, 0x97], false),
+    (0xd780, &[0xc5, 0x98], false),
+    (0xd782, &[0xc5, 0x99], false),
+    (0xd783, &[0xc5, 0x9a], false),
+    (0xd784, &[0xc5, 0x9b], false),
+    (0xd785, &[0xc5, 0x9c], false),
+    (0xd786, &[0xc5, 0x9d], false),
+    (0xd787, &[0xc5, 0x9e], false),
+    (0xd78a, &[0xc5, 0x9f], false),
+    (0xd78b, &[0xc5, 0xa0], false),
+    (0xd044, &[0xc5, 0xa1], false),
+    (0xd045, &[0xc5, 0xa2], false),
+    (0xd047, &[0xc5, 0xa3], false),
+    (0xd049, &[0xc5, 0xa4], false),
+    (0xd050, &[0xc5, 0xa5], false),
+    (0xd054, &[0xc5, 0xa6], false),
+    (0xd058, &[0xc5, 0xa7], false),
+    (0xd060, &[0xc5, 0xa8], false),
+    (0xd06c, &[0xc5, 0xa9], false),
+    (0xd06d, &[0xc5, 0xaa], false),
+    (0xd070, &[0xc5, 0xab], false),
+    (0xd074, &[0xc5, 0xac], false),
+    (0xd07c, &[0xc5, 0xad], false),
+    (0xd07d, &[0xc5, 0xae], false),
+    (0xd081, &[0xc5, 0xaf], false),
+    (0xd0a4, &[0xc5, 0xb0], false),
+    (0xd0a5, &[0xc5, 0xb1], false),
+    (0xd0a8, &[0xc5, 0xb2], false),
+    (0xd0ac, &[0xc5, 0xb3], false),
+    (0xd0b4, &[0xc5, 0xb4], false),
+    (0xd0b5, &[0xc5, 0xb5], false),
+    (0xd0b7, &[0xc5, 0xb6], false),
+    (0xd0b9, &[0xc5, 0xb7], false),
+    (0xd0c0, &[0xc5, 0xb8], false),
+    (0xd0c1, &[0xc5, 0xb9], false),
+    (0xd0c4, &[0xc5, 0xba], false),
+    (0xd0c8, &[0xc5, 0xbb], false),
+    (0xd0c9, &[0xc5, 0xbc], false),
+    (0xd0d0, &[0xc5, 0xbd], false),
+    (0xd0d1, &[0xc5, 0xbe], false),
+    (0xd0d3, &[0xc5, 0xbf], false),
+    (0xd0d4, &[0xc5, 0xc0], false),
+    (0xd0d5, &[0xc5, 0xc1], false),
+    (0xd0dc, &[0xc5, 0xc2], false),
+    (0xd0dd, &[0xc5, 0xc3], false),
+    (0xd0e0, &[0xc5, 0xc4], false),
+    (0xd0e4, &[0xc5, 0xc5], false),
+    (0xd0ec, &[0xc5, 0xc6], false),
+    (0xd0ed, &[0xc5, 0xc7], false),
+    (0xd0ef, &[0xc5, 0xc8], false),
+    (0xd0f0, &[0xc5, 0xc9], false),
+    (0xd0f1, &[0xc5, 0xca], false),
+    (0xd0f8, &[0xc5, 0xcb], false),
+    (0xd10d, &[0xc5, 0xcc], false),
+    (0xd130, &[0xc5, 0xcd], false),
+    (0xd131, &[0xc5, 0xce], false),
+    (0xd134, &[0xc5, 0xcf], false),
+    (0xd138, &[0xc5, 0xd0], false),
+    (0xd13a, &[0xc5, 0xd1], false),
+    (0xd140, &[0xc5, 0xd2], false),
+    (0xd141, &[0xc5, 0xd3], false),
+    (0xd143, &[0xc5, 0xd4], false),
+    (0xd144, &[0xc5, 0xd5], false),
+    (0xd145, &[0xc5, 0xd6], false),
+    (0xd14c, &[0xc5, 0xd7], false),
+    (0xd14d, &[0xc5, 0xd8], false),
+    (0xd150, &[0xc5, 0xd9], false),
+    (0xd154, &[0xc5, 0xda], false),
+    (0xd15c, &[0xc5, 0xdb], false),
+    (0xd15d, &[0xc5, 0xdc], false),
+    (0xd15f, &[0xc5, 0xdd], false),
+    (0xd161, &[0xc5, 0xde], false),
+    (0xd168, &[0xc5, 0xdf], false),
+    (0xd16c, &[0xc5, 0xe0], false),
+    (0xd17c, &[0xc5, 0xe1], false),
+    (0xd184, &[0xc5, 0xe2], false),
+    (0xd188, &[0xc5, 0xe3], false),
+    (0xd1a0, &[0xc5, 0xe4], false),
+    (0xd1a1, &[0xc5, 0xe5], false),
+    (0xd1a4, &[0xc5, 0xe6], false),
+    (0xd1a8, &[0xc5, 0xe7], false),
+    (0xd1b0, &[0xc5, 0xe8], false),
+    (0xd1b1, &[0xc5, 0xe9], false),
+    (0xd1b3, &[0xc5, 0xea], false),
+    (0xd1b5, &[0xc5, 0xeb], false),
+    (0xd1ba, &[0xc5, 0xec], false),
+    (0xd1bc, &[0xc5, 0xed], false),
+    (0xd1c0, &[0xc5, 0xee], false),
+    (0xd1d8, &[0xc5, 0xef], false),
+    (0xd1f4, &[0xc5, 0xf0], false),
+    (0xd1f8, &[0xc5, 0xf1], false),
+    (0xd207, &[0xc5, 0xf2], false),
+    (0xd209, &[0xc5, 0xf3], false),
+    (0xd210, &[0xc5, 0xf4], false),
+    (0xd22c, &[0xc5, 0xf5], false),
+    (0xd22d, &[0xc5, 0xf6], false),
+    (0xd230, &[0xc5, 0xf7], false),
+    (0xd234, &[0xc5, 0xf8], false),
+    (0xd23c, &[0xc5, 0xf9], false),
+    (0xd23d, &[0xc5, 0xfa], false),
+    (0xd23f, &[0xc5, 0xfb], false),
+    (0xd241, &[0xc5, 0xfc], false),
+    (0xd248, &[0xc5, 0xfd], false),
+    (0xd25c, &[0xc5, 0xfe], false),
+    (0xd78d, &[0xc6, 0x41], false),
+    (0xd78e, &[0xc6, 0x42], false),
+    (0xd78f, &[0xc6, 0x43], false),
+    (0xd791, &[0xc6, 0x44], false),
+    (0xd792, &[0xc6, 0x45], false),
+    (0xd793, &[0xc6, 0x46], false),
+    (0xd794, &[0xc6, 0x47], false),
+    (0xd795, &[0xc6, 0x48], false),
+    (0xd796, &[0xc6, 0x49], false),
+    (0xd797, &[0xc6, 0x4a], false),
+    (0xd79a, &[0xc6, 0x4b], false),
+    (0xd79c, &[0xc6, 0x4c], false),
+    (0xd79e, &[0xc6, 0x4d], false),
+    (0xd79f, &[0xc6, 0x4e], false),
+    (0xd7a0, &[0xc6, 0x4f], false),
+    (0xd7a1, &[0xc6, 0x50], false),
+    (0xd7a2, &[0xc6, 0x51], false),
+    (0xd7a3, &[0xc6, 0x52], false),
+    (0xd264, &[0xc6, 0xa1], false),
+    (0xd280, &[0xc6, 0xa2], false),
+    (0xd281, &[0xc6, 0xa3], false),
+    (0xd284, &[0xc6, 0xa4], false),
+    (0xd288, &[0xc6, 0xa5], false),
+    (0xd290, &[0xc6, 0xa6], false),
+    (0xd291, &[0xc6, 0xa7], false),
+    (0xd295, &[0xc6, 0xa8], false),
+    (0xd29c, &[0xc6, 0xa9], false),
+    (0xd2a0, &[0xc6, 0xaa], false),
+    (0xd2a4, &[0xc6, 0xab], false),
+    (0xd2ac, &[0xc6, 0xac], false),
+    (0xd2b1, &[0xc6, 0xad], false),
+    (0xd2b8, &[0xc6, 0xae], false),
+    (0xd2b9, &[0xc6, 0xaf], false),
+    (0xd2bc, &[0xc6, 0xb0], false),
+    (0xd2bf, &[0xc6, 0xb1], false),
+    (0xd2c0, &[0xc6, 0xb2], false),
+    (0xd2c2, &[0xc6, 0xb3], false),
+    (0xd2c8, &[0xc6, 0xb4], false),
+    (0xd2c9, &[0xc6, 0xb5], false),
+    (0xd2cb, &[0xc6, 0xb6], false),
+    (0xd2d4, &[0xc6, 0xb7], false),
+    (0xd2d8, &[0xc6, 0xb8], false),
+    (0xd2dc, &[0xc6, 0xb9], false),
+    (0xd2e4, &[0xc6, 0xba], false),
+    (0xd2e5, &[0xc6, 0xbb], false),
+    (0xd2f0, &[0xc6, 0xbc], false),
+    (0xd2f1, &[0xc6, 0xbd], false),
+    (0xd2f4, &[0xc6, 0xbe], false),
+    (0xd2f8, &[0xc6, 0xbf], false),
+    (0xd300, &[0xc6, 0xc0], false),
+    (0xd301, &[0xc6, 0xc1], false),
+    (0xd303, &[0xc6, 0xc2], false),
+    (0xd305, &[0xc6, 0xc3], false),
+    (0xd30c, &[0xc6, 0xc4], false),
+    (0xd30d, &[0xc6, 0xc5], false),
+    (0xd30e, &[0xc6, 0xc6], false),
+    (0xd310, &[0xc6, 0xc7], false),
+    (0xd314, &[0xc6, 0xc8], false),
+    (0xd316, &[0xc6, 0xc9], false),
+    (0xd31c, &[0xc6, 0xca], false),
+    (0xd31d, &[0xc6, 0xcb], false),
+    (0xd31f, &[0xc6, 0xcc], false),
+    (0xd320, &[0xc6, 0xcd], false),
+    (0xd321, &[0xc6, 0xce], false),
+    (0xd325, &[0xc6, 0xcf], false),
+    (0xd328, &[0xc6, 0xd0], false),
+    (0xd329, &[0xc6, 0xd1], false),
+    (0xd32c, &[0xc6, 0xd2], false),
+    (0xd330, &[0xc6, 0xd3], false),
+    (0xd338, &[0xc6, 0xd4], false),
+    (0xd339, &[0xc6, 0xd5], false),
+    (0xd33b, &[0xc6, 0xd6], false),
+    (0xd33c, &[0xc6, 0xd7], false),
+    (0xd33d, &[0xc6, 0xd8], false),
+    (0xd344, &[0xc6, 0xd9], false),
+    (0xd345, &[0xc6, 0xda], false),
+    (0xd37c, &[0xc6, 0xdb], false),
+    (0xd37d, &[0xc6, 0xdc], false),
+    (0xd380, &[0xc6, 0xdd], false),
+    (0xd384, &[0xc6, 0xde], false),
+    (0xd38c, &[0xc6, 0xdf], false),
+    (0xd38d, &[0xc6, 0xe0], false),
+    (0xd38f, &[0xc6, 0xe1], false),
+    (0xd390, &[0xc6, 0xe2], false),
+    (0xd391, &[0xc6, 0xe3], false),
+    (0xd398, &[0xc6, 0xe4], false),
+    (0xd399, &[0xc6, 0xe5], false),
+    (0xd39c, &[0xc6, 0xe6], false),
+    (0xd3a0, &[0xc6, 0xe7], false),
+    (0xd3a8, &[0xc6, 0xe8], false),
+    (0xd3a9, &[0xc6, 0xe9], false),
+    (0xd3ab, &[0xc6, 0xea], false),
+    (0xd3ad, &[0xc6, 0xeb], false),
+    (0xd3b4, &[0xc6, 0xec], false),
+    (0xd3b8, &[0xc6, 0xed], false),
+    (0xd3bc, &[0xc6, 0xee], false),
+    (0xd3c4, &[0xc6, 0xef], false),
+    (0xd3c5, &[0xc6, 0xf0], false),
+    (0xd3c8, &[0xc6, 0xf1], false),
+    (0xd3c9, &[0xc6, 0xf2], false),
+    (0xd3d0, &[0xc6, 0xf3], false),
+    (0xd3d8, &[0xc6, 0xf4], false),
+    (0xd3e1, &[0xc6, 0xf5], false),
+    (0xd3e3, &[0xc6, 0xf6], false),
+    (0xd3ec, &[0xc6, 0xf7], false),
+    (0xd3ed, &[0xc6, 0xf8], false),
+    (0xd3f0, &[0xc6, 0xf9], false),
+    (0xd3f4, &[0xc6, 0xfa], false),
+    (0xd3fc, &[0xc6, 0xfb], false),
+    (0xd3fd, &[0xc6, 0xfc], false),
+    (0xd3ff, &[0xc6, 0xfd], false),
+    (0xd401, &[0xc6, 0xfe], false),
+    (0xd408, &[0xc7, 0xa1], false),
+    (0xd41d, &[0xc7, 0xa2], false),
+    (0xd440, &[0xc7, 0xa3], false),
+    (0xd444, &[0xc7, 0xa4], false),
+    (0xd45c, &[0xc7, 0xa5], false),
+    (0xd460, &[0xc7, 0xa6], false),
+    (0xd464, &[0xc7, 0xa7], false),
+    (0xd46d, &[0xc7, 0xa8], false),
+    (0xd46f, &[0xc7, 0xa9], false),
+    (0xd478, &[0xc7, 0xaa], false),
+    (0xd479, &[0xc7, 0xab], false),
+    (0xd47c, &[0xc7, 0xac], false),
+    (0xd47f, &[0xc7, 0xad], false),
+    (0xd480, &[0xc7, 0xae], false),
+    (0xd482, &[0xc7, 0xaf], false),
+    (0xd488, &[0xc7, 0xb0], false),
+    (0xd489, &[0xc7, 0xb1], false),
+    (0xd48b, &[0xc7, 0xb2], false),
+    (0xd48d, &[0xc7, 0xb3], false),
+    (0xd494, &[0xc7, 0xb4], false),
+    (0xd4a9, &[0xc7, 0xb5], false),
+    (0xd4cc, &[0xc7, 0xb6], false),
+    (0xd4d0, &[0xc7, 0xb7], false),
+    (0xd4d4, &[0xc7, 0xb8], false),
+    (0xd4dc, &[0xc7, 0xb9], false),
+    (0xd4df, &[0xc7, 0xba], false),
+    (0xd4e8, &[0xc7, 0xbb], false),
+    (0xd4ec, &[0xc7, 0xbc], false),
+    (0xd4f0, &[0xc7, 0xbd], false),
+    (0xd4f8, &[0xc7, 0xbe], false),
+    (0xd4fb, &[0xc7, 0xbf], false),
+    (0xd4fd, &[0xc7, 0xc0], false),
+    (0xd504, &[0xc7, 0xc1], false),
+    (0xd508, &[0xc7, 0xc2], false),
+    (0xd50c, &[0xc7, 0xc3], false),
+    (0xd514, &[0xc7, 0xc4], false),
+    (0xd515, &[0xc7, 0xc5], false),
+    (0xd517, &[0xc7, 0xc6], false),
+    (0xd53c, &[0xc7, 0xc7], false),
+    (0xd53d, &[0xc7, 0xc8], false),
+    (0xd540, &[0xc7, 0xc9], false),
+    (0xd544, &[0xc7, 0xca], false),
+    (0xd54c, &[0xc7, 0xcb], false),
+    (0xd54d, &[0xc7, 0xcc], false),
+    (0xd54f, &[0xc7, 0xcd], false),
+    (0xd551, &[0xc7, 0xce], false),
+    (0xd558, &[0xc7, 0xcf], false),
+    (0xd559, &[0xc7, 0xd0], false),
+    (0xd55c, &[0xc7, 0xd1], false),
+    (0xd560, &[0xc7, 0xd2], false),
+    (0xd565, &[0xc7, 0xd3], false),
+    (0xd568, &[0xc7, 0xd4], false),
+    (0xd569, &[0xc7, 0xd5], false),
+    (0xd56b, &[0xc7, 0xd6], false),
+    (0xd56d, &[0xc7, 0xd7], false),
+    (0xd574, &[0xc7, 0xd8], false),
+    (0xd575, &[0xc7, 0xd9], false),
+    (0xd578, &[0xc7, 0xda], false),
+    (0xd57c, &[0xc7, 0xdb], false),
+    (0xd584, &[0xc7, 0xdc], false),
+    (0xd585, &[0xc7, 0xdd], false),
+    (0xd587, &[0xc7, 0xde], false),
+    (0xd588, &[0xc7, 0xdf], false),
+    (0xd589, &[0xc7, 0xe0], false),
+    (0xd590, &[0xc7, 0xe1], false),
+    (0xd5a5, &[0xc7, 0xe2], false),
+    (0xd5c8, &[0xc7, 0xe3], false),
+    (0xd5c9, &[0xc7, 0xe4], false),
+    (0xd5cc, &[0xc7, 0xe5], false),
+    (0xd5d0, &[0xc7, 0xe6], false),
+    (0xd5d2, &[0xc7, 0xe7], false),
+    (0xd5d8, &[0xc7, 0xe8], false),
+    (0xd5d9, &[0xc7, 0xe9], false),
+    (0xd5db, &[0xc7, 0xea], false),
+    (0xd5dd, &[0xc7, 0xeb], false),
+    (0xd5e4, &[0xc7, 0xec], false),
+    (0xd5e5, &[0xc7, 0xed], false),
+    (0xd5e8, &[0xc7, 0xee], false),
+    (0xd5ec, &[0xc7, 0xef], false),
+    (0xd5f4, &[0xc7, 0xf0], false),
+    (0xd5f5, &[0xc7, 0xf1], false),
+    (0xd5f7, &[0xc7, 0xf2], false),
+    (0xd5f9, &[0xc7, 0xf3], false),
+    (0xd600, &[0xc7, 0xf4], false),
+    (0xd601, &[0xc7, 0xf5], false),
+    (0xd604, &[0xc7, 0xf6], false),
+    (0xd608, &[0xc7, 0xf7], false),
+    (0xd610, &[0xc7, 0xf8], false),
+    (0xd611, &[0xc7, 0xf9], false),
+    (0xd613, &[0xc7, 0xfa], false),
+    (0xd614, &[0xc7, 0xfb], false),
+    (0xd615, &[0xc7, 0xfc], false),
+    (0xd61c, &[0xc7, 0xfd], false),
+    (0xd620, &[0xc7, 0xfe], false),
+    (0xd624, &[0xc8, 0xa1], false),
+    (0xd62d, &[0xc8, 0xa2], false),
+    (0xd638, &[0xc8, 0xa3], false),
+    (0xd639, &[0xc8, 0xa4], false),
+    (0xd63c, &[0xc8, 0xa5], false),
+    (0xd640, &[0xc8, 0xa6], false),
+    (0xd645, &[0xc8, 0xa7], false),
+    (0xd648, &[0xc8, 0xa8], false),
+    (0xd649, &[0xc8, 0xa9], false),
+    (0xd64b, &[0xc8, 0xaa], false),
+    (0xd64d, &[0xc8, 0xab], false),
+    (0xd651, &[0xc8, 0xac], false),
+    (0xd654, &[0xc8, 0xad], false),
+    (0xd655, &[0xc8, 0xae], false),
+    (0xd658, &[0xc8, 0xaf], false),
+    (0xd65c, &[0xc8, 0xb0], false),
+    (0xd667, &[0xc8, 0xb1], false),
+    (0xd669, &[0xc8, 0xb2], false),
+    (0xd670, &[0xc8, 0xb3], false),
+    (0xd671, &[0xc8, 0xb4], false),
+    (0xd674, &[0xc8, 0xb5], false),
+    (0xd683, &[0xc8, 0xb6], false),
+    (0xd685, &[0xc8, 0xb7], false),
+    (0xd68c, &[0xc8, 0xb8], false),
+    (0xd68d, &[0xc8, 0xb9], false),
+    (0xd690, &[0xc8, 0xba], false),
+    (0xd694, &[0xc8, 0xbb], false),
+    (0xd69d, &[0xc8, 0xbc], false),
+    (0xd69f, &[0xc8, 0xbd], false),
+    (0xd6a1, &[0xc8, 0xbe], false),
+    (0xd6a8, &[0xc8, 0xbf], false),
+    (0xd6ac, &[0xc8, 0xc0], false),
+    (0xd6b0, &[0xc8, 0xc1], false),
+    (0xd6b9, &[0xc8, 0xc2], false),
+    (0xd6bb, &[0xc8, 0xc3], false),
+    (0xd6c4, &[0xc8, 0xc4], false),
+    (0xd6c5, &[0xc8, 0xc5], false),
+    (0xd6c8, &[0xc8, 0xc6], false),
+    (0xd6cc, &[0xc8, 0xc7], false),
+    (0xd6d1, &[0xc8, 0xc8], false),
+    (0xd6d4, &[0xc8, 0xc9], false),
+    (0xd6d7, &[0xc8, 0xca], false),
+    (0xd6d9, &[0xc8, 0xcb], false),
+    (0xd6e0, &[0xc8, 0xcc], false),
+    (0xd6e4, &[0xc8, 0xcd], false),
+    (0xd6e8, &[0xc8, 0xce], false),
+    (0xd6f0, &[0xc8, 0xcf], false),
+    (0xd6f5, &[0xc8, 0xd0], false),
+    (0xd6fc, &[0xc8, 0xd1], false),
+    (0xd6fd, &[0xc8, 0xd2], false),
+    (0xd700, &[0xc8, 0xd3], false),
+    (0xd704, &[0xc8, 0xd4], false),
+    (0xd711, &[0xc8, 0xd5], false),
+    (0xd718, &[0xc8, 0xd6], false),
+    (0xd719, &[0xc8, 0xd7], false),
+    (0xd71c, &[0xc8, 0xd8], false),
+    (0xd720, &[0xc8, 0xd9], false),
+    (0xd728, &[0xc8, 0xda], false),
+    (0xd729, &[0xc8, 0xdb], false),
+    (0xd72b, &[0xc8, 0xdc], false),
+    (0xd72d, &[0xc8, 0xdd], false),
+    (0xd734, &[0xc8, 0xde], false),
+    (0xd735, &[0xc8, 0xdf], false),
+    (0xd738, &[0xc8, 0xe0], false),
+    (0xd73c, &[0xc8, 0xe1], false),
+    (0xd744, &[0xc8, 0xe2], false),
+    (0xd747, &[0xc8, 0xe3], false),
+    (0xd749, &[0xc8, 0xe4], false),
+    (0xd750, &[0xc8, 0xe5], false),
+    (0xd751, &[0xc8, 0xe6], false),
+    (0xd754, &[0xc8, 0xe7], false),
+    (0xd756, &[0xc8, 0xe8], false),
+    (0xd757, &[0xc8, 0xe9], false),
+    (0xd758, &[0xc8, 0xea], false),
+    (0xd759, &[0xc8, 0xeb], false),
+    (0xd760, &[0xc8, 0xec], false),
+    (0xd761, &[0xc8, 0xed], false),
+    (0xd763, &[0xc8, 0xee], false),
+    (0xd765, &[0xc8, 0xef], false),
+    (0xd769, &[0xc8, 0xf0], false),
+    (0xd76c, &[0xc8, 0xf1], false),
+    (0xd770, &[0xc8, 0xf2], false),
+    (0xd774, &[0xc8, 0xf3], false),
+    (0xd77c, &[0xc8, 0xf4], false),
+    (0xd77d, &[0xc8, 0xf5], false),
+    (0xd781, &[0xc8, 0xf6], false),
+    (0xd788, &[0xc8, 0xf7], false),
+    (0xd789, &[0xc8, 0xf8], false),
+    (0xd78c, &[0xc8, 0xf9], false),
+    (0xd790, &[0xc8, 0xfa], false),
+    (0xd798, &[0xc8, 0xfb], false),
+    (0xd799, &[0xc8, 0xfc], false),
+    (0xd79b, &[0xc8, 0xfd], false),
+    (0xd79d, &[0xc8, 0xfe], false),
+    (0x4f3d, &[0xca, 0xa1], false),
+    (0x4f73, &[0xca, 0xa2], false),
+    (0x5047, &[0xca, 0xa3], false),
+    (0x50f9, &[0xca, 0xa4], false),
+    (0x52a0, &[0xca, 0xa5], false),
+    (0x53ef, &[0xca, 0xa6], false),
+    (0x5475, &[0xca, 0xa7], false),
+    (0x54e5, &[0xca, 0xa8], false),
+    (0x5609, &[0xca, 0xa9], false),
+    (0x5ac1, &[0xca, 0xaa], false),
+    (0x5bb6, &[0xca, 0xab], false),
+    (0x6687, &[0xca, 0xac], false),
+    (0x67b6, &[0xca, 0xad], false),
+    (0x67b7, &[0xca, 0xae], false),
+    (0x67ef, &[0xca, 0xaf], false),
+    (0x6b4c, &[0xca, 0xb0], false),
+    (0x73c2, &[0xca, 0xb1], false),
+    (0x75c2, &[0xca, 0xb2], false),
+    (0x7a3c, &[0xca, 0xb3], false),
+    (0x82db, &[0xca, 0xb4], false),
+    (0x8304, &[0xca, 0xb5], false),
+    (0x8857, &[0xca, 0xb6], false),
+    (0x8888, &[0xca, 0xb7], false),
+    (0x8a36, &[0xca, 0xb8], false),
+    (0x8cc8, &[0xca, 0xb9], false),
+    (0x8dcf, &[0xca, 0xba], false),
+    (0x8efb, &[0xca, 0xbb], false),
+    (0x8fe6, &[0xca, 0xbc], false),
+    (0x99d5, &[0xca, 0xbd], false),
+    (0x523b, &[0xca, 0xbe], false),
+    (0x5374, &[0xca, 0xbf], false),
+    (0x5404, &[0xca, 0xc0], false),
+    (0x606a, &[0xca, 0xc1], false),
+    (0x6164, &[0xca, 0xc2], false),
+    (0x6bbc, &[0xca, 0xc3], false),
+    (0x73cf, &[0xca, 0xc4], false),
+    (0x811a, &[0xca, 0xc5], false),
+    (0x89ba, &[0xca, 0xc6], false),
+    (0x89d2, &[0xca, 0xc7], false),
+    (0x95a3, &[0xca, 0xc8], false),
+    (0x4f83, &[0xca, 0xc9], false),
+    (0x520a, &[0xca, 0xca], false),
+    (0x58be, &[0xca, 0xcb], false),
+    (0x5978, &[0xca, 0xcc], false),
+    (0x59e6, &[0xca, 0xcd], false),
+    (0x5e72, &[0xca, 0xce], false),
+    (0x5e79, &[0xca, 0xcf], false),
+    (0x61c7, &[0xca, 0xd0], false),
+    (0x63c0, &[0xca, 0xd1], false),
+    (0x6746, &[0xca, 0xd2], false),
+    (0x67ec, &[0xca, 0xd3], false),
+    (0x687f, &[0xca, 0xd4], false),
+    (0x6f97, &[0xca, 0xd5], false),
+    (0x764e, &[0xca, 0xd6], false),
+    (0x770b, &[0xca, 0xd7], false),
+    (0x78f5, &[0xca, 0xd8], false),
+    (0x7a08, &[0xca, 0xd9], false),
+    (0x7aff, &[0xca, 0xda], false),
+    (0x7c21, &[0xca, 0xdb], false),
+    (0x809d, &[0xca, 0xdc], false),
+    (0x826e, &[0xca, 0xdd], false),
+    (0x8271, &[0xca, 0xde], false),
+    (0x8aeb, &[0xca, 0xdf], false),
+    (0x9593, &[0xca, 0xe0], false),
+    (0x4e6b, &[0xca, 0xe1], false),
+    (0x559d, &[0xca, 0xe2], false),
+    (0x66f7, &[0xca, 0xe3], false),
+    (0x6e34, &[0xca, 0xe4], false),
+    (0x78a3, &[0xca, 0xe5], false),
+    (0x7aed, &[0xca, 0xe6], false),
+    (0x845b, &[0xca, 0xe7], false),
+    (0x8910, &[0xca, 0xe8], false),
+    (0x874e, &[0xca, 0xe9], false),
+    (0x97a8, &[0xca, 0xea], false),
+    (0x52d8, &[0xca, 0xeb], false),
+    (0x574e, &[0xca, 0xec], false),
+    (0x582a, &[0xca, 0xed], false),
+    (0x5d4c, &[0xca, 0xee], false),
+    (0x611f, &[0xca, 0xef], false),
+    (0x61be, &[0xca, 0xf0], false),
+    (0x6221, &[0xca, 0xf1], false),
+    (0x6562, &[0xca, 0xf2], false),
+    (0x67d1, &[0xca, 0xf3], false),
+    (0x6a44, &[0xca, 0xf4], false),
+    (0x6e1b, &[0xca, 0xf5], false),
+    (0x7518, &[0xca, 0xf6], false),
+    (0x75b3, &[0xca, 0xf7], false),
+    (0x76e3, &[0xca, 0xf8], false),
+    (0x77b0, &[0xca, 0xf9], false),
+    (0x7d3a, &[0xca, 0xfa], false),
+    (0x90af, &[0xca, 0xfb], false),
+    (0x9451, &[0xca, 0xfc], false),
+    (0x9452, &[0xca, 0xfd], false),
+    (0x9f95, &[0xca, 0xfe], false),
+    (0x5323, &[0xcb, 0xa1], false),
+    (0x5cac, &[0xcb, 0xa2], false),
+    (0x7532, &[0xcb, 0xa3], false),
+    (0x80db, &[0xcb, 0xa4], false),
+    (0x9240, &[0xcb, 0xa5], false),
+    (0x9598, &[0xcb, 0xa6], false),
+    (0x525b, &[0xcb, 0xa7], false),
+    (0x5808, &[0xcb, 0xa8], false),
+    (0x59dc, &[0xcb, 0xa9], false),
+    (0x5ca1, &[0xcb, 0xaa], false),
+    (0x5d17, &[0xcb, 0xab], false),
+    (0x5eb7, &[0xcb, 0xac], false),
+    (0x5f3a, &[0xcb, 0xad], false),
+    (0x5f4a, &[0xcb, 0xae], false),
+    (0x6177, &[0xcb, 0xaf], false),
+    (0x6c5f, &[0xcb, 0xb0], false),
+    (0x757a, &[0xcb, 0xb1], false),
+    (0x7586, &[0xcb, 0xb2], false),
+    (0x7ce0, &[0xcb, 0xb3], false),
+    (0x7d73, &[0xcb, 0xb4], false),
+    (0x7db1, &[0xcb, 0xb5], false),
+    (0x7f8c, &[0xcb, 0xb6], false),
+    (0x8154, &[0xcb, 0xb7], false),
+    (0x8221, &[0xcb, 0xb8], false),
+    (0x8591, &[0xcb, 0xb9], false),
+    (0x8941, &[0xcb, 0xba], false),
+    (0x8b1b, &[0xcb, 0xbb], false),
+    (0x92fc, &[0xcb, 0xbc], false),
+    (0x964d, &[0xcb, 0xbd], false),
+    (0x9c47, &[0xcb, 0xbe], false),
+    (0x4ecb, &[0xcb, 0xbf], false),
+    (0x4ef7, &[0xcb, 0xc0], false),
+    (0x500b, &[0xcb, 0xc1], false),
+    (0x51f1, &[0xcb, 0xc2], false),
+    (0x584f, &[0xcb, 0xc3], false),
+    (0x6137, &[0xcb, 0xc4], false),
+    (0x613e, &[0xcb, 0xc5], false),
+    (0x6168, &[0xcb, 0xc6], false),
+    (0x6539, &[0xcb, 0xc7], false),
+    (0x69ea, &[0xcb, 0xc8], false),
+    (0x6f11, &[0xcb, 0xc9], false),
+    (0x75a5, &[0xcb, 0xca], false),
+    (0x7686, &[0xcb, 0xcb], false),
+    (0x76d6, &[0xcb, 0xcc], false),
+    (0x7b87, &[0xcb, 0xcd], false),
+    (0x82a5, &[0xcb, 0xce], false),
+    (0x84cb, &[0xcb, 0xcf], false),
+    (0xf900, &[0xcb, 0xd0], false),
+    (0x93a7, &[0xcb, 0xd1], false),
+    (0x958b, &[0xcb, 0xd2], false),
+    (0x5580, &[0xcb, 0xd3], false),
+    (0x5ba2, &[0xcb, 0xd4], false),
+    (0x5751, &[0xcb, 0xd5], false),
+    (0xf901, &[0xcb, 0xd6], false),
+    (0x7cb3, &[0xcb, 0xd7], false),
+    (0x7fb9, &[0xcb, 0xd8], false),
+    (0x91b5, &[0xcb, 0xd9], false),
+    (0x5028, &[0xcb, 0xda], false),
+    (0x53bb, &[0xcb, 0xdb], false),
+    (0x5c45, &[0xcb, 0xdc], false),
+    (0x5de8, &[0xcb, 0xdd], false),
+    (0x62d2, &[0xcb, 0xde], false),
+    (0x636e, &[0xcb, 0xdf], false),
+    (0x64da, &[0xcb, 0xe0], false),
+    (0x64e7, &[0xcb, 0xe1], false),
+    (0x6e20, &[0xcb, 0xe2], false),
+    (0x70ac, &[0xcb, 0xe3], false),
+    (0x795b, &[0xcb, 0xe4], false),
+    (0x8ddd, &[0xcb, 0xe5], false),
+    (0x8e1e, &[0xcb, 0xe6], false),
+    (0xf902, &[0xcb, 0xe7], false),
+    (0x907d, &[0xcb, 0xe8], false),
+    (0x9245, &[0xcb, 0xe9], false),
+    (0x92f8, &[0xcb, 0xea], false),
+    (0x4e7e, &[0xcb, 0xeb], false),
+    (0x4ef6, &[0xcb, 0xec], false),
+    (0x5065, &[0xcb, 0xed], false),
+    (0x5dfe, &[0xcb, 0xee], false),
+    (0x5efa, &[0xcb, 0xef], false),
+    (0x6106, &[0xcb, 0xf0], false),
+    (0x6957, &[0xcb, 0xf1], false),
+    (0x8171, &[0xcb, 0xf2], false),
+    (0x8654, &[0xcb, 0xf3], false),
+    (0x8e47, &[0xcb, 0xf4], false),
+    (0x9375, &[0xcb, 0xf5], false),
+    (0x9a2b, &[0xcb, 0xf6], false),
+    (0x4e5e, &[0xcb, 0xf7], false),
+    (0x5091, &[0xcb, 0xf8], false),
+    (0x6770, &[0xcb, 0xf9], false),
+    (0x6840, &[0xcb, 0xfa], false),
+    (0x5109, &[0xcb, 0xfb], false),
+    (0x528d, &[0xcb, 0xfc], false),
+    (0x5292, &[0xcb, 0xfd], false),
+    (0x6aa2, &[0xcb, 0xfe], false),
+    (0x77bc, &[0xcc, 0xa1], false),
+    (0x9210, &[0xcc, 0xa2], false),
+    (0x9ed4, &[0xcc, 0xa3], false),
+    (0x52ab, &[0xcc, 0xa4], false),
+    (0x602f, &[0xcc, 0xa5], false),
+    (0x8ff2, &[0xcc, 0xa6], false),
+    (0x5048, &[0xcc, 0xa7], false),
+    (0x61a9, &[0xcc, 0xa8], false),
+    (0x63ed, &[0xcc, 0xa9], false),
+    (0x64ca, &[0xcc, 0xaa], false),
+    (0x683c, &[0xcc, 0xab], false),
+    (0x6a84, &[0xcc, 0xac], false),
+    (0x6fc0, &[0xcc, 0xad], false),
+    (0x8188, &[0xcc, 0xae], false),
+    (0x89a1, &[0xcc, 0xaf], false),
+    (0x9694, &[0xcc, 0xb0], false),
+    (0x5805, &[0xcc, 0xb1], false),
+    (0x727d, &[0xcc, 0xb2], false),
+    (0x72ac, &[0xcc, 0xb3], false),
+    (0x7504, &[0xcc, 0xb4], false),
+    (0x7d79, &[0xcc, 0xb5], false),
+    (0x7e6d, &[0xcc, 0xb6], false),
+    (0x80a9, &[0xcc, 0xb7], false),
+    (0x898b, &[0xcc, 0xb8], false),
+    (0x8b74, &[0xcc, 0xb9], false),
+    (0x9063, &[0xcc, 0xba], false),
+    (0x9d51, &[0xcc, 0xbb], false),
+    (0x6289, &[0xcc, 0xbc], false),
+    (0x6c7a, &[0xcc, 0xbd], false),
+    (0x6f54, &[0xcc, 0xbe], false),
+    (0x7d50, &[0xcc, 0xbf], false),
+    (0x7f3a, &[0xcc, 0xc0], false),
+    (0x8a23, &[0xcc, 0xc1], false),
+    (0x517c, &[0xcc, 0xc2], false),
+    (0x614a, &[0xcc, 0xc3], false),
+    (0x7b9d, &[0xcc, 0xc4], false),
+    (0x8b19, &[0xcc, 0xc5], false),
+    (0x9257, &[0xcc, 0xc6], false),
+    (0x938c, &[0xcc, 0xc7], false),
+    (0x4eac, &[0xcc, 0xc8], false),
+    (0x4fd3, &[0xcc, 0xc9], false),
+    (0x501e, &[0xcc, 0xca], false),
+    (0x50be, &[0xcc, 0xcb], false),
+    (0x5106, &[0xcc, 0xcc], false),
+    (0x52c1, &[0xcc, 0xcd], false),
+    (0x52cd, &[0xcc, 0xce], false),
+    (0x537f, &[0xcc, 0xcf], false),
+    (0x5770, &[0xcc, 0xd0], false),
+    (0x5883, &[0xcc, 0xd1], false),
+    (0x5e9a, &[0xcc, 0xd2], false),
+    (0x5f91, &[0xcc, 0xd3], false),
+    (0x6176, &[0xcc, 0xd4], false),
+    (0x61ac, &[0xcc, 0xd5], false),
+    (0x64ce, &[0xcc, 0xd6], false),
+    (0x656c, &[0xcc, 0xd7], false),
+    (0x666f, &[0xcc, 0xd8], false),
+    (0x66bb, &[0xcc, 0xd9], false),
+    (0x66f4, &[0xcc, 0xda], false),
+    (0x6897, &[0xcc, 0xdb], false),
+    (0x6d87, &[0xcc, 0xdc], false),
+    (0x7085, &[0xcc, 0xdd], false),
+    (0x70f1, &[0xcc, 0xde], false),
+    (0x749f, &[0xcc, 0xdf], false),
+    (0x74a5, &[0xcc, 0xe0], false),
+    (0x74ca, &[0xcc, 0xe1], false),
+    (0x75d9, &[0xcc, 0xe2], false),
+    (0x786c, &[0xcc, 0xe3], false),
+    (0x78ec, &[0xcc, 0xe4], false),
+    (0x7adf, &[0xcc, 0xe5], false),
+    (0x7af6, &[0xcc, 0xe6], false),
+    (0x7d45, &[0xcc, 0xe7], false),
+    (0x7d93, &[0xcc, 0xe8], false),
+    (0x8015, &[0xcc, 0xe9], false),
+    (0x803f, &[0xcc, 0xea], false),
+    (0x811b, &[0xcc, 0xeb], false),
+    (0x8396, &[0xcc, 0xec], false),
+    (0x8b66, &[0xcc, 0xed], false),
+    (0x8f15, &[0xcc, 0xee], false),
+    (0x9015, &[0xcc, 0xef], false),
+    (0x93e1, &[0xcc, 0xf0], false),
+    (0x9803, &[0xcc, 0xf1], false),
+    (0x9838, &[0xcc, 0xf2], false),
+    (0x9a5a, &[0xcc, 0xf3], false),
+    (0x9be8, &[0xcc, 0xf4], false),
+    (0x4fc2, &[0xcc, 0xf5], false),
+    (0x5553, &[0xcc, 0xf6], false),
+    (0x583a, &[0xcc, 0xf7], false),
+    (0x5951, &[0xcc, 0xf8], false),
+    (0x5b63, &[0xcc, 0xf9], false),
+    (0x5c46, &[0xcc, 0xfa], false),
+    (0x60b8, &[0xcc, 0xfb], false),
+    (0x6212, &[0xcc, 0xfc], false),
+    (0x6842, &[0xcc, 0xfd], false),
+    (0x68b0, &[0xcc, 0xfe], false),
+    (0x68e8, &[0xcd, 0xa1], false),
+    (0x6eaa, &[0xcd, 0xa2], false),
+    (0x754c, &[0xcd, 0xa3], false),
+    (0x7678, &[0xcd, 0xa4], false),
+    (0x78ce, &[0xcd, 0xa5], false),
+    (0x7a3d, &[0xcd, 0xa6], false),
+    (0x7cfb, &[0xcd, 0xa7], false),
+    (0x7e6b, &[0xcd, 0xa8], false),
+    (0x7e7c, &[0xcd, 0xa9], false),
+    (0x8a08, &[0xcd, 0xaa], false),
+    (0x8aa1, &[0xcd, 0xab], false),
+    (0x8c3f, &[0xcd, 0xac], false),
+    (0x968e, &[0xcd, 0xad], false),
+    (0x9dc4, &[0xcd, 0xae], false),
+    (0x53e4, &[0xcd, 0xaf], false),
+    (0x53e9, &[0xcd, 0xb0], false),
+    (0x544a, &[0xcd, 0xb1], false),
+    (0x5471, &[0xcd, 0xb2], false),
+    (0x56fa, &[0xcd, 0xb3], false),
+    (0x59d1, &[0xcd, 0xb4], false),
+    (0x5b64, &[0xcd, 0xb5], false),
+    (0x5c3b, &[0xcd, 0xb6], false),
+    (0x5eab, &[0xcd, 0xb7], false),
+    (0x62f7, &[0xcd, 0xb8], false),
+    (0x6537, &[0xcd, 0xb9], false),
+    (0x6545, &[0xcd, 0xba], false),
+    (0x6572, &[0xcd, 0xbb], false),
+    (0x66a0, &[0xcd, 0xbc], false),
+    (0x67af, &[0xcd, 0xbd], false),
+    (0x69c1, &[0xcd, 0xbe], false),
+    (0x6cbd, &[0xcd, 0xbf], false),
+    (0x75fc, &[0xcd, 0xc0], false),
+    (0x7690, &[0xcd, 0xc1], false),
+    (0x777e, &[0xcd, 0xc2], false),
+    (0x7a3f, &[0xcd, 0xc3], false),
+    (0x7f94, &[0xcd, 0xc4], false),
+    (0x8003, &[0xcd, 0xc5], false),
+    (0x80a1, &[0xcd, 0xc6], false),
+    (0x818f, &[0xcd, 0xc7], false),
+    (0x82e6, &[0xcd, 0xc8], false),
+    (0x82fd, &[0xcd, 0xc9], false),
+    (0x83f0, &[0xcd, 0xca], false),
+    (0x85c1, &[0xcd, 0xcb], false),
+    (0x8831, &[0xcd, 0xcc], false),
+    (0x88b4, &[0xcd, 0xcd], false),
+    (0x8aa5, &[0xcd, 0xce], false),
+    (0xf903, &[0xcd, 0xcf], false),
+    (0x8f9c, &[0xcd, 0xd0], false),
+    (0x932e, &[0xcd, 0xd1], false),
+    (0x96c7, &[0xcd, 0xd2], false),
+    (0x9867, &[0xcd, 0xd3], false),
+    (0x9ad8, &[0xcd, 0xd4], false),
+    (0x9f13, &[0xcd, 0xd5], false),
+    (0x54ed, &[0xcd, 0xd6], false),
+    (0x659b, &[0xcd, 0xd7], false),
+    (0x66f2, &[0xcd, 0xd8], false),
+    (0x688f, &[0xcd, 0xd9], false),
+    (0x7a40, &[0xcd, 0xda], false),
+    (0x8c37, &[0xcd, 0xdb], false),
+    (0x9d60, &[0xcd, 0xdc], false),
+    (0x56f0, &[0xcd, 0xdd], false),
+    (0x5764, &[0xcd, 0xde], false),
+    (0x5d11, &[0xcd, 0xdf], false),
+    (0x6606, &[0xcd, 0xe0], false),
+    (0x68b1, &[0xcd, 0xe1], false),
+    (0x68cd, &[0xcd, 0xe2], false),
+    (0x6efe, &[0xcd, 0xe3], false),
+    (0x7428, &[0xcd, 0xe4], false),
+    (0x889e, &[0xcd, 0xe5], false),
+    (0x9be4, &[0xcd, 0xe6], false),
+    (0x6c68, &[0xcd, 0xe7], false),
+    (0xf904, &[0xcd, 0xe8], false),
+    (0x9aa8, &[0xcd, 0xe9], false),
+    (0x4f9b, &[0xcd, 0xea], false),
+    (0x516c, &[0xcd, 0xeb], false),
+    (0x5171, &[0xcd, 0xec], false),
+    (0x529f, &[0xcd, 0xed], false),
+    (0x5b54, &[0xcd, 0xee], false),
+    (0x5de5, &[0xcd, 0xef], false),
+    (0x6050, &[0xcd, 0xf0], false),
+    (0x606d, &[0xcd, 0xf1], false),
+    (0x62f1, &[0xcd, 0xf2], false),
+    (0x63a7, &[0xcd, 0xf3], false),
+    (0x653b, &[0xcd, 0xf4], false),
+    (0x73d9, &[0xcd, 0xf5], false),
+    (0x7a7a, &[0xcd, 0xf6], false),
+    (0x86a3, &[0xcd, 0xf7], false),
+    (0x8ca2, &[0xcd, 0xf8], false),
+    (0x978f, &[0xcd, 0xf9], false),
+    (0x4e32, &[0xcd, 0xfa], false),
+    (0x5be1, &[0xcd, 0xfb], false),
+    (0x6208, &[0xcd, 0xfc], false),
+    (0x679c, &[0xcd, 0xfd], false),
+    (0x74dc, &[0xcd, 0xfe], false),
+    (0x79d1, &[0xce, 0xa1], false),
+    (0x83d3, &[0xce, 0xa2], false),
+    (0x8a87, &[0xce, 0xa3], false),
+    (0x8ab2, &[0xce, 0xa4], false),
+    (0x8de8, &[0xce, 0xa5], false),
+    (0x904e, &[0xce, 0xa6], false),
+    (0x934b, &[0xce, 0xa7], false),
+    (0x9846, &[0xce, 0xa8], false),
+    (0x5ed3, &[0xce, 0xa9], false),
+    (0x69e8, &[0xce, 0xaa], false),
+    (0x85ff, &[0xce, 0xab], false),
+    (0x90ed, &[0xce, 0xac], false),
+    (0xf905, &[0xce, 0xad], false),
+    (0x51a0, &[0xce, 0xae], false),
+    (0x5b98, &[0xce, 0xaf], false),
+    (0x5bec, &[0xce, 0xb0], false),
+    (0x6163, &[0xce, 0xb1], false),
+    (0x68fa, &[0xce, 0xb2], false),
+    (0x6b3e, &[0xce, 0xb3], false),
+    (0x704c, &[0xce, 0xb4], false),
+    (0x742f, &[0xce, 0xb5], false),
+    (0x74d8, &[0xce, 0xb6], false),
+    (0x7ba1, &[0xce, 0xb7], false),
+    (0x7f50, &[0xce, 0xb8], false),
+    (0x83c5, &[0xce, 0xb9], false),
+    (0x89c0, &[0xce, 0xba], false),
+    (0x8cab, &[0xce, 0xbb], false),
+    (0x95dc, &[0xce, 0xbc], false),
+    (0x9928, &[0xce, 0xbd], false),
+    (0x522e, &[0xce, 0xbe], false),
+    (0x605d, &[0xce, 0xbf], false),
+    (0x62ec, &[0xce, 0xc0], false),
+    (0x9002, &[0xce, 0xc1], false),
+    (0x4f8a, &[0xce, 0xc2], false),
+    (0x5149, &[0xce, 0xc3], false),
+    (0x5321, &[0xce, 0xc4], false),
+    (0x58d9, &[0xce, 0xc5], false),
+    (0x5ee3, &[0xce, 0xc6], false),
+    (0x66e0, &[0xce, 0xc7], false),
+    (0x6d38, &[0xce, 0xc8], false),
+    (0x709a, &[0xce, 0xc9], false),
+    (0x72c2, &[0xce, 0xca], false),
+    (0x73d6, &[0xce, 0xcb], false),
+    (0x7b50, &[0xce, 0xcc], false),
+    (0x80f1, &[0xce, 0xcd], false),
+    (0x945b, &[0xce, 0xce], false),
+    (0x5366, &[0xce, 0xcf], false),
+    (0x639b, &[0xce, 0xd0], false),
+    (0x7f6b, &[0xce, 0xd1], false),
+    (0x4e56, &[0xce, 0xd2], false),
+    (0x5080, &[0xce, 0xd3], false),
+    (0x584a, &[0xce, 0xd4], false),
+    (0x58de, &[0xce, 0xd5], false),
+    (0x602a, &[0xce, 0xd6], false),
+    (0x6127, &[0xce, 0xd7], false),
+    (0x62d0, &[0xce, 0xd8], false),
+    (0x69d0, &[0xce, 0xd9], false),
+    (0x9b41, &[0xce, 0xda], false),
+    (0x5b8f, &[0xce, 0xdb], false),
+    (0x7d18, &[0xce, 0xdc], false),
+    (0x80b1, &[0xce, 0xdd], false),
+    (0x8f5f, &[0xce, 0xde], false),
+    (0x4ea4, &[0xce, 0xdf], false),
+    (0x50d1, &[0xce, 0xe0], false),
+    (0x54ac, &[0xce, 0xe1], false),
+    (0x55ac, &[0xce, 0xe2], false),
+    (0x5b0c, &[0xce, 0xe3], false),
+    (0x5da0, &[0xce, 0xe4], false),
+    (0x5de7, &[0xce, 0xe5], false),
+    (0x652a, &[0xce, 0xe6], false),
+    (0x654e, &[0xce, 0xe7], false),
+    (0x6821, &[0xce, 0xe8], false),
+    (0x6a4b, &[0xce, 0xe9], false),
+    (0x72e1, &[0xce, 0xea], false),
+    (0x768e, &[0xce, 0xeb], false),
+    (0x77ef, &[0xce, 0xec], false),
+    (0x7d5e, &[0xce, 0xed], false),
+    (0x7ff9, &[0xce, 0xee], false),
+    (0x81a0, &[0xce, 0xef], false),
+    (0x854e, &[0xce, 0xf0], false),
+    (0x86df, &[0xce, 0xf1], false),
+    (0x8f03, &[0xce, 0xf2], false),
+    (0x8f4e, &[0xce, 0xf3], false),
+    (0x90ca, &[0xce, 0xf4], false),
+    (0x9903, &[0xce, 0xf5], false),
+    (0x9a55, &[0xce, 0xf6], false),
+    (0x9bab, &[0xce, 0xf7], false),
+    (0x4e18, &[0xce, 0xf8], false),
+    (0x4e45, &[0xce, 0xf9], false),
+    (0x4e5d, &[0xce, 0xfa], false),
+    (0x4ec7, &[0xce, 0xfb], false),
+    (0x4ff1, &[0xce, 0xfc], false),
+    (0x5177, &[0xce, 0xfd], false),
+    (0x52fe, &[0xce, 0xfe], false),
+    (0x5340, &[0xcf, 0xa1], false),
+    (0x53e3, &[0xcf, 0xa2], false),
+    (0x53e5, &[0xcf, 0xa3], false),
+    (0x548e, &[0xcf, 0xa4], false),
+    (0x5614, &[0xcf, 0xa5], false),
+    (0x5775, &[0xcf, 0xa6], false),
+    (0x57a2, &[0xcf, 0xa7], false),
+    (0x5bc7, &[0xcf, 0xa8], false),
+    (0x5d87, &[0xcf, 0xa9], false),
+    (0x5ed0, &[0xcf, 0xaa], false),
+    (0x61fc, &[0xcf, 0xab], false),
+    (0x62d8, &[0xcf, 0xac], false),
+    (0x6551, &[0xcf, 0xad], false),
+    (0x67b8, &[0xcf, 0xae], false),
+    (0x67e9, &[0xcf, 0xaf], false),
+    (0x69cb, &[0xcf, 0xb0], false),
+    (0x6b50, &[0xcf, 0xb1], false),
+    (0x6bc6, &[0xcf, 0xb2], false),
+    (0x6bec, &[0xcf, 0xb3], false),
+    (0x6c42, &[0xcf, 0xb4], false),
+    (0x6e9d, &[0xcf, 0xb5], false),
+    (0x7078, &[0xcf, 0xb6], false),
+    (0x72d7, &[0xcf, 0xb7], false),
+    (0x7396, &[0xcf, 0xb8], false),
+    (0x7403, &[0xcf, 0xb9], false),
+    (0x77bf, &[0xcf, 0xba], false),
+    (0x77e9, &[0xcf, 0xbb], false),
+    (0x7a76, &[0xcf, 0xbc], false),
+    (0x7d7f, &[0xcf, 0xbd], false),
+    (0x8009, &[0xcf, 0xbe], false),
+    (0x81fc, &[0xcf, 0xbf], false),
+    (0x8205, &[0xcf, 0xc0], false),
+    (0x820a, &[0xcf, 0xc1], false),
+    (0x82df, &[0xcf, 0xc2], false),
+    (0x8862, &[0xcf, 0xc3], false),
+    (0x8b33, &[0xcf, 0xc4], false),
+    (0x8cfc, &[0xcf, 0xc5], false),
+    (0x8ec0, &[0xcf, 0xc6], false),
+    (0x9011, &[0xcf, 0xc7], false),
+    (0x90b1, &[0xcf, 0xc8], false),
+    (0x9264, &[0xcf, 0xc9], false),
+    (0x92b6, &[0xcf, 0xca], false),
+    (0x99d2, &[0xcf, 0xcb], false),
+    (0x9a45, &[0xcf, 0xcc], false),
+    (0x9ce9, &[0xcf, 0xcd], false),
+    (0x9dd7, &[0xcf, 0xce], false),
+    (0x9f9c, &[0xcf, 0xcf], false),
+    (0x570b, &[0xcf, 0xd0], false),
+    (0x5c40, &[0xcf, 0xd1], false),
+    (0x83ca, &[0xcf, 0xd2], false),
+    (0x97a0, &[0xcf, 0xd3], false),
+    (0x97ab, &[0xcf, 0xd4], false),
+    (0x9eb4, &[0xcf, 0xd5], false),
+    (0x541b, &[0xcf, 0xd6], false),
+    (0x7a98, &[0xcf, 0xd7], false),
+    (0x7fa4, &[0xcf, 0xd8], false),
+    (0x88d9, &[0xcf, 0xd9], false),
+    (0x8ecd, &[0xcf, 0xda], false),
+    (0x90e1, &[0xcf, 0xdb], false),
+    (0x5800, &[0xcf, 0xdc], false),
+    (0x5c48, &[0xcf, 0xdd], false),
+    (0x6398, &[0xcf, 0xde], false),
+    (0x7a9f, &[0xcf, 0xdf], false),
+    (0x5bae, &[0xcf, 0xe0], false),
+    (0x5f13, &[0xcf, 0xe1], false),
+    (0x7a79, &[0xcf, 0xe2], false),
+    (0x7aae, &[0xcf, 0xe3], false),
+    (0x828e, &[0xcf, 0xe4], false),
+    (0x8eac, &[0xcf, 0xe5], false),
+    (0x5026, &[0xcf, 0xe6], false),
+    (0x5238, &[0xcf, 0xe7], false),
+    (0x52f8, &[0xcf, 0xe8], false),
+    (0x5377, &[0xcf, 0xe9], false),
+    (0x5708, &[0xcf, 0xea], false),
+    (0x62f3, &[0xcf, 0xeb], false),
+    (0x6372, &[0xcf, 0xec], false),
+    (0x6b0a, &[0xcf, 0xed], false),
+    (0x6dc3, &[0xcf, 0xee], false),
+    (0x7737, &[0xcf, 0xef], false),
+    (0x53a5, &[0xcf, 0xf0], false),
+    (0x7357, &[0xcf, 0xf1], false),
+    (0x8568, &[0xcf, 0xf2], false),
+    (0x8e76, &[0xcf, 0xf3], false),
+    (0x95d5, &[0xcf, 0xf4], false),
+    (0x673a, &[0xcf, 0xf5], false),
+    (0x6ac3, &[0xcf, 0xf6], false),
+    (0x6f70, &[0xcf, 0xf7], false),
+    (0x8a6d, &[0xcf, 0xf8], false),
+    (0x8ecc, &[0xcf, 0xf9], false),
+    (0x994b, &[0xcf, 0xfa], false),
+    (0xf906, &[0xcf, 0xfb], false),
+    (0x6677, &[0xcf, 0xfc], false),
+    (0x6b78, &[0xcf, 0xfd], false),
+    (0x8cb4, &[0xcf, 0xfe], false),
+    (0x9b3c, &[0xd0, 0xa1], false),
+    (0xf907, &[0xd0, 0xa2], false),
+    (0x53eb, &[0xd0, 0xa3], false),
+    (0x572d, &[0xd0, 0xa4], false),
+    (0x594e, &[0xd0, 0xa5], false),
+    (0x63c6, &[0xd0, 0xa6], false),
+    (0x69fb, &[0xd0, 0xa7], false),
+    (0x73ea, &[0xd0, 0xa8], false),
+    (0x7845, &[0xd0, 0xa9], false),
+    (0x7aba, &[0xd0, 0xaa], false),
+    (0x7ac5, &[0xd0, 0xab], false),
+    (0x7cfe, &[0xd0, 0xac], false),
+    (0x8475, &[0xd0, 0xad], false),
+    (0x898f, &[0xd0, 0xae], false),
+    (0x8d73, &[0xd0, 0xaf], false),
+    (0x9035, &[0xd0, 0xb0], false),
+    (0x95a8, &[0xd0, 0xb1], false),
+    (0x52fb, &[0xd0, 0xb2], false),
+    (0x5747, &[0xd0, 0xb3], false),
+    (0x7547, &[0xd0, 0xb4], false),
+    (0x7b60, &[0xd0, 0xb5], false),
+    (0x83cc, &[0xd0, 0xb6], false),
+    (0x921e, &[0xd0, 0xb7], false),
+    (0xf908, &[0xd0, 0xb8], false),
+    (0x6a58, &[0xd0, 0xb9], false),
+    (0x514b, &[0xd0, 0xba], false),
+    (0x524b, &[0xd0, 0xbb], false),
+    (0x5287, &[0xd0, 0xbc], false),
+    (0x621f, &[0xd0, 0xbd], false),
+    (0x68d8, &[0xd0, 0xbe], false),
+    (0x6975, &[0xd0, 0xbf], false),
+    (0x9699, &[0xd0, 0xc0], false),
+    (0x50c5, &[0xd0, 0xc1], false),
+    (0x52a4, &[0xd0, 0xc2], false),
+    (0x52e4, &[0xd0, 0xc3], false),
+    (0x61c3, &[0xd0, 0xc4], false),
+    (0x65a4, &[0xd0, 0xc5], false),
+    (0x6839, &[0xd0, 0xc6], false),
+    (0x69ff, &[0xd0, 0xc7], false),
+    (0x747e, &[0xd0, 0xc8], false),
+    (0x7b4b, &[0xd0, 0xc9], false),
+    (0x82b9, &[0xd0, 0xca], false),
+    (0x83eb, &[0xd0, 0xcb], false),
+    (0x89b2, &[0xd0, 0xcc], false),
+    (0x8b39, &[0xd0, 0xcd], false),
+    (0x8fd1, &[0xd0, 0xce], false),
+    (0x9949, &[0xd0, 0xcf], false),
+    (0xf909, &[0xd0, 0xd0], false),
+    (0x4eca, &[0xd0, 0xd1], false),
+    (0x5997, &[0xd0, 0xd2], false),
+    (0x64d2, &[0xd0, 0xd3], false),
+    (0x6611, &[0xd0, 0xd4], false),
+    (0x6a8e, &[0xd0, 0xd5], false),
+    (0x7434, &[0xd0, 0xd6], false),
+    (0x7981, &[0xd0, 0xd7], false),
+    (0x79bd, &[0xd0, 0xd8], false),
+    (0x82a9, &[0xd0, 0xd9], false),
+    (0x887e, &[0xd0, 0xda], false),
+    (0x887f, &[0xd0, 0xdb], false),
+    (0x895f, &[0xd0, 0xdc], false),
+    (0xf90a, &[0xd0, 0xdd], false),
+    (0x9326, &[0xd0, 0xde], false),
+    (0x4f0b, &[0xd0, 0xdf], false),
+    (0x53ca, &[0xd0, 0xe0], false),
+    (0x6025, &[0xd0, 0xe1], false),
+    (0x6271, &[0xd0, 0xe2], false),
+    (0x6c72, &[0xd0, 0xe3], false),
+    (0x7d1a, &[0xd0, 0xe4], false),
+    (0x7d66, &[0xd0, 0xe5], false),
+    (0x4e98, &[0xd0, 0xe6], false),
+    (0x5162, &[0xd0, 0xe7], false),
+    (0x77dc, &[0xd0, 0xe8], false),
+    (0x80af, &[0xd0, 0xe9], false),
+    (0x4f01, &[0xd0, 0xea], false),
+    (0x4f0e, &[0xd0, 0xeb], false),
+    (0x5176, &[0xd0, 0xec], false),
+    (0x5180, &[0xd0, 0xed], false),
+    (0x55dc, &[0xd0, 0xee], false),
+    (0x5668, &[0xd0, 0xef], false),
+    (0x573b, &[0xd0, 0xf0], false),
+    (0x57fa, &[0xd0, 0xf1], false),
+    (0x57fc, &[0xd0, 0xf2], false),
+    (0x5914, &[0xd0, 0xf3], false),
+    (0x5947, &[0xd0, 0xf4], false),
+    (0x5993, &[0xd0, 0xf5], false),
+    (0x5bc4, &[0xd0, 0xf6], false),
+    (0x5c90, &[0xd0, 0xf7], false),
+    (0x5d0e, &[0xd0, 0xf8], false),
+    (0x5df1, &[0xd0, 0xf9], false),
+    (0x5e7e, &[0xd0, 0xfa], false),
+    (0x5fcc, &[0xd0, 0xfb], false),
+    (0x6280, &[0xd0, 0xfc], false),
+    (0x65d7, &[0xd0, 0xfd], false),
+    (0x65e3, &[0xd0, 0xfe], false),
+    (0x671e, &[0xd1, 0xa1], false),
+    (0x671f, &[0xd1, 0xa2], false),
+    (0x675e, &[0xd1, 0xa3], false),
+    (0x68cb, &[0xd1, 0xa4], false),
+    (0x68c4, &[0xd1, 0xa5], false),
+    (0x6a5f, &[0xd1, 0xa6], false),
+    (0x6b3a, &[0xd1, 0xa7], false),
+    (0x6c23, &[0xd1, 0xa8], false),
+    (0x6c7d, &[0xd1, 0xa9], false),
+    (0x6c82, &[0xd1, 0xaa], false),
+    (0x6dc7, &[0xd1, 0xab], false),
+    (0x7398, &[0xd1, 0xac], false),
+    (0x7426, &[0xd1, 0xad], false),
+    (0x742a, &[0xd1, 0xae], false),
+    (0x7482, &[0xd1, 0xaf], false),
+    (0x74a3, &[0xd1, 0xb0], false),
+    (0x7578, &[0xd1, 0xb1], false),
+    (0x757f, &[0xd1, 0xb2], false),
+    (0x7881, &[0xd1, 0xb3], false),
+    (0x78ef, &[0xd1, 0xb4], false),
+    (0x7941, &[0xd1, 0xb5], false),
+    (0x7947, &[0xd1, 0xb6], false),
+    (0x7948, &[0xd1, 0xb7], false),
+    (0x797a, &[0xd1, 0xb8], false),
+    (0x7b95, &[0xd1, 0xb9], false),
+    (0x7d00, &[0xd1, 0xba], false),
+    (0x7dba, &[0xd1, 0xbb], false),
+    (0x7f88, &[0xd1, 0xbc], false),
+    (0x8006, &[0xd1, 0xbd], false),
+    (0x802d, &[0xd1, 0xbe], false),
+    (0x808c, &[0xd1, 0xbf], false),
+    (0x8a18, &[0xd1, 0xc0], false),
+    (0x8b4f, &[0xd1, 0xc1], false),
+    (0x8c48, &[0xd1, 0xc2], false),
+    (0x8d77, &[0xd1, 0xc3], false),
+    (0x9321, &[0xd1, 0xc4], false),
+    (0x9324, &[0xd1, 0xc5], false),
+    (0x98e2, &[0xd1, 0xc6], false),
+    (0x9951, &[0xd1, 0xc7], false),
+    (0x9a0e, &[0xd1, 0xc8], false),
+    (0x9a0f, &[0xd1, 0xc9], false),
+    (0x9a65, &[0xd1, 0xca], false),
+    (0x9e92, &[0xd1, 0xcb], false),
+    (0x7dca, &[0xd1, 0xcc], false),
+    (0x4f76, &[0xd1, 0xcd], false),
+    (0x5409, &[0xd1, 0xce], false),
+    (0x62ee, &[0xd1, 0xcf], false),
+    (0x6854, &[0xd1, 0xd0], false),
+    (0x91d1, &[0xd1, 0xd1], false),
+    (0x55ab, &[0xd1, 0xd2], false),
+    (0x513a, &[0xd1, 0xd3], false),
+    (0xf90b, &[0xd1, 0xd4], false),
+    (0xf90c, &[0xd1, 0xd5], false),
+    (0x5a1c, &[0xd1, 0xd6], false),
+    (0x61e6, &[0xd1, 0xd7], false),
+    (0xf90d, &[0xd1, 0xd8], false),
+    (0x62cf, &[0xd1, 0xd9], false),
+    (0x62ff, &[0xd1, 0xda], false),
+    (0xf90e, &[0xd1, 0xdb], false),
+    (0xf90f, &[0xd1, 0xdc], false),
+    (0xf910, &[0xd1, 0xdd], false),
+    (0xf911, &[0xd1, 0xde], false),
+    (0xf912, &[0xd1, 0xdf], false),
+    (0xf913, &[0xd1, 0xe0], false),
+    (0x90a3, &[0xd1, 0xe1], false),
+    (0xf914, &[0xd1, 0xe2], false),
+    (0xf915, &[0xd1, 0xe3], false),
+    (0xf916, &[0xd1, 0xe4], false),
+    (0xf917, &[0xd1, 0xe5], false),
+    (0xf918, &[0xd1, 0xe6], false),
+    (0x8afe, &[0xd1, 0xe7], false),
+    (0xf919, &[0xd1, 0xe8], false),
+    (0xf91a, &[0xd1, 0xe9], false),
+    (0xf91b, &[0xd1, 0xea], false),
+    (0xf91c, &[0xd1, 0xeb], false),
+    (0x6696, &[0xd1, 0xec], false),
+    (0xf91d, &[0xd1, 0xed], false),
+    (0x7156, &[0xd1, 0xee], false),
+    (0xf91e, &[0xd1, 0xef], false),
+    (0xf91f, &[0xd1, 0xf0], false),
+    (0x96e3, &[0xd1, 0xf1], false),
+    (0xf920, &[0xd1, 0xf2], false),
+    (0x634f, &[0xd1, 0xf3], false),
+    (0x637a, &[0xd1, 0xf4], false),
+    (0x5357, &[0xd1, 0xf5], false),
+    (0xf921, &[0xd1, 0xf6], false),
+    (0x678f, &[0xd1, 0xf7], false),
+    (0x6960, &[0xd1, 0xf8], false),
+    (0x6e73, &[0xd1, 0xf9], false),
+    (0xf922, &[0xd1, 0xfa], false),
+    (0x7537, &[0xd1, 0xfb], false),
+    (0xf923, &[0xd1, 0xfc], false),
+    (0xf924, &[0xd1, 0xfd], false),
+    (0xf925, &[0xd1, 0xfe], false),
+    (0x7d0d, &[0xd2, 0xa1], false),
+    (0xf926, &[0xd2, 0xa2], false),
+    (0xf927, &[0xd2, 0xa3], false),
+    (0x8872, &[0xd2, 0xa4], false),
+    (0x56ca, &[0xd2, 0xa5], false),
+    (0x5a18, &[0xd2, 0xa6], false),
+    (0xf928, &[0xd2, 0xa7], false),
+    (0xf929, &[0xd2, 0xa8], false),
+    (0xf92a, &[0xd2, 0xa9], false),
+    (0xf92b, &[0xd2, 0xaa], false),
+    (0xf92c, &[0xd2, 0xab], false),
+    (0x4e43, &[0xd2, 0xac], false),
+    (0xf92d, &[0xd2, 0xad], false),
+    (0x5167, &[0xd2, 0xae], false),
+    (0x5948, &[0xd2, 0xaf], false),
+    (0x67f0, &[0xd2, 0xb0], false),
+    (0x8010, &[0xd2, 0xb1], false),
+    (0xf92e, &[0xd2, 0xb2], false),
+    (0x5973, &[0xd2, 0xb3], false),
+    (0x5e74, &[0xd2, 0xb4], false),
+    (0x649a, &[0xd2, 0xb5], false),
+    (0x79ca, &[0xd2, 0xb6], false),
+    (0x5ff5, &[0xd2, 0xb7], false),
+    (0x606c, &[0xd2, 0xb8], false),
+    (0x62c8, &[0xd2, 0xb9], false),
+    (0x637b, &[0xd2, 0xba], false),
+    (0x5be7, &[0xd2, 0xbb], false),
+    (0x5bd7, &[0xd2, 0xbc], false),
+    (0x52aa, &[0xd2, 0xbd], false),
+    (0xf92f, &[0xd2, 0xbe], false),
+    (0x5974, &[0xd2, 0xbf], false),
+    (0x5f29, &[0xd2, 0xc0], false),
+    (0x6012, &[0xd2, 0xc1], false),
+    (0xf930, &[0xd2, 0xc2], false),
+    (0xf931, &[0xd2, 0xc3], false),
+    (0xf932, &[0xd2, 0xc4], false),
+    (0x7459, &[0xd2, 0xc5], false),
+    (0xf933, &[0xd2, 0xc6], false),
+    (0xf934, &[0xd2, 0xc7], false),
+    (0xf935, &[0xd2, 0xc8], false),
+    (0xf936, &[0xd2, 0xc9], false),
+    (0xf937, &[0xd2, 0xca], false),
+    (0xf938, &[0xd2, 0xcb], false),
+    (0x99d1, &[0xd2, 0xcc], false),
+    (0xf939, &[0xd2, 0xcd], false),
+    (0xf93a, &[0xd2, 0xce], false),
+    (0xf93b, &[0xd2, 0xcf], false),
+    (0xf93c, &[0xd2, 0xd0], false),
+    (0xf93d, &[0xd2, 0xd1], false),
+    (0xf93e, &[0xd2, 0xd2], false),
+    (0xf93f, &[0xd2, 0xd3], false),
+    (0xf940, &[0xd2, 0xd4], false),
+    (0xf941, &[0xd2, 0xd5], false),
+    (0xf942, &[0xd2, 0xd6], false),
+    (0xf943, &[0xd2, 0xd7], false),
+    (0x6fc3, &[0xd2, 0xd8], false),
+    (0xf944, &[0xd2, 0xd9], false),
+    (0xf945, &[0xd2, 0xda], false),
+    (0x81bf, &[0xd2, 0xdb], false),
+    (0x8fb2, &[0xd2, 0xdc], false),
+    (0x60f1, &[0xd2, 0xdd], false),
+    (0xf946, &[0xd2, 0xde], false),
+    (0xf947, &[0xd2, 0xdf], false),
+    (0x8166, &[0xd2, 0xe0], false),
+    (0xf948, &[0xd2, 0xe1], false),
+    (0xf949, &[0xd2, 0xe2], false),
+    (0x5c3f, &[0xd2, 0xe3], false),
+    (0xf94a, &[0xd2, 0xe4], false),
+    (0xf94b, &[0xd2, 0xe5], false),
+    (0xf94c, &[0xd2, 0xe6], false),
+    (0xf94d, &[0xd2, 0xe7], false),
+    (0xf94e, &[0xd2, 0xe8], false),
+    (0xf94f, &[0xd2, 0xe9], false),
+    (0xf950, &[0xd2, 0xea], false),
+    (0xf951, &[0xd2, 0xeb], false),
+    (0x5ae9, &[0xd2, 0xec], false),
+    (0x8a25, &[0xd2, 0xed], false),
+    (0x677b, &[0xd2, 0xee], false),
+    (0x7d10, &[0xd2, 0xef], false),
+    (0xf952, &[0xd2, 0xf0], false),
+    (0xf953, &[0xd2, 0xf1], false),
+    (0xf954, &[0xd2, 0xf2], false),
+    (0xf955, &[0xd2, 0xf3], false),
+    (0xf956, &[0xd2, 0xf4], false),
+    (0xf957, &[0xd2, 0xf5], false),
+    (0x80fd, &[0xd2, 0xf6], false),
+    (0xf958, &[0xd2, 0xf7], false),
+    (0xf959, &[0xd2, 0xf8], false),
+    (0x5c3c, &[0xd2, 0xf9], false),
+    (0x6ce5, &[0xd2, 0xfa], false),
+    (0x533f, &[0xd2, 0xfb], false),
+    (0x6eba, &[0xd2, 0xfc], false),
+    (0x591a, &[0xd2, 0xfd], false),
+    (0x8336, &[0xd2, 0xfe], false),
+    (0x4e39, &[0xd3, 0xa1], false),
+    (0x4eb6, &[0xd3, 0xa2], false),
+    (0x4f46, &[0xd3, 0xa3], false),
+    (0x55ae, &[0xd3, 0xa4], false),
+    (0x5718, &[0xd3, 0xa5], false),
+    (0x58c7, &[0xd3, 0xa6], false),
+    (0x5f56, &[0xd3, 0xa7], false),
+    (0x65b7, &[0xd3, 0xa8], false),
+    (0x65e6, &[0xd3, 0xa9], false),
+    (0x6a80, &[0xd3, 0xaa], false),
+    (0x6bb5, &[0xd3, 0xab], false),
+    (0x6e4d, &[0xd3, 0xac], false),
+    (0x77ed, &[0xd3, 0xad], false),
+    (0x7aef, &[0xd3, 0xae], false),
+    (0x7c1e, &[0xd3, 0xaf], false),
+    (0x7dde, &[0xd3, 0xb0], false),
+    (0x86cb, &[0xd3, 0xb1], false),
+    (0x8892, &[0xd3, 0xb2], false),
+    (0x9132, &[0xd3, 0xb3], false),
+    (0x935b, &[0xd3, 0xb4], false),
+    (0x64bb, &[0xd3, 0xb5], false),
+    (0x6fbe, &[0xd3, 0xb6], false),
+    (0x737a, &[0xd3, 0xb7], false),
+    (0x75b8, &[0xd3, 0xb8], false),
+    (0x9054, &[0xd3, 0xb9], false),
+    (0x5556, &[0xd3, 0xba], false),
+    (0x574d, &[0xd3, 0xbb], false),
+    (0x61ba, &[0xd3, 0xbc], false),
+    (0x64d4, &[0xd3, 0xbd], false),
+    (0x66c7, &[0xd3, 0xbe], false),
+    (0x6de1, &[0xd3, 0xbf], false),
+    (0x6e5b, &[0xd3, 0xc0], false),
+    (0x6f6d, &[0xd3, 0xc1], false),
+    (0x6fb9, &[0xd3, 0xc2], false),
+    (0x75f0, &[0xd3, 0xc3], false),
+    (0x8043, &[0xd3, 0xc4], false),
+    (0x81bd, &[0xd3, 0xc5], false),
+    (0x8541, &[0xd3, 0xc6], false),
+    (0x8983, &[0xd3, 0xc7], false),
+    (0x8ac7, &[0xd3, 0xc8], false),
+    (0x8b5a, &[0xd3, 0xc9], false),
+    (0x931f, &[0xd3, 0xca], false),
+    (0x6c93, &[0xd3, 0xcb], false),
+    (0x7553, &[0xd3, 0xcc], false),
+    (0x7b54, &[0xd3, 0xcd], false),
+    (0x8e0f, &[0xd3, 0xce], false),
+    (0x905d, &[0xd3, 0xcf], false),
+    (0x5510, &[0xd3, 0xd0], false),
+    (0x5802, &[0xd3, 0xd1], false),
+    (0x5858, &[0xd3, 0xd2], false),
+    (0x5e62, &[0xd3, 0xd3], false),
+    (0x6207, &[0xd3, 0xd4], false),
+    (0x649e, &[0xd3, 0xd5], false),
+    (0x68e0, &[0xd3, 0xd6], false),
+    (0x7576, &[0xd3, 0xd7], false),
+    (0x7cd6, &[0xd3, 0xd8], false),
+    (0x87b3, &[0xd3, 0xd9], false),
+    (0x9ee8, &[0xd3, 0xda], false),
+    (0x4ee3, &[0xd3, 0xdb], false),
+    (0x5788, &[0xd3, 0xdc], false),
+    (0x576e, &[0xd3, 0xdd], false),
+    (0x5927, &[0xd3, 0xde], false),
+    (0x5c0d, &[0xd3, 0xdf], false),
+    (0x5cb1, &[0xd3, 0xe0], false),
+    (0x5e36, &[0xd3, 0xe1], false),
+    (0x5f85, &[0xd3, 0xe2], false),
+    (0x6234, &[0xd3, 0xe3], false),
+    (0x64e1, &[0xd3, 0xe4], false),
+    (0x73b3, &[0xd3, 0xe5], false),
+    (0x81fa, &[0xd3, 0xe6], false),
+    (0x888b, &[0xd3, 0xe7], false),
+    (0x8cb8, &[0xd3, 0xe8], false),
+    (0x968a, &[0xd3, 0xe9], false),
+    (0x9edb, &[0xd3, 0xea], false),
+    (0x5b85, &[0xd3, 0xeb], false),
+    (0x5fb7, &[0xd3, 0xec], false),
+    (0x60b3, &[0xd3, 0xed], false),
+    (0x5012, &[0xd3, 0xee], false),
+    (0x5200, &[0xd3, 0xef], false),
+    (0x5230, &[0xd3, 0xf0], false),
+    (0x5716, &[0xd3, 0xf1], false),
+    (0x5835, &[0xd3, 0xf2], false),
+    (0x5857, &[0xd3, 0xf3], false),
+    (0x5c0e, &[0xd3, 0xf4], false),
+    (0x5c60, &[0xd3, 0xf5], false),
+    (0x5cf6, &[0xd3, 0xf6], false),
+    (0x5d8b, &[0xd3, 0xf7], false),
+    (0x5ea6, &[0xd3, 0xf8], false),
+    (0x5f92, &[0xd3, 0xf9], false),
+    (0x60bc, &[0xd3, 0xfa], false),
+    (0x6311, &[0xd3, 0xfb], false),
+    (0x6389, &[0xd3, 0xfc], false),
+    (0x6417, &[0xd3, 0xfd], false),
+    (0x6843, &[0xd3, 0xfe], false),
+    (0x68f9, &[0xd4, 0xa1], false),
+    (0x6ac2, &[0xd4, 0xa2], false),
+    (0x6dd8, &[0xd4, 0xa3], false),
+    (0x6e21, &[0xd4, 0xa4], false),
+    (0x6ed4, &[0xd4, 0xa5], false),
+    (0x6fe4, &[0xd4, 0xa6], false),
+    (0x71fe, &[0xd4, 0xa7], false),
+    (0x76dc, &[0xd4, 0xa8], false),
+    (0x7779, &[0xd4, 0xa9], false),
+    (0x79b1, &[0xd4, 0xaa], false),
+    (0x7a3b, &[0xd4, 0xab], false),
+    (0x8404, &[0xd4, 0xac], false),
+    (0x89a9, &[0xd4, 0xad], false),
+    (0x8ced, &[0xd4, 0xae], false),
+    (0x8df3, &[0xd4, 0xaf], false),
+    (0x8e48, &[0xd4, 0xb0], false),
+    (0x9003, &[0xd4, 0xb1], false),
+    (0x9014, &[0xd4, 0xb2], false),
+    (0x9053, &[0xd4, 0xb3], false),
+    (0x90fd, &[0xd4, 0xb4], false),
+    (0x934d, &[0xd4, 0xb5], false),
+    (0x9676, &[0xd4, 0xb6], false),
+    (0x97dc, &[0xd4, 0xb7], false),
+    (0x6bd2, &[0xd4, 0xb8], false),
+    (0x7006, &[0xd4, 0xb9], false),
+    (0x7258, &[0xd4, 0xba], false),
+    (0x72a2, &[0xd4, 0xbb], false),
+    (0x7368, &[0xd4, 0xbc], false),
+    (0x7763, &[0xd4, 0xbd], false),
+    (0x79bf, &[0xd4, 0xbe], false),
+    (0x7be4, &[0xd4, 0xbf], false),
+    (0x7e9b, &[0xd4, 0xc0], false),
+    (0x8b80, &[0xd4, 0xc1], false),
+    (0x58a9, &[0xd4, 0xc2], false),
+    (0x60c7, &[0xd4, 0xc3], false),
+    (0x6566, &[0xd4, 0xc4], false),
+    (0x65fd, &[0xd4, 0xc5], false),
+    (0x66be, &[0xd4, 0xc6], false),
+    (0x6c8c, &[0xd4, 0xc7], false),
+    (0x711e, &[0xd4, 0xc8], false),
+    (0x71c9, &[0xd4, 0xc9], false),
+    (0x8c5a, &[0xd4, 0xca], false),
+    (0x9813, &[0xd4, 0xcb], false),
+    (0x4e6d, &[0xd4, 0xcc], false),
+    (0x7a81, &[0xd4, 0xcd], false),
+    (0x4edd, &[0xd4, 0xce], false),
+    (0x51ac, &[0xd4, 0xcf], false),
+    (0x51cd, &[0xd4, 0xd0], false),
+    (0x52d5, &[0xd4, 0xd1], false),
+    (0x540c, &[0xd4, 0xd2], false),
+    (0x61a7, &[0xd4, 0xd3], false),
+    (0x6771, &[0xd4, 0xd4], false),
+    (0x6850, &[0xd4, 0xd5], false),
+    (0x68df, &[0xd4, 0xd6], false),
+    (0x6d1e, &[0xd4, 0xd7], false),
+    (0x6f7c, &[0xd4, 0xd8], false),
+    (0x75bc, &[0xd4, 0xd9], false),
+    (0x77b3, &[0xd4, 0xda], false),
+    (0x7ae5, &[0xd4, 0xdb], false),
+    (0x80f4, &[0xd4, 0xdc], false),
+    (0x8463, &[0xd4, 0xdd], false),
+    (0x9285, &[0xd4, 0xde], false),
+    (0x515c, &[0xd4, 0xdf], false),
+    (0x6597, &[0xd4, 0xe0], false),
+    (0x675c, &[0xd4, 0xe1], false),
+    (0x6793, &[0xd4, 0xe2], false),
+    (0x75d8, &[0xd4, 0xe3], false),
+    (0x7ac7, &[0xd4, 0xe4], false),
+    (0x8373, &[0xd4, 0xe5], false),
+    (0xf95a, &[0xd4, 0xe6], false),
+    (0x8c46, &[0xd4, 0xe7], false),
+    (0x9017, &[0xd4, 0xe8], false),
+    (0x982d, &[0xd4, 0xe9], false),
+    (0x5c6f, &[0xd4, 0xea], false),
+    (0x81c0, &[0xd4, 0xeb], false),
+    (0x829a, &[0xd4, 0xec], false),
+    (0x9041, &[0xd4, 0xed], false),
+    (0x906f, &[0xd4, 0xee], false),
+    (0x920d, &[0xd4, 0xef], false),
+    (0x5f97, &[0xd4, 0xf0], false),
+    (0x5d9d, &[0xd4, 0xf1], false),
+    (0x6a59, &[0xd4, 0xf2], false),
+    (0x71c8, &[0xd4, 0xf3], false),
+    (0x767b, &[0xd4, 0xf4], false),
+    (0x7b49, &[0xd4, 0xf5], false),
+    (0x85e4, &[0xd4, 0xf6], false),
+    (0x8b04, &[0xd4, 0xf7], false),
+    (0x9127, &[0xd4, 0xf8], false),
+    (0x9a30, &[0xd4, 0xf9], false),
+    (0x5587, &[0xd4, 0xfa], false),
+    (0x61f6, &[0xd4, 0xfb], false),
+    (0xf95b, &[0xd4, 0xfc], false),
+    (0x7669, &[0xd4, 0xfd], false),
+    (0x7f85, &[0xd4, 0xfe], false),
+    (0x863f, &[0xd5, 0xa1], false),
+    (0x87ba, &[0xd5, 0xa2], false),
+    (0x88f8, &[0xd5, 0xa3], false),
+    (0x908f, &[0xd5, 0xa4], false),
+    (0xf95c, &[0xd5, 0xa5], false),
+    (0x6d1b, &[0xd5, 0xa6], false),
+    (0x70d9, &[0xd5, 0xa7], false),
+    (0x73de, &[0xd5, 0xa8], false),
+    (0x7d61, &[0xd5, 0xa9], false),
+    (0x843d, &[0xd5, 0xaa], false),
+    (0xf95d, &[0xd5, 0xab], false),
+    (0x916a, &[0xd5, 0xac], false),
+    (0x99f1, &[0xd5, 0xad], false),
+    (0xf95e, &[0xd5, 0xae], false),
+    (0x4e82, &[0xd5, 0xaf], false),
+    (0x5375, &[0xd5, 0xb0], false),
+    (0x6b04, &[0xd5, 0xb1], false),
+    (0x6b12, &[0xd5, 0xb2], false),
+    (0x703e, &[0xd5, 0xb3], false),
+    (0x721b, &[0xd5, 0xb4], false),
+    (0x862d, &[0xd5, 0xb5], false),
+    (0x9e1e, &[0xd5, 0xb6], false),
+    (0x524c, &[0xd5, 0xb7], false),
+    (0x8fa3, &[0xd5, 0xb8], false),
+    (0x5d50, &[0xd5, 0xb9], false),
+    (0x64e5, &[0xd5, 0xba], false),
+    (0x652c, &[0xd5, 0xbb], false),
+    (0x6b16, &[0xd5, 0xbc], false),
+    (0x6feb, &[0xd5, 0xbd], false),
+    (0x7c43, &[0xd5, 0xbe], false),
+    (0x7e9c, &[0xd5, 0xbf], false),
+    (0x85cd, &[0xd5, 0xc0], false),
+    (0x8964, &[0xd5, 0xc1], false),
+    (0x89bd, &[0xd5, 0xc2], false),
+    (0x62c9, &[0xd5, 0xc3], false),
+    (0x81d8, &[0xd5, 0xc4], false),
+    (0x881f, &[0xd5, 0xc5], false),
+    (0x5eca, &[0xd5, 0xc6], false),
+    (0x6717, &[0xd5, 0xc7], false),
+    (0x6d6a, &[0xd5, 0xc8], false),
+    (0x72fc, &[0xd5, 0xc9], false),
+    (0x7405, &[0xd5, 0xca], false),
+    (0x746f, &[0xd5, 0xcb], false),
+    (0x8782, &[0xd5, 0xcc], false),
+    (0x90de, &[0xd5, 0xcd], false),
+    (0x4f86, &[0xd5, 0xce], false),
+    (0x5d0d, &[0xd5, 0xcf], false),
+    (0x5fa0, &[0xd5, 0xd0], false),
+    (0x840a, &[0xd5, 0xd1], false),
+    (0x51b7, &[0xd5, 0xd2], false),
+    (0x63a0, &[0xd5, 0xd3], false),
+    (0x7565, &[0xd5, 0xd4], false),
+    (0x4eae, &[0xd5, 0xd5], false),
+    (0x5006, &[0xd5, 0xd6], false),
+    (0x5169, &[0xd5, 0xd7], false),
+    (0x51c9, &[0xd5, 0xd8], false),
+    (0x6881, &[0xd5, 0xd9], false),
+    (0x6a11, &[0xd5, 0xda], false),
+    (0x7cae, &[0xd5, 0xdb], false),
+    (0x7cb1, &[0xd5, 0xdc], false),
+    (0x7ce7, &[0xd5, 0xdd], false),
+    (0x826f, &[0xd5, 0xde], false),
+    (0x8ad2, &[0xd5, 0xdf], false),
+    (0x8f1b, &[0xd5, 0xe0], false),
+    (0x91cf, &[0xd5, 0xe1], false),
+    (0x4fb6, &[0xd5, 0xe2], false),
+    (0x5137, &[0xd5, 0xe3], false),
+    (0x52f5, &[0xd5, 0xe4], false),
+    (0x5442, &[0xd5, 0xe5], false),
+    (0x5eec, &[0xd5, 0xe6], false),
+    (0x616e, &[0xd5, 0xe7], false),
+    (0x623e, &[0xd5, 0xe8], false),
+    (0x65c5, &[0xd5, 0xe9], false),
+    (0x6ada, &[0xd5, 0xea], false),
+    (0x6ffe, &[0xd5, 0xeb], false),
+    (0x792a, &[0xd5, 0xec], false),
+    (0x85dc, &[0xd5, 0xed], false),
+    (0x8823, &[0xd5, 0xee], false),
+    (0x95ad, &[0xd5, 0xef], false),
+    (0x9a62, &[0xd5, 0xf0], false),
+    (0x9a6a, &[0xd5, 0xf1], false),
+    (0x9e97, &[0xd5, 0xf2], false),
+    (0x9ece, &[0xd5, 0xf3], false),
+    (0x529b, &[0xd5, 0xf4], false),
+    (0x66c6, &[0xd5, 0xf5], false),
+    (0x6b77, &[0xd5, 0xf6], false),
+    (0x701d, &[0xd5, 0xf7], false),
+    (0x792b, &[0xd5, 0xf8], false),
+    (0x8f62, &[0xd5, 0xf9], false),
+    (0x9742, &[0xd5, 0xfa], false),
+    (0x6190, &[0xd5, 0xfb], false),
+    (0x6200, &[0xd5, 0xfc], false),
+    (0x6523, &[0xd5, 0xfd], false),
+    (0x6f23, &[0xd5, 0xfe], false),
+    (0x7149, &[0xd6, 0xa1], false),
+    (0x7489, &[0xd6, 0xa2], false),
+    (0x7df4, &[0xd6, 0xa3], false),
+    (0x806f, &[0xd6, 0xa4], false),
+    (0x84ee, &[0xd6, 0xa5], false),
+    (0x8f26, &[0xd6, 0xa6], false),
+    (0x9023, &[0xd6, 0xa7], false),
+    (0x934a, &[0xd6, 0xa8], false),
+    (0x51bd, &[0xd6, 0xa9], false),
+    (0x5217, &[0xd6, 0xaa], false),
+    (0x52a3, &[0xd6, 0xab], false),
+    (0x6d0c, &[0xd6, 0xac], false),
+    (0x70c8, &[0xd6, 0xad], false),
+    (0x88c2, &[0xd6, 0xae], false),
+    (0x5ec9, &[0xd6, 0xaf], false),
+    (0x6582, &[0xd6, 0xb0], false),
+    (0x6bae, &[0xd6, 0xb1], false),
+    (0x6fc2, &[0xd6, 0xb2], false),
+    (0x7c3e, &[0xd6, 0xb3], false),
+    (0x7375, &[0xd6, 0xb4], false),
+    (0x4ee4, &[0xd6, 0xb5], false),
+    (0x4f36, &[0xd6, 0xb6], false),
+    (0x56f9, &[0xd6, 0xb7], false),
+    (0xf95f, &[0xd6, 0xb8], false),
+    (0x5cba, &[0xd6, 0xb9], false),
+    (0x5dba, &[0xd6, 0xba], false),
+    (0x601c, &[0xd6, 0xbb], false),
+    (0x73b2, &[0xd6, 0xbc], false),
+    (0x7b2d, &[0xd6, 0xbd], false),
+    (0x7f9a, &[0xd6, 0xbe], false),
+    (0x7fce, &[0xd6, 0xbf], false),
+    (0x8046, &[0xd6, 0xc0], false),
+    (0x901e, &[0xd6, 0xc1], false),
+    (0x9234, &[0xd6, 0xc2], false),
+    (0x96f6, &[0xd6, 0xc3], false),
+    (0x9748, &[0xd6, 0xc4], false),
+    (0x9818, &[0xd6, 0xc5], false),
+    (0x9f61, &[0xd6, 0xc6], false),
+    (0x4f8b, &[0xd6, 0xc7], false),
+    (0x6fa7, &[0xd6, 0xc8], false),
+    (0x79ae, &[0xd6, 0xc9], false),
+    (0x91b4, &[0xd6, 0xca], false),
+    (0x96b7, &[0xd6, 0xcb], false),
+    (0x52de, &[0xd6, 0xcc], false),
+    (0xf960, &[0xd6, 0xcd], false),
+    (0x6488, &[0xd6, 0xce], false),
+    (0x64c4, &[0xd6, 0xcf], false),
+    (0x6ad3, &[0xd6, 0xd0], false),
+    (0x6f5e, &[0xd6, 0xd1], false),
+    (0x7018, &[0xd6, 0xd2], false),
+    (0x7210, &[0xd6, 0xd3], false),
+    (0x76e7, &[0xd6, 0xd4], false),
+    (0x8001, &[0xd6, 0xd5], false),
+    (0x8606, &[0xd6, 0xd6], false),
+    (0x865c, &[0xd6, 0xd7], false),
+    (0x8def, &[0xd6, 0xd8], false),
+    (0x8f05, &[0xd6, 0xd9], false),
+    (0x9732, &[0xd6, 0xda], false),
+    (0x9b6f, &[0xd6, 0xdb], false),
+    (0x9dfa, &[0xd6, 0xdc], false),
+    (0x9e75, &[0xd6, 0xdd], false),
+    (0x788c, &[0xd6, 0xde], false),
+    (0x797f, &[0xd6, 0xdf], false),
+    (0x7da0, &[0xd6, 0xe0], false),
+    (0x83c9, &[0xd6, 0xe1], false),
+    (0x9304, &[0xd6, 0xe2], false),
+    (0x9e7f, &[0xd6, 0xe3], false),
+    (0x9e93, &[0xd6, 0xe4], false),
+    (0x8ad6, &[0xd6, 0xe5], false),
+    (0x58df, &[0xd6, 0xe6], false),
+    (0x5f04, &[0xd6, 0xe7], false),
+    (0x6727, &[0xd6, 0xe8], false),
+    (0x7027, &[0xd6, 0xe9], false),
+    (0x74cf, &[0xd6, 0xea], false),
+    (0x7c60, &[0xd6, 0xeb], false),
+    (0x807e, &[0xd6, 0xec], false),
+    (0x5121, &[0xd6, 0xed], false),
+    (0x7028, &[0xd6, 0xee], false),
+    (0x7262, &[0xd6, 0xef], false),
+    (0x78ca, &[0xd6, 0xf0], false),
+    (0x8cc2, &[0xd6, 0xf1], false),
+    (0x8cda, &[0xd6, 0xf2], false),
+    (0x8cf4, &[0xd6, 0xf3], false),
+    (0x96f7, &[0xd6, 0xf4], false),
+    (0x4e86, &[0xd6, 0xf5], false),
+    (0x50da, &[0xd6, 0xf6], false),
+    (0x5bee, &[0xd6, 0xf7], false),
+    (0x5ed6, &[0xd6, 0xf8], false),
+    (0x6599, &[0xd6, 0xf9], false),
+    (0x71ce, &[0xd6, 0xfa], false),
+    (0x7642, &[0xd6, 0xfb], false),
+    (0x77ad, &[0xd6, 0xfc], false),
+    (0x804a, &[0xd6, 0xfd], false),
+    (0x84fc, &[0xd6, 0xfe], false),
+    (0x907c, &[0xd7, 0xa1], false),
+    (0x9b27, &[0xd7, 0xa2], false),
+    (0x9f8d, &[0xd7, 0xa3], false),
+    (0x58d8, &[0xd7, 0xa4], false),
+    (0x5a41, &[0xd7, 0xa5], false),
+    (0x5c62, &[0xd7, 0xa6], false),
+    (0x6a13, &[0xd7, 0xa7], false),
+    (0x6dda, &[0xd7, 0xa8], false),
+    (0x6f0f, &[0xd7, 0xa9], false),
+    (0x763b, &[0xd7, 0xaa], false),
+    (0x7d2f, &[0xd7, 0xab], false),
+    (0x7e37, &[0xd7, 0xac], false),
+    (0x851e, &[0xd7, 0xad], false),
+    (0x8938, &[0xd7, 0xae], false),
+    (0x93e4, &[0xd7, 0xaf], false),
+    (0x964b, &[0xd7, 0xb0], false),
+    (0x5289, &[0xd7, 0xb1], false),
+    (0x65d2, &[0xd7, 0xb2], false),
+    (0x67f3, &[0xd7, 0xb3], false),
+    (0x69b4, &[0xd7, 0xb4], false),
+    (0x6d41, &[0xd7, 0xb5], false),
+    (0x6e9c, &[0xd7, 0xb6], false),
+    (0x700f, &[0xd7, 0xb7], false),
+    (0x7409, &[0xd7, 0xb8], false),
+    (0x7460, &[0xd7, 0xb9], false),
+    (0x7559, &[0xd7, 0xba], false),
+    (0x7624, &[0xd7, 0xbb], false),
+    (0x786b, &[0xd7, 0xbc], false),
+    (0x8b2c, &[0xd7, 0xbd], false),
+    (0x985e, &[0xd7, 0xbe], false),
+    (0x516d, &[0xd7, 0xbf], false),
+    (0x622e, &[0xd7, 0xc0], false),
+    (0x9678, &[0xd7, 0xc1], false),
+    (0x4f96, &[0xd7, 0xc2], false),
+    (0x502b, &[0xd7, 0xc3], false),
+    (0x5d19, &[0xd7, 0xc4], false),
+    (0x6dea, &[0xd7, 0xc5], false),
+    (0x7db8, &[0xd7, 0xc6], false),
+    (0x8f2a, &[0xd7, 0xc7], false),
+    (0x5f8b, &[0xd7, 0xc8], false),
+    (0x6144, &[0xd7, 0xc9], false),
+    (0x6817, &[0xd7, 0xca], false),
+    (0xf961, &[0xd7, 0xcb], false),
+    (0x9686, &[0xd7, 0xcc], false),
+    (0x52d2, &[0xd7, 0xcd], false),
+    (0x808b, &[0xd7, 0xce], false),
+    (0x51dc, &[0xd7, 0xcf], false),
+    (0x51cc, &[0xd7, 0xd0], false),
+    (0x695e, &[0xd7, 0xd1], false),
+    (0x7a1c, &[0xd7, 0xd2], false),
+    (0x7dbe, &[0xd7, 0xd3], false),
+    (0x83f1, &[0xd7, 0xd4], false),
+    (0x9675, &[0xd7, 0xd5], false),
+    (0x4fda, &[0xd7, 0xd6], false),
+    (0x5229, &[0xd7, 0xd7], false),
+    (0x5398, &[0xd7, 0xd8], false),
+    (0x540f, &[0xd7, 0xd9], false),
+    (0x550e, &[0xd7, 0xda], false),
+    (0x5c65, &[0xd7, 0xdb], false),
+    (0x60a7, &[0xd7, 0xdc], false),
+    (0x674e, &[0xd7, 0xdd], false),
+    (0x68a8, &[0xd7, 0xde], false),
+    (0x6d6c, &[0xd7, 0xdf], false),
+    (0x7281, &[0xd7, 0xe0], false),
+    (0x72f8, &[0xd7, 0xe1], false),
+    (0x7406, &[0xd7, 0xe2], false),
+    (0x7483, &[0xd7, 0xe3], false),
+    (0xf962, &[0xd7, 0xe4], false),
+    (0x75e2, &[0xd7, 0xe5], false),
+    (0x7c6c, &[0xd7, 0xe6], false),
+    (0x7f79, &[0xd7, 0xe7], false),
+    (0x7fb8, &[0xd7, 0xe8], false),
+    (0x8389, &[0xd7, 0xe9], false),
+    (0x88cf, &[0xd7, 0xea], false),
+    (0x88e1, &[0xd7, 0xeb], false),
+    (0x91cc, &[0xd7, 0xec], false),
+    (0x91d0, &[0xd7, 0xed], false),
+    (0x96e2, &[0xd7, 0xee], false),
+    (0x9bc9, &[0xd7, 0xef], false),
+    (0x541d, &[0xd7, 0xf0], false),
+    (0x6f7e, &[0xd7, 0xf1], false),
+    (0x71d0, &[0xd7, 0xf2], false),
+    (0x7498, &[0xd7, 0xf3], false),
+    (0x85fa, &[0xd7, 0xf4], false),
+    (0x8eaa, &[0xd7, 0xf5], false),
+    (0x96a3, &[0xd7, 0xf6], false),
+    (0x9c57, &[0xd7, 0xf7], false),
+    (0x9e9f, &[0xd7, 0xf8], false),
+    (0x6797, &[0xd7, 0xf9], false),
+    (0x6dcb, &[0xd7, 0xfa], false),
+    (0x7433, &[0xd7, 0xfb], false),
+    (0x81e8, &[0xd7, 0xfc], false),
+    (0x9716, &[0xd7, 0xfd], false),
+    (0x782c, &[0xd7, 0xfe], false),
+    (0x7acb, &[0xd8, 0xa1], false),
+    (0x7b20, &[0xd8, 0xa2], false),
+    (0x7c92, &[0xd8, 0xa3], false),
+    (0x6469, &[0xd8, 0xa4], false),
+    (0x746a, &[0xd8, 0xa5], false),
+    (0x75f2, &[0xd8, 0xa6], false),
+    (0x78bc, &[0xd8, 0xa7], false),
+    (0x78e8, &[0xd8, 0xa8], false),
+    (0x99ac, &[0xd8, 0xa9], false),
+    (0x9b54, &[0xd8, 0xaa], false),
+    (0x9ebb, &[0xd8, 0xab], false),
+    (0x5bde, &[0xd8, 0xac], false),
+    (0x5e55, &[0xd8, 0xad], false),
+    (0x6f20, &[0xd8, 0xae], false),
+    (0x819c, &[0xd8, 0xaf], false),
+    (0x83ab, &[0xd8, 0xb0], false),
+    (0x9088, &[0xd8, 0xb1], false),
+    (0x4e07, &[0xd8, 0xb2], false),
+    (0x534d, &[0xd8, 0xb3], false),
+    (0x5a29, &[0xd8, 0xb4], false),
+    (0x5dd2, &[0xd8, 0xb5], false),
+    (0x5f4e, &[0xd8, 0xb6], false),
+    (0x6162, &[0xd8, 0xb7], false),
+    (0x633d, &[0xd8, 0xb8], false),
+    (0x6669, &[0xd8, 0xb9], false),
+    (0x66fc, &[0xd8, 0xba], false),
+    (0x6eff, &[0xd8, 0xbb], false),
+    (0x6f2b, &[0xd8, 0xbc], false),
+    (0x7063, &[0xd8, 0xbd], false),
+    (0x779e, &[0xd8, 0xbe], false),
+    (0x842c, &[0xd8, 0xbf], false),
+    (0x8513, &[0xd8, 0xc0], false),
+    (0x883b, &[0xd8, 0xc1], false),
+    (0x8f13, &[0xd8, 0xc2], false),
+    (0x9945, &[0xd8, 0xc3], false),
+    (0x9c3b, &[0xd8, 0xc4], false),
+    (0x551c, &[0xd8, 0xc5], false),
+    (0x62b9, &[0xd8, 0xc6], false),
+    (0x672b, &[0xd8, 0xc7], false),
+    (0x6cab, &[0xd8, 0xc8], false),
+    (0x8309, &[0xd8, 0xc9], false),
+    (0x896a, &[0xd8, 0xca], false),
+    (0x977a, &[0xd8, 0xcb], false),
+    (0x4ea1, &[0xd8, 0xcc], false),
+    (0x5984, &[0xd8, 0xcd], false),
+    (0x5fd8, &[0xd8, 0xce], false),
+    (0x5fd9, &[0xd8, 0xcf], false),
+    (0x671b, &[0xd8, 0xd0], false),
+    (0x7db2, &[0xd8, 0xd1], false),
+    (0x7f54, &[0xd8, 0xd2], false),
+    (0x8292, &[0xd8, 0xd3], false),
+    (0x832b, &[0xd8, 0xd4], false),
+    (0x83bd, &[0xd8, 0xd5], false),
+    (0x8f1e, &[0xd8, 0xd6], false),
+    (0x9099, &[0xd8, 0xd7], false),
+    (0x57cb, &[0xd8, 0xd8], false),
+    (0x59b9, &[0xd8, 0xd9], false),
+    (0x5a92, &[0xd8, 0xda], false),
+    (0x5bd0, &[0xd8, 0xdb], false),
+    (0x6627, &[0xd8, 0xdc], false),
+    (0x679a, &[0xd8, 0xdd], false),
+    (0x6885, &[0xd8, 0xde], false),
+    (0x6bcf, &[0xd8, 0xdf], false),
+    (0x7164, &[0xd8, 0xe0], false),
+    (0x7f75, &[0xd8, 0xe1], false),
+    (0x8cb7, &[0xd8, 0xe2], false),
+    (0x8ce3, &[0xd8, 0xe3], false),
+    (0x9081, &[0xd8, 0xe4], false),
+    (0x9b45, &[0xd8, 0xe5], false),
+    (0x8108, &[0xd8, 0xe6], false),
+    (0x8c8a, &[0xd8, 0xe7], false),
+    (0x964c, &[0xd8, 0xe8], false),
+    (0x9a40, &[0xd8, 0xe9], false),
+    (0x9ea5, &[0xd8, 0xea], false),
+    (0x5b5f, &[0xd8, 0xeb], false),
+    (0x6c13, &[0xd8, 0xec], false),
+    (0x731b, &[0xd8, 0xed], false),
+    (0x76f2, &[0xd8, 0xee], false),
+    (0x76df, &[0xd8, 0xef], false),
+    (0x840c, &[0xd8, 0xf0], false),
+    (0x51aa, &[0xd8, 0xf1], false),
+    (0x8993, &[0xd8, 0xf2], false),
+    (0x514d, &[0xd8, 0xf3], false),
+    (0x5195, &[0xd8, 0xf4], false),
+    (0x52c9, &[0xd8, 0xf5], false),
+    (0x68c9, &[0xd8, 0xf6], false),
+    (0x6c94, &[0xd8, 0xf7], false),
+    (0x7704, &[0xd8, 0xf8], false),
+    (0x7720, &[0xd8, 0xf9], false),
+    (0x7dbf, &[0xd8, 0xfa], false),
+    (0x7dec, &[0xd8, 0xfb], false),
+    (0x9762, &[0xd8, 0xfc], false),
+    (0x9eb5, &[0xd8, 0xfd], false),
+    (0x6ec5, &[0xd8, 0xfe], false),
+    (0x8511, &[0xd9, 0xa1], false),
+    (0x51a5, &[0xd9, 0xa2], false),
+    (0x540d, &[0xd9, 0xa3], false),
+    (0x547d, &[0xd9, 0xa4], false),
+    (0x660e, &[0xd9, 0xa5], false),
+    (0x669d, &[0xd9, 0xa6], false),
+    (0x6927, &[0xd9, 0xa7], false),
+    (0x6e9f, &[0xd9, 0xa8], false),
+    (0x76bf, &[0xd9, 0xa9], false),
+    (0x7791, &[0xd9, 0xaa], false),
+    (0x8317, &[0xd9, 0xab], false),
+    (0x84c2, &[0xd9, 0xac], false),
+    (0x879f, &[0xd9, 0xad], false),
+    (0x9169, &[0xd9, 0xae], false),
+    (0x9298, &[0xd9, 0xaf], false),
+    (0x9cf4, &[0xd9, 0xb0], false),
+    (0x8882, &[0xd9, 0xb1], false),
+    (0x4fae, &[0xd9, 0xb2], false),
+    (0x5192, &[0xd9, 0xb3], false),
+    (0x52df, &[0xd9, 0xb4], false),
+    (0x59c6, &[0xd9, 0xb5], false),
+    (0x5e3d, &[0xd9, 0xb6], false),
+    (0x6155, &[0xd9, 0xb7], false),
+    (0x6478, &[0xd9, 0xb8], false),
+    (0x6479, &[0xd9, 0xb9], false),
+    (0x66ae, &[0xd9, 0xba], false),
+    (0x67d0, &[0xd9, 0xbb], false),
+    (0x6a21, &[0xd9, 0xbc], false),
+    (0x6bcd, &[0xd9, 0xbd], false),
+    (0x6bdb, &[0xd9, 0xbe], false),
+    (0x725f, &[0xd9, 0xbf], false),
+    (0x7261, &[0xd9, 0xc0], false),
+    (0x7441, &[0xd9, 0xc1], false),
+    (0x7738, &[0xd9, 0xc2], false),
+    (0x77db, &[0xd9, 0xc3], false),
+    (0x8017, &[0xd9, 0xc4], false),
+    (0x82bc, &[0xd9, 0xc5], false),
+    (0x8305, &[0xd9, 0xc6], false),
+    (0x8b00, &[0xd9, 0xc7], false),
+    (0x8b28, &[0xd9, 0xc8], false),
+    (0x8c8c, &[0xd9, 0xc9], false),
+    (0x6728, &[0xd9, 0xca], false),
+    (0x6c90, &[0xd9, 0xcb], false),
+    (0x7267, &[0xd9, 0xcc], false),
+    (0x76ee, &[0xd9, 0xcd], false),
+    (0x7766, &[0xd9, 0xce], false),
+    (0x7a46, &[0xd9, 0xcf], false),
+    (0x9da9, &[0xd9, 0xd0], false),
+    (0x6b7f, &[0xd9, 0xd1], false),
+    (0x6c92, &[0xd9, 0xd2], false),
+    (0x5922, &[0xd9, 0xd3], false),
+    (0x6726, &[0xd9, 0xd4], false),
+    (0x8499, &[0xd9, 0xd5], false),
+    (0x536f, &[0xd9, 0xd6], false),
+    (0x5893, &[0xd9, 0xd7], false),
+    (0x5999, &[0xd9, 0xd8], false),
+    (0x5edf, &[0xd9, 0xd9], false),
+    (0x63cf, &[0xd9, 0xda], false),
+    (0x6634, &[0xd9, 0xdb], false),
+    (0x6773, &[0xd9, 0xdc], false),
+    (0x6e3a, &[0xd9, 0xdd], false),
+    (0x732b, &[0xd9, 0xde], false),
+    (0x7ad7, &[0xd9, 0xdf], false),
+    (0x82d7, &[0xd9, 0xe0], false),
+    (0x9328, &[0xd9, 0xe1], false),
+    (0x52d9, &[0xd9, 0xe2], false),
+    (0x5deb, &[0xd9, 0xe3], false),
+    (0x61ae, &[0xd9, 0xe4], false),
+    (0x61cb, &[0xd9, 0xe5], false),
+    (0x620a, &[0xd9, 0xe6], false),
+    (0x62c7, &[0xd9, 0xe7], false),
+    (0x64ab, &[0xd9, 0xe8], false),
+    (0x65e0, &[0xd9, 0xe9], false),
+    (0x6959, &[0xd9, 0xea], false),
+    (0x6b66, &[0xd9, 0xeb], false),
+    (0x6bcb, &[0xd9, 0xec], false),
+    (0x7121, &[0xd9, 0xed], false),
+    (0x73f7, &[0xd9, 0xee], false),
+    (0x755d, &[0xd9, 0xef], false),
+    (0x7e46, &[0xd9, 0xf0], false),
+    (0x821e, &[0xd9, 0xf1], false),
+    (0x8302, &[0xd9, 0xf2], false),
+    (0x856a, &[0xd9, 0xf3], false),
+    (0x8aa3, &[0xd9, 0xf4], false),
+    (0x8cbf, &[0xd9, 0xf5], false),
+    (0x9727, &[0xd9, 0xf6], false),
+    (0x9d61, &[0xd9, 0xf7], false),
+    (0x58a8, &[0xd9, 0xf8], false),
+    (0x9ed8, &[0xd9, 0xf9], false),
+    (0x5011, &[0xd9, 0xfa], false),
+    (0x520e, &[0xd9, 0xfb], false),
+    (0x543b, &[0xd9, 0xfc], false),
+    (0x554f, &[0xd9, 0xfd], false),
+    (0x6587, &[0xd9, 0xfe], false),
+    (0x6c76, &[0xda, 0xa1], false),
+    (0x7d0a, &[0xda, 0xa2], false),
+    (0x7d0b, &[0xda, 0xa3], false),
+    (0x805e, &[0xda, 0xa4], false),
+    (0x868a, &[0xda, 0xa5], false),
+    (0x9580, &[0xda, 0xa6], false),
+    (0x96ef, &[0xda, 0xa7], false),
+    (0x52ff, &[0xda, 0xa8], false),
+    (0x6c95, &[0xda, 0xa9], false),
+    (0x7269, &[0xda, 0xaa], false),
+    (0x5473, &[0xda, 0xab], false),
+    (0x5a9a, &[0xda, 0xac], false),
+    (0x5c3e, &[0xda, 0xad], false),
+    (0x5d4b, &[0xda, 0xae], false),
+    (0x5f4c, &[0xda, 0xaf], false),
+    (0x5fae, &[0xda, 0xb0], false),
+    (0x672a, &[0xda, 0xb1], false),
+    (0x68b6, &[0xda, 0xb2], false),
+    (0x6963, &[0xda, 0xb3], false),
+    (0x6e3c, &[0xda, 0xb4], false),
+    (0x6e44, &[0xda, 0xb5], false),
+    (0x7709, &[0xda, 0xb6], false),
+    (0x7c73, &[0xda, 0xb7], false),
+    (0x7f8e, &[0xda, 0xb8], false),
+    (0x8587, &[0xda, 0xb9], false),
+    (0x8b0e, &[0xda, 0xba], false),
+    (0x8ff7, &[0xda, 0xbb], false),
+    (0x9761, &[0xda, 0xbc], false),
+    (0x9ef4, &[0xda, 0xbd], false),
+    (0x5cb7, &[0xda, 0xbe], false),
+    (0x60b6, &[0xda, 0xbf], false),
+    (0x610d, &[0xda, 0xc0], false),
+    (0x61ab, &[0xda, 0xc1], false),
+    (0x654f, &[0xda, 0xc2], false),
+    (0x65fb, &[0xda, 0xc3], false),
+    (0x65fc, &[0xda, 0xc4], false),
+    (0x6c11, &[0xda, 0xc5], false),
+    (0x6cef, &[0xda, 0xc6], false),
+    (0x739f, &[0xda, 0xc7], false),
+    (0x73c9, &[0xda, 0xc8], false),
+    (0x7de1, &[0xda, 0xc9], false),
+    (0x9594, &[0xda, 0xca], false),
+    (0x5bc6, &[0xda, 0xcb], false),
+    (0x871c, &[0xda, 0xcc], false),
+    (0x8b10, &[0xda, 0xcd], false),
+    (0x525d, &[0xda, 0xce], false),
+    (0x535a, &[0xda, 0xcf], false),
+    (0x62cd, &[0xda, 0xd0], false),
+    (0x640f, &[0xda, 0xd1], false),
+    (0x64b2, &[0xda, 0xd2], false),
+    (0x6734, &[0xda, 0xd3], false),
+    (0x6a38, &[0xda, 0xd4], false),
+    (0x6cca, &[0xda, 0xd5], false),
+    (0x73c0, &[0xda, 0xd6], false),
+    (0x749e, &[0xda, 0xd7], false),
+    (0x7b94, &[0xda, 0xd8], false),
+    (0x7c95, &[0xda, 0xd9], false),
+    (0x7e1b, &[0xda, 0xda], false),
+    (0x818a, &[0xda, 0xdb], false),
+    (0x8236, &[0xda, 0xdc], false),
+    (0x8584, &[0xda, 0xdd], false),
+    (0x8feb, &[0xda, 0xde], false),
+    (0x96f9, &[0xda, 0xdf], false),
+    (0x99c1, &[0xda, 0xe0], false),
+    (0x4f34, &[0xda, 0xe1], false),
+    (0x534a, &[0xda, 0xe2], false),
+    (0x53cd, &[0xda, 0xe3], false),
+    (0x53db, &[0xda, 0xe4], false),
+    (0x62cc, &[0xda, 0xe5], false),
+    (0x642c, &[0xda, 0xe6], false),
+    (0x6500, &[0xda, 0xe7], false),
+    (0x6591, &[0xda, 0xe8], false),
+    (0x69c3, &[0xda, 0xe9], false),
+    (0x6cee, &[0xda, 0xea], false),
+    (0x6f58, &[0xda, 0xeb], false),
+    (0x73ed, &[0xda, 0xec], false),
+    (0x7554, &[0xda, 0xed], false),
+    (0x7622, &[0xda, 0xee], false),
+    (0x76e4, &[0xda, 0xef], false),
+    (0x76fc, &[0xda, 0xf0], false),
+    (0x78d0, &[0xda, 0xf1], false),
+    (0x78fb, &[0xda, 0xf2], false),
+    (0x792c, &[0xda, 0xf3], false),
+    (0x7d46, &[0xda, 0xf4], false),
+    (0x822c, &[0xda, 0xf5], false),
+    (0x87e0, &[0xda, 0xf6], false),
+    (0x8fd4, &[0xda, 0xf7], false),
+    (0x9812, &[0xda, 0xf8], false),
+    (0x98ef, &[0xda, 0xf9], false),
+    (0x52c3, &[0xda, 0xfa], false),
+    (0x62d4, &[0xda, 0xfb], false),
+    (0x64a5, &[0xda, 0xfc], false),
+    (0x6e24, &[0xda, 0xfd], false),
+    (0x6f51, &[0xda, 0xfe], false),
+    (0x767c, &[0xdb, 0xa1], false),
+    (0x8dcb, &[0xdb, 0xa2], false),
+    (0x91b1, &[0xdb, 0xa3], false),
+    (0x9262, &[0xdb, 0xa4], false),
+    (0x9aee, &[0xdb, 0xa5], false),
+    (0x9b43, &[0xdb, 0xa6], false),
+    (0x5023, &[0xdb, 0xa7], false),
+    (0x508d, &[0xdb, 0xa8], false),
+    (0x574a, &[0xdb, 0xa9], false),
+    (0x59a8, &[0xdb, 0xaa], false),
+    (0x5c28, &[0xdb, 0xab], false),
+    (0x5e47, &[0xdb, 0xac], false),
+    (0x5f77, &[0xdb, 0xad], false),
+    (0x623f, &[0xdb, 0xae], false),
+    (0x653e, &[0xdb, 0xaf], false),
+    (0x65b9, &[0xdb, 0xb0], false),
+    (0x65c1, &[0xdb, 0xb1], false),
+    (0x6609, &[0xdb, 0xb2], false),
+    (0x678b, &[0xdb, 0xb3], false),
+    (0x699c, &[0xdb, 0xb4], false),
+    (0x6ec2, &[0xdb, 0xb5], false),
+    (0x78c5, &[0xdb, 0xb6], false),
+    (0x7d21, &[0xdb, 0xb7], false),
+    (0x80aa, &[0xdb, 0xb8], false),
+    (0x8180, &[0xdb, 0xb9], false),
+    (0x822b, &[0xdb, 0xba], false),
+    (0x82b3, &[0xdb, 0xbb], false),
+    (0x84a1, &[0xdb, 0xbc], false),
+    (0x868c, &[0xdb, 0xbd], false),
+    (0x8a2a, &[0xdb, 0xbe], false),
+    (0x8b17, &[0xdb, 0xbf], false),
+    (0x90a6, &[0xdb, 0xc0], false),
+    (0x9632, &[0xdb, 0xc1], false),
+    (0x9f90, &[0xdb, 0xc2], false),
+    (0x500d, &[0xdb, 0xc3], false),
+    (0x4ff3, &[0xdb, 0xc4], false),
+    (0xf963, &[0xdb, 0xc5], false),
+    (0x57f9, &[0xdb, 0xc6], false),
+    (0x5f98, &[0xdb, 0xc7], false),
+    (0x62dc, &[0xdb, 0xc8], false),
+    (0x6392, &[0xdb, 0xc9], false),
+    (0x676f, &[0xdb, 0xca], false),
+    (0x6e43, &[0xdb, 0xcb], false),
+    (0x7119, &[0xdb, 0xcc], false),
+    (0x76c3, &[0xdb, 0xcd], false),
+    (0x80cc, &[0xdb, 0xce], false),
+    (0x80da, &[0xdb, 0xcf], false),
+    (0x88f4, &[0xdb, 0xd0], false),
+    (0x88f5, &[0xdb, 0xd1], false),
+    (0x8919, &[0xdb, 0xd2], false),
+    (0x8ce0, &[0xdb, 0xd3], false),
+    (0x8f29, &[0xdb, 0xd4], false),
+    (0x914d, &[0xdb, 0xd5], false),
+    (0x966a, &[0xdb, 0xd6], false),
+    (0x4f2f, &[0xdb, 0xd7], false),
+    (0x4f70, &[0xdb, 0xd8], false),
+    (0x5e1b, &[0xdb, 0xd9], false),
+    (0x67cf, &[0xdb, 0xda], false),
+    (0x6822, &[0xdb, 0xdb], false),
+    (0x767d, &[0xdb, 0xdc], false),
+    (0x767e, &[0xdb, 0xdd], false),
+    (0x9b44, &[0xdb, 0xde], false),
+    (0x5e61, &[0xdb, 0xdf], false),
+    (0x6a0a, &[0xdb, 0xe0], false),
+    (0x7169, &[0xdb, 0xe1], false),
+    (0x71d4, &[0xdb, 0xe2], false),
+    (0x756a, &[0xdb, 0xe3], false),
+    (0xf964, &[0xdb, 0xe4], false),
+    (0x7e41, &[0xdb, 0xe5], false),
+    (0x8543, &[0xdb, 0xe6], false),
+    (0x85e9, &[0xdb, 0xe7], false),
+    (0x98dc, &[0xdb, 0xe8], false),
+    (0x4f10, &[0xdb, 0xe9], false),
+    (0x7b4f, &[0xdb, 0xea], false),
+    (0x7f70, &[0xdb, 0xeb], false),
+    (0x95a5, &[0xdb, 0xec], false),
+    (0x51e1, &[0xdb, 0xed], false),
+    (0x5e06, &[0xdb, 0xee], false),
+    (0x68b5, &[0xdb, 0xef], false),
+    (0x6c3e, &[0xdb, 0xf0], false),
+    (0x6c4e, &[0xdb, 0xf1], false),
+    (0x6cdb, &[0xdb, 0xf2], false),
+    (0x72af, &[0xdb, 0xf3], false),
+    (0x7bc4, &[0xdb, 0xf4], false),
+    (0x8303, &[0xdb, 0xf5], false),
+    (0x6cd5, &[0xdb, 0xf6], false),
+    (0x743a, &[0xdb, 0xf7], false),
+    (0x50fb, &[0xdb, 0xf8], false),
+    (0x5288, &[0xdb, 0xf9], false),
+    (0x58c1, &[0xdb, 0xfa], false),
+    (0x64d8, &[0xdb, 0xfb], false),
+    (0x6a97, &[0xdb, 0xfc], false),
+    (0x74a7, &[0xdb, 0xfd], false),
+    (0x7656, &[0xdb, 0xfe], false),
+    (0x78a7, &[0xdc, 0xa1], false),
+    (0x8617, &[0xdc, 0xa2], false),
+    (0x95e2, &[0xdc, 0xa3], false),
+    (0x9739, &[0xdc, 0xa4], false),
+    (0xf965, &[0xdc, 0xa5], false),
+    (0x535e, &[0xdc, 0xa6], false),
+    (0x5f01, &[0xdc, 0xa7], false),
+    (0x8b8a, &[0xdc, 0xa8], false),
+    (0x8fa8, &[0xdc, 0xa9], false),
+    (0x8faf, &[0xdc, 0xaa], false),
+    (0x908a, &[0xdc, 0xab], false),
+    (0x5225, &[0xdc, 0xac], false),
+    (0x77a5, &[0xdc, 0xad], false),
+    (0x9c49, &[0xdc, 0xae], false),
+    (0x9f08, &[0xdc, 0xaf], false),
+    (0x4e19, &[0xdc, 0xb0], false),
+    (0x5002, &[0xdc, 0xb1], false),
+    (0x5175, &[0xdc, 0xb2], false),
+    (0x5c5b, &[0xdc, 0xb3], false),
+    (0x5e77, &[0xdc, 0xb4], false),
+    (0x661e, &[0xdc, 0xb5], false),
+    (0x663a, &[0xdc, 0xb6], false),
+    (0x67c4, &[0xdc, 0xb7], false),
+    (0x68c5, &[0xdc, 0xb8], false),
+    (0x70b3, &[0xdc, 0xb9], false),
+    (0x7501, &[0xdc, 0xba], false),
+    (0x75c5, &[0xdc, 0xbb], false),
+    (0x79c9, &[0xdc, 0xbc], false),
+    (0x7add, &[0xdc, 0xbd], false),
+    (0x8f27, &[0xdc, 0xbe], false),
+    (0x9920, &[0xdc, 0xbf], false),
+    (0x9a08, &[0xdc, 0xc0], false),
+    (0x4fdd, &[0xdc, 0xc1], false),
+    (0x5821, &[0xdc, 0xc2], false),
+    (0x5831, &[0xdc, 0xc3], false),
+    (0x5bf6, &[0xdc, 0xc4], false),
+    (0x666e, &[0xdc, 0xc5], false),
+    (0x6b65, &[0xdc, 0xc6], false),
+    (0x6d11, &[0xdc, 0xc7], false),
+    (0x6e7a, &[0xdc, 0xc8], false),
+    (0x6f7d, &[0xdc, 0xc9], false),
+    (0x73e4, &[0xdc, 0xca], false),
+    (0x752b, &[0xdc, 0xcb], false),
+    (0x83e9, &[0xdc, 0xcc], false),
+    (0x88dc, &[0xdc, 0xcd], false),
+    (0x8913, &[0xdc, 0xce], false),
+    (0x8b5c, &[0xdc, 0xcf], false),
+    (0x8f14, &[0xdc, 0xd0], false),
+    (0x4f0f, &[0xdc, 0xd1], false),
+    (0x50d5, &[0xdc, 0xd2], false),
+    (0x5310, &[0xdc, 0xd3], false),
+    (0x535c, &[0xdc, 0xd4], false),
+    (0x5b93, &[0xdc, 0xd5], false),
+    (0x5fa9, &[0xdc, 0xd6], false),
+    (0x670d, &[0xdc, 0xd7], false),
+    (0x798f, &[0xdc, 0xd8], false),
+    (0x8179, &[0xdc, 0xd9], false),
+    (0x832f, &[0xdc, 0xda], false),
+    (0x8514, &[0xdc, 0xdb], false),
+    (0x8907, &[0xdc, 0xdc], false),
+    (0x8986, &[0xdc, 0xdd], false),
+    (0x8f39, &[0xdc, 0xde], false),
+    (0x8f3b, &[0xdc, 0xdf], false),
+    (0x99a5, &[0xdc, 0xe0], false),
+    (0x9c12, &[0xdc, 0xe1], false),
+    (0x672c, &[0xdc, 0xe2], false),
+    (0x4e76, &[0xdc, 0xe3], false),
+    (0x4ff8, &[0xdc, 0xe4], false),
+    (0x5949, &[0xdc, 0xe5], false),
+    (0x5c01, &[0xdc, 0xe6], false),
+    (0x5cef, &[0xdc, 0xe7], false),
+    (0x5cf0, &[0xdc, 0xe8], false),
+    (0x6367, &[0xdc, 0xe9], false),
+    (0x68d2, &[0xdc, 0xea], false),
+    (0x70fd, &[0xdc, 0xeb], false),
+    (0x71a2, &[0xdc, 0xec], false),
+    (0x742b, &[0xdc, 0xed], false),
+    (0x7e2b, &[0xdc, 0xee], false),
+    (0x84ec, &[0xdc, 0xef], false),
+    (0x8702, &[0xdc, 0xf0], false),
+    (0x9022, &[0xdc, 0xf1], false),
+    (0x92d2, &[0xdc, 0xf2], false),
+    (0x9cf3, &[0xdc, 0xf3], false),
+    (0x4e0d, &[0xdc, 0xf4], false),
+    (0x4ed8, &[0xdc, 0xf5], false),
+    (0x4fef, &[0xdc, 0xf6], false),
+    (0x5085, &[0xdc, 0xf7], false),
+    (0x5256, &[0xdc, 0xf8], false),
+    (0x526f, &[0xdc, 0xf9], false),
+    (0x5426, &[0xdc, 0xfa], false),
+    (0x5490, &[0xdc, 0xfb], false),
+    (0x57e0, &[0xdc, 0xfc], false),
+    (0x592b, &[0xdc, 0xfd], false),
+    (0x5a66, &[0xdc, 0xfe], false),
+    (0x5b5a, &[0xdd, 0xa1], false),
+    (0x5b75, &[0xdd, 0xa2], false),
+    (0x5bcc, &[0xdd, 0xa3], false),
+    (0x5e9c, &[0xdd, 0xa4], false),
+    (0xf966, &[0xdd, 0xa5], false),
+    (0x6276, &[0xdd, 0xa6], false),
+    (0x6577, &[0xdd, 0xa7], false),
+    (0x65a7, &[0xdd, 0xa8], false),
+    (0x6d6e, &[0xdd, 0xa9], false),
+    (0x6ea5, &[0xdd, 0xaa], false),
+    (0x7236, &[0xdd, 0xab], false),
+    (0x7b26, &[0xdd, 0xac], false),
+    (0x7c3f, &[0xdd, 0xad], false),
+    (0x7f36, &[0xdd, 0xae], false),
+    (0x8150, &[0xdd, 0xaf], false),
+    (0x8151, &[0xdd, 0xb0], false),
+    (0x819a, &[0xdd, 0xb1], false),
+    (0x8240, &[0xdd, 0xb2], false),
+    (0x8299, &[0xdd, 0xb3], false),
+    (0x83a9, &[0xdd, 0xb4], false),
+    (0x8a03, &[0xdd, 0xb5], false),
+    (0x8ca0, &[0xdd, 0xb6], false),
+    (0x8ce6, &[0xdd, 0xb7], false),
+    (0x8cfb, &[0xdd, 0xb8], false),
+    (0x8d74, &[0xdd, 0xb9], false),
+    (0x8dba, &[0xdd, 0xba], false),
+    (0x90e8, &[0xdd, 0xbb], false),
+    (0x91dc, &[0xdd, 0xbc], false),
+    (0x961c, &[0xdd, 0xbd], false),
+    (0x9644, &[0xdd, 0xbe], false),
+    (0x99d9, &[0xdd, 0xbf], false),
+    (0x9ce7, &[0xdd, 0xc0], false),
+    (0x5317, &[0xdd, 0xc1], false),
+    (0x5206, &[0xdd, 0xc2], false),
+    (0x5429, &[0xdd, 0xc3], false),
+    (0x5674, &[0xdd, 0xc4], false),
+    (0x58b3, &[0xdd, 0xc5], false),
+    (0x5954, &[0xdd, 0xc6], false),
+    (0x596e, &[0xdd, 0xc7], false),
+    (0x5fff, &[0xdd, 0xc8], false),
+    (0x61a4, &[0xdd, 0xc9], false),
+    (0x626e, &[0xdd, 0xca], false),
+    (0x6610, &[0xdd, 0xcb], false),
+    (0x6c7e, &[0xdd, 0xcc], false),
+    (0x711a, &[0xdd, 0xcd], false),
+    (0x76c6, &[0xdd, 0xce], false),
+    (0x7c89, &[0xdd, 0xcf], false),
+    (0x7cde, &[0xdd, 0xd0], false),
+    (0x7d1b, &[0xdd, 0xd1], false),
+    (0x82ac, &[0xdd, 0xd2], false),
+    (0x8cc1, &[0xdd, 0xd3], false),
+    (0x96f0, &[0xdd, 0xd4], false),
+    (0xf967, &[0xdd, 0xd5], false),
+    (0x4f5b, &[0xdd, 0xd6], false),
+    (0x5f17, &[0xdd, 0xd7], false),
+    (0x5f7f, &[0xdd, 0xd8], false),
+    (0x62c2, &[0xdd, 0xd9], false),
+    (0x5d29, &[0xdd, 0xda], false),
+    (0x670b, &[0xdd, 0xdb], false),
+    (0x68da, &[0xdd, 0xdc], false),
+    (0x787c, &[0xdd, 0xdd], false),
+    (0x7e43, &[0xdd, 0xde], false),
+    (0x9d6c, &[0xdd, 0xdf], false),
+    (0x4e15, &[0xdd, 0xe0], false),
+    (0x5099, &[0xdd, 0xe1], false),
+    (0x5315, &[0xdd, 0xe2], false),
+    (0x532a, &[0xdd, 0xe3], false),
+    (0x5351, &[0xdd, 0xe4], false),
+    (0x5983, &[0xdd, 0xe5], false),
+    (0x5a62, &[0xdd, 0xe6], false),
+    (0x5e87, &[0xdd, 0xe7], false),
+    (0x60b2, &[0xdd, 0xe8], false),
+    (0x618a, &[0xdd, 0xe9], false),
+    (0x6249, &[0xdd, 0xea], false),
+    (0x6279, &[0xdd, 0xeb], false),
+    (0x6590, &[0xdd, 0xec], false),
+    (0x6787, &[0xdd, 0xed], false),
+    (0x69a7, &[0xdd, 0xee], false),
+    (0x6bd4, &[0xdd, 0xef], false),
+    (0x6bd6, &[0xdd, 0xf0], false),
+    (0x6bd7, &[0xdd, 0xf1], false),
+    (0x6bd8, &[0xdd, 0xf2], false),
+    (0x6cb8, &[0xdd, 0xf3], false),
+    (0xf968, &[0xdd, 0xf4], false),
+    (0x7435, &[0xdd, 0xf5], false),
+    (0x75fa, &[0xdd, 0xf6], false),
+    (0x7812, &[0xdd, 0xf7], false),
+    (0x7891, &[0xdd, 0xf8], false),
+    (0x79d5, &[0xdd, 0xf9], false),
+    (0x79d8, &[0xdd, 0xfa], false),
+    (0x7c83, &[0xdd, 0xfb], false),
+    (0x7dcb, &[0xdd, 0xfc], false),
+    (0x7fe1, &[0xdd, 0xfd], false),
+    (0x80a5, &[0xdd, 0xfe], false),
+    (0x813e, &[0xde, 0xa1], false),
+    (0x81c2, &[0xde, 0xa2], false),
+    (0x83f2, &[0xde, 0xa3], false),
+    (0x871a, &[0xde, 0xa4], false),
+    (0x88e8, &[0xde, 0xa5], false),
+    (0x8ab9, &[0xde, 0xa6], false),
+    (0x8b6c, &[0xde, 0xa7], false),
+    (0x8cbb, &[0xde, 0xa8], false),
+    (0x9119, &[0xde, 0xa9], false),
+    (0x975e, &[0xde, 0xaa], false),
+    (0x98db, &[0xde, 0xab], false),
+    (0x9f3b, &[0xde, 0xac], false),
+    (0x56ac, &[0xde, 0xad], false),
+    (0x5b2a, &[0xde, 0xae], false),
+    (0x5f6c, &[0xde, 0xaf], false),
+    (0x658c, &[0xde, 0xb0], false),
+    (0x6ab3, &[0xde, 0xb1], false),
+    (0x6baf, &[0xde, 0xb2], false),
+    (0x6d5c, &[0xde, 0xb3], false),
+    (0x6ff1, &[0xde, 0xb4], false),
+    (0x7015, &[0xde, 0xb5], false),
+    (0x725d, &[0xde, 0xb6], false),
+    (0x73ad, &[0xde, 0xb7], false),
+    (0x8ca7, &[0xde, 0xb8], false),
+    (0x8cd3, &[0xde, 0xb9], false),
+    (0x983b, &[0xde, 0xba], false),
+    (0x6191, &[0xde, 0xbb], false),
+    (0x6c37, &[0xde, 0xbc], false),
+    (0x8058, &[0xde, 0xbd], false),
+    (0x9a01, &[0xde, 0xbe], false),
+    (0x4e4d, &[0xde, 0xbf], false),
+    (0x4e8b, &[0xde, 0xc0], false),
+    (0x4e9b, &[0xde, 0xc1], false),
+    (0x4ed5, &[0xde, 0xc2], false),
+    (0x4f3a, &[0xde, 0xc3], false),
+    (0x4f3c, &[0xde, 0xc4], false),
+    (0x4f7f, &[0xde, 0xc5], false),
+    (0x4fdf, &[0xde, 0xc6], false),
+    (0x50ff, &[0xde, 0xc7], false),
+    (0x53f2, &[0xde, 0xc8], false),
+    (0x53f8, &[0xde, 0xc9], false),
+    (0x5506, &[0xde, 0xca], false),
+    (0x55e3, &[0xde, 0xcb], false),
+    (0x56db, &[0xde, 0xcc], false),
+    (0x58eb, &[0xde, 0xcd], false),
+    (0x5962, &[0xde, 0xce], false),
+    (0x5a11, &[0xde, 0xcf], false),
+    (0x5beb, &[0xde, 0xd0], false),
+    (0x5bfa, &[0xde, 0xd1], false),
+    (0x5c04, &[0xde, 0xd2], false),
+    (0x5df3, &[0xde, 0xd3], false),
+    (0x5e2b, &[0xde, 0xd4], false),
+    (0x5f99, &[0xde, 0xd5], false),
+    (0x601d, &[0xde, 0xd6], false),
+    (0x6368, &[0xde, 0xd7], false),
+    (0x659c, &[0xde, 0xd8], false),
+    (0x65af, &[0xde, 0xd9], false),
+    (0x67f6, &[0xde, 0xda], false),
+    (0x67fb, &[0xde, 0xdb], false),
+    (0x68ad, &[0xde, 0xdc], false),
+    (0x6b7b, &[0xde, 0xdd], false),
+    (0x6c99, &[0xde, 0xde], false),
+    (0x6cd7, &[0xde, 0xdf], false),
+    (0x6e23, &[0xde, 0xe0], false),
+    (0x7009, &[0xde, 0xe1], false),
+    (0x7345, &[0xde, 0xe2], false),
+    (0x7802, &[0xde, 0xe3], false),
+    (0x793e, &[0xde, 0xe4], false),
+    (0x7940, &[0xde, 0xe5], false),
+    (0x7960, &[0xde, 0xe6], false),
+    (0x79c1, &[0xde, 0xe7], false),
+    (0x7be9, &[0xde, 0xe8], false),
+    (0x7d17, &[0xde, 0xe9], false),
+    (0x7d72, &[0xde, 0xea], false),
+    (0x8086, &[0xde, 0xeb], false),
+    (0x820d, &[0xde, 0xec], false),
+    (0x838e, &[0xde, 0xed], false),
+    (0x84d1, &[0xde, 0xee], false),
+    (0x86c7, &[0xde, 0xef], false),
+    (0x88df, &[0xde, 0xf0], false),
+    (0x8a50, &[0xde, 0xf1], false),
+    (0x8a5e, &[0xde, 0xf2], false),
+    (0x8b1d, &[0xde, 0xf3], false),
+    (0x8cdc, &[0xde, 0xf4], false),
+    (0x8d66, &[0xde, 0xf5], false),
+    (0x8fad, &[0xde, 0xf6], false),
+    (0x90aa, &[0xde, 0xf7], false),
+    (0x98fc, &[0xde, 0xf8], false),
+    (0x99df, &[0xde, 0xf9], false),
+    (0x9e9d, &[0xde, 0xfa], false),
+    (0x524a, &[0xde, 0xfb], false),
+    (0xf969, &[0xde, 0xfc], false),
+    (0x6714, &[0xde, 0xfd], false),
+    (0xf96a, &[0xde, 0xfe], false),
+    (0x5098, &[0xdf, 0xa1], false),
+    (0x522a, &[0xdf, 0xa2], false),
+    (0x5c71, &[0xdf, 0xa3], false),
+    (0x6563, &[0xdf, 0xa4], false),
+    (0x6c55, &[0xdf, 0xa5], false),
+    (0x73ca, &[0xdf, 0xa6], false),
+    (0x7523, &[0xdf, 0xa7], false),
+    (0x759d, &[0xdf, 0xa8], false),
+    (0x7b97, &[0xdf, 0xa9], false),
+    (0x849c, &[0xdf, 0xaa], false),
+    (0x9178, &[0xdf, 0xab], false),
+    (0x9730, &[0xdf, 0xac], false),
+    (0x4e77, &[0xdf, 0xad], false),
+    (0x6492, &[0xdf, 0xae], false),
+    (0x6bba, &[0xdf, 0xaf], false),
+    (0x715e, &[0xdf, 0xb0], false),
+    (0x85a9, &[0xdf, 0xb1], false),
+    (0x4e09, &[0xdf, 0xb2], false),
+    (0xf96b, &[0xdf, 0xb3], false),
+    (0x6749, &[0xdf, 0xb4], false),
+    (0x68ee, &[0xdf, 0xb5], false),
+    (0x6e17, &[0xdf, 0xb6], false),
+    (0x829f, &[0xdf, 0xb7], false),
+    (0x8518, &[0xdf, 0xb8], false),
+    (0x886b, &[0xdf, 0xb9], false),
+    (0x63f7, &[0xdf, 0xba], false),
+    (0x6f81, &[0xdf, 0xbb], false),
+    (0x9212, &[0xdf, 0xbc], false),
+    (0x98af, &[0xdf, 0xbd], false),
+    (0x4e0a, &[0xdf, 0xbe], false),
+    (0x50b7, &[0xdf, 0xbf], false),
+    (0x50cf, &[0xdf, 0xc0], false),
+    (0x511f, &[0xdf, 0xc1], false),
+    (0x5546, &[0xdf, 0xc2], false),
+    (0x55aa, &[0xdf, 0xc3], false),
+    (0x5617, &[0xdf, 0xc4], false),
+    (0x5b40, &[0xdf, 0xc5], false),
+    (0x5c19, &[0xdf, 0xc6], false),
+    (0x5ce0, &[0xdf, 0xc7], false),
+    (0x5e38, &[0xdf, 0xc8], false),
+    (0x5e8a, &[0xdf, 0xc9], false),
+    (0x5ea0, &[0xdf, 0xca], false),
+    (0x5ec2, &[0xdf, 0xcb], false),
+    (0x60f3, &[0xdf, 0xcc], false),
+    (0x6851, &[0xdf, 0xcd], false),
+    (0x6a61, &[0xdf, 0xce], false),
+    (0x6e58, &[0xdf, 0xcf], false),
+    (0x723d, &[0xdf, 0xd0], false),
+    (0x7240, &[0xdf, 0xd1], false),
+    (0x72c0, &[0xdf, 0xd2], false),
+    (0x76f8, &[0xdf, 0xd3], false),
+    (0x7965, &[0xdf, 0xd4], false),
+    (0x7bb1, &[0xdf, 0xd5], false),
+    (0x7fd4, &[0xdf, 0xd6], false),
+    (0x88f3, &[0xdf, 0xd7], false),
+    (0x89f4, &[0xdf, 0xd8], false),
+    (0x8a73, &[0xdf, 0xd9], false),
+    (0x8c61, &[0xdf, 0xda], false),
+    (0x8cde, &[0xdf, 0xdb], false),
+    (0x971c, &[0xdf, 0xdc], false),
+    (0x585e, &[0xdf, 0xdd], false),
+    (0x74bd, &[0xdf, 0xde], false),
+    (0x8cfd, &[0xdf, 0xdf], false),
+    (0x55c7, &[0xdf, 0xe0], false),
+    (0xf96c, &[0xdf, 0xe1], false),
+    (0x7a61, &[0xdf, 0xe2], false),
+    (0x7d22, &[0xdf, 0xe3], false),
+    (0x8272, &[0xdf, 0xe4], false),
+    (0x7272, &[0xdf, 0xe5], false),
+    (0x751f, &[0xdf, 0xe6], false),
+    (0x7525, &[0xdf, 0xe7], false),
+    (0xf96d, &[0xdf, 0xe8], false),
+    (0x7b19, &[0xdf, 0xe9], false),
+    (0x5885, &[0xdf, 0xea], false),
+    (0x58fb, &[0xdf, 0xeb], false),
+    (0x5dbc, &[0xdf, 0xec], false),
+    (0x5e8f, &[0xdf, 0xed], false),
+    (0x5eb6, &[0xdf, 0xee], false),
+    (0x5f90, &[0xdf, 0xef], false),
+    (0x6055, &[0xdf, 0xf0], false),
+    (0x6292, &[0xdf, 0xf1], false),
+    (0x637f, &[0xdf, 0xf2], false),
+    (0x654d, &[0xdf, 0xf3], false),
+    (0x6691, &[0xdf, 0xf4], false),
+    (0x66d9, &[0xdf, 0xf5], false),
+    (0x66f8, &[0xdf, 0xf6], false),
+    (0x6816, &[0xdf, 0xf7], false),
+    (0x68f2, &[0xdf, 0xf8], false),
+    (0x7280, &[0xdf, 0xf9], false),
+    (0x745e, &[0xdf, 0xfa], false),
+    (0x7b6e, &[0xdf, 0xfb], false),
+    (0x7d6e, &[0xdf, 0xfc], false),
+    (0x7dd6, &[0xdf, 0xfd], false),
+    (0x7f72, &[0xdf, 0xfe], false),
+    (0x80e5, &[0xe0, 0xa1], false),
+    (0x8212, &[0xe0, 0xa2], false),
+    (0x85af, &[0xe0, 0xa3], false),
+    (0x897f, &[0xe0, 0xa4], false),
+    (0x8a93, &[0xe0, 0xa5], false),
+    (0x901d, &[0xe0, 0xa6], false),
+    (0x92e4, &[0xe0, 0xa7], false),
+    (0x9ecd, &[0xe0, 0xa8], false),
+    (0x9f20, &[0xe0, 0xa9], false),
+    (0x5915, &[0xe0, 0xaa], false),
+    (0x596d, &[0xe0, 0xab], false),
+    (0x5e2d, &[0xe0, 0xac], false),
+    (0x60dc, &[0xe0, 0xad], false),
+    (0x6614, &[0xe0, 0xae], false),
+    (0x6673, &[0xe0, 0xaf], false),
+    (0x6790, &[0xe0, 0xb0], false),
+    (0x6c50, &[0xe0, 0xb1], false),
+    (0x6dc5, &[0xe0, 0xb2], false),
+    (0x6f5f, &[0xe0, 0xb3], false),
+    (0x77f3, &[0xe0, 0xb4], false),
+    (0x78a9, &[0xe0, 0xb5], false),
+    (0x84c6, &[0xe0, 0xb6], false),
+    (0x91cb, &[0xe0, 0xb7], false),
+    (0x932b, &[0xe0, 0xb8], false),
+    (0x4ed9, &[0xe0, 0xb9], false),
+    (0x50ca, &[0xe0, 0xba], false),
+    (0x5148, &[0xe0, 0xbb], false),
+    (0x5584, &[0xe0, 0xbc], false),
+    (0x5b0b, &[0xe0, 0xbd], false),
+    (0x5ba3, &[0xe0, 0xbe], false),
+    (0x6247, &[0xe0, 0xbf], false),
+    (0x657e, &[0xe0, 0xc0], false),
+    (0x65cb, &[0xe0, 0xc1], false),
+    (0x6e32, &[0xe0, 0xc2], false),
+    (0x717d, &[0xe0, 0xc3], false),
+    (0x7401, &[0xe0, 0xc4], false),
+    (0x7444, &[0xe0, 0xc5], false),
+    (0x7487, &[0xe0, 0xc6], false),
+    (0x74bf, &[0xe0, 0xc7], false),
+    (0x766c, &[0xe0, 0xc8], false),
+    (0x79aa, &[0xe0, 0xc9], false),
+    (0x7dda, &[0xe0, 0xca], false),
+    (0x7e55, &[0xe0, 0xcb], false),
+    (0x7fa8, &[0xe0, 0xcc], false),
+    (0x817a, &[0xe0, 0xcd], false),
+    (0x81b3, &[0xe0, 0xce], false),
+    (0x8239, &[0xe0, 0xcf], false),
+    (0x861a, &[0xe0, 0xd0], false),
+    (0x87ec, &[0xe0, 0xd1], false),
+    (0x8a75, &[0xe0, 0xd2], false),
+    (0x8de3, &[0xe0, 0xd3], false),
+    (0x9078, &[0xe0, 0xd4], false),
+    (0x9291, &[0xe0, 0xd5], false),
+    (0x9425, &[0xe0, 0xd6], false),
+    (0x994d, &[0xe0, 0xd7], false),
+    (0x9bae, &[0xe0, 0xd8], false),
+    (0x5368, &[0xe0, 0xd9], false),
+    (0x5c51, &[0xe0, 0xda], false),
+    (0x6954, &[0xe0, 0xdb], false),
+    (0x6cc4, &[0xe0, 0xdc], false),
+    (0x6d29, &[0xe0, 0xdd], false),
+    (0x6e2b, &[0xe0, 0xde], false),
+    (0x820c, &[0xe0, 0xdf], false),
+    (0x859b, &[0xe0, 0xe0], false),
+    (0x893b, &[0xe0, 0xe1], false),
+    (0x8a2d, &[0xe0, 0xe2], false),
+    (0x8aaa, &[0xe0, 0xe3], false),
+    (0x96ea, &[0xe0, 0xe4], false),
+    (0x9f67, &[0xe0, 0xe5], false),
+    (0x5261, &[0xe0, 0xe6], false),
+    (0x66b9, &[0xe0, 0xe7], false),
+    (0x6bb2, &[0xe0, 0xe8], false),
+    (0x7e96, &[0xe0, 0xe9], false),
+    (0x87fe, &[0xe0, 0xea], false),
+    (0x8d0d, &[0xe0, 0xeb], false),
+    (0x9583, &[0xe0, 0xec], false),
+    (0x965d, &[0xe0, 0xed], false),
+    (0x651d, &[0xe0, 0xee], false),
+    (0x6d89, &[0xe0, 0xef], false),
+    (0x71ee, &[0xe0, 0xf0], false),
+    (0xf96e, &[0xe0, 0xf1], false),
+    (0x57ce, &[0xe0, 0xf2], false),
+    (0x59d3, &[0xe0, 0xf3], false),
+    (0x5bac, &[0xe0, 0xf4], false),
+    (0x6027, &[0xe0, 0xf5], false),
+    (0x60fa, &[0xe0, 0xf6], false),
+    (0x6210, &[0xe0, 0xf7], false),
+    (0x661f, &[0xe0, 0xf8], false),
+    (0x665f, &[0xe0, 0xf9], false),
+    (0x7329, &[0xe0, 0xfa], false),
+    (0x73f9, &[0xe0, 0xfb], false),
+    (0x76db, &[0xe0, 0xfc], false),
+    (0x7701, &[0xe0, 0xfd], false),
+    (0x7b6c, &[0xe0, 0xfe], false),
+    (0x8056, &[0xe1, 0xa1], false),
+    (0x8072, &[0xe1, 0xa2], false),
+    (0x8165, &[0xe1, 0xa3], false),
+    (0x8aa0, &[0xe1, 0xa4], false),
+    (0x9192, &[0xe1, 0xa5], false),
+    (0x4e16, &[0xe1, 0xa6], false),
+    (0x52e2, &[0xe1, 0xa7], false),
+    (0x6b72, &[0xe1, 0xa8], false),
+    (0x6d17, &[0xe1, 0xa9], false),
+    (0x7a05, &[0xe1, 0xaa], false),
+    (0x7b39, &[0xe1, 0xab], false),
+    (0x7d30, &[0xe1, 0xac], false),
+    (0xf96f, &[0xe1, 0xad], false),
+    (0x8cb0, &[0xe1, 0xae], false),
+    (0x53ec, &[0xe1, 0xaf], false),
+    (0x562f, &[0xe1, 0xb0], false),
+    (0x5851, &[0xe1, 0xb1], false),
+    (0x5bb5, &[0xe1, 0xb2], false),
+    (0x5c0f, &[0xe1, 0xb3], false),
+    (0x5c11, &[0xe1, 0xb4], false),
+    (0x5de2, &[0xe1, 0xb5], false),
+    (0x6240, &[0xe1, 0xb6], false),
+    (0x6383, &[0xe1, 0xb7], false),
+    (0x6414, &[0xe1, 0xb8], false),
+    (0x662d, &[0xe1, 0xb9], false),
+    (0x68b3, &[0xe1, 0xba], false),
+    (0x6cbc, &[0xe1, 0xbb], false),
+    (0x6d88, &[0xe1, 0xbc], false),
+    (0x6eaf, &[0xe1, 0xbd], false),
+    (0x701f, &[0xe1, 0xbe], false),
+    (0x70a4, &[0xe1, 0xbf], false),
+    (0x71d2, &[0xe1, 0xc0], false),
+    (0x7526, &[0xe1, 0xc1], false),
+    (0x758f, &[0xe1, 0xc2], false),
+    (0x758e, &[0xe1, 0xc3], false),
+    (0x7619, &[0xe1, 0xc4], false),
+    (0x7b11, &[0xe1, 0xc5], false),
+    (0x7be0, &[0xe1, 0xc6], false),
+    (0x7c2b, &[0xe1, 0xc7], false),
+    (0x7d20, &[0xe1, 0xc8], false),
+    (0x7d39, &[0xe1, 0xc9], false),
+    (0x852c, &[0xe1, 0xca], false),
+    (0x856d, &[0xe1, 0xcb], false),
+    (0x8607, &[0xe1, 0xcc], false),
+    (0x8a34, &[0xe1, 0xcd], false),
+    (0x900d, &[0xe1, 0xce], false),
+    (0x9061, &[0xe1, 0xcf], false),
+    (0x90b5, &[0xe1, 0xd0], false),
+    (0x92b7, &[0xe1, 0xd1], false),
+    (0x97f6, &[0xe1, 0xd2], false),
+    (0x9a37, &[0xe1, 0xd3], false),
+    (0x4fd7, &[0xe1, 0xd4], false),
+    (0x5c6c, &[0xe1, 0xd5], false),
+    (0x675f, &[0xe1, 0xd6], false),
+    (0x6d91, &[0xe1, 0xd7], false),
+    (0x7c9f, &[0xe1, 0xd8], false),
+    (0x7e8c, &[0xe1, 0xd9], false),
+    (0x8b16, &[0xe1, 0xda], false),
+    (0x8d16, &[0xe1, 0xdb], false),
+    (0x901f, &[0xe1, 0xdc], false),
+    (0x5b6b, &[0xe1, 0xdd], false),
+    (0x5dfd, &[0xe1, 0xde], false),
+    (0x640d, &[0xe1, 0xdf], false),
+    (0x84c0, &[0xe1, 0xe0], false),
+    (0x905c, &[0xe1, 0xe1], false),
+    (0x98e1, &[0xe1, 0xe2], false),
+    (0x7387, &[0xe1, 0xe3], false),
+    (0x5b8b, &[0xe1, 0xe4], false),
+    (0x609a, &[0xe1, 0xe5], false),
+    (0x677e, &[0xe1, 0xe6], false),
+    (0x6dde, &[0xe1, 0xe7], false),
+    (0x8a1f, &[0xe1, 0xe8], false),
+    (0x8aa6, &[0xe1, 0xe9], false),
+    (0x9001, &[0xe1, 0xea], false),
+    (0x980c, &[0xe1, 0xeb], false),
+    (0x5237, &[0xe1, 0xec], false),
+    (0xf970, &[0xe1, 0xed], false),
+    (0x7051, &[0xe1, 0xee], false),
+    (0x788e, &[0xe1, 0xef], false),
+    (0x9396, &[0xe1, 0xf0], false),
+    (0x8870, &[0xe1, 0xf1], false),
+    (0x91d7, &[0xe1, 0xf2], false),
+    (0x4fee, &[0xe1, 0xf3], false),
+    (0x53d7, &[0xe1, 0xf4], false),
+    (0x55fd, &[0xe1, 0xf5], false),
+    (0x56da, &[0xe1, 0xf6], false),
+    (0x5782, &[0xe1, 0xf7], false),
+    (0x58fd, &[0xe1, 0xf8], false),
+    (0x5ac2, &[0xe1, 0xf9], false),
+    (0x5b88, &[0xe1, 0xfa], false),
+    (0x5cab, &[0xe1, 0xfb], false),
+    (0x5cc0, &[0xe1, 0xfc], false),
+    (0x5e25, &[0xe1, 0xfd], false),
+    (0x6101, &[0xe1, 0xfe], false),
+    (0x620d, &[0xe2, 0xa1], false),
+    (0x624b, &[0xe2, 0xa2], false),
+    (0x6388, &[0xe2, 0xa3], false),
+    (0x641c, &[0xe2, 0xa4], false),
+    (0x6536, &[0xe2, 0xa5], false),
+    (0x6578, &[0xe2, 0xa6], false),
+    (0x6a39, &[0xe2, 0xa7], false),
+    (0x6b8a, &[0xe2, 0xa8], false),
+    (0x6c34, &[0xe2, 0xa9], false),
+    (0x6d19, &[0xe2, 0xaa], false),
+    (0x6f31, &[0xe2, 0xab], false),
+    (0x71e7, &[0xe2, 0xac], false),
+    (0x72e9, &[0xe2, 0xad], false),
+    (0x7378, &[0xe2, 0xae], false),
+    (0x7407, &[0xe2, 0xaf], false),
+    (0x74b2, &[0xe2, 0xb0], false),
+    (0x7626, &[0xe2, 0xb1], false),
+    (0x7761, &[0xe2, 0xb2], false),
+    (0x79c0, &[0xe2, 0xb3], false),
+    (0x7a57, &[0xe2, 0xb4], false),
+    (0x7aea, &[0xe2, 0xb5], false),
+    (0x7cb9, &[0xe2, 0xb6], false),
+    (0x7d8f, &[0xe2, 0xb7], false),
+    (0x7dac, &[0xe2, 0xb8], false),
+    (0x7e61, &[0xe2, 0xb9], false),
+    (0x7f9e, &[0xe2, 0xba], false),
+    (0x8129, &[0xe2, 0xbb], false),
+    (0x8331, &[0xe2, 0xbc], false),
+    (0x8490, &[0xe2, 0xbd], false),
+    (0x84da, &[0xe2, 0xbe], false),
+    (0x85ea, &[0xe2, 0xbf], false),
+    (0x8896, &[0xe2, 0xc0], false),
+    (0x8ab0, &[0xe2, 0xc1], false),
+    (0x8b90, &[0xe2, 0xc2], false),
+    (0x8f38, &[0xe2, 0xc3], false),
+    (0x9042, &[0xe2, 0xc4], false),
+    (0x9083, &[0xe2, 0xc5], false),
+    (0x916c, &[0xe2, 0xc6], false),
+    (0x9296, &[0xe2, 0xc7], false),
+    (0x92b9, &[0xe2, 0xc8], false),
+    (0x968b, &[0xe2, 0xc9], false),
+    (0x96a7, &[0xe2, 0xca], false),
+    (0x96a8, &[0xe2, 0xcb], false),
+    (0x96d6, &[0xe2, 0xcc], false),
+    (0x9700, &[0xe2, 0xcd], false),
+    (0x9808, &[0xe2, 0xce], false),
+    (0x9996, &[0xe2, 0xcf], false),
+    (0x9ad3, &[0xe2, 0xd0], false),
+    (0x9b1a, &[0xe2, 0xd1], false),
+    (0x53d4, &[0xe2, 0xd2], false),
+    (0x587e, &[0xe2, 0xd3], false),
+    (0x5919, &[0xe2, 0xd4], false),
+    (0x5b70, &[0xe2, 0xd5], false),
+    (0x5bbf, &[0xe2, 0xd6], false),
+    (0x6dd1, &[0xe2, 0xd7], false),
+    (0x6f5a, &[0xe2, 0xd8], false),
+    (0x719f, &[0xe2, 0xd9], false),
+    (0x7421, &[0xe2, 0xda], false),
+    (0x74b9, &[0xe2, 0xdb], false),
+    (0x8085, &[0xe2, 0xdc], false),
+    (0x83fd, &[0xe2, 0xdd], false),
+    (0x5de1, &[0xe2, 0xde], false),
+    (0x5f87, &[0xe2, 0xdf], false),
+    (0x5faa, &[0xe2, 0xe0], false),
+    (0x6042, &[0xe2, 0xe1], false),
+    (0x65ec, &[0xe2, 0xe2], false),
+    (0x6812, &[0xe2, 0xe3], false),
+    (0x696f, &[0xe2, 0xe4], false),
+    (0x6a53, &[0xe2, 0xe5], false),
+    (0x6b89, &[0xe2, 0xe6], false),
+    (0x6d35, &[0xe2, 0xe7], false),
+    (0x6df3, &[0xe2, 0xe8], false),
+    (0x73e3, &[0xe2, 0xe9], false),
+    (0x76fe, &[0xe2, 0xea], false),
+    (0x77ac, &[0xe2, 0xeb], false),
+    (0x7b4d, &[0xe2, 0xec], false),
+    (0x7d14, &[0xe2, 0xed], false),
+    (0x8123, &[0xe2, 0xee], false),
+    (0x821c, &[0xe2, 0xef], false),
+    (0x8340, &[0xe2, 0xf0], false),
+    (0x84f4, &[0xe2, 0xf1], false),
+    (0x8563, &[0xe2, 0xf2], false),
+    (0x8a62, &[0xe2, 0xf3], false),
+    (0x8ac4, &[0xe2, 0xf4], false),
+    (0x9187, &[0xe2, 0xf5], false),
+    (0x931e, &[0xe2, 0xf6], false),
+    (0x9806, &[0xe2, 0xf7], false),
+    (0x99b4, &[0xe2, 0xf8], false),
+    (0x620c, &[0xe2, 0xf9], false),
+    (0x8853, &[0xe2, 0xfa], false),
+    (0x8ff0, &[0xe2, 0xfb], false),
+    (0x9265, &[0xe2, 0xfc], false),
+    (0x5d07, &[0xe2, 0xfd], false),
+    (0x5d27, &[0xe2, 0xfe], false),
+    (0x5d69, &[0xe3, 0xa1], false),
+    (0x745f, &[0xe3, 0xa2], false),
+    (0x819d, &[0xe3, 0xa3], false),
+    (0x8768, &[0xe3, 0xa4], false),
+    (0x6fd5, &[0xe3, 0xa5], false),
+    (0x62fe, &[0xe3, 0xa6], false),
+    (0x7fd2, &[0xe3, 0xa7], false),
+    (0x8936, &[0xe3, 0xa8], false),
+    (0x8972, &[0xe3, 0xa9], false),
+    (0x4e1e, &[0xe3, 0xaa], false),
+    (0x4e58, &[0xe3, 0xab], false),
+    (0x50e7, &[0xe3, 0xac], false),
+    (0x52dd, &[0xe3, 0xad], false),
+    (0x5347, &[0xe3, 0xae], false),
+    (0x627f, &[0xe3, 0xaf], false),
+    (0x6607, &[0xe3, 0xb0], false),
+    (0x7e69, &[0xe3, 0xb1], false),
+    (0x8805, &[0xe3, 0xb2], false),
+    (0x965e, &[0xe3, 0xb3], false),
+    (0x4f8d, &[0xe3, 0xb4], false),
+    (0x5319, &[0xe3, 0xb5], false),
+    (0x5636, &[0xe3, 0xb6], false),
+    (0x59cb, &[0xe3, 0xb7], false),
+    (0x5aa4, &[0xe3, 0xb8], false),
+    (0x5c38, &[0xe3, 0xb9], false),
+    (0x5c4e, &[0xe3, 0xba], false),
+    (0x5c4d, &[0xe3, 0xbb], false),
+    (0x5e02, &[0xe3, 0xbc], false),
+    (0x5f11, &[0xe3, 0xbd], false),
+    (0x6043, &[0xe3, 0xbe], false),
+    (0x65bd, &[0xe3, 0xbf], false),
+    (0x662f, &[0xe3, 0xc0], false),
+    (0x6642, &[0xe3, 0xc1], false),
+    (0x67be, &[0xe3, 0xc2], false),
+    (0x67f4, &[0xe3, 0xc3], false),
+    (0x731c, &[0xe3, 0xc4], false),
+    (0x77e2, &[0xe3, 0xc5], false),
+    (0x793a, &[0xe3, 0xc6], false),
+    (0x7fc5, &[0xe3, 0xc7], false),
+    (0x8494, &[0xe3, 0xc8], false),
+    (0x84cd, &[0xe3, 0xc9], false),
+    (0x8996, &[0xe3, 0xca], false),
+    (0x8a66, &[0xe3, 0xcb], false),
+    (0x8a69, &[0xe3, 0xcc], false),
+    (0x8ae1, &[0xe3, 0xcd], false),
+    (0x8c55, &[0xe3, 0xce], false),
+    (0x8c7a, &[0xe3, 0xcf], false),
+    (0x57f4, &[0xe3, 0xd0], false),
+    (0x5bd4, &[0xe3, 0xd1], false),
+    (0x5f0f, &[0xe3, 0xd2], false),
+    (0x606f, &[0xe3, 0xd3], false),
+    (0x62ed, &[0xe3, 0xd4], false),
+    (0x690d, &[0xe3, 0xd5], false),
+    (0x6b96, &[0xe3, 0xd6], false),
+    (0x6e5c, &[0xe3, 0xd7], false),
+    (0x7184, &[0xe3, 0xd8], false),
+    (0x7bd2, &[0xe3, 0xd9], false),
+    (0x8755, &[0xe3, 0xda], false),
+    (0x8b58, &[0xe3, 0xdb], false),
+    (0x8efe, &[0xe3, 0xdc], false),
+    (0x98df, &[0xe3, 0xdd], false),
+    (0x98fe, &[0xe3, 0xde], false),
+    (0x4f38, &[0xe3, 0xdf], false),
+    (0x4f81, &[0xe3, 0xe0], false),
+    (0x4fe1, &[0xe3, 0xe1], false),
+    (0x547b, &[0xe3, 0xe2], false),
+    (0x5a20, &[0xe3, 0xe3], false),
+    (0x5bb8, &[0xe3, 0xe4], false),
+    (0x613c, &[0xe3, 0xe5], false),
+    (0x65b0, &[0xe3, 0xe6], false),
+    (0x6668, &[0xe3, 0xe7], false),
+    (0x71fc, &[0xe3, 0xe8], false),
+    (0x7533, &[0xe3, 0xe9], false),
+    (0x795e, &[0xe3, 0xea], false),
+    (0x7d33, &[0xe3, 0xeb], false),
+    (0x814e, &[0xe3, 0xec], false),
+    (0x81e3, &[0xe3, 0xed], false),
+    (0x8398, &[0xe3, 0xee], false),
+    (0x85aa, &[0xe3, 0xef], false),
+    (0x85ce, &[0xe3, 0xf0], false),
+    (0x8703, &[0xe3, 0xf1], false),
+    (0x8a0a, &[0xe3, 0xf2], false),
+    (0x8eab, &[0xe3, 0xf3], false),
+    (0x8f9b, &[0xe3, 0xf4], false),
+    (0xf971, &[0xe3, 0xf5], false),
+    (0x8fc5, &[0xe3, 0xf6], false),
+    (0x5931, &[0xe3, 0xf7], false),
+    (0x5ba4, &[0xe3, 0xf8], false),
+    (0x5be6, &[0xe3, 0xf9], false),
+    (0x6089, &[0xe3, 0xfa], false),
+    (0x5be9, &[0xe3, 0xfb], false),
+    (0x5c0b, &[0xe3, 0xfc], false),
+    (0x5fc3, &[0xe3, 0xfd], false),
+    (0x6c81, &[0xe3, 0xfe], false),
+    (0xf972, &[0xe4, 0xa1], false),
+    (0x6df1, &[0xe4, 0xa2], false),
+    (0x700b, &[0xe4, 0xa3], false),
+    (0x751a, &[0xe4, 0xa4], false),
+    (0x82af, &[0xe4, 0xa5], false),
+    (0x8af6, &[0xe4, 0xa6], false),
+    (0x4ec0, &[0xe4, 0xa7], false),
+    (0x5341, &[0xe4, 0xa8], false),
+    (0xf973, &[0xe4, 0xa9], false),
+    (0x96d9, &[0xe4, 0xaa], false),
+    (0x6c0f, &[0xe4, 0xab], false),
+    (0x4e9e, &[0xe4, 0xac], false),
+    (0x4fc4, &[0xe4, 0xad], false),
+    (0x5152, &[0xe4, 0xae], false),
+    (0x555e, &[0xe4, 0xaf], false),
+    (0x5a25, &[0xe4, 0xb0], false),
+    (0x5ce8, &[0xe4, 0xb1], false),
+    (0x6211, &[0xe4, 0xb2], false),
+    (0x7259, &[0xe4, 0xb3], false),
+    (0x82bd, &[0xe4, 0xb4], false),
+    (0x83aa, &[0xe4, 0xb5], false),
+    (0x86fe, &[0xe4, 0xb6], false),
+    (0x8859, &[0xe4, 0xb7], false),
+    (0x8a1d, &[0xe4, 0xb8], false),
+    (0x963f, &[0xe4, 0xb9], false),
+    (0x96c5, &[0xe4, 0xba], false),
+    (0x9913, &[0xe4, 0xbb], false),
+    (0x9d09, &[0xe4, 0xbc], false),
+    (0x9d5d, &[0xe4, 0xbd], false),
+    (0x580a, &[0xe4, 0xbe], false),
+    (0x5cb3, &[0xe4, 0xbf], false),
+    (0x5dbd, &[0xe4, 0xc0], false),
+    (0x5e44, &[0xe4, 0xc1], false),
+    (0x60e1, &[0xe4, 0xc2], false),
+    (0x6115, &[0xe4, 0xc3], false),
+    (0x63e1, &[0xe4, 0xc4], false),
+    (0x6a02, &[0xe4, 0xc5], false),
+    (0x6e25, &[0xe4, 0xc6], false),
+    (0x9102, &[0xe4, 0xc7], false),
+    (0x9354, &[0xe4, 0xc8], false),
+    (0x984e, &[0xe4, 0xc9], false),
+    (0x9c10, &[0xe4, 0xca], false),
+    (0x9f77, &[0xe4, 0xcb], false),
+    (0x5b89, &[0xe4, 0xcc], false),
+    (0x5cb8, &[0xe4, 0xcd], false),
+    (0x6309, &[0xe4, 0xce], false),
+    (0x664f, &[0xe4, 0xcf], false),
+    (0x6848, &[0xe4, 0xd0], false),
+    (0x773c, &[0xe4, 0xd1], false),
+    (0x96c1, &[0xe4, 0xd2], false),
+    (0x978d, &[0xe4, 0xd3], false),
+    (0x9854, &[0xe4, 0xd4], false),
+    (0x9b9f, &[0xe4, 0xd5], false),
+    (0x65a1, &[0xe4, 0xd6], false),
+    (0x8b01, &[0xe4, 0xd7], false),
+    (0x8ecb, &[0xe4, 0xd8], false),
+    (0x95bc, &[0xe4, 0xd9], false),
+    (0x5535, &[0xe4, 0xda], false),
+    (0x5ca9, &[0xe4, 0xdb], false),
+    (0x5dd6, &[0xe4, 0xdc], false),
+    (0x5eb5, &[0xe4, 0xdd], false),
+    (0x6697, &[0xe4, 0xde], false),
+    (0x764c, &[0xe4, 0xdf], false),
+    (0x83f4, &[0xe4, 0xe0], false),
+    (0x95c7, &[0xe4, 0xe1], false),
+    (0x58d3, &[0xe4, 0xe2], false),
+    (0x62bc, &[0xe4, 0xe3], false),
+    (0x72ce, &[0xe4, 0xe4], false),
+    (0x9d28, &[0xe4, 0xe5], false),
+    (0x4ef0, &[0xe4, 0xe6], false),
+    (0x592e, &[0xe4, 0xe7], false),
+    (0x600f, &[0xe4, 0xe8], false),
+    (0x663b, &[0xe4, 0xe9], false),
+    (0x6b83, &[0xe4, 0xea], false),
+    (0x79e7, &[0xe4, 0xeb], false),
+    (0x9d26, &[0xe4, 0xec], false),
+    (0x5393, &[0xe4, 0xed], false),
+    (0x54c0, &[0xe4, 0xee], false),
+    (0x57c3, &[0xe4, 0xef], false),
+    (0x5d16, &[0xe4, 0xf0], false),
+    (0x611b, &[0xe4, 0xf1], false),
+    (0x66d6, &[0xe4, 0xf2], false),
+    (0x6daf, &[0xe4, 0xf3], false),
+    (0x788d, &[0xe4, 0xf4], false),
+    (0x827e, &[0xe4, 0xf5], false),
+    (0x9698, &[0xe4, 0xf6], false),
+    (0x9744, &[0xe4, 0xf7], false),
+    (0x5384, &[0xe4, 0xf8], false),
+    (0x627c, &[0xe4, 0xf9], false),
+    (0x6396, &[0xe4, 0xfa], false),
+    (0x6db2, &[0xe4, 0xfb], false),
+    (0x7e0a, &[0xe4, 0xfc], false),
+    (0x814b, &[0xe4, 0xfd], false),
+    (0x984d, &[0xe4, 0xfe], false),
+    (0x6afb, &[0xe5, 0xa1], false),
+    (0x7f4c, &[0xe5, 0xa2], false),
+    (0x9daf, &[0xe5, 0xa3], false),
+    (0x9e1a, &[0xe5, 0xa4], false),
+    (0x4e5f, &[0xe5, 0xa5], false),
+    (0x503b, &[0xe5, 0xa6], false),
+    (0x51b6, &[0xe5, 0xa7], false),
+    (0x591c, &[0xe5, 0xa8], false),
+    (0x60f9, &[0xe5, 0xa9], false),
+    (0x63f6, &[0xe5, 0xaa], false),
+    (0x6930, &[0xe5, 0xab], false),
+    (0x723a, &[0xe5, 0xac], false),
+    (0x8036, &[0xe5, 0xad], false),
+    (0xf974, &[0xe5, 0xae], false),
+    (0x91ce, &[0xe5, 0xaf], false),
+    (0x5f31, &[0xe5, 0xb0], false),
+    (0xf975, &[0xe5, 0xb1], false),
+    (0xf976, &[0xe5, 0xb2], false),
+    (0x7d04, &[0xe5, 0xb3], false),
+    (0x82e5, &[0xe5, 0xb4], false),
+    (0x846f, &[0xe5, 0xb5], false),
+    (0x84bb, &[0xe5, 0xb6], false),
+    (0x85e5, &[0xe5, 0xb7], false),
+    (0x8e8d, &[0xe5, 0xb8], false),
+    (0xf977, &[0xe5, 0xb9], false),
+    (0x4f6f, &[0xe5, 0xba], false),
+    (0xf978, &[0xe5, 0xbb], false),
+    (0xf979, &[0xe5, 0xbc], false),
+    (0x58e4, &[0xe5, 0xbd], false),
+    (0x5b43, &[0xe5, 0xbe], false),
+    (0x6059, &[0xe5, 0xbf], false),
+    (0x63da, &[0xe5, 0xc0], false),
+    (0x6518, &[0xe5, 0xc1], false),
+    (0x656d, &[0xe5, 0xc2], false),
+    (0x6698, &[0xe5, 0xc3], false),
+    (0xf97a, &[0xe5, 0xc4], false),
+    (0x694a, &[0xe5, 0xc5], false),
+    (0x6a23, &[0xe5, 0xc6], false),
+    (0x6d0b, &[0xe5, 0xc7], false),
+    (0x7001, &[0xe5, 0xc8], false),
+    (0x716c, &[0xe5, 0xc9], false),
+    (0x75d2, &[0xe5, 0xca], false),
+    (0x760d, &[0xe5, 0xcb], false),
+    (0x79b3, &[0xe5, 0xcc], false),
+    (0x7a70, &[0xe5, 0xcd], false),
+    (0xf97b, &[0xe5, 0xce], false),
+    (0x7f8a, &[0xe5, 0xcf], false),
+    (0xf97c, &[0xe5, 0xd0], false),
+    (0x8944, &[0xe5, 0xd1], false),
+    (0xf97d, &[0xe5, 0xd2], false),
+    (0x8b93, &[0xe5, 0xd3], false),
+    (0x91c0, &[0xe5, 0xd4], false),
+    (0x967d, &[0xe5, 0xd5], false),
+    (0xf97e, &[0xe5, 0xd6], false),
+    (0x990a, &[0xe5, 0xd7], false),
+    (0x5704, &[0xe5, 0xd8], false),
+    (0x5fa1, &[0xe5, 0xd9], false),
+    (0x65bc, &[0xe5, 0xda], false),
+    (0x6f01, &[0xe5, 0xdb], false),
+    (0x7600, &[0xe5, 0xdc], false),
+    (0x79a6, &[0xe5, 0xdd], false),
+    (0x8a9e, &[0xe5, 0xde], false),
+    (0x99ad, &[0xe5, 0xdf], false),
+    (0x9b5a, &[0xe5, 0xe0], false),
+    (0x9f6c, &[0xe5, 0xe1], false),
+    (0x5104, &[0xe5, 0xe2], false),
+    (0x61b6, &[0xe5, 0xe3], false),
+    (0x6291, &[0xe5, 0xe4], false),
+    (0x6a8d, &[0xe5, 0xe5], false),
+    (0x81c6, &[0xe5, 0xe6], false),
+    (0x5043, &[0xe5, 0xe7], false),
+    (0x5830, &[0xe5, 0xe8], false),
+    (0x5f66, &[0xe5, 0xe9], false),
+    (0x7109, &[0xe5, 0xea], false),
+    (0x8a00, &[0xe5, 0xeb], false),
+    (0x8afa, &[0xe5, 0xec], false),
+    (0x5b7c, &[0xe5, 0xed], false),
+    (0x8616, &[0xe5, 0xee], false),
+    (0x4ffa, &[0xe5, 0xef], false),
+    (0x513c, &[0xe5, 0xf0], false),
+    (0x56b4, &[0xe5, 0xf1], false),
+    (0x5944, &[0xe5, 0xf2], false),
+    (0x63a9, &[0xe5, 0xf3], false),
+    (0x6df9, &[0xe5, 0xf4], false),
+    (0x5daa, &[0xe5, 0xf5], false),
+    (0x696d, &[0xe5, 0xf6], false),
+    (0x5186, &[0xe5, 0xf7], false),
+    (0x4e88, &[0xe5, 0xf8], false),
+    (0x4f59, &[0xe5, 0xf9], false),
+    (0xf97f, &[0xe5, 0xfa], false),
+    (0xf980, &[0xe5, 0xfb], false),
+    (0xf981, &[0xe5, 0xfc], false),
+    (0x5982, &[0xe5, 0xfd], false),
+    (0xf982, &[0xe5, 0xfe], false),
+    (0xf983, &[0xe6, 0xa1], false),
+    (0x6b5f, &[0xe6, 0xa2], false),
+    (0x6c5d, &[0xe6, 0xa3], false),
+    (0xf984, &[0xe6, 0xa4], false),
+    (0x74b5, &[0xe6, 0xa5], false),
+    (0x7916, &[0xe6, 0xa6], false),
+    (0xf985, &[0xe6, 0xa7], false),
+    (0x8207, &[0xe6, 0xa8], false),
+    (0x8245, &[0xe6, 0xa9], false),
+    (0x8339, &[0xe6, 0xaa], false),
+    (0x8f3f, &[0xe6, 0xab], false),
+    (0x8f5d, &[0xe6, 0xac], false),
+    (0xf986, &[0xe6, 0xad], false),
+    (0x9918, &[0xe6, 0xae], false),
+    (0xf987, &[0xe6, 0xaf], false),
+    (0xf988, &[0xe6, 0xb0], false),
+    (0xf989, &[0xe6, 0xb1], false),
+    (0x4ea6, &[0xe6, 0xb2], false),
+    (0xf98a, &[0xe6, 0xb3], false),
+    (0x57df, &[0xe6, 0xb4], false),
+    (0x5f79, &[0xe6, 0xb5], false),
+    (0x6613, &[0xe6, 0xb6], false),
+    (0xf98b, &[0xe6, 0xb7], false),
+    (0xf98c, &[0xe6, 0xb8], false),
+    (0x75ab, &[0xe6, 0xb9], false),
+    (0x7e79, &[0xe6, 0xba], false),
+    (0x8b6f, &[0xe6, 0xbb], false),
+    (0xf98d, &[0xe6, 0xbc], false),
+    (0x9006, &[0xe6, 0xbd], false),
+    (0x9a5b, &[0xe6, 0xbe], false),
+    (0x56a5, &[0xe6, 0xbf], false),
+    (0x5827, &[0xe6, 0xc0], false),
+    (0x59f8, &[0xe6, 0xc1], false),
+    (0x5a1f, &[0xe6, 0xc2], false),
+    (0x5bb4, &[0xe6, 0xc3], false),
+    (0xf98e, &[0xe6, 0xc4], false),
+    (0x5ef6, &[0xe6, 0xc5], false),
+    (0xf98f, &[0xe6, 0xc6], false),
+    (0xf990, &[0xe6, 0xc7], false),
+    (0x6350, &[0xe6, 0xc8], false),
+    (0x633b, &[0xe6, 0xc9], false),
+    (0xf991, &[0xe6, 0xca], false),
+    (0x693d, &[0xe6, 0xcb], false),
+    (0x6c87, &[0xe6, 0xcc], false),
+    (0x6cbf, &[0xe6, 0xcd], false),
+    (0x6d8e, &[0xe6, 0xce], false),
+    (0x6d93, &[0xe6, 0xcf], false),
+    (0x6df5, &[0xe6, 0xd0], false),
+    (0x6f14, &[0xe6, 0xd1], false),
+    (0xf992, &[0xe6, 0xd2], false),
+    (0x70df, &[0xe6, 0xd3], false),
+    (0x7136, &[0xe6, 0xd4], false),
+    (0x7159, &[0xe6, 0xd5], false),
+    (0xf993, &[0xe6, 0xd6], false),
+    (0x71c3, &[0xe6, 0xd7], false),
+    (0x71d5, &[0xe6, 0xd8], false),
+    (0xf994, &[0xe6, 0xd9], false),
+    (0x784f, &[0xe6, 0xda], false),
+    (0x786f, &[0xe6, 0xdb], false),
+    (0xf995, &[0xe6, 0xdc], false),
+    (0x7b75, &[0xe6, 0xdd], false),
+    (0x7de3, &[0xe6, 0xde], false),
+    (0xf996, &[0xe6, 0xdf], false),
+    (0x7e2f, &[0xe6, 0xe0], false),
+    (0xf997, &[0xe6, 0xe1], false),
+    (0x884d, &[0xe6, 0xe2], false),
+    (0x8edf, &[0xe6, 0xe3], false),
+    (0xf998, &[0xe6, 0xe4], false),
+    (0xf999, &[0xe6, 0xe5], false),
+    (0xf99a, &[0xe6, 0xe6], false),
+    (0x925b, &[0xe6, 0xe7], false),
+    (0xf99b, &[0xe6, 0xe8], false),
+    (0x9cf6, &[0xe6, 0xe9], false),
+    (0xf99c, &[0xe6, 0xea], false),
+    (0xf99d, &[0xe6, 0xeb], false),
+    (0xf99e, &[0xe6, 0xec], false),
+    (0x6085, &[0xe6, 0xed], false),
+    (0x6d85, &[0xe6, 0xee], false),
+    (0xf99f, &[0xe6, 0xef], false),
+    (0x71b1, &[0xe6, 0xf0], false),
+    (0xf9a0, &[0xe6, 0xf1], false),
+    (0xf9a1, &[0xe6, 0xf2], false),
+    (0x95b1, &[0xe6, 0xf3], false),
+    (0x53ad, &[0xe6, 0xf4], false),
+    (0xf9a2, &[0xe6, 0xf5], false),
+    (0xf9a3, &[0xe6, 0xf6], false),
+    (0xf9a4, &[0xe6, 0xf7], false),
+    (0x67d3, &[0xe6, 0xf8], false),
+    (0xf9a5, &[0xe6, 0xf9], false),
+    (0x708e, &[0xe6, 0xfa], false),
+    (0x7130, &[0xe6, 0xfb], false),
+    (0x7430, &[0xe6, 0xfc], false),
+    (0x8276, &[0xe6, 0xfd], false),
+    (0x82d2, &[0xe6, 0xfe], false),
+    (0xf9a6, &[0xe7, 0xa1], false),
+    (0x95bb, &[0xe7, 0xa2], false),
+    (0x9ae5, &[0xe7, 0xa3], false),
+    (0x9e7d, &[0xe7, 0xa4], false),
+    (0x66c4, &[0xe7, 0xa5], false),
+    (0xf9a7, &[0xe7, 0xa6], false),
+    (0x71c1, &[0xe7, 0xa7], false),
+    (0x8449, &[0xe7, 0xa8], false),
+    (0xf9a8, &[0xe7, 0xa9], false),
+    (0xf9a9, &[0xe7, 0xaa], false),
+    (0x584b, &[0xe7, 0xab], false),
+    (0xf9aa, &[0xe7, 0xac], false),
+    (0xf9ab, &[0xe7, 0xad], false),
+    (0x5db8, &[0xe7, 0xae], false),
+    (0x5f71, &[0xe7, 0xaf], false),
+    (0xf9ac, &[0xe7, 0xb0], false),
+    (0x6620, &[0xe7, 0xb1], false),
+    (0x668e, &[0xe7, 0xb2], false),
+    (0x6979, &[0xe7, 0xb3], false),
+    (0x69ae, &[0xe7, 0xb4], false),
+    (0x6c38, &[0xe7, 0xb5], false),
+    (0x6cf3, &[0xe7, 0xb6], false),
+    (0x6e36, &[0xe7, 0xb7], false),
+    (0x6f41, &[0xe7, 0xb8], false),
+    (0x6fda, &[0xe7, 0xb9], false),
+    (0x701b, &[0xe7, 0xba], false),
+    (0x702f, &[0xe7, 0xbb], false),
+    (0x7150, &[0xe7, 0xbc], false),
+    (0x71df, &[0xe7, 0xbd], false),
+    (0x7370, &[0xe7, 0xbe], false),
+    (0xf9ad, &[0xe7, 0xbf], false),
+    (0x745b, &[0xe7, 0xc0], false),
+    (0xf9ae, &[0xe7, 0xc1], false),
+    (0x74d4, &[0xe7, 0xc2], false),
+    (0x76c8, &[0xe7, 0xc3], false),
+    (0x7a4e, &[0xe7, 0xc4], false),
+    (0x7e93, &[0xe7, 0xc5], false),
+    (0xf9af, &[0xe7, 0xc6], false),
+    (0xf9b0, &[0xe7, 0xc7], false),
+    (0x82f1, &[0xe7, 0xc8], false),
+    (0x8a60, &[0xe7, 0xc9], false),
+    (0x8fce, &[0xe7, 0xca], false),
+    (0xf9b1, &[0xe7, 0xcb], false),
+    (0x9348, &[0xe7, 0xcc], false),
+    (0xf9b2, &[0xe7, 0xcd], false),
+    (0x9719, &[0xe7, 0xce], false),
+    (0xf9b3, &[0xe7, 0xcf], false),
+    (0xf9b4, &[0xe7, 0xd0], false),
+    (0x4e42, &[0xe7, 0xd1], false),
+    (0x502a, &[0xe7, 0xd2], false),
+    (0xf9b5, &[0xe7, 0xd3], false),
+    (0x5208, &[0xe7, 0xd4], false),
+    (0x53e1, &[0xe7, 0xd5], false),
+    (0x66f3, &[0xe7, 0xd6], false),
+    (0x6c6d, &[0xe7, 0xd7], false),
+    (0x6fca, &[0xe7, 0xd8], false),
+    (0x730a, &[0xe7, 0xd9], false),
+    (0x777f, &[0xe7, 0xda], false),
+    (0x7a62, &[0xe7, 0xdb], false),
+    (0x82ae, &[0xe7, 0xdc], false),
+    (0x85dd, &[0xe7, 0xdd], false),
+    (0x8602, &[0xe7, 0xde], false),
+    (0xf9b6, &[0xe7, 0xdf], false),
+    (0x88d4, &[0xe7, 0xe0], false),
+    (0x8a63, &[0xe7, 0xe1], false),
+    (0x8b7d, &[0xe7, 0xe2], false),
+    (0x8c6b, &[0xe7, 0xe3], false),
+    (0xf9b7, &[0xe7, 0xe4], false),
+    (0x92b3, &[0xe7, 0xe5], false),
+    (0xf9b8, &[0xe7, 0xe6], false),
+    (0x9713, &[0xe7, 0xe7], false),
+    (0x9810, &[0xe7, 0xe8], false),
+    (0x4e94, &[0xe7, 0xe9], false),
+    (0x4f0d, &[0xe7, 0xea], false),
+    (0x4fc9, &[0xe7, 0xeb], false),
+    (0x50b2, &[0xe7, 0xec], false),
+    (0x5348, &[0xe7, 0xed], false),
+    (0x543e, &[0xe7, 0xee], false),
+    (0x5433, &[0xe7, 0xef], false),
+    (0x55da, &[0xe7, 0xf0], false),
+    (0x5862, &[0xe7, 0xf1], false),
+    (0x58ba, &[0xe7, 0xf2], false),
+    (0x5967, &[0xe7, 0xf3], false),
+    (0x5a1b, &[0xe7, 0xf4], false),
+    (0x5be4, &[0xe7, 0xf5], false),
+    (0x609f, &[0xe7, 0xf6], false),
+    (0xf9b9, &[0xe7, 0xf7], false),
+    (0x61ca, &[0xe7, 0xf8], false),
+    (0x6556, &[0xe7, 0xf9], false),
+    (0x65ff, &[0xe7, 0xfa], false),
+    (0x6664, &[0xe7, 0xfb], false),
+    (0x68a7, &[0xe7, 0xfc], false),
+    (0x6c5a, &[0xe7, 0xfd], false),
+    (0x6fb3, &[0xe7, 0xfe], false),
+    (0x70cf, &[0xe8, 0xa1], false),
+    (0x71ac, &[0xe8, 0xa2], false),
+    (0x7352, &[0xe8, 0xa3], false),
+    (0x7b7d, &[0xe8, 0xa4], false),
+    (0x8708, &[0xe8, 0xa5], false),
+    (0x8aa4, &[0xe8, 0xa6], false),
+    (0x9c32, &[0xe8, 0xa7], false),
+    (0x9f07, &[0xe8, 0xa8], false),
+    (0x5c4b, &[0xe8, 0xa9], false),
+    (0x6c83, &[0xe8, 0xaa], false),
+    (0x7344, &[0xe8, 0xab], false),
+    (0x7389, &[0xe8, 0xac], false),
+    (0x923a, &[0xe8, 0xad], false),
+    (0x6eab, &[0xe8, 0xae], false),
+    (0x7465, &[0xe8, 0xaf], false),
+    (0x761f, &[0xe8, 0xb0], false),
+    (0x7a69, &[0xe8, 0xb1], false),
+    (0x7e15, &[0xe8, 0xb2], false),
+    (0x860a, &[0xe8, 0xb3], false),
+    (0x5140, &[0xe8, 0xb4], false),
+    (0x58c5, &[0xe8, 0xb5], false),
+    (0x64c1, &[0xe8, 0xb6], false),
+    (0x74ee, &[0xe8, 0xb7], false),
+    (0x7515, &[0xe8, 0xb8], false),
+    (0x7670, &[0xe8, 0xb9], false),
+    (0x7fc1, &[0xe8, 0xba], false),
+    (0x9095, &[0xe8, 0xbb], false),
+    (0x96cd, &[0xe8, 0xbc], false),
+    (0x9954, &[0xe8, 0xbd], false),
+    (0x6e26, &[0xe8, 0xbe], false),
+    (0x74e6, &[0xe8, 0xbf], false),
+    (0x7aa9, &[0xe8, 0xc0], false),
+    (0x7aaa, &[0xe8, 0xc1], false),
+    (0x81e5, &[0xe8, 0xc2], false),
+    (0x86d9, &[0xe8, 0xc3], false),
+    (0x8778, &[0xe8, 0xc4], false),
+    (0x8a1b, &[0xe8, 0xc5], false),
+    (0x5a49, &[0xe8, 0xc6], false),
+    (0x5b8c, &[0xe8, 0xc7], false),
+    (0x5b9b, &[0xe8, 0xc8], false),
+    (0x68a1, &[0xe8, 0xc9], false),
+    (0x6900, &[0xe8, 0xca], false),
+    (0x6d63, &[0xe8, 0xcb], false),
+    (0x73a9, &[0xe8, 0xcc], false),
+    (0x7413, &[0xe8, 0xcd], false),
+    (0x742c, &[0xe8, 0xce], false),
+    (0x7897, &[0xe8, 0xcf], false),
+    (0x7de9, &[0xe8, 0xd0], false),
+    (0x7feb, &[0xe8, 0xd1], false),
+    (0x8118, &[0xe8, 0xd2], false),
+    (0x8155, &[0xe8, 0xd3], false),
+    (0x839e, &[0xe8, 0xd4], false),
+    (0x8c4c, &[0xe8, 0xd5], false),
+    (0x962e, &[0xe8, 0xd6], false),
+    (0x9811, &[0xe8, 0xd7], false),
+    (0x66f0, &[0xe8, 0xd8], false),
+    (0x5f80, &[0xe8, 0xd9], false),
+    (0x65fa, &[0xe8, 0xda], false),
+    (0x6789, &[0xe8, 0xdb], false),
+    (0x6c6a, &[0xe8, 0xdc], false),
+    (0x738b, &[0xe8, 0xdd], false),
+    (0x502d, &[0xe8, 0xde], false),
+    (0x5a03, &[0xe8, 0xdf], false),
+    (0x6b6a, &[0xe8, 0xe0], false),
+    (0x77ee, &[0xe8, 0xe1], false),
+    (0x5916, &[0xe8, 0xe2], false),
+    (0x5d6c, &[0xe8, 0xe3], false),
+    (0x5dcd, &[0xe8, 0xe4], false),
+    (0x7325, &[0xe8, 0xe5], false),
+    (0x754f, &[0xe8, 0xe6], false),
+    (0xf9ba, &[0xe8, 0xe7], false),
+    (0xf9bb, &[0xe8, 0xe8], false),
+    (0x50e5, &[0xe8, 0xe9], false),
+    (0x51f9, &[0xe8, 0xea], false),
+    (0x582f, &[0xe8, 0xeb], false),
+    (0x592d, &[0xe8, 0xec], false),
+    (0x5996, &[0xe8, 0xed], false),
+    (0x59da, &[0xe8, 0xee], false),
+    (0x5be5, &[0xe8, 0xef], false),
+    (0xf9bc, &[0xe8, 0xf0], false),
+    (0xf9bd, &[0xe8, 0xf1], false),
+    (0x5da2, &[0xe8, 0xf2], false),
+    (0x62d7, &[0xe8, 0xf3], false),
+    (0x6416, &[0xe8, 0xf4], false),
+    (0x6493, &[0xe8, 0xf5], false),
+    (0x64fe, &[0xe8, 0xf6], false),
+    (0xf9be, &[0xe8, 0xf7], false),
+    (0x66dc, &[0xe8, 0xf8], false),
+    (0xf9bf, &[0xe8, 0xf9], false),
+    (0x6a48, &[0xe8, 0xfa], false),
+    (0xf9c0, &[0xe8, 0xfb], false),
+    (0x71ff, &[0xe8, 0xfc], false),
+    (0x7464, &[0xe8, 0xfd], false),
+    (0xf9c1, &[0xe8, 0xfe], false),
+    (0x7a88, &[0xe9, 0xa1], false),
+    (0x7aaf, &[0xe9, 0xa2], false),
+    (0x7e47, &[0xe9, 0xa3], false),
+    (0x7e5e, &[0xe9, 0xa4], false),
+    (0x8000, &[0xe9, 0xa5], false),
+    (0x8170, &[0xe9, 0xa6], false),
+    (0xf9c2, &[0xe9, 0xa7], false),
+    (0x87ef, &[0xe9, 0xa8], false),
+    (0x8981, &[0xe9, 0xa9], false),
+    (0x8b20, &[0xe9, 0xaa], false),
+    (0x9059, &[0xe9, 0xab], false),
+    (0xf9c3, &[0xe9, 0xac], false),
+    (0x9080, &[0xe9, 0xad], false),
+    (0x9952, &[0xe9, 0xae], false),
+    (0x617e, &[0xe9, 0xaf], false),
+    (0x6b32, &[0xe9, 0xb0], false),
+    (0x6d74, &[0xe9, 0xb1], false),
+    (0x7e1f, &[0xe9, 0xb2], false),
+    (0x8925, &[0xe9, 0xb3], false),
+    (0x8fb1, &[0xe9, 0xb4], false),
+    (0x4fd1, &[0xe9, 0xb5], false),
+    (0x50ad, &[0xe9, 0xb6], false),
+    (0x5197, &[0xe9, 0xb7], false),
+    (0x52c7, &[0xe9, 0xb8], false),
+    (0x57c7, &[0xe9, 0xb9], false),
+    (0x5889, &[0xe9, 0xba], false),
+    (0x5bb9, &[0xe9, 0xbb], false),
+    (0x5eb8, &[0xe9, 0xbc], false),
+    (0x6142, &[0xe9, 0xbd], false),
+    (0x6995, &[0xe9, 0xbe], false),
+    (0x6d8c, &[0xe9, 0xbf], false),
+    (0x6e67, &[0xe9, 0xc0], false),
+    (0x6eb6, &[0xe9, 0xc1], false),
+    (0x7194, &[0xe9, 0xc2], false),
+    (0x7462, &[0xe9, 0xc3], false),
+    (0x7528, &[0xe9, 0xc4], false),
+    (0x752c, &[0xe9, 0xc5], false),
+    (0x8073, &[0xe9, 0xc6], false),
+    (0x8338, &[0xe9, 0xc7], false),
+    (0x84c9, &[0xe9, 0xc8], false),
+    (0x8e0a, &[0xe9, 0xc9], false),
+    (0x9394, &[0xe9, 0xca], false),
+    (0x93de, &[0xe9, 0xcb], false),
+    (0xf9c4, &[0xe9, 0xcc], false),
+    (0x4e8e, &[0xe9, 0xcd], false),
+    (0x4f51, &[0xe9, 0xce], false),
+    (0x5076, &[0xe9, 0xcf], false),
+    (0x512a, &[0xe9, 0xd0], false),
+    (0x53c8, &[0xe9, 0xd1], false),
+    (0x53cb, &[0xe9, 0xd2], false),
+    (0x53f3, &[0xe9, 0xd3], false),
+    (0x5b87, &[0xe9, 0xd4], false),
+    (0x5bd3, &[0xe9, 0xd5], false),
+    (0x5c24, &[0xe9, 0xd6], false),
+    (0x611a, &[0xe9, 0xd7], false),
+    (0x6182, &[0xe9, 0xd8], false),
+    (0x65f4, &[0xe9, 0xd9], false),
+    (0x725b, &[0xe9, 0xda], false),
+    (0x7397, &[0xe9, 0xdb], false),
+    (0x7440, &[0xe9, 0xdc], false),
+    (0x76c2, &[0xe9, 0xdd], false),
+    (0x7950, &[0xe9, 0xde], false),
+    (0x7991, &[0xe9, 0xdf], false),
+    (0x79b9, &[0xe9, 0xe0], false),
+    (0x7d06, &[0xe9, 0xe1], false),
+    (0x7fbd, &[0xe9, 0xe2], false),
+    (0x828b, &[0xe9, 0xe3], false),
+    (0x85d5, &[0xe9, 0xe4], false),
+    (0x865e, &[0xe9, 0xe5], false),
+    (0x8fc2, &[0xe9, 0xe6], false),
+    (0x9047, &[0xe9, 0xe7], false),
+    (0x90f5, &[0xe9, 0xe8], false),
+    (0x91ea, &[0xe9, 0xe9], false),
+    (0x9685, &[0xe9, 0xea], false),
+    (0x96e8, &[0xe9, 0xeb], false),
+    (0x96e9, &[0xe9, 0xec], false),
+    (0x52d6, &[0xe9, 0xed], false),
+    (0x5f67, &[0xe9, 0xee], false),
+    (0x65ed, &[0xe9, 0xef], false),
+    (0x6631, &[0xe9, 0xf0], false),
+    (0x682f, &[0xe9, 0xf1], false),
+    (0x715c, &[0xe9, 0xf2], false),
+    (0x7a36, &[0xe9, 0xf3], false),
+    (0x90c1, &[0xe9, 0xf4], false),
+    (0x980a, &[0xe9, 0xf5], false),
+    (0x4e91, &[0xe9, 0xf6], false),
+    (0xf9c5, &[0xe9, 0xf7], false),
+    (0x6a52, &[0xe9, 0xf8], false),
+    (0x6b9e, &[0xe9, 0xf9], false),
+    (0x6f90, &[0xe9, 0xfa], false),
+    (0x7189, &[0xe9, 0xfb], false),
+    (0x8018, &[0xe9, 0xfc], false),
+    (0x82b8, &[0xe9, 0xfd], false),
+    (0x8553, &[0xe9, 0xfe], false),
+    (0x904b, &[0xea, 0xa1], false),
+    (0x9695, &[0xea, 0xa2], false),
+    (0x96f2, &[0xea, 0xa3], false),
+    (0x97fb, &[0xea, 0xa4], false),
+    (0x851a, &[0xea, 0xa5], false),
+    (0x9b31, &[0xea, 0xa6], false),
+    (0x4e90, &[0xea, 0xa7], false),
+    (0x718a, &[0xea, 0xa8], false),
+    (0x96c4, &[0xea, 0xa9], false),
+    (0x5143, &[0xea, 0xaa], false),
+    (0x539f, &[0xea, 0xab], false),
+    (0x54e1, &[0xea, 0xac], false),
+    (0x5713, &[0xea, 0xad], false),
+    (0x5712, &[0xea, 0xae], false),
+    (0x57a3, &[0xea, 0xaf], false),
+    (0x5a9b, &[0xea, 0xb0], false),
+    (0x5ac4, &[0xea, 0xb1], false),
+    (0x5bc3, &[0xea, 0xb2], false),
+    (0x6028, &[0xea, 0xb3], false),
+    (0x613f, &[0xea, 0xb4], false),
+    (0x63f4, &[0xea, 0xb5], false),
+    (0x6c85, &[0xea, 0xb6], false),
+    (0x6d39, &[0xea, 0xb7], false),
+    (0x6e72, &[0xea, 0xb8], false),
+    (0x6e90, &[0xea, 0xb9], false),
+    (0x7230, &[0xea, 0xba], false),
+    (0x733f, &[0xea, 0xbb], false),
+    (0x7457, &[0xea, 0xbc], false),
+    (0x82d1, &[0xea, 0xbd], false),
+    (0x8881, &[0xea, 0xbe], false),
+    (0x8f45, &[0xea, 0xbf], false),
+    (0x9060, &[0xea, 0xc0], false),
+    (0xf9c6, &[0xea, 0xc1], false),
+    (0x9662, &[0xea, 0xc2], false),
+    (0x9858, &[0xea, 0xc3], false),
+    (0x9d1b, &[0xea, 0xc4], false),
+    (0x6708, &[0xea, 0xc5], false),
+    (0x8d8a, &[0xea, 0xc6], false),
+    (0x925e, &[0xea, 0xc7], false),
+    (0x4f4d, &[0xea, 0xc8], false),
+    (0x5049, &[0xea, 0xc9], false),
+    (0x50de, &[0xea, 0xca], false),
+    (0x5371, &[0xea, 0xcb], false),
+    (0x570d, &[0xea, 0xcc], false),
+    (0x59d4, &[0xea, 0xcd], false),
+    (0x5a01, &[0xea, 0xce], false),
+    (0x5c09, &[0xea, 0xcf], false),
+    (0x6170, &[0xea, 0xd0], false),
+    (0x6690, &[0xea, 0xd1], false),
+    (0x6e2d, &[0xea, 0xd2], false),
+    (0x7232, &[0xea, 0xd3], false),
+    (0x744b, &[0xea, 0xd4], false),
+    (0x7def, &[0xea, 0xd5], false),
+    (0x80c3, &[0xea, 0xd6], false),
+    (0x840e, &[0xea, 0xd7], false),
+    (0x8466, &[0xea, 0xd8], false),
+    (0x853f, &[0xea, 0xd9], false),
+    (0x875f, &[0xea, 0xda], false),
+    (0x885b, &[0xea, 0xdb], false),
+    (0x8918, &[0xea, 0xdc], false),
+    (0x8b02, &[0xea, 0xdd], false),
+    (0x9055, &[0xea, 0xde], false),
+    (0x97cb, &[0xea, 0xdf], false),
+    (0x9b4f, &[0xea, 0xe0], false),
+    (0x4e73, &[0xea, 0xe1], false),
+    (0x4f91, &[0xea, 0xe2], false),
+    (0x5112, &[0xea, 0xe3], false),
+    (0x516a, &[0xea, 0xe4], false),
+    (0xf9c7, &[0xea, 0xe5], false),
+    (0x552f, &[0xea, 0xe6], false),
+    (0x55a9, &[0xea, 0xe7], false),
+    (0x5b7a, &[0xea, 0xe8], false),
+    (0x5ba5, &[0xea, 0xe9], false),
+    (0x5e7c, &[0xea, 0xea], false),
+    (0x5e7d, &[0xea, 0xeb], false),
+    (0x5ebe, &[0xea, 0xec], false),
+    (0x60a0, &[0xea, 0xed], false),
+    (0x60df, &[0xea, 0xee], false),
+    (0x6108, &[0xea, 0xef], false),
+    (0x6109, &[0xea, 0xf0], false),
+    (0x63c4, &[0xea, 0xf1], false),
+    (0x6538, &[0xea, 0xf2], false),
+    (0x6709, &[0xea, 0xf3], false),
+    (0xf9c8, &[0xea, 0xf4], false),
+    (0x67d4, &[0xea, 0xf5], false),
+    (0x67da, &[0xea, 0xf6], false),
+    (0xf9c9, &[0xea, 0xf7], false),
+    (0x6961, &[0xea, 0xf8], false),
+    (0x6962, &[0xea, 0xf9], false),
+    (0x6cb9, &[0xea, 0xfa], false),
+    (0x6d27, &[0xea, 0xfb], false),
+    (0xf9ca, &[0xea, 0xfc], false),
+    (0x6e38, &[0xea, 0xfd], false),
+    (0xf9cb, &[0xea, 0xfe], false),
+    (0x6fe1, &[0xeb, 0xa1], false),
+    (0x7336, &[0xeb, 0xa2], false),
+    (0x7337, &[0xeb, 0xa3], false),
+    (0xf9cc, &[0xeb, 0xa4], false),
+    (0x745c, &[0xeb, 0xa5], false),
+    (0x7531, &[0xeb, 0xa6], false),
+    (0xf9cd, &[0xeb, 0xa7], false),
+    (0x7652, &[0xeb, 0xa8], false),
+    (0xf9ce, &[0xeb, 0xa9], false),
+    (0xf9cf, &[0xeb, 0xaa], false),
+    (0x7dad, &[0xeb, 0xab], false),
+    (0x81fe, &[0xeb, 0xac], false),
+    (0x8438, &[0xeb, 0xad], false),
+    (0x88d5, &[0xeb, 0xae], false),
+    (0x8a98, &[0xeb, 0xaf], false),
+    (0x8adb, &[0xeb, 0xb0], false),
+    (0x8aed, &[0xeb, 0xb1], false),
+    (0x8e30, &[0xeb, 0xb2], false),
+    (0x8e42, &[0xeb, 0xb3], false),
+    (0x904a, &[0xeb, 0xb4], false),
+    (0x903e, &[0xeb, 0xb5], false),
+    (0x907a, &[0xeb, 0xb6], false),
+    (0x9149, &[0xeb, 0xb7], false),
+    (0x91c9, &[0xeb, 0xb8], false),
+    (0x936e, &[0xeb, 0xb9], false),
+    (0xf9d0, &[0xeb, 0xba], false),
+    (0xf9d1, &[0xeb, 0xbb], false),
+    (0x5809, &[0xeb, 0xbc], false),
+    (0xf9d2, &[0xeb, 0xbd], false),
+    (0x6bd3, &[0xeb, 0xbe], false),
+    (0x8089, &[0xeb, 0xbf], false),
+    (0x80b2, &[0xeb, 0xc0], false),
+    (0xf9d3, &[0xeb, 0xc1], false),
+    (0xf9d4, &[0xeb, 0xc2], false),
+    (0x5141, &[0xeb, 0xc3], false),
+    (0x596b, &[0xeb, 0xc4], false),
+    (0x5c39, &[0xeb, 0xc5], false),
+    (0xf9d5, &[0xeb, 0xc6], false),
+    (0xf9d6, &[0xeb, 0xc7], false),
+    (0x6f64, &[0xeb, 0xc8], false),
+    (0x73a7, &[0xeb, 0xc9], false),
+    (0x80e4, &[0xeb, 0xca], false),
+    (0x8d07, &[0xeb, 0xcb], false),
+    (0xf9d7, &[0xeb, 0xcc], false),
+    (0x9217, &[0xeb, 0xcd], false),
+    (0x958f, &[0xeb, 0xce], false),
+    (0xf9d8, &[0xeb, 0xcf], false),
+    (0xf9d9, &[0xeb, 0xd0], false),
+    (0xf9da, &[0xeb, 0xd1], false),
+    (0xf9db, &[0xeb, 0xd2], false),
+    (0x807f, &[0xeb, 0xd3], false),
+    (0x620e, &[0xeb, 0xd4], false),
+    (0x701c, &[0xeb, 0xd5], false),
+    (0x7d68, &[0xeb, 0xd6], false),
+    (0x878d, &[0xeb, 0xd7], false),
+    (0xf9dc, &[0xeb, 0xd8], false),
+    (0x57a0, &[0xeb, 0xd9], false),
+    (0x6069, &[0xeb, 0xda], false),
+    (0x6147, &[0xeb, 0xdb], false),
+    (0x6bb7, &[0xeb, 0xdc], false),
+    (0x8abe, &[0xeb, 0xdd], false),
+    (0x9280, &[0xeb, 0xde], false),
+    (0x96b1, &[0xeb, 0xdf], false),
+    (0x4e59, &[0xeb, 0xe0], false),
+    (0x541f, &[0xeb, 0xe1], false),
+    (0x6deb, &[0xeb, 0xe2], false),
+    (0x852d, &[0xeb, 0xe3], false),
+    (0x9670, &[0xeb, 0xe4], false),
+    (0x97f3, &[0xeb, 0xe5], false),
+    (0x98ee, &[0xeb, 0xe6], false),
+    (0x63d6, &[0xeb, 0xe7], false),
+    (0x6ce3, &[0xeb, 0xe8], false),
+    (0x9091, &[0xeb, 0xe9], false),
+    (0x51dd, &[0xeb, 0xea], false),
+    (0x61c9, &[0xeb, 0xeb], false),
+    (0x81ba, &[0xeb, 0xec], false),
+    (0x9df9, &[0xeb, 0xed], false),
+    (0x4f9d, &[0xeb, 0xee], false),
+    (0x501a, &[0xeb, 0xef], false),
+    (0x5100, &[0xeb, 0xf0], false),
+    (0x5b9c, &[0xeb, 0xf1], false),
+    (0x610f, &[0xeb, 0xf2], false),
+    (0x61ff, &[0xeb, 0xf3], false),
+    (0x64ec, &[0xeb, 0xf4], false),
+    (0x6905, &[0xeb, 0xf5], false),
+    (0x6bc5, &[0xeb, 0xf6], false),
+    (0x7591, &[0xeb, 0xf7], false),
+    (0x77e3, &[0xeb, 0xf8], false),
+    (0x7fa9, &[0xeb, 0xf9], false),
+    (0x8264, &[0xeb, 0xfa], false),
+    (0x858f, &[0xeb, 0xfb], false),
+    (0x87fb, &[0xeb, 0xfc], false),
+    (0x8863, &[0xeb, 0xfd], false),
+    (0x8abc, &[0xeb, 0xfe], false),
+    (0x8b70, &[0xec, 0xa1], false),
+    (0x91ab, &[0xec, 0xa2], false),
+    (0x4e8c, &[0xec, 0xa3], false),
+    (0x4ee5, &[0xec, 0xa4], false),
+    (0x4f0a, &[0xec, 0xa5], false),
+    (0xf9dd, &[0xec, 0xa6], false),
+    (0xf9de, &[0xec, 0xa7], false),
+    (0x5937, &[0xec, 0xa8], false),
+    (0x59e8, &[0xec, 0xa9], false),
+    (0xf9df, &[0xec, 0xaa], false),
+    (0x5df2, &[0xec, 0xab], false),
+    (0x5f1b, &[0xec, 0xac], false),
+    (0x5f5b, &[0xec, 0xad], false),
+    (0x6021, &[0xec, 0xae], false),
+    (0xf9e0, &[0xec, 0xaf], false),
+    (0xf9e1, &[0xec, 0xb0], false),
+    (0xf9e2, &[0xec, 0xb1], false),
+    (0xf9e3, &[0xec, 0xb2], false),
+    (0x723e, &[0xec, 0xb3], false),
+    (0x73e5, &[0xec, 0xb4], false),
+    (0xf9e4, &[0xec, 0xb5], false),
+    (0x7570, &[0xec, 0xb6], false),
+    (0x75cd, &[0xec, 0xb7], false),
+    (0xf9e5, &[0xec, 0xb8], false),
+    (0x79fb, &[0xec, 0xb9], false),
+    (0xf9e6, &[0xec, 0xba], false),
+    (0x800c, &[0xec, 0xbb], false),
+    (0x8033, &[0xec, 0xbc], false),
+    (0x8084, &[0xec, 0xbd], false),
+    (0x82e1, &[0xec, 0xbe], false),
+    (0x8351, &[0xec, 0xbf], false),
+    (0xf9e7, &[0xec, 0xc0], false),
+    (0xf9e8, &[0xec, 0xc1], false),
+    (0x8cbd, &[0xec, 0xc2], false),
+    (0x8cb3, &[0xec, 0xc3], false),
+    (0x9087, &[0xec, 0xc4], false),
+    (0xf9e9, &[0xec, 0xc5], false),
+    (0xf9ea, &[0xec, 0xc6], false),
+    (0x98f4, &[0xec, 0xc7], false),
+    (0x990c, &[0xec, 0xc8], false),
+    (0xf9eb, &[0xec, 0xc9], false),
+    (0xf9ec, &[0xec, 0xca], false),
+    (0x7037, &[0xec, 0xcb], false),
+    (0x76ca, &[0xec, 0xcc], false),
+    (0x7fca, &[0xec, 0xcd], false),
+    (0x7fcc, &[0xec, 0xce], false),
+    (0x7ffc, &[0xec, 0xcf], false),
+    (0x8b1a, &[0xec, 0xd0], false),
+    (0x4eba, &[0xec, 0xd1], false),
+    (0x4ec1, &[0xec, 0xd2], false),
+    (0x5203, &[0xec, 0xd3], false),
+    (0x5370, &[0xec, 0xd4], false),
+    (0xf9ed, &[0xec, 0xd5], false),
+    (0x54bd, &[0xec, 0xd6], false),
+    (0x56e0, &[0xec, 0xd7], false),
+    (0x59fb, &[0xec, 0xd8], false),
+    (0x5bc5, &[0xec, 0xd9], false),
+    (0x5f15, &[0xec, 0xda], false),
+    (0x5fcd, &[0xec, 0xdb], false),
+    (0x6e6e, &[0xec, 0xdc], false),
+    (0xf9ee, &[0xec, 0xdd], false),
+    (0xf9ef, &[0xec, 0xde], false),
+    (0x7d6a, &[0xec, 0xdf], false),
+    (0x8335, &[0xec, 0xe0], false),
+    (0xf9f0, &[0xec, 0xe1], false),
+    (0x8693, &[0xec, 0xe2], false),
+    (0x8a8d, &[0xec, 0xe3], false),
+    (0xf9f1, &[0xec, 0xe4], false),
+    (0x976d, &[0xec, 0xe5], false),
+    (0x9777, &[0xec, 0xe6], false),
+    (0xf9f2, &[0xec, 0xe7], false),
+    (0xf9f3, &[0xec, 0xe8], false),
+    (0x4e00, &[0xec, 0xe9], false),
+    (0x4f5a, &[0xec, 0xea], false),
+    (0x4f7e, &[0xec, 0xeb], false),
+    (0x58f9, &[0xec, 0xec], false),
+    (0x65e5, &[0xec, 0xed], false),
+    (0x6ea2, &[0xec, 0xee], false),
+    (0x9038, &[0xec, 0xef], false),
+    (0x93b0, &[0xec, 0xf0], false),
+    (0x99b9, &[0xec, 0xf1], false),
+    (0x4efb, &[0xec, 0xf2], false),
+    (0x58ec, &[0xec, 0xf3], false),
+    (0x598a, &[0xec, 0xf4], false),
+    (0x59d9, &[0xec, 0xf5], false),
+    (0x6041, &[0xec, 0xf6], false),
+    (0xf9f4, &[0xec, 0xf7], false),
+    (0xf9f5, &[0xec, 0xf8], false),
+    (0x7a14, &[0xec, 0xf9], false),
+    (0xf9f6, &[0xec, 0xfa], false),
+    (0x834f, &[0xec, 0xfb], false),
+    (0x8cc3, &[0xec, 0xfc], false),
+    (0x5165, &[0xec, 0xfd], false),
+    (0x5344, &[0xec, 0xfe], false),
+    (0xf9f7, &[0xed, 0xa1], false),
+    (0xf9f8, &[0xed, 0xa2], false),
+    (0xf9f9, &[0xed, 0xa3], false),
+    (0x4ecd, &[0xed, 0xa4], false),
+    (0x5269, &[0xed, 0xa5], false),
+    (0x5b55, &[0xed, 0xa6], false),
+    (0x82bf, &[0xed, 0xa7], false),
+    (0x4ed4, &[0xed, 0xa8], false),
+    (0x523a, &[0xed, 0xa9], false),
+    (0x54a8, &[0xed, 0xaa], false),
+    (0x59c9, &[0xed, 0xab], false),
+    (0x59ff, &[0xed, 0xac], false),
+    (0x5b50, &[0xed, 0xad], false),
+    (0x5b57, &[0xed, 0xae], false),
+    (0x5b5c, &[0xed, 0xaf], false),
+    (0x6063, &[0xed, 0xb0], false),
+    (0x6148, &[0xed, 0xb1], false),
+    (0x6ecb, &[0xed, 0xb2], false),
+    (0x7099, &[0xed, 0xb3], false),
+    (0x716e, &[0xed, 0xb4], false),
+    (0x7386, &[0xed, 0xb5], false),
+    (0x74f7, &[0xed, 0xb6], false),
+    (0x75b5, &[0xed, 0xb7], false),
+    (0x78c1, &[0xed, 0xb8], false),
+    (0x7d2b, &[0xed, 0xb9], false),
+    (0x8005, &[0xed, 0xba], false),
+    (0x81ea, &[0xed, 0xbb], false),
+    (0x8328, &[0xed, 0xbc], false),
+    (0x8517, &[0xed, 0xbd], false),
+    (0x85c9, &[0xed, 0xbe], false),
+    (0x8aee, &[0xed, 0xbf], false),
+    (0x8cc7, &[0xed, 0xc0], false),
+    (0x96cc, &[0xed, 0xc1], false),
+    (0x4f5c, &[0xed, 0xc2], false),
+    (0x52fa, &[0xed, 0xc3], false),
+    (0x56bc, &[0xed, 0xc4], false),
+    (0x65ab, &[0xed, 0xc5], false),
+    (0x6628, &[0xed, 0xc6], false),
+    (0x707c, &[0xed, 0xc7], false),
+    (0x70b8, &[0xed, 0xc8], false),
+    (0x7235, &[0xed, 0xc9], false),
+    (0x7dbd, &[0xed, 0xca], false),
+    (0x828d, &[0xed, 0xcb], false),
+    (0x914c, &[0xed, 0xcc], false),
+    (0x96c0, &[0xed, 0xcd], false),
+    (0x9d72, &[0xed, 0xce], false),
+    (0x5b71, &[0xed, 0xcf], false),
+    (0x68e7, &[0xed, 0xd0], false),
+    (0x6b98, &[0xed, 0xd1], false),
+    (0x6f7a, &[0xed, 0xd2], false),
+    (0x76de, &[0xed, 0xd3], false),
+    (0x5c91, &[0xed, 0xd4], false),
+    (0x66ab, &[0xed, 0xd5], false),
+    (0x6f5b, &[0xed, 0xd6], false),
+    (0x7bb4, &[0xed, 0xd7], false),
+    (0x7c2a, &[0xed, 0xd8], false),
+    (0x8836, &[0xed, 0xd9], false),
+    (0x96dc, &[0xed, 0xda], false),
+    (0x4e08, &[0xed, 0xdb], false),
+    (0x4ed7, &[0xed, 0xdc], false),
+    (0x5320, &[0xed, 0xdd], false),
+    (0x5834, &[0xed, 0xde], false),
+    (0x58bb, &[0xed, 0xdf], false),
+    (0x58ef, &[0xed, 0xe0], false),
+    (0x596c, &[0xed, 0xe1], false),
+    (0x5c07, &[0xed, 0xe2], false),
+    (0x5e33, &[0xed, 0xe3], false),
+    (0x5e84, &[0xed, 0xe4], false),
+    (0x5f35, &[0xed, 0xe5], false),
+    (0x638c, &[0xed, 0xe6], false),
+    (0x66b2, &[0xed, 0xe7], false),
+    (0x6756, &[0xed, 0xe8], false),
+    (0x6a1f, &[0xed, 0xe9], false),
+    (0x6aa3, &[0xed, 0xea], false),
+    (0x6b0c, &[0xed, 0xeb], false),
+    (0x6f3f, &[0xed, 0xec], false),
+    (0x7246, &[0xed, 0xed], false),
+    (0xf9fa, &[0xed, 0xee], false),
+    (0x7350, &[0xed, 0xef], false),
+    (0x748b, &[0xed, 0xf0], false),
+    (0x7ae0, &[0xed, 0xf1], false),
+    (0x7ca7, &[0xed, 0xf2], false),
+    (0x8178, &[0xed, 0xf3], false),
+    (0x81df, &[0xed, 0xf4], false),
+    (0x81e7, &[0xed, 0xf5], false),
+    (0x838a, &[0xed, 0xf6], false),
+    (0x846c, &[0xed, 0xf7], false),
+    (0x8523, &[0xed, 0xf8], false),
+    (0x8594, &[0xed, 0xf9], false),
+    (0x85cf, &[0xed, 0xfa], false),
+    (0x88dd, &[0xed, 0xfb], false),
+    (0x8d13, &[0xed, 0xfc], false),
+    (0x91ac, &[0xed, 0xfd], false),
+    (0x9577, &[0xed, 0xfe], false),
+    (0x969c, &[0xee, 0xa1], false),
+    (0x518d, &[0xee, 0xa2], false),
+    (0x54c9, &[0xee, 0xa3], false),
+    (0x5728, &[0xee, 0xa4], false),
+    (0x5bb0, &[0xee, 0xa5], false),
+    (0x624d, &[0xee, 0xa6], false),
+    (0x6750, &[0xee, 0xa7], false),
+    (0x683d, &[0xee, 0xa8], false),
+    (0x6893, &[0xee, 0xa9], false),
+    (0x6e3d, &[0xee, 0xaa], false),
+    (0x6ed3, &[0xee, 0xab], false),
+    (0x707d, &[0xee, 0xac], false),
+    (0x7e21, &[0xee, 0xad], false),
+    (0x88c1, &[0xee, 0xae], false),
+    (0x8ca1, &[0xee, 0xaf], false),
+    (0x8f09, &[0xee, 0xb0], false),
+    (0x9f4b, &[0xee, 0xb1], false),
+    (0x9f4e, &[0xee, 0xb2], false),
+    (0x722d, &[0xee, 0xb3], false),
+    (0x7b8f, &[0xee, 0xb4], false),
+    (0x8acd, &[0xee, 0xb5], false),
+    (0x931a, &[0xee, 0xb6], false),
+    (0x4f47, &[0xee, 0xb7], false),
+    (0x4f4e, &[0xee, 0xb8], false),
+    (0x5132, &[0xee, 0xb9], false),
+    (0x5480, &[0xee, 0xba], false),
+    (0x59d0, &[0xee, 0xbb], false),
+    (0x5e95, &[0xee, 0xbc], false),
+    (0x62b5, &[0xee, 0xbd], false),
+    (0x6775, &[0xee, 0xbe], false),
+    (0x696e, &[0xee, 0xbf], false),
+    (0x6a17, &[0xee, 0xc0], false),
+    (0x6cae, &[0xee, 0xc1], false),
+    (0x6e1a, &[0xee, 0xc2], false),
+    (0x72d9, &[0xee, 0xc3], false),
+    (0x732a, &[0xee, 0xc4], false),
+    (0x75bd, &[0xee, 0xc5], false),
+    (0x7bb8, &[0xee, 0xc6], false),
+    (0x7d35, &[0xee, 0xc7], false),
+    (0x82e7, &[0xee, 0xc8], false),
+    (0x83f9, &[0xee, 0xc9], false),
+    (0x8457, &[0xee, 0xca], false),
+    (0x85f7, &[0xee, 0xcb], false),
+    (0x8a5b, &[0xee, 0xcc], false),
+    (0x8caf, &[0xee, 0xcd], false),
+    (0x8e87, &[0xee, 0xce], false),
+    (0x9019, &[0xee, 0xcf], false),
+    (0x90b8, &[0xee, 0xd0], false),
+    (0x96ce, &[0xee, 0xd1], false),
+    (0x9f5f, &[0xee, 0xd2], false),
+    (0x52e3, &[0xee, 0xd3], false),
+    (0x540a, &[0xee, 0xd4], false),
+    (0x5ae1, &[0xee, 0xd5], false),
+    (0x5bc2, &[0xee, 0xd6], false),
+    (0x6458, &[0xee, 0xd7], false),
+    (0x6575, &[0xee, 0xd8], false),
+    (0x6ef4, &[0xee, 0xd9], false),
+    (0x72c4, &[0xee, 0xda], false),
+    (0xf9fb, &[0xee, 0xdb], false),
+    (0x7684, &[0xee, 0xdc], false),
+    (0x7a4d, &[0xee, 0xdd], false),
+    (0x7b1b, &[0xee, 0xde], false),
+    (0x7c4d, &[0xee, 0xdf], false),
+    (0x7e3e, &[0xee, 0xe0], false),
+    (0x7fdf, &[0xee, 0xe1], false),
+    (0x837b, &[0xee, 0xe2], false),
+    (0x8b2b, &[0xee, 0xe3], false),
+    (0x8cca, &[0xee, 0xe4], false),
+    (0x8d64, &[0xee, 0xe5], false),
+    (0x8de1, &[0xee, 0xe6], false),
+    (0x8e5f, &[0xee, 0xe7], false),
+    (0x8fea, &[0xee, 0xe8], false),
+    (0x8ff9, &[0xee, 0xe9], false),
+    (0x9069, &[0xee, 0xea], false),
+    (0x93d1, &[0xee, 0xeb], false),
+    (0x4f43, &[0xee, 0xec], false),
+    (0x4f7a, &[0xee, 0xed], false),
+    (0x50b3, &[0xee, 0xee], false),
+    (0x5168, &[0xee, 0xef], false),
+    (0x5178, &[0xee, 0xf0], false),
+    (0x524d, &[0xee, 0xf1], false),
+    (0x526a, &[0xee, 0xf2], false),
+    (0x5861, &[0xee, 0xf3], false),
+    (0x587c, &[0xee, 0xf4], false),
+    (0x5960, &[0xee, 0xf5], false),
+    (0x5c08, &[0xee, 0xf6], false),
+    (0x5c55, &[0xee, 0xf7], false),
+    (0x5edb, &[0xee, 0xf8], false),
+    (0x609b, &[0xee, 0xf9], false),
+    (0x6230, &[0xee, 0xfa], false),
+    (0x6813, &[0xee, 0xfb], false),
+    (0x6bbf, &[0xee, 0xfc], false),
+    (0x6c08, &[0xee, 0xfd], false),
+    (0x6fb1, &[0xee, 0xfe], false),
+    (0x714e, &[0xef, 0xa1], false),
+    (0x7420, &[0xef, 0xa2], false),
+    (0x7530, &[0xef, 0xa3], false),
+    (0x7538, &[0xef, 0xa4], false),
+    (0x7551, &[0xef, 0xa5], false),
+    (0x7672, &[0xef, 0xa6], false),
+    (0x7b4c, &[0xef, 0xa7], false),
+    (0x7b8b, &[0xef, 0xa8], false),
+    (0x7bad, &[0xef, 0xa9], false),
+    (0x7bc6, &[0xef, 0xaa], false),
+    (0x7e8f, &[0xef, 0xab], false),
+    (0x8a6e, &[0xef, 0xac], false),
+    (0x8f3e, &[0xef, 0xad], false),
+    (0x8f49, &[0xef, 0xae], false),
+    (0x923f, &[0xef, 0xaf], false),
+    (0x9293, &[0xef, 0xb0], false),
+    (0x9322, &[0xef, 0xb1], false),
+    (0x942b, &[0xef, 0xb2], false),
+    (0x96fb, &[0xef, 0xb3], false),
+    (0x985a, &[0xef, 0xb4], false),
+    (0x986b, &[0xef, 0xb5], false),
+    (0x991e, &[0xef, 0xb6], false),
+    (0x5207, &[0xef, 0xb7], false),
+    (0x622a, &[0xef, 0xb8], false),
+    (0x6298, &[0xef, 0xb9], false),
+    (0x6d59, &[0xef, 0xba], false),
+    (0x7664, &[0xef, 0xbb], false),
+    (0x7aca, &[0xef, 0xbc], false),
+    (0x7bc0, &[0xef, 0xbd], false),
+    (0x7d76, &[0xef, 0xbe], false),
+    (0x5360, &[0xef, 0xbf], false),
+    (0x5cbe, &[0xef, 0xc0], false),
+    (0x5e97, &[0xef, 0xc1], false),
+    (0x6f38, &[0xef, 0xc2], false),
+    (0x70b9, &[0xef, 0xc3], false),
+    (0x7c98, &[0xef, 0xc4], false),
+    (0x9711, &[0xef, 0xc5], false),
+    (0x9b8e, &[0xef, 0xc6], false),
+    (0x9ede, &[0xef, 0xc7], false),
+    (0x63a5, &[0xef, 0xc8], false),
+    (0x647a, &[0xef, 0xc9], false),
+    (0x8776, &[0xef, 0xca], false),
+    (0x4e01, &[0xef, 0xcb], false),
+    (0x4e95, &[0xef, 0xcc], false),
+    (0x4ead, &[0xef, 0xcd], false),
+    (0x505c, &[0xef, 0xce], false),
+    (0x5075, &[0xef, 0xcf], false),
+    (0x5448, &[0xef, 0xd0], false),
+    (0x59c3, &[0xef, 0xd1], false),
+    (0x5b9a, &[0xef, 0xd2], false),
+    (0x5e40, &[0xef, 0xd3], false),
+    (0x5ead, &[0xef, 0xd4], false),
+    (0x5ef7, &[0xef, 0xd5], false),
+    (0x5f81, &[0xef, 0xd6], false),
+    (0x60c5, &[0xef, 0xd7], false),
+    (0x633a, &[0xef, 0xd8], false),
+    (0x653f, &[0xef, 0xd9], false),
+    (0x6574, &[0xef, 0xda], false),
+    (0x65cc, &[0xef, 0xdb], false),
+    (0x6676, &[0xef, 0xdc], false),
+    (0x6678, &[0xef, 0xdd], false),
+    (0x67fe, &[0xef, 0xde], false),
+    (0x6968, &[0xef, 0xdf], false),
+    (0x6a89, &[0xef, 0xe0], false),
+    (0x6b63, &[0xef, 0xe1], false),
+    (0x6c40, &[0xef, 0xe2], false),
+    (0x6dc0, &[0xef, 0xe3], false),
+    (0x6de8, &[0xef, 0xe4], false),
+    (0x6e1f, &[0xef, 0xe5], false),
+    (0x6e5e, &[0xef, 0xe6], false),
+    (0x701e, &[0xef, 0xe7], false),
+    (0x70a1, &[0xef, 0xe8], false),
+    (0x738e, &[0xef, 0xe9], false),
+    (0x73fd, &[0xef, 0xea], false),
+    (0x753a, &[0xef, 0xeb], false),
+    (0x775b, &[0xef, 0xec], false),
+    (0x7887, &[0xef, 0xed], false),
+    (0x798e, &[0xef, 0xee], false),
+    (0x7a0b, &[0xef, 0xef], false),
+    (0x7a7d, &[0xef, 0xf0], false),
+    (0x7cbe, &[0xef, 0xf1], false),
+    (0x7d8e, &[0xef, 0xf2], false),
+    (0x8247, &[0xef, 0xf3], false),
+    (0x8a02, &[0xef, 0xf4], false),
+    (0x8aea, &[0xef, 0xf5], false),
+    (0x8c9e, &[0xef, 0xf6], false),
+    (0x912d, &[0xef, 0xf7], false),
+    (0x914a, &[0xef, 0xf8], false),
+    (0x91d8, &[0xef, 0xf9], false),
+    (0x9266, &[0xef, 0xfa], false),
+    (0x92cc, &[0xef, 0xfb], false),
+    (0x9320, &[0xef, 0xfc], false),
+    (0x9706, &[0xef, 0xfd], false),
+    (0x9756, &[0xef, 0xfe], false),
+    (0x975c, &[0xf0, 0xa1], false),
+    (0x9802, &[0xf0, 0xa2], false),
+    (0x9f0e, &[0xf0, 0xa3], false),
+    (0x5236, &[0xf0, 0xa4], false),
+    (0x5291, &[0xf0, 0xa5], false),
+    (0x557c, &[0xf0, 0xa6], false),
+    (0x5824, &[0xf0, 0xa7], false),
+    (0x5e1d, &[0xf0, 0xa8], false),
+    (0x5f1f, &[0xf0, 0xa9], false),
+    (0x608c, &[0xf0, 0xaa], false),
+    (0x63d0, &[0xf0, 0xab], false),
+    (0x68af, &[0xf0, 0xac], false),
+    (0x6fdf, &[0xf0, 0xad], false),
+    (0x796d, &[0xf0, 0xae], false),
+    (0x7b2c, &[0xf0, 0xaf], false),
+    (0x81cd, &[0xf0, 0xb0], false),
+    (0x85ba, &[0xf0, 0xb1], false),
+    (0x88fd, &[0xf0, 0xb2], false),
+    (0x8af8, &[0xf0, 0xb3], false),
+    (0x8e44, &[0xf0, 0xb4], false),
+    (0x918d, &[0xf0, 0xb5], false),
+    (0x9664, &[0xf0, 0xb6], false),
+    (0x969b, &[0xf0, 0xb7], false),
+    (0x973d, &[0xf0, 0xb8], false),
+    (0x984c, &[0xf0, 0xb9], false),
+    (0x9f4a, &[0xf0, 0xba], false),
+    (0x4fce, &[0xf0, 0xbb], false),
+    (0x5146, &[0xf0, 0xbc], false),
+    (0x51cb, &[0xf0, 0xbd], false),
+    (0x52a9, &[0xf0, 0xbe], false),
+    (0x5632, &[0xf0, 0xbf], false),
+    (0x5f14, &[0xf0, 0xc0], false),
+    (0x5f6b, &[0xf0, 0xc1], false),
+    (0x63aa, &[0xf0, 0xc2], false),
+    (0x64cd, &[0xf0, 0xc3], false),
+    (0x65e9, &[0xf0, 0xc4], false),
+    (0x6641, &[0xf0, 0xc5], false),
+    (0x66fa, &[0xf0, 0xc6], false),
+    (0x66f9, &[0xf0, 0xc7], false),
+    (0x671d, &[0xf0, 0xc8], false),
+    (0x689d, &[0xf0, 0xc9], false),
+    (0x68d7, &[0xf0, 0xca], false),
+    (0x69fd, &[0xf0, 0xcb], false),
+    (0x6f15, &[0xf0, 0xcc], false),
+    (0x6f6e, &[0xf0, 0xcd], false),
+    (0x7167, &[0xf0, 0xce], false),
+    (0x71e5, &[0xf0, 0xcf], false),
+    (0x722a, &[0xf0, 0xd0], false),
+    (0x74aa, &[0xf0, 0xd1], false),
+    (0x773a, &[0xf0, 0xd2], false),
+    (0x7956, &[0xf0, 0xd3], false),
+    (0x795a, &[0xf0, 0xd4], false),
+    (0x79df, &[0xf0, 0xd5], false),
+    (0x7a20, &[0xf0, 0xd6], false),
+    (0x7a95, &[0xf0, 0xd7], false),
+    (0x7c97, &[0xf0, 0xd8], false),
+    (0x7cdf, &[0xf0, 0xd9], false),
+    (0x7d44, &[0xf0, 0xda], false),
+    (0x7e70, &[0xf0, 0xdb], false),
+    (0x8087, &[0xf0, 0xdc], false),
+    (0x85fb, &[0xf0, 0xdd], false),
+    (0x86a4, &[0xf0, 0xde], false),
+    (0x8a54, &[0xf0, 0xdf], false),
+    (0x8abf, &[0xf0, 0xe0], false),
+    (0x8d99, &[0xf0, 0xe1], false),
+    (0x8e81, &[0xf0, 0xe2], false),
+    (0x9020, &[0xf0, 0xe3], false),
+    (0x906d, &[0xf0, 0xe4], false),
+    (0x91e3, &[0xf0, 0xe5], false),
+    (0x963b, &[0xf0, 0xe6], false),
+    (0x96d5, &[0xf0, 0xe7], false),
+    (0x9ce5, &[0xf0, 0xe8], false),
+    (0x65cf, &[0xf0, 0xe9], false),
+    (0x7c07, &[0xf0, 0xea], false),
+    (0x8db3, &[0xf0, 0xeb], false),
+    (0x93c3, &[0xf0, 0xec], false),
+    (0x5b58, &[0xf0, 0xed], false),
+    (0x5c0a, &[0xf0, 0xee], false),
+    (0x5352, &[0xf0, 0xef], false),
+    (0x62d9, &[0xf0, 0xf0], false),
+    (0x731d, &[0xf0, 0xf1], false),
+    (0x5027, &[0xf0, 0xf2], false),
+    (0x5b97, &[0xf0, 0xf3], false),
+    (0x5f9e, &[0xf0, 0xf4], false),
+    (0x60b0, &[0xf0, 0xf5], false),
+    (0x616b, &[0xf0, 0xf6], false),
+    (0x68d5, &[0xf0, 0xf7], false),
+    (0x6dd9, &[0xf0, 0xf8], false),
+    (0x742e, &[0xf0, 0xf9], false),
+    (0x7a2e, &[0xf0, 0xfa], false),
+    (0x7d42, &[0xf0, 0xfb], false),
+    (0x7d9c, &[0xf0, 0xfc], false),
+    (0x7e31, &[0xf0, 0xfd], false),
+    (0x816b, &[0xf0, 0xfe], false),
+    (0x8e2a, &[0xf1, 0xa1], false),
+    (0x8e35, &[0xf1, 0xa2], false),
+    (0x937e, &[0xf1, 0xa3], false),
+    (0x9418, &[0xf1, 0xa4], false),
+    (0x4f50, &[0xf1, 0xa5], false),
+    (0x5750, &[0xf1, 0xa6], false),
+    (0x5de6, &[0xf1, 0xa7], false),
+    (0x5ea7, &[0xf1, 0xa8], false),
+    (0x632b, &[0xf1, 0xa9], false),
+    (0x7f6a, &[0xf1, 0xaa], false),
+    (0x4e3b, &[0xf1, 0xab], false),
+    (0x4f4f, &[0xf1, 0xac], false),
+    (0x4f8f, &[0xf1, 0xad], false),
+    (0x505a, &[0xf1, 0xae], false),
+    (0x59dd, &[0xf1, 0xaf], false),
+    (0x80c4, &[0xf1, 0xb0], false),
+    (0x546a, &[0xf1, 0xb1], false),
+    (0x5468, &[0xf1, 0xb2], false),
+    (0x55fe, &[0xf1, 0xb3], false),
+    (0x594f, &[0xf1, 0xb4], false),
+    (0x5b99, &[0xf1, 0xb5], false),
+    (0x5dde, &[0xf1, 0xb6], false),
+    (0x5eda, &[0xf1, 0xb7], false),
+    (0x665d, &[0xf1, 0xb8], false),
+    (0x6731, &[0xf1, 0xb9], false),
+    (0x67f1, &[0xf1, 0xba], false),
+    (0x682a, &[0xf1, 0xbb], false),
+    (0x6ce8, &[0xf1, 0xbc], false),
+    (0x6d32, &[0xf1, 0xbd], false),
+    (0x6e4a, &[0xf1, 0xbe], false),
+    (0x6f8d, &[0xf1, 0xbf], false),
+    (0x70b7, &[0xf1, 0xc0], false),
+    (0x73e0, &[0xf1, 0xc1], false),
+    (0x7587, &[0xf1, 0xc2], false),
+    (0x7c4c, &[0xf1, 0xc3], false),
+    (0x7d02, &[0xf1, 0xc4], false),
+    (0x7d2c, &[0xf1, 0xc5], false),
+    (0x7da2, &[0xf1, 0xc6], false),
+    (0x821f, &[0xf1, 0xc7], false),
+    (0x86db, &[0xf1, 0xc8], false),
+    (0x8a3b, &[0xf1, 0xc9], false),
+    (0x8a85, &[0xf1, 0xca], false),
+    (0x8d70, &[0xf1, 0xcb], false),
+    (0x8e8a, &[0xf1, 0xcc], false),
+    (0x8f33, &[0xf1, 0xcd], false),
+    (0x9031, &[0xf1, 0xce], false),
+    (0x914e, &[0xf1, 0xcf], false),
+    (0x9152, &[0xf1, 0xd0], false),
+    (0x9444, &[0xf1, 0xd1], false),
+    (0x99d0, &[0xf1, 0xd2], false),
+    (0x7af9, &[0xf1, 0xd3], false),
+    (0x7ca5, &[0xf1, 0xd4], false),
+    (0x4fca, &[0xf1, 0xd5], false),
+    (0x5101, &[0xf1, 0xd6], false),
+    (0x51c6, &[0xf1, 0xd7], false),
+    (0x57c8, &[0xf1, 0xd8], false),
+    (0x5bef, &[0xf1, 0xd9], false),
+    (0x5cfb, &[0xf1, 0xda], false),
+    (0x6659, &[0xf1, 0xdb], false),
+    (0x6a3d, &[0xf1, 0xdc], false),
+    (0x6d5a, &[0xf1, 0xdd], false),
+    (0x6e96, &[0xf1, 0xde], false),
+    (0x6fec, &[0xf1, 0xdf], false),
+    (0x710c, &[0xf1, 0xe0], false),
+    (0x756f, &[0xf1, 0xe1], false),
+    (0x7ae3, &[0xf1, 0xe2], false),
+    (0x8822, &[0xf1, 0xe3], false),
+    (0x9021, &[0xf1, 0xe4], false),
+    (0x9075, &[0xf1, 0xe5], false),
+    (0x96cb, &[0xf1, 0xe6], false),
+    (0x99ff, &[0xf1, 0xe7], false),
+    (0x8301, &[0xf1, 0xe8], false),
+    (0x4e2d, &[0xf1, 0xe9], false),
+    (0x4ef2, &[0xf1, 0xea], false),
+    (0x8846, &[0xf1, 0xeb], false),
+    (0x91cd, &[0xf1, 0xec], false),
+    (0x537d, &[0xf1, 0xed], false),
+    (0x6adb, &[0xf1, 0xee], false),
+    (0x696b, &[0xf1, 0xef], false),
+    (0x6c41, &[0xf1, 0xf0], false),
+    (0x847a, &[0xf1, 0xf1], false),
+    (0x589e, &[0xf1, 0xf2], false),
+    (0x618e, &[0xf1, 0xf3], false),
+    (0x66fe, &[0xf1, 0xf4], false),
+    (0x62ef, &[0xf1, 0xf5], false),
+    (0x70dd, &[0xf1, 0xf6], false),
+    (0x7511, &[0xf1, 0xf7], false),
+    (0x75c7, &[0xf1, 0xf8], false),
+    (0x7e52, &[0xf1, 0xf9], false),
+    (0x84b8, &[0xf1, 0xfa], false),
+    (0x8b49, &[0xf1, 0xfb], false),
+    (0x8d08, &[0xf1, 0xfc], false),
+    (0x4e4b, &[0xf1, 0xfd], false),
+    (0x53ea, &[0xf1, 0xfe], false),
+    (0x54ab, &[0xf2, 0xa1], false),
+    (0x5730, &[0xf2, 0xa2], false),
+    (0x5740, &[0xf2, 0xa3], false),
+    (0x5fd7, &[0xf2, 0xa4], false),
+    (0x6301, &[0xf2, 0xa5], false),
+    (0x6307, &[0xf2, 0xa6], false),
+    (0x646f, &[0xf2, 0xa7], false),
+    (0x652f, &[0xf2, 0xa8], false),
+    (0x65e8, &[0xf2, 0xa9], false),
+    (0x667a, &[0xf2, 0xaa], false),
+    (0x679d, &[0xf2, 0xab], false),
+    (0x67b3, &[0xf2, 0xac], false),
+    (0x6b62, &[0xf2, 0xad], false),
+    (0x6c60, &[0xf2, 0xae], false),
+    (0x6c9a, &[0xf2, 0xaf], false),
+    (0x6f2c, &[0xf2, 0xb0], false),
+    (0x77e5, &[0xf2, 0xb1], false),
+    (0x7825, &[0xf2, 0xb2], false),
+    (0x7949, &[0xf2, 0xb3], false),
+    (0x7957, &[0xf2, 0xb4], false),
+    (0x7d19, &[0xf2, 0xb5], false),
+    (0x80a2, &[0xf2, 0xb6], false),
+    (0x8102, &[0xf2, 0xb7], false),
+    (0x81f3, &[0xf2, 0xb8], false),
+    (0x829d, &[0xf2, 0xb9], false),
+    (0x82b7, &[0xf2, 0xba], false),
+    (0x8718, &[0xf2, 0xbb], false),
+    (0x8a8c, &[0xf2, 0xbc], false),
+    (0xf9fc, &[0xf2, 0xbd], false),
+    (0x8d04, &[0xf2, 0xbe], false),
+    (0x8dbe, &[0xf2, 0xbf], false),
+    (0x9072, &[0xf2, 0xc0], false),
+    (0x76f4, &[0xf2, 0xc1], false),
+    (0x7a19, &[0xf2, 0xc2], false),
+    (0x7a37, &[0xf2, 0xc3], false),
+    (0x7e54, &[0xf2, 0xc4], false),
+    (0x8077, &[0xf2, 0xc5], false),
+    (0x5507, &[0xf2, 0xc6], false),
+    (0x55d4, &[0xf2, 0xc7], false),
+    (0x5875, &[0xf2, 0xc8], false),
+    (0x632f, &[0xf2, 0xc9], false),
+    (0x6422, &[0xf2, 0xca], false),
+    (0x6649, &[0xf2, 0xcb], false),
+    (0x664b, &[0xf2, 0xcc], false),
+    (0x686d, &[0xf2, 0xcd], false),
+    (0x699b, &[0xf2, 0xce], false),
+    (0x6b84, &[0xf2, 0xcf], false),
+    (0x6d25, &[0xf2, 0xd0], false),
+    (0x6eb1, &[0xf2, 0xd1], false),
+    (0x73cd, &[0xf2, 0xd2], false),
+    (0x7468, &[0xf2, 0xd3], false),
+    (0x74a1, &[0xf2, 0xd4], false),
+    (0x755b, &[0xf2, 0xd5], false),
+    (0x75b9, &[0xf2, 0xd6], false),
+    (0x76e1, &[0xf2, 0xd7], false),
+    (0x771e, &[0xf2, 0xd8], false),
+    (0x778b, &[0xf2, 0xd9], false),
+    (0x79e6, &[0xf2, 0xda], false),
+    (0x7e09, &[0xf2, 0xdb], false),
+    (0x7e1d, &[0xf2, 0xdc], false),
+    (0x81fb, &[0xf2, 0xdd], false),
+    (0x852f, &[0xf2, 0xde], false),
+    (0x8897, &[0xf2, 0xdf], false),
+    (0x8a3a, &[0xf2, 0xe0], false),
+    (0x8cd1, &[0xf2, 0xe1], false),
+    (0x8eeb, &[0xf2, 0xe2], false),
+    (0x8fb0, &[0xf2, 0xe3], false),
+    (0x9032, &[0xf2, 0xe4], false),
+    (0x93ad, &[0xf2, 0xe5], false),
+    (0x9663, &[0xf2, 0xe6], false),
+    (0x9673, &[0xf2, 0xe7], false),
+    (0x9707, &[0xf2, 0xe8], false),
+    (0x4f84, &[0xf2, 0xe9], false),
+    (0x53f1, &[0xf2, 0xea], false),
+    (0x59ea, &[0xf2, 0xeb], false),
+    (0x5ac9, &[0xf2, 0xec], false),
+    (0x5e19, &[0xf2, 0xed], false),
+    (0x684e, &[0xf2, 0xee], false),
+    (0x74c6, &[0xf2, 0xef], false),
+    (0x75be, &[0xf2, 0xf0], false),
+    (0x79e9, &[0xf2, 0xf1], false),
+    (0x7a92, &[0xf2, 0xf2], false),
+    (0x81a3, &[0xf2, 0xf3], false),
+    (0x86ed, &[0xf2, 0xf4], false),
+    (0x8cea, &[0xf2, 0xf5], false),
+    (0x8dcc, &[0xf2, 0xf6], false),
+    (0x8fed, &[0xf2, 0xf7], false),
+    (0x659f, &[0xf2, 0xf8], false),
+    (0x6715, &[0xf2, 0xf9], false),
+    (0xf9fd, &[0xf2, 0xfa], false),
+    (0x57f7, &[0xf2, 0xfb], false),
+    (0x6f57, &[0xf2, 0xfc], false),
+    (0x7ddd, &[0xf2, 0xfd], false),
+    (0x8f2f, &[0xf2, 0xfe], false),
+    (0x93f6, &[0xf3, 0xa1], false),
+    (0x96c6, &[0xf3, 0xa2], false),
+    (0x5fb5, &[0xf3, 0xa3], false),
+    (0x61f2, &[0xf3, 0xa4], false),
+    (0x6f84, &[0xf3, 0xa5], false),
+    (0x4e14, &[0xf3, 0xa6], false),
+    (0x4f98, &[0xf3, 0xa7], false),
+    (0x501f, &[0xf3, 0xa8], false),
+    (0x53c9, &[0xf3, 0xa9], false),
+    (0x55df, &[0xf3, 0xaa], false),
+    (0x5d6f, &[0xf3, 0xab], false),
+    (0x5dee, &[0xf3, 0xac], false),
+    (0x6b21, &[0xf3, 0xad], false),
+    (0x6b64, &[0xf3, 0xae], false),
+    (0x78cb, &[0xf3, 0xaf], false),
+    (0x7b9a, &[0xf3, 0xb0], false),
+    (0xf9fe, &[0xf3, 0xb1], false),
+    (0x8e49, &[0xf3, 0xb2], false),
+    (0x8eca, &[0xf3, 0xb3], false),
+    (0x906e, &[0xf3, 0xb4], false),
+    (0x6349, &[0xf3, 0xb5], false),
+    (0x643e, &[0xf3, 0xb6], false),
+    (0x7740, &[0xf3, 0xb7], false),
+    (0x7a84, &[0xf3, 0xb8], false),
+    (0x932f, &[0xf3, 0xb9], false),
+    (0x947f, &[0xf3, 0xba], false),
+    (0x9f6a, &[0xf3, 0xbb], false),
+    (0x64b0, &[0xf3, 0xbc], false),
+    (0x6faf, &[0xf3, 0xbd], false),
+    (0x71e6, &[0xf3, 0xbe], false),
+    (0x74a8, &[0xf3, 0xbf], false),
+    (0x74da, &[0xf3, 0xc0], false),
+    (0x7ac4, &[0xf3, 0xc1], false),
+    (0x7c12, &[0xf3, 0xc2], false),
+    (0x7e82, &[0xf3, 0xc3], false),
+    (0x7cb2, &[0xf3, 0xc4], false),
+    (0x7e98, &[0xf3, 0xc5], false),
+    (0x8b9a, &[0xf3, 0xc6], false),
+    (0x8d0a, &[0xf3, 0xc7], false),
+    (0x947d, &[0xf3, 0xc8], false),
+    (0x9910, &[0xf3, 0xc9], false),
+    (0x994c, &[0xf3, 0xca], false),
+    (0x5239, &[0xf3, 0xcb], false),
+    (0x5bdf, &[0xf3, 0xcc], false),
+    (0x64e6, &[0xf3, 0xcd], false),
+    (0x672d, &[0xf3, 0xce], false),
+    (0x7d2e, &[0xf3, 0xcf], false),
+    (0x50ed, &[0xf3, 0xd0], false),
+    (0x53c3, &[0xf3, 0xd1], false),
+    (0x5879, &[0xf3, 0xd2], false),
+    (0x6158, &[0xf3, 0xd3], false),
+    (0x6159, &[0xf3, 0xd4], false),
+    (0x61fa, &[0xf3, 0xd5], false),
+    (0x65ac, &[0xf3, 0xd6], false),
+    (0x7ad9, &[0xf3, 0xd7], false),
+    (0x8b92, &[0xf3, 0xd8], false),
+    (0x8b96, &[0xf3, 0xd9], false),
+    (0x5009, &[0xf3, 0xda], false),
+    (0x5021, &[0xf3, 0xdb], false),
+    (0x5275, &[0xf3, 0xdc], false),
+    (0x5531, &[0xf3, 0xdd], false),
+    (0x5a3c, &[0xf3, 0xde], false),
+    (0x5ee0, &[0xf3, 0xdf], false),
+    (0x5f70, &[0xf3, 0xe0], false),
+    (0x6134, &[0xf3, 0xe1], false),
+    (0x655e, &[0xf3, 0xe2], false),
+    (0x660c, &[0xf3, 0xe3], false),
+    (0x6636, &[0xf3, 0xe4], false),
+    (0x66a2, &[0xf3, 0xe5], false),
+    (0x69cd, &[0xf3, 0xe6], false),
+    (0x6ec4, &[0xf3, 0xe7], false),
+    (0x6f32, &[0xf3, 0xe8], false),
+    (0x7316, &[0xf3, 0xe9], false),
+    (0x7621, &[0xf3, 0xea], false),
+    (0x7a93, &[0xf3, 0xeb], false),
+    (0x8139, &[0xf3, 0xec], false),
+    (0x8259, &[0xf3, 0xed], false),
+    (0x83d6, &[0xf3, 0xee], false),
+    (0x84bc, &[0xf3, 0xef], false),
+    (0x50b5, &[0xf3, 0xf0], false),
+    (0x57f0, &[0xf3, 0xf1], false),
+    (0x5bc0, &[0xf3, 0xf2], false),
+    (0x5be8, &[0xf3, 0xf3], false),
+    (0x5f69, &[0xf3, 0xf4], false),
+    (0x63a1, &[0xf3, 0xf5], false),
+    (0x7826, &[0xf3, 0xf6], false),
+    (0x7db5, &[0xf3, 0xf7], false),
+    (0x83dc, &[0xf3, 0xf8], false),
+    (0x8521, &[0xf3, 0xf9], false),
+    (0x91c7, &[0xf3, 0xfa], false),
+    (0x91f5, &[0xf3, 0xfb], false),
+    (0x518a, &[0xf3, 0xfc], false),
+    (0x67f5, &[0xf3, 0xfd], false),
+    (0x7b56, &[0xf3, 0xfe], false),
+    (0x8cac, &[0xf4, 0xa1], false),
+    (0x51c4, &[0xf4, 0xa2], false),
+    (0x59bb, &[0xf4, 0xa3], false),
+    (0x60bd, &[0xf4, 0xa4], false),
+    (0x8655, &[0xf4, 0xa5], false),
+    (0x501c, &[0xf4, 0xa6], false),
+    (0xf9ff, &[0xf4, 0xa7], false),
+    (0x5254, &[0xf4, 0xa8], false),
+    (0x5c3a, &[0xf4, 0xa9], false),
+    (0x617d, &[0xf4, 0xaa], false),
+    (0x621a, &[0xf4, 0xab], false),
+    (0x62d3, &[0xf4, 0xac], false),
+    (0x64f2, &[0xf4, 0xad], false),
+    (0x65a5, &[0xf4, 0xae], false),
+    (0x6ecc, &[0xf4, 0xaf], false),
+    (0x7620, &[0xf4, 0xb0], false),
+    (0x810a, &[0xf4, 0xb1], false),
+    (0x8e60, &[0xf4, 0xb2], false),
+    (0x965f, &[0xf4, 0xb3], false),
+    (0x96bb, &[0xf4, 0xb4], false),
+    (0x4edf, &[0xf4, 0xb5], false),
+    (0x5343, &[0xf4, 0xb6], false),
+    (0x5598, &[0xf4, 0xb7], false),
+    (0x5929, &[0xf4, 0xb8], false),
+    (0x5ddd, &[0xf4, 0xb9], false),
+    (0x64c5, &[0xf4, 0xba], false),
+    (0x6cc9, &[0xf4, 0xbb], false),
+    (0x6dfa, &[0xf4, 0xbc], false),
+    (0x7394, &[0xf4, 0xbd], false),
+    (0x7a7f, &[0xf4, 0xbe], false),
+    (0x821b, &[0xf4, 0xbf], false),
+    (0x85a6, &[0xf4, 0xc0], false),
+    (0x8ce4, &[0xf4, 0xc1], false),
+    (0x8e10, &[0xf4, 0xc2], false),
+    (0x9077, &[0xf4, 0xc3], false),
+    (0x91e7, &[0xf4, 0xc4], false),
+    (0x95e1, &[0xf4, 0xc5], false),
+    (0x9621, &[0xf4, 0xc6], false),
+    (0x97c6, &[0xf4, 0xc7], false),
+    (0x51f8, &[0xf4, 0xc8], false),
+    (0x54f2, &[0xf4, 0xc9], false),
+    (0x5586, &[0xf4, 0xca], false),
+    (0x5fb9, &[0xf4, 0xcb], false),
+    (0x64a4, &[0xf4, 0xcc], false),
+    (0x6f88, &[0xf4, 0xcd], false),
+    (0x7db4, &[0xf4, 0xce], false),
+    (0x8f1f, &[0xf4, 0xcf], false),
+    (0x8f4d, &[0xf4, 0xd0], false),
+    (0x9435, &[0xf4, 0xd1], false),
+    (0x50c9, &[0xf4, 0xd2], false),
+    (0x5c16, &[0xf4, 0xd3], false),
+    (0x6cbe, &[0xf4, 0xd4], false),
+    (0x6dfb, &[0xf4, 0xd5], false),
+    (0x751b, &[0xf4, 0xd6], false),
+    (0x77bb, &[0xf4, 0xd7], false),
+    (0x7c3d, &[0xf4, 0xd8], false),
+    (0x7c64, &[0xf4, 0xd9], false),
+    (0x8a79, &[0xf4, 0xda], false),
+    (0x8ac2, &[0xf4, 0xdb], false),
+    (0x581e, &[0xf4, 0xdc], false),
+    (0x59be, &[0xf4, 0xdd], false),
+    (0x5e16, &[0xf4, 0xde], false),
+    (0x6377, &[0xf4, 0xdf], false),
+    (0x7252, &[0xf4, 0xe0], false),
+    (0x758a, &[0xf4, 0xe1], false),
+    (0x776b, &[0xf4, 0xe2], false),
+    (0x8adc, &[0xf4, 0xe3], false),
+    (0x8cbc, &[0xf4, 0xe4], false),
+    (0x8f12, &[0xf4, 0xe5], false),
+    (0x5ef3, &[0xf4, 0xe6], false),
+    (0x6674, &[0xf4, 0xe7], false),
+    (0x6df8, &[0xf4, 0xe8], false),
+    (0x807d, &[0xf4, 0xe9], false),
+    (0x83c1, &[0xf4, 0xea], false),
+    (0x8acb, &[0xf4, 0xeb], false),
+    (0x9751, &[0xf4, 0xec], false),
+    (0x9bd6, &[0xf4, 0xed], false),
+    (0xfa00, &[0xf4, 0xee], false),
+    (0x5243, &[0xf4, 0xef], false),
+    (0x66ff, &[0xf4, 0xf0], false),
+    (0x6d95, &[0xf4, 0xf1], false),
+    (0x6eef, &[0xf4, 0xf2], false),
+    (0x7de0, &[0xf4, 0xf3], false),
+    (0x8ae6, &[0xf4, 0xf4], false),
+    (0x902e, &[0xf4, 0xf5], false),
+    (0x905e, &[0xf4, 0xf6], false),
+    (0x9ad4, &[0xf4, 0xf7], false),
+    (0x521d, &[0xf4, 0xf8], false),
+    (0x527f, &[0xf4, 0xf9], false),
+    (0x54e8, &[0xf4, 0xfa], false),
+    (0x6194, &[0xf4, 0xfb], false),
+    (0x6284, &[0xf4, 0xfc], false),
+    (0x62db, &[0xf4, 0xfd], false),
+    (0x68a2, &[0xf4, 0xfe], false),
+    (0x6912, &[0xf5, 0xa1], false),
+    (0x695a, &[0xf5, 0xa2], false),
+    (0x6a35, &[0xf5, 0xa3], false),
+    (0x7092, &[0xf5, 0xa4], false),
+    (0x7126, &[0xf5, 0xa5], false),
+    (0x785d, &[0xf5, 0xa6], false),
+    (0x7901, &[0xf5, 0xa7], false),
+    (0x790e, &[0xf5, 0xa8], false),
+    (0x79d2, &[0xf5, 0xa9], false),
+    (0x7a0d, &[0xf5, 0xaa], false),
+    (0x8096, &[0xf5, 0xab], false),
+    (0x8278, &[0xf5, 0xac], false),
+    (0x82d5, &[0xf5, 0xad], false),
+    (0x8349, &[0xf5, 0xae], false),
+    (0x8549, &[0xf5, 0xaf], false),
+    (0x8c82, &[0xf5, 0xb0], false),
+    (0x8d85, &[0xf5, 0xb1], false),
+    (0x9162, &[0xf5, 0xb2], false),
+    (0x918b, &[0xf5, 0xb3], false),
+    (0x91ae, &[0xf5, 0xb4], false),
+    (0x4fc3, &[0xf5, 0xb5], false),
+    (0x56d1, &[0xf5, 0xb6], false),
+    (0x71ed, &[0xf5, 0xb7], false),
+    (0x77d7, &[0xf5, 0xb8], false),
+    (0x8700, &[0xf5, 0xb9], false),
+    (0x89f8, &[0xf5, 0xba], false),
+    (0x5bf8, &[0xf5, 0xbb], false),
+    (0x5fd6, &[0xf5, 0xbc], false),
+    (0x6751, &[0xf5, 0xbd], false),
+    (0x90a8, &[0xf5, 0xbe], false),
+    (0x53e2, &[0xf5, 0xbf], false),
+    (0x585a, &[0xf5, 0xc0], false),
+    (0x5bf5, &[0xf5, 0xc1], false),
+    (0x60a4, &[0xf5, 0xc2], false),
+    (0x6181, &[0xf5, 0xc3], false),
+    (0x6460, &[0xf5, 0xc4], false),
+    (0x7e3d, &[0xf5, 0xc5], false),
+    (0x8070, &[0xf5, 0xc6], false),
+    (0x8525, &[0xf5, 0xc7], false),
+    (0x9283, &[0xf5, 0xc8], false),
+    (0x64ae, &[0xf5, 0xc9], false),
+    (0x50ac, &[0xf5, 0xca], false),
+    (0x5d14, &[0xf5, 0xcb], false),
+    (0x6700, &[0xf5, 0xcc], false),
+    (0x589c, &[0xf5, 0xcd], false),
+    (0x62bd, &[0xf5, 0xce], false),
+    (0x63a8, &[0xf5, 0xcf], false),
+    (0x690e, &[0xf5, 0xd0], false),
+    (0x6978, &[0xf5, 0xd1], false),
+    (0x6a1e, &[0xf5, 0xd2], false),
+    (0x6e6b, &[0xf5, 0xd3], false),
+    (0x76ba, &[0xf5, 0xd4], false),
+    (0x79cb, &[0xf5, 0xd5], false),
+    (0x82bb, &[0xf5, 0xd6], false),
+    (0x8429, &[0xf5, 0xd7], false),
+    (0x8acf, &[0xf5, 0xd8], false),
+    (0x8da8, &[0xf5, 0xd9], false),
+    (0x8ffd, &[0xf5, 0xda], false),
+    (0x9112, &[0xf5, 0xdb], false),
+    (0x914b, &[0xf5, 0xdc], false),
+    (0x919c, &[0xf5, 0xdd], false),
+    (0x9310, &[0xf5, 0xde], false),
+    (0x9318, &[0xf5, 0xdf], false),
+    (0x939a, &[0xf5, 0xe0], false),
+    (0x96db, &[0xf5, 0xe1], false),
+    (0x9a36, &[0xf5, 0xe2], false),
+    (0x9c0d, &[0xf5, 0xe3], false),
+    (0x4e11, &[0xf5, 0xe4], false),
+    (0x755c, &[0xf5, 0xe5], false),
+    (0x795d, &[0xf5, 0xe6], false),
+    (0x7afa, &[0xf5, 0xe7], false),
+    (0x7b51, &[0xf5, 0xe8], false),
+    (0x7bc9, &[0xf5, 0xe9], false),
+    (0x7e2e, &[0xf5, 0xea], false),
+    (0x84c4, &[0xf5, 0xeb], false),
+    (0x8e59, &[0xf5, 0xec], false),
+    (0x8e74, &[0xf5, 0xed], false),
+    (0x8ef8, &[0xf5, 0xee], false),
+    (0x9010, &[0xf5, 0xef], false),
+    (0x6625, &[0xf5, 0xf0], false),
+    (0x693f, &[0xf5, 0xf1], false),
+    (0x7443, &[0xf5, 0xf2], false),
+    (0x51fa, &[0xf5, 0xf3], false),
+    (0x672e, &[0xf5, 0xf4], false),
+    (0x9edc, &[0xf5, 0xf5], false),
+    (0x5145, &[0xf5, 0xf6], false),
+    (0x5fe0, &[0xf5, 0xf7], false),
+    (0x6c96, &[0xf5, 0xf8], false),
+    (0x87f2, &[0xf5, 0xf9], false),
+    (0x885d, &[0xf5, 0xfa], false),
+    (0x8877, &[0xf5, 0xfb], false),
+    (0x60b4, &[0xf5, 0xfc], false),
+    (0x81b5, &[0xf5, 0xfd], false),
+    (0x8403, &[0xf5, 0xfe], false),
+    (0x8d05, &[0xf6, 0xa1], false),
+    (0x53d6, &[0xf6, 0xa2], false),
+    (0x5439, &[0xf6, 0xa3], false),
+    (0x5634, &[0xf6, 0xa4], false),
+    (0x5a36, &[0xf6, 0xa5], false),
+    (0x5c31, &[0xf6, 0xa6], false),
+    (0x708a, &[0xf6, 0xa7], false),
+    (0x7fe0, &[0xf6, 0xa8], false),
+    (0x805a, &[0xf6, 0xa9], false),
+    (0x8106, &[0xf6, 0xaa], false),
+    (0x81ed, &[0xf6, 0xab], false),
+    (0x8da3, &[0xf6, 0xac], false),
+    (0x9189, &[0xf6, 0xad], false),
+    (0x9a5f, &[0xf6, 0xae], false),
+    (0x9df2, &[0xf6, 0xaf], false),
+    (0x5074, &[0xf6, 0xb0], false),
+    (0x4ec4, &[0xf6, 0xb1], false),
+    (0x53a0, &[0xf6, 0xb2], false),
+    (0x60fb, &[0xf6, 0xb3], false),
+    (0x6e2c, &[0xf6, 0xb4], false),
+    (0x5c64, &[0xf6, 0xb5], false),
+    (0x4f88, &[0xf6, 0xb6], false),
+    (0x5024, &[0xf6, 0xb7], false),
+    (0x55e4, &[0xf6, 0xb8], false),
+    (0x5cd9, &[0xf6, 0xb9], false),
+    (0x5e5f, &[0xf6, 0xba], false),
+    (0x6065, &[0xf6, 0xbb], false),
+    (0x6894, &[0xf6, 0xbc], false),
+    (0x6cbb, &[0xf6, 0xbd], false),
+    (0x6dc4, &[0xf6, 0xbe], false),
+    (0x71be, &[0xf6, 0xbf], false),
+    (0x75d4, &[0xf6, 0xc0], false),
+    (0x75f4, &[0xf6, 0xc1], false),
+    (0x7661, &[0xf6, 0xc2], false),
+    (0x7a1a, &[0xf6, 0xc3], false),
+    (0x7a49, &[0xf6, 0xc4], false),
+    (0x7dc7, &[0xf6, 0xc5], false),
+    (0x7dfb, &[0xf6, 0xc6], false),
+    (0x7f6e, &[0xf6, 0xc7], false),
+    (0x81f4, &[0xf6, 0xc8], false),
+    (0x86a9, &[0xf6, 0xc9], false),
+    (0x8f1c, &[0xf6, 0xca], false),
+    (0x96c9, &[0xf6, 0xcb], false),
+    (0x99b3, &[0xf6, 0xcc], false),
+    (0x9f52, &[0xf6, 0xcd], false),
+    (0x5247, &[0xf6, 0xce], false),
+    (0x52c5, &[0xf6, 0xcf], false),
+    (0x98ed, &[0xf6, 0xd0], false),
+    (0x89aa, &[0xf6, 0xd1], false),
+    (0x4e03, &[0xf6, 0xd2], false),
+    (0x67d2, &[0xf6, 0xd3], false),
+    (0x6f06, &[0xf6, 0xd4], false),
+    (0x4fb5, &[0xf6, 0xd5], false),
+    (0x5be2, &[0xf6, 0xd6], false),
+    (0x6795, &[0xf6, 0xd7], false),
+    (0x6c88, &[0xf6, 0xd8], false),
+    (0x6d78, &[0xf6, 0xd9], false),
+    (0x741b, &[0xf6, 0xda], false),
+    (0x7827, &[0xf6, 0xdb], false),
+    (0x91dd, &[0xf6, 0xdc], false),
+    (0x937c, &[0xf6, 0xdd], false),
+    (0x87c4, &[0xf6, 0xde], false),
+    (0x79e4, &[0xf6, 0xdf], false),
+    (0x7a31, &[0xf6, 0xe0], false),
+    (0x5feb, &[0xf6, 0xe1], false),
+    (0x4ed6, &[0xf6, 0xe2], false),
+    (0x54a4, &[0xf6, 0xe3], false),
+    (0x553e, &[0xf6, 0xe4], false),
+    (0x58ae, &[0xf6, 0xe5], false),
+    (0x59a5, &[0xf6, 0xe6], false),
+    (0x60f0, &[0xf6, 0xe7], false),
+    (0x6253, &[0xf6, 0xe8], false),
+    (0x62d6, &[0xf6, 0xe9], false),
+    (0x6736, &[0xf6, 0xea], false),
+    (0x6955, &[0xf6, 0xeb], false),
+    (0x8235, &[0xf6, 0xec], false),
+    (0x9640, &[0xf6, 0xed], false),
+    (0x99b1, &[0xf6, 0xee], false),
+    (0x99dd, &[0xf6, 0xef], false),
+    (0x502c, &[0xf6, 0xf0], false),
+    (0x5353, &[0xf6, 0xf1], false),
+    (0x5544, &[0xf6, 0xf2], false),
+    (0x577c, &[0xf6, 0xf3], false),
+    (0xfa01, &[0xf6, 0xf4], false),
+    (0x6258, &[0xf6, 0xf5], false),
+    (0xfa02, &[0xf6, 0xf6], false),
+    (0x64e2, &[0xf6, 0xf7], false),
+    (0x666b, &[0xf6, 0xf8], false),
+    (0x67dd, &[0xf6, 0xf9], false),
+    (0x6fc1, &[0xf6, 0xfa], false),
+    (0x6fef, &[0xf6, 0xfb], false),
+    (0x7422, &[0xf6, 0xfc], false),
+    (0x7438, &[0xf6, 0xfd], false),
+    (0x8a17, &[0xf6, 0xfe], false),
+    (0x9438, &[0xf7, 0xa1], false),
+    (0x5451, &[0xf7, 0xa2], false),
+    (0x5606, &[0xf7, 0xa3], false),
+    (0x5766, &[0xf7, 0xa4], false),
+    (0x5f48, &[0xf7, 0xa5], false),
+    (0x619a, &[0xf7, 0xa6], false),
+    (0x6b4e, &[0xf7, 0xa7], false),
+    (0x7058, &[0xf7, 0xa8], false),
+    (0x70ad, &[0xf7, 0xa9], false),
+    (0x7dbb, &[0xf7, 0xaa], false),
+    (0x8a95, &[0xf7, 0xab], false),
+    (0x596a, &[0xf7, 0xac], false),
+    (0x812b, &[0xf7, 0xad], false),
+    (0x63a2, &[0xf7, 0xae], false),
+    (0x7708, &[0xf7, 0xaf], false),
+    (0x803d, &[0xf7, 0xb0], false),
+    (0x8caa, &[0xf7, 0xb1], false),
+    (0x5854, &[0xf7, 0xb2], false),
+    (0x642d, &[0xf7, 0xb3], false),
+    (0x69bb, &[0xf7, 0xb4], false),
+    (0x5b95, &[0xf7, 0xb5], false),
+    (0x5e11, &[0xf7, 0xb6], false),
+    (0x6e6f, &[0xf7, 0xb7], false),
+    (0xfa03, &[0xf7, 0xb8], false),
+    (0x8569, &[0xf7, 0xb9], false),
+    (0x514c, &[0xf7, 0xba], false),
+    (0x53f0, &[0xf7, 0xbb], false),
+    (0x592a, &[0xf7, 0xbc], false),
+    (0x6020, &[0xf7, 0xbd], false),
+    (0x614b, &[0xf7, 0xbe], false),
+    (0x6b86, &[0xf7, 0xbf], false),
+    (0x6c70, &[0xf7, 0xc0], false),
+    (0x6cf0, &[0xf7, 0xc1], false),
+    (0x7b1e, &[0xf7, 0xc2], false),
+    (0x80ce, &[0xf7, 0xc3], false),
+    (0x82d4, &[0xf7, 0xc4], false),
+    (0x8dc6, &[0xf7, 0xc5], false),
+    (0x90b0, &[0xf7, 0xc6], false),
+    (0x98b1, &[0xf7, 0xc7], false),
+    (0xfa04, &[0xf7, 0xc8], false),
+    (0x64c7, &[0xf7, 0xc9], false),
+    (0x6fa4, &[0xf7, 0xca], false),
+    (0x6491, &[0xf7, 0xcb], false),
+    (0x6504, &[0xf7, 0xcc], false),
+    (0x514e, &[0xf7, 0xcd], false),
+    (0x5410, &[0xf7, 0xce], false),
+    (0x571f, &[0xf7, 0xcf], false),
+    (0x8a0e, &[0xf7, 0xd0], false),
+    (0x615f, &[0xf7, 0xd1], false),
+    (0x6876, &[0xf7, 0xd2], false),
+    (0xfa05, &[0xf7, 0xd3], false),
+    (0x75db, &[0xf7, 0xd4], false),
+    (0x7b52, &[0xf7, 0xd5], false),
+    (0x7d71, &[0xf7, 0xd6], false),
+    (0x901a, &[0xf7, 0xd7], false),
+    (0x5806, &[0xf7, 0xd8], false),
+    (0x69cc, &[0xf7, 0xd9], false),
+    (0x817f, &[0xf7, 0xda], false),
+    (0x892a, &[0xf7, 0xdb], false),
+    (0x9000, &[0xf7, 0xdc], false),
+    (0x9839, &[0xf7, 0xdd], false),
+    (0x5078, &[0xf7, 0xde], false),
+    (0x5957, &[0xf7, 0xdf], false),
+    (0x59ac, &[0xf7, 0xe0], false),
+    (0x6295, &[0xf7, 0xe1], false),
+    (0x900f, &[0xf7, 0xe2], false),
+    (0x9b2a, &[0xf7, 0xe3], false),
+    (0x615d, &[0xf7, 0xe4], false),
+    (0x7279, &[0xf7, 0xe5], false),
+    (0x95d6, &[0xf7, 0xe6], false),
+    (0x5761, &[0xf7, 0xe7], false),
+    (0x5a46, &[0xf7, 0xe8], false),
+    (0x5df4, &[0xf7, 0xe9], false),
+    (0x628a, &[0xf7, 0xea], false),
+    (0x64ad, &[0xf7, 0xeb], false),
+    (0x64fa, &[0xf7, 0xec], false),
+    (0x6777, &[0xf7, 0xed], false),
+    (0x6ce2, &[0xf7, 0xee], false),
+    (0x6d3e, &[0xf7, 0xef], false),
+    (0x722c, &[0xf7, 0xf0], false),
+    (0x7436, &[0xf7, 0xf1], false),
+    (0x7834, &[0xf7, 0xf2], false),
+    (0x7f77, &[0xf7, 0xf3], false),
+    (0x82ad, &[0xf7, 0xf4], false),
+    (0x8ddb, &[0xf7, 0xf5], false),
+    (0x9817, &[0xf7, 0xf6], false),
+    (0x5224, &[0xf7, 0xf7], false),
+    (0x5742, &[0xf7, 0xf8], false),
+    (0x677f, &[0xf7, 0xf9], false),
+    (0x7248, &[0xf7, 0xfa], false),
+    (0x74e3, &[0xf7, 0xfb], false),
+    (0x8ca9, &[0xf7, 0xfc], false),
+    (0x8fa6, &[0xf7, 0xfd], false),
+    (0x9211, &[0xf7, 0xfe], false),
+    (0x962a, &[0xf8, 0xa1], false),
+    (0x516b, &[0xf8, 0xa2], false),
+    (0x53ed, &[0xf8, 0xa3], false),
+    (0x634c, &[0xf8, 0xa4], false),
+    (0x4f69, &[0xf8, 0xa5], false),
+    (0x5504, &[0xf8, 0xa6], false),
+    (0x6096, &[0xf8, 0xa7], false),
+    (0x6557, &[0xf8, 0xa8], false),
+    (0x6c9b, &[0xf8, 0xa9], false),
+    (0x6d7f, &[0xf8, 0xaa], false),
+    (0x724c, &[0xf8, 0xab], false),
+    (0x72fd, &[0xf8, 0xac], false),
+    (0x7a17, &[0xf8, 0xad], false),
+    (0x8987, &[0xf8, 0xae], false),
+    (0x8c9d, &[0xf8, 0xaf], false),
+    (0x5f6d, &[0xf8, 0xb0], false),
+    (0x6f8e, &[0xf8, 0xb1], false),
+    (0x70f9, &[0xf8, 0xb2], false),
+    (0x81a8, &[0xf8, 0xb3], false),
+    (0x610e, &[0xf8, 0xb4], false),
+    (0x4fbf, &[0xf8, 0xb5], false),
+    (0x504f, &[0xf8, 0xb6], false),
+    (0x6241, &[0xf8, 0xb7], false),
+    (0x7247, &[0xf8, 0xb8], false),
+    (0x7bc7, &[0xf8, 0xb9], false),
+    (0x7de8, &[0xf8, 0xba], false),
+    (0x7fe9, &[0xf8, 0xbb], false),
+    (0x904d, &[0xf8, 0xbc], false),
+    (0x97ad, &[0xf8, 0xbd], false),
+    (0x9a19, &[0xf8, 0xbe], false),
+    (0x8cb6, &[0xf8, 0xbf], false),
+    (0x576a, &[0xf8, 0xc0], false),
+    (0x5e73, &[0xf8, 0xc1], false),
+    (0x67b0, &[0xf8, 0xc2], false),
+    (0x840d, &[0xf8, 0xc3], false),
+    (0x8a55, &[0xf8, 0xc4], false),
+    (0x5420, &[0xf8, 0xc5], false),
+    (0x5b16, &[0xf8, 0xc6], false),
+    (0x5e63, &[0xf8, 0xc7], false),
+    (0x5ee2, &[0xf8, 0xc8], false),
+    (0x5f0a, &[0xf8, 0xc9], false),
+    (0x6583, &[0xf8, 0xca], false),
+    (0x80ba, &[0xf8, 0xcb], false),
+    (0x853d, &[0xf8, 0xcc], false),
+    (0x9589, &[0xf8, 0xcd], false),
+    (0x965b, &[0xf8, 0xce], false),
+    (0x4f48, &[0xf8, 0xcf], false),
+    (0x5305, &[0xf8, 0xd0], false),
+    (0x530d, &[0xf8, 0xd1], false),
+    (0x530f, &[0xf8, 0xd2], false),
+    (0x5486, &[0xf8, 0xd3], false),
+    (0x54fa, &[0xf8, 0xd4], false),
+    (0x5703, &[0xf8, 0xd5], false),
+    (0x5e03, &[0xf8, 0xd6], false),
+    (0x6016, &[0xf8, 0xd7], false),
+    (0x629b, &[0xf8, 0xd8], false),
+    (0x62b1, &[0xf8, 0xd9], false),
+    (0x6355, &[0xf8, 0xda], false),
+    (0xfa06, &[0xf8, 0xdb], false),
+    (0x6ce1, &[0xf8, 0xdc], false),
+    (0x6d66, &[0xf8, 0xdd], false),
+    (0x75b1, &[0xf8, 0xde], false),
+    (0x7832, &[0xf8, 0xdf], false),
+    (0x80de, &[0xf8, 0xe0], false),
+    (0x812f, &[0xf8, 0xe1], false),
+    (0x82de, &[0xf8, 0xe2], false),
+    (0x8461, &[0xf8, 0xe3], false),
+    (0x84b2, &[0xf8, 0xe4], false),
+    (0x888d, &[0xf8, 0xe5], false),
+    (0x8912, &[0xf8, 0xe6], false),
+    (0x900b, &[0xf8, 0xe7], false),
+    (0x92ea, &[0xf8, 0xe8], false),
+    (0x98fd, &[0xf8, 0xe9], false),
+    (0x9b91, &[0xf8, 0xea], false),
+    (0x5e45, &[0xf8, 0xeb], false),
+    (0x66b4, &[0xf8, 0xec], false),
+    (0x66dd, &[0xf8, 0xed], false),
+    (0x7011, &[0xf8, 0xee], false),
+    (0x7206, &[0xf8, 0xef], false),
+    (0xfa07, &[0xf8, 0xf0], false),
+    (0x4ff5, &[0xf8, 0xf1], false),
+    (0x527d, &[0xf8, 0xf2], false),
+    (0x5f6a, &[0xf8, 0xf3], false),
+    (0x6153, &[0xf8, 0xf4], false),
+    (0x6753, &[0xf8, 0xf5], false),
+    (0x6a19, &[0xf8, 0xf6], false),
+    (0x6f02, &[0xf8, 0xf7], false),
+    (0x74e2, &[0xf8, 0xf8], false),
+    (0x7968, &[0xf8, 0xf9], false),
+    (0x8868, &[0xf8, 0xfa], false),
+    (0x8c79, &[0xf8, 0xfb], false),
+    (0x98c7, &[0xf8, 0xfc], false),
+    (0x98c4, &[0xf8, 0xfd], false),
+    (0x9a43, &[0xf8, 0xfe], false),
+    (0x54c1, &[0xf9, 0xa1], false),
+    (0x7a1f, &[0xf9, 0xa2], false),
+    (0x6953, &[0xf9, 0xa3], false),
+    (0x8af7, &[0xf9, 0xa4], false),
+    (0x8c4a, &[0xf9, 0xa5], false),
+    (0x98a8, &[0xf9, 0xa6], false),
+    (0x99ae, &[0xf9, 0xa7], false),
+    (0x5f7c, &[0xf9, 0xa8], false),
+    (0x62ab, &[0xf9, 0xa9], false),
+    (0x75b2, &[0xf9, 0xaa], false),
+    (0x76ae, &[0xf9, 0xab], false),
+    (0x88ab, &[0xf9, 0xac], false),
+    (0x907f, &[0xf9, 0xad], false),
+    (0x9642, &[0xf9, 0xae], false),
+    (0x5339, &[0xf9, 0xaf], false),
+    (0x5f3c, &[0xf9, 0xb0], false),
+    (0x5fc5, &[0xf9, 0xb1], false),
+    (0x6ccc, &[0xf9, 0xb2], false),
+    (0x73cc, &[0xf9, 0xb3], false),
+    (0x7562, &[0xf9, 0xb4], false),
+    (0x758b, &[0xf9, 0xb5], false),
+    (0x7b46, &[0xf9, 0xb6], false),
+    (0x82fe, &[0xf9, 0xb7], false),
+    (0x999d, &[0xf9, 0xb8], false),
+    (0x4e4f, &[0xf9, 0xb9], false),
+    (0x903c, &[0xf9, 0xba], false),
+    (0x4e0b, &[0xf9, 0xbb], false),
+    (0x4f55, &[0xf9, 0xbc], false),
+    (0x53a6, &[0xf9, 0xbd], false),
+    (0x590f, &[0xf9, 0xbe], false),
+    (0x5ec8, &[0xf9, 0xbf], false),
+    (0x6630, &[0xf9, 0xc0], false),
+    (0x6cb3, &[0xf9, 0xc1], false),
+    (0x7455, &[0xf9, 0xc2], false),
+    (0x8377, &[0xf9, 0xc3], false),
+    (0x8766, &[0xf9, 0xc4], false),
+    (0x8cc0, &[0xf9, 0xc5], false),
+    (0x9050, &[0xf9, 0xc6], false),
+    (0x971e, &[0xf9, 0xc7], false),
+    (0x9c15, &[0xf9, 0xc8], false),
+    (0x58d1, &[0xf9, 0xc9], false),
+    (0x5b78, &[0xf9, 0xca], false),
+    (0x8650, &[0xf9, 0xcb], false),
+    (0x8b14, &[0xf9, 0xcc], false),
+    (0x9db4, &[0xf9, 0xcd], false),
+    (0x5bd2, &[0xf9, 0xce], false),
+    (0x6068, &[0xf9, 0xcf], false),
+    (0x608d, &[0xf9, 0xd0], false),
+    (0x65f1, &[0xf9, 0xd1], false),
+    (0x6c57, &[0xf9, 0xd2], false),
+    (0x6f22, &[0xf9, 0xd3], false),
+    (0x6fa3, &[0xf9, 0xd4], false),
+    (0x701a, &[0xf9, 0xd5], false),
+    (0x7f55, &[0xf9, 0xd6], false),
+    (0x7ff0, &[0xf9, 0xd7], false),
+    (0x9591, &[0xf9, 0xd8], false),
+    (0x9592, &[0xf9, 0xd9], false),
+    (0x9650, &[0xf9, 0xda], false),
+    (0x97d3, &[0xf9, 0xdb], false),
+    (0x5272, &[0xf9, 0xdc], false),
+    (0x8f44, &[0xf9, 0xdd], false),
+    (0x51fd, &[0xf9, 0xde], false),
+    (0x542b, &[0xf9, 0xdf], false),
+    (0x54b8, &[0xf9, 0xe0], false),
+    (0x5563, &[0xf9, 0xe1], false),
+    (0x558a, &[0xf9, 0xe2], false),
+    (0x6abb, &[0xf9, 0xe3], false),
+    (0x6db5, &[0xf9, 0xe4], false),
+    (0x7dd8, &[0xf9, 0xe5], false),
+    (0x8266, &[0xf9, 0xe6], false),
+    (0x929c, &[0xf9, 0xe7], false),
+    (0x9677, &[0xf9, 0xe8], false),
+    (0x9e79, &[0xf9, 0xe9], false),
+    (0x5408, &[0xf9, 0xea], false),
+    (0x54c8, &[0xf9, 0xeb], false),
+    (0x76d2, &[0xf9, 0xec], false),
+    (0x86e4, &[0xf9, 0xed], false),
+    (0x95a4, &[0xf9, 0xee], false),
+    (0x95d4, &[0xf9, 0xef], false),
+    (0x965c, &[0xf9, 0xf0], false),
+    (0x4ea2, &[0xf9, 0xf1], false),
+    (0x4f09, &[0xf9, 0xf2], false),
+    (0x59ee, &[0xf9, 0xf3], false),
+    (0x5ae6, &[0xf9, 0xf4], false),
+    (0x5df7, &[0xf9, 0xf5], false),
+    (0x6052, &[0xf9, 0xf6], false),
+    (0x6297, &[0xf9, 0xf7], false),
+    (0x676d, &[0xf9, 0xf8], false),
+    (0x6841, &[0xf9, 0xf9], false),
+    (0x6c86, &[0xf9, 0xfa], false),
+    (0x6e2f, &[0xf9, 0xfb], false),
+    (0x7f38, &[0xf9, 0xfc], false),
+    (0x809b, &[0xf9, 0xfd], false),
+    (0x822a, &[0xf9, 0xfe], false),
+    (0xfa08, &[0xfa, 0xa1], false),
+    (0xfa09, &[0xfa, 0xa2], false),
+    (0x9805, &[0xfa, 0xa3], false),
+    (0x4ea5, &[0xfa, 0xa4], false),
+    (0x5055, &[0xfa, 0xa5], false),
+    (0x54b3, &[0xfa, 0xa6], false),
+    (0x5793, &[0xfa, 0xa7], false),
+    (0x595a, &[0xfa, 0xa8], false),
+    (0x5b69, &[0xfa, 0xa9], false),
+    (0x5bb3, &[0xfa, 0xaa], false),
+    (0x61c8, &[0xfa, 0xab], false),
+    (0x6977, &[0xfa, 0xac], false),
+    (0x6d77, &[0xfa, 0xad], false),
+    (0x7023, &[0xfa, 0xae], false),
+    (0x87f9, &[0xfa, 0xaf], false),
+    (0x89e3, &[0xfa, 0xb0], false),
+    (0x8a72, &[0xfa, 0xb1], false),
+    (0x8ae7, &[0xfa, 0xb2], false),
+    (0x9082, &[0xfa, 0xb3], false),
+    (0x99ed, &[0xfa, 0xb4], false),
+    (0x9ab8, &[0xfa, 0xb5], false),
+    (0x52be, &[0xfa, 0xb6], false),
+    (0x6838, &[0xfa, 0xb7], false),
+    (0x5016, &[0xfa, 0xb8], false),
+    (0x5e78, &[0xfa, 0xb9], false),
+    (0x674f, &[0xfa, 0xba], false),
+    (0x8347, &[0xfa, 0xbb], false),
+    (0x884c, &[0xfa, 0xbc], false),
+    (0x4eab, &[0xfa, 0xbd], false),
+    (0x5411, &[0xfa, 0xbe], false),
+    (0x56ae, &[0xfa, 0xbf], false),
+    (0x73e6, &[0xfa, 0xc0], false),
+    (0x9115, &[0xfa, 0xc1], false),
+    (0x97ff, &[0xfa, 0xc2], false),
+    (0x9909, &[0xfa, 0xc3], false),
+    (0x9957, &[0xfa, 0xc4], false),
+    (0x9999, &[0xfa, 0xc5], false),
+    (0x5653, &[0xfa, 0xc6], false),
+    (0x589f, &[0xfa, 0xc7], false),
+    (0x865b, &[0xfa, 0xc8], false),
+    (0x8a31, &[0xfa, 0xc9], false),
+    (0x61b2, &[0xfa, 0xca], false),
+    (0x6af6, &[0xfa, 0xcb], false),
+    (0x737b, &[0xfa, 0xcc], false),
+    (0x8ed2, &[0xfa, 0xcd], false),
+    (0x6b47, &[0xfa, 0xce], false),
+    (0x96aa, &[0xfa, 0xcf], false),
+    (0x9a57, &[0xfa, 0xd0], false),
+    (0x5955, &[0xfa, 0xd1], false),
+    (0x7200, &[0xfa, 0xd2], false),
+    (0x8d6b, &[0xfa, 0xd3], false),
+    (0x9769, &[0xfa, 0xd4], false),
+    (0x4fd4, &[0xfa, 0xd5], false),
+    (0x5cf4, &[0xfa, 0xd6], false),
+    (0x5f26, &[0xfa, 0xd7], false),
+    (0x61f8, &[0xfa, 0xd8], false),
+    (0x665b, &[0xfa, 0xd9], false),
+    (0x6ceb, &[0xfa, 0xda], false),
+    (0x70ab, &[0xfa, 0xdb], false),
+    (0x7384, &[0xfa, 0xdc], false),
+    (0x73b9, &[0xfa, 0xdd], false),
+    (0x73fe, &[0xfa, 0xde], false),
+    (0x7729, &[0xfa, 0xdf], false),
+    (0x774d, &[0xfa, 0xe0], false),
+    (0x7d43, &[0xfa, 0xe1], false),
+    (0x7d62, &[0xfa, 0xe2], false),
+    (0x7e23, &[0xfa, 0xe3], false),
+    (0x8237, &[0xfa, 0xe4], false),
+    (0x8852, &[0xfa, 0xe5], false),
+    (0xfa0a, &[0xfa, 0xe6], false),
+    (0x8ce2, &[0xfa, 0xe7], false),
+    (0x9249, &[0xfa, 0xe8], false),
+    (0x986f, &[0xfa, 0xe9], false),
+    (0x5b51, &[0xfa, 0xea], false),
+    (0x7a74, &[0xfa, 0xeb], false),
+    (0x8840, &[0xfa, 0xec], false),
+    (0x9801, &[0xfa, 0xed], false),
+    (0x5acc, &[0xfa, 0xee], false),
+    (0x4fe0, &[0xfa, 0xef], false),
+    (0x5354, &[0xfa, 0xf0], false),
+    (0x593e, &[0xfa, 0xf1], false),
+    (0x5cfd, &[0xfa, 0xf2], false),
+    (0x633e, &[0xfa, 0xf3], false),
+    (0x6d79, &[0xfa, 0xf4], false),
+    (0x72f9, &[0xfa, 0xf5], false),
+    (0x8105, &[0xfa, 0xf6], false),
+    (0x8107, &[0xfa, 0xf7], false),
+    (0x83a2, &[0xfa, 0xf8], false),
+    (0x92cf, &[0xfa, 0xf9], false),
+    (0x9830, &[0xfa, 0xfa], false),
+    (0x4ea8, &[0xfa, 0xfb], false),
+    (0x5144, &[0xfa, 0xfc], false),
+    (0x5211, &[0xfa, 0xfd], false),
+    (0x578b, &[0xfa, 0xfe], false),
+    (0x5f62, &[0xfb, 0xa1], false),
+    (0x6cc2, &[0xfb, 0xa2], false),
+    (0x6ece, &[0xfb, 0xa3], false),
+    (0x7005, &[0xfb, 0xa4], false),
+    (0x7050, &[0xfb, 0xa5], false),
+    (0x70af, &[0xfb, 0xa6], false),
+    (0x7192, &[0xfb, 0xa7], false),
+    (0x73e9, &[0xfb, 0xa8], false),
+    (0x7469, &[0xfb, 0xa9], false),
+    (0x834a, &[0xfb, 0xaa], false),
+    (0x87a2, &[0xfb, 0xab], false),
+    (0x8861, &[0xfb, 0xac], false),
+    (0x9008, &[0xfb, 0xad], false),
+    (0x90a2, &[0xfb, 0xae], false),
+    (0x93a3, &[0xfb, 0xaf], false),
+    (0x99a8, &[0xfb, 0xb0], false),
+    (0x516e, &[0xfb, 0xb1], false),
+    (0x5f57, &[0xfb, 0xb2], false),
+    (0x60e0, &[0xfb, 0xb3], false),
+    (0x6167, &[0xfb, 0xb4], false),
+    (0x66b3, &[0xfb, 0xb5], false),
+    (0x8559, &[0xfb, 0xb6], false),
+    (0x8e4a, &[0xfb, 0xb7], false),
+    (0x91af, &[0xfb, 0xb8], false),
+    (0x978b, &[0xfb, 0xb9], false),
+    (0x4e4e, &[0xfb, 0xba], false),
+    (0x4e92, &[0xfb, 0xbb], false),
+    (0x547c, &[0xfb, 0xbc], false),
+    (0x58d5, &[0xfb, 0xbd], false),
+    (0x58fa, &[0xfb, 0xbe], false),
+    (0x597d, &[0xfb, 0xbf], false),
+    (0x5cb5, &[0xfb, 0xc0], false),
+    (0x5f27, &[0xfb, 0xc1], false),
+    (0x6236, &[0xfb, 0xc2], false),
+    (0x6248, &[0xfb, 0xc3], false),
+    (0x660a, &[0xfb, 0xc4], false),
+    (0x6667, &[0xfb, 0xc5], false),
+    (0x6beb, &[0xfb, 0xc6], false),
+    (0x6d69, &[0xfb, 0xc7], false),
+    (0x6dcf, &[0xfb, 0xc8], false),
+    (0x6e56, &[0xfb, 0xc9], false),
+    (0x6ef8, &[0xfb, 0xca], false),
+    (0x6f94, &[0xfb, 0xcb], false),
+    (0x6fe0, &[0xfb, 0xcc], false),
+    (0x6fe9, &[0xfb, 0xcd], false),
+    (0x705d, &[0xfb, 0xce], false),
+    (0x72d0, &[0xfb, 0xcf], false),
+    (0x7425, &[0xfb, 0xd0], false),
+    (0x745a, &[0xfb, 0xd1], false),
+    (0x74e0, &[0xfb, 0xd2], false),
+    (0x7693, &[0xfb, 0xd3], false),
+    (0x795c, &[0xfb, 0xd4], false),
+    (0x7cca, &[0xfb, 0xd5], false),
+    (0x7e1e, &[0xfb, 0xd6], false),
+    (0x80e1, &[0xfb, 0xd7], false),
+    (0x82a6, &[0xfb, 0xd8], false),
+    (0x846b, &[0xfb, 0xd9], false),
+    (0x84bf, &[0xfb, 0xda], false),
+    (0x864e, &[0xfb, 0xdb], false),
+    (0x865f, &[0xfb, 0xdc], false),
+    (0x8774, &[0xfb, 0xdd], false),
+    (0x8b77, &[0xfb, 0xde], false),
+    (0x8c6a, &[0xfb, 0xdf], false),
+    (0x93ac, &[0xfb, 0xe0], false),
+    (0x9800, &[0xfb, 0xe1], false),
+    (0x9865, &[0xfb, 0xe2], false),
+    (0x60d1, &[0xfb, 0xe3], false),
+    (0x6216, &[0xfb, 0xe4], false),
+    (0x9177, &[0xfb, 0xe5], false),
+    (0x5a5a, &[0xfb, 0xe6], false),
+    (0x660f, &[0xfb, 0xe7], false),
+    (0x6df7, &[0xfb, 0xe8], false),
+    (0x6e3e, &[0xfb, 0xe9], false),
+    (0x743f, &[0xfb, 0xea], false),
+    (0x9b42, &[0xfb, 0xeb], false),
+    (0x5ffd, &[0xfb, 0xec], false),
+    (0x60da, &[0xfb, 0xed], false),
+    (0x7b0f, &[0xfb, 0xee], false),
+    (0x54c4, &[0xfb, 0xef], false),
+    (0x5f18, &[0xfb, 0xf0], false),
+    (0x6c5e, &[0xfb, 0xf1], false),
+    (0x6cd3, &[0xfb, 0xf2], false),
+    (0x6d2a, &[0xfb, 0xf3], false),
+    (0x70d8, &[0xfb, 0xf4], false),
+    (0x7d05, &[0xfb, 0xf5], false),
+    (0x8679, &[0xfb, 0xf6], false),
+    (0x8a0c, &[0xfb, 0xf7], false),
+    (0x9d3b, &[0xfb, 0xf8], false),
+    (0x5316, &[0xfb, 0xf9], false),
+    (0x548c, &[0xfb, 0xfa], false),
+    (0x5b05, &[0xfb, 0xfb], false),
+    (0x6a3a, &[0xfb, 0xfc], false),
+    (0x706b, &[0xfb, 0xfd], false),
+    (0x7575, &[0xfb, 0xfe], false),
+    (0x798d, &[0xfc, 0xa1], false),
+    (0x79be, &[0xfc, 0xa2], false),
+    (0x82b1, &[0xfc, 0xa3], false),
+    (0x83ef, &[0xfc, 0xa4], false),
+    (0x8a71, &[0xfc, 0xa5], false),
+    (0x8b41, &[0xfc, 0xa6], false),
+    (0x8ca8, &[0xfc, 0xa7], false),
+    (0x9774, &[0xfc, 0xa8], false),
+    (0xfa0b, &[0xfc, 0xa9], false),
+    (0x64f4, &[0xfc, 0xaa], false),
+    (0x652b, &[0xfc, 0xab], false),
+    (0x78ba, &[0xfc, 0xac], false),
+    (0x78bb, &[0xfc, 0xad], false),
+    (0x7a6b, &[0xfc, 0xae], false),
+    (0x4e38, &[0xfc, 0xaf], false),
+    (0x559a, &[0xfc, 0xb0], false),
+    (0x5950, &[0xfc, 0xb1], false),
+    (0x5ba6, &[0xfc, 0xb2], false),
+    (0x5e7b, &[0xfc, 0xb3], false),
+    (0x60a3, &[0xfc, 0xb4], false),
+    (0x63db, &[0xfc, 0xb5], false),
+    (0x6b61, &[0xfc, 0xb6], false),
+    (0x6665, &[0xfc, 0xb7], false),
+    (0x6853, &[0xfc, 0xb8], false),
+    (0x6e19, &[0xfc, 0xb9], false),
+    (0x7165, &[0xfc, 0xba], false),
+    (0x74b0, &[0xfc, 0xbb], false),
+    (0x7d08, &[0xfc, 0xbc], false),
+    (0x9084, &[0xfc, 0xbd], false),
+    (0x9a69, &[0xfc, 0xbe], false),
+    (0x9c25, &[0xfc, 0xbf], false),
+    (0x6d3b, &[0xfc, 0xc0], false),
+    (0x6ed1, &[0xfc, 0xc1], false),
+    (0x733e, &[0xfc, 0xc2], false),
+    (0x8c41, &[0xfc, 0xc3], false),
+    (0x95ca, &[0xfc, 0xc4], false),
+    (0x51f0, &[0xfc, 0xc5], false),
+    (0x5e4c, &[0xfc, 0xc6], false),
+    (0x5fa8, &[0xfc, 0xc7], false),
+    (0x604d, &[0xfc, 0xc8], false),
+    (0x60f6, &[0xfc, 0xc9], false),
+    (0x6130, &[0xfc, 0xca], false),
+    (0x614c, &[0xfc, 0xcb], false),
+    (0x6643, &[0xfc, 0xcc], false),
+    (0x6644, &[0xfc, 0xcd], false),
+    (0x69a5, &[0xfc, 0xce], false),
+    (0x6cc1, &[0xfc, 0xcf], false),
+    (0x6e5f, &[0xfc, 0xd0], false),
+    (0x6ec9, &[0xfc, 0xd1], false),
+    (0x6f62, &[0xfc, 0xd2], false),
+    (0x714c, &[0xfc, 0xd3], false),
+    (0x749c, &[0xfc, 0xd4], false),
+    (0x7687, &[0xfc, 0xd5], false),
+    (0x7bc1, &[0xfc, 0xd6], false),
+    (0x7c27, &[0xfc, 0xd7], false),
+    (0x8352, &[0xfc, 0xd8], false),
+    (0x8757, &[0xfc, 0xd9], false),
+    (0x9051, &[0xfc, 0xda], false),
+    (0x968d, &[0xfc, 0xdb], false),
+    (0x9ec3, &[0xfc, 0xdc], false),
+    (0x532f, &[0xfc, 0xdd], false),
+    (0x56de, &[0xfc, 0xde], false),
+    (0x5efb, &[0xfc, 0xdf], false),
+    (0x5f8a, &[0xfc, 0xe0], false),
+    (0x6062, &[0xfc, 0xe1], false),
+    (0x6094, &[0xfc, 0xe2], false),
+    (0x61f7, &[0xfc, 0xe3], false),
+    (0x6666, &[0xfc, 0xe4], false),
+    (0x6703, &[0xfc, 0xe5], false),
+    (0x6a9c, &[0xfc, 0xe6], false),
+    (0x6dee, &[0xfc, 0xe7], false),
+    (0x6fae, &[0xfc, 0xe8], false),
+    (0x7070, &[0xfc, 0xe9], false),
+    (0x736a, &[0xfc, 0xea], false),
+    (0x7e6a, &[0xfc, 0xeb], false),
+    (0x81be, &[0xfc, 0xec], false),
+    (0x8334, &[0xfc, 0xed], false),
+    (0x86d4, &[0xfc, 0xee], false),
+    (0x8aa8, &[0xfc, 0xef], false),
+    (0x8cc4, &[0xfc, 0xf0], false),
+    (0x5283, &[0xfc, 0xf1], false),
+    (0x7372, &[0xfc, 0xf2], false),
+    (0x5b96, &[0xfc, 0xf3], false),
+    (0x6a6b, &[0xfc, 0xf4], false),
+    (0x9404, &[0xfc, 0xf5], false),
+    (0x54ee, &[0xfc, 0xf6], false),
+    (0x5686, &[0xfc, 0xf7], false),
+    (0x5b5d, &[0xfc, 0xf8], false),
+    (0x6548, &[0xfc, 0xf9], false),
+    (0x6585, &[0xfc, 0xfa], false),
+    (0x66c9, &[0xfc, 0xfb], false),
+    (0x689f, &[0xfc, 0xfc], false),
+    (0x6d8d, &[0xfc, 0xfd], false),
+    (0x6dc6, &[0xfc, 0xfe], false),
+    (0x723b, &[0xfd, 0xa1], false),
+    (0x80b4, &[0xfd, 0xa2], false),
+    (0x9175, &[0xfd, 0xa3], false),
+    (0x9a4d, &[0xfd, 0xa4], false),
+    (0x4faf, &[0xfd, 0xa5], false),
+    (0x5019, &[0xfd, 0xa6], false),
+    (0x539a, &[0xfd, 0xa7], false),
+    (0x540e, &[0xfd, 0xa8], false),
+    (0x543c, &[0xfd, 0xa9], false),
+    (0x5589, &[0xfd, 0xaa], false),
+    (0x55c5, &[0xfd, 0xab], false),
+    (0x5e3f, &[0xfd, 0xac], false),
+    (0x5f8c, &[0xfd, 0xad], false),
+    (0x673d, &[0xfd, 0xae], false),
+    (0x7166, &[0xfd, 0xaf], false),
+    (0x73dd, &[0xfd, 0xb0], false),
+    (0x9005, &[0xfd, 0xb1], false),
+    (0x52db, &[0xfd, 0xb2], false),
+    (0x52f3, &[0xfd, 0xb3], false),
+    (0x5864, &[0xfd, 0xb4], false),
+    (0x58ce, &[0xfd, 0xb5], false),
+    (0x7104, &[0xfd, 0xb6], false),
+    (0x718f, &[0xfd, 0xb7], false),
+    (0x71fb, &[0xfd, 0xb8], false),
+    (0x85b0, &[0xfd, 0xb9], false),
+    (0x8a13, &[0xfd, 0xba], false),
+    (0x6688, &[0xfd, 0xbb], false),
+    (0x85a8, &[0xfd, 0xbc], false),
+    (0x55a7, &[0xfd, 0xbd], false),
+    (0x6684, &[0xfd, 0xbe], false),
+    (0x714a, &[0xfd, 0xbf], false),
+    (0x8431, &[0xfd, 0xc0], false),
+    (0x5349, &[0xfd, 0xc1], false),
+    (0x5599, &[0xfd, 0xc2], false),
+    (0x6bc1, &[0xfd, 0xc3], false),
+    (0x5f59, &[0xfd, 0xc4], false),
+    (0x5fbd, &[0xfd, 0xc5], false),
+    (0x63ee, &[0xfd, 0xc6], false),
+    (0x6689, &[0xfd, 0xc7], false),
+    (0x7147, &[0xfd, 0xc8], false),
+    (0x8af1, &[0xfd, 0xc9], false),
+    (0x8f1d, &[0xfd, 0xca], false),
+    (0x9ebe, &[0xfd, 0xcb], false),
+    (0x4f11, &[0xfd, 0xcc], false),
+    (0x643a, &[0xfd, 0xcd], false),
+    (0x70cb, &[0xfd, 0xce], false),
+    (0x7566, &[0xfd, 0xcf], false),
+    (0x8667, &[0xfd, 0xd0], false),
+    (0x6064, &[0xfd, 0xd1], false),
+    (0x8b4e, &[0xfd, 0xd2], false),
+    (0x9df8, &[0xfd, 0xd3], false),
+    (0x5147, &[0xfd, 0xd4], false),
+    (0x51f6, &[0xfd, 0xd5], false),
+    (0x5308, &[0xfd, 0xd6], false),
+    (0x6d36, &[0xfd, 0xd7], false),
+    (0x80f8, &[0xfd, 0xd8], false),
+    (0x9ed1, &[0xfd, 0xd9], false),
+    (0x6615, &[0xfd, 0xda], false),
+    (0x6b23, &[0xfd, 0xdb], false),
+    (0x7098, &[0xfd, 0xdc], false),
+    (0x75d5, &[0xfd, 0xdd], false),
+    (0x5403, &[0xfd, 0xde], false),
+    (0x5c79, &[0xfd, 0xdf], false),
+    (0x7d07, &[0xfd, 0xe0], false),
+    (0x8a16, &[0xfd, 0xe1], false),
+    (0x6b20, &[0xfd, 0xe2], false),
+    (0x6b3d, &[0xfd, 0xe3], false),
+    (0x6b46, &[0xfd, 0xe4], false),
+    (0x5438, &[0xfd, 0xe5], false),
+    (0x6070, &[0xfd, 0xe6], false),
+    (0x6d3d, &[0xfd, 0xe7], false),
+    (0x7fd5, &[0xfd, 0xe8], false),
+    (0x8208, &[0xfd, 0xe9], false),
+    (0x50d6, &[0xfd, 0xea], false),
+    (0x51de, &[0xfd, 0xeb], false),
+    (0x559c, &[0xfd, 0xec], false),
+    (0x566b, &[0xfd, 0xed], false),
+    (0x56cd, &[0xfd, 0xee], false),
+    (0x59ec, &[0xfd, 0xef], false),
+    (0x5b09, &[0xfd, 0xf0], false),
+    (0x5e0c, &[0xfd, 0xf1], false),
+    (0x6199, &[0xfd, 0xf2], false),
+    (0x6198, &[0xfd, 0xf3], false),
+    (0x6231, &[0xfd, 0xf4], false),
+    (0x665e, &[0xfd, 0xf5], false),
+    (0x66e6, &[0xfd, 0xf6], false),
+    (0x7199, &[0xfd, 0xf7], false),
+    (0x71b9, &[0xfd, 0xf8], false),
+    (0x71ba, &[0xfd, 0xf9], false),
+    (0x72a7, &[0xfd, 0xfa], false),
+    (0x79a7, &[0xfd, 0xfb], false),
+    (0x7a00, &[0xfd, 0xfc], false),
+    (0x7fb2, &[0xfd, 0xfd], false),
+    (0x8a70, &[0xfd, 0xfe], false),
+];
+
+#[test]
+fn decode() -> Result<(), ErrorTrace> {
+    for (expected_code_point, test_byte_string, _) in WINDOWS_949_TEST_VECTOR.iter() {
+        let mut decoder: DecoderWindows949 = DecoderWindows949::new(test_byte_string);
+
+        let test_code_point: u32 = match decoder.next() {
+            Some(Ok(code_point)) => code_point,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to decode Windows 949 as code_point: U+{:04x}",
+                    *expected_code_point as u32
+                )));
+            }
+        };
+        assert_eq!(test_code_point, *expected_code_point);
+    }
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<(), ErrorTrace> {
+    for (test_code_point, expected_byte_string, is_duplicate) in WINDOWS_949_TEST_VECTOR.iter() {
+        let code_points: [u32; 1] = [*test_code_point];
+        let mut encoder: EncoderWindows949 = EncoderWindows949::new(&code_points);
+
+        let test_byte_string: Vec<u8> = match encoder.next() {
+            Some(Ok(byte_string)) => byte_string,
+            Some(Err(error)) => return Err(error),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Failed to encode code point: U+{:04x} as Windows 949",
+                    *test_code_point as u32
+                )));
+            }
+        };
+        if !is_duplicate {
+            assert_eq!(test_byte_string, *expected_byte_string);
+        }
+    }
+    Ok(())
+}
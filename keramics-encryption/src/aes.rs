@@ -0,0 +1,512 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Advanced Encryption Standard (AES).
+//!
+//! Provides support for AES encryption and decryption (FIPS 197) with the
+//! Electronic Codebook (ECB) and Cipher Block Chaining (CBC) modes.
+
+use keramics_core::ErrorTrace;
+use keramics_types::bytes_to_u32_be;
+
+/// AES block size.
+pub const AES_BLOCK_SIZE: usize = 16;
+
+/// AES substitution box.
+const AES_SUBSTITUTION_BOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// AES inverse substitution box.
+const AES_INVERSE_SUBSTITUTION_BOX: [u8; 256] = get_inverse_substitution_box();
+
+/// AES round constants.
+const AES_ROUND_CONSTANTS: [u32; 10] = [
+    0x01000000, 0x02000000, 0x04000000, 0x08000000, 0x10000000, 0x20000000, 0x40000000, 0x80000000,
+    0x1b000000, 0x36000000,
+];
+
+/// AES forward table.
+const AES_FORWARD_TABLE: [u32; 256] = get_forward_table();
+
+/// AES reverse table.
+const AES_REVERSE_TABLE: [u32; 256] = get_reverse_table();
+
+/// Multiplies two values in the Galois field GF(2^8).
+const fn galois_multiply(mut value1: u8, mut value2: u8) -> u8 {
+    let mut result: u8 = 0;
+
+    while value2 != 0 {
+        if value2 & 0x01 != 0 {
+            result ^= value1;
+        }
+        let carry: u8 = value1 & 0x80;
+
+        value1 <<= 1;
+        if carry != 0 {
+            value1 ^= 0x1b;
+        }
+        value2 >>= 1;
+    }
+    result
+}
+
+/// Generates the inverse substitution box.
+const fn get_inverse_substitution_box() -> [u8; 256] {
+    let mut table: [u8; 256] = [0; 256];
+    let mut table_index: usize = 0;
+
+    while table_index < 256 {
+        table[AES_SUBSTITUTION_BOX[table_index] as usize] = table_index as u8;
+        table_index += 1;
+    }
+    table
+}
+
+/// Generates the forward table, which combines the SubBytes and MixColumns steps.
+const fn get_forward_table() -> [u32; 256] {
+    let mut table: [u32; 256] = [0; 256];
+    let mut table_index: usize = 0;
+
+    while table_index < 256 {
+        let value: u8 = AES_SUBSTITUTION_BOX[table_index];
+
+        table[table_index] = ((galois_multiply(value, 2) as u32) << 24)
+            | ((value as u32) << 16)
+            | ((value as u32) << 8)
+            | (galois_multiply(value, 3) as u32);
+        table_index += 1;
+    }
+    table
+}
+
+/// Generates the reverse table, which combines the InvSubBytes and InvMixColumns steps.
+const fn get_reverse_table() -> [u32; 256] {
+    let mut table: [u32; 256] = [0; 256];
+    let mut table_index: usize = 0;
+
+    while table_index < 256 {
+        let value: u8 = AES_INVERSE_SUBSTITUTION_BOX[table_index];
+
+        table[table_index] = ((galois_multiply(value, 14) as u32) << 24)
+            | ((galois_multiply(value, 9) as u32) << 16)
+            | ((galois_multiply(value, 13) as u32) << 8)
+            | (galois_multiply(value, 11) as u32);
+        table_index += 1;
+    }
+    table
+}
+
+/// Applies the forward table to the 4 bytes of 32-bit values.
+#[inline(always)]
+fn forward_round(value0: u32, value1: u32, value2: u32, value3: u32) -> u32 {
+    AES_FORWARD_TABLE[(value0 >> 24) as usize]
+        ^ AES_FORWARD_TABLE[((value1 >> 16) & 0xff) as usize].rotate_right(8)
+        ^ AES_FORWARD_TABLE[((value2 >> 8) & 0xff) as usize].rotate_right(16)
+        ^ AES_FORWARD_TABLE[(value3 & 0xff) as usize].rotate_right(24)
+}
+
+/// Applies the reverse table to the 4 bytes of 32-bit values.
+#[inline(always)]
+fn reverse_round(value0: u32, value1: u32, value2: u32, value3: u32) -> u32 {
+    AES_REVERSE_TABLE[(value0 >> 24) as usize]
+        ^ AES_REVERSE_TABLE[((value1 >> 16) & 0xff) as usize].rotate_right(8)
+        ^ AES_REVERSE_TABLE[((value2 >> 8) & 0xff) as usize].rotate_right(16)
+        ^ AES_REVERSE_TABLE[(value3 & 0xff) as usize].rotate_right(24)
+}
+
+/// Applies a substitution box to the 4 bytes of 32-bit values.
+#[inline(always)]
+fn substitute(
+    substitution_box: &[u8; 256],
+    value0: u32,
+    value1: u32,
+    value2: u32,
+    value3: u32,
+) -> u32 {
+    ((substitution_box[(value0 >> 24) as usize] as u32) << 24)
+        | ((substitution_box[((value1 >> 16) & 0xff) as usize] as u32) << 16)
+        | ((substitution_box[((value2 >> 8) & 0xff) as usize] as u32) << 8)
+        | (substitution_box[(value3 & 0xff) as usize] as u32)
+}
+
+/// Context for AES encryption and decryption.
+pub struct AesContext {
+    /// Number of rounds.
+    number_of_rounds: usize,
+
+    /// Encryption round keys.
+    encryption_round_keys: [u32; 60],
+
+    /// Decryption round keys.
+    decryption_round_keys: [u32; 60],
+}
+
+impl AesContext {
+    /// Creates a new context.
+    pub fn new(key: &[u8]) -> Result<Self, ErrorTrace> {
+        let number_of_rounds: usize = match key.len() {
+            16 => 10,
+            24 => 12,
+            32 => 14,
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported key size: {}",
+                    key.len()
+                )));
+            }
+        };
+        let mut context: Self = Self {
+            number_of_rounds: number_of_rounds,
+            encryption_round_keys: [0; 60],
+            decryption_round_keys: [0; 60],
+        };
+        context.expand_key(key);
+
+        Ok(context)
+    }
+
+    /// Expands the key into the encryption and decryption round keys.
+    fn expand_key(&mut self, key: &[u8]) {
+        let number_of_key_values: usize = key.len() / 4;
+        let number_of_round_key_values: usize = (self.number_of_rounds + 1) * 4;
+
+        for value_index in 0..number_of_key_values {
+            self.encryption_round_keys[value_index] = bytes_to_u32_be!(key, value_index * 4);
+        }
+        for value_index in number_of_key_values..number_of_round_key_values {
+            let mut value: u32 = self.encryption_round_keys[value_index - 1];
+
+            if value_index % number_of_key_values == 0 {
+                value = value.rotate_left(8);
+                value = substitute(&AES_SUBSTITUTION_BOX, value, value, value, value)
+                    ^ AES_ROUND_CONSTANTS[(value_index / number_of_key_values) - 1];
+            } else if number_of_key_values > 6 && value_index % number_of_key_values == 4 {
+                value = substitute(&AES_SUBSTITUTION_BOX, value, value, value, value);
+            }
+            self.encryption_round_keys[value_index] =
+                self.encryption_round_keys[value_index - number_of_key_values] ^ value;
+        }
+        // The decryption round keys are the encryption round keys in reverse order
+        // with the InvMixColumns step applied to all but the first and last round.
+        for round_index in 0..=self.number_of_rounds {
+            let encryption_offset: usize = (self.number_of_rounds - round_index) * 4;
+            let decryption_offset: usize = round_index * 4;
+
+            for value_index in 0..4 {
+                let mut value: u32 = self.encryption_round_keys[encryption_offset + value_index];
+
+                if round_index > 0 && round_index < self.number_of_rounds {
+                    let substituted_value: u32 =
+                        substitute(&AES_SUBSTITUTION_BOX, value, value, value, value);
+                    value = reverse_round(
+                        substituted_value,
+                        substituted_value,
+                        substituted_value,
+                        substituted_value,
+                    );
+                }
+                self.decryption_round_keys[decryption_offset + value_index] = value;
+            }
+        }
+    }
+
+    /// Encrypts a single block of data in-place.
+    pub fn encrypt_block(&self, data: &mut [u8]) {
+        let round_keys: &[u32; 60] = &self.encryption_round_keys;
+
+        let mut value0: u32 = bytes_to_u32_be!(data, 0) ^ round_keys[0];
+        let mut value1: u32 = bytes_to_u32_be!(data, 4) ^ round_keys[1];
+        let mut value2: u32 = bytes_to_u32_be!(data, 8) ^ round_keys[2];
+        let mut value3: u32 = bytes_to_u32_be!(data, 12) ^ round_keys[3];
+
+        for round_index in 1..self.number_of_rounds {
+            let round_key_offset: usize = round_index * 4;
+
+            let result0: u32 =
+                forward_round(value0, value1, value2, value3) ^ round_keys[round_key_offset];
+            let result1: u32 =
+                forward_round(value1, value2, value3, value0) ^ round_keys[round_key_offset + 1];
+            let result2: u32 =
+                forward_round(value2, value3, value0, value1) ^ round_keys[round_key_offset + 2];
+            let result3: u32 =
+                forward_round(value3, value0, value1, value2) ^ round_keys[round_key_offset + 3];
+
+            value0 = result0;
+            value1 = result1;
+            value2 = result2;
+            value3 = result3;
+        }
+        let round_key_offset: usize = self.number_of_rounds * 4;
+
+        let result0: u32 = substitute(&AES_SUBSTITUTION_BOX, value0, value1, value2, value3)
+            ^ round_keys[round_key_offset];
+        let result1: u32 = substitute(&AES_SUBSTITUTION_BOX, value1, value2, value3, value0)
+            ^ round_keys[round_key_offset + 1];
+        let result2: u32 = substitute(&AES_SUBSTITUTION_BOX, value2, value3, value0, value1)
+            ^ round_keys[round_key_offset + 2];
+        let result3: u32 = substitute(&AES_SUBSTITUTION_BOX, value3, value0, value1, value2)
+            ^ round_keys[round_key_offset + 3];
+
+        data[0..4].copy_from_slice(&result0.to_be_bytes());
+        data[4..8].copy_from_slice(&result1.to_be_bytes());
+        data[8..12].copy_from_slice(&result2.to_be_bytes());
+        data[12..16].copy_from_slice(&result3.to_be_bytes());
+    }
+
+    /// Decrypts a single block of data in-place.
+    pub fn decrypt_block(&self, data: &mut [u8]) {
+        let round_keys: &[u32; 60] = &self.decryption_round_keys;
+
+        let mut value0: u32 = bytes_to_u32_be!(data, 0) ^ round_keys[0];
+        let mut value1: u32 = bytes_to_u32_be!(data, 4) ^ round_keys[1];
+        let mut value2: u32 = bytes_to_u32_be!(data, 8) ^ round_keys[2];
+        let mut value3: u32 = bytes_to_u32_be!(data, 12) ^ round_keys[3];
+
+        for round_index in 1..self.number_of_rounds {
+            let round_key_offset: usize = round_index * 4;
+
+            let result0: u32 =
+                reverse_round(value0, value3, value2, value1) ^ round_keys[round_key_offset];
+            let result1: u32 =
+                reverse_round(value1, value0, value3, value2) ^ round_keys[round_key_offset + 1];
+            let result2: u32 =
+                reverse_round(value2, value1, value0, value3) ^ round_keys[round_key_offset + 2];
+            let result3: u32 =
+                reverse_round(value3, value2, value1, value0) ^ round_keys[round_key_offset + 3];
+
+            value0 = result0;
+            value1 = result1;
+            value2 = result2;
+            value3 = result3;
+        }
+        let round_key_offset: usize = self.number_of_rounds * 4;
+
+        let result0: u32 = substitute(
+            &AES_INVERSE_SUBSTITUTION_BOX,
+            value0,
+            value3,
+            value2,
+            value1,
+        ) ^ round_keys[round_key_offset];
+        let result1: u32 = substitute(
+            &AES_INVERSE_SUBSTITUTION_BOX,
+            value1,
+            value0,
+            value3,
+            value2,
+        ) ^ round_keys[round_key_offset + 1];
+        let result2: u32 = substitute(
+            &AES_INVERSE_SUBSTITUTION_BOX,
+            value2,
+            value1,
+            value0,
+            value3,
+        ) ^ round_keys[round_key_offset + 2];
+        let result3: u32 = substitute(
+            &AES_INVERSE_SUBSTITUTION_BOX,
+            value3,
+            value2,
+            value1,
+            value0,
+        ) ^ round_keys[round_key_offset + 3];
+
+        data[0..4].copy_from_slice(&result0.to_be_bytes());
+        data[4..8].copy_from_slice(&result1.to_be_bytes());
+        data[8..12].copy_from_slice(&result2.to_be_bytes());
+        data[12..16].copy_from_slice(&result3.to_be_bytes());
+    }
+
+    /// Encrypts data in-place using Cipher Block Chaining (CBC) mode.
+    pub fn encrypt_cbc(
+        &self,
+        initialization_vector: &[u8],
+        data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        if initialization_vector.len() != AES_BLOCK_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported initialization vector size"
+            ));
+        }
+        if data.len() % AES_BLOCK_SIZE != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported data size value not a multiple of the block size"
+            ));
+        }
+        let mut previous_block: [u8; AES_BLOCK_SIZE] = [0; AES_BLOCK_SIZE];
+        previous_block.copy_from_slice(initialization_vector);
+
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            for byte_index in 0..AES_BLOCK_SIZE {
+                block[byte_index] ^= previous_block[byte_index];
+            }
+            self.encrypt_block(block);
+
+            previous_block.copy_from_slice(block);
+        }
+        Ok(())
+    }
+
+    /// Decrypts data in-place using Cipher Block Chaining (CBC) mode.
+    pub fn decrypt_cbc(
+        &self,
+        initialization_vector: &[u8],
+        data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        if initialization_vector.len() != AES_BLOCK_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported initialization vector size"
+            ));
+        }
+        if data.len() % AES_BLOCK_SIZE != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported data size value not a multiple of the block size"
+            ));
+        }
+        let mut previous_block: [u8; AES_BLOCK_SIZE] = [0; AES_BLOCK_SIZE];
+        previous_block.copy_from_slice(initialization_vector);
+
+        let mut encrypted_block: [u8; AES_BLOCK_SIZE] = [0; AES_BLOCK_SIZE];
+
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            encrypted_block.copy_from_slice(block);
+
+            self.decrypt_block(block);
+
+            for byte_index in 0..AES_BLOCK_SIZE {
+                block[byte_index] ^= previous_block[byte_index];
+            }
+            previous_block.copy_from_slice(&encrypted_block);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let result = AesContext::new(&[0; 16]);
+        assert!(result.is_ok());
+
+        let result = AesContext::new(&[0; 24]);
+        assert!(result.is_ok());
+
+        let result = AesContext::new(&[0; 32]);
+        assert!(result.is_ok());
+
+        let result = AesContext::new(&[0; 20]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encrypt_block() -> Result<(), ErrorTrace> {
+        // Test vectors from FIPS 197 appendix C.
+        let plaintext: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        let key: Vec<u8> = (0..32).collect();
+
+        let test_context: AesContext = AesContext::new(&key[0..16])?;
+        let mut data: [u8; 16] = plaintext;
+        test_context.encrypt_block(&mut data);
+
+        let expected_data: [u8; 16] = [
+            0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4,
+            0xc5, 0x5a,
+        ];
+        assert_eq!(data, expected_data);
+
+        let test_context: AesContext = AesContext::new(&key[0..24])?;
+        let mut data: [u8; 16] = plaintext;
+        test_context.encrypt_block(&mut data);
+
+        let expected_data: [u8; 16] = [
+            0xdd, 0xa9, 0x7c, 0xa4, 0x86, 0x4c, 0xdf, 0xe0, 0x6e, 0xaf, 0x70, 0xa0, 0xec, 0x0d,
+            0x71, 0x91,
+        ];
+        assert_eq!(data, expected_data);
+
+        let test_context: AesContext = AesContext::new(&key[0..32])?;
+        let mut data: [u8; 16] = plaintext;
+        test_context.encrypt_block(&mut data);
+
+        let expected_data: [u8; 16] = [
+            0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
+            0x60, 0x89,
+        ];
+        assert_eq!(data, expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_block() -> Result<(), ErrorTrace> {
+        let key: Vec<u8> = (0..32).collect();
+
+        let test_context: AesContext = AesContext::new(&key[0..32])?;
+        let mut data: [u8; 16] = [
+            0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
+            0x60, 0x89,
+        ];
+        test_context.decrypt_block(&mut data);
+
+        let expected_data: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        assert_eq!(data, expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_cbc() -> Result<(), ErrorTrace> {
+        let key: Vec<u8> = (0..16).collect();
+        let initialization_vector: [u8; 16] = [0x5a; 16];
+
+        let test_context: AesContext = AesContext::new(&key)?;
+
+        let expected_data: Vec<u8> = b"Keramics test data ".repeat(4)[0..64].to_vec();
+        let mut data: Vec<u8> = expected_data.clone();
+
+        test_context.encrypt_cbc(&initialization_vector, &mut data)?;
+        assert_ne!(data, expected_data);
+
+        test_context.decrypt_cbc(&initialization_vector, &mut data)?;
+        assert_eq!(data, expected_data);
+
+        let result = test_context.decrypt_cbc(&initialization_vector, &mut data[0..15]);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Password-Based Key Derivation Function 2 (PBKDF2).
+//!
+//! Provides support for deriving a key with PBKDF2 using HMAC (RFC 8018, RFC 2104).
+
+use keramics_core::ErrorTrace;
+use keramics_hashes::{DigestHashContext, DigestHashMethod, new_digest_hash_context};
+
+/// Context for calculating a Hash-based Message Authentication Code (HMAC).
+struct HmacContext {
+    /// Digest hash method.
+    digest_hash_method: DigestHashMethod,
+
+    /// Inner padded key.
+    inner_padded_key: Vec<u8>,
+
+    /// Outer padded key.
+    outer_padded_key: Vec<u8>,
+}
+
+impl HmacContext {
+    /// Creates a new context.
+    fn new(digest_hash_method: &DigestHashMethod, key: &[u8]) -> Self {
+        let block_size: usize = digest_hash_method.get_block_size();
+
+        let mut padded_key: Vec<u8> = if key.len() > block_size {
+            let mut hash_context: Box<dyn DigestHashContext> =
+                new_digest_hash_context(digest_hash_method);
+            hash_context.update(key);
+            hash_context.finalize()
+        } else {
+            key.to_vec()
+        };
+        padded_key.resize(block_size, 0);
+
+        Self {
+            digest_hash_method: digest_hash_method.clone(),
+            inner_padded_key: padded_key
+                .iter()
+                .map(|byte_value| byte_value ^ 0x36)
+                .collect(),
+            outer_padded_key: padded_key
+                .iter()
+                .map(|byte_value| byte_value ^ 0x5c)
+                .collect(),
+        }
+    }
+
+    /// Calculates the HMAC of the data.
+    fn calculate(&self, data: &[&[u8]]) -> Vec<u8> {
+        let mut hash_context: Box<dyn DigestHashContext> =
+            new_digest_hash_context(&self.digest_hash_method);
+        hash_context.update(&self.inner_padded_key);
+
+        for data_segment in data.iter() {
+            hash_context.update(data_segment);
+        }
+        let inner_hash: Vec<u8> = hash_context.finalize();
+
+        let mut hash_context: Box<dyn DigestHashContext> =
+            new_digest_hash_context(&self.digest_hash_method);
+        hash_context.update(&self.outer_padded_key);
+        hash_context.update(&inner_hash);
+        hash_context.finalize()
+    }
+}
+
+/// Derives a key using PBKDF2 with HMAC.
+pub fn pbkdf2_derive_key(
+    digest_hash_method: &DigestHashMethod,
+    password: &[u8],
+    salt: &[u8],
+    number_of_iterations: u32,
+    key: &mut [u8],
+) -> Result<(), ErrorTrace> {
+    if number_of_iterations == 0 {
+        return Err(keramics_core::error_trace_new!(
+            "Invalid number of iterations value zero"
+        ));
+    }
+    let hmac_context: HmacContext = HmacContext::new(digest_hash_method, password);
+    let hash_size: usize = digest_hash_method.get_hash_size();
+
+    for (block_index, key_block) in key.chunks_mut(hash_size).enumerate() {
+        let block_number: [u8; 4] = ((block_index + 1) as u32).to_be_bytes();
+
+        let mut hash_value: Vec<u8> = hmac_context.calculate(&[salt, &block_number]);
+        let mut block_value: Vec<u8> = hash_value.clone();
+
+        for _ in 1..number_of_iterations {
+            hash_value = hmac_context.calculate(&[&hash_value]);
+
+            for (byte_index, byte_value) in hash_value.iter().enumerate() {
+                block_value[byte_index] ^= byte_value;
+            }
+        }
+        let block_size: usize = key_block.len();
+        key_block.copy_from_slice(&block_value[0..block_size]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_calculate() {
+        // Test case 2 from RFC 4231.
+        let hmac_context: HmacContext = HmacContext::new(&DigestHashMethod::Sha256, b"Jefe");
+        let hash_value: Vec<u8> = hmac_context.calculate(&[b"what do ya want ", b"for nothing?"]);
+
+        let expected_hash_value: [u8; 32] = [
+            0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+            0x75, 0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9,
+            0x64, 0xec, 0x38, 0x43,
+        ];
+        assert_eq!(hash_value, expected_hash_value);
+    }
+
+    #[test]
+    fn test_pbkdf2_derive_key() -> Result<(), ErrorTrace> {
+        // Test vector from RFC 6070.
+        let mut key: [u8; 20] = [0; 20];
+        pbkdf2_derive_key(&DigestHashMethod::Sha1, b"password", b"salt", 2, &mut key)?;
+
+        let expected_key: [u8; 20] = [
+            0xea, 0x6c, 0x01, 0x4d, 0xc7, 0x2d, 0x6f, 0x8c, 0xcd, 0x1e, 0xd9, 0x2a, 0xce, 0x1d,
+            0x41, 0xf0, 0xd8, 0xde, 0x89, 0x57,
+        ];
+        assert_eq!(key, expected_key);
+
+        let mut key: [u8; 25] = [0; 25];
+        pbkdf2_derive_key(
+            &DigestHashMethod::Sha1,
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            &mut key,
+        )?;
+        let expected_key: [u8; 25] = [
+            0x3d, 0x2e, 0xec, 0x4f, 0xe4, 0x1c, 0x84, 0x9b, 0x80, 0xc8, 0xd8, 0x36, 0x62, 0xc0,
+            0xe4, 0x4a, 0x8b, 0x29, 0x1a, 0x96, 0x4c, 0xf2, 0xf0, 0x70, 0x38,
+        ];
+        assert_eq!(key, expected_key);
+
+        let result = pbkdf2_derive_key(&DigestHashMethod::Sha1, b"password", b"salt", 0, &mut key);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! XEX-based tweaked-codebook mode with ciphertext stealing (XTS).
+//!
+//! Provides support for AES-XTS encryption and decryption (IEEE 1619) of data
+//! that is a multiple of the block size.
+
+use keramics_core::ErrorTrace;
+
+use super::aes::{AES_BLOCK_SIZE, AesContext};
+
+/// Context for AES-XTS encryption and decryption.
+pub struct AesXtsContext {
+    /// Data encryption context.
+    data_context: AesContext,
+
+    /// Tweak encryption context.
+    tweak_context: AesContext,
+}
+
+impl AesXtsContext {
+    /// Creates a new context.
+    ///
+    /// The key consists of the data encryption key followed by the tweak encryption key.
+    pub fn new(key: &[u8]) -> Result<Self, ErrorTrace> {
+        let key_size: usize = key.len();
+
+        if key_size != 32 && key_size != 48 && key_size != 64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported key size: {}",
+                key_size
+            )));
+        }
+        let half_key_size: usize = key_size / 2;
+
+        Ok(Self {
+            data_context: AesContext::new(&key[0..half_key_size])?,
+            tweak_context: AesContext::new(&key[half_key_size..])?,
+        })
+    }
+
+    /// Multiplies the tweak value by the primitive element in GF(2^128).
+    #[inline(always)]
+    fn multiply_tweak(tweak: &mut [u8; AES_BLOCK_SIZE]) {
+        let mut carry: u8 = 0;
+
+        for byte_index in 0..AES_BLOCK_SIZE {
+            let next_carry: u8 = tweak[byte_index] >> 7;
+
+            tweak[byte_index] = (tweak[byte_index] << 1) | carry;
+            carry = next_carry;
+        }
+        if carry != 0 {
+            tweak[0] ^= 0x87;
+        }
+    }
+
+    /// Processes data in-place.
+    fn process_data(
+        &self,
+        tweak_value: &[u8],
+        data: &mut [u8],
+        encrypt: bool,
+    ) -> Result<(), ErrorTrace> {
+        if tweak_value.len() != AES_BLOCK_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported tweak value size"
+            ));
+        }
+        if data.len() % AES_BLOCK_SIZE != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported data size value not a multiple of the block size"
+            ));
+        }
+        let mut tweak: [u8; AES_BLOCK_SIZE] = [0; AES_BLOCK_SIZE];
+        tweak.copy_from_slice(tweak_value);
+
+        self.tweak_context.encrypt_block(&mut tweak);
+
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            for byte_index in 0..AES_BLOCK_SIZE {
+                block[byte_index] ^= tweak[byte_index];
+            }
+            if encrypt {
+                self.data_context.encrypt_block(block);
+            } else {
+                self.data_context.decrypt_block(block);
+            }
+            for byte_index in 0..AES_BLOCK_SIZE {
+                block[byte_index] ^= tweak[byte_index];
+            }
+            Self::multiply_tweak(&mut tweak);
+        }
+        Ok(())
+    }
+
+    /// Encrypts data in-place.
+    pub fn encrypt(&self, tweak_value: &[u8], data: &mut [u8]) -> Result<(), ErrorTrace> {
+        self.process_data(tweak_value, data, true)
+    }
+
+    /// Decrypts data in-place.
+    pub fn decrypt(&self, tweak_value: &[u8], data: &mut [u8]) -> Result<(), ErrorTrace> {
+        self.process_data(tweak_value, data, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let result = AesXtsContext::new(&[0; 32]);
+        assert!(result.is_ok());
+
+        let result = AesXtsContext::new(&[0; 64]);
+        assert!(result.is_ok());
+
+        let result = AesXtsContext::new(&[0; 16]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encrypt() -> Result<(), ErrorTrace> {
+        // Test vector 1 from IEEE 1619 appendix B.
+        let test_context: AesXtsContext = AesXtsContext::new(&[0; 32])?;
+
+        let mut data: [u8; 32] = [0; 32];
+        test_context.encrypt(&[0; 16], &mut data)?;
+
+        let expected_data: [u8; 32] = [
+            0x91, 0x7c, 0xf6, 0x9e, 0xbd, 0x68, 0xb2, 0xec, 0x9b, 0x9f, 0xe9, 0xa3, 0xea, 0xdd,
+            0xa6, 0x92, 0xcd, 0x43, 0xd2, 0xf5, 0x95, 0x98, 0xed, 0x85, 0x8c, 0x02, 0xc2, 0x65,
+            0x2f, 0xbf, 0x92, 0x2e,
+        ];
+        assert_eq!(data, expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt() -> Result<(), ErrorTrace> {
+        let test_context: AesXtsContext = AesXtsContext::new(&[0; 32])?;
+
+        let mut data: [u8; 32] = [
+            0x91, 0x7c, 0xf6, 0x9e, 0xbd, 0x68, 0xb2, 0xec, 0x9b, 0x9f, 0xe9, 0xa3, 0xea, 0xdd,
+            0xa6, 0x92, 0xcd, 0x43, 0xd2, 0xf5, 0x95, 0x98, 0xed, 0x85, 0x8c, 0x02, 0xc2, 0x65,
+            0x2f, 0xbf, 0x92, 0x2e,
+        ];
+        test_context.decrypt(&[0; 16], &mut data)?;
+        assert_eq!(data, [0; 32]);
+
+        let result = test_context.decrypt(&[0; 16], &mut data[0..17]);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@ pub enum FormatIdentifier {
     Ewf,
     Fat,
     Gpt,
+    Gzip,
     Mbr,
     Ntfs,
     Qcow,
@@ -27,6 +28,7 @@ pub enum FormatIdentifier {
     Unknown,
     Vhd,
     Vhdx,
+    Vss,
 }
 
 impl FormatIdentifier {
@@ -38,6 +40,7 @@ impl FormatIdentifier {
             FormatIdentifier::Ewf => "ewf",
             FormatIdentifier::Fat => "fat",
             FormatIdentifier::Gpt => "gpt",
+            FormatIdentifier::Gzip => "gzip",
             FormatIdentifier::Mbr => "mbr",
             FormatIdentifier::Ntfs => "ntfs",
             FormatIdentifier::Qcow => "qcow",
@@ -46,6 +49,7 @@ impl FormatIdentifier {
             FormatIdentifier::Unknown => "unknown",
             FormatIdentifier::Vhd => "vhd",
             FormatIdentifier::Vhdx => "vhdx",
+            FormatIdentifier::Vss => "vss",
         }
     }
 }
@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 use std::io::SeekFrom;
 
-use keramics_compression::ZlibContext;
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStreamReference, ErrorTrace};
 
@@ -48,9 +48,9 @@ impl EwfHeader {
         // as the compressed header.
         let mut header_data: Vec<u8> = vec![0; data_size * 4];
 
-        let mut zlib_context: ZlibContext = ZlibContext::new();
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&CompressionMethod::Zlib);
 
-        match zlib_context.decompress(data, &mut header_data) {
+        match decompressor.decompress(data, &mut header_data) {
             Ok(_) => {}
             Err(mut error) => {
                 keramics_core::error_trace_add_frame!(error, "Unable to decompress header data");
@@ -60,7 +60,7 @@ impl EwfHeader {
         if self.mediator.debug_output {
             self.mediator.debug_print(format!(
                 "Uncompressed header data of size: {}\n",
-                zlib_context.uncompressed_data_size,
+                decompressor.get_uncompressed_data_size(),
             ));
             self.mediator.debug_print_data(&header_data, true);
         }
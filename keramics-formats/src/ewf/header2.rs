@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 use std::io::SeekFrom;
 
-use keramics_compression::ZlibContext;
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{ByteOrder, DataStreamReference, ErrorTrace};
 use keramics_datetime::PosixTime32;
@@ -50,9 +50,9 @@ impl EwfHeader2 {
         // should be a multitude of 2 bytes.
         let mut header2_data: Vec<u8> = vec![0; data_size * 4];
 
-        let mut zlib_context: ZlibContext = ZlibContext::new();
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&CompressionMethod::Zlib);
 
-        match zlib_context.decompress(data, &mut header2_data) {
+        match decompressor.decompress(data, &mut header2_data) {
             Ok(_) => {}
             Err(mut error) => {
                 keramics_core::error_trace_add_frame!(error, "Unable to decompress header2 data");
@@ -62,7 +62,7 @@ impl EwfHeader2 {
         if self.mediator.debug_output {
             self.mediator.debug_print(format!(
                 "Uncompressed header2 data of size: {}\n",
-                zlib_context.uncompressed_data_size,
+                decompressor.get_uncompressed_data_size(),
             ));
             self.mediator.debug_print_data(&header2_data, true);
         }
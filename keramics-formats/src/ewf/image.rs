@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 use std::io::SeekFrom;
 
-use keramics_compression::ZlibContext;
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
 use keramics_types::Uuid;
@@ -286,9 +286,10 @@ impl EwfImage {
                         }
                         let mut block_data: Vec<u8> = vec![0; self.block_size as usize];
 
-                        let mut zlib_context: ZlibContext = ZlibContext::new();
+                        let mut decompressor: Box<dyn Decompressor> =
+                            new_decompressor(&CompressionMethod::Zlib);
 
-                        match zlib_context.decompress(&compressed_data, &mut block_data) {
+                        match decompressor.decompress(&compressed_data, &mut block_data) {
                             Ok(_) => {}
                             Err(mut error) => {
                                 keramics_core::error_trace_add_frame!(
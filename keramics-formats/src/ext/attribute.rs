@@ -11,16 +11,41 @@
  * under the License.
  */
 
+use keramics_types::ByteString;
+
 /// Extended File System (ext) attribute.
-pub struct ExtAttribute {}
+pub struct ExtAttribute {
+    /// Name, including the namespace prefix.
+    name: ByteString,
+
+    /// Value data.
+    pub(super) value_data: Vec<u8>,
+
+    /// Value data inode number, where 0 represents the value data is not stored in an inode.
+    pub(super) value_data_inode_number: u32,
+
+    /// Value data size.
+    pub(super) value_data_size: u32,
+}
 
 impl ExtAttribute {
     /// Creates a new attribute.
-    pub(super) fn new() -> Self {
-        Self {}
+    pub(super) fn new(name: ByteString) -> Self {
+        Self {
+            name: name,
+            value_data: Vec::new(),
+            value_data_inode_number: 0,
+            value_data_size: 0,
+        }
+    }
+
+    /// Retrieves the name.
+    pub fn get_name(&self) -> &ByteString {
+        &self.name
     }
 
-    // TODO: add method to retrieve name
-    // TODO: add methods to retrieve extents
-    // TODO: add methods to read and seek data
+    /// Retrieves the value data.
+    pub fn get_value_data(&self) -> &[u8] {
+        &self.value_data
+    }
 }
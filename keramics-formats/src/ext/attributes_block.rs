@@ -33,11 +33,16 @@ impl ExtAttributesBlock {
     }
 
     /// Reads the attributes block from a buffer.
+    ///
+    /// The value data offset of an entry is relative to value_data_base_offset, which is the
+    /// start of the block for an attributes block and the start of the first entry for
+    /// attributes stored in the inode.
     pub fn read_entries(
         &self,
         data: &[u8],
         data_offset: usize,
         data_size: usize,
+        value_data_base_offset: usize,
         entries: &mut Vec<ExtAttribute>,
     ) -> Result<(), ErrorTrace> {
         let mut entry_data_offset: usize = data_offset;
@@ -76,9 +81,6 @@ impl ExtAttributesBlock {
             }
             entry_data_offset = data_end_offset;
 
-            let attribute: ExtAttribute = ExtAttribute::new();
-            // TODO: fill attribute
-
             let name: ByteString = match entry.read_name(&data[entry_data_offset..]) {
                 Ok(name) => name,
                 Err(mut error) => {
@@ -110,8 +112,34 @@ impl ExtAttributesBlock {
                 );
             }
 
-            // TODO: read inline value data.
+            let mut attribute: ExtAttribute = ExtAttribute::new(name);
+
+            attribute.value_data_inode_number = entry.value_data_inode_number;
+            attribute.value_data_size = entry.value_data_size;
 
+            // Value data stored in an inode is read on demand by the file entry.
+            if entry.value_data_inode_number == 0 && entry.value_data_size > 0 {
+                let value_data_offset: usize =
+                    value_data_base_offset + (entry.value_data_offset as usize);
+                let value_data_end_offset: usize =
+                    value_data_offset + (entry.value_data_size as usize);
+
+                if value_data_end_offset > data_size {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid attribute value data offset: {} and size: {} value out of bounds",
+                        entry.value_data_offset, entry.value_data_size
+                    )));
+                }
+                if self.mediator.debug_output {
+                    self.mediator.debug_print(format!(
+                        "ExtAttributesBlock: value data of size: {} at offset: {} (0x{:08x})\n",
+                        entry.value_data_size, value_data_offset, value_data_offset
+                    ));
+                    self.mediator
+                        .debug_print_data(&data[value_data_offset..value_data_end_offset], true);
+                }
+                attribute.value_data = data[value_data_offset..value_data_end_offset].to_vec();
+            }
             entries.push(attribute);
 
             entry_data_offset += alignment_padding_size;
@@ -210,8 +238,24 @@ mod tests {
         let test_struct = ExtAttributesBlock::new();
 
         let mut entries: Vec<ExtAttribute> = Vec::new();
-        test_struct.read_entries(&test_data, 32, 1024, &mut entries)?;
+        test_struct.read_entries(&test_data, 32, 1024, 0, &mut entries)?;
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].get_name().to_string(), "user.myxattr");
+        assert_eq!(entries[0].get_value_data(), b"My extended attribute");
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_data_with_invalid_value_data_size() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[40] = 0x20;
+
+        let test_struct = ExtAttributesBlock::new();
+
+        let mut entries: Vec<ExtAttribute> = Vec::new();
+        let result = test_struct.read_entries(&test_data, 32, 1024, 0, &mut entries);
+        assert!(result.is_err());
+    }
 }
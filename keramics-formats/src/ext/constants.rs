@@ -21,6 +21,7 @@ pub(super) const EXT_EXTENTS_HEADER_SIGNATURE: [u8; 2] = [0x0a, 0xf3];
 pub(super) const EXT_ATTRIBUTES_HEADER_SIGNATURE: [u8; 4] = [0x00, 0x00, 0x02, 0xea];
 
 /// ext compatible feature flags
+pub const EXT_COMPATIBLE_FEATURE_FLAG_HAS_JOURNAL: u32 = 0x00000004;
 pub const EXT_COMPATIBLE_FEATURE_FLAG_SPARSE_SUPERBLOCK2: u32 = 0x00000200;
 
 /// ext incompatible feature flags
@@ -51,3 +52,61 @@ pub const EXT_FILE_MODE_TYPE_SOCKET: u16 = 0xc000;
 
 /// ext root directory identifier (inode number).
 pub(super) const EXT_ROOT_DIRECTORY_IDENTIFIER: u32 = 2;
+
+/// ext journal (JBD2) block signature: "\xc0\x3b\x39\x98"
+pub(super) const EXT_JOURNAL_SIGNATURE: [u8; 4] = [0xc0, 0x3b, 0x39, 0x98];
+
+/// ext journal (JBD2) block types
+pub const EXT_JOURNAL_BLOCK_TYPE_DESCRIPTOR: u32 = 1;
+pub const EXT_JOURNAL_BLOCK_TYPE_COMMIT: u32 = 2;
+pub const EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V1: u32 = 3;
+pub const EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V2: u32 = 4;
+pub const EXT_JOURNAL_BLOCK_TYPE_REVOKE: u32 = 5;
+
+/// ext journal (JBD2) compatible feature flags
+pub const EXT_JOURNAL_COMPATIBLE_FEATURE_FLAG_CHECKSUM: u32 = 0x00000001;
+
+/// ext journal (JBD2) incompatible feature flags
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_REVOKE: u32 = 0x00000001;
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_64BIT: u32 = 0x00000002;
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_ASYNC_COMMIT: u32 = 0x00000004;
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V2: u32 = 0x00000008;
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V3: u32 = 0x00000010;
+pub const EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_FAST_COMMIT: u32 = 0x00000020;
+
+/// ext journal (JBD2) block tag flags
+pub const EXT_JOURNAL_BLOCK_TAG_FLAG_ESCAPED: u32 = 0x00000001;
+pub const EXT_JOURNAL_BLOCK_TAG_FLAG_SAME_IDENTIFIER: u32 = 0x00000002;
+pub const EXT_JOURNAL_BLOCK_TAG_FLAG_DELETED: u32 = 0x00000004;
+pub const EXT_JOURNAL_BLOCK_TAG_FLAG_LAST_TAG: u32 = 0x00000008;
+
+/// ext journal (JBD2) checksum types
+pub(super) const EXT_JOURNAL_CHECKSUM_TYPE_CRC32C: u8 = 4;
+
+/// ext journal (JBD2) block header size.
+pub(super) const EXT_JOURNAL_BLOCK_HEADER_SIZE: usize = 12;
+
+/// ext POSIX access control list (ACL) versions
+pub const EXT_POSIX_ACL_VERSION_EXT: u32 = 1;
+pub const EXT_POSIX_ACL_VERSION_XATTR: u32 = 2;
+
+/// ext POSIX access control list (ACL) entry tags
+pub const EXT_POSIX_ACL_TAG_USER_OBJECT: u16 = 0x0001;
+pub const EXT_POSIX_ACL_TAG_USER: u16 = 0x0002;
+pub const EXT_POSIX_ACL_TAG_GROUP_OBJECT: u16 = 0x0004;
+pub const EXT_POSIX_ACL_TAG_GROUP: u16 = 0x0008;
+pub const EXT_POSIX_ACL_TAG_MASK: u16 = 0x0010;
+pub const EXT_POSIX_ACL_TAG_OTHER: u16 = 0x0020;
+
+/// ext POSIX access control list (ACL) entry permissions
+pub const EXT_POSIX_ACL_PERMISSION_EXECUTE: u16 = 0x0001;
+pub const EXT_POSIX_ACL_PERMISSION_WRITE: u16 = 0x0002;
+pub const EXT_POSIX_ACL_PERMISSION_READ: u16 = 0x0004;
+
+/// ext file capabilities revisions
+pub const EXT_FILE_CAPABILITIES_REVISION_1: u32 = 0x01000000;
+pub const EXT_FILE_CAPABILITIES_REVISION_2: u32 = 0x02000000;
+pub const EXT_FILE_CAPABILITIES_REVISION_3: u32 = 0x03000000;
+
+/// ext file capabilities flags
+pub const EXT_FILE_CAPABILITIES_FLAG_EFFECTIVE: u32 = 0x00000001;
@@ -0,0 +1,168 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::bytes_to_u32_le;
+
+use super::constants::*;
+
+/// Extended File System (ext) file capabilities.
+///
+/// Stored in the security.capability extended attribute.
+pub struct ExtFileCapabilities {
+    /// Revision.
+    pub revision: u32,
+
+    /// Flags.
+    pub flags: u32,
+
+    /// Permitted capabilities.
+    pub permitted: u64,
+
+    /// Inheritable capabilities.
+    pub inheritable: u64,
+
+    /// Effective capabilities.
+    pub effective: u64,
+
+    /// Root user identifier (UID) of the user namespace, which is only stored in revision 3.
+    pub root_identifier: Option<u32>,
+}
+
+impl ExtFileCapabilities {
+    /// Creates new file capabilities.
+    pub fn new() -> Self {
+        Self {
+            revision: 0,
+            flags: 0,
+            permitted: 0,
+            inheritable: 0,
+            effective: 0,
+            root_identifier: None,
+        }
+    }
+
+    /// Reads the file capabilities from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 4 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext file capabilities data size"
+            ));
+        }
+        let magic_and_flags: u32 = bytes_to_u32_le!(data, 0);
+
+        self.revision = magic_and_flags & 0xff000000;
+        self.flags = magic_and_flags & 0x00ffffff;
+
+        let data_size: usize = match self.revision {
+            EXT_FILE_CAPABILITIES_REVISION_1 => 12,
+            EXT_FILE_CAPABILITIES_REVISION_2 => 20,
+            EXT_FILE_CAPABILITIES_REVISION_3 => 24,
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported ext file capabilities revision: 0x{:08x}",
+                    self.revision
+                )));
+            }
+        };
+        if data.len() < data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext file capabilities data size"
+            ));
+        }
+        self.permitted = bytes_to_u32_le!(data, 4) as u64;
+        self.inheritable = bytes_to_u32_le!(data, 8) as u64;
+
+        // Revision 2 and later store the upper 32 bits of the capabilities.
+        if data_size >= 20 {
+            self.permitted |= (bytes_to_u32_le!(data, 12) as u64) << 32;
+            self.inheritable |= (bytes_to_u32_le!(data, 16) as u64) << 32;
+        }
+        if data_size >= 24 {
+            self.root_identifier = Some(bytes_to_u32_le!(data, 20));
+        }
+        // The effective flag indicates all permitted capabilities are effective on execution.
+        self.effective = if self.flags & EXT_FILE_CAPABILITIES_FLAG_EFFECTIVE != 0 {
+            self.permitted
+        } else {
+            0
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x01, 0x00, 0x00, 0x02, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtFileCapabilities::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.revision, EXT_FILE_CAPABILITIES_REVISION_2);
+        assert_eq!(test_struct.flags, EXT_FILE_CAPABILITIES_FLAG_EFFECTIVE);
+        assert_eq!(test_struct.permitted, 0x0000000100002400);
+        assert_eq!(test_struct.inheritable, 0);
+        assert_eq!(test_struct.effective, 0x0000000100002400);
+        assert_eq!(test_struct.root_identifier, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_revision_3() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0x00;
+        test_data[3] = 0x03;
+        test_data.extend_from_slice(&[0xe8, 0x03, 0x00, 0x00]);
+
+        let mut test_struct = ExtFileCapabilities::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.revision, EXT_FILE_CAPABILITIES_REVISION_3);
+        assert_eq!(test_struct.permitted, 0x0000000100002400);
+        assert_eq!(test_struct.effective, 0);
+        assert_eq!(test_struct.root_identifier, Some(1000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtFileCapabilities::new();
+        let result = test_struct.read_data(&test_data[0..19]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_revision() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[3] = 0x04;
+
+        let mut test_struct = ExtFileCapabilities::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
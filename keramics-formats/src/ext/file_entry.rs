@@ -12,12 +12,16 @@
  */
 
 use std::cmp::max;
+use std::io::SeekFrom;
 use std::sync::{Arc, RwLock};
 
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace, FakeDataStream};
 use keramics_datetime::DateTime;
 use keramics_types::{ByteString, bytes_to_u16_le};
 
+use super::attribute::ExtAttribute;
+use super::attributes_block::ExtAttributesBlock;
+use super::attributes_block_header::ExtAttributesBlockHeader;
 use super::block_stream::ExtBlockStream;
 use super::constants::*;
 use super::directory_entries::ExtDirectoryEntries;
@@ -46,6 +50,9 @@ pub struct ExtFileEntry {
 
     /// Symbolic link target.
     symbolic_link_target: Option<ByteString>,
+
+    /// Value to indicate the attributes block and attribute values stored in inodes were read.
+    attributes_read: bool,
 }
 
 impl ExtFileEntry {
@@ -66,6 +73,7 @@ impl ExtFileEntry {
             name: name,
             sub_directory_entries: sub_directory_entries,
             symbolic_link_target: None,
+            attributes_read: false,
         }
     }
 
@@ -199,12 +207,188 @@ impl ExtFileEntry {
         Ok(self.symbolic_link_target.as_ref())
     }
 
+    /// Reads the attributes block and the attribute values stored in inodes.
+    fn read_attributes(&mut self) -> Result<(), ErrorTrace> {
+        if self.attributes_read {
+            return Ok(());
+        }
+        let block_size: u32 = self.inode_table.block_size;
+
+        // The attributes are read into a separate vector so that a failed read does not
+        // leave partially read attributes behind.
+        let mut attributes: Vec<ExtAttribute> = Vec::new();
+
+        if self.inode.attributes_block_number != 0 {
+            let block_offset: u64 = self.inode.attributes_block_number * (block_size as u64);
+            let mut data: Vec<u8> = vec![0; block_size as usize];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                self.data_stream,
+                &mut data,
+                SeekFrom::Start(block_offset)
+            );
+            let mut attributes_block_header: ExtAttributesBlockHeader =
+                ExtAttributesBlockHeader::new();
+
+            match attributes_block_header.read_data(&data[0..32]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to read attributes block header at offset: {} (0x{:08x})",
+                            block_offset, block_offset
+                        )
+                    );
+                    return Err(error);
+                }
+            }
+            let attributes_block: ExtAttributesBlock = ExtAttributesBlock::new();
+
+            match attributes_block.read_entries(&data, 32, block_size as usize, 0, &mut attributes)
+            {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read attributes block entries"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        let mut inode_value_data: Vec<(usize, Vec<u8>)> = Vec::new();
+
+        for (attribute_index, attribute) in self.inode.attributes.iter().enumerate() {
+            if attribute.value_data_inode_number == 0 {
+                continue;
+            }
+            match self.read_attribute_value_data(attribute) {
+                Ok(data) => inode_value_data.push((attribute_index, data)),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read attribute: {} value data", attribute_index)
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        for (attribute_index, attribute) in attributes.iter_mut().enumerate() {
+            if attribute.value_data_inode_number == 0 {
+                continue;
+            }
+            match self.read_attribute_value_data(attribute) {
+                Ok(data) => attribute.value_data = data,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to read attributes block attribute: {} value data",
+                            attribute_index
+                        )
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        for (attribute_index, data) in inode_value_data.into_iter() {
+            self.inode.attributes[attribute_index].value_data = data;
+        }
+        self.inode.attributes.extend(attributes);
+
+        self.attributes_read = true;
+
+        Ok(())
+    }
+
+    /// Reads attribute value data stored in an extended attribute inode.
+    fn read_attribute_value_data(&self, attribute: &ExtAttribute) -> Result<Vec<u8>, ErrorTrace> {
+        let block_size: u32 = self.inode_table.block_size;
+        let inode_number: u32 = attribute.value_data_inode_number;
+
+        let inode: ExtInode = match self.inode_table.get_inode(&self.data_stream, inode_number) {
+            Ok(inode) => inode,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to retrieve attribute value inode: {}", inode_number)
+                );
+                return Err(error);
+            }
+        };
+        if inode.flags & EXT_INODE_FLAG_IS_EXTENDED_ATTRIBUTE_INODE == 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute value inode: {} missing extended attribute inode flag",
+                inode_number
+            )));
+        }
+        if (attribute.value_data_size as u64) > inode.data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid attribute value data size: {} value exceeds inode data size",
+                attribute.value_data_size
+            )));
+        }
+        let number_of_blocks: u64 = max(
+            inode.data_size.div_ceil(block_size as u64),
+            inode.number_of_blocks,
+        );
+        let mut block_stream: ExtBlockStream = ExtBlockStream::new(block_size, inode.data_size);
+
+        match block_stream.open(&self.data_stream, number_of_blocks, &inode.block_ranges) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to open block stream");
+                return Err(error);
+            }
+        }
+        let mut data: Vec<u8> = vec![0; attribute.value_data_size as usize];
+
+        match block_stream.read_exact(&mut data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read attribute value data from block stream"
+                );
+                return Err(error);
+            }
+        }
+        Ok(data)
+    }
+
     /// Retrieves the number of attributes.
     pub fn get_number_of_attributes(&mut self) -> Result<usize, ErrorTrace> {
+        match self.read_attributes() {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read attributes");
+                return Err(error);
+            }
+        }
         Ok(self.inode.attributes.len())
     }
 
-    // TODO: add get extended_attributes iterator
+    /// Retrieves a specific attribute.
+    pub fn get_attribute_by_index(
+        &mut self,
+        attribute_index: usize,
+    ) -> Result<&ExtAttribute, ErrorTrace> {
+        match self.read_attributes() {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read attributes");
+                return Err(error);
+            }
+        }
+        match self.inode.attributes.get(attribute_index) {
+            Some(attribute) => Ok(attribute),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "Missing attribute: {}",
+                attribute_index
+            ))),
+        }
+    }
 
     /// Retrieves the default data stream.
     pub fn get_data_stream(&self) -> Result<Option<DataStreamReference>, ErrorTrace> {
@@ -428,6 +612,7 @@ mod tests {
 
     use crate::ext::file_system::ExtFileSystem;
     use crate::ext::path::ExtPath;
+    use crate::ext::posix_acl::ExtPosixAcl;
 
     use crate::tests::get_test_data_path;
 
@@ -660,6 +845,16 @@ mod tests {
         Ok(())
     }
 
+    fn get_xattr_file_system() -> Result<ExtFileSystem, ErrorTrace> {
+        let mut file_system: ExtFileSystem = ExtFileSystem::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("ext/ext4_xattr.raw").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+        file_system.read_data_stream(&data_stream)?;
+
+        Ok(file_system)
+    }
+
     #[test]
     fn test_get_number_of_attributes() -> Result<(), ErrorTrace> {
         let ext_file_system: ExtFileSystem = get_file_system()?;
@@ -671,7 +866,86 @@ mod tests {
         let number_of_attributes: usize = ext_file_entry.get_number_of_attributes()?;
         assert_eq!(number_of_attributes, 0);
 
-        // TODO: test with file entry with attributes
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_number_of_attributes_with_xattrs() -> Result<(), ErrorTrace> {
+        let ext_file_system: ExtFileSystem = get_xattr_file_system()?;
+
+        let ext_path: ExtPath = ExtPath::from("/xattr1");
+        let mut ext_file_entry: ExtFileEntry =
+            ext_file_system.get_file_entry_by_path(&ext_path)?.unwrap();
+
+        let number_of_attributes: usize = ext_file_entry.get_number_of_attributes()?;
+        assert_eq!(number_of_attributes, 4);
+
+        // Reading the attributes a second time should not add duplicate entries.
+        let number_of_attributes: usize = ext_file_entry.get_number_of_attributes()?;
+        assert_eq!(number_of_attributes, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_attribute_by_index() -> Result<(), ErrorTrace> {
+        let ext_file_system: ExtFileSystem = get_file_system()?;
+
+        let ext_path: ExtPath = ExtPath::from("/testdir1/testfile1");
+        let mut ext_file_entry: ExtFileEntry =
+            ext_file_system.get_file_entry_by_path(&ext_path)?.unwrap();
+
+        let result = ext_file_entry.get_attribute_by_index(0);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_attribute_by_index_with_xattrs() -> Result<(), ErrorTrace> {
+        let ext_file_system: ExtFileSystem = get_xattr_file_system()?;
+
+        let ext_path: ExtPath = ExtPath::from("/xattr1");
+        let mut ext_file_entry: ExtFileEntry =
+            ext_file_system.get_file_entry_by_path(&ext_path)?.unwrap();
+
+        let attribute: &ExtAttribute = ext_file_entry.get_attribute_by_index(0)?;
+        assert_eq!(attribute.get_name(), &ByteString::from("user.myxattr1"));
+        assert_eq!(attribute.get_value_data(), b"My 1st extended attribute");
+
+        let attribute: &ExtAttribute = ext_file_entry.get_attribute_by_index(1)?;
+        assert_eq!(
+            attribute.get_name(),
+            &ByteString::from("system.posix_acl_access")
+        );
+        assert_eq!(
+            attribute.get_value_data(),
+            [
+                0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x06, 0x00, 0x04, 0x00, 0x04, 0x00, 0x10, 0x00,
+                0x04, 0x00, 0x20, 0x00, 0x04, 0x00,
+            ]
+        );
+        let mut posix_acl: ExtPosixAcl = ExtPosixAcl::new();
+        posix_acl.read_data(attribute.get_value_data())?;
+
+        assert_eq!(posix_acl.entries.len(), 4);
+        assert_eq!(posix_acl.entries[0].tag, 0x0001);
+        assert_eq!(posix_acl.entries[0].permissions, 6);
+
+        // The value of this attribute is stored in an extended attribute inode.
+        let attribute: &ExtAttribute = ext_file_entry.get_attribute_by_index(2)?;
+        assert_eq!(attribute.get_name(), &ByteString::from("user.large"));
+        assert_eq!(attribute.get_value_data(), vec![b'A'; 1024]);
+
+        let attribute: &ExtAttribute = ext_file_entry.get_attribute_by_index(3)?;
+        assert_eq!(attribute.get_name(), &ByteString::from("security.selinux"));
+        assert_eq!(
+            attribute.get_value_data(),
+            b"unconfined_u:object_r:user_home_t:s0"
+        );
+
+        let result = ext_file_entry.get_attribute_by_index(4);
+        assert!(result.is_err());
 
         Ok(())
     }
@@ -28,6 +28,7 @@ use super::group_descriptor::ExtGroupDescriptor;
 use super::group_descriptor_table::ExtGroupDescriptorTable;
 use super::inode::ExtInode;
 use super::inode_table::ExtInodeTable;
+use super::journal::ExtJournal;
 use super::path::ExtPath;
 use super::superblock::ExtSuperblock;
 
@@ -57,6 +58,9 @@ pub struct ExtFileSystem {
     /// Metadata checksum seed.
     metadata_checksum_seed: u32,
 
+    /// Journal inode number.
+    journal_inode_number: u32,
+
     /// Volume label.
     volume_label: Option<ByteString>,
 
@@ -82,6 +86,7 @@ impl ExtFileSystem {
             inode_size: 0,
             inode_table: Arc::new(ExtInodeTable::new()),
             metadata_checksum_seed: 0,
+            journal_inode_number: 0,
             volume_label: None,
             last_mount_path: ByteString::new(),
             last_mount_time: DateTime::NotSet,
@@ -199,6 +204,55 @@ impl ExtFileSystem {
         Ok(Some(file_entry))
     }
 
+    /// Retrieves the journal.
+    ///
+    /// Returns None if the file system has no journal or uses an external journal device, which
+    /// can be read with ExtJournal::read_data_stream.
+    pub fn get_journal(&self) -> Result<Option<ExtJournal>, ErrorTrace> {
+        if self.journal_inode_number == 0 {
+            return Ok(None);
+        }
+        let file_entry: ExtFileEntry =
+            match self.get_file_entry_by_identifier(self.journal_inode_number) {
+                Ok(file_entry) => file_entry,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to retrieve journal file entry: {}",
+                            self.journal_inode_number
+                        )
+                    );
+                    return Err(error);
+                }
+            };
+        let data_stream: DataStreamReference = match file_entry.get_data_stream() {
+            Ok(Some(data_stream)) => data_stream,
+            Ok(None) => {
+                return Err(keramics_core::error_trace_new!(
+                    "Missing journal file entry data stream"
+                ));
+            }
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve journal file entry data stream"
+                );
+                return Err(error);
+            }
+        };
+        let mut journal: ExtJournal = ExtJournal::new();
+
+        match journal.read_data_stream(&data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read journal");
+                return Err(error);
+            }
+        }
+        Ok(Some(journal))
+    }
+
     /// Retrieves the root directory (file entry).
     pub fn get_root_directory(&self) -> Result<Option<ExtFileEntry>, ErrorTrace> {
         if self.number_of_inodes == 0 {
@@ -314,6 +368,13 @@ impl ExtFileSystem {
                     self.block_size = superblock.block_size;
                     self.inode_size = superblock.inode_size;
 
+                    if self.features.compatible_feature_flags
+                        & EXT_COMPATIBLE_FEATURE_FLAG_HAS_JOURNAL
+                        != 0
+                    {
+                        self.journal_inode_number = superblock.journal_inode_number;
+                    }
+
                     if !superblock.volume_label.is_empty() {
                         self.volume_label = Some(superblock.volume_label);
                     }
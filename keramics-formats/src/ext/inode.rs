@@ -82,6 +82,9 @@ pub struct ExtInode {
     /// Block ranges.
     pub block_ranges: Vec<ExtBlockRange>,
 
+    /// Attributes block number.
+    pub attributes_block_number: u64,
+
     /// Attributes.
     pub attributes: Vec<ExtAttribute>,
 }
@@ -108,6 +111,7 @@ impl ExtInode {
             checksum: 0,
             creation_time: None,
             block_ranges: Vec::new(),
+            attributes_block_number: 0,
             attributes: Vec::new(),
         }
     }
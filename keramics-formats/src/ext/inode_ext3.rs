@@ -94,6 +94,8 @@ impl Ext3Inode {
 
         inode.data_reference.copy_from_slice(&data[40..100]);
 
+        inode.attributes_block_number = bytes_to_u32_le!(data, 104) as u64;
+
         Ok(())
     }
 }
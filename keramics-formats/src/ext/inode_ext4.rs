@@ -102,6 +102,10 @@ impl Ext4Inode {
 
         inode.data_reference.copy_from_slice(&data[40..100]);
 
+        let lower_32bit: u32 = bytes_to_u32_le!(data, 104);
+        let upper_16bit: u16 = bytes_to_u16_le!(data, 118);
+        inode.attributes_block_number = ((upper_16bit as u64) << 32) | (lower_32bit as u64);
+
         let lower_16bit: u16 = bytes_to_u16_le!(data, 124);
         inode.checksum = lower_16bit as u32;
 
@@ -123,6 +123,7 @@ impl Ext4InodeExtension {
                     data,
                     data_offset,
                     data_size,
+                    data_offset,
                     &mut inode.attributes,
                 ) {
                     Ok(_) => {}
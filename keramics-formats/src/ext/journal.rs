@@ -0,0 +1,542 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_encodings::CharacterEncoding;
+
+use super::constants::*;
+use super::journal_block::ExtJournalBlock;
+use super::journal_block_header::ExtJournalBlockHeader;
+use super::journal_commit_block::ExtJournalCommitBlock;
+use super::journal_descriptor_block::ExtJournalDescriptorBlock;
+use super::journal_revoke_block::ExtJournalRevokeBlock;
+use super::journal_superblock::ExtJournalSuperblock;
+use super::journal_transaction::ExtJournalTransaction;
+use super::superblock::ExtSuperblock;
+
+/// Extended File System (ext) journal (JBD2).
+pub struct ExtJournal {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Superblock.
+    superblock: ExtJournalSuperblock,
+
+    /// Transactions.
+    transactions: Vec<ExtJournalTransaction>,
+}
+
+impl ExtJournal {
+    /// Creates a new journal.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            superblock: ExtJournalSuperblock::new(),
+            transactions: Vec::new(),
+        }
+    }
+
+    /// Retrieves the block size.
+    pub fn get_block_size(&self) -> u32 {
+        self.superblock.block_size
+    }
+
+    /// Retrieves the compatible feature flags.
+    pub fn get_compatible_feature_flags(&self) -> u32 {
+        self.superblock.compatible_feature_flags
+    }
+
+    /// Retrieves the incompatible feature flags.
+    pub fn get_incompatible_feature_flags(&self) -> u32 {
+        self.superblock.incompatible_feature_flags
+    }
+
+    /// Retrieves the read-only compatible feature flags.
+    pub fn get_read_only_compatible_feature_flags(&self) -> u32 {
+        self.superblock.read_only_compatible_feature_flags
+    }
+
+    /// Retrieves the journal identifier.
+    pub fn get_journal_identifier(&self) -> &[u8; 16] {
+        &self.superblock.journal_identifier
+    }
+
+    /// Retrieves the number of blocks.
+    pub fn get_number_of_blocks(&self) -> u32 {
+        self.superblock.number_of_blocks
+    }
+
+    /// Retrieves the number of transactions.
+    pub fn get_number_of_transactions(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Retrieves a specific transaction.
+    pub fn get_transaction_by_index(
+        &self,
+        transaction_index: usize,
+    ) -> Result<&ExtJournalTransaction, ErrorTrace> {
+        match self.transactions.get(transaction_index) {
+            Some(transaction) => Ok(transaction),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "Missing transaction: {}",
+                transaction_index
+            ))),
+        }
+    }
+
+    /// Determines if the journal is empty (clean), which means it contains no transactions that
+    /// need to be replayed. Transactions of a clean journal are remnants of earlier use.
+    pub fn is_clean(&self) -> bool {
+        self.superblock.start_block_number == 0
+    }
+
+    /// Reads the copy of a file system block stored in the journal.
+    pub fn read_block_data(
+        &self,
+        journal_block: &ExtJournalBlock,
+        data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        if data.len() != self.superblock.block_size as usize {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported data size: {} value does not match block size: {}",
+                data.len(),
+                self.superblock.block_size
+            )));
+        }
+        let block_offset: u64 =
+            journal_block.journal_block_number * (self.superblock.block_size as u64);
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            data,
+            SeekFrom::Start(block_offset)
+        );
+        // The first 4 bytes of a block that start with a journal block signature are set to 0
+        // when the block is stored in the journal.
+        if journal_block.is_escaped() {
+            data[0..4].copy_from_slice(&EXT_JOURNAL_SIGNATURE);
+        }
+        Ok(())
+    }
+
+    /// Reads a journal from a data stream.
+    ///
+    /// The data stream either contains the journal inode data or an external journal device.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        let superblock_offset: u64 = match self.get_superblock_offset(data_stream) {
+            Ok(offset) => offset,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to determine journal superblock offset"
+                );
+                return Err(error);
+            }
+        };
+        match self
+            .superblock
+            .read_at_position(data_stream, SeekFrom::Start(superblock_offset))
+        {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to read journal superblock at offset: {} (0x{:08x})",
+                        superblock_offset, superblock_offset
+                    )
+                );
+                return Err(error);
+            }
+        }
+        match self.read_transactions(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read transactions");
+                return Err(error);
+            }
+        }
+        self.data_stream = Some(data_stream.clone());
+
+        Ok(())
+    }
+
+    /// Determines the offset of the journal superblock.
+    fn get_superblock_offset(&self, data_stream: &DataStreamReference) -> Result<u64, ErrorTrace> {
+        let mut data: [u8; 4] = [0; 4];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            SeekFrom::Start(0)
+        );
+        if ExtJournalBlockHeader::has_signature(&data) {
+            return Ok(0);
+        }
+        // An external journal device starts with an ext superblock, which is followed by the
+        // journal superblock in the next block.
+        let mut superblock: ExtSuperblock = ExtSuperblock::new(&CharacterEncoding::Utf8);
+
+        match superblock.read_at_position(data_stream, SeekFrom::Start(1024)) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read superblock");
+                return Err(error);
+            }
+        }
+        if superblock.incompatible_feature_flags & EXT_INCOMPATIBLE_FEATURE_FLAG_JOURNAL_DEVICE == 0
+        {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext file system is not an external journal device"
+            ));
+        }
+        if superblock.block_size == 1024 {
+            Ok(2048)
+        } else {
+            Ok(superblock.block_size as u64)
+        }
+    }
+
+    /// Reads the transactions.
+    ///
+    /// All log blocks are scanned, instead of only the blocks from the start block number, to
+    /// also find remnants of transactions that were already checkpointed. Data blocks cannot be
+    /// mistaken for journal blocks since their journal block signature is escaped.
+    fn read_transactions(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        let block_size: u64 = self.superblock.block_size as u64;
+        let data_stream_size: u64 = keramics_core::data_stream_get_size!(data_stream);
+
+        let first_log_block_number: u64 = self.superblock.first_log_block_number as u64;
+        let last_log_block_number: u64 = self.superblock.get_last_log_block_number() as u64;
+        let number_of_log_blocks: u64 = last_log_block_number - first_log_block_number;
+
+        let mut data: Vec<u8> = vec![0; block_size as usize];
+        let mut transaction_indexes: HashMap<u32, usize> = HashMap::new();
+
+        let mut block_number: u64 = first_log_block_number;
+
+        while block_number < last_log_block_number {
+            let block_offset: u64 = block_number * block_size;
+
+            if block_offset + block_size > data_stream_size {
+                break;
+            }
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut data,
+                SeekFrom::Start(block_offset)
+            );
+            if !ExtJournalBlockHeader::has_signature(&data) {
+                block_number += 1;
+                continue;
+            }
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "ExtJournalBlockHeader data of size: {} at offset: {} (0x{:08x})\n",
+                    EXT_JOURNAL_BLOCK_HEADER_SIZE, block_offset, block_offset
+                ));
+                self.mediator
+                    .debug_print_data(&data[0..EXT_JOURNAL_BLOCK_HEADER_SIZE], true);
+                self.mediator
+                    .debug_print(ExtJournalBlockHeader::debug_read_data(
+                        &data[0..EXT_JOURNAL_BLOCK_HEADER_SIZE],
+                    ));
+            }
+            let mut block_header: ExtJournalBlockHeader = ExtJournalBlockHeader::new();
+
+            match block_header.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read journal block header: {}", block_number)
+                    );
+                    return Err(error);
+                }
+            }
+            let transaction_index: usize =
+                match transaction_indexes.get(&block_header.sequence_number) {
+                    Some(transaction_index) => *transaction_index,
+                    None => {
+                        let transaction_index: usize = self.transactions.len();
+
+                        self.transactions
+                            .push(ExtJournalTransaction::new(block_header.sequence_number));
+                        transaction_indexes.insert(block_header.sequence_number, transaction_index);
+
+                        transaction_index
+                    }
+                };
+            let transaction: &mut ExtJournalTransaction = &mut self.transactions[transaction_index];
+
+            match block_header.block_type {
+                EXT_JOURNAL_BLOCK_TYPE_DESCRIPTOR => {
+                    let mut descriptor_block: ExtJournalDescriptorBlock =
+                        ExtJournalDescriptorBlock::new();
+
+                    match descriptor_block.read_data(&data, &self.superblock) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!(
+                                    "Unable to read journal descriptor block: {}",
+                                    block_number
+                                )
+                            );
+                            return Err(error);
+                        }
+                    }
+                    if transaction.journal_block_number == 0 {
+                        transaction.journal_block_number = block_number;
+                    }
+                    // The data blocks directly follow the descriptor block and wrap around to
+                    // the first log block.
+                    let mut journal_block_number: u64 = block_number;
+
+                    for block_tag in descriptor_block.tags.iter() {
+                        journal_block_number += 1;
+
+                        if journal_block_number >= last_log_block_number {
+                            journal_block_number -= number_of_log_blocks;
+                        }
+                        transaction.blocks.push(ExtJournalBlock::new(
+                            block_tag.block_number,
+                            journal_block_number,
+                            block_tag.flags,
+                        ));
+                    }
+                }
+                EXT_JOURNAL_BLOCK_TYPE_COMMIT => {
+                    let mut commit_block: ExtJournalCommitBlock = ExtJournalCommitBlock::new();
+
+                    match commit_block.read_data(&data) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!("Unable to read journal commit block: {}", block_number)
+                            );
+                            return Err(error);
+                        }
+                    }
+                    transaction.is_committed = true;
+                    transaction.commit_time = commit_block.commit_time;
+                }
+                EXT_JOURNAL_BLOCK_TYPE_REVOKE => {
+                    let mut revoke_block: ExtJournalRevokeBlock = ExtJournalRevokeBlock::new();
+
+                    match revoke_block.read_data(&data, &self.superblock) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!("Unable to read journal revoke block: {}", block_number)
+                            );
+                            return Err(error);
+                        }
+                    }
+                    transaction
+                        .revoked_block_numbers
+                        .extend_from_slice(&revoke_block.block_numbers);
+                }
+                _ => {}
+            }
+            block_number += 1;
+        }
+        // TODO: handle sequence number wrap around.
+        self.transactions
+            .sort_by_key(|transaction| transaction.sequence_number);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+    use keramics_datetime::{DateTime, PosixTime64Ns};
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 16 * 1024];
+
+        // Journal superblock.
+        data[0..12].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        data[12..32].copy_from_slice(&[
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x08, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        data[40..44].copy_from_slice(&[0x00, 0x00, 0x00, 0x01]);
+
+        // Commit block of transaction 5.
+        data[12288..12300].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05,
+        ]);
+        data[12336..12348].copy_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, 0x5f, 0x5e, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        // Descriptor block of transaction 6 with 3 block tags.
+        data[13312..13324].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06,
+        ]);
+        data[13324..13332].copy_from_slice(&[0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00]);
+        data[13348..13356].copy_from_slice(&[0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x03]);
+        data[13356..13364].copy_from_slice(&[0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x0a]);
+
+        // Data blocks of transaction 6, where the second block is escaped and the third block
+        // wraps around to the first log block.
+        data[14336..15360].fill(0x11);
+        data[15360..16384].fill(0x22);
+        data[15360..15364].fill(0x00);
+        data[1024..2048].fill(0x33);
+
+        // Commit block of transaction 6.
+        data[2048..2060].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x06,
+        ]);
+        data[2096..2108].copy_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, 0x5f, 0x5e, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        // Descriptor block of transaction 7 with 1 block tag.
+        data[3072..3084].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07,
+        ]);
+        data[3084..3092].copy_from_slice(&[0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x08]);
+
+        // Revoke block of transaction 7.
+        data[5120..5144].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+            0x00, 0x14, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        data
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut journal = ExtJournal::new();
+        journal.read_data_stream(&data_stream)?;
+
+        assert_eq!(journal.get_block_size(), 1024);
+        assert_eq!(journal.get_number_of_blocks(), 16);
+        assert_eq!(journal.is_clean(), true);
+        assert_eq!(journal.get_number_of_transactions(), 3);
+
+        let transaction: &ExtJournalTransaction = journal.get_transaction_by_index(0)?;
+        assert_eq!(transaction.sequence_number, 5);
+        assert_eq!(transaction.is_committed, true);
+        assert_eq!(transaction.blocks.len(), 0);
+
+        let transaction: &ExtJournalTransaction = journal.get_transaction_by_index(1)?;
+        assert_eq!(transaction.sequence_number, 6);
+        assert_eq!(transaction.journal_block_number, 13);
+        assert_eq!(transaction.is_committed, true);
+        assert_eq!(
+            transaction.commit_time,
+            DateTime::PosixTime64Ns(PosixTime64Ns::new(0x5f5e1010, 0))
+        );
+        assert_eq!(
+            transaction.blocks,
+            vec![
+                ExtJournalBlock::new(0x20, 14, 0),
+                ExtJournalBlock::new(0x21, 15, 0x03),
+                ExtJournalBlock::new(0x22, 1, 0x0a)
+            ]
+        );
+        let transaction: &ExtJournalTransaction = journal.get_transaction_by_index(2)?;
+        assert_eq!(transaction.sequence_number, 7);
+        assert_eq!(transaction.is_committed, false);
+        assert_eq!(
+            transaction.blocks,
+            vec![ExtJournalBlock::new(0x30, 4, 0x08)]
+        );
+        assert_eq!(transaction.revoked_block_numbers, vec![0x20]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_data() {
+        let test_data: Vec<u8> = vec![0; 4096];
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut journal = ExtJournal::new();
+        let result = journal.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_transaction_by_index() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut journal = ExtJournal::new();
+        journal.read_data_stream(&data_stream)?;
+
+        let result = journal.get_transaction_by_index(99);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_block_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut journal = ExtJournal::new();
+        journal.read_data_stream(&data_stream)?;
+
+        let transaction: &ExtJournalTransaction = journal.get_transaction_by_index(1)?;
+
+        let mut data: Vec<u8> = vec![0; 1024];
+        journal.read_block_data(&transaction.blocks[0], &mut data)?;
+        assert_eq!(data, vec![0x11; 1024]);
+
+        journal.read_block_data(&transaction.blocks[1], &mut data)?;
+        assert_eq!(data[0..4], EXT_JOURNAL_SIGNATURE);
+        assert_eq!(data[4..1024], vec![0x22; 1020]);
+
+        journal.read_block_data(&transaction.blocks[2], &mut data)?;
+        assert_eq!(data, vec![0x33; 1024]);
+
+        let result = journal.read_block_data(&transaction.blocks[1], &mut data[0..512]);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::constants::*;
+
+/// Extended File System (ext) journal (JBD2) block.
+///
+/// Represents a copy of a file system block stored in the journal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtJournalBlock {
+    /// File system block number.
+    pub block_number: u64,
+
+    /// Journal block number of the copy.
+    pub journal_block_number: u64,
+
+    /// Block tag flags.
+    pub flags: u32,
+}
+
+impl ExtJournalBlock {
+    /// Creates a new journal block.
+    pub fn new(block_number: u64, journal_block_number: u64, flags: u32) -> Self {
+        Self {
+            block_number: block_number,
+            journal_block_number: journal_block_number,
+            flags: flags,
+        }
+    }
+
+    /// Determines if the first 4 bytes of the copy were escaped.
+    pub fn is_escaped(&self) -> bool {
+        self.flags & EXT_JOURNAL_BLOCK_TAG_FLAG_ESCAPED != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_escaped() {
+        let test_struct: ExtJournalBlock = ExtJournalBlock::new(32, 3, 0);
+        assert!(!test_struct.is_escaped());
+
+        let test_struct: ExtJournalBlock =
+            ExtJournalBlock::new(32, 3, EXT_JOURNAL_BLOCK_TAG_FLAG_ESCAPED);
+        assert!(test_struct.is_escaped());
+    }
+}
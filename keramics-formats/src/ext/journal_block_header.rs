@@ -0,0 +1,121 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::bytes_to_u32_be;
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "signature", data_type = "[u8; 4]", format = "hex"),
+        field(name = "block_type", data_type = "u32"),
+        field(name = "sequence_number", data_type = "u32"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Extended File System (ext) journal (JBD2) block header.
+pub struct ExtJournalBlockHeader {
+    /// Block type.
+    pub block_type: u32,
+
+    /// Sequence number (transaction identifier).
+    pub sequence_number: u32,
+}
+
+impl ExtJournalBlockHeader {
+    /// Creates a new journal block header.
+    pub fn new() -> Self {
+        Self {
+            block_type: 0,
+            sequence_number: 0,
+        }
+    }
+
+    /// Determines if the data starts with a journal block signature.
+    pub fn has_signature(data: &[u8]) -> bool {
+        data.len() >= 4 && data[0..4] == EXT_JOURNAL_SIGNATURE
+    }
+
+    /// Reads the journal block header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < EXT_JOURNAL_BLOCK_HEADER_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal block header data size"
+            ));
+        }
+        if data[0..4] != EXT_JOURNAL_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal block header signature"
+            ));
+        }
+        self.block_type = bytes_to_u32_be!(data, 4);
+        self.sequence_number = bytes_to_u32_be!(data, 8);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05,
+        ];
+    }
+
+    #[test]
+    fn test_has_signature() {
+        let test_data: Vec<u8> = get_test_data();
+
+        assert!(ExtJournalBlockHeader::has_signature(&test_data));
+        assert!(!ExtJournalBlockHeader::has_signature(&test_data[4..]));
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.block_type, EXT_JOURNAL_BLOCK_TYPE_COMMIT);
+        assert_eq!(test_struct.sequence_number, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockHeader::new();
+        let result = test_struct.read_data(&test_data[0..11]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = ExtJournalBlockHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,126 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{bytes_to_u16_be, bytes_to_u32_be};
+
+/// Extended File System (ext) journal (JBD2) block tag.
+pub struct ExtJournalBlockTag {
+    /// File system block number.
+    pub block_number: u64,
+
+    /// Flags.
+    pub flags: u32,
+}
+
+impl ExtJournalBlockTag {
+    /// Creates a new journal block tag.
+    pub fn new() -> Self {
+        Self {
+            block_number: 0,
+            flags: 0,
+        }
+    }
+
+    /// Reads the journal block tag from a buffer.
+    pub fn read_data(
+        &mut self,
+        data: &[u8],
+        has_64bit_block_numbers: bool,
+        has_checksum_v3: bool,
+    ) -> Result<(), ErrorTrace> {
+        let minimum_data_size: usize = if has_checksum_v3 {
+            16
+        } else if has_64bit_block_numbers {
+            12
+        } else {
+            8
+        };
+        if data.len() < minimum_data_size {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal block tag data size"
+            ));
+        }
+        self.block_number = bytes_to_u32_be!(data, 0) as u64;
+
+        if has_checksum_v3 {
+            self.flags = bytes_to_u32_be!(data, 4);
+        } else {
+            self.flags = bytes_to_u16_be!(data, 6) as u32;
+        }
+        if has_64bit_block_numbers {
+            self.block_number |= (bytes_to_u32_be!(data, 8) as u64) << 32;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockTag::new();
+        test_struct.read_data(&test_data[0..8], false, false)?;
+
+        assert_eq!(test_struct.block_number, 0x0102);
+        assert_eq!(test_struct.flags, 0x000a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_64bit_block_numbers() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockTag::new();
+        test_struct.read_data(&test_data[0..12], true, false)?;
+
+        assert_eq!(test_struct.block_number, 0x0000000100000102);
+        assert_eq!(test_struct.flags, 0x000a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_checksum_v3() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockTag::new();
+        test_struct.read_data(&test_data, false, true)?;
+
+        assert_eq!(test_struct.block_number, 0x0102);
+        assert_eq!(test_struct.flags, 0x0000000a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalBlockTag::new();
+        let result = test_struct.read_data(&test_data[0..12], false, true);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,125 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_datetime::{DateTime, PosixTime64Ns};
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u32_be, bytes_to_u64_be};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "signature", data_type = "[u8; 4]", format = "hex"),
+        field(name = "block_type", data_type = "u32"),
+        field(name = "sequence_number", data_type = "u32"),
+        field(name = "checksum_type", data_type = "u8"),
+        field(name = "checksum_size", data_type = "u8"),
+        field(name = "padding1", data_type = "[u8; 2]"),
+        field(name = "checksums", data_type = "[u8; 32]"),
+        field(name = "commit_time_seconds", data_type = "u64"),
+        field(name = "commit_time_nanoseconds", data_type = "u32"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Extended File System (ext) journal (JBD2) commit block.
+pub struct ExtJournalCommitBlock {
+    /// Commit date and time.
+    pub commit_time: DateTime,
+}
+
+impl ExtJournalCommitBlock {
+    /// Creates a new journal commit block.
+    pub fn new() -> Self {
+        Self {
+            commit_time: DateTime::NotSet,
+        }
+    }
+
+    /// Reads the journal commit block from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 60 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal commit block data size"
+            ));
+        }
+        if data[0..4] != EXT_JOURNAL_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal commit block signature"
+            ));
+        }
+        let commit_time_seconds: u64 = bytes_to_u64_be!(data, 48);
+        let commit_time_nanoseconds: u32 = bytes_to_u32_be!(data, 56);
+
+        // Commit blocks written by older versions of JBD do not contain a commit time.
+        if commit_time_seconds != 0 {
+            self.commit_time = DateTime::PosixTime64Ns(PosixTime64Ns::new(
+                commit_time_seconds as i64,
+                commit_time_nanoseconds,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 64];
+        data[0..12].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05,
+        ]);
+        data[48..60].copy_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, 0x5f, 0x5e, 0x10, 0x00, 0x00, 0x00, 0x03, 0xe8,
+        ]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalCommitBlock::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.commit_time,
+            DateTime::PosixTime64Ns(PosixTime64Ns::new(0x5f5e1000, 1000))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalCommitBlock::new();
+        let result = test_struct.read_data(&test_data[0..59]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = ExtJournalCommitBlock::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
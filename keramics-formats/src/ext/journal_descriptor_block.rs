@@ -0,0 +1,155 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+
+use super::constants::*;
+use super::journal_block_tag::ExtJournalBlockTag;
+use super::journal_superblock::ExtJournalSuperblock;
+
+/// Extended File System (ext) journal (JBD2) descriptor block.
+pub struct ExtJournalDescriptorBlock {
+    /// Block tags.
+    pub tags: Vec<ExtJournalBlockTag>,
+}
+
+impl ExtJournalDescriptorBlock {
+    /// Creates a new journal descriptor block.
+    pub fn new() -> Self {
+        Self { tags: Vec::new() }
+    }
+
+    /// Reads the journal descriptor block from a buffer.
+    pub fn read_data(
+        &mut self,
+        data: &[u8],
+        superblock: &ExtJournalSuperblock,
+    ) -> Result<(), ErrorTrace> {
+        let block_tag_size: usize = superblock.get_block_tag_size();
+        let has_64bit_block_numbers: bool = superblock.has_64bit_block_numbers();
+        let has_checksum_v3: bool = superblock.incompatible_feature_flags
+            & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V3
+            != 0;
+
+        // With metadata checksums the last 4 bytes of the block contain the block tail.
+        let data_size: usize = if superblock.has_metadata_checksums() {
+            data.len().saturating_sub(4)
+        } else {
+            data.len()
+        };
+        if data_size < EXT_JOURNAL_BLOCK_HEADER_SIZE + block_tag_size {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal descriptor block data size"
+            ));
+        }
+        let mut data_offset: usize = EXT_JOURNAL_BLOCK_HEADER_SIZE;
+
+        while data_offset + block_tag_size <= data_size {
+            let data_end_offset: usize = data_offset + block_tag_size;
+
+            let mut block_tag: ExtJournalBlockTag = ExtJournalBlockTag::new();
+
+            match block_tag.read_data(
+                &data[data_offset..data_end_offset],
+                has_64bit_block_numbers,
+                has_checksum_v3,
+            ) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read block tag at offset: {}", data_offset)
+                    );
+                    return Err(error);
+                }
+            }
+            data_offset = data_end_offset;
+
+            // A block tag without the same identifier flag is followed by a 16-byte journal
+            // identifier.
+            if block_tag.flags & EXT_JOURNAL_BLOCK_TAG_FLAG_SAME_IDENTIFIER == 0 {
+                data_offset += 16;
+            }
+            let is_last_tag: bool = block_tag.flags & EXT_JOURNAL_BLOCK_TAG_FLAG_LAST_TAG != 0;
+
+            self.tags.push(block_tag);
+
+            if is_last_tag {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_superblock() -> ExtJournalSuperblock {
+        let mut superblock: ExtJournalSuperblock = ExtJournalSuperblock::new();
+        superblock.block_type = EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V2;
+        superblock.block_size = 1024;
+        superblock
+    }
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 1024];
+        data[0..12].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        ]);
+        // First tag with a journal identifier.
+        data[12..20].copy_from_slice(&[0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00]);
+        data[20..36].copy_from_slice(&[
+            0x55, 0x72, 0x6f, 0x58, 0x45, 0x6e, 0x4d, 0x09, 0x8e, 0x8b, 0x3a, 0x9a, 0x2e, 0x17,
+            0x48, 0x5c,
+        ]);
+        // Second tag with same identifier and escaped flags.
+        data[36..44].copy_from_slice(&[0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x03]);
+        // Last tag.
+        data[44..52].copy_from_slice(&[0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x0a]);
+        // Stale tag after the last tag.
+        data[52..60].copy_from_slice(&[0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x0a]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_superblock: ExtJournalSuperblock = get_test_superblock();
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalDescriptorBlock::new();
+        test_struct.read_data(&test_data, &test_superblock)?;
+
+        assert_eq!(test_struct.tags.len(), 3);
+        assert_eq!(test_struct.tags[0].block_number, 0x20);
+        assert_eq!(test_struct.tags[1].block_number, 0x21);
+        assert_eq!(
+            test_struct.tags[1].flags,
+            EXT_JOURNAL_BLOCK_TAG_FLAG_ESCAPED | EXT_JOURNAL_BLOCK_TAG_FLAG_SAME_IDENTIFIER
+        );
+        assert_eq!(test_struct.tags[2].block_number, 0x22);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_superblock: ExtJournalSuperblock = get_test_superblock();
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalDescriptorBlock::new();
+        let result = test_struct.read_data(&test_data[0..16], &test_superblock);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,147 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{bytes_to_u32_be, bytes_to_u64_be};
+
+use super::constants::*;
+use super::journal_superblock::ExtJournalSuperblock;
+
+/// Extended File System (ext) journal (JBD2) revoke block.
+pub struct ExtJournalRevokeBlock {
+    /// Revoked file system block numbers.
+    pub block_numbers: Vec<u64>,
+}
+
+impl ExtJournalRevokeBlock {
+    /// Creates a new journal revoke block.
+    pub fn new() -> Self {
+        Self {
+            block_numbers: Vec::new(),
+        }
+    }
+
+    /// Reads the journal revoke block from a buffer.
+    pub fn read_data(
+        &mut self,
+        data: &[u8],
+        superblock: &ExtJournalSuperblock,
+    ) -> Result<(), ErrorTrace> {
+        if data.len() < EXT_JOURNAL_BLOCK_HEADER_SIZE + 4 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal revoke block data size"
+            ));
+        }
+        if data[0..4] != EXT_JOURNAL_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal revoke block signature"
+            ));
+        }
+        // With metadata checksums the last 4 bytes of the block contain the block tail.
+        let maximum_data_size: usize = if superblock.has_metadata_checksums() {
+            data.len() - 4
+        } else {
+            data.len()
+        };
+        // The data size includes the block header and the data size value.
+        let data_size: usize = bytes_to_u32_be!(data, 12) as usize;
+
+        if data_size < 16 || data_size > maximum_data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid data size: {} value out of bounds",
+                data_size
+            )));
+        }
+        let block_number_size: usize = if superblock.has_64bit_block_numbers() {
+            8
+        } else {
+            4
+        };
+        let mut data_offset: usize = 16;
+
+        while data_offset + block_number_size <= data_size {
+            let block_number: u64 = if block_number_size == 8 {
+                bytes_to_u64_be!(data, data_offset)
+            } else {
+                bytes_to_u32_be!(data, data_offset) as u64
+            };
+            self.block_numbers.push(block_number);
+
+            data_offset += block_number_size;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 1024];
+        data[0..24].copy_from_slice(&[
+            0xc0, 0x3b, 0x39, 0x98, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+            0x00, 0x18, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x31,
+        ]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_superblock: ExtJournalSuperblock = ExtJournalSuperblock::new();
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalRevokeBlock::new();
+        test_struct.read_data(&test_data, &test_superblock)?;
+
+        assert_eq!(test_struct.block_numbers, vec![0x30, 0x31]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_64bit_block_numbers() -> Result<(), ErrorTrace> {
+        let mut test_superblock: ExtJournalSuperblock = ExtJournalSuperblock::new();
+        test_superblock.incompatible_feature_flags = EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_64BIT;
+
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalRevokeBlock::new();
+        test_struct.read_data(&test_data, &test_superblock)?;
+
+        assert_eq!(test_struct.block_numbers, vec![0x0000003000000031]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_superblock: ExtJournalSuperblock = ExtJournalSuperblock::new();
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalRevokeBlock::new();
+        let result = test_struct.read_data(&test_data[0..15], &test_superblock);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_data_size() {
+        let test_superblock: ExtJournalSuperblock = ExtJournalSuperblock::new();
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[14] = 0x08;
+
+        let mut test_struct = ExtJournalRevokeBlock::new();
+        let result = test_struct.read_data(&test_data, &test_superblock);
+        assert!(result.is_err());
+    }
+}
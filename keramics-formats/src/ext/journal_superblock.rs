@@ -0,0 +1,373 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_checksums::ReversedCrc32Context;
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::bytes_to_u32_be;
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "signature", data_type = "[u8; 4]", format = "hex"),
+        field(name = "block_type", data_type = "u32"),
+        field(name = "sequence_number", data_type = "u32"),
+        field(name = "block_size", data_type = "u32"),
+        field(name = "number_of_blocks", data_type = "u32"),
+        field(name = "first_log_block_number", data_type = "u32"),
+        field(name = "first_sequence_number", data_type = "u32"),
+        field(name = "start_block_number", data_type = "u32"),
+        field(name = "error_number", data_type = "u32"),
+        field(name = "compatible_feature_flags", data_type = "u32", format = "hex"),
+        field(name = "incompatible_feature_flags", data_type = "u32", format = "hex"),
+        field(
+            name = "read_only_compatible_feature_flags",
+            data_type = "u32",
+            format = "hex"
+        ),
+        field(name = "journal_identifier", data_type = "uuid", byte_order = "big"),
+        field(name = "number_of_users", data_type = "u32"),
+        field(name = "dynamic_superblock_block_number", data_type = "u32"),
+        field(name = "maximum_transaction_size", data_type = "u32"),
+        field(name = "maximum_transaction_data_size", data_type = "u32"),
+        field(name = "checksum_type", data_type = "u8"),
+        field(name = "padding1", data_type = "[u8; 3]"),
+        field(name = "number_of_fast_commit_blocks", data_type = "u32"),
+        field(name = "head_block_number", data_type = "u32"),
+        field(name = "padding2", data_type = "[u8; 160]"),
+        field(name = "checksum", data_type = "u32", format = "hex"),
+        field(name = "user_identifiers", data_type = "[u8; 768]"),
+    ),
+    method(name = "debug_read_data"),
+    method(name = "read_at_position")
+)]
+/// Extended File System (ext) journal (JBD2) superblock.
+pub struct ExtJournalSuperblock {
+    /// Block type.
+    pub block_type: u32,
+
+    /// Block size.
+    pub block_size: u32,
+
+    /// Number of blocks.
+    pub number_of_blocks: u32,
+
+    /// First log block number.
+    pub first_log_block_number: u32,
+
+    /// First sequence number (transaction identifier).
+    pub first_sequence_number: u32,
+
+    /// Start block number, where 0 represents the journal is empty (clean).
+    pub start_block_number: u32,
+
+    /// Compatible feature flags.
+    pub compatible_feature_flags: u32,
+
+    /// Incompatible feature flags.
+    pub incompatible_feature_flags: u32,
+
+    /// Read-only compatible feature flags.
+    pub read_only_compatible_feature_flags: u32,
+
+    /// Journal identifier.
+    pub journal_identifier: [u8; 16],
+
+    /// Checksum type.
+    pub checksum_type: u8,
+
+    /// Number of fast commit blocks.
+    pub number_of_fast_commit_blocks: u32,
+}
+
+impl ExtJournalSuperblock {
+    /// Creates a new journal superblock.
+    pub fn new() -> Self {
+        Self {
+            block_type: 0,
+            block_size: 0,
+            number_of_blocks: 0,
+            first_log_block_number: 0,
+            first_sequence_number: 0,
+            start_block_number: 0,
+            compatible_feature_flags: 0,
+            incompatible_feature_flags: 0,
+            read_only_compatible_feature_flags: 0,
+            journal_identifier: [0; 16],
+            checksum_type: 0,
+            number_of_fast_commit_blocks: 0,
+        }
+    }
+
+    /// Reads the journal superblock from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 1024 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal superblock data size"
+            ));
+        }
+        if data[0..4] != EXT_JOURNAL_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext journal superblock signature"
+            ));
+        }
+        self.block_type = bytes_to_u32_be!(data, 4);
+
+        if self.block_type != EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V1
+            && self.block_type != EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V2
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported block type: {}",
+                self.block_type
+            )));
+        }
+        self.block_size = bytes_to_u32_be!(data, 12);
+
+        if self.block_size < 1024 || self.block_size > 65536 || !self.block_size.is_power_of_two() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid block size: {} value out of bounds",
+                self.block_size
+            )));
+        }
+        self.number_of_blocks = bytes_to_u32_be!(data, 16);
+        self.first_log_block_number = bytes_to_u32_be!(data, 20);
+
+        if self.first_log_block_number == 0 || self.first_log_block_number >= self.number_of_blocks
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid first log block number: {} value out of bounds",
+                self.first_log_block_number
+            )));
+        }
+        self.first_sequence_number = bytes_to_u32_be!(data, 24);
+        self.start_block_number = bytes_to_u32_be!(data, 28);
+
+        if self.start_block_number != 0
+            && (self.start_block_number < self.first_log_block_number
+                || self.start_block_number >= self.number_of_blocks)
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid start block number: {} value out of bounds",
+                self.start_block_number
+            )));
+        }
+        // The version 1 superblock does not define feature flags.
+        if self.block_type == EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V2 {
+            self.compatible_feature_flags = bytes_to_u32_be!(data, 36);
+            self.incompatible_feature_flags = bytes_to_u32_be!(data, 40);
+            self.read_only_compatible_feature_flags = bytes_to_u32_be!(data, 44);
+            self.journal_identifier.copy_from_slice(&data[48..64]);
+            self.checksum_type = data[80];
+        }
+        if self.incompatible_feature_flags & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_FAST_COMMIT != 0
+        {
+            self.number_of_fast_commit_blocks = bytes_to_u32_be!(data, 84);
+
+            // A value of 0 represents the default number of fast commit blocks.
+            if self.number_of_fast_commit_blocks == 0 {
+                self.number_of_fast_commit_blocks = 256;
+            }
+            if self.number_of_fast_commit_blocks
+                >= self.number_of_blocks - self.first_log_block_number
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid number of fast commit blocks: {} value out of bounds",
+                    self.number_of_fast_commit_blocks
+                )));
+            }
+        }
+        if self.incompatible_feature_flags
+            & (EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V2
+                | EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V3)
+            != 0
+        {
+            if self.checksum_type != EXT_JOURNAL_CHECKSUM_TYPE_CRC32C {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported checksum type: {}",
+                    self.checksum_type
+                )));
+            }
+            let stored_checksum: u32 = bytes_to_u32_be!(data, 252);
+
+            let mut crc32_context: ReversedCrc32Context = ReversedCrc32Context::new(0x82f63b78, 0);
+            crc32_context.update(&data[0..252]);
+            crc32_context.update(&[0; 4]);
+            crc32_context.update(&data[256..1024]);
+
+            let mut calculated_checksum: u32 = crc32_context.finalize();
+            calculated_checksum = 0xffffffff - calculated_checksum;
+
+            if stored_checksum != 0 && stored_checksum != calculated_checksum {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                    stored_checksum, calculated_checksum
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Retrieves the size of a block tag in a descriptor block.
+    pub fn get_block_tag_size(&self) -> usize {
+        if self.incompatible_feature_flags & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V3 != 0
+        {
+            return 16;
+        }
+        let mut block_tag_size: usize = 12;
+
+        if self.incompatible_feature_flags & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V2 != 0
+        {
+            block_tag_size += 2;
+        }
+        if self.incompatible_feature_flags & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_64BIT == 0 {
+            block_tag_size -= 4;
+        }
+        block_tag_size
+    }
+
+    /// Retrieves the number of the block after the last log block.
+    ///
+    /// The fast commit blocks are stored after the last log block.
+    pub fn get_last_log_block_number(&self) -> u32 {
+        self.number_of_blocks - self.number_of_fast_commit_blocks
+    }
+
+    /// Determines if the 64-bit block numbers feature is used.
+    pub fn has_64bit_block_numbers(&self) -> bool {
+        self.incompatible_feature_flags & EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_64BIT != 0
+    }
+
+    /// Determines if the metadata checksum version 2 or 3 feature is used.
+    pub fn has_metadata_checksums(&self) -> bool {
+        self.incompatible_feature_flags
+            & (EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V2
+                | EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_CHECKSUM_V3)
+            != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 1024];
+        data[0..4].copy_from_slice(&EXT_JOURNAL_SIGNATURE);
+        data[4..8].copy_from_slice(&[0x00, 0x00, 0x00, 0x04]);
+        data[12..16].copy_from_slice(&[0x00, 0x00, 0x04, 0x00]);
+        data[16..20].copy_from_slice(&[0x00, 0x00, 0x04, 0x00]);
+        data[20..24].copy_from_slice(&[0x00, 0x00, 0x00, 0x01]);
+        data[24..28].copy_from_slice(&[0x00, 0x00, 0x00, 0x02]);
+        data[28..32].copy_from_slice(&[0x00, 0x00, 0x00, 0x01]);
+        data[40..44].copy_from_slice(&[0x00, 0x00, 0x00, 0x01]);
+        data[48..64].copy_from_slice(&[
+            0x55, 0x72, 0x6f, 0x58, 0x45, 0x6e, 0x4d, 0x09, 0x8e, 0x8b, 0x3a, 0x9a, 0x2e, 0x17,
+            0x48, 0x5c,
+        ]);
+        data[64..68].copy_from_slice(&[0x00, 0x00, 0x00, 0x01]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.block_type, EXT_JOURNAL_BLOCK_TYPE_SUPERBLOCK_V2);
+        assert_eq!(test_struct.block_size, 1024);
+        assert_eq!(test_struct.number_of_blocks, 1024);
+        assert_eq!(test_struct.first_log_block_number, 1);
+        assert_eq!(test_struct.first_sequence_number, 2);
+        assert_eq!(test_struct.start_block_number, 1);
+        assert_eq!(test_struct.compatible_feature_flags, 0);
+        assert_eq!(
+            test_struct.incompatible_feature_flags,
+            EXT_JOURNAL_INCOMPATIBLE_FEATURE_FLAG_REVOKE
+        );
+        assert_eq!(test_struct.read_only_compatible_feature_flags, 0);
+        assert_eq!(test_struct.get_block_tag_size(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_checksum_v3() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[43] = 0x13;
+        test_data[80] = EXT_JOURNAL_CHECKSUM_TYPE_CRC32C;
+
+        let mut crc32_context: ReversedCrc32Context = ReversedCrc32Context::new(0x82f63b78, 0);
+        crc32_context.update(&test_data);
+        let checksum: u32 = 0xffffffff - crc32_context.finalize();
+        test_data[252..256].copy_from_slice(&checksum.to_be_bytes());
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.get_block_tag_size(), 16);
+        assert!(test_struct.has_64bit_block_numbers());
+        assert!(test_struct.has_metadata_checksums());
+
+        test_data[253] ^= 0xff;
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        let result = test_struct.read_data(&test_data[0..1023]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_block_type() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[7] = 0x01;
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_block_size() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[14] = 0x05;
+
+        let mut test_struct = ExtJournalSuperblock::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
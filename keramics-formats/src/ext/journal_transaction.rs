@@ -0,0 +1,58 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_datetime::DateTime;
+
+use super::journal_block::ExtJournalBlock;
+
+/// Extended File System (ext) journal (JBD2) transaction.
+#[derive(Clone, Debug)]
+pub struct ExtJournalTransaction {
+    /// Sequence number (transaction identifier).
+    pub sequence_number: u32,
+
+    /// Journal block number of the first descriptor block, where 0 represents no descriptor
+    /// block was found.
+    pub journal_block_number: u64,
+
+    /// File system blocks overwritten by the transaction.
+    pub blocks: Vec<ExtJournalBlock>,
+
+    /// Revoked file system block numbers.
+    pub revoked_block_numbers: Vec<u64>,
+
+    /// Value to indicate a commit block was found.
+    pub is_committed: bool,
+
+    /// Commit date and time.
+    pub commit_time: DateTime,
+}
+
+impl ExtJournalTransaction {
+    /// Creates a new journal transaction.
+    pub fn new(sequence_number: u32) -> Self {
+        Self {
+            sequence_number: sequence_number,
+            journal_block_number: 0,
+            blocks: Vec::new(),
+            revoked_block_numbers: Vec::new(),
+            is_committed: false,
+            commit_time: DateTime::NotSet,
+        }
+    }
+
+    /// Retrieves the number of file system blocks overwritten by the transaction.
+    pub fn get_number_of_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+}
@@ -28,6 +28,7 @@ mod extents_footer;
 mod extents_header;
 mod extents_tree;
 mod features;
+mod file_capabilities;
 mod file_entry;
 mod file_system;
 mod group_descriptor;
@@ -40,9 +41,27 @@ mod inode_ext3;
 mod inode_ext4;
 mod inode_extension_ext4;
 mod inode_table;
+mod journal;
+mod journal_block;
+mod journal_block_header;
+mod journal_block_tag;
+mod journal_commit_block;
+mod journal_descriptor_block;
+mod journal_revoke_block;
+mod journal_superblock;
+mod journal_transaction;
 mod path;
+mod posix_acl;
+mod posix_acl_entry;
 mod superblock;
 
+pub use attribute::ExtAttribute;
+pub use file_capabilities::ExtFileCapabilities;
 pub use file_entry::ExtFileEntry;
 pub use file_system::ExtFileSystem;
+pub use journal::ExtJournal;
+pub use journal_block::ExtJournalBlock;
+pub use journal_transaction::ExtJournalTransaction;
 pub use path::ExtPath;
+pub use posix_acl::ExtPosixAcl;
+pub use posix_acl_entry::ExtPosixAclEntry;
@@ -0,0 +1,188 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le};
+
+use super::constants::*;
+use super::posix_acl_entry::ExtPosixAclEntry;
+
+/// Extended File System (ext) POSIX access control list (ACL).
+///
+/// Stored in the system.posix_acl_access and system.posix_acl_default extended attributes.
+pub struct ExtPosixAcl {
+    /// Version.
+    pub version: u32,
+
+    /// Entries.
+    pub entries: Vec<ExtPosixAclEntry>,
+}
+
+impl ExtPosixAcl {
+    /// Creates a new POSIX ACL.
+    pub fn new() -> Self {
+        Self {
+            version: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads the POSIX ACL from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 4 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext POSIX ACL data size"
+            ));
+        }
+        self.version = bytes_to_u32_le!(data, 0);
+
+        // The ext on-disk format uses 4-byte entries for entries without a qualifier, where
+        // the extended attribute (xattr) format that Linux exposes to user space always uses
+        // 8-byte entries.
+        if self.version != EXT_POSIX_ACL_VERSION_EXT && self.version != EXT_POSIX_ACL_VERSION_XATTR
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported ext POSIX ACL version: {}",
+                self.version
+            )));
+        }
+        let mut data_offset: usize = 4;
+
+        while data_offset < data_size {
+            if data_offset + 4 > data_size {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid ext POSIX ACL entry at offset: {} value out of bounds",
+                    data_offset
+                )));
+            }
+            let tag: u16 = bytes_to_u16_le!(data, data_offset);
+
+            let entry_size: usize = if self.version == EXT_POSIX_ACL_VERSION_XATTR
+                || ExtPosixAclEntry::has_qualifier(tag)
+            {
+                8
+            } else {
+                4
+            };
+            let data_end_offset: usize = data_offset + entry_size;
+
+            if data_end_offset > data_size {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid ext POSIX ACL entry at offset: {} value out of bounds",
+                    data_offset
+                )));
+            }
+            let mut entry: ExtPosixAclEntry = ExtPosixAclEntry::new();
+
+            match entry.read_data(&data[data_offset..data_end_offset]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read POSIX ACL entry at offset: {}", data_offset)
+                    );
+                    return Err(error);
+                }
+            }
+            self.entries.push(entry);
+
+            data_offset = data_end_offset;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x06, 0x00, 0x02, 0x00, 0x06, 0x00, 0xe8, 0x03,
+            0x00, 0x00, 0x04, 0x00, 0x04, 0x00, 0x08, 0x00, 0x05, 0x00, 0xe9, 0x03, 0x00, 0x00,
+            0x10, 0x00, 0x07, 0x00, 0x20, 0x00, 0x04, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtPosixAcl::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.version, EXT_POSIX_ACL_VERSION_EXT);
+        assert_eq!(test_struct.entries.len(), 6);
+        assert_eq!(test_struct.entries[0].tag, EXT_POSIX_ACL_TAG_USER_OBJECT);
+        assert_eq!(test_struct.entries[0].qualifier, None);
+        assert_eq!(test_struct.entries[1].tag, EXT_POSIX_ACL_TAG_USER);
+        assert_eq!(test_struct.entries[1].qualifier, Some(1000));
+        assert_eq!(test_struct.entries[3].tag, EXT_POSIX_ACL_TAG_GROUP);
+        assert_eq!(test_struct.entries[3].qualifier, Some(1001));
+        assert_eq!(
+            test_struct.entries[3].permissions,
+            EXT_POSIX_ACL_PERMISSION_READ | EXT_POSIX_ACL_PERMISSION_EXECUTE
+        );
+        assert_eq!(test_struct.entries[5].tag, EXT_POSIX_ACL_TAG_OTHER);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_xattr_version() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = vec![
+            0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0xff, 0x02, 0x00,
+            0x06, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        ];
+
+        let mut test_struct = ExtPosixAcl::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.version, EXT_POSIX_ACL_VERSION_XATTR);
+        assert_eq!(test_struct.entries.len(), 2);
+        assert_eq!(test_struct.entries[0].qualifier, None);
+        assert_eq!(test_struct.entries[1].qualifier, Some(1000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtPosixAcl::new();
+        let result = test_struct.read_data(&test_data[0..3]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_version() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0x03;
+
+        let mut test_struct = ExtPosixAcl::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_entry_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtPosixAcl::new();
+        let result = test_struct.read_data(&test_data[0..14]);
+        assert!(result.is_err());
+    }
+}
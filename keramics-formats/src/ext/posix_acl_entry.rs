@@ -0,0 +1,144 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le};
+
+use super::constants::*;
+
+/// Extended File System (ext) POSIX access control list (ACL) entry.
+pub struct ExtPosixAclEntry {
+    /// Tag.
+    pub tag: u16,
+
+    /// Permissions.
+    pub permissions: u16,
+
+    /// Qualifier, which contains the user identifier (UID) for a user entry and the group
+    /// identifier (GID) for a group entry.
+    pub qualifier: Option<u32>,
+}
+
+impl ExtPosixAclEntry {
+    /// Creates a new POSIX ACL entry.
+    pub fn new() -> Self {
+        Self {
+            tag: 0,
+            permissions: 0,
+            qualifier: None,
+        }
+    }
+
+    /// Determines if the entry has a qualifier.
+    pub(super) fn has_qualifier(tag: u16) -> bool {
+        tag == EXT_POSIX_ACL_TAG_USER || tag == EXT_POSIX_ACL_TAG_GROUP
+    }
+
+    /// Reads the POSIX ACL entry from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 4 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported ext POSIX ACL entry data size"
+            ));
+        }
+        self.tag = bytes_to_u16_le!(data, 0);
+        self.permissions = bytes_to_u16_le!(data, 2);
+
+        match self.tag {
+            EXT_POSIX_ACL_TAG_USER_OBJECT
+            | EXT_POSIX_ACL_TAG_GROUP_OBJECT
+            | EXT_POSIX_ACL_TAG_MASK
+            | EXT_POSIX_ACL_TAG_OTHER => {}
+            EXT_POSIX_ACL_TAG_USER | EXT_POSIX_ACL_TAG_GROUP => {
+                if data.len() < 8 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Unsupported ext POSIX ACL entry data size"
+                    ));
+                }
+                self.qualifier = Some(bytes_to_u32_le!(data, 4));
+            }
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported ext POSIX ACL entry tag: 0x{:04x}",
+                    self.tag
+                )));
+            }
+        }
+        if self.permissions & 0xfff8 != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported ext POSIX ACL entry permissions: 0x{:04x}",
+                self.permissions
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![0x02, 0x00, 0x06, 0x00, 0xe8, 0x03, 0x00, 0x00];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtPosixAclEntry::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.tag, EXT_POSIX_ACL_TAG_USER);
+        assert_eq!(
+            test_struct.permissions,
+            EXT_POSIX_ACL_PERMISSION_READ | EXT_POSIX_ACL_PERMISSION_WRITE
+        );
+        assert_eq!(test_struct.qualifier, Some(1000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_without_qualifier() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = vec![0x20, 0x00, 0x04, 0x00];
+
+        let mut test_struct = ExtPosixAclEntry::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.tag, EXT_POSIX_ACL_TAG_OTHER);
+        assert_eq!(test_struct.permissions, EXT_POSIX_ACL_PERMISSION_READ);
+        assert_eq!(test_struct.qualifier, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = ExtPosixAclEntry::new();
+        let result = test_struct.read_data(&test_data[0..4]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_tag() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0x03;
+
+        let mut test_struct = ExtPosixAclEntry::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
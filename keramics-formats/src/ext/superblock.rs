@@ -183,6 +183,9 @@ pub struct ExtSuperblock {
     /// Last mount path.
     pub last_mount_path: ByteString,
 
+    /// Journal inode number.
+    pub journal_inode_number: u32,
+
     /// Group descriptor size.
     pub group_descriptor_size: u16,
 
@@ -215,6 +218,7 @@ impl ExtSuperblock {
             file_system_identifier: [0; 16],
             volume_label: ByteString::new_with_encoding(encoding),
             last_mount_path: ByteString::new_with_encoding(encoding),
+            journal_inode_number: 0,
             group_descriptor_size: 0,
             number_of_block_groups_per_flex_group: 0,
             first_meta_block_group: 0,
@@ -288,6 +292,7 @@ impl ExtSuperblock {
         self.last_mount_time = PosixTime32::from_le_bytes(&data[44..48]);
         self.last_written_time = PosixTime32::from_le_bytes(&data[48..52]);
         self.inode_size = bytes_to_u16_le!(data, 88);
+        self.journal_inode_number = bytes_to_u32_le!(data, 224);
         self.group_descriptor_size = bytes_to_u16_le!(data, 254);
         self.first_meta_block_group = bytes_to_u32_le!(data, 260);
         self.file_system_identifier.copy_from_slice(&data[104..120]);
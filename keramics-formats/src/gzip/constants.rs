@@ -0,0 +1,42 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// gzip member header signature.
+pub(super) const GZIP_MEMBER_HEADER_SIGNATURE: [u8; 2] = [0x1f, 0x8b];
+
+/// gzip compression method: deflate.
+pub(super) const GZIP_COMPRESSION_METHOD_DEFLATE: u8 = 8;
+
+/// gzip member flag: a CRC-16 of the member header is present.
+pub(super) const GZIP_FLAG_HEADER_CRC: u8 = 0x02;
+
+/// gzip member flag: extra field is present.
+pub(super) const GZIP_FLAG_EXTRA_FIELD: u8 = 0x04;
+
+/// gzip member flag: original file name is present.
+pub(super) const GZIP_FLAG_NAME: u8 = 0x08;
+
+/// gzip member flag: comment is present.
+pub(super) const GZIP_FLAG_COMMENT: u8 = 0x10;
+
+/// gzip member flags that are reserved.
+pub(super) const GZIP_RESERVED_FLAGS: u8 = 0xe0;
+
+/// Size of the compressed data that is read at once.
+pub(super) const GZIP_READ_BUFFER_SIZE: usize = 16384;
+
+/// Minimum distance in uncompressed data between seek points.
+pub(super) const GZIP_SEEK_POINT_DISTANCE: u64 = 1048576;
+
+/// Maximum size of a zero-terminated string in the member header.
+pub(super) const GZIP_MAXIMUM_STRING_SIZE: usize = 65536;
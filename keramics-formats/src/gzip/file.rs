@@ -0,0 +1,912 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_checksums::ReversedCrc32Context;
+use keramics_compression::{DeflateStreamContext, StreamDecompressor};
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
+use keramics_datetime::DateTime;
+use keramics_encodings::CharacterEncoding;
+use keramics_types::{ByteString, bytes_to_u16_le};
+
+use super::constants::*;
+use super::member::GzipMember;
+use super::member_footer::GzipMemberFooter;
+use super::member_header::GzipMemberHeader;
+use super::seek_point::GzipSeekPoint;
+
+/// gzip compressed file.
+pub struct GzipFile {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Members.
+    members: Vec<GzipMember>,
+
+    /// Seek points.
+    seek_points: Vec<GzipSeekPoint>,
+
+    /// Stream context of the member that is being decompressed.
+    stream_context: Option<DeflateStreamContext>,
+
+    /// Index of the member that is being decompressed.
+    stream_member_index: usize,
+
+    /// Offset of the compressed data that is decompressed next.
+    stream_compressed_data_offset: u64,
+
+    /// Most recently uncompressed data.
+    uncompressed_data: Vec<u8>,
+
+    /// Media offset of the most recently uncompressed data.
+    uncompressed_data_media_offset: u64,
+
+    /// Media size.
+    pub media_size: u64,
+
+    /// Media offset.
+    media_offset: u64,
+}
+
+impl GzipFile {
+    /// Creates a file.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            members: Vec::new(),
+            seek_points: Vec::new(),
+            stream_context: None,
+            stream_member_index: 0,
+            stream_compressed_data_offset: 0,
+            uncompressed_data: Vec::new(),
+            uncompressed_data_media_offset: 0,
+            media_size: 0,
+            media_offset: 0,
+        }
+    }
+
+    /// Retrieves the number of members.
+    pub fn get_number_of_members(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Retrieves a specific member.
+    pub fn get_member_by_index(&self, member_index: usize) -> Result<&GzipMember, ErrorTrace> {
+        match self.members.get(member_index) {
+            Some(member) => Ok(member),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "No member with index: {}",
+                member_index
+            ))),
+        }
+    }
+
+    /// Reads a file from a data stream.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        match self.read_members(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read members");
+                return Err(error);
+            }
+        }
+        self.data_stream = Some(data_stream.clone());
+
+        Ok(())
+    }
+
+    /// Reads the members.
+    ///
+    /// The compressed data of every member is decompressed to validate the checksum and
+    /// uncompressed data size and to build the seek points used for random access.
+    fn read_members(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        let file_size: u64 = keramics_core::data_stream_get_size!(data_stream);
+
+        let mut file_offset: u64 = 0;
+        let mut media_offset: u64 = 0;
+
+        while file_offset < file_size {
+            // Data that follows the last member, such as padding, is ignored.
+            if !self.members.is_empty() {
+                let mut signature: [u8; 2] = [0; 2];
+
+                let read_count: usize = keramics_core::data_stream_read_at_position!(
+                    data_stream,
+                    &mut signature,
+                    SeekFrom::Start(file_offset)
+                );
+                if read_count != 2 || signature != GZIP_MEMBER_HEADER_SIGNATURE {
+                    break;
+                }
+            }
+            let member_index: usize = self.members.len();
+
+            let mut member: GzipMember = GzipMember::new();
+            member.media_offset = media_offset;
+
+            match self.read_member_header(data_stream, file_offset, &mut member) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read member: {} header", member_index)
+                    );
+                    return Err(error);
+                }
+            }
+            match self.read_member_data(data_stream, member_index, &mut member) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read member: {} data", member_index)
+                    );
+                    return Err(error);
+                }
+            }
+            file_offset = member.compressed_data_offset + member.compressed_data_size + 8;
+            media_offset += member.uncompressed_data_size;
+
+            self.members.push(member);
+        }
+        if self.members.is_empty() {
+            return Err(keramics_core::error_trace_new!("Missing members"));
+        }
+        self.media_size = media_offset;
+
+        Ok(())
+    }
+
+    /// Reads a member header.
+    fn read_member_header(
+        &self,
+        data_stream: &DataStreamReference,
+        file_offset: u64,
+        member: &mut GzipMember,
+    ) -> Result<(), ErrorTrace> {
+        let mut header_data: Vec<u8> = vec![0; 10];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut header_data,
+            SeekFrom::Start(file_offset)
+        );
+        let mut member_header: GzipMemberHeader = GzipMemberHeader::new();
+
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "GzipMemberHeader data of size: 10 at offset: {} (0x{:08x})\n",
+                file_offset, file_offset
+            ));
+            self.mediator.debug_print_data(&header_data, true);
+            self.mediator
+                .debug_print(GzipMemberHeader::debug_read_data(&header_data));
+        }
+        match member_header.read_data(&header_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read member header");
+                return Err(error);
+            }
+        }
+        let mut data_offset: u64 = file_offset + 10;
+
+        if member_header.flags & GZIP_FLAG_EXTRA_FIELD != 0 {
+            let mut size_data: [u8; 2] = [0; 2];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut size_data,
+                SeekFrom::Start(data_offset)
+            );
+            let extra_field_size: usize = bytes_to_u16_le!(size_data, 0) as usize;
+
+            let mut extra_field_data: Vec<u8> = vec![0; extra_field_size];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut extra_field_data,
+                SeekFrom::Start(data_offset + 2)
+            );
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "GzipExtraField data of size: {} at offset: {} (0x{:08x})\n",
+                    extra_field_size,
+                    data_offset + 2,
+                    data_offset + 2
+                ));
+                self.mediator.debug_print_data(&extra_field_data, true);
+            }
+            header_data.extend_from_slice(&size_data);
+            header_data.extend_from_slice(&extra_field_data);
+
+            data_offset += 2 + extra_field_size as u64;
+        }
+        if member_header.flags & GZIP_FLAG_NAME != 0 {
+            match self.read_string(data_stream, &mut data_offset, &mut header_data) {
+                Ok(byte_string) => member.name = Some(byte_string),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read name");
+                    return Err(error);
+                }
+            }
+        }
+        if member_header.flags & GZIP_FLAG_COMMENT != 0 {
+            match self.read_string(data_stream, &mut data_offset, &mut header_data) {
+                Ok(byte_string) => member.comment = Some(byte_string),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read comment");
+                    return Err(error);
+                }
+            }
+        }
+        if member_header.flags & GZIP_FLAG_HEADER_CRC != 0 {
+            let mut checksum_data: [u8; 2] = [0; 2];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut checksum_data,
+                SeekFrom::Start(data_offset)
+            );
+            let stored_checksum: u16 = bytes_to_u16_le!(checksum_data, 0);
+
+            let mut crc32_context: ReversedCrc32Context = ReversedCrc32Context::new(0xedb88320, 0);
+            crc32_context.update(&header_data);
+
+            // The header checksum consists of the lower 16 bits of the CRC-32.
+            let calculated_checksum: u16 = (crc32_context.finalize() & 0x0000ffff) as u16;
+
+            if stored_checksum != calculated_checksum {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Mismatch between stored: 0x{:04x} and calculated: 0x{:04x} checksums",
+                    stored_checksum, calculated_checksum
+                )));
+            }
+            data_offset += 2;
+        }
+        member.modification_time = if member_header.modification_time.timestamp == 0 {
+            DateTime::NotSet
+        } else {
+            DateTime::PosixTime32(member_header.modification_time)
+        };
+        member.operating_system = member_header.operating_system;
+        member.compressed_data_offset = data_offset;
+
+        Ok(())
+    }
+
+    /// Reads a member compressed data and footer.
+    fn read_member_data(
+        &mut self,
+        data_stream: &DataStreamReference,
+        member_index: usize,
+        member: &mut GzipMember,
+    ) -> Result<(), ErrorTrace> {
+        let mut stream_context: DeflateStreamContext = DeflateStreamContext::new();
+        let mut crc32_context: ReversedCrc32Context = ReversedCrc32Context::new(0xedb88320, 0);
+        let mut compressed_data: Vec<u8> = vec![0; GZIP_READ_BUFFER_SIZE];
+        let mut uncompressed_data: Vec<u8> = Vec::new();
+        let mut compressed_data_offset: u64 = member.compressed_data_offset;
+        let mut media_offset: u64 = member.media_offset;
+
+        stream_context.set_block_boundary_interval(GZIP_SEEK_POINT_DISTANCE);
+
+        self.seek_points.push(GzipSeekPoint::new(
+            member_index,
+            compressed_data_offset,
+            0,
+            media_offset,
+            &[],
+        ));
+        while !stream_context.is_end_of_stream() {
+            let read_count: usize = keramics_core::data_stream_read_at_position!(
+                data_stream,
+                &mut compressed_data,
+                SeekFrom::Start(compressed_data_offset)
+            );
+            if read_count == 0 {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid compressed data value too small"
+                ));
+            }
+            compressed_data_offset += read_count as u64;
+
+            uncompressed_data.clear();
+
+            match stream_context
+                .decompress_chunk(&compressed_data[..read_count], &mut uncompressed_data)
+            {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decompress data");
+                    return Err(error);
+                }
+            }
+            crc32_context.update(&uncompressed_data);
+
+            media_offset += uncompressed_data.len() as u64;
+
+            for block_boundary in stream_context.take_block_boundaries() {
+                self.seek_points.push(GzipSeekPoint::new(
+                    member_index,
+                    member.compressed_data_offset + block_boundary.compressed_data_offset,
+                    block_boundary.bit_offset,
+                    member.media_offset + block_boundary.uncompressed_data_offset,
+                    &block_boundary.window,
+                ));
+            }
+        }
+        let footer_offset: u64 =
+            compressed_data_offset - stream_context.get_trailing_data().len() as u64;
+
+        member.compressed_data_size = footer_offset - member.compressed_data_offset;
+        member.uncompressed_data_size = media_offset - member.media_offset;
+
+        let mut footer_data: [u8; 8] = [0; 8];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut footer_data,
+            SeekFrom::Start(footer_offset)
+        );
+        let mut member_footer: GzipMemberFooter = GzipMemberFooter::new();
+
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "GzipMemberFooter data of size: 8 at offset: {} (0x{:08x})\n",
+                footer_offset, footer_offset
+            ));
+            self.mediator.debug_print_data(&footer_data, true);
+            self.mediator
+                .debug_print(GzipMemberFooter::debug_read_data(&footer_data));
+        }
+        match member_footer.read_data(&footer_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read member footer");
+                return Err(error);
+            }
+        }
+        let calculated_checksum: u32 = crc32_context.finalize();
+
+        if member_footer.checksum != calculated_checksum {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: 0x{:08x} and calculated: 0x{:08x} checksums",
+                member_footer.checksum, calculated_checksum
+            )));
+        }
+        // The stored uncompressed data size is the size modulo 2^32.
+        let calculated_uncompressed_data_size: u32 = member.uncompressed_data_size as u32;
+
+        if member_footer.uncompressed_data_size != calculated_uncompressed_data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between stored: {} and calculated: {} uncompressed data sizes",
+                member_footer.uncompressed_data_size, calculated_uncompressed_data_size
+            )));
+        }
+        member.checksum = member_footer.checksum;
+
+        Ok(())
+    }
+
+    /// Reads a zero-terminated ISO 8859-1 string.
+    fn read_string(
+        &self,
+        data_stream: &DataStreamReference,
+        data_offset: &mut u64,
+        header_data: &mut Vec<u8>,
+    ) -> Result<ByteString, ErrorTrace> {
+        let mut data: [u8; 256] = [0; 256];
+        let mut string_data: Vec<u8> = Vec::new();
+
+        loop {
+            let read_count: usize = keramics_core::data_stream_read_at_position!(
+                data_stream,
+                &mut data,
+                SeekFrom::Start(*data_offset + string_data.len() as u64)
+            );
+            if read_count == 0 {
+                return Err(keramics_core::error_trace_new!(
+                    "Missing end-of-string character"
+                ));
+            }
+            match data[..read_count].iter().position(|byte| *byte == 0) {
+                Some(data_index) => {
+                    string_data.extend_from_slice(&data[..data_index + 1]);
+                    break;
+                }
+                None => string_data.extend_from_slice(&data[..read_count]),
+            }
+            if string_data.len() > GZIP_MAXIMUM_STRING_SIZE {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid string size value exceeds maximum: {}",
+                    GZIP_MAXIMUM_STRING_SIZE
+                )));
+            }
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "GzipString data of size: {} at offset: {} (0x{:08x})\n",
+                string_data.len(),
+                *data_offset,
+                *data_offset
+            ));
+            self.mediator.debug_print_data(&string_data, true);
+        }
+        header_data.extend_from_slice(&string_data);
+
+        *data_offset += string_data.len() as u64;
+
+        let mut byte_string: ByteString =
+            ByteString::new_with_encoding(&CharacterEncoding::Iso8859_1);
+        byte_string.read_data(&string_data);
+
+        Ok(byte_string)
+    }
+
+    /// Retrieves the index of the seek point that precedes a specific media offset.
+    fn get_seek_point_index(&self, media_offset: u64) -> Result<usize, ErrorTrace> {
+        let number_of_seek_points: usize = self
+            .seek_points
+            .partition_point(|seek_point| seek_point.media_offset <= media_offset);
+
+        if number_of_seek_points == 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Missing seek point for media offset: {} (0x{:08x})",
+                media_offset, media_offset
+            )));
+        }
+        Ok(number_of_seek_points - 1)
+    }
+
+    /// Resumes decompression at a specific seek point.
+    fn resume_at_seek_point(&mut self, seek_point_index: usize) {
+        let seek_point: &GzipSeekPoint = &self.seek_points[seek_point_index];
+
+        self.stream_context = Some(DeflateStreamContext::new_at_block_boundary(
+            seek_point.bit_offset,
+            &seek_point.window,
+        ));
+        self.stream_member_index = seek_point.member_index;
+        self.stream_compressed_data_offset = seek_point.compressed_data_offset;
+        self.uncompressed_data.clear();
+        self.uncompressed_data_media_offset = seek_point.media_offset;
+    }
+
+    /// Decompresses the next chunk of compressed data.
+    fn decompress_next_chunk(&mut self) -> Result<(), ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let stream_context: &mut DeflateStreamContext = match self.stream_context.as_mut() {
+            Some(stream_context) => stream_context,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing stream context"));
+            }
+        };
+        self.uncompressed_data_media_offset += self.uncompressed_data.len() as u64;
+        self.uncompressed_data.clear();
+
+        if stream_context.is_end_of_stream() {
+            self.stream_member_index += 1;
+
+            let member: &GzipMember = match self.members.get(self.stream_member_index) {
+                Some(member) => member,
+                None => {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Missing member: {}",
+                        self.stream_member_index
+                    )));
+                }
+            };
+            *stream_context = DeflateStreamContext::new();
+            self.stream_compressed_data_offset = member.compressed_data_offset;
+
+            return Ok(());
+        }
+        let member: &GzipMember = &self.members[self.stream_member_index];
+
+        // Include the member footer since the end of the compressed stream can only be
+        // determined when data that follows it is available.
+        let member_end_offset: u64 =
+            member.compressed_data_offset + member.compressed_data_size + 8;
+
+        if self.stream_compressed_data_offset >= member_end_offset {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        let read_size: usize = std::cmp::min(
+            member_end_offset - self.stream_compressed_data_offset,
+            GZIP_READ_BUFFER_SIZE as u64,
+        ) as usize;
+
+        let mut compressed_data: Vec<u8> = vec![0; read_size];
+
+        let read_count: usize = keramics_core::data_stream_read_at_position!(
+            data_stream,
+            &mut compressed_data,
+            SeekFrom::Start(self.stream_compressed_data_offset)
+        );
+        if read_count == 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid compressed data value too small"
+            ));
+        }
+        self.stream_compressed_data_offset += read_count as u64;
+
+        match stream_context
+            .decompress_chunk(&compressed_data[..read_count], &mut self.uncompressed_data)
+        {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to decompress data");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads media data based on the members and seek points.
+    fn read_data_from_members(&mut self, data: &mut [u8]) -> Result<usize, ErrorTrace> {
+        let read_size: usize = data.len();
+        let mut data_offset: usize = 0;
+        let mut media_offset: u64 = self.media_offset;
+
+        while data_offset < read_size {
+            if media_offset >= self.media_size {
+                break;
+            }
+            let uncompressed_data_size: usize = self.uncompressed_data.len();
+            let uncompressed_data_end_offset: u64 =
+                self.uncompressed_data_media_offset + uncompressed_data_size as u64;
+
+            if media_offset >= self.uncompressed_data_media_offset
+                && media_offset < uncompressed_data_end_offset
+            {
+                let uncompressed_data_offset: usize =
+                    (media_offset - self.uncompressed_data_media_offset) as usize;
+                let copy_size: usize = std::cmp::min(
+                    read_size - data_offset,
+                    uncompressed_data_size - uncompressed_data_offset,
+                );
+                let data_end_offset: usize = data_offset + copy_size;
+
+                data[data_offset..data_end_offset].copy_from_slice(
+                    &self.uncompressed_data
+                        [uncompressed_data_offset..uncompressed_data_offset + copy_size],
+                );
+                data_offset = data_end_offset;
+                media_offset += copy_size as u64;
+
+                continue;
+            }
+            let seek_point_index: usize = self.get_seek_point_index(media_offset)?;
+
+            // Resume decompression at a seek point if the media offset precedes the most
+            // recently uncompressed data or if a seek point is closer to the media offset.
+            if self.stream_context.is_none()
+                || media_offset < self.uncompressed_data_media_offset
+                || self.seek_points[seek_point_index].media_offset > uncompressed_data_end_offset
+            {
+                self.resume_at_seek_point(seek_point_index);
+            }
+            match self.decompress_next_chunk() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decompress next chunk");
+                    return Err(error);
+                }
+            }
+        }
+        Ok(data_offset)
+    }
+}
+
+impl DataStream for GzipFile {
+    /// Retrieves the size of the data.
+    fn get_size(&mut self) -> Result<u64, ErrorTrace> {
+        Ok(self.media_size)
+    }
+
+    /// Reads data at the current position.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, ErrorTrace> {
+        if self.media_offset >= self.media_size {
+            return Ok(0);
+        }
+        let remaining_media_size: u64 = self.media_size - self.media_offset;
+        let mut read_size: usize = buf.len();
+
+        if (read_size as u64) > remaining_media_size {
+            read_size = remaining_media_size as usize;
+        }
+        let read_count: usize = match self.read_data_from_members(&mut buf[..read_size]) {
+            Ok(read_count) => read_count,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read data from members");
+                return Err(error);
+            }
+        };
+        self.media_offset += read_count as u64;
+
+        Ok(read_count)
+    }
+
+    /// Sets the current position of the data.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ErrorTrace> {
+        self.media_offset = match pos {
+            SeekFrom::Current(relative_offset) => {
+                let mut current_offset: i64 = self.media_offset as i64;
+                current_offset += relative_offset;
+                current_offset as u64
+            }
+            SeekFrom::End(relative_offset) => {
+                let mut end_offset: i64 = self.media_size as i64;
+                end_offset += relative_offset;
+                end_offset as u64
+            }
+            SeekFrom::Start(offset) => offset,
+        };
+        Ok(self.media_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    use keramics_core::{open_fake_data_stream, open_os_data_stream};
+    use keramics_datetime::PosixTime32;
+
+    use crate::tests::get_test_data_path;
+
+    fn get_file() -> Result<GzipFile, ErrorTrace> {
+        let mut file: GzipFile = GzipFile::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+        file.read_data_stream(&data_stream)?;
+
+        Ok(file)
+    }
+
+    #[test]
+    fn test_get_number_of_members() -> Result<(), ErrorTrace> {
+        let file: GzipFile = get_file()?;
+
+        assert_eq!(file.get_number_of_members(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_member_by_index() -> Result<(), ErrorTrace> {
+        let file: GzipFile = get_file()?;
+
+        let member: &GzipMember = file.get_member_by_index(0)?;
+        assert_eq!(
+            member
+                .name
+                .as_ref()
+                .map(|byte_string| byte_string.to_string()),
+            Some(String::from("ext2.raw"))
+        );
+        assert_eq!(member.comment, None);
+        assert_eq!(
+            member.modification_time,
+            DateTime::PosixTime32(PosixTime32::new(1761998400))
+        );
+        assert_eq!(member.operating_system, 3);
+        assert_eq!(member.compressed_data_offset, 19);
+        assert_eq!(member.media_offset, 0);
+        assert_eq!(member.uncompressed_data_size, 4194304);
+        assert_eq!(member.checksum, 0x51ced35a);
+
+        let result: Result<&GzipMember, ErrorTrace> = file.get_member_by_index(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = GzipFile::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+        file.read_data_stream(&data_stream)?;
+
+        assert_eq!(file.media_size, 4194304);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_multiple_members() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = GzipFile::new();
+
+        let path_buf: PathBuf =
+            PathBuf::from(get_test_data_path("gzip/ext2_multi_member.raw.gz").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+        file.read_data_stream(&data_stream)?;
+
+        assert_eq!(file.media_size, 4194304);
+        assert_eq!(file.get_number_of_members(), 2);
+
+        let member: &GzipMember = file.get_member_by_index(1)?;
+        assert_eq!(member.name, None);
+        assert_eq!(member.media_offset, 2097152);
+        assert_eq!(member.uncompressed_data_size, 2097152);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_member_header() -> Result<(), ErrorTrace> {
+        let file: GzipFile = GzipFile::new();
+
+        let test_data: Vec<u8> = vec![
+            0x1f, 0x8b, 0x08, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x02, 0x00, 0x41, 0x42,
+            0x74, 0x65, 0x73, 0x74, 0x00, 0x4b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73, 0x00,
+            0x68, 0x97,
+        ];
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut member: GzipMember = GzipMember::new();
+        file.read_member_header(&data_stream, 0, &mut member)?;
+
+        assert_eq!(
+            member
+                .name
+                .as_ref()
+                .map(|byte_string| byte_string.to_string()),
+            Some(String::from("test"))
+        );
+        assert_eq!(
+            member
+                .comment
+                .as_ref()
+                .map(|byte_string| byte_string.to_string()),
+            Some(String::from("Keramics"))
+        );
+        assert_eq!(member.modification_time, DateTime::NotSet);
+        assert_eq!(member.operating_system, 255);
+        assert_eq!(member.compressed_data_offset, 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_start() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+
+        let offset: u64 = file.seek(SeekFrom::Start(1024))?;
+        assert_eq!(offset, 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_end() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+
+        let offset: u64 = file.seek(SeekFrom::End(-512))?;
+        assert_eq!(offset, file.media_size - 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_current() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+
+        let offset = file.seek(SeekFrom::Start(1024))?;
+        assert_eq!(offset, 1024);
+
+        let offset: u64 = file.seek(SeekFrom::Current(-512))?;
+        assert_eq!(offset, 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_beyond_media_size() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+
+        let offset: u64 = file.seek(SeekFrom::End(512))?;
+        assert_eq!(offset, file.media_size + 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+        file.seek(SeekFrom::Start(1024))?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        let read_size: usize = file.read(&mut data)?;
+        assert_eq!(read_size, 512);
+
+        let expected_data: Vec<u8> = vec![
+            0x00, 0x04, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0xcc, 0x00, 0x00, 0x00, 0x43, 0x0f,
+            0x00, 0x00, 0xe3, 0x03, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x04,
+            0x00, 0x00, 0x0a, 0xea, 0x78, 0x67, 0x0a, 0xea, 0x78, 0x67, 0x02, 0x00, 0xff, 0xff,
+            0x53, 0xef, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0xea, 0x78, 0x67, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x0b, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x57, 0x1e, 0x25, 0x97, 0x42, 0xa1, 0x4d, 0x6a,
+            0xad, 0xa9, 0xcd, 0xb1, 0x19, 0x1b, 0x5d, 0xea, 0x65, 0x78, 0x74, 0x32, 0x5f, 0x74,
+            0x65, 0x73, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2f, 0x6d, 0x6e, 0x74,
+            0x2f, 0x6b, 0x65, 0x72, 0x61, 0x6d, 0x69, 0x63, 0x73, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x43,
+            0x11, 0xae, 0xbe, 0xdb, 0x40, 0x41, 0xa4, 0xb6, 0xf5, 0x6b, 0x15, 0x34, 0xd6, 0x66,
+            0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0xea,
+            0x78, 0x67, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2e, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(data, expected_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read_beyond_media_size() -> Result<(), ErrorTrace> {
+        let mut file: GzipFile = get_file()?;
+        file.seek(SeekFrom::End(512))?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        let read_size: usize = file.read(&mut data)?;
+        assert_eq!(read_size, 0);
+
+        Ok(())
+    }
+
+    // TODO: add tests for get_size.
+}
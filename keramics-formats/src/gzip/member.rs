@@ -0,0 +1,62 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_datetime::DateTime;
+use keramics_types::ByteString;
+
+/// gzip member.
+pub struct GzipMember {
+    /// Original file name.
+    pub name: Option<ByteString>,
+
+    /// Comment.
+    pub comment: Option<ByteString>,
+
+    /// Modification date and time.
+    pub modification_time: DateTime,
+
+    /// Operating system.
+    pub operating_system: u8,
+
+    /// Offset of the compressed data.
+    pub compressed_data_offset: u64,
+
+    /// Size of the compressed data.
+    pub compressed_data_size: u64,
+
+    /// Media offset of the uncompressed data.
+    pub media_offset: u64,
+
+    /// Size of the uncompressed data.
+    pub uncompressed_data_size: u64,
+
+    /// Checksum (CRC-32) of the uncompressed data.
+    pub checksum: u32,
+}
+
+impl GzipMember {
+    /// Creates a new member.
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            comment: None,
+            modification_time: DateTime::NotSet,
+            operating_system: 0,
+            compressed_data_offset: 0,
+            compressed_data_size: 0,
+            media_offset: 0,
+            uncompressed_data_size: 0,
+            checksum: 0,
+        }
+    }
+}
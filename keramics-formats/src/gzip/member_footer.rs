@@ -0,0 +1,88 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::bytes_to_u32_le;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "checksum", data_type = "u32", format = "hex"),
+        field(name = "uncompressed_data_size", data_type = "u32"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// gzip member footer.
+pub struct GzipMemberFooter {
+    /// Checksum (CRC-32) of the uncompressed data.
+    pub checksum: u32,
+
+    /// Uncompressed data size modulo 2^32.
+    pub uncompressed_data_size: u32,
+}
+
+impl GzipMemberFooter {
+    /// Creates a new member footer.
+    pub fn new() -> Self {
+        Self {
+            checksum: 0,
+            uncompressed_data_size: 0,
+        }
+    }
+
+    /// Reads the member footer from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported gzip member footer data size"
+            ));
+        }
+        self.checksum = bytes_to_u32_le!(data, 0);
+        self.uncompressed_data_size = bytes_to_u32_le!(data, 4);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![0x5a, 0xd3, 0xce, 0x51, 0x00, 0x00, 0x40, 0x00];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = GzipMemberFooter::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.checksum, 0x51ced35a);
+        assert_eq!(test_struct.uncompressed_data_size, 4194304);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = GzipMemberFooter::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..7]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,147 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_datetime::PosixTime32;
+use keramics_layout_map::LayoutMap;
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "signature", data_type = "[u8; 2]", format = "hex"),
+        field(name = "compression_method", data_type = "u8"),
+        field(name = "flags", data_type = "u8", format = "hex"),
+        field(name = "modification_time", data_type = "PosixTime32"),
+        field(name = "extra_flags", data_type = "u8", format = "hex"),
+        field(name = "operating_system", data_type = "u8"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// gzip member header.
+pub struct GzipMemberHeader {
+    /// Flags.
+    pub flags: u8,
+
+    /// Modification date and time.
+    pub modification_time: PosixTime32,
+
+    /// Operating system.
+    pub operating_system: u8,
+}
+
+impl GzipMemberHeader {
+    /// Creates a new member header.
+    pub fn new() -> Self {
+        Self {
+            flags: 0,
+            modification_time: PosixTime32::new(0),
+            operating_system: 0,
+        }
+    }
+
+    /// Reads the member header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 10 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported gzip member header data size"
+            ));
+        }
+        if data[0..2] != GZIP_MEMBER_HEADER_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported gzip member header signature"
+            ));
+        }
+        if data[2] != GZIP_COMPRESSION_METHOD_DEFLATE {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported compression method: {}",
+                data[2]
+            )));
+        }
+        self.flags = data[3];
+        self.modification_time = PosixTime32::from_le_bytes(&data[4..8]);
+        self.operating_system = data[9];
+
+        if self.flags & GZIP_RESERVED_FLAGS != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported flags: 0x{:02x}",
+                self.flags
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![0x1f, 0x8b, 0x08, 0x08, 0x40, 0xf6, 0x05, 0x69, 0x00, 0x03];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = GzipMemberHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.flags, 0x08);
+        assert_eq!(test_struct.modification_time, PosixTime32::new(1761998400));
+        assert_eq!(test_struct.operating_system, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = GzipMemberHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..9]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = GzipMemberHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_compression_method() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[2] = 0x07;
+
+        let mut test_struct = GzipMemberHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_flags() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[3] = 0x20;
+
+        let mut test_struct = GzipMemberHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
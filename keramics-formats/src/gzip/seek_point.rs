@@ -0,0 +1,51 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// gzip seek point.
+///
+/// A seek point contains the state needed to resume decompression at a deflate block boundary.
+pub(super) struct GzipSeekPoint {
+    /// Index of the member.
+    pub member_index: usize,
+
+    /// Offset of the compressed data.
+    pub compressed_data_offset: u64,
+
+    /// Number of bits of the first byte of the compressed data that precede the block.
+    pub bit_offset: usize,
+
+    /// Media offset of the uncompressed data.
+    pub media_offset: u64,
+
+    /// Sliding window with the uncompressed data that precedes the block.
+    pub window: Vec<u8>,
+}
+
+impl GzipSeekPoint {
+    /// Creates a new seek point.
+    pub fn new(
+        member_index: usize,
+        compressed_data_offset: u64,
+        bit_offset: usize,
+        media_offset: u64,
+        window: &[u8],
+    ) -> Self {
+        Self {
+            member_index: member_index,
+            compressed_data_offset: compressed_data_offset,
+            bit_offset: bit_offset,
+            media_offset: media_offset,
+            window: window.to_vec(),
+        }
+    }
+}
@@ -25,6 +25,9 @@ mod scanner;
 // Data formats used in other formats.
 mod plist;
 
+// Compressed file formats
+pub mod gzip;
+
 // Storage media image formats
 pub mod ewf;
 pub mod qcow;
@@ -38,6 +41,7 @@ pub mod vhdx;
 pub mod apm;
 pub mod gpt;
 pub mod mbr;
+pub mod vss;
 
 // File system formats
 pub mod ext;
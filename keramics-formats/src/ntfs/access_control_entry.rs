@@ -0,0 +1,201 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{Uuid, bytes_to_u16_le, bytes_to_u32_le};
+
+use super::security_identifier::NtfsSecurityIdentifier;
+
+/// New Technologies File System (NTFS) Windows NT access control entry (ACE).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsAccessControlEntry {
+    /// Entry type.
+    pub entry_type: u8,
+
+    /// Flags.
+    pub flags: u8,
+
+    /// Access rights flags (or access mask).
+    pub access_mask: u32,
+
+    /// Object flags, which are only used by object entry types.
+    pub object_flags: u32,
+
+    /// Object type.
+    pub object_type: Option<Uuid>,
+
+    /// Inherited object type.
+    pub inherited_object_type: Option<Uuid>,
+
+    /// Security identifier (SID).
+    pub security_identifier: Option<NtfsSecurityIdentifier>,
+}
+
+impl NtfsAccessControlEntry {
+    /// Creates a new access control entry.
+    pub fn new() -> Self {
+        Self {
+            entry_type: 0,
+            flags: 0,
+            access_mask: 0,
+            object_flags: 0,
+            object_type: None,
+            inherited_object_type: None,
+            security_identifier: None,
+        }
+    }
+
+    /// Reads the access control entry from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 4 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS access control entry data size"
+            ));
+        }
+        let entry_size: usize = bytes_to_u16_le!(data, 2) as usize;
+
+        if entry_size < 4 || entry_size > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid access control entry size: {} value out of bounds",
+                entry_size
+            )));
+        }
+        self.entry_type = data[0];
+        self.flags = data[1];
+
+        let mut data_offset: usize = match self.entry_type {
+            0x00..=0x03 | 0x09 | 0x0a | 0x0d | 0x0e | 0x11..=0x14 => 8,
+            0x05..=0x08 | 0x0b | 0x0c | 0x0f | 0x10 => 12,
+            // Other entry types, such as the compound entry, are not supported.
+            _ => return Ok(entry_size),
+        };
+        if data_offset > entry_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid access control entry size: {} value too small",
+                entry_size
+            )));
+        }
+        self.access_mask = bytes_to_u32_le!(data, 4);
+
+        if data_offset == 12 {
+            self.object_flags = bytes_to_u32_le!(data, 8);
+
+            // ACE_OBJECT_TYPE_PRESENT
+            if self.object_flags & 0x00000001 != 0 {
+                if data_offset + 16 > entry_size {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid object type value out of bounds"
+                    ));
+                }
+                self.object_type = Some(Uuid::from_le_bytes(&data[data_offset..]));
+                data_offset += 16;
+            }
+            // ACE_INHERITED_OBJECT_TYPE_PRESENT
+            if self.object_flags & 0x00000002 != 0 {
+                if data_offset + 16 > entry_size {
+                    return Err(keramics_core::error_trace_new!(
+                        "Invalid inherited object type value out of bounds"
+                    ));
+                }
+                self.inherited_object_type = Some(Uuid::from_le_bytes(&data[data_offset..]));
+                data_offset += 16;
+            }
+        }
+        let mut security_identifier: NtfsSecurityIdentifier = NtfsSecurityIdentifier::new();
+
+        match security_identifier.read_data(&data[data_offset..entry_size]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read security identifier");
+                return Err(error);
+            }
+        }
+        self.security_identifier = Some(security_identifier);
+
+        Ok(entry_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x03, 0x14, 0x00, 0xff, 0x01, 0x1f, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x05, 0x12, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsAccessControlEntry::new();
+        let entry_size: usize = test_struct.read_data(&test_data)?;
+        assert_eq!(entry_size, 20);
+
+        assert_eq!(test_struct.entry_type, 0);
+        assert_eq!(test_struct.flags, 0x03);
+        assert_eq!(test_struct.access_mask, 0x001f01ff);
+        assert_eq!(test_struct.object_type, None);
+
+        let security_identifier: &NtfsSecurityIdentifier =
+            test_struct.security_identifier.as_ref().unwrap();
+        assert_eq!(security_identifier.to_string(), "S-1-5-18");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_object_entry_type() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = vec![
+            0x05, 0x00, 0x28, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+            0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x0b, 0x00, 0x00, 0x00,
+        ];
+
+        let mut test_struct = NtfsAccessControlEntry::new();
+        let entry_size: usize = test_struct.read_data(&test_data)?;
+        assert_eq!(entry_size, 40);
+
+        assert_eq!(test_struct.entry_type, 5);
+        assert_eq!(test_struct.access_mask, 0x00000100);
+        assert_eq!(test_struct.object_flags, 0x00000001);
+        assert_eq!(
+            test_struct.object_type.as_ref().unwrap().to_string(),
+            "03020100-0504-0706-0809-0a0b0c0d0e0f"
+        );
+        assert_eq!(test_struct.inherited_object_type, None);
+
+        let security_identifier: &NtfsSecurityIdentifier =
+            test_struct.security_identifier.as_ref().unwrap();
+        assert_eq!(security_identifier.to_string(), "S-1-5-11");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsAccessControlEntry::new();
+        let result = test_struct.read_data(&test_data[0..3]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..16]);
+        assert!(result.is_err());
+    }
+}
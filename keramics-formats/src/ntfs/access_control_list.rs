@@ -0,0 +1,123 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::bytes_to_u16_le;
+
+use super::access_control_entry::NtfsAccessControlEntry;
+
+/// New Technologies File System (NTFS) Windows NT access control list (ACL).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsAccessControlList {
+    /// Revision number.
+    pub revision_number: u8,
+
+    /// Entries.
+    pub entries: Vec<NtfsAccessControlEntry>,
+}
+
+impl NtfsAccessControlList {
+    /// Creates a new access control list.
+    pub fn new() -> Self {
+        Self {
+            revision_number: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads the access control list from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS access control list data size"
+            ));
+        }
+        let list_size: usize = bytes_to_u16_le!(data, 2) as usize;
+
+        if list_size < 8 || list_size > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid access control list size: {} value out of bounds",
+                list_size
+            )));
+        }
+        let number_of_entries: u16 = bytes_to_u16_le!(data, 4);
+
+        self.revision_number = data[0];
+        self.entries.clear();
+
+        let mut data_offset: usize = 8;
+
+        for entry_index in 0..number_of_entries {
+            let mut access_control_entry: NtfsAccessControlEntry = NtfsAccessControlEntry::new();
+
+            let entry_size: usize =
+                match access_control_entry.read_data(&data[data_offset..list_size]) {
+                    Ok(entry_size) => entry_size,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to read access control entry: {}", entry_index)
+                        );
+                        return Err(error);
+                    }
+                };
+            self.entries.push(access_control_entry);
+
+            data_offset += entry_size;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x02, 0x00, 0x34, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0x14, 0x00, 0xff, 0x01,
+            0x1f, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x12, 0x00, 0x00, 0x00,
+            0x00, 0x03, 0x18, 0x00, 0xa9, 0x00, 0x12, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x05, 0x20, 0x00, 0x00, 0x00, 0x21, 0x02, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsAccessControlList::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.revision_number, 2);
+        assert_eq!(test_struct.entries.len(), 2);
+        assert_eq!(test_struct.entries[0].access_mask, 0x001f01ff);
+        assert_eq!(test_struct.entries[1].access_mask, 0x001200a9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsAccessControlList::new();
+        let result = test_struct.read_data(&test_data[0..7]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..40]);
+        assert!(result.is_err());
+    }
+}
@@ -15,10 +15,16 @@ use keramics_types::Ucs2String;
 
 use super::attribute_list::NtfsAttributeList;
 use super::constants::*;
+use super::efs_information::NtfsEfsInformation;
+use super::extended_attributes::NtfsExtendedAttributes;
+use super::extended_attributes_information::NtfsExtendedAttributesInformation;
 use super::file_name::NtfsFileName;
 use super::mft_attribute::NtfsMftAttribute;
+use super::object_identifier::NtfsObjectIdentifier;
 use super::reparse_point::NtfsReparsePoint;
+use super::security_descriptor::NtfsSecurityDescriptor;
 use super::standard_information::NtfsStandardInformation;
+use super::transactional_data::NtfsTransactionalData;
 use super::volume_information::NtfsVolumeInformation;
 
 /// New Technologies File System (NTFS) attribute.
@@ -26,18 +32,36 @@ pub enum NtfsAttribute<'a> {
     AttributeList {
         attribute_list: NtfsAttributeList,
     },
+    EfsInformation {
+        efs_information: NtfsEfsInformation,
+    },
+    ExtendedAttributes {
+        extended_attributes: NtfsExtendedAttributes,
+    },
+    ExtendedAttributesInformation {
+        extended_attributes_information: NtfsExtendedAttributesInformation,
+    },
     FileName {
         file_name: NtfsFileName,
     },
     Generic {
         mft_attribute: &'a NtfsMftAttribute,
     },
+    ObjectIdentifier {
+        object_identifier: NtfsObjectIdentifier,
+    },
     ReparsePoint {
         reparse_point: NtfsReparsePoint,
     },
+    SecurityDescriptor {
+        security_descriptor: NtfsSecurityDescriptor,
+    },
     StandardInformation {
         standard_information: NtfsStandardInformation,
     },
+    TransactionalData {
+        transactional_data: NtfsTransactionalData,
+    },
     VolumeInformation {
         volume_information: NtfsVolumeInformation,
     },
@@ -51,10 +75,18 @@ impl<'a> NtfsAttribute<'a> {
     pub fn get_attribute_type(&self) -> u32 {
         match self {
             NtfsAttribute::AttributeList { .. } => NTFS_ATTRIBUTE_TYPE_ATTRIBUTE_LIST,
+            NtfsAttribute::EfsInformation { .. } => NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM,
+            NtfsAttribute::ExtendedAttributes { .. } => NTFS_ATTRIBUTE_TYPE_EXTENDED,
+            NtfsAttribute::ExtendedAttributesInformation { .. } => {
+                NTFS_ATTRIBUTE_TYPE_EXTENDED_INFORMATION
+            }
             NtfsAttribute::FileName { .. } => NTFS_ATTRIBUTE_TYPE_FILE_NAME,
             NtfsAttribute::Generic { mft_attribute } => mft_attribute.attribute_type,
+            NtfsAttribute::ObjectIdentifier { .. } => NTFS_ATTRIBUTE_TYPE_OBJECT_IDENTIFIER,
             NtfsAttribute::ReparsePoint { .. } => NTFS_ATTRIBUTE_TYPE_REPARSE_POINT,
+            NtfsAttribute::SecurityDescriptor { .. } => NTFS_ATTRIBUTE_TYPE_SECURITY_DESCRIPTOR,
             NtfsAttribute::StandardInformation { .. } => NTFS_ATTRIBUTE_TYPE_STANDARD_INFORMATION,
+            NtfsAttribute::TransactionalData { .. } => NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM,
             NtfsAttribute::VolumeInformation { .. } => NTFS_ATTRIBUTE_TYPE_VOLUME_INFORMATION,
             NtfsAttribute::VolumeName { .. } => NTFS_ATTRIBUTE_TYPE_VOLUME_NAME,
         }
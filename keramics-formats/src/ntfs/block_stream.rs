@@ -67,6 +67,12 @@ impl NtfsBlockStream {
                 "Unsupported resident $DATA attribute"
             ));
         }
+        // Note that the cluster block size is 0 for a standalone $MFT.
+        if self.cluster_block_size == 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Missing cluster block size, non-resident data is not available"
+            ));
+        }
         let block_tree_size: u64 = data_attribute
             .allocated_data_size
             .div_ceil(self.cluster_block_size as u64)
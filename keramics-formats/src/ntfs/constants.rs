@@ -24,6 +24,21 @@ pub(super) const NTFS_BAD_MFT_ENTRY_SIGNATURE: [u8; 4] = [0x42, 0x41, 0x41, 0x44
 /// NTFS index entry signature: "INDX".
 pub(super) const NTFS_INDEX_ENTRY_SIGNATURE: [u8; 4] = [0x49, 0x4e, 0x44, 0x58];
 
+/// NTFS log file restart page signature: "RSTR".
+pub(super) const NTFS_LOG_FILE_RESTART_PAGE_SIGNATURE: [u8; 4] = [0x52, 0x53, 0x54, 0x52];
+
+/// NTFS log file checkdisk restart page signature: "CHKD".
+pub(super) const NTFS_LOG_FILE_CHKDSK_PAGE_SIGNATURE: [u8; 4] = [0x43, 0x48, 0x4b, 0x44];
+
+/// NTFS log file record page signature: "RCRD".
+pub(super) const NTFS_LOG_FILE_RECORD_PAGE_SIGNATURE: [u8; 4] = [0x52, 0x43, 0x52, 0x44];
+
+/// NTFS log file number of tail copy pages that succeed the restart pages.
+pub(super) const NTFS_LOG_FILE_NUMBER_OF_TAIL_COPY_PAGES: u64 = 2;
+
+/// NTFS MFT entry flag to indicate the entry is in use (allocated).
+pub(super) const NTFS_MFT_ENTRY_FLAG_IN_USE: u16 = 0x0001;
+
 /// NTFS $STANDARD_INFORMATION attribute type.
 pub const NTFS_ATTRIBUTE_TYPE_STANDARD_INFORMATION: u32 = 0x00000010;
 
@@ -98,3 +113,150 @@ pub const NTFS_REPARSE_POINT_TAG_SYMBOLIC_LINK: u32 = 0xa000000c;
 
 /// NTFS Windows Overlay Filter (WOF) reparse point tag.
 pub const NTFS_REPARSE_POINT_TAG_WOF: u32 = 0x80000017;
+
+/// NTFS USN change journal block size.
+pub(super) const NTFS_USN_JOURNAL_BLOCK_SIZE: u64 = 4096;
+
+/// NTFS secure file ("$Secure") identifier (MFT entry number).
+pub(super) const NTFS_SECURE_FILE_IDENTIFIER: u64 = 9;
+
+/// NTFS access allowed access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_ACCESS_ALLOWED: u8 = 0x00;
+
+/// NTFS access denied access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_ACCESS_DENIED: u8 = 0x01;
+
+/// NTFS system audit access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_SYSTEM_AUDIT: u8 = 0x02;
+
+/// NTFS system alarm access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_SYSTEM_ALARM: u8 = 0x03;
+
+/// NTFS access allowed object access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_ACCESS_ALLOWED_OBJECT: u8 = 0x05;
+
+/// NTFS access denied object access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_ACCESS_DENIED_OBJECT: u8 = 0x06;
+
+/// NTFS system audit object access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_SYSTEM_AUDIT_OBJECT: u8 = 0x07;
+
+/// NTFS system alarm object access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_SYSTEM_ALARM_OBJECT: u8 = 0x08;
+
+/// NTFS system mandatory label access control entry (ACE) type.
+pub const NTFS_ACE_TYPE_SYSTEM_MANDATORY_LABEL: u8 = 0x11;
+
+/// NTFS log file ("$LogFile") identifier (MFT entry number).
+pub(super) const NTFS_LOG_FILE_IDENTIFIER: u64 = 2;
+
+/// NTFS log file client record type.
+pub const NTFS_LOG_FILE_RECORD_TYPE_CLIENT_RECORD: u32 = 1;
+
+/// NTFS log file client restart record type.
+pub const NTFS_LOG_FILE_RECORD_TYPE_CLIENT_RESTART: u32 = 2;
+
+/// NTFS log file no operation operation.
+pub const NTFS_LOG_FILE_OPERATION_NOOP: u16 = 0x0000;
+
+/// NTFS log file compensation log record operation.
+pub const NTFS_LOG_FILE_OPERATION_COMPENSATION_LOG_RECORD: u16 = 0x0001;
+
+/// NTFS log file initialize file record segment (MFT entry) operation.
+pub const NTFS_LOG_FILE_OPERATION_INITIALIZE_FILE_RECORD_SEGMENT: u16 = 0x0002;
+
+/// NTFS log file deallocate file record segment (MFT entry) operation.
+pub const NTFS_LOG_FILE_OPERATION_DEALLOCATE_FILE_RECORD_SEGMENT: u16 = 0x0003;
+
+/// NTFS log file write end of file record segment (MFT entry) operation.
+pub const NTFS_LOG_FILE_OPERATION_WRITE_END_OF_FILE_RECORD_SEGMENT: u16 = 0x0004;
+
+/// NTFS log file create attribute operation.
+pub const NTFS_LOG_FILE_OPERATION_CREATE_ATTRIBUTE: u16 = 0x0005;
+
+/// NTFS log file delete attribute operation.
+pub const NTFS_LOG_FILE_OPERATION_DELETE_ATTRIBUTE: u16 = 0x0006;
+
+/// NTFS log file update resident value operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_RESIDENT_VALUE: u16 = 0x0007;
+
+/// NTFS log file update non-resident value operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_NON_RESIDENT_VALUE: u16 = 0x0008;
+
+/// NTFS log file update mapping pairs (data runs) operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_MAPPING_PAIRS: u16 = 0x0009;
+
+/// NTFS log file delete dirty clusters operation.
+pub const NTFS_LOG_FILE_OPERATION_DELETE_DIRTY_CLUSTERS: u16 = 0x000a;
+
+/// NTFS log file set new attribute sizes operation.
+pub const NTFS_LOG_FILE_OPERATION_SET_NEW_ATTRIBUTE_SIZES: u16 = 0x000b;
+
+/// NTFS log file add index entry to index root operation.
+pub const NTFS_LOG_FILE_OPERATION_ADD_INDEX_ENTRY_ROOT: u16 = 0x000c;
+
+/// NTFS log file delete index entry from index root operation.
+pub const NTFS_LOG_FILE_OPERATION_DELETE_INDEX_ENTRY_ROOT: u16 = 0x000d;
+
+/// NTFS log file add index entry to index allocation operation.
+pub const NTFS_LOG_FILE_OPERATION_ADD_INDEX_ENTRY_ALLOCATION: u16 = 0x000e;
+
+/// NTFS log file delete index entry from index allocation operation.
+pub const NTFS_LOG_FILE_OPERATION_DELETE_INDEX_ENTRY_ALLOCATION: u16 = 0x000f;
+
+/// NTFS log file write end of index buffer operation.
+pub const NTFS_LOG_FILE_OPERATION_WRITE_END_OF_INDEX_BUFFER: u16 = 0x0010;
+
+/// NTFS log file set index entry VCN in index root operation.
+pub const NTFS_LOG_FILE_OPERATION_SET_INDEX_ENTRY_VCN_ROOT: u16 = 0x0011;
+
+/// NTFS log file set index entry VCN in index allocation operation.
+pub const NTFS_LOG_FILE_OPERATION_SET_INDEX_ENTRY_VCN_ALLOCATION: u16 = 0x0012;
+
+/// NTFS log file update file name in index root operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_FILE_NAME_ROOT: u16 = 0x0013;
+
+/// NTFS log file update file name in index allocation operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_FILE_NAME_ALLOCATION: u16 = 0x0014;
+
+/// NTFS log file set bits in non-resident bitmap operation.
+pub const NTFS_LOG_FILE_OPERATION_SET_BITS_IN_NON_RESIDENT_BIT_MAP: u16 = 0x0015;
+
+/// NTFS log file clear bits in non-resident bitmap operation.
+pub const NTFS_LOG_FILE_OPERATION_CLEAR_BITS_IN_NON_RESIDENT_BIT_MAP: u16 = 0x0016;
+
+/// NTFS log file hot fix operation.
+pub const NTFS_LOG_FILE_OPERATION_HOT_FIX: u16 = 0x0017;
+
+/// NTFS log file end top level action operation.
+pub const NTFS_LOG_FILE_OPERATION_END_TOP_LEVEL_ACTION: u16 = 0x0018;
+
+/// NTFS log file prepare transaction operation.
+pub const NTFS_LOG_FILE_OPERATION_PREPARE_TRANSACTION: u16 = 0x0019;
+
+/// NTFS log file commit transaction operation.
+pub const NTFS_LOG_FILE_OPERATION_COMMIT_TRANSACTION: u16 = 0x001a;
+
+/// NTFS log file forget transaction operation.
+pub const NTFS_LOG_FILE_OPERATION_FORGET_TRANSACTION: u16 = 0x001b;
+
+/// NTFS log file open non-resident attribute operation.
+pub const NTFS_LOG_FILE_OPERATION_OPEN_NON_RESIDENT_ATTRIBUTE: u16 = 0x001c;
+
+/// NTFS log file open attribute table dump operation.
+pub const NTFS_LOG_FILE_OPERATION_OPEN_ATTRIBUTE_TABLE_DUMP: u16 = 0x001d;
+
+/// NTFS log file attribute names dump operation.
+pub const NTFS_LOG_FILE_OPERATION_ATTRIBUTE_NAMES_DUMP: u16 = 0x001e;
+
+/// NTFS log file dirty page table dump operation.
+pub const NTFS_LOG_FILE_OPERATION_DIRTY_PAGE_TABLE_DUMP: u16 = 0x001f;
+
+/// NTFS log file transaction table dump operation.
+pub const NTFS_LOG_FILE_OPERATION_TRANSACTION_TABLE_DUMP: u16 = 0x0020;
+
+/// NTFS log file update record data in index root operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_RECORD_DATA_ROOT: u16 = 0x0021;
+
+/// NTFS log file update record data in index allocation operation.
+pub const NTFS_LOG_FILE_OPERATION_UPDATE_RECORD_DATA_ALLOCATION: u16 = 0x0022;
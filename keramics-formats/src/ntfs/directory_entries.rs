@@ -14,6 +14,7 @@
 use std::collections::HashMap;
 
 use keramics_core::ErrorTrace;
+use keramics_types::Ucs2String;
 
 use super::constants::*;
 use super::directory_entry::NtfsDirectoryEntry;
@@ -61,6 +62,14 @@ impl NtfsDirectoryEntries {
         Ok(())
     }
 
+    /// Determines if an entry with a specific file reference and name exists.
+    pub fn contains_entry(&self, file_reference: u64, name: &Ucs2String) -> bool {
+        self.entries.iter().any(|directory_entry| {
+            directory_entry.file_reference == file_reference
+                && directory_entry.file_name.name == *name
+        })
+    }
+
     /// Determines the number of entries.
     pub fn get_number_of_entries(&self) -> usize {
         self.entries.len()
@@ -71,11 +80,10 @@ impl NtfsDirectoryEntries {
         match self.entries.get_mut(index) {
             Some(directory_entry) => {
                 if directory_entry.file_name.name_space == NTFS_NAME_SPACE_WINDOWS
-                    && directory_entry.short_file_name.is_some()
+                    && directory_entry.short_file_name.is_none()
                 {
                     match self.short_names.get(&directory_entry.file_reference) {
                         Some(short_name) => {
-                            // TODO: consider removing the short name.
                             directory_entry.short_file_name = Some(short_name.clone());
                         }
                         None => {}
@@ -95,5 +103,69 @@ impl NtfsDirectoryEntries {
 mod tests {
     use super::*;
 
-    // TODO: add tests
+    fn get_file_name(name: &str, name_space: u8) -> NtfsFileName {
+        let mut file_name: NtfsFileName = NtfsFileName::new();
+        file_name.name = Ucs2String::from(name);
+        file_name.name_size = file_name.name.elements.len() as u8;
+        file_name.name_space = name_space;
+
+        file_name
+    }
+
+    #[test]
+    fn test_add() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsDirectoryEntries = NtfsDirectoryEntries::new();
+
+        test_struct.add(
+            0x0001000000000040,
+            get_file_name(".", NTFS_NAME_SPACE_WINDOWS),
+        )?;
+        test_struct.add(
+            0x0001000000000041,
+            get_file_name("long file name.txt", NTFS_NAME_SPACE_WINDOWS),
+        )?;
+        test_struct.add(
+            0x0001000000000041,
+            get_file_name("LONGFI~1.TXT", NTFS_NAME_SPACE_DOS),
+        )?;
+        assert_eq!(test_struct.get_number_of_entries(), 1);
+        assert_eq!(test_struct.short_names.len(), 1);
+
+        let result = test_struct.add(
+            0x0001000000000041,
+            get_file_name("LONGFI~2.TXT", NTFS_NAME_SPACE_DOS),
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_entry_by_index() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsDirectoryEntries = NtfsDirectoryEntries::new();
+
+        test_struct.add(
+            0x0001000000000041,
+            get_file_name("long file name.txt", NTFS_NAME_SPACE_WINDOWS),
+        )?;
+        test_struct.add(
+            0x0001000000000041,
+            get_file_name("LONGFI~1.TXT", NTFS_NAME_SPACE_DOS),
+        )?;
+        let directory_entry: &NtfsDirectoryEntry = test_struct.get_entry_by_index(0)?;
+        assert_eq!(
+            directory_entry.get_name(),
+            &Ucs2String::from("long file name.txt")
+        );
+        assert_eq!(
+            directory_entry
+                .short_file_name
+                .as_ref()
+                .map(|file_name| &file_name.name),
+            Some(&Ucs2String::from("LONGFI~1.TXT"))
+        );
+        assert!(test_struct.get_entry_by_index(1).is_err());
+
+        Ok(())
+    }
 }
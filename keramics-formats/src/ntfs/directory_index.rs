@@ -17,7 +17,7 @@ use std::sync::Arc;
 
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStreamReference, ErrorTrace};
-use keramics_types::{Ucs2String, bytes_to_u64_le};
+use keramics_types::{Ucs2String, bytes_to_u16_le, bytes_to_u64_le};
 
 use super::constants::*;
 use super::directory_entries::NtfsDirectoryEntries;
@@ -174,6 +174,13 @@ impl NtfsDirectoryIndex {
         Ok(())
     }
 
+    /// Sets the lenient mode.
+    ///
+    /// In lenient mode corrupted index entries are used instead of returning an error.
+    pub fn set_lenient_mode(&mut self, is_lenient: bool) {
+        self.index.is_lenient = is_lenient;
+    }
+
     /// Retrieves a directory entry by name.
     pub fn get_directory_entry_by_name(
         &self,
@@ -185,22 +192,14 @@ impl NtfsDirectoryIndex {
                 "Directory index was not initialized"
             ));
         }
-        if self.use_case_folding {
-            let case_folded_name: Ucs2String =
-                Ucs2String::new_with_case_folding(name, &self.case_folding_mappings);
-
-            self.get_directory_entry_by_name_from_node(
-                &self.root_node_data,
-                16,
-                data_stream,
-                &case_folded_name,
-            )
-        } else {
-            self.get_directory_entry_by_name_from_node(&self.root_node_data, 16, data_stream, name)
-        }
+        self.get_directory_entry_by_name_from_node(&self.root_node_data, 16, data_stream, name)
     }
 
     /// Retrieves a directory entry by name from an index node.
+    ///
+    /// The index values in a node are stored in collation order, hence the search stops at the
+    /// first index key that is greater than the name and, for a branch node, continues in the
+    /// corresponding sub node.
     fn get_directory_entry_by_name_from_node(
         &self,
         data: &[u8],
@@ -270,21 +269,21 @@ impl NtfsDirectoryIndex {
                         return Err(error);
                     }
                 };
-            let result: Ordering = if self.use_case_folding {
-                let case_folded_name: Ucs2String =
-                    Ucs2String::new_with_case_folding(&file_name.name, &self.case_folding_mappings);
-
-                // TODO: add a compare_with_case_folding
-                case_folded_name.compare(name)
-            } else {
-                file_name.name.compare(name)
-            };
-            if result == Ordering::Equal {
+            // The index is sorted in $UpCase collation order, also for case-sensitive
+            // directories, hence the case-sensitive comparison is only used to distinguish
+            // names that are equal after case folding.
+            let result: Ordering = file_name
+                .name
+                .compare_with_case_folding(name, &self.case_folding_mappings);
+
+            if result == Ordering::Equal
+                && (self.use_case_folding || file_name.name.compare(name) == Ordering::Equal)
+            {
                 let directory_entry: NtfsDirectoryEntry =
                     NtfsDirectoryEntry::new(index_value.file_reference, file_name);
                 return Ok(Some(directory_entry));
             }
-            if result == Ordering::Greater && is_branch {
+            if result == Ordering::Greater {
                 break;
             }
             index_value_offset += value_data_size;
@@ -488,6 +487,160 @@ impl NtfsDirectoryIndex {
         Ok(())
     }
 
+    /// Retrieves directory entries carved from the index node slack space.
+    ///
+    /// Only index values with a $FILE_NAME key that refers to the parent directory are
+    /// considered.
+    pub fn get_slack_directory_entries(
+        &self,
+        data_stream: &DataStreamReference,
+        parent_mft_entry_number: u64,
+        entries: &mut Vec<NtfsDirectoryEntry>,
+    ) -> Result<(), ErrorTrace> {
+        if !self.is_initialized {
+            return Err(keramics_core::error_trace_new!(
+                "Directory index was not initialized"
+            ));
+        }
+        self.get_slack_directory_entries_from_node(
+            &self.root_node_data,
+            16,
+            parent_mft_entry_number,
+            entries,
+        );
+        let number_of_index_entries: u64 = self.index.get_number_of_entries();
+
+        for index_entry_index in 0..number_of_index_entries {
+            // Note that index entries that cannot be read, such as index entries that were never
+            // used, are ignored.
+            let index_entry: NtfsIndexEntry = match self
+                .index
+                .get_entry_by_index(data_stream, index_entry_index)
+            {
+                Ok(index_entry) => index_entry,
+                Err(_) => continue,
+            };
+            self.get_slack_directory_entries_from_node(
+                &index_entry.data,
+                24,
+                parent_mft_entry_number,
+                entries,
+            );
+        }
+        Ok(())
+    }
+
+    /// Retrieves directory entries carved from the slack space of an index node.
+    fn get_slack_directory_entries_from_node(
+        &self,
+        data: &[u8],
+        index_node_offset: usize,
+        parent_mft_entry_number: u64,
+        entries: &mut Vec<NtfsDirectoryEntry>,
+    ) {
+        let data_size: usize = data.len();
+
+        if index_node_offset + 16 > data_size {
+            return;
+        }
+        let mut index_node_header: NtfsIndexNodeHeader = NtfsIndexNodeHeader::new();
+
+        if index_node_header
+            .read_data(&data[index_node_offset..])
+            .is_err()
+        {
+            return;
+        }
+        let mut slack_offset: usize = index_node_offset + (index_node_header.size as usize);
+        let slack_end_offset: usize = usize::min(
+            index_node_offset + (index_node_header.allocated_size as usize),
+            data_size,
+        );
+        let alignment_padding: usize = slack_offset % 8;
+        if alignment_padding > 0 {
+            slack_offset += 8 - alignment_padding;
+        }
+        if slack_offset >= slack_end_offset {
+            return;
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "NtfsDirectoryIndexSlack data of size: {} at offset: {} (0x{:08x})\n",
+                slack_end_offset - slack_offset,
+                slack_offset,
+                slack_offset,
+            ));
+            self.mediator
+                .debug_print_data(&data[slack_offset..slack_end_offset], true);
+        }
+        // Index values are 8-byte aligned, hence residual index values are searched for on
+        // 8-byte boundaries.
+        while slack_offset + 16 + 66 <= slack_end_offset {
+            match Self::read_slack_index_value(
+                data,
+                slack_offset,
+                slack_end_offset,
+                parent_mft_entry_number,
+            ) {
+                Some((index_value_size, directory_entry)) => {
+                    entries.push(directory_entry);
+
+                    slack_offset += index_value_size;
+                }
+                None => slack_offset += 8,
+            }
+        }
+    }
+
+    /// Reads a residual index value from slack space.
+    fn read_slack_index_value(
+        data: &[u8],
+        index_value_offset: usize,
+        slack_end_offset: usize,
+        parent_mft_entry_number: u64,
+    ) -> Option<(usize, NtfsDirectoryEntry)> {
+        let file_reference: u64 = bytes_to_u64_le!(data, index_value_offset);
+        let index_value_size: usize = bytes_to_u16_le!(data, index_value_offset + 8) as usize;
+        let key_data_size: usize = bytes_to_u16_le!(data, index_value_offset + 10) as usize;
+
+        if file_reference == 0
+            || key_data_size < 66
+            || index_value_size < 16 + key_data_size
+            || index_value_size % 8 != 0
+        {
+            return None;
+        }
+        let key_data_offset: usize = index_value_offset + 16;
+        let key_data_end_offset: usize = key_data_offset + key_data_size;
+
+        if key_data_end_offset > slack_end_offset {
+            return None;
+        }
+        let parent_file_reference: u64 = bytes_to_u64_le!(data, key_data_offset);
+        let name_size: usize = data[key_data_offset + 64] as usize;
+        let name_space: u8 = data[key_data_offset + 65];
+
+        if parent_file_reference & 0x0000ffffffffffff != parent_mft_entry_number
+            || name_size == 0
+            || name_space > 3
+            || 66 + name_size * 2 > key_data_size
+        {
+            return None;
+        }
+        let mut file_name: NtfsFileName = NtfsFileName::new();
+
+        if file_name
+            .read_data(&data[key_data_offset..key_data_end_offset])
+            .is_err()
+        {
+            return None;
+        }
+        let directory_entry: NtfsDirectoryEntry =
+            NtfsDirectoryEntry::new(file_reference, file_name);
+
+        Some((index_value_size, directory_entry))
+    }
+
     /// Reads an index branch value.
     fn read_index_branch_value(
         &self,
@@ -646,11 +799,9 @@ mod tests {
     use keramics_types::constants::UCS2_CASE_MAPPINGS;
 
     // TODO: add tests for initialize
-    // TODO: add tests for get_directory_entry_by_name
     // TODO: add tests for read_directory_entries
 
-    #[test]
-    fn test_get_directory_entries_from_node() -> Result<(), ErrorTrace> {
+    fn get_test_index_entry_data() -> Result<Vec<u8>, ErrorTrace> {
         let mut test_data: Vec<u8> = vec![
             0x49, 0x4e, 0x44, 0x58, 0x28, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
@@ -950,6 +1101,13 @@ mod tests {
         let mut index_entry = NtfsIndexEntry::new();
         index_entry.read_data(&mut test_data)?;
 
+        Ok(test_data)
+    }
+
+    #[test]
+    fn test_get_directory_entries_from_node() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_index_entry_data()?;
+
         let case_folding_mappings: Arc<HashMap<u16, u16>> = Arc::new(
             UCS2_CASE_MAPPINGS
                 .into_iter()
@@ -966,6 +1124,156 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_directory_entry_by_name_from_node() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_index_entry_data()?;
+
+        let case_folding_mappings: Arc<HashMap<u16, u16>> = Arc::new(
+            UCS2_CASE_MAPPINGS
+                .into_iter()
+                .collect::<HashMap<u16, u16>>(),
+        );
+        let test_struct = NtfsDirectoryIndex::new(4096, &case_folding_mappings);
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+
+        let mut entries: NtfsDirectoryEntries = NtfsDirectoryEntries::new();
+        test_struct.get_directory_entries_from_node(&test_data, 24, &data_stream, &mut entries)?;
+
+        for entry_index in 0..entries.get_number_of_entries() {
+            let directory_entry: &NtfsDirectoryEntry = entries.get_entry_by_index(entry_index)?;
+            let name: String = directory_entry.get_name().to_string();
+
+            let upper_case_name: Ucs2String = Ucs2String::from(name.to_uppercase().as_str());
+            let result: Option<NtfsDirectoryEntry> = test_struct
+                .get_directory_entry_by_name_from_node(
+                    &test_data,
+                    24,
+                    &data_stream,
+                    &upper_case_name,
+                )?;
+            assert_eq!(
+                result.map(|result_entry| result_entry.file_reference),
+                Some(directory_entry.file_reference)
+            );
+            let lower_case_name: Ucs2String = Ucs2String::from(name.to_lowercase().as_str());
+            let result: Option<NtfsDirectoryEntry> = test_struct
+                .get_directory_entry_by_name_from_node(
+                    &test_data,
+                    24,
+                    &data_stream,
+                    &lower_case_name,
+                )?;
+            assert_eq!(
+                result.map(|result_entry| result_entry.file_reference),
+                Some(directory_entry.file_reference)
+            );
+        }
+        let name: Ucs2String = Ucs2String::from("bogus");
+        let result: Option<NtfsDirectoryEntry> = test_struct
+            .get_directory_entry_by_name_from_node(&test_data, 24, &data_stream, &name)?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    fn get_test_index_value_data(file_reference: u8, name: u8) -> Vec<u8> {
+        // Index value of size 88 with a key data size of 68 for a single character name.
+        let mut data: Vec<u8> = vec![0; 88];
+        data[0] = file_reference;
+        data[8] = 0x58;
+        data[10] = 0x44;
+        data[80] = 0x01;
+        data[82] = name;
+        data
+    }
+
+    #[test]
+    fn test_get_directory_entry_by_name_from_node_case_sensitive() -> Result<(), ErrorTrace> {
+        // Index node header with a size of 296.
+        let mut test_data: Vec<u8> = vec![
+            0x10, 0x00, 0x00, 0x00, 0x28, 0x01, 0x00, 0x00, 0x28, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        // Index values in $UpCase collation order: "a", "B", "c"
+        test_data.append(&mut get_test_index_value_data(64, b'a'));
+        test_data.append(&mut get_test_index_value_data(65, b'B'));
+        test_data.append(&mut get_test_index_value_data(66, b'c'));
+        // Last index value.
+        test_data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00,
+        ]);
+        let case_folding_mappings: Arc<HashMap<u16, u16>> = Arc::new(
+            UCS2_CASE_MAPPINGS
+                .into_iter()
+                .collect::<HashMap<u16, u16>>(),
+        );
+        let mut test_struct = NtfsDirectoryIndex::new(4096, &case_folding_mappings);
+        test_struct.use_case_folding = false;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+
+        for (name, file_reference) in [("a", 64), ("B", 65), ("c", 66)] {
+            let name: Ucs2String = Ucs2String::from(name);
+            let result: Option<NtfsDirectoryEntry> = test_struct
+                .get_directory_entry_by_name_from_node(&test_data, 0, &data_stream, &name)?;
+            assert_eq!(
+                result.map(|result_entry| result_entry.file_reference),
+                Some(file_reference)
+            );
+        }
+        for name in ["A", "b", "C"] {
+            let name: Ucs2String = Ucs2String::from(name);
+            let result: Option<NtfsDirectoryEntry> = test_struct
+                .get_directory_entry_by_name_from_node(&test_data, 0, &data_stream, &name)?;
+            assert!(result.is_none());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_slack_directory_entries_from_node() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = vec![0; 4096];
+        test_data[0..4].copy_from_slice(&NTFS_INDEX_ENTRY_SIGNATURE);
+
+        // Index node header with a size of 32 and allocated size of 4072.
+        test_data[24..40].copy_from_slice(&[
+            0x10, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0xe8, 0x0f, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ]);
+        // Residual index value of "$AttrDef" in the slack space.
+        test_data[64..168].copy_from_slice(&[
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x68, 0x00, 0x52, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x5b, 0x3c, 0xa6,
+            0x8b, 0x5e, 0xdb, 0x01, 0x00, 0x5b, 0x3c, 0xa6, 0x8b, 0x5e, 0xdb, 0x01, 0x00, 0x5b,
+            0x3c, 0xa6, 0x8b, 0x5e, 0xdb, 0x01, 0x00, 0x5b, 0x3c, 0xa6, 0x8b, 0x5e, 0xdb, 0x01,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x03, 0x24, 0x00,
+            0x41, 0x00, 0x74, 0x00, 0x74, 0x00, 0x72, 0x00, 0x44, 0x00, 0x65, 0x00, 0x66, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        ]);
+        let case_folding_mappings: Arc<HashMap<u16, u16>> = Arc::new(
+            UCS2_CASE_MAPPINGS
+                .into_iter()
+                .collect::<HashMap<u16, u16>>(),
+        );
+        let test_struct = NtfsDirectoryIndex::new(4096, &case_folding_mappings);
+
+        let mut entries: Vec<NtfsDirectoryEntry> = Vec::new();
+        test_struct.get_slack_directory_entries_from_node(&test_data, 24, 5, &mut entries);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].file_reference, 0x0004000000000004);
+        assert_eq!(entries[0].get_name(), &Ucs2String::from("$AttrDef"));
+
+        let mut entries: Vec<NtfsDirectoryEntry> = Vec::new();
+        test_struct.get_slack_directory_entries_from_node(&test_data, 24, 6, &mut entries);
+
+        assert_eq!(entries.len(), 0);
+
+        Ok(())
+    }
+
     // TODO: add tests for read_index_branch_value
     // TODO: add tests for read_index_key
     // TODO: add tests for read_index_node_header
@@ -0,0 +1,338 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{Ucs2String, bytes_to_u32_le};
+
+use super::security_identifier::NtfsSecurityIdentifier;
+
+/// NTFS EFS data field certificate thumbprint credential type.
+const NTFS_EFS_CREDENTIAL_TYPE_CERTIFICATE_THUMBPRINT: u32 = 3;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "data_field_size", data_type = "u32")),
+        member(field(name = "credential_header_offset", data_type = "u32", format = "hex")),
+        member(field(name = "encrypted_fek_size", data_type = "u32")),
+        member(field(name = "encrypted_fek_offset", data_type = "u32", format = "hex")),
+        member(field(name = "unknown1", data_type = "u32", format = "hex")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) Encrypting File System (EFS) data field.
+///
+/// A data field contains the File Encryption Key (FEK) encrypted for a specific user, either
+/// a Data Decryption Field (DDF) or a Data Recovery Field (DRF).
+pub struct NtfsEfsDataField {
+    /// Credential type.
+    pub credential_type: u32,
+
+    /// Security identifier (SID) of the user.
+    pub security_identifier: Option<NtfsSecurityIdentifier>,
+
+    /// Certificate thumbprint.
+    pub certificate_thumbprint: Option<Vec<u8>>,
+
+    /// Container name.
+    pub container_name: Option<Ucs2String>,
+
+    /// Cryptographic provider name.
+    pub provider_name: Option<Ucs2String>,
+
+    /// User name.
+    pub user_name: Option<Ucs2String>,
+
+    /// Encrypted File Encryption Key (FEK).
+    pub encrypted_fek: Vec<u8>,
+}
+
+impl NtfsEfsDataField {
+    /// Creates a new data field.
+    pub fn new() -> Self {
+        Self {
+            credential_type: 0,
+            security_identifier: None,
+            certificate_thumbprint: None,
+            container_name: None,
+            provider_name: None,
+            user_name: None,
+            encrypted_fek: Vec::new(),
+        }
+    }
+
+    /// Reads the data field from a buffer.
+    ///
+    /// Returns the size of the data field.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        if data.len() < 20 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS EFS data field data size"
+            ));
+        }
+        let data_field_size: usize = bytes_to_u32_le!(data, 0) as usize;
+
+        if data_field_size < 20 || data_field_size > data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid data field size: {} value out of bounds",
+                data_field_size
+            )));
+        }
+        let data: &[u8] = &data[..data_field_size];
+
+        let credential_header_offset: usize = bytes_to_u32_le!(data, 4) as usize;
+        let encrypted_fek_size: usize = bytes_to_u32_le!(data, 8) as usize;
+        let encrypted_fek_offset: usize = bytes_to_u32_le!(data, 12) as usize;
+
+        let encrypted_fek_end_offset: usize = encrypted_fek_offset + encrypted_fek_size;
+
+        if encrypted_fek_offset < 20 || encrypted_fek_end_offset > data_field_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid encrypted FEK offset: {} value out of bounds",
+                encrypted_fek_offset
+            )));
+        }
+        self.encrypted_fek = data[encrypted_fek_offset..encrypted_fek_end_offset].to_vec();
+
+        if credential_header_offset < 20 || credential_header_offset + 12 > data_field_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid credential header offset: {} value out of bounds",
+                credential_header_offset
+            )));
+        }
+        let credential_header_data: &[u8] = &data[credential_header_offset..];
+
+        let security_identifier_offset: usize =
+            bytes_to_u32_le!(credential_header_data, 4) as usize;
+        self.credential_type = bytes_to_u32_le!(credential_header_data, 8);
+
+        // Note that an offset of 0 represents a NULL security identifier.
+        if security_identifier_offset != 0 {
+            if security_identifier_offset >= credential_header_data.len() {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid security identifier offset: {} value out of bounds",
+                    security_identifier_offset
+                )));
+            }
+            let mut security_identifier: NtfsSecurityIdentifier = NtfsSecurityIdentifier::new();
+
+            match security_identifier
+                .read_data(&credential_header_data[security_identifier_offset..])
+            {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read security identifier"
+                    );
+                    return Err(error);
+                }
+            }
+            self.security_identifier = Some(security_identifier);
+        }
+        if self.credential_type == NTFS_EFS_CREDENTIAL_TYPE_CERTIFICATE_THUMBPRINT {
+            match self.read_certificate_thumbprint(credential_header_data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read certificate thumbprint"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(data_field_size)
+    }
+
+    /// Reads the certificate thumbprint from the credential header data.
+    fn read_certificate_thumbprint(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 20 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS EFS credential header data size"
+            ));
+        }
+        let thumbprint_header_offset: usize = bytes_to_u32_le!(data, 16) as usize;
+
+        if thumbprint_header_offset < 20 || thumbprint_header_offset + 20 > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid certificate thumbprint header offset: {} value out of bounds",
+                thumbprint_header_offset
+            )));
+        }
+        let thumbprint_header_data: &[u8] = &data[thumbprint_header_offset..];
+        let thumbprint_header_data_size: usize = thumbprint_header_data.len();
+
+        let thumbprint_offset: usize = bytes_to_u32_le!(thumbprint_header_data, 0) as usize;
+        let thumbprint_size: usize = bytes_to_u32_le!(thumbprint_header_data, 4) as usize;
+
+        if thumbprint_offset < 20
+            || thumbprint_offset + thumbprint_size > thumbprint_header_data_size
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid certificate thumbprint offset: {} value out of bounds",
+                thumbprint_offset
+            )));
+        }
+        let container_name: Option<Ucs2String> = match Self::read_name(thumbprint_header_data, 8) {
+            Ok(name) => name,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read container name");
+                return Err(error);
+            }
+        };
+        let provider_name: Option<Ucs2String> = match Self::read_name(thumbprint_header_data, 12) {
+            Ok(name) => name,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read provider name");
+                return Err(error);
+            }
+        };
+        let user_name: Option<Ucs2String> = match Self::read_name(thumbprint_header_data, 16) {
+            Ok(name) => name,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read user name");
+                return Err(error);
+            }
+        };
+        self.certificate_thumbprint = Some(
+            thumbprint_header_data[thumbprint_offset..thumbprint_offset + thumbprint_size].to_vec(),
+        );
+        self.container_name = container_name;
+        self.provider_name = provider_name;
+        self.user_name = user_name;
+
+        Ok(())
+    }
+
+    /// Reads a name of which the offset is stored at a specific offset in the buffer.
+    fn read_name(data: &[u8], data_offset: usize) -> Result<Option<Ucs2String>, ErrorTrace> {
+        let name_offset: usize = bytes_to_u32_le!(data, data_offset) as usize;
+
+        // Note that an offset of 0 represents a NULL name.
+        if name_offset == 0 {
+            return Ok(None);
+        }
+        if name_offset < 20 || name_offset >= data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid name offset: {} value out of bounds",
+                name_offset
+            )));
+        }
+        Ok(Some(Ucs2String::from_le_bytes(&data[name_offset..])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 128];
+
+        // Data field header.
+        data[0..20].copy_from_slice(&[
+            0x80, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x78, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        // Credential header with a SID at offset 20 and thumbprint header at offset 32.
+        data[20..40].copy_from_slice(&[
+            0x5c, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x48, 0x00,
+            0x00, 0x00, 0x24, 0x00, 0x00, 0x00,
+        ]);
+        // SID: S-1-5-32-544
+        data[40..56].copy_from_slice(&[
+            0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x20, 0x00, 0x00, 0x00, 0x20, 0x02,
+            0x00, 0x00,
+        ]);
+        // Certificate thumbprint header with a thumbprint of 4 bytes and user name "Usr".
+        data[56..76].copy_from_slice(&[
+            0x14, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        ]);
+        data[76..80].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        data[80..88].copy_from_slice(&[0x55, 0x00, 0x73, 0x00, 0x72, 0x00, 0x00, 0x00]);
+
+        // Encrypted FEK.
+        data[120..128].copy_from_slice(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsEfsDataField = NtfsEfsDataField::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let data_field_size: usize = test_struct.read_data(&test_data)?;
+
+        assert_eq!(data_field_size, 128);
+        assert_eq!(test_struct.credential_type, 3);
+        assert_eq!(
+            test_struct
+                .security_identifier
+                .as_ref()
+                .map(|security_identifier| security_identifier.to_string()),
+            Some(String::from("S-1-5-32-544"))
+        );
+        assert_eq!(
+            test_struct.certificate_thumbprint,
+            Some(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(test_struct.container_name, None);
+        assert_eq!(test_struct.provider_name, None);
+        assert_eq!(test_struct.user_name, Some(Ucs2String::from("Usr")));
+        assert_eq!(
+            test_struct.encrypted_fek,
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsEfsDataField = NtfsEfsDataField::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..19]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_data_field_size() {
+        let mut test_struct: NtfsEfsDataField = NtfsEfsDataField::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..64]);
+
+        assert!(result.is_err());
+    }
+    #[test]
+    fn test_read_data_with_invalid_user_name_offset() {
+        let mut test_struct: NtfsEfsDataField = NtfsEfsDataField::new();
+
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[72] = 0xff;
+
+        let result = test_struct.read_data(&test_data);
+
+        assert!(result.is_err());
+        assert_eq!(test_struct.certificate_thumbprint, None);
+        assert_eq!(test_struct.user_name, None);
+    }
+}
@@ -0,0 +1,303 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::Mediator;
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
+use keramics_layout_map::LayoutMap;
+use keramics_types::bytes_to_u32_le;
+
+use super::block_stream::NtfsBlockStream;
+use super::constants::*;
+use super::efs_data_field::NtfsEfsDataField;
+use super::mft_attribute::NtfsMftAttribute;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "size", data_type = "u32")),
+        member(field(name = "state", data_type = "u32")),
+        member(field(name = "format_version", data_type = "u32")),
+        member(field(name = "crypto_api_version", data_type = "u32")),
+        member(field(name = "checksum", data_type = "[u8; 16]")),
+        member(field(name = "ddf_checksum", data_type = "[u8; 16]")),
+        member(field(name = "drf_checksum", data_type = "[u8; 16]")),
+        member(field(name = "ddf_array_offset", data_type = "u32", format = "hex")),
+        member(field(name = "drf_array_offset", data_type = "u32", format = "hex")),
+        member(field(name = "unknown1", data_type = "[u8; 12]")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) Encrypting File System (EFS) information ($EFS).
+///
+/// The EFS information is stored in a $LOGGED_UTILITY_STREAM attribute named "$EFS".
+pub struct NtfsEfsInformation {
+    /// Format version.
+    pub format_version: u32,
+
+    /// Data Decryption Fields (DDF).
+    pub decryption_fields: Vec<NtfsEfsDataField>,
+
+    /// Data Recovery Fields (DRF).
+    pub recovery_fields: Vec<NtfsEfsDataField>,
+}
+
+impl NtfsEfsInformation {
+    /// Creates new EFS information.
+    pub fn new() -> Self {
+        Self {
+            format_version: 0,
+            decryption_fields: Vec::new(),
+            recovery_fields: Vec::new(),
+        }
+    }
+
+    /// Reads the EFS information from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 76 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS EFS information data size"
+            ));
+        }
+        let mediator = Mediator::current();
+        if mediator.debug_output {
+            mediator.debug_print(NtfsEfsInformation::debug_read_data(data));
+        }
+        let size: usize = bytes_to_u32_le!(data, 0) as usize;
+
+        if size < 76 || size > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid EFS information size: {} value out of bounds",
+                size
+            )));
+        }
+        let data: &[u8] = &data[..size];
+
+        self.format_version = bytes_to_u32_le!(data, 8);
+
+        let ddf_array_offset: u32 = bytes_to_u32_le!(data, 64);
+        let drf_array_offset: u32 = bytes_to_u32_le!(data, 68);
+
+        match Self::read_data_fields(data, ddf_array_offset, &mut self.decryption_fields) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read DDF array");
+                return Err(error);
+            }
+        }
+        match Self::read_data_fields(data, drf_array_offset, &mut self.recovery_fields) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read DRF array");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a data field array at a specific offset in the buffer.
+    fn read_data_fields(
+        data: &[u8],
+        array_offset: u32,
+        data_fields: &mut Vec<NtfsEfsDataField>,
+    ) -> Result<(), ErrorTrace> {
+        // Note that an offset of 0 represents an empty array.
+        if array_offset == 0 {
+            return Ok(());
+        }
+        let data_size: usize = data.len();
+        let mut data_offset: usize = array_offset as usize;
+
+        if data_offset < 76 || data_offset + 4 > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid data field array offset: {} value out of bounds",
+                array_offset
+            )));
+        }
+        let number_of_data_fields: u32 = bytes_to_u32_le!(data, data_offset);
+        data_offset += 4;
+
+        for data_field_index in 0..number_of_data_fields {
+            if data_offset >= data_size {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid number of data fields: {} value out of bounds",
+                    number_of_data_fields
+                )));
+            }
+            let mediator = Mediator::current();
+            if mediator.debug_output {
+                mediator.debug_print(NtfsEfsDataField::debug_read_data(&data[data_offset..]));
+            }
+            let mut data_field: NtfsEfsDataField = NtfsEfsDataField::new();
+
+            let data_field_size: usize = match data_field.read_data(&data[data_offset..]) {
+                Ok(data_field_size) => data_field_size,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read data field: {}", data_field_index)
+                    );
+                    return Err(error);
+                }
+            };
+            data_offset += data_field_size;
+
+            data_fields.push(data_field);
+        }
+        Ok(())
+    }
+
+    /// Reads the EFS information from a MFT attribute.
+    pub fn read_attribute(
+        &mut self,
+        mft_attribute: &NtfsMftAttribute,
+        data_stream: &DataStreamReference,
+        cluster_block_size: u32,
+    ) -> Result<(), ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $LOGGED_UTILITY_STREAM attribute"
+            ));
+        }
+        if mft_attribute.is_resident() {
+            match self.read_data(&mft_attribute.resident_data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read resident EFS information"
+                    );
+                    return Err(error);
+                }
+            }
+        } else {
+            // Note that the size of a $LOGGED_UTILITY_STREAM attribute is limited to 64 KiB.
+            if mft_attribute.data_size > 65536 {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported EFS information data size: {} value out of bounds",
+                    mft_attribute.data_size
+                )));
+            }
+            let mut block_stream: NtfsBlockStream = NtfsBlockStream::new(cluster_block_size);
+
+            match block_stream.open(data_stream, mft_attribute) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to open block stream");
+                    return Err(error);
+                }
+            }
+            let mut data: Vec<u8> = vec![0; mft_attribute.data_size as usize];
+
+            match block_stream.read_exact_at_position(&mut data, SeekFrom::Start(0)) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read EFS information from block stream"
+                    );
+                    return Err(error);
+                }
+            }
+            match self.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read non-resident EFS information"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 80 + 56];
+
+        // Header with a DDF array at offset 76 and no DRF array.
+        data[0..16].copy_from_slice(&[
+            0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ]);
+        data[64..72].copy_from_slice(&[0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        // DDF array with 1 data field.
+        data[76..80].copy_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+
+        // Data field with a credential header without SID and an encrypted FEK of 4 bytes.
+        data[80..112].copy_from_slice(&[
+            0x38, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x34, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00,
+        ]);
+        data[132..136].copy_from_slice(&[0x01, 0x02, 0x03, 0x04]);
+
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsEfsInformation = NtfsEfsInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.format_version, 2);
+        assert_eq!(test_struct.decryption_fields.len(), 1);
+        assert_eq!(test_struct.recovery_fields.len(), 0);
+
+        let data_field: &NtfsEfsDataField = &test_struct.decryption_fields[0];
+        assert_eq!(data_field.credential_type, 1);
+        assert!(data_field.security_identifier.is_none());
+        assert_eq!(data_field.encrypted_fek, [0x01, 0x02, 0x03, 0x04]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsEfsInformation = NtfsEfsInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..75]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_size() {
+        let mut test_struct: NtfsEfsInformation = NtfsEfsInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..128]);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,150 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{ByteString, bytes_to_u16_le, bytes_to_u32_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "next_entry_offset", data_type = "u32")),
+        member(field(name = "flags", data_type = "u8", format = "hex")),
+        member(field(name = "name_size", data_type = "u8")),
+        member(field(name = "value_data_size", data_type = "u16")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) extended attribute.
+pub struct NtfsExtendedAttribute {
+    /// Flags.
+    pub flags: u8,
+
+    /// Name.
+    pub name: ByteString,
+
+    /// Value data.
+    pub value_data: Vec<u8>,
+}
+
+impl NtfsExtendedAttribute {
+    /// Creates a new extended attribute.
+    pub fn new() -> Self {
+        Self {
+            flags: 0,
+            name: ByteString::new(),
+            value_data: Vec::new(),
+        }
+    }
+
+    /// Reads the extended attribute from a buffer.
+    ///
+    /// Returns the size of the extended attribute including alignment padding.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS extended attribute data size"
+            ));
+        }
+        let next_entry_offset: usize = bytes_to_u32_le!(data, 0) as usize;
+        let name_size: usize = data[5] as usize;
+        let value_data_size: usize = bytes_to_u16_le!(data, 6) as usize;
+
+        // The name is stored with an end-of-string character.
+        let value_data_offset: usize = 8 + name_size + 1;
+        let value_data_end_offset: usize = value_data_offset + value_data_size;
+
+        if value_data_end_offset > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid value data size: {} value out of bounds",
+                value_data_size
+            )));
+        }
+        self.flags = data[4];
+        self.name = ByteString::from(&data[8..8 + name_size]);
+        self.value_data = data[value_data_offset..value_data_end_offset].to_vec();
+
+        // Extended attributes are stored 4-byte aligned.
+        let entry_size: usize = if next_entry_offset == 0 {
+            (value_data_end_offset + 3) & !3
+        } else {
+            next_entry_offset
+        };
+        if entry_size < value_data_end_offset {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid next entry offset: {} value out of bounds",
+                next_entry_offset
+            )));
+        }
+        Ok(entry_size)
+    }
+
+    /// Retrieves the value as a 32-bit integer.
+    pub fn get_value_as_u32(&self) -> Option<u32> {
+        if self.value_data.len() != 4 {
+            return None;
+        }
+        Some(bytes_to_u32_le!(self.value_data, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x14, 0x00, 0x00, 0x00, 0x00, 0x06, 0x04, 0x00, 0x24, 0x4c, 0x58, 0x55, 0x49, 0x44,
+            0x00, 0xe8, 0x03, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsExtendedAttribute = NtfsExtendedAttribute::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let entry_size: usize = test_struct.read_data(&test_data)?;
+
+        assert_eq!(entry_size, 20);
+        assert_eq!(test_struct.flags, 0x00);
+        assert_eq!(test_struct.name, "$LXUID");
+        assert_eq!(test_struct.value_data, [0xe8, 0x03, 0x00, 0x00]);
+        assert_eq!(test_struct.get_value_as_u32(), Some(1000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsExtendedAttribute = NtfsExtendedAttribute::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..7]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_value_data_size() {
+        let mut test_struct: NtfsExtendedAttribute = NtfsExtendedAttribute::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..18]);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,251 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::Mediator;
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
+use keramics_types::bytes_to_u32_le;
+
+use super::block_stream::NtfsBlockStream;
+use super::constants::*;
+use super::extended_attribute::NtfsExtendedAttribute;
+use super::mft_attribute::NtfsMftAttribute;
+
+/// New Technologies File System (NTFS) extended attributes ($EA).
+pub struct NtfsExtendedAttributes {
+    /// Entries.
+    pub entries: Vec<NtfsExtendedAttribute>,
+}
+
+impl NtfsExtendedAttributes {
+    /// Creates new extended attributes.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads the extended attributes from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let mut data_offset: usize = 0;
+        let data_size: usize = data.len();
+
+        while data_offset + 8 <= data_size {
+            let mediator = Mediator::current();
+            if mediator.debug_output {
+                mediator.debug_print(NtfsExtendedAttribute::debug_read_data(&data[data_offset..]));
+            }
+            let mut entry: NtfsExtendedAttribute = NtfsExtendedAttribute::new();
+
+            let entry_size: usize = match entry.read_data(&data[data_offset..]) {
+                Ok(entry_size) => entry_size,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to read extended attribute at offset: {} (0x{:08x})",
+                            data_offset, data_offset
+                        )
+                    );
+                    return Err(error);
+                }
+            };
+            data_offset += entry_size;
+
+            self.entries.push(entry);
+        }
+        Ok(())
+    }
+
+    /// Reads the extended attributes from a MFT attribute.
+    pub fn read_attribute(
+        &mut self,
+        mft_attribute: &NtfsMftAttribute,
+        data_stream: &DataStreamReference,
+        cluster_block_size: u32,
+    ) -> Result<(), ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_EXTENDED {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $EA attribute"
+            ));
+        }
+        if mft_attribute.is_resident() {
+            match self.read_data(&mft_attribute.resident_data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read resident extended attributes"
+                    );
+                    return Err(error);
+                }
+            }
+        } else {
+            // Note that the size of the extended attributes of a file is limited to 64 KiB.
+            if mft_attribute.data_size > 65536 {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported extended attributes data size: {} value out of bounds",
+                    mft_attribute.data_size
+                )));
+            }
+            let mut block_stream: NtfsBlockStream = NtfsBlockStream::new(cluster_block_size);
+
+            match block_stream.open(data_stream, mft_attribute) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to open block stream");
+                    return Err(error);
+                }
+            }
+            let mut data: Vec<u8> = vec![0; mft_attribute.data_size as usize];
+
+            match block_stream.read_exact_at_position(&mut data, SeekFrom::Start(0)) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read extended attributes from block stream"
+                    );
+                    return Err(error);
+                }
+            }
+            match self.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read non-resident extended attributes"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Retrieves a specific extended attribute by name.
+    pub fn get_entry_by_name(&self, name: &str) -> Option<&NtfsExtendedAttribute> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Retrieves the Windows Subsystem for Linux (WSL) file mode from the "$LXMOD" extended
+    /// attribute.
+    pub fn get_wsl_file_mode(&self) -> Option<u32> {
+        self.get_entry_by_name("$LXMOD")
+            .and_then(|entry| entry.get_value_as_u32())
+    }
+
+    /// Retrieves the Windows Subsystem for Linux (WSL) owner identifier from the "$LXUID"
+    /// extended attribute.
+    pub fn get_wsl_owner_identifier(&self) -> Option<u32> {
+        self.get_entry_by_name("$LXUID")
+            .and_then(|entry| entry.get_value_as_u32())
+    }
+
+    /// Retrieves the Windows Subsystem for Linux (WSL) group identifier from the "$LXGID"
+    /// extended attribute.
+    pub fn get_wsl_group_identifier(&self) -> Option<u32> {
+        self.get_entry_by_name("$LXGID")
+            .and_then(|entry| entry.get_value_as_u32())
+    }
+
+    /// Retrieves the Windows Subsystem for Linux (WSL) device major and minor numbers from the
+    /// "$LXDEV" extended attribute.
+    pub fn get_wsl_device_identifier(&self) -> Option<(u32, u32)> {
+        match self.get_entry_by_name("$LXDEV") {
+            Some(entry) => {
+                if entry.value_data.len() != 8 {
+                    return None;
+                }
+                let major_device_number: u32 = bytes_to_u32_le!(entry.value_data, 0);
+                let minor_device_number: u32 = bytes_to_u32_le!(entry.value_data, 4);
+
+                Some((major_device_number, minor_device_number))
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x14, 0x00, 0x00, 0x00, 0x00, 0x06, 0x04, 0x00, 0x24, 0x4c, 0x58, 0x55, 0x49, 0x44,
+            0x00, 0xe8, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x06, 0x04, 0x00,
+            0x24, 0x4c, 0x58, 0x47, 0x49, 0x44, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00,
+            0x00, 0x00, 0x00, 0x06, 0x04, 0x00, 0x24, 0x4c, 0x58, 0x4d, 0x4f, 0x44, 0x00, 0xa4,
+            0x81, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsExtendedAttributes = NtfsExtendedAttributes::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.entries.len(), 3);
+        assert_eq!(test_struct.entries[1].name, "$LXGID");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_entry() {
+        let mut test_struct: NtfsExtendedAttributes = NtfsExtendedAttributes::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..36]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_entry_by_name() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsExtendedAttributes = NtfsExtendedAttributes::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert!(test_struct.get_entry_by_name("$LXMOD").is_some());
+        assert!(test_struct.get_entry_by_name("$LXDEV").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_wsl_values() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsExtendedAttributes = NtfsExtendedAttributes::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.get_wsl_file_mode(), Some(0o100644));
+        assert_eq!(test_struct.get_wsl_owner_identifier(), Some(1000));
+        assert_eq!(test_struct.get_wsl_group_identifier(), Some(1000));
+        assert_eq!(test_struct.get_wsl_device_identifier(), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le};
+
+use super::constants::*;
+use super::mft_attribute::NtfsMftAttribute;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "packed_size", data_type = "u16")),
+        member(field(name = "number_of_need_ea_entries", data_type = "u16")),
+        member(field(name = "unpacked_size", data_type = "u32")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) extended attributes information ($EA_INFORMATION).
+pub struct NtfsExtendedAttributesInformation {
+    /// Packed extended attributes size.
+    pub packed_size: u16,
+
+    /// Number of extended attributes with the NEED_EA flag set.
+    pub number_of_need_ea_entries: u16,
+
+    /// Unpacked extended attributes size.
+    pub unpacked_size: u32,
+}
+
+impl NtfsExtendedAttributesInformation {
+    /// Creates new extended attributes information.
+    pub fn new() -> Self {
+        Self {
+            packed_size: 0,
+            number_of_need_ea_entries: 0,
+            unpacked_size: 0,
+        }
+    }
+
+    /// Reads the extended attributes information from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS extended attributes information data size"
+            ));
+        }
+        self.packed_size = bytes_to_u16_le!(data, 0);
+        self.number_of_need_ea_entries = bytes_to_u16_le!(data, 2);
+        self.unpacked_size = bytes_to_u32_le!(data, 4);
+
+        Ok(())
+    }
+
+    /// Reads the extended attributes information from a MFT attribute.
+    pub fn from_attribute(mft_attribute: &NtfsMftAttribute) -> Result<Self, ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_EXTENDED_INFORMATION {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $EA_INFORMATION attribute"
+            ));
+        }
+        if !mft_attribute.is_resident() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported non-resident $EA_INFORMATION attribute"
+            ));
+        }
+        let mut extended_attributes_information: NtfsExtendedAttributesInformation =
+            NtfsExtendedAttributesInformation::new();
+
+        match extended_attributes_information.read_data(&mft_attribute.resident_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read extended attributes information"
+                );
+                return Err(error);
+            }
+        }
+        Ok(extended_attributes_information)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![0x54, 0x00, 0x00, 0x00, 0x68, 0x00, 0x00, 0x00];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsExtendedAttributesInformation =
+            NtfsExtendedAttributesInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.packed_size, 84);
+        assert_eq!(test_struct.number_of_need_ea_entries, 0);
+        assert_eq!(test_struct.unpacked_size, 104);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsExtendedAttributesInformation =
+            NtfsExtendedAttributesInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..7]);
+
+        assert!(result.is_err());
+    }
+}
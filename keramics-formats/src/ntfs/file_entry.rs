@@ -25,13 +25,21 @@ use super::data_fork::NtfsDataFork;
 use super::directory_entries::NtfsDirectoryEntries;
 use super::directory_entry::NtfsDirectoryEntry;
 use super::directory_index::NtfsDirectoryIndex;
+use super::efs_information::NtfsEfsInformation;
+use super::extended_attributes::NtfsExtendedAttributes;
+use super::extended_attributes_information::NtfsExtendedAttributesInformation;
 use super::file_name::NtfsFileName;
+use super::fixup_value_mismatch::NtfsFixupValueMismatch;
 use super::master_file_table::NtfsMasterFileTable;
 use super::mft_attribute::NtfsMftAttribute;
 use super::mft_attributes::NtfsMftAttributes;
 use super::mft_entry::NtfsMftEntry;
+use super::object_identifier::NtfsObjectIdentifier;
 use super::reparse_point::NtfsReparsePoint;
+use super::security_descriptor::NtfsSecurityDescriptor;
+use super::security_descriptors::NtfsSecurityDescriptors;
 use super::standard_information::NtfsStandardInformation;
+use super::transactional_data::NtfsTransactionalData;
 use super::volume_information::NtfsVolumeInformation;
 
 /// New Technologies File System (NTFS) file entry.
@@ -42,6 +50,9 @@ pub struct NtfsFileEntry {
     /// Master File Table (MFT).
     mft: Arc<NtfsMasterFileTable>,
 
+    /// Security descriptors.
+    security_descriptors: Arc<NtfsSecurityDescriptors>,
+
     /// The MFT entry number.
     pub mft_entry_number: u64,
 
@@ -71,6 +82,12 @@ pub struct NtfsFileEntry {
 
     /// Value to indicate the sub directory entries were read.
     read_sub_directory_entries: bool,
+
+    /// Deleted sub directory entries.
+    deleted_sub_directory_entries: Vec<NtfsDirectoryEntry>,
+
+    /// Value to indicate the deleted sub directory entries were read.
+    read_deleted_sub_directory_entries: bool,
 }
 
 impl NtfsFileEntry {
@@ -79,6 +96,7 @@ impl NtfsFileEntry {
         data_stream: &DataStreamReference,
         mft: &Arc<NtfsMasterFileTable>,
         case_folding_mappings: &Arc<HashMap<u16, u16>>,
+        security_descriptors: &Arc<NtfsSecurityDescriptors>,
         mft_entry_number: u64,
         mft_entry: NtfsMftEntry,
         name: Option<Ucs2String>,
@@ -87,19 +105,26 @@ impl NtfsFileEntry {
         let sequence_number: u16 = mft_entry.sequence_number;
         let cluster_block_size: u32 = mft.cluster_block_size;
 
+        let mut directory_index: NtfsDirectoryIndex =
+            NtfsDirectoryIndex::new(cluster_block_size, case_folding_mappings);
+        directory_index.set_lenient_mode(mft.is_lenient);
+
         Self {
             data_stream: data_stream.clone(),
             mft: mft.clone(),
+            security_descriptors: security_descriptors.clone(),
             mft_entry_number: mft_entry_number,
             mft_entry: mft_entry,
             sequence_number: sequence_number,
             name: name,
             mft_attributes: NtfsMftAttributes::new(),
             directory_entry: directory_entry,
-            directory_index: NtfsDirectoryIndex::new(cluster_block_size, case_folding_mappings),
+            directory_index: directory_index,
             sub_directory_entries: NtfsDirectoryEntries::new(),
             has_sub_directory_entries: false,
             read_sub_directory_entries: false,
+            deleted_sub_directory_entries: Vec::new(),
+            read_deleted_sub_directory_entries: false,
         }
     }
 
@@ -132,6 +157,72 @@ impl NtfsFileEntry {
         }
     }
 
+    /// Retrieves the Encrypting File System (EFS) information from the "$EFS"
+    /// $LOGGED_UTILITY_STREAM attribute.
+    pub fn get_efs_information(&self) -> Result<Option<NtfsEfsInformation>, ErrorTrace> {
+        let name: Option<Ucs2String> = Some(Ucs2String::from("$EFS"));
+
+        match self
+            .mft_attributes
+            .get_attribute(&name, NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM)
+        {
+            Some(mft_attribute) => match self.read_efs_information(mft_attribute) {
+                Ok(efs_information) => Ok(Some(efs_information)),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read EFS information");
+                    Err(error)
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieves the extended attributes from the $EA attribute.
+    pub fn get_extended_attributes(&self) -> Result<Option<NtfsExtendedAttributes>, ErrorTrace> {
+        match self
+            .mft_attributes
+            .get_attribute(&None, NTFS_ATTRIBUTE_TYPE_EXTENDED)
+        {
+            Some(mft_attribute) => match self.read_extended_attributes(mft_attribute) {
+                Ok(extended_attributes) => Ok(Some(extended_attributes)),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read extended attributes"
+                    );
+                    Err(error)
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieves the extended attributes information from the $EA_INFORMATION attribute.
+    pub fn get_extended_attributes_information(
+        &self,
+    ) -> Result<Option<NtfsExtendedAttributesInformation>, ErrorTrace> {
+        match self
+            .mft_attributes
+            .get_attribute(&None, NTFS_ATTRIBUTE_TYPE_EXTENDED_INFORMATION)
+        {
+            Some(mft_attribute) => {
+                match NtfsExtendedAttributesInformation::from_attribute(mft_attribute) {
+                    Ok(extended_attributes_information) => {
+                        Ok(Some(extended_attributes_information))
+                    }
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to create extended attributes information from attribute"
+                        );
+                        Err(error)
+                    }
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Retrieves the file attribute flags from the $STANDARD_INFORMATION attribute.
     pub fn get_file_attribute_flags(&self) -> u32 {
         match &self.mft_attributes.standard_information {
@@ -148,6 +239,30 @@ impl NtfsFileEntry {
         }
     }
 
+    /// Retrieves the number of $FILE_NAME attributes.
+    pub fn get_number_of_file_names(&self) -> usize {
+        self.mft_attributes.file_names.len()
+    }
+
+    /// Retrieves a specific $FILE_NAME attribute.
+    pub fn get_file_name_by_index(
+        &self,
+        file_name_index: usize,
+    ) -> Result<&NtfsFileName, ErrorTrace> {
+        match self.mft_attributes.file_names.get(file_name_index) {
+            Some(file_name) => Ok(file_name),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "Missing file name: {}",
+                file_name_index
+            ))),
+        }
+    }
+
+    /// Retrieves the fix-up value mismatches of the MFT entry.
+    pub fn get_fixup_value_mismatches(&self) -> &[NtfsFixupValueMismatch] {
+        &self.mft_entry.fixup_value_mismatches
+    }
+
     /// Retrieves the journal sequence number.
     pub fn get_journal_sequence_number(&self) -> u64 {
         self.mft_entry.journal_sequence_number
@@ -166,6 +281,26 @@ impl NtfsFileEntry {
         self.name.as_ref()
     }
 
+    /// Retrieves the object identifier from the $OBJECT_ID attribute.
+    pub fn get_object_identifier(&self) -> Result<Option<NtfsObjectIdentifier>, ErrorTrace> {
+        match self
+            .mft_attributes
+            .get_attribute(&None, NTFS_ATTRIBUTE_TYPE_OBJECT_IDENTIFIER)
+        {
+            Some(mft_attribute) => match NtfsObjectIdentifier::from_attribute(mft_attribute) {
+                Ok(object_identifier) => Ok(Some(object_identifier)),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to create object identifier from attribute"
+                    );
+                    Err(error)
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
     /// Retrieves the parent file reference.
     pub fn get_parent_file_reference(&self) -> Option<u64> {
         match &self.directory_entry {
@@ -174,6 +309,142 @@ impl NtfsFileEntry {
         }
     }
 
+    /// Retrieves the $FILE_NAME of the directory entry.
+    pub fn get_directory_entry_file_name(&self) -> Option<&NtfsFileName> {
+        match &self.directory_entry {
+            Some(directory_entry) => Some(&directory_entry.file_name),
+            None => None,
+        }
+    }
+
+    /// Retrieves the short name from the $FILE_NAME in the DOS name space.
+    pub fn get_short_name(&self) -> Option<&Ucs2String> {
+        self.mft_attributes
+            .file_names
+            .iter()
+            .find(|file_name| file_name.name_space == NTFS_NAME_SPACE_DOS)
+            .map(|file_name| &file_name.name)
+    }
+
+    /// Retrieves the security descriptor.
+    ///
+    /// The security descriptor is read from the $SECURITY_DESCRIPTOR attribute if present,
+    /// otherwise it is looked up in the "$Secure" metadata file using the security descriptor
+    /// identifier from the $STANDARD_INFORMATION attribute.
+    pub fn get_security_descriptor(&self) -> Result<Option<NtfsSecurityDescriptor>, ErrorTrace> {
+        match self
+            .mft_attributes
+            .get_attribute(&None, NTFS_ATTRIBUTE_TYPE_SECURITY_DESCRIPTOR)
+        {
+            Some(mft_attribute) => {
+                let mut security_descriptor: NtfsSecurityDescriptor = NtfsSecurityDescriptor::new();
+
+                match security_descriptor.read_attribute(
+                    mft_attribute,
+                    &self.data_stream,
+                    self.mft.cluster_block_size,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read security descriptor"
+                        );
+                        return Err(error);
+                    }
+                }
+                return Ok(Some(security_descriptor));
+            }
+            None => {}
+        }
+        let security_descriptor_identifier: u32 = match &self.mft_attributes.standard_information {
+            Some(standard_information) => {
+                match standard_information.security_descriptor_identifier {
+                    Some(security_descriptor_identifier) => security_descriptor_identifier,
+                    None => return Ok(None),
+                }
+            }
+            None => return Ok(None),
+        };
+        match self
+            .security_descriptors
+            .get_security_descriptor_by_identifier(security_descriptor_identifier)
+        {
+            Ok(result) => Ok(result),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to retrieve security descriptor: {}",
+                        security_descriptor_identifier
+                    )
+                );
+                Err(error)
+            }
+        }
+    }
+
+    /// Retrieves the Transactional NTFS (TxF) data from the "$TXF_DATA"
+    /// $LOGGED_UTILITY_STREAM attribute.
+    pub fn get_transactional_data(&self) -> Result<Option<NtfsTransactionalData>, ErrorTrace> {
+        let name: Option<Ucs2String> = Some(Ucs2String::from("$TXF_DATA"));
+
+        match self
+            .mft_attributes
+            .get_attribute(&name, NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM)
+        {
+            Some(mft_attribute) => match NtfsTransactionalData::from_attribute(mft_attribute) {
+                Ok(transactional_data) => Ok(Some(transactional_data)),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to create TxF data from attribute"
+                    );
+                    Err(error)
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Determines if the $STANDARD_INFORMATION times indicate timestomping.
+    ///
+    /// The $FILE_NAME times are updated by the kernel, hence a $STANDARD_INFORMATION creation or
+    /// modification time before the creation time of the directory entry $FILE_NAME indicates the
+    /// $STANDARD_INFORMATION times were altered.
+    pub fn has_timestomping_indicators(&self) -> bool {
+        let standard_information: &NtfsStandardInformation =
+            match &self.mft_attributes.standard_information {
+                Some(standard_information) => standard_information,
+                None => return false,
+            };
+        let file_name: &NtfsFileName = match self.get_directory_entry_file_name() {
+            Some(file_name) => file_name,
+            None => match self.mft_attributes.file_names.first() {
+                Some(file_name) => file_name,
+                None => return false,
+            },
+        };
+        let file_name_creation_time: u64 = match &file_name.creation_time {
+            DateTime::Filetime(filetime) => filetime.timestamp,
+            _ => return false,
+        };
+        for date_time in [
+            &standard_information.creation_time,
+            &standard_information.modification_time,
+        ] {
+            match date_time {
+                DateTime::Filetime(filetime) => {
+                    if filetime.timestamp < file_name_creation_time {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Retrieves the size.
     pub fn get_size(&self) -> u64 {
         match self
@@ -217,6 +488,20 @@ impl NtfsFileEntry {
         )
     }
 
+    /// Retrieves the $DATA attribute with the specified name.
+    pub(super) fn get_data_attribute_by_name(
+        &self,
+        name: &Option<Ucs2String>,
+    ) -> Option<&NtfsMftAttribute> {
+        self.mft_attributes
+            .get_attribute(name, NTFS_ATTRIBUTE_TYPE_DATA)
+    }
+
+    /// Retrieves the MFT attributes.
+    pub(super) fn get_mft_attributes(&self) -> &NtfsMftAttributes {
+        &self.mft_attributes
+    }
+
     /// Retrieves the number of data forks.
     pub fn get_number_of_data_forks(&self) -> Result<usize, ErrorTrace> {
         Ok(self.mft_attributes.get_number_of_data_attributes())
@@ -346,6 +631,27 @@ impl NtfsFileEntry {
                     volume_name: volume_name,
                 }
             }
+            NTFS_ATTRIBUTE_TYPE_SECURITY_DESCRIPTOR => {
+                let mut security_descriptor: NtfsSecurityDescriptor = NtfsSecurityDescriptor::new();
+
+                match security_descriptor.read_attribute(
+                    mft_attribute,
+                    &self.data_stream,
+                    self.mft.cluster_block_size,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read security descriptor"
+                        );
+                        return Err(error);
+                    }
+                }
+                NtfsAttribute::SecurityDescriptor {
+                    security_descriptor: security_descriptor,
+                }
+            }
             NTFS_ATTRIBUTE_TYPE_REPARSE_POINT => {
                 let reparse_point: NtfsReparsePoint =
                     match NtfsReparsePoint::from_attribute(mft_attribute) {
@@ -362,6 +668,95 @@ impl NtfsFileEntry {
                     reparse_point: reparse_point,
                 }
             }
+            NTFS_ATTRIBUTE_TYPE_OBJECT_IDENTIFIER => {
+                let object_identifier: NtfsObjectIdentifier =
+                    match NtfsObjectIdentifier::from_attribute(mft_attribute) {
+                        Ok(object_identifier) => object_identifier,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to create object identifier from attribute"
+                            );
+                            return Err(error);
+                        }
+                    };
+                NtfsAttribute::ObjectIdentifier {
+                    object_identifier: object_identifier,
+                }
+            }
+            NTFS_ATTRIBUTE_TYPE_EXTENDED_INFORMATION => {
+                let extended_attributes_information: NtfsExtendedAttributesInformation =
+                    match NtfsExtendedAttributesInformation::from_attribute(mft_attribute) {
+                        Ok(extended_attributes_information) => extended_attributes_information,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to create extended attributes information from attribute"
+                            );
+                            return Err(error);
+                        }
+                    };
+                NtfsAttribute::ExtendedAttributesInformation {
+                    extended_attributes_information: extended_attributes_information,
+                }
+            }
+            NTFS_ATTRIBUTE_TYPE_EXTENDED => {
+                let extended_attributes: NtfsExtendedAttributes =
+                    match self.read_extended_attributes(mft_attribute) {
+                        Ok(extended_attributes) => extended_attributes,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to read extended attributes"
+                            );
+                            return Err(error);
+                        }
+                    };
+                NtfsAttribute::ExtendedAttributes {
+                    extended_attributes: extended_attributes,
+                }
+            }
+            NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM => {
+                let name: Option<String> = mft_attribute.name.as_ref().map(|name| name.to_string());
+
+                match name.as_deref() {
+                    Some("$EFS") => {
+                        let efs_information: NtfsEfsInformation =
+                            match self.read_efs_information(mft_attribute) {
+                                Ok(efs_information) => efs_information,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to read EFS information"
+                                    );
+                                    return Err(error);
+                                }
+                            };
+                        NtfsAttribute::EfsInformation {
+                            efs_information: efs_information,
+                        }
+                    }
+                    Some("$TXF_DATA") => {
+                        let transactional_data: NtfsTransactionalData =
+                            match NtfsTransactionalData::from_attribute(mft_attribute) {
+                                Ok(transactional_data) => transactional_data,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to create TxF data from attribute"
+                                    );
+                                    return Err(error);
+                                }
+                            };
+                        NtfsAttribute::TransactionalData {
+                            transactional_data: transactional_data,
+                        }
+                    }
+                    _ => NtfsAttribute::Generic {
+                        mft_attribute: mft_attribute,
+                    },
+                }
+            }
             _ => NtfsAttribute::Generic {
                 mft_attribute: mft_attribute,
             },
@@ -369,6 +764,38 @@ impl NtfsFileEntry {
         Ok(attribute)
     }
 
+    /// Reads the EFS information from a MFT attribute.
+    fn read_efs_information(
+        &self,
+        mft_attribute: &NtfsMftAttribute,
+    ) -> Result<NtfsEfsInformation, ErrorTrace> {
+        let mut efs_information: NtfsEfsInformation = NtfsEfsInformation::new();
+
+        efs_information.read_attribute(
+            mft_attribute,
+            &self.data_stream,
+            self.mft.cluster_block_size,
+        )?;
+
+        Ok(efs_information)
+    }
+
+    /// Reads the extended attributes from a MFT attribute.
+    fn read_extended_attributes(
+        &self,
+        mft_attribute: &NtfsMftAttribute,
+    ) -> Result<NtfsExtendedAttributes, ErrorTrace> {
+        let mut extended_attributes: NtfsExtendedAttributes = NtfsExtendedAttributes::new();
+
+        extended_attributes.read_attribute(
+            mft_attribute,
+            &self.data_stream,
+            self.mft.cluster_block_size,
+        )?;
+
+        Ok(extended_attributes)
+    }
+
     /// Retrieves the number of sub file entries.
     pub fn get_number_of_sub_file_entries(&mut self) -> Result<usize, ErrorTrace> {
         if !self.has_sub_directory_entries {
@@ -432,6 +859,7 @@ impl NtfsFileEntry {
             &self.data_stream,
             &self.mft,
             &self.directory_index.case_folding_mappings,
+            &self.security_descriptors,
             mft_entry_number,
             mft_entry,
             Some(name.clone()),
@@ -447,6 +875,109 @@ impl NtfsFileEntry {
         Ok(file_entry)
     }
 
+    /// Retrieves the number of deleted sub file entries.
+    ///
+    /// Deleted sub file entries are carved from the slack space of the directory index.
+    pub fn get_number_of_deleted_sub_file_entries(&mut self) -> Result<usize, ErrorTrace> {
+        if !self.has_sub_directory_entries {
+            return Ok(0);
+        }
+        if !self.read_deleted_sub_directory_entries {
+            match self.read_deleted_sub_directory_entries() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read deleted sub directory entries"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(self.deleted_sub_directory_entries.len())
+    }
+
+    /// Retrieves a specific deleted sub file entry.
+    ///
+    /// The MFT entry is only used if its sequence number matches the file reference of the
+    /// directory entry and its attributes can be read, otherwise the file entry only contains
+    /// the directory entry $FILE_NAME.
+    pub fn get_deleted_sub_file_entry_by_index(
+        &mut self,
+        sub_file_entry_index: usize,
+    ) -> Result<NtfsFileEntry, ErrorTrace> {
+        if !self.read_deleted_sub_directory_entries {
+            match self.read_deleted_sub_directory_entries() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read deleted sub directory entries"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        let directory_entry: &NtfsDirectoryEntry =
+            match self.deleted_sub_directory_entries.get(sub_file_entry_index) {
+                Some(directory_entry) => directory_entry,
+                None => {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Missing deleted directory entry: {}",
+                        sub_file_entry_index
+                    )));
+                }
+            };
+        let mft_entry_number: u64 = directory_entry.file_reference & 0x0000ffffffffffff;
+        let sequence_number: u16 = (directory_entry.file_reference >> 48) as u16;
+
+        // Note that the MFT entry can be reused by another file or be unreadable.
+        let mft_entry: NtfsMftEntry = match self.mft.get_entry(&self.data_stream, mft_entry_number)
+        {
+            Ok(mft_entry) => mft_entry,
+            Err(_) => NtfsMftEntry::new(),
+        };
+        // The sequence number of a MFT entry is incremented when it is deallocated.
+        let use_mft_entry: bool = !mft_entry.is_empty
+            && !mft_entry.is_bad
+            && mft_entry.base_record_file_reference == 0
+            && (mft_entry.sequence_number == sequence_number
+                || (!mft_entry.is_allocated
+                    && mft_entry.sequence_number == sequence_number.wrapping_add(1)));
+
+        let name: &Ucs2String = directory_entry.get_name();
+
+        let mut file_entry: NtfsFileEntry = NtfsFileEntry::new(
+            &self.data_stream,
+            &self.mft,
+            &self.directory_index.case_folding_mappings,
+            &self.security_descriptors,
+            mft_entry_number,
+            if use_mft_entry {
+                mft_entry
+            } else {
+                NtfsMftEntry::new()
+            },
+            Some(name.clone()),
+            Some(directory_entry.clone()),
+        );
+        // Note that the attributes of a reused or corrupted MFT entry can be unreadable, in
+        // which case the file entry only contains the directory entry $FILE_NAME.
+        if use_mft_entry && file_entry.read_attributes().is_err() {
+            file_entry = NtfsFileEntry::new(
+                &self.data_stream,
+                &self.mft,
+                &self.directory_index.case_folding_mappings,
+                &self.security_descriptors,
+                mft_entry_number,
+                NtfsMftEntry::new(),
+                Some(name.clone()),
+                Some(directory_entry.clone()),
+            );
+        }
+        Ok(file_entry)
+    }
+
     /// Reads the attributes.
     pub(super) fn read_attributes(&mut self) -> Result<(), ErrorTrace> {
         match self.mft_entry.read_attributes(&mut self.mft_attributes) {
@@ -457,70 +988,13 @@ impl NtfsFileEntry {
             }
         }
         match self.mft_attributes.attribute_list {
-            Some(attribute_index) => {
-                let mft_attribute: &NtfsMftAttribute =
-                    match self.mft_attributes.get_attribute_by_index(attribute_index) {
-                        Ok(attribute) => attribute,
-                        Err(mut error) => {
-                            keramics_core::error_trace_add_frame!(
-                                error,
-                                format!("Unable to retrieve attribute: {}", attribute_index)
-                            );
-                            return Err(error);
-                        }
-                    };
-                let mut attribute_list: NtfsAttributeList = NtfsAttributeList::new();
-
-                match attribute_list.read_attribute(
-                    &mft_attribute,
-                    &self.data_stream,
-                    self.mft.cluster_block_size,
-                ) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to read attribute list"
-                        );
-                        return Err(error);
-                    }
-                }
-                let mut mft_entries_set: HashSet<u64> = HashSet::new();
-
-                for entry in attribute_list.entries.iter() {
-                    let mft_entry_number: u64 = entry.file_reference & 0x0000ffffffffffff;
-                    if mft_entry_number != self.mft_entry_number {
-                        mft_entries_set.insert(mft_entry_number);
-                    }
-                }
-                let mut mft_entries: Vec<u64> = mft_entries_set.drain().collect::<Vec<u64>>();
-
-                mft_entries.sort();
-
-                for mft_entry_number in mft_entries.iter() {
-                    let mft_entry: NtfsMftEntry =
-                        match self.mft.get_entry(&self.data_stream, *mft_entry_number) {
-                            Ok(mft_entry) => mft_entry,
-                            Err(mut error) => {
-                                keramics_core::error_trace_add_frame!(
-                                    error,
-                                    "Unable to retrieve MFT entry"
-                                );
-                                return Err(error);
-                            }
-                        };
-                    match mft_entry.read_attributes(&mut self.mft_attributes) {
-                        Ok(_) => {}
-                        Err(mut error) => {
-                            keramics_core::error_trace_add_frame!(
-                                error,
-                                "Unable to read attributes"
-                            );
-                            return Err(error);
-                        }
-                    }
+            Some(attribute_index) => match self.read_attribute_list(attribute_index) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read attribute list");
+                    return Err(error);
                 }
-            }
+            },
             None => {}
         };
         let i30_index_name: Option<Ucs2String> = Some(Ucs2String::from("$I30"));
@@ -530,7 +1004,108 @@ impl NtfsFileEntry {
         Ok(())
     }
 
+    /// Reads the attributes of the MFT entries in the attribute list.
+    fn read_attribute_list(&mut self, attribute_index: usize) -> Result<(), ErrorTrace> {
+        let mft_attribute: &NtfsMftAttribute =
+            match self.mft_attributes.get_attribute_by_index(attribute_index) {
+                Ok(attribute) => attribute,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to retrieve attribute: {}", attribute_index)
+                    );
+                    return Err(error);
+                }
+            };
+        // Note that without a volume, such as for a standalone $MFT, the cluster block
+        // size is 0 and the data of a non-resident $ATTRIBUTE_LIST is not available,
+        // hence only the attributes of the base MFT entry are used.
+        if self.mft.cluster_block_size == 0 && !mft_attribute.is_resident() {
+            return Ok(());
+        }
+        let mut attribute_list: NtfsAttributeList = NtfsAttributeList::new();
+
+        match attribute_list.read_attribute(
+            &mft_attribute,
+            &self.data_stream,
+            self.mft.cluster_block_size,
+        ) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read attribute list");
+                return Err(error);
+            }
+        }
+        let mut mft_entries_set: HashSet<u64> = HashSet::new();
+
+        for entry in attribute_list.entries.iter() {
+            let mft_entry_number: u64 = entry.file_reference & 0x0000ffffffffffff;
+            if mft_entry_number != self.mft_entry_number {
+                mft_entries_set.insert(mft_entry_number);
+            }
+        }
+        let mut mft_entries: Vec<u64> = mft_entries_set.drain().collect::<Vec<u64>>();
+
+        mft_entries.sort();
+
+        for mft_entry_number in mft_entries.iter() {
+            let mft_entry: NtfsMftEntry = match self
+                .mft
+                .get_entry(&self.data_stream, *mft_entry_number)
+            {
+                Ok(mft_entry) => mft_entry,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to retrieve MFT entry");
+                    return Err(error);
+                }
+            };
+            match mft_entry.read_attributes(&mut self.mft_attributes) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read attributes");
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the name and directory entry from the $FILE_NAME attributes.
+    ///
+    /// A $FILE_NAME in the DOS name space is only used when no other $FILE_NAME is present.
+    /// Returns false if the MFT entry has no $FILE_NAME attribute.
+    pub(super) fn set_directory_entry_from_attributes(&mut self) -> Result<bool, ErrorTrace> {
+        let mut result: Option<&NtfsFileName> = None;
+
+        for file_name in self.mft_attributes.file_names.iter() {
+            let is_dos_name: bool = file_name.name_space == NTFS_NAME_SPACE_DOS;
+
+            if result.is_none() || !is_dos_name {
+                result = Some(file_name);
+            }
+            if !is_dos_name {
+                break;
+            }
+        }
+        match result {
+            Some(file_name) => {
+                let file_reference: u64 =
+                    self.mft_entry_number | ((self.sequence_number as u64) << 48);
+
+                self.name = Some(file_name.name.clone());
+                self.directory_entry =
+                    Some(NtfsDirectoryEntry::new(file_reference, file_name.clone()));
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Retrieves a specific sub file entry.
+    ///
+    /// The name is looked up in the $I30 index using the same collation as NTFS, which is
+    /// case-insensitive using the $UpCase case folding mappings.
     pub fn get_sub_file_entry_by_name(
         &mut self,
         sub_file_entry_name: &Ucs2String,
@@ -580,6 +1155,7 @@ impl NtfsFileEntry {
                     &self.data_stream,
                     &self.mft,
                     &self.directory_index.case_folding_mappings,
+                    &self.security_descriptors,
                     mft_entry_number,
                     mft_entry,
                     Some(name.clone()),
@@ -614,6 +1190,14 @@ impl NtfsFileEntry {
         self.mft_entry.is_bad
     }
 
+    /// Determines if the file entry is corrupted.
+    ///
+    /// A file entry is corrupted if the fix-up values of its MFT entry do not match, for example
+    /// due to a torn write. Corrupted file entries are only available in lenient mode.
+    pub fn is_corrupted(&self) -> bool {
+        self.mft_entry.is_corrupted
+    }
+
     /// Determines if the file entry is a directory.
     pub fn is_directory(&self) -> bool {
         self.has_sub_directory_entries
@@ -645,6 +1229,66 @@ impl NtfsFileEntry {
         }
     }
 
+    /// Reads the deleted sub directory entries.
+    fn read_deleted_sub_directory_entries(&mut self) -> Result<(), ErrorTrace> {
+        if !self.read_sub_directory_entries {
+            match self.read_sub_directory_entries() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read sub directory entries"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        let mut slack_directory_entries: Vec<NtfsDirectoryEntry> = Vec::new();
+
+        match self.directory_index.get_slack_directory_entries(
+            &self.data_stream,
+            self.mft_entry_number,
+            &mut slack_directory_entries,
+        ) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve slack directory entries"
+                );
+                return Err(error);
+            }
+        }
+        // Slack space often contains copies of index values that are still in use, for example
+        // after an index node was split, hence only index values that are not in use are kept.
+        let mut seen_directory_entries: HashSet<(u64, Ucs2String)> = HashSet::new();
+
+        for directory_entry in slack_directory_entries.drain(..) {
+            let file_name: &NtfsFileName = &directory_entry.file_name;
+
+            if file_name.name_space == NTFS_NAME_SPACE_DOS
+                || (file_name.name_size == 1 && file_name.name.elements[0] == 0x002e)
+            {
+                continue;
+            }
+            if self
+                .sub_directory_entries
+                .contains_entry(directory_entry.file_reference, &file_name.name)
+            {
+                continue;
+            }
+            if !seen_directory_entries
+                .insert((directory_entry.file_reference, file_name.name.clone()))
+            {
+                continue;
+            }
+            self.deleted_sub_directory_entries.push(directory_entry);
+        }
+        self.read_deleted_sub_directory_entries = true;
+
+        Ok(())
+    }
+
     /// Reads the sub directory entries.
     fn read_sub_directory_entries(&mut self) -> Result<(), ErrorTrace> {
         if !self.has_sub_directory_entries {
@@ -778,6 +1422,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_file_name_by_index() -> Result<(), ErrorTrace> {
+        let ntfs_file_system: NtfsFileSystem = get_file_system()?;
+
+        let ntfs_path: NtfsPath = NtfsPath::from("\\testdir1\\testfile1");
+        let ntfs_file_entry: NtfsFileEntry = ntfs_file_system
+            .get_file_entry_by_path(&ntfs_path)?
+            .unwrap();
+
+        let number_of_file_names: usize = ntfs_file_entry.get_number_of_file_names();
+        assert_eq!(number_of_file_names, 1);
+
+        let file_name: &NtfsFileName = ntfs_file_entry.get_file_name_by_index(0)?;
+        assert_eq!(file_name.name, Ucs2String::from("testfile1"));
+        assert_eq!(
+            Some(file_name.parent_file_reference),
+            ntfs_file_entry.get_parent_file_reference()
+        );
+        assert!(ntfs_file_entry.get_file_name_by_index(1).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_journal_sequence_number() -> Result<(), ErrorTrace> {
         let ntfs_file_system: NtfsFileSystem = get_file_system()?;
@@ -826,6 +1493,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_short_name() -> Result<(), ErrorTrace> {
+        let ntfs_file_system: NtfsFileSystem = get_file_system()?;
+
+        let ntfs_path: NtfsPath = NtfsPath::from("\\testdir1\\testfile1");
+        let ntfs_file_entry: NtfsFileEntry = ntfs_file_system
+            .get_file_entry_by_path(&ntfs_path)?
+            .unwrap();
+
+        assert_eq!(ntfs_file_entry.get_short_name(), None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_size() -> Result<(), ErrorTrace> {
         let ntfs_file_system: NtfsFileSystem = get_file_system()?;
@@ -840,6 +1521,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_has_timestomping_indicators() -> Result<(), ErrorTrace> {
+        let ntfs_file_system: NtfsFileSystem = get_file_system()?;
+
+        let ntfs_path: NtfsPath = NtfsPath::from("\\testdir1\\testfile1");
+        let ntfs_file_entry: NtfsFileEntry = ntfs_file_system
+            .get_file_entry_by_path(&ntfs_path)?
+            .unwrap();
+
+        assert_eq!(ntfs_file_entry.has_timestomping_indicators(), false);
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_symbolic_link_target() -> Result<(), ErrorTrace> {
         let ntfs_file_system: NtfsFileSystem = get_file_system()?;
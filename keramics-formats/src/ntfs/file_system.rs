@@ -21,12 +21,16 @@ use keramics_types::{Ucs2String, bytes_to_u16_le};
 use super::block_stream::NtfsBlockStream;
 use super::boot_record::NtfsBootRecord;
 use super::constants::*;
+use super::data_run::NtfsDataRunType;
 use super::file_entry::NtfsFileEntry;
+use super::log_file::NtfsLogFile;
 use super::master_file_table::NtfsMasterFileTable;
 use super::mft_attribute::NtfsMftAttribute;
 use super::mft_attributes::NtfsMftAttributes;
 use super::mft_entry::NtfsMftEntry;
 use super::path::NtfsPath;
+use super::security_descriptors::NtfsSecurityDescriptors;
+use super::usn_journal::NtfsUsnJournal;
 use super::volume_information::NtfsVolumeInformation;
 
 /// New Technologies File System (NTFS).
@@ -52,6 +56,9 @@ pub struct NtfsFileSystem {
     /// Case folding mappings.
     case_folding_mappings: Arc<HashMap<u16, u16>>,
 
+    /// Security descriptors from the "$Secure" metadata file.
+    security_descriptors: Arc<NtfsSecurityDescriptors>,
+
     /// Volume information from the $VOLUME_INFORMATION attribute of the "$Volume" metadata file.
     volume_information: Option<NtfsVolumeInformation>,
 
@@ -73,6 +80,7 @@ impl NtfsFileSystem {
             index_entry_size: 0,
             mft: Arc::new(NtfsMasterFileTable::new()),
             case_folding_mappings: Arc::new(HashMap::new()),
+            security_descriptors: Arc::new(NtfsSecurityDescriptors::new()),
             volume_information: None,
             volume_label: None,
             volume_serial_number: 0,
@@ -90,6 +98,23 @@ impl NtfsFileSystem {
         }
     }
 
+    /// Retrieves the number of security descriptors in the $Secure metadata file.
+    pub fn get_number_of_security_descriptors(&self) -> Result<usize, ErrorTrace> {
+        match self
+            .security_descriptors
+            .get_number_of_security_descriptors()
+        {
+            Ok(number_of_security_descriptors) => Ok(number_of_security_descriptors),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve number of security descriptors"
+                );
+                Err(error)
+            }
+        }
+    }
+
     /// Retrieves the volume flags.
     pub fn get_volume_flags(&self) -> Option<u16> {
         match &self.volume_information {
@@ -103,6 +128,11 @@ impl NtfsFileSystem {
         self.volume_label.as_ref()
     }
 
+    /// Retrieves the number of file entries (MFT entries).
+    pub fn get_number_of_file_entries(&self) -> u64 {
+        self.mft.number_of_entries
+    }
+
     /// Retrieves the file entry for a specific identifier (MFT entry number).
     pub fn get_file_entry_by_identifier(
         &self,
@@ -134,6 +164,7 @@ impl NtfsFileSystem {
             data_stream,
             &self.mft,
             &self.case_folding_mappings,
+            &self.security_descriptors,
             mft_entry_number,
             mft_entry,
             None,
@@ -149,7 +180,68 @@ impl NtfsFileSystem {
         Ok(file_entry)
     }
 
+    /// Retrieves a deleted file entry for a specific identifier (MFT entry number).
+    ///
+    /// Returns None if the MFT entry is allocated or cannot be recovered. A MFT entry is
+    /// considered recoverable if it is an unallocated base record that can be read and contains
+    /// a $FILE_NAME attribute and either a default $DATA attribute or a $I30 index.
+    pub fn get_deleted_file_entry_by_identifier(
+        &self,
+        mft_entry_number: u64,
+    ) -> Result<Option<NtfsFileEntry>, ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        if mft_entry_number >= self.mft.number_of_entries {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid MFT entry number: {} value out of bounds",
+                mft_entry_number
+            )));
+        }
+        // Note that unallocated MFT entries that cannot be read, for example due to corrupted
+        // fix-up values, are not recoverable.
+        let mft_entry: NtfsMftEntry = match self.mft.get_entry(data_stream, mft_entry_number) {
+            Ok(mft_entry) => mft_entry,
+            Err(_) => return Ok(None),
+        };
+        if mft_entry.is_allocated
+            || mft_entry.is_empty
+            || mft_entry.is_bad
+            || mft_entry.base_record_file_reference != 0
+        {
+            return Ok(None);
+        }
+        let mut file_entry: NtfsFileEntry = NtfsFileEntry::new(
+            data_stream,
+            &self.mft,
+            &self.case_folding_mappings,
+            &self.security_descriptors,
+            mft_entry_number,
+            mft_entry,
+            None,
+            None,
+        );
+        if file_entry.read_attributes().is_err() {
+            return Ok(None);
+        }
+        if !file_entry.is_directory() && file_entry.get_data_attribute_by_name(&None).is_none() {
+            return Ok(None);
+        }
+        match file_entry.set_directory_entry_from_attributes() {
+            Ok(true) => Ok(Some(file_entry)),
+            // Note that unallocated MFT entries with an unreadable $FILE_NAME are not
+            // recoverable.
+            Ok(false) | Err(_) => Ok(None),
+        }
+    }
+
     /// Retrieves the file entry for a specific path.
+    ///
+    /// Path components are matched case-insensitive using the $UpCase case folding mappings,
+    /// unless a directory is marked as case sensitive.
     pub fn get_file_entry_by_path(
         &self,
         path: &NtfsPath,
@@ -196,7 +288,113 @@ impl NtfsFileSystem {
         }
     }
 
-    // TODO: add method to retrieve USN journal file entry
+    /// Retrieves the metadata transaction journal from the "$LogFile" metadata file.
+    ///
+    /// Returns None if the "$LogFile" metadata file has no data.
+    pub fn get_log_file(&self) -> Result<Option<NtfsLogFile>, ErrorTrace> {
+        let file_entry: NtfsFileEntry =
+            match self.get_file_entry_by_identifier(NTFS_LOG_FILE_IDENTIFIER) {
+                Ok(file_entry) => file_entry,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve $LogFile file entry"
+                    );
+                    return Err(error);
+                }
+            };
+        let data_stream: DataStreamReference = match file_entry.get_data_stream_by_name(&None) {
+            Ok(Some(data_stream)) => data_stream,
+            Ok(None) => return Ok(None),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve $LogFile data stream"
+                );
+                return Err(error);
+            }
+        };
+        let mut log_file: NtfsLogFile =
+            NtfsLogFile::new(self.cluster_block_size, self.mft_entry_size);
+
+        match log_file.read_data_stream(&data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read $LogFile");
+                return Err(error);
+            }
+        }
+        Ok(Some(log_file))
+    }
+
+    /// Retrieves the USN change journal file entry from the "$Extend\$UsnJrnl" metadata file.
+    pub fn get_usn_journal_file_entry(&self) -> Result<Option<NtfsFileEntry>, ErrorTrace> {
+        let path: NtfsPath = NtfsPath::from("\\$Extend\\$UsnJrnl");
+
+        match self.get_file_entry_by_path(&path) {
+            Ok(result) => Ok(result),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve USN change journal file entry"
+                );
+                Err(error)
+            }
+        }
+    }
+
+    /// Retrieves the USN change journal.
+    ///
+    /// Returns None if the file system has no USN change journal.
+    pub fn get_usn_journal(&self) -> Result<Option<NtfsUsnJournal>, ErrorTrace> {
+        let file_entry: NtfsFileEntry = match self.get_usn_journal_file_entry() {
+            Ok(Some(file_entry)) => file_entry,
+            Ok(None) => return Ok(None),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve USN change journal"
+                );
+                return Err(error);
+            }
+        };
+        let name: Option<Ucs2String> = Some(Ucs2String::from("$J"));
+
+        let data_stream: DataStreamReference = match file_entry.get_data_stream_by_name(&name) {
+            Ok(Some(data_stream)) => data_stream,
+            Ok(None) => return Ok(None),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to retrieve USN change journal $J data stream"
+                );
+                return Err(error);
+            }
+        };
+        // The leading part of the $J data stream is sparse and contains no records.
+        let mut start_offset: u64 = 0;
+
+        if let Some(data_attribute) = file_entry.get_data_attribute_by_name(&name) {
+            for data_run_index in 0..data_attribute.get_number_of_data_runs() {
+                match data_attribute.get_data_run(data_run_index) {
+                    Some(data_run) if data_run.run_type == NtfsDataRunType::Sparse => {
+                        start_offset += data_run.number_of_blocks * self.cluster_block_size as u64;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        let mut usn_journal: NtfsUsnJournal = NtfsUsnJournal::new();
+
+        match usn_journal.read_data_stream(&data_stream, start_offset) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read USN change journal");
+                return Err(error);
+            }
+        }
+        Ok(Some(usn_journal))
+    }
 
     /// Reads a file system from a data stream.
     pub fn read_data_stream(
@@ -227,7 +425,11 @@ impl NtfsFileSystem {
                 return Err(error);
             }
         }
-        // TODO: read security descriptors, MFT entry 9 ($Secure)
+        // Note that the $Secure metadata file is read on first use.
+        let mut security_descriptors: NtfsSecurityDescriptors = NtfsSecurityDescriptors::new();
+        security_descriptors.initialize(data_stream, &self.mft, self.cluster_block_size);
+
+        self.security_descriptors = Arc::new(security_descriptors);
 
         self.data_stream = Some(data_stream.clone());
 
@@ -485,6 +687,23 @@ impl NtfsFileSystem {
         };
         Ok(())
     }
+
+    /// Sets the lenient mode.
+    ///
+    /// In lenient mode MFT and index entries with corrupted fix-up values are used on a
+    /// best-effort basis instead of returning an error. The lenient mode must be set before
+    /// the file system is read.
+    pub fn set_lenient_mode(&mut self, is_lenient: bool) -> Result<(), ErrorTrace> {
+        match Arc::get_mut(&mut self.mft) {
+            Some(mft) => mft.is_lenient = is_lenient,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unable to obtain mutable reference to master file table"
+                ));
+            }
+        };
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -585,6 +804,16 @@ mod tests {
         let name: Option<&Ucs2String> = file_entry.get_name();
         assert_eq!(name, Some(Ucs2String::from("testfile1")).as_ref());
 
+        let ntfs_path: NtfsPath = NtfsPath::from("\\TESTDIR1\\TestFile1");
+        let file_entry: NtfsFileEntry = file_system.get_file_entry_by_path(&ntfs_path)?.unwrap();
+
+        assert_eq!(file_entry.mft_entry_number, 66);
+
+        let ntfs_path: NtfsPath = NtfsPath::from("\\testdir1\\bogus");
+        let result: Option<NtfsFileEntry> = file_system.get_file_entry_by_path(&ntfs_path)?;
+
+        assert!(result.is_none());
+
         Ok(())
     }
 
@@ -682,4 +911,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_set_lenient_mode() -> Result<(), ErrorTrace> {
+        let mut file_system: NtfsFileSystem = NtfsFileSystem::new();
+        file_system.set_lenient_mode(true)?;
+
+        assert_eq!(file_system.mft.is_lenient, true);
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("ntfs/ntfs.raw").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+        file_system.read_data_stream(&data_stream)?;
+
+        let file_entry: NtfsFileEntry = file_system.get_file_entry_by_identifier(5)?;
+        assert_eq!(file_entry.is_corrupted(), false);
+
+        Ok(())
+    }
 }
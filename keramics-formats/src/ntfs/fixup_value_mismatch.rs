@@ -0,0 +1,67 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::fmt;
+
+/// New Technologies File System (NTFS) fix-up value mismatch.
+///
+/// A mismatch indicates that the last 2 bytes of a sector do not contain the update sequence
+/// number (placeholder value), for example when the sector was not written (torn write).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsFixupValueMismatch {
+    /// Sector index.
+    pub sector_index: u16,
+
+    /// Expected value, which is the update sequence number.
+    pub expected_value: u16,
+
+    /// Actual value stored at the end of the sector.
+    pub actual_value: u16,
+}
+
+impl NtfsFixupValueMismatch {
+    /// Creates a new fix-up value mismatch.
+    pub fn new(sector_index: u16, expected_value: u16, actual_value: u16) -> Self {
+        Self {
+            sector_index: sector_index,
+            expected_value: expected_value,
+            actual_value: actual_value,
+        }
+    }
+}
+
+impl fmt::Display for NtfsFixupValueMismatch {
+    /// Formats the fix-up value mismatch for display.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "sector: {} expected update sequence number: 0x{:04x} actual value: 0x{:04x}",
+            self.sector_index, self.expected_value, self.actual_value
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let test_struct: NtfsFixupValueMismatch = NtfsFixupValueMismatch::new(1, 0x0002, 0x00ff);
+
+        assert_eq!(
+            test_struct.to_string(),
+            "sector: 1 expected update sequence number: 0x0002 actual value: 0x00ff"
+        );
+    }
+}
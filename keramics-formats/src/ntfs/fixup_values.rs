@@ -15,12 +15,17 @@ use keramics_core::ErrorTrace;
 use keramics_core::mediator::Mediator;
 use keramics_types::bytes_to_u16_le;
 
+use super::fixup_value_mismatch::NtfsFixupValueMismatch;
+
 /// Applies the fix-up values to the buffer.
+///
+/// Returns the fix-up value mismatches. The fix-up value is not applied to a sector with
+/// a mismatch, so that the data of the sector is retained as-is.
 pub fn apply_fixup_values(
     buffer: &mut [u8],
     fixup_values_offset: u16,
     number_of_fixup_values: u16,
-) -> Result<(), ErrorTrace> {
+) -> Result<Vec<NtfsFixupValueMismatch>, ErrorTrace> {
     let buffer_size: usize = buffer.len();
 
     if fixup_values_offset as usize >= buffer_size {
@@ -68,27 +73,36 @@ pub fn apply_fixup_values(
     fixup_value_offset = fixup_value_end_offset;
 
     let mut buffer_offset: usize = 510;
+    let mut mismatches: Vec<NtfsFixupValueMismatch> = Vec::new();
 
-    for _ in 1..number_of_fixup_values {
+    for sector_index in 0..number_of_fixup_values.saturating_sub(1) {
         let fixup_value_end_offset: usize = fixup_value_offset + 2;
         let buffer_end_offset: usize = buffer_offset + 2;
 
         if buffer_end_offset <= buffer_size {
             if buffer[buffer_offset..buffer_end_offset] != placeholder_value_data {
-                // TODO: flag corruption
                 let placeholder_value: u16 = bytes_to_u16_le!(placeholder_value_data, 0);
-                let fixup_value: u16 = bytes_to_u16_le!(buffer, fixup_value_offset);
-                return Err(keramics_core::error_trace_new!(format!(
-                    "corruption detected - mismatch between placeholder: {} and value: {} at offset: {} (0x{:08x})",
-                    placeholder_value, fixup_value, fixup_value_offset, fixup_value_offset,
-                )));
+                let stored_value: u16 = bytes_to_u16_le!(buffer, buffer_offset);
+
+                if mediator.debug_output {
+                    mediator.debug_print(format!(
+                        "NtfsFixupValues mismatch between placeholder: {} and value: {} at offset: {} (0x{:08x})\n\n",
+                        placeholder_value, stored_value, buffer_offset, buffer_offset,
+                    ));
+                }
+                mismatches.push(NtfsFixupValueMismatch::new(
+                    sector_index,
+                    placeholder_value,
+                    stored_value,
+                ));
+            } else {
+                buffer.copy_within(fixup_value_offset..fixup_value_end_offset, buffer_offset);
             }
-            buffer.copy_within(fixup_value_offset..fixup_value_end_offset, buffer_offset);
         }
         fixup_value_offset = fixup_value_end_offset;
         buffer_offset += 512;
     }
-    Ok(())
+    Ok(mismatches)
 }
 
 #[cfg(test)]
@@ -181,7 +195,8 @@ mod tests {
         assert_eq!(test_data[510..512], test_data[48..50]);
         assert_eq!(test_data[1022..1024], test_data[48..50]);
 
-        apply_fixup_values(&mut test_data, 48, 3)?;
+        let mismatches: Vec<NtfsFixupValueMismatch> = apply_fixup_values(&mut test_data, 48, 3)?;
+        assert!(mismatches.is_empty());
 
         assert_eq!(test_data[510..512], test_data[50..52]);
         assert_eq!(test_data[1022..1024], test_data[52..54]);
@@ -190,12 +205,19 @@ mod tests {
     }
 
     #[test]
-    fn test_apply_fixup_values_with_corruption() {
+    fn test_apply_fixup_values_with_corruption() -> Result<(), ErrorTrace> {
         let mut test_data: Vec<u8> = get_test_data();
         test_data[1022] = 0xff;
 
-        let result = apply_fixup_values(&mut test_data, 48, 3);
+        let mismatches: Vec<NtfsFixupValueMismatch> = apply_fixup_values(&mut test_data, 48, 3)?;
+
+        assert_eq!(
+            mismatches,
+            vec![NtfsFixupValueMismatch::new(1, 0x0002, 0x00ff)]
+        );
+        assert_eq!(test_data[510..512], test_data[50..52]);
+        assert_eq!(test_data[1022..1024], [0xff, 0x00]);
 
-        assert!(result.is_err());
+        Ok(())
     }
 }
@@ -30,8 +30,14 @@ pub struct NtfsIndex {
     /// Index entry size.
     pub index_entry_size: u32,
 
+    /// Data size.
+    data_size: u64,
+
     /// Block tree.
     block_tree: BlockTree<NtfsBlockRange>,
+
+    /// Value to indicate corrupted index entries should be returned instead of an error.
+    pub is_lenient: bool,
 }
 
 impl NtfsIndex {
@@ -40,8 +46,27 @@ impl NtfsIndex {
         Self {
             cluster_block_size: cluster_block_size,
             index_entry_size: 0,
+            data_size: 0,
             block_tree: BlockTree::<NtfsBlockRange>::new(0, 0, 0),
+            is_lenient: false,
+        }
+    }
+
+    /// Retrieves the number of index entries.
+    pub fn get_number_of_entries(&self) -> u64 {
+        if self.index_entry_size == 0 {
+            return 0;
         }
+        self.data_size / (self.index_entry_size as u64)
+    }
+
+    /// Retrieves a specific index entry.
+    pub fn get_entry_by_index(
+        &self,
+        data_stream: &DataStreamReference,
+        entry_index: u64,
+    ) -> Result<NtfsIndexEntry, ErrorTrace> {
+        self.get_entry_at_offset(data_stream, entry_index * (self.index_entry_size as u64))
     }
 
     /// Retrieves a specific index entry.
@@ -50,14 +75,24 @@ impl NtfsIndex {
         data_stream: &DataStreamReference,
         virtual_cluster_number: u64,
     ) -> Result<NtfsIndexEntry, ErrorTrace> {
-        let virtual_cluster_offset: u64 = virtual_cluster_number * (self.cluster_block_size as u64);
+        self.get_entry_at_offset(
+            data_stream,
+            virtual_cluster_number * (self.cluster_block_size as u64),
+        )
+    }
 
+    /// Retrieves the index entry at a specific offset relative to the start of the index data.
+    fn get_entry_at_offset(
+        &self,
+        data_stream: &DataStreamReference,
+        virtual_cluster_offset: u64,
+    ) -> Result<NtfsIndexEntry, ErrorTrace> {
         let block_range: &NtfsBlockRange = match self.block_tree.get_value(virtual_cluster_offset) {
             Some(value) => value,
             None => {
                 return Err(keramics_core::error_trace_new!(format!(
-                    "Missing block range for VCN: {}",
-                    virtual_cluster_number
+                    "Missing block range for offset: {} (0x{:08x})",
+                    virtual_cluster_offset, virtual_cluster_offset
                 )));
             }
         };
@@ -95,6 +130,12 @@ impl NtfsIndex {
                 return Err(error);
             }
         };
+        if index_entry.is_corrupted && !self.is_lenient {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Corrupted index entry at offset: {} (0x{:08x}) fix-up values - {}",
+                index_entry_offset, index_entry_offset, index_entry.fixup_value_mismatches[0]
+            )));
+        }
         Ok(index_entry)
     }
 
@@ -114,6 +155,12 @@ impl NtfsIndex {
                 "Unsupported compressed $INDEX_ALLOCATION attribute"
             ));
         }
+        // Note that the cluster block size is 0 for a standalone $MFT.
+        if self.cluster_block_size == 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Missing cluster block size, non-resident data is not available"
+            ));
+        }
         let block_tree_size: u64 = (index_allocation_attribute.allocated_data_size
             / (self.cluster_block_size as u64))
             * (self.cluster_block_size as u64);
@@ -170,6 +217,7 @@ impl NtfsIndex {
             }
         }
         self.index_entry_size = index_entry_size;
+        self.data_size = block_tree_size;
 
         Ok(())
     }
@@ -16,6 +16,7 @@ use std::io::SeekFrom;
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStreamReference, ErrorTrace};
 
+use super::fixup_value_mismatch::NtfsFixupValueMismatch;
 use super::fixup_values::apply_fixup_values;
 use super::index_entry_header::NtfsIndexEntryHeader;
 
@@ -26,6 +27,12 @@ pub struct NtfsIndexEntry {
 
     /// Data.
     pub data: Vec<u8>,
+
+    /// Value to indicate the index entry is corrupted.
+    pub is_corrupted: bool,
+
+    /// Fix-up value mismatches.
+    pub fixup_value_mismatches: Vec<NtfsFixupValueMismatch>,
 }
 
 impl NtfsIndexEntry {
@@ -34,6 +41,8 @@ impl NtfsIndexEntry {
         Self {
             mediator: Mediator::current(),
             data: Vec::new(),
+            is_corrupted: false,
+            fixup_value_mismatches: Vec::new(),
         }
     }
 
@@ -61,18 +70,19 @@ impl NtfsIndexEntry {
                 index_entry_header.fixup_values_offset,
             )));
         }
-        // TODO: set is_corrupted (or equiv) when fix-up values are corrupted.
-        match apply_fixup_values(
+        self.fixup_value_mismatches = match apply_fixup_values(
             data,
             index_entry_header.fixup_values_offset,
             index_entry_header.number_of_fixup_values,
         ) {
-            Ok(_) => {}
+            Ok(fixup_value_mismatches) => fixup_value_mismatches,
             Err(mut error) => {
                 keramics_core::error_trace_add_frame!(error, "Unable to apply fix-up values");
                 return Err(error);
             }
-        }
+        };
+        self.is_corrupted = !self.fixup_value_mismatches.is_empty();
+
         // TODO: debug print 8-byte alignment padding.
 
         Ok(())
@@ -434,6 +444,24 @@ mod tests {
         assert_eq!(test_data[510..512], test_data[42..44]);
         assert_eq!(test_data[1022..1024], test_data[44..46]);
 
+        assert_eq!(test_struct.is_corrupted, false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_corruption() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[1022] = 0xff;
+
+        let mut test_struct = NtfsIndexEntry::new();
+        test_struct.read_data(&mut test_data)?;
+
+        assert_eq!(test_struct.is_corrupted, true);
+        assert_eq!(
+            test_struct.fixup_value_mismatches,
+            vec![NtfsFixupValueMismatch::new(1, 0x000a, 0x00ff)]
+        );
         Ok(())
     }
 
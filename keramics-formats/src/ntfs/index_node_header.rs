@@ -34,6 +34,9 @@ pub struct NtfsIndexNodeHeader {
     /// Size.
     pub size: u32,
 
+    /// Allocated size.
+    pub allocated_size: u32,
+
     /// Flags.
     pub flags: u32,
 }
@@ -44,6 +47,7 @@ impl NtfsIndexNodeHeader {
         Self {
             index_values_offset: 0,
             size: 0,
+            allocated_size: 0,
             flags: 0,
         }
     }
@@ -57,6 +61,7 @@ impl NtfsIndexNodeHeader {
         }
         self.index_values_offset = bytes_to_u32_le!(data, 0);
         self.size = bytes_to_u32_le!(data, 4);
+        self.allocated_size = bytes_to_u32_le!(data, 8);
         self.flags = bytes_to_u32_le!(data, 12);
 
         Ok(())
@@ -83,6 +88,7 @@ mod tests {
 
         assert_eq!(test_struct.index_values_offset, 16);
         assert_eq!(test_struct.size, 40);
+        assert_eq!(test_struct.allocated_size, 40);
         assert_eq!(test_struct.flags, 0x00000001);
 
         Ok(())
@@ -0,0 +1,869 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+use super::fixup_value_mismatch::NtfsFixupValueMismatch;
+use super::fixup_values::apply_fixup_values;
+use super::log_file_client_record::NtfsLogFileClientRecord;
+use super::log_file_record::NtfsLogFileRecord;
+use super::log_file_record_page_header::NtfsLogFileRecordPageHeader;
+use super::log_file_restart_area::NtfsLogFileRestartArea;
+use super::log_file_restart_page_header::NtfsLogFileRestartPageHeader;
+
+/// New Technologies File System (NTFS) metadata transaction journal ("$LogFile").
+///
+/// The log file starts with 2 restart pages followed by the log record pages. Note that
+/// the first log record pages are tail copy pages, which can contain copies of records
+/// stored in other pages.
+///
+/// The log records are read in LSN order, starting with the oldest LSN of the clients
+/// up to and including the current LSN of the restart area.
+pub struct NtfsLogFile {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Data size.
+    data_size: u64,
+
+    /// Cluster block size.
+    cluster_block_size: u32,
+
+    /// MFT entry size.
+    mft_entry_size: u32,
+
+    /// Major format version.
+    pub major_format_version: i16,
+
+    /// Minor format version.
+    pub minor_format_version: i16,
+
+    /// System page size.
+    pub system_page_size: u32,
+
+    /// Log page size.
+    pub log_page_size: u32,
+
+    /// Restart area.
+    pub restart_area: NtfsLogFileRestartArea,
+
+    /// Client records.
+    pub client_records: Vec<NtfsLogFileClientRecord>,
+
+    /// Log record page data offset.
+    page_data_offset: usize,
+
+    /// Offset of the first log record page after the tail copy pages.
+    first_record_page_offset: u64,
+
+    /// Offset of the current log record page.
+    page_offset: u64,
+
+    /// Data of the current log record page.
+    page_data: Vec<u8>,
+
+    /// Next record offset of the current log record page.
+    page_next_record_offset: usize,
+
+    /// Log sequence number (LSN) of the next log record, where 0 represents no next log
+    /// record.
+    next_lsn: u64,
+}
+
+impl NtfsLogFile {
+    /// Creates a new log file.
+    pub fn new(cluster_block_size: u32, mft_entry_size: u32) -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            data_size: 0,
+            cluster_block_size: cluster_block_size,
+            mft_entry_size: mft_entry_size,
+            major_format_version: 0,
+            minor_format_version: 0,
+            system_page_size: 0,
+            log_page_size: 0,
+            restart_area: NtfsLogFileRestartArea::new(),
+            client_records: Vec::new(),
+            page_data_offset: 0,
+            first_record_page_offset: 0,
+            page_offset: 0,
+            page_data: Vec::new(),
+            page_next_record_offset: 0,
+            next_lsn: 0,
+        }
+    }
+
+    /// Reads the log file from a "$LogFile" data stream.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        self.data_size = keramics_core::data_stream_get_size!(data_stream);
+
+        let mut data: [u8; 30] = [0; 30];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            SeekFrom::Start(0)
+        );
+        let mut restart_page_header: NtfsLogFileRestartPageHeader =
+            NtfsLogFileRestartPageHeader::new();
+
+        match restart_page_header.read_data(&data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read restart page header");
+                return Err(error);
+            }
+        }
+        let log_page_size: u32 = restart_page_header.log_page_size;
+
+        if log_page_size < 512 || log_page_size > 65536 || !log_page_size.is_power_of_two() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported log page size: {}",
+                log_page_size
+            )));
+        }
+        // The restart pages are followed by the tail copy pages.
+        let first_record_page_offset: u64 =
+            (log_page_size as u64) * (2 + NTFS_LOG_FILE_NUMBER_OF_TAIL_COPY_PAGES);
+
+        if first_record_page_offset + (log_page_size as u64) > self.data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid log page size: {} value out of bounds",
+                log_page_size
+            )));
+        }
+        // The second restart page is a copy of the first restart page, where the restart
+        // page with the most recent current LSN is used.
+        let first_restart_page = match self.read_restart_page(data_stream, 0, log_page_size) {
+            Ok(restart_page) => restart_page,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read first restart page");
+                return Err(error);
+            }
+        };
+        let (restart_page_header, restart_area, client_records) =
+            match self.read_restart_page(data_stream, log_page_size as u64, log_page_size) {
+                Ok(restart_page)
+                    if restart_page.1.current_lsn > first_restart_page.1.current_lsn =>
+                {
+                    restart_page
+                }
+                _ => first_restart_page,
+            };
+        let page_data_offset: usize = restart_area.page_data_offset as usize;
+
+        if page_data_offset < 40 || page_data_offset + 48 > log_page_size as usize {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid log record page data offset: {} value out of bounds",
+                page_data_offset
+            )));
+        }
+        let sequence_number_bits: u32 = restart_area.sequence_number_bits;
+
+        if sequence_number_bits < 3 || sequence_number_bits > 60 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported number of sequence number bits: {}",
+                sequence_number_bits
+            )));
+        }
+        // The log records are read starting with the oldest LSN of the clients.
+        let next_lsn: u64 = client_records
+            .iter()
+            .map(|client_record| client_record.oldest_lsn)
+            .filter(|oldest_lsn| *oldest_lsn != 0)
+            .min()
+            .unwrap_or(restart_area.current_lsn);
+
+        self.data_stream = Some(data_stream.clone());
+        self.major_format_version = restart_page_header.major_format_version;
+        self.minor_format_version = restart_page_header.minor_format_version;
+        self.system_page_size = restart_page_header.system_page_size;
+        self.log_page_size = log_page_size;
+        self.restart_area = restart_area;
+        self.client_records = client_records;
+        self.page_data_offset = page_data_offset;
+        self.first_record_page_offset = first_record_page_offset;
+        self.page_offset = 0;
+        self.page_data = Vec::new();
+        self.page_next_record_offset = 0;
+        self.next_lsn = next_lsn;
+
+        Ok(())
+    }
+
+    /// Reads a restart page.
+    fn read_restart_page(
+        &self,
+        data_stream: &DataStreamReference,
+        page_offset: u64,
+        log_page_size: u32,
+    ) -> Result<
+        (
+            NtfsLogFileRestartPageHeader,
+            NtfsLogFileRestartArea,
+            Vec<NtfsLogFileClientRecord>,
+        ),
+        ErrorTrace,
+    > {
+        let mut data: Vec<u8> = vec![0; log_page_size as usize];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            SeekFrom::Start(page_offset)
+        );
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "NtfsLogFileRestartPage data of size: {} at offset: {} (0x{:08x})\n",
+                data.len(),
+                page_offset,
+                page_offset
+            ));
+            self.mediator.debug_print_data(&data, true);
+            self.mediator
+                .debug_print(NtfsLogFileRestartPageHeader::debug_read_data(&data[0..30]));
+        }
+        let mut restart_page_header: NtfsLogFileRestartPageHeader =
+            NtfsLogFileRestartPageHeader::new();
+
+        match restart_page_header.read_data(&data[0..30]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read restart page header");
+                return Err(error);
+            }
+        }
+        let fixup_value_mismatches: Vec<NtfsFixupValueMismatch> = match apply_fixup_values(
+            &mut data,
+            restart_page_header.fixup_values_offset,
+            restart_page_header.number_of_fixup_values,
+        ) {
+            Ok(fixup_value_mismatches) => fixup_value_mismatches,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to apply fix-up values");
+                return Err(error);
+            }
+        };
+        if let Some(fixup_value_mismatch) = fixup_value_mismatches.first() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Corrupted fix-up values - {}",
+                fixup_value_mismatch
+            )));
+        }
+        let restart_area_offset: usize = restart_page_header.restart_area_offset as usize;
+        let restart_area_end_offset: usize = restart_area_offset + 48;
+
+        if restart_area_offset < 30 || restart_area_end_offset > data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid restart area offset: {} value out of bounds",
+                restart_area_offset
+            )));
+        }
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(NtfsLogFileRestartArea::debug_read_data(
+                    &data[restart_area_offset..restart_area_end_offset],
+                ));
+        }
+        let mut restart_area: NtfsLogFileRestartArea = NtfsLogFileRestartArea::new();
+
+        match restart_area.read_data(&data[restart_area_offset..restart_area_end_offset]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read restart area");
+                return Err(error);
+            }
+        }
+        let mut client_records: Vec<NtfsLogFileClientRecord> = Vec::new();
+        let mut client_record_offset: usize =
+            restart_area_offset + restart_area.client_array_offset as usize;
+
+        for client_index in 0..restart_area.number_of_clients {
+            let client_record_end_offset: usize = client_record_offset + 160;
+
+            if client_record_end_offset > data.len() {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid client record: {} offset: {} value out of bounds",
+                    client_index, client_record_offset
+                )));
+            }
+            if self.mediator.debug_output {
+                self.mediator
+                    .debug_print(NtfsLogFileClientRecord::debug_read_data(
+                        &data[client_record_offset..client_record_end_offset],
+                    ));
+            }
+            let mut client_record: NtfsLogFileClientRecord = NtfsLogFileClientRecord::new();
+
+            match client_record.read_data(&data[client_record_offset..client_record_end_offset]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read client record: {}", client_index)
+                    );
+                    return Err(error);
+                }
+            }
+            client_records.push(client_record);
+
+            client_record_offset = client_record_end_offset;
+        }
+        Ok((restart_page_header, restart_area, client_records))
+    }
+
+    /// Reads a log record page.
+    ///
+    /// Returns None if the page does not contain a log record page signature, for example
+    /// if the page is not used.
+    fn read_record_page(
+        &self,
+        data_stream: &DataStreamReference,
+        page_offset: u64,
+    ) -> Result<Option<(NtfsLogFileRecordPageHeader, Vec<u8>)>, ErrorTrace> {
+        let mut data: Vec<u8> = vec![0; self.log_page_size as usize];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            SeekFrom::Start(page_offset)
+        );
+        if data[0..4] != NTFS_LOG_FILE_RECORD_PAGE_SIGNATURE {
+            return Ok(None);
+        }
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "NtfsLogFileRecordPage data of size: {} at offset: {} (0x{:08x})\n",
+                data.len(),
+                page_offset,
+                page_offset
+            ));
+            self.mediator.debug_print_data(&data, true);
+            self.mediator
+                .debug_print(NtfsLogFileRecordPageHeader::debug_read_data(&data[0..40]));
+        }
+        let mut record_page_header: NtfsLogFileRecordPageHeader =
+            NtfsLogFileRecordPageHeader::new();
+
+        match record_page_header.read_data(&data[0..40]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read record page header");
+                return Err(error);
+            }
+        }
+        let fixup_value_mismatches: Vec<NtfsFixupValueMismatch> = match apply_fixup_values(
+            &mut data,
+            record_page_header.fixup_values_offset,
+            record_page_header.number_of_fixup_values,
+        ) {
+            Ok(fixup_value_mismatches) => fixup_value_mismatches,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to apply fix-up values");
+                return Err(error);
+            }
+        };
+        if let Some(fixup_value_mismatch) = fixup_value_mismatches.first() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Corrupted fix-up values - {}",
+                fixup_value_mismatch
+            )));
+        }
+        Ok(Some((record_page_header, data)))
+    }
+
+    /// Reads the log record page at a specific offset into the current log record page.
+    fn read_page(
+        &mut self,
+        data_stream: &DataStreamReference,
+        page_offset: u64,
+    ) -> Result<(), ErrorTrace> {
+        if !self.page_data.is_empty() && self.page_offset == page_offset {
+            return Ok(());
+        }
+        let (record_page_header, page_data) = match self.read_record_page(data_stream, page_offset)
+        {
+            Ok(Some(record_page)) => record_page,
+            Ok(None) => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Missing log record page at offset: {} (0x{:08x})",
+                    page_offset, page_offset
+                )));
+            }
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to read record page at offset: {} (0x{:08x})",
+                        page_offset, page_offset
+                    )
+                );
+                return Err(error);
+            }
+        };
+        self.page_offset = page_offset;
+        self.page_data = page_data;
+        self.page_next_record_offset = record_page_header.next_record_offset as usize;
+
+        Ok(())
+    }
+
+    /// Retrieves the offset and sequence number of the log record page that succeeds
+    /// a specific log record page.
+    ///
+    /// The log record pages are used as a circular buffer, where the log continues in the
+    /// first log record page after the tail copy pages with an incremented sequence number.
+    fn get_next_page_offset(&self, page_offset: u64, sequence_number: u64) -> (u64, u64) {
+        let log_page_size: u64 = self.log_page_size as u64;
+        let next_page_offset: u64 = page_offset + log_page_size;
+
+        if next_page_offset + log_page_size > self.data_size {
+            (self.first_record_page_offset, sequence_number + 1)
+        } else {
+            (next_page_offset, sequence_number)
+        }
+    }
+
+    /// Retrieves the file offset and sequence number of a log sequence number (LSN).
+    ///
+    /// The upper bits of a LSN contain the sequence number and the lower bits the file
+    /// offset divided by 8.
+    fn get_lsn_file_offset(&self, lsn: u64) -> (u64, u64) {
+        let sequence_number_bits: u32 = self.restart_area.sequence_number_bits;
+
+        (
+            (lsn << sequence_number_bits) >> (sequence_number_bits - 3),
+            lsn >> (64 - sequence_number_bits),
+        )
+    }
+
+    /// Retrieves the log sequence number (LSN) of a file offset and sequence number.
+    fn get_file_offset_lsn(&self, file_offset: u64, sequence_number: u64) -> u64 {
+        (sequence_number << (64 - self.restart_area.sequence_number_bits)) | (file_offset >> 3)
+    }
+
+    /// Reads the next log record.
+    fn read_next_record(&mut self) -> Result<Option<NtfsLogFileRecord>, ErrorTrace> {
+        let data_stream: DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream.clone(),
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let log_page_size: usize = self.log_page_size as usize;
+
+        loop {
+            let lsn: u64 = self.next_lsn;
+
+            if lsn == 0 || lsn > self.restart_area.current_lsn {
+                return Ok(None);
+            }
+            let (file_offset, sequence_number): (u64, u64) = self.get_lsn_file_offset(lsn);
+
+            let page_offset: u64 = file_offset - (file_offset % log_page_size as u64);
+            let record_offset: usize = (file_offset % log_page_size as u64) as usize;
+
+            // Note that a log record header does not span multiple pages.
+            if page_offset < self.first_record_page_offset
+                || page_offset + log_page_size as u64 > self.data_size
+                || record_offset < self.page_data_offset
+                || record_offset + 48 > log_page_size
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid LSN: 0x{:016x} value out of bounds",
+                    lsn
+                )));
+            }
+            match self.read_page(&data_stream, page_offset) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read log record page of LSN: 0x{:016x}", lsn)
+                    );
+                    return Err(error);
+                }
+            }
+            let record_lsn: u64 = bytes_to_u64_le!(self.page_data, record_offset);
+
+            if record_lsn != lsn {
+                // The log continues in the next log record page if there is no log record
+                // at or beyond the next record offset of the page.
+                if record_offset >= self.page_next_record_offset {
+                    let (next_page_offset, next_sequence_number): (u64, u64) =
+                        self.get_next_page_offset(page_offset, sequence_number);
+
+                    self.next_lsn = self.get_file_offset_lsn(
+                        next_page_offset + self.page_data_offset as u64,
+                        next_sequence_number,
+                    );
+                    continue;
+                }
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Mismatch between LSN: 0x{:016x} and log record LSN: 0x{:016x}",
+                    lsn, record_lsn
+                )));
+            }
+            let client_data_size: u32 = bytes_to_u32_le!(self.page_data, record_offset + 24);
+            let record_size: usize = 48 + client_data_size as usize;
+
+            if record_size as u64 > self.data_size {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid client data size: {} value out of bounds",
+                    client_data_size
+                )));
+            }
+            let mut data: Vec<u8> = Vec::with_capacity(record_size);
+            let mut data_end_offset: usize = record_offset + record_size;
+            let mut data_page_offset: u64 = page_offset;
+            let mut data_sequence_number: u64 = sequence_number;
+
+            if data_end_offset <= log_page_size {
+                data.extend_from_slice(&self.page_data[record_offset..data_end_offset]);
+            } else {
+                // The remainder of the log record is stored after the header of the
+                // successive log record pages.
+                data.extend_from_slice(&self.page_data[record_offset..log_page_size]);
+
+                while data.len() < record_size {
+                    (data_page_offset, data_sequence_number) =
+                        self.get_next_page_offset(data_page_offset, data_sequence_number);
+
+                    match self.read_page(&data_stream, data_page_offset) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!(
+                                    "Unable to read log record page of LSN: 0x{:016x} continuation",
+                                    lsn
+                                )
+                            );
+                            return Err(error);
+                        }
+                    }
+                    let remaining_size: usize =
+                        (record_size - data.len()).min(log_page_size - self.page_data_offset);
+
+                    data_end_offset = self.page_data_offset + remaining_size;
+                    data.extend_from_slice(&self.page_data[self.page_data_offset..data_end_offset]);
+                }
+            }
+            // Log records are aligned to 8 bytes and the next log record is stored in the
+            // successive log record page if its header does not fit in the remainder of
+            // the page.
+            let next_record_offset: usize = data_end_offset.div_ceil(8) * 8;
+
+            self.next_lsn = if next_record_offset + 48 > log_page_size {
+                let (next_page_offset, next_sequence_number): (u64, u64) =
+                    self.get_next_page_offset(data_page_offset, data_sequence_number);
+
+                self.get_file_offset_lsn(
+                    next_page_offset + self.page_data_offset as u64,
+                    next_sequence_number,
+                )
+            } else {
+                self.get_file_offset_lsn(
+                    data_page_offset + next_record_offset as u64,
+                    data_sequence_number,
+                )
+            };
+            let mut log_file_record: NtfsLogFileRecord = NtfsLogFileRecord::new();
+
+            match log_file_record.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read log record");
+                    return Err(error);
+                }
+            }
+            log_file_record.set_mft_entry_number(self.cluster_block_size, self.mft_entry_size);
+
+            return Ok(Some(log_file_record));
+        }
+    }
+}
+
+impl Iterator for NtfsLogFile {
+    type Item = Result<NtfsLogFileRecord, ErrorTrace>;
+
+    /// Retrieves the next log record.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_next_record() {
+            Ok(Some(log_file_record)) => Some(Ok(log_file_record)),
+            Ok(None) => None,
+            Err(error) => {
+                // The LSN of the next log record cannot be determined after a corrupted
+                // log record.
+                self.next_lsn = 0;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+    use keramics_types::Ucs2String;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 24576];
+
+        // Restart pages, where the second restart page contains an older current LSN.
+        for page_offset in [0, 4096] {
+            let restart_page_header_data: [u8; 30] = [
+                0x52, 0x53, 0x54, 0x52, 0x1e, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x30, 0x00, 0x01, 0x00,
+                0x01, 0x00,
+            ];
+            data[page_offset..page_offset + 30].copy_from_slice(&restart_page_header_data);
+
+            let restart_area_data: [u8; 48] = [
+                0x2e, 0x08, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0xff, 0xff, 0x00, 0x00,
+                0x02, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x40, 0x00, 0x30, 0x00, 0x00, 0x60, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x40, 0x00, 0x01, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ];
+            data[page_offset + 48..page_offset + 96].copy_from_slice(&restart_area_data);
+
+            let client_record_data: [u8; 40] = [
+                0x08, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x1f, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x08, 0x00, 0x00, 0x00, 0x4e, 0x00, 0x54, 0x00, 0x46, 0x00, 0x53, 0x00,
+            ];
+            data[page_offset + 96..page_offset + 136].copy_from_slice(&client_record_data);
+        }
+        data[4096 + 48] = 0x00;
+
+        // Log record pages, which succeed the tail copy pages, where the third log record
+        // spans the last and the first log record page.
+        let record_page_header_data: [u8; 40] = [
+            0x52, 0x43, 0x52, 0x44, 0x28, 0x00, 0x09, 0x00, 0x2e, 0x08, 0x20, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0xc8, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x2e, 0x08, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        data[16384..16424].copy_from_slice(&record_page_header_data);
+
+        let record_page_header_data: [u8; 40] = [
+            0x52, 0x43, 0x52, 0x44, 0x28, 0x00, 0x09, 0x00, 0x20, 0x0a, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x13, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        data[20480..20520].copy_from_slice(&record_page_header_data);
+
+        let record1_data: [u8; 88] = [
+            0x08, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x12, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        data[20544..20632].copy_from_slice(&record1_data);
+
+        let record2_data: [u8; 104] = [
+            0x13, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x0a, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x08, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x28, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x01, 0x00, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a,
+            0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+        ];
+        data[20632..20736].copy_from_slice(&record2_data);
+
+        let record3_data: [u8; 80] = [
+            0x20, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0a, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x13, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x07, 0x00, 0x20, 0x00, 0xe0, 0x0f,
+            0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x38, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        data[20736..20816].copy_from_slice(&record3_data);
+        data[20816..24576].fill(0x5a);
+        data[16448..16752].fill(0x5a);
+
+        let record4_data: [u8; 88] = [
+            0x2e, 0x08, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x0a, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x20, 0x0a, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x35, 0x12, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        data[16752..16840].copy_from_slice(&record4_data);
+
+        // Remnant of a log record of a previous sequence after the next record offset.
+        data[16840..16928].copy_from_slice(&record1_data);
+        data[16840] = 0x39;
+        data[16841] = 0x08;
+
+        for page_offset in [0, 4096, 16384, 20480] {
+            set_test_fixup_values(&mut data[page_offset..page_offset + 4096]);
+        }
+        data
+    }
+
+    /// Replaces the last 2 bytes of every 512-byte block in the page by the placeholder value.
+    fn set_test_fixup_values(page_data: &mut [u8]) {
+        let fixup_values_offset: usize = page_data[4] as usize;
+
+        page_data[fixup_values_offset] = 0x01;
+        page_data[fixup_values_offset + 1] = 0x00;
+
+        for fixup_value_index in 1..9 {
+            let page_offset: usize = (fixup_value_index * 512) - 2;
+            let fixup_value_offset: usize = fixup_values_offset + (fixup_value_index * 2);
+
+            page_data[fixup_value_offset] = page_data[page_offset];
+            page_data[fixup_value_offset + 1] = page_data[page_offset + 1];
+            page_data[page_offset] = 0x01;
+            page_data[page_offset + 1] = 0x00;
+        }
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut log_file: NtfsLogFile = NtfsLogFile::new(4096, 1024);
+        log_file.read_data_stream(&data_stream)?;
+
+        assert_eq!(log_file.major_format_version, 1);
+        assert_eq!(log_file.minor_format_version, 1);
+        assert_eq!(log_file.system_page_size, 4096);
+        assert_eq!(log_file.log_page_size, 4096);
+        assert_eq!(log_file.restart_area.current_lsn, 0x20082e);
+        assert_eq!(log_file.restart_area.file_size, 24576);
+        assert_eq!(log_file.client_records.len(), 1);
+        assert_eq!(log_file.client_records[0].name, Ucs2String::from("NTFS"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut log_file: NtfsLogFile = NtfsLogFile::new(4096, 1024);
+        let result = log_file.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_iterator() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut log_file: NtfsLogFile = NtfsLogFile::new(4096, 1024);
+        log_file.read_data_stream(&data_stream)?;
+
+        let log_file_records: Vec<NtfsLogFileRecord> =
+            log_file.collect::<Result<Vec<NtfsLogFileRecord>, ErrorTrace>>()?;
+        assert_eq!(log_file_records.len(), 4);
+
+        assert_eq!(log_file_records[0].lsn, 0x100a08);
+        assert_eq!(
+            log_file_records[0].redo_operation,
+            NTFS_LOG_FILE_OPERATION_INITIALIZE_FILE_RECORD_SEGMENT
+        );
+        assert_eq!(log_file_records[0].mft_entry_number, Some(17));
+
+        assert_eq!(log_file_records[1].lsn, 0x100a13);
+        assert_eq!(log_file_records[1].mft_entry_number, None);
+        assert_eq!(log_file_records[1].index_record_vcn, Some(16));
+
+        assert_eq!(log_file_records[2].lsn, 0x100a20);
+        assert_eq!(
+            log_file_records[2].redo_operation,
+            NTFS_LOG_FILE_OPERATION_UPDATE_RESIDENT_VALUE
+        );
+        assert_eq!(log_file_records[2].redo_data, vec![0x5a; 4064]);
+        assert_eq!(log_file_records[2].mft_entry_number, Some(16));
+
+        assert_eq!(log_file_records[3].lsn, 0x20082e);
+        assert_eq!(
+            log_file_records[3].redo_operation,
+            NTFS_LOG_FILE_OPERATION_DEALLOCATE_FILE_RECORD_SEGMENT
+        );
+        assert_eq!(log_file_records[3].mft_entry_number, Some(20));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterator_with_unused_page_remainder() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+
+        // Set the current LSN to the LSN that succeeds the fourth log record.
+        test_data[48] = 0x39;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut log_file: NtfsLogFile = NtfsLogFile::new(4096, 1024);
+        log_file.read_data_stream(&data_stream)?;
+
+        let log_file_records: Vec<NtfsLogFileRecord> =
+            log_file.collect::<Result<Vec<NtfsLogFileRecord>, ErrorTrace>>()?;
+        assert_eq!(log_file_records.len(), 4);
+
+        assert_eq!(log_file_records[3].lsn, 0x20082e);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterator_with_lsn_mismatch() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[20632] = 0xff;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut log_file: NtfsLogFile = NtfsLogFile::new(4096, 1024);
+        log_file.read_data_stream(&data_stream)?;
+
+        let result = log_file.next();
+        assert!(matches!(result, Some(Ok(_))));
+
+        let result = log_file.next();
+        assert!(matches!(result, Some(Err(_))));
+
+        let result = log_file.next();
+        assert!(result.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{Ucs2String, bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "oldest_lsn", data_type = "u64")),
+        member(field(name = "client_restart_lsn", data_type = "u64")),
+        member(field(name = "previous_client", data_type = "u16")),
+        member(field(name = "next_client", data_type = "u16")),
+        member(field(name = "sequence_number", data_type = "u16")),
+        member(field(name = "unknown1", data_type = "[u8; 6]")),
+        member(field(name = "name_size", data_type = "u32")),
+        member(field(name = "name", data_type = "[u8; 128]")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file service client record.
+pub struct NtfsLogFileClientRecord {
+    /// Oldest log sequence number (LSN).
+    pub oldest_lsn: u64,
+
+    /// Client restart log sequence number (LSN).
+    pub client_restart_lsn: u64,
+
+    /// Previous client index.
+    pub previous_client: u16,
+
+    /// Next client index.
+    pub next_client: u16,
+
+    /// Sequence number.
+    pub sequence_number: u16,
+
+    /// Name.
+    pub name: Ucs2String,
+}
+
+impl NtfsLogFileClientRecord {
+    /// Creates a new client record.
+    pub fn new() -> Self {
+        Self {
+            oldest_lsn: 0,
+            client_restart_lsn: 0,
+            previous_client: 0,
+            next_client: 0,
+            sequence_number: 0,
+            name: Ucs2String::new(),
+        }
+    }
+
+    /// Reads the client record from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 160 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file client record data size"
+            ));
+        }
+        let name_size: u32 = bytes_to_u32_le!(data, 28);
+
+        if name_size > 128 || name_size % 2 != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid name size: {} value out of bounds",
+                name_size
+            )));
+        }
+        self.oldest_lsn = bytes_to_u64_le!(data, 0);
+        self.client_restart_lsn = bytes_to_u64_le!(data, 8);
+        self.previous_client = bytes_to_u16_le!(data, 16);
+        self.next_client = bytes_to_u16_le!(data, 18);
+        self.sequence_number = bytes_to_u16_le!(data, 20);
+
+        let data_end_offset: usize = 32 + name_size as usize;
+
+        self.name = Ucs2String::new();
+        self.name.read_data_le(&data[32..data_end_offset]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x1f, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x08, 0x00, 0x00, 0x00, 0x4e, 0x00, 0x54, 0x00, 0x46, 0x00, 0x53, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileClientRecord::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.oldest_lsn, 0x2010);
+        assert_eq!(test_struct.client_restart_lsn, 0x1f80);
+        assert_eq!(test_struct.previous_client, 0xffff);
+        assert_eq!(test_struct.next_client, 0xffff);
+        assert_eq!(test_struct.sequence_number, 1);
+        assert_eq!(test_struct.name, Ucs2String::from("NTFS"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileClientRecord::new();
+        let result = test_struct.read_data(&test_data[0..159]);
+        assert!(result.is_err());
+    }
+}
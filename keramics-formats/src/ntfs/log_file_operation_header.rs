@@ -0,0 +1,165 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u64_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "redo_operation", data_type = "u16", format = "hex")),
+        member(field(name = "undo_operation", data_type = "u16", format = "hex")),
+        member(field(name = "redo_data_offset", data_type = "u16")),
+        member(field(name = "redo_data_size", data_type = "u16")),
+        member(field(name = "undo_data_offset", data_type = "u16")),
+        member(field(name = "undo_data_size", data_type = "u16")),
+        member(field(name = "target_attribute", data_type = "u16")),
+        member(field(name = "number_of_lcns", data_type = "u16")),
+        member(field(name = "record_offset", data_type = "u16")),
+        member(field(name = "attribute_offset", data_type = "u16")),
+        member(field(name = "cluster_block_offset", data_type = "u16")),
+        member(field(name = "unknown1", data_type = "[u8; 2]")),
+        member(field(name = "target_vcn", data_type = "u64")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file operation header.
+///
+/// The operation header is stored at the start of the client data of an NTFS client record.
+pub struct NtfsLogFileOperationHeader {
+    /// Redo operation.
+    pub redo_operation: u16,
+
+    /// Undo operation.
+    pub undo_operation: u16,
+
+    /// Redo data offset, relative to the start of the operation header.
+    pub redo_data_offset: u16,
+
+    /// Redo data size.
+    pub redo_data_size: u16,
+
+    /// Undo data offset, relative to the start of the operation header.
+    pub undo_data_offset: u16,
+
+    /// Undo data size.
+    pub undo_data_size: u16,
+
+    /// Target attribute, which contains an index into the open attribute table.
+    pub target_attribute: u16,
+
+    /// Number of logical cluster numbers (LCNs) that follow the operation header.
+    pub number_of_lcns: u16,
+
+    /// Record offset.
+    pub record_offset: u16,
+
+    /// Attribute offset.
+    pub attribute_offset: u16,
+
+    /// Cluster block offset, in 512-byte units.
+    pub cluster_block_offset: u16,
+
+    /// Target virtual cluster number (VCN).
+    pub target_vcn: u64,
+}
+
+impl NtfsLogFileOperationHeader {
+    /// Creates a new operation header.
+    pub fn new() -> Self {
+        Self {
+            redo_operation: 0,
+            undo_operation: 0,
+            redo_data_offset: 0,
+            redo_data_size: 0,
+            undo_data_offset: 0,
+            undo_data_size: 0,
+            target_attribute: 0,
+            number_of_lcns: 0,
+            record_offset: 0,
+            attribute_offset: 0,
+            cluster_block_offset: 0,
+            target_vcn: 0,
+        }
+    }
+
+    /// Reads the operation header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 32 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file operation header data size"
+            ));
+        }
+        self.redo_operation = bytes_to_u16_le!(data, 0);
+        self.undo_operation = bytes_to_u16_le!(data, 2);
+        self.redo_data_offset = bytes_to_u16_le!(data, 4);
+        self.redo_data_size = bytes_to_u16_le!(data, 6);
+        self.undo_data_offset = bytes_to_u16_le!(data, 8);
+        self.undo_data_size = bytes_to_u16_le!(data, 10);
+        self.target_attribute = bytes_to_u16_le!(data, 12);
+        self.number_of_lcns = bytes_to_u16_le!(data, 14);
+        self.record_offset = bytes_to_u16_le!(data, 16);
+        self.attribute_offset = bytes_to_u16_le!(data, 18);
+        self.cluster_block_offset = bytes_to_u16_le!(data, 20);
+        self.target_vcn = bytes_to_u64_le!(data, 24);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x0e, 0x00, 0x0f, 0x00, 0x28, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00,
+            0x01, 0x00, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileOperationHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.redo_operation, 0x000e);
+        assert_eq!(test_struct.undo_operation, 0x000f);
+        assert_eq!(test_struct.redo_data_offset, 40);
+        assert_eq!(test_struct.redo_data_size, 16);
+        assert_eq!(test_struct.undo_data_offset, 0);
+        assert_eq!(test_struct.undo_data_size, 0);
+        assert_eq!(test_struct.target_attribute, 40);
+        assert_eq!(test_struct.number_of_lcns, 1);
+        assert_eq!(test_struct.record_offset, 72);
+        assert_eq!(test_struct.attribute_offset, 0);
+        assert_eq!(test_struct.cluster_block_offset, 0);
+        assert_eq!(test_struct.target_vcn, 16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileOperationHeader::new();
+        let result = test_struct.read_data(&test_data[0..31]);
+        assert!(result.is_err());
+    }
+}
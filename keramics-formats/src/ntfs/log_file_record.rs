@@ -0,0 +1,418 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_types::bytes_to_u64_le;
+
+use super::constants::*;
+use super::log_file_operation_header::NtfsLogFileOperationHeader;
+use super::log_file_record_header::NtfsLogFileRecordHeader;
+
+/// New Technologies File System (NTFS) log file record.
+pub struct NtfsLogFileRecord {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Log sequence number (LSN).
+    pub lsn: u64,
+
+    /// Client previous log sequence number (LSN).
+    pub client_previous_lsn: u64,
+
+    /// Client undo next log sequence number (LSN).
+    pub client_undo_next_lsn: u64,
+
+    /// Client index.
+    pub client_index: u16,
+
+    /// Record type.
+    pub record_type: u32,
+
+    /// Transaction identifier.
+    pub transaction_identifier: u32,
+
+    /// Flags.
+    pub flags: u16,
+
+    /// Redo operation.
+    pub redo_operation: u16,
+
+    /// Undo operation.
+    pub undo_operation: u16,
+
+    /// Target attribute, which contains an index into the open attribute table.
+    pub target_attribute: u16,
+
+    /// Record offset.
+    pub record_offset: u16,
+
+    /// Attribute offset.
+    pub attribute_offset: u16,
+
+    /// Cluster block offset, in 512-byte units.
+    pub cluster_block_offset: u16,
+
+    /// Target virtual cluster number (VCN).
+    pub target_vcn: u64,
+
+    /// Logical cluster numbers (LCNs).
+    pub lcns: Vec<u64>,
+
+    /// Redo data.
+    pub redo_data: Vec<u8>,
+
+    /// Undo data.
+    pub undo_data: Vec<u8>,
+
+    /// Number of the MFT entry targeted by the operation.
+    pub mft_entry_number: Option<u64>,
+
+    /// Virtual cluster number (VCN) of the index record targeted by the operation.
+    pub index_record_vcn: Option<u64>,
+}
+
+impl NtfsLogFileRecord {
+    /// Creates a new log file record.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            lsn: 0,
+            client_previous_lsn: 0,
+            client_undo_next_lsn: 0,
+            client_index: 0,
+            record_type: 0,
+            transaction_identifier: 0,
+            flags: 0,
+            redo_operation: 0,
+            undo_operation: 0,
+            target_attribute: 0,
+            record_offset: 0,
+            attribute_offset: 0,
+            cluster_block_offset: 0,
+            target_vcn: 0,
+            lcns: Vec::new(),
+            redo_data: Vec::new(),
+            undo_data: Vec::new(),
+            mft_entry_number: None,
+            index_record_vcn: None,
+        }
+    }
+
+    /// Reads the log file record from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 48 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS log file record data size"
+            ));
+        }
+        let mut record_header: NtfsLogFileRecordHeader = NtfsLogFileRecordHeader::new();
+
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(NtfsLogFileRecordHeader::debug_read_data(&data[0..48]));
+        }
+        match record_header.read_data(&data[0..48]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read record header");
+                return Err(error);
+            }
+        }
+        let data_end_offset: usize = 48 + record_header.client_data_size as usize;
+
+        if data_end_offset > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid client data size: {} value out of bounds",
+                record_header.client_data_size
+            )));
+        }
+        self.lsn = record_header.lsn;
+        self.client_previous_lsn = record_header.client_previous_lsn;
+        self.client_undo_next_lsn = record_header.client_undo_next_lsn;
+        self.client_index = record_header.client_index;
+        self.record_type = record_header.record_type;
+        self.transaction_identifier = record_header.transaction_identifier;
+        self.flags = record_header.flags;
+
+        // Only client records contain an operation, client restart records contain
+        // a restart area of the client instead.
+        if record_header.record_type == NTFS_LOG_FILE_RECORD_TYPE_CLIENT_RECORD {
+            match self.read_operation(&data[48..data_end_offset]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read operation");
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the operation from the client data.
+    fn read_operation(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 32 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS log file operation data size"
+            ));
+        }
+        let mut operation_header: NtfsLogFileOperationHeader = NtfsLogFileOperationHeader::new();
+
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(NtfsLogFileOperationHeader::debug_read_data(&data[0..32]));
+        }
+        match operation_header.read_data(&data[0..32]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read operation header");
+                return Err(error);
+            }
+        }
+        let lcns_end_offset: usize = 32 + (operation_header.number_of_lcns as usize) * 8;
+
+        if lcns_end_offset > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid number of LCNs: {} value out of bounds",
+                operation_header.number_of_lcns
+            )));
+        }
+        self.redo_operation = operation_header.redo_operation;
+        self.undo_operation = operation_header.undo_operation;
+        self.target_attribute = operation_header.target_attribute;
+        self.record_offset = operation_header.record_offset;
+        self.attribute_offset = operation_header.attribute_offset;
+        self.cluster_block_offset = operation_header.cluster_block_offset;
+        self.target_vcn = operation_header.target_vcn;
+
+        self.lcns = (32..lcns_end_offset)
+            .step_by(8)
+            .map(|data_offset| bytes_to_u64_le!(data, data_offset))
+            .collect::<Vec<u64>>();
+
+        self.redo_data = match Self::get_operation_data(
+            data,
+            operation_header.redo_data_offset,
+            operation_header.redo_data_size,
+        ) {
+            Some(redo_data) => redo_data.to_vec(),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid redo data offset: {} and size: {} value out of bounds",
+                    operation_header.redo_data_offset, operation_header.redo_data_size
+                )));
+            }
+        };
+        self.undo_data = match Self::get_operation_data(
+            data,
+            operation_header.undo_data_offset,
+            operation_header.undo_data_size,
+        ) {
+            Some(undo_data) => undo_data.to_vec(),
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid undo data offset: {} and size: {} value out of bounds",
+                    operation_header.undo_data_offset, operation_header.undo_data_size
+                )));
+            }
+        };
+        if Self::is_index_allocation_operation(self.get_target_operation()) {
+            self.index_record_vcn = Some(self.target_vcn);
+        }
+        Ok(())
+    }
+
+    /// Retrieves the redo or undo data of the operation.
+    fn get_operation_data(data: &[u8], data_offset: u16, data_size: u16) -> Option<&[u8]> {
+        if data_size == 0 {
+            return Some(&[]);
+        }
+        let data_offset: usize = data_offset as usize;
+        let data_end_offset: usize = data_offset + data_size as usize;
+
+        if data_offset < 32 || data_end_offset > data.len() {
+            return None;
+        }
+        Some(&data[data_offset..data_end_offset])
+    }
+
+    /// Retrieves the operation that determines the target of the record.
+    ///
+    /// Compensation log records and no operation redo records describe their target
+    /// with the undo operation.
+    fn get_target_operation(&self) -> u16 {
+        match self.redo_operation {
+            NTFS_LOG_FILE_OPERATION_NOOP | NTFS_LOG_FILE_OPERATION_COMPENSATION_LOG_RECORD => {
+                self.undo_operation
+            }
+            _ => self.redo_operation,
+        }
+    }
+
+    /// Determines if an operation targets a MFT entry.
+    fn is_mft_entry_operation(operation: u16) -> bool {
+        match operation {
+            NTFS_LOG_FILE_OPERATION_INITIALIZE_FILE_RECORD_SEGMENT
+            | NTFS_LOG_FILE_OPERATION_DEALLOCATE_FILE_RECORD_SEGMENT
+            | NTFS_LOG_FILE_OPERATION_WRITE_END_OF_FILE_RECORD_SEGMENT
+            | NTFS_LOG_FILE_OPERATION_CREATE_ATTRIBUTE
+            | NTFS_LOG_FILE_OPERATION_DELETE_ATTRIBUTE
+            | NTFS_LOG_FILE_OPERATION_UPDATE_RESIDENT_VALUE
+            | NTFS_LOG_FILE_OPERATION_UPDATE_MAPPING_PAIRS
+            | NTFS_LOG_FILE_OPERATION_SET_NEW_ATTRIBUTE_SIZES
+            | NTFS_LOG_FILE_OPERATION_ADD_INDEX_ENTRY_ROOT
+            | NTFS_LOG_FILE_OPERATION_DELETE_INDEX_ENTRY_ROOT
+            | NTFS_LOG_FILE_OPERATION_SET_INDEX_ENTRY_VCN_ROOT
+            | NTFS_LOG_FILE_OPERATION_UPDATE_FILE_NAME_ROOT
+            | NTFS_LOG_FILE_OPERATION_UPDATE_RECORD_DATA_ROOT => true,
+            _ => false,
+        }
+    }
+
+    /// Determines if an operation targets an index record of an index allocation.
+    fn is_index_allocation_operation(operation: u16) -> bool {
+        match operation {
+            NTFS_LOG_FILE_OPERATION_ADD_INDEX_ENTRY_ALLOCATION
+            | NTFS_LOG_FILE_OPERATION_DELETE_INDEX_ENTRY_ALLOCATION
+            | NTFS_LOG_FILE_OPERATION_WRITE_END_OF_INDEX_BUFFER
+            | NTFS_LOG_FILE_OPERATION_SET_INDEX_ENTRY_VCN_ALLOCATION
+            | NTFS_LOG_FILE_OPERATION_UPDATE_FILE_NAME_ALLOCATION
+            | NTFS_LOG_FILE_OPERATION_UPDATE_RECORD_DATA_ALLOCATION => true,
+            _ => false,
+        }
+    }
+
+    /// Determines the number of the MFT entry targeted by the operation.
+    ///
+    /// The target of MFT entry operations is stored as a VCN in the MFT and a cluster
+    /// block offset in 512-byte units.
+    pub(super) fn set_mft_entry_number(&mut self, cluster_block_size: u32, mft_entry_size: u32) {
+        if self.record_type != NTFS_LOG_FILE_RECORD_TYPE_CLIENT_RECORD
+            || mft_entry_size == 0
+            || !Self::is_mft_entry_operation(self.get_target_operation())
+        {
+            return;
+        }
+        self.mft_entry_number = self
+            .target_vcn
+            .checked_mul(cluster_block_size as u64)
+            .and_then(|offset| offset.checked_add((self.cluster_block_offset as u64) * 512))
+            .map(|offset| offset / (mft_entry_size as u64));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x20, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x28, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x01, 0x00, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a,
+            0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+        ];
+    }
+
+    fn get_test_data_mft_entry_operation() -> Vec<u8> {
+        return vec![
+            0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x12, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRecord::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.lsn, 0x2020);
+        assert_eq!(test_struct.client_previous_lsn, 0x2010);
+        assert_eq!(test_struct.client_undo_next_lsn, 0x2010);
+        assert_eq!(test_struct.record_type, 1);
+        assert_eq!(test_struct.transaction_identifier, 24);
+        assert_eq!(
+            test_struct.redo_operation,
+            NTFS_LOG_FILE_OPERATION_ADD_INDEX_ENTRY_ALLOCATION
+        );
+        assert_eq!(
+            test_struct.undo_operation,
+            NTFS_LOG_FILE_OPERATION_DELETE_INDEX_ENTRY_ALLOCATION
+        );
+        assert_eq!(test_struct.target_attribute, 40);
+        assert_eq!(test_struct.record_offset, 72);
+        assert_eq!(test_struct.target_vcn, 16);
+        assert_eq!(test_struct.lcns, [0x2000]);
+        assert_eq!(
+            test_struct.redo_data,
+            [
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+                0x0f, 0x10
+            ]
+        );
+        assert_eq!(test_struct.undo_data, []);
+        assert_eq!(test_struct.mft_entry_number, None);
+        assert_eq!(test_struct.index_record_vcn, Some(16));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRecord::new();
+        let result = test_struct.read_data(&test_data[0..47]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..96]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_set_mft_entry_number() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data_mft_entry_operation();
+
+        let mut test_struct = NtfsLogFileRecord::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.redo_operation,
+            NTFS_LOG_FILE_OPERATION_INITIALIZE_FILE_RECORD_SEGMENT
+        );
+        assert_eq!(test_struct.cluster_block_offset, 2);
+        assert_eq!(test_struct.target_vcn, 4);
+        assert_eq!(test_struct.mft_entry_number, None);
+        assert_eq!(test_struct.index_record_vcn, None);
+
+        test_struct.set_mft_entry_number(4096, 1024);
+        assert_eq!(test_struct.mft_entry_number, Some(17));
+
+        Ok(())
+    }
+}
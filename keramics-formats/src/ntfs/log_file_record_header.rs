@@ -0,0 +1,137 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "lsn", data_type = "u64")),
+        member(field(name = "client_previous_lsn", data_type = "u64")),
+        member(field(name = "client_undo_next_lsn", data_type = "u64")),
+        member(field(name = "client_data_size", data_type = "u32")),
+        member(field(name = "client_sequence_number", data_type = "u16")),
+        member(field(name = "client_index", data_type = "u16")),
+        member(field(name = "record_type", data_type = "u32")),
+        member(field(name = "transaction_identifier", data_type = "u32")),
+        member(field(name = "flags", data_type = "u16", format = "hex")),
+        member(field(name = "unknown1", data_type = "[u8; 6]")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file service record header.
+pub struct NtfsLogFileRecordHeader {
+    /// Log sequence number (LSN).
+    pub lsn: u64,
+
+    /// Client previous log sequence number (LSN).
+    pub client_previous_lsn: u64,
+
+    /// Client undo next log sequence number (LSN).
+    pub client_undo_next_lsn: u64,
+
+    /// Client data size.
+    pub client_data_size: u32,
+
+    /// Client index.
+    pub client_index: u16,
+
+    /// Record type.
+    pub record_type: u32,
+
+    /// Transaction identifier.
+    pub transaction_identifier: u32,
+
+    /// Flags.
+    pub flags: u16,
+}
+
+impl NtfsLogFileRecordHeader {
+    /// Creates a new record header.
+    pub fn new() -> Self {
+        Self {
+            lsn: 0,
+            client_previous_lsn: 0,
+            client_undo_next_lsn: 0,
+            client_data_size: 0,
+            client_index: 0,
+            record_type: 0,
+            transaction_identifier: 0,
+            flags: 0,
+        }
+    }
+
+    /// Reads the record header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 48 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file record header data size"
+            ));
+        }
+        self.lsn = bytes_to_u64_le!(data, 0);
+        self.client_previous_lsn = bytes_to_u64_le!(data, 8);
+        self.client_undo_next_lsn = bytes_to_u64_le!(data, 16);
+        self.client_data_size = bytes_to_u32_le!(data, 24);
+        self.client_index = bytes_to_u16_le!(data, 30);
+        self.record_type = bytes_to_u32_le!(data, 32);
+        self.transaction_identifier = bytes_to_u32_le!(data, 36);
+        self.flags = bytes_to_u16_le!(data, 40);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileRecordHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.lsn, 0x2010);
+        assert_eq!(test_struct.client_previous_lsn, 0x2000);
+        assert_eq!(test_struct.client_undo_next_lsn, 0x2000);
+        assert_eq!(test_struct.client_data_size, 40);
+        assert_eq!(test_struct.client_index, 0);
+        assert_eq!(test_struct.record_type, 1);
+        assert_eq!(test_struct.transaction_identifier, 24);
+        assert_eq!(test_struct.flags, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRecordHeader::new();
+        let result = test_struct.read_data(&test_data[0..47]);
+        assert!(result.is_err());
+    }
+}
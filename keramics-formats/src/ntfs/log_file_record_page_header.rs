@@ -0,0 +1,153 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "signature", data_type = "ByteString<4>")),
+        member(field(name = "fixup_values_offset", data_type = "u16")),
+        member(field(name = "number_of_fixup_values", data_type = "u16")),
+        member(field(name = "last_lsn", data_type = "u64")),
+        member(field(name = "flags", data_type = "u32", format = "hex")),
+        member(field(name = "page_count", data_type = "u16")),
+        member(field(name = "page_position", data_type = "u16")),
+        member(field(name = "next_record_offset", data_type = "u16")),
+        member(field(name = "unknown1", data_type = "[u8; 6]")),
+        member(field(name = "last_end_lsn", data_type = "u64")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file service record page header.
+pub struct NtfsLogFileRecordPageHeader {
+    /// Fix-up values offset.
+    pub fixup_values_offset: u16,
+
+    /// Number of fix-up values.
+    pub number_of_fixup_values: u16,
+
+    /// Last log sequence number (LSN) or file offset.
+    pub last_lsn: u64,
+
+    /// Flags.
+    pub flags: u32,
+
+    /// Page count.
+    pub page_count: u16,
+
+    /// Page position.
+    pub page_position: u16,
+
+    /// Next record offset, relative to the start of the page.
+    pub next_record_offset: u16,
+
+    /// Log sequence number (LSN) of the last record that ends in the page.
+    pub last_end_lsn: u64,
+}
+
+impl NtfsLogFileRecordPageHeader {
+    /// Creates a new record page header.
+    pub fn new() -> Self {
+        Self {
+            fixup_values_offset: 0,
+            number_of_fixup_values: 0,
+            last_lsn: 0,
+            flags: 0,
+            page_count: 0,
+            page_position: 0,
+            next_record_offset: 0,
+            last_end_lsn: 0,
+        }
+    }
+
+    /// Reads the record page header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 40 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file record page header data size"
+            ));
+        }
+        if data[0..4] != NTFS_LOG_FILE_RECORD_PAGE_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS log file record page signature"
+            ));
+        }
+        self.fixup_values_offset = bytes_to_u16_le!(data, 4);
+        self.number_of_fixup_values = bytes_to_u16_le!(data, 6);
+        self.last_lsn = bytes_to_u64_le!(data, 8);
+        self.flags = bytes_to_u32_le!(data, 16);
+        self.page_count = bytes_to_u16_le!(data, 20);
+        self.page_position = bytes_to_u16_le!(data, 22);
+        self.next_record_offset = bytes_to_u16_le!(data, 24);
+        self.last_end_lsn = bytes_to_u64_le!(data, 32);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x52, 0x43, 0x52, 0x44, 0x28, 0x00, 0x09, 0x00, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x98, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileRecordPageHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.fixup_values_offset, 40);
+        assert_eq!(test_struct.number_of_fixup_values, 9);
+        assert_eq!(test_struct.last_lsn, 0x2010);
+        assert_eq!(test_struct.flags, 0x00000001);
+        assert_eq!(test_struct.page_count, 1);
+        assert_eq!(test_struct.page_position, 1);
+        assert_eq!(test_struct.next_record_offset, 152);
+        assert_eq!(test_struct.last_end_lsn, 0x2010);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRecordPageHeader::new();
+        let result = test_struct.read_data(&test_data[0..39]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = NtfsLogFileRecordPageHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
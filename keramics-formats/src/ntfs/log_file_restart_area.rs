@@ -0,0 +1,159 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "current_lsn", data_type = "u64")),
+        member(field(name = "number_of_clients", data_type = "u16")),
+        member(field(name = "client_free_list", data_type = "u16")),
+        member(field(name = "client_in_use_list", data_type = "u16")),
+        member(field(name = "flags", data_type = "u16", format = "hex")),
+        member(field(name = "sequence_number_bits", data_type = "u32")),
+        member(field(name = "restart_area_size", data_type = "u16")),
+        member(field(name = "client_array_offset", data_type = "u16")),
+        member(field(name = "file_size", data_type = "u64")),
+        member(field(name = "last_lsn_data_size", data_type = "u32")),
+        member(field(name = "record_header_size", data_type = "u16")),
+        member(field(name = "page_data_offset", data_type = "u16")),
+        member(field(name = "restart_log_open_count", data_type = "u32")),
+        member(field(name = "unknown1", data_type = "[u8; 4]")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file service restart area.
+pub struct NtfsLogFileRestartArea {
+    /// Current log sequence number (LSN).
+    pub current_lsn: u64,
+
+    /// Number of clients.
+    pub number_of_clients: u16,
+
+    /// Client free list.
+    pub client_free_list: u16,
+
+    /// Client in-use list.
+    pub client_in_use_list: u16,
+
+    /// Flags.
+    pub flags: u16,
+
+    /// Number of bits of a log sequence number (LSN) used to store the sequence number.
+    pub sequence_number_bits: u32,
+
+    /// Restart area size.
+    pub restart_area_size: u16,
+
+    /// Client array offset, relative to the start of the restart area.
+    pub client_array_offset: u16,
+
+    /// File size.
+    pub file_size: u64,
+
+    /// Log record header size.
+    pub record_header_size: u16,
+
+    /// Log record page data offset.
+    pub page_data_offset: u16,
+}
+
+impl NtfsLogFileRestartArea {
+    /// Creates a new restart area.
+    pub fn new() -> Self {
+        Self {
+            current_lsn: 0,
+            number_of_clients: 0,
+            client_free_list: 0,
+            client_in_use_list: 0,
+            flags: 0,
+            sequence_number_bits: 0,
+            restart_area_size: 0,
+            client_array_offset: 0,
+            file_size: 0,
+            record_header_size: 0,
+            page_data_offset: 0,
+        }
+    }
+
+    /// Reads the restart area from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 48 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file restart area data size"
+            ));
+        }
+        self.current_lsn = bytes_to_u64_le!(data, 0);
+        self.number_of_clients = bytes_to_u16_le!(data, 8);
+        self.client_free_list = bytes_to_u16_le!(data, 10);
+        self.client_in_use_list = bytes_to_u16_le!(data, 12);
+        self.flags = bytes_to_u16_le!(data, 14);
+        self.sequence_number_bits = bytes_to_u32_le!(data, 16);
+        self.restart_area_size = bytes_to_u16_le!(data, 20);
+        self.client_array_offset = bytes_to_u16_le!(data, 22);
+        self.file_size = bytes_to_u64_le!(data, 24);
+        self.record_header_size = bytes_to_u16_le!(data, 36);
+        self.page_data_offset = bytes_to_u16_le!(data, 38);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0xff, 0xff, 0x00, 0x00,
+            0x02, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x40, 0x00, 0x30, 0x00, 0x00, 0x40, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x40, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileRestartArea::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.current_lsn, 0x2010);
+        assert_eq!(test_struct.number_of_clients, 1);
+        assert_eq!(test_struct.client_free_list, 0xffff);
+        assert_eq!(test_struct.client_in_use_list, 0);
+        assert_eq!(test_struct.flags, 0x0002);
+        assert_eq!(test_struct.sequence_number_bits, 44);
+        assert_eq!(test_struct.restart_area_size, 64);
+        assert_eq!(test_struct.client_array_offset, 48);
+        assert_eq!(test_struct.file_size, 16384);
+        assert_eq!(test_struct.record_header_size, 48);
+        assert_eq!(test_struct.page_data_offset, 64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRestartArea::new();
+        let result = test_struct.read_data(&test_data[0..47]);
+        assert!(result.is_err());
+    }
+}
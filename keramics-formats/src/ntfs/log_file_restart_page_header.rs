@@ -0,0 +1,154 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "signature", data_type = "ByteString<4>")),
+        member(field(name = "fixup_values_offset", data_type = "u16")),
+        member(field(name = "number_of_fixup_values", data_type = "u16")),
+        member(field(name = "chkdsk_last_lsn", data_type = "u64")),
+        member(field(name = "system_page_size", data_type = "u32")),
+        member(field(name = "log_page_size", data_type = "u32")),
+        member(field(name = "restart_area_offset", data_type = "u16")),
+        member(field(name = "minor_format_version", data_type = "i16")),
+        member(field(name = "major_format_version", data_type = "i16")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) log file service restart page header.
+pub struct NtfsLogFileRestartPageHeader {
+    /// Fix-up values offset.
+    pub fixup_values_offset: u16,
+
+    /// Number of fix-up values.
+    pub number_of_fixup_values: u16,
+
+    /// Checkdisk last log sequence number (LSN).
+    pub chkdsk_last_lsn: u64,
+
+    /// System page size.
+    pub system_page_size: u32,
+
+    /// Log page size.
+    pub log_page_size: u32,
+
+    /// Restart area offset.
+    pub restart_area_offset: u16,
+
+    /// Minor format version.
+    pub minor_format_version: i16,
+
+    /// Major format version.
+    pub major_format_version: i16,
+}
+
+impl NtfsLogFileRestartPageHeader {
+    /// Creates a new restart page header.
+    pub fn new() -> Self {
+        Self {
+            fixup_values_offset: 0,
+            number_of_fixup_values: 0,
+            chkdsk_last_lsn: 0,
+            system_page_size: 0,
+            log_page_size: 0,
+            restart_area_offset: 0,
+            minor_format_version: 0,
+            major_format_version: 0,
+        }
+    }
+
+    /// Reads the restart page header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 30 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported log file restart page header data size"
+            ));
+        }
+        if data[0..4] != NTFS_LOG_FILE_RESTART_PAGE_SIGNATURE
+            && data[0..4] != NTFS_LOG_FILE_CHKDSK_PAGE_SIGNATURE
+        {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS log file restart page signature"
+            ));
+        }
+        self.fixup_values_offset = bytes_to_u16_le!(data, 4);
+        self.number_of_fixup_values = bytes_to_u16_le!(data, 6);
+        self.chkdsk_last_lsn = bytes_to_u64_le!(data, 8);
+        self.system_page_size = bytes_to_u32_le!(data, 16);
+        self.log_page_size = bytes_to_u32_le!(data, 20);
+        self.restart_area_offset = bytes_to_u16_le!(data, 24);
+        self.minor_format_version = bytes_to_u16_le!(data, 26) as i16;
+        self.major_format_version = bytes_to_u16_le!(data, 28) as i16;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x52, 0x53, 0x54, 0x52, 0x1e, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x30, 0x00, 0x01, 0x00,
+            0x01, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsLogFileRestartPageHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.fixup_values_offset, 30);
+        assert_eq!(test_struct.number_of_fixup_values, 9);
+        assert_eq!(test_struct.chkdsk_last_lsn, 0);
+        assert_eq!(test_struct.system_page_size, 4096);
+        assert_eq!(test_struct.log_page_size, 4096);
+        assert_eq!(test_struct.restart_area_offset, 48);
+        assert_eq!(test_struct.minor_format_version, 1);
+        assert_eq!(test_struct.major_format_version, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsLogFileRestartPageHeader::new();
+        let result = test_struct.read_data(&test_data[0..29]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = NtfsLogFileRestartPageHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
@@ -26,6 +26,7 @@ use super::data_run::NtfsDataRunType;
 use super::mft_attribute::NtfsMftAttribute;
 use super::mft_attributes::NtfsMftAttributes;
 use super::mft_entry::NtfsMftEntry;
+use super::mft_entry_header::NtfsMftEntryHeader;
 
 /// New Technologies File System (NTFS) Master File Table (MFT).
 pub struct NtfsMasterFileTable {
@@ -33,13 +34,16 @@ pub struct NtfsMasterFileTable {
     pub cluster_block_size: u32,
 
     /// MFT entry size.
-    mft_entry_size: u32,
+    pub mft_entry_size: u32,
 
     /// Number of entries.
     pub number_of_entries: u64,
 
     /// Block tree.
     block_tree: BlockTree<NtfsBlockRange>,
+
+    /// Value to indicate corrupted MFT entries should be returned instead of an error.
+    pub is_lenient: bool,
 }
 
 impl NtfsMasterFileTable {
@@ -50,6 +54,7 @@ impl NtfsMasterFileTable {
             mft_entry_size: 0,
             number_of_entries: 0,
             block_tree: BlockTree::<NtfsBlockRange>::new(0, 0, 0),
+            is_lenient: false,
         }
     }
 
@@ -144,6 +149,12 @@ impl NtfsMasterFileTable {
                 return Err(error);
             }
         }
+        if mft_entry.is_corrupted && !self.is_lenient {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Corrupted MFT entry: {} fix-up values - {}",
+                entry_number, mft_entry.fixup_value_mismatches[0]
+            )));
+        }
         Ok(mft_entry)
     }
 
@@ -170,6 +181,12 @@ impl NtfsMasterFileTable {
                 "Unsupported marked bad MFT entry: 0"
             ));
         }
+        if mft_entry.is_corrupted && !self.is_lenient {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Corrupted MFT entry: 0 fix-up values - {}",
+                mft_entry.fixup_value_mismatches[0]
+            )));
+        }
         if !mft_entry.is_allocated {
             return Err(keramics_core::error_trace_new!(
                 "Unsupported unallocated MFT entry: 0"
@@ -305,12 +322,95 @@ impl NtfsMasterFileTable {
         }
         Ok(())
     }
+
+    /// Reads the master file table from a standalone $MFT data stream.
+    ///
+    /// If no MFT entry size is provided it is determined from the header of the first MFT
+    /// entry. Since there is no volume the cluster block size is 0 and non-resident data is not
+    /// available.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+    ) -> Result<(), ErrorTrace> {
+        let mft_entry_size: u32 = match mft_entry_size {
+            Some(mft_entry_size) => mft_entry_size,
+            None => {
+                let mut data: [u8; 42] = [0; 42];
+
+                keramics_core::data_stream_read_exact_at_position!(
+                    data_stream,
+                    &mut data,
+                    SeekFrom::Start(0)
+                );
+                let mut mft_entry_header: NtfsMftEntryHeader = NtfsMftEntryHeader::new();
+
+                match mft_entry_header.read_data(&data) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read MFT entry: 0 header"
+                        );
+                        return Err(error);
+                    }
+                }
+                mft_entry_header.mft_entry_size
+            }
+        };
+        // Note that 42 is the minimum MFT entry size and 65535 is chosen given the fix-up values
+        // and attributes offsets of the MFT entry are 16-bit.
+        if mft_entry_size < 42 || mft_entry_size > 65535 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported MFT entry size: {} value out of bounds",
+                mft_entry_size
+            )));
+        }
+        let data_size: u64 = keramics_core::data_stream_get_size!(data_stream);
+
+        let number_of_mft_entries: u64 = data_size / (mft_entry_size as u64);
+
+        if number_of_mft_entries == 0 || number_of_mft_entries >= u32::MAX as u64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid number of MFT entries: {} value out of bounds",
+                number_of_mft_entries
+            )));
+        }
+        let block_tree_size: u64 = number_of_mft_entries * (mft_entry_size as u64);
+
+        self.block_tree =
+            BlockTree::<NtfsBlockRange>::new(block_tree_size, 0, mft_entry_size as u64);
+
+        // The $MFT data stream is mapped as a single range, where the cluster block number of 0
+        // maps the virtual cluster offset onto the same offset in the data stream.
+        let block_range: NtfsBlockRange =
+            NtfsBlockRange::new(0, 0, number_of_mft_entries, NtfsBlockRangeType::InFile);
+        match self
+            .block_tree
+            .insert_value(0, block_tree_size, block_range)
+        {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to insert block range into block tree",
+                    error
+                ));
+            }
+        };
+        self.cluster_block_size = 0;
+        self.mft_entry_size = mft_entry_size;
+        self.number_of_entries = number_of_mft_entries;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use keramics_core::open_fake_data_stream;
+
     fn get_test_mft_attribute_data() -> Vec<u8> {
         return vec![
             0x80, 0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x01, 0x00, 0x40, 0x00, 0x00, 0x00,
@@ -341,6 +441,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = vec![0; 2048];
+        test_data[0..4].copy_from_slice(&NTFS_MFT_ENTRY_SIGNATURE);
+        test_data[28..32].copy_from_slice(&[0x00, 0x04, 0x00, 0x00]);
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut test_struct: NtfsMasterFileTable = NtfsMasterFileTable::new();
+        test_struct.read_data_stream(&data_stream, None)?;
+
+        assert_eq!(test_struct.cluster_block_size, 0);
+        assert_eq!(test_struct.mft_entry_size, 1024);
+        assert_eq!(test_struct.number_of_entries, 2);
+
+        let mut test_struct: NtfsMasterFileTable = NtfsMasterFileTable::new();
+        let result = test_struct.read_data_stream(&data_stream, Some(4096));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_signature() {
+        let test_data: Vec<u8> = vec![0; 2048];
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut test_struct: NtfsMasterFileTable = NtfsMasterFileTable::new();
+        let result = test_struct.read_data_stream(&data_stream, None);
+        assert!(result.is_err());
+    }
+
     // TODO: add tests for get_entry
     // TODO: add tests for initialize
 }
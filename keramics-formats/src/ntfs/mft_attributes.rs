@@ -20,6 +20,7 @@ use keramics_types::Ucs2String;
 use super::block_stream::NtfsBlockStream;
 use super::compressed_stream::NtfsCompressedStream;
 use super::constants::*;
+use super::file_name::NtfsFileName;
 use super::mft_attribute::NtfsMftAttribute;
 use super::mft_attribute_group::NtfsMftAttributeGroup;
 use super::reparse_point::NtfsReparsePoint;
@@ -40,6 +41,9 @@ pub struct NtfsMftAttributes {
     /// Indexes of data attributes in the attributes vector.
     data_attributes: Vec<usize>,
 
+    /// File names.
+    pub file_names: Vec<NtfsFileName>,
+
     /// Reparse point.
     pub reparse_point: Option<NtfsReparsePoint>,
 
@@ -55,6 +59,7 @@ impl NtfsMftAttributes {
             attribute_groups: HashMap::new(),
             attribute_list: None,
             data_attributes: Vec::new(),
+            file_names: Vec::new(),
             reparse_point: None,
             standard_information: None,
         }
@@ -115,6 +120,20 @@ impl NtfsMftAttributes {
                     NTFS_ATTRIBUTE_TYPE_DATA => {
                         self.data_attributes.push(attribute_index);
                     }
+                    NTFS_ATTRIBUTE_TYPE_FILE_NAME => {
+                        let file_name: NtfsFileName = match NtfsFileName::from_attribute(&attribute)
+                        {
+                            Ok(file_name) => file_name,
+                            Err(mut error) => {
+                                keramics_core::error_trace_add_frame!(
+                                    error,
+                                    "Unable to create file name from attribute"
+                                );
+                                return Err(error);
+                            }
+                        };
+                        self.file_names.push(file_name);
+                    }
                     NTFS_ATTRIBUTE_TYPE_REPARSE_POINT => {
                         if self.reparse_point.is_some() {
                             return Err(keramics_core::error_trace_new!(
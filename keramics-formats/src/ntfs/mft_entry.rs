@@ -17,6 +17,7 @@ use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStreamReference, ErrorTrace};
 
 use super::constants::*;
+use super::fixup_value_mismatch::NtfsFixupValueMismatch;
 use super::fixup_values::apply_fixup_values;
 use super::mft_attribute::NtfsMftAttribute;
 use super::mft_attributes::NtfsMftAttributes;
@@ -52,6 +53,12 @@ pub struct NtfsMftEntry {
 
     /// Value to indicate the MFT entry is allocated (used).
     pub is_allocated: bool,
+
+    /// Value to indicate the MFT entry is corrupted.
+    pub is_corrupted: bool,
+
+    /// Fix-up value mismatches.
+    pub fixup_value_mismatches: Vec<NtfsFixupValueMismatch>,
 }
 
 impl NtfsMftEntry {
@@ -67,10 +74,15 @@ impl NtfsMftEntry {
             is_empty: false,
             is_bad: false,
             is_allocated: false,
+            is_corrupted: false,
+            fixup_value_mismatches: Vec::new(),
         }
     }
 
     /// Reads the attributes.
+    ///
+    /// For a corrupted MFT entry the attributes are read on a best-effort basis, where reading
+    /// stops at the first attribute that cannot be read.
     pub fn read_attributes(
         &self,
         mft_attributes: &mut NtfsMftAttributes,
@@ -80,6 +92,9 @@ impl NtfsMftEntry {
 
         loop {
             if data_offset > data_size - 4 {
+                if self.is_corrupted {
+                    break;
+                }
                 return Err(keramics_core::error_trace_new!(format!(
                     "Invalid data offset: {} value out of bounds",
                     data_offset
@@ -94,15 +109,29 @@ impl NtfsMftEntry {
             match mft_attribute.read_data(&self.data[data_offset..]) {
                 Ok(_) => {}
                 Err(mut error) => {
+                    if self.is_corrupted {
+                        break;
+                    }
                     keramics_core::error_trace_add_frame!(error, "Unable to read MFT attribute");
                     return Err(error);
                 }
             }
+            if mft_attribute.attribute_size == 0 {
+                if self.is_corrupted {
+                    break;
+                }
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid MFT attribute size: 0 value out of bounds"
+                ));
+            }
             data_offset += mft_attribute.attribute_size as usize;
 
             match mft_attributes.add_attribute(mft_attribute) {
                 Ok(_) => {}
                 Err(mut error) => {
+                    if self.is_corrupted {
+                        break;
+                    }
                     keramics_core::error_trace_add_frame!(error, "Unable to add MFT attribute");
                     return Err(error);
                 }
@@ -171,23 +200,24 @@ impl NtfsMftEntry {
                 mft_entry_header.fixup_values_offset, mft_entry_header.attributes_offset,
             )));
         }
-        // TODO: set is_corrupted (or equiv) when fix-up values are corrupted.
-        match apply_fixup_values(
+        self.fixup_value_mismatches = match apply_fixup_values(
             data,
             mft_entry_header.fixup_values_offset,
             mft_entry_header.number_of_fixup_values,
         ) {
-            Ok(_) => {}
+            Ok(fixup_value_mismatches) => fixup_value_mismatches,
             Err(mut error) => {
                 keramics_core::error_trace_add_frame!(error, "Unable to apply fix-up values");
                 return Err(error);
             }
-        }
+        };
+        self.is_corrupted = !self.fixup_value_mismatches.is_empty();
+
         self.sequence_number = mft_entry_header.sequence_number;
         self.base_record_file_reference = mft_entry_header.base_record_file_reference;
         self.journal_sequence_number = mft_entry_header.journal_sequence_number;
         self.attributes_offset = mft_entry_header.attributes_offset;
-        self.is_allocated = true;
+        self.is_allocated = mft_entry_header.flags & NTFS_MFT_ENTRY_FLAG_IN_USE != 0;
 
         Ok(())
     }
@@ -370,6 +400,30 @@ mod tests {
         assert_eq!(test_struct.is_empty, false);
         assert_eq!(test_struct.is_bad, false);
         assert_eq!(test_struct.is_allocated, true);
+        assert_eq!(test_struct.is_corrupted, false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_corruption() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[1022] = 0xff;
+
+        let mut test_struct = NtfsMftEntry::new();
+        test_struct.read_data(&mut test_data)?;
+
+        assert_eq!(test_struct.is_corrupted, true);
+        assert_eq!(
+            test_struct.fixup_value_mismatches,
+            vec![NtfsFixupValueMismatch::new(1, 0x0002, 0x00ff)]
+        );
+        test_struct.data = test_data;
+
+        let mut mft_attributes: NtfsMftAttributes = NtfsMftAttributes::new();
+        test_struct.read_attributes(&mut mft_attributes)?;
+
+        assert_eq!(mft_attributes.attributes.len(), 4);
 
         Ok(())
     }
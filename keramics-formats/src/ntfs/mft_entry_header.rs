@@ -55,6 +55,9 @@ pub struct NtfsMftEntryHeader {
     /// Attributes offset.
     pub attributes_offset: u16,
 
+    /// Flags.
+    pub flags: u16,
+
     /// MFT entry size.
     pub mft_entry_size: u32,
 
@@ -71,6 +74,7 @@ impl NtfsMftEntryHeader {
             journal_sequence_number: 0,
             sequence_number: 0,
             attributes_offset: 0,
+            flags: 0,
             mft_entry_size: 0,
             base_record_file_reference: 0,
         }
@@ -93,6 +97,7 @@ impl NtfsMftEntryHeader {
         self.journal_sequence_number = bytes_to_u64_le!(data, 8);
         self.sequence_number = bytes_to_u16_le!(data, 16);
         self.attributes_offset = bytes_to_u16_le!(data, 20);
+        self.flags = bytes_to_u16_le!(data, 22);
         self.mft_entry_size = bytes_to_u32_le!(data, 28);
         self.base_record_file_reference = bytes_to_u64_le!(data, 32);
 
@@ -125,6 +130,7 @@ mod tests {
         assert_eq!(test_struct.journal_sequence_number, 0);
         assert_eq!(test_struct.sequence_number, 1);
         assert_eq!(test_struct.attributes_offset, 56);
+        assert_eq!(test_struct.flags, 0x0001);
         assert_eq!(test_struct.mft_entry_size, 1024);
         assert_eq!(test_struct.base_record_file_reference, 0);
 
@@ -0,0 +1,519 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::Ucs2String;
+
+use super::constants::*;
+use super::file_entry::NtfsFileEntry;
+use super::file_name::NtfsFileName;
+use super::master_file_table::NtfsMasterFileTable;
+use super::mft_entry::NtfsMftEntry;
+use super::path::NtfsPath;
+use super::security_descriptors::NtfsSecurityDescriptors;
+
+/// New Technologies File System (NTFS) standalone Master File Table (MFT) file.
+///
+/// Reads the MFT entries of an extracted "$MFT" metadata file without the corresponding volume.
+/// Since there is no volume only resident data is available and paths are reconstructed using
+/// the parent file references of the $FILE_NAME attributes.
+pub struct NtfsMftFile {
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Master File Table (MFT).
+    mft: Arc<NtfsMasterFileTable>,
+
+    /// Case folding mappings, which are empty since there is no "$UpCase" metadata file.
+    case_folding_mappings: Arc<HashMap<u16, u16>>,
+
+    /// Security descriptors, which are empty since there is no "$Secure" metadata file.
+    security_descriptors: Arc<NtfsSecurityDescriptors>,
+
+    /// Directory paths per MFT entry number, with the sequence number and allocation status.
+    directory_paths: HashMap<u64, (u16, bool, NtfsPath)>,
+}
+
+impl NtfsMftFile {
+    const ORPHAN_FILES_DIRECTORY_NAME: &'static str = "$OrphanFiles";
+
+    /// Creates a new MFT file.
+    pub fn new() -> Self {
+        Self {
+            data_stream: None,
+            mft: Arc::new(NtfsMasterFileTable::new()),
+            case_folding_mappings: Arc::new(HashMap::new()),
+            security_descriptors: Arc::new(NtfsSecurityDescriptors::new()),
+            directory_paths: HashMap::new(),
+        }
+    }
+
+    /// Retrieves the MFT entry size.
+    pub fn get_mft_entry_size(&self) -> u32 {
+        self.mft.mft_entry_size
+    }
+
+    /// Retrieves the number of file entries (MFT entries).
+    pub fn get_number_of_file_entries(&self) -> u64 {
+        self.mft.number_of_entries
+    }
+
+    /// Retrieves the file entry for a specific identifier (MFT entry number).
+    ///
+    /// The name and directory entry of the file entry are set from its $FILE_NAME attributes.
+    /// The attributes of empty and marked bad MFT entries are not read.
+    pub fn get_file_entry_by_identifier(
+        &self,
+        mft_entry_number: u64,
+    ) -> Result<NtfsFileEntry, ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        if mft_entry_number >= self.mft.number_of_entries {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid MFT entry number: {} value out of bounds",
+                mft_entry_number
+            )));
+        }
+        let mft_entry: NtfsMftEntry = match self.mft.get_entry(data_stream, mft_entry_number) {
+            Ok(mft_entry) => mft_entry,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to retrieve MFT entry: {}", mft_entry_number)
+                );
+                return Err(error);
+            }
+        };
+        let read_attributes: bool = !mft_entry.is_empty && !mft_entry.is_bad;
+
+        let mut file_entry: NtfsFileEntry = NtfsFileEntry::new(
+            data_stream,
+            &self.mft,
+            &self.case_folding_mappings,
+            &self.security_descriptors,
+            mft_entry_number,
+            mft_entry,
+            None,
+            None,
+        );
+        if read_attributes {
+            match file_entry.read_attributes() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read attributes");
+                    return Err(error);
+                }
+            }
+            match file_entry.set_directory_entry_from_attributes() {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to set directory entry from attributes"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(file_entry)
+    }
+
+    /// Retrieves the path for a specific identifier (MFT entry number).
+    ///
+    /// Returns None if the MFT entry has no $FILE_NAME attribute.
+    pub fn get_path_by_identifier(
+        &mut self,
+        mft_entry_number: u64,
+    ) -> Result<Option<NtfsPath>, ErrorTrace> {
+        if mft_entry_number == NTFS_ROOT_DIRECTORY_IDENTIFIER {
+            return Ok(Some(NtfsPath::from("\\")));
+        }
+        let file_entry: NtfsFileEntry = match self.get_file_entry_by_identifier(mft_entry_number) {
+            Ok(file_entry) => file_entry,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to retrieve file entry: {}", mft_entry_number)
+                );
+                return Err(error);
+            }
+        };
+        Ok(self.get_path_of_file_entry(&file_entry))
+    }
+
+    /// Retrieves the path of a file entry.
+    ///
+    /// The path is reconstructed by following the parent file references of the $FILE_NAME
+    /// attributes up to the root directory. If a parent cannot be resolved, for example because
+    /// its MFT entry was reused, the remainder of the path is placed under "\$OrphanFiles".
+    /// Returns None if the file entry has no $FILE_NAME attribute.
+    pub fn get_path_of_file_entry(&mut self, file_entry: &NtfsFileEntry) -> Option<NtfsPath> {
+        if file_entry.mft_entry_number == NTFS_ROOT_DIRECTORY_IDENTIFIER {
+            return Some(NtfsPath::from("\\"));
+        }
+        let file_name: &NtfsFileName = match file_entry.get_directory_entry_file_name() {
+            Some(file_name) => file_name,
+            None => return None,
+        };
+        let parent_path: NtfsPath = self.get_directory_path(file_name.parent_file_reference);
+
+        Some(parent_path.new_with_join(&[file_name.name.clone()]))
+    }
+
+    /// Retrieves the path of a directory for a specific file reference.
+    fn get_directory_path(&mut self, file_reference: u64) -> NtfsPath {
+        let mut file_reference: u64 = file_reference;
+        let mut directories: Vec<(u64, u16, bool, Ucs2String)> = Vec::new();
+        let mut visited_mft_entries: HashSet<u64> = HashSet::new();
+
+        let mut path: NtfsPath = loop {
+            let mft_entry_number: u64 = file_reference & 0x0000ffffffffffff;
+            let sequence_number: u16 = (file_reference >> 48) as u16;
+
+            if let Some((directory_sequence_number, is_allocated, directory_path)) =
+                self.directory_paths.get(&mft_entry_number)
+            {
+                if Self::is_parent_sequence_number(
+                    sequence_number,
+                    *directory_sequence_number,
+                    *is_allocated,
+                ) {
+                    break directory_path.clone();
+                }
+                break Self::get_orphan_files_path();
+            }
+            if mft_entry_number == NTFS_ROOT_DIRECTORY_IDENTIFIER {
+                break NtfsPath::from("\\");
+            }
+            // Note that visited MFT entries are tracked to prevent loops.
+            if !visited_mft_entries.insert(mft_entry_number) {
+                break Self::get_orphan_files_path();
+            }
+            let file_entry: NtfsFileEntry =
+                match self.get_file_entry_by_identifier(mft_entry_number) {
+                    Ok(file_entry) => file_entry,
+                    Err(_) => break Self::get_orphan_files_path(),
+                };
+            let is_allocated: bool = file_entry.is_allocated();
+
+            if !Self::is_parent_sequence_number(
+                sequence_number,
+                file_entry.sequence_number,
+                is_allocated,
+            ) {
+                break Self::get_orphan_files_path();
+            }
+            let file_name: &NtfsFileName = match file_entry.get_directory_entry_file_name() {
+                Some(file_name) => file_name,
+                None => break Self::get_orphan_files_path(),
+            };
+            directories.push((
+                mft_entry_number,
+                file_entry.sequence_number,
+                is_allocated,
+                file_name.name.clone(),
+            ));
+            file_reference = file_name.parent_file_reference;
+        };
+        while let Some((mft_entry_number, sequence_number, is_allocated, name)) = directories.pop()
+        {
+            path.push(name);
+
+            self.directory_paths.insert(
+                mft_entry_number,
+                (sequence_number, is_allocated, path.clone()),
+            );
+        }
+        path
+    }
+
+    /// Retrieves the path of the orphan files directory.
+    fn get_orphan_files_path() -> NtfsPath {
+        NtfsPath::from(
+            &[
+                Ucs2String::new(),
+                Ucs2String::from(Self::ORPHAN_FILES_DIRECTORY_NAME),
+            ][..],
+        )
+    }
+
+    /// Determines if a sequence number of a parent file reference matches a directory.
+    ///
+    /// When a MFT entry is deallocated its sequence number is incremented, hence the parent
+    /// file reference of a file in a deleted directory contains the sequence number minus 1.
+    fn is_parent_sequence_number(
+        sequence_number: u16,
+        directory_sequence_number: u16,
+        is_allocated: bool,
+    ) -> bool {
+        sequence_number == directory_sequence_number
+            || (!is_allocated && sequence_number.wrapping_add(1) == directory_sequence_number)
+    }
+
+    /// Reads the MFT file from a "$MFT" data stream.
+    ///
+    /// If no MFT entry size is provided it is determined from the first MFT entry.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+    ) -> Result<(), ErrorTrace> {
+        let mft: &mut NtfsMasterFileTable = match Arc::get_mut(&mut self.mft) {
+            Some(mft) => mft,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unable to obtain mutable reference to master file table"
+                ));
+            }
+        };
+        match mft.read_data_stream(data_stream, mft_entry_size) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read master file table");
+                return Err(error);
+            }
+        }
+        self.data_stream = Some(data_stream.clone());
+        self.directory_paths = HashMap::new();
+
+        Ok(())
+    }
+
+    /// Sets the lenient mode.
+    ///
+    /// In lenient mode MFT entries with corrupted fix-up values are used on a best-effort basis
+    /// instead of returning an error. The lenient mode must be set before the MFT file is read.
+    pub fn set_lenient_mode(&mut self, is_lenient: bool) -> Result<(), ErrorTrace> {
+        match Arc::get_mut(&mut self.mft) {
+            Some(mft) => mft.is_lenient = is_lenient,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unable to obtain mutable reference to master file table"
+                ));
+            }
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x46, 0x49, 0x4c, 0x45, 0x30, 0x00, 0x03, 0x00, 0x52, 0x51, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x38, 0x00, 0x01, 0x00, 0xa8, 0x01, 0x00, 0x00,
+            0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0xe7, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x10, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x96, 0xd5, 0x86, 0xa0,
+            0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5, 0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5,
+            0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5, 0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01,
+            0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00,
+            0x00, 0x00, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00,
+            0x4a, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x96, 0xd5, 0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5, 0x86, 0xa0,
+            0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5, 0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01, 0x96, 0xd5,
+            0x86, 0xa0, 0x08, 0x60, 0xd5, 0x01, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x04, 0x03, 0x24, 0x00, 0x4d, 0x00, 0x46, 0x00, 0x54, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x40, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x04,
+            0xfa, 0x00, 0x21, 0x3c, 0x85, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0xb0, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00, 0x01, 0x00, 0x40, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x01, 0xf9, 0x00, 0x21, 0x01,
+            0xe7, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+            0x00, 0x00, 0x00, 0x00, 0x21, 0x04, 0xfa, 0x00, 0x21, 0x3c, 0x85, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x21, 0x01, 0xf9, 0x00, 0x21, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x02, 0x00,
+        ];
+    }
+
+    fn get_mft_file() -> Result<NtfsMftFile, ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut mft_file: NtfsMftFile = NtfsMftFile::new();
+        mft_file.read_data_stream(&data_stream, None)?;
+
+        Ok(mft_file)
+    }
+
+    #[test]
+    fn test_get_number_of_file_entries() -> Result<(), ErrorTrace> {
+        let mft_file: NtfsMftFile = get_mft_file()?;
+
+        assert_eq!(mft_file.get_mft_entry_size(), 1024);
+        assert_eq!(mft_file.get_number_of_file_entries(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_identifier() -> Result<(), ErrorTrace> {
+        let mft_file: NtfsMftFile = get_mft_file()?;
+
+        let file_entry: NtfsFileEntry = mft_file.get_file_entry_by_identifier(0)?;
+        assert_eq!(file_entry.get_name(), Some(&Ucs2String::from("$MFT")));
+        assert_eq!(
+            file_entry.get_parent_file_reference(),
+            Some(0x0005000000000005)
+        );
+        assert_eq!(file_entry.get_size(), 262144);
+
+        let result: Result<NtfsFileEntry, ErrorTrace> = mft_file.get_file_entry_by_identifier(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_identifier_with_non_resident_attribute_list() -> Result<(), ErrorTrace>
+    {
+        let mut test_data: Vec<u8> = get_test_data();
+
+        // Change the non-resident $DATA attribute into a non-resident $ATTRIBUTE_LIST.
+        test_data[256] = 0x20;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut mft_file: NtfsMftFile = NtfsMftFile::new();
+        mft_file.read_data_stream(&data_stream, None)?;
+
+        let file_entry: NtfsFileEntry = mft_file.get_file_entry_by_identifier(0)?;
+        assert_eq!(file_entry.get_name(), Some(&Ucs2String::from("$MFT")));
+
+        let path: Option<NtfsPath> = mft_file.get_path_of_file_entry(&file_entry);
+        assert_eq!(path, Some(NtfsPath::from("\\$MFT")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_path_by_identifier() -> Result<(), ErrorTrace> {
+        let mut mft_file: NtfsMftFile = get_mft_file()?;
+
+        let path: Option<NtfsPath> = mft_file.get_path_by_identifier(0)?;
+        assert_eq!(path, Some(NtfsPath::from("\\$MFT")));
+
+        let path: Option<NtfsPath> = mft_file.get_path_by_identifier(5)?;
+        assert_eq!(path, Some(NtfsPath::from("\\")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_directory_path() -> Result<(), ErrorTrace> {
+        let mut mft_file: NtfsMftFile = get_mft_file()?;
+
+        let path: NtfsPath = mft_file.get_directory_path(0x0005000000000005);
+        assert_eq!(path, NtfsPath::from("\\"));
+
+        // MFT entry 0 is not a directory with a matching sequence number.
+        let path: NtfsPath = mft_file.get_directory_path(0x0002000000000000);
+        assert_eq!(path, NtfsPath::from("\\$OrphanFiles"));
+
+        // MFT entry 0 refers to the root directory as parent.
+        let path: NtfsPath = mft_file.get_directory_path(0x0001000000000000);
+        assert_eq!(path, NtfsPath::from("\\$MFT"));
+
+        let path: NtfsPath = mft_file.get_directory_path(0x0001000000000010);
+        assert_eq!(path, NtfsPath::from("\\$OrphanFiles"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_parent_sequence_number() {
+        assert!(NtfsMftFile::is_parent_sequence_number(3, 3, true));
+        assert!(!NtfsMftFile::is_parent_sequence_number(3, 4, true));
+        assert!(NtfsMftFile::is_parent_sequence_number(3, 4, false));
+        assert!(!NtfsMftFile::is_parent_sequence_number(3, 5, false));
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut mft_file: NtfsMftFile = NtfsMftFile::new();
+        mft_file.read_data_stream(&data_stream, Some(1024))?;
+
+        assert_eq!(mft_file.get_number_of_file_entries(), 1);
+
+        let mut mft_file: NtfsMftFile = NtfsMftFile::new();
+        let result: Result<(), ErrorTrace> = mft_file.read_data_stream(&data_stream, Some(16));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
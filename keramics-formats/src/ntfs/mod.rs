@@ -11,6 +11,8 @@
  * under the License.
  */
 
+mod access_control_entry;
+mod access_control_list;
 mod attribute;
 mod attribute_list;
 mod attribute_list_entry;
@@ -26,9 +28,15 @@ mod data_run;
 mod directory_entries;
 mod directory_entry;
 mod directory_index;
+mod efs_data_field;
+mod efs_information;
+mod extended_attribute;
+mod extended_attributes;
+mod extended_attributes_information;
 mod file_entry;
 mod file_name;
 mod file_system;
+mod fixup_value_mismatch;
 mod fixup_values;
 mod index;
 mod index_entry;
@@ -37,6 +45,14 @@ mod index_node_header;
 mod index_root_header;
 mod index_value;
 mod junction_reparse_data;
+mod log_file;
+mod log_file_client_record;
+mod log_file_operation_header;
+mod log_file_record;
+mod log_file_record_header;
+mod log_file_record_page_header;
+mod log_file_restart_area;
+mod log_file_restart_page_header;
 mod master_file_table;
 mod mft_attribute;
 mod mft_attribute_group;
@@ -46,18 +62,52 @@ mod mft_attribute_resident;
 mod mft_attributes;
 mod mft_entry;
 mod mft_entry_header;
+mod mft_file;
+mod object_identifier;
 mod path;
 mod reparse_point;
 mod reparse_point_header;
+mod security_descriptor;
+mod security_descriptor_header;
+mod security_descriptor_index_value;
+mod security_descriptors;
+mod security_identifier;
 mod standard_information;
 mod symbolic_link_reparse_data;
+mod transactional_data;
+mod usn_journal;
+mod usn_record;
+mod usn_record_v2;
+mod usn_record_v3;
+mod usn_record_v4;
+mod view_index;
 mod volume_information;
 mod wof_compressed_stream;
 mod wof_reparse_data;
 
+pub use access_control_entry::NtfsAccessControlEntry;
+pub use access_control_list::NtfsAccessControlList;
 pub use attribute::NtfsAttribute;
 pub use attribute_list_entry::NtfsAttributeListEntry;
 pub use data_fork::NtfsDataFork;
+pub use efs_data_field::NtfsEfsDataField;
+pub use efs_information::NtfsEfsInformation;
+pub use extended_attribute::NtfsExtendedAttribute;
+pub use extended_attributes::NtfsExtendedAttributes;
+pub use extended_attributes_information::NtfsExtendedAttributesInformation;
 pub use file_entry::NtfsFileEntry;
+pub use file_name::NtfsFileName;
 pub use file_system::NtfsFileSystem;
+pub use fixup_value_mismatch::NtfsFixupValueMismatch;
+pub use log_file::NtfsLogFile;
+pub use log_file_client_record::NtfsLogFileClientRecord;
+pub use log_file_record::NtfsLogFileRecord;
+pub use log_file_restart_area::NtfsLogFileRestartArea;
+pub use mft_file::NtfsMftFile;
+pub use object_identifier::NtfsObjectIdentifier;
 pub use path::NtfsPath;
+pub use security_descriptor::NtfsSecurityDescriptor;
+pub use security_identifier::NtfsSecurityIdentifier;
+pub use transactional_data::NtfsTransactionalData;
+pub use usn_journal::NtfsUsnJournal;
+pub use usn_record::{NtfsUsnRecord, NtfsUsnRecordExtent};
@@ -0,0 +1,175 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::Uuid;
+
+use super::constants::*;
+use super::mft_attribute::NtfsMftAttribute;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "droid_file_identifier", data_type = "Uuid")),
+        member(field(name = "birth_droid_volume_identifier", data_type = "Uuid")),
+        member(field(name = "birth_droid_file_identifier", data_type = "Uuid")),
+        member(field(name = "birth_droid_domain_identifier", data_type = "Uuid")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) object identifier ($OBJECT_ID).
+pub struct NtfsObjectIdentifier {
+    /// Droid file identifier.
+    pub droid_file_identifier: Uuid,
+
+    /// Birth droid volume identifier.
+    pub birth_droid_volume_identifier: Option<Uuid>,
+
+    /// Birth droid file identifier.
+    pub birth_droid_file_identifier: Option<Uuid>,
+
+    /// Birth droid domain identifier.
+    pub birth_droid_domain_identifier: Option<Uuid>,
+}
+
+impl NtfsObjectIdentifier {
+    /// Creates a new object identifier.
+    pub fn new() -> Self {
+        Self {
+            droid_file_identifier: Uuid::new(),
+            birth_droid_volume_identifier: None,
+            birth_droid_file_identifier: None,
+            birth_droid_domain_identifier: None,
+        }
+    }
+
+    /// Reads the object identifier from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size != 16 && data_size != 64 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS object identifier data size"
+            ));
+        }
+        self.droid_file_identifier = Uuid::from_le_bytes(&data[0..16]);
+
+        // The birth droid identifiers are optional.
+        if data_size == 64 {
+            self.birth_droid_volume_identifier = Some(Uuid::from_le_bytes(&data[16..32]));
+            self.birth_droid_file_identifier = Some(Uuid::from_le_bytes(&data[32..48]));
+            self.birth_droid_domain_identifier = Some(Uuid::from_le_bytes(&data[48..64]));
+        }
+        Ok(())
+    }
+
+    /// Reads the object identifier from a MFT attribute.
+    pub fn from_attribute(mft_attribute: &NtfsMftAttribute) -> Result<Self, ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_OBJECT_IDENTIFIER {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $OBJECT_ID attribute"
+            ));
+        }
+        if !mft_attribute.is_resident() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported non-resident $OBJECT_ID attribute"
+            ));
+        }
+        let mut object_identifier: NtfsObjectIdentifier = NtfsObjectIdentifier::new();
+
+        match object_identifier.read_data(&mft_attribute.resident_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read object identifier");
+                return Err(error);
+            }
+        }
+        Ok(object_identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x6b, 0x2f, 0x41, 0x4f, 0x3a, 0x2b, 0xf0, 0x11, 0xb7, 0x3e, 0x00, 0x0c, 0x29, 0x5d,
+            0x27, 0x48, 0x87, 0x1a, 0x4b, 0x92, 0x34, 0x1b, 0x2c, 0x46, 0xa4, 0x9e, 0x07, 0x25,
+            0x9e, 0x51, 0x3e, 0x42, 0x6b, 0x2f, 0x41, 0x4f, 0x3a, 0x2b, 0xf0, 0x11, 0xb7, 0x3e,
+            0x00, 0x0c, 0x29, 0x5d, 0x27, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsObjectIdentifier = NtfsObjectIdentifier::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.droid_file_identifier.to_string(),
+            "4f412f6b-2b3a-11f0-b73e-000c295d2748"
+        );
+        assert_eq!(
+            test_struct
+                .birth_droid_volume_identifier
+                .as_ref()
+                .map(|uuid| uuid.to_string()),
+            Some(String::from("924b1a87-1b34-462c-a49e-07259e513e42"))
+        );
+        assert_eq!(
+            test_struct.birth_droid_file_identifier,
+            Some(test_struct.droid_file_identifier.clone())
+        );
+        assert_eq!(test_struct.birth_droid_domain_identifier, Some(Uuid::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_without_birth_droid_identifiers() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsObjectIdentifier = NtfsObjectIdentifier::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data[0..16])?;
+
+        assert_eq!(
+            test_struct.droid_file_identifier.to_string(),
+            "4f412f6b-2b3a-11f0-b73e-000c295d2748"
+        );
+        assert_eq!(test_struct.birth_droid_volume_identifier, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsObjectIdentifier = NtfsObjectIdentifier::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..32]);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,313 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::Mediator;
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
+
+use super::access_control_list::NtfsAccessControlList;
+use super::block_stream::NtfsBlockStream;
+use super::constants::*;
+use super::mft_attribute::NtfsMftAttribute;
+use super::security_descriptor_header::NtfsSecurityDescriptorHeader;
+use super::security_identifier::NtfsSecurityIdentifier;
+
+/// New Technologies File System (NTFS) Windows NT security descriptor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsSecurityDescriptor {
+    /// Control flags.
+    pub control_flags: u16,
+
+    /// Owner security identifier (SID).
+    pub owner: Option<NtfsSecurityIdentifier>,
+
+    /// Group security identifier (SID).
+    pub group: Option<NtfsSecurityIdentifier>,
+
+    /// System access control list (SACL).
+    pub sacl: Option<NtfsAccessControlList>,
+
+    /// Discretionary access control list (DACL).
+    pub dacl: Option<NtfsAccessControlList>,
+}
+
+impl NtfsSecurityDescriptor {
+    /// Creates a new security descriptor.
+    pub fn new() -> Self {
+        Self {
+            control_flags: 0,
+            owner: None,
+            group: None,
+            sacl: None,
+            dacl: None,
+        }
+    }
+
+    /// Reads the security descriptor from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let mut security_descriptor_header: NtfsSecurityDescriptorHeader =
+            NtfsSecurityDescriptorHeader::new();
+
+        let mediator = Mediator::current();
+        if mediator.debug_output {
+            mediator.debug_print(NtfsSecurityDescriptorHeader::debug_read_data(data));
+        }
+        match security_descriptor_header.read_data(data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read security descriptor header"
+                );
+                return Err(error);
+            }
+        }
+        self.control_flags = security_descriptor_header.control_flags;
+
+        self.owner =
+            match Self::read_security_identifier(data, security_descriptor_header.owner_offset) {
+                Ok(security_identifier) => security_identifier,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read owner SID");
+                    return Err(error);
+                }
+            };
+        self.group =
+            match Self::read_security_identifier(data, security_descriptor_header.group_offset) {
+                Ok(security_identifier) => security_identifier,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read group SID");
+                    return Err(error);
+                }
+            };
+        // SE_SACL_PRESENT
+        if self.control_flags & 0x0010 != 0 {
+            self.sacl = match Self::read_access_control_list(
+                data,
+                security_descriptor_header.sacl_offset,
+            ) {
+                Ok(access_control_list) => access_control_list,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read SACL");
+                    return Err(error);
+                }
+            };
+        }
+        // SE_DACL_PRESENT
+        if self.control_flags & 0x0004 != 0 {
+            self.dacl = match Self::read_access_control_list(
+                data,
+                security_descriptor_header.dacl_offset,
+            ) {
+                Ok(access_control_list) => access_control_list,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read DACL");
+                    return Err(error);
+                }
+            };
+        }
+        Ok(())
+    }
+
+    /// Reads an access control list at a specific offset in the buffer.
+    fn read_access_control_list(
+        data: &[u8],
+        data_offset: u32,
+    ) -> Result<Option<NtfsAccessControlList>, ErrorTrace> {
+        // Note that an offset of 0 represents a NULL access control list.
+        if data_offset == 0 {
+            return Ok(None);
+        }
+        if data_offset < 20 || data_offset as usize >= data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid access control list offset: {} value out of bounds",
+                data_offset
+            )));
+        }
+        let mut access_control_list: NtfsAccessControlList = NtfsAccessControlList::new();
+
+        access_control_list.read_data(&data[data_offset as usize..])?;
+
+        Ok(Some(access_control_list))
+    }
+
+    /// Reads a security identifier at a specific offset in the buffer.
+    fn read_security_identifier(
+        data: &[u8],
+        data_offset: u32,
+    ) -> Result<Option<NtfsSecurityIdentifier>, ErrorTrace> {
+        if data_offset == 0 {
+            return Ok(None);
+        }
+        if data_offset < 20 || data_offset as usize >= data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid security identifier offset: {} value out of bounds",
+                data_offset
+            )));
+        }
+        let mut security_identifier: NtfsSecurityIdentifier = NtfsSecurityIdentifier::new();
+
+        security_identifier.read_data(&data[data_offset as usize..])?;
+
+        Ok(Some(security_identifier))
+    }
+
+    /// Reads the security descriptor from a MFT attribute.
+    pub fn read_attribute(
+        &mut self,
+        mft_attribute: &NtfsMftAttribute,
+        data_stream: &DataStreamReference,
+        cluster_block_size: u32,
+    ) -> Result<(), ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_SECURITY_DESCRIPTOR {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $SECURITY_DESCRIPTOR attribute"
+            ));
+        }
+        if mft_attribute.is_resident() {
+            match self.read_data(&mft_attribute.resident_data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read resident security descriptor"
+                    );
+                    return Err(error);
+                }
+            }
+        } else {
+            // Note that 1048576 is an arbitrary chosen limit, given the size of an access
+            // control list is stored in 16 bits.
+            if mft_attribute.data_size > 1048576 {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported security descriptor data size: {} value out of bounds",
+                    mft_attribute.data_size
+                )));
+            }
+            let mut block_stream: NtfsBlockStream = NtfsBlockStream::new(cluster_block_size);
+
+            match block_stream.open(data_stream, mft_attribute) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to open block stream");
+                    return Err(error);
+                }
+            }
+            let mut data: Vec<u8> = vec![0; mft_attribute.data_size as usize];
+
+            match block_stream.read_exact_at_position(&mut data, SeekFrom::Start(0)) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read security descriptor from block stream"
+                    );
+                    return Err(error);
+                }
+            }
+            match self.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read non-resident security descriptor"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x01, 0x00, 0x14, 0x80, 0x78, 0x00, 0x00, 0x00, 0x88, 0x00, 0x00, 0x00, 0x14, 0x00,
+            0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x02, 0x00, 0x1c, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x02, 0x40, 0x14, 0x00, 0x16, 0x01, 0x0d, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x48, 0x00, 0x03, 0x00, 0x00, 0x00,
+            0x00, 0x03, 0x14, 0x00, 0xff, 0x01, 0x1f, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x05, 0x12, 0x00, 0x00, 0x00, 0x00, 0x03, 0x18, 0x00, 0xa9, 0x00, 0x12, 0x00,
+            0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x20, 0x00, 0x00, 0x00, 0x21, 0x02,
+            0x00, 0x00, 0x01, 0x00, 0x14, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x05, 0x20, 0x00, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x05, 0x12, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityDescriptor::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.control_flags, 0x8014);
+        assert_eq!(
+            test_struct.owner.as_ref().unwrap().to_string(),
+            "S-1-5-32-544"
+        );
+        assert_eq!(test_struct.group.as_ref().unwrap().to_string(), "S-1-5-18");
+
+        let sacl: &NtfsAccessControlList = test_struct.sacl.as_ref().unwrap();
+        assert_eq!(sacl.entries.len(), 1);
+        assert_eq!(sacl.entries[0].entry_type, NTFS_ACE_TYPE_SYSTEM_AUDIT);
+
+        let dacl: &NtfsAccessControlList = test_struct.dacl.as_ref().unwrap();
+        assert_eq!(dacl.entries.len(), 3);
+        assert_eq!(dacl.entries[0].entry_type, NTFS_ACE_TYPE_ACCESS_ALLOWED);
+        assert_eq!(
+            dacl.entries[1]
+                .security_identifier
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "S-1-5-32-545"
+        );
+        assert_eq!(dacl.entries[2].entry_type, NTFS_ACE_TYPE_ACCESS_DENIED);
+        assert_eq!(
+            dacl.entries[2]
+                .security_identifier
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "S-1-1-0"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityDescriptor::new();
+        let result = test_struct.read_data(&test_data[0..19]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..120]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,126 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "revision_number", data_type = "u8")),
+        member(field(name = "unknown1", data_type = "u8")),
+        member(field(name = "control_flags", data_type = "u16", format = "hex")),
+        member(field(name = "owner_offset", data_type = "u32")),
+        member(field(name = "group_offset", data_type = "u32")),
+        member(field(name = "sacl_offset", data_type = "u32")),
+        member(field(name = "dacl_offset", data_type = "u32")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) Windows NT security descriptor header.
+pub struct NtfsSecurityDescriptorHeader {
+    /// Revision number.
+    pub revision_number: u8,
+
+    /// Control flags.
+    pub control_flags: u16,
+
+    /// Owner security identifier (SID) offset.
+    pub owner_offset: u32,
+
+    /// Group security identifier (SID) offset.
+    pub group_offset: u32,
+
+    /// System access control list (SACL) offset.
+    pub sacl_offset: u32,
+
+    /// Discretionary access control list (DACL) offset.
+    pub dacl_offset: u32,
+}
+
+impl NtfsSecurityDescriptorHeader {
+    /// Creates a new security descriptor header.
+    pub fn new() -> Self {
+        Self {
+            revision_number: 0,
+            control_flags: 0,
+            owner_offset: 0,
+            group_offset: 0,
+            sacl_offset: 0,
+            dacl_offset: 0,
+        }
+    }
+
+    /// Reads the security descriptor header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 20 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS security descriptor header data size"
+            ));
+        }
+        if data[0] != 1 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported security descriptor revision number: {}",
+                data[0]
+            )));
+        }
+        self.revision_number = data[0];
+        self.control_flags = bytes_to_u16_le!(data, 2);
+        self.owner_offset = bytes_to_u32_le!(data, 4);
+        self.group_offset = bytes_to_u32_le!(data, 8);
+        self.sacl_offset = bytes_to_u32_le!(data, 12);
+        self.dacl_offset = bytes_to_u32_le!(data, 16);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x01, 0x00, 0x14, 0x80, 0x78, 0x00, 0x00, 0x00, 0x88, 0x00, 0x00, 0x00, 0x14, 0x00,
+            0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x02, 0x00, 0x1c, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsSecurityDescriptorHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.revision_number, 1);
+        assert_eq!(test_struct.control_flags, 0x8014);
+        assert_eq!(test_struct.owner_offset, 120);
+        assert_eq!(test_struct.group_offset, 136);
+        assert_eq!(test_struct.sacl_offset, 20);
+        assert_eq!(test_struct.dacl_offset, 48);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityDescriptorHeader::new();
+        let result = test_struct.read_data(&test_data[0..19]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,108 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "hash", data_type = "u32", format = "hex")),
+        member(field(name = "identifier", data_type = "u32")),
+        member(field(name = "data_offset", data_type = "u64")),
+        member(field(name = "data_size", data_type = "u32")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) security descriptor index value.
+///
+/// The same structure is used as the header of a $Secure:$SDS entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsSecurityDescriptorIndexValue {
+    /// Security descriptor hash.
+    pub hash: u32,
+
+    /// Security descriptor identifier.
+    pub identifier: u32,
+
+    /// Security descriptor data offset (in $SDS).
+    pub data_offset: u64,
+
+    /// Security descriptor data size (in $SDS).
+    pub data_size: u32,
+}
+
+impl NtfsSecurityDescriptorIndexValue {
+    /// Creates a new security descriptor index value.
+    pub fn new() -> Self {
+        Self {
+            hash: 0,
+            identifier: 0,
+            data_offset: 0,
+            data_size: 0,
+        }
+    }
+
+    /// Reads the security descriptor index value from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 20 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS security descriptor index value data size"
+            ));
+        }
+        self.hash = bytes_to_u32_le!(data, 0);
+        self.identifier = bytes_to_u32_le!(data, 4);
+        self.data_offset = bytes_to_u64_le!(data, 8);
+        self.data_size = bytes_to_u32_le!(data, 16);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x2a, 0x2e, 0x7a, 0x5f, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct = NtfsSecurityDescriptorIndexValue::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.hash, 0x5f7a2e2a);
+        assert_eq!(test_struct.identifier, 256);
+        assert_eq!(test_struct.data_offset, 0);
+        assert_eq!(test_struct.data_size, 168);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityDescriptorIndexValue::new();
+        let result = test_struct.read_data(&test_data[0..19]);
+        assert!(result.is_err());
+    }
+}
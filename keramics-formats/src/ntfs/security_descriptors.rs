@@ -0,0 +1,507 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::{Ucs2String, bytes_to_u32_le};
+
+use super::constants::*;
+use super::file_entry::NtfsFileEntry;
+use super::master_file_table::NtfsMasterFileTable;
+use super::mft_attributes::NtfsMftAttributes;
+use super::mft_entry::NtfsMftEntry;
+use super::security_descriptor::NtfsSecurityDescriptor;
+use super::security_descriptor_index_value::NtfsSecurityDescriptorIndexValue;
+use super::view_index::{NtfsViewIndex, NtfsViewIndexEntry};
+
+/// New Technologies File System (NTFS) security descriptors index.
+struct NtfsSecurityDescriptorsIndex {
+    /// Security descriptor ($SDS) data stream.
+    sds_data_stream: Option<DataStreamReference>,
+
+    /// Index values per security descriptor identifier.
+    index_values: HashMap<u32, NtfsSecurityDescriptorIndexValue>,
+}
+
+impl NtfsSecurityDescriptorsIndex {
+    /// Creates a new security descriptors index.
+    fn new() -> Self {
+        Self {
+            sds_data_stream: None,
+            index_values: HashMap::new(),
+        }
+    }
+}
+
+/// New Technologies File System (NTFS) security descriptors.
+///
+/// Contains the security descriptors stored in the "$Secure" metadata file. The "$Secure"
+/// metadata file is read on first use, hence a corrupted "$Secure" metadata file does not
+/// prevent the file system from being opened.
+pub struct NtfsSecurityDescriptors {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Master File Table (MFT).
+    mft: Option<Arc<NtfsMasterFileTable>>,
+
+    /// Cluster block size.
+    cluster_block_size: u32,
+
+    /// Security descriptors index, which is None if not yet read.
+    index: RwLock<Option<NtfsSecurityDescriptorsIndex>>,
+}
+
+impl NtfsSecurityDescriptors {
+    /// Creates new security descriptors.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            mft: None,
+            cluster_block_size: 0,
+            index: RwLock::new(None),
+        }
+    }
+
+    /// Initializes the security descriptors.
+    ///
+    /// The "$Secure" metadata file is not read until a security descriptor is retrieved.
+    pub fn initialize(
+        &mut self,
+        data_stream: &DataStreamReference,
+        mft: &Arc<NtfsMasterFileTable>,
+        cluster_block_size: u32,
+    ) {
+        self.data_stream = Some(data_stream.clone());
+        self.mft = Some(mft.clone());
+        self.cluster_block_size = cluster_block_size;
+        self.index = RwLock::new(None);
+    }
+
+    /// Retrieves the security descriptors index, which is read on first use.
+    fn get_index(
+        &self,
+    ) -> Result<RwLockReadGuard<'_, Option<NtfsSecurityDescriptorsIndex>>, ErrorTrace> {
+        let is_read: bool = match self.index.read() {
+            Ok(index) => index.is_some(),
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to obtain read lock on security descriptors index",
+                    error
+                ));
+            }
+        };
+        if !is_read {
+            let index: NtfsSecurityDescriptorsIndex = match self.read_secure_file() {
+                Ok(index) => index,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read $Secure");
+                    return Err(error);
+                }
+            };
+            match self.index.write() {
+                Ok(mut index_guard) => *index_guard = Some(index),
+                Err(error) => {
+                    return Err(keramics_core::error_trace_new_with_error!(
+                        "Unable to obtain write lock on security descriptors index",
+                        error
+                    ));
+                }
+            }
+        }
+        match self.index.read() {
+            Ok(index) => Ok(index),
+            Err(error) => Err(keramics_core::error_trace_new_with_error!(
+                "Unable to obtain read lock on security descriptors index",
+                error
+            )),
+        }
+    }
+
+    /// Retrieves the number of security descriptors.
+    pub fn get_number_of_security_descriptors(&self) -> Result<usize, ErrorTrace> {
+        let index_guard: RwLockReadGuard<'_, Option<NtfsSecurityDescriptorsIndex>> =
+            match self.get_index() {
+                Ok(index_guard) => index_guard,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve security descriptors index"
+                    );
+                    return Err(error);
+                }
+            };
+        match index_guard.as_ref() {
+            Some(index) => Ok(index.index_values.len()),
+            None => Ok(0),
+        }
+    }
+
+    /// Retrieves the security descriptor for a specific identifier.
+    pub fn get_security_descriptor_by_identifier(
+        &self,
+        identifier: u32,
+    ) -> Result<Option<NtfsSecurityDescriptor>, ErrorTrace> {
+        let index_guard: RwLockReadGuard<'_, Option<NtfsSecurityDescriptorsIndex>> =
+            match self.get_index() {
+                Ok(index_guard) => index_guard,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve security descriptors index"
+                    );
+                    return Err(error);
+                }
+            };
+        let index: &NtfsSecurityDescriptorsIndex = match index_guard.as_ref() {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let index_value: &NtfsSecurityDescriptorIndexValue =
+            match index.index_values.get(&identifier) {
+                Some(index_value) => index_value,
+                None => return Ok(None),
+            };
+        let sds_data_stream: &DataStreamReference = match index.sds_data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing $SDS data stream"));
+            }
+        };
+        // Note that 1048576 is an arbitrary chosen limit, given the size of an access control
+        // list is stored in 16 bits.
+        if index_value.data_size < 40 || index_value.data_size > 1048576 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid security descriptor data size: {} value out of bounds",
+                index_value.data_size
+            )));
+        }
+        let mut data: Vec<u8> = vec![0; index_value.data_size as usize];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            sds_data_stream,
+            &mut data,
+            SeekFrom::Start(index_value.data_offset)
+        );
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "NtfsSecurityDescriptor data of size: {} at offset: {} (0x{:08x})\n",
+                data.len(),
+                index_value.data_offset,
+                index_value.data_offset
+            ));
+            self.mediator.debug_print_data(&data, true);
+
+            self.mediator
+                .debug_print(NtfsSecurityDescriptorIndexValue::debug_read_data(
+                    &data[0..20],
+                ));
+        }
+        let mut entry_header: NtfsSecurityDescriptorIndexValue =
+            NtfsSecurityDescriptorIndexValue::new();
+
+        match entry_header.read_data(&data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read security descriptor entry header"
+                );
+                return Err(error);
+            }
+        }
+        if entry_header.identifier != index_value.identifier
+            || entry_header.data_offset != index_value.data_offset
+        {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Mismatch between security descriptor identifier: {} and $SDS entry at offset: {} (0x{:08x})",
+                identifier, index_value.data_offset, index_value.data_offset
+            )));
+        }
+        let mut security_descriptor: NtfsSecurityDescriptor = NtfsSecurityDescriptor::new();
+
+        match security_descriptor.read_data(&data[20..]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to read security descriptor: {}", identifier)
+                );
+                return Err(error);
+            }
+        }
+        Ok(Some(security_descriptor))
+    }
+
+    /// Reads the "$Secure" metadata file.
+    fn read_secure_file(&self) -> Result<NtfsSecurityDescriptorsIndex, ErrorTrace> {
+        let (data_stream, mft): (&DataStreamReference, &Arc<NtfsMasterFileTable>) =
+            match (self.data_stream.as_ref(), self.mft.as_ref()) {
+                (Some(data_stream), Some(mft)) => (data_stream, mft),
+                _ => return Ok(NtfsSecurityDescriptorsIndex::new()),
+            };
+        let mft_entry: NtfsMftEntry = match mft.get_entry(data_stream, NTFS_SECURE_FILE_IDENTIFIER)
+        {
+            Ok(mft_entry) => mft_entry,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to retrieve MFT entry: {}",
+                        NTFS_SECURE_FILE_IDENTIFIER
+                    )
+                );
+                return Err(error);
+            }
+        };
+        // Note that in NTFS version 1.2 the $Quota metadata file is stored in MFT entry 9 and
+        // security descriptors are only stored in $SECURITY_DESCRIPTOR attributes.
+        if mft_entry.is_bad || !mft_entry.is_allocated {
+            return Ok(NtfsSecurityDescriptorsIndex::new());
+        }
+        let mut file_entry: NtfsFileEntry = NtfsFileEntry::new(
+            data_stream,
+            mft,
+            &Arc::new(HashMap::new()),
+            &Arc::new(NtfsSecurityDescriptors::new()),
+            NTFS_SECURE_FILE_IDENTIFIER,
+            mft_entry,
+            None,
+            None,
+        );
+        match file_entry.read_attributes() {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read attributes");
+                return Err(error);
+            }
+        }
+        Self::read_index(
+            data_stream,
+            file_entry.get_mft_attributes(),
+            self.cluster_block_size,
+        )
+    }
+
+    /// Reads the security descriptors index from the attributes of the "$Secure" metadata file.
+    fn read_index(
+        data_stream: &DataStreamReference,
+        mft_attributes: &NtfsMftAttributes,
+        cluster_block_size: u32,
+    ) -> Result<NtfsSecurityDescriptorsIndex, ErrorTrace> {
+        let mut index: NtfsSecurityDescriptorsIndex = NtfsSecurityDescriptorsIndex::new();
+
+        let sds_name: Option<Ucs2String> = Some(Ucs2String::from("$SDS"));
+
+        index.sds_data_stream = match mft_attributes.get_data_stream_by_name(
+            &sds_name,
+            data_stream,
+            cluster_block_size,
+        ) {
+            Ok(result) => result,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to retrieve $SDS data stream");
+                return Err(error);
+            }
+        };
+        if index.sds_data_stream.is_none() {
+            return Ok(index);
+        }
+        // The $SII index is used to look up security descriptors by identifier, the $SDH index
+        // is only used for identifiers that are missing from the $SII index.
+        for index_name in ["$SII", "$SDH"] {
+            let name: Option<Ucs2String> = Some(Ucs2String::from(index_name));
+
+            if !mft_attributes.has_attribute_group(&name) {
+                continue;
+            }
+            let mut view_index: NtfsViewIndex = NtfsViewIndex::new(cluster_block_size);
+
+            match view_index.initialize(mft_attributes, index_name) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to initialize {} index", index_name)
+                    );
+                    return Err(error);
+                }
+            }
+            let entries: Vec<NtfsViewIndexEntry> = match view_index.get_entries(data_stream) {
+                Ok(entries) => entries,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to retrieve {} index entries", index_name)
+                    );
+                    return Err(error);
+                }
+            };
+            for entry in entries.iter() {
+                let mut index_value: NtfsSecurityDescriptorIndexValue =
+                    NtfsSecurityDescriptorIndexValue::new();
+
+                match index_value.read_data(&entry.value_data) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to read {} index value", index_name)
+                        );
+                        return Err(error);
+                    }
+                }
+                // The key data of both the $SII and $SDH index ends with the identifier.
+                let key_data_size: usize = entry.key_data.len();
+
+                if key_data_size < 4
+                    || bytes_to_u32_le!(entry.key_data, key_data_size - 4) != index_value.identifier
+                {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Mismatch between {} index key and value identifier: {}",
+                        index_name, index_value.identifier
+                    )));
+                }
+                index
+                    .index_values
+                    .entry(index_value.identifier)
+                    .or_insert(index_value);
+            }
+        }
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x2a, 0x2e, 0x7a, 0x5f, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xa8, 0x00, 0x00, 0x00, 0x01, 0x00, 0x14, 0x80, 0x78, 0x00, 0x00, 0x00,
+            0x88, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x1c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x40, 0x14, 0x00, 0x16, 0x01, 0x0d, 0x00,
+            0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x48, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x03, 0x14, 0x00, 0xff, 0x01, 0x1f, 0x00,
+            0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x12, 0x00, 0x00, 0x00, 0x00, 0x03,
+            0x18, 0x00, 0xa9, 0x00, 0x12, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05,
+            0x20, 0x00, 0x00, 0x00, 0x21, 0x02, 0x00, 0x00, 0x01, 0x00, 0x14, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x20, 0x00, 0x00, 0x00, 0x20, 0x02,
+            0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x12, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    fn get_security_descriptors() -> NtfsSecurityDescriptors {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut index: NtfsSecurityDescriptorsIndex = NtfsSecurityDescriptorsIndex::new();
+        index.sds_data_stream = Some(open_fake_data_stream(&test_data));
+
+        let mut index_value: NtfsSecurityDescriptorIndexValue =
+            NtfsSecurityDescriptorIndexValue::new();
+        index_value.hash = 0x5f7a2e2a;
+        index_value.identifier = 256;
+        index_value.data_offset = 0;
+        index_value.data_size = 168;
+
+        index.index_values.insert(256, index_value);
+
+        let mut security_descriptors: NtfsSecurityDescriptors = NtfsSecurityDescriptors::new();
+        security_descriptors.index = RwLock::new(Some(index));
+
+        security_descriptors
+    }
+
+    #[test]
+    fn test_initialize() -> Result<(), ErrorTrace> {
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+        let mft: Arc<NtfsMasterFileTable> = Arc::new(NtfsMasterFileTable::new());
+
+        // The "$Secure" metadata file is read on first use, hence errors are returned when
+        // retrieving a security descriptor instead of by initialize.
+        let mut security_descriptors: NtfsSecurityDescriptors = NtfsSecurityDescriptors::new();
+        security_descriptors.initialize(&data_stream, &mft, 4096);
+
+        let result = security_descriptors.get_security_descriptor_by_identifier(256);
+        assert!(result.is_err());
+
+        let result = security_descriptors.get_number_of_security_descriptors();
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_number_of_security_descriptors() -> Result<(), ErrorTrace> {
+        let security_descriptors: NtfsSecurityDescriptors = get_security_descriptors();
+
+        assert_eq!(
+            security_descriptors.get_number_of_security_descriptors()?,
+            1
+        );
+
+        let security_descriptors: NtfsSecurityDescriptors = NtfsSecurityDescriptors::new();
+
+        assert_eq!(
+            security_descriptors.get_number_of_security_descriptors()?,
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_security_descriptor_by_identifier() -> Result<(), ErrorTrace> {
+        let security_descriptors: NtfsSecurityDescriptors = get_security_descriptors();
+
+        let security_descriptor: NtfsSecurityDescriptor = security_descriptors
+            .get_security_descriptor_by_identifier(256)?
+            .unwrap();
+        assert_eq!(
+            security_descriptor.owner.as_ref().unwrap().to_string(),
+            "S-1-5-32-544"
+        );
+
+        let result: Option<NtfsSecurityDescriptor> =
+            security_descriptors.get_security_descriptor_by_identifier(257)?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_security_descriptor_by_identifier_with_mismatch() {
+        let mut security_descriptors: NtfsSecurityDescriptors = get_security_descriptors();
+
+        if let Some(index) = security_descriptors.index.get_mut().unwrap().as_mut() {
+            if let Some(index_value) = index.index_values.get_mut(&256) {
+                index_value.identifier = 257;
+            }
+        }
+        let result = security_descriptors.get_security_descriptor_by_identifier(256);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,165 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::fmt;
+
+use keramics_core::ErrorTrace;
+use keramics_types::bytes_to_u32_le;
+
+/// New Technologies File System (NTFS) Windows NT security identifier (SID).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NtfsSecurityIdentifier {
+    /// Revision number.
+    pub revision_number: u8,
+
+    /// Authority.
+    pub authority: u64,
+
+    /// Sub authorities.
+    pub sub_authorities: Vec<u32>,
+}
+
+impl NtfsSecurityIdentifier {
+    /// Creates a new security identifier.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the security identifier from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS security identifier data size"
+            ));
+        }
+        if data[0] != 1 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported security identifier revision number: {}",
+                data[0]
+            )));
+        }
+        let number_of_sub_authorities: usize = data[1] as usize;
+        let security_identifier_size: usize = 8 + (number_of_sub_authorities * 4);
+
+        if security_identifier_size > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid number of sub authorities: {} value out of bounds",
+                number_of_sub_authorities
+            )));
+        }
+        self.revision_number = data[0];
+
+        // The authority is stored as a 48-bit big-endian value.
+        self.authority = data[2..8].iter().fold(0, |authority, byte_value| {
+            (authority << 8) | (*byte_value as u64)
+        });
+
+        self.sub_authorities.clear();
+
+        for data_offset in (8..security_identifier_size).step_by(4) {
+            self.sub_authorities
+                .push(bytes_to_u32_le!(data, data_offset));
+        }
+        Ok(security_identifier_size)
+    }
+
+    /// Retrieves the string representation of a security identifier.
+    pub fn to_string(&self) -> String {
+        let mut string_parts: Vec<String> = Vec::new();
+
+        string_parts.push(format!("S-{}", self.revision_number));
+
+        if self.authority > 0xffffffff {
+            string_parts.push(format!("0x{:012X}", self.authority));
+        } else {
+            string_parts.push(format!("{}", self.authority));
+        }
+        for sub_authority in self.sub_authorities.iter() {
+            string_parts.push(format!("{}", sub_authority));
+        }
+        string_parts.join("-")
+    }
+}
+
+impl fmt::Display for NtfsSecurityIdentifier {
+    /// Formats the security identifier for display.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x01, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x15, 0x00, 0x00, 0x00, 0xdc, 0xf4,
+            0xdc, 0x3b, 0x83, 0x3d, 0x2b, 0x46, 0x82, 0x8b, 0xa6, 0x28, 0x00, 0x02, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityIdentifier::new();
+        let security_identifier_size: usize = test_struct.read_data(&test_data)?;
+        assert_eq!(security_identifier_size, 28);
+
+        assert_eq!(test_struct.revision_number, 1);
+        assert_eq!(test_struct.authority, 5);
+        assert_eq!(
+            test_struct.sub_authorities,
+            vec![21, 1004336348, 1177238915, 682003330, 512]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityIdentifier::new();
+        let result = test_struct.read_data(&test_data[0..7]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..24]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_to_string() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsSecurityIdentifier::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.to_string(),
+            "S-1-5-21-1004336348-1177238915-682003330-512"
+        );
+
+        let test_data: [u8; 12] = [
+            0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ];
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.to_string(), "S-1-0x010000000000-1");
+
+        Ok(())
+    }
+}
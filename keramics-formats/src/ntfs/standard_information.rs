@@ -63,6 +63,9 @@ pub struct NtfsStandardInformation {
 
     /// Owner identifier.
     pub owner_identifier: Option<u32>,
+
+    /// Security descriptor identifier.
+    pub security_descriptor_identifier: Option<u32>,
 }
 
 impl NtfsStandardInformation {
@@ -77,6 +80,7 @@ impl NtfsStandardInformation {
             maximum_number_of_versions: 0,
             version_number: 0,
             owner_identifier: None,
+            security_descriptor_identifier: None,
         }
     }
 
@@ -124,6 +128,9 @@ impl NtfsStandardInformation {
         if data_size >= 52 {
             self.owner_identifier = Some(bytes_to_u32_le!(data, 48));
         }
+        if data_size >= 56 {
+            self.security_descriptor_identifier = Some(bytes_to_u32_le!(data, 52));
+        }
         Ok(())
     }
 
@@ -202,6 +209,7 @@ mod tests {
         assert_eq!(test_struct.file_attribute_flags, 0x00000006);
         assert_eq!(test_struct.maximum_number_of_versions, 0);
         assert_eq!(test_struct.version_number, 0);
+        assert_eq!(test_struct.security_descriptor_identifier, Some(256));
 
         Ok(())
     }
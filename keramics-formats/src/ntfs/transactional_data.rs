@@ -0,0 +1,172 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u64_le};
+
+use super::constants::*;
+use super::mft_attribute::NtfsMftAttribute;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        member(field(name = "unknown1", data_type = "[u8; 6]")),
+        member(field(
+            name = "resource_manager_root_file_reference",
+            data_type = "u64",
+            format = "hex"
+        )),
+        member(field(name = "usn_index", data_type = "u64")),
+        member(field(name = "file_identifier", data_type = "u64", format = "hex")),
+        member(field(name = "data_lsn", data_type = "u64")),
+        member(field(name = "metadata_lsn", data_type = "u64")),
+        member(field(name = "directory_index_lsn", data_type = "u64")),
+        member(field(name = "flags", data_type = "u16", format = "hex")),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) Transactional NTFS (TxF) data ($TXF_DATA).
+///
+/// The TxF data is stored in a $LOGGED_UTILITY_STREAM attribute named "$TXF_DATA".
+pub struct NtfsTransactionalData {
+    /// Resource manager root file reference.
+    pub resource_manager_root_file_reference: u64,
+
+    /// Update sequence number (USN) index.
+    pub usn_index: u64,
+
+    /// TxF file identifier.
+    pub file_identifier: u64,
+
+    /// Data log sequence number (LSN).
+    pub data_lsn: u64,
+
+    /// Metadata log sequence number (LSN).
+    pub metadata_lsn: u64,
+
+    /// Directory index log sequence number (LSN).
+    pub directory_index_lsn: u64,
+
+    /// Flags.
+    pub flags: u16,
+}
+
+impl NtfsTransactionalData {
+    /// Creates new TxF data.
+    pub fn new() -> Self {
+        Self {
+            resource_manager_root_file_reference: 0,
+            usn_index: 0,
+            file_identifier: 0,
+            data_lsn: 0,
+            metadata_lsn: 0,
+            directory_index_lsn: 0,
+            flags: 0,
+        }
+    }
+
+    /// Reads the TxF data from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < 56 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS TxF data size"
+            ));
+        }
+        self.resource_manager_root_file_reference = bytes_to_u64_le!(data, 6);
+        self.usn_index = bytes_to_u64_le!(data, 14);
+        self.file_identifier = bytes_to_u64_le!(data, 22);
+        self.data_lsn = bytes_to_u64_le!(data, 30);
+        self.metadata_lsn = bytes_to_u64_le!(data, 38);
+        self.directory_index_lsn = bytes_to_u64_le!(data, 46);
+        self.flags = bytes_to_u16_le!(data, 54);
+
+        Ok(())
+    }
+
+    /// Reads the TxF data from a MFT attribute.
+    pub fn from_attribute(mft_attribute: &NtfsMftAttribute) -> Result<Self, ErrorTrace> {
+        if mft_attribute.attribute_type != NTFS_ATTRIBUTE_TYPE_LOGGED_UTILITY_STREAM {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported attribute type: 0x{:08x}",
+                mft_attribute.attribute_type
+            )));
+        }
+        if mft_attribute.is_compressed() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported compressed $LOGGED_UTILITY_STREAM attribute"
+            ));
+        }
+        if !mft_attribute.is_resident() {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported non-resident $LOGGED_UTILITY_STREAM attribute"
+            ));
+        }
+        let mut transactional_data: NtfsTransactionalData = NtfsTransactionalData::new();
+
+        match transactional_data.read_data(&mft_attribute.resident_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read TxF data");
+                return Err(error);
+            }
+        }
+        Ok(transactional_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let mut test_struct: NtfsTransactionalData = NtfsTransactionalData::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.resource_manager_root_file_reference,
+            0x000100000000001b
+        );
+        assert_eq!(test_struct.usn_index, 0);
+        assert_eq!(test_struct.file_identifier, 42);
+        assert_eq!(test_struct.data_lsn, 0x1000);
+        assert_eq!(test_struct.metadata_lsn, 0x2000);
+        assert_eq!(test_struct.directory_index_lsn, 0);
+        assert_eq!(test_struct.flags, 0x0002);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct: NtfsTransactionalData = NtfsTransactionalData::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..55]);
+
+        assert!(result.is_err());
+    }
+}
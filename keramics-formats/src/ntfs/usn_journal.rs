@@ -0,0 +1,291 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::bytes_to_u32_le;
+
+use super::constants::*;
+use super::usn_record::NtfsUsnRecord;
+
+/// New Technologies File System (NTFS) update sequence number (USN) change journal.
+///
+/// The journal is stored in the sparse "$J" data stream of the "$UsnJrnl" metadata file.
+pub struct NtfsUsnJournal {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Data size.
+    data_size: u64,
+
+    /// Offset of the first record, which follows the sparse leading part of the stream.
+    pub start_offset: u64,
+
+    /// Current offset.
+    current_offset: u64,
+}
+
+impl NtfsUsnJournal {
+    /// Creates a new USN change journal.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            data_size: 0,
+            start_offset: 0,
+            current_offset: 0,
+        }
+    }
+
+    /// Reads the USN change journal from a "$J" data stream.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+        start_offset: u64,
+    ) -> Result<(), ErrorTrace> {
+        self.data_size = keramics_core::data_stream_get_size!(data_stream);
+
+        if start_offset > self.data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid start offset: {} value out of bounds",
+                start_offset
+            )));
+        }
+        self.data_stream = Some(data_stream.clone());
+        self.start_offset = start_offset;
+        self.current_offset = start_offset;
+
+        Ok(())
+    }
+
+    /// Reads the next USN record.
+    fn read_next_record(&mut self) -> Result<Option<NtfsUsnRecord>, ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        loop {
+            if self.current_offset + 8 > self.data_size {
+                return Ok(None);
+            }
+            let mut header_data: [u8; 8] = [0; 8];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut header_data,
+                SeekFrom::Start(self.current_offset)
+            );
+            let record_size: u32 = bytes_to_u32_le!(header_data, 0);
+
+            // The remainder of a journal block is padded with zero bytes.
+            if record_size == 0 {
+                self.current_offset = (self.current_offset / NTFS_USN_JOURNAL_BLOCK_SIZE + 1)
+                    * NTFS_USN_JOURNAL_BLOCK_SIZE;
+                continue;
+            }
+            let record_offset: u64 = self.current_offset;
+
+            if record_size < 8
+                || record_size % 8 != 0
+                || record_offset + record_size as u64 > self.data_size
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid record size: {} at offset: {} (0x{:08x})",
+                    record_size, record_offset, record_offset
+                )));
+            }
+            let mut data: Vec<u8> = vec![0; record_size as usize];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut data,
+                SeekFrom::Start(record_offset)
+            );
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "NtfsUsnRecord data of size: {} at offset: {} (0x{:08x})\n",
+                    data.len(),
+                    record_offset,
+                    record_offset
+                ));
+                self.mediator.debug_print_data(&data, true);
+            }
+            let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+
+            match usn_record.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to read USN record at offset: {} (0x{:08x})",
+                            record_offset, record_offset
+                        )
+                    );
+                    return Err(error);
+                }
+            }
+            self.current_offset = record_offset + record_size as u64;
+
+            return Ok(Some(usn_record));
+        }
+    }
+}
+
+impl Iterator for NtfsUsnJournal {
+    type Item = Result<NtfsUsnRecord, ErrorTrace>;
+
+    /// Retrieves the next USN record.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_next_record() {
+            Ok(Some(usn_record)) => Some(Ok(usn_record)),
+            Ok(None) => None,
+            Err(error) => {
+                // Continue with the next journal block after a corrupted record.
+                self.current_offset = (self.current_offset / NTFS_USN_JOURNAL_BLOCK_SIZE + 1)
+                    * NTFS_USN_JOURNAL_BLOCK_SIZE;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+    use keramics_types::Ucs2String;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 8192];
+
+        // Version 2 record at offset 4096 followed by version 3 and version 4 records.
+        let records: [&[u8]; 3] = [
+            &get_test_record_v2(),
+            &get_test_record_v3(),
+            &get_test_record_v4(),
+        ];
+        let mut data_offset: usize = 4096;
+
+        for record_data in records {
+            let data_end_offset: usize = data_offset + record_data.len();
+            data[data_offset..data_end_offset].copy_from_slice(record_data);
+            data_offset = data_end_offset;
+        }
+        data
+    }
+
+    fn get_test_record_v2() -> Vec<u8> {
+        return vec![
+            0x50, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x8c, 0x5e, 0x7b, 0x4c, 0xdb, 0x01, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x12, 0x00, 0x3c, 0x00, 0x74, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74, 0x00, 0x66, 0x00,
+            0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x31, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    fn get_test_record_v3() -> Vec<u8> {
+        return vec![
+            0x60, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x8c, 0x5e, 0x7b, 0x4c, 0xdb, 0x01,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00,
+            0x00, 0x00, 0x12, 0x00, 0x4c, 0x00, 0x74, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74, 0x00,
+            0x66, 0x00, 0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x31, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    fn get_test_record_v4() -> Vec<u8> {
+        return vec![
+            0x50, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut usn_journal: NtfsUsnJournal = NtfsUsnJournal::new();
+        usn_journal.read_data_stream(&data_stream, 0)?;
+
+        assert_eq!(usn_journal.start_offset, 0);
+        assert_eq!(usn_journal.data_size, 8192);
+
+        let result = usn_journal.read_data_stream(&data_stream, 16384);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterator() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut usn_journal: NtfsUsnJournal = NtfsUsnJournal::new();
+        usn_journal.read_data_stream(&data_stream, 0)?;
+
+        let usn_records: Vec<NtfsUsnRecord> =
+            usn_journal.collect::<Result<Vec<NtfsUsnRecord>, ErrorTrace>>()?;
+        assert_eq!(usn_records.len(), 3);
+
+        assert_eq!(usn_records[0].major_format_version, 2);
+        assert_eq!(usn_records[0].update_sequence_number, 4096);
+        assert_eq!(usn_records[0].name, Ucs2String::from("testfile1"));
+
+        assert_eq!(usn_records[1].major_format_version, 3);
+        assert_eq!(usn_records[1].update_sequence_number, 4176);
+        assert_eq!(usn_records[1].name, Ucs2String::from("testfile1"));
+
+        assert_eq!(usn_records[2].major_format_version, 4);
+        assert_eq!(usn_records[2].update_sequence_number, 4272);
+        assert_eq!(usn_records[2].extents.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterator_with_corrupted_record() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0x0c;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut usn_journal: NtfsUsnJournal = NtfsUsnJournal::new();
+        usn_journal.read_data_stream(&data_stream, 0)?;
+
+        assert!(usn_journal.next().unwrap().is_err());
+
+        let usn_record: NtfsUsnRecord = usn_journal.next().unwrap()?;
+        assert_eq!(usn_record.update_sequence_number, 4096);
+
+        Ok(())
+    }
+}
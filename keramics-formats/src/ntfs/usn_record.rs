@@ -0,0 +1,246 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::Mediator;
+use keramics_datetime::DateTime;
+use keramics_types::{Ucs2String, bytes_to_u16_le, bytes_to_u32_le};
+
+use super::usn_record_v2::NtfsUsnRecordV2;
+use super::usn_record_v3::NtfsUsnRecordV3;
+use super::usn_record_v4::NtfsUsnRecordV4;
+
+/// New Technologies File System (NTFS) update sequence number (USN) record extent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfsUsnRecordExtent {
+    /// Offset of the modified range.
+    pub offset: i64,
+
+    /// Size of the modified range.
+    pub size: i64,
+}
+
+impl NtfsUsnRecordExtent {
+    /// Creates a new USN record extent.
+    pub fn new(offset: i64, size: i64) -> Self {
+        Self { offset, size }
+    }
+}
+
+/// New Technologies File System (NTFS) update sequence number (USN) record.
+#[derive(Clone)]
+pub struct NtfsUsnRecord {
+    /// Major format version.
+    pub major_format_version: u16,
+
+    /// Minor format version.
+    pub minor_format_version: u16,
+
+    /// File reference.
+    pub file_reference: u128,
+
+    /// Parent file reference.
+    pub parent_file_reference: u128,
+
+    /// Update sequence number.
+    pub update_sequence_number: u64,
+
+    /// Timestamp, which is not set for version 4 records.
+    pub timestamp: DateTime,
+
+    /// Update reason flags.
+    pub update_reason_flags: u32,
+
+    /// Update source flags.
+    pub update_source_flags: u32,
+
+    /// Security descriptor identifier.
+    pub security_descriptor_identifier: u32,
+
+    /// File attribute flags.
+    pub file_attribute_flags: u32,
+
+    /// Name.
+    pub name: Ucs2String,
+
+    /// Extents, which are only set for version 4 records.
+    pub extents: Vec<NtfsUsnRecordExtent>,
+}
+
+impl NtfsUsnRecord {
+    /// Creates a new USN record.
+    pub fn new() -> Self {
+        Self {
+            major_format_version: 0,
+            minor_format_version: 0,
+            file_reference: 0,
+            parent_file_reference: 0,
+            update_sequence_number: 0,
+            timestamp: DateTime::NotSet,
+            update_reason_flags: 0,
+            update_source_flags: 0,
+            security_descriptor_identifier: 0,
+            file_attribute_flags: 0,
+            name: Ucs2String::new(),
+            extents: Vec::new(),
+        }
+    }
+
+    /// Reads the USN record from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 8 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS USN record data size"
+            ));
+        }
+        let record_size: u32 = bytes_to_u32_le!(data, 0);
+
+        if record_size < 8 || record_size as usize > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid record size: {} value out of bounds",
+                record_size
+            )));
+        }
+        let record_data: &[u8] = &data[0..record_size as usize];
+
+        self.major_format_version = bytes_to_u16_le!(data, 4);
+        self.minor_format_version = bytes_to_u16_le!(data, 6);
+
+        let mediator = Mediator::current();
+
+        let result: Result<(), ErrorTrace> = match self.major_format_version {
+            2 => {
+                if mediator.debug_output {
+                    mediator.debug_print(NtfsUsnRecordV2::debug_read_data(record_data));
+                }
+                NtfsUsnRecordV2::read_data(self, record_data)
+            }
+            3 => {
+                if mediator.debug_output {
+                    mediator.debug_print(NtfsUsnRecordV3::debug_read_data(record_data));
+                }
+                NtfsUsnRecordV3::read_data(self, record_data)
+            }
+            4 => {
+                if mediator.debug_output {
+                    mediator.debug_print(NtfsUsnRecordV4::debug_read_data(record_data));
+                }
+                NtfsUsnRecordV4::read_data(self, record_data)
+            }
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported format version: {}.{}",
+                    self.major_format_version, self.minor_format_version
+                )));
+            }
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to read USN record version: {}",
+                        self.major_format_version
+                    )
+                );
+                Err(error)
+            }
+        }
+    }
+
+    /// Reads the name from a buffer.
+    pub(super) fn read_name(
+        &mut self,
+        data: &[u8],
+        name_offset: usize,
+        name_size: usize,
+        minimum_offset: usize,
+    ) -> Result<(), ErrorTrace> {
+        let data_end_offset: usize = name_offset + name_size;
+
+        if name_offset < minimum_offset || data_end_offset > data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid name offset: {} and size: {} value out of bounds",
+                name_offset, name_size
+            )));
+        }
+        self.name = Ucs2String::new();
+        self.name.read_data_le(&data[name_offset..data_end_offset]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_datetime::Filetime;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x50, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x8c, 0x5e, 0x7b, 0x4c, 0xdb, 0x01, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x12, 0x00, 0x3c, 0x00, 0x74, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74, 0x00, 0x66, 0x00,
+            0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x31, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsUsnRecord::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.major_format_version, 2);
+        assert_eq!(test_struct.minor_format_version, 0);
+        assert_eq!(test_struct.file_reference, 0x0001000000000040);
+        assert_eq!(test_struct.update_sequence_number, 4096);
+        assert_eq!(
+            test_struct.timestamp,
+            DateTime::Filetime(Filetime::new(0x01db4c7b5e8c7e00))
+        );
+        assert_eq!(test_struct.name, Ucs2String::from("testfile1"));
+        assert!(test_struct.extents.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = NtfsUsnRecord::new();
+        let result = test_struct.read_data(&test_data[0..7]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..72]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_format_version() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[4] = 5;
+
+        let mut test_struct = NtfsUsnRecord::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,128 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_datetime::{DateTime, Filetime};
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+use super::usn_record::NtfsUsnRecord;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "record_size", data_type = "u32"),
+        field(name = "major_format_version", data_type = "u16"),
+        field(name = "minor_format_version", data_type = "u16"),
+        field(name = "file_reference", data_type = "u64", format = "hex"),
+        field(name = "parent_file_reference", data_type = "u64", format = "hex"),
+        field(name = "update_sequence_number", data_type = "u64"),
+        field(name = "timestamp", data_type = "Filetime"),
+        field(name = "update_reason_flags", data_type = "u32", format = "hex"),
+        field(name = "update_source_flags", data_type = "u32", format = "hex"),
+        field(name = "security_descriptor_identifier", data_type = "u32"),
+        field(name = "file_attribute_flags", data_type = "u32", format = "hex"),
+        field(name = "name_size", data_type = "u16"),
+        field(name = "name_offset", data_type = "u16"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) update sequence number (USN) record version 2.
+pub struct NtfsUsnRecordV2 {}
+
+impl NtfsUsnRecordV2 {
+    /// Reads the USN record from a buffer.
+    pub fn read_data(usn_record: &mut NtfsUsnRecord, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 60 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS USN record version 2 data size"
+            ));
+        }
+        usn_record.file_reference = bytes_to_u64_le!(data, 8) as u128;
+        usn_record.parent_file_reference = bytes_to_u64_le!(data, 16) as u128;
+        usn_record.update_sequence_number = bytes_to_u64_le!(data, 24);
+
+        let filetime: Filetime = Filetime::from_bytes(&data[32..]);
+
+        usn_record.timestamp = if filetime.timestamp == 0 {
+            DateTime::NotSet
+        } else {
+            DateTime::Filetime(filetime)
+        };
+        usn_record.update_reason_flags = bytes_to_u32_le!(data, 40);
+        usn_record.update_source_flags = bytes_to_u32_le!(data, 44);
+        usn_record.security_descriptor_identifier = bytes_to_u32_le!(data, 48);
+        usn_record.file_attribute_flags = bytes_to_u32_le!(data, 52);
+
+        let name_size: u16 = bytes_to_u16_le!(data, 56);
+        let name_offset: u16 = bytes_to_u16_le!(data, 58);
+
+        usn_record.read_name(data, name_offset as usize, name_size as usize, 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_types::Ucs2String;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x50, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x8c, 0x5e, 0x7b, 0x4c, 0xdb, 0x01, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x12, 0x00, 0x3c, 0x00, 0x74, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74, 0x00, 0x66, 0x00,
+            0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x31, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+        NtfsUsnRecordV2::read_data(&mut usn_record, &test_data)?;
+
+        assert_eq!(usn_record.file_reference, 0x0001000000000040);
+        assert_eq!(usn_record.parent_file_reference, 0x0005000000000005);
+        assert_eq!(usn_record.update_sequence_number, 4096);
+        assert_eq!(
+            usn_record.timestamp,
+            DateTime::Filetime(Filetime::new(0x01db4c7b5e8c7e00))
+        );
+        assert_eq!(usn_record.update_reason_flags, 0x00000100);
+        assert_eq!(usn_record.update_source_flags, 0);
+        assert_eq!(usn_record.security_descriptor_identifier, 0);
+        assert_eq!(usn_record.file_attribute_flags, 0x00000020);
+        assert_eq!(usn_record.name, Ucs2String::from("testfile1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+        let result = NtfsUsnRecordV2::read_data(&mut usn_record, &test_data[0..59]);
+        assert!(result.is_err());
+
+        let result = NtfsUsnRecordV2::read_data(&mut usn_record, &test_data[0..70]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,138 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_le, bytes_to_u32_le, bytes_to_u64_le};
+
+use super::usn_record::{NtfsUsnRecord, NtfsUsnRecordExtent};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "record_size", data_type = "u32"),
+        field(name = "major_format_version", data_type = "u16"),
+        field(name = "minor_format_version", data_type = "u16"),
+        field(name = "file_reference", data_type = "[u8; 16]", format = "hex"),
+        field(name = "parent_file_reference", data_type = "[u8; 16]", format = "hex"),
+        field(name = "update_sequence_number", data_type = "u64"),
+        field(name = "update_reason_flags", data_type = "u32", format = "hex"),
+        field(name = "update_source_flags", data_type = "u32", format = "hex"),
+        field(name = "remaining_number_of_extents", data_type = "u32"),
+        field(name = "number_of_extents", data_type = "u16"),
+        field(name = "extent_size", data_type = "u16"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// New Technologies File System (NTFS) update sequence number (USN) record version 4.
+///
+/// Version 4 records track modified ranges and do not contain a timestamp or name.
+pub struct NtfsUsnRecordV4 {}
+
+impl NtfsUsnRecordV4 {
+    /// Reads the USN record from a buffer.
+    pub fn read_data(usn_record: &mut NtfsUsnRecord, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 64 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported NTFS USN record version 4 data size"
+            ));
+        }
+        usn_record.file_reference =
+            ((bytes_to_u64_le!(data, 16) as u128) << 64) | (bytes_to_u64_le!(data, 8) as u128);
+        usn_record.parent_file_reference =
+            ((bytes_to_u64_le!(data, 32) as u128) << 64) | (bytes_to_u64_le!(data, 24) as u128);
+        usn_record.update_sequence_number = bytes_to_u64_le!(data, 40);
+        usn_record.update_reason_flags = bytes_to_u32_le!(data, 48);
+        usn_record.update_source_flags = bytes_to_u32_le!(data, 52);
+
+        let number_of_extents: u16 = bytes_to_u16_le!(data, 60);
+        let extent_size: u16 = bytes_to_u16_le!(data, 62);
+
+        if number_of_extents > 0 && extent_size < 16 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported extent size: {}",
+                extent_size
+            )));
+        }
+        let mut data_offset: usize = 64;
+
+        for _ in 0..number_of_extents {
+            let data_end_offset: usize = data_offset + extent_size as usize;
+
+            if data_end_offset > data_size {
+                return Err(keramics_core::error_trace_new!(
+                    "Invalid number of extents value out of bounds"
+                ));
+            }
+            usn_record.extents.push(NtfsUsnRecordExtent::new(
+                bytes_to_u64_le!(data, data_offset) as i64,
+                bytes_to_u64_le!(data, data_offset + 8) as i64,
+            ));
+            data_offset = data_end_offset;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_datetime::DateTime;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x50, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+        NtfsUsnRecordV4::read_data(&mut usn_record, &test_data)?;
+
+        assert_eq!(usn_record.file_reference, 0x0001000000000040);
+        assert_eq!(usn_record.parent_file_reference, 0x0005000000000005);
+        assert_eq!(usn_record.update_sequence_number, 4272);
+        assert_eq!(usn_record.timestamp, DateTime::NotSet);
+        assert_eq!(usn_record.update_reason_flags, 0x00000002);
+        assert_eq!(usn_record.extents.len(), 1);
+        assert_eq!(usn_record.extents[0].offset, 0);
+        assert_eq!(usn_record.extents[0].size, 4096);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+        let result = NtfsUsnRecordV4::read_data(&mut usn_record, &test_data[0..63]);
+        assert!(result.is_err());
+
+        let mut usn_record: NtfsUsnRecord = NtfsUsnRecord::new();
+        let result = NtfsUsnRecordV4::read_data(&mut usn_record, &test_data[0..72]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,376 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::{Ucs2String, bytes_to_u16_le, bytes_to_u64_le};
+
+use super::constants::*;
+use super::index::NtfsIndex;
+use super::index_entry::NtfsIndexEntry;
+use super::index_node_header::NtfsIndexNodeHeader;
+use super::index_root_header::NtfsIndexRootHeader;
+use super::index_value::NtfsIndexValue;
+use super::mft_attribute::NtfsMftAttribute;
+use super::mft_attribute_group::NtfsMftAttributeGroup;
+use super::mft_attributes::NtfsMftAttributes;
+
+/// New Technologies File System (NTFS) view index entry.
+pub struct NtfsViewIndexEntry {
+    /// Key data.
+    pub key_data: Vec<u8>,
+
+    /// Value data.
+    pub value_data: Vec<u8>,
+}
+
+/// New Technologies File System (NTFS) view index.
+///
+/// A view index is an index that does not index file names, such as the
+/// $SDH and $SII indexes of the "$Secure" metadata file.
+pub struct NtfsViewIndex {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Index.
+    index: NtfsIndex,
+
+    /// Root node data.
+    root_node_data: Vec<u8>,
+
+    /// Value to indicate that the index was initialized.
+    pub is_initialized: bool,
+}
+
+impl NtfsViewIndex {
+    /// Creates a new view index.
+    pub fn new(cluster_block_size: u32) -> Self {
+        Self {
+            mediator: Mediator::current(),
+            index: NtfsIndex::new(cluster_block_size),
+            root_node_data: Vec::new(),
+            is_initialized: false,
+        }
+    }
+
+    /// Initializes the view index.
+    pub fn initialize(
+        &mut self,
+        mft_attributes: &NtfsMftAttributes,
+        index_name: &str,
+    ) -> Result<(), ErrorTrace> {
+        let name: Option<Ucs2String> = Some(Ucs2String::from(index_name));
+        let attribute_group: &NtfsMftAttributeGroup =
+            match mft_attributes.get_attribute_group(&name) {
+                Some(attribute_group) => attribute_group,
+                None => {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Missing {} attributes",
+                        index_name
+                    )));
+                }
+            };
+        let index_root_attribute: &NtfsMftAttribute = match mft_attributes
+            .get_attribute_for_group(attribute_group, NTFS_ATTRIBUTE_TYPE_INDEX_ROOT)
+        {
+            Some(mft_attribute) => mft_attribute,
+            None => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Missing {} $INDEX_ROOT attribute",
+                    index_name
+                )));
+            }
+        };
+        if !index_root_attribute.is_resident() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported non-resident {} $INDEX_ROOT attribute",
+                index_name
+            )));
+        }
+        let mut index_root_header: NtfsIndexRootHeader = NtfsIndexRootHeader::new();
+
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(NtfsIndexRootHeader::debug_read_data(
+                    &index_root_attribute.resident_data,
+                ));
+        }
+        match index_root_header.read_data(&index_root_attribute.resident_data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read index root header");
+                return Err(error);
+            }
+        }
+        // A view index does not index an attribute and therefore has an attribute type of 0.
+        if index_root_header.attribute_type != 0 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported {} $INDEX_ROOT attribute type: 0x{:08x}",
+                index_name, index_root_header.attribute_type
+            )));
+        }
+        // Note that 2097152 is an arbitrary chosen limit, given 2048 KiB is the largest known
+        // cluster block size.
+        if index_root_header.index_entry_size < 20 || index_root_header.index_entry_size > 2097152 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported {} index entry size: {} value out of bounds",
+                index_name, index_root_header.index_entry_size
+            )));
+        }
+        // Note that the $INDEX_ALLOCATION attribute is optional.
+        match mft_attributes
+            .get_attribute_for_group(attribute_group, NTFS_ATTRIBUTE_TYPE_INDEX_ALLOCATION)
+        {
+            Some(mft_attribute) => match self
+                .index
+                .initialize(index_root_header.index_entry_size, mft_attribute)
+            {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to initialize index");
+                    return Err(error);
+                }
+            },
+            None => {}
+        };
+        self.root_node_data = index_root_attribute.resident_data.clone();
+        self.is_initialized = true;
+
+        Ok(())
+    }
+
+    /// Retrieves the index entries.
+    pub fn get_entries(
+        &self,
+        data_stream: &DataStreamReference,
+    ) -> Result<Vec<NtfsViewIndexEntry>, ErrorTrace> {
+        if !self.is_initialized {
+            return Err(keramics_core::error_trace_new!(
+                "View index was not initialized"
+            ));
+        }
+        let mut entries: Vec<NtfsViewIndexEntry> = Vec::new();
+
+        self.get_entries_from_node(&self.root_node_data, 16, data_stream, &mut entries)?;
+
+        Ok(entries)
+    }
+
+    /// Retrieves the index entries from an index node.
+    fn get_entries_from_node(
+        &self,
+        data: &[u8],
+        index_node_offset: usize,
+        data_stream: &DataStreamReference,
+        entries: &mut Vec<NtfsViewIndexEntry>,
+    ) -> Result<(), ErrorTrace> {
+        if index_node_offset + 16 > data.len() {
+            return Err(keramics_core::error_trace_new!(
+                "Invalid index node offset value out of bounds"
+            ));
+        }
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(NtfsIndexNodeHeader::debug_read_data(
+                    &data[index_node_offset..],
+                ));
+        }
+        let mut index_node_header: NtfsIndexNodeHeader = NtfsIndexNodeHeader::new();
+
+        match index_node_header.read_data(&data[index_node_offset..]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read index node header");
+                return Err(error);
+            }
+        }
+        let index_values_end_offset: usize = index_node_offset + index_node_header.size as usize;
+
+        if index_values_end_offset > data.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid index node size: {} value out of bounds",
+                index_node_header.size
+            )));
+        }
+        let index_values_offset: usize = index_node_header.index_values_offset as usize;
+
+        if index_node_header.size == 0 {
+            return Ok(());
+        }
+        if index_values_offset < 16 || index_values_offset >= index_node_header.size as usize {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid index values offset: {} value out of bounds",
+                index_node_header.index_values_offset
+            )));
+        }
+        let mut index_value_offset: usize = index_node_offset + index_values_offset;
+
+        while index_value_offset + 16 <= index_values_end_offset {
+            if self.mediator.debug_output {
+                self.mediator.debug_print(NtfsIndexValue::debug_read_data(
+                    &data[index_value_offset..index_value_offset + 16],
+                ));
+            }
+            let mut index_value: NtfsIndexValue = NtfsIndexValue::new();
+
+            match index_value.read_data(&data[index_value_offset..]) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read index value");
+                    return Err(error);
+                }
+            }
+            let index_value_size: usize = index_value.size as usize;
+            let index_value_end_offset: usize = index_value_offset + index_value_size;
+
+            if index_value_size < 16 || index_value_end_offset > index_values_end_offset {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid index value size: {} value out of bounds",
+                    index_value.size
+                )));
+            }
+            if index_value.flags & NTFS_INDEX_VALUE_FLAG_IS_BRANCH != 0 {
+                if index_value_size < 24 {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid index branch value size: {} value out of bounds",
+                        index_value.size
+                    )));
+                }
+                let sub_node_vcn: u64 = bytes_to_u64_le!(data, index_value_end_offset - 8);
+
+                let index_entry: NtfsIndexEntry = match self
+                    .index
+                    .get_entry_at_cluster_block(data_stream, sub_node_vcn)
+                {
+                    Ok(index_entry) => index_entry,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve index entry"
+                        );
+                        return Err(error);
+                    }
+                };
+                match self.get_entries_from_node(&index_entry.data, 24, data_stream, entries) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve entries from sub node"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+            if index_value.flags & NTFS_INDEX_VALUE_FLAG_IS_LAST != 0 {
+                break;
+            }
+            // In a view index the first 8 bytes of the index value contain the value data
+            // offset and size instead of a file reference.
+            let value_data_offset: usize =
+                index_value_offset + bytes_to_u16_le!(data, index_value_offset) as usize;
+            let value_data_size: usize = bytes_to_u16_le!(data, index_value_offset + 2) as usize;
+
+            let key_data_offset: usize = index_value_offset + 16;
+            let key_data_end_offset: usize = key_data_offset + index_value.key_data_size as usize;
+
+            if key_data_end_offset > index_value_end_offset {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid index value key data size: {} value out of bounds",
+                    index_value.key_data_size
+                )));
+            }
+            let value_data_end_offset: usize = value_data_offset + value_data_size;
+
+            if value_data_offset < key_data_offset || value_data_end_offset > index_value_end_offset
+            {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid index value data offset: {} and size: {} value out of bounds",
+                    value_data_offset - index_value_offset,
+                    value_data_size
+                )));
+            }
+            entries.push(NtfsViewIndexEntry {
+                key_data: data[key_data_offset..key_data_end_offset].to_vec(),
+                value_data: data[value_data_offset..value_data_end_offset].to_vec(),
+            });
+            index_value_offset = index_value_end_offset;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00,
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x11, 0x11, 0x11, 0x11,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x98, 0x00,
+            0x00, 0x00, 0x14, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x11, 0x11, 0x11, 0x11, 0x01, 0x01,
+            0x00, 0x00, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_get_entries_from_node() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+
+        let test_struct = NtfsViewIndex::new(4096);
+
+        let mut entries: Vec<NtfsViewIndexEntry> = Vec::new();
+        test_struct.get_entries_from_node(&test_data, 16, &data_stream, &mut entries)?;
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key_data, [0x00, 0x01, 0x00, 0x00]);
+        assert_eq!(entries[0].value_data.len(), 20);
+        assert_eq!(entries[1].key_data, [0x01, 0x01, 0x00, 0x00]);
+        assert_eq!(entries[1].value_data[4..8], [0x01, 0x01, 0x00, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_entries_from_node_with_invalid_data() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[40] = 0xff;
+
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+
+        let test_struct = NtfsViewIndex::new(4096);
+
+        let mut entries: Vec<NtfsViewIndexEntry> = Vec::new();
+        let result = test_struct.get_entries_from_node(&test_data, 16, &data_stream, &mut entries);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_entries_without_initialize() {
+        let data_stream: DataStreamReference = open_fake_data_stream(&[]);
+
+        let test_struct = NtfsViewIndex::new(4096);
+
+        let result = test_struct.get_entries(&data_stream);
+        assert!(result.is_err());
+    }
+}
@@ -15,7 +15,7 @@ use std::cmp::min;
 use std::io::SeekFrom;
 use std::sync::{Arc, RwLock};
 
-use keramics_compression::{LzxContext, LzxpressHuffmanContext};
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
 use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
@@ -248,41 +248,27 @@ impl NtfsWofCompressedStream {
             ));
             self.mediator.debug_print_data(&compressed_data, true);
         }
-        match self.compression_method {
-            0 | 2 | 3 => {
-                let mut lzxpress_context: LzxpressHuffmanContext = LzxpressHuffmanContext::new();
-
-                match lzxpress_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress LZXPRESS Huffman data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
-            1 => {
-                let mut lzx_context: LzxContext = LzxContext::new();
-
-                match lzx_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress LZX data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
+        let compression_method: CompressionMethod = match self.compression_method {
+            0 | 2 | 3 => CompressionMethod::LzxpressHuffman,
+            1 => CompressionMethod::Lzx,
             _ => {
                 return Err(keramics_core::error_trace_new!(format!(
                     "Unsupported compression method: {}",
                     self.compression_method
                 )));
             }
+        };
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&compression_method);
+
+        match decompressor.decompress(&compressed_data, data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to decompress {:?} data", compression_method)
+                );
+                return Err(error);
+            }
         }
         Ok(())
     }
@@ -15,6 +15,7 @@
 #[derive(Clone, Debug, PartialEq)]
 pub enum QcowBlockRangeType {
     Compressed,
+    Encrypted,
     InBackingFile,
     InFile,
     Sparse,
@@ -32,6 +33,9 @@ pub struct QcowBlockRange {
     /// Size.
     pub size: u64,
 
+    /// Compressed data size.
+    pub compressed_data_size: u64,
+
     /// Range type.
     pub range_type: QcowBlockRangeType,
 }
@@ -42,12 +46,14 @@ impl QcowBlockRange {
         media_offset: u64,
         data_offset: u64,
         size: u64,
+        compressed_data_size: u64,
         range_type: QcowBlockRangeType,
     ) -> Self {
         Self {
             media_offset: media_offset,
             data_offset: data_offset,
             size: size,
+            compressed_data_size: compressed_data_size,
             range_type: range_type,
         }
     }
@@ -13,3 +13,9 @@
 
 /// QCOW file header signature: "QFI\xfb".
 pub(super) const QCOW_FILE_HEADER_SIGNATURE: [u8; 4] = [0x51, 0x46, 0x49, 0xfb];
+
+/// QCOW full disk encryption header extension type.
+pub(super) const QCOW_HEADER_EXTENSION_FULL_DISK_ENCRYPTION: u32 = 0x0537be77;
+
+/// LUKS header signature: "LUKS\xba\xbe".
+pub(super) const QCOW_LUKS_HEADER_SIGNATURE: [u8; 6] = [0x4c, 0x55, 0x4b, 0x53, 0xba, 0xbe];
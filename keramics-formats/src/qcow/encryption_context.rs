@@ -0,0 +1,226 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_encryption::{AES_BLOCK_SIZE, AesContext, AesXtsContext};
+use keramics_hashes::{DigestHashContext, DigestHashMethod, new_digest_hash_context};
+
+/// QEMU Copy-On-Write (QCOW) sector cipher.
+enum QcowSectorCipher {
+    /// AES-CBC with the 32-bit sector number as initialization vector.
+    AesCbcPlain(AesContext),
+
+    /// AES-CBC with the 64-bit sector number as initialization vector.
+    AesCbcPlain64(AesContext),
+
+    /// AES-CBC with an encrypted salt-sector initialization vector (ESSIV).
+    AesCbcEssiv(AesContext, AesContext),
+
+    /// AES-XTS with the 32-bit sector number as tweak value.
+    AesXtsPlain(AesXtsContext),
+
+    /// AES-XTS with the 64-bit sector number as tweak value.
+    AesXtsPlain64(AesXtsContext),
+}
+
+/// QEMU Copy-On-Write (QCOW) encryption context.
+pub struct QcowEncryptionContext {
+    /// Sector cipher.
+    sector_cipher: QcowSectorCipher,
+}
+
+impl QcowEncryptionContext {
+    /// Sector size.
+    pub const SECTOR_SIZE: usize = 512;
+
+    /// Creates a new encryption context.
+    pub fn new(cipher_name: &str, cipher_mode: &str, key: &[u8]) -> Result<Self, ErrorTrace> {
+        if cipher_name != "aes" {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported cipher name: {}",
+                cipher_name
+            )));
+        }
+        let result: Result<QcowSectorCipher, ErrorTrace> = match cipher_mode {
+            "cbc-plain" => AesContext::new(key).map(QcowSectorCipher::AesCbcPlain),
+            "cbc-plain64" => AesContext::new(key).map(QcowSectorCipher::AesCbcPlain64),
+            "cbc-essiv:sha256" => {
+                let mut hash_context: Box<dyn DigestHashContext> =
+                    new_digest_hash_context(&DigestHashMethod::Sha256);
+                hash_context.update(key);
+                let essiv_key: Vec<u8> = hash_context.finalize();
+
+                match AesContext::new(&essiv_key) {
+                    Ok(essiv_context) => AesContext::new(key).map(|aes_context| {
+                        QcowSectorCipher::AesCbcEssiv(aes_context, essiv_context)
+                    }),
+                    Err(error) => Err(error),
+                }
+            }
+            "xts-plain" => AesXtsContext::new(key).map(QcowSectorCipher::AesXtsPlain),
+            "xts-plain64" => AesXtsContext::new(key).map(QcowSectorCipher::AesXtsPlain64),
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported cipher mode: {}",
+                    cipher_mode
+                )));
+            }
+        };
+        let sector_cipher: QcowSectorCipher = match result {
+            Ok(sector_cipher) => sector_cipher,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to create {}-{} sector cipher",
+                        cipher_name, cipher_mode
+                    )
+                );
+                return Err(error);
+            }
+        };
+        Ok(Self {
+            sector_cipher: sector_cipher,
+        })
+    }
+
+    /// Decrypts sectors in-place.
+    pub fn decrypt_sectors(&self, sector_number: u64, data: &mut [u8]) -> Result<(), ErrorTrace> {
+        if data.len() % Self::SECTOR_SIZE != 0 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported data size value not a multiple of the sector size"
+            ));
+        }
+        for (sector_index, sector_data) in data.chunks_exact_mut(Self::SECTOR_SIZE).enumerate() {
+            let sector: u64 = sector_number + sector_index as u64;
+
+            // Note that plain initialization vectors contain the sector number truncated to
+            // 32 bits and plain64 initialization vectors the 64-bit sector number, both stored
+            // in little-endian followed by zero bytes.
+            let mut initialization_vector: [u8; AES_BLOCK_SIZE] = [0; AES_BLOCK_SIZE];
+
+            let result: Result<(), ErrorTrace> = match &self.sector_cipher {
+                QcowSectorCipher::AesCbcPlain(aes_context) => {
+                    initialization_vector[0..4].copy_from_slice(&(sector as u32).to_le_bytes());
+
+                    aes_context.decrypt_cbc(&initialization_vector, sector_data)
+                }
+                QcowSectorCipher::AesCbcPlain64(aes_context) => {
+                    initialization_vector[0..8].copy_from_slice(&sector.to_le_bytes());
+
+                    aes_context.decrypt_cbc(&initialization_vector, sector_data)
+                }
+                QcowSectorCipher::AesCbcEssiv(aes_context, essiv_context) => {
+                    initialization_vector[0..8].copy_from_slice(&sector.to_le_bytes());
+
+                    essiv_context.encrypt_block(&mut initialization_vector);
+
+                    aes_context.decrypt_cbc(&initialization_vector, sector_data)
+                }
+                QcowSectorCipher::AesXtsPlain(xts_context) => {
+                    initialization_vector[0..4].copy_from_slice(&(sector as u32).to_le_bytes());
+
+                    xts_context.decrypt(&initialization_vector, sector_data)
+                }
+                QcowSectorCipher::AesXtsPlain64(xts_context) => {
+                    initialization_vector[0..8].copy_from_slice(&sector.to_le_bytes());
+
+                    xts_context.decrypt(&initialization_vector, sector_data)
+                }
+            };
+            if let Err(mut error) = result {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to decrypt sector: {}", sector)
+                );
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let result = QcowEncryptionContext::new("aes", "cbc-plain", &[0; 16]);
+        assert!(result.is_ok());
+
+        let result = QcowEncryptionContext::new("aes", "cbc-plain64", &[0; 16]);
+        assert!(result.is_ok());
+
+        let result = QcowEncryptionContext::new("aes", "cbc-essiv:sha256", &[0; 32]);
+        assert!(result.is_ok());
+
+        let result = QcowEncryptionContext::new("aes", "xts-plain64", &[0; 64]);
+        assert!(result.is_ok());
+
+        let result = QcowEncryptionContext::new("twofish", "xts-plain64", &[0; 64]);
+        assert!(result.is_err());
+
+        let result = QcowEncryptionContext::new("aes", "ecb", &[0; 16]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decrypt_sectors() -> Result<(), ErrorTrace> {
+        let test_context: QcowEncryptionContext = QcowEncryptionContext::new(
+            "aes",
+            "cbc-plain64",
+            b"keramics\x00\x00\x00\x00\x00\x00\x00\x00",
+        )?;
+
+        let mut data: Vec<u8> = vec![0; 1024];
+        test_context.decrypt_sectors(1, &mut data)?;
+
+        assert_eq!(
+            data[0..16],
+            [
+                0x9c, 0x39, 0x75, 0x83, 0xc4, 0x27, 0xde, 0x1e, 0xda, 0x6e, 0x05, 0x5d, 0xe6, 0x7b,
+                0xe4, 0xc1
+            ]
+        );
+        let result = test_context.decrypt_sectors(0, &mut data[0..511]);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_sectors_with_32bit_initialization_vector() -> Result<(), ErrorTrace> {
+        let key: &[u8; 16] = b"keramics\x00\x00\x00\x00\x00\x00\x00\x00";
+
+        let plain_context: QcowEncryptionContext =
+            QcowEncryptionContext::new("aes", "cbc-plain", key)?;
+        let plain64_context: QcowEncryptionContext =
+            QcowEncryptionContext::new("aes", "cbc-plain64", key)?;
+
+        // The plain initialization vector of sector 0x100000001 is that of sector 1.
+        let mut plain_data: Vec<u8> = vec![0; 512];
+        plain_context.decrypt_sectors(0x100000001, &mut plain_data)?;
+
+        let mut plain64_data: Vec<u8> = vec![0; 512];
+        plain64_context.decrypt_sectors(1, &mut plain64_data)?;
+
+        assert_eq!(plain_data, plain64_data);
+
+        plain64_context.decrypt_sectors(0x100000001, &mut plain64_data)?;
+
+        assert_ne!(plain_data, plain64_data);
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 pub enum QcowCompressionMethod {
     Unknown,
     Zlib,
+    Zstd,
 }
 
 #[derive(Clone, Eq, Hash, PartialEq)]
@@ -14,16 +14,22 @@
 use std::io::SeekFrom;
 use std::sync::{Arc, RwLock};
 
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
-use keramics_types::ByteString;
+use keramics_types::{ByteString, bytes_to_u32_be, bytes_to_u64_be};
 
 use crate::block_tree::BlockTree;
+use crate::lru_cache::LruCache;
 
 use super::block_range::{QcowBlockRange, QcowBlockRangeType};
 use super::cluster_table::{QcowClusterTable, QcowClusterTableEntry};
+use super::constants::*;
+use super::encryption_context::QcowEncryptionContext;
 use super::enums::{QcowCompressionMethod, QcowEncryptionMethod};
 use super::file_header::QcowFileHeader;
+use super::luks_header::QcowLuksHeader;
+use super::snapshot::QcowSnapshot;
 
 /// QEMU Copy-On-Write (QCOW) file.
 pub struct QcowFile {
@@ -81,15 +87,30 @@ pub struct QcowFile {
     /// Encryption method.
     pub encryption_method: QcowEncryptionMethod,
 
+    /// Encryption header offset.
+    encryption_header_offset: u64,
+
+    /// Encryption header size.
+    encryption_header_size: u64,
+
+    /// Encryption context.
+    encryption_context: Option<Arc<QcowEncryptionContext>>,
+
     /// Block tree.
     block_tree: BlockTree<QcowBlockRange>,
 
+    /// Block cache.
+    block_cache: LruCache<u64, Vec<u8>>,
+
     /// Backing file name.
     backing_file_name: Option<ByteString>,
 
     /// Backing file.
     backing_file: Option<Arc<RwLock<QcowFile>>>,
 
+    /// Snapshots.
+    snapshots: Vec<QcowSnapshot>,
+
     /// Media size.
     pub media_size: u64,
 
@@ -119,9 +140,14 @@ impl QcowFile {
             compression_flag_bit_mask: 0,
             compression_method: QcowCompressionMethod::Zlib,
             encryption_method: QcowEncryptionMethod::None,
+            encryption_header_offset: 0,
+            encryption_header_size: 0,
+            encryption_context: None,
             block_tree: BlockTree::<QcowBlockRange>::new(0, 0, 0),
+            block_cache: LruCache::new(64),
             backing_file_name: None,
             backing_file: None,
+            snapshots: Vec::new(),
             media_size: 0,
             media_offset: 0,
         }
@@ -132,6 +158,16 @@ impl QcowFile {
         self.backing_file_name.as_ref()
     }
 
+    /// Determines if the file is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption_method != QcowEncryptionMethod::None
+    }
+
+    /// Determines if the file is encrypted and could not be unlocked.
+    pub fn is_locked(&self) -> bool {
+        self.encryption_method != QcowEncryptionMethod::None && self.encryption_context.is_none()
+    }
+
     /// Reads a data stream.
     pub fn read_data_stream(
         &mut self,
@@ -174,6 +210,7 @@ impl QcowFile {
         if self.format_version == 3 {
             self.compression_method = match file_header.compression_method {
                 0 => QcowCompressionMethod::Zlib,
+                1 => QcowCompressionMethod::Zstd,
                 _ => QcowCompressionMethod::Unknown,
             };
         }
@@ -184,7 +221,8 @@ impl QcowFile {
         } else {
             self.offset_bit_mask = 0x3fffffffffffffff;
             self.compression_flag_bit_mask = 1 << 62;
-            self.compression_bit_shift = 62 - self.number_of_cluster_block_bits;
+            // The compressed size is stored in number of 512-byte sectors.
+            self.compression_bit_shift = 62 - (self.number_of_cluster_block_bits - 8);
         }
         self.level1_index_bit_shift =
             self.number_of_cluster_block_bits + file_header.number_of_level2_table_bits;
@@ -269,15 +307,192 @@ impl QcowFile {
                 }
             }
         }
+        if file_header.number_of_snapshots > 0 {
+            match self.read_snapshot_table(
+                data_stream,
+                file_header.snapshots_offset,
+                file_header.number_of_snapshots,
+            ) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read snapshot table");
+                    return Err(error);
+                }
+            }
+        }
+        if self.format_version > 1 {
+            match self.read_header_extensions(data_stream) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read header extensions"
+                    );
+                    return Err(error);
+                }
+            }
+        }
         if self.encryption_method != QcowEncryptionMethod::None {
-            // TODO: handle encryption
-            return Err(keramics_core::error_trace_new!(
-                "Unsupported encryption method"
-            ));
+            match self.read_encryption_context(data_stream) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read encryption context"
+                    );
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the header extensions.
+    fn read_header_extensions(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        let mut extension_offset: u64 = self.file_header_size as u64;
+
+        // The header extensions are stored in the first cluster.
+        while extension_offset + 8 <= self.cluster_block_size {
+            let mut data: [u8; 8] = [0; 8];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut data,
+                SeekFrom::Start(extension_offset)
+            );
+            let extension_type: u32 = bytes_to_u32_be!(data, 0);
+            let extension_data_size: u32 = bytes_to_u32_be!(data, 4);
+
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "QcowHeaderExtension at offset: {} (0x{:08x})\n",
+                    extension_offset, extension_offset
+                ));
+                self.mediator.debug_print(format!(
+                    "    extension_type: 0x{:08x}\n    extension_data_size: {}\n\n",
+                    extension_type, extension_data_size
+                ));
+            }
+            if extension_type == 0 {
+                break;
+            }
+            extension_offset += 8;
+
+            if extension_type == QCOW_HEADER_EXTENSION_FULL_DISK_ENCRYPTION {
+                if extension_data_size != 16 {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Unsupported full disk encryption header extension data size: {}",
+                        extension_data_size
+                    )));
+                }
+                let mut extension_data: [u8; 16] = [0; 16];
+
+                keramics_core::data_stream_read_exact_at_position!(
+                    data_stream,
+                    &mut extension_data,
+                    SeekFrom::Start(extension_offset)
+                );
+                self.encryption_header_offset = bytes_to_u64_be!(extension_data, 0);
+                self.encryption_header_size = bytes_to_u64_be!(extension_data, 8);
+            }
+            // The header extension data is aligned to 8 bytes.
+            extension_offset += (extension_data_size as u64).div_ceil(8) * 8;
         }
         Ok(())
     }
 
+    /// Reads the encryption context.
+    ///
+    /// The file remains locked if no password was provided or the password is incorrect.
+    fn read_encryption_context(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        let encryption_context: QcowEncryptionContext = match self.encryption_method {
+            QcowEncryptionMethod::AesCbc128 => {
+                let password: String = match self.mediator.get_credential("QCOW password") {
+                    Some(password) => password,
+                    None => return Ok(()),
+                };
+                // The key consists of the first 16 bytes of the password padded with zero bytes.
+                let mut key: [u8; 16] = [0; 16];
+
+                let password_data: &[u8] = password.as_bytes();
+                let key_size: usize = password_data.len().min(16);
+                key[0..key_size].copy_from_slice(&password_data[0..key_size]);
+
+                match QcowEncryptionContext::new("aes", "cbc-plain64", &key) {
+                    Ok(encryption_context) => encryption_context,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to create encryption context"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+            QcowEncryptionMethod::Luks => {
+                if self.encryption_header_offset == 0 || self.encryption_header_size < 592 {
+                    return Err(keramics_core::error_trace_new!(
+                        "Missing LUKS encryption header"
+                    ));
+                }
+                let mut luks_header: QcowLuksHeader = QcowLuksHeader::new();
+
+                match luks_header
+                    .read_at_position(data_stream, SeekFrom::Start(self.encryption_header_offset))
+                {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(error, "Unable to read LUKS header");
+                        return Err(error);
+                    }
+                }
+                let password: String = match self.mediator.get_credential("QCOW LUKS password") {
+                    Some(password) => password,
+                    None => return Ok(()),
+                };
+                let master_key: Vec<u8> = match luks_header.get_master_key(
+                    data_stream,
+                    self.encryption_header_offset,
+                    password.as_bytes(),
+                ) {
+                    Ok(Some(master_key)) => master_key,
+                    Ok(None) => return Ok(()),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve LUKS master key"
+                        );
+                        return Err(error);
+                    }
+                };
+                match luks_header.new_encryption_context(&master_key) {
+                    Ok(encryption_context) => encryption_context,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to create LUKS encryption context"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+            _ => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unsupported encryption method"
+                ));
+            }
+        };
+        self.encryption_context = Some(Arc::new(encryption_context));
+
+        Ok(())
+    }
+
     /// Reads the backing file name.
     fn read_backing_file_name(
         &mut self,
@@ -318,6 +533,117 @@ impl QcowFile {
         Ok(())
     }
 
+    /// Reads the snapshot table.
+    fn read_snapshot_table(
+        &mut self,
+        data_stream: &DataStreamReference,
+        snapshots_offset: u64,
+        number_of_snapshots: u32,
+    ) -> Result<(), ErrorTrace> {
+        if snapshots_offset < self.file_header_size as u64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported snapshots offset: {}",
+                snapshots_offset
+            )));
+        }
+        // Note that QEMU limits the number of snapshots to 65536.
+        if number_of_snapshots > 65536 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported number of snapshots: {}",
+                number_of_snapshots
+            )));
+        }
+        let mut snapshot_offset: u64 = snapshots_offset;
+
+        for snapshot_index in 0..number_of_snapshots {
+            let mut snapshot: QcowSnapshot = QcowSnapshot::new();
+
+            snapshot_offset =
+                match snapshot.read_at_position(data_stream, SeekFrom::Start(snapshot_offset)) {
+                    Ok(next_offset) => next_offset,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to read snapshot: {}", snapshot_index)
+                        );
+                        return Err(error);
+                    }
+                };
+            if snapshot.media_size == 0 {
+                snapshot.media_size = self.media_size;
+            }
+            self.snapshots.push(snapshot);
+        }
+        Ok(())
+    }
+
+    /// Retrieves the number of snapshots.
+    pub fn get_number_of_snapshots(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Retrieves a specific snapshot.
+    pub fn get_snapshot_by_index(
+        &self,
+        snapshot_index: usize,
+    ) -> Result<&QcowSnapshot, ErrorTrace> {
+        match self.snapshots.get(snapshot_index) {
+            Some(snapshot) => Ok(snapshot),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "No snapshot with index: {}",
+                snapshot_index
+            ))),
+        }
+    }
+
+    /// Opens a specific snapshot as a read-only file.
+    pub fn open_snapshot_by_index(&self, snapshot_index: usize) -> Result<QcowFile, ErrorTrace> {
+        let snapshot: &QcowSnapshot = self.get_snapshot_by_index(snapshot_index)?;
+
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let mut file: QcowFile = QcowFile::new();
+
+        file.data_stream = Some(data_stream.clone());
+        file.format_version = self.format_version;
+        file.file_header_size = self.file_header_size;
+        file.offset_bit_mask = self.offset_bit_mask;
+        file.level1_index_bit_shift = self.level1_index_bit_shift;
+        file.level2_index_bit_mask = self.level2_index_bit_mask;
+        file.level2_table_number_of_references = self.level2_table_number_of_references;
+        file.number_of_cluster_block_bits = self.number_of_cluster_block_bits;
+        file.cluster_block_bit_mask = self.cluster_block_bit_mask;
+        file.cluster_block_size = self.cluster_block_size;
+        file.compression_bit_shift = self.compression_bit_shift;
+        file.compression_bit_mask = self.compression_bit_mask;
+        file.compression_flag_bit_mask = self.compression_flag_bit_mask;
+        file.compression_method = self.compression_method.clone();
+        file.encryption_method = self.encryption_method.clone();
+        file.encryption_header_offset = self.encryption_header_offset;
+        file.encryption_header_size = self.encryption_header_size;
+        file.encryption_context = self.encryption_context.clone();
+        file.backing_file_name = self.backing_file_name.clone();
+        file.backing_file = self.backing_file.clone();
+        file.media_size = snapshot.media_size;
+
+        file.level1_cluster_table.set_range(
+            snapshot.level1_table_offset,
+            snapshot.level1_table_number_of_references,
+        );
+        let block_tree_data_size: u64 =
+            file.media_size.div_ceil(file.cluster_block_size) * file.cluster_block_size;
+        file.block_tree = BlockTree::<QcowBlockRange>::new(
+            block_tree_data_size,
+            file.level2_table_number_of_references,
+            file.cluster_block_size,
+        );
+        Ok(file)
+    }
+
     /// Reads a specific cluster block entry and fills the block tree.
     fn read_cluster_block_entry(&mut self, media_offset: u64) -> Result<(), ErrorTrace> {
         let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
@@ -351,6 +677,7 @@ impl QcowFile {
                 level1_media_offset,
                 0,
                 range_media_size,
+                0,
                 QcowBlockRangeType::Sparse,
             );
             match self
@@ -388,7 +715,9 @@ impl QcowFile {
             };
             let level2_media_offset: u64 =
                 level1_media_offset + (level2_table_index * self.cluster_block_size);
-            let block_data_offset: u64 = level2_entry.reference & self.offset_bit_mask;
+            let mut block_data_offset: u64 = level2_entry.reference & self.offset_bit_mask;
+            let mut compressed_data_size: u64 = 0;
+
             let range_type: QcowBlockRangeType = if block_data_offset == 0 {
                 if self.backing_file_name.is_some() {
                     QcowBlockRangeType::InBackingFile
@@ -397,13 +726,30 @@ impl QcowFile {
                 }
             } else {
                 if (level2_entry.reference & self.compression_flag_bit_mask) == 0 {
-                    QcowBlockRangeType::InFile
+                    if self.encryption_method != QcowEncryptionMethod::None {
+                        QcowBlockRangeType::Encrypted
+                    } else {
+                        QcowBlockRangeType::InFile
+                    }
                 } else {
                     if self.encryption_method != QcowEncryptionMethod::None {
                         return Err(keramics_core::error_trace_new!(
                             "Unsupported combined encryption and compression"
                         ));
                     }
+                    let compressed_size_value: u64 =
+                        block_data_offset >> self.compression_bit_shift;
+                    block_data_offset &= self.compression_bit_mask;
+
+                    compressed_data_size = if self.format_version == 1 {
+                        compressed_size_value & self.cluster_block_bit_mask
+                    } else {
+                        // The number of additional sectors is stored, where the first sector
+                        // can start at an offset that is not aligned to a sector boundary.
+                        let number_of_sectors: u64 =
+                            (compressed_size_value & (self.cluster_block_bit_mask >> 8)) + 1;
+                        (number_of_sectors * 512) - (block_data_offset & 511)
+                    };
                     QcowBlockRangeType::Compressed
                 }
             };
@@ -411,6 +757,7 @@ impl QcowFile {
                 level2_media_offset,
                 block_data_offset,
                 self.cluster_block_size,
+                compressed_data_size,
                 range_type,
             );
             match self.block_tree.insert_value(
@@ -475,9 +822,51 @@ impl QcowFile {
             }
             let data_end_offset: usize = data_offset + range_read_size;
             let range_read_count: usize = match block_range.range_type {
-                QcowBlockRangeType::Compressed => {
-                    // TODO: add compression support.
-                    todo!();
+                QcowBlockRangeType::Compressed | QcowBlockRangeType::Encrypted => {
+                    let range_data_offset: usize = range_relative_offset as usize;
+                    let range_data_end_offset: usize = range_data_offset + range_read_size;
+
+                    if !self.block_cache.contains(&block_range.data_offset) {
+                        let mut data: Vec<u8> = vec![0; block_range.size as usize];
+
+                        if block_range.range_type == QcowBlockRangeType::Compressed {
+                            match self.read_compressed_block(block_range, &mut data) {
+                                Ok(_) => {}
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to read compressed block"
+                                    );
+                                    return Err(error);
+                                }
+                            }
+                        } else {
+                            match self.read_encrypted_block(block_range, &mut data) {
+                                Ok(_) => {}
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to read encrypted block"
+                                    );
+                                    return Err(error);
+                                }
+                            }
+                        }
+                        self.block_cache.insert(block_range.data_offset, data);
+                    }
+                    let range_data: &Vec<u8> = match self.block_cache.get(&block_range.data_offset)
+                    {
+                        Some(data) => data,
+                        None => {
+                            return Err(keramics_core::error_trace_new!(format!(
+                                "Unable to retrieve data from cache"
+                            )));
+                        }
+                    };
+                    data[data_offset..data_end_offset]
+                        .copy_from_slice(&range_data[range_data_offset..range_data_end_offset]);
+
+                    range_read_size
                 }
                 QcowBlockRangeType::InBackingFile => {
                     let backing_file: &Arc<RwLock<QcowFile>> = match self.backing_file.as_ref() {
@@ -522,6 +911,102 @@ impl QcowFile {
         Ok(data_offset)
     }
 
+    /// Reads a compressed block range.
+    fn read_compressed_block(
+        &self,
+        block_range: &QcowBlockRange,
+        data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let mut compressed_data: Vec<u8> = vec![0; block_range.compressed_data_size as usize];
+
+        // The compressed data size is rounded up to a sector boundary and can therefore
+        // extend beyond the end of the file.
+        let read_count: usize = keramics_core::data_stream_read_at_position!(
+            data_stream,
+            &mut compressed_data,
+            SeekFrom::Start(block_range.data_offset)
+        );
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "Compressed data of size: {} at offset: {} (0x{:08x})\n",
+                read_count, block_range.data_offset, block_range.data_offset,
+            ));
+            self.mediator
+                .debug_print_data(&compressed_data[..read_count], true);
+        }
+        let compression_method: CompressionMethod = match self.compression_method {
+            // Zlib compressed clusters are stored as raw deflate data without a zlib header.
+            QcowCompressionMethod::Zlib => CompressionMethod::Deflate,
+            QcowCompressionMethod::Zstd => CompressionMethod::Zstd,
+            _ => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unsupported compression method"
+                ));
+            }
+        };
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&compression_method);
+
+        match decompressor.decompress(&compressed_data[..read_count], data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to decompress {:?} data", compression_method)
+                );
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads an encrypted block range.
+    fn read_encrypted_block(
+        &self,
+        block_range: &QcowBlockRange,
+        data: &mut Vec<u8>,
+    ) -> Result<(), ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let encryption_context: &Arc<QcowEncryptionContext> = match self.encryption_context.as_ref()
+        {
+            Some(encryption_context) => encryption_context,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unable to decrypt data file is locked"
+                ));
+            }
+        };
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            data,
+            SeekFrom::Start(block_range.data_offset)
+        );
+        // The legacy AES method uses the sector number of the media offset as initialization
+        // vector, where LUKS uses the sector number of the offset in the file.
+        let sector_number: u64 = match self.encryption_method {
+            QcowEncryptionMethod::AesCbc128 => block_range.media_offset >> 9,
+            _ => block_range.data_offset >> 9,
+        };
+        match encryption_context.decrypt_sectors(sector_number, data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to decrypt data");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
     /// Sets the backing file.
     pub fn set_backing_file(
         &mut self,
@@ -68,9 +68,12 @@ impl QcowFileHeaderV3 {
         }
         let supported_flags: u64 = 1;
 
+        // Supported incompatible feature flags are dirty (bit 0) and compression type (bit 3).
+        let supported_incompatible_flags: u64 = 0x00000009;
+
         let incompatible_feature_flags: u64 = bytes_to_u64_be!(data, 72);
 
-        if incompatible_feature_flags & !(supported_flags) != 0 {
+        if incompatible_feature_flags & !(supported_incompatible_flags) != 0 {
             return Err(keramics_core::error_trace_new!(format!(
                 "Unsupported incompatible feature flags: 0x{:016x}",
                 incompatible_feature_flags
@@ -94,7 +97,11 @@ impl QcowFileHeaderV3 {
         file_header.number_of_snapshots = bytes_to_u32_be!(data, 60);
         file_header.snapshots_offset = bytes_to_u64_be!(data, 64);
         file_header.header_size = bytes_to_u32_be!(data, 100);
-        file_header.compression_method = data[104];
+
+        // The compression method is only stored in headers of 112 bytes or more.
+        if file_header.header_size >= 112 {
+            file_header.compression_method = data[104];
+        }
 
         if file_header.number_of_cluster_block_bits <= 8
             || file_header.number_of_cluster_block_bits > 63
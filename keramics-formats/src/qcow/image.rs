@@ -11,7 +11,7 @@
  * under the License.
  */
 
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 use keramics_core::{DataStreamReference, ErrorTrace};
 
@@ -19,6 +19,7 @@ use crate::file_resolver::FileResolverReference;
 use crate::path_component::PathComponent;
 
 use super::file::QcowFile;
+use super::snapshot::QcowSnapshot;
 
 pub type QcowImageLayer = Arc<RwLock<QcowFile>>;
 
@@ -26,12 +27,40 @@ pub type QcowImageLayer = Arc<RwLock<QcowFile>>;
 pub struct QcowImage {
     /// Files.
     files: Vec<Arc<RwLock<QcowFile>>>,
+
+    /// Snapshots.
+    snapshots: Vec<QcowSnapshot>,
+
+    /// Snapshot files.
+    snapshot_files: Vec<Arc<RwLock<QcowFile>>>,
+
+    /// Value to indicate one or more of the files are encrypted.
+    is_encrypted: bool,
+
+    /// Value to indicate one or more of the files are encrypted and could not be unlocked.
+    is_locked: bool,
 }
 
 impl QcowImage {
     /// Creates a new storage media image.
     pub fn new() -> Self {
-        Self { files: Vec::new() }
+        Self {
+            files: Vec::new(),
+            snapshots: Vec::new(),
+            snapshot_files: Vec::new(),
+            is_encrypted: false,
+            is_locked: false,
+        }
+    }
+
+    /// Determines if the image is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.is_encrypted
+    }
+
+    /// Determines if the image is encrypted and could not be unlocked.
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
     }
 
     /// Retrieves the number of layers.
@@ -50,6 +79,39 @@ impl QcowImage {
         }
     }
 
+    /// Retrieves the number of snapshots.
+    pub fn get_number_of_snapshots(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Retrieves a snapshot by index.
+    pub fn get_snapshot_by_index(
+        &self,
+        snapshot_index: usize,
+    ) -> Result<&QcowSnapshot, ErrorTrace> {
+        match self.snapshots.get(snapshot_index) {
+            Some(snapshot) => Ok(snapshot),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "No snapshot with index: {}",
+                snapshot_index
+            ))),
+        }
+    }
+
+    /// Retrieves a snapshot layer by index.
+    pub fn get_snapshot_layer_by_index(
+        &self,
+        snapshot_index: usize,
+    ) -> Result<QcowImageLayer, ErrorTrace> {
+        match self.snapshot_files.get(snapshot_index) {
+            Some(file) => Ok(file.clone()),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "No snapshot with index: {}",
+                snapshot_index
+            ))),
+        }
+    }
+
     /// Opens a storage media image.
     pub fn open(
         &mut self,
@@ -135,10 +197,45 @@ impl QcowImage {
                     }
                 }
             }
+            if file.is_encrypted() {
+                self.is_encrypted = true;
+            }
+            if file.is_locked() {
+                self.is_locked = true;
+            }
             self.files.push(Arc::new(RwLock::new(file)));
 
             file_index += 1;
         }
+        // Internal snapshots are stored in the file that was opened, which is the last layer.
+        if let Some(last_file) = self.files.last() {
+            let file: RwLockReadGuard<QcowFile> = match last_file.read() {
+                Ok(file) => file,
+                Err(error) => {
+                    return Err(keramics_core::error_trace_new_with_error!(
+                        "Unable to obtain read lock on QCOW file",
+                        error
+                    ));
+                }
+            };
+            for snapshot_index in 0..file.get_number_of_snapshots() {
+                let snapshot: &QcowSnapshot = file.get_snapshot_by_index(snapshot_index)?;
+                self.snapshots.push(snapshot.clone());
+
+                let snapshot_file: QcowFile = match file.open_snapshot_by_index(snapshot_index) {
+                    Ok(snapshot_file) => snapshot_file,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to open snapshot: {}", snapshot_index)
+                        );
+                        return Err(error);
+                    }
+                };
+                self.snapshot_files
+                    .push(Arc::new(RwLock::new(snapshot_file)));
+            }
+        }
         Ok(())
     }
 }
@@ -191,6 +288,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_number_of_snapshots() -> Result<(), ErrorTrace> {
+        let image: QcowImage = get_image()?;
+
+        assert_eq!(image.get_number_of_snapshots(), 0);
+
+        let mut image: QcowImage = QcowImage::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("qcow").as_str());
+        let file_resolver: FileResolverReference = open_os_file_resolver(&path_buf)?;
+        let file_name: PathComponent = PathComponent::from("ext2_snapshot.qcow2");
+        image.open(&file_resolver, &file_name)?;
+
+        assert_eq!(image.get_number_of_snapshots(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_snapshot_by_index() -> Result<(), ErrorTrace> {
+        let mut image: QcowImage = QcowImage::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("qcow").as_str());
+        let file_resolver: FileResolverReference = open_os_file_resolver(&path_buf)?;
+        let file_name: PathComponent = PathComponent::from("ext2_snapshot.qcow2");
+        image.open(&file_resolver, &file_name)?;
+
+        let snapshot: &QcowSnapshot = image.get_snapshot_by_index(0)?;
+        assert_eq!(snapshot.identifier, "1");
+        assert_eq!(snapshot.name, "snapshot1");
+        assert_eq!(snapshot.media_size, 4194304);
+
+        let result: Result<&QcowSnapshot, ErrorTrace> = image.get_snapshot_by_index(1);
+        assert!(result.is_err());
+
+        let layer: QcowImageLayer = image.get_snapshot_layer_by_index(0)?;
+
+        match layer.read() {
+            Ok(file) => assert_eq!(file.media_size, 4194304),
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to obtain read lock on QCOW snapshot layer",
+                    error
+                ));
+            }
+        };
+        Ok(())
+    }
+
     #[test]
     fn test_open() -> Result<(), ErrorTrace> {
         let mut image: QcowImage = QcowImage::new();
@@ -0,0 +1,399 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_encryption::pbkdf2_derive_key;
+use keramics_hashes::{DigestHashContext, DigestHashMethod, new_digest_hash_context};
+use keramics_layout_map::LayoutMap;
+use keramics_types::{ByteString, bytes_to_u16_be, bytes_to_u32_be};
+
+use super::constants::*;
+use super::encryption_context::QcowEncryptionContext;
+use super::luks_key_slot::QcowLuksKeySlot;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "signature", data_type = "[u8; 6]", format = "hex"),
+        field(name = "format_version", data_type = "u16"),
+        field(name = "cipher_name", data_type = "ByteString<32>"),
+        field(name = "cipher_mode", data_type = "ByteString<32>"),
+        field(name = "hash_specification", data_type = "ByteString<32>"),
+        field(name = "payload_sector_number", data_type = "u32"),
+        field(name = "key_size", data_type = "u32"),
+        field(name = "master_key_digest", data_type = "[u8; 20]", format = "hex"),
+        field(
+            name = "master_key_digest_salt",
+            data_type = "[u8; 32]",
+            format = "hex"
+        ),
+        field(name = "master_key_digest_number_of_iterations", data_type = "u32"),
+        field(name = "identifier", data_type = "ByteString<40>"),
+        field(name = "key_slots", data_type = "[Struct<QcowLuksKeySlot; 48>; 8]"),
+    ),
+    method(name = "debug_read_data"),
+    method(name = "read_at_position")
+)]
+/// QEMU Copy-On-Write (QCOW) Linux Unified Key Setup (LUKS) version 1 header.
+pub struct QcowLuksHeader {
+    /// Format version.
+    pub format_version: u16,
+
+    /// Cipher name.
+    pub cipher_name: ByteString,
+
+    /// Cipher mode.
+    pub cipher_mode: ByteString,
+
+    /// Hash specification.
+    pub hash_specification: ByteString,
+
+    /// Key size.
+    pub key_size: u32,
+
+    /// Master key digest.
+    pub master_key_digest: [u8; 20],
+
+    /// Master key digest salt.
+    pub master_key_digest_salt: [u8; 32],
+
+    /// Master key digest number of PBKDF2 iterations.
+    pub master_key_digest_number_of_iterations: u32,
+
+    /// Key slots.
+    pub key_slots: Vec<QcowLuksKeySlot>,
+}
+
+impl QcowLuksHeader {
+    /// Creates a new LUKS header.
+    pub fn new() -> Self {
+        Self {
+            format_version: 0,
+            cipher_name: ByteString::new(),
+            cipher_mode: ByteString::new(),
+            hash_specification: ByteString::new(),
+            key_size: 0,
+            master_key_digest: [0; 20],
+            master_key_digest_salt: [0; 32],
+            master_key_digest_number_of_iterations: 0,
+            key_slots: Vec::new(),
+        }
+    }
+
+    /// Reads the LUKS header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 592 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported LUKS header data size"
+            ));
+        }
+        if data[0..6] != QCOW_LUKS_HEADER_SIGNATURE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported LUKS header signature"
+            ));
+        }
+        self.format_version = bytes_to_u16_be!(data, 6);
+
+        if self.format_version != 1 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported LUKS format version: {}",
+                self.format_version
+            )));
+        }
+        self.cipher_name = ByteString::from(&data[8..40]);
+        self.cipher_mode = ByteString::from(&data[40..72]);
+        self.hash_specification = ByteString::from(&data[72..104]);
+        self.key_size = bytes_to_u32_be!(data, 108);
+        self.master_key_digest.copy_from_slice(&data[112..132]);
+        self.master_key_digest_salt.copy_from_slice(&data[132..164]);
+        self.master_key_digest_number_of_iterations = bytes_to_u32_be!(data, 164);
+
+        if self.key_size == 0 || self.key_size > 64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid key size: {} value out of bounds",
+                self.key_size
+            )));
+        }
+        self.key_slots.clear();
+
+        for data_offset in (208..592).step_by(48) {
+            let mut key_slot: QcowLuksKeySlot = QcowLuksKeySlot::new();
+
+            let data_end_offset: usize = data_offset + 48;
+            key_slot.read_data(&data[data_offset..data_end_offset])?;
+
+            self.key_slots.push(key_slot);
+        }
+        Ok(())
+    }
+
+    /// Retrieves the digest hash method of the hash specification.
+    fn get_digest_hash_method(&self) -> Result<DigestHashMethod, ErrorTrace> {
+        let hash_specification: String = self.hash_specification.to_string();
+
+        match hash_specification.as_str() {
+            "sha1" => Ok(DigestHashMethod::Sha1),
+            "sha224" => Ok(DigestHashMethod::Sha224),
+            "sha256" => Ok(DigestHashMethod::Sha256),
+            "sha512" => Ok(DigestHashMethod::Sha512),
+            _ => Err(keramics_core::error_trace_new!(format!(
+                "Unsupported hash specification: {}",
+                hash_specification
+            ))),
+        }
+    }
+
+    /// Creates an encryption context for a specific key.
+    pub fn new_encryption_context(&self, key: &[u8]) -> Result<QcowEncryptionContext, ErrorTrace> {
+        QcowEncryptionContext::new(
+            self.cipher_name.to_string().as_str(),
+            self.cipher_mode.to_string().as_str(),
+            key,
+        )
+    }
+
+    /// Merges anti-forensic split key material into a key.
+    fn merge_key_material(
+        digest_hash_method: &DigestHashMethod,
+        key_material: &[u8],
+        key_size: usize,
+        number_of_stripes: usize,
+    ) -> Vec<u8> {
+        let hash_size: usize = digest_hash_method.get_hash_size();
+        let mut key: Vec<u8> = vec![0; key_size];
+
+        for stripe_index in 0..number_of_stripes {
+            let stripe_offset: usize = stripe_index * key_size;
+
+            for byte_index in 0..key_size {
+                key[byte_index] ^= key_material[stripe_offset + byte_index];
+            }
+            if stripe_index + 1 == number_of_stripes {
+                break;
+            }
+            // Diffuse the intermediate key by hashing every hash sized block together
+            // with its big-endian block number.
+            for (block_index, key_block) in key.chunks_mut(hash_size).enumerate() {
+                let mut hash_context: Box<dyn DigestHashContext> =
+                    new_digest_hash_context(digest_hash_method);
+                hash_context.update(&(block_index as u32).to_be_bytes());
+                hash_context.update(key_block);
+                let hash_value: Vec<u8> = hash_context.finalize();
+
+                let block_size: usize = key_block.len();
+                key_block.copy_from_slice(&hash_value[0..block_size]);
+            }
+        }
+        key
+    }
+
+    /// Retrieves the master key using a password.
+    ///
+    /// Returns None if the password does not unlock any of the active key slots.
+    pub fn get_master_key(
+        &self,
+        data_stream: &DataStreamReference,
+        header_offset: u64,
+        password: &[u8],
+    ) -> Result<Option<Vec<u8>>, ErrorTrace> {
+        let digest_hash_method: DigestHashMethod = self.get_digest_hash_method()?;
+        let key_size: usize = self.key_size as usize;
+
+        for key_slot in self.key_slots.iter() {
+            if !key_slot.is_active() {
+                continue;
+            }
+            let number_of_stripes: usize = key_slot.number_of_stripes as usize;
+
+            if number_of_stripes == 0 || number_of_stripes > 65536 {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Invalid number of stripes: {} value out of bounds",
+                    number_of_stripes
+                )));
+            }
+            let mut slot_key: Vec<u8> = vec![0; key_size];
+
+            pbkdf2_derive_key(
+                &digest_hash_method,
+                password,
+                &key_slot.salt,
+                key_slot.number_of_iterations,
+                &mut slot_key,
+            )?;
+            let key_material_size: usize = (key_size * number_of_stripes)
+                .div_ceil(QcowEncryptionContext::SECTOR_SIZE)
+                * QcowEncryptionContext::SECTOR_SIZE;
+            let key_material_offset: u64 = header_offset
+                + (key_slot.key_material_sector_number as u64
+                    * QcowEncryptionContext::SECTOR_SIZE as u64);
+
+            let mut key_material: Vec<u8> = vec![0; key_material_size];
+
+            keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut key_material,
+                SeekFrom::Start(key_material_offset)
+            );
+            let encryption_context: QcowEncryptionContext =
+                self.new_encryption_context(&slot_key)?;
+
+            match encryption_context.decrypt_sectors(0, &mut key_material) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to decrypt key material");
+                    return Err(error);
+                }
+            }
+            let master_key: Vec<u8> = Self::merge_key_material(
+                &digest_hash_method,
+                &key_material,
+                key_size,
+                number_of_stripes,
+            );
+            let mut master_key_digest: [u8; 20] = [0; 20];
+
+            pbkdf2_derive_key(
+                &digest_hash_method,
+                &master_key,
+                &self.master_key_digest_salt,
+                self.master_key_digest_number_of_iterations,
+                &mut master_key_digest,
+            )?;
+            if master_key_digest == self.master_key_digest {
+                return Ok(Some(master_key));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    use keramics_core::open_os_data_stream;
+
+    use crate::tests::get_test_data_path;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x4c, 0x55, 0x4b, 0x53, 0xba, 0xbe, 0x00, 0x01, 0x61, 0x65, 0x73, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x74,
+            0x73, 0x2d, 0x70, 0x6c, 0x61, 0x69, 0x6e, 0x36, 0x34, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x73, 0x68, 0x61, 0x32, 0x35, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+            0x13, 0x6e, 0xb7, 0x02, 0x12, 0x30, 0xb6, 0xb6, 0x9f, 0x77, 0x60, 0x90, 0x03, 0x0f,
+            0xe3, 0x57, 0xb8, 0x8f, 0x50, 0xc3, 0x38, 0xc2, 0x75, 0xf3, 0x4a, 0xed, 0x05, 0x6a,
+            0xd6, 0xea, 0x8e, 0xec, 0xa4, 0x19, 0x2f, 0xa1, 0xfe, 0xb9, 0xdc, 0x4b, 0x1e, 0xbe,
+            0x55, 0xe5, 0xb8, 0xf9, 0xb6, 0x80, 0xef, 0xf7, 0x6c, 0x81, 0x00, 0x00, 0x03, 0xe8,
+            0x30, 0x62, 0x39, 0x65, 0x34, 0x61, 0x36, 0x33, 0x2d, 0x34, 0x61, 0x65, 0x35, 0x2d,
+            0x34, 0x62, 0x31, 0x65, 0x2d, 0x61, 0x35, 0x61, 0x33, 0x2d, 0x33, 0x63, 0x31, 0x64,
+            0x35, 0x66, 0x31, 0x65, 0x34, 0x61, 0x32, 0x66, 0x00, 0x00, 0x00, 0x00, 0x00, 0xac,
+            0x71, 0xf3, 0x00, 0x00, 0x03, 0xe8, 0xd4, 0xe9, 0xab, 0x30, 0x4d, 0x48, 0x96, 0xf9,
+            0xe1, 0x7f, 0xd8, 0xf0, 0x81, 0x64, 0x96, 0xda, 0x08, 0x7a, 0x3e, 0xbe, 0xcc, 0x67,
+            0x6a, 0xaa, 0x2c, 0x5d, 0x8c, 0xe1, 0xb3, 0xc6, 0xac, 0xbc, 0x00, 0x00, 0x00, 0x08,
+            0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0xde, 0xad, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0xde, 0xad,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xf8, 0x00, 0x00,
+            0x0f, 0xa0, 0x00, 0x00, 0xde, 0xad, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0xf0, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0xde, 0xad, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xe8, 0x00, 0x00, 0x0f, 0xa0,
+            0x00, 0x00, 0xde, 0xad, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x09, 0xe0, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0xde, 0xad, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0xd8, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00,
+            0xde, 0xad, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0xd0,
+            0x00, 0x00, 0x0f, 0xa0,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowLuksHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.format_version, 1);
+        assert_eq!(test_struct.cipher_name, "aes");
+        assert_eq!(test_struct.cipher_mode, "xts-plain64");
+        assert_eq!(test_struct.hash_specification, "sha256");
+        assert_eq!(test_struct.key_size, 64);
+        assert_eq!(test_struct.master_key_digest_number_of_iterations, 1000);
+        assert_eq!(test_struct.key_slots.len(), 8);
+        assert!(test_struct.key_slots[0].is_active());
+        assert!(!test_struct.key_slots[1].is_active());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowLuksHeader::new();
+        let result = test_struct.read_data(&test_data[0..591]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_signature() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = QcowLuksHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_master_key() -> Result<(), ErrorTrace> {
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("qcow/ext2_luks.qcow2").as_str());
+        let data_stream: DataStreamReference = open_os_data_stream(&path_buf)?;
+
+        let mut test_struct = QcowLuksHeader::new();
+        test_struct.read_at_position(&data_stream, SeekFrom::Start(327680))?;
+
+        let master_key: Option<Vec<u8>> =
+            test_struct.get_master_key(&data_stream, 327680, b"keramics")?;
+        assert!(master_key.is_some());
+        assert_eq!(master_key.unwrap().len(), 64);
+
+        let master_key: Option<Vec<u8>> =
+            test_struct.get_master_key(&data_stream, 327680, b"incorrect")?;
+        assert!(master_key.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::bytes_to_u32_be;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "state", data_type = "u32", format = "hex"),
+        field(name = "number_of_iterations", data_type = "u32"),
+        field(name = "salt", data_type = "[u8; 32]", format = "hex"),
+        field(name = "key_material_sector_number", data_type = "u32"),
+        field(name = "number_of_stripes", data_type = "u32"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// QEMU Copy-On-Write (QCOW) Linux Unified Key Setup (LUKS) key slot.
+pub struct QcowLuksKeySlot {
+    /// State.
+    pub state: u32,
+
+    /// Number of PBKDF2 iterations.
+    pub number_of_iterations: u32,
+
+    /// PBKDF2 salt.
+    pub salt: [u8; 32],
+
+    /// Key material sector number, relative to the start of the LUKS header.
+    pub key_material_sector_number: u32,
+
+    /// Number of anti-forensic stripes.
+    pub number_of_stripes: u32,
+}
+
+impl QcowLuksKeySlot {
+    /// Creates a new key slot.
+    pub fn new() -> Self {
+        Self {
+            state: 0,
+            number_of_iterations: 0,
+            salt: [0; 32],
+            key_material_sector_number: 0,
+            number_of_stripes: 0,
+        }
+    }
+
+    /// Determines if the key slot is active.
+    pub fn is_active(&self) -> bool {
+        self.state == 0x00ac71f3
+    }
+
+    /// Reads the key slot from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 48 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported LUKS key slot data size"
+            ));
+        }
+        self.state = bytes_to_u32_be!(data, 0);
+        self.number_of_iterations = bytes_to_u32_be!(data, 4);
+        self.salt.copy_from_slice(&data[8..40]);
+        self.key_material_sector_number = bytes_to_u32_be!(data, 40);
+        self.number_of_stripes = bytes_to_u32_be!(data, 44);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0xac, 0x71, 0xf3, 0x00, 0x00, 0x03, 0xe8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+            0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
+            0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x00, 0x00,
+            0x00, 0x08, 0x00, 0x00, 0x0f, 0xa0,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowLuksKeySlot::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.state, 0x00ac71f3);
+        assert!(test_struct.is_active());
+        assert_eq!(test_struct.number_of_iterations, 1000);
+        assert_eq!(test_struct.salt, test_data[8..40]);
+        assert_eq!(test_struct.key_material_sector_number, 8);
+        assert_eq!(test_struct.number_of_stripes, 4000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowLuksKeySlot::new();
+        let result = test_struct.read_data(&test_data[0..47]);
+        assert!(result.is_err());
+    }
+}
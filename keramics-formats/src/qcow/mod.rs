@@ -14,6 +14,7 @@
 mod block_range;
 mod cluster_table;
 mod constants;
+mod encryption_context;
 mod enums;
 mod file;
 mod file_header;
@@ -21,7 +22,12 @@ mod file_header_v1;
 mod file_header_v2;
 mod file_header_v3;
 mod image;
+mod luks_header;
+mod luks_key_slot;
+mod snapshot;
+mod snapshot_header;
 
 pub use enums::{QcowCompressionMethod, QcowEncryptionMethod};
 pub use file::QcowFile;
 pub use image::{QcowImage, QcowImageLayer};
+pub use snapshot::QcowSnapshot;
@@ -0,0 +1,246 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_datetime::PosixTime64Ns;
+use keramics_types::{ByteString, bytes_to_u16_be, bytes_to_u32_be, bytes_to_u64_be};
+
+use super::snapshot_header::QcowSnapshotHeader;
+
+/// QEMU Copy-On-Write (QCOW) internal snapshot.
+#[derive(Clone)]
+pub struct QcowSnapshot {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Identifier.
+    pub identifier: ByteString,
+
+    /// Name.
+    pub name: ByteString,
+
+    /// Date and time the snapshot was created.
+    pub date: PosixTime64Ns,
+
+    /// Virtual machine clock in nanoseconds.
+    pub vm_clock: u64,
+
+    /// Virtual machine state size.
+    pub vm_state_size: u64,
+
+    /// Media size, where 0 represents the media size of the image.
+    pub media_size: u64,
+
+    /// Level 1 table offset.
+    pub(super) level1_table_offset: u64,
+
+    /// Level 1 table number of references.
+    pub(super) level1_table_number_of_references: u32,
+}
+
+impl QcowSnapshot {
+    /// Creates a new snapshot.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            identifier: ByteString::new(),
+            name: ByteString::new(),
+            date: PosixTime64Ns::new(0, 0),
+            vm_clock: 0,
+            vm_state_size: 0,
+            media_size: 0,
+            level1_table_offset: 0,
+            level1_table_number_of_references: 0,
+        }
+    }
+
+    /// Reads the snapshot from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<usize, ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 40 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported QCOW snapshot data size"
+            ));
+        }
+        let mut snapshot_header: QcowSnapshotHeader = QcowSnapshotHeader::new();
+
+        if self.mediator.debug_output {
+            self.mediator
+                .debug_print(QcowSnapshotHeader::debug_read_data(&data[0..40]));
+        }
+        match snapshot_header.read_data(&data[0..40]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read snapshot header");
+                return Err(error);
+            }
+        }
+        let extra_data_size: usize = snapshot_header.extra_data_size as usize;
+        let identifier_size: usize = snapshot_header.identifier_size as usize;
+        let name_size: usize = snapshot_header.name_size as usize;
+
+        let entry_size: usize = 40 + extra_data_size + identifier_size + name_size;
+
+        if entry_size > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid snapshot entry size: {} value out of bounds",
+                entry_size
+            )));
+        }
+        self.level1_table_offset = snapshot_header.level1_table_offset;
+        self.level1_table_number_of_references = snapshot_header.level1_table_number_of_references;
+        self.date = PosixTime64Ns::new(
+            snapshot_header.date_seconds as i64,
+            snapshot_header.date_nanoseconds,
+        );
+        self.vm_clock = snapshot_header.vm_clock;
+        self.vm_state_size = snapshot_header.vm_state_size as u64;
+
+        // The extra data contains a 64-bit virtual machine state size and the media size
+        // in later versions of the format.
+        if extra_data_size >= 8 {
+            self.vm_state_size = bytes_to_u64_be!(data, 40);
+        }
+        if extra_data_size >= 16 {
+            self.media_size = bytes_to_u64_be!(data, 48);
+        }
+        let mut data_offset: usize = 40 + extra_data_size;
+        let data_end_offset: usize = data_offset + identifier_size;
+
+        self.identifier = ByteString::from(&data[data_offset..data_end_offset]);
+        data_offset = data_end_offset;
+
+        let data_end_offset: usize = data_offset + name_size;
+
+        self.name = ByteString::from(&data[data_offset..data_end_offset]);
+
+        // Snapshot table entries are aligned to 8 bytes.
+        Ok(entry_size.div_ceil(8) * 8)
+    }
+
+    /// Reads the snapshot from a specific position in a data stream.
+    pub fn read_at_position(
+        &mut self,
+        data_stream: &DataStreamReference,
+        position: SeekFrom,
+    ) -> Result<u64, ErrorTrace> {
+        let mut header_data: [u8; 40] = [0; 40];
+
+        let offset: u64 = keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut header_data,
+            position
+        );
+        let extra_data_size: u32 = bytes_to_u32_be!(header_data, 36);
+
+        // Note that QEMU limits the size of the extra data to 1024 bytes.
+        if extra_data_size > 1024 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported snapshot extra data size: {}",
+                extra_data_size
+            )));
+        }
+        let identifier_size: u16 = bytes_to_u16_be!(header_data, 12);
+        let name_size: u16 = bytes_to_u16_be!(header_data, 14);
+
+        let data_size: usize =
+            40 + extra_data_size as usize + identifier_size as usize + name_size as usize;
+        let mut data: Vec<u8> = vec![0; data_size];
+
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            SeekFrom::Start(offset)
+        );
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "QcowSnapshot data of size: {} at offset: {} (0x{:08x})\n",
+                data.len(),
+                offset,
+                offset
+            ));
+            self.mediator.debug_print_data(&data, true);
+        }
+        let entry_size: usize = self.read_data(&data)?;
+
+        Ok(offset + entry_size as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01,
+            0x00, 0x09, 0x69, 0x05, 0xf6, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x31, 0x73, 0x6e, 0x61, 0x70, 0x73,
+            0x68, 0x6f, 0x74, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowSnapshot::new();
+        let entry_size: usize = test_struct.read_data(&test_data)?;
+        assert_eq!(entry_size, 80);
+
+        assert_eq!(test_struct.identifier, "1");
+        assert_eq!(test_struct.name, "snapshot1");
+        assert_eq!(test_struct.date, PosixTime64Ns::new(1761998400, 0));
+        assert_eq!(test_struct.vm_clock, 0);
+        assert_eq!(test_struct.vm_state_size, 0);
+        assert_eq!(test_struct.media_size, 4194304);
+        assert_eq!(test_struct.level1_table_offset, 786432);
+        assert_eq!(test_struct.level1_table_number_of_references, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowSnapshot::new();
+        let result = test_struct.read_data(&test_data[0..39]);
+        assert!(result.is_err());
+
+        let result = test_struct.read_data(&test_data[0..70]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_at_position() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut test_struct = QcowSnapshot::new();
+        let next_offset: u64 = test_struct.read_at_position(&data_stream, SeekFrom::Start(0))?;
+        assert_eq!(next_offset, 80);
+
+        assert_eq!(test_struct.name, "snapshot1");
+
+        Ok(())
+    }
+}
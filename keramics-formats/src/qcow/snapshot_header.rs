@@ -0,0 +1,141 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u16_be, bytes_to_u32_be, bytes_to_u64_be};
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "big",
+        field(name = "level1_table_offset", data_type = "u64"),
+        field(name = "level1_table_number_of_references", data_type = "u32"),
+        field(name = "identifier_size", data_type = "u16"),
+        field(name = "name_size", data_type = "u16"),
+        field(name = "date_seconds", data_type = "u32"),
+        field(name = "date_nanoseconds", data_type = "u32"),
+        field(name = "vm_clock", data_type = "u64"),
+        field(name = "vm_state_size", data_type = "u32"),
+        field(name = "extra_data_size", data_type = "u32"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// QEMU Copy-On-Write (QCOW) snapshot header.
+pub struct QcowSnapshotHeader {
+    /// Level 1 table offset.
+    pub level1_table_offset: u64,
+
+    /// Level 1 table number of references.
+    pub level1_table_number_of_references: u32,
+
+    /// Identifier size.
+    pub identifier_size: u16,
+
+    /// Name size.
+    pub name_size: u16,
+
+    /// Date seconds.
+    pub date_seconds: u32,
+
+    /// Date nanoseconds.
+    pub date_nanoseconds: u32,
+
+    /// Virtual machine clock in nanoseconds.
+    pub vm_clock: u64,
+
+    /// Virtual machine state size.
+    pub vm_state_size: u32,
+
+    /// Extra data size.
+    pub extra_data_size: u32,
+}
+
+impl QcowSnapshotHeader {
+    /// Creates a new snapshot header.
+    pub fn new() -> Self {
+        Self {
+            level1_table_offset: 0,
+            level1_table_number_of_references: 0,
+            identifier_size: 0,
+            name_size: 0,
+            date_seconds: 0,
+            date_nanoseconds: 0,
+            vm_clock: 0,
+            vm_state_size: 0,
+            extra_data_size: 0,
+        }
+    }
+
+    /// Reads the snapshot header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 40 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported QCOW snapshot header data size"
+            ));
+        }
+        self.level1_table_offset = bytes_to_u64_be!(data, 0);
+        self.level1_table_number_of_references = bytes_to_u32_be!(data, 8);
+        self.identifier_size = bytes_to_u16_be!(data, 12);
+        self.name_size = bytes_to_u16_be!(data, 14);
+        self.date_seconds = bytes_to_u32_be!(data, 16);
+        self.date_nanoseconds = bytes_to_u32_be!(data, 20);
+        self.vm_clock = bytes_to_u64_be!(data, 24);
+        self.vm_state_size = bytes_to_u32_be!(data, 32);
+        self.extra_data_size = bytes_to_u32_be!(data, 36);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01,
+            0x00, 0x09, 0x69, 0x05, 0xf6, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowSnapshotHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.level1_table_offset, 786432);
+        assert_eq!(test_struct.level1_table_number_of_references, 1);
+        assert_eq!(test_struct.identifier_size, 1);
+        assert_eq!(test_struct.name_size, 9);
+        assert_eq!(test_struct.date_seconds, 1761998400);
+        assert_eq!(test_struct.date_nanoseconds, 0);
+        assert_eq!(test_struct.vm_clock, 0);
+        assert_eq!(test_struct.vm_state_size, 0);
+        assert_eq!(test_struct.extra_data_size, 24);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = QcowSnapshotHeader::new();
+        let result = test_struct.read_data(&test_data[0..39]);
+        assert!(result.is_err());
+    }
+}
@@ -129,6 +129,17 @@ impl FormatScanner {
         ));
     }
 
+    /// Adds gzip signatures.
+    pub fn add_gzip_signatures(&mut self) {
+        // Signature and deflate compression method in member header.
+        self.signature_scanner.add_signature(Signature::new(
+            "gzip1",
+            PatternType::BoundToStart,
+            0,
+            &[0x1f, 0x8b, 0x08],
+        ));
+    }
+
     /// Adds Master Boot Record (MBR) signatures.
     pub fn add_mbr_signatures(&mut self) {
         // Signature for 512 bytes per sector.
@@ -241,6 +252,20 @@ impl FormatScanner {
         ));
     }
 
+    /// Adds Volume Shadow Snapshots (VSS) signatures.
+    pub fn add_vss_signatures(&mut self) {
+        // Signature in volume header.
+        self.signature_scanner.add_signature(Signature::new(
+            "vss1",
+            PatternType::BoundToStart,
+            7680,
+            &[
+                0x6b, 0x87, 0x08, 0x38, 0x76, 0xc1, 0x48, 0x4e, 0xb7, 0xae, 0x04, 0x04, 0x6e, 0x6c,
+                0xc7, 0x52,
+            ],
+        ));
+    }
+
     /// Builds the format signature scanner.
     pub fn build(&mut self) -> Result<(), BuildError> {
         self.signature_scanner.build()
@@ -291,6 +316,7 @@ impl FormatScanner {
                 "ewf1" => FormatIdentifier::Ewf,
                 "fat1" | "fat2" | "fat3" => FormatIdentifier::Fat,
                 "gpt1" | "gpt2" | "gpt3" | "gpt4" => FormatIdentifier::Gpt,
+                "gzip1" => FormatIdentifier::Gzip,
                 "mbr1" | "mbr2" | "mbr3" | "mbr4" => FormatIdentifier::Mbr,
                 "ntfs1" => FormatIdentifier::Ntfs,
                 "qcow1" | "qcow2" | "qcow3" => FormatIdentifier::Qcow,
@@ -298,6 +324,7 @@ impl FormatScanner {
                 "udif1" => FormatIdentifier::Udif,
                 "vhd1" => FormatIdentifier::Vhd,
                 "vhdx1" => FormatIdentifier::Vhdx,
+                "vss1" => FormatIdentifier::Vss,
                 _ => FormatIdentifier::Unknown,
             };
             scan_results.insert(format_identifier);
@@ -312,7 +339,7 @@ mod tests {
 
     use std::path::PathBuf;
 
-    use keramics_core::open_os_data_stream;
+    use keramics_core::{open_fake_data_stream, open_os_data_stream};
 
     use crate::tests::get_test_data_path;
 
@@ -324,12 +351,14 @@ mod tests {
         format_scanner.add_ewf_signatures();
         format_scanner.add_fat_signatures();
         format_scanner.add_gpt_signatures();
+        format_scanner.add_gzip_signatures();
         format_scanner.add_ntfs_signatures();
         format_scanner.add_qcow_signatures();
         format_scanner.add_sparseimage_signatures();
         format_scanner.add_udif_signatures();
         format_scanner.add_vhd_signatures();
         format_scanner.add_vhdx_signatures();
+        format_scanner.add_vss_signatures();
 
         format_scanner.build()
     }
@@ -342,6 +371,7 @@ mod tests {
         format_scanner.add_ewf_signatures();
         format_scanner.add_fat_signatures();
         format_scanner.add_gpt_signatures();
+        format_scanner.add_gzip_signatures();
         format_scanner.add_ntfs_signatures();
         format_scanner.add_qcow_signatures();
         format_scanner.add_sparseimage_signatures();
@@ -368,4 +398,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_scan_data_stream_with_vss() -> Result<(), ErrorTrace> {
+        let mut format_scanner: FormatScanner = FormatScanner::new();
+        format_scanner.add_vss_signatures();
+
+        match format_scanner.build() {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to build format scanner",
+                    error
+                ));
+            }
+        };
+        let mut test_data: Vec<u8> = vec![0; 16384];
+        test_data[7680..7696].copy_from_slice(&[
+            0x6b, 0x87, 0x08, 0x38, 0x76, 0xc1, 0x48, 0x4e, 0xb7, 0xae, 0x04, 0x04, 0x6e, 0x6c,
+            0xc7, 0x52,
+        ]);
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+        let scan_results: HashSet<FormatIdentifier> =
+            format_scanner.scan_data_stream(&data_stream)?;
+
+        assert_eq!(scan_results.len(), 1);
+        assert!(scan_results.iter().next() == Some(&FormatIdentifier::Vss));
+
+        Ok(())
+    }
 }
@@ -13,7 +13,7 @@
 
 use std::io::SeekFrom;
 
-use keramics_compression::{AdcContext, Bzip2Context, LzfseContext, ZlibContext};
+use keramics_compression::{CompressionMethod, Decompressor, new_decompressor};
 use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
 
@@ -270,7 +270,7 @@ impl UdifFile {
                             0,
                             UdifBlockRangeType::InFile,
                         ),
-                        0x80000004..0x80000008 => {
+                        0x80000004..=0x80000008 => {
                             if block_table_entry.number_of_sectors > 2048 {
                                 return Err(keramics_core::error_trace_new!(format!(
                                     "Unsupported compressed block table: {} entry: {} number of sectors value out of bounds",
@@ -462,73 +462,31 @@ impl UdifFile {
             ));
             self.mediator.debug_print_data(&compressed_data, true);
         }
-        match self.compression_method {
-            UdifCompressionMethod::Adc => {
-                let mut adc_context: AdcContext = AdcContext::new();
-
-                match adc_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress ADC data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
-            UdifCompressionMethod::Bzip2 => {
-                let mut bzip2_context: Bzip2Context = Bzip2Context::new();
-
-                match bzip2_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress bzip2 data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
-            UdifCompressionMethod::Lzfse => {
-                let mut lzfse_context: LzfseContext = LzfseContext::new();
-
-                match lzfse_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress LZFSE data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
-            UdifCompressionMethod::Lzma => {
-                // TODO: add support for UdifCompressionMethod::Lzma,
-                todo!();
-            }
-            UdifCompressionMethod::Zlib => {
-                let mut zlib_context: ZlibContext = ZlibContext::new();
-
-                match zlib_context.decompress(&compressed_data, data) {
-                    Ok(_) => {}
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to decompress zlib data"
-                        );
-                        return Err(error);
-                    }
-                }
-            }
+        let compression_method: CompressionMethod = match self.compression_method {
+            UdifCompressionMethod::Adc => CompressionMethod::Adc,
+            UdifCompressionMethod::Bzip2 => CompressionMethod::Bzip2,
+            UdifCompressionMethod::Lzfse => CompressionMethod::Lzfse,
+            // LZMA compressed block ranges are stored in the XZ container format.
+            UdifCompressionMethod::Lzma => CompressionMethod::Xz,
+            UdifCompressionMethod::Zlib => CompressionMethod::Zlib,
             _ => {
                 return Err(keramics_core::error_trace_new!(
                     "Unsupported compression method"
                 ));
             }
         };
+        let mut decompressor: Box<dyn Decompressor> = new_decompressor(&compression_method);
+
+        match decompressor.decompress(&compressed_data, data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to decompress {:?} data", compression_method)
+                );
+                return Err(error);
+            }
+        }
         Ok(())
     }
 }
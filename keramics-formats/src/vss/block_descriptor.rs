@@ -0,0 +1,124 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "original_offset", data_type = "u64"),
+        field(name = "relative_store_data_offset", data_type = "u64"),
+        field(name = "store_data_offset", data_type = "u64"),
+        field(name = "flags", data_type = "u32", format = "hex"),
+        field(name = "allocation_bitmap", data_type = "u32", format = "hex"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Volume Shadow Snapshots (VSS) block descriptor.
+pub struct VssBlockDescriptor {
+    /// Original offset.
+    pub original_offset: u64,
+
+    /// Relative store data offset.
+    pub relative_store_data_offset: u64,
+
+    /// Store data offset.
+    pub store_data_offset: u64,
+
+    /// Flags.
+    pub flags: u32,
+
+    /// Allocation bitmap, where every bit represents a 512-byte sector of an overlay.
+    pub allocation_bitmap: u32,
+}
+
+impl VssBlockDescriptor {
+    /// Creates a new block descriptor.
+    pub fn new() -> Self {
+        Self {
+            original_offset: 0,
+            relative_store_data_offset: 0,
+            store_data_offset: 0,
+            flags: 0,
+            allocation_bitmap: 0,
+        }
+    }
+
+    /// Determines if the block descriptor is empty.
+    pub fn is_empty(&self) -> bool {
+        self.original_offset == 0
+            && self.relative_store_data_offset == 0
+            && self.store_data_offset == 0
+            && self.flags == 0
+    }
+
+    /// Reads the block descriptor from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != VSS_BLOCK_DESCRIPTOR_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS block descriptor data size"
+            ));
+        }
+        self.original_offset = bytes_to_u64_le!(data, 0);
+        self.relative_store_data_offset = bytes_to_u64_le!(data, 8);
+        self.store_data_offset = bytes_to_u64_le!(data, 16);
+        self.flags = bytes_to_u32_le!(data, 24);
+        self.allocation_bitmap = bytes_to_u32_le!(data, 28);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        return vec![
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+            0x03, 0x00, 0x00, 0x00,
+        ];
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = VssBlockDescriptor::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.original_offset, 0x10000);
+        assert_eq!(test_struct.relative_store_data_offset, 0);
+        assert_eq!(test_struct.store_data_offset, 0x14000);
+        assert_eq!(test_struct.flags, VSS_BLOCK_FLAG_IS_OVERLAY);
+        assert_eq!(test_struct.allocation_bitmap, 0x00000003);
+        assert_eq!(test_struct.is_empty(), false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = VssBlockDescriptor::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..31]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "identifier", data_type = "uuid"),
+        field(name = "format_version", data_type = "u32"),
+        field(name = "record_type", data_type = "u32"),
+        field(name = "relative_offset", data_type = "u64"),
+        field(name = "current_offset", data_type = "u64"),
+        field(name = "next_offset", data_type = "u64"),
+        field(name = "unknown1", data_type = "[u8; 80]"),
+    ),
+    method(name = "debug_read_data")
+)]
+/// Volume Shadow Snapshots (VSS) block header.
+pub struct VssBlockHeader {
+    /// Record type.
+    pub record_type: u32,
+
+    /// Relative offset.
+    pub relative_offset: u64,
+
+    /// Current offset.
+    pub current_offset: u64,
+
+    /// Next offset.
+    pub next_offset: u64,
+}
+
+impl VssBlockHeader {
+    /// Creates a new block header.
+    pub fn new() -> Self {
+        Self {
+            record_type: 0,
+            relative_offset: 0,
+            current_offset: 0,
+            next_offset: 0,
+        }
+    }
+
+    /// Reads the block header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() < VSS_BLOCK_HEADER_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS block header data size"
+            ));
+        }
+        if data[0..16] != VSS_IDENTIFIER {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS block header identifier"
+            ));
+        }
+        let format_version: u32 = bytes_to_u32_le!(data, 16);
+
+        if format_version != 1 && format_version != 2 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported format version: {}",
+                format_version
+            )));
+        }
+        self.record_type = bytes_to_u32_le!(data, 20);
+        self.relative_offset = bytes_to_u64_le!(data, 24);
+        self.current_offset = bytes_to_u64_le!(data, 32);
+        self.next_offset = bytes_to_u64_le!(data, 40);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 128];
+        data[0..16].copy_from_slice(&VSS_IDENTIFIER);
+        data[16] = 0x01;
+        data[20] = 0x02;
+        data[33] = 0x40;
+        data[42] = 0x01;
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = VssBlockHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.record_type, 2);
+        assert_eq!(test_struct.relative_offset, 0);
+        assert_eq!(test_struct.current_offset, 0x4000);
+        assert_eq!(test_struct.next_offset, 0x10000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = VssBlockHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..127]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_identifier() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = VssBlockHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_format_version() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[16] = 0xff;
+
+        let mut test_struct = VssBlockHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,166 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{Uuid, bytes_to_u64_le};
+
+use super::constants::*;
+
+/// Volume Shadow Snapshots (VSS) catalog entry.
+pub struct VssCatalogEntry {
+    /// Entry type.
+    pub entry_type: u64,
+
+    /// Volume size, used by entry type 2.
+    pub volume_size: u64,
+
+    /// Store identifier, used by entry types 2 and 3.
+    pub store_identifier: Uuid,
+
+    /// Creation time, used by entry type 2.
+    pub creation_time: u64,
+
+    /// Store block list offset, used by entry type 3.
+    pub store_block_list_offset: u64,
+
+    /// Store header offset, used by entry type 3.
+    pub store_header_offset: u64,
+
+    /// Store block range list offset, used by entry type 3.
+    pub store_block_range_list_offset: u64,
+
+    /// Store current bitmap offset, used by entry type 3.
+    pub store_bitmap_offset: u64,
+}
+
+impl VssCatalogEntry {
+    /// Creates a new catalog entry.
+    pub fn new() -> Self {
+        Self {
+            entry_type: 0,
+            volume_size: 0,
+            store_identifier: Uuid::new(),
+            creation_time: 0,
+            store_block_list_offset: 0,
+            store_header_offset: 0,
+            store_block_range_list_offset: 0,
+            store_bitmap_offset: 0,
+        }
+    }
+
+    /// Reads the catalog entry from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != VSS_CATALOG_ENTRY_SIZE {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS catalog entry data size"
+            ));
+        }
+        self.entry_type = bytes_to_u64_le!(data, 0);
+
+        match self.entry_type {
+            0 | 1 => {}
+            2 => {
+                self.volume_size = bytes_to_u64_le!(data, 8);
+                self.store_identifier = Uuid::from_le_bytes(&data[16..32]);
+                self.creation_time = bytes_to_u64_le!(data, 48);
+            }
+            3 => {
+                self.store_block_list_offset = bytes_to_u64_le!(data, 8);
+                self.store_identifier = Uuid::from_le_bytes(&data[16..32]);
+                self.store_header_offset = bytes_to_u64_le!(data, 32);
+                self.store_block_range_list_offset = bytes_to_u64_le!(data, 40);
+                self.store_bitmap_offset = bytes_to_u64_le!(data, 48);
+            }
+            _ => {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported catalog entry type: {}",
+                    self.entry_type
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 128];
+        data[0] = 0x02;
+        data[10] = 0x02;
+        data[16..32].copy_from_slice(&[
+            0x72, 0xe4, 0x8f, 0x9d, 0x83, 0x8e, 0xe1, 0x11, 0x9c, 0x9f, 0x00, 0x0c, 0x29, 0x2f,
+            0x6a, 0x3b,
+        ]);
+        data[48..56].copy_from_slice(&[0x80, 0x3e, 0xd5, 0xde, 0xb1, 0x9d, 0x01, 0x00]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = VssCatalogEntry::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.entry_type, 2);
+        assert_eq!(test_struct.volume_size, 0x20000);
+        assert_eq!(
+            test_struct.store_identifier.to_string(),
+            "9d8fe472-8e83-11e1-9c9f-000c292f6a3b"
+        );
+        assert_eq!(test_struct.creation_time, 0x019db1ded53e80);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_store_entry() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0x03;
+        test_data[8..16].copy_from_slice(&[0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        test_data[32..40].copy_from_slice(&[0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        test_data[48..56].copy_from_slice(&[0x00; 8]);
+
+        let mut test_struct = VssCatalogEntry::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.entry_type, 3);
+        assert_eq!(test_struct.store_block_list_offset, 0x8000);
+        assert_eq!(test_struct.store_header_offset, 0xc000);
+        assert_eq!(test_struct.store_bitmap_offset, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = VssCatalogEntry::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..127]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_entry_type() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = VssCatalogEntry::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
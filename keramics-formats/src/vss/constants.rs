@@ -0,0 +1,57 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// VSS identifier: {3808876b-c176-4e48-b7ae-04046e6cc752}
+pub(super) const VSS_IDENTIFIER: [u8; 16] = [
+    0x6b, 0x87, 0x08, 0x38, 0x76, 0xc1, 0x48, 0x4e, 0xb7, 0xae, 0x04, 0x04, 0x6e, 0x6c, 0xc7, 0x52,
+];
+
+/// VSS volume header offset.
+pub(super) const VSS_VOLUME_HEADER_OFFSET: u64 = 0x1e00;
+
+/// VSS block size.
+pub(super) const VSS_BLOCK_SIZE: u64 = 0x4000;
+
+/// VSS block header size.
+pub(super) const VSS_BLOCK_HEADER_SIZE: usize = 128;
+
+/// VSS record type of a volume header.
+pub(super) const VSS_RECORD_TYPE_VOLUME_HEADER: u32 = 1;
+
+/// VSS record type of a catalog block.
+pub(super) const VSS_RECORD_TYPE_CATALOG_BLOCK: u32 = 2;
+
+/// VSS record type of a store block list block.
+pub(super) const VSS_RECORD_TYPE_BLOCK_LIST_BLOCK: u32 = 3;
+
+/// VSS record type of a store header block.
+pub(super) const VSS_RECORD_TYPE_STORE_HEADER_BLOCK: u32 = 4;
+
+/// VSS record type of a store bitmap block.
+pub(super) const VSS_RECORD_TYPE_STORE_BITMAP_BLOCK: u32 = 6;
+
+/// VSS catalog entry size.
+pub(super) const VSS_CATALOG_ENTRY_SIZE: usize = 128;
+
+/// VSS block descriptor size.
+pub(super) const VSS_BLOCK_DESCRIPTOR_SIZE: usize = 32;
+
+/// VSS block descriptor flag to indicate the data is stored at the original offset of another
+/// block.
+pub(super) const VSS_BLOCK_FLAG_IS_FORWARDER: u32 = 0x00000001;
+
+/// VSS block descriptor flag to indicate the data only overlays part of the block.
+pub(super) const VSS_BLOCK_FLAG_IS_OVERLAY: u32 = 0x00000002;
+
+/// VSS block descriptor flag to indicate the block descriptor is not used.
+pub(super) const VSS_BLOCK_FLAG_NOT_USED: u32 = 0x00000004;
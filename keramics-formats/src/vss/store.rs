@@ -0,0 +1,547 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace};
+use keramics_datetime::{DateTime, Filetime};
+use keramics_types::Uuid;
+
+use super::constants::*;
+use super::store_descriptor::VssStoreDescriptor;
+
+/// Volume Shadow Snapshots (VSS) store.
+///
+/// The store provides a read-only view of the volume at the time the shadow copy was created.
+/// The data of a block is read from the first store, starting with the store itself and followed
+/// by the more recent stores, that contains the original data of the block. If none of the stores
+/// contain the block, the data is read from the current volume.
+///
+/// Overlays of a store, which contain partial block data, are applied on top of the data of the
+/// block as resolved by the store and the more recent stores. Blocks that are marked as not in use
+/// by the store bitmap and that are not contained in any of the stores are read as zeros.
+pub struct VssStore {
+    /// The data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// The current offset.
+    current_offset: u64,
+
+    /// Store descriptors, starting with the descriptor of the store followed by more recent ones.
+    store_descriptors: Vec<Arc<VssStoreDescriptor>>,
+
+    /// The index of the store.
+    pub store_index: usize,
+
+    /// The store identifier.
+    pub identifier: Uuid,
+
+    /// The shadow copy identifier.
+    pub copy_identifier: Uuid,
+
+    /// The shadow copy set identifier.
+    pub copy_set_identifier: Uuid,
+
+    /// The creation time.
+    pub creation_time: DateTime,
+
+    /// The size of the store (volume).
+    pub size: u64,
+}
+
+impl VssStore {
+    /// Creates a new store.
+    pub(super) fn new(store_index: usize, store_descriptors: &[Arc<VssStoreDescriptor>]) -> Self {
+        let store_descriptors: Vec<Arc<VssStoreDescriptor>> =
+            store_descriptors[store_index..].to_vec();
+
+        let store_descriptor: &VssStoreDescriptor = &store_descriptors[0];
+
+        let creation_time: DateTime = if store_descriptor.creation_time == 0 {
+            DateTime::NotSet
+        } else {
+            DateTime::Filetime(Filetime::new(store_descriptor.creation_time))
+        };
+        Self {
+            data_stream: None,
+            current_offset: 0,
+            identifier: store_descriptor.identifier.clone(),
+            copy_identifier: store_descriptor.information.copy_identifier.clone(),
+            copy_set_identifier: store_descriptor.information.copy_set_identifier.clone(),
+            creation_time: creation_time,
+            size: store_descriptor.volume_size,
+            store_index: store_index,
+            store_descriptors: store_descriptors,
+        }
+    }
+
+    /// Opens a store.
+    pub(super) fn open(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        self.data_stream = Some(data_stream.clone());
+
+        Ok(())
+    }
+
+    /// Reads the data of a block.
+    fn read_block(
+        &self,
+        data_stream: &DataStreamReference,
+        block_offset: u64,
+        data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        data.fill(0);
+
+        match self.read_block_from_store(data_stream, 0, block_offset, data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to read block from store: {}", self.store_index)
+                );
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the data of a block from a specific store or the current volume.
+    ///
+    /// The store index is relative to the store, where 0 represents the store itself.
+    fn read_block_from_store(
+        &self,
+        data_stream: &DataStreamReference,
+        store_index: usize,
+        original_offset: u64,
+        data: &mut [u8],
+    ) -> Result<(), ErrorTrace> {
+        let store_descriptor: &VssStoreDescriptor = match self.store_descriptors.get(store_index) {
+            Some(store_descriptor) => store_descriptor,
+            None => {
+                // Blocks that were not in use when the shadow copy was created are not
+                // preserved, hence their data in the current volume is not read.
+                if !self.store_descriptors[0].is_unused_block(original_offset) {
+                    keramics_core::data_stream_read_at_position!(
+                        data_stream,
+                        data,
+                        SeekFrom::Start(original_offset)
+                    );
+                }
+                return Ok(());
+            }
+        };
+        match store_descriptor.block_descriptors.get(&original_offset) {
+            Some(block_descriptor) => {
+                if block_descriptor.flags & VSS_BLOCK_FLAG_IS_FORWARDER != 0 {
+                    // The data is stored at another original offset in a more recent store
+                    // or in the current volume.
+                    match self.read_block_from_store(
+                        data_stream,
+                        store_index + 1,
+                        block_descriptor.relative_store_data_offset,
+                        data,
+                    ) {
+                        Ok(_) => {}
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to read forwarded block"
+                            );
+                            return Err(error);
+                        }
+                    }
+                } else {
+                    keramics_core::data_stream_read_at_position!(
+                        data_stream,
+                        data,
+                        SeekFrom::Start(block_descriptor.store_data_offset)
+                    );
+                }
+            }
+            None => {
+                match self.read_block_from_store(
+                    data_stream,
+                    store_index + 1,
+                    original_offset,
+                    data,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read block from more recent store"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+        }
+        // The overlays of a store apply to the data of the block as seen by that store,
+        // including data that was resolved from more recent stores.
+        match store_descriptor
+            .overlay_block_descriptors
+            .get(&original_offset)
+        {
+            Some(overlay_block_descriptors) => {
+                for block_descriptor in overlay_block_descriptors.iter() {
+                    for sector_index in 0..32 {
+                        if block_descriptor.allocation_bitmap & (1 << sector_index) == 0 {
+                            continue;
+                        }
+                        let data_offset: usize = sector_index * 512;
+                        let data_end_offset: usize = data_offset + 512;
+
+                        keramics_core::data_stream_read_exact_at_position!(
+                            data_stream,
+                            &mut data[data_offset..data_end_offset],
+                            SeekFrom::Start(
+                                block_descriptor.store_data_offset + data_offset as u64
+                            )
+                        );
+                    }
+                }
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+impl DataStream for VssStore {
+    /// Retrieves the size of the data.
+    fn get_size(&mut self) -> Result<u64, ErrorTrace> {
+        Ok(self.size)
+    }
+
+    /// Reads data at the current position.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, ErrorTrace> {
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        if self.current_offset >= self.size {
+            return Ok(0);
+        }
+        let remaining_size: u64 = self.size - self.current_offset;
+        let mut read_size: usize = buf.len();
+
+        if (read_size as u64) > remaining_size {
+            read_size = remaining_size as usize;
+        }
+        let mut block_data: Vec<u8> = vec![0; VSS_BLOCK_SIZE as usize];
+        let mut data_offset: usize = 0;
+
+        while data_offset < read_size {
+            let range_offset: u64 = self.current_offset % VSS_BLOCK_SIZE;
+            let block_offset: u64 = self.current_offset - range_offset;
+
+            let mut range_size: usize = (VSS_BLOCK_SIZE - range_offset) as usize;
+
+            if range_size > read_size - data_offset {
+                range_size = read_size - data_offset;
+            }
+            match self.read_block(data_stream, block_offset, &mut block_data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!(
+                            "Unable to read block at offset: {} (0x{:08x})",
+                            block_offset, block_offset
+                        )
+                    );
+                    return Err(error);
+                }
+            }
+            let range_start: usize = range_offset as usize;
+            let data_end_offset: usize = data_offset + range_size;
+
+            buf[data_offset..data_end_offset]
+                .copy_from_slice(&block_data[range_start..range_start + range_size]);
+
+            data_offset = data_end_offset;
+            self.current_offset += range_size as u64;
+        }
+        Ok(read_size)
+    }
+
+    /// Sets the current position of the data.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ErrorTrace> {
+        self.current_offset = match pos {
+            SeekFrom::Current(relative_offset) => {
+                let mut current_offset: i64 = self.current_offset as i64;
+                current_offset += relative_offset;
+                current_offset as u64
+            }
+            SeekFrom::End(relative_offset) => {
+                let mut end_offset: i64 = self.size as i64;
+                end_offset += relative_offset;
+                end_offset as u64
+            }
+            SeekFrom::Start(offset) => offset,
+        };
+        Ok(self.current_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    use super::super::block_descriptor::VssBlockDescriptor;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 0x20000];
+
+        // Current volume data.
+        data[0x08000..0x0c000].fill(0xaa);
+        data[0x0c000..0x10000].fill(0xbb);
+        data[0x10000..0x14000].fill(0xcc);
+
+        // Store data.
+        data[0x14000..0x18000].fill(0x11);
+        data[0x18000..0x1c000].fill(0x22);
+        data[0x1c000..0x20000].fill(0x33);
+
+        data
+    }
+
+    fn new_block_descriptor(
+        original_offset: u64,
+        relative_store_data_offset: u64,
+        store_data_offset: u64,
+        flags: u32,
+        allocation_bitmap: u32,
+    ) -> VssBlockDescriptor {
+        let mut block_descriptor: VssBlockDescriptor = VssBlockDescriptor::new();
+        block_descriptor.original_offset = original_offset;
+        block_descriptor.relative_store_data_offset = relative_store_data_offset;
+        block_descriptor.store_data_offset = store_data_offset;
+        block_descriptor.flags = flags;
+        block_descriptor.allocation_bitmap = allocation_bitmap;
+        block_descriptor
+    }
+
+    fn get_store_descriptors() -> Vec<Arc<VssStoreDescriptor>> {
+        let mut store_descriptor1: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor1.volume_size = 0x14000;
+        store_descriptor1.creation_time = 0x01d0c4a6d0f9b8f0;
+        store_descriptor1
+            .block_descriptors
+            .insert(0x08000, new_block_descriptor(0x08000, 0, 0x14000, 0, 0));
+        store_descriptor1.block_descriptors.insert(
+            0x0c000,
+            new_block_descriptor(0x0c000, 0x10000, 0, VSS_BLOCK_FLAG_IS_FORWARDER, 0),
+        );
+        store_descriptor1.overlay_block_descriptors.insert(
+            0x10000,
+            vec![new_block_descriptor(
+                0x10000,
+                0,
+                0x1c000,
+                VSS_BLOCK_FLAG_IS_OVERLAY,
+                0x00000002,
+            )],
+        );
+        let mut store_descriptor2: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor2.volume_size = 0x14000;
+        store_descriptor2
+            .block_descriptors
+            .insert(0x10000, new_block_descriptor(0x10000, 0, 0x18000, 0, 0));
+        store_descriptor2.overlay_block_descriptors.insert(
+            0x04000,
+            vec![new_block_descriptor(
+                0x04000,
+                0,
+                0x1c000,
+                VSS_BLOCK_FLAG_IS_OVERLAY,
+                0x00000001,
+            )],
+        );
+        store_descriptor2.bitmap = vec![0x08];
+        vec![Arc::new(store_descriptor1), Arc::new(store_descriptor2)]
+    }
+
+    fn get_store(store_index: usize) -> Result<VssStore, ErrorTrace> {
+        let store_descriptors: Vec<Arc<VssStoreDescriptor>> = get_store_descriptors();
+        let mut store: VssStore = VssStore::new(store_index, &store_descriptors);
+
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+        store.open(&data_stream)?;
+
+        Ok(store)
+    }
+
+    #[test]
+    fn test_new() {
+        let store_descriptors: Vec<Arc<VssStoreDescriptor>> = get_store_descriptors();
+        let store: VssStore = VssStore::new(0, &store_descriptors);
+
+        assert_eq!(store.store_index, 0);
+        assert_eq!(store.size, 0x14000);
+        assert_eq!(
+            store.creation_time,
+            DateTime::Filetime(Filetime::new(0x01d0c4a6d0f9b8f0))
+        );
+
+        let store: VssStore = VssStore::new(1, &store_descriptors);
+
+        assert_eq!(store.store_index, 1);
+        assert_eq!(store.creation_time, DateTime::NotSet);
+    }
+
+    #[test]
+    fn test_get_size() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        let size: u64 = store.get_size()?;
+        assert_eq!(size, 0x14000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_start() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        let offset: u64 = store.seek(SeekFrom::Start(1024))?;
+        assert_eq!(offset, 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_end() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        let offset: u64 = store.seek(SeekFrom::End(-512))?;
+        assert_eq!(offset, store.size - 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_from_current() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        let offset = store.seek(SeekFrom::Start(1024))?;
+        assert_eq!(offset, 1024);
+
+        let offset: u64 = store.seek(SeekFrom::Current(-512))?;
+        assert_eq!(offset, 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        // Block stored in the store itself.
+        store.seek(SeekFrom::Start(0x07f00))?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 512);
+        assert_eq!(data[0..256], [0x00; 256]);
+        assert_eq!(data[256..512], [0x11; 256]);
+
+        // Block forwarded to a block stored in a more recent store.
+        store.seek(SeekFrom::Start(0x0c000))?;
+
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 512);
+        assert_eq!(data, [0x22; 512]);
+
+        // Block stored in a more recent store with an overlay of the second sector.
+        store.seek(SeekFrom::Start(0x10000))?;
+
+        let mut data: Vec<u8> = vec![0; 1536];
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 1536);
+        assert_eq!(data[0..512], [0x22; 512]);
+        assert_eq!(data[512..1024], [0x33; 512]);
+        assert_eq!(data[1024..1536], [0x22; 512]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read_with_more_recent_store() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(1)?;
+
+        // Block not stored in a store.
+        store.seek(SeekFrom::Start(0x08000))?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 512);
+        assert_eq!(data, [0xaa; 512]);
+
+        // Block stored in the store itself, overlays of older stores are not applied.
+        store.seek(SeekFrom::Start(0x10200))?;
+
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 512);
+        assert_eq!(data, [0x22; 512]);
+
+        // Block not stored in a store and not in use when the shadow copy was created.
+        store.seek(SeekFrom::Start(0x0c000))?;
+
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 512);
+        assert_eq!(data, [0x00; 512]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read_with_overlay_of_more_recent_store() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+
+        // Block not stored in a store with an overlay of the first sector in a more recent store.
+        store.seek(SeekFrom::Start(0x04000))?;
+
+        let mut data: Vec<u8> = vec![0; 1024];
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 1024);
+        assert_eq!(data[0..512], [0x33; 512]);
+        assert_eq!(data[512..1024], [0x00; 512]);
+
+        // The bitmap of a more recent store is not applied.
+        store.seek(SeekFrom::Start(0x0c000))?;
+
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 1024);
+        assert_eq!(data, [0x22; 1024]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_and_read_beyond_size() -> Result<(), ErrorTrace> {
+        let mut store: VssStore = get_store(0)?;
+        store.seek(SeekFrom::End(512))?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        let read_size: usize = store.read(&mut data)?;
+        assert_eq!(read_size, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::io::SeekFrom;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::Uuid;
+
+use super::block_descriptor::VssBlockDescriptor;
+use super::block_header::VssBlockHeader;
+use super::constants::*;
+use super::store_information::VssStoreInformation;
+
+/// Volume Shadow Snapshots (VSS) store descriptor.
+pub struct VssStoreDescriptor {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Store identifier.
+    pub identifier: Uuid,
+
+    /// Volume size.
+    pub volume_size: u64,
+
+    /// Creation time.
+    pub creation_time: u64,
+
+    /// Store block list offset.
+    pub block_list_offset: u64,
+
+    /// Store header offset.
+    pub header_offset: u64,
+
+    /// Store (current) bitmap offset.
+    pub bitmap_offset: u64,
+
+    /// Store information.
+    pub information: VssStoreInformation,
+
+    /// Block descriptors per original offset.
+    pub block_descriptors: HashMap<u64, VssBlockDescriptor>,
+
+    /// Overlay block descriptors per original offset.
+    pub overlay_block_descriptors: HashMap<u64, Vec<VssBlockDescriptor>>,
+
+    /// Bitmap, where every bit represents a block of the volume that was not in use when the
+    /// shadow copy was created.
+    pub bitmap: Vec<u8>,
+}
+
+impl VssStoreDescriptor {
+    /// Creates a new store descriptor.
+    pub fn new(identifier: &Uuid) -> Self {
+        Self {
+            mediator: Mediator::current(),
+            identifier: identifier.clone(),
+            volume_size: 0,
+            creation_time: 0,
+            block_list_offset: 0,
+            header_offset: 0,
+            bitmap_offset: 0,
+            information: VssStoreInformation::new(),
+            block_descriptors: HashMap::new(),
+            overlay_block_descriptors: HashMap::new(),
+            bitmap: Vec::new(),
+        }
+    }
+
+    /// Determines if a block was not in use when the shadow copy was created.
+    pub fn is_unused_block(&self, block_offset: u64) -> bool {
+        let block_number: u64 = block_offset / VSS_BLOCK_SIZE;
+        let byte_index: usize = (block_number / 8) as usize;
+
+        match self.bitmap.get(byte_index) {
+            Some(byte_value) => byte_value & (1 << (block_number % 8)) != 0,
+            None => false,
+        }
+    }
+
+    /// Reads a block and its header.
+    fn read_block(
+        &self,
+        data_stream: &DataStreamReference,
+        block_offset: u64,
+        record_type: u32,
+        data: &mut [u8],
+    ) -> Result<VssBlockHeader, ErrorTrace> {
+        let offset: u64 = keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            data,
+            SeekFrom::Start(block_offset)
+        );
+        if self.mediator.debug_output {
+            self.mediator.debug_print(format!(
+                "VssBlock data of size: {} at offset: {} (0x{:08x})\n",
+                data.len(),
+                offset,
+                offset
+            ));
+            self.mediator.debug_print_data(data, true);
+            self.mediator.debug_print(VssBlockHeader::debug_read_data(
+                &data[0..VSS_BLOCK_HEADER_SIZE],
+            ));
+        }
+        let mut block_header: VssBlockHeader = VssBlockHeader::new();
+
+        match block_header.read_data(data) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read block header");
+                return Err(error);
+            }
+        }
+        if block_header.record_type != record_type {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported record type: {} at offset: {} (0x{:08x})",
+                block_header.record_type, block_offset, block_offset
+            )));
+        }
+        Ok(block_header)
+    }
+
+    /// Reads the store block list.
+    fn read_block_list(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        let mut data: Vec<u8> = vec![0; VSS_BLOCK_SIZE as usize];
+        let mut block_offsets: HashSet<u64> = HashSet::new();
+        let mut block_offset: u64 = self.block_list_offset;
+
+        while block_offset != 0 {
+            if !block_offsets.insert(block_offset) {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Store block list contains a loop at offset: {} (0x{:08x})",
+                    block_offset, block_offset
+                )));
+            }
+            let block_header: VssBlockHeader = match self.read_block(
+                data_stream,
+                block_offset,
+                VSS_RECORD_TYPE_BLOCK_LIST_BLOCK,
+                &mut data,
+            ) {
+                Ok(block_header) => block_header,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read store block list block"
+                    );
+                    return Err(error);
+                }
+            };
+            for data_offset in
+                (VSS_BLOCK_HEADER_SIZE..data.len()).step_by(VSS_BLOCK_DESCRIPTOR_SIZE)
+            {
+                let data_end_offset: usize = data_offset + VSS_BLOCK_DESCRIPTOR_SIZE;
+
+                let mut block_descriptor: VssBlockDescriptor = VssBlockDescriptor::new();
+
+                if self.mediator.debug_output {
+                    self.mediator
+                        .debug_print(VssBlockDescriptor::debug_read_data(
+                            &data[data_offset..data_end_offset],
+                        ));
+                }
+                match block_descriptor.read_data(&data[data_offset..data_end_offset]) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read block descriptor"
+                        );
+                        return Err(error);
+                    }
+                }
+                if block_descriptor.is_empty()
+                    || block_descriptor.flags & VSS_BLOCK_FLAG_NOT_USED != 0
+                {
+                    continue;
+                }
+                if block_descriptor.flags & VSS_BLOCK_FLAG_IS_OVERLAY != 0 {
+                    self.overlay_block_descriptors
+                        .entry(block_descriptor.original_offset)
+                        .or_insert_with(Vec::new)
+                        .push(block_descriptor);
+                } else {
+                    // Note that a later block descriptor supersedes an earlier one.
+                    self.block_descriptors
+                        .insert(block_descriptor.original_offset, block_descriptor);
+                }
+            }
+            block_offset = block_header.next_offset;
+        }
+        Ok(())
+    }
+
+    /// Reads the store bitmap.
+    fn read_bitmap(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        let mut data: Vec<u8> = vec![0; VSS_BLOCK_SIZE as usize];
+        let mut block_offsets: HashSet<u64> = HashSet::new();
+        let mut block_offset: u64 = self.bitmap_offset;
+
+        while block_offset != 0 {
+            if !block_offsets.insert(block_offset) {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Store bitmap contains a loop at offset: {} (0x{:08x})",
+                    block_offset, block_offset
+                )));
+            }
+            let block_header: VssBlockHeader = match self.read_block(
+                data_stream,
+                block_offset,
+                VSS_RECORD_TYPE_STORE_BITMAP_BLOCK,
+                &mut data,
+            ) {
+                Ok(block_header) => block_header,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read store bitmap block"
+                    );
+                    return Err(error);
+                }
+            };
+            self.bitmap
+                .extend_from_slice(&data[VSS_BLOCK_HEADER_SIZE..]);
+
+            block_offset = block_header.next_offset;
+        }
+        Ok(())
+    }
+
+    /// Reads the store header.
+    fn read_header(&mut self, data_stream: &DataStreamReference) -> Result<(), ErrorTrace> {
+        let mut data: Vec<u8> = vec![0; VSS_BLOCK_SIZE as usize];
+
+        match self.read_block(
+            data_stream,
+            self.header_offset,
+            VSS_RECORD_TYPE_STORE_HEADER_BLOCK,
+            &mut data,
+        ) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read store header block");
+                return Err(error);
+            }
+        }
+        match self.information.read_data(&data[VSS_BLOCK_HEADER_SIZE..]) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read store information");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the store header and block list from a data stream.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        if self.header_offset != 0 {
+            match self.read_header(data_stream) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read store header");
+                    return Err(error);
+                }
+            }
+        }
+        match self.read_block_list(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read store block list");
+                return Err(error);
+            }
+        }
+        match self.read_bitmap(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read store bitmap");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 0x10000];
+
+        // Store block list block.
+        data[0x4000..0x4010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0x4010] = 0x01;
+        data[0x4014] = 0x03;
+        data[0x4080..0x40a0].copy_from_slice(&[
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ]);
+        data[0x40a0..0x40c0].copy_from_slice(&[
+            0x00, 0x80, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x80, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00,
+        ]);
+        data[0x40c0..0x40e0].copy_from_slice(&[
+            0x00, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ]);
+        // Store header block.
+        data[0x8000..0x8010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0x8010] = 0x01;
+        data[0x8014] = 0x04;
+        data[0x80b0] = 0x09;
+
+        // Store bitmap block.
+        data[0xc000..0xc010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0xc010] = 0x01;
+        data[0xc014] = 0x06;
+        data[0xc080] = 0x05;
+
+        data
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut store_descriptor: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor.block_list_offset = 0x4000;
+        store_descriptor.header_offset = 0x8000;
+        store_descriptor.read_data_stream(&data_stream)?;
+
+        assert_eq!(store_descriptor.information.snapshot_context, 9);
+        assert_eq!(store_descriptor.block_descriptors.len(), 1);
+
+        let block_descriptor: &VssBlockDescriptor =
+            store_descriptor.block_descriptors.get(&0x10000).unwrap();
+        assert_eq!(block_descriptor.store_data_offset, 0x14000);
+
+        assert_eq!(store_descriptor.overlay_block_descriptors.len(), 1);
+
+        let overlay_block_descriptors: &Vec<VssBlockDescriptor> = store_descriptor
+            .overlay_block_descriptors
+            .get(&0x18000)
+            .unwrap();
+        assert_eq!(overlay_block_descriptors.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_bitmap() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut store_descriptor: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor.block_list_offset = 0x4000;
+        store_descriptor.bitmap_offset = 0xc000;
+        store_descriptor.read_data_stream(&data_stream)?;
+
+        assert_eq!(
+            store_descriptor.bitmap.len(),
+            VSS_BLOCK_SIZE as usize - VSS_BLOCK_HEADER_SIZE
+        );
+        assert_eq!(store_descriptor.is_unused_block(0x0000), true);
+        assert_eq!(store_descriptor.is_unused_block(0x4000), false);
+        assert_eq!(store_descriptor.is_unused_block(0x8000), true);
+        assert_eq!(store_descriptor.is_unused_block(0x100000000), false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_bitmap_record_type() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0xc014] = 0x03;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut store_descriptor: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor.block_list_offset = 0x4000;
+        store_descriptor.bitmap_offset = 0xc000;
+
+        let result = store_descriptor.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_record_type() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0x4014] = 0x02;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut store_descriptor: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor.block_list_offset = 0x4000;
+        store_descriptor.header_offset = 0x8000;
+
+        let result = store_descriptor.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_stream_with_loop() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0x4029] = 0x40;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut store_descriptor: VssStoreDescriptor = VssStoreDescriptor::new(&Uuid::new());
+        store_descriptor.block_list_offset = 0x4000;
+
+        let result = store_descriptor.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,158 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_types::{Ucs2String, Uuid, bytes_to_u16_le, bytes_to_u32_le};
+
+/// Volume Shadow Snapshots (VSS) store information.
+pub struct VssStoreInformation {
+    /// Shadow copy identifier.
+    pub copy_identifier: Uuid,
+
+    /// Shadow copy set identifier.
+    pub copy_set_identifier: Uuid,
+
+    /// Snapshot context.
+    pub snapshot_context: u32,
+
+    /// Attribute flags.
+    pub attribute_flags: u32,
+
+    /// Operating machine string.
+    pub operating_machine_string: Ucs2String,
+
+    /// Service machine string.
+    pub service_machine_string: Ucs2String,
+}
+
+impl VssStoreInformation {
+    /// Creates new store information.
+    pub fn new() -> Self {
+        Self {
+            copy_identifier: Uuid::new(),
+            copy_set_identifier: Uuid::new(),
+            snapshot_context: 0,
+            attribute_flags: 0,
+            operating_machine_string: Ucs2String::new(),
+            service_machine_string: Ucs2String::new(),
+        }
+    }
+
+    /// Reads the store information from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        let data_size: usize = data.len();
+
+        if data_size < 66 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS store information data size"
+            ));
+        }
+        self.copy_identifier = Uuid::from_le_bytes(&data[16..32]);
+        self.copy_set_identifier = Uuid::from_le_bytes(&data[32..48]);
+        self.snapshot_context = bytes_to_u32_le!(data, 48);
+        self.attribute_flags = bytes_to_u32_le!(data, 56);
+
+        let mut data_offset: usize = 64;
+
+        let string_size: usize = bytes_to_u16_le!(data, data_offset) as usize;
+        data_offset += 2;
+
+        let data_end_offset: usize = data_offset + string_size;
+
+        if data_end_offset + 2 > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid operating machine string size: {} value out of bounds",
+                string_size
+            )));
+        }
+        self.operating_machine_string =
+            Ucs2String::from_le_bytes(&data[data_offset..data_end_offset]);
+        data_offset = data_end_offset;
+
+        let string_size: usize = bytes_to_u16_le!(data, data_offset) as usize;
+        data_offset += 2;
+
+        let data_end_offset: usize = data_offset + string_size;
+
+        if data_end_offset > data_size {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Invalid service machine string size: {} value out of bounds",
+                string_size
+            )));
+        }
+        self.service_machine_string =
+            Ucs2String::from_le_bytes(&data[data_offset..data_end_offset]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 96];
+        data[16..32].copy_from_slice(&[
+            0x3d, 0x2a, 0x4c, 0x6f, 0x1d, 0x1f, 0x2e, 0x4a, 0x8f, 0x3e, 0x6a, 0x4f, 0x4e, 0x56,
+            0x8e, 0x3f,
+        ]);
+        data[48] = 0x09;
+        data[56] = 0x0d;
+        data[57] = 0x00;
+        data[58] = 0x42;
+        data[64] = 0x08;
+        data[66..74].copy_from_slice(&[0x50, 0x00, 0x43, 0x00, 0x2d, 0x00, 0x31, 0x00]);
+        data[74] = 0x08;
+        data[76..84].copy_from_slice(&[0x50, 0x00, 0x43, 0x00, 0x2d, 0x00, 0x32, 0x00]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = VssStoreInformation::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(
+            test_struct.copy_identifier.to_string(),
+            "6f4c2a3d-1f1d-4a2e-8f3e-6a4f4e568e3f"
+        );
+        assert_eq!(test_struct.snapshot_context, 9);
+        assert_eq!(test_struct.attribute_flags, 0x0042000d);
+        assert_eq!(test_struct.operating_machine_string.to_string(), "PC-1");
+        assert_eq!(test_struct.service_machine_string.to_string(), "PC-2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = VssStoreInformation::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..65]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_invalid_string_size() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[65] = 0xff;
+
+        let mut test_struct = VssStoreInformation::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
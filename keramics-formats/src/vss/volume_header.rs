@@ -0,0 +1,186 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_core::ErrorTrace;
+use keramics_layout_map::LayoutMap;
+use keramics_types::{Uuid, bytes_to_u32_le, bytes_to_u64_le};
+
+use super::constants::*;
+
+#[derive(LayoutMap)]
+#[layout_map(
+    structure(
+        byte_order = "little",
+        field(name = "identifier", data_type = "uuid"),
+        field(name = "format_version", data_type = "u32"),
+        field(name = "record_type", data_type = "u32"),
+        field(name = "current_offset", data_type = "u64"),
+        field(name = "unknown1", data_type = "u64"),
+        field(name = "unknown2", data_type = "u64"),
+        field(name = "catalog_offset", data_type = "u64"),
+        field(name = "maximum_size", data_type = "u64"),
+        field(name = "volume_identifier", data_type = "uuid"),
+        field(name = "storage_volume_identifier", data_type = "uuid"),
+        field(name = "unknown3", data_type = "u32"),
+        field(name = "unknown4", data_type = "[u8; 412]"),
+    ),
+    method(name = "debug_read_data"),
+    method(name = "read_at_position")
+)]
+/// Volume Shadow Snapshots (VSS) volume header.
+pub struct VssVolumeHeader {
+    /// Format version.
+    pub format_version: u32,
+
+    /// Catalog offset.
+    pub catalog_offset: u64,
+
+    /// Maximum size.
+    pub maximum_size: u64,
+
+    /// Volume identifier.
+    pub volume_identifier: Uuid,
+
+    /// Shadow copy storage volume identifier.
+    pub storage_volume_identifier: Uuid,
+}
+
+impl VssVolumeHeader {
+    /// Creates a new volume header.
+    pub fn new() -> Self {
+        Self {
+            format_version: 0,
+            catalog_offset: 0,
+            maximum_size: 0,
+            volume_identifier: Uuid::new(),
+            storage_volume_identifier: Uuid::new(),
+        }
+    }
+
+    /// Reads the volume header from a buffer.
+    pub fn read_data(&mut self, data: &[u8]) -> Result<(), ErrorTrace> {
+        if data.len() != 512 {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS volume header data size"
+            ));
+        }
+        if data[0..16] != VSS_IDENTIFIER {
+            return Err(keramics_core::error_trace_new!(
+                "Unsupported VSS volume header identifier"
+            ));
+        }
+        self.format_version = bytes_to_u32_le!(data, 16);
+
+        if self.format_version != 1 && self.format_version != 2 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported format version: {}",
+                self.format_version
+            )));
+        }
+        let record_type: u32 = bytes_to_u32_le!(data, 20);
+
+        if record_type != VSS_RECORD_TYPE_VOLUME_HEADER {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported record type: {}",
+                record_type
+            )));
+        }
+        self.catalog_offset = bytes_to_u64_le!(data, 48);
+        self.maximum_size = bytes_to_u64_le!(data, 56);
+        self.volume_identifier = Uuid::from_le_bytes(&data[64..80]);
+        self.storage_volume_identifier = Uuid::from_le_bytes(&data[80..96]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 512];
+        data[0..16].copy_from_slice(&VSS_IDENTIFIER);
+        data[16] = 0x01;
+        data[20] = 0x01;
+        data[25] = 0x1e;
+        data[49] = 0x40;
+        data[58] = 0x10;
+        data[64..80].copy_from_slice(&[
+            0x2f, 0x6e, 0x9c, 0x5a, 0x11, 0x8e, 0xe1, 0x11, 0x9c, 0x9f, 0x00, 0x0c, 0x29, 0x2f,
+            0x6a, 0x3b,
+        ]);
+        data[80..96].copy_from_slice(&[
+            0x2f, 0x6e, 0x9c, 0x5a, 0x11, 0x8e, 0xe1, 0x11, 0x9c, 0x9f, 0x00, 0x0c, 0x29, 0x2f,
+            0x6a, 0x3b,
+        ]);
+        data
+    }
+
+    #[test]
+    fn test_read_data() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+
+        let mut test_struct = VssVolumeHeader::new();
+        test_struct.read_data(&test_data)?;
+
+        assert_eq!(test_struct.format_version, 1);
+        assert_eq!(test_struct.catalog_offset, 0x4000);
+        assert_eq!(test_struct.maximum_size, 0x100000);
+        assert_eq!(
+            test_struct.volume_identifier.to_string(),
+            "5a9c6e2f-8e11-11e1-9c9f-000c292f6a3b"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_data_size() {
+        let mut test_struct = VssVolumeHeader::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let result = test_struct.read_data(&test_data[0..511]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_identifier() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0] = 0xff;
+
+        let mut test_struct = VssVolumeHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_format_version() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[16] = 0xff;
+
+        let mut test_struct = VssVolumeHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_with_unsupported_record_type() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[20] = 0x02;
+
+        let mut test_struct = VssVolumeHeader::new();
+        let result = test_struct.read_data(&test_data);
+        assert!(result.is_err());
+    }
+}
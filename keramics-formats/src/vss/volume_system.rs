@@ -0,0 +1,401 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashSet;
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_types::Uuid;
+
+use super::block_header::VssBlockHeader;
+use super::catalog_entry::VssCatalogEntry;
+use super::constants::*;
+use super::store::VssStore;
+use super::store_descriptor::VssStoreDescriptor;
+use super::volume_header::VssVolumeHeader;
+
+/// Volume Shadow Snapshots (VSS) volume system.
+pub struct VssVolumeSystem {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// Data stream.
+    data_stream: Option<DataStreamReference>,
+
+    /// Volume identifier.
+    pub volume_identifier: Uuid,
+
+    /// Maximum size of the shadow copy storage.
+    pub maximum_size: u64,
+
+    /// Store descriptors, ordered from oldest to most recent.
+    store_descriptors: Vec<Arc<VssStoreDescriptor>>,
+}
+
+impl VssVolumeSystem {
+    /// Creates a volume system.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::current(),
+            data_stream: None,
+            volume_identifier: Uuid::new(),
+            maximum_size: 0,
+            store_descriptors: Vec::new(),
+        }
+    }
+
+    /// Retrieves the number of stores.
+    pub fn get_number_of_stores(&self) -> usize {
+        self.store_descriptors.len()
+    }
+
+    /// Retrieves a store by index.
+    pub fn get_store_by_index(&self, store_index: usize) -> Result<VssStore, ErrorTrace> {
+        if store_index >= self.store_descriptors.len() {
+            return Err(keramics_core::error_trace_new!(format!(
+                "No store with index: {}",
+                store_index
+            )));
+        }
+        let data_stream: &DataStreamReference = match self.data_stream.as_ref() {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        let mut store: VssStore = VssStore::new(store_index, &self.store_descriptors);
+
+        match store.open(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!("Unable to open store: {}", store_index)
+                );
+                return Err(error);
+            }
+        }
+        Ok(store)
+    }
+
+    /// Reads the volume system from a data stream.
+    pub fn read_data_stream(
+        &mut self,
+        data_stream: &DataStreamReference,
+    ) -> Result<(), ErrorTrace> {
+        let mut volume_header: VssVolumeHeader = VssVolumeHeader::new();
+
+        match volume_header.read_at_position(data_stream, SeekFrom::Start(VSS_VOLUME_HEADER_OFFSET))
+        {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to read volume header");
+                return Err(error);
+            }
+        }
+        self.volume_identifier = volume_header.volume_identifier;
+        self.maximum_size = volume_header.maximum_size;
+
+        // Note that the catalog offset is 0 when no shadow copies have been created.
+        if volume_header.catalog_offset != 0 {
+            match self.read_catalog(data_stream, volume_header.catalog_offset) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to read catalog");
+                    return Err(error);
+                }
+            }
+        }
+        self.data_stream = Some(data_stream.clone());
+
+        Ok(())
+    }
+
+    /// Reads the catalog.
+    fn read_catalog(
+        &mut self,
+        data_stream: &DataStreamReference,
+        catalog_offset: u64,
+    ) -> Result<(), ErrorTrace> {
+        let mut store_descriptors: Vec<VssStoreDescriptor> = Vec::new();
+
+        let mut data: Vec<u8> = vec![0; VSS_BLOCK_SIZE as usize];
+        let mut block_offsets: HashSet<u64> = HashSet::new();
+        let mut block_offset: u64 = catalog_offset;
+
+        while block_offset != 0 {
+            if !block_offsets.insert(block_offset) {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Catalog contains a loop at offset: {} (0x{:08x})",
+                    block_offset, block_offset
+                )));
+            }
+            let offset: u64 = keramics_core::data_stream_read_exact_at_position!(
+                data_stream,
+                &mut data,
+                SeekFrom::Start(block_offset)
+            );
+            if self.mediator.debug_output {
+                self.mediator.debug_print(format!(
+                    "VssCatalogBlock data of size: {} at offset: {} (0x{:08x})\n",
+                    data.len(),
+                    offset,
+                    offset
+                ));
+                self.mediator.debug_print_data(&data, true);
+                self.mediator.debug_print(VssBlockHeader::debug_read_data(
+                    &data[0..VSS_BLOCK_HEADER_SIZE],
+                ));
+            }
+            let mut block_header: VssBlockHeader = VssBlockHeader::new();
+
+            match block_header.read_data(&data) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to read catalog block header"
+                    );
+                    return Err(error);
+                }
+            }
+            if block_header.record_type != VSS_RECORD_TYPE_CATALOG_BLOCK {
+                return Err(keramics_core::error_trace_new!(format!(
+                    "Unsupported catalog block record type: {}",
+                    block_header.record_type
+                )));
+            }
+            for data_offset in (VSS_BLOCK_HEADER_SIZE..data.len()).step_by(VSS_CATALOG_ENTRY_SIZE) {
+                let data_end_offset: usize = data_offset + VSS_CATALOG_ENTRY_SIZE;
+
+                let mut catalog_entry: VssCatalogEntry = VssCatalogEntry::new();
+
+                match catalog_entry.read_data(&data[data_offset..data_end_offset]) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to read catalog entry"
+                        );
+                        return Err(error);
+                    }
+                }
+                match catalog_entry.entry_type {
+                    2 => {
+                        let mut store_descriptor: VssStoreDescriptor =
+                            VssStoreDescriptor::new(&catalog_entry.store_identifier);
+                        store_descriptor.volume_size = catalog_entry.volume_size;
+                        store_descriptor.creation_time = catalog_entry.creation_time;
+
+                        store_descriptors.push(store_descriptor);
+                    }
+                    3 => {
+                        match store_descriptors.iter_mut().find(|store_descriptor| {
+                            store_descriptor.identifier == catalog_entry.store_identifier
+                        }) {
+                            Some(store_descriptor) => {
+                                store_descriptor.block_list_offset =
+                                    catalog_entry.store_block_list_offset;
+                                store_descriptor.header_offset = catalog_entry.store_header_offset;
+                                store_descriptor.bitmap_offset = catalog_entry.store_bitmap_offset;
+                            }
+                            None => {
+                                return Err(keramics_core::error_trace_new!(format!(
+                                    "Missing store information for store: {}",
+                                    catalog_entry.store_identifier
+                                )));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            block_offset = block_header.next_offset;
+        }
+        // The stores are ordered from oldest to most recent since the data of older stores
+        // is resolved using more recent stores.
+        store_descriptors.sort_by_key(|store_descriptor| store_descriptor.creation_time);
+
+        for mut store_descriptor in store_descriptors.into_iter() {
+            match store_descriptor.read_data_stream(data_stream) {
+                Ok(_) => {}
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to read store: {}", store_descriptor.identifier)
+                    );
+                    return Err(error);
+                }
+            }
+            self.store_descriptors.push(Arc::new(store_descriptor));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::{DataStream, open_fake_data_stream};
+
+    fn get_test_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 0x20000];
+
+        // Volume header.
+        data[0x1e00..0x1e10].copy_from_slice(&VSS_IDENTIFIER);
+        data[0x1e10] = 0x01;
+        data[0x1e14] = 0x01;
+        data[0x1e19] = 0x1e;
+        data[0x1e31] = 0x40;
+
+        // Catalog block.
+        data[0x4000..0x4010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0x4010] = 0x01;
+        data[0x4014] = 0x02;
+        data[0x4021] = 0x40;
+
+        // Catalog entry type 2.
+        data[0x4080] = 0x02;
+        data[0x408a] = 0x02;
+        data[0x4090] = 0x01;
+        data[0x40b0..0x40b8].copy_from_slice(&[0xf0, 0xb8, 0xf9, 0xd0, 0xa6, 0xc4, 0xd0, 0x01]);
+
+        // Catalog entry type 3.
+        data[0x4100] = 0x03;
+        data[0x4109] = 0x80;
+        data[0x4110] = 0x01;
+        data[0x4121] = 0xc0;
+
+        // Store block list block.
+        data[0x8000..0x8010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0x8010] = 0x01;
+        data[0x8014] = 0x03;
+        data[0x8082] = 0x01;
+        data[0x8091] = 0x40;
+        data[0x8092] = 0x01;
+
+        // Store header block.
+        data[0xc000..0xc010].copy_from_slice(&VSS_IDENTIFIER);
+        data[0xc010] = 0x01;
+        data[0xc014] = 0x04;
+        data[0xc090] = 0x02;
+
+        // Current volume data.
+        data[0x10000..0x14000].fill(0xaa);
+
+        // Store data.
+        data[0x14000..0x18000].fill(0x55);
+
+        data
+    }
+
+    #[test]
+    fn test_get_number_of_stores() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        volume_system.read_data_stream(&data_stream)?;
+
+        assert_eq!(volume_system.get_number_of_stores(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_store_by_index() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        volume_system.read_data_stream(&data_stream)?;
+
+        let mut store: VssStore = volume_system.get_store_by_index(0)?;
+
+        assert_eq!(store.size, 0x20000);
+        assert_eq!(
+            store.identifier.to_string(),
+            "00000001-0000-0000-0000-000000000000"
+        );
+        assert_eq!(
+            store.copy_identifier.to_string(),
+            "00000002-0000-0000-0000-000000000000"
+        );
+
+        let mut data: Vec<u8> = vec![0; 512];
+        store.seek(SeekFrom::Start(0x10000))?;
+        store.read(&mut data)?;
+        assert_eq!(data, [0x55; 512]);
+
+        store.seek(SeekFrom::Start(0x14000))?;
+        store.read(&mut data)?;
+        assert_eq!(data, [0x55; 512]);
+
+        let result = volume_system.get_store_by_index(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream() -> Result<(), ErrorTrace> {
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        volume_system.read_data_stream(&data_stream)?;
+
+        assert_eq!(volume_system.get_number_of_stores(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_without_catalog() -> Result<(), ErrorTrace> {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0x1e31] = 0x00;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        volume_system.read_data_stream(&data_stream)?;
+
+        assert_eq!(volume_system.get_number_of_stores(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_stream_with_unsupported_identifier() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0x1e00] = 0xff;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        let result = volume_system.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_data_stream_with_missing_store_information() {
+        let mut test_data: Vec<u8> = get_test_data();
+        test_data[0x4090] = 0x02;
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+        let result = volume_system.read_data_stream(&data_stream);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,150 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::io::SeekFrom;
+use std::path::PathBuf;
+
+use keramics_core::formatters::format_as_string;
+use keramics_core::{DataStream, DataStreamReference, ErrorTrace, open_os_data_stream};
+use keramics_formats::gzip::GzipFile;
+use keramics_hashes::{DigestHashContext, Md5Context};
+
+fn read_media_from_file(file: &mut GzipFile) -> Result<(u64, String), ErrorTrace> {
+    let mut data: Vec<u8> = vec![0; 35891];
+    let mut md5_context: Md5Context = Md5Context::new();
+    let mut media_offset: u64 = 0;
+
+    loop {
+        let read_count = match file.read(&mut data) {
+            Ok(read_count) => read_count,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to read from gzip file at offset {} (0x{:08x})",
+                        media_offset, media_offset
+                    )
+                );
+                return Err(error);
+            }
+        };
+        if read_count == 0 {
+            break;
+        }
+        md5_context.update(&data[..read_count]);
+
+        media_offset += read_count as u64;
+    }
+    let hash_value: Vec<u8> = md5_context.finalize();
+    let hash_string: String = format_as_string(&hash_value);
+
+    Ok((media_offset, hash_string))
+}
+
+fn read_media_from_file_in_reverse(file: &mut GzipFile) -> Result<String, ErrorTrace> {
+    let mut data: Vec<u8> = vec![0; file.media_size as usize];
+    let mut md5_context: Md5Context = Md5Context::new();
+    let mut data_end_offset: usize = data.len();
+
+    while data_end_offset > 0 {
+        let data_offset: usize = data_end_offset.saturating_sub(35891);
+
+        file.seek(SeekFrom::Start(data_offset as u64))?;
+
+        let read_count = match file.read(&mut data[data_offset..data_end_offset]) {
+            Ok(read_count) => read_count,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    format!(
+                        "Unable to read from gzip file at offset {} (0x{:08x})",
+                        data_offset, data_offset
+                    )
+                );
+                return Err(error);
+            }
+        };
+        assert_eq!(read_count, data_end_offset - data_offset);
+
+        data_end_offset = data_offset;
+    }
+    md5_context.update(&data);
+
+    let hash_value: Vec<u8> = md5_context.finalize();
+    let hash_string: String = format_as_string(&hash_value);
+
+    Ok(hash_string)
+}
+
+fn open_file(path: &PathBuf) -> Result<GzipFile, ErrorTrace> {
+    let data_stream: DataStreamReference = match open_os_data_stream(path) {
+        Ok(data_stream) => data_stream,
+        Err(error) => {
+            return Err(keramics_core::error_trace_new_with_error!(
+                "Unable to open data stream",
+                error
+            ));
+        }
+    };
+    let mut file: GzipFile = GzipFile::new();
+
+    match file.read_data_stream(&data_stream) {
+        Ok(_) => {}
+        Err(mut error) => {
+            keramics_core::error_trace_add_frame!(
+                error,
+                "Unable to read gzip file from data stream"
+            );
+            return Err(error);
+        }
+    }
+    Ok(file)
+}
+
+#[test]
+fn read_media() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/gzip/ext2.raw.gz");
+    let mut file: GzipFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_in_reverse() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/gzip/ext2.raw.gz");
+    let mut file: GzipFile = open_file(&path_buf)?;
+
+    let md5_hash: String = read_media_from_file_in_reverse(&mut file)?;
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_multiple_members() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/gzip/ext2_multi_member.raw.gz");
+    let mut file: GzipFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    let md5_hash: String = read_media_from_file_in_reverse(&mut file)?;
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
@@ -14,6 +14,7 @@
 use std::path::PathBuf;
 
 use keramics_core::formatters::format_as_string;
+use keramics_core::mediator::Mediator;
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace, open_os_data_stream};
 use keramics_formats::qcow::QcowFile;
 use keramics_hashes::{DigestHashContext, Md5Context};
@@ -87,4 +88,119 @@ fn read_media() -> Result<(), ErrorTrace> {
     Ok(())
 }
 
+#[test]
+fn read_media_with_zlib_compression() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_zlib.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_zstd_compression() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_zstd.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_snapshot() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_snapshot.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "8d73ca9b91dfa14c52442fa2dc96f860");
+
+    assert_eq!(file.get_number_of_snapshots(), 1);
+
+    let mut snapshot_file: QcowFile = file.open_snapshot_by_index(0)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut snapshot_file)?;
+    assert_eq!(media_offset, snapshot_file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+fn set_password(password: &str) {
+    let password: String = password.to_string();
+
+    Mediator {
+        debug_output: false,
+        credential_callback: Some(Box::new(move |_: &str| Some(password.clone()))),
+    }
+    .make_current();
+}
+
+#[test]
+fn read_media_with_aes_encryption() -> Result<(), ErrorTrace> {
+    set_password("keramics");
+
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_aes.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    assert!(file.is_encrypted());
+    assert!(!file.is_locked());
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_luks_encryption() -> Result<(), ErrorTrace> {
+    set_password("keramics");
+
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_luks.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    assert!(file.is_encrypted());
+    assert!(!file.is_locked());
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "b1760d0b35a512ef56970df4e6f8c5d6");
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_luks_encryption_and_incorrect_password() -> Result<(), ErrorTrace> {
+    set_password("incorrect");
+
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_luks.qcow2");
+    let mut file: QcowFile = open_file(&path_buf)?;
+
+    assert!(file.is_encrypted());
+    assert!(file.is_locked());
+
+    let result = read_media_from_file(&mut file);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn read_media_with_luks_encryption_without_password() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/qcow/ext2_luks.qcow2");
+    let file: QcowFile = open_file(&path_buf)?;
+
+    assert!(file.is_encrypted());
+    assert!(file.is_locked());
+
+    Ok(())
+}
+
 // TODO: add test with backing file.
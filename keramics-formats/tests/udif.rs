@@ -111,6 +111,18 @@ fn read_media_lzfse_compressed() -> Result<(), ErrorTrace> {
     Ok(())
 }
 
+#[test]
+fn read_media_lzma_compressed() -> Result<(), ErrorTrace> {
+    let path_buf: PathBuf = PathBuf::from("../test_data/udif/hfsplus_lzma.dmg");
+    let mut file: UdifFile = open_file(&path_buf)?;
+
+    let (media_offset, md5_hash): (u64, String) = read_media_from_file(&mut file)?;
+    assert_eq!(media_offset, file.media_size);
+    assert_eq!(md5_hash.as_str(), "ee3b14a7a0824c9d06bfeab97c614767");
+
+    Ok(())
+}
+
 #[test]
 fn read_media_zlib_compressed() -> Result<(), ErrorTrace> {
     let path_buf: PathBuf = PathBuf::from("../test_data/udif/hfsplus_zlib.dmg");
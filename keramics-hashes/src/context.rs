@@ -0,0 +1,57 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::enums::DigestHashMethod;
+use super::md5::Md5Context;
+use super::sha1::Sha1Context;
+use super::sha224::Sha224Context;
+use super::sha256::Sha256Context;
+use super::sha512::Sha512Context;
+use super::traits::DigestHashContext;
+
+/// Creates a new digest hash context for a specific digest hash method.
+pub fn new_digest_hash_context(
+    digest_hash_method: &DigestHashMethod,
+) -> Box<dyn DigestHashContext> {
+    match digest_hash_method {
+        DigestHashMethod::Md5 => Box::new(Md5Context::new()),
+        DigestHashMethod::Sha1 => Box::new(Sha1Context::new()),
+        DigestHashMethod::Sha224 => Box::new(Sha224Context::new()),
+        DigestHashMethod::Sha256 => Box::new(Sha256Context::new()),
+        DigestHashMethod::Sha512 => Box::new(Sha512Context::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_digest_hash_context() {
+        let digest_hash_methods: [DigestHashMethod; 5] = [
+            DigestHashMethod::Md5,
+            DigestHashMethod::Sha1,
+            DigestHashMethod::Sha224,
+            DigestHashMethod::Sha256,
+            DigestHashMethod::Sha512,
+        ];
+        for digest_hash_method in digest_hash_methods.iter() {
+            let mut context: Box<dyn DigestHashContext> =
+                new_digest_hash_context(digest_hash_method);
+            context.update(b"Keramics");
+
+            let hash_value: Vec<u8> = context.finalize();
+            assert_eq!(hash_value.len(), digest_hash_method.get_hash_size());
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// Digest hash method.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DigestHashMethod {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha512,
+}
+
+impl DigestHashMethod {
+    /// Retrieves the block size.
+    pub fn get_block_size(&self) -> usize {
+        match self {
+            DigestHashMethod::Sha512 => 128,
+            _ => 64,
+        }
+    }
+
+    /// Retrieves the digest hash size.
+    pub fn get_hash_size(&self) -> usize {
+        match self {
+            DigestHashMethod::Md5 => 16,
+            DigestHashMethod::Sha1 => 20,
+            DigestHashMethod::Sha224 => 28,
+            DigestHashMethod::Sha256 => 32,
+            DigestHashMethod::Sha512 => 64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_hash_size() {
+        assert_eq!(DigestHashMethod::Md5.get_hash_size(), 16);
+        assert_eq!(DigestHashMethod::Sha1.get_hash_size(), 20);
+        assert_eq!(DigestHashMethod::Sha512.get_hash_size(), 64);
+    }
+}
@@ -11,6 +11,8 @@
  * under the License.
  */
 
+mod context;
+mod enums;
 mod md5;
 mod sha1;
 mod sha224;
@@ -18,6 +20,8 @@ mod sha256;
 mod sha512;
 mod traits;
 
+pub use context::new_digest_hash_context;
+pub use enums::DigestHashMethod;
 pub use md5::Md5Context;
 pub use sha1::Sha1Context;
 pub use sha224::Sha224Context;
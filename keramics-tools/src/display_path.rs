@@ -186,7 +186,7 @@ impl DisplayPath {
                 let path_string: String = path.to_string();
                 match vfs_type {
                     VfsType::Apm => path_string.replace("apm", "p"),
-                    VfsType::Ext | VfsType::Fat | VfsType::Ntfs => {
+                    VfsType::Ext | VfsType::Fat | VfsType::Ntfs | VfsType::Vss => {
                         let parent_display_path: String = match self.get_path(parent) {
                             Ok(path) => path,
                             Err(mut error) => {
@@ -103,6 +103,72 @@ pub enum EncodingType {
     #[value(alias("latin-10"))]
     Iso8859_16,
 
+    /// MacArabic
+    MacArabic,
+
+    /// MacCeltic
+    MacCeltic,
+
+    /// MacCentralEurRoman
+    MacCentralEurRoman,
+
+    /// MacChineseSimplified
+    MacChineseSimplified,
+
+    /// MacChineseTraditional
+    MacChineseTraditional,
+
+    /// MacCroatian
+    MacCroatian,
+
+    /// MacCyrillic
+    MacCyrillic,
+
+    /// MacDingbats
+    MacDingbats,
+
+    /// MacFarsi
+    MacFarsi,
+
+    /// MacGaelic
+    MacGaelic,
+
+    /// MacGreek
+    MacGreek,
+
+    /// MacHebrew
+    MacHebrew,
+
+    /// MacIcelandic
+    MacIcelandic,
+
+    /// MacInuit
+    MacInuit,
+
+    /// MacJapanese
+    MacJapanese,
+
+    /// MacKorean
+    MacKorean,
+
+    /// MacRoman
+    MacRoman,
+
+    /// MacRussian
+    MacRussian,
+
+    /// MacSymbol
+    MacSymbol,
+
+    /// MacThai
+    MacThai,
+
+    /// MacTurkish
+    MacTurkish,
+
+    /// MacUkrainian
+    MacUkrainian,
+
     /// UTF-8
     Utf8,
 }
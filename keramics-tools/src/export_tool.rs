@@ -16,7 +16,7 @@ use std::process::ExitCode;
 
 use clap::{Args, Parser, Subcommand};
 
-use keramics_core::mediator::Mediator;
+use keramics_core::mediator::{Mediator, MediatorCredentialCallback};
 use keramics_core::{DataStreamReference, ErrorTrace};
 use keramics_vfs::{
     VfsLocation, VfsPath, VfsResolver, VfsResolverReference, VfsScanContext, VfsScanNode,
@@ -34,6 +34,10 @@ struct CommandLineArguments {
     /// Enable debug output
     debug: bool,
 
+    #[arg(long)]
+    /// Password or passphrase to unlock encrypted storage media
+    password: Option<String>,
+
     #[arg(short, long, default_value_t = 0)]
     /// Offset within the source file.
     offset: u64,
@@ -180,8 +184,17 @@ fn main() -> ExitCode {
         println!("No file system found in source");
         return ExitCode::FAILURE;
     }
+    let credential_callback: Option<MediatorCredentialCallback> = match &arguments.password {
+        Some(password) => {
+            let password: String = password.clone();
+
+            Some(Box::new(move |_: &str| Some(password.clone())))
+        }
+        None => None,
+    };
     Mediator {
         debug_output: arguments.debug,
+        credential_callback: credential_callback,
     }
     .make_current();
 
@@ -18,7 +18,7 @@ use std::process::ExitCode;
 use clap::Parser;
 
 use keramics_core::formatters::format_as_string;
-use keramics_core::mediator::Mediator;
+use keramics_core::mediator::{Mediator, MediatorCredentialCallback};
 use keramics_core::{DataStreamReference, ErrorTrace, open_os_data_stream};
 use keramics_hashes::{
     DigestHashContext, Md5Context, Sha1Context, Sha224Context, Sha256Context, Sha512Context,
@@ -44,6 +44,10 @@ struct CommandLineArguments {
     /// Enable debug output
     debug: bool,
 
+    #[arg(long)]
+    /// Password or passphrase to unlock encrypted storage media
+    password: Option<String>,
+
     /// Digest hash type
     #[arg(short, long, default_value_t = DigestHashType::Md5, value_enum)]
     digest_hash_type: DigestHashType,
@@ -316,8 +320,17 @@ impl HashTool {
 fn main() -> ExitCode {
     let arguments = CommandLineArguments::parse();
 
+    let credential_callback: Option<MediatorCredentialCallback> = match &arguments.password {
+        Some(password) => {
+            let password: String = password.clone();
+
+            Some(Box::new(move |_: &str| Some(password.clone())))
+        }
+        None => None,
+    };
     Mediator {
         debug_output: arguments.debug,
+        credential_callback: credential_callback,
     }
     .make_current();
 
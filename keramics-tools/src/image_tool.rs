@@ -21,10 +21,10 @@ use clap::{Args, Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressState, ProgressStyle};
 
 use keramics_core::formatters::format_as_string;
-use keramics_core::mediator::Mediator;
+use keramics_core::mediator::{Mediator, MediatorCredentialCallback};
 use keramics_core::{DataStream, DataStreamReference, ErrorTrace, open_os_data_stream};
 use keramics_formats::ewf::EwfImage;
-use keramics_formats::ntfs::NtfsAttribute;
+use keramics_formats::ntfs::NtfsFileName;
 use keramics_formats::qcow::{QcowImage, QcowImageLayer};
 use keramics_formats::sparseimage::SparseImageFile;
 use keramics_formats::udif::UdifFile;
@@ -35,9 +35,9 @@ use keramics_formats::{FormatIdentifier, FormatScanner};
 use keramics_hashes::{DigestHashContext, Md5Context};
 use keramics_types::Ucs2String;
 use keramics_vfs::{
-    VfsDataFork, VfsFileEntry, VfsFileSystemReference, VfsFileType, VfsFinder, VfsLocation,
-    VfsPath, VfsResolver, VfsResolverReference, VfsScanContext, VfsScanNode, VfsScanner, VfsString,
-    VfsType, new_os_vfs_location,
+    VfsDataFork, VfsDeletedFinder, VfsFileEntry, VfsFileSystemReference, VfsFileType, VfsFinder,
+    VfsLocation, VfsPath, VfsResolver, VfsResolverReference, VfsScanContext, VfsScanNode,
+    VfsScanner, VfsString, VfsType, new_os_vfs_location,
 };
 
 mod bodyfile;
@@ -58,6 +58,10 @@ struct CommandLineArguments {
     /// Enable debug output
     debug: bool,
 
+    #[arg(long)]
+    /// Password or passphrase to unlock encrypted storage media
+    password: Option<String>,
+
     /// Path of the source file
     source: PathBuf,
 
@@ -544,36 +548,54 @@ impl ImageTool {
         string_parts.join("")
     }
 
+    /// Calculates the MD5 of the data stream of a file entry.
+    fn calculate_md5_of_file_entry(file_entry: &VfsFileEntry) -> Result<String, ErrorTrace> {
+        let result: Option<DataStreamReference> = match file_entry.get_data_stream() {
+            Ok(result) => result,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to retrieve data stream");
+                return Err(error);
+            }
+        };
+        match result {
+            Some(data_stream) => match Bodyfile::calculate_md5(&data_stream) {
+                Ok(md5_string) => Ok(md5_string),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to calculate MD5 of data stream"
+                    );
+                    Err(error)
+                }
+            },
+            None => Ok(String::from("00000000000000000000000000000000")),
+        }
+    }
+
     /// Prints the file entry in bodyfile format.
     fn print_file_entry_as_bodyfile(
         &self,
         file_entry: &mut VfsFileEntry,
         file_system_display_path: &String,
         path_components: &Vec<VfsString>,
+        is_deleted: bool,
         calculate_md5: bool,
     ) -> Result<(), ErrorTrace> {
         let md5: String = if !calculate_md5 {
             String::from("0")
         } else {
-            let result: Option<DataStreamReference> = match file_entry.get_data_stream() {
-                Ok(result) => result,
+            match Self::calculate_md5_of_file_entry(file_entry) {
+                Ok(md5_string) => md5_string,
+                // The data of a deleted file entry can be unreadable, in which case the MD5 is
+                // not set.
+                Err(_) if is_deleted => String::from("0"),
                 Err(mut error) => {
-                    keramics_core::error_trace_add_frame!(error, "Unable to retrieve data stream");
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to calculate MD5 of file entry"
+                    );
                     return Err(error);
                 }
-            };
-            match result {
-                Some(data_stream) => match Bodyfile::calculate_md5(&data_stream) {
-                    Ok(md5_string) => md5_string,
-                    Err(mut error) => {
-                        keramics_core::error_trace_add_frame!(
-                            error,
-                            "Unable to calculate MD5 of data stream"
-                        );
-                        return Err(error);
-                    }
-                },
-                None => String::from("00000000000000000000000000000000"),
             }
         };
         let display_path: String = self.display_path.join_path_components(path_components);
@@ -598,6 +620,8 @@ impl ImageTool {
             Some(symbolic_link_target) => format!(" -> {}", symbolic_link_target.to_string()),
             None => String::new(),
         };
+        let deleted_suffix: &str = if is_deleted { " (deleted)" } else { "" };
+
         let file_identifier: String = match file_entry {
             VfsFileEntry::Ext(ext_file_entry) => {
                 format!("{}", ext_file_entry.inode_number)
@@ -696,10 +720,11 @@ impl ImageTool {
                 }
             };
         println!(
-            "{}|{}{}{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}{}{}{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             md5,
             path_prefix,
             display_path,
+            deleted_suffix,
             path_suffix,
             file_identifier,
             file_mode_string,
@@ -779,11 +804,12 @@ impl ImageTool {
                 }
             };
             println!(
-                "{}|{}{}{}{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                "{}|{}{}{}{}{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
                 md5,
                 path_prefix,
                 display_path,
                 data_fork_name,
+                deleted_suffix,
                 path_suffix,
                 file_identifier,
                 file_mode_string,
@@ -800,104 +826,103 @@ impl ImageTool {
             VfsFileEntry::Ntfs(ntfs_file_entry) => {
                 if let Some(parent_file_reference) = ntfs_file_entry.get_parent_file_reference() {
                     let name: Option<&Ucs2String> = ntfs_file_entry.get_name();
-                    let number_of_attributes: usize = ntfs_file_entry.get_number_of_attributes();
+                    let number_of_file_names: usize = ntfs_file_entry.get_number_of_file_names();
+
+                    let mut file_names: Vec<NtfsFileName> = Vec::new();
 
                     // TODO: print index names
-                    for attribute_index in 0..number_of_attributes {
-                        let attribute: NtfsAttribute =
-                            match ntfs_file_entry.get_attribute_by_index(attribute_index) {
-                                Ok(attribute) => attribute,
+                    for file_name_index in 0..number_of_file_names {
+                        let file_name: &NtfsFileName =
+                            match ntfs_file_entry.get_file_name_by_index(file_name_index) {
+                                Ok(file_name) => file_name,
                                 Err(mut error) => {
                                     keramics_core::error_trace_add_frame!(
                                         error,
                                         format!(
-                                            "Unable to retrieve NTFS MFT entry: {} attribute: {}",
-                                            ntfs_file_entry.mft_entry_number, attribute_index
+                                            "Unable to retrieve NTFS MFT entry: {} file name: {}",
+                                            ntfs_file_entry.mft_entry_number, file_name_index
                                         )
                                     );
                                     return Err(error);
                                 }
                             };
-                        match attribute {
-                            NtfsAttribute::FileName { file_name } => {
-                                if file_name.parent_file_reference != parent_file_reference
-                                    || Some(&file_name.name) != name
-                                {
-                                    continue;
+                        if file_name.parent_file_reference != parent_file_reference
+                            || Some(&file_name.name) != name
+                        {
+                            continue;
+                        }
+                        file_names.push(file_name.clone());
+                    }
+                    // A deleted file entry carved from a directory index can lack MFT attributes
+                    // in which case the $FILE_NAME of the directory entry is used.
+                    if number_of_file_names == 0 {
+                        if let Some(file_name) = ntfs_file_entry.get_directory_entry_file_name() {
+                            file_names.push(file_name.clone());
+                        }
+                    }
+                    for file_name in file_names.iter() {
+                        let file_name_access_time: String =
+                            match Bodyfile::format_as_timestamp(Some(&file_name.access_time)) {
+                                Ok(timestamp_string) => timestamp_string,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to format $FILE_NAME access time"
+                                    );
+                                    return Err(error);
                                 }
-                                if file_name.name_space == 0x02 {
-                                    continue;
+                            };
+                        let file_name_modification_time: String =
+                            match Bodyfile::format_as_timestamp(Some(&file_name.modification_time))
+                            {
+                                Ok(timestamp_string) => timestamp_string,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to format $FILE_NAME modification time"
+                                    );
+                                    return Err(error);
                                 }
-                                let file_name_access_time: String =
-                                    match Bodyfile::format_as_timestamp(Some(
-                                        &file_name.access_time,
-                                    )) {
-                                        Ok(timestamp_string) => timestamp_string,
-                                        Err(mut error) => {
-                                            keramics_core::error_trace_add_frame!(
-                                                error,
-                                                "Unable to format $FILE_NAME access time"
-                                            );
-                                            return Err(error);
-                                        }
-                                    };
-                                let file_name_modification_time: String =
-                                    match Bodyfile::format_as_timestamp(Some(
-                                        &file_name.modification_time,
-                                    )) {
-                                        Ok(timestamp_string) => timestamp_string,
-                                        Err(mut error) => {
-                                            keramics_core::error_trace_add_frame!(
-                                                error,
-                                                "Unable to format $FILE_NAME modification time"
-                                            );
-                                            return Err(error);
-                                        }
-                                    };
-                                let file_name_change_time: String =
-                                    match Bodyfile::format_as_timestamp(Some(
-                                        &file_name.entry_modification_time,
-                                    )) {
-                                        Ok(timestamp_string) => timestamp_string,
-                                        Err(mut error) => {
-                                            keramics_core::error_trace_add_frame!(
-                                                error,
-                                                "Unable to format $FILE_NAME entry modification time"
-                                            );
-                                            return Err(error);
-                                        }
-                                    };
-                                let file_name_creation_time: String =
-                                    match Bodyfile::format_as_timestamp(Some(
-                                        &file_name.creation_time,
-                                    )) {
-                                        Ok(timestamp_string) => timestamp_string,
-                                        Err(mut error) => {
-                                            keramics_core::error_trace_add_frame!(
-                                                error,
-                                                "Unable to format $FILE_NAME creation time"
-                                            );
-                                            return Err(error);
-                                        }
-                                    };
-                                println!(
-                                    "{}|{}{} ($FILE_NAME)|{}|{}|{}|{}|{}|{}|{}|{}|{}",
-                                    md5,
-                                    path_prefix,
-                                    display_path,
-                                    file_identifier,
-                                    file_mode_string,
-                                    owner_identifier,
-                                    group_identifier,
-                                    size,
-                                    file_name_access_time,
-                                    file_name_modification_time,
-                                    file_name_change_time,
-                                    file_name_creation_time
+                            };
+                        let file_name_change_time: String = match Bodyfile::format_as_timestamp(
+                            Some(&file_name.entry_modification_time),
+                        ) {
+                            Ok(timestamp_string) => timestamp_string,
+                            Err(mut error) => {
+                                keramics_core::error_trace_add_frame!(
+                                    error,
+                                    "Unable to format $FILE_NAME entry modification time"
                                 );
+                                return Err(error);
                             }
-                            _ => {}
-                        }
+                        };
+                        let file_name_creation_time: String =
+                            match Bodyfile::format_as_timestamp(Some(&file_name.creation_time)) {
+                                Ok(timestamp_string) => timestamp_string,
+                                Err(mut error) => {
+                                    keramics_core::error_trace_add_frame!(
+                                        error,
+                                        "Unable to format $FILE_NAME creation time"
+                                    );
+                                    return Err(error);
+                                }
+                            };
+                        println!(
+                            "{}|{}{} ($FILE_NAME){}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                            md5,
+                            path_prefix,
+                            display_path,
+                            deleted_suffix,
+                            file_identifier,
+                            file_mode_string,
+                            owner_identifier,
+                            group_identifier,
+                            size,
+                            file_name_access_time,
+                            file_name_modification_time,
+                            file_name_change_time,
+                            file_name_creation_time
+                        );
                     }
                 }
             }
@@ -933,12 +958,20 @@ impl ImageTool {
             },
             None => String::new(),
         };
+        let encryption_suffix: &str = if vfs_scan_node.is_locked {
+            " (encrypted, locked)"
+        } else if vfs_scan_node.is_encrypted {
+            " (encrypted)"
+        } else {
+            ""
+        };
         println!(
-            "{}{}: path: {}{}",
+            "{}{}: path: {}{}{}",
             indentation,
             vfs_type.as_str(),
             vfs_path.to_string(),
             suffix,
+            encryption_suffix,
         );
         for sub_scan_node in vfs_scan_node.sub_nodes.iter() {
             self.print_scan_node(sub_scan_node, depth + 1)?;
@@ -981,24 +1014,32 @@ impl ImageTool {
                 },
                 None => String::new(),
             };
+            // The deleted file entries finder uses the traversal of the allocated file entries
+            // to carve deleted file entries and determine the paths of deleted file entries.
+            let mut deleted_finder: VfsDeletedFinder =
+                VfsDeletedFinder::new_with_external_traversal(&file_system);
+
             for result in VfsFinder::new(&file_system) {
                 match result {
-                    Ok((mut file_entry, path_components)) => match self
-                        .print_file_entry_as_bodyfile(
+                    Ok((mut file_entry, path_components)) => {
+                        match self.print_file_entry_as_bodyfile(
                             &mut file_entry,
                             &display_path,
                             &path_components,
+                            false,
                             calculate_md5,
                         ) {
-                        Ok(_) => {}
-                        Err(mut error) => {
-                            keramics_core::error_trace_add_frame!(
-                                error,
-                                "Unable to print file entry"
-                            );
-                            return Err(error);
+                            Ok(_) => {}
+                            Err(mut error) => {
+                                keramics_core::error_trace_add_frame!(
+                                    error,
+                                    "Unable to print file entry"
+                                );
+                                return Err(error);
+                            }
                         }
-                    },
+                        deleted_finder.add_allocated_file_entry(&mut file_entry, &path_components);
+                    }
                     Err(mut error) => {
                         keramics_core::error_trace_add_frame!(
                             error,
@@ -1008,6 +1049,31 @@ impl ImageTool {
                     }
                 };
             }
+            // Recovery of deleted file entries is best-effort, hence deleted file entries that
+            // cannot be printed are reported and skipped.
+            for result in deleted_finder {
+                match result {
+                    Ok((mut file_entry, path_components)) => match self
+                        .print_file_entry_as_bodyfile(
+                            &mut file_entry,
+                            &display_path,
+                            &path_components,
+                            true,
+                            calculate_md5,
+                        ) {
+                        Ok(_) => {}
+                        Err(error) => {
+                            eprintln!("Unable to print deleted file entry with error:\n{}", error);
+                        }
+                    },
+                    Err(error) => {
+                        eprintln!(
+                            "Unable to retrieve deleted file entry from finder with error:\n{}",
+                            error
+                        );
+                    }
+                };
+            }
         } else {
             for sub_scan_node in vfs_scan_node.sub_nodes.iter() {
                 match self.print_scan_node_as_bodyfile(sub_scan_node, calculate_md5) {
@@ -1123,8 +1189,17 @@ fn main() -> ExitCode {
             return ExitCode::FAILURE;
         }
     };
+    let credential_callback: Option<MediatorCredentialCallback> = match &arguments.password {
+        Some(password) => {
+            let password: String = password.clone();
+
+            Some(Box::new(move |_: &str| Some(password.clone())))
+        }
+        None => None,
+    };
     Mediator {
         debug_output: arguments.debug,
+        credential_callback: credential_callback,
     }
     .make_current();
 
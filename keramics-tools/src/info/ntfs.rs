@@ -17,9 +17,11 @@ use keramics_core::{DataStreamReference, ErrorTrace};
 use keramics_datetime::DateTime;
 use keramics_formats::ntfs::constants::*;
 use keramics_formats::ntfs::{
-    NtfsAttribute, NtfsAttributeListEntry, NtfsDataFork, NtfsFileEntry, NtfsFileSystem, NtfsPath,
+    NtfsAccessControlList, NtfsAttribute, NtfsAttributeListEntry, NtfsDataFork, NtfsEfsDataField,
+    NtfsFileEntry, NtfsFileSystem, NtfsMftFile, NtfsPath, NtfsSecurityDescriptor,
 };
 
+use crate::bodyfile::Bodyfile;
 use crate::formatters::format_as_bytesize;
 
 /// Information about a New Technologies File System (NTFS).
@@ -237,8 +239,90 @@ impl NtfsInfo {
                 }
                 println!("");
             }
-            // TODO: add support for $EA
-            // TODO: add support for $EA_INFORMATION
+            NtfsAttribute::EfsInformation { efs_information } => {
+                println!("    Attribute name\t\t\t: $EFS");
+                println!(
+                    "    Format version\t\t\t: {}",
+                    efs_information.format_version
+                );
+                println!(
+                    "    Number of DDF entries\t\t: {}",
+                    efs_information.decryption_fields.len()
+                );
+                for (entry_index, data_field) in
+                    efs_information.decryption_fields.iter().enumerate()
+                {
+                    println!("    DDF entry: {}", entry_index + 1);
+                    Self::print_efs_data_field(data_field);
+                }
+                println!(
+                    "    Number of DRF entries\t\t: {}",
+                    efs_information.recovery_fields.len()
+                );
+                for (entry_index, data_field) in efs_information.recovery_fields.iter().enumerate()
+                {
+                    println!("    DRF entry: {}", entry_index + 1);
+                    Self::print_efs_data_field(data_field);
+                }
+                println!("");
+            }
+            NtfsAttribute::ExtendedAttributes {
+                extended_attributes,
+            } => {
+                let number_of_entries: usize = extended_attributes.entries.len();
+                println!("    Number of entries\t\t\t: {}", number_of_entries);
+
+                for (entry_index, entry) in extended_attributes.entries.iter().enumerate() {
+                    println!(
+                        "    Entry: {}\t\t\t\t: {} with flags: 0x{:02x} and value data size: {}",
+                        entry_index + 1,
+                        entry.name.to_string(),
+                        entry.flags,
+                        entry.value_data.len()
+                    );
+                }
+                match extended_attributes.get_wsl_file_mode() {
+                    Some(file_mode) => println!("    WSL file mode\t\t\t: 0o{:o}", file_mode),
+                    None => {}
+                };
+                match extended_attributes.get_wsl_owner_identifier() {
+                    Some(owner_identifier) => {
+                        println!("    WSL owner identifier\t\t: {}", owner_identifier)
+                    }
+                    None => {}
+                };
+                match extended_attributes.get_wsl_group_identifier() {
+                    Some(group_identifier) => {
+                        println!("    WSL group identifier\t\t: {}", group_identifier)
+                    }
+                    None => {}
+                };
+                match extended_attributes.get_wsl_device_identifier() {
+                    Some((major_device_number, minor_device_number)) => println!(
+                        "    WSL device identifier\t\t: {},{}",
+                        major_device_number, minor_device_number
+                    ),
+                    None => {}
+                };
+                println!("");
+            }
+            NtfsAttribute::ExtendedAttributesInformation {
+                extended_attributes_information,
+            } => {
+                println!(
+                    "    Packed size\t\t\t\t: {}",
+                    extended_attributes_information.packed_size
+                );
+                println!(
+                    "    Number of NEED_EA entries\t\t: {}",
+                    extended_attributes_information.number_of_need_ea_entries
+                );
+                println!(
+                    "    Unpacked size\t\t\t: {}",
+                    extended_attributes_information.unpacked_size
+                );
+                println!("");
+            }
             NtfsAttribute::FileName { file_name } => {
                 let name_spaces = HashMap::<u8, &'static str>::from([
                     (0, "POSIX"),
@@ -328,8 +412,25 @@ impl NtfsInfo {
                 }
                 println!("");
             }
-            // TODO: add support for $LOGGED_UTILITY_STREAM
-            // TODO: add support for $OBJECT_ID
+            NtfsAttribute::ObjectIdentifier { object_identifier } => {
+                println!(
+                    "    Droid file identifier\t\t: {}",
+                    object_identifier.droid_file_identifier
+                );
+                match &object_identifier.birth_droid_volume_identifier {
+                    Some(uuid) => println!("    Birth droid volume identifier\t: {}", uuid),
+                    None => {}
+                };
+                match &object_identifier.birth_droid_file_identifier {
+                    Some(uuid) => println!("    Birth droid file identifier\t\t: {}", uuid),
+                    None => {}
+                };
+                match &object_identifier.birth_droid_domain_identifier {
+                    Some(uuid) => println!("    Birth droid domain identifier\t: {}", uuid),
+                    None => {}
+                };
+                println!("");
+            }
             // TODO: add support for $PROPERTY_SET
             NtfsAttribute::ReparsePoint { reparse_point } => {
                 let reparse_tag: u32 = reparse_point.get_reparse_tag();
@@ -338,7 +439,12 @@ impl NtfsInfo {
 
                 println!("");
             }
-            // TODO: add support for $SECURITY_DESCRIPTOR
+            NtfsAttribute::SecurityDescriptor {
+                security_descriptor,
+            } => {
+                Self::print_security_descriptor(security_descriptor);
+                println!("");
+            }
             NtfsAttribute::StandardInformation {
                 standard_information,
             } => {
@@ -377,6 +483,30 @@ impl NtfsInfo {
                     println!("");
                 }
             }
+            NtfsAttribute::TransactionalData { transactional_data } => {
+                println!("    Attribute name\t\t\t: $TXF_DATA");
+                println!(
+                    "    Resource manager root reference\t: {}-{}",
+                    transactional_data.resource_manager_root_file_reference & 0x0000ffffffffffff,
+                    transactional_data.resource_manager_root_file_reference >> 48
+                );
+                println!("    USN index\t\t\t\t: {}", transactional_data.usn_index);
+                println!(
+                    "    TxF file identifier\t\t\t: 0x{:016x}",
+                    transactional_data.file_identifier
+                );
+                println!("    Data LSN\t\t\t\t: {}", transactional_data.data_lsn);
+                println!(
+                    "    Metadata LSN\t\t\t: {}",
+                    transactional_data.metadata_lsn
+                );
+                println!(
+                    "    Directory index LSN\t\t\t: {}",
+                    transactional_data.directory_index_lsn
+                );
+                println!("    Flags\t\t\t\t: 0x{:04x}", transactional_data.flags);
+                println!("");
+            }
             NtfsAttribute::VolumeInformation { volume_information } => {
                 println!(
                     "    Format version\t\t\t: {}.{}",
@@ -397,6 +527,36 @@ impl NtfsInfo {
         Ok(())
     }
 
+    /// Prints information about an access control list.
+    fn print_access_control_list(access_control_list: &NtfsAccessControlList) {
+        for (entry_index, access_control_entry) in access_control_list.entries.iter().enumerate() {
+            let entry_type_string: String = match access_control_entry.entry_type {
+                NTFS_ACE_TYPE_ACCESS_ALLOWED => String::from("Access allowed"),
+                NTFS_ACE_TYPE_ACCESS_DENIED => String::from("Access denied"),
+                NTFS_ACE_TYPE_SYSTEM_AUDIT => String::from("System audit"),
+                NTFS_ACE_TYPE_SYSTEM_ALARM => String::from("System alarm"),
+                NTFS_ACE_TYPE_ACCESS_ALLOWED_OBJECT => String::from("Access allowed object"),
+                NTFS_ACE_TYPE_ACCESS_DENIED_OBJECT => String::from("Access denied object"),
+                NTFS_ACE_TYPE_SYSTEM_AUDIT_OBJECT => String::from("System audit object"),
+                NTFS_ACE_TYPE_SYSTEM_ALARM_OBJECT => String::from("System alarm object"),
+                NTFS_ACE_TYPE_SYSTEM_MANDATORY_LABEL => String::from("System mandatory label"),
+                entry_type => format!("0x{:02x}", entry_type),
+            };
+            let sid_string: String = match &access_control_entry.security_identifier {
+                Some(security_identifier) => security_identifier.to_string(),
+                None => String::from("N/A"),
+            };
+            println!(
+                "            Entry: {}\t\t\t: {}, flags: 0x{:02x}, access mask: 0x{:08x}, SID: {}",
+                entry_index + 1,
+                entry_type_string,
+                access_control_entry.flags,
+                access_control_entry.access_mask,
+                sid_string
+            );
+        }
+    }
+
     /// Prints information about a file entry.
     fn print_file_entry(file_entry: &mut NtfsFileEntry) -> Result<(), ErrorTrace> {
         // Note that the directory entry file reference can be differrent
@@ -458,48 +618,24 @@ impl NtfsInfo {
         );
         println!("");
 
+        match file_entry.get_security_descriptor()? {
+            Some(security_descriptor) => {
+                println!("    Security descriptor:");
+                Self::print_security_descriptor(&security_descriptor);
+                println!("");
+            }
+            None => {}
+        };
         // TODO: print information about reparse point
 
         Ok(())
     }
 
-    /// Prints information about a specific file entry.
-    pub fn print_file_entry_by_identifier(
-        data_stream: &DataStreamReference,
-        ntfs_entry_identifier: u64,
-    ) -> Result<(), ErrorTrace> {
-        let mut ntfs_file_system = NtfsFileSystem::new();
-
-        match ntfs_file_system.read_data_stream(data_stream) {
-            Ok(_) => {}
-            Err(mut error) => {
-                keramics_core::error_trace_add_frame!(
-                    error,
-                    "Unable to open NTFS file file system"
-                );
-                return Err(error);
-            }
-        }
-        if ntfs_entry_identifier > u32::MAX as u64 {
-            return Err(keramics_core::error_trace_new!(format!(
-                "Unsupported identifier: {} value out of bounds",
-                ntfs_entry_identifier
-            )));
-        }
-        let file_entry: NtfsFileEntry =
-            match ntfs_file_system.get_file_entry_by_identifier(ntfs_entry_identifier) {
-                Ok(file_entry) => file_entry,
-                Err(mut error) => {
-                    keramics_core::error_trace_add_frame!(
-                        error,
-                        format!("Unable to retrieve file entry: {}", ntfs_entry_identifier)
-                    );
-                    return Err(error);
-                }
-            };
+    /// Prints information about a MFT entry.
+    fn print_mft_entry(file_entry: &NtfsFileEntry) -> Result<(), ErrorTrace> {
         println!(
             "New Technologies File System (NTFS) MFT entry: {} information:",
-            ntfs_entry_identifier
+            file_entry.mft_entry_number
         );
 
         if file_entry.is_empty() {
@@ -526,9 +662,18 @@ impl NtfsInfo {
                 file_entry.get_journal_sequence_number()
             );
 
+            if file_entry.is_corrupted() {
+                println!("    Is corrupted");
+
+                for fixup_value_mismatch in file_entry.get_fixup_value_mismatches().iter() {
+                    println!(
+                        "        Fix-up value mismatch\t\t: {}",
+                        fixup_value_mismatch
+                    );
+                }
+            }
             let number_of_attributes: usize = file_entry.get_number_of_attributes();
 
-            // TODO: print is corrupted.
             println!("");
 
             for attribute_index in 0..number_of_attributes {
@@ -540,7 +685,7 @@ impl NtfsInfo {
                                 error,
                                 format!(
                                     "Unable to retrieve file entry: {} attribute: {}",
-                                    ntfs_entry_identifier, attribute_index
+                                    file_entry.mft_entry_number, attribute_index
                                 )
                             );
                             return Err(error);
@@ -561,6 +706,125 @@ impl NtfsInfo {
         Ok(())
     }
 
+    /// Prints information about an EFS data field.
+    fn print_efs_data_field(data_field: &NtfsEfsDataField) {
+        match &data_field.security_identifier {
+            Some(security_identifier) => {
+                println!("        SID\t\t\t\t: {}", security_identifier)
+            }
+            None => {}
+        };
+        match &data_field.certificate_thumbprint {
+            Some(certificate_thumbprint) => println!(
+                "        Certificate thumbprint\t\t: {}",
+                certificate_thumbprint
+                    .iter()
+                    .map(|byte_value| format!("{:02x}", byte_value))
+                    .collect::<Vec<String>>()
+                    .join("")
+            ),
+            None => {}
+        };
+        match &data_field.container_name {
+            Some(name) => println!("        Container name\t\t\t: {}", name.to_string()),
+            None => {}
+        };
+        match &data_field.provider_name {
+            Some(name) => println!("        Provider name\t\t\t: {}", name.to_string()),
+            None => {}
+        };
+        match &data_field.user_name {
+            Some(name) => println!("        User name\t\t\t: {}", name.to_string()),
+            None => {}
+        };
+    }
+
+    /// Prints information about a security descriptor.
+    fn print_security_descriptor(security_descriptor: &NtfsSecurityDescriptor) {
+        println!(
+            "        Control flags\t\t\t: 0x{:04x}",
+            security_descriptor.control_flags
+        );
+        match &security_descriptor.owner {
+            Some(security_identifier) => {
+                println!("        Owner SID\t\t\t: {}", security_identifier)
+            }
+            None => {}
+        };
+        match &security_descriptor.group {
+            Some(security_identifier) => {
+                println!("        Group SID\t\t\t: {}", security_identifier)
+            }
+            None => {}
+        };
+        match &security_descriptor.dacl {
+            Some(access_control_list) => {
+                println!("        Discretionary ACL:");
+                Self::print_access_control_list(access_control_list);
+            }
+            None => {}
+        };
+        match &security_descriptor.sacl {
+            Some(access_control_list) => {
+                println!("        System ACL:");
+                Self::print_access_control_list(access_control_list);
+            }
+            None => {}
+        };
+    }
+
+    /// Prints information about a specific file entry.
+    pub fn print_file_entry_by_identifier(
+        data_stream: &DataStreamReference,
+        ntfs_entry_identifier: u64,
+    ) -> Result<(), ErrorTrace> {
+        let mut ntfs_file_system = NtfsFileSystem::new();
+
+        // Use lenient mode so that corrupted MFT entries can be inspected.
+        match ntfs_file_system.set_lenient_mode(true) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to set lenient mode");
+                return Err(error);
+            }
+        }
+        match ntfs_file_system.read_data_stream(data_stream) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to open NTFS file file system"
+                );
+                return Err(error);
+            }
+        }
+        if ntfs_entry_identifier > u32::MAX as u64 {
+            return Err(keramics_core::error_trace_new!(format!(
+                "Unsupported identifier: {} value out of bounds",
+                ntfs_entry_identifier
+            )));
+        }
+        let file_entry: NtfsFileEntry =
+            match ntfs_file_system.get_file_entry_by_identifier(ntfs_entry_identifier) {
+                Ok(file_entry) => file_entry,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to retrieve file entry: {}", ntfs_entry_identifier)
+                    );
+                    return Err(error);
+                }
+            };
+        match Self::print_mft_entry(&file_entry) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to print MFT entry");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
     /// Prints information about a specific file entry.
     pub fn print_file_entry_by_path(
         data_stream: &DataStreamReference,
@@ -815,6 +1079,302 @@ impl NtfsInfo {
 
         Ok(())
     }
+
+    /// Opens a standalone $MFT file.
+    fn open_mft_file(
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+    ) -> Result<NtfsMftFile, ErrorTrace> {
+        let mut mft_file: NtfsMftFile = NtfsMftFile::new();
+
+        // Use lenient mode so that corrupted MFT entries are read on a best-effort basis.
+        match mft_file.set_lenient_mode(true) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to set lenient mode");
+                return Err(error);
+            }
+        }
+        match mft_file.read_data_stream(data_stream, mft_entry_size) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to open NTFS $MFT file");
+                return Err(error);
+            }
+        }
+        Ok(mft_file)
+    }
+
+    /// Retrieves a bodyfile file mode string representation of a file entry.
+    fn get_bodyfile_file_mode_string(file_entry: &NtfsFileEntry) -> String {
+        let mut string_parts: Vec<&str> = vec!["-", "r", "w", "x", "r", "w", "x", "r", "w", "x"];
+
+        if file_entry.is_symbolic_link() {
+            string_parts[0] = "l";
+        } else if file_entry.is_directory() {
+            string_parts[0] = "d";
+        }
+        // Read-only (FILE_ATTRIBUTE_READ_ONLY) or system (FILE_ATTRIBUTE_SYSTEM).
+        if file_entry.get_file_attribute_flags() & 0x00000005 != 0 {
+            string_parts[2] = "-";
+            string_parts[5] = "-";
+            string_parts[8] = "-";
+        }
+        string_parts.join("")
+    }
+
+    /// Retrieves a string representation of a path.
+    fn get_path_string(path: &NtfsPath) -> String {
+        if path.components.len() == 1 && path.components[0].is_empty() {
+            String::from("/")
+        } else {
+            path.components
+                .iter()
+                .map(|component| component.to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+    }
+
+    /// Prints information about a standalone $MFT file.
+    pub fn print_mft_file(
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+    ) -> Result<(), ErrorTrace> {
+        let mft_file: NtfsMftFile = Self::open_mft_file(data_stream, mft_entry_size)?;
+
+        println!("New Technologies File System (NTFS) Master File Table (MFT) information:");
+        println!(
+            "    MFT entry size\t\t\t: {} bytes",
+            mft_file.get_mft_entry_size()
+        );
+        println!(
+            "    Number of MFT entries\t\t: {}",
+            mft_file.get_number_of_file_entries()
+        );
+        println!("");
+
+        Ok(())
+    }
+
+    /// Prints the file entries of a standalone $MFT file in bodyfile format.
+    ///
+    /// Since there is no volume only resident data can be hashed, the MD5 of a file entry with
+    /// non-resident data is "0".
+    pub fn print_mft_file_as_bodyfile(
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+        calculate_md5: bool,
+    ) -> Result<(), ErrorTrace> {
+        let mut mft_file: NtfsMftFile = Self::open_mft_file(data_stream, mft_entry_size)?;
+
+        println!("{}", Bodyfile::FILE_HEADER);
+
+        let number_of_file_entries: u64 = mft_file.get_number_of_file_entries();
+
+        for mft_entry_number in 0..number_of_file_entries {
+            // Note that MFT entries that cannot be read, for example leftover entries that
+            // are corrupted, are reported and skipped.
+            let file_entry: NtfsFileEntry =
+                match mft_file.get_file_entry_by_identifier(mft_entry_number) {
+                    Ok(file_entry) => file_entry,
+                    Err(error) => {
+                        eprintln!(
+                            "Unable to retrieve file entry: {} with error:\n{}",
+                            mft_entry_number, error
+                        );
+                        continue;
+                    }
+                };
+            // Note that extension MFT entries are part of their base record MFT entry.
+            if file_entry.is_empty()
+                || file_entry.is_bad()
+                || file_entry.get_base_record_file_reference() != 0
+            {
+                continue;
+            }
+            let path: NtfsPath = match mft_file.get_path_of_file_entry(&file_entry) {
+                Some(path) => path,
+                None => continue,
+            };
+            let md5: String = if !calculate_md5 {
+                String::from("0")
+            } else {
+                // Note that the data stream of non-resident data is not available.
+                match file_entry.get_data_stream() {
+                    Ok(Some(data_stream)) => match Bodyfile::calculate_md5(&data_stream) {
+                        Ok(md5_string) => md5_string,
+                        Err(_) => String::from("0"),
+                    },
+                    Ok(None) => String::from("00000000000000000000000000000000"),
+                    Err(_) => String::from("0"),
+                }
+            };
+            let path_string: String = Self::get_path_string(&path);
+            let deleted_suffix: &str = if file_entry.is_allocated() {
+                ""
+            } else {
+                " (deleted)"
+            };
+            let file_identifier: String =
+                format!("{}-{}", mft_entry_number, file_entry.sequence_number);
+            let file_mode_string: String = Self::get_bodyfile_file_mode_string(&file_entry);
+            let size: u64 = file_entry.get_size();
+
+            let access_time: String =
+                match Bodyfile::format_as_timestamp(file_entry.get_access_time()) {
+                    Ok(timestamp_string) => timestamp_string,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to format access time"
+                        );
+                        return Err(error);
+                    }
+                };
+            let modification_time: String =
+                match Bodyfile::format_as_timestamp(file_entry.get_modification_time()) {
+                    Ok(timestamp_string) => timestamp_string,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to format modification time"
+                        );
+                        return Err(error);
+                    }
+                };
+            let change_time: String =
+                match Bodyfile::format_as_timestamp(file_entry.get_change_time()) {
+                    Ok(timestamp_string) => timestamp_string,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to format change time"
+                        );
+                        return Err(error);
+                    }
+                };
+            let creation_time: String =
+                match Bodyfile::format_as_timestamp(file_entry.get_creation_time()) {
+                    Ok(timestamp_string) => timestamp_string,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to format creation time"
+                        );
+                        return Err(error);
+                    }
+                };
+            println!(
+                "{}|{}{}|{}|{}|||{}|{}|{}|{}|{}",
+                md5,
+                path_string,
+                deleted_suffix,
+                file_identifier,
+                file_mode_string,
+                size,
+                access_time,
+                modification_time,
+                change_time,
+                creation_time
+            );
+            // TODO: add support for alternate data streams.
+
+            if let Some(file_name) = file_entry.get_directory_entry_file_name() {
+                let file_name_access_time: String =
+                    match Bodyfile::format_as_timestamp(Some(&file_name.access_time)) {
+                        Ok(timestamp_string) => timestamp_string,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to format $FILE_NAME access time"
+                            );
+                            return Err(error);
+                        }
+                    };
+                let file_name_modification_time: String =
+                    match Bodyfile::format_as_timestamp(Some(&file_name.modification_time)) {
+                        Ok(timestamp_string) => timestamp_string,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to format $FILE_NAME modification time"
+                            );
+                            return Err(error);
+                        }
+                    };
+                let file_name_change_time: String =
+                    match Bodyfile::format_as_timestamp(Some(&file_name.entry_modification_time)) {
+                        Ok(timestamp_string) => timestamp_string,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to format $FILE_NAME entry modification time"
+                            );
+                            return Err(error);
+                        }
+                    };
+                let file_name_creation_time: String =
+                    match Bodyfile::format_as_timestamp(Some(&file_name.creation_time)) {
+                        Ok(timestamp_string) => timestamp_string,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to format $FILE_NAME creation time"
+                            );
+                            return Err(error);
+                        }
+                    };
+                println!(
+                    "{}|{} ($FILE_NAME){}|{}|{}|||{}|{}|{}|{}|{}",
+                    md5,
+                    path_string,
+                    deleted_suffix,
+                    file_identifier,
+                    file_mode_string,
+                    size,
+                    file_name_access_time,
+                    file_name_modification_time,
+                    file_name_change_time,
+                    file_name_creation_time
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints information about a specific file entry of a standalone $MFT file.
+    pub fn print_mft_file_entry_by_identifier(
+        data_stream: &DataStreamReference,
+        mft_entry_size: Option<u32>,
+        ntfs_entry_identifier: u64,
+    ) -> Result<(), ErrorTrace> {
+        let mut mft_file: NtfsMftFile = Self::open_mft_file(data_stream, mft_entry_size)?;
+
+        let file_entry: NtfsFileEntry =
+            match mft_file.get_file_entry_by_identifier(ntfs_entry_identifier) {
+                Ok(file_entry) => file_entry,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to retrieve file entry: {}", ntfs_entry_identifier)
+                    );
+                    return Err(error);
+                }
+            };
+        match Self::print_mft_entry(&file_entry) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to print MFT entry");
+                return Err(error);
+            }
+        }
+        match mft_file.get_path_of_file_entry(&file_entry) {
+            Some(path) => println!("    Path\t\t\t\t: {}", Self::get_path_string(&path)),
+            None => {}
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -836,6 +1396,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_path_string() {
+        let path: NtfsPath = NtfsPath::from("\\");
+        assert_eq!(NtfsInfo::get_path_string(&path), "/");
+
+        let path: NtfsPath = NtfsPath::from("\\$OrphanFiles\\file.txt");
+        assert_eq!(NtfsInfo::get_path_string(&path), "/$OrphanFiles/file.txt");
+    }
+
     #[test]
     fn test_get_file_attribute_flags_strings() {
         let flags_strings: Vec<String> = NtfsInfo::get_file_attribute_flags_strings(0x00000001);
@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 
 use keramics_core::{DataStreamReference, ErrorTrace};
-use keramics_formats::qcow::{QcowCompressionMethod, QcowEncryptionMethod, QcowFile};
+use keramics_formats::qcow::{QcowCompressionMethod, QcowEncryptionMethod, QcowFile, QcowSnapshot};
 
 use crate::formatters::format_as_bytesize;
 
@@ -36,6 +36,7 @@ impl QcowInfo {
         let compression_methods = HashMap::<QcowCompressionMethod, &'static str>::from([
             (QcowCompressionMethod::Unknown, "Unknown"),
             (QcowCompressionMethod::Zlib, "zlib"),
+            (QcowCompressionMethod::Zstd, "zstd"),
         ]);
         let encryption_methods = HashMap::<QcowEncryptionMethod, &'static str>::from([
             (QcowEncryptionMethod::AesCbc128, "AES-CBC 128-bit"),
@@ -78,9 +79,49 @@ impl QcowInfo {
             }
             None => {}
         }
+        let number_of_snapshots: usize = qcow_file.get_number_of_snapshots();
+        println!("    Number of snapshots\t\t\t: {}", number_of_snapshots);
+
         // TODO: print feature flags.
-        // TODO: print snapshot information.
 
+        for snapshot_index in 0..number_of_snapshots {
+            println!("");
+
+            let qcow_snapshot: &QcowSnapshot = match qcow_file.get_snapshot_by_index(snapshot_index)
+            {
+                Ok(snapshot) => snapshot,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        format!("Unable to retrieve QCOW snapshot: {}", snapshot_index)
+                    );
+                    return Err(error);
+                }
+            };
+            println!("Snapshot: {}", snapshot_index + 1);
+            println!(
+                "    Identifier\t\t\t\t: {}",
+                qcow_snapshot.identifier.to_string()
+            );
+            println!("    Name\t\t\t\t: {}", qcow_snapshot.name.to_string());
+            println!(
+                "    Date\t\t\t\t: {}",
+                qcow_snapshot.date.to_iso8601_string()
+            );
+            println!(
+                "    VM clock\t\t\t\t: {} nanoseconds",
+                qcow_snapshot.vm_clock
+            );
+            if qcow_snapshot.media_size < 1024 {
+                println!("    Media size\t\t\t\t: {} bytes", qcow_snapshot.media_size);
+            } else {
+                let media_size_string: String = format_as_bytesize(qcow_snapshot.media_size, 1024);
+                println!(
+                    "    Media size\t\t\t\t: {} ({} bytes)",
+                    media_size_string, qcow_snapshot.media_size
+                );
+            }
+        }
         println!("");
 
         Ok(())
@@ -19,11 +19,12 @@ use std::sync::{Arc, RwLock};
 use clap::{Args, Parser, Subcommand};
 use clap_num::maybe_hex;
 
-use keramics_core::mediator::Mediator;
+use keramics_core::mediator::{Mediator, MediatorCredentialCallback};
 use keramics_core::{DataStreamReference, ErrorTrace};
 use keramics_encodings::CharacterEncoding;
 use keramics_formats::{FormatIdentifier, FormatScanner};
 
+mod bodyfile;
 mod enums;
 mod formatters;
 mod info;
@@ -43,10 +44,23 @@ struct CommandLineArguments {
     /// Enable debug output
     debug: bool,
 
+    #[arg(long)]
+    /// Password or passphrase to unlock encrypted storage media
+    password: Option<String>,
+
     /// Character encoding
     #[arg(long, value_enum)]
     encoding: Option<EncodingType>,
 
+    #[arg(long, default_value_t = false)]
+    /// Treat the source as a standalone NTFS $MFT file
+    mft: bool,
+
+    #[arg(long)]
+    /// MFT entry size of a standalone NTFS $MFT file, if not provided it is determined from
+    /// the first MFT entry
+    mft_entry_size: Option<u32>,
+
     #[arg(short, long, default_value_t = 0, value_parser=maybe_hex::<u64>)]
     /// Offset within the source file
     offset: u64,
@@ -60,6 +74,9 @@ struct CommandLineArguments {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
+    /// Output file entries in bodyfile format
+    Bodyfile(BodyfileCommandArguments),
+
     /// Show the information about a specific entry
     Entry(EntryCommandArguments),
 
@@ -70,6 +87,13 @@ enum Commands {
     Path(PathCommandArguments),
 }
 
+#[derive(Args, Debug)]
+struct BodyfileCommandArguments {
+    #[arg(long, default_value_t = false)]
+    /// Calculate MD5 hashes of the content of file entries
+    calculate_md5: bool,
+}
+
 #[derive(Args, Debug)]
 struct EntryCommandArguments {
     /// Format specific entry identifier
@@ -198,6 +222,28 @@ fn main() -> ExitCode {
         Some(EncodingType::Iso8859_14) => Some(CharacterEncoding::Iso8859_14),
         Some(EncodingType::Iso8859_15) => Some(CharacterEncoding::Iso8859_15),
         Some(EncodingType::Iso8859_16) => Some(CharacterEncoding::Iso8859_16),
+        Some(EncodingType::MacArabic) => Some(CharacterEncoding::MacArabic),
+        Some(EncodingType::MacCeltic) => Some(CharacterEncoding::MacCeltic),
+        Some(EncodingType::MacCentralEurRoman) => Some(CharacterEncoding::MacCentralEurRoman),
+        Some(EncodingType::MacChineseSimplified) => Some(CharacterEncoding::MacChineseSimplified),
+        Some(EncodingType::MacChineseTraditional) => Some(CharacterEncoding::MacChineseTraditional),
+        Some(EncodingType::MacCroatian) => Some(CharacterEncoding::MacCroatian),
+        Some(EncodingType::MacCyrillic) => Some(CharacterEncoding::MacCyrillic),
+        Some(EncodingType::MacDingbats) => Some(CharacterEncoding::MacDingbats),
+        Some(EncodingType::MacFarsi) => Some(CharacterEncoding::MacFarsi),
+        Some(EncodingType::MacGaelic) => Some(CharacterEncoding::MacGaelic),
+        Some(EncodingType::MacGreek) => Some(CharacterEncoding::MacGreek),
+        Some(EncodingType::MacHebrew) => Some(CharacterEncoding::MacHebrew),
+        Some(EncodingType::MacIcelandic) => Some(CharacterEncoding::MacIcelandic),
+        Some(EncodingType::MacInuit) => Some(CharacterEncoding::MacInuit),
+        Some(EncodingType::MacJapanese) => Some(CharacterEncoding::MacJapanese),
+        Some(EncodingType::MacKorean) => Some(CharacterEncoding::MacKorean),
+        Some(EncodingType::MacRoman) => Some(CharacterEncoding::MacRoman),
+        Some(EncodingType::MacRussian) => Some(CharacterEncoding::MacRussian),
+        Some(EncodingType::MacSymbol) => Some(CharacterEncoding::MacSymbol),
+        Some(EncodingType::MacThai) => Some(CharacterEncoding::MacThai),
+        Some(EncodingType::MacTurkish) => Some(CharacterEncoding::MacTurkish),
+        Some(EncodingType::MacUkrainian) => Some(CharacterEncoding::MacUkrainian),
         Some(EncodingType::Utf8) => Some(CharacterEncoding::Utf8),
         None => None,
     };
@@ -212,6 +258,39 @@ fn main() -> ExitCode {
     };
     let data_stream: DataStreamReference = Arc::new(RwLock::new(file_range_stream));
 
+    if arguments.mft {
+        Mediator {
+            debug_output: arguments.debug,
+            credential_callback: None,
+        }
+        .make_current();
+
+        let result: Result<(), ErrorTrace> = match arguments.command {
+            Some(Commands::Bodyfile(command_arguments)) => NtfsInfo::print_mft_file_as_bodyfile(
+                &data_stream,
+                arguments.mft_entry_size,
+                command_arguments.calculate_md5,
+            ),
+            Some(Commands::Entry(command_arguments)) => {
+                NtfsInfo::print_mft_file_entry_by_identifier(
+                    &data_stream,
+                    arguments.mft_entry_size,
+                    command_arguments.entry,
+                )
+            }
+            None => NtfsInfo::print_mft_file(&data_stream, arguments.mft_entry_size),
+            _ => Err(keramics_core::error_trace_new!(
+                "Unsupported command for standalone $MFT file"
+            )),
+        };
+        return match result {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(error) => {
+                println!("Unable to provide information about: {}\n{}", source, error);
+                ExitCode::FAILURE
+            }
+        };
+    }
     let result: Option<FormatIdentifier> = match scan_for_formats(&data_stream) {
         Ok(result) => result,
         Err(error) => {
@@ -229,12 +308,25 @@ fn main() -> ExitCode {
             return ExitCode::FAILURE;
         }
     };
+    let credential_callback: Option<MediatorCredentialCallback> = match &arguments.password {
+        Some(password) => {
+            let password: String = password.clone();
+
+            Some(Box::new(move |_: &str| Some(password.clone())))
+        }
+        None => None,
+    };
     Mediator {
         debug_output: arguments.debug,
+        credential_callback: credential_callback,
     }
     .make_current();
 
     let result: Result<(), ErrorTrace> = match arguments.command {
+        Some(Commands::Bodyfile(_)) => Err(keramics_core::error_trace_new!(format!(
+            "Unsupported format: {}, bodyfile output requires a standalone $MFT file",
+            format_identifier.to_string()
+        ))),
         Some(Commands::Entry(command_arguments)) => match &format_identifier {
             FormatIdentifier::Ext => ExtInfo::print_file_entry_by_identifier(
                 &data_stream,
@@ -14,7 +14,7 @@
 use keramics_core::ErrorTrace;
 use keramics_encodings::{
     CharacterDecoder, CharacterEncoder, CharacterEncoding, new_character_decoder,
-    new_character_encoder,
+    try_new_character_encoder,
 };
 
 /// String of 8-bit elements.
@@ -80,7 +80,16 @@ impl ByteString {
     /// Extends the string from code points.
     pub fn extend_from_codepoints(&mut self, code_points: &Vec<u32>) -> Result<(), ErrorTrace> {
         let mut character_encoder: CharacterEncoder =
-            new_character_encoder(&self.encoding, code_points);
+            match try_new_character_encoder(&self.encoding, code_points) {
+                Ok(character_encoder) => character_encoder,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to create character encoder"
+                    );
+                    return Err(error);
+                }
+            };
 
         while let Some(result) = character_encoder.next() {
             match result {
@@ -111,8 +120,18 @@ impl ByteString {
 
     /// Reads the string from a buffer.
     pub fn read_data(&mut self, data: &[u8]) {
-        let slice: &[u8] = match data.iter().position(|value| *value == 0) {
-            Some(data_index) => &data[0..data_index],
+        let element_size: usize = match self.encoding {
+            CharacterEncoding::Ucs2
+            | CharacterEncoding::Utf16BigEndian
+            | CharacterEncoding::Utf16LittleEndian => 2,
+            CharacterEncoding::Utf32BigEndian | CharacterEncoding::Utf32LittleEndian => 4,
+            _ => 1,
+        };
+        let slice: &[u8] = match data
+            .chunks(element_size)
+            .position(|chunk| chunk.iter().all(|value| *value == 0))
+        {
+            Some(chunk_index) => &data[0..chunk_index * element_size],
             None => &data,
         };
         self.elements.extend_from_slice(&slice);
@@ -246,6 +265,18 @@ mod tests {
         assert_eq!(byte_string.len(), 12);
     }
 
+    #[test]
+    fn test_read_data_with_utf16() {
+        let mut byte_string: ByteString =
+            ByteString::new_with_encoding(&CharacterEncoding::Utf16LittleEndian);
+        assert_eq!(byte_string.len(), 0);
+
+        let test_data: [u8; 10] = [0x00, 0x01, 0x55, 0x00, 0x31, 0x00, 0x00, 0x00, 0x36, 0x00];
+        byte_string.read_data(&test_data);
+        assert_eq!(byte_string.len(), 6);
+        assert_eq!(byte_string.to_string(), String::from("\u{100}U1"));
+    }
+
     // TODO: add test for to_string
 
     #[test]
@@ -127,6 +127,48 @@ impl Ucs2String {
         Ordering::Equal
     }
 
+    /// Compares two strings with case folding applied.
+    ///
+    /// The case folding mappings are applied to the elements of both strings, which
+    /// corresponds to the collation used by NTFS file name indexes.
+    pub fn compare_with_case_folding(
+        &self,
+        other: &Self,
+        mappings: &HashMap<u16, u16>,
+    ) -> Ordering {
+        let self_size: usize = self.elements.len();
+        let other_size: usize = other.len();
+
+        let mut element_index: usize = 0;
+        while element_index < self_size && element_index < other_size {
+            let mut self_element: u16 = self.elements[element_index];
+            let mut other_element: u16 = other.elements[element_index];
+
+            if self_element != other_element {
+                if let Some(&value) = mappings.get(&self_element) {
+                    self_element = value;
+                }
+                if let Some(&value) = mappings.get(&other_element) {
+                    other_element = value;
+                }
+                if self_element < other_element {
+                    return Ordering::Less;
+                }
+                if self_element > other_element {
+                    return Ordering::Greater;
+                }
+            }
+            element_index += 1;
+        }
+        if element_index < other_size {
+            return Ordering::Less;
+        }
+        if element_index < self_size {
+            return Ordering::Greater;
+        }
+        Ordering::Equal
+    }
+
     /// Converts a [`Ucs2String`] to a [`String`].
     pub fn to_string(&self) -> String {
         self.elements
@@ -189,7 +231,27 @@ impl From<&Vec<u16>> for Ucs2String {
 mod tests {
     use super::*;
 
-    // TODO: add tests for new_with_case_folding
+    fn get_case_folding_mappings() -> HashMap<u16, u16> {
+        let mut mappings: HashMap<u16, u16> = HashMap::new();
+        for character_value in 0x0061..=0x007a {
+            mappings.insert(character_value, character_value - 0x0020);
+        }
+        mappings
+    }
+
+    #[test]
+    fn test_new_with_case_folding() {
+        let mappings: HashMap<u16, u16> = get_case_folding_mappings();
+
+        let ucs2_string: Ucs2String = Ucs2String::from("System32");
+        let case_folded_string: Ucs2String =
+            Ucs2String::new_with_case_folding(&ucs2_string, &mappings);
+
+        let expected_elements: Vec<u16> = vec![
+            0x0053, 0x0059, 0x0053, 0x0054, 0x0045, 0x004d, 0x0033, 0x0032,
+        ];
+        assert_eq!(case_folded_string.elements, expected_elements);
+    }
 
     #[test]
     fn test_from_be_bytes() {
@@ -273,6 +335,51 @@ mod tests {
         assert_eq!(ucs2_string.compare(&compare_ucs2_string), Ordering::Less);
     }
 
+    #[test]
+    fn test_compare_with_case_folding() {
+        let mappings: HashMap<u16, u16> = get_case_folding_mappings();
+
+        let ucs2_string: Ucs2String = Ucs2String::from("string1");
+
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("STRING1");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Equal
+        );
+
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("String0");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Greater
+        );
+
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("sTRING2");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Less
+        );
+
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("STRING");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Greater
+        );
+
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("STRING10");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Less
+        );
+
+        // Note that "_" (0x5f) sorts before "a" (0x61) but after "A" (0x41).
+        let ucs2_string: Ucs2String = Ucs2String::from("a");
+        let compare_ucs2_string: Ucs2String = Ucs2String::from("_");
+        assert_eq!(
+            ucs2_string.compare_with_case_folding(&compare_ucs2_string, &mappings),
+            Ordering::Less
+        );
+    }
+
     #[test]
     fn test_to_string() {
         let test_data: [u8; 24] = [
@@ -25,8 +25,8 @@ use super::types::VfsFileSystemReference;
 
 /// Virtual File System (VFS) context.
 pub struct VfsContext {
-    /// File systems.
-    file_systems: HashMap<VfsLocation, Weak<VfsFileSystem>>,
+    /// File systems, keyed by type and parent location.
+    file_systems: HashMap<(VfsType, VfsLocation), Weak<VfsFileSystem>>,
 
     /// Operating system (OS) file system path.
     os_vfs_location: VfsLocation,
@@ -90,12 +90,12 @@ impl VfsContext {
         };
         let parent_vfs_location: Option<&VfsLocation> = vfs_location.get_parent();
 
-        let lookup_key: &VfsLocation = match parent_vfs_location {
-            Some(parent_vfs_location) => parent_vfs_location,
-            None => &self.os_vfs_location,
+        let lookup_key: (VfsType, VfsLocation) = match parent_vfs_location {
+            Some(parent_vfs_location) => (vfs_type.clone(), parent_vfs_location.clone()),
+            None => (vfs_type.clone(), self.os_vfs_location.clone()),
         };
         let cached_file_system: Option<VfsFileSystemReference> =
-            match self.file_systems.get(lookup_key) {
+            match self.file_systems.get(&lookup_key) {
                 Some(file_system) => file_system.upgrade(),
                 None => None,
             };
@@ -131,7 +131,7 @@ impl VfsContext {
                     VfsFileSystemReference::new(file_system);
 
                 self.file_systems.insert(
-                    file_system_path,
+                    lookup_key,
                     VfsFileSystemReference::downgrade(&cached_file_system),
                 );
                 Ok(cached_file_system)
@@ -20,7 +20,9 @@ use super::string::VfsString;
 pub enum VfsDataFork<'a> {
     Ext(DataStreamReference),
     Fat(DataStreamReference),
+    Gzip(DataStreamReference),
     Ntfs(NtfsDataFork<'a>),
+    Vss(DataStreamReference),
 }
 
 impl<'a> VfsDataFork<'a> {
@@ -29,7 +31,9 @@ impl<'a> VfsDataFork<'a> {
         match self {
             VfsDataFork::Ext(data_stream) => Ok(data_stream.clone()),
             VfsDataFork::Fat(data_stream) => Ok(data_stream.clone()),
+            VfsDataFork::Gzip(data_stream) => Ok(data_stream.clone()),
             VfsDataFork::Ntfs(data_fork) => data_fork.get_data_stream(),
+            VfsDataFork::Vss(data_stream) => Ok(data_stream.clone()),
         }
     }
 
@@ -38,10 +42,12 @@ impl<'a> VfsDataFork<'a> {
         match self {
             VfsDataFork::Ext(_) => None,
             VfsDataFork::Fat(_) => None,
+            VfsDataFork::Gzip(_) => None,
             VfsDataFork::Ntfs(data_fork) => match data_fork.get_name() {
                 Some(name) => Some(VfsString::Ucs2(name.clone())),
                 None => None,
             },
+            VfsDataFork::Vss(_) => None,
         }
     }
 }
@@ -33,6 +33,7 @@ pub enum VfsType {
     Fake,
     Fat,
     Gpt,
+    Gzip,
     Mbr,
     Ntfs,
     Os,
@@ -41,6 +42,7 @@ pub enum VfsType {
     Udif,
     Vhd,
     Vhdx,
+    Vss,
 }
 
 impl VfsType {
@@ -53,6 +55,7 @@ impl VfsType {
             VfsType::Fake => "FAKE",
             VfsType::Fat => "FAT",
             VfsType::Gpt => "GPT",
+            VfsType::Gzip => "GZIP",
             VfsType::Mbr => "MBR",
             VfsType::Ntfs => "NTFS",
             VfsType::Os => "OS",
@@ -61,6 +64,7 @@ impl VfsType {
             VfsType::Udif => "UDIF",
             VfsType::Vhd => "VHD",
             VfsType::Vhdx => "VHDX",
+            VfsType::Vss => "VSS",
         }
     }
 }
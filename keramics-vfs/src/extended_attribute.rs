@@ -0,0 +1,39 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use keramics_formats::ext::ExtAttribute;
+
+use super::string::VfsString;
+
+/// Virtual File System (VFS) extended attribute.
+pub enum VfsExtendedAttribute<'a> {
+    Ext(&'a ExtAttribute),
+}
+
+impl<'a> VfsExtendedAttribute<'a> {
+    /// Retrieves the name.
+    pub fn get_name(&self) -> VfsString {
+        match self {
+            VfsExtendedAttribute::Ext(ext_attribute) => {
+                VfsString::Byte(ext_attribute.get_name().clone())
+            }
+        }
+    }
+
+    /// Retrieves the value data.
+    pub fn get_value_data(&self) -> &[u8] {
+        match self {
+            VfsExtendedAttribute::Ext(ext_attribute) => ext_attribute.get_value_data(),
+        }
+    }
+}
@@ -25,8 +25,10 @@ use super::apm::ApmFileEntry;
 use super::data_fork::VfsDataFork;
 use super::enums::VfsFileType;
 use super::ewf::EwfFileEntry;
+use super::extended_attribute::VfsExtendedAttribute;
 use super::fake::FakeFileEntry;
 use super::gpt::GptFileEntry;
+use super::gzip::GzipFileEntry;
 use super::iterators::VfsFileEntriesIterator;
 use super::mbr::MbrFileEntry;
 use super::os::OsFileEntry;
@@ -37,6 +39,7 @@ use super::string::VfsString;
 use super::udif::UdifFileEntry;
 use super::vhd::VhdFileEntry;
 use super::vhdx::VhdxFileEntry;
+use super::vss::VssFileEntry;
 
 /// Virtual File System (VFS) file entry.
 pub enum VfsFileEntry {
@@ -46,6 +49,7 @@ pub enum VfsFileEntry {
     Fake(Arc<FakeFileEntry>),
     Fat(FatFileEntry),
     Gpt(GptFileEntry),
+    Gzip(GzipFileEntry),
     Mbr(MbrFileEntry),
     Ntfs(NtfsFileEntry),
     Os(OsFileEntry),
@@ -54,6 +58,7 @@ pub enum VfsFileEntry {
     Udif(UdifFileEntry),
     Vhd(VhdFileEntry),
     Vhdx(VhdxFileEntry),
+    Vss(VssFileEntry),
 }
 
 impl VfsFileEntry {
@@ -63,12 +68,14 @@ impl VfsFileEntry {
             VfsFileEntry::Apm(_)
             | VfsFileEntry::Ewf(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => None,
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => None,
             VfsFileEntry::Ext(ext_file_entry) => ext_file_entry.get_access_time(),
             VfsFileEntry::Fake(fake_file_entry) => fake_file_entry.get_access_time(),
             VfsFileEntry::Fat(fat_file_entry) => fat_file_entry.get_access_time(),
@@ -84,12 +91,14 @@ impl VfsFileEntry {
             | VfsFileEntry::Ewf(_)
             | VfsFileEntry::Fat(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => None,
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => None,
             VfsFileEntry::Ext(ext_file_entry) => ext_file_entry.get_change_time(),
             VfsFileEntry::Fake(fake_file_entry) => fake_file_entry.get_change_time(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => ntfs_file_entry.get_change_time(),
@@ -103,6 +112,7 @@ impl VfsFileEntry {
             VfsFileEntry::Apm(_)
             | VfsFileEntry::Ewf(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
@@ -114,6 +124,7 @@ impl VfsFileEntry {
             VfsFileEntry::Fat(fat_file_entry) => fat_file_entry.get_creation_time(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => ntfs_file_entry.get_creation_time(),
             VfsFileEntry::Os(os_file_entry) => os_file_entry.get_creation_time(),
+            VfsFileEntry::Vss(vss_file_entry) => vss_file_entry.get_creation_time(),
         }
     }
 
@@ -145,6 +156,7 @@ impl VfsFileEntry {
                 }
             }
             VfsFileEntry::Gpt(gpt_file_entry) => gpt_file_entry.get_file_type(),
+            VfsFileEntry::Gzip(gzip_file_entry) => gzip_file_entry.get_file_type(),
             VfsFileEntry::Mbr(mbr_file_entry) => mbr_file_entry.get_file_type(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => {
                 if ntfs_file_entry.is_symbolic_link() {
@@ -163,6 +175,7 @@ impl VfsFileEntry {
             VfsFileEntry::Udif(udif_file_entry) => udif_file_entry.get_file_type(),
             VfsFileEntry::Vhd(vhd_file_entry) => vhd_file_entry.get_file_type(),
             VfsFileEntry::Vhdx(vhdx_file_entry) => vhdx_file_entry.get_file_type(),
+            VfsFileEntry::Vss(vss_file_entry) => vss_file_entry.get_file_type(),
         }
     }
 
@@ -172,12 +185,14 @@ impl VfsFileEntry {
             VfsFileEntry::Apm(_)
             | VfsFileEntry::Ewf(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => None,
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => None,
             VfsFileEntry::Ext(ext_file_entry) => ext_file_entry.get_modification_time(),
             VfsFileEntry::Fake(fake_file_entry) => fake_file_entry.get_modification_time(),
             VfsFileEntry::Fat(fat_file_entry) => fat_file_entry.get_modification_time(),
@@ -213,6 +228,10 @@ impl VfsFileEntry {
                 },
                 None => None,
             },
+            VfsFileEntry::Gzip(gzip_file_entry) => match gzip_file_entry.get_name() {
+                Some(name) => Some(VfsString::String(name)),
+                None => None,
+            },
             VfsFileEntry::Mbr(mbr_file_entry) => match mbr_file_entry.get_name() {
                 Some(name) => Some(VfsString::String(name)),
                 None => None,
@@ -247,6 +266,10 @@ impl VfsFileEntry {
                 Some(name) => Some(VfsString::String(name)),
                 None => None,
             },
+            VfsFileEntry::Vss(vss_file_entry) => match vss_file_entry.get_name() {
+                Some(name) => Some(VfsString::String(name)),
+                None => None,
+            },
         }
     }
 
@@ -257,12 +280,14 @@ impl VfsFileEntry {
             | VfsFileEntry::Ewf(_)
             | VfsFileEntry::Fake(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => 1,
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => 1,
             VfsFileEntry::Ext(ext_file_entry) => ext_file_entry.get_size(),
             VfsFileEntry::Fat(fat_file_entry) => fat_file_entry.get_size(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => ntfs_file_entry.get_size(),
@@ -278,12 +303,14 @@ impl VfsFileEntry {
             | VfsFileEntry::Fake(_)
             | VfsFileEntry::Fat(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => Ok(None),
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => Ok(None),
             VfsFileEntry::Ext(ext_file_entry) => match ext_file_entry.get_symbolic_link_target() {
                 Ok(result) => match result {
                     Some(name) => {
@@ -368,6 +395,10 @@ impl VfsFileEntry {
                 GptFileEntry::Partition { .. } => 1,
                 GptFileEntry::Root { .. } => 0,
             },
+            VfsFileEntry::Gzip(gzip_file_entry) => match gzip_file_entry {
+                GzipFileEntry::Layer { .. } => 1,
+                GzipFileEntry::Root { .. } => 0,
+            },
             VfsFileEntry::Mbr(mbr_file_entry) => match mbr_file_entry {
                 MbrFileEntry::Partition { .. } => 1,
                 MbrFileEntry::Root { .. } => 0,
@@ -389,7 +420,7 @@ impl VfsFileEntry {
                 _ => 0,
             },
             VfsFileEntry::Qcow(qcow_file_entry) => match qcow_file_entry {
-                QcowFileEntry::Layer { .. } => 1,
+                QcowFileEntry::Layer { .. } | QcowFileEntry::Snapshot { .. } => 1,
                 QcowFileEntry::Root { .. } => 0,
             },
             VfsFileEntry::SparseImage(sparseimage_file_entry) => match sparseimage_file_entry {
@@ -408,6 +439,10 @@ impl VfsFileEntry {
                 VhdxFileEntry::Layer { .. } => 1,
                 VhdxFileEntry::Root { .. } => 0,
             },
+            VfsFileEntry::Vss(vss_file_entry) => match vss_file_entry {
+                VssFileEntry::Root { .. } => 0,
+                VssFileEntry::Store { .. } => 1,
+            },
         };
         Ok(result)
     }
@@ -470,6 +505,30 @@ impl VfsFileEntry {
                 }
             }
             VfsFileEntry::Gpt(_) => todo!(),
+            VfsFileEntry::Gzip(gzip_file_entry) => {
+                if data_fork_index != 0 {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid data fork index: {}",
+                        data_fork_index
+                    )));
+                }
+                let result: Option<DataStreamReference> = match gzip_file_entry.get_data_stream() {
+                    Ok(result) => result,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve gzip data stream"
+                        );
+                        return Err(error);
+                    }
+                };
+                match result {
+                    Some(data_stream) => VfsDataFork::Gzip(data_stream),
+                    None => {
+                        return Err(keramics_core::error_trace_new!("Missing gzip data stream"));
+                    }
+                }
+            }
             VfsFileEntry::Mbr(_) => todo!(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => {
                 let ntfs_data_fork: NtfsDataFork =
@@ -491,6 +550,30 @@ impl VfsFileEntry {
             VfsFileEntry::Udif(_) => todo!(),
             VfsFileEntry::Vhd(_) => todo!(),
             VfsFileEntry::Vhdx(_) => todo!(),
+            VfsFileEntry::Vss(vss_file_entry) => {
+                if data_fork_index != 0 {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "Invalid data fork index: {}",
+                        data_fork_index
+                    )));
+                }
+                let result: Option<DataStreamReference> = match vss_file_entry.get_data_stream() {
+                    Ok(result) => result,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve VSS data stream"
+                        );
+                        return Err(error);
+                    }
+                };
+                match result {
+                    Some(data_stream) => VfsDataFork::Vss(data_stream),
+                    None => {
+                        return Err(keramics_core::error_trace_new!("Missing VSS data stream"));
+                    }
+                }
+            }
         };
         Ok(data_fork)
     }
@@ -558,6 +641,16 @@ impl VfsFileEntry {
                     return Err(error);
                 }
             },
+            VfsFileEntry::Gzip(gzip_file_entry) => match gzip_file_entry.get_data_stream() {
+                Ok(result) => result,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve gzip data stream"
+                    );
+                    return Err(error);
+                }
+            },
             VfsFileEntry::Mbr(mbr_file_entry) => match mbr_file_entry.get_data_stream() {
                 Ok(result) => result,
                 Err(mut error) => {
@@ -640,6 +733,16 @@ impl VfsFileEntry {
                     return Err(error);
                 }
             },
+            VfsFileEntry::Vss(vss_file_entry) => match vss_file_entry.get_data_stream() {
+                Ok(result) => result,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve VSS data stream"
+                    );
+                    return Err(error);
+                }
+            },
         };
         Ok(result)
     }
@@ -656,13 +759,15 @@ impl VfsFileEntry {
             | VfsFileEntry::Fake(_)
             | VfsFileEntry::Fat(_)
             | VfsFileEntry::Gpt(_)
+            | VfsFileEntry::Gzip(_)
             | VfsFileEntry::Mbr(_)
             | VfsFileEntry::Os(_)
             | VfsFileEntry::Qcow(_)
             | VfsFileEntry::SparseImage(_)
             | VfsFileEntry::Udif(_)
             | VfsFileEntry::Vhd(_)
-            | VfsFileEntry::Vhdx(_) => match name {
+            | VfsFileEntry::Vhdx(_)
+            | VfsFileEntry::Vss(_) => match name {
                 Some(_) => None,
                 None => match self.get_data_stream() {
                     Ok(result) => result,
@@ -759,6 +864,18 @@ impl VfsFileEntry {
                     }
                 }
             }
+            VfsFileEntry::Gzip(gzip_file_entry) => {
+                match gzip_file_entry.get_number_of_sub_file_entries() {
+                    Ok(number_of_sub_file_entries) => number_of_sub_file_entries,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve number of gzip sub file entries"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
             VfsFileEntry::Mbr(mbr_file_entry) => {
                 match mbr_file_entry.get_number_of_sub_file_entries() {
                     Ok(number_of_sub_file_entries) => number_of_sub_file_entries,
@@ -844,6 +961,18 @@ impl VfsFileEntry {
                     }
                 }
             }
+            VfsFileEntry::Vss(vss_file_entry) => {
+                match vss_file_entry.get_number_of_sub_file_entries() {
+                    Ok(number_of_sub_file_entries) => number_of_sub_file_entries,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve number of VSS sub file entries"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
         };
         Ok(number_of_sub_file_entries)
     }
@@ -930,6 +1059,21 @@ impl VfsFileEntry {
                     }
                 }
             }
+            VfsFileEntry::Gzip(gzip_file_entry) => {
+                match gzip_file_entry.get_sub_file_entry_by_index(sub_file_entry_index) {
+                    Ok(sub_file_entry) => VfsFileEntry::Gzip(sub_file_entry),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!(
+                                "Unable to retrieve gzip sub file entry: {}",
+                                sub_file_entry_index
+                            )
+                        );
+                        return Err(error);
+                    }
+                }
+            }
             VfsFileEntry::Mbr(mbr_file_entry) => {
                 match mbr_file_entry.get_sub_file_entry_by_index(sub_file_entry_index) {
                     Ok(sub_file_entry) => VfsFileEntry::Mbr(sub_file_entry),
@@ -1036,10 +1180,119 @@ impl VfsFileEntry {
                     }
                 }
             }
+            VfsFileEntry::Vss(vss_file_entry) => {
+                match vss_file_entry.get_sub_file_entry_by_index(sub_file_entry_index) {
+                    Ok(sub_file_entry) => VfsFileEntry::Vss(sub_file_entry),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!(
+                                "Unable to retrieve VSS sub file entry: {}",
+                                sub_file_entry_index
+                            )
+                        );
+                        return Err(error);
+                    }
+                }
+            }
         };
         Ok(sub_file_entry)
     }
 
+    /// Retrieves the number of deleted sub file entries.
+    ///
+    /// Deleted sub file entries are currently only supported for NTFS.
+    pub fn get_number_of_deleted_sub_file_entries(&mut self) -> Result<usize, ErrorTrace> {
+        match self {
+            VfsFileEntry::Ntfs(ntfs_file_entry) => {
+                match ntfs_file_entry.get_number_of_deleted_sub_file_entries() {
+                    Ok(number_of_sub_file_entries) => Ok(number_of_sub_file_entries),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve number of NTFS deleted sub file entries"
+                        );
+                        Err(error)
+                    }
+                }
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// Retrieves a specific deleted sub file entry.
+    pub fn get_deleted_sub_file_entry_by_index(
+        &mut self,
+        sub_file_entry_index: usize,
+    ) -> Result<VfsFileEntry, ErrorTrace> {
+        match self {
+            VfsFileEntry::Ntfs(ntfs_file_entry) => {
+                match ntfs_file_entry.get_deleted_sub_file_entry_by_index(sub_file_entry_index) {
+                    Ok(sub_file_entry) => Ok(VfsFileEntry::Ntfs(sub_file_entry)),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!(
+                                "Unable to retrieve NTFS deleted sub file entry: {}",
+                                sub_file_entry_index
+                            )
+                        );
+                        Err(error)
+                    }
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!(
+                "Unsupported deleted sub file entries"
+            )),
+        }
+    }
+
+    /// Retrieves the number of extended attributes.
+    ///
+    /// Extended attributes are currently only supported for ext.
+    pub fn get_number_of_extended_attributes(&mut self) -> Result<usize, ErrorTrace> {
+        match self {
+            VfsFileEntry::Ext(ext_file_entry) => match ext_file_entry.get_number_of_attributes() {
+                Ok(number_of_attributes) => Ok(number_of_attributes),
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(
+                        error,
+                        "Unable to retrieve number of ext extended attributes"
+                    );
+                    Err(error)
+                }
+            },
+            _ => Ok(0),
+        }
+    }
+
+    /// Retrieves a specific extended attribute.
+    pub fn get_extended_attribute_by_index(
+        &mut self,
+        extended_attribute_index: usize,
+    ) -> Result<VfsExtendedAttribute<'_>, ErrorTrace> {
+        match self {
+            VfsFileEntry::Ext(ext_file_entry) => {
+                match ext_file_entry.get_attribute_by_index(extended_attribute_index) {
+                    Ok(ext_attribute) => Ok(VfsExtendedAttribute::Ext(ext_attribute)),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!(
+                                "Unable to retrieve ext extended attribute: {}",
+                                extended_attribute_index
+                            )
+                        );
+                        Err(error)
+                    }
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!(
+                "Unsupported extended attributes"
+            )),
+        }
+    }
+
     /// Retrieves a sub file entries iterator.
     pub fn sub_file_entries(&mut self) -> Result<VfsFileEntriesIterator<'_>, ErrorTrace> {
         let number_of_sub_file_entries: usize = self.get_number_of_sub_file_entries()?;
@@ -1058,6 +1311,10 @@ impl VfsFileEntry {
             VfsFileEntry::Fake(_) => todo!(),
             VfsFileEntry::Fat(fat_file_entry) => fat_file_entry.is_root_directory(),
             VfsFileEntry::Gpt(gpt_file_entry) => todo!(),
+            VfsFileEntry::Gzip(gzip_file_entry) => match gzip_file_entry {
+                GzipFileEntry::Layer { .. } => false,
+                GzipFileEntry::Root { .. } => true,
+            },
             VfsFileEntry::Mbr(mbr_file_entry) => todo!(),
             VfsFileEntry::Ntfs(ntfs_file_entry) => ntfs_file_entry.is_root_directory(),
             VfsFileEntry::Os(_) => todo!(),
@@ -1066,6 +1323,10 @@ impl VfsFileEntry {
             VfsFileEntry::Udif(udif_file_entry) => todo!(),
             VfsFileEntry::Vhd(vhd_file_entry) => todo!(),
             VfsFileEntry::Vhdx(vhdx_file_entry) => todo!(),
+            VfsFileEntry::Vss(vss_file_entry) => match vss_file_entry {
+                VssFileEntry::Root { .. } => true,
+                VssFileEntry::Store { .. } => false,
+            },
         }
     }
 }
@@ -1074,7 +1335,9 @@ impl VfsFileEntry {
 mod tests {
     use super::*;
 
+    use keramics_core::open_fake_data_stream;
     use keramics_datetime::{FatDate, FatTimeDate, FatTimeDate10Ms, Filetime, PosixTime32};
+    use keramics_formats::vss::{VssStore, VssVolumeSystem};
 
     use crate::enums::{VfsFileType, VfsType};
     use crate::file_system::VfsFileSystem;
@@ -1083,6 +1346,7 @@ mod tests {
     use crate::types::VfsFileSystemReference;
 
     use crate::tests::get_test_data_path;
+    use crate::vss::tests::get_test_data as get_vss_test_data;
 
     fn get_parent_file_system() -> VfsFileSystemReference {
         VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os))
@@ -1326,6 +1590,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_number_of_extended_attributes_with_ext() -> Result<(), ErrorTrace> {
+        let mut vfs_file_entry: VfsFileEntry = get_ext_file_entry("/testdir1/testfile1")?;
+
+        let number_of_extended_attributes: usize =
+            vfs_file_entry.get_number_of_extended_attributes()?;
+        assert_eq!(number_of_extended_attributes, 0);
+
+        let result = vfs_file_entry.get_extended_attribute_by_index(0);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_extended_attribute_by_index_with_ext_xattrs() -> Result<(), ErrorTrace> {
+        let mut vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Ext);
+
+        let parent_file_system: VfsFileSystemReference = get_parent_file_system();
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("ext/ext4_xattr.raw").as_str());
+        vfs_file_system.open(Some(&parent_file_system), &vfs_location)?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Ext, "/xattr1");
+        let mut vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        let number_of_extended_attributes: usize =
+            vfs_file_entry.get_number_of_extended_attributes()?;
+        assert_eq!(number_of_extended_attributes, 4);
+
+        let extended_attribute: VfsExtendedAttribute =
+            vfs_file_entry.get_extended_attribute_by_index(0)?;
+        assert_eq!(
+            extended_attribute.get_name(),
+            VfsString::Byte(ByteString::from("user.myxattr1"))
+        );
+        assert_eq!(
+            extended_attribute.get_value_data(),
+            b"My 1st extended attribute"
+        );
+
+        let extended_attribute: VfsExtendedAttribute =
+            vfs_file_entry.get_extended_attribute_by_index(2)?;
+        assert_eq!(
+            extended_attribute.get_name(),
+            VfsString::Byte(ByteString::from("user.large"))
+        );
+        assert_eq!(extended_attribute.get_value_data(), vec![b'A'; 1024]);
+
+        let extended_attribute: VfsExtendedAttribute =
+            vfs_file_entry.get_extended_attribute_by_index(3)?;
+        assert_eq!(
+            extended_attribute.get_name(),
+            VfsString::Byte(ByteString::from("security.selinux"))
+        );
+
+        Ok(())
+    }
+
     // Tests with EWF.
 
     fn get_ewf_file_system() -> Result<VfsFileSystem, ErrorTrace> {
@@ -1691,6 +2015,155 @@ mod tests {
         Ok(())
     }
 
+    // Tests with gzip.
+
+    fn get_gzip_file_system() -> Result<VfsFileSystem, ErrorTrace> {
+        let mut vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Gzip);
+
+        let parent_file_system: VfsFileSystemReference = get_parent_file_system();
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        vfs_file_system.open(Some(&parent_file_system), &vfs_location)?;
+
+        Ok(vfs_file_system)
+    }
+
+    fn get_gzip_file_entry(path: &str) -> Result<VfsFileEntry, ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, path);
+        match vfs_file_system.get_file_entry_by_path(&vfs_path)? {
+            Some(file_entry) => Ok(file_entry),
+            None => Err(keramics_core::error_trace_new!(format!(
+                "No such file entry: {}",
+                path
+            ))),
+        }
+    }
+
+    #[test]
+    fn test_get_access_time_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_access_time(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_change_time_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_change_time(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_creation_time_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_creation_time(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_type_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(vfs_file_entry.get_file_type() == VfsFileType::Directory);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(vfs_file_entry.get_file_type() == VfsFileType::File);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_modification_time_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_modification_time(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_name_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_name(), Some(VfsString::from("gzip1")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_data_stream_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        let result: Option<DataStreamReference> = vfs_file_entry.get_data_stream()?;
+        assert!(result.is_none());
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        let result: Option<DataStreamReference> = vfs_file_entry.get_data_stream()?;
+        assert!(result.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_data_fork_by_index_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_entry: VfsFileEntry = get_gzip_file_entry("/gzip1")?;
+
+        assert_eq!(vfs_file_entry.get_number_of_data_forks()?, 1);
+
+        let vfs_data_fork: VfsDataFork = vfs_file_entry.get_data_fork_by_index(0)?;
+        assert_eq!(vfs_data_fork.get_name(), None);
+
+        let data_stream: DataStreamReference = vfs_data_fork.get_data_stream()?;
+        let data_size: u64 = keramics_core::data_stream_get_size!(data_stream);
+        assert_eq!(data_size, 4194304);
+
+        let result = vfs_file_entry.get_data_fork_by_index(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_root_directory_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(vfs_file_entry.is_root_directory());
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(!vfs_file_entry.is_root_directory());
+
+        Ok(())
+    }
+
     // Tests with MBR.
 
     fn get_mbr_file_system() -> Result<VfsFileSystem, ErrorTrace> {
@@ -2536,6 +3009,64 @@ mod tests {
     // TODO: add tests for get_symbolic_link_target
     // TODO: add tests for get_number_of_data_forks
 
+    // Tests with VSS.
+
+    fn get_vss_volume_system() -> Result<Arc<VssVolumeSystem>, ErrorTrace> {
+        let mut vss_volume_system: VssVolumeSystem = VssVolumeSystem::new();
+
+        let test_data: Vec<u8> = get_vss_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+        vss_volume_system.read_data_stream(&data_stream)?;
+
+        Ok(Arc::new(vss_volume_system))
+    }
+
+    #[test]
+    fn test_get_data_fork_by_index_with_vss() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = get_vss_volume_system()?;
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let vfs_file_entry: VfsFileEntry = VfsFileEntry::Vss(VssFileEntry::new_store(0, vss_store));
+
+        assert_eq!(vfs_file_entry.get_number_of_data_forks()?, 1);
+
+        let vfs_data_fork: VfsDataFork = vfs_file_entry.get_data_fork_by_index(0)?;
+        assert_eq!(vfs_data_fork.get_name(), None);
+
+        let data_stream: DataStreamReference = vfs_data_fork.get_data_stream()?;
+
+        let mut data: Vec<u8> = vec![0; 512];
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            std::io::SeekFrom::Start(0x10000)
+        );
+        assert_eq!(data, [0x55; 512]);
+
+        let result = vfs_file_entry.get_data_fork_by_index(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_root_directory_with_vss() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = get_vss_volume_system()?;
+
+        let vfs_file_entry: VfsFileEntry = VfsFileEntry::Vss(VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        });
+
+        assert!(vfs_file_entry.is_root_directory());
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let vfs_file_entry: VfsFileEntry = VfsFileEntry::Vss(VssFileEntry::new_store(0, vss_store));
+
+        assert!(!vfs_file_entry.is_root_directory());
+
+        Ok(())
+    }
+
     // TODO: add tests for get_number_of_sub_file_entries
     // TODO: add tests for get_sub_file_entry_by_index
     // TODO: add tests for sub_file_entries
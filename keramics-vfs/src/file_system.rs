@@ -24,6 +24,7 @@ use super::ewf::{EwfFileEntry, EwfFileSystem};
 use super::fake::{FakeFileEntry, FakeFileSystem};
 use super::file_entry::VfsFileEntry;
 use super::gpt::{GptFileEntry, GptFileSystem};
+use super::gzip::{GzipFileEntry, GzipFileSystem};
 use super::location::VfsLocation;
 use super::mbr::{MbrFileEntry, MbrFileSystem};
 use super::os::OsFileEntry;
@@ -34,6 +35,7 @@ use super::types::VfsFileSystemReference;
 use super::udif::{UdifFileEntry, UdifFileSystem};
 use super::vhd::{VhdFileEntry, VhdFileSystem};
 use super::vhdx::{VhdxFileEntry, VhdxFileSystem};
+use super::vss::{VssFileEntry, VssFileSystem};
 
 /// Virtual File System (VFS) file system.
 pub enum VfsFileSystem {
@@ -43,6 +45,7 @@ pub enum VfsFileSystem {
     Fake(FakeFileSystem),
     Fat(FatFileSystem),
     Gpt(GptFileSystem),
+    Gzip(GzipFileSystem),
     Mbr(MbrFileSystem),
     Ntfs(NtfsFileSystem),
     Os,
@@ -51,6 +54,7 @@ pub enum VfsFileSystem {
     Udif(UdifFileSystem),
     Vhd(VhdFileSystem),
     Vhdx(VhdxFileSystem),
+    Vss(VssFileSystem),
 }
 
 impl VfsFileSystem {
@@ -63,6 +67,7 @@ impl VfsFileSystem {
             VfsType::Fake => VfsFileSystem::Fake(FakeFileSystem::new()),
             VfsType::Fat => VfsFileSystem::Fat(FatFileSystem::new()),
             VfsType::Gpt => VfsFileSystem::Gpt(GptFileSystem::new()),
+            VfsType::Gzip => VfsFileSystem::Gzip(GzipFileSystem::new()),
             VfsType::Mbr => VfsFileSystem::Mbr(MbrFileSystem::new()),
             VfsType::Ntfs => VfsFileSystem::Ntfs(NtfsFileSystem::new()),
             VfsType::Os => VfsFileSystem::Os,
@@ -71,6 +76,7 @@ impl VfsFileSystem {
             VfsType::Udif => VfsFileSystem::Udif(UdifFileSystem::new()),
             VfsType::Vhd => VfsFileSystem::Vhd(VhdFileSystem::new()),
             VfsType::Vhdx => VfsFileSystem::Vhdx(VhdxFileSystem::new()),
+            VfsType::Vss => VfsFileSystem::Vss(VssFileSystem::new()),
         }
     }
 
@@ -169,6 +175,18 @@ impl VfsFileSystem {
                     }
                 }
             }
+            VfsFileSystem::Gzip(gzip_file_system) => {
+                match gzip_file_system.file_entry_exists(vfs_path) {
+                    Ok(result) => Ok(result),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to determine if gzip file entry exists"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
             VfsFileSystem::Mbr(mbr_file_system) => {
                 match mbr_file_system.file_entry_exists(vfs_path) {
                     Ok(result) => Ok(result),
@@ -277,6 +295,18 @@ impl VfsFileSystem {
                     }
                 }
             }
+            VfsFileSystem::Vss(vss_file_system) => {
+                match vss_file_system.file_entry_exists(vfs_path) {
+                    Ok(result) => Ok(result),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to determine if VSS file entry exists"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
         }
     }
 
@@ -412,6 +442,23 @@ impl VfsFileSystem {
                     None => Ok(None),
                 }
             }
+            VfsFileSystem::Gzip(gzip_file_system) => {
+                let result: Option<GzipFileEntry> =
+                    match gzip_file_system.get_file_entry_by_path(vfs_path) {
+                        Ok(result) => result,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to retrieve gzip file entry"
+                            );
+                            return Err(error);
+                        }
+                    };
+                match result {
+                    Some(gzip_file_entry) => Ok(Some(VfsFileEntry::Gzip(gzip_file_entry))),
+                    None => Ok(None),
+                }
+            }
             VfsFileSystem::Mbr(mbr_file_system) => {
                 let result: Option<MbrFileEntry> =
                     match mbr_file_system.get_file_entry_by_path(vfs_path) {
@@ -571,6 +618,23 @@ impl VfsFileSystem {
                     None => Ok(None),
                 }
             }
+            VfsFileSystem::Vss(vss_file_system) => {
+                let result: Option<VssFileEntry> =
+                    match vss_file_system.get_file_entry_by_path(vfs_path) {
+                        Ok(result) => result,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                "Unable to retrieve VSS file entry"
+                            );
+                            return Err(error);
+                        }
+                    };
+                match result {
+                    Some(vss_file_entry) => Ok(Some(VfsFileEntry::Vss(vss_file_entry))),
+                    None => Ok(None),
+                }
+            }
         }
     }
 
@@ -640,6 +704,19 @@ impl VfsFileSystem {
                 };
                 Ok(Some(VfsFileEntry::Gpt(gpt_file_entry)))
             }
+            VfsFileSystem::Gzip(gzip_file_system) => {
+                let gzip_file_entry: GzipFileEntry = match gzip_file_system.get_root_file_entry() {
+                    Ok(file_entry) => file_entry,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve gzip root file entry"
+                        );
+                        return Err(error);
+                    }
+                };
+                Ok(Some(VfsFileEntry::Gzip(gzip_file_entry)))
+            }
             VfsFileSystem::Mbr(mbr_file_system) => {
                 let mbr_file_entry: MbrFileEntry = match mbr_file_system.get_root_file_entry() {
                     Ok(file_entry) => file_entry,
@@ -733,6 +810,19 @@ impl VfsFileSystem {
                 };
                 Ok(Some(VfsFileEntry::Vhdx(vhdx_file_entry)))
             }
+            VfsFileSystem::Vss(vss_file_system) => {
+                let vss_file_entry: VssFileEntry = match vss_file_system.get_root_file_entry() {
+                    Ok(file_entry) => file_entry,
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to retrieve VSS root file entry"
+                        );
+                        return Err(error);
+                    }
+                };
+                Ok(Some(VfsFileEntry::Vss(vss_file_entry)))
+            }
         }
     }
 
@@ -870,6 +960,18 @@ impl VfsFileSystem {
                     }
                 }
             }
+            VfsFileSystem::Gzip(gzip_file_system) => {
+                match gzip_file_system.open(parent_file_system, vfs_location) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to open gzip file system"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
             VfsFileSystem::Mbr(mbr_file_system) => {
                 match mbr_file_system.open(parent_file_system, vfs_location) {
                     Ok(_) => {}
@@ -984,6 +1086,18 @@ impl VfsFileSystem {
                     }
                 }
             }
+            VfsFileSystem::Vss(vss_file_system) => {
+                match vss_file_system.open(parent_file_system, vfs_location) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to open VSS file system"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -1380,6 +1494,71 @@ mod tests {
         Ok(())
     }
 
+    // Tests with gzip.
+
+    fn get_gzip_file_system() -> Result<VfsFileSystem, ErrorTrace> {
+        let mut vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Gzip);
+
+        let parent_file_system: VfsFileSystemReference =
+            VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os));
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        vfs_file_system.open(Some(&parent_file_system), &vfs_location)?;
+
+        Ok(vfs_file_system)
+    }
+
+    #[test]
+    fn test_file_entry_exists_with_gzip() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        assert_eq!(vfs_file_system.file_entry_exists(&vfs_path)?, true);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/bogus1");
+        assert_eq!(vfs_file_system.file_entry_exists(&vfs_path)?, false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_path_with_gzip_non_existing() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/bogus1");
+        let result: Option<VfsFileEntry> = vfs_file_system.get_file_entry_by_path(&vfs_path)?;
+
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_path_with_gzip_layer() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(vfs_file_entry.get_file_type() == VfsFileType::File);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_path_with_gzip_root() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_gzip_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let vfs_file_entry: VfsFileEntry =
+            vfs_file_system.get_file_entry_by_path(&vfs_path)?.unwrap();
+
+        assert!(vfs_file_entry.get_file_type() == VfsFileType::Directory);
+
+        Ok(())
+    }
+
     // Tests with MBR.
 
     fn get_mbr_file_system() -> Result<VfsFileSystem, ErrorTrace> {
@@ -0,0 +1,355 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use keramics_core::ErrorTrace;
+use keramics_core::mediator::{Mediator, MediatorReference};
+use keramics_formats::ntfs::NtfsFileEntry;
+
+use crate::file_entry::VfsFileEntry;
+use crate::file_system::VfsFileSystem;
+use crate::string::VfsString;
+
+use super::finder::VfsFinder;
+
+/// Name of the virtual directory that contains deleted file entries without a known parent.
+const ORPHAN_FILES_DIRECTORY_NAME: &str = "$OrphanFiles";
+
+/// Virtual File System (VFS) deleted file entries finder.
+///
+/// The finder first carves deleted file entries from the directories of the file system and
+/// then enumerates the recoverable unallocated file entries of the file system.
+///
+/// Recovery is best-effort, deleted file entries that cannot be read are skipped.
+pub struct VfsDeletedFinder<'a> {
+    /// Mediator.
+    mediator: MediatorReference,
+
+    /// File system.
+    file_system: &'a VfsFileSystem,
+
+    /// Finder of the allocated file entries, which is None if the allocated file entries are
+    /// provided by the caller.
+    finder: Option<VfsFinder<'a>>,
+
+    /// Value to indicate the finder of the allocated file entries has completed.
+    finder_completed: bool,
+
+    /// Pending file entries and their path components.
+    pending_file_entries: VecDeque<(VfsFileEntry, Vec<VfsString>)>,
+
+    /// Path components of directories per file reference.
+    directory_paths: HashMap<u64, Vec<VfsString>>,
+
+    /// File entry identifiers of deleted file entries that were already returned.
+    returned_identifiers: HashSet<u64>,
+
+    /// File entry identifier.
+    file_entry_identifier: u64,
+}
+
+impl<'a> VfsDeletedFinder<'a> {
+    /// Creates a new deleted file entries finder.
+    pub fn new(file_system: &'a VfsFileSystem) -> Self {
+        Self {
+            mediator: Mediator::current(),
+            file_system: file_system,
+            finder: Some(VfsFinder::new(file_system)),
+            // Deleted sub file entries are currently only supported for NTFS.
+            finder_completed: !matches!(file_system, VfsFileSystem::Ntfs(_)),
+            pending_file_entries: VecDeque::new(),
+            directory_paths: HashMap::new(),
+            returned_identifiers: HashSet::new(),
+            file_entry_identifier: 0,
+        }
+    }
+
+    /// Creates a new deleted file entries finder that uses an existing traversal.
+    ///
+    /// The allocated file entries of the traversal are provided with add_allocated_file_entry
+    /// before the deleted file entries are retrieved, which allows a single traversal of the
+    /// file system to enumerate both the allocated and deleted file entries.
+    pub fn new_with_external_traversal(file_system: &'a VfsFileSystem) -> Self {
+        Self {
+            mediator: Mediator::current(),
+            file_system: file_system,
+            finder: None,
+            finder_completed: true,
+            pending_file_entries: VecDeque::new(),
+            directory_paths: HashMap::new(),
+            returned_identifiers: HashSet::new(),
+            file_entry_identifier: 0,
+        }
+    }
+
+    /// Adds an allocated file entry.
+    ///
+    /// The deleted sub file entries of the file entry are carved and the path of the file
+    /// entry is used as parent path of recovered unallocated file entries.
+    pub fn add_allocated_file_entry(
+        &mut self,
+        file_entry: &mut VfsFileEntry,
+        path_components: &Vec<VfsString>,
+    ) {
+        match &file_entry {
+            VfsFileEntry::Ntfs(ntfs_file_entry) => {
+                if ntfs_file_entry.is_directory() {
+                    self.directory_paths.insert(
+                        ntfs_file_entry.get_file_reference(),
+                        path_components.clone(),
+                    );
+                }
+            }
+            _ => return,
+        }
+        self.add_deleted_sub_file_entries(file_entry, path_components);
+    }
+
+    /// Adds the deleted sub file entries of a file entry.
+    fn add_deleted_sub_file_entries(
+        &mut self,
+        file_entry: &mut VfsFileEntry,
+        path_components: &Vec<VfsString>,
+    ) {
+        let number_of_sub_file_entries: usize = match file_entry
+            .get_number_of_deleted_sub_file_entries()
+        {
+            Ok(number_of_sub_file_entries) => number_of_sub_file_entries,
+            Err(error) => {
+                if self.mediator.debug_output {
+                    self.mediator.debug_print(format!(
+                            "VfsDeletedFinder: unable to retrieve number of deleted sub file entries with error:\n{}\n",
+                            error
+                        ));
+                }
+                return;
+            }
+        };
+        for sub_file_entry_index in 0..number_of_sub_file_entries {
+            let sub_file_entry: VfsFileEntry = match file_entry
+                .get_deleted_sub_file_entry_by_index(sub_file_entry_index)
+            {
+                Ok(sub_file_entry) => sub_file_entry,
+                Err(error) => {
+                    if self.mediator.debug_output {
+                        self.mediator.debug_print(format!(
+                                "VfsDeletedFinder: unable to retrieve deleted sub file entry: {} with error:\n{}\n",
+                                sub_file_entry_index, error
+                            ));
+                    }
+                    continue;
+                }
+            };
+            match &sub_file_entry {
+                // Unallocated MFT entries that were recovered from a directory are not returned
+                // again when enumerating the unallocated MFT entries.
+                VfsFileEntry::Ntfs(ntfs_file_entry) => {
+                    if ntfs_file_entry.get_number_of_attributes() > 0 {
+                        self.returned_identifiers
+                            .insert(ntfs_file_entry.mft_entry_number);
+                    }
+                }
+                _ => {}
+            }
+            let mut sub_path_components: Vec<VfsString> = path_components.clone();
+
+            match sub_file_entry.get_name() {
+                Some(name) => sub_path_components.push(name),
+                None => sub_path_components.push(VfsString::Empty),
+            }
+            self.pending_file_entries
+                .push_back((sub_file_entry, sub_path_components));
+        }
+    }
+
+    /// Retrieves the path components of a deleted file entry based on its parent.
+    fn get_path_components(&self, file_entry: &VfsFileEntry) -> Vec<VfsString> {
+        let parent_file_reference: Option<u64> = match file_entry {
+            VfsFileEntry::Ntfs(ntfs_file_entry) => ntfs_file_entry.get_parent_file_reference(),
+            _ => None,
+        };
+        let mut path_components: Vec<VfsString> = match parent_file_reference
+            .and_then(|file_reference| self.directory_paths.get(&file_reference))
+        {
+            Some(directory_path_components) => directory_path_components.clone(),
+            None => vec![
+                VfsString::Empty,
+                VfsString::from(ORPHAN_FILES_DIRECTORY_NAME),
+            ],
+        };
+        match file_entry.get_name() {
+            Some(name) => path_components.push(name),
+            None => path_components.push(VfsString::Empty),
+        }
+        path_components
+    }
+
+    /// Retrieves the next recoverable unallocated file entry.
+    fn get_next_unallocated_file_entry(
+        &mut self,
+    ) -> Result<Option<(VfsFileEntry, Vec<VfsString>)>, ErrorTrace> {
+        match self.file_system {
+            VfsFileSystem::Ntfs(ntfs_file_system) => {
+                let number_of_file_entries: u64 = ntfs_file_system.get_number_of_file_entries();
+
+                while self.file_entry_identifier < number_of_file_entries {
+                    let mft_entry_number: u64 = self.file_entry_identifier;
+
+                    self.file_entry_identifier += 1;
+
+                    if self.returned_identifiers.contains(&mft_entry_number) {
+                        continue;
+                    }
+                    let result: Option<NtfsFileEntry> = match ntfs_file_system
+                        .get_deleted_file_entry_by_identifier(mft_entry_number)
+                    {
+                        Ok(result) => result,
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!(
+                                    "Unable to retrieve NTFS deleted file entry: {}",
+                                    mft_entry_number
+                                )
+                            );
+                            return Err(error);
+                        }
+                    };
+                    match result {
+                        Some(ntfs_file_entry) => {
+                            let file_reference: u64 = ntfs_file_entry.get_file_reference();
+                            let is_directory: bool = ntfs_file_entry.is_directory();
+
+                            let file_entry: VfsFileEntry = VfsFileEntry::Ntfs(ntfs_file_entry);
+                            let path_components: Vec<VfsString> =
+                                self.get_path_components(&file_entry);
+
+                            if is_directory {
+                                self.directory_paths
+                                    .insert(file_reference, path_components.clone());
+                            }
+                            return Ok(Some((file_entry, path_components)));
+                        }
+                        None => {}
+                    }
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'a> Iterator for VfsDeletedFinder<'a> {
+    type Item = Result<(VfsFileEntry, Vec<VfsString>), ErrorTrace>;
+
+    /// Retrieves the next deleted file entry.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.pending_file_entries.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.finder_completed {
+                break;
+            }
+            let result: Option<Result<(VfsFileEntry, Vec<VfsString>), ErrorTrace>> =
+                match self.finder.as_mut() {
+                    Some(finder) => finder.next(),
+                    None => None,
+                };
+            match result {
+                Some(Ok((mut file_entry, path_components))) => {
+                    self.add_allocated_file_entry(&mut file_entry, &path_components);
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => self.finder_completed = true,
+            }
+        }
+        match self.get_next_unallocated_file_entry() {
+            Ok(Some(item)) => Some(Ok(item)),
+            Ok(None) => None,
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::enums::VfsType;
+    use crate::location::{VfsLocation, new_os_vfs_location};
+    use crate::types::VfsFileSystemReference;
+
+    use crate::tests::get_test_data_path;
+
+    fn get_ntfs_file_system() -> Result<VfsFileSystem, ErrorTrace> {
+        let mut vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Ntfs);
+
+        let parent_file_system: VfsFileSystemReference =
+            VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os));
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("ntfs/ntfs.raw").as_str());
+        vfs_file_system.open(Some(&parent_file_system), &vfs_location)?;
+
+        Ok(vfs_file_system)
+    }
+
+    #[test]
+    fn test_next_with_fake() {
+        let vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Fake);
+
+        let mut deleted_finder: VfsDeletedFinder = VfsDeletedFinder::new(&vfs_file_system);
+        assert!(deleted_finder.next().is_none());
+    }
+
+    #[test]
+    fn test_next_with_ntfs() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_ntfs_file_system()?;
+
+        for result in VfsDeletedFinder::new(&vfs_file_system) {
+            let (_, path_components): (VfsFileEntry, Vec<VfsString>) = result?;
+
+            assert!(path_components.len() >= 2);
+            assert_eq!(path_components[0], VfsString::Empty);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_external_traversal() -> Result<(), ErrorTrace> {
+        let vfs_file_system: VfsFileSystem = get_ntfs_file_system()?;
+
+        let mut expected_paths: Vec<Vec<VfsString>> = Vec::new();
+        for result in VfsDeletedFinder::new(&vfs_file_system) {
+            let (_, path_components): (VfsFileEntry, Vec<VfsString>) = result?;
+            expected_paths.push(path_components);
+        }
+        let mut deleted_finder: VfsDeletedFinder =
+            VfsDeletedFinder::new_with_external_traversal(&vfs_file_system);
+
+        for result in VfsFinder::new(&vfs_file_system) {
+            let (mut file_entry, path_components): (VfsFileEntry, Vec<VfsString>) = result?;
+            deleted_finder.add_allocated_file_entry(&mut file_entry, &path_components);
+        }
+        let mut paths: Vec<Vec<VfsString>> = Vec::new();
+        for result in deleted_finder {
+            let (_, path_components): (VfsFileEntry, Vec<VfsString>) = result?;
+            paths.push(path_components);
+        }
+        assert_eq!(paths, expected_paths);
+
+        Ok(())
+    }
+}
@@ -11,6 +11,8 @@
  * under the License.
  */
 
+mod deleted_finder;
 mod finder;
 
+pub use deleted_finder::VfsDeletedFinder;
 pub use finder::VfsFinder;
@@ -0,0 +1,297 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::sync::{Arc, RwLock};
+
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_formats::gzip::GzipFile;
+
+use crate::location::VfsLocation;
+use crate::path::VfsPath;
+use crate::types::VfsFileSystemReference;
+
+use super::file_entry::GzipFileEntry;
+
+/// gzip compressed file system.
+pub struct GzipFileSystem {
+    /// File.
+    file: Arc<RwLock<GzipFile>>,
+
+    /// Number of layers.
+    number_of_layers: usize,
+}
+
+impl GzipFileSystem {
+    pub const PATH_PREFIX: &'static str = "/gzip";
+
+    /// Creates a new file system.
+    pub fn new() -> Self {
+        Self {
+            file: Arc::new(RwLock::new(GzipFile::new())),
+            number_of_layers: 0,
+        }
+    }
+
+    /// Determines if the file entry with the specified path exists.
+    pub fn file_entry_exists(&self, vfs_path: &VfsPath) -> Result<bool, ErrorTrace> {
+        match vfs_path {
+            VfsPath::String(string_path) => {
+                let number_of_components: usize = string_path.components.len();
+                if number_of_components == 0 || number_of_components > 2 {
+                    return Ok(false);
+                }
+                if string_path.components[0] != "" {
+                    return Ok(false);
+                }
+                // A single empty component represents "/".
+                if number_of_components == 1 {
+                    return Ok(true);
+                }
+                if string_path.components[1] == "gzip1" {
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!("Unsupported VFS path type")),
+        }
+    }
+
+    /// Retrieves the file entry with the specific location.
+    pub fn get_file_entry_by_path(
+        &self,
+        vfs_path: &VfsPath,
+    ) -> Result<Option<GzipFileEntry>, ErrorTrace> {
+        match vfs_path {
+            VfsPath::String(string_path) => {
+                let number_of_components: usize = string_path.components.len();
+                if number_of_components == 0 || number_of_components > 2 {
+                    return Ok(None);
+                }
+                if string_path.components[0] != "" {
+                    return Ok(None);
+                }
+                // A single empty component represents "/".
+                if number_of_components == 1 {
+                    let gzip_file_entry: GzipFileEntry = self.get_root_file_entry()?;
+
+                    return Ok(Some(gzip_file_entry));
+                }
+                if string_path.components[1] == "gzip1" {
+                    let gzip_file_entry: GzipFileEntry = GzipFileEntry::Layer {
+                        file: self.file.clone(),
+                    };
+                    Ok(Some(gzip_file_entry))
+                } else {
+                    Ok(None)
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!("Unsupported VFS path type")),
+        }
+    }
+
+    /// Retrieves the root file entry.
+    pub fn get_root_file_entry(&self) -> Result<GzipFileEntry, ErrorTrace> {
+        Ok(GzipFileEntry::Root {
+            file: self.file.clone(),
+        })
+    }
+
+    /// Opens the file system.
+    pub fn open(
+        &mut self,
+        parent_file_system: Option<&VfsFileSystemReference>,
+        vfs_location: &VfsLocation,
+    ) -> Result<(), ErrorTrace> {
+        let file_system: &VfsFileSystemReference = match parent_file_system {
+            Some(file_system) => file_system,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Missing parent file system"
+                ));
+            }
+        };
+        let vfs_path: &VfsPath = vfs_location.get_path();
+
+        match self.file.write() {
+            Ok(mut file) => {
+                match Self::open_file(&mut file, file_system, vfs_path) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(error, "Unable to open gzip file");
+                        return Err(error);
+                    }
+                }
+                self.number_of_layers = 1;
+            }
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to obtain write lock on gzip file",
+                    error
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens a gzip file.
+    pub(crate) fn open_file(
+        file: &mut GzipFile,
+        file_system: &VfsFileSystemReference,
+        vfs_path: &VfsPath,
+    ) -> Result<(), ErrorTrace> {
+        let result: Option<DataStreamReference> =
+            match file_system.get_data_stream_by_path_and_name(vfs_path, None) {
+                Ok(result) => result,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to retrieve data stream");
+                    return Err(error);
+                }
+            };
+        let data_stream: DataStreamReference = match result {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        match file.read_data_stream(&data_stream) {
+            Ok(()) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read gzip file from data stream"
+                );
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::enums::{VfsFileType, VfsType};
+    use crate::file_system::VfsFileSystem;
+    use crate::location::new_os_vfs_location;
+
+    use crate::tests::get_test_data_path;
+
+    fn get_file_system() -> Result<GzipFileSystem, ErrorTrace> {
+        let mut gzip_file_system: GzipFileSystem = GzipFileSystem::new();
+
+        let parent_file_system: VfsFileSystemReference =
+            VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os));
+        let parent_vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        gzip_file_system.open(Some(&parent_file_system), &parent_vfs_location)?;
+
+        Ok(gzip_file_system)
+    }
+
+    #[test]
+    fn test_file_entry_exists() -> Result<(), ErrorTrace> {
+        let gzip_file_system: GzipFileSystem = get_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let result: bool = gzip_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, true);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let result: bool = gzip_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, true);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/bogus1");
+        let result: bool = gzip_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, false);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1/bogus1");
+        let result: bool = gzip_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, false);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "bogus1");
+        let result: bool = gzip_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, false);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Os, "/");
+        let result: Result<bool, ErrorTrace> = gzip_file_system.file_entry_exists(&vfs_path);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_path() -> Result<(), ErrorTrace> {
+        let gzip_file_system: GzipFileSystem = get_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/");
+        let result: Option<GzipFileEntry> = gzip_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_some());
+
+        let gzip_file_entry: GzipFileEntry = result.unwrap();
+
+        let name: Option<String> = gzip_file_entry.get_name();
+        assert!(name.is_none());
+
+        let file_type: VfsFileType = gzip_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::Directory);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/gzip1");
+        let result: Option<GzipFileEntry> = gzip_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_some());
+
+        let gzip_file_entry: GzipFileEntry = result.unwrap();
+
+        let name: Option<String> = gzip_file_entry.get_name();
+        assert_eq!(name, Some(String::from("gzip1")));
+
+        let file_type: VfsFileType = gzip_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::File);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Gzip, "/bogus1");
+        let result: Option<GzipFileEntry> = gzip_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_root_file_entry() -> Result<(), ErrorTrace> {
+        let gzip_file_system: GzipFileSystem = get_file_system()?;
+
+        let gzip_file_entry: GzipFileEntry = gzip_file_system.get_root_file_entry()?;
+
+        let file_type: VfsFileType = gzip_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::Directory);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open() -> Result<(), ErrorTrace> {
+        let mut gzip_file_system: GzipFileSystem = GzipFileSystem::new();
+
+        let parent_file_system: VfsFileSystemReference =
+            VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os));
+        let parent_vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        gzip_file_system.open(Some(&parent_file_system), &parent_vfs_location)?;
+
+        assert_eq!(gzip_file_system.number_of_layers, 1);
+
+        Ok(())
+    }
+
+    // TODO: add tests for open_file
+}
@@ -14,6 +14,7 @@
 mod context;
 mod data_fork;
 mod enums;
+mod extended_attribute;
 mod file_entry;
 mod file_resolver;
 mod file_system;
@@ -29,6 +30,7 @@ mod apm;
 mod ewf;
 mod fake;
 mod gpt;
+mod gzip;
 mod mbr;
 mod os;
 mod qcow;
@@ -36,6 +38,7 @@ mod sparseimage;
 mod udif;
 mod vhd;
 mod vhdx;
+mod vss;
 
 // Helpers.
 mod finder;
@@ -45,10 +48,11 @@ mod scanner;
 pub use context::VfsContext;
 pub use data_fork::VfsDataFork;
 pub use enums::*;
+pub use extended_attribute::VfsExtendedAttribute;
 pub use file_entry::VfsFileEntry;
 pub use file_resolver::{VfsFileResolver, new_vfs_file_resolver};
 pub use file_system::VfsFileSystem;
-pub use finder::VfsFinder;
+pub use finder::{VfsDeletedFinder, VfsFinder};
 pub use location::{VfsLocation, new_os_vfs_location};
 pub use path::VfsPath;
 pub use resolver::VfsResolver;
@@ -41,12 +41,14 @@ impl VfsPath {
             | VfsType::Ewf
             | VfsType::Fake
             | VfsType::Gpt
+            | VfsType::Gzip
             | VfsType::Mbr
             | VfsType::Qcow
             | VfsType::SparseImage
             | VfsType::Udif
             | VfsType::Vhd
-            | VfsType::Vhdx => VfsPath::String(StringPath::from(path)),
+            | VfsType::Vhdx
+            | VfsType::Vss => VfsPath::String(StringPath::from(path)),
             VfsType::Ext => VfsPath::Ext(ExtPath::from(path)),
             VfsType::Fat => VfsPath::Fat(FatPath::from(path)),
             VfsType::Ntfs => VfsPath::Ntfs(NtfsPath::from(path)),
@@ -61,12 +63,14 @@ impl VfsPath {
             | VfsType::Ewf
             | VfsType::Fake
             | VfsType::Gpt
+            | VfsType::Gzip
             | VfsType::Mbr
             | VfsType::Qcow
             | VfsType::SparseImage
             | VfsType::Udif
             | VfsType::Vhd
-            | VfsType::Vhdx => VfsPath::String(StringPath::from(path_components)),
+            | VfsType::Vhdx
+            | VfsType::Vss => VfsPath::String(StringPath::from(path_components)),
             VfsType::Ext => VfsPath::Ext(ExtPath::from(path_components)),
             VfsType::Fat => VfsPath::Fat(FatPath::from(path_components)),
             VfsType::Ntfs => VfsPath::Ntfs(NtfsPath::from(path_components)),
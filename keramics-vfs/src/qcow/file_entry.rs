@@ -34,6 +34,15 @@ pub enum QcowFileEntry {
         /// Storage media image.
         image: Arc<QcowImage>,
     },
+
+    /// Snapshot file entry.
+    Snapshot {
+        /// Snapshot index.
+        index: usize,
+
+        /// Snapshot layer.
+        layer: QcowImageLayer,
+    },
 }
 
 impl QcowFileEntry {
@@ -42,6 +51,7 @@ impl QcowFileEntry {
         match self {
             QcowFileEntry::Layer { layer, .. } => Ok(Some(layer.clone())),
             QcowFileEntry::Root { .. } => Ok(None),
+            QcowFileEntry::Snapshot { layer, .. } => Ok(Some(layer.clone())),
         }
     }
 
@@ -50,6 +60,7 @@ impl QcowFileEntry {
         match self {
             QcowFileEntry::Layer { .. } => VfsFileType::File,
             QcowFileEntry::Root { .. } => VfsFileType::Directory,
+            QcowFileEntry::Snapshot { .. } => VfsFileType::File,
         }
     }
 
@@ -58,6 +69,7 @@ impl QcowFileEntry {
         match self {
             QcowFileEntry::Layer { index, .. } => Some(format!("qcow{}", index + 1)),
             QcowFileEntry::Root { .. } => None,
+            QcowFileEntry::Snapshot { index, .. } => Some(format!("snapshot{}", index + 1)),
         }
     }
 
@@ -65,7 +77,10 @@ impl QcowFileEntry {
     pub fn get_number_of_sub_file_entries(&self) -> Result<usize, ErrorTrace> {
         match self {
             QcowFileEntry::Layer { .. } => Ok(0),
-            QcowFileEntry::Root { image } => Ok(image.get_number_of_layers()),
+            QcowFileEntry::Root { image } => {
+                Ok(image.get_number_of_layers() + image.get_number_of_snapshots())
+            }
+            QcowFileEntry::Snapshot { .. } => Ok(0),
         }
     }
 
@@ -75,25 +90,50 @@ impl QcowFileEntry {
         sub_file_entry_index: usize,
     ) -> Result<QcowFileEntry, ErrorTrace> {
         match self {
-            QcowFileEntry::Layer { .. } => {
+            QcowFileEntry::Layer { .. } | QcowFileEntry::Snapshot { .. } => {
                 Err(keramics_core::error_trace_new!("No sub file entries"))
             }
-            QcowFileEntry::Root { image } => match image.get_layer_by_index(sub_file_entry_index) {
-                Ok(qcow_layer) => Ok(QcowFileEntry::Layer {
-                    index: sub_file_entry_index,
-                    layer: qcow_layer.clone(),
-                }),
-                Err(mut error) => {
-                    keramics_core::error_trace_add_frame!(
-                        error,
-                        format!(
-                            "Unable to retrieve QCOW image layer: {}",
-                            sub_file_entry_index
-                        )
-                    );
-                    return Err(error);
+            QcowFileEntry::Root { image } => {
+                let number_of_layers: usize = image.get_number_of_layers();
+
+                // Snapshots are stored after the layers.
+                if sub_file_entry_index >= number_of_layers {
+                    let snapshot_index: usize = sub_file_entry_index - number_of_layers;
+
+                    return match image.get_snapshot_layer_by_index(snapshot_index) {
+                        Ok(qcow_layer) => Ok(QcowFileEntry::Snapshot {
+                            index: snapshot_index,
+                            layer: qcow_layer.clone(),
+                        }),
+                        Err(mut error) => {
+                            keramics_core::error_trace_add_frame!(
+                                error,
+                                format!(
+                                    "Unable to retrieve QCOW image snapshot: {}",
+                                    snapshot_index
+                                )
+                            );
+                            Err(error)
+                        }
+                    };
                 }
-            },
+                match image.get_layer_by_index(sub_file_entry_index) {
+                    Ok(qcow_layer) => Ok(QcowFileEntry::Layer {
+                        index: sub_file_entry_index,
+                        layer: qcow_layer.clone(),
+                    }),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!(
+                                "Unable to retrieve QCOW image layer: {}",
+                                sub_file_entry_index
+                            )
+                        );
+                        return Err(error);
+                    }
+                }
+            }
         }
     }
 }
@@ -181,5 +221,30 @@ mod tests {
         Ok(())
     }
 
-    // TODO: add tests for get_sub_file_entry_by_index
+    #[test]
+    fn test_get_sub_file_entry_by_index() -> Result<(), ErrorTrace> {
+        let mut qcow_image: QcowImage = QcowImage::new();
+
+        let path_buf: PathBuf = PathBuf::from(get_test_data_path("qcow").as_str());
+        let file_resolver: FileResolverReference = open_os_file_resolver(&path_buf)?;
+        let file_name: PathComponent = PathComponent::from("ext2_snapshot.qcow2");
+        qcow_image.open(&file_resolver, &file_name)?;
+
+        let mut file_entry = QcowFileEntry::Root {
+            image: Arc::new(qcow_image),
+        };
+        let number_of_sub_file_entries: usize = file_entry.get_number_of_sub_file_entries()?;
+        assert_eq!(number_of_sub_file_entries, 2);
+
+        let sub_file_entry: QcowFileEntry = file_entry.get_sub_file_entry_by_index(0)?;
+        assert_eq!(sub_file_entry.get_name(), Some(String::from("qcow1")));
+
+        let sub_file_entry: QcowFileEntry = file_entry.get_sub_file_entry_by_index(1)?;
+        assert_eq!(sub_file_entry.get_name(), Some(String::from("snapshot1")));
+
+        let result: Result<QcowFileEntry, ErrorTrace> = file_entry.get_sub_file_entry_by_index(2);
+        assert!(result.is_err());
+
+        Ok(())
+    }
 }
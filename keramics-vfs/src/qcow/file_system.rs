@@ -31,16 +31,22 @@ pub struct QcowFileSystem {
 
     /// Number of layers.
     number_of_layers: usize,
+
+    /// Number of snapshots.
+    number_of_snapshots: usize,
 }
 
 impl QcowFileSystem {
     pub const PATH_PREFIX: &'static str = "/qcow";
 
+    pub const SNAPSHOT_PATH_PREFIX: &'static str = "/snapshot";
+
     /// Creates a new file system.
     pub fn new() -> Self {
         Self {
             image: Arc::new(QcowImage::new()),
             number_of_layers: 0,
+            number_of_snapshots: 0,
         }
     }
 
@@ -59,7 +65,10 @@ impl QcowFileSystem {
                 if number_of_components == 1 {
                     return Ok(true);
                 }
-                match self.get_layer_index(&string_path.components[1]) {
+                if self.get_layer_index(&string_path.components[1]).is_some() {
+                    return Ok(true);
+                }
+                match self.get_snapshot_index(&string_path.components[1]) {
                     Some(_) => Ok(true),
                     None => Ok(false),
                 }
@@ -88,6 +97,18 @@ impl QcowFileSystem {
 
                     return Ok(Some(qcow_file_entry));
                 }
+                if let Some(snapshot_index) = self.get_snapshot_index(&string_path.components[1]) {
+                    return match self.image.get_snapshot_layer_by_index(snapshot_index) {
+                        Ok(qcow_layer) => Ok(Some(QcowFileEntry::Snapshot {
+                            index: snapshot_index,
+                            layer: qcow_layer.clone(),
+                        })),
+                        Err(error) => Err(keramics_core::error_trace_new_with_error!(
+                            format!("Unable to retrieve QCOW snapshot: {}", snapshot_index),
+                            error
+                        )),
+                    };
+                }
                 let layer_index: usize = match self.get_layer_index(&string_path.components[1]) {
                     Some(layer_index) => layer_index,
                     None => return Ok(None),
@@ -124,6 +145,23 @@ impl QcowFileSystem {
         }
     }
 
+    /// Retrieves the snapshot index.
+    fn get_snapshot_index(&self, file_name: &String) -> Option<usize> {
+        if !file_name.starts_with("snapshot") {
+            return None;
+        }
+        match file_name[8..].parse::<usize>() {
+            Ok(snapshot_index) => {
+                if snapshot_index > 0 && snapshot_index <= self.number_of_snapshots {
+                    Some(snapshot_index - 1)
+                } else {
+                    None
+                }
+            }
+            Err(_) => None,
+        }
+    }
+
     /// Retrieves the root file entry.
     pub fn get_root_file_entry(&self) -> Result<QcowFileEntry, ErrorTrace> {
         Ok(QcowFileEntry::Root {
@@ -157,6 +195,7 @@ impl QcowFileSystem {
                     }
                 }
                 self.number_of_layers = image.get_number_of_layers();
+                self.number_of_snapshots = image.get_number_of_snapshots();
             }
             None => {
                 return Err(keramics_core::error_trace_new!(
@@ -311,6 +350,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_snapshot_index() -> Result<(), ErrorTrace> {
+        let mut qcow_file_system: QcowFileSystem = QcowFileSystem::new();
+
+        let parent_file_system: VfsFileSystemReference =
+            VfsFileSystemReference::new(VfsFileSystem::new(&VfsType::Os));
+        let parent_vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("qcow/ext2_snapshot.qcow2").as_str());
+        qcow_file_system.open(Some(&parent_file_system), &parent_vfs_location)?;
+
+        let file_name: String = String::from("snapshot1");
+        let snapshot_index: Option<usize> = qcow_file_system.get_snapshot_index(&file_name);
+        assert_eq!(snapshot_index, Some(0));
+
+        let file_name: String = String::from("snapshot2");
+        let snapshot_index: Option<usize> = qcow_file_system.get_snapshot_index(&file_name);
+        assert!(snapshot_index.is_none());
+
+        let file_name: String = String::from("qcow1");
+        let snapshot_index: Option<usize> = qcow_file_system.get_snapshot_index(&file_name);
+        assert!(snapshot_index.is_none());
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Qcow, "/snapshot1");
+        let result: Option<QcowFileEntry> = qcow_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_some());
+
+        let qcow_file_entry: QcowFileEntry = result.unwrap();
+
+        let name: Option<String> = qcow_file_entry.get_name();
+        assert_eq!(name, Some(String::from("snapshot1")));
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_root_file_entry() -> Result<(), ErrorTrace> {
         let qcow_file_system: QcowFileSystem = get_file_system()?;
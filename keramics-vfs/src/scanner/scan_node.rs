@@ -22,6 +22,12 @@ pub struct VfsScanNode {
 
     /// Sub nodes.
     pub sub_nodes: Vec<VfsScanNode>,
+
+    /// Value to indicate the format is encrypted.
+    pub is_encrypted: bool,
+
+    /// Value to indicate the format is encrypted and could not be unlocked.
+    pub is_locked: bool,
 }
 
 impl VfsScanNode {
@@ -30,6 +36,8 @@ impl VfsScanNode {
         Self {
             location: location,
             sub_nodes: Vec::new(),
+            is_encrypted: false,
+            is_locked: false,
         }
     }
 
@@ -52,13 +60,15 @@ impl VfsScanNode {
             | VfsType::Ewf
             | VfsType::Fake
             | VfsType::Gpt
+            | VfsType::Gzip
             | VfsType::Mbr
             | VfsType::Os
             | VfsType::Qcow
             | VfsType::SparseImage
             | VfsType::Udif
             | VfsType::Vhd
-            | VfsType::Vhdx => false,
+            | VfsType::Vhdx
+            | VfsType::Vss => false,
             VfsType::Ext | VfsType::Fat | VfsType::Ntfs => true,
         }
     }
@@ -13,18 +13,21 @@
 
 use std::collections::HashSet;
 
+use keramics_core::mediator::{Mediator, MediatorReference};
 use keramics_core::{DataStreamReference, ErrorTrace};
 use keramics_sigscan::BuildError;
 
 use keramics_formats::apm::ApmVolumeSystem;
 use keramics_formats::ewf::EwfImage;
 use keramics_formats::gpt::GptVolumeSystem;
+use keramics_formats::gzip::GzipFile;
 use keramics_formats::mbr::MbrVolumeSystem;
 use keramics_formats::qcow::QcowImage;
 use keramics_formats::sparseimage::SparseImageFile;
 use keramics_formats::udif::UdifFile;
 use keramics_formats::vhd::VhdImage;
 use keramics_formats::vhdx::VhdxImage;
+use keramics_formats::vss::VssVolumeSystem;
 use keramics_formats::{FormatIdentifier, FormatScanner};
 
 use crate::apm::ApmFileSystem;
@@ -33,6 +36,7 @@ use crate::ewf::EwfFileSystem;
 use crate::file_entry::VfsFileEntry;
 use crate::file_system::VfsFileSystem;
 use crate::gpt::GptFileSystem;
+use crate::gzip::GzipFileSystem;
 use crate::location::VfsLocation;
 use crate::mbr::MbrFileSystem;
 use crate::path::VfsPath;
@@ -43,12 +47,16 @@ use crate::types::{VfsFileSystemReference, VfsResolverReference};
 use crate::udif::UdifFileSystem;
 use crate::vhd::VhdFileSystem;
 use crate::vhdx::VhdxFileSystem;
+use crate::vss::VssFileSystem;
 
 use super::scan_context::VfsScanContext;
 use super::scan_node::VfsScanNode;
 
 /// Virtual File System (VFS) scanner.
 pub struct VfsScanner {
+    /// Mediator.
+    mediator: MediatorReference,
+
     /// Resolver.
     resolver: VfsResolverReference,
 
@@ -66,24 +74,30 @@ pub struct VfsScanner {
 
     /// Storage media image format signature scanner.
     storage_media_image_scanner: FormatScanner,
+
+    /// Volume Shadow Snapshots (VSS) format signature scanner.
+    volume_shadow_snapshots_scanner: FormatScanner,
 }
 
 impl VfsScanner {
     /// Creates a new scanner.
     pub fn new() -> Self {
         Self {
+            mediator: Mediator::current(),
             resolver: VfsResolver::current(),
             file_system_scanner: FormatScanner::new(),
             phase1_volume_system_scanner: FormatScanner::new(),
             phase2_volume_system_scanner: FormatScanner::new(),
             phase3_volume_system_scanner: FormatScanner::new(),
             storage_media_image_scanner: FormatScanner::new(),
+            volume_shadow_snapshots_scanner: FormatScanner::new(),
         }
     }
 
     /// Builds the scanner.
     pub fn build(&mut self) -> Result<(), BuildError> {
         self.storage_media_image_scanner.add_ewf_signatures();
+        self.storage_media_image_scanner.add_gzip_signatures();
         self.storage_media_image_scanner.add_qcow_signatures();
         self.storage_media_image_scanner
             .add_sparseimage_signatures();
@@ -119,6 +133,11 @@ impl VfsScanner {
         self.file_system_scanner.add_ntfs_signatures();
         self.file_system_scanner.build()?;
 
+        // Volume Shadow Snapshots (VSS) are stored within a NTFS volume and
+        // are scanned for separately after a NTFS file system was found.
+        self.volume_shadow_snapshots_scanner.add_vss_signatures();
+        self.volume_shadow_snapshots_scanner.build()?;
+
         Ok(())
     }
 
@@ -179,9 +198,10 @@ impl VfsScanner {
             }
         };
         match vfs_location.get_type() {
-            VfsType::Apm { .. } | VfsType::Gpt { .. } | VfsType::Mbr { .. } => {
-                self.scan_for_file_system_format(&data_stream)
-            }
+            VfsType::Apm { .. }
+            | VfsType::Gpt { .. }
+            | VfsType::Mbr { .. }
+            | VfsType::Vss { .. } => self.scan_for_file_system_format(&data_stream),
             VfsType::Ewf { .. }
             | VfsType::Qcow { .. }
             | VfsType::Vhd { .. }
@@ -194,7 +214,7 @@ impl VfsScanner {
                 }
                 Ok(result)
             }
-            VfsType::Fake { .. } | VfsType::Os { .. } => {
+            VfsType::Fake { .. } | VfsType::Gzip { .. } | VfsType::Os { .. } => {
                 let mut result: Option<VfsType> =
                     self.scan_for_storage_media_image_format(&data_stream)?;
 
@@ -272,6 +292,7 @@ impl VfsScanner {
         match scan_results.iter().next() {
             Some(format_identifier) => match format_identifier {
                 FormatIdentifier::Ewf => Ok(Some(VfsType::Ewf)),
+                FormatIdentifier::Gzip => Ok(Some(VfsType::Gzip)),
                 FormatIdentifier::Qcow => Ok(Some(VfsType::Qcow)),
                 FormatIdentifier::SparseImage => Ok(Some(VfsType::SparseImage)),
                 FormatIdentifier::Udif => Ok(Some(VfsType::Udif)),
@@ -326,12 +347,91 @@ impl VfsScanner {
                 self.scan_for_sub_nodes(&node_file_system, &node_vfs_location, &mut sub_scan_node)?;
 
                 scan_node.sub_nodes.push(sub_scan_node);
+
+                if sub_node_vfs_type == VfsType::Ntfs {
+                    self.scan_for_volume_shadow_snapshots_sub_nodes(
+                        &node_file_system,
+                        &node_vfs_location,
+                        scan_node,
+                    );
+                }
             }
             None => {}
         };
         Ok(())
     }
 
+    /// Scans for storage media image snapshot sub nodes.
+    fn scan_for_storage_media_image_snapshot_sub_nodes(
+        &self,
+        vfs_location: &VfsLocation,
+        scan_node: &mut VfsScanNode,
+        path_prefix: &str,
+        number_of_snapshots: usize,
+    ) -> Result<(), ErrorTrace> {
+        if number_of_snapshots == 0 {
+            return Ok(());
+        }
+        let vfs_type: &VfsType = scan_node.get_type();
+
+        let vfs_path: VfsPath = VfsPath::from_path(vfs_type, "/");
+        let file_system_vfs_location: VfsLocation = vfs_location.new_with_layer(vfs_type, vfs_path);
+        let node_file_system: VfsFileSystemReference =
+            match self.resolver.open_file_system(&file_system_vfs_location) {
+                Ok(file_system) => file_system,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to open file system");
+                    return Err(error);
+                }
+            };
+        for snapshot_index in 0..number_of_snapshots {
+            let vfs_type: &VfsType = scan_node.get_type();
+
+            let snapshot_path: String = format!("{}{}", path_prefix, snapshot_index + 1);
+
+            let node_vfs_path: VfsPath = VfsPath::from_path(vfs_type, snapshot_path.as_str());
+            let node_vfs_location: VfsLocation =
+                vfs_location.new_with_layer(vfs_type, node_vfs_path);
+            let mut snapshot_scan_node: VfsScanNode = VfsScanNode::new(node_vfs_location);
+
+            match self.scan_for_format(&node_file_system, &snapshot_scan_node.location)? {
+                Some(sub_node_vfs_type) => {
+                    let root_path: &str = match sub_node_vfs_type {
+                        VfsType::Ntfs { .. } => "\\",
+                        _ => "/",
+                    };
+                    let sub_node_vfs_path: VfsPath =
+                        VfsPath::from_path(&sub_node_vfs_type, root_path);
+                    let sub_node_vfs_location: VfsLocation = snapshot_scan_node
+                        .location
+                        .new_with_layer(&sub_node_vfs_type, sub_node_vfs_path);
+                    let mut sub_scan_node: VfsScanNode = VfsScanNode::new(sub_node_vfs_location);
+                    self.scan_for_sub_nodes(
+                        &node_file_system,
+                        &snapshot_scan_node.location,
+                        &mut sub_scan_node,
+                    )?;
+
+                    snapshot_scan_node.sub_nodes.push(sub_scan_node);
+
+                    if sub_node_vfs_type == VfsType::Ntfs {
+                        let snapshot_vfs_location: VfsLocation =
+                            snapshot_scan_node.location.clone();
+
+                        self.scan_for_volume_shadow_snapshots_sub_nodes(
+                            &node_file_system,
+                            &snapshot_vfs_location,
+                            &mut snapshot_scan_node,
+                        );
+                    }
+                }
+                None => {}
+            };
+            scan_node.sub_nodes.push(snapshot_scan_node);
+        }
+        Ok(())
+    }
+
     /// Scans a node for supported formats.
     fn scan_for_sub_nodes(
         &self,
@@ -438,6 +538,29 @@ impl VfsScanner {
                     }
                 }
             }
+            VfsType::Gzip { .. } => {
+                let mut gzip_file: GzipFile = GzipFile::new();
+
+                match GzipFileSystem::open_file(&mut gzip_file, file_system, vfs_path) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(error, "Unable to open gzip file");
+                        return Err(error);
+                    }
+                }
+                match self.scan_for_storage_media_image_sub_nodes(
+                    vfs_location,
+                    scan_node,
+                    GzipFileSystem::PATH_PREFIX,
+                    1,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(error, "Unable to scan gzip file");
+                        return Err(error);
+                    }
+                }
+            }
             VfsType::Mbr { .. } => {
                 let mut mbr_volume_system: MbrVolumeSystem = MbrVolumeSystem::new();
 
@@ -494,6 +617,14 @@ impl VfsScanner {
                         }
                     }
                     scan_node.sub_nodes.push(sub_scan_node);
+
+                    if sub_node_vfs_type == VfsType::Ntfs {
+                        self.scan_for_volume_shadow_snapshots_sub_nodes(
+                            file_system,
+                            vfs_location,
+                            scan_node,
+                        );
+                    }
                 }
                 None => {}
             },
@@ -507,6 +638,13 @@ impl VfsScanner {
                         return Err(error);
                     }
                 }
+                scan_node.is_encrypted = qcow_image.is_encrypted();
+                scan_node.is_locked = qcow_image.is_locked();
+
+                // The contents of a locked image cannot be scanned.
+                if scan_node.is_locked {
+                    return Ok(());
+                }
                 let number_of_layers: usize = qcow_image.get_number_of_layers();
 
                 match self.scan_for_storage_media_image_sub_nodes(
@@ -521,6 +659,23 @@ impl VfsScanner {
                         return Err(error);
                     }
                 }
+                let number_of_snapshots: usize = qcow_image.get_number_of_snapshots();
+
+                match self.scan_for_storage_media_image_snapshot_sub_nodes(
+                    vfs_location,
+                    scan_node,
+                    QcowFileSystem::SNAPSHOT_PATH_PREFIX,
+                    number_of_snapshots,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to scan QCOW image snapshots"
+                        );
+                        return Err(error);
+                    }
+                }
             }
             VfsType::SparseImage { .. } => {
                 let mut sparseimage_file: SparseImageFile = SparseImageFile::new();
@@ -625,6 +780,41 @@ impl VfsScanner {
                     }
                 }
             }
+            VfsType::Vss { .. } => {
+                let mut vss_volume_system: VssVolumeSystem = VssVolumeSystem::new();
+
+                match VssFileSystem::open_volume_system(
+                    &mut vss_volume_system,
+                    file_system,
+                    vfs_path,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to open VSS volume system"
+                        );
+                        return Err(error);
+                    }
+                };
+                let number_of_stores: usize = vss_volume_system.get_number_of_stores();
+
+                match self.scan_for_volume_system_sub_nodes(
+                    vfs_location,
+                    scan_node,
+                    VssFileSystem::PATH_PREFIX,
+                    number_of_stores,
+                ) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to scan VSS volume system"
+                        );
+                        return Err(error);
+                    }
+                }
+            }
             _ => {
                 return Err(keramics_core::error_trace_new!(
                     "Unsupported VFS location type"
@@ -634,6 +824,89 @@ impl VfsScanner {
         Ok(())
     }
 
+    /// Scans for Volume Shadow Snapshots (VSS) sub nodes.
+    ///
+    /// Failing to scan the VSS volume system is not fatal, since the file system that contains
+    /// it can still be accessed. The error is reported as debug output and the VSS scan node is
+    /// skipped.
+    fn scan_for_volume_shadow_snapshots_sub_nodes(
+        &self,
+        file_system: &VfsFileSystemReference,
+        vfs_location: &VfsLocation,
+        scan_node: &mut VfsScanNode,
+    ) {
+        // Volume Shadow Snapshots themselves can contain a NTFS file system
+        // with Volume Shadow Snapshots metadata, which should not be scanned.
+        if vfs_location.get_type() == &VfsType::Vss {
+            return;
+        }
+        match self.scan_for_volume_shadow_snapshots_scan_node(file_system, vfs_location) {
+            Ok(Some(vss_scan_node)) => scan_node.sub_nodes.push(vss_scan_node),
+            Ok(None) => {}
+            Err(error) => {
+                if self.mediator.debug_output {
+                    self.mediator.debug_print(format!(
+                        "VfsScanner: unable to scan for VSS volume system in: {} with error:\n{}\n",
+                        vfs_location.to_string(),
+                        error
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Scans for a Volume Shadow Snapshots (VSS) scan node.
+    fn scan_for_volume_shadow_snapshots_scan_node(
+        &self,
+        file_system: &VfsFileSystemReference,
+        vfs_location: &VfsLocation,
+    ) -> Result<Option<VfsScanNode>, ErrorTrace> {
+        let vfs_path: &VfsPath = vfs_location.get_path();
+        let data_stream: DataStreamReference =
+            match file_system.get_data_stream_by_path_and_name(vfs_path, None) {
+                Ok(Some(data_stream)) => data_stream,
+                Ok(None) => {
+                    return Err(keramics_core::error_trace_new!(format!(
+                        "No such file: {}",
+                        vfs_location.to_string()
+                    )));
+                }
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to retrieve data stream");
+                    return Err(error);
+                }
+            };
+        let scan_results: HashSet<FormatIdentifier> = match self
+            .volume_shadow_snapshots_scanner
+            .scan_data_stream(&data_stream)
+        {
+            Ok(scan_results) => scan_results,
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to scan data stream for known VSS format signatures"
+                );
+                return Err(error);
+            }
+        };
+        if !scan_results.contains(&FormatIdentifier::Vss) {
+            return Ok(None);
+        }
+        let vss_vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/");
+        let vss_vfs_location: VfsLocation =
+            vfs_location.new_with_layer(&VfsType::Vss, vss_vfs_path);
+        let mut vss_scan_node: VfsScanNode = VfsScanNode::new(vss_vfs_location);
+
+        match self.scan_for_sub_nodes(file_system, vfs_location, &mut vss_scan_node) {
+            Ok(_) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(error, "Unable to scan VSS volume system");
+                return Err(error);
+            }
+        }
+        Ok(Some(vss_scan_node))
+    }
+
     /// Scans a data stream for a supported volume system format.
     fn scan_for_volume_system_format(
         &self,
@@ -781,6 +1054,16 @@ impl VfsScanner {
                     )?;
 
                     volume_scan_node.sub_nodes.push(sub_scan_node);
+
+                    if sub_node_vfs_type == VfsType::Ntfs {
+                        let volume_vfs_location: VfsLocation = volume_scan_node.location.clone();
+
+                        self.scan_for_volume_shadow_snapshots_sub_nodes(
+                            &node_file_system,
+                            &volume_vfs_location,
+                            &mut volume_scan_node,
+                        );
+                    }
                 }
                 None => {}
             };
@@ -794,6 +1077,8 @@ impl VfsScanner {
 mod tests {
     use super::*;
 
+    use keramics_core::mediator::Mediator;
+
     use crate::context::VfsContext;
     use crate::location::new_os_vfs_location;
 
@@ -860,6 +1145,128 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_scan_with_gzip() -> Result<(), ErrorTrace> {
+        let mut format_scanner: VfsScanner = VfsScanner::new();
+        match format_scanner.build() {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to build format scanner",
+                    error
+                ));
+            }
+        }
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("gzip/ext2.raw.gz").as_str());
+        let mut scan_context: VfsScanContext = VfsScanContext::new();
+        format_scanner.scan(&mut scan_context, &vfs_location)?;
+
+        let scan_node: &VfsScanNode = scan_context.root_node.as_ref().unwrap();
+        let vfs_type: &VfsType = scan_node.get_type();
+        assert!(vfs_type == &VfsType::Os);
+        assert_eq!(scan_node.sub_nodes.len(), 1);
+
+        let scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = scan_node.get_type();
+        assert!(vfs_type == &VfsType::Gzip);
+        assert_eq!(scan_node.sub_nodes.len(), 1);
+
+        let scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = scan_node.get_type();
+        assert!(vfs_type == &VfsType::Ext);
+        assert_eq!(scan_node.sub_nodes.len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_with_qcow_snapshot() -> Result<(), ErrorTrace> {
+        let mut format_scanner: VfsScanner = VfsScanner::new();
+        match format_scanner.build() {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to build format scanner",
+                    error
+                ));
+            }
+        }
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("qcow/ext2_snapshot.qcow2").as_str());
+        let mut scan_context: VfsScanContext = VfsScanContext::new();
+        format_scanner.scan(&mut scan_context, &vfs_location)?;
+
+        let scan_node: &VfsScanNode = scan_context.root_node.as_ref().unwrap();
+        let scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = scan_node.get_type();
+        assert!(vfs_type == &VfsType::Qcow);
+        assert_eq!(scan_node.sub_nodes.len(), 2);
+
+        let sub_scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = sub_scan_node.get_type();
+        assert!(vfs_type == &VfsType::Ext);
+
+        let sub_scan_node: &VfsScanNode = scan_node.sub_nodes.get(1).unwrap();
+        let vfs_type: &VfsType = sub_scan_node.get_type();
+        assert!(vfs_type == &VfsType::Qcow);
+        assert_eq!(sub_scan_node.location.get_path().to_string(), "/snapshot1");
+        assert_eq!(sub_scan_node.sub_nodes.len(), 1);
+
+        let sub_scan_node: &VfsScanNode = sub_scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = sub_scan_node.get_type();
+        assert!(vfs_type == &VfsType::Ext);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_with_qcow_encryption() -> Result<(), ErrorTrace> {
+        let mut format_scanner: VfsScanner = VfsScanner::new();
+        match format_scanner.build() {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to build format scanner",
+                    error
+                ));
+            }
+        }
+        let vfs_location: VfsLocation =
+            new_os_vfs_location(get_test_data_path("qcow/ext2_luks.qcow2").as_str());
+        let mut scan_context: VfsScanContext = VfsScanContext::new();
+        format_scanner.scan(&mut scan_context, &vfs_location)?;
+
+        let scan_node: &VfsScanNode = scan_context.root_node.as_ref().unwrap();
+        let scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = scan_node.get_type();
+        assert!(vfs_type == &VfsType::Qcow);
+        assert!(scan_node.is_encrypted);
+        assert!(scan_node.is_locked);
+        assert_eq!(scan_node.sub_nodes.len(), 0);
+
+        Mediator {
+            debug_output: false,
+            credential_callback: Some(Box::new(|_: &str| Some(String::from("keramics")))),
+        }
+        .make_current();
+
+        let mut scan_context: VfsScanContext = VfsScanContext::new();
+        format_scanner.scan(&mut scan_context, &vfs_location)?;
+
+        let scan_node: &VfsScanNode = scan_context.root_node.as_ref().unwrap();
+        let scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        assert!(scan_node.is_encrypted);
+        assert!(!scan_node.is_locked);
+        assert_eq!(scan_node.sub_nodes.len(), 1);
+
+        let sub_scan_node: &VfsScanNode = scan_node.sub_nodes.get(0).unwrap();
+        let vfs_type: &VfsType = sub_scan_node.get_type();
+        assert!(vfs_type == &VfsType::Ext);
+
+        Ok(())
+    }
+
     #[test]
     fn test_scan_for_format() -> Result<(), ErrorTrace> {
         let mut format_scanner: VfsScanner = VfsScanner::new();
@@ -999,6 +1406,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_scan_for_storage_media_image_format_with_gzip() -> Result<(), ErrorTrace> {
+        let mut format_scanner: VfsScanner = VfsScanner::new();
+        match format_scanner.build() {
+            Ok(_) => {}
+            Err(error) => {
+                return Err(keramics_core::error_trace_new_with_error!(
+                    "Unable to build format scanner",
+                    error
+                ));
+            }
+        }
+        let data_stream: DataStreamReference =
+            get_data_stream(get_test_data_path("gzip/ext2.raw.gz").as_str())?;
+        let vfs_type: VfsType = format_scanner
+            .scan_for_storage_media_image_format(&data_stream)?
+            .unwrap();
+
+        assert!(vfs_type == VfsType::Gzip);
+
+        Ok(())
+    }
+
     #[test]
     fn test_scan_for_file_system_format_with_ntfs() -> Result<(), ErrorTrace> {
         let mut format_scanner: VfsScanner = VfsScanner::new();
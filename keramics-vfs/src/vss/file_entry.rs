@@ -0,0 +1,289 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::sync::{Arc, RwLock};
+
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_datetime::DateTime;
+use keramics_formats::vss::{VssStore, VssVolumeSystem};
+use keramics_types::Uuid;
+
+use crate::enums::VfsFileType;
+
+/// Volume Shadow Snapshots (VSS) file entry.
+pub enum VssFileEntry {
+    /// Root file entry.
+    Root {
+        /// Volume system.
+        volume_system: Arc<VssVolumeSystem>,
+    },
+
+    /// Store file entry.
+    Store {
+        /// Store index.
+        index: usize,
+
+        /// Store identifier.
+        identifier: Uuid,
+
+        /// Creation time.
+        creation_time: DateTime,
+
+        /// Store.
+        store: Arc<RwLock<VssStore>>,
+    },
+}
+
+impl VssFileEntry {
+    /// Creates a new store file entry.
+    pub(crate) fn new_store(index: usize, store: VssStore) -> Self {
+        Self::Store {
+            index: index,
+            identifier: store.identifier.clone(),
+            creation_time: store.creation_time.clone(),
+            store: Arc::new(RwLock::new(store)),
+        }
+    }
+
+    /// Retrieves the creation time.
+    pub fn get_creation_time(&self) -> Option<&DateTime> {
+        match self {
+            VssFileEntry::Root { .. } => None,
+            VssFileEntry::Store { creation_time, .. } => Some(creation_time),
+        }
+    }
+
+    /// Retrieves the default data stream.
+    pub fn get_data_stream(&self) -> Result<Option<DataStreamReference>, ErrorTrace> {
+        match self {
+            VssFileEntry::Root { .. } => Ok(None),
+            VssFileEntry::Store { store, .. } => Ok(Some(store.clone())),
+        }
+    }
+
+    /// Retrieves the file type.
+    pub fn get_file_type(&self) -> VfsFileType {
+        match self {
+            VssFileEntry::Root { .. } => VfsFileType::Directory,
+            VssFileEntry::Store { .. } => VfsFileType::File,
+        }
+    }
+
+    /// Retrieves the identifier.
+    pub fn get_identifier(&self) -> Option<Uuid> {
+        match self {
+            VssFileEntry::Root { .. } => None,
+            VssFileEntry::Store { identifier, .. } => Some(identifier.clone()),
+        }
+    }
+
+    /// Retrieves the name.
+    pub fn get_name(&self) -> Option<String> {
+        match self {
+            VssFileEntry::Root { .. } => None,
+            VssFileEntry::Store { index, .. } => Some(format!("vss{}", index + 1)),
+        }
+    }
+
+    /// Retrieves the number of sub file entries.
+    pub fn get_number_of_sub_file_entries(&self) -> Result<usize, ErrorTrace> {
+        match self {
+            VssFileEntry::Root { volume_system } => Ok(volume_system.get_number_of_stores()),
+            VssFileEntry::Store { .. } => Ok(0),
+        }
+    }
+
+    /// Retrieves a specific sub file entry.
+    pub fn get_sub_file_entry_by_index(
+        &mut self,
+        sub_file_entry_index: usize,
+    ) -> Result<VssFileEntry, ErrorTrace> {
+        match self {
+            VssFileEntry::Root { volume_system } => {
+                match volume_system.get_store_by_index(sub_file_entry_index) {
+                    Ok(vss_store) => Ok(VssFileEntry::new_store(sub_file_entry_index, vss_store)),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to retrieve VSS store: {}", sub_file_entry_index)
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+            VssFileEntry::Store { .. } => {
+                Err(keramics_core::error_trace_new!("No sub file entries"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keramics_core::open_fake_data_stream;
+    use keramics_datetime::Filetime;
+
+    use super::super::tests::get_test_data;
+
+    fn get_volume_system() -> Result<VssVolumeSystem, ErrorTrace> {
+        let mut volume_system: VssVolumeSystem = VssVolumeSystem::new();
+
+        let test_data: Vec<u8> = get_test_data();
+        let data_stream: DataStreamReference = open_fake_data_stream(&test_data);
+        volume_system.read_data_stream(&data_stream)?;
+
+        Ok(volume_system)
+    }
+
+    #[test]
+    fn test_get_creation_time() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        assert_eq!(file_entry.get_creation_time(), None);
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let file_entry = VssFileEntry::new_store(0, vss_store);
+
+        assert_eq!(
+            file_entry.get_creation_time(),
+            Some(&DateTime::Filetime(Filetime::new(0x01d0c4a6d0f9b8f0)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_data_stream() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        let result: Option<DataStreamReference> = file_entry.get_data_stream()?;
+        assert!(result.is_none());
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let file_entry = VssFileEntry::new_store(0, vss_store);
+
+        let result: Option<DataStreamReference> = file_entry.get_data_stream()?;
+        assert!(result.is_some());
+
+        let data_stream: DataStreamReference = result.unwrap();
+
+        let mut data: Vec<u8> = vec![0; 512];
+        keramics_core::data_stream_read_exact_at_position!(
+            data_stream,
+            &mut data,
+            std::io::SeekFrom::Start(0x10000)
+        );
+        assert_eq!(data, [0x55; 512]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_type() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        let file_type: VfsFileType = file_entry.get_file_type();
+        assert!(file_type == VfsFileType::Directory);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_identifier() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let file_entry = VssFileEntry::new_store(0, vss_store);
+
+        let identifier: Option<Uuid> = file_entry.get_identifier();
+        assert_eq!(
+            identifier.unwrap().to_string(),
+            "00000001-0000-0000-0000-000000000000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_name() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        let name: Option<String> = file_entry.get_name();
+        assert!(name.is_none());
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let file_entry = VssFileEntry::new_store(0, vss_store);
+
+        let name: Option<String> = file_entry.get_name();
+        assert_eq!(name, Some(String::from("vss1")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_number_of_sub_file_entries() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        let number_of_sub_file_entries: usize = file_entry.get_number_of_sub_file_entries()?;
+        assert_eq!(number_of_sub_file_entries, 1);
+
+        let vss_store: VssStore = vss_volume_system.get_store_by_index(0)?;
+        let file_entry = VssFileEntry::new_store(0, vss_store);
+
+        let number_of_sub_file_entries: usize = file_entry.get_number_of_sub_file_entries()?;
+        assert_eq!(number_of_sub_file_entries, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_sub_file_entry_by_index() -> Result<(), ErrorTrace> {
+        let vss_volume_system: Arc<VssVolumeSystem> = Arc::new(get_volume_system()?);
+
+        let mut file_entry = VssFileEntry::Root {
+            volume_system: vss_volume_system.clone(),
+        };
+
+        let sub_file_entry: VssFileEntry = file_entry.get_sub_file_entry_by_index(0)?;
+
+        let name: Option<String> = sub_file_entry.get_name();
+        assert_eq!(name, Some(String::from("vss1")));
+
+        let result = file_entry.get_sub_file_entry_by_index(1);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
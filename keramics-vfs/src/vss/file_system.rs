@@ -0,0 +1,346 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::sync::Arc;
+
+use keramics_core::{DataStreamReference, ErrorTrace};
+use keramics_formats::vss::VssVolumeSystem;
+
+use crate::location::VfsLocation;
+use crate::path::VfsPath;
+use crate::types::VfsFileSystemReference;
+
+use super::file_entry::VssFileEntry;
+
+/// Volume Shadow Snapshots (VSS) file system.
+pub struct VssFileSystem {
+    /// Volume system.
+    volume_system: Arc<VssVolumeSystem>,
+
+    /// Number of stores.
+    number_of_stores: usize,
+}
+
+impl VssFileSystem {
+    pub const PATH_PREFIX: &'static str = "/vss";
+
+    /// Creates a new file system.
+    pub fn new() -> Self {
+        Self {
+            volume_system: Arc::new(VssVolumeSystem::new()),
+            number_of_stores: 0,
+        }
+    }
+
+    /// Determines if the file entry with the specified path exists.
+    pub fn file_entry_exists(&self, vfs_path: &VfsPath) -> Result<bool, ErrorTrace> {
+        match vfs_path {
+            VfsPath::String(string_path) => {
+                let number_of_components: usize = string_path.components.len();
+                if number_of_components == 0 || number_of_components > 2 {
+                    return Ok(false);
+                }
+                if string_path.components[0] != "" {
+                    return Ok(false);
+                }
+                // A single empty component represents "/".
+                if number_of_components == 1 {
+                    return Ok(true);
+                }
+                match self.get_store_index(&string_path.components[1]) {
+                    Some(_) => Ok(true),
+                    None => Ok(false),
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!("Unsupported VFS path type")),
+        }
+    }
+
+    /// Retrieves the file entry with the specific location.
+    pub fn get_file_entry_by_path(
+        &self,
+        vfs_path: &VfsPath,
+    ) -> Result<Option<VssFileEntry>, ErrorTrace> {
+        match vfs_path {
+            VfsPath::String(string_path) => {
+                let number_of_components: usize = string_path.components.len();
+                if number_of_components == 0 || number_of_components > 2 {
+                    return Ok(None);
+                }
+                if string_path.components[0] != "" {
+                    return Ok(None);
+                }
+                // A single empty component represents "/".
+                if number_of_components == 1 {
+                    let vss_file_entry: VssFileEntry = self.get_root_file_entry()?;
+
+                    return Ok(Some(vss_file_entry));
+                }
+                let store_index: usize = match self.get_store_index(&string_path.components[1]) {
+                    Some(store_index) => store_index,
+                    None => return Ok(None),
+                };
+                match self.volume_system.get_store_by_index(store_index) {
+                    Ok(vss_store) => Ok(Some(VssFileEntry::new_store(store_index, vss_store))),
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            format!("Unable to retrieve VSS store: {}", store_index)
+                        );
+                        return Err(error);
+                    }
+                }
+            }
+            _ => Err(keramics_core::error_trace_new!("Unsupported VFS path type")),
+        }
+    }
+
+    /// Retrieves the root file entry.
+    pub fn get_root_file_entry(&self) -> Result<VssFileEntry, ErrorTrace> {
+        Ok(VssFileEntry::Root {
+            volume_system: self.volume_system.clone(),
+        })
+    }
+
+    /// Retrieves the store index.
+    fn get_store_index(&self, file_name: &String) -> Option<usize> {
+        if !file_name.starts_with("vss") {
+            return None;
+        }
+        match file_name[3..].parse::<usize>() {
+            Ok(store_index) => {
+                if store_index > 0 && store_index <= self.number_of_stores {
+                    Some(store_index - 1)
+                } else {
+                    None
+                }
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Opens the file system.
+    pub fn open(
+        &mut self,
+        parent_file_system: Option<&VfsFileSystemReference>,
+        vfs_location: &VfsLocation,
+    ) -> Result<(), ErrorTrace> {
+        let file_system: &VfsFileSystemReference = match parent_file_system {
+            Some(file_system) => file_system,
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Missing parent file system"
+                ));
+            }
+        };
+        let vfs_path: &VfsPath = vfs_location.get_path();
+
+        match Arc::get_mut(&mut self.volume_system) {
+            Some(volume_system) => {
+                match Self::open_volume_system(volume_system, file_system, vfs_path) {
+                    Ok(_) => {}
+                    Err(mut error) => {
+                        keramics_core::error_trace_add_frame!(
+                            error,
+                            "Unable to open VSS volume system"
+                        );
+                        return Err(error);
+                    }
+                }
+                self.number_of_stores = volume_system.get_number_of_stores();
+            }
+            None => {
+                return Err(keramics_core::error_trace_new!(
+                    "Unable to obtain mutable reference to VSS volume system"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens a VSS volume system.
+    pub(crate) fn open_volume_system(
+        volume_system: &mut VssVolumeSystem,
+        file_system: &VfsFileSystemReference,
+        vfs_path: &VfsPath,
+    ) -> Result<(), ErrorTrace> {
+        let result: Option<DataStreamReference> =
+            match file_system.get_data_stream_by_path_and_name(vfs_path, None) {
+                Ok(result) => result,
+                Err(mut error) => {
+                    keramics_core::error_trace_add_frame!(error, "Unable to retrieve data stream");
+                    return Err(error);
+                }
+            };
+        let data_stream: DataStreamReference = match result {
+            Some(data_stream) => data_stream,
+            None => {
+                return Err(keramics_core::error_trace_new!("Missing data stream"));
+            }
+        };
+        match volume_system.read_data_stream(&data_stream) {
+            Ok(()) => {}
+            Err(mut error) => {
+                keramics_core::error_trace_add_frame!(
+                    error,
+                    "Unable to read VSS volume system from data stream"
+                );
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::enums::{VfsFileType, VfsType};
+    use crate::fake::FakeFileEntry;
+    use crate::file_system::VfsFileSystem;
+
+    use super::super::tests::get_test_data;
+
+    fn get_parent_file_system() -> VfsFileSystemReference {
+        let mut vfs_file_system: VfsFileSystem = VfsFileSystem::new(&VfsType::Fake);
+        if let VfsFileSystem::Fake(fake_file_system) = &mut vfs_file_system {
+            let test_data: Vec<u8> = get_test_data();
+            let fake_file_entry: FakeFileEntry = FakeFileEntry::new_file(&test_data);
+            _ = fake_file_system.add_file_entry("/ntfs.raw", fake_file_entry);
+        }
+        VfsFileSystemReference::new(vfs_file_system)
+    }
+
+    fn get_file_system() -> Result<VssFileSystem, ErrorTrace> {
+        let mut vss_file_system: VssFileSystem = VssFileSystem::new();
+
+        let parent_file_system: VfsFileSystemReference = get_parent_file_system();
+        let parent_vfs_location: VfsLocation = VfsLocation::new_base(
+            &VfsType::Fake,
+            VfsPath::from_path(&VfsType::Fake, "/ntfs.raw"),
+        );
+        vss_file_system.open(Some(&parent_file_system), &parent_vfs_location)?;
+
+        Ok(vss_file_system)
+    }
+
+    #[test]
+    fn test_file_entry_exists() -> Result<(), ErrorTrace> {
+        let vss_file_system: VssFileSystem = get_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/");
+        let result: bool = vss_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, true);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/vss1");
+        let result: bool = vss_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, true);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/vss2");
+        let result: bool = vss_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, false);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/vss1/bogus1");
+        let result: bool = vss_file_system.file_entry_exists(&vfs_path)?;
+        assert_eq!(result, false);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Os, "/");
+        let result: Result<bool, ErrorTrace> = vss_file_system.file_entry_exists(&vfs_path);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_file_entry_by_path() -> Result<(), ErrorTrace> {
+        let vss_file_system: VssFileSystem = get_file_system()?;
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/");
+        let result: Option<VssFileEntry> = vss_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_some());
+
+        let vss_file_entry: VssFileEntry = result.unwrap();
+
+        let name: Option<String> = vss_file_entry.get_name();
+        assert!(name.is_none());
+
+        let file_type: VfsFileType = vss_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::Directory);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/vss1");
+        let result: Option<VssFileEntry> = vss_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_some());
+
+        let vss_file_entry: VssFileEntry = result.unwrap();
+
+        let name: Option<String> = vss_file_entry.get_name();
+        assert_eq!(name, Some(String::from("vss1")));
+
+        let file_type: VfsFileType = vss_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::File);
+
+        let vfs_path: VfsPath = VfsPath::from_path(&VfsType::Vss, "/bogus1");
+        let result: Option<VssFileEntry> = vss_file_system.get_file_entry_by_path(&vfs_path)?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_root_file_entry() -> Result<(), ErrorTrace> {
+        let vss_file_system: VssFileSystem = get_file_system()?;
+
+        let vss_file_entry: VssFileEntry = vss_file_system.get_root_file_entry()?;
+
+        let file_type: VfsFileType = vss_file_entry.get_file_type();
+        assert!(file_type == VfsFileType::Directory);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_store_index() -> Result<(), ErrorTrace> {
+        let vss_file_system: VssFileSystem = get_file_system()?;
+
+        let file_name: String = String::from("vss1");
+        let store_index: Option<usize> = vss_file_system.get_store_index(&file_name);
+        assert_eq!(store_index, Some(0));
+
+        let file_name: String = String::from("vss99");
+        let store_index: Option<usize> = vss_file_system.get_store_index(&file_name);
+        assert!(store_index.is_none());
+
+        let file_name: String = String::from("bogus1");
+        let store_index: Option<usize> = vss_file_system.get_store_index(&file_name);
+        assert!(store_index.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open() -> Result<(), ErrorTrace> {
+        let mut vss_file_system: VssFileSystem = VssFileSystem::new();
+
+        let parent_file_system: VfsFileSystemReference = get_parent_file_system();
+        let parent_vfs_location: VfsLocation = VfsLocation::new_base(
+            &VfsType::Fake,
+            VfsPath::from_path(&VfsType::Fake, "/ntfs.raw"),
+        );
+        vss_file_system.open(Some(&parent_file_system), &parent_vfs_location)?;
+
+        assert_eq!(vss_file_system.number_of_stores, 1);
+
+        Ok(())
+    }
+}
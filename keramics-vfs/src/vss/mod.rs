@@ -0,0 +1,73 @@
+/* Copyright 2024-2025 Joachim Metz <joachim.metz@gmail.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License. You may
+ * obtain a copy of the License at https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+ * WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+ * License for the specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod file_entry;
+mod file_system;
+
+pub use file_entry::VssFileEntry;
+pub use file_system::VssFileSystem;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    /// Retrieves a synthetic NTFS volume with a single shadow copy store.
+    pub fn get_test_data() -> Vec<u8> {
+        let vss_identifier: [u8; 16] = [
+            0x6b, 0x87, 0x08, 0x38, 0x76, 0xc1, 0x48, 0x4e, 0xb7, 0xae, 0x04, 0x04, 0x6e, 0x6c,
+            0xc7, 0x52,
+        ];
+        let mut data: Vec<u8> = vec![0; 0x20000];
+
+        // NTFS boot record signature.
+        data[3..11].copy_from_slice(&[0x4e, 0x54, 0x46, 0x53, 0x20, 0x20, 0x20, 0x20]);
+
+        // Volume header.
+        data[0x1e00..0x1e10].copy_from_slice(&vss_identifier);
+        data[0x1e10] = 0x01;
+        data[0x1e14] = 0x01;
+        data[0x1e31] = 0x40;
+
+        // Catalog block with a store information (type 2) and store (type 3) entry.
+        data[0x4000..0x4010].copy_from_slice(&vss_identifier);
+        data[0x4010] = 0x01;
+        data[0x4014] = 0x02;
+        data[0x4080] = 0x02;
+        data[0x408a] = 0x02;
+        data[0x4090] = 0x01;
+        data[0x40b0..0x40b8].copy_from_slice(&[0xf0, 0xb8, 0xf9, 0xd0, 0xa6, 0xc4, 0xd0, 0x01]);
+        data[0x4100] = 0x03;
+        data[0x4109] = 0x80;
+        data[0x4110] = 0x01;
+        data[0x4121] = 0xc0;
+
+        // Store block list block with a block descriptor of the block at offset 0x10000.
+        data[0x8000..0x8010].copy_from_slice(&vss_identifier);
+        data[0x8010] = 0x01;
+        data[0x8014] = 0x03;
+        data[0x8082] = 0x01;
+        data[0x8091] = 0x40;
+        data[0x8092] = 0x01;
+
+        // Store header block.
+        data[0xc000..0xc010].copy_from_slice(&vss_identifier);
+        data[0xc010] = 0x01;
+        data[0xc014] = 0x04;
+
+        // Current volume data.
+        data[0x10000..0x14000].fill(0xaa);
+
+        // Store data.
+        data[0x14000..0x18000].fill(0x55);
+
+        data
+    }
+}
@@ -142,6 +142,7 @@ assert_availability_binary cryptsetup
 assert_availability_binary dd
 assert_availability_binary fdisk
 assert_availability_binary genisoimage
+assert_availability_binary gzip
 assert_availability_binary gdisk
 assert_availability_binary losetup
 assert_availability_binary lvcreate
@@ -218,6 +219,33 @@ create_test_file_entries_with_extended_attributes ${MOUNT_POINT}
 
 sudo umount ${MOUNT_POINT}
 
+# Create an ext4 file system with extended attributes stored in the inode, in the
+# attributes block and in an extended attribute inode.
+IMAGE_FILE="test_data/ext/ext4_xattr.raw"
+IMAGE_SIZE=$(( 512 * 1024 ))
+
+dd if=/dev/zero of=${IMAGE_FILE} bs=${SECTOR_SIZE} count=$(( ${IMAGE_SIZE} / ${SECTOR_SIZE} )) 2> /dev/null
+
+mke2fs -b 1024 -I 256 -L ext4_xattr -q -t ext4 -O ^has_journal,ea_inode -E root_owner=0:0 ${IMAGE_FILE}
+
+TEMP_DIRECTORY=$(mktemp -d)
+
+echo "File with extended attributes" > ${TEMP_DIRECTORY}/xattr1
+echo -n "My 1st extended attribute" > ${TEMP_DIRECTORY}/user.myxattr1
+echo -n "unconfined_u:object_r:user_home_t:s0" > ${TEMP_DIRECTORY}/security.selinux
+printf '\x02\x00\x00\x00\x01\x00\x06\x00\xff\xff\xff\xff\x04\x00\x04\x00\xff\xff\xff\xff\x10\x00\x04\x00\xff\xff\xff\xff\x20\x00\x04\x00\xff\xff\xff\xff' > ${TEMP_DIRECTORY}/system.posix_acl_access
+head -c 1024 /dev/zero | tr '\0' 'A' > ${TEMP_DIRECTORY}/user.large
+
+debugfs -w -R "write ${TEMP_DIRECTORY}/xattr1 xattr1" ${IMAGE_FILE}
+
+for NAME in user.myxattr1 security.selinux system.posix_acl_access user.large
+do
+	debugfs -w -R "ea_set -f ${TEMP_DIRECTORY}/${NAME} /xattr1 ${NAME}" ${IMAGE_FILE}
+done
+e2fsck -fy ${IMAGE_FILE}
+
+rm -rf ${TEMP_DIRECTORY}
+
 # Create a FAT-12 file system.
 IMAGE_FILE="test_data/fat/fat12.raw"
 IMAGE_SIZE=$(( 4 * 1024 * 1024 ))
@@ -485,6 +513,15 @@ then
 	ewfacquire -u -c best -C case -D description -e examiner -E evidence -M logical -N notes -t test_data/ewf/ext2 test_data/ext/ext2.raw
 fi
 
+# Create a gzip compressed file with an ext2 file system.
+mkdir -p test_data/gzip
+
+gzip -c test_data/ext/ext2.raw > test_data/gzip/ext2.raw.gz
+
+# Create a gzip compressed file with multiple members with an ext2 file system.
+dd if=test_data/ext/ext2.raw bs=1M count=2 2> /dev/null | gzip -c > test_data/gzip/ext2_multi_member.raw.gz
+dd if=test_data/ext/ext2.raw bs=1M skip=2 2> /dev/null | gzip -c >> test_data/gzip/ext2_multi_member.raw.gz
+
 # Create a QCOW image with an ext2 file system.
 IMAGE_FILE="test_data/qcow/ext2.qcow2"
 
@@ -492,6 +529,21 @@ mkdir -p test_data/qcow
 
 qemu-img convert -f raw -O qcow2 test_data/ext/ext2.raw ${IMAGE_FILE}
 
+# Create QCOW images with zlib and zstd compressed clusters and an ext2 file system.
+qemu-img convert -c -f raw -O qcow2 test_data/ext/ext2.raw test_data/qcow/ext2_zlib.qcow2
+qemu-img convert -c -f raw -O qcow2 -o compression_type=zstd test_data/ext/ext2.raw test_data/qcow/ext2_zstd.qcow2
+
+# Create a QCOW image with an internal snapshot and an ext2 file system.
+IMAGE_FILE="test_data/qcow/ext2_snapshot.qcow2"
+
+qemu-img convert -f raw -O qcow2 test_data/ext/ext2.raw ${IMAGE_FILE}
+qemu-img snapshot -c snapshot1 ${IMAGE_FILE}
+qemu-io -c "write -P 0x4b 3584k 64k" ${IMAGE_FILE}
+
+# Create QCOW images with AES-CBC and LUKS encryption and an ext2 file system.
+qemu-img convert -f raw -O qcow2 --object secret,id=sec0,data=keramics -o encrypt.format=aes,encrypt.key-secret=sec0 test_data/ext/ext2.raw test_data/qcow/ext2_aes.qcow2
+qemu-img convert -f raw -O qcow2 --object secret,id=sec0,data=keramics -o encrypt.format=luks,encrypt.key-secret=sec0,encrypt.iter-time=10 test_data/ext/ext2.raw test_data/qcow/ext2_luks.qcow2
+
 # Create QCOW image with a FAT-16 file system.
 IMAGE_FILE="test_data/fat/fat16.raw"
 IMAGE_SIZE=$(( 16 * 1024 * 1024 ))